@@ -0,0 +1,92 @@
+// Clock - 画面の時刻基準
+// 責務: 業務日付の既定値と、記録時刻（UTC）の設定タイムゾーンでの表示
+
+use std::sync::{
+    Arc, OnceLock,
+    atomic::{AtomicU8, Ordering},
+};
+
+use chrono::{DateTime, FixedOffset, NaiveDate};
+use javelin_application::parameter_validation::FiscalCalendar;
+use javelin_domain::time_provider::{SystemTimeProvider, TimeProvider};
+
+/// 画面で使用するTimeProvider（起動時に一度だけ設定）
+static TIME_PROVIDER: OnceLock<Arc<dyn TimeProvider>> = OnceLock::new();
+
+/// 会計年度の開始月（起動時にシステム設定から設定）
+static FISCAL_YEAR_START_MONTH: AtomicU8 = AtomicU8::new(4);
+
+/// 画面で使用するTimeProviderを設定
+///
+/// 未設定の場合はシステム時計とローカルタイムゾーンを使用する。
+pub fn init_clock(time_provider: Arc<dyn TimeProvider>) {
+    let _ = TIME_PROVIDER.set(time_provider);
+}
+
+fn time_provider() -> &'static Arc<dyn TimeProvider> {
+    TIME_PROVIDER.get_or_init(|| Arc::new(SystemTimeProvider::local()))
+}
+
+/// 現在の業務日付
+pub fn business_date() -> NaiveDate {
+    time_provider().business_date()
+}
+
+/// 会計年度の開始月を設定
+pub fn set_fiscal_year_start_month(month: u8) {
+    FISCAL_YEAR_START_MONTH.store(month, Ordering::Relaxed);
+}
+
+/// 業務日付時点の会計カレンダー（条件入力の範囲チェックに使用）
+pub fn fiscal_calendar() -> FiscalCalendar {
+    FiscalCalendar::new(FISCAL_YEAR_START_MONTH.load(Ordering::Relaxed), business_date())
+}
+
+/// 設定タイムゾーンでの現在時刻
+pub fn now() -> DateTime<FixedOffset> {
+    let provider = time_provider();
+    provider.now().with_timezone(&provider.time_zone())
+}
+
+/// 業務日付・表示に用いるタイムゾーン
+pub fn time_zone() -> FixedOffset {
+    time_provider().time_zone()
+}
+
+/// 記録時刻（RFC3339）を設定タイムゾーンの `YYYY-MM-DD HH:MM:SS` で表示
+pub fn format_timestamp(timestamp: &str) -> String {
+    format_timestamp_in(timestamp, time_provider().time_zone(), "%Y-%m-%d %H:%M:%S")
+}
+
+/// 記録時刻（RFC3339）を指定タイムゾーン・書式で表示（解析できない場合はそのまま）
+pub fn format_timestamp_in(timestamp: &str, time_zone: FixedOffset, format: &str) -> String {
+    DateTime::parse_from_rfc3339(timestamp)
+        .map(|value| value.with_timezone(&time_zone).format(format).to_string())
+        .unwrap_or_else(|_| timestamp.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use javelin_domain::time_provider::parse_utc_offset;
+
+    use super::*;
+
+    #[test]
+    fn test_format_timestamp_in_configured_time_zone() {
+        let timestamp = "2024-03-31T16:30:00+00:00";
+        let format = "%Y-%m-%d %H:%M:%S";
+
+        assert_eq!(
+            format_timestamp_in(timestamp, parse_utc_offset("+09:00").unwrap(), format),
+            "2024-04-01 01:30:00"
+        );
+        assert_eq!(
+            format_timestamp_in(timestamp, parse_utc_offset("-05:00").unwrap(), format),
+            "2024-03-31 11:30:00"
+        );
+        assert_eq!(
+            format_timestamp_in("記録なし", parse_utc_offset("Z").unwrap(), format),
+            "記録なし"
+        );
+    }
+}
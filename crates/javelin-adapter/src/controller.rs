@@ -2,22 +2,78 @@
 // 責務: DTO変換、InputPort呼び出し
 // 禁止: 業務判断
 
+pub mod account_activity_controller;
 pub mod account_master_controller;
+pub mod account_reconciliation_controller;
+pub mod accounting_policy_controller;
 pub mod application_settings_controller;
+pub mod audit_export_controller;
+pub mod authentication_controller;
+pub mod balance_analysis_controller;
 pub mod batch_history_controller;
+pub mod business_metrics_controller;
+pub mod calendar_master_controller;
 pub mod closing_controller;
+pub mod closing_timetable_controller;
 pub mod company_master_controller;
+pub mod consistency_check_controller;
+pub mod dimension_master_controller;
+pub mod entry_link_controller;
+pub mod export_protection_controller;
+pub mod financial_instrument_controller;
+pub mod inbox_controller;
+pub mod initial_setup_controller;
+pub mod integrity_audit_controller;
+pub mod inventory_worksheet_controller;
+pub mod job_queue_controller;
 pub mod journal_entry_controller;
+pub mod journal_import_controller;
+pub mod ledger_annotation_controller;
 pub mod ledger_controller;
+pub mod management_account_mapping_controller;
+pub mod note_cross_reference_controller;
+pub mod period_reopen_controller;
+pub mod projection_console_controller;
 pub mod record_user_action_controller;
+pub mod report_archive_controller;
+pub mod report_parameter_history_controller;
+pub mod request_control;
 pub mod search_controller;
+pub mod sequence_audit_controller;
+pub mod session_log_controller;
+pub mod statement_line_mapping_controller;
+pub mod storage_telemetry_controller;
 pub mod subsidiary_account_master_controller;
+pub mod supplier_invoice_controller;
+pub mod suspense_clearing_controller;
+pub mod table_preference_controller;
+pub mod use_case_handle;
+pub mod user_activity_controller;
+pub mod variance_commentary_controller;
 
+pub use account_activity_controller::AccountActivityController;
 pub use account_master_controller::AccountMasterController;
+pub use account_reconciliation_controller::AccountReconciliationController;
+pub use accounting_policy_controller::AccountingPolicyController;
 pub use application_settings_controller::ApplicationSettingsController;
+pub use audit_export_controller::AuditExportController;
+pub use authentication_controller::AuthenticationController;
+pub use balance_analysis_controller::BalanceAnalysisController;
 pub use batch_history_controller::BatchHistoryController;
-pub use closing_controller::ClosingController;
+pub use business_metrics_controller::BusinessMetricsController;
+pub use calendar_master_controller::CalendarMasterController;
+pub use closing_controller::{ClosingController, ClosingUseCases};
+pub use closing_timetable_controller::ClosingTimetableController;
 pub use company_master_controller::CompanyMasterController;
+pub use consistency_check_controller::ConsistencyCheckController;
+pub use dimension_master_controller::DimensionMasterController;
+pub use entry_link_controller::EntryLinkController;
+pub use export_protection_controller::ExportProtectionController;
+pub use financial_instrument_controller::FinancialInstrumentController;
+pub use inbox_controller::InboxController;
+pub use initial_setup_controller::InitialSetupController;
+pub use integrity_audit_controller::IntegrityAuditController;
+pub use inventory_worksheet_controller::InventoryWorksheetController;
 // Re-export application layer DTOs for convenience
 pub use javelin_application::dtos::{
     request::{
@@ -31,8 +87,31 @@ pub use javelin_application::dtos::{
         UserOptionsDto,
     },
 };
+pub use job_queue_controller::{ControllerJobRunner, JobQueueController};
 pub use journal_entry_controller::JournalEntryController;
+pub use journal_import_controller::JournalImportController;
+pub use ledger_annotation_controller::LedgerAnnotationController;
 pub use ledger_controller::LedgerController;
+pub use management_account_mapping_controller::ManagementAccountMappingController;
+pub use note_cross_reference_controller::NoteCrossReferenceController;
+pub use period_reopen_controller::PeriodReopenController;
+pub use projection_console_controller::ProjectionConsoleController;
 pub use record_user_action_controller::RecordUserActionController;
+pub use report_archive_controller::ReportArchiveController;
+pub use report_parameter_history_controller::ReportParameterHistoryController;
+pub use request_control::{
+    CancellationToken, DEFAULT_REQUEST_TIMEOUT, RequestKind, RequestTicket, RequestTracker,
+    run_to_completion, run_with_timeout,
+};
 pub use search_controller::SearchController;
+pub use sequence_audit_controller::SequenceAuditController;
+pub use session_log_controller::SessionLogController;
+pub use statement_line_mapping_controller::StatementLineMappingController;
+pub use storage_telemetry_controller::StorageTelemetryController;
 pub use subsidiary_account_master_controller::SubsidiaryAccountMasterController;
+pub use supplier_invoice_controller::SupplierInvoiceController;
+pub use suspense_clearing_controller::SuspenseClearingController;
+pub use table_preference_controller::TablePreferenceController;
+pub use use_case_handle::{UseCaseFuture, UseCaseHandle};
+pub use user_activity_controller::{USER_ACTIVITY_REPORT_DAYS, UserActivityController};
+pub use variance_commentary_controller::VarianceCommentaryController;
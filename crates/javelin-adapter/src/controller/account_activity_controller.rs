@@ -0,0 +1,53 @@
+// AccountActivityController - 勘定科目別の記帳量分析コントローラ
+
+use std::sync::Arc;
+
+use javelin_application::{
+    dtos::{request::AnalyzeAccountActivityRequest, response::AnalyzeAccountActivityResponse},
+    interactor::{AccountingPolicyInteractor, AnalyzeAccountActivityInteractor},
+};
+use javelin_infrastructure::{
+    ledger_query_service_impl::LedgerQueryServiceImpl, repositories::AccountingPolicyRepositoryImpl,
+};
+
+use crate::error_log::to_user_message;
+
+/// 勘定科目別の記帳量分析コントローラ
+pub struct AccountActivityController {
+    interactor: AnalyzeAccountActivityInteractor<LedgerQueryServiceImpl>,
+    /// 金額マスキングの取得元
+    accounting_policy: AccountingPolicyInteractor<AccountingPolicyRepositoryImpl>,
+}
+
+impl AccountActivityController {
+    pub fn new(
+        ledger_query_service: Arc<LedgerQueryServiceImpl>,
+        accounting_policy_repository: Arc<AccountingPolicyRepositoryImpl>,
+    ) -> Self {
+        Self {
+            interactor: AnalyzeAccountActivityInteractor::new(ledger_query_service),
+            accounting_policy: AccountingPolicyInteractor::new(accounting_policy_repository),
+        }
+    }
+
+    /// 指定月までのmonthsか月分の記帳件数・金額を勘定科目別に集計
+    ///
+    /// ユーザが参照できない科目の金額はマスキングする（マスキングを取得できない場合は集計しない）。
+    pub async fn analyze(
+        &self,
+        user_id: &str,
+        fiscal_year: i32,
+        period: u8,
+        months: u8,
+    ) -> Result<AnalyzeAccountActivityResponse, String> {
+        let amount_mask =
+            self.accounting_policy.amount_mask(user_id).await.map_err(to_user_message)?;
+        let mut response = self
+            .interactor
+            .execute(AnalyzeAccountActivityRequest { fiscal_year, period, months })
+            .await
+            .map_err(to_user_message)?;
+        response.mask_amounts(&amount_mask);
+        Ok(response)
+    }
+}
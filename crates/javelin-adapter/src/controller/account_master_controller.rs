@@ -1,28 +1,60 @@
 // AccountMasterController - 勘定科目マスタコントローラ
 
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
 use javelin_application::{
-    dtos::{request::LoadAccountMasterRequest, response::LoadAccountMasterResponse},
+    dtos::{
+        request::{LoadAccountMasterRequest, RevertMasterChangeRequest},
+        response::{LoadAccountMasterResponse, MasterChangeDto},
+    },
     input_ports::LoadAccountMasterInputPort,
-    interactor::master_data::LoadAccountMasterInteractor,
+    interactor::{
+        AccountMasterInteractor, UpdateAccountMasterRequest,
+        master_data::LoadAccountMasterInteractor,
+    },
+    query_service::AccountMasterCache,
+};
+use javelin_infrastructure::{
+    queries::master_data_loader_impl::MasterDataLoaderImpl,
+    repositories::AccountMasterRepositoryImpl,
 };
-use javelin_infrastructure::queries::master_data_loader_impl::MasterDataLoaderImpl;
 
-use crate::navigation::PresenterRegistry;
+use crate::{
+    controller::RequestTracker, error_log::to_user_message, navigation::PresenterRegistry,
+};
 
 /// 勘定科目マスタコントローラ
 pub struct AccountMasterController {
     query_service: Arc<MasterDataLoaderImpl>,
+    /// 勘定科目マスタのキャッシュ（科目選択のたびの再ロードを避ける）
+    cache: Arc<AccountMasterCache>,
+    /// 変更・変更履歴・取消し（変更イベントとして記録する）
+    interactor: AccountMasterInteractor<AccountMasterRepositoryImpl>,
     presenter_registry: Arc<PresenterRegistry>,
+    requests: RequestTracker,
 }
 
 impl AccountMasterController {
     pub fn new(
         query_service: Arc<MasterDataLoaderImpl>,
+        cache: Arc<AccountMasterCache>,
         presenter_registry: Arc<PresenterRegistry>,
     ) -> Self {
-        Self { query_service, presenter_registry }
+        let interactor = AccountMasterInteractor::new(query_service.account_repository())
+            .with_cache(Arc::clone(&cache));
+        Self {
+            query_service,
+            cache,
+            interactor,
+            presenter_registry,
+            requests: RequestTracker::default(),
+        }
+    }
+
+    /// リクエストタイムアウトを設定
+    pub fn with_request_timeout(mut self, timeout: Duration) -> Self {
+        self.requests = RequestTracker::new(timeout);
+        self
     }
 
     /// PresenterRegistryへの参照を取得
@@ -47,12 +79,59 @@ impl AccountMasterController {
             let interactor = LoadAccountMasterInteractor::new(
                 Arc::clone(&self.query_service),
                 account_master_presenter,
-            );
+            )
+            .with_cache(Arc::clone(&self.cache));
 
-            // 実行
-            interactor.execute(request).await.map_err(|e| e.to_string())
+            // 実行（タイムアウト・キャンセル時は結果を破棄）
+            self.requests
+                .run(page_id, interactor.execute(request))
+                .await
+                .map_err(to_user_message)?
+                .map_err(to_user_message)
         } else {
             Err(format!("AccountMasterPresenter not found for page_id: {}", page_id))
         }
     }
+
+    /// キャッシュを破棄して勘定科目マスタを再読込
+    pub async fn handle_reload_account_master(
+        &self,
+        page_id: uuid::Uuid,
+        request: LoadAccountMasterRequest,
+    ) -> Result<LoadAccountMasterResponse, String> {
+        self.cache.invalidate();
+        self.handle_load_account_master(page_id, request).await
+    }
+
+    /// 勘定科目の名称・有効状態を変更
+    pub async fn update_account(
+        &self,
+        code: String,
+        name: String,
+        is_active: bool,
+        changed_by: String,
+    ) -> Result<(), String> {
+        self.interactor
+            .update(UpdateAccountMasterRequest { code, name, is_active, changed_by })
+            .await
+            .map_err(to_user_message)
+    }
+
+    /// 勘定科目の変更履歴（新しい順）
+    pub async fn history(&self, code: String) -> Result<Vec<MasterChangeDto>, String> {
+        self.interactor.history(code).await.map_err(to_user_message)
+    }
+
+    /// 勘定科目の変更を取り消す
+    pub async fn revert_change(
+        &self,
+        code: String,
+        sequence: u64,
+        changed_by: String,
+    ) -> Result<(), String> {
+        self.interactor
+            .revert(RevertMasterChangeRequest { code, sequence, changed_by })
+            .await
+            .map_err(to_user_message)
+    }
 }
@@ -0,0 +1,136 @@
+// AccountReconciliationController - 勘定照合（残高の裏付け）コントローラ
+
+use std::sync::Arc;
+
+use javelin_application::{
+    dtos::{
+        request::{
+            AccountReconciliationTarget, AddReconciliationItemRequest,
+            MarkAccountReconciledRequest, RemoveReconciliationItemRequest,
+        },
+        response::AccountReconciliationResponse,
+    },
+    interactor::AccountReconciliationInteractor,
+};
+use javelin_infrastructure::{
+    ledger_query_service_impl::LedgerQueryServiceImpl,
+    repositories::AccountReconciliationRepositoryImpl,
+};
+
+use super::inventory_worksheet_controller::split_csv_line;
+use crate::error_log::to_user_message;
+
+/// 勘定照合コントローラ
+pub struct AccountReconciliationController {
+    interactor: AccountReconciliationInteractor<
+        AccountReconciliationRepositoryImpl,
+        LedgerQueryServiceImpl,
+    >,
+}
+
+impl AccountReconciliationController {
+    pub fn new(
+        reconciliation_repository: Arc<AccountReconciliationRepositoryImpl>,
+        ledger_query_service: Arc<LedgerQueryServiceImpl>,
+    ) -> Self {
+        Self {
+            interactor: AccountReconciliationInteractor::new(
+                reconciliation_repository,
+                ledger_query_service,
+            ),
+        }
+    }
+
+    /// 会計期間の照合ワークスペース一覧を取得
+    pub async fn list(
+        &self,
+        fiscal_year: i32,
+        period: u8,
+    ) -> Result<Vec<AccountReconciliationResponse>, String> {
+        self.interactor.list(fiscal_year, period).await.map_err(to_user_message)
+    }
+
+    /// 入力（内容,金額[,証憑]）から裏付け明細を追加
+    pub async fn add_item(
+        &self,
+        target: AccountReconciliationTarget,
+        input: &str,
+    ) -> Result<AccountReconciliationResponse, String> {
+        let (description, amount, reference) = parse_item(input)?;
+        self.interactor
+            .add_item(AddReconciliationItemRequest { target, description, amount, reference })
+            .await
+            .map_err(to_user_message)
+    }
+
+    /// 裏付け明細を削除
+    pub async fn remove_item(
+        &self,
+        target: AccountReconciliationTarget,
+        item_index: usize,
+    ) -> Result<AccountReconciliationResponse, String> {
+        self.interactor
+            .remove_item(RemoveReconciliationItemRequest { target, item_index })
+            .await
+            .map_err(to_user_message)
+    }
+
+    /// 現在の元帳残高で照合済にする
+    pub async fn mark_reconciled(
+        &self,
+        target: AccountReconciliationTarget,
+        reconciled_by: String,
+    ) -> Result<AccountReconciliationResponse, String> {
+        self.interactor
+            .mark_reconciled(MarkAccountReconciledRequest { target, reconciled_by })
+            .await
+            .map_err(to_user_message)
+    }
+
+    /// 照合を取り消す
+    pub async fn reopen(
+        &self,
+        target: AccountReconciliationTarget,
+    ) -> Result<AccountReconciliationResponse, String> {
+        self.interactor.reopen(target).await.map_err(to_user_message)
+    }
+
+    /// 締日固定前に照合が必要な科目かを設定
+    pub async fn set_required(&self, account_code: &str, required: bool) -> Result<(), String> {
+        self.interactor
+            .set_required(account_code, required)
+            .await
+            .map_err(to_user_message)
+    }
+}
+
+/// 裏付け明細の入力（内容,金額[,証憑]）を分解
+fn parse_item(input: &str) -> Result<(String, f64, Option<String>), String> {
+    let fields = split_csv_line(input);
+    if !(2..=3).contains(&fields.len()) {
+        return Err("裏付け明細は「内容,金額[,証憑]」の形式で入力してください".to_string());
+    }
+    let amount = fields[1]
+        .replace(',', "")
+        .parse::<f64>()
+        .map_err(|_| format!("金額が数値ではありません: {}", fields[1]))?;
+    Ok((fields[0].clone(), amount, fields.get(2).cloned()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_item() {
+        let (description, amount, reference) =
+            parse_item("A銀行 残高証明,\"1,000,000\",BS-001.pdf").unwrap();
+        assert_eq!(description, "A銀行 残高証明");
+        assert_eq!(amount, 1_000_000.0);
+        assert_eq!(reference.as_deref(), Some("BS-001.pdf"));
+
+        assert_eq!(parse_item("未達小切手,-500").unwrap().2, None);
+        assert!(parse_item("未達小切手").is_err());
+        assert_eq!(parse_item("未達小切手,五百").unwrap_err(), "金額が数値ではありません: 五百");
+    }
+}
@@ -0,0 +1,86 @@
+// AccountingPolicyController - 会計方針設定コントローラ
+
+use std::sync::Arc;
+
+use javelin_application::{
+    dtos::{
+        request::{
+            UpdateAmountMaskingRulesRequest, UpdateCurrencyRoundingRequest,
+            UpdateNegativePresentationRequest, UpdateTaxRoundingRequest, UpdateUserRolesRequest,
+        },
+        response::{AmountFormat, AmountMask, LoadAccountingPolicyResponse},
+    },
+    interactor::AccountingPolicyInteractor,
+};
+use javelin_infrastructure::repositories::AccountingPolicyRepositoryImpl;
+
+use crate::error_log::to_user_message;
+
+/// 会計方針設定コントローラ
+pub struct AccountingPolicyController {
+    interactor: AccountingPolicyInteractor<AccountingPolicyRepositoryImpl>,
+}
+
+impl AccountingPolicyController {
+    pub fn new(repository: Arc<AccountingPolicyRepositoryImpl>) -> Self {
+        Self { interactor: AccountingPolicyInteractor::new(repository) }
+    }
+
+    /// 会計方針と変更履歴を取得
+    pub async fn load_policy(&self, user_id: &str) -> Result<LoadAccountingPolicyResponse, String> {
+        self.interactor.load(user_id).await.map_err(to_user_message)
+    }
+
+    /// 帳票・エクスポート用の金額書式を取得
+    pub async fn amount_format(&self) -> Result<AmountFormat, String> {
+        self.interactor.amount_format().await.map_err(to_user_message)
+    }
+
+    /// ユーザの金額マスキングを取得
+    pub async fn amount_mask(&self, user_id: &str) -> Result<AmountMask, String> {
+        self.interactor.amount_mask(user_id).await.map_err(to_user_message)
+    }
+
+    /// 通貨別端数処理を変更（変更がなければfalse）
+    pub async fn update_currency_rounding(
+        &self,
+        request: UpdateCurrencyRoundingRequest,
+    ) -> Result<bool, String> {
+        self.interactor.update_currency_rounding(request).await.map_err(to_user_message)
+    }
+
+    /// 税区分別税額端数処理を変更（変更がなければfalse）
+    pub async fn update_tax_rounding(
+        &self,
+        request: UpdateTaxRoundingRequest,
+    ) -> Result<bool, String> {
+        self.interactor.update_tax_rounding(request).await.map_err(to_user_message)
+    }
+
+    /// 負数表示方法を変更（変更がなければfalse）
+    pub async fn update_negative_presentation(
+        &self,
+        request: UpdateNegativePresentationRequest,
+    ) -> Result<bool, String> {
+        self.interactor
+            .update_negative_presentation(request)
+            .await
+            .map_err(to_user_message)
+    }
+
+    /// 金額マスキング規則を変更（変更がなければfalse）
+    pub async fn update_amount_masking_rules(
+        &self,
+        request: UpdateAmountMaskingRulesRequest,
+    ) -> Result<bool, String> {
+        self.interactor
+            .update_amount_masking_rules(request)
+            .await
+            .map_err(to_user_message)
+    }
+
+    /// ユーザのロール割当を変更（変更がなければfalse）
+    pub async fn update_user_roles(&self, request: UpdateUserRolesRequest) -> Result<bool, String> {
+        self.interactor.update_user_roles(request).await.map_err(to_user_message)
+    }
+}
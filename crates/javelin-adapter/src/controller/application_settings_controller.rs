@@ -5,24 +5,39 @@ use std::sync::Arc;
 use javelin_application::{
     dtos::{request::LoadApplicationSettingsRequest, response::LoadApplicationSettingsResponse},
     input_ports::LoadApplicationSettingsInputPort,
-    interactor::master_data::LoadApplicationSettingsInteractor,
+    interactor::{
+        ApplicationSettingsInteractor, ToggleClosingLockCheckRequest, UpdateApprovalSlaRequest,
+        UpdateBatchNotificationRequest, UpdateDeletedEntryRetentionRequest,
+        UpdateExportProtectionRequest, UpdateReportDeliveryRequest,
+        master_data::LoadApplicationSettingsInteractor,
+    },
+};
+use javelin_infrastructure::{
+    queries::master_data_loader_impl::MasterDataLoaderImpl,
+    repositories::ApplicationSettingsRepositoryImpl,
 };
-use javelin_infrastructure::queries::master_data_loader_impl::MasterDataLoaderImpl;
 
-use crate::navigation::PresenterRegistry;
+use crate::{
+    error_log::to_user_message, navigation::PresenterRegistry, notification::BatchNotifier,
+};
 
 /// アプリケーション設定コントローラ
 pub struct ApplicationSettingsController {
     query_service: Arc<MasterDataLoaderImpl>,
     presenter_registry: Arc<PresenterRegistry>,
+    settings_interactor: ApplicationSettingsInteractor<ApplicationSettingsRepositoryImpl>,
+    notifier: Arc<BatchNotifier>,
 }
 
 impl ApplicationSettingsController {
     pub fn new(
         query_service: Arc<MasterDataLoaderImpl>,
         presenter_registry: Arc<PresenterRegistry>,
+        notifier: Arc<BatchNotifier>,
     ) -> Self {
-        Self { query_service, presenter_registry }
+        let settings_interactor =
+            ApplicationSettingsInteractor::new(query_service.settings_repository());
+        Self { query_service, presenter_registry, settings_interactor, notifier }
     }
 
     /// PresenterRegistryへの参照を取得
@@ -50,9 +65,98 @@ impl ApplicationSettingsController {
             );
 
             // 実行
-            interactor.execute(request).await.map_err(|e| e.to_string())
+            interactor.execute(request).await.map_err(to_user_message)
         } else {
             Err(format!("ApplicationSettingsPresenter not found for page_id: {}", page_id))
         }
     }
+
+    /// バッチ完了通知設定を保存し、実行中の通知にも反映
+    pub async fn update_batch_notification(
+        &self,
+        bell_enabled: bool,
+        desktop_enabled: bool,
+    ) -> Result<(), String> {
+        let settings = self
+            .settings_interactor
+            .update_batch_notification(UpdateBatchNotificationRequest {
+                bell_enabled,
+                desktop_enabled,
+            })
+            .await
+            .map_err(to_user_message)?;
+        self.notifier.configure(settings.bell_enabled, settings.desktop_enabled);
+        Ok(())
+    }
+
+    /// 承認SLAの閾値（時間）を保存
+    pub async fn update_approval_sla(
+        &self,
+        warning_hours: u32,
+        breach_hours: u32,
+    ) -> Result<(), String> {
+        self.settings_interactor
+            .update_approval_sla(UpdateApprovalSlaRequest { warning_hours, breach_hours })
+            .await
+            .map(|_| ())
+            .map_err(|e| e.to_string())
+    }
+
+    /// 出力ファイル保護設定を保存
+    pub async fn update_export_protection(
+        &self,
+        encryption_enabled: bool,
+        signature_enabled: bool,
+    ) -> Result<(), String> {
+        self.settings_interactor
+            .update_export_protection(UpdateExportProtectionRequest {
+                encryption_enabled,
+                signature_enabled,
+            })
+            .await
+            .map(|_| ())
+            .map_err(to_user_message)
+    }
+
+    /// 削除済み仕訳の保持設定を保存
+    pub async fn update_deleted_entry_retention(
+        &self,
+        retain_enabled: bool,
+        purge_after_months: u32,
+    ) -> Result<(), String> {
+        self.settings_interactor
+            .update_deleted_entry_retention(UpdateDeletedEntryRetentionRequest {
+                retain_enabled,
+                purge_after_months,
+            })
+            .await
+            .map(|_| ())
+            .map_err(to_user_message)
+    }
+
+    /// 締日固定の事前検証項目の有効・無効を切り替えて保存
+    pub async fn toggle_closing_lock_check(&self, check: &str) -> Result<(), String> {
+        self.settings_interactor
+            .toggle_closing_lock_check(ToggleClosingLockCheckRequest { check: check.to_string() })
+            .await
+            .map(|_| ())
+            .map_err(to_user_message)
+    }
+
+    /// 帳票の配信先（空白区切りのURI、空の場合は配信しない）を保存し、配信先の件数を返す
+    pub async fn update_report_delivery(
+        &self,
+        report_type: &str,
+        input: &str,
+    ) -> Result<usize, String> {
+        let destinations = input.split_whitespace().map(String::from).collect();
+        self.settings_interactor
+            .update_report_delivery(UpdateReportDeliveryRequest {
+                report_type: report_type.to_string(),
+                destinations,
+            })
+            .await
+            .map(|settings| settings.destinations(report_type).len())
+            .map_err(to_user_message)
+    }
 }
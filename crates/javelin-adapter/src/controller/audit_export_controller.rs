@@ -0,0 +1,59 @@
+// AuditExportController - 監査用帳簿出力コントローラ
+// 責務: 仕訳帳・総勘定元帳・索引ファイルの作成と出力先への書き込み
+
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use javelin_application::{
+    dtos::{request::AuditExportRequest, response::AuditExportResponse},
+    interactor::AuditExportInteractor,
+};
+use javelin_infrastructure::{queries::AuditExportQueryServiceImpl, services::ReportDigesterImpl};
+
+use crate::error_log::to_user_message;
+
+/// 監査用帳簿出力コントローラ
+///
+/// 画面とコマンドラインの両方から利用する。
+pub struct AuditExportController {
+    interactor: AuditExportInteractor<AuditExportQueryServiceImpl, ReportDigesterImpl>,
+}
+
+impl AuditExportController {
+    pub fn new(query_service: Arc<AuditExportQueryServiceImpl>) -> Self {
+        Self {
+            interactor: AuditExportInteractor::new(query_service, Arc::new(ReportDigesterImpl)),
+        }
+    }
+
+    /// 指定年度の帳簿を `output_dir/audit_export_{年度}/` に出力
+    ///
+    /// 既存のファイルは上書きする。出力先のディレクトリと出力結果を返す。
+    pub async fn export(
+        &self,
+        fiscal_year: u32,
+        exported_by: String,
+        output_dir: &Path,
+    ) -> Result<(PathBuf, AuditExportResponse), String> {
+        let response = self
+            .interactor
+            .execute(AuditExportRequest { fiscal_year, exported_by })
+            .await
+            .map_err(to_user_message)?;
+
+        let directory = output_dir.join(response.directory_name());
+        tokio::fs::create_dir_all(&directory)
+            .await
+            .map_err(|e| format!("出力先を作成できません: {} ({})", directory.display(), e))?;
+        for file in response.files() {
+            let path = directory.join(&file.file_name);
+            tokio::fs::write(&path, &file.content)
+                .await
+                .map_err(|e| format!("出力に失敗しました: {} ({})", path.display(), e))?;
+        }
+
+        Ok((directory, response))
+    }
+}
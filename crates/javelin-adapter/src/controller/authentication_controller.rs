@@ -0,0 +1,56 @@
+// AuthenticationController - ログイン・利用者登録コントローラ
+
+use std::sync::Arc;
+
+use javelin_application::{
+    dtos::{
+        request::{LoginRequest, RegisterUserAccountRequest},
+        response::LoginResponse,
+    },
+    interactor::{AuthenticationInteractor, UserAccountInteractor},
+};
+use javelin_infrastructure::{
+    repositories::{AccountingPolicyRepositoryImpl, UserAccountRepositoryImpl},
+    services::PasswordHasherImpl,
+};
+
+use crate::error_log::to_user_message;
+
+/// ログイン・利用者登録コントローラ
+pub struct AuthenticationController {
+    interactor: AuthenticationInteractor<UserAccountRepositoryImpl, PasswordHasherImpl>,
+    user_accounts: UserAccountInteractor<
+        UserAccountRepositoryImpl,
+        AccountingPolicyRepositoryImpl,
+        PasswordHasherImpl,
+    >,
+}
+
+impl AuthenticationController {
+    pub fn new(
+        repository: Arc<UserAccountRepositoryImpl>,
+        policy_repository: Arc<AccountingPolicyRepositoryImpl>,
+        hasher: Arc<PasswordHasherImpl>,
+    ) -> Self {
+        Self {
+            interactor: AuthenticationInteractor::new(Arc::clone(&repository), Arc::clone(&hasher)),
+            user_accounts: UserAccountInteractor::new(repository, policy_repository, hasher),
+        }
+    }
+
+    /// ローカル資格情報でログイン
+    pub async fn login(&self, user_id: String, password: String) -> Result<LoginResponse, String> {
+        self.interactor
+            .login(LoginRequest { user_id, password })
+            .await
+            .map_err(to_user_message)
+    }
+
+    /// 利用者を登録（管理者のみ）
+    pub async fn register_user(
+        &self,
+        request: RegisterUserAccountRequest,
+    ) -> Result<LoginResponse, String> {
+        self.user_accounts.register(request).await.map_err(to_user_message)
+    }
+}
@@ -0,0 +1,38 @@
+// BalanceAnalysisController - 勘定残高の期間比較分析コントローラ
+
+use std::sync::Arc;
+
+use javelin_application::{
+    dtos::{
+        request::{AnalyzeBalancesRequest, BalanceAnomalyThresholds},
+        response::AnalyzeBalancesResponse,
+    },
+    interactor::AnalyzeBalancesInteractor,
+};
+use javelin_infrastructure::ledger_query_service_impl::LedgerQueryServiceImpl;
+
+use crate::error_log::to_user_message;
+
+/// 勘定残高の期間比較分析コントローラ
+pub struct BalanceAnalysisController {
+    interactor: AnalyzeBalancesInteractor<LedgerQueryServiceImpl>,
+}
+
+impl BalanceAnalysisController {
+    pub fn new(ledger_query_service: Arc<LedgerQueryServiceImpl>) -> Self {
+        Self { interactor: AnalyzeBalancesInteractor::new(ledger_query_service) }
+    }
+
+    /// 指定月の残高を前月・前年同月と比較し、異常値を重要度順に取得
+    pub async fn analyze(
+        &self,
+        fiscal_year: i32,
+        period: u8,
+        thresholds: BalanceAnomalyThresholds,
+    ) -> Result<AnalyzeBalancesResponse, String> {
+        self.interactor
+            .execute(AnalyzeBalancesRequest { fiscal_year, period, thresholds })
+            .await
+            .map_err(to_user_message)
+    }
+}
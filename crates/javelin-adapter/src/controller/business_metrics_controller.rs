@@ -0,0 +1,24 @@
+// BusinessMetricsController - 業務指標（KPI）コントローラ
+
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+use javelin_application::business_metrics::{BusinessMetrics, DailyMetric};
+
+use crate::error_log::to_user_message;
+
+/// 業務指標（KPI）コントローラ
+pub struct BusinessMetricsController {
+    metrics: Arc<dyn BusinessMetrics>,
+}
+
+impl BusinessMetricsController {
+    pub fn new(metrics: Arc<dyn BusinessMetrics>) -> Self {
+        Self { metrics }
+    }
+
+    /// 指定日以降の日次集計（日付順）
+    pub async fn daily_metrics(&self, from: NaiveDate) -> Result<Vec<DailyMetric>, String> {
+        self.metrics.daily_metrics(from).await.map_err(to_user_message)
+    }
+}
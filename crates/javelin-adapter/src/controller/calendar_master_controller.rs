@@ -0,0 +1,70 @@
+// CalendarMasterController - カレンダーマスタコントローラ
+
+use std::{sync::Arc, time::Duration};
+
+use javelin_application::{
+    dtos::{request::LoadCalendarMasterRequest, response::LoadCalendarMasterResponse},
+    input_ports::LoadCalendarMasterInputPort,
+    interactor::master_data::LoadCalendarMasterInteractor,
+};
+use javelin_infrastructure::queries::master_data_loader_impl::MasterDataLoaderImpl;
+
+use crate::{
+    controller::RequestTracker, error_log::to_user_message, navigation::PresenterRegistry,
+};
+
+/// カレンダーマスタコントローラ
+pub struct CalendarMasterController {
+    query_service: Arc<MasterDataLoaderImpl>,
+    presenter_registry: Arc<PresenterRegistry>,
+    requests: RequestTracker,
+}
+
+impl CalendarMasterController {
+    pub fn new(
+        query_service: Arc<MasterDataLoaderImpl>,
+        presenter_registry: Arc<PresenterRegistry>,
+    ) -> Self {
+        Self { query_service, presenter_registry, requests: RequestTracker::default() }
+    }
+
+    /// リクエストタイムアウトを設定
+    pub fn with_request_timeout(mut self, timeout: Duration) -> Self {
+        self.requests = RequestTracker::new(timeout);
+        self
+    }
+
+    /// PresenterRegistryへの参照を取得
+    pub fn presenter_registry(&self) -> &Arc<PresenterRegistry> {
+        &self.presenter_registry
+    }
+
+    /// カレンダーマスタを取得
+    pub async fn handle_load_calendar_master(
+        &self,
+        page_id: uuid::Uuid,
+        request: LoadCalendarMasterRequest,
+    ) -> Result<LoadCalendarMasterResponse, String> {
+        // PresenterRegistryからpage_id用のPresenterを取得
+        if let Some(calendar_master_presenter_arc) =
+            self.presenter_registry.get_calendar_master_presenter(page_id)
+        {
+            let calendar_master_presenter = (*calendar_master_presenter_arc).clone();
+
+            // このページ専用のInteractorを動的に作成
+            let interactor = LoadCalendarMasterInteractor::new(
+                Arc::clone(&self.query_service),
+                calendar_master_presenter,
+            );
+
+            // 実行（タイムアウト・キャンセル時は結果を破棄）
+            self.requests
+                .run(page_id, interactor.execute(request))
+                .await
+                .map_err(to_user_message)?
+                .map_err(to_user_message)
+        } else {
+            Err(format!("CalendarMasterPresenter not found for page_id: {}", page_id))
+        }
+    }
+}
@@ -1,88 +1,146 @@
 // ClosingController - 月次決算処理コントローラ
 // 責務: 月次決算関連のユースケースを呼び出す
 
-use std::sync::Arc;
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
 use javelin_application::{
+    business_metrics::{BusinessMetric, BusinessMetrics, record_metric},
     dtos::{
         AdjustAccountsRequest, AdjustAccountsResponse, ApplyIfrsValuationRequest,
         ApplyIfrsValuationResponse, ConsolidateLedgerRequest, ConsolidateLedgerResponse,
         GenerateFinancialStatementsRequest, GenerateFinancialStatementsResponse,
         GenerateNoteDraftRequest, GenerateNoteDraftResponse, GenerateTrialBalanceRequest,
         GenerateTrialBalanceResponse, LockClosingPeriodRequest, LockClosingPeriodResponse,
-        PrepareClosingRequest, PrepareClosingResponse,
-    },
-    input_ports::{
-        AdjustAccountsUseCase, ApplyIfrsValuationUseCase, ConsolidateLedgerUseCase,
-        GenerateFinancialStatementsUseCase, GenerateNoteDraftUseCase, GenerateTrialBalanceUseCase,
-        LockClosingPeriodUseCase, PrepareClosingUseCase,
+        PrepareClosingRequest, PrepareClosingResponse, request::ValidateClosingLockRequest,
+        response::ClosingLockValidationReport,
     },
+    error::ApplicationResult,
+    interactor::ClosingTimetableInteractor,
+};
+use javelin_domain::masters::ClosingStep;
+use javelin_infrastructure::repositories::ClosingTimetableRepositoryImpl;
+
+use crate::{
+    controller::{DEFAULT_REQUEST_TIMEOUT, RequestKind, UseCaseHandle, run_with_timeout},
+    error::{AdapterError, AdapterResult},
+    error_log::record_error,
+    notification::{BatchEvent, BatchNotifier},
 };
 
-use crate::error::AdapterResult;
-
-pub struct ClosingController<
-    Consolidate,
-    Prepare,
-    Lock,
-    TrialBalance,
-    NoteDraft,
-    Adjust,
-    Ifrs,
-    Financial,
-> where
-    Consolidate: ConsolidateLedgerUseCase,
-    Prepare: PrepareClosingUseCase,
-    Lock: LockClosingPeriodUseCase,
-    TrialBalance: GenerateTrialBalanceUseCase,
-    NoteDraft: GenerateNoteDraftUseCase,
-    Adjust: AdjustAccountsUseCase,
-    Ifrs: ApplyIfrsValuationUseCase,
-    Financial: GenerateFinancialStatementsUseCase,
-{
-    consolidate_ledger: Arc<Consolidate>,
-    prepare_closing: Arc<Prepare>,
-    lock_closing_period: Arc<Lock>,
-    generate_trial_balance: Arc<TrialBalance>,
-    generate_note_draft: Arc<NoteDraft>,
-    adjust_accounts: Arc<Adjust>,
-    apply_ifrs_valuation: Arc<Ifrs>,
-    generate_financial_statements: Arc<Financial>,
+/// 締め処理の正常終了で進捗を完了にした場合の更新者
+const TIMETABLE_COMPLETED_BY: &str = "system";
+
+/// 締め処理のユースケース
+///
+/// ユースケースを追加する場合は、ここにフィールドを追加して構築時（app_setup）に
+/// Interactorを登録する。コントローラの型引数は変わらない。
+pub struct ClosingUseCases {
+    pub consolidate_ledger: UseCaseHandle<ConsolidateLedgerRequest, ConsolidateLedgerResponse>,
+    pub prepare_closing: UseCaseHandle<PrepareClosingRequest, PrepareClosingResponse>,
+    pub lock_closing_period: UseCaseHandle<LockClosingPeriodRequest, LockClosingPeriodResponse>,
+    /// 締日固定の事前検証（固定はしない）
+    pub validate_closing_lock:
+        UseCaseHandle<ValidateClosingLockRequest, ClosingLockValidationReport>,
+    pub generate_trial_balance:
+        UseCaseHandle<GenerateTrialBalanceRequest, GenerateTrialBalanceResponse>,
+    pub generate_note_draft: UseCaseHandle<GenerateNoteDraftRequest, GenerateNoteDraftResponse>,
+    pub adjust_accounts: UseCaseHandle<AdjustAccountsRequest, AdjustAccountsResponse>,
+    pub apply_ifrs_valuation: UseCaseHandle<ApplyIfrsValuationRequest, ApplyIfrsValuationResponse>,
+    pub generate_financial_statements:
+        UseCaseHandle<GenerateFinancialStatementsRequest, GenerateFinancialStatementsResponse>,
 }
 
-impl<Consolidate, Prepare, Lock, TrialBalance, NoteDraft, Adjust, Ifrs, Financial>
-    ClosingController<Consolidate, Prepare, Lock, TrialBalance, NoteDraft, Adjust, Ifrs, Financial>
-where
-    Consolidate: ConsolidateLedgerUseCase,
-    Prepare: PrepareClosingUseCase,
-    Lock: LockClosingPeriodUseCase,
-    TrialBalance: GenerateTrialBalanceUseCase,
-    NoteDraft: GenerateNoteDraftUseCase,
-    Adjust: AdjustAccountsUseCase,
-    Ifrs: ApplyIfrsValuationUseCase,
-    Financial: GenerateFinancialStatementsUseCase,
-{
-    #[allow(clippy::too_many_arguments)]
-    pub fn new(
-        consolidate_ledger: Arc<Consolidate>,
-        prepare_closing: Arc<Prepare>,
-        lock_closing_period: Arc<Lock>,
-        generate_trial_balance: Arc<TrialBalance>,
-        generate_note_draft: Arc<NoteDraft>,
-        adjust_accounts: Arc<Adjust>,
-        apply_ifrs_valuation: Arc<Ifrs>,
-        generate_financial_statements: Arc<Financial>,
-    ) -> Self {
+pub struct ClosingController {
+    use_cases: ClosingUseCases,
+    request_timeout: Duration,
+    notifier: Option<Arc<BatchNotifier>>,
+    /// 業務指標の記録先（締め処理の各ステップの処理時間）
+    metrics: Option<Arc<dyn BusinessMetrics>>,
+    /// 締めスケジュール（正常終了した工程に対応するタスクを完了にする）
+    timetable: Option<ClosingTimetableInteractor<ClosingTimetableRepositoryImpl>>,
+}
+
+impl ClosingController {
+    pub fn new(use_cases: ClosingUseCases) -> Self {
         Self {
-            consolidate_ledger,
-            prepare_closing,
-            lock_closing_period,
-            generate_trial_balance,
-            generate_note_draft,
-            adjust_accounts,
-            apply_ifrs_valuation,
-            generate_financial_statements,
+            use_cases,
+            request_timeout: DEFAULT_REQUEST_TIMEOUT,
+            notifier: None,
+            metrics: None,
+            timetable: None,
+        }
+    }
+
+    /// リクエストタイムアウトを設定
+    pub fn with_request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = timeout;
+        self
+    }
+
+    /// バッチ完了・失敗時の通知先を設定
+    pub fn with_notifier(mut self, notifier: Arc<BatchNotifier>) -> Self {
+        self.notifier = Some(notifier);
+        self
+    }
+
+    /// 業務指標の記録先を設定
+    pub fn with_metrics(mut self, metrics: Arc<dyn BusinessMetrics>) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// 締めスケジュールを設定
+    pub fn with_timetable(mut self, repository: Arc<ClosingTimetableRepositoryImpl>) -> Self {
+        self.timetable = Some(ClosingTimetableInteractor::new(repository));
+        self
+    }
+
+    /// 正常終了した工程を締めスケジュールの進捗に反映
+    ///
+    /// 進捗の記録に失敗しても締め処理の結果は変えず、エラーログに記録する。
+    async fn complete_timetable_step(&self, fiscal_year: i32, period: u8, step: ClosingStep) {
+        if let Some(timetable) = &self.timetable
+            && let Err(e) =
+                timetable.complete_step(fiscal_year, period, step, TIMETABLE_COMPLETED_BY).await
+        {
+            record_error(&e.into());
+        }
+    }
+
+    /// バッチ処理を実行し、完了・失敗を通知
+    ///
+    /// タイムアウトを適用するのは参照系のみ。更新系は完了まで待ち、実際の結果を返す。
+    async fn run_batch<T>(
+        &self,
+        batch_name: &str,
+        kind: RequestKind,
+        future: impl Future<Output = ApplicationResult<T>>,
+    ) -> AdapterResult<T> {
+        let started = Instant::now();
+        let result = match kind {
+            RequestKind::Query => run_with_timeout(self.request_timeout, future)
+                .await
+                .and_then(|result| result.map_err(AdapterError::ApplicationError)),
+            RequestKind::Command => future.await.map_err(AdapterError::ApplicationError),
+        };
+
+        if let Some(notifier) = &self.notifier {
+            let elapsed = started.elapsed();
+            let event = match &result {
+                Ok(_) => BatchEvent::completed(batch_name, elapsed),
+                Err(e) => BatchEvent::failed(batch_name, e.to_string(), elapsed),
+            };
+            notifier.notify(&event);
+        }
+        if result.is_ok() {
+            record_metric(self.metrics.as_ref(), BusinessMetric::ClosingStep, started.elapsed())
+                .await;
         }
+
+        result
     }
 
     /// 元帳集約処理
@@ -90,10 +148,17 @@ where
         &self,
         request: ConsolidateLedgerRequest,
     ) -> AdapterResult<ConsolidateLedgerResponse> {
-        self.consolidate_ledger
-            .execute(request)
-            .await
-            .map_err(crate::error::AdapterError::ApplicationError)
+        let (fiscal_year, period) = (request.fiscal_year, request.period);
+        let response = self
+            .run_batch(
+                "元帳集約",
+                RequestKind::Query,
+                self.use_cases.consolidate_ledger.execute(request),
+            )
+            .await?;
+        self.complete_timetable_step(fiscal_year, period, ClosingStep::LedgerConsolidation)
+            .await;
+        Ok(response)
     }
 
     /// 締準備処理
@@ -101,10 +166,17 @@ where
         &self,
         request: PrepareClosingRequest,
     ) -> AdapterResult<PrepareClosingResponse> {
-        self.prepare_closing
-            .execute(request)
-            .await
-            .map_err(crate::error::AdapterError::ApplicationError)
+        let (fiscal_year, period) = (request.fiscal_year, request.period);
+        let response = self
+            .run_batch(
+                "締準備",
+                RequestKind::Query,
+                self.use_cases.prepare_closing.execute(request),
+            )
+            .await?;
+        self.complete_timetable_step(fiscal_year, period, ClosingStep::ClosingPreparation)
+            .await;
+        Ok(response)
     }
 
     /// 締日固定処理
@@ -112,10 +184,28 @@ where
         &self,
         request: LockClosingPeriodRequest,
     ) -> AdapterResult<LockClosingPeriodResponse> {
-        self.lock_closing_period
+        let (fiscal_year, period) = (request.fiscal_year, request.period);
+        let response = self
+            .run_batch(
+                "締日固定",
+                RequestKind::Command,
+                self.use_cases.lock_closing_period.execute(request),
+            )
+            .await?;
+        self.complete_timetable_step(fiscal_year, period, ClosingStep::PeriodLock).await;
+        Ok(response)
+    }
+
+    /// 締日固定の事前検証（固定はしない）
+    pub async fn validate_closing_lock(
+        &self,
+        request: ValidateClosingLockRequest,
+    ) -> AdapterResult<ClosingLockValidationReport> {
+        self.use_cases
+            .validate_closing_lock
             .execute(request)
             .await
-            .map_err(crate::error::AdapterError::ApplicationError)
+            .map_err(AdapterError::ApplicationError)
     }
 
     /// 試算表生成処理
@@ -123,10 +213,17 @@ where
         &self,
         request: GenerateTrialBalanceRequest,
     ) -> AdapterResult<GenerateTrialBalanceResponse> {
-        self.generate_trial_balance
-            .execute(request)
-            .await
-            .map_err(crate::error::AdapterError::ApplicationError)
+        let (fiscal_year, period) = (request.fiscal_year, request.period);
+        let response = self
+            .run_batch(
+                "試算表生成",
+                RequestKind::Query,
+                self.use_cases.generate_trial_balance.execute(request),
+            )
+            .await?;
+        self.complete_timetable_step(fiscal_year, period, ClosingStep::TrialBalance)
+            .await;
+        Ok(response)
     }
 
     /// 注記草案生成処理
@@ -134,32 +231,58 @@ where
         &self,
         request: GenerateNoteDraftRequest,
     ) -> AdapterResult<GenerateNoteDraftResponse> {
-        self.generate_note_draft
-            .execute(request)
-            .await
-            .map_err(crate::error::AdapterError::ApplicationError)
+        let (fiscal_year, period) = (request.fiscal_year, request.period);
+        let response = self
+            .run_batch(
+                "注記草案生成",
+                RequestKind::Query,
+                self.use_cases.generate_note_draft.execute(request),
+            )
+            .await?;
+        self.complete_timetable_step(fiscal_year, period, ClosingStep::NoteDraft).await;
+        Ok(response)
     }
 
-    /// 勘定補正処理
+    /// 勘定補正処理（試算の場合はタイムテーブルを完了にしない）
     pub async fn adjust_accounts(
         &self,
         request: AdjustAccountsRequest,
     ) -> AdapterResult<AdjustAccountsResponse> {
-        self.adjust_accounts
-            .execute(request)
-            .await
-            .map_err(crate::error::AdapterError::ApplicationError)
+        let (fiscal_year, period, dry_run) = (request.fiscal_year, request.period, request.dry_run);
+        let (batch_name, kind) = if dry_run {
+            ("勘定補正（試算）", RequestKind::Query)
+        } else {
+            ("勘定補正", RequestKind::Command)
+        };
+        let response = self
+            .run_batch(batch_name, kind, self.use_cases.adjust_accounts.execute(request))
+            .await?;
+        if !dry_run {
+            self.complete_timetable_step(fiscal_year, period, ClosingStep::AccountAdjustment)
+                .await;
+        }
+        Ok(response)
     }
 
-    /// IFRS評価処理
+    /// IFRS評価処理（試算の場合はタイムテーブルを完了にしない）
     pub async fn apply_ifrs_valuation(
         &self,
         request: ApplyIfrsValuationRequest,
     ) -> AdapterResult<ApplyIfrsValuationResponse> {
-        self.apply_ifrs_valuation
-            .execute(request)
-            .await
-            .map_err(crate::error::AdapterError::ApplicationError)
+        let (fiscal_year, period, dry_run) = (request.fiscal_year, request.period, request.dry_run);
+        let (batch_name, kind) = if dry_run {
+            ("IFRS評価（試算）", RequestKind::Query)
+        } else {
+            ("IFRS評価", RequestKind::Command)
+        };
+        let response = self
+            .run_batch(batch_name, kind, self.use_cases.apply_ifrs_valuation.execute(request))
+            .await?;
+        if !dry_run {
+            self.complete_timetable_step(fiscal_year, period, ClosingStep::IfrsValuation)
+                .await;
+        }
+        Ok(response)
     }
 
     /// 財務諸表生成処理
@@ -167,9 +290,16 @@ where
         &self,
         request: GenerateFinancialStatementsRequest,
     ) -> AdapterResult<GenerateFinancialStatementsResponse> {
-        self.generate_financial_statements
-            .execute(request)
-            .await
-            .map_err(crate::error::AdapterError::ApplicationError)
+        let (fiscal_year, period) = (request.fiscal_year, request.period);
+        let response = self
+            .run_batch(
+                "財務諸表生成",
+                RequestKind::Command,
+                self.use_cases.generate_financial_statements.execute(request),
+            )
+            .await?;
+        self.complete_timetable_step(fiscal_year, period, ClosingStep::FinancialStatements)
+            .await;
+        Ok(response)
     }
 }
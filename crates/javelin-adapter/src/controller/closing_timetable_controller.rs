@@ -0,0 +1,57 @@
+// ClosingTimetableController - 締めスケジュールコントローラ
+
+use std::sync::Arc;
+
+use javelin_application::{
+    dtos::{
+        request::{
+            DeleteClosingTimetableItemRequest, LoadClosingTimetableBoardRequest,
+            SaveClosingTimetableItemRequest, UpdateClosingTaskStatusRequest,
+        },
+        response::ClosingTimetableBoardResponse,
+    },
+    interactor::ClosingTimetableInteractor,
+};
+use javelin_infrastructure::repositories::ClosingTimetableRepositoryImpl;
+
+use crate::error_log::to_user_message;
+
+/// 締めスケジュールコントローラ
+pub struct ClosingTimetableController {
+    interactor: ClosingTimetableInteractor<ClosingTimetableRepositoryImpl>,
+}
+
+impl ClosingTimetableController {
+    pub fn new(repository: Arc<ClosingTimetableRepositoryImpl>) -> Self {
+        Self { interactor: ClosingTimetableInteractor::new(repository) }
+    }
+
+    /// 期間の進捗ボードを取得
+    pub async fn load_board(
+        &self,
+        request: LoadClosingTimetableBoardRequest,
+    ) -> Result<ClosingTimetableBoardResponse, String> {
+        self.interactor.load_board(request).await.map_err(to_user_message)
+    }
+
+    /// 締めタスクを保存
+    pub async fn save_item(&self, request: SaveClosingTimetableItemRequest) -> Result<(), String> {
+        self.interactor.save_item(request).await.map_err(to_user_message)
+    }
+
+    /// 締めタスクを削除
+    pub async fn delete_item(
+        &self,
+        request: DeleteClosingTimetableItemRequest,
+    ) -> Result<(), String> {
+        self.interactor.delete_item(request).await.map_err(to_user_message)
+    }
+
+    /// 締めタスクの進捗を更新
+    pub async fn update_status(
+        &self,
+        request: UpdateClosingTaskStatusRequest,
+    ) -> Result<(), String> {
+        self.interactor.update_status(request).await.map_err(to_user_message)
+    }
+}
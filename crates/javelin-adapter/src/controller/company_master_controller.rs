@@ -3,17 +3,28 @@
 use std::sync::Arc;
 
 use javelin_application::{
-    dtos::{request::LoadCompanyMasterRequest, response::LoadCompanyMasterResponse},
+    dtos::{
+        request::{LoadCompanyMasterRequest, RevertMasterChangeRequest},
+        response::{LoadCompanyMasterResponse, MasterChangeDto},
+    },
     input_ports::LoadCompanyMasterInputPort,
-    interactor::master_data::LoadCompanyMasterInteractor,
+    interactor::{
+        CompanyMasterInteractor, UpdateCompanyMasterRequest,
+        master_data::LoadCompanyMasterInteractor,
+    },
+};
+use javelin_infrastructure::{
+    queries::master_data_loader_impl::MasterDataLoaderImpl,
+    repositories::CompanyMasterRepositoryImpl,
 };
-use javelin_infrastructure::queries::master_data_loader_impl::MasterDataLoaderImpl;
 
-use crate::navigation::PresenterRegistry;
+use crate::{error_log::to_user_message, navigation::PresenterRegistry};
 
 /// 会社マスタコントローラ
 pub struct CompanyMasterController {
     query_service: Arc<MasterDataLoaderImpl>,
+    /// 変更・変更履歴・取消し（変更イベントとして記録する）
+    interactor: CompanyMasterInteractor<CompanyMasterRepositoryImpl>,
     presenter_registry: Arc<PresenterRegistry>,
 }
 
@@ -22,7 +33,8 @@ impl CompanyMasterController {
         query_service: Arc<MasterDataLoaderImpl>,
         presenter_registry: Arc<PresenterRegistry>,
     ) -> Self {
-        Self { query_service, presenter_registry }
+        let interactor = CompanyMasterInteractor::new(query_service.company_repository());
+        Self { query_service, interactor, presenter_registry }
     }
 
     /// PresenterRegistryへの参照を取得
@@ -50,9 +62,41 @@ impl CompanyMasterController {
             );
 
             // 実行
-            interactor.execute(request).await.map_err(|e| e.to_string())
+            interactor.execute(request).await.map_err(to_user_message)
         } else {
             Err(format!("CompanyMasterPresenter not found for page_id: {}", page_id))
         }
     }
+
+    /// 会社の名称・有効状態を変更
+    pub async fn update_company(
+        &self,
+        code: String,
+        name: String,
+        is_active: bool,
+        changed_by: String,
+    ) -> Result<(), String> {
+        self.interactor
+            .update(UpdateCompanyMasterRequest { code, name, is_active, changed_by })
+            .await
+            .map_err(to_user_message)
+    }
+
+    /// 会社の変更履歴（新しい順）
+    pub async fn history(&self, code: String) -> Result<Vec<MasterChangeDto>, String> {
+        self.interactor.history(code).await.map_err(to_user_message)
+    }
+
+    /// 会社の変更を取り消す
+    pub async fn revert_change(
+        &self,
+        code: String,
+        sequence: u64,
+        changed_by: String,
+    ) -> Result<(), String> {
+        self.interactor
+            .revert(RevertMasterChangeRequest { code, sequence, changed_by })
+            .await
+            .map_err(to_user_message)
+    }
 }
@@ -0,0 +1,26 @@
+// ConsistencyCheckController - Projection間整合性チェックコントローラ
+
+use std::sync::Arc;
+
+use javelin_application::{
+    dtos::response::ConsistencyCheckResponse, interactor::ConsistencyCheckInteractor,
+};
+use javelin_infrastructure::queries::ProjectionConsistencyQueryServiceImpl;
+
+use crate::error_log::to_user_message;
+
+/// Projection間整合性チェックコントローラ
+pub struct ConsistencyCheckController {
+    interactor: ConsistencyCheckInteractor<ProjectionConsistencyQueryServiceImpl>,
+}
+
+impl ConsistencyCheckController {
+    pub fn new(query_service: Arc<ProjectionConsistencyQueryServiceImpl>) -> Self {
+        Self { interactor: ConsistencyCheckInteractor::new(query_service) }
+    }
+
+    /// 整合性チェックを実行
+    pub async fn run_check(&self) -> Result<ConsistencyCheckResponse, String> {
+        self.interactor.execute().await.map_err(to_user_message)
+    }
+}
@@ -0,0 +1,40 @@
+// DimensionMasterController - 分析軸マスタコントローラ
+
+use std::sync::Arc;
+
+use javelin_application::{
+    dtos::{
+        request::{DeleteDimensionMasterRequest, SaveDimensionMasterRequest},
+        response::LoadDimensionMastersResponse,
+    },
+    interactor::DimensionMasterInteractor,
+};
+use javelin_infrastructure::repositories::DimensionMasterRepositoryImpl;
+
+use crate::error_log::to_user_message;
+
+/// 分析軸マスタコントローラ
+pub struct DimensionMasterController {
+    interactor: DimensionMasterInteractor<DimensionMasterRepositoryImpl>,
+}
+
+impl DimensionMasterController {
+    pub fn new(repository: Arc<DimensionMasterRepositoryImpl>) -> Self {
+        Self { interactor: DimensionMasterInteractor::new(repository) }
+    }
+
+    /// 分析軸の一覧を取得
+    pub async fn load_masters(&self) -> Result<LoadDimensionMastersResponse, String> {
+        self.interactor.load().await.map_err(to_user_message)
+    }
+
+    /// 分析軸を保存
+    pub async fn save_master(&self, request: SaveDimensionMasterRequest) -> Result<(), String> {
+        self.interactor.save(request).await.map_err(to_user_message)
+    }
+
+    /// 分析軸を削除
+    pub async fn delete_master(&self, request: DeleteDimensionMasterRequest) -> Result<(), String> {
+        self.interactor.delete(request).await.map_err(to_user_message)
+    }
+}
@@ -0,0 +1,51 @@
+// EntryLinkController - 仕訳間の紐付けコントローラ
+
+use std::sync::Arc;
+
+use javelin_application::{
+    dtos::{
+        request::{LinkEntriesRequest, UnlinkEntriesRequest, UnsettledLinksRequest},
+        response::{EntryLinksResponse, UnsettledLinksResponse},
+    },
+    interactor::EntryLinkInteractor,
+};
+use javelin_infrastructure::event_store::EventStore;
+
+use crate::error_log::to_user_message;
+
+/// 仕訳間の紐付けコントローラ
+pub struct EntryLinkController {
+    interactor: EntryLinkInteractor<EventStore>,
+}
+
+impl EntryLinkController {
+    pub fn new(event_store: Arc<EventStore>) -> Self {
+        Self { interactor: EntryLinkInteractor::new(event_store) }
+    }
+
+    /// 仕訳の紐付け一覧を取得
+    pub async fn load_links(&self, entry_id: &str) -> Result<EntryLinksResponse, String> {
+        self.interactor.links(entry_id).await.map_err(to_user_message)
+    }
+
+    /// 計上・前払の仕訳と決済・償却の仕訳を紐付ける
+    pub async fn link(&self, request: LinkEntriesRequest) -> Result<EntryLinksResponse, String> {
+        self.interactor.link(request).await.map_err(to_user_message)
+    }
+
+    /// 紐付けを解除する
+    pub async fn unlink(
+        &self,
+        request: UnlinkEntriesRequest,
+    ) -> Result<EntryLinksResponse, String> {
+        self.interactor.unlink(request).await.map_err(to_user_message)
+    }
+
+    /// 期末時点で未決済の計上・前払を取得
+    pub async fn unsettled(
+        &self,
+        request: UnsettledLinksRequest,
+    ) -> Result<UnsettledLinksResponse, String> {
+        self.interactor.unsettled(request).await.map_err(to_user_message)
+    }
+}
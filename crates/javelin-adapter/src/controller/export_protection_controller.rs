@@ -0,0 +1,87 @@
+// ExportProtectionController - 出力ファイル保護コントローラ
+// 責務: 設定に従った出力ファイルの暗号化・署名と書き込み、分離署名の検証
+
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use javelin_application::{
+    dtos::{
+        request::{ProtectExportRequest, VerifyExportSignatureRequest},
+        response::ExportSignatureVerificationDto,
+    },
+    interactor::ExportProtectionInteractor,
+};
+use javelin_domain::masters::ExportProtectionSettings;
+use javelin_infrastructure::{
+    repositories::ApplicationSettingsRepositoryImpl, services::ExportProtectionImpl,
+};
+
+use crate::error_log::to_user_message;
+
+/// 出力ファイル保護コントローラ
+///
+/// 画面からの出力と、コマンドラインからの署名検証の両方で利用する。
+pub struct ExportProtectionController {
+    interactor: ExportProtectionInteractor<ApplicationSettingsRepositoryImpl, ExportProtectionImpl>,
+}
+
+impl ExportProtectionController {
+    pub fn new(
+        settings_repository: Arc<ApplicationSettingsRepositoryImpl>,
+        protection: Arc<ExportProtectionImpl>,
+    ) -> Self {
+        Self { interactor: ExportProtectionInteractor::new(settings_repository, protection) }
+    }
+
+    /// 現在の出力ファイル保護設定
+    pub async fn settings(&self) -> Result<ExportProtectionSettings, String> {
+        self.interactor.settings().await.map_err(to_user_message)
+    }
+
+    /// 出力内容を設定に従って保護し、`directory` に書き込む
+    ///
+    /// 既存のファイルは上書きする。書き込んだファイルのパスを返す。
+    pub async fn write(
+        &self,
+        directory: &Path,
+        file_name: String,
+        content: Vec<u8>,
+        password: Option<String>,
+    ) -> Result<Vec<PathBuf>, String> {
+        let response = self
+            .interactor
+            .protect(ProtectExportRequest { file_name, content, password })
+            .await
+            .map_err(to_user_message)?;
+
+        let mut paths = Vec::new();
+        for file in response.files {
+            let path = directory.join(&file.file_name);
+            tokio::fs::write(&path, &file.content)
+                .await
+                .map_err(|e| format!("出力に失敗しました: {} ({})", path.display(), e))?;
+            paths.push(path);
+        }
+        Ok(paths)
+    }
+
+    /// 出力ファイルを分離署名ファイルで検証
+    pub async fn verify(
+        &self,
+        file: &Path,
+        signature: &Path,
+    ) -> Result<ExportSignatureVerificationDto, String> {
+        let content = tokio::fs::read(file)
+            .await
+            .map_err(|e| format!("ファイルを読み込めません: {} ({})", file.display(), e))?;
+        let signature = tokio::fs::read_to_string(signature).await.map_err(|e| {
+            format!("署名ファイルを読み込めません: {} ({})", signature.display(), e)
+        })?;
+
+        self.interactor
+            .verify(VerifyExportSignatureRequest { content, signature })
+            .map_err(to_user_message)
+    }
+}
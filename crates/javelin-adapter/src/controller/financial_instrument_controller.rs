@@ -0,0 +1,114 @@
+// FinancialInstrumentController - 償却原価で測定する金融商品コントローラ
+
+use std::{path::Path, sync::Arc};
+
+use javelin_application::{
+    dtos::{request::RegisterFinancialInstrumentRequest, response::FinancialInstrumentResponse},
+    interactor::FinancialInstrumentInteractor,
+};
+use javelin_infrastructure::repositories::FinancialInstrumentRepositoryImpl;
+
+use super::inventory_worksheet_controller::split_csv_line;
+use crate::error_log::to_user_message;
+
+/// 取込ファイルの列数
+/// （金融商品ID,名称,種類,保有区分,勘定科目,通貨,額面,帳簿価額,表面利率(%),実効金利(%),取得日,
+/// 満期日）
+const IMPORT_COLUMN_COUNT: usize = 12;
+
+/// 金融商品コントローラ
+pub struct FinancialInstrumentController {
+    interactor: FinancialInstrumentInteractor<FinancialInstrumentRepositoryImpl>,
+}
+
+impl FinancialInstrumentController {
+    pub fn new(instrument_repository: Arc<FinancialInstrumentRepositoryImpl>) -> Self {
+        Self { interactor: FinancialInstrumentInteractor::new(instrument_repository) }
+    }
+
+    /// 金融商品（CSV、1行目は見出し）を取り込み、取り込んだ件数を返す
+    pub async fn import_csv(&self, path: &Path) -> Result<usize, String> {
+        let content = tokio::fs::read_to_string(path)
+            .await
+            .map_err(|e| format!("{} を読み込めません: {}", path.display(), e))?;
+        let requests = parse_instruments(&content)?;
+        let count = requests.len();
+
+        for request in requests {
+            let instrument_id = request.instrument_id.clone();
+            self.interactor
+                .register(request)
+                .await
+                .map_err(|e| format!("{}: {}", instrument_id, to_user_message(e)))?;
+        }
+        Ok(count)
+    }
+
+    /// 登録済みの金融商品を償却原価スケジュールとともに取得
+    pub async fn list(&self) -> Result<Vec<FinancialInstrumentResponse>, String> {
+        self.interactor.list().await.map_err(to_user_message)
+    }
+}
+
+/// CSVを金融商品登録リクエストに変換（利率は%で指定）
+fn parse_instruments(content: &str) -> Result<Vec<RegisterFinancialInstrumentRequest>, String> {
+    let mut requests = Vec::new();
+    for (index, line) in content.lines().enumerate().skip(1) {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let row_number = index + 1;
+        let fields = split_csv_line(line);
+        if fields.len() != IMPORT_COLUMN_COUNT {
+            return Err(format!(
+                "{}行目: 列数が不正です（{}列、期待値 {}列）",
+                row_number,
+                fields.len(),
+                IMPORT_COLUMN_COUNT
+            ));
+        }
+        let number = |column: usize, label: &str| {
+            fields[column].replace(',', "").parse::<f64>().map_err(|_| {
+                format!("{}行目: {}が数値ではありません: {}", row_number, label, fields[column])
+            })
+        };
+
+        requests.push(RegisterFinancialInstrumentRequest {
+            instrument_id: fields[0].clone(),
+            name: fields[1].clone(),
+            kind: fields[2].clone(),
+            position: fields[3].clone(),
+            account_code: fields[4].clone(),
+            currency: fields[5].clone(),
+            face_value: number(6, "額面")?,
+            initial_carrying_amount: number(7, "帳簿価額")?,
+            coupon_rate: number(8, "表面利率")? / 100.0,
+            effective_rate: number(9, "実効金利")? / 100.0,
+            start_date: fields[10].clone(),
+            maturity_date: fields[11].clone(),
+        });
+    }
+    Ok(requests)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_instruments() {
+        let content = "金融商品ID,名称,種類,保有区分,勘定科目,通貨,額面,帳簿価額,表面利率(%),実効金利(%),取得日,満期日\n\
+                       BOND-001,第1回社債,Bond,Asset,1500,JPY,\"1,000,000\",980000,1.0,3.05,2024-11-01,2025-11-01\n\
+                       \n\
+                       LOAN-001,長期借入金,Loan,Liability,2500,JPY,5000000,5000000,2,二,2024-04-01,2029-04-01\n";
+
+        let error = parse_instruments(content).unwrap_err();
+        assert_eq!(error, "4行目: 実効金利が数値ではありません: 二");
+
+        let requests = parse_instruments(&content.replace(",二,", ",2,")).unwrap();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].face_value, 1_000_000.0);
+        assert_eq!(requests[0].effective_rate, 0.0305);
+        assert_eq!(requests[1].position, "Liability");
+    }
+}
@@ -0,0 +1,53 @@
+// InboxController - 受信箱コントローラ
+
+use std::sync::Arc;
+
+use javelin_application::{
+    interactor::{ApplicationSettingsInteractor, GetApplicationSettingsQuery},
+    query_service::{InboxItem, InboxQueryService},
+};
+use javelin_domain::masters::ApprovalSlaSettings;
+use javelin_infrastructure::{
+    queries::InboxQueryServiceImpl, repositories::ApplicationSettingsRepositoryImpl,
+};
+
+use crate::{error_log::to_user_message, navigation::Session};
+
+/// 受信箱コントローラ
+///
+/// ログイン中の利用者の受信箱と、承認待ちの滞留を判定する承認SLAを照会する。
+pub struct InboxController {
+    query_service: Arc<InboxQueryServiceImpl>,
+    session: Arc<Session>,
+    settings_interactor: ApplicationSettingsInteractor<ApplicationSettingsRepositoryImpl>,
+}
+
+impl InboxController {
+    pub fn new(
+        query_service: Arc<InboxQueryServiceImpl>,
+        session: Arc<Session>,
+        settings_repository: Arc<ApplicationSettingsRepositoryImpl>,
+    ) -> Self {
+        let settings_interactor = ApplicationSettingsInteractor::new(settings_repository);
+        Self { query_service, session, settings_interactor }
+    }
+
+    /// 受信箱の利用者ID
+    pub fn user_id(&self) -> String {
+        self.session.user_id()
+    }
+
+    /// 受信箱を取得（新しい順）
+    pub async fn load_inbox(&self) -> Result<Vec<InboxItem>, String> {
+        self.query_service.get_inbox(&self.user_id()).await.map_err(to_user_message)
+    }
+
+    /// 承認SLA設定を取得
+    pub async fn load_approval_sla(&self) -> Result<ApprovalSlaSettings, String> {
+        self.settings_interactor
+            .get(GetApplicationSettingsQuery)
+            .await
+            .map(|settings| settings.approval_sla())
+            .map_err(to_user_message)
+    }
+}
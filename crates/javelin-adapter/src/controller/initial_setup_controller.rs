@@ -0,0 +1,69 @@
+// InitialSetupController - 初期セットアップコントローラ
+
+use std::sync::Arc;
+
+use javelin_application::{
+    dtos::{
+        request::InitialSetupRequest,
+        response::{InitialSetupOptions, InitialSetupResponse},
+    },
+    interactor::InitialSetupInteractor,
+};
+use javelin_infrastructure::{
+    repositories::{
+        AccountMasterRepositoryImpl, AccountingPolicyRepositoryImpl,
+        ApplicationSettingsRepositoryImpl, CompanyMasterRepositoryImpl, UserAccountRepositoryImpl,
+    },
+    services::PasswordHasherImpl,
+};
+
+use crate::error_log::to_user_message;
+
+type Interactor = InitialSetupInteractor<
+    UserAccountRepositoryImpl,
+    PasswordHasherImpl,
+    CompanyMasterRepositoryImpl,
+    AccountMasterRepositoryImpl,
+    ApplicationSettingsRepositoryImpl,
+    AccountingPolicyRepositoryImpl,
+>;
+
+/// 初期セットアップコントローラ
+pub struct InitialSetupController {
+    interactor: Interactor,
+}
+
+impl InitialSetupController {
+    pub fn new(
+        users: Arc<UserAccountRepositoryImpl>,
+        hasher: Arc<PasswordHasherImpl>,
+        companies: Arc<CompanyMasterRepositoryImpl>,
+        accounts: Arc<AccountMasterRepositoryImpl>,
+        settings: Arc<ApplicationSettingsRepositoryImpl>,
+        policy: Arc<AccountingPolicyRepositoryImpl>,
+    ) -> Self {
+        Self {
+            interactor: InitialSetupInteractor::new(
+                users, hasher, companies, accounts, settings, policy,
+            ),
+        }
+    }
+
+    /// 初期セットアップが必要か（判定できない場合は通常のログインへ進む）
+    pub async fn is_required(&self) -> bool {
+        self.interactor.is_required().await.unwrap_or(false)
+    }
+
+    /// 基準通貨と勘定科目テンプレートの選択肢
+    pub fn options(&self) -> InitialSetupOptions {
+        self.interactor.options()
+    }
+
+    /// 初期セットアップを実行
+    pub async fn execute(
+        &self,
+        request: InitialSetupRequest,
+    ) -> Result<InitialSetupResponse, String> {
+        self.interactor.execute(request).await.map_err(to_user_message)
+    }
+}
@@ -0,0 +1,69 @@
+// IntegrityAuditController - 整合性監査コントローラ
+
+use std::sync::Arc;
+
+use javelin_application::{
+    dtos::{request::RunIntegrityAuditRequest, response::IntegrityAuditResponse},
+    interactor::IntegrityAuditInteractor,
+};
+use javelin_domain::integrity_audit::AuditTrigger;
+use javelin_infrastructure::{
+    event_store::EventStore,
+    queries::{
+        EventChecksumQueryServiceImpl, ProjectionConsistencyQueryServiceImpl,
+        SequenceAuditQueryServiceImpl,
+    },
+};
+
+use crate::error_log::to_user_message;
+
+/// 整合性監査コントローラ
+pub struct IntegrityAuditController {
+    interactor: IntegrityAuditInteractor<
+        ProjectionConsistencyQueryServiceImpl,
+        SequenceAuditQueryServiceImpl,
+        EventChecksumQueryServiceImpl,
+        EventStore,
+    >,
+}
+
+impl IntegrityAuditController {
+    pub fn new(
+        consistency_query_service: Arc<ProjectionConsistencyQueryServiceImpl>,
+        sequence_query_service: Arc<SequenceAuditQueryServiceImpl>,
+        event_store: Arc<EventStore>,
+    ) -> Self {
+        let checksum_query_service =
+            Arc::new(EventChecksumQueryServiceImpl::new(Arc::clone(&event_store)));
+        Self {
+            interactor: IntegrityAuditInteractor::new(
+                consistency_query_service,
+                sequence_query_service,
+                checksum_query_service,
+                event_store,
+            ),
+        }
+    }
+
+    /// 監査を実行し、結果を記録
+    pub async fn run(
+        &self,
+        trigger: AuditTrigger,
+        requested_by: String,
+    ) -> Result<IntegrityAuditResponse, String> {
+        self.interactor
+            .run(RunIntegrityAuditRequest { trigger: trigger.as_str().to_string(), requested_by })
+            .await
+            .map_err(to_user_message)
+    }
+
+    /// 最新の監査結果（未実施の場合はNone）
+    pub async fn latest(&self) -> Result<Option<IntegrityAuditResponse>, String> {
+        self.interactor.latest().await.map_err(to_user_message)
+    }
+
+    /// 定期監査が必要か
+    pub async fn is_due(&self) -> Result<bool, String> {
+        self.interactor.is_due().await.map_err(to_user_message)
+    }
+}
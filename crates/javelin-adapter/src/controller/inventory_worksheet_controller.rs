@@ -0,0 +1,154 @@
+// InventoryWorksheetController - 棚卸資産評価ワークシートコントローラ
+
+use std::{path::Path, sync::Arc};
+
+use javelin_application::{
+    dtos::{
+        request::{
+            ImportInventoryWorksheetRequest, InventoryValuationItemDto,
+            LoadInventoryWorksheetRequest,
+        },
+        response::InventoryWorksheetResponse,
+    },
+    interactor::InventoryWorksheetInteractor,
+};
+use javelin_infrastructure::repositories::InventoryWorksheetRepositoryImpl;
+
+use crate::error_log::to_user_message;
+
+/// 取込ファイルの列数（品目コード,品目名,品目グループ,数量,単価,正味実現可能価額単価）
+const IMPORT_COLUMN_COUNT: usize = 6;
+
+/// 棚卸資産評価ワークシートコントローラ
+pub struct InventoryWorksheetController {
+    interactor: InventoryWorksheetInteractor<InventoryWorksheetRepositoryImpl>,
+}
+
+impl InventoryWorksheetController {
+    pub fn new(worksheet_repository: Arc<InventoryWorksheetRepositoryImpl>) -> Self {
+        Self { interactor: InventoryWorksheetInteractor::new(worksheet_repository) }
+    }
+
+    /// 品目別の評価データ（CSV、1行目は見出し）を取り込む
+    pub async fn import_csv(
+        &self,
+        fiscal_year: i32,
+        period: u8,
+        method: String,
+        currency: String,
+        path: &Path,
+    ) -> Result<InventoryWorksheetResponse, String> {
+        let content = tokio::fs::read_to_string(path)
+            .await
+            .map_err(|e| format!("{} を読み込めません: {}", path.display(), e))?;
+        let items = parse_items(&content)?;
+
+        self.interactor
+            .import(ImportInventoryWorksheetRequest {
+                fiscal_year,
+                period,
+                method,
+                currency,
+                items,
+            })
+            .await
+            .map_err(to_user_message)
+    }
+
+    /// 会計期間のワークシートを取得
+    pub async fn load(
+        &self,
+        fiscal_year: i32,
+        period: u8,
+    ) -> Result<Option<InventoryWorksheetResponse>, String> {
+        self.interactor
+            .load(LoadInventoryWorksheetRequest { fiscal_year, period })
+            .await
+            .map_err(to_user_message)
+    }
+}
+
+/// CSVを品目別の評価データに変換
+fn parse_items(content: &str) -> Result<Vec<InventoryValuationItemDto>, String> {
+    let mut items = Vec::new();
+    for (index, line) in content.lines().enumerate().skip(1) {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let row_number = index + 1;
+        let fields = split_csv_line(line);
+        if fields.len() != IMPORT_COLUMN_COUNT {
+            return Err(format!(
+                "{}行目: 列数が不正です（{}列、期待値 {}列）",
+                row_number,
+                fields.len(),
+                IMPORT_COLUMN_COUNT
+            ));
+        }
+        let number = |column: usize, label: &str| {
+            fields[column].replace(',', "").parse::<f64>().map_err(|_| {
+                format!("{}行目: {}が数値ではありません: {}", row_number, label, fields[column])
+            })
+        };
+
+        items.push(InventoryValuationItemDto {
+            item_code: fields[0].clone(),
+            item_name: fields[1].clone(),
+            product_group: fields[2].clone(),
+            quantity: number(3, "数量")?,
+            unit_cost: number(4, "単価")?,
+            unit_net_realizable_value: number(5, "正味実現可能価額単価")?,
+        });
+    }
+    Ok(items)
+}
+
+/// CSVの1行を列に分割（ダブルクォートで囲まれた列に対応）
+pub(crate) fn split_csv_line(line: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = line.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if in_quotes && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => in_quotes = !in_quotes,
+            ',' if !in_quotes => fields.push(std::mem::take(&mut field).trim().to_string()),
+            _ => field.push(c),
+        }
+    }
+    fields.push(field.trim().to_string());
+    fields
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_items() {
+        let content = "品目コード,品目名,品目グループ,数量,単価,正味実現可能価額単価\n\
+                       A-001,\"商品A, 大\",G1,10,\"1,200\",900\n\
+                       \n\
+                       B-001,商品B,G2,5,300,350\n";
+
+        let items = parse_items(content).unwrap();
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].item_name, "商品A, 大");
+        assert_eq!(items[0].unit_cost, 1200.0);
+        assert_eq!(items[1].unit_net_realizable_value, 350.0);
+    }
+
+    #[test]
+    fn test_parse_items_reports_row_number() {
+        let content = "見出し\nA-001,商品A,G1,十,100,80\n";
+        assert_eq!(parse_items(content).unwrap_err(), "2行目: 数量が数値ではありません: 十");
+
+        let content = "見出し\nA-001,商品A,G1\n";
+        assert!(parse_items(content).unwrap_err().starts_with("2行目: 列数が不正です"));
+    }
+}
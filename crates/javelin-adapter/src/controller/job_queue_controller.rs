@@ -0,0 +1,420 @@
+// JobQueueController - 長時間処理ジョブキューコントローラ
+
+use std::{path::Path, sync::Arc, time::Duration};
+
+use chrono::NaiveDate;
+use javelin_application::{
+    dtos::{
+        request::{CancelJobRequest, ConsolidateLedgerRequest, EnqueueJobRequest, RetryJobRequest},
+        response::{JobResponse, RecoverJobsResponse},
+    },
+    error::{ApplicationError, ApplicationResult},
+    interactor::JobQueueInteractor,
+    job_runner::{JobProgress, JobRunner},
+    projection_builder::ProjectionBuilder,
+    projection_compactor::ProjectionCompactor,
+};
+use javelin_domain::{integrity_audit::AuditTrigger, job::JobKind};
+use javelin_infrastructure::repositories::JobRepositoryImpl;
+
+use crate::{
+    controller::{IntegrityAuditController, InventoryWorksheetController},
+    error_log::to_user_message,
+    navigation::controllers::ClosingControllerType,
+};
+
+/// ジョブの終了を待つ間の状態確認間隔
+const WATCH_INTERVAL: Duration = Duration::from_millis(500);
+
+/// 各コントローラを呼び出してジョブを実行する
+pub struct ControllerJobRunner {
+    closing: Arc<ClosingControllerType>,
+    inventory_worksheet: Arc<InventoryWorksheetController>,
+    projection_builder: Arc<dyn ProjectionBuilder>,
+    projection_compactor: Arc<dyn ProjectionCompactor>,
+    integrity_audit: Arc<IntegrityAuditController>,
+}
+
+impl ControllerJobRunner {
+    pub fn new(
+        closing: Arc<ClosingControllerType>,
+        inventory_worksheet: Arc<InventoryWorksheetController>,
+        projection_builder: Arc<dyn ProjectionBuilder>,
+        projection_compactor: Arc<dyn ProjectionCompactor>,
+        integrity_audit: Arc<IntegrityAuditController>,
+    ) -> Self {
+        Self {
+            closing,
+            inventory_worksheet,
+            projection_builder,
+            projection_compactor,
+            integrity_audit,
+        }
+    }
+}
+
+impl JobRunner for ControllerJobRunner {
+    async fn run(
+        &self,
+        kind: JobKind,
+        parameters: Vec<(String, String)>,
+        progress: JobProgress,
+    ) -> ApplicationResult<String> {
+        let parameters = JobParameters(parameters);
+
+        match kind {
+            JobKind::LedgerConsolidation => {
+                progress.report(10, "仕訳データを集約しています");
+                let response = self
+                    .closing
+                    .consolidate_ledger(ConsolidateLedgerRequest {
+                        fiscal_year: parameters.number("fiscal_year")?,
+                        period: parameters.number("period")?,
+                        from_date: parameters.text("from_date")?.to_string(),
+                        to_date: parameters.text("to_date")?.to_string(),
+                    })
+                    .await
+                    .map_err(|e| ApplicationError::UseCaseExecutionFailed(to_user_message(e)))?;
+                Ok(format!(
+                    "{}件の仕訳を集約し、{}勘定を更新しました（差異{}件）",
+                    response.processed_entries_count,
+                    response.updated_accounts_count,
+                    response.discrepancies.len()
+                ))
+            }
+            JobKind::ProjectionRebuild => {
+                progress.report(10, "全イベントを再生しています");
+                self.projection_builder.rebuild_all_projections().await?;
+                Ok("Projectionを再構築しました".to_string())
+            }
+            JobKind::ProjectionCompaction => {
+                progress.report(10, "削除済み仕訳のキーを除去しています");
+                let report = self.projection_compactor.compact().await?;
+                let retained = if report.retained_deleted > 0 {
+                    format!("保持期間内の削除済み仕訳{}件は残しました。", report.retained_deleted)
+                } else {
+                    String::new()
+                };
+                Ok(format!(
+                    "{}件のキーを除去しました（{} → {}、{}削減）。{}圧縮後の環境は次回起動時に反映されます",
+                    report.swept_keys,
+                    format_bytes(report.size_before),
+                    format_bytes(report.size_after),
+                    format_bytes(report.reclaimed_bytes()),
+                    retained
+                ))
+            }
+            JobKind::IntegrityAudit => {
+                progress.report(10, "チェックサム・整合性・番号連続性を検査しています");
+                let trigger = AuditTrigger::parse(parameters.text("trigger")?)?;
+                let response = self
+                    .integrity_audit
+                    .run(trigger, parameters.text("requested_by")?.to_string())
+                    .await
+                    .map_err(ApplicationError::UseCaseExecutionFailed)?;
+                if response.passed {
+                    Ok(format!("整合性監査に合格しました（{}）", response.audit_id))
+                } else {
+                    Ok(format!(
+                        "整合性監査で検出事項が{}件ありました（{}）",
+                        response.finding_count, response.audit_id
+                    ))
+                }
+            }
+            JobKind::InventoryImport => {
+                let path = parameters.text("path")?;
+                progress.report(10, format!("{} を取り込んでいます", path));
+                let worksheet = self
+                    .inventory_worksheet
+                    .import_csv(
+                        parameters.number("fiscal_year")?,
+                        parameters.number("period")?,
+                        parameters.text("method")?.to_string(),
+                        parameters.text("currency")?.to_string(),
+                        Path::new(path),
+                    )
+                    .await
+                    .map_err(ApplicationError::UseCaseExecutionFailed)?;
+                Ok(format!("{} を取り込みました（{}品目）", path, worksheet.item_count))
+            }
+        }
+    }
+}
+
+/// バイト数を表示用の単位に変換
+fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} B", bytes)
+    } else {
+        format!("{:.1} {}", value, UNITS[unit])
+    }
+}
+
+/// ジョブの実行パラメータ
+struct JobParameters(Vec<(String, String)>);
+
+impl JobParameters {
+    fn text(&self, key: &str) -> ApplicationResult<&str> {
+        self.0
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, value)| value.as_str())
+            .ok_or_else(|| {
+                ApplicationError::ValidationError(format!(
+                    "ジョブのパラメータがありません: {}",
+                    key
+                ))
+            })
+    }
+
+    fn number<T: std::str::FromStr>(&self, key: &str) -> ApplicationResult<T> {
+        let value = self.text(key)?;
+        value.parse().map_err(|_| {
+            ApplicationError::ValidationError(format!(
+                "ジョブのパラメータが不正です: {}={}",
+                key, value
+            ))
+        })
+    }
+}
+
+/// 長時間処理ジョブキューコントローラ
+pub struct JobQueueController {
+    interactor: Arc<JobQueueInteractor<JobRepositoryImpl, ControllerJobRunner>>,
+}
+
+impl JobQueueController {
+    pub fn new(job_repository: Arc<JobRepositoryImpl>, runner: Arc<ControllerJobRunner>) -> Self {
+        Self { interactor: Arc::new(JobQueueInteractor::new(job_repository, runner)) }
+    }
+
+    /// 会計期間（月）の元帳集約を登録
+    pub async fn enqueue_consolidation(
+        &self,
+        fiscal_year: i32,
+        period: u8,
+        requested_by: String,
+    ) -> Result<JobResponse, String> {
+        let from_date = NaiveDate::from_ymd_opt(fiscal_year, period as u32, 1)
+            .ok_or_else(|| format!("会計期間が不正です: {}年{}月", fiscal_year, period))?;
+        let to_date = from_date
+            .checked_add_months(chrono::Months::new(1))
+            .and_then(|next| next.pred_opt())
+            .unwrap_or(from_date);
+
+        self.enqueue(
+            JobKind::LedgerConsolidation,
+            vec![
+                ("fiscal_year".to_string(), fiscal_year.to_string()),
+                ("period".to_string(), period.to_string()),
+                ("from_date".to_string(), from_date.format("%Y-%m-%d").to_string()),
+                ("to_date".to_string(), to_date.format("%Y-%m-%d").to_string()),
+            ],
+            requested_by,
+        )
+        .await
+    }
+
+    /// Projection再構築を登録
+    pub async fn enqueue_projection_rebuild(
+        &self,
+        requested_by: String,
+    ) -> Result<JobResponse, String> {
+        self.enqueue(JobKind::ProjectionRebuild, vec![], requested_by).await
+    }
+
+    /// Projection圧縮を登録
+    pub async fn enqueue_projection_compaction(
+        &self,
+        requested_by: String,
+    ) -> Result<JobResponse, String> {
+        self.enqueue(JobKind::ProjectionCompaction, vec![], requested_by).await
+    }
+
+    /// 未終了のProjection圧縮がなければ登録（定期実行用）
+    ///
+    /// 登録した場合はそのジョブを、既に待機中・実行中のものがあれば `None` を返す。
+    pub async fn schedule_projection_compaction(
+        &self,
+        requested_by: String,
+    ) -> Result<Option<JobResponse>, String> {
+        let pending = self
+            .list()
+            .await?
+            .into_iter()
+            .any(|job| job.kind == JobKind::ProjectionCompaction.as_str() && !job.finished);
+        if pending {
+            return Ok(None);
+        }
+        self.enqueue_projection_compaction(requested_by).await.map(Some)
+    }
+
+    /// 整合性監査を登録
+    pub async fn enqueue_integrity_audit(
+        &self,
+        trigger: AuditTrigger,
+        requested_by: String,
+    ) -> Result<JobResponse, String> {
+        self.enqueue(
+            JobKind::IntegrityAudit,
+            vec![
+                ("trigger".to_string(), trigger.as_str().to_string()),
+                ("requested_by".to_string(), requested_by.clone()),
+            ],
+            requested_by,
+        )
+        .await
+    }
+
+    /// 未終了の整合性監査がなければ定期監査を登録（定期実行用）
+    ///
+    /// 登録した場合はそのジョブを、既に待機中・実行中のものがあれば `None` を返す。
+    pub async fn schedule_integrity_audit(
+        &self,
+        requested_by: String,
+    ) -> Result<Option<JobResponse>, String> {
+        let pending = self
+            .list()
+            .await?
+            .into_iter()
+            .any(|job| job.kind == JobKind::IntegrityAudit.as_str() && !job.finished);
+        if pending {
+            return Ok(None);
+        }
+        self.enqueue_integrity_audit(AuditTrigger::Scheduled, requested_by)
+            .await
+            .map(Some)
+    }
+
+    /// 棚卸資産評価データの取込を登録
+    pub async fn enqueue_inventory_import(
+        &self,
+        fiscal_year: i32,
+        period: u8,
+        method: String,
+        currency: String,
+        path: &Path,
+        requested_by: String,
+    ) -> Result<JobResponse, String> {
+        // 作業ディレクトリに依存しないよう、登録時点の絶対パスで記録する
+        let path = std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf());
+        self.enqueue(
+            JobKind::InventoryImport,
+            vec![
+                ("fiscal_year".to_string(), fiscal_year.to_string()),
+                ("period".to_string(), period.to_string()),
+                ("method".to_string(), method),
+                ("currency".to_string(), currency),
+                ("path".to_string(), path.display().to_string()),
+            ],
+            requested_by,
+        )
+        .await
+    }
+
+    /// ジョブの一覧（登録日時の新しい順）
+    pub async fn list(&self) -> Result<Vec<JobResponse>, String> {
+        self.interactor.list().await.map_err(to_user_message)
+    }
+
+    /// ジョブの状態を取得
+    pub async fn find(&self, job_id: String) -> Result<JobResponse, String> {
+        self.interactor.find(&job_id).await.map_err(to_user_message)
+    }
+
+    /// ジョブが終了するまで状態を監視し、変化のたびに通知する
+    pub async fn watch(
+        &self,
+        job_id: String,
+        mut on_change: impl FnMut(&JobResponse) + Send,
+    ) -> Result<JobResponse, String> {
+        let mut last_updated_at = String::new();
+        loop {
+            let job = self.find(job_id.clone()).await?;
+            if job.updated_at != last_updated_at {
+                last_updated_at = job.updated_at.clone();
+                on_change(&job);
+            }
+            if job.finished {
+                return Ok(job);
+            }
+            tokio::time::sleep(WATCH_INTERVAL).await;
+        }
+    }
+
+    /// ジョブを取消
+    pub async fn cancel(
+        &self,
+        job_id: String,
+        cancelled_by: String,
+    ) -> Result<JobResponse, String> {
+        self.interactor
+            .cancel(CancelJobRequest { job_id, cancelled_by })
+            .await
+            .map_err(to_user_message)
+    }
+
+    /// 失敗・取消したジョブを再実行
+    pub async fn retry(&self, job_id: String) -> Result<JobResponse, String> {
+        self.interactor.retry(RetryJobRequest { job_id }).await.map_err(to_user_message)
+    }
+
+    /// 再起動時の復旧（ワーカーの起動前に呼び出す）
+    pub async fn recover(&self) -> Result<RecoverJobsResponse, String> {
+        self.interactor.recover().await.map_err(to_user_message)
+    }
+
+    /// ワーカーを起動
+    pub fn spawn_worker(&self) {
+        tokio::spawn(Arc::clone(&self.interactor).run_worker());
+    }
+
+    async fn enqueue(
+        &self,
+        kind: JobKind,
+        parameters: Vec<(String, String)>,
+        requested_by: String,
+    ) -> Result<JobResponse, String> {
+        self.interactor
+            .enqueue(EnqueueJobRequest {
+                kind: kind.as_str().to_string(),
+                parameters,
+                requested_by,
+            })
+            .await
+            .map_err(to_user_message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_job_parameters_parse_numbers() {
+        let parameters = JobParameters(vec![
+            ("fiscal_year".to_string(), "2024".to_string()),
+            ("period".to_string(), "13".to_string()),
+            ("method".to_string(), "FIFO".to_string()),
+        ]);
+
+        assert_eq!(parameters.number::<i32>("fiscal_year").unwrap(), 2024);
+        assert_eq!(parameters.number::<u8>("period").unwrap(), 13);
+        assert!(parameters.number::<u8>("method").is_err());
+        assert!(parameters.text("path").is_err());
+    }
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(2048), "2.0 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024 + 512 * 1024), "3.5 MiB");
+    }
+}
@@ -1,19 +1,53 @@
 // JournalEntryController実装
-// 仕訳登録に関する外部入力を受け付ける
+// 仕訳登録・承認・差戻しに関する外部入力を受け付ける
 
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
-use javelin_application::dtos::RegisterJournalEntryRequest;
-use javelin_infrastructure::{event_store::EventStore, services::VoucherNumberGeneratorImpl};
+use javelin_application::{
+    business_metrics::BusinessMetrics,
+    dtos::{ApproveJournalEntryRequest, RegisterJournalEntryRequest, RejectJournalEntryRequest},
+    interactor::{
+        AccountingPolicyInteractor, ApproveJournalEntryInteractor, DimensionMasterInteractor,
+        RejectJournalEntryInteractor,
+    },
+    query_service::AccountMasterCache,
+};
+use javelin_infrastructure::{
+    event_store::EventStore,
+    queries::master_data_loader_impl::MasterDataLoaderImpl,
+    repositories::{
+        AccountingPolicyRepositoryImpl, ApplicationSettingsRepositoryImpl,
+        DimensionMasterRepositoryImpl,
+    },
+    services::{EntryNumberGeneratorImpl, VoucherNumberGeneratorImpl},
+};
+
+use crate::{
+    controller::RequestTracker,
+    error_log::to_user_message,
+    presenter::{JournalEntryPresenter, Presenter},
+};
 
 /// 仕訳登録コントローラ
 ///
-/// 仕訳登録に関するすべての操作を受け付ける。
+/// 仕訳登録・承認・差戻しの操作を受け付ける。
 /// ユースケースへの委譲のみを行い、ビジネスロジックは含まない。
 pub struct JournalEntryController {
     event_store: Arc<EventStore>,
     voucher_generator: Arc<VoucherNumberGeneratorImpl>,
+    /// 承認時の仕訳番号の採番（採番中の番号を共有するため全承認で同じインスタンスを使う）
+    entry_number_generator: Arc<EntryNumberGeneratorImpl>,
+    /// 仕訳番号の採番範囲（既定の会社・会計年度の開始月）の取得元
+    settings_repository: Arc<ApplicationSettingsRepositoryImpl>,
     presenter_registry: Arc<crate::navigation::PresenterRegistry>,
+    accounting_policy: AccountingPolicyInteractor<AccountingPolicyRepositoryImpl>,
+    /// 明細の分析軸の検証に用いる分析軸マスタ（未設定の場合は検証しない）
+    dimension_masters: Option<DimensionMasterInteractor<DimensionMasterRepositoryImpl>>,
+    /// イベントに埋め込む勘定科目名の取得元（未設定の場合は名称を埋め込まない）
+    account_master: Option<(Arc<MasterDataLoaderImpl>, Arc<AccountMasterCache>)>,
+    requests: RequestTracker,
+    /// 業務指標の記録先（仕訳起票の件数と処理時間）
+    metrics: Option<Arc<dyn BusinessMetrics>>,
 }
 
 impl JournalEntryController {
@@ -21,9 +55,59 @@ impl JournalEntryController {
     pub fn new(
         event_store: Arc<EventStore>,
         voucher_generator: Arc<VoucherNumberGeneratorImpl>,
+        entry_number_generator: Arc<EntryNumberGeneratorImpl>,
+        settings_repository: Arc<ApplicationSettingsRepositoryImpl>,
         presenter_registry: Arc<crate::navigation::PresenterRegistry>,
+        accounting_policy_repository: Arc<AccountingPolicyRepositoryImpl>,
+    ) -> Self {
+        Self {
+            event_store,
+            voucher_generator,
+            entry_number_generator,
+            settings_repository,
+            presenter_registry,
+            accounting_policy: AccountingPolicyInteractor::new(accounting_policy_repository),
+            dimension_masters: None,
+            account_master: None,
+            requests: RequestTracker::default(),
+            metrics: None,
+        }
+    }
+
+    /// リクエストタイムアウトを設定
+    pub fn with_request_timeout(mut self, timeout: Duration) -> Self {
+        self.requests = RequestTracker::new(timeout);
+        self
+    }
+
+    /// 分析軸マスタを設定（登録時に明細の分析軸を検証する）
+    pub fn with_dimension_masters(
+        mut self,
+        dimension_master_repository: Arc<DimensionMasterRepositoryImpl>,
+    ) -> Self {
+        self.dimension_masters = Some(DimensionMasterInteractor::new(dimension_master_repository));
+        self
+    }
+
+    /// 勘定科目マスタを設定（登録時に明細へ勘定科目名を埋め込む）
+    pub fn with_account_master(
+        mut self,
+        master_data_loader: Arc<MasterDataLoaderImpl>,
+        cache: Arc<AccountMasterCache>,
     ) -> Self {
-        Self { event_store, voucher_generator, presenter_registry }
+        self.account_master = Some((master_data_loader, cache));
+        self
+    }
+
+    /// 業務指標の記録先を設定
+    pub fn with_metrics(mut self, metrics: Arc<dyn BusinessMetrics>) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// 実行中の登録処理をキャンセル（キャンセルされた処理の結果は破棄される）
+    pub fn cancel(&self, page_id: uuid::Uuid) {
+        self.requests.cancel(page_id);
     }
 
     /// PresenterRegistryへの参照を取得
@@ -39,7 +123,7 @@ impl JournalEntryController {
     ///
     /// # Returns
     /// * `Ok(())` - 登録成功（結果はOutputPort経由で通知）
-    /// * `Err(String)` - 登録失敗
+    /// * `Err(String)` - 登録失敗（タイムアウト時間を過ぎても完了まで待つ）
     pub async fn handle_register_journal_entry(
         &self,
         page_id: uuid::Uuid,
@@ -54,23 +138,119 @@ impl JournalEntryController {
             // ArcからPresenterをclone
             let journal_entry_presenter = (*journal_entry_presenter_arc).clone();
 
+            // 端数処理は登録時点の会計方針に従う
+            let accounting_policy =
+                self.accounting_policy.policy().await.map_err(to_user_message)?;
+
             // EventPresenterはダミーを作成（イベント通知は不要）
             let (event_tx, _) = tokio::sync::mpsc::unbounded_channel();
             let event_presenter = Arc::new(crate::presenter::Presenter::new(event_tx));
 
             // このページ専用のInteractorを動的に作成
-            let interactor = javelin_application::interactor::RegisterJournalEntryInteractor::new(
-                Arc::clone(&self.event_store),
-                event_presenter,
-                journal_entry_presenter.into(),
-                Arc::clone(&self.voucher_generator),
-            );
-
-            // 実行
-            interactor.execute(request).await.map_err(|e| e.to_string())?;
-            Ok(())
+            let mut interactor =
+                javelin_application::interactor::RegisterJournalEntryInteractor::new(
+                    Arc::clone(&self.event_store),
+                    event_presenter,
+                    journal_entry_presenter.into(),
+                    Arc::clone(&self.voucher_generator),
+                )
+                .with_accounting_policy(accounting_policy);
+            if let Some(dimension_masters) = &self.dimension_masters {
+                let masters = dimension_masters.masters().await.map_err(to_user_message)?;
+                interactor = interactor.with_dimension_masters(masters);
+            }
+            if let Some((master_data_loader, cache)) = &self.account_master {
+                let accounts = cache
+                    .get_or_load(master_data_loader.as_ref())
+                    .await
+                    .map_err(to_user_message)?;
+                interactor = interactor.with_account_names(
+                    accounts.iter().map(|a| (a.code.clone(), a.name.clone())).collect(),
+                );
+            }
+            if let Some(metrics) = &self.metrics {
+                interactor = interactor.with_metrics(Arc::clone(metrics));
+            }
+
+            // 登録は途中で破棄しない（確定済みの仕訳を失敗と報告したり、
+            // 採番済みの伝票番号を確定も返却もせずに残したりしないため）
+            self.requests
+                .run_to_completion(interactor.execute(request), || async {
+                    use javelin_application::output_port::JournalEntryOutputPort;
+
+                    journal_entry_presenter_arc
+                        .notify_progress(
+                            "登録処理に時間がかかっています。完了までお待ちください".to_string(),
+                        )
+                        .await;
+                })
+                .await
+                .map_err(to_user_message)
         } else {
             Err(format!("JournalEntryPresenter not found for page_id: {}", page_id))
         }
     }
+    /// 承認待ちの仕訳を承認（仕訳番号を採番して記帳する）
+    ///
+    /// 更新系のため途中で破棄せず、結果は呼び出し元に返す。
+    pub async fn approve_journal_entry(
+        &self,
+        entry_id: String,
+        approver_id: String,
+    ) -> Result<(), String> {
+        use javelin_application::input_ports::ApproveJournalEntryUseCase;
+
+        let mut interactor = ApproveJournalEntryInteractor::new(
+            Arc::clone(&self.event_store),
+            Self::event_presenter(),
+            Self::detached_presenter(),
+            Arc::clone(&self.entry_number_generator),
+            Arc::clone(&self.settings_repository),
+        );
+        if let Some(metrics) = &self.metrics {
+            interactor = interactor.with_metrics(Arc::clone(metrics));
+        }
+
+        interactor
+            .execute(ApproveJournalEntryRequest { entry_id, approver_id })
+            .await
+            .map_err(to_user_message)
+    }
+
+    /// 承認待ちの仕訳を差戻し（下書きに戻す）
+    pub async fn reject_journal_entry(
+        &self,
+        entry_id: String,
+        reason: String,
+        rejected_by: String,
+    ) -> Result<(), String> {
+        use javelin_application::input_ports::RejectJournalEntryUseCase;
+
+        let mut interactor = RejectJournalEntryInteractor::new(
+            Arc::clone(&self.event_store),
+            Self::event_presenter(),
+            Self::detached_presenter(),
+        );
+        if let Some(metrics) = &self.metrics {
+            interactor = interactor.with_metrics(Arc::clone(metrics));
+        }
+
+        interactor
+            .execute(RejectJournalEntryRequest { entry_id, reason, rejected_by })
+            .await
+            .map_err(to_user_message)
+    }
+
+    /// イベント通知用のPresenter（イベント通知は不要のためダミー）
+    fn event_presenter() -> Arc<Presenter> {
+        let (event_tx, _) = tokio::sync::mpsc::unbounded_channel();
+        Arc::new(Presenter::new(event_tx))
+    }
+
+    /// 画面に結果を通知しない操作用のPresenter（結果は戻り値で呼び出し元に返す）
+    fn detached_presenter() -> Arc<JournalEntryPresenter> {
+        let (list_tx, _, detail_tx, _, result_tx, _, progress_tx, _) =
+            JournalEntryPresenter::create_channels();
+        Arc::new(JournalEntryPresenter::new(list_tx, detail_tx, result_tx, progress_tx))
+    }
 }
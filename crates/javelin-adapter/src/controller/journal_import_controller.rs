@@ -0,0 +1,77 @@
+// JournalImportController - 表計算ファイルからの仕訳取込コントローラ
+
+use std::sync::Arc;
+
+use javelin_application::{
+    dtos::{
+        request::{JournalImportPreviewRequest, JournalImportRequest},
+        response::{
+            JournalImportPreviewResponse, JournalImportResponse, JournalImportValidationReport,
+        },
+    },
+    interactor::JournalImportInteractor,
+    projection_builder::ProjectionBuilder,
+};
+use javelin_infrastructure::{
+    event_store::EventStore, repositories::JournalImportTemplateRepositoryImpl,
+    services::SpreadsheetReaderImpl,
+};
+
+use crate::error_log::to_user_message;
+
+type Interactor =
+    JournalImportInteractor<SpreadsheetReaderImpl, JournalImportTemplateRepositoryImpl, EventStore>;
+
+/// 表計算ファイルからの仕訳取込コントローラ
+pub struct JournalImportController {
+    interactor: Interactor,
+}
+
+impl JournalImportController {
+    pub fn new(
+        template_repository: Arc<JournalImportTemplateRepositoryImpl>,
+        event_store: Arc<EventStore>,
+    ) -> Self {
+        Self {
+            interactor: JournalImportInteractor::new(
+                Arc::new(SpreadsheetReaderImpl::new()),
+                template_repository,
+                event_store,
+            ),
+        }
+    }
+
+    /// 一括取込中に反映を保留するProjectionを設定
+    pub fn with_deferred_projections(mut self, projections: Arc<dyn ProjectionBuilder>) -> Self {
+        self.interactor = self.interactor.with_deferred_projections(projections);
+        self
+    }
+
+    /// ファイルを読み込み、列の割当とプレビューを取得
+    pub async fn preview(&self, path: String) -> Result<JournalImportPreviewResponse, String> {
+        self.interactor
+            .preview(JournalImportPreviewRequest { path })
+            .await
+            .map_err(to_user_message)
+    }
+
+    /// 取込内容を検証
+    pub async fn validate(
+        &self,
+        request: JournalImportRequest,
+    ) -> Result<JournalImportValidationReport, String> {
+        self.interactor.validate(request).await.map_err(to_user_message)
+    }
+
+    /// 仕訳を下書きとして取り込む（追記・反映の進捗を `progress` へ通知）
+    pub async fn import(
+        &self,
+        request: JournalImportRequest,
+        progress: impl Fn(String) + Send + Sync,
+    ) -> Result<JournalImportResponse, String> {
+        self.interactor
+            .import_with_progress(request, &progress)
+            .await
+            .map_err(to_user_message)
+    }
+}
@@ -0,0 +1,49 @@
+// LedgerAnnotationController - 仕訳明細の注記コントローラ
+
+use std::sync::Arc;
+
+use javelin_application::{
+    dtos::{
+        request::{AnnotateEntryLineRequest, ResolveAnnotationRequest},
+        response::EntryAnnotationsResponse,
+    },
+    interactor::LedgerAnnotationInteractor,
+};
+use javelin_infrastructure::event_store::EventStore;
+
+use crate::error_log::to_user_message;
+
+/// 仕訳明細の注記コントローラ
+pub struct LedgerAnnotationController {
+    interactor: LedgerAnnotationInteractor<EventStore>,
+}
+
+impl LedgerAnnotationController {
+    pub fn new(event_store: Arc<EventStore>) -> Self {
+        Self { interactor: LedgerAnnotationInteractor::new(event_store) }
+    }
+
+    /// 仕訳の注記一覧を取得
+    pub async fn load_annotations(
+        &self,
+        entry_id: &str,
+    ) -> Result<EntryAnnotationsResponse, String> {
+        self.interactor.annotations(entry_id).await.map_err(to_user_message)
+    }
+
+    /// 明細に注記を付ける
+    pub async fn annotate(
+        &self,
+        request: AnnotateEntryLineRequest,
+    ) -> Result<EntryAnnotationsResponse, String> {
+        self.interactor.annotate(request).await.map_err(to_user_message)
+    }
+
+    /// 注記を解決する
+    pub async fn resolve(
+        &self,
+        request: ResolveAnnotationRequest,
+    ) -> Result<EntryAnnotationsResponse, String> {
+        self.interactor.resolve(request).await.map_err(to_user_message)
+    }
+}
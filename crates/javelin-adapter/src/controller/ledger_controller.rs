@@ -3,10 +3,17 @@
 
 use std::sync::Arc;
 
-use javelin_application::query_service::{
-    GetLedgerQuery, GetTrialBalanceQuery, LedgerQueryService,
+use javelin_application::{
+    dtos::{request::RollOverPeriodRequest, response::RollOverPeriodResponse},
+    interactor::RollOverPeriodInteractor,
+    query_service::{
+        EntryHistoryResult, GetEntryHistoryQuery, GetLedgerQuery, GetTrialBalanceQuery,
+        LedgerQueryService, ProjectionWarmUp, WarmUpProgress,
+    },
 };
 
+use crate::error_log::to_user_message;
+
 /// 元帳コントローラ
 ///
 /// 元帳・試算表照会に関するすべての操作を受け付ける。
@@ -33,7 +40,7 @@ where
             .get_ledger(query)
             .await
             .map(|_| ())
-            .map_err(|e| e.to_string())
+            .map_err(to_user_message)
     }
 
     /// 試算表を取得
@@ -42,6 +49,44 @@ where
             .get_trial_balance(query)
             .await
             .map(|_| ())
-            .map_err(|e| e.to_string())
+            .map_err(to_user_message)
+    }
+
+    /// 月初の繰越残高を確定
+    pub async fn roll_over_period(
+        &self,
+        fiscal_year: i32,
+        period: u8,
+    ) -> Result<RollOverPeriodResponse, String> {
+        RollOverPeriodInteractor::new(Arc::clone(&self.ledger_query_service))
+            .execute(RollOverPeriodRequest { fiscal_year, period })
+            .await
+            .map_err(to_user_message)
+    }
+
+    /// 仕訳修正履歴を取得
+    pub async fn get_entry_history(
+        &self,
+        query: GetEntryHistoryQuery,
+    ) -> Result<EntryHistoryResult, String> {
+        self.ledger_query_service
+            .get_entry_history(query)
+            .await
+            .map_err(to_user_message)
+    }
+}
+
+impl<L> LedgerController<L>
+where
+    L: LedgerQueryService + ProjectionWarmUp,
+{
+    /// 元帳Projectionを事前構築
+    pub async fn warm_up(&self) -> Result<(), String> {
+        self.ledger_query_service.warm_up().await.map_err(to_user_message)
+    }
+
+    /// 元帳Projectionの構築進捗
+    pub fn warm_up_progress(&self) -> WarmUpProgress {
+        self.ledger_query_service.warm_up_progress()
     }
 }
@@ -0,0 +1,65 @@
+// ManagementAccountMappingController - 管理会計科目マッピングコントローラ
+
+use std::sync::Arc;
+
+use javelin_application::{
+    dtos::{
+        CurrencyTrialBalanceDto,
+        request::{AssignManagementAccountRequest, RemoveManagementAccountMappingRequest},
+        response::{LoadManagementAccountMappingResponse, MappedTrialBalanceResponse},
+    },
+    interactor::ManagementAccountMappingInteractor,
+};
+use javelin_infrastructure::{
+    queries::MasterDataLoaderImpl, repositories::ManagementAccountMappingRepositoryImpl,
+};
+
+use crate::error_log::to_user_message;
+
+/// 管理会計科目マッピングコントローラ
+pub struct ManagementAccountMappingController {
+    interactor: ManagementAccountMappingInteractor<
+        ManagementAccountMappingRepositoryImpl,
+        MasterDataLoaderImpl,
+    >,
+}
+
+impl ManagementAccountMappingController {
+    pub fn new(
+        repository: Arc<ManagementAccountMappingRepositoryImpl>,
+        master_data_loader: Arc<MasterDataLoaderImpl>,
+    ) -> Self {
+        Self {
+            interactor: ManagementAccountMappingInteractor::new(repository, master_data_loader),
+        }
+    }
+
+    /// 勘定科目ごとの割当状況を取得
+    pub async fn load_mappings(&self) -> Result<LoadManagementAccountMappingResponse, String> {
+        self.interactor.load().await.map_err(to_user_message)
+    }
+
+    /// 管理会計科目を割り当てる
+    pub async fn assign_management_account(
+        &self,
+        request: AssignManagementAccountRequest,
+    ) -> Result<(), String> {
+        self.interactor.assign(request).await.map_err(to_user_message)
+    }
+
+    /// 管理会計科目の割当を解除
+    pub async fn remove_mapping(
+        &self,
+        request: RemoveManagementAccountMappingRequest,
+    ) -> Result<(), String> {
+        self.interactor.remove(request).await.map_err(to_user_message)
+    }
+
+    /// 試算表を管理会計科目体系で集計し直す
+    pub async fn map_trial_balance(
+        &self,
+        trial_balance: &CurrencyTrialBalanceDto,
+    ) -> Result<MappedTrialBalanceResponse, String> {
+        self.interactor.map_trial_balance(trial_balance).await.map_err(to_user_message)
+    }
+}
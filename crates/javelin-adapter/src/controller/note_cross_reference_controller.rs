@@ -0,0 +1,59 @@
+// NoteCrossReferenceController - 注記参照コントローラ
+
+use std::sync::Arc;
+
+use javelin_application::{
+    dtos::{
+        request::{
+            DeleteNoteCrossReferenceRequest, DeleteNoteSectionRequest,
+            SaveNoteCrossReferenceRequest, SaveNoteSectionRequest,
+        },
+        response::LoadNoteCrossReferencesResponse,
+    },
+    interactor::NoteCrossReferenceInteractor,
+};
+use javelin_infrastructure::repositories::NoteCrossReferenceRepositoryImpl;
+
+use crate::error_log::to_user_message;
+
+/// 注記参照コントローラ
+pub struct NoteCrossReferenceController {
+    interactor: NoteCrossReferenceInteractor<NoteCrossReferenceRepositoryImpl>,
+}
+
+impl NoteCrossReferenceController {
+    pub fn new(repository: Arc<NoteCrossReferenceRepositoryImpl>) -> Self {
+        Self { interactor: NoteCrossReferenceInteractor::new(repository) }
+    }
+
+    /// 注記と参照の一覧を取得
+    pub async fn load(&self) -> Result<LoadNoteCrossReferencesResponse, String> {
+        self.interactor.load().await.map_err(to_user_message)
+    }
+
+    /// 注記を保存
+    pub async fn save_section(&self, request: SaveNoteSectionRequest) -> Result<(), String> {
+        self.interactor.save_section(request).await.map_err(to_user_message)
+    }
+
+    /// 注記を削除
+    pub async fn delete_section(&self, request: DeleteNoteSectionRequest) -> Result<(), String> {
+        self.interactor.delete_section(request).await.map_err(to_user_message)
+    }
+
+    /// 注記参照を保存
+    pub async fn save_reference(
+        &self,
+        request: SaveNoteCrossReferenceRequest,
+    ) -> Result<(), String> {
+        self.interactor.save_reference(request).await.map_err(to_user_message)
+    }
+
+    /// 注記参照を解除
+    pub async fn delete_reference(
+        &self,
+        request: DeleteNoteCrossReferenceRequest,
+    ) -> Result<(), String> {
+        self.interactor.delete_reference(request).await.map_err(to_user_message)
+    }
+}
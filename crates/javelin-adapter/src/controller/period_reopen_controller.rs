@@ -0,0 +1,68 @@
+// PeriodReopenController - 会計期間の再オープンコントローラ
+
+use std::sync::Arc;
+
+use javelin_application::{
+    dtos::{
+        request::{ReopenClosingPeriodRequest, ReopenWindowReportRequest},
+        response::{ReopenClosingPeriodResponse, ReopenWindowReportResponse},
+    },
+    interactor::ReopenClosingPeriodInteractor,
+};
+use javelin_domain::time_provider::TimeProvider;
+use javelin_infrastructure::{
+    event_store::EventStore, queries::PeriodReopenQueryServiceImpl,
+    repositories::UserAccountRepositoryImpl, services::PasswordHasherImpl,
+};
+
+use crate::error_log::to_user_message;
+
+/// 会計期間の再オープンコントローラ
+pub struct PeriodReopenController {
+    interactor: ReopenClosingPeriodInteractor<
+        EventStore,
+        PeriodReopenQueryServiceImpl,
+        UserAccountRepositoryImpl,
+        PasswordHasherImpl,
+    >,
+}
+
+impl PeriodReopenController {
+    pub fn new(
+        event_store: Arc<EventStore>,
+        query_service: Arc<PeriodReopenQueryServiceImpl>,
+        user_repository: Arc<UserAccountRepositoryImpl>,
+        hasher: Arc<PasswordHasherImpl>,
+        time_provider: Arc<dyn TimeProvider>,
+    ) -> Self {
+        Self {
+            interactor: ReopenClosingPeriodInteractor::new(
+                event_store,
+                query_service,
+                user_repository,
+                hasher,
+                time_provider,
+            ),
+        }
+    }
+
+    /// 承認者の承認を得て会計期間を再オープン
+    pub async fn reopen(
+        &self,
+        request: ReopenClosingPeriodRequest,
+    ) -> Result<ReopenClosingPeriodResponse, String> {
+        self.interactor.reopen(request).await.map_err(to_user_message)
+    }
+
+    /// 直近の再オープン期間中に行われた記帳の一覧
+    pub async fn window_report(
+        &self,
+        fiscal_year: u32,
+        period: u8,
+    ) -> Result<ReopenWindowReportResponse, String> {
+        self.interactor
+            .window_report(ReopenWindowReportRequest { fiscal_year, period })
+            .await
+            .map_err(to_user_message)
+    }
+}
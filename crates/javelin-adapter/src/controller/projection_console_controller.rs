@@ -0,0 +1,46 @@
+// ProjectionConsoleController - Projection照会コンソールコントローラ
+
+use std::sync::Arc;
+
+use javelin_application::{
+    dtos::{request::QueryProjectionRequest, response::QueryProjectionResponse},
+    interactor::ProjectionConsoleInteractor,
+};
+use javelin_infrastructure::{
+    queries::ProjectionInspectionQueryServiceImpl, repositories::AccountingPolicyRepositoryImpl,
+};
+
+use crate::error_log::to_user_message;
+
+/// 1ページの表示件数
+const PAGE_SIZE: usize = 50;
+
+/// Projection照会コンソールコントローラ
+pub struct ProjectionConsoleController {
+    interactor: ProjectionConsoleInteractor<
+        ProjectionInspectionQueryServiceImpl,
+        AccountingPolicyRepositoryImpl,
+    >,
+}
+
+impl ProjectionConsoleController {
+    pub fn new(
+        query_service: Arc<ProjectionInspectionQueryServiceImpl>,
+        policy_repository: Arc<AccountingPolicyRepositoryImpl>,
+    ) -> Self {
+        Self { interactor: ProjectionConsoleInteractor::new(query_service, policy_repository) }
+    }
+
+    /// キー前方一致でProjectionを照会（afterは前ページの最終キー）
+    pub async fn query(
+        &self,
+        user_id: String,
+        prefix: String,
+        after: Option<String>,
+    ) -> Result<QueryProjectionResponse, String> {
+        self.interactor
+            .execute(QueryProjectionRequest { user_id, prefix, after, page_size: PAGE_SIZE })
+            .await
+            .map_err(to_user_message)
+    }
+}
@@ -1,11 +1,14 @@
 // ユーザ操作記録コントローラ
 
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
 use javelin_application::{
-    dtos::{RecordUserActionRequest, RecordUserActionResponse},
+    dtos::{RecordUserActionRequest, RecordUserActionResponse, request::RecordedActionKind},
     input_ports::RecordUserActionUseCase,
 };
+use javelin_domain::masters::SCREEN_VISIT_ACTION;
+
+use crate::error_log::to_user_message;
 
 /// ユーザ操作記録コントローラ
 pub struct RecordUserActionController {
@@ -17,19 +20,59 @@ impl RecordUserActionController {
         Self { use_case }
     }
 
-    /// ユーザ操作を記録
+    /// 画面の表示を記録（durationは表示していた時間）
+    pub async fn record_screen_visit(
+        &self,
+        user: impl Into<String>,
+        screen: impl Into<String>,
+        duration: Duration,
+    ) -> Result<RecordUserActionResponse, String> {
+        self.record(
+            user.into(),
+            RecordedActionKind::ScreenVisited,
+            screen.into(),
+            SCREEN_VISIT_ACTION.to_string(),
+            duration,
+        )
+        .await
+    }
+
+    /// 操作の実行を記録（durationは処理時間）
+    ///
+    /// 画面・操作には英数字の識別子を指定する（入力値などの自由記述は記録できない）。
     pub async fn record_action(
         &self,
         user: impl Into<String>,
-        location: impl Into<String>,
+        screen: impl Into<String>,
         action: impl Into<String>,
+        duration: Duration,
+    ) -> Result<RecordUserActionResponse, String> {
+        self.record(
+            user.into(),
+            RecordedActionKind::ActionPerformed,
+            screen.into(),
+            action.into(),
+            duration,
+        )
+        .await
+    }
+
+    async fn record(
+        &self,
+        user: String,
+        kind: RecordedActionKind,
+        location: String,
+        action: String,
+        duration: Duration,
     ) -> Result<RecordUserActionResponse, String> {
         let request = RecordUserActionRequest {
-            user: user.into(),
-            location: location.into(),
-            action: action.into(),
+            user,
+            kind,
+            location,
+            action,
+            duration_ms: duration.as_millis().min(u64::MAX as u128) as u64,
         };
 
-        self.use_case.execute(request).await.map_err(|e| e.to_string())
+        self.use_case.execute(request).await.map_err(to_user_message)
     }
 }
@@ -0,0 +1,92 @@
+// ReportArchiveController - 帳票アーカイブコントローラ
+
+use std::sync::Arc;
+
+use javelin_application::{
+    dtos::{
+        request::{ArchiveReportRequest, CompareArchivedReportRequest, OpenArchivedReportRequest},
+        response::{
+            ArchivedReportResponse, ArchivedReportSummary, FINANCIAL_STATEMENTS_REPORT_TYPE,
+            GenerateFinancialStatementsResponse, ReportComparisonResponse,
+        },
+    },
+    interactor::ReportArchiveInteractor,
+};
+use javelin_infrastructure::{
+    repositories::ReportArchiveRepositoryImpl, services::ReportDigesterImpl,
+};
+
+use crate::error_log::to_user_message;
+
+/// 帳票アーカイブコントローラ
+pub struct ReportArchiveController {
+    interactor: ReportArchiveInteractor<ReportArchiveRepositoryImpl, ReportDigesterImpl>,
+}
+
+impl ReportArchiveController {
+    pub fn new(archive_repository: Arc<ReportArchiveRepositoryImpl>) -> Self {
+        Self {
+            interactor: ReportArchiveInteractor::new(
+                archive_repository,
+                Arc::new(ReportDigesterImpl),
+            ),
+        }
+    }
+
+    /// 確定した財務諸表を承認してアーカイブ
+    pub async fn sign_off_financial_statements(
+        &self,
+        fiscal_year: i32,
+        period: u8,
+        statements: &GenerateFinancialStatementsResponse,
+        signed_by: String,
+    ) -> Result<ArchivedReportSummary, String> {
+        let cross_check = if statements.cross_check_passed {
+            "一致"
+        } else {
+            "不一致"
+        };
+        self.interactor
+            .archive(ArchiveReportRequest {
+                report_type: FINANCIAL_STATEMENTS_REPORT_TYPE.to_string(),
+                fiscal_year,
+                period,
+                parameters: vec![
+                    ("集計対象".to_string(), "承認済み仕訳".to_string()),
+                    ("貸借・相互検証".to_string(), cross_check.to_string()),
+                ],
+                lines: statements.report_lines(),
+                signed_by,
+            })
+            .await
+            .map_err(to_user_message)
+    }
+
+    /// アーカイブ済み帳票の一覧（承認日時の新しい順）
+    pub async fn list(&self) -> Result<Vec<ArchivedReportSummary>, String> {
+        self.interactor.list().await.map_err(to_user_message)
+    }
+
+    /// アーカイブ済み帳票を開く（ハッシュ値を検証）
+    pub async fn open(&self, archive_id: String) -> Result<ArchivedReportResponse, String> {
+        self.interactor
+            .open(OpenArchivedReportRequest { archive_id })
+            .await
+            .map_err(to_user_message)
+    }
+
+    /// 再生成した財務諸表と承認時点の数値を比較
+    pub async fn compare_financial_statements(
+        &self,
+        archive_id: String,
+        statements: &GenerateFinancialStatementsResponse,
+    ) -> Result<ReportComparisonResponse, String> {
+        self.interactor
+            .compare(CompareArchivedReportRequest {
+                archive_id,
+                current_lines: statements.report_lines(),
+            })
+            .await
+            .map_err(to_user_message)
+    }
+}
@@ -0,0 +1,44 @@
+// ReportParameterHistoryController - 帳票の最近使った条件コントローラ
+
+use std::{collections::BTreeMap, sync::Arc};
+
+use javelin_application::{
+    dtos::{request::RememberReportParametersRequest, response::RecentReportParametersResponse},
+    interactor::ReportParameterHistoryInteractor,
+};
+use javelin_infrastructure::repositories::ReportParameterHistoryRepositoryImpl;
+
+use crate::error_log::to_user_message;
+
+/// 帳票の最近使った条件コントローラ
+pub struct ReportParameterHistoryController {
+    interactor: ReportParameterHistoryInteractor<ReportParameterHistoryRepositoryImpl>,
+}
+
+impl ReportParameterHistoryController {
+    pub fn new(repository: Arc<ReportParameterHistoryRepositoryImpl>) -> Self {
+        Self { interactor: ReportParameterHistoryInteractor::new(repository) }
+    }
+
+    /// 最近使った条件を新しい順に取得
+    pub async fn recent(
+        &self,
+        user_id: &str,
+        report_id: &str,
+    ) -> Result<RecentReportParametersResponse, String> {
+        self.interactor.recent(user_id, report_id).await.map_err(to_user_message)
+    }
+
+    /// 実行した条件を記録
+    pub async fn remember(
+        &self,
+        user_id: String,
+        report_id: String,
+        values: BTreeMap<String, String>,
+    ) -> Result<RecentReportParametersResponse, String> {
+        self.interactor
+            .remember(RememberReportParametersRequest { user_id, report_id, values })
+            .await
+            .map_err(to_user_message)
+    }
+}
@@ -0,0 +1,317 @@
+// RequestControl - コントローラ呼び出しのタイムアウト・キャンセル制御
+// 責務: ページ単位の実行中リクエスト管理、タイムアウト、古い応答の破棄
+//
+// タイムアウト・キャンセルで処理を破棄するのは参照系のみ。
+// 更新系は破棄すると確定済みの結果を失敗として報告してしまうため、完了まで実行する。
+
+use std::{
+    collections::HashMap,
+    future::Future,
+    sync::{
+        Mutex,
+        atomic::{AtomicU64, Ordering},
+    },
+    time::Duration,
+};
+
+pub use tokio_util::sync::CancellationToken;
+use uuid::Uuid;
+
+use crate::error::{AdapterError, AdapterResult};
+
+/// デフォルトのリクエストタイムアウト
+pub const DEFAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// リクエストの種別
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RequestKind {
+    /// 参照系（タイムアウト・置き換え時は破棄してよい）
+    Query,
+    /// 更新系（途中で破棄せず、実際の結果を返す）
+    Command,
+}
+
+/// 実行中リクエストの識別子
+#[derive(Clone)]
+pub struct RequestTicket {
+    page_id: Uuid,
+    generation: u64,
+    token: CancellationToken,
+}
+
+impl RequestTicket {
+    pub fn page_id(&self) -> Uuid {
+        self.page_id
+    }
+
+    pub fn token(&self) -> &CancellationToken {
+        &self.token
+    }
+}
+
+/// ページ単位のリクエスト管理
+///
+/// 同じページから新しいリクエストが来ると、実行中の古いリクエストはキャンセルされる。
+/// キャンセル・置き換えられたリクエストの応答は呼び出し元に返さない。
+pub struct RequestTracker {
+    timeout: Duration,
+    next_generation: AtomicU64,
+    in_flight: Mutex<HashMap<Uuid, (u64, CancellationToken)>>,
+}
+
+impl Default for RequestTracker {
+    fn default() -> Self {
+        Self::new(DEFAULT_REQUEST_TIMEOUT)
+    }
+}
+
+impl RequestTracker {
+    pub fn new(timeout: Duration) -> Self {
+        Self {
+            timeout,
+            next_generation: AtomicU64::new(0),
+            in_flight: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// タイムアウト時間
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    /// リクエストを開始（同一ページの実行中リクエストはキャンセル）
+    pub fn begin(&self, page_id: Uuid) -> RequestTicket {
+        let generation = self.next_generation.fetch_add(1, Ordering::SeqCst);
+        let token = CancellationToken::new();
+
+        let previous = self.in_flight.lock().unwrap().insert(page_id, (generation, token.clone()));
+        if let Some((_, previous_token)) = previous {
+            previous_token.cancel();
+        }
+
+        RequestTicket { page_id, generation, token }
+    }
+
+    /// ページの実行中リクエストをキャンセル
+    pub fn cancel(&self, page_id: Uuid) {
+        if let Some((_, token)) = self.in_flight.lock().unwrap().remove(&page_id) {
+            token.cancel();
+        }
+    }
+
+    /// チケットが最新のリクエストかどうか
+    pub fn is_current(&self, ticket: &RequestTicket) -> bool {
+        !ticket.token.is_cancelled()
+            && self
+                .in_flight
+                .lock()
+                .unwrap()
+                .get(&ticket.page_id)
+                .is_some_and(|(generation, _)| *generation == ticket.generation)
+    }
+
+    /// 実行中リクエスト数
+    pub fn in_flight_count(&self) -> usize {
+        self.in_flight.lock().unwrap().len()
+    }
+
+    /// リクエストを完了（最新のリクエストの場合のみ登録を解除）
+    fn finish(&self, ticket: &RequestTicket) {
+        let mut in_flight = self.in_flight.lock().unwrap();
+        if in_flight
+            .get(&ticket.page_id)
+            .is_some_and(|(generation, _)| *generation == ticket.generation)
+        {
+            in_flight.remove(&ticket.page_id);
+        }
+    }
+
+    /// 更新系のリクエストを完了まで実行
+    ///
+    /// タイムアウト時間を過ぎても中断せず、`on_slow` を一度呼び出して完了を待つ。
+    /// 実行中の参照系リクエストの置き換えやキャンセルの対象にもならない。
+    pub async fn run_to_completion<T, F, S, SF>(&self, future: F, on_slow: S) -> T
+    where
+        F: Future<Output = T>,
+        S: FnOnce() -> SF,
+        SF: Future<Output = ()>,
+    {
+        run_to_completion(self.timeout, future, on_slow).await
+    }
+
+    /// タイムアウト・キャンセル付きで参照系のリクエストを実行
+    ///
+    /// 更新系には使用しないこと（`run_to_completion` を使う）。
+    ///
+    /// # Returns
+    /// * `Ok(T)` - 完了（最新のリクエストの場合のみ）
+    /// * `Err(AdapterError::RequestTimedOut)` - タイムアウト
+    /// * `Err(AdapterError::RequestCancelled)` - キャンセルまたは新しいリクエストで置き換え
+    pub async fn run<T, F>(&self, page_id: Uuid, future: F) -> AdapterResult<T>
+    where
+        F: Future<Output = T>,
+    {
+        let ticket = self.begin(page_id);
+
+        let result = tokio::select! {
+            biased;
+            _ = ticket.token.cancelled() => Err(AdapterError::RequestCancelled),
+            result = tokio::time::timeout(self.timeout, future) => {
+                result.map_err(|_| AdapterError::RequestTimedOut(self.timeout.as_millis() as u64))
+            }
+        };
+
+        // 置き換えられたリクエストの応答は破棄
+        let current = self.is_current(&ticket);
+        self.finish(&ticket);
+        if !current {
+            return Err(AdapterError::RequestCancelled);
+        }
+
+        result
+    }
+}
+
+/// 更新系の処理を完了まで実行（ページに紐付かない呼び出し用）
+///
+/// `timeout` を過ぎても中断せず、`on_slow` を一度呼び出して完了を待つ。
+pub async fn run_to_completion<T, F, S, SF>(timeout: Duration, future: F, on_slow: S) -> T
+where
+    F: Future<Output = T>,
+    S: FnOnce() -> SF,
+    SF: Future<Output = ()>,
+{
+    tokio::pin!(future);
+    match tokio::time::timeout(timeout, future.as_mut()).await {
+        Ok(output) => output,
+        Err(_) => {
+            on_slow().await;
+            future.await
+        }
+    }
+}
+
+/// タイムアウト付きで参照系の処理を実行（ページに紐付かない呼び出し用）
+pub async fn run_with_timeout<T, F>(timeout: Duration, future: F) -> AdapterResult<T>
+where
+    F: Future<Output = T>,
+{
+    tokio::time::timeout(timeout, future)
+        .await
+        .map_err(|_| AdapterError::RequestTimedOut(timeout.as_millis() as u64))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, atomic::AtomicBool};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_run_completes() {
+        let tracker = RequestTracker::new(Duration::from_secs(1));
+        let result = tracker.run(Uuid::new_v4(), async { 42 }).await;
+        assert_eq!(result.unwrap(), 42);
+        assert_eq!(tracker.in_flight_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_run_times_out() {
+        let tracker = RequestTracker::new(Duration::from_millis(10));
+        let result = tracker.run(Uuid::new_v4(), std::future::pending::<()>()).await;
+        assert!(matches!(result, Err(AdapterError::RequestTimedOut(10))));
+        assert_eq!(tracker.in_flight_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_command_runs_to_completion_past_timeout() {
+        let tracker = RequestTracker::new(Duration::from_millis(10));
+        let slow_notified = Arc::new(AtomicBool::new(false));
+
+        let result = tracker
+            .run_to_completion(
+                async {
+                    tokio::time::sleep(Duration::from_millis(50)).await;
+                    "committed"
+                },
+                || {
+                    let slow_notified = Arc::clone(&slow_notified);
+                    async move { slow_notified.store(true, Ordering::SeqCst) }
+                },
+            )
+            .await;
+
+        // タイムアウト後も破棄せず、実際の結果を返す
+        assert_eq!(result, "committed");
+        assert!(slow_notified.load(Ordering::SeqCst));
+        assert_eq!(tracker.in_flight_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_command_is_not_cancelled_by_newer_query() {
+        let tracker = Arc::new(RequestTracker::new(Duration::from_secs(5)));
+        let page_id = Uuid::new_v4();
+
+        let command = {
+            let tracker = Arc::clone(&tracker);
+            tokio::spawn(async move {
+                tracker
+                    .run_to_completion(
+                        async {
+                            tokio::time::sleep(Duration::from_millis(100)).await;
+                            "committed"
+                        },
+                        || async {},
+                    )
+                    .await
+            })
+        };
+        tokio::time::sleep(Duration::from_millis(20)).await;
+
+        assert_eq!(tracker.run(page_id, async { "query" }).await.unwrap(), "query");
+        tracker.cancel(page_id);
+
+        assert_eq!(command.await.unwrap(), "committed");
+    }
+
+    #[tokio::test]
+    async fn test_newer_request_supersedes_older() {
+        let tracker = Arc::new(RequestTracker::new(Duration::from_secs(5)));
+        let page_id = Uuid::new_v4();
+
+        let stale = {
+            let tracker = Arc::clone(&tracker);
+            tokio::spawn(async move {
+                tracker
+                    .run(page_id, async {
+                        tokio::time::sleep(Duration::from_millis(200)).await;
+                        "stale"
+                    })
+                    .await
+            })
+        };
+        tokio::time::sleep(Duration::from_millis(20)).await;
+
+        let fresh = tracker.run(page_id, async { "fresh" }).await;
+
+        assert_eq!(fresh.unwrap(), "fresh");
+        assert!(matches!(stale.await.unwrap(), Err(AdapterError::RequestCancelled)));
+    }
+
+    #[tokio::test]
+    async fn test_cancel_by_page() {
+        let tracker = Arc::new(RequestTracker::new(Duration::from_secs(5)));
+        let page_id = Uuid::new_v4();
+
+        let handle = {
+            let tracker = Arc::clone(&tracker);
+            tokio::spawn(async move { tracker.run(page_id, std::future::pending::<()>()).await })
+        };
+        tokio::time::sleep(Duration::from_millis(20)).await;
+
+        tracker.cancel(page_id);
+
+        assert!(matches!(handle.await.unwrap(), Err(AdapterError::RequestCancelled)));
+        assert_eq!(tracker.in_flight_count(), 0);
+    }
+}
@@ -1,12 +1,18 @@
 // SearchController実装
 // 仕訳検索に関する外部入力を受け付ける
 
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
-use javelin_application::dtos::request::SearchCriteriaDto;
+use javelin_application::{
+    dtos::request::SearchCriteriaDto,
+    query_service::{ProjectionWarmUp, WarmUpProgress},
+};
 use javelin_infrastructure::queries::JournalEntrySearchQueryServiceImpl;
 
-use crate::navigation::PresenterRegistry;
+use crate::{
+    controller::RequestTracker, error::AdapterError, error_log::to_user_message,
+    navigation::PresenterRegistry,
+};
 
 /// 検索コントローラ
 ///
@@ -15,6 +21,7 @@ use crate::navigation::PresenterRegistry;
 pub struct SearchController {
     query_service: Arc<JournalEntrySearchQueryServiceImpl>,
     presenter_registry: Arc<PresenterRegistry>,
+    requests: RequestTracker,
 }
 
 impl SearchController {
@@ -23,7 +30,18 @@ impl SearchController {
         query_service: Arc<JournalEntrySearchQueryServiceImpl>,
         presenter_registry: Arc<PresenterRegistry>,
     ) -> Self {
-        Self { query_service, presenter_registry }
+        Self { query_service, presenter_registry, requests: RequestTracker::default() }
+    }
+
+    /// リクエストタイムアウトを設定
+    pub fn with_request_timeout(mut self, timeout: Duration) -> Self {
+        self.requests = RequestTracker::new(timeout);
+        self
+    }
+
+    /// 実行中の検索をキャンセル（キャンセルされた検索の結果は破棄される）
+    pub fn cancel_search(&self, page_id: uuid::Uuid) {
+        self.requests.cancel(page_id);
     }
 
     /// PresenterRegistryへの参照を取得
@@ -31,6 +49,16 @@ impl SearchController {
         &self.presenter_registry
     }
 
+    /// 仕訳検索Projectionを事前構築
+    pub async fn warm_up(&self) -> Result<(), String> {
+        self.query_service.warm_up().await.map_err(to_user_message)
+    }
+
+    /// 仕訳検索Projectionの構築進捗
+    pub fn warm_up_progress(&self) -> WarmUpProgress {
+        self.query_service.warm_up_progress()
+    }
+
     /// 仕訳を検索
     ///
     /// # Arguments
@@ -39,7 +67,7 @@ impl SearchController {
     ///
     /// # Returns
     /// * `Ok(())` - 検索成功（結果はOutputPort経由で通知）
-    /// * `Err(String)` - 検索失敗
+    /// * `Err(String)` - 検索失敗・タイムアウト・キャンセル
     pub async fn handle_search(
         &self,
         page_id: uuid::Uuid,
@@ -59,9 +87,19 @@ impl SearchController {
                     presenter.into(),
                 );
 
-            // 実行
-            interactor.execute(criteria).await.map_err(|e| e.to_string())?;
-            Ok(())
+            // 実行（タイムアウト時は進捗・エラーチャネルへ通知してスピナーを止める）
+            match self.requests.run(page_id, interactor.execute(criteria)).await {
+                Ok(result) => result.map_err(to_user_message),
+                Err(e @ AdapterError::RequestTimedOut(_)) => {
+                    use javelin_application::output_port::SearchOutputPort;
+
+                    presenter_arc.present_progress(e.to_string());
+                    presenter_arc
+                        .present_validation_error(format!("検索がタイムアウトしました: {}", e));
+                    Err(e.to_string())
+                }
+                Err(e) => Err(e.to_string()),
+            }
         } else {
             Err(format!("SearchPresenter not found for page_id: {}", page_id))
         }
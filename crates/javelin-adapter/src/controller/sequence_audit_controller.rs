@@ -0,0 +1,33 @@
+// SequenceAuditController - 番号連続性監査コントローラ
+
+use std::sync::Arc;
+
+use javelin_application::{
+    dtos::{request::SequenceAuditRequest, response::SequenceAuditResponse},
+    interactor::SequenceAuditInteractor,
+};
+use javelin_infrastructure::queries::SequenceAuditQueryServiceImpl;
+
+use crate::error_log::to_user_message;
+
+/// 番号連続性監査コントローラ
+pub struct SequenceAuditController {
+    interactor: SequenceAuditInteractor<SequenceAuditQueryServiceImpl>,
+}
+
+impl SequenceAuditController {
+    pub fn new(query_service: Arc<SequenceAuditQueryServiceImpl>) -> Self {
+        Self { interactor: SequenceAuditInteractor::new(query_service) }
+    }
+
+    /// 番号連続性監査を実行（年度を省略した場合は全年度）
+    pub async fn run_audit(
+        &self,
+        fiscal_year: Option<u32>,
+    ) -> Result<SequenceAuditResponse, String> {
+        self.interactor
+            .execute(SequenceAuditRequest { fiscal_year })
+            .await
+            .map_err(to_user_message)
+    }
+}
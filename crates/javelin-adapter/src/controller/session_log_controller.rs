@@ -0,0 +1,91 @@
+// SessionLogController - ログインセッションの記録と管理コントローラ
+
+use std::{sync::Arc, time::Duration};
+
+use javelin_application::{
+    dtos::{
+        request::{ForceLogoutRequest, SessionOverviewRequest},
+        response::SessionOverviewResponse,
+    },
+    interactor::SessionLogInteractor,
+};
+use javelin_infrastructure::{
+    event_store::EventStore, repositories::AccountingPolicyRepositoryImpl,
+};
+
+use crate::{error_log::to_user_message, navigation::Session};
+
+/// 強制ログアウトを確認する間隔
+const FORCED_LOGOUT_CHECK_INTERVAL: Duration = Duration::from_secs(5);
+
+/// 強制ログアウトされた利用者へのログイン画面の通知
+const FORCED_LOGOUT_NOTICE: &str = "管理者によりログアウトされました。再度ログインしてください";
+
+/// ログインセッションの記録と管理コントローラ
+pub struct SessionLogController {
+    interactor: Arc<SessionLogInteractor<EventStore, AccountingPolicyRepositoryImpl>>,
+}
+
+impl SessionLogController {
+    pub fn new(
+        event_store: Arc<EventStore>,
+        policy_repository: Arc<AccountingPolicyRepositoryImpl>,
+    ) -> Self {
+        Self { interactor: Arc::new(SessionLogInteractor::new(event_store, policy_repository)) }
+    }
+
+    /// ログインを記録し、強制ログアウトの監視を開始
+    ///
+    /// 記録したセッションが強制終了されると、セッションをロックしてログイン画面へ戻す。
+    pub fn track_login(&self, session: Arc<Session>, user_id: String) {
+        let interactor = Arc::clone(&self.interactor);
+        tokio::spawn(async move {
+            let Ok(session_id) = interactor.record_login(&user_id).await else {
+                return;
+            };
+            session.set_session_id(session_id.clone());
+
+            loop {
+                tokio::time::sleep(FORCED_LOGOUT_CHECK_INTERVAL).await;
+                // ログアウト・再ログインで記録中のセッションが変わったら監視を終える
+                if session.session_id().as_deref() != Some(session_id.as_str()) {
+                    return;
+                }
+                if let Ok(false) = interactor.is_active(&session_id).await {
+                    session.revoke(&session_id, FORCED_LOGOUT_NOTICE);
+                    return;
+                }
+            }
+        });
+    }
+
+    /// ログインの失敗を記録
+    pub async fn record_failed_login(&self, user_id: String, reason: String) {
+        let _ = self.interactor.record_failed_login(&user_id, &reason).await;
+    }
+
+    /// ログアウトを記録
+    pub async fn record_logout(&self, session_id: String) -> Result<(), String> {
+        self.interactor.record_logout(&session_id).await.map_err(to_user_message)
+    }
+
+    /// ログイン中のセッション・最近のログイン・ログイン失敗の一覧
+    pub async fn overview(&self, user_id: String) -> Result<SessionOverviewResponse, String> {
+        self.interactor
+            .overview(SessionOverviewRequest { user_id })
+            .await
+            .map_err(to_user_message)
+    }
+
+    /// セッションを強制ログアウトする
+    pub async fn force_logout(
+        &self,
+        user_id: String,
+        session_id: String,
+    ) -> Result<SessionOverviewResponse, String> {
+        self.interactor
+            .force_logout(ForceLogoutRequest { session_id, user_id })
+            .await
+            .map_err(to_user_message)
+    }
+}
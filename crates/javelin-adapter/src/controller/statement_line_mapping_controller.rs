@@ -0,0 +1,52 @@
+// StatementLineMappingController - 財務諸表表示科目マッピングコントローラ
+
+use std::sync::Arc;
+
+use javelin_application::{
+    dtos::{
+        request::{AssignStatementLineRequest, RemoveStatementLineMappingRequest},
+        response::LoadStatementLineMappingResponse,
+    },
+    interactor::StatementLineMappingInteractor,
+};
+use javelin_infrastructure::{
+    queries::MasterDataLoaderImpl, repositories::StatementLineMappingRepositoryImpl,
+};
+
+use crate::error_log::to_user_message;
+
+/// 財務諸表表示科目マッピングコントローラ
+pub struct StatementLineMappingController {
+    interactor:
+        StatementLineMappingInteractor<StatementLineMappingRepositoryImpl, MasterDataLoaderImpl>,
+}
+
+impl StatementLineMappingController {
+    pub fn new(
+        repository: Arc<StatementLineMappingRepositoryImpl>,
+        master_data_loader: Arc<MasterDataLoaderImpl>,
+    ) -> Self {
+        Self { interactor: StatementLineMappingInteractor::new(repository, master_data_loader) }
+    }
+
+    /// 勘定科目ごとの割当状況を取得
+    pub async fn load_mappings(&self) -> Result<LoadStatementLineMappingResponse, String> {
+        self.interactor.load().await.map_err(to_user_message)
+    }
+
+    /// 表示科目を割り当てる
+    pub async fn assign_statement_line(
+        &self,
+        request: AssignStatementLineRequest,
+    ) -> Result<(), String> {
+        self.interactor.assign(request).await.map_err(to_user_message)
+    }
+
+    /// 表示科目の割当を解除
+    pub async fn remove_mapping(
+        &self,
+        request: RemoveStatementLineMappingRequest,
+    ) -> Result<(), String> {
+        self.interactor.remove(request).await.map_err(to_user_message)
+    }
+}
@@ -0,0 +1,28 @@
+// StorageTelemetryController - ストレージ使用状況の時系列コントローラ
+
+use std::sync::Arc;
+
+use javelin_application::storage_telemetry::{StorageSample, StorageTelemetry};
+
+use crate::error_log::to_user_message;
+
+/// ストレージ使用状況の時系列コントローラ
+pub struct StorageTelemetryController {
+    telemetry: Arc<dyn StorageTelemetry>,
+}
+
+impl StorageTelemetryController {
+    pub fn new(telemetry: Arc<dyn StorageTelemetry>) -> Self {
+        Self { telemetry }
+    }
+
+    /// 現在の使用状況を標本として記録
+    pub async fn record_sample(&self) -> Result<StorageSample, String> {
+        self.telemetry.record_sample().await.map_err(to_user_message)
+    }
+
+    /// 記録済みの標本（古い順）
+    pub async fn samples(&self) -> Result<Vec<StorageSample>, String> {
+        self.telemetry.samples().await.map_err(to_user_message)
+    }
+}
@@ -11,7 +11,7 @@ use javelin_application::{
 };
 use javelin_infrastructure::repositories::subsidiary_account_master_repository_impl::SubsidiaryAccountMasterRepositoryImpl;
 
-use crate::navigation::PresenterRegistry;
+use crate::{error_log::to_user_message, navigation::PresenterRegistry};
 
 /// 補助科目マスタコントローラ
 pub struct SubsidiaryAccountMasterController {
@@ -53,7 +53,7 @@ impl SubsidiaryAccountMasterController {
             );
 
             // 実行
-            interactor.execute(request).await.map_err(|e| e.to_string())
+            interactor.execute(request).await.map_err(to_user_message)
         } else {
             Err(format!("SubsidiaryAccountMasterPresenter not found for page_id: {}", page_id))
         }
@@ -0,0 +1,168 @@
+// SupplierInvoiceController - 仕入先請求書台帳コントローラ
+
+use std::{path::Path, sync::Arc};
+
+use chrono::NaiveDate;
+use javelin_application::{
+    dtos::{
+        request::{
+            CreateInvoiceJournalDraftRequest, RecordInvoicePaymentRequest,
+            RegisterSupplierInvoiceRequest,
+        },
+        response::{InvoiceJournalDraftResponse, SupplierInvoiceResponse},
+    },
+    interactor::SupplierInvoiceInteractor,
+};
+use javelin_infrastructure::{
+    event_store::EventStore, repositories::SupplierInvoiceRepositoryImpl,
+    services::VoucherNumberGeneratorImpl,
+};
+
+use super::inventory_worksheet_controller::split_csv_line;
+use crate::error_log::to_user_message;
+
+/// 取込ファイルの列数
+/// （請求書番号,仕入先,請求日,支払期日,勘定科目,通貨,税抜金額,消費税額）
+const IMPORT_COLUMN_COUNT: usize = 8;
+
+/// 仕入先請求書台帳コントローラ
+pub struct SupplierInvoiceController {
+    interactor: SupplierInvoiceInteractor<
+        SupplierInvoiceRepositoryImpl,
+        EventStore,
+        VoucherNumberGeneratorImpl,
+    >,
+}
+
+impl SupplierInvoiceController {
+    pub fn new(
+        invoice_repository: Arc<SupplierInvoiceRepositoryImpl>,
+        event_store: Arc<EventStore>,
+        voucher_generator: Arc<VoucherNumberGeneratorImpl>,
+    ) -> Self {
+        Self {
+            interactor: SupplierInvoiceInteractor::new(
+                invoice_repository,
+                event_store,
+                voucher_generator,
+            ),
+        }
+    }
+
+    /// 請求書（CSV、1行目は見出し）を取り込み、取り込んだ件数を返す
+    pub async fn import_csv(&self, path: &Path) -> Result<usize, String> {
+        let content = tokio::fs::read_to_string(path)
+            .await
+            .map_err(|e| format!("{} を読み込めません: {}", path.display(), e))?;
+        let requests = parse_invoices(&content)?;
+        let count = requests.len();
+
+        for request in requests {
+            let invoice_number = request.invoice_number.clone();
+            self.interactor
+                .register(request)
+                .await
+                .map_err(|e| format!("{}: {}", invoice_number, to_user_message(e)))?;
+        }
+        Ok(count)
+    }
+
+    /// 登録済みの請求書を支払状況とともに取得
+    pub async fn list(&self, as_of: NaiveDate) -> Result<Vec<SupplierInvoiceResponse>, String> {
+        self.interactor.list(as_of).await.map_err(to_user_message)
+    }
+
+    /// 請求書から買掛金計上仕訳を下書きとして起票
+    pub async fn create_journal_draft(
+        &self,
+        user_id: String,
+        counterparty: String,
+        invoice_number: String,
+    ) -> Result<InvoiceJournalDraftResponse, String> {
+        self.interactor
+            .create_journal_draft(CreateInvoiceJournalDraftRequest {
+                user_id,
+                counterparty,
+                invoice_number,
+            })
+            .await
+            .map_err(to_user_message)
+    }
+
+    /// 請求書の支払を記録（支払日はYYYY-MM-DD形式）
+    pub async fn record_payment(
+        &self,
+        counterparty: String,
+        invoice_number: String,
+        paid_date: String,
+        amount: f64,
+    ) -> Result<SupplierInvoiceResponse, String> {
+        self.interactor
+            .record_payment(RecordInvoicePaymentRequest {
+                counterparty,
+                invoice_number,
+                paid_date,
+                amount,
+            })
+            .await
+            .map_err(to_user_message)
+    }
+}
+
+/// CSVを請求書登録リクエストに変換
+fn parse_invoices(content: &str) -> Result<Vec<RegisterSupplierInvoiceRequest>, String> {
+    let mut requests = Vec::new();
+    for (index, line) in content.lines().enumerate().skip(1) {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let row_number = index + 1;
+        let fields = split_csv_line(line);
+        if fields.len() != IMPORT_COLUMN_COUNT {
+            return Err(format!(
+                "{}行目: 列数が不正です（{}列、期待値 {}列）",
+                row_number,
+                fields.len(),
+                IMPORT_COLUMN_COUNT
+            ));
+        }
+        let number = |column: usize, label: &str| {
+            fields[column].replace(',', "").parse::<f64>().map_err(|_| {
+                format!("{}行目: {}が数値ではありません: {}", row_number, label, fields[column])
+            })
+        };
+
+        requests.push(RegisterSupplierInvoiceRequest {
+            invoice_number: fields[0].clone(),
+            counterparty: fields[1].clone(),
+            invoice_date: fields[2].clone(),
+            due_date: fields[3].clone(),
+            expense_account_code: fields[4].clone(),
+            currency: fields[5].clone(),
+            amount: number(6, "税抜金額")?,
+            tax_amount: number(7, "消費税額")?,
+        });
+    }
+    Ok(requests)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_invoices() {
+        let content = "請求書番号,仕入先,請求日,支払期日,勘定科目,通貨,税抜金額,消費税額\n\
+                       INV-001,X001,2024-04-05,2024-05-31,5100,JPY,\"100,000\",10000\n\
+                       \n\
+                       INV-002,X002,2024-04-10,2024-05-31,5200,JPY,50000\n";
+
+        let error = parse_invoices(content).unwrap_err();
+        assert_eq!(error, "4行目: 列数が不正です（7列、期待値 8列）");
+
+        let requests = parse_invoices(&content.replace(",50000\n", ",50000,5000\n")).unwrap();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].amount, 100_000.0);
+        assert_eq!(requests[1].tax_amount, 5_000.0);
+    }
+}
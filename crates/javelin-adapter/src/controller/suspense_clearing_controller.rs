@@ -0,0 +1,63 @@
+// SuspenseClearingController - 仮勘定の滞留分析・整理コントローラ
+
+use std::sync::Arc;
+
+use javelin_application::{
+    dtos::{
+        request::{AcceptClearingSuggestionRequest, SuspenseAgingRequest},
+        response::{AcceptClearingSuggestionResponse, SuspenseAgingResponse},
+    },
+    interactor::SuspenseClearingInteractor,
+};
+use javelin_infrastructure::{
+    event_store::EventStore, queries::SuspenseAgingQueryServiceImpl,
+    services::VoucherNumberGeneratorImpl,
+};
+
+use crate::error_log::to_user_message;
+
+/// 仮勘定の滞留分析・整理コントローラ
+pub struct SuspenseClearingController {
+    interactor: SuspenseClearingInteractor<
+        SuspenseAgingQueryServiceImpl,
+        EventStore,
+        VoucherNumberGeneratorImpl,
+    >,
+}
+
+impl SuspenseClearingController {
+    pub fn new(
+        query_service: Arc<SuspenseAgingQueryServiceImpl>,
+        event_store: Arc<EventStore>,
+        voucher_generator: Arc<VoucherNumberGeneratorImpl>,
+    ) -> Self {
+        Self {
+            interactor: SuspenseClearingInteractor::new(
+                query_service,
+                event_store,
+                voucher_generator,
+            ),
+        }
+    }
+
+    /// 基準日時点の仮勘定の滞留状況を取得（YYYY-MM-DD形式）
+    pub async fn load_aging(&self, as_of_date: String) -> Result<SuspenseAgingResponse, String> {
+        self.interactor
+            .load(SuspenseAgingRequest { as_of_date })
+            .await
+            .map_err(to_user_message)
+    }
+
+    /// 提案された整理仕訳を下書きとして起票
+    pub async fn accept_suggestion(
+        &self,
+        user_id: String,
+        entry_id: String,
+        line_number: u32,
+    ) -> Result<AcceptClearingSuggestionResponse, String> {
+        self.interactor
+            .accept(AcceptClearingSuggestionRequest { user_id, entry_id, line_number })
+            .await
+            .map_err(to_user_message)
+    }
+}
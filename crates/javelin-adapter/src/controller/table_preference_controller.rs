@@ -0,0 +1,35 @@
+// TablePreferenceController - 一覧テーブル表示設定コントローラ
+
+use std::sync::Arc;
+
+use javelin_application::{
+    dtos::{request::SaveTablePreferenceRequest, response::TablePreferenceResponse},
+    interactor::TablePreferenceInteractor,
+};
+use javelin_infrastructure::repositories::TablePreferenceRepositoryImpl;
+
+use crate::error_log::to_user_message;
+
+/// 一覧テーブル表示設定コントローラ
+pub struct TablePreferenceController {
+    interactor: TablePreferenceInteractor<TablePreferenceRepositoryImpl>,
+}
+
+impl TablePreferenceController {
+    pub fn new(repository: Arc<TablePreferenceRepositoryImpl>) -> Self {
+        Self { interactor: TablePreferenceInteractor::new(repository) }
+    }
+
+    /// 表示設定を取得（未保存の場合はNone）
+    pub async fn load_preference(
+        &self,
+        table_id: &str,
+    ) -> Result<Option<TablePreferenceResponse>, String> {
+        self.interactor.load(table_id).await.map_err(to_user_message)
+    }
+
+    /// 表示設定を保存
+    pub async fn save_preference(&self, request: SaveTablePreferenceRequest) -> Result<(), String> {
+        self.interactor.save(request).await.map_err(to_user_message)
+    }
+}
@@ -0,0 +1,102 @@
+// UseCaseHandle - 型を消去したユースケース
+// 責務: Interactorの具体型をコントローラの型引数から切り離す
+
+use std::{future::Future, pin::Pin, sync::Arc};
+
+use javelin_application::error::ApplicationResult;
+
+/// ユースケースの実行結果（実行中のFutureはハンドルを借用しない）
+pub type UseCaseFuture<Res> = Pin<Box<dyn Future<Output = ApplicationResult<Res>> + Send>>;
+
+type BoxedExecute<Req, Res> = dyn Fn(Req) -> UseCaseFuture<Res> + Send + Sync;
+
+/// 型を消去したユースケース
+///
+/// InputPortは `async fn` のトレイトのためトレイトオブジェクトにできない。
+/// Interactorの具体型が分かる構築時（app_setup）に実行処理を閉じ込め、
+/// コントローラはリクエストとレスポンスの型だけを持つ。
+///
+/// 登録には `use_case!` マクロを使う。
+///
+/// ```ignore
+/// let adjust_accounts = use_case!(adjust_accounts_interactor);
+/// let validate_closing_lock = use_case!(lock_closing_period_interactor, validate);
+/// ```
+pub struct UseCaseHandle<Req, Res> {
+    execute: Arc<BoxedExecute<Req, Res>>,
+}
+
+impl<Req, Res> UseCaseHandle<Req, Res>
+where
+    Req: 'static,
+    Res: 'static,
+{
+    /// Interactorと、そのInputPortを呼び出す処理から作成
+    pub fn new<U, F, Fut>(use_case: Arc<U>, execute: F) -> Self
+    where
+        U: Send + Sync + 'static,
+        F: Fn(Arc<U>, Req) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ApplicationResult<Res>> + Send + 'static,
+    {
+        Self {
+            execute: Arc::new(move |request| Box::pin(execute(Arc::clone(&use_case), request))),
+        }
+    }
+
+    /// ユースケースを実行
+    pub fn execute(&self, request: Req) -> UseCaseFuture<Res> {
+        (self.execute)(request)
+    }
+}
+
+impl<Req, Res> Clone for UseCaseHandle<Req, Res> {
+    fn clone(&self) -> Self {
+        Self { execute: Arc::clone(&self.execute) }
+    }
+}
+
+/// Interactorを型を消去したユースケースとして登録するマクロ
+///
+/// 既定ではInputPortの `execute` を呼び出し、メソッド名を指定した場合はそのメソッドを呼び出す。
+/// InputPortのトレイトは呼び出し側でスコープに入れておくこと。
+#[macro_export]
+macro_rules! use_case {
+    ($use_case:expr) => {
+        $crate::use_case!($use_case, execute)
+    };
+    ($use_case:expr, $method:ident) => {
+        $crate::controller::UseCaseHandle::new($use_case, |use_case, request| async move {
+            use_case.$method(request).await
+        })
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use javelin_application::error::ApplicationError;
+
+    use super::*;
+
+    struct Doubler;
+
+    impl Doubler {
+        async fn double(&self, value: i32) -> ApplicationResult<i32> {
+            if value < 0 {
+                return Err(ApplicationError::ValidationError("負の値".to_string()));
+            }
+            Ok(value * 2)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_handle_runs_wrapped_use_case_and_can_be_spawned() {
+        let handle: UseCaseHandle<i32, i32> = crate::use_case!(Arc::new(Doubler), double);
+
+        assert_eq!(handle.execute(21).await.unwrap(), 42);
+        assert!(handle.execute(-1).await.is_err());
+
+        // 実行中のFutureはハンドルを借用しないため、別タスクで実行できる
+        let spawned = tokio::spawn(handle.clone().execute(5));
+        assert_eq!(spawned.await.unwrap().unwrap(), 10);
+    }
+}
@@ -0,0 +1,40 @@
+// UserActivityController - 利用状況レポートコントローラ
+
+use std::sync::Arc;
+
+use chrono::{NaiveDate, TimeDelta};
+use javelin_application::{
+    dtos::{request::UserActivityReportRequest, response::UserActivityReportResponse},
+    interactor::UserActivityReportInteractor,
+    user_activity::UserActivityProjection,
+};
+use javelin_infrastructure::repositories::AccountingPolicyRepositoryImpl;
+
+use crate::error_log::to_user_message;
+
+/// レポートの集計期間（日数）
+pub const USER_ACTIVITY_REPORT_DAYS: i64 = 30;
+
+/// 利用状況レポートコントローラ
+pub struct UserActivityController {
+    interactor: UserActivityReportInteractor<AccountingPolicyRepositoryImpl>,
+}
+
+impl UserActivityController {
+    pub fn new(
+        activity: Arc<dyn UserActivityProjection>,
+        policy_repository: Arc<AccountingPolicyRepositoryImpl>,
+    ) -> Self {
+        Self { interactor: UserActivityReportInteractor::new(activity, policy_repository) }
+    }
+
+    /// 直近の利用状況を取得（管理者のみ）
+    pub async fn report(&self, user_id: String) -> Result<UserActivityReportResponse, String> {
+        let from: NaiveDate =
+            crate::clock::business_date() - TimeDelta::days(USER_ACTIVITY_REPORT_DAYS - 1);
+        self.interactor
+            .execute(UserActivityReportRequest { user_id, from })
+            .await
+            .map_err(to_user_message)
+    }
+}
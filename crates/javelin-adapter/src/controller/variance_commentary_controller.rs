@@ -0,0 +1,39 @@
+// VarianceCommentaryController - 試算表の増減コメントコントローラ
+
+use std::sync::Arc;
+
+use javelin_application::{
+    dtos::{request::SaveVarianceCommentaryRequest, response::VarianceCommentaryDto},
+    interactor::VarianceCommentaryInteractor,
+};
+use javelin_infrastructure::repositories::VarianceCommentaryRepositoryImpl;
+
+use crate::error_log::to_user_message;
+
+/// 増減コメントコントローラ
+pub struct VarianceCommentaryController {
+    interactor: VarianceCommentaryInteractor<VarianceCommentaryRepositoryImpl>,
+}
+
+impl VarianceCommentaryController {
+    pub fn new(repository: Arc<VarianceCommentaryRepositoryImpl>) -> Self {
+        Self { interactor: VarianceCommentaryInteractor::new(repository) }
+    }
+
+    /// 会計期間の増減コメント一覧を取得
+    pub async fn list(
+        &self,
+        fiscal_year: i32,
+        period: u8,
+    ) -> Result<Vec<VarianceCommentaryDto>, String> {
+        self.interactor.list(fiscal_year, period).await.map_err(to_user_message)
+    }
+
+    /// 増減コメントを保存（空の場合は削除）
+    pub async fn save(
+        &self,
+        request: SaveVarianceCommentaryRequest,
+    ) -> Result<Option<VarianceCommentaryDto>, String> {
+        self.interactor.save(request).await.map_err(to_user_message)
+    }
+}
@@ -1,6 +1,7 @@
 // Adapter Layer Errors
 // エラーコード: V-xxxx
 
+use javelin_application::{error::ErrorCategory, error_report::ErrorReport};
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -38,6 +39,12 @@ pub enum AdapterError {
     #[error("[V-3004] Page not implemented: {0}")]
     PageNotImplemented(String),
 
+    #[error("[V-5001] Request timed out after {0}ms")]
+    RequestTimedOut(u64),
+
+    #[error("[V-5002] Request cancelled")]
+    RequestCancelled,
+
     #[error("[V-4001] Application error: {0}")]
     ApplicationError(#[from] javelin_application::error::ApplicationError),
 
@@ -46,3 +53,75 @@ pub enum AdapterError {
 }
 
 pub type AdapterResult<T> = Result<T, AdapterError>;
+
+impl AdapterError {
+    /// エラーコード（アプリケーションエラーは元のコードを優先する）
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::TerminalInitFailed(_) => "V-1001",
+            Self::TerminalCleanupFailed(_) => "V-1002",
+            Self::RawModeEnableFailed(_) => "V-1003",
+            Self::RawModeDisableFailed(_) => "V-1004",
+            Self::RenderingFailed(_) => "V-2001",
+            Self::EventPollingFailed(_) => "V-2002",
+            Self::EventReadFailed(_) => "V-2003",
+            Self::InputValidationFailed(_) => "V-3001",
+            Self::DtoConversionFailed(_) => "V-3002",
+            Self::PageNotFound(_) => "V-3003",
+            Self::PageNotImplemented(_) => "V-3004",
+            Self::RequestTimedOut(_) => "V-5001",
+            Self::RequestCancelled => "V-5002",
+            Self::ApplicationError(error) => error.code(),
+            Self::Unknown(_) => "V-9999",
+        }
+    }
+
+    pub fn category(&self) -> ErrorCategory {
+        match self {
+            Self::InputValidationFailed(_) | Self::DtoConversionFailed(_) => {
+                ErrorCategory::Validation
+            }
+            Self::PageNotFound(_) | Self::PageNotImplemented(_) => ErrorCategory::NotFound,
+            Self::RequestTimedOut(_) | Self::RequestCancelled => ErrorCategory::Conflict,
+            Self::ApplicationError(error) => error.category(),
+            _ => ErrorCategory::System,
+        }
+    }
+
+    /// 利用者向けのメッセージ（エラーコードを含まない）
+    pub fn user_message(&self) -> String {
+        match self {
+            Self::TerminalInitFailed(_) => "端末を初期化できませんでした".to_string(),
+            Self::TerminalCleanupFailed(_) => "端末を元の状態に戻せませんでした".to_string(),
+            Self::RawModeEnableFailed(_) | Self::RawModeDisableFailed(_) => {
+                "端末の入力モードを切り替えられませんでした".to_string()
+            }
+            Self::RenderingFailed(_) => "画面を描画できませんでした".to_string(),
+            Self::EventPollingFailed(_) | Self::EventReadFailed(_) => {
+                "キー入力を読み取れませんでした".to_string()
+            }
+            Self::InputValidationFailed(message) | Self::DtoConversionFailed(message) => {
+                message.clone()
+            }
+            Self::PageNotFound(page) => format!("画面が見つかりません: {}", page),
+            Self::PageNotImplemented(page) => format!("この画面は未実装です: {}", page),
+            Self::RequestTimedOut(millis) => {
+                format!("処理が{}ミリ秒以内に完了しませんでした", millis)
+            }
+            Self::RequestCancelled => "処理が取り消されました".to_string(),
+            Self::ApplicationError(error) => error.user_message(),
+            Self::Unknown(message) => message.clone(),
+        }
+    }
+
+    /// 構造化エラーに変換
+    pub fn report(&self) -> ErrorReport {
+        ErrorReport::from_error(self.category(), self.code(), self.user_message(), self)
+    }
+}
+
+impl From<AdapterError> for ErrorReport {
+    fn from(error: AdapterError) -> Self {
+        error.report()
+    }
+}
@@ -0,0 +1,85 @@
+// ErrorLog - エラーログ
+// 責務: 構造化エラーを問い合わせID付きでファイルへ記録（サポート時の突き合わせ用）
+
+use std::{
+    fs::OpenOptions,
+    io::Write,
+    path::{Path, PathBuf},
+    sync::OnceLock,
+};
+
+use javelin_application::error_report::ErrorReport;
+
+/// ログファイルのパス（起動時に一度だけ設定）
+static ERROR_LOG_PATH: OnceLock<PathBuf> = OnceLock::new();
+
+/// ログファイルの出力先を設定
+pub fn init_error_log(path: impl Into<PathBuf>) {
+    let path = path.into();
+    if let Some(parent) = path.parent() {
+        let _ = std::fs::create_dir_all(parent);
+    }
+    let _ = ERROR_LOG_PATH.set(path);
+}
+
+/// 構造化エラーを記録（出力先が未設定の場合は何もしない）
+///
+/// 記録に失敗しても画面操作は継続させるため、書き込みエラーは無視する。
+pub fn record_error(report: &ErrorReport) {
+    if let Some(path) = ERROR_LOG_PATH.get() {
+        let _ = append(path, report);
+    }
+}
+
+/// エラーを記録し、画面表示用の1行メッセージを返す
+pub fn to_user_message(error: impl Into<ErrorReport>) -> String {
+    let report = error.into();
+    record_error(&report);
+    report.summary()
+}
+
+fn append(path: &Path, report: &ErrorReport) -> std::io::Result<()> {
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "{}", format_entry(report, crate::clock::now().to_rfc3339().as_str()))
+}
+
+/// ログ1行分（タブ区切り: 日時・問い合わせID・コード・分類・メッセージ・原因の連鎖）
+fn format_entry(report: &ErrorReport, timestamp: &str) -> String {
+    format!(
+        "{}\t{}\t{}\t{:?}\t{}\t{}",
+        timestamp,
+        report.correlation_id,
+        report.code,
+        report.category,
+        report.message,
+        report.chain.join(" <- ")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use javelin_application::error::ApplicationError;
+    use javelin_domain::error::DomainError;
+
+    use super::*;
+
+    #[test]
+    fn test_entry_contains_correlation_id_and_full_chain() {
+        let error =
+            ApplicationError::DomainError(DomainError::RepositoryError("disk full".to_string()));
+        let report = ErrorReport::from(&error);
+
+        let entry = format_entry(&report, "2024-04-01T09:00:00+09:00");
+        let fields: Vec<&str> = entry.split('\t').collect();
+
+        assert_eq!(fields[0], "2024-04-01T09:00:00+09:00");
+        assert_eq!(fields[1], report.correlation_id);
+        assert_eq!(fields[2], "D-4002");
+        assert_eq!(fields[3], "Storage");
+        assert_eq!(fields[4], "disk full");
+        assert_eq!(
+            fields[5],
+            "[A-5001] Domain error: [D-4002] Repository error: disk full <- [D-4002] Repository error: disk full"
+        );
+    }
+}
@@ -99,6 +99,45 @@ impl ModifyInputType {
         (true, None)
     }
 
+    /// 入力形式の説明（フィールドヘルプ用）
+    pub fn format_hint(&self) -> &'static str {
+        match self {
+            ModifyInputType::Direct => "自由入力",
+            ModifyInputType::OverlayList => "一覧から選択（iで一覧を表示、j/kで移動、Enterで確定）",
+            ModifyInputType::Calendar => "8桁の数字（YYYYMMDD）、iでカレンダーから選択",
+            ModifyInputType::NumberOnly => "半角数字（カンマは自動表示）",
+            ModifyInputType::BooleanToggle => "スペースキーで切替",
+        }
+    }
+
+    /// 確定時に検証されるルール（フィールドヘルプ用）
+    ///
+    /// `is_char_allowed` と `validate_date_input` の検証内容に対応する。
+    pub fn validation_rules(&self) -> &'static [&'static str] {
+        match self {
+            ModifyInputType::Direct => &[],
+            ModifyInputType::OverlayList => &["一覧にない値は入力できません"],
+            ModifyInputType::Calendar => &[
+                "8桁で入力してください",
+                "2000年代の日付のみ入力できます",
+                "月は01-12の範囲です",
+                "日は月の日数以内です（うるう年考慮）",
+            ],
+            ModifyInputType::NumberOnly => &["0-9の数字のみ入力できます（小数・負数は不可）"],
+            ModifyInputType::BooleanToggle => &["文字入力はできません"],
+        }
+    }
+
+    /// 既定の入力例（フィールドヘルプ用）
+    pub fn default_examples(&self) -> &'static [&'static str] {
+        match self {
+            ModifyInputType::Direct | ModifyInputType::OverlayList => &[],
+            ModifyInputType::Calendar => &["20250401 → 2025-04-01"],
+            ModifyInputType::NumberOnly => &["1500000 → 1,500,000"],
+            ModifyInputType::BooleanToggle => &[],
+        }
+    }
+
     /// 日付入力を表示用にフォーマット（YYYY-MM-DD）
     pub fn format_date_input(input: &str) -> String {
         let len = input.len();
@@ -1,18 +1,24 @@
 // Adapter Layer - 外部入出力変換
 // 依存方向: → Application
 
+pub mod clock;
 pub mod controller;
 pub mod error;
+pub mod error_log;
 pub mod input_mode;
 pub mod navigation;
+pub mod notification;
 pub mod page_states;
 pub mod presenter;
+pub mod startup_report;
 pub mod views;
 
 // Re-export for convenience
 pub use input_mode::{InputMode, JjEscapeDetector, ModifyInputType};
 pub use navigation::{
-    Controllers, NavAction, NavigationStack, PageState, PresenterRegistry, Route,
+    Controllers, NavAction, NavigationStack, PageState, PresenterRegistry, Route, Session,
+    SessionUser,
 };
+pub use notification::{BatchEvent, BatchNotifier, BatchOutcome};
 pub use page_states::*;
 pub use views::*;
@@ -7,6 +7,7 @@ pub mod navigation_stack;
 pub mod page_state;
 pub mod presenter_registry;
 pub mod route;
+pub mod session;
 
 #[cfg(test)]
 mod end_to_end_tests;
@@ -23,3 +24,4 @@ pub use navigation_stack::NavigationStack;
 pub use page_state::PageState;
 pub use presenter_registry::PresenterRegistry;
 pub use route::Route;
+pub use session::{Session, SessionUser};
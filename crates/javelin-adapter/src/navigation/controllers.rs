@@ -3,19 +3,26 @@
 
 use std::sync::Arc;
 
-use javelin_application::interactor::{
-    AdjustAccountsInteractor, ApplyIfrsValuationInteractor, ConsolidateLedgerInteractor,
-    GenerateFinancialStatementsInteractor, GenerateNoteDraftInteractor,
-    GenerateTrialBalanceInteractor, LockClosingPeriodInteractor, PrepareClosingInteractor,
-};
-use javelin_infrastructure::{
-    event_store::EventStore, ledger_query_service_impl::LedgerQueryServiceImpl,
-};
+use javelin_application::projection_events::ProjectionEventBus;
+use javelin_infrastructure::ledger_query_service_impl::LedgerQueryServiceImpl;
 
+use super::Session;
 use crate::controller::{
-    AccountMasterController, ApplicationSettingsController, BatchHistoryController,
-    ClosingController, CompanyMasterController, JournalEntryController, SearchController,
-    SubsidiaryAccountMasterController,
+    AccountActivityController, AccountMasterController, AccountReconciliationController,
+    AccountingPolicyController, ApplicationSettingsController, AuditExportController,
+    AuthenticationController, BalanceAnalysisController, BatchHistoryController,
+    BusinessMetricsController, CalendarMasterController, ClosingController,
+    ClosingTimetableController, CompanyMasterController, ConsistencyCheckController,
+    DimensionMasterController, EntryLinkController, ExportProtectionController,
+    FinancialInstrumentController, InboxController, InitialSetupController,
+    IntegrityAuditController, InventoryWorksheetController, JobQueueController,
+    JournalEntryController, JournalImportController, LedgerAnnotationController, LedgerController,
+    ManagementAccountMappingController, NoteCrossReferenceController, PeriodReopenController,
+    ProjectionConsoleController, RecordUserActionController, ReportArchiveController,
+    ReportParameterHistoryController, SearchController, SequenceAuditController,
+    SessionLogController, StatementLineMappingController, StorageTelemetryController,
+    SubsidiaryAccountMasterController, SupplierInvoiceController, SuspenseClearingController,
+    TablePreferenceController, UserActivityController, VarianceCommentaryController,
 };
 
 /// Type alias for AccountMasterController (no generics needed)
@@ -39,23 +46,131 @@ pub type SearchControllerType = SearchController;
 /// Type alias for BatchHistoryController (no generics needed)
 pub type BatchHistoryControllerType = BatchHistoryController;
 
-/// Type alias for ClosingController with concrete types
-pub type ClosingControllerType = ClosingController<
-    ConsolidateLedgerInteractor<LedgerQueryServiceImpl>,
-    PrepareClosingInteractor<LedgerQueryServiceImpl>,
-    LockClosingPeriodInteractor<EventStore>,
-    GenerateTrialBalanceInteractor<LedgerQueryServiceImpl>,
-    GenerateNoteDraftInteractor<LedgerQueryServiceImpl>,
-    AdjustAccountsInteractor<EventStore, LedgerQueryServiceImpl>,
-    ApplyIfrsValuationInteractor<EventStore, LedgerQueryServiceImpl>,
-    GenerateFinancialStatementsInteractor<LedgerQueryServiceImpl>,
->;
+/// Type alias for CalendarMasterController (no generics needed)
+pub type CalendarMasterControllerType = CalendarMasterController;
+
+/// Type alias for StatementLineMappingController (no generics needed)
+pub type StatementLineMappingControllerType = StatementLineMappingController;
+
+/// Type alias for ManagementAccountMappingController (no generics needed)
+pub type ManagementAccountMappingControllerType = ManagementAccountMappingController;
+
+/// Type alias for TablePreferenceController (no generics needed)
+pub type TablePreferenceControllerType = TablePreferenceController;
+
+/// Type alias for ConsistencyCheckController (no generics needed)
+pub type ConsistencyCheckControllerType = ConsistencyCheckController;
+
+/// Type alias for InboxController (no generics needed)
+pub type InboxControllerType = InboxController;
+
+/// Type alias for SequenceAuditController (no generics needed)
+pub type SequenceAuditControllerType = SequenceAuditController;
+
+/// Type alias for AccountingPolicyController (no generics needed)
+pub type AccountingPolicyControllerType = AccountingPolicyController;
+
+/// Type alias for SuspenseClearingController (no generics needed)
+pub type SuspenseClearingControllerType = SuspenseClearingController;
+
+/// Type alias for SupplierInvoiceController (no generics needed)
+pub type SupplierInvoiceControllerType = SupplierInvoiceController;
+
+/// Type alias for InventoryWorksheetController (no generics needed)
+pub type InventoryWorksheetControllerType = InventoryWorksheetController;
+
+/// Type alias for InitialSetupController (no generics needed)
+pub type InitialSetupControllerType = InitialSetupController;
+
+/// Type alias for FinancialInstrumentController (no generics needed)
+pub type FinancialInstrumentControllerType = FinancialInstrumentController;
+
+/// Type alias for AccountReconciliationController (no generics needed)
+pub type AccountReconciliationControllerType = AccountReconciliationController;
+
+/// Type alias for ProjectionConsoleController (no generics needed)
+pub type ProjectionConsoleControllerType = ProjectionConsoleController;
+
+/// Type alias for BalanceAnalysisController (no generics needed)
+pub type BalanceAnalysisControllerType = BalanceAnalysisController;
+
+/// Type alias for AccountActivityController (no generics needed)
+pub type AccountActivityControllerType = AccountActivityController;
+
+/// Type alias for ReportArchiveController (no generics needed)
+pub type ReportArchiveControllerType = ReportArchiveController;
+
+/// Type alias for JobQueueController (no generics needed)
+pub type JobQueueControllerType = JobQueueController;
+
+/// Type alias for AuditExportController (no generics needed)
+pub type AuditExportControllerType = AuditExportController;
+
+/// Type alias for PeriodReopenController (no generics needed)
+pub type PeriodReopenControllerType = PeriodReopenController;
+
+/// Type alias for StorageTelemetryController (no generics needed)
+pub type StorageTelemetryControllerType = StorageTelemetryController;
+
+/// Type alias for LedgerAnnotationController (no generics needed)
+pub type LedgerAnnotationControllerType = LedgerAnnotationController;
+
+/// Type alias for EntryLinkController (no generics needed)
+pub type EntryLinkControllerType = EntryLinkController;
+
+/// Type alias for JournalImportController (no generics needed)
+pub type JournalImportControllerType = JournalImportController;
+
+/// Type alias for BusinessMetricsController (no generics needed)
+pub type BusinessMetricsControllerType = BusinessMetricsController;
+
+/// Type alias for DimensionMasterController (no generics needed)
+pub type DimensionMasterControllerType = DimensionMasterController;
+
+/// Type alias for ClosingTimetableController (no generics needed)
+pub type ClosingTimetableControllerType = ClosingTimetableController;
+
+/// Type alias for NoteCrossReferenceController (no generics needed)
+pub type NoteCrossReferenceControllerType = NoteCrossReferenceController;
+
+/// Type alias for VarianceCommentaryController (no generics needed)
+pub type VarianceCommentaryControllerType = VarianceCommentaryController;
+
+/// Type alias for RecordUserActionController (no generics needed)
+pub type RecordUserActionControllerType = RecordUserActionController;
+
+/// Type alias for UserActivityController (no generics needed)
+pub type UserActivityControllerType = UserActivityController;
+
+/// Type alias for SessionLogController (no generics needed)
+pub type SessionLogControllerType = SessionLogController;
+
+/// Type alias for IntegrityAuditController (no generics needed)
+pub type IntegrityAuditControllerType = IntegrityAuditController;
+
+/// Type alias for ReportParameterHistoryController (no generics needed)
+pub type ReportParameterHistoryControllerType = ReportParameterHistoryController;
+
+/// Type alias for ExportProtectionController (no generics needed)
+pub type ExportProtectionControllerType = ExportProtectionController;
+
+/// Type alias for AuthenticationController (no generics needed)
+pub type AuthenticationControllerType = AuthenticationController;
+
+/// Type alias for LedgerController with concrete types
+pub type LedgerControllerType = LedgerController<LedgerQueryServiceImpl>;
+
+/// Type alias for ClosingController (no generics needed)
+pub type ClosingControllerType = ClosingController;
 
 /// Container for all controllers
 ///
 /// Bundles all controllers into a single struct for easy passing to pages.
 /// This simplifies the PageState::run() signature and makes it easier to
 /// add new controllers without changing existing page implementations.
+///
+/// Built with a struct literal in app_setup: adding a controller means one
+/// field here and one line there.
 pub struct Controllers {
     pub account_master: Arc<AccountMasterControllerType>,
     pub application_settings: Arc<ApplicationSettingsControllerType>,
@@ -65,30 +180,46 @@ pub struct Controllers {
     pub closing: Arc<ClosingControllerType>,
     pub search: Arc<SearchControllerType>,
     pub batch_history: Arc<BatchHistoryControllerType>,
-}
-
-impl Controllers {
-    /// Create a new Controllers container
-    #[allow(clippy::too_many_arguments)]
-    pub fn new(
-        account_master: Arc<AccountMasterControllerType>,
-        application_settings: Arc<ApplicationSettingsControllerType>,
-        company_master: Arc<CompanyMasterControllerType>,
-        subsidiary_account_master: Arc<SubsidiaryAccountMasterControllerType>,
-        journal_entry: Arc<JournalEntryControllerType>,
-        closing: Arc<ClosingControllerType>,
-        search: Arc<SearchControllerType>,
-        batch_history: Arc<BatchHistoryControllerType>,
-    ) -> Self {
-        Self {
-            account_master,
-            application_settings,
-            company_master,
-            subsidiary_account_master,
-            journal_entry,
-            closing,
-            search,
-            batch_history,
-        }
-    }
+    pub calendar_master: Arc<CalendarMasterControllerType>,
+    pub ledger: Arc<LedgerControllerType>,
+    pub statement_line_mapping: Arc<StatementLineMappingControllerType>,
+    pub management_account_mapping: Arc<ManagementAccountMappingControllerType>,
+    pub table_preference: Arc<TablePreferenceControllerType>,
+    pub consistency_check: Arc<ConsistencyCheckControllerType>,
+    pub inbox: Arc<InboxControllerType>,
+    pub sequence_audit: Arc<SequenceAuditControllerType>,
+    pub accounting_policy: Arc<AccountingPolicyControllerType>,
+    pub suspense_clearing: Arc<SuspenseClearingControllerType>,
+    pub supplier_invoice: Arc<SupplierInvoiceControllerType>,
+    pub authentication: Arc<AuthenticationControllerType>,
+    pub initial_setup: Arc<InitialSetupControllerType>,
+    pub inventory_worksheet: Arc<InventoryWorksheetControllerType>,
+    pub projection_console: Arc<ProjectionConsoleControllerType>,
+    pub balance_analysis: Arc<BalanceAnalysisControllerType>,
+    pub account_activity: Arc<AccountActivityControllerType>,
+    pub report_archive: Arc<ReportArchiveControllerType>,
+    pub job_queue: Arc<JobQueueControllerType>,
+    pub audit_export: Arc<AuditExportControllerType>,
+    pub period_reopen: Arc<PeriodReopenControllerType>,
+    pub financial_instrument: Arc<FinancialInstrumentControllerType>,
+    pub account_reconciliation: Arc<AccountReconciliationControllerType>,
+    pub storage_telemetry: Arc<StorageTelemetryControllerType>,
+    pub ledger_annotation: Arc<LedgerAnnotationControllerType>,
+    pub entry_link: Arc<EntryLinkControllerType>,
+    pub journal_import: Arc<JournalImportControllerType>,
+    pub business_metrics: Arc<BusinessMetricsControllerType>,
+    pub dimension_master: Arc<DimensionMasterControllerType>,
+    pub closing_timetable: Arc<ClosingTimetableControllerType>,
+    pub note_cross_reference: Arc<NoteCrossReferenceControllerType>,
+    pub export_protection: Arc<ExportProtectionControllerType>,
+    pub variance_commentary: Arc<VarianceCommentaryControllerType>,
+    pub report_parameter_history: Arc<ReportParameterHistoryControllerType>,
+    pub record_user_action: Arc<RecordUserActionControllerType>,
+    pub user_activity: Arc<UserActivityControllerType>,
+    pub session_log: Arc<SessionLogControllerType>,
+    pub integrity_audit: Arc<IntegrityAuditControllerType>,
+    /// Login session shared by all pages (current user and idle lock)
+    pub session: Arc<Session>,
+    /// Projection更新通知（表示中の画面の自動再読込用）
+    pub projection_events: Arc<ProjectionEventBus>,
 }
@@ -25,12 +25,12 @@ mod tests {
         // Navigate to Search
         stack.push(Box::new(SearchPageState::new(Arc::clone(&registry))));
         assert_eq!(stack.current().unwrap().route(), Route::Search);
-        assert_eq!(registry.total_count(), 1);
+        assert_eq!(registry.total_count(), 3); // Search + AccountMaster + CalendarMaster presenters
 
         // Navigate back to Home
         stack.pop();
         assert_eq!(stack.current().unwrap().route(), Route::Home);
-        assert_eq!(registry.total_count(), 0); // Search presenter cleaned up
+        assert_eq!(registry.total_count(), 0); // Search presenters cleaned up
     }
 
     #[test]
@@ -46,12 +46,12 @@ mod tests {
         // Navigate to JournalEntry
         stack.push(Box::new(JournalEntryPageState::new(Arc::clone(&registry))));
         assert_eq!(stack.current().unwrap().route(), Route::JournalEntry);
-        assert_eq!(registry.total_count(), 2); // AccountMaster + JournalEntry presenters
+        assert_eq!(registry.total_count(), 3); // AccountMaster + JournalEntry + CalendarMaster presenters
 
         // Navigate back to Home
         stack.pop();
         assert_eq!(stack.current().unwrap().route(), Route::Home);
-        assert_eq!(registry.total_count(), 0); // Both presenters cleaned up
+        assert_eq!(registry.total_count(), 0); // All presenters cleaned up
     }
 
     #[test]
@@ -67,22 +67,22 @@ mod tests {
         // Level 2: Search
         stack.push(Box::new(SearchPageState::new(Arc::clone(&registry))));
         assert_eq!(stack.current().unwrap().route(), Route::Search);
-        assert_eq!(registry.total_count(), 1);
+        assert_eq!(registry.total_count(), 3);
 
         // Level 3: JournalEntry
         stack.push(Box::new(JournalEntryPageState::new(Arc::clone(&registry))));
         assert_eq!(stack.current().unwrap().route(), Route::JournalEntry);
-        assert_eq!(registry.total_count(), 3); // 1 Search + 2 JournalEntry
+        assert_eq!(registry.total_count(), 6); // 3 Search + 3 JournalEntry
 
         // Back to Search
         stack.pop();
         assert_eq!(stack.current().unwrap().route(), Route::Search);
-        assert_eq!(registry.total_count(), 1); // JournalEntry presenters cleaned up
+        assert_eq!(registry.total_count(), 3); // JournalEntry presenters cleaned up
 
         // Back to Home
         stack.pop();
         assert_eq!(stack.current().unwrap().route(), Route::Home);
-        assert_eq!(registry.total_count(), 0); // Search presenter cleaned up
+        assert_eq!(registry.total_count(), 0); // Search presenters cleaned up
     }
 
     #[test]
@@ -98,7 +98,7 @@ mod tests {
         stack.push(Box::new(LedgerPageState::new()));
 
         assert_eq!(stack.current().unwrap().route(), Route::Ledger);
-        assert_eq!(registry.total_count(), 3); // Search + JournalEntry (Ledger has no presenters)
+        assert_eq!(registry.total_count(), 6); // Search + JournalEntry (Ledger has no presenters)
 
         // Navigate back through all levels
         stack.pop(); // Ledger → JournalEntry
@@ -106,7 +106,7 @@ mod tests {
 
         stack.pop(); // JournalEntry → Search
         assert_eq!(stack.current().unwrap().route(), Route::Search);
-        assert_eq!(registry.total_count(), 1);
+        assert_eq!(registry.total_count(), 3);
 
         stack.pop(); // Search → Home
         assert_eq!(stack.current().unwrap().route(), Route::Home);
@@ -125,7 +125,7 @@ mod tests {
         // Home → Search
         stack.push(Box::new(SearchPageState::new(Arc::clone(&registry))));
         assert_eq!(stack.current().unwrap().route(), Route::Search);
-        assert_eq!(registry.total_count(), 1);
+        assert_eq!(registry.total_count(), 3);
 
         // Search → Back → Home
         stack.pop();
@@ -135,7 +135,7 @@ mod tests {
         // Home → JournalEntry
         stack.push(Box::new(JournalEntryPageState::new(Arc::clone(&registry))));
         assert_eq!(stack.current().unwrap().route(), Route::JournalEntry);
-        assert_eq!(registry.total_count(), 2);
+        assert_eq!(registry.total_count(), 3);
 
         // JournalEntry → Back → Home
         stack.pop();
@@ -145,7 +145,7 @@ mod tests {
         // Home → Search (again)
         stack.push(Box::new(SearchPageState::new(Arc::clone(&registry))));
         assert_eq!(stack.current().unwrap().route(), Route::Search);
-        assert_eq!(registry.total_count(), 1);
+        assert_eq!(registry.total_count(), 3);
 
         // Search → Back → Home
         stack.pop();
@@ -195,17 +195,17 @@ mod tests {
         // First Search instance
         stack.push(Box::new(SearchPageState::new(Arc::clone(&registry))));
         assert_eq!(stack.current().unwrap().route(), Route::Search);
-        assert_eq!(registry.total_count(), 1);
+        assert_eq!(registry.total_count(), 3);
 
         // Second Search instance
         stack.push(Box::new(SearchPageState::new(Arc::clone(&registry))));
         assert_eq!(stack.current().unwrap().route(), Route::Search);
-        assert_eq!(registry.total_count(), 2); // Two separate instances
+        assert_eq!(registry.total_count(), 6); // Two separate instances
 
         // Back to first Search
         stack.pop();
         assert_eq!(stack.current().unwrap().route(), Route::Search);
-        assert_eq!(registry.total_count(), 1); // Second instance cleaned up
+        assert_eq!(registry.total_count(), 3); // Second instance cleaned up
 
         // Back to Home
         stack.pop();
@@ -271,25 +271,25 @@ mod tests {
         stack.push(Box::new(HomePageState::new()));
         assert_eq!(registry.total_count(), 0);
 
-        // Search: 1 presenter
+        // Search: 3 presenters
         stack.push(Box::new(SearchPageState::new(Arc::clone(&registry))));
-        assert_eq!(registry.total_count(), 1);
+        assert_eq!(registry.total_count(), 3);
 
-        // JournalEntry: 2 presenters
+        // JournalEntry: 3 presenters
         stack.push(Box::new(JournalEntryPageState::new(Arc::clone(&registry))));
-        assert_eq!(registry.total_count(), 3); // 1 + 2
+        assert_eq!(registry.total_count(), 6); // 3 + 3
 
         // Ledger: 0 presenters
         stack.push(Box::new(LedgerPageState::new()));
-        assert_eq!(registry.total_count(), 3); // Still 1 + 2
+        assert_eq!(registry.total_count(), 6); // Still 3 + 3
 
         // Back to JournalEntry
         stack.pop();
-        assert_eq!(registry.total_count(), 3);
+        assert_eq!(registry.total_count(), 6);
 
         // Back to Search
         stack.pop();
-        assert_eq!(registry.total_count(), 1);
+        assert_eq!(registry.total_count(), 3);
 
         // Back to Home
         stack.pop();
@@ -324,7 +324,7 @@ mod tests {
             // Home → Search
             stack.push(Box::new(HomePageState::new()));
             stack.push(Box::new(SearchPageState::new(Arc::clone(&registry))));
-            assert_eq!(registry.total_count(), 1);
+            assert_eq!(registry.total_count(), 3);
 
             // Search → Back
             stack.pop();
@@ -42,8 +42,8 @@ mod tests {
             let page = SearchPageState::new(Arc::clone(&registry));
             let id = page.route(); // Get some identifier
 
-            // Verify presenter is registered
-            assert_eq!(registry.total_count(), 1);
+            // Verify presenters are registered (Search + AccountMaster + CalendarMaster)
+            assert_eq!(registry.total_count(), 3);
 
             id
         }; // page is dropped here
@@ -66,8 +66,8 @@ mod tests {
             let page2 = JournalEntryPageState::new(Arc::clone(&registry));
             let page3 = SearchPageState::new(Arc::clone(&registry));
 
-            // Should have 4 presenters (1 + 2 + 1)
-            assert_eq!(registry.total_count(), 4);
+            // Should have 9 presenters (3 + 3 + 3)
+            assert_eq!(registry.total_count(), 9);
 
             vec![page1.route(), page2.route(), page3.route()]
         }; // All pages dropped here
@@ -90,14 +90,14 @@ mod tests {
         stack.push(Box::new(SearchPageState::new(Arc::clone(&registry))));
         stack.push(Box::new(JournalEntryPageState::new(Arc::clone(&registry))));
 
-        // Should have 3 presenters
-        assert_eq!(registry.total_count(), 3);
+        // Should have 6 presenters
+        assert_eq!(registry.total_count(), 6);
 
-        // Pop JournalEntry (2 presenters)
+        // Pop JournalEntry (3 presenters)
         stack.pop();
-        assert_eq!(registry.total_count(), 1);
+        assert_eq!(registry.total_count(), 3);
 
-        // Pop Search (1 presenter)
+        // Pop Search (3 presenters)
         stack.pop();
         assert_eq!(registry.total_count(), 0);
 
@@ -116,15 +116,15 @@ mod tests {
         let page2 = SearchPageState::new(Arc::clone(&registry));
         let page3 = SearchPageState::new(Arc::clone(&registry));
 
-        assert_eq!(registry.total_count(), 3);
+        assert_eq!(registry.total_count(), 9);
 
         // Drop page2
         drop(page2);
-        assert_eq!(registry.total_count(), 2);
+        assert_eq!(registry.total_count(), 6);
 
         // Drop page1
         drop(page1);
-        assert_eq!(registry.total_count(), 1);
+        assert_eq!(registry.total_count(), 3);
 
         // Drop page3
         drop(page3);
@@ -147,8 +147,8 @@ mod tests {
             }
         }
 
-        // Count presenters: 5 Search (1 each) + 4 JournalEntry (2 each) = 13
-        assert_eq!(registry.total_count(), 13);
+        // Count presenters: 5 Search (3 each) + 4 JournalEntry (3 each) = 27
+        assert_eq!(registry.total_count(), 27);
 
         // Pop all pages
         for _ in 0..9 {
@@ -175,7 +175,7 @@ mod tests {
             let _page3 = SearchPageState::new(Arc::clone(&registry));
 
             // All should be registered
-            assert_eq!(registry.total_count(), 4); // 1 + 2 + 1
+            assert_eq!(registry.total_count(), 9); // 3 + 3 + 3
         } // All dropped at end of loop
 
         // All should be cleaned up
@@ -195,15 +195,15 @@ mod tests {
         stack.push(Box::new(SearchPageState::new(Arc::clone(&registry))));
         stack.push(Box::new(JournalEntryPageState::new(Arc::clone(&registry))));
 
-        // Should have 6 presenters (1 + 2 + 1 + 2)
-        assert_eq!(registry.total_count(), 6);
+        // Should have 12 presenters (3 + 3 + 3 + 3)
+        assert_eq!(registry.total_count(), 12);
 
         // Pop 2 pages
-        stack.pop(); // JournalEntry (2 presenters)
-        stack.pop(); // Search (1 presenter)
+        stack.pop(); // JournalEntry (3 presenters)
+        stack.pop(); // Search (3 presenters)
 
-        // Should have 3 presenters left
-        assert_eq!(registry.total_count(), 3);
+        // Should have 6 presenters left
+        assert_eq!(registry.total_count(), 6);
 
         // Remaining pages should still work
         assert_eq!(stack.current().unwrap().route(), crate::navigation::Route::JournalEntry);
@@ -219,7 +219,7 @@ mod tests {
         let _page2 = JournalEntryPageState::new(Arc::clone(&registry));
         let _page3 = SearchPageState::new(Arc::clone(&registry));
 
-        assert_eq!(registry.total_count(), 4);
+        assert_eq!(registry.total_count(), 9);
 
         // Clear all presenters
         registry.clear_all();
@@ -261,14 +261,14 @@ mod tests {
             }
 
             {
-                let _search = SearchPageState::new(Arc::clone(&registry)); // 1 presenter
-                assert_eq!(registry.total_count(), 1);
+                let _search = SearchPageState::new(Arc::clone(&registry)); // 3 presenters
+                assert_eq!(registry.total_count(), 3);
             }
             assert_eq!(registry.total_count(), 0);
 
             {
-                let _je = JournalEntryPageState::new(Arc::clone(&registry)); // 2 presenters
-                assert_eq!(registry.total_count(), 2);
+                let _je = JournalEntryPageState::new(Arc::clone(&registry)); // 3 presenters
+                assert_eq!(registry.total_count(), 3);
             }
             assert_eq!(registry.total_count(), 0);
         }
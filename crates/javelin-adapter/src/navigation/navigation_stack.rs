@@ -1,7 +1,9 @@
 // NavigationStack - Stack-based navigation manager
 // Manages the navigation stack with push/pop operations and lifecycle hooks
 
-use super::PageState;
+use std::collections::HashMap;
+
+use super::{PageState, Route};
 
 /// Stack-based navigation manager
 ///
@@ -14,6 +16,13 @@ use super::PageState;
 /// - When popping a page, the previous page's `on_resume()` is called
 /// - This ensures proper state transitions during navigation
 ///
+/// # State Preservation
+///
+/// Pages that opt in via `preserves_state()` are kept when closed with `back()`
+/// instead of being dropped. The next navigation to the same route can take the
+/// preserved page with `take_preserved()`, restoring its criteria, selection and
+/// scroll offsets. `clear_preserved()` discards every preserved page.
+///
 /// # Example
 ///
 /// ```rust,ignore
@@ -28,12 +37,14 @@ use super::PageState;
 /// ```
 pub struct NavigationStack {
     stack: Vec<Box<dyn PageState>>,
+    /// Pages closed via `back()` that asked to keep their state, keyed by route
+    preserved: HashMap<Route, Box<dyn PageState>>,
 }
 
 impl NavigationStack {
     /// Create a new empty navigation stack
     pub fn new() -> Self {
-        Self { stack: Vec::new() }
+        Self { stack: Vec::new(), preserved: HashMap::new() }
     }
 
     /// Push a new page onto the stack
@@ -59,6 +70,34 @@ impl NavigationStack {
         popped
     }
 
+    /// Navigate back, preserving the closed page if it opts in
+    ///
+    /// Behaves like `pop()`, except that a page whose `preserves_state()` returns
+    /// true is paused and kept for `take_preserved()` instead of being dropped.
+    pub fn back(&mut self) {
+        let Some(mut popped) = self.pop() else {
+            return;
+        };
+        if popped.preserves_state() {
+            popped.on_pause();
+            self.preserved.insert(popped.route(), popped);
+        }
+    }
+
+    /// Take the preserved page for the route, if any
+    ///
+    /// Calls `on_restore()` on the page before returning it.
+    pub fn take_preserved(&mut self, route: &Route) -> Option<Box<dyn PageState>> {
+        let mut page = self.preserved.remove(route)?;
+        page.on_restore();
+        Some(page)
+    }
+
+    /// Discard all preserved pages (e.g. when the session changes)
+    pub fn clear_preserved(&mut self) {
+        self.preserved.clear();
+    }
+
     /// Get a mutable reference to the current page (top of stack)
     ///
     /// Returns None if the stack is empty.
@@ -97,6 +136,8 @@ mod tests {
         route: Route,
         pause_count: Arc<Mutex<usize>>,
         resume_count: Arc<Mutex<usize>>,
+        restore_count: Arc<Mutex<usize>>,
+        preserves_state: bool,
     }
 
     impl MockPageState {
@@ -105,8 +146,14 @@ mod tests {
                 route,
                 pause_count: Arc::new(Mutex::new(0)),
                 resume_count: Arc::new(Mutex::new(0)),
+                restore_count: Arc::new(Mutex::new(0)),
+                preserves_state: false,
             }
         }
+
+        fn preserving(route: Route) -> Self {
+            Self { preserves_state: true, ..Self::new(route) }
+        }
     }
 
     impl PageState for MockPageState {
@@ -129,6 +176,14 @@ mod tests {
         fn on_resume(&mut self) {
             *self.resume_count.lock().unwrap() += 1;
         }
+
+        fn preserves_state(&self) -> bool {
+            self.preserves_state
+        }
+
+        fn on_restore(&mut self) {
+            *self.restore_count.lock().unwrap() += 1;
+        }
     }
 
     /// Property 1: Navigation Stack Maintains History
@@ -245,4 +300,33 @@ mod tests {
         assert!(stack.is_empty());
         assert!(stack.current().is_none());
     }
+
+    /// Property: Back Navigation Preserves Opted-In Pages
+    #[test]
+    fn property_back_navigation_preserves_opted_in_pages() {
+        let mut stack = NavigationStack::new();
+
+        let search = Box::new(MockPageState::preserving(Route::Search));
+        let restore_count = search.restore_count.clone();
+
+        stack.push(Box::new(MockPageState::new(Route::Home)));
+        stack.push(search);
+        stack.back();
+        assert_eq!(stack.current().unwrap().route(), Route::Home);
+
+        stack.push(Box::new(MockPageState::new(Route::JournalEntry)));
+        stack.back();
+        assert!(stack.take_preserved(&Route::JournalEntry).is_none());
+
+        let restored = stack.take_preserved(&Route::Search).unwrap();
+        assert_eq!(restored.route(), Route::Search);
+        assert_eq!(*restore_count.lock().unwrap(), 1);
+        // A restored page is no longer preserved
+        assert!(stack.take_preserved(&Route::Search).is_none());
+
+        stack.push(restored);
+        stack.back();
+        stack.clear_preserved();
+        assert!(stack.take_preserved(&Route::Search).is_none());
+    }
 }
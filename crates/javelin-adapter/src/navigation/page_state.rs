@@ -20,6 +20,7 @@ use crate::error::AdapterResult;
 /// 3. **Pause**: `on_pause()` is called when navigating away
 /// 4. **Resume**: `on_resume()` is called when navigating back
 /// 5. **Destruction**: PageState is dropped when permanently removed from stack
+/// 6. **Restore**: `on_restore()` is called when a page preserved on Back is reopened
 ///
 /// # Example
 ///
@@ -76,9 +77,29 @@ pub trait PageState: Send {
     /// or clean up temporary resources.
     fn on_pause(&mut self) {}
 
+    /// Whether this page keeps its state when closed via Back
+    ///
+    /// When true, the navigation stack preserves the page instead of dropping it,
+    /// and reopening the same route restores criteria, selection and scroll offsets.
+    fn preserves_state(&self) -> bool {
+        false
+    }
+
+    /// Called when a page preserved on Back is reopened
+    ///
+    /// Use this to tell the user that previous state was restored
+    /// and how to refresh it explicitly.
+    fn on_restore(&mut self) {}
+
     /// Called when a navigation error occurs
     ///
     /// Use this to display error messages in the page's event log
     /// instead of printing to stderr which breaks the TUI.
     fn on_navigation_error(&mut self, _error_message: &str) {}
+
+    /// Called when an informational notice should be shown
+    ///
+    /// Use this to display status updates (e.g. replica refresh)
+    /// in the page's event log.
+    fn on_notice(&mut self, _message: &str) {}
 }
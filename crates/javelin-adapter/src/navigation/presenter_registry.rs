@@ -10,7 +10,7 @@ use uuid::Uuid;
 
 use crate::presenter::{
     AccountMasterPresenter, ApplicationSettingsPresenter, BatchHistoryPresenter,
-    CompanyMasterPresenter, JournalEntryPresenter, SearchPresenter,
+    CalendarMasterPresenter, CompanyMasterPresenter, JournalEntryPresenter, SearchPresenter,
     SubsidiaryAccountMasterPresenter,
 };
 
@@ -46,6 +46,7 @@ pub struct PresenterRegistry {
     journal_entry_presenters: Arc<RwLock<HashMap<Uuid, Arc<JournalEntryPresenter>>>>,
     account_master_presenters: Arc<RwLock<HashMap<Uuid, Arc<AccountMasterPresenter>>>>,
     company_master_presenters: Arc<RwLock<HashMap<Uuid, Arc<CompanyMasterPresenter>>>>,
+    calendar_master_presenters: Arc<RwLock<HashMap<Uuid, Arc<CalendarMasterPresenter>>>>,
     application_settings_presenters: Arc<RwLock<HashMap<Uuid, Arc<ApplicationSettingsPresenter>>>>,
     subsidiary_account_master_presenters:
         Arc<RwLock<HashMap<Uuid, Arc<SubsidiaryAccountMasterPresenter>>>>,
@@ -60,6 +61,7 @@ impl PresenterRegistry {
             journal_entry_presenters: Arc::new(RwLock::new(HashMap::new())),
             account_master_presenters: Arc::new(RwLock::new(HashMap::new())),
             company_master_presenters: Arc::new(RwLock::new(HashMap::new())),
+            calendar_master_presenters: Arc::new(RwLock::new(HashMap::new())),
             application_settings_presenters: Arc::new(RwLock::new(HashMap::new())),
             subsidiary_account_master_presenters: Arc::new(RwLock::new(HashMap::new())),
             batch_history_presenters: Arc::new(RwLock::new(HashMap::new())),
@@ -146,6 +148,27 @@ impl PresenterRegistry {
         self.company_master_presenters.write().unwrap().remove(&id);
     }
 
+    // Calendar Master Presenter methods
+
+    /// Register a calendar master presenter for a page instance
+    pub fn register_calendar_master_presenter(
+        &self,
+        id: Uuid,
+        presenter: Arc<CalendarMasterPresenter>,
+    ) {
+        self.calendar_master_presenters.write().unwrap().insert(id, presenter);
+    }
+
+    /// Get a calendar master presenter by page instance ID
+    pub fn get_calendar_master_presenter(&self, id: Uuid) -> Option<Arc<CalendarMasterPresenter>> {
+        self.calendar_master_presenters.read().unwrap().get(&id).cloned()
+    }
+
+    /// Unregister a calendar master presenter
+    pub fn unregister_calendar_master_presenter(&self, id: Uuid) {
+        self.calendar_master_presenters.write().unwrap().remove(&id);
+    }
+
     // Application Settings Presenter methods
 
     /// Register an application settings presenter for a page instance
@@ -223,6 +246,7 @@ impl PresenterRegistry {
             + self.journal_entry_presenters.read().unwrap().len()
             + self.account_master_presenters.read().unwrap().len()
             + self.company_master_presenters.read().unwrap().len()
+            + self.calendar_master_presenters.read().unwrap().len()
             + self.application_settings_presenters.read().unwrap().len()
             + self.subsidiary_account_master_presenters.read().unwrap().len()
             + self.batch_history_presenters.read().unwrap().len()
@@ -234,6 +258,7 @@ impl PresenterRegistry {
         self.journal_entry_presenters.write().unwrap().clear();
         self.account_master_presenters.write().unwrap().clear();
         self.company_master_presenters.write().unwrap().clear();
+        self.calendar_master_presenters.write().unwrap().clear();
         self.application_settings_presenters.write().unwrap().clear();
         self.subsidiary_account_master_presenters.write().unwrap().clear();
         self.batch_history_presenters.write().unwrap().clear();
@@ -260,7 +285,7 @@ mod tests {
         // Create dummy channels for presenter
         let (result_tx, _) = tokio::sync::mpsc::channel(100);
         let (error_tx, _) = tokio::sync::mpsc::channel(100);
-        let (progress_tx, _) = tokio::sync::mpsc::channel(100);
+        let (progress_tx, _) = crate::presenter::progress_channel(100);
         let (execution_time_tx, _) = tokio::sync::mpsc::channel(100);
 
         let presenter =
@@ -282,7 +307,7 @@ mod tests {
 
         let (result_tx, _) = tokio::sync::mpsc::channel(100);
         let (error_tx, _) = tokio::sync::mpsc::channel(100);
-        let (progress_tx, _) = tokio::sync::mpsc::channel(100);
+        let (progress_tx, _) = crate::presenter::progress_channel(100);
         let (execution_time_tx, _) = tokio::sync::mpsc::channel(100);
 
         let presenter =
@@ -305,7 +330,7 @@ mod tests {
         let (list_tx, _) = tokio::sync::mpsc::unbounded_channel();
         let (detail_tx, _) = tokio::sync::mpsc::unbounded_channel();
         let (result_tx, _) = tokio::sync::mpsc::unbounded_channel();
-        let (progress_tx, _) = tokio::sync::mpsc::unbounded_channel();
+        let (progress_tx, _) = crate::presenter::progress_channel(100);
 
         let presenter =
             Arc::new(JournalEntryPresenter::new(list_tx, detail_tx, result_tx, progress_tx));
@@ -342,7 +367,7 @@ mod tests {
         // Register search presenter
         let (result_tx, _) = tokio::sync::mpsc::channel(100);
         let (error_tx, _) = tokio::sync::mpsc::channel(100);
-        let (progress_tx, _) = tokio::sync::mpsc::channel(100);
+        let (progress_tx, _) = crate::presenter::progress_channel(100);
         let (execution_time_tx, _) = tokio::sync::mpsc::channel(100);
         let search_presenter =
             Arc::new(SearchPresenter::new(result_tx, error_tx, progress_tx, execution_time_tx));
@@ -352,7 +377,7 @@ mod tests {
         let (list_tx, _) = tokio::sync::mpsc::unbounded_channel();
         let (detail_tx, _) = tokio::sync::mpsc::unbounded_channel();
         let (result_tx, _) = tokio::sync::mpsc::unbounded_channel();
-        let (progress_tx, _) = tokio::sync::mpsc::unbounded_channel();
+        let (progress_tx, _) = crate::presenter::progress_channel(100);
         let je_presenter =
             Arc::new(JournalEntryPresenter::new(list_tx, detail_tx, result_tx, progress_tx));
         registry.register_journal_entry_presenter(je_id, je_presenter);
@@ -375,7 +400,7 @@ mod tests {
         // Register multiple presenters
         let (result_tx, _) = tokio::sync::mpsc::channel(100);
         let (error_tx, _) = tokio::sync::mpsc::channel(100);
-        let (progress_tx, _) = tokio::sync::mpsc::channel(100);
+        let (progress_tx, _) = crate::presenter::progress_channel(100);
         let (execution_time_tx, _) = tokio::sync::mpsc::channel(100);
         let search_presenter =
             Arc::new(SearchPresenter::new(result_tx, error_tx, progress_tx, execution_time_tx));
@@ -406,7 +431,7 @@ mod tests {
                 // Register
                 let (result_tx, _) = tokio::sync::mpsc::channel(100);
                 let (error_tx, _) = tokio::sync::mpsc::channel(100);
-                let (progress_tx, _) = tokio::sync::mpsc::channel(100);
+                let (progress_tx, _) = crate::presenter::progress_channel(100);
                 let (execution_time_tx, _) = tokio::sync::mpsc::channel(100);
                 let presenter = Arc::new(SearchPresenter::new(
                     result_tx,
@@ -16,12 +16,18 @@ pub enum Route {
     /// 102 - Journal entry search
     Search,
 
+    /// 103 - Supplier invoice register (AP journal drafts and payment status)
+    SupplierInvoice,
+
     /// 401 - Ledger view
     Ledger,
 
     /// Ledger detail view (drill-down from Ledger)
     LedgerDetail,
 
+    /// 402 - Reports hub (parametrized standard reports with recent parameters)
+    ReportHub,
+
     /// 201 - Ledger consolidation
     LedgerConsolidation,
 
@@ -34,9 +40,18 @@ pub enum Route {
     /// 301E - Closing preparation execution
     ClosingPreparationExecution,
 
+    /// 301A - Balance anomaly review (period-over-period comparison)
+    BalanceAnalysis,
+
+    /// 301H - Account activity heatmap (posting volume per account per month)
+    AccountActivity,
+
     /// 302 - Closing lock
     ClosingLock,
 
+    /// 302R - Account reconciliation workspace (balance substantiation before lock)
+    AccountReconciliation,
+
     /// 303 - Trial balance
     TrialBalance,
 
@@ -55,12 +70,24 @@ pub enum Route {
     /// 306E - IFRS valuation execution
     IfrsValuationExecution,
 
+    /// 306W - Inventory write-down worksheet
+    InventoryWorksheet,
+
     /// 307 - Financial statement generation
     FinancialStatement,
 
     /// 307E - Financial statement generation execution
     FinancialStatementExecution,
 
+    /// 307A - Archived report snapshots (signed-off statements)
+    ReportArchive,
+
+    /// 307N - Note cross-references (note sections and the captions referring to them)
+    NoteCrossReference,
+
+    /// 308 - Closing timetable (deadlines, owners and progress board)
+    ClosingTimetable,
+
     /// 901 - Account master management
     AccountMaster,
 
@@ -75,4 +102,49 @@ pub enum Route {
 
     /// 905 - Data export
     DataExport,
+
+    /// 906 - Statement line mapping management
+    StatementLineMapping,
+
+    /// 906M - Management chart of accounts mapping (alternate reporting view)
+    ManagementAccountMapping,
+
+    /// 907 - Maintenance (projection consistency check)
+    Maintenance,
+
+    /// 907P - Projection query console (read-only, administrators only)
+    ProjectionConsole,
+
+    /// 907J - Long-running job queue (consolidation, projection rebuild, import)
+    JobQueue,
+
+    /// 907U - User activity report (usage patterns and slowest operations, administrators only)
+    UserActivity,
+
+    /// 907A - Access log and active sessions (force logout, administrators only)
+    SessionLog,
+
+    /// 908 - Accounting policy (rounding and negative-number presentation)
+    AccountingPolicy,
+
+    /// 909 - Analysis dimension master (projects, segments and other line tags)
+    DimensionMaster,
+
+    /// 910 - System information (startup diagnostics)
+    SystemInfo,
+
+    /// 501 - Inbox (pending approvals and rejected drafts)
+    Inbox,
+
+    /// Inbox item detail (drill-down from Inbox)
+    InboxDetail,
+
+    /// 502 - KPI dashboard (daily counts and latencies of key use cases)
+    KpiDashboard,
+
+    /// Login / session unlock
+    Login,
+
+    /// First-run setup wizard (company, fiscal year, currency, chart of accounts, admin user)
+    SetupWizard,
 }
@@ -0,0 +1,232 @@
+// Session - ログインセッション
+// 責務: ログイン中の利用者の保持と無操作時の自動ロック
+//
+// 各画面は操作ユーザ（created_by / approved_by 等）をセッションから取得する。
+// 入力待ちは `poll_input` 経由で行い、最終操作時刻を記録する。
+// 無操作が `idle_timeout` を超えるとロックされ、ログイン画面で再認証するまで操作できない。
+// 管理者に強制ログアウトされた場合も同様にロックし、利用者を外す。
+
+use std::{
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use crossterm::event;
+
+use crate::error::{AdapterError, AdapterResult};
+
+/// 既定の自動ロックまでの無操作時間
+pub const DEFAULT_IDLE_TIMEOUT: Duration = Duration::from_secs(15 * 60);
+
+/// ログイン中の利用者
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SessionUser {
+    pub user_id: String,
+    pub display_name: String,
+}
+
+struct SessionState {
+    user: Option<SessionUser>,
+    /// セッション記録のID（ログイン時に記録したもの）
+    session_id: Option<String>,
+    locked: bool,
+    last_activity: Instant,
+    /// 次に表示するログイン画面への通知
+    notice: Option<String>,
+}
+
+/// ログインセッション
+pub struct Session {
+    state: Mutex<SessionState>,
+    idle_timeout: Duration,
+}
+
+impl Session {
+    pub fn new(idle_timeout: Duration) -> Self {
+        Self {
+            state: Mutex::new(SessionState {
+                user: None,
+                session_id: None,
+                locked: false,
+                last_activity: Instant::now(),
+                notice: None,
+            }),
+            idle_timeout,
+        }
+    }
+
+    /// ログイン（ロック中の場合は解除）
+    pub fn start(&self, user: SessionUser) {
+        let mut state = self.state.lock().unwrap();
+        state.user = Some(user);
+        state.locked = false;
+        state.last_activity = Instant::now();
+    }
+
+    /// ログアウト
+    pub fn end(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.user = None;
+        state.session_id = None;
+        state.locked = false;
+    }
+
+    /// ログイン時に記録したセッションIDを設定
+    pub fn set_session_id(&self, session_id: String) {
+        self.state.lock().unwrap().session_id = Some(session_id);
+    }
+
+    /// 記録中のセッションID
+    pub fn session_id(&self) -> Option<String> {
+        self.state.lock().unwrap().session_id.clone()
+    }
+
+    /// 記録中のセッションが強制終了された場合にロックし、利用者を外す
+    ///
+    /// 既に別のセッションでログインし直している場合は何もしない。
+    pub fn revoke(&self, session_id: &str, notice: impl Into<String>) {
+        let mut state = self.state.lock().unwrap();
+        if state.session_id.as_deref() == Some(session_id) {
+            state.user = None;
+            state.session_id = None;
+            state.locked = true;
+            state.notice = Some(notice.into());
+        }
+    }
+
+    /// ログイン画面に表示する通知を取り出す
+    pub fn take_notice(&self) -> Option<String> {
+        self.state.lock().unwrap().notice.take()
+    }
+
+    /// ログイン中の利用者（ロック中も保持する）
+    pub fn user(&self) -> Option<SessionUser> {
+        self.state.lock().unwrap().user.clone()
+    }
+
+    /// 操作ユーザID（未ログインの場合は空文字）
+    pub fn user_id(&self) -> String {
+        self.user().map(|user| user.user_id).unwrap_or_default()
+    }
+
+    /// ログイン中かつロックされていないか
+    pub fn is_active(&self) -> bool {
+        let state = self.state.lock().unwrap();
+        state.user.is_some() && !state.locked
+    }
+
+    /// ロック中か
+    pub fn is_locked(&self) -> bool {
+        self.state.lock().unwrap().locked
+    }
+
+    /// 自動ロックまでの無操作時間
+    pub fn idle_timeout(&self) -> Duration {
+        self.idle_timeout
+    }
+
+    /// 操作があったことを記録
+    pub fn touch(&self) {
+        self.state.lock().unwrap().last_activity = Instant::now();
+    }
+
+    /// 無操作時間を超えていればロックし、ロック中かを返す
+    ///
+    /// 各画面はイベントループごとに呼び出し、trueの場合はログイン画面へ遷移する。
+    pub fn lock_if_idle(&self) -> bool {
+        self.lock_if_idle_at(Instant::now())
+    }
+
+    fn lock_if_idle_at(&self, now: Instant) -> bool {
+        let mut state = self.state.lock().unwrap();
+        if state.user.is_some()
+            && !state.locked
+            && now.saturating_duration_since(state.last_activity) >= self.idle_timeout
+        {
+            state.locked = true;
+        }
+        state.locked
+    }
+
+    /// 入力イベントを待機し、入力があれば操作として記録
+    pub fn poll_input(&self, timeout: Duration) -> AdapterResult<bool> {
+        let available = event::poll(timeout).map_err(AdapterError::EventReadFailed)?;
+        if available {
+            self.touch();
+        }
+        Ok(available)
+    }
+}
+
+impl Default for Session {
+    fn default() -> Self {
+        Self::new(DEFAULT_IDLE_TIMEOUT)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn user(user_id: &str) -> SessionUser {
+        SessionUser { user_id: user_id.to_string(), display_name: user_id.to_string() }
+    }
+
+    #[test]
+    fn test_session_lifecycle() {
+        let session = Session::default();
+        assert!(!session.is_active());
+        assert_eq!(session.user_id(), "");
+
+        session.start(user("tanaka"));
+        assert!(session.is_active());
+        assert_eq!(session.user_id(), "tanaka");
+
+        session.end();
+        assert!(!session.is_active());
+        assert_eq!(session.user(), None);
+    }
+
+    #[test]
+    fn test_locks_after_idle_timeout() {
+        let session = Session::new(Duration::from_secs(60));
+        let now = Instant::now();
+
+        // 未ログインの場合はロックしない
+        assert!(!session.lock_if_idle_at(now + Duration::from_secs(120)));
+
+        session.start(user("tanaka"));
+        assert!(!session.lock_if_idle_at(Instant::now() + Duration::from_secs(30)));
+        assert!(session.lock_if_idle_at(Instant::now() + Duration::from_secs(61)));
+        assert!(session.is_locked());
+        assert!(!session.is_active());
+        // ロック中も利用者は保持する
+        assert_eq!(session.user_id(), "tanaka");
+
+        // 再認証でロック解除
+        session.start(user("tanaka"));
+        assert!(session.is_active());
+        assert!(!session.lock_if_idle());
+    }
+
+    #[test]
+    fn test_revoke_locks_and_clears_user() {
+        let session = Session::default();
+        session.start(user("tanaka"));
+        session.set_session_id("S-1".to_string());
+
+        // 記録中でないセッションは対象外
+        session.revoke("S-0", "強制ログアウト");
+        assert!(session.is_active());
+
+        session.revoke("S-1", "強制ログアウト");
+        assert!(session.is_locked());
+        assert_eq!(session.user(), None);
+        assert_eq!(session.session_id(), None);
+        assert_eq!(session.take_notice().as_deref(), Some("強制ログアウト"));
+        assert_eq!(session.take_notice(), None);
+
+        session.start(user("suzuki"));
+        assert!(session.is_active());
+    }
+}
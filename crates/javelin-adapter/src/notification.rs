@@ -0,0 +1,219 @@
+// Notification - バッチ完了通知
+// 責務: 長時間のバッチ処理の完了・失敗を利用者に知らせる
+//
+// 端末ベルは常に利用可能。OSのデスクトップ通知は `desktop-notification`
+// フィーチャ有効時のみ送信する（Linux: notify-send / macOS: osascript）。
+// 通知方法はアプリケーション設定で切り替える。
+
+use std::{
+    io::Write,
+    sync::atomic::{AtomicBool, Ordering},
+    time::Duration,
+};
+
+/// 完了通知を行う最小処理時間（これより短いバッチは完了を通知しない）
+pub const DEFAULT_MIN_BATCH_DURATION: Duration = Duration::from_secs(5);
+
+/// バッチ処理の結果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BatchOutcome {
+    Completed,
+    Failed(String),
+}
+
+/// バッチ処理の完了・失敗イベント
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BatchEvent {
+    pub batch_name: String,
+    pub outcome: BatchOutcome,
+    pub elapsed: Duration,
+}
+
+impl BatchEvent {
+    pub fn completed(batch_name: impl Into<String>, elapsed: Duration) -> Self {
+        Self { batch_name: batch_name.into(), outcome: BatchOutcome::Completed, elapsed }
+    }
+
+    pub fn failed(
+        batch_name: impl Into<String>,
+        message: impl Into<String>,
+        elapsed: Duration,
+    ) -> Self {
+        Self {
+            batch_name: batch_name.into(),
+            outcome: BatchOutcome::Failed(message.into()),
+            elapsed,
+        }
+    }
+
+    /// 通知のタイトル
+    pub fn title(&self) -> String {
+        match self.outcome {
+            BatchOutcome::Completed => format!("{} が完了しました", self.batch_name),
+            BatchOutcome::Failed(_) => format!("{} が失敗しました", self.batch_name),
+        }
+    }
+
+    /// 通知の本文
+    pub fn body(&self) -> String {
+        let elapsed = format!("処理時間 {}秒", self.elapsed.as_secs());
+        match &self.outcome {
+            BatchOutcome::Completed => elapsed,
+            BatchOutcome::Failed(message) => format!("{} / {}", message, elapsed),
+        }
+    }
+}
+
+/// バッチ完了通知
+///
+/// 通知方法（ベル・デスクトップ通知）は実行中に切り替えられる。
+/// いずれも無効の場合は何もしない。
+pub struct BatchNotifier {
+    bell_enabled: AtomicBool,
+    desktop_enabled: AtomicBool,
+    min_duration: Duration,
+}
+
+impl BatchNotifier {
+    pub fn new() -> Self {
+        Self {
+            bell_enabled: AtomicBool::new(false),
+            desktop_enabled: AtomicBool::new(false),
+            min_duration: DEFAULT_MIN_BATCH_DURATION,
+        }
+    }
+
+    /// 完了通知を行う最小処理時間を設定
+    pub fn with_min_duration(mut self, min_duration: Duration) -> Self {
+        self.min_duration = min_duration;
+        self
+    }
+
+    /// 通知方法を設定
+    pub fn configure(&self, bell_enabled: bool, desktop_enabled: bool) {
+        self.bell_enabled.store(bell_enabled, Ordering::Relaxed);
+        self.desktop_enabled.store(desktop_enabled, Ordering::Relaxed);
+    }
+
+    pub fn bell_enabled(&self) -> bool {
+        self.bell_enabled.load(Ordering::Relaxed)
+    }
+
+    pub fn desktop_enabled(&self) -> bool {
+        self.desktop_enabled.load(Ordering::Relaxed)
+    }
+
+    /// デスクトップ通知に対応したビルドか
+    pub fn desktop_supported() -> bool {
+        cfg!(feature = "desktop-notification")
+    }
+
+    /// イベントを通知すべきか（失敗は処理時間に関わらず通知する）
+    pub fn should_notify(&self, event: &BatchEvent) -> bool {
+        match event.outcome {
+            BatchOutcome::Completed => event.elapsed >= self.min_duration,
+            BatchOutcome::Failed(_) => true,
+        }
+    }
+
+    /// バッチイベントを通知
+    ///
+    /// 通知の失敗はバッチ処理の結果に影響させないため、エラーは無視する。
+    pub fn notify(&self, event: &BatchEvent) {
+        if !self.should_notify(event) {
+            return;
+        }
+
+        if self.bell_enabled() {
+            let mut stdout = std::io::stdout();
+            let _ = stdout.write_all(b"\x07");
+            let _ = stdout.flush();
+        }
+
+        if self.desktop_enabled() {
+            send_desktop_notification(&event.title(), &event.body());
+        }
+    }
+}
+
+impl Default for BatchNotifier {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "desktop-notification")]
+fn send_desktop_notification(title: &str, body: &str) {
+    use std::process::{Command, Stdio};
+
+    let mut command = if cfg!(target_os = "macos") {
+        let script = format!(
+            "display notification \"{}\" with title \"Javelin\" subtitle \"{}\"",
+            escape_apple_script(body),
+            escape_apple_script(title)
+        );
+        let mut command = Command::new("osascript");
+        command.arg("-e").arg(script);
+        command
+    } else {
+        let mut command = Command::new("notify-send");
+        command.arg("--app-name=Javelin").arg(title).arg(body);
+        command
+    };
+
+    let spawned = command.stdin(Stdio::null()).stdout(Stdio::null()).stderr(Stdio::null()).spawn();
+
+    // 終了待ちは別スレッドで行い、ゾンビプロセスを残さない
+    if let Ok(mut child) = spawned {
+        std::thread::spawn(move || {
+            let _ = child.wait();
+        });
+    }
+}
+
+#[cfg(not(feature = "desktop-notification"))]
+fn send_desktop_notification(_title: &str, _body: &str) {}
+
+#[cfg(feature = "desktop-notification")]
+fn escape_apple_script(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_short_completed_batch_is_not_notified() {
+        let notifier = BatchNotifier::new().with_min_duration(Duration::from_secs(5));
+
+        assert!(
+            !notifier.should_notify(&BatchEvent::completed("元帳集約", Duration::from_secs(1)))
+        );
+        assert!(notifier.should_notify(&BatchEvent::completed("元帳集約", Duration::from_secs(5))));
+        assert!(notifier.should_notify(&BatchEvent::failed(
+            "元帳集約",
+            "タイムアウト",
+            Duration::from_secs(1)
+        )));
+    }
+
+    #[test]
+    fn test_configure_channels() {
+        let notifier = BatchNotifier::new();
+        assert!(!notifier.bell_enabled());
+        assert!(!notifier.desktop_enabled());
+
+        notifier.configure(true, false);
+        assert!(notifier.bell_enabled());
+        assert!(!notifier.desktop_enabled());
+    }
+
+    #[test]
+    fn test_event_message() {
+        let event =
+            BatchEvent::failed("試算表生成", "期間が締められていません", Duration::from_secs(12));
+        assert_eq!(event.title(), "試算表生成 が失敗しました");
+        assert_eq!(event.body(), "期間が締められていません / 処理時間 12秒");
+    }
+}
@@ -1,46 +1,102 @@
 // Page States module - PageState implementations for each screen
 // Each screen has its own PageState that manages state and channels independently
 
+pub mod account_activity_page_state;
 pub mod account_adjustment_execution_page_state;
 pub mod account_adjustment_page_state;
 pub mod account_master_page_state;
+pub mod account_reconciliation_page_state;
+pub mod accounting_policy_page_state;
 pub mod application_settings_page_state;
+pub mod balance_analysis_page_state;
 pub mod closing_lock_page_state;
 pub mod closing_preparation_execution_page_state;
 pub mod closing_preparation_page_state;
+pub mod closing_timetable_page_state;
+pub mod dimension_master_page_state;
 pub mod financial_statement_execution_page_state;
 pub mod financial_statement_page_state;
 pub mod home_page_state;
 pub mod ifrs_valuation_execution_page_state;
 pub mod ifrs_valuation_page_state;
+pub mod inbox_detail_page_state;
+pub mod inbox_page_state;
+pub mod inventory_worksheet_page_state;
+pub mod job_queue_page_state;
 pub mod journal_entry_page_state;
+pub mod journal_import_page_state;
+pub mod kpi_dashboard_page_state;
 pub mod ledger_consolidation_execution_page_state;
 pub mod ledger_consolidation_page_state;
 pub mod ledger_detail_page_state;
 pub mod ledger_page_state;
+pub mod login_page_state;
+pub mod maintenance_page_state;
+pub mod management_account_mapping_page_state;
+pub mod note_cross_reference_page_state;
 pub mod note_draft_page_state;
+pub mod period_selector;
+pub mod projection_console_page_state;
+pub mod protected_export;
+pub mod report_archive_page_state;
+pub mod report_hub_page_state;
+pub mod report_parameters;
 pub mod search_page_state;
+pub mod session_log_page_state;
+pub mod setup_wizard_page_state;
+pub mod statement_line_mapping_page_state;
 pub mod subsidiary_account_master_page_state;
+pub mod supplier_invoice_page_state;
+pub mod system_info_page_state;
+pub mod table_preference_sync;
 pub mod trial_balance_page_state;
+pub mod user_activity_page_state;
 
+pub use account_activity_page_state::AccountActivityPageState;
 pub use account_adjustment_execution_page_state::AccountAdjustmentExecutionPageState;
 pub use account_adjustment_page_state::AccountAdjustmentPageState;
 pub use account_master_page_state::AccountMasterPageState;
+pub use account_reconciliation_page_state::AccountReconciliationPageState;
+pub use accounting_policy_page_state::AccountingPolicyPageState;
 pub use application_settings_page_state::ApplicationSettingsPageState;
+pub use balance_analysis_page_state::BalanceAnalysisPageState;
 pub use closing_lock_page_state::ClosingLockPageState;
 pub use closing_preparation_execution_page_state::ClosingPreparationExecutionPageState;
 pub use closing_preparation_page_state::ClosingPreparationPageState;
+pub use closing_timetable_page_state::ClosingTimetablePageState;
+pub use dimension_master_page_state::DimensionMasterPageState;
 pub use financial_statement_execution_page_state::FinancialStatementExecutionPageState;
 pub use financial_statement_page_state::FinancialStatementPageState;
 pub use home_page_state::HomePageState;
 pub use ifrs_valuation_execution_page_state::IfrsValuationExecutionPageState;
 pub use ifrs_valuation_page_state::IfrsValuationPageState;
+pub use inbox_detail_page_state::InboxDetailPageState;
+pub use inbox_page_state::InboxPageState;
+pub use inventory_worksheet_page_state::InventoryWorksheetPageState;
+pub use job_queue_page_state::JobQueuePageState;
 pub use journal_entry_page_state::JournalEntryPageState;
+pub use journal_import_page_state::JournalImportPageState;
+pub use kpi_dashboard_page_state::KpiDashboardPageState;
 pub use ledger_consolidation_execution_page_state::LedgerConsolidationExecutionPageState;
 pub use ledger_consolidation_page_state::LedgerConsolidationPageState;
 pub use ledger_detail_page_state::LedgerDetailPageState;
 pub use ledger_page_state::LedgerPageState;
+pub use login_page_state::LoginPageState;
+pub use maintenance_page_state::MaintenancePageState;
+pub use management_account_mapping_page_state::ManagementAccountMappingPageState;
+pub use note_cross_reference_page_state::NoteCrossReferencePageState;
 pub use note_draft_page_state::NoteDraftPageState;
+pub use projection_console_page_state::ProjectionConsolePageState;
+pub use protected_export::ProtectedExport;
+pub use report_archive_page_state::ReportArchivePageState;
+pub use report_hub_page_state::ReportHubPageState;
 pub use search_page_state::SearchPageState;
+pub use session_log_page_state::SessionLogPageState;
+pub use setup_wizard_page_state::SetupWizardPageState;
+pub use statement_line_mapping_page_state::StatementLineMappingPageState;
 pub use subsidiary_account_master_page_state::SubsidiaryAccountMasterPageState;
+pub use supplier_invoice_page_state::SupplierInvoicePageState;
+pub use system_info_page_state::SystemInfoPageState;
+pub use table_preference_sync::TablePreferenceSync;
 pub use trial_balance_page_state::TrialBalancePageState;
+pub use user_activity_page_state::UserActivityPageState;
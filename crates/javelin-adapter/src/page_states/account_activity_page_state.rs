@@ -0,0 +1,161 @@
+// AccountActivityPageState - 勘定科目別の記帳量ヒートマップ画面の状態
+// 責務: 集計期間の切替と集計の実行
+
+use std::{sync::Arc, time::Instant};
+
+use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use javelin_application::dtos::{
+    request::DEFAULT_ACTIVITY_MONTHS, response::AnalyzeAccountActivityResponse,
+};
+use ratatui::DefaultTerminal;
+use tokio::sync::mpsc;
+
+use crate::{
+    error::AdapterResult,
+    navigation::{Controllers, NavAction, PageState, Route},
+    page_states::{period_selector::PeriodSelector, report_parameters::StandardReport},
+    views::{layouts::render_guarded, pages::AccountActivityPage},
+};
+
+/// 集計結果
+enum ActivityUpdate {
+    Completed(AnalyzeAccountActivityResponse),
+    Failed(String),
+}
+
+pub struct AccountActivityPageState {
+    page: AccountActivityPage,
+    /// 集計期間の最終月
+    period_selector: PeriodSelector,
+    loading: bool,
+    update_tx: mpsc::UnboundedSender<ActivityUpdate>,
+    update_rx: mpsc::UnboundedReceiver<ActivityUpdate>,
+    /// データロード済みフラグ
+    data_loaded: bool,
+}
+
+impl AccountActivityPageState {
+    /// 帳票メニューから条件が渡された場合はその月、それ以外は業務日付の当月までを集計する
+    pub fn new() -> Self {
+        let (update_tx, update_rx) = mpsc::unbounded_channel();
+        Self {
+            page: AccountActivityPage::new(),
+            period_selector: PeriodSelector::handed_off_or_current(StandardReport::AccountActivity),
+            loading: false,
+            update_tx,
+            update_rx,
+            data_loaded: false,
+        }
+    }
+
+    /// 集計を開始
+    fn load_activity(&mut self, controllers: &Controllers) {
+        if self.loading {
+            return;
+        }
+        self.loading = true;
+        self.page.start_loading();
+
+        let controller = Arc::clone(&controllers.account_activity);
+        let recorder = Arc::clone(&controllers.record_user_action);
+        let user_id = controllers.session.user_id();
+        let update_tx = self.update_tx.clone();
+        let (fiscal_year, period) =
+            (self.period_selector.fiscal_year(), self.period_selector.period());
+
+        tokio::spawn(async move {
+            let started = Instant::now();
+            let result =
+                controller.analyze(&user_id, fiscal_year, period, DEFAULT_ACTIVITY_MONTHS).await;
+            let _ = recorder
+                .record_action(user_id, "AccountActivity", "analyze", started.elapsed())
+                .await;
+            let update = match result {
+                Ok(response) => ActivityUpdate::Completed(response),
+                Err(e) => ActivityUpdate::Failed(e),
+            };
+            let _ = update_tx.send(update);
+        });
+    }
+
+    /// 集計結果を反映
+    fn poll_updates(&mut self) {
+        while let Ok(update) = self.update_rx.try_recv() {
+            self.loading = false;
+            match update {
+                ActivityUpdate::Completed(response) => self.page.set_result(response),
+                ActivityUpdate::Failed(message) => self.page.set_error(message),
+            }
+        }
+    }
+}
+
+impl PageState for AccountActivityPageState {
+    fn route(&self) -> Route {
+        Route::AccountActivity
+    }
+
+    fn run(
+        &mut self,
+        terminal: &mut DefaultTerminal,
+        controllers: &Controllers,
+    ) -> AdapterResult<NavAction> {
+        if !self.data_loaded {
+            self.load_activity(controllers);
+            self.data_loaded = true;
+        }
+
+        loop {
+            self.poll_updates();
+            self.page.tick();
+
+            let period_label = self.period_selector.label();
+            terminal
+                .draw(|frame| {
+                    render_guarded(frame, |frame| self.page.render(frame, &period_label));
+                })
+                .map_err(|e| crate::error::AdapterError::RenderingFailed(e.to_string()))?;
+
+            if controllers.session.lock_if_idle() {
+                return Ok(NavAction::Go(Route::Login));
+            }
+
+            if controllers.session.poll_input(std::time::Duration::from_millis(100))?
+                && let Event::Key(key) =
+                    event::read().map_err(crate::error::AdapterError::EventReadFailed)?
+            {
+                if key.kind != KeyEventKind::Press {
+                    continue;
+                }
+
+                match key.code {
+                    KeyCode::Esc => return Ok(NavAction::Back),
+                    KeyCode::Char('j') | KeyCode::Down => self.page.select_next(),
+                    KeyCode::Char('k') | KeyCode::Up => self.page.select_previous(),
+                    KeyCode::Char('h') | KeyCode::Left if !self.loading => {
+                        self.period_selector.shift(false);
+                        self.load_activity(controllers);
+                    }
+                    KeyCode::Char('l') | KeyCode::Right if !self.loading => {
+                        self.period_selector.shift(true);
+                        self.load_activity(controllers);
+                    }
+                    KeyCode::Char('m') => self.page.toggle_metric(),
+                    KeyCode::Char('s') => self.page.toggle_spikes_only(),
+                    KeyCode::Char('r') => self.load_activity(controllers),
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    fn on_navigation_error(&mut self, error_message: &str) {
+        self.page.add_error(error_message);
+    }
+}
+
+impl Default for AccountActivityPageState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
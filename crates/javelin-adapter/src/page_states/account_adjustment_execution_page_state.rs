@@ -1,22 +1,78 @@
 // AccountAdjustmentExecutionPageState - 勘定補正実行画面の状態管理
-// 責務: 勘定補正実行画面の状態とイベント処理
+// 責務: 勘定補正実行画面の状態とイベント処理（試算・実行）
+
+use std::sync::Arc;
 
 use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use javelin_application::dtos::{request::AdjustAccountsRequest, response::AdjustAccountsResponse};
 use ratatui::DefaultTerminal;
+use tokio::sync::mpsc;
 
 use crate::{
     error::AdapterResult,
+    error_log::to_user_message,
     navigation::{Controllers, NavAction, Route, page_state::PageState},
-    views::pages::AccountAdjustmentExecutionPage,
+    page_states::period_selector::PeriodSelector,
+    views::{layouts::render_guarded, pages::AccountAdjustmentExecutionPage},
 };
 
 pub struct AccountAdjustmentExecutionPageState {
     page: AccountAdjustmentExecutionPage,
+    period_selector: PeriodSelector,
+    running: bool,
+    result_tx: mpsc::UnboundedSender<Result<AdjustAccountsResponse, String>>,
+    result_rx: mpsc::UnboundedReceiver<Result<AdjustAccountsResponse, String>>,
 }
 
 impl AccountAdjustmentExecutionPageState {
     pub fn new() -> Self {
-        Self { page: AccountAdjustmentExecutionPage::new() }
+        let (result_tx, result_rx) = mpsc::unbounded_channel();
+        Self {
+            page: AccountAdjustmentExecutionPage::new(),
+            period_selector: PeriodSelector::current(),
+            running: false,
+            result_tx,
+            result_rx,
+        }
+    }
+
+    /// 対象月を前後に移動
+    fn shift_period(&mut self, forward: bool) {
+        self.period_selector.shift(forward);
+        self.page.set_period(&self.period_selector.label());
+    }
+
+    /// 勘定補正を開始（試算の場合は仕訳・イベントを記録しない）
+    fn start_execution(&mut self, controllers: &Controllers, dry_run: bool) {
+        if self.running {
+            return;
+        }
+        self.running = true;
+        self.page.start_execution(&self.period_selector.label(), dry_run);
+
+        let controller = Arc::clone(&controllers.closing);
+        let result_tx = self.result_tx.clone();
+        let request = AdjustAccountsRequest {
+            fiscal_year: self.period_selector.fiscal_year(),
+            period: self.period_selector.period(),
+            dry_run,
+        };
+
+        tokio::spawn(async move {
+            let result = controller.adjust_accounts(request).await.map_err(to_user_message);
+            let _ = result_tx.send(result);
+        });
+    }
+
+    /// 処理結果を反映
+    fn poll_results(&mut self) {
+        while let Ok(result) = self.result_rx.try_recv() {
+            self.running = false;
+            match result {
+                Ok(response) => self.page.set_result(&response),
+                Err(error) => self.page.set_execution_error(error),
+            }
+        }
     }
 }
 
@@ -28,19 +84,23 @@ impl PageState for AccountAdjustmentExecutionPageState {
     fn run(
         &mut self,
         terminal: &mut DefaultTerminal,
-        _controllers: &Controllers,
+        controllers: &Controllers,
     ) -> AdapterResult<NavAction> {
         loop {
+            self.poll_results();
             self.page.tick();
 
             terminal
                 .draw(|frame| {
-                    self.page.render(frame);
+                    render_guarded(frame, |frame| self.page.render(frame));
                 })
                 .map_err(|e| crate::error::AdapterError::RenderingFailed(e.to_string()))?;
 
-            if event::poll(std::time::Duration::from_millis(100))
-                .map_err(crate::error::AdapterError::EventReadFailed)?
+            if controllers.session.lock_if_idle() {
+                return Ok(NavAction::Go(Route::Login));
+            }
+
+            if controllers.session.poll_input(std::time::Duration::from_millis(100))?
                 && let Event::Key(key) =
                     event::read().map_err(crate::error::AdapterError::EventReadFailed)?
             {
@@ -50,7 +110,10 @@ impl PageState for AccountAdjustmentExecutionPageState {
 
                 match key.code {
                     KeyCode::Esc => return Ok(NavAction::Back),
-                    KeyCode::Char('s') => self.page.start_execution(),
+                    KeyCode::Char('t') => self.start_execution(controllers, true),
+                    KeyCode::Char('s') => self.start_execution(controllers, false),
+                    KeyCode::Char('h') | KeyCode::Left if !self.running => self.shift_period(false),
+                    KeyCode::Char('l') | KeyCode::Right if !self.running => self.shift_period(true),
                     KeyCode::Char('j') | KeyCode::Down => self.page.select_next(),
                     KeyCode::Char('k') | KeyCode::Up => self.page.select_previous(),
                     _ => {}
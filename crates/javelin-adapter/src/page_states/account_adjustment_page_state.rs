@@ -2,23 +2,39 @@
 
 use std::sync::Arc;
 
+use chrono::Datelike;
 use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use javelin_application::dtos::{request::UnsettledLinksRequest, response::UnsettledLinksResponse};
 use ratatui::DefaultTerminal;
+use tokio::sync::mpsc;
 use uuid::Uuid;
 
 use crate::{
     error::AdapterResult,
     navigation::{Controllers, NavAction, PageState, PresenterRegistry, Route},
-    presenter::BatchHistoryPresenter,
-    views::pages::AccountAdjustmentPage,
+    page_states::report_parameters::{self, PARAM_AS_OF_DATE, StandardReport},
+    presenter::{BatchHistoryPresenter, SuspenseAgingViewModel},
+    views::{layouts::render_guarded, pages::AccountAdjustmentPage},
 };
 
+/// 仮勘定の滞留状況・未決済の計上・前払に関する非同期処理の結果
+enum AgingUpdate {
+    Loaded(Result<SuspenseAgingViewModel, String>),
+    Accepted(Result<String, String>),
+    /// 期末時点で未決済の計上・前払
+    Unsettled(Result<UnsettledLinksResponse, String>),
+}
+
 pub struct AccountAdjustmentPageState {
     page: AccountAdjustmentPage,
     page_id: Uuid,
     registry: Arc<PresenterRegistry>,
     result_rx: tokio::sync::mpsc::Receiver<crate::presenter::BatchHistoryViewModel>,
     error_rx: tokio::sync::mpsc::Receiver<String>,
+    aging_tx: mpsc::UnboundedSender<AgingUpdate>,
+    aging_rx: mpsc::UnboundedReceiver<AgingUpdate>,
+    /// 滞留状況の基準日（帳票メニューで指定された場合。未指定は業務日付）
+    aging_as_of: Option<String>,
 }
 
 impl AccountAdjustmentPageState {
@@ -40,12 +56,105 @@ impl AccountAdjustmentPageState {
             let _ = controller.handle_get_history(page_id, batch_type).await;
         });
 
-        Self {
+        let (aging_tx, aging_rx) = mpsc::unbounded_channel();
+        let aging_as_of = report_parameters::take_handed_off(StandardReport::SuspenseAging)
+            .and_then(|mut values| values.remove(PARAM_AS_OF_DATE));
+
+        let mut state = Self {
             page,
             page_id,
             registry,
             result_rx: channels.result_rx,
             error_rx: channels.error_rx,
+            aging_tx,
+            aging_rx,
+            aging_as_of,
+        };
+        // 帳票メニューから基準日が渡された場合は滞留状況を表示して開く
+        if state.aging_as_of.is_some() {
+            state.page.show_aging();
+            state.load_aging(controllers);
+        }
+        state
+    }
+
+    /// 基準日時点の仮勘定の滞留状況を読み込む
+    fn load_aging(&self, controllers: &Controllers) {
+        let controller = Arc::clone(&controllers.suspense_clearing);
+        let accounting_policy = Arc::clone(&controllers.accounting_policy);
+        let tx = self.aging_tx.clone();
+        let as_of_date = self
+            .aging_as_of
+            .clone()
+            .unwrap_or_else(|| crate::clock::business_date().format("%Y-%m-%d").to_string());
+
+        tokio::spawn(async move {
+            let amount_format = accounting_policy.amount_format().await.unwrap_or_default();
+            let result = controller
+                .load_aging(as_of_date)
+                .await
+                .map(|response| SuspenseAgingViewModel::from_response(&response, &amount_format));
+            let _ = tx.send(AgingUpdate::Loaded(result));
+        });
+    }
+
+    /// 業務日付の月末時点で未決済の計上・前払を読み込む
+    fn load_unsettled(&self, controllers: &Controllers) {
+        let controller = Arc::clone(&controllers.entry_link);
+        let tx = self.aging_tx.clone();
+        let business_date = crate::clock::business_date();
+        let request = UnsettledLinksRequest {
+            fiscal_year: business_date.year(),
+            period: business_date.month() as u8,
+        };
+
+        tokio::spawn(async move {
+            let _ = tx.send(AgingUpdate::Unsettled(controller.unsettled(request).await));
+        });
+    }
+
+    /// 選択中の仮勘定計上について提案された整理仕訳を起票
+    fn accept_suggestion(&mut self, controllers: &Controllers) {
+        let Some(item) = self.page.selected_aging_item() else {
+            return;
+        };
+        if item.suggestion.is_none() {
+            self.page.set_aging_status("整理仕訳の提案がありません", true);
+            return;
+        }
+
+        let controller = Arc::clone(&controllers.suspense_clearing);
+        let tx = self.aging_tx.clone();
+        let entry_id = item.entry_id.clone();
+        let line_number = item.line_number;
+        let entry_number = item.entry_number.clone();
+        let user_id = controllers.session.user_id();
+
+        tokio::spawn(async move {
+            let result = controller.accept_suggestion(user_id, entry_id, line_number).await.map(
+                |response| {
+                    format!(
+                        "{} の整理仕訳を下書き起票しました（証憑番号 {}）",
+                        entry_number, response.voucher_number
+                    )
+                },
+            );
+            let _ = tx.send(AgingUpdate::Accepted(result));
+        });
+    }
+
+    fn poll_aging_updates(&mut self) {
+        while let Ok(update) = self.aging_rx.try_recv() {
+            match update {
+                AgingUpdate::Loaded(Ok(view_model)) => self.page.set_aging(view_model),
+                AgingUpdate::Loaded(Err(e)) => self.page.set_aging_error(e),
+                AgingUpdate::Accepted(Ok(message)) => self.page.set_aging_status(message, false),
+                AgingUpdate::Accepted(Err(e)) => {
+                    self.page.set_aging_status(format!("起票に失敗しました: {}", e), true)
+                }
+                AgingUpdate::Unsettled(Ok(response)) => self.page.set_unsettled(response),
+                AgingUpdate::Unsettled(Err(e)) => self.page.set_unsettled_error(e),
+            }
         }
     }
 }
@@ -58,7 +167,7 @@ impl PageState for AccountAdjustmentPageState {
     fn run(
         &mut self,
         terminal: &mut DefaultTerminal,
-        _controllers: &Controllers,
+        controllers: &Controllers,
     ) -> AdapterResult<NavAction> {
         loop {
             if let Ok(result) = self.result_rx.try_recv() {
@@ -73,16 +182,20 @@ impl PageState for AccountAdjustmentPageState {
                 self.page.set_error(error);
             }
 
+            self.poll_aging_updates();
             self.page.tick();
 
             terminal
                 .draw(|frame| {
-                    self.page.render(frame);
+                    render_guarded(frame, |frame| self.page.render(frame));
                 })
                 .map_err(|e| crate::error::AdapterError::RenderingFailed(e.to_string()))?;
 
-            if event::poll(std::time::Duration::from_millis(100))
-                .map_err(crate::error::AdapterError::EventReadFailed)?
+            if controllers.session.lock_if_idle() {
+                return Ok(NavAction::Go(Route::Login));
+            }
+
+            if controllers.session.poll_input(std::time::Duration::from_millis(100))?
                 && let Event::Key(key) =
                     event::read().map_err(crate::error::AdapterError::EventReadFailed)?
             {
@@ -90,11 +203,46 @@ impl PageState for AccountAdjustmentPageState {
                     continue;
                 }
 
+                if self.page.is_showing_unsettled() {
+                    match key.code {
+                        KeyCode::Esc | KeyCode::Char('u') => self.page.hide_unsettled(),
+                        KeyCode::Char('r') => {
+                            self.page.show_unsettled();
+                            self.load_unsettled(controllers);
+                        }
+                        _ => {}
+                    }
+                    continue;
+                }
+
+                if self.page.is_showing_aging() {
+                    match key.code {
+                        KeyCode::Esc | KeyCode::Char('a') => self.page.hide_aging(),
+                        KeyCode::Enter => self.accept_suggestion(controllers),
+                        KeyCode::Char('r') => {
+                            self.page.show_aging();
+                            self.load_aging(controllers);
+                        }
+                        KeyCode::Char('j') | KeyCode::Down => self.page.select_next(),
+                        KeyCode::Char('k') | KeyCode::Up => self.page.select_previous(),
+                        _ => {}
+                    }
+                    continue;
+                }
+
                 match key.code {
                     KeyCode::Esc => return Ok(NavAction::Back),
                     KeyCode::Char('e') => {
                         return Ok(NavAction::Go(Route::AccountAdjustmentExecution));
                     }
+                    KeyCode::Char('a') => {
+                        self.page.show_aging();
+                        self.load_aging(controllers);
+                    }
+                    KeyCode::Char('u') => {
+                        self.page.show_unsettled();
+                        self.load_unsettled(controllers);
+                    }
                     KeyCode::Char('j') | KeyCode::Down => self.page.select_next(),
                     KeyCode::Char('k') | KeyCode::Up => self.page.select_previous(),
                     _ => {}
@@ -1,18 +1,40 @@
 // AccountMasterPageState - 勘定科目マスタ画面の状態
+// 責務: 勘定科目一覧の表示と名称・有効状態の変更、変更履歴の表示と変更の取消し
 
 use std::sync::Arc;
 
+use crossterm::event::KeyCode;
 use javelin_application::dtos::request::LoadAccountMasterRequest;
 use ratatui::DefaultTerminal;
+use tokio::sync::mpsc;
 use uuid::Uuid;
 
 use crate::{
     error::AdapterResult,
     navigation::{Controllers, NavAction, PageState, PresenterRegistry, Route},
-    presenter::{AccountMasterPresenter, AccountMasterViewModel},
-    views::pages::AccountMasterPage,
+    presenter::{AccountMasterPresenter, AccountMasterViewModel, MasterChangeHistoryViewModel},
+    views::{layouts::render_guarded, pages::AccountMasterPage},
 };
 
+/// 一覧の取得条件（無効化した勘定科目も変更・取消しできるよう含める）
+const LOAD_REQUEST: LoadAccountMasterRequest =
+    LoadAccountMasterRequest { filter: None, active_only: false };
+
+/// 変更操作の結果
+enum AccountMasterUpdate {
+    Saved(String),
+    SaveFailed(String),
+    History(MasterChangeHistoryViewModel),
+    HistoryFailed(String),
+}
+
+/// 名称変更中の勘定科目
+struct RenameInput {
+    code: String,
+    is_active: bool,
+    value: String,
+}
+
 /// 勘定科目マスタ画面の状態
 pub struct AccountMasterPageState {
     /// Unique identifier for presenter registration
@@ -33,6 +55,11 @@ pub struct AccountMasterPageState {
     is_loading: bool,
     /// データロード済みフラグ
     data_loaded: bool,
+    update_tx: mpsc::UnboundedSender<AccountMasterUpdate>,
+    update_rx: mpsc::UnboundedReceiver<AccountMasterUpdate>,
+    input: Option<RenameInput>,
+    /// 変更履歴を表示中の勘定科目（コード・表示名）
+    history_target: Option<(String, String)>,
 }
 
 impl AccountMasterPageState {
@@ -45,6 +72,7 @@ impl AccountMasterPageState {
 
         // Register presenter
         registry.register_account_master_presenter(id, presenter);
+        let (update_tx, update_rx) = mpsc::unbounded_channel();
 
         Self {
             id,
@@ -56,6 +84,10 @@ impl AccountMasterPageState {
             selected_index: 0,
             is_loading: true,
             data_loaded: false,
+            update_tx,
+            update_rx,
+            input: None,
+            history_target: None,
         }
     }
 
@@ -67,6 +99,161 @@ impl AccountMasterPageState {
         }
     }
 
+    /// キャッシュを破棄して勘定科目マスタを再読込
+    fn reload(&mut self, controllers: &Controllers) {
+        self.is_loading = true;
+        let controller = Arc::clone(&controllers.account_master);
+        let page_id = self.id;
+
+        tokio::spawn(async move {
+            let _ = controller.handle_reload_account_master(page_id, LOAD_REQUEST).await;
+        });
+    }
+
+    /// 変更操作の結果を反映
+    fn poll_updates(&mut self, controllers: &Controllers) {
+        while let Ok(update) = self.update_rx.try_recv() {
+            match update {
+                AccountMasterUpdate::Saved(message) => {
+                    self.page.set_status_message(message);
+                    self.reload(controllers);
+                    if self.page.history_mut().is_visible() {
+                        self.load_history(controllers);
+                    }
+                }
+                AccountMasterUpdate::SaveFailed(message) => {
+                    self.page.set_status_message(format!("更新に失敗しました: {}", message));
+                }
+                AccountMasterUpdate::History(view_model) => {
+                    self.page.history_mut().show(view_model);
+                }
+                AccountMasterUpdate::HistoryFailed(message) => {
+                    self.page
+                        .set_status_message(format!("変更履歴を取得できませんでした: {}", message));
+                }
+            }
+        }
+    }
+
+    /// 選択中の勘定科目の名称変更を開始
+    fn start_rename(&mut self) {
+        if let Some(item) = self.page.selected_item() {
+            self.input = Some(RenameInput {
+                code: item.code.clone(),
+                is_active: item.is_active,
+                value: item.name.clone(),
+            });
+        }
+    }
+
+    /// 名称変更中のキー操作
+    fn handle_input_key(&mut self, code: KeyCode, controllers: &Controllers) {
+        let Some(input) = self.input.as_mut() else {
+            return;
+        };
+        match code {
+            KeyCode::Esc => self.input = None,
+            KeyCode::Enter => {
+                if let Some(input) = self.input.take() {
+                    self.update(controllers, input.code, input.value, input.is_active);
+                }
+            }
+            KeyCode::Backspace => {
+                input.value.pop();
+            }
+            KeyCode::Char(c) => input.value.push(c),
+            _ => {}
+        }
+    }
+
+    /// 選択中の勘定科目の有効・無効を切り替える
+    fn toggle_selected(&self, controllers: &Controllers) {
+        if let Some(item) = self.page.selected_item() {
+            self.update(controllers, item.code.clone(), item.name.clone(), !item.is_active);
+        }
+    }
+
+    fn update(&self, controllers: &Controllers, code: String, name: String, is_active: bool) {
+        let controller = Arc::clone(&controllers.account_master);
+        let changed_by = controllers.session.user_id();
+        let update_tx = self.update_tx.clone();
+
+        tokio::spawn(async move {
+            let message = format!("{} を変更しました", code);
+            let update = match controller.update_account(code, name, is_active, changed_by).await {
+                Ok(()) => AccountMasterUpdate::Saved(message),
+                Err(e) => AccountMasterUpdate::SaveFailed(e),
+            };
+            let _ = update_tx.send(update);
+        });
+    }
+
+    /// 選択中の勘定科目の変更履歴を表示
+    fn open_history(&mut self, controllers: &Controllers) {
+        if let Some(item) = self.page.selected_item() {
+            self.history_target = Some((item.code.clone(), format!("{} {}", item.code, item.name)));
+            self.load_history(controllers);
+        }
+    }
+
+    fn load_history(&self, controllers: &Controllers) {
+        let Some((code, title)) = self.history_target.clone() else {
+            return;
+        };
+        let controller = Arc::clone(&controllers.account_master);
+        let update_tx = self.update_tx.clone();
+
+        tokio::spawn(async move {
+            let update = match controller.history(code).await {
+                Ok(changes) => AccountMasterUpdate::History(
+                    MasterChangeHistoryViewModel::from_dtos(title, &changes),
+                ),
+                Err(e) => AccountMasterUpdate::HistoryFailed(e),
+            };
+            let _ = update_tx.send(update);
+        });
+    }
+
+    /// 変更履歴表示中のキー操作
+    fn handle_history_key(&mut self, code: KeyCode, controllers: &Controllers) {
+        match code {
+            KeyCode::Esc => {
+                self.page.history_mut().hide();
+                self.history_target = None;
+            }
+            KeyCode::Up | KeyCode::Char('k') => self.page.history_mut().select_previous(),
+            KeyCode::Down | KeyCode::Char('j') => self.page.history_mut().select_next(),
+            KeyCode::Char('u') => self.revert_selected(controllers),
+            _ => {}
+        }
+    }
+
+    /// 選択中の変更を取り消す（取り消せない変更の場合は案内のみ）
+    fn revert_selected(&mut self, controllers: &Controllers) {
+        let Some((code, _)) = self.history_target.clone() else {
+            return;
+        };
+        let Some(sequence) = self.page.history_mut().selected_revertible() else {
+            self.page.set_status_message(
+                "この変更より後の変更が残っています。新しい変更から順に取り消してください",
+            );
+            return;
+        };
+
+        let controller = Arc::clone(&controllers.account_master);
+        let changed_by = controllers.session.user_id();
+        let update_tx = self.update_tx.clone();
+
+        tokio::spawn(async move {
+            let message = format!("{} の変更 #{} を取り消しました", code, sequence);
+            let update = match controller.revert_change(code, sequence, changed_by).await {
+                Ok(()) => AccountMasterUpdate::Saved(message),
+                Err(e) => AccountMasterUpdate::SaveFailed(e),
+            };
+            let _ = update_tx.send(update);
+        });
+    }
+
     /// 総ページ数を取得
     fn total_pages(&self) -> usize {
         let total_items = self.page.total_items();
@@ -126,40 +313,57 @@ impl PageState for AccountMasterPageState {
             let page_id = self.id;
 
             tokio::spawn(async move {
-                let request = LoadAccountMasterRequest { filter: None, active_only: true };
-                let _ = controller.handle_load_account_master(page_id, request).await;
+                let _ = controller.handle_load_account_master(page_id, LOAD_REQUEST).await;
             });
         }
 
         loop {
             // Poll for data updates
             self.poll_data();
+            self.poll_updates(controllers);
 
             // Render
             terminal
                 .draw(|frame| {
-                    self.page.render(frame);
+                    let input = self.input.as_ref().map(|input| input.value.as_str());
+                    render_guarded(frame, |frame| self.page.render(frame, input));
                 })
                 .map_err(|e| crate::error::AdapterError::RenderingFailed(e.to_string()))?;
 
+            if controllers.session.lock_if_idle() {
+                return Ok(NavAction::Go(Route::Login));
+            }
+
             // Handle events
-            if crossterm::event::poll(std::time::Duration::from_millis(100))
-                .map_err(crate::error::AdapterError::EventReadFailed)?
+            if controllers.session.poll_input(std::time::Duration::from_millis(100))?
                 && let crossterm::event::Event::Key(key) =
                     crossterm::event::read().map_err(crate::error::AdapterError::EventReadFailed)?
             {
-                use crossterm::event::{KeyCode, KeyEventKind};
+                use crossterm::event::KeyEventKind;
 
                 if key.kind != KeyEventKind::Press {
                     continue;
                 }
 
+                if self.input.is_some() {
+                    self.handle_input_key(key.code, controllers);
+                    continue;
+                }
+                if self.page.history_mut().is_visible() {
+                    self.handle_history_key(key.code, controllers);
+                    continue;
+                }
+
                 match key.code {
                     KeyCode::Esc => return Ok(NavAction::Back),
                     KeyCode::Up | KeyCode::Char('k') => self.move_up(),
                     KeyCode::Down | KeyCode::Char('j') => self.move_down(),
                     KeyCode::Left | KeyCode::Char('h') => self.prev_page(),
                     KeyCode::Right | KeyCode::Char('l') => self.next_page(),
+                    KeyCode::Char('r') => self.reload(controllers),
+                    KeyCode::Char('e') => self.start_rename(),
+                    KeyCode::Char('t') => self.toggle_selected(controllers),
+                    KeyCode::Char('H') => self.open_history(controllers),
                     _ => {}
                 }
             }
@@ -0,0 +1,293 @@
+// AccountReconciliationPageState - 勘定照合ワークスペース画面の状態
+// 責務: 対象月の切替、裏付け明細の追加・削除と照合済登録
+
+use std::sync::Arc;
+
+use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use javelin_application::dtos::{
+    request::AccountReconciliationTarget, response::AccountReconciliationResponse,
+};
+use ratatui::DefaultTerminal;
+use tokio::sync::mpsc;
+
+use crate::{
+    controller::AccountReconciliationController,
+    error::AdapterResult,
+    navigation::{Controllers, NavAction, PageState, Route},
+    page_states::period_selector::PeriodSelector,
+    views::{layouts::render_guarded, pages::AccountReconciliationPage},
+};
+
+/// 読込・更新の結果
+enum ReconciliationUpdate {
+    Loaded(Vec<AccountReconciliationResponse>),
+    Info(String),
+    /// 更新処理の失敗（一覧は読み直す）
+    Rejected(String),
+    Failed(String),
+}
+
+pub struct AccountReconciliationPageState {
+    page: AccountReconciliationPage,
+    period_selector: PeriodSelector,
+    /// 入力中の裏付け明細（内容,金額[,証憑]）
+    item_input: Option<String>,
+    loading: bool,
+    update_tx: mpsc::UnboundedSender<ReconciliationUpdate>,
+    update_rx: mpsc::UnboundedReceiver<ReconciliationUpdate>,
+    /// データロード済みフラグ
+    data_loaded: bool,
+}
+
+impl AccountReconciliationPageState {
+    pub fn new() -> Self {
+        let (update_tx, update_rx) = mpsc::unbounded_channel();
+        Self {
+            page: AccountReconciliationPage::new(),
+            period_selector: PeriodSelector::current(),
+            item_input: None,
+            loading: false,
+            update_tx,
+            update_rx,
+            data_loaded: false,
+        }
+    }
+
+    /// 選択中の科目の照合対象
+    fn selected_target(&self) -> Option<AccountReconciliationTarget> {
+        self.page.selected().map(|reconciliation| AccountReconciliationTarget {
+            account_code: reconciliation.account_code.clone(),
+            fiscal_year: self.period_selector.fiscal_year(),
+            period: self.period_selector.period(),
+        })
+    }
+
+    /// 照合状況を読込
+    fn load(&mut self, controllers: &Controllers) {
+        self.execute(controllers, |_, _| async { Ok(None) });
+    }
+
+    /// 更新処理を実行し、照合状況を読み直す
+    ///
+    /// 更新処理は成功時に表示するメッセージ（任意）を返す。
+    fn execute<F, Fut>(&mut self, controllers: &Controllers, action: F)
+    where
+        F: FnOnce(Arc<AccountReconciliationController>, String) -> Fut + Send + 'static,
+        Fut: std::future::Future<Output = Result<Option<String>, String>> + Send,
+    {
+        if self.loading {
+            return;
+        }
+        self.loading = true;
+        self.page.start_loading();
+
+        let controller = Arc::clone(&controllers.account_reconciliation);
+        let user_id = controllers.session.user_id();
+        let update_tx = self.update_tx.clone();
+        let (fiscal_year, period) =
+            (self.period_selector.fiscal_year(), self.period_selector.period());
+
+        tokio::spawn(async move {
+            match action(Arc::clone(&controller), user_id).await {
+                Ok(Some(message)) => {
+                    let _ = update_tx.send(ReconciliationUpdate::Info(message));
+                }
+                Ok(None) => {}
+                Err(e) => {
+                    let _ = update_tx.send(ReconciliationUpdate::Rejected(e));
+                }
+            }
+            let update = match controller.list(fiscal_year, period).await {
+                Ok(reconciliations) => ReconciliationUpdate::Loaded(reconciliations),
+                Err(e) => ReconciliationUpdate::Failed(e),
+            };
+            let _ = update_tx.send(update);
+        });
+    }
+
+    /// 入力中の裏付け明細を追加
+    fn submit_item(&mut self, controllers: &Controllers) {
+        let Some(input) = self.item_input.take() else {
+            return;
+        };
+        let Some(target) = self.selected_target() else {
+            return;
+        };
+        self.execute(controllers, move |controller, _| async move {
+            let response = controller.add_item(target, &input).await?;
+            Ok(Some(format!("{}: 裏付け明細を追加しました", response.account_code)))
+        });
+    }
+
+    /// 末尾の裏付け明細を削除
+    fn remove_last_item(&mut self, controllers: &Controllers) {
+        let Some(item_count) = self.page.selected().map(|r| r.items.len()) else {
+            return;
+        };
+        let Some(target) = self.selected_target() else {
+            return;
+        };
+        if item_count == 0 {
+            self.page
+                .add_error(format!("{}: 削除する裏付け明細がありません", target.account_code));
+            return;
+        }
+        self.execute(controllers, move |controller, _| async move {
+            let response = controller.remove_item(target, item_count - 1).await?;
+            Ok(Some(format!("{}: 裏付け明細を削除しました", response.account_code)))
+        });
+    }
+
+    fn mark_reconciled(&mut self, controllers: &Controllers) {
+        let Some(target) = self.selected_target() else {
+            return;
+        };
+        self.execute(controllers, move |controller, user_id| async move {
+            let response = controller.mark_reconciled(target, user_id).await?;
+            Ok(Some(format!("{}: 照合済にしました", response.account_code)))
+        });
+    }
+
+    fn reopen(&mut self, controllers: &Controllers) {
+        let Some(target) = self.selected_target() else {
+            return;
+        };
+        self.execute(controllers, move |controller, _| async move {
+            let response = controller.reopen(target).await?;
+            Ok(Some(format!("{}: 照合を取り消しました", response.account_code)))
+        });
+    }
+
+    /// 選択中の科目の照合必須を切り替え
+    fn toggle_required(&mut self, controllers: &Controllers) {
+        let Some((account_code, required)) =
+            self.page.selected().map(|r| (r.account_code.clone(), !r.required))
+        else {
+            return;
+        };
+        self.execute(controllers, move |controller, _| async move {
+            controller.set_required(&account_code, required).await?;
+            Ok(Some(if required {
+                format!("{}: 締日固定前の照合を必須にしました", account_code)
+            } else {
+                format!("{}: 照合必須を解除しました", account_code)
+            }))
+        });
+    }
+
+    /// 読込・更新の結果を反映
+    fn poll_updates(&mut self) {
+        while let Ok(update) = self.update_rx.try_recv() {
+            match update {
+                ReconciliationUpdate::Loaded(reconciliations) => {
+                    self.loading = false;
+                    self.page.set_reconciliations(reconciliations);
+                }
+                ReconciliationUpdate::Info(message) => self.page.add_info(message),
+                ReconciliationUpdate::Rejected(message) => self.page.add_error(message),
+                ReconciliationUpdate::Failed(message) => {
+                    self.loading = false;
+                    self.page.set_error(message);
+                }
+            }
+        }
+    }
+
+    /// 明細入力中のキー操作
+    fn handle_input_key(&mut self, code: KeyCode, controllers: &Controllers) {
+        let Some(input) = self.item_input.as_mut() else {
+            return;
+        };
+        match code {
+            KeyCode::Esc => self.item_input = None,
+            KeyCode::Enter => self.submit_item(controllers),
+            KeyCode::Backspace => {
+                input.pop();
+            }
+            KeyCode::Char(c) => input.push(c),
+            _ => {}
+        }
+    }
+}
+
+impl PageState for AccountReconciliationPageState {
+    fn route(&self) -> Route {
+        Route::AccountReconciliation
+    }
+
+    fn run(
+        &mut self,
+        terminal: &mut DefaultTerminal,
+        controllers: &Controllers,
+    ) -> AdapterResult<NavAction> {
+        if !self.data_loaded {
+            self.load(controllers);
+            self.data_loaded = true;
+        }
+
+        loop {
+            self.poll_updates();
+            self.page.tick();
+
+            let period_label = self.period_selector.label();
+            terminal
+                .draw(|frame| {
+                    render_guarded(frame, |frame| {
+                        self.page.render(frame, &period_label, self.item_input.as_deref())
+                    });
+                })
+                .map_err(|e| crate::error::AdapterError::RenderingFailed(e.to_string()))?;
+
+            if controllers.session.lock_if_idle() {
+                return Ok(NavAction::Go(Route::Login));
+            }
+
+            if controllers.session.poll_input(std::time::Duration::from_millis(100))?
+                && let Event::Key(key) =
+                    event::read().map_err(crate::error::AdapterError::EventReadFailed)?
+            {
+                if key.kind != KeyEventKind::Press {
+                    continue;
+                }
+
+                if self.item_input.is_some() {
+                    self.handle_input_key(key.code, controllers);
+                    continue;
+                }
+
+                match key.code {
+                    KeyCode::Esc => return Ok(NavAction::Back),
+                    KeyCode::Char('j') | KeyCode::Down => self.page.select_next(),
+                    KeyCode::Char('k') | KeyCode::Up => self.page.select_previous(),
+                    KeyCode::Char('h') | KeyCode::Left if !self.loading => {
+                        self.period_selector.shift(false);
+                        self.load(controllers);
+                    }
+                    KeyCode::Char('l') | KeyCode::Right if !self.loading => {
+                        self.period_selector.shift(true);
+                        self.load(controllers);
+                    }
+                    KeyCode::Char('a') if !self.loading && self.page.selected().is_some() => {
+                        self.item_input = Some(String::new());
+                    }
+                    KeyCode::Char('d') => self.remove_last_item(controllers),
+                    KeyCode::Char('m') => self.mark_reconciled(controllers),
+                    KeyCode::Char('u') => self.reopen(controllers),
+                    KeyCode::Char('c') => self.toggle_required(controllers),
+                    KeyCode::Char('r') => self.load(controllers),
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    fn on_navigation_error(&mut self, error_message: &str) {
+        self.page.add_error(error_message);
+    }
+}
+
+impl Default for AccountReconciliationPageState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
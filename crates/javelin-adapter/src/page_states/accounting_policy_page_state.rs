@@ -0,0 +1,390 @@
+// AccountingPolicyPageState - 会計方針設定画面の状態
+// 責務: 会計方針の取得と管理者による設定変更・利用者登録の操作
+
+use std::sync::Arc;
+
+use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use javelin_application::dtos::request::{
+    RegisterUserAccountRequest, UpdateAmountMaskingRulesRequest, UpdateCurrencyRoundingRequest,
+    UpdateNegativePresentationRequest, UpdateTaxRoundingRequest, UpdateUserRolesRequest,
+};
+use ratatui::DefaultTerminal;
+use tokio::sync::mpsc;
+
+use crate::{
+    error::AdapterResult,
+    navigation::{Controllers, NavAction, PageState, Route},
+    presenter::{AccountingPolicySetting, AccountingPolicyViewModel},
+    views::{layouts::render_guarded, pages::AccountingPolicyPage},
+};
+
+/// 操作結果
+enum PolicyUpdate {
+    Loaded(AccountingPolicyViewModel),
+    Saved(String),
+    SaveFailed(String),
+    LoadFailed(String),
+}
+
+/// 入力行で編集する設定
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PolicyInput {
+    /// 金額マスキング規則（空白区切り）
+    AmountMaskingRules,
+    /// ロール割当（`<ユーザ>=<ロール>[,<ロール>...]`）
+    UserRoles,
+    /// 利用者の登録（`<ユーザID> <表示名> <初期パスワード>`）
+    NewUser,
+}
+
+impl PolicyInput {
+    fn label(&self) -> &'static str {
+        match self {
+            PolicyInput::AmountMaskingRules => {
+                "金額マスキング規則（<開始科目>-<終了科目>:<ロール>,...、空白区切り）"
+            }
+            PolicyInput::UserRoles => "ロール割当（<ユーザ>=<ロール>,...、ロールを空にすると削除）",
+            PolicyInput::NewUser => {
+                "利用者の登録（<ユーザID> <表示名> <初期パスワード>、空白区切り）"
+            }
+        }
+    }
+
+    /// 入力行の表示（初期パスワードは伏せる）
+    fn display(&self, input: &str) -> String {
+        if *self != PolicyInput::NewUser {
+            return input.to_string();
+        }
+        let mut separators = 0;
+        input
+            .chars()
+            .map(|c| {
+                if c.is_whitespace() {
+                    separators += 1;
+                    c
+                } else if separators >= 2 {
+                    '*'
+                } else {
+                    c
+                }
+            })
+            .collect()
+    }
+}
+
+pub struct AccountingPolicyPageState {
+    page: AccountingPolicyPage,
+    /// 入力中の設定と入力内容
+    input: Option<(PolicyInput, String)>,
+    update_tx: mpsc::UnboundedSender<PolicyUpdate>,
+    update_rx: mpsc::UnboundedReceiver<PolicyUpdate>,
+    /// データロード済みフラグ
+    data_loaded: bool,
+}
+
+impl AccountingPolicyPageState {
+    pub fn new() -> Self {
+        let (update_tx, update_rx) = mpsc::unbounded_channel();
+        Self {
+            page: AccountingPolicyPage::new(),
+            input: None,
+            update_tx,
+            update_rx,
+            data_loaded: false,
+        }
+    }
+
+    /// 会計方針を再取得
+    fn load_policy(&self, controllers: &Controllers) {
+        let controller = Arc::clone(&controllers.accounting_policy);
+        let update_tx = self.update_tx.clone();
+        let user_id = controllers.session.user_id();
+
+        tokio::spawn(async move {
+            let update = match controller.load_policy(&user_id).await {
+                Ok(response) => {
+                    PolicyUpdate::Loaded(AccountingPolicyViewModel::from_response(&response))
+                }
+                Err(e) => PolicyUpdate::LoadFailed(e),
+            };
+            let _ = update_tx.send(update);
+        });
+    }
+
+    /// 選択中の設定行の方法を切り替え
+    fn cycle_selected(&mut self, controllers: &Controllers, forward: bool) {
+        let setting = self
+            .page
+            .selected_index()
+            .and_then(|index| self.page.view_model().cycled_setting(index, forward));
+        if let Some(setting) = setting {
+            self.save_setting(controllers, setting);
+        }
+    }
+
+    /// 選択中の通貨の小数点以下桁数を増減
+    fn adjust_decimal_places(&mut self, controllers: &Controllers, increase: bool) {
+        let setting = self
+            .page
+            .selected_index()
+            .and_then(|index| self.page.view_model().adjusted_decimal_places(index, increase));
+        if let Some(setting) = setting {
+            self.save_setting(controllers, setting);
+        }
+    }
+
+    /// 選択中の金額マスキング規則・ロール割当の入力を開始（現在の値を初期値とする）
+    fn start_edit_selected(&mut self) {
+        let input = match self.page.selected_row().map(|row| &row.setting) {
+            Some(AccountingPolicySetting::AmountMaskingRules { rules }) => {
+                (PolicyInput::AmountMaskingRules, rules.clone())
+            }
+            Some(AccountingPolicySetting::UserRoles { user_id, roles }) => {
+                (PolicyInput::UserRoles, format!("{}={}", user_id, roles.join(",")))
+            }
+            _ => return,
+        };
+        self.start_input(input);
+    }
+
+    fn start_input(&mut self, input: (PolicyInput, String)) {
+        if !self.page.view_model().is_administrator {
+            self.page.set_error_message("会計方針の変更・利用者の登録は管理者のみ可能です");
+            return;
+        }
+        self.input = Some(input);
+    }
+
+    /// 入力内容を保存
+    fn submit_input(&mut self, controllers: &Controllers) {
+        let Some((kind, input)) = self.input.take() else {
+            return;
+        };
+        let setting = match kind {
+            PolicyInput::AmountMaskingRules => {
+                AccountingPolicySetting::AmountMaskingRules { rules: input }
+            }
+            PolicyInput::NewUser => {
+                let [user_id, display_name, password] =
+                    input.split_whitespace().collect::<Vec<_>>()[..]
+                else {
+                    self.page.set_error_message(
+                        "<ユーザID> <表示名> <初期パスワード> の形式で入力してください",
+                    );
+                    return;
+                };
+                let request = RegisterUserAccountRequest {
+                    registered_by: controllers.session.user_id(),
+                    user_id: user_id.to_string(),
+                    display_name: display_name.to_string(),
+                    password: password.to_string(),
+                };
+                self.register_user(controllers, request);
+                return;
+            }
+            PolicyInput::UserRoles => {
+                let Some((user_id, roles)) = input.split_once('=') else {
+                    self.page.set_error_message("<ユーザ>=<ロール> の形式で入力してください");
+                    return;
+                };
+                AccountingPolicySetting::UserRoles {
+                    user_id: user_id.trim().to_string(),
+                    roles: roles.split(',').map(|role| role.trim().to_string()).collect(),
+                }
+            }
+        };
+        self.save_setting(controllers, setting);
+    }
+
+    /// 入力中のキー操作
+    fn handle_input_key(&mut self, code: KeyCode, controllers: &Controllers) {
+        let Some((_, input)) = self.input.as_mut() else {
+            return;
+        };
+        match code {
+            KeyCode::Esc => self.input = None,
+            KeyCode::Enter => self.submit_input(controllers),
+            KeyCode::Backspace => {
+                input.pop();
+            }
+            KeyCode::Char(c) => input.push(c),
+            _ => {}
+        }
+    }
+
+    /// 利用者を登録（登録した利用者は承認者として指定できる）
+    fn register_user(&mut self, controllers: &Controllers, request: RegisterUserAccountRequest) {
+        let controller = Arc::clone(&controllers.authentication);
+        let update_tx = self.update_tx.clone();
+
+        tokio::spawn(async move {
+            let update = match controller.register_user(request).await {
+                Ok(response) => PolicyUpdate::Saved(format!(
+                    "利用者 {}（{}）を登録しました",
+                    response.user_id, response.display_name
+                )),
+                Err(e) => PolicyUpdate::SaveFailed(e),
+            };
+            let _ = update_tx.send(update);
+        });
+    }
+
+    /// 設定値を保存（管理者以外は変更しない）
+    fn save_setting(&mut self, controllers: &Controllers, setting: AccountingPolicySetting) {
+        if !self.page.view_model().is_administrator {
+            self.page.set_error_message("会計方針は管理者のみ変更できます");
+            return;
+        }
+
+        let controller = Arc::clone(&controllers.accounting_policy);
+        let update_tx = self.update_tx.clone();
+        let user_id = controllers.session.user_id();
+
+        tokio::spawn(async move {
+            let result = match setting {
+                AccountingPolicySetting::CurrencyRounding {
+                    currency,
+                    decimal_places,
+                    rounding_mode,
+                } => {
+                    controller
+                        .update_currency_rounding(UpdateCurrencyRoundingRequest {
+                            user_id,
+                            currency,
+                            decimal_places,
+                            rounding_mode,
+                        })
+                        .await
+                }
+                AccountingPolicySetting::TaxRounding { tax_type, rounding_mode } => {
+                    controller
+                        .update_tax_rounding(UpdateTaxRoundingRequest {
+                            user_id,
+                            tax_type,
+                            rounding_mode,
+                        })
+                        .await
+                }
+                AccountingPolicySetting::NegativePresentation { code } => {
+                    controller
+                        .update_negative_presentation(UpdateNegativePresentationRequest {
+                            user_id,
+                            negative_presentation: code,
+                        })
+                        .await
+                }
+                AccountingPolicySetting::AmountMaskingRules { rules } => {
+                    controller
+                        .update_amount_masking_rules(UpdateAmountMaskingRulesRequest {
+                            user_id,
+                            rules,
+                        })
+                        .await
+                }
+                AccountingPolicySetting::UserRoles { user_id: target_user_id, roles } => {
+                    controller
+                        .update_user_roles(UpdateUserRolesRequest {
+                            user_id,
+                            target_user_id,
+                            roles,
+                        })
+                        .await
+                }
+            };
+            let update = match result {
+                Ok(true) => PolicyUpdate::Saved("会計方針を更新しました".to_string()),
+                Ok(false) => PolicyUpdate::Saved("変更はありません".to_string()),
+                Err(e) => PolicyUpdate::SaveFailed(e),
+            };
+            let _ = update_tx.send(update);
+        });
+    }
+
+    /// 操作結果を反映
+    fn poll_updates(&mut self, controllers: &Controllers) {
+        while let Ok(update) = self.update_rx.try_recv() {
+            match update {
+                PolicyUpdate::Loaded(view_model) => self.page.set_data(view_model),
+                PolicyUpdate::Saved(message) => {
+                    self.page.set_status_message(message);
+                    self.load_policy(controllers);
+                }
+                PolicyUpdate::SaveFailed(message) => {
+                    self.page.set_error_message(format!("更新に失敗しました: {}", message));
+                }
+                PolicyUpdate::LoadFailed(message) => self.page.set_error(message),
+            }
+        }
+    }
+}
+
+impl PageState for AccountingPolicyPageState {
+    fn route(&self) -> Route {
+        Route::AccountingPolicy
+    }
+
+    fn run(
+        &mut self,
+        terminal: &mut DefaultTerminal,
+        controllers: &Controllers,
+    ) -> AdapterResult<NavAction> {
+        // 初回ロード
+        if !self.data_loaded {
+            self.data_loaded = true;
+            self.load_policy(controllers);
+        }
+
+        loop {
+            self.poll_updates(controllers);
+
+            terminal
+                .draw(|frame| {
+                    let input = self
+                        .input
+                        .as_ref()
+                        .map(|(kind, input)| (kind.label(), kind.display(input)));
+                    let input = input.as_ref().map(|(label, input)| (*label, input.as_str()));
+                    render_guarded(frame, |frame| self.page.render(frame, input));
+                })
+                .map_err(|e| crate::error::AdapterError::RenderingFailed(e.to_string()))?;
+
+            if controllers.session.lock_if_idle() {
+                return Ok(NavAction::Go(Route::Login));
+            }
+
+            if controllers.session.poll_input(std::time::Duration::from_millis(100))?
+                && let Event::Key(key) =
+                    event::read().map_err(crate::error::AdapterError::EventReadFailed)?
+            {
+                if key.kind != KeyEventKind::Press {
+                    continue;
+                }
+                if self.input.is_some() {
+                    self.handle_input_key(key.code, controllers);
+                    continue;
+                }
+
+                match key.code {
+                    KeyCode::Esc => return Ok(NavAction::Back),
+                    KeyCode::Up | KeyCode::Char('k') => self.page.select_previous(),
+                    KeyCode::Down | KeyCode::Char('j') => self.page.select_next(),
+                    KeyCode::Right | KeyCode::Char('l') => self.cycle_selected(controllers, true),
+                    KeyCode::Left | KeyCode::Char('h') => self.cycle_selected(controllers, false),
+                    KeyCode::Char('+') => self.adjust_decimal_places(controllers, true),
+                    KeyCode::Char('-') => self.adjust_decimal_places(controllers, false),
+                    KeyCode::Char('e') | KeyCode::Enter => self.start_edit_selected(),
+                    KeyCode::Char('a') => self.start_input((PolicyInput::UserRoles, String::new())),
+                    KeyCode::Char('u') => self.start_input((PolicyInput::NewUser, String::new())),
+                    KeyCode::Char('r') => self.load_policy(controllers),
+                    _ => {}
+                }
+            }
+        }
+    }
+}
+
+impl Default for AccountingPolicyPageState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -2,17 +2,30 @@
 
 use std::sync::Arc;
 
-use javelin_application::dtos::request::LoadApplicationSettingsRequest;
+use crossterm::event::{KeyCode, KeyEventKind};
+use javelin_application::dtos::{
+    request::LoadApplicationSettingsRequest,
+    response::{FINANCIAL_STATEMENTS_REPORT_TYPE, TRIAL_BALANCE_REPORT_TYPE, report_type_label},
+};
+use javelin_domain::masters::ClosingLockCheck;
 use ratatui::DefaultTerminal;
 use uuid::Uuid;
 
 use crate::{
     error::AdapterResult,
     navigation::{Controllers, NavAction, PageState, PresenterRegistry, Route},
+    notification::BatchNotifier,
     presenter::{ApplicationSettingsPresenter, ApplicationSettingsViewModel},
-    views::pages::ApplicationSettingsPage,
+    views::{layouts::render_guarded, pages::ApplicationSettingsPage},
 };
 
+/// 承認SLAの選択肢（注意閾値, 超過閾値）（時間）
+const APPROVAL_SLA_PRESETS: [(u32, u32); 4] = [(8, 24), (24, 48), (48, 72), (72, 120)];
+
+/// 削除済み仕訳の保持の選択肢（保持する・物理削除までの月数）
+const DELETED_ENTRY_RETENTION_PRESETS: [(bool, u32); 5] =
+    [(false, 12), (true, 6), (true, 12), (true, 24), (true, 84)];
+
 /// アプリケーション設定画面の状態
 pub struct ApplicationSettingsPageState {
     /// Unique identifier for presenter registration
@@ -27,6 +40,11 @@ pub struct ApplicationSettingsPageState {
     is_loading: bool,
     /// データロード済みフラグ
     data_loaded: bool,
+    /// 設定保存結果の通知
+    save_tx: tokio::sync::mpsc::UnboundedSender<Result<String, String>>,
+    save_rx: tokio::sync::mpsc::UnboundedReceiver<Result<String, String>>,
+    /// 入力中の帳票配信先（帳票の種類, 空白区切りのURI）
+    delivery_input: Option<(&'static str, String)>,
 }
 
 impl ApplicationSettingsPageState {
@@ -39,6 +57,7 @@ impl ApplicationSettingsPageState {
 
         // Register presenter
         registry.register_application_settings_presenter(id, presenter);
+        let (save_tx, save_rx) = tokio::sync::mpsc::unbounded_channel();
 
         Self {
             id,
@@ -47,6 +66,195 @@ impl ApplicationSettingsPageState {
             data_rx: rx,
             is_loading: true,
             data_loaded: false,
+            save_tx,
+            save_rx,
+            delivery_input: None,
+        }
+    }
+
+    /// 設定を再取得
+    fn load_settings(&self, controllers: &Controllers) {
+        let controller = Arc::clone(&controllers.application_settings);
+        let page_id = self.id;
+
+        tokio::spawn(async move {
+            let request = LoadApplicationSettingsRequest;
+            let _ = controller.handle_load_application_settings(page_id, request).await;
+        });
+    }
+
+    /// バッチ完了通知設定を切り替えて保存
+    fn toggle_batch_notification(&mut self, controllers: &Controllers, bell: bool) {
+        let Some(vm) = self.page.view_model() else {
+            return;
+        };
+        let (bell_enabled, desktop_enabled) = if bell {
+            (!vm.batch_notification_bell, vm.batch_notification_desktop)
+        } else {
+            (vm.batch_notification_bell, !vm.batch_notification_desktop)
+        };
+
+        let controller = Arc::clone(&controllers.application_settings);
+        let save_tx = self.save_tx.clone();
+
+        tokio::spawn(async move {
+            let result = controller
+                .update_batch_notification(bell_enabled, desktop_enabled)
+                .await
+                .map(|_| "バッチ完了通知設定を更新しました".to_string());
+            let _ = save_tx.send(result);
+        });
+    }
+
+    /// 承認SLAを次の選択肢に切り替えて保存
+    fn cycle_approval_sla(&mut self, controllers: &Controllers) {
+        let Some(vm) = self.page.view_model() else {
+            return;
+        };
+        let (warning_hours, breach_hours) =
+            next_approval_sla(vm.approval_sla_warning_hours, vm.approval_sla_breach_hours);
+
+        let controller = Arc::clone(&controllers.application_settings);
+        let save_tx = self.save_tx.clone();
+
+        tokio::spawn(async move {
+            let result = controller
+                .update_approval_sla(warning_hours, breach_hours)
+                .await
+                .map(|_| "承認SLAを更新しました".to_string());
+            let _ = save_tx.send(result);
+        });
+    }
+
+    /// 削除済み仕訳の保持を次の選択肢に切り替えて保存
+    fn cycle_deleted_entry_retention(&mut self, controllers: &Controllers) {
+        let Some(vm) = self.page.view_model() else {
+            return;
+        };
+        let (retain_enabled, purge_after_months) = next_deleted_entry_retention(
+            vm.deleted_entry_retain_enabled,
+            vm.deleted_entry_purge_after_months,
+        );
+
+        let controller = Arc::clone(&controllers.application_settings);
+        let save_tx = self.save_tx.clone();
+
+        tokio::spawn(async move {
+            let result = controller
+                .update_deleted_entry_retention(retain_enabled, purge_after_months)
+                .await
+                .map(|_| "削除済み仕訳の保持設定を更新しました".to_string());
+            let _ = save_tx.send(result);
+        });
+    }
+
+    /// 出力ファイルの暗号化・署名を切り替えて保存
+    fn toggle_export_protection(&mut self, controllers: &Controllers, encryption: bool) {
+        let Some(vm) = self.page.view_model() else {
+            return;
+        };
+        let (encryption_enabled, signature_enabled) = if encryption {
+            (!vm.export_encryption_enabled, vm.export_signature_enabled)
+        } else {
+            (vm.export_encryption_enabled, !vm.export_signature_enabled)
+        };
+
+        let controller = Arc::clone(&controllers.application_settings);
+        let save_tx = self.save_tx.clone();
+
+        tokio::spawn(async move {
+            let result = controller
+                .update_export_protection(encryption_enabled, signature_enabled)
+                .await
+                .map(|_| "出力ファイルの保護設定を更新しました".to_string());
+            let _ = save_tx.send(result);
+        });
+    }
+
+    /// 締日固定の事前検証項目（表示順の番号）の有効・無効を切り替えて保存
+    fn toggle_closing_lock_check(&mut self, controllers: &Controllers, number: char) {
+        let Some(check) = number
+            .to_digit(10)
+            .and_then(|number| ClosingLockCheck::ALL.get((number as usize).checked_sub(1)?))
+        else {
+            return;
+        };
+
+        let controller = Arc::clone(&controllers.application_settings);
+        let save_tx = self.save_tx.clone();
+        let (code, name) = (check.code(), check.display_name());
+
+        tokio::spawn(async move {
+            let result = controller
+                .toggle_closing_lock_check(code)
+                .await
+                .map(|_| format!("締日固定の検証項目「{}」を切り替えました", name));
+            let _ = save_tx.send(result);
+        });
+    }
+
+    /// 帳票の配信先の入力を開始（現在の配信先を初期値とする）
+    fn start_delivery_input(&mut self, report_type: &'static str) {
+        let Some(vm) = self.page.view_model() else {
+            return;
+        };
+        let destinations = if report_type == TRIAL_BALANCE_REPORT_TYPE {
+            &vm.trial_balance_delivery
+        } else {
+            &vm.financial_statements_delivery
+        };
+        self.delivery_input = Some((report_type, destinations.join(" ")));
+    }
+
+    /// 入力中の配信先を保存
+    fn submit_delivery_input(&mut self, controllers: &Controllers) {
+        let Some((report_type, input)) = self.delivery_input.take() else {
+            return;
+        };
+
+        let controller = Arc::clone(&controllers.application_settings);
+        let save_tx = self.save_tx.clone();
+
+        tokio::spawn(async move {
+            let label = report_type_label(report_type);
+            let result =
+                controller.update_report_delivery(report_type, &input).await.map(|count| {
+                    if count == 0 {
+                        format!("{}を配信しない設定にしました", label)
+                    } else {
+                        format!("{}の配信先を{}件に更新しました", label, count)
+                    }
+                });
+            let _ = save_tx.send(result);
+        });
+    }
+
+    /// 配信先入力中のキー操作
+    fn handle_delivery_input_key(&mut self, code: KeyCode, controllers: &Controllers) {
+        let Some((_, input)) = self.delivery_input.as_mut() else {
+            return;
+        };
+        match code {
+            KeyCode::Esc => self.delivery_input = None,
+            KeyCode::Enter => self.submit_delivery_input(controllers),
+            KeyCode::Backspace => {
+                input.pop();
+            }
+            KeyCode::Char(c) => input.push(c),
+            _ => {}
+        }
+    }
+
+    /// 保存結果を反映
+    fn poll_save_results(&mut self, controllers: &Controllers) {
+        while let Ok(result) = self.save_rx.try_recv() {
+            match result {
+                Ok(message) => {
+                    self.page.set_status_message(message);
+                    self.load_settings(controllers);
+                }
+                Err(e) => self.page.set_error_message(format!("更新に失敗しました: {}", e)),
+            }
         }
     }
 
@@ -59,6 +267,26 @@ impl ApplicationSettingsPageState {
     }
 }
 
+/// 現在の承認SLAの次の選択肢（選択肢にない値の場合は先頭）
+fn next_approval_sla(warning_hours: u32, breach_hours: u32) -> (u32, u32) {
+    let next = APPROVAL_SLA_PRESETS
+        .iter()
+        .position(|&preset| preset == (warning_hours, breach_hours))
+        .map_or(0, |index| (index + 1) % APPROVAL_SLA_PRESETS.len());
+    APPROVAL_SLA_PRESETS[next]
+}
+
+/// 現在の削除済み仕訳の保持の次の選択肢（保持しない場合は月数を問わない）
+fn next_deleted_entry_retention(retain_enabled: bool, purge_after_months: u32) -> (bool, u32) {
+    let next = DELETED_ENTRY_RETENTION_PRESETS
+        .iter()
+        .position(|&(retain, months)| {
+            retain == retain_enabled && (!retain || months == purge_after_months)
+        })
+        .map_or(0, |index| (index + 1) % DELETED_ENTRY_RETENTION_PRESETS.len());
+    DELETED_ENTRY_RETENTION_PRESETS[next]
+}
+
 impl PageState for ApplicationSettingsPageState {
     fn route(&self) -> Route {
         Route::ApplicationSettings
@@ -72,40 +300,59 @@ impl PageState for ApplicationSettingsPageState {
         // 初回ロード
         if !self.data_loaded {
             self.data_loaded = true;
-            let controller = Arc::clone(&controllers.application_settings);
-            let page_id = self.id;
-
-            tokio::spawn(async move {
-                let request = LoadApplicationSettingsRequest;
-                let _ = controller.handle_load_application_settings(page_id, request).await;
-            });
+            self.load_settings(controllers);
         }
 
         loop {
             // Poll for data updates
             self.poll_data();
+            self.poll_save_results(controllers);
 
             // Render
             terminal
                 .draw(|frame| {
-                    self.page.render(frame);
+                    let delivery_input =
+                        self.delivery_input.as_ref().map(|(report_type, input)| {
+                            (report_type_label(report_type), input.as_str())
+                        });
+                    render_guarded(frame, |frame| self.page.render(frame, delivery_input));
                 })
                 .map_err(|e| crate::error::AdapterError::RenderingFailed(e.to_string()))?;
 
+            if controllers.session.lock_if_idle() {
+                return Ok(NavAction::Go(Route::Login));
+            }
+
             // Handle events
-            if crossterm::event::poll(std::time::Duration::from_millis(100))
-                .map_err(crate::error::AdapterError::EventReadFailed)?
+            if controllers.session.poll_input(std::time::Duration::from_millis(100))?
                 && let crossterm::event::Event::Key(key) =
                     crossterm::event::read().map_err(crate::error::AdapterError::EventReadFailed)?
             {
-                use crossterm::event::{KeyCode, KeyEventKind};
-
                 if key.kind != KeyEventKind::Press {
                     continue;
                 }
 
-                if key.code == KeyCode::Esc {
-                    return Ok(NavAction::Back);
+                if self.delivery_input.is_some() {
+                    self.handle_delivery_input_key(key.code, controllers);
+                    continue;
+                }
+
+                match key.code {
+                    KeyCode::Esc => return Ok(NavAction::Back),
+                    KeyCode::Char('b') => self.toggle_batch_notification(controllers, true),
+                    KeyCode::Char('a') => self.cycle_approval_sla(controllers),
+                    KeyCode::Char('e') => self.toggle_export_protection(controllers, true),
+                    KeyCode::Char('s') => self.toggle_export_protection(controllers, false),
+                    KeyCode::Char('p') => self.cycle_deleted_entry_retention(controllers),
+                    KeyCode::Char(c @ '1'..='4') => self.toggle_closing_lock_check(controllers, c),
+                    KeyCode::Char('t') => self.start_delivery_input(TRIAL_BALANCE_REPORT_TYPE),
+                    KeyCode::Char('f') => {
+                        self.start_delivery_input(FINANCIAL_STATEMENTS_REPORT_TYPE)
+                    }
+                    KeyCode::Char('d') if BatchNotifier::desktop_supported() => {
+                        self.toggle_batch_notification(controllers, false)
+                    }
+                    _ => {}
                 }
             }
         }
@@ -118,3 +365,23 @@ impl Drop for ApplicationSettingsPageState {
         self.registry.unregister_application_settings_presenter(self.id);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next_approval_sla_cycles_presets() {
+        assert_eq!(next_approval_sla(24, 48), (48, 72));
+        assert_eq!(next_approval_sla(72, 120), (8, 24));
+        assert_eq!(next_approval_sla(10, 20), (8, 24));
+    }
+
+    #[test]
+    fn test_next_deleted_entry_retention_cycles_presets() {
+        assert_eq!(next_deleted_entry_retention(false, 36), (true, 6));
+        assert_eq!(next_deleted_entry_retention(true, 12), (true, 24));
+        assert_eq!(next_deleted_entry_retention(true, 84), (false, 12));
+        assert_eq!(next_deleted_entry_retention(true, 36), (false, 12));
+    }
+}
@@ -0,0 +1,187 @@
+// BalanceAnalysisPageState - 勘定残高の異常値レビュー画面の状態
+// 責務: 対象月・閾値の切替と分析の実行
+
+use std::{sync::Arc, time::Instant};
+
+use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use javelin_application::dtos::{
+    request::BalanceAnomalyThresholds, response::AnalyzeBalancesResponse,
+};
+use ratatui::DefaultTerminal;
+use tokio::sync::mpsc;
+
+use crate::{
+    error::AdapterResult,
+    navigation::{Controllers, NavAction, PageState, Route},
+    page_states::{period_selector::PeriodSelector, report_parameters::StandardReport},
+    views::{layouts::render_guarded, pages::BalanceAnalysisPage},
+};
+
+/// 切替可能な増減率の閾値
+const CHANGE_RATE_PRESETS: [f64; 3] = [0.3, 0.5, 1.0];
+
+/// 分析結果
+enum AnalysisUpdate {
+    Completed(AnalyzeBalancesResponse),
+    Failed(String),
+}
+
+pub struct BalanceAnalysisPageState {
+    page: BalanceAnalysisPage,
+    period_selector: PeriodSelector,
+    /// 選択中の増減率閾値（CHANGE_RATE_PRESETSの添字）
+    preset_index: usize,
+    loading: bool,
+    update_tx: mpsc::UnboundedSender<AnalysisUpdate>,
+    update_rx: mpsc::UnboundedReceiver<AnalysisUpdate>,
+    /// データロード済みフラグ
+    data_loaded: bool,
+}
+
+impl BalanceAnalysisPageState {
+    /// 帳票メニューから条件が渡された場合はその対象月、それ以外は業務日付の当月を分析する
+    pub fn new() -> Self {
+        let (update_tx, update_rx) = mpsc::unbounded_channel();
+        Self {
+            page: BalanceAnalysisPage::new(),
+            period_selector: PeriodSelector::handed_off_or_current(StandardReport::BalanceAnalysis),
+            preset_index: 0,
+            loading: false,
+            update_tx,
+            update_rx,
+            data_loaded: false,
+        }
+    }
+
+    fn thresholds(&self) -> BalanceAnomalyThresholds {
+        BalanceAnomalyThresholds {
+            change_rate: CHANGE_RATE_PRESETS[self.preset_index],
+            ..BalanceAnomalyThresholds::default()
+        }
+    }
+
+    fn threshold_label(&self) -> String {
+        format!("{:.0}%", self.thresholds().change_rate * 100.0)
+    }
+
+    /// 分析を開始
+    fn load_analysis(&mut self, controllers: &Controllers) {
+        if self.loading {
+            return;
+        }
+        self.loading = true;
+        self.page.start_loading();
+
+        let controller = Arc::clone(&controllers.balance_analysis);
+        let recorder = Arc::clone(&controllers.record_user_action);
+        let user_id = controllers.session.user_id();
+        let update_tx = self.update_tx.clone();
+        let (fiscal_year, period, thresholds) = (
+            self.period_selector.fiscal_year(),
+            self.period_selector.period(),
+            self.thresholds(),
+        );
+
+        tokio::spawn(async move {
+            let started = Instant::now();
+            let result = controller.analyze(fiscal_year, period, thresholds).await;
+            let _ = recorder
+                .record_action(user_id, "BalanceAnalysis", "analyze", started.elapsed())
+                .await;
+            let update = match result {
+                Ok(response) => AnalysisUpdate::Completed(response),
+                Err(e) => AnalysisUpdate::Failed(e),
+            };
+            let _ = update_tx.send(update);
+        });
+    }
+
+    /// 分析結果を反映
+    fn poll_updates(&mut self) {
+        while let Ok(update) = self.update_rx.try_recv() {
+            self.loading = false;
+            match update {
+                AnalysisUpdate::Completed(response) => {
+                    let thresholds = self.thresholds();
+                    self.page.set_result(response, thresholds);
+                }
+                AnalysisUpdate::Failed(message) => self.page.set_error(message),
+            }
+        }
+    }
+}
+
+impl PageState for BalanceAnalysisPageState {
+    fn route(&self) -> Route {
+        Route::BalanceAnalysis
+    }
+
+    fn run(
+        &mut self,
+        terminal: &mut DefaultTerminal,
+        controllers: &Controllers,
+    ) -> AdapterResult<NavAction> {
+        if !self.data_loaded {
+            self.load_analysis(controllers);
+            self.data_loaded = true;
+        }
+
+        loop {
+            self.poll_updates();
+            self.page.tick();
+
+            let period_label = self.period_selector.label();
+            let threshold_label = self.threshold_label();
+            terminal
+                .draw(|frame| {
+                    render_guarded(frame, |frame| {
+                        self.page.render(frame, &period_label, &threshold_label)
+                    });
+                })
+                .map_err(|e| crate::error::AdapterError::RenderingFailed(e.to_string()))?;
+
+            if controllers.session.lock_if_idle() {
+                return Ok(NavAction::Go(Route::Login));
+            }
+
+            if controllers.session.poll_input(std::time::Duration::from_millis(100))?
+                && let Event::Key(key) =
+                    event::read().map_err(crate::error::AdapterError::EventReadFailed)?
+            {
+                if key.kind != KeyEventKind::Press {
+                    continue;
+                }
+
+                match key.code {
+                    KeyCode::Esc => return Ok(NavAction::Back),
+                    KeyCode::Char('j') | KeyCode::Down => self.page.select_next(),
+                    KeyCode::Char('k') | KeyCode::Up => self.page.select_previous(),
+                    KeyCode::Char('h') | KeyCode::Left if !self.loading => {
+                        self.period_selector.shift(false);
+                        self.load_analysis(controllers);
+                    }
+                    KeyCode::Char('l') | KeyCode::Right if !self.loading => {
+                        self.period_selector.shift(true);
+                        self.load_analysis(controllers);
+                    }
+                    KeyCode::Char('t') if !self.loading => {
+                        self.preset_index = (self.preset_index + 1) % CHANGE_RATE_PRESETS.len();
+                        self.load_analysis(controllers);
+                    }
+                    KeyCode::Char('r') => self.load_analysis(controllers),
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    fn on_navigation_error(&mut self, error_message: &str) {
+        self.page.add_error(error_message);
+    }
+}
+
+impl Default for BalanceAnalysisPageState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
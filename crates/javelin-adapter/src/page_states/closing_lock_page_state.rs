@@ -1,21 +1,234 @@
 // ClosingLockPageState - PageState implementation for closing lock screen
+// 責務: 締日固定（事前検証と管理者による上書きを含む）、承認付きの期間再オープンと、
+// 再オープン期間中の記帳レポートの読込
+
+use std::sync::Arc;
 
 use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use javelin_application::dtos::{
+    LockClosingPeriodRequest, LockClosingPeriodResponse,
+    request::{ReopenClosingPeriodRequest, ValidateClosingLockRequest},
+    response::{
+        ClosingLockValidationReport, IntegrityAuditResponse, ReopenClosingPeriodResponse,
+        ReopenWindowReportResponse,
+    },
+};
+use javelin_domain::integrity_audit::AuditTrigger;
 use ratatui::DefaultTerminal;
+use tokio::sync::mpsc;
 
 use crate::{
     error::AdapterResult,
+    error_log::to_user_message,
     navigation::{Controllers, NavAction, PageState, Route},
-    views::pages::ClosingLockPage,
+    views::{layouts::render_guarded, pages::ClosingLockPage},
 };
 
+/// 締日固定・事前検証・再オープン・レポート読込の結果
+enum ReopenUpdate {
+    Audited(IntegrityAuditResponse),
+    AuditFailed(String),
+    Locked(LockClosingPeriodResponse),
+    Validated(ClosingLockValidationReport),
+    Reopened(ReopenClosingPeriodResponse),
+    Report(ReopenWindowReportResponse),
+    Failed(String),
+}
+
 pub struct ClosingLockPageState {
     page: ClosingLockPage,
+    update_tx: mpsc::UnboundedSender<ReopenUpdate>,
+    update_rx: mpsc::UnboundedReceiver<ReopenUpdate>,
 }
 
 impl ClosingLockPageState {
     pub fn new() -> Self {
-        Self { page: ClosingLockPage::new() }
+        let (update_tx, update_rx) = mpsc::unbounded_channel();
+        Self { page: ClosingLockPage::new(), update_tx, update_rx }
+    }
+
+    /// 整合性監査を実行してから対象期間を締日固定（事前検証に失敗した場合は拒否される）
+    ///
+    /// 監査の結果は記録とログ表示のみで、検出事項があっても締日固定は続ける。
+    /// `override_failures` の場合は理由欄の内容を上書きの理由とする（管理者のみ）。
+    fn lock_period(&mut self, controllers: &Controllers, override_failures: bool) {
+        if self.page.is_processing() {
+            return;
+        }
+        let (fiscal_year, period) = match self.page.target_period() {
+            Ok(target) => target,
+            Err(e) => {
+                self.page.add_error(e);
+                return;
+            }
+        };
+        let override_reason = if override_failures {
+            let reason = self.page.reason().trim();
+            if reason.is_empty() {
+                self.page.add_error("上書きの理由を入力してください");
+                return;
+            }
+            Some(reason.to_string())
+        } else {
+            None
+        };
+        let request = LockClosingPeriodRequest {
+            fiscal_year: fiscal_year as i32,
+            period,
+            locked_by: controllers.session.user_id(),
+            override_reason,
+        };
+        self.page
+            .start_processing(format!("{}-{:02} を締日固定しています...", fiscal_year, period));
+
+        let controller = Arc::clone(&controllers.closing);
+        let integrity_audit = Arc::clone(&controllers.integrity_audit);
+        let update_tx = self.update_tx.clone();
+
+        tokio::spawn(async move {
+            match integrity_audit.run(AuditTrigger::BeforeLock, request.locked_by.clone()).await {
+                Ok(audit) => {
+                    let _ = update_tx.send(ReopenUpdate::Audited(audit));
+                }
+                Err(e) => {
+                    let _ = update_tx.send(ReopenUpdate::AuditFailed(e));
+                }
+            }
+            let update = match controller.lock_closing_period(request).await {
+                Ok(response) => ReopenUpdate::Locked(response),
+                Err(e) => ReopenUpdate::Failed(to_user_message(e)),
+            };
+            let _ = update_tx.send(update);
+        });
+    }
+
+    /// 対象期間の事前検証のみを実行
+    fn validate_lock(&mut self, controllers: &Controllers) {
+        if self.page.is_processing() {
+            return;
+        }
+        let (fiscal_year, period) = match self.page.target_period() {
+            Ok(target) => target,
+            Err(e) => {
+                self.page.add_error(e);
+                return;
+            }
+        };
+        let request = ValidateClosingLockRequest { fiscal_year: fiscal_year as i32, period };
+        self.page.start_processing(format!(
+            "{}-{:02} の締日固定前の検証をしています...",
+            fiscal_year, period
+        ));
+
+        let controller = Arc::clone(&controllers.closing);
+        let update_tx = self.update_tx.clone();
+
+        tokio::spawn(async move {
+            let update = match controller.validate_closing_lock(request).await {
+                Ok(report) => ReopenUpdate::Validated(report),
+                Err(e) => ReopenUpdate::Failed(to_user_message(e)),
+            };
+            let _ = update_tx.send(update);
+        });
+    }
+
+    /// 入力内容で再オープンを申請し、成功したら記帳レポートを読込
+    fn submit_reopen(&mut self, controllers: &Controllers) {
+        if self.page.is_processing() {
+            return;
+        }
+        let (fiscal_year, period) = match self.page.target_period() {
+            Ok(target) => target,
+            Err(e) => {
+                self.page.add_error(e);
+                return;
+            }
+        };
+        let request = ReopenClosingPeriodRequest {
+            fiscal_year,
+            period,
+            reason: self.page.reason().to_string(),
+            requested_by: controllers.session.user_id(),
+            approved_by: self.page.approver().to_string(),
+            approver_password: self.page.approver_password().to_string(),
+        };
+        self.page
+            .start_processing(format!("{}-{:02} を再オープンしています...", fiscal_year, period));
+
+        let controller = Arc::clone(&controllers.period_reopen);
+        let update_tx = self.update_tx.clone();
+
+        tokio::spawn(async move {
+            match controller.reopen(request).await {
+                Ok(response) => {
+                    let _ = update_tx.send(ReopenUpdate::Reopened(response));
+                    let update = match controller.window_report(fiscal_year, period).await {
+                        Ok(report) => ReopenUpdate::Report(report),
+                        Err(e) => ReopenUpdate::Failed(e),
+                    };
+                    let _ = update_tx.send(update);
+                }
+                Err(e) => {
+                    let _ = update_tx.send(ReopenUpdate::Failed(e));
+                }
+            }
+        });
+    }
+
+    /// 対象期間の再オープン期間中の記帳レポートを読込
+    fn load_report(&mut self, controllers: &Controllers) {
+        if self.page.is_processing() {
+            return;
+        }
+        let (fiscal_year, period) = match self.page.target_period() {
+            Ok(target) => target,
+            Err(e) => {
+                self.page.add_error(e);
+                return;
+            }
+        };
+        self.page.start_processing(format!(
+            "{}-{:02} の再オープン期間中の記帳を集計しています...",
+            fiscal_year, period
+        ));
+
+        let controller = Arc::clone(&controllers.period_reopen);
+        let update_tx = self.update_tx.clone();
+
+        tokio::spawn(async move {
+            let update = match controller.window_report(fiscal_year, period).await {
+                Ok(report) => ReopenUpdate::Report(report),
+                Err(e) => ReopenUpdate::Failed(e),
+            };
+            let _ = update_tx.send(update);
+        });
+    }
+
+    /// 処理結果を反映
+    fn poll_updates(&mut self) {
+        while let Ok(update) = self.update_rx.try_recv() {
+            match update {
+                ReopenUpdate::Audited(audit) if audit.passed => {
+                    self.page.add_info(format!("整合性監査に合格しました（{}）", audit.audit_id));
+                }
+                ReopenUpdate::Audited(audit) => self.page.add_error(format!(
+                    "整合性監査で検出事項が{}件ありました（{}）",
+                    audit.finding_count, audit.audit_id
+                )),
+                ReopenUpdate::AuditFailed(error) => {
+                    self.page.add_error(format!("整合性監査を実行できませんでした: {}", error));
+                }
+                ReopenUpdate::Locked(response) => self.page.set_response(response),
+                ReopenUpdate::Validated(report) => self.page.set_validation_report(&report),
+                ReopenUpdate::Reopened(response) => {
+                    self.page.set_reopen_result(&response);
+                    // 続けてレポートを読み込む間は処理中のままにする
+                    self.page.start_processing("再オープン期間中の記帳を集計しています...");
+                }
+                ReopenUpdate::Report(report) => self.page.set_window_report(&report),
+                ReopenUpdate::Failed(error) => self.page.set_error(error),
+            }
+        }
     }
 }
 
@@ -27,22 +240,26 @@ impl PageState for ClosingLockPageState {
     fn run(
         &mut self,
         terminal: &mut DefaultTerminal,
-        _controllers: &Controllers,
+        controllers: &Controllers,
     ) -> AdapterResult<NavAction> {
         loop {
+            self.poll_updates();
             // Tick animation
             self.page.tick();
 
             // Render the page
             terminal
                 .draw(|frame| {
-                    self.page.render(frame);
+                    render_guarded(frame, |frame| self.page.render(frame));
                 })
                 .map_err(|e| crate::error::AdapterError::RenderingFailed(e.to_string()))?;
 
+            if controllers.session.lock_if_idle() {
+                return Ok(NavAction::Go(Route::Login));
+            }
+
             // Handle events with timeout for animation updates
-            if event::poll(std::time::Duration::from_millis(100))
-                .map_err(crate::error::AdapterError::EventReadFailed)?
+            if controllers.session.poll_input(std::time::Duration::from_millis(100))?
                 && let Event::Key(key) =
                     event::read().map_err(crate::error::AdapterError::EventReadFailed)?
             {
@@ -50,8 +267,21 @@ impl PageState for ClosingLockPageState {
                     continue;
                 }
 
-                if key.code == KeyCode::Esc {
-                    return Ok(NavAction::Back);
+                match key.code {
+                    KeyCode::Esc => return Ok(NavAction::Back),
+                    KeyCode::Tab => self.page.focus_next(),
+                    KeyCode::BackTab => self.page.focus_previous(),
+                    KeyCode::Enter => self.submit_reopen(controllers),
+                    KeyCode::F(2) => self.load_report(controllers),
+                    KeyCode::F(3) => return Ok(NavAction::Go(Route::AccountReconciliation)),
+                    KeyCode::F(4) => self.lock_period(controllers, false),
+                    KeyCode::F(5) => self.validate_lock(controllers),
+                    KeyCode::F(6) => self.lock_period(controllers, true),
+                    KeyCode::Down => self.page.select_next(),
+                    KeyCode::Up => self.page.select_previous(),
+                    KeyCode::Backspace => self.page.delete_char(),
+                    KeyCode::Char(c) => self.page.input_char(c),
+                    _ => {}
                 }
             }
         }
@@ -1,22 +1,89 @@
 // ClosingPreparationExecutionPageState - 締準備実行画面の状態管理
 // 責務: 締準備実行画面の状態とイベント処理
 
+use std::sync::Arc;
+
 use crossterm::event::{self, Event, KeyCode, KeyEventKind};
 use ratatui::DefaultTerminal;
+use tokio::sync::mpsc;
 
 use crate::{
     error::AdapterResult,
     navigation::{Controllers, NavAction, Route, page_state::PageState},
-    views::pages::ClosingPreparationExecutionPage,
+    page_states::ProtectedExport,
+    presenter::SequenceAuditViewModel,
+    views::{layouts::render_guarded, pages::ClosingPreparationExecutionPage},
 };
 
 pub struct ClosingPreparationExecutionPageState {
     page: ClosingPreparationExecutionPage,
+    audit_tx: mpsc::UnboundedSender<Result<SequenceAuditViewModel, String>>,
+    audit_rx: mpsc::UnboundedReceiver<Result<SequenceAuditViewModel, String>>,
+    /// 直近の番号連続性監査結果（レポート出力用）
+    sequence_audit: Option<SequenceAuditViewModel>,
+    /// 監査レポートの保護付き書き出し
+    export: ProtectedExport,
 }
 
 impl ClosingPreparationExecutionPageState {
     pub fn new() -> Self {
-        Self { page: ClosingPreparationExecutionPage::new() }
+        let (audit_tx, audit_rx) = mpsc::unbounded_channel();
+        Self {
+            page: ClosingPreparationExecutionPage::new(),
+            audit_tx,
+            audit_rx,
+            sequence_audit: None,
+            export: ProtectedExport::new(),
+        }
+    }
+
+    /// 締準備を開始し、番号連続性監査を実行
+    fn start_execution(&mut self, controllers: &Controllers) {
+        self.page.start_execution();
+        self.page.start_sequence_audit();
+
+        let controller = Arc::clone(&controllers.sequence_audit);
+        let audit_tx = self.audit_tx.clone();
+
+        tokio::spawn(async move {
+            let result = controller
+                .run_audit(None)
+                .await
+                .map(|response| SequenceAuditViewModel::from_response(&response));
+            let _ = audit_tx.send(result);
+        });
+    }
+
+    /// 監査結果を反映
+    fn poll_updates(&mut self) {
+        while let Ok(result) = self.audit_rx.try_recv() {
+            match result {
+                Ok(view_model) => {
+                    self.page.set_sequence_audit(&view_model);
+                    self.sequence_audit = Some(view_model);
+                }
+                Err(e) => self.page.set_sequence_audit_error(e),
+            }
+        }
+        for result in self.export.poll() {
+            match result {
+                Ok(message) => self.page.add_info(message),
+                Err(e) => self.page.add_error(format!("監査レポートの出力に失敗しました: {}", e)),
+            }
+        }
+    }
+
+    /// 番号連続性監査レポートをCSVファイルへ出力
+    fn export_sequence_audit(&mut self, controllers: &Controllers) {
+        let Some(view_model) = &self.sequence_audit else {
+            self.page.add_error("番号連続性確認が未実行です");
+            return;
+        };
+
+        let file_name =
+            format!("sequence_audit_{}.csv", crate::clock::now().format("%Y%m%d_%H%M%S"));
+        let content = view_model.to_csv().into_bytes();
+        self.export.export(controllers, file_name, content, "");
     }
 }
 
@@ -28,19 +95,28 @@ impl PageState for ClosingPreparationExecutionPageState {
     fn run(
         &mut self,
         terminal: &mut DefaultTerminal,
-        _controllers: &Controllers,
+        controllers: &Controllers,
     ) -> AdapterResult<NavAction> {
+        self.export.request_settings(controllers);
+
         loop {
+            self.poll_updates();
             self.page.tick();
 
             terminal
                 .draw(|frame| {
-                    self.page.render(frame);
+                    render_guarded(frame, |frame| {
+                        self.page.render(frame);
+                        self.export.render(frame);
+                    });
                 })
                 .map_err(|e| crate::error::AdapterError::RenderingFailed(e.to_string()))?;
 
-            if event::poll(std::time::Duration::from_millis(100))
-                .map_err(crate::error::AdapterError::EventReadFailed)?
+            if controllers.session.lock_if_idle() {
+                return Ok(NavAction::Go(Route::Login));
+            }
+
+            if controllers.session.poll_input(std::time::Duration::from_millis(100))?
                 && let Event::Key(key) =
                     event::read().map_err(crate::error::AdapterError::EventReadFailed)?
             {
@@ -48,9 +124,15 @@ impl PageState for ClosingPreparationExecutionPageState {
                     continue;
                 }
 
+                // 暗号化パスワード入力中
+                if self.export.handle_key(key.code, controllers) {
+                    continue;
+                }
+
                 match key.code {
                     KeyCode::Esc => return Ok(NavAction::Back),
-                    KeyCode::Char('s') => self.page.start_execution(),
+                    KeyCode::Char('s') => self.start_execution(controllers),
+                    KeyCode::Char('x') => self.export_sequence_audit(controllers),
                     KeyCode::Char('j') | KeyCode::Down => self.page.select_next(),
                     KeyCode::Char('k') | KeyCode::Up => self.page.select_previous(),
                     _ => {}
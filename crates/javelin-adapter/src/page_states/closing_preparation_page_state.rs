@@ -1,16 +1,21 @@
 // ClosingPreparationPageState - PageState implementation for closing preparation history screen
 
-use std::sync::Arc;
+use std::{collections::BTreeMap, sync::Arc};
 
+use chrono::{Datelike, Months, NaiveDate};
 use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use javelin_application::dtos::{request::PrepareClosingRequest, response::PrepareClosingResponse};
 use ratatui::DefaultTerminal;
+use tokio::sync::mpsc;
 use uuid::Uuid;
 
 use crate::{
     error::AdapterResult,
+    error_log::to_user_message,
     navigation::{Controllers, NavAction, PageState, PresenterRegistry, Route},
+    page_states::report_parameters::{self, PARAM_AS_OF_DATE, StandardReport},
     presenter::BatchHistoryPresenter,
-    views::pages::ClosingPreparationPage,
+    views::{layouts::render_guarded, pages::ClosingPreparationPage},
 };
 
 pub struct ClosingPreparationPageState {
@@ -19,6 +24,8 @@ pub struct ClosingPreparationPageState {
     registry: Arc<PresenterRegistry>,
     result_rx: tokio::sync::mpsc::Receiver<crate::presenter::BatchHistoryViewModel>,
     error_rx: tokio::sync::mpsc::Receiver<String>,
+    prepare_tx: mpsc::UnboundedSender<Result<PrepareClosingResponse, String>>,
+    prepare_rx: mpsc::UnboundedReceiver<Result<PrepareClosingResponse, String>>,
 }
 
 impl ClosingPreparationPageState {
@@ -40,14 +47,62 @@ impl ClosingPreparationPageState {
             let _ = controller.handle_get_history(page_id, batch_type).await;
         });
 
+        let (prepare_tx, prepare_rx) = mpsc::unbounded_channel();
         Self {
             page,
             page_id,
             registry,
             result_rx: channels.result_rx,
             error_rx: channels.error_rx,
+            prepare_tx,
+            prepare_rx,
         }
     }
+
+    /// 業務日付の月の締準備を実行し、仮勘定・清算勘定の滞留状況を表示
+    fn prepare(&mut self, controllers: &Controllers) {
+        let business_date = crate::clock::business_date();
+        let request = PrepareClosingRequest {
+            fiscal_year: business_date.year(),
+            period: business_date.month() as u8,
+        };
+        self.page.show_result(format!("{}-{:02}", request.fiscal_year, request.period));
+
+        let controller = Arc::clone(&controllers.closing);
+        let tx = self.prepare_tx.clone();
+        tokio::spawn(async move {
+            let result = controller.prepare_closing(request).await.map_err(to_user_message);
+            let _ = tx.send(result);
+        });
+    }
+
+    fn poll_prepare_results(&mut self) {
+        while let Ok(result) = self.prepare_rx.try_recv() {
+            match result {
+                Ok(response) => self.page.set_result(response),
+                Err(e) => self.page.set_result_error(e),
+            }
+        }
+    }
+
+    /// 選択中の計上を仮勘定の滞留状況画面（業務日付の月末基準）で開く
+    fn open_selected_item(&self) -> Option<NavAction> {
+        self.page.selected_aging_item()?;
+        let as_of_date = month_end(crate::clock::business_date())?;
+        report_parameters::hand_off(
+            StandardReport::SuspenseAging,
+            BTreeMap::from([(
+                PARAM_AS_OF_DATE.to_string(),
+                as_of_date.format("%Y-%m-%d").to_string(),
+            )]),
+        );
+        Some(NavAction::Go(Route::AccountAdjustment))
+    }
+}
+
+/// 月末日
+fn month_end(date: NaiveDate) -> Option<NaiveDate> {
+    date.with_day(1)?.checked_add_months(Months::new(1))?.pred_opt()
 }
 
 impl PageState for ClosingPreparationPageState {
@@ -58,7 +113,7 @@ impl PageState for ClosingPreparationPageState {
     fn run(
         &mut self,
         terminal: &mut DefaultTerminal,
-        _controllers: &Controllers,
+        controllers: &Controllers,
     ) -> AdapterResult<NavAction> {
         loop {
             if let Ok(result) = self.result_rx.try_recv() {
@@ -73,16 +128,20 @@ impl PageState for ClosingPreparationPageState {
                 self.page.set_error(error);
             }
 
+            self.poll_prepare_results();
             self.page.tick();
 
             terminal
                 .draw(|frame| {
-                    self.page.render(frame);
+                    render_guarded(frame, |frame| self.page.render(frame));
                 })
                 .map_err(|e| crate::error::AdapterError::RenderingFailed(e.to_string()))?;
 
-            if event::poll(std::time::Duration::from_millis(100))
-                .map_err(crate::error::AdapterError::EventReadFailed)?
+            if controllers.session.lock_if_idle() {
+                return Ok(NavAction::Go(Route::Login));
+            }
+
+            if controllers.session.poll_input(std::time::Duration::from_millis(100))?
                 && let Event::Key(key) =
                     event::read().map_err(crate::error::AdapterError::EventReadFailed)?
             {
@@ -90,11 +149,30 @@ impl PageState for ClosingPreparationPageState {
                     continue;
                 }
 
+                if self.page.is_showing_result() {
+                    match key.code {
+                        KeyCode::Esc | KeyCode::Char('p') => self.page.hide_result(),
+                        KeyCode::Enter => {
+                            if let Some(action) = self.open_selected_item() {
+                                return Ok(action);
+                            }
+                        }
+                        KeyCode::Char('r') => self.prepare(controllers),
+                        KeyCode::Char('j') | KeyCode::Down => self.page.select_next(),
+                        KeyCode::Char('k') | KeyCode::Up => self.page.select_previous(),
+                        _ => {}
+                    }
+                    continue;
+                }
+
                 match key.code {
                     KeyCode::Esc => return Ok(NavAction::Back),
+                    KeyCode::Char('p') => self.prepare(controllers),
                     KeyCode::Char('e') => {
                         return Ok(NavAction::Go(Route::ClosingPreparationExecution));
                     }
+                    KeyCode::Char('a') => return Ok(NavAction::Go(Route::BalanceAnalysis)),
+                    KeyCode::Char('h') => return Ok(NavAction::Go(Route::AccountActivity)),
                     KeyCode::Char('j') | KeyCode::Down => self.page.select_next(),
                     KeyCode::Char('k') | KeyCode::Up => self.page.select_previous(),
                     _ => {}
@@ -0,0 +1,264 @@
+// ClosingTimetablePageState - 締めスケジュール画面の状態
+// 責務: 期間ごとの締め進捗ボードの取得、締めタスクの登録・編集・削除と進捗の切替
+
+use std::sync::Arc;
+
+use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use javelin_application::dtos::request::{
+    DeleteClosingTimetableItemRequest, LoadClosingTimetableBoardRequest,
+    SaveClosingTimetableItemRequest, UpdateClosingTaskStatusRequest,
+};
+use ratatui::DefaultTerminal;
+use tokio::sync::mpsc;
+
+use crate::{
+    error::AdapterResult,
+    navigation::{Controllers, NavAction, PageState, Route},
+    page_states::period_selector::PeriodSelector,
+    presenter::ClosingTimetableViewModel,
+    views::{layouts::render_guarded, pages::ClosingTimetablePage},
+};
+
+/// 操作結果
+enum TimetableUpdate {
+    Loaded(ClosingTimetableViewModel),
+    Saved(String),
+    SaveFailed(String),
+    LoadFailed(String),
+}
+
+pub struct ClosingTimetablePageState {
+    page: ClosingTimetablePage,
+    period_selector: PeriodSelector,
+    update_tx: mpsc::UnboundedSender<TimetableUpdate>,
+    update_rx: mpsc::UnboundedReceiver<TimetableUpdate>,
+    /// 編集中の締めタスク（`<コード>:<タスク>:<担当>:<期日>:<工程>`）
+    input: Option<String>,
+    /// データロード済みフラグ
+    data_loaded: bool,
+}
+
+impl ClosingTimetablePageState {
+    /// 締め作業は翌月初に行うため、業務日付の前月を初期表示する
+    pub fn new() -> Self {
+        let (update_tx, update_rx) = mpsc::unbounded_channel();
+        Self {
+            page: ClosingTimetablePage::new(),
+            period_selector: PeriodSelector::previous(),
+            update_tx,
+            update_rx,
+            input: None,
+            data_loaded: false,
+        }
+    }
+
+    /// 進捗ボードを再取得
+    fn load_board(&self, controllers: &Controllers) {
+        let controller = Arc::clone(&controllers.closing_timetable);
+        let update_tx = self.update_tx.clone();
+        let request = LoadClosingTimetableBoardRequest {
+            fiscal_year: self.period_selector.fiscal_year(),
+            period: self.period_selector.period(),
+            today: crate::clock::business_date(),
+        };
+
+        tokio::spawn(async move {
+            let update = match controller.load_board(request).await {
+                Ok(response) => {
+                    TimetableUpdate::Loaded(ClosingTimetableViewModel::from_response(&response))
+                }
+                Err(e) => TimetableUpdate::LoadFailed(e),
+            };
+            let _ = update_tx.send(update);
+        });
+    }
+
+    /// 対象月を前後に移動
+    fn shift_period(&mut self, forward: bool, controllers: &Controllers) {
+        self.period_selector.shift(forward);
+        self.page.set_loading();
+        self.load_board(controllers);
+    }
+
+    /// 入力中のキー操作
+    fn handle_input_key(&mut self, code: KeyCode, controllers: &Controllers) {
+        let Some(input) = self.input.as_mut() else {
+            return;
+        };
+        match code {
+            KeyCode::Esc => self.input = None,
+            KeyCode::Enter => self.submit_input(controllers),
+            KeyCode::Backspace => {
+                input.pop();
+            }
+            KeyCode::Char(c) => input.push(c),
+            _ => {}
+        }
+    }
+
+    /// 入力した締めタスクを保存（項目の検証はドメインで行う）
+    fn submit_input(&mut self, controllers: &Controllers) {
+        let Some(input) = self.input.take() else {
+            return;
+        };
+        let mut parts = input.splitn(5, ':');
+        let code = parts.next().unwrap_or_default().to_string();
+        let task = parts.next().unwrap_or_default().to_string();
+        let owner = parts.next().unwrap_or_default().to_string();
+        let Ok(due_offset_days) = parts.next().unwrap_or_default().trim().parse::<i32>() else {
+            self.page
+                .set_status_message("期日は期末日からの日数（例: 3、-2）で入力してください");
+            self.input = Some(input);
+            return;
+        };
+        let request = SaveClosingTimetableItemRequest {
+            code,
+            task,
+            owner,
+            due_offset_days,
+            step: parts.next().unwrap_or_default().to_string(),
+        };
+
+        let controller = Arc::clone(&controllers.closing_timetable);
+        let update_tx = self.update_tx.clone();
+        tokio::spawn(async move {
+            let label = request.code.trim().to_string();
+            let update = match controller.save_item(request).await {
+                Ok(()) => TimetableUpdate::Saved(format!("{} を保存しました", label)),
+                Err(e) => TimetableUpdate::SaveFailed(e),
+            };
+            let _ = update_tx.send(update);
+        });
+    }
+
+    /// 選択中のタスクの進捗を切り替える（未着手 → 進行中 → 完了）
+    fn cycle_selected_status(&self, controllers: &Controllers) {
+        let Some(item) = self.page.selected_item() else {
+            return;
+        };
+        let request = UpdateClosingTaskStatusRequest {
+            fiscal_year: self.period_selector.fiscal_year(),
+            period: self.period_selector.period(),
+            code: item.code.clone(),
+            status: item.next_status.clone(),
+            updated_by: controllers.session.user_id(),
+        };
+
+        let message = format!("{} を{}にしました", item.code, item.next_status_label);
+
+        let controller = Arc::clone(&controllers.closing_timetable);
+        let update_tx = self.update_tx.clone();
+        tokio::spawn(async move {
+            let update = match controller.update_status(request).await {
+                Ok(()) => TimetableUpdate::Saved(message),
+                Err(e) => TimetableUpdate::SaveFailed(e),
+            };
+            let _ = update_tx.send(update);
+        });
+    }
+
+    /// 選択中のタスクを削除
+    fn delete_selected(&self, controllers: &Controllers) {
+        let Some(item) = self.page.selected_item() else {
+            return;
+        };
+
+        let controller = Arc::clone(&controllers.closing_timetable);
+        let update_tx = self.update_tx.clone();
+        let request = DeleteClosingTimetableItemRequest { code: item.code.clone() };
+
+        tokio::spawn(async move {
+            let label = request.code.clone();
+            let update = match controller.delete_item(request).await {
+                Ok(()) => TimetableUpdate::Saved(format!("{} を削除しました", label)),
+                Err(e) => TimetableUpdate::SaveFailed(e),
+            };
+            let _ = update_tx.send(update);
+        });
+    }
+
+    /// 操作結果を反映
+    fn poll_updates(&mut self, controllers: &Controllers) {
+        while let Ok(update) = self.update_rx.try_recv() {
+            match update {
+                TimetableUpdate::Loaded(view_model) => self.page.set_data(view_model),
+                TimetableUpdate::Saved(message) => {
+                    self.page.set_status_message(message);
+                    self.load_board(controllers);
+                }
+                TimetableUpdate::SaveFailed(message) => {
+                    self.page.set_status_message(format!("更新に失敗しました: {}", message));
+                }
+                TimetableUpdate::LoadFailed(message) => self.page.set_error(message),
+            }
+        }
+    }
+}
+
+impl PageState for ClosingTimetablePageState {
+    fn route(&self) -> Route {
+        Route::ClosingTimetable
+    }
+
+    fn run(
+        &mut self,
+        terminal: &mut DefaultTerminal,
+        controllers: &Controllers,
+    ) -> AdapterResult<NavAction> {
+        // 初回ロード
+        if !self.data_loaded {
+            self.data_loaded = true;
+            self.load_board(controllers);
+        }
+
+        loop {
+            self.poll_updates(controllers);
+
+            terminal
+                .draw(|frame| {
+                    render_guarded(frame, |frame| self.page.render(frame, self.input.as_deref()));
+                })
+                .map_err(|e| crate::error::AdapterError::RenderingFailed(e.to_string()))?;
+
+            if controllers.session.lock_if_idle() {
+                return Ok(NavAction::Go(Route::Login));
+            }
+
+            if controllers.session.poll_input(std::time::Duration::from_millis(100))?
+                && let Event::Key(key) =
+                    event::read().map_err(crate::error::AdapterError::EventReadFailed)?
+            {
+                if key.kind != KeyEventKind::Press {
+                    continue;
+                }
+
+                if self.input.is_some() {
+                    self.handle_input_key(key.code, controllers);
+                    continue;
+                }
+
+                match key.code {
+                    KeyCode::Esc => return Ok(NavAction::Back),
+                    KeyCode::Up | KeyCode::Char('k') => self.page.select_previous(),
+                    KeyCode::Down | KeyCode::Char('j') => self.page.select_next(),
+                    KeyCode::Left | KeyCode::Char('h') => self.shift_period(false, controllers),
+                    KeyCode::Right | KeyCode::Char('l') => self.shift_period(true, controllers),
+                    KeyCode::Char(' ') => self.cycle_selected_status(controllers),
+                    KeyCode::Char('a') => self.input = Some(String::new()),
+                    KeyCode::Enter | KeyCode::Char('e') => {
+                        self.input = self.page.selected_item().map(|item| item.spec.clone())
+                    }
+                    KeyCode::Char('x') => self.delete_selected(controllers),
+                    KeyCode::Char('r') => self.load_board(controllers),
+                    _ => {}
+                }
+            }
+        }
+    }
+}
+
+impl Default for ClosingTimetablePageState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
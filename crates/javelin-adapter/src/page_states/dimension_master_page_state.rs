@@ -0,0 +1,242 @@
+// DimensionMasterPageState - 分析軸マスタ画面の状態
+// 責務: 分析軸一覧の取得と登録・更新・有効状態の切替・削除操作
+
+use std::sync::Arc;
+
+use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use javelin_application::dtos::request::{
+    DeleteDimensionMasterRequest, SaveDimensionMasterRequest,
+};
+use ratatui::DefaultTerminal;
+use tokio::sync::mpsc;
+
+use crate::{
+    error::AdapterResult,
+    navigation::{Controllers, NavAction, PageState, Route},
+    presenter::DimensionMasterViewModel,
+    views::{layouts::render_guarded, pages::DimensionMasterPage},
+};
+
+/// 操作結果
+enum DimensionUpdate {
+    Loaded(DimensionMasterViewModel),
+    Saved(String),
+    SaveFailed(String),
+    LoadFailed(String),
+}
+
+/// 編集中の分析軸
+struct DimensionInput {
+    /// 保存時の有効状態（新規は有効、既存は現在の状態を引き継ぐ）
+    is_active: bool,
+    value: String,
+}
+
+pub struct DimensionMasterPageState {
+    page: DimensionMasterPage,
+    update_tx: mpsc::UnboundedSender<DimensionUpdate>,
+    update_rx: mpsc::UnboundedReceiver<DimensionUpdate>,
+    input: Option<DimensionInput>,
+    /// データロード済みフラグ
+    data_loaded: bool,
+}
+
+impl DimensionMasterPageState {
+    pub fn new() -> Self {
+        let (update_tx, update_rx) = mpsc::unbounded_channel();
+        Self {
+            page: DimensionMasterPage::new(),
+            update_tx,
+            update_rx,
+            input: None,
+            data_loaded: false,
+        }
+    }
+
+    /// 分析軸一覧を再取得
+    fn load_masters(&self, controllers: &Controllers) {
+        let controller = Arc::clone(&controllers.dimension_master);
+        let update_tx = self.update_tx.clone();
+
+        tokio::spawn(async move {
+            let update = match controller.load_masters().await {
+                Ok(response) => {
+                    DimensionUpdate::Loaded(DimensionMasterViewModel::from_response(&response))
+                }
+                Err(e) => DimensionUpdate::LoadFailed(e),
+            };
+            let _ = update_tx.send(update);
+        });
+    }
+
+    /// 選択中の分析軸の編集を開始
+    fn start_edit(&mut self) {
+        if let Some(item) = self.page.selected_item() {
+            self.input =
+                Some(DimensionInput { is_active: item.is_active, value: item.spec.clone() });
+        }
+    }
+
+    /// 入力中のキー操作
+    fn handle_input_key(&mut self, code: KeyCode, controllers: &Controllers) {
+        let Some(input) = self.input.as_mut() else {
+            return;
+        };
+        match code {
+            KeyCode::Esc => self.input = None,
+            KeyCode::Enter => self.submit_input(controllers),
+            KeyCode::Backspace => {
+                input.value.pop();
+            }
+            KeyCode::Char(c) => input.value.push(c),
+            _ => {}
+        }
+    }
+
+    /// 入力した分析軸を保存（形式の検証はドメインで行う）
+    fn submit_input(&mut self, controllers: &Controllers) {
+        let Some(input) = self.input.take() else {
+            return;
+        };
+        let mut parts = input.value.splitn(3, ':');
+        let request = SaveDimensionMasterRequest {
+            kind: parts.next().unwrap_or_default().to_string(),
+            code: parts.next().unwrap_or_default().to_string(),
+            name: parts.next().unwrap_or_default().to_string(),
+            is_active: input.is_active,
+        };
+        self.save(request, controllers);
+    }
+
+    /// 選択中の分析軸の有効・無効を切り替える
+    fn toggle_selected(&self, controllers: &Controllers) {
+        let Some(item) = self.page.selected_item() else {
+            return;
+        };
+        let request = SaveDimensionMasterRequest {
+            kind: item.kind.clone(),
+            code: item.code.clone(),
+            name: item.name.clone(),
+            is_active: !item.is_active,
+        };
+        self.save(request, controllers);
+    }
+
+    fn save(&self, request: SaveDimensionMasterRequest, controllers: &Controllers) {
+        let controller = Arc::clone(&controllers.dimension_master);
+        let update_tx = self.update_tx.clone();
+
+        tokio::spawn(async move {
+            let label = format!("{}={}", request.kind.trim(), request.code.trim());
+            let update = match controller.save_master(request).await {
+                Ok(()) => DimensionUpdate::Saved(format!("{} を保存しました", label)),
+                Err(e) => DimensionUpdate::SaveFailed(e),
+            };
+            let _ = update_tx.send(update);
+        });
+    }
+
+    /// 選択中の分析軸を削除
+    fn delete_selected(&self, controllers: &Controllers) {
+        let Some(item) = self.page.selected_item() else {
+            return;
+        };
+
+        let controller = Arc::clone(&controllers.dimension_master);
+        let update_tx = self.update_tx.clone();
+        let request =
+            DeleteDimensionMasterRequest { kind: item.kind.clone(), code: item.code.clone() };
+
+        tokio::spawn(async move {
+            let label = format!("{}={}", request.kind, request.code);
+            let update = match controller.delete_master(request).await {
+                Ok(()) => DimensionUpdate::Saved(format!("{} を削除しました", label)),
+                Err(e) => DimensionUpdate::SaveFailed(e),
+            };
+            let _ = update_tx.send(update);
+        });
+    }
+
+    /// 操作結果を反映
+    fn poll_updates(&mut self, controllers: &Controllers) {
+        while let Ok(update) = self.update_rx.try_recv() {
+            match update {
+                DimensionUpdate::Loaded(view_model) => self.page.set_data(view_model),
+                DimensionUpdate::Saved(message) => {
+                    self.page.set_status_message(message);
+                    self.load_masters(controllers);
+                }
+                DimensionUpdate::SaveFailed(message) => {
+                    self.page.set_status_message(format!("更新に失敗しました: {}", message));
+                }
+                DimensionUpdate::LoadFailed(message) => self.page.set_error(message),
+            }
+        }
+    }
+}
+
+impl PageState for DimensionMasterPageState {
+    fn route(&self) -> Route {
+        Route::DimensionMaster
+    }
+
+    fn run(
+        &mut self,
+        terminal: &mut DefaultTerminal,
+        controllers: &Controllers,
+    ) -> AdapterResult<NavAction> {
+        // 初回ロード
+        if !self.data_loaded {
+            self.data_loaded = true;
+            self.load_masters(controllers);
+        }
+
+        loop {
+            self.poll_updates(controllers);
+
+            terminal
+                .draw(|frame| {
+                    let input = self.input.as_ref().map(|input| input.value.as_str());
+                    render_guarded(frame, |frame| self.page.render(frame, input));
+                })
+                .map_err(|e| crate::error::AdapterError::RenderingFailed(e.to_string()))?;
+
+            if controllers.session.lock_if_idle() {
+                return Ok(NavAction::Go(Route::Login));
+            }
+
+            if controllers.session.poll_input(std::time::Duration::from_millis(100))?
+                && let Event::Key(key) =
+                    event::read().map_err(crate::error::AdapterError::EventReadFailed)?
+            {
+                if key.kind != KeyEventKind::Press {
+                    continue;
+                }
+
+                if self.input.is_some() {
+                    self.handle_input_key(key.code, controllers);
+                    continue;
+                }
+
+                match key.code {
+                    KeyCode::Esc => return Ok(NavAction::Back),
+                    KeyCode::Up | KeyCode::Char('k') => self.page.select_previous(),
+                    KeyCode::Down | KeyCode::Char('j') => self.page.select_next(),
+                    KeyCode::Char('a') => {
+                        self.input = Some(DimensionInput { is_active: true, value: String::new() })
+                    }
+                    KeyCode::Enter | KeyCode::Char('e') => self.start_edit(),
+                    KeyCode::Char('t') => self.toggle_selected(controllers),
+                    KeyCode::Char('x') => self.delete_selected(controllers),
+                    _ => {}
+                }
+            }
+        }
+    }
+}
+
+impl Default for DimensionMasterPageState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
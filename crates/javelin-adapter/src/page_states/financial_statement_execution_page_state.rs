@@ -1,22 +1,135 @@
 // FinancialStatementExecutionPageState - 財務諸表生成実行画面の状態管理
 // 責務: 財務諸表生成実行画面の状態とイベント処理
 
+use std::sync::Arc;
+
 use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use javelin_application::dtos::{
+    GenerateFinancialStatementsRequest,
+    response::{ArchivedReportSummary, GenerateFinancialStatementsResponse},
+};
 use ratatui::DefaultTerminal;
+use tokio::sync::mpsc;
 
 use crate::{
     error::AdapterResult,
+    error_log::to_user_message,
     navigation::{Controllers, NavAction, Route, page_state::PageState},
-    views::pages::FinancialStatementExecutionPage,
+    page_states::period_selector::PeriodSelector,
+    views::{layouts::render_guarded, pages::FinancialStatementExecutionPage},
 };
 
+/// 生成・承認処理の結果
+enum StatementUpdate {
+    Generated(Box<GenerateFinancialStatementsResponse>),
+    GenerationFailed(String),
+    SignedOff(ArchivedReportSummary),
+    SignOffFailed(String),
+}
+
 pub struct FinancialStatementExecutionPageState {
     page: FinancialStatementExecutionPage,
+    period_selector: PeriodSelector,
+    running: bool,
+    /// 直近に生成した財務諸表（承認・アーカイブ対象）
+    statements: Option<GenerateFinancialStatementsResponse>,
+    update_tx: mpsc::UnboundedSender<StatementUpdate>,
+    update_rx: mpsc::UnboundedReceiver<StatementUpdate>,
 }
 
 impl FinancialStatementExecutionPageState {
     pub fn new() -> Self {
-        Self { page: FinancialStatementExecutionPage::new() }
+        let (update_tx, update_rx) = mpsc::unbounded_channel();
+        Self {
+            page: FinancialStatementExecutionPage::new(),
+            period_selector: PeriodSelector::current(),
+            running: false,
+            statements: None,
+            update_tx,
+            update_rx,
+        }
+    }
+
+    /// 対象月を前後に移動（生成済みの結果は破棄する）
+    fn shift_period(&mut self, forward: bool) {
+        self.period_selector.shift(forward);
+        self.statements = None;
+        self.page.set_period(&self.period_selector.label());
+    }
+
+    /// 財務諸表の生成を開始
+    fn start_execution(&mut self, controllers: &Controllers) {
+        if self.running {
+            return;
+        }
+        self.running = true;
+        self.statements = None;
+        self.page.start_execution(&self.period_selector.label());
+
+        let controller = Arc::clone(&controllers.closing);
+        let update_tx = self.update_tx.clone();
+        let request = GenerateFinancialStatementsRequest {
+            fiscal_year: self.period_selector.fiscal_year(),
+            period: self.period_selector.period(),
+        };
+
+        tokio::spawn(async move {
+            let update = match controller.generate_financial_statements(request).await {
+                Ok(response) => StatementUpdate::Generated(Box::new(response)),
+                Err(e) => StatementUpdate::GenerationFailed(to_user_message(e)),
+            };
+            let _ = update_tx.send(update);
+        });
+    }
+
+    /// 生成した財務諸表を承認し、アーカイブへ保存
+    fn sign_off(&mut self, controllers: &Controllers) {
+        if self.running {
+            return;
+        }
+        let Some(statements) = self.statements.clone() else {
+            self.page.add_error("承認する財務諸表がありません。[s] で生成してください");
+            return;
+        };
+        self.running = true;
+        self.page.start_sign_off();
+
+        let controller = Arc::clone(&controllers.report_archive);
+        let update_tx = self.update_tx.clone();
+        let signed_by = controllers.session.user_id();
+        let (fiscal_year, period) =
+            (self.period_selector.fiscal_year(), self.period_selector.period());
+
+        tokio::spawn(async move {
+            let update = match controller
+                .sign_off_financial_statements(fiscal_year, period, &statements, signed_by)
+                .await
+            {
+                Ok(summary) => StatementUpdate::SignedOff(summary),
+                Err(e) => StatementUpdate::SignOffFailed(e),
+            };
+            let _ = update_tx.send(update);
+        });
+    }
+
+    /// 処理結果を反映
+    fn poll_updates(&mut self) {
+        while let Ok(update) = self.update_rx.try_recv() {
+            self.running = false;
+            match update {
+                StatementUpdate::Generated(statements) => {
+                    self.page.set_statements(&statements);
+                    self.statements = Some(*statements);
+                }
+                StatementUpdate::GenerationFailed(error) => self.page.set_execution_error(error),
+                StatementUpdate::SignedOff(summary) => {
+                    // 同じ生成結果を二重に承認しない
+                    self.statements = None;
+                    self.page.set_signed_off(&summary);
+                }
+                StatementUpdate::SignOffFailed(error) => self.page.set_sign_off_error(error),
+            }
+        }
     }
 }
 
@@ -28,19 +141,23 @@ impl PageState for FinancialStatementExecutionPageState {
     fn run(
         &mut self,
         terminal: &mut DefaultTerminal,
-        _controllers: &Controllers,
+        controllers: &Controllers,
     ) -> AdapterResult<NavAction> {
         loop {
+            self.poll_updates();
             self.page.tick();
 
             terminal
                 .draw(|frame| {
-                    self.page.render(frame);
+                    render_guarded(frame, |frame| self.page.render(frame));
                 })
                 .map_err(|e| crate::error::AdapterError::RenderingFailed(e.to_string()))?;
 
-            if event::poll(std::time::Duration::from_millis(100))
-                .map_err(crate::error::AdapterError::EventReadFailed)?
+            if controllers.session.lock_if_idle() {
+                return Ok(NavAction::Go(Route::Login));
+            }
+
+            if controllers.session.poll_input(std::time::Duration::from_millis(100))?
                 && let Event::Key(key) =
                     event::read().map_err(crate::error::AdapterError::EventReadFailed)?
             {
@@ -50,7 +167,12 @@ impl PageState for FinancialStatementExecutionPageState {
 
                 match key.code {
                     KeyCode::Esc => return Ok(NavAction::Back),
-                    KeyCode::Char('s') => self.page.start_execution(),
+                    KeyCode::Char('s') => self.start_execution(controllers),
+                    KeyCode::Char('f') => self.sign_off(controllers),
+                    KeyCode::Char('a') => return Ok(NavAction::Go(Route::ReportArchive)),
+                    KeyCode::Char('n') => return Ok(NavAction::Go(Route::NoteCrossReference)),
+                    KeyCode::Char('h') | KeyCode::Left if !self.running => self.shift_period(false),
+                    KeyCode::Char('l') | KeyCode::Right if !self.running => self.shift_period(true),
                     KeyCode::Char('j') | KeyCode::Down => self.page.select_next(),
                     KeyCode::Char('k') | KeyCode::Up => self.page.select_previous(),
                     _ => {}
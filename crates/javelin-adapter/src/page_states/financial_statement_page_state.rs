@@ -10,7 +10,7 @@ use crate::{
     error::AdapterResult,
     navigation::{Controllers, NavAction, PageState, PresenterRegistry, Route},
     presenter::BatchHistoryPresenter,
-    views::pages::FinancialStatementPage,
+    views::{layouts::render_guarded, pages::FinancialStatementPage},
 };
 
 pub struct FinancialStatementPageState {
@@ -58,7 +58,7 @@ impl PageState for FinancialStatementPageState {
     fn run(
         &mut self,
         terminal: &mut DefaultTerminal,
-        _controllers: &Controllers,
+        controllers: &Controllers,
     ) -> AdapterResult<NavAction> {
         loop {
             if let Ok(result) = self.result_rx.try_recv() {
@@ -77,12 +77,15 @@ impl PageState for FinancialStatementPageState {
 
             terminal
                 .draw(|frame| {
-                    self.page.render(frame);
+                    render_guarded(frame, |frame| self.page.render(frame));
                 })
                 .map_err(|e| crate::error::AdapterError::RenderingFailed(e.to_string()))?;
 
-            if event::poll(std::time::Duration::from_millis(100))
-                .map_err(crate::error::AdapterError::EventReadFailed)?
+            if controllers.session.lock_if_idle() {
+                return Ok(NavAction::Go(Route::Login));
+            }
+
+            if controllers.session.poll_input(std::time::Duration::from_millis(100))?
                 && let Event::Key(key) =
                     event::read().map_err(crate::error::AdapterError::EventReadFailed)?
             {
@@ -95,6 +98,8 @@ impl PageState for FinancialStatementPageState {
                     KeyCode::Char('e') => {
                         return Ok(NavAction::Go(Route::FinancialStatementExecution));
                     }
+                    KeyCode::Char('a') => return Ok(NavAction::Go(Route::ReportArchive)),
+                    KeyCode::Char('n') => return Ok(NavAction::Go(Route::NoteCrossReference)),
                     KeyCode::Char('j') | KeyCode::Down => self.page.select_next(),
                     KeyCode::Char('k') | KeyCode::Up => self.page.select_previous(),
                     _ => {}
@@ -1,27 +1,133 @@
 // HomePageState - PageState implementation for home screen
 // Wraps HomePage and implements navigation logic
 
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
 use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use javelin_application::{
+    dtos::response::IntegrityAuditResponse, query_service::ApprovalSlaSummary,
+};
 use ratatui::DefaultTerminal;
+use tokio::sync::mpsc;
 
 use crate::{
     error::AdapterResult,
     navigation::{Controllers, NavAction, PageState, Route},
-    views::pages::{HomePage, home_page::ViewType},
+    views::{
+        layouts::render_guarded,
+        pages::{HomePage, home_page::ViewType},
+    },
 };
 
+/// How often the inbox badge and the integrity audit status are refreshed
+/// while the home screen is shown
+const INBOX_REFRESH_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Inbox badge contents: item count and approvals over SLA
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct InboxBadge {
+    count: usize,
+    overdue: usize,
+}
+
 /// PageState implementation for the home screen
 ///
 /// The home screen displays the main menu and allows users to
-/// navigate to other screens. It has no channels or async communication.
+/// navigate to other screens. The inbox item count and the number of
+/// approvals over SLA are loaded asynchronously each time the screen is
+/// shown, refreshed periodically, and displayed as a badge. The latest
+/// integrity audit (pass/fail and findings) is loaded the same way.
 pub struct HomePageState {
     page: HomePage,
+    inbox_badge_tx: mpsc::UnboundedSender<InboxBadge>,
+    inbox_badge_rx: mpsc::UnboundedReceiver<InboxBadge>,
+    /// Last badge shown, used to log only when approvals newly go over SLA
+    inbox_badge: Option<InboxBadge>,
+    integrity_audit_tx: mpsc::UnboundedSender<Option<IntegrityAuditResponse>>,
+    integrity_audit_rx: mpsc::UnboundedReceiver<Option<IntegrityAuditResponse>>,
+    /// Last audit shown, used to log only when a new audit has failed
+    integrity_audit_id: Option<String>,
 }
 
 impl HomePageState {
     /// Create a new HomePageState
     pub fn new() -> Self {
-        Self { page: HomePage::new() }
+        let (inbox_badge_tx, inbox_badge_rx) = mpsc::unbounded_channel();
+        let (integrity_audit_tx, integrity_audit_rx) = mpsc::unbounded_channel();
+        Self {
+            page: HomePage::new(),
+            inbox_badge_tx,
+            inbox_badge_rx,
+            inbox_badge: None,
+            integrity_audit_tx,
+            integrity_audit_rx,
+            integrity_audit_id: None,
+        }
+    }
+
+    /// Load the inbox badge in the background
+    fn load_inbox_badge(&self, controllers: &Controllers) {
+        let controller = Arc::clone(&controllers.inbox);
+        let inbox_badge_tx = self.inbox_badge_tx.clone();
+
+        tokio::spawn(async move {
+            let Ok(items) = controller.load_inbox().await else {
+                return;
+            };
+            let sla = controller.load_approval_sla().await.unwrap_or_default();
+            let now = crate::clock::now().with_timezone(&chrono::Utc);
+            let summary = ApprovalSlaSummary::from_items(&items, sla, now);
+            let _ =
+                inbox_badge_tx.send(InboxBadge { count: items.len(), overdue: summary.breached });
+        });
+    }
+
+    /// Load the latest integrity audit in the background
+    fn load_integrity_audit(&self, controllers: &Controllers) {
+        let controller = Arc::clone(&controllers.integrity_audit);
+        let integrity_audit_tx = self.integrity_audit_tx.clone();
+
+        tokio::spawn(async move {
+            if let Ok(audit) = controller.latest().await {
+                let _ = integrity_audit_tx.send(audit);
+            }
+        });
+    }
+
+    /// Apply the loaded integrity audit to the page
+    fn poll_integrity_audit(&mut self) {
+        while let Ok(audit) = self.integrity_audit_rx.try_recv() {
+            if let Some(audit) = &audit
+                && self.integrity_audit_id.as_deref() != Some(audit.audit_id.as_str())
+            {
+                if !audit.passed {
+                    self.page.add_error(&format!(
+                        "整合性監査で検出事項が{}件あります",
+                        audit.finding_count
+                    ));
+                }
+                self.integrity_audit_id = Some(audit.audit_id.clone());
+            }
+            self.page.set_integrity_audit(audit);
+        }
+    }
+
+    /// Apply loaded inbox badges to the page
+    fn poll_inbox_badge(&mut self) {
+        while let Ok(badge) = self.inbox_badge_rx.try_recv() {
+            let previous_overdue = self.inbox_badge.map_or(0, |badge| badge.overdue);
+            if badge.overdue > previous_overdue {
+                self.page.add_error(&format!(
+                    "承認待ちのうち{}件が承認SLAを超過しています",
+                    badge.overdue
+                ));
+            }
+            self.page.set_inbox_count(badge.count, badge.overdue);
+            self.inbox_badge = Some(badge);
+        }
     }
 }
 
@@ -33,19 +139,36 @@ impl PageState for HomePageState {
     fn run(
         &mut self,
         terminal: &mut DefaultTerminal,
-        _controllers: &Controllers,
+        controllers: &Controllers,
     ) -> AdapterResult<NavAction> {
+        self.load_inbox_badge(controllers);
+        self.load_integrity_audit(controllers);
+        let mut last_refresh = Instant::now();
+
         loop {
+            if last_refresh.elapsed() >= INBOX_REFRESH_INTERVAL {
+                self.load_inbox_badge(controllers);
+                self.load_integrity_audit(controllers);
+                last_refresh = Instant::now();
+            }
+            self.poll_inbox_badge();
+            self.poll_integrity_audit();
+
             // Render the page
             terminal
                 .draw(|frame| {
-                    self.page.render(frame);
+                    render_guarded(frame, |frame| self.page.render(frame));
                 })
                 .map_err(|e| crate::error::AdapterError::RenderingFailed(e.to_string()))?;
 
-            // Handle events
-            if let Event::Key(key) =
-                event::read().map_err(crate::error::AdapterError::EventReadFailed)?
+            if controllers.session.lock_if_idle() {
+                return Ok(NavAction::Go(Route::Login));
+            }
+
+            // Handle events (poll so that background updates are rendered)
+            if controllers.session.poll_input(std::time::Duration::from_millis(100))?
+                && let Event::Key(key) =
+                    event::read().map_err(crate::error::AdapterError::EventReadFailed)?
             {
                 if key.kind != KeyEventKind::Press {
                     continue;
@@ -81,6 +204,10 @@ impl PageState for HomePageState {
     fn on_navigation_error(&mut self, error_message: &str) {
         self.page.add_error(error_message);
     }
+
+    fn on_notice(&mut self, message: &str) {
+        self.page.add_info(message);
+    }
 }
 
 impl Default for HomePageState {
@@ -98,7 +225,9 @@ fn view_type_to_route(view_type: ViewType) -> Route {
         ViewType::Home => Route::Home,
         ViewType::JournalEntry => Route::JournalEntry,
         ViewType::Search => Route::Search,
+        ViewType::SupplierInvoice => Route::SupplierInvoice,
         ViewType::Ledger => Route::Ledger,
+        ViewType::ReportHub => Route::ReportHub,
         ViewType::LedgerConsolidation => Route::LedgerConsolidation,
         ViewType::ClosingPreparation => Route::ClosingPreparation,
         ViewType::ClosingLock => Route::ClosingLock,
@@ -107,11 +236,19 @@ fn view_type_to_route(view_type: ViewType) -> Route {
         ViewType::AccountAdjustment => Route::AccountAdjustment,
         ViewType::IfrsValuation => Route::IfrsValuation,
         ViewType::FinancialStatement => Route::FinancialStatement,
+        ViewType::ClosingTimetable => Route::ClosingTimetable,
         ViewType::AccountMasterManagement => Route::AccountMaster,
         ViewType::SubsidiaryAccountMasterManagement => Route::SubsidiaryAccountMaster,
         ViewType::UserSettingsManagement => Route::ApplicationSettings,
         ViewType::DataImport => Route::DataImport,
         ViewType::DataExport => Route::DataExport,
+        ViewType::StatementLineMappingManagement => Route::StatementLineMapping,
+        ViewType::Maintenance => Route::Maintenance,
+        ViewType::AccountingPolicy => Route::AccountingPolicy,
+        ViewType::DimensionMasterManagement => Route::DimensionMaster,
+        ViewType::SystemInfo => Route::SystemInfo,
+        ViewType::Inbox => Route::Inbox,
+        ViewType::KpiDashboard => Route::KpiDashboard,
     }
 }
 
@@ -130,7 +267,9 @@ mod tests {
         assert_eq!(view_type_to_route(ViewType::Home), Route::Home);
         assert_eq!(view_type_to_route(ViewType::JournalEntry), Route::JournalEntry);
         assert_eq!(view_type_to_route(ViewType::Search), Route::Search);
+        assert_eq!(view_type_to_route(ViewType::SupplierInvoice), Route::SupplierInvoice);
         assert_eq!(view_type_to_route(ViewType::Ledger), Route::Ledger);
+        assert_eq!(view_type_to_route(ViewType::ReportHub), Route::ReportHub);
         assert_eq!(view_type_to_route(ViewType::LedgerConsolidation), Route::LedgerConsolidation);
         assert_eq!(view_type_to_route(ViewType::ClosingPreparation), Route::ClosingPreparation);
         assert_eq!(view_type_to_route(ViewType::ClosingLock), Route::ClosingLock);
@@ -139,6 +278,7 @@ mod tests {
         assert_eq!(view_type_to_route(ViewType::AccountAdjustment), Route::AccountAdjustment);
         assert_eq!(view_type_to_route(ViewType::IfrsValuation), Route::IfrsValuation);
         assert_eq!(view_type_to_route(ViewType::FinancialStatement), Route::FinancialStatement);
+        assert_eq!(view_type_to_route(ViewType::ClosingTimetable), Route::ClosingTimetable);
         assert_eq!(view_type_to_route(ViewType::AccountMasterManagement), Route::AccountMaster);
         assert_eq!(
             view_type_to_route(ViewType::SubsidiaryAccountMasterManagement),
@@ -150,6 +290,16 @@ mod tests {
         );
         assert_eq!(view_type_to_route(ViewType::DataImport), Route::DataImport);
         assert_eq!(view_type_to_route(ViewType::DataExport), Route::DataExport);
+        assert_eq!(
+            view_type_to_route(ViewType::StatementLineMappingManagement),
+            Route::StatementLineMapping
+        );
+        assert_eq!(view_type_to_route(ViewType::Maintenance), Route::Maintenance);
+        assert_eq!(view_type_to_route(ViewType::AccountingPolicy), Route::AccountingPolicy);
+        assert_eq!(view_type_to_route(ViewType::DimensionMasterManagement), Route::DimensionMaster);
+        assert_eq!(view_type_to_route(ViewType::SystemInfo), Route::SystemInfo);
+        assert_eq!(view_type_to_route(ViewType::Inbox), Route::Inbox);
+        assert_eq!(view_type_to_route(ViewType::KpiDashboard), Route::KpiDashboard);
     }
 
     #[test]
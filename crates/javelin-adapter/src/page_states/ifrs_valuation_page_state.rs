@@ -1,24 +1,40 @@
 // IfrsValuationPageState - PageState implementation for IFRS valuation screen
 
-use std::sync::Arc;
+use std::{path::PathBuf, sync::Arc};
 
 use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use javelin_application::dtos::response::FinancialInstrumentResponse;
 use ratatui::DefaultTerminal;
+use tokio::sync::mpsc;
 use uuid::Uuid;
 
 use crate::{
     error::AdapterResult,
     navigation::{Controllers, NavAction, PageState, PresenterRegistry, Route},
     presenter::BatchHistoryPresenter,
-    views::pages::IfrsValuationPage,
+    views::{layouts::render_guarded, pages::IfrsValuationPage},
 };
 
+/// 金融商品の取込ファイル
+const INSTRUMENT_IMPORT_FILE: &str = "financial_instruments.csv";
+
+/// 金融商品の読込・取込の結果
+enum InstrumentUpdate {
+    Loaded(Vec<FinancialInstrumentResponse>),
+    Imported(usize),
+    Failed(String),
+}
+
 pub struct IfrsValuationPageState {
     page: IfrsValuationPage,
     page_id: Uuid,
     registry: Arc<PresenterRegistry>,
     result_rx: tokio::sync::mpsc::Receiver<crate::presenter::BatchHistoryViewModel>,
     error_rx: tokio::sync::mpsc::Receiver<String>,
+    update_tx: mpsc::UnboundedSender<InstrumentUpdate>,
+    update_rx: mpsc::UnboundedReceiver<InstrumentUpdate>,
+    /// 金融商品を読込済みか
+    instruments_loaded: bool,
 }
 
 impl IfrsValuationPageState {
@@ -40,12 +56,75 @@ impl IfrsValuationPageState {
             let _ = controller.handle_get_history(page_id, batch_type).await;
         });
 
+        let (update_tx, update_rx) = mpsc::unbounded_channel();
+
         Self {
             page,
             page_id,
             registry,
             result_rx: channels.result_rx,
             error_rx: channels.error_rx,
+            update_tx,
+            update_rx,
+            instruments_loaded: false,
+        }
+    }
+
+    /// 金融商品と償却原価スケジュールを読込
+    fn load_instruments(&mut self, controllers: &Controllers) {
+        self.instruments_loaded = true;
+        self.page.start_schedule_loading();
+        let controller = Arc::clone(&controllers.financial_instrument);
+        let update_tx = self.update_tx.clone();
+
+        tokio::spawn(async move {
+            let update = match controller.list().await {
+                Ok(instruments) => InstrumentUpdate::Loaded(instruments),
+                Err(e) => InstrumentUpdate::Failed(e),
+            };
+            let _ = update_tx.send(update);
+        });
+    }
+
+    /// 取込ファイルから金融商品を登録し、スケジュールを読み直す
+    fn import_instruments(&mut self, controllers: &Controllers) {
+        self.page.start_schedule_loading();
+        let controller = Arc::clone(&controllers.financial_instrument);
+        let update_tx = self.update_tx.clone();
+        let path = PathBuf::from(INSTRUMENT_IMPORT_FILE);
+
+        tokio::spawn(async move {
+            let imported = match controller.import_csv(&path).await {
+                Ok(count) => InstrumentUpdate::Imported(count),
+                Err(e) => InstrumentUpdate::Failed(e),
+            };
+            let _ = update_tx.send(imported);
+            let update = match controller.list().await {
+                Ok(instruments) => InstrumentUpdate::Loaded(instruments),
+                Err(e) => InstrumentUpdate::Failed(e),
+            };
+            let _ = update_tx.send(update);
+        });
+    }
+
+    /// 読込・取込の結果を反映
+    fn poll_updates(&mut self) {
+        while let Ok(update) = self.update_rx.try_recv() {
+            match update {
+                InstrumentUpdate::Loaded(instruments) => {
+                    if instruments.is_empty() {
+                        self.page.add_info(format!(
+                            "金融商品が未登録です（[i] で {} を取り込みます）",
+                            INSTRUMENT_IMPORT_FILE
+                        ));
+                    }
+                    self.page.set_instruments(instruments);
+                }
+                InstrumentUpdate::Imported(count) => {
+                    self.page.add_info(format!("金融商品を取り込みました: {}件", count));
+                }
+                InstrumentUpdate::Failed(error) => self.page.set_schedule_error(error),
+            }
         }
     }
 }
@@ -58,7 +137,7 @@ impl PageState for IfrsValuationPageState {
     fn run(
         &mut self,
         terminal: &mut DefaultTerminal,
-        _controllers: &Controllers,
+        controllers: &Controllers,
     ) -> AdapterResult<NavAction> {
         loop {
             if let Ok(result) = self.result_rx.try_recv() {
@@ -73,16 +152,21 @@ impl PageState for IfrsValuationPageState {
                 self.page.set_error(error);
             }
 
+            self.poll_updates();
+
             self.page.tick();
 
             terminal
                 .draw(|frame| {
-                    self.page.render(frame);
+                    render_guarded(frame, |frame| self.page.render(frame));
                 })
                 .map_err(|e| crate::error::AdapterError::RenderingFailed(e.to_string()))?;
 
-            if event::poll(std::time::Duration::from_millis(100))
-                .map_err(crate::error::AdapterError::EventReadFailed)?
+            if controllers.session.lock_if_idle() {
+                return Ok(NavAction::Go(Route::Login));
+            }
+
+            if controllers.session.poll_input(std::time::Duration::from_millis(100))?
                 && let Event::Key(key) =
                     event::read().map_err(crate::error::AdapterError::EventReadFailed)?
             {
@@ -90,11 +174,35 @@ impl PageState for IfrsValuationPageState {
                     continue;
                 }
 
+                if self.page.is_schedule_view() {
+                    match key.code {
+                        KeyCode::Esc => return Ok(NavAction::Back),
+                        KeyCode::Char('a') => self.page.toggle_view(),
+                        KeyCode::Char('i') => self.import_instruments(controllers),
+                        KeyCode::F(5) => self.load_instruments(controllers),
+                        KeyCode::Char('h') | KeyCode::Left => self.page.previous_instrument(),
+                        KeyCode::Char('l') | KeyCode::Right => self.page.next_instrument(),
+                        KeyCode::Char('j') | KeyCode::Down => self.page.select_next(),
+                        KeyCode::Char('k') | KeyCode::Up => self.page.select_previous(),
+                        _ => {}
+                    }
+                    continue;
+                }
+
                 match key.code {
                     KeyCode::Esc => return Ok(NavAction::Back),
+                    KeyCode::Char('a') => {
+                        self.page.toggle_view();
+                        if !self.instruments_loaded {
+                            self.load_instruments(controllers);
+                        }
+                    }
                     KeyCode::Char('e') => {
                         return Ok(NavAction::Go(Route::IfrsValuationExecution));
                     }
+                    KeyCode::Char('w') => {
+                        return Ok(NavAction::Go(Route::InventoryWorksheet));
+                    }
                     KeyCode::Char('j') | KeyCode::Down => self.page.select_next(),
                     KeyCode::Char('k') | KeyCode::Up => self.page.select_previous(),
                     _ => {}
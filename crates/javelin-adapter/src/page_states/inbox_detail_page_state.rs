@@ -0,0 +1,172 @@
+// InboxDetailPageState - 受信箱項目の詳細画面の状態
+// 責務: 選択された仕訳の表示と、承認・差戻しの実行
+
+use std::sync::Arc;
+
+use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use javelin_domain::financial_close::journal_entry::values::{JournalAction, JournalStatus};
+use ratatui::DefaultTerminal;
+use tokio::sync::mpsc;
+
+use crate::{
+    error::AdapterResult,
+    navigation::{Controllers, NavAction, PageState, Route},
+    page_states::InboxPageState,
+    views::{layouts::render_guarded, pages::InboxDetailPage},
+};
+
+/// 承認・差戻しの結果
+enum InboxDetailUpdate {
+    Approved,
+    Rejected,
+    Failed(String),
+}
+
+pub struct InboxDetailPageState {
+    page: InboxDetailPage,
+    update_tx: mpsc::UnboundedSender<InboxDetailUpdate>,
+    update_rx: mpsc::UnboundedReceiver<InboxDetailUpdate>,
+}
+
+impl InboxDetailPageState {
+    pub fn new() -> Self {
+        // 受信箱画面で選択された項目を取得
+        let page = InboxPageState::take_selected_item()
+            .map(InboxDetailPage::new)
+            .unwrap_or_default();
+        let (update_tx, update_rx) = mpsc::unbounded_channel();
+
+        Self { page, update_tx, update_rx }
+    }
+
+    /// 承認（承認できない場合は理由を表示）
+    fn approve(&mut self, controllers: &Controllers) {
+        let entry_id = match self.page.check_action(JournalAction::Approve) {
+            Ok(entry_id) => entry_id,
+            Err(reason) => return self.page.set_error(reason),
+        };
+        self.page.start_processing("承認しています...");
+
+        let controller = Arc::clone(&controllers.journal_entry);
+        let approver_id = controllers.session.user_id();
+        let update_tx = self.update_tx.clone();
+        tokio::spawn(async move {
+            let update = match controller.approve_journal_entry(entry_id, approver_id).await {
+                Ok(()) => InboxDetailUpdate::Approved,
+                Err(e) => InboxDetailUpdate::Failed(e),
+            };
+            let _ = update_tx.send(update);
+        });
+    }
+
+    /// 差戻理由の入力を開始（差戻しできない場合は理由を表示）
+    fn start_reject(&mut self) {
+        match self.page.check_action(JournalAction::Reject) {
+            Ok(_) => self.page.start_reason_input(),
+            Err(reason) => self.page.set_error(reason),
+        }
+    }
+
+    /// 入力した理由で差戻し
+    fn reject(&mut self, controllers: &Controllers) {
+        let Some(reason) = self.page.take_reason_input() else {
+            return;
+        };
+        let entry_id = match self.page.check_action(JournalAction::Reject) {
+            Ok(entry_id) => entry_id,
+            Err(reason) => return self.page.set_error(reason),
+        };
+        self.page.start_processing("差し戻しています...");
+
+        let controller = Arc::clone(&controllers.journal_entry);
+        let rejected_by = controllers.session.user_id();
+        let update_tx = self.update_tx.clone();
+        tokio::spawn(async move {
+            let update = match controller.reject_journal_entry(entry_id, reason, rejected_by).await
+            {
+                Ok(()) => InboxDetailUpdate::Rejected,
+                Err(e) => InboxDetailUpdate::Failed(e),
+            };
+            let _ = update_tx.send(update);
+        });
+    }
+
+    /// 実行結果を反映
+    fn poll_updates(&mut self) {
+        while let Ok(update) = self.update_rx.try_recv() {
+            match update {
+                InboxDetailUpdate::Approved => {
+                    self.page.complete(JournalStatus::Posted.as_str(), "承認しました")
+                }
+                InboxDetailUpdate::Rejected => {
+                    self.page.complete(JournalStatus::Draft.as_str(), "差し戻しました")
+                }
+                InboxDetailUpdate::Failed(e) => self.page.set_error(e),
+            }
+        }
+    }
+
+    /// 差戻理由の入力中のキー操作
+    fn handle_reason_key(&mut self, code: KeyCode, controllers: &Controllers) {
+        match code {
+            KeyCode::Enter => self.reject(controllers),
+            KeyCode::Esc => self.page.cancel_reason_input(),
+            KeyCode::Backspace => self.page.delete_reason_char(),
+            KeyCode::Char(c) => self.page.input_reason_char(c),
+            _ => {}
+        }
+    }
+}
+
+impl Default for InboxDetailPageState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PageState for InboxDetailPageState {
+    fn route(&self) -> Route {
+        Route::InboxDetail
+    }
+
+    fn run(
+        &mut self,
+        terminal: &mut DefaultTerminal,
+        controllers: &Controllers,
+    ) -> AdapterResult<NavAction> {
+        loop {
+            self.poll_updates();
+
+            terminal
+                .draw(|frame| {
+                    render_guarded(frame, |frame| self.page.render(frame));
+                })
+                .map_err(|e| crate::error::AdapterError::RenderingFailed(e.to_string()))?;
+
+            if controllers.session.lock_if_idle() {
+                return Ok(NavAction::Go(Route::Login));
+            }
+
+            if controllers.session.poll_input(std::time::Duration::from_millis(100))?
+                && let Event::Key(key) =
+                    event::read().map_err(crate::error::AdapterError::EventReadFailed)?
+            {
+                if key.kind != KeyEventKind::Press {
+                    continue;
+                }
+
+                if self.page.is_entering_reason() {
+                    self.handle_reason_key(key.code, controllers);
+                    continue;
+                }
+
+                match key.code {
+                    KeyCode::Esc => return Ok(NavAction::Back),
+                    KeyCode::Char('a') => self.approve(controllers),
+                    KeyCode::Char('d') => self.start_reject(),
+                    _ => {}
+                }
+            }
+        }
+    }
+}
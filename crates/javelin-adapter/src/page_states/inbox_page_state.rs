@@ -0,0 +1,140 @@
+// InboxPageState - 受信箱画面の状態
+// 責務: 受信箱の取得と詳細画面への遷移
+
+use std::sync::{Arc, Mutex};
+
+use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use ratatui::DefaultTerminal;
+use tokio::sync::mpsc;
+
+use crate::{
+    error::AdapterResult,
+    navigation::{Controllers, NavAction, PageState, Route},
+    presenter::{InboxItemViewModel, InboxViewModel},
+    views::{layouts::render_guarded, pages::InboxPage},
+};
+
+// Shared state for passing selected item to detail view
+lazy_static::lazy_static! {
+    static ref SELECTED_INBOX_ITEM: Arc<Mutex<Option<InboxItemViewModel>>> =
+        Arc::new(Mutex::new(None));
+}
+
+pub struct InboxPageState {
+    page: InboxPage,
+    update_tx: mpsc::UnboundedSender<Result<InboxViewModel, String>>,
+    update_rx: mpsc::UnboundedReceiver<Result<InboxViewModel, String>>,
+}
+
+impl InboxPageState {
+    pub fn new() -> Self {
+        let (update_tx, update_rx) = mpsc::unbounded_channel();
+        Self { page: InboxPage::new(), update_tx, update_rx }
+    }
+
+    /// 受信箱を再取得（承認待ちは承認SLAで滞留状況を判定）
+    fn load_inbox(&mut self, controllers: &Controllers) {
+        let controller = Arc::clone(&controllers.inbox);
+        let update_tx = self.update_tx.clone();
+        self.page.set_user_id(controller.user_id());
+
+        tokio::spawn(async move {
+            let result = async {
+                let items = controller.load_inbox().await?;
+                let sla = controller.load_approval_sla().await?;
+                let now = crate::clock::now().with_timezone(&chrono::Utc);
+                Ok(InboxViewModel::from_items(&items, sla, now))
+            }
+            .await;
+            let _ = update_tx.send(result);
+        });
+    }
+
+    /// 取得結果を反映
+    fn poll_updates(&mut self) {
+        while let Ok(result) = self.update_rx.try_recv() {
+            match result {
+                Ok(view_model) => self.page.set_data(view_model),
+                Err(e) => self.page.set_error(e),
+            }
+        }
+    }
+
+    /// 選択された項目を共有状態に保存
+    fn store_selected_item(&self) -> bool {
+        if let Some(item) = self.page.selected_item()
+            && let Ok(mut guard) = SELECTED_INBOX_ITEM.lock()
+        {
+            *guard = Some(item.clone());
+            return true;
+        }
+        false
+    }
+
+    /// 共有状態から選択された項目を取得
+    pub fn take_selected_item() -> Option<InboxItemViewModel> {
+        SELECTED_INBOX_ITEM.lock().ok()?.take()
+    }
+}
+
+impl PageState for InboxPageState {
+    fn route(&self) -> Route {
+        Route::Inbox
+    }
+
+    fn run(
+        &mut self,
+        terminal: &mut DefaultTerminal,
+        controllers: &Controllers,
+    ) -> AdapterResult<NavAction> {
+        // 詳細画面から戻った時も最新の状態を表示
+        self.load_inbox(controllers);
+        let mut projection_changes = controllers.projection_events.subscribe();
+
+        loop {
+            // 仕訳一覧が更新されたら承認待ちの状況も再取得
+            if projection_changes.poll().journal_list_changed() {
+                self.load_inbox(controllers);
+            }
+            self.poll_updates();
+
+            terminal
+                .draw(|frame| {
+                    render_guarded(frame, |frame| self.page.render(frame));
+                })
+                .map_err(|e| crate::error::AdapterError::RenderingFailed(e.to_string()))?;
+
+            if controllers.session.lock_if_idle() {
+                return Ok(NavAction::Go(Route::Login));
+            }
+
+            if controllers.session.poll_input(std::time::Duration::from_millis(100))?
+                && let Event::Key(key) =
+                    event::read().map_err(crate::error::AdapterError::EventReadFailed)?
+            {
+                if key.kind != KeyEventKind::Press {
+                    continue;
+                }
+
+                match key.code {
+                    KeyCode::Esc => return Ok(NavAction::Back),
+                    KeyCode::Enter => {
+                        if self.store_selected_item() {
+                            return Ok(NavAction::Go(Route::InboxDetail));
+                        }
+                    }
+                    KeyCode::Char('r') => self.load_inbox(controllers),
+                    KeyCode::Up | KeyCode::Char('k') => self.page.select_previous(),
+                    KeyCode::Down | KeyCode::Char('j') => self.page.select_next(),
+                    _ => {}
+                }
+            }
+        }
+    }
+}
+
+impl Default for InboxPageState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
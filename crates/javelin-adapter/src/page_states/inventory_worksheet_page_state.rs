@@ -0,0 +1,225 @@
+// InventoryWorksheetPageState - 棚卸資産評価ワークシート画面の状態管理
+
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use chrono::Datelike;
+use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use javelin_application::dtos::response::InventoryWorksheetResponse;
+use ratatui::DefaultTerminal;
+use tokio::sync::mpsc;
+
+use crate::{
+    error::AdapterResult,
+    navigation::{
+        Controllers, NavAction, PageState, Route,
+        controllers::{InventoryWorksheetControllerType, JobQueueControllerType},
+    },
+    views::{layouts::render_guarded, pages::InventoryWorksheetPage},
+};
+
+/// ワークシートの通貨
+const WORKSHEET_CURRENCY: &str = "JPY";
+
+/// 非同期処理の結果
+enum WorksheetUpdate {
+    Loaded(Option<InventoryWorksheetResponse>),
+    Imported(InventoryWorksheetResponse),
+    Failed(String),
+}
+
+pub struct InventoryWorksheetPageState {
+    page: InventoryWorksheetPage,
+    fiscal_year: i32,
+    period: u8,
+    update_tx: mpsc::UnboundedSender<WorksheetUpdate>,
+    update_rx: mpsc::UnboundedReceiver<WorksheetUpdate>,
+    /// データロード済みフラグ
+    data_loaded: bool,
+}
+
+impl InventoryWorksheetPageState {
+    pub fn new() -> Self {
+        let today = crate::clock::business_date();
+        let (fiscal_year, period) = (today.year(), today.month() as u8);
+        let (update_tx, update_rx) = mpsc::unbounded_channel();
+
+        Self {
+            page: InventoryWorksheetPage::new(format!(
+                "inventory_valuation_{:04}{:02}.csv",
+                fiscal_year, period
+            )),
+            fiscal_year,
+            period,
+            update_tx,
+            update_rx,
+            data_loaded: false,
+        }
+    }
+
+    /// 会計期間のワークシートを読み込む
+    fn load_worksheet(&mut self, controllers: &Controllers) {
+        self.page.start_loading();
+        let controller = Arc::clone(&controllers.inventory_worksheet);
+        let update_tx = self.update_tx.clone();
+        let (fiscal_year, period) = (self.fiscal_year, self.period);
+
+        tokio::spawn(async move {
+            let update = match controller.load(fiscal_year, period).await {
+                Ok(worksheet) => WorksheetUpdate::Loaded(worksheet),
+                Err(e) => WorksheetUpdate::Failed(e),
+            };
+            let _ = update_tx.send(update);
+        });
+    }
+
+    /// 取込ファイルからワークシートを作成
+    ///
+    /// 取込はジョブとして登録し、完了後に作成されたワークシートを読み込む。
+    fn import_worksheet(&mut self, controllers: &Controllers) {
+        self.page.start_loading();
+        let job_queue = Arc::clone(&controllers.job_queue);
+        let controller = Arc::clone(&controllers.inventory_worksheet);
+        let update_tx = self.update_tx.clone();
+        let (fiscal_year, period) = (self.fiscal_year, self.period);
+        let method = self.page.method().code().to_string();
+        let path = PathBuf::from(self.page.import_file_name());
+        let requested_by = controllers.session.user_id();
+
+        tokio::spawn(async move {
+            let update = match import_as_job(
+                &job_queue,
+                &controller,
+                fiscal_year,
+                period,
+                method,
+                &path,
+                requested_by,
+            )
+            .await
+            {
+                Ok(worksheet) => WorksheetUpdate::Imported(worksheet),
+                Err(e) => WorksheetUpdate::Failed(e),
+            };
+            let _ = update_tx.send(update);
+        });
+    }
+
+    fn apply_update(&mut self, update: WorksheetUpdate) {
+        match update {
+            WorksheetUpdate::Loaded(Some(worksheet)) => self.page.set_worksheet(&worksheet),
+            WorksheetUpdate::Loaded(None) => self.page.set_empty(),
+            WorksheetUpdate::Imported(worksheet) => {
+                self.page.set_worksheet(&worksheet);
+                self.page.add_info(format!(
+                    "取り込みました: {} ({}品目)",
+                    self.page.import_file_name(),
+                    worksheet.item_count
+                ));
+            }
+            WorksheetUpdate::Failed(error) => self.page.set_error(error),
+        }
+    }
+}
+
+/// 取込ジョブを登録して終了を待ち、作成されたワークシートを返す
+async fn import_as_job(
+    job_queue: &JobQueueControllerType,
+    controller: &InventoryWorksheetControllerType,
+    fiscal_year: i32,
+    period: u8,
+    method: String,
+    path: &Path,
+    requested_by: String,
+) -> Result<InventoryWorksheetResponse, String> {
+    let job = job_queue
+        .enqueue_inventory_import(
+            fiscal_year,
+            period,
+            method,
+            WORKSHEET_CURRENCY.to_string(),
+            path,
+            requested_by,
+        )
+        .await?;
+    let job = job_queue.watch(job.job_id, |_| {}).await?;
+    if !job.is_completed() {
+        return Err(format!(
+            "取込ジョブが{}しました: {}",
+            job.status_label,
+            job.message.unwrap_or_default()
+        ));
+    }
+
+    controller
+        .load(fiscal_year, period)
+        .await?
+        .ok_or_else(|| "取り込んだワークシートが見つかりません".to_string())
+}
+
+impl PageState for InventoryWorksheetPageState {
+    fn route(&self) -> Route {
+        Route::InventoryWorksheet
+    }
+
+    fn run(
+        &mut self,
+        terminal: &mut DefaultTerminal,
+        controllers: &Controllers,
+    ) -> AdapterResult<NavAction> {
+        // 初回ロード
+        if !self.data_loaded {
+            self.data_loaded = true;
+            self.load_worksheet(controllers);
+        }
+
+        loop {
+            while let Ok(update) = self.update_rx.try_recv() {
+                self.apply_update(update);
+            }
+
+            self.page.tick();
+
+            terminal
+                .draw(|frame| {
+                    render_guarded(frame, |frame| self.page.render(frame));
+                })
+                .map_err(|e| crate::error::AdapterError::RenderingFailed(e.to_string()))?;
+
+            if controllers.session.lock_if_idle() {
+                return Ok(NavAction::Go(Route::Login));
+            }
+
+            if controllers.session.poll_input(std::time::Duration::from_millis(100))?
+                && let Event::Key(key) =
+                    event::read().map_err(crate::error::AdapterError::EventReadFailed)?
+            {
+                if key.kind != KeyEventKind::Press {
+                    continue;
+                }
+
+                match key.code {
+                    KeyCode::Esc => return Ok(NavAction::Back),
+                    KeyCode::Char('j') | KeyCode::Down => self.page.select_next(),
+                    KeyCode::Char('k') | KeyCode::Up => self.page.select_previous(),
+                    KeyCode::Char('m') => self.page.toggle_method(),
+                    KeyCode::Char('i') => self.import_worksheet(controllers),
+                    KeyCode::Char('r') => self.load_worksheet(controllers),
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    fn on_navigation_error(&mut self, error_message: &str) {
+        self.page.add_error(error_message);
+    }
+}
+
+impl Default for InventoryWorksheetPageState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,225 @@
+// JobQueuePageState - 長時間処理ジョブの一覧画面の状態
+// 責務: 一覧の定期更新、選択したジョブの取消・再実行、Projection再構築の登録
+
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use javelin_application::dtos::response::JobResponse;
+use ratatui::DefaultTerminal;
+use tokio::sync::mpsc;
+
+use crate::{
+    error::AdapterResult,
+    navigation::{Controllers, NavAction, PageState, Route},
+    views::{layouts::render_guarded, pages::JobQueuePage},
+};
+
+/// 一覧の自動更新間隔
+const REFRESH_INTERVAL: Duration = Duration::from_secs(1);
+
+/// 読込・登録・取消・再実行の結果
+enum JobUpdate {
+    Listed(Vec<JobResponse>),
+    ListFailed(String),
+    Enqueued(JobResponse),
+    Cancelled(JobResponse),
+    Retried(JobResponse),
+    Failed(String),
+}
+
+pub struct JobQueuePageState {
+    page: JobQueuePage,
+    loading: bool,
+    last_refresh: Option<Instant>,
+    update_tx: mpsc::UnboundedSender<JobUpdate>,
+    update_rx: mpsc::UnboundedReceiver<JobUpdate>,
+}
+
+impl JobQueuePageState {
+    pub fn new() -> Self {
+        let (update_tx, update_rx) = mpsc::unbounded_channel();
+        Self {
+            page: JobQueuePage::new(),
+            loading: false,
+            last_refresh: None,
+            update_tx,
+            update_rx,
+        }
+    }
+
+    /// 一覧を読込
+    fn load_list(&mut self, controllers: &Controllers) {
+        if self.loading {
+            return;
+        }
+        self.loading = true;
+        self.last_refresh = Some(Instant::now());
+
+        let controller = Arc::clone(&controllers.job_queue);
+        let update_tx = self.update_tx.clone();
+
+        tokio::spawn(async move {
+            let update = match controller.list().await {
+                Ok(jobs) => JobUpdate::Listed(jobs),
+                Err(e) => JobUpdate::ListFailed(e),
+            };
+            let _ = update_tx.send(update);
+        });
+    }
+
+    /// Projection再構築を登録
+    fn enqueue_rebuild(&mut self, controllers: &Controllers) {
+        let controller = Arc::clone(&controllers.job_queue);
+        let update_tx = self.update_tx.clone();
+        let requested_by = controllers.session.user_id();
+
+        tokio::spawn(async move {
+            let update = match controller.enqueue_projection_rebuild(requested_by).await {
+                Ok(job) => JobUpdate::Enqueued(job),
+                Err(e) => JobUpdate::Failed(e),
+            };
+            let _ = update_tx.send(update);
+        });
+    }
+
+    /// 選択中のジョブを取消
+    fn cancel_selected(&mut self, controllers: &Controllers) {
+        let Some(job) = self.page.selected() else {
+            return;
+        };
+        if job.finished {
+            self.page.add_error(format!("{} は終了しているため取り消せません", job.job_id));
+            return;
+        }
+
+        let controller = Arc::clone(&controllers.job_queue);
+        let update_tx = self.update_tx.clone();
+        let job_id = job.job_id.clone();
+        let cancelled_by = controllers.session.user_id();
+
+        tokio::spawn(async move {
+            let update = match controller.cancel(job_id, cancelled_by).await {
+                Ok(job) => JobUpdate::Cancelled(job),
+                Err(e) => JobUpdate::Failed(e),
+            };
+            let _ = update_tx.send(update);
+        });
+    }
+
+    /// 選択中のジョブを再実行
+    fn retry_selected(&mut self, controllers: &Controllers) {
+        let Some(job) = self.page.selected() else {
+            return;
+        };
+
+        let controller = Arc::clone(&controllers.job_queue);
+        let update_tx = self.update_tx.clone();
+        let job_id = job.job_id.clone();
+
+        tokio::spawn(async move {
+            let update = match controller.retry(job_id).await {
+                Ok(job) => JobUpdate::Retried(job),
+                Err(e) => JobUpdate::Failed(e),
+            };
+            let _ = update_tx.send(update);
+        });
+    }
+
+    /// 処理結果を反映
+    fn poll_updates(&mut self, controllers: &Controllers) {
+        while let Ok(update) = self.update_rx.try_recv() {
+            match update {
+                JobUpdate::Listed(jobs) => {
+                    self.loading = false;
+                    self.page.set_jobs(jobs);
+                }
+                JobUpdate::ListFailed(error) => {
+                    self.loading = false;
+                    self.page.set_error(error);
+                }
+                JobUpdate::Enqueued(job) => {
+                    self.page.add_info(format!("{} を登録しました", job.kind_label));
+                    self.load_list(controllers);
+                }
+                JobUpdate::Cancelled(job) => {
+                    self.page.add_info(format!("{} の取消を受け付けました", job.job_id));
+                    self.load_list(controllers);
+                }
+                JobUpdate::Retried(job) => {
+                    self.page.add_info(format!("{} を再実行します", job.job_id));
+                    self.load_list(controllers);
+                }
+                JobUpdate::Failed(error) => self.page.add_error(error),
+            }
+        }
+
+        if self.last_refresh.is_some_and(|at| at.elapsed() >= REFRESH_INTERVAL) {
+            self.load_list(controllers);
+        }
+    }
+}
+
+impl PageState for JobQueuePageState {
+    fn route(&self) -> Route {
+        Route::JobQueue
+    }
+
+    fn run(
+        &mut self,
+        terminal: &mut DefaultTerminal,
+        controllers: &Controllers,
+    ) -> AdapterResult<NavAction> {
+        if self.last_refresh.is_none() {
+            self.page.start_loading();
+            self.load_list(controllers);
+        }
+
+        loop {
+            self.poll_updates(controllers);
+            self.page.tick();
+
+            terminal
+                .draw(|frame| {
+                    render_guarded(frame, |frame| self.page.render(frame));
+                })
+                .map_err(|e| crate::error::AdapterError::RenderingFailed(e.to_string()))?;
+
+            if controllers.session.lock_if_idle() {
+                return Ok(NavAction::Go(Route::Login));
+            }
+
+            if controllers.session.poll_input(std::time::Duration::from_millis(100))?
+                && let Event::Key(key) =
+                    event::read().map_err(crate::error::AdapterError::EventReadFailed)?
+            {
+                if key.kind != KeyEventKind::Press {
+                    continue;
+                }
+
+                match key.code {
+                    KeyCode::Esc => return Ok(NavAction::Back),
+                    KeyCode::Char('j') | KeyCode::Down => self.page.select_next(),
+                    KeyCode::Char('k') | KeyCode::Up => self.page.select_previous(),
+                    KeyCode::Char('x') => self.cancel_selected(controllers),
+                    KeyCode::Char('t') => self.retry_selected(controllers),
+                    KeyCode::Char('r') => self.load_list(controllers),
+                    KeyCode::Char('R') => self.enqueue_rebuild(controllers),
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    fn on_navigation_error(&mut self, error_message: &str) {
+        self.page.add_error(error_message);
+    }
+}
+
+impl Default for JobQueuePageState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -9,8 +9,11 @@ use uuid::Uuid;
 use crate::{
     error::AdapterResult,
     navigation::{Controllers, NavAction, PageState, PresenterRegistry, Route},
-    presenter::{AccountMasterPresenter, JournalEntryPresenter},
-    views::pages::JournalEntryFormPage,
+    presenter::{
+        AccountMasterPresenter, CalendarMasterPresenter, JournalEntryPresenter,
+        PROGRESS_CHANNEL_CAPACITY, progress_channel,
+    },
+    views::{components::InputField, layouts::render_guarded, pages::JournalEntryFormPage},
 };
 
 /// Journal entry page state with owned channels
@@ -27,14 +30,20 @@ pub struct JournalEntryPageState {
     /// Journal entry presenter for this page
     #[allow(dead_code)]
     journal_entry_presenter: Arc<JournalEntryPresenter>,
+    /// Calendar master presenter for the date picker
+    #[allow(dead_code)]
+    calendar_master_presenter: Arc<CalendarMasterPresenter>,
+    /// Whether the calendar master load has been requested
+    calendar_loaded: bool,
 }
 
 impl JournalEntryPageState {
     /// Create a new JournalEntryPageState with its own channels
     ///
     /// This method:
-    /// 1. Creates 3 channels (account_master, result, progress)
-    /// 2. Creates AccountMasterPresenter and JournalEntryPresenter with senders
+    /// 1. Creates 4 channels (account_master, calendar_master, result, progress)
+    /// 2. Creates AccountMasterPresenter, CalendarMasterPresenter and JournalEntryPresenter with
+    ///    senders
     /// 3. Registers presenters in PresenterRegistry
     /// 4. Creates JournalEntryFormPage with receivers
     ///
@@ -49,15 +58,19 @@ impl JournalEntryPageState {
         // Generate unique ID for this page instance
         let id = Uuid::new_v4();
 
-        // Create 3 channels for journal entry communication
+        // Create 4 channels for journal entry communication
         let (account_master_tx, account_master_rx) = tokio::sync::mpsc::unbounded_channel();
+        let (calendar_master_tx, calendar_master_rx) = CalendarMasterPresenter::create_channel();
         let (result_tx, result_rx) = tokio::sync::mpsc::unbounded_channel();
-        let (progress_tx, progress_rx) = tokio::sync::mpsc::unbounded_channel();
+        let (progress_tx, progress_rx) = progress_channel(PROGRESS_CHANNEL_CAPACITY);
 
         // Create AccountMasterPresenter with channel sender
         let account_master_presenter =
             Arc::new(AccountMasterPresenter::new(account_master_tx.clone()));
 
+        // Create CalendarMasterPresenter with channel sender
+        let calendar_master_presenter = Arc::new(CalendarMasterPresenter::new(calendar_master_tx));
+
         // Create JournalEntryPresenter with channel senders
         // Note: JournalEntryPresenter needs 4 channels, but we only use 2 here
         // The other 2 (list and detail) are not used in the form page
@@ -73,14 +86,24 @@ impl JournalEntryPageState {
         // Register presenters in PresenterRegistry with unique ID
         registry.register_account_master_presenter(id, Arc::clone(&account_master_presenter));
         registry.register_journal_entry_presenter(id, Arc::clone(&journal_entry_presenter));
+        registry.register_calendar_master_presenter(id, Arc::clone(&calendar_master_presenter));
 
         // Create JournalEntryFormPage and set receivers
         let mut page = JournalEntryFormPage::new();
         page.set_account_master_receiver(account_master_rx);
+        page.set_calendar_master_receiver(calendar_master_rx);
         page.set_result_receiver(result_rx);
         page.set_progress_receiver(progress_rx);
 
-        Self { id, registry, page, account_master_presenter, journal_entry_presenter }
+        Self {
+            id,
+            registry,
+            page,
+            account_master_presenter,
+            journal_entry_presenter,
+            calendar_master_presenter,
+            calendar_loaded: false,
+        }
     }
 }
 
@@ -96,9 +119,26 @@ impl PageState for JournalEntryPageState {
     ) -> AdapterResult<NavAction> {
         use crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
 
+        // Load calendar master once for the date picker
+        if !self.calendar_loaded {
+            self.calendar_loaded = true;
+
+            let controller = Arc::clone(&controllers.calendar_master);
+            let page_id = self.id;
+
+            tokio::spawn(async move {
+                use javelin_application::dtos::request::LoadCalendarMasterRequest;
+
+                let request = LoadCalendarMasterRequest { year: None };
+
+                let _ = controller.handle_load_calendar_master(page_id, request).await;
+            });
+        }
+
         loop {
             // Poll for async data updates
             self.page.poll_account_master_data();
+            self.page.poll_calendar_master_data();
             self.page.poll_result_data();
             self.page.poll_progress_messages();
 
@@ -121,13 +161,16 @@ impl PageState for JournalEntryPageState {
             // Render the page
             terminal
                 .draw(|frame| {
-                    self.page.render(frame);
+                    render_guarded(frame, |frame| self.page.render(frame));
                 })
                 .map_err(|e| crate::error::AdapterError::RenderingFailed(e.to_string()))?;
 
+            if controllers.session.lock_if_idle() {
+                return Ok(NavAction::Go(Route::Login));
+            }
+
             // Handle events with timeout for animation updates
-            if event::poll(std::time::Duration::from_millis(100))
-                .map_err(crate::error::AdapterError::EventReadFailed)?
+            if controllers.session.poll_input(std::time::Duration::from_millis(100))?
                 && let Event::Key(key) =
                     event::read().map_err(crate::error::AdapterError::EventReadFailed)?
             {
@@ -135,11 +178,34 @@ impl PageState for JournalEntryPageState {
                     continue;
                 }
 
+                // Field help overlay is visible
+                if self.page.is_field_help_visible() {
+                    if matches!(key.code, KeyCode::Esc | KeyCode::Char('?')) {
+                        self.page.close_field_help();
+                    }
+                    continue;
+                }
+
+                // Tax calculator overlay is visible
+                if self.page.is_tax_calculator_visible() {
+                    match key.code {
+                        KeyCode::Esc => self.page.close_tax_calculator(),
+                        KeyCode::Enter => self.page.apply_tax_calculator(),
+                        KeyCode::Tab => self.page.tax_calculator_toggle_conversion(),
+                        KeyCode::Char('r') => self.page.tax_calculator_cycle_rate(),
+                        KeyCode::Backspace => self.page.tax_calculator_backspace(),
+                        KeyCode::Char(c) => self.page.tax_calculator_input_char(c),
+                        _ => {}
+                    }
+                    continue;
+                }
+
                 match self.page.input_mode() {
                     crate::input_mode::InputMode::Normal => {
                         match key.code {
                             KeyCode::Esc => {
-                                // Navigate back to home
+                                // Cancel in-flight submission and navigate back to home
+                                controllers.journal_entry.cancel(self.id);
                                 return Ok(NavAction::Back);
                             }
                             KeyCode::Char('i') => {
@@ -149,7 +215,10 @@ impl PageState for JournalEntryPageState {
                             KeyCode::Char('s') if key.modifiers.contains(KeyModifiers::CONTROL) => {
                                 // Submit journal entry (Ctrl+S)
                                 if !self.page.is_submitting() {
-                                    match self.page.to_register_request("system_user".to_string()) {
+                                    match self
+                                        .page
+                                        .to_register_request(controllers.session.user_id())
+                                    {
                                         Ok(request) => {
                                             self.page.start_submit();
 
@@ -180,12 +249,79 @@ impl PageState for JournalEntryPageState {
                             KeyCode::BackTab => {
                                 self.page.focus_previous();
                             }
+                            KeyCode::Char('h') | KeyCode::Left => {
+                                self.page.move_left();
+                            }
+                            KeyCode::Char('l') | KeyCode::Right => {
+                                self.page.move_right();
+                            }
+                            KeyCode::Char('H') => {
+                                // Move current line before the previous one
+                                self.page.move_line_up();
+                            }
+                            KeyCode::Char('L') => {
+                                // Move current line after the next one
+                                self.page.move_line_down();
+                            }
+                            KeyCode::Char('D') => {
+                                // Duplicate current line
+                                self.page.duplicate_line();
+                            }
+                            KeyCode::Char('S') => {
+                                // Split current line amounts across two lines
+                                self.page.split_line();
+                            }
+                            KeyCode::Char('U') => {
+                                // Merge lines with the same accounts
+                                self.page.merge_same_account_lines();
+                            }
+                            KeyCode::Char('B') => {
+                                // Balance the remaining debit/credit difference into current line
+                                self.page.balance_remaining();
+                            }
+                            KeyCode::Char('C') => {
+                                // Open the tax-inclusive/exclusive calculator
+                                self.page.open_tax_calculator();
+                            }
+                            KeyCode::Char('?') => {
+                                // Show help for the focused field
+                                self.page.show_field_help();
+                            }
                             _ => {}
                         }
                     }
                     crate::input_mode::InputMode::Modify => {
-                        // Check if overlay is visible
-                        if self.page.is_overlay_visible() {
+                        // Check if calendar or overlay is visible
+                        if self.page.is_calendar_visible() {
+                            // Calendar-specific key handling
+                            match key.code {
+                                KeyCode::Esc => {
+                                    self.page.calendar_cancel();
+                                }
+                                KeyCode::Char('h') | KeyCode::Left => {
+                                    self.page.calendar_move_days(-1);
+                                }
+                                KeyCode::Char('l') | KeyCode::Right => {
+                                    self.page.calendar_move_days(1);
+                                }
+                                KeyCode::Char('j') | KeyCode::Down => {
+                                    self.page.calendar_move_days(7);
+                                }
+                                KeyCode::Char('k') | KeyCode::Up => {
+                                    self.page.calendar_move_days(-7);
+                                }
+                                KeyCode::Char('H') => {
+                                    self.page.calendar_move_months(-1);
+                                }
+                                KeyCode::Char('L') => {
+                                    self.page.calendar_move_months(1);
+                                }
+                                KeyCode::Enter => {
+                                    self.page.calendar_confirm();
+                                }
+                                _ => {}
+                            }
+                        } else if self.page.is_overlay_visible() {
                             // Overlay-specific key handling
                             match key.code {
                                 KeyCode::Esc => {
@@ -220,6 +356,21 @@ impl PageState for JournalEntryPageState {
                                 KeyCode::Backspace => {
                                     self.page.backspace();
                                 }
+                                KeyCode::Delete => {
+                                    self.page.delete();
+                                }
+                                KeyCode::Left => {
+                                    self.page.move_cursor(InputField::move_cursor_left);
+                                }
+                                KeyCode::Right => {
+                                    self.page.move_cursor(InputField::move_cursor_right);
+                                }
+                                KeyCode::Home => {
+                                    self.page.move_cursor(InputField::move_cursor_home);
+                                }
+                                KeyCode::End => {
+                                    self.page.move_cursor(InputField::move_cursor_end);
+                                }
                                 KeyCode::Enter => {
                                     // Commit and exit modify mode
                                     self.page.enter_normal_mode();
@@ -239,6 +390,7 @@ impl Drop for JournalEntryPageState {
         // Unregister both presenters from registry when page is destroyed
         self.registry.unregister_account_master_presenter(self.id);
         self.registry.unregister_journal_entry_presenter(self.id);
+        self.registry.unregister_calendar_master_presenter(self.id);
         // Channels are automatically cleaned up when dropped
     }
 }
@@ -261,10 +413,11 @@ mod tests {
 
         let state = JournalEntryPageState::new(Arc::clone(&registry));
 
-        // Verify both presenters were registered (AccountMaster + JournalEntry)
-        assert_eq!(registry.total_count(), count_before + 2);
+        // Verify all presenters were registered (AccountMaster + JournalEntry + CalendarMaster)
+        assert_eq!(registry.total_count(), count_before + 3);
         assert!(registry.get_account_master_presenter(state.id).is_some());
         assert!(registry.get_journal_entry_presenter(state.id).is_some());
+        assert!(registry.get_calendar_master_presenter(state.id).is_some());
     }
 
     #[test]
@@ -276,15 +429,15 @@ mod tests {
             let state = JournalEntryPageState::new(Arc::clone(&registry));
             let state_id = state.id;
 
-            // Verify both presenters are registered
-            assert_eq!(registry.total_count(), count_before + 2);
+            // Verify all presenters are registered
+            assert_eq!(registry.total_count(), count_before + 3);
             assert!(registry.get_account_master_presenter(state_id).is_some());
             assert!(registry.get_journal_entry_presenter(state_id).is_some());
 
             // state goes out of scope here
         }
 
-        // Verify both presenters were unregistered
+        // Verify all presenters were unregistered
         assert_eq!(registry.total_count(), count_before);
     }
 
@@ -298,8 +451,8 @@ mod tests {
         // Both should have unique IDs
         assert_ne!(state1.id, state2.id);
 
-        // Both should have 2 presenters each (4 total)
-        assert_eq!(registry.total_count(), 4);
+        // Both should have 3 presenters each (6 total)
+        assert_eq!(registry.total_count(), 6);
 
         // Verify state1's presenters
         assert!(registry.get_account_master_presenter(state1.id).is_some());
@@ -319,11 +472,11 @@ mod tests {
         let state2 = JournalEntryPageState::new(Arc::clone(&registry));
         let id2 = state2.id;
 
-        assert_eq!(registry.total_count(), 4); // 2 presenters × 2 states
+        assert_eq!(registry.total_count(), 6); // 3 presenters × 2 states
 
         // Drop state1
         drop(state1);
-        assert_eq!(registry.total_count(), 2);
+        assert_eq!(registry.total_count(), 3);
         assert!(registry.get_account_master_presenter(id1).is_none());
         assert!(registry.get_journal_entry_presenter(id1).is_none());
         assert!(registry.get_account_master_presenter(id2).is_some());
@@ -0,0 +1,209 @@
+// JournalImportPageState - 表計算ファイルからの仕訳取込画面の状態
+// 責務: ファイルの読込、列の割当の編集、検証と取込の実行
+
+use std::sync::Arc;
+
+use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use javelin_application::dtos::{
+    request::JournalImportRequest,
+    response::{
+        JournalImportPreviewResponse, JournalImportResponse, JournalImportValidationReport,
+    },
+};
+use ratatui::DefaultTerminal;
+use tokio::sync::mpsc;
+
+use crate::{
+    error::AdapterResult,
+    navigation::{Controllers, NavAction, PageState, Route},
+    views::{layouts::render_guarded, pages::JournalImportPage},
+};
+
+/// 操作結果
+enum ImportUpdate {
+    Previewed(JournalImportPreviewResponse),
+    Validated(JournalImportValidationReport),
+    Imported(JournalImportResponse),
+    Progress(String),
+    Failed(String),
+}
+
+pub struct JournalImportPageState {
+    page: JournalImportPage,
+    update_tx: mpsc::UnboundedSender<ImportUpdate>,
+    update_rx: mpsc::UnboundedReceiver<ImportUpdate>,
+    /// 入力中のファイルパス
+    input: Option<String>,
+}
+
+impl JournalImportPageState {
+    pub fn new() -> Self {
+        let (update_tx, update_rx) = mpsc::unbounded_channel();
+        Self {
+            page: JournalImportPage::new(),
+            update_tx,
+            update_rx,
+            // 初回はファイルパスの入力から始める
+            input: Some(String::new()),
+        }
+    }
+
+    /// 入力中のキー操作
+    fn handle_input_key(&mut self, code: KeyCode, controllers: &Controllers) {
+        let Some(input) = self.input.as_mut() else {
+            return;
+        };
+        match code {
+            KeyCode::Esc => self.input = None,
+            KeyCode::Enter => {
+                let path = self.input.take().unwrap_or_default();
+                let path = path.trim();
+                if !path.is_empty() {
+                    self.load_preview(path.to_string(), controllers);
+                }
+            }
+            KeyCode::Backspace => {
+                input.pop();
+            }
+            KeyCode::Char(c) => input.push(c),
+            _ => {}
+        }
+    }
+
+    /// ファイルを読み込み、列の割当を復元・推測する
+    fn load_preview(&mut self, path: String, controllers: &Controllers) {
+        self.page.set_status_message(format!("{} を読み込み中...", path));
+        let controller = Arc::clone(&controllers.journal_import);
+        let update_tx = self.update_tx.clone();
+
+        tokio::spawn(async move {
+            let update = match controller.preview(path).await {
+                Ok(preview) => ImportUpdate::Previewed(preview),
+                Err(e) => ImportUpdate::Failed(e),
+            };
+            let _ = update_tx.send(update);
+        });
+    }
+
+    /// 現在の割当での検証・取込リクエスト
+    fn request(&self, controllers: &Controllers) -> Option<JournalImportRequest> {
+        let path = self.page.path()?;
+        Some(JournalImportRequest {
+            path: path.to_string(),
+            columns: self.page.columns().clone(),
+            user_id: controllers.session.user_id(),
+        })
+    }
+
+    fn validate(&mut self, controllers: &Controllers) {
+        let Some(request) = self.request(controllers) else {
+            return;
+        };
+        self.page.set_status_message("検証中...");
+        let controller = Arc::clone(&controllers.journal_import);
+        let update_tx = self.update_tx.clone();
+
+        tokio::spawn(async move {
+            let update = match controller.validate(request).await {
+                Ok(report) => ImportUpdate::Validated(report),
+                Err(e) => ImportUpdate::Failed(e),
+            };
+            let _ = update_tx.send(update);
+        });
+    }
+
+    fn import(&mut self, controllers: &Controllers) {
+        let Some(request) = self.request(controllers) else {
+            return;
+        };
+        self.page.set_status_message("取込中...");
+        let controller = Arc::clone(&controllers.journal_import);
+        let update_tx = self.update_tx.clone();
+
+        tokio::spawn(async move {
+            let progress_tx = update_tx.clone();
+            let progress = move |message| {
+                let _ = progress_tx.send(ImportUpdate::Progress(message));
+            };
+            let update = match controller.import(request, progress).await {
+                Ok(response) => ImportUpdate::Imported(response),
+                Err(e) => ImportUpdate::Failed(e),
+            };
+            let _ = update_tx.send(update);
+        });
+    }
+
+    /// 操作結果を反映
+    fn poll_updates(&mut self) {
+        while let Ok(update) = self.update_rx.try_recv() {
+            match update {
+                ImportUpdate::Previewed(preview) => self.page.set_preview(preview),
+                ImportUpdate::Validated(report) => self.page.set_report(report),
+                ImportUpdate::Imported(response) => self.page.set_imported(response),
+                ImportUpdate::Progress(message) => self.page.set_status_message(message),
+                ImportUpdate::Failed(message) => self.page.set_error_message(message),
+            }
+        }
+    }
+}
+
+impl PageState for JournalImportPageState {
+    fn route(&self) -> Route {
+        Route::DataImport
+    }
+
+    fn run(
+        &mut self,
+        terminal: &mut DefaultTerminal,
+        controllers: &Controllers,
+    ) -> AdapterResult<NavAction> {
+        loop {
+            self.poll_updates();
+
+            terminal
+                .draw(|frame| {
+                    let input = self.input.as_deref();
+                    render_guarded(frame, |frame| self.page.render(frame, input));
+                })
+                .map_err(|e| crate::error::AdapterError::RenderingFailed(e.to_string()))?;
+
+            if controllers.session.lock_if_idle() {
+                return Ok(NavAction::Go(Route::Login));
+            }
+
+            if controllers.session.poll_input(std::time::Duration::from_millis(100))?
+                && let Event::Key(key) =
+                    event::read().map_err(crate::error::AdapterError::EventReadFailed)?
+            {
+                if key.kind != KeyEventKind::Press {
+                    continue;
+                }
+
+                if self.input.is_some() {
+                    self.handle_input_key(key.code, controllers);
+                    continue;
+                }
+
+                match key.code {
+                    KeyCode::Esc => return Ok(NavAction::Back),
+                    KeyCode::Char('o') => {
+                        self.input = Some(self.page.path().unwrap_or_default().to_string());
+                    }
+                    KeyCode::Up | KeyCode::Char('k') => self.page.select_previous(),
+                    KeyCode::Down | KeyCode::Char('j') => self.page.select_next(),
+                    KeyCode::Left | KeyCode::Char('h') => self.page.cycle_column(false),
+                    KeyCode::Right | KeyCode::Char('l') => self.page.cycle_column(true),
+                    KeyCode::Char('v') => self.validate(controllers),
+                    KeyCode::Char('i') => self.import(controllers),
+                    _ => {}
+                }
+            }
+        }
+    }
+}
+
+impl Default for JournalImportPageState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
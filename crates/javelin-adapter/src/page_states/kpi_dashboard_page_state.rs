@@ -0,0 +1,116 @@
+// KpiDashboardPageState - KPIダッシュボード画面の状態
+// 責務: 業務指標の日次集計の読み込みと反映
+
+use std::sync::Arc;
+
+use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use ratatui::DefaultTerminal;
+use tokio::sync::mpsc;
+
+use crate::{
+    error::AdapterResult,
+    navigation::{Controllers, NavAction, PageState, Route},
+    presenter::KpiDashboardViewModel,
+    views::{layouts::render_guarded, pages::KpiDashboardPage},
+};
+
+/// 読み込み結果
+enum KpiUpdate {
+    Loaded(KpiDashboardViewModel),
+    Failed(String),
+}
+
+pub struct KpiDashboardPageState {
+    page: KpiDashboardPage,
+    update_tx: mpsc::UnboundedSender<KpiUpdate>,
+    update_rx: mpsc::UnboundedReceiver<KpiUpdate>,
+    /// 集計を読み込み済みか（画面表示時に一度だけ読み込む）
+    requested: bool,
+}
+
+impl KpiDashboardPageState {
+    pub fn new() -> Self {
+        let (update_tx, update_rx) = mpsc::unbounded_channel();
+        Self { page: KpiDashboardPage::new(), update_tx, update_rx, requested: false }
+    }
+
+    /// 直近の日次集計を読み込む（集計は記録日の業務日付単位のため、業務日付を基準にする）
+    fn load(&mut self, controllers: &Controllers) {
+        self.requested = true;
+        self.page.set_loading();
+
+        let controller = Arc::clone(&controllers.business_metrics);
+        let update_tx = self.update_tx.clone();
+        let today = crate::clock::business_date();
+
+        tokio::spawn(async move {
+            let from = KpiDashboardViewModel::start_date(today);
+            let update = match controller.daily_metrics(from).await {
+                Ok(daily_metrics) => KpiUpdate::Loaded(KpiDashboardViewModel::from_daily_metrics(
+                    &daily_metrics,
+                    today,
+                )),
+                Err(e) => KpiUpdate::Failed(e),
+            };
+            let _ = update_tx.send(update);
+        });
+    }
+
+    fn poll_updates(&mut self) {
+        while let Ok(update) = self.update_rx.try_recv() {
+            match update {
+                KpiUpdate::Loaded(view_model) => self.page.set_view_model(view_model),
+                KpiUpdate::Failed(message) => self.page.set_error(message),
+            }
+        }
+    }
+}
+
+impl PageState for KpiDashboardPageState {
+    fn route(&self) -> Route {
+        Route::KpiDashboard
+    }
+
+    fn run(
+        &mut self,
+        terminal: &mut DefaultTerminal,
+        controllers: &Controllers,
+    ) -> AdapterResult<NavAction> {
+        if !self.requested {
+            self.load(controllers);
+        }
+
+        loop {
+            self.poll_updates();
+
+            terminal
+                .draw(|frame| render_guarded(frame, |frame| self.page.render(frame)))
+                .map_err(|e| crate::error::AdapterError::RenderingFailed(e.to_string()))?;
+
+            if controllers.session.lock_if_idle() {
+                return Ok(NavAction::Go(Route::Login));
+            }
+
+            if controllers.session.poll_input(std::time::Duration::from_millis(100))?
+                && let Event::Key(key) =
+                    event::read().map_err(crate::error::AdapterError::EventReadFailed)?
+            {
+                if key.kind != KeyEventKind::Press {
+                    continue;
+                }
+
+                match key.code {
+                    KeyCode::Esc => return Ok(NavAction::Back),
+                    KeyCode::Char('r') => self.load(controllers),
+                    _ => {}
+                }
+            }
+        }
+    }
+}
+
+impl Default for KpiDashboardPageState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
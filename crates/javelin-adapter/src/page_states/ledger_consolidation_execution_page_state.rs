@@ -1,22 +1,95 @@
 // LedgerConsolidationExecutionPageState - 元帳集約実行画面の状態管理
-// 責務: 元帳集約実行画面の状態とイベント処理
+// 責務: 元帳集約ジョブの登録と進捗の監視
 
+use std::sync::Arc;
+
+use chrono::Datelike;
 use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use javelin_application::dtos::response::JobResponse;
 use ratatui::DefaultTerminal;
+use tokio::sync::mpsc;
 
 use crate::{
     error::AdapterResult,
     navigation::{Controllers, NavAction, Route, page_state::PageState},
-    views::pages::LedgerConsolidationExecutionPage,
+    views::{layouts::render_guarded, pages::LedgerConsolidationExecutionPage},
 };
 
+/// ジョブの監視結果
+enum JobUpdate {
+    Changed(Box<JobResponse>),
+    Finished,
+    Failed(String),
+}
+
 pub struct LedgerConsolidationExecutionPageState {
     page: LedgerConsolidationExecutionPage,
+    /// ジョブを監視中か
+    watching: bool,
+    update_tx: mpsc::UnboundedSender<JobUpdate>,
+    update_rx: mpsc::UnboundedReceiver<JobUpdate>,
 }
 
 impl LedgerConsolidationExecutionPageState {
     pub fn new() -> Self {
-        Self { page: LedgerConsolidationExecutionPage::new() }
+        let (update_tx, update_rx) = mpsc::unbounded_channel();
+        Self {
+            page: LedgerConsolidationExecutionPage::new(),
+            watching: false,
+            update_tx,
+            update_rx,
+        }
+    }
+
+    /// 当月の元帳集約をジョブとして登録し、終了まで監視
+    fn start_consolidation(&mut self, controllers: &Controllers) {
+        if self.watching {
+            return;
+        }
+        self.watching = true;
+        self.page.start_execution();
+
+        let controller = Arc::clone(&controllers.job_queue);
+        let update_tx = self.update_tx.clone();
+        let today = crate::clock::business_date();
+        let (fiscal_year, period) = (today.year(), today.month() as u8);
+        let requested_by = controllers.session.user_id();
+
+        tokio::spawn(async move {
+            let job =
+                match controller.enqueue_consolidation(fiscal_year, period, requested_by).await {
+                    Ok(job) => job,
+                    Err(e) => {
+                        let _ = update_tx.send(JobUpdate::Failed(e));
+                        return;
+                    }
+                };
+            let watch_tx = update_tx.clone();
+            let update = match controller
+                .watch(job.job_id, move |job| {
+                    let _ = watch_tx.send(JobUpdate::Changed(Box::new(job.clone())));
+                })
+                .await
+            {
+                Ok(_) => JobUpdate::Finished,
+                Err(e) => JobUpdate::Failed(e),
+            };
+            let _ = update_tx.send(update);
+        });
+    }
+
+    /// 監視結果を反映
+    fn poll_updates(&mut self) {
+        while let Ok(update) = self.update_rx.try_recv() {
+            match update {
+                JobUpdate::Changed(job) => self.page.show_job(&job),
+                JobUpdate::Finished => self.watching = false,
+                JobUpdate::Failed(error) => {
+                    self.watching = false;
+                    self.page.add_error(error);
+                }
+            }
+        }
     }
 }
 
@@ -28,22 +101,27 @@ impl PageState for LedgerConsolidationExecutionPageState {
     fn run(
         &mut self,
         terminal: &mut DefaultTerminal,
-        _controllers: &Controllers,
+        controllers: &Controllers,
     ) -> AdapterResult<NavAction> {
         loop {
+            self.poll_updates();
+
             // Tick animation
             self.page.tick();
 
             // Render the page
             terminal
                 .draw(|frame| {
-                    self.page.render(frame);
+                    render_guarded(frame, |frame| self.page.render(frame));
                 })
                 .map_err(|e| crate::error::AdapterError::RenderingFailed(e.to_string()))?;
 
+            if controllers.session.lock_if_idle() {
+                return Ok(NavAction::Go(Route::Login));
+            }
+
             // Handle events with timeout for animation updates
-            if event::poll(std::time::Duration::from_millis(100))
-                .map_err(crate::error::AdapterError::EventReadFailed)?
+            if controllers.session.poll_input(std::time::Duration::from_millis(100))?
                 && let Event::Key(key) =
                     event::read().map_err(crate::error::AdapterError::EventReadFailed)?
             {
@@ -55,9 +133,8 @@ impl PageState for LedgerConsolidationExecutionPageState {
                     KeyCode::Esc => {
                         return Ok(NavAction::Back);
                     }
-                    KeyCode::Char('s') => {
-                        self.page.start_execution();
-                    }
+                    KeyCode::Char('s') => self.start_consolidation(controllers),
+                    KeyCode::Char('b') => return Ok(NavAction::Go(Route::JobQueue)),
                     KeyCode::Char('j') | KeyCode::Down => {
                         self.page.select_next();
                     }
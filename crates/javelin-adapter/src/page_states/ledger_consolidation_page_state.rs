@@ -10,7 +10,7 @@ use crate::{
     error::AdapterResult,
     navigation::{Controllers, NavAction, PageState, PresenterRegistry, Route},
     presenter::BatchHistoryPresenter,
-    views::pages::LedgerConsolidationPage,
+    views::{layouts::render_guarded, pages::LedgerConsolidationPage},
 };
 
 pub struct LedgerConsolidationPageState {
@@ -63,7 +63,7 @@ impl PageState for LedgerConsolidationPageState {
     fn run(
         &mut self,
         terminal: &mut DefaultTerminal,
-        _controllers: &Controllers,
+        controllers: &Controllers,
     ) -> AdapterResult<NavAction> {
         loop {
             // Check for results from presenter
@@ -86,13 +86,16 @@ impl PageState for LedgerConsolidationPageState {
             // Render the page
             terminal
                 .draw(|frame| {
-                    self.page.render(frame);
+                    render_guarded(frame, |frame| self.page.render(frame));
                 })
                 .map_err(|e| crate::error::AdapterError::RenderingFailed(e.to_string()))?;
 
+            if controllers.session.lock_if_idle() {
+                return Ok(NavAction::Go(Route::Login));
+            }
+
             // Handle events with timeout for animation updates
-            if event::poll(std::time::Duration::from_millis(100))
-                .map_err(crate::error::AdapterError::EventReadFailed)?
+            if controllers.session.poll_input(std::time::Duration::from_millis(100))?
                 && let Event::Key(key) =
                     event::read().map_err(crate::error::AdapterError::EventReadFailed)?
             {
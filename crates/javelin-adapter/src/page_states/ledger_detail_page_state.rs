@@ -1,32 +1,199 @@
 // LedgerDetailPageState - PageState implementation for ledger detail view screen
 
+use std::sync::Arc;
+
 use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use javelin_application::{
+    dtos::{
+        request::{
+            AnnotateEntryLineRequest, LinkEntriesRequest, ResolveAnnotationRequest,
+            UnlinkEntriesRequest,
+        },
+        response::{EntryAnnotationsResponse, EntryLinksResponse},
+    },
+    query_service::GetEntryHistoryQuery,
+};
+use javelin_domain::financial_close::journal_entry::entities::EntryLinkKind;
 use ratatui::DefaultTerminal;
+use tokio::sync::mpsc;
 
 use crate::{
     error::AdapterResult,
     navigation::{Controllers, NavAction, PageState, Route},
     page_states::LedgerPageState,
-    views::pages::LedgerDetailPage,
+    presenter::EntryHistoryViewModel,
+    views::{
+        layouts::render_guarded,
+        pages::{AnnotationInput, LedgerDetailPage},
+    },
 };
 
-#[derive(Default)]
 pub struct LedgerDetailPageState {
     page: LedgerDetailPage,
+    /// Channel for entry history (correction chain diff)
+    history_tx: mpsc::UnboundedSender<Result<EntryHistoryViewModel, String>>,
+    history_rx: mpsc::UnboundedReceiver<Result<EntryHistoryViewModel, String>>,
+    /// 修正履歴ロード済みフラグ
+    history_loaded: bool,
+    /// Channel for line annotations
+    annotation_tx: mpsc::UnboundedSender<Result<EntryAnnotationsResponse, String>>,
+    annotation_rx: mpsc::UnboundedReceiver<Result<EntryAnnotationsResponse, String>>,
+    /// Channel for entry links (accrual/settlement, prepayment/amortization)
+    link_tx: mpsc::UnboundedSender<Result<EntryLinksResponse, String>>,
+    link_rx: mpsc::UnboundedReceiver<Result<EntryLinksResponse, String>>,
 }
 
 impl LedgerDetailPageState {
     pub fn new() -> Self {
         // Try to get the selected entry from shared state
-        if let Some((entry, account_code, account_name)) = LedgerPageState::take_selected_entry() {
-            Self { page: LedgerDetailPage::new(entry, account_code, account_name) }
+        let page = if let Some((entry, account_code, account_name)) =
+            LedgerPageState::take_selected_entry()
+        {
+            LedgerDetailPage::new(entry, account_code, account_name)
         } else {
             // Fallback to default if no data available
-            Self { page: LedgerDetailPage::default() }
+            LedgerDetailPage::default()
+        };
+        let (history_tx, history_rx) = mpsc::unbounded_channel();
+        let (annotation_tx, annotation_rx) = mpsc::unbounded_channel();
+        let (link_tx, link_rx) = mpsc::unbounded_channel();
+
+        Self {
+            page,
+            history_tx,
+            history_rx,
+            history_loaded: false,
+            annotation_tx,
+            annotation_rx,
+            link_tx,
+            link_rx,
+        }
+    }
+
+    /// 修正履歴の取得を開始
+    fn load_history(&self, controllers: &Controllers) {
+        let controller = Arc::clone(&controllers.ledger);
+        let history_tx = self.history_tx.clone();
+        let query = GetEntryHistoryQuery { entry_id: self.page.entry_id().to_string() };
+
+        tokio::spawn(async move {
+            let result = controller
+                .get_entry_history(query)
+                .await
+                .map(EntryHistoryViewModel::from_result);
+            let _ = history_tx.send(result);
+        });
+    }
+
+    /// 注記の取得を開始
+    fn load_annotations(&self, controllers: &Controllers) {
+        let controller = Arc::clone(&controllers.ledger_annotation);
+        let annotation_tx = self.annotation_tx.clone();
+        let entry_id = self.page.entry_id().to_string();
+
+        tokio::spawn(async move {
+            let _ = annotation_tx.send(controller.load_annotations(&entry_id).await);
+        });
+    }
+
+    /// 紐付けの取得を開始
+    fn load_links(&self, controllers: &Controllers) {
+        let controller = Arc::clone(&controllers.entry_link);
+        let link_tx = self.link_tx.clone();
+        let entry_id = self.page.entry_id().to_string();
+
+        tokio::spawn(async move {
+            let _ = link_tx.send(controller.load_links(&entry_id).await);
+        });
+    }
+
+    /// 最後に紐付けた紐付けを解除
+    fn unlink_latest(&mut self, controllers: &Controllers) {
+        let Some(link_id) = self.page.latest_link_id() else {
+            self.page.set_link_error("解除する紐付けはありません");
+            return;
+        };
+        let controller = Arc::clone(&controllers.entry_link);
+        let link_tx = self.link_tx.clone();
+        let request = UnlinkEntriesRequest {
+            entry_id: self.page.entry_id().to_string(),
+            link_id: link_id.to_string(),
+            user_id: controllers.session.user_id(),
+        };
+
+        tokio::spawn(async move {
+            let _ = link_tx.send(controller.unlink(request).await);
+        });
+    }
+
+    /// 入力した注記・解決内容を登録（内容の検証はドメインで行う）
+    fn submit_input(&mut self, controllers: &Controllers) {
+        let Some((kind, input)) = self.page.take_input() else {
+            return;
+        };
+        let controller = Arc::clone(&controllers.ledger_annotation);
+        let annotation_tx = self.annotation_tx.clone();
+        let entry_id = self.page.entry_id().to_string();
+        let user_id = controllers.session.user_id();
+
+        match kind {
+            AnnotationInput::Note => {
+                let request = AnnotateEntryLineRequest {
+                    entry_id,
+                    line_number: self.page.line_number(),
+                    note: input,
+                    user_id,
+                };
+                tokio::spawn(async move {
+                    let _ = annotation_tx.send(controller.annotate(request).await);
+                });
+            }
+            AnnotationInput::Resolution(annotation_id) => {
+                let request = ResolveAnnotationRequest {
+                    entry_id,
+                    annotation_id,
+                    resolution: input,
+                    user_id,
+                };
+                tokio::spawn(async move {
+                    let _ = annotation_tx.send(controller.resolve(request).await);
+                });
+            }
+            AnnotationInput::Link(kind) => {
+                // 入力は「相手仕訳ID [金額]」（金額省略時は未決済残高の全額）
+                let mut parts = input.split_whitespace();
+                let target_entry_id = parts.next().unwrap_or_default().to_string();
+                let amount = match parts.next().map(|amount| amount.replace(',', "").parse()) {
+                    Some(Ok(amount)) => Some(amount),
+                    Some(Err(_)) => {
+                        self.page.set_link_error("金額は数値で入力してください");
+                        return;
+                    }
+                    None => None,
+                };
+                let controller = Arc::clone(&controllers.entry_link);
+                let link_tx = self.link_tx.clone();
+                let request = LinkEntriesRequest {
+                    source_entry_id: entry_id,
+                    target_entry_id,
+                    kind,
+                    amount,
+                    user_id,
+                };
+                tokio::spawn(async move {
+                    let _ = link_tx.send(controller.link(request).await);
+                });
+            }
         }
     }
 }
 
+impl Default for LedgerDetailPageState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl PageState for LedgerDetailPageState {
     fn route(&self) -> Route {
         Route::LedgerDetail
@@ -35,26 +202,104 @@ impl PageState for LedgerDetailPageState {
     fn run(
         &mut self,
         terminal: &mut DefaultTerminal,
-        _controllers: &Controllers,
+        controllers: &Controllers,
     ) -> AdapterResult<NavAction> {
+        // 初回ロード
+        if !self.history_loaded {
+            self.history_loaded = true;
+            self.load_history(controllers);
+            self.load_annotations(controllers);
+            self.load_links(controllers);
+        }
+        let mut projection_changes = controllers.projection_events.subscribe();
+
         loop {
+            // 表示中の勘定科目の元帳が更新されたら修正履歴・注記を再取得
+            if projection_changes.poll().ledger_changed(self.page.account_code()) {
+                self.load_history(controllers);
+                self.load_annotations(controllers);
+                self.load_links(controllers);
+            }
+
+            // Receive entry history
+            while let Ok(result) = self.history_rx.try_recv() {
+                match result {
+                    Ok(history) => self.page.set_history(history),
+                    Err(e) => self.page.set_history_error(e),
+                }
+            }
+
+            // Receive line annotations
+            while let Ok(result) = self.annotation_rx.try_recv() {
+                match result {
+                    Ok(annotations) => self.page.set_annotations(annotations),
+                    Err(e) => self.page.set_annotation_error(e),
+                }
+            }
+
+            // Receive entry links
+            while let Ok(result) = self.link_rx.try_recv() {
+                match result {
+                    Ok(links) => self.page.set_links(links),
+                    Err(e) => self.page.set_link_error(e),
+                }
+            }
+
             // Render the page
             terminal
                 .draw(|frame| {
-                    self.page.render(frame);
+                    render_guarded(frame, |frame| self.page.render(frame));
                 })
                 .map_err(|e| crate::error::AdapterError::RenderingFailed(e.to_string()))?;
 
-            // Handle events
-            if let Event::Key(key) =
-                event::read().map_err(crate::error::AdapterError::EventReadFailed)?
+            if controllers.session.lock_if_idle() {
+                return Ok(NavAction::Go(Route::Login));
+            }
+
+            // Handle events with timeout for async updates
+            if controllers.session.poll_input(std::time::Duration::from_millis(100))?
+                && let Event::Key(key) =
+                    event::read().map_err(crate::error::AdapterError::EventReadFailed)?
             {
                 if key.kind != KeyEventKind::Press {
                     continue;
                 }
 
-                if key.code == KeyCode::Esc {
-                    return Ok(NavAction::Back);
+                // 注記・解決内容の入力中
+                if self.page.is_input_active() {
+                    match key.code {
+                        KeyCode::Esc => self.page.cancel_input(),
+                        KeyCode::Enter => self.submit_input(controllers),
+                        KeyCode::Backspace => self.page.delete_char(),
+                        KeyCode::Char(c) => self.page.input_char(c),
+                        _ => {}
+                    }
+                    continue;
+                }
+
+                match key.code {
+                    KeyCode::Esc => {
+                        return Ok(NavAction::Back);
+                    }
+                    KeyCode::Char('d') => {
+                        self.page.toggle_history();
+                    }
+                    KeyCode::Char('a') => {
+                        self.page.start_note_input();
+                    }
+                    KeyCode::Char('r') => {
+                        self.page.start_resolution_input();
+                    }
+                    KeyCode::Char('l') => {
+                        self.page.start_link_input(EntryLinkKind::AccrualSettlement.code());
+                    }
+                    KeyCode::Char('p') => {
+                        self.page.start_link_input(EntryLinkKind::PrepaymentAmortization.code());
+                    }
+                    KeyCode::Char('x') => {
+                        self.unlink_latest(controllers);
+                    }
+                    _ => {}
                 }
             }
         }
@@ -9,8 +9,9 @@ use ratatui::DefaultTerminal;
 use crate::{
     error::AdapterResult,
     navigation::{Controllers, NavAction, PageState, Route},
-    presenter::LedgerEntryViewModel,
-    views::pages::LedgerPage,
+    page_states::{ProtectedExport, TablePreferenceSync},
+    presenter::{LedgerEntryViewModel, warm_up_message},
+    views::{layouts::render_guarded, pages::LedgerPage},
 };
 
 // Shared state for passing selected entry to detail view
@@ -23,6 +24,10 @@ lazy_static::lazy_static! {
 pub struct LedgerPageState {
     /// The ledger page view
     page: LedgerPage,
+    /// 元帳テーブルの表示設定
+    table_preference: TablePreferenceSync,
+    /// 出力ファイルの保護付き書き出し
+    export: ProtectedExport,
 }
 
 impl LedgerPageState {
@@ -41,7 +46,11 @@ impl LedgerPageState {
         let (_tx, rx) = tokio::sync::mpsc::unbounded_channel();
         let page = LedgerPage::new(rx);
 
-        Self { page }
+        Self {
+            page,
+            table_preference: TablePreferenceSync::new("ledger"),
+            export: ProtectedExport::new(),
+        }
     }
 
     /// 選択されたエントリのインデックスを取得
@@ -60,6 +69,26 @@ impl LedgerPageState {
         }
     }
 
+    /// 保持していた表示状態を破棄して元帳を読み直す
+    fn refresh(&mut self) {
+        let (_tx, rx) = tokio::sync::mpsc::unbounded_channel();
+        self.page = LedgerPage::new(rx);
+        self.page.add_info("元帳を再読込しました");
+    }
+
+    /// 入力されたファイル名で表示中の元帳を出力
+    fn confirm_export(&mut self, controllers: &Controllers) {
+        let Some(file_name) = self.page.export_prompt_mut().file_name() else {
+            return;
+        };
+        let format = self.page.export_prompt_mut().format();
+        self.page.export_prompt_mut().hide();
+
+        let (content, count) = self.page.export_content(format);
+        self.export
+            .export(controllers, file_name, content.into_bytes(), format!(" ({}件)", count));
+    }
+
     /// 共有状態から選択されたエントリを取得
     pub fn take_selected_entry() -> Option<(LedgerEntryViewModel, String, String)> {
         SELECTED_LEDGER_ENTRY.lock().ok()?.take()
@@ -77,14 +106,40 @@ impl PageState for LedgerPageState {
         Route::Ledger
     }
 
+    fn preserves_state(&self) -> bool {
+        true
+    }
+
+    fn on_restore(&mut self) {
+        self.page.add_info("前回の表示位置を復元しました（[F5] 再読込）");
+    }
+
     fn run(
         &mut self,
         terminal: &mut DefaultTerminal,
-        _controllers: &Controllers,
+        controllers: &Controllers,
     ) -> AdapterResult<NavAction> {
+        self.table_preference.request_load(controllers);
+        self.export.request_settings(controllers);
+
         loop {
             // Update page state
             self.page.update();
+            self.table_preference.apply_loaded(self.page.ledger_table_mut());
+            for result in self.export.poll() {
+                match result {
+                    Ok(message) => self.page.add_info(message),
+                    Err(e) => self.page.add_error(e),
+                }
+            }
+
+            // 元帳Projectionの構築中は構築進捗を表示
+            if let Some(message) =
+                warm_up_message("元帳データ", &controllers.ledger.warm_up_progress())
+                && self.page.ledger_table_mut().is_loading()
+            {
+                self.page.ledger_table_mut().set_loading_progress(message);
+            }
 
             // Tick animation
             self.page.tick();
@@ -92,13 +147,19 @@ impl PageState for LedgerPageState {
             // Render the page
             terminal
                 .draw(|frame| {
-                    self.page.render(frame);
+                    render_guarded(frame, |frame| {
+                        self.page.render(frame);
+                        self.export.render(frame);
+                    });
                 })
                 .map_err(|e| crate::error::AdapterError::RenderingFailed(e.to_string()))?;
 
+            if controllers.session.lock_if_idle() {
+                return Ok(NavAction::Go(Route::Login));
+            }
+
             // Handle events with timeout for animation
-            if event::poll(std::time::Duration::from_millis(100))
-                .map_err(crate::error::AdapterError::EventReadFailed)?
+            if controllers.session.poll_input(std::time::Duration::from_millis(100))?
                 && let Event::Key(key) =
                     event::read().map_err(crate::error::AdapterError::EventReadFailed)?
             {
@@ -106,25 +167,62 @@ impl PageState for LedgerPageState {
                     continue;
                 }
 
+                // 暗号化パスワード入力中
+                if self.export.handle_key(key.code, controllers) {
+                    continue;
+                }
+
+                // 出力ファイル名入力中
+                if self.page.is_export_prompt_visible() {
+                    match key.code {
+                        KeyCode::Esc => self.page.export_prompt_mut().hide(),
+                        KeyCode::Enter => self.confirm_export(controllers),
+                        KeyCode::Tab => self.page.export_prompt_mut().toggle_format(),
+                        KeyCode::Backspace => self.page.export_prompt_mut().delete_char(),
+                        KeyCode::Char(c) => self.page.export_prompt_mut().input_char(c),
+                        _ => {}
+                    }
+                    continue;
+                }
+
                 match key.code {
                     KeyCode::Esc => {
                         // Navigate back to home
                         return Ok(NavAction::Back);
                     }
                     KeyCode::Enter => {
-                        // Navigate to ledger detail view
-                        if self.selected_entry_index().is_some() {
+                        // Navigate to ledger detail view (前月繰越行は仕訳がないため対象外)
+                        if self
+                            .page
+                            .get_selected_entry()
+                            .is_some_and(|entry| !entry.carried_forward)
+                        {
                             self.store_selected_entry();
                             return Ok(NavAction::Go(Route::LedgerDetail));
                         }
                     }
+                    KeyCode::Char('x') => {
+                        self.page.open_export_prompt();
+                    }
+                    KeyCode::Char('f') => {
+                        self.page.toggle_flagged_only();
+                    }
+                    KeyCode::F(5) => {
+                        self.refresh();
+                    }
                     KeyCode::Char('j') | KeyCode::Down => {
                         self.page.select_next();
                     }
                     KeyCode::Char('k') | KeyCode::Up => {
                         self.page.select_previous();
                     }
-                    _ => {}
+                    code => {
+                        self.table_preference.handle_key(
+                            code,
+                            self.page.ledger_table_mut(),
+                            controllers,
+                        );
+                    }
                 }
             }
         }
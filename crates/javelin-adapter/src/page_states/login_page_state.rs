@@ -0,0 +1,156 @@
+// LoginPageState - ログイン画面の状態
+// 責務: ローカル資格情報による認証とロック解除、ログイン・ログイン失敗の記録
+
+use std::sync::Arc;
+
+use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use javelin_application::dtos::response::LoginResponse;
+use ratatui::DefaultTerminal;
+use tokio::sync::mpsc;
+
+use crate::{
+    error::AdapterResult,
+    navigation::{Controllers, NavAction, PageState, Route, SessionUser},
+    views::{
+        layouts::render_guarded,
+        pages::{LoginField, LoginPage},
+    },
+};
+
+pub struct LoginPageState {
+    page: LoginPage,
+    result_tx: mpsc::UnboundedSender<Result<LoginResponse, String>>,
+    result_rx: mpsc::UnboundedReceiver<Result<LoginResponse, String>>,
+    /// 認証中のユーザID（ログイン失敗の記録用）
+    submitted_user_id: String,
+}
+
+impl LoginPageState {
+    pub fn new() -> Self {
+        let (result_tx, result_rx) = mpsc::unbounded_channel();
+        Self { page: LoginPage::new(), result_tx, result_rx, submitted_user_id: String::new() }
+    }
+
+    /// 入力された資格情報で認証
+    fn submit(&mut self, controllers: &Controllers) {
+        if self.page.is_submitting() {
+            return;
+        }
+
+        let controller = Arc::clone(&controllers.authentication);
+        let result_tx = self.result_tx.clone();
+        let user_id = self.page.user_id().to_string();
+        let password = self.page.password().to_string();
+        self.submitted_user_id = user_id.clone();
+        self.page.start_submitting();
+
+        tokio::spawn(async move {
+            let _ = result_tx.send(controller.login(user_id, password).await);
+        });
+    }
+
+    /// 認証結果を反映し、成功した場合はセッションを開始
+    ///
+    /// ロック解除の場合は記録中のセッションを継続し、新しいログインとしては記録しない。
+    fn poll_result(&mut self, controllers: &Controllers) -> bool {
+        let Ok(result) = self.result_rx.try_recv() else {
+            return false;
+        };
+
+        match result {
+            Ok(response) => {
+                let unlocking =
+                    controllers.session.is_locked() && controllers.session.session_id().is_some();
+                let user_id = response.user_id.clone();
+                controllers.session.start(SessionUser {
+                    user_id: response.user_id,
+                    display_name: response.display_name,
+                });
+                if !unlocking {
+                    controllers.session_log.track_login(Arc::clone(&controllers.session), user_id);
+                }
+                true
+            }
+            Err(message) => {
+                let controller = Arc::clone(&controllers.session_log);
+                let user_id = self.submitted_user_id.clone();
+                let reason = message.clone();
+                tokio::spawn(async move {
+                    controller.record_failed_login(user_id, reason).await;
+                });
+                self.page.set_error(message);
+                false
+            }
+        }
+    }
+}
+
+impl PageState for LoginPageState {
+    fn route(&self) -> Route {
+        Route::Login
+    }
+
+    fn run(
+        &mut self,
+        terminal: &mut DefaultTerminal,
+        controllers: &Controllers,
+    ) -> AdapterResult<NavAction> {
+        // ロック中は同じ利用者のみ解除できる
+        if controllers.session.is_locked()
+            && let Some(user) = controllers.session.user()
+        {
+            self.page.set_locked_user(user.user_id);
+        }
+        // 強制ログアウトされた場合はその旨を表示する
+        if let Some(notice) = controllers.session.take_notice() {
+            self.page.set_error(notice);
+        }
+
+        loop {
+            if self.poll_result(controllers) {
+                return Ok(NavAction::Back);
+            }
+
+            self.page.tick();
+
+            terminal
+                .draw(|frame| {
+                    render_guarded(frame, |frame| self.page.render(frame));
+                })
+                .map_err(|e| crate::error::AdapterError::RenderingFailed(e.to_string()))?;
+
+            if controllers.session.poll_input(std::time::Duration::from_millis(100))?
+                && let Event::Key(key) =
+                    event::read().map_err(crate::error::AdapterError::EventReadFailed)?
+            {
+                if key.kind != KeyEventKind::Press {
+                    continue;
+                }
+
+                match key.code {
+                    KeyCode::Esc => return Ok(NavAction::Back),
+                    KeyCode::Enter => match self.page.focus() {
+                        LoginField::UserId => self.page.toggle_focus(),
+                        LoginField::Password => self.submit(controllers),
+                    },
+                    KeyCode::Tab | KeyCode::BackTab | KeyCode::Up | KeyCode::Down => {
+                        self.page.toggle_focus()
+                    }
+                    KeyCode::Backspace => self.page.delete_char(),
+                    KeyCode::Char(c) => self.page.input_char(c),
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    fn on_navigation_error(&mut self, error_message: &str) {
+        self.page.set_error(error_message);
+    }
+}
+
+impl Default for LoginPageState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
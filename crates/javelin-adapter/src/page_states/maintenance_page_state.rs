@@ -0,0 +1,177 @@
+// MaintenancePageState - メンテナンス画面の状態
+// 責務: Projection間整合性チェックの実行と結果の反映、Projection圧縮の登録、
+//       ストレージ使用状況の推移の読み込み
+
+use std::sync::Arc;
+
+use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use ratatui::DefaultTerminal;
+use tokio::sync::mpsc;
+
+use crate::{
+    error::AdapterResult,
+    navigation::{Controllers, NavAction, PageState, Route},
+    presenter::{ConsistencyCheckViewModel, StorageTrendViewModel, progress_channel_stats},
+    views::{layouts::render_guarded, pages::MaintenancePage},
+};
+
+/// チェック結果・圧縮ジョブの登録結果・使用状況の推移
+enum CheckUpdate {
+    Completed(ConsistencyCheckViewModel),
+    Failed(String),
+    CompactionEnqueued(String),
+    CompactionFailed(String),
+    TrendLoaded(StorageTrendViewModel),
+    TrendFailed(String),
+}
+
+pub struct MaintenancePageState {
+    page: MaintenancePage,
+    update_tx: mpsc::UnboundedSender<CheckUpdate>,
+    update_rx: mpsc::UnboundedReceiver<CheckUpdate>,
+    /// 推移を読み込み済みか（画面表示時に一度だけ読み込む）
+    trend_requested: bool,
+}
+
+impl MaintenancePageState {
+    pub fn new() -> Self {
+        let (update_tx, update_rx) = mpsc::unbounded_channel();
+        Self { page: MaintenancePage::new(), update_tx, update_rx, trend_requested: false }
+    }
+
+    /// 整合性チェックを開始
+    fn run_check(&mut self, controllers: &Controllers) {
+        if self.page.is_running() {
+            return;
+        }
+        self.page.set_running();
+
+        let controller = Arc::clone(&controllers.consistency_check);
+        let update_tx = self.update_tx.clone();
+
+        tokio::spawn(async move {
+            let update = match controller.run_check().await {
+                Ok(response) => {
+                    CheckUpdate::Completed(ConsistencyCheckViewModel::from_response(&response))
+                }
+                Err(e) => CheckUpdate::Failed(e),
+            };
+            let _ = update_tx.send(update);
+        });
+    }
+
+    /// Projection圧縮をジョブキューに登録
+    fn enqueue_compaction(&mut self, controllers: &Controllers) {
+        let controller = Arc::clone(&controllers.job_queue);
+        let update_tx = self.update_tx.clone();
+        let requested_by = controllers.session.user_id();
+
+        tokio::spawn(async move {
+            let update = match controller.enqueue_projection_compaction(requested_by).await {
+                Ok(job) => CheckUpdate::CompactionEnqueued(job.job_id),
+                Err(e) => CheckUpdate::CompactionFailed(e),
+            };
+            let _ = update_tx.send(update);
+        });
+    }
+
+    /// ストレージ使用状況の推移を読み込む（`record` の場合は現在の使用状況を記録してから）
+    fn load_storage_trend(&mut self, controllers: &Controllers, record: bool) {
+        self.trend_requested = true;
+        let controller = Arc::clone(&controllers.storage_telemetry);
+        let update_tx = self.update_tx.clone();
+
+        tokio::spawn(async move {
+            if record && let Err(e) = controller.record_sample().await {
+                let _ = update_tx.send(CheckUpdate::TrendFailed(e));
+                return;
+            }
+            let update = match controller.samples().await {
+                Ok(samples) => {
+                    CheckUpdate::TrendLoaded(StorageTrendViewModel::from_samples(&samples))
+                }
+                Err(e) => CheckUpdate::TrendFailed(e),
+            };
+            let _ = update_tx.send(update);
+        });
+    }
+
+    /// チェック結果を反映
+    fn poll_updates(&mut self) {
+        while let Ok(update) = self.update_rx.try_recv() {
+            match update {
+                CheckUpdate::Completed(view_model) => self.page.set_result(view_model),
+                CheckUpdate::Failed(message) => self.page.set_error(message),
+                CheckUpdate::CompactionEnqueued(job_id) => self.page.set_notice(format!(
+                    "Projection圧縮を登録しました（{}）。進捗は [b] ジョブ一覧で確認できます",
+                    job_id
+                )),
+                CheckUpdate::CompactionFailed(message) => {
+                    self.page.set_notice(format!("Projection圧縮を登録できません: {}", message))
+                }
+                CheckUpdate::TrendLoaded(view_model) => self.page.set_storage_trend(view_model),
+                CheckUpdate::TrendFailed(message) => self.page.set_storage_trend_error(message),
+            }
+        }
+    }
+}
+
+impl PageState for MaintenancePageState {
+    fn route(&self) -> Route {
+        Route::Maintenance
+    }
+
+    fn run(
+        &mut self,
+        terminal: &mut DefaultTerminal,
+        controllers: &Controllers,
+    ) -> AdapterResult<NavAction> {
+        if !self.trend_requested {
+            self.load_storage_trend(controllers, false);
+        }
+
+        loop {
+            self.poll_updates();
+            self.page.set_progress_stats(progress_channel_stats());
+
+            terminal
+                .draw(|frame| {
+                    render_guarded(frame, |frame| self.page.render(frame));
+                })
+                .map_err(|e| crate::error::AdapterError::RenderingFailed(e.to_string()))?;
+
+            if controllers.session.lock_if_idle() {
+                return Ok(NavAction::Go(Route::Login));
+            }
+
+            if controllers.session.poll_input(std::time::Duration::from_millis(100))?
+                && let Event::Key(key) =
+                    event::read().map_err(crate::error::AdapterError::EventReadFailed)?
+            {
+                if key.kind != KeyEventKind::Press {
+                    continue;
+                }
+
+                match key.code {
+                    KeyCode::Esc => return Ok(NavAction::Back),
+                    KeyCode::Char('r') => self.run_check(controllers),
+                    KeyCode::Char('g') => self.enqueue_compaction(controllers),
+                    KeyCode::Char('s') => self.load_storage_trend(controllers, true),
+                    KeyCode::Char('p') => return Ok(NavAction::Go(Route::ProjectionConsole)),
+                    KeyCode::Char('b') => return Ok(NavAction::Go(Route::JobQueue)),
+                    KeyCode::Char('u') => return Ok(NavAction::Go(Route::UserActivity)),
+                    KeyCode::Char('a') => return Ok(NavAction::Go(Route::SessionLog)),
+                    KeyCode::Up | KeyCode::Char('k') => self.page.select_previous(),
+                    KeyCode::Down | KeyCode::Char('j') => self.page.select_next(),
+                    _ => {}
+                }
+            }
+        }
+    }
+}
+
+impl Default for MaintenancePageState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,220 @@
+// ManagementAccountMappingPageState - 管理会計科目マッピング画面の状態
+// 責務: 割当状況の取得と管理会計科目の割当・解除操作
+
+use std::sync::Arc;
+
+use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use javelin_application::dtos::request::{
+    AssignManagementAccountRequest, RemoveManagementAccountMappingRequest,
+};
+use ratatui::DefaultTerminal;
+use tokio::sync::mpsc;
+
+use crate::{
+    error::AdapterResult,
+    navigation::{Controllers, NavAction, PageState, Route},
+    presenter::ManagementAccountMappingViewModel,
+    views::{layouts::render_guarded, pages::ManagementAccountMappingPage},
+};
+
+/// 操作結果
+enum MappingUpdate {
+    Loaded(ManagementAccountMappingViewModel),
+    Saved(String),
+    SaveFailed(String),
+    LoadFailed(String),
+}
+
+pub struct ManagementAccountMappingPageState {
+    page: ManagementAccountMappingPage,
+    update_tx: mpsc::UnboundedSender<MappingUpdate>,
+    update_rx: mpsc::UnboundedReceiver<MappingUpdate>,
+    /// 編集中の勘定科目コードと入力値
+    input: Option<(String, String)>,
+    /// データロード済みフラグ
+    data_loaded: bool,
+}
+
+impl ManagementAccountMappingPageState {
+    pub fn new() -> Self {
+        let (update_tx, update_rx) = mpsc::unbounded_channel();
+        Self {
+            page: ManagementAccountMappingPage::new(),
+            update_tx,
+            update_rx,
+            input: None,
+            data_loaded: false,
+        }
+    }
+
+    /// 割当状況を再取得
+    fn load_mappings(&self, controllers: &Controllers) {
+        let controller = Arc::clone(&controllers.management_account_mapping);
+        let update_tx = self.update_tx.clone();
+
+        tokio::spawn(async move {
+            let update = match controller.load_mappings().await {
+                Ok(response) => MappingUpdate::Loaded(
+                    ManagementAccountMappingViewModel::from_response(&response),
+                ),
+                Err(e) => MappingUpdate::LoadFailed(e),
+            };
+            let _ = update_tx.send(update);
+        });
+    }
+
+    /// 選択中の勘定科目の編集を開始（現在の割当を初期値とする）
+    fn start_edit(&mut self) {
+        if let Some(item) = self.page.selected_item() {
+            self.input = Some((item.account_code.clone(), item.management_account_spec.clone()));
+        }
+    }
+
+    /// 入力中のキー操作
+    fn handle_input_key(&mut self, code: KeyCode, controllers: &Controllers) {
+        let Some((_, input)) = self.input.as_mut() else {
+            return;
+        };
+        match code {
+            KeyCode::Esc => self.input = None,
+            KeyCode::Enter => self.submit_input(controllers),
+            KeyCode::Backspace => {
+                input.pop();
+            }
+            KeyCode::Char(c) => input.push(c),
+            _ => {}
+        }
+    }
+
+    /// 入力した管理会計科目を割り当てる（形式の検証はドメインで行う）
+    fn submit_input(&mut self, controllers: &Controllers) {
+        let Some((account_code, input)) = self.input.take() else {
+            return;
+        };
+        let (management_account_code, management_account_name) =
+            input.split_once(':').unwrap_or((input.as_str(), ""));
+
+        let controller = Arc::clone(&controllers.management_account_mapping);
+        let update_tx = self.update_tx.clone();
+        let request = AssignManagementAccountRequest {
+            account_code,
+            management_account_code: management_account_code.to_string(),
+            management_account_name: management_account_name.to_string(),
+        };
+
+        tokio::spawn(async move {
+            let account_code = request.account_code.clone();
+            let update = match controller.assign_management_account(request).await {
+                Ok(()) => {
+                    MappingUpdate::Saved(format!("{} の管理会計科目を更新しました", account_code))
+                }
+                Err(e) => MappingUpdate::SaveFailed(e),
+            };
+            let _ = update_tx.send(update);
+        });
+    }
+
+    /// 選択中の勘定科目の割当を解除
+    fn remove_selected(&self, controllers: &Controllers) {
+        let Some(item) = self.page.selected_item().filter(|item| item.is_mapped()) else {
+            return;
+        };
+
+        let controller = Arc::clone(&controllers.management_account_mapping);
+        let update_tx = self.update_tx.clone();
+        let request =
+            RemoveManagementAccountMappingRequest { account_code: item.account_code.clone() };
+
+        tokio::spawn(async move {
+            let account_code = request.account_code.clone();
+            let update = match controller.remove_mapping(request).await {
+                Ok(()) => MappingUpdate::Saved(format!("{} の割当を解除しました", account_code)),
+                Err(e) => MappingUpdate::SaveFailed(e),
+            };
+            let _ = update_tx.send(update);
+        });
+    }
+
+    /// 操作結果を反映
+    fn poll_updates(&mut self, controllers: &Controllers) {
+        while let Ok(update) = self.update_rx.try_recv() {
+            match update {
+                MappingUpdate::Loaded(view_model) => self.page.set_data(view_model),
+                MappingUpdate::Saved(message) => {
+                    self.page.set_status_message(message);
+                    self.load_mappings(controllers);
+                }
+                MappingUpdate::SaveFailed(message) => {
+                    self.page.set_status_message(format!("更新に失敗しました: {}", message));
+                }
+                MappingUpdate::LoadFailed(message) => self.page.set_error(message),
+            }
+        }
+    }
+}
+
+impl PageState for ManagementAccountMappingPageState {
+    fn route(&self) -> Route {
+        Route::ManagementAccountMapping
+    }
+
+    fn run(
+        &mut self,
+        terminal: &mut DefaultTerminal,
+        controllers: &Controllers,
+    ) -> AdapterResult<NavAction> {
+        // 初回ロード
+        if !self.data_loaded {
+            self.data_loaded = true;
+            self.load_mappings(controllers);
+        }
+
+        loop {
+            self.poll_updates(controllers);
+
+            terminal
+                .draw(|frame| {
+                    let input = self
+                        .input
+                        .as_ref()
+                        .map(|(account_code, value)| (account_code.as_str(), value.as_str()));
+                    render_guarded(frame, |frame| self.page.render(frame, input));
+                })
+                .map_err(|e| crate::error::AdapterError::RenderingFailed(e.to_string()))?;
+
+            if controllers.session.lock_if_idle() {
+                return Ok(NavAction::Go(Route::Login));
+            }
+
+            if controllers.session.poll_input(std::time::Duration::from_millis(100))?
+                && let Event::Key(key) =
+                    event::read().map_err(crate::error::AdapterError::EventReadFailed)?
+            {
+                if key.kind != KeyEventKind::Press {
+                    continue;
+                }
+
+                if self.input.is_some() {
+                    self.handle_input_key(key.code, controllers);
+                    continue;
+                }
+
+                match key.code {
+                    KeyCode::Esc => return Ok(NavAction::Back),
+                    KeyCode::Up | KeyCode::Char('k') => self.page.select_previous(),
+                    KeyCode::Down | KeyCode::Char('j') => self.page.select_next(),
+                    KeyCode::Enter | KeyCode::Char('e') => self.start_edit(),
+                    KeyCode::Char('x') => self.remove_selected(controllers),
+                    KeyCode::Char('n') => self.page.select_next_unmapped(),
+                    _ => {}
+                }
+            }
+        }
+    }
+}
+
+impl Default for ManagementAccountMappingPageState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
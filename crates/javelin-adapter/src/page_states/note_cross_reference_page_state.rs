@@ -0,0 +1,304 @@
+// NoteCrossReferencePageState - 注記参照画面の状態
+// 責務: 注記一覧の取得、注記の登録・編集・削除と、表示科目・勘定科目からの参照の登録・解除
+
+use std::sync::Arc;
+
+use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use javelin_application::dtos::request::{
+    DeleteNoteCrossReferenceRequest, DeleteNoteSectionRequest, SaveNoteCrossReferenceRequest,
+    SaveNoteSectionRequest,
+};
+use ratatui::DefaultTerminal;
+use tokio::sync::mpsc;
+
+use crate::{
+    error::AdapterResult,
+    navigation::{Controllers, NavAction, PageState, Route},
+    presenter::NoteCrossReferenceViewModel,
+    views::{layouts::render_guarded, pages::NoteCrossReferencePage},
+};
+
+/// 操作結果
+enum NoteUpdate {
+    Loaded(NoteCrossReferenceViewModel),
+    Saved(String),
+    SaveFailed(String),
+    LoadFailed(String),
+}
+
+/// 編集中の入力
+enum NoteInput {
+    /// 注記（`<番号>:<表題>:<本文>`）
+    Section(String),
+    /// 注記を参照する参照先（必須は末尾に `!`）
+    Reference(u32, String),
+    /// 参照を解除する参照先
+    Unlink(u32, String),
+}
+
+impl NoteInput {
+    fn label(&self) -> String {
+        match self {
+            Self::Section(_) => "注記（<番号>:<表題>:<本文>）".to_string(),
+            Self::Reference(number, _) => format!(
+                "注{}の参照先（表示科目コード BS-CA 等 / 勘定科目コード、必須は末尾に!）",
+                number
+            ),
+            Self::Unlink(number, _) => format!("注{}の参照を解除する参照先", number),
+        }
+    }
+
+    fn value_mut(&mut self) -> &mut String {
+        match self {
+            Self::Section(value) | Self::Reference(_, value) | Self::Unlink(_, value) => value,
+        }
+    }
+
+    fn value(&self) -> &str {
+        match self {
+            Self::Section(value) | Self::Reference(_, value) | Self::Unlink(_, value) => value,
+        }
+    }
+}
+
+pub struct NoteCrossReferencePageState {
+    page: NoteCrossReferencePage,
+    update_tx: mpsc::UnboundedSender<NoteUpdate>,
+    update_rx: mpsc::UnboundedReceiver<NoteUpdate>,
+    input: Option<NoteInput>,
+    /// データロード済みフラグ
+    data_loaded: bool,
+}
+
+impl NoteCrossReferencePageState {
+    pub fn new() -> Self {
+        let (update_tx, update_rx) = mpsc::unbounded_channel();
+        Self {
+            page: NoteCrossReferencePage::new(),
+            update_tx,
+            update_rx,
+            input: None,
+            data_loaded: false,
+        }
+    }
+
+    /// 注記一覧を再取得
+    fn load(&self, controllers: &Controllers) {
+        let controller = Arc::clone(&controllers.note_cross_reference);
+        let update_tx = self.update_tx.clone();
+
+        tokio::spawn(async move {
+            let update = match controller.load().await {
+                Ok(response) => {
+                    NoteUpdate::Loaded(NoteCrossReferenceViewModel::from_response(&response))
+                }
+                Err(e) => NoteUpdate::LoadFailed(e),
+            };
+            let _ = update_tx.send(update);
+        });
+    }
+
+    /// 入力中のキー操作
+    fn handle_input_key(&mut self, code: KeyCode, controllers: &Controllers) {
+        let Some(input) = self.input.as_mut() else {
+            return;
+        };
+        match code {
+            KeyCode::Esc => self.input = None,
+            KeyCode::Enter => self.submit_input(controllers),
+            KeyCode::Backspace => {
+                input.value_mut().pop();
+            }
+            KeyCode::Char(c) => input.value_mut().push(c),
+            _ => {}
+        }
+    }
+
+    /// 入力内容を保存（項目の検証はドメインで行う）
+    fn submit_input(&mut self, controllers: &Controllers) {
+        let Some(input) = self.input.take() else {
+            return;
+        };
+        let controller = Arc::clone(&controllers.note_cross_reference);
+        let update_tx = self.update_tx.clone();
+
+        match input {
+            NoteInput::Section(value) => {
+                let mut parts = value.splitn(3, ':');
+                let Ok(number) = parts.next().unwrap_or_default().trim().parse::<u32>() else {
+                    self.page.set_status_message("注記番号は数値で入力してください");
+                    self.input = Some(NoteInput::Section(value));
+                    return;
+                };
+                let request = SaveNoteSectionRequest {
+                    number,
+                    title: parts.next().unwrap_or_default().to_string(),
+                    body: parts.next().unwrap_or_default().to_string(),
+                };
+                tokio::spawn(async move {
+                    let update = match controller.save_section(request).await {
+                        Ok(()) => NoteUpdate::Saved(format!("注{}を保存しました", number)),
+                        Err(e) => NoteUpdate::SaveFailed(e),
+                    };
+                    let _ = update_tx.send(update);
+                });
+            }
+            NoteInput::Reference(note_number, value) => {
+                let value = value.trim();
+                let (target, required) = match value.strip_suffix('!') {
+                    Some(target) => (target.to_string(), true),
+                    None => (value.to_string(), false),
+                };
+                let request = SaveNoteCrossReferenceRequest { target, note_number, required };
+                tokio::spawn(async move {
+                    let message = format!("{} から注{}を参照します", request.target, note_number);
+                    let update = match controller.save_reference(request).await {
+                        Ok(()) => NoteUpdate::Saved(message),
+                        Err(e) => NoteUpdate::SaveFailed(e),
+                    };
+                    let _ = update_tx.send(update);
+                });
+            }
+            NoteInput::Unlink(note_number, value) => {
+                let request = DeleteNoteCrossReferenceRequest {
+                    target: value.trim().to_string(),
+                    note_number,
+                };
+                tokio::spawn(async move {
+                    let message =
+                        format!("{} から注{}への参照を解除しました", request.target, note_number);
+                    let update = match controller.delete_reference(request).await {
+                        Ok(()) => NoteUpdate::Saved(message),
+                        Err(e) => NoteUpdate::SaveFailed(e),
+                    };
+                    let _ = update_tx.send(update);
+                });
+            }
+        }
+    }
+
+    /// 選択中の注記を削除
+    fn delete_selected(&self, controllers: &Controllers) {
+        let Some(item) = self.page.selected_item() else {
+            return;
+        };
+
+        let controller = Arc::clone(&controllers.note_cross_reference);
+        let update_tx = self.update_tx.clone();
+        let number = item.number;
+
+        tokio::spawn(async move {
+            let update = match controller.delete_section(DeleteNoteSectionRequest { number }).await
+            {
+                Ok(()) => NoteUpdate::Saved(format!("注{}を削除しました", number)),
+                Err(e) => NoteUpdate::SaveFailed(e),
+            };
+            let _ = update_tx.send(update);
+        });
+    }
+
+    /// 操作結果を反映
+    fn poll_updates(&mut self, controllers: &Controllers) {
+        while let Ok(update) = self.update_rx.try_recv() {
+            match update {
+                NoteUpdate::Loaded(view_model) => self.page.set_data(view_model),
+                NoteUpdate::Saved(message) => {
+                    self.page.set_status_message(message);
+                    self.load(controllers);
+                }
+                NoteUpdate::SaveFailed(message) => {
+                    self.page.set_status_message(format!("更新に失敗しました: {}", message));
+                }
+                NoteUpdate::LoadFailed(message) => self.page.set_error(message),
+            }
+        }
+    }
+}
+
+impl PageState for NoteCrossReferencePageState {
+    fn route(&self) -> Route {
+        Route::NoteCrossReference
+    }
+
+    fn run(
+        &mut self,
+        terminal: &mut DefaultTerminal,
+        controllers: &Controllers,
+    ) -> AdapterResult<NavAction> {
+        // 初回ロード
+        if !self.data_loaded {
+            self.data_loaded = true;
+            self.load(controllers);
+        }
+
+        loop {
+            self.poll_updates(controllers);
+
+            terminal
+                .draw(|frame| {
+                    render_guarded(frame, |frame| {
+                        let label = self.input.as_ref().map(NoteInput::label);
+                        let input = self.input.as_ref().map(NoteInput::value);
+                        self.page.render(frame, label.as_deref().zip(input));
+                    });
+                })
+                .map_err(|e| crate::error::AdapterError::RenderingFailed(e.to_string()))?;
+
+            if controllers.session.lock_if_idle() {
+                return Ok(NavAction::Go(Route::Login));
+            }
+
+            if controllers.session.poll_input(std::time::Duration::from_millis(100))?
+                && let Event::Key(key) =
+                    event::read().map_err(crate::error::AdapterError::EventReadFailed)?
+            {
+                if key.kind != KeyEventKind::Press {
+                    continue;
+                }
+
+                if self.input.is_some() {
+                    self.handle_input_key(key.code, controllers);
+                    continue;
+                }
+
+                match key.code {
+                    KeyCode::Esc => return Ok(NavAction::Back),
+                    KeyCode::Up | KeyCode::Char('k') => self.page.select_previous(),
+                    KeyCode::Down | KeyCode::Char('j') => self.page.select_next(),
+                    KeyCode::Char('a') => self.input = Some(NoteInput::Section(String::new())),
+                    KeyCode::Enter | KeyCode::Char('e') => {
+                        self.input = self
+                            .page
+                            .selected_item()
+                            .map(|item| NoteInput::Section(item.spec.clone()))
+                    }
+                    KeyCode::Char('x') => self.delete_selected(controllers),
+                    KeyCode::Char('c') => {
+                        self.input = self
+                            .page
+                            .selected_item()
+                            .map(|item| NoteInput::Reference(item.number, String::new()))
+                    }
+                    KeyCode::Char('u') => {
+                        // 参照先が1件だけの場合は初期値にする
+                        self.input = self.page.selected_item().map(|item| {
+                            let target = match item.reference_targets.as_slice() {
+                                [target] => target.clone(),
+                                _ => String::new(),
+                            };
+                            NoteInput::Unlink(item.number, target)
+                        })
+                    }
+                    KeyCode::Char('r') => self.load(controllers),
+                    _ => {}
+                }
+            }
+        }
+    }
+}
+
+impl Default for NoteCrossReferencePageState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
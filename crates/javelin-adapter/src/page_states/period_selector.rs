@@ -0,0 +1,81 @@
+// PeriodSelector - 画面で選択中の対象月
+// 責務: 決算・帳票画面に共通する対象月の初期値、前後への移動、表示ラベル
+
+use chrono::Datelike;
+
+use crate::page_states::report_parameters::{self, StandardReport};
+
+/// 選択中の対象月（会計年度・月）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PeriodSelector {
+    fiscal_year: i32,
+    period: u8,
+}
+
+impl PeriodSelector {
+    pub fn new(fiscal_year: i32, period: u8) -> Self {
+        Self { fiscal_year, period }
+    }
+
+    /// 業務日付の当月
+    pub fn current() -> Self {
+        let today = crate::clock::business_date();
+        Self::new(today.year(), today.month() as u8)
+    }
+
+    /// 業務日付の前月
+    pub fn previous() -> Self {
+        let mut selector = Self::current();
+        selector.shift(false);
+        selector
+    }
+
+    /// 帳票メニューから条件が渡された場合はその対象月、それ以外は業務日付の当月
+    pub fn handed_off_or_current(report: StandardReport) -> Self {
+        report_parameters::take_handed_off(report)
+            .as_ref()
+            .and_then(report_parameters::parse_period)
+            .map(|(fiscal_year, period)| Self::new(fiscal_year, period))
+            .unwrap_or_else(Self::current)
+    }
+
+    pub fn fiscal_year(&self) -> i32 {
+        self.fiscal_year
+    }
+
+    pub fn period(&self) -> u8 {
+        self.period
+    }
+
+    /// 表示ラベル（YYYY-MM）
+    pub fn label(&self) -> String {
+        format!("{}-{:02}", self.fiscal_year, self.period)
+    }
+
+    /// 対象月を前後に移動（年をまたぐ場合は年も移動する）
+    pub fn shift(&mut self, forward: bool) {
+        (self.fiscal_year, self.period) = match (forward, self.period) {
+            (true, 12) => (self.fiscal_year + 1, 1),
+            (true, period) => (self.fiscal_year, period + 1),
+            (false, 1) => (self.fiscal_year - 1, 12),
+            (false, period) => (self.fiscal_year, period - 1),
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shift_wraps_across_years() {
+        let mut selector = PeriodSelector::new(2024, 12);
+        selector.shift(true);
+        assert_eq!(selector, PeriodSelector::new(2025, 1));
+        assert_eq!(selector.label(), "2025-01");
+
+        selector.shift(false);
+        selector.shift(false);
+        assert_eq!(selector, PeriodSelector::new(2024, 11));
+    }
+}
@@ -0,0 +1,175 @@
+// ProjectionConsolePageState - Projection照会コンソールの状態
+// 責務: 前方一致条件の入力・照会の実行とページングの管理
+
+use std::sync::Arc;
+
+use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use javelin_application::dtos::response::QueryProjectionResponse;
+use ratatui::DefaultTerminal;
+use tokio::sync::mpsc;
+
+use crate::{
+    error::AdapterResult,
+    navigation::{Controllers, NavAction, PageState, Route},
+    views::{layouts::render_guarded, pages::ProjectionConsolePage},
+};
+
+/// 照会結果
+enum QueryUpdate {
+    Completed { response: QueryProjectionResponse, page_start: Option<String> },
+    Failed(String),
+}
+
+pub struct ProjectionConsolePageState {
+    page: ProjectionConsolePage,
+    /// 照会中の前方一致条件
+    prefix: String,
+    /// 表示中ページの開始条件（前ページの最終キー）
+    current_start: Option<String>,
+    /// 前ページまでの開始条件（前ページへ戻るためのスタック）
+    previous_starts: Vec<Option<String>>,
+    /// 次ページの開始条件
+    next_start: Option<String>,
+    update_tx: mpsc::UnboundedSender<QueryUpdate>,
+    update_rx: mpsc::UnboundedReceiver<QueryUpdate>,
+}
+
+impl ProjectionConsolePageState {
+    pub fn new() -> Self {
+        let (update_tx, update_rx) = mpsc::unbounded_channel();
+        Self {
+            page: ProjectionConsolePage::new(),
+            prefix: String::new(),
+            current_start: None,
+            previous_starts: Vec::new(),
+            next_start: None,
+            update_tx,
+            update_rx,
+        }
+    }
+
+    /// 入力中の条件で先頭ページから照会
+    fn run_query(&mut self, controllers: &Controllers) {
+        self.prefix = self.page.prefix().to_string();
+        self.previous_starts.clear();
+        self.load_page(controllers, None);
+    }
+
+    /// 次ページを照会
+    fn next_page(&mut self, controllers: &Controllers) {
+        if let Some(next_start) = self.next_start.clone() {
+            self.previous_starts.push(self.current_start.clone());
+            self.load_page(controllers, Some(next_start));
+        }
+    }
+
+    /// 前ページを照会
+    fn previous_page(&mut self, controllers: &Controllers) {
+        if let Some(previous_start) = self.previous_starts.pop() {
+            self.load_page(controllers, previous_start);
+        }
+    }
+
+    fn load_page(&mut self, controllers: &Controllers, page_start: Option<String>) {
+        if self.page.is_loading() {
+            return;
+        }
+        self.page.set_loading();
+
+        let controller = Arc::clone(&controllers.projection_console);
+        let user_id = controllers.session.user_id();
+        let prefix = self.prefix.clone();
+        let update_tx = self.update_tx.clone();
+
+        tokio::spawn(async move {
+            let update = match controller.query(user_id, prefix, page_start.clone()).await {
+                Ok(response) => QueryUpdate::Completed { response, page_start },
+                Err(e) => QueryUpdate::Failed(e),
+            };
+            let _ = update_tx.send(update);
+        });
+    }
+
+    /// 照会結果を反映
+    fn poll_updates(&mut self) {
+        while let Ok(update) = self.update_rx.try_recv() {
+            match update {
+                QueryUpdate::Completed { response, page_start } => {
+                    self.next_start = response.next_after.clone();
+                    self.current_start = page_start;
+                    self.page.set_result(response, self.previous_starts.len() + 1);
+                }
+                QueryUpdate::Failed(message) => self.page.set_error(message),
+            }
+        }
+    }
+}
+
+impl PageState for ProjectionConsolePageState {
+    fn route(&self) -> Route {
+        Route::ProjectionConsole
+    }
+
+    fn run(
+        &mut self,
+        terminal: &mut DefaultTerminal,
+        controllers: &Controllers,
+    ) -> AdapterResult<NavAction> {
+        loop {
+            self.poll_updates();
+
+            terminal
+                .draw(|frame| {
+                    render_guarded(frame, |frame| self.page.render(frame));
+                })
+                .map_err(|e| crate::error::AdapterError::RenderingFailed(e.to_string()))?;
+
+            if controllers.session.lock_if_idle() {
+                return Ok(NavAction::Go(Route::Login));
+            }
+
+            if controllers.session.poll_input(std::time::Duration::from_millis(100))?
+                && let Event::Key(key) =
+                    event::read().map_err(crate::error::AdapterError::EventReadFailed)?
+            {
+                if key.kind != KeyEventKind::Press {
+                    continue;
+                }
+
+                // 前方一致条件の入力中
+                if self.page.is_editing() {
+                    match key.code {
+                        KeyCode::Esc => self.page.stop_editing(),
+                        KeyCode::Enter => self.run_query(controllers),
+                        KeyCode::Backspace => self.page.delete_char(),
+                        KeyCode::Char(c) => self.page.input_char(c),
+                        _ => {}
+                    }
+                    continue;
+                }
+
+                match key.code {
+                    KeyCode::Esc => return Ok(NavAction::Back),
+                    KeyCode::Char('/') => self.page.start_editing(),
+                    KeyCode::Char('n') => self.next_page(controllers),
+                    KeyCode::Char('b') => self.previous_page(controllers),
+                    KeyCode::Char('r') if !self.prefix.is_empty() => {
+                        let current_start = self.current_start.clone();
+                        self.load_page(controllers, current_start);
+                    }
+                    KeyCode::Up | KeyCode::Char('k') => self.page.select_previous(),
+                    KeyCode::Down | KeyCode::Char('j') => self.page.select_next(),
+                    KeyCode::PageDown => self.page.scroll_value_down(),
+                    KeyCode::PageUp => self.page.scroll_value_up(),
+                    _ => {}
+                }
+            }
+        }
+    }
+}
+
+impl Default for ProjectionConsolePageState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
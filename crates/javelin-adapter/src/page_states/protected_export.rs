@@ -0,0 +1,142 @@
+// ProtectedExport - 出力ファイルの保護付き書き出し
+// 責務: 出力ファイル保護設定の読込、暗号化パスワードの入力と、保護したファイルの書き出し
+
+use std::{path::Path, sync::Arc};
+
+use crossterm::event::KeyCode;
+use ratatui::Frame;
+use tokio::sync::mpsc;
+
+use crate::{navigation::Controllers, views::components::PasswordPrompt};
+
+/// パスワード入力を待っている出力
+struct PendingExport {
+    file_name: String,
+    content: Vec<u8>,
+    summary: String,
+}
+
+/// 出力ファイルの保護付き書き出し
+///
+/// 画面表示時に出力ファイル保護設定を読み込み、暗号化が有効な場合は
+/// 出力前にパスワードを入力させる。書き出しの結果はメッセージとして受け取る。
+/// 出力先はカレントディレクトリ。
+pub struct ProtectedExport {
+    encryption_required: bool,
+    prompt: PasswordPrompt,
+    pending: Option<PendingExport>,
+    settings_tx: mpsc::UnboundedSender<bool>,
+    settings_rx: mpsc::UnboundedReceiver<bool>,
+    result_tx: mpsc::UnboundedSender<Result<String, String>>,
+    result_rx: mpsc::UnboundedReceiver<Result<String, String>>,
+}
+
+impl ProtectedExport {
+    pub fn new() -> Self {
+        let (settings_tx, settings_rx) = mpsc::unbounded_channel();
+        let (result_tx, result_rx) = mpsc::unbounded_channel();
+        Self {
+            encryption_required: false,
+            prompt: PasswordPrompt::new(),
+            pending: None,
+            settings_tx,
+            settings_rx,
+            result_tx,
+            result_rx,
+        }
+    }
+
+    /// 出力ファイル保護設定を読み込む（設定画面での変更を反映するため画面表示のたびに呼ぶ）
+    pub fn request_settings(&self, controllers: &Controllers) {
+        let controller = Arc::clone(&controllers.export_protection);
+        let settings_tx = self.settings_tx.clone();
+
+        tokio::spawn(async move {
+            if let Ok(settings) = controller.settings().await {
+                let _ = settings_tx.send(settings.encryption_enabled);
+            }
+        });
+    }
+
+    /// 出力を開始（暗号化が有効な場合はパスワード入力を表示する）
+    ///
+    /// `summary` は出力完了のメッセージに添える件数等。
+    pub fn export(
+        &mut self,
+        controllers: &Controllers,
+        file_name: String,
+        content: Vec<u8>,
+        summary: impl Into<String>,
+    ) {
+        let pending = PendingExport { file_name, content, summary: summary.into() };
+        if self.encryption_required {
+            self.prompt.open(&pending.file_name);
+            self.pending = Some(pending);
+        } else {
+            self.write(controllers, pending, None);
+        }
+    }
+
+    /// パスワード入力中のキー操作を処理（処理した場合はtrue）
+    pub fn handle_key(&mut self, code: KeyCode, controllers: &Controllers) -> bool {
+        if !self.prompt.is_visible() {
+            return false;
+        }
+        match code {
+            KeyCode::Esc => {
+                self.prompt.hide();
+                self.pending = None;
+            }
+            KeyCode::Enter => {
+                if let Some(password) = self.prompt.confirm()
+                    && let Some(pending) = self.pending.take()
+                {
+                    self.write(controllers, pending, Some(password));
+                }
+            }
+            KeyCode::Backspace => self.prompt.delete_char(),
+            KeyCode::Char(c) => self.prompt.input_char(c),
+            _ => {}
+        }
+        true
+    }
+
+    /// 読み込んだ設定の反映と、書き出し結果の取得
+    pub fn poll(&mut self) -> Vec<Result<String, String>> {
+        while let Ok(encryption_required) = self.settings_rx.try_recv() {
+            self.encryption_required = encryption_required;
+        }
+        std::iter::from_fn(|| self.result_rx.try_recv().ok()).collect()
+    }
+
+    /// パスワード入力を描画
+    pub fn render(&self, frame: &mut Frame) {
+        self.prompt.render(frame, frame.area());
+    }
+
+    fn write(&self, controllers: &Controllers, pending: PendingExport, password: Option<String>) {
+        let controller = Arc::clone(&controllers.export_protection);
+        let result_tx = self.result_tx.clone();
+
+        tokio::spawn(async move {
+            let result = controller
+                .write(Path::new("."), pending.file_name, pending.content, password)
+                .await
+                .map(|paths| {
+                    let files: Vec<String> = paths
+                        .iter()
+                        .filter_map(|path| path.file_name())
+                        .map(|name| name.to_string_lossy().into_owned())
+                        .collect();
+                    format!("出力しました: {}{}", files.join(", "), pending.summary)
+                });
+            let _ = result_tx.send(result);
+        });
+    }
+}
+
+impl Default for ProtectedExport {
+    fn default() -> Self {
+        Self::new()
+    }
+}
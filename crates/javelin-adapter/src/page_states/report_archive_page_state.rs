@@ -0,0 +1,215 @@
+// ReportArchivePageState - アーカイブ済み帳票の一覧画面の状態
+// 責務: 一覧の読込、帳票を開いた際のハッシュ検証、再生成した数値との比較
+
+use std::sync::Arc;
+
+use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use javelin_application::dtos::{
+    GenerateFinancialStatementsRequest,
+    response::{
+        ArchivedReportResponse, ArchivedReportSummary, FINANCIAL_STATEMENTS_REPORT_TYPE,
+        ReportComparisonResponse,
+    },
+};
+use ratatui::DefaultTerminal;
+use tokio::sync::mpsc;
+
+use crate::{
+    error::AdapterResult,
+    error_log::to_user_message,
+    navigation::{Controllers, NavAction, PageState, Route},
+    views::{layouts::render_guarded, pages::ReportArchivePage},
+};
+
+/// 読込・検証・比較の結果
+enum ArchiveUpdate {
+    Listed(Vec<ArchivedReportSummary>),
+    ListFailed(String),
+    Opened(ArchivedReportResponse),
+    Compared(ReportComparisonResponse),
+    Failed(String),
+}
+
+pub struct ReportArchivePageState {
+    page: ReportArchivePage,
+    loading: bool,
+    update_tx: mpsc::UnboundedSender<ArchiveUpdate>,
+    update_rx: mpsc::UnboundedReceiver<ArchiveUpdate>,
+    /// データロード済みフラグ
+    data_loaded: bool,
+}
+
+impl ReportArchivePageState {
+    pub fn new() -> Self {
+        let (update_tx, update_rx) = mpsc::unbounded_channel();
+        Self {
+            page: ReportArchivePage::new(),
+            loading: false,
+            update_tx,
+            update_rx,
+            data_loaded: false,
+        }
+    }
+
+    /// 一覧を読込
+    fn load_list(&mut self, controllers: &Controllers) {
+        if self.loading {
+            return;
+        }
+        self.loading = true;
+        self.page.start_loading();
+
+        let controller = Arc::clone(&controllers.report_archive);
+        let update_tx = self.update_tx.clone();
+
+        tokio::spawn(async move {
+            let update = match controller.list().await {
+                Ok(summaries) => ArchiveUpdate::Listed(summaries),
+                Err(e) => ArchiveUpdate::ListFailed(e),
+            };
+            let _ = update_tx.send(update);
+        });
+    }
+
+    /// 選択中の帳票を開き、ハッシュ値を検証
+    fn open_selected(&mut self, controllers: &Controllers) {
+        if self.loading {
+            return;
+        }
+        let Some(summary) = self.page.selected() else {
+            return;
+        };
+        self.loading = true;
+
+        let controller = Arc::clone(&controllers.report_archive);
+        let update_tx = self.update_tx.clone();
+        let archive_id = summary.archive_id.clone();
+
+        tokio::spawn(async move {
+            let update = match controller.open(archive_id).await {
+                Ok(report) => ArchiveUpdate::Opened(report),
+                Err(e) => ArchiveUpdate::Failed(e),
+            };
+            let _ = update_tx.send(update);
+        });
+    }
+
+    /// 同じ会計期間の財務諸表を再生成し、承認時点の数値と比較
+    fn compare_selected(&mut self, controllers: &Controllers) {
+        if self.loading {
+            return;
+        }
+        let Some(summary) = self.page.selected().cloned() else {
+            return;
+        };
+        if summary.report_type != FINANCIAL_STATEMENTS_REPORT_TYPE {
+            self.page.add_error(format!(
+                "{} は再生成による比較に対応していません",
+                summary.report_label
+            ));
+            return;
+        }
+        self.loading = true;
+        self.page.add_info(format!(
+            "{}-{:02} の財務諸表を再生成しています...",
+            summary.fiscal_year, summary.period
+        ));
+
+        let closing = Arc::clone(&controllers.closing);
+        let controller = Arc::clone(&controllers.report_archive);
+        let update_tx = self.update_tx.clone();
+
+        tokio::spawn(async move {
+            let request = GenerateFinancialStatementsRequest {
+                fiscal_year: summary.fiscal_year,
+                period: summary.period,
+            };
+            let update = match closing.generate_financial_statements(request).await {
+                Ok(statements) => match controller
+                    .compare_financial_statements(summary.archive_id, &statements)
+                    .await
+                {
+                    Ok(comparison) => ArchiveUpdate::Compared(comparison),
+                    Err(e) => ArchiveUpdate::Failed(e),
+                },
+                Err(e) => ArchiveUpdate::Failed(to_user_message(e)),
+            };
+            let _ = update_tx.send(update);
+        });
+    }
+
+    /// 処理結果を反映
+    fn poll_updates(&mut self) {
+        while let Ok(update) = self.update_rx.try_recv() {
+            self.loading = false;
+            match update {
+                ArchiveUpdate::Listed(summaries) => self.page.set_summaries(summaries),
+                ArchiveUpdate::ListFailed(error) => self.page.set_error(error),
+                ArchiveUpdate::Opened(report) => self.page.set_report(&report),
+                ArchiveUpdate::Compared(comparison) => self.page.set_comparison(&comparison),
+                ArchiveUpdate::Failed(error) => self.page.add_error(error),
+            }
+        }
+    }
+}
+
+impl PageState for ReportArchivePageState {
+    fn route(&self) -> Route {
+        Route::ReportArchive
+    }
+
+    fn run(
+        &mut self,
+        terminal: &mut DefaultTerminal,
+        controllers: &Controllers,
+    ) -> AdapterResult<NavAction> {
+        if !self.data_loaded {
+            self.load_list(controllers);
+            self.data_loaded = true;
+        }
+
+        loop {
+            self.poll_updates();
+            self.page.tick();
+
+            terminal
+                .draw(|frame| {
+                    render_guarded(frame, |frame| self.page.render(frame));
+                })
+                .map_err(|e| crate::error::AdapterError::RenderingFailed(e.to_string()))?;
+
+            if controllers.session.lock_if_idle() {
+                return Ok(NavAction::Go(Route::Login));
+            }
+
+            if controllers.session.poll_input(std::time::Duration::from_millis(100))?
+                && let Event::Key(key) =
+                    event::read().map_err(crate::error::AdapterError::EventReadFailed)?
+            {
+                if key.kind != KeyEventKind::Press {
+                    continue;
+                }
+
+                match key.code {
+                    KeyCode::Esc => return Ok(NavAction::Back),
+                    KeyCode::Char('j') | KeyCode::Down => self.page.select_next(),
+                    KeyCode::Char('k') | KeyCode::Up => self.page.select_previous(),
+                    KeyCode::Enter => self.open_selected(controllers),
+                    KeyCode::Char('c') => self.compare_selected(controllers),
+                    KeyCode::Char('r') => self.load_list(controllers),
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    fn on_navigation_error(&mut self, error_message: &str) {
+        self.page.add_error(error_message);
+    }
+}
+
+impl Default for ReportArchivePageState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,292 @@
+// ReportHubPageState - 帳票メニュー画面の状態
+// 責務: 帳票の選択、実行条件の入力・検証、最近使った条件の読込と記録、帳票画面への遷移
+
+use std::{collections::BTreeMap, path::Path, sync::Arc, time::Instant};
+
+use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use ratatui::DefaultTerminal;
+use tokio::sync::mpsc;
+
+use crate::{
+    error::AdapterResult,
+    navigation::{Controllers, NavAction, PageState, Route},
+    page_states::report_parameters::{self, PARAM_FISCAL_YEAR, StandardReport},
+    views::{
+        components::{ListItemData, ParameterForm, ParameterKind, TOGGLE_ON},
+        layouts::render_guarded,
+        pages::ReportHubPage,
+    },
+};
+
+/// 非同期処理の結果
+enum HubUpdate {
+    /// 帳票IDと最近使った条件
+    Recent(&'static str, Result<Vec<BTreeMap<String, String>>, String>),
+    Exported(Result<String, String>),
+}
+
+pub struct ReportHubPageState {
+    page: ReportHubPage,
+    /// 選択中の帳票の最近使った条件（新しい順）
+    recent: Vec<BTreeMap<String, String>>,
+    update_tx: mpsc::UnboundedSender<HubUpdate>,
+    update_rx: mpsc::UnboundedReceiver<HubUpdate>,
+}
+
+impl ReportHubPageState {
+    pub fn new() -> Self {
+        let items = StandardReport::ALL
+            .iter()
+            .map(|report| ListItemData::new(report.code(), report.name(), report.description()))
+            .collect();
+        let (update_tx, update_rx) = mpsc::unbounded_channel();
+        Self {
+            page: ReportHubPage::new(items, Self::form_for(StandardReport::ALL[0])),
+            recent: Vec::new(),
+            update_tx,
+            update_rx,
+        }
+    }
+
+    fn form_for(report: StandardReport) -> ParameterForm {
+        ParameterForm::new(
+            format!("◇ {} の条件 ◇", report.name()),
+            report.parameters(),
+            &report.defaults(crate::clock::business_date()),
+        )
+        .with_calendar(crate::clock::fiscal_calendar())
+    }
+
+    fn selected_report(&self) -> StandardReport {
+        self.page
+            .selected_index()
+            .and_then(|index| StandardReport::ALL.get(index).copied())
+            .unwrap_or(StandardReport::ALL[0])
+    }
+
+    /// 選択した帳票の条件フォームと最近使った条件に切り替える
+    fn on_selection_changed(&mut self, controllers: &Controllers) {
+        let report = self.selected_report();
+        self.page.set_form(Self::form_for(report));
+        self.recent.clear();
+        self.page.set_recent(Vec::new());
+        self.load_recent(controllers, report);
+    }
+
+    /// 最近使った条件を読込
+    fn load_recent(&self, controllers: &Controllers, report: StandardReport) {
+        let controller = Arc::clone(&controllers.report_parameter_history);
+        let update_tx = self.update_tx.clone();
+        let user_id = controllers.session.user_id();
+
+        tokio::spawn(async move {
+            let result =
+                controller.recent(&user_id, report.id()).await.map(|response| response.recent);
+            let _ = update_tx.send(HubUpdate::Recent(report.id(), result));
+        });
+    }
+
+    /// 最近使った条件をフォームへ反映
+    fn recall(&mut self, index: usize) {
+        if let Some(values) = self.recent.get(index).cloned() {
+            self.page.form_mut().apply(&values);
+            self.page.add_info(format!("最近使った条件 [{}] を反映しました", index + 1));
+        }
+    }
+
+    /// 条件を検証して帳票を実行
+    ///
+    /// 条件を記録してから、画面で表示する帳票は条件を渡して遷移し、
+    /// 出力のみの帳票はこの画面で実行する。
+    fn run_report(&mut self, controllers: &Controllers) -> Option<Route> {
+        let report = self.selected_report();
+        let values = match self.page.form_mut().validate() {
+            Ok(values) => values,
+            Err(e) => {
+                self.page.add_error(e);
+                return None;
+            }
+        };
+
+        let controller = Arc::clone(&controllers.report_parameter_history);
+        let user_id = controllers.session.user_id();
+        let remembered = values.clone();
+        tokio::spawn(async move {
+            // 記録に失敗しても帳票の実行は続ける
+            let _ = controller.remember(user_id, report.id().to_string(), remembered).await;
+        });
+
+        match report.route() {
+            Some(route) => {
+                report_parameters::hand_off(report, values);
+                Some(route)
+            }
+            None => {
+                self.export_audit_books(controllers, &values);
+                None
+            }
+        }
+    }
+
+    /// 指定年度の監査用帳簿をカレントディレクトリへ出力
+    fn export_audit_books(&mut self, controllers: &Controllers, values: &BTreeMap<String, String>) {
+        let Some(fiscal_year) = values.get(PARAM_FISCAL_YEAR).and_then(|v| v.parse::<u32>().ok())
+        else {
+            return;
+        };
+        self.page
+            .add_info(format!("{}年度の監査用帳簿を出力しています...", fiscal_year));
+
+        let controller = Arc::clone(&controllers.audit_export);
+        let recorder = Arc::clone(&controllers.record_user_action);
+        let exported_by = controllers.session.user_id();
+        let update_tx = self.update_tx.clone();
+
+        tokio::spawn(async move {
+            let started = Instant::now();
+            let result = controller.export(fiscal_year, exported_by.clone(), Path::new(".")).await;
+            let _ = recorder
+                .record_action(exported_by, "ReportHub", "audit_export", started.elapsed())
+                .await;
+            let result = result.map(|(directory, response)| {
+                format!(
+                    "監査用帳簿を出力しました: {} (仕訳 {}件)",
+                    directory.display(),
+                    response.entry_count
+                )
+            });
+            let _ = update_tx.send(HubUpdate::Exported(result));
+        });
+    }
+
+    /// 条件を表示用の文字列にする
+    fn describe(report: StandardReport, values: &BTreeMap<String, String>) -> String {
+        report
+            .parameters()
+            .iter()
+            .filter_map(|spec| {
+                let value = values.get(spec.name)?;
+                Some(match spec.kind {
+                    ParameterKind::Toggle if value == TOGGLE_ON => spec.label.to_string(),
+                    ParameterKind::Toggle => return None,
+                    _ => format!("{} {}", spec.label, value),
+                })
+            })
+            .collect::<Vec<_>>()
+            .join(" / ")
+    }
+
+    /// 処理結果を反映
+    fn poll_updates(&mut self) {
+        while let Ok(update) = self.update_rx.try_recv() {
+            match update {
+                HubUpdate::Recent(report_id, result) => {
+                    // 選択を切り替えた後に届いた結果は捨てる
+                    let report = self.selected_report();
+                    if report.id() != report_id {
+                        continue;
+                    }
+                    match result {
+                        Ok(recent) => {
+                            let labels = recent
+                                .iter()
+                                .map(|values| Self::describe(report, values))
+                                .collect();
+                            self.page.set_recent(labels);
+                            self.recent = recent;
+                        }
+                        Err(e) => self.page.set_recent_error(e),
+                    }
+                }
+                HubUpdate::Exported(Ok(message)) => self.page.add_info(message),
+                HubUpdate::Exported(Err(e)) => {
+                    self.page.add_error(format!("監査用帳簿の出力に失敗しました: {}", e))
+                }
+            }
+        }
+    }
+}
+
+impl PageState for ReportHubPageState {
+    fn route(&self) -> Route {
+        Route::ReportHub
+    }
+
+    fn run(
+        &mut self,
+        terminal: &mut DefaultTerminal,
+        controllers: &Controllers,
+    ) -> AdapterResult<NavAction> {
+        // 帳票画面から戻るたびに、記録した条件を反映するため読み直す
+        self.load_recent(controllers, self.selected_report());
+
+        loop {
+            self.poll_updates();
+            self.page.tick();
+
+            terminal
+                .draw(|frame| {
+                    render_guarded(frame, |frame| self.page.render(frame));
+                })
+                .map_err(|e| crate::error::AdapterError::RenderingFailed(e.to_string()))?;
+
+            if controllers.session.lock_if_idle() {
+                return Ok(NavAction::Go(Route::Login));
+            }
+
+            if controllers.session.poll_input(std::time::Duration::from_millis(100))?
+                && let Event::Key(key) =
+                    event::read().map_err(crate::error::AdapterError::EventReadFailed)?
+            {
+                if key.kind != KeyEventKind::Press {
+                    continue;
+                }
+
+                if self.page.is_editing() {
+                    match key.code {
+                        KeyCode::Esc => self.page.set_editing(false),
+                        KeyCode::Enter => {
+                            if let Some(route) = self.run_report(controllers) {
+                                self.page.set_editing(false);
+                                return Ok(NavAction::Go(route));
+                            }
+                        }
+                        KeyCode::Tab | KeyCode::Down => self.page.form_mut().focus_next(),
+                        KeyCode::BackTab | KeyCode::Up => self.page.form_mut().focus_prev(),
+                        KeyCode::Backspace => self.page.form_mut().delete_char(),
+                        KeyCode::Char(c) => self.page.form_mut().input_char(c),
+                        _ => {}
+                    }
+                    continue;
+                }
+
+                match key.code {
+                    KeyCode::Esc => return Ok(NavAction::Back),
+                    KeyCode::Char('j') | KeyCode::Down => {
+                        self.page.select_next();
+                        self.on_selection_changed(controllers);
+                    }
+                    KeyCode::Char('k') | KeyCode::Up => {
+                        self.page.select_previous();
+                        self.on_selection_changed(controllers);
+                    }
+                    KeyCode::Enter | KeyCode::Tab => self.page.set_editing(true),
+                    KeyCode::Char(c @ '1'..='5') => {
+                        self.recall(c as usize - '1' as usize);
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    fn on_navigation_error(&mut self, error_message: &str) {
+        self.page.add_error(error_message);
+    }
+}
+
+impl Default for ReportHubPageState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
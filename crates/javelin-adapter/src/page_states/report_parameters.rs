@@ -0,0 +1,198 @@
+// ReportParameters - 帳票メニューの帳票定義と実行条件の受け渡し
+// 責務: 条件付きで実行する定型帳票の一覧、条件項目と既定値、遷移先画面への条件の受け渡し
+
+use std::{
+    collections::BTreeMap,
+    sync::{Arc, Mutex},
+};
+
+use chrono::{Datelike, NaiveDate};
+
+use crate::{
+    navigation::Route,
+    views::components::{ParameterKind, ParameterSpec, TOGGLE_OFF, TOGGLE_ON},
+};
+
+/// 条件名: 会計期間（YYYY-MM）
+pub const PARAM_PERIOD: &str = "period";
+/// 条件名: 基準日（YYYY-MM-DD）
+pub const PARAM_AS_OF_DATE: &str = "as_of_date";
+/// 条件名: 会計年度（YYYY）
+pub const PARAM_FISCAL_YEAR: &str = "fiscal_year";
+/// 条件名: 承認待ちの仕訳を含めるか
+pub const PARAM_INCLUDE_PENDING: &str = "include_pending";
+
+/// 帳票メニューから遷移先画面へ渡す実行条件
+type HandedOffParameters = Option<(StandardReport, BTreeMap<String, String>)>;
+
+lazy_static::lazy_static! {
+    static ref HANDED_OFF_PARAMETERS: Arc<Mutex<HandedOffParameters>> = Arc::new(Mutex::new(None));
+}
+
+/// 帳票メニューに表示する定型帳票
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StandardReport {
+    /// 試算表
+    TrialBalance,
+    /// 勘定残高の異常値レビュー
+    BalanceAnalysis,
+    /// 勘定科目別の記帳量ヒートマップ
+    AccountActivity,
+    /// 仮勘定の滞留状況
+    SuspenseAging,
+    /// 監査用帳簿の出力
+    AuditExport,
+}
+
+impl StandardReport {
+    /// メニューの表示順
+    pub const ALL: [StandardReport; 5] = [
+        StandardReport::TrialBalance,
+        StandardReport::BalanceAnalysis,
+        StandardReport::AccountActivity,
+        StandardReport::SuspenseAging,
+        StandardReport::AuditExport,
+    ];
+
+    /// 最近使った条件の保存に使う帳票ID
+    pub fn id(&self) -> &'static str {
+        match self {
+            StandardReport::TrialBalance => "trial_balance",
+            StandardReport::BalanceAnalysis => "balance_analysis",
+            StandardReport::AccountActivity => "account_activity",
+            StandardReport::SuspenseAging => "suspense_aging",
+            StandardReport::AuditExport => "audit_export",
+        }
+    }
+
+    /// 遷移先画面の番号
+    pub fn code(&self) -> &'static str {
+        match self {
+            StandardReport::TrialBalance => "303",
+            StandardReport::BalanceAnalysis => "301A",
+            StandardReport::AccountActivity => "301H",
+            StandardReport::SuspenseAging => "305",
+            StandardReport::AuditExport => "AUD",
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            StandardReport::TrialBalance => "試算表",
+            StandardReport::BalanceAnalysis => "残高異常値レビュー",
+            StandardReport::AccountActivity => "記帳量ヒートマップ",
+            StandardReport::SuspenseAging => "仮勘定滞留状況",
+            StandardReport::AuditExport => "監査用帳簿出力",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            StandardReport::TrialBalance => "指定月の試算表（承認待ちを含む試算も可）",
+            StandardReport::BalanceAnalysis => "指定月と前月・前年同月の残高比較",
+            StandardReport::AccountActivity => "指定月までの12か月の科目別記帳件数・金額と急増月",
+            StandardReport::SuspenseAging => "基準日時点の仮払金・仮受金の滞留日数",
+            StandardReport::AuditExport => {
+                "指定年度の仕訳帳・総勘定元帳・索引をカレントディレクトリへ出力"
+            }
+        }
+    }
+
+    /// 条件項目
+    pub fn parameters(&self) -> Vec<ParameterSpec> {
+        match self {
+            StandardReport::TrialBalance => vec![
+                ParameterSpec::new(PARAM_PERIOD, "対象月", ParameterKind::Period),
+                ParameterSpec::new(PARAM_INCLUDE_PENDING, "承認待ちを含む", ParameterKind::Toggle),
+            ],
+            StandardReport::BalanceAnalysis | StandardReport::AccountActivity => {
+                vec![ParameterSpec::new(PARAM_PERIOD, "対象月", ParameterKind::Period)]
+            }
+            StandardReport::SuspenseAging => {
+                vec![ParameterSpec::new(PARAM_AS_OF_DATE, "基準日", ParameterKind::Date)]
+            }
+            StandardReport::AuditExport => {
+                vec![ParameterSpec::new(PARAM_FISCAL_YEAR, "会計年度", ParameterKind::Year)]
+            }
+        }
+    }
+
+    /// 業務日付を基準にした条件の既定値
+    pub fn defaults(&self, today: NaiveDate) -> BTreeMap<String, String> {
+        self.parameters()
+            .iter()
+            .map(|spec| {
+                let value = match spec.kind {
+                    ParameterKind::Period => today.format("%Y-%m").to_string(),
+                    ParameterKind::Date => today.format("%Y-%m-%d").to_string(),
+                    ParameterKind::Year => today.year().to_string(),
+                    ParameterKind::Toggle => TOGGLE_OFF.to_string(),
+                };
+                (spec.name.to_string(), value)
+            })
+            .collect()
+    }
+
+    /// 遷移先画面（帳票メニュー内で実行する帳票はNone）
+    pub fn route(&self) -> Option<Route> {
+        match self {
+            StandardReport::TrialBalance => Some(Route::TrialBalance),
+            StandardReport::BalanceAnalysis => Some(Route::BalanceAnalysis),
+            StandardReport::AccountActivity => Some(Route::AccountActivity),
+            StandardReport::SuspenseAging => Some(Route::AccountAdjustment),
+            StandardReport::AuditExport => None,
+        }
+    }
+}
+
+/// 遷移先画面へ実行条件を渡す
+pub fn hand_off(report: StandardReport, values: BTreeMap<String, String>) {
+    if let Ok(mut guard) = HANDED_OFF_PARAMETERS.lock() {
+        *guard = Some((report, values));
+    }
+}
+
+/// 帳票メニューから渡された実行条件を受け取る
+///
+/// 別の帳票向けの条件は受け取らずに破棄する（メニューを経由しない遷移で
+/// 古い条件が使われないようにするため）。
+pub fn take_handed_off(report: StandardReport) -> Option<BTreeMap<String, String>> {
+    let (handed_off, values) = HANDED_OFF_PARAMETERS.lock().ok()?.take()?;
+    (handed_off == report).then_some(values)
+}
+
+/// 会計期間（YYYY-MM）を年・月に分解
+pub fn parse_period(values: &BTreeMap<String, String>) -> Option<(i32, u8)> {
+    let date =
+        NaiveDate::parse_from_str(&format!("{}-01", values.get(PARAM_PERIOD)?), "%Y-%m-%d").ok()?;
+    Some((date.year(), date.month() as u8))
+}
+
+/// 切替項目がオンか
+pub fn is_enabled(values: &BTreeMap<String, String>, name: &str) -> bool {
+    values.get(name).is_some_and(|value| value == TOGGLE_ON)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_defaults_cover_parameters_and_hand_off_is_per_report() {
+        let today = NaiveDate::from_ymd_opt(2024, 3, 15).unwrap();
+        for report in StandardReport::ALL {
+            let defaults = report.defaults(today);
+            assert_eq!(defaults.len(), report.parameters().len());
+        }
+        let defaults = StandardReport::TrialBalance.defaults(today);
+        assert_eq!(parse_period(&defaults), Some((2024, 3)));
+        assert!(!is_enabled(&defaults, PARAM_INCLUDE_PENDING));
+
+        hand_off(StandardReport::BalanceAnalysis, defaults.clone());
+        assert_eq!(take_handed_off(StandardReport::TrialBalance), None);
+        assert_eq!(take_handed_off(StandardReport::BalanceAnalysis), None);
+
+        hand_off(StandardReport::TrialBalance, defaults.clone());
+        assert_eq!(take_handed_off(StandardReport::TrialBalance), Some(defaults));
+    }
+}
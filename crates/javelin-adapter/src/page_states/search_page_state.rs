@@ -3,14 +3,19 @@
 
 use std::sync::Arc;
 
+use javelin_application::{dtos::request::SearchCriteriaDto, output_port::SearchOutputPort};
 use ratatui::DefaultTerminal;
 use uuid::Uuid;
 
 use crate::{
     error::AdapterResult,
     navigation::{Controllers, NavAction, PageState, PresenterRegistry, Route},
-    presenter::{AccountMasterPresenter, SearchPresenter},
-    views::pages::SearchPage,
+    page_states::{ProtectedExport, TablePreferenceSync},
+    presenter::{
+        AccountMasterPresenter, CalendarMasterPresenter, PROGRESS_CHANNEL_CAPACITY,
+        SearchPresenter, progress_channel, warm_up_message,
+    },
+    views::{components::InputField, layouts::render_guarded, pages::SearchPage},
 };
 
 /// Search page state with owned channels
@@ -21,17 +26,32 @@ pub struct SearchPageState {
     registry: Arc<PresenterRegistry>,
     /// The search page view
     page: SearchPage,
+    /// Search presenter for this page (検索前に利用者の金額マスキングを設定する)
+    presenter: Arc<SearchPresenter>,
     /// Account master presenter for this page
     #[allow(dead_code)]
     account_master_presenter: Arc<AccountMasterPresenter>,
+    /// Calendar master presenter for the date picker
+    #[allow(dead_code)]
+    calendar_master_presenter: Arc<CalendarMasterPresenter>,
+    /// Whether the calendar master load has been requested
+    calendar_loaded: bool,
+    /// 検索結果テーブルの表示設定
+    table_preference: TablePreferenceSync,
+    /// 出力ファイルの保護付き書き出し
+    export: ProtectedExport,
+    /// 最後に実行した検索条件（仕訳一覧の更新時に再検索する）
+    last_criteria: Option<SearchCriteriaDto>,
 }
 
 impl SearchPageState {
     /// Create a new SearchPageState with its own channels
     ///
     /// This method:
-    /// 1. Creates 5 channels (result, error, progress, execution_time, account_master)
-    /// 2. Creates SearchPresenter and AccountMasterPresenter with channel senders
+    /// 1. Creates 6 channels (result, error, progress, execution_time, account_master,
+    ///    calendar_master)
+    /// 2. Creates SearchPresenter, AccountMasterPresenter and CalendarMasterPresenter with channel
+    ///    senders
     /// 3. Registers presenters in PresenterRegistry with unique ID
     /// 4. Creates SearchPage with channel receivers
     ///
@@ -49,11 +69,12 @@ impl SearchPageState {
         // Create 4 channels for search communication
         let (result_tx, result_rx) = tokio::sync::mpsc::channel(100);
         let (error_tx, error_rx) = tokio::sync::mpsc::channel(100);
-        let (progress_tx, progress_rx) = tokio::sync::mpsc::channel(100);
+        let (progress_tx, progress_rx) = progress_channel(PROGRESS_CHANNEL_CAPACITY);
         let (execution_time_tx, execution_time_rx) = tokio::sync::mpsc::channel(100);
 
         // Create channel for account master (unbounded to match presenter)
         let (account_master_tx, account_master_rx) = tokio::sync::mpsc::unbounded_channel();
+        let (calendar_master_tx, calendar_master_rx) = CalendarMasterPresenter::create_channel();
 
         // Create SearchPresenter with channel senders
         let presenter =
@@ -62,15 +83,78 @@ impl SearchPageState {
         // Create AccountMasterPresenter with channel sender
         let account_master_presenter = Arc::new(AccountMasterPresenter::new(account_master_tx));
 
+        // Create CalendarMasterPresenter with channel sender
+        let calendar_master_presenter = Arc::new(CalendarMasterPresenter::new(calendar_master_tx));
+
         // Register presenters in PresenterRegistry with unique ID
-        registry.register_search_presenter(id, presenter);
+        registry.register_search_presenter(id, Arc::clone(&presenter));
         registry.register_account_master_presenter(id, Arc::clone(&account_master_presenter));
+        registry.register_calendar_master_presenter(id, Arc::clone(&calendar_master_presenter));
 
         // Create SearchPage with channel receivers
         let mut page = SearchPage::new(result_rx, error_rx, progress_rx, execution_time_rx);
         page.set_account_master_receiver(account_master_rx);
+        page.set_calendar_master_receiver(calendar_master_rx);
+
+        Self {
+            id,
+            registry,
+            page,
+            presenter,
+            account_master_presenter,
+            calendar_master_presenter,
+            calendar_loaded: false,
+            table_preference: TablePreferenceSync::new("search_results"),
+            export: ProtectedExport::new(),
+            last_criteria: None,
+        }
+    }
+
+    /// 検索を実行
+    ///
+    /// 金額マスキングを取得してから検索する（取得できない場合は金額を表示しないよう検索しない）。
+    fn execute_search(&mut self, controllers: &Controllers, criteria: SearchCriteriaDto) {
+        self.last_criteria = Some(criteria.clone());
+        let page_id = self.id;
+        let controller = Arc::clone(&controllers.search);
+        let policy_controller = Arc::clone(&controllers.accounting_policy);
+        let user_id = controllers.session.user_id();
+        let presenter = Arc::clone(&self.presenter);
+
+        tokio::spawn(async move {
+            match policy_controller.amount_mask(&user_id).await {
+                Ok(amount_mask) => presenter.set_amount_mask(amount_mask),
+                Err(e) => {
+                    presenter.present_validation_error(format!(
+                        "金額マスキングの取得に失敗しました: {}",
+                        e
+                    ));
+                    return;
+                }
+            }
+            let _ = controller.handle_search(page_id, criteria).await;
+        });
+    }
+
+    /// 前回の検索条件で再検索（復元した結果を最新にする）
+    fn refresh(&mut self, controllers: &Controllers) {
+        match self.last_criteria.clone() {
+            Some(criteria) => self.execute_search(controllers, criteria),
+            None => self.page.add_info("再検索する検索条件がありません"),
+        }
+    }
 
-        Self { id, registry, page, account_master_presenter }
+    /// 入力されたファイル名で表示中の検索結果を出力
+    fn confirm_export(&mut self, controllers: &Controllers) {
+        let Some(file_name) = self.page.export_prompt_mut().file_name() else {
+            return;
+        };
+        let format = self.page.export_prompt_mut().format();
+        self.page.export_prompt_mut().hide();
+
+        let (content, count) = self.page.export_content(format);
+        self.export
+            .export(controllers, file_name, content.into_bytes(), format!(" ({}件)", count));
     }
 }
 
@@ -79,6 +163,14 @@ impl PageState for SearchPageState {
         Route::Search
     }
 
+    fn preserves_state(&self) -> bool {
+        true
+    }
+
+    fn on_restore(&mut self) {
+        self.page.add_info("前回の検索条件と結果を復元しました（[F5] 再検索）");
+    }
+
     fn run(
         &mut self,
         terminal: &mut DefaultTerminal,
@@ -86,7 +178,34 @@ impl PageState for SearchPageState {
     ) -> AdapterResult<NavAction> {
         use crossterm::event::{self, Event, KeyCode, KeyEventKind};
 
+        // カレンダーマスターを初回のみ読み込み
+        if !self.calendar_loaded {
+            self.calendar_loaded = true;
+
+            let controller = Arc::clone(&controllers.calendar_master);
+            let page_id = self.id;
+
+            tokio::spawn(async move {
+                use javelin_application::dtos::request::LoadCalendarMasterRequest;
+
+                let request = LoadCalendarMasterRequest { year: None };
+
+                let _ = controller.handle_load_calendar_master(page_id, request).await;
+            });
+        }
+
+        self.table_preference.request_load(controllers);
+        self.export.request_settings(controllers);
+        let mut projection_changes = controllers.projection_events.subscribe();
+
         loop {
+            // 仕訳一覧が更新されたら、表示中の検索結果を同じ条件で再検索
+            if projection_changes.poll().journal_list_changed()
+                && let Some(criteria) = self.last_criteria.clone()
+            {
+                self.execute_search(controllers, criteria);
+            }
+
             // 科目マスター読み込み待機中の場合、読み込みを開始
             if self.page.is_pending_account_load() {
                 self.page.clear_pending_account_load();
@@ -105,6 +224,21 @@ impl PageState for SearchPageState {
 
             // Update page state (check for async messages)
             self.page.update();
+            self.table_preference.apply_loaded(self.page.result_table_mut());
+            for result in self.export.poll() {
+                match result {
+                    Ok(message) => self.page.add_info(message),
+                    Err(e) => self.page.add_error(e),
+                }
+            }
+
+            // 検索Projectionの構築中は構築進捗を表示
+            if let Some(message) =
+                warm_up_message("仕訳検索データ", &controllers.search.warm_up_progress())
+                && self.page.result_table_mut().is_loading()
+            {
+                self.page.result_table_mut().set_loading_progress(message);
+            }
 
             // Tick animation
             self.page.tick();
@@ -112,13 +246,19 @@ impl PageState for SearchPageState {
             // Render the page
             terminal
                 .draw(|frame| {
-                    self.page.render(frame);
+                    render_guarded(frame, |frame| {
+                        self.page.render(frame);
+                        self.export.render(frame);
+                    });
                 })
                 .map_err(|e| crate::error::AdapterError::RenderingFailed(e.to_string()))?;
 
+            if controllers.session.lock_if_idle() {
+                return Ok(NavAction::Go(Route::Login));
+            }
+
             // Handle events with timeout for animation updates
-            if event::poll(std::time::Duration::from_millis(100))
-                .map_err(crate::error::AdapterError::EventReadFailed)?
+            if controllers.session.poll_input(std::time::Duration::from_millis(100))?
                 && let Event::Key(key) =
                     event::read().map_err(crate::error::AdapterError::EventReadFailed)?
             {
@@ -126,11 +266,38 @@ impl PageState for SearchPageState {
                     continue;
                 }
 
+                // 暗号化パスワード入力中
+                if self.export.handle_key(key.code, controllers) {
+                    continue;
+                }
+
+                // 出力ファイル名入力中
+                if self.page.is_export_prompt_visible() {
+                    match key.code {
+                        KeyCode::Esc => self.page.export_prompt_mut().hide(),
+                        KeyCode::Enter => self.confirm_export(controllers),
+                        KeyCode::Tab => self.page.export_prompt_mut().toggle_format(),
+                        KeyCode::Backspace => self.page.export_prompt_mut().delete_char(),
+                        KeyCode::Char(c) => self.page.export_prompt_mut().input_char(c),
+                        _ => {}
+                    }
+                    continue;
+                }
+
+                // フィールドヘルプ表示中
+                if self.page.is_field_help_visible() {
+                    if matches!(key.code, KeyCode::Esc | KeyCode::Char('?')) {
+                        self.page.close_field_help();
+                    }
+                    continue;
+                }
+
                 match self.page.input_mode() {
                     crate::input_mode::InputMode::Normal => {
                         match key.code {
                             KeyCode::Esc => {
-                                // Navigate back to home
+                                // 実行中の検索をキャンセルして戻る
+                                controllers.search.cancel_search(self.id);
                                 return Ok(NavAction::Back);
                             }
                             KeyCode::Char('i') => {
@@ -163,20 +330,94 @@ impl PageState for SearchPageState {
                                 self.page.toggle_focus_area();
                             }
                             KeyCode::Enter => {
+                                // 日付の前後関係などに誤りがある間は検索しない
+                                if self.page.has_field_errors() {
+                                    continue;
+                                }
                                 // Execute search
                                 let criteria = self.page.to_search_criteria_dto();
-                                let page_id = self.id;
-                                let controller = Arc::clone(&controllers.search);
-
-                                // Spawn async task to execute search
-                                tokio::spawn(async move {
-                                    let _ = controller.handle_search(page_id, criteria).await;
-                                });
+                                self.execute_search(controllers, criteria);
+                            }
+                            KeyCode::F(5) => {
+                                self.refresh(controllers);
                             }
                             KeyCode::Char('c') => {
                                 // Clear search criteria
                                 self.page.clear_criteria();
                             }
+                            KeyCode::Char('f') => {
+                                // 注記付きのみの絞り込みを切り替えて再検索
+                                self.page.toggle_flagged_only();
+                                let criteria = self.page.to_search_criteria_dto();
+                                self.execute_search(controllers, criteria);
+                            }
+                            KeyCode::Char('d') => {
+                                // 削除済みの仕訳の表示を切り替えて再検索
+                                self.page.toggle_include_deleted();
+                                let criteria = self.page.to_search_criteria_dto();
+                                self.execute_search(controllers, criteria);
+                            }
+                            KeyCode::Char('g') => {
+                                // グループ化を切り替えて再検索（小計は検索時に集計する）
+                                self.page.cycle_grouping();
+                                let criteria = self.page.to_search_criteria_dto();
+                                self.execute_search(controllers, criteria);
+                            }
+                            KeyCode::Char(' ')
+                                if self.page.focus_area()
+                                    == crate::views::pages::search_page::FocusArea::Results =>
+                            {
+                                self.page.toggle_selected_group();
+                            }
+                            KeyCode::Char('?') => {
+                                // Show help for the focused field
+                                self.page.show_field_help();
+                            }
+                            KeyCode::Char('x')
+                                if self.page.focus_area()
+                                    == crate::views::pages::search_page::FocusArea::Results =>
+                            {
+                                self.page.open_export_prompt();
+                            }
+                            code if self.page.focus_area()
+                                == crate::views::pages::search_page::FocusArea::Results =>
+                            {
+                                self.table_preference.handle_key(
+                                    code,
+                                    self.page.result_table_mut(),
+                                    controllers,
+                                );
+                            }
+                            _ => {}
+                        }
+                    }
+                    crate::input_mode::InputMode::Modify if self.page.is_calendar_visible() => {
+                        // カレンダー選択中のキー操作
+                        match key.code {
+                            KeyCode::Esc => {
+                                self.page.calendar_cancel();
+                            }
+                            KeyCode::Char('h') | KeyCode::Left => {
+                                self.page.calendar_move_days(-1);
+                            }
+                            KeyCode::Char('l') | KeyCode::Right => {
+                                self.page.calendar_move_days(1);
+                            }
+                            KeyCode::Char('j') | KeyCode::Down => {
+                                self.page.calendar_move_days(7);
+                            }
+                            KeyCode::Char('k') | KeyCode::Up => {
+                                self.page.calendar_move_days(-7);
+                            }
+                            KeyCode::Char('H') => {
+                                self.page.calendar_move_months(-1);
+                            }
+                            KeyCode::Char('L') => {
+                                self.page.calendar_move_months(1);
+                            }
+                            KeyCode::Enter => {
+                                self.page.calendar_confirm();
+                            }
                             _ => {}
                         }
                     }
@@ -206,6 +447,21 @@ impl PageState for SearchPageState {
                             KeyCode::Backspace => {
                                 self.page.backspace();
                             }
+                            KeyCode::Delete => {
+                                self.page.delete();
+                            }
+                            KeyCode::Left => {
+                                self.page.move_cursor(InputField::move_cursor_left);
+                            }
+                            KeyCode::Right => {
+                                self.page.move_cursor(InputField::move_cursor_right);
+                            }
+                            KeyCode::Home => {
+                                self.page.move_cursor(InputField::move_cursor_home);
+                            }
+                            KeyCode::End => {
+                                self.page.move_cursor(InputField::move_cursor_end);
+                            }
                             KeyCode::Enter => {
                                 // オーバーレイが表示されている場合は選択を確定
                                 if self.page.is_overlay_visible() {
@@ -226,8 +482,10 @@ impl PageState for SearchPageState {
 
 impl Drop for SearchPageState {
     fn drop(&mut self) {
-        // Unregister presenter from registry when page is destroyed
+        // Unregister all presenters from registry when page is destroyed
         self.registry.unregister_search_presenter(self.id);
+        self.registry.unregister_account_master_presenter(self.id);
+        self.registry.unregister_calendar_master_presenter(self.id);
         // Channels are automatically cleaned up when dropped
     }
 }
@@ -252,9 +510,11 @@ mod tests {
 
         let state = SearchPageState::new(Arc::clone(&registry));
 
-        // Verify presenter was registered
-        assert_eq!(registry.total_count(), id_before + 1);
+        // Verify presenters were registered (Search + AccountMaster + CalendarMaster)
+        assert_eq!(registry.total_count(), id_before + 3);
         assert!(registry.get_search_presenter(state.id).is_some());
+        assert!(registry.get_account_master_presenter(state.id).is_some());
+        assert!(registry.get_calendar_master_presenter(state.id).is_some());
     }
 
     #[test]
@@ -266,14 +526,14 @@ mod tests {
             let state = SearchPageState::new(Arc::clone(&registry));
             let state_id = state.id;
 
-            // Verify presenter is registered
-            assert_eq!(registry.total_count(), id_before + 1);
+            // Verify presenters are registered
+            assert_eq!(registry.total_count(), id_before + 3);
             assert!(registry.get_search_presenter(state_id).is_some());
 
             // state goes out of scope here
         }
 
-        // Verify presenter was unregistered
+        // Verify all presenters were unregistered
         assert_eq!(registry.total_count(), id_before);
     }
 
@@ -291,7 +551,7 @@ mod tests {
         assert_ne!(state2.id, state3.id);
 
         // All three should be registered
-        assert_eq!(registry.total_count(), 3);
+        assert_eq!(registry.total_count(), 9);
         assert!(registry.get_search_presenter(state1.id).is_some());
         assert!(registry.get_search_presenter(state2.id).is_some());
         assert!(registry.get_search_presenter(state3.id).is_some());
@@ -313,7 +573,7 @@ mod tests {
 
         // Create a search page (simulating navigation to search)
         let state = SearchPageState::new(Arc::clone(&registry));
-        assert_eq!(registry.total_count(), 1);
+        assert_eq!(registry.total_count(), 3);
 
         // Drop the search page (simulating navigation away)
         drop(state);
@@ -339,18 +599,18 @@ mod tests {
         let state3 = SearchPageState::new(Arc::clone(&registry));
         let id3 = state3.id;
 
-        assert_eq!(registry.total_count(), 3);
+        assert_eq!(registry.total_count(), 9);
 
         // Drop state2 (simulating back navigation)
         drop(state2);
-        assert_eq!(registry.total_count(), 2);
+        assert_eq!(registry.total_count(), 6);
         assert!(registry.get_search_presenter(id1).is_some());
         assert!(registry.get_search_presenter(id2).is_none()); // Cleaned up
         assert!(registry.get_search_presenter(id3).is_some());
 
         // Drop state1
         drop(state1);
-        assert_eq!(registry.total_count(), 1);
+        assert_eq!(registry.total_count(), 3);
         assert!(registry.get_search_presenter(id1).is_none()); // Cleaned up
         assert!(registry.get_search_presenter(id3).is_some());
 
@@ -397,8 +657,8 @@ mod tests {
         // Create a search page
         let state = SearchPageState::new(Arc::clone(&registry));
 
-        // After creating the page, exactly one presenter should exist
-        assert_eq!(registry.total_count(), count_before + 1);
+        // After creating the page, exactly the page's presenters should exist
+        assert_eq!(registry.total_count(), count_before + 3);
 
         // The presenter should be retrievable by the page's ID
         assert!(registry.get_search_presenter(state.id).is_some());
@@ -406,8 +666,8 @@ mod tests {
         // Create another search page
         let state2 = SearchPageState::new(Arc::clone(&registry));
 
-        // Now two presenters should exist
-        assert_eq!(registry.total_count(), count_before + 2);
+        // Now both pages' presenters should exist
+        assert_eq!(registry.total_count(), count_before + 6);
 
         // Both presenters should be retrievable
         assert!(registry.get_search_presenter(state.id).is_some());
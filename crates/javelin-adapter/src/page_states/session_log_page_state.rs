@@ -0,0 +1,140 @@
+// SessionLogPageState - アクセスログ・セッション一覧画面の状態
+// 責務: セッション一覧の読み込みと強制ログアウト（管理者のみ）
+
+use std::sync::Arc;
+
+use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use javelin_application::dtos::response::SessionOverviewResponse;
+use ratatui::DefaultTerminal;
+use tokio::sync::mpsc;
+
+use crate::{
+    error::AdapterResult,
+    navigation::{Controllers, NavAction, PageState, Route},
+    views::{layouts::render_guarded, pages::SessionLogPage},
+};
+
+/// 読み込み・強制ログアウトの結果
+enum SessionLogUpdate {
+    Loaded(SessionOverviewResponse),
+    ForcedOut { session_id: String, overview: SessionOverviewResponse },
+    Failed(String),
+}
+
+pub struct SessionLogPageState {
+    page: SessionLogPage,
+    update_tx: mpsc::UnboundedSender<SessionLogUpdate>,
+    update_rx: mpsc::UnboundedReceiver<SessionLogUpdate>,
+    /// 一覧を読み込み済みか（画面表示時に一度だけ読み込む）
+    requested: bool,
+}
+
+impl SessionLogPageState {
+    pub fn new() -> Self {
+        let (update_tx, update_rx) = mpsc::unbounded_channel();
+        Self { page: SessionLogPage::new(), update_tx, update_rx, requested: false }
+    }
+
+    fn load(&mut self, controllers: &Controllers) {
+        self.requested = true;
+        self.page.set_loading();
+
+        let controller = Arc::clone(&controllers.session_log);
+        let user_id = controllers.session.user_id();
+        let update_tx = self.update_tx.clone();
+
+        tokio::spawn(async move {
+            let update = match controller.overview(user_id).await {
+                Ok(overview) => SessionLogUpdate::Loaded(overview),
+                Err(e) => SessionLogUpdate::Failed(e),
+            };
+            let _ = update_tx.send(update);
+        });
+    }
+
+    /// 選択中のセッションを強制ログアウト
+    fn force_logout(&mut self, controllers: &Controllers) {
+        let Some(session) = self.page.selected_session() else {
+            return;
+        };
+
+        let controller = Arc::clone(&controllers.session_log);
+        let user_id = controllers.session.user_id();
+        let session_id = session.session_id.clone();
+        let update_tx = self.update_tx.clone();
+        self.page.set_loading();
+
+        tokio::spawn(async move {
+            let update = match controller.force_logout(user_id, session_id.clone()).await {
+                Ok(overview) => SessionLogUpdate::ForcedOut { session_id, overview },
+                Err(e) => SessionLogUpdate::Failed(e),
+            };
+            let _ = update_tx.send(update);
+        });
+    }
+
+    fn poll_updates(&mut self) {
+        while let Ok(update) = self.update_rx.try_recv() {
+            match update {
+                SessionLogUpdate::Loaded(overview) => self.page.set_overview(overview),
+                SessionLogUpdate::ForcedOut { session_id, overview } => {
+                    self.page.set_overview(overview);
+                    self.page.set_info(format!("{} を強制ログアウトしました", session_id));
+                }
+                SessionLogUpdate::Failed(message) => self.page.set_error(message),
+            }
+        }
+    }
+}
+
+impl PageState for SessionLogPageState {
+    fn route(&self) -> Route {
+        Route::SessionLog
+    }
+
+    fn run(
+        &mut self,
+        terminal: &mut DefaultTerminal,
+        controllers: &Controllers,
+    ) -> AdapterResult<NavAction> {
+        if !self.requested {
+            self.load(controllers);
+        }
+
+        loop {
+            self.poll_updates();
+
+            terminal
+                .draw(|frame| render_guarded(frame, |frame| self.page.render(frame)))
+                .map_err(|e| crate::error::AdapterError::RenderingFailed(e.to_string()))?;
+
+            if controllers.session.lock_if_idle() {
+                return Ok(NavAction::Go(Route::Login));
+            }
+
+            if controllers.session.poll_input(std::time::Duration::from_millis(100))?
+                && let Event::Key(key) =
+                    event::read().map_err(crate::error::AdapterError::EventReadFailed)?
+            {
+                if key.kind != KeyEventKind::Press {
+                    continue;
+                }
+
+                match key.code {
+                    KeyCode::Esc => return Ok(NavAction::Back),
+                    KeyCode::Char('r') => self.load(controllers),
+                    KeyCode::Char('f') => self.force_logout(controllers),
+                    KeyCode::Up | KeyCode::Char('k') => self.page.select_previous(),
+                    KeyCode::Down | KeyCode::Char('j') => self.page.select_next(),
+                    _ => {}
+                }
+            }
+        }
+    }
+}
+
+impl Default for SessionLogPageState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,173 @@
+// SetupWizardPageState - 初期セットアップ画面の状態
+// 責務: 初回起動時のマスタ作成と管理者としてのセッション開始
+
+use std::sync::Arc;
+
+use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use javelin_application::dtos::{
+    request::InitialSetupRequest,
+    response::{InitialSetupResponse, PolicyOption},
+};
+use ratatui::DefaultTerminal;
+use tokio::sync::mpsc;
+
+use crate::{
+    error::AdapterResult,
+    navigation::{Controllers, NavAction, PageState, Route, SessionUser},
+    views::{
+        layouts::render_guarded,
+        pages::{SetupChoice, SetupStep, SetupWizardPage},
+    },
+};
+
+pub struct SetupWizardPageState {
+    page: SetupWizardPage,
+    options_loaded: bool,
+    result_tx: mpsc::UnboundedSender<Result<InitialSetupResponse, String>>,
+    result_rx: mpsc::UnboundedReceiver<Result<InitialSetupResponse, String>>,
+}
+
+impl SetupWizardPageState {
+    pub fn new() -> Self {
+        let (result_tx, result_rx) = mpsc::unbounded_channel();
+        Self { page: SetupWizardPage::new(), options_loaded: false, result_tx, result_rx }
+    }
+
+    fn load_options(&mut self, controllers: &Controllers) {
+        if self.options_loaded {
+            return;
+        }
+        let to_choices = |options: Vec<PolicyOption>| {
+            options
+                .into_iter()
+                .map(|option| SetupChoice { code: option.code, name: option.name })
+                .collect()
+        };
+        let options = controllers.initial_setup.options();
+        self.page
+            .set_options(to_choices(options.currencies), to_choices(options.chart_templates));
+        self.options_loaded = true;
+    }
+
+    /// 入力内容でマスタを作成
+    fn submit(&mut self, controllers: &Controllers) {
+        if self.page.is_submitting() {
+            return;
+        }
+
+        let controller = Arc::clone(&controllers.initial_setup);
+        let result_tx = self.result_tx.clone();
+        let input = self.page.input();
+        self.page.start_submitting();
+
+        tokio::spawn(async move {
+            let request = InitialSetupRequest {
+                company_code: input.company_code,
+                company_name: input.company_name,
+                fiscal_year_start_month: input.fiscal_year_start_month,
+                base_currency: input.base_currency,
+                chart_template: input.chart_template,
+                admin_user_id: input.admin_user_id,
+                admin_display_name: input.admin_display_name,
+                admin_password: input.admin_password,
+            };
+            let _ = result_tx.send(controller.execute(request).await);
+        });
+    }
+
+    /// 作成結果を反映し、成功した場合は管理者としてセッションを開始
+    fn poll_result(&mut self, controllers: &Controllers) -> bool {
+        let Ok(result) = self.result_rx.try_recv() else {
+            return false;
+        };
+
+        match result {
+            Ok(response) => {
+                crate::clock::set_fiscal_year_start_month(
+                    self.page.input().fiscal_year_start_month,
+                );
+                controllers.session.start(SessionUser {
+                    user_id: response.admin_user_id.clone(),
+                    display_name: response.admin_display_name,
+                });
+                controllers
+                    .session_log
+                    .track_login(Arc::clone(&controllers.session), response.admin_user_id);
+                true
+            }
+            Err(message) => {
+                self.page.set_error(message);
+                false
+            }
+        }
+    }
+}
+
+impl PageState for SetupWizardPageState {
+    fn route(&self) -> Route {
+        Route::SetupWizard
+    }
+
+    fn run(
+        &mut self,
+        terminal: &mut DefaultTerminal,
+        controllers: &Controllers,
+    ) -> AdapterResult<NavAction> {
+        self.load_options(controllers);
+
+        loop {
+            if self.poll_result(controllers) {
+                return Ok(NavAction::Back);
+            }
+
+            self.page.tick();
+
+            terminal
+                .draw(|frame| {
+                    render_guarded(frame, |frame| self.page.render(frame));
+                })
+                .map_err(|e| crate::error::AdapterError::RenderingFailed(e.to_string()))?;
+
+            if controllers.session.poll_input(std::time::Duration::from_millis(100))?
+                && let Event::Key(key) =
+                    event::read().map_err(crate::error::AdapterError::EventReadFailed)?
+            {
+                if key.kind != KeyEventKind::Press || self.page.is_submitting() {
+                    continue;
+                }
+
+                match key.code {
+                    KeyCode::Esc => {
+                        if !self.page.back() {
+                            return Ok(NavAction::Back);
+                        }
+                    }
+                    KeyCode::Enter => {
+                        if self.page.step() == SetupStep::Confirm {
+                            self.submit(controllers);
+                        } else {
+                            self.page.advance();
+                        }
+                    }
+                    KeyCode::Tab => self.page.next_field(),
+                    KeyCode::BackTab => self.page.previous_field(),
+                    KeyCode::Up => self.page.select(false),
+                    KeyCode::Down => self.page.select(true),
+                    KeyCode::Backspace => self.page.delete_char(),
+                    KeyCode::Char(c) => self.page.input_char(c),
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    fn on_navigation_error(&mut self, error_message: &str) {
+        self.page.set_error(error_message);
+    }
+}
+
+impl Default for SetupWizardPageState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
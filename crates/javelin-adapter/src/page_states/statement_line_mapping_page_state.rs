@@ -0,0 +1,186 @@
+// StatementLineMappingPageState - 財務諸表表示科目マッピング画面の状態
+// 責務: 割当状況の取得と表示科目の割当・解除操作
+
+use std::sync::Arc;
+
+use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use javelin_application::dtos::request::{
+    AssignStatementLineRequest, RemoveStatementLineMappingRequest,
+};
+use ratatui::DefaultTerminal;
+use tokio::sync::mpsc;
+
+use crate::{
+    error::AdapterResult,
+    navigation::{Controllers, NavAction, PageState, Route},
+    presenter::StatementLineMappingViewModel,
+    views::{layouts::render_guarded, pages::StatementLineMappingPage},
+};
+
+/// 操作結果
+enum MappingUpdate {
+    Loaded(StatementLineMappingViewModel),
+    Saved(String),
+    SaveFailed(String),
+    LoadFailed(String),
+}
+
+pub struct StatementLineMappingPageState {
+    page: StatementLineMappingPage,
+    update_tx: mpsc::UnboundedSender<MappingUpdate>,
+    update_rx: mpsc::UnboundedReceiver<MappingUpdate>,
+    /// データロード済みフラグ
+    data_loaded: bool,
+}
+
+impl StatementLineMappingPageState {
+    pub fn new() -> Self {
+        let (update_tx, update_rx) = mpsc::unbounded_channel();
+        Self { page: StatementLineMappingPage::new(), update_tx, update_rx, data_loaded: false }
+    }
+
+    /// 割当状況を再取得
+    fn load_mappings(&self, controllers: &Controllers) {
+        let controller = Arc::clone(&controllers.statement_line_mapping);
+        let update_tx = self.update_tx.clone();
+
+        tokio::spawn(async move {
+            let update = match controller.load_mappings().await {
+                Ok(response) => {
+                    MappingUpdate::Loaded(StatementLineMappingViewModel::from_response(&response))
+                }
+                Err(e) => MappingUpdate::LoadFailed(e),
+            };
+            let _ = update_tx.send(update);
+        });
+    }
+
+    /// 選択中の勘定科目の表示科目を切り替え
+    fn cycle_selected(&self, controllers: &Controllers, forward: bool) {
+        let Some(index) = self.page.selected_index() else {
+            return;
+        };
+        let view_model = self.page.view_model();
+        let (Some(item), Some(statement_line_code)) =
+            (view_model.items.get(index), view_model.cycled_line_code(index, forward))
+        else {
+            return;
+        };
+
+        let controller = Arc::clone(&controllers.statement_line_mapping);
+        let update_tx = self.update_tx.clone();
+        let request = AssignStatementLineRequest {
+            account_code: item.account_code.clone(),
+            statement_line_code,
+        };
+
+        tokio::spawn(async move {
+            let account_code = request.account_code.clone();
+            let update = match controller.assign_statement_line(request).await {
+                Ok(()) => {
+                    MappingUpdate::Saved(format!("{} の表示科目を更新しました", account_code))
+                }
+                Err(e) => MappingUpdate::SaveFailed(e),
+            };
+            let _ = update_tx.send(update);
+        });
+    }
+
+    /// 選択中の勘定科目の割当を解除
+    fn remove_selected(&self, controllers: &Controllers) {
+        let Some(item) = self.page.selected_item().filter(|item| item.is_mapped()) else {
+            return;
+        };
+
+        let controller = Arc::clone(&controllers.statement_line_mapping);
+        let update_tx = self.update_tx.clone();
+        let request = RemoveStatementLineMappingRequest { account_code: item.account_code.clone() };
+
+        tokio::spawn(async move {
+            let account_code = request.account_code.clone();
+            let update = match controller.remove_mapping(request).await {
+                Ok(()) => MappingUpdate::Saved(format!("{} の割当を解除しました", account_code)),
+                Err(e) => MappingUpdate::SaveFailed(e),
+            };
+            let _ = update_tx.send(update);
+        });
+    }
+
+    /// 操作結果を反映
+    fn poll_updates(&mut self, controllers: &Controllers) {
+        while let Ok(update) = self.update_rx.try_recv() {
+            match update {
+                MappingUpdate::Loaded(view_model) => self.page.set_data(view_model),
+                MappingUpdate::Saved(message) => {
+                    self.page.set_status_message(message);
+                    self.load_mappings(controllers);
+                }
+                MappingUpdate::SaveFailed(message) => {
+                    self.page.set_status_message(format!("更新に失敗しました: {}", message));
+                }
+                MappingUpdate::LoadFailed(message) => self.page.set_error(message),
+            }
+        }
+    }
+}
+
+impl PageState for StatementLineMappingPageState {
+    fn route(&self) -> Route {
+        Route::StatementLineMapping
+    }
+
+    fn run(
+        &mut self,
+        terminal: &mut DefaultTerminal,
+        controllers: &Controllers,
+    ) -> AdapterResult<NavAction> {
+        // 初回ロード
+        if !self.data_loaded {
+            self.data_loaded = true;
+            self.load_mappings(controllers);
+        }
+
+        loop {
+            self.poll_updates(controllers);
+
+            terminal
+                .draw(|frame| {
+                    render_guarded(frame, |frame| self.page.render(frame));
+                })
+                .map_err(|e| crate::error::AdapterError::RenderingFailed(e.to_string()))?;
+
+            if controllers.session.lock_if_idle() {
+                return Ok(NavAction::Go(Route::Login));
+            }
+
+            if controllers.session.poll_input(std::time::Duration::from_millis(100))?
+                && let Event::Key(key) =
+                    event::read().map_err(crate::error::AdapterError::EventReadFailed)?
+            {
+                if key.kind != KeyEventKind::Press {
+                    continue;
+                }
+
+                match key.code {
+                    KeyCode::Esc => return Ok(NavAction::Back),
+                    KeyCode::Up | KeyCode::Char('k') => self.page.select_previous(),
+                    KeyCode::Down | KeyCode::Char('j') => self.page.select_next(),
+                    KeyCode::Right | KeyCode::Char('l') => self.cycle_selected(controllers, true),
+                    KeyCode::Left | KeyCode::Char('h') => self.cycle_selected(controllers, false),
+                    KeyCode::Char('x') => self.remove_selected(controllers),
+                    KeyCode::Char('n') => self.page.select_next_unmapped(),
+                    KeyCode::Char('m') => {
+                        return Ok(NavAction::Go(Route::ManagementAccountMapping));
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+}
+
+impl Default for StatementLineMappingPageState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
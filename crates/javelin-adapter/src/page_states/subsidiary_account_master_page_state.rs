@@ -10,7 +10,7 @@ use crate::{
     error::AdapterResult,
     navigation::{Controllers, NavAction, PageState, PresenterRegistry, Route},
     presenter::{SubsidiaryAccountMasterPresenter, SubsidiaryAccountMasterViewModel},
-    views::pages::SubsidiaryAccountMasterPage,
+    views::{layouts::render_guarded, pages::SubsidiaryAccountMasterPage},
 };
 
 /// 補助科目マスタ画面の状態
@@ -139,13 +139,16 @@ impl PageState for SubsidiaryAccountMasterPageState {
             // Render
             terminal
                 .draw(|frame| {
-                    self.page.render(frame);
+                    render_guarded(frame, |frame| self.page.render(frame));
                 })
                 .map_err(|e| crate::error::AdapterError::RenderingFailed(e.to_string()))?;
 
+            if controllers.session.lock_if_idle() {
+                return Ok(NavAction::Go(Route::Login));
+            }
+
             // Handle events
-            if crossterm::event::poll(std::time::Duration::from_millis(100))
-                .map_err(crate::error::AdapterError::EventReadFailed)?
+            if controllers.session.poll_input(std::time::Duration::from_millis(100))?
                 && let crossterm::event::Event::Key(key) =
                     crossterm::event::read().map_err(crate::error::AdapterError::EventReadFailed)?
             {
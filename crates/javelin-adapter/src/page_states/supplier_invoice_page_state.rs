@@ -0,0 +1,228 @@
+// SupplierInvoicePageState - 仕入先請求書台帳画面の状態管理
+
+use std::{path::PathBuf, sync::Arc};
+
+use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use javelin_application::dtos::response::{InvoiceJournalDraftResponse, SupplierInvoiceResponse};
+use ratatui::DefaultTerminal;
+use tokio::sync::mpsc;
+
+use crate::{
+    error::AdapterResult,
+    navigation::{
+        Controllers, NavAction, PageState, Route, controllers::SupplierInvoiceControllerType,
+    },
+    views::{layouts::render_guarded, pages::SupplierInvoicePage},
+};
+
+/// 取込ファイル名
+const IMPORT_FILE_NAME: &str = "supplier_invoices.csv";
+
+/// 非同期処理の結果
+enum InvoiceUpdate {
+    Loaded(Vec<SupplierInvoiceResponse>),
+    Imported(usize),
+    Drafted(InvoiceJournalDraftResponse),
+    Paid(SupplierInvoiceResponse),
+    Failed(String),
+}
+
+pub struct SupplierInvoicePageState {
+    page: SupplierInvoicePage,
+    update_tx: mpsc::UnboundedSender<InvoiceUpdate>,
+    update_rx: mpsc::UnboundedReceiver<InvoiceUpdate>,
+    /// データロード済みフラグ
+    data_loaded: bool,
+}
+
+impl SupplierInvoicePageState {
+    pub fn new() -> Self {
+        let (update_tx, update_rx) = mpsc::unbounded_channel();
+
+        Self {
+            page: SupplierInvoicePage::new(IMPORT_FILE_NAME),
+            update_tx,
+            update_rx,
+            data_loaded: false,
+        }
+    }
+
+    /// 請求書一覧を読み込む（期日超過は業務日付で判定）
+    fn load_invoices(&mut self, controllers: &Controllers) {
+        self.page.start_loading();
+        let controller = Arc::clone(&controllers.supplier_invoice);
+        let update_tx = self.update_tx.clone();
+
+        tokio::spawn(async move {
+            send_list(&controller, &update_tx).await;
+        });
+    }
+
+    /// 取込ファイルから請求書を登録
+    fn import_invoices(&mut self, controllers: &Controllers) {
+        self.page.start_loading();
+        let controller = Arc::clone(&controllers.supplier_invoice);
+        let update_tx = self.update_tx.clone();
+        let path = PathBuf::from(self.page.import_file_name());
+
+        tokio::spawn(async move {
+            let update = match controller.import_csv(&path).await {
+                Ok(count) => InvoiceUpdate::Imported(count),
+                Err(e) => InvoiceUpdate::Failed(e),
+            };
+            let _ = update_tx.send(update);
+            send_list(&controller, &update_tx).await;
+        });
+    }
+
+    /// 選択中の請求書から買掛金計上仕訳（下書き）を起票
+    fn create_journal_draft(&mut self, controllers: &Controllers) {
+        let Some(invoice) = self.page.selected_invoice() else {
+            self.page.add_error("請求書を選択してください");
+            return;
+        };
+        let controller = Arc::clone(&controllers.supplier_invoice);
+        let update_tx = self.update_tx.clone();
+        let user_id = controllers.session.user_id();
+        let (counterparty, invoice_number) =
+            (invoice.counterparty.clone(), invoice.invoice_number.clone());
+
+        tokio::spawn(async move {
+            let update = match controller
+                .create_journal_draft(user_id, counterparty, invoice_number)
+                .await
+            {
+                Ok(draft) => InvoiceUpdate::Drafted(draft),
+                Err(e) => InvoiceUpdate::Failed(e),
+            };
+            let _ = update_tx.send(update);
+            send_list(&controller, &update_tx).await;
+        });
+    }
+
+    /// 選択中の請求書の未払残高を業務日付で支払済みとして記録
+    fn record_payment(&mut self, controllers: &Controllers) {
+        let Some(invoice) = self.page.selected_invoice() else {
+            self.page.add_error("請求書を選択してください");
+            return;
+        };
+        let controller = Arc::clone(&controllers.supplier_invoice);
+        let update_tx = self.update_tx.clone();
+        let paid_date = crate::clock::business_date().format("%Y-%m-%d").to_string();
+        let (counterparty, invoice_number, amount) = (
+            invoice.counterparty.clone(),
+            invoice.invoice_number.clone(),
+            invoice.outstanding_amount,
+        );
+
+        tokio::spawn(async move {
+            let update = match controller
+                .record_payment(counterparty, invoice_number, paid_date, amount)
+                .await
+            {
+                Ok(invoice) => InvoiceUpdate::Paid(invoice),
+                Err(e) => InvoiceUpdate::Failed(e),
+            };
+            let _ = update_tx.send(update);
+            send_list(&controller, &update_tx).await;
+        });
+    }
+
+    fn apply_update(&mut self, update: InvoiceUpdate) {
+        match update {
+            InvoiceUpdate::Loaded(invoices) => self.page.set_invoices(invoices),
+            InvoiceUpdate::Imported(count) => {
+                let message =
+                    format!("取り込みました: {} ({}件)", self.page.import_file_name(), count);
+                self.page.add_info(message);
+            }
+            InvoiceUpdate::Drafted(draft) => self.page.add_info(format!(
+                "買掛金計上仕訳を起票しました: {} ({})",
+                draft.voucher_number, draft.status
+            )),
+            InvoiceUpdate::Paid(invoice) => self.page.add_info(format!(
+                "支払を記録しました: {} {} ({})",
+                invoice.counterparty, invoice.invoice_number, invoice.payment_status_label
+            )),
+            InvoiceUpdate::Failed(error) => self.page.set_error(error),
+        }
+    }
+}
+
+/// 最新の請求書一覧を送る
+async fn send_list(
+    controller: &SupplierInvoiceControllerType,
+    update_tx: &mpsc::UnboundedSender<InvoiceUpdate>,
+) {
+    let update = match controller.list(crate::clock::business_date()).await {
+        Ok(invoices) => InvoiceUpdate::Loaded(invoices),
+        Err(e) => InvoiceUpdate::Failed(e),
+    };
+    let _ = update_tx.send(update);
+}
+
+impl PageState for SupplierInvoicePageState {
+    fn route(&self) -> Route {
+        Route::SupplierInvoice
+    }
+
+    fn run(
+        &mut self,
+        terminal: &mut DefaultTerminal,
+        controllers: &Controllers,
+    ) -> AdapterResult<NavAction> {
+        // 初回ロード
+        if !self.data_loaded {
+            self.data_loaded = true;
+            self.load_invoices(controllers);
+        }
+
+        loop {
+            while let Ok(update) = self.update_rx.try_recv() {
+                self.apply_update(update);
+            }
+
+            self.page.tick();
+
+            terminal
+                .draw(|frame| {
+                    render_guarded(frame, |frame| self.page.render(frame));
+                })
+                .map_err(|e| crate::error::AdapterError::RenderingFailed(e.to_string()))?;
+
+            if controllers.session.lock_if_idle() {
+                return Ok(NavAction::Go(Route::Login));
+            }
+
+            if controllers.session.poll_input(std::time::Duration::from_millis(100))?
+                && let Event::Key(key) =
+                    event::read().map_err(crate::error::AdapterError::EventReadFailed)?
+            {
+                if key.kind != KeyEventKind::Press {
+                    continue;
+                }
+
+                match key.code {
+                    KeyCode::Esc => return Ok(NavAction::Back),
+                    KeyCode::Char('j') | KeyCode::Down => self.page.select_next(),
+                    KeyCode::Char('k') | KeyCode::Up => self.page.select_previous(),
+                    KeyCode::Char('i') => self.import_invoices(controllers),
+                    KeyCode::Char('d') => self.create_journal_draft(controllers),
+                    KeyCode::Char('p') => self.record_payment(controllers),
+                    KeyCode::Char('r') => self.load_invoices(controllers),
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    fn on_navigation_error(&mut self, error_message: &str) {
+        self.page.add_error(error_message);
+    }
+}
+
+impl Default for SupplierInvoicePageState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,66 @@
+// SystemInfoPageState - システム情報画面の状態
+// 責務: 起動時に記録した起動診断レポートの表示
+
+use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use ratatui::DefaultTerminal;
+
+use crate::{
+    error::AdapterResult,
+    navigation::{Controllers, NavAction, PageState, Route},
+    startup_report::startup_report,
+    views::{layouts::render_guarded, pages::SystemInfoPage},
+};
+
+pub struct SystemInfoPageState {
+    page: SystemInfoPage,
+}
+
+impl SystemInfoPageState {
+    pub fn new() -> Self {
+        Self { page: SystemInfoPage::new(startup_report()) }
+    }
+}
+
+impl PageState for SystemInfoPageState {
+    fn route(&self) -> Route {
+        Route::SystemInfo
+    }
+
+    fn run(
+        &mut self,
+        terminal: &mut DefaultTerminal,
+        controllers: &Controllers,
+    ) -> AdapterResult<NavAction> {
+        loop {
+            terminal
+                .draw(|frame| render_guarded(frame, |frame| self.page.render(frame)))
+                .map_err(|e| crate::error::AdapterError::RenderingFailed(e.to_string()))?;
+
+            if controllers.session.lock_if_idle() {
+                return Ok(NavAction::Go(Route::Login));
+            }
+
+            if controllers.session.poll_input(std::time::Duration::from_millis(100))?
+                && let Event::Key(key) =
+                    event::read().map_err(crate::error::AdapterError::EventReadFailed)?
+            {
+                if key.kind != KeyEventKind::Press {
+                    continue;
+                }
+
+                match key.code {
+                    KeyCode::Esc => return Ok(NavAction::Back),
+                    KeyCode::Up | KeyCode::Char('k') => self.page.select_previous(),
+                    KeyCode::Down | KeyCode::Char('j') => self.page.select_next(),
+                    _ => {}
+                }
+            }
+        }
+    }
+}
+
+impl Default for SystemInfoPageState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,120 @@
+// TablePreferenceSync - 一覧テーブル表示設定の読込・保存
+// 責務: DataTableの列設定・並び替え操作と設定の永続化の橋渡し
+
+use std::sync::Arc;
+
+use crossterm::event::KeyCode;
+use javelin_application::dtos::{
+    request::SaveTablePreferenceRequest,
+    response::{ColumnPreferenceDto, TablePreferenceResponse, TableSortDto},
+};
+use tokio::sync::mpsc;
+
+use crate::{
+    navigation::Controllers,
+    views::components::{ColumnLayout, DataTable, SortKey},
+};
+
+/// 列幅変更の刻み
+const RESIZE_STEP: i16 = 2;
+
+/// 一覧テーブル表示設定の同期
+///
+/// 画面表示時に保存済み設定を読み込んでDataTableへ適用し、
+/// キー操作で変更した設定を保存する。
+///
+/// - `s` 並び替えキーの切替
+/// - `<` / `>` 対象列の幅変更
+/// - `[` / `]` 対象列の移動
+/// - `v` 対象列の非表示 / `V` 全列表示
+pub struct TablePreferenceSync {
+    table_id: &'static str,
+    load_requested: bool,
+    loaded_tx: mpsc::UnboundedSender<TablePreferenceResponse>,
+    loaded_rx: mpsc::UnboundedReceiver<TablePreferenceResponse>,
+}
+
+impl TablePreferenceSync {
+    pub fn new(table_id: &'static str) -> Self {
+        let (loaded_tx, loaded_rx) = mpsc::unbounded_channel();
+        Self { table_id, load_requested: false, loaded_tx, loaded_rx }
+    }
+
+    /// 保存済み設定の読込を開始（初回のみ）
+    pub fn request_load(&mut self, controllers: &Controllers) {
+        if self.load_requested {
+            return;
+        }
+        self.load_requested = true;
+
+        let controller = Arc::clone(&controllers.table_preference);
+        let table_id = self.table_id;
+        let loaded_tx = self.loaded_tx.clone();
+
+        tokio::spawn(async move {
+            if let Ok(Some(preference)) = controller.load_preference(table_id).await {
+                let _ = loaded_tx.send(preference);
+            }
+        });
+    }
+
+    /// 読み込んだ設定をテーブルへ適用
+    pub fn apply_loaded(&mut self, table: &mut DataTable) {
+        while let Ok(preference) = self.loaded_rx.try_recv() {
+            let columns = preference
+                .columns
+                .iter()
+                .map(|c| ColumnLayout { column: c.column, visible: c.visible, width: c.width })
+                .collect();
+            let sort =
+                preference.sort.map(|s| SortKey { column: s.column, descending: s.descending });
+            table.apply_layout(columns, sort);
+        }
+    }
+
+    /// 表示設定のキー操作を処理（処理した場合はtrue）
+    pub fn handle_key(
+        &self,
+        code: KeyCode,
+        table: &mut DataTable,
+        controllers: &Controllers,
+    ) -> bool {
+        match code {
+            KeyCode::Char('s') => table.cycle_sort(),
+            KeyCode::Char('<') => table.resize_active_column(-RESIZE_STEP),
+            KeyCode::Char('>') => table.resize_active_column(RESIZE_STEP),
+            KeyCode::Char('[') => table.move_active_column(false),
+            KeyCode::Char(']') => table.move_active_column(true),
+            KeyCode::Char('v') => table.hide_active_column(),
+            KeyCode::Char('V') => table.show_all_columns(),
+            _ => return false,
+        }
+
+        self.save(table, controllers);
+        true
+    }
+
+    /// 現在の表示設定を保存
+    fn save(&self, table: &DataTable, controllers: &Controllers) {
+        let request = SaveTablePreferenceRequest {
+            table_id: self.table_id.to_string(),
+            columns: table
+                .column_layout()
+                .iter()
+                .map(|c| ColumnPreferenceDto {
+                    column: c.column,
+                    visible: c.visible,
+                    width: c.width,
+                })
+                .collect(),
+            sort: table
+                .sort_key()
+                .map(|key| TableSortDto { column: key.column, descending: key.descending }),
+        };
+        let controller = Arc::clone(&controllers.table_preference);
+
+        tokio::spawn(async move {
+            let _ = controller.save_preference(request).await;
+        });
+    }
+}
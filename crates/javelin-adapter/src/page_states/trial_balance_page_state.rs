@@ -1,24 +1,338 @@
 // TrialBalancePageState - PageState implementation for trial balance screen
 // Uses ClosingPage which displays trial balance
 
+use std::{path::Path, sync::Arc, time::Instant};
+
+use chrono::Datelike;
 use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use javelin_application::dtos::{
+    GenerateTrialBalanceRequest, TranslationRateDto, request::SaveVarianceCommentaryRequest,
+    response::AmountFormat,
+};
 use ratatui::DefaultTerminal;
+use tokio::sync::mpsc;
 
 use crate::{
     error::AdapterResult,
     navigation::{Controllers, NavAction, PageState, Route},
-    views::pages::ClosingPage,
+    page_states::{
+        ProtectedExport,
+        report_parameters::{self, PARAM_INCLUDE_PENDING, StandardReport},
+    },
+    presenter::{ManagementTrialBalanceViewModel, TrialBalanceViewModel},
+    views::{layouts::render_guarded, pages::ClosingPage},
 };
 
+/// 試算表の表示通貨
+const PRESENTATION_CURRENCY: &str = "JPY";
+
 pub struct TrialBalancePageState {
     page: ClosingPage,
+    /// Sender for trial balance data
+    trial_balance_tx: mpsc::UnboundedSender<TrialBalanceViewModel>,
+    /// 管理会計科目体系で集計し直した試算表
+    management_rx: mpsc::UnboundedReceiver<ManagementTrialBalanceViewModel>,
+    management_tx: mpsc::UnboundedSender<ManagementTrialBalanceViewModel>,
+    /// Error receiver for failed loads
+    error_rx: mpsc::UnboundedReceiver<String>,
+    error_tx: mpsc::UnboundedSender<String>,
+    /// 監査用帳簿の出力結果
+    status_rx: mpsc::UnboundedReceiver<String>,
+    status_tx: mpsc::UnboundedSender<String>,
+    /// エクスポート時の金額書式（会計方針）
+    amount_format: AmountFormat,
+    amount_format_tx: mpsc::UnboundedSender<AmountFormat>,
+    amount_format_rx: mpsc::UnboundedReceiver<AmountFormat>,
+    /// 対象の会計年度・月
+    fiscal_year: i32,
+    period: u8,
+    /// 承認待ちの仕訳を含めて集計するか
+    include_pending_approval: bool,
+    /// 表示通貨以外の取引通貨の換算レート（画面で入力）
+    translation_rates: Vec<TranslationRateDto>,
+    /// 増減コメント（科目コードとコメント）
+    commentary_tx: mpsc::UnboundedSender<Vec<(String, String)>>,
+    commentary_rx: mpsc::UnboundedReceiver<Vec<(String, String)>>,
+    /// 試算表CSVの保護付き書き出し
+    export: ProtectedExport,
+    /// データロード済みフラグ
+    data_loaded: bool,
 }
 
 impl TrialBalancePageState {
+    /// 帳票メニューから条件が渡された場合はその対象月・集計方法で、
+    /// それ以外は業務日付の当月で試算表を生成する
     pub fn new() -> Self {
+        let today = crate::clock::business_date();
+        let parameters = report_parameters::take_handed_off(StandardReport::TrialBalance);
+        let (fiscal_year, period) = parameters
+            .as_ref()
+            .and_then(report_parameters::parse_period)
+            .unwrap_or((today.year(), today.month() as u8));
+        let include_pending_approval = parameters
+            .as_ref()
+            .is_some_and(|values| report_parameters::is_enabled(values, PARAM_INCLUDE_PENDING));
+
         // Create channel for trial balance data
-        let (_, trial_balance_rx) = tokio::sync::mpsc::unbounded_channel();
-        Self { page: ClosingPage::new(trial_balance_rx) }
+        let (trial_balance_tx, trial_balance_rx) = mpsc::unbounded_channel();
+        let (error_tx, error_rx) = mpsc::unbounded_channel();
+        let (status_tx, status_rx) = mpsc::unbounded_channel();
+        let (management_tx, management_rx) = mpsc::unbounded_channel();
+        let (amount_format_tx, amount_format_rx) = mpsc::unbounded_channel();
+        let (commentary_tx, commentary_rx) = mpsc::unbounded_channel();
+        let mut page = ClosingPage::new(trial_balance_rx);
+        if include_pending_approval {
+            page.set_include_pending_approval(true);
+        }
+        Self {
+            page,
+            trial_balance_tx,
+            management_rx,
+            management_tx,
+            error_rx,
+            error_tx,
+            status_rx,
+            status_tx,
+            amount_format: AmountFormat::default(),
+            amount_format_tx,
+            amount_format_rx,
+            fiscal_year,
+            period,
+            include_pending_approval,
+            translation_rates: Vec::new(),
+            commentary_tx,
+            commentary_rx,
+            export: ProtectedExport::new(),
+            data_loaded: false,
+        }
+    }
+
+    /// 試算表の生成を開始
+    ///
+    /// 利用者の金額マスキングを取得してから生成する（取得できない場合は表示しない）。
+    fn load_trial_balance(&self, controllers: &Controllers) {
+        let controller = Arc::clone(&controllers.closing);
+        let policy_controller = Arc::clone(&controllers.accounting_policy);
+        let mapping_controller = Arc::clone(&controllers.management_account_mapping);
+        let recorder = Arc::clone(&controllers.record_user_action);
+        let user_id = controllers.session.user_id();
+        let trial_balance_tx = self.trial_balance_tx.clone();
+        let management_tx = self.management_tx.clone();
+        let error_tx = self.error_tx.clone();
+        let status_tx = self.status_tx.clone();
+        let (year, month) = (self.fiscal_year, self.period);
+        let include_pending_approval = self.include_pending_approval;
+        let translation_rates = self.translation_rates.clone();
+
+        tokio::spawn(async move {
+            let amount_mask = match policy_controller.amount_mask(&user_id).await {
+                Ok(amount_mask) => amount_mask,
+                Err(e) => {
+                    let _ = error_tx.send(format!("金額マスキングの取得に失敗しました: {}", e));
+                    return;
+                }
+            };
+            let request = GenerateTrialBalanceRequest {
+                fiscal_year: year,
+                period: month,
+                presentation_currency: PRESENTATION_CURRENCY.to_string(),
+                translation_rates,
+                include_pending_approval,
+            };
+            let started = Instant::now();
+            let result = controller.generate_trial_balance(request).await;
+            let _ = recorder
+                .record_action(user_id, "TrialBalance", "generate", started.elapsed())
+                .await;
+            match result {
+                Ok(response) => {
+                    let view_model = TrialBalanceViewModel::from_response(
+                        year as u32,
+                        month,
+                        &response,
+                        &amount_mask,
+                    );
+                    let _ = trial_balance_tx.send(view_model);
+                    // 管理会計科目体系で集計し直す（表示通貨建てのみ）
+                    match mapping_controller
+                        .map_trial_balance(&response.presentation_trial_balance)
+                        .await
+                    {
+                        Ok(mapped) => {
+                            let _ =
+                                management_tx.send(ManagementTrialBalanceViewModel::from_response(
+                                    year as u32,
+                                    month,
+                                    &mapped,
+                                    include_pending_approval,
+                                    &amount_mask,
+                                ));
+                        }
+                        Err(e) => {
+                            let _ = status_tx
+                                .send(format!("管理会計科目体系の集計に失敗しました: {}", e));
+                        }
+                    }
+                    // 配信先が設定されている場合は配信結果を表示
+                    if !response.deliveries.is_empty() {
+                        let message = response
+                            .deliveries
+                            .iter()
+                            .map(|delivery| delivery.summary())
+                            .collect::<Vec<_>>()
+                            .join(" / ");
+                        let _ = status_tx.send(message);
+                    }
+                }
+                Err(e) => {
+                    let _ = error_tx.send(e.to_string());
+                }
+            }
+        });
+    }
+
+    /// 入力した換算レートを設定して再集計する（同じ通貨のレートは置き換える）
+    fn apply_translation_rate(&mut self, controllers: &Controllers) {
+        let Some(input) = self.page.take_rate_input() else {
+            return;
+        };
+        match parse_translation_rate(&input) {
+            Ok(rate) => {
+                self.page.set_status_message(format!(
+                    "換算レートを設定しました: {} 決算日 {} / 期中平均 {}",
+                    rate.currency, rate.closing_rate, rate.average_rate
+                ));
+                self.translation_rates.retain(|r| r.currency != rate.currency);
+                self.translation_rates.push(rate);
+                self.page.start_loading();
+                self.load_trial_balance(controllers);
+            }
+            Err(message) => self.page.set_status_message(message),
+        }
+    }
+
+    /// 対象月の増減コメントを取得
+    fn load_commentaries(&self, controllers: &Controllers) {
+        let controller = Arc::clone(&controllers.variance_commentary);
+        let commentary_tx = self.commentary_tx.clone();
+        let status_tx = self.status_tx.clone();
+        let (fiscal_year, period) = (self.fiscal_year, self.period);
+
+        tokio::spawn(async move {
+            match controller.list(fiscal_year, period).await {
+                Ok(commentaries) => {
+                    let _ = commentary_tx.send(
+                        commentaries
+                            .into_iter()
+                            .map(|commentary| (commentary.account_code, commentary.commentary))
+                            .collect(),
+                    );
+                }
+                Err(e) => {
+                    let _ = status_tx.send(format!("増減コメントの取得に失敗しました: {}", e));
+                }
+            }
+        });
+    }
+
+    /// 記入した増減コメントを保存して読み込み直す
+    fn save_commentary(&mut self, controllers: &Controllers) {
+        let Some((account_code, commentary)) = self.page.take_commentary() else {
+            return;
+        };
+        let controller = Arc::clone(&controllers.variance_commentary);
+        let status_tx = self.status_tx.clone();
+        let commentary_tx = self.commentary_tx.clone();
+        let (fiscal_year, period) = (self.fiscal_year, self.period);
+        let request = SaveVarianceCommentaryRequest {
+            account_code: account_code.clone(),
+            fiscal_year,
+            period,
+            commentary,
+            written_by: controllers.session.user_id(),
+        };
+
+        tokio::spawn(async move {
+            let message = match controller.save(request).await {
+                Ok(Some(_)) => format!("増減コメントを保存しました: {}", account_code),
+                Ok(None) => format!("増減コメントを削除しました: {}", account_code),
+                Err(e) => format!("増減コメントの保存に失敗しました: {}", e),
+            };
+            let _ = status_tx.send(message);
+            if let Ok(commentaries) = controller.list(fiscal_year, period).await {
+                let _ = commentary_tx.send(
+                    commentaries
+                        .into_iter()
+                        .map(|commentary| (commentary.account_code, commentary.commentary))
+                        .collect(),
+                );
+            }
+        });
+    }
+
+    /// エクスポート用の金額書式を取得（取得できない場合は既定の書式を使う）
+    fn load_amount_format(&self, controllers: &Controllers) {
+        let controller = Arc::clone(&controllers.accounting_policy);
+        let amount_format_tx = self.amount_format_tx.clone();
+
+        tokio::spawn(async move {
+            if let Ok(amount_format) = controller.amount_format().await {
+                let _ = amount_format_tx.send(amount_format);
+            }
+        });
+    }
+
+    /// 表示中の試算表をCSVファイルへ出力
+    fn export_current_section(&mut self, controllers: &Controllers) {
+        let Some(section) = self.page.current_section() else {
+            return;
+        };
+
+        // 承認待ちを含む試算・管理会計科目体系は正式な試算表と区別できるファイル名にする
+        let file_name = format!(
+            "trial_balance_{:04}{:02}_{}{}{}.csv",
+            section.period_year,
+            section.period_month,
+            section.currency,
+            if self.page.is_management_view() {
+                "_management"
+            } else {
+                ""
+            },
+            if section.include_pending_approval {
+                "_pending"
+            } else {
+                ""
+            }
+        );
+        let content = section.to_csv(&self.amount_format).into_bytes();
+        self.export.export(controllers, file_name, content, "");
+    }
+
+    /// 対象月の年度の監査用帳簿（仕訳帳・総勘定元帳・索引）をカレントディレクトリへ出力
+    fn export_audit_books(&self, controllers: &Controllers) {
+        let controller = Arc::clone(&controllers.audit_export);
+        let recorder = Arc::clone(&controllers.record_user_action);
+        let exported_by = controllers.session.user_id();
+        let status_tx = self.status_tx.clone();
+        let fiscal_year = self.fiscal_year as u32;
+
+        tokio::spawn(async move {
+            let started = Instant::now();
+            let result = controller.export(fiscal_year, exported_by.clone(), Path::new(".")).await;
+            let _ = recorder
+                .record_action(exported_by, "TrialBalance", "audit_export", started.elapsed())
+                .await;
+            let message = match result {
+                Ok((directory, response)) => format!(
+                    "監査用帳簿を出力しました: {} (仕訳 {}件)",
+                    directory.display(),
+                    response.entry_count
+                ),
+                Err(e) => format!("監査用帳簿の出力に失敗しました: {}", e),
+            };
+            let _ = status_tx.send(message);
+        });
     }
 }
 
@@ -30,25 +344,59 @@ impl PageState for TrialBalancePageState {
     fn run(
         &mut self,
         terminal: &mut DefaultTerminal,
-        _controllers: &Controllers,
+        controllers: &Controllers,
     ) -> AdapterResult<NavAction> {
+        // 初回ロード
+        if !self.data_loaded {
+            self.data_loaded = true;
+            self.load_trial_balance(controllers);
+            self.load_amount_format(controllers);
+            self.load_commentaries(controllers);
+        }
+        self.export.request_settings(controllers);
+
         loop {
             // Tick animation
             self.page.tick();
 
             // Update trial balance data
             self.page.update();
+            while let Ok(view_model) = self.management_rx.try_recv() {
+                self.page.set_management_trial_balance(view_model);
+            }
+            while let Ok(message) = self.error_rx.try_recv() {
+                self.page.set_error(message);
+            }
+            while let Ok(message) = self.status_rx.try_recv() {
+                self.page.set_status_message(message);
+            }
+            while let Ok(commentaries) = self.commentary_rx.try_recv() {
+                self.page.set_commentaries(commentaries);
+            }
+            while let Ok(amount_format) = self.amount_format_rx.try_recv() {
+                self.amount_format = amount_format;
+            }
+            for result in self.export.poll() {
+                let message = result.unwrap_or_else(|e| format!("出力に失敗しました: {}", e));
+                self.page.set_status_message(message);
+            }
 
             // Render the page
             terminal
                 .draw(|frame| {
-                    self.page.render(frame);
+                    render_guarded(frame, |frame| {
+                        self.page.render(frame);
+                        self.export.render(frame);
+                    });
                 })
                 .map_err(|e| crate::error::AdapterError::RenderingFailed(e.to_string()))?;
 
+            if controllers.session.lock_if_idle() {
+                return Ok(NavAction::Go(Route::Login));
+            }
+
             // Handle events with timeout for animation updates
-            if event::poll(std::time::Duration::from_millis(100))
-                .map_err(crate::error::AdapterError::EventReadFailed)?
+            if controllers.session.poll_input(std::time::Duration::from_millis(100))?
                 && let Event::Key(key) =
                     event::read().map_err(crate::error::AdapterError::EventReadFailed)?
             {
@@ -56,6 +404,35 @@ impl PageState for TrialBalancePageState {
                     continue;
                 }
 
+                // 暗号化パスワード入力中
+                if self.export.handle_key(key.code, controllers) {
+                    continue;
+                }
+
+                // 換算レート入力中
+                if self.page.is_entering_rate() {
+                    match key.code {
+                        KeyCode::Esc => self.page.cancel_rate_input(),
+                        KeyCode::Enter => self.apply_translation_rate(controllers),
+                        KeyCode::Backspace => self.page.delete_rate_char(),
+                        KeyCode::Char(c) => self.page.input_rate_char(c),
+                        _ => {}
+                    }
+                    continue;
+                }
+
+                // 増減コメント記入中
+                if self.page.is_editing_commentary() {
+                    match key.code {
+                        KeyCode::Esc => self.page.cancel_commentary(),
+                        KeyCode::Enter => self.save_commentary(controllers),
+                        KeyCode::Backspace => self.page.delete_commentary_char(),
+                        KeyCode::Char(c) => self.page.input_commentary_char(c),
+                        _ => {}
+                    }
+                    continue;
+                }
+
                 match key.code {
                     KeyCode::Esc => {
                         return Ok(NavAction::Back);
@@ -66,6 +443,33 @@ impl PageState for TrialBalancePageState {
                     KeyCode::Char('k') | KeyCode::Up => {
                         self.page.select_previous();
                     }
+                    KeyCode::Char(' ') => {
+                        self.page.toggle_group();
+                    }
+                    KeyCode::Char('c') => {
+                        self.page.next_section();
+                    }
+                    KeyCode::Char('m') => {
+                        self.page.toggle_chart();
+                    }
+                    KeyCode::Char('r') => {
+                        self.page.start_rate_input();
+                    }
+                    KeyCode::Char('x') => {
+                        self.export_current_section(controllers);
+                    }
+                    KeyCode::Char('n') => {
+                        self.page.start_commentary();
+                    }
+                    KeyCode::Char('a') => {
+                        self.page.set_status_message("監査用帳簿を出力しています...");
+                        self.export_audit_books(controllers);
+                    }
+                    KeyCode::Char('p') => {
+                        self.include_pending_approval = !self.include_pending_approval;
+                        self.page.set_include_pending_approval(self.include_pending_approval);
+                        self.load_trial_balance(controllers);
+                    }
                     _ => {}
                 }
             }
@@ -78,3 +482,50 @@ impl Default for TrialBalancePageState {
         Self::new()
     }
 }
+
+/// 換算レートの入力（例: "USD 150.25 148.10"）を解析
+fn parse_translation_rate(input: &str) -> Result<TranslationRateDto, String> {
+    let parts: Vec<&str> = input.split_whitespace().collect();
+    let [currency, closing_rate, average_rate] = parts.as_slice() else {
+        return Err(
+            "換算レートは「通貨 決算日レート 期中平均レート」の形式で入力してください".to_string()
+        );
+    };
+    let currency = currency.to_ascii_uppercase();
+    if currency.len() != 3 || !currency.chars().all(|c| c.is_ascii_alphabetic()) {
+        return Err(format!("通貨はISO 4217の3文字で入力してください: {}", currency));
+    }
+    if currency == PRESENTATION_CURRENCY {
+        return Err(format!("表示通貨（{}）の換算レートは不要です", PRESENTATION_CURRENCY));
+    }
+    let rate = |value: &str, label: &str| {
+        value
+            .parse::<f64>()
+            .ok()
+            .filter(|rate| rate.is_finite() && *rate > 0.0)
+            .ok_or_else(|| format!("{}は正の数値で入力してください: {}", label, value))
+    };
+    Ok(TranslationRateDto {
+        closing_rate: rate(closing_rate, "決算日レート")?,
+        average_rate: rate(average_rate, "期中平均レート")?,
+        currency,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_translation_rate() {
+        let rate = parse_translation_rate(" usd 150.25 148.1 ").unwrap();
+        assert_eq!(rate.currency, "USD");
+        assert_eq!(rate.closing_rate, 150.25);
+        assert_eq!(rate.average_rate, 148.1);
+
+        assert!(parse_translation_rate("USD 150.25").is_err());
+        assert!(parse_translation_rate("USD 0 148.1").is_err());
+        assert!(parse_translation_rate("JPY 1 1").is_err());
+        assert!(parse_translation_rate("US 150 148").is_err());
+    }
+}
@@ -0,0 +1,111 @@
+// UserActivityPageState - 利用状況レポート画面の状態
+// 責務: 利用状況レポートの読み込みと反映（管理者のみ）
+
+use std::sync::Arc;
+
+use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use javelin_application::dtos::response::UserActivityReportResponse;
+use ratatui::DefaultTerminal;
+use tokio::sync::mpsc;
+
+use crate::{
+    error::AdapterResult,
+    navigation::{Controllers, NavAction, PageState, Route},
+    views::{layouts::render_guarded, pages::UserActivityPage},
+};
+
+/// 読み込み結果
+enum ActivityUpdate {
+    Loaded(UserActivityReportResponse),
+    Failed(String),
+}
+
+pub struct UserActivityPageState {
+    page: UserActivityPage,
+    update_tx: mpsc::UnboundedSender<ActivityUpdate>,
+    update_rx: mpsc::UnboundedReceiver<ActivityUpdate>,
+    /// レポートを読み込み済みか（画面表示時に一度だけ読み込む）
+    requested: bool,
+}
+
+impl UserActivityPageState {
+    pub fn new() -> Self {
+        let (update_tx, update_rx) = mpsc::unbounded_channel();
+        Self { page: UserActivityPage::new(), update_tx, update_rx, requested: false }
+    }
+
+    fn load(&mut self, controllers: &Controllers) {
+        self.requested = true;
+        self.page.set_loading();
+
+        let controller = Arc::clone(&controllers.user_activity);
+        let user_id = controllers.session.user_id();
+        let update_tx = self.update_tx.clone();
+
+        tokio::spawn(async move {
+            let update = match controller.report(user_id).await {
+                Ok(report) => ActivityUpdate::Loaded(report),
+                Err(e) => ActivityUpdate::Failed(e),
+            };
+            let _ = update_tx.send(update);
+        });
+    }
+
+    fn poll_updates(&mut self) {
+        while let Ok(update) = self.update_rx.try_recv() {
+            match update {
+                ActivityUpdate::Loaded(report) => self.page.set_report(report),
+                ActivityUpdate::Failed(message) => self.page.set_error(message),
+            }
+        }
+    }
+}
+
+impl PageState for UserActivityPageState {
+    fn route(&self) -> Route {
+        Route::UserActivity
+    }
+
+    fn run(
+        &mut self,
+        terminal: &mut DefaultTerminal,
+        controllers: &Controllers,
+    ) -> AdapterResult<NavAction> {
+        if !self.requested {
+            self.load(controllers);
+        }
+
+        loop {
+            self.poll_updates();
+
+            terminal
+                .draw(|frame| render_guarded(frame, |frame| self.page.render(frame)))
+                .map_err(|e| crate::error::AdapterError::RenderingFailed(e.to_string()))?;
+
+            if controllers.session.lock_if_idle() {
+                return Ok(NavAction::Go(Route::Login));
+            }
+
+            if controllers.session.poll_input(std::time::Duration::from_millis(100))?
+                && let Event::Key(key) =
+                    event::read().map_err(crate::error::AdapterError::EventReadFailed)?
+            {
+                if key.kind != KeyEventKind::Press {
+                    continue;
+                }
+
+                match key.code {
+                    KeyCode::Esc => return Ok(NavAction::Back),
+                    KeyCode::Char('r') => self.load(controllers),
+                    _ => {}
+                }
+            }
+        }
+    }
+}
+
+impl Default for UserActivityPageState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
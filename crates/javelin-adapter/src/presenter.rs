@@ -3,44 +3,92 @@
 // 禁止: 業務判断
 
 pub mod account_master_presenter;
+pub mod accounting_policy_presenter;
 pub mod application_settings_presenter;
 pub mod batch_history_presenter;
+pub mod calendar_master_presenter;
+pub mod closing_timetable_presenter;
 pub mod company_master_presenter;
+pub mod consistency_check_presenter;
+pub mod dimension_master_presenter;
+pub mod inbox_presenter;
 pub mod journal_entry_presenter;
+pub mod kpi_dashboard_presenter;
 pub mod ledger_presenter;
+pub mod management_account_mapping_presenter;
+pub mod master_change_presenter;
+pub mod note_cross_reference_presenter;
+pub mod progress_channel;
 pub mod search_presenter;
+pub mod sequence_audit_presenter;
+pub mod statement_line_mapping_presenter;
+pub mod storage_trend_presenter;
 pub mod subsidiary_account_master_presenter;
+pub mod suspense_aging_presenter;
+pub mod warm_up_presenter;
 
 pub use account_master_presenter::{
     AccountMasterItemViewModel, AccountMasterPresenter, AccountMasterViewModel,
 };
+pub use accounting_policy_presenter::{
+    AccountingPolicyRowViewModel, AccountingPolicySetting, AccountingPolicyViewModel,
+};
 pub use application_settings_presenter::{
     ApplicationSettingsPresenter, ApplicationSettingsViewModel,
 };
 pub use batch_history_presenter::{
     BatchHistoryChannels, BatchHistoryPresenter, BatchHistoryViewModel,
 };
+pub use calendar_master_presenter::{
+    CalendarMasterPresenter, CalendarMasterViewModel, HolidayViewModel,
+};
+pub use closing_timetable_presenter::{ClosingTimetableItemViewModel, ClosingTimetableViewModel};
 pub use company_master_presenter::{
     CompanyMasterItemViewModel, CompanyMasterPresenter, CompanyMasterViewModel,
 };
+pub use consistency_check_presenter::{ConsistencyCheckViewModel, ConsistencyViolationViewModel};
+pub use dimension_master_presenter::{DimensionMasterItemViewModel, DimensionMasterViewModel};
+pub use inbox_presenter::{InboxItemViewModel, InboxLineViewModel, InboxViewModel};
 use javelin_application::output_port::{EventNotification, EventOutputPort};
 pub use journal_entry_presenter::{
-    JournalEntryDetailViewModel, JournalEntryLineViewModel, JournalEntryListItemViewModel,
-    JournalEntryListViewModel, JournalEntryPresenter, JournalEntryViewModel,
+    JournalActionViewModel, JournalEntryDetailViewModel, JournalEntryLineViewModel,
+    JournalEntryListItemViewModel, JournalEntryListViewModel, JournalEntryPresenter,
+    JournalEntryViewModel,
 };
+pub use kpi_dashboard_presenter::{KPI_TREND_DAYS, KpiCardViewModel, KpiDashboardViewModel};
 pub use ledger_presenter::{
-    LedgerEntryViewModel, LedgerPresenter, LedgerViewModel, TrialBalanceEntryViewModel,
-    TrialBalanceViewModel,
+    EntryHistoryViewModel, EntryLineDiffViewModel, EntryLineSideViewModel, LedgerEntryViewModel,
+    LedgerPresenter, LedgerViewModel, ManagementTrialBalanceViewModel, TrialBalanceClassViewModel,
+    TrialBalanceEntryViewModel, TrialBalanceGroupViewModel, TrialBalanceProofViewModel,
+    TrialBalanceRow, TrialBalanceViewModel,
+};
+pub use management_account_mapping_presenter::{
+    ManagementAccountMappingItemViewModel, ManagementAccountMappingViewModel,
+};
+pub use master_change_presenter::{MasterChangeHistoryViewModel, MasterChangeItemViewModel};
+pub use note_cross_reference_presenter::{NoteCrossReferenceViewModel, NoteSectionViewModel};
+pub use progress_channel::{
+    PROGRESS_CHANNEL_CAPACITY, ProgressChannelStats, ProgressReceiver, ProgressSender,
+    progress_channel, progress_channel_stats,
 };
 pub use search_presenter::{
-    JournalEntryItemViewModel, JournalEntryLineItemViewModel, SearchChannels, SearchPresenter,
-    SearchResultViewModel,
+    JournalEntryItemViewModel, JournalEntryLineItemViewModel, SearchChannels, SearchGroupViewModel,
+    SearchPresenter, SearchResultViewModel,
+};
+pub use sequence_audit_presenter::{
+    SequenceAuditEntryViewModel, SequenceAuditViewModel, SequenceFindingViewModel,
+};
+pub use statement_line_mapping_presenter::{
+    StatementLineMappingItemViewModel, StatementLineMappingViewModel,
 };
+pub use storage_trend_presenter::StorageTrendViewModel;
 pub use subsidiary_account_master_presenter::{
     SubsidiaryAccountMasterItemViewModel, SubsidiaryAccountMasterPresenter,
     SubsidiaryAccountMasterViewModel,
 };
+pub use suspense_aging_presenter::{SuspenseAgingItemViewModel, SuspenseAgingViewModel};
 use tokio::sync::mpsc;
+pub use warm_up_presenter::warm_up_message;
 
 /// イベント通知用のチャネル
 pub type EventSender = mpsc::UnboundedSender<EventNotification>;
@@ -19,6 +19,9 @@ pub struct AccountMasterItemViewModel {
     pub name: String,
     pub account_type: String,
     pub account_type_label: String,
+    pub is_active: bool,
+    /// 有効・無効の表示
+    pub status_label: String,
 }
 
 /// 勘定科目マスタPresenter
@@ -64,6 +67,8 @@ impl AccountMasterOutputPort for AccountMasterPresenter {
                 name: item.name.clone(),
                 account_type: item.account_type.clone(),
                 account_type_label: Self::format_account_type_label(&item.account_type),
+                is_active: item.is_active,
+                status_label: if item.is_active { "有効" } else { "無効" }.to_string(),
             })
             .collect();
 
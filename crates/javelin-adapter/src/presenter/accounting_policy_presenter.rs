@@ -0,0 +1,168 @@
+// AccountingPolicyPresenter - 会計方針設定の表示整形
+// 通貨別端数処理・税額端数処理・負数表示方法・金額マスキングを設定行として整形する
+
+use javelin_application::dtos::response::{
+    AccountingPolicyChangeItem, LoadAccountingPolicyResponse, PolicyOption,
+};
+
+/// 会計方針ViewModel
+#[derive(Debug, Clone, Default)]
+pub struct AccountingPolicyViewModel {
+    pub rows: Vec<AccountingPolicyRowViewModel>,
+    /// 選択可能な端数処理方法（表示順）
+    pub rounding_modes: Vec<PolicyOption>,
+    /// 選択可能な負数表示方法（表示順）
+    pub negative_presentations: Vec<PolicyOption>,
+    pub is_administrator: bool,
+    /// 変更履歴（新しい順）
+    pub changes: Vec<AccountingPolicyChangeItem>,
+}
+
+/// 設定行ViewModel
+#[derive(Debug, Clone)]
+pub struct AccountingPolicyRowViewModel {
+    pub category: String,
+    pub target: String,
+    pub value_label: String,
+    pub setting: AccountingPolicySetting,
+}
+
+/// 設定行が表す設定値
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AccountingPolicySetting {
+    CurrencyRounding {
+        currency: String,
+        decimal_places: u8,
+        rounding_mode: String,
+    },
+    TaxRounding {
+        tax_type: String,
+        rounding_mode: String,
+    },
+    NegativePresentation {
+        code: String,
+    },
+    /// 空白区切りの金額マスキング規則
+    AmountMaskingRules {
+        rules: String,
+    },
+    UserRoles {
+        user_id: String,
+        roles: Vec<String>,
+    },
+}
+
+impl AccountingPolicyViewModel {
+    /// 会計方針取得レスポンスからViewModelを作成
+    pub fn from_response(response: &LoadAccountingPolicyResponse) -> Self {
+        let currency_rows =
+            response.currency_roundings.iter().map(|r| AccountingPolicyRowViewModel {
+                category: "通貨端数処理".to_string(),
+                target: r.currency.clone(),
+                value_label: format!("小数{}桁 {}", r.decimal_places, r.rounding_mode_name),
+                setting: AccountingPolicySetting::CurrencyRounding {
+                    currency: r.currency.clone(),
+                    decimal_places: r.decimal_places,
+                    rounding_mode: r.rounding_mode.clone(),
+                },
+            });
+        let tax_rows = response.tax_roundings.iter().map(|r| AccountingPolicyRowViewModel {
+            category: "税額端数処理".to_string(),
+            target: r.tax_type_name.clone(),
+            value_label: r.rounding_mode_name.clone(),
+            setting: AccountingPolicySetting::TaxRounding {
+                tax_type: r.tax_type.clone(),
+                rounding_mode: r.rounding_mode.clone(),
+            },
+        });
+        let presentation_row = AccountingPolicyRowViewModel {
+            category: "負数表示".to_string(),
+            target: "-".to_string(),
+            value_label: response.negative_presentation.name.clone(),
+            setting: AccountingPolicySetting::NegativePresentation {
+                code: response.negative_presentation.code.clone(),
+            },
+        };
+
+        let masking_rules = response.amount_masking_rules.join(" ");
+        let masking_row = AccountingPolicyRowViewModel {
+            category: "金額マスキング".to_string(),
+            target: "科目範囲".to_string(),
+            value_label: if masking_rules.is_empty() {
+                "なし".to_string()
+            } else {
+                masking_rules.clone()
+            },
+            setting: AccountingPolicySetting::AmountMaskingRules { rules: masking_rules },
+        };
+        let role_rows = response.user_roles.iter().map(|item| AccountingPolicyRowViewModel {
+            category: "ロール割当".to_string(),
+            target: item.user_id.clone(),
+            value_label: item.roles.join(","),
+            setting: AccountingPolicySetting::UserRoles {
+                user_id: item.user_id.clone(),
+                roles: item.roles.clone(),
+            },
+        });
+
+        Self {
+            rows: currency_rows
+                .chain(tax_rows)
+                .chain([presentation_row, masking_row])
+                .chain(role_rows)
+                .collect(),
+            rounding_modes: response.rounding_modes.clone(),
+            negative_presentations: response.negative_presentations.clone(),
+            is_administrator: response.is_administrator,
+            changes: response.changes.clone(),
+        }
+    }
+
+    /// 指定行の方法（端数処理方法または負数表示方法）を選択肢の順で前後に切り替えた設定値
+    pub fn cycled_setting(&self, index: usize, forward: bool) -> Option<AccountingPolicySetting> {
+        let cycle = |options: &[PolicyOption], current: &str| {
+            let len = options.len();
+            let position = options.iter().position(|option| option.code == current)?;
+            let next = if forward {
+                (position + 1) % len
+            } else {
+                (position + len - 1) % len
+            };
+            Some(options[next].code.clone())
+        };
+
+        let mut setting = self.rows.get(index)?.setting.clone();
+        match &mut setting {
+            AccountingPolicySetting::CurrencyRounding { rounding_mode, .. }
+            | AccountingPolicySetting::TaxRounding { rounding_mode, .. } => {
+                *rounding_mode = cycle(&self.rounding_modes, rounding_mode)?;
+            }
+            AccountingPolicySetting::NegativePresentation { code } => {
+                *code = cycle(&self.negative_presentations, code)?;
+            }
+            AccountingPolicySetting::AmountMaskingRules { .. }
+            | AccountingPolicySetting::UserRoles { .. } => return None,
+        }
+        Some(setting)
+    }
+
+    /// 指定行（通貨端数処理）の小数点以下桁数を増減した設定値
+    ///
+    /// 桁数の上限はドメイン側で検証する。
+    pub fn adjusted_decimal_places(
+        &self,
+        index: usize,
+        increase: bool,
+    ) -> Option<AccountingPolicySetting> {
+        let mut setting = self.rows.get(index)?.setting.clone();
+        let AccountingPolicySetting::CurrencyRounding { decimal_places, .. } = &mut setting else {
+            return None;
+        };
+        *decimal_places = if increase {
+            decimal_places.checked_add(1)?
+        } else {
+            decimal_places.checked_sub(1)?
+        };
+        Some(setting)
+    }
+}
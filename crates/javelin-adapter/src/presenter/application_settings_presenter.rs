@@ -2,10 +2,17 @@
 // アプリケーション設定の出力を整形してビューに渡す
 
 use javelin_application::{
-    dtos::response::LoadApplicationSettingsResponse, output_port::ApplicationSettingsOutputPort,
+    dtos::response::{
+        FINANCIAL_STATEMENTS_REPORT_TYPE, LoadApplicationSettingsResponse,
+        TRIAL_BALANCE_REPORT_TYPE,
+    },
+    output_port::ApplicationSettingsOutputPort,
 };
+use javelin_domain::masters::ClosingLockCheck;
 use tokio::sync::mpsc;
 
+use crate::notification::BatchNotifier;
+
 /// アプリケーション設定ViewModel
 #[derive(Debug, Clone)]
 pub struct ApplicationSettingsViewModel {
@@ -14,12 +21,34 @@ pub struct ApplicationSettingsViewModel {
     pub language_label: String,
     pub decimal_places: u8,
     pub date_format: String,
+    pub batch_notification_bell: bool,
+    pub batch_notification_bell_label: String,
+    pub batch_notification_desktop: bool,
+    pub batch_notification_desktop_label: String,
     pub fiscal_year_start_month: u8,
     pub fiscal_year_start_month_label: String,
     pub closing_day: u8,
     pub auto_backup_enabled: bool,
     pub auto_backup_label: String,
     pub backup_retention_days: u32,
+    pub approval_sla_warning_hours: u32,
+    pub approval_sla_breach_hours: u32,
+    pub approval_sla_label: String,
+    /// 試算表の配信先（URI形式）
+    pub trial_balance_delivery: Vec<String>,
+    pub trial_balance_delivery_label: String,
+    /// 財務諸表の配信先（URI形式）
+    pub financial_statements_delivery: Vec<String>,
+    pub financial_statements_delivery_label: String,
+    pub export_encryption_enabled: bool,
+    pub export_signature_enabled: bool,
+    pub export_protection_label: String,
+    pub deleted_entry_retain_enabled: bool,
+    pub deleted_entry_purge_after_months: u32,
+    pub deleted_entry_retention_label: String,
+    /// 締日固定前に検証しない項目（検証項目コード）
+    pub closing_lock_disabled_checks: Vec<String>,
+    pub closing_lock_validation_label: String,
 }
 
 /// アプリケーション設定Presenter
@@ -54,34 +83,139 @@ impl ApplicationSettingsPresenter {
         format!("{}月", month)
     }
 
-    fn format_backup_label(enabled: bool) -> String {
+    fn format_desktop_notification_label(enabled: bool) -> String {
+        if !BatchNotifier::desktop_supported() {
+            "未対応（desktop-notification フィーチャ無効）".to_string()
+        } else {
+            Self::format_enabled_label(enabled)
+        }
+    }
+
+    fn format_enabled_label(enabled: bool) -> String {
         if enabled {
             "有効".to_string()
         } else {
             "無効".to_string()
         }
     }
+
+    fn format_delivery_label(destinations: &[String]) -> String {
+        if destinations.is_empty() {
+            "配信しない".to_string()
+        } else {
+            destinations.join("  ")
+        }
+    }
+
+    fn format_export_protection_label(encryption_enabled: bool, signature_enabled: bool) -> String {
+        match (encryption_enabled, signature_enabled) {
+            (false, false) => "保護しない".to_string(),
+            (true, false) => "パスワード付きZIP".to_string(),
+            (false, true) => "分離署名".to_string(),
+            (true, true) => "パスワード付きZIP + 分離署名".to_string(),
+        }
+    }
+
+    fn format_deleted_entry_retention_label(
+        retain_enabled: bool,
+        purge_after_months: u32,
+    ) -> String {
+        if retain_enabled {
+            format!("保持する（{}か月後に物理削除）", purge_after_months)
+        } else {
+            "保持しない".to_string()
+        }
+    }
+
+    fn format_closing_lock_validation_label(disabled_checks: &[String]) -> String {
+        ClosingLockCheck::ALL
+            .iter()
+            .enumerate()
+            .map(|(index, check)| {
+                let mark = if disabled_checks.iter().any(|code| code == check.code()) {
+                    "－"
+                } else {
+                    "✓"
+                };
+                format!("[{}]{} {}", index + 1, mark, check.display_name())
+            })
+            .collect::<Vec<_>>()
+            .join("  ")
+    }
 }
 
 #[allow(async_fn_in_trait)]
 impl ApplicationSettingsOutputPort for ApplicationSettingsPresenter {
     async fn present_application_settings(&self, response: &LoadApplicationSettingsResponse) {
+        let delivery = |report_type: &str| {
+            response
+                .system_settings
+                .report_delivery
+                .get(report_type)
+                .cloned()
+                .unwrap_or_default()
+        };
+        let trial_balance_delivery = delivery(TRIAL_BALANCE_REPORT_TYPE);
+        let financial_statements_delivery = delivery(FINANCIAL_STATEMENTS_REPORT_TYPE);
+
         let view_model = ApplicationSettingsViewModel {
             default_company_code: response.user_options.default_company_code.clone(),
             language: response.user_options.language.clone(),
             language_label: Self::format_language_label(&response.user_options.language),
             decimal_places: response.user_options.decimal_places,
             date_format: response.user_options.date_format.clone(),
+            batch_notification_bell: response.user_options.batch_notification_bell,
+            batch_notification_bell_label: Self::format_enabled_label(
+                response.user_options.batch_notification_bell,
+            ),
+            batch_notification_desktop: response.user_options.batch_notification_desktop,
+            batch_notification_desktop_label: Self::format_desktop_notification_label(
+                response.user_options.batch_notification_desktop,
+            ),
             fiscal_year_start_month: response.system_settings.fiscal_year_start_month,
             fiscal_year_start_month_label: Self::format_month_label(
                 response.system_settings.fiscal_year_start_month,
             ),
             closing_day: response.system_settings.closing_day,
             auto_backup_enabled: response.system_settings.auto_backup_enabled,
-            auto_backup_label: Self::format_backup_label(
+            auto_backup_label: Self::format_enabled_label(
                 response.system_settings.auto_backup_enabled,
             ),
             backup_retention_days: response.system_settings.backup_retention_days,
+            approval_sla_warning_hours: response.system_settings.approval_sla_warning_hours,
+            approval_sla_breach_hours: response.system_settings.approval_sla_breach_hours,
+            approval_sla_label: format!(
+                "注意 {}時間 / 超過 {}時間",
+                response.system_settings.approval_sla_warning_hours,
+                response.system_settings.approval_sla_breach_hours
+            ),
+            trial_balance_delivery_label: Self::format_delivery_label(&trial_balance_delivery),
+            trial_balance_delivery,
+            financial_statements_delivery_label: Self::format_delivery_label(
+                &financial_statements_delivery,
+            ),
+            financial_statements_delivery,
+            export_encryption_enabled: response.system_settings.export_encryption_enabled,
+            export_signature_enabled: response.system_settings.export_signature_enabled,
+            export_protection_label: Self::format_export_protection_label(
+                response.system_settings.export_encryption_enabled,
+                response.system_settings.export_signature_enabled,
+            ),
+            deleted_entry_retain_enabled: response.system_settings.deleted_entry_retain_enabled,
+            deleted_entry_purge_after_months: response
+                .system_settings
+                .deleted_entry_purge_after_months,
+            deleted_entry_retention_label: Self::format_deleted_entry_retention_label(
+                response.system_settings.deleted_entry_retain_enabled,
+                response.system_settings.deleted_entry_purge_after_months,
+            ),
+            closing_lock_validation_label: Self::format_closing_lock_validation_label(
+                &response.system_settings.closing_lock_disabled_checks,
+            ),
+            closing_lock_disabled_checks: response
+                .system_settings
+                .closing_lock_disabled_checks
+                .clone(),
         };
 
         let _ = self.sender.send(view_model);
@@ -0,0 +1,65 @@
+// CalendarMasterPresenter実装
+// カレンダーマスタの出力を整形してビューに渡す
+
+use chrono::NaiveDate;
+use javelin_application::{
+    dtos::response::LoadCalendarMasterResponse, output_port::CalendarMasterOutputPort,
+};
+use tokio::sync::mpsc;
+
+/// カレンダーマスタViewModel
+#[derive(Debug, Clone)]
+pub struct CalendarMasterViewModel {
+    pub holidays: Vec<HolidayViewModel>,
+    pub fiscal_year_start_month: u32,
+}
+
+/// 休日ViewModel
+#[derive(Debug, Clone)]
+pub struct HolidayViewModel {
+    pub date: NaiveDate,
+    pub name: String,
+}
+
+/// カレンダーマスタPresenter
+#[derive(Clone)]
+pub struct CalendarMasterPresenter {
+    sender: mpsc::UnboundedSender<CalendarMasterViewModel>,
+}
+
+impl CalendarMasterPresenter {
+    pub fn new(sender: mpsc::UnboundedSender<CalendarMasterViewModel>) -> Self {
+        Self { sender }
+    }
+
+    /// チャネルを作成
+    pub fn create_channel() -> (
+        mpsc::UnboundedSender<CalendarMasterViewModel>,
+        mpsc::UnboundedReceiver<CalendarMasterViewModel>,
+    ) {
+        mpsc::unbounded_channel()
+    }
+}
+
+#[allow(async_fn_in_trait)]
+impl CalendarMasterOutputPort for CalendarMasterPresenter {
+    async fn present_calendar_master(&self, response: &LoadCalendarMasterResponse) {
+        // 日付として解釈できない休日は表示対象外
+        let holidays = response
+            .holidays
+            .iter()
+            .filter_map(|item| {
+                NaiveDate::parse_from_str(&item.date, "%Y-%m-%d")
+                    .ok()
+                    .map(|date| HolidayViewModel { date, name: item.name.clone() })
+            })
+            .collect();
+
+        let view_model = CalendarMasterViewModel {
+            holidays,
+            fiscal_year_start_month: response.fiscal_year_start_month as u32,
+        };
+
+        let _ = self.sender.send(view_model);
+    }
+}
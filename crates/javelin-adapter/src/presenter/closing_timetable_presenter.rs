@@ -0,0 +1,91 @@
+// ClosingTimetablePresenter - 締め進捗ボードの表示整形
+// 締めタスクの期日・残り日数・進捗をビュー向けに整形する
+
+use javelin_application::dtos::response::ClosingTimetableBoardResponse;
+use javelin_domain::masters::ClosingTaskStatus;
+
+/// 締め進捗ボードViewModel
+#[derive(Debug, Clone, Default)]
+pub struct ClosingTimetableViewModel {
+    /// 対象期間（例: `2024-04（期末日 2024-04-30）`）
+    pub period_label: String,
+    /// 進捗の要約（例: `完了 3/5件 期日超過 1件`）
+    pub summary: String,
+    pub has_overdue: bool,
+    pub items: Vec<ClosingTimetableItemViewModel>,
+}
+
+/// 締めタスクViewModel
+#[derive(Debug, Clone)]
+pub struct ClosingTimetableItemViewModel {
+    pub code: String,
+    pub task: String,
+    pub owner: String,
+    pub due_date: String,
+    pub step_label: String,
+    /// 進捗状態（英語名）
+    pub status: String,
+    pub status_label: String,
+    /// 進捗を切り替えた場合の次の状態（英語名・表示名）
+    pub next_status: String,
+    pub next_status_label: String,
+    /// 残り日数の表示（`あと2日` / `本日期限` / `1日超過` / `完了`）
+    pub countdown: String,
+    pub is_overdue: bool,
+    pub is_completed: bool,
+    pub updated_by: String,
+    /// 編集欄の初期値（`<コード>:<タスク>:<担当>:<期日>:<工程>`）
+    pub spec: String,
+}
+
+impl ClosingTimetableViewModel {
+    /// 進捗ボードレスポンスからViewModelを作成
+    pub fn from_response(response: &ClosingTimetableBoardResponse) -> Self {
+        let items = response
+            .items
+            .iter()
+            .map(|item| {
+                let next = ClosingTaskStatus::parse(&item.status).unwrap_or_default().next();
+                ClosingTimetableItemViewModel {
+                    code: item.code.clone(),
+                    task: item.task.clone(),
+                    owner: item.owner.clone(),
+                    due_date: item.due_date.clone(),
+                    step_label: item.step_label.clone(),
+                    status: item.status.clone(),
+                    status_label: item.status_label.clone(),
+                    next_status: next.as_str().to_string(),
+                    next_status_label: next.label().to_string(),
+                    countdown: match item.days_remaining {
+                        None => "完了".to_string(),
+                        Some(0) => "本日期限".to_string(),
+                        Some(days) if days > 0 => format!("あと{}日", days),
+                        Some(days) => format!("{}日超過", -days),
+                    },
+                    is_overdue: item.is_overdue,
+                    is_completed: item.days_remaining.is_none(),
+                    updated_by: item.updated_by.clone().unwrap_or_default(),
+                    spec: format!(
+                        "{}:{}:{}:{}:{}",
+                        item.code, item.task, item.owner, item.due_offset_days, item.step_label
+                    ),
+                }
+            })
+            .collect();
+
+        let mut summary = format!("完了 {}/{}件", response.completed_count, response.items.len());
+        if response.overdue_count > 0 {
+            summary.push_str(&format!("  期日超過 {}件", response.overdue_count));
+        }
+
+        Self {
+            period_label: format!(
+                "{}-{:02}（期末日 {}）",
+                response.fiscal_year, response.period, response.period_end
+            ),
+            summary,
+            has_overdue: response.overdue_count > 0,
+            items,
+        }
+    }
+}
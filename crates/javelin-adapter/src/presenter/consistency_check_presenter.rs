@@ -0,0 +1,48 @@
+// ConsistencyCheckPresenter - Projection間整合性チェック結果の表示整形
+
+use javelin_application::dtos::response::ConsistencyCheckResponse;
+
+/// 整合性チェック結果ViewModel
+#[derive(Debug, Clone, Default)]
+pub struct ConsistencyCheckViewModel {
+    pub violations: Vec<ConsistencyViolationViewModel>,
+    /// 検証件数の要約
+    pub summary: String,
+}
+
+/// 整合性違反ViewModel
+#[derive(Debug, Clone)]
+pub struct ConsistencyViolationViewModel {
+    pub kind_label: String,
+    pub subject: String,
+    pub detail: String,
+    pub suggestion: String,
+}
+
+impl ConsistencyCheckViewModel {
+    pub fn from_response(response: &ConsistencyCheckResponse) -> Self {
+        let violations = response
+            .violations
+            .iter()
+            .map(|v| ConsistencyViolationViewModel {
+                kind_label: v.kind.label().to_string(),
+                subject: v.subject.clone(),
+                detail: v.detail.clone(),
+                suggestion: v.suggestion.clone(),
+            })
+            .collect();
+
+        let summary = format!(
+            "仕訳 {}件・勘定科目 {}件を検証 / 違反 {}件",
+            response.checked_entries,
+            response.checked_accounts,
+            response.violations.len()
+        );
+
+        Self { violations, summary }
+    }
+
+    pub fn is_consistent(&self) -> bool {
+        self.violations.is_empty()
+    }
+}
@@ -0,0 +1,42 @@
+// DimensionMasterPresenter - 分析軸マスタの表示整形
+// 分析軸の一覧をビュー向けに整形する
+
+use javelin_application::dtos::response::LoadDimensionMastersResponse;
+
+/// 分析軸マスタViewModel
+#[derive(Debug, Clone, Default)]
+pub struct DimensionMasterViewModel {
+    pub items: Vec<DimensionMasterItemViewModel>,
+}
+
+/// 分析軸項目ViewModel
+#[derive(Debug, Clone)]
+pub struct DimensionMasterItemViewModel {
+    pub kind: String,
+    pub code: String,
+    pub name: String,
+    pub is_active: bool,
+    pub status_label: String,
+    /// 編集欄の初期値（`<種類>:<コード>:<名称>`）
+    pub spec: String,
+}
+
+impl DimensionMasterViewModel {
+    /// 分析軸一覧レスポンスからViewModelを作成
+    pub fn from_response(response: &LoadDimensionMastersResponse) -> Self {
+        let items = response
+            .items
+            .iter()
+            .map(|item| DimensionMasterItemViewModel {
+                kind: item.kind.clone(),
+                code: item.code.clone(),
+                name: item.name.clone(),
+                is_active: item.is_active,
+                status_label: if item.is_active { "有効" } else { "無効" }.to_string(),
+                spec: format!("{}:{}:{}", item.kind, item.code, item.name),
+            })
+            .collect();
+
+        Self { items }
+    }
+}
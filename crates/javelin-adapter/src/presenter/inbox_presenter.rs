@@ -0,0 +1,171 @@
+// InboxPresenter - 受信箱の表示整形
+
+use chrono::{DateTime, Duration, Utc};
+use javelin_application::query_service::{ApprovalSlaSummary, InboxItem, InboxItemKind};
+use javelin_domain::masters::{ApprovalAging, ApprovalSlaSettings};
+
+use crate::presenter::JournalActionViewModel;
+
+/// 受信箱ViewModel
+#[derive(Debug, Clone, Default)]
+pub struct InboxViewModel {
+    pub items: Vec<InboxItemViewModel>,
+    /// 承認待ちのSLA集計
+    pub summary: ApprovalSlaSummary,
+    /// 承認SLAの閾値（表示用）
+    pub sla_label: String,
+}
+
+/// 受信箱項目ViewModel
+#[derive(Debug, Clone)]
+pub struct InboxItemViewModel {
+    pub kind: InboxItemKind,
+    pub kind_label: String,
+    pub entry_id: String,
+    pub voucher_number: String,
+    pub transaction_date: String,
+    pub from_user: String,
+    pub reason: Option<String>,
+    /// 申請・差戻し日時（表示用）
+    pub occurred_at: String,
+    /// 承認待ちの滞留状況（承認待ち以外はNone）
+    pub aging: Option<ApprovalAging>,
+    /// 承認待ちの経過時間（表示用、承認待ち以外は空）
+    pub aging_label: String,
+    pub lines: Vec<InboxLineViewModel>,
+    /// 借方合計
+    pub total_amount: f64,
+    /// 仕訳に対して可能な操作
+    pub actions: Vec<JournalActionViewModel>,
+}
+
+/// 受信箱項目の明細ViewModel
+#[derive(Debug, Clone)]
+pub struct InboxLineViewModel {
+    pub side_label: String,
+    pub account_code: String,
+    pub amount: f64,
+}
+
+impl InboxViewModel {
+    /// 受信箱項目を承認SLAで判定して整形
+    pub fn from_items(items: &[InboxItem], sla: ApprovalSlaSettings, now: DateTime<Utc>) -> Self {
+        Self {
+            items: items.iter().map(|item| InboxItemViewModel::from_item(item, sla, now)).collect(),
+            summary: ApprovalSlaSummary::from_items(items, sla, now),
+            sla_label: format!(
+                "注意 {}時間 / 超過 {}時間",
+                sla.warning_hours(),
+                sla.breach_hours()
+            ),
+        }
+    }
+}
+
+impl InboxItemViewModel {
+    pub fn from_item(item: &InboxItem, sla: ApprovalSlaSettings, now: DateTime<Utc>) -> Self {
+        let occurred_at = crate::clock::format_timestamp_in(
+            &item.occurred_at,
+            crate::clock::time_zone(),
+            "%Y-%m-%d %H:%M",
+        );
+
+        let lines: Vec<InboxLineViewModel> = item
+            .lines
+            .iter()
+            .map(|line| InboxLineViewModel {
+                side_label: if line.side == "Debit" {
+                    "借方"
+                } else {
+                    "貸方"
+                }
+                .to_string(),
+                account_code: line.account_code.clone(),
+                amount: line.amount,
+            })
+            .collect();
+        let total_amount = item
+            .lines
+            .iter()
+            .filter(|line| line.side == "Debit")
+            .map(|line| line.amount)
+            .sum();
+
+        Self {
+            kind: item.kind,
+            kind_label: item.kind.label().to_string(),
+            entry_id: item.entry_id.clone(),
+            voucher_number: item.voucher_number.clone(),
+            transaction_date: item.transaction_date.clone(),
+            from_user: item.from_user.clone(),
+            reason: item.reason.clone(),
+            occurred_at,
+            aging: item.approval_aging(sla, now),
+            aging_label: item.pending_duration(now).map(format_elapsed).unwrap_or_default(),
+            lines,
+            total_amount,
+            actions: JournalActionViewModel::from_affordances(&item.actions),
+        }
+    }
+}
+
+/// 経過時間を「1日4時間」「3時間」「45分」の形式で表示
+fn format_elapsed(elapsed: Duration) -> String {
+    let days = elapsed.num_days();
+    let hours = elapsed.num_hours() % 24;
+    if days > 0 {
+        format!("{}日{}時間", days, hours)
+    } else if hours > 0 {
+        format!("{}時間", hours)
+    } else {
+        format!("{}分", elapsed.num_minutes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_aging_label_and_summary() {
+        let now = DateTime::parse_from_rfc3339("2024-04-03T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let item = |kind: InboxItemKind, occurred_at: &str| InboxItem {
+            kind,
+            entry_id: "entry-1".to_string(),
+            voucher_number: "V-0001".to_string(),
+            transaction_date: "2024-04-01".to_string(),
+            from_user: "user1".to_string(),
+            reason: None,
+            occurred_at: occurred_at.to_string(),
+            lines: vec![],
+            actions: javelin_application::dtos::response::JournalActionAffordance::for_status(
+                kind.status(),
+            ),
+        };
+        let items = vec![
+            item(InboxItemKind::AwaitingApproval, "2024-04-03T11:15:00Z"),
+            item(InboxItemKind::AwaitingApproval, "2024-04-01T08:00:00Z"),
+            item(InboxItemKind::Rejected, "2024-04-01T08:00:00Z"),
+        ];
+
+        let view_model = InboxViewModel::from_items(&items, ApprovalSlaSettings::default(), now);
+
+        assert_eq!(view_model.items[0].aging_label, "45分");
+        assert_eq!(view_model.items[0].aging, Some(ApprovalAging::WithinSla));
+        assert_eq!(view_model.items[1].aging_label, "2日4時間");
+        assert_eq!(view_model.items[1].aging, Some(ApprovalAging::Breached));
+        assert_eq!(view_model.items[2].aging, None);
+        assert!(view_model.items[2].aging_label.is_empty());
+        assert_eq!(view_model.summary.breached, 1);
+        assert_eq!(view_model.sla_label, "注意 24時間 / 超過 48時間");
+
+        // 差し戻された下書きは承認できず、承認申請できる
+        let actions = &view_model.items[2].actions;
+        let approve = actions.iter().find(|action| action.label == "承認").unwrap();
+        assert!(!approve.enabled);
+        assert_eq!(approve.reason, "下書きの仕訳は承認できません（承認待ちのみ）");
+        assert!(actions.iter().any(|action| action.label == "承認申請" && action.enabled));
+    }
+}
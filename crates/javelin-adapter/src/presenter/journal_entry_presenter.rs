@@ -1,17 +1,24 @@
 // JournalEntryPresenter実装
 // 仕訳登録の出力を整形してビューに渡す
 
+use std::collections::BTreeMap;
+
 use javelin_application::{
     dtos::{
         ApproveJournalEntryResponse, CorrectJournalEntryResponse, DeleteDraftJournalEntryResponse,
-        JournalEntryDetail, JournalEntryListResult, RegisterJournalEntryResponse,
-        RejectJournalEntryResponse, ReverseJournalEntryResponse, SubmitForApprovalResponse,
-        UpdateDraftJournalEntryResponse,
+        JournalActionAffordance, JournalEntryDetail, JournalEntryListResult,
+        RegisterJournalEntryResponse, RejectJournalEntryResponse, ReverseJournalEntryResponse,
+        SubmitForApprovalResponse, UpdateDraftJournalEntryResponse,
     },
     output_port::{JournalEntryOutputPort, QueryOutputPort},
 };
+use javelin_domain::financial_close::journal_entry::values::JournalAction;
 use tokio::sync::mpsc;
 
+use crate::presenter::{
+    PROGRESS_CHANNEL_CAPACITY, ProgressReceiver, ProgressSender, progress_channel,
+};
+
 /// 仕訳一覧ViewModel
 #[derive(Debug, Clone)]
 pub struct JournalEntryListViewModel {
@@ -50,6 +57,32 @@ pub struct JournalEntryDetailViewModel {
     pub updated_at: Option<String>,
     pub approved_by: Option<String>,
     pub approved_at: Option<String>,
+    /// 現在のステータスで可能な操作
+    pub actions: Vec<JournalActionViewModel>,
+}
+
+/// 仕訳に対する操作ViewModel（不可の操作は理由と共に無効表示する）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JournalActionViewModel {
+    pub action: JournalAction,
+    pub label: String,
+    pub enabled: bool,
+    /// 無効の理由（有効な場合は空）
+    pub reason: String,
+}
+
+impl JournalActionViewModel {
+    pub fn from_affordances(actions: &[JournalActionAffordance]) -> Vec<Self> {
+        actions
+            .iter()
+            .map(|action| Self {
+                action: action.action,
+                label: action.label.clone(),
+                enabled: action.allowed,
+                reason: action.reason.clone().unwrap_or_default(),
+            })
+            .collect()
+    }
 }
 
 /// 仕訳明細ViewModel
@@ -62,6 +95,7 @@ pub struct JournalEntryLineViewModel {
     pub account_name: String,
     pub sub_account_code: Option<String>,
     pub department_code: Option<String>,
+    pub dimensions: BTreeMap<String, String>,
     pub amount: f64,
     pub currency: String,
     pub tax_type: String,
@@ -123,7 +157,7 @@ pub struct JournalEntryPresenter {
     list_sender: mpsc::UnboundedSender<JournalEntryListViewModel>,
     detail_sender: mpsc::UnboundedSender<JournalEntryDetailViewModel>,
     result_sender: mpsc::UnboundedSender<JournalEntryViewModel>,
-    progress_sender: mpsc::UnboundedSender<String>,
+    progress_sender: ProgressSender,
 }
 
 /// チャネル作成の戻り値型
@@ -134,8 +168,8 @@ pub type JournalEntryChannels = (
     mpsc::UnboundedReceiver<JournalEntryDetailViewModel>,
     mpsc::UnboundedSender<JournalEntryViewModel>,
     mpsc::UnboundedReceiver<JournalEntryViewModel>,
-    mpsc::UnboundedSender<String>,
-    mpsc::UnboundedReceiver<String>,
+    ProgressSender,
+    ProgressReceiver,
 );
 
 impl JournalEntryPresenter {
@@ -143,7 +177,7 @@ impl JournalEntryPresenter {
         list_sender: mpsc::UnboundedSender<JournalEntryListViewModel>,
         detail_sender: mpsc::UnboundedSender<JournalEntryDetailViewModel>,
         result_sender: mpsc::UnboundedSender<JournalEntryViewModel>,
-        progress_sender: mpsc::UnboundedSender<String>,
+        progress_sender: ProgressSender,
     ) -> Self {
         Self { list_sender, detail_sender, result_sender, progress_sender }
     }
@@ -153,7 +187,7 @@ impl JournalEntryPresenter {
         let (list_tx, list_rx) = mpsc::unbounded_channel();
         let (detail_tx, detail_rx) = mpsc::unbounded_channel();
         let (result_tx, result_rx) = mpsc::unbounded_channel();
-        let (progress_tx, progress_rx) = mpsc::unbounded_channel();
+        let (progress_tx, progress_rx) = progress_channel(PROGRESS_CHANNEL_CAPACITY);
         (
             list_tx,
             list_rx,
@@ -237,6 +271,7 @@ impl QueryOutputPort for JournalEntryPresenter {
                 account_name: line.account_name,
                 sub_account_code: line.sub_account_code,
                 department_code: line.department_code,
+                dimensions: line.dimensions,
                 amount: line.amount,
                 currency: line.currency,
                 tax_type: line.tax_type,
@@ -258,6 +293,7 @@ impl QueryOutputPort for JournalEntryPresenter {
             updated_at: result.updated_at,
             approved_by: result.approved_by,
             approved_at: result.approved_at,
+            actions: JournalActionViewModel::from_affordances(&result.actions),
         };
 
         let _ = self.detail_sender.send(view_model);
@@ -288,7 +324,7 @@ impl JournalEntryOutputPort for JournalEntryPresenter {
     }
 
     async fn notify_progress(&self, message: String) {
-        let _ = self.progress_sender.send(message);
+        self.progress_sender.send(message);
     }
 
     async fn notify_error(&self, error_message: String) {
@@ -0,0 +1,150 @@
+// KpiDashboardPresenter - 業務指標の日次集計の表示整形
+// 指標ごとに日次件数の系列と直近の件数・所要時間の要約に整形する
+
+use chrono::{NaiveDate, TimeDelta};
+use javelin_application::business_metrics::{BusinessMetric, DailyMetric};
+
+/// 件数の推移を表示する日数
+pub const KPI_TREND_DAYS: i64 = 14;
+
+/// 所要時間を集計する直近の日数
+const KPI_SUMMARY_DAYS: i64 = 7;
+
+/// 指標ごとのカード
+#[derive(Debug, Clone, PartialEq)]
+pub struct KpiCardViewModel {
+    pub metric: BusinessMetric,
+    /// 日次件数（古い順、記録のない日は0）
+    pub count_series: Vec<u64>,
+    /// 本日の件数と直近7日の件数（例: "本日 3件 / 7日 12件"）
+    pub count_label: String,
+    /// 直近7日の所要時間の平均と最大（例: "承認待ち時間 平均 2時間5分 / 最大 1日3時間"）
+    pub latency_label: String,
+}
+
+/// KPIダッシュボードViewModel
+#[derive(Debug, Clone, Default)]
+pub struct KpiDashboardViewModel {
+    pub cards: Vec<KpiCardViewModel>,
+    /// 集計の期間（例: "2024-04-01 〜 2024-04-14"）
+    pub period_label: String,
+}
+
+impl KpiDashboardViewModel {
+    /// 集計期間の開始日
+    pub fn start_date(today: NaiveDate) -> NaiveDate {
+        today - TimeDelta::days(KPI_TREND_DAYS - 1)
+    }
+
+    pub fn from_daily_metrics(daily_metrics: &[DailyMetric], today: NaiveDate) -> Self {
+        let start = Self::start_date(today);
+        let summary_start = today - TimeDelta::days(KPI_SUMMARY_DAYS - 1);
+
+        let cards = BusinessMetric::ALL
+            .into_iter()
+            .map(|metric| {
+                let days: Vec<&DailyMetric> = daily_metrics
+                    .iter()
+                    .filter(|d| d.metric == metric && d.date >= start && d.date <= today)
+                    .collect();
+
+                let count_series = (0..KPI_TREND_DAYS)
+                    .map(|offset| {
+                        let date = start + TimeDelta::days(offset);
+                        days.iter().find(|d| d.date == date).map_or(0, |d| d.count)
+                    })
+                    .collect();
+
+                let recent: Vec<&DailyMetric> =
+                    days.iter().copied().filter(|d| d.date >= summary_start).collect();
+                let today_count = days.iter().find(|d| d.date == today).map_or(0, |d| d.count);
+                let recent_count: u64 = recent.iter().map(|d| d.count).sum();
+                let total_latency_ms: u64 = recent.iter().map(|d| d.total_latency_ms).sum();
+                let max_latency_ms = recent.iter().map(|d| d.max_latency_ms).max().unwrap_or(0);
+
+                let latency_label = match total_latency_ms.checked_div(recent_count) {
+                    Some(average_ms) => format!(
+                        "{} 平均 {} / 最大 {}",
+                        metric.latency_label(),
+                        format_latency(average_ms),
+                        format_latency(max_latency_ms)
+                    ),
+                    None => format!("{} -", metric.latency_label()),
+                };
+
+                KpiCardViewModel {
+                    metric,
+                    count_series,
+                    count_label: format!(
+                        "本日 {}件 / {}日 {}件",
+                        today_count, KPI_SUMMARY_DAYS, recent_count
+                    ),
+                    latency_label,
+                }
+            })
+            .collect();
+
+        Self {
+            cards,
+            period_label: format!("{} 〜 {}", start.format("%Y-%m-%d"), today.format("%Y-%m-%d")),
+        }
+    }
+}
+
+/// 所要時間を読みやすい単位に整形
+fn format_latency(ms: u64) -> String {
+    let seconds = ms / 1000;
+    match seconds {
+        0 => format!("{}ms", ms),
+        1..60 => format!("{:.1}秒", ms as f64 / 1000.0),
+        60..3600 => format!("{}分{}秒", seconds / 60, seconds % 60),
+        3600..86400 => format!("{}時間{}分", seconds / 3600, seconds % 3600 / 60),
+        _ => format!("{}日{}時間", seconds / 86400, seconds % 86400 / 3600),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn daily(day: u32, metric: BusinessMetric, count: u64, total: u64, max: u64) -> DailyMetric {
+        DailyMetric {
+            date: NaiveDate::from_ymd_opt(2024, 4, day).unwrap(),
+            metric,
+            count,
+            total_latency_ms: total,
+            max_latency_ms: max,
+        }
+    }
+
+    #[test]
+    fn test_from_daily_metrics_fills_series_and_summarizes_recent_days() {
+        let today = NaiveDate::from_ymd_opt(2024, 4, 14).unwrap();
+        let view_model = KpiDashboardViewModel::from_daily_metrics(
+            &[
+                daily(1, BusinessMetric::EntryApproved, 5, 5_000, 2_000),
+                daily(10, BusinessMetric::EntryApproved, 2, 7_200_000, 7_000_000),
+                daily(14, BusinessMetric::EntryApproved, 1, 600_000, 600_000),
+                daily(14, BusinessMetric::EntryRegistered, 3, 120, 60),
+            ],
+            today,
+        );
+
+        assert_eq!(view_model.period_label, "2024-04-01 〜 2024-04-14");
+        assert_eq!(view_model.cards.len(), BusinessMetric::ALL.len());
+
+        let approved = &view_model.cards[1];
+        assert_eq!(approved.metric, BusinessMetric::EntryApproved);
+        assert_eq!(approved.count_series.len(), KPI_TREND_DAYS as usize);
+        assert_eq!(approved.count_series[0], 5);
+        assert_eq!(approved.count_series[9], 2);
+        assert_eq!(approved.count_series[13], 1);
+        // 直近7日（4/8〜4/14）のみ集計する
+        assert_eq!(approved.count_label, "本日 1件 / 7日 3件");
+        assert_eq!(approved.latency_label, "承認待ち時間 平均 43分20秒 / 最大 1時間56分");
+
+        assert_eq!(view_model.cards[0].latency_label, "処理時間 平均 40ms / 最大 60ms");
+        assert_eq!(view_model.cards[2].count_label, "本日 0件 / 7日 0件");
+        assert_eq!(view_model.cards[2].latency_label, "承認待ち時間 -");
+    }
+}
@@ -1,10 +1,23 @@
 // LedgerPresenter実装
 // 元帳・試算表の出力を整形してビューに渡す
+// 利用者のロールで参照できない科目の金額はマスキングする（試算表の合計は保持する）
+
+use std::{collections::BTreeSet, sync::Mutex};
 
 use javelin_application::{
-    dtos::{JournalEntryDetail, JournalEntryListResult},
+    dtos::{
+        CurrencyTrialBalanceDto, GenerateTrialBalanceResponse, JournalEntryDetail,
+        JournalEntryListResult,
+        response::{
+            AmountFormat, AmountMask, MappedTrialBalanceResponse, TrialBalanceProofDto,
+            TrialBalanceSubtotalDto,
+        },
+    },
     output_port::QueryOutputPort,
-    query_service::{LedgerResult, TrialBalanceResult},
+    query_service::{
+        CARRIED_FORWARD_DESCRIPTION, EntryHistoryLine, EntryHistoryResult, LedgerResult,
+        LineChangeKind, TrialBalanceResult,
+    },
 };
 use tokio::sync::mpsc;
 
@@ -18,6 +31,8 @@ pub struct LedgerViewModel {
     pub closing_balance: f64,
     pub total_debit: f64,
     pub total_credit: f64,
+    /// 利用者のロールでは金額を参照できない科目か（金額はすべて0）
+    pub amounts_masked: bool,
 }
 
 /// 元帳明細ViewModel
@@ -26,10 +41,16 @@ pub struct LedgerEntryViewModel {
     pub transaction_date: String,
     pub entry_number: String,
     pub entry_id: String,
+    /// 仕訳内の明細番号
+    pub line_number: u32,
     pub description: String,
     pub debit_amount: f64,
     pub credit_amount: f64,
     pub balance: f64,
+    /// 未解決の注記数
+    pub open_annotations: u32,
+    /// 前月繰越行（仕訳に対応せず、残高のみ表示する）
+    pub carried_forward: bool,
 }
 
 /// 試算表ViewModel
@@ -37,9 +58,336 @@ pub struct LedgerEntryViewModel {
 pub struct TrialBalanceViewModel {
     pub period_year: u32,
     pub period_month: u8,
+    pub currency: String,
     pub entries: Vec<TrialBalanceEntryViewModel>,
     pub total_debit: f64,
     pub total_credit: f64,
+    /// 為替換算差額（表示通貨建て）
+    pub translation_difference: f64,
+    /// 取引通貨別の内訳試算表
+    pub currency_breakdowns: Vec<TrialBalanceViewModel>,
+    /// 承認待ちの仕訳を含む試算か
+    pub include_pending_approval: bool,
+    /// 縦計・横計の検証結果（試算表生成を経由しない表示ではNone）
+    pub proof: Option<TrialBalanceProofViewModel>,
+    /// 勘定科目区分・科目グループ別の小計（空の場合は明細行のみ表示する）
+    pub hierarchy: Vec<TrialBalanceClassViewModel>,
+}
+
+/// 試算表の勘定科目区分ViewModel（資産・負債など）
+#[derive(Debug, Clone)]
+pub struct TrialBalanceClassViewModel {
+    pub name: String,
+    pub groups: Vec<TrialBalanceGroupViewModel>,
+    /// 区分の合計行（科目コードは空欄）
+    pub subtotal: TrialBalanceEntryViewModel,
+}
+
+/// 試算表の科目グループViewModel（流動資産など）
+#[derive(Debug, Clone)]
+pub struct TrialBalanceGroupViewModel {
+    /// 開閉状態のキー（区分名/グループ名）
+    pub key: String,
+    pub name: String,
+    pub account_codes: Vec<String>,
+    /// グループの小計行（科目コードは空欄）
+    pub subtotal: TrialBalanceEntryViewModel,
+}
+
+/// 試算表の表示行（明細行と小計行）
+#[derive(Debug, Clone, Copy)]
+pub enum TrialBalanceRow<'a> {
+    Account(&'a TrialBalanceEntryViewModel),
+    /// 科目グループの小計（折りたたみ中は明細行を省く）
+    GroupSubtotal {
+        key: &'a str,
+        entry: &'a TrialBalanceEntryViewModel,
+        collapsed: bool,
+    },
+    ClassSubtotal(&'a TrialBalanceEntryViewModel),
+}
+
+impl<'a> TrialBalanceRow<'a> {
+    /// 行の金額
+    pub fn entry(&self) -> &'a TrialBalanceEntryViewModel {
+        match self {
+            TrialBalanceRow::Account(entry)
+            | TrialBalanceRow::GroupSubtotal { entry, .. }
+            | TrialBalanceRow::ClassSubtotal(entry) => entry,
+        }
+    }
+
+    /// 明細行の勘定科目（小計行はNone）
+    pub fn account(&self) -> Option<&'a TrialBalanceEntryViewModel> {
+        match self {
+            TrialBalanceRow::Account(entry) => Some(entry),
+            _ => None,
+        }
+    }
+}
+
+/// 管理会計科目体系の試算表ViewModel
+#[derive(Debug, Clone)]
+pub struct ManagementTrialBalanceViewModel {
+    /// 管理会計科目ごとの試算表（検証結果は制度会計の試算表のみのためNone）
+    pub trial_balance: TrialBalanceViewModel,
+    /// 管理会計科目が未割当の勘定科目数
+    pub unmapped_account_count: usize,
+}
+
+impl ManagementTrialBalanceViewModel {
+    /// 集計し直した試算表からViewModelを作成
+    ///
+    /// 集計元の勘定科目のいずれかがマスキング対象の場合は、管理会計科目の金額を隠す。
+    pub fn from_response(
+        period_year: u32,
+        period_month: u8,
+        response: &MappedTrialBalanceResponse,
+        include_pending_approval: bool,
+        amount_mask: &AmountMask,
+    ) -> Self {
+        let entries = response
+            .lines
+            .iter()
+            .map(|line| {
+                let entry = TrialBalanceEntryViewModel {
+                    account_code: line.account_code.clone(),
+                    account_name: if line.mapped {
+                        line.account_name.clone()
+                    } else {
+                        format!("{}（未割当）", line.account_name)
+                    },
+                    opening_balance: line.opening_balance,
+                    debit_amount: line.debit_amount,
+                    credit_amount: line.credit_amount,
+                    closing_balance: line.closing_balance,
+                    amount_masked: false,
+                };
+                if line.source_account_codes.iter().any(|code| amount_mask.is_masked(code)) {
+                    entry.masked()
+                } else {
+                    entry
+                }
+            })
+            .collect();
+
+        Self {
+            trial_balance: TrialBalanceViewModel {
+                period_year,
+                period_month,
+                currency: response.currency.clone(),
+                entries,
+                total_debit: response.total_debit,
+                total_credit: response.total_credit,
+                translation_difference: 0.0,
+                currency_breakdowns: vec![],
+                include_pending_approval,
+                proof: None,
+                hierarchy: vec![],
+            },
+            unmapped_account_count: response.unmapped_account_count,
+        }
+    }
+}
+
+/// 試算表の検証結果ViewModel
+#[derive(Debug, Clone)]
+pub struct TrialBalanceProofViewModel {
+    pub debit_total: f64,
+    pub credit_total: f64,
+    pub difference: f64,
+    pub cross_foot_difference: f64,
+    pub account_count: usize,
+    pub rows_hash: String,
+    pub footed: bool,
+}
+
+impl From<&TrialBalanceProofDto> for TrialBalanceProofViewModel {
+    fn from(proof: &TrialBalanceProofDto) -> Self {
+        Self {
+            debit_total: proof.debit_total,
+            credit_total: proof.credit_total,
+            difference: proof.difference,
+            cross_foot_difference: proof.cross_foot_difference,
+            account_count: proof.account_count,
+            rows_hash: proof.rows_hash.clone(),
+            footed: proof.footed,
+        }
+    }
+}
+
+impl TrialBalanceViewModel {
+    /// 試算表生成レスポンスからViewModelを作成（マスキング対象科目の金額は隠す）
+    pub fn from_response(
+        period_year: u32,
+        period_month: u8,
+        response: &GenerateTrialBalanceResponse,
+        amount_mask: &AmountMask,
+    ) -> Self {
+        // 小計は集計元の科目のいずれかがマスキング対象の場合に金額を隠す
+        let subtotal = |subtotal: &TrialBalanceSubtotalDto, account_codes: &[&String]| {
+            let entry = TrialBalanceEntryViewModel {
+                account_code: String::new(),
+                account_name: subtotal.label.clone(),
+                opening_balance: subtotal.opening_balance,
+                debit_amount: subtotal.debit_amount,
+                credit_amount: subtotal.credit_amount,
+                closing_balance: subtotal.closing_balance,
+                amount_masked: false,
+            };
+            if account_codes.iter().any(|code| amount_mask.is_masked(code)) {
+                entry.masked()
+            } else {
+                entry
+            }
+        };
+        let section = |tb: &CurrencyTrialBalanceDto| TrialBalanceViewModel {
+            period_year,
+            period_month,
+            currency: tb.currency.clone(),
+            entries: tb
+                .lines
+                .iter()
+                .map(|line| {
+                    TrialBalanceEntryViewModel {
+                        account_code: line.account_code.clone(),
+                        account_name: line.account_name.clone(),
+                        opening_balance: line.opening_balance,
+                        debit_amount: line.debit_amount,
+                        credit_amount: line.credit_amount,
+                        closing_balance: line.closing_balance,
+                        amount_masked: false,
+                    }
+                    .masked_by(amount_mask)
+                })
+                .collect(),
+            total_debit: tb.total_debit,
+            total_credit: tb.total_credit,
+            translation_difference: 0.0,
+            currency_breakdowns: vec![],
+            include_pending_approval: response.include_pending_approval,
+            proof: Some(TrialBalanceProofViewModel::from(&tb.proof)),
+            hierarchy: tb
+                .hierarchy
+                .iter()
+                .map(|class| TrialBalanceClassViewModel {
+                    name: class.name.clone(),
+                    groups: class
+                        .groups
+                        .iter()
+                        .map(|group| TrialBalanceGroupViewModel {
+                            key: format!("{}/{}", class.name, group.name),
+                            name: group.name.clone(),
+                            account_codes: group.account_codes.clone(),
+                            subtotal: subtotal(
+                                &group.subtotal,
+                                &group.account_codes.iter().collect::<Vec<_>>(),
+                            ),
+                        })
+                        .collect(),
+                    subtotal: subtotal(
+                        &class.subtotal,
+                        &class
+                            .groups
+                            .iter()
+                            .flat_map(|group| &group.account_codes)
+                            .collect::<Vec<_>>(),
+                    ),
+                })
+                .collect(),
+        };
+
+        let mut view_model = section(&response.presentation_trial_balance);
+        view_model.translation_difference = response.translation_difference;
+        view_model.currency_breakdowns =
+            response.currency_trial_balances.iter().map(section).collect();
+        view_model
+    }
+
+    /// 表示行（区分・グループの順に明細行と小計行を並べる）
+    ///
+    /// `collapsed`
+    /// に含まれるグループ（キーは「区分名/グループ名」）は明細行を省き、小計行のみ返す。
+    /// 小計がない試算表（管理会計科目体系など）は明細行のみ返す。
+    pub fn rows<'a>(&'a self, collapsed: &BTreeSet<String>) -> Vec<TrialBalanceRow<'a>> {
+        if self.hierarchy.is_empty() {
+            return self.entries.iter().map(TrialBalanceRow::Account).collect();
+        }
+        let mut rows = Vec::new();
+        for class in &self.hierarchy {
+            for group in &class.groups {
+                let is_collapsed = collapsed.contains(&group.key);
+                if !is_collapsed {
+                    rows.extend(
+                        self.entries
+                            .iter()
+                            .filter(|entry| group.account_codes.contains(&entry.account_code))
+                            .map(TrialBalanceRow::Account),
+                    );
+                }
+                rows.push(TrialBalanceRow::GroupSubtotal {
+                    key: &group.key,
+                    entry: &group.subtotal,
+                    collapsed: is_collapsed,
+                });
+            }
+            rows.push(TrialBalanceRow::ClassSubtotal(&class.subtotal));
+        }
+        rows
+    }
+
+    /// CSV形式に変換（エクスポート用）
+    ///
+    /// 区分・グループの小計行は科目コードを空欄にして、所属する明細行の後に出力する。
+    /// 金額は会計方針の端数処理・負数表示方法に従って整形する。
+    /// 検証結果がある場合は末尾に検証行を付け、検証値は整形せずに出力する。
+    /// マスキング対象科目の金額は隠し、合計は集計結果のまま出力する。
+    pub fn to_csv(&self, amount_format: &AmountFormat) -> String {
+        let amount = |value: f64| format!("\"{}\"", amount_format.format(value, &self.currency));
+        let mut csv = String::from("科目コード,科目名,通貨,期首残高,借方合計,貸方合計,期末残高\n");
+        for row in self.rows(&BTreeSet::new()) {
+            let entry = row.entry();
+            let entry_amount = |value: f64| {
+                if entry.amount_masked {
+                    AmountMask::MASKED.to_string()
+                } else {
+                    amount(value)
+                }
+            };
+            csv.push_str(&format!(
+                "{},\"{}\",{},{},{},{},{}\n",
+                entry.account_code,
+                entry.account_name.replace('"', "\"\""),
+                self.currency,
+                entry_amount(entry.opening_balance),
+                entry_amount(entry.debit_amount),
+                entry_amount(entry.credit_amount),
+                entry_amount(entry.closing_balance),
+            ));
+        }
+        csv.push_str(&format!(
+            ",合計,{},,{},{},\n",
+            self.currency,
+            amount(self.total_debit),
+            amount(self.total_credit)
+        ));
+        if let Some(proof) = &self.proof {
+            csv.push_str(
+                "検証,借方縦計,貸方縦計,貸借差額,横計差額,科目数,行ハッシュ(SHA-256),結果\n",
+            );
+            csv.push_str(&format!(
+                "検証,{:.2},{:.2},{:.2},{:.2},{},{},{}\n",
+                proof.debit_total,
+                proof.credit_total,
+                proof.difference,
+                proof.cross_foot_difference,
+                proof.account_count,
+                proof.rows_hash,
+                if proof.footed { "一致" } else { "不一致" },
+            ));
+        }
+        csv
+    }
 }
 
 /// 試算表明細ViewModel
@@ -51,12 +399,112 @@ pub struct TrialBalanceEntryViewModel {
     pub debit_amount: f64,
     pub credit_amount: f64,
     pub closing_balance: f64,
+    /// 利用者のロールでは金額を参照できない科目か（金額はすべて0）
+    pub amount_masked: bool,
+}
+
+impl TrialBalanceEntryViewModel {
+    /// マスキング対象科目の場合は金額を隠す
+    fn masked_by(self, amount_mask: &AmountMask) -> Self {
+        if amount_mask.is_masked(&self.account_code) {
+            self.masked()
+        } else {
+            self
+        }
+    }
+
+    /// 金額を隠す
+    fn masked(self) -> Self {
+        Self {
+            opening_balance: 0.0,
+            debit_amount: 0.0,
+            credit_amount: 0.0,
+            closing_balance: 0.0,
+            amount_masked: true,
+            ..self
+        }
+    }
+}
+
+/// 仕訳修正履歴ViewModel
+#[derive(Debug, Clone)]
+pub struct EntryHistoryViewModel {
+    pub original_entry_id: String,
+    pub reversal_entry_id: Option<String>,
+    pub correcting_entry_id: Option<String>,
+    pub reason: Option<String>,
+    pub lines: Vec<EntryLineDiffViewModel>,
+}
+
+impl EntryHistoryViewModel {
+    /// 修正履歴結果からViewModelを作成
+    pub fn from_result(result: EntryHistoryResult) -> Self {
+        let lines = result
+            .diffs
+            .into_iter()
+            .map(|diff| EntryLineDiffViewModel {
+                line_number: diff.line_number,
+                change: diff.change,
+                original: diff.original.as_ref().map(EntryLineSideViewModel::from),
+                corrected: diff.corrected.as_ref().map(EntryLineSideViewModel::from),
+            })
+            .collect();
+
+        Self {
+            original_entry_id: result.original_entry_id,
+            reversal_entry_id: result.reversal_entry_id,
+            correcting_entry_id: result.correcting_entry_id,
+            reason: result.reason,
+            lines,
+        }
+    }
+
+    /// 修正チェーンが存在するか
+    pub fn has_correction(&self) -> bool {
+        self.correcting_entry_id.is_some()
+    }
+}
+
+/// 明細差分ViewModel
+#[derive(Debug, Clone)]
+pub struct EntryLineDiffViewModel {
+    pub line_number: u32,
+    pub change: LineChangeKind,
+    pub original: Option<EntryLineSideViewModel>,
+    pub corrected: Option<EntryLineSideViewModel>,
+}
+
+/// 差分の片側（元仕訳または修正仕訳の明細）
+#[derive(Debug, Clone)]
+pub struct EntryLineSideViewModel {
+    pub side: String,
+    pub account_code: String,
+    pub amount: f64,
+    pub description: String,
+}
+
+impl From<&EntryHistoryLine> for EntryLineSideViewModel {
+    fn from(line: &EntryHistoryLine) -> Self {
+        let side = match line.side.as_str() {
+            "Debit" => "借方",
+            "Credit" => "貸方",
+            other => other,
+        };
+        Self {
+            side: side.to_string(),
+            account_code: line.account_code.clone(),
+            amount: line.amount,
+            description: line.description.clone().unwrap_or_default(),
+        }
+    }
 }
 
 /// 元帳Presenter
 pub struct LedgerPresenter {
     ledger_sender: mpsc::UnboundedSender<LedgerViewModel>,
     trial_balance_sender: mpsc::UnboundedSender<TrialBalanceViewModel>,
+    /// 照会した利用者の金額マスキング
+    amount_mask: Mutex<AmountMask>,
 }
 
 impl LedgerPresenter {
@@ -64,7 +512,16 @@ impl LedgerPresenter {
         ledger_sender: mpsc::UnboundedSender<LedgerViewModel>,
         trial_balance_sender: mpsc::UnboundedSender<TrialBalanceViewModel>,
     ) -> Self {
-        Self { ledger_sender, trial_balance_sender }
+        Self {
+            ledger_sender,
+            trial_balance_sender,
+            amount_mask: Mutex::new(AmountMask::default()),
+        }
+    }
+
+    /// 以降の元帳・試算表に適用する金額マスキングを設定
+    pub fn set_amount_mask(&self, amount_mask: AmountMask) {
+        *self.amount_mask.lock().unwrap() = amount_mask;
     }
 
     /// チャネルを作成
@@ -91,55 +548,317 @@ impl QueryOutputPort for LedgerPresenter {
     }
 
     async fn present_ledger(&self, result: LedgerResult) {
-        let entries = result
-            .entries
+        // マスキング対象科目の元帳は金額をすべて隠す
+        let masked = self.amount_mask.lock().unwrap().is_masked(&result.account_code);
+        let amount = |value: f64| if masked { 0.0 } else { value };
+
+        // 期首残高が繰越残高の場合は先頭に前月繰越行を表示する
+        let carried_forward =
+            result.carried_forward_date.map(|transaction_date| LedgerEntryViewModel {
+                transaction_date,
+                entry_number: String::new(),
+                entry_id: String::new(),
+                line_number: 0,
+                description: CARRIED_FORWARD_DESCRIPTION.to_string(),
+                debit_amount: 0.0,
+                credit_amount: 0.0,
+                balance: amount(result.opening_balance),
+                open_annotations: 0,
+                carried_forward: true,
+            });
+
+        let entries = carried_forward
             .into_iter()
-            .map(|entry| LedgerEntryViewModel {
+            .chain(result.entries.into_iter().map(|entry| LedgerEntryViewModel {
                 transaction_date: entry.transaction_date,
                 entry_number: entry.entry_number,
                 entry_id: entry.entry_id,
+                line_number: entry.line_number,
                 description: entry.description,
-                debit_amount: entry.debit_amount,
-                credit_amount: entry.credit_amount,
-                balance: entry.balance,
-            })
+                debit_amount: amount(entry.debit_amount),
+                credit_amount: amount(entry.credit_amount),
+                balance: amount(entry.balance),
+                open_annotations: entry.open_annotations,
+                carried_forward: false,
+            }))
             .collect();
 
         let view_model = LedgerViewModel {
             account_code: result.account_code,
             account_name: result.account_name,
-            opening_balance: result.opening_balance,
+            opening_balance: amount(result.opening_balance),
             entries,
-            closing_balance: result.closing_balance,
-            total_debit: result.total_debit,
-            total_credit: result.total_credit,
+            closing_balance: amount(result.closing_balance),
+            total_debit: amount(result.total_debit),
+            total_credit: amount(result.total_credit),
+            amounts_masked: masked,
         };
 
         let _ = self.ledger_sender.send(view_model);
     }
 
     async fn present_trial_balance(&self, result: TrialBalanceResult) {
+        let amount_mask = self.amount_mask.lock().unwrap().clone();
         let entries = result
             .entries
             .into_iter()
-            .map(|entry| TrialBalanceEntryViewModel {
-                account_code: entry.account_code,
-                account_name: entry.account_name,
-                opening_balance: entry.opening_balance,
-                debit_amount: entry.debit_amount,
-                credit_amount: entry.credit_amount,
-                closing_balance: entry.closing_balance,
+            .map(|entry| {
+                TrialBalanceEntryViewModel {
+                    account_code: entry.account_code,
+                    account_name: entry.account_name,
+                    opening_balance: entry.opening_balance,
+                    debit_amount: entry.debit_amount,
+                    credit_amount: entry.credit_amount,
+                    closing_balance: entry.closing_balance,
+                    amount_masked: false,
+                }
+                .masked_by(&amount_mask)
             })
             .collect();
 
         let view_model = TrialBalanceViewModel {
             period_year: result.period_year,
             period_month: result.period_month,
+            currency: "JPY".to_string(),
             entries,
             total_debit: result.total_debit,
             total_credit: result.total_credit,
+            translation_difference: 0.0,
+            currency_breakdowns: vec![],
+            include_pending_approval: false,
+            proof: None,
+            hierarchy: vec![],
         };
 
         let _ = self.trial_balance_sender.send(view_model);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use javelin_application::dtos::response::MaskedAccountRange;
+
+    use super::*;
+
+    #[test]
+    fn test_masked_trial_balance_rows_keep_totals() {
+        let amount_mask = AmountMask {
+            masked_ranges: vec![MaskedAccountRange {
+                account_from: "5100".to_string(),
+                account_to: "5199".to_string(),
+            }],
+        };
+        let entry = |account_code: &str, debit_amount: f64| {
+            TrialBalanceEntryViewModel {
+                account_code: account_code.to_string(),
+                account_name: String::new(),
+                opening_balance: 0.0,
+                debit_amount,
+                credit_amount: 0.0,
+                closing_balance: debit_amount,
+                amount_masked: false,
+            }
+            .masked_by(&amount_mask)
+        };
+        let view_model = TrialBalanceViewModel {
+            period_year: 2024,
+            period_month: 4,
+            currency: "JPY".to_string(),
+            entries: vec![entry("5110", 300000.0), entry("5200", 50000.0)],
+            total_debit: 350000.0,
+            total_credit: 350000.0,
+            translation_difference: 0.0,
+            currency_breakdowns: vec![],
+            include_pending_approval: false,
+            proof: None,
+            hierarchy: vec![],
+        };
+
+        assert!(view_model.entries[0].amount_masked);
+        assert_eq!(view_model.entries[0].debit_amount, 0.0);
+        let csv = view_model.to_csv(&AmountFormat::default());
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines[1], "5110,\"\",JPY,***,***,***,***");
+        assert_eq!(lines[2], "5200,\"\",JPY,\"0\",\"50,000\",\"0\",\"50,000\"");
+        assert_eq!(lines[3], ",合計,JPY,,\"350,000\",\"350,000\",");
+    }
+
+    #[test]
+    fn test_trial_balance_rows_include_collapsible_subtotals() {
+        use javelin_application::dtos::{
+            TrialBalanceLineDto,
+            response::{TrialBalanceClassDto, TrialBalanceGroupDto},
+        };
+
+        let amount_mask = AmountMask {
+            masked_ranges: vec![MaskedAccountRange {
+                account_from: "5100".to_string(),
+                account_to: "5199".to_string(),
+            }],
+        };
+        let line = |account_code: &str, debit_amount: f64| TrialBalanceLineDto {
+            account_code: account_code.to_string(),
+            account_name: format!("科目{}", account_code),
+            opening_balance: 0.0,
+            debit_amount,
+            credit_amount: 0.0,
+            closing_balance: debit_amount,
+            exchange_rate: 1.0,
+        };
+        let lines = vec![line("1000", 100.0), line("1100", 200.0), line("5100", 300.0)];
+        let group = |name: &str, members: &[TrialBalanceLineDto]| TrialBalanceGroupDto {
+            name: name.to_string(),
+            account_codes: members.iter().map(|line| line.account_code.clone()).collect(),
+            subtotal: TrialBalanceSubtotalDto::sum(format!("{}計", name), members),
+        };
+        let class = |name: &str, groups: Vec<TrialBalanceGroupDto>, members| TrialBalanceClassDto {
+            name: name.to_string(),
+            groups,
+            subtotal: TrialBalanceSubtotalDto::sum(format!("{}合計", name), members),
+        };
+        let trial_balance = CurrencyTrialBalanceDto {
+            currency: "JPY".to_string(),
+            hierarchy: vec![
+                class("資産", vec![group("流動資産", &lines[..2])], &lines[..2]),
+                class("費用", vec![group("売上原価", &lines[2..])], &lines[2..]),
+            ],
+            lines,
+            total_debit: 600.0,
+            total_credit: 0.0,
+            proof: TrialBalanceProofDto {
+                debit_total: 600.0,
+                credit_total: 0.0,
+                difference: 600.0,
+                cross_foot_difference: 0.0,
+                account_count: 3,
+                rows_hash: String::new(),
+                footed: false,
+            },
+        };
+        let response = GenerateTrialBalanceResponse {
+            total_debit: 600.0,
+            total_debit_currency: "JPY".to_string(),
+            total_credit: 0.0,
+            total_credit_currency: "JPY".to_string(),
+            is_balanced: false,
+            account_balances: vec![],
+            temporary_account_balances: vec![],
+            foreign_exchange_differences: vec![],
+            presentation_trial_balance: trial_balance,
+            currency_trial_balances: vec![],
+            translation_difference: 0.0,
+            translation_difference_currency: "JPY".to_string(),
+            include_pending_approval: false,
+            deliveries: vec![],
+        };
+        let view_model = TrialBalanceViewModel::from_response(2024, 4, &response, &amount_mask);
+
+        let names = |rows: Vec<TrialBalanceRow>| -> Vec<String> {
+            rows.iter().map(|row| row.entry().account_name.clone()).collect()
+        };
+        assert_eq!(
+            names(view_model.rows(&BTreeSet::new())),
+            vec![
+                "科目1000",
+                "科目1100",
+                "流動資産計",
+                "資産合計",
+                "科目5100",
+                "売上原価計",
+                "費用合計"
+            ]
+        );
+
+        // 折りたたんだグループは小計行のみ
+        let collapsed = BTreeSet::from(["資産/流動資産".to_string()]);
+        let rows = view_model.rows(&collapsed);
+        assert!(matches!(rows[0], TrialBalanceRow::GroupSubtotal { collapsed: true, .. }));
+        assert_eq!(rows[0].entry().closing_balance, 300.0);
+        assert_eq!(rows.len(), 5);
+
+        // マスキング対象科目を含む小計は金額を隠してCSVに出力する
+        let csv = view_model.to_csv(&AmountFormat::default());
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines[3], ",\"流動資産計\",JPY,\"0\",\"300\",\"0\",\"300\"");
+        assert_eq!(lines[6], ",\"売上原価計\",JPY,***,***,***,***");
+    }
+
+    #[test]
+    fn test_management_trial_balance_masks_lines_with_masked_sources() {
+        use javelin_application::dtos::response::MappedTrialBalanceLineDto;
+
+        let amount_mask = AmountMask {
+            masked_ranges: vec![MaskedAccountRange {
+                account_from: "5100".to_string(),
+                account_to: "5199".to_string(),
+            }],
+        };
+        let line = |account_code: &str, sources: &[&str], mapped: bool| MappedTrialBalanceLineDto {
+            account_code: account_code.to_string(),
+            account_name: "科目".to_string(),
+            source_account_codes: sources.iter().map(|code| code.to_string()).collect(),
+            mapped,
+            opening_balance: 0.0,
+            debit_amount: 1000.0,
+            credit_amount: 0.0,
+            closing_balance: 1000.0,
+        };
+        let response = MappedTrialBalanceResponse {
+            currency: "JPY".to_string(),
+            lines: vec![line("M510", &["5110", "5200"], true), line("1000", &["1000"], false)],
+            total_debit: 2000.0,
+            total_credit: 2000.0,
+            unmapped_account_count: 1,
+        };
+
+        let view_model =
+            ManagementTrialBalanceViewModel::from_response(2024, 4, &response, false, &amount_mask);
+        let entries = &view_model.trial_balance.entries;
+        assert!(entries[0].amount_masked);
+        assert_eq!(entries[0].debit_amount, 0.0);
+        assert!(!entries[1].amount_masked);
+        assert_eq!(entries[1].account_name, "科目（未割当）");
+        assert_eq!(view_model.trial_balance.total_debit, 2000.0);
+        assert!(view_model.trial_balance.proof.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_present_ledger_prepends_carried_forward_row() {
+        use javelin_application::query_service::LedgerEntry;
+
+        let (ledger_tx, mut ledger_rx, trial_balance_tx, _) = LedgerPresenter::create_channels();
+        let presenter = LedgerPresenter::new(ledger_tx, trial_balance_tx);
+
+        presenter
+            .present_ledger(LedgerResult {
+                account_code: "1100".to_string(),
+                account_name: "売掛金".to_string(),
+                opening_balance: 1500.0,
+                carried_forward_date: Some("2024-04-01".to_string()),
+                entries: vec![LedgerEntry {
+                    transaction_date: "2024-04-20".to_string(),
+                    entry_number: "EN-001".to_string(),
+                    entry_id: "JE001".to_string(),
+                    line_number: 1,
+                    description: "売上".to_string(),
+                    debit_amount: 50.0,
+                    credit_amount: 0.0,
+                    balance: 1550.0,
+                    open_annotations: 0,
+                }],
+                closing_balance: 1550.0,
+                total_debit: 50.0,
+                total_credit: 0.0,
+            })
+            .await;
+
+        let view_model = ledger_rx.try_recv().unwrap();
+        assert_eq!(view_model.entries.len(), 2);
+        let carried_forward = &view_model.entries[0];
+        assert!(carried_forward.carried_forward);
+        assert_eq!(carried_forward.transaction_date, "2024-04-01");
+        assert_eq!(carried_forward.description, CARRIED_FORWARD_DESCRIPTION);
+        assert_eq!(carried_forward.balance, 1500.0);
+        assert!(!view_model.entries[1].carried_forward);
+    }
+}
@@ -0,0 +1,57 @@
+// ManagementAccountMappingPresenter - 管理会計科目マッピングの表示整形
+// 勘定科目ごとの管理会計科目の割当状況をビュー向けに整形する
+
+use javelin_application::dtos::response::LoadManagementAccountMappingResponse;
+
+/// 管理会計科目マッピングViewModel
+#[derive(Debug, Clone, Default)]
+pub struct ManagementAccountMappingViewModel {
+    pub items: Vec<ManagementAccountMappingItemViewModel>,
+    pub unmapped_count: usize,
+}
+
+/// 管理会計科目マッピング項目ViewModel
+#[derive(Debug, Clone)]
+pub struct ManagementAccountMappingItemViewModel {
+    pub account_code: String,
+    pub account_name: String,
+    /// 割当済みの管理会計科目コード（未割当の場合はNone）
+    pub management_account_code: Option<String>,
+    pub management_account_label: String,
+    /// 編集欄の初期値（`<科目コード>:<科目名>`、未割当の場合は空）
+    pub management_account_spec: String,
+}
+
+impl ManagementAccountMappingItemViewModel {
+    pub fn is_mapped(&self) -> bool {
+        self.management_account_code.is_some()
+    }
+}
+
+impl ManagementAccountMappingViewModel {
+    /// マッピング取得レスポンスからViewModelを作成
+    pub fn from_response(response: &LoadManagementAccountMappingResponse) -> Self {
+        let items = response
+            .items
+            .iter()
+            .map(|item| {
+                let (label, spec) =
+                    match (&item.management_account_code, &item.management_account_name) {
+                        (Some(code), Some(name)) => {
+                            (format!("{} {}", code, name), format!("{}:{}", code, name))
+                        }
+                        _ => ("未設定".to_string(), String::new()),
+                    };
+                ManagementAccountMappingItemViewModel {
+                    account_code: item.account_code.clone(),
+                    account_name: item.account_name.clone(),
+                    management_account_code: item.management_account_code.clone(),
+                    management_account_label: label,
+                    management_account_spec: spec,
+                }
+            })
+            .collect();
+
+        Self { items, unmapped_count: response.unmapped_count() }
+    }
+}
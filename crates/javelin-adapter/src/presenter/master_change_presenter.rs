@@ -0,0 +1,84 @@
+// MasterChangePresenter - マスタ変更履歴の表示整形
+// 責務: マスタ変更履歴DTOの画面表示用ViewModelへの変換
+
+use javelin_application::dtos::response::MasterChangeDto;
+
+/// マスタ変更履歴の1件
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MasterChangeItemViewModel {
+    pub sequence: u64,
+    pub kind_label: String,
+    pub before: String,
+    pub after: String,
+    pub changed_by: String,
+    /// 変更日時（設定タイムゾーン）
+    pub changed_at: String,
+    /// 取消しによる変更の場合の注記
+    pub note: String,
+    pub revertible: bool,
+}
+
+impl MasterChangeItemViewModel {
+    pub fn from_dto(dto: &MasterChangeDto) -> Self {
+        let image = |value: &Option<String>| value.clone().unwrap_or_else(|| "-".to_string());
+        Self {
+            sequence: dto.sequence,
+            kind_label: dto.kind_label.clone(),
+            before: image(&dto.before),
+            after: image(&dto.after),
+            changed_by: dto.changed_by.clone(),
+            changed_at: dto
+                .changed_at
+                .with_timezone(&crate::clock::time_zone())
+                .format("%Y-%m-%d %H:%M")
+                .to_string(),
+            note: dto
+                .reverts
+                .map(|sequence| format!("#{} の取消し", sequence))
+                .unwrap_or_default(),
+            revertible: dto.revertible,
+        }
+    }
+}
+
+/// マスタ変更履歴（新しい順）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MasterChangeHistoryViewModel {
+    /// 対象レコードの表示名（コードと名称）
+    pub title: String,
+    pub items: Vec<MasterChangeItemViewModel>,
+}
+
+impl MasterChangeHistoryViewModel {
+    pub fn from_dtos(title: impl Into<String>, dtos: &[MasterChangeDto]) -> Self {
+        Self {
+            title: title.into(),
+            items: dtos.iter().map(MasterChangeItemViewModel::from_dto).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+
+    use super::*;
+
+    #[test]
+    fn test_revert_changes_are_annotated() {
+        let dto = MasterChangeDto {
+            sequence: 4,
+            kind_label: "削除".to_string(),
+            before: Some("現金（有効）".to_string()),
+            after: None,
+            changed_by: "alice".to_string(),
+            changed_at: Utc.with_ymd_and_hms(2026, 4, 1, 0, 0, 0).unwrap(),
+            reverts: Some(1),
+            revertible: true,
+        };
+        let item = MasterChangeItemViewModel::from_dto(&dto);
+        assert_eq!(item.after, "-");
+        assert_eq!(item.note, "#1 の取消し");
+        assert!(item.revertible);
+    }
+}
@@ -0,0 +1,117 @@
+// NoteCrossReferencePresenter - 注記参照一覧の表示整形
+// 注記ごとの作成状況と、その注記を参照する表示科目・勘定科目をビュー向けに整形する
+
+use javelin_application::dtos::response::LoadNoteCrossReferencesResponse;
+
+/// 注記参照一覧ViewModel
+#[derive(Debug, Clone, Default)]
+pub struct NoteCrossReferenceViewModel {
+    /// 作成状況の要約（例: `注記 5件  未作成 2件  必須未作成 1件`）
+    pub summary: String,
+    /// 必須の参照先を持つ未作成の注記があるか（財務諸表を生成できない）
+    pub has_missing_required: bool,
+    pub items: Vec<NoteSectionViewModel>,
+}
+
+/// 注記ViewModel
+#[derive(Debug, Clone)]
+pub struct NoteSectionViewModel {
+    pub number: u32,
+    pub title: String,
+    pub status_label: String,
+    pub is_drafted: bool,
+    /// 必須の参照先があるのに未作成
+    pub is_missing_required: bool,
+    /// 参照先の表示（必須は末尾に `*`）
+    pub references: String,
+    /// 参照先コード（参照解除の対象）
+    pub reference_targets: Vec<String>,
+    /// 編集欄の初期値（`<番号>:<表題>:<本文>`）
+    pub spec: String,
+}
+
+impl NoteCrossReferenceViewModel {
+    /// 注記参照一覧レスポンスからViewModelを作成
+    pub fn from_response(response: &LoadNoteCrossReferencesResponse) -> Self {
+        let items: Vec<NoteSectionViewModel> = response
+            .sections
+            .iter()
+            .map(|section| {
+                let references: Vec<String> = section
+                    .references
+                    .iter()
+                    .map(|reference| {
+                        let mark = if reference.required { "*" } else { "" };
+                        format!("{}{}", reference.target_label, mark)
+                    })
+                    .collect();
+                let required = section.references.iter().any(|reference| reference.required);
+                NoteSectionViewModel {
+                    number: section.number,
+                    title: section.title.clone(),
+                    status_label: if section.is_drafted {
+                        "作成済"
+                    } else {
+                        "未作成"
+                    }
+                    .to_string(),
+                    is_drafted: section.is_drafted,
+                    is_missing_required: required && !section.is_drafted,
+                    references: references.join("、"),
+                    reference_targets: section
+                        .references
+                        .iter()
+                        .map(|reference| reference.target.clone())
+                        .collect(),
+                    spec: format!("{}:{}:{}", section.number, section.title, section.body),
+                }
+            })
+            .collect();
+
+        let undrafted = items.iter().filter(|item| !item.is_drafted).count();
+        let missing_required = items.iter().filter(|item| item.is_missing_required).count();
+        let mut summary = format!("注記 {}件  未作成 {}件", items.len(), undrafted);
+        if missing_required > 0 {
+            summary.push_str(&format!("  必須未作成 {}件", missing_required));
+        }
+
+        Self { summary, has_missing_required: missing_required > 0, items }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use javelin_application::dtos::response::{NoteReferenceItem, NoteSectionItem};
+
+    use super::*;
+
+    #[test]
+    fn test_required_references_are_marked_and_counted() {
+        let response = LoadNoteCrossReferencesResponse {
+            sections: vec![NoteSectionItem {
+                number: 3,
+                title: "収益認識".to_string(),
+                body: String::new(),
+                is_drafted: false,
+                references: vec![
+                    NoteReferenceItem {
+                        target: "PL-REV".to_string(),
+                        target_label: "売上収益".to_string(),
+                        required: true,
+                    },
+                    NoteReferenceItem {
+                        target: "4100".to_string(),
+                        target_label: "勘定科目 4100".to_string(),
+                        required: false,
+                    },
+                ],
+            }],
+        };
+
+        let view_model = NoteCrossReferenceViewModel::from_response(&response);
+        assert_eq!(view_model.items[0].references, "売上収益*、勘定科目 4100");
+        assert_eq!(view_model.items[0].spec, "3:収益認識:");
+        assert!(view_model.has_missing_required);
+        assert_eq!(view_model.summary, "注記 1件  未作成 1件  必須未作成 1件");
+    }
+}
@@ -0,0 +1,200 @@
+// ProgressChannel - 進捗メッセージ用の有界チャネル
+// 責務: 段階ごとの最新メッセージへの集約と、上限超過時の破棄件数の記録
+
+use std::{
+    collections::VecDeque,
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering},
+    },
+};
+
+use tokio::sync::mpsc::error::TryRecvError;
+
+/// 進捗チャネルの既定の容量（保持する段階の数）
+pub const PROGRESS_CHANNEL_CAPACITY: usize = 16;
+
+/// 全チャネルの集約件数（ステータス表示用）
+static COALESCED_TOTAL: AtomicU64 = AtomicU64::new(0);
+/// 全チャネルの破棄件数（ステータス表示用）
+static DROPPED_TOTAL: AtomicU64 = AtomicU64::new(0);
+
+/// 進捗チャネルの集約・破棄件数
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ProgressChannelStats {
+    /// 同じ段階の新しいメッセージで置き換えた件数
+    pub coalesced: u64,
+    /// 容量超過で捨てた件数
+    pub dropped: u64,
+}
+
+/// 起動以降の全進捗チャネルの集約・破棄件数
+pub fn progress_channel_stats() -> ProgressChannelStats {
+    ProgressChannelStats {
+        coalesced: COALESCED_TOTAL.load(Ordering::Relaxed),
+        dropped: DROPPED_TOTAL.load(Ordering::Relaxed),
+    }
+}
+
+struct Shared {
+    /// 未受信のメッセージ（段階, メッセージ）
+    queue: Mutex<VecDeque<(String, String)>>,
+    capacity: usize,
+    senders: AtomicUsize,
+    receiver_closed: AtomicBool,
+    coalesced: AtomicU64,
+    dropped: AtomicU64,
+}
+
+/// 進捗チャネルを作成
+///
+/// 未受信のメッセージは段階ごとに最新の1件だけを保持し、
+/// 段階の数が `capacity` を超えた場合は最も古いものを捨てる。
+pub fn progress_channel(capacity: usize) -> (ProgressSender, ProgressReceiver) {
+    let shared = Arc::new(Shared {
+        queue: Mutex::new(VecDeque::with_capacity(capacity)),
+        capacity: capacity.max(1),
+        senders: AtomicUsize::new(1),
+        receiver_closed: AtomicBool::new(false),
+        coalesced: AtomicU64::new(0),
+        dropped: AtomicU64::new(0),
+    });
+    (ProgressSender { shared: Arc::clone(&shared) }, ProgressReceiver { shared })
+}
+
+/// 進捗メッセージの段階
+///
+/// 件数や割合だけが異なるメッセージ（「取込中 120/5000件」など）を
+/// 同じ段階とみなすため、数字を除いた文字列を使う。
+fn stage_of(message: &str) -> String {
+    message.chars().filter(|c| !c.is_ascii_digit()).collect()
+}
+
+/// 進捗チャネルの送信側
+pub struct ProgressSender {
+    shared: Arc<Shared>,
+}
+
+impl ProgressSender {
+    /// 進捗を送信（受信側が閉じている場合は破棄される）
+    ///
+    /// 送信側を待たせないよう、容量を超えても待機せずに古いメッセージを捨てる。
+    pub fn send(&self, message: impl Into<String>) {
+        if self.shared.receiver_closed.load(Ordering::Acquire) {
+            return;
+        }
+        let message = message.into();
+        let stage = stage_of(&message);
+
+        let Ok(mut queue) = self.shared.queue.lock() else {
+            return;
+        };
+        if let Some(index) = queue.iter().position(|(pending, _)| *pending == stage) {
+            queue.remove(index);
+            self.shared.coalesced.fetch_add(1, Ordering::Relaxed);
+            COALESCED_TOTAL.fetch_add(1, Ordering::Relaxed);
+        } else if queue.len() >= self.shared.capacity {
+            queue.pop_front();
+            self.shared.dropped.fetch_add(1, Ordering::Relaxed);
+            DROPPED_TOTAL.fetch_add(1, Ordering::Relaxed);
+        }
+        queue.push_back((stage, message));
+    }
+}
+
+impl Clone for ProgressSender {
+    fn clone(&self) -> Self {
+        self.shared.senders.fetch_add(1, Ordering::AcqRel);
+        Self { shared: Arc::clone(&self.shared) }
+    }
+}
+
+impl Drop for ProgressSender {
+    fn drop(&mut self) {
+        self.shared.senders.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+/// 進捗チャネルの受信側
+pub struct ProgressReceiver {
+    shared: Arc<Shared>,
+}
+
+impl ProgressReceiver {
+    /// 未受信のメッセージを古い順に1件取り出す
+    pub fn try_recv(&mut self) -> Result<String, TryRecvError> {
+        let message = self.shared.queue.lock().ok().and_then(|mut queue| queue.pop_front());
+        match message {
+            Some((_, message)) => Ok(message),
+            None if self.shared.senders.load(Ordering::Acquire) == 0 => {
+                Err(TryRecvError::Disconnected)
+            }
+            None => Err(TryRecvError::Empty),
+        }
+    }
+
+    /// このチャネルの集約・破棄件数
+    pub fn stats(&self) -> ProgressChannelStats {
+        ProgressChannelStats {
+            coalesced: self.shared.coalesced.load(Ordering::Relaxed),
+            dropped: self.shared.dropped.load(Ordering::Relaxed),
+        }
+    }
+}
+
+impl Drop for ProgressReceiver {
+    fn drop(&mut self) {
+        self.shared.receiver_closed.store(true, Ordering::Release);
+        if let Ok(mut queue) = self.shared.queue.lock() {
+            queue.clear();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn drain(receiver: &mut ProgressReceiver) -> Vec<String> {
+        std::iter::from_fn(|| receiver.try_recv().ok()).collect()
+    }
+
+    #[test]
+    fn test_messages_of_same_stage_are_coalesced_to_latest() {
+        let (sender, mut receiver) = progress_channel(4);
+        sender.send("検索条件を検証中...");
+        for count in 1..=500 {
+            sender.send(format!("取込中 {}/500件", count));
+        }
+
+        assert_eq!(drain(&mut receiver), vec!["検索条件を検証中...", "取込中 500/500件"]);
+        assert_eq!(receiver.stats(), ProgressChannelStats { coalesced: 499, dropped: 0 });
+    }
+
+    #[test]
+    fn test_oldest_stage_is_dropped_when_full() {
+        let (sender, mut receiver) = progress_channel(2);
+        sender.send("仕訳明細を作成しました");
+        sender.send("借貸バランスを検証しました");
+        sender.send("イベントストアへ保存しました");
+
+        assert_eq!(
+            drain(&mut receiver),
+            vec!["借貸バランスを検証しました", "イベントストアへ保存しました"]
+        );
+        assert_eq!(receiver.stats().dropped, 1);
+    }
+
+    #[test]
+    fn test_disconnected_after_all_senders_dropped() {
+        let (sender, mut receiver) = progress_channel(2);
+        let cloned = sender.clone();
+        drop(sender);
+        cloned.send("完了");
+
+        assert_eq!(receiver.try_recv(), Ok("完了".to_string()));
+        assert_eq!(receiver.try_recv(), Err(TryRecvError::Empty));
+        drop(cloned);
+        assert_eq!(receiver.try_recv(), Err(TryRecvError::Disconnected));
+    }
+}
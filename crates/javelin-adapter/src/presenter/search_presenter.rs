@@ -1,16 +1,63 @@
 // SearchPresenter実装
 // 仕訳検索結果の出力を整形してビューに渡す
 
+use std::sync::{Arc, Mutex};
+
 use javelin_application::{
-    dtos::response::JournalEntrySearchResultDto, output_port::SearchOutputPort,
+    dtos::{
+        request::SearchGrouping,
+        response::{AmountMask, JournalEntrySearchResultDto},
+    },
+    output_port::SearchOutputPort,
 };
 use tokio::sync::mpsc;
 
+use crate::presenter::{
+    PROGRESS_CHANNEL_CAPACITY, ProgressReceiver, ProgressSender, progress_channel,
+};
+
 /// 検索結果ViewModel
 #[derive(Debug, Clone)]
 pub struct SearchResultViewModel {
     pub items: Vec<JournalEntryItemViewModel>,
     pub total_count: usize,
+    /// 適用したグループ化
+    pub grouping: SearchGrouping,
+    /// グループごとの小計（検索結果の全件が対象）
+    pub groups: Vec<SearchGroupViewModel>,
+}
+
+impl SearchResultViewModel {
+    /// 明細が属するグループのキー（グループ化しない場合はNone）
+    pub fn group_key(
+        &self,
+        entry: &JournalEntryItemViewModel,
+        line: &JournalEntryLineItemViewModel,
+    ) -> Option<String> {
+        match self.grouping {
+            SearchGrouping::None => None,
+            SearchGrouping::Account => Some(line.account_code.clone()),
+            SearchGrouping::Month => {
+                Some(entry.transaction_date.get(..7).unwrap_or(&entry.transaction_date).to_string())
+            }
+            SearchGrouping::Status => Some(entry.status.clone()),
+        }
+    }
+}
+
+/// 検索結果のグループ小計ViewModel
+#[derive(Debug, Clone)]
+pub struct SearchGroupViewModel {
+    pub key: String,
+    /// グループの表示名（科目コード+科目名 / YYYY年MM月 / ステータス名）
+    pub label: String,
+    pub entry_count: usize,
+    /// 借方合計（マスキング対象の場合は0）
+    pub debit_total: f64,
+    /// 貸方合計（マスキング対象の場合は0）
+    pub credit_total: f64,
+    /// 利用者のロールでは参照できない科目を小計に含むか
+    pub amount_masked: bool,
 }
 
 /// 仕訳項目ViewModel
@@ -33,7 +80,12 @@ pub struct JournalEntryLineItemViewModel {
     pub account_code: String,
     pub account_name: String,
     pub description: String,
+    /// 金額（マスキング対象の場合は0）
     pub amount: f64,
+    /// 利用者のロールでは金額を参照できない明細か
+    pub amount_masked: bool,
+    /// 未解決の注記数
+    pub open_annotations: u32,
 }
 
 /// 検索Presenter
@@ -41,14 +93,16 @@ pub struct JournalEntryLineItemViewModel {
 pub struct SearchPresenter {
     result_tx: mpsc::Sender<SearchResultViewModel>,
     error_tx: mpsc::Sender<String>,
-    progress_tx: mpsc::Sender<String>,
+    progress_tx: ProgressSender,
     execution_time_tx: mpsc::Sender<usize>,
+    /// 検索した利用者の金額マスキング
+    amount_mask: Arc<Mutex<AmountMask>>,
 }
 
 pub struct SearchChannels {
     pub result_rx: mpsc::Receiver<SearchResultViewModel>,
     pub error_rx: mpsc::Receiver<String>,
-    pub progress_rx: mpsc::Receiver<String>,
+    pub progress_rx: ProgressReceiver,
     pub execution_time_rx: mpsc::Receiver<usize>,
 }
 
@@ -56,16 +110,27 @@ impl SearchPresenter {
     pub fn new(
         result_tx: mpsc::Sender<SearchResultViewModel>,
         error_tx: mpsc::Sender<String>,
-        progress_tx: mpsc::Sender<String>,
+        progress_tx: ProgressSender,
         execution_time_tx: mpsc::Sender<usize>,
     ) -> Self {
-        Self { result_tx, error_tx, progress_tx, execution_time_tx }
+        Self {
+            result_tx,
+            error_tx,
+            progress_tx,
+            execution_time_tx,
+            amount_mask: Arc::new(Mutex::new(AmountMask::default())),
+        }
+    }
+
+    /// 以降の検索結果に適用する金額マスキングを設定
+    pub fn set_amount_mask(&self, amount_mask: AmountMask) {
+        *self.amount_mask.lock().unwrap() = amount_mask;
     }
 
     pub fn create_channels() -> (SearchPresenter, SearchChannels) {
         let (result_tx, result_rx) = mpsc::channel(100);
         let (error_tx, error_rx) = mpsc::channel(100);
-        let (progress_tx, progress_rx) = mpsc::channel(100);
+        let (progress_tx, progress_rx) = progress_channel(PROGRESS_CHANNEL_CAPACITY);
         let (execution_time_tx, execution_time_rx) = mpsc::channel(100);
 
         let presenter = SearchPresenter::new(result_tx, error_tx, progress_tx, execution_time_tx);
@@ -99,8 +164,47 @@ impl SearchPresenter {
         .to_string()
     }
 
+    /// グループの日本語表示を取得
+    fn format_group_label(grouping: SearchGrouping, key: &str, name: &str) -> String {
+        match grouping {
+            SearchGrouping::Account => format!("{} {}", key, name),
+            SearchGrouping::Month => match key.split_once('-') {
+                Some((year, month)) => format!("{}年{}月", year, month),
+                None => key.to_string(),
+            },
+            SearchGrouping::Status => Self::format_status_label(key),
+            SearchGrouping::None => key.to_string(),
+        }
+    }
+
     /// DTOからViewModelへの変換
     fn to_view_model(&self, dto: JournalEntrySearchResultDto) -> SearchResultViewModel {
+        let amount_mask = self.amount_mask.lock().unwrap().clone();
+        let grouping = dto.grouping;
+        let groups = dto
+            .groups
+            .into_iter()
+            .map(|group| {
+                let amount_masked =
+                    group.account_codes.iter().any(|code| amount_mask.is_masked(code));
+                SearchGroupViewModel {
+                    label: Self::format_group_label(grouping, &group.key, &group.name),
+                    key: group.key,
+                    entry_count: group.entry_count as usize,
+                    debit_total: if amount_masked {
+                        0.0
+                    } else {
+                        group.debit_total
+                    },
+                    credit_total: if amount_masked {
+                        0.0
+                    } else {
+                        group.credit_total
+                    },
+                    amount_masked,
+                }
+            })
+            .collect();
         let items = dto
             .entries
             .into_iter()
@@ -108,14 +212,19 @@ impl SearchPresenter {
                 let lines = entry
                     .lines
                     .into_iter()
-                    .map(|line| JournalEntryLineItemViewModel {
-                        line_number: line.line_number,
-                        side: line.side.clone(),
-                        side_label: Self::format_side_label(&line.side),
-                        account_code: line.account_code,
-                        account_name: line.account_name,
-                        description: line.description.unwrap_or_default(),
-                        amount: line.amount,
+                    .map(|line| {
+                        let amount_masked = amount_mask.is_masked(&line.account_code);
+                        JournalEntryLineItemViewModel {
+                            line_number: line.line_number,
+                            side: line.side.clone(),
+                            side_label: Self::format_side_label(&line.side),
+                            account_code: line.account_code,
+                            account_name: line.account_name,
+                            description: line.description.unwrap_or_default(),
+                            amount: if amount_masked { 0.0 } else { line.amount },
+                            amount_masked,
+                            open_annotations: line.open_annotations,
+                        }
                     })
                     .collect();
 
@@ -130,7 +239,7 @@ impl SearchPresenter {
             })
             .collect();
 
-        SearchResultViewModel { items, total_count: dto.total_count as usize }
+        SearchResultViewModel { items, total_count: dto.total_count as usize, grouping, groups }
     }
 }
 
@@ -145,12 +254,17 @@ impl SearchOutputPort for SearchPresenter {
     }
 
     fn present_no_results(&self) {
-        let view_model = SearchResultViewModel { items: vec![], total_count: 0 };
+        let view_model = SearchResultViewModel {
+            items: vec![],
+            total_count: 0,
+            grouping: SearchGrouping::None,
+            groups: vec![],
+        };
         let _ = self.result_tx.try_send(view_model);
     }
 
     fn present_progress(&self, message: String) {
-        let _ = self.progress_tx.try_send(message);
+        self.progress_tx.send(message);
     }
 
     fn present_execution_time(&self, elapsed_ms: usize) {
@@ -181,7 +295,7 @@ mod tests {
     async fn test_present_search_result() {
         let (presenter, mut channels) = SearchPresenter::create_channels();
 
-        let dto = JournalEntrySearchResultDto { entries: vec![], total_count: 0 };
+        let dto = JournalEntrySearchResultDto::empty();
 
         presenter.present_search_result(dto);
 
@@ -190,6 +304,83 @@ mod tests {
         assert_eq!(result.items.len(), 0);
     }
 
+    #[tokio::test]
+    async fn test_masked_lines_hide_amounts() {
+        use javelin_application::dtos::response::{
+            JournalEntryItemDto, JournalEntryLineItemDto, MaskedAccountRange,
+        };
+
+        let (presenter, mut channels) = SearchPresenter::create_channels();
+        presenter.set_amount_mask(AmountMask {
+            masked_ranges: vec![MaskedAccountRange {
+                account_from: "5100".to_string(),
+                account_to: "5199".to_string(),
+            }],
+        });
+
+        let line = |line_number, side: &str, account_code: &str| {
+            JournalEntryLineItemDto::new(
+                line_number,
+                side.to_string(),
+                account_code.to_string(),
+                String::new(),
+                300000.0,
+                None,
+            )
+        };
+        let entry = JournalEntryItemDto::new(
+            "JE-1".to_string(),
+            None,
+            "2024-04-25".to_string(),
+            "Posted".to_string(),
+            vec![line(1, "Debit", "5110"), line(2, "Credit", "1110")],
+        );
+        presenter.present_search_result(JournalEntrySearchResultDto::new(vec![entry], 1));
+
+        let result = channels.result_rx.recv().await.unwrap();
+        let lines = &result.items[0].lines;
+        assert!(lines[0].amount_masked);
+        assert_eq!(lines[0].amount, 0.0);
+        assert!(!lines[1].amount_masked);
+        assert_eq!(lines[1].amount, 300000.0);
+    }
+
+    #[tokio::test]
+    async fn test_group_subtotals_are_labeled_and_masked() {
+        use javelin_application::dtos::response::{JournalEntrySearchGroupDto, MaskedAccountRange};
+
+        let (presenter, mut channels) = SearchPresenter::create_channels();
+        presenter.set_amount_mask(AmountMask {
+            masked_ranges: vec![MaskedAccountRange {
+                account_from: "5100".to_string(),
+                account_to: "5199".to_string(),
+            }],
+        });
+
+        let group = |key: &str, account_codes: &[&str]| JournalEntrySearchGroupDto {
+            key: key.to_string(),
+            name: String::new(),
+            entry_count: 2,
+            debit_total: 300000.0,
+            credit_total: 300000.0,
+            account_codes: account_codes.iter().map(|code| code.to_string()).collect(),
+        };
+        let dto = JournalEntrySearchResultDto::new(vec![], 2).with_groups(
+            SearchGrouping::Month,
+            vec![group("2024-04", &["1110", "4110"]), group("2024-05", &["1110", "5110"])],
+        );
+        presenter.present_search_result(dto);
+
+        let result = channels.result_rx.recv().await.unwrap();
+        assert_eq!(result.grouping, SearchGrouping::Month);
+        assert_eq!(result.groups[0].label, "2024年04月");
+        assert!(!result.groups[0].amount_masked);
+        assert_eq!(result.groups[0].debit_total, 300000.0);
+        // マスキング対象の科目を含む小計は金額を表示しない
+        assert!(result.groups[1].amount_masked);
+        assert_eq!(result.groups[1].debit_total, 0.0);
+    }
+
     #[tokio::test]
     async fn test_present_validation_error() {
         let (presenter, mut channels) = SearchPresenter::create_channels();
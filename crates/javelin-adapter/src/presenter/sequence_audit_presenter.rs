@@ -0,0 +1,112 @@
+// SequenceAuditPresenter - 番号連続性監査結果の表示整形
+
+use javelin_application::dtos::response::SequenceAuditResponse;
+
+/// 番号連続性監査結果ViewModel
+#[derive(Debug, Clone, Default)]
+pub struct SequenceAuditViewModel {
+    pub findings: Vec<SequenceFindingViewModel>,
+    /// 検査件数の要約
+    pub summary: String,
+    pub gap_count: usize,
+    pub duplicate_count: usize,
+    /// 採番後に取り消された証憑番号の件数
+    pub voided_count: usize,
+}
+
+/// 監査指摘ViewModel
+#[derive(Debug, Clone)]
+pub struct SequenceFindingViewModel {
+    pub kind_label: String,
+    pub number_kind_label: String,
+    pub fiscal_year: String,
+    pub subject: String,
+    pub detail: String,
+    pub surrounding: Vec<SequenceAuditEntryViewModel>,
+}
+
+/// 指摘箇所の前後の仕訳ViewModel
+#[derive(Debug, Clone)]
+pub struct SequenceAuditEntryViewModel {
+    pub entry_id: String,
+    pub number: String,
+    pub transaction_date: String,
+    pub status: String,
+}
+
+impl SequenceAuditViewModel {
+    pub fn from_response(response: &SequenceAuditResponse) -> Self {
+        let findings = response
+            .findings
+            .iter()
+            .map(|f| SequenceFindingViewModel {
+                kind_label: f.kind.label().to_string(),
+                number_kind_label: f.number_kind.label().to_string(),
+                fiscal_year: f.fiscal_year.map(|y| y.to_string()).unwrap_or_default(),
+                subject: f.subject.clone(),
+                detail: f.detail.clone(),
+                surrounding: f
+                    .surrounding
+                    .iter()
+                    .map(|e| SequenceAuditEntryViewModel {
+                        entry_id: e.entry_id.clone(),
+                        number: e.number.clone(),
+                        transaction_date: e.transaction_date.clone(),
+                        status: e.status.clone(),
+                    })
+                    .collect(),
+            })
+            .collect();
+
+        let numbers: usize = response.series.iter().map(|s| s.count).sum();
+        let summary = format!(
+            "{}系列・番号 {}件を検査 / 欠番 {}件・重複 {}件・取消 {}件",
+            response.series.len(),
+            numbers,
+            response.gap_count(),
+            response.duplicate_count(),
+            response.voided_count()
+        );
+
+        Self {
+            findings,
+            summary,
+            gap_count: response.gap_count(),
+            duplicate_count: response.duplicate_count(),
+            voided_count: response.voided_count(),
+        }
+    }
+
+    /// 欠番・重複の指摘があるか（取消番号は理由が記録済みのため含めない）
+    pub fn has_findings(&self) -> bool {
+        self.gap_count + self.duplicate_count > 0
+    }
+
+    /// CSV形式に変換（エクスポート用）
+    ///
+    /// 指摘ごとに前後の仕訳を1行ずつ出力する。
+    pub fn to_csv(&self) -> String {
+        let mut csv =
+            String::from("区分,番号種別,年度,対象番号,内容,仕訳ID,番号,取引日,ステータス\n");
+        for finding in &self.findings {
+            let head = format!(
+                "{},{},{},{},\"{}\"",
+                finding.kind_label,
+                finding.number_kind_label,
+                finding.fiscal_year,
+                finding.subject,
+                finding.detail.replace('"', "\"\"")
+            );
+            if finding.surrounding.is_empty() {
+                csv.push_str(&format!("{},,,,\n", head));
+            }
+            for entry in &finding.surrounding {
+                csv.push_str(&format!(
+                    "{},{},{},{},{}\n",
+                    head, entry.entry_id, entry.number, entry.transaction_date, entry.status
+                ));
+            }
+        }
+        csv
+    }
+}
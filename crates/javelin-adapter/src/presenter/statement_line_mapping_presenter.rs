@@ -0,0 +1,80 @@
+// StatementLineMappingPresenter - 財務諸表表示科目マッピングの表示整形
+// 勘定科目ごとの割当状況をビュー向けに整形する
+
+use javelin_application::dtos::response::{LoadStatementLineMappingResponse, StatementLineOption};
+
+/// 表示科目マッピングViewModel
+#[derive(Debug, Clone, Default)]
+pub struct StatementLineMappingViewModel {
+    pub items: Vec<StatementLineMappingItemViewModel>,
+    /// 割当可能な表示科目（表示順）
+    pub statement_lines: Vec<StatementLineOption>,
+    pub unmapped_count: usize,
+}
+
+/// 表示科目マッピング項目ViewModel
+#[derive(Debug, Clone)]
+pub struct StatementLineMappingItemViewModel {
+    pub account_code: String,
+    pub account_name: String,
+    /// 割当済みの表示科目コード（未割当の場合はNone）
+    pub statement_line_code: Option<String>,
+    pub statement_line_label: String,
+    pub statement_label: String,
+}
+
+impl StatementLineMappingItemViewModel {
+    pub fn is_mapped(&self) -> bool {
+        self.statement_line_code.is_some()
+    }
+}
+
+impl StatementLineMappingViewModel {
+    /// マッピング取得レスポンスからViewModelを作成
+    pub fn from_response(response: &LoadStatementLineMappingResponse) -> Self {
+        let items = response
+            .items
+            .iter()
+            .map(|item| StatementLineMappingItemViewModel {
+                account_code: item.account_code.clone(),
+                account_name: item.account_name.clone(),
+                statement_line_code: item.statement_line_code.clone(),
+                statement_line_label: item
+                    .statement_line_name
+                    .clone()
+                    .unwrap_or_else(|| "未設定".to_string()),
+                statement_label: item.statement.clone().unwrap_or_else(|| "-".to_string()),
+            })
+            .collect();
+
+        Self {
+            items,
+            statement_lines: response.statement_lines.clone(),
+            unmapped_count: response.unmapped_count(),
+        }
+    }
+
+    /// 指定項目の表示科目を選択肢の順で前後に切り替えた場合のコード
+    ///
+    /// 未割当の場合は先頭（逆方向は末尾）の表示科目を返す。
+    pub fn cycled_line_code(&self, index: usize, forward: bool) -> Option<String> {
+        let item = self.items.get(index)?;
+        let len = self.statement_lines.len();
+        if len == 0 {
+            return None;
+        }
+
+        let current = item
+            .statement_line_code
+            .as_ref()
+            .and_then(|code| self.statement_lines.iter().position(|line| &line.code == code));
+        let next = match (current, forward) {
+            (Some(i), true) => (i + 1) % len,
+            (Some(i), false) => (i + len - 1) % len,
+            (None, true) => 0,
+            (None, false) => len - 1,
+        };
+
+        Some(self.statement_lines[next].code.clone())
+    }
+}
@@ -0,0 +1,116 @@
+// StorageTrendPresenter - ストレージ使用状況の推移の表示整形
+// 標本の時系列をスパークライン用の系列と最新値・増減の要約に整形する
+
+use javelin_application::storage_telemetry::StorageSample;
+
+/// 使用率の系列の倍率（スパークラインは整数値のため0.1%単位に換算）
+const USAGE_SCALE: f64 = 10.0;
+
+/// ストレージ使用状況の推移ViewModel
+#[derive(Debug, Clone, Default)]
+pub struct StorageTrendViewModel {
+    /// 使用率（0.1%単位、古い順）
+    pub usage_series: Vec<u64>,
+    /// イベント件数（古い順）
+    pub entries_series: Vec<u64>,
+    /// Projectionへの未反映イベント数（古い順）
+    pub lag_series: Vec<u64>,
+    /// 最新の使用率と期間中の増減（例: "42.5% (+1.2pt)"）
+    pub usage_label: String,
+    /// 最新のイベント件数と期間中の増減（例: "1200件 (+35)"）
+    pub entries_label: String,
+    /// 最新の未反映イベント数と期間中の最大値
+    pub lag_label: String,
+    /// 標本の期間（例: "2024-04-01 09:00 〜 2024-04-02 09:00 (145件)"）
+    pub period_label: String,
+    /// 使用率が警告水準（80%）以上
+    pub usage_warning: bool,
+}
+
+impl StorageTrendViewModel {
+    pub fn from_samples(samples: &[StorageSample]) -> Self {
+        let (Some(first), Some(last)) = (samples.first(), samples.last()) else {
+            return Self::default();
+        };
+
+        let max_lag = samples.iter().map(|s| s.projection_lag).max().unwrap_or_default();
+
+        Self {
+            usage_series: samples
+                .iter()
+                .map(|s| (s.usage_percent * USAGE_SCALE).round().max(0.0) as u64)
+                .collect(),
+            entries_series: samples.iter().map(|s| s.entries).collect(),
+            lag_series: samples.iter().map(|s| s.projection_lag).collect(),
+            usage_label: format!(
+                "{:.1}% ({:+.1}pt)",
+                last.usage_percent,
+                last.usage_percent - first.usage_percent
+            ),
+            entries_label: format!(
+                "{}件 ({:+})",
+                last.entries,
+                last.entries as i64 - first.entries as i64
+            ),
+            lag_label: format!("{}件 (最大 {}件)", last.projection_lag, max_lag),
+            period_label: format!(
+                "{} 〜 {} ({}件)",
+                first.sampled_at.format("%Y-%m-%d %H:%M"),
+                last.sampled_at.format("%Y-%m-%d %H:%M"),
+                samples.len()
+            ),
+            usage_warning: last.usage_percent >= 80.0,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.usage_series.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, TimeZone, Utc};
+
+    use super::*;
+
+    fn sample(
+        minutes: i64,
+        usage_percent: f64,
+        entries: u64,
+        projection_lag: u64,
+    ) -> StorageSample {
+        StorageSample {
+            sampled_at: Utc.with_ymd_and_hms(2024, 4, 1, 9, 0, 0).unwrap()
+                + Duration::minutes(minutes),
+            usage_percent,
+            used_size: 0,
+            map_size: 0,
+            entries,
+            projection_lag,
+        }
+    }
+
+    #[test]
+    fn test_from_samples_builds_series_and_trend_labels() {
+        let view_model = StorageTrendViewModel::from_samples(&[
+            sample(0, 41.25, 1000, 0),
+            sample(10, 41.9, 1020, 12),
+            sample(20, 42.5, 1035, 3),
+        ]);
+
+        assert_eq!(view_model.usage_series, vec![413, 419, 425]);
+        assert_eq!(view_model.entries_series, vec![1000, 1020, 1035]);
+        assert_eq!(view_model.lag_series, vec![0, 12, 3]);
+        assert_eq!(view_model.usage_label, "42.5% (+1.2pt)");
+        assert_eq!(view_model.entries_label, "1035件 (+35)");
+        assert_eq!(view_model.lag_label, "3件 (最大 12件)");
+        assert_eq!(view_model.period_label, "2024-04-01 09:00 〜 2024-04-01 09:20 (3件)");
+        assert!(!view_model.usage_warning);
+    }
+
+    #[test]
+    fn test_from_samples_without_samples_is_empty() {
+        assert!(StorageTrendViewModel::from_samples(&[]).is_empty());
+    }
+}
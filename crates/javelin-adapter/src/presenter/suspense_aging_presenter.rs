@@ -0,0 +1,137 @@
+// SuspenseAgingPresenter - 仮勘定の滞留状況の表示整形
+// 経過日数の区分ごとの集計と整理仕訳の提案をビュー向けに整形する
+
+use javelin_application::dtos::response::{AmountFormat, SuspenseAgingResponse};
+
+/// 仮勘定滞留状況ViewModel
+#[derive(Debug, Clone, Default)]
+pub struct SuspenseAgingViewModel {
+    pub as_of_date: String,
+    pub items: Vec<SuspenseAgingItemViewModel>,
+    /// 経過日数の区分ごとの件数・金額の要約
+    pub bucket_summary: String,
+    pub suggestion_count: usize,
+}
+
+/// 滞留中の仮勘定計上ViewModel
+#[derive(Debug, Clone)]
+pub struct SuspenseAgingItemViewModel {
+    pub entry_id: String,
+    pub entry_number: String,
+    pub line_number: u32,
+    pub transaction_date: String,
+    pub account_code: String,
+    pub side_label: String,
+    pub amount: String,
+    pub description: String,
+    pub age_days: i64,
+    pub bucket_label: String,
+    /// 91日以上滞留（強調表示）
+    pub overdue: bool,
+    /// 整理仕訳の提案（例: "→ 2000 (EN-... 2024-02-01)"）
+    pub suggestion: Option<String>,
+}
+
+impl SuspenseAgingViewModel {
+    pub fn from_response(response: &SuspenseAgingResponse, amount_format: &AmountFormat) -> Self {
+        let items: Vec<SuspenseAgingItemViewModel> = response
+            .items
+            .iter()
+            .map(|item| SuspenseAgingItemViewModel {
+                entry_id: item.entry_id.clone(),
+                entry_number: item.entry_number.clone(),
+                line_number: item.line_number,
+                transaction_date: item.transaction_date.clone(),
+                account_code: item.account_code.clone(),
+                side_label: if item.side == "Debit" {
+                    "借方"
+                } else {
+                    "貸方"
+                }
+                .to_string(),
+                amount: amount_format.format(item.amount, &item.currency),
+                description: item.description.clone().unwrap_or_default(),
+                age_days: item.age_days,
+                bucket_label: item.bucket.label().to_string(),
+                overdue: item.age_days > 90,
+                suggestion: item.suggestion.as_ref().map(|s| {
+                    format!(
+                        "→ {} ({} {})",
+                        s.account_code, s.matched_entry_number, s.matched_transaction_date
+                    )
+                }),
+            })
+            .collect();
+
+        let bucket_summary = response
+            .buckets
+            .iter()
+            .map(|b| format!("{} {}件", b.bucket.label(), b.count))
+            .collect::<Vec<_>>()
+            .join(" / ");
+
+        Self {
+            as_of_date: response.as_of_date.clone(),
+            suggestion_count: items.iter().filter(|item| item.suggestion.is_some()).count(),
+            items,
+            bucket_summary,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use javelin_application::dtos::response::{
+        AgingBucket, AgingBucketSummary, ClearingSuggestion, SuspenseAgingItem,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_from_response_formats_items_and_buckets() {
+        let response = SuspenseAgingResponse {
+            as_of_date: "2024-03-31".to_string(),
+            items: vec![SuspenseAgingItem {
+                entry_id: "e1".to_string(),
+                entry_number: "EN-001".to_string(),
+                line_number: 1,
+                transaction_date: "2023-12-01".to_string(),
+                account_code: "9999".to_string(),
+                side: "Debit".to_string(),
+                amount: 10000.0,
+                currency: "JPY".to_string(),
+                description: None,
+                age_days: 121,
+                bucket: AgingBucket::Over90Days,
+                suggestion: Some(ClearingSuggestion {
+                    matched_entry_id: "m1".to_string(),
+                    matched_entry_number: "EN-002".to_string(),
+                    matched_line_number: 2,
+                    matched_transaction_date: "2024-01-10".to_string(),
+                    account_code: "2000".to_string(),
+                    description: None,
+                }),
+            }],
+            buckets: AgingBucket::ALL
+                .into_iter()
+                .map(|bucket| AgingBucketSummary {
+                    bucket,
+                    count: usize::from(bucket == AgingBucket::Over90Days),
+                    amount: 0.0,
+                })
+                .collect(),
+        };
+
+        let view_model = SuspenseAgingViewModel::from_response(&response, &AmountFormat::default());
+
+        assert_eq!(view_model.suggestion_count, 1);
+        let item = &view_model.items[0];
+        assert_eq!(item.side_label, "借方");
+        assert!(item.overdue);
+        assert_eq!(item.suggestion.as_deref(), Some("→ 2000 (EN-002 2024-01-10)"));
+        assert_eq!(
+            view_model.bucket_summary,
+            "30日以内 0件 / 31〜60日 0件 / 61〜90日 0件 / 91日以上 1件"
+        );
+    }
+}
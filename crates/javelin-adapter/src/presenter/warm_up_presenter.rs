@@ -0,0 +1,33 @@
+// WarmUpPresenter - Projection構築進捗の表示整形
+
+use javelin_application::query_service::WarmUpProgress;
+
+/// 構築中の進捗メッセージ（構築中でない場合はNone）
+pub fn warm_up_message(label: &str, progress: &WarmUpProgress) -> Option<String> {
+    progress.in_progress.then(|| {
+        format!(
+            "{}を準備中... {}% ({}/{}件)",
+            label,
+            progress.percent(),
+            progress.processed,
+            progress.total
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_warm_up_message_only_while_in_progress() {
+        let progress = WarmUpProgress { processed: 30, total: 120, in_progress: true };
+        assert_eq!(
+            warm_up_message("元帳", &progress).as_deref(),
+            Some("元帳を準備中... 25% (30/120件)")
+        );
+
+        let done = WarmUpProgress { in_progress: false, ..progress };
+        assert_eq!(warm_up_message("元帳", &done), None);
+    }
+}
@@ -0,0 +1,183 @@
+// StartupReport - 起動診断レポート
+// 責務: 起動処理の各段階の結果と所要時間を記録し、システム情報画面へ提供する
+//
+// 起動処理はTUIの代替画面を開く前に行われるため、標準出力へ表示した内容は
+// 画面の切り替えで見えなくなる。起動時の情報はこのレポートに集約して画面で確認する。
+
+use std::{
+    path::Path,
+    sync::{Arc, OnceLock},
+    time::{Duration, Instant},
+};
+
+/// 起動時に記録したレポート（起動完了時に一度だけ設定）
+static STARTUP_REPORT: OnceLock<Arc<StartupReport>> = OnceLock::new();
+
+/// 起動診断レポートを設定
+pub fn init_startup_report(report: StartupReport) {
+    let _ = STARTUP_REPORT.set(Arc::new(report));
+}
+
+/// 起動診断レポートを取得（起動処理を経ていない場合はNone）
+pub fn startup_report() -> Option<Arc<StartupReport>> {
+    STARTUP_REPORT.get().cloned()
+}
+
+/// 起動処理の段階
+#[derive(Debug, Clone)]
+pub struct StartupStep {
+    pub name: String,
+    pub details: Vec<String>,
+    pub duration: Duration,
+}
+
+/// 保存先ごとのディスク使用量
+#[derive(Debug, Clone)]
+pub struct StoreSize {
+    pub name: String,
+    pub bytes: u64,
+}
+
+/// 起動診断レポート
+#[derive(Debug, Clone)]
+pub struct StartupReport {
+    pub version: String,
+    /// 起動日時（設定タイムゾーンでの表示）
+    pub started_at: String,
+    pub data_dir: String,
+    pub launch_mode: String,
+    pub time_zone: String,
+    pub steps: Vec<StartupStep>,
+    /// 起動時に行った復旧・再構築などの処置
+    pub actions: Vec<String>,
+    pub store_sizes: Vec<StoreSize>,
+    pub total_duration: Duration,
+}
+
+impl StartupReport {
+    pub fn new(
+        version: impl Into<String>,
+        data_dir: &Path,
+        launch_mode: impl Into<String>,
+    ) -> Self {
+        Self {
+            version: version.into(),
+            started_at: String::new(),
+            data_dir: data_dir.display().to_string(),
+            launch_mode: launch_mode.into(),
+            time_zone: String::new(),
+            steps: Vec::new(),
+            actions: Vec::new(),
+            store_sizes: Vec::new(),
+            total_duration: Duration::ZERO,
+        }
+    }
+
+    /// 段階の結果を記録（所要時間は `started` からの経過時間）
+    pub fn record_step(&mut self, name: impl Into<String>, started: Instant, details: Vec<String>) {
+        self.steps
+            .push(StartupStep { name: name.into(), details, duration: started.elapsed() });
+    }
+
+    /// 起動時に行った処置を記録
+    pub fn record_action(&mut self, action: impl Into<String>) {
+        self.actions.push(action.into());
+    }
+
+    /// 保存先の合計使用量
+    pub fn total_store_size(&self) -> u64 {
+        self.store_sizes.iter().map(|store| store.bytes).sum()
+    }
+
+    /// テキスト形式（ログファイル出力用）
+    pub fn to_lines(&self) -> Vec<String> {
+        let mut lines = vec![
+            format!("Javelin {}", self.version),
+            format!("起動日時: {}", self.started_at),
+            format!("起動モード: {}", self.launch_mode),
+            format!("データディレクトリ: {}", self.data_dir),
+            format!("タイムゾーン: {}", self.time_zone),
+            format!("起動所要時間: {}", format_duration(self.total_duration)),
+            String::new(),
+            "[起動処理]".to_string(),
+        ];
+        for step in &self.steps {
+            lines.push(format!("{} ({})", step.name, format_duration(step.duration)));
+            lines.extend(step.details.iter().map(|detail| format!("  - {}", detail)));
+        }
+
+        lines.push(String::new());
+        lines.push("[処置]".to_string());
+        if self.actions.is_empty() {
+            lines.push("なし".to_string());
+        }
+        lines.extend(self.actions.iter().map(|action| format!("- {}", action)));
+
+        lines.push(String::new());
+        lines.push("[ディスク使用量]".to_string());
+        for store in &self.store_sizes {
+            lines.push(format!("{}: {}", store.name, format_bytes(store.bytes)));
+        }
+        lines.push(format!("合計: {}", format_bytes(self.total_store_size())));
+        lines
+    }
+}
+
+/// 所要時間の表示（1秒未満はミリ秒）
+pub fn format_duration(duration: Duration) -> String {
+    if duration < Duration::from_secs(1) {
+        format!("{}ms", duration.as_millis())
+    } else {
+        format!("{:.1}s", duration.as_secs_f64())
+    }
+}
+
+/// バイト数の表示（KiB/MiB/GiB）
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 3] = ["KiB", "MiB", "GiB"];
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut value = bytes as f64;
+    let mut unit = "B";
+    for next in UNITS {
+        if value < 1024.0 {
+            break;
+        }
+        value /= 1024.0;
+        unit = next;
+    }
+    format!("{:.1} {}", value, unit)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_report_lines_include_steps_actions_and_sizes() {
+        let mut report = StartupReport::new("0.1.0", Path::new("/tmp/data"), "通常");
+        report.time_zone = "UTC+09:00".to_string();
+        report.record_step("Projection", Instant::now(), vec!["最新イベント番号: 25".to_string()]);
+        report.record_action("Projectionを再構築しました（10 → 25）");
+        report.store_sizes = vec![
+            StoreSize { name: "events".to_string(), bytes: 2048 },
+            StoreSize { name: "projections".to_string(), bytes: 3 * 1024 * 1024 },
+        ];
+
+        let lines = report.to_lines();
+        assert_eq!(lines[0], "Javelin 0.1.0");
+        assert!(lines.contains(&"  - 最新イベント番号: 25".to_string()));
+        assert!(lines.contains(&"- Projectionを再構築しました（10 → 25）".to_string()));
+        assert!(lines.contains(&"events: 2.0 KiB".to_string()));
+        assert!(lines.contains(&"合計: 3.0 MiB".to_string()));
+    }
+
+    #[test]
+    fn test_format_duration_and_bytes() {
+        assert_eq!(format_duration(Duration::from_millis(250)), "250ms");
+        assert_eq!(format_duration(Duration::from_millis(1500)), "1.5s");
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(5 * 1024 * 1024 * 1024), "5.0 GiB");
+    }
+}
@@ -2,24 +2,40 @@
 // 責務: 共通コンポーネントの定義
 
 pub mod calendar;
+pub mod calendar_picker;
 pub mod data_table;
+pub mod error_panel;
 pub mod event_viewer;
+pub mod export_prompt;
+pub mod field_help_overlay;
 pub mod info_panel;
 pub mod input_field;
 pub mod list_selector;
 pub mod loading_spinner;
+pub mod master_change_history;
 pub mod overlay_selector;
+pub mod parameter_form;
+pub mod password_prompt;
 pub mod status_bar;
 pub mod tabbed_journal_entry_form;
+pub mod tax_calculator;
 
 // Re-export
 pub use calendar::*;
+pub use calendar_picker::*;
 pub use data_table::*;
+pub use error_panel::*;
 pub use event_viewer::*;
+pub use export_prompt::*;
+pub use field_help_overlay::*;
 pub use info_panel::*;
 pub use input_field::*;
 pub use list_selector::*;
 pub use loading_spinner::*;
+pub use master_change_history::*;
 pub use overlay_selector::*;
+pub use parameter_form::*;
+pub use password_prompt::*;
 pub use status_bar::*;
 pub use tabbed_journal_entry_form::*;
+pub use tax_calculator::*;
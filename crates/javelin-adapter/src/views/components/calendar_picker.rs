@@ -0,0 +1,264 @@
+// CalendarPicker - カレンダー選択オーバーレイ
+// 責務: 日付入力欄の月グリッド表示、hjklでの日付選択、会計期間・休日の強調表示
+
+use chrono::{Datelike, Duration, Months, NaiveDate};
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Clear, Paragraph},
+};
+
+use crate::presenter::{CalendarMasterViewModel, HolidayViewModel};
+
+/// カレンダー選択オーバーレイ
+pub struct CalendarPicker {
+    visible: bool,
+    /// カーソル位置の日付
+    cursor: NaiveDate,
+    /// 休日（カレンダーマスタ）
+    holidays: Vec<HolidayViewModel>,
+    /// 会計年度開始月
+    fiscal_year_start_month: u32,
+}
+
+impl Default for CalendarPicker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CalendarPicker {
+    pub fn new() -> Self {
+        Self {
+            visible: false,
+            cursor: crate::clock::business_date(),
+            holidays: Vec::new(),
+            fiscal_year_start_month: 4,
+        }
+    }
+
+    /// カレンダーマスタを設定
+    pub fn set_calendar_master(&mut self, view_model: CalendarMasterViewModel) {
+        self.holidays = view_model.holidays;
+        if (1..=12).contains(&view_model.fiscal_year_start_month) {
+            self.fiscal_year_start_month = view_model.fiscal_year_start_month;
+        }
+    }
+
+    /// オーバーレイを開く（入力値の日付、なければ当日にカーソルを合わせる）
+    pub fn open(&mut self, date: Option<NaiveDate>) {
+        self.cursor = date.unwrap_or_else(crate::clock::business_date);
+        self.visible = true;
+    }
+
+    /// オーバーレイを閉じる
+    pub fn close(&mut self) {
+        self.visible = false;
+    }
+
+    /// 表示中かどうか
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// カーソル位置の日付
+    pub fn selected_date(&self) -> NaiveDate {
+        self.cursor
+    }
+
+    /// 日単位で移動（h/l: ±1日、j/k: ±1週）
+    pub fn move_days(&mut self, days: i64) {
+        if let Some(date) = self.cursor.checked_add_signed(Duration::days(days)) {
+            self.cursor = date;
+        }
+    }
+
+    /// 月単位で移動（H/L）
+    pub fn move_months(&mut self, months: i32) {
+        let moved = if months >= 0 {
+            self.cursor.checked_add_months(Months::new(months as u32))
+        } else {
+            self.cursor.checked_sub_months(Months::new(months.unsigned_abs()))
+        };
+        if let Some(date) = moved {
+            self.cursor = date;
+        }
+    }
+
+    /// 会計年度と期間（1始まり）を取得
+    pub fn fiscal_period(&self, date: NaiveDate) -> (i32, u32) {
+        let start = self.fiscal_year_start_month;
+        let fiscal_year = if date.month() >= start {
+            date.year()
+        } else {
+            date.year() - 1
+        };
+        let period = (date.month() + 12 - start) % 12 + 1;
+        (fiscal_year, period)
+    }
+
+    /// 指定日の休日名
+    fn holiday_name(&self, date: NaiveDate) -> Option<&str> {
+        self.holidays.iter().find(|h| h.date == date).map(|h| h.name.as_str())
+    }
+
+    /// 描画
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        if !self.visible {
+            return;
+        }
+
+        let overlay_area = Self::centered_rect(40, 22, area);
+        frame.render_widget(Clear, overlay_area);
+
+        let (fiscal_year, period) = self.fiscal_period(self.cursor);
+        let title = format!(
+            " {}年{}月  (FY{} 第{}期間) ",
+            self.cursor.year(),
+            self.cursor.month(),
+            fiscal_year,
+            period
+        );
+        let block = Block::default()
+            .title(title)
+            .title_style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(Color::Cyan));
+        let inner = block.inner(overlay_area);
+        frame.render_widget(block, overlay_area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(8), Constraint::Min(2), Constraint::Length(1)])
+            .split(inner);
+
+        frame.render_widget(
+            Paragraph::new(self.grid_lines()).alignment(Alignment::Center),
+            chunks[0],
+        );
+        frame.render_widget(Paragraph::new(self.holiday_lines()), chunks[1]);
+        frame.render_widget(
+            Paragraph::new(Line::from(Span::styled(
+                "[hjkl] 移動  [H/L] 前月/翌月  [Enter] 確定  [Esc] 取消",
+                Style::default().fg(Color::DarkGray),
+            )))
+            .alignment(Alignment::Center),
+            chunks[2],
+        );
+    }
+
+    /// 月グリッドを構築（日曜始まり）
+    fn grid_lines(&self) -> Vec<Line<'static>> {
+        let today = crate::clock::business_date();
+        let first = self.cursor.with_day(1).unwrap_or(self.cursor);
+        let offset = first.weekday().num_days_from_sunday() as i64;
+        let grid_start = first - Duration::days(offset);
+        let month_end = first
+            .checked_add_months(Months::new(1))
+            .map(|next| next - Duration::days(1))
+            .unwrap_or(first);
+        let fiscal_year_end = self.fiscal_period(self.cursor).1 == 12;
+
+        let mut lines = vec![Line::from(
+            ["日", "月", "火", "水", "木", "金", "土"]
+                .iter()
+                .enumerate()
+                .map(|(i, name)| {
+                    let color = match i {
+                        0 => Color::Red,
+                        6 => Color::Blue,
+                        _ => Color::Gray,
+                    };
+                    Span::styled(
+                        format!(" {} ", name),
+                        Style::default().fg(color).add_modifier(Modifier::BOLD),
+                    )
+                })
+                .collect::<Vec<_>>(),
+        )];
+
+        for week in 0..6 {
+            let spans = (0..7)
+                .map(|weekday| {
+                    let date = grid_start + Duration::days(week * 7 + weekday);
+                    let in_period = date.month() == self.cursor.month();
+
+                    let mut style = if !in_period {
+                        // 会計期間外（前後月）
+                        Style::default().fg(Color::DarkGray).add_modifier(Modifier::DIM)
+                    } else if weekday == 0 || self.holiday_name(date).is_some() {
+                        Style::default().fg(Color::Red)
+                    } else if weekday == 6 {
+                        Style::default().fg(Color::Blue)
+                    } else {
+                        Style::default().fg(Color::White)
+                    };
+                    if in_period && fiscal_year_end {
+                        // 決算月は背景で強調
+                        style = style.bg(Color::Rgb(40, 40, 70));
+                    }
+                    if date == month_end {
+                        // 期間末日（締日）
+                        style = style.add_modifier(Modifier::UNDERLINED);
+                    }
+                    if date == today {
+                        style = style.fg(Color::Yellow).add_modifier(Modifier::BOLD);
+                    }
+                    if date == self.cursor {
+                        style = style.add_modifier(Modifier::REVERSED | Modifier::BOLD);
+                    }
+
+                    Span::styled(format!("{:>3} ", date.day()), style)
+                })
+                .collect::<Vec<_>>();
+            lines.push(Line::from(spans));
+        }
+
+        lines
+    }
+
+    /// カーソル日と当月の休日一覧
+    fn holiday_lines(&self) -> Vec<Line<'static>> {
+        let mut lines = Vec::new();
+
+        let selected = match self.holiday_name(self.cursor) {
+            Some(name) => format!(" {} ({})", self.cursor.format("%Y-%m-%d"), name),
+            None => format!(" {}", self.cursor.format("%Y-%m-%d")),
+        };
+        lines.push(Line::from(Span::styled(
+            selected,
+            Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+        )));
+
+        for holiday in self.holidays.iter().filter(|h| {
+            h.date.year() == self.cursor.year() && h.date.month() == self.cursor.month()
+        }) {
+            lines.push(Line::from(Span::styled(
+                format!("  {:>2}日 {}", holiday.date.day(), holiday.name),
+                Style::default().fg(Color::Red),
+            )));
+        }
+
+        lines
+    }
+
+    /// 中央配置の矩形（横は割合、縦は行数）
+    fn centered_rect(width_percent: u16, height: u16, r: Rect) -> Rect {
+        let vertical = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Fill(1), Constraint::Length(height), Constraint::Fill(1)])
+            .split(r);
+
+        Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([
+                Constraint::Percentage((100 - width_percent) / 2),
+                Constraint::Percentage(width_percent),
+                Constraint::Percentage((100 - width_percent) / 2),
+            ])
+            .split(vertical[1])[1]
+    }
+}
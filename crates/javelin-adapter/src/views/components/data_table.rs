@@ -1,15 +1,95 @@
 // DataTable - データテーブルコンポーネント
 // 責務: 表形式データの表示（Ratatui Tableウィジェット活用）
 
+use std::cmp::Ordering;
+
 use ratatui::{
     Frame,
     layout::Rect,
     style::{Color, Modifier, Style},
-    widgets::{Block, BorderType, Borders, Row, Table, TableState},
+    widgets::{Block, BorderType, Borders, Cell, Row, Table, TableState},
 };
 
 use super::LoadingSpinner;
 
+/// 列幅の最小値
+const MIN_COLUMN_WIDTH: u16 = 4;
+/// 列幅の最大値
+const MAX_COLUMN_WIDTH: u16 = 80;
+/// 列幅以外に必要な横幅（左右の枠線 + 選択行の記号）
+const TABLE_CHROME_WIDTH: u16 = 4;
+
+/// 列の表示設定（テーブル定義上の列番号で管理）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ColumnLayout {
+    pub column: usize,
+    pub visible: bool,
+    pub width: u16,
+}
+
+/// 並び替えキー
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SortKey {
+    pub column: usize,
+    pub descending: bool,
+}
+
+/// 出力形式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ExportFormat {
+    #[default]
+    Csv,
+    Tsv,
+}
+
+impl ExportFormat {
+    /// ファイル拡張子
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "csv",
+            ExportFormat::Tsv => "tsv",
+        }
+    }
+
+    /// 表示名
+    pub fn label(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "CSV",
+            ExportFormat::Tsv => "TSV",
+        }
+    }
+
+    /// もう一方の形式
+    pub fn toggled(&self) -> Self {
+        match self {
+            ExportFormat::Csv => ExportFormat::Tsv,
+            ExportFormat::Tsv => ExportFormat::Csv,
+        }
+    }
+
+    fn delimiter(&self) -> char {
+        match self {
+            ExportFormat::Csv => ',',
+            ExportFormat::Tsv => '\t',
+        }
+    }
+
+    /// セル値を出力形式に合わせて変換
+    fn escape(&self, value: &str) -> String {
+        match self {
+            ExportFormat::Csv => {
+                if value.contains([',', '"', '\n', '\r']) {
+                    format!("\"{}\"", value.replace('"', "\"\""))
+                } else {
+                    value.to_string()
+                }
+            }
+            // TSVは区切り文字と改行を空白に置き換える
+            ExportFormat::Tsv => value.replace(['\t', '\n', '\r'], " "),
+        }
+    }
+}
+
 /// データテーブルの状態
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum DataTableState {
@@ -28,7 +108,16 @@ pub struct DataTable {
     title: String,
     headers: Vec<String>,
     rows: Vec<Vec<String>>,
-    column_widths: Vec<u16>,
+    /// 列の表示設定（表示順）
+    columns: Vec<ColumnLayout>,
+    /// 並び替えキー
+    sort: Option<SortKey>,
+    /// 幅変更・移動の対象列（テーブル定義上の列番号）
+    active_column: usize,
+    /// 表示順の行インデックス（並び替え結果）
+    display_order: Vec<usize>,
+    /// 幅が足りない場合に先に折りたたむ列（テーブル定義上の列番号）
+    collapse_order: Vec<usize>,
     table_state: TableState,
     highlight_style: Style,
     state: DataTableState,
@@ -42,7 +131,13 @@ impl DataTable {
             title: title.into(),
             headers,
             rows: Vec::new(),
-            column_widths: vec![15; column_count],
+            columns: (0..column_count)
+                .map(|column| ColumnLayout { column, visible: true, width: 15 })
+                .collect(),
+            sort: None,
+            active_column: 0,
+            display_order: Vec::new(),
+            collapse_order: Vec::new(),
             table_state: TableState::default(),
             highlight_style: Style::default()
                 .fg(Color::Black)
@@ -56,14 +151,202 @@ impl DataTable {
     pub fn with_rows(mut self, rows: Vec<Vec<String>>) -> Self {
         self.rows = rows;
         self.state = DataTableState::Showing;
+        self.apply_sort();
         self
     }
 
     pub fn with_column_widths(mut self, widths: Vec<u16>) -> Self {
-        self.column_widths = widths;
+        for (layout, width) in self.columns.iter_mut().zip(widths) {
+            layout.width = width;
+        }
+        self
+    }
+
+    /// 幅が足りない場合に折りたたむ列の優先順を設定
+    ///
+    /// 指定のない列は右端から折りたたむ。
+    pub fn with_collapse_order(mut self, columns: Vec<usize>) -> Self {
+        self.collapse_order = columns;
         self
     }
 
+    /// 列の表示設定（表示順）
+    pub fn column_layout(&self) -> &[ColumnLayout] {
+        &self.columns
+    }
+
+    /// 並び替えキー
+    pub fn sort_key(&self) -> Option<SortKey> {
+        self.sort
+    }
+
+    /// 保存済みの表示設定を適用
+    ///
+    /// 定義にない列は無視し、設定に含まれない列は末尾に表示する。
+    /// 表示列がなくなる設定は適用しない。
+    pub fn apply_layout(&mut self, columns: Vec<ColumnLayout>, sort: Option<SortKey>) {
+        let column_count = self.headers.len();
+        let mut applied: Vec<ColumnLayout> = Vec::with_capacity(column_count);
+        for layout in columns {
+            if layout.column < column_count && !applied.iter().any(|c| c.column == layout.column) {
+                applied.push(ColumnLayout {
+                    width: layout.width.clamp(MIN_COLUMN_WIDTH, MAX_COLUMN_WIDTH),
+                    ..layout
+                });
+            }
+        }
+        for layout in &self.columns {
+            if !applied.iter().any(|c| c.column == layout.column) {
+                applied.push(*layout);
+            }
+        }
+        if !applied.iter().any(|c| c.visible) {
+            return;
+        }
+
+        self.columns = applied;
+        self.sort = sort.filter(|key| self.is_visible(key.column));
+        let next_active = self
+            .sort
+            .map(|key| key.column)
+            .or_else(|| self.visible_columns().next())
+            .unwrap_or(0);
+        self.active_column = next_active;
+        self.apply_sort();
+    }
+
+    /// 並び替えキーを切り替え
+    ///
+    /// 表示列を左から順に 昇順 → 降順 → 次の列 と巡回し、最後の列の降順の次は並び替えなし。
+    pub fn cycle_sort(&mut self) {
+        let visible: Vec<usize> = self.visible_columns().collect();
+        self.sort = match self.sort {
+            None => visible.first().map(|&column| SortKey { column, descending: false }),
+            Some(SortKey { column, descending: false }) => {
+                Some(SortKey { column, descending: true })
+            }
+            Some(SortKey { column, descending: true }) => visible
+                .iter()
+                .position(|&c| c == column)
+                .and_then(|i| visible.get(i + 1))
+                .map(|&column| SortKey { column, descending: false }),
+        };
+        if let Some(key) = self.sort {
+            self.active_column = key.column;
+        }
+        self.apply_sort();
+    }
+
+    /// 対象列の幅を変更
+    pub fn resize_active_column(&mut self, delta: i16) {
+        let active = self.active_column;
+        if let Some(layout) = self.columns.iter_mut().find(|c| c.column == active) {
+            layout.width = layout
+                .width
+                .saturating_add_signed(delta)
+                .clamp(MIN_COLUMN_WIDTH, MAX_COLUMN_WIDTH);
+        }
+    }
+
+    /// 対象列を表示順で左右に移動
+    pub fn move_active_column(&mut self, forward: bool) {
+        let Some(index) = self.columns.iter().position(|c| c.column == self.active_column) else {
+            return;
+        };
+        let target = if forward {
+            (index + 1 < self.columns.len()).then_some(index + 1)
+        } else {
+            index.checked_sub(1)
+        };
+        if let Some(target) = target {
+            self.columns.swap(index, target);
+        }
+    }
+
+    /// 対象列を非表示にする（最後の表示列は非表示にしない）
+    pub fn hide_active_column(&mut self) {
+        if self.visible_columns().count() <= 1 {
+            return;
+        }
+        let active = self.active_column;
+        if let Some(layout) = self.columns.iter_mut().find(|c| c.column == active) {
+            layout.visible = false;
+        }
+        if self.sort.is_some_and(|key| key.column == active) {
+            self.sort = None;
+            self.apply_sort();
+        }
+        let next_active = self.visible_columns().next().unwrap_or(0);
+        self.active_column = next_active;
+    }
+
+    /// すべての列を表示する
+    pub fn show_all_columns(&mut self) {
+        for layout in &mut self.columns {
+            layout.visible = true;
+        }
+    }
+
+    fn is_visible(&self, column: usize) -> bool {
+        self.columns.iter().any(|c| c.column == column && c.visible)
+    }
+
+    fn visible_columns(&self) -> impl Iterator<Item = usize> + '_ {
+        self.columns.iter().filter(|c| c.visible).map(|c| c.column)
+    }
+
+    /// 描画幅に収まる表示列（収まらない列は折りたたむ。最低1列は表示する）
+    ///
+    /// 戻り値の2つ目は折りたたんだ列数。表示設定（`column_layout`）と出力対象は変えない。
+    fn fitted_columns(&self, width: u16) -> (Vec<ColumnLayout>, usize) {
+        let mut fitted: Vec<ColumnLayout> =
+            self.columns.iter().filter(|c| c.visible).copied().collect();
+        let available = width.saturating_sub(TABLE_CHROME_WIDTH);
+        let required = |columns: &[ColumnLayout]| -> u16 {
+            columns.iter().map(|c| c.width).sum::<u16>() + columns.len().saturating_sub(1) as u16
+        };
+
+        let mut collapsed = 0;
+        while fitted.len() > 1 && required(&fitted) > available {
+            let index = self
+                .collapse_order
+                .iter()
+                .find_map(|column| fitted.iter().position(|c| c.column == *column))
+                .unwrap_or(fitted.len() - 1);
+            fitted.remove(index);
+            collapsed += 1;
+        }
+        (fitted, collapsed)
+    }
+
+    /// 折りたたんだ列がある場合はタイトルに列数を添える
+    fn title_with_collapsed(&self, collapsed: usize) -> String {
+        if collapsed == 0 {
+            self.title.clone()
+        } else {
+            format!("{} (+{}列 省略)", self.title, collapsed)
+        }
+    }
+
+    /// 並び替えキーに従って表示順を再計算
+    fn apply_sort(&mut self) {
+        let mut order: Vec<usize> = (0..self.rows.len()).collect();
+        if let Some(key) = self.sort {
+            let empty = String::new();
+            order.sort_by(|&a, &b| {
+                let left = self.rows[a].get(key.column).unwrap_or(&empty);
+                let right = self.rows[b].get(key.column).unwrap_or(&empty);
+                let ordering = compare_cells(left, right);
+                if key.descending {
+                    ordering.reverse()
+                } else {
+                    ordering
+                }
+            });
+        }
+        self.display_order = order;
+    }
+
     /// ローディング状態に設定
     pub fn start_loading(&mut self) {
         self.state = DataTableState::Loading;
@@ -78,6 +361,7 @@ impl DataTable {
     pub fn set_data(&mut self, rows: Vec<Vec<String>>) {
         self.rows = rows;
         self.state = DataTableState::Showing;
+        self.apply_sort();
     }
 
     /// エラー状態に設定
@@ -85,15 +369,21 @@ impl DataTable {
         self.state = DataTableState::Error(message);
     }
 
+    /// ローディング中かどうか
+    pub fn is_loading(&self) -> bool {
+        matches!(self.state, DataTableState::Loading | DataTableState::LoadingWithProgress(_))
+    }
+
     /// ローディングアニメーションを更新
     pub fn tick_loading(&mut self) {
-        if matches!(self.state, DataTableState::Loading | DataTableState::LoadingWithProgress(_)) {
+        if self.is_loading() {
             self.loading_spinner.tick();
         }
     }
 
     pub fn add_row(&mut self, row: Vec<String>) {
         self.rows.push(row);
+        self.apply_sort();
     }
 
     pub fn select_next(&mut self) {
@@ -132,8 +422,51 @@ impl DataTable {
         self.table_state.select(Some(i));
     }
 
+    /// 表示中の行数
+    pub fn row_count(&self) -> usize {
+        self.display_order.len()
+    }
+
+    /// 表示中の列・並び順でデータを出力
+    ///
+    /// `source_rows` は表示用の行と同じ並び・列構成の元データ（切り詰め前の値）。
+    /// 非表示の列は出力せず、行は現在の並び替え順で出力する。
+    pub fn export(&self, source_rows: &[Vec<String>], format: ExportFormat) -> String {
+        let delimiter = format.delimiter().to_string();
+        let columns: Vec<usize> = self.visible_columns().collect();
+
+        let mut output = columns
+            .iter()
+            .map(|&column| format.escape(&self.headers[column]))
+            .collect::<Vec<_>>()
+            .join(&delimiter);
+        output.push('\n');
+
+        for &index in &self.display_order {
+            let Some(row) = source_rows.get(index) else {
+                continue;
+            };
+            let line = columns
+                .iter()
+                .map(|&column| format.escape(row.get(column).map(String::as_str).unwrap_or("")))
+                .collect::<Vec<_>>()
+                .join(&delimiter);
+            output.push_str(&line);
+            output.push('\n');
+        }
+        output
+    }
+
+    /// 選択中の行インデックス（並び替え前のデータ上の位置）
     pub fn selected_index(&self) -> Option<usize> {
-        self.table_state.selected()
+        self.table_state.selected().and_then(|i| self.display_order.get(i).copied())
+    }
+
+    /// 行を選択（並び替え前のデータ上の位置）
+    pub fn select_index(&mut self, index: usize) {
+        if let Some(position) = self.display_order.iter().position(|&i| i == index) {
+            self.table_state.select(Some(position));
+        }
     }
 
     /// 描画
@@ -147,20 +480,57 @@ impl DataTable {
     }
 
     /// ローディング画面を描画
+    ///
+    /// 列見出しと同じ列幅のスケルトン行を表示し、読み込み後のレイアウトを先に示す。
     fn render_loading(&self, frame: &mut Frame, area: Rect, message: &str) {
-        // 外枠を描画
-        let block = Block::default()
-            .title(self.title.as_str())
-            .title_style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
-            .borders(Borders::ALL)
-            .border_type(BorderType::Thick)
-            .border_style(Style::default().fg(Color::Cyan));
+        let (visible, collapsed) = self.fitted_columns(area.width);
+
+        let header = Row::new(
+            visible
+                .iter()
+                .map(|layout| self.headers.get(layout.column).cloned().unwrap_or_default()),
+        )
+        .style(
+            Style::default()
+                .fg(Color::Black)
+                .bg(Color::DarkGray)
+                .add_modifier(Modifier::BOLD),
+        )
+        .height(1);
+
+        // 枠線・見出しを除いた高さ分のスケルトン行（光る行がアニメーションで下へ流れる）
+        let row_count = area.height.saturating_sub(4) as usize;
+        let shimmer_row = self.loading_spinner.frame_count() % row_count.max(1);
+        let rows: Vec<Row> = (0..row_count)
+            .map(|i| {
+                let (fill, color) = if i == shimmer_row {
+                    ("▒", Color::Gray)
+                } else {
+                    ("░", Color::DarkGray)
+                };
+                Row::new(
+                    visible
+                        .iter()
+                        .map(|layout| fill.repeat(layout.width.saturating_sub(2) as usize)),
+                )
+                .style(Style::default().fg(color))
+            })
+            .collect();
+
+        let constraints: Vec<ratatui::layout::Constraint> =
+            visible.iter().map(|c| ratatui::layout::Constraint::Length(c.width)).collect();
 
-        frame.render_widget(block, area);
+        let table = Table::new(rows, constraints).header(header).block(
+            Block::default()
+                .title(self.title_with_collapsed(collapsed))
+                .title_style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+                .title_bottom(format!(" {} {} ", self.loading_spinner.spinner(), message))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Thick)
+                .border_style(Style::default().fg(Color::Cyan)),
+        );
 
-        // 内側にスピナーを描画
-        let inner = area.inner(ratatui::layout::Margin { horizontal: 1, vertical: 1 });
-        self.loading_spinner.render(frame, inner, message);
+        frame.render_widget(table, area);
     }
 
     /// エラー画面を描画
@@ -195,32 +565,54 @@ impl DataTable {
 
     /// テーブルを描画
     fn render_table(&mut self, frame: &mut Frame, area: Rect) {
-        // ヘッダー行
-        let header = Row::new(self.headers.clone())
+        let (visible, collapsed) = self.fitted_columns(area.width);
+
+        // ヘッダー行（並び替え列に▲▼、対象列に下線）
+        let header_cells: Vec<Cell> = visible
+            .iter()
+            .map(|layout| {
+                let title = self.headers.get(layout.column).cloned().unwrap_or_default();
+                let title = match self.sort {
+                    Some(key) if key.column == layout.column => {
+                        format!("{}{}", title, if key.descending { "▼" } else { "▲" })
+                    }
+                    _ => title,
+                };
+                let style = if layout.column == self.active_column {
+                    Style::default().add_modifier(Modifier::UNDERLINED)
+                } else {
+                    Style::default()
+                };
+                Cell::from(title).style(style)
+            })
+            .collect();
+        let header = Row::new(header_cells)
             .style(Style::default().fg(Color::Black).bg(Color::Cyan).add_modifier(Modifier::BOLD))
             .height(1);
 
         // データ行
         let rows: Vec<Row> = self
-            .rows
+            .display_order
             .iter()
             .enumerate()
-            .map(|(i, row)| {
+            .map(|(i, &row_index)| {
                 let style = if i % 2 == 0 {
                     Style::default().fg(Color::White)
                 } else {
                     Style::default().fg(Color::Gray)
                 };
-                Row::new(row.clone()).style(style)
+                let row = &self.rows[row_index];
+                let cells: Vec<String> = visible
+                    .iter()
+                    .map(|layout| row.get(layout.column).cloned().unwrap_or_default())
+                    .collect();
+                Row::new(cells).style(style)
             })
             .collect();
 
         // カラム幅の制約
-        let constraints: Vec<ratatui::layout::Constraint> = self
-            .column_widths
-            .iter()
-            .map(|&w| ratatui::layout::Constraint::Length(w))
-            .collect();
+        let constraints: Vec<ratatui::layout::Constraint> =
+            visible.iter().map(|c| ratatui::layout::Constraint::Length(c.width)).collect();
 
         // テーブル
         let table = Table::new(rows, constraints)
@@ -229,7 +621,7 @@ impl DataTable {
             .highlight_symbol("> ")
             .block(
                 Block::default()
-                    .title(self.title.as_str())
+                    .title(self.title_with_collapsed(collapsed))
                     .title_style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
                     .borders(Borders::ALL)
                     .border_type(BorderType::Thick)
@@ -239,3 +631,160 @@ impl DataTable {
         frame.render_stateful_widget(table, area, &mut self.table_state);
     }
 }
+
+/// セル値を比較（金額として読める場合は数値で比較）
+fn compare_cells(left: &str, right: &str) -> Ordering {
+    match (parse_numeric(left), parse_numeric(right)) {
+        (Some(l), Some(r)) => l.partial_cmp(&r).unwrap_or(Ordering::Equal),
+        _ => left.trim().cmp(right.trim()),
+    }
+}
+
+/// 金額表示を数値に変換（"1,234"、"(1,234)"、"---" に対応）
+fn parse_numeric(value: &str) -> Option<f64> {
+    let value = value.trim();
+    if value == "---" {
+        return Some(0.0);
+    }
+    let (negative, digits) = match value.strip_prefix('(').and_then(|v| v.strip_suffix(')')) {
+        Some(inner) => (true, inner),
+        None => (false, value),
+    };
+    let number: f64 = digits.replace(',', "").parse().ok()?;
+    Some(if negative { -number } else { number })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table() -> DataTable {
+        DataTable::new("test", vec!["日付".to_string(), "金額".to_string()]).with_rows(vec![
+            vec!["2024-01-03".to_string(), "1,000".to_string()],
+            vec!["2024-01-01".to_string(), "(500)".to_string()],
+            vec!["2024-01-02".to_string(), "20,000".to_string()],
+        ])
+    }
+
+    #[test]
+    fn test_cycle_sort_orders_rows_and_maps_selection() {
+        let mut table = table();
+
+        table.cycle_sort();
+        assert_eq!(table.sort_key(), Some(SortKey { column: 0, descending: false }));
+        table.select_next();
+        assert_eq!(table.selected_index(), Some(1));
+
+        table.cycle_sort();
+        table.cycle_sort();
+        assert_eq!(table.sort_key(), Some(SortKey { column: 1, descending: false }));
+        assert_eq!(table.selected_index(), Some(1));
+        table.select_next();
+        assert_eq!(table.selected_index(), Some(0));
+
+        table.cycle_sort();
+        table.cycle_sort();
+        assert_eq!(table.sort_key(), None);
+    }
+
+    #[test]
+    fn test_apply_layout_ignores_unknown_and_appends_missing_columns() {
+        let mut table = table();
+        table.apply_layout(
+            vec![
+                ColumnLayout { column: 1, visible: true, width: 200 },
+                ColumnLayout { column: 9, visible: true, width: 10 },
+            ],
+            Some(SortKey { column: 1, descending: true }),
+        );
+
+        let columns = table.column_layout();
+        assert_eq!(columns.len(), 2);
+        assert_eq!(columns[0], ColumnLayout { column: 1, visible: true, width: MAX_COLUMN_WIDTH });
+        assert_eq!(columns[1].column, 0);
+
+        table.resize_active_column(-100);
+        assert_eq!(table.column_layout()[0].width, MIN_COLUMN_WIDTH);
+    }
+
+    #[test]
+    fn test_last_visible_column_cannot_be_hidden() {
+        let mut table = table();
+        table.hide_active_column();
+        table.hide_active_column();
+        assert_eq!(table.column_layout().iter().filter(|c| c.visible).count(), 1);
+
+        table.show_all_columns();
+        assert!(table.column_layout().iter().all(|c| c.visible));
+    }
+
+    #[test]
+    fn test_export_follows_sort_and_visible_columns() {
+        let mut table = table();
+        table.cycle_sort();
+        let source = vec![
+            vec!["2024-01-03".to_string(), "1000".to_string()],
+            vec!["2024-01-01".to_string(), "-500".to_string()],
+            vec!["2024-01-02".to_string(), "20000".to_string()],
+        ];
+
+        assert_eq!(
+            table.export(&source, ExportFormat::Csv),
+            "日付,金額\n2024-01-01,-500\n2024-01-02,20000\n2024-01-03,1000\n"
+        );
+
+        table.apply_layout(
+            vec![
+                ColumnLayout { column: 1, visible: true, width: 10 },
+                ColumnLayout { column: 0, visible: false, width: 10 },
+            ],
+            Some(SortKey { column: 1, descending: true }),
+        );
+        assert_eq!(table.export(&source, ExportFormat::Tsv), "金額\n20000\n1000\n-500\n");
+    }
+
+    #[test]
+    fn test_export_escapes_cells() {
+        let table = DataTable::new("test", vec!["摘要".to_string()])
+            .with_rows(vec![vec!["a".to_string()], vec!["b".to_string()]]);
+        let source = vec![vec!["売上, \"A社\"".to_string()], vec!["x\ty".to_string()]];
+
+        assert_eq!(table.export(&source, ExportFormat::Csv), "摘要\n\"売上, \"\"A社\"\"\"\nx\ty\n");
+        assert_eq!(table.export(&source, ExportFormat::Tsv), "摘要\n売上, \"A社\"\nx y\n");
+    }
+
+    #[test]
+    fn test_narrow_width_collapses_columns_by_priority() {
+        let table = DataTable::new(
+            "test",
+            vec!["日付".to_string(), "摘要".to_string(), "科目".to_string(), "金額".to_string()],
+        )
+        .with_column_widths(vec![10, 30, 10, 12])
+        .with_collapse_order(vec![1]);
+
+        let (fitted, collapsed) = table.fitted_columns(100);
+        assert_eq!(fitted.len(), 4);
+        assert_eq!(collapsed, 0);
+
+        // 摘要を先に折りたたみ、続けて右端の列を折りたたむ
+        let (fitted, collapsed) = table.fitted_columns(30);
+        assert_eq!(fitted.iter().map(|c| c.column).collect::<Vec<_>>(), vec![0, 2]);
+        assert_eq!(collapsed, 2);
+
+        let (fitted, _) = table.fitted_columns(5);
+        assert_eq!(fitted.len(), 1);
+        assert_eq!(table.column_layout().len(), 4);
+    }
+
+    #[test]
+    fn test_loading_state_until_data_is_set() {
+        let mut table = DataTable::new("test", vec!["日付".to_string()]);
+        assert!(table.is_loading());
+
+        table.set_loading_progress("準備中 50%".to_string());
+        assert!(table.is_loading());
+
+        table.set_data(vec![vec!["2024-01-01".to_string()]]);
+        assert!(!table.is_loading());
+    }
+}
@@ -0,0 +1,105 @@
+// ErrorPanel - エラー表示オーバーレイ
+// 責務: 構造化エラー（分類・コード・メッセージ・対処方法・問い合わせID）の表示
+
+use javelin_application::{error::ErrorCategory, error_report::ErrorReport};
+use ratatui::{
+    Frame,
+    layout::{Constraint, Flex, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Clear, Paragraph, Wrap},
+};
+
+/// エラー表示オーバーレイ
+pub struct ErrorPanel {
+    report: Option<ErrorReport>,
+}
+
+impl ErrorPanel {
+    pub fn new() -> Self {
+        Self { report: None }
+    }
+
+    /// エラーを表示
+    pub fn show(&mut self, report: ErrorReport) {
+        self.report = Some(report);
+    }
+
+    /// 非表示に設定
+    pub fn hide(&mut self) {
+        self.report = None;
+    }
+
+    /// 表示中かどうか
+    pub fn is_visible(&self) -> bool {
+        self.report.is_some()
+    }
+
+    /// 描画
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        let Some(report) = &self.report else {
+            return;
+        };
+
+        let [popup] = Layout::horizontal([Constraint::Length(72)]).flex(Flex::Center).areas(area);
+        let [popup] = Layout::vertical([Constraint::Length(11)]).flex(Flex::Center).areas(popup);
+
+        frame.render_widget(Clear, popup);
+
+        let color = category_color(report.category);
+        let block = Block::default()
+            .title(format!(" ◆ {} ◆ ", report.category.label()))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(color));
+
+        let label = Style::default().fg(Color::Gray);
+        let lines = vec![
+            Line::from(vec![
+                Span::styled("コード:       ", label),
+                Span::styled(
+                    report.code.as_str(),
+                    Style::default().fg(color).add_modifier(Modifier::BOLD),
+                ),
+            ]),
+            Line::from(vec![
+                Span::styled("内容:         ", label),
+                Span::styled(report.message.as_str(), Style::default().fg(Color::White)),
+            ]),
+            Line::from(vec![
+                Span::styled("対処:         ", label),
+                Span::styled(report.remediation.as_str(), Style::default().fg(Color::Yellow)),
+            ]),
+            Line::from(vec![
+                Span::styled("問い合わせID: ", label),
+                Span::styled(
+                    report.correlation_id.as_str(),
+                    Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+                ),
+            ]),
+            Line::from(""),
+            Line::from(vec![
+                Span::styled("[任意のキー] ", Style::default().fg(Color::DarkGray)),
+                Span::styled("閉じる", label),
+            ]),
+        ];
+
+        frame.render_widget(Paragraph::new(lines).wrap(Wrap { trim: true }).block(block), popup);
+    }
+}
+
+impl Default for ErrorPanel {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// 分類ごとの表示色（利用者が修正可能なものは黄、それ以外は赤）
+fn category_color(category: ErrorCategory) -> Color {
+    match category {
+        ErrorCategory::Validation | ErrorCategory::NotFound | ErrorCategory::Conflict => {
+            Color::Yellow
+        }
+        ErrorCategory::Permission | ErrorCategory::Storage | ErrorCategory::System => Color::Red,
+    }
+}
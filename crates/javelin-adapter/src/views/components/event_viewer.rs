@@ -28,7 +28,7 @@ impl Event {
     pub fn info(message: impl Into<String>) -> Self {
         Self {
             level: EventLevel::Info,
-            timestamp: chrono::Local::now().format("%H:%M:%S").to_string(),
+            timestamp: crate::clock::now().format("%H:%M:%S").to_string(),
             message: message.into(),
         }
     }
@@ -36,7 +36,7 @@ impl Event {
     pub fn error(message: impl Into<String>) -> Self {
         Self {
             level: EventLevel::Error,
-            timestamp: chrono::Local::now().format("%H:%M:%S").to_string(),
+            timestamp: crate::clock::now().format("%H:%M:%S").to_string(),
             message: message.into(),
         }
     }
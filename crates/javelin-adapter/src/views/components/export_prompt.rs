@@ -0,0 +1,131 @@
+// ExportPrompt - 出力ファイル名入力オーバーレイ
+// 責務: 出力ファイル名と出力形式（CSV/TSV）の入力
+
+use ratatui::{
+    Frame,
+    layout::{Constraint, Flex, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Clear, Paragraph},
+};
+
+use super::ExportFormat;
+
+/// 出力ファイル名入力オーバーレイ
+///
+/// ファイル名は拡張子を除いて入力し、拡張子は出力形式から付与する。
+pub struct ExportPrompt {
+    visible: bool,
+    file_stem: String,
+    format: ExportFormat,
+}
+
+impl ExportPrompt {
+    pub fn new() -> Self {
+        Self { visible: false, file_stem: String::new(), format: ExportFormat::default() }
+    }
+
+    /// 既定のファイル名で表示
+    pub fn open(&mut self, default_file_stem: impl Into<String>) {
+        self.file_stem = default_file_stem.into();
+        self.visible = true;
+    }
+
+    /// 非表示に設定
+    pub fn hide(&mut self) {
+        self.visible = false;
+    }
+
+    /// 表示中かどうか
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    pub fn input_char(&mut self, c: char) {
+        // パス区切りは受け付けない（カレントディレクトリに出力する）
+        if !matches!(c, '/' | '\\') {
+            self.file_stem.push(c);
+        }
+    }
+
+    pub fn delete_char(&mut self) {
+        self.file_stem.pop();
+    }
+
+    /// 出力形式を切り替え
+    pub fn toggle_format(&mut self) {
+        self.format = self.format.toggled();
+    }
+
+    pub fn format(&self) -> ExportFormat {
+        self.format
+    }
+
+    /// 確定するファイル名（未入力の場合は None）
+    pub fn file_name(&self) -> Option<String> {
+        let stem = self.file_stem.trim();
+        if stem.is_empty() {
+            None
+        } else {
+            Some(format!("{}.{}", stem, self.format.extension()))
+        }
+    }
+
+    /// 描画
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        if !self.visible {
+            return;
+        }
+
+        let [popup] = Layout::horizontal([Constraint::Length(60)]).flex(Flex::Center).areas(area);
+        let [popup] = Layout::vertical([Constraint::Length(7)]).flex(Flex::Center).areas(popup);
+
+        frame.render_widget(Clear, popup);
+
+        let block = Block::default()
+            .title(" ◆ 出力 ◆ ")
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(Color::Yellow));
+
+        let lines = vec![
+            Line::from(vec![
+                Span::styled("ファイル名: ", Style::default().fg(Color::Gray)),
+                Span::styled(
+                    format!("{}▮", self.file_stem),
+                    Style::default().fg(Color::White).add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    format!(".{}", self.format.extension()),
+                    Style::default().fg(Color::DarkGray),
+                ),
+            ]),
+            Line::from(vec![
+                Span::styled("形式:       ", Style::default().fg(Color::Gray)),
+                Span::styled(
+                    self.format.label(),
+                    Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+                ),
+            ]),
+            Line::from(""),
+            Line::from(vec![
+                Span::styled("[Enter] ", Style::default().fg(Color::DarkGray)),
+                Span::styled("出力", Style::default().fg(Color::Gray)),
+                Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+                Span::styled("[Tab] ", Style::default().fg(Color::DarkGray)),
+                Span::styled("形式切替", Style::default().fg(Color::Gray)),
+                Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+                Span::styled("[Esc] ", Style::default().fg(Color::DarkGray)),
+                Span::styled("取消", Style::default().fg(Color::Gray)),
+            ]),
+        ];
+
+        frame.render_widget(Paragraph::new(lines).block(block), popup);
+    }
+}
+
+impl Default for ExportPrompt {
+    fn default() -> Self {
+        Self::new()
+    }
+}
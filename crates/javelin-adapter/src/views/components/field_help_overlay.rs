@@ -0,0 +1,134 @@
+// FieldHelpOverlay - フィールドヘルプオーバーレイ
+// 責務: フォーカス中の入力欄の入力形式・検証ルール・入力例の表示
+
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Clear, Paragraph, Wrap},
+};
+
+use crate::views::components::FieldHelp;
+
+/// フィールドヘルプオーバーレイ
+///
+/// 内容は `InputField::help()` から構築するため、入力欄を持つ画面は開閉と描画だけを行えばよい。
+#[derive(Default)]
+pub struct FieldHelpOverlay {
+    visible: bool,
+    help: FieldHelp,
+}
+
+impl FieldHelpOverlay {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// ヘルプを開く（`InputField::help()` の結果を渡す）
+    pub fn open(&mut self, help: FieldHelp) {
+        self.help = help;
+        self.visible = true;
+    }
+
+    /// オーバーレイを閉じる
+    pub fn close(&mut self) {
+        self.visible = false;
+    }
+
+    /// 表示中かどうか
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// 描画
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        if !self.visible {
+            return;
+        }
+
+        let lines = self.lines();
+        // 本文 + 枠線 + 操作説明
+        let height = (lines.len() as u16 + 4).min(area.height);
+        let overlay_area = Self::centered_rect(60, height, area);
+        frame.render_widget(Clear, overlay_area);
+
+        let block = Block::default()
+            .title(format!(" ヘルプ: {} ", self.help.label))
+            .title_style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(Color::Cyan));
+        let inner = block.inner(overlay_area);
+        frame.render_widget(block, overlay_area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(1), Constraint::Length(1)])
+            .split(inner);
+
+        frame.render_widget(Paragraph::new(lines).wrap(Wrap { trim: false }), chunks[0]);
+        frame.render_widget(
+            Paragraph::new(Line::from(Span::styled(
+                "[Esc/?] 閉じる",
+                Style::default().fg(Color::DarkGray),
+            )))
+            .alignment(Alignment::Center),
+            chunks[1],
+        );
+    }
+
+    /// 説明・入力形式・検証ルール・入力例の行を構築
+    fn lines(&self) -> Vec<Line<'static>> {
+        let heading = Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD);
+        let body = Style::default().fg(Color::White);
+
+        let mut lines = Vec::new();
+        if let Some(description) = &self.help.description {
+            lines.push(Line::from(Span::styled(description.clone(), body)));
+            lines.push(Line::from(""));
+        }
+
+        lines.push(Line::from(Span::styled("入力形式", heading)));
+        lines.push(Line::from(Span::styled(format!("  {}", self.help.format), body)));
+
+        if !self.help.rules.is_empty() {
+            lines.push(Line::from(Span::styled("検証ルール", heading)));
+            lines.extend(
+                self.help
+                    .rules
+                    .iter()
+                    .map(|rule| Line::from(Span::styled(format!("  ・{}", rule), body))),
+            );
+        }
+
+        if !self.help.examples.is_empty() {
+            lines.push(Line::from(Span::styled("入力例", heading)));
+            lines.extend(self.help.examples.iter().map(|example| {
+                Line::from(Span::styled(
+                    format!("  {}", example),
+                    Style::default().fg(Color::Green),
+                ))
+            }));
+        }
+
+        lines
+    }
+
+    /// 中央配置の矩形（横は割合、縦は行数）
+    fn centered_rect(width_percent: u16, height: u16, r: Rect) -> Rect {
+        let vertical = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Fill(1), Constraint::Length(height), Constraint::Fill(1)])
+            .split(r);
+
+        Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([
+                Constraint::Percentage((100 - width_percent) / 2),
+                Constraint::Percentage(width_percent),
+                Constraint::Percentage((100 - width_percent) / 2),
+            ])
+            .split(vertical[1])[1]
+    }
+}
@@ -8,9 +8,26 @@ use ratatui::{
     text::{Line, Span},
     widgets::{Block, Borders, Paragraph},
 };
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
 
 use crate::input_mode::ModifyInputType;
 
+/// フィールドヘルプ（`?` で表示する入力形式・検証ルール・入力例）
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FieldHelp {
+    /// 項目名
+    pub label: String,
+    /// 項目の説明
+    pub description: Option<String>,
+    /// 入力形式
+    pub format: String,
+    /// 検証ルール
+    pub rules: Vec<String>,
+    /// 入力例
+    pub examples: Vec<String>,
+}
+
 /// 入力フィールド
 pub struct InputField {
     label: String,
@@ -23,8 +40,16 @@ pub struct InputField {
     input_type: ModifyInputType,
     // 一時入力バッファ（MODIFYモード中の入力）
     temp_buffer: String,
+    // 一時バッファ内のカーソル位置（書記素単位）
+    cursor: usize,
     // BooleanToggle用の表示ラベル（true時, false時）
     boolean_labels: Option<(String, String)>,
+    // フィールドヘルプ用の説明・追加ルール・入力例
+    help_description: Option<String>,
+    help_rules: Vec<String>,
+    help_examples: Vec<String>,
+    // 項目間の検証エラー（範囲の前後関係など。ラベル横に表示し枠を赤くする）
+    error: Option<String>,
 }
 
 impl InputField {
@@ -39,7 +64,12 @@ impl InputField {
             max_length: None,
             input_type: ModifyInputType::Direct,
             temp_buffer: String::new(),
+            cursor: 0,
             boolean_labels: None,
+            help_description: None,
+            help_rules: Vec::new(),
+            help_examples: Vec::new(),
+            error: None,
         }
     }
 
@@ -100,6 +130,79 @@ impl InputField {
         self
     }
 
+    /// ヘルプに表示する項目の説明
+    pub fn with_help(mut self, description: impl Into<String>) -> Self {
+        self.help_description = Some(description.into());
+        self
+    }
+
+    /// ヘルプに表示する検証ルールを追加（入力タイプ・必須・桁数のルールは自動で表示）
+    pub fn with_rule(mut self, rule: impl Into<String>) -> Self {
+        self.help_rules.push(rule.into());
+        self
+    }
+
+    /// ヘルプに表示する入力例を追加（指定しない場合は入力タイプの既定例）
+    pub fn with_example(mut self, example: impl Into<String>) -> Self {
+        self.help_examples.push(example.into());
+        self
+    }
+
+    /// フィールドヘルプを構築
+    ///
+    /// 入力タイプ・必須・参照専用・最大文字数から導出したルールに、個別に追加したルールを続ける。
+    pub fn help(&self) -> FieldHelp {
+        let mut rules = Vec::new();
+        if self.is_readonly {
+            rules.push("参照専用です（入力できません）".to_string());
+        }
+        if self.is_required {
+            rules.push("必須項目です".to_string());
+        }
+        rules.extend(self.input_type.validation_rules().iter().map(|rule| rule.to_string()));
+        if let Some(max_length) = self.max_length
+            && self.input_type != ModifyInputType::Calendar
+        {
+            rules.push(format!("{}文字以内", max_length));
+        }
+        rules.extend(self.help_rules.iter().cloned());
+
+        let examples = if self.help_examples.is_empty() {
+            self.input_type
+                .default_examples()
+                .iter()
+                .map(|example| example.to_string())
+                .collect()
+        } else {
+            self.help_examples.clone()
+        };
+
+        let format = match &self.boolean_labels {
+            Some((true_label, false_label))
+                if self.input_type == ModifyInputType::BooleanToggle =>
+            {
+                format!("{}（{} / {}）", self.input_type.format_hint(), false_label, true_label)
+            }
+            _ => self.input_type.format_hint().to_string(),
+        };
+
+        FieldHelp {
+            label: self.label.clone(),
+            description: self.help_description.clone(),
+            format,
+            rules,
+            examples,
+        }
+    }
+
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    pub fn set_label(&mut self, label: impl Into<String>) {
+        self.label = label.into();
+    }
+
     pub fn input_type(&self) -> ModifyInputType {
         self.input_type
     }
@@ -123,47 +226,125 @@ impl InputField {
         &self.value
     }
 
-    /// MODIFYモード開始時：一時バッファを初期化
+    /// 検証エラーを設定（Noneで解除）
+    pub fn set_error(&mut self, error: Option<String>) {
+        self.error = error;
+    }
+
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    /// MODIFYモード開始時：一時バッファを初期化（カーソルは末尾）
     pub fn start_modify(&mut self) {
         self.temp_buffer = self.value.clone();
+        self.move_cursor_end();
     }
 
-    /// 一時バッファに文字を追加
-    pub fn append_to_buffer(&mut self, ch: char) {
+    /// 一時バッファのカーソル位置に文字を挿入
+    pub fn insert_char(&mut self, ch: char) {
         // BooleanToggle: スペースキーで切り替え
         if self.input_type == ModifyInputType::BooleanToggle && ch == ' ' {
             self.toggle_boolean();
             return;
         }
 
-        // Calendar入力タイプの場合は8桁まで
-        if self.input_type == ModifyInputType::Calendar {
-            if self.temp_buffer.len() < 8 {
-                self.temp_buffer.push(ch);
-            }
+        // Calendar入力タイプの場合は8桁まで、その他は最大文字数（バイト数ではない）まで
+        let limit = if self.input_type == ModifyInputType::Calendar {
+            Some(8)
+        } else {
+            self.max_length
+        };
+        if limit.is_some_and(|limit| self.temp_buffer.chars().count() >= limit) {
             return;
         }
 
-        // その他の入力タイプ
-        if let Some(max_len) = self.max_length {
-            // 文字数（バイト数ではない）でチェック
-            if self.temp_buffer.chars().count() < max_len {
-                self.temp_buffer.push(ch);
-            }
-        } else {
-            self.temp_buffer.push(ch);
-        }
+        let offset = self.cursor_byte_offset();
+        self.temp_buffer.insert(offset, ch);
+        // 結合文字は直前の書記素に合成されるため、挿入位置までの書記素数でカーソルを求める
+        self.cursor = self.temp_buffer[..offset + ch.len_utf8()].graphemes(true).count();
     }
 
     /// Boolean値を切り替え
     fn toggle_boolean(&mut self) {
         let current_value = self.temp_buffer == "true";
         self.temp_buffer = if current_value { "false" } else { "true" }.to_string();
+        self.move_cursor_end();
     }
 
-    /// 一時バッファから文字を削除
+    /// 一時バッファのカーソル直前の1文字（書記素）を削除
     pub fn backspace_buffer(&mut self) {
-        self.temp_buffer.pop();
+        if self.cursor == 0 {
+            return;
+        }
+        self.cursor -= 1;
+        self.delete_at_cursor();
+    }
+
+    /// 一時バッファのカーソル位置の1文字（書記素）を削除
+    pub fn delete_at_cursor(&mut self) {
+        let start = self.cursor_byte_offset();
+        if let Some(grapheme) = self.temp_buffer[start..].graphemes(true).next() {
+            let end = start + grapheme.len();
+            self.temp_buffer.replace_range(start..end, "");
+        }
+    }
+
+    /// カーソルを1文字左へ
+    pub fn move_cursor_left(&mut self) {
+        self.cursor = self.cursor.saturating_sub(1);
+    }
+
+    /// カーソルを1文字右へ
+    pub fn move_cursor_right(&mut self) {
+        self.cursor = (self.cursor + 1).min(self.grapheme_count());
+    }
+
+    /// カーソルを先頭へ
+    pub fn move_cursor_home(&mut self) {
+        self.cursor = 0;
+    }
+
+    /// カーソルを末尾へ
+    pub fn move_cursor_end(&mut self) {
+        self.cursor = self.grapheme_count();
+    }
+
+    /// 一時バッファ内のカーソル位置（書記素単位）
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    fn grapheme_count(&self) -> usize {
+        self.temp_buffer.graphemes(true).count()
+    }
+
+    /// カーソル位置のバイトオフセット
+    fn cursor_byte_offset(&self) -> usize {
+        self.temp_buffer
+            .grapheme_indices(true)
+            .nth(self.cursor)
+            .map(|(offset, _)| offset)
+            .unwrap_or(self.temp_buffer.len())
+    }
+
+    /// 編集中の表示テキストにおけるカーソルの表示桁（全角文字は2桁）
+    ///
+    /// NumberOnlyのカンマ区切り、CalendarのYYYY-MM-DD表示で挿入される区切り文字も数える。
+    fn cursor_display_column(&self) -> usize {
+        let before = &self.temp_buffer[..self.cursor_byte_offset()];
+        let separators = match self.input_type {
+            ModifyInputType::NumberOnly => {
+                let len = self.temp_buffer.chars().count();
+                (1..=self.cursor).filter(|&i| i < len && (len - i).is_multiple_of(3)).count()
+            }
+            ModifyInputType::Calendar => {
+                let len = self.temp_buffer.len();
+                usize::from(len > 4 && self.cursor >= 4) + usize::from(len > 6 && self.cursor >= 6)
+            }
+            _ => 0,
+        };
+        before.width() + separators
     }
 
     /// 一時バッファの内容を取得
@@ -188,6 +369,7 @@ impl InputField {
     /// ESCでクリア：一時バッファを破棄
     pub fn clear_buffer(&mut self) {
         self.temp_buffer.clear();
+        self.cursor = 0;
     }
 
     /// Boolean値を表示用にフォーマット
@@ -213,7 +395,7 @@ impl InputField {
         };
 
         // ラベルテキスト
-        let label_text = if self.is_required {
+        let mut label_text = if self.is_required {
             Line::from(vec![
                 Span::styled("※", Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)),
                 Span::styled(&self.label, label_style),
@@ -221,6 +403,10 @@ impl InputField {
         } else {
             Line::from(Span::styled(&self.label, label_style))
         };
+        if let Some(error) = &self.error {
+            label_text
+                .push_span(Span::styled(format!(" ⚠ {}", error), Style::default().fg(Color::Red)));
+        }
 
         // 入力欄スタイル
         let input_style = if self.is_readonly {
@@ -273,8 +459,24 @@ impl InputField {
             }
         };
 
-        // カーソル
-        let cursor = if self.is_focused && !self.is_readonly {
+        // 編集中はカーソル位置に端末カーソルを置く（IMEの変換候補もこの位置に表示される）
+        let editing = self.is_focused
+            && is_in_modify
+            && !self.is_readonly
+            && self.input_type != ModifyInputType::BooleanToggle;
+        let cursor_column = if editing && !self.temp_buffer.is_empty() {
+            Some(self.cursor_display_column())
+        } else if editing {
+            Some(0)
+        } else {
+            None
+        };
+
+        // 末尾のカーソル（編集中にカーソルが途中にある場合は端末カーソルのみ）
+        let cursor = if self.is_focused
+            && !self.is_readonly
+            && (!editing || self.cursor >= self.grapheme_count())
+        {
             "_" // Windowsでも安全なアンダースコア
         } else {
             ""
@@ -282,12 +484,6 @@ impl InputField {
 
         let input_text = format!("{}{}", display_text, cursor);
 
-        // 2段レイアウト: ラベル + 入力欄
-        let label_widget = Paragraph::new(label_text);
-        let input_widget = Paragraph::new(input_text)
-            .style(input_style)
-            .block(Block::default().borders(Borders::ALL));
-
         let chunks = ratatui::layout::Layout::default()
             .direction(ratatui::layout::Direction::Vertical)
             .constraints([
@@ -296,7 +492,168 @@ impl InputField {
             ])
             .split(area);
 
+        // 長い入力はカーソルが見える位置まで横スクロール
+        let inner_width = usize::from(chunks[1].width.saturating_sub(2));
+        let scroll = cursor_column
+            .map(|column| column.saturating_sub(inner_width.saturating_sub(1)))
+            .unwrap_or(0);
+
+        // 2段レイアウト: ラベル + 入力欄
+        let label_widget = Paragraph::new(label_text);
+        let input_widget = Paragraph::new(input_text)
+            .style(input_style)
+            .scroll((0, u16::try_from(scroll).unwrap_or(u16::MAX)))
+            .block(Block::default().borders(Borders::ALL).border_style(if self.error.is_some() {
+                Style::default().fg(Color::Red)
+            } else {
+                Style::default()
+            }));
+
         frame.render_widget(label_widget, chunks[0]);
         frame.render_widget(input_widget, chunks[1]);
+
+        if let Some(column) = cursor_column
+            && inner_width > 0
+        {
+            let x = chunks[1].x + 1 + u16::try_from(column - scroll).unwrap_or(0);
+            frame.set_cursor_position((x, chunks[1].y + 1));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_help_derives_rules_from_field_metadata() {
+        let field = InputField::new("取引日付")
+            .required()
+            .with_max_length(10)
+            .with_input_type(ModifyInputType::Calendar);
+
+        let help = field.help();
+
+        assert_eq!(help.label, "取引日付");
+        assert_eq!(help.format, ModifyInputType::Calendar.format_hint());
+        assert_eq!(help.rules[0], "必須項目です");
+        assert!(help.rules.iter().any(|rule| rule == "2000年代の日付のみ入力できます"));
+        // カレンダーは8桁固定のため最大文字数は表示しない
+        assert!(!help.rules.iter().any(|rule| rule.contains("文字以内")));
+        assert_eq!(help.examples, vec!["20250401 → 2025-04-01".to_string()]);
+    }
+
+    #[test]
+    fn test_help_appends_custom_metadata() {
+        let field = InputField::new("伝票番号")
+            .with_max_length(20)
+            .with_help("証憑に記載された番号")
+            .with_rule("完全一致で検索します")
+            .with_example("V-2025-0001");
+
+        let help = field.help();
+
+        assert_eq!(help.description.as_deref(), Some("証憑に記載された番号"));
+        assert_eq!(help.rules, vec!["20文字以内".to_string(), "完全一致で検索します".to_string()]);
+        assert_eq!(help.examples, vec!["V-2025-0001".to_string()]);
+    }
+
+    #[test]
+    fn test_help_shows_boolean_labels() {
+        let field = InputField::new("借方/貸方")
+            .with_input_type(ModifyInputType::BooleanToggle)
+            .with_boolean_labels("貸方", "借方")
+            .readonly();
+
+        let help = field.help();
+
+        assert!(help.format.contains("借方 / 貸方"));
+        assert_eq!(help.rules[0], "参照専用です（入力できません）");
+    }
+
+    #[test]
+    fn test_insert_and_delete_at_cursor() {
+        let mut field = InputField::new("摘要").with_value("売上計上");
+        field.start_modify();
+        assert_eq!(field.cursor(), 4);
+
+        field.move_cursor_left();
+        field.move_cursor_left();
+        field.insert_char('高');
+        assert_eq!(field.temp_buffer(), "売上高計上");
+        assert_eq!(field.cursor(), 3);
+
+        field.backspace_buffer();
+        assert_eq!(field.temp_buffer(), "売上計上");
+        field.delete_at_cursor();
+        assert_eq!(field.temp_buffer(), "売上上");
+
+        field.move_cursor_home();
+        field.backspace_buffer();
+        field.insert_char('＊');
+        field.move_cursor_end();
+        field.move_cursor_right();
+        field.insert_char('分');
+        assert_eq!(field.temp_buffer(), "＊売上上分");
+        assert_eq!(field.cursor(), 5);
+    }
+
+    #[test]
+    fn test_cursor_moves_by_grapheme() {
+        // が（か＋結合用濁点）は1文字として扱う
+        let mut field = InputField::new("摘要").with_value("か\u{3099}い");
+        field.start_modify();
+        assert_eq!(field.cursor(), 2);
+
+        field.move_cursor_left();
+        field.move_cursor_left();
+        field.delete_at_cursor();
+        assert_eq!(field.temp_buffer(), "い");
+
+        field.insert_char('か');
+        field.insert_char('\u{3099}');
+        assert_eq!(field.cursor(), 1);
+        assert_eq!(field.temp_buffer(), "か\u{3099}い");
+    }
+
+    #[test]
+    fn test_cursor_display_column_counts_wide_chars_and_separators() {
+        let mut field = InputField::new("摘要").with_value("A売上");
+        field.start_modify();
+        assert_eq!(field.cursor_display_column(), 5);
+        field.move_cursor_left();
+        assert_eq!(field.cursor_display_column(), 3);
+
+        // 1,234,567 の「4」と「5」の間 → 区切りのカンマを越えた「5」の位置
+        let mut amount = InputField::new("金額")
+            .with_value("1234567")
+            .with_input_type(ModifyInputType::NumberOnly);
+        amount.start_modify();
+        assert_eq!(amount.cursor_display_column(), 9);
+        amount.move_cursor_left();
+        amount.move_cursor_left();
+        amount.move_cursor_left();
+        assert_eq!(amount.cursor_display_column(), 6);
+
+        let mut date = InputField::new("取引日付")
+            .with_value("202504")
+            .with_input_type(ModifyInputType::Calendar);
+        date.start_modify();
+        assert_eq!(date.cursor_display_column(), 7);
+        date.move_cursor_home();
+        date.move_cursor_right();
+        date.move_cursor_right();
+        date.move_cursor_right();
+        date.move_cursor_right();
+        assert_eq!(date.cursor_display_column(), 5);
+    }
+
+    #[test]
+    fn test_insert_respects_max_length() {
+        let mut field = InputField::new("伝票番号").with_max_length(3).with_value("ABC");
+        field.start_modify();
+        field.move_cursor_home();
+        field.insert_char('X');
+        assert_eq!(field.temp_buffer(), "ABC");
     }
 }
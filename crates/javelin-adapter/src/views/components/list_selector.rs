@@ -45,6 +45,13 @@ impl ListSelector {
         Self { title: title.into(), items, state, is_active: false }
     }
 
+    /// 項目のラベルを変更
+    pub fn set_item_label(&mut self, index: usize, label: impl Into<String>) {
+        if let Some(item) = self.items.get_mut(index) {
+            item.label = label.into();
+        }
+    }
+
     /// アクティブ状態を設定
     pub fn set_active(&mut self, active: bool) {
         self.is_active = active;
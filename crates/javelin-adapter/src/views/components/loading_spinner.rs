@@ -9,6 +9,8 @@ use ratatui::{
     widgets::{Block, Borders, Paragraph},
 };
 
+const SPINNER_CHARS: [&str; 8] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧"];
+
 pub struct LoadingSpinner {
     frame_count: usize,
 }
@@ -19,12 +21,21 @@ impl LoadingSpinner {
     }
 
     pub fn tick(&mut self) {
-        self.frame_count = (self.frame_count + 1) % 8;
+        self.frame_count = (self.frame_count + 1) % SPINNER_CHARS.len();
+    }
+
+    /// 現在のフレーム番号
+    pub fn frame_count(&self) -> usize {
+        self.frame_count
+    }
+
+    /// 現在のスピナー文字
+    pub fn spinner(&self) -> &'static str {
+        SPINNER_CHARS[self.frame_count]
     }
 
     pub fn render(&self, frame: &mut Frame, area: Rect, message: &str) {
-        let spinner_chars = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧"];
-        let spinner = spinner_chars[self.frame_count];
+        let spinner = self.spinner();
 
         let text = vec![
             Line::from(""),
@@ -0,0 +1,199 @@
+// MasterChangeHistoryOverlay - マスタ変更履歴オーバーレイ
+// 責務: マスタレコードの変更履歴（変更前後の内容）の表示と、取り消す変更の選択
+
+use ratatui::{
+    Frame,
+    layout::{Constraint, Flex, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Cell, Clear, Paragraph, Row, Table, TableState},
+};
+
+use crate::presenter::MasterChangeHistoryViewModel;
+
+/// マスタ変更履歴オーバーレイ
+///
+/// 取り消せる変更（現在の内容が変更後の内容と一致する変更）のみ取消しを受け付ける。
+pub struct MasterChangeHistoryOverlay {
+    view_model: Option<MasterChangeHistoryViewModel>,
+    table_state: TableState,
+}
+
+impl MasterChangeHistoryOverlay {
+    pub fn new() -> Self {
+        Self { view_model: None, table_state: TableState::default() }
+    }
+
+    /// 履歴を表示（再読込時は選択位置を保つ）
+    pub fn show(&mut self, view_model: MasterChangeHistoryViewModel) {
+        let selected = self.table_state.selected().unwrap_or(0);
+        let selected =
+            (!view_model.items.is_empty()).then(|| selected.min(view_model.items.len() - 1));
+        self.table_state.select(selected);
+        self.view_model = Some(view_model);
+    }
+
+    pub fn hide(&mut self) {
+        self.view_model = None;
+        self.table_state.select(None);
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.view_model.is_some()
+    }
+
+    pub fn select_next(&mut self) {
+        let len = self.view_model.as_ref().map_or(0, |view_model| view_model.items.len());
+        if let Some(selected) = self.table_state.selected()
+            && selected + 1 < len
+        {
+            self.table_state.select(Some(selected + 1));
+        }
+    }
+
+    pub fn select_previous(&mut self) {
+        if let Some(selected) = self.table_state.selected() {
+            self.table_state.select(Some(selected.saturating_sub(1)));
+        }
+    }
+
+    /// 選択中の変更の連番（取り消せる変更の場合のみ）
+    pub fn selected_revertible(&self) -> Option<u64> {
+        let item = self.view_model.as_ref()?.items.get(self.table_state.selected()?)?;
+        item.revertible.then_some(item.sequence)
+    }
+
+    /// 描画
+    pub fn render(&mut self, frame: &mut Frame, area: Rect) {
+        let Some(view_model) = &self.view_model else {
+            return;
+        };
+
+        let [popup] =
+            Layout::horizontal([Constraint::Percentage(90)]).flex(Flex::Center).areas(area);
+        let [popup] =
+            Layout::vertical([Constraint::Percentage(70)]).flex(Flex::Center).areas(popup);
+        frame.render_widget(Clear, popup);
+
+        let block = Block::default()
+            .title(format!(" ◆ 変更履歴: {} ◆ ", view_model.title))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(Color::Yellow));
+        let inner = block.inner(popup);
+        frame.render_widget(block, popup);
+
+        let [table_area, hint_area] =
+            Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(inner);
+
+        if view_model.items.is_empty() {
+            frame.render_widget(
+                Paragraph::new("変更履歴はありません").style(Style::default().fg(Color::DarkGray)),
+                table_area,
+            );
+        } else {
+            let header = Row::new(vec!["#", "日時", "変更者", "種類", "変更前", "変更後", "備考"])
+                .style(Style::default().add_modifier(Modifier::BOLD));
+            let rows: Vec<Row> = view_model
+                .items
+                .iter()
+                .map(|item| {
+                    let style = if item.revertible {
+                        Style::default().fg(Color::White)
+                    } else {
+                        Style::default().fg(Color::DarkGray)
+                    };
+                    Row::new(vec![
+                        Cell::from(item.sequence.to_string()),
+                        Cell::from(item.changed_at.as_str()),
+                        Cell::from(item.changed_by.as_str()),
+                        Cell::from(item.kind_label.as_str()),
+                        Cell::from(item.before.as_str()),
+                        Cell::from(item.after.as_str()),
+                        Cell::from(item.note.as_str()),
+                    ])
+                    .style(style)
+                })
+                .collect();
+            let table = Table::new(
+                rows,
+                [
+                    Constraint::Length(4),
+                    Constraint::Length(16),
+                    Constraint::Length(10),
+                    Constraint::Length(4),
+                    Constraint::Min(16),
+                    Constraint::Min(16),
+                    Constraint::Length(12),
+                ],
+            )
+            .header(header)
+            .row_highlight_style(Style::default().bg(Color::DarkGray).add_modifier(Modifier::BOLD));
+            frame.render_stateful_widget(table, table_area, &mut self.table_state);
+        }
+
+        let label = Style::default().fg(Color::Gray);
+        let key = Style::default().fg(Color::DarkGray);
+        frame.render_widget(
+            Paragraph::new(Line::from(vec![
+                Span::styled("[↑↓] ", key),
+                Span::styled("選択", label),
+                Span::styled(" │ ", key),
+                Span::styled("[u] ", key),
+                Span::styled("取消し（新しい変更から順に）", label),
+                Span::styled(" │ ", key),
+                Span::styled("[Esc] ", key),
+                Span::styled("閉じる", label),
+            ])),
+            hint_area,
+        );
+    }
+}
+
+impl Default for MasterChangeHistoryOverlay {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::presenter::MasterChangeItemViewModel;
+
+    fn item(sequence: u64, revertible: bool) -> MasterChangeItemViewModel {
+        MasterChangeItemViewModel {
+            sequence,
+            kind_label: "変更".to_string(),
+            before: "現金（有効）".to_string(),
+            after: "小口現金（有効）".to_string(),
+            changed_by: "alice".to_string(),
+            changed_at: "2026-04-01 09:00".to_string(),
+            note: String::new(),
+            revertible,
+        }
+    }
+
+    #[test]
+    fn test_only_revertible_changes_are_selected_for_revert() {
+        let mut history = MasterChangeHistoryOverlay::new();
+        history.show(MasterChangeHistoryViewModel {
+            title: "1000 現金".to_string(),
+            items: vec![item(2, true), item(1, false)],
+        });
+        assert_eq!(history.selected_revertible(), Some(2));
+        history.select_next();
+        assert_eq!(history.selected_revertible(), None);
+        history.select_next();
+        assert_eq!(history.selected_revertible(), None);
+
+        // 取消し後の再読込で件数が増えても選択位置を保つ
+        history.show(MasterChangeHistoryViewModel {
+            title: "1000 現金".to_string(),
+            items: vec![item(3, true), item(2, false), item(1, false)],
+        });
+        assert_eq!(history.selected_revertible(), None);
+        history.hide();
+        assert!(!history.is_visible());
+    }
+}
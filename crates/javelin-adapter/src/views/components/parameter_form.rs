@@ -0,0 +1,401 @@
+// ParameterForm - 帳票の実行条件入力フォーム
+// 責務: 会計期間・日付・年度・切替などの条件入力、形式・会計カレンダーの範囲チェック、
+//       最近使った条件の反映
+
+use std::collections::BTreeMap;
+
+use chrono::{Datelike, NaiveDate};
+use javelin_application::parameter_validation::{FieldError, FiscalCalendar};
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph},
+};
+
+/// 切替項目の値
+pub const TOGGLE_ON: &str = "true";
+pub const TOGGLE_OFF: &str = "false";
+
+/// 条件の種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParameterKind {
+    /// 会計期間（YYYY-MM）
+    Period,
+    /// 日付（YYYY-MM-DD）
+    Date,
+    /// 会計年度（YYYY）
+    Year,
+    /// 切替（[Space]でオン・オフ）
+    Toggle,
+}
+
+impl ParameterKind {
+    fn placeholder(&self) -> &'static str {
+        match self {
+            ParameterKind::Period => "YYYY-MM",
+            ParameterKind::Date => "YYYY-MM-DD",
+            ParameterKind::Year => "YYYY",
+            ParameterKind::Toggle => "[Space] 切替",
+        }
+    }
+
+    /// 入力値の形式チェック
+    fn validate(&self, value: &str) -> bool {
+        match self {
+            ParameterKind::Period => {
+                NaiveDate::parse_from_str(&format!("{}-01", value), "%Y-%m-%d").is_ok()
+                    && value.len() == 7
+            }
+            ParameterKind::Date => {
+                NaiveDate::parse_from_str(value, "%Y-%m-%d").is_ok() && value.len() == 10
+            }
+            ParameterKind::Year => value.len() == 4 && value.parse::<u32>().is_ok(),
+            ParameterKind::Toggle => value == TOGGLE_ON || value == TOGGLE_OFF,
+        }
+    }
+
+    /// 形式が正しい入力値が会計カレンダーの範囲内かを検証
+    fn check_calendar(
+        &self,
+        calendar: &FiscalCalendar,
+        field: &str,
+        value: &str,
+    ) -> Option<FieldError> {
+        match self {
+            ParameterKind::Period => {
+                let first_day = NaiveDate::parse_from_str(&format!("{}-01", value), "%Y-%m-%d");
+                first_day
+                    .ok()
+                    .and_then(|date| calendar.check_period(field, date.year(), date.month()))
+            }
+            ParameterKind::Date => NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                .ok()
+                .and_then(|date| calendar.check_date(field, date)),
+            ParameterKind::Year => value
+                .parse::<i32>()
+                .ok()
+                .and_then(|year| calendar.check_fiscal_year(field, year)),
+            ParameterKind::Toggle => None,
+        }
+    }
+}
+
+/// 条件項目の定義
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParameterSpec {
+    /// 保存・受け渡しに使う条件名
+    pub name: &'static str,
+    pub label: &'static str,
+    pub kind: ParameterKind,
+}
+
+impl ParameterSpec {
+    pub const fn new(name: &'static str, label: &'static str, kind: ParameterKind) -> Self {
+        Self { name, label, kind }
+    }
+}
+
+/// 帳票の実行条件入力フォーム
+pub struct ParameterForm {
+    title: String,
+    specs: Vec<ParameterSpec>,
+    values: Vec<String>,
+    focused: usize,
+    is_active: bool,
+    error: Option<String>,
+    /// 範囲チェックに使う会計カレンダー（未設定の場合は形式のみチェック）
+    calendar: Option<FiscalCalendar>,
+    /// 項目ごとの範囲エラー（入力のたびに再計算し、該当項目に表示する）
+    field_errors: Vec<Option<String>>,
+}
+
+impl ParameterForm {
+    /// 条件項目と初期値からフォームを作成
+    pub fn new(
+        title: impl Into<String>,
+        specs: Vec<ParameterSpec>,
+        defaults: &BTreeMap<String, String>,
+    ) -> Self {
+        let values = vec![String::new(); specs.len()];
+        let field_errors = vec![None; specs.len()];
+        let mut form = Self {
+            title: title.into(),
+            specs,
+            values,
+            focused: 0,
+            is_active: false,
+            error: None,
+            calendar: None,
+            field_errors,
+        };
+        form.apply(defaults);
+        form
+    }
+
+    /// 会計カレンダーの範囲外の条件を入力中に指摘する
+    pub fn with_calendar(mut self, calendar: FiscalCalendar) -> Self {
+        self.calendar = Some(calendar);
+        self.refresh_field_errors();
+        self
+    }
+
+    /// 形式が正しい項目について会計カレンダーの範囲を再検証
+    fn refresh_field_errors(&mut self) {
+        self.field_errors = self
+            .specs
+            .iter()
+            .zip(&self.values)
+            .map(|(spec, value)| {
+                let value = value.trim();
+                let calendar = self.calendar.as_ref()?;
+                if !spec.kind.validate(value) {
+                    return None;
+                }
+                spec.kind.check_calendar(calendar, spec.name, value).map(|error| error.message)
+            })
+            .collect();
+    }
+
+    /// 条件の値を反映（フォームにない条件名は無視する）
+    pub fn apply(&mut self, values: &BTreeMap<String, String>) {
+        for (spec, value) in self.specs.iter().zip(self.values.iter_mut()) {
+            if let Some(new_value) = values.get(spec.name) {
+                *value = new_value.clone();
+            } else if spec.kind == ParameterKind::Toggle && value.is_empty() {
+                *value = TOGGLE_OFF.to_string();
+            }
+        }
+        self.error = None;
+        self.refresh_field_errors();
+    }
+
+    pub fn set_active(&mut self, active: bool) {
+        self.is_active = active;
+    }
+
+    pub fn focus_next(&mut self) {
+        if !self.specs.is_empty() {
+            self.focused = (self.focused + 1) % self.specs.len();
+        }
+    }
+
+    pub fn focus_prev(&mut self) {
+        if !self.specs.is_empty() {
+            self.focused = (self.focused + self.specs.len() - 1) % self.specs.len();
+        }
+    }
+
+    pub fn input_char(&mut self, c: char) {
+        let Some(spec) = self.specs.get(self.focused) else {
+            return;
+        };
+        match spec.kind {
+            ParameterKind::Toggle if c == ' ' => self.toggle(),
+            ParameterKind::Toggle => {}
+            _ if c.is_ascii_digit() || c == '-' => {
+                self.values[self.focused].push(c);
+                self.error = None;
+                self.refresh_field_errors();
+            }
+            _ => {}
+        }
+    }
+
+    pub fn delete_char(&mut self) {
+        if self
+            .specs
+            .get(self.focused)
+            .is_some_and(|spec| spec.kind != ParameterKind::Toggle)
+        {
+            self.values[self.focused].pop();
+            self.refresh_field_errors();
+        }
+    }
+
+    /// フォーカス中の切替項目をオン・オフ
+    pub fn toggle(&mut self) {
+        if self
+            .specs
+            .get(self.focused)
+            .is_some_and(|spec| spec.kind == ParameterKind::Toggle)
+        {
+            let value = &mut self.values[self.focused];
+            *value = if value == TOGGLE_ON {
+                TOGGLE_OFF
+            } else {
+                TOGGLE_ON
+            }
+            .to_string();
+        }
+    }
+
+    /// 入力値を検証し、条件名と値の組を返す
+    ///
+    /// 形式の誤り・会計カレンダーの範囲外がある場合はその項目へフォーカスを移し、
+    /// エラーを表示する。
+    pub fn validate(&mut self) -> Result<BTreeMap<String, String>, String> {
+        let invalid = self
+            .specs
+            .iter()
+            .zip(&self.values)
+            .position(|(spec, value)| !spec.kind.validate(value.trim()));
+        if let Some(index) = invalid {
+            let spec = self.specs[index];
+            let message =
+                format!("{} は {} の形式で入力してください", spec.label, spec.kind.placeholder());
+            self.focused = index;
+            self.error = Some(message.clone());
+            return Err(message);
+        }
+
+        self.refresh_field_errors();
+        if let Some(index) = self.field_errors.iter().position(Option::is_some) {
+            let message = format!(
+                "{}: {}",
+                self.specs[index].label,
+                self.field_errors[index].clone().unwrap_or_default()
+            );
+            self.focused = index;
+            self.error = Some(message.clone());
+            return Err(message);
+        }
+
+        self.error = None;
+        Ok(self
+            .specs
+            .iter()
+            .zip(&self.values)
+            .map(|(spec, value)| (spec.name.to_string(), value.trim().to_string()))
+            .collect())
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        let border_color = if self.is_active {
+            Color::Yellow
+        } else {
+            Color::DarkGray
+        };
+        let block = Block::default()
+            .title(format!(" {} ", self.title))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(border_color));
+
+        let mut lines: Vec<Line> = if self.specs.is_empty() {
+            vec![Line::from(Span::styled(
+                "入力する条件はありません",
+                Style::default().fg(Color::Gray),
+            ))]
+        } else {
+            self.specs
+                .iter()
+                .zip(&self.values)
+                .enumerate()
+                .map(|(index, (spec, value))| {
+                    let focused = self.is_active && index == self.focused;
+                    let field_error = self.field_errors.get(index).and_then(Option::as_ref);
+                    let marker = if focused { "▶ " } else { "  " };
+                    let text = match spec.kind {
+                        ParameterKind::Toggle if value == TOGGLE_ON => "[x] する".to_string(),
+                        ParameterKind::Toggle => "[ ] しない".to_string(),
+                        _ if focused => format!("{}▮", value),
+                        _ => value.clone(),
+                    };
+                    let value_style = if field_error.is_some() {
+                        Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)
+                    } else if focused {
+                        Style::default().fg(Color::White).add_modifier(Modifier::BOLD)
+                    } else {
+                        Style::default().fg(Color::Cyan)
+                    };
+                    let hint = match field_error {
+                        Some(error) => {
+                            Span::styled(format!("  ⚠ {}", error), Style::default().fg(Color::Red))
+                        }
+                        None => Span::styled(
+                            format!("  {}", spec.kind.placeholder()),
+                            Style::default().fg(Color::DarkGray),
+                        ),
+                    };
+                    Line::from(vec![
+                        Span::styled(marker, Style::default().fg(Color::Yellow)),
+                        Span::styled(
+                            format!("{:<14}", spec.label),
+                            Style::default().fg(Color::Gray),
+                        ),
+                        Span::styled(text, value_style),
+                        hint,
+                    ])
+                })
+                .collect()
+        };
+
+        if let Some(error) = &self.error {
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::styled(error.clone(), Style::default().fg(Color::Red))));
+        }
+
+        frame.render_widget(Paragraph::new(lines).block(block), area);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SPECS: [ParameterSpec; 2] = [
+        ParameterSpec::new("period", "対象月", ParameterKind::Period),
+        ParameterSpec::new("include_pending", "承認待ちを含む", ParameterKind::Toggle),
+    ];
+
+    #[test]
+    fn test_validate_applies_recent_values_and_rejects_bad_format() {
+        let defaults = BTreeMap::from([("period".to_string(), "2024-03".to_string())]);
+        let mut form = ParameterForm::new("条件", SPECS.to_vec(), &defaults);
+        assert_eq!(
+            form.validate().unwrap(),
+            BTreeMap::from([
+                ("include_pending".to_string(), TOGGLE_OFF.to_string()),
+                ("period".to_string(), "2024-03".to_string()),
+            ])
+        );
+
+        form.focus_next();
+        form.input_char(' ');
+        form.focus_next();
+        form.delete_char();
+        form.input_char('x');
+        assert!(form.validate().is_err());
+        form.input_char('4');
+        assert_eq!(form.validate().unwrap()["include_pending"], TOGGLE_ON);
+
+        form.apply(&BTreeMap::from([("period".to_string(), "2024-13".to_string())]));
+        assert!(form.validate().is_err());
+        assert_eq!(form.focused, 0);
+    }
+
+    #[test]
+    fn test_calendar_flags_periods_after_current_fiscal_year() {
+        // 4月開始、業務日付 2025-02-10 の当会計年度は 2025-03 まで
+        let calendar = FiscalCalendar::new(4, NaiveDate::from_ymd_opt(2025, 2, 10).unwrap());
+        let defaults = BTreeMap::from([("period".to_string(), "2025-03".to_string())]);
+        let mut form =
+            ParameterForm::new("条件", SPECS.to_vec(), &defaults).with_calendar(calendar);
+        assert!(form.field_errors[0].is_none());
+
+        form.delete_char();
+        form.input_char('4');
+        assert!(form.field_errors[0].is_some());
+        form.focus_next();
+        assert!(form.validate().is_err());
+        assert_eq!(form.focused, 0);
+
+        // 入力途中（形式が不完全）の間は範囲エラーを表示しない
+        form.delete_char();
+        assert!(form.field_errors[0].is_none());
+        form.input_char('2');
+        assert!(form.validate().is_ok());
+    }
+}
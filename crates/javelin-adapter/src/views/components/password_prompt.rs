@@ -0,0 +1,173 @@
+// PasswordPrompt - 出力ファイル暗号化パスワード入力オーバーレイ
+// 責務: 伏せ字でのパスワード入力と確認入力の一致確認
+
+use ratatui::{
+    Frame,
+    layout::{Constraint, Flex, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Clear, Paragraph},
+};
+
+/// パスワード入力オーバーレイ
+///
+/// 入力を取り違えたまま暗号化しないよう、同じパスワードを2回入力させる。
+pub struct PasswordPrompt {
+    visible: bool,
+    file_name: String,
+    password: String,
+    confirmation: String,
+    confirming: bool,
+    error: Option<String>,
+}
+
+impl PasswordPrompt {
+    pub fn new() -> Self {
+        Self {
+            visible: false,
+            file_name: String::new(),
+            password: String::new(),
+            confirmation: String::new(),
+            confirming: false,
+            error: None,
+        }
+    }
+
+    /// 出力するファイル名を表示して入力を開始
+    pub fn open(&mut self, file_name: impl Into<String>) {
+        *self = Self { visible: true, file_name: file_name.into(), ..Self::new() };
+    }
+
+    /// 非表示にして入力内容を破棄
+    pub fn hide(&mut self) {
+        *self = Self::new();
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    pub fn input_char(&mut self, c: char) {
+        if self.confirming {
+            self.confirmation.push(c);
+        } else {
+            self.password.push(c);
+        }
+        self.error = None;
+    }
+
+    pub fn delete_char(&mut self) {
+        if self.confirming {
+            self.confirmation.pop();
+        } else {
+            self.password.pop();
+        }
+    }
+
+    /// 入力を確定（確認入力が一致した場合にパスワードを返す）
+    ///
+    /// 1回目の確定で確認入力へ進み、一致しない場合は最初から入力し直させる。
+    pub fn confirm(&mut self) -> Option<String> {
+        if !self.confirming {
+            self.confirming = true;
+            return None;
+        }
+        if self.password != self.confirmation {
+            self.password.clear();
+            self.confirmation.clear();
+            self.confirming = false;
+            self.error = Some("パスワードが一致しません".to_string());
+            return None;
+        }
+        let password = std::mem::take(&mut self.password);
+        self.hide();
+        Some(password)
+    }
+
+    /// 描画
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        if !self.visible {
+            return;
+        }
+
+        let [popup] = Layout::horizontal([Constraint::Length(60)]).flex(Flex::Center).areas(area);
+        let [popup] = Layout::vertical([Constraint::Length(8)]).flex(Flex::Center).areas(popup);
+
+        frame.render_widget(Clear, popup);
+
+        let block = Block::default()
+            .title(" ◆ 暗号化パスワード ◆ ")
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(Color::Yellow));
+
+        let label = Style::default().fg(Color::Gray);
+        let masked = |value: &str, active: bool| {
+            let mut text = "●".repeat(value.chars().count());
+            if active {
+                text.push('▮');
+            }
+            Span::styled(text, Style::default().fg(Color::White).add_modifier(Modifier::BOLD))
+        };
+
+        let lines = vec![
+            Line::from(vec![
+                Span::styled("ファイル:   ", label),
+                Span::styled(format!("{}.zip", self.file_name), Style::default().fg(Color::Cyan)),
+            ]),
+            Line::from(vec![
+                Span::styled("パスワード: ", label),
+                masked(&self.password, !self.confirming),
+            ]),
+            Line::from(vec![
+                Span::styled("確認:       ", label),
+                masked(&self.confirmation, self.confirming),
+            ]),
+            Line::from(Span::styled(
+                self.error.clone().unwrap_or_default(),
+                Style::default().fg(Color::Red),
+            )),
+            Line::from(vec![
+                Span::styled("[Enter] ", Style::default().fg(Color::DarkGray)),
+                Span::styled("確定", label),
+                Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+                Span::styled("[Esc] ", Style::default().fg(Color::DarkGray)),
+                Span::styled("取消", label),
+            ]),
+        ];
+
+        frame.render_widget(Paragraph::new(lines).block(block), popup);
+    }
+}
+
+impl Default for PasswordPrompt {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn type_text(prompt: &mut PasswordPrompt, text: &str) {
+        text.chars().for_each(|c| prompt.input_char(c));
+    }
+
+    #[test]
+    fn test_confirmation_must_match() {
+        let mut prompt = PasswordPrompt::new();
+        prompt.open("ledger.csv");
+        type_text(&mut prompt, "secret-1");
+        assert_eq!(prompt.confirm(), None);
+        type_text(&mut prompt, "secret-2");
+        assert_eq!(prompt.confirm(), None);
+        assert!(prompt.is_visible());
+
+        type_text(&mut prompt, "secret-1");
+        prompt.confirm();
+        type_text(&mut prompt, "secret-1");
+        assert_eq!(prompt.confirm().as_deref(), Some("secret-1"));
+        assert!(!prompt.is_visible());
+    }
+}
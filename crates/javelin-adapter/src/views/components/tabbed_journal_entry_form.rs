@@ -11,6 +11,22 @@ use ratatui::{
 
 use crate::{input_mode::ModifyInputType, views::components::InputField};
 
+/// 差額を反映する側（借方・貸方）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BalanceSide {
+    Debit,
+    Credit,
+}
+
+impl BalanceSide {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Debit => "借方",
+            Self::Credit => "貸方",
+        }
+    }
+}
+
 /// 仕訳明細行（UI用）
 pub struct JournalEntryLineForm {
     debit_account: InputField,
@@ -25,22 +41,56 @@ impl JournalEntryLineForm {
         Self {
             debit_account: InputField::new(format!("借方科目 #{}", line_number))
                 .with_placeholder("科目コード")
-                .with_input_type(ModifyInputType::OverlayList),
+                .with_input_type(ModifyInputType::OverlayList)
+                .with_help("勘定科目マスタに登録された科目"),
             debit_amount: InputField::new(format!("借方金額 #{}", line_number))
                 .with_placeholder("0")
-                .with_input_type(ModifyInputType::NumberOnly),
+                .with_input_type(ModifyInputType::NumberOnly)
+                .with_rule("科目と金額の両方を入力した明細のみ登録されます")
+                .with_rule("借方合計と貸方合計は一致する必要があります"),
             credit_account: InputField::new(format!("貸方科目 #{}", line_number))
                 .with_placeholder("科目コード")
-                .with_input_type(ModifyInputType::OverlayList),
+                .with_input_type(ModifyInputType::OverlayList)
+                .with_help("勘定科目マスタに登録された科目"),
             credit_amount: InputField::new(format!("貸方金額 #{}", line_number))
                 .with_placeholder("0")
-                .with_input_type(ModifyInputType::NumberOnly),
+                .with_input_type(ModifyInputType::NumberOnly)
+                .with_rule("科目と金額の両方を入力した明細のみ登録されます")
+                .with_rule("借方合計と貸方合計は一致する必要があります"),
             description: InputField::new(format!("摘要 #{}", line_number))
-                .with_placeholder("取引内容")
-                .with_input_type(ModifyInputType::Direct),
+                .with_placeholder("空欄は伝票摘要を引継ぎ")
+                .with_input_type(ModifyInputType::Direct)
+                .with_help("借方・貸方の両明細に記録される取引内容")
+                .with_rule("空欄の場合は伝票摘要が引き継がれます")
+                .with_example("4月分 事務所家賃"),
         }
     }
 
+    /// 明細番号に合わせてラベルを振り直す
+    pub fn renumber(&mut self, line_number: usize) {
+        self.debit_account.set_label(format!("借方科目 #{}", line_number));
+        self.debit_amount.set_label(format!("借方金額 #{}", line_number));
+        self.credit_account.set_label(format!("貸方科目 #{}", line_number));
+        self.credit_amount.set_label(format!("貸方金額 #{}", line_number));
+        self.description.set_label(format!("摘要 #{}", line_number));
+    }
+
+    /// 入力値を複製した明細行を作成
+    fn duplicate(&self, line_number: usize) -> Self {
+        let mut line = Self::new(line_number);
+        line.debit_account.set_value(self.debit_account.value().to_string());
+        line.debit_amount.set_value(self.debit_amount.value().to_string());
+        line.credit_account.set_value(self.credit_account.value().to_string());
+        line.credit_amount.set_value(self.credit_amount.value().to_string());
+        line.description.set_value(self.description.value().to_string());
+        line
+    }
+
+    /// 科目の組み合わせ（借方科目, 貸方科目）を取得
+    fn account_key(&self) -> (&str, &str) {
+        (self.debit_account.value(), self.credit_account.value())
+    }
+
     pub fn debit_account(&self) -> &InputField {
         &self.debit_account
     }
@@ -159,6 +209,156 @@ impl TabbedJournalEntryForm {
         }
     }
 
+    /// 現在の明細行を複製し、直後に挿入する
+    pub fn duplicate_line(&mut self) {
+        let line = self.current_line().duplicate(self.current_line_index + 2);
+        self.lines.insert(self.current_line_index + 1, line);
+        self.current_line_index += 1;
+        self.renumber();
+    }
+
+    /// 現在の明細行の金額を2行に分割する
+    ///
+    /// 借方・貸方の金額をそれぞれ半分ずつに分け、端数は元の行に残す。
+    /// 金額が未入力または数値でない場合は分割しない。
+    pub fn split_line(&mut self) -> bool {
+        let current = self.current_line();
+        let debit = parse_amount(current.debit_amount.value());
+        let credit = parse_amount(current.credit_amount.value());
+        let (Some(debit), Some(credit)) = (debit, credit) else {
+            return false;
+        };
+        if debit.unwrap_or(0) < 2 && credit.unwrap_or(0) < 2 {
+            return false;
+        }
+
+        let mut split = current.duplicate(self.current_line_index + 2);
+        let current = &mut self.lines[self.current_line_index];
+        if let Some(debit) = debit {
+            current.debit_amount.set_value((debit - debit / 2).to_string());
+            split.debit_amount.set_value((debit / 2).to_string());
+        }
+        if let Some(credit) = credit {
+            current.credit_amount.set_value((credit - credit / 2).to_string());
+            split.credit_amount.set_value((credit / 2).to_string());
+        }
+
+        self.lines.insert(self.current_line_index + 1, split);
+        self.current_line_index += 1;
+        self.renumber();
+        true
+    }
+
+    /// 借方科目・貸方科目が同一の明細行を統合する
+    ///
+    /// 先に現れた行へ金額を合算し、後続の行を削除する。科目が未入力の行と
+    /// 金額が数値でない行は対象外。統合した行数を返す。
+    pub fn merge_same_account_lines(&mut self) -> usize {
+        let mut merged: Vec<JournalEntryLineForm> = Vec::with_capacity(self.lines.len());
+        let mut merged_count = 0;
+
+        for line in self.lines.drain(..) {
+            let (debit_account, credit_account) = line.account_key();
+            let mergeable = !(debit_account.is_empty() && credit_account.is_empty())
+                && parse_amount(line.debit_amount.value()).is_some()
+                && parse_amount(line.credit_amount.value()).is_some();
+            let target = merged.iter_mut().find(|existing| {
+                mergeable
+                    && existing.account_key() == line.account_key()
+                    && parse_amount(existing.debit_amount.value()).is_some()
+                    && parse_amount(existing.credit_amount.value()).is_some()
+            });
+
+            match target {
+                Some(target) => {
+                    sum_amount(&mut target.debit_amount, &line.debit_amount);
+                    sum_amount(&mut target.credit_amount, &line.credit_amount);
+                    merged_count += 1;
+                }
+                None => merged.push(line),
+            }
+        }
+
+        self.lines = merged;
+        // 最低2行は残す
+        while self.lines.len() < 2 {
+            self.lines.push(JournalEntryLineForm::new(self.lines.len() + 1));
+        }
+        if self.current_line_index >= self.lines.len() {
+            self.current_line_index = self.lines.len() - 1;
+        }
+        self.renumber();
+        merged_count
+    }
+
+    /// 現在の明細行を1つ前へ移動する
+    pub fn move_line_up(&mut self) -> bool {
+        if self.current_line_index == 0 {
+            return false;
+        }
+        self.lines.swap(self.current_line_index, self.current_line_index - 1);
+        self.current_line_index -= 1;
+        self.renumber();
+        true
+    }
+
+    /// 現在の明細行を1つ後ろへ移動する
+    pub fn move_line_down(&mut self) -> bool {
+        if self.current_line_index + 1 >= self.lines.len() {
+            return false;
+        }
+        self.lines.swap(self.current_line_index, self.current_line_index + 1);
+        self.current_line_index += 1;
+        self.renumber();
+        true
+    }
+
+    /// 借方・貸方の合計金額（数値でない金額は0として扱う）
+    pub fn totals(&self) -> (u64, u64) {
+        self.lines.iter().fold((0, 0), |(debit, credit), line| {
+            (
+                debit + parse_amount(line.debit_amount.value()).flatten().unwrap_or(0),
+                credit + parse_amount(line.credit_amount.value()).flatten().unwrap_or(0),
+            )
+        })
+    }
+
+    /// 貸借の差額を現在の明細行へ反映する
+    ///
+    /// 指定した側の金額を、その行の既存金額を除いた合計との差で置き換え、貸借を一致させる。
+    /// 側を指定しない場合は合計の少ない側へ反映する。反映する金額が正にならない場合
+    /// （すでに一致している、指定側のほうが多い）は反映せずNoneを返す。
+    pub fn balance_current_line(
+        &mut self,
+        side: Option<BalanceSide>,
+    ) -> Option<(BalanceSide, u64)> {
+        let (debit_total, credit_total) = self.totals();
+        let side = side.or(match debit_total.cmp(&credit_total) {
+            std::cmp::Ordering::Less => Some(BalanceSide::Debit),
+            std::cmp::Ordering::Greater => Some(BalanceSide::Credit),
+            std::cmp::Ordering::Equal => None,
+        })?;
+
+        let line = &mut self.lines[self.current_line_index];
+        let (field, own_total, other_total) = match side {
+            BalanceSide::Debit => (&mut line.debit_amount, debit_total, credit_total),
+            BalanceSide::Credit => (&mut line.credit_amount, credit_total, debit_total),
+        };
+        let own_rest = own_total - parse_amount(field.value()).flatten().unwrap_or(0);
+        let amount = other_total.checked_sub(own_rest).filter(|amount| *amount > 0)?;
+        field.set_value(amount.to_string());
+        Some((side, amount))
+    }
+
+    /// 明細番号を並び順（1始まり）に振り直す
+    ///
+    /// 登録時の行番号（LineNumber）も並び順から採番されるため、画面表示と一致させる。
+    fn renumber(&mut self) {
+        for (idx, line) in self.lines.iter_mut().enumerate() {
+            line.renumber(idx + 1);
+        }
+    }
+
     /// 次の明細行へ移動
     pub fn next_line(&mut self) {
         if self.current_line_index < self.lines.len() - 1 {
@@ -255,8 +455,147 @@ impl TabbedJournalEntryForm {
     }
 }
 
+/// 金額入力を解析（未入力はSome(None)、数値でない場合はNone）
+fn parse_amount(value: &str) -> Option<Option<u64>> {
+    if value.is_empty() {
+        Some(None)
+    } else {
+        value.parse().ok().map(Some)
+    }
+}
+
+/// 金額を合算する
+fn sum_amount(target: &mut InputField, source: &InputField) {
+    let total = parse_amount(target.value()).flatten().unwrap_or(0)
+        + parse_amount(source.value()).flatten().unwrap_or(0);
+    if !target.value().is_empty() || !source.value().is_empty() {
+        target.set_value(total.to_string());
+    }
+}
+
 impl Default for TabbedJournalEntryForm {
     fn default() -> Self {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set_line(form: &mut TabbedJournalEntryForm, index: usize, values: [&str; 5]) {
+        let line = &mut form.lines_mut()[index];
+        for (field_index, value) in values.into_iter().enumerate() {
+            line.get_field_mut(field_index).unwrap().set_value(value.to_string());
+        }
+    }
+
+    fn values(form: &TabbedJournalEntryForm, index: usize) -> Vec<&str> {
+        (0..5).map(|i| form.lines()[index].get_field(i).unwrap().value()).collect()
+    }
+
+    fn assert_numbered(form: &TabbedJournalEntryForm) {
+        for (idx, line) in form.lines().iter().enumerate() {
+            assert_eq!(line.debit_account().label(), format!("借方科目 #{}", idx + 1));
+            assert_eq!(line.description().label(), format!("摘要 #{}", idx + 1));
+        }
+    }
+
+    #[test]
+    fn test_duplicate_line_inserts_copy_after_current() {
+        let mut form = TabbedJournalEntryForm::new();
+        set_line(&mut form, 0, ["1001", "1000", "4001", "1000", "売上"]);
+
+        form.duplicate_line();
+
+        assert_eq!(form.line_count(), 3);
+        assert_eq!(form.current_line_index(), 1);
+        assert_eq!(values(&form, 1), values(&form, 0));
+        assert_eq!(values(&form, 2), vec!["", "", "", "", ""]);
+        assert_numbered(&form);
+    }
+
+    #[test]
+    fn test_split_line_keeps_remainder_on_original() {
+        let mut form = TabbedJournalEntryForm::new();
+        set_line(&mut form, 0, ["1001", "1001", "4001", "", "売上"]);
+
+        assert!(form.split_line());
+
+        assert_eq!(values(&form, 0), vec!["1001", "501", "4001", "", "売上"]);
+        assert_eq!(values(&form, 1), vec!["1001", "500", "4001", "", "売上"]);
+        assert_eq!(form.line_count(), 3);
+        assert_numbered(&form);
+
+        // 金額が数値でない行は分割しない
+        set_line(&mut form, 1, ["1001", "abc", "", "", ""]);
+        assert!(!form.split_line());
+        assert_eq!(form.line_count(), 3);
+    }
+
+    #[test]
+    fn test_merge_same_account_lines_sums_amounts() {
+        let mut form = TabbedJournalEntryForm::new();
+        form.add_line();
+        form.add_line();
+        set_line(&mut form, 0, ["1001", "1000", "4001", "1000", "売上A"]);
+        set_line(&mut form, 1, ["5001", "300", "1001", "300", "仕入"]);
+        set_line(&mut form, 2, ["1001", "500", "4001", "500", "売上B"]);
+
+        assert_eq!(form.merge_same_account_lines(), 1);
+
+        assert_eq!(form.line_count(), 3);
+        assert_eq!(values(&form, 0), vec!["1001", "1500", "4001", "1500", "売上A"]);
+        assert_eq!(values(&form, 1), vec!["5001", "300", "1001", "300", "仕入"]);
+        // 科目が未入力の行は統合しない
+        assert_eq!(values(&form, 2), vec!["", "", "", "", ""]);
+        assert_numbered(&form);
+    }
+
+    #[test]
+    fn test_move_line_reorders_and_renumbers() {
+        let mut form = TabbedJournalEntryForm::new();
+        set_line(&mut form, 0, ["1001", "1000", "", "", "first"]);
+        set_line(&mut form, 1, ["", "", "4001", "1000", "second"]);
+
+        assert!(!form.move_line_up());
+        assert!(form.move_line_down());
+        assert_eq!(form.current_line_index(), 1);
+        assert_eq!(form.lines()[0].description().value(), "second");
+        assert_eq!(form.lines()[1].description().value(), "first");
+        assert!(!form.move_line_down());
+        assert_numbered(&form);
+
+        assert!(form.move_line_up());
+        assert_eq!(form.lines()[0].description().value(), "first");
+        assert_numbered(&form);
+    }
+
+    #[test]
+    fn test_balance_current_line_fills_remaining_amount() {
+        let mut form = TabbedJournalEntryForm::new();
+        form.add_line();
+        set_line(&mut form, 0, ["1001", "1100", "", "", ""]);
+        set_line(&mut form, 1, ["", "", "4001", "1000", ""]);
+        set_line(&mut form, 2, ["", "", "2101", "50", ""]);
+        assert_eq!(form.totals(), (1100, 1050));
+
+        // 行3の貸方金額を置き換えて一致させる
+        form.next_line();
+        form.next_line();
+        assert_eq!(form.balance_current_line(None), Some((BalanceSide::Credit, 100)));
+        assert_eq!(values(&form, 2), vec!["", "", "2101", "100", ""]);
+        assert_eq!(form.totals(), (1100, 1100));
+
+        // 一致済みの場合は反映しない
+        assert_eq!(form.balance_current_line(None), None);
+        // 指定側の残りの合計のほうが多い場合は反映しない
+        form.previous_line();
+        form.previous_line();
+        assert_eq!(form.balance_current_line(Some(BalanceSide::Credit)), None);
+        assert_eq!(
+            form.balance_current_line(Some(BalanceSide::Debit)),
+            Some((BalanceSide::Debit, 1100))
+        );
+    }
+}
@@ -0,0 +1,345 @@
+// TaxCalculator - 電卓オーバーレイ（税込・税抜換算）
+// 責務: 金額の四則演算と消費税の税込・税抜換算、換算結果の金額欄への反映
+
+use ratatui::{
+    Frame,
+    layout::{Constraint, Flex, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Clear, Paragraph},
+};
+
+use crate::format_number;
+
+/// 切り替えられる消費税率（%）
+const TAX_RATES: [u64; 2] = [10, 8];
+
+/// 換算方向
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TaxConversion {
+    /// 税抜金額から税込金額を求める
+    #[default]
+    ExclusiveToInclusive,
+    /// 税込金額から税抜金額を求める
+    InclusiveToExclusive,
+}
+
+impl TaxConversion {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::ExclusiveToInclusive => "税抜 → 税込",
+            Self::InclusiveToExclusive => "税込 → 税抜",
+        }
+    }
+
+    fn toggled(&self) -> Self {
+        match self {
+            Self::ExclusiveToInclusive => Self::InclusiveToExclusive,
+            Self::InclusiveToExclusive => Self::ExclusiveToInclusive,
+        }
+    }
+}
+
+/// 税額の内訳（円未満の消費税は切り捨て）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TaxBreakdown {
+    pub exclusive: u64,
+    pub tax: u64,
+    pub inclusive: u64,
+}
+
+/// 電卓オーバーレイ
+///
+/// 入力した式（+ - * /）を計算し、結果を税込・税抜に換算する。
+/// 確定すると換算後の金額（税抜 → 税込なら税込金額）を反映する。
+pub struct TaxCalculator {
+    visible: bool,
+    expression: String,
+    conversion: TaxConversion,
+    rate_index: usize,
+}
+
+impl TaxCalculator {
+    pub fn new() -> Self {
+        Self {
+            visible: false,
+            expression: String::new(),
+            conversion: TaxConversion::default(),
+            rate_index: 0,
+        }
+    }
+
+    /// 初期値（フォーカス中の金額）を入れて表示
+    pub fn open(&mut self, initial: &str) {
+        self.expression = initial.trim().to_string();
+        self.visible = true;
+    }
+
+    pub fn hide(&mut self) {
+        self.visible = false;
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// 数字・演算子のみ受け付ける
+    pub fn input_char(&mut self, c: char) {
+        if c.is_ascii_digit() || matches!(c, '+' | '-' | '*' | '/' | '.' | ',') {
+            self.expression.push(c);
+        }
+    }
+
+    pub fn delete_char(&mut self) {
+        self.expression.pop();
+    }
+
+    /// 換算方向を切り替え
+    pub fn toggle_conversion(&mut self) {
+        self.conversion = self.conversion.toggled();
+    }
+
+    /// 税率を切り替え
+    pub fn cycle_rate(&mut self) {
+        self.rate_index = (self.rate_index + 1) % TAX_RATES.len();
+    }
+
+    pub fn rate(&self) -> u64 {
+        TAX_RATES[self.rate_index]
+    }
+
+    pub fn conversion(&self) -> TaxConversion {
+        self.conversion
+    }
+
+    /// 式の計算結果（円未満は四捨五入、負の値・不正な式はNone）
+    pub fn value(&self) -> Option<u64> {
+        let value = evaluate(&self.expression)?;
+        (value >= 0.0 && value.is_finite()).then(|| value.round() as u64)
+    }
+
+    /// 計算結果の税額の内訳
+    pub fn breakdown(&self) -> Option<TaxBreakdown> {
+        let amount = self.value()?;
+        let rate = self.rate();
+        Some(match self.conversion {
+            TaxConversion::ExclusiveToInclusive => {
+                let tax = amount * rate / 100;
+                TaxBreakdown { exclusive: amount, tax, inclusive: amount + tax }
+            }
+            TaxConversion::InclusiveToExclusive => {
+                let tax = amount * rate / (100 + rate);
+                TaxBreakdown { exclusive: amount - tax, tax, inclusive: amount }
+            }
+        })
+    }
+
+    /// 確定時に反映する金額（換算後の金額）
+    pub fn result(&self) -> Option<u64> {
+        let breakdown = self.breakdown()?;
+        Some(match self.conversion {
+            TaxConversion::ExclusiveToInclusive => breakdown.inclusive,
+            TaxConversion::InclusiveToExclusive => breakdown.exclusive,
+        })
+    }
+
+    /// 描画
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        if !self.visible {
+            return;
+        }
+
+        let [popup] = Layout::horizontal([Constraint::Length(52)]).flex(Flex::Center).areas(area);
+        let [popup] = Layout::vertical([Constraint::Length(11)]).flex(Flex::Center).areas(popup);
+
+        frame.render_widget(Clear, popup);
+
+        let block = Block::default()
+            .title(" ◆ 電卓 ◆ ")
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(Color::Yellow));
+
+        let label = Style::default().fg(Color::Gray);
+        let amount = |value: Option<u64>| match value {
+            Some(value) => format!("{:>16}", format_number!(value as f64)),
+            None => format!("{:>16}", "---"),
+        };
+        let breakdown = self.breakdown();
+        let (exclusive_style, inclusive_style) = match self.conversion {
+            TaxConversion::ExclusiveToInclusive => {
+                (Style::default().fg(Color::White), Self::result_style())
+            }
+            TaxConversion::InclusiveToExclusive => {
+                (Self::result_style(), Style::default().fg(Color::White))
+            }
+        };
+
+        let lines = vec![
+            Line::from(vec![
+                Span::styled("式:     ", label),
+                Span::styled(
+                    format!("{}▮", self.expression),
+                    Style::default().fg(Color::White).add_modifier(Modifier::BOLD),
+                ),
+            ]),
+            Line::from(vec![
+                Span::styled("計算値: ", label),
+                Span::styled(amount(self.value()), Style::default().fg(Color::White)),
+            ]),
+            Line::from(vec![
+                Span::styled("換算:   ", label),
+                Span::styled(
+                    format!("{}（税率 {}%）", self.conversion.label(), self.rate()),
+                    Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+                ),
+            ]),
+            Line::from(""),
+            Line::from(vec![
+                Span::styled("税抜:   ", label),
+                Span::styled(amount(breakdown.map(|b| b.exclusive)), exclusive_style),
+            ]),
+            Line::from(vec![
+                Span::styled("消費税: ", label),
+                Span::styled(amount(breakdown.map(|b| b.tax)), Style::default().fg(Color::White)),
+            ]),
+            Line::from(vec![
+                Span::styled("税込:   ", label),
+                Span::styled(amount(breakdown.map(|b| b.inclusive)), inclusive_style),
+            ]),
+            Line::from(""),
+            Line::from(vec![
+                Span::styled("[Enter] ", Style::default().fg(Color::DarkGray)),
+                Span::styled("反映", label),
+                Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+                Span::styled("[Tab] ", Style::default().fg(Color::DarkGray)),
+                Span::styled("換算切替", label),
+                Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+                Span::styled("[r] ", Style::default().fg(Color::DarkGray)),
+                Span::styled("税率", label),
+                Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+                Span::styled("[Esc] ", Style::default().fg(Color::DarkGray)),
+                Span::styled("閉じる", label),
+            ]),
+        ];
+
+        frame.render_widget(Paragraph::new(lines).block(block), popup);
+    }
+
+    fn result_style() -> Style {
+        Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+    }
+}
+
+impl Default for TaxCalculator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// 四則演算の式を計算（乗除を優先、カンマは無視、0除算・不正な式はNone）
+fn evaluate(expression: &str) -> Option<f64> {
+    let tokens = tokenize(expression)?;
+    let mut tokens = tokens.iter().peekable();
+
+    let mut total = term(&mut tokens)?;
+    while let Some(Token::Operator(op)) = tokens.peek().copied() {
+        tokens.next();
+        let rhs = term(&mut tokens)?;
+        match op {
+            '+' => total += rhs,
+            '-' => total -= rhs,
+            _ => return None,
+        }
+    }
+    tokens.next().is_none().then_some(total)
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Token {
+    Number(f64),
+    Operator(char),
+}
+
+fn tokenize(expression: &str) -> Option<Vec<Token>> {
+    let mut tokens = Vec::new();
+    let mut number = String::new();
+    for c in expression.chars().filter(|c| !c.is_whitespace() && *c != ',') {
+        if c.is_ascii_digit() || c == '.' {
+            number.push(c);
+            continue;
+        }
+        if !number.is_empty() {
+            tokens.push(Token::Number(number.parse().ok()?));
+            number.clear();
+        }
+        tokens.push(Token::Operator(c));
+    }
+    if !number.is_empty() {
+        tokens.push(Token::Number(number.parse().ok()?));
+    }
+    Some(tokens)
+}
+
+/// 乗除の項を計算
+fn term<'a>(tokens: &mut std::iter::Peekable<impl Iterator<Item = &'a Token>>) -> Option<f64> {
+    let Some(Token::Number(mut value)) = tokens.next().copied() else {
+        return None;
+    };
+    while let Some(Token::Operator(op @ ('*' | '/'))) = tokens.peek().copied() {
+        tokens.next();
+        let Some(Token::Number(rhs)) = tokens.next().copied() else {
+            return None;
+        };
+        if *op == '*' {
+            value *= rhs;
+        } else if rhs == 0.0 {
+            return None;
+        } else {
+            value /= rhs;
+        }
+    }
+    Some(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn calculator(expression: &str) -> TaxCalculator {
+        let mut calculator = TaxCalculator::new();
+        calculator.open(expression);
+        calculator
+    }
+
+    #[test]
+    fn test_evaluate_respects_precedence() {
+        assert_eq!(evaluate("1,000+200*3"), Some(1600.0));
+        assert_eq!(evaluate("1000/4-50"), Some(200.0));
+        assert_eq!(evaluate("10/0"), None);
+        assert_eq!(evaluate("100+"), None);
+        assert_eq!(evaluate(""), None);
+    }
+
+    #[test]
+    fn test_tax_conversion_truncates_tax() {
+        let mut calculator = calculator("1,234");
+        assert_eq!(
+            calculator.breakdown(),
+            Some(TaxBreakdown { exclusive: 1234, tax: 123, inclusive: 1357 })
+        );
+        assert_eq!(calculator.result(), Some(1357));
+
+        calculator.toggle_conversion();
+        calculator.cycle_rate();
+        assert_eq!(calculator.rate(), 8);
+        // 1,234 × 8/108 = 91.4 → 91
+        assert_eq!(
+            calculator.breakdown(),
+            Some(TaxBreakdown { exclusive: 1143, tax: 91, inclusive: 1234 })
+        );
+        assert_eq!(calculator.result(), Some(1143));
+
+        assert_eq!(self::calculator("100-200").result(), None);
+    }
+}
@@ -4,11 +4,13 @@
 pub mod form_layout;
 pub mod main_layout;
 pub mod menu_layout;
+pub mod responsive;
 pub mod templates;
 pub mod view_layout;
 
 pub use form_layout::*;
 pub use main_layout::*;
 pub use menu_layout::*;
+pub use responsive::*;
 pub use templates::*;
 pub use view_layout::*;
@@ -10,8 +10,12 @@ use ratatui::{
     widgets::{Block, Borders, Paragraph},
 };
 
+use super::shows_side_panel;
 use crate::{input_mode::InputMode, views::components::EventViewer};
 
+/// 幅が狭い場合にフォームの下へ表示するイベントビューアの高さ
+const STACKED_EVENT_VIEWER_HEIGHT: u16 = 6;
+
 /// フォームレイアウト
 pub struct FormLayout {
     title: String,
@@ -45,6 +49,8 @@ impl FormLayout {
     }
 
     /// レイアウトを描画（左62%フォーム、右38%イベントビューア+カレンダー）
+    ///
+    /// 幅が狭い場合はイベントビューアをフォームの下に移し、フォームを全幅で表示する。
     pub fn render<F>(
         &mut self,
         frame: &mut Frame,
@@ -70,10 +76,18 @@ impl FormLayout {
         self.render_header(frame, chunks[0]);
 
         // メインエリアを左右分割: フォーム(62%) + イベントビューア+カレンダー(38%)
-        let main_chunks = Layout::default()
-            .direction(Direction::Horizontal)
-            .constraints([Constraint::Percentage(62), Constraint::Percentage(38)])
-            .split(chunks[1]);
+        // 幅が狭い場合はイベントビューアをフォームの下に縮めて表示する
+        let main_chunks = if shows_side_panel(size.width) {
+            Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Percentage(62), Constraint::Percentage(38)])
+                .split(chunks[1])
+        } else {
+            Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Min(0), Constraint::Length(STACKED_EVENT_VIEWER_HEIGHT)])
+                .split(chunks[1])
+        };
 
         render_form(frame, main_chunks[0]);
 
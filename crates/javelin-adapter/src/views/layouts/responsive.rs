@@ -0,0 +1,139 @@
+// Responsive - 端末サイズに応じたレイアウト調整
+// 責務: 最小サイズ未満の端末での案内表示と、幅が狭い場合の列の折りたたみ
+
+use ratatui::{
+    Frame,
+    layout::{Alignment, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Clear, Paragraph},
+};
+
+/// 画面を描画できる最小の端末幅
+pub const MIN_TERMINAL_WIDTH: u16 = 80;
+
+/// 画面を描画できる最小の端末高さ
+pub const MIN_TERMINAL_HEIGHT: u16 = 24;
+
+/// イベントビューア等の補助ペインを横に並べる最小幅（未満なら主ペインのみ表示）
+pub const SIDE_PANEL_MIN_WIDTH: u16 = 120;
+
+/// 最小サイズ未満か
+pub fn is_below_minimum(area: Rect) -> bool {
+    area.width < MIN_TERMINAL_WIDTH || area.height < MIN_TERMINAL_HEIGHT
+}
+
+/// 最小サイズ以上なら画面を描画し、未満なら端末の拡大を促す画面を表示
+///
+/// すべての画面の描画をこの関数を経由させ、狭い端末での表示崩れを防ぐ。
+pub fn render_guarded(frame: &mut Frame, render: impl FnOnce(&mut Frame)) {
+    let area = frame.area();
+    if is_below_minimum(area) {
+        render_enlarge_notice(frame, area);
+    } else {
+        render(frame);
+    }
+}
+
+/// 端末の拡大を促す画面
+fn render_enlarge_notice(frame: &mut Frame, area: Rect) {
+    let size_style = |ok: bool| {
+        if ok {
+            Style::default().fg(Color::Green)
+        } else {
+            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)
+        }
+    };
+
+    let lines = vec![
+        Line::from(Span::styled(
+            "端末を拡大してください",
+            Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("現在 ", Style::default().fg(Color::Gray)),
+            Span::styled(area.width.to_string(), size_style(area.width >= MIN_TERMINAL_WIDTH)),
+            Span::styled(" x ", Style::default().fg(Color::Gray)),
+            Span::styled(area.height.to_string(), size_style(area.height >= MIN_TERMINAL_HEIGHT)),
+        ]),
+        Line::from(Span::styled(
+            format!("必要 {} x {}", MIN_TERMINAL_WIDTH, MIN_TERMINAL_HEIGHT),
+            Style::default().fg(Color::Gray),
+        )),
+    ];
+
+    // 枠線が入らないほど小さい場合は文言のみ表示する
+    let paragraph = Paragraph::new(lines).alignment(Alignment::Center);
+    let paragraph = if area.width >= 24 && area.height >= 6 {
+        paragraph.block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(Color::Yellow)),
+        )
+    } else {
+        paragraph
+    };
+
+    frame.render_widget(Clear, area);
+    frame.render_widget(paragraph, area);
+}
+
+/// 補助ペインを横に並べる幅があるか
+pub fn shows_side_panel(width: u16) -> bool {
+    width >= SIDE_PANEL_MIN_WIDTH
+}
+
+/// 幅を等分したとき、各列がmin_column_width以上となる最大の列数（1〜columns）
+pub fn fitting_columns(width: u16, min_column_width: u16, columns: usize) -> usize {
+    let fitting = (width / min_column_width.max(1)) as usize;
+    fitting.clamp(1, columns.max(1))
+}
+
+/// columns列のうちvisible列だけ表示する場合の先頭の列（focused列が必ず含まれる）
+pub fn column_window_start(focused: usize, visible: usize, columns: usize) -> usize {
+    let visible = visible.clamp(1, columns.max(1));
+    focused
+        .min(columns.saturating_sub(1))
+        .saturating_sub(visible - 1)
+        .min(columns - visible)
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::{Terminal, backend::TestBackend};
+
+    use super::*;
+
+    fn rendered_text(width: u16, height: u16) -> String {
+        let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+        terminal
+            .draw(|frame| {
+                render_guarded(frame, |frame| {
+                    frame.render_widget(Paragraph::new("page"), frame.area());
+                })
+            })
+            .unwrap();
+        terminal.backend().buffer().content().iter().map(|cell| cell.symbol()).collect()
+    }
+
+    #[test]
+    fn test_guard_replaces_page_below_minimum_size() {
+        assert!(rendered_text(MIN_TERMINAL_WIDTH, MIN_TERMINAL_HEIGHT).starts_with("page"));
+        assert!(!rendered_text(MIN_TERMINAL_WIDTH - 1, MIN_TERMINAL_HEIGHT).contains("page"));
+        assert!(!rendered_text(20, 3).contains("page"));
+    }
+
+    #[test]
+    fn test_fitting_columns_and_window_follow_focus() {
+        assert_eq!(fitting_columns(120, 30, 3), 3);
+        assert_eq!(fitting_columns(70, 30, 3), 2);
+        assert_eq!(fitting_columns(10, 30, 3), 1);
+
+        assert_eq!(column_window_start(0, 2, 3), 0);
+        assert_eq!(column_window_start(2, 2, 3), 1);
+        assert_eq!(column_window_start(1, 1, 3), 1);
+        assert_eq!(column_window_start(2, 3, 3), 0);
+    }
+}
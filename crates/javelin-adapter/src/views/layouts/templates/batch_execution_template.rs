@@ -138,6 +138,7 @@ pub trait BatchExecutionItem {
     fn has_errors(&self) -> bool;
 }
 
+use javelin_application::dtos::response::{ClosingEntryDto, TrialBalanceImpactDto};
 use ratatui::{
     Frame,
     layout::{Constraint, Layout, Rect},
@@ -146,7 +147,10 @@ use ratatui::{
     widgets::{Block, Borders, Paragraph},
 };
 
-use crate::views::components::{EventViewer, LoadingSpinner};
+use crate::{
+    format_amount, format_balance,
+    views::components::{EventViewer, LoadingSpinner},
+};
 
 /// バッチ実行テンプレート
 ///
@@ -288,6 +292,24 @@ impl BatchExecutionTemplate {
         self.event_viewer.add_error(message);
     }
 
+    /// 締め処理の仕訳と試算表への影響を追加
+    ///
+    /// 試算の場合は起票予定の仕訳と、反映後の残高（反映前 → 反映後）を表示します。
+    ///
+    /// # Arguments
+    ///
+    /// * `entries` - 起票した（試算の場合は起票予定の）仕訳
+    /// * `impact` - 仕訳を反映した試算表の残高
+    pub fn add_closing_entries(
+        &mut self,
+        entries: &[ClosingEntryDto],
+        impact: &[TrialBalanceImpactDto],
+    ) {
+        for line in closing_entry_lines(entries, impact) {
+            self.event_viewer.add_info(line);
+        }
+    }
+
     /// 次のステップを選択
     ///
     /// 選択インデックスを1つ増やします。
@@ -445,6 +467,53 @@ impl BatchExecutionTemplate {
     }
 }
 
+/// 締め処理の仕訳と試算表への影響の表示行
+fn closing_entry_lines(
+    entries: &[ClosingEntryDto],
+    impact: &[TrialBalanceImpactDto],
+) -> Vec<String> {
+    if entries.is_empty() {
+        return vec!["起票する仕訳はありません".to_string()];
+    }
+
+    let mut lines = Vec::new();
+    for entry in entries {
+        lines.push(format!(
+            "仕訳 {} {}{}",
+            entry.transaction_date,
+            entry.description,
+            entry.entry_id.as_deref().map(|id| format!("（{}）", id)).unwrap_or_default()
+        ));
+        for line in &entry.lines {
+            let side = if line.side == "Debit" {
+                "借方"
+            } else {
+                "貸方"
+            };
+            lines.push(format!(
+                "  {} {} {} {}",
+                side,
+                line.account_code,
+                format_amount!(line.amount),
+                line.currency
+            ));
+        }
+    }
+    lines.push("試算表への影響（反映前 → 反映後）".to_string());
+    for account in impact {
+        lines.push(format!(
+            "  {} {}: {} → {}（借方 {} / 貸方 {}）",
+            account.account_code,
+            account.account_name,
+            format_balance!(account.closing_balance),
+            format_balance!(account.simulated_balance),
+            format_amount!(account.debit_amount),
+            format_amount!(account.credit_amount)
+        ));
+    }
+    lines
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -610,6 +679,47 @@ mod tests {
         assert_eq!(template.animation_frame, 0);
     }
 
+    #[test]
+    fn test_closing_entry_lines_show_entries_and_simulated_balances() {
+        use javelin_application::dtos::request::JournalEntryLineDto;
+
+        let line = |side: &str, account_code: &str| JournalEntryLineDto {
+            line_number: 1,
+            side: side.to_string(),
+            account_code: account_code.to_string(),
+            sub_account_code: None,
+            department_code: None,
+            dimensions: Default::default(),
+            amount: 1000.0,
+            currency: "JPY".to_string(),
+            tax_type: "NonTaxable".to_string(),
+            tax_amount: 0.0,
+            description: None,
+        };
+        let entries = vec![ClosingEntryDto {
+            entry_id: None,
+            transaction_date: "2024-03-31".to_string(),
+            description: "棚卸資産評価損".to_string(),
+            lines: vec![line("Debit", "5300"), line("Credit", "1300")],
+        }];
+        let impact = vec![TrialBalanceImpactDto {
+            account_code: "1300".to_string(),
+            account_name: "商品".to_string(),
+            closing_balance: 5000.0,
+            debit_amount: 0.0,
+            credit_amount: 1000.0,
+            simulated_balance: 4000.0,
+        }];
+
+        let lines = closing_entry_lines(&entries, &impact);
+        assert_eq!(lines[0], "仕訳 2024-03-31 棚卸資産評価損");
+        assert!(lines[1].contains("借方 5300"));
+        assert!(lines[2].contains("貸方 1300"));
+        assert!(lines[4].contains("1300 商品: 5,000 → 4,000"));
+
+        assert_eq!(closing_entry_lines(&[], &[]), vec!["起票する仕訳はありません"]);
+    }
+
     #[test]
     fn test_process_step_status_equality() {
         assert_eq!(ProcessStepStatus::Waiting, ProcessStepStatus::Waiting);
@@ -1,46 +1,96 @@
 // Pages - ページ単位のビュー
 // 責務: 各画面の実装
 
+pub mod account_activity_page;
 pub mod account_adjustment_execution_page;
 pub mod account_adjustment_page;
 pub mod account_master_page;
+pub mod account_reconciliation_page;
+pub mod accounting_policy_page;
 pub mod application_settings_page;
+pub mod balance_analysis_page;
 pub mod closing_lock_page;
 pub mod closing_page;
 pub mod closing_preparation_execution_page;
 pub mod closing_preparation_page;
+pub mod closing_timetable_page;
+pub mod dimension_master_page;
 pub mod financial_statement_execution_page;
 pub mod financial_statement_page;
 pub mod home_page;
 pub mod ifrs_valuation_execution_page;
 pub mod ifrs_valuation_page;
+pub mod inbox_detail_page;
+pub mod inbox_page;
+pub mod inventory_worksheet_page;
+pub mod job_queue_page;
 pub mod journal_entry_form_page;
+pub mod journal_import_page;
+pub mod kpi_dashboard_page;
 pub mod ledger_consolidation_execution_page;
 pub mod ledger_consolidation_page;
 pub mod ledger_detail_page;
 pub mod ledger_page;
+pub mod login_page;
+pub mod maintenance_page;
+pub mod management_account_mapping_page;
+pub mod note_cross_reference_page;
 pub mod note_draft_page;
+pub mod projection_console_page;
+pub mod report_archive_page;
+pub mod report_hub_page;
 pub mod search_page;
+pub mod session_log_page;
+pub mod setup_wizard_page;
+pub mod statement_line_mapping_page;
 pub mod subsidiary_account_master_page;
+pub mod supplier_invoice_page;
+pub mod system_info_page;
+pub mod user_activity_page;
 
+pub use account_activity_page::*;
 pub use account_adjustment_execution_page::*;
 pub use account_adjustment_page::*;
 pub use account_master_page::*;
+pub use account_reconciliation_page::*;
+pub use accounting_policy_page::*;
 pub use application_settings_page::*;
+pub use balance_analysis_page::*;
 pub use closing_lock_page::*;
 pub use closing_page::*;
 pub use closing_preparation_execution_page::*;
 pub use closing_preparation_page::*;
+pub use closing_timetable_page::*;
+pub use dimension_master_page::*;
 pub use financial_statement_execution_page::*;
 pub use financial_statement_page::*;
 pub use home_page::*;
 pub use ifrs_valuation_execution_page::*;
 pub use ifrs_valuation_page::*;
+pub use inbox_detail_page::*;
+pub use inbox_page::*;
+pub use inventory_worksheet_page::*;
+pub use job_queue_page::*;
 pub use journal_entry_form_page::*;
+pub use journal_import_page::*;
+pub use kpi_dashboard_page::*;
 pub use ledger_consolidation_execution_page::*;
 pub use ledger_consolidation_page::*;
 pub use ledger_detail_page::*;
 pub use ledger_page::*;
+pub use login_page::*;
+pub use maintenance_page::*;
+pub use management_account_mapping_page::*;
+pub use note_cross_reference_page::*;
 pub use note_draft_page::*;
+pub use projection_console_page::*;
+pub use report_archive_page::*;
+pub use report_hub_page::*;
 pub use search_page::*;
+pub use session_log_page::*;
+pub use setup_wizard_page::*;
+pub use statement_line_mapping_page::*;
 pub use subsidiary_account_master_page::*;
+pub use supplier_invoice_page::*;
+pub use system_info_page::*;
+pub use user_activity_page::*;
@@ -0,0 +1,467 @@
+// AccountActivityPage - 勘定科目別の記帳量ヒートマップ画面
+// 責務: 科目×月の記帳件数・金額の濃淡表示と急増月の強調、選択科目の詳細表示
+
+use javelin_application::dtos::response::{
+    AccountActivityHeatCell, AccountActivityHeatRow, AmountMask, AnalyzeAccountActivityResponse,
+};
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Cell, Paragraph, Row, Table, TableState},
+};
+
+use crate::{
+    format_balance,
+    views::components::{EventViewer, InfoPanel},
+};
+
+/// 濃度ごとの背景色（0は記帳なし）
+const HEAT_COLORS: [Color; 5] = [
+    Color::Reset,
+    Color::Rgb(20, 60, 40),
+    Color::Rgb(30, 100, 60),
+    Color::Rgb(40, 150, 80),
+    Color::Rgb(60, 200, 100),
+];
+
+/// ヒートマップに表示する値
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ActivityMetric {
+    /// 記帳件数
+    PostingCount,
+    /// 借方・貸方金額の合計
+    Amount,
+}
+
+impl ActivityMetric {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ActivityMetric::PostingCount => "件数",
+            ActivityMetric::Amount => "金額",
+        }
+    }
+
+    pub fn toggled(&self) -> Self {
+        match self {
+            ActivityMetric::PostingCount => ActivityMetric::Amount,
+            ActivityMetric::Amount => ActivityMetric::PostingCount,
+        }
+    }
+}
+
+pub struct AccountActivityPage {
+    table_state: TableState,
+    info_panel: InfoPanel,
+    event_viewer: EventViewer,
+    periods: Vec<String>,
+    rows: Vec<AccountActivityHeatRow>,
+    metric: ActivityMetric,
+    /// 急増月のある科目のみ表示
+    spikes_only: bool,
+    loading: bool,
+    error: Option<String>,
+    animation_frame: usize,
+}
+
+impl AccountActivityPage {
+    pub fn new() -> Self {
+        let mut event_viewer = EventViewer::new();
+        event_viewer
+            .add_info("勘定科目別・月別の記帳量を濃淡で表示します（濃度は科目ごとの最大値基準）");
+
+        Self {
+            table_state: TableState::default(),
+            info_panel: InfoPanel::new("◇ 詳細 ◇").with_border_color(Color::Cyan),
+            event_viewer,
+            periods: Vec::new(),
+            rows: Vec::new(),
+            metric: ActivityMetric::PostingCount,
+            spikes_only: false,
+            loading: false,
+            error: None,
+            animation_frame: 0,
+        }
+    }
+
+    /// 分析結果を表示
+    pub fn set_result(&mut self, response: AnalyzeAccountActivityResponse) {
+        self.loading = false;
+        self.error = None;
+        self.event_viewer.add_info(format!(
+            "{}〜{}: {}科目を集計、急増 {}件",
+            response.periods.first().map(String::as_str).unwrap_or("-"),
+            response.periods.last().map(String::as_str).unwrap_or("-"),
+            response.rows.len(),
+            response.spike_count()
+        ));
+        self.periods = response.periods;
+        self.rows = response.rows;
+        self.reset_selection();
+    }
+
+    pub fn set_error(&mut self, error: String) {
+        self.loading = false;
+        self.rows.clear();
+        self.error = Some(error.clone());
+        self.reset_selection();
+        self.event_viewer.add_error(error);
+    }
+
+    pub fn start_loading(&mut self) {
+        self.loading = true;
+    }
+
+    pub fn add_error(&mut self, message: impl Into<String>) {
+        self.event_viewer.add_error(message);
+    }
+
+    pub fn metric(&self) -> ActivityMetric {
+        self.metric
+    }
+
+    /// 件数・金額の表示を切替
+    pub fn toggle_metric(&mut self) {
+        self.metric = self.metric.toggled();
+    }
+
+    /// 急増月のある科目のみの表示を切替
+    pub fn toggle_spikes_only(&mut self) {
+        self.spikes_only = !self.spikes_only;
+        self.reset_selection();
+    }
+
+    pub fn select_next(&mut self) {
+        let len = self.visible_rows().len();
+        if len > 0 {
+            let next = self.table_state.selected().map_or(0, |index| (index + 1).min(len - 1));
+            self.table_state.select(Some(next));
+        }
+        self.update_detail();
+    }
+
+    pub fn select_previous(&mut self) {
+        if let Some(index) = self.table_state.selected() {
+            self.table_state.select(Some(index.saturating_sub(1)));
+        }
+        self.update_detail();
+    }
+
+    /// 表示対象の科目
+    fn visible_rows(&self) -> Vec<&AccountActivityHeatRow> {
+        self.rows
+            .iter()
+            .filter(|row| !self.spikes_only || row.spike_count() > 0)
+            .collect()
+    }
+
+    fn reset_selection(&mut self) {
+        let has_rows = !self.visible_rows().is_empty();
+        self.table_state.select(has_rows.then_some(0));
+        self.update_detail();
+    }
+
+    /// 選択科目の月別内訳を表示
+    fn update_detail(&mut self) {
+        self.info_panel.clear();
+        let visible = self.visible_rows();
+        let Some(row) = self.table_state.selected().and_then(|index| visible.get(index)).copied()
+        else {
+            self.info_panel.add_text("記帳のある科目はありません");
+            return;
+        };
+
+        let account = format!("{} {}", row.account_code, row.account_name);
+        let amount = |amount: f64| {
+            if row.amount_masked {
+                AmountMask::MASKED.to_string()
+            } else {
+                format_balance!(amount)
+            }
+        };
+        let total = format!("{}件 / {}", row.total_count, amount(row.total_amount));
+        let spikes: Vec<String> = self
+            .periods
+            .iter()
+            .zip(&row.cells)
+            .filter(|(_, cell)| cell.spike)
+            .map(|(period, cell)| {
+                format!("{}: {}件 / {}", period, cell.posting_count, amount(cell.amount))
+            })
+            .collect();
+        self.info_panel.add_line("科目", account);
+        self.info_panel.add_line("合計", total);
+        if spikes.is_empty() {
+            self.info_panel.add_text("急増した月はありません");
+        } else {
+            for spike in spikes {
+                self.info_panel.add_warning(format!("急増 {}", spike));
+            }
+        }
+    }
+
+    pub fn tick(&mut self) {
+        self.animation_frame = (self.animation_frame + 1) % 60;
+    }
+
+    pub fn render(&mut self, frame: &mut Frame, period_label: &str) {
+        let area = frame.area();
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(10), Constraint::Length(3)])
+            .split(area);
+
+        let main_chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(75), Constraint::Percentage(25)])
+            .split(chunks[0]);
+
+        let side_chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(8), Constraint::Length(8)])
+            .split(main_chunks[1]);
+
+        self.render_heatmap(frame, main_chunks[0]);
+        self.info_panel.render(frame, side_chunks[0]);
+        self.event_viewer.render(frame, side_chunks[1]);
+        self.render_status_bar(frame, chunks[1], period_label);
+    }
+
+    fn render_heatmap(&mut self, frame: &mut Frame, area: Rect) {
+        let title = format!(
+            "◆ 記帳量ヒートマップ（{}{}）◆",
+            self.metric.label(),
+            if self.spikes_only {
+                "・急増のみ"
+            } else {
+                ""
+            }
+        );
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Plain)
+            .border_style(Style::default().fg(Color::Cyan))
+            .title(title);
+
+        let message = if self.loading {
+            Some(Span::styled("集計中...", Style::default().fg(Color::Yellow)))
+        } else {
+            self.error
+                .as_deref()
+                .map(|error| Span::styled(error.to_string(), Style::default().fg(Color::Red)))
+        };
+        if let Some(message) = message {
+            frame.render_widget(Paragraph::new(Line::from(message)).block(block), area);
+            return;
+        }
+
+        // 列見出しは月のみ（MM）、年の切替わりは1月の列で判別できる
+        let header = Row::new(
+            std::iter::once(Cell::from("科目"))
+                .chain(self.periods.iter().map(|period| {
+                    Cell::from(period.get(5..).unwrap_or(period.as_str()).to_string())
+                }))
+                .chain(std::iter::once(Cell::from("合計"))),
+        )
+        .style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD));
+
+        let metric = self.metric;
+        let rows: Vec<Row> = self
+            .visible_rows()
+            .into_iter()
+            .map(|row| {
+                let total = match metric {
+                    ActivityMetric::PostingCount => row.total_count.to_string(),
+                    ActivityMetric::Amount if row.amount_masked => AmountMask::MASKED.to_string(),
+                    ActivityMetric::Amount => compact_amount(row.total_amount),
+                };
+                Row::new(
+                    std::iter::once(Cell::from(format!(
+                        "{} {}",
+                        row.account_code, row.account_name
+                    )))
+                    .chain(row.cells.iter().map(|cell| heat_cell(cell, metric, row.amount_masked)))
+                    .chain(std::iter::once(Cell::from(total))),
+                )
+            })
+            .collect();
+
+        let widths: Vec<Constraint> = std::iter::once(Constraint::Length(20))
+            .chain(self.periods.iter().map(|_| Constraint::Length(6)))
+            .chain(std::iter::once(Constraint::Length(8)))
+            .collect();
+
+        let table = Table::new(rows, widths)
+            .header(header)
+            .column_spacing(1)
+            .row_highlight_style(Style::default().add_modifier(Modifier::BOLD | Modifier::REVERSED))
+            .block(block);
+
+        frame.render_stateful_widget(table, area, &mut self.table_state);
+    }
+
+    fn render_status_bar(&self, frame: &mut Frame, area: Rect, period_label: &str) {
+        let cursor = if self.animation_frame < 30 {
+            "▮"
+        } else {
+            " "
+        };
+
+        let status_text = vec![Line::from(vec![
+            Span::styled(" [↑↓] ", Style::default().fg(Color::DarkGray)),
+            Span::styled("選択", Style::default().fg(Color::Gray)),
+            Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+            Span::styled("[h/l] ", Style::default().fg(Color::DarkGray)),
+            Span::styled(format!("最終月: {}", period_label), Style::default().fg(Color::Gray)),
+            Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+            Span::styled("[m] ", Style::default().fg(Color::DarkGray)),
+            Span::styled(
+                format!("表示: {}", self.metric.label()),
+                Style::default().fg(Color::Gray),
+            ),
+            Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+            Span::styled("[s] ", Style::default().fg(Color::DarkGray)),
+            Span::styled("急増のみ", Style::default().fg(Color::Gray)),
+            Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+            Span::styled("[r] ", Style::default().fg(Color::DarkGray)),
+            Span::styled("再集計", Style::default().fg(Color::Gray)),
+            Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+            Span::styled("[Esc] ", Style::default().fg(Color::DarkGray)),
+            Span::styled("戻る", Style::default().fg(Color::Gray)),
+            Span::styled(
+                format!(" {}", cursor),
+                Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+            ),
+        ])];
+
+        let paragraph = Paragraph::new(status_text).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Plain)
+                .border_style(Style::default().fg(Color::DarkGray)),
+        );
+
+        frame.render_widget(paragraph, area);
+    }
+}
+
+/// 濃度を背景色で表したセル（急増月は赤字で強調、マスキングした金額は濃度を表示しない）
+fn heat_cell(
+    cell: &AccountActivityHeatCell,
+    metric: ActivityMetric,
+    amount_masked: bool,
+) -> Cell<'static> {
+    if metric == ActivityMetric::Amount && amount_masked && cell.posting_count > 0 {
+        return Cell::from(format!("{:>5}", AmountMask::MASKED))
+            .style(Style::default().fg(Color::DarkGray));
+    }
+    let (text, level) = match metric {
+        ActivityMetric::PostingCount => (cell.posting_count.to_string(), cell.count_level),
+        ActivityMetric::Amount => (compact_amount(cell.amount), cell.amount_level),
+    };
+    if level == 0 {
+        return Cell::from("·").style(Style::default().fg(Color::DarkGray));
+    }
+    let mut style = Style::default().bg(HEAT_COLORS[usize::from(level).min(HEAT_COLORS.len() - 1)]);
+    style = if cell.spike {
+        style.fg(Color::LightRed).add_modifier(Modifier::BOLD)
+    } else {
+        style.fg(Color::White)
+    };
+    Cell::from(format!("{:>5}", text)).style(style)
+}
+
+/// セル幅に収まる金額表記（万・億単位）
+fn compact_amount(amount: f64) -> String {
+    let amount = amount.abs();
+    if amount >= 100_000_000.0 {
+        format!("{:.1}億", amount / 100_000_000.0)
+    } else if amount >= 10_000.0 {
+        format!("{:.0}万", amount / 10_000.0)
+    } else {
+        format!("{:.0}", amount)
+    }
+}
+
+impl Default for AccountActivityPage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cell(posting_count: u32, spike: bool) -> AccountActivityHeatCell {
+        AccountActivityHeatCell {
+            posting_count,
+            amount: f64::from(posting_count) * 10_000.0,
+            count_level: posting_count.min(4) as u8,
+            amount_level: posting_count.min(4) as u8,
+            spike,
+        }
+    }
+
+    fn row(account_code: &str, cells: Vec<AccountActivityHeatCell>) -> AccountActivityHeatRow {
+        AccountActivityHeatRow {
+            account_code: account_code.to_string(),
+            account_name: format!("勘定科目{}", account_code),
+            total_count: cells.iter().map(|cell| cell.posting_count).sum(),
+            total_amount: cells.iter().map(|cell| cell.amount).sum(),
+            cells,
+            amount_masked: false,
+        }
+    }
+
+    #[test]
+    fn test_spikes_only_filters_rows_and_amount_is_compact() {
+        let mut page = AccountActivityPage::new();
+        page.set_result(AnalyzeAccountActivityResponse {
+            periods: vec!["2024-02".to_string(), "2024-03".to_string()],
+            rows: vec![
+                row("1100", vec![cell(1, false), cell(1, false)]),
+                row("5000", vec![cell(1, false), cell(6, true)]),
+            ],
+        });
+        assert_eq!(page.visible_rows().len(), 2);
+
+        page.toggle_spikes_only();
+        let visible = page.visible_rows();
+        assert_eq!(visible.len(), 1);
+        assert_eq!(visible[0].account_code, "5000");
+        assert_eq!(page.table_state.selected(), Some(0));
+
+        page.toggle_metric();
+        assert_eq!(page.metric(), ActivityMetric::Amount);
+        assert_eq!(compact_amount(60_000.0), "6万");
+        assert_eq!(compact_amount(250_000_000.0), "2.5億");
+        assert_eq!(compact_amount(800.0), "800");
+    }
+
+    #[test]
+    fn test_masked_rows_hide_amounts() {
+        let mut response = AnalyzeAccountActivityResponse {
+            periods: vec!["2024-03".to_string()],
+            rows: vec![row("1100", vec![cell(2, false)]), row("5000", vec![cell(3, true)])],
+        };
+        response.mask_amounts(&AmountMask {
+            masked_ranges: vec![javelin_application::dtos::response::MaskedAccountRange {
+                account_from: "5000".to_string(),
+                account_to: "5999".to_string(),
+            }],
+        });
+
+        let masked = &response.rows[1];
+        assert!(masked.amount_masked);
+        assert_eq!(masked.total_amount, 0.0);
+        assert_eq!(masked.cells[0].amount, 0.0);
+        assert_eq!(masked.cells[0].amount_level, 0);
+        // 件数は参照できる
+        assert_eq!(masked.total_count, 3);
+        assert!(!response.rows[0].amount_masked);
+        assert_eq!(response.rows[0].total_amount, 20_000.0);
+    }
+}
@@ -1,10 +1,14 @@
 // AccountAdjustmentExecutionPage - 勘定補正実行画面
 // 責務: 勘定補正処理の実行とプログレス表示
 
+use javelin_application::dtos::response::AdjustAccountsResponse;
 use ratatui::Frame;
 
 use crate::views::layouts::templates::{BatchExecutionTemplate, ProcessStep, ProcessStepStatus};
 
+/// 処理のステップ数
+const STEP_COUNT: usize = 5;
+
 pub struct AccountAdjustmentExecutionPage {
     template: BatchExecutionTemplate,
 }
@@ -24,17 +28,54 @@ impl AccountAdjustmentExecutionPage {
 
         template.set_steps(steps);
         template.add_info("勘定補正処理画面を開きました");
+        template.add_info("仕訳を起票せずに結果を確認するには [t] で試算してください");
         template.add_info("処理を開始するには [s] キーを押してください");
+        template.add_info("対象月は [h/l] で変更できます");
 
         Self { template }
     }
 
-    /// 処理を開始
-    pub fn start_execution(&mut self) {
-        self.template.add_info("勘定補正処理を開始します...");
+    /// 処理を開始（試算の場合は仕訳・イベントを記録しない）
+    pub fn start_execution(&mut self, period_label: &str, dry_run: bool) {
+        let mode = if dry_run { "試算" } else { "実行" };
+        self.template
+            .add_info(format!("{} の勘定補正処理を{}します...", period_label, mode));
+        for index in 0..STEP_COUNT {
+            self.template.update_step(index, ProcessStepStatus::Waiting, 0);
+        }
         self.template.update_step(0, ProcessStepStatus::Running, 0);
     }
 
+    /// 対象月の変更を表示
+    pub fn set_period(&mut self, period_label: &str) {
+        self.template.add_info(format!("対象月: {}", period_label));
+    }
+
+    /// 処理結果と試算表への影響を表示
+    pub fn set_result(&mut self, response: &AdjustAccountsResponse) {
+        for index in 0..STEP_COUNT {
+            self.template.update_step(index, ProcessStepStatus::Completed, 100);
+        }
+        self.template.add_info(format!(
+            "補正仕訳 {} 件 / 科目振替 {} 件 / 税効果調整 {} 件",
+            response.entries.len(),
+            response.reclassified_accounts.len(),
+            response.tax_effect_adjustments.len()
+        ));
+        self.template
+            .add_closing_entries(&response.entries, &response.trial_balance_impact);
+        if response.dry_run {
+            self.template
+                .add_info("試算のため記録していません。確定する場合は [s] で実行してください");
+        }
+    }
+
+    /// 処理の失敗を表示
+    pub fn set_execution_error(&mut self, error: String) {
+        self.template.update_step(0, ProcessStepStatus::Error(error.clone()), 0);
+        self.template.add_error(format!("勘定補正処理に失敗しました: {}", error));
+    }
+
     /// ステップの状態を更新
     pub fn update_step(&mut self, index: usize, status: ProcessStepStatus, progress: u8) {
         self.template.update_step(index, status, progress);
@@ -1,18 +1,51 @@
 // AccountAdjustmentPage - 勘定補正実行履歴画面
-// 責務: 勘定補正処理の実行履歴表示
+// 責務: 勘定補正処理の実行履歴表示、仮勘定の滞留状況と整理仕訳の提案の表示、
+//       期末時点で未決済の計上・前払（仕訳間の紐付けから集計）の表示
 
-use ratatui::Frame;
+use javelin_application::dtos::response::UnsettledLinksResponse;
+use ratatui::{
+    Frame,
+    layout::{Constraint, Layout},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState},
+};
 
-use crate::views::layouts::templates::{BatchHistoryItem, BatchHistoryTemplate};
+use crate::{
+    format_amount,
+    presenter::{SuspenseAgingItemViewModel, SuspenseAgingViewModel},
+    views::layouts::templates::{BatchHistoryItem, BatchHistoryTemplate},
+};
+
+#[derive(Debug, Clone, PartialEq)]
+enum LoadingState {
+    Loading,
+    Loaded,
+    Error(String),
+}
+
+/// 仮勘定の滞留状況ビュー
+struct SuspenseAgingView {
+    view_model: SuspenseAgingViewModel,
+    table_state: TableState,
+    loading_state: LoadingState,
+    status_message: Option<(String, Color)>,
+}
 
 pub struct AccountAdjustmentPage {
     template: BatchHistoryTemplate,
+    /// 仮勘定の滞留状況を表示中の場合はSome
+    aging: Option<SuspenseAgingView>,
+    /// 未決済の計上・前払を表示中の場合はSome（読み込み中はSome(Loading)）
+    unsettled: Option<(LoadingState, Option<UnsettledLinksResponse>)>,
 }
 
 impl AccountAdjustmentPage {
     pub fn new() -> Self {
-        let template = BatchHistoryTemplate::new("勘定補正処理 - 実行履歴");
-        Self { template }
+        let mut template = BatchHistoryTemplate::new("勘定補正処理 - 実行履歴");
+        template.add_info("[a] 仮勘定の滞留状況・整理提案を表示");
+        template.add_info("[u] 期末時点で未決済の計上・前払を表示");
+        Self { template, aging: None, unsettled: None }
     }
 
     pub fn set_history(&mut self, history: Vec<BatchHistoryItem>) {
@@ -35,12 +68,110 @@ impl AccountAdjustmentPage {
         self.template.add_error(message);
     }
 
+    /// 仮勘定の滞留状況を表示中か
+    pub fn is_showing_aging(&self) -> bool {
+        self.aging.is_some()
+    }
+
+    /// 仮勘定の滞留状況の表示を開始（読み込み中）
+    pub fn show_aging(&mut self) {
+        self.aging = Some(SuspenseAgingView {
+            view_model: SuspenseAgingViewModel::default(),
+            table_state: TableState::default(),
+            loading_state: LoadingState::Loading,
+            status_message: None,
+        });
+    }
+
+    /// 実行履歴の表示に戻る
+    pub fn hide_aging(&mut self) {
+        self.aging = None;
+    }
+
+    /// 未決済の計上・前払を表示中か
+    pub fn is_showing_unsettled(&self) -> bool {
+        self.unsettled.is_some()
+    }
+
+    /// 未決済の計上・前払の表示を開始（読み込み中）
+    pub fn show_unsettled(&mut self) {
+        self.unsettled = Some((LoadingState::Loading, None));
+    }
+
+    /// 実行履歴の表示に戻る
+    pub fn hide_unsettled(&mut self) {
+        self.unsettled = None;
+    }
+
+    pub fn set_unsettled(&mut self, response: UnsettledLinksResponse) {
+        if self.unsettled.is_some() {
+            self.unsettled = Some((LoadingState::Loaded, Some(response)));
+        }
+    }
+
+    pub fn set_unsettled_error(&mut self, error: String) {
+        if let Some((loading_state, _)) = &mut self.unsettled {
+            *loading_state = LoadingState::Error(error);
+        }
+    }
+
+    /// 滞留状況を設定（選択位置は可能な限り維持）
+    pub fn set_aging(&mut self, view_model: SuspenseAgingViewModel) {
+        let Some(aging) = &mut self.aging else {
+            return;
+        };
+        let selected = if view_model.items.is_empty() {
+            None
+        } else {
+            Some(aging.table_state.selected().unwrap_or(0).min(view_model.items.len() - 1))
+        };
+        aging.table_state.select(selected);
+        aging.view_model = view_model;
+        aging.loading_state = LoadingState::Loaded;
+    }
+
+    pub fn set_aging_error(&mut self, error: String) {
+        if let Some(aging) = &mut self.aging {
+            aging.loading_state = LoadingState::Error(error);
+        }
+    }
+
+    pub fn set_aging_status(&mut self, message: impl Into<String>, is_error: bool) {
+        if let Some(aging) = &mut self.aging {
+            let color = if is_error { Color::Red } else { Color::Green };
+            aging.status_message = Some((message.into(), color));
+        }
+    }
+
+    /// 選択中の仮勘定計上
+    pub fn selected_aging_item(&self) -> Option<&SuspenseAgingItemViewModel> {
+        let aging = self.aging.as_ref()?;
+        aging.table_state.selected().and_then(|index| aging.view_model.items.get(index))
+    }
+
     pub fn select_next(&mut self) {
-        self.template.select_next();
+        match &mut self.aging {
+            Some(aging) => {
+                let len = aging.view_model.items.len();
+                if len > 0 {
+                    let next = aging.table_state.selected().map_or(0, |i| (i + 1).min(len - 1));
+                    aging.table_state.select(Some(next));
+                }
+            }
+            None => self.template.select_next(),
+        }
     }
 
     pub fn select_previous(&mut self) {
-        self.template.select_previous();
+        match &mut self.aging {
+            Some(aging) => {
+                if !aging.view_model.items.is_empty() {
+                    let previous = aging.table_state.selected().map_or(0, |i| i.saturating_sub(1));
+                    aging.table_state.select(Some(previous));
+                }
+            }
+            None => self.template.select_previous(),
+        }
     }
 
     pub fn tick(&mut self) {
@@ -48,7 +179,184 @@ impl AccountAdjustmentPage {
     }
 
     pub fn render(&mut self, frame: &mut Frame) {
-        self.template.render(frame);
+        if let Some((loading_state, response)) = &self.unsettled {
+            Self::render_unsettled(loading_state, response.as_ref(), frame);
+            return;
+        }
+        match &mut self.aging {
+            Some(aging) => Self::render_aging(aging, frame),
+            None => self.template.render(frame),
+        }
+    }
+
+    fn render_unsettled(
+        loading_state: &LoadingState,
+        response: Option<&UnsettledLinksResponse>,
+        frame: &mut Frame,
+    ) {
+        let area = frame.area();
+
+        let response = match (loading_state, response) {
+            (LoadingState::Error(error), _) => {
+                let error_widget = Paragraph::new(error.as_str())
+                    .style(Style::default().fg(Color::Red))
+                    .block(Block::default().borders(Borders::ALL).title("エラー"));
+                frame.render_widget(error_widget, area);
+                return;
+            }
+            (LoadingState::Loaded, Some(response)) => response,
+            _ => {
+                let loading = Paragraph::new("読み込み中...")
+                    .block(Block::default().borders(Borders::ALL).title("未決済の計上・前払"));
+                frame.render_widget(loading, area);
+                return;
+            }
+        };
+
+        let chunks = Layout::vertical([Constraint::Min(0), Constraint::Length(3)]).split(area);
+
+        let header = Row::new(vec!["取引日", "仕訳ID", "種類", "計上額", "決済済", "未決済残高"])
+            .style(Style::default().add_modifier(Modifier::BOLD));
+        let rows: Vec<Row> = response
+            .items
+            .iter()
+            .map(|item| {
+                Row::new(vec![
+                    Cell::from(item.transaction_date.as_str()),
+                    Cell::from(item.entry_id.as_str()),
+                    Cell::from(item.kind_name.as_str()),
+                    Cell::from(Line::from(format_amount!(item.amount)).right_aligned()),
+                    Cell::from(Line::from(format_amount!(item.settled_amount)).right_aligned()),
+                    Cell::from(Line::from(format_amount!(item.outstanding_amount)).right_aligned())
+                        .style(Style::default().fg(Color::Yellow)),
+                ])
+            })
+            .collect();
+
+        let title = format!(
+            "未決済の計上・前払（{}時点 {}件 / 残高合計 {}）",
+            response.period_end,
+            response.items.len(),
+            format_amount!(response.total_outstanding())
+        );
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Length(10),
+                Constraint::Min(20),
+                Constraint::Length(12),
+                Constraint::Length(14),
+                Constraint::Length(14),
+                Constraint::Length(14),
+            ],
+        )
+        .header(header)
+        .block(Block::default().borders(Borders::ALL).title(title));
+        frame.render_widget(table, chunks[0]);
+
+        let status_bar = Paragraph::new("[r] 再読込 [u/Esc] 実行履歴へ")
+            .block(Block::default().borders(Borders::ALL));
+        frame.render_widget(status_bar, chunks[1]);
+    }
+
+    fn render_aging(aging: &mut SuspenseAgingView, frame: &mut Frame) {
+        let area = frame.area();
+
+        if aging.loading_state == LoadingState::Loading {
+            let loading = Paragraph::new("読み込み中...")
+                .block(Block::default().borders(Borders::ALL).title("仮勘定滞留状況"));
+            frame.render_widget(loading, area);
+            return;
+        }
+
+        if let LoadingState::Error(error) = &aging.loading_state {
+            let error_widget = Paragraph::new(error.as_str())
+                .style(Style::default().fg(Color::Red))
+                .block(Block::default().borders(Borders::ALL).title("エラー"));
+            frame.render_widget(error_widget, area);
+            return;
+        }
+
+        let chunks =
+            Layout::vertical([Constraint::Length(3), Constraint::Min(0), Constraint::Length(3)])
+                .split(area);
+
+        let view_model = &aging.view_model;
+        let summary = Paragraph::new(view_model.bucket_summary.as_str()).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!("経過日数（基準日 {}）", view_model.as_of_date)),
+        );
+        frame.render_widget(summary, chunks[0]);
+
+        let header = Row::new(vec![
+            "計上日",
+            "伝票番号",
+            "科目",
+            "貸借",
+            "金額",
+            "経過",
+            "摘要",
+            "整理提案",
+        ])
+        .style(Style::default().add_modifier(Modifier::BOLD));
+
+        let rows: Vec<Row> = view_model
+            .items
+            .iter()
+            .map(|item| {
+                let age_style = if item.overdue {
+                    Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default()
+                };
+                Row::new(vec![
+                    Cell::from(item.transaction_date.as_str()),
+                    Cell::from(item.entry_number.as_str()),
+                    Cell::from(item.account_code.as_str()),
+                    Cell::from(item.side_label.as_str()),
+                    Cell::from(Line::from(item.amount.as_str()).right_aligned()),
+                    Cell::from(format!("{}日", item.age_days)).style(age_style),
+                    Cell::from(item.description.as_str()),
+                    Cell::from(item.suggestion.as_deref().unwrap_or("-"))
+                        .style(Style::default().fg(Color::Cyan)),
+                ])
+            })
+            .collect();
+
+        let title = format!(
+            "仮勘定滞留状況 ({}件 / 整理提案 {}件)",
+            view_model.items.len(),
+            view_model.suggestion_count
+        );
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Length(10),
+                Constraint::Length(18),
+                Constraint::Length(6),
+                Constraint::Length(4),
+                Constraint::Length(14),
+                Constraint::Length(6),
+                Constraint::Min(12),
+                Constraint::Length(32),
+            ],
+        )
+        .header(header)
+        .row_highlight_style(Style::default().bg(Color::DarkGray).add_modifier(Modifier::BOLD))
+        .block(Block::default().borders(Borders::ALL).title(title));
+
+        frame.render_stateful_widget(table, chunks[1], &mut aging.table_state);
+
+        let mut status =
+            vec![Span::raw("[↑↓] 選択 [Enter] 整理仕訳を起票 [r] 再読込 [a/Esc] 実行履歴へ")];
+        if let Some((message, color)) = &aging.status_message {
+            status.push(Span::styled(format!("  {}", message), Style::default().fg(*color)));
+        }
+        let status_bar =
+            Paragraph::new(Line::from(status)).block(Block::default().borders(Borders::ALL));
+
+        frame.render_widget(status_bar, chunks[2]);
     }
 }
 
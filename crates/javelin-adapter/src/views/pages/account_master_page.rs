@@ -4,10 +4,11 @@ use ratatui::{
     Frame,
     layout::{Constraint, Layout},
     style::{Color, Modifier, Style},
+    text::{Line, Span},
     widgets::{Block, Borders, Cell, Paragraph, Row, Table},
 };
 
-use crate::presenter::AccountMasterItemViewModel;
+use crate::{presenter::AccountMasterItemViewModel, views::components::MasterChangeHistoryOverlay};
 
 #[derive(Debug, Clone, PartialEq)]
 enum LoadingState {
@@ -22,6 +23,9 @@ pub struct AccountMasterPage {
     items_per_page: usize,
     selected_index: usize,
     loading_state: LoadingState,
+    status_message: Option<String>,
+    /// 選択中の勘定科目の変更履歴
+    history: MasterChangeHistoryOverlay,
 }
 
 impl AccountMasterPage {
@@ -32,6 +36,8 @@ impl AccountMasterPage {
             items_per_page: 10,
             selected_index: 0,
             loading_state: LoadingState::Loading,
+            status_message: None,
+            history: MasterChangeHistoryOverlay::new(),
         }
     }
 
@@ -55,6 +61,19 @@ impl AccountMasterPage {
         self.loading_state = LoadingState::Error(error);
     }
 
+    pub fn set_status_message(&mut self, message: impl Into<String>) {
+        self.status_message = Some(message.into());
+    }
+
+    pub fn history_mut(&mut self) -> &mut MasterChangeHistoryOverlay {
+        &mut self.history
+    }
+
+    /// 選択中の勘定科目
+    pub fn selected_item(&self) -> Option<&AccountMasterItemViewModel> {
+        self.current_page_items().get(self.selected_index)
+    }
+
     pub fn total_items(&self) -> usize {
         self.accounts.len()
     }
@@ -77,7 +96,8 @@ impl AccountMasterPage {
         }
     }
 
-    pub fn render(&mut self, frame: &mut Frame) {
+    /// 描画（`input` は名称変更中の入力値）
+    pub fn render(&mut self, frame: &mut Frame, input: Option<&str>) {
         let area = frame.area();
 
         if self.loading_state == LoadingState::Loading {
@@ -98,7 +118,7 @@ impl AccountMasterPage {
         let chunks = Layout::vertical([Constraint::Min(3), Constraint::Length(3)]).split(area);
 
         // テーブル
-        let header = Row::new(vec!["コード", "名称", "種別"])
+        let header = Row::new(vec!["コード", "名称", "種別", "状態"])
             .style(Style::default().add_modifier(Modifier::BOLD));
 
         let rows: Vec<Row> = self
@@ -113,36 +133,65 @@ impl AccountMasterPage {
                 };
 
                 let type_str = &account.account_type_label;
+                let status_style = if account.is_active {
+                    Style::default().fg(Color::Green)
+                } else {
+                    Style::default().fg(Color::DarkGray)
+                };
 
                 Row::new(vec![
                     Cell::from(account.code.as_str()),
                     Cell::from(account.name.as_str()),
                     Cell::from(type_str.as_str()),
+                    Cell::from(account.status_label.as_str()).style(status_style),
                 ])
                 .style(style)
             })
             .collect();
 
-        let table =
-            Table::new(rows, [Constraint::Length(10), Constraint::Min(20), Constraint::Length(10)])
-                .header(header)
-                .block(
-                    Block::default()
-                        .borders(Borders::ALL)
-                        .title(format!("勘定科目マスタ ({}件)", self.accounts.len())),
-                );
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Length(10),
+                Constraint::Min(20),
+                Constraint::Length(10),
+                Constraint::Length(6),
+            ],
+        )
+        .header(header)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!("勘定科目マスタ ({}件)", self.accounts.len())),
+        );
 
         frame.render_widget(table, chunks[0]);
 
-        // ページング情報
-        let page_info = Paragraph::new(format!(
-            "ページ {}/{} | [↑↓] 選択 [←→] ページ [Esc] 戻る",
-            self.current_page + 1,
-            self.total_pages()
-        ))
-        .block(Block::default().borders(Borders::ALL));
+        // ページング情報（名称変更中は入力欄）
+        let page_info = if let Some(value) = input {
+            Paragraph::new(Line::from(vec![
+                Span::styled("科目名: ", Style::default().fg(Color::Yellow)),
+                Span::raw(value),
+                Span::styled("▮", Style::default().fg(Color::Yellow)),
+            ]))
+            .block(Block::default().borders(Borders::ALL).title("[Enter] 保存 [Esc] キャンセル"))
+        } else {
+            let mut status = vec![Span::raw(format!(
+                "ページ {}/{} | [↑↓] 選択 [←→] ページ [e] 名称変更 [t] 有効/無効 [H] 変更履歴 [r] 再読込 [Esc] 戻る",
+                self.current_page + 1,
+                self.total_pages()
+            ))];
+            if let Some(message) = &self.status_message {
+                status.push(Span::styled(
+                    format!("  {}", message),
+                    Style::default().fg(Color::Green),
+                ));
+            }
+            Paragraph::new(Line::from(status)).block(Block::default().borders(Borders::ALL))
+        };
 
         frame.render_widget(page_info, chunks[1]);
+        self.history.render(frame, area);
     }
 }
 
@@ -0,0 +1,318 @@
+// AccountReconciliationPage - 勘定照合ワークスペース画面
+// 責務: 貸借対照表科目の元帳残高と裏付け明細の照合状況の一覧と、選択科目の明細表示
+
+use javelin_application::dtos::response::AccountReconciliationResponse;
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph},
+};
+
+use crate::{
+    format_balance,
+    views::components::{DataTable, EventViewer, InfoPanel},
+};
+
+pub struct AccountReconciliationPage {
+    reconciliation_table: DataTable,
+    info_panel: InfoPanel,
+    event_viewer: EventViewer,
+    reconciliations: Vec<AccountReconciliationResponse>,
+    animation_frame: usize,
+}
+
+impl AccountReconciliationPage {
+    pub fn new() -> Self {
+        let headers = vec![
+            "必須".to_string(),
+            "科目".to_string(),
+            "元帳残高".to_string(),
+            "明細合計".to_string(),
+            "差額".to_string(),
+            "状況".to_string(),
+        ];
+
+        let reconciliation_table =
+            DataTable::new("◆ 勘定照合 ◆", headers).with_column_widths(vec![4, 22, 16, 16, 14, 10]);
+
+        let mut event_viewer = EventViewer::new();
+        event_viewer.add_info("元帳残高を裏付ける明細を登録し、一致したら照合済にします");
+        event_viewer.add_info("照合必須の科目は、照合済でないと締日固定できません");
+
+        Self {
+            reconciliation_table,
+            info_panel: InfoPanel::new("◇ 裏付け明細 ◇").with_border_color(Color::Cyan),
+            event_viewer,
+            reconciliations: Vec::new(),
+            animation_frame: 0,
+        }
+    }
+
+    /// 照合状況を表示（選択中の科目はできるだけ維持する）
+    pub fn set_reconciliations(&mut self, reconciliations: Vec<AccountReconciliationResponse>) {
+        let selected_code = self.selected().map(|r| r.account_code.clone());
+
+        let rows = reconciliations
+            .iter()
+            .map(|reconciliation| {
+                vec![
+                    if reconciliation.required { "★" } else { "" }.to_string(),
+                    format!("{} {}", reconciliation.account_code, reconciliation.account_name),
+                    format_balance!(reconciliation.gl_balance, 14),
+                    format_balance!(reconciliation.supported_total, 14),
+                    format_balance!(reconciliation.difference, 12),
+                    status_label(reconciliation).to_string(),
+                ]
+            })
+            .collect();
+        self.reconciliation_table.set_data(rows);
+
+        let target = selected_code
+            .and_then(|code| {
+                reconciliations
+                    .iter()
+                    .position(|reconciliation| reconciliation.account_code == code)
+            })
+            .unwrap_or(0);
+        for _ in 0..reconciliations.len() {
+            if self.reconciliation_table.selected_index() == Some(target) {
+                break;
+            }
+            self.reconciliation_table.select_next();
+        }
+
+        let outstanding = reconciliations
+            .iter()
+            .filter(|r| r.required && status_label(r) != RECONCILED_LABEL)
+            .count();
+        if outstanding > 0 {
+            self.event_viewer
+                .add_error(format!("照合必須の科目のうち {} 件が未完了です", outstanding));
+        }
+        self.reconciliations = reconciliations;
+        self.update_detail();
+    }
+
+    /// 選択中の科目
+    pub fn selected(&self) -> Option<&AccountReconciliationResponse> {
+        self.reconciliation_table
+            .selected_index()
+            .and_then(|index| self.reconciliations.get(index))
+    }
+
+    pub fn set_error(&mut self, error: String) {
+        self.reconciliations.clear();
+        self.reconciliation_table.set_error(error.clone());
+        self.info_panel.clear();
+        self.event_viewer.add_error(error);
+    }
+
+    pub fn start_loading(&mut self) {
+        self.reconciliation_table.start_loading();
+    }
+
+    pub fn add_info(&mut self, message: impl Into<String>) {
+        self.event_viewer.add_info(message);
+    }
+
+    pub fn add_error(&mut self, message: impl Into<String>) {
+        self.event_viewer.add_error(message);
+    }
+
+    pub fn select_next(&mut self) {
+        self.reconciliation_table.select_next();
+        self.update_detail();
+    }
+
+    pub fn select_previous(&mut self) {
+        self.reconciliation_table.select_previous();
+        self.update_detail();
+    }
+
+    /// 選択科目の裏付け明細を表示
+    fn update_detail(&mut self) {
+        self.info_panel.clear();
+        let Some(reconciliation) = self.selected().cloned() else {
+            self.info_panel.add_text("照合対象の科目はありません");
+            return;
+        };
+
+        self.info_panel.add_line(
+            "科目",
+            format!("{} {}", reconciliation.account_code, reconciliation.account_name),
+        );
+        self.info_panel.add_line("元帳残高", format_balance!(reconciliation.gl_balance));
+        if reconciliation.items.is_empty() {
+            self.info_panel.add_text("裏付け明細は未登録です");
+        }
+        for (index, item) in reconciliation.items.iter().enumerate() {
+            let reference = item
+                .reference
+                .as_deref()
+                .map(|reference| format!(" [{}]", reference))
+                .unwrap_or_default();
+            self.info_panel.add_line(
+                format!("{}. {}", index + 1, item.description),
+                format!("{}{}", format_balance!(item.amount), reference),
+            );
+        }
+        self.info_panel.add_line("差額", format_balance!(reconciliation.difference));
+        if let (Some(by), Some(at)) = (&reconciliation.reconciled_by, &reconciliation.reconciled_at)
+        {
+            self.info_panel.add_line("照合", format!("{} ({})", by, at));
+            if !reconciliation.current {
+                self.info_panel
+                    .add_warning("照合後に元帳残高が変わっています。再照合してください");
+            }
+        }
+    }
+
+    pub fn tick(&mut self) {
+        self.animation_frame = (self.animation_frame + 1) % 60;
+        self.reconciliation_table.tick_loading();
+    }
+
+    /// 描画（明細入力中は入力欄を表示する）
+    pub fn render(&mut self, frame: &mut Frame, period_label: &str, item_input: Option<&str>) {
+        let area = frame.area();
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(10), Constraint::Length(3)])
+            .split(area);
+
+        let main_chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(62), Constraint::Percentage(38)])
+            .split(chunks[0]);
+
+        let side_chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(10), Constraint::Length(8)])
+            .split(main_chunks[1]);
+
+        self.reconciliation_table.render(frame, main_chunks[0]);
+        self.info_panel.render(frame, side_chunks[0]);
+        self.event_viewer.render(frame, side_chunks[1]);
+        match item_input {
+            Some(input) => self.render_item_input(frame, chunks[1], input),
+            None => self.render_status_bar(frame, chunks[1], period_label),
+        }
+    }
+
+    fn render_item_input(&self, frame: &mut Frame, area: Rect, input: &str) {
+        let cursor = if self.animation_frame < 30 {
+            "▮"
+        } else {
+            " "
+        };
+
+        let line = Line::from(vec![
+            Span::styled(" 内容,金額[,証憑]: ", Style::default().fg(Color::Cyan)),
+            Span::styled(input.to_string(), Style::default().fg(Color::White)),
+            Span::styled(cursor, Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+            Span::styled("  [Enter] 追加 [Esc] 取消", Style::default().fg(Color::DarkGray)),
+        ]);
+
+        let paragraph = Paragraph::new(line).block(
+            Block::default()
+                .title("裏付け明細の追加")
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(Color::Cyan)),
+        );
+
+        frame.render_widget(paragraph, area);
+    }
+
+    fn render_status_bar(&self, frame: &mut Frame, area: Rect, period_label: &str) {
+        let status_text = vec![Line::from(vec![
+            Span::styled(" [↑↓] ", Style::default().fg(Color::DarkGray)),
+            Span::styled("選択", Style::default().fg(Color::Gray)),
+            Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+            Span::styled("[←→] ", Style::default().fg(Color::DarkGray)),
+            Span::styled(format!("対象月: {}", period_label), Style::default().fg(Color::Gray)),
+            Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+            Span::styled("[a] ", Style::default().fg(Color::DarkGray)),
+            Span::styled("明細追加", Style::default().fg(Color::Gray)),
+            Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+            Span::styled("[d] ", Style::default().fg(Color::DarkGray)),
+            Span::styled("末尾の明細削除", Style::default().fg(Color::Gray)),
+            Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+            Span::styled("[m] ", Style::default().fg(Color::DarkGray)),
+            Span::styled("照合済", Style::default().fg(Color::Gray)),
+            Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+            Span::styled("[u] ", Style::default().fg(Color::DarkGray)),
+            Span::styled("照合取消", Style::default().fg(Color::Gray)),
+            Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+            Span::styled("[c] ", Style::default().fg(Color::DarkGray)),
+            Span::styled("必須切替", Style::default().fg(Color::Gray)),
+            Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+            Span::styled("[Esc] ", Style::default().fg(Color::DarkGray)),
+            Span::styled("戻る", Style::default().fg(Color::Gray)),
+        ])];
+
+        let paragraph = Paragraph::new(status_text).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Plain)
+                .border_style(Style::default().fg(Color::DarkGray)),
+        );
+
+        frame.render_widget(paragraph, area);
+    }
+}
+
+impl Default for AccountReconciliationPage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// 照合済で元帳残高が変わっていない状態の表示
+const RECONCILED_LABEL: &str = "照合済";
+
+/// 照合状況の表示
+fn status_label(reconciliation: &AccountReconciliationResponse) -> &'static str {
+    match (reconciliation.reconciled, reconciliation.current) {
+        (true, true) => RECONCILED_LABEL,
+        (true, false) => "要再照合",
+        (false, _) if reconciliation.items.is_empty() => "未着手",
+        (false, _) => "作業中",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reconciliation(account_code: &str, required: bool) -> AccountReconciliationResponse {
+        AccountReconciliationResponse::new(
+            account_code,
+            "現金預金",
+            2024,
+            3,
+            1_000.0,
+            None,
+            required,
+        )
+    }
+
+    #[test]
+    fn test_selection_is_kept_across_reload() {
+        let mut page = AccountReconciliationPage::new();
+        page.set_reconciliations(vec![reconciliation("1100", true), reconciliation("1200", false)]);
+        page.select_next();
+        assert_eq!(page.selected().unwrap().account_code, "1200");
+
+        page.set_reconciliations(vec![
+            reconciliation("1000", false),
+            reconciliation("1100", true),
+            reconciliation("1200", true),
+        ]);
+        assert_eq!(page.selected().unwrap().account_code, "1200");
+        assert_eq!(status_label(page.selected().unwrap()), "未着手");
+    }
+}
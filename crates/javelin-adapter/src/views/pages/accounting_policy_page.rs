@@ -0,0 +1,218 @@
+// AccountingPolicyPage - 会計方針設定画面のビューコンポーネント
+// 責務: 端数処理・負数表示方法の設定一覧と変更履歴の表示
+
+use ratatui::{
+    Frame,
+    layout::{Constraint, Layout},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState},
+};
+
+use crate::presenter::{AccountingPolicyRowViewModel, AccountingPolicyViewModel};
+
+#[derive(Debug, Clone, PartialEq)]
+enum LoadingState {
+    Loading,
+    Loaded,
+    Error(String),
+}
+
+pub struct AccountingPolicyPage {
+    view_model: AccountingPolicyViewModel,
+    table_state: TableState,
+    loading_state: LoadingState,
+    status_message: Option<(String, bool)>,
+}
+
+impl AccountingPolicyPage {
+    pub fn new() -> Self {
+        Self {
+            view_model: AccountingPolicyViewModel::default(),
+            table_state: TableState::default(),
+            loading_state: LoadingState::Loading,
+            status_message: None,
+        }
+    }
+
+    /// 会計方針を設定（選択位置は可能な限り維持）
+    pub fn set_data(&mut self, view_model: AccountingPolicyViewModel) {
+        let selected = self.table_state.selected().unwrap_or(0);
+        let selected = if view_model.rows.is_empty() {
+            None
+        } else {
+            Some(selected.min(view_model.rows.len() - 1))
+        };
+        self.table_state.select(selected);
+        self.view_model = view_model;
+        self.loading_state = LoadingState::Loaded;
+    }
+
+    pub fn set_error(&mut self, error: String) {
+        self.loading_state = LoadingState::Error(error);
+    }
+
+    pub fn set_status_message(&mut self, message: impl Into<String>) {
+        self.status_message = Some((message.into(), false));
+    }
+
+    pub fn set_error_message(&mut self, message: impl Into<String>) {
+        self.status_message = Some((message.into(), true));
+    }
+
+    pub fn view_model(&self) -> &AccountingPolicyViewModel {
+        &self.view_model
+    }
+
+    /// 選択中の行インデックス
+    pub fn selected_index(&self) -> Option<usize> {
+        self.table_state.selected()
+    }
+
+    /// 選択中の設定行
+    pub fn selected_row(&self) -> Option<&AccountingPolicyRowViewModel> {
+        self.selected_index().and_then(|index| self.view_model.rows.get(index))
+    }
+
+    pub fn select_next(&mut self) {
+        let len = self.view_model.rows.len();
+        if len > 0 {
+            let next = self.table_state.selected().map_or(0, |i| (i + 1).min(len - 1));
+            self.table_state.select(Some(next));
+        }
+    }
+
+    pub fn select_previous(&mut self) {
+        if !self.view_model.rows.is_empty() {
+            let previous = self.table_state.selected().map_or(0, |i| i.saturating_sub(1));
+            self.table_state.select(Some(previous));
+        }
+    }
+
+    /// 描画（入力中は、入力する設定と入力内容をステータスバーに表示する）
+    pub fn render(&mut self, frame: &mut Frame, input: Option<(&str, &str)>) {
+        let area = frame.area();
+
+        if self.loading_state == LoadingState::Loading {
+            let loading = Paragraph::new("読み込み中...")
+                .block(Block::default().borders(Borders::ALL).title("会計方針"));
+            frame.render_widget(loading, area);
+            return;
+        }
+
+        if let LoadingState::Error(error) = &self.loading_state {
+            let error_widget = Paragraph::new(error.as_str())
+                .style(Style::default().fg(Color::Red))
+                .block(Block::default().borders(Borders::ALL).title("エラー"));
+            frame.render_widget(error_widget, area);
+            return;
+        }
+
+        let chunks = Layout::vertical([
+            Constraint::Length(self.view_model.rows.len() as u16 + 3),
+            Constraint::Min(0),
+            Constraint::Length(3),
+        ])
+        .split(area);
+
+        let header = Row::new(vec!["区分", "対象", "設定"])
+            .style(Style::default().add_modifier(Modifier::BOLD));
+        let rows: Vec<Row> = self
+            .view_model
+            .rows
+            .iter()
+            .map(|row| {
+                Row::new(vec![
+                    Cell::from(row.category.as_str()),
+                    Cell::from(row.target.as_str()),
+                    Cell::from(row.value_label.as_str()),
+                ])
+            })
+            .collect();
+
+        let title = if self.view_model.is_administrator {
+            Line::from("会計方針")
+        } else {
+            Line::from(vec![
+                Span::raw("会計方針 "),
+                Span::styled("参照のみ（管理者のみ変更可能）", Style::default().fg(Color::Yellow)),
+            ])
+        };
+
+        let table =
+            Table::new(rows, [Constraint::Length(14), Constraint::Length(12), Constraint::Min(20)])
+                .header(header)
+                .row_highlight_style(
+                    Style::default().bg(Color::DarkGray).add_modifier(Modifier::BOLD),
+                )
+                .block(Block::default().borders(Borders::ALL).title(title));
+
+        frame.render_stateful_widget(table, chunks[0], &mut self.table_state);
+
+        let change_header = Row::new(vec!["変更日時", "設定", "変更前", "変更後", "変更者"])
+            .style(Style::default().add_modifier(Modifier::BOLD));
+        let change_rows: Vec<Row> = self
+            .view_model
+            .changes
+            .iter()
+            .map(|change| {
+                Row::new(vec![
+                    Cell::from(change.changed_at.as_str()),
+                    Cell::from(change.setting.as_str()),
+                    Cell::from(change.before.as_str()),
+                    Cell::from(change.after.as_str()),
+                    Cell::from(change.changed_by.as_str()),
+                ])
+            })
+            .collect();
+        let changes = Table::new(
+            change_rows,
+            [
+                Constraint::Length(26),
+                Constraint::Length(20),
+                Constraint::Min(16),
+                Constraint::Min(16),
+                Constraint::Length(14),
+            ],
+        )
+        .header(change_header)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!("変更履歴 ({}件)", self.view_model.changes.len())),
+        );
+
+        frame.render_widget(changes, chunks[1]);
+
+        if let Some((label, input)) = input {
+            let input_widget = Paragraph::new(format!("{}▮", input))
+                .style(Style::default().fg(Color::White))
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .border_style(Style::default().fg(Color::Cyan))
+                        .title(format!("{}  [Enter] 保存 [Esc] 取消", label)),
+                );
+            frame.render_widget(input_widget, chunks[2]);
+            return;
+        }
+
+        let mut status = vec![Span::raw(
+            "[↑↓] 選択 [←→] 方法変更 [+/-] 小数桁数 [e] マスキング・ロール編集 [a] ロール追加 [u] 利用者登録 [r] 再読込 [Esc] 戻る",
+        )];
+        if let Some((message, is_error)) = &self.status_message {
+            let color = if *is_error { Color::Red } else { Color::Green };
+            status.push(Span::styled(format!("  {}", message), Style::default().fg(color)));
+        }
+        let status_bar =
+            Paragraph::new(Line::from(status)).block(Block::default().borders(Borders::ALL));
+
+        frame.render_widget(status_bar, chunks[2]);
+    }
+}
+
+impl Default for AccountingPolicyPage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
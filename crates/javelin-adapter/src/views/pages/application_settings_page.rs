@@ -2,11 +2,12 @@
 
 use ratatui::{
     Frame,
+    layout::{Constraint, Direction, Layout},
     style::{Color, Style},
     widgets::{Block, Borders, Paragraph},
 };
 
-use crate::presenter::ApplicationSettingsViewModel;
+use crate::{notification::BatchNotifier, presenter::ApplicationSettingsViewModel};
 
 #[derive(Debug, Clone, PartialEq)]
 enum LoadingState {
@@ -18,11 +19,25 @@ enum LoadingState {
 pub struct ApplicationSettingsPage {
     view_model: Option<ApplicationSettingsViewModel>,
     loading_state: LoadingState,
+    /// 設定変更の結果（エラーの場合はtrue）
+    message: Option<(String, bool)>,
 }
 
 impl ApplicationSettingsPage {
     pub fn new() -> Self {
-        Self { view_model: None, loading_state: LoadingState::Loading }
+        Self { view_model: None, loading_state: LoadingState::Loading, message: None }
+    }
+
+    pub fn view_model(&self) -> Option<&ApplicationSettingsViewModel> {
+        self.view_model.as_ref()
+    }
+
+    pub fn set_status_message(&mut self, message: impl Into<String>) {
+        self.message = Some((message.into(), false));
+    }
+
+    pub fn set_error_message(&mut self, message: impl Into<String>) {
+        self.message = Some((message.into(), true));
     }
 
     pub fn set_data(&mut self, view_model: ApplicationSettingsViewModel) {
@@ -38,7 +53,8 @@ impl ApplicationSettingsPage {
         self.loading_state = LoadingState::Error(error);
     }
 
-    pub fn render(&mut self, frame: &mut Frame) {
+    /// 描画（配信先の入力中は、帳票名と入力内容を表示する）
+    pub fn render(&mut self, frame: &mut Frame, delivery_input: Option<(&str, &str)>) {
         let area = frame.area();
 
         if self.loading_state == LoadingState::Loading {
@@ -62,25 +78,75 @@ impl ApplicationSettingsPage {
                  言語: {}\n\
                  小数点以下桁数: {}\n\
                  日付フォーマット: {}\n\
+                 バッチ完了通知（ベル）: {}\n\
+                 バッチ完了通知（デスクトップ）: {}\n\
                  会計年度開始月: {}\n\
                  締日: {}日\n\
                  自動バックアップ: {}\n\
-                 バックアップ保持日数: {}日\n\n\
-                 [Esc] 戻る",
+                 バックアップ保持日数: {}日\n\
+                 承認SLA: {}\n\
+                 試算表の配信先: {}\n\
+                 財務諸表の配信先: {}\n\
+                 出力ファイルの保護: {}\n\
+                 削除済み仕訳: {}\n\
+                 締日固定の事前検証: {}\n\n\
+                 {}[a] 承認SLA切替  [t] 試算表の配信先  [f] 財務諸表の配信先\n\
+                 [e] 暗号化切替  [s] 署名切替  [p] 削除済み仕訳の保持切替  \
+                 [1-4] 締日固定の検証項目切替  [Esc] 戻る",
                 vm.default_company_code.as_deref().unwrap_or("未設定"),
                 vm.language_label,
                 vm.decimal_places,
                 vm.date_format,
+                vm.batch_notification_bell_label,
+                vm.batch_notification_desktop_label,
                 vm.fiscal_year_start_month_label,
                 vm.closing_day,
                 vm.auto_backup_label,
-                vm.backup_retention_days
+                vm.backup_retention_days,
+                vm.approval_sla_label,
+                vm.trial_balance_delivery_label,
+                vm.financial_statements_delivery_label,
+                vm.export_protection_label,
+                vm.deleted_entry_retention_label,
+                vm.closing_lock_validation_label,
+                if BatchNotifier::desktop_supported() {
+                    "[b] ベル通知切替  [d] デスクトップ通知切替  "
+                } else {
+                    "[b] ベル通知切替  "
+                }
             );
 
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Min(5), Constraint::Length(3)])
+                .split(area);
+
             let widget = Paragraph::new(text)
                 .block(Block::default().borders(Borders::ALL).title("アプリケーション設定"));
+            frame.render_widget(widget, chunks[0]);
+
+            if let Some((report_label, input)) = delivery_input {
+                let input_widget = Paragraph::new(format!("{}▮", input))
+                    .style(Style::default().fg(Color::White))
+                    .block(
+                        Block::default()
+                            .borders(Borders::ALL)
+                            .border_style(Style::default().fg(Color::Cyan))
+                            .title(format!(
+                                "{}の配信先（file:<dir> / smtp://<host>?from=&to= / http://…、空白区切り）  [Enter] 保存 [Esc] 取消",
+                                report_label
+                            )),
+                    );
+                frame.render_widget(input_widget, chunks[1]);
+                return;
+            }
 
-            frame.render_widget(widget, area);
+            let (message, is_error) = self.message.clone().unwrap_or_default();
+            let color = if is_error { Color::Red } else { Color::Gray };
+            let message_widget = Paragraph::new(message)
+                .style(Style::default().fg(color))
+                .block(Block::default().borders(Borders::ALL));
+            frame.render_widget(message_widget, chunks[1]);
         }
     }
 }
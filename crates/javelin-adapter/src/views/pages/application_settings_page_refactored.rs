@@ -19,10 +19,18 @@ impl SettingsItem for ApplicationSettingsViewModel {
             ("言語".to_string(), self.language_label.clone()),
             ("小数点以下桁数".to_string(), self.decimal_places.to_string()),
             ("日付フォーマット".to_string(), self.date_format.clone()),
+            ("バッチ完了通知（ベル）".to_string(), self.batch_notification_bell_label.clone()),
+            (
+                "バッチ完了通知（デスクトップ）".to_string(),
+                self.batch_notification_desktop_label.clone(),
+            ),
             ("会計年度開始月".to_string(), self.fiscal_year_start_month_label.clone()),
             ("締日".to_string(), format!("{}日", self.closing_day)),
             ("自動バックアップ".to_string(), self.auto_backup_label.clone()),
             ("バックアップ保持日数".to_string(), format!("{}日", self.backup_retention_days)),
+            ("承認SLA".to_string(), self.approval_sla_label.clone()),
+            ("試算表の配信先".to_string(), self.trial_balance_delivery_label.clone()),
+            ("財務諸表の配信先".to_string(), self.financial_statements_delivery_label.clone()),
         ]
     }
 }
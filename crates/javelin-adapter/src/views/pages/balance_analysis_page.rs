@@ -0,0 +1,218 @@
+// BalanceAnalysisPage - 勘定残高の異常値レビュー画面
+// 責務: 前月・前年同月比較で抽出した異常値の一覧と選択行の詳細表示
+
+use javelin_application::dtos::{
+    request::BalanceAnomalyThresholds,
+    response::{AnalyzeBalancesResponse, BalanceAnomalyItem},
+};
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph},
+};
+
+use crate::{
+    format_balance,
+    views::components::{DataTable, EventViewer, InfoPanel},
+};
+
+pub struct BalanceAnalysisPage {
+    anomaly_table: DataTable,
+    info_panel: InfoPanel,
+    event_viewer: EventViewer,
+    anomalies: Vec<BalanceAnomalyItem>,
+    animation_frame: usize,
+}
+
+impl BalanceAnalysisPage {
+    pub fn new() -> Self {
+        let headers = vec![
+            "区分".to_string(),
+            "科目".to_string(),
+            "当月末残高".to_string(),
+            "比較残高".to_string(),
+            "増減額".to_string(),
+            "増減率".to_string(),
+        ];
+
+        let anomaly_table = DataTable::new("◆ 残高異常値レビュー ◆", headers)
+            .with_column_widths(vec![10, 22, 16, 16, 16, 9]);
+
+        let mut event_viewer = EventViewer::new();
+        event_viewer.add_info("前月・前年同月との残高比較で異常値を抽出します");
+
+        Self {
+            anomaly_table,
+            info_panel: InfoPanel::new("◇ 詳細 ◇").with_border_color(Color::Cyan),
+            event_viewer,
+            anomalies: Vec::new(),
+            animation_frame: 0,
+        }
+    }
+
+    /// 分析結果を表示
+    pub fn set_result(
+        &mut self,
+        response: AnalyzeBalancesResponse,
+        thresholds: BalanceAnomalyThresholds,
+    ) {
+        let rows = response
+            .anomalies
+            .iter()
+            .map(|item| {
+                vec![
+                    item.kind.label().to_string(),
+                    format!("{} {}", item.account_code, item.account_name),
+                    format_balance!(item.current_balance, 14),
+                    format_balance!(item.comparison_balance, 14),
+                    format_balance!(item.change_amount, 14),
+                    item.change_rate
+                        .map(|rate| format!("{:>7.1}%", rate * 100.0))
+                        .unwrap_or_else(|| "---".to_string()),
+                ]
+            })
+            .collect();
+        self.anomaly_table.set_data(rows);
+
+        self.event_viewer.add_info(format!(
+            "{}-{:02}: {}科目を分析、異常値 {}件（増減率 {:.0}%以上かつ増減額 {}以上）",
+            response.fiscal_year,
+            response.period,
+            response.analyzed_accounts,
+            response.anomalies.len(),
+            thresholds.change_rate * 100.0,
+            format_balance!(thresholds.min_change_amount)
+        ));
+        self.anomalies = response.anomalies;
+        self.update_detail();
+    }
+
+    pub fn set_error(&mut self, error: String) {
+        self.anomalies.clear();
+        self.anomaly_table.set_error(error.clone());
+        self.info_panel.clear();
+        self.event_viewer.add_error(error);
+    }
+
+    pub fn start_loading(&mut self) {
+        self.anomaly_table.start_loading();
+    }
+
+    pub fn add_info(&mut self, message: impl Into<String>) {
+        self.event_viewer.add_info(message);
+    }
+
+    pub fn add_error(&mut self, message: impl Into<String>) {
+        self.event_viewer.add_error(message);
+    }
+
+    pub fn select_next(&mut self) {
+        self.anomaly_table.select_next();
+        self.update_detail();
+    }
+
+    pub fn select_previous(&mut self) {
+        self.anomaly_table.select_previous();
+        self.update_detail();
+    }
+
+    /// 選択行の詳細を表示
+    fn update_detail(&mut self) {
+        self.info_panel.clear();
+        let Some(item) =
+            self.anomaly_table.selected_index().and_then(|index| self.anomalies.get(index))
+        else {
+            self.info_panel.add_text("異常値はありません");
+            return;
+        };
+
+        self.info_panel
+            .add_line("科目", format!("{} {}", item.account_code, item.account_name));
+        self.info_panel.add_line("区分", item.kind.label());
+        self.info_panel.add_line("当月末残高", format_balance!(item.current_balance));
+        self.info_panel.add_line("比較残高", format_balance!(item.comparison_balance));
+        self.info_panel.add_line("増減額", format_balance!(item.change_amount));
+        self.info_panel.add_warning(item.detail.as_str());
+    }
+
+    pub fn tick(&mut self) {
+        self.animation_frame = (self.animation_frame + 1) % 60;
+        self.anomaly_table.tick_loading();
+    }
+
+    pub fn render(&mut self, frame: &mut Frame, period_label: &str, threshold_label: &str) {
+        let area = frame.area();
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(10), Constraint::Length(3)])
+            .split(area);
+
+        let main_chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(65), Constraint::Percentage(35)])
+            .split(chunks[0]);
+
+        let side_chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(10), Constraint::Min(6)])
+            .split(main_chunks[1]);
+
+        self.anomaly_table.render(frame, main_chunks[0]);
+        self.info_panel.render(frame, side_chunks[0]);
+        self.event_viewer.render(frame, side_chunks[1]);
+        self.render_status_bar(frame, chunks[1], period_label, threshold_label);
+    }
+
+    fn render_status_bar(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        period_label: &str,
+        threshold_label: &str,
+    ) {
+        let cursor = if self.animation_frame < 30 {
+            "▮"
+        } else {
+            " "
+        };
+
+        let status_text = vec![Line::from(vec![
+            Span::styled(" [↑↓] ", Style::default().fg(Color::DarkGray)),
+            Span::styled("選択", Style::default().fg(Color::Gray)),
+            Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+            Span::styled("[h/l] ", Style::default().fg(Color::DarkGray)),
+            Span::styled(format!("対象月: {}", period_label), Style::default().fg(Color::Gray)),
+            Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+            Span::styled("[t] ", Style::default().fg(Color::DarkGray)),
+            Span::styled(format!("閾値: {}", threshold_label), Style::default().fg(Color::Gray)),
+            Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+            Span::styled("[r] ", Style::default().fg(Color::DarkGray)),
+            Span::styled("再分析", Style::default().fg(Color::Gray)),
+            Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+            Span::styled("[Esc] ", Style::default().fg(Color::DarkGray)),
+            Span::styled("戻る", Style::default().fg(Color::Gray)),
+            Span::styled(
+                format!(" {}", cursor),
+                Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+            ),
+        ])];
+
+        let paragraph = Paragraph::new(status_text).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Plain)
+                .border_style(Style::default().fg(Color::DarkGray)),
+        );
+
+        frame.render_widget(paragraph, area);
+    }
+}
+
+impl Default for BalanceAnalysisPage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,7 +1,12 @@
 // ClosingLockPage - 締日固定画面
-// 責務: 取引データのロック処理
+// 責務: 取引データのロック処理（事前検証と管理者による上書きを含む）と、承認付きの期間再オープン
 
-use javelin_application::dtos::LockClosingPeriodResponse;
+use javelin_application::dtos::{
+    LockClosingPeriodResponse,
+    response::{
+        ClosingLockValidationReport, ReopenClosingPeriodResponse, ReopenWindowReportResponse,
+    },
+};
 use ratatui::{
     Frame,
     layout::{Constraint, Direction, Layout, Rect},
@@ -10,80 +15,261 @@ use ratatui::{
     widgets::{Block, BorderType, Borders, Paragraph},
 };
 
-use crate::views::components::{DataTable, EventViewer, LoadingSpinner};
+use crate::{
+    clock::format_timestamp,
+    views::components::{DataTable, EventViewer},
+};
 
-#[derive(Debug, Clone, PartialEq)]
-enum LoadingState {
-    Loading,
-    Loaded,
-    Error(String),
+/// 再オープン申請の入力項目
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ReopenField {
+    /// 対象期間（YYYY-MM）
+    Period,
+    Reason,
+    Approver,
+    ApproverPassword,
+}
+
+impl ReopenField {
+    fn next(self) -> Self {
+        match self {
+            Self::Period => Self::Reason,
+            Self::Reason => Self::Approver,
+            Self::Approver => Self::ApproverPassword,
+            Self::ApproverPassword => Self::Period,
+        }
+    }
+
+    fn previous(self) -> Self {
+        match self {
+            Self::Period => Self::ApproverPassword,
+            Self::Reason => Self::Period,
+            Self::Approver => Self::Reason,
+            Self::ApproverPassword => Self::Approver,
+        }
+    }
 }
 
 pub struct ClosingLockPage {
-    lock_table: DataTable,
+    window_table: DataTable,
     event_viewer: EventViewer,
-    loading_spinner: LoadingSpinner,
-    loading_state: LoadingState,
+    period: String,
+    reason: String,
+    approver: String,
+    approver_password: String,
+    focus: ReopenField,
+    /// 表示中の再オープン期間の概要
+    window_summary: Option<String>,
+    processing: bool,
     animation_frame: usize,
 }
 
 impl ClosingLockPage {
     pub fn new() -> Self {
         let headers = vec![
-            "期間".to_string(),
-            "ロック状態".to_string(),
-            "ロック日時".to_string(),
-            "ロック件数".to_string(),
-            "監査ログID".to_string(),
+            "記録日時".to_string(),
+            "種類".to_string(),
+            "伝票番号".to_string(),
+            "取引日付".to_string(),
+            "仕訳ID".to_string(),
+            "記録者".to_string(),
         ];
 
-        let lock_table = DataTable::new("◆ 締日固定 - データロック管理 ◆", headers)
-            .with_column_widths(vec![15, 12, 20, 12, 35]);
+        let window_table = DataTable::new("◆ 再オープン期間中の記帳 ◆", headers)
+            .with_column_widths(vec![20, 6, 16, 12, 30, 12]);
 
         let mut event_viewer = EventViewer::new();
         event_viewer.add_info("締日固定画面を開きました");
-        event_viewer.add_info("データを読み込んでいます...");
+        event_viewer.add_info(
+            "締日固定前に試算表・未記帳仕訳・仮勘定・勘定照合（[F3]）を検証します（[F5] で確認）",
+        );
+        event_viewer.add_info("検証に失敗した場合、管理者は理由を入力して [F6] で上書きできます");
+        event_viewer.add_info("再オープンには理由と、申請者以外の承認者の承認が必要です");
 
         Self {
-            lock_table,
+            window_table,
             event_viewer,
-            loading_spinner: LoadingSpinner::new(),
-            loading_state: LoadingState::Loading,
+            period: String::new(),
+            reason: String::new(),
+            approver: String::new(),
+            approver_password: String::new(),
+            focus: ReopenField::Period,
+            window_summary: None,
+            processing: false,
             animation_frame: 0,
         }
     }
 
     pub fn set_response(&mut self, response: LockClosingPeriodResponse) {
-        let data = vec![vec![
-            "2024-12".to_string(),
-            "ロック済".to_string(),
-            response.locked_at.clone(),
-            response.locked_entries_count.to_string(),
-            response.audit_log_id.clone(),
-        ]];
-
-        self.lock_table.set_data(data);
-        self.loading_state = LoadingState::Loaded;
+        self.processing = false;
+        if response.overridden {
+            self.reason.clear();
+            self.event_viewer.add_error(format!(
+                "事前検証の失敗を上書きしました: {}",
+                response.validation.failure_messages().join(" / ")
+            ));
+        }
         self.event_viewer.add_info(format!(
-            "締日固定完了: {} 件のエントリをロック",
-            response.locked_entries_count
+            "締日固定完了: {} 件のエントリをロック（{}）",
+            response.locked_entries_count, response.audit_log_id
+        ));
+    }
+
+    /// 事前検証の結果を項目ごとに表示
+    pub fn set_validation_report(&mut self, report: &ClosingLockValidationReport) {
+        self.processing = false;
+        if report.checks.is_empty() {
+            self.event_viewer
+                .add_info(format!("{} の事前検証: 検証項目なし", report.period_id));
+            return;
+        }
+        for check in &report.checks {
+            if check.passed {
+                self.event_viewer.add_info(format!("✓ {}", check.name));
+            } else {
+                self.event_viewer.add_error(format!(
+                    "✗ {}: {}",
+                    check.name,
+                    check.findings.join(", ")
+                ));
+            }
+        }
+        let failed = report.failed_checks().count();
+        if failed == 0 {
+            self.event_viewer
+                .add_info(format!("{} の事前検証: すべて成功（[F4] で固定）", report.period_id));
+        } else {
+            self.event_viewer.add_error(format!(
+                "{} の事前検証: {} 項目が失敗しました",
+                report.period_id, failed
+            ));
+        }
+    }
+
+    /// 再オープンの結果を反映（承認者のパスワードは消去する）
+    pub fn set_reopen_result(&mut self, response: &ReopenClosingPeriodResponse) {
+        self.processing = false;
+        self.approver_password.clear();
+        self.event_viewer.add_info(format!(
+            "{} を再オープンしました（{} → オープン、申請: {}、承認: {}）",
+            response.period_id,
+            response.previous_status,
+            response.reopened_by,
+            response.approved_by
+        ));
+        if !response.reopened_entry_ids.is_empty() {
+            self.event_viewer.add_info(format!(
+                "締め済の仕訳 {} 件を記帳済に戻しました",
+                response.reopened_entry_ids.len()
+            ));
+        }
+    }
+
+    /// 再オープン期間中の記帳レポートを表示
+    pub fn set_window_report(&mut self, report: &ReopenWindowReportResponse) {
+        self.processing = false;
+        let until = report
+            .closed_again_at
+            .as_deref()
+            .map(format_timestamp)
+            .unwrap_or_else(|| "再オープン中".to_string());
+        self.window_summary = Some(format!(
+            "{} [{}] {} 〜 {}  理由: {}  申請: {} / 承認: {}",
+            report.period_id,
+            report.status,
+            format_timestamp(&report.reopened_at),
+            until,
+            report.reason,
+            report.reopened_by,
+            report.approved_by
+        ));
+
+        let rows = report
+            .postings
+            .iter()
+            .map(|posting| {
+                vec![
+                    format_timestamp(&posting.recorded_at),
+                    posting.kind.clone(),
+                    posting.entry_number.clone().unwrap_or_else(|| "-".to_string()),
+                    posting.transaction_date.clone(),
+                    posting.entry_id.clone(),
+                    posting.recorded_by.clone(),
+                ]
+            })
+            .collect();
+        self.window_table.set_data(rows);
+        self.event_viewer.add_info(format!(
+            "{} の再オープン期間中の記帳: {} 件",
+            report.period_id,
+            report.postings.len()
         ));
     }
 
     pub fn set_error(&mut self, error: String) {
-        self.loading_state = LoadingState::Error(error.clone());
+        self.processing = false;
         self.event_viewer.add_error(format!("エラー: {}", error));
     }
 
-    pub fn is_loading(&self) -> bool {
-        self.loading_state == LoadingState::Loading
+    pub fn is_processing(&self) -> bool {
+        self.processing
+    }
+
+    pub fn start_processing(&mut self, message: impl Into<String>) {
+        self.processing = true;
+        self.event_viewer.add_info(message);
+    }
+
+    /// 入力された対象期間（YYYY-MM）を年・月に分解
+    pub fn target_period(&self) -> Result<(u32, u8), String> {
+        let invalid = || format!("対象期間は YYYY-MM 形式で入力してください: {}", self.period);
+        let (year, month) = self.period.trim().split_once('-').ok_or_else(invalid)?;
+        Ok((year.parse().map_err(|_| invalid())?, month.parse().map_err(|_| invalid())?))
+    }
+
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+
+    pub fn approver(&self) -> &str {
+        &self.approver
+    }
+
+    pub fn approver_password(&self) -> &str {
+        &self.approver_password
+    }
+
+    pub fn focus_next(&mut self) {
+        self.focus = self.focus.next();
+    }
+
+    pub fn focus_previous(&mut self) {
+        self.focus = self.focus.previous();
+    }
+
+    fn focused_value(&mut self) -> &mut String {
+        match self.focus {
+            ReopenField::Period => &mut self.period,
+            ReopenField::Reason => &mut self.reason,
+            ReopenField::Approver => &mut self.approver,
+            ReopenField::ApproverPassword => &mut self.approver_password,
+        }
+    }
+
+    pub fn input_char(&mut self, c: char) {
+        if !self.processing {
+            self.focused_value().push(c);
+        }
+    }
+
+    pub fn delete_char(&mut self) {
+        if !self.processing {
+            self.focused_value().pop();
+        }
     }
 
     pub fn tick(&mut self) {
         self.animation_frame = (self.animation_frame + 1) % 60;
-        if self.loading_state == LoadingState::Loading {
-            self.loading_spinner.tick();
-        }
     }
 
     pub fn add_info(&mut self, message: impl Into<String>) {
@@ -95,11 +281,11 @@ impl ClosingLockPage {
     }
 
     pub fn select_next(&mut self) {
-        self.lock_table.select_next();
+        self.window_table.select_next();
     }
 
     pub fn select_previous(&mut self) {
-        self.lock_table.select_previous();
+        self.window_table.select_previous();
     }
 
     pub fn render(&mut self, frame: &mut Frame) {
@@ -112,40 +298,116 @@ impl ClosingLockPage {
 
         let left_chunks = Layout::default()
             .direction(Direction::Vertical)
-            .constraints([Constraint::Min(10), Constraint::Length(3)])
+            .constraints([
+                Constraint::Length(7),
+                Constraint::Length(3),
+                Constraint::Min(8),
+                Constraint::Length(3),
+            ])
             .split(chunks[0]);
 
-        if self.loading_state == LoadingState::Loading {
-            self.loading_spinner.render(
-                frame,
-                left_chunks[0],
-                "締日固定データを読み込んでいます...",
-            );
-        } else {
-            self.lock_table.render(frame, left_chunks[0]);
-        }
-
-        self.render_status_bar(frame, left_chunks[1]);
+        self.render_form(frame, left_chunks[0]);
+        self.render_window_summary(frame, left_chunks[1]);
+        self.window_table.render(frame, left_chunks[2]);
+        self.render_status_bar(frame, left_chunks[3]);
         self.event_viewer.render(frame, chunks[1]);
     }
 
-    fn render_status_bar(&self, frame: &mut Frame, area: Rect) {
-        let cursor = if self.animation_frame < 30 {
-            "▮"
-        } else {
-            " "
+    fn render_form(&self, frame: &mut Frame, area: Rect) {
+        let masked = "*".repeat(self.approver_password.chars().count());
+        let fields = [
+            ("対象期間 (YYYY-MM)", self.period.as_str(), ReopenField::Period),
+            ("理由（再オープン/上書き）", self.reason.as_str(), ReopenField::Reason),
+            ("承認者ID", self.approver.as_str(), ReopenField::Approver),
+            ("承認者パスワード", masked.as_str(), ReopenField::ApproverPassword),
+        ];
+
+        let lines: Vec<Line> = fields
+            .into_iter()
+            .map(|(label, value, field)| {
+                let focused = self.focus == field && !self.processing;
+                let cursor = if focused && self.animation_frame < 30 {
+                    "▮"
+                } else {
+                    " "
+                };
+                let label_style = if focused {
+                    Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(Color::DarkGray)
+                };
+                Line::from(vec![
+                    Span::styled(format!("  {:<20}", label), label_style),
+                    Span::styled(value.to_string(), Style::default().fg(Color::White)),
+                    Span::styled(
+                        cursor,
+                        Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+                    ),
+                ])
+            })
+            .collect();
+
+        let paragraph = Paragraph::new(lines).block(
+            Block::default()
+                .title("◇ 期間再オープン申請 ◇")
+                .title_style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(Color::Yellow)),
+        );
+
+        frame.render_widget(paragraph, area);
+    }
+
+    fn render_window_summary(&self, frame: &mut Frame, area: Rect) {
+        let line = match &self.window_summary {
+            Some(summary) => {
+                Line::from(Span::styled(format!(" {}", summary), Style::default().fg(Color::White)))
+            }
+            None => Line::from(Span::styled(
+                " [F2] で対象期間の再オープン期間中の記帳を表示します",
+                Style::default().fg(Color::DarkGray),
+            )),
         };
 
+        let paragraph = Paragraph::new(line).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Plain)
+                .border_style(Style::default().fg(Color::DarkGray)),
+        );
+
+        frame.render_widget(paragraph, area);
+    }
+
+    fn render_status_bar(&self, frame: &mut Frame, area: Rect) {
         let status_text = vec![Line::from(vec![
-            Span::styled(" [↑↓] ", Style::default().fg(Color::DarkGray)),
+            Span::styled(" [Tab] ", Style::default().fg(Color::DarkGray)),
+            Span::styled("項目移動", Style::default().fg(Color::Gray)),
+            Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+            Span::styled("[Enter] ", Style::default().fg(Color::DarkGray)),
+            Span::styled("再オープン", Style::default().fg(Color::Gray)),
+            Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+            Span::styled("[F2] ", Style::default().fg(Color::DarkGray)),
+            Span::styled("記帳レポート", Style::default().fg(Color::Gray)),
+            Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+            Span::styled("[F3] ", Style::default().fg(Color::DarkGray)),
+            Span::styled("勘定照合", Style::default().fg(Color::Gray)),
+            Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+            Span::styled("[F4] ", Style::default().fg(Color::DarkGray)),
+            Span::styled("締日固定", Style::default().fg(Color::Gray)),
+            Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+            Span::styled("[F5] ", Style::default().fg(Color::DarkGray)),
+            Span::styled("事前検証", Style::default().fg(Color::Gray)),
+            Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+            Span::styled("[F6] ", Style::default().fg(Color::DarkGray)),
+            Span::styled("上書き固定", Style::default().fg(Color::Gray)),
+            Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+            Span::styled("[↑↓] ", Style::default().fg(Color::DarkGray)),
             Span::styled("選択", Style::default().fg(Color::Gray)),
             Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
             Span::styled("[Esc] ", Style::default().fg(Color::DarkGray)),
             Span::styled("戻る", Style::default().fg(Color::Gray)),
-            Span::styled(
-                format!(" {}", cursor),
-                Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
-            ),
         ])];
 
         let paragraph = Paragraph::new(status_text).block(
@@ -1,6 +1,9 @@
 // ClosingPage - 決算処理画面（試算表表示）
 // 責務: 月次決算処理と試算表の表示（レトロで哀愁漂うデザイン）
 
+use std::collections::{BTreeMap, BTreeSet};
+
+use javelin_application::dtos::response::AmountMask;
 use ratatui::{
     Frame,
     layout::{Constraint, Direction, Layout, Rect},
@@ -11,7 +14,9 @@ use ratatui::{
 use tokio::sync::mpsc;
 
 use crate::{
-    format_amount, format_balance, format_number, presenter::TrialBalanceViewModel, truncate_text,
+    format_amount, format_balance, format_number,
+    presenter::{ManagementTrialBalanceViewModel, TrialBalanceRow, TrialBalanceViewModel},
+    truncate_text,
     views::components::DataTable,
 };
 
@@ -26,6 +31,13 @@ pub enum ClosingPageState {
     Completed,
 }
 
+/// 記入中の増減コメント
+struct CommentaryInput {
+    account_code: String,
+    account_name: String,
+    value: String,
+}
+
 /// 決算処理画面
 pub struct ClosingPage {
     /// 試算表テーブル
@@ -34,12 +46,30 @@ pub struct ClosingPage {
     trial_balance_receiver: mpsc::UnboundedReceiver<TrialBalanceViewModel>,
     /// 現在の試算表データ
     current_trial_balance: Option<TrialBalanceViewModel>,
+    /// 管理会計科目体系で集計し直した試算表
+    management_trial_balance: Option<ManagementTrialBalanceViewModel>,
+    /// 管理会計科目体系で表示中か（falseは制度会計の科目体系）
+    management_view: bool,
     /// 画面状態
     state: ClosingPageState,
     /// アニメーションフレーム
     animation_frame: usize,
     /// 処理進捗（0-100）
     progress: u8,
+    /// 表示中の通貨区分（0: 表示通貨建て、1以降: 取引通貨別内訳）
+    section_index: usize,
+    /// ステータスメッセージ
+    status_message: Option<String>,
+    /// 承認待ちの仕訳を含めて集計するか（次回の読込に適用）
+    include_pending_approval: bool,
+    /// 科目コードごとの増減コメント（表示中の会計期間）
+    commentaries: BTreeMap<String, String>,
+    /// 記入中の増減コメント
+    commentary_input: Option<CommentaryInput>,
+    /// 入力中の換算レート（通貨 決算日レート 期中平均レート）
+    rate_input: Option<String>,
+    /// 折りたたみ中の科目グループ（区分名/グループ名、通貨の切替後も維持する）
+    collapsed_groups: BTreeSet<String>,
 }
 
 impl ClosingPage {
@@ -52,43 +82,303 @@ impl ClosingPage {
             "借方合計".to_string(),
             "貸方合計".to_string(),
             "期末残高".to_string(),
+            "注".to_string(),
         ];
 
-        let trial_balance_table =
-            DataTable::new("◆ 試算表 ◆", headers).with_column_widths(vec![12, 25, 13, 13, 13, 13]);
+        let trial_balance_table = DataTable::new("◆ 試算表 ◆", headers)
+            .with_column_widths(vec![12, 25, 13, 13, 13, 13, 4]);
 
         Self {
             trial_balance_table,
             trial_balance_receiver,
             current_trial_balance: None,
+            management_trial_balance: None,
+            management_view: false,
             state: ClosingPageState::TrialBalance,
             animation_frame: 0,
             progress: 0,
+            section_index: 0,
+            status_message: None,
+            include_pending_approval: false,
+            commentaries: BTreeMap::new(),
+            commentary_input: None,
+            rate_input: None,
+            collapsed_groups: BTreeSet::new(),
         }
     }
 
     /// ViewModelを受信してテーブルを更新
     pub fn update(&mut self) {
         if let Ok(view_model) = self.trial_balance_receiver.try_recv() {
+            self.current_trial_balance = Some(view_model);
+            self.management_trial_balance = None;
+            self.section_index = 0;
+            self.refresh_table();
+            self.state = ClosingPageState::TrialBalance;
+        }
+    }
+
+    /// 表示中の試算表（表示通貨建て、取引通貨別内訳、または管理会計科目体系）
+    pub fn current_section(&self) -> Option<&TrialBalanceViewModel> {
+        if self.management_view {
+            return self.management_trial_balance.as_ref().map(|m| &m.trial_balance);
+        }
+        let tb = self.current_trial_balance.as_ref()?;
+        match self.section_index {
+            0 => Some(tb),
+            i => tb.currency_breakdowns.get(i - 1),
+        }
+    }
+
+    /// 表示通貨建て→取引通貨別内訳の順に切り替える
+    pub fn next_section(&mut self) {
+        // 管理会計科目体系は表示通貨建てのみ
+        if self.management_view {
+            return;
+        }
+        if let Some(tb) = &self.current_trial_balance {
+            self.section_index = (self.section_index + 1) % (tb.currency_breakdowns.len() + 1);
+            self.refresh_table();
+        }
+    }
+
+    /// 管理会計科目体系の試算表を設定
+    pub fn set_management_trial_balance(&mut self, view_model: ManagementTrialBalanceViewModel) {
+        self.management_trial_balance = Some(view_model);
+        if self.management_view {
+            self.refresh_table();
+        }
+    }
+
+    /// 制度会計・管理会計の科目体系を切り替える
+    pub fn toggle_chart(&mut self) {
+        self.management_view = !self.management_view;
+        if self.management_view && self.management_trial_balance.is_none() {
+            self.trial_balance_table.start_loading();
+        } else {
+            self.refresh_table();
+        }
+    }
+
+    /// 管理会計科目体系で表示中か
+    pub fn is_management_view(&self) -> bool {
+        self.management_view
+    }
+
+    /// 読み込みエラーを表示
+    pub fn set_error(&mut self, message: String) {
+        self.trial_balance_table.set_error(message);
+    }
+
+    /// ステータスメッセージを設定
+    pub fn set_status_message(&mut self, message: impl Into<String>) {
+        self.status_message = Some(message.into());
+    }
+
+    /// 再集計中の表示に切り替える
+    pub fn start_loading(&mut self) {
+        self.trial_balance_table.start_loading();
+    }
+
+    /// 承認待ちの仕訳を含めるかを設定
+    pub fn set_include_pending_approval(&mut self, include: bool) {
+        self.include_pending_approval = include;
+        self.trial_balance_table.start_loading();
+    }
+
+    /// 増減コメントを設定（科目コードとコメント）
+    pub fn set_commentaries(&mut self, commentaries: impl IntoIterator<Item = (String, String)>) {
+        self.commentaries = commentaries.into_iter().collect();
+        self.refresh_table();
+    }
+
+    /// 選択中の科目の増減コメント記入を開始（既存のコメントを編集する）
+    ///
+    /// 管理会計科目体系の表示中は制度会計の科目ではないため記入できない。
+    pub fn start_commentary(&mut self) {
+        if self.management_view {
+            self.set_status_message("増減コメントは制度会計の科目体系で記入してください");
+            return;
+        }
+        let Some(entry) = self.selected_row().and_then(|row| row.account()) else {
+            return;
+        };
+        self.commentary_input = Some(CommentaryInput {
+            account_code: entry.account_code.clone(),
+            account_name: entry.account_name.clone(),
+            value: self.commentaries.get(&entry.account_code).cloned().unwrap_or_default(),
+        });
+    }
+
+    /// 増減コメントを記入中か
+    pub fn is_editing_commentary(&self) -> bool {
+        self.commentary_input.is_some()
+    }
+
+    pub fn input_commentary_char(&mut self, c: char) {
+        if let Some(input) = self.commentary_input.as_mut() {
+            input.value.push(c);
+        }
+    }
+
+    pub fn delete_commentary_char(&mut self) {
+        if let Some(input) = self.commentary_input.as_mut() {
+            input.value.pop();
+        }
+    }
+
+    pub fn cancel_commentary(&mut self) {
+        self.commentary_input = None;
+    }
+
+    /// 記入を確定し、科目コードとコメントを返す（空のコメントは削除を表す）
+    pub fn take_commentary(&mut self) -> Option<(String, String)> {
+        self.commentary_input.take().map(|input| (input.account_code, input.value))
+    }
+
+    /// 換算レートの入力を開始
+    pub fn start_rate_input(&mut self) {
+        self.rate_input = Some(String::new());
+    }
+
+    /// 換算レートを入力中か
+    pub fn is_entering_rate(&self) -> bool {
+        self.rate_input.is_some()
+    }
+
+    pub fn input_rate_char(&mut self, c: char) {
+        if let Some(input) = self.rate_input.as_mut() {
+            input.push(c);
+        }
+    }
+
+    pub fn delete_rate_char(&mut self) {
+        if let Some(input) = self.rate_input.as_mut() {
+            input.pop();
+        }
+    }
+
+    pub fn cancel_rate_input(&mut self) {
+        self.rate_input = None;
+    }
+
+    /// 入力を確定し、入力内容を返す
+    pub fn take_rate_input(&mut self) -> Option<String> {
+        self.rate_input.take()
+    }
+
+    /// 選択中の科目の増減コメント
+    fn selected_commentary(&self) -> Option<(&str, &str)> {
+        if self.management_view {
+            return None;
+        }
+        let entry = self.selected_row()?.account()?;
+        self.commentaries
+            .get(&entry.account_code)
+            .map(|commentary| (entry.account_name.as_str(), commentary.as_str()))
+    }
+
+    /// 選択中の表示行
+    fn selected_row(&self) -> Option<TrialBalanceRow<'_>> {
+        let index = self.trial_balance_table.selected_index()?;
+        self.current_section()?.rows(&self.collapsed_groups).into_iter().nth(index)
+    }
+
+    /// 選択中の科目グループを折りたたむ・展開する（明細行の選択中はその科目のグループ）
+    pub fn toggle_group(&mut self) {
+        let (Some(section), Some(row)) = (self.current_section(), self.selected_row()) else {
+            return;
+        };
+        let key = match row {
+            TrialBalanceRow::GroupSubtotal { key, .. } => key.to_string(),
+            TrialBalanceRow::Account(entry) => {
+                let Some(group) = section
+                    .hierarchy
+                    .iter()
+                    .flat_map(|class| &class.groups)
+                    .find(|group| group.account_codes.contains(&entry.account_code))
+                else {
+                    return;
+                };
+                group.key.clone()
+            }
+            TrialBalanceRow::ClassSubtotal(_) => return,
+        };
+        if !self.collapsed_groups.remove(&key) {
+            self.collapsed_groups.insert(key.clone());
+        }
+        self.refresh_table();
+
+        // 開閉したグループの小計行を選択し直す
+        let position = self.current_section().and_then(|section| {
+            section.rows(&self.collapsed_groups).iter().position(
+                |row| matches!(row, TrialBalanceRow::GroupSubtotal { key: k, .. } if *k == key),
+            )
+        });
+        if let Some(index) = position {
+            self.trial_balance_table.select_index(index);
+        }
+    }
+
+    /// 表示中の試算表でテーブルを再構築
+    fn refresh_table(&mut self) {
+        let mark = |account_code: &str| {
+            if !self.management_view && self.commentaries.contains_key(account_code) {
+                "●".to_string()
+            } else {
+                String::new()
+            }
+        };
+        if let Some(view_model) = self.current_section() {
             // テーブルデータを構築
+            // 小計行は科目コード欄に開閉状態、科目名欄に小計名を表示する
             let rows: Vec<Vec<String>> = view_model
-                .entries
-                .iter()
-                .map(|entry| {
+                .rows(&self.collapsed_groups)
+                .into_iter()
+                .map(|row| {
+                    let entry = row.entry();
+                    let (code, name, note) = match row {
+                        TrialBalanceRow::Account(_) => (
+                            entry.account_code.clone(),
+                            truncate_text!(&entry.account_name, 23),
+                            mark(&entry.account_code),
+                        ),
+                        TrialBalanceRow::GroupSubtotal { collapsed, .. } => (
+                            if collapsed { "▶" } else { "▼" }.to_string(),
+                            truncate_text!(&format!("  {}", entry.account_name), 23),
+                            String::new(),
+                        ),
+                        TrialBalanceRow::ClassSubtotal(_) => (
+                            "■".to_string(),
+                            truncate_text!(&format!("【{}】", entry.account_name), 23),
+                            String::new(),
+                        ),
+                    };
+                    if entry.amount_masked {
+                        let masked = format!("{:>11}", AmountMask::MASKED);
+                        return vec![
+                            code,
+                            name,
+                            masked.clone(),
+                            masked.clone(),
+                            masked.clone(),
+                            masked,
+                            note,
+                        ];
+                    }
                     vec![
-                        entry.account_code.clone(),
-                        truncate_text!(&entry.account_name, 23),
+                        code,
+                        name,
                         format_balance!(entry.opening_balance, 11),
                         format_amount!(entry.debit_amount, 11),
                         format_amount!(entry.credit_amount, 11),
                         format_balance!(entry.closing_balance, 11),
+                        note,
                     ]
                 })
                 .collect();
 
             self.trial_balance_table.set_data(rows);
-            self.current_trial_balance = Some(view_model);
-            self.state = ClosingPageState::TrialBalance;
         }
     }
 
@@ -151,10 +441,10 @@ impl ClosingPage {
     fn render_main_area(&mut self, frame: &mut Frame, area: Rect) {
         match self.state {
             ClosingPageState::TrialBalance => {
-                // 試算表表示
+                // 試算表表示（合計欄に増減コメントの行を含む）
                 let chunks = Layout::default()
                     .direction(Direction::Vertical)
-                    .constraints([Constraint::Min(10), Constraint::Length(5)])
+                    .constraints([Constraint::Min(10), Constraint::Length(6)])
                     .split(area);
 
                 self.trial_balance_table.render(frame, chunks[0]);
@@ -188,9 +478,51 @@ impl ClosingPage {
 
     /// 試算表サマリーを描画（レトロな集計表示）
     fn render_summary(&self, frame: &mut Frame, area: Rect) {
-        if let Some(tb) = &self.current_trial_balance {
-            let text = vec![
-                Line::from(""),
+        if let Some(tb) = self.current_section() {
+            let mut summary = vec![Span::styled(
+                format!("  [{}]", tb.currency),
+                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+            )];
+            if tb.include_pending_approval {
+                summary.push(Span::styled(
+                    "  試算（承認待ちを含む）",
+                    Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+                ));
+            }
+            if self.management_view {
+                summary.push(Span::styled(
+                    "  管理会計科目体系",
+                    Style::default().fg(Color::Magenta).add_modifier(Modifier::BOLD),
+                ));
+                let unmapped = self
+                    .management_trial_balance
+                    .as_ref()
+                    .map(|m| m.unmapped_account_count)
+                    .unwrap_or_default();
+                if unmapped > 0 {
+                    summary.push(Span::styled(
+                        format!("  未割当科目: {}", unmapped),
+                        Style::default().fg(Color::Red),
+                    ));
+                }
+            }
+            if !self.management_view && self.section_index == 0 && tb.translation_difference != 0.0
+            {
+                summary.push(Span::styled("  換算差額: ", Style::default().fg(Color::DarkGray)));
+                summary.push(Span::styled(
+                    format_balance!(tb.translation_difference),
+                    Style::default().fg(Color::Magenta).add_modifier(Modifier::BOLD),
+                ));
+            }
+            if let Some(message) = &self.status_message {
+                summary.push(Span::styled(
+                    format!("  {}", message),
+                    Style::default().fg(Color::Green),
+                ));
+            }
+
+            let mut text = vec![
+                Line::from(summary),
                 Line::from(vec![
                     Span::styled("  借方合計: ", Style::default().fg(Color::DarkGray)),
                     Span::styled(
@@ -204,6 +536,59 @@ impl ClosingPage {
                     ),
                 ]),
             ];
+            if let Some(proof) = &tb.proof {
+                let (result, color) = if proof.footed {
+                    ("縦計・横計 一致", Color::Green)
+                } else {
+                    ("縦計・横計 不一致", Color::Red)
+                };
+                let hash: String = proof.rows_hash.chars().take(12).collect();
+                text.push(Line::from(vec![
+                    Span::styled(
+                        format!("  {}", result),
+                        Style::default().fg(color).add_modifier(Modifier::BOLD),
+                    ),
+                    Span::styled("  科目数: ", Style::default().fg(Color::DarkGray)),
+                    Span::styled(
+                        proof.account_count.to_string(),
+                        Style::default().fg(Color::White),
+                    ),
+                    Span::styled("  貸借差額: ", Style::default().fg(Color::DarkGray)),
+                    Span::styled(format!("{:.2}", proof.difference), Style::default().fg(color)),
+                    Span::styled("  横計差額: ", Style::default().fg(Color::DarkGray)),
+                    Span::styled(
+                        format!("{:.2}", proof.cross_foot_difference),
+                        Style::default().fg(color),
+                    ),
+                    Span::styled("  ハッシュ: ", Style::default().fg(Color::DarkGray)),
+                    Span::styled(hash, Style::default().fg(Color::DarkGray)),
+                ]));
+            }
+            if let Some(input) = &self.rate_input {
+                text.push(Line::from(vec![
+                    Span::styled(
+                        "  換算レート（通貨 決算日レート 期中平均レート）: ",
+                        Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+                    ),
+                    Span::styled(format!("{}▮", input), Style::default().fg(Color::White)),
+                ]));
+            } else if let Some(input) = &self.commentary_input {
+                text.push(Line::from(vec![
+                    Span::styled(
+                        format!("  増減コメント {} {}: ", input.account_code, input.account_name),
+                        Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+                    ),
+                    Span::styled(format!("{}▮", input.value), Style::default().fg(Color::White)),
+                ]));
+            } else if let Some((account_name, commentary)) = self.selected_commentary() {
+                text.push(Line::from(vec![
+                    Span::styled(
+                        format!("  ● {}: ", account_name),
+                        Style::default().fg(Color::Yellow),
+                    ),
+                    Span::styled(commentary.to_string(), Style::default().fg(Color::White)),
+                ]));
+            }
 
             let paragraph = Paragraph::new(text).block(
                 Block::default()
@@ -286,10 +671,66 @@ impl ClosingPage {
         };
 
         let status_text = match self.state {
+            ClosingPageState::TrialBalance if self.rate_input.is_some() => {
+                vec![Line::from(vec![
+                    Span::styled(" [Enter] ", Style::default().fg(Color::DarkGray)),
+                    Span::styled("設定して再集計", Style::default().fg(Color::Gray)),
+                    Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+                    Span::styled("[Esc] ", Style::default().fg(Color::DarkGray)),
+                    Span::styled("取消", Style::default().fg(Color::Gray)),
+                ])]
+            }
+            ClosingPageState::TrialBalance if self.commentary_input.is_some() => {
+                vec![Line::from(vec![
+                    Span::styled(" [Enter] ", Style::default().fg(Color::DarkGray)),
+                    Span::styled("保存（空の場合は削除）", Style::default().fg(Color::Gray)),
+                    Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+                    Span::styled("[Esc] ", Style::default().fg(Color::DarkGray)),
+                    Span::styled("取消", Style::default().fg(Color::Gray)),
+                ])]
+            }
             ClosingPageState::TrialBalance => vec![Line::from(vec![
                 Span::styled(" [↑↓] ", Style::default().fg(Color::DarkGray)),
                 Span::styled("選択", Style::default().fg(Color::Gray)),
                 Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+                Span::styled("[Space] ", Style::default().fg(Color::DarkGray)),
+                Span::styled("グループ開閉", Style::default().fg(Color::Gray)),
+                Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+                Span::styled("[c] ", Style::default().fg(Color::DarkGray)),
+                Span::styled("通貨切替", Style::default().fg(Color::Gray)),
+                Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+                Span::styled("[r] ", Style::default().fg(Color::DarkGray)),
+                Span::styled("換算レート", Style::default().fg(Color::Gray)),
+                Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+                Span::styled("[m] ", Style::default().fg(Color::DarkGray)),
+                Span::styled(
+                    if self.management_view {
+                        "科目体系: 管理"
+                    } else {
+                        "科目体系: 制度"
+                    },
+                    Style::default().fg(Color::Gray),
+                ),
+                Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+                Span::styled("[x] ", Style::default().fg(Color::DarkGray)),
+                Span::styled("CSV出力", Style::default().fg(Color::Gray)),
+                Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+                Span::styled("[a] ", Style::default().fg(Color::DarkGray)),
+                Span::styled("監査用帳簿出力", Style::default().fg(Color::Gray)),
+                Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+                Span::styled("[n] ", Style::default().fg(Color::DarkGray)),
+                Span::styled("増減コメント", Style::default().fg(Color::Gray)),
+                Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+                Span::styled("[p] ", Style::default().fg(Color::DarkGray)),
+                Span::styled(
+                    if self.include_pending_approval {
+                        "承認待ち: 含む"
+                    } else {
+                        "承認待ち: 除く"
+                    },
+                    Style::default().fg(Color::Gray),
+                ),
+                Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
                 Span::styled("[F5] ", Style::default().fg(Color::DarkGray)),
                 Span::styled("決算実行", Style::default().fg(Color::Gray)),
                 Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
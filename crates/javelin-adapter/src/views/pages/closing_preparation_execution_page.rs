@@ -3,7 +3,15 @@
 
 use ratatui::Frame;
 
-use crate::views::layouts::templates::{BatchExecutionTemplate, ProcessStep, ProcessStepStatus};
+use crate::{
+    presenter::SequenceAuditViewModel,
+    views::layouts::templates::{BatchExecutionTemplate, ProcessStep, ProcessStepStatus},
+};
+
+/// 番号連続性確認ステップの位置
+const SEQUENCE_AUDIT_STEP: usize = 4;
+/// イベントログに表示する監査指摘の最大件数
+const MAX_LISTED_FINDINGS: usize = 5;
 
 pub struct ClosingPreparationExecutionPage {
     template: BatchExecutionTemplate,
@@ -19,6 +27,7 @@ impl ClosingPreparationExecutionPage {
             ProcessStep::new("銀行照合差異確認"),
             ProcessStep::new("発生仕訳作成"),
             ProcessStep::new("暫定財務諸表生成"),
+            ProcessStep::new("番号連続性確認"),
             ProcessStep::new("結果確認"),
         ];
 
@@ -35,6 +44,47 @@ impl ClosingPreparationExecutionPage {
         self.template.update_step(0, ProcessStepStatus::Running, 0);
     }
 
+    /// 番号連続性確認を開始
+    pub fn start_sequence_audit(&mut self) {
+        self.template.update_step(SEQUENCE_AUDIT_STEP, ProcessStepStatus::Running, 0);
+    }
+
+    /// 番号連続性確認の結果を表示（指摘がある場合は警告）
+    pub fn set_sequence_audit(&mut self, view_model: &SequenceAuditViewModel) {
+        self.template
+            .update_step(SEQUENCE_AUDIT_STEP, ProcessStepStatus::Completed, 100);
+        self.template.add_info(format!("番号連続性確認: {}", view_model.summary));
+        if view_model.voided_count > 0 {
+            self.template.add_info(format!(
+                "取消番号 {}件は理由とともに監査レポートに記載されます [x] で出力",
+                view_model.voided_count
+            ));
+        }
+
+        if !view_model.has_findings() {
+            return;
+        }
+        self.template.add_error(format!(
+            "警告: 欠番 {}件・重複 {}件があります [x] で監査レポートを出力",
+            view_model.gap_count, view_model.duplicate_count
+        ));
+        // 取消番号の指摘は末尾に並ぶため、欠番・重複の件数分だけを一覧表示する
+        let warnings = view_model.gap_count + view_model.duplicate_count;
+        for finding in view_model.findings.iter().take(warnings.min(MAX_LISTED_FINDINGS)) {
+            self.template.add_error(format!("  {}", finding.detail));
+        }
+        if warnings > MAX_LISTED_FINDINGS {
+            self.template.add_error(format!("  ほか {}件", warnings - MAX_LISTED_FINDINGS));
+        }
+    }
+
+    /// 番号連続性確認の失敗を表示
+    pub fn set_sequence_audit_error(&mut self, error: String) {
+        self.template
+            .update_step(SEQUENCE_AUDIT_STEP, ProcessStepStatus::Error(error.clone()), 0);
+        self.template.add_error(format!("番号連続性確認に失敗しました: {}", error));
+    }
+
     /// ステップの状態を更新
     pub fn update_step(&mut self, index: usize, status: ProcessStepStatus, progress: u8) {
         self.template.update_step(index, status, progress);
@@ -1,18 +1,49 @@
 // ClosingPreparationPage - 締準備実行履歴画面
-// 責務: 締準備処理の実行履歴表示
+// 責務: 締準備処理の実行履歴表示、締準備の結果（仮勘定・清算勘定の滞留状況）の表示
 
-use ratatui::Frame;
+use javelin_application::dtos::response::{AgingBucket, PrepareClosingResponse, SuspenseAgingItem};
+use ratatui::{
+    Frame,
+    layout::{Constraint, Layout},
+    style::{Color, Modifier, Style},
+    text::Line,
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState},
+};
 
-use crate::views::layouts::templates::{BatchHistoryItem, BatchHistoryTemplate};
+use crate::{
+    format_amount,
+    views::layouts::templates::{BatchHistoryItem, BatchHistoryTemplate},
+};
+
+#[derive(Debug, Clone, PartialEq)]
+enum LoadingState {
+    Loading,
+    Loaded,
+    Error(String),
+}
+
+/// 締準備の結果ビュー
+struct PreparationResultView {
+    /// 対象期間（YYYY-MM）
+    period_label: String,
+    response: Option<PrepareClosingResponse>,
+    table_state: TableState,
+    loading_state: LoadingState,
+}
 
 pub struct ClosingPreparationPage {
     template: BatchHistoryTemplate,
+    /// 締準備の結果を表示中の場合はSome
+    result: Option<PreparationResultView>,
 }
 
 impl ClosingPreparationPage {
     pub fn new() -> Self {
-        let template = BatchHistoryTemplate::new("締準備処理 - 実行履歴");
-        Self { template }
+        let mut template = BatchHistoryTemplate::new("締準備処理 - 実行履歴");
+        template.add_info("[p] 当月の締準備を実行（仮勘定・清算勘定の滞留状況を確認）");
+        template.add_info("[a] 残高異常値レビュー（前月・前年同月比較）");
+        template.add_info("[h] 記帳量ヒートマップ（科目×月の件数・金額）");
+        Self { template, result: None }
     }
 
     pub fn set_history(&mut self, history: Vec<BatchHistoryItem>) {
@@ -35,12 +66,72 @@ impl ClosingPreparationPage {
         self.template.add_error(message);
     }
 
+    /// 締準備の結果を表示中か
+    pub fn is_showing_result(&self) -> bool {
+        self.result.is_some()
+    }
+
+    /// 締準備の結果の表示を開始（実行中）
+    pub fn show_result(&mut self, period_label: impl Into<String>) {
+        self.result = Some(PreparationResultView {
+            period_label: period_label.into(),
+            response: None,
+            table_state: TableState::default(),
+            loading_state: LoadingState::Loading,
+        });
+    }
+
+    /// 実行履歴の表示に戻る
+    pub fn hide_result(&mut self) {
+        self.result = None;
+    }
+
+    pub fn set_result(&mut self, response: PrepareClosingResponse) {
+        let Some(result) = &mut self.result else {
+            return;
+        };
+        let has_items = !aging_items(&response).is_empty();
+        result.table_state.select(has_items.then_some(0));
+        result.response = Some(response);
+        result.loading_state = LoadingState::Loaded;
+    }
+
+    pub fn set_result_error(&mut self, error: String) {
+        if let Some(result) = &mut self.result {
+            result.loading_state = LoadingState::Error(error);
+        }
+    }
+
+    /// 選択中の未整理の計上
+    pub fn selected_aging_item(&self) -> Option<&SuspenseAgingItem> {
+        let result = self.result.as_ref()?;
+        let items = aging_items(result.response.as_ref()?);
+        result.table_state.selected().and_then(|index| items.get(index).copied())
+    }
+
     pub fn select_next(&mut self) {
-        self.template.select_next();
+        match &mut self.result {
+            Some(result) => {
+                let len = result.response.as_ref().map_or(0, |r| aging_items(r).len());
+                if len > 0 {
+                    let next = result.table_state.selected().map_or(0, |i| (i + 1).min(len - 1));
+                    result.table_state.select(Some(next));
+                }
+            }
+            None => self.template.select_next(),
+        }
     }
 
     pub fn select_previous(&mut self) {
-        self.template.select_previous();
+        match &mut self.result {
+            Some(result) => {
+                if result.response.as_ref().is_some_and(|r| !aging_items(r).is_empty()) {
+                    let previous = result.table_state.selected().map_or(0, |i| i.saturating_sub(1));
+                    result.table_state.select(Some(previous));
+                }
+            }
+            None => self.template.select_previous(),
+        }
     }
 
     pub fn tick(&mut self) {
@@ -48,10 +139,141 @@ impl ClosingPreparationPage {
     }
 
     pub fn render(&mut self, frame: &mut Frame) {
-        self.template.render(frame);
+        match &mut self.result {
+            Some(result) => Self::render_result(result, frame),
+            None => self.template.render(frame),
+        }
+    }
+
+    fn render_result(result: &mut PreparationResultView, frame: &mut Frame) {
+        let area = frame.area();
+        let title = format!("締準備 {} - 仮勘定・清算勘定の滞留状況", result.period_label);
+
+        let response = match (&result.loading_state, &result.response) {
+            (LoadingState::Error(error), _) => {
+                let error_widget = Paragraph::new(error.as_str())
+                    .style(Style::default().fg(Color::Red))
+                    .block(Block::default().borders(Borders::ALL).title("エラー"));
+                frame.render_widget(error_widget, area);
+                return;
+            }
+            (LoadingState::Loaded, Some(response)) => response,
+            _ => {
+                let loading = Paragraph::new("締準備を実行中...")
+                    .block(Block::default().borders(Borders::ALL).title(title));
+                frame.render_widget(loading, area);
+                return;
+            }
+        };
+
+        let summary_height = response.suspense_aging.len().max(1) as u16 + 3;
+        let chunks = Layout::vertical([
+            Constraint::Length(summary_height),
+            Constraint::Min(0),
+            Constraint::Length(3),
+        ])
+        .split(area);
+
+        // 勘定科目ごとの経過日数区分別の残高
+        let mut header_cells = vec!["科目".to_string()];
+        header_cells.extend(AgingBucket::ALL.iter().map(|bucket| bucket.label().to_string()));
+        header_cells.extend(["合計".to_string(), "最長".to_string()]);
+        let header = Row::new(header_cells).style(Style::default().add_modifier(Modifier::BOLD));
+        let rows: Vec<Row> = response
+            .suspense_aging
+            .iter()
+            .map(|account| {
+                let mut cells = vec![Cell::from(account.account_code.as_str())];
+                cells.extend(account.buckets.iter().map(|summary| {
+                    let style = if summary.bucket == AgingBucket::Over90Days && summary.count > 0 {
+                        Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)
+                    } else {
+                        Style::default()
+                    };
+                    let text = if summary.count == 0 {
+                        "-".to_string()
+                    } else {
+                        format!("{} ({}件)", format_amount!(summary.amount), summary.count)
+                    };
+                    Cell::from(Line::from(text).right_aligned()).style(style)
+                }));
+                cells.push(Cell::from(
+                    Line::from(format_amount!(account.total_amount())).right_aligned(),
+                ));
+                cells.push(Cell::from(format!("{}日", account.oldest_age_days())));
+                Row::new(cells)
+            })
+            .collect();
+        let mut widths = vec![Constraint::Length(8)];
+        widths.extend(AgingBucket::ALL.iter().map(|_| Constraint::Length(18)));
+        widths.extend([Constraint::Length(14), Constraint::Length(8)]);
+        let summary_title = if response.suspense_aging.is_empty() {
+            format!("{}（未整理の計上はありません）", title)
+        } else {
+            title
+        };
+        let summary = Table::new(rows, widths)
+            .header(header)
+            .block(Block::default().borders(Borders::ALL).title(summary_title));
+        frame.render_widget(summary, chunks[0]);
+
+        // 未整理の計上（仕訳へのドリルダウン）
+        let items = aging_items(response);
+        let header = Row::new(vec!["科目", "計上日", "伝票番号", "行", "金額", "経過", "摘要"])
+            .style(Style::default().add_modifier(Modifier::BOLD));
+        let rows: Vec<Row> = items
+            .iter()
+            .map(|item| {
+                let age_style = if item.bucket == AgingBucket::Over90Days {
+                    Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default()
+                };
+                Row::new(vec![
+                    Cell::from(item.account_code.as_str()),
+                    Cell::from(item.transaction_date.as_str()),
+                    Cell::from(item.entry_number.as_str()),
+                    Cell::from(item.line_number.to_string()),
+                    Cell::from(Line::from(format_amount!(item.amount)).right_aligned()),
+                    Cell::from(format!("{}日", item.age_days)).style(age_style),
+                    Cell::from(item.description.as_deref().unwrap_or("")),
+                ])
+            })
+            .collect();
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Length(8),
+                Constraint::Length(10),
+                Constraint::Length(16),
+                Constraint::Length(4),
+                Constraint::Length(14),
+                Constraint::Length(8),
+                Constraint::Min(20),
+            ],
+        )
+        .header(header)
+        .row_highlight_style(Style::default().bg(Color::DarkGray).add_modifier(Modifier::BOLD))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!("締日固定前に整理が必要な計上 ({}件)", items.len())),
+        );
+        frame.render_stateful_widget(table, chunks[1], &mut result.table_state);
+
+        let status_bar = Paragraph::new(
+            "[↑↓] 選択 [Enter] 仮勘定の滞留状況で整理 [r] 再実行 [p/Esc] 実行履歴へ",
+        )
+        .block(Block::default().borders(Borders::ALL));
+        frame.render_widget(status_bar, chunks[2]);
     }
 }
 
+/// 未整理の計上（勘定科目コード順、科目内は経過日数の長い順）
+fn aging_items(response: &PrepareClosingResponse) -> Vec<&SuspenseAgingItem> {
+    response.suspense_aging.iter().flat_map(|account| &account.items).collect()
+}
+
 impl Default for ClosingPreparationPage {
     fn default() -> Self {
         Self::new()
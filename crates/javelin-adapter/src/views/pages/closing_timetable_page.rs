@@ -0,0 +1,208 @@
+// ClosingTimetablePage - 締めスケジュール画面のビューコンポーネント
+// 責務: 締めタスクの期日・担当者・残り日数・進捗のボード表示と編集欄の表示
+
+use ratatui::{
+    Frame,
+    layout::{Constraint, Layout},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState},
+};
+
+use crate::presenter::{ClosingTimetableItemViewModel, ClosingTimetableViewModel};
+
+#[derive(Debug, Clone, PartialEq)]
+enum LoadingState {
+    Loading,
+    Loaded,
+    Error(String),
+}
+
+pub struct ClosingTimetablePage {
+    view_model: ClosingTimetableViewModel,
+    table_state: TableState,
+    loading_state: LoadingState,
+    status_message: Option<String>,
+}
+
+impl ClosingTimetablePage {
+    pub fn new() -> Self {
+        Self {
+            view_model: ClosingTimetableViewModel::default(),
+            table_state: TableState::default(),
+            loading_state: LoadingState::Loading,
+            status_message: None,
+        }
+    }
+
+    /// 進捗ボードを設定（選択位置は可能な限り維持）
+    pub fn set_data(&mut self, view_model: ClosingTimetableViewModel) {
+        let selected = self.table_state.selected().unwrap_or(0);
+        let selected = if view_model.items.is_empty() {
+            None
+        } else {
+            Some(selected.min(view_model.items.len() - 1))
+        };
+        self.table_state.select(selected);
+        self.view_model = view_model;
+        self.loading_state = LoadingState::Loaded;
+    }
+
+    pub fn set_loading(&mut self) {
+        self.loading_state = LoadingState::Loading;
+    }
+
+    pub fn set_error(&mut self, error: String) {
+        self.loading_state = LoadingState::Error(error);
+    }
+
+    pub fn set_status_message(&mut self, message: impl Into<String>) {
+        self.status_message = Some(message.into());
+    }
+
+    /// 選択中の項目
+    pub fn selected_item(&self) -> Option<&ClosingTimetableItemViewModel> {
+        self.table_state.selected().and_then(|index| self.view_model.items.get(index))
+    }
+
+    pub fn select_next(&mut self) {
+        let len = self.view_model.items.len();
+        if len > 0 {
+            let next = self.table_state.selected().map_or(0, |i| (i + 1).min(len - 1));
+            self.table_state.select(Some(next));
+        }
+    }
+
+    pub fn select_previous(&mut self) {
+        if !self.view_model.items.is_empty() {
+            let previous = self.table_state.selected().map_or(0, |i| i.saturating_sub(1));
+            self.table_state.select(Some(previous));
+        }
+    }
+
+    /// 描画（`input` は編集中の入力値）
+    pub fn render(&mut self, frame: &mut Frame, input: Option<&str>) {
+        let area = frame.area();
+
+        if self.loading_state == LoadingState::Loading {
+            let loading = Paragraph::new("読み込み中...")
+                .block(Block::default().borders(Borders::ALL).title("締めスケジュール"));
+            frame.render_widget(loading, area);
+            return;
+        }
+
+        if let LoadingState::Error(error) = &self.loading_state {
+            let error_widget = Paragraph::new(error.as_str())
+                .style(Style::default().fg(Color::Red))
+                .block(Block::default().borders(Borders::ALL).title("エラー"));
+            frame.render_widget(error_widget, area);
+            return;
+        }
+
+        let chunks =
+            Layout::vertical([Constraint::Length(3), Constraint::Min(0), Constraint::Length(3)])
+                .split(area);
+
+        let summary_style = if self.view_model.has_overdue {
+            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::Green)
+        };
+        let summary = Paragraph::new(Line::from(vec![
+            Span::raw(format!("対象期間: {}  ", self.view_model.period_label)),
+            Span::styled(self.view_model.summary.as_str(), summary_style),
+        ]))
+        .block(Block::default().borders(Borders::ALL).title("締めスケジュール"));
+        frame.render_widget(summary, chunks[0]);
+
+        let header =
+            Row::new(vec!["コード", "タスク", "担当", "期日", "残り", "進捗", "工程", "更新者"])
+                .style(Style::default().add_modifier(Modifier::BOLD));
+
+        let rows: Vec<Row> = self
+            .view_model
+            .items
+            .iter()
+            .map(|item| {
+                let countdown_style = if item.is_overdue {
+                    Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)
+                } else if item.is_completed {
+                    Style::default().fg(Color::Green)
+                } else {
+                    Style::default().fg(Color::Yellow)
+                };
+                let row_style = if item.is_completed {
+                    Style::default().fg(Color::DarkGray)
+                } else {
+                    Style::default()
+                };
+
+                Row::new(vec![
+                    Cell::from(item.code.as_str()),
+                    Cell::from(item.task.as_str()),
+                    Cell::from(item.owner.as_str()),
+                    Cell::from(item.due_date.as_str()),
+                    Cell::from(item.countdown.as_str()).style(countdown_style),
+                    Cell::from(item.status_label.as_str()),
+                    Cell::from(item.step_label.as_str()),
+                    Cell::from(item.updated_by.as_str()),
+                ])
+                .style(row_style)
+            })
+            .collect();
+
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Length(8),
+                Constraint::Min(20),
+                Constraint::Length(10),
+                Constraint::Length(11),
+                Constraint::Length(10),
+                Constraint::Length(7),
+                Constraint::Length(13),
+                Constraint::Length(10),
+            ],
+        )
+        .header(header)
+        .row_highlight_style(Style::default().bg(Color::DarkGray).add_modifier(Modifier::BOLD))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!("締めタスク ({}件)", self.view_model.items.len())),
+        );
+
+        frame.render_stateful_widget(table, chunks[1], &mut self.table_state);
+
+        let status_bar = if let Some(value) = input {
+            Paragraph::new(Line::from(vec![
+                Span::styled(
+                    "締めタスク（<コード>:<タスク>:<担当>:<期末日からの日数>:<工程>）: ",
+                    Style::default().fg(Color::Yellow),
+                ),
+                Span::raw(value),
+                Span::styled("▮", Style::default().fg(Color::Yellow)),
+            ]))
+            .block(Block::default().borders(Borders::ALL).title("[Enter] 保存 [Esc] キャンセル"))
+        } else {
+            let mut status = vec![Span::raw(
+                "[←→] 期間 [↑↓] 選択 [Space] 進捗切替 [a] 追加 [e] 編集 [x] 削除 [r] 再読込 [Esc] 戻る",
+            )];
+            if let Some(message) = &self.status_message {
+                status.push(Span::styled(
+                    format!("  {}", message),
+                    Style::default().fg(Color::Green),
+                ));
+            }
+            Paragraph::new(Line::from(status)).block(Block::default().borders(Borders::ALL))
+        };
+
+        frame.render_widget(status_bar, chunks[2]);
+    }
+}
+
+impl Default for ClosingTimetablePage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
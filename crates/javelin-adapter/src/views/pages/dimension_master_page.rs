@@ -0,0 +1,173 @@
+// DimensionMasterPage - 分析軸マスタ画面のビューコンポーネント
+// 責務: 分析軸（種類・コード・名称・有効状態）の一覧と編集欄の表示
+
+use ratatui::{
+    Frame,
+    layout::{Constraint, Layout},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState},
+};
+
+use crate::presenter::{DimensionMasterItemViewModel, DimensionMasterViewModel};
+
+#[derive(Debug, Clone, PartialEq)]
+enum LoadingState {
+    Loading,
+    Loaded,
+    Error(String),
+}
+
+pub struct DimensionMasterPage {
+    view_model: DimensionMasterViewModel,
+    table_state: TableState,
+    loading_state: LoadingState,
+    status_message: Option<String>,
+}
+
+impl DimensionMasterPage {
+    pub fn new() -> Self {
+        Self {
+            view_model: DimensionMasterViewModel::default(),
+            table_state: TableState::default(),
+            loading_state: LoadingState::Loading,
+            status_message: None,
+        }
+    }
+
+    /// 分析軸一覧を設定（選択位置は可能な限り維持）
+    pub fn set_data(&mut self, view_model: DimensionMasterViewModel) {
+        let selected = self.table_state.selected().unwrap_or(0);
+        let selected = if view_model.items.is_empty() {
+            None
+        } else {
+            Some(selected.min(view_model.items.len() - 1))
+        };
+        self.table_state.select(selected);
+        self.view_model = view_model;
+        self.loading_state = LoadingState::Loaded;
+    }
+
+    pub fn set_error(&mut self, error: String) {
+        self.loading_state = LoadingState::Error(error);
+    }
+
+    pub fn set_status_message(&mut self, message: impl Into<String>) {
+        self.status_message = Some(message.into());
+    }
+
+    /// 選択中の項目
+    pub fn selected_item(&self) -> Option<&DimensionMasterItemViewModel> {
+        self.table_state.selected().and_then(|index| self.view_model.items.get(index))
+    }
+
+    pub fn select_next(&mut self) {
+        let len = self.view_model.items.len();
+        if len > 0 {
+            let next = self.table_state.selected().map_or(0, |i| (i + 1).min(len - 1));
+            self.table_state.select(Some(next));
+        }
+    }
+
+    pub fn select_previous(&mut self) {
+        if !self.view_model.items.is_empty() {
+            let previous = self.table_state.selected().map_or(0, |i| i.saturating_sub(1));
+            self.table_state.select(Some(previous));
+        }
+    }
+
+    /// 描画（`input` は編集中の入力値）
+    pub fn render(&mut self, frame: &mut Frame, input: Option<&str>) {
+        let area = frame.area();
+
+        if self.loading_state == LoadingState::Loading {
+            let loading = Paragraph::new("読み込み中...")
+                .block(Block::default().borders(Borders::ALL).title("分析軸マスタ"));
+            frame.render_widget(loading, area);
+            return;
+        }
+
+        if let LoadingState::Error(error) = &self.loading_state {
+            let error_widget = Paragraph::new(error.as_str())
+                .style(Style::default().fg(Color::Red))
+                .block(Block::default().borders(Borders::ALL).title("エラー"));
+            frame.render_widget(error_widget, area);
+            return;
+        }
+
+        let chunks = Layout::vertical([Constraint::Min(0), Constraint::Length(3)]).split(area);
+
+        let header = Row::new(vec!["種類", "コード", "名称", "状態"])
+            .style(Style::default().add_modifier(Modifier::BOLD));
+
+        let rows: Vec<Row> = self
+            .view_model
+            .items
+            .iter()
+            .map(|item| {
+                let status_style = if item.is_active {
+                    Style::default().fg(Color::Green)
+                } else {
+                    Style::default().fg(Color::DarkGray)
+                };
+
+                Row::new(vec![
+                    Cell::from(item.kind.as_str()),
+                    Cell::from(item.code.as_str()),
+                    Cell::from(item.name.as_str()),
+                    Cell::from(item.status_label.as_str()).style(status_style),
+                ])
+            })
+            .collect();
+
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Length(14),
+                Constraint::Length(14),
+                Constraint::Min(20),
+                Constraint::Length(6),
+            ],
+        )
+        .header(header)
+        .row_highlight_style(Style::default().bg(Color::DarkGray).add_modifier(Modifier::BOLD))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!("分析軸マスタ ({}件)", self.view_model.items.len())),
+        );
+
+        frame.render_stateful_widget(table, chunks[0], &mut self.table_state);
+
+        let status_bar = if let Some(value) = input {
+            Paragraph::new(Line::from(vec![
+                Span::styled(
+                    "分析軸（<種類>:<コード>:<名称>）: ",
+                    Style::default().fg(Color::Yellow),
+                ),
+                Span::raw(value),
+                Span::styled("▮", Style::default().fg(Color::Yellow)),
+            ]))
+            .block(Block::default().borders(Borders::ALL).title("[Enter] 保存 [Esc] キャンセル"))
+        } else {
+            let mut status = vec![Span::raw(
+                "[↑↓] 選択 [a] 追加 [Enter/e] 編集 [t] 有効/無効 [x] 削除 [Esc] 戻る",
+            )];
+            if let Some(message) = &self.status_message {
+                status.push(Span::styled(
+                    format!("  {}", message),
+                    Style::default().fg(Color::Green),
+                ));
+            }
+            Paragraph::new(Line::from(status)).block(Block::default().borders(Borders::ALL))
+        };
+
+        frame.render_widget(status_bar, chunks[1]);
+    }
+}
+
+impl Default for DimensionMasterPage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,9 +1,20 @@
 // FinancialStatementExecutionPage - 財務諸表生成実行画面
 // 責務: 財務諸表生成処理の実行とプログレス表示
 
+use javelin_application::dtos::response::{
+    ArchivedReportSummary, GenerateFinancialStatementsResponse,
+};
 use ratatui::Frame;
 
-use crate::views::layouts::templates::{BatchExecutionTemplate, ProcessStep, ProcessStepStatus};
+use crate::{
+    format_balance,
+    views::layouts::templates::{BatchExecutionTemplate, ProcessStep, ProcessStepStatus},
+};
+
+/// 生成処理のステップ数（承認・アーカイブを除く）
+const GENERATION_STEPS: usize = 6;
+/// 承認・アーカイブステップの位置
+const SIGN_OFF_STEP: usize = 6;
 
 pub struct FinancialStatementExecutionPage {
     template: BatchExecutionTemplate,
@@ -21,21 +32,100 @@ impl FinancialStatementExecutionPage {
             ProcessStep::new("キャッシュフロー計算書生成"),
             ProcessStep::new("注記情報生成"),
             ProcessStep::new("結果確認"),
+            ProcessStep::new("承認・アーカイブ"),
         ];
 
         template.set_steps(steps);
         template.add_info("財務諸表生成処理画面を開きました");
         template.add_info("処理を開始するには [s] キーを押してください");
+        template.add_info("対象月は [h/l] で変更できます");
+        template.add_info("表示科目から参照する注記は [n] で設定できます");
 
         Self { template }
     }
 
     /// 処理を開始
-    pub fn start_execution(&mut self) {
-        self.template.add_info("財務諸表生成処理を開始します...");
+    pub fn start_execution(&mut self, period_label: &str) {
+        self.template
+            .add_info(format!("{} の財務諸表生成処理を開始します...", period_label));
+        for index in 0..=SIGN_OFF_STEP {
+            self.template.update_step(index, ProcessStepStatus::Waiting, 0);
+        }
         self.template.update_step(0, ProcessStepStatus::Running, 0);
     }
 
+    /// 対象月の変更を表示
+    pub fn set_period(&mut self, period_label: &str) {
+        self.template.add_info(format!("対象月: {}", period_label));
+    }
+
+    /// 生成結果を表示
+    pub fn set_statements(&mut self, statements: &GenerateFinancialStatementsResponse) {
+        for index in 0..GENERATION_STEPS {
+            self.template.update_step(index, ProcessStepStatus::Completed, 100);
+        }
+
+        let position = &statements.statement_of_financial_position;
+        let profit_or_loss = &statements.statement_of_profit_or_loss;
+        self.template.add_info(format!(
+            "売上収益 {} / 当期純利益 {} / 資本 {}",
+            format_balance!(profit_or_loss.revenue),
+            format_balance!(profit_or_loss.net_profit),
+            format_balance!(position.equity)
+        ));
+        for caption in &statements.statement_captions {
+            self.template.add_info(format!(
+                "{} {}{}",
+                caption.caption,
+                format_balance!(caption.amount),
+                note_marks(&caption.note_numbers)
+            ));
+        }
+        if statements.cross_check_passed {
+            self.template.add_info("財務諸表間の相互検証: 一致");
+        } else {
+            self.template.add_error("警告: 財務諸表間の相互検証で不一致があります");
+        }
+        for delivery in &statements.deliveries {
+            if delivery.delivered {
+                self.template.add_info(delivery.summary());
+            } else {
+                self.template.add_error(delivery.summary());
+            }
+        }
+        self.template
+            .add_info("内容を確定する場合は [f] で承認しアーカイブしてください");
+    }
+
+    /// 生成の失敗を表示
+    pub fn set_execution_error(&mut self, error: String) {
+        self.template.update_step(0, ProcessStepStatus::Error(error.clone()), 0);
+        self.template.add_error(format!("財務諸表生成に失敗しました: {}", error));
+    }
+
+    /// 承認・アーカイブを開始
+    pub fn start_sign_off(&mut self) {
+        self.template.update_step(SIGN_OFF_STEP, ProcessStepStatus::Running, 0);
+    }
+
+    /// アーカイブ結果を表示
+    pub fn set_signed_off(&mut self, summary: &ArchivedReportSummary) {
+        self.template.update_step(SIGN_OFF_STEP, ProcessStepStatus::Completed, 100);
+        self.template.add_info(format!(
+            "承認しアーカイブしました: {}（承認者 {}）",
+            summary.archive_id, summary.signed_by
+        ));
+        self.template.add_info(format!("ハッシュ値: {}", summary.content_hash));
+        self.template.add_info("アーカイブ済み帳票は [a] で確認できます");
+    }
+
+    /// 承認・アーカイブの失敗を表示
+    pub fn set_sign_off_error(&mut self, error: String) {
+        self.template
+            .update_step(SIGN_OFF_STEP, ProcessStepStatus::Error(error.clone()), 0);
+        self.template.add_error(format!("アーカイブに失敗しました: {}", error));
+    }
+
     /// ステップの状態を更新
     pub fn update_step(&mut self, index: usize, status: ProcessStepStatus, progress: u8) {
         self.template.update_step(index, status, progress);
@@ -72,6 +162,15 @@ impl FinancialStatementExecutionPage {
     }
 }
 
+/// 表示科目に付ける注記番号（例: `（注3・注7）`、参照がなければ空）
+fn note_marks(note_numbers: &[u32]) -> String {
+    if note_numbers.is_empty() {
+        return String::new();
+    }
+    let marks: Vec<String> = note_numbers.iter().map(|number| format!("注{}", number)).collect();
+    format!("（{}）", marks.join("・"))
+}
+
 impl Default for FinancialStatementExecutionPage {
     fn default() -> Self {
         Self::new()
@@ -11,7 +11,9 @@ pub struct FinancialStatementPage {
 
 impl FinancialStatementPage {
     pub fn new() -> Self {
-        let template = BatchHistoryTemplate::new("財務諸表生成処理 - 実行履歴");
+        let mut template = BatchHistoryTemplate::new("財務諸表生成処理 - 実行履歴");
+        template.add_info("承認済みの財務諸表は [a] でアーカイブから確認できます");
+        template.add_info("表示科目の注記参照は [n] で設定できます");
         Self { template }
     }
 
@@ -1,11 +1,20 @@
 // HomePage - ホーム画面（業務メニュー + システムマスタメニュー）
 // 責務: 業務メニューとシステムマスタメニューの表示、h/lで枠切り替え、j/kで内部フォーカス移動
 
-use ratatui::Frame;
+use javelin_application::dtos::response::IntegrityAuditResponse;
+use ratatui::{
+    Frame,
+    style::{Color, Style},
+    text::Line,
+    widgets::{Block, Borders, Paragraph},
+};
 
-use crate::views::{
-    components::{ListItemData, ListSelector},
-    layouts::MenuLayout,
+use crate::{
+    clock::format_timestamp,
+    views::{
+        components::{ListItemData, ListSelector},
+        layouts::MenuLayout,
+    },
 };
 
 /// ViewType enum (temporary, for compatibility)
@@ -17,7 +26,9 @@ pub enum ViewType {
     Home,
     JournalEntry,
     Search,
+    SupplierInvoice,
     Ledger,
+    ReportHub,
     LedgerConsolidation,
     ClosingPreparation,
     ClosingLock,
@@ -26,13 +37,26 @@ pub enum ViewType {
     AccountAdjustment,
     IfrsValuation,
     FinancialStatement,
+    ClosingTimetable,
     AccountMasterManagement,
     SubsidiaryAccountMasterManagement,
     UserSettingsManagement,
     DataImport,
     DataExport,
+    StatementLineMappingManagement,
+    Maintenance,
+    AccountingPolicy,
+    DimensionMasterManagement,
+    SystemInfo,
+    Inbox,
+    KpiDashboard,
 }
 
+/// 受信箱メニューの項目位置
+const INBOX_MENU_INDEX: usize = 14;
+/// 受信箱メニューのラベル
+const INBOX_LABEL: &str = "受信箱";
+
 /// メニュータイプ
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum MenuType {
@@ -45,6 +69,8 @@ pub struct HomePage {
     business_menu_selector: ListSelector,
     system_menu_selector: ListSelector,
     active_menu: MenuType,
+    /// 最新の整合性監査（読み込み前・未実施の場合はNone）
+    integrity_audit: Option<IntegrityAuditResponse>,
 }
 
 impl HomePage {
@@ -56,6 +82,11 @@ impl HomePage {
         let business_menu_items = vec![
             ListItemData::new("101", "原始記録登録", "日次：仕訳帳・キャッシュログ入力"),
             ListItemData::new("102", "仕訳検索", "日次：仕訳の検索・照会"),
+            ListItemData::new(
+                "103",
+                "仕入先請求書台帳",
+                "日次：請求書登録・買掛金計上仕訳の起票・支払状況",
+            ),
             ListItemData::new("201", "元帳集約", "週次：総勘定元帳への転記処理"),
             ListItemData::new("301", "締準備", "月次：期間帰属確認・仮仕訳作成"),
             ListItemData::new("302", "締日固定", "月次：取引データのロック処理"),
@@ -64,7 +95,15 @@ impl HomePage {
             ListItemData::new("305", "勘定補正", "月次：仮勘定整理・区分修正"),
             ListItemData::new("306", "IFRS評価", "月次：見積会計・公正価値測定"),
             ListItemData::new("307", "財務諸表生成", "月次：制度開示資料作成"),
+            ListItemData::new("308", "締めスケジュール", "月次：締めタスクの期限・担当と進捗"),
             ListItemData::new("401", "元帳閲覧", "照会：総勘定元帳・補助元帳"),
+            ListItemData::new("402", "帳票メニュー", "照会：条件を指定して定型帳票を実行"),
+            ListItemData::new("501", INBOX_LABEL, "通知：承認待ち・差戻しの確認"),
+            ListItemData::new(
+                "502",
+                "KPIダッシュボード",
+                "照会：仕訳起票・承認件数と処理時間の推移",
+            ),
         ];
 
         let system_menu_items = vec![
@@ -77,6 +116,19 @@ impl HomePage {
             ),
             ListItemData::new("904", "データインポート", "外部データの一括取込"),
             ListItemData::new("905", "データエクスポート", "マスタデータの出力"),
+            ListItemData::new("906", "表示科目マッピング", "勘定科目と財務諸表表示科目の対応付け"),
+            ListItemData::new("907", "メンテナンス", "仕訳一覧と元帳の整合性チェック"),
+            ListItemData::new("908", "会計方針", "端数処理・税額端数処理・負数表示の設定"),
+            ListItemData::new(
+                "909",
+                "分析軸マスタ",
+                "プロジェクト・セグメント等の分析軸の登録・無効化",
+            ),
+            ListItemData::new(
+                "910",
+                "システム情報",
+                "バージョン・データディレクトリ・起動時の処理と所要時間",
+            ),
         ];
 
         let business_menu_selector = ListSelector::new("業務メニュー", business_menu_items);
@@ -87,6 +139,7 @@ impl HomePage {
             business_menu_selector,
             system_menu_selector,
             active_menu: MenuType::Business,
+            integrity_audit: None,
         }
     }
 
@@ -104,6 +157,26 @@ impl HomePage {
         };
     }
 
+    /// 受信箱の件数と承認SLA超過件数をメニューに表示
+    pub fn set_inbox_count(&mut self, count: usize, overdue: usize) {
+        let label = match (count, overdue) {
+            (0, _) => INBOX_LABEL.to_string(),
+            (count, 0) => format!("{} [{}]", INBOX_LABEL, count),
+            (count, overdue) => format!("{} [{}] SLA超過{}", INBOX_LABEL, count, overdue),
+        };
+        self.business_menu_selector.set_item_label(INBOX_MENU_INDEX, label);
+    }
+
+    /// 最新の整合性監査の結果を表示
+    pub fn set_integrity_audit(&mut self, audit: Option<IntegrityAuditResponse>) {
+        self.integrity_audit = audit;
+    }
+
+    /// 情報メッセージをイベントログに追加
+    pub fn add_info(&mut self, message: &str) {
+        self.layout.event_viewer_mut().add_info(message);
+    }
+
     /// エラーメッセージをイベントログに追加
     pub fn add_error(&mut self, message: &str) {
         self.layout.event_viewer_mut().add_error(message);
@@ -132,15 +205,20 @@ impl HomePage {
                 self.business_menu_selector.selected_index().and_then(|idx| match idx {
                     0 => Some(ViewType::JournalEntry),
                     1 => Some(ViewType::Search),
-                    2 => Some(ViewType::LedgerConsolidation),
-                    3 => Some(ViewType::ClosingPreparation),
-                    4 => Some(ViewType::ClosingLock),
-                    5 => Some(ViewType::TrialBalance),
-                    6 => Some(ViewType::NoteDraft),
-                    7 => Some(ViewType::AccountAdjustment),
-                    8 => Some(ViewType::IfrsValuation),
-                    9 => Some(ViewType::FinancialStatement),
-                    10 => Some(ViewType::Ledger),
+                    2 => Some(ViewType::SupplierInvoice),
+                    3 => Some(ViewType::LedgerConsolidation),
+                    4 => Some(ViewType::ClosingPreparation),
+                    5 => Some(ViewType::ClosingLock),
+                    6 => Some(ViewType::TrialBalance),
+                    7 => Some(ViewType::NoteDraft),
+                    8 => Some(ViewType::AccountAdjustment),
+                    9 => Some(ViewType::IfrsValuation),
+                    10 => Some(ViewType::FinancialStatement),
+                    11 => Some(ViewType::ClosingTimetable),
+                    12 => Some(ViewType::Ledger),
+                    13 => Some(ViewType::ReportHub),
+                    14 => Some(ViewType::Inbox),
+                    15 => Some(ViewType::KpiDashboard),
                     _ => None,
                 })
             }
@@ -151,6 +229,11 @@ impl HomePage {
                     2 => Some(ViewType::UserSettingsManagement),
                     3 => Some(ViewType::DataImport),
                     4 => Some(ViewType::DataExport),
+                    5 => Some(ViewType::StatementLineMappingManagement),
+                    6 => Some(ViewType::Maintenance),
+                    7 => Some(ViewType::AccountingPolicy),
+                    8 => Some(ViewType::DimensionMasterManagement),
+                    9 => Some(ViewType::SystemInfo),
                     _ => None,
                 })
             }
@@ -164,12 +247,17 @@ impl HomePage {
         let active_menu = self.active_menu;
         let business_selector = &mut self.business_menu_selector;
         let system_selector = &mut self.system_menu_selector;
+        let (audit_status, audit_color) = integrity_audit_status(self.integrity_audit.as_ref());
 
         self.layout.render(frame, |frame, area| {
-            // メインエリアを上下分割: 業務メニュー(上) + システムマスタ(下)
+            // メインエリアを上下分割: 業務メニュー(上) + システムマスタ(中) + 整合性監査(下)
             let menu_chunks = Layout::default()
                 .direction(Direction::Vertical)
-                .constraints([Constraint::Percentage(70), Constraint::Percentage(30)])
+                .constraints([
+                    Constraint::Percentage(70),
+                    Constraint::Percentage(30),
+                    Constraint::Length(3),
+                ])
                 .split(area);
 
             // 業務メニュー（枠なし、ListSelectorが自分で枠を描画）
@@ -181,12 +269,86 @@ impl HomePage {
             let is_system_active = active_menu == MenuType::System;
             system_selector.set_active(is_system_active);
             system_selector.render(frame, menu_chunks[1]);
+
+            // 最新の整合性監査
+            let audit =
+                Paragraph::new(Line::styled(audit_status, Style::default().fg(audit_color)))
+                    .block(Block::default().borders(Borders::ALL).title("整合性監査"));
+            frame.render_widget(audit, menu_chunks[2]);
         });
     }
 }
 
+/// 最新の整合性監査の表示内容と表示色
+fn integrity_audit_status(audit: Option<&IntegrityAuditResponse>) -> (String, Color) {
+    let Some(audit) = audit else {
+        return ("未実施".to_string(), Color::Gray);
+    };
+    let completed = format!("{}（{}）", format_timestamp(&audit.completed_at), audit.trigger);
+    if audit.passed {
+        return (format!("合格  最終実行: {}", completed), Color::Green);
+    }
+    let failed: Vec<String> = audit
+        .checks
+        .iter()
+        .filter(|check| !check.passed)
+        .map(|check| format!("{} {}件", check.name, check.findings.len()))
+        .collect();
+    (
+        format!(
+            "不合格  検出事項{}件（{}）  最終実行: {}",
+            audit.finding_count,
+            failed.join("・"),
+            completed
+        ),
+        Color::Red,
+    )
+}
+
 impl Default for HomePage {
     fn default() -> Self {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use javelin_application::dtos::response::IntegrityAuditCheckDto;
+
+    use super::*;
+
+    fn check(name: &str, findings: Vec<&str>) -> IntegrityAuditCheckDto {
+        IntegrityAuditCheckDto {
+            name: name.to_string(),
+            summary: String::new(),
+            passed: findings.is_empty(),
+            findings: findings.into_iter().map(String::from).collect(),
+        }
+    }
+
+    #[test]
+    fn test_integrity_audit_status_shows_failed_checks() {
+        assert_eq!(integrity_audit_status(None), ("未実施".to_string(), Color::Gray));
+
+        let mut audit = IntegrityAuditResponse {
+            audit_id: "IA-1".to_string(),
+            trigger: "定期".to_string(),
+            requested_by: "system".to_string(),
+            started_at: "2024-04-01T00:00:00Z".to_string(),
+            completed_at: "2024-04-01T00:00:00Z".to_string(),
+            passed: true,
+            finding_count: 0,
+            checks: vec![check("Projection整合性", vec![]), check("番号連続性", vec![])],
+        };
+        let (status, color) = integrity_audit_status(Some(&audit));
+        assert!(status.starts_with("合格"));
+        assert_eq!(color, Color::Green);
+
+        audit.passed = false;
+        audit.finding_count = 2;
+        audit.checks[1] = check("番号連続性", vec!["欠番 V-2024-00002", "重複 V-2024-00005"]);
+        let (status, color) = integrity_audit_status(Some(&audit));
+        assert!(status.starts_with("不合格  検出事項2件（番号連続性 2件）"), "{}", status);
+        assert_eq!(color, Color::Red);
+    }
+}
@@ -1,18 +1,75 @@
 // IfrsValuationPage - IFRS評価実行履歴画面
-// 責務: IFRS評価処理の実行履歴表示
+// 責務: IFRS評価処理の実行履歴と、償却原価で測定する金融商品のスケジュール表示
 
-use ratatui::Frame;
+use javelin_application::dtos::response::FinancialInstrumentResponse;
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph},
+};
 
-use crate::views::layouts::templates::{BatchHistoryItem, BatchHistoryTemplate};
+use crate::{
+    format_amount, format_balance,
+    views::{
+        components::{DataTable, EventViewer, InfoPanel},
+        layouts::templates::{BatchHistoryItem, BatchHistoryTemplate},
+    },
+};
+
+/// 表示中のビュー
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum IfrsValuationView {
+    /// 実行履歴
+    History,
+    /// 償却原価スケジュール
+    Schedule,
+}
 
 pub struct IfrsValuationPage {
     template: BatchHistoryTemplate,
+    view: IfrsValuationView,
+    /// 償却原価で測定する金融商品
+    instruments: Vec<FinancialInstrumentResponse>,
+    selected_instrument: usize,
+    schedule_table: DataTable,
+    instrument_panel: InfoPanel,
+    event_viewer: EventViewer,
+    animation_frame: usize,
 }
 
 impl IfrsValuationPage {
     pub fn new() -> Self {
-        let template = BatchHistoryTemplate::new("IFRS評価処理 - 実行履歴");
-        Self { template }
+        let mut template = BatchHistoryTemplate::new("IFRS評価処理 - 実行履歴");
+        template.add_info("[w] 棚卸資産評価ワークシート");
+        template.add_info("[a] 金融商品の償却原価スケジュール");
+
+        let headers = vec![
+            "会計期間".to_string(),
+            "期首償却原価".to_string(),
+            "実効金利利息".to_string(),
+            "表面利息".to_string(),
+            "償却額".to_string(),
+            "期末償却原価".to_string(),
+            "見越計上".to_string(),
+        ];
+        let schedule_table = DataTable::new("◆ 償却原価スケジュール ◆", headers)
+            .with_column_widths(vec![10, 16, 14, 12, 12, 16, 8]);
+
+        let mut event_viewer = EventViewer::new();
+        event_viewer.add_info("利息の見越計上はIFRS評価処理の実行時に起票されます");
+
+        Self {
+            template,
+            view: IfrsValuationView::History,
+            instruments: vec![],
+            selected_instrument: 0,
+            schedule_table,
+            instrument_panel: InfoPanel::new("◇ 金融商品 ◇").with_border_color(Color::Cyan),
+            event_viewer,
+            animation_frame: 0,
+        }
     }
 
     pub fn set_history(&mut self, history: Vec<BatchHistoryItem>) {
@@ -28,27 +85,222 @@ impl IfrsValuationPage {
     }
 
     pub fn add_info(&mut self, message: impl Into<String>) {
+        let message = message.into();
+        self.event_viewer.add_info(message.clone());
         self.template.add_info(message);
     }
 
     pub fn add_error(&mut self, message: impl Into<String>) {
+        let message = message.into();
+        self.event_viewer.add_error(message.clone());
         self.template.add_error(message);
     }
 
+    /// 実行履歴と償却原価スケジュールを切り替え
+    pub fn toggle_view(&mut self) {
+        self.view = match self.view {
+            IfrsValuationView::History => IfrsValuationView::Schedule,
+            IfrsValuationView::Schedule => IfrsValuationView::History,
+        };
+    }
+
+    pub fn is_schedule_view(&self) -> bool {
+        self.view == IfrsValuationView::Schedule
+    }
+
+    pub fn start_schedule_loading(&mut self) {
+        self.schedule_table.start_loading();
+    }
+
+    pub fn set_schedule_error(&mut self, error: String) {
+        self.schedule_table.set_error(error.clone());
+        self.event_viewer.add_error(error);
+    }
+
+    /// 金融商品を表示（選択中の金融商品はできるだけ維持する）
+    pub fn set_instruments(&mut self, instruments: Vec<FinancialInstrumentResponse>) {
+        let selected_id = self
+            .instruments
+            .get(self.selected_instrument)
+            .map(|instrument| instrument.instrument_id.clone());
+        self.selected_instrument = selected_id
+            .and_then(|id| instruments.iter().position(|instrument| instrument.instrument_id == id))
+            .unwrap_or(0);
+        self.instruments = instruments;
+        self.show_selected_instrument();
+    }
+
+    pub fn next_instrument(&mut self) {
+        if self.selected_instrument + 1 < self.instruments.len() {
+            self.selected_instrument += 1;
+            self.show_selected_instrument();
+        }
+    }
+
+    pub fn previous_instrument(&mut self) {
+        if self.selected_instrument > 0 {
+            self.selected_instrument -= 1;
+            self.show_selected_instrument();
+        }
+    }
+
+    fn show_selected_instrument(&mut self) {
+        self.instrument_panel.clear();
+        let Some(instrument) = self.instruments.get(self.selected_instrument) else {
+            self.schedule_table.set_data(vec![]);
+            self.instrument_panel.add_text("登録済みの金融商品はありません");
+            return;
+        };
+
+        let rows = instrument
+            .schedule
+            .iter()
+            .map(|line| {
+                vec![
+                    format!("{}-{:02}", line.fiscal_year, line.period),
+                    format_amount!(line.opening_carrying_amount, 14),
+                    format_amount!(line.effective_interest, 12),
+                    format_amount!(line.coupon_interest, 10),
+                    format_balance!(line.amortization, 10),
+                    format_amount!(line.closing_carrying_amount, 14),
+                    if line.accrued_entry_id.is_some() {
+                        "済"
+                    } else {
+                        ""
+                    }
+                    .to_string(),
+                ]
+            })
+            .collect();
+        self.schedule_table.set_data(rows);
+
+        let kind = match instrument.kind.as_str() {
+            "Bond" => "債券",
+            _ => "貸付金・借入金",
+        };
+        let position = match instrument.position.as_str() {
+            "Asset" => "金融資産",
+            _ => "金融負債",
+        };
+        self.instrument_panel.add_line(
+            "金融商品",
+            format!(
+                "{} ({}/{})",
+                instrument.instrument_id,
+                self.selected_instrument + 1,
+                self.instruments.len()
+            ),
+        );
+        self.instrument_panel.add_line("名称", &instrument.name);
+        self.instrument_panel.add_line("区分", format!("{} / {}", kind, position));
+        self.instrument_panel.add_line("勘定科目", &instrument.account_code);
+        self.instrument_panel.add_line(
+            "額面",
+            format!("{} {}", format_amount!(instrument.face_value), instrument.currency),
+        );
+        self.instrument_panel
+            .add_line("取得時帳簿価額", format_amount!(instrument.initial_carrying_amount));
+        self.instrument_panel.add_line(
+            "表面利率/実効金利",
+            format!(
+                "{:.3}% / {:.3}%",
+                instrument.coupon_rate * 100.0,
+                instrument.effective_rate * 100.0
+            ),
+        );
+        self.instrument_panel
+            .add_line("期間", format!("{} 〜 {}", instrument.start_date, instrument.maturity_date));
+    }
+
     pub fn select_next(&mut self) {
-        self.template.select_next();
+        match self.view {
+            IfrsValuationView::History => self.template.select_next(),
+            IfrsValuationView::Schedule => self.schedule_table.select_next(),
+        }
     }
 
     pub fn select_previous(&mut self) {
-        self.template.select_previous();
+        match self.view {
+            IfrsValuationView::History => self.template.select_previous(),
+            IfrsValuationView::Schedule => self.schedule_table.select_previous(),
+        }
     }
 
     pub fn tick(&mut self) {
+        self.animation_frame = (self.animation_frame + 1) % 60;
+        self.schedule_table.tick_loading();
         self.template.tick();
     }
 
     pub fn render(&mut self, frame: &mut Frame) {
-        self.template.render(frame);
+        match self.view {
+            IfrsValuationView::History => self.template.render(frame),
+            IfrsValuationView::Schedule => self.render_schedule(frame),
+        }
+    }
+
+    /// 償却原価スケジュールを描画
+    fn render_schedule(&mut self, frame: &mut Frame) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(10), Constraint::Length(3)])
+            .split(frame.area());
+
+        let main_chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(64), Constraint::Percentage(36)])
+            .split(chunks[0]);
+
+        let side_chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(11), Constraint::Min(6)])
+            .split(main_chunks[1]);
+
+        self.schedule_table.render(frame, main_chunks[0]);
+        self.instrument_panel.render(frame, side_chunks[0]);
+        self.event_viewer.render(frame, side_chunks[1]);
+        self.render_schedule_status_bar(frame, chunks[1]);
+    }
+
+    fn render_schedule_status_bar(&self, frame: &mut Frame, area: Rect) {
+        let cursor = if self.animation_frame < 30 {
+            "▮"
+        } else {
+            " "
+        };
+
+        let status_text = vec![Line::from(vec![
+            Span::styled(" [↑↓] ", Style::default().fg(Color::DarkGray)),
+            Span::styled("選択", Style::default().fg(Color::Gray)),
+            Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+            Span::styled("[←→] ", Style::default().fg(Color::DarkGray)),
+            Span::styled("金融商品", Style::default().fg(Color::Gray)),
+            Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+            Span::styled("[i] ", Style::default().fg(Color::DarkGray)),
+            Span::styled("取込", Style::default().fg(Color::Gray)),
+            Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+            Span::styled("[F5] ", Style::default().fg(Color::DarkGray)),
+            Span::styled("再読込", Style::default().fg(Color::Gray)),
+            Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+            Span::styled("[a] ", Style::default().fg(Color::DarkGray)),
+            Span::styled("実行履歴", Style::default().fg(Color::Gray)),
+            Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+            Span::styled("[Esc] ", Style::default().fg(Color::DarkGray)),
+            Span::styled("戻る", Style::default().fg(Color::Gray)),
+            Span::styled(
+                format!(" {}", cursor),
+                Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+            ),
+        ])];
+
+        let paragraph = Paragraph::new(status_text).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Plain)
+                .border_style(Style::default().fg(Color::DarkGray)),
+        );
+
+        frame.render_widget(paragraph, area);
     }
 }
 
@@ -0,0 +1,276 @@
+// InboxDetailPage - 受信箱項目の詳細画面のビューコンポーネント
+// 責務: 承認待ち・差戻しとなった仕訳の内容と、現在のステータスで可能な操作の表示
+
+use javelin_application::dtos::JournalActionAffordance;
+use javelin_domain::financial_close::journal_entry::values::JournalAction;
+use ratatui::{
+    Frame,
+    layout::{Constraint, Layout},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, Wrap},
+};
+
+use crate::presenter::{InboxItemViewModel, JournalActionViewModel};
+
+#[derive(Default)]
+pub struct InboxDetailPage {
+    item: Option<InboxItemViewModel>,
+    /// 入力中の差戻理由（入力中でない場合はNone）
+    reason_input: Option<String>,
+    /// 承認・差戻しの実行中
+    processing: bool,
+    /// 直前の操作の結果（成功したかどうかとメッセージ）
+    status_message: Option<(bool, String)>,
+}
+
+impl InboxDetailPage {
+    pub fn new(item: InboxItemViewModel) -> Self {
+        Self { item: Some(item), ..Self::default() }
+    }
+
+    /// 操作を実行できる場合は対象の仕訳ID、できない場合は理由を返す
+    pub fn check_action(&self, action: JournalAction) -> Result<String, String> {
+        let item = self.item.as_ref().ok_or_else(|| "表示する項目がありません".to_string())?;
+        if self.processing {
+            return Err("処理中です。完了までお待ちください".to_string());
+        }
+        match item.actions.iter().find(|a| a.action == action) {
+            Some(a) if a.enabled => Ok(item.entry_id.clone()),
+            Some(a) => Err(a.reason.clone()),
+            None => Err(format!("この仕訳は{}できません", action.display_name())),
+        }
+    }
+
+    /// 差戻理由の入力を開始
+    pub fn start_reason_input(&mut self) {
+        self.reason_input = Some(String::new());
+        self.status_message = None;
+    }
+
+    pub fn is_entering_reason(&self) -> bool {
+        self.reason_input.is_some()
+    }
+
+    pub fn input_reason_char(&mut self, c: char) {
+        if let Some(input) = &mut self.reason_input {
+            input.push(c);
+        }
+    }
+
+    pub fn delete_reason_char(&mut self) {
+        if let Some(input) = &mut self.reason_input {
+            input.pop();
+        }
+    }
+
+    pub fn cancel_reason_input(&mut self) {
+        self.reason_input = None;
+    }
+
+    /// 入力した差戻理由を取り出して入力を終了
+    pub fn take_reason_input(&mut self) -> Option<String> {
+        self.reason_input.take()
+    }
+
+    /// 承認・差戻しの実行を開始
+    pub fn start_processing(&mut self, message: impl Into<String>) {
+        self.processing = true;
+        self.status_message = Some((true, message.into()));
+    }
+
+    /// 操作の完了を反映（操作後のステータスで可能な操作を表示し直す）
+    pub fn complete(&mut self, status: &str, message: impl Into<String>) {
+        self.processing = false;
+        self.status_message = Some((true, message.into()));
+        if let Some(item) = &mut self.item {
+            item.actions = JournalActionViewModel::from_affordances(
+                &JournalActionAffordance::for_status(status),
+            );
+        }
+    }
+
+    /// 操作の失敗または実行できない理由を表示
+    pub fn set_error(&mut self, message: impl Into<String>) {
+        self.processing = false;
+        self.status_message = Some((false, message.into()));
+    }
+
+    pub fn render(&mut self, frame: &mut Frame) {
+        let area = frame.area();
+
+        let Some(item) = &self.item else {
+            let empty = Paragraph::new("表示する項目がありません")
+                .block(Block::default().borders(Borders::ALL).title("受信箱 - 詳細"));
+            frame.render_widget(empty, area);
+            return;
+        };
+
+        let chunks = Layout::vertical([
+            Constraint::Length(8),
+            Constraint::Length(item.actions.len() as u16 + 2),
+            Constraint::Min(0),
+            Constraint::Length(4),
+        ])
+        .split(area);
+
+        let label = Style::default().fg(Color::DarkGray);
+        let value = Style::default().fg(Color::White);
+        let mut summary = vec![
+            Line::from(vec![
+                Span::styled("種別:     ", label),
+                Span::styled(
+                    item.kind_label.as_str(),
+                    Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+                ),
+            ]),
+            Line::from(vec![
+                Span::styled("伝票番号: ", label),
+                Span::styled(item.voucher_number.as_str(), value),
+                Span::styled("  取引日: ", label),
+                Span::styled(item.transaction_date.as_str(), value),
+            ]),
+            Line::from(vec![
+                Span::styled("依頼者:   ", label),
+                Span::styled(item.from_user.as_str(), value),
+                Span::styled("  日時: ", label),
+                Span::styled(item.occurred_at.as_str(), value),
+            ]),
+            Line::from(vec![
+                Span::styled("仕訳ID:   ", label),
+                Span::styled(item.entry_id.as_str(), Style::default().fg(Color::DarkGray)),
+            ]),
+        ];
+        if let Some(reason) = &item.reason {
+            summary.push(Line::from(vec![
+                Span::styled("差戻理由: ", label),
+                Span::styled(reason.as_str(), Style::default().fg(Color::Red)),
+            ]));
+        }
+
+        let summary_widget = Paragraph::new(summary)
+            .wrap(Wrap { trim: true })
+            .block(Block::default().borders(Borders::ALL).title("受信箱 - 詳細"));
+        frame.render_widget(summary_widget, chunks[0]);
+
+        // 不可の操作は淡色で理由と共に表示する
+        let actions: Vec<Line> = item
+            .actions
+            .iter()
+            .map(|action| {
+                if action.enabled {
+                    Line::from(Span::styled(
+                        format!("● {}", action.label),
+                        Style::default().fg(Color::Green).add_modifier(Modifier::BOLD),
+                    ))
+                } else {
+                    Line::from(vec![
+                        Span::styled(
+                            format!("○ {}", action.label),
+                            Style::default().fg(Color::DarkGray),
+                        ),
+                        Span::styled(
+                            format!("  {}", action.reason),
+                            Style::default().fg(Color::DarkGray).add_modifier(Modifier::ITALIC),
+                        ),
+                    ])
+                }
+            })
+            .collect();
+        let actions_widget =
+            Paragraph::new(actions).block(Block::default().borders(Borders::ALL).title("操作"));
+        frame.render_widget(actions_widget, chunks[1]);
+
+        let header = Row::new(vec!["貸借", "勘定科目", "金額"])
+            .style(Style::default().add_modifier(Modifier::BOLD));
+        let rows: Vec<Row> = item
+            .lines
+            .iter()
+            .map(|line| {
+                Row::new(vec![
+                    Cell::from(line.side_label.as_str()),
+                    Cell::from(line.account_code.as_str()),
+                    Cell::from(format!("{:>14.0}", line.amount)),
+                ])
+            })
+            .collect();
+        let lines_table = Table::new(
+            rows,
+            [Constraint::Length(6), Constraint::Length(12), Constraint::Length(15)],
+        )
+        .header(header)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!("明細 ({}行)", item.lines.len())),
+        );
+        frame.render_widget(lines_table, chunks[2]);
+
+        // 差戻理由の入力中は入力欄、それ以外は直前の操作の結果とキー操作
+        let status_bar = if let Some(input) = &self.reason_input {
+            Paragraph::new(Line::from(vec![
+                Span::styled("差戻理由: ", Style::default().fg(Color::Yellow)),
+                Span::raw(input.as_str()),
+                Span::styled("▮", Style::default().fg(Color::Yellow)),
+            ]))
+            .block(Block::default().borders(Borders::ALL).title("[Enter] 差戻し [Esc] キャンセル"))
+        } else {
+            let mut lines = vec![Line::from("[a] 承認 [d] 差戻し [Esc] 戻る")];
+            if let Some((success, message)) = &self.status_message {
+                let color = if *success { Color::Green } else { Color::Red };
+                lines.push(Line::from(Span::styled(message.as_str(), Style::default().fg(color))));
+            }
+            Paragraph::new(lines).block(Block::default().borders(Borders::ALL))
+        };
+        frame.render_widget(status_bar, chunks[3]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use javelin_application::query_service::{InboxItem, InboxItemKind};
+    use javelin_domain::masters::ApprovalSlaSettings;
+
+    use super::*;
+
+    fn detail_page(kind: InboxItemKind) -> InboxDetailPage {
+        let item = InboxItem {
+            kind,
+            entry_id: "entry-1".to_string(),
+            voucher_number: "V-0001".to_string(),
+            transaction_date: "2024-04-01".to_string(),
+            from_user: "user1".to_string(),
+            reason: None,
+            occurred_at: "2024-04-01T08:00:00Z".to_string(),
+            lines: vec![],
+            actions: JournalActionAffordance::for_status(kind.status()),
+        };
+        InboxDetailPage::new(InboxItemViewModel::from_item(
+            &item,
+            ApprovalSlaSettings::default(),
+            Utc::now(),
+        ))
+    }
+
+    #[test]
+    fn test_actions_follow_status_and_completion() {
+        let mut page = detail_page(InboxItemKind::AwaitingApproval);
+        assert_eq!(page.check_action(JournalAction::Approve), Ok("entry-1".to_string()));
+
+        page.start_processing("承認しています...");
+        assert!(page.check_action(JournalAction::Approve).is_err());
+
+        // 承認後は記帳済みの仕訳として、承認・差戻しとも不可になる
+        page.complete("Posted", "承認しました");
+        assert!(page.check_action(JournalAction::Approve).is_err());
+        assert!(page.check_action(JournalAction::Reject).is_err());
+
+        // 差し戻された下書きは承認できない（理由を返す）
+        let page = detail_page(InboxItemKind::Rejected);
+        assert_eq!(
+            page.check_action(JournalAction::Approve),
+            Err("下書きの仕訳は承認できません（承認待ちのみ）".to_string())
+        );
+    }
+}
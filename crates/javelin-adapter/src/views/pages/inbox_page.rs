@@ -0,0 +1,191 @@
+// InboxPage - 受信箱画面のビューコンポーネント
+// 責務: 承認待ち・差戻しなど対応が必要な項目の一覧表示
+
+use javelin_domain::masters::ApprovalAging;
+use ratatui::{
+    Frame,
+    layout::{Constraint, Layout},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState},
+};
+
+use crate::presenter::{InboxItemViewModel, InboxViewModel};
+
+#[derive(Debug, Clone, PartialEq)]
+enum LoadingState {
+    Loading,
+    Loaded,
+    Error(String),
+}
+
+pub struct InboxPage {
+    user_id: String,
+    view_model: InboxViewModel,
+    table_state: TableState,
+    loading_state: LoadingState,
+}
+
+impl InboxPage {
+    pub fn new() -> Self {
+        Self {
+            user_id: String::new(),
+            view_model: InboxViewModel::default(),
+            table_state: TableState::default(),
+            loading_state: LoadingState::Loading,
+        }
+    }
+
+    pub fn set_user_id(&mut self, user_id: impl Into<String>) {
+        self.user_id = user_id.into();
+    }
+
+    /// 受信箱を設定（選択位置は可能な限り維持）
+    pub fn set_data(&mut self, view_model: InboxViewModel) {
+        let selected = self.table_state.selected().unwrap_or(0);
+        let selected = if view_model.items.is_empty() {
+            None
+        } else {
+            Some(selected.min(view_model.items.len() - 1))
+        };
+        self.table_state.select(selected);
+        self.view_model = view_model;
+        self.loading_state = LoadingState::Loaded;
+    }
+
+    pub fn set_error(&mut self, error: String) {
+        self.loading_state = LoadingState::Error(error);
+    }
+
+    /// 選択中の項目
+    pub fn selected_item(&self) -> Option<&InboxItemViewModel> {
+        self.table_state.selected().and_then(|index| self.view_model.items.get(index))
+    }
+
+    pub fn select_next(&mut self) {
+        let len = self.view_model.items.len();
+        if len > 0 {
+            let next = self.table_state.selected().map_or(0, |i| (i + 1).min(len - 1));
+            self.table_state.select(Some(next));
+        }
+    }
+
+    pub fn select_previous(&mut self) {
+        if !self.view_model.items.is_empty() {
+            let previous = self.table_state.selected().map_or(0, |i| i.saturating_sub(1));
+            self.table_state.select(Some(previous));
+        }
+    }
+
+    pub fn render(&mut self, frame: &mut Frame) {
+        let area = frame.area();
+
+        if self.loading_state == LoadingState::Loading {
+            let loading = Paragraph::new("読み込み中...")
+                .block(Block::default().borders(Borders::ALL).title("受信箱"));
+            frame.render_widget(loading, area);
+            return;
+        }
+
+        if let LoadingState::Error(error) = &self.loading_state {
+            let error_widget = Paragraph::new(error.as_str())
+                .style(Style::default().fg(Color::Red))
+                .block(Block::default().borders(Borders::ALL).title("エラー"));
+            frame.render_widget(error_widget, area);
+            return;
+        }
+
+        let chunks = Layout::vertical([Constraint::Min(0), Constraint::Length(3)]).split(area);
+        let summary = self.view_model.summary;
+        let title = if summary.pending > 0 {
+            format!(
+                "受信箱 - {} ({}件) 承認待ち {}件 / 注意 {}件 / SLA超過 {}件",
+                self.user_id,
+                self.view_model.items.len(),
+                summary.pending,
+                summary.warning,
+                summary.breached
+            )
+        } else {
+            format!("受信箱 - {} ({}件)", self.user_id, self.view_model.items.len())
+        };
+
+        if self.view_model.items.is_empty() {
+            let empty = Paragraph::new("対応が必要な項目はありません")
+                .style(Style::default().fg(Color::Green))
+                .block(Block::default().borders(Borders::ALL).title(title));
+            frame.render_widget(empty, chunks[0]);
+        } else {
+            let header =
+                Row::new(vec!["種別", "日時", "経過", "伝票番号", "取引日", "金額", "依頼者"])
+                    .style(Style::default().add_modifier(Modifier::BOLD));
+
+            let rows: Vec<Row> = self
+                .view_model
+                .items
+                .iter()
+                .map(|item| {
+                    let kind_color = match item.reason {
+                        Some(_) => Color::Red,
+                        None => Color::Yellow,
+                    };
+                    Row::new(vec![
+                        Cell::from(item.kind_label.as_str()).style(Style::default().fg(kind_color)),
+                        Cell::from(item.occurred_at.as_str()),
+                        Cell::from(item.aging_label.as_str())
+                            .style(Style::default().fg(aging_color(item.aging))),
+                        Cell::from(item.voucher_number.as_str()),
+                        Cell::from(item.transaction_date.as_str()),
+                        Cell::from(format!("{:>14.0}", item.total_amount)),
+                        Cell::from(item.from_user.as_str()),
+                    ])
+                })
+                .collect();
+
+            let table = Table::new(
+                rows,
+                [
+                    Constraint::Length(10),
+                    Constraint::Length(17),
+                    Constraint::Length(10),
+                    Constraint::Length(16),
+                    Constraint::Length(11),
+                    Constraint::Length(15),
+                    Constraint::Min(10),
+                ],
+            )
+            .header(header)
+            .row_highlight_style(Style::default().bg(Color::DarkGray).add_modifier(Modifier::BOLD))
+            .block(Block::default().borders(Borders::ALL).title(title));
+
+            frame.render_stateful_widget(table, chunks[0], &mut self.table_state);
+        }
+
+        let status_bar = Paragraph::new(Line::from(vec![
+            Span::raw("[↑↓] 選択 [Enter] 詳細 [r] 再読込 [Esc] 戻る"),
+            Span::styled(
+                format!("  承認SLA: {}", self.view_model.sla_label),
+                Style::default().fg(Color::DarkGray),
+            ),
+        ]))
+        .block(Block::default().borders(Borders::ALL));
+
+        frame.render_widget(status_bar, chunks[1]);
+    }
+}
+
+/// 承認待ちの滞留状況に応じた表示色（承認待ち以外は既定色）
+fn aging_color(aging: Option<ApprovalAging>) -> Color {
+    match aging {
+        Some(ApprovalAging::WithinSla) => Color::Green,
+        Some(ApprovalAging::Warning) => Color::Yellow,
+        Some(ApprovalAging::Breached) => Color::Red,
+        None => Color::Reset,
+    }
+}
+
+impl Default for InboxPage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,242 @@
+// InventoryWorksheetPage - 棚卸資産評価ワークシート画面
+// 責務: 品目別評価データの取込と評価減の算定結果の表示
+
+use javelin_application::dtos::response::InventoryWorksheetResponse;
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph},
+};
+
+use crate::{
+    format_amount,
+    views::components::{DataTable, EventViewer, InfoPanel},
+};
+
+/// 評価減の算定単位（取込時に指定）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WorksheetMethod {
+    ItemByItem,
+    ProductGroup,
+}
+
+impl WorksheetMethod {
+    pub fn code(&self) -> &'static str {
+        match self {
+            WorksheetMethod::ItemByItem => "ItemByItem",
+            WorksheetMethod::ProductGroup => "ProductGroup",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            WorksheetMethod::ItemByItem => "品目別",
+            WorksheetMethod::ProductGroup => "品目グループ別",
+        }
+    }
+
+    fn from_code(code: &str) -> Self {
+        match code {
+            "ProductGroup" => WorksheetMethod::ProductGroup,
+            _ => WorksheetMethod::ItemByItem,
+        }
+    }
+
+    fn toggled(&self) -> Self {
+        match self {
+            WorksheetMethod::ItemByItem => WorksheetMethod::ProductGroup,
+            WorksheetMethod::ProductGroup => WorksheetMethod::ItemByItem,
+        }
+    }
+}
+
+pub struct InventoryWorksheetPage {
+    write_down_table: DataTable,
+    info_panel: InfoPanel,
+    event_viewer: EventViewer,
+    /// 次回取込時の算定単位
+    method: WorksheetMethod,
+    /// 取込ファイル名
+    import_file_name: String,
+    animation_frame: usize,
+}
+
+impl InventoryWorksheetPage {
+    pub fn new(import_file_name: impl Into<String>) -> Self {
+        let headers = vec![
+            "算定単位".to_string(),
+            "取得原価".to_string(),
+            "正味実現可能価額".to_string(),
+            "評価減".to_string(),
+        ];
+
+        let write_down_table = DataTable::new("◆ 棚卸資産評価ワークシート ◆", headers)
+            .with_column_widths(vec![20, 16, 18, 16]);
+
+        let import_file_name = import_file_name.into();
+        let mut event_viewer = EventViewer::new();
+        event_viewer.add_info("棚卸資産評価ワークシートを開きました");
+        event_viewer.add_info(format!(
+            "[i] で {} を取り込みます（品目コード,品目名,品目グループ,数量,単価,正味実現可能価額単価）",
+            import_file_name
+        ));
+
+        Self {
+            write_down_table,
+            info_panel: InfoPanel::new("◇ ワークシート ◇").with_border_color(Color::Cyan),
+            event_viewer,
+            method: WorksheetMethod::ItemByItem,
+            import_file_name,
+            animation_frame: 0,
+        }
+    }
+
+    /// ワークシートを表示
+    pub fn set_worksheet(&mut self, worksheet: &InventoryWorksheetResponse) {
+        let rows = worksheet
+            .write_downs
+            .iter()
+            .map(|line| {
+                vec![
+                    line.item.clone(),
+                    format_amount!(line.cost, 14),
+                    format_amount!(line.net_realizable_value, 14),
+                    format_amount!(line.write_down_amount, 14),
+                ]
+            })
+            .collect();
+        self.write_down_table.set_data(rows);
+        self.method = WorksheetMethod::from_code(&worksheet.method);
+
+        self.info_panel.clear();
+        self.info_panel.add_line("ワークシート", &worksheet.worksheet_id);
+        self.info_panel
+            .add_line("会計期間", format!("{}-{:02}", worksheet.fiscal_year, worksheet.period));
+        self.info_panel.add_line("算定単位", self.method.label());
+        self.info_panel.add_line("品目数", worksheet.item_count.to_string());
+        self.info_panel.add_line(
+            "評価減合計",
+            format!("{} {}", format_amount!(worksheet.total_write_down), worksheet.currency),
+        );
+        self.info_panel.add_line(
+            "評価減仕訳",
+            worksheet.posted_entry_id.as_deref().unwrap_or("未起票（IFRS評価で起票）"),
+        );
+    }
+
+    /// ワークシート未作成
+    pub fn set_empty(&mut self) {
+        self.write_down_table.set_data(vec![]);
+        self.info_panel.clear();
+        self.info_panel.add_text("この会計期間のワークシートはありません");
+    }
+
+    pub fn set_error(&mut self, error: String) {
+        self.write_down_table.set_error(error.clone());
+        self.event_viewer.add_error(error);
+    }
+
+    pub fn start_loading(&mut self) {
+        self.write_down_table.start_loading();
+    }
+
+    pub fn method(&self) -> WorksheetMethod {
+        self.method
+    }
+
+    pub fn import_file_name(&self) -> &str {
+        &self.import_file_name
+    }
+
+    /// 取込時の算定単位を切り替え
+    pub fn toggle_method(&mut self) {
+        self.method = self.method.toggled();
+        self.event_viewer
+            .add_info(format!("算定単位: {}（次回の取込から適用）", self.method.label()));
+    }
+
+    pub fn add_info(&mut self, message: impl Into<String>) {
+        self.event_viewer.add_info(message);
+    }
+
+    pub fn add_error(&mut self, message: impl Into<String>) {
+        self.event_viewer.add_error(message);
+    }
+
+    pub fn select_next(&mut self) {
+        self.write_down_table.select_next();
+    }
+
+    pub fn select_previous(&mut self) {
+        self.write_down_table.select_previous();
+    }
+
+    pub fn tick(&mut self) {
+        self.animation_frame = (self.animation_frame + 1) % 60;
+        self.write_down_table.tick_loading();
+    }
+
+    pub fn render(&mut self, frame: &mut Frame) {
+        let area = frame.area();
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(10), Constraint::Length(3)])
+            .split(area);
+
+        let main_chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(62), Constraint::Percentage(38)])
+            .split(chunks[0]);
+
+        let side_chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(10), Constraint::Min(6)])
+            .split(main_chunks[1]);
+
+        self.write_down_table.render(frame, main_chunks[0]);
+        self.info_panel.render(frame, side_chunks[0]);
+        self.event_viewer.render(frame, side_chunks[1]);
+        self.render_status_bar(frame, chunks[1]);
+    }
+
+    fn render_status_bar(&self, frame: &mut Frame, area: Rect) {
+        let cursor = if self.animation_frame < 30 {
+            "▮"
+        } else {
+            " "
+        };
+
+        let status_text = vec![Line::from(vec![
+            Span::styled(" [↑↓] ", Style::default().fg(Color::DarkGray)),
+            Span::styled("選択", Style::default().fg(Color::Gray)),
+            Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+            Span::styled("[i] ", Style::default().fg(Color::DarkGray)),
+            Span::styled("取込", Style::default().fg(Color::Gray)),
+            Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+            Span::styled("[m] ", Style::default().fg(Color::DarkGray)),
+            Span::styled(
+                format!("算定単位: {}", self.method.label()),
+                Style::default().fg(Color::Gray),
+            ),
+            Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+            Span::styled("[Esc] ", Style::default().fg(Color::DarkGray)),
+            Span::styled("戻る", Style::default().fg(Color::Gray)),
+            Span::styled(
+                format!(" {}", cursor),
+                Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+            ),
+        ])];
+
+        let paragraph = Paragraph::new(status_text).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Plain)
+                .border_style(Style::default().fg(Color::DarkGray)),
+        );
+
+        frame.render_widget(paragraph, area);
+    }
+}
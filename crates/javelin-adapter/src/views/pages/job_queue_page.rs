@@ -0,0 +1,202 @@
+// JobQueuePage - 長時間処理ジョブの一覧画面
+// 責務: ジョブの状態・進捗の一覧と、選択したジョブの詳細の表示
+
+use javelin_application::dtos::response::JobResponse;
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph},
+};
+
+use crate::views::components::{DataTable, EventViewer, InfoPanel};
+
+pub struct JobQueuePage {
+    job_table: DataTable,
+    info_panel: InfoPanel,
+    event_viewer: EventViewer,
+    jobs: Vec<JobResponse>,
+    animation_frame: usize,
+}
+
+impl JobQueuePage {
+    pub fn new() -> Self {
+        let headers = vec![
+            "種別".to_string(),
+            "状態".to_string(),
+            "進捗".to_string(),
+            "依頼者".to_string(),
+            "登録日時".to_string(),
+        ];
+
+        let job_table =
+            DataTable::new("◆ ジョブ一覧 ◆", headers).with_column_widths(vec![16, 8, 6, 14, 20]);
+
+        let mut event_viewer = EventViewer::new();
+        event_viewer.add_info("ジョブはアプリケーションを終了しても記録が残ります");
+
+        Self {
+            job_table,
+            info_panel: InfoPanel::new("◇ ジョブ詳細 ◇").with_border_color(Color::Cyan),
+            event_viewer,
+            jobs: Vec::new(),
+            animation_frame: 0,
+        }
+    }
+
+    /// 一覧を表示（選択位置は維持する）
+    pub fn set_jobs(&mut self, jobs: Vec<JobResponse>) {
+        let rows = jobs
+            .iter()
+            .map(|job| {
+                vec![
+                    job.kind_label.clone(),
+                    job.status_label.clone(),
+                    format!("{}%", job.progress),
+                    job.requested_by.clone(),
+                    crate::clock::format_timestamp(&job.created_at),
+                ]
+            })
+            .collect();
+        self.job_table.set_data(rows);
+        self.jobs = jobs;
+        self.show_selected_job();
+    }
+
+    /// 選択中のジョブ
+    pub fn selected(&self) -> Option<&JobResponse> {
+        self.job_table.selected_index().and_then(|index| self.jobs.get(index))
+    }
+
+    pub fn set_error(&mut self, error: String) {
+        self.jobs.clear();
+        self.job_table.set_error(error.clone());
+        self.info_panel.clear();
+        self.event_viewer.add_error(error);
+    }
+
+    pub fn start_loading(&mut self) {
+        self.job_table.start_loading();
+    }
+
+    pub fn add_info(&mut self, message: impl Into<String>) {
+        self.event_viewer.add_info(message);
+    }
+
+    pub fn add_error(&mut self, message: impl Into<String>) {
+        self.event_viewer.add_error(message);
+    }
+
+    pub fn select_next(&mut self) {
+        self.job_table.select_next();
+        self.show_selected_job();
+    }
+
+    pub fn select_previous(&mut self) {
+        self.job_table.select_previous();
+        self.show_selected_job();
+    }
+
+    fn show_selected_job(&mut self) {
+        self.info_panel.clear();
+        let Some(job) = self.selected().cloned() else {
+            self.info_panel.add_text("ジョブはありません");
+            return;
+        };
+
+        self.info_panel.add_line("ジョブID", job.job_id);
+        self.info_panel.add_line("種別", job.kind_label);
+        for (key, value) in &job.parameters {
+            self.info_panel.add_line(key.as_str(), value.as_str());
+        }
+        self.info_panel.add_line("実行回数", job.attempts.to_string());
+        self.info_panel
+            .add_line("更新日時", crate::clock::format_timestamp(&job.updated_at));
+        let message = job.message.unwrap_or_default();
+        match job.status.as_str() {
+            "Completed" => self.info_panel.add_success(format!("完了: {}", message)),
+            "Failed" => self.info_panel.add_error(format!("失敗: {}", message)),
+            "Cancelled" => self.info_panel.add_warning(format!("取消: {}", message)),
+            _ => self
+                .info_panel
+                .add_line(job.status_label.as_str(), format!("{}% {}", job.progress, message)),
+        }
+    }
+
+    pub fn tick(&mut self) {
+        self.animation_frame = (self.animation_frame + 1) % 60;
+        self.job_table.tick_loading();
+    }
+
+    pub fn render(&mut self, frame: &mut Frame) {
+        let area = frame.area();
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(10), Constraint::Length(3)])
+            .split(area);
+
+        let main_chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(55), Constraint::Percentage(45)])
+            .split(chunks[0]);
+
+        let side_chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(10), Constraint::Length(8)])
+            .split(main_chunks[1]);
+
+        self.job_table.render(frame, main_chunks[0]);
+        self.info_panel.render(frame, side_chunks[0]);
+        self.event_viewer.render(frame, side_chunks[1]);
+        self.render_status_bar(frame, chunks[1]);
+    }
+
+    fn render_status_bar(&self, frame: &mut Frame, area: Rect) {
+        let cursor = if self.animation_frame < 30 {
+            "▮"
+        } else {
+            " "
+        };
+
+        let status_text = vec![Line::from(vec![
+            Span::styled(" [↑↓] ", Style::default().fg(Color::DarkGray)),
+            Span::styled("選択", Style::default().fg(Color::Gray)),
+            Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+            Span::styled("[x] ", Style::default().fg(Color::DarkGray)),
+            Span::styled("取消", Style::default().fg(Color::Gray)),
+            Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+            Span::styled("[t] ", Style::default().fg(Color::DarkGray)),
+            Span::styled("再実行", Style::default().fg(Color::Gray)),
+            Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+            Span::styled("[R] ", Style::default().fg(Color::DarkGray)),
+            Span::styled("Projection再構築", Style::default().fg(Color::Gray)),
+            Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+            Span::styled("[r] ", Style::default().fg(Color::DarkGray)),
+            Span::styled("再読込", Style::default().fg(Color::Gray)),
+            Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+            Span::styled("[Esc] ", Style::default().fg(Color::DarkGray)),
+            Span::styled("戻る", Style::default().fg(Color::Gray)),
+            Span::styled(
+                format!(" {}", cursor),
+                Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+            ),
+        ])];
+
+        let paragraph = Paragraph::new(status_text).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Plain)
+                .border_style(Style::default().fg(Color::DarkGray)),
+        );
+
+        frame.render_widget(paragraph, area);
+    }
+}
+
+impl Default for JobQueuePage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
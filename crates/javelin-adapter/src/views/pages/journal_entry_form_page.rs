@@ -1,18 +1,27 @@
 // JournalEntryFormPage - 原始記録登録画面
 // 責務: 仕訳入力フォーム（4.1 原始記録登録処理）
 
-use javelin_application::dtos::{JournalEntryLineDto, RegisterJournalEntryRequest};
+use chrono::NaiveDate;
+use javelin_application::dtos::{
+    JournalEntryLineDto, RegisterJournalEntryRequest, request::parse_transaction_date,
+};
 use ratatui::{
     Frame,
     layout::{Constraint, Direction, Layout},
-    style::{Color, Style},
+    style::{Color, Modifier, Style},
     text::{Line, Span},
+    widgets::Paragraph,
 };
 
 use crate::{
+    format_number,
     input_mode::{InputMode, JjEscapeDetector, JournalEntryEditMode, ModifyInputType},
+    presenter::ProgressReceiver,
     views::{
-        components::{InputField, LoadingSpinner, OverlaySelector, TabbedJournalEntryForm},
+        components::{
+            BalanceSide, CalendarPicker, FieldHelpOverlay, InputField, LoadingSpinner,
+            OverlaySelector, TabbedJournalEntryForm, TaxCalculator,
+        },
         layouts::FormLayout,
     },
 };
@@ -35,26 +44,36 @@ pub struct JournalEntryFormPage {
     // ヘッダーフィールド
     date_field: InputField,
     voucher_field: InputField,
+    // 伝票の摘要（摘要のない明細に引き継がれる）
+    description_field: InputField,
     risk_field: InputField,
     // 明細行フォーム（タブ付き）
     tabbed_form: TabbedJournalEntryForm,
     // 状態
-    focused_field: usize, // 0-2: ヘッダー, 3-7: 明細行
+    focused_field: usize, // 0-3: ヘッダー, 4-8: 明細行
     // Vimライク操作
     input_mode: InputMode,
     jj_detector: JjEscapeDetector,
     // オーバーレイセレクタ
     overlay_selector: OverlaySelector,
+    // カレンダー選択オーバーレイ
+    calendar_picker: CalendarPicker,
+    field_help: FieldHelpOverlay,
+    // 電卓（税込・税抜換算）
+    tax_calculator: TaxCalculator,
     // データロード要求フラグ
     pending_account_load: bool,
     // AccountMasterデータ受信用（オプション）
     account_master_receiver:
         Option<tokio::sync::mpsc::UnboundedReceiver<crate::presenter::AccountMasterViewModel>>,
+    // CalendarMasterデータ受信用（オプション）
+    calendar_master_receiver:
+        Option<tokio::sync::mpsc::UnboundedReceiver<crate::presenter::CalendarMasterViewModel>>,
     // JournalEntryViewModel受信用（オプション）
     result_receiver:
         Option<tokio::sync::mpsc::UnboundedReceiver<crate::presenter::JournalEntryViewModel>>,
     // 進捗メッセージ受信用（オプション）
-    progress_receiver: Option<tokio::sync::mpsc::UnboundedReceiver<String>>,
+    progress_receiver: Option<ProgressReceiver>,
     // 確定処理の状態
     submit_state: SubmitState,
     submit_error_message: Option<String>,
@@ -67,7 +86,7 @@ impl JournalEntryFormPage {
         layout.event_viewer_mut().add_info("原始記録登録画面を開きました");
 
         // 取引日付のデフォルト値を当日に設定（8桁の数字形式: YYYYMMDD）
-        let today = chrono::Local::now().format("%Y%m%d").to_string();
+        let today = crate::clock::business_date().format("%Y%m%d").to_string();
 
         let mut page = Self {
             layout,
@@ -79,15 +98,26 @@ impl JournalEntryFormPage {
                 .with_max_length(10)
                 .with_value(today)
                 .with_input_type(ModifyInputType::Calendar),
-            voucher_field: InputField::new("伝票番号").with_placeholder("自動採番").readonly(),
+            voucher_field: InputField::new("伝票番号")
+                .with_placeholder("自動採番")
+                .readonly()
+                .with_help("登録時に自動で採番されます"),
+            description_field: InputField::new("伝票摘要")
+                .with_placeholder("明細の摘要が空欄の場合に引き継がれます")
+                .with_max_length(200)
+                .with_help("伝票全体の摘要です。摘要を入力していない明細に引き継がれます"),
             risk_field: InputField::new("リスク分類").with_value("Low").readonly(),
             tabbed_form: TabbedJournalEntryForm::new(),
             focused_field: 0,
             input_mode: InputMode::Normal,
             jj_detector: JjEscapeDetector::new(),
             overlay_selector: OverlaySelector::new("選択してください"),
+            calendar_picker: CalendarPicker::new(),
+            field_help: FieldHelpOverlay::new(),
+            tax_calculator: TaxCalculator::new(),
             pending_account_load: false,
             account_master_receiver: None,
+            calendar_master_receiver: None,
             result_receiver: None,
             progress_receiver: None,
             submit_state: SubmitState::Idle,
@@ -117,6 +147,170 @@ impl JournalEntryFormPage {
         }
     }
 
+    /// 現在の明細行を複製
+    pub fn duplicate_line(&mut self) {
+        if self.input_mode.is_modify() {
+            return;
+        }
+        self.tabbed_form.duplicate_line();
+        self.layout.event_viewer_mut().add_info(format!(
+            "明細行を複製し #{} に挿入しました",
+            self.tabbed_form.current_line_index() + 1
+        ));
+    }
+
+    /// 現在の明細行の金額を2行に分割
+    pub fn split_line(&mut self) {
+        if self.input_mode.is_modify() {
+            return;
+        }
+        if self.tabbed_form.split_line() {
+            self.layout.event_viewer_mut().add_info(format!(
+                "明細行を #{} と #{} に分割しました",
+                self.tabbed_form.current_line_index(),
+                self.tabbed_form.current_line_index() + 1
+            ));
+        } else {
+            self.layout.event_viewer_mut().add_info("分割できる金額が入力されていません");
+        }
+    }
+
+    /// 同一科目の明細行を統合
+    pub fn merge_same_account_lines(&mut self) {
+        if self.input_mode.is_modify() {
+            return;
+        }
+        match self.tabbed_form.merge_same_account_lines() {
+            0 => self.layout.event_viewer_mut().add_info("統合できる明細行はありません"),
+            merged => self
+                .layout
+                .event_viewer_mut()
+                .add_info(format!("同一科目の明細行を{}行統合しました", merged)),
+        }
+    }
+
+    /// 現在の明細行を前へ移動
+    pub fn move_line_up(&mut self) {
+        if self.input_mode.is_modify() {
+            return;
+        }
+        if self.tabbed_form.move_line_up() {
+            self.layout.event_viewer_mut().add_info(format!(
+                "明細行を #{} へ移動しました",
+                self.tabbed_form.current_line_index() + 1
+            ));
+        }
+    }
+
+    /// 現在の明細行を後ろへ移動
+    pub fn move_line_down(&mut self) {
+        if self.input_mode.is_modify() {
+            return;
+        }
+        if self.tabbed_form.move_line_down() {
+            self.layout.event_viewer_mut().add_info(format!(
+                "明細行を #{} へ移動しました",
+                self.tabbed_form.current_line_index() + 1
+            ));
+        }
+    }
+
+    /// 貸借の差額を現在の明細行へ反映
+    ///
+    /// 借方側の項目にフォーカスがある場合は借方金額、貸方側の場合は貸方金額へ、
+    /// それ以外は合計の少ない側へ反映する。
+    pub fn balance_remaining(&mut self) {
+        if self.input_mode.is_modify() {
+            return;
+        }
+        let line_number = self.tabbed_form.current_line_index() + 1;
+        match self.tabbed_form.balance_current_line(self.focused_side()) {
+            Some((side, amount)) => self.layout.event_viewer_mut().add_info(format!(
+                "差額 {} を{}金額 #{} に反映しました",
+                format_number!(amount as f64),
+                side.label(),
+                line_number
+            )),
+            None => self.layout.event_viewer_mut().add_info("反映できる貸借差額はありません"),
+        }
+    }
+
+    /// フォーカス中の明細項目の側（借方・貸方）
+    fn focused_side(&self) -> Option<BalanceSide> {
+        match self.focused_field {
+            4 | 5 => Some(BalanceSide::Debit),
+            6 | 7 => Some(BalanceSide::Credit),
+            _ => None,
+        }
+    }
+
+    /// 電卓を開く（金額欄にフォーカスがある場合はその金額を初期値にする）
+    pub fn open_tax_calculator(&mut self) {
+        if self.input_mode.is_modify() {
+            return;
+        }
+        let initial = match self.focused_field {
+            5 | 7 => self.get_focused_field().value().to_string(),
+            _ => String::new(),
+        };
+        self.tax_calculator.open(&initial);
+    }
+
+    /// 電卓が表示中かどうか
+    pub fn is_tax_calculator_visible(&self) -> bool {
+        self.tax_calculator.is_visible()
+    }
+
+    /// 電卓を閉じる
+    pub fn close_tax_calculator(&mut self) {
+        self.tax_calculator.hide();
+    }
+
+    /// 電卓の式に文字を入力
+    pub fn tax_calculator_input_char(&mut self, c: char) {
+        self.tax_calculator.input_char(c);
+    }
+
+    /// 電卓の式を1文字削除
+    pub fn tax_calculator_backspace(&mut self) {
+        self.tax_calculator.delete_char();
+    }
+
+    /// 電卓の換算方向を切り替え
+    pub fn tax_calculator_toggle_conversion(&mut self) {
+        self.tax_calculator.toggle_conversion();
+    }
+
+    /// 電卓の税率を切り替え
+    pub fn tax_calculator_cycle_rate(&mut self) {
+        self.tax_calculator.cycle_rate();
+    }
+
+    /// 電卓の換算結果をフォーカス中の金額欄へ反映して閉じる
+    pub fn apply_tax_calculator(&mut self) {
+        let Some(amount) = self.tax_calculator.result() else {
+            self.layout.event_viewer_mut().add_info("計算できない式です");
+            return;
+        };
+        self.tax_calculator.hide();
+
+        if !matches!(self.focused_field, 5 | 7) {
+            self.layout.event_viewer_mut().add_info(format!(
+                "計算結果: {}（金額欄にフォーカスすると反映できます）",
+                format_number!(amount as f64)
+            ));
+            return;
+        }
+        let field = self.get_focused_field_mut();
+        field.set_value(amount.to_string());
+        let label = field.label().to_string();
+        self.layout.event_viewer_mut().add_info(format!(
+            "{} を {} に反映しました",
+            format_number!(amount as f64),
+            label
+        ));
+    }
+
     /// 次の明細行へ移動
     pub fn next_line(&mut self) {
         self.tabbed_form.next_line();
@@ -250,6 +444,8 @@ impl JournalEntryFormPage {
         &self,
         user_id: String,
     ) -> Result<RegisterJournalEntryRequest, String> {
+        let transaction_date = parse_transaction_date(self.date_field.value())
+            .map_err(|e| format!("取引日付: {}", e))?;
         let mut lines = Vec::new();
 
         for (idx, line_form) in self.tabbed_form.lines().iter().enumerate() {
@@ -276,6 +472,7 @@ impl JournalEntryFormPage {
                     account_code: debit_account.to_string(),
                     sub_account_code: None,
                     department_code: None,
+                    dimensions: Default::default(),
                     amount: debit_amount,
                     currency: "JPY".to_string(),
                     tax_type: "NonTaxable".to_string(),
@@ -298,6 +495,7 @@ impl JournalEntryFormPage {
                     account_code: credit_account.to_string(),
                     sub_account_code: None,
                     department_code: None,
+                    dimensions: Default::default(),
                     amount: credit_amount,
                     currency: "JPY".to_string(),
                     tax_type: "NonTaxable".to_string(),
@@ -311,10 +509,13 @@ impl JournalEntryFormPage {
             return Err("明細行が入力されていません".to_string());
         }
 
+        let description = self.description_field.value().trim();
+
         Ok(RegisterJournalEntryRequest {
-            transaction_date: self.date_field.value().to_string(),
+            transaction_date,
             voucher_number: self.voucher_field.value().to_string(),
             lines,
+            description: (!description.is_empty()).then(|| description.to_string()),
             user_id,
         })
     }
@@ -385,8 +586,9 @@ impl JournalEntryFormPage {
                 self.pending_account_load = true;
             }
             ModifyInputType::Calendar => {
-                // カレンダー選択モードに入る
-                self.get_focused_field_mut().start_modify();
+                // カレンダー選択モードに入る（入力値の日付にカーソルを合わせる）
+                let current = NaiveDate::parse_from_str(field.value(), "%Y%m%d").ok();
+                self.calendar_picker.open(current);
                 self.input_mode.enter_modify();
                 self.jj_detector.reset();
             }
@@ -449,6 +651,23 @@ impl JournalEntryFormPage {
         }
     }
 
+    /// CalendarMasterレシーバーを設定
+    pub fn set_calendar_master_receiver(
+        &mut self,
+        receiver: tokio::sync::mpsc::UnboundedReceiver<crate::presenter::CalendarMasterViewModel>,
+    ) {
+        self.calendar_master_receiver = Some(receiver);
+    }
+
+    /// CalendarMasterデータを受信してカレンダーに反映
+    pub fn poll_calendar_master_data(&mut self) {
+        if let Some(receiver) = &mut self.calendar_master_receiver
+            && let Ok(view_model) = receiver.try_recv()
+        {
+            self.calendar_picker.set_calendar_master(view_model);
+        }
+    }
+
     /// JournalEntryResultレシーバーを設定
     pub fn set_result_receiver(
         &mut self,
@@ -465,17 +684,12 @@ impl JournalEntryFormPage {
     }
 
     /// 進捗メッセージレシーバーを設定
-    pub fn set_progress_receiver(
-        &mut self,
-        receiver: tokio::sync::mpsc::UnboundedReceiver<String>,
-    ) {
+    pub fn set_progress_receiver(&mut self, receiver: ProgressReceiver) {
         self.progress_receiver = Some(receiver);
     }
 
     /// 進捗メッセージレシーバーを取り出す
-    pub fn take_progress_receiver(
-        &mut self,
-    ) -> Option<tokio::sync::mpsc::UnboundedReceiver<String>> {
+    pub fn take_progress_receiver(&mut self) -> Option<ProgressReceiver> {
         self.progress_receiver.take()
     }
 
@@ -554,6 +768,51 @@ impl JournalEntryFormPage {
         self.input_mode.enter_normal();
     }
 
+    /// カレンダーが表示中かどうか
+    pub fn is_calendar_visible(&self) -> bool {
+        self.calendar_picker.is_visible()
+    }
+
+    /// フォーカス中のフィールドのヘルプを表示
+    pub fn show_field_help(&mut self) {
+        let help = self.get_focused_field().help();
+        self.field_help.open(help);
+    }
+
+    /// フィールドヘルプが表示されているか
+    pub fn is_field_help_visible(&self) -> bool {
+        self.field_help.is_visible()
+    }
+
+    /// フィールドヘルプを閉じる
+    pub fn close_field_help(&mut self) {
+        self.field_help.close();
+    }
+
+    /// カレンダーのカーソルを日単位で移動
+    pub fn calendar_move_days(&mut self, days: i64) {
+        self.calendar_picker.move_days(days);
+    }
+
+    /// カレンダーのカーソルを月単位で移動
+    pub fn calendar_move_months(&mut self, months: i32) {
+        self.calendar_picker.move_months(months);
+    }
+
+    /// カレンダーで選択を確定
+    pub fn calendar_confirm(&mut self) {
+        let selected = self.calendar_picker.selected_date().format("%Y%m%d").to_string();
+        self.get_focused_field_mut().set_value(selected);
+        self.calendar_picker.close();
+        self.input_mode.enter_normal();
+    }
+
+    /// カレンダーをキャンセル
+    pub fn calendar_cancel(&mut self) {
+        self.calendar_picker.close();
+        self.input_mode.enter_normal();
+    }
+
     /// ローディングアニメーションを更新
     pub fn tick_loading(&mut self) {
         self.overlay_selector.tick_loading();
@@ -585,10 +844,11 @@ impl JournalEntryFormPage {
         match self.focused_field {
             0 => &self.date_field,
             1 => &self.voucher_field,
-            2 => &self.risk_field,
-            // 3-7は現在選択中の明細行のフィールド
-            n if (3..=7).contains(&n) => {
-                let field_index = n - 3;
+            2 => &self.description_field,
+            3 => &self.risk_field,
+            // 4-8は現在選択中の明細行のフィールド
+            n if (4..=8).contains(&n) => {
+                let field_index = n - 4;
                 self.tabbed_form
                     .current_line()
                     .get_field(field_index)
@@ -603,10 +863,11 @@ impl JournalEntryFormPage {
         match self.focused_field {
             0 => &mut self.date_field,
             1 => &mut self.voucher_field,
-            2 => &mut self.risk_field,
-            // 3-7は現在選択中の明細行のフィールド
-            n if (3..=7).contains(&n) => {
-                let field_index = n - 3;
+            2 => &mut self.description_field,
+            3 => &mut self.risk_field,
+            // 4-8は現在選択中の明細行のフィールド
+            n if (4..=8).contains(&n) => {
+                let field_index = n - 4;
                 self.tabbed_form.current_line_mut().get_field_mut(field_index).unwrap()
             }
             _ => &mut self.date_field,
@@ -632,7 +893,7 @@ impl JournalEntryFormPage {
             let input_type = field.input_type();
 
             if input_type.is_char_allowed(ch) {
-                self.get_focused_field_mut().append_to_buffer(ch);
+                self.get_focused_field_mut().insert_char(ch);
             }
             // 許可されない文字の場合は無視（何もしない）
         }
@@ -652,6 +913,31 @@ impl JournalEntryFormPage {
         self.get_focused_field_mut().backspace_buffer();
     }
 
+    /// カーソル位置の文字を削除（Deleteキー、変更モード時）
+    pub fn delete(&mut self) {
+        if !self.input_mode.is_modify() {
+            return;
+        }
+        self.flush_pending_j();
+        self.get_focused_field_mut().delete_at_cursor();
+    }
+
+    /// 入力中のカーソルを移動（←/→/Home/End、変更モード時）
+    pub fn move_cursor(&mut self, movement: fn(&mut InputField)) {
+        if !self.input_mode.is_modify() {
+            return;
+        }
+        self.flush_pending_j();
+        movement(self.get_focused_field_mut());
+    }
+
+    /// 保留中のjを確定してからカーソル操作を行う
+    fn flush_pending_j(&mut self) {
+        if let Some(pending_j) = self.jj_detector.flush_pending() {
+            self.get_focused_field_mut().insert_char(pending_j);
+        }
+    }
+
     /// 上に移動（kキー、非変更モード時）
     pub fn move_up(&mut self) {
         if self.input_mode.is_modify() {
@@ -690,7 +976,7 @@ impl JournalEntryFormPage {
 
     /// 次のフィールドへ移動
     pub fn focus_next(&mut self) {
-        if self.focused_field < 8 {
+        if self.focused_field < 9 {
             self.focused_field += 1;
         }
         self.update_focus();
@@ -707,11 +993,12 @@ impl JournalEntryFormPage {
     fn update_focus(&mut self) {
         self.date_field.set_focused(self.focused_field == 0);
         self.voucher_field.set_focused(self.focused_field == 1);
-        self.risk_field.set_focused(self.focused_field == 2);
+        self.description_field.set_focused(self.focused_field == 2);
+        self.risk_field.set_focused(self.focused_field == 3);
 
         // タブ内のフィールドにフォーカスがある場合
-        if self.focused_field >= 3 && self.focused_field <= 7 {
-            let field_index = self.focused_field - 3;
+        if self.focused_field >= 4 && self.focused_field <= 8 {
+            let field_index = self.focused_field - 4;
             self.tabbed_form.current_line_mut().update_focus(field_index);
         } else {
             // タブ外にフォーカスがある場合、タブ内のすべてのフォーカスをクリア
@@ -719,6 +1006,34 @@ impl JournalEntryFormPage {
         }
     }
 
+    /// 借方・貸方の合計と差額の表示行（一致で緑、不一致で赤）
+    fn balance_line(&self) -> Line<'static> {
+        let (debit_total, credit_total) = self.tabbed_form.totals();
+        let label = Style::default().fg(Color::DarkGray);
+        let (status, status_style) = if debit_total == credit_total {
+            ("貸借一致".to_string(), Style::default().fg(Color::Green))
+        } else {
+            let difference = debit_total.abs_diff(credit_total);
+            let short_side = if debit_total < credit_total {
+                "借方"
+            } else {
+                "貸方"
+            };
+            (
+                format!("差額 {}（{}不足）", format_number!(difference as f64), short_side),
+                Style::default().fg(Color::Red),
+            )
+        };
+        Line::from(vec![
+            Span::styled(" 借方合計 ", label),
+            Span::styled(format_number!(debit_total as f64), Style::default().fg(Color::White)),
+            Span::styled("  貸方合計 ", label),
+            Span::styled(format_number!(credit_total as f64), Style::default().fg(Color::White)),
+            Span::styled("  ", label),
+            Span::styled(status, status_style.add_modifier(Modifier::BOLD)),
+        ])
+    }
+
     /// 描画
     pub fn render(&mut self, frame: &mut Frame) {
         // フォーカス状態を更新
@@ -745,39 +1060,73 @@ impl JournalEntryFormPage {
             Span::styled("]明細削除 [", Style::default().fg(Color::DarkGray)),
             Span::styled("h/l", Style::default().fg(Color::Cyan)),
             Span::styled("]明細切替 [", Style::default().fg(Color::DarkGray)),
+            Span::styled("H/L", Style::default().fg(Color::Cyan)),
+            Span::styled("]明細並替 [", Style::default().fg(Color::DarkGray)),
+            Span::styled("D", Style::default().fg(Color::Cyan)),
+            Span::styled("]複製 [", Style::default().fg(Color::DarkGray)),
+            Span::styled("S", Style::default().fg(Color::Cyan)),
+            Span::styled("]分割 [", Style::default().fg(Color::DarkGray)),
+            Span::styled("U", Style::default().fg(Color::Cyan)),
+            Span::styled("]統合 [", Style::default().fg(Color::DarkGray)),
+            Span::styled("B", Style::default().fg(Color::Cyan)),
+            Span::styled("]差額反映 [", Style::default().fg(Color::DarkGray)),
+            Span::styled("C", Style::default().fg(Color::Cyan)),
+            Span::styled("]電卓 [", Style::default().fg(Color::DarkGray)),
             Span::styled("Ctrl+s", Style::default().fg(Color::Cyan)),
             Span::styled("]確定 [", Style::default().fg(Color::DarkGray)),
+            Span::styled("?", Style::default().fg(Color::Cyan)),
+            Span::styled("]ヘルプ [", Style::default().fg(Color::DarkGray)),
             Span::styled("Esc", Style::default().fg(Color::Cyan)),
             Span::styled("]戻る", Style::default().fg(Color::DarkGray)),
         ]));
 
+        let balance_line = self.balance_line();
+
         self.layout.render(frame, input_mode, footer_text, |frame, area| {
-            // エリアを分割：ヘッダー + タブ付きフォーム
+            // エリアを分割：ヘッダー + タブ付きフォーム + 貸借差額
             let chunks = Layout::default()
                 .direction(Direction::Vertical)
                 .constraints([
                     Constraint::Length(4), // 取引日付
                     Constraint::Length(4), // 伝票番号
-                    Constraint::Length(4), // リスク分類
+                    Constraint::Length(4), // 伝票摘要・リスク分類
                     Constraint::Min(0),    // タブ付きフォーム
+                    Constraint::Length(1), // 貸借差額
                 ])
                 .split(area);
+            let description_chunks = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Percentage(70), Constraint::Percentage(30)])
+                .split(chunks[2]);
 
             let is_in_modify = input_mode.is_modify();
 
             // ヘッダーフィールドを描画
             self.date_field.render(frame, chunks[0], is_in_modify);
             self.voucher_field.render(frame, chunks[1], is_in_modify);
-            self.risk_field.render(frame, chunks[2], is_in_modify);
+            self.description_field.render(frame, description_chunks[0], is_in_modify);
+            self.risk_field.render(frame, description_chunks[1], is_in_modify);
 
             // タブ付きフォームを描画
             self.tabbed_form.render(frame, chunks[3], is_in_modify);
 
+            // 貸借差額を描画
+            frame.render_widget(Paragraph::new(balance_line), chunks[4]);
+
             // オーバーレイセレクタを最前面に描画
             if is_overlay_visible {
                 self.overlay_selector.render(frame, area);
             }
 
+            // カレンダーを最前面に描画
+            self.calendar_picker.render(frame, area);
+
+            // 電卓を最前面に描画
+            self.tax_calculator.render(frame, area);
+
+            // フィールドヘルプを最前面に描画
+            self.field_help.render(frame, area);
+
             // 確定処理中はローディングスピナーを表示
             if is_submitting {
                 let loading_message = match self.edit_mode {
@@ -0,0 +1,392 @@
+// JournalImportPage - 表計算ファイルからの仕訳取込画面のビューコンポーネント
+// 責務: 列の割当ウィザード（項目と列の対応、先頭行のプレビュー、検証結果）の表示
+
+use std::collections::BTreeMap;
+
+use javelin_application::dtos::response::{
+    AmountFormat, JournalImportPreviewResponse, JournalImportResponse,
+    JournalImportValidationReport,
+};
+use javelin_domain::masters::ImportField;
+use ratatui::{
+    Frame,
+    layout::{Constraint, Layout},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState, Wrap},
+};
+
+pub struct JournalImportPage {
+    preview: Option<JournalImportPreviewResponse>,
+    /// 編集中の列の割当
+    columns: BTreeMap<ImportField, usize>,
+    field_state: TableState,
+    report: Option<JournalImportValidationReport>,
+    /// 表示メッセージと、エラーかどうか
+    status_message: Option<(String, bool)>,
+}
+
+impl JournalImportPage {
+    pub fn new() -> Self {
+        Self {
+            preview: None,
+            columns: BTreeMap::new(),
+            field_state: TableState::default().with_selected(Some(0)),
+            report: None,
+            status_message: None,
+        }
+    }
+
+    /// 読み込んだファイルを設定（列の割当はプレビューの割当で初期化）
+    pub fn set_preview(&mut self, preview: JournalImportPreviewResponse) {
+        self.columns = preview.columns.clone();
+        let message = if preview.remembered {
+            "記憶済みの列の割当を復元しました"
+        } else {
+            "見出しから列の割当を推測しました"
+        };
+        self.status_message = Some((message.to_string(), false));
+        self.preview = Some(preview);
+        self.report = None;
+    }
+
+    pub fn set_report(&mut self, report: JournalImportValidationReport) {
+        self.status_message = Some(if report.is_valid() {
+            (format!("検証OK: 仕訳{}件を取り込めます", report.entries.len()), false)
+        } else {
+            (format!("検証エラー {}件", report.errors.len()), true)
+        });
+        self.report = Some(report);
+    }
+
+    pub fn set_imported(&mut self, response: JournalImportResponse) {
+        let mut message = format!(
+            "仕訳{}件を下書きとして取り込み、列の割当を記憶しました",
+            response.imported.len()
+        );
+        if let Some(events) = response.deferred_projection {
+            message.push_str(&format!("（Projectionへ{}件をまとめて反映）", events));
+        }
+        self.status_message = Some((message, false));
+        self.report = Some(response.report);
+    }
+
+    pub fn set_status_message(&mut self, message: impl Into<String>) {
+        self.status_message = Some((message.into(), false));
+    }
+
+    pub fn set_error_message(&mut self, message: impl Into<String>) {
+        self.status_message = Some((message.into(), true));
+    }
+
+    /// 読み込み済みのファイルのパス
+    pub fn path(&self) -> Option<&str> {
+        self.preview.as_ref().map(|preview| preview.path.as_str())
+    }
+
+    pub fn columns(&self) -> &BTreeMap<ImportField, usize> {
+        &self.columns
+    }
+
+    /// 選択中の項目
+    pub fn selected_field(&self) -> ImportField {
+        ImportField::ALL[self.field_state.selected().unwrap_or(0)]
+    }
+
+    pub fn select_next(&mut self) {
+        let next = self
+            .field_state
+            .selected()
+            .map_or(0, |i| (i + 1).min(ImportField::ALL.len() - 1));
+        self.field_state.select(Some(next));
+    }
+
+    pub fn select_previous(&mut self) {
+        let previous = self.field_state.selected().map_or(0, |i| i.saturating_sub(1));
+        self.field_state.select(Some(previous));
+    }
+
+    /// 選択中の項目の列を切り替える（未割当 → 1列目 → … → 最終列 → 未割当）
+    pub fn cycle_column(&mut self, forward: bool) {
+        let Some(column_count) = self.preview.as_ref().map(|preview| preview.headers.len()) else {
+            return;
+        };
+        if column_count == 0 {
+            return;
+        }
+        let field = self.selected_field();
+        let next = match (self.columns.get(&field).copied(), forward) {
+            (None, true) => Some(0),
+            (None, false) => Some(column_count - 1),
+            (Some(column), true) if column + 1 < column_count => Some(column + 1),
+            (Some(column), false) if column > 0 => Some(column - 1),
+            _ => None,
+        };
+        match next {
+            Some(column) => self.columns.insert(field, column),
+            None => self.columns.remove(&field),
+        };
+        // 割当を変えたら検証をやり直す
+        self.report = None;
+    }
+
+    /// 描画（`input` は入力中のファイルパス）
+    pub fn render(&mut self, frame: &mut Frame, input: Option<&str>) {
+        let area = frame.area();
+        let chunks = Layout::vertical([
+            Constraint::Length(3),
+            Constraint::Min(8),
+            Constraint::Length(10),
+            Constraint::Length(3),
+        ])
+        .split(area);
+
+        self.render_file_info(frame, chunks[0]);
+
+        let middle =
+            Layout::horizontal([Constraint::Length(36), Constraint::Min(0)]).split(chunks[1]);
+        self.render_fields(frame, middle[0]);
+        self.render_preview(frame, middle[1]);
+        self.render_report(frame, chunks[2]);
+        self.render_status_bar(frame, chunks[3], input);
+    }
+
+    fn render_file_info(&self, frame: &mut Frame, area: ratatui::layout::Rect) {
+        let text = match &self.preview {
+            Some(preview) => Line::from(vec![
+                Span::raw(format!(
+                    "{}  シート: {}  データ行: {}件  ",
+                    preview.path, preview.sheet_name, preview.total_rows
+                )),
+                if preview.remembered {
+                    Span::styled("記憶済みの割当", Style::default().fg(Color::Green))
+                } else {
+                    Span::styled("新しい様式", Style::default().fg(Color::Yellow))
+                },
+            ]),
+            None => {
+                Line::from("[o] で取り込むファイル（.xlsx / .xls / .ods）のパスを入力してください")
+            }
+        };
+        let info = Paragraph::new(text)
+            .block(Block::default().borders(Borders::ALL).title("仕訳取込（表計算ファイル）"));
+        frame.render_widget(info, area);
+    }
+
+    fn render_fields(&mut self, frame: &mut Frame, area: ratatui::layout::Rect) {
+        let headers =
+            self.preview.as_ref().map(|preview| preview.headers.as_slice()).unwrap_or(&[]);
+        let rows: Vec<Row> = ImportField::ALL
+            .iter()
+            .map(|field| {
+                let label = if field.is_required() {
+                    format!("{} *", field.label())
+                } else {
+                    field.label().to_string()
+                };
+                let column = match self.columns.get(field) {
+                    Some(&column) => Cell::from(format!(
+                        "{} {}",
+                        column_letter(column),
+                        headers.get(column).map(String::as_str).unwrap_or("")
+                    )),
+                    None if field.is_required() => {
+                        Cell::from("未割当").style(Style::default().fg(Color::Red))
+                    }
+                    None => Cell::from("-").style(Style::default().fg(Color::DarkGray)),
+                };
+                Row::new(vec![Cell::from(label), column])
+            })
+            .collect();
+
+        let table = Table::new(rows, [Constraint::Length(12), Constraint::Min(10)])
+            .header(
+                Row::new(vec!["項目", "列"]).style(Style::default().add_modifier(Modifier::BOLD)),
+            )
+            .row_highlight_style(Style::default().bg(Color::DarkGray).add_modifier(Modifier::BOLD))
+            .block(Block::default().borders(Borders::ALL).title("列の割当（* 必須）"));
+        frame.render_stateful_widget(table, area, &mut self.field_state);
+    }
+
+    fn render_preview(&self, frame: &mut Frame, area: ratatui::layout::Rect) {
+        let block = Block::default().borders(Borders::ALL).title("プレビュー（先頭行）");
+        let Some(preview) = &self.preview else {
+            frame.render_widget(Paragraph::new("").block(block), area);
+            return;
+        };
+
+        let selected_column = self.columns.get(&self.selected_field()).copied();
+        let column_style = |column: usize| {
+            if Some(column) == selected_column {
+                Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default()
+            }
+        };
+
+        let header = Row::new(
+            preview
+                .headers
+                .iter()
+                .enumerate()
+                .map(|(column, name)| {
+                    Cell::from(format!("{} {}", column_letter(column), name))
+                        .style(column_style(column).add_modifier(Modifier::BOLD))
+                })
+                .collect::<Vec<_>>(),
+        );
+        let rows: Vec<Row> = preview
+            .preview_rows
+            .iter()
+            .map(|cells| {
+                Row::new(
+                    (0..preview.headers.len())
+                        .map(|column| {
+                            Cell::from(cells.get(column).map(String::as_str).unwrap_or(""))
+                                .style(column_style(column))
+                        })
+                        .collect::<Vec<_>>(),
+                )
+            })
+            .collect();
+
+        let widths = vec![Constraint::Length(14); preview.headers.len()];
+        frame.render_widget(Table::new(rows, widths).header(header).block(block), area);
+    }
+
+    fn render_report(&self, frame: &mut Frame, area: ratatui::layout::Rect) {
+        let Some(report) = &self.report else {
+            let hint = Paragraph::new("[v] で割当に従って全行を検証します")
+                .style(Style::default().fg(Color::DarkGray))
+                .block(Block::default().borders(Borders::ALL).title("検証結果"));
+            frame.render_widget(hint, area);
+            return;
+        };
+
+        let amount_format = AmountFormat::default();
+        let mut lines: Vec<Line> = report
+            .errors
+            .iter()
+            .map(|error| {
+                Line::styled(
+                    format!("{}行目: {}", error.row, error.message),
+                    Style::default().fg(Color::Red),
+                )
+            })
+            .collect();
+        lines.extend(report.entries.iter().map(|entry| {
+            Line::from(format!(
+                "{}  {}  明細{}行  借方 {}  貸方 {}",
+                entry.voucher_number,
+                entry.transaction_date,
+                entry.line_count,
+                amount_format.format(entry.debit_total, "JPY"),
+                amount_format.format(entry.credit_total, "JPY"),
+            ))
+        }));
+
+        let title = format!(
+            "検証結果: 行{}件 / 仕訳{}件 / エラー{}件",
+            report.total_rows,
+            report.entries.len(),
+            report.errors.len()
+        );
+        let border_style = if report.is_valid() {
+            Style::default().fg(Color::Green)
+        } else {
+            Style::default().fg(Color::Red)
+        };
+        let widget = Paragraph::new(lines)
+            .wrap(Wrap { trim: false })
+            .block(Block::default().borders(Borders::ALL).border_style(border_style).title(title));
+        frame.render_widget(widget, area);
+    }
+
+    fn render_status_bar(
+        &self,
+        frame: &mut Frame,
+        area: ratatui::layout::Rect,
+        input: Option<&str>,
+    ) {
+        let status_bar = if let Some(value) = input {
+            Paragraph::new(Line::from(vec![
+                Span::styled("ファイルパス: ", Style::default().fg(Color::Yellow)),
+                Span::raw(value),
+                Span::styled("▮", Style::default().fg(Color::Yellow)),
+            ]))
+            .block(Block::default().borders(Borders::ALL).title("[Enter] 読込 [Esc] キャンセル"))
+        } else {
+            let mut status = vec![Span::raw(
+                "[o] ファイル [↑↓] 項目 [←→] 列を変更 [v] 検証 [i] 取込 [Esc] 戻る",
+            )];
+            if let Some((message, is_error)) = &self.status_message {
+                let color = if *is_error { Color::Red } else { Color::Green };
+                status.push(Span::styled(format!("  {}", message), Style::default().fg(color)));
+            }
+            Paragraph::new(Line::from(status)).block(Block::default().borders(Borders::ALL))
+        };
+        frame.render_widget(status_bar, area);
+    }
+}
+
+impl Default for JournalImportPage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// 表計算ソフトの列記号（0 → A, 25 → Z, 26 → AA）
+fn column_letter(column: usize) -> String {
+    let mut letters = Vec::new();
+    let mut n = column + 1;
+    while n > 0 {
+        let remainder = (n - 1) % 26;
+        letters.push((b'A' + remainder as u8) as char);
+        n = (n - 1) / 26;
+    }
+    letters.iter().rev().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn preview() -> JournalImportPreviewResponse {
+        let headers: Vec<String> = ["日付", "伝票番号", "貸借", "科目", "金額"]
+            .iter()
+            .map(|h| h.to_string())
+            .collect();
+        JournalImportPreviewResponse {
+            path: "journal.xlsx".to_string(),
+            sheet_name: "Sheet1".to_string(),
+            columns: BTreeMap::from([(ImportField::TransactionDate, 0)]),
+            headers,
+            preview_rows: vec![],
+            total_rows: 0,
+            remembered: false,
+        }
+    }
+
+    #[test]
+    fn test_column_letter() {
+        assert_eq!(column_letter(0), "A");
+        assert_eq!(column_letter(25), "Z");
+        assert_eq!(column_letter(26), "AA");
+    }
+
+    #[test]
+    fn test_cycle_column_wraps_through_unassigned() {
+        let mut page = JournalImportPage::new();
+        page.set_preview(preview());
+        assert_eq!(page.selected_field(), ImportField::TransactionDate);
+
+        page.cycle_column(false);
+        assert_eq!(page.columns().get(&ImportField::TransactionDate), None);
+        page.cycle_column(false);
+        assert_eq!(page.columns().get(&ImportField::TransactionDate), Some(&4));
+
+        page.select_next();
+        page.cycle_column(true);
+        assert_eq!(page.columns().get(&ImportField::VoucherNumber), Some(&0));
+    }
+}
@@ -0,0 +1,110 @@
+// KpiDashboardPage - KPIダッシュボード画面のビューコンポーネント
+// 責務: 仕訳起票・承認・差戻し・締め処理の日次件数の推移と所要時間の要約の表示
+
+use ratatui::{
+    Frame,
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::Line,
+    widgets::{Block, Borders, Paragraph, Sparkline},
+};
+
+use crate::presenter::{KpiCardViewModel, KpiDashboardViewModel};
+
+pub struct KpiDashboardPage {
+    view_model: KpiDashboardViewModel,
+    loading: bool,
+    error_message: Option<String>,
+}
+
+impl KpiDashboardPage {
+    pub fn new() -> Self {
+        Self {
+            view_model: KpiDashboardViewModel::default(),
+            loading: false,
+            error_message: None,
+        }
+    }
+
+    pub fn set_loading(&mut self) {
+        self.loading = true;
+        self.error_message = None;
+    }
+
+    pub fn set_view_model(&mut self, view_model: KpiDashboardViewModel) {
+        self.view_model = view_model;
+        self.loading = false;
+        self.error_message = None;
+    }
+
+    pub fn set_error(&mut self, message: String) {
+        self.loading = false;
+        self.error_message = Some(message);
+    }
+
+    pub fn render(&self, frame: &mut Frame) {
+        let chunks =
+            Layout::vertical([Constraint::Min(0), Constraint::Length(3)]).split(frame.area());
+
+        let title = format!("KPIダッシュボード {}", self.view_model.period_label);
+        let block = Block::default().borders(Borders::ALL).title(title);
+
+        if let Some(error) = &self.error_message {
+            let message = Paragraph::new(error.as_str())
+                .style(Style::default().fg(Color::Red))
+                .block(block);
+            frame.render_widget(message, chunks[0]);
+        } else if self.loading && self.view_model.cards.is_empty() {
+            frame.render_widget(Paragraph::new("読み込み中...").block(block), chunks[0]);
+        } else {
+            let inner = block.inner(chunks[0]);
+            frame.render_widget(block, chunks[0]);
+
+            let rows = Layout::vertical([Constraint::Ratio(1, 2); 2]).split(inner);
+            let colors = [Color::Cyan, Color::Green, Color::Yellow, Color::Magenta];
+            for (index, card) in self.view_model.cards.iter().enumerate() {
+                let columns =
+                    Layout::horizontal([Constraint::Ratio(1, 2); 2]).split(rows[index / 2]);
+                Self::render_card(frame, columns[index % 2], card, colors[index % colors.len()]);
+            }
+        }
+
+        let status = if self.loading {
+            "読み込み中..."
+        } else {
+            ""
+        };
+        let status_bar = Paragraph::new(format!("[r] 再読込 [Esc] 戻る  {}", status))
+            .block(Block::default().borders(Borders::ALL));
+        frame.render_widget(status_bar, chunks[1]);
+    }
+
+    /// 指標ごとの件数の推移と要約
+    fn render_card(frame: &mut Frame, area: Rect, card: &KpiCardViewModel, color: Color) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(card.metric.label())
+            .title_style(Style::default().fg(color).add_modifier(Modifier::BOLD));
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let parts = Layout::vertical([Constraint::Length(2), Constraint::Min(0)]).split(inner);
+        frame.render_widget(
+            Paragraph::new(vec![
+                Line::from(card.count_label.as_str()),
+                Line::from(card.latency_label.as_str()),
+            ]),
+            parts[0],
+        );
+        frame.render_widget(
+            Sparkline::default().data(&card.count_series).style(Style::default().fg(color)),
+            parts[1],
+        );
+    }
+}
+
+impl Default for KpiDashboardPage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
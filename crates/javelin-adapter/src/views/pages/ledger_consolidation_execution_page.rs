@@ -1,10 +1,14 @@
 // LedgerConsolidationExecutionPage - 元帳集約実行画面
 // 責務: 元帳集約処理の実行とプログレス表示
 
+use javelin_application::dtos::response::JobResponse;
 use ratatui::Frame;
 
 use crate::views::layouts::templates::{BatchExecutionTemplate, ProcessStep, ProcessStepStatus};
 
+/// プロセスステップ数
+const STEP_COUNT: usize = 5;
+
 pub struct LedgerConsolidationExecutionPage {
     template: BatchExecutionTemplate,
 }
@@ -25,6 +29,7 @@ impl LedgerConsolidationExecutionPage {
         template.set_steps(steps);
         template.add_info("元帳集約処理画面を開きました");
         template.add_info("処理を開始するには [s] キーを押してください");
+        template.add_info("処理はジョブとして登録され、画面を離れても継続します（[b] ジョブ一覧）");
 
         Self { template }
     }
@@ -35,6 +40,44 @@ impl LedgerConsolidationExecutionPage {
         self.template.update_step(0, ProcessStepStatus::Running, 0);
     }
 
+    /// ジョブの状態をプロセスステップに反映
+    ///
+    /// ジョブの進捗（0〜100）を各ステップに均等に割り当てる。
+    pub fn show_job(&mut self, job: &JobResponse) {
+        let current = (job.progress as usize * STEP_COUNT / 100).min(STEP_COUNT - 1);
+        let message = job.message.clone().unwrap_or_default();
+
+        match job.status.as_str() {
+            "Queued" => self.template.update_step(0, ProcessStepStatus::Waiting, 0),
+            "Running" => {
+                for index in 0..current {
+                    self.template.update_step(index, ProcessStepStatus::Completed, 100);
+                }
+                self.template.update_step(current, ProcessStepStatus::Running, job.progress);
+            }
+            "Completed" => {
+                for index in 0..STEP_COUNT {
+                    self.template.update_step(index, ProcessStepStatus::Completed, 100);
+                }
+                self.template.add_info(message);
+                return;
+            }
+            _ => {
+                self.template.update_step(
+                    current,
+                    ProcessStepStatus::Error(job.status_label.clone()),
+                    job.progress,
+                );
+                self.template.add_error(format!("{}: {}", job.status_label, message));
+                return;
+            }
+        }
+
+        if !message.is_empty() {
+            self.template.add_info(format!("[{}] {}", job.status_label, message));
+        }
+    }
+
     /// ステップの状態を更新
     pub fn update_step(&mut self, index: usize, status: ProcessStepStatus, progress: u8) {
         self.template.update_step(index, status, progress);
@@ -1,6 +1,13 @@
 // LedgerDetailPage - 元帳詳細閲覧画面
 // 責務: 選択された元帳エントリの詳細表示
 
+use javelin_application::{
+    dtos::response::{
+        ANNOTATION_FLAG, EntryAnnotationsResponse, EntryLinkDto, EntryLinksResponse,
+        LineAnnotationDto,
+    },
+    query_service::LineChangeKind,
+};
 use ratatui::{
     Frame,
     layout::{Constraint, Direction, Layout, Rect},
@@ -9,7 +16,21 @@ use ratatui::{
     widgets::{Block, BorderType, Borders, Paragraph, Wrap},
 };
 
-use crate::{format_amount, format_balance, presenter::LedgerEntryViewModel};
+use crate::{
+    format_amount, format_balance,
+    presenter::{EntryHistoryViewModel, EntryLineSideViewModel, LedgerEntryViewModel},
+};
+
+/// 注記の入力内容
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AnnotationInput {
+    /// 明細への注記
+    Note,
+    /// 注記の解決（対象の注記ID）
+    Resolution(String),
+    /// 表示中の仕訳を計上・前払として決済・償却の仕訳と紐付け（紐付けの種類コード）
+    Link(String),
+}
 
 /// 元帳詳細閲覧画面
 pub struct LedgerDetailPage {
@@ -18,12 +39,153 @@ pub struct LedgerDetailPage {
     /// 勘定科目情報
     account_code: String,
     account_name: String,
+    /// 修正履歴（差分）
+    history: Option<EntryHistoryViewModel>,
+    /// 修正履歴の取得エラー
+    history_error: Option<String>,
+    /// 差分表示中か
+    show_history: bool,
+    /// 表示中の明細の注記（注記順）
+    annotations: Vec<LineAnnotationDto>,
+    /// 注記の取得・登録エラー
+    annotation_error: Option<String>,
+    /// 入力中の注記・解決内容
+    input: Option<(AnnotationInput, String)>,
+    /// 表示中の仕訳の紐付け（紐付け順）
+    links: Vec<EntryLinkDto>,
+    /// 紐付けの取得・登録エラー
+    link_error: Option<String>,
 }
 
 impl LedgerDetailPage {
     /// 新しいLedgerDetailPageを作成
     pub fn new(entry: LedgerEntryViewModel, account_code: String, account_name: String) -> Self {
-        Self { entry, account_code, account_name }
+        Self {
+            entry,
+            account_code,
+            account_name,
+            history: None,
+            history_error: None,
+            show_history: false,
+            annotations: Vec::new(),
+            annotation_error: None,
+            input: None,
+            links: Vec::new(),
+            link_error: None,
+        }
+    }
+
+    /// 表示中のエントリの仕訳ID
+    pub fn entry_id(&self) -> &str {
+        &self.entry.entry_id
+    }
+
+    /// 表示中の勘定科目コード
+    pub fn account_code(&self) -> &str {
+        &self.account_code
+    }
+
+    /// 修正履歴を設定
+    pub fn set_history(&mut self, history: EntryHistoryViewModel) {
+        self.history = Some(history);
+        self.history_error = None;
+    }
+
+    /// 修正履歴の取得エラーを設定
+    pub fn set_history_error(&mut self, message: impl Into<String>) {
+        self.history_error = Some(message.into());
+    }
+
+    /// 詳細表示と差分表示を切り替え
+    pub fn toggle_history(&mut self) {
+        self.show_history = !self.show_history;
+    }
+
+    /// 表示中のエントリの明細番号
+    pub fn line_number(&self) -> u32 {
+        self.entry.line_number
+    }
+
+    /// 仕訳の注記一覧から表示中の明細の注記を設定
+    pub fn set_annotations(&mut self, response: EntryAnnotationsResponse) {
+        let line_number = self.entry.line_number;
+        self.annotations = response
+            .annotations
+            .into_iter()
+            .filter(|a| a.line_number == line_number)
+            .collect();
+        self.entry.open_annotations =
+            self.annotations.iter().filter(|a| a.is_open()).count() as u32;
+        self.annotation_error = None;
+    }
+
+    /// 注記の取得・登録エラーを設定
+    pub fn set_annotation_error(&mut self, message: impl Into<String>) {
+        self.annotation_error = Some(message.into());
+    }
+
+    /// 注記の入力を開始
+    pub fn start_note_input(&mut self) {
+        self.input = Some((AnnotationInput::Note, String::new()));
+    }
+
+    /// 最も古い未解決の注記の解決内容の入力を開始
+    pub fn start_resolution_input(&mut self) {
+        match self.annotations.iter().find(|a| a.is_open()) {
+            Some(annotation) => {
+                self.input = Some((
+                    AnnotationInput::Resolution(annotation.annotation_id.clone()),
+                    String::new(),
+                ));
+            }
+            None => self.annotation_error = Some("未解決の注記はありません".to_string()),
+        }
+    }
+
+    /// 仕訳の紐付けを設定
+    pub fn set_links(&mut self, response: EntryLinksResponse) {
+        self.links = response.links;
+        self.link_error = None;
+    }
+
+    /// 紐付けの取得・登録エラーを設定
+    pub fn set_link_error(&mut self, message: impl Into<String>) {
+        self.link_error = Some(message.into());
+    }
+
+    /// 紐付けの入力（相手仕訳IDと任意の金額）を開始
+    pub fn start_link_input(&mut self, kind: &str) {
+        self.input = Some((AnnotationInput::Link(kind.to_string()), String::new()));
+    }
+
+    /// 最後に紐付けた紐付けのID（解除対象）
+    pub fn latest_link_id(&self) -> Option<&str> {
+        self.links.last().map(|link| link.link_id.as_str())
+    }
+
+    pub fn is_input_active(&self) -> bool {
+        self.input.is_some()
+    }
+
+    pub fn input_char(&mut self, c: char) {
+        if let Some((_, input)) = self.input.as_mut() {
+            input.push(c);
+        }
+    }
+
+    pub fn delete_char(&mut self) {
+        if let Some((_, input)) = self.input.as_mut() {
+            input.pop();
+        }
+    }
+
+    pub fn cancel_input(&mut self) {
+        self.input = None;
+    }
+
+    /// 入力内容を取り出す（入力を終了）
+    pub fn take_input(&mut self) -> Option<(AnnotationInput, String)> {
+        self.input.take()
     }
 
     /// エラーメッセージをイベントログに追加（互換性のため）
@@ -41,6 +203,8 @@ impl LedgerDetailPage {
             .constraints([
                 Constraint::Length(3), // ヘッダー
                 Constraint::Min(10),   // メイン
+                Constraint::Length(8), // 注記
+                Constraint::Length(5), // 紐付け
                 Constraint::Length(3), // ステータスバー
             ])
             .split(area);
@@ -49,10 +213,20 @@ impl LedgerDetailPage {
         self.render_header(frame, chunks[0]);
 
         // メインエリア
-        self.render_main(frame, chunks[1]);
+        if self.show_history {
+            self.render_history(frame, chunks[1]);
+        } else {
+            self.render_main(frame, chunks[1]);
+        }
+
+        // 注記
+        self.render_annotations(frame, chunks[2]);
+
+        // 紐付け
+        self.render_links(frame, chunks[3]);
 
         // ステータスバー
-        self.render_status_bar(frame, chunks[2]);
+        self.render_status_bar(frame, chunks[4]);
     }
 
     /// ヘッダーを描画
@@ -155,10 +329,277 @@ impl LedgerDetailPage {
         frame.render_widget(paragraph, area);
     }
 
+    /// 修正履歴（元仕訳と修正仕訳の明細差分）を描画
+    fn render_history(&self, frame: &mut Frame, area: Rect) {
+        let mut content = vec![Line::from("")];
+
+        match (&self.history, &self.history_error) {
+            (_, Some(error)) => {
+                content.push(Line::from(Span::styled(
+                    format!("修正履歴を取得できませんでした: {}", error),
+                    Style::default().fg(Color::Red),
+                )));
+            }
+            (None, None) => {
+                content.push(Line::from(Span::styled(
+                    "修正履歴を読み込み中...",
+                    Style::default().fg(Color::DarkGray),
+                )));
+            }
+            (Some(history), None) if !history.has_correction() => {
+                content.push(Line::from(vec![
+                    Span::styled("元仕訳: ", Style::default().fg(Color::Gray)),
+                    Span::styled(&history.original_entry_id, Style::default().fg(Color::White)),
+                ]));
+                content.push(Line::from(""));
+                content.push(Line::from(Span::styled(
+                    "この仕訳に修正履歴はありません",
+                    Style::default().fg(Color::DarkGray),
+                )));
+            }
+            (Some(history), None) => {
+                content.push(Line::from(vec![
+                    Span::styled("元仕訳: ", Style::default().fg(Color::Gray)),
+                    Span::styled(&history.original_entry_id, Style::default().fg(Color::White)),
+                    Span::styled("  →  取消: ", Style::default().fg(Color::Gray)),
+                    Span::styled(
+                        history.reversal_entry_id.as_deref().unwrap_or("-"),
+                        Style::default().fg(Color::White),
+                    ),
+                    Span::styled("  →  修正: ", Style::default().fg(Color::Gray)),
+                    Span::styled(
+                        history.correcting_entry_id.as_deref().unwrap_or("-"),
+                        Style::default().fg(Color::Yellow),
+                    ),
+                ]));
+                content.push(Line::from(vec![
+                    Span::styled("修正理由: ", Style::default().fg(Color::Gray)),
+                    Span::styled(
+                        history.reason.as_deref().unwrap_or(""),
+                        Style::default().fg(Color::White),
+                    ),
+                ]));
+                content.push(Line::from(""));
+                content.push(Line::from(Span::styled(
+                    format!("{:>4}  {:<8}  {:<34}  {:<34}", "行", "区分", "元仕訳", "修正仕訳"),
+                    Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+                )));
+
+                for line in &history.lines {
+                    let style = match line.change {
+                        LineChangeKind::Unchanged => Style::default().fg(Color::DarkGray),
+                        LineChangeKind::Added => Style::default().fg(Color::Green),
+                        LineChangeKind::Removed => Style::default().fg(Color::Red),
+                        LineChangeKind::Modified => Style::default().fg(Color::Yellow),
+                    };
+                    content.push(Line::from(Span::styled(
+                        format!(
+                            "{:>4}  {:<8}  {:<34}  {:<34}",
+                            line.line_number,
+                            line.change.display_name(),
+                            Self::format_side(line.original.as_ref()),
+                            Self::format_side(line.corrected.as_ref()),
+                        ),
+                        style,
+                    )));
+                }
+            }
+        }
+
+        let paragraph = Paragraph::new(content)
+            .block(
+                Block::default()
+                    .title(" 修正履歴 ")
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .border_style(Style::default().fg(Color::White)),
+            )
+            .wrap(Wrap { trim: false });
+
+        frame.render_widget(paragraph, area);
+    }
+
+    /// 明細の注記と解決の記録を描画
+    fn render_annotations(&self, frame: &mut Frame, area: Rect) {
+        let mut content = Vec::new();
+
+        let label = match &self.input {
+            Some((AnnotationInput::Note, input)) => Some(("注記: ".to_string(), input)),
+            Some((AnnotationInput::Resolution(annotation_id), input)) => {
+                Some((format!("{} の解決: ", annotation_id), input))
+            }
+            // 紐付けの入力は紐付け欄に表示する
+            Some((AnnotationInput::Link(_), _)) | None => None,
+        };
+        if let Some((label, input)) = label {
+            content.push(Line::from(vec![
+                Span::styled(label, Style::default().fg(Color::Yellow)),
+                Span::styled(format!("{}▮", input), Style::default().fg(Color::White)),
+            ]));
+        }
+        if let Some(error) = &self.annotation_error {
+            content.push(Line::from(Span::styled(error.as_str(), Style::default().fg(Color::Red))));
+        }
+        if self.annotations.is_empty() {
+            content.push(Line::from(Span::styled(
+                "この明細に注記はありません",
+                Style::default().fg(Color::DarkGray),
+            )));
+        }
+
+        for annotation in &self.annotations {
+            let (marker, style) = if annotation.is_open() {
+                (ANNOTATION_FLAG, Style::default().fg(Color::Yellow))
+            } else {
+                ("✓", Style::default().fg(Color::DarkGray))
+            };
+            content.push(Line::from(vec![
+                Span::styled(format!("{} {} ", marker, annotation.annotation_id), style),
+                Span::styled(annotation.note.as_str(), Style::default().fg(Color::White)),
+                Span::styled(
+                    format!(
+                        "  ({} {})",
+                        annotation.annotated_by,
+                        crate::clock::format_timestamp(&annotation.annotated_at)
+                    ),
+                    Style::default().fg(Color::DarkGray),
+                ),
+            ]));
+            if let (Some(resolution), Some(resolved_by), Some(resolved_at)) =
+                (&annotation.resolution, &annotation.resolved_by, &annotation.resolved_at)
+            {
+                content.push(Line::from(vec![
+                    Span::styled("    解決: ", Style::default().fg(Color::Gray)),
+                    Span::styled(resolution.as_str(), Style::default().fg(Color::Green)),
+                    Span::styled(
+                        format!(
+                            "  ({} {})",
+                            resolved_by,
+                            crate::clock::format_timestamp(resolved_at)
+                        ),
+                        Style::default().fg(Color::DarkGray),
+                    ),
+                ]));
+            }
+        }
+
+        let open_count = self.annotations.iter().filter(|a| a.is_open()).count();
+        let paragraph = Paragraph::new(content)
+            .block(
+                Block::default()
+                    .title(format!(
+                        " 注記（明細{} 未解決: {}） ",
+                        self.entry.line_number, open_count
+                    ))
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .border_style(if open_count > 0 {
+                        Style::default().fg(Color::Yellow)
+                    } else {
+                        Style::default().fg(Color::White)
+                    }),
+            )
+            .wrap(Wrap { trim: false });
+
+        frame.render_widget(paragraph, area);
+    }
+
+    /// 計上↔決済・前払↔償却の紐付けを描画
+    fn render_links(&self, frame: &mut Frame, area: Rect) {
+        let mut content = Vec::new();
+
+        if let Some((AnnotationInput::Link(_), input)) = &self.input {
+            content.push(Line::from(vec![
+                Span::styled("相手仕訳ID [金額]: ", Style::default().fg(Color::Yellow)),
+                Span::styled(format!("{}▮", input), Style::default().fg(Color::White)),
+            ]));
+        }
+        if let Some(error) = &self.link_error {
+            content.push(Line::from(Span::styled(error.as_str(), Style::default().fg(Color::Red))));
+        }
+        if self.links.is_empty() {
+            content.push(Line::from(Span::styled(
+                "この仕訳に紐付けはありません",
+                Style::default().fg(Color::DarkGray),
+            )));
+        }
+
+        for link in &self.links {
+            content.push(Line::from(vec![
+                Span::styled(
+                    format!(
+                        "{} {} → {} ",
+                        link.link_id, link.role_name, link.counterpart_role_name
+                    ),
+                    Style::default().fg(Color::Cyan),
+                ),
+                Span::styled(link.counterpart_id.as_str(), Style::default().fg(Color::White)),
+                Span::styled(
+                    format!("  {}", format_amount!(link.amount)),
+                    Style::default().fg(Color::Yellow),
+                ),
+                Span::styled(
+                    format!(
+                        "  ({} {})",
+                        link.linked_by,
+                        crate::clock::format_timestamp(&link.linked_at)
+                    ),
+                    Style::default().fg(Color::DarkGray),
+                ),
+            ]));
+        }
+
+        let paragraph = Paragraph::new(content)
+            .block(
+                Block::default()
+                    .title(format!(" 紐付け（{}件） ", self.links.len()))
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .border_style(Style::default().fg(Color::White)),
+            )
+            .wrap(Wrap { trim: false });
+
+        frame.render_widget(paragraph, area);
+    }
+
+    /// 差分の片側を1セルの文字列に整形
+    fn format_side(side: Option<&EntryLineSideViewModel>) -> String {
+        match side {
+            Some(side) if side.description.is_empty() => {
+                format!("{} {} {}", side.side, side.account_code, format_amount!(side.amount))
+            }
+            Some(side) => format!(
+                "{} {} {} {}",
+                side.side,
+                side.account_code,
+                format_amount!(side.amount),
+                side.description
+            ),
+            None => "-".to_string(),
+        }
+    }
+
     /// ステータスバーを描画
     fn render_status_bar(&self, frame: &mut Frame, area: Rect) {
+        let toggle_label = if self.show_history {
+            "]詳細表示 ["
+        } else {
+            "]修正履歴 ["
+        };
         let status_text = vec![Line::from(vec![
             Span::styled("[", Style::default().fg(Color::DarkGray)),
+            Span::styled("d", Style::default().fg(Color::Cyan)),
+            Span::styled(toggle_label, Style::default().fg(Color::DarkGray)),
+            Span::styled("a", Style::default().fg(Color::Cyan)),
+            Span::styled("]注記 [", Style::default().fg(Color::DarkGray)),
+            Span::styled("r", Style::default().fg(Color::Cyan)),
+            Span::styled("]解決 [", Style::default().fg(Color::DarkGray)),
+            Span::styled("l", Style::default().fg(Color::Cyan)),
+            Span::styled("]計上↔決済 [", Style::default().fg(Color::DarkGray)),
+            Span::styled("p", Style::default().fg(Color::Cyan)),
+            Span::styled("]前払↔償却 [", Style::default().fg(Color::DarkGray)),
+            Span::styled("x", Style::default().fg(Color::Cyan)),
+            Span::styled("]紐付け解除 [", Style::default().fg(Color::DarkGray)),
             Span::styled("Esc", Style::default().fg(Color::Cyan)),
             Span::styled("]戻る", Style::default().fg(Color::DarkGray)),
         ])];
@@ -181,13 +622,24 @@ impl Default for LedgerDetailPage {
                 transaction_date: "2024-01-01".to_string(),
                 entry_number: "JE-001".to_string(),
                 entry_id: "entry-001".to_string(),
+                line_number: 1,
                 description: "サンプルエントリ".to_string(),
                 debit_amount: 0.0,
                 credit_amount: 0.0,
                 balance: 0.0,
+                open_annotations: 0,
+                carried_forward: false,
             },
             account_code: "1001".to_string(),
             account_name: "現金".to_string(),
+            history: None,
+            history_error: None,
+            show_history: false,
+            annotations: Vec::new(),
+            annotation_error: None,
+            input: None,
+            links: Vec::new(),
+            link_error: None,
         }
     }
 }
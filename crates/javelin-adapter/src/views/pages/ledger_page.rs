@@ -1,6 +1,7 @@
 // LedgerPage - 元帳一覧画面
 // 責務: 勘定科目別元帳の一覧表示（レトロで哀愁漂うデザイン）
 
+use javelin_application::dtos::response::{ANNOTATION_FLAG, AmountMask};
 use ratatui::{
     Frame,
     layout::{Constraint, Direction, Layout, Rect},
@@ -12,9 +13,9 @@ use tokio::sync::mpsc;
 
 use crate::{
     format_amount, format_balance,
-    presenter::LedgerViewModel,
+    presenter::{LedgerEntryViewModel, LedgerViewModel},
     truncate_text,
-    views::components::{DataTable, InfoPanel},
+    views::components::{DataTable, EventViewer, ExportFormat, ExportPrompt, InfoPanel},
 };
 
 /// 元帳一覧画面
@@ -23,10 +24,18 @@ pub struct LedgerPage {
     ledger_table: DataTable,
     /// 勘定情報パネル
     info_panel: InfoPanel,
+    /// イベントビューア
+    event_viewer: EventViewer,
+    /// 出力ファイル名入力
+    export_prompt: ExportPrompt,
+    /// 出力用の行データ（表示行と同じ並び、切り詰め前の値）
+    export_rows: Vec<Vec<String>>,
     /// ViewModelレシーバー
     ledger_receiver: mpsc::UnboundedReceiver<LedgerViewModel>,
     /// 現在表示中の元帳データ
     current_ledger: Option<LedgerViewModel>,
+    /// 未解決の注記がある明細のみ表示
+    flagged_only: bool,
     /// アニメーションフレーム
     animation_frame: usize,
 }
@@ -51,8 +60,12 @@ impl LedgerPage {
         Self {
             ledger_table,
             info_panel,
+            event_viewer: EventViewer::new(),
+            export_prompt: ExportPrompt::new(),
+            export_rows: Vec::new(),
             ledger_receiver,
             current_ledger: None,
+            flagged_only: false,
             animation_frame: 0,
         }
     }
@@ -60,29 +73,87 @@ impl LedgerPage {
     /// ViewModelを受信してテーブルを更新
     pub fn update(&mut self) {
         if let Ok(view_model) = self.ledger_receiver.try_recv() {
-            // テーブルデータを構築
-            let rows: Vec<Vec<String>> = view_model
-                .entries
-                .iter()
-                .map(|entry| {
-                    vec![
-                        entry.transaction_date.clone(),
-                        entry.entry_number.clone(),
-                        truncate_text!(&entry.description, 33),
+            // 情報パネルを更新
+            self.update_info_panel(&view_model);
+
+            self.current_ledger = Some(view_model);
+            self.rebuild_rows();
+        }
+    }
+
+    /// 表示中の元帳から行データを構築（注記付きのみの絞り込みを反映）
+    fn rebuild_rows(&mut self) {
+        let Some(view_model) = &self.current_ledger else {
+            return;
+        };
+        let masked = view_model.amounts_masked;
+        let masked_cell = || format!("{:>11}", AmountMask::MASKED);
+        let entries: Vec<&LedgerEntryViewModel> = view_model
+            .entries
+            .iter()
+            .filter(|entry| !self.flagged_only || entry.open_annotations > 0)
+            .collect();
+
+        // テーブルデータを構築
+        let rows: Vec<Vec<String>> = entries
+            .iter()
+            .map(|entry| {
+                let (debit, credit, balance) = if masked {
+                    (masked_cell(), masked_cell(), masked_cell())
+                } else if entry.carried_forward {
+                    (String::new(), String::new(), format_balance!(entry.balance, 11))
+                } else {
+                    (
                         format_amount!(entry.debit_amount, 11),
                         format_amount!(entry.credit_amount, 11),
                         format_balance!(entry.balance, 11),
-                    ]
-                })
-                .collect();
+                    )
+                };
+                let description = if entry.open_annotations > 0 {
+                    format!("{} {}", ANNOTATION_FLAG, truncate_text!(&entry.description, 31))
+                } else {
+                    truncate_text!(&entry.description, 33)
+                };
+                vec![
+                    entry.transaction_date.clone(),
+                    entry.entry_number.clone(),
+                    description,
+                    debit,
+                    credit,
+                    balance,
+                ]
+            })
+            .collect();
 
-            self.ledger_table.set_data(rows);
+        let export_amount = |value: f64| {
+            if masked {
+                AmountMask::MASKED.to_string()
+            } else {
+                value.to_string()
+            }
+        };
 
-            // 情報パネルを更新
-            self.update_info_panel(&view_model);
+        self.export_rows = entries
+            .iter()
+            .map(|entry| {
+                vec![
+                    entry.transaction_date.clone(),
+                    entry.entry_number.clone(),
+                    entry.description.clone(),
+                    export_amount(entry.debit_amount),
+                    export_amount(entry.credit_amount),
+                    export_amount(entry.balance),
+                ]
+            })
+            .collect();
 
-            self.current_ledger = Some(view_model);
-        }
+        self.ledger_table.set_data(rows);
+    }
+
+    /// 未解決の注記がある明細のみの表示を切り替え
+    pub fn toggle_flagged_only(&mut self) {
+        self.flagged_only = !self.flagged_only;
+        self.rebuild_rows();
     }
 
     /// 情報パネルを更新
@@ -94,6 +165,13 @@ impl LedgerPage {
         self.info_panel.add_line("科目コード", &ledger.account_code);
         self.info_panel.add_line("科目名", &ledger.account_name);
         self.info_panel.add_text("━━━━━━━━━━━━━━");
+        if ledger.amounts_masked {
+            for label in ["期首残高", "当期借方", "当期貸方", "期末残高"] {
+                self.info_panel.add_line(label, AmountMask::MASKED);
+            }
+            self.info_panel.add_text("━━━━━━━━━━━━━━");
+            return;
+        }
         self.info_panel.add_line("期首残高", &format_balance!(ledger.opening_balance));
         self.info_panel.add_line("当期借方", &format_amount!(ledger.total_debit));
         self.info_panel.add_line("当期貸方", &format_amount!(ledger.total_credit));
@@ -116,10 +194,55 @@ impl LedgerPage {
     pub fn selected_index(&self) -> Option<usize> {
         self.ledger_table.selected_index()
     }
+
+    /// 元帳テーブル（表示設定の変更用）
+    pub fn ledger_table_mut(&mut self) -> &mut DataTable {
+        &mut self.ledger_table
+    }
+
+    /// 出力ファイル名入力を表示
+    pub fn open_export_prompt(&mut self) {
+        let Some(ledger) = &self.current_ledger else {
+            self.event_viewer.add_info("出力する元帳がありません");
+            return;
+        };
+        let today = crate::clock::business_date().format("%Y%m%d");
+        self.export_prompt.open(format!("ledger_{}_{}", ledger.account_code, today));
+    }
+
+    pub fn is_export_prompt_visible(&self) -> bool {
+        self.export_prompt.is_visible()
+    }
+
+    pub fn export_prompt_mut(&mut self) -> &mut ExportPrompt {
+        &mut self.export_prompt
+    }
+
+    /// 表示中の並び・列で元帳を出力形式に変換（戻り値は内容と件数）
+    pub fn export_content(&self, format: ExportFormat) -> (String, usize) {
+        (
+            self.ledger_table.export(&self.export_rows, format),
+            self.ledger_table.row_count(),
+        )
+    }
+
+    pub fn add_info(&mut self, message: impl Into<String>) {
+        self.event_viewer.add_info(message);
+    }
+
+    pub fn add_error(&mut self, message: impl Into<String>) {
+        self.event_viewer.add_error(message);
+    }
+
     /// 選択中のエントリを取得
-    pub fn get_selected_entry(&self) -> Option<&crate::presenter::LedgerEntryViewModel> {
+    pub fn get_selected_entry(&self) -> Option<&LedgerEntryViewModel> {
         let index = self.selected_index()?;
-        self.current_ledger.as_ref()?.entries.get(index)
+        self.current_ledger
+            .as_ref()?
+            .entries
+            .iter()
+            .filter(|entry| !self.flagged_only || entry.open_annotations > 0)
+            .nth(index)
     }
 
     /// 勘定科目コードを取得
@@ -157,11 +280,23 @@ impl LedgerPage {
         // 元帳テーブル
         self.ledger_table.render(frame, main_chunks[0]);
 
+        // 右側を上下に分割（情報パネル + イベントビューア）
+        let side_chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(12), Constraint::Length(8)])
+            .split(main_chunks[1]);
+
         // 情報パネル
-        self.info_panel.render(frame, main_chunks[1]);
+        self.info_panel.render(frame, side_chunks[0]);
+
+        // イベントビューア
+        self.event_viewer.render(frame, side_chunks[1]);
 
         // ステータスバー（レトロな雰囲気）
         self.render_status_bar(frame, chunks[1]);
+
+        // 出力ファイル名入力を最前面に描画
+        self.export_prompt.render(frame, area);
     }
 
     /// ステータスバーを描画（レトロな雰囲気）
@@ -180,9 +315,31 @@ impl LedgerPage {
             Span::styled("[Enter] ", Style::default().fg(Color::DarkGray)),
             Span::styled("詳細", Style::default().fg(Color::Gray)),
             Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+            Span::styled("[s] ", Style::default().fg(Color::DarkGray)),
+            Span::styled("並替", Style::default().fg(Color::Gray)),
+            Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+            Span::styled("[<>] ", Style::default().fg(Color::DarkGray)),
+            Span::styled("列幅", Style::default().fg(Color::Gray)),
+            Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+            Span::styled("[x] ", Style::default().fg(Color::DarkGray)),
+            Span::styled("出力", Style::default().fg(Color::Gray)),
+            Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+            Span::styled("[f] ", Style::default().fg(Color::DarkGray)),
+            if self.flagged_only {
+                Span::styled(
+                    format!("{} 注記付きのみ", ANNOTATION_FLAG),
+                    Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+                )
+            } else {
+                Span::styled("注記付き絞込", Style::default().fg(Color::Gray))
+            },
+            Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
             Span::styled("[F2] ", Style::default().fg(Color::DarkGray)),
             Span::styled("科目変更", Style::default().fg(Color::Gray)),
             Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+            Span::styled("[F5] ", Style::default().fg(Color::DarkGray)),
+            Span::styled("再読込", Style::default().fg(Color::Gray)),
+            Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
             Span::styled("[Esc] ", Style::default().fg(Color::DarkGray)),
             Span::styled("戻る", Style::default().fg(Color::Gray)),
             Span::styled(
@@ -64,12 +64,18 @@ impl LedgerViewPage {
                 .entries
                 .iter()
                 .map(|entry| {
+                    // 前月繰越行は残高のみ表示する
+                    let (debit, credit) = if entry.carried_forward {
+                        (String::new(), String::new())
+                    } else {
+                        (format_amount!(entry.debit_amount), format_amount!(entry.credit_amount))
+                    };
                     vec![
                         entry.transaction_date.clone(),
                         entry.entry_number.clone(),
                         truncate_text!(&entry.description, 28),
-                        format_amount!(entry.debit_amount),
-                        format_amount!(entry.credit_amount),
+                        debit,
+                        credit,
                         format_balance!(entry.balance),
                     ]
                 })
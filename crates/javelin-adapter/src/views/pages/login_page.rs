@@ -0,0 +1,242 @@
+// LoginPage - ログイン画面
+// 責務: 資格情報の入力とロック解除
+
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Flex, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph},
+};
+
+/// 入力中の項目
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoginField {
+    UserId,
+    Password,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Message {
+    None,
+    Status(String),
+    Error(String),
+}
+
+pub struct LoginPage {
+    user_id: String,
+    password: String,
+    focus: LoginField,
+    /// ロック解除モード（ユーザIDは固定）
+    locked_user: Option<String>,
+    submitting: bool,
+    message: Message,
+    animation_frame: usize,
+}
+
+impl LoginPage {
+    pub fn new() -> Self {
+        Self {
+            user_id: String::new(),
+            password: String::new(),
+            focus: LoginField::UserId,
+            locked_user: None,
+            submitting: false,
+            message: Message::None,
+            animation_frame: 0,
+        }
+    }
+
+    /// ロック解除モードに切り替え（ユーザIDを固定し、パスワード入力から開始）
+    pub fn set_locked_user(&mut self, user_id: impl Into<String>) {
+        let user_id = user_id.into();
+        self.user_id = user_id.clone();
+        self.locked_user = Some(user_id);
+        self.password.clear();
+        self.focus = LoginField::Password;
+        self.message = Message::Status("無操作のためロックしました".to_string());
+    }
+
+    pub fn is_unlock_mode(&self) -> bool {
+        self.locked_user.is_some()
+    }
+
+    pub fn user_id(&self) -> &str {
+        &self.user_id
+    }
+
+    pub fn password(&self) -> &str {
+        &self.password
+    }
+
+    pub fn focus(&self) -> LoginField {
+        self.focus
+    }
+
+    pub fn is_submitting(&self) -> bool {
+        self.submitting
+    }
+
+    /// 入力項目を切り替え（ロック解除モードではパスワードのみ）
+    pub fn toggle_focus(&mut self) {
+        if self.is_unlock_mode() {
+            return;
+        }
+        self.focus = match self.focus {
+            LoginField::UserId => LoginField::Password,
+            LoginField::Password => LoginField::UserId,
+        };
+    }
+
+    pub fn input_char(&mut self, c: char) {
+        if self.submitting {
+            return;
+        }
+        match self.focus {
+            LoginField::UserId => self.user_id.push(c),
+            LoginField::Password => self.password.push(c),
+        }
+    }
+
+    pub fn delete_char(&mut self) {
+        if self.submitting {
+            return;
+        }
+        match self.focus {
+            LoginField::UserId => {
+                self.user_id.pop();
+            }
+            LoginField::Password => {
+                self.password.pop();
+            }
+        }
+    }
+
+    /// 認証中に切り替え
+    pub fn start_submitting(&mut self) {
+        self.submitting = true;
+        self.message = Message::Status("認証しています...".to_string());
+    }
+
+    /// 認証失敗（パスワードを消去して再入力させる）
+    pub fn set_error(&mut self, message: impl Into<String>) {
+        self.submitting = false;
+        self.password.clear();
+        self.focus = LoginField::Password;
+        self.message = Message::Error(message.into());
+    }
+
+    pub fn tick(&mut self) {
+        self.animation_frame = (self.animation_frame + 1) % 60;
+    }
+
+    pub fn render(&mut self, frame: &mut Frame) {
+        let [area] = Layout::horizontal([Constraint::Length(56)])
+            .flex(Flex::Center)
+            .areas(frame.area());
+        let [area] = Layout::vertical([Constraint::Length(14)]).flex(Flex::Center).areas(area);
+
+        let title = if self.is_unlock_mode() {
+            " ◆ Javelin - ロック解除 ◆ "
+        } else {
+            " ◆ Javelin - ログイン ◆ "
+        };
+        let block = Block::default()
+            .title(title)
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(Color::Cyan));
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(1)
+            .constraints([
+                Constraint::Length(3),
+                Constraint::Length(3),
+                Constraint::Length(2),
+                Constraint::Min(1),
+            ])
+            .split(inner);
+
+        let masked = "*".repeat(self.password.chars().count());
+        self.render_field(frame, chunks[0], "ユーザID", &self.user_id, LoginField::UserId);
+        self.render_field(frame, chunks[1], "パスワード", &masked, LoginField::Password);
+        self.render_message(frame, chunks[2]);
+        self.render_help(frame, chunks[3]);
+    }
+
+    fn render_field(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        label: &str,
+        value: &str,
+        field: LoginField,
+    ) {
+        let focused = self.focus == field && !self.submitting;
+        let fixed = field == LoginField::UserId && self.is_unlock_mode();
+        let cursor = if focused && self.animation_frame < 30 {
+            "▮"
+        } else {
+            " "
+        };
+
+        let border_color = if focused {
+            Color::Cyan
+        } else {
+            Color::DarkGray
+        };
+        let value_color = if fixed { Color::DarkGray } else { Color::White };
+
+        let paragraph = Paragraph::new(Line::from(vec![
+            Span::styled(value.to_string(), Style::default().fg(value_color)),
+            Span::styled(cursor, Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+        ]))
+        .block(
+            Block::default()
+                .title(format!(" {} ", label))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Plain)
+                .border_style(Style::default().fg(border_color)),
+        );
+
+        frame.render_widget(paragraph, area);
+    }
+
+    fn render_message(&self, frame: &mut Frame, area: Rect) {
+        let line = match &self.message {
+            Message::None => Line::from(""),
+            Message::Status(message) => {
+                Line::from(Span::styled(message.clone(), Style::default().fg(Color::Gray)))
+            }
+            Message::Error(message) => {
+                Line::from(Span::styled(message.clone(), Style::default().fg(Color::Red)))
+            }
+        };
+        frame.render_widget(Paragraph::new(line), area);
+    }
+
+    fn render_help(&self, frame: &mut Frame, area: Rect) {
+        let mut spans = Vec::new();
+        if !self.is_unlock_mode() {
+            spans.push(Span::styled("[Tab] ", Style::default().fg(Color::DarkGray)));
+            spans.push(Span::styled("項目切替", Style::default().fg(Color::Gray)));
+            spans.push(Span::styled(" │ ", Style::default().fg(Color::DarkGray)));
+        }
+        spans.push(Span::styled("[Enter] ", Style::default().fg(Color::DarkGray)));
+        spans.push(Span::styled("ログイン", Style::default().fg(Color::Gray)));
+        spans.push(Span::styled(" │ ", Style::default().fg(Color::DarkGray)));
+        spans.push(Span::styled("[Esc] ", Style::default().fg(Color::DarkGray)));
+        spans.push(Span::styled("終了", Style::default().fg(Color::Gray)));
+
+        frame.render_widget(Paragraph::new(Line::from(spans)), area);
+    }
+}
+
+impl Default for LoginPage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
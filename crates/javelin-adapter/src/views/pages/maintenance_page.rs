@@ -0,0 +1,297 @@
+// MaintenancePage - メンテナンス画面のビューコンポーネント
+// 責務: Projection間整合性チェック結果と修復方法、ストレージ使用状況の推移、
+//       進捗通知の集約・破棄件数の表示
+
+use ratatui::{
+    Frame,
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Sparkline, Table, TableState, Wrap},
+};
+
+use crate::presenter::{
+    ConsistencyCheckViewModel, ConsistencyViolationViewModel, ProgressChannelStats,
+    StorageTrendViewModel,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+enum CheckState {
+    NotRun,
+    Running,
+    Completed,
+    Error(String),
+}
+
+pub struct MaintenancePage {
+    view_model: ConsistencyCheckViewModel,
+    table_state: TableState,
+    check_state: CheckState,
+    /// 操作結果の通知（ステータスバーに表示）
+    notice: Option<String>,
+    progress_stats: ProgressChannelStats,
+    storage_trend: StorageTrendViewModel,
+    /// 推移の読み込みエラー
+    storage_trend_error: Option<String>,
+}
+
+impl MaintenancePage {
+    pub fn new() -> Self {
+        Self {
+            view_model: ConsistencyCheckViewModel::default(),
+            table_state: TableState::default(),
+            check_state: CheckState::NotRun,
+            notice: None,
+            progress_stats: ProgressChannelStats::default(),
+            storage_trend: StorageTrendViewModel::default(),
+            storage_trend_error: None,
+        }
+    }
+
+    /// チェック実行中の場合はtrue
+    pub fn is_running(&self) -> bool {
+        self.check_state == CheckState::Running
+    }
+
+    pub fn set_running(&mut self) {
+        self.check_state = CheckState::Running;
+    }
+
+    pub fn set_result(&mut self, view_model: ConsistencyCheckViewModel) {
+        let selected = if view_model.violations.is_empty() {
+            None
+        } else {
+            Some(0)
+        };
+        self.table_state.select(selected);
+        self.view_model = view_model;
+        self.check_state = CheckState::Completed;
+    }
+
+    pub fn set_error(&mut self, error: String) {
+        self.check_state = CheckState::Error(error);
+    }
+
+    pub fn set_notice(&mut self, notice: String) {
+        self.notice = Some(notice);
+    }
+
+    /// 進捗通知チャネルの集約・破棄件数を更新
+    pub fn set_progress_stats(&mut self, stats: ProgressChannelStats) {
+        self.progress_stats = stats;
+    }
+
+    /// ストレージ使用状況の推移を更新
+    pub fn set_storage_trend(&mut self, storage_trend: StorageTrendViewModel) {
+        self.storage_trend = storage_trend;
+        self.storage_trend_error = None;
+    }
+
+    pub fn set_storage_trend_error(&mut self, error: String) {
+        self.storage_trend_error = Some(error);
+    }
+
+    /// 選択中の違反
+    pub fn selected_violation(&self) -> Option<&ConsistencyViolationViewModel> {
+        self.table_state
+            .selected()
+            .and_then(|index| self.view_model.violations.get(index))
+    }
+
+    pub fn select_next(&mut self) {
+        let len = self.view_model.violations.len();
+        if len > 0 {
+            let next = self.table_state.selected().map_or(0, |i| (i + 1).min(len - 1));
+            self.table_state.select(Some(next));
+        }
+    }
+
+    pub fn select_previous(&mut self) {
+        if !self.view_model.violations.is_empty() {
+            let previous = self.table_state.selected().map_or(0, |i| i.saturating_sub(1));
+            self.table_state.select(Some(previous));
+        }
+    }
+
+    pub fn render(&mut self, frame: &mut Frame) {
+        let area = frame.area();
+        let chunks = Layout::vertical([
+            Constraint::Min(0),
+            Constraint::Length(6),
+            Constraint::Length(8),
+            Constraint::Length(4),
+        ])
+        .split(area);
+
+        let title = "メンテナンス - 整合性チェック";
+        match &self.check_state {
+            CheckState::NotRun => {
+                let message = Paragraph::new("[r] で仕訳一覧と元帳の整合性チェックを実行します")
+                    .block(Block::default().borders(Borders::ALL).title(title));
+                frame.render_widget(message, chunks[0]);
+            }
+            CheckState::Running => {
+                let message = Paragraph::new("チェック中...")
+                    .block(Block::default().borders(Borders::ALL).title(title));
+                frame.render_widget(message, chunks[0]);
+            }
+            CheckState::Error(error) => {
+                let message = Paragraph::new(error.as_str())
+                    .style(Style::default().fg(Color::Red))
+                    .block(Block::default().borders(Borders::ALL).title("エラー"));
+                frame.render_widget(message, chunks[0]);
+            }
+            CheckState::Completed if self.view_model.is_consistent() => {
+                let message = Paragraph::new(Line::from(vec![
+                    Span::styled(
+                        "整合性違反はありません  ",
+                        Style::default().fg(Color::Green).add_modifier(Modifier::BOLD),
+                    ),
+                    Span::raw(self.view_model.summary.as_str()),
+                ]))
+                .block(Block::default().borders(Borders::ALL).title(title));
+                frame.render_widget(message, chunks[0]);
+            }
+            CheckState::Completed => {
+                let header = Row::new(vec!["種別", "対象", "内容"])
+                    .style(Style::default().add_modifier(Modifier::BOLD));
+                let rows: Vec<Row> = self
+                    .view_model
+                    .violations
+                    .iter()
+                    .map(|v| {
+                        Row::new(vec![
+                            Cell::from(v.kind_label.as_str())
+                                .style(Style::default().fg(Color::Red)),
+                            Cell::from(v.subject.as_str()),
+                            Cell::from(v.detail.as_str()),
+                        ])
+                    })
+                    .collect();
+
+                let table = Table::new(
+                    rows,
+                    [Constraint::Length(16), Constraint::Length(38), Constraint::Min(20)],
+                )
+                .header(header)
+                .row_highlight_style(
+                    Style::default().bg(Color::DarkGray).add_modifier(Modifier::BOLD),
+                )
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .title(format!("{} ({})", title, self.view_model.summary)),
+                );
+
+                frame.render_stateful_widget(table, chunks[0], &mut self.table_state);
+            }
+        }
+
+        let suggestion = self
+            .selected_violation()
+            .map(|v| format!("{}\n{}", v.detail, v.suggestion))
+            .unwrap_or_default();
+        let suggestion_widget = Paragraph::new(suggestion)
+            .wrap(Wrap { trim: true })
+            .block(Block::default().borders(Borders::ALL).title("修復方法"));
+        frame.render_widget(suggestion_widget, chunks[1]);
+
+        self.render_storage_trend(frame, chunks[2]);
+
+        let mut status_block = Block::default().borders(Borders::ALL);
+        if let Some(notice) = &self.notice {
+            status_block = status_block.title(notice.as_str());
+        }
+        let progress_style = if self.progress_stats.dropped > 0 {
+            Style::default().fg(Color::Yellow)
+        } else {
+            Style::default().fg(Color::DarkGray)
+        };
+        let status_bar = Paragraph::new(vec![
+            Line::from(
+                "[r] 整合性チェック実行 [g] Projection圧縮 [s] 使用状況を記録 [p] Projection照会 [b] ジョブ一覧 [u] 利用状況 [a] アクセスログ [↑↓] 選択 [Esc] 戻る",
+            ),
+            Line::from(Span::styled(
+                format!(
+                    "進捗通知: 集約 {}件 / 容量超過で破棄 {}件",
+                    self.progress_stats.coalesced, self.progress_stats.dropped
+                ),
+                progress_style,
+            )),
+        ])
+        .block(status_block);
+        frame.render_widget(status_bar, chunks[3]);
+    }
+
+    /// 使用率・イベント件数・Projection遅延の推移
+    fn render_storage_trend(&self, frame: &mut Frame, area: Rect) {
+        let title = if self.storage_trend.is_empty() {
+            "ストレージ使用状況の推移".to_string()
+        } else {
+            format!("ストレージ使用状況の推移 {}", self.storage_trend.period_label)
+        };
+        let block = Block::default().borders(Borders::ALL).title(title);
+
+        if let Some(error) = &self.storage_trend_error {
+            let message = Paragraph::new(error.as_str())
+                .style(Style::default().fg(Color::Red))
+                .block(block);
+            frame.render_widget(message, area);
+            return;
+        }
+        if self.storage_trend.is_empty() {
+            let message =
+                Paragraph::new("記録された標本がありません（[s] で現在の使用状況を記録）")
+                    .block(block);
+            frame.render_widget(message, area);
+            return;
+        }
+
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let trend = &self.storage_trend;
+        let usage_color = if trend.usage_warning {
+            Color::Yellow
+        } else {
+            Color::Green
+        };
+        let charts = [
+            ("使用率", trend.usage_label.as_str(), trend.usage_series.as_slice(), usage_color),
+            (
+                "イベント",
+                trend.entries_label.as_str(),
+                trend.entries_series.as_slice(),
+                Color::Cyan,
+            ),
+            (
+                "Projection遅延",
+                trend.lag_label.as_str(),
+                trend.lag_series.as_slice(),
+                Color::Magenta,
+            ),
+        ];
+        let rows = Layout::vertical([Constraint::Ratio(1, 3); 3]).split(inner);
+        for ((label, value, series, color), row) in charts.into_iter().zip(rows.iter()) {
+            let columns =
+                Layout::horizontal([Constraint::Length(34), Constraint::Min(0)]).split(*row);
+            frame.render_widget(
+                Paragraph::new(format!("{:<14}{}", label, value)).style(Style::default().fg(color)),
+                columns[0],
+            );
+            // 右端に最新の標本が来るよう、表示幅に収まる直近の標本のみ描画する
+            let width = columns[1].width as usize;
+            let visible = &series[series.len().saturating_sub(width)..];
+            frame.render_widget(
+                Sparkline::default().data(visible).style(Style::default().fg(color)),
+                columns[1],
+            );
+        }
+    }
+}
+
+impl Default for MaintenancePage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,189 @@
+// NoteCrossReferencePage - 注記参照画面のビューコンポーネント
+// 責務: 注記の作成状況と参照する表示科目・勘定科目の一覧表示、編集欄の表示
+
+use ratatui::{
+    Frame,
+    layout::{Constraint, Layout},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState},
+};
+
+use crate::presenter::{NoteCrossReferenceViewModel, NoteSectionViewModel};
+
+#[derive(Debug, Clone, PartialEq)]
+enum LoadingState {
+    Loading,
+    Loaded,
+    Error(String),
+}
+
+pub struct NoteCrossReferencePage {
+    view_model: NoteCrossReferenceViewModel,
+    table_state: TableState,
+    loading_state: LoadingState,
+    status_message: Option<String>,
+}
+
+impl NoteCrossReferencePage {
+    pub fn new() -> Self {
+        Self {
+            view_model: NoteCrossReferenceViewModel::default(),
+            table_state: TableState::default(),
+            loading_state: LoadingState::Loading,
+            status_message: None,
+        }
+    }
+
+    /// 注記一覧を設定（選択位置は可能な限り維持）
+    pub fn set_data(&mut self, view_model: NoteCrossReferenceViewModel) {
+        let selected = self.table_state.selected().unwrap_or(0);
+        let selected = if view_model.items.is_empty() {
+            None
+        } else {
+            Some(selected.min(view_model.items.len() - 1))
+        };
+        self.table_state.select(selected);
+        self.view_model = view_model;
+        self.loading_state = LoadingState::Loaded;
+    }
+
+    pub fn set_error(&mut self, error: String) {
+        self.loading_state = LoadingState::Error(error);
+    }
+
+    pub fn set_status_message(&mut self, message: impl Into<String>) {
+        self.status_message = Some(message.into());
+    }
+
+    /// 選択中の注記
+    pub fn selected_item(&self) -> Option<&NoteSectionViewModel> {
+        self.table_state.selected().and_then(|index| self.view_model.items.get(index))
+    }
+
+    pub fn select_next(&mut self) {
+        let len = self.view_model.items.len();
+        if len > 0 {
+            let next = self.table_state.selected().map_or(0, |i| (i + 1).min(len - 1));
+            self.table_state.select(Some(next));
+        }
+    }
+
+    pub fn select_previous(&mut self) {
+        if !self.view_model.items.is_empty() {
+            let previous = self.table_state.selected().map_or(0, |i| i.saturating_sub(1));
+            self.table_state.select(Some(previous));
+        }
+    }
+
+    /// 描画（`input` は編集中の入力欄の見出しと入力値）
+    pub fn render(&mut self, frame: &mut Frame, input: Option<(&str, &str)>) {
+        let area = frame.area();
+
+        if self.loading_state == LoadingState::Loading {
+            let loading = Paragraph::new("読み込み中...")
+                .block(Block::default().borders(Borders::ALL).title("注記参照"));
+            frame.render_widget(loading, area);
+            return;
+        }
+
+        if let LoadingState::Error(error) = &self.loading_state {
+            let error_widget = Paragraph::new(error.as_str())
+                .style(Style::default().fg(Color::Red))
+                .block(Block::default().borders(Borders::ALL).title("エラー"));
+            frame.render_widget(error_widget, area);
+            return;
+        }
+
+        let chunks =
+            Layout::vertical([Constraint::Length(3), Constraint::Min(0), Constraint::Length(3)])
+                .split(area);
+
+        let summary_style = if self.view_model.has_missing_required {
+            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::Green)
+        };
+        let summary = Paragraph::new(Line::from(vec![
+            Span::styled(self.view_model.summary.as_str(), summary_style),
+            Span::styled(
+                "  （* は財務諸表の生成時に注記の作成が必須の参照先）",
+                Style::default().fg(Color::DarkGray),
+            ),
+        ]))
+        .block(Block::default().borders(Borders::ALL).title("注記参照"));
+        frame.render_widget(summary, chunks[0]);
+
+        let header = Row::new(vec!["番号", "表題", "状態", "参照先"])
+            .style(Style::default().add_modifier(Modifier::BOLD));
+
+        let rows: Vec<Row> = self
+            .view_model
+            .items
+            .iter()
+            .map(|item| {
+                let status_style = if item.is_missing_required {
+                    Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)
+                } else if item.is_drafted {
+                    Style::default().fg(Color::Green)
+                } else {
+                    Style::default().fg(Color::Yellow)
+                };
+
+                Row::new(vec![
+                    Cell::from(format!("注{}", item.number)),
+                    Cell::from(item.title.as_str()),
+                    Cell::from(item.status_label.as_str()).style(status_style),
+                    Cell::from(item.references.as_str()),
+                ])
+            })
+            .collect();
+
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Length(6),
+                Constraint::Length(24),
+                Constraint::Length(8),
+                Constraint::Min(20),
+            ],
+        )
+        .header(header)
+        .row_highlight_style(Style::default().bg(Color::DarkGray).add_modifier(Modifier::BOLD))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!("注記 ({}件)", self.view_model.items.len())),
+        );
+
+        frame.render_stateful_widget(table, chunks[1], &mut self.table_state);
+
+        let status_bar = if let Some((label, value)) = input {
+            Paragraph::new(Line::from(vec![
+                Span::styled(format!("{}: ", label), Style::default().fg(Color::Yellow)),
+                Span::raw(value),
+                Span::styled("▮", Style::default().fg(Color::Yellow)),
+            ]))
+            .block(Block::default().borders(Borders::ALL).title("[Enter] 確定 [Esc] キャンセル"))
+        } else {
+            let mut status = vec![Span::raw(
+                "[↑↓] 選択 [a] 注記追加 [e] 編集 [x] 削除 [c] 参照追加 [u] 参照解除 [r] 再読込 [Esc] 戻る",
+            )];
+            if let Some(message) = &self.status_message {
+                status.push(Span::styled(
+                    format!("  {}", message),
+                    Style::default().fg(Color::Green),
+                ));
+            }
+            Paragraph::new(Line::from(status)).block(Block::default().borders(Borders::ALL))
+        };
+
+        frame.render_widget(status_bar, chunks[2]);
+    }
+}
+
+impl Default for NoteCrossReferencePage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -66,13 +66,27 @@ impl NoteDraftPage {
             data.push(vec!["勘定科目内訳".to_string(), breakdown.account_code.clone(), components]);
         }
 
+        // 根拠資料
+        for document in &response.supporting_documents {
+            let reference = match &document.journal_entry_id {
+                Some(entry_id) => format!("{}（仕訳: {}）", document.summary, entry_id),
+                None => document.summary.clone(),
+            };
+            data.push(vec![
+                "根拠資料".to_string(),
+                format!("{} [{}]", document.title, document.document_id),
+                reference,
+            ]);
+        }
+
         self.note_table.set_data(data);
         self.loading_state = LoadingState::Loaded;
         self.event_viewer.add_info(format!(
-            "注記草案生成完了: 会計方針 {} 件、見積り {} 件、内訳 {} 件",
+            "注記草案生成完了: 会計方針 {} 件、見積り {} 件、内訳 {} 件、根拠資料 {} 件",
             response.accounting_policies.len(),
             response.significant_estimates.len(),
-            response.account_breakdowns.len()
+            response.account_breakdowns.len(),
+            response.supporting_documents.len()
         ));
     }
 
@@ -0,0 +1,249 @@
+// ProjectionConsolePage - Projection照会コンソールのビューコンポーネント
+// 責務: キー前方一致で取得した読み取りモデルの一覧と整形済みJSONの表示
+
+use javelin_application::dtos::response::{ProjectionRecordItem, QueryProjectionResponse};
+use ratatui::{
+    Frame,
+    layout::{Constraint, Layout},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState, Wrap},
+};
+
+#[derive(Debug, Clone, PartialEq)]
+enum ConsoleState {
+    Idle,
+    Loading,
+    Loaded,
+    Error(String),
+}
+
+pub struct ProjectionConsolePage {
+    /// 入力中の前方一致条件
+    prefix: String,
+    /// 前方一致条件を編集中
+    editing: bool,
+    records: Vec<ProjectionRecordItem>,
+    /// 表示中の前方一致条件（照会済み）
+    queried_prefix: String,
+    /// 表示中のページ番号（1始まり）
+    page_number: usize,
+    has_next_page: bool,
+    table_state: TableState,
+    /// 値表示欄のスクロール位置
+    value_scroll: u16,
+    state: ConsoleState,
+}
+
+impl ProjectionConsolePage {
+    pub fn new() -> Self {
+        Self {
+            prefix: String::new(),
+            editing: true,
+            records: Vec::new(),
+            queried_prefix: String::new(),
+            page_number: 1,
+            has_next_page: false,
+            table_state: TableState::default(),
+            value_scroll: 0,
+            state: ConsoleState::Idle,
+        }
+    }
+
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    pub fn is_editing(&self) -> bool {
+        self.editing
+    }
+
+    pub fn is_loading(&self) -> bool {
+        self.state == ConsoleState::Loading
+    }
+
+    pub fn start_editing(&mut self) {
+        self.editing = true;
+    }
+
+    pub fn stop_editing(&mut self) {
+        self.editing = false;
+    }
+
+    pub fn input_char(&mut self, c: char) {
+        self.prefix.push(c);
+    }
+
+    pub fn delete_char(&mut self) {
+        self.prefix.pop();
+    }
+
+    pub fn set_loading(&mut self) {
+        self.editing = false;
+        self.state = ConsoleState::Loading;
+    }
+
+    /// 照会結果を表示
+    pub fn set_result(&mut self, response: QueryProjectionResponse, page_number: usize) {
+        self.table_state.select(if response.records.is_empty() {
+            None
+        } else {
+            Some(0)
+        });
+        self.value_scroll = 0;
+        self.has_next_page = response.next_after.is_some();
+        self.queried_prefix = response.prefix;
+        self.records = response.records;
+        self.page_number = page_number;
+        self.state = ConsoleState::Loaded;
+    }
+
+    pub fn set_error(&mut self, error: String) {
+        self.state = ConsoleState::Error(error);
+    }
+
+    pub fn select_next(&mut self) {
+        let len = self.records.len();
+        if len > 0 {
+            let next = self.table_state.selected().map_or(0, |i| (i + 1).min(len - 1));
+            self.table_state.select(Some(next));
+            self.value_scroll = 0;
+        }
+    }
+
+    pub fn select_previous(&mut self) {
+        if !self.records.is_empty() {
+            let previous = self.table_state.selected().map_or(0, |i| i.saturating_sub(1));
+            self.table_state.select(Some(previous));
+            self.value_scroll = 0;
+        }
+    }
+
+    pub fn scroll_value_down(&mut self) {
+        self.value_scroll = self.value_scroll.saturating_add(10);
+    }
+
+    pub fn scroll_value_up(&mut self) {
+        self.value_scroll = self.value_scroll.saturating_sub(10);
+    }
+
+    fn selected_record(&self) -> Option<&ProjectionRecordItem> {
+        self.table_state.selected().and_then(|index| self.records.get(index))
+    }
+
+    pub fn render(&mut self, frame: &mut Frame) {
+        let area = frame.area();
+        let chunks =
+            Layout::vertical([Constraint::Length(3), Constraint::Min(0), Constraint::Length(3)])
+                .split(area);
+
+        self.render_prefix_input(frame, chunks[0]);
+
+        let body = Layout::horizontal([Constraint::Percentage(45), Constraint::Percentage(55)])
+            .split(chunks[1]);
+
+        let title = "Projection照会（読み取り専用）";
+        match &self.state {
+            ConsoleState::Idle => {
+                let message = Paragraph::new(
+                    "キーの前方一致条件（例: ledger:1000:2024:）を入力して Enter で照会します",
+                )
+                .wrap(Wrap { trim: true })
+                .block(Block::default().borders(Borders::ALL).title(title));
+                frame.render_widget(message, body[0]);
+            }
+            ConsoleState::Loading => {
+                let message = Paragraph::new("照会中...")
+                    .block(Block::default().borders(Borders::ALL).title(title));
+                frame.render_widget(message, body[0]);
+            }
+            ConsoleState::Error(error) => {
+                let message = Paragraph::new(error.as_str())
+                    .style(Style::default().fg(Color::Red))
+                    .wrap(Wrap { trim: true })
+                    .block(Block::default().borders(Borders::ALL).title("エラー"));
+                frame.render_widget(message, body[0]);
+            }
+            ConsoleState::Loaded => {
+                let header = Row::new(vec!["キー", "サイズ"])
+                    .style(Style::default().add_modifier(Modifier::BOLD));
+                let rows: Vec<Row> = self
+                    .records
+                    .iter()
+                    .map(|record| {
+                        Row::new(vec![
+                            Cell::from(record.key.as_str()),
+                            Cell::from(format!("{} B", record.size)),
+                        ])
+                    })
+                    .collect();
+
+                let page_label = format!(
+                    "{} - {} ({}ページ目 {}件{})",
+                    title,
+                    self.queried_prefix,
+                    self.page_number,
+                    self.records.len(),
+                    if self.has_next_page {
+                        "、続きあり"
+                    } else {
+                        ""
+                    }
+                );
+                let table = Table::new(rows, [Constraint::Min(20), Constraint::Length(10)])
+                    .header(header)
+                    .row_highlight_style(
+                        Style::default().bg(Color::DarkGray).add_modifier(Modifier::BOLD),
+                    )
+                    .block(Block::default().borders(Borders::ALL).title(page_label));
+
+                frame.render_stateful_widget(table, body[0], &mut self.table_state);
+            }
+        }
+
+        let (value_title, value) = match self.selected_record() {
+            Some(record) => (format!("値 - {}", record.key), record.value.as_str()),
+            None => ("値".to_string(), ""),
+        };
+        let value_widget = Paragraph::new(value)
+            .scroll((self.value_scroll, 0))
+            .block(Block::default().borders(Borders::ALL).title(value_title));
+        frame.render_widget(value_widget, body[1]);
+
+        let status_text = if self.editing {
+            "[Enter] 照会 [Esc] 入力終了"
+        } else {
+            "[/] 条件入力 [n] 次ページ [b] 前ページ [r] 再読込 [↑↓] 選択 [PgUp/PgDn] 値スクロール [Esc] 戻る"
+        };
+        let status_bar = Paragraph::new(status_text).block(Block::default().borders(Borders::ALL));
+        frame.render_widget(status_bar, chunks[2]);
+    }
+
+    fn render_prefix_input(&self, frame: &mut Frame, area: ratatui::layout::Rect) {
+        let border_color = if self.editing {
+            Color::Cyan
+        } else {
+            Color::DarkGray
+        };
+        let mut spans = vec![
+            Span::styled("キー前方一致: ", Style::default().fg(Color::Gray)),
+            Span::styled(self.prefix.as_str(), Style::default().fg(Color::White)),
+        ];
+        if self.editing {
+            spans.push(Span::styled("▮", Style::default().fg(Color::Cyan)));
+        }
+
+        let input = Paragraph::new(Line::from(spans)).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(border_color)),
+        );
+        frame.render_widget(input, area);
+    }
+}
+
+impl Default for ProjectionConsolePage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
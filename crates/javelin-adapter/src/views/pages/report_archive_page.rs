@@ -0,0 +1,271 @@
+// ReportArchivePage - アーカイブ済み帳票の一覧画面
+// 責務: 承認済み帳票の一覧、ハッシュ検証結果と承認時点の数値・再生成した数値との差異の表示
+
+use javelin_application::dtos::response::{
+    ArchivedReportResponse, ArchivedReportSummary, ReportComparisonResponse,
+};
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph},
+};
+
+use crate::{
+    format_balance,
+    views::components::{DataTable, EventViewer, InfoPanel},
+};
+
+/// 一覧に表示するハッシュ値の桁数
+const HASH_PREFIX_LENGTH: usize = 12;
+
+pub struct ReportArchivePage {
+    archive_table: DataTable,
+    info_panel: InfoPanel,
+    event_viewer: EventViewer,
+    summaries: Vec<ArchivedReportSummary>,
+    animation_frame: usize,
+}
+
+impl ReportArchivePage {
+    pub fn new() -> Self {
+        let headers = vec![
+            "帳票".to_string(),
+            "対象月".to_string(),
+            "承認者".to_string(),
+            "承認日時".to_string(),
+            "ハッシュ値".to_string(),
+        ];
+
+        let archive_table = DataTable::new("◆ アーカイブ済み帳票 ◆", headers)
+            .with_column_widths(vec![10, 9, 14, 20, 14]);
+
+        let mut event_viewer = EventViewer::new();
+        event_viewer.add_info("承認時に保存した帳票は変更できません。開くとハッシュ値を検証します");
+
+        Self {
+            archive_table,
+            info_panel: InfoPanel::new("◇ 帳票内容 ◇").with_border_color(Color::Cyan),
+            event_viewer,
+            summaries: Vec::new(),
+            animation_frame: 0,
+        }
+    }
+
+    /// 一覧を表示
+    pub fn set_summaries(&mut self, summaries: Vec<ArchivedReportSummary>) {
+        let rows = summaries
+            .iter()
+            .map(|summary| {
+                vec![
+                    summary.report_label.clone(),
+                    format!("{}-{:02}", summary.fiscal_year, summary.period),
+                    summary.signed_by.clone(),
+                    crate::clock::format_timestamp(&summary.signed_at),
+                    summary.content_hash.chars().take(HASH_PREFIX_LENGTH).collect(),
+                ]
+            })
+            .collect();
+        self.archive_table.set_data(rows);
+
+        if summaries.is_empty() {
+            self.event_viewer.add_info("アーカイブ済みの帳票はありません");
+        }
+        self.summaries = summaries;
+        self.show_selected_summary();
+    }
+
+    /// 選択中の帳票
+    pub fn selected(&self) -> Option<&ArchivedReportSummary> {
+        self.archive_table.selected_index().and_then(|index| self.summaries.get(index))
+    }
+
+    /// 開いた帳票の内容とハッシュ検証結果を表示
+    pub fn set_report(&mut self, report: &ArchivedReportResponse) {
+        self.info_panel.clear();
+        self.info_panel.add_line("アーカイブID", report.summary.archive_id.as_str());
+        self.info_panel.add_line(
+            "承認",
+            format!(
+                "{} / {}",
+                report.summary.signed_by,
+                crate::clock::format_timestamp(&report.summary.signed_at)
+            ),
+        );
+        if report.hash_verified {
+            self.info_panel.add_success("ハッシュ値一致: 承認時点から変更されていません");
+        } else {
+            self.info_panel.add_error("ハッシュ値不一致: 承認後に内容が変更されています");
+        }
+        for (key, value) in &report.parameters {
+            self.info_panel.add_line(key.as_str(), value.as_str());
+        }
+        for line in &report.lines {
+            self.info_panel.add_line(line.label.as_str(), format_balance!(line.amount));
+        }
+
+        if report.hash_verified {
+            self.event_viewer
+                .add_info(format!("{} を開きました（ハッシュ値一致）", report.summary.archive_id));
+        } else {
+            self.event_viewer.add_error(format!(
+                "{} のハッシュ値が一致しません。保存内容が改ざんされた可能性があります",
+                report.summary.archive_id
+            ));
+        }
+    }
+
+    /// 再生成した数値との比較結果を表示
+    pub fn set_comparison(&mut self, comparison: &ReportComparisonResponse) {
+        self.info_panel.clear();
+        self.info_panel.add_line("アーカイブID", comparison.archive_id.as_str());
+        if comparison.differences.is_empty() {
+            self.info_panel.add_success("再生成した数値は承認時点と一致しています");
+            self.event_viewer
+                .add_info(format!("{}: 再生成した数値と一致しました", comparison.archive_id));
+            return;
+        }
+
+        self.info_panel.add_warning(format!(
+            "承認時点と異なる表示科目が {}件あります",
+            comparison.differences.len()
+        ));
+        for difference in &comparison.differences {
+            let amount = |value: Option<f64>| {
+                value
+                    .map(|amount| format_balance!(amount))
+                    .unwrap_or_else(|| "なし".to_string())
+            };
+            self.info_panel.add_line(
+                difference.label.as_str(),
+                format!(
+                    "{} → {}（差額 {}）",
+                    amount(difference.archived_amount),
+                    amount(difference.current_amount),
+                    format_balance!(difference.difference)
+                ),
+            );
+        }
+        self.event_viewer.add_error(format!(
+            "{}: 承認後に数値が変わっています（{}件）",
+            comparison.archive_id,
+            comparison.differences.len()
+        ));
+    }
+
+    pub fn set_error(&mut self, error: String) {
+        self.summaries.clear();
+        self.archive_table.set_error(error.clone());
+        self.info_panel.clear();
+        self.event_viewer.add_error(error);
+    }
+
+    pub fn start_loading(&mut self) {
+        self.archive_table.start_loading();
+    }
+
+    pub fn add_info(&mut self, message: impl Into<String>) {
+        self.event_viewer.add_info(message);
+    }
+
+    pub fn add_error(&mut self, message: impl Into<String>) {
+        self.event_viewer.add_error(message);
+    }
+
+    pub fn select_next(&mut self) {
+        self.archive_table.select_next();
+        self.show_selected_summary();
+    }
+
+    pub fn select_previous(&mut self) {
+        self.archive_table.select_previous();
+        self.show_selected_summary();
+    }
+
+    /// 選択行の概要を表示（内容は開いたときに表示する）
+    fn show_selected_summary(&mut self) {
+        self.info_panel.clear();
+        let Some(summary) = self.selected().cloned() else {
+            self.info_panel.add_text("帳票を選択してください");
+            return;
+        };
+
+        self.info_panel.add_line("アーカイブID", summary.archive_id);
+        self.info_panel.add_line("ハッシュ値", summary.content_hash);
+        self.info_panel.add_text("[Enter] で開いてハッシュ値を検証します");
+    }
+
+    pub fn tick(&mut self) {
+        self.animation_frame = (self.animation_frame + 1) % 60;
+        self.archive_table.tick_loading();
+    }
+
+    pub fn render(&mut self, frame: &mut Frame) {
+        let area = frame.area();
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(10), Constraint::Length(3)])
+            .split(area);
+
+        let main_chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(chunks[0]);
+
+        let side_chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(10), Constraint::Length(8)])
+            .split(main_chunks[1]);
+
+        self.archive_table.render(frame, main_chunks[0]);
+        self.info_panel.render(frame, side_chunks[0]);
+        self.event_viewer.render(frame, side_chunks[1]);
+        self.render_status_bar(frame, chunks[1]);
+    }
+
+    fn render_status_bar(&self, frame: &mut Frame, area: Rect) {
+        let cursor = if self.animation_frame < 30 {
+            "▮"
+        } else {
+            " "
+        };
+
+        let status_text = vec![Line::from(vec![
+            Span::styled(" [↑↓] ", Style::default().fg(Color::DarkGray)),
+            Span::styled("選択", Style::default().fg(Color::Gray)),
+            Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+            Span::styled("[Enter] ", Style::default().fg(Color::DarkGray)),
+            Span::styled("開く・検証", Style::default().fg(Color::Gray)),
+            Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+            Span::styled("[c] ", Style::default().fg(Color::DarkGray)),
+            Span::styled("再生成して比較", Style::default().fg(Color::Gray)),
+            Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+            Span::styled("[r] ", Style::default().fg(Color::DarkGray)),
+            Span::styled("再読込", Style::default().fg(Color::Gray)),
+            Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+            Span::styled("[Esc] ", Style::default().fg(Color::DarkGray)),
+            Span::styled("戻る", Style::default().fg(Color::Gray)),
+            Span::styled(
+                format!(" {}", cursor),
+                Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+            ),
+        ])];
+
+        let paragraph = Paragraph::new(status_text).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Plain)
+                .border_style(Style::default().fg(Color::DarkGray)),
+        );
+
+        frame.render_widget(paragraph, area);
+    }
+}
+
+impl Default for ReportArchivePage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
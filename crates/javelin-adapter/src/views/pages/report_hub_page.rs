@@ -0,0 +1,176 @@
+// ReportHubPage - 帳票メニュー画面
+// 責務: 定型帳票の一覧、実行条件の入力フォーム、最近使った条件の表示
+
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph},
+};
+
+use crate::views::components::{EventViewer, InfoPanel, ListItemData, ListSelector, ParameterForm};
+
+pub struct ReportHubPage {
+    report_list: ListSelector,
+    form: ParameterForm,
+    recent_panel: InfoPanel,
+    event_viewer: EventViewer,
+    /// 条件入力中か
+    editing: bool,
+    animation_frame: usize,
+}
+
+impl ReportHubPage {
+    pub fn new(reports: Vec<ListItemData>, form: ParameterForm) -> Self {
+        let mut report_list = ListSelector::new("◆ 帳票メニュー ◆", reports);
+        report_list.set_active(true);
+
+        let mut event_viewer = EventViewer::new();
+        event_viewer.add_info("帳票を選択し、[Enter] で条件を入力して実行します");
+
+        Self {
+            report_list,
+            form,
+            recent_panel: InfoPanel::new("◇ 最近使った条件 ◇").with_border_color(Color::Cyan),
+            event_viewer,
+            editing: false,
+            animation_frame: 0,
+        }
+    }
+
+    pub fn selected_index(&self) -> Option<usize> {
+        self.report_list.selected_index()
+    }
+
+    pub fn select_next(&mut self) {
+        self.report_list.select_next();
+    }
+
+    pub fn select_previous(&mut self) {
+        self.report_list.select_previous();
+    }
+
+    /// 選択した帳票の条件入力フォームに差し替える
+    pub fn set_form(&mut self, form: ParameterForm) {
+        self.form = form;
+        self.form.set_active(self.editing);
+    }
+
+    pub fn form_mut(&mut self) -> &mut ParameterForm {
+        &mut self.form
+    }
+
+    pub fn is_editing(&self) -> bool {
+        self.editing
+    }
+
+    /// 条件入力の開始・終了
+    pub fn set_editing(&mut self, editing: bool) {
+        self.editing = editing;
+        self.form.set_active(editing);
+        self.report_list.set_active(!editing);
+    }
+
+    /// 最近使った条件を表示（新しい順）
+    pub fn set_recent(&mut self, recent: Vec<String>) {
+        self.recent_panel.clear();
+        if recent.is_empty() {
+            self.recent_panel.add_text("最近使った条件はありません");
+            return;
+        }
+        for (index, label) in recent.into_iter().enumerate() {
+            self.recent_panel.add_line(format!("[{}]", index + 1), label);
+        }
+        self.recent_panel.add_text("[1-5] で条件を呼び出します");
+    }
+
+    pub fn set_recent_error(&mut self, error: String) {
+        self.recent_panel.clear();
+        self.recent_panel
+            .add_error(format!("最近使った条件を取得できません: {}", error));
+    }
+
+    pub fn add_info(&mut self, message: impl Into<String>) {
+        self.event_viewer.add_info(message);
+    }
+
+    pub fn add_error(&mut self, message: impl Into<String>) {
+        self.event_viewer.add_error(message);
+    }
+
+    pub fn tick(&mut self) {
+        self.animation_frame = (self.animation_frame + 1) % 60;
+    }
+
+    pub fn render(&mut self, frame: &mut Frame) {
+        let area = frame.area();
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(10), Constraint::Length(3)])
+            .split(area);
+
+        let main_chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(chunks[0]);
+
+        let side_chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(8), Constraint::Min(6), Constraint::Length(8)])
+            .split(main_chunks[1]);
+
+        self.report_list.render(frame, main_chunks[0]);
+        self.form.render(frame, side_chunks[0]);
+        self.recent_panel.render(frame, side_chunks[1]);
+        self.event_viewer.render(frame, side_chunks[2]);
+        self.render_status_bar(frame, chunks[1]);
+    }
+
+    fn render_status_bar(&self, frame: &mut Frame, area: Rect) {
+        let cursor = if self.animation_frame < 30 {
+            "▮"
+        } else {
+            " "
+        };
+
+        let keys: &[(&str, &str)] = if self.editing {
+            &[
+                ("[Tab/↑↓] ", "項目移動"),
+                ("[Space] ", "切替"),
+                ("[Enter] ", "実行"),
+                ("[Esc] ", "一覧へ"),
+            ]
+        } else {
+            &[
+                ("[↑↓] ", "選択"),
+                ("[Enter] ", "条件入力"),
+                ("[1-5] ", "最近の条件"),
+                ("[Esc] ", "戻る"),
+            ]
+        };
+
+        let mut spans = Vec::new();
+        for (index, (key, label)) in keys.iter().enumerate() {
+            if index > 0 {
+                spans.push(Span::styled(" │ ", Style::default().fg(Color::DarkGray)));
+            }
+            spans.push(Span::styled(format!(" {}", key), Style::default().fg(Color::DarkGray)));
+            spans.push(Span::styled(*label, Style::default().fg(Color::Gray)));
+        }
+        spans.push(Span::styled(
+            format!(" {}", cursor),
+            Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+        ));
+
+        let paragraph = Paragraph::new(vec![Line::from(spans)]).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Plain)
+                .border_style(Style::default().fg(Color::DarkGray)),
+        );
+
+        frame.render_widget(paragraph, area);
+    }
+}
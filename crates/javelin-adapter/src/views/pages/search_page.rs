@@ -1,6 +1,16 @@
 // SearchPage - 仕訳検索画面
 // 責務: 仕訳検索条件入力と検索結果表示
 
+use std::collections::{BTreeMap, HashSet};
+
+use chrono::NaiveDate;
+use javelin_application::{
+    dtos::{
+        request::{SEARCH_FIELD_FROM_DATE, SEARCH_FIELD_TO_DATE, SearchGrouping},
+        response::{ANNOTATION_FLAG, AmountMask},
+    },
+    parameter_validation::check_date_range,
+};
 use ratatui::{
     Frame,
     layout::{Alignment, Constraint, Direction, Layout, Rect},
@@ -13,11 +23,26 @@ use tokio::sync::mpsc;
 use crate::{
     format_amount,
     input_mode::{InputMode, JjEscapeDetector},
-    presenter::SearchResultViewModel,
+    presenter::{
+        JournalEntryItemViewModel, JournalEntryLineItemViewModel, ProgressReceiver,
+        SearchResultViewModel,
+    },
     truncate_text,
-    views::components::{DataTable, InputField, OverlaySelector},
+    views::{
+        components::{
+            CalendarPicker, DataTable, EventViewer, ExportFormat, ExportPrompt, FieldHelpOverlay,
+            InputField, OverlaySelector,
+        },
+        layouts::{column_window_start, fitting_columns, shows_side_panel},
+    },
 };
 
+/// 検索条件グリッドの列数
+const CRITERIA_COLUMNS: usize = 3;
+
+/// 検索条件グリッドの1列あたりの最小幅（未満なら列を折りたたむ）
+const CRITERIA_COLUMN_MIN_WIDTH: u16 = 32;
+
 /// 検索フィールド
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum SearchField {
@@ -26,8 +51,12 @@ enum SearchField {
     Description,
     AccountCode,
     DebitCredit,
+    VoucherNumber,
     MinAmount,
     MaxAmount,
+    EntryNumber,
+    FreeText,
+    Dimensions,
 }
 
 /// フォーカスエリア
@@ -45,8 +74,12 @@ impl SearchField {
             Self::Description,
             Self::AccountCode,
             Self::DebitCredit,
+            Self::VoucherNumber,
             Self::MinAmount,
             Self::MaxAmount,
+            Self::EntryNumber,
+            Self::FreeText,
+            Self::Dimensions,
         ]
     }
 
@@ -66,10 +99,11 @@ impl SearchField {
         }
     }
 
-    // 3列グリッドレイアウトでの移動
+    // 3列グリッド + 全幅1行のレイアウトでの移動
     // 左列: FromDate(0), ToDate(1), Description(2)
-    // 中央列: AccountCode(3), DebitCredit(4)
-    // 右列: MinAmount(5), MaxAmount(6)
+    // 中央列: AccountCode(3), DebitCredit(4), VoucherNumber(5)
+    // 右列: MinAmount(6), MaxAmount(7), EntryNumber(8)
+    // 最下段: FreeText(9), Dimensions(10)
 
     fn move_up(&self) -> Self {
         match self {
@@ -78,8 +112,12 @@ impl SearchField {
             Self::Description => Self::ToDate,
             Self::AccountCode => Self::AccountCode,
             Self::DebitCredit => Self::AccountCode,
+            Self::VoucherNumber => Self::DebitCredit,
             Self::MinAmount => Self::MinAmount,
             Self::MaxAmount => Self::MinAmount,
+            Self::EntryNumber => Self::MaxAmount,
+            Self::FreeText => Self::Description,
+            Self::Dimensions => Self::EntryNumber,
         }
     }
 
@@ -87,11 +125,15 @@ impl SearchField {
         match self {
             Self::FromDate => Self::ToDate,
             Self::ToDate => Self::Description,
-            Self::Description => Self::Description,
+            Self::Description => Self::FreeText,
             Self::AccountCode => Self::DebitCredit,
-            Self::DebitCredit => Self::DebitCredit,
+            Self::DebitCredit => Self::VoucherNumber,
+            Self::VoucherNumber => Self::FreeText,
             Self::MinAmount => Self::MaxAmount,
-            Self::MaxAmount => Self::MaxAmount,
+            Self::MaxAmount => Self::EntryNumber,
+            Self::EntryNumber => Self::Dimensions,
+            Self::FreeText => Self::FreeText,
+            Self::Dimensions => Self::Dimensions,
         }
     }
 
@@ -102,8 +144,12 @@ impl SearchField {
             Self::Description => Self::DebitCredit,
             Self::AccountCode => Self::FromDate,
             Self::DebitCredit => Self::ToDate,
+            Self::VoucherNumber => Self::Description,
             Self::MinAmount => Self::AccountCode,
             Self::MaxAmount => Self::DebitCredit,
+            Self::EntryNumber => Self::VoucherNumber,
+            Self::FreeText => Self::EntryNumber,
+            Self::Dimensions => Self::FreeText,
         }
     }
 
@@ -111,11 +157,24 @@ impl SearchField {
         match self {
             Self::FromDate => Self::AccountCode,
             Self::ToDate => Self::DebitCredit,
-            Self::Description => Self::DebitCredit,
+            Self::Description => Self::VoucherNumber,
             Self::AccountCode => Self::MinAmount,
             Self::DebitCredit => Self::MaxAmount,
+            Self::VoucherNumber => Self::EntryNumber,
             Self::MinAmount => Self::MinAmount,
             Self::MaxAmount => Self::FromDate,
+            Self::EntryNumber => Self::FreeText,
+            Self::FreeText => Self::Dimensions,
+            Self::Dimensions => Self::FromDate,
+        }
+    }
+
+    /// グリッド上の列（最下段のFreeTextは左列、Dimensionsは右列として扱う）
+    fn grid_column(&self) -> usize {
+        match self {
+            Self::FromDate | Self::ToDate | Self::Description | Self::FreeText => 0,
+            Self::AccountCode | Self::DebitCredit | Self::VoucherNumber => 1,
+            Self::MinAmount | Self::MaxAmount | Self::EntryNumber | Self::Dimensions => 2,
         }
     }
 }
@@ -134,14 +193,34 @@ pub struct SearchPage {
     description: InputField,
     account_code: InputField,
     debit_credit: InputField,
+    voucher_number: InputField,
     min_amount: InputField,
     max_amount: InputField,
+    entry_number: InputField,
+    free_text: InputField,
+    dimensions: InputField,
+    /// 未解決の注記がある仕訳のみ表示
+    flagged_only: bool,
+    /// 削除済みの仕訳も表示
+    include_deleted: bool,
+    /// 検索結果のグループ化
+    grouping: SearchGrouping,
+    /// 折りたたんだグループのキー
+    collapsed_groups: HashSet<String>,
+    /// 表示行ごとのグループキー（グループ小計行のみSome）
+    row_groups: Vec<Option<String>>,
     /// 検索結果テーブル
     result_table: DataTable,
+    /// 出力用の行データ（表示行と同じ並び、切り詰め前の値）
+    export_rows: Vec<Vec<String>>,
+    /// イベントビューア
+    event_viewer: EventViewer,
+    /// 出力ファイル名入力
+    export_prompt: ExportPrompt,
     /// ViewModelレシーバー
     result_receiver: mpsc::Receiver<SearchResultViewModel>,
     error_receiver: mpsc::Receiver<String>,
-    progress_receiver: mpsc::Receiver<String>,
+    progress_receiver: ProgressReceiver,
     execution_time_receiver: mpsc::Receiver<usize>,
     /// 現在表示中の検索結果
     current_result: Option<SearchResultViewModel>,
@@ -161,18 +240,25 @@ pub struct SearchPage {
     execution_time_ms: Option<usize>,
     /// オーバーレイセレクタ
     overlay_selector: OverlaySelector,
+    /// カレンダー選択オーバーレイ
+    calendar_picker: CalendarPicker,
+    /// フィールドヘルプオーバーレイ
+    field_help: FieldHelpOverlay,
     /// 科目マスター読み込み待機フラグ
     pending_account_load: bool,
     /// 科目マスターレシーバー（ViewModel用、unbounded）
     account_master_receiver_vm:
         Option<tokio::sync::mpsc::UnboundedReceiver<crate::presenter::AccountMasterViewModel>>,
+    /// カレンダーマスターレシーバー
+    calendar_master_receiver:
+        Option<tokio::sync::mpsc::UnboundedReceiver<crate::presenter::CalendarMasterViewModel>>,
 }
 
 impl SearchPage {
     pub fn new(
         result_receiver: mpsc::Receiver<SearchResultViewModel>,
         error_receiver: mpsc::Receiver<String>,
-        progress_receiver: mpsc::Receiver<String>,
+        progress_receiver: ProgressReceiver,
         execution_time_receiver: mpsc::Receiver<usize>,
     ) -> Self {
         // 検索結果テーブルのヘッダー
@@ -185,8 +271,10 @@ impl SearchPage {
             "金額".to_string(),
         ];
 
+        // 幅が足りない場合は 摘要 → 状態 → 伝票No の順に折りたたむ
         let result_table = DataTable::new("◆ 検索結果 ◆", headers)
-            .with_column_widths(vec![12, 15, 10, 30, 15, 13]);
+            .with_column_widths(vec![12, 15, 10, 30, 15, 13])
+            .with_collapse_order(vec![3, 2, 1]);
 
         Self {
             input_mode: InputMode::Normal,
@@ -209,13 +297,45 @@ impl SearchPage {
                 .with_input_type(crate::input_mode::ModifyInputType::BooleanToggle)
                 .with_boolean_labels("貸方", "借方")
                 .with_value("false".to_string()), // デフォルトは借方
+            voucher_number: InputField::new("伝票番号")
+                .with_placeholder("完全一致")
+                .with_input_type(crate::input_mode::ModifyInputType::Direct)
+                .with_help("起票時に入力した証憑の伝票番号")
+                .with_rule("完全一致で検索します"),
             min_amount: InputField::new("金額(最小)")
                 .with_placeholder("0")
-                .with_input_type(crate::input_mode::ModifyInputType::NumberOnly),
+                .with_input_type(crate::input_mode::ModifyInputType::NumberOnly)
+                .with_rule("最小金額は最大金額以下である必要があります"),
             max_amount: InputField::new("金額(最大)")
                 .with_placeholder("999999999")
-                .with_input_type(crate::input_mode::ModifyInputType::NumberOnly),
+                .with_input_type(crate::input_mode::ModifyInputType::NumberOnly)
+                .with_rule("最小金額は最大金額以下である必要があります"),
+            entry_number: InputField::new("記帳番号")
+                .with_placeholder("完全一致")
+                .with_input_type(crate::input_mode::ModifyInputType::Direct)
+                .with_help("記帳時に会社・年度ごとに採番される番号")
+                .with_rule("完全一致で検索します")
+                .with_example("EN-0001-2025-000001"),
+            free_text: InputField::new("フリーワード")
+                .with_placeholder("摘要・勘定科目・伝票番号・記帳番号のいずれかに部分一致")
+                .with_input_type(crate::input_mode::ModifyInputType::Direct)
+                .with_help("摘要・勘定科目・伝票番号・記帳番号をまとめて検索")
+                .with_rule("いずれかの項目に部分一致した仕訳を表示します"),
+            dimensions: InputField::new("分析軸")
+                .with_placeholder("project=P001")
+                .with_input_type(crate::input_mode::ModifyInputType::Direct)
+                .with_help("プロジェクト・セグメント等の分析軸で絞り込み")
+                .with_rule("<種類>=<コード> をカンマ区切りで指定し、すべてを持つ明細を含む仕訳を表示します")
+                .with_example("project=P001, segment=S1"),
+            flagged_only: false,
+            include_deleted: false,
+            grouping: SearchGrouping::None,
+            collapsed_groups: HashSet::new(),
+            row_groups: Vec::new(),
             result_table,
+            export_rows: Vec::new(),
+            event_viewer: EventViewer::new(),
+            export_prompt: ExportPrompt::new(),
             result_receiver,
             error_receiver,
             progress_receiver,
@@ -229,8 +349,11 @@ impl SearchPage {
             min_progress_display_duration: 500, // 0.5秒
             execution_time_ms: None,
             overlay_selector: OverlaySelector::new("勘定科目を選択"),
+            calendar_picker: CalendarPicker::new(),
+            field_help: FieldHelpOverlay::new(),
             pending_account_load: false,
             account_master_receiver_vm: None,
+            calendar_master_receiver: None,
         }
     }
 
@@ -250,6 +373,13 @@ impl SearchPage {
             self.pending_account_load = false;
         }
 
+        // カレンダーマスターを受信
+        if let Some(ref mut receiver) = self.calendar_master_receiver
+            && let Ok(view_model) = receiver.try_recv()
+        {
+            self.calendar_picker.set_calendar_master(view_model);
+        }
+
         // すべての進捗メッセージを消費（最新のものを保持）
         let mut latest_progress = None;
         while let Ok(progress_message) = self.progress_receiver.try_recv() {
@@ -280,46 +410,8 @@ impl SearchPage {
             };
 
             if should_display {
-                // テーブルデータを構築（明細を展開）
-                let mut rows: Vec<Vec<String>> = Vec::new();
-
-                for entry in &view_model.items {
-                    for (idx, line) in entry.lines.iter().enumerate() {
-                        let date = if idx == 0 {
-                            entry.transaction_date.clone()
-                        } else {
-                            "".to_string()
-                        };
-
-                        let entry_num = if idx == 0 {
-                            entry.entry_number.clone().unwrap_or_default()
-                        } else {
-                            "".to_string()
-                        };
-
-                        let status = if idx == 0 {
-                            entry.status_label.clone()
-                        } else {
-                            "".to_string()
-                        };
-
-                        rows.push(vec![
-                            date,
-                            entry_num,
-                            status,
-                            truncate_text!(&line.description, 28),
-                            format!(
-                                "{} {}",
-                                line.account_code,
-                                truncate_text!(&line.account_name, 8)
-                            ),
-                            format_amount!(line.amount, 11),
-                        ]);
-                    }
-                }
-
-                self.result_table.set_data(rows);
                 self.current_result = Some(view_model);
+                self.rebuild_rows();
                 self.error_message = None;
                 self.progress_display_start = None; // リセット
             } else {
@@ -330,6 +422,7 @@ impl SearchPage {
 
         // エラーメッセージを受信
         if let Ok(error) = self.error_receiver.try_recv() {
+            self.result_table.set_error(error.clone());
             self.error_message = Some(error);
             self.progress_display_start = None; // リセット
             self.pending_result = None; // 保留中の結果をクリア
@@ -341,6 +434,98 @@ impl SearchPage {
         }
     }
 
+    /// 表示中の検索結果からテーブルの行を構築
+    ///
+    /// グループ化した場合はグループごとに小計行を置き、展開中のグループの明細をその下に並べる。
+    fn rebuild_rows(&mut self) {
+        let Some(result) = &self.current_result else {
+            return;
+        };
+
+        let mut rows: Vec<Vec<String>> = Vec::new();
+        // 出力用は明細ごとに日付・伝票No・状態を繰り返す
+        let mut export_rows: Vec<Vec<String>> = Vec::new();
+        let mut row_groups: Vec<Option<String>> = Vec::new();
+
+        if result.grouping == SearchGrouping::None {
+            for entry in &result.items {
+                push_entry_rows(&mut rows, &mut export_rows, entry, |_| true);
+            }
+            row_groups.resize(rows.len(), None);
+        } else {
+            for group in &result.groups {
+                let collapsed = self.collapsed_groups.contains(&group.key);
+                let (debit, credit, export_debit, export_credit) = if group.amount_masked {
+                    let masked = AmountMask::MASKED.to_string();
+                    (masked.clone(), masked.clone(), masked.clone(), masked)
+                } else {
+                    (
+                        format_amount!(group.debit_total, 11),
+                        format_amount!(group.credit_total, 11),
+                        group.debit_total.to_string(),
+                        group.credit_total.to_string(),
+                    )
+                };
+                rows.push(vec![
+                    format!("{} {}", if collapsed { "▶" } else { "▼" }, group.label),
+                    String::new(),
+                    String::new(),
+                    format!("小計 {}件", group.entry_count),
+                    format!("借 {}", debit.trim_start()),
+                    format!("貸 {}", credit.trim_start()),
+                ]);
+                export_rows.push(vec![
+                    group.label.clone(),
+                    String::new(),
+                    String::new(),
+                    format!("小計 {}件", group.entry_count),
+                    format!("借方 {}", export_debit),
+                    format!("貸方 {}", export_credit),
+                ]);
+                row_groups.push(Some(group.key.clone()));
+
+                if collapsed {
+                    continue;
+                }
+                for entry in &result.items {
+                    push_entry_rows(&mut rows, &mut export_rows, entry, |line| {
+                        result.group_key(entry, line).as_deref() == Some(group.key.as_str())
+                    });
+                }
+                row_groups.resize(rows.len(), None);
+            }
+        }
+
+        self.result_table.set_data(rows);
+        self.export_rows = export_rows;
+        self.row_groups = row_groups;
+    }
+
+    /// 検索結果のグループ化を切り替え（次の検索から適用）
+    pub fn cycle_grouping(&mut self) {
+        self.grouping = self.grouping.next();
+        self.collapsed_groups.clear();
+    }
+
+    pub fn grouping(&self) -> SearchGrouping {
+        self.grouping
+    }
+
+    /// 選択中のグループ小計行の展開・折りたたみを切り替え（小計行でない場合はfalse）
+    pub fn toggle_selected_group(&mut self) -> bool {
+        let Some(key) = self
+            .selected_index()
+            .and_then(|index| self.row_groups.get(index).cloned().flatten())
+        else {
+            return false;
+        };
+        if !self.collapsed_groups.remove(&key) {
+            self.collapsed_groups.insert(key);
+        }
+        self.rebuild_rows();
+        true
+    }
+
     /// 次のフィールドにフォーカス
     pub fn focus_next_field(&mut self) {
         self.focused_field = self.focused_field.next();
@@ -399,6 +584,45 @@ impl SearchPage {
         self.result_table.selected_index()
     }
 
+    /// 出力ファイル名入力を表示
+    pub fn open_export_prompt(&mut self) {
+        if self.current_result.is_none() {
+            self.event_viewer.add_info("出力する検索結果がありません");
+            return;
+        }
+        let today = crate::clock::business_date().format("%Y%m%d");
+        self.export_prompt.open(format!("journal_search_{}", today));
+    }
+
+    pub fn is_export_prompt_visible(&self) -> bool {
+        self.export_prompt.is_visible()
+    }
+
+    pub fn export_prompt_mut(&mut self) -> &mut ExportPrompt {
+        &mut self.export_prompt
+    }
+
+    /// 表示中の並び・列で検索結果を出力形式に変換（戻り値は内容と件数）
+    pub fn export_content(&self, format: ExportFormat) -> (String, usize) {
+        (
+            self.result_table.export(&self.export_rows, format),
+            self.result_table.row_count(),
+        )
+    }
+
+    pub fn add_info(&mut self, message: impl Into<String>) {
+        self.event_viewer.add_info(message);
+    }
+
+    pub fn add_error(&mut self, message: impl Into<String>) {
+        self.event_viewer.add_error(message);
+    }
+
+    /// 検索結果テーブル（表示設定の変更用）
+    pub fn result_table_mut(&mut self) -> &mut DataTable {
+        &mut self.result_table
+    }
+
     /// 科目マスターレシーバーを設定（AccountMasterViewModel用、unbounded）
     pub fn set_account_master_receiver(
         &mut self,
@@ -407,6 +631,14 @@ impl SearchPage {
         self.account_master_receiver_vm = Some(receiver);
     }
 
+    /// カレンダーマスターレシーバーを設定
+    pub fn set_calendar_master_receiver(
+        &mut self,
+        receiver: tokio::sync::mpsc::UnboundedReceiver<crate::presenter::CalendarMasterViewModel>,
+    ) {
+        self.calendar_master_receiver = Some(receiver);
+    }
+
     /// 科目マスター読み込み待機中かどうか
     pub fn is_pending_account_load(&self) -> bool {
         self.pending_account_load
@@ -450,6 +682,56 @@ impl SearchPage {
         self.jj_detector.reset();
     }
 
+    /// フォーカス中の検索条件のヘルプを表示（検索結果エリアでは何もしない）
+    pub fn show_field_help(&mut self) {
+        if self.focus_area == FocusArea::Criteria {
+            let help = self.get_focused_field().help();
+            self.field_help.open(help);
+        }
+    }
+
+    /// フィールドヘルプが表示されているか
+    pub fn is_field_help_visible(&self) -> bool {
+        self.field_help.is_visible()
+    }
+
+    /// フィールドヘルプを閉じる
+    pub fn close_field_help(&mut self) {
+        self.field_help.close();
+    }
+
+    /// カレンダーが表示されているか
+    pub fn is_calendar_visible(&self) -> bool {
+        self.calendar_picker.is_visible()
+    }
+
+    /// カレンダーのカーソルを日単位で移動
+    pub fn calendar_move_days(&mut self, days: i64) {
+        self.calendar_picker.move_days(days);
+    }
+
+    /// カレンダーのカーソルを月単位で移動
+    pub fn calendar_move_months(&mut self, months: i32) {
+        self.calendar_picker.move_months(months);
+    }
+
+    /// カレンダーで選択を確定
+    pub fn calendar_confirm(&mut self) {
+        let selected = self.calendar_picker.selected_date().format("%Y%m%d").to_string();
+        self.get_focused_field_mut().set_value(selected);
+
+        self.calendar_picker.close();
+        self.input_mode = InputMode::Normal;
+        self.jj_detector.reset();
+    }
+
+    /// カレンダーをキャンセル
+    pub fn calendar_cancel(&mut self) {
+        self.calendar_picker.close();
+        self.input_mode = InputMode::Normal;
+        self.jj_detector.reset();
+    }
+
     /// オーバーレイセレクタが表示されているか
     pub fn is_overlay_visible(&self) -> bool {
         self.overlay_selector.is_visible()
@@ -473,6 +755,13 @@ impl SearchPage {
                 self.jj_detector.reset();
                 self.pending_account_load = true;
             }
+            crate::input_mode::ModifyInputType::Calendar => {
+                // カレンダー選択モードに入る（入力値の日付にカーソルを合わせる）
+                let current = NaiveDate::parse_from_str(field.value(), "%Y%m%d").ok();
+                self.calendar_picker.open(current);
+                self.input_mode = InputMode::Modify;
+                self.jj_detector.reset();
+            }
             _ => {
                 // その他の入力タイプ
                 field.set_focused(true);
@@ -494,8 +783,9 @@ impl SearchPage {
         // バリデーション結果に応じてエラーメッセージを更新
         match validation_result {
             Ok(_) => {
-                // 成功時はエラーメッセージをクリア
+                // 成功時はエラーメッセージをクリアし、日付の前後関係を再検証
                 self.error_message = None;
+                self.check_date_fields();
             }
             Err(error_msg) => {
                 // エラー時はメッセージを保存
@@ -506,6 +796,31 @@ impl SearchPage {
         self.jj_detector.reset();
     }
 
+    /// 開始日・終了日の前後関係と会計カレンダーの範囲を検証し、誤りのある項目に表示
+    fn check_date_fields(&mut self) {
+        let parse = |value: &str| NaiveDate::parse_from_str(&value.replace('-', ""), "%Y%m%d").ok();
+        let from = parse(self.from_date.value());
+        let to = parse(self.to_date.value());
+        let calendar = crate::clock::fiscal_calendar();
+
+        let from_error = from.and_then(|from| calendar.check_date(SEARCH_FIELD_FROM_DATE, from));
+        let to_error = to.and_then(|to| {
+            from.and_then(|from| check_date_range(from, SEARCH_FIELD_TO_DATE, to))
+                .or_else(|| calendar.check_date(SEARCH_FIELD_TO_DATE, to))
+        });
+
+        self.from_date.set_error(from_error.as_ref().map(|error| error.message.clone()));
+        self.to_date.set_error(to_error.as_ref().map(|error| error.message.clone()));
+        if let Some(error) = from_error.or(to_error) {
+            self.error_message = Some(format!("入力エラー: {}", error.message));
+        }
+    }
+
+    /// 項目間の検証エラーがあるか（検索の実行前に確認する）
+    pub fn has_field_errors(&self) -> bool {
+        self.from_date.error().is_some() || self.to_date.error().is_some()
+    }
+
     /// 文字を入力
     pub fn input_char(&mut self, ch: char) -> bool {
         if self.input_mode.is_modify() {
@@ -521,12 +836,12 @@ impl SearchPage {
             if self.jj_detector.has_pending_j()
                 && let Some(pending_j) = self.jj_detector.flush_pending()
             {
-                self.get_focused_field_mut().append_to_buffer(pending_j);
+                self.get_focused_field_mut().insert_char(pending_j);
             }
 
             // 今回の文字を入力
             if let Some(c) = char_to_input {
-                self.get_focused_field_mut().append_to_buffer(c);
+                self.get_focused_field_mut().insert_char(c);
             }
         }
         false
@@ -544,7 +859,47 @@ impl SearchPage {
         }
     }
 
+    /// カーソル位置の文字を削除（Deleteキー）
+    pub fn delete(&mut self) {
+        if self.input_mode.is_modify() {
+            self.flush_pending_j();
+            self.get_focused_field_mut().delete_at_cursor();
+        }
+    }
+
+    /// 入力中のカーソルを移動（←/→/Home/End）
+    pub fn move_cursor(&mut self, movement: fn(&mut InputField)) {
+        if self.input_mode.is_modify() {
+            self.flush_pending_j();
+            movement(self.get_focused_field_mut());
+        }
+    }
+
+    /// 保留中のjを確定してからカーソル操作を行う
+    fn flush_pending_j(&mut self) {
+        if let Some(pending_j) = self.jj_detector.flush_pending() {
+            self.get_focused_field_mut().insert_char(pending_j);
+        }
+    }
+
     /// フォーカス中のフィールドを取得
+    fn get_focused_field(&self) -> &InputField {
+        match self.focused_field {
+            SearchField::FromDate => &self.from_date,
+            SearchField::ToDate => &self.to_date,
+            SearchField::Description => &self.description,
+            SearchField::AccountCode => &self.account_code,
+            SearchField::DebitCredit => &self.debit_credit,
+            SearchField::VoucherNumber => &self.voucher_number,
+            SearchField::MinAmount => &self.min_amount,
+            SearchField::MaxAmount => &self.max_amount,
+            SearchField::EntryNumber => &self.entry_number,
+            SearchField::FreeText => &self.free_text,
+            SearchField::Dimensions => &self.dimensions,
+        }
+    }
+
+    /// フォーカス中のフィールドを取得（可変）
     fn get_focused_field_mut(&mut self) -> &mut InputField {
         match self.focused_field {
             SearchField::FromDate => &mut self.from_date,
@@ -552,8 +907,12 @@ impl SearchPage {
             SearchField::Description => &mut self.description,
             SearchField::AccountCode => &mut self.account_code,
             SearchField::DebitCredit => &mut self.debit_credit,
+            SearchField::VoucherNumber => &mut self.voucher_number,
             SearchField::MinAmount => &mut self.min_amount,
             SearchField::MaxAmount => &mut self.max_amount,
+            SearchField::EntryNumber => &mut self.entry_number,
+            SearchField::FreeText => &mut self.free_text,
+            SearchField::Dimensions => &mut self.dimensions,
         }
     }
 
@@ -566,9 +925,31 @@ impl SearchPage {
         self.debit_credit.set_value(String::new());
         self.min_amount.set_value(String::new());
         self.max_amount.set_value(String::new());
+        self.voucher_number.set_value(String::new());
+        self.entry_number.set_value(String::new());
+        self.free_text.set_value(String::new());
+        self.dimensions.set_value(String::new());
+        self.flagged_only = false;
+        self.include_deleted = false;
+        self.from_date.set_error(None);
+        self.to_date.set_error(None);
         self.error_message = None;
     }
 
+    /// 注記付きのみの絞り込みを切り替え
+    pub fn toggle_flagged_only(&mut self) {
+        self.flagged_only = !self.flagged_only;
+    }
+
+    pub fn is_flagged_only(&self) -> bool {
+        self.flagged_only
+    }
+
+    /// 削除済みの仕訳の表示を切り替え
+    pub fn toggle_include_deleted(&mut self) {
+        self.include_deleted = !self.include_deleted;
+    }
+
     /// 検索条件を取得
     pub fn get_criteria(&self) -> SearchCriteria {
         SearchCriteria {
@@ -607,6 +988,26 @@ impl SearchPage {
             } else {
                 Some(self.max_amount.value().to_string())
             },
+            voucher_number: if self.voucher_number.value().trim().is_empty() {
+                None
+            } else {
+                Some(self.voucher_number.value().trim().to_string())
+            },
+            entry_number: if self.entry_number.value().trim().is_empty() {
+                None
+            } else {
+                Some(self.entry_number.value().trim().to_string())
+            },
+            free_text: if self.free_text.value().trim().is_empty() {
+                None
+            } else {
+                Some(self.free_text.value().trim().to_string())
+            },
+            dimensions: if self.dimensions.value().trim().is_empty() {
+                None
+            } else {
+                Some(self.dimensions.value().trim().to_string())
+            },
         }
     }
 
@@ -635,6 +1036,18 @@ impl SearchPage {
             }
         };
 
+        // 分析軸を「種類=コード」のカンマ区切りから変換（形式の検証はユースケースで行う）
+        let parse_dimensions = |s: &str| -> BTreeMap<String, String> {
+            s.split(',')
+                .map(str::trim)
+                .filter(|item| !item.is_empty())
+                .map(|item| {
+                    let (kind, code) = item.split_once('=').unwrap_or((item, ""));
+                    (kind.trim().to_string(), code.trim().to_string())
+                })
+                .collect()
+        };
+
         javelin_application::dtos::request::SearchCriteriaDto {
             from_date: criteria.from_date.and_then(|s| format_date(&s)),
             to_date: criteria.to_date.and_then(|s| format_date(&s)),
@@ -643,6 +1056,13 @@ impl SearchPage {
             debit_credit: criteria.debit_credit.and_then(|s| format_debit_credit(&s)),
             min_amount: criteria.min_amount.and_then(|s| parse_amount(&s)),
             max_amount: criteria.max_amount.and_then(|s| parse_amount(&s)),
+            voucher_number: criteria.voucher_number,
+            entry_number: criteria.entry_number,
+            free_text: criteria.free_text,
+            flagged_only: self.flagged_only,
+            dimensions: criteria.dimensions.map(|s| parse_dimensions(&s)).unwrap_or_default(),
+            group_by: self.grouping,
+            include_deleted: self.include_deleted,
             limit: Some(100),
             offset: Some(0),
         }
@@ -664,7 +1084,7 @@ impl SearchPage {
     ) -> (
         mpsc::Receiver<SearchResultViewModel>,
         mpsc::Receiver<String>,
-        mpsc::Receiver<String>,
+        ProgressReceiver,
         mpsc::Receiver<usize>,
     ) {
         (
@@ -690,7 +1110,7 @@ impl SearchPage {
         let chunks = Layout::default()
             .direction(Direction::Vertical)
             .constraints([
-                Constraint::Length(19), // 検索条件
+                Constraint::Length(19), // 検索条件（4段 + エラー表示行）
                 Constraint::Min(10),    // 検索結果
                 Constraint::Length(3),  // ステータスバー
             ])
@@ -699,13 +1119,27 @@ impl SearchPage {
         // 検索条件エリア
         self.render_search_criteria(frame, chunks[0]);
 
-        // 検索結果エリア（左右分割なし、全幅で表示）
+        // 検索結果エリア（検索結果 + イベントビューア。幅が狭い場合は検索結果のみ）
+        let result_chunks = if shows_side_panel(area.width) {
+            Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Percentage(75), Constraint::Percentage(25)])
+                .split(chunks[1])
+        } else {
+            Layout::default().constraints([Constraint::Min(0)]).split(chunks[1])
+        };
+
         if self.current_result.is_none() && self.error_message.is_none() {
             // 初期状態：検索条件を指定してくださいメッセージ
-            self.render_initial_message(frame, chunks[1]);
+            self.render_initial_message(frame, result_chunks[0]);
         } else {
             // 検索結果テーブル
-            self.result_table.render(frame, chunks[1]);
+            self.result_table.render(frame, result_chunks[0]);
+        }
+
+        // イベントビューア
+        if let Some(&event_area) = result_chunks.get(1) {
+            self.event_viewer.render(frame, event_area);
         }
 
         // ステータスバー
@@ -715,6 +1149,15 @@ impl SearchPage {
         if self.overlay_selector.is_visible() {
             self.overlay_selector.render(frame, area);
         }
+
+        // カレンダーを最前面に描画
+        self.calendar_picker.render(frame, area);
+
+        // フィールドヘルプを最前面に描画
+        self.field_help.render(frame, area);
+
+        // 出力ファイル名入力を最前面に描画
+        self.export_prompt.render(frame, area);
     }
 
     /// 初期メッセージを描画
@@ -744,9 +1187,29 @@ impl SearchPage {
     }
 
     /// 検索条件エリアを描画
+    ///
+    /// 幅が狭い場合は3列のグリッドを折りたたみ、フォーカス中の列を含む列だけ表示する。
     fn render_search_criteria(&mut self, frame: &mut Frame, area: Rect) {
+        let column_count = fitting_columns(
+            area.width.saturating_sub(2),
+            CRITERIA_COLUMN_MIN_WIDTH,
+            CRITERIA_COLUMNS,
+        );
+        let first_column =
+            column_window_start(self.focused_field.grid_column(), column_count, CRITERIA_COLUMNS);
+        let title = if column_count < CRITERIA_COLUMNS {
+            format!(
+                "◆ 検索条件 ◆ 列 {}-{}/{} [h/l]切替",
+                first_column + 1,
+                first_column + column_count,
+                CRITERIA_COLUMNS
+            )
+        } else {
+            "◆ 検索条件 ◆".to_string()
+        };
+
         let block = Block::default()
-            .title("◆ 検索条件 ◆")
+            .title(title)
             .borders(Borders::ALL)
             .border_type(BorderType::Rounded)
             .border_style(Style::default().fg(Color::Cyan));
@@ -754,58 +1217,65 @@ impl SearchPage {
         let inner = block.inner(area);
         frame.render_widget(block, area);
 
-        // フィールドを3列に配置
-        let columns = Layout::default()
-            .direction(Direction::Horizontal)
-            .constraints([
-                Constraint::Percentage(33),
-                Constraint::Percentage(34),
-                Constraint::Percentage(33),
-            ])
-            .split(inner);
-
-        // 左列（各フィールドに4行確保）
-        let left_fields = Layout::default()
+        // 列のグリッドと、その下の全幅のフリーワード欄に分割
+        let rows = Layout::default()
             .direction(Direction::Vertical)
-            .constraints([Constraint::Length(4), Constraint::Length(4), Constraint::Length(4)])
-            .split(columns[0]);
+            .constraints([Constraint::Length(12), Constraint::Length(4), Constraint::Min(0)])
+            .split(inner);
 
-        // 中央列（各フィールドに4行確保）
-        let middle_fields = Layout::default()
-            .direction(Direction::Vertical)
-            .constraints([Constraint::Length(4), Constraint::Length(4)])
-            .split(columns[1]);
+        // 表示する列を等分に配置
+        let columns = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(vec![Constraint::Ratio(1, column_count as u32); column_count])
+            .split(rows[0]);
 
-        // 右列（各フィールドに4行確保）
-        let right_fields = Layout::default()
-            .direction(Direction::Vertical)
-            .constraints([Constraint::Length(4), Constraint::Length(4)])
-            .split(columns[2]);
+        // 各列とも各フィールドに4行確保
+        let field_constraints =
+            [Constraint::Length(4), Constraint::Length(4), Constraint::Length(4)];
 
-        // フィールドを描画（左列）
+        // フィールドを描画（左列・中央列・右列）
         // Normalモードでもフォーカス中のフィールドをハイライト表示
-        self.from_date.set_focused(self.focused_field == SearchField::FromDate);
-        self.from_date.render(frame, left_fields[0], self.input_mode.is_modify());
-
-        self.to_date.set_focused(self.focused_field == SearchField::ToDate);
-        self.to_date.render(frame, left_fields[1], self.input_mode.is_modify());
-
-        self.description.set_focused(self.focused_field == SearchField::Description);
-        self.description.render(frame, left_fields[2], self.input_mode.is_modify());
-
-        // 中央列
-        self.account_code.set_focused(self.focused_field == SearchField::AccountCode);
-        self.account_code.render(frame, middle_fields[0], self.input_mode.is_modify());
-
-        self.debit_credit.set_focused(self.focused_field == SearchField::DebitCredit);
-        self.debit_credit.render(frame, middle_fields[1], self.input_mode.is_modify());
-
-        // 右列
-        self.min_amount.set_focused(self.focused_field == SearchField::MinAmount);
-        self.min_amount.render(frame, right_fields[0], self.input_mode.is_modify());
+        let focused = self.focused_field;
+        let is_modify = self.input_mode.is_modify();
+        let grid = [
+            [
+                (&mut self.from_date, SearchField::FromDate),
+                (&mut self.to_date, SearchField::ToDate),
+                (&mut self.description, SearchField::Description),
+            ],
+            [
+                (&mut self.account_code, SearchField::AccountCode),
+                (&mut self.debit_credit, SearchField::DebitCredit),
+                (&mut self.voucher_number, SearchField::VoucherNumber),
+            ],
+            [
+                (&mut self.min_amount, SearchField::MinAmount),
+                (&mut self.max_amount, SearchField::MaxAmount),
+                (&mut self.entry_number, SearchField::EntryNumber),
+            ],
+        ];
+        for (column_area, fields) in
+            columns.iter().zip(grid.into_iter().skip(first_column).take(column_count))
+        {
+            let cells = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints(field_constraints)
+                .split(*column_area);
+            for (cell, (field, id)) in cells.iter().zip(fields) {
+                field.set_focused(focused == id);
+                field.render(frame, *cell, is_modify);
+            }
+        }
 
-        self.max_amount.set_focused(self.focused_field == SearchField::MaxAmount);
-        self.max_amount.render(frame, right_fields[1], self.input_mode.is_modify());
+        // 最下段（フリーワードと分析軸）
+        let bottom = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Ratio(2, 3), Constraint::Ratio(1, 3)])
+            .split(rows[1]);
+        self.free_text.set_focused(self.focused_field == SearchField::FreeText);
+        self.free_text.render(frame, bottom[0], self.input_mode.is_modify());
+        self.dimensions.set_focused(self.focused_field == SearchField::Dimensions);
+        self.dimensions.render(frame, bottom[1], self.input_mode.is_modify());
 
         // エラーメッセージを表示
         if let Some(error) = &self.error_message {
@@ -865,8 +1335,70 @@ impl SearchPage {
             Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
             Span::styled("[Enter] ", Style::default().fg(Color::DarkGray)),
             Span::styled("検索", Style::default().fg(Color::Gray)),
+            Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+            Span::styled("[F5] ", Style::default().fg(Color::DarkGray)),
+            Span::styled("再検索", Style::default().fg(Color::Gray)),
+            Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+            Span::styled("[f] ", Style::default().fg(Color::DarkGray)),
+            if self.flagged_only {
+                Span::styled(
+                    format!("{} 注記付きのみ", ANNOTATION_FLAG),
+                    Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+                )
+            } else {
+                Span::styled("注記付き絞込", Style::default().fg(Color::Gray))
+            },
+            Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+            Span::styled("[d] ", Style::default().fg(Color::DarkGray)),
+            if self.include_deleted {
+                Span::styled(
+                    "削除済みを含む",
+                    Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+                )
+            } else {
+                Span::styled("削除済みを表示", Style::default().fg(Color::Gray))
+            },
+            Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+            Span::styled("[g] ", Style::default().fg(Color::DarkGray)),
+            Span::styled(
+                format!("グループ:{}", grouping_label(self.grouping)),
+                if self.grouping == SearchGrouping::None {
+                    Style::default().fg(Color::Gray)
+                } else {
+                    Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+                },
+            ),
         ];
 
+        if self.focus_area == FocusArea::Criteria {
+            status_spans.extend([
+                Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+                Span::styled("[?] ", Style::default().fg(Color::DarkGray)),
+                Span::styled("ヘルプ", Style::default().fg(Color::Gray)),
+            ]);
+        }
+
+        if self.focus_area == FocusArea::Results {
+            if self.grouping != SearchGrouping::None {
+                status_spans.extend([
+                    Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+                    Span::styled("[Space] ", Style::default().fg(Color::DarkGray)),
+                    Span::styled("開閉", Style::default().fg(Color::Gray)),
+                ]);
+            }
+            status_spans.extend([
+                Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+                Span::styled("[s] ", Style::default().fg(Color::DarkGray)),
+                Span::styled("並替", Style::default().fg(Color::Gray)),
+                Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+                Span::styled("[<>] ", Style::default().fg(Color::DarkGray)),
+                Span::styled("列幅", Style::default().fg(Color::Gray)),
+                Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
+                Span::styled("[x] ", Style::default().fg(Color::DarkGray)),
+                Span::styled("出力", Style::default().fg(Color::Gray)),
+            ]);
+        }
+
         // 実行時間を表示
         if let Some(elapsed_ms) = self.execution_time_ms {
             status_spans.push(Span::styled(" │ ", Style::default().fg(Color::DarkGray)));
@@ -894,6 +1426,67 @@ impl SearchPage {
     }
 }
 
+/// 仕訳の明細のうち `include` に該当するものを表示行・出力行に追加
+///
+/// 取引日付・伝票No・状態は仕訳の最初の表示行にのみ表示する。
+fn push_entry_rows(
+    rows: &mut Vec<Vec<String>>,
+    export_rows: &mut Vec<Vec<String>>,
+    entry: &JournalEntryItemViewModel,
+    include: impl Fn(&JournalEntryLineItemViewModel) -> bool,
+) {
+    for (idx, line) in entry.lines.iter().filter(|line| include(line)).enumerate() {
+        let (date, entry_num, status) = if idx == 0 {
+            (
+                entry.transaction_date.clone(),
+                entry.entry_number.clone().unwrap_or_default(),
+                entry.status_label.clone(),
+            )
+        } else {
+            (String::new(), String::new(), String::new())
+        };
+
+        rows.push(vec![
+            date,
+            entry_num,
+            status,
+            if line.open_annotations > 0 {
+                format!("{} {}", ANNOTATION_FLAG, truncate_text!(&line.description, 26))
+            } else {
+                truncate_text!(&line.description, 28)
+            },
+            format!("{} {}", line.account_code, truncate_text!(&line.account_name, 8)),
+            if line.amount_masked {
+                format!("{:>11}", AmountMask::MASKED)
+            } else {
+                format_amount!(line.amount, 11)
+            },
+        ]);
+        export_rows.push(vec![
+            entry.transaction_date.clone(),
+            entry.entry_number.clone().unwrap_or_default(),
+            entry.status_label.clone(),
+            line.description.clone(),
+            format!("{} {}", line.account_code, line.account_name),
+            if line.amount_masked {
+                AmountMask::MASKED.to_string()
+            } else {
+                line.amount.to_string()
+            },
+        ]);
+    }
+}
+
+/// グループ化の表示名
+fn grouping_label(grouping: SearchGrouping) -> &'static str {
+    match grouping {
+        SearchGrouping::None => "なし",
+        SearchGrouping::Account => "科目別",
+        SearchGrouping::Month => "月別",
+        SearchGrouping::Status => "状態別",
+    }
+}
+
 /// 検索条件（View層用）
 #[derive(Debug, Clone)]
 pub struct SearchCriteria {
@@ -904,4 +1497,9 @@ pub struct SearchCriteria {
     pub debit_credit: Option<String>,
     pub min_amount: Option<String>,
     pub max_amount: Option<String>,
+    pub voucher_number: Option<String>,
+    pub entry_number: Option<String>,
+    pub free_text: Option<String>,
+    /// 分析軸（「種類=コード」のカンマ区切り）
+    pub dimensions: Option<String>,
 }
@@ -0,0 +1,210 @@
+// SessionLogPage - アクセスログ・セッション一覧画面のビューコンポーネント
+// 責務: ログイン中のセッション、最近のログイン、ログイン失敗の表示
+
+use javelin_application::dtos::response::{SessionDto, SessionOverviewResponse};
+use ratatui::{
+    Frame,
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::Line,
+    widgets::{Block, Borders, Paragraph},
+};
+
+use crate::clock::format_timestamp;
+
+pub struct SessionLogPage {
+    overview: Option<SessionOverviewResponse>,
+    /// ログイン中のセッションの選択位置
+    selected: usize,
+    loading: bool,
+    error_message: Option<String>,
+    info_message: Option<String>,
+}
+
+impl SessionLogPage {
+    pub fn new() -> Self {
+        Self {
+            overview: None,
+            selected: 0,
+            loading: false,
+            error_message: None,
+            info_message: None,
+        }
+    }
+
+    pub fn set_loading(&mut self) {
+        self.loading = true;
+        self.error_message = None;
+    }
+
+    pub fn set_overview(&mut self, overview: SessionOverviewResponse) {
+        self.selected = self.selected.min(overview.active_sessions.len().saturating_sub(1));
+        self.overview = Some(overview);
+        self.loading = false;
+        self.error_message = None;
+    }
+
+    pub fn set_error(&mut self, message: String) {
+        self.loading = false;
+        self.error_message = Some(message);
+        self.info_message = None;
+    }
+
+    pub fn set_info(&mut self, message: String) {
+        self.info_message = Some(message);
+    }
+
+    pub fn select_next(&mut self) {
+        let count = self.overview.as_ref().map_or(0, |o| o.active_sessions.len());
+        if self.selected + 1 < count {
+            self.selected += 1;
+        }
+    }
+
+    pub fn select_previous(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    /// 選択中のログイン中セッション
+    pub fn selected_session(&self) -> Option<&SessionDto> {
+        self.overview.as_ref().and_then(|o| o.active_sessions.get(self.selected))
+    }
+
+    pub fn render(&self, frame: &mut Frame) {
+        let chunks =
+            Layout::vertical([Constraint::Min(0), Constraint::Length(4)]).split(frame.area());
+        let block = Block::default().borders(Borders::ALL).title("アクセスログ・セッション一覧");
+
+        match (&self.error_message, &self.overview) {
+            (Some(error), None) => {
+                let message = Paragraph::new(error.as_str())
+                    .style(Style::default().fg(Color::Red))
+                    .block(block);
+                frame.render_widget(message, chunks[0]);
+            }
+            (_, None) => {
+                frame.render_widget(Paragraph::new("読み込み中...").block(block), chunks[0]);
+            }
+            (_, Some(overview)) => {
+                let inner = block.inner(chunks[0]);
+                frame.render_widget(block, chunks[0]);
+                let rows = Layout::vertical([Constraint::Ratio(2, 5), Constraint::Ratio(3, 5)])
+                    .split(inner);
+                let columns = Layout::horizontal([Constraint::Ratio(1, 2); 2]).split(rows[1]);
+                self.render_active(frame, rows[0], overview);
+                Self::render_recent(frame, columns[0], overview);
+                Self::render_failed(frame, columns[1], overview);
+            }
+        }
+
+        let (status, style) = match (&self.error_message, &self.info_message) {
+            (Some(error), _) if self.overview.is_some() => {
+                (error.as_str(), Style::default().fg(Color::Red))
+            }
+            (_, Some(info)) => (info.as_str(), Style::default().fg(Color::Green)),
+            _ if self.loading => ("読み込み中...", Style::default()),
+            _ => ("", Style::default()),
+        };
+        let status_bar = Paragraph::new(vec![
+            Line::from("[↑↓] 選択 [f] 強制ログアウト [r] 再読込 [Esc] 戻る"),
+            Line::styled(status.to_string(), style),
+        ])
+        .block(Block::default().borders(Borders::ALL));
+        frame.render_widget(status_bar, chunks[1]);
+    }
+
+    /// ログイン中のセッション
+    fn render_active(&self, frame: &mut Frame, area: Rect, overview: &SessionOverviewResponse) {
+        let mut lines =
+            vec![Self::header(format!("  {:<16}{:<16}{:<20}", "セッション", "ユーザ", "開始"))];
+        lines.extend(overview.active_sessions.iter().enumerate().map(|(index, session)| {
+            let text = format!(
+                "{}{:<16}{:<16}{:<20}",
+                if index == self.selected { "> " } else { "  " },
+                session.session_id,
+                session.user_id,
+                format_timestamp(&session.started_at)
+            );
+            if index == self.selected {
+                Line::styled(text, Style::default().add_modifier(Modifier::REVERSED))
+            } else {
+                Line::from(text)
+            }
+        }));
+        Self::render_section(
+            frame,
+            area,
+            "ログイン中のセッション",
+            lines,
+            overview.active_sessions.is_empty(),
+        );
+    }
+
+    /// 最近のログイン
+    fn render_recent(frame: &mut Frame, area: Rect, overview: &SessionOverviewResponse) {
+        let mut lines = vec![Self::header(format!("{:<20}{:<14}{:<16}", "開始", "ユーザ", "状態"))];
+        lines.extend(overview.recent_logins.iter().map(|session| {
+            let status = match (&session.forced_by, &session.closed_at) {
+                (Some(forced_by), _) => format!("{}（{}）", session.status, forced_by),
+                (None, Some(closed_at)) => {
+                    format!("{} {}", session.status, format_timestamp(closed_at))
+                }
+                (None, None) => session.status.clone(),
+            };
+            Line::from(format!(
+                "{:<20}{:<14}{:<16}",
+                format_timestamp(&session.started_at),
+                session.user_id,
+                status
+            ))
+        }));
+        Self::render_section(
+            frame,
+            area,
+            "最近のログイン",
+            lines,
+            overview.recent_logins.is_empty(),
+        );
+    }
+
+    /// ログイン失敗
+    fn render_failed(frame: &mut Frame, area: Rect, overview: &SessionOverviewResponse) {
+        let mut lines = vec![Self::header(format!("{:<20}{:<14}{}", "日時", "ユーザ", "理由"))];
+        lines.extend(overview.failed_logins.iter().map(|failed| {
+            Line::styled(
+                format!(
+                    "{:<20}{:<14}{}",
+                    format_timestamp(&failed.failed_at),
+                    failed.user_id,
+                    failed.reason
+                ),
+                Style::default().fg(Color::Red),
+            )
+        }));
+        Self::render_section(frame, area, "ログイン失敗", lines, overview.failed_logins.is_empty());
+    }
+
+    fn header(text: String) -> Line<'static> {
+        Line::styled(text, Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+    }
+
+    fn render_section(
+        frame: &mut Frame,
+        area: Rect,
+        title: &str,
+        mut lines: Vec<Line<'static>>,
+        empty: bool,
+    ) {
+        if empty {
+            lines.push(Line::styled("記録はありません", Style::default().fg(Color::Gray)));
+        }
+        let block = Block::default().borders(Borders::ALL).title(title.to_string());
+        frame.render_widget(Paragraph::new(lines).block(block), area);
+    }
+}
+
+impl Default for SessionLogPage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
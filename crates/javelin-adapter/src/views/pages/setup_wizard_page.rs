@@ -0,0 +1,608 @@
+// SetupWizardPage - 初期セットアップ画面
+// 責務: 初回起動時の会社情報・会計年度・基準通貨・勘定科目テンプレート・管理者の入力
+
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Flex, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph},
+};
+
+/// セットアップの手順
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SetupStep {
+    Company,
+    FiscalYear,
+    Currency,
+    ChartOfAccounts,
+    Administrator,
+    Confirm,
+}
+
+impl SetupStep {
+    const ALL: [SetupStep; 6] = [
+        SetupStep::Company,
+        SetupStep::FiscalYear,
+        SetupStep::Currency,
+        SetupStep::ChartOfAccounts,
+        SetupStep::Administrator,
+        SetupStep::Confirm,
+    ];
+
+    fn index(&self) -> usize {
+        Self::ALL.iter().position(|step| step == self).unwrap_or(0)
+    }
+
+    fn title(&self) -> &'static str {
+        match self {
+            SetupStep::Company => "会社情報",
+            SetupStep::FiscalYear => "会計年度",
+            SetupStep::Currency => "基準通貨",
+            SetupStep::ChartOfAccounts => "勘定科目",
+            SetupStep::Administrator => "管理者",
+            SetupStep::Confirm => "確認",
+        }
+    }
+
+    /// 入力欄の数（選択式の手順は0）
+    fn field_count(&self) -> usize {
+        match self {
+            SetupStep::Company => 2,
+            SetupStep::Administrator => 4,
+            _ => 0,
+        }
+    }
+}
+
+/// 選択肢（コードと表示名）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SetupChoice {
+    pub code: String,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Message {
+    None,
+    Status(String),
+    Error(String),
+}
+
+/// 入力内容（確定時にリクエストへ変換する）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SetupInput {
+    pub company_code: String,
+    pub company_name: String,
+    pub fiscal_year_start_month: u8,
+    pub base_currency: String,
+    pub chart_template: String,
+    pub admin_user_id: String,
+    pub admin_display_name: String,
+    pub admin_password: String,
+}
+
+pub struct SetupWizardPage {
+    step: SetupStep,
+    /// 入力中の欄（手順内の位置）
+    field: usize,
+    company_code: String,
+    company_name: String,
+    fiscal_year_start_month: u8,
+    currencies: Vec<SetupChoice>,
+    currency_index: usize,
+    templates: Vec<SetupChoice>,
+    template_index: usize,
+    admin_user_id: String,
+    admin_display_name: String,
+    admin_password: String,
+    admin_password_confirm: String,
+    submitting: bool,
+    message: Message,
+    animation_frame: usize,
+}
+
+impl SetupWizardPage {
+    pub fn new() -> Self {
+        Self {
+            step: SetupStep::Company,
+            field: 0,
+            company_code: "0001".to_string(),
+            company_name: String::new(),
+            fiscal_year_start_month: 4,
+            currencies: Vec::new(),
+            currency_index: 0,
+            templates: Vec::new(),
+            template_index: 0,
+            admin_user_id: String::new(),
+            admin_display_name: String::new(),
+            admin_password: String::new(),
+            admin_password_confirm: String::new(),
+            submitting: false,
+            message: Message::None,
+            animation_frame: 0,
+        }
+    }
+
+    /// 基準通貨と勘定科目テンプレートの選択肢を設定
+    pub fn set_options(&mut self, currencies: Vec<SetupChoice>, templates: Vec<SetupChoice>) {
+        self.currencies = currencies;
+        self.templates = templates;
+        self.currency_index = 0;
+        self.template_index = 0;
+    }
+
+    pub fn step(&self) -> SetupStep {
+        self.step
+    }
+
+    pub fn is_submitting(&self) -> bool {
+        self.submitting
+    }
+
+    /// 手順内の次の入力欄へ
+    pub fn next_field(&mut self) {
+        let count = self.step.field_count();
+        if count > 0 {
+            self.field = (self.field + 1) % count;
+        }
+    }
+
+    /// 手順内の前の入力欄へ
+    pub fn previous_field(&mut self) {
+        let count = self.step.field_count();
+        if count > 0 {
+            self.field = (self.field + count - 1) % count;
+        }
+    }
+
+    /// 選択式の手順で選択を移動（会計年度は月を増減、入力式の手順では入力欄を移動）
+    pub fn select(&mut self, forward: bool) {
+        if self.submitting {
+            return;
+        }
+        let shift = |index: usize, len: usize| match (len, forward) {
+            (0, _) => 0,
+            (len, true) => (index + 1) % len,
+            (len, false) => (index + len - 1) % len,
+        };
+        match self.step {
+            SetupStep::FiscalYear => {
+                self.fiscal_year_start_month =
+                    shift(self.fiscal_year_start_month as usize - 1, 12) as u8 + 1;
+            }
+            SetupStep::Currency => {
+                self.currency_index = shift(self.currency_index, self.currencies.len())
+            }
+            SetupStep::ChartOfAccounts => {
+                self.template_index = shift(self.template_index, self.templates.len())
+            }
+            _ if forward => self.next_field(),
+            _ => self.previous_field(),
+        }
+    }
+
+    /// 入力を確認して次の手順へ（不足がある場合はメッセージを表示して留まる）
+    pub fn advance(&mut self) -> bool {
+        if let Err(message) = self.validate_step() {
+            self.message = Message::Error(message.to_string());
+            return false;
+        }
+        self.message = Message::None;
+        if let Some(next) = SetupStep::ALL.get(self.step.index() + 1) {
+            self.step = *next;
+            self.field = 0;
+        }
+        true
+    }
+
+    /// 前の手順へ（最初の手順ではfalse）
+    pub fn back(&mut self) -> bool {
+        if self.submitting || self.step.index() == 0 {
+            return false;
+        }
+        self.step = SetupStep::ALL[self.step.index() - 1];
+        self.field = 0;
+        self.message = Message::None;
+        true
+    }
+
+    fn validate_step(&self) -> Result<(), &'static str> {
+        match self.step {
+            SetupStep::Company if self.company_code.trim().is_empty() => {
+                Err("会社コードを入力してください")
+            }
+            SetupStep::Company if self.company_name.trim().is_empty() => {
+                Err("会社名を入力してください")
+            }
+            SetupStep::Administrator if self.admin_user_id.trim().is_empty() => {
+                Err("管理者のユーザIDを入力してください")
+            }
+            SetupStep::Administrator if self.admin_password != self.admin_password_confirm => {
+                Err("パスワードが確認用と一致しません")
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// 確定する入力内容
+    pub fn input(&self) -> SetupInput {
+        let code = |choices: &[SetupChoice], index: usize| {
+            choices.get(index).map(|choice| choice.code.clone()).unwrap_or_default()
+        };
+        SetupInput {
+            company_code: self.company_code.trim().to_string(),
+            company_name: self.company_name.trim().to_string(),
+            fiscal_year_start_month: self.fiscal_year_start_month,
+            base_currency: code(&self.currencies, self.currency_index),
+            chart_template: code(&self.templates, self.template_index),
+            admin_user_id: self.admin_user_id.trim().to_string(),
+            admin_display_name: self.admin_display_name.trim().to_string(),
+            admin_password: self.admin_password.clone(),
+        }
+    }
+
+    fn focused_text(&mut self) -> Option<&mut String> {
+        match (self.step, self.field) {
+            (SetupStep::Company, 0) => Some(&mut self.company_code),
+            (SetupStep::Company, _) => Some(&mut self.company_name),
+            (SetupStep::Administrator, 0) => Some(&mut self.admin_user_id),
+            (SetupStep::Administrator, 1) => Some(&mut self.admin_display_name),
+            (SetupStep::Administrator, 2) => Some(&mut self.admin_password),
+            (SetupStep::Administrator, _) => Some(&mut self.admin_password_confirm),
+            _ => None,
+        }
+    }
+
+    pub fn input_char(&mut self, c: char) {
+        if self.submitting {
+            return;
+        }
+        if let Some(text) = self.focused_text() {
+            text.push(c);
+        }
+    }
+
+    pub fn delete_char(&mut self) {
+        if self.submitting {
+            return;
+        }
+        if let Some(text) = self.focused_text() {
+            text.pop();
+        }
+    }
+
+    /// 登録中に切り替え
+    pub fn start_submitting(&mut self) {
+        self.submitting = true;
+        self.message = Message::Status("マスタを作成しています...".to_string());
+    }
+
+    /// 登録失敗（入力内容は残し、確認画面から修正できるようにする）
+    pub fn set_error(&mut self, message: impl Into<String>) {
+        self.submitting = false;
+        self.message = Message::Error(message.into());
+    }
+
+    pub fn tick(&mut self) {
+        self.animation_frame = (self.animation_frame + 1) % 60;
+    }
+
+    pub fn render(&mut self, frame: &mut Frame) {
+        let [area] = Layout::horizontal([Constraint::Length(72)])
+            .flex(Flex::Center)
+            .areas(frame.area());
+        let [area] = Layout::vertical([Constraint::Length(24)]).flex(Flex::Center).areas(area);
+
+        let block = Block::default()
+            .title(" ◆ Javelin - 初期セットアップ ◆ ")
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(Color::Cyan));
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(1)
+            .constraints([
+                Constraint::Length(2),
+                Constraint::Min(12),
+                Constraint::Length(2),
+                Constraint::Length(1),
+            ])
+            .split(inner);
+
+        self.render_steps(frame, chunks[0]);
+        match self.step {
+            SetupStep::Company => {
+                let areas = Self::field_areas(chunks[1], 2);
+                self.render_field(frame, areas[0], "会社コード", &self.company_code, 0);
+                self.render_field(frame, areas[1], "会社名", &self.company_name, 1);
+            }
+            SetupStep::FiscalYear => {
+                let months = (1..=12u8)
+                    .map(|month| SetupChoice {
+                        code: month.to_string(),
+                        name: format!("{}月開始", month),
+                    })
+                    .collect::<Vec<_>>();
+                self.render_choices(
+                    frame,
+                    chunks[1],
+                    "会計年度の開始月",
+                    &months,
+                    self.fiscal_year_start_month as usize - 1,
+                );
+            }
+            SetupStep::Currency => {
+                self.render_choices(
+                    frame,
+                    chunks[1],
+                    "帳簿の基準通貨",
+                    &self.currencies,
+                    self.currency_index,
+                );
+            }
+            SetupStep::ChartOfAccounts => {
+                self.render_choices(
+                    frame,
+                    chunks[1],
+                    "勘定科目テンプレート",
+                    &self.templates,
+                    self.template_index,
+                );
+            }
+            SetupStep::Administrator => {
+                let areas = Self::field_areas(chunks[1], 4);
+                let masked = "*".repeat(self.admin_password.chars().count());
+                let masked_confirm = "*".repeat(self.admin_password_confirm.chars().count());
+                self.render_field(frame, areas[0], "ユーザID", &self.admin_user_id, 0);
+                self.render_field(frame, areas[1], "表示名", &self.admin_display_name, 1);
+                self.render_field(frame, areas[2], "パスワード", &masked, 2);
+                self.render_field(frame, areas[3], "パスワード（確認）", &masked_confirm, 3);
+            }
+            SetupStep::Confirm => self.render_summary(frame, chunks[1]),
+        }
+        self.render_message(frame, chunks[2]);
+        self.render_help(frame, chunks[3]);
+    }
+
+    fn field_areas(area: Rect, count: usize) -> Vec<Rect> {
+        Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(vec![Constraint::Length(3); count])
+            .split(area)
+            .to_vec()
+    }
+
+    fn render_steps(&self, frame: &mut Frame, area: Rect) {
+        let mut spans = Vec::new();
+        for (index, step) in SetupStep::ALL.iter().enumerate() {
+            if index > 0 {
+                spans.push(Span::styled(" › ", Style::default().fg(Color::DarkGray)));
+            }
+            let style = if *step == self.step {
+                Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)
+            } else if index < self.step.index() {
+                Style::default().fg(Color::Green)
+            } else {
+                Style::default().fg(Color::DarkGray)
+            };
+            spans.push(Span::styled(format!("{}. {}", index + 1, step.title()), style));
+        }
+        frame.render_widget(Paragraph::new(Line::from(spans)), area);
+    }
+
+    fn render_field(&self, frame: &mut Frame, area: Rect, label: &str, value: &str, field: usize) {
+        let focused = self.field == field && !self.submitting;
+        let cursor = if focused && self.animation_frame < 30 {
+            "▮"
+        } else {
+            " "
+        };
+        let border_color = if focused {
+            Color::Cyan
+        } else {
+            Color::DarkGray
+        };
+
+        let paragraph = Paragraph::new(Line::from(vec![
+            Span::styled(value.to_string(), Style::default().fg(Color::White)),
+            Span::styled(cursor, Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+        ]))
+        .block(
+            Block::default()
+                .title(format!(" {} ", label))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Plain)
+                .border_style(Style::default().fg(border_color)),
+        );
+        frame.render_widget(paragraph, area);
+    }
+
+    fn render_choices(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        label: &str,
+        choices: &[SetupChoice],
+        selected: usize,
+    ) {
+        let lines = choices
+            .iter()
+            .enumerate()
+            .map(|(index, choice)| {
+                if index == selected {
+                    Line::from(Span::styled(
+                        format!("▶ {}", choice.name),
+                        Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+                    ))
+                } else {
+                    Line::from(Span::styled(
+                        format!("  {}", choice.name),
+                        Style::default().fg(Color::Gray),
+                    ))
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let paragraph = Paragraph::new(lines).block(
+            Block::default()
+                .title(format!(" {} ", label))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Plain)
+                .border_style(Style::default().fg(Color::Cyan)),
+        );
+        frame.render_widget(paragraph, area);
+    }
+
+    fn render_summary(&self, frame: &mut Frame, area: Rect) {
+        let name = |choices: &[SetupChoice], index: usize| {
+            choices.get(index).map(|choice| choice.name.clone()).unwrap_or_default()
+        };
+        let admin = if self.admin_display_name.trim().is_empty() {
+            self.admin_user_id.clone()
+        } else {
+            format!("{}（{}）", self.admin_user_id, self.admin_display_name)
+        };
+        let rows = [
+            ("会社", format!("{} {}", self.company_code, self.company_name)),
+            ("会計年度", format!("{}月開始", self.fiscal_year_start_month)),
+            ("基準通貨", name(&self.currencies, self.currency_index)),
+            ("勘定科目", name(&self.templates, self.template_index)),
+            ("管理者", admin),
+        ];
+        let mut lines = rows
+            .into_iter()
+            .map(|(label, value)| {
+                Line::from(vec![
+                    Span::styled(format!("{:<8}", label), Style::default().fg(Color::Gray)),
+                    Span::styled(value, Style::default().fg(Color::White)),
+                ])
+            })
+            .collect::<Vec<_>>();
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            "Enterで上記の内容でマスタを作成し、管理者としてログインします",
+            Style::default().fg(Color::Yellow),
+        )));
+
+        let paragraph = Paragraph::new(lines).block(
+            Block::default()
+                .title(" 入力内容の確認 ")
+                .borders(Borders::ALL)
+                .border_type(BorderType::Plain)
+                .border_style(Style::default().fg(Color::Cyan)),
+        );
+        frame.render_widget(paragraph, area);
+    }
+
+    fn render_message(&self, frame: &mut Frame, area: Rect) {
+        let line = match &self.message {
+            Message::None => Line::from(""),
+            Message::Status(message) => {
+                Line::from(Span::styled(message.clone(), Style::default().fg(Color::Gray)))
+            }
+            Message::Error(message) => {
+                Line::from(Span::styled(message.clone(), Style::default().fg(Color::Red)))
+            }
+        };
+        frame.render_widget(Paragraph::new(line), area);
+    }
+
+    fn render_help(&self, frame: &mut Frame, area: Rect) {
+        let mut spans = Vec::new();
+        let mut hint = |key: &str, label: &str| {
+            if !spans.is_empty() {
+                spans.push(Span::styled(" │ ", Style::default().fg(Color::DarkGray)));
+            }
+            spans.push(Span::styled(format!("[{}] ", key), Style::default().fg(Color::DarkGray)));
+            spans.push(Span::styled(label.to_string(), Style::default().fg(Color::Gray)));
+        };
+        if self.step.field_count() > 0 {
+            hint("Tab", "項目切替");
+        } else if self.step != SetupStep::Confirm {
+            hint("↑↓", "選択");
+        }
+        if self.step == SetupStep::Confirm {
+            hint("Enter", "作成");
+        } else {
+            hint("Enter", "次へ");
+        }
+        if self.step == SetupStep::Company {
+            hint("Esc", "中止");
+        } else {
+            hint("Esc", "戻る");
+        }
+
+        frame.render_widget(Paragraph::new(Line::from(spans)), area);
+    }
+}
+
+impl Default for SetupWizardPage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn choices(codes: &[&str]) -> Vec<SetupChoice> {
+        codes
+            .iter()
+            .map(|code| SetupChoice { code: code.to_string(), name: code.to_string() })
+            .collect()
+    }
+
+    fn type_text(page: &mut SetupWizardPage, text: &str) {
+        text.chars().for_each(|c| page.input_char(c));
+    }
+
+    #[test]
+    fn test_wizard_walks_through_steps() {
+        let mut page = SetupWizardPage::new();
+        page.set_options(choices(&["JPY", "USD"]), choices(&["general", "trading"]));
+
+        // 会社名が未入力の場合は先へ進めない
+        assert!(!page.advance());
+        page.next_field();
+        type_text(&mut page, "株式会社テスト");
+        assert!(page.advance());
+
+        page.select(false);
+        page.select(false);
+        page.select(false);
+        assert!(page.advance());
+        page.select(true);
+        assert!(page.advance());
+        page.select(false);
+        assert!(page.advance());
+
+        type_text(&mut page, "tanaka");
+        page.next_field();
+        page.next_field();
+        type_text(&mut page, "passw0rd");
+        page.next_field();
+        type_text(&mut page, "passw0rX");
+        assert!(!page.advance());
+        page.delete_char();
+        type_text(&mut page, "d");
+        assert!(page.advance());
+        assert_eq!(page.step(), SetupStep::Confirm);
+
+        let input = page.input();
+        assert_eq!(input.company_code, "0001");
+        assert_eq!(input.company_name, "株式会社テスト");
+        assert_eq!(input.fiscal_year_start_month, 1);
+        assert_eq!(input.base_currency, "USD");
+        assert_eq!(input.chart_template, "trading");
+        assert_eq!(input.admin_user_id, "tanaka");
+        assert_eq!(input.admin_password, "passw0rd");
+
+        assert!(page.back());
+        assert_eq!(page.step(), SetupStep::Administrator);
+    }
+}
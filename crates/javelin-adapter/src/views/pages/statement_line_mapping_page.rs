@@ -0,0 +1,188 @@
+// StatementLineMappingPage - 財務諸表表示科目マッピング画面のビューコンポーネント
+// 責務: 勘定科目ごとの表示科目割当状況の表示（未設定科目の強調）
+
+use ratatui::{
+    Frame,
+    layout::{Constraint, Layout},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState},
+};
+
+use crate::presenter::{StatementLineMappingItemViewModel, StatementLineMappingViewModel};
+
+#[derive(Debug, Clone, PartialEq)]
+enum LoadingState {
+    Loading,
+    Loaded,
+    Error(String),
+}
+
+pub struct StatementLineMappingPage {
+    view_model: StatementLineMappingViewModel,
+    table_state: TableState,
+    loading_state: LoadingState,
+    status_message: Option<String>,
+}
+
+impl StatementLineMappingPage {
+    pub fn new() -> Self {
+        Self {
+            view_model: StatementLineMappingViewModel::default(),
+            table_state: TableState::default(),
+            loading_state: LoadingState::Loading,
+            status_message: None,
+        }
+    }
+
+    /// 割当状況を設定（選択位置は可能な限り維持）
+    pub fn set_data(&mut self, view_model: StatementLineMappingViewModel) {
+        let selected = self.table_state.selected().unwrap_or(0);
+        let selected = if view_model.items.is_empty() {
+            None
+        } else {
+            Some(selected.min(view_model.items.len() - 1))
+        };
+        self.table_state.select(selected);
+        self.view_model = view_model;
+        self.loading_state = LoadingState::Loaded;
+    }
+
+    pub fn set_error(&mut self, error: String) {
+        self.loading_state = LoadingState::Error(error);
+    }
+
+    pub fn set_status_message(&mut self, message: impl Into<String>) {
+        self.status_message = Some(message.into());
+    }
+
+    pub fn view_model(&self) -> &StatementLineMappingViewModel {
+        &self.view_model
+    }
+
+    /// 選択中の行インデックス
+    pub fn selected_index(&self) -> Option<usize> {
+        self.table_state.selected()
+    }
+
+    /// 選択中の項目
+    pub fn selected_item(&self) -> Option<&StatementLineMappingItemViewModel> {
+        self.selected_index().and_then(|index| self.view_model.items.get(index))
+    }
+
+    pub fn select_next(&mut self) {
+        let len = self.view_model.items.len();
+        if len > 0 {
+            let next = self.table_state.selected().map_or(0, |i| (i + 1).min(len - 1));
+            self.table_state.select(Some(next));
+        }
+    }
+
+    pub fn select_previous(&mut self) {
+        if !self.view_model.items.is_empty() {
+            let previous = self.table_state.selected().map_or(0, |i| i.saturating_sub(1));
+            self.table_state.select(Some(previous));
+        }
+    }
+
+    /// 次の未設定科目へ移動
+    pub fn select_next_unmapped(&mut self) {
+        let start = self.table_state.selected().map_or(0, |i| i + 1);
+        let items = &self.view_model.items;
+        if let Some(index) = (0..items.len())
+            .map(|offset| (start + offset) % items.len())
+            .find(|&index| !items[index].is_mapped())
+        {
+            self.table_state.select(Some(index));
+        }
+    }
+
+    pub fn render(&mut self, frame: &mut Frame) {
+        let area = frame.area();
+
+        if self.loading_state == LoadingState::Loading {
+            let loading = Paragraph::new("読み込み中...")
+                .block(Block::default().borders(Borders::ALL).title("表示科目マッピング"));
+            frame.render_widget(loading, area);
+            return;
+        }
+
+        if let LoadingState::Error(error) = &self.loading_state {
+            let error_widget = Paragraph::new(error.as_str())
+                .style(Style::default().fg(Color::Red))
+                .block(Block::default().borders(Borders::ALL).title("エラー"));
+            frame.render_widget(error_widget, area);
+            return;
+        }
+
+        let chunks = Layout::vertical([Constraint::Min(0), Constraint::Length(3)]).split(area);
+
+        let header = Row::new(vec!["コード", "勘定科目名", "表示科目", "区分"])
+            .style(Style::default().add_modifier(Modifier::BOLD));
+
+        let rows: Vec<Row> = self
+            .view_model
+            .items
+            .iter()
+            .map(|item| {
+                let line_style = if item.is_mapped() {
+                    Style::default()
+                } else {
+                    Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)
+                };
+
+                Row::new(vec![
+                    Cell::from(item.account_code.as_str()),
+                    Cell::from(item.account_name.as_str()),
+                    Cell::from(item.statement_line_label.as_str()).style(line_style),
+                    Cell::from(item.statement_label.as_str()),
+                ])
+            })
+            .collect();
+
+        let unmapped_count = self.view_model.unmapped_count;
+        let title = if unmapped_count > 0 {
+            Line::from(vec![
+                Span::raw(format!("表示科目マッピング ({}件) ", self.view_model.items.len())),
+                Span::styled(
+                    format!("未設定 {}件", unmapped_count),
+                    Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+                ),
+            ])
+        } else {
+            Line::from(format!("表示科目マッピング ({}件)", self.view_model.items.len()))
+        };
+
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Length(10),
+                Constraint::Min(20),
+                Constraint::Length(24),
+                Constraint::Length(6),
+            ],
+        )
+        .header(header)
+        .row_highlight_style(Style::default().bg(Color::DarkGray).add_modifier(Modifier::BOLD))
+        .block(Block::default().borders(Borders::ALL).title(title));
+
+        frame.render_stateful_widget(table, chunks[0], &mut self.table_state);
+
+        let mut status = vec![Span::raw(
+            "[↑↓] 選択 [←→] 表示科目変更 [x] 割当解除 [n] 次の未設定 [m] 管理会計科目 [Esc] 戻る",
+        )];
+        if let Some(message) = &self.status_message {
+            status.push(Span::styled(format!("  {}", message), Style::default().fg(Color::Green)));
+        }
+        let status_bar =
+            Paragraph::new(Line::from(status)).block(Block::default().borders(Borders::ALL));
+
+        frame.render_widget(status_bar, chunks[1]);
+    }
+}
+
+impl Default for StatementLineMappingPage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,210 @@
+// SupplierInvoicePage - 仕入先請求書台帳画面
+// 責務: 請求書の一覧（支払期日・未払残高・支払状況・買掛金計上仕訳）と集計の表示
+
+use javelin_application::dtos::response::SupplierInvoiceResponse;
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph},
+};
+
+use crate::{
+    format_amount,
+    views::components::{DataTable, EventViewer, InfoPanel},
+};
+
+pub struct SupplierInvoicePage {
+    invoice_table: DataTable,
+    info_panel: InfoPanel,
+    event_viewer: EventViewer,
+    /// 表示中の請求書（表の行と同じ順）
+    invoices: Vec<SupplierInvoiceResponse>,
+    /// 取込ファイル名
+    import_file_name: String,
+    animation_frame: usize,
+}
+
+impl SupplierInvoicePage {
+    pub fn new(import_file_name: impl Into<String>) -> Self {
+        let headers = vec![
+            "仕入先".to_string(),
+            "請求書番号".to_string(),
+            "支払期日".to_string(),
+            "請求額（税込）".to_string(),
+            "未払残高".to_string(),
+            "支払状況".to_string(),
+            "仕訳".to_string(),
+        ];
+
+        let invoice_table = DataTable::new("◆ 仕入先請求書台帳 ◆", headers)
+            .with_column_widths(vec![10, 14, 12, 16, 16, 12, 8]);
+
+        let import_file_name = import_file_name.into();
+        let mut event_viewer = EventViewer::new();
+        event_viewer.add_info("仕入先請求書台帳を開きました");
+        event_viewer.add_info(format!(
+            "[i] で {} を取り込みます（請求書番号,仕入先,請求日,支払期日,勘定科目,通貨,税抜金額,消費税額）",
+            import_file_name
+        ));
+
+        Self {
+            invoice_table,
+            info_panel: InfoPanel::new("◇ 買掛金 ◇").with_border_color(Color::Cyan),
+            event_viewer,
+            invoices: Vec::new(),
+            import_file_name,
+            animation_frame: 0,
+        }
+    }
+
+    /// 請求書一覧を表示
+    pub fn set_invoices(&mut self, invoices: Vec<SupplierInvoiceResponse>) {
+        let rows = invoices
+            .iter()
+            .map(|invoice| {
+                let status = if invoice.overdue {
+                    format!("{}（超過）", invoice.payment_status_label)
+                } else {
+                    invoice.payment_status_label.clone()
+                };
+                vec![
+                    invoice.counterparty.clone(),
+                    invoice.invoice_number.clone(),
+                    invoice.due_date.clone(),
+                    format_amount!(invoice.total_amount, 14),
+                    format_amount!(invoice.outstanding_amount, 14),
+                    status,
+                    if invoice.journal_entry_id.is_some() {
+                        "起票済"
+                    } else {
+                        "未起票"
+                    }
+                    .to_string(),
+                ]
+            })
+            .collect();
+        self.invoice_table.set_data(rows);
+
+        let outstanding: f64 = invoices.iter().map(|invoice| invoice.outstanding_amount).sum();
+        let overdue = invoices.iter().filter(|invoice| invoice.overdue).count();
+        let unjournalized =
+            invoices.iter().filter(|invoice| invoice.journal_entry_id.is_none()).count();
+        self.info_panel.clear();
+        self.info_panel.add_line("請求書", format!("{}件", invoices.len()));
+        self.info_panel.add_line("未払残高合計", format_amount!(outstanding));
+        self.info_panel.add_line("期日超過", format!("{}件", overdue));
+        self.info_panel.add_line("仕訳未起票", format!("{}件", unjournalized));
+
+        self.invoices = invoices;
+    }
+
+    /// 選択中の請求書
+    pub fn selected_invoice(&self) -> Option<&SupplierInvoiceResponse> {
+        self.invoice_table.selected_index().and_then(|index| self.invoices.get(index))
+    }
+
+    pub fn set_error(&mut self, error: String) {
+        self.invoice_table.set_error(error.clone());
+        self.event_viewer.add_error(error);
+    }
+
+    pub fn start_loading(&mut self) {
+        self.invoice_table.start_loading();
+    }
+
+    pub fn import_file_name(&self) -> &str {
+        &self.import_file_name
+    }
+
+    pub fn add_info(&mut self, message: impl Into<String>) {
+        self.event_viewer.add_info(message);
+    }
+
+    pub fn add_error(&mut self, message: impl Into<String>) {
+        self.event_viewer.add_error(message);
+    }
+
+    pub fn select_next(&mut self) {
+        self.invoice_table.select_next();
+    }
+
+    pub fn select_previous(&mut self) {
+        self.invoice_table.select_previous();
+    }
+
+    pub fn tick(&mut self) {
+        self.animation_frame = (self.animation_frame + 1) % 60;
+        self.invoice_table.tick_loading();
+    }
+
+    pub fn render(&mut self, frame: &mut Frame) {
+        let area = frame.area();
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(10), Constraint::Length(3)])
+            .split(area);
+
+        let main_chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(68), Constraint::Percentage(32)])
+            .split(chunks[0]);
+
+        let side_chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(8), Constraint::Min(6)])
+            .split(main_chunks[1]);
+
+        self.invoice_table.render(frame, main_chunks[0]);
+        self.info_panel.render(frame, side_chunks[0]);
+        self.event_viewer.render(frame, side_chunks[1]);
+        self.render_status_bar(frame, chunks[1]);
+    }
+
+    fn render_status_bar(&self, frame: &mut Frame, area: Rect) {
+        let cursor = if self.animation_frame < 30 {
+            "▮"
+        } else {
+            " "
+        };
+
+        let key = |text: &'static str| Span::styled(text, Style::default().fg(Color::DarkGray));
+        let label = |text: &'static str| Span::styled(text, Style::default().fg(Color::Gray));
+        let separator = || Span::styled(" │ ", Style::default().fg(Color::DarkGray));
+
+        let status_text = vec![Line::from(vec![
+            key(" [↑↓] "),
+            label("選択"),
+            separator(),
+            key("[i] "),
+            label("取込"),
+            separator(),
+            key("[d] "),
+            label("仕訳起票（下書き）"),
+            separator(),
+            key("[p] "),
+            label("残額を支払済にする"),
+            separator(),
+            key("[r] "),
+            label("再読込"),
+            separator(),
+            key("[Esc] "),
+            label("戻る"),
+            Span::styled(
+                format!(" {}", cursor),
+                Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+            ),
+        ])];
+
+        let paragraph = Paragraph::new(status_text).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Plain)
+                .border_style(Style::default().fg(Color::DarkGray)),
+        );
+
+        frame.render_widget(paragraph, area);
+    }
+}
@@ -0,0 +1,144 @@
+// SystemInfoPage - システム情報画面のビューコンポーネント
+// 責務: 起動診断レポート（バージョン・データディレクトリ・起動処理・処置・ディスク使用量）の表示
+
+use std::sync::Arc;
+
+use ratatui::{
+    Frame,
+    layout::{Constraint, Layout},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState},
+};
+
+use crate::startup_report::{StartupReport, format_bytes, format_duration};
+
+pub struct SystemInfoPage {
+    report: Option<Arc<StartupReport>>,
+    table_state: TableState,
+}
+
+impl SystemInfoPage {
+    pub fn new(report: Option<Arc<StartupReport>>) -> Self {
+        let mut table_state = TableState::default();
+        if report.as_ref().is_some_and(|report| !report.steps.is_empty()) {
+            table_state.select(Some(0));
+        }
+        Self { report, table_state }
+    }
+
+    pub fn select_next(&mut self) {
+        let len = self.report.as_ref().map_or(0, |report| report.steps.len());
+        if len > 0 {
+            let next = self.table_state.selected().map_or(0, |i| (i + 1).min(len - 1));
+            self.table_state.select(Some(next));
+        }
+    }
+
+    pub fn select_previous(&mut self) {
+        if self.report.as_ref().is_some_and(|report| !report.steps.is_empty()) {
+            let previous = self.table_state.selected().map_or(0, |i| i.saturating_sub(1));
+            self.table_state.select(Some(previous));
+        }
+    }
+
+    /// 描画
+    pub fn render(&mut self, frame: &mut Frame) {
+        let area = frame.area();
+
+        let Some(report) = self.report.clone() else {
+            let empty = Paragraph::new("起動診断レポートがありません")
+                .style(Style::default().fg(Color::DarkGray))
+                .block(Block::default().borders(Borders::ALL).title("システム情報"));
+            frame.render_widget(empty, area);
+            return;
+        };
+
+        let actions_height = report.actions.len().max(1) as u16 + 2;
+        let stores_height = report.store_sizes.len() as u16 + 4;
+        let chunks = Layout::vertical([
+            Constraint::Length(8),
+            Constraint::Min(5),
+            Constraint::Length(actions_height),
+            Constraint::Length(stores_height),
+            Constraint::Length(3),
+        ])
+        .split(area);
+
+        let label = |text: &'static str| Span::styled(text, Style::default().fg(Color::Cyan));
+        let summary = Paragraph::new(vec![
+            Line::from(vec![label("バージョン        : "), Span::raw(report.version.as_str())]),
+            Line::from(vec![label("起動日時          : "), Span::raw(report.started_at.as_str())]),
+            Line::from(vec![label("起動モード        : "), Span::raw(report.launch_mode.as_str())]),
+            Line::from(vec![label("データディレクトリ: "), Span::raw(report.data_dir.as_str())]),
+            Line::from(vec![label("タイムゾーン      : "), Span::raw(report.time_zone.as_str())]),
+            Line::from(vec![
+                label("起動所要時間      : "),
+                Span::raw(format_duration(report.total_duration)),
+            ]),
+        ])
+        .block(Block::default().borders(Borders::ALL).title("システム情報"));
+        frame.render_widget(summary, chunks[0]);
+
+        let header = Row::new(vec!["起動処理", "所要時間", "詳細"])
+            .style(Style::default().add_modifier(Modifier::BOLD));
+        let rows: Vec<Row> = report
+            .steps
+            .iter()
+            .map(|step| {
+                Row::new(vec![
+                    Cell::from(step.name.as_str()),
+                    Cell::from(format_duration(step.duration)),
+                    Cell::from(step.details.join(" / ")),
+                ])
+            })
+            .collect();
+        let steps =
+            Table::new(rows, [Constraint::Length(34), Constraint::Length(10), Constraint::Min(20)])
+                .header(header)
+                .row_highlight_style(Style::default().bg(Color::DarkGray))
+                .block(Block::default().borders(Borders::ALL).title("起動処理"));
+        frame.render_stateful_widget(steps, chunks[1], &mut self.table_state);
+
+        let actions: Vec<Line> = if report.actions.is_empty() {
+            vec![Line::styled("なし", Style::default().fg(Color::DarkGray))]
+        } else {
+            report
+                .actions
+                .iter()
+                .map(|action| Line::styled(action.as_str(), Style::default().fg(Color::Yellow)))
+                .collect()
+        };
+        let actions = Paragraph::new(actions)
+            .block(Block::default().borders(Borders::ALL).title("起動時の処置"));
+        frame.render_widget(actions, chunks[2]);
+
+        let mut store_rows: Vec<Row> = report
+            .store_sizes
+            .iter()
+            .map(|store| {
+                Row::new(vec![
+                    Cell::from(store.name.as_str()),
+                    Cell::from(format_bytes(store.bytes)),
+                ])
+            })
+            .collect();
+        store_rows.push(
+            Row::new(vec![Cell::from("合計"), Cell::from(format_bytes(report.total_store_size()))])
+                .style(Style::default().add_modifier(Modifier::BOLD)),
+        );
+        let stores = Table::new(store_rows, [Constraint::Length(20), Constraint::Length(14)])
+            .header(
+                Row::new(vec!["保存先", "使用量"])
+                    .style(Style::default().add_modifier(Modifier::BOLD)),
+            )
+            .block(Block::default().borders(Borders::ALL).title("ディスク使用量（起動時）"));
+        frame.render_widget(stores, chunks[3]);
+
+        let footer = Paragraph::new(
+            "[↑↓] 選択 [Esc] 戻る  起動診断は logs/startup_report.txt にも保存されています",
+        )
+        .block(Block::default().borders(Borders::ALL));
+        frame.render_widget(footer, chunks[4]);
+    }
+}
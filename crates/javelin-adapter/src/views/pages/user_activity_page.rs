@@ -0,0 +1,166 @@
+// UserActivityPage - 利用状況レポート画面のビューコンポーネント
+// 責務: 画面ごとの利用状況、処理時間の長い操作、日ごとの利用者数の表示
+
+use javelin_application::dtos::response::UserActivityReportResponse;
+use ratatui::{
+    Frame,
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::Line,
+    widgets::{Block, Borders, Paragraph},
+};
+
+pub struct UserActivityPage {
+    report: Option<UserActivityReportResponse>,
+    loading: bool,
+    error_message: Option<String>,
+}
+
+impl UserActivityPage {
+    pub fn new() -> Self {
+        Self { report: None, loading: false, error_message: None }
+    }
+
+    pub fn set_loading(&mut self) {
+        self.loading = true;
+        self.error_message = None;
+    }
+
+    pub fn set_report(&mut self, report: UserActivityReportResponse) {
+        self.report = Some(report);
+        self.loading = false;
+        self.error_message = None;
+    }
+
+    pub fn set_error(&mut self, message: String) {
+        self.loading = false;
+        self.error_message = Some(message);
+    }
+
+    pub fn render(&self, frame: &mut Frame) {
+        let chunks =
+            Layout::vertical([Constraint::Min(0), Constraint::Length(3)]).split(frame.area());
+
+        let title = match &self.report {
+            Some(report) => format!("利用状況レポート {}〜", report.from),
+            None => "利用状況レポート".to_string(),
+        };
+        let block = Block::default().borders(Borders::ALL).title(title);
+
+        match (&self.error_message, &self.report) {
+            (Some(error), _) => {
+                let message = Paragraph::new(error.as_str())
+                    .style(Style::default().fg(Color::Red))
+                    .block(block);
+                frame.render_widget(message, chunks[0]);
+            }
+            (None, None) => {
+                frame.render_widget(Paragraph::new("読み込み中...").block(block), chunks[0]);
+            }
+            (None, Some(report)) => {
+                let inner = block.inner(chunks[0]);
+                frame.render_widget(block, chunks[0]);
+                let columns = Layout::horizontal([Constraint::Ratio(1, 2); 2]).split(inner);
+                let right = Layout::vertical([Constraint::Ratio(1, 2); 2]).split(columns[1]);
+                Self::render_screens(frame, columns[0], report);
+                Self::render_slowest(frame, right[0], report);
+                Self::render_daily(frame, right[1], report);
+            }
+        }
+
+        let status = if self.loading {
+            "読み込み中..."
+        } else {
+            ""
+        };
+        let status_bar = Paragraph::new(format!("[r] 再読込 [Esc] 戻る  {}", status))
+            .block(Block::default().borders(Borders::ALL));
+        frame.render_widget(status_bar, chunks[1]);
+    }
+
+    /// 表示回数の多い画面
+    fn render_screens(frame: &mut Frame, area: Rect, report: &UserActivityReportResponse) {
+        let mut lines = vec![Self::header(format!(
+            "{:<24}{:>8}{:>8}{:>12}",
+            "画面", "表示", "利用者", "平均表示"
+        ))];
+        lines.extend(report.screens.iter().map(|screen| {
+            Line::from(format!(
+                "{:<24}{:>8}{:>8}{:>12}",
+                screen.screen,
+                screen.visits,
+                screen.users,
+                format_duration(screen.total_duration_ms / screen.visits.max(1))
+            ))
+        }));
+        Self::render_section(frame, area, "画面の利用状況", lines, report.screens.is_empty());
+    }
+
+    /// 処理時間の長い操作
+    fn render_slowest(frame: &mut Frame, area: Rect, report: &UserActivityReportResponse) {
+        let mut lines = vec![Self::header(format!(
+            "{:<30}{:>6}{:>10}{:>10}",
+            "画面 / 操作", "件数", "平均", "最大"
+        ))];
+        lines.extend(report.slowest_operations.iter().map(|operation| {
+            Line::from(format!(
+                "{:<30}{:>6}{:>10}{:>10}",
+                format!("{} / {}", operation.screen, operation.action),
+                operation.count,
+                format_duration(operation.average_duration_ms),
+                format_duration(operation.max_duration_ms)
+            ))
+        }));
+        Self::render_section(
+            frame,
+            area,
+            "処理時間の長い操作",
+            lines,
+            report.slowest_operations.is_empty(),
+        );
+    }
+
+    /// 日ごとの操作件数・利用者数
+    fn render_daily(frame: &mut Frame, area: Rect, report: &UserActivityReportResponse) {
+        let mut lines = vec![Self::header(format!("{:<12}{:>10}{:>10}", "日付", "操作", "利用者"))];
+        lines.extend(report.daily.iter().rev().map(|day| {
+            Line::from(format!("{:<12}{:>10}{:>10}", day.date, day.actions, day.active_users))
+        }));
+        Self::render_section(frame, area, "日ごとの利用", lines, report.daily.is_empty());
+    }
+
+    fn header(text: String) -> Line<'static> {
+        Line::styled(text, Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+    }
+
+    fn render_section(
+        frame: &mut Frame,
+        area: Rect,
+        title: &str,
+        mut lines: Vec<Line<'static>>,
+        empty: bool,
+    ) {
+        if empty {
+            lines.push(Line::styled("記録はありません", Style::default().fg(Color::Gray)));
+        }
+        let block = Block::default().borders(Borders::ALL).title(title.to_string());
+        frame.render_widget(Paragraph::new(lines).block(block), area);
+    }
+}
+
+impl Default for UserActivityPage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// 所要時間（ミリ秒）を表示用の文字列にする
+fn format_duration(ms: u64) -> String {
+    if ms < 1_000 {
+        format!("{}ms", ms)
+    } else if ms < 60_000 {
+        format!("{:.1}s", ms as f64 / 1_000.0)
+    } else {
+        format!("{}m{:02}s", ms / 60_000, ms % 60_000 / 1_000)
+    }
+}
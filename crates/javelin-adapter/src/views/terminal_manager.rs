@@ -1,9 +1,14 @@
 // TerminalManager - ターミナルの初期化とクリーンアップ
 // 責務: ターミナルのライフサイクル管理
 
+use crossterm::event::{self, Event, KeyEventKind};
+use javelin_application::error_report::ErrorReport;
 use ratatui::DefaultTerminal;
 
-use crate::error::AdapterResult;
+use crate::{
+    error::{AdapterError, AdapterResult},
+    views::components::ErrorPanel,
+};
 
 pub struct TerminalManager {
     terminal: DefaultTerminal,
@@ -20,6 +25,24 @@ impl TerminalManager {
     pub fn terminal_mut(&mut self) -> &mut DefaultTerminal {
         &mut self.terminal
     }
+
+    /// 構造化エラーを表示し、キー入力まで待機
+    pub fn show_error_report(&mut self, report: ErrorReport) -> AdapterResult<()> {
+        let mut panel = ErrorPanel::new();
+        panel.show(report);
+
+        self.terminal
+            .draw(|frame| panel.render(frame, frame.area()))
+            .map_err(|e| AdapterError::RenderingFailed(e.to_string()))?;
+
+        loop {
+            if let Event::Key(key) = event::read().map_err(AdapterError::EventReadFailed)?
+                && key.kind == KeyEventKind::Press
+            {
+                return Ok(());
+            }
+        }
+    }
 }
 
 impl Drop for TerminalManager {
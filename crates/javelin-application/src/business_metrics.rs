@@ -0,0 +1,148 @@
+// BusinessMetrics - 業務指標の記録インターフェース
+// 責務: 主要ユースケースの件数と所要時間の記録と、日次集計の取得
+// 具象実装: Infrastructure層で提供
+
+use std::{fmt, sync::Arc, time::Duration};
+
+use chrono::{DateTime, NaiveDate, Utc};
+use javelin_domain::financial_close::journal_entry::events::JournalEntryEvent;
+use serde::{Deserialize, Serialize};
+
+use crate::error::ApplicationResult;
+
+/// 業務指標
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum BusinessMetric {
+    /// 仕訳の起票（所要時間は登録処理の時間）
+    EntryRegistered,
+    /// 仕訳の承認（所要時間は承認申請からの待ち時間）
+    EntryApproved,
+    /// 仕訳の差戻し（所要時間は承認申請からの待ち時間）
+    EntryRejected,
+    /// 締め処理の各ステップ（所要時間はバッチの処理時間）
+    ClosingStep,
+}
+
+impl BusinessMetric {
+    pub const ALL: [BusinessMetric; 4] = [
+        Self::EntryRegistered,
+        Self::EntryApproved,
+        Self::EntryRejected,
+        Self::ClosingStep,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::EntryRegistered => "仕訳起票",
+            Self::EntryApproved => "承認",
+            Self::EntryRejected => "差戻し",
+            Self::ClosingStep => "締め処理",
+        }
+    }
+
+    /// 所要時間の表示名
+    pub fn latency_label(&self) -> &'static str {
+        match self {
+            Self::EntryApproved | Self::EntryRejected => "承認待ち時間",
+            Self::EntryRegistered | Self::ClosingStep => "処理時間",
+        }
+    }
+}
+
+impl fmt::Display for BusinessMetric {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.label())
+    }
+}
+
+/// 業務指標の日次集計
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DailyMetric {
+    pub date: NaiveDate,
+    pub metric: BusinessMetric,
+    pub count: u64,
+    /// 所要時間の合計（ミリ秒）
+    pub total_latency_ms: u64,
+    /// 所要時間の最大値（ミリ秒）
+    pub max_latency_ms: u64,
+}
+
+impl DailyMetric {
+    /// 平均所要時間（ミリ秒）。件数がない場合はNone
+    pub fn average_latency_ms(&self) -> Option<u64> {
+        (self.count > 0).then(|| self.total_latency_ms / self.count)
+    }
+}
+
+/// BusinessMetricsトレイト
+///
+/// 記録日（業務日付）ごとに件数と所要時間を集計し、保持期間を過ぎた日は破棄する。
+#[async_trait::async_trait]
+pub trait BusinessMetrics: Send + Sync {
+    /// 指標を1件記録
+    async fn record(&self, metric: BusinessMetric, latency: Duration) -> ApplicationResult<()>;
+
+    /// 指定日以降の日次集計（日付順）
+    async fn daily_metrics(&self, from: NaiveDate) -> ApplicationResult<Vec<DailyMetric>>;
+}
+
+/// 指標を記録（記録の失敗でユースケースを失敗させないため、エラーは無視する）
+pub async fn record_metric(
+    metrics: Option<&Arc<dyn BusinessMetrics>>,
+    metric: BusinessMetric,
+    latency: Duration,
+) {
+    if let Some(metrics) = metrics {
+        let _ = metrics.record(metric, latency).await;
+    }
+}
+
+/// 最後の承認申請から指定時刻までの承認待ち時間（申請がない場合は0）
+pub fn approval_wait(events: &[JournalEntryEvent], at: DateTime<Utc>) -> Duration {
+    events
+        .iter()
+        .rev()
+        .find_map(|event| match event {
+            JournalEntryEvent::ApprovalRequested { requested_at, .. } => Some(*requested_at),
+            _ => None,
+        })
+        .and_then(|requested_at| (at - requested_at).to_std().ok())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeDelta;
+
+    use super::*;
+
+    #[test]
+    fn test_approval_wait_uses_latest_request() {
+        let at = Utc::now();
+        let requested = |hours_ago: i64| JournalEntryEvent::ApprovalRequested {
+            entry_id: "JE001".to_string(),
+            requested_by: "clerk".to_string(),
+            requested_at: at - TimeDelta::hours(hours_ago),
+        };
+        let rejected = JournalEntryEvent::Rejected {
+            entry_id: "JE001".to_string(),
+            reason: "証憑不足".to_string(),
+            rejected_by: "manager".to_string(),
+            rejected_at: at - TimeDelta::hours(3),
+        };
+
+        assert_eq!(approval_wait(&[], at), Duration::ZERO);
+        // 差戻し後の再申請からの待ち時間
+        let events = [requested(5), rejected, requested(2)];
+        assert_eq!(approval_wait(&events, at), Duration::from_secs(2 * 3600));
+
+        let daily = DailyMetric {
+            date: at.date_naive(),
+            metric: BusinessMetric::EntryApproved,
+            count: 4,
+            total_latency_ms: 1000,
+            max_latency_ms: 600,
+        };
+        assert_eq!(daily.average_latency_ms(), Some(250));
+    }
+}
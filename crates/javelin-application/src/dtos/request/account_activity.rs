@@ -0,0 +1,15 @@
+// AccountActivity - 勘定科目別の記帳量分析リクエスト
+
+/// 既定の集計月数（対象月を含む直近12か月）
+pub const DEFAULT_ACTIVITY_MONTHS: u8 = 12;
+
+/// 勘定科目別・月別の記帳量分析リクエスト
+#[derive(Debug, Clone)]
+pub struct AnalyzeAccountActivityRequest {
+    /// 集計期間の最終月（年）
+    pub fiscal_year: i32,
+    /// 集計期間の最終月（月）
+    pub period: u8,
+    /// 対象月を含めて遡る月数
+    pub months: u8,
+}
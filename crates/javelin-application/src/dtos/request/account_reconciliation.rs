@@ -0,0 +1,34 @@
+// AccountReconciliation - 勘定照合リクエスト
+
+/// 照合対象（科目・会計期間）
+#[derive(Debug, Clone)]
+pub struct AccountReconciliationTarget {
+    pub account_code: String,
+    pub fiscal_year: i32,
+    pub period: u8,
+}
+
+/// 裏付け明細追加リクエスト
+#[derive(Debug, Clone)]
+pub struct AddReconciliationItemRequest {
+    pub target: AccountReconciliationTarget,
+    pub description: String,
+    pub amount: f64,
+    /// 証憑の参照（ファイル名・文書番号など）
+    pub reference: Option<String>,
+}
+
+/// 裏付け明細削除リクエスト
+#[derive(Debug, Clone)]
+pub struct RemoveReconciliationItemRequest {
+    pub target: AccountReconciliationTarget,
+    /// 明細の位置（0始まり）
+    pub item_index: usize,
+}
+
+/// 照合済登録リクエスト
+#[derive(Debug, Clone)]
+pub struct MarkAccountReconciledRequest {
+    pub target: AccountReconciliationTarget,
+    pub reconciled_by: String,
+}
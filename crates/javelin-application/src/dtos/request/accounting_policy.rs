@@ -0,0 +1,47 @@
+// AccountingPolicy - 会計方針設定の変更リクエスト
+
+/// 通貨別端数処理の変更リクエスト
+#[derive(Debug, Clone)]
+pub struct UpdateCurrencyRoundingRequest {
+    pub user_id: String,
+    /// 通貨コード（例: "JPY"）
+    pub currency: String,
+    pub decimal_places: u8,
+    /// 端数処理方法コード（例: "HalfUp"）
+    pub rounding_mode: String,
+}
+
+/// 税区分別税額端数処理の変更リクエスト
+#[derive(Debug, Clone)]
+pub struct UpdateTaxRoundingRequest {
+    pub user_id: String,
+    /// 税区分コード（例: "Taxable"）
+    pub tax_type: String,
+    pub rounding_mode: String,
+}
+
+/// 負数表示方法の変更リクエスト
+#[derive(Debug, Clone)]
+pub struct UpdateNegativePresentationRequest {
+    pub user_id: String,
+    /// 負数表示方法コード（例: "Triangle"）
+    pub negative_presentation: String,
+}
+
+/// 金額マスキング規則の変更リクエスト
+#[derive(Debug, Clone)]
+pub struct UpdateAmountMaskingRulesRequest {
+    pub user_id: String,
+    /// 空白区切りの規則（例: "5100-5199:payroll 2150:payroll,hr"）
+    pub rules: String,
+}
+
+/// ユーザのロール割当の変更リクエスト
+#[derive(Debug, Clone)]
+pub struct UpdateUserRolesRequest {
+    pub user_id: String,
+    /// ロールを割り当てるユーザ
+    pub target_user_id: String,
+    /// 割り当てるロール（空の場合は割当を削除）
+    pub roles: Vec<String>,
+}
@@ -11,8 +11,12 @@ pub struct UpdateApplicationSettingsRequest {
     pub language: String,
     pub decimal_places: u8,
     pub date_format: String,
+    pub batch_notification_bell: bool,
+    pub batch_notification_desktop: bool,
     pub fiscal_year_start_month: u8,
     pub closing_day: u8,
     pub auto_backup_enabled: bool,
     pub backup_retention_days: u32,
+    pub approval_sla_warning_hours: u32,
+    pub approval_sla_breach_hours: u32,
 }
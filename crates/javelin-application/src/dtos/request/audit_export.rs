@@ -0,0 +1,10 @@
+// AuditExport - 監査用帳簿出力リクエスト
+
+/// 監査用帳簿出力リクエスト（仕訳帳・総勘定元帳・索引）
+#[derive(Debug, Clone)]
+pub struct AuditExportRequest {
+    /// 対象年度（取引日付の暦年）
+    pub fiscal_year: u32,
+    /// 出力者のユーザID（索引ファイルに記録する）
+    pub exported_by: String,
+}
@@ -0,0 +1,45 @@
+// Authentication - ログイン・利用者登録リクエスト
+
+/// ログインリクエスト
+///
+/// 利用者が未登録の場合（初回起動時）は、この資格情報で最初のアカウントを作成する。
+#[derive(Clone)]
+pub struct LoginRequest {
+    pub user_id: String,
+    pub password: String,
+}
+
+impl std::fmt::Debug for LoginRequest {
+    // パスワードはログに出力しない
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LoginRequest")
+            .field("user_id", &self.user_id)
+            .field("password", &"********")
+            .finish()
+    }
+}
+
+/// 利用者登録リクエスト（管理者のみ）
+///
+/// 仕訳の承認や期間の再オープンの承認者となる、初回起動時以外の利用者を登録する。
+#[derive(Clone)]
+pub struct RegisterUserAccountRequest {
+    /// 登録を行う利用者
+    pub registered_by: String,
+    pub user_id: String,
+    pub display_name: String,
+    /// 初期パスワード
+    pub password: String,
+}
+
+impl std::fmt::Debug for RegisterUserAccountRequest {
+    // パスワードはログに出力しない
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RegisterUserAccountRequest")
+            .field("registered_by", &self.registered_by)
+            .field("user_id", &self.user_id)
+            .field("display_name", &self.display_name)
+            .field("password", &"********")
+            .finish()
+    }
+}
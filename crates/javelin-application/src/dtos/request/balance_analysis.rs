@@ -0,0 +1,24 @@
+// BalanceAnalysis - 勘定残高の期間比較分析リクエスト
+
+/// 異常とみなす増減の閾値
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BalanceAnomalyThresholds {
+    /// 比較対象残高に対する増減率（0.3 = 30%）
+    pub change_rate: f64,
+    /// 増減額の下限（これ未満の増減は率に関わらず対象外）
+    pub min_change_amount: f64,
+}
+
+impl Default for BalanceAnomalyThresholds {
+    fn default() -> Self {
+        Self { change_rate: 0.3, min_change_amount: 100_000.0 }
+    }
+}
+
+/// 勘定残高の期間比較分析リクエスト
+#[derive(Debug, Clone)]
+pub struct AnalyzeBalancesRequest {
+    pub fiscal_year: i32,
+    pub period: u8,
+    pub thresholds: BalanceAnomalyThresholds,
+}
@@ -0,0 +1,8 @@
+// CalendarMaster - カレンダーマスタ操作リクエスト
+
+/// カレンダーマスタ取得リクエスト
+#[derive(Debug, Clone)]
+pub struct LoadCalendarMasterRequest {
+    /// 対象年（Noneの場合は全件）
+    pub year: Option<i32>,
+}
@@ -23,6 +23,15 @@ pub struct LockClosingPeriodRequest {
     pub fiscal_year: i32,
     pub period: u8,
     pub locked_by: String,
+    /// 事前検証に失敗しても固定する場合の理由（管理者のみ）
+    pub override_reason: Option<String>,
+}
+
+/// 締日固定の事前検証
+#[derive(Debug, Clone)]
+pub struct ValidateClosingLockRequest {
+    pub fiscal_year: i32,
+    pub period: u8,
 }
 
 /// 試算表生成処理
@@ -30,6 +39,22 @@ pub struct LockClosingPeriodRequest {
 pub struct GenerateTrialBalanceRequest {
     pub fiscal_year: i32,
     pub period: u8,
+    /// 表示通貨（ISO 4217）
+    pub presentation_currency: String,
+    /// 取引通貨ごとの換算レート（表示通貨以外の通貨は必須）
+    pub translation_rates: Vec<TranslationRateDto>,
+    /// 承認待ちの仕訳を含めて集計する（試算）
+    pub include_pending_approval: bool,
+}
+
+/// 換算レート（1取引通貨あたりの表示通貨額）
+#[derive(Debug, Clone)]
+pub struct TranslationRateDto {
+    pub currency: String,
+    /// 決算日レート（B/S科目に適用）
+    pub closing_rate: f64,
+    /// 期中平均レート（P/L科目に適用）
+    pub average_rate: f64,
 }
 
 /// 注記草案生成処理
@@ -44,6 +69,8 @@ pub struct GenerateNoteDraftRequest {
 pub struct AdjustAccountsRequest {
     pub fiscal_year: i32,
     pub period: u8,
+    /// 試算のみ（補正を計算して返し、イベントは記録しない）
+    pub dry_run: bool,
 }
 
 /// IFRS評価処理
@@ -51,6 +78,8 @@ pub struct AdjustAccountsRequest {
 pub struct ApplyIfrsValuationRequest {
     pub fiscal_year: i32,
     pub period: u8,
+    /// 試算のみ（仕訳を計算して返し、仕訳・イベントは記録しない）
+    pub dry_run: bool,
 }
 
 /// 財務諸表生成処理
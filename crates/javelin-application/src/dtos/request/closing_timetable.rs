@@ -0,0 +1,41 @@
+// ClosingTimetable - 締めスケジュール操作リクエスト
+
+use chrono::NaiveDate;
+
+/// 締めタスク保存リクエスト（既存のタスクコードの場合は置き換える）
+#[derive(Debug, Clone)]
+pub struct SaveClosingTimetableItemRequest {
+    pub code: String,
+    pub task: String,
+    pub owner: String,
+    /// 期末日からの日数（翌月3日なら3、期末日の2日前なら-2）
+    pub due_offset_days: i32,
+    /// 対応する締め工程（英語名または表示名。空の場合は手動）
+    pub step: String,
+}
+
+/// 締めタスク削除リクエスト
+#[derive(Debug, Clone)]
+pub struct DeleteClosingTimetableItemRequest {
+    pub code: String,
+}
+
+/// 締め進捗ボード取得リクエスト
+#[derive(Debug, Clone)]
+pub struct LoadClosingTimetableBoardRequest {
+    pub fiscal_year: i32,
+    pub period: u8,
+    /// 残り日数・期日超過の基準日（業務日付）
+    pub today: NaiveDate,
+}
+
+/// 締めタスク進捗更新リクエスト
+#[derive(Debug, Clone)]
+pub struct UpdateClosingTaskStatusRequest {
+    pub fiscal_year: i32,
+    pub period: u8,
+    pub code: String,
+    /// 進捗状態（NotStarted / InProgress / Completed）
+    pub status: String,
+    pub updated_by: String,
+}
@@ -0,0 +1,17 @@
+// DimensionMaster - 分析軸マスタ操作リクエスト
+
+/// 分析軸保存リクエスト（既存の種類・コードの場合は名称と有効状態を更新）
+#[derive(Debug, Clone)]
+pub struct SaveDimensionMasterRequest {
+    pub kind: String,
+    pub code: String,
+    pub name: String,
+    pub is_active: bool,
+}
+
+/// 分析軸削除リクエスト
+#[derive(Debug, Clone)]
+pub struct DeleteDimensionMasterRequest {
+    pub kind: String,
+    pub code: String,
+}
@@ -0,0 +1,32 @@
+// EntryLink - 仕訳間の紐付けリクエスト
+
+/// 仕訳の紐付けリクエスト
+#[derive(Debug, Clone)]
+pub struct LinkEntriesRequest {
+    /// 計上・前払の仕訳
+    pub source_entry_id: String,
+    /// 決済・償却の仕訳
+    pub target_entry_id: String,
+    /// 紐付けの種類コード
+    pub kind: String,
+    /// 消し込む金額（Noneの場合は元仕訳の未決済残高）
+    pub amount: Option<f64>,
+    /// 紐付けた利用者（ログイン中の利用者）
+    pub user_id: String,
+}
+
+/// 紐付けの解除リクエスト
+#[derive(Debug, Clone)]
+pub struct UnlinkEntriesRequest {
+    pub entry_id: String,
+    pub link_id: String,
+    /// 解除した利用者（ログイン中の利用者）
+    pub user_id: String,
+}
+
+/// 期末時点の未決済の計上・前払の照会リクエスト
+#[derive(Debug, Clone)]
+pub struct UnsettledLinksRequest {
+    pub fiscal_year: i32,
+    pub period: u8,
+}
@@ -0,0 +1,22 @@
+// ExportProtection - 出力ファイル保護リクエスト
+
+/// 出力ファイル保護リクエスト
+///
+/// 設定に従って出力内容を暗号化・署名する。暗号化が有効な場合はパスワードが必要。
+#[derive(Debug, Clone)]
+pub struct ProtectExportRequest {
+    /// 出力するファイル名（例: "ledger_1100.csv"）
+    pub file_name: String,
+    pub content: Vec<u8>,
+    /// 暗号化のパスワード（保存しない）
+    pub password: Option<String>,
+}
+
+/// 分離署名の検証リクエスト
+#[derive(Debug, Clone)]
+pub struct VerifyExportSignatureRequest {
+    /// 検証する出力ファイルの内容
+    pub content: Vec<u8>,
+    /// 署名ファイル（.sig）の内容
+    pub signature: String,
+}
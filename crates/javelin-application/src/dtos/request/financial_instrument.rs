@@ -0,0 +1,26 @@
+// FinancialInstrument - 金融商品リクエスト
+
+/// 金融商品登録リクエスト
+#[derive(Debug, Clone)]
+pub struct RegisterFinancialInstrumentRequest {
+    pub instrument_id: String,
+    pub name: String,
+    /// 種類（"Bond" / "Loan"）
+    pub kind: String,
+    /// 保有区分（"Asset" / "Liability"）
+    pub position: String,
+    /// 帳簿価額を計上する勘定科目
+    pub account_code: String,
+    pub currency: String,
+    pub face_value: f64,
+    /// 取得時（発行時）の帳簿価額
+    pub initial_carrying_amount: f64,
+    /// 表面利率（年率、0.01 = 1%）
+    pub coupon_rate: f64,
+    /// 実効金利（年率、0.01 = 1%）
+    pub effective_rate: f64,
+    /// 取得日（YYYY-MM-DD）
+    pub start_date: String,
+    /// 満期日（YYYY-MM-DD）
+    pub maturity_date: String,
+}
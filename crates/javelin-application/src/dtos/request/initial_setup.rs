@@ -0,0 +1,33 @@
+// InitialSetup - 初期セットアップリクエスト
+
+/// 初期セットアップリクエスト（空のデータディレクトリでの初回起動時）
+#[derive(Clone)]
+pub struct InitialSetupRequest {
+    pub company_code: String,
+    pub company_name: String,
+    /// 会計年度開始月（1〜12）
+    pub fiscal_year_start_month: u8,
+    /// 基準通貨コード（JPY/USD/EUR）
+    pub base_currency: String,
+    /// 勘定科目テンプレートのコード
+    pub chart_template: String,
+    pub admin_user_id: String,
+    pub admin_display_name: String,
+    pub admin_password: String,
+}
+
+impl std::fmt::Debug for InitialSetupRequest {
+    // パスワードはログに出力しない
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("InitialSetupRequest")
+            .field("company_code", &self.company_code)
+            .field("company_name", &self.company_name)
+            .field("fiscal_year_start_month", &self.fiscal_year_start_month)
+            .field("base_currency", &self.base_currency)
+            .field("chart_template", &self.chart_template)
+            .field("admin_user_id", &self.admin_user_id)
+            .field("admin_display_name", &self.admin_display_name)
+            .field("admin_password", &"********")
+            .finish()
+    }
+}
@@ -0,0 +1,9 @@
+// IntegrityAudit - 整合性監査リクエスト
+
+/// 整合性監査の実行リクエスト
+#[derive(Debug, Clone)]
+pub struct RunIntegrityAuditRequest {
+    /// 実行契機（Scheduled / BeforeLock / Manual）
+    pub trigger: String,
+    pub requested_by: String,
+}
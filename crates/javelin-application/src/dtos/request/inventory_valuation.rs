@@ -0,0 +1,31 @@
+// InventoryValuation - 棚卸資産評価ワークシートリクエスト
+
+/// 品目別の評価データ（取得原価・正味実現可能価額）
+#[derive(Debug, Clone)]
+pub struct InventoryValuationItemDto {
+    pub item_code: String,
+    pub item_name: String,
+    /// 類似品目のグループ（グループ単位で評価する場合に使用）
+    pub product_group: String,
+    pub quantity: f64,
+    pub unit_cost: f64,
+    pub unit_net_realizable_value: f64,
+}
+
+/// 棚卸資産評価ワークシート取込リクエスト
+#[derive(Debug, Clone)]
+pub struct ImportInventoryWorksheetRequest {
+    pub fiscal_year: i32,
+    pub period: u8,
+    /// 評価減の算定単位（"ItemByItem" / "ProductGroup"）
+    pub method: String,
+    pub currency: String,
+    pub items: Vec<InventoryValuationItemDto>,
+}
+
+/// 棚卸資産評価ワークシート取得リクエスト
+#[derive(Debug, Clone)]
+pub struct LoadInventoryWorksheetRequest {
+    pub fiscal_year: i32,
+    pub period: u8,
+}
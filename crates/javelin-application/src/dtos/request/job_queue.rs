@@ -0,0 +1,25 @@
+// JobQueue - 長時間処理ジョブリクエスト
+
+/// ジョブの登録リクエスト
+#[derive(Debug, Clone)]
+pub struct EnqueueJobRequest {
+    /// ジョブ種別（"LedgerConsolidation" | "ProjectionRebuild" | "InventoryImport"）
+    pub kind: String,
+    /// 実行パラメータ（項目名, 値）
+    pub parameters: Vec<(String, String)>,
+    /// 依頼者（ログイン中の利用者）
+    pub requested_by: String,
+}
+
+/// ジョブの取消リクエスト
+#[derive(Debug, Clone)]
+pub struct CancelJobRequest {
+    pub job_id: String,
+    pub cancelled_by: String,
+}
+
+/// ジョブの再実行リクエスト
+#[derive(Debug, Clone)]
+pub struct RetryJobRequest {
+    pub job_id: String,
+}
@@ -1,5 +1,8 @@
 // 仕訳照会リクエストDTO
 
+/// 削除済みの仕訳のステータス
+pub const DELETED_JOURNAL_ENTRY_STATUS: &str = "Deleted";
+
 /// 仕訳一覧照会クエリ
 #[derive(Debug, Clone)]
 pub struct ListJournalEntriesQuery {
@@ -8,6 +11,18 @@ pub struct ListJournalEntriesQuery {
     pub to_date: Option<String>,
     pub limit: Option<u32>,
     pub offset: Option<u32>,
+    /// 削除済みの仕訳を含める（ステータスに削除済みを指定した場合は常に含める）
+    pub include_deleted: bool,
+}
+
+impl ListJournalEntriesQuery {
+    /// 指定したステータスの仕訳が照会対象か
+    pub fn matches_status(&self, status: &str) -> bool {
+        match &self.status {
+            Some(filter) => filter == status,
+            None => self.include_deleted || status != DELETED_JOURNAL_ENTRY_STATUS,
+        }
+    }
 }
 
 /// 仕訳詳細照会クエリ
@@ -15,3 +30,26 @@ pub struct ListJournalEntriesQuery {
 pub struct GetJournalEntryQuery {
     pub entry_id: String,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deleted_entries_excluded_unless_requested() {
+        let query = |status: Option<&str>, include_deleted| ListJournalEntriesQuery {
+            status: status.map(str::to_string),
+            from_date: None,
+            to_date: None,
+            limit: None,
+            offset: None,
+            include_deleted,
+        };
+
+        assert!(query(None, false).matches_status("Draft"));
+        assert!(!query(None, false).matches_status(DELETED_JOURNAL_ENTRY_STATUS));
+        assert!(query(None, true).matches_status(DELETED_JOURNAL_ENTRY_STATUS));
+        assert!(query(Some("Deleted"), false).matches_status(DELETED_JOURNAL_ENTRY_STATUS));
+        assert!(!query(Some("Deleted"), false).matches_status("Draft"));
+    }
+}
@@ -1,30 +1,46 @@
 // 仕訳登録ユースケース - Request DTOs
 
-use javelin_domain::financial_close::{
-    AccountCode,
-    journal_entry::{
-        entities::JournalEntryLine,
-        values::{
-            Amount, Currency, DebitCredit, DepartmentCode, Description, LineNumber, SubAccountCode,
-            TaxType,
-        },
-    },
+use std::collections::BTreeMap;
+
+use javelin_domain::financial_close::journal_entry::{
+    entities::JournalEntryLine, values::TransactionDate,
 };
+use serde::{Deserialize, Deserializer};
 
+use super::{ValidatedJournalEntryLine, parse_transaction_date};
 use crate::error::ApplicationError;
 
+/// JSONの取引日付（`YYYY-MM-DD`）を画面入力と同じ規則で解析する
+fn deserialize_transaction_date<'de, D>(deserializer: D) -> Result<TransactionDate, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value = String::deserialize(deserializer)?;
+    parse_transaction_date(&value).map_err(|e| serde::de::Error::custom(format!("取引日付: {}", e)))
+}
+
 /// 仕訳明細DTO
-#[derive(Debug, Clone)]
+///
+/// 外部から受け付けるJSONは未知の項目を拒否する。
+/// 値の検証は `ValidatedJournalEntryLine` への変換で行う。
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct JournalEntryLineDto {
     pub line_number: u32,
     pub side: String, // "Debit" or "Credit"
     pub account_code: String,
+    #[serde(default)]
     pub sub_account_code: Option<String>,
+    #[serde(default)]
     pub department_code: Option<String>,
+    /// 分析軸（種類 → コード）
+    #[serde(default)]
+    pub dimensions: BTreeMap<String, String>,
     pub amount: f64,
     pub currency: String,
     pub tax_type: String,
     pub tax_amount: f64,
+    #[serde(default)]
     pub description: Option<String>,
 }
 
@@ -32,82 +48,7 @@ impl TryFrom<&JournalEntryLineDto> for JournalEntryLine {
     type Error = ApplicationError;
 
     fn try_from(dto: &JournalEntryLineDto) -> Result<Self, Self::Error> {
-        let line_number = LineNumber::new(dto.line_number).map_err(|e| {
-            ApplicationError::ValidationFailed(vec![format!("Invalid line number: {:?}", e)])
-        })?;
-
-        let side = dto
-            .side
-            .parse::<DebitCredit>()
-            .map_err(|e| ApplicationError::ValidationFailed(vec![e]))?;
-
-        let account_code = AccountCode::new(dto.account_code.clone()).map_err(|e| {
-            ApplicationError::ValidationFailed(vec![format!("Invalid account code: {:?}", e)])
-        })?;
-
-        let sub_account_code = dto
-            .sub_account_code
-            .as_ref()
-            .map(|code| SubAccountCode::new(code.clone()))
-            .transpose()
-            .map_err(|e| {
-                ApplicationError::ValidationFailed(vec![format!(
-                    "Invalid sub account code: {:?}",
-                    e
-                )])
-            })?;
-
-        let department_code = dto
-            .department_code
-            .as_ref()
-            .map(|code| DepartmentCode::new(code.clone()))
-            .transpose()
-            .map_err(|e| {
-                ApplicationError::ValidationFailed(vec![format!(
-                    "Invalid department code: {:?}",
-                    e
-                )])
-            })?;
-
-        let currency = dto
-            .currency
-            .parse::<Currency>()
-            .map_err(|e| ApplicationError::ValidationFailed(vec![e]))?;
-
-        let amount = Amount::new(dto.amount, currency.clone()).map_err(|e| {
-            ApplicationError::ValidationFailed(vec![format!("Invalid amount: {:?}", e)])
-        })?;
-
-        let tax_type = dto
-            .tax_type
-            .parse::<TaxType>()
-            .map_err(|e| ApplicationError::ValidationFailed(vec![e]))?;
-
-        let tax_amount = Amount::new(dto.tax_amount, currency).map_err(|e| {
-            ApplicationError::ValidationFailed(vec![format!("Invalid tax amount: {:?}", e)])
-        })?;
-
-        let description = dto
-            .description
-            .as_ref()
-            .map(|desc| Description::new(desc.clone()))
-            .transpose()
-            .map_err(|e| {
-                ApplicationError::ValidationFailed(vec![format!("Invalid description: {:?}", e)])
-            })?;
-
-        JournalEntryLine::new(
-            line_number,
-            side,
-            account_code,
-            sub_account_code,
-            department_code,
-            amount,
-            tax_type,
-            tax_amount,
-            description,
-        )
-        .map_err(ApplicationError::DomainError)
+        ValidatedJournalEntryLine::try_from(dto)?.into_line()
     }
 }
 
@@ -125,6 +66,7 @@ impl TryFrom<&javelin_domain::financial_close::journal_entry::events::JournalEnt
             account_code: domain_dto.account_code.clone(),
             sub_account_code: domain_dto.sub_account_code.clone(),
             department_code: domain_dto.department_code.clone(),
+            dimensions: domain_dto.dimensions.clone(),
             amount: domain_dto.amount,
             currency: domain_dto.currency.clone(),
             tax_type: domain_dto.tax_type.clone(),
@@ -135,11 +77,21 @@ impl TryFrom<&javelin_domain::financial_close::journal_entry::events::JournalEnt
 }
 
 /// 仕訳登録リクエスト（下書き作成）
-#[derive(Debug, Clone)]
+///
+/// 取引日付は画面・JSONの境界で解析済みの値を受け取る。
+/// その他の値の検証は `ValidatedRegisterJournalEntryRequest` への変換で行う。
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct RegisterJournalEntryRequest {
-    pub transaction_date: String,
+    #[serde(deserialize_with = "deserialize_transaction_date")]
+    pub transaction_date: TransactionDate,
+    /// 伝票番号（空の場合は自動採番）
+    #[serde(default)]
     pub voucher_number: String,
     pub lines: Vec<JournalEntryLineDto>,
+    /// 伝票の摘要（摘要のない明細に引き継がれる）
+    #[serde(default)]
+    pub description: Option<String>,
     pub user_id: String,
 }
 
@@ -186,9 +138,11 @@ pub struct CorrectJournalEntryRequest {
 #[derive(Debug, Clone)]
 pub struct UpdateDraftJournalEntryRequest {
     pub entry_id: String,
-    pub transaction_date: Option<String>,
+    pub transaction_date: Option<TransactionDate>,
     pub voucher_number: Option<String>,
     pub lines: Option<Vec<JournalEntryLineDto>>,
+    /// 伝票の摘要（Noneの場合は変更なし）
+    pub description: Option<String>,
     pub user_id: String,
 }
 
@@ -203,7 +157,7 @@ pub struct DeleteDraftJournalEntryRequest {
 #[derive(Debug, Clone)]
 pub struct CancelJournalEntryRequest {
     pub reference_entry_id: String,
-    pub transaction_date: String,
+    pub transaction_date: TransactionDate,
     pub voucher_number: String,
     pub user_id: String,
 }
@@ -212,36 +166,42 @@ pub struct CancelJournalEntryRequest {
 #[derive(Debug, Clone)]
 pub struct CreateReversalEntryRequest {
     pub reference_entry_id: String,
-    pub transaction_date: String,
+    pub transaction_date: TransactionDate,
     pub voucher_number: String,
     pub user_id: String,
 }
 
 /// 追加仕訳登録リクエスト
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct CreateAdditionalEntryRequest {
     pub reference_entry_id: String,
-    pub transaction_date: String,
+    #[serde(deserialize_with = "deserialize_transaction_date")]
+    pub transaction_date: TransactionDate,
     pub voucher_number: String,
     pub lines: Vec<JournalEntryLineDto>,
     pub user_id: String,
 }
 
 /// 再分類仕訳登録リクエスト
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct CreateReclassificationEntryRequest {
     pub reference_entry_id: String,
-    pub transaction_date: String,
+    #[serde(deserialize_with = "deserialize_transaction_date")]
+    pub transaction_date: TransactionDate,
     pub voucher_number: String,
     pub lines: Vec<JournalEntryLineDto>,
     pub user_id: String,
 }
 
 /// 洗替仕訳登録リクエスト
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct CreateReplacementEntryRequest {
     pub reference_entry_id: String,
-    pub transaction_date: String,
+    #[serde(deserialize_with = "deserialize_transaction_date")]
+    pub transaction_date: TransactionDate,
     pub voucher_number: String,
     pub lines: Vec<JournalEntryLineDto>,
     pub user_id: String,
@@ -0,0 +1,21 @@
+// JournalImport - 表計算ファイルからの仕訳取込リクエスト
+
+use std::collections::BTreeMap;
+
+use javelin_domain::masters::ImportField;
+
+/// 取込ファイルのプレビューリクエスト
+#[derive(Debug, Clone)]
+pub struct JournalImportPreviewRequest {
+    pub path: String,
+}
+
+/// 列の割当を指定した検証・取込リクエスト
+#[derive(Debug, Clone)]
+pub struct JournalImportRequest {
+    pub path: String,
+    /// 項目と列番号（0始まり）の対応
+    pub columns: BTreeMap<ImportField, usize>,
+    /// 起票者（ログイン中の利用者）
+    pub user_id: String,
+}
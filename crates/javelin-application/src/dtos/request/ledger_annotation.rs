@@ -0,0 +1,21 @@
+// LedgerAnnotation - 仕訳明細の注記リクエスト
+
+/// 明細への注記リクエスト
+#[derive(Debug, Clone)]
+pub struct AnnotateEntryLineRequest {
+    pub entry_id: String,
+    pub line_number: u32,
+    pub note: String,
+    /// 注記者（ログイン中の利用者）
+    pub user_id: String,
+}
+
+/// 注記の解決リクエスト
+#[derive(Debug, Clone)]
+pub struct ResolveAnnotationRequest {
+    pub entry_id: String,
+    pub annotation_id: String,
+    pub resolution: String,
+    /// 解決者（ログイン中の利用者）
+    pub user_id: String,
+}
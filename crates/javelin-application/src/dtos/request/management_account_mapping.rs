@@ -0,0 +1,15 @@
+// ManagementAccountMapping - 管理会計科目マッピング操作リクエスト
+
+/// 管理会計科目割当リクエスト
+#[derive(Debug, Clone)]
+pub struct AssignManagementAccountRequest {
+    pub account_code: String,
+    pub management_account_code: String,
+    pub management_account_name: String,
+}
+
+/// 管理会計科目割当解除リクエスト
+#[derive(Debug, Clone)]
+pub struct RemoveManagementAccountMappingRequest {
+    pub account_code: String,
+}
@@ -0,0 +1,11 @@
+// MasterChange - マスタ変更履歴操作リクエスト
+
+/// マスタ変更取消リクエスト
+#[derive(Debug, Clone)]
+pub struct RevertMasterChangeRequest {
+    /// 勘定科目コード・会社コード
+    pub code: String,
+    /// 取り消す変更の連番
+    pub sequence: u64,
+    pub changed_by: String,
+}
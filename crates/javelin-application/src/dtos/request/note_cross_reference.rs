@@ -0,0 +1,33 @@
+// NoteCrossReference - 注記参照操作リクエスト
+
+/// 注記保存リクエスト（既存の注記番号の場合は置き換える）
+#[derive(Debug, Clone)]
+pub struct SaveNoteSectionRequest {
+    pub number: u32,
+    pub title: String,
+    /// 本文（空の場合は未作成の注記として扱う）
+    pub body: String,
+}
+
+/// 注記削除リクエスト
+#[derive(Debug, Clone)]
+pub struct DeleteNoteSectionRequest {
+    pub number: u32,
+}
+
+/// 注記参照保存リクエスト
+#[derive(Debug, Clone)]
+pub struct SaveNoteCrossReferenceRequest {
+    /// 参照先（表示科目コードまたは勘定科目コード）
+    pub target: String,
+    pub note_number: u32,
+    /// 財務諸表の生成時に注記の作成を必須とするか
+    pub required: bool,
+}
+
+/// 注記参照削除リクエスト
+#[derive(Debug, Clone)]
+pub struct DeleteNoteCrossReferenceRequest {
+    pub target: String,
+    pub note_number: u32,
+}
@@ -0,0 +1,22 @@
+// PeriodReopen - 会計期間の再オープンリクエスト
+
+/// 会計期間の再オープンリクエスト
+#[derive(Debug, Clone)]
+pub struct ReopenClosingPeriodRequest {
+    pub fiscal_year: u32,
+    pub period: u8,
+    pub reason: String,
+    /// 申請者（ログイン中の利用者）
+    pub requested_by: String,
+    /// 承認者（申請者と異なる利用者）
+    pub approved_by: String,
+    /// 承認者のパスワード（承認の本人確認に使う）
+    pub approver_password: String,
+}
+
+/// 再オープン期間中の記帳レポートのリクエスト
+#[derive(Debug, Clone)]
+pub struct ReopenWindowReportRequest {
+    pub fiscal_year: u32,
+    pub period: u8,
+}
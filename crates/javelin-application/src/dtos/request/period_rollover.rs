@@ -0,0 +1,9 @@
+// PeriodRollover - 月初の繰越残高の確定リクエスト
+
+/// 月初の繰越残高の確定リクエスト
+#[derive(Debug, Clone)]
+pub struct RollOverPeriodRequest {
+    pub fiscal_year: i32,
+    /// 繰越残高を確定する月（前月末の残高を当月へ繰り越す）
+    pub period: u8,
+}
@@ -0,0 +1,13 @@
+// ProjectionConsole - Projection照会コンソールリクエスト
+
+/// Projection照会リクエスト
+#[derive(Debug, Clone)]
+pub struct QueryProjectionRequest {
+    /// 操作ユーザ（管理者のみ照会可能）
+    pub user_id: String,
+    /// キーの前方一致条件（例: `ledger:1000:2024:`）
+    pub prefix: String,
+    /// 前ページ最終キー（先頭ページはNone）
+    pub after: Option<String>,
+    pub page_size: usize,
+}
@@ -0,0 +1,31 @@
+// ReportArchive - 帳票アーカイブリクエスト
+
+use crate::dtos::response::ReportLineDto;
+
+/// 帳票の承認・アーカイブリクエスト
+#[derive(Debug, Clone)]
+pub struct ArchiveReportRequest {
+    /// 帳票の種類（例: "FS" = 財務諸表）
+    pub report_type: String,
+    pub fiscal_year: i32,
+    pub period: u8,
+    /// 出力条件（項目名, 値）
+    pub parameters: Vec<(String, String)>,
+    pub lines: Vec<ReportLineDto>,
+    /// 承認者（ログイン中の利用者）
+    pub signed_by: String,
+}
+
+/// アーカイブ済み帳票の表示リクエスト
+#[derive(Debug, Clone)]
+pub struct OpenArchivedReportRequest {
+    pub archive_id: String,
+}
+
+/// アーカイブ済み帳票と再生成した数値の比較リクエスト
+#[derive(Debug, Clone)]
+pub struct CompareArchivedReportRequest {
+    pub archive_id: String,
+    /// 同じ出力条件で再生成した表示行
+    pub current_lines: Vec<ReportLineDto>,
+}
@@ -0,0 +1,12 @@
+// ReportParameterHistory - 帳票の最近使った条件の記録リクエスト
+
+use std::collections::BTreeMap;
+
+/// 実行した帳票条件の記録リクエスト
+#[derive(Debug, Clone)]
+pub struct RememberReportParametersRequest {
+    pub user_id: String,
+    pub report_id: String,
+    /// パラメータ名と入力値
+    pub values: BTreeMap<String, String>,
+}
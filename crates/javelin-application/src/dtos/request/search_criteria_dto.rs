@@ -1,6 +1,43 @@
 // 仕訳検索条件DTO
 // 検索条件を構造化されたデータとして転送
 
+use std::collections::BTreeMap;
+
+/// 検索条件の項目名（項目ごとの検証エラーで該当項目を示す）
+pub const SEARCH_FIELD_FROM_DATE: &str = "from_date";
+pub const SEARCH_FIELD_TO_DATE: &str = "to_date";
+pub const SEARCH_FIELD_MIN_AMOUNT: &str = "min_amount";
+pub const SEARCH_FIELD_MAX_AMOUNT: &str = "max_amount";
+pub const SEARCH_FIELD_DIMENSIONS: &str = "dimensions";
+
+/// 検索結果のグループ化
+///
+/// グループ化した場合、検索結果の全件（ページネーション前）についてグループごとの小計を求める。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SearchGrouping {
+    /// グループ化しない
+    #[default]
+    None,
+    /// 勘定科目別（明細単位で集計）
+    Account,
+    /// 取引月別
+    Month,
+    /// ステータス別
+    Status,
+}
+
+impl SearchGrouping {
+    /// 画面で切り替える次のグループ化（なし → 科目 → 月 → ステータス → なし）
+    pub fn next(&self) -> Self {
+        match self {
+            Self::None => Self::Account,
+            Self::Account => Self::Month,
+            Self::Month => Self::Status,
+            Self::Status => Self::None,
+        }
+    }
+}
+
 /// 仕訳検索条件DTO
 ///
 /// ユーザーが指定する検索条件を表現する。
@@ -28,6 +65,27 @@ pub struct SearchCriteriaDto {
     /// 金額範囲 - 最大金額
     pub max_amount: Option<f64>,
 
+    /// 伝票番号（完全一致）
+    pub voucher_number: Option<String>,
+
+    /// 記帳番号（完全一致、記帳時に採番）
+    pub entry_number: Option<String>,
+
+    /// フリーワード（摘要・勘定科目・伝票番号・記帳番号のいずれかに部分一致）
+    pub free_text: Option<String>,
+
+    /// 未解決の注記が付いた明細を含む仕訳のみ
+    pub flagged_only: bool,
+
+    /// 分析軸（種類 → コード、指定した分析軸をすべて持つ明細を含む仕訳のみ）
+    pub dimensions: BTreeMap<String, String>,
+
+    /// 検索結果のグループ化（グループごとの小計を求める）
+    pub group_by: SearchGrouping,
+
+    /// 削除済みの仕訳を含める（デフォルトは含めない）
+    pub include_deleted: bool,
+
     /// ページネーション - 取得件数上限（デフォルト100）
     pub limit: Option<u32>,
 
@@ -46,6 +104,13 @@ impl SearchCriteriaDto {
             debit_credit: None,
             min_amount: None,
             max_amount: None,
+            voucher_number: None,
+            entry_number: None,
+            free_text: None,
+            flagged_only: false,
+            dimensions: BTreeMap::new(),
+            group_by: SearchGrouping::None,
+            include_deleted: false,
             limit: Some(100),
             offset: Some(0),
         }
@@ -93,6 +158,48 @@ impl SearchCriteriaDto {
         self
     }
 
+    /// ビルダーパターン: 伝票番号を設定
+    pub fn with_voucher_number(mut self, voucher_number: String) -> Self {
+        self.voucher_number = Some(voucher_number);
+        self
+    }
+
+    /// ビルダーパターン: 記帳番号を設定
+    pub fn with_entry_number(mut self, entry_number: String) -> Self {
+        self.entry_number = Some(entry_number);
+        self
+    }
+
+    /// ビルダーパターン: フリーワードを設定
+    pub fn with_free_text(mut self, free_text: String) -> Self {
+        self.free_text = Some(free_text);
+        self
+    }
+
+    /// ビルダーパターン: 未解決の注記が付いた仕訳のみに絞り込む
+    pub fn with_flagged_only(mut self, flagged_only: bool) -> Self {
+        self.flagged_only = flagged_only;
+        self
+    }
+
+    /// ビルダーパターン: 分析軸の条件を追加
+    pub fn with_dimension(mut self, kind: String, code: String) -> Self {
+        self.dimensions.insert(kind, code);
+        self
+    }
+
+    /// ビルダーパターン: 検索結果のグループ化を設定
+    pub fn with_group_by(mut self, group_by: SearchGrouping) -> Self {
+        self.group_by = group_by;
+        self
+    }
+
+    /// ビルダーパターン: 削除済みの仕訳を含める
+    pub fn with_include_deleted(mut self, include_deleted: bool) -> Self {
+        self.include_deleted = include_deleted;
+        self
+    }
+
     /// ビルダーパターン: 取得件数上限を設定
     pub fn with_limit(mut self, limit: u32) -> Self {
         self.limit = Some(limit);
@@ -114,6 +221,11 @@ impl SearchCriteriaDto {
             && self.debit_credit.is_none()
             && self.min_amount.is_none()
             && self.max_amount.is_none()
+            && self.voucher_number.is_none()
+            && self.entry_number.is_none()
+            && self.free_text.is_none()
+            && !self.flagged_only
+            && self.dimensions.is_empty()
     }
 }
 
@@ -138,6 +250,12 @@ mod tests {
         assert!(criteria.debit_credit.is_none());
         assert!(criteria.min_amount.is_none());
         assert!(criteria.max_amount.is_none());
+        assert!(criteria.voucher_number.is_none());
+        assert!(criteria.entry_number.is_none());
+        assert!(criteria.free_text.is_none());
+        assert!(!criteria.flagged_only);
+        assert_eq!(criteria.group_by, SearchGrouping::None);
+        assert!(!criteria.include_deleted);
         assert_eq!(criteria.limit, Some(100));
         assert_eq!(criteria.offset, Some(0));
     }
@@ -173,5 +291,25 @@ mod tests {
 
         let non_empty_criteria = SearchCriteriaDto::new().with_from_date("2024-01-01".to_string());
         assert!(!non_empty_criteria.is_empty());
+
+        // グループ化は検索条件ではない
+        assert!(SearchCriteriaDto::new().with_group_by(SearchGrouping::Month).is_empty());
+
+        let dimension_criteria =
+            SearchCriteriaDto::new().with_dimension("project".to_string(), "P001".to_string());
+        assert!(!dimension_criteria.is_empty());
+    }
+
+    #[test]
+    fn test_number_and_free_text_criteria() {
+        let criteria = SearchCriteriaDto::new()
+            .with_voucher_number("V001".to_string())
+            .with_entry_number("EN-2024-001".to_string())
+            .with_free_text("現金".to_string());
+
+        assert_eq!(criteria.voucher_number, Some("V001".to_string()));
+        assert_eq!(criteria.entry_number, Some("EN-2024-001".to_string()));
+        assert_eq!(criteria.free_text, Some("現金".to_string()));
+        assert!(!SearchCriteriaDto::new().with_free_text("現金".to_string()).is_empty());
     }
 }
@@ -0,0 +1,8 @@
+// SequenceAudit - 番号連続性監査リクエスト
+
+/// 番号連続性監査リクエスト
+#[derive(Debug, Clone, Default)]
+pub struct SequenceAuditRequest {
+    /// 対象年度（Noneの場合は全年度）
+    pub fiscal_year: Option<u32>,
+}
@@ -0,0 +1,16 @@
+// SessionLog - ログインセッションの照会・強制ログアウトリクエスト
+
+/// セッション一覧の照会リクエスト
+#[derive(Debug, Clone)]
+pub struct SessionOverviewRequest {
+    /// 照会する利用者（管理者のみ照会できる）
+    pub user_id: String,
+}
+
+/// 強制ログアウトリクエスト
+#[derive(Debug, Clone)]
+pub struct ForceLogoutRequest {
+    pub session_id: String,
+    /// 操作する管理者（ログイン中の利用者）
+    pub user_id: String,
+}
@@ -0,0 +1,15 @@
+// StatementLineMapping - 財務諸表表示科目マッピング操作リクエスト
+
+/// 表示科目割当リクエスト
+#[derive(Debug, Clone)]
+pub struct AssignStatementLineRequest {
+    pub account_code: String,
+    /// 表示科目コード（例: "BS-CA"）
+    pub statement_line_code: String,
+}
+
+/// 表示科目割当解除リクエスト
+#[derive(Debug, Clone)]
+pub struct RemoveStatementLineMappingRequest {
+    pub account_code: String,
+}
@@ -0,0 +1,37 @@
+// SupplierInvoice - 仕入先請求書台帳リクエスト
+
+/// 仕入先請求書登録リクエスト
+#[derive(Debug, Clone)]
+pub struct RegisterSupplierInvoiceRequest {
+    pub invoice_number: String,
+    /// 仕入先（取引先コード）
+    pub counterparty: String,
+    /// 請求日（YYYY-MM-DD）
+    pub invoice_date: String,
+    /// 支払期日（YYYY-MM-DD）
+    pub due_date: String,
+    /// 借方に置く費用・資産の勘定科目
+    pub expense_account_code: String,
+    pub currency: String,
+    /// 税抜金額
+    pub amount: f64,
+    pub tax_amount: f64,
+}
+
+/// 請求書から買掛金計上仕訳（下書き）を起票するリクエスト
+#[derive(Debug, Clone)]
+pub struct CreateInvoiceJournalDraftRequest {
+    pub user_id: String,
+    pub counterparty: String,
+    pub invoice_number: String,
+}
+
+/// 請求書の支払を記録するリクエスト
+#[derive(Debug, Clone)]
+pub struct RecordInvoicePaymentRequest {
+    pub counterparty: String,
+    pub invoice_number: String,
+    /// 支払日（YYYY-MM-DD）
+    pub paid_date: String,
+    pub amount: f64,
+}
@@ -0,0 +1,17 @@
+// SuspenseClearing - 仮勘定の滞留分析・整理リクエスト
+
+/// 仮勘定滞留分析リクエスト
+#[derive(Debug, Clone)]
+pub struct SuspenseAgingRequest {
+    /// 経過日数の基準日（YYYY-MM-DD形式）
+    pub as_of_date: String,
+}
+
+/// 整理仕訳の提案を採用するリクエスト
+#[derive(Debug, Clone)]
+pub struct AcceptClearingSuggestionRequest {
+    pub user_id: String,
+    /// 整理対象の仮勘定計上（仕訳IDと明細番号）
+    pub entry_id: String,
+    pub line_number: u32,
+}
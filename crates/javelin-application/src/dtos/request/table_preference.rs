@@ -0,0 +1,12 @@
+// TablePreference - 一覧テーブル表示設定操作リクエスト
+
+use crate::dtos::response::{ColumnPreferenceDto, TableSortDto};
+
+/// 表示設定保存リクエスト
+#[derive(Debug, Clone)]
+pub struct SaveTablePreferenceRequest {
+    pub table_id: String,
+    /// 表示順の列設定
+    pub columns: Vec<ColumnPreferenceDto>,
+    pub sort: Option<TableSortDto>,
+}
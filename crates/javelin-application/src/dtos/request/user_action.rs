@@ -1,8 +1,31 @@
 // ユーザ操作記録 - Request DTOs
 
+/// 記録する操作の種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecordedActionKind {
+    /// 画面の表示（所要時間は表示していた時間）
+    ScreenVisited,
+    /// 操作の実行（所要時間は処理時間）
+    ActionPerformed,
+}
+
+/// ユーザ操作の記録
+///
+/// location・action には入力値を含めず、画面名・操作名の識別子のみを指定する。
 #[derive(Debug, Clone)]
 pub struct RecordUserActionRequest {
     pub user: String,
+    pub kind: RecordedActionKind,
     pub location: String,
     pub action: String,
+    pub duration_ms: u64,
+}
+
+/// 利用状況レポートの取得
+#[derive(Debug, Clone)]
+pub struct UserActivityReportRequest {
+    /// 照会する利用者（管理者のみ照会できる）
+    pub user_id: String,
+    /// 集計の開始日（この日を含む）
+    pub from: chrono::NaiveDate,
 }
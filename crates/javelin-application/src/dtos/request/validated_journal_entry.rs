@@ -0,0 +1,472 @@
+// 検証済み仕訳リクエスト
+// 責務: 文字列・数値で受け付けたリクエストDTOを型付きの値へ一括検証して変換する
+//
+// 取引日付は画面・JSONの境界で TransactionDate に解析済みのものを受け取る。
+// 金額は Money、貸借区分・税区分は列挙型として保持する。
+// 検証エラーは最初の1件で打ち切らず、すべての項目の問題を1つのリストで返す。
+
+use chrono::NaiveDate;
+use javelin_domain::{
+    financial_close::{
+        AccountCode,
+        journal_entry::{
+            entities::JournalEntryLine,
+            values::{
+                Amount, Currency, DebitCredit, DepartmentCode, Description, LineDimensions,
+                LineNumber, SubAccountCode, TaxType, TransactionDate, UserId, VoucherNumber,
+            },
+        },
+    },
+    masters::AccountingPolicy,
+};
+
+use super::{
+    CreateAdditionalEntryRequest, CreateReclassificationEntryRequest,
+    CreateReplacementEntryRequest, JournalEntryLineDto, RegisterJournalEntryRequest,
+};
+use crate::error::{ApplicationError, ApplicationResult};
+
+/// リクエストの日付形式
+pub const REQUEST_DATE_FORMAT: &str = "%Y-%m-%d";
+
+/// `YYYY-MM-DD` 形式の取引日付を解析（画面・JSONの境界で使用する）
+pub fn parse_transaction_date(value: &str) -> Result<TransactionDate, String> {
+    NaiveDate::parse_from_str(value.trim(), REQUEST_DATE_FORMAT)
+        .ok()
+        .and_then(|date| TransactionDate::new(date).ok())
+        .ok_or_else(|| format!("YYYY-MM-DD形式で入力してください: {}", value))
+}
+
+/// 検証エラーを集約して型付きの値へ変換する
+#[derive(Debug, Default)]
+struct ErrorCollector {
+    errors: Vec<String>,
+}
+
+impl ErrorCollector {
+    /// 検証結果を取り込み、エラーの場合は記録してNoneを返す
+    fn check<T, E: std::fmt::Display>(&mut self, field: &str, result: Result<T, E>) -> Option<T> {
+        match result {
+            Ok(value) => Some(value),
+            Err(e) => {
+                self.errors.push(format!("{}: {}", field, e));
+                None
+            }
+        }
+    }
+
+    /// 集約済みの検証結果（明細の検証など）を取り込む
+    fn absorb<T>(&mut self, result: ApplicationResult<T>) -> Option<T> {
+        result.map_err(|e| self.errors.extend(validation_errors(e))).ok()
+    }
+
+    fn push(&mut self, message: impl Into<String>) {
+        self.errors.push(message.into());
+    }
+
+    fn extend_prefixed(&mut self, prefix: &str, errors: Vec<String>) {
+        self.errors.extend(errors.into_iter().map(|e| format!("{} {}", prefix, e)));
+    }
+
+    fn into_result<T>(self, value: impl FnOnce() -> Option<T>) -> ApplicationResult<T> {
+        if !self.errors.is_empty() {
+            return Err(ApplicationError::ValidationFailed(self.errors));
+        }
+        value().ok_or_else(|| ApplicationError::ValidationFailed(vec!["入力が不正です".into()]))
+    }
+}
+
+fn validation_errors(error: ApplicationError) -> Vec<String> {
+    match error {
+        ApplicationError::ValidationFailed(errors) => errors,
+        other => vec![other.to_string()],
+    }
+}
+
+/// 金額と通貨
+///
+/// 有限かつ0以上の金額であることのみを保証する。小数点以下の桁数は
+/// 会計方針による端数処理の後、仕訳明細の作成時に検証する。
+#[derive(Debug, Clone, PartialEq)]
+pub struct Money {
+    value: f64,
+    currency: Currency,
+}
+
+impl Money {
+    pub fn new(value: f64, currency: Currency) -> Result<Self, String> {
+        if !value.is_finite() {
+            return Err(format!("金額が数値ではありません: {}", value));
+        }
+        if value < 0.0 {
+            return Err(format!("金額は0以上で入力してください: {}", value));
+        }
+        Ok(Self { value, currency })
+    }
+
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    pub fn currency(&self) -> &Currency {
+        &self.currency
+    }
+
+    /// 仕訳明細の金額へ変換
+    pub fn to_amount(&self) -> ApplicationResult<Amount> {
+        Ok(Amount::new(self.value, self.currency.clone())?)
+    }
+
+    fn with_value(&self, value: f64) -> Self {
+        Self { value, currency: self.currency.clone() }
+    }
+}
+
+/// 検証済みの仕訳明細
+#[derive(Debug, Clone)]
+pub struct ValidatedJournalEntryLine {
+    pub line_number: LineNumber,
+    pub side: DebitCredit,
+    pub account_code: AccountCode,
+    pub sub_account_code: Option<SubAccountCode>,
+    pub department_code: Option<DepartmentCode>,
+    pub dimensions: LineDimensions,
+    pub amount: Money,
+    pub tax_type: TaxType,
+    pub tax_amount: Money,
+    pub description: Option<Description>,
+}
+
+impl ValidatedJournalEntryLine {
+    /// 金額を通貨別端数処理、税額を税区分別端数処理に従って丸める
+    pub fn rounded(mut self, accounting_policy: &AccountingPolicy) -> Self {
+        let currency = self.amount.currency().clone();
+        self.amount = self
+            .amount
+            .with_value(accounting_policy.round_amount(&currency, self.amount.value()));
+        self.tax_amount = self.tax_amount.with_value(accounting_policy.round_tax(
+            &self.tax_type,
+            &currency,
+            self.tax_amount.value(),
+        ));
+        self
+    }
+
+    /// 仕訳明細エンティティを作成
+    pub fn into_line(self) -> ApplicationResult<JournalEntryLine> {
+        let amount = self.amount.to_amount()?;
+        let tax_amount = self.tax_amount.to_amount()?;
+        Ok(JournalEntryLine::new(
+            self.line_number,
+            self.side,
+            self.account_code,
+            self.sub_account_code,
+            self.department_code,
+            amount,
+            self.tax_type,
+            tax_amount,
+            self.description,
+        )?
+        .with_dimensions(self.dimensions))
+    }
+}
+
+impl TryFrom<&JournalEntryLineDto> for ValidatedJournalEntryLine {
+    type Error = ApplicationError;
+
+    fn try_from(dto: &JournalEntryLineDto) -> Result<Self, Self::Error> {
+        let mut errors = ErrorCollector::default();
+
+        let line_number = errors.check("行番号", LineNumber::new(dto.line_number));
+        let side = errors.check("貸借区分", dto.side.parse::<DebitCredit>());
+        let account_code = errors.check("勘定科目", AccountCode::new(dto.account_code.clone()));
+        let sub_account_code = errors
+            .check("補助科目", dto.sub_account_code.clone().map(SubAccountCode::new).transpose());
+        let department_code =
+            errors.check("部門", dto.department_code.clone().map(DepartmentCode::new).transpose());
+        let dimensions = errors.check("分析軸", LineDimensions::new(dto.dimensions.clone()));
+        let currency = errors.check("通貨", dto.currency.parse::<Currency>());
+        let tax_type = errors.check("税区分", dto.tax_type.parse::<TaxType>());
+        let description =
+            errors.check("摘要", dto.description.clone().map(Description::new).transpose());
+
+        let (amount, tax_amount) = match &currency {
+            Some(currency) => (
+                errors.check("金額", Money::new(dto.amount, currency.clone())),
+                errors.check("税額", Money::new(dto.tax_amount, currency.clone())),
+            ),
+            None => (None, None),
+        };
+
+        errors.into_result(|| {
+            Some(Self {
+                line_number: line_number?,
+                side: side?,
+                account_code: account_code?,
+                sub_account_code: sub_account_code?,
+                department_code: department_code?,
+                dimensions: dimensions?,
+                amount: amount?,
+                tax_type: tax_type?,
+                tax_amount: tax_amount?,
+                description: description?,
+            })
+        })
+    }
+}
+
+/// 明細をすべて検証（エラーは明細ごとに行番号を付けて集約）
+pub fn validate_lines(
+    lines: &[JournalEntryLineDto],
+) -> ApplicationResult<Vec<ValidatedJournalEntryLine>> {
+    let mut errors = ErrorCollector::default();
+    let mut validated = Vec::with_capacity(lines.len());
+
+    for (index, dto) in lines.iter().enumerate() {
+        match ValidatedJournalEntryLine::try_from(dto) {
+            Ok(line) => validated.push(line),
+            Err(e) => errors.extend_prefixed(
+                &format!("明細{}（行番号{}）", index + 1, dto.line_number),
+                validation_errors(e),
+            ),
+        }
+    }
+
+    errors.into_result(|| Some(validated))
+}
+
+/// 検証済みの仕訳登録リクエスト
+#[derive(Debug, Clone)]
+pub struct ValidatedRegisterJournalEntryRequest {
+    pub transaction_date: TransactionDate,
+    /// 伝票番号（未入力の場合はNoneで、登録時に採番する）
+    pub voucher_number: Option<VoucherNumber>,
+    pub lines: Vec<ValidatedJournalEntryLine>,
+    /// 伝票の摘要（空欄は摘要なし）
+    pub description: Option<Description>,
+    pub user_id: UserId,
+}
+
+impl TryFrom<&RegisterJournalEntryRequest> for ValidatedRegisterJournalEntryRequest {
+    type Error = ApplicationError;
+
+    fn try_from(request: &RegisterJournalEntryRequest) -> Result<Self, Self::Error> {
+        let mut errors = ErrorCollector::default();
+
+        let voucher_number = errors.check(
+            "伝票番号",
+            Some(request.voucher_number.as_str())
+                .filter(|number| !number.is_empty())
+                .map(|number| VoucherNumber::new(number.to_string()))
+                .transpose(),
+        );
+        let description = errors.check(
+            "摘要",
+            request
+                .description
+                .as_deref()
+                .map(str::trim)
+                .filter(|description| !description.is_empty())
+                .map(|description| Description::new(description.to_string()))
+                .transpose(),
+        );
+        let lines = errors.absorb(validate_lines(&request.lines));
+
+        errors.into_result(|| {
+            Some(Self {
+                transaction_date: request.transaction_date.clone(),
+                voucher_number: voucher_number?,
+                lines: lines?,
+                description: description?,
+                user_id: UserId::new(request.user_id.clone()),
+            })
+        })
+    }
+}
+
+/// 検証済みの追加・再分類・洗替仕訳リクエスト（参照元伝票に紐づく仕訳）
+#[derive(Debug, Clone)]
+pub struct ValidatedReferencedEntryRequest {
+    pub reference_entry_id: String,
+    pub transaction_date: TransactionDate,
+    pub voucher_number: VoucherNumber,
+    pub lines: Vec<ValidatedJournalEntryLine>,
+    pub user_id: UserId,
+}
+
+impl ValidatedReferencedEntryRequest {
+    fn validate(
+        reference_entry_id: &str,
+        transaction_date: TransactionDate,
+        voucher_number: &str,
+        lines: &[JournalEntryLineDto],
+        user_id: &str,
+    ) -> ApplicationResult<Self> {
+        let mut errors = ErrorCollector::default();
+
+        if reference_entry_id.trim().is_empty() {
+            errors.push("参照元伝票を指定してください");
+        }
+        let voucher_number =
+            errors.check("伝票番号", VoucherNumber::new(voucher_number.to_string()));
+        let lines = errors.absorb(validate_lines(lines));
+
+        errors.into_result(|| {
+            Some(Self {
+                reference_entry_id: reference_entry_id.to_string(),
+                transaction_date,
+                voucher_number: voucher_number?,
+                lines: lines?,
+                user_id: UserId::new(user_id.to_string()),
+            })
+        })
+    }
+}
+
+impl TryFrom<&CreateAdditionalEntryRequest> for ValidatedReferencedEntryRequest {
+    type Error = ApplicationError;
+
+    fn try_from(request: &CreateAdditionalEntryRequest) -> Result<Self, Self::Error> {
+        Self::validate(
+            &request.reference_entry_id,
+            request.transaction_date.clone(),
+            &request.voucher_number,
+            &request.lines,
+            &request.user_id,
+        )
+    }
+}
+
+impl TryFrom<&CreateReclassificationEntryRequest> for ValidatedReferencedEntryRequest {
+    type Error = ApplicationError;
+
+    fn try_from(request: &CreateReclassificationEntryRequest) -> Result<Self, Self::Error> {
+        Self::validate(
+            &request.reference_entry_id,
+            request.transaction_date.clone(),
+            &request.voucher_number,
+            &request.lines,
+            &request.user_id,
+        )
+    }
+}
+
+impl TryFrom<&CreateReplacementEntryRequest> for ValidatedReferencedEntryRequest {
+    type Error = ApplicationError;
+
+    fn try_from(request: &CreateReplacementEntryRequest) -> Result<Self, Self::Error> {
+        Self::validate(
+            &request.reference_entry_id,
+            request.transaction_date.clone(),
+            &request.voucher_number,
+            &request.lines,
+            &request.user_id,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line(line_number: u32, side: &str, amount: f64) -> JournalEntryLineDto {
+        JournalEntryLineDto {
+            line_number,
+            side: side.to_string(),
+            account_code: "1010".to_string(),
+            sub_account_code: None,
+            department_code: None,
+            dimensions: Default::default(),
+            amount,
+            currency: "JPY".to_string(),
+            tax_type: "NonTaxable".to_string(),
+            tax_amount: 0.0,
+            description: None,
+        }
+    }
+
+    fn request(lines: Vec<JournalEntryLineDto>) -> RegisterJournalEntryRequest {
+        RegisterJournalEntryRequest {
+            transaction_date: parse_transaction_date("2024-01-15").unwrap(),
+            voucher_number: String::new(),
+            lines,
+            description: Some("  ".to_string()),
+            user_id: "user1".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_validated_request_has_typed_values() {
+        let validated = ValidatedRegisterJournalEntryRequest::try_from(&request(vec![
+            line(1, "Debit", 1000.0),
+            line(2, "Credit", 1000.0),
+        ]))
+        .unwrap();
+
+        assert_eq!(
+            validated.transaction_date.value(),
+            NaiveDate::from_ymd_opt(2024, 1, 15).unwrap()
+        );
+        assert!(validated.voucher_number.is_none());
+        assert!(validated.description.is_none());
+        assert_eq!(validated.lines[0].side, DebitCredit::Debit);
+        assert_eq!(validated.lines[1].tax_type, TaxType::NonTaxable);
+        assert_eq!(validated.lines[1].amount.currency(), &Currency::JPY);
+    }
+
+    #[test]
+    fn test_validation_collects_all_errors() {
+        let mut broken = line(2, "Left", -1.0);
+        broken.tax_type = "Unknown".to_string();
+        let invalid = request(vec![line(1, "Debit", 1000.0), broken]);
+
+        let Err(ApplicationError::ValidationFailed(errors)) =
+            ValidatedRegisterJournalEntryRequest::try_from(&invalid)
+        else {
+            panic!("Expected ValidationFailed");
+        };
+        assert_eq!(errors.len(), 3, "{:?}", errors);
+        assert!(errors[0].starts_with("明細2（行番号2） 貸借区分"));
+        assert!(errors.iter().any(|e| e.contains("税区分")));
+        assert!(errors.iter().any(|e| e.contains("金額")));
+    }
+
+    #[test]
+    fn test_request_json_rejects_unknown_fields() {
+        let json = r#"{
+            "transaction_date": "2024-01-15",
+            "user_id": "user1",
+            "lines": [
+                {"line_number": 1, "side": "Debit", "account_code": "1010", "amount": 500.0,
+                 "currency": "JPY", "tax_type": "NonTaxable", "tax_amount": 0.0},
+                {"line_number": 2, "side": "Credit", "account_code": "4010", "amount": 500.0,
+                 "currency": "JPY", "tax_type": "NonTaxable", "tax_amount": 0.0,
+                 "dimensions": {"project": "P001"}}
+            ]
+        }"#;
+        let parsed: RegisterJournalEntryRequest = serde_json::from_str(json).unwrap();
+        let validated = ValidatedRegisterJournalEntryRequest::try_from(&parsed).unwrap();
+        assert_eq!(validated.lines[1].dimensions.get("project"), Some("P001"));
+
+        let misspelled = json.replace("\"tax_amount\": 0.0}", "\"tax_amout\": 0.0}");
+        assert!(serde_json::from_str::<RegisterJournalEntryRequest>(&misspelled).is_err());
+        let extra = json.replace("\"user_id\"", "\"approved\": true, \"user_id\"");
+        assert!(serde_json::from_str::<RegisterJournalEntryRequest>(&extra).is_err());
+
+        // 取引日付は境界で解析し、形式が不正なJSONは受け付けない
+        let slashed = json.replace("2024-01-15", "2024/01/15");
+        let error = serde_json::from_str::<RegisterJournalEntryRequest>(&slashed).unwrap_err();
+        assert!(error.to_string().starts_with("取引日付"), "{}", error);
+    }
+
+    #[test]
+    fn test_rounding_happens_before_amount_precision_check() {
+        let mut dto = line(1, "Debit", 1000.4);
+        dto.tax_amount = 100.456;
+        let validated = ValidatedJournalEntryLine::try_from(&dto).unwrap();
+        assert!(validated.clone().into_line().is_err());
+
+        let line = validated.rounded(&AccountingPolicy::default()).into_line().unwrap();
+        assert_eq!(line.amount().value(), 1000.0);
+    }
+}
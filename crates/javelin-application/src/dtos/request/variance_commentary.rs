@@ -0,0 +1,11 @@
+// VarianceCommentary - 増減コメントリクエスト
+
+/// 増減コメント保存リクエスト（コメントが空の場合は削除する）
+#[derive(Debug, Clone)]
+pub struct SaveVarianceCommentaryRequest {
+    pub account_code: String,
+    pub fiscal_year: i32,
+    pub period: u8,
+    pub commentary: String,
+    pub written_by: String,
+}
@@ -1,25 +1,95 @@
 // Response DTOs - Interactor → OutputPort → Presenter
 // Query結果およびCommand実行結果のデータ転送オブジェクト
 
+pub mod account_activity;
 pub mod account_master;
+pub mod account_reconciliation;
+pub mod accounting_policy;
 pub mod application_settings;
+pub mod audit_export;
+pub mod authentication;
+pub mod balance_analysis;
+pub mod calendar_master;
 pub mod closing_process;
+pub mod closing_timetable;
 pub mod company_master;
+pub mod consistency_check;
+pub mod dimension_master;
+pub mod entry_link;
+pub mod export_protection;
+pub mod financial_instrument;
+pub mod initial_setup;
+pub mod integrity_audit;
+pub mod inventory_valuation;
+pub mod job_queue;
 pub mod journal_entry_query;
 pub mod journal_entry_registration;
 pub mod journal_entry_search_result_dto;
+pub mod journal_import;
+pub mod ledger_annotation;
 pub mod load_account_master;
+pub mod management_account_mapping;
+pub mod master_change;
+pub mod note_cross_reference;
+pub mod period_reopen;
+pub mod period_rollover;
+pub mod projection_console;
+pub mod report_archive;
+pub mod report_delivery;
+pub mod report_parameter_history;
+pub mod sequence_audit;
+pub mod session_log;
+pub mod statement_line_mapping;
 pub mod subsidiary_account_master;
+pub mod supplier_invoice;
+pub mod suspense_clearing;
+pub mod table_preference;
 pub mod user_action;
+pub mod variance_commentary;
 
 // Re-export for convenience
+pub use account_activity::*;
 pub use account_master::*;
+pub use account_reconciliation::*;
+pub use accounting_policy::*;
 pub use application_settings::*;
+pub use audit_export::*;
+pub use authentication::*;
+pub use balance_analysis::*;
+pub use calendar_master::*;
 pub use closing_process::*;
+pub use closing_timetable::*;
 pub use company_master::*;
+pub use consistency_check::*;
+pub use dimension_master::*;
+pub use entry_link::*;
+pub use export_protection::*;
+pub use financial_instrument::*;
+pub use initial_setup::*;
+pub use integrity_audit::*;
+pub use inventory_valuation::*;
+pub use job_queue::*;
 pub use journal_entry_query::*;
 pub use journal_entry_registration::*;
 pub use journal_entry_search_result_dto::*;
+pub use journal_import::*;
+pub use ledger_annotation::*;
 pub use load_account_master::*;
+pub use management_account_mapping::*;
+pub use master_change::*;
+pub use note_cross_reference::*;
+pub use period_reopen::*;
+pub use period_rollover::*;
+pub use projection_console::*;
+pub use report_archive::*;
+pub use report_delivery::*;
+pub use report_parameter_history::*;
+pub use sequence_audit::*;
+pub use session_log::*;
+pub use statement_line_mapping::*;
 pub use subsidiary_account_master::*;
+pub use supplier_invoice::*;
+pub use suspense_clearing::*;
+pub use table_preference::*;
 pub use user_action::*;
+pub use variance_commentary::*;
@@ -0,0 +1,68 @@
+// AccountActivity - 勘定科目別の記帳量分析結果
+
+use super::AmountMask;
+
+/// ヒートマップの濃度の最大段階（0は記帳なし）
+pub const MAX_HEAT_LEVEL: u8 = 4;
+
+/// 1勘定科目・1か月の記帳量
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AccountActivityHeatCell {
+    pub posting_count: u32,
+    /// 借方・貸方金額の合計
+    pub amount: f64,
+    /// 勘定科目の期間中最大件数に対する濃度（0〜MAX_HEAT_LEVEL）
+    pub count_level: u8,
+    /// 勘定科目の期間中最大金額に対する濃度（0〜MAX_HEAT_LEVEL）
+    pub amount_level: u8,
+    /// 件数または金額が、他の月の平均を大きく上回る
+    pub spike: bool,
+}
+
+/// 勘定科目の月別の記帳量
+#[derive(Debug, Clone)]
+pub struct AccountActivityHeatRow {
+    pub account_code: String,
+    pub account_name: String,
+    /// periodsと同じ並び
+    pub cells: Vec<AccountActivityHeatCell>,
+    pub total_count: u32,
+    pub total_amount: f64,
+    /// 金額をマスキングしたか（金額・金額の濃度は0とする）
+    pub amount_masked: bool,
+}
+
+impl AccountActivityHeatRow {
+    /// 急増した月の数
+    pub fn spike_count(&self) -> usize {
+        self.cells.iter().filter(|cell| cell.spike).count()
+    }
+}
+
+/// 勘定科目別・月別の記帳量分析結果
+#[derive(Debug, Clone)]
+pub struct AnalyzeAccountActivityResponse {
+    /// 集計期間の月（YYYY-MM、古い順）
+    pub periods: Vec<String>,
+    /// 集計期間中に記帳のあった勘定科目（科目コード順）
+    pub rows: Vec<AccountActivityHeatRow>,
+}
+
+impl AnalyzeAccountActivityResponse {
+    /// 急増した月の総数
+    pub fn spike_count(&self) -> usize {
+        self.rows.iter().map(AccountActivityHeatRow::spike_count).sum()
+    }
+
+    /// ユーザが参照できない科目の金額をマスキング
+    pub fn mask_amounts(&mut self, amount_mask: &AmountMask) {
+        for row in self.rows.iter_mut().filter(|row| amount_mask.is_masked(&row.account_code)) {
+            row.amount_masked = true;
+            row.total_amount = 0.0;
+            for cell in &mut row.cells {
+                cell.amount = 0.0;
+                cell.amount_level = 0;
+            }
+        }
+    }
+}
@@ -0,0 +1,91 @@
+// AccountReconciliation - 勘定照合ワークスペース
+
+use javelin_domain::financial_close::account_reconciliation::{
+    AccountReconciliation, ReconciliationStatus,
+};
+
+/// 裏付け明細
+#[derive(Debug, Clone)]
+pub struct ReconciliationItemDto {
+    pub description: String,
+    pub amount: f64,
+    pub reference: Option<String>,
+}
+
+/// 勘定照合ワークスペースのレスポンス
+#[derive(Debug, Clone)]
+pub struct AccountReconciliationResponse {
+    pub account_code: String,
+    pub account_name: String,
+    pub fiscal_year: i32,
+    pub period: u8,
+    /// 期末の元帳残高（記帳済の仕訳のみ）
+    pub gl_balance: f64,
+    pub supported_total: f64,
+    /// 元帳残高 − 明細合計
+    pub difference: f64,
+    pub items: Vec<ReconciliationItemDto>,
+    pub reconciled: bool,
+    pub reconciled_by: Option<String>,
+    pub reconciled_at: Option<String>,
+    /// 照合後に元帳残高が変わっていないか（照合済の場合のみtrue）
+    pub current: bool,
+    /// 締日固定前に照合が必要な科目か
+    pub required: bool,
+}
+
+impl AccountReconciliationResponse {
+    pub fn new(
+        account_code: impl Into<String>,
+        account_name: impl Into<String>,
+        fiscal_year: i32,
+        period: u8,
+        gl_balance: f64,
+        reconciliation: Option<&AccountReconciliation>,
+        required: bool,
+    ) -> Self {
+        let (items, supported_total, reconciled_by, reconciled_at, current) = match reconciliation {
+            Some(reconciliation) => {
+                let (reconciled_by, reconciled_at) = match reconciliation.status() {
+                    ReconciliationStatus::Reconciled { reconciled_by, reconciled_at, .. } => {
+                        (Some(reconciled_by.clone()), Some(reconciled_at.clone()))
+                    }
+                    ReconciliationStatus::Open => (None, None),
+                };
+                let items = reconciliation
+                    .items()
+                    .iter()
+                    .map(|item| ReconciliationItemDto {
+                        description: item.description.clone(),
+                        amount: item.amount,
+                        reference: item.reference.clone(),
+                    })
+                    .collect();
+                (
+                    items,
+                    reconciliation.supported_total(),
+                    reconciled_by,
+                    reconciled_at,
+                    reconciliation.is_current(gl_balance),
+                )
+            }
+            None => (Vec::new(), 0.0, None, None, false),
+        };
+
+        Self {
+            account_code: account_code.into(),
+            account_name: account_name.into(),
+            fiscal_year,
+            period,
+            gl_balance,
+            supported_total,
+            difference: gl_balance - supported_total,
+            items,
+            reconciled: reconciled_by.is_some(),
+            reconciled_by,
+            reconciled_at,
+            current,
+            required,
+        }
+    }
+}
@@ -0,0 +1,161 @@
+// AccountingPolicy - 会計方針設定のレスポンス
+
+use javelin_domain::masters::{
+    AccountingPolicy, MASKED_AMOUNT, NegativeNumberPresentation, RoundingMode,
+};
+use serde::{Deserialize, Serialize};
+
+/// 会計方針取得レスポンス
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LoadAccountingPolicyResponse {
+    pub currency_roundings: Vec<CurrencyRoundingItem>,
+    pub tax_roundings: Vec<TaxRoundingItem>,
+    pub negative_presentation: PolicyOption,
+    /// 選択可能な端数処理方法（表示順）
+    pub rounding_modes: Vec<PolicyOption>,
+    /// 選択可能な負数表示方法（表示順）
+    pub negative_presentations: Vec<PolicyOption>,
+    /// 金額マスキング規則（`<科目範囲>:<ロール>`形式）
+    pub amount_masking_rules: Vec<String>,
+    /// ロール割当（ユーザID順）
+    pub user_roles: Vec<UserRolesItem>,
+    /// 要求したユーザが変更権限を持つか
+    pub is_administrator: bool,
+    /// 変更履歴（新しい順）
+    pub changes: Vec<AccountingPolicyChangeItem>,
+}
+
+/// 通貨別端数処理
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CurrencyRoundingItem {
+    pub currency: String,
+    pub decimal_places: u8,
+    pub rounding_mode: String,
+    pub rounding_mode_name: String,
+}
+
+/// 税区分別税額端数処理
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaxRoundingItem {
+    pub tax_type: String,
+    pub tax_type_name: String,
+    pub rounding_mode: String,
+    pub rounding_mode_name: String,
+}
+
+/// ユーザのロール割当
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UserRolesItem {
+    pub user_id: String,
+    pub roles: Vec<String>,
+}
+
+/// 選択肢
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PolicyOption {
+    pub code: String,
+    pub name: String,
+}
+
+/// 会計方針の変更履歴
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountingPolicyChangeItem {
+    pub setting: String,
+    pub before: String,
+    pub after: String,
+    pub changed_by: String,
+    pub changed_at: String,
+}
+
+/// 金額の出力書式（帳票・エクスポート用）
+///
+/// 会計方針の通貨別端数処理と負数表示方法に従って金額を整形する。
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AmountFormat {
+    pub currency_roundings: Vec<CurrencyRoundingItem>,
+    pub negative_presentation: String,
+}
+
+impl AmountFormat {
+    /// 会計方針から出力書式を作成
+    pub fn from_policy(policy: &AccountingPolicy) -> Self {
+        Self {
+            currency_roundings: policy
+                .currency_roundings()
+                .iter()
+                .map(|r| CurrencyRoundingItem {
+                    currency: r.currency().as_str().to_string(),
+                    decimal_places: r.decimal_places().value(),
+                    rounding_mode: r.mode().code().to_string(),
+                    rounding_mode_name: r.mode().display_name().to_string(),
+                })
+                .collect(),
+            negative_presentation: policy.negative_presentation().code().to_string(),
+        }
+    }
+
+    /// 金額を通貨の桁数・端数処理と負数表示方法に従って整形する
+    ///
+    /// 未設定の通貨は小数点以下2桁・四捨五入で整形する。
+    pub fn format(&self, value: f64, currency: &str) -> String {
+        let (decimal_places, mode) = self
+            .currency_roundings
+            .iter()
+            .find(|r| r.currency == currency)
+            .map(|r| {
+                (r.decimal_places, RoundingMode::from_code(&r.rounding_mode).unwrap_or_default())
+            })
+            .unwrap_or((2, RoundingMode::HalfUp));
+        let presentation =
+            NegativeNumberPresentation::from_code(&self.negative_presentation).unwrap_or_default();
+        presentation.format(mode.round(value, decimal_places), decimal_places)
+    }
+}
+
+impl Default for AmountFormat {
+    fn default() -> Self {
+        Self::from_policy(&AccountingPolicy::default())
+    }
+}
+
+/// マスキング対象の科目範囲（両端を含む）
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MaskedAccountRange {
+    pub account_from: String,
+    pub account_to: String,
+}
+
+/// ユーザごとの金額マスキング（照会・帳票・エクスポート用）
+///
+/// 会計方針の金額マスキング規則のうち、ユーザのロールで参照が許可されない科目範囲を保持する。
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AmountMask {
+    pub masked_ranges: Vec<MaskedAccountRange>,
+}
+
+impl AmountMask {
+    /// マスキングされた金額の表示
+    pub const MASKED: &'static str = MASKED_AMOUNT;
+
+    /// 会計方針からユーザのマスキングを作成
+    pub fn for_user(policy: &AccountingPolicy, user_id: &str) -> Self {
+        Self {
+            masked_ranges: policy
+                .amount_masking()
+                .masked_rules_for(user_id)
+                .into_iter()
+                .map(|rule| MaskedAccountRange {
+                    account_from: rule.account_from().to_string(),
+                    account_to: rule.account_to().to_string(),
+                })
+                .collect(),
+        }
+    }
+
+    /// 科目の金額をマスキングするか
+    pub fn is_masked(&self, account_code: &str) -> bool {
+        self.masked_ranges.iter().any(|range| {
+            range.account_from.as_str() <= account_code && account_code <= range.account_to.as_str()
+        })
+    }
+}
@@ -1,5 +1,7 @@
 // ApplicationSettings - アプリケーション設定操作レスポンス
 
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
 
 /// アプリケーション設定取得レスポンス
@@ -16,6 +18,8 @@ pub struct UserOptionsDto {
     pub language: String,
     pub decimal_places: u8,
     pub date_format: String,
+    pub batch_notification_bell: bool,
+    pub batch_notification_desktop: bool,
 }
 
 /// システム設定DTO
@@ -25,6 +29,16 @@ pub struct SystemSettingsDto {
     pub closing_day: u8,
     pub auto_backup_enabled: bool,
     pub backup_retention_days: u32,
+    pub approval_sla_warning_hours: u32,
+    pub approval_sla_breach_hours: u32,
+    /// 帳票の種類ごとの配信先（URI形式）
+    pub report_delivery: BTreeMap<String, Vec<String>>,
+    pub export_encryption_enabled: bool,
+    pub export_signature_enabled: bool,
+    pub deleted_entry_retain_enabled: bool,
+    pub deleted_entry_purge_after_months: u32,
+    /// 締日固定前に検証しない項目（検証項目コード）
+    pub closing_lock_disabled_checks: Vec<String>,
 }
 
 /// アプリケーション設定更新レスポンス
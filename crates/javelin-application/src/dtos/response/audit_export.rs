@@ -0,0 +1,45 @@
+// AuditExport - 監査用帳簿出力結果
+
+use chrono::{DateTime, Utc};
+
+/// 出力ファイル
+#[derive(Debug, Clone)]
+pub struct AuditExportFile {
+    pub file_name: String,
+    /// 帳簿の種類（例: "仕訳帳"）
+    pub book: String,
+    /// ファイルの内容（UTF-8のCSV）
+    pub content: String,
+    /// 見出し行を除くレコード件数
+    pub record_count: usize,
+    /// 内容のハッシュ値（SHA-256の16進文字列）
+    pub digest: String,
+}
+
+/// 監査用帳簿出力結果
+///
+/// 仕訳帳・総勘定元帳と、それらの件数・ハッシュ値を記録した索引ファイルを含む。
+#[derive(Debug, Clone)]
+pub struct AuditExportResponse {
+    pub fiscal_year: u32,
+    pub exported_by: String,
+    pub exported_at: DateTime<Utc>,
+    /// 対象の仕訳件数
+    pub entry_count: usize,
+    /// 帳簿ファイル（仕訳帳・総勘定元帳の順）
+    pub books: Vec<AuditExportFile>,
+    /// 索引ファイル
+    pub index: AuditExportFile,
+}
+
+impl AuditExportResponse {
+    /// 出力先のディレクトリ名
+    pub fn directory_name(&self) -> String {
+        format!("audit_export_{}", self.fiscal_year)
+    }
+
+    /// 索引を含むすべての出力ファイル
+    pub fn files(&self) -> impl Iterator<Item = &AuditExportFile> {
+        self.books.iter().chain(std::iter::once(&self.index))
+    }
+}
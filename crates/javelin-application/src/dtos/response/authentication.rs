@@ -0,0 +1,10 @@
+// Authentication - ログイン結果
+
+/// ログイン結果（セッションの利用者情報）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoginResponse {
+    pub user_id: String,
+    pub display_name: String,
+    /// 初回起動時にアカウントを作成した場合はtrue
+    pub account_created: bool,
+}
@@ -0,0 +1,48 @@
+// BalanceAnalysis - 勘定残高の期間比較分析結果
+
+/// 残高異常の種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BalanceAnomalyKind {
+    /// 通常と逆側の残高への符号反転
+    SignChange,
+    /// 前月比の増減が閾値超過
+    PriorPeriodChange,
+    /// 前年同月比の増減が閾値超過
+    PriorYearChange,
+}
+
+impl BalanceAnomalyKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::SignChange => "符号反転",
+            Self::PriorPeriodChange => "前月比",
+            Self::PriorYearChange => "前年同月比",
+        }
+    }
+}
+
+/// 残高異常（1勘定科目・1比較につき1件）
+#[derive(Debug, Clone)]
+pub struct BalanceAnomalyItem {
+    pub account_code: String,
+    pub account_name: String,
+    pub kind: BalanceAnomalyKind,
+    /// 当月末残高（借方残高を正とする）
+    pub current_balance: f64,
+    /// 比較対象期間末残高
+    pub comparison_balance: f64,
+    pub change_amount: f64,
+    /// 増減率（比較対象残高がゼロの場合はNone）
+    pub change_rate: Option<f64>,
+    pub detail: String,
+}
+
+/// 勘定残高の期間比較分析結果
+#[derive(Debug, Clone)]
+pub struct AnalyzeBalancesResponse {
+    pub fiscal_year: i32,
+    pub period: u8,
+    pub analyzed_accounts: usize,
+    /// 重要度順（符号反転を優先し、増減額の大きい順）
+    pub anomalies: Vec<BalanceAnomalyItem>,
+}
@@ -0,0 +1,18 @@
+// CalendarMaster - カレンダーマスタ操作レスポンス
+
+use serde::{Deserialize, Serialize};
+
+/// カレンダーマスタ取得レスポンス
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LoadCalendarMasterResponse {
+    pub holidays: Vec<HolidayItem>,
+    /// 会計年度開始月（会計期間の強調表示に使用）
+    pub fiscal_year_start_month: u8,
+}
+
+/// 休日項目
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HolidayItem {
+    pub date: String, // YYYY-MM-DD format
+    pub name: String,
+}
@@ -1,6 +1,9 @@
 // 決算処理関連 - Response DTOs
 // すべてのプロパティはプリミティブ型
 
+use super::{AgingBucketSummary, ReportDeliveryResultDto, ReportLineDto, SuspenseAgingItem};
+use crate::{dtos::request::JournalEntryLineDto, query_service::TrialBalanceResult};
+
 /// 元帳集約処理レスポンス
 #[derive(Debug, Clone)]
 pub struct ConsolidateLedgerResponse {
@@ -27,6 +30,30 @@ pub struct PrepareClosingResponse {
     pub bank_reconciliation_differences: Vec<BankReconciliationDifferenceDto>,
     pub accrual_entries_created: usize,
     pub provisional_financial_statements_generated: bool,
+    /// 仮勘定・清算勘定の期末日時点の滞留状況（勘定科目コード順、未整理の計上がある科目のみ）
+    pub suspense_aging: Vec<SuspenseAccountAgingDto>,
+}
+
+/// 仮勘定・清算勘定の勘定科目ごとの滞留状況
+#[derive(Debug, Clone)]
+pub struct SuspenseAccountAgingDto {
+    pub account_code: String,
+    /// 経過日数区分ごとの集計（AgingBucket::ALLの順）
+    pub buckets: Vec<AgingBucketSummary>,
+    /// 未整理の計上（経過日数の長い順）。仕訳IDと行番号で元の仕訳を参照する
+    pub items: Vec<SuspenseAgingItem>,
+}
+
+impl SuspenseAccountAgingDto {
+    /// 未整理残高の合計
+    pub fn total_amount(&self) -> f64 {
+        self.items.iter().map(|item| item.amount).sum()
+    }
+
+    /// 最も古い計上の経過日数
+    pub fn oldest_age_days(&self) -> i64 {
+        self.items.iter().map(|item| item.age_days).max().unwrap_or_default()
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -46,6 +73,45 @@ pub struct LockClosingPeriodResponse {
     pub locked_entries_count: usize,
     pub locked_at: String, // ISO 8601 format
     pub audit_log_id: String,
+    /// 固定前に行った事前検証の結果
+    pub validation: ClosingLockValidationReport,
+    /// 事前検証の失敗を管理者が上書きして固定したか
+    pub overridden: bool,
+}
+
+/// 締日固定の事前検証項目ごとの結果
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClosingLockCheckResult {
+    /// 検証項目コード
+    pub code: String,
+    pub name: String,
+    pub passed: bool,
+    /// 失敗の内容（科目・仕訳ごと）
+    pub findings: Vec<String>,
+}
+
+/// 締日固定の事前検証結果（設定で無効にした項目は含まない）
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ClosingLockValidationReport {
+    pub period_id: String,
+    pub checks: Vec<ClosingLockCheckResult>,
+}
+
+impl ClosingLockValidationReport {
+    pub fn passed(&self) -> bool {
+        self.checks.iter().all(|check| check.passed)
+    }
+
+    pub fn failed_checks(&self) -> impl Iterator<Item = &ClosingLockCheckResult> {
+        self.checks.iter().filter(|check| !check.passed)
+    }
+
+    /// 失敗した項目ごとのメッセージ
+    pub fn failure_messages(&self) -> Vec<String> {
+        self.failed_checks()
+            .map(|check| format!("{}: {}", check.name, check.findings.join(", ")))
+            .collect()
+    }
 }
 
 /// 試算表生成処理レスポンス
@@ -59,6 +125,139 @@ pub struct GenerateTrialBalanceResponse {
     pub account_balances: Vec<AccountBalanceDto>,
     pub temporary_account_balances: Vec<AccountBalanceDto>,
     pub foreign_exchange_differences: Vec<ForeignExchangeDifferenceDto>,
+    /// 表示通貨建て試算表（換算差額行を含む）
+    pub presentation_trial_balance: CurrencyTrialBalanceDto,
+    /// 取引通貨別試算表
+    pub currency_trial_balances: Vec<CurrencyTrialBalanceDto>,
+    pub translation_difference: f64,
+    pub translation_difference_currency: String,
+    /// 承認待ちの仕訳を含めて集計したか
+    pub include_pending_approval: bool,
+    /// 設定された配信先への配信結果
+    pub deliveries: Vec<ReportDeliveryResultDto>,
+}
+
+/// 通貨別試算表
+#[derive(Debug, Clone)]
+pub struct CurrencyTrialBalanceDto {
+    pub currency: String,
+    pub lines: Vec<TrialBalanceLineDto>,
+    pub total_debit: f64,
+    pub total_credit: f64,
+    /// 縦計・横計の検証結果
+    pub proof: TrialBalanceProofDto,
+    /// 勘定科目区分・科目グループ別の小計（表示順）
+    pub hierarchy: Vec<TrialBalanceClassDto>,
+}
+
+/// 試算表の勘定科目区分（資産・負債・純資産・収益・費用）
+#[derive(Debug, Clone)]
+pub struct TrialBalanceClassDto {
+    /// 区分名（例: 資産）
+    pub name: String,
+    pub groups: Vec<TrialBalanceGroupDto>,
+    /// 区分の合計（例: 資産合計）
+    pub subtotal: TrialBalanceSubtotalDto,
+}
+
+/// 試算表の科目グループ（財務諸表表示科目。例: 流動資産）
+#[derive(Debug, Clone)]
+pub struct TrialBalanceGroupDto {
+    /// グループ名（表示科目が未割当の科目は「未割当」）
+    pub name: String,
+    /// 所属する勘定科目コード（科目コード順）
+    pub account_codes: Vec<String>,
+    /// グループの小計（例: 流動資産計）
+    pub subtotal: TrialBalanceSubtotalDto,
+}
+
+/// 試算表の小計行
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrialBalanceSubtotalDto {
+    pub label: String,
+    pub opening_balance: f64,
+    pub debit_amount: f64,
+    pub credit_amount: f64,
+    pub closing_balance: f64,
+}
+
+impl TrialBalanceSubtotalDto {
+    /// 明細行を合算した小計
+    pub fn sum<'a>(
+        label: impl Into<String>,
+        lines: impl IntoIterator<Item = &'a TrialBalanceLineDto>,
+    ) -> Self {
+        lines.into_iter().fold(
+            Self {
+                label: label.into(),
+                opening_balance: 0.0,
+                debit_amount: 0.0,
+                credit_amount: 0.0,
+                closing_balance: 0.0,
+            },
+            |mut subtotal, line| {
+                subtotal.opening_balance += line.opening_balance;
+                subtotal.debit_amount += line.debit_amount;
+                subtotal.credit_amount += line.credit_amount;
+                subtotal.closing_balance += line.closing_balance;
+                subtotal
+            },
+        )
+    }
+}
+
+/// 試算表の検証結果（縦計・横計と明細行のハッシュ値）
+///
+/// 出力した試算表の改ざん・欠落を検出できるよう、明細行から再計算した値を持つ。
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrialBalanceProofDto {
+    /// 明細行の借方合計（縦計）
+    pub debit_total: f64,
+    /// 明細行の貸方合計（縦計）
+    pub credit_total: f64,
+    /// 借方合計 − 貸方合計
+    pub difference: f64,
+    /// 横計の不一致（Σ期末残高 −（Σ期首残高 + 借方合計 − 貸方合計））
+    pub cross_foot_difference: f64,
+    /// 科目数（明細行数）
+    pub account_count: usize,
+    /// 明細行のハッシュ値（16進文字列、対象は `trial_balance_rows_payload`）
+    pub rows_hash: String,
+    /// 縦計が報告された合計と一致し、横計の不一致がないか
+    pub footed: bool,
+}
+
+/// 試算表明細行のハッシュ対象
+///
+/// 通貨と、各明細行の科目コード・科目名・金額（小数点以下2桁）をタブ区切りで並べる。
+/// 出力ファイルの明細から同じ文字列を組み立てれば、ハッシュ値を照合できる。
+pub fn trial_balance_rows_payload(currency: &str, lines: &[TrialBalanceLineDto]) -> String {
+    let mut payload = format!("{}\n", currency);
+    for line in lines {
+        payload.push_str(&format!(
+            "{}\t{}\t{:.2}\t{:.2}\t{:.2}\t{:.2}\n",
+            line.account_code,
+            line.account_name,
+            line.opening_balance,
+            line.debit_amount,
+            line.credit_amount,
+            line.closing_balance,
+        ));
+    }
+    payload
+}
+
+/// 試算表明細行（金額はすべて所属する試算表の通貨建て）
+#[derive(Debug, Clone)]
+pub struct TrialBalanceLineDto {
+    pub account_code: String,
+    pub account_name: String,
+    pub opening_balance: f64,
+    pub debit_amount: f64,
+    pub credit_amount: f64,
+    pub closing_balance: f64,
+    /// 適用換算レート（取引通貨建て試算表では1.0）
+    pub exchange_rate: f64,
 }
 
 #[derive(Debug, Clone)]
@@ -90,9 +289,21 @@ pub struct GenerateNoteDraftResponse {
     pub accounting_policies: Vec<String>,
     pub significant_estimates: Vec<String>,
     pub account_breakdowns: Vec<AccountBreakdownDto>,
+    /// 見積りの根拠資料
+    pub supporting_documents: Vec<SupportingDocumentDto>,
     pub note_draft: String,
 }
 
+/// 注記の根拠資料
+#[derive(Debug, Clone)]
+pub struct SupportingDocumentDto {
+    pub document_id: String,
+    pub title: String,
+    pub summary: String,
+    /// 根拠資料に基づいて起票した仕訳
+    pub journal_entry_id: Option<String>,
+}
+
 #[derive(Debug, Clone)]
 pub struct AccountBreakdownDto {
     pub account_code: String,
@@ -105,6 +316,71 @@ pub struct AdjustAccountsResponse {
     pub adjustment_entries_created: usize,
     pub reclassified_accounts: Vec<AccountReclassificationDto>,
     pub tax_effect_adjustments: Vec<TaxEffectAdjustmentDto>,
+    /// 試算（イベントを記録していない）か
+    pub dry_run: bool,
+    /// 起票した（試算の場合は起票予定の）補正
+    pub entries: Vec<ClosingEntryDto>,
+    /// 補正を反映した試算表の残高
+    pub trial_balance_impact: Vec<TrialBalanceImpactDto>,
+}
+
+/// 締め処理で起票する仕訳
+#[derive(Debug, Clone)]
+pub struct ClosingEntryDto {
+    /// 起票した仕訳・補正のID（試算の場合はNone）
+    pub entry_id: Option<String>,
+    /// YYYY-MM-DD形式
+    pub transaction_date: String,
+    pub description: String,
+    pub lines: Vec<JournalEntryLineDto>,
+}
+
+/// 締め処理の仕訳を反映した試算表の勘定残高
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrialBalanceImpactDto {
+    pub account_code: String,
+    /// 試算表にない勘定科目は空
+    pub account_name: String,
+    /// 反映前の期末残高（借方を正）
+    pub closing_balance: f64,
+    pub debit_amount: f64,
+    pub credit_amount: f64,
+    /// 反映後の期末残高（借方を正）
+    pub simulated_balance: f64,
+}
+
+impl TrialBalanceImpactDto {
+    /// 仕訳の影響を受ける勘定科目ごとに、反映前後の残高を集計（勘定科目コード順）
+    pub fn simulate(trial_balance: &TrialBalanceResult, entries: &[ClosingEntryDto]) -> Vec<Self> {
+        let mut impacts: std::collections::BTreeMap<&str, Self> = Default::default();
+        for line in entries.iter().flat_map(|entry| &entry.lines) {
+            let impact = impacts.entry(line.account_code.as_str()).or_insert_with(|| {
+                let current =
+                    trial_balance.entries.iter().find(|e| e.account_code == line.account_code);
+                Self {
+                    account_code: line.account_code.clone(),
+                    account_name: current.map(|e| e.account_name.clone()).unwrap_or_default(),
+                    closing_balance: current.map_or(0.0, |e| e.closing_balance),
+                    debit_amount: 0.0,
+                    credit_amount: 0.0,
+                    simulated_balance: 0.0,
+                }
+            });
+            if line.side == "Debit" {
+                impact.debit_amount += line.amount;
+            } else {
+                impact.credit_amount += line.amount;
+            }
+        }
+        impacts
+            .into_values()
+            .map(|mut impact| {
+                impact.simulated_balance =
+                    impact.closing_balance + impact.debit_amount - impact.credit_amount;
+                impact
+            })
+            .collect()
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -135,6 +411,14 @@ pub struct ApplyIfrsValuationResponse {
     pub impairment_losses: Vec<ImpairmentLossDto>,
     pub fair_value_adjustments: Vec<FairValueAdjustmentDto>,
     pub lease_measurements: Vec<LeaseMeasurementDto>,
+    /// 償却原価で測定する金融商品の利息の見越計上
+    pub interest_accruals: Vec<InterestAccrualDto>,
+    /// 試算（仕訳・イベントを記録していない）か
+    pub dry_run: bool,
+    /// 起票した（試算の場合は起票予定の）仕訳
+    pub entries: Vec<ClosingEntryDto>,
+    /// 仕訳を反映した試算表の残高
+    pub trial_balance_impact: Vec<TrialBalanceImpactDto>,
 }
 
 #[derive(Debug, Clone)]
@@ -178,6 +462,18 @@ pub struct FairValueAdjustmentDto {
     pub adjustment_currency: String,
 }
 
+#[derive(Debug, Clone)]
+pub struct InterestAccrualDto {
+    pub instrument_id: String,
+    pub instrument_name: String,
+    pub effective_interest: f64,
+    pub coupon_interest: f64,
+    pub amortization: f64,
+    pub currency: String,
+    /// 見越計上の仕訳（試算の場合は空）
+    pub entry_id: String,
+}
+
 #[derive(Debug, Clone)]
 pub struct LeaseMeasurementDto {
     pub lease_contract: String,
@@ -193,9 +489,87 @@ pub struct GenerateFinancialStatementsResponse {
     pub statement_of_financial_position: StatementOfFinancialPositionDto,
     pub statement_of_profit_or_loss: StatementOfProfitOrLossDto,
     pub statement_of_changes_in_equity: StatementOfChangesInEquityDto,
-    pub statement_of_cash_flows: StatementOfCashFlowsDto,
+    /// キャッシュ・フロー計算書（表示科目に現金及び現金同等物の区分がなく算出できない場合はNone）
+    pub statement_of_cash_flows: Option<StatementOfCashFlowsDto>,
     pub financial_indicators: FinancialIndicatorsDto,
     pub cross_check_passed: bool,
+    /// 表示科目ごとの金額と参照する注記番号（表示順）
+    pub statement_captions: Vec<StatementCaptionDto>,
+    /// 別紙に添付する試算表の増減コメント（科目コード順）
+    pub variance_commentaries: Vec<StatementCommentaryDto>,
+    /// 設定された配信先への配信結果
+    pub deliveries: Vec<ReportDeliveryResultDto>,
+}
+
+/// 財務諸表の表示科目と注記参照
+#[derive(Debug, Clone)]
+pub struct StatementCaptionDto {
+    /// 表示科目コード（BS-CA など）
+    pub statement_line: String,
+    pub caption: String,
+    pub amount: f64,
+    /// 参照する注記番号（昇順。勘定科目からの参照を含む）
+    pub note_numbers: Vec<u32>,
+}
+
+/// 財務諸表の別紙に添付する増減コメント
+#[derive(Debug, Clone)]
+pub struct StatementCommentaryDto {
+    pub account_code: String,
+    pub account_name: String,
+    pub commentary: String,
+    pub written_by: String,
+    pub written_at: String,
+}
+
+impl GenerateFinancialStatementsResponse {
+    /// 帳票アーカイブ用の表示行（財務諸表の各表示科目と金額）
+    pub fn report_lines(&self) -> Vec<ReportLineDto> {
+        let position = &self.statement_of_financial_position;
+        let profit_or_loss = &self.statement_of_profit_or_loss;
+        let equity = &self.statement_of_changes_in_equity;
+        let mut lines = vec![
+            ReportLineDto::new("財政状態計算書/流動資産", position.current_assets),
+            ReportLineDto::new("財政状態計算書/非流動資産", position.non_current_assets),
+            ReportLineDto::new("財政状態計算書/流動負債", position.current_liabilities),
+            ReportLineDto::new("財政状態計算書/非流動負債", position.non_current_liabilities),
+            ReportLineDto::new("財政状態計算書/資本", position.equity),
+            ReportLineDto::new("損益計算書/売上収益", profit_or_loss.revenue),
+            ReportLineDto::new("損益計算書/売上原価", profit_or_loss.cost_of_sales),
+            ReportLineDto::new("損益計算書/売上総利益", profit_or_loss.gross_profit),
+            ReportLineDto::new(
+                "損益計算書/販売費及び一般管理費",
+                profit_or_loss.operating_expenses,
+            ),
+            ReportLineDto::new("損益計算書/営業利益", profit_or_loss.operating_profit),
+            ReportLineDto::new("損益計算書/当期純利益", profit_or_loss.net_profit),
+            ReportLineDto::new("持分変動計算書/期首残高", equity.opening_balance),
+            ReportLineDto::new("持分変動計算書/当期純利益", equity.net_profit),
+            ReportLineDto::new("持分変動計算書/配当", equity.dividends),
+            ReportLineDto::new("持分変動計算書/期末残高", equity.closing_balance),
+        ];
+        if let Some(cash_flows) = &self.statement_of_cash_flows {
+            lines.extend([
+                ReportLineDto::new(
+                    "キャッシュ・フロー計算書/営業活動",
+                    cash_flows.operating_activities,
+                ),
+                ReportLineDto::new(
+                    "キャッシュ・フロー計算書/投資活動",
+                    cash_flows.investing_activities,
+                ),
+                ReportLineDto::new(
+                    "キャッシュ・フロー計算書/財務活動",
+                    cash_flows.financing_activities,
+                ),
+                ReportLineDto::new(
+                    "キャッシュ・フロー計算書/現金増減額",
+                    cash_flows.net_change_in_cash,
+                ),
+            ]);
+        }
+        lines
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -0,0 +1,38 @@
+// ClosingTimetable - 締めスケジュール操作レスポンス
+
+use serde::{Deserialize, Serialize};
+
+/// 締め進捗ボード取得レスポンス
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClosingTimetableBoardResponse {
+    pub fiscal_year: i32,
+    pub period: u8,
+    /// 期末日（YYYY-MM-DD）
+    pub period_end: String,
+    /// タスク（期日・タスクコード順）
+    pub items: Vec<ClosingTimetableBoardItem>,
+    pub completed_count: usize,
+    pub overdue_count: usize,
+}
+
+/// 締め進捗ボードの項目
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClosingTimetableBoardItem {
+    pub code: String,
+    pub task: String,
+    pub owner: String,
+    pub due_offset_days: i32,
+    /// 期日（YYYY-MM-DD）
+    pub due_date: String,
+    /// 対応する締め工程（英語名）
+    pub step: String,
+    pub step_label: String,
+    /// 進捗状態（英語名）
+    pub status: String,
+    pub status_label: String,
+    /// 期日までの残り日数（期日超過は負数、完了済みはNone）
+    pub days_remaining: Option<i64>,
+    pub is_overdue: bool,
+    /// 最終更新者（未更新はNone）
+    pub updated_by: Option<String>,
+}
@@ -0,0 +1,47 @@
+// ConsistencyCheck - Projection間整合性チェック結果
+
+/// 整合性違反の種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConsistencyViolationKind {
+    /// 勘定科目別の仕訳明細合計と元帳の増減が一致しない
+    AccountMovementMismatch,
+    /// 記帳済仕訳が元帳に存在しない
+    PostedEntryMissingFromLedger,
+    /// 記帳イベントのない元帳明細
+    LedgerEntryWithoutPostedEvent,
+}
+
+impl ConsistencyViolationKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::AccountMovementMismatch => "科目残高不一致",
+            Self::PostedEntryMissingFromLedger => "元帳未反映",
+            Self::LedgerEntryWithoutPostedEvent => "記帳イベントなし",
+        }
+    }
+}
+
+/// 整合性違反
+#[derive(Debug, Clone)]
+pub struct ConsistencyViolation {
+    pub kind: ConsistencyViolationKind,
+    /// 対象（勘定科目コードまたは伝票番号）
+    pub subject: String,
+    pub detail: String,
+    /// 修復方法の提案
+    pub suggestion: String,
+}
+
+/// 整合性チェック結果
+#[derive(Debug, Clone)]
+pub struct ConsistencyCheckResponse {
+    pub checked_entries: usize,
+    pub checked_accounts: usize,
+    pub violations: Vec<ConsistencyViolation>,
+}
+
+impl ConsistencyCheckResponse {
+    pub fn is_consistent(&self) -> bool {
+        self.violations.is_empty()
+    }
+}
@@ -0,0 +1,19 @@
+// DimensionMaster - 分析軸マスタ操作レスポンス
+
+use serde::{Deserialize, Serialize};
+
+/// 分析軸マスタ取得レスポンス
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LoadDimensionMastersResponse {
+    /// 分析軸（種類・コード順）
+    pub items: Vec<DimensionMasterItem>,
+}
+
+/// 分析軸マスタ項目
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DimensionMasterItem {
+    pub kind: String,
+    pub code: String,
+    pub name: String,
+    pub is_active: bool,
+}
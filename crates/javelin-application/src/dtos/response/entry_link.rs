@@ -0,0 +1,53 @@
+// EntryLink - 仕訳間の紐付け
+
+/// 仕訳の紐付け
+#[derive(Debug, Clone)]
+pub struct EntryLinkDto {
+    pub link_id: String,
+    pub counterpart_id: String,
+    pub kind: String,
+    pub kind_name: String,
+    /// この仕訳の立場（計上・決済・前払・償却）
+    pub role_name: String,
+    /// 相手仕訳の立場
+    pub counterpart_role_name: String,
+    pub amount: f64,
+    pub linked_by: String,
+    pub linked_at: String,
+}
+
+/// 仕訳の紐付け一覧
+#[derive(Debug, Clone)]
+pub struct EntryLinksResponse {
+    pub entry_id: String,
+    /// 紐付け順（解除済みは除く）
+    pub links: Vec<EntryLinkDto>,
+}
+
+/// 期末時点で未決済の計上・前払
+#[derive(Debug, Clone)]
+pub struct UnsettledLinkItem {
+    pub entry_id: String,
+    pub transaction_date: String,
+    pub kind_name: String,
+    /// 元仕訳の金額（借方合計）
+    pub amount: f64,
+    /// 期末までに決済・償却された金額
+    pub settled_amount: f64,
+    pub outstanding_amount: f64,
+}
+
+/// 期末時点の未決済の計上・前払の一覧
+#[derive(Debug, Clone)]
+pub struct UnsettledLinksResponse {
+    pub period_end: String,
+    /// 取引日順
+    pub items: Vec<UnsettledLinkItem>,
+}
+
+impl UnsettledLinksResponse {
+    /// 未決済残高の合計
+    pub fn total_outstanding(&self) -> f64 {
+        self.items.iter().map(|item| item.outstanding_amount).sum()
+    }
+}
@@ -0,0 +1,42 @@
+// ExportProtection - 出力ファイル保護レスポンス
+
+/// 保護済みの出力ファイル
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProtectedExportFileDto {
+    pub file_name: String,
+    pub content: Vec<u8>,
+}
+
+/// 出力ファイル保護レスポンス
+///
+/// 出力するファイル（暗号化した場合はZIP、署名した場合は署名ファイルを続けて）を返す。
+#[derive(Debug, Clone)]
+pub struct ProtectExportResponse {
+    pub files: Vec<ProtectedExportFileDto>,
+    pub encrypted: bool,
+    pub signed: bool,
+}
+
+impl ProtectExportResponse {
+    /// 出力するファイル名の一覧
+    pub fn file_names(&self) -> Vec<&str> {
+        self.files.iter().map(|file| file.file_name.as_str()).collect()
+    }
+}
+
+/// 分離署名の検証結果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExportSignatureVerificationDto {
+    /// 署名ファイルに記録されたファイル名
+    pub file_name: String,
+    /// 署名ファイルに記録されたSHA-256
+    pub digest: String,
+    /// 署名した公開鍵（16進）
+    pub public_key: String,
+    /// 署名日時（RFC 3339）
+    pub signed_at: String,
+    /// ファイルのハッシュ値と署名が一致したか
+    pub valid: bool,
+    /// このインストールの署名鍵で署名されたか
+    pub trusted: bool,
+}
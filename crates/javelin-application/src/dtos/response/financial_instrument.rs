@@ -0,0 +1,73 @@
+// FinancialInstrument - 金融商品と償却原価スケジュール
+
+use javelin_domain::financial_close::financial_instrument::FinancialInstrument;
+
+/// 金融商品レスポンス
+#[derive(Debug, Clone)]
+pub struct FinancialInstrumentResponse {
+    pub instrument_id: String,
+    pub name: String,
+    pub kind: String,
+    pub position: String,
+    pub account_code: String,
+    pub currency: String,
+    pub face_value: f64,
+    pub initial_carrying_amount: f64,
+    pub coupon_rate: f64,
+    pub effective_rate: f64,
+    pub start_date: String,
+    pub maturity_date: String,
+    /// 償却原価スケジュール（月次）
+    pub schedule: Vec<AmortizationScheduleLineDto>,
+}
+
+/// 償却原価スケジュールの1か月分
+#[derive(Debug, Clone)]
+pub struct AmortizationScheduleLineDto {
+    pub fiscal_year: i32,
+    pub period: u8,
+    pub opening_carrying_amount: f64,
+    pub effective_interest: f64,
+    pub coupon_interest: f64,
+    pub amortization: f64,
+    pub closing_carrying_amount: f64,
+    /// 利息の見越計上仕訳（起票済みの場合）
+    pub accrued_entry_id: Option<String>,
+}
+
+impl FinancialInstrumentResponse {
+    pub fn from_instrument(instrument: &FinancialInstrument) -> Self {
+        let schedule = instrument
+            .amortization_schedule()
+            .into_iter()
+            .map(|line| AmortizationScheduleLineDto {
+                accrued_entry_id: instrument
+                    .accrued_entry_id(line.fiscal_year, line.period)
+                    .map(str::to_string),
+                fiscal_year: line.fiscal_year,
+                period: line.period,
+                opening_carrying_amount: line.opening_carrying_amount,
+                effective_interest: line.effective_interest,
+                coupon_interest: line.coupon_interest,
+                amortization: line.amortization,
+                closing_carrying_amount: line.closing_carrying_amount,
+            })
+            .collect();
+
+        Self {
+            instrument_id: instrument.instrument_id().to_string(),
+            name: instrument.name().to_string(),
+            kind: instrument.kind().as_str().to_string(),
+            position: instrument.position().as_str().to_string(),
+            account_code: instrument.account_code().to_string(),
+            currency: instrument.currency().to_string(),
+            face_value: instrument.face_value(),
+            initial_carrying_amount: instrument.initial_carrying_amount(),
+            coupon_rate: instrument.coupon_rate(),
+            effective_rate: instrument.effective_rate(),
+            start_date: instrument.start_date().format("%Y-%m-%d").to_string(),
+            maturity_date: instrument.maturity_date().format("%Y-%m-%d").to_string(),
+            schedule,
+        }
+    }
+}
@@ -0,0 +1,21 @@
+// InitialSetup - 初期セットアップのレスポンス
+
+use super::PolicyOption;
+
+/// 初期セットアップの選択肢一覧
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InitialSetupOptions {
+    pub currencies: Vec<PolicyOption>,
+    /// 勘定科目テンプレート（名称に科目数を含む）
+    pub chart_templates: Vec<PolicyOption>,
+}
+
+/// 初期セットアップの結果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InitialSetupResponse {
+    pub company_code: String,
+    pub accounts_registered: usize,
+    /// 作成した管理者（そのままログイン状態にする）
+    pub admin_user_id: String,
+    pub admin_display_name: String,
+}
@@ -0,0 +1,26 @@
+// IntegrityAudit - 整合性監査結果
+
+/// 監査項目ごとの結果
+#[derive(Debug, Clone)]
+pub struct IntegrityAuditCheckDto {
+    pub name: String,
+    pub summary: String,
+    pub passed: bool,
+    pub findings: Vec<String>,
+}
+
+/// 整合性監査結果
+#[derive(Debug, Clone)]
+pub struct IntegrityAuditResponse {
+    pub audit_id: String,
+    /// 実行契機の表示名
+    pub trigger: String,
+    pub requested_by: String,
+    /// RFC 3339形式
+    pub started_at: String,
+    /// RFC 3339形式
+    pub completed_at: String,
+    pub passed: bool,
+    pub finding_count: usize,
+    pub checks: Vec<IntegrityAuditCheckDto>,
+}
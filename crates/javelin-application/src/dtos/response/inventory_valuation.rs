@@ -0,0 +1,56 @@
+// InventoryValuation - 棚卸資産評価ワークシート
+
+use javelin_domain::financial_close::inventory_valuation::InventoryWriteDownWorksheet;
+
+use super::InventoryWriteDownDto;
+
+/// 棚卸資産評価ワークシートレスポンス
+#[derive(Debug, Clone)]
+pub struct InventoryWorksheetResponse {
+    pub worksheet_id: String,
+    pub fiscal_year: i32,
+    pub period: u8,
+    pub method: String,
+    pub currency: String,
+    pub item_count: usize,
+    /// 算定単位ごとの評価減
+    pub write_downs: Vec<InventoryWriteDownDto>,
+    pub total_write_down: f64,
+    /// 評価減の仕訳（起票済みの場合）
+    pub posted_entry_id: Option<String>,
+}
+
+impl InventoryWorksheetResponse {
+    pub fn from_worksheet(worksheet: &InventoryWriteDownWorksheet) -> Self {
+        Self {
+            worksheet_id: worksheet.worksheet_id(),
+            fiscal_year: worksheet.fiscal_year(),
+            period: worksheet.period(),
+            method: worksheet.method().as_str().to_string(),
+            currency: worksheet.currency().to_string(),
+            item_count: worksheet.items().len(),
+            write_downs: InventoryWriteDownDto::from_worksheet(worksheet),
+            total_write_down: worksheet.total_write_down(),
+            posted_entry_id: worksheet.posted_entry_id().map(str::to_string),
+        }
+    }
+}
+
+impl InventoryWriteDownDto {
+    /// ワークシートの算定単位ごとの評価減
+    pub fn from_worksheet(worksheet: &InventoryWriteDownWorksheet) -> Vec<Self> {
+        worksheet
+            .calculate()
+            .into_iter()
+            .map(|line| Self {
+                item: line.unit,
+                cost: line.cost,
+                cost_currency: worksheet.currency().to_string(),
+                net_realizable_value: line.net_realizable_value,
+                net_realizable_value_currency: worksheet.currency().to_string(),
+                write_down_amount: line.write_down,
+                write_down_currency: worksheet.currency().to_string(),
+            })
+            .collect()
+    }
+}
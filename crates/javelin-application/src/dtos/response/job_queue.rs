@@ -0,0 +1,60 @@
+// JobQueue - 長時間処理ジョブレスポンス
+
+use javelin_domain::job::Job;
+
+/// ジョブの状態
+#[derive(Debug, Clone)]
+pub struct JobResponse {
+    pub job_id: String,
+    pub kind: String,
+    /// ジョブ種別の表示名
+    pub kind_label: String,
+    pub parameters: Vec<(String, String)>,
+    pub status: String,
+    /// 状態の表示名
+    pub status_label: String,
+    /// 終了済み（完了・失敗・取消）か
+    pub finished: bool,
+    pub progress: u8,
+    pub message: Option<String>,
+    pub attempts: u32,
+    pub requested_by: String,
+    /// 登録日時（ISO 8601）
+    pub created_at: String,
+    /// 更新日時（ISO 8601）
+    pub updated_at: String,
+}
+
+impl JobResponse {
+    pub fn from_job(job: &Job) -> Self {
+        Self {
+            job_id: job.job_id().to_string(),
+            kind: job.kind().as_str().to_string(),
+            kind_label: job.kind().label().to_string(),
+            parameters: job.parameters().to_vec(),
+            status: job.status().as_str().to_string(),
+            status_label: job.status().label().to_string(),
+            finished: job.status().is_finished(),
+            progress: job.progress(),
+            message: job.message().map(str::to_string),
+            attempts: job.attempts(),
+            requested_by: job.requested_by().to_string(),
+            created_at: job.created_at().to_rfc3339(),
+            updated_at: job.updated_at().to_rfc3339(),
+        }
+    }
+
+    /// 完了したか
+    pub fn is_completed(&self) -> bool {
+        self.status == "Completed"
+    }
+}
+
+/// 再起動時の復旧結果
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RecoverJobsResponse {
+    /// 待機中として再開するジョブ数（中断から待機へ戻したものを含む）
+    pub resumed: usize,
+    /// 中断により失敗として記録したジョブ数
+    pub failed: usize,
+}
@@ -1,5 +1,9 @@
 // 仕訳照会レスポンスDTO
 
+use std::collections::BTreeMap;
+
+use javelin_domain::financial_close::journal_entry::values::{JournalAction, JournalStatus};
+
 /// 仕訳一覧アイテム
 #[derive(Debug, Clone)]
 pub struct JournalEntryListItem {
@@ -30,6 +34,8 @@ pub struct JournalEntryLineDetail {
     pub account_name: String,
     pub sub_account_code: Option<String>,
     pub department_code: Option<String>,
+    /// 分析軸（種類 → コード）
+    pub dimensions: BTreeMap<String, String>,
     pub amount: f64,
     pub currency: String,
     pub tax_type: String,
@@ -51,4 +57,72 @@ pub struct JournalEntryDetail {
     pub updated_at: Option<String>,
     pub approved_by: Option<String>,
     pub approved_at: Option<String>,
+    /// 現在のステータスで可能な操作
+    pub actions: Vec<JournalActionAffordance>,
+}
+
+/// 仕訳に対する操作の可否
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JournalActionAffordance {
+    pub action: JournalAction,
+    pub label: String,
+    pub allowed: bool,
+    /// 操作できない理由
+    pub reason: Option<String>,
+}
+
+impl JournalActionAffordance {
+    /// ステータス名（JournalStatus::as_str）から各操作の可否を求める
+    pub fn for_status(status: &str) -> Vec<Self> {
+        // 取消・修正の理由は操作の可否に影響しないため空で復元する
+        let status = match status {
+            "Draft" => Some(JournalStatus::Draft),
+            "PendingApproval" => Some(JournalStatus::PendingApproval),
+            "Posted" => Some(JournalStatus::Posted),
+            "Reversed" => {
+                Some(JournalStatus::Reversed { reason: String::new(), original_id: String::new() })
+            }
+            "Corrected" => {
+                Some(JournalStatus::Corrected { reason: String::new(), reversed_id: String::new() })
+            }
+            "Closed" => Some(JournalStatus::Closed),
+            _ => None,
+        };
+
+        JournalAction::ALL
+            .iter()
+            .map(|&action| {
+                let checked = match &status {
+                    Some(status) => status.check_action(action),
+                    None => Err(format!("この仕訳は{}できません", action.display_name())),
+                };
+                Self {
+                    action,
+                    label: action.display_name().to_string(),
+                    allowed: checked.is_ok(),
+                    reason: checked.err(),
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_affordances_for_pending_approval() {
+        let actions = JournalActionAffordance::for_status("PendingApproval");
+        assert_eq!(actions.len(), JournalAction::ALL.len());
+
+        let allowed: Vec<_> = actions.iter().filter(|a| a.allowed).map(|a| a.action).collect();
+        assert_eq!(allowed, vec![JournalAction::Approve, JournalAction::Reject]);
+
+        let edit = actions.iter().find(|a| a.action == JournalAction::Edit).unwrap();
+        assert_eq!(edit.reason.as_deref(), Some("承認待ちの仕訳は編集できません（下書きのみ）"));
+
+        // 削除済など操作対象外のステータスはすべて不可
+        assert!(JournalActionAffordance::for_status("Deleted").iter().all(|a| !a.allowed));
+    }
 }
@@ -1,6 +1,8 @@
 // 仕訳検索結果DTO
 // 検索結果をアダプター層へ転送
 
+use crate::dtos::request::SearchGrouping;
+
 /// 仕訳検索結果DTO
 ///
 /// 検索結果の仕訳リストと総件数を表現する。
@@ -11,20 +13,61 @@ pub struct JournalEntrySearchResultDto {
 
     /// 総件数（ページネーション前の全体件数）
     pub total_count: u32,
+
+    /// 適用したグループ化
+    pub grouping: SearchGrouping,
+
+    /// グループごとの小計（グループ化した場合のみ、グループキー順）
+    pub groups: Vec<JournalEntrySearchGroupDto>,
 }
 
 impl JournalEntrySearchResultDto {
     /// 新しい検索結果DTOを作成
     pub fn new(entries: Vec<JournalEntryItemDto>, total_count: u32) -> Self {
-        Self { entries, total_count }
+        Self { entries, total_count, grouping: SearchGrouping::None, groups: Vec::new() }
     }
 
     /// 空の検索結果を作成
     pub fn empty() -> Self {
-        Self { entries: Vec::new(), total_count: 0 }
+        Self::new(Vec::new(), 0)
+    }
+
+    /// グループごとの小計を設定
+    pub fn with_groups(
+        mut self,
+        grouping: SearchGrouping,
+        groups: Vec<JournalEntrySearchGroupDto>,
+    ) -> Self {
+        self.grouping = grouping;
+        self.groups = groups;
+        self
     }
 }
 
+/// 検索結果のグループ小計DTO
+///
+/// ページネーション前の検索結果全件を対象に集計する。
+#[derive(Debug, Clone, PartialEq)]
+pub struct JournalEntrySearchGroupDto {
+    /// グループキー（勘定科目コード / YYYY-MM / ステータス）
+    pub key: String,
+
+    /// グループ名（勘定科目名。科目以外のグループ化では空）
+    pub name: String,
+
+    /// グループに含まれる仕訳の件数
+    pub entry_count: u32,
+
+    /// 借方合計
+    pub debit_total: f64,
+
+    /// 貸方合計
+    pub credit_total: f64,
+
+    /// 小計に含まれる勘定科目コード（金額マスキングの判定用）
+    pub account_codes: Vec<String>,
+}
+
 /// 仕訳項目DTO
 ///
 /// 検索結果の1件の仕訳を表現する。
@@ -42,7 +85,10 @@ pub struct JournalEntryItemDto {
     /// ステータス
     pub status: String,
 
-    /// 仕訳明細リスト
+    /// 伝票の摘要
+    pub description: Option<String>,
+
+    /// 仕訳明細リスト（摘要は伝票の摘要を引き継いだもの）
     pub lines: Vec<JournalEntryLineItemDto>,
 }
 
@@ -55,7 +101,13 @@ impl JournalEntryItemDto {
         status: String,
         lines: Vec<JournalEntryLineItemDto>,
     ) -> Self {
-        Self { entry_id, entry_number, transaction_date, status, lines }
+        Self { entry_id, entry_number, transaction_date, status, description: None, lines }
+    }
+
+    /// 伝票の摘要を設定
+    pub fn with_description(mut self, description: Option<String>) -> Self {
+        self.description = description;
+        self
     }
 }
 
@@ -81,6 +133,9 @@ pub struct JournalEntryLineItemDto {
 
     /// 摘要
     pub description: Option<String>,
+
+    /// 未解決の注記数
+    pub open_annotations: u32,
 }
 
 impl JournalEntryLineItemDto {
@@ -93,7 +148,26 @@ impl JournalEntryLineItemDto {
         amount: f64,
         description: Option<String>,
     ) -> Self {
-        Self { line_number, side, account_code, account_name, amount, description }
+        Self {
+            line_number,
+            side,
+            account_code,
+            account_name,
+            amount,
+            description,
+            open_annotations: 0,
+        }
+    }
+
+    /// 未解決の注記数を設定
+    pub fn with_open_annotations(mut self, open_annotations: u32) -> Self {
+        self.open_annotations = open_annotations;
+        self
+    }
+
+    /// 未解決の注記が付いているか
+    pub fn is_flagged(&self) -> bool {
+        self.open_annotations > 0
     }
 }
 
@@ -0,0 +1,75 @@
+// JournalImport - 表計算ファイルからの仕訳取込
+
+use std::collections::BTreeMap;
+
+use javelin_domain::masters::ImportField;
+
+/// プレビューに表示するデータ行数
+pub const IMPORT_PREVIEW_ROWS: usize = 5;
+
+/// 取込ファイルのプレビュー
+#[derive(Debug, Clone)]
+pub struct JournalImportPreviewResponse {
+    pub path: String,
+    pub sheet_name: String,
+    pub headers: Vec<String>,
+    /// 先頭のデータ行
+    pub preview_rows: Vec<Vec<String>>,
+    pub total_rows: usize,
+    /// 列の割当（記憶済みのテンプレート、または見出しからの推測）
+    pub columns: BTreeMap<ImportField, usize>,
+    /// 記憶済みのテンプレートを復元したか
+    pub remembered: bool,
+}
+
+/// 取込予定の仕訳
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImportedEntrySummary {
+    pub voucher_number: String,
+    pub transaction_date: String,
+    pub line_count: usize,
+    pub debit_total: f64,
+    pub credit_total: f64,
+}
+
+/// 取込時の行エラー
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportRowError {
+    /// シート上の行番号（1始まり）
+    pub row: usize,
+    pub message: String,
+}
+
+/// 取込内容の検証結果
+#[derive(Debug, Clone, Default)]
+pub struct JournalImportValidationReport {
+    /// 空行を除いたデータ行数
+    pub total_rows: usize,
+    /// 伝票番号ごとの仕訳（ファイルでの出現順）
+    pub entries: Vec<ImportedEntrySummary>,
+    pub errors: Vec<ImportRowError>,
+}
+
+impl JournalImportValidationReport {
+    /// エラーがなく取込可能か
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty() && !self.entries.is_empty()
+    }
+}
+
+/// 取り込んだ仕訳
+#[derive(Debug, Clone)]
+pub struct ImportedEntryDto {
+    pub voucher_number: String,
+    pub entry_id: String,
+}
+
+/// 取込結果
+#[derive(Debug, Clone)]
+pub struct JournalImportResponse {
+    pub report: JournalImportValidationReport,
+    /// 下書きとして起票した仕訳
+    pub imported: Vec<ImportedEntryDto>,
+    /// 反映を保留して取り込んだ場合、取込後にまとめて反映したイベント数
+    pub deferred_projection: Option<u64>,
+}
@@ -0,0 +1,39 @@
+// LedgerAnnotation - 仕訳明細の注記
+
+/// 未解決の注記がある明細の表示
+pub const ANNOTATION_FLAG: &str = "⚑";
+
+/// 明細の注記
+#[derive(Debug, Clone)]
+pub struct LineAnnotationDto {
+    pub annotation_id: String,
+    pub line_number: u32,
+    pub note: String,
+    pub annotated_by: String,
+    pub annotated_at: String,
+    /// 解決内容（未解決の場合はNone）
+    pub resolution: Option<String>,
+    pub resolved_by: Option<String>,
+    pub resolved_at: Option<String>,
+}
+
+impl LineAnnotationDto {
+    pub fn is_open(&self) -> bool {
+        self.resolution.is_none()
+    }
+}
+
+/// 仕訳の注記一覧
+#[derive(Debug, Clone)]
+pub struct EntryAnnotationsResponse {
+    pub entry_id: String,
+    /// 注記順
+    pub annotations: Vec<LineAnnotationDto>,
+}
+
+impl EntryAnnotationsResponse {
+    /// 未解決の注記数
+    pub fn open_count(&self) -> usize {
+        self.annotations.iter().filter(|a| a.is_open()).count()
+    }
+}
@@ -18,4 +18,6 @@ pub struct AccountMasterItem {
     pub name: String,
     /// 科目タイプ
     pub account_type: String,
+    /// 有効か
+    pub is_active: bool,
 }
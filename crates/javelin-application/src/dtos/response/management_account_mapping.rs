@@ -0,0 +1,59 @@
+// ManagementAccountMapping - 管理会計科目マッピング操作レスポンス
+
+use serde::{Deserialize, Serialize};
+
+/// 管理会計科目マッピング取得レスポンス
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LoadManagementAccountMappingResponse {
+    /// 勘定科目ごとの割当状況（勘定科目コード順）
+    pub items: Vec<ManagementAccountMappingItem>,
+}
+
+impl LoadManagementAccountMappingResponse {
+    /// 未割当の勘定科目数
+    pub fn unmapped_count(&self) -> usize {
+        self.items.iter().filter(|item| item.management_account_code.is_none()).count()
+    }
+}
+
+/// 管理会計科目マッピング項目
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ManagementAccountMappingItem {
+    pub account_code: String,
+    pub account_name: String,
+    /// 管理会計科目コード（未割当の場合はNone）
+    pub management_account_code: Option<String>,
+    /// 管理会計科目名（未割当の場合はNone）
+    pub management_account_name: Option<String>,
+}
+
+/// 管理会計科目体系で集計し直した試算表
+///
+/// 金額は元の試算表と同じ通貨建て。未割当の勘定科目は勘定科目のまま1行として残す。
+#[derive(Debug, Clone)]
+pub struct MappedTrialBalanceResponse {
+    pub currency: String,
+    /// 管理会計科目コード順（未割当の勘定科目は末尾に勘定科目コード順）
+    pub lines: Vec<MappedTrialBalanceLineDto>,
+    pub total_debit: f64,
+    pub total_credit: f64,
+    /// 管理会計科目が未割当の勘定科目数
+    pub unmapped_account_count: usize,
+}
+
+/// 管理会計科目体系の試算表明細行
+#[derive(Debug, Clone)]
+pub struct MappedTrialBalanceLineDto {
+    /// 管理会計科目コード（未割当の場合は勘定科目コード）
+    pub account_code: String,
+    /// 管理会計科目名（未割当の場合は勘定科目名）
+    pub account_name: String,
+    /// 集計元の勘定科目コード
+    pub source_account_codes: Vec<String>,
+    /// 管理会計科目が割り当てられているか
+    pub mapped: bool,
+    pub opening_balance: f64,
+    pub debit_amount: f64,
+    pub credit_amount: f64,
+    pub closing_balance: f64,
+}
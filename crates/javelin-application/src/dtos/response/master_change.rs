@@ -0,0 +1,48 @@
+// MasterChange - マスタ変更履歴レスポンス
+
+use chrono::{DateTime, Utc};
+use javelin_domain::masters::{MasterChangeHistory, MasterRecord};
+use serde::{Deserialize, Serialize};
+
+/// マスタ変更履歴の1件
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MasterChangeDto {
+    /// レコード内の連番
+    pub sequence: u64,
+    /// 変更の種類（登録・変更・削除）
+    pub kind_label: String,
+    /// 変更前の内容（登録の場合はNone）
+    pub before: Option<String>,
+    /// 変更後の内容（削除の場合はNone）
+    pub after: Option<String>,
+    pub changed_by: String,
+    pub changed_at: DateTime<Utc>,
+    /// 取り消した変更の連番（取消しによる変更の場合）
+    pub reverts: Option<u64>,
+    /// 現在の内容から取り消せるか
+    pub revertible: bool,
+}
+
+impl MasterChangeDto {
+    /// 変更履歴を新しい順に変換（`current` は現在のマスタの内容）
+    pub fn from_history<T: MasterRecord>(
+        history: &MasterChangeHistory<T>,
+        current: Option<&T>,
+    ) -> Vec<Self> {
+        history
+            .changes()
+            .iter()
+            .rev()
+            .map(|change| Self {
+                sequence: change.sequence(),
+                kind_label: change.kind().label().to_string(),
+                before: change.before().map(MasterRecord::describe),
+                after: change.after().map(MasterRecord::describe),
+                changed_by: change.changed_by().to_string(),
+                changed_at: change.changed_at(),
+                reverts: change.reverts(),
+                revertible: history.is_revertible(change.sequence(), current),
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,29 @@
+// NoteCrossReference - 注記参照操作レスポンス
+
+use serde::{Deserialize, Serialize};
+
+/// 注記参照一覧取得レスポンス
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LoadNoteCrossReferencesResponse {
+    /// 注記（注記番号順）
+    pub sections: Vec<NoteSectionItem>,
+}
+
+/// 注記と、その注記を参照する表示科目・勘定科目
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NoteSectionItem {
+    pub number: u32,
+    pub title: String,
+    pub body: String,
+    pub is_drafted: bool,
+    pub references: Vec<NoteReferenceItem>,
+}
+
+/// 注記参照の項目
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NoteReferenceItem {
+    /// 参照先（表示科目コードまたは勘定科目コード）
+    pub target: String,
+    pub target_label: String,
+    pub required: bool,
+}
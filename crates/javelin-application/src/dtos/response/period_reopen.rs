@@ -0,0 +1,48 @@
+// PeriodReopen - 会計期間の再オープン結果
+
+/// 会計期間の再オープン結果
+#[derive(Debug, Clone)]
+pub struct ReopenClosingPeriodResponse {
+    pub period_id: String,
+    /// 再オープン前の期間ステータス（表示名）
+    pub previous_status: String,
+    pub reopened_by: String,
+    pub approved_by: String,
+    /// RFC 3339形式
+    pub reopened_at: String,
+    /// 締め済から記帳済へ戻した仕訳のID
+    pub reopened_entry_ids: Vec<String>,
+}
+
+/// 再オープン期間中の記帳
+#[derive(Debug, Clone)]
+pub struct ReopenWindowPosting {
+    pub entry_id: String,
+    pub entry_number: Option<String>,
+    /// YYYY-MM-DD形式
+    pub transaction_date: String,
+    /// 記帳の種類（記帳・取消・修正）
+    pub kind: String,
+    pub recorded_by: String,
+    /// RFC 3339形式
+    pub recorded_at: String,
+}
+
+/// 再オープン期間中の記帳レポート
+///
+/// 直近の再オープンから締め直しまで（再オープン中は現在まで）に
+/// 対象期間の仕訳へ行われた記帳を記録順に列挙する。
+#[derive(Debug, Clone)]
+pub struct ReopenWindowReportResponse {
+    pub period_id: String,
+    /// 現在の期間ステータス（表示名）
+    pub status: String,
+    pub reason: String,
+    pub reopened_by: String,
+    pub approved_by: String,
+    /// RFC 3339形式
+    pub reopened_at: String,
+    /// 締め直した日時（RFC 3339形式、再オープン中はNone）
+    pub closed_again_at: Option<String>,
+    pub postings: Vec<ReopenWindowPosting>,
+}
@@ -0,0 +1,21 @@
+// PeriodRollover - 月初の繰越残高の確定結果
+
+/// 月初の繰越残高の確定結果
+#[derive(Debug, Clone)]
+pub struct RollOverPeriodResponse {
+    pub fiscal_year: i32,
+    pub period: u8,
+    /// 繰越残高のある勘定科目数
+    pub account_count: usize,
+    /// 借方残高の合計
+    pub debit_balance_total: f64,
+    /// 貸方残高の合計
+    pub credit_balance_total: f64,
+}
+
+impl RollOverPeriodResponse {
+    /// 借方残高と貸方残高の合計が一致するか
+    pub fn is_balanced(&self) -> bool {
+        (self.debit_balance_total - self.credit_balance_total).abs() < 0.005
+    }
+}
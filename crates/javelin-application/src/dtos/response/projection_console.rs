@@ -0,0 +1,19 @@
+// ProjectionConsole - Projection照会コンソール結果
+
+/// Projectionのレコード（値はJSONとして整形済み）
+#[derive(Debug, Clone)]
+pub struct ProjectionRecordItem {
+    pub key: String,
+    /// 整形済みJSON（JSONでない値はテキストまたはバイト数で表示）
+    pub value: String,
+    pub size: usize,
+}
+
+/// Projection照会結果（1ページ分）
+#[derive(Debug, Clone)]
+pub struct QueryProjectionResponse {
+    pub prefix: String,
+    pub records: Vec<ProjectionRecordItem>,
+    /// 次ページの開始条件（最終ページはNone）
+    pub next_after: Option<String>,
+}
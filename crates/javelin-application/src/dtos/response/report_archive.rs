@@ -0,0 +1,105 @@
+// ReportArchive - 帳票アーカイブレスポンス
+
+use javelin_domain::financial_close::report_archive::{
+    ArchivedReport, ReportLine, ReportLineDifference,
+};
+
+/// 財務諸表の帳票種類
+pub const FINANCIAL_STATEMENTS_REPORT_TYPE: &str = "FS";
+
+/// 試算表の帳票種類
+pub const TRIAL_BALANCE_REPORT_TYPE: &str = "TB";
+
+/// 帳票の表示行
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReportLineDto {
+    pub label: String,
+    pub amount: f64,
+}
+
+impl ReportLineDto {
+    pub fn new(label: impl Into<String>, amount: f64) -> Self {
+        Self { label: label.into(), amount }
+    }
+
+    pub(crate) fn to_domain(&self) -> ReportLine {
+        ReportLine::new(self.label.clone(), self.amount)
+    }
+}
+
+/// アーカイブ済み帳票の一覧項目
+#[derive(Debug, Clone)]
+pub struct ArchivedReportSummary {
+    pub archive_id: String,
+    pub report_type: String,
+    /// 帳票の種類の表示名
+    pub report_label: String,
+    pub fiscal_year: i32,
+    pub period: u8,
+    pub content_hash: String,
+    pub signed_by: String,
+    /// 承認日時（ISO 8601）
+    pub signed_at: String,
+}
+
+impl ArchivedReportSummary {
+    pub fn from_report(report: &ArchivedReport) -> Self {
+        Self {
+            archive_id: report.archive_id().to_string(),
+            report_type: report.report_type().to_string(),
+            report_label: report_type_label(report.report_type()).to_string(),
+            fiscal_year: report.fiscal_year(),
+            period: report.period(),
+            content_hash: report.content_hash().to_string(),
+            signed_by: report.signed_by().to_string(),
+            signed_at: report.signed_at().to_rfc3339(),
+        }
+    }
+}
+
+/// アーカイブ済み帳票（ハッシュ検証結果付き）
+#[derive(Debug, Clone)]
+pub struct ArchivedReportResponse {
+    pub summary: ArchivedReportSummary,
+    pub parameters: Vec<(String, String)>,
+    pub lines: Vec<ReportLineDto>,
+    /// 内容から再計算したハッシュ値が保存時のハッシュ値と一致したか
+    pub hash_verified: bool,
+}
+
+/// 承認時点と再生成時点の差異
+#[derive(Debug, Clone)]
+pub struct ReportLineDifferenceDto {
+    pub label: String,
+    pub archived_amount: Option<f64>,
+    pub current_amount: Option<f64>,
+    pub difference: f64,
+}
+
+impl From<ReportLineDifference> for ReportLineDifferenceDto {
+    fn from(difference: ReportLineDifference) -> Self {
+        Self {
+            difference: difference.difference(),
+            label: difference.label,
+            archived_amount: difference.archived,
+            current_amount: difference.current,
+        }
+    }
+}
+
+/// アーカイブ済み帳票と再生成した数値の比較結果
+#[derive(Debug, Clone)]
+pub struct ReportComparisonResponse {
+    pub archive_id: String,
+    /// 金額が異なる表示科目（一致した場合は空）
+    pub differences: Vec<ReportLineDifferenceDto>,
+}
+
+/// 帳票の種類の表示名
+pub fn report_type_label(report_type: &str) -> &str {
+    match report_type {
+        FINANCIAL_STATEMENTS_REPORT_TYPE => "財務諸表",
+        TRIAL_BALANCE_REPORT_TYPE => "試算表",
+        other => other,
+    }
+}
@@ -0,0 +1,54 @@
+// ReportDelivery - 帳票配信の対象と結果
+
+/// 配信する帳票
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReportDocument {
+    /// 帳票の種類（配信先の設定キー、例: "TB"）
+    pub report_type: String,
+    pub fiscal_year: i32,
+    pub period: u8,
+    pub file_name: String,
+    /// 帳票の内容（UTF-8のCSV）
+    pub content: String,
+}
+
+impl ReportDocument {
+    /// 件名・通知に用いる表題（例: "試算表 2024-03"）
+    pub fn title(&self) -> String {
+        format!(
+            "{} {}-{:02}",
+            super::report_type_label(&self.report_type),
+            self.fiscal_year,
+            self.period
+        )
+    }
+}
+
+/// 配信先ごとの配信結果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReportDeliveryResultDto {
+    /// 配信先（URI形式）
+    pub destination: String,
+    pub delivered: bool,
+    /// 配信結果の詳細（保存先のパス・失敗理由等）
+    pub message: String,
+}
+
+impl ReportDeliveryResultDto {
+    pub fn delivered(destination: impl Into<String>, message: impl Into<String>) -> Self {
+        Self { destination: destination.into(), delivered: true, message: message.into() }
+    }
+
+    pub fn failed(destination: impl Into<String>, message: impl Into<String>) -> Self {
+        Self { destination: destination.into(), delivered: false, message: message.into() }
+    }
+
+    /// 画面表示用の1行
+    pub fn summary(&self) -> String {
+        if self.delivered {
+            format!("配信しました: {} ({})", self.destination, self.message)
+        } else {
+            format!("配信に失敗しました: {} ({})", self.destination, self.message)
+        }
+    }
+}
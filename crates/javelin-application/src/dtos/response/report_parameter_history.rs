@@ -0,0 +1,11 @@
+// ReportParameterHistory - 帳票の最近使った条件レスポンス
+
+use std::collections::BTreeMap;
+
+/// 帳票の最近使った条件
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RecentReportParametersResponse {
+    pub report_id: String,
+    /// 新しい順の実行条件（パラメータ名と入力値）
+    pub recent: Vec<BTreeMap<String, String>>,
+}
@@ -0,0 +1,106 @@
+// SequenceAudit - 番号連続性監査結果
+
+/// 監査対象の番号の種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum NumberKind {
+    /// 記帳時に採番される伝票番号
+    EntryNumber,
+    /// 起票時に採番される証憑番号
+    VoucherNumber,
+}
+
+impl NumberKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::EntryNumber => "伝票番号",
+            Self::VoucherNumber => "証憑番号",
+        }
+    }
+}
+
+/// 監査指摘の種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SequenceFindingKind {
+    /// 欠番
+    Gap,
+    /// 重複
+    Duplicate,
+    /// 採番後の取消（登録失敗による欠番）
+    Voided,
+}
+
+impl SequenceFindingKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Gap => "欠番",
+            Self::Duplicate => "重複",
+            Self::Voided => "取消",
+        }
+    }
+}
+
+/// 指摘箇所の前後の仕訳
+#[derive(Debug, Clone)]
+pub struct SequenceAuditEntry {
+    pub entry_id: String,
+    pub number: String,
+    pub transaction_date: String,
+    pub status: String,
+}
+
+/// 監査指摘
+#[derive(Debug, Clone)]
+pub struct SequenceFinding {
+    pub kind: SequenceFindingKind,
+    pub number_kind: NumberKind,
+    pub fiscal_year: Option<u32>,
+    /// 対象の番号（欠番が連続する場合は範囲）
+    pub subject: String,
+    pub detail: String,
+    /// 欠番の場合は前後の仕訳、重複の場合は同一番号の仕訳（取消の場合は空）
+    pub surrounding: Vec<SequenceAuditEntry>,
+}
+
+/// 番号系列ごとの集計
+#[derive(Debug, Clone)]
+pub struct SequenceSeriesSummary {
+    pub number_kind: NumberKind,
+    pub fiscal_year: Option<u32>,
+    /// 連番部分を除いた番号の接頭辞（例: "V-2024"）
+    pub series: String,
+    pub count: usize,
+    pub first: String,
+    pub last: String,
+    /// 連番形式か（連番形式でない系列は重複のみ検査する）
+    pub sequential: bool,
+}
+
+/// 番号連続性監査結果
+#[derive(Debug, Clone)]
+pub struct SequenceAuditResponse {
+    pub fiscal_year: Option<u32>,
+    pub series: Vec<SequenceSeriesSummary>,
+    pub findings: Vec<SequenceFinding>,
+}
+
+impl SequenceAuditResponse {
+    pub fn gap_count(&self) -> usize {
+        self.findings.iter().filter(|f| f.kind == SequenceFindingKind::Gap).count()
+    }
+
+    pub fn duplicate_count(&self) -> usize {
+        self.findings
+            .iter()
+            .filter(|f| f.kind == SequenceFindingKind::Duplicate)
+            .count()
+    }
+
+    pub fn voided_count(&self) -> usize {
+        self.findings.iter().filter(|f| f.kind == SequenceFindingKind::Voided).count()
+    }
+
+    /// 欠番・重複の指摘があるか（取消番号は説明済みのため含めない）
+    pub fn has_findings(&self) -> bool {
+        self.gap_count() + self.duplicate_count() > 0
+    }
+}
@@ -0,0 +1,34 @@
+// SessionLog - ログインセッションの一覧
+
+/// ログインセッション
+#[derive(Debug, Clone)]
+pub struct SessionDto {
+    pub session_id: String,
+    pub user_id: String,
+    pub started_at: String,
+    /// 状態の表示（ログイン中・ログアウト・強制ログアウト）
+    pub status: String,
+    /// 終了日時（ログイン中の場合はNone）
+    pub closed_at: Option<String>,
+    /// 強制ログアウトした管理者
+    pub forced_by: Option<String>,
+}
+
+/// ログインに失敗した試行
+#[derive(Debug, Clone)]
+pub struct FailedLoginDto {
+    pub user_id: String,
+    pub reason: String,
+    pub failed_at: String,
+}
+
+/// ログインセッションの一覧
+#[derive(Debug, Clone)]
+pub struct SessionOverviewResponse {
+    /// ログイン中のセッション（開始順）
+    pub active_sessions: Vec<SessionDto>,
+    /// 最近のログイン（新しい順）
+    pub recent_logins: Vec<SessionDto>,
+    /// 最近のログイン失敗（新しい順）
+    pub failed_logins: Vec<FailedLoginDto>,
+}
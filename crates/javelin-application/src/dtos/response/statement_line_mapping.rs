@@ -0,0 +1,41 @@
+// StatementLineMapping - 財務諸表表示科目マッピング操作レスポンス
+
+use serde::{Deserialize, Serialize};
+
+/// 表示科目マッピング取得レスポンス
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LoadStatementLineMappingResponse {
+    /// 勘定科目ごとの割当状況（勘定科目コード順）
+    pub items: Vec<StatementLineMappingItem>,
+    /// 割当可能な表示科目（表示順）
+    pub statement_lines: Vec<StatementLineOption>,
+}
+
+impl LoadStatementLineMappingResponse {
+    /// 未割当の勘定科目数
+    pub fn unmapped_count(&self) -> usize {
+        self.items.iter().filter(|item| item.statement_line_code.is_none()).count()
+    }
+}
+
+/// 表示科目マッピング項目
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatementLineMappingItem {
+    pub account_code: String,
+    pub account_name: String,
+    /// 表示科目コード（未割当の場合はNone）
+    pub statement_line_code: Option<String>,
+    /// 表示科目名（未割当の場合はNone）
+    pub statement_line_name: Option<String>,
+    /// 所属する財務諸表（"BS" / "PL"、未割当の場合はNone）
+    pub statement: Option<String>,
+}
+
+/// 表示科目の選択肢
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatementLineOption {
+    pub code: String,
+    pub name: String,
+    /// 所属する財務諸表（"BS" / "PL"）
+    pub statement: String,
+}
@@ -0,0 +1,59 @@
+// SupplierInvoice - 仕入先請求書台帳と支払状況
+
+use chrono::NaiveDate;
+use javelin_domain::financial_close::supplier_invoice::SupplierInvoice;
+
+/// 仕入先請求書レスポンス
+#[derive(Debug, Clone)]
+pub struct SupplierInvoiceResponse {
+    pub invoice_number: String,
+    pub counterparty: String,
+    pub invoice_date: String,
+    pub due_date: String,
+    pub expense_account_code: String,
+    pub currency: String,
+    pub amount: f64,
+    pub tax_amount: f64,
+    /// 請求額（税込）
+    pub total_amount: f64,
+    pub paid_amount: f64,
+    pub outstanding_amount: f64,
+    /// 支払状況（"Unpaid" / "PartiallyPaid" / "Paid"）
+    pub payment_status: String,
+    pub payment_status_label: String,
+    /// 基準日時点で支払期日を過ぎた未払があるか
+    pub overdue: bool,
+    /// 買掛金計上仕訳（起票済みの場合）
+    pub journal_entry_id: Option<String>,
+}
+
+impl SupplierInvoiceResponse {
+    pub fn from_invoice(invoice: &SupplierInvoice, as_of: NaiveDate) -> Self {
+        let status = invoice.payment_status();
+        Self {
+            invoice_number: invoice.invoice_number().to_string(),
+            counterparty: invoice.counterparty().to_string(),
+            invoice_date: invoice.invoice_date().format("%Y-%m-%d").to_string(),
+            due_date: invoice.due_date().format("%Y-%m-%d").to_string(),
+            expense_account_code: invoice.expense_account_code().to_string(),
+            currency: invoice.currency().to_string(),
+            amount: invoice.amount(),
+            tax_amount: invoice.tax_amount(),
+            total_amount: invoice.total_amount(),
+            paid_amount: invoice.paid_amount(),
+            outstanding_amount: invoice.outstanding_amount(),
+            payment_status: status.as_str().to_string(),
+            payment_status_label: status.label().to_string(),
+            overdue: invoice.is_overdue(as_of),
+            journal_entry_id: invoice.journal_entry_id().map(str::to_string),
+        }
+    }
+}
+
+/// 買掛金計上仕訳（下書き）の起票結果
+#[derive(Debug, Clone)]
+pub struct InvoiceJournalDraftResponse {
+    pub entry_id: String,
+    pub voucher_number: String,
+    pub status: String,
+}
@@ -0,0 +1,95 @@
+// SuspenseClearing - 仮勘定の滞留分析・整理結果
+
+/// 経過日数の区分
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum AgingBucket {
+    /// 30日以内
+    Within30Days,
+    /// 31〜60日
+    Within60Days,
+    /// 61〜90日
+    Within90Days,
+    /// 91日以上
+    Over90Days,
+}
+
+impl AgingBucket {
+    pub const ALL: [AgingBucket; 4] =
+        [Self::Within30Days, Self::Within60Days, Self::Within90Days, Self::Over90Days];
+
+    pub fn from_age_days(age_days: i64) -> Self {
+        match age_days {
+            ..=30 => Self::Within30Days,
+            31..=60 => Self::Within60Days,
+            61..=90 => Self::Within90Days,
+            _ => Self::Over90Days,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Within30Days => "30日以内",
+            Self::Within60Days => "31〜60日",
+            Self::Within90Days => "61〜90日",
+            Self::Over90Days => "91日以上",
+        }
+    }
+}
+
+/// 整理仕訳の提案
+///
+/// 仮勘定計上の後に記帳された同額・反対側の明細を整理先とみなし、
+/// 仮勘定から整理先の勘定科目へ振り替える仕訳を提案する。
+#[derive(Debug, Clone)]
+pub struct ClearingSuggestion {
+    /// 整理先の根拠となった仕訳
+    pub matched_entry_id: String,
+    pub matched_entry_number: String,
+    pub matched_line_number: u32,
+    pub matched_transaction_date: String,
+    /// 振替先の勘定科目
+    pub account_code: String,
+    pub description: Option<String>,
+}
+
+/// 滞留中の仮勘定計上
+#[derive(Debug, Clone)]
+pub struct SuspenseAgingItem {
+    pub entry_id: String,
+    pub entry_number: String,
+    pub line_number: u32,
+    pub transaction_date: String,
+    pub account_code: String,
+    pub side: String,
+    pub amount: f64,
+    pub currency: String,
+    pub description: Option<String>,
+    pub age_days: i64,
+    pub bucket: AgingBucket,
+    pub suggestion: Option<ClearingSuggestion>,
+}
+
+/// 経過日数区分ごとの集計
+#[derive(Debug, Clone)]
+pub struct AgingBucketSummary {
+    pub bucket: AgingBucket,
+    pub count: usize,
+    pub amount: f64,
+}
+
+/// 仮勘定滞留分析結果
+#[derive(Debug, Clone)]
+pub struct SuspenseAgingResponse {
+    pub as_of_date: String,
+    /// 経過日数の長い順
+    pub items: Vec<SuspenseAgingItem>,
+    pub buckets: Vec<AgingBucketSummary>,
+}
+
+/// 整理仕訳の起票結果
+#[derive(Debug, Clone)]
+pub struct AcceptClearingSuggestionResponse {
+    pub entry_id: String,
+    pub voucher_number: String,
+    pub status: String,
+}
@@ -0,0 +1,26 @@
+// TablePreference - 一覧テーブル表示設定レスポンス
+
+/// 列の表示設定
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ColumnPreferenceDto {
+    /// テーブル定義上の列番号
+    pub column: usize,
+    pub visible: bool,
+    pub width: u16,
+}
+
+/// 並び替えキー
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TableSortDto {
+    pub column: usize,
+    pub descending: bool,
+}
+
+/// 表示設定取得レスポンス
+#[derive(Debug, Clone)]
+pub struct TablePreferenceResponse {
+    pub table_id: String,
+    /// 表示順の列設定
+    pub columns: Vec<ColumnPreferenceDto>,
+    pub sort: Option<TableSortDto>,
+}
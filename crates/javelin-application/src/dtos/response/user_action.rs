@@ -6,3 +6,45 @@ pub struct RecordUserActionResponse {
     pub action_id: String,
     pub recorded_at: String, // ISO 8601 format
 }
+
+/// 画面の利用状況
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScreenUsageItem {
+    pub screen: String,
+    pub visits: u64,
+    /// 表示した利用者数
+    pub users: usize,
+    /// 表示していた時間の合計（ミリ秒）
+    pub total_duration_ms: u64,
+}
+
+/// 操作の処理時間
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OperationLatencyItem {
+    pub screen: String,
+    pub action: String,
+    pub count: u64,
+    pub average_duration_ms: u64,
+    pub max_duration_ms: u64,
+}
+
+/// 日ごとの利用状況
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DailyActivityItem {
+    pub date: String, // YYYY-MM-DD
+    /// 画面の表示と操作の件数
+    pub actions: u64,
+    pub active_users: usize,
+}
+
+/// 利用状況レポート
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UserActivityReportResponse {
+    pub from: String, // YYYY-MM-DD
+    /// 表示回数の多い順
+    pub screens: Vec<ScreenUsageItem>,
+    /// 最大処理時間の長い順
+    pub slowest_operations: Vec<OperationLatencyItem>,
+    /// 日付順
+    pub daily: Vec<DailyActivityItem>,
+}
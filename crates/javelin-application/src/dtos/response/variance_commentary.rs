@@ -0,0 +1,28 @@
+// VarianceCommentary - 増減コメント
+
+use javelin_domain::financial_close::variance_commentary::VarianceCommentary;
+
+/// 増減コメント
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VarianceCommentaryDto {
+    pub account_code: String,
+    pub fiscal_year: i32,
+    pub period: u8,
+    pub commentary: String,
+    pub written_by: String,
+    /// 記入日時（RFC3339）
+    pub written_at: String,
+}
+
+impl From<&VarianceCommentary> for VarianceCommentaryDto {
+    fn from(commentary: &VarianceCommentary) -> Self {
+        Self {
+            account_code: commentary.account_code().to_string(),
+            fiscal_year: commentary.fiscal_year(),
+            period: commentary.period(),
+            commentary: commentary.commentary().to_string(),
+            written_by: commentary.written_by().to_string(),
+            written_at: commentary.written_at().to_string(),
+        }
+    }
+}
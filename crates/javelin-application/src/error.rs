@@ -14,6 +14,10 @@ pub enum ApplicationError {
     #[error("[A-1003] Validation error: {0}")]
     ValidationError(String),
 
+    /// 項目ごとの検証エラー（画面で該当項目を示すために使う）
+    #[error("[A-1004] Invalid fields: {0:?}")]
+    InvalidFields(Vec<crate::parameter_validation::FieldError>),
+
     #[error("[A-2001] Query execution failed: {0}")]
     QueryExecutionFailed(String),
 
@@ -34,3 +38,113 @@ pub enum ApplicationError {
 }
 
 pub type ApplicationResult<T> = Result<T, ApplicationError>;
+
+/// エラー分類（画面表示と対処方法の決定に使う）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorCategory {
+    /// 入力・業務ルール違反（利用者が修正可能）
+    Validation,
+    /// 対象データが存在しない
+    NotFound,
+    /// 他の操作との競合
+    Conflict,
+    /// 権限不足
+    Permission,
+    /// 保存先（イベントストア・Projection）の障害
+    Storage,
+    /// 想定外の障害
+    System,
+}
+
+impl ErrorCategory {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Validation => "入力エラー",
+            Self::NotFound => "データなし",
+            Self::Conflict => "競合",
+            Self::Permission => "権限エラー",
+            Self::Storage => "保存先エラー",
+            Self::System => "システムエラー",
+        }
+    }
+
+    /// 利用者向けの対処方法
+    pub fn remediation(&self) -> &'static str {
+        match self {
+            Self::Validation => "入力内容を確認して再実行してください",
+            Self::NotFound => "対象が削除・変更されていないか確認し、画面を再読込してください",
+            Self::Conflict => "最新の状態を再読込してから再実行してください",
+            Self::Permission => "会計方針の管理者に権限を確認してください",
+            Self::Storage => {
+                "データディレクトリの空き容量と権限を確認し、解決しない場合は問い合わせIDを添えて連絡してください"
+            }
+            Self::System => "問い合わせIDを添えてサポートへ連絡してください",
+        }
+    }
+}
+
+impl ApplicationError {
+    /// エラーコード（ドメインエラーはドメインのコードを優先する）
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::UseCaseExecutionFailed(_) => "A-1001",
+            Self::ValidationFailed(_) => "A-1002",
+            Self::ValidationError(_) => "A-1003",
+            Self::InvalidFields(_) => "A-1004",
+            Self::QueryExecutionFailed(_) => "A-2001",
+            Self::ProjectionBuildFailed(_) => "A-3001",
+            Self::EventStoreError(_) => "A-4001",
+            Self::ProjectionDatabaseError(_) => "A-4002",
+            Self::DomainError(error) => error.code(),
+            Self::Unknown(_) => "A-9999",
+        }
+    }
+
+    pub fn category(&self) -> ErrorCategory {
+        use javelin_domain::error::DomainError;
+
+        match self {
+            Self::ValidationFailed(_) | Self::ValidationError(_) | Self::InvalidFields(_) => {
+                ErrorCategory::Validation
+            }
+            Self::ProjectionBuildFailed(_)
+            | Self::EventStoreError(_)
+            | Self::ProjectionDatabaseError(_) => ErrorCategory::Storage,
+            Self::UseCaseExecutionFailed(_) | Self::QueryExecutionFailed(_) | Self::Unknown(_) => {
+                ErrorCategory::System
+            }
+            Self::DomainError(error) => match error {
+                DomainError::EntityNotFound(_) | DomainError::NotFound(_) => {
+                    ErrorCategory::NotFound
+                }
+                DomainError::VersionConflict => ErrorCategory::Conflict,
+                DomainError::PermissionDenied(_) => ErrorCategory::Permission,
+                DomainError::SerializationFailed(_) | DomainError::RepositoryError(_) => {
+                    ErrorCategory::Storage
+                }
+                DomainError::Unknown(_) => ErrorCategory::System,
+                _ => ErrorCategory::Validation,
+            },
+        }
+    }
+
+    /// 利用者向けのメッセージ（エラーコードを含まない）
+    pub fn user_message(&self) -> String {
+        match self {
+            Self::ValidationFailed(messages) => messages.join(" / "),
+            Self::InvalidFields(errors) => errors
+                .iter()
+                .map(|error| error.message.as_str())
+                .collect::<Vec<_>>()
+                .join(" / "),
+            Self::DomainError(error) => error.user_message(),
+            Self::UseCaseExecutionFailed(message)
+            | Self::ValidationError(message)
+            | Self::QueryExecutionFailed(message)
+            | Self::ProjectionBuildFailed(message)
+            | Self::EventStoreError(message)
+            | Self::ProjectionDatabaseError(message)
+            | Self::Unknown(message) => message.clone(),
+        }
+    }
+}
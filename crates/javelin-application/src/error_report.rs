@@ -0,0 +1,135 @@
+// ErrorReport - 層をまたいで画面まで伝搬する構造化エラー
+// 責務: 分類・コード・利用者向けメッセージ・対処方法・問い合わせIDの保持
+
+use std::fmt;
+
+use crate::error::{ApplicationError, ErrorCategory};
+
+/// 構造化エラー
+///
+/// 画面には利用者向けのメッセージと対処方法を表示し、
+/// 問い合わせIDでログに記録したエラーの連鎖全体と突き合わせる。
+#[derive(Debug, Clone, PartialEq)]
+pub struct ErrorReport {
+    pub category: ErrorCategory,
+    /// エラーコード（例: A-1003, D-5001）
+    pub code: String,
+    /// 利用者向けのメッセージ
+    pub message: String,
+    /// 対処方法
+    pub remediation: String,
+    /// 問い合わせID（ログとの突き合わせ用）
+    pub correlation_id: String,
+    /// 原因の連鎖（外側から順、ログ出力用）
+    pub chain: Vec<String>,
+}
+
+impl ErrorReport {
+    pub fn new(
+        category: ErrorCategory,
+        code: impl Into<String>,
+        message: impl Into<String>,
+    ) -> Self {
+        let message = message.into();
+        Self {
+            category,
+            code: code.into(),
+            remediation: category.remediation().to_string(),
+            correlation_id: new_correlation_id(),
+            chain: vec![message.clone()],
+            message,
+        }
+    }
+
+    /// 任意のエラーから原因の連鎖を収集して作成
+    pub fn from_error(
+        category: ErrorCategory,
+        code: impl Into<String>,
+        message: impl Into<String>,
+        error: &(dyn std::error::Error + 'static),
+    ) -> Self {
+        let mut report = Self::new(category, code, message);
+        report.chain = error_chain(error);
+        report
+    }
+
+    /// 1行表示（イベントログ等の文字列しか扱えない表示先向け）
+    pub fn summary(&self) -> String {
+        format!(
+            "[{}] {}（{} / 問い合わせID: {}）",
+            self.code, self.message, self.remediation, self.correlation_id
+        )
+    }
+}
+
+impl From<&ApplicationError> for ErrorReport {
+    fn from(error: &ApplicationError) -> Self {
+        Self::from_error(error.category(), error.code(), error.user_message(), error)
+    }
+}
+
+impl From<ApplicationError> for ErrorReport {
+    fn from(error: ApplicationError) -> Self {
+        Self::from(&error)
+    }
+}
+
+impl fmt::Display for ErrorReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.summary())
+    }
+}
+
+/// エラーと原因（source）を外側から順に収集
+pub fn error_chain(error: &(dyn std::error::Error + 'static)) -> Vec<String> {
+    let mut chain = vec![error.to_string()];
+    let mut source = error.source();
+    while let Some(cause) = source {
+        chain.push(cause.to_string());
+        source = cause.source();
+    }
+    chain
+}
+
+/// 問い合わせID（口頭でも伝えやすいよう8桁に短縮）
+fn new_correlation_id() -> String {
+    uuid::Uuid::new_v4().simple().to_string()[..8].to_uppercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use javelin_domain::error::DomainError;
+
+    use super::*;
+
+    #[test]
+    fn test_report_from_domain_error_keeps_domain_code_and_chain() {
+        let error = ApplicationError::DomainError(DomainError::PermissionDenied(
+            "管理者のみ可能です".to_string(),
+        ));
+
+        let report = ErrorReport::from(&error);
+
+        assert_eq!(report.category, ErrorCategory::Permission);
+        assert_eq!(report.code, "D-5001");
+        assert_eq!(report.message, "管理者のみ可能です");
+        assert_eq!(report.remediation, ErrorCategory::Permission.remediation());
+        assert_eq!(report.correlation_id.len(), 8);
+        assert_eq!(report.chain.len(), 2);
+        assert!(report.chain[0].starts_with("[A-5001]"));
+        assert!(report.chain[1].starts_with("[D-5001]"));
+        assert!(report.summary().contains(&report.correlation_id));
+    }
+
+    #[test]
+    fn test_correlation_ids_are_unique_per_report() {
+        let error = ApplicationError::ValidationError("会計期間が不正です".to_string());
+
+        let first = ErrorReport::from(&error);
+        let second = ErrorReport::from(&error);
+
+        assert_eq!(first.category, ErrorCategory::Validation);
+        assert_eq!(first.code, "A-1003");
+        assert_ne!(first.correlation_id, second.correlation_id);
+    }
+}
@@ -0,0 +1,31 @@
+// TimeOrderedIdGenerator - 生成順に並ぶ集約IDの採番
+// 責務: UUIDv7（先頭48ビットがミリ秒単位の生成時刻）による集約IDの生成
+
+use javelin_domain::id_generator::AggregateIdGenerator;
+use uuid::Uuid;
+
+/// UUIDv7で集約IDを生成するAggregateIdGenerator
+///
+/// 複数の端末・プロセスで採番しても衝突せず、同一プロセス内では同じミリ秒内の
+/// 生成でも生成順に並ぶ（文字列表現も同じ順に並ぶ）。
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TimeOrderedIdGenerator;
+
+impl AggregateIdGenerator for TimeOrderedIdGenerator {
+    fn next_id(&self) -> String {
+        Uuid::now_v7().to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ids_are_unique_and_sort_by_creation_time() {
+        let generator = TimeOrderedIdGenerator;
+        let ids: Vec<String> = (0..1_000).map(|_| generator.next_id()).collect();
+        assert!(ids.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(Uuid::parse_str(&ids[0]).unwrap().get_version_num(), 7);
+    }
+}
@@ -0,0 +1,17 @@
+// LoadCalendarMaster - カレンダーマスタ取得
+// 責務: 休日と会計期間情報の取得
+
+use crate::{
+    dtos::{request::LoadCalendarMasterRequest, response::LoadCalendarMasterResponse},
+    error::ApplicationResult,
+};
+
+/// カレンダーマスタ取得Input Port
+#[allow(async_fn_in_trait)]
+pub trait LoadCalendarMasterInputPort: Send + Sync {
+    /// カレンダーマスタを取得
+    async fn execute(
+        &self,
+        request: LoadCalendarMasterRequest,
+    ) -> ApplicationResult<LoadCalendarMasterResponse>;
+}
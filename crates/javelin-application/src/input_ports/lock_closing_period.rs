@@ -2,15 +2,25 @@
 // 目的: 当月の会計データを確定し、改竄防止
 
 use crate::{
-    dtos::{LockClosingPeriodRequest, LockClosingPeriodResponse},
+    dtos::{
+        LockClosingPeriodRequest, LockClosingPeriodResponse, request::ValidateClosingLockRequest,
+        response::ClosingLockValidationReport,
+    },
     error::ApplicationResult,
 };
 
 /// 締日固定ユースケース
 #[allow(async_fn_in_trait)]
 pub trait LockClosingPeriodUseCase: Send + Sync {
+    /// 事前検証を行い、すべて成功した場合（または管理者が理由を付けて上書きした場合）に固定
     async fn execute(
         &self,
         request: LockClosingPeriodRequest,
     ) -> ApplicationResult<LockClosingPeriodResponse>;
+
+    /// 固定せずに事前検証のみを行う
+    async fn validate(
+        &self,
+        request: ValidateClosingLockRequest,
+    ) -> ApplicationResult<ClosingLockValidationReport>;
 }
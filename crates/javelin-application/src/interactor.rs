@@ -3,39 +3,99 @@
 // 利用対象: Entity / ValueObject / DomainService / RepositoryTrait
 
 pub mod account_master_interactor;
+pub mod accounting_policy_interactor;
 pub mod application_settings_interactor;
+pub mod audit_export_interactor;
+pub mod authentication_interactor;
 pub mod closing;
+pub mod closing_timetable_interactor;
 pub mod company_master_interactor;
+pub mod consistency_check_interactor;
+pub mod dimension_master_interactor;
+pub mod entry_link_interactor;
+pub mod export_protection_interactor;
+pub mod financial_instrument_interactor;
+pub mod initial_setup_interactor;
+pub mod integrity_audit_interactor;
+pub mod inventory_worksheet_interactor;
+pub mod job_queue_interactor;
 pub mod journal_entry;
+pub mod journal_import_interactor;
+pub mod ledger_annotation_interactor;
+pub mod management_account_mapping_interactor;
 pub mod master_data;
+pub mod note_cross_reference_interactor;
+pub mod projection_console_interactor;
+pub mod report_archive_interactor;
+pub mod report_parameter_history_interactor;
+pub mod sequence_audit_interactor;
+pub mod session_log_interactor;
+pub mod statement_line_mapping_interactor;
 pub mod subsidiary_account_master_interactor;
+pub mod supplier_invoice_interactor;
+pub mod suspense_clearing_interactor;
+pub mod table_preference_interactor;
+pub mod user_account_interactor;
+pub mod user_activity_report_interactor;
 
 pub use account_master_interactor::{
     AccountMasterInteractor, GetAccountMastersQuery, RegisterAccountMasterRequest,
     UpdateAccountMasterRequest,
 };
+pub use accounting_policy_interactor::AccountingPolicyInteractor;
 pub use application_settings_interactor::{
-    ApplicationSettingsInteractor, GetApplicationSettingsQuery, UpdateApplicationSettingsRequest,
+    ApplicationSettingsInteractor, GetApplicationSettingsQuery, ToggleClosingLockCheckRequest,
+    UpdateApplicationSettingsRequest, UpdateApprovalSlaRequest, UpdateBatchNotificationRequest,
+    UpdateDeletedEntryRetentionRequest, UpdateExportProtectionRequest, UpdateReportDeliveryRequest,
 };
+pub use audit_export_interactor::AuditExportInteractor;
+pub use authentication_interactor::AuthenticationInteractor;
 pub use closing::{
-    AdjustAccountsInteractor, ApplyIfrsValuationInteractor, ConsolidateLedgerInteractor,
+    AccountReconciliationInteractor, AdjustAccountsInteractor, AnalyzeAccountActivityInteractor,
+    AnalyzeBalancesInteractor, ApplyIfrsValuationInteractor, ConsolidateLedgerInteractor,
     GenerateFinancialStatementsInteractor, GenerateNoteDraftInteractor,
     GenerateTrialBalanceInteractor, LockClosingPeriodInteractor, PrepareClosingInteractor,
+    ReopenClosingPeriodInteractor, RollOverPeriodInteractor, VarianceCommentaryInteractor,
 };
+pub use closing_timetable_interactor::ClosingTimetableInteractor;
 pub use company_master_interactor::{
     CompanyMasterInteractor, GetCompanyMastersQuery, RegisterCompanyMasterRequest,
     UpdateCompanyMasterRequest,
 };
+pub use consistency_check_interactor::ConsistencyCheckInteractor;
+pub use dimension_master_interactor::DimensionMasterInteractor;
+pub use entry_link_interactor::EntryLinkInteractor;
+pub use export_protection_interactor::{ExportProtectionInteractor, MIN_EXPORT_PASSWORD_LENGTH};
+pub use financial_instrument_interactor::FinancialInstrumentInteractor;
+pub use initial_setup_interactor::InitialSetupInteractor;
+pub use integrity_audit_interactor::IntegrityAuditInteractor;
+pub use inventory_worksheet_interactor::InventoryWorksheetInteractor;
+pub use job_queue_interactor::JobQueueInteractor;
 pub use journal_entry::{
     ApproveJournalEntryInteractor, CancelJournalEntryInteractor, CorrectJournalEntryInteractor,
     CreateAdditionalEntryInteractor, CreateReclassificationEntryInteractor,
     CreateReplacementEntryInteractor, CreateReversalEntryInteractor,
-    DeleteDraftJournalEntryInteractor, RegisterJournalEntryInteractor,
+    DeleteDraftJournalEntryInteractor, JournalEntryChainValidator, RegisterJournalEntryInteractor,
     RejectJournalEntryInteractor, ReverseJournalEntryInteractor, SubmitForApprovalInteractor,
     UpdateDraftJournalEntryInteractor,
 };
+pub use journal_import_interactor::JournalImportInteractor;
+pub use ledger_annotation_interactor::LedgerAnnotationInteractor;
+pub use management_account_mapping_interactor::ManagementAccountMappingInteractor;
 pub use master_data::{LoadAccountMasterInteractor, RecordUserActionInteractor};
+pub use note_cross_reference_interactor::NoteCrossReferenceInteractor;
+pub use projection_console_interactor::ProjectionConsoleInteractor;
+pub use report_archive_interactor::ReportArchiveInteractor;
+pub use report_parameter_history_interactor::ReportParameterHistoryInteractor;
+pub use sequence_audit_interactor::SequenceAuditInteractor;
+pub use session_log_interactor::SessionLogInteractor;
+pub use statement_line_mapping_interactor::StatementLineMappingInteractor;
 pub use subsidiary_account_master_interactor::SubsidiaryAccountMasterInteractor;
+pub use supplier_invoice_interactor::SupplierInvoiceInteractor;
+pub use suspense_clearing_interactor::SuspenseClearingInteractor;
+pub use table_preference_interactor::TablePreferenceInteractor;
+pub use user_account_interactor::UserAccountInteractor;
+pub use user_activity_report_interactor::UserActivityReportInteractor;
 
 #[cfg(test)]
 mod interactor_property_tests;
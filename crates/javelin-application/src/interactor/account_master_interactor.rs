@@ -1,13 +1,20 @@
 // AccountMasterInteractor - 勘定科目マスタ操作のユースケース
+//
+// 登録・更新・削除は変更イベントとして記録し、マスタには変更後のイメージを反映する。
+// 変更の取消しは逆向きの変更イベントを記録して行う。
 
 use std::sync::Arc;
 
 use javelin_domain::{
-    masters::{AccountCode, AccountMaster, AccountName, AccountType},
+    masters::{AccountCode, AccountMaster, AccountName, AccountType, MasterChangeHistory},
     repositories::AccountMasterRepository,
 };
 
-use crate::error::ApplicationResult;
+use crate::{
+    dtos::{request::RevertMasterChangeRequest, response::MasterChangeDto},
+    error::ApplicationResult,
+    query_service::AccountMasterCache,
+};
 
 /// 勘定科目マスタ取得クエリ
 #[derive(Debug, Clone)]
@@ -19,6 +26,7 @@ pub struct RegisterAccountMasterRequest {
     pub code: String,
     pub name: String,
     pub account_type: AccountType,
+    pub changed_by: String,
 }
 
 /// 勘定科目マスタ更新リクエスト
@@ -27,6 +35,7 @@ pub struct UpdateAccountMasterRequest {
     pub code: String,
     pub name: String,
     pub is_active: bool,
+    pub changed_by: String,
 }
 
 /// 勘定科目マスタInteractor
@@ -35,6 +44,8 @@ where
     R: AccountMasterRepository,
 {
     repository: Arc<R>,
+    /// 編集時に無効化する勘定科目マスタのキャッシュ
+    cache: Option<Arc<AccountMasterCache>>,
 }
 
 impl<R> AccountMasterInteractor<R>
@@ -42,7 +53,23 @@ where
     R: AccountMasterRepository,
 {
     pub fn new(repository: Arc<R>) -> Self {
-        Self { repository }
+        Self { repository, cache: None }
+    }
+
+    /// 編集時に無効化する勘定科目マスタのキャッシュを設定
+    pub fn with_cache(mut self, cache: Arc<AccountMasterCache>) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// 編集が成功したらキャッシュを無効化
+    fn invalidate_cache(&self, result: ApplicationResult<()>) -> ApplicationResult<()> {
+        if result.is_ok()
+            && let Some(cache) = &self.cache
+        {
+            cache.invalidate();
+        }
+        result
     }
 
     /// 全勘定科目マスタを取得
@@ -71,12 +98,9 @@ where
             )));
         }
 
-        let account_master = AccountMaster::new(code, name, request.account_type, true);
+        let account_master = AccountMaster::new(code.clone(), name, request.account_type, true);
 
-        self.repository
-            .save(&account_master)
-            .await
-            .map_err(|e| crate::error::ApplicationError::UseCaseExecutionFailed(e.to_string()))
+        self.apply_change(&code, None, Some(account_master), &request.changed_by).await
     }
 
     /// 勘定科目マスタを更新
@@ -94,23 +118,81 @@ where
         let name = AccountName::new(request.name)
             .map_err(|e| crate::error::ApplicationError::ValidationError(e.to_string()))?;
 
-        let updated =
-            AccountMaster::new(code, name, account_master.account_type(), request.is_active);
+        let updated = AccountMaster::new(
+            code.clone(),
+            name,
+            account_master.account_type(),
+            request.is_active,
+        );
 
-        self.repository
-            .save(&updated)
+        self.apply_change(&code, Some(account_master), Some(updated), &request.changed_by)
             .await
-            .map_err(|e| crate::error::ApplicationError::UseCaseExecutionFailed(e.to_string()))
     }
 
     /// 勘定科目マスタを削除
-    pub async fn delete(&self, code: String) -> ApplicationResult<()> {
+    pub async fn delete(&self, code: String, changed_by: &str) -> ApplicationResult<()> {
         let code = AccountCode::new(code)
             .map_err(|e| crate::error::ApplicationError::ValidationError(e.to_string()))?;
 
-        self.repository
-            .delete(&code)
+        let Some(account_master) = self.repository.find_by_code(&code).await? else {
+            return Ok(());
+        };
+        self.apply_change(&code, Some(account_master), None, changed_by).await
+    }
+
+    /// 勘定科目マスタの変更履歴を取得（新しい順）
+    pub async fn history(&self, code: String) -> ApplicationResult<Vec<MasterChangeDto>> {
+        let code = AccountCode::new(code)
+            .map_err(|e| crate::error::ApplicationError::ValidationError(e.to_string()))?;
+
+        let current = self.repository.find_by_code(&code).await?;
+        let history = self.load_history(&code).await?;
+        Ok(MasterChangeDto::from_history(&history, current.as_ref()))
+    }
+
+    /// 勘定科目マスタの変更を取り消す（逆向きの変更を記録する）
+    pub async fn revert(&self, request: RevertMasterChangeRequest) -> ApplicationResult<()> {
+        let code = AccountCode::new(request.code)
+            .map_err(|e| crate::error::ApplicationError::ValidationError(e.to_string()))?;
+
+        let current = self.repository.find_by_code(&code).await?;
+        let history = self.load_history(&code).await?;
+        let change = history.revert(request.sequence, current.as_ref(), &request.changed_by)?;
+
+        let result = self
+            .repository
+            .apply_change(&change)
+            .await
+            .map_err(|e| crate::error::ApplicationError::UseCaseExecutionFailed(e.to_string()));
+        self.invalidate_cache(result)
+    }
+
+    async fn load_history(
+        &self,
+        code: &AccountCode,
+    ) -> ApplicationResult<MasterChangeHistory<AccountMaster>> {
+        let changes = self.repository.find_changes(code).await?;
+        Ok(MasterChangeHistory::new(code.value(), changes))
+    }
+
+    /// 変更イベントを記録してマスタへ反映（変更がない場合は何もしない）
+    async fn apply_change(
+        &self,
+        code: &AccountCode,
+        before: Option<AccountMaster>,
+        after: Option<AccountMaster>,
+        changed_by: &str,
+    ) -> ApplicationResult<()> {
+        let history = self.load_history(code).await?;
+        let Some(change) = history.record(before, after, changed_by)? else {
+            return Ok(());
+        };
+
+        let result = self
+            .repository
+            .apply_change(&change)
             .await
-            .map_err(|e| crate::error::ApplicationError::UseCaseExecutionFailed(e.to_string()))
+            .map_err(|e| crate::error::ApplicationError::UseCaseExecutionFailed(e.to_string()));
+        self.invalidate_cache(result)
     }
 }
@@ -0,0 +1,352 @@
+// AccountingPolicyInteractor - 会計方針設定のユースケース
+// 責務: 端数処理・負数表示・金額マスキング方針の参照と管理者による変更（変更イベントの記録）
+
+use std::sync::Arc;
+
+use javelin_domain::{
+    error::DomainError,
+    financial_close::journal_entry::values::{Currency, TaxType},
+    masters::{
+        AccountingPolicy, AccountingPolicyChanged, AmountMaskingPolicy, CurrencyRounding,
+        DecimalPlaces, NegativeNumberPresentation, RoundingMode, TaxRounding,
+    },
+    repositories::AccountingPolicyRepository,
+};
+
+use crate::{
+    dtos::{
+        request::{
+            UpdateAmountMaskingRulesRequest, UpdateCurrencyRoundingRequest,
+            UpdateNegativePresentationRequest, UpdateTaxRoundingRequest, UpdateUserRolesRequest,
+        },
+        response::{
+            AccountingPolicyChangeItem, AmountFormat, AmountMask, LoadAccountingPolicyResponse,
+            PolicyOption, TaxRoundingItem, UserRolesItem,
+        },
+    },
+    error::ApplicationResult,
+};
+
+/// 会計方針設定のInteractor
+pub struct AccountingPolicyInteractor<R>
+where
+    R: AccountingPolicyRepository,
+{
+    repository: Arc<R>,
+}
+
+impl<R> AccountingPolicyInteractor<R>
+where
+    R: AccountingPolicyRepository,
+{
+    pub fn new(repository: Arc<R>) -> Self {
+        Self { repository }
+    }
+
+    /// 現在の会計方針（税額計算・財務諸表生成で参照する）
+    pub async fn policy(&self) -> ApplicationResult<AccountingPolicy> {
+        Ok(self.repository.load().await?)
+    }
+
+    /// 金額の出力書式（帳票・エクスポートで参照する）
+    pub async fn amount_format(&self) -> ApplicationResult<AmountFormat> {
+        Ok(AmountFormat::from_policy(&self.repository.load().await?))
+    }
+
+    /// ユーザの金額マスキング（照会・帳票・エクスポートで参照する）
+    pub async fn amount_mask(&self, user_id: &str) -> ApplicationResult<AmountMask> {
+        Ok(AmountMask::for_user(&self.repository.load().await?, user_id))
+    }
+
+    /// 会計方針と変更履歴を取得
+    pub async fn load(&self, user_id: &str) -> ApplicationResult<LoadAccountingPolicyResponse> {
+        let policy = self.repository.load().await?;
+        let mut changes = self.repository.find_changes().await?;
+        changes.reverse();
+
+        let option = |code: &str, name: &str| PolicyOption {
+            code: code.to_string(),
+            name: name.to_string(),
+        };
+        let presentation = policy.negative_presentation();
+
+        Ok(LoadAccountingPolicyResponse {
+            currency_roundings: AmountFormat::from_policy(&policy).currency_roundings,
+            tax_roundings: policy
+                .tax_roundings()
+                .iter()
+                .map(|r| TaxRoundingItem {
+                    tax_type: r.tax_type().as_str().to_string(),
+                    tax_type_name: r.tax_type().display_name().to_string(),
+                    rounding_mode: r.mode().code().to_string(),
+                    rounding_mode_name: r.mode().display_name().to_string(),
+                })
+                .collect(),
+            negative_presentation: option(presentation.code(), presentation.display_name()),
+            rounding_modes: RoundingMode::ALL
+                .iter()
+                .map(|mode| option(mode.code(), mode.display_name()))
+                .collect(),
+            negative_presentations: NegativeNumberPresentation::ALL
+                .iter()
+                .map(|p| option(p.code(), p.display_name()))
+                .collect(),
+            amount_masking_rules: policy
+                .amount_masking()
+                .rules()
+                .iter()
+                .map(|rule| rule.to_spec())
+                .collect(),
+            user_roles: policy
+                .amount_masking()
+                .user_roles()
+                .iter()
+                .map(|(user_id, roles)| UserRolesItem {
+                    user_id: user_id.clone(),
+                    roles: roles.iter().cloned().collect(),
+                })
+                .collect(),
+            is_administrator: policy.is_administrator(user_id),
+            changes: changes
+                .into_iter()
+                .map(|change| AccountingPolicyChangeItem {
+                    setting: change.setting,
+                    before: change.before,
+                    after: change.after,
+                    changed_by: change.changed_by,
+                    changed_at: change.changed_at.to_rfc3339(),
+                })
+                .collect(),
+        })
+    }
+
+    /// 通貨別端数処理を変更（変更がなければfalse）
+    pub async fn update_currency_rounding(
+        &self,
+        request: UpdateCurrencyRoundingRequest,
+    ) -> ApplicationResult<bool> {
+        let currency =
+            request.currency.parse::<Currency>().map_err(DomainError::ValidationError)?;
+        let rounding = CurrencyRounding::new(
+            currency,
+            DecimalPlaces::new(request.decimal_places)?,
+            RoundingMode::from_code(&request.rounding_mode)?,
+        )?;
+
+        let mut policy = self.repository.load().await?;
+        let change = policy.change_currency_rounding(&request.user_id, rounding)?;
+        self.save(&policy, change).await
+    }
+
+    /// 税区分別税額端数処理を変更（変更がなければfalse）
+    pub async fn update_tax_rounding(
+        &self,
+        request: UpdateTaxRoundingRequest,
+    ) -> ApplicationResult<bool> {
+        let tax_type = request.tax_type.parse::<TaxType>().map_err(DomainError::ValidationError)?;
+        let rounding = TaxRounding::new(tax_type, RoundingMode::from_code(&request.rounding_mode)?);
+
+        let mut policy = self.repository.load().await?;
+        let change = policy.change_tax_rounding(&request.user_id, rounding)?;
+        self.save(&policy, change).await
+    }
+
+    /// 負数表示方法を変更（変更がなければfalse）
+    pub async fn update_negative_presentation(
+        &self,
+        request: UpdateNegativePresentationRequest,
+    ) -> ApplicationResult<bool> {
+        let presentation = NegativeNumberPresentation::from_code(&request.negative_presentation)?;
+
+        let mut policy = self.repository.load().await?;
+        let change = policy.change_negative_presentation(&request.user_id, presentation)?;
+        self.save(&policy, change).await
+    }
+
+    /// 金額マスキング規則を変更（変更がなければfalse）
+    pub async fn update_amount_masking_rules(
+        &self,
+        request: UpdateAmountMaskingRulesRequest,
+    ) -> ApplicationResult<bool> {
+        let rules = AmountMaskingPolicy::parse_rules(&request.rules)?;
+
+        let mut policy = self.repository.load().await?;
+        let change = policy.change_amount_masking_rules(&request.user_id, rules)?;
+        self.save(&policy, change).await
+    }
+
+    /// ユーザのロール割当を変更（変更がなければfalse）
+    pub async fn update_user_roles(
+        &self,
+        request: UpdateUserRolesRequest,
+    ) -> ApplicationResult<bool> {
+        let target_user_id = request.target_user_id.trim();
+        if target_user_id.is_empty() {
+            return Err(DomainError::ValidationError(
+                "ロールを割り当てるユーザを指定してください".to_string(),
+            )
+            .into());
+        }
+        let roles = request
+            .roles
+            .iter()
+            .map(|role| role.trim())
+            .filter(|role| !role.is_empty())
+            .map(String::from)
+            .collect();
+
+        let mut policy = self.repository.load().await?;
+        let change = policy.change_user_roles(&request.user_id, target_user_id, roles)?;
+        self.save(&policy, change).await
+    }
+
+    async fn save(
+        &self,
+        policy: &AccountingPolicy,
+        change: Option<AccountingPolicyChanged>,
+    ) -> ApplicationResult<bool> {
+        match change {
+            Some(change) => {
+                self.repository.save(policy, &change).await?;
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use javelin_domain::{error::DomainResult, masters::DEFAULT_POLICY_ADMINISTRATOR};
+
+    use super::*;
+    use crate::error::ApplicationError;
+
+    #[derive(Default)]
+    struct MockRepository {
+        policy: Mutex<Option<AccountingPolicy>>,
+        changes: Mutex<Vec<AccountingPolicyChanged>>,
+    }
+
+    impl AccountingPolicyRepository for MockRepository {
+        async fn load(&self) -> DomainResult<AccountingPolicy> {
+            Ok(self.policy.lock().unwrap().clone().unwrap_or_default())
+        }
+
+        async fn save(
+            &self,
+            policy: &AccountingPolicy,
+            change: &AccountingPolicyChanged,
+        ) -> DomainResult<()> {
+            *self.policy.lock().unwrap() = Some(policy.clone());
+            self.changes.lock().unwrap().push(change.clone());
+            Ok(())
+        }
+
+        async fn find_changes(&self) -> DomainResult<Vec<AccountingPolicyChanged>> {
+            Ok(self.changes.lock().unwrap().clone())
+        }
+    }
+
+    fn interactor() -> AccountingPolicyInteractor<MockRepository> {
+        AccountingPolicyInteractor::new(Arc::new(MockRepository::default()))
+    }
+
+    #[tokio::test]
+    async fn test_administrator_changes_are_recorded() {
+        let interactor = interactor();
+
+        let changed = interactor
+            .update_currency_rounding(UpdateCurrencyRoundingRequest {
+                user_id: DEFAULT_POLICY_ADMINISTRATOR.to_string(),
+                currency: "JPY".to_string(),
+                decimal_places: 0,
+                rounding_mode: "Truncate".to_string(),
+            })
+            .await
+            .unwrap();
+        assert!(changed);
+
+        let changed = interactor
+            .update_negative_presentation(UpdateNegativePresentationRequest {
+                user_id: DEFAULT_POLICY_ADMINISTRATOR.to_string(),
+                negative_presentation: "Triangle".to_string(),
+            })
+            .await
+            .unwrap();
+        assert!(changed);
+
+        let response = interactor.load(DEFAULT_POLICY_ADMINISTRATOR).await.unwrap();
+        assert!(response.is_administrator);
+        assert_eq!(response.negative_presentation.code, "Triangle");
+        assert_eq!(response.changes.len(), 2);
+        // 新しい順
+        assert_eq!(response.changes[0].setting, "負数表示");
+
+        let format = interactor.amount_format().await.unwrap();
+        assert_eq!(format.format(-1234.9, "JPY"), "△1,234");
+        assert_eq!(format.format(-1234.5, "USD"), "△1,234.50");
+    }
+
+    #[tokio::test]
+    async fn test_non_administrator_is_rejected() {
+        let interactor = interactor();
+
+        let result = interactor
+            .update_tax_rounding(UpdateTaxRoundingRequest {
+                user_id: "clerk".to_string(),
+                tax_type: "Taxable".to_string(),
+                rounding_mode: "HalfUp".to_string(),
+            })
+            .await;
+        assert!(matches!(
+            result,
+            Err(ApplicationError::DomainError(DomainError::PermissionDenied(_)))
+        ));
+
+        let response = interactor.load("clerk").await.unwrap();
+        assert!(!response.is_administrator);
+        assert!(response.changes.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_amount_mask_follows_user_roles() {
+        let interactor = interactor();
+
+        interactor
+            .update_amount_masking_rules(UpdateAmountMaskingRulesRequest {
+                user_id: DEFAULT_POLICY_ADMINISTRATOR.to_string(),
+                rules: "5100-5199:payroll".to_string(),
+            })
+            .await
+            .unwrap();
+        interactor
+            .update_user_roles(UpdateUserRolesRequest {
+                user_id: DEFAULT_POLICY_ADMINISTRATOR.to_string(),
+                target_user_id: "hr_manager".to_string(),
+                roles: vec!["payroll".to_string()],
+            })
+            .await
+            .unwrap();
+
+        let clerk = interactor.amount_mask("clerk").await.unwrap();
+        assert!(clerk.is_masked("5150"));
+        assert!(!clerk.is_masked("5200"));
+        assert!(!interactor.amount_mask("hr_manager").await.unwrap().is_masked("5150"));
+
+        let response = interactor.load("clerk").await.unwrap();
+        assert_eq!(response.amount_masking_rules, vec!["5100-5199:payroll".to_string()]);
+        assert_eq!(response.user_roles[0].user_id, "hr_manager");
+
+        // 管理者以外は規則を変更できない
+        let result = interactor
+            .update_amount_masking_rules(UpdateAmountMaskingRulesRequest {
+                user_id: "clerk".to_string(),
+                rules: String::new(),
+            })
+            .await;
+        assert!(result.is_err());
+    }
+}
@@ -4,8 +4,10 @@ use std::sync::Arc;
 
 use javelin_domain::{
     masters::{
-        ApplicationSettings, BackupRetentionDays, ClosingDay, CompanyCode, DateFormat,
-        DecimalPlaces, FiscalYearStartMonth, Language,
+        ApplicationSettings, ApprovalSlaSettings, BackupRetentionDays, BatchNotificationSettings,
+        ClosingDay, ClosingLockCheck, ClosingLockValidationSettings, CompanyCode, DateFormat,
+        DecimalPlaces, DeletedEntryRetentionSettings, ExportProtectionSettings,
+        FiscalYearStartMonth, Language, ReportDeliverySettings, ReportDestination,
     },
     repositories::ApplicationSettingsRepository,
 };
@@ -23,10 +25,58 @@ pub struct UpdateApplicationSettingsRequest {
     pub language: String,
     pub decimal_places: u8,
     pub date_format: String,
+    pub batch_notification_bell: bool,
+    pub batch_notification_desktop: bool,
     pub fiscal_year_start_month: u8,
     pub closing_day: u8,
     pub auto_backup_enabled: bool,
     pub backup_retention_days: u32,
+    pub approval_sla_warning_hours: u32,
+    pub approval_sla_breach_hours: u32,
+}
+
+/// バッチ完了通知設定の更新リクエスト
+#[derive(Debug, Clone)]
+pub struct UpdateBatchNotificationRequest {
+    pub bell_enabled: bool,
+    pub desktop_enabled: bool,
+}
+
+/// 承認SLA設定の更新リクエスト
+#[derive(Debug, Clone)]
+pub struct UpdateApprovalSlaRequest {
+    pub warning_hours: u32,
+    pub breach_hours: u32,
+}
+
+/// 出力ファイル保護設定の更新リクエスト
+#[derive(Debug, Clone)]
+pub struct UpdateExportProtectionRequest {
+    pub encryption_enabled: bool,
+    pub signature_enabled: bool,
+}
+
+/// 削除済み仕訳の保持設定の更新リクエスト
+#[derive(Debug, Clone)]
+pub struct UpdateDeletedEntryRetentionRequest {
+    pub retain_enabled: bool,
+    pub purge_after_months: u32,
+}
+
+/// 締日固定の事前検証項目の切替リクエスト
+#[derive(Debug, Clone)]
+pub struct ToggleClosingLockCheckRequest {
+    /// 検証項目コード（例: "suspense_cleared"）
+    pub check: String,
+}
+
+/// 帳票配信先の更新リクエスト
+#[derive(Debug, Clone)]
+pub struct UpdateReportDeliveryRequest {
+    /// 帳票の種類（例: "TB"）
+    pub report_type: String,
+    /// 配信先（URI形式、空の場合は配信しない）
+    pub destinations: Vec<String>,
 }
 
 /// アプリケーション設定Interactor
@@ -83,8 +133,13 @@ where
             .map_err(|e| crate::error::ApplicationError::ValidationError(e.to_string()))?;
         let backup_retention_days = BackupRetentionDays::new(request.backup_retention_days)
             .map_err(|e| crate::error::ApplicationError::ValidationError(e.to_string()))?;
+        let approval_sla = ApprovalSlaSettings::new(
+            request.approval_sla_warning_hours,
+            request.approval_sla_breach_hours,
+        )
+        .map_err(|e| crate::error::ApplicationError::ValidationError(e.to_string()))?;
 
-        let settings = ApplicationSettings::new(
+        let mut settings = ApplicationSettings::new(
             default_company_code,
             language,
             decimal_places,
@@ -94,10 +149,149 @@ where
             request.auto_backup_enabled,
             backup_retention_days,
         );
+        settings.update_batch_notification(BatchNotificationSettings::new(
+            request.batch_notification_bell,
+            request.batch_notification_desktop,
+        ));
+        settings.update_approval_sla(approval_sla);
+
+        // 基準通貨・帳票配信先・出力ファイル保護・削除済み仕訳の保持・締日固定の事前検証・
+        // 為替換算調整勘定は個別に設定するため、保存済みの設定を引き継ぐ
+        if let Some(current) = self
+            .repository
+            .find()
+            .await
+            .map_err(|e| crate::error::ApplicationError::QueryExecutionFailed(e.to_string()))?
+        {
+            settings.update_base_currency(current.base_currency().clone());
+            settings.update_report_delivery(current.report_delivery().clone());
+            settings.update_export_protection(current.export_protection());
+            settings.update_deleted_entry_retention(current.deleted_entry_retention());
+            settings.update_closing_lock_validation(current.closing_lock_validation().clone());
+            settings.update_translation_adjustment_account(
+                current.translation_adjustment_account().clone(),
+            );
+        }
 
         self.repository
             .save(&settings)
             .await
             .map_err(|e| crate::error::ApplicationError::UseCaseExecutionFailed(e.to_string()))
     }
+
+    /// バッチ完了通知設定のみを更新
+    pub async fn update_batch_notification(
+        &self,
+        request: UpdateBatchNotificationRequest,
+    ) -> ApplicationResult<BatchNotificationSettings> {
+        let mut settings = self.get(GetApplicationSettingsQuery).await?;
+        let batch_notification =
+            BatchNotificationSettings::new(request.bell_enabled, request.desktop_enabled);
+        settings.update_batch_notification(batch_notification);
+
+        self.repository
+            .save(&settings)
+            .await
+            .map_err(|e| crate::error::ApplicationError::UseCaseExecutionFailed(e.to_string()))?;
+
+        Ok(batch_notification)
+    }
+
+    /// 承認SLA設定のみを更新
+    pub async fn update_approval_sla(
+        &self,
+        request: UpdateApprovalSlaRequest,
+    ) -> ApplicationResult<ApprovalSlaSettings> {
+        let approval_sla = ApprovalSlaSettings::new(request.warning_hours, request.breach_hours)
+            .map_err(|e| crate::error::ApplicationError::ValidationError(e.to_string()))?;
+        let mut settings = self.get(GetApplicationSettingsQuery).await?;
+        settings.update_approval_sla(approval_sla);
+
+        self.repository
+            .save(&settings)
+            .await
+            .map_err(|e| crate::error::ApplicationError::UseCaseExecutionFailed(e.to_string()))?;
+
+        Ok(approval_sla)
+    }
+
+    /// 出力ファイル保護設定のみを更新
+    pub async fn update_export_protection(
+        &self,
+        request: UpdateExportProtectionRequest,
+    ) -> ApplicationResult<ExportProtectionSettings> {
+        let mut settings = self.get(GetApplicationSettingsQuery).await?;
+        let export_protection =
+            ExportProtectionSettings::new(request.encryption_enabled, request.signature_enabled);
+        settings.update_export_protection(export_protection);
+
+        self.repository
+            .save(&settings)
+            .await
+            .map_err(|e| crate::error::ApplicationError::UseCaseExecutionFailed(e.to_string()))?;
+
+        Ok(export_protection)
+    }
+
+    /// 削除済み仕訳の保持設定のみを更新
+    pub async fn update_deleted_entry_retention(
+        &self,
+        request: UpdateDeletedEntryRetentionRequest,
+    ) -> ApplicationResult<DeletedEntryRetentionSettings> {
+        let retention =
+            DeletedEntryRetentionSettings::new(request.retain_enabled, request.purge_after_months)
+                .map_err(|e| crate::error::ApplicationError::ValidationError(e.to_string()))?;
+        let mut settings = self.get(GetApplicationSettingsQuery).await?;
+        settings.update_deleted_entry_retention(retention);
+
+        self.repository
+            .save(&settings)
+            .await
+            .map_err(|e| crate::error::ApplicationError::UseCaseExecutionFailed(e.to_string()))?;
+
+        Ok(retention)
+    }
+
+    /// 締日固定の事前検証項目の有効・無効を切り替える
+    pub async fn toggle_closing_lock_check(
+        &self,
+        request: ToggleClosingLockCheckRequest,
+    ) -> ApplicationResult<ClosingLockValidationSettings> {
+        let check = ClosingLockCheck::from_code(&request.check)
+            .map_err(|e| crate::error::ApplicationError::ValidationError(e.to_string()))?;
+        let mut settings = self.get(GetApplicationSettingsQuery).await?;
+        let validation = settings.closing_lock_validation().toggled(check);
+        settings.update_closing_lock_validation(validation.clone());
+
+        self.repository
+            .save(&settings)
+            .await
+            .map_err(|e| crate::error::ApplicationError::UseCaseExecutionFailed(e.to_string()))?;
+
+        Ok(validation)
+    }
+
+    /// 帳票の種類の配信先のみを更新
+    pub async fn update_report_delivery(
+        &self,
+        request: UpdateReportDeliveryRequest,
+    ) -> ApplicationResult<ReportDeliverySettings> {
+        let destinations = request
+            .destinations
+            .iter()
+            .map(|uri| ReportDestination::parse(uri))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| crate::error::ApplicationError::ValidationError(e.to_string()))?;
+        let mut settings = self.get(GetApplicationSettingsQuery).await?;
+        let mut report_delivery = settings.report_delivery().clone();
+        report_delivery.set_destinations(request.report_type, destinations);
+        settings.update_report_delivery(report_delivery.clone());
+
+        self.repository
+            .save(&settings)
+            .await
+            .map_err(|e| crate::error::ApplicationError::UseCaseExecutionFailed(e.to_string()))?;
+
+        Ok(report_delivery)
+    }
 }
@@ -0,0 +1,618 @@
+// AuditExportInteractor - 監査用帳簿出力のユースケース
+// 責務: 電子帳簿保存法向けの仕訳帳・総勘定元帳・索引ファイルの作成
+
+use std::{collections::HashMap, sync::Arc};
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use javelin_domain::financial_close::report_archive::ReportDigester;
+
+use crate::{
+    dtos::{
+        request::AuditExportRequest,
+        response::{AuditExportFile, AuditExportResponse},
+    },
+    error::{ApplicationError, ApplicationResult},
+    query_service::{AccountMaster, AccountType, AuditExportQueryService, AuditJournalEntry},
+};
+
+/// 仕訳帳の列（固定）
+const JOURNAL_BOOK_COLUMNS: &[&str] = &[
+    "取引日付",
+    "伝票番号",
+    "証憑番号",
+    "行番号",
+    "貸借区分",
+    "勘定科目コード",
+    "勘定科目名",
+    "補助科目コード",
+    "部門コード",
+    "借方金額",
+    "貸方金額",
+    "通貨",
+    "税区分",
+    "税額",
+    "摘要",
+    "状態",
+    "起票者",
+    "起票日時",
+    "最終更新者",
+    "最終更新日時",
+    "承認申請者",
+    "承認申請日時",
+    "記帳承認者",
+    "記帳日時",
+    "仕訳ID",
+];
+
+/// 総勘定元帳の列（固定）
+const GENERAL_LEDGER_COLUMNS: &[&str] = &[
+    "勘定科目コード",
+    "勘定科目名",
+    "取引日付",
+    "伝票番号",
+    "証憑番号",
+    "行番号",
+    "相手勘定科目",
+    "摘要",
+    "借方金額",
+    "貸方金額",
+    "残高",
+    "起票者",
+    "記帳承認者",
+    "記帳日時",
+    "仕訳ID",
+];
+
+/// 索引ファイルの列（固定）
+const INDEX_COLUMNS: &[&str] = &[
+    "ファイル名",
+    "帳簿種別",
+    "対象年度",
+    "対象期間開始",
+    "対象期間終了",
+    "レコード件数",
+    "SHA-256",
+    "作成日時",
+    "作成者",
+];
+
+/// 相手勘定科目が複数ある場合の表示
+const MULTIPLE_COUNTER_ACCOUNTS: &str = "諸口";
+
+/// 監査用帳簿出力のInteractor
+///
+/// 対象年度に記帳された仕訳から、監査人が求める固定列の仕訳帳と総勘定元帳を作成する。
+/// 年度は取引日付の暦年とする（伝票番号の採番範囲と同じ）。
+/// 各帳簿には起票・承認の利用者と日時を含め、索引ファイルに件数とハッシュ値を記録して
+/// 出力後の改ざんを検出できるようにする。
+pub struct AuditExportInteractor<Q, D>
+where
+    Q: AuditExportQueryService,
+    D: ReportDigester,
+{
+    query_service: Arc<Q>,
+    digester: Arc<D>,
+}
+
+impl<Q, D> AuditExportInteractor<Q, D>
+where
+    Q: AuditExportQueryService,
+    D: ReportDigester,
+{
+    pub fn new(query_service: Arc<Q>, digester: Arc<D>) -> Self {
+        Self { query_service, digester }
+    }
+
+    pub async fn execute(
+        &self,
+        request: AuditExportRequest,
+    ) -> ApplicationResult<AuditExportResponse> {
+        if !(2000..=2100).contains(&request.fiscal_year) {
+            return Err(ApplicationError::ValidationError(format!(
+                "会計年度が不正です: {}",
+                request.fiscal_year
+            )));
+        }
+
+        let entries = self.query_service.load_posted_entries().await?;
+        let accounts = self.query_service.load_accounts().await?;
+        let exported_at = Utc::now();
+
+        // 翌年度以降の仕訳は対象外、前年度以前の仕訳は前期繰越の算出に使う
+        let year_prefix = format!("{}-", request.fiscal_year);
+        let next_year_prefix = format!("{}-", request.fiscal_year + 1);
+        let (mut current, prior): (Vec<_>, Vec<_>) = entries
+            .into_iter()
+            .filter(|entry| entry.transaction_date < next_year_prefix)
+            .partition(|entry| entry.transaction_date.starts_with(&year_prefix));
+        current.sort_by(|a, b| {
+            (&a.transaction_date, &a.entry_number).cmp(&(&b.transaction_date, &b.entry_number))
+        });
+
+        let accounts: HashMap<&str, &AccountMaster> =
+            accounts.iter().map(|account| (account.code.as_str(), account)).collect();
+
+        let books = vec![
+            self.file(
+                format!("journal_book_{}.csv", request.fiscal_year),
+                "仕訳帳",
+                journal_book(&current, &accounts),
+            ),
+            self.file(
+                format!("general_ledger_{}.csv", request.fiscal_year),
+                "総勘定元帳",
+                general_ledger(request.fiscal_year, &current, &prior, &accounts),
+            ),
+        ];
+        let index = self.file(
+            format!("index_{}.csv", request.fiscal_year),
+            "索引",
+            index(request.fiscal_year, &books, &request.exported_by, exported_at),
+        );
+
+        Ok(AuditExportResponse {
+            fiscal_year: request.fiscal_year,
+            exported_by: request.exported_by,
+            exported_at,
+            entry_count: current.len(),
+            books,
+            index,
+        })
+    }
+
+    fn file(&self, file_name: String, book: &str, rows: Vec<Vec<String>>) -> AuditExportFile {
+        let content: String = rows.iter().map(|row| csv_row(row)).collect();
+        AuditExportFile {
+            file_name,
+            book: book.to_string(),
+            digest: self.digester.digest(&content),
+            record_count: rows.len() - 1,
+            content,
+        }
+    }
+}
+
+/// 仕訳帳（取引日付・伝票番号・行番号順に明細1行ずつ）
+fn journal_book(
+    entries: &[AuditJournalEntry],
+    accounts: &HashMap<&str, &AccountMaster>,
+) -> Vec<Vec<String>> {
+    let mut rows = vec![columns(JOURNAL_BOOK_COLUMNS)];
+    for entry in entries {
+        let (updated_by, updated_at) = actor_columns(entry.updated.as_ref());
+        let (requested_by, requested_at) = actor_columns(entry.approval_requested.as_ref());
+        for line in &entry.lines {
+            let (debit, credit) = debit_credit_columns(line.is_debit(), line.amount);
+            rows.push(vec![
+                entry.transaction_date.clone(),
+                entry.entry_number.clone(),
+                entry.voucher_number.clone().unwrap_or_default(),
+                line.line_number.to_string(),
+                if line.is_debit() { "借方" } else { "貸方" }.to_string(),
+                line.account_code.clone(),
+                account_name(accounts, &line.account_code),
+                line.sub_account_code.clone().unwrap_or_default(),
+                line.department_code.clone().unwrap_or_default(),
+                debit,
+                credit,
+                line.currency.clone(),
+                line.tax_type.clone(),
+                format_amount(line.tax_amount),
+                line.description.clone().unwrap_or_default(),
+                status_label(&entry.status).to_string(),
+                entry.created_by.clone(),
+                format_timestamp(&entry.created_at),
+                updated_by.clone(),
+                updated_at.clone(),
+                requested_by.clone(),
+                requested_at.clone(),
+                entry.posted_by.clone(),
+                format_timestamp(&entry.posted_at),
+                entry.entry_id.clone(),
+            ]);
+        }
+    }
+    rows
+}
+
+/// 総勘定元帳（勘定科目コード順、科目ごとに前期繰越と取引日付順の明細）
+///
+/// 前期繰越は資産・負債・純資産の科目のみ、前年度以前の記帳から算出する。
+/// 残高は科目の正常残高の側（資産・費用は借方、それ以外は貸方）を正とする。
+fn general_ledger(
+    fiscal_year: u32,
+    current: &[AuditJournalEntry],
+    prior: &[AuditJournalEntry],
+    accounts: &HashMap<&str, &AccountMaster>,
+) -> Vec<Vec<String>> {
+    let mut opening: HashMap<&str, f64> = HashMap::new();
+    for entry in prior {
+        for line in &entry.lines {
+            if carries_forward(accounts, &line.account_code) {
+                *opening.entry(line.account_code.as_str()).or_default() +=
+                    signed_amount(accounts, &line.account_code, line.is_debit(), line.amount);
+            }
+        }
+    }
+
+    let mut account_codes: Vec<&str> = current
+        .iter()
+        .flat_map(|entry| entry.lines.iter().map(|line| line.account_code.as_str()))
+        .chain(opening.iter().filter(|(_, balance)| balance.abs() >= 0.005).map(|(c, _)| *c))
+        .collect();
+    account_codes.sort_unstable();
+    account_codes.dedup();
+
+    let mut rows = vec![columns(GENERAL_LEDGER_COLUMNS)];
+    for account_code in account_codes {
+        let name = account_name(accounts, account_code);
+        let mut balance = opening.get(account_code).copied().unwrap_or_default();
+        if carries_forward(accounts, account_code) {
+            let mut row = vec![String::new(); GENERAL_LEDGER_COLUMNS.len()];
+            row[0] = account_code.to_string();
+            row[1] = name.clone();
+            row[2] = format!("{}-01-01", fiscal_year);
+            row[7] = "前期繰越".to_string();
+            row[10] = format_amount(balance);
+            rows.push(row);
+        }
+
+        for entry in current {
+            for line in entry.lines.iter().filter(|line| line.account_code == account_code) {
+                balance += signed_amount(accounts, account_code, line.is_debit(), line.amount);
+                let (debit, credit) = debit_credit_columns(line.is_debit(), line.amount);
+                rows.push(vec![
+                    account_code.to_string(),
+                    name.clone(),
+                    entry.transaction_date.clone(),
+                    entry.entry_number.clone(),
+                    entry.voucher_number.clone().unwrap_or_default(),
+                    line.line_number.to_string(),
+                    counter_account(entry, line.is_debit(), accounts),
+                    line.description.clone().unwrap_or_default(),
+                    debit,
+                    credit,
+                    format_amount(balance),
+                    entry.created_by.clone(),
+                    entry.posted_by.clone(),
+                    format_timestamp(&entry.posted_at),
+                    entry.entry_id.clone(),
+                ]);
+            }
+        }
+    }
+    rows
+}
+
+/// 索引ファイル（帳簿ファイルごとの対象期間・件数・ハッシュ値・作成者）
+fn index(
+    fiscal_year: u32,
+    books: &[AuditExportFile],
+    exported_by: &str,
+    exported_at: DateTime<Utc>,
+) -> Vec<Vec<String>> {
+    let mut rows = vec![columns(INDEX_COLUMNS)];
+    for book in books {
+        rows.push(vec![
+            book.file_name.clone(),
+            book.book.clone(),
+            fiscal_year.to_string(),
+            format!("{}-01-01", fiscal_year),
+            format!("{}-12-31", fiscal_year),
+            book.record_count.to_string(),
+            book.digest.clone(),
+            format_timestamp(&exported_at),
+            exported_by.to_string(),
+        ]);
+    }
+    rows
+}
+
+/// 相手勘定科目（反対側の科目が1つならその科目、複数なら諸口）
+fn counter_account(
+    entry: &AuditJournalEntry,
+    is_debit: bool,
+    accounts: &HashMap<&str, &AccountMaster>,
+) -> String {
+    let mut counter: Vec<&str> = entry
+        .lines
+        .iter()
+        .filter(|line| line.is_debit() != is_debit)
+        .map(|line| line.account_code.as_str())
+        .collect();
+    counter.sort_unstable();
+    counter.dedup();
+
+    match counter.as_slice() {
+        [] => String::new(),
+        [code] => format!("{} {}", code, account_name(accounts, code)).trim_end().to_string(),
+        _ => MULTIPLE_COUNTER_ACCOUNTS.to_string(),
+    }
+}
+
+fn account_name(accounts: &HashMap<&str, &AccountMaster>, account_code: &str) -> String {
+    accounts
+        .get(account_code)
+        .map(|account| account.name.clone())
+        .unwrap_or_default()
+}
+
+/// 前期繰越を行う科目か（資産・負債・純資産）
+fn carries_forward(accounts: &HashMap<&str, &AccountMaster>, account_code: &str) -> bool {
+    accounts.get(account_code).is_some_and(|account| {
+        matches!(
+            account.account_type,
+            AccountType::Asset | AccountType::Liability | AccountType::Equity
+        )
+    })
+}
+
+/// 正常残高の側を正とした金額
+fn signed_amount(
+    accounts: &HashMap<&str, &AccountMaster>,
+    account_code: &str,
+    is_debit: bool,
+    amount: f64,
+) -> f64 {
+    let debit_normal = accounts.get(account_code).is_none_or(|account| {
+        matches!(account.account_type, AccountType::Asset | AccountType::Expense)
+    });
+    if is_debit == debit_normal {
+        amount
+    } else {
+        -amount
+    }
+}
+
+fn status_label(status: &str) -> &str {
+    match status {
+        "Posted" => "記帳済",
+        "Reversed" => "取消済",
+        "Corrected" => "修正済",
+        "Closed" => "締め済",
+        other => other,
+    }
+}
+
+fn columns(names: &[&str]) -> Vec<String> {
+    names.iter().map(|name| name.to_string()).collect()
+}
+
+fn actor_columns(actor: Option<&(String, DateTime<Utc>)>) -> (String, String) {
+    actor
+        .map(|(user_id, at)| (user_id.clone(), format_timestamp(at)))
+        .unwrap_or_default()
+}
+
+fn debit_credit_columns(is_debit: bool, amount: f64) -> (String, String) {
+    if is_debit {
+        (format_amount(amount), String::new())
+    } else {
+        (String::new(), format_amount(amount))
+    }
+}
+
+/// 金額（端数がなければ整数、あれば小数点以下2桁）
+fn format_amount(amount: f64) -> String {
+    if amount.fract().abs() < 0.005 {
+        format!("{:.0}", amount)
+    } else {
+        format!("{:.2}", amount)
+    }
+}
+
+/// タイムスタンプ（UTC、秒単位のRFC 3339形式）
+fn format_timestamp(at: &DateTime<Utc>) -> String {
+    at.to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+/// CSVの1行（区切り文字・引用符・改行を含む項目は引用符で囲む）
+pub(crate) fn csv_row(fields: &[String]) -> String {
+    let mut row = fields
+        .iter()
+        .map(|field| {
+            if field.contains([',', '"', '\n', '\r']) {
+                format!("\"{}\"", field.replace('"', "\"\""))
+            } else {
+                field.clone()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",");
+    row.push_str("\r\n");
+    row
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+    use crate::query_service::AuditJournalLine;
+
+    struct StubDigester;
+
+    impl ReportDigester for StubDigester {
+        fn digest(&self, payload: &str) -> String {
+            format!("len:{}", payload.len())
+        }
+    }
+
+    struct StubQueryService {
+        entries: Vec<AuditJournalEntry>,
+    }
+
+    impl AuditExportQueryService for StubQueryService {
+        async fn load_posted_entries(&self) -> ApplicationResult<Vec<AuditJournalEntry>> {
+            Ok(self.entries.clone())
+        }
+
+        async fn load_accounts(&self) -> ApplicationResult<Vec<AccountMaster>> {
+            Ok(vec![
+                account("1100", "現金", AccountType::Asset),
+                account("1200", "売掛金", AccountType::Asset),
+                account("4000", "売上", AccountType::Revenue),
+                account("2000", "買掛金", AccountType::Liability),
+            ])
+        }
+    }
+
+    fn account(code: &str, name: &str, account_type: AccountType) -> AccountMaster {
+        AccountMaster {
+            code: code.to_string(),
+            name: name.to_string(),
+            account_type,
+            is_active: true,
+        }
+    }
+
+    fn line(line_number: u32, side: &str, account_code: &str, amount: f64) -> AuditJournalLine {
+        AuditJournalLine {
+            line_number,
+            side: side.to_string(),
+            account_code: account_code.to_string(),
+            sub_account_code: None,
+            department_code: None,
+            amount,
+            currency: "JPY".to_string(),
+            tax_type: "NonTaxable".to_string(),
+            tax_amount: 0.0,
+            description: None,
+        }
+    }
+
+    fn entry(
+        entry_id: &str,
+        date: &str,
+        entry_number: &str,
+        lines: Vec<AuditJournalLine>,
+    ) -> AuditJournalEntry {
+        let at = Utc.with_ymd_and_hms(2024, 4, 1, 9, 0, 0).unwrap();
+        AuditJournalEntry {
+            entry_id: entry_id.to_string(),
+            transaction_date: date.to_string(),
+            entry_number: entry_number.to_string(),
+            voucher_number: Some(format!("V-{}", entry_id)),
+            status: "Posted".to_string(),
+            created_by: "clerk".to_string(),
+            created_at: at,
+            updated: None,
+            approval_requested: Some(("clerk".to_string(), at)),
+            posted_by: "manager".to_string(),
+            posted_at: at,
+            lines,
+        }
+    }
+
+    async fn run(entries: Vec<AuditJournalEntry>) -> AuditExportResponse {
+        let interactor = AuditExportInteractor::new(
+            Arc::new(StubQueryService { entries }),
+            Arc::new(StubDigester),
+        );
+        interactor
+            .execute(AuditExportRequest { fiscal_year: 2024, exported_by: "auditor".to_string() })
+            .await
+            .unwrap()
+    }
+
+    fn sample_entries() -> Vec<AuditJournalEntry> {
+        vec![
+            entry(
+                "e0",
+                "2023-12-20",
+                "EN-0001-2023-000001",
+                vec![line(1, "Debit", "1100", 1000.0), line(2, "Credit", "4000", 1000.0)],
+            ),
+            entry(
+                "e2",
+                "2024-05-10",
+                "EN-0001-2024-000002",
+                vec![
+                    line(1, "Debit", "1100", 300.0),
+                    line(2, "Debit", "1200", 200.0),
+                    line(3, "Credit", "4000", 500.0),
+                ],
+            ),
+            entry(
+                "e1",
+                "2024-04-01",
+                "EN-0001-2024-000001",
+                vec![line(1, "Debit", "1200", 800.0), line(2, "Credit", "4000", 800.0)],
+            ),
+            entry(
+                "e9",
+                "2025-01-05",
+                "EN-0001-2025-000001",
+                vec![line(1, "Debit", "1100", 50.0), line(2, "Credit", "4000", 50.0)],
+            ),
+        ]
+    }
+
+    #[tokio::test]
+    async fn test_journal_book_lists_lines_of_the_fiscal_year_with_audit_metadata() {
+        let mut entries = sample_entries();
+        entries[2].lines[0].description = Some("売上, 4月分".to_string());
+        let response = run(entries).await;
+
+        assert_eq!(response.entry_count, 2);
+        let journal_book = &response.books[0];
+        assert_eq!(journal_book.file_name, "journal_book_2024.csv");
+        assert_eq!(journal_book.record_count, 5);
+
+        let lines: Vec<&str> = journal_book.content.lines().collect();
+        assert!(lines[0].starts_with("取引日付,伝票番号,証憑番号,行番号,貸借区分"));
+        assert!(lines[1].starts_with("2024-04-01,EN-0001-2024-000001,V-e1,1,借方,1200,売掛金"));
+        assert!(lines[1].contains("\"売上, 4月分\",記帳済,clerk,2024-04-01T09:00:00Z"));
+        assert!(lines[1].ends_with("manager,2024-04-01T09:00:00Z,e1"));
+        assert!(lines[3].starts_with("2024-05-10,EN-0001-2024-000002"));
+    }
+
+    #[tokio::test]
+    async fn test_general_ledger_carries_forward_balance_sheet_accounts_only() {
+        let response = run(sample_entries()).await;
+
+        let general_ledger = &response.books[1];
+        let rows: Vec<Vec<&str>> =
+            general_ledger.content.lines().map(|line| line.split(',').collect()).collect();
+        let cash: Vec<&Vec<&str>> = rows.iter().filter(|row| row[0] == "1100").collect();
+        assert_eq!(cash[0][7], "前期繰越");
+        assert_eq!(cash[0][10], "1000");
+        assert_eq!(cash[1][6], "4000 売上");
+        assert_eq!(cash[1][10], "1300");
+
+        // 損益科目は前期繰越を行わず、貸方を正とする
+        let sales: Vec<&Vec<&str>> = rows.iter().filter(|row| row[0] == "4000").collect();
+        assert_eq!(sales.len(), 2);
+        assert_eq!(sales[1][10], "1300");
+        assert_eq!(sales[1][6], MULTIPLE_COUNTER_ACCOUNTS);
+    }
+
+    #[tokio::test]
+    async fn test_index_records_counts_and_digests_of_each_book() {
+        let response = run(sample_entries()).await;
+
+        assert_eq!(response.index.file_name, "index_2024.csv");
+        assert_eq!(response.files().count(), 3);
+        let rows: Vec<&str> = response.index.content.lines().collect();
+        assert_eq!(rows.len(), 3);
+        for (row, book) in rows[1..].iter().zip(&response.books) {
+            assert!(row.starts_with(&format!(
+                "{},{},2024,2024-01-01,2024-12-31,{},{}",
+                book.file_name, book.book, book.record_count, book.digest
+            )));
+            assert!(row.ends_with(",auditor"));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_invalid_fiscal_year_is_rejected() {
+        let interactor = AuditExportInteractor::new(
+            Arc::new(StubQueryService { entries: vec![] }),
+            Arc::new(StubDigester),
+        );
+        let result = interactor
+            .execute(AuditExportRequest { fiscal_year: 24, exported_by: "auditor".to_string() })
+            .await;
+        assert!(result.is_err());
+    }
+}
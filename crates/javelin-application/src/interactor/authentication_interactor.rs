@@ -0,0 +1,195 @@
+// AuthenticationInteractor - ログインのユースケース
+// 責務: ローカル資格情報の照合と初回起動時のアカウント作成
+
+use std::sync::Arc;
+
+use javelin_domain::{
+    error::DomainResult,
+    masters::{PasswordHasher, UserAccount},
+    repositories::UserAccountRepository,
+};
+
+use crate::{
+    dtos::{request::LoginRequest, response::LoginResponse},
+    error::{ApplicationError, ApplicationResult},
+};
+
+/// 照合失敗時のメッセージ（ユーザの存在有無を区別しない）
+const INVALID_CREDENTIALS: &str = "ユーザIDまたはパスワードが正しくありません";
+
+/// ログインのInteractor
+pub struct AuthenticationInteractor<R, H>
+where
+    R: UserAccountRepository,
+    H: PasswordHasher + 'static,
+{
+    repository: Arc<R>,
+    hasher: Arc<H>,
+}
+
+impl<R, H> AuthenticationInteractor<R, H>
+where
+    R: UserAccountRepository,
+    H: PasswordHasher + 'static,
+{
+    pub fn new(repository: Arc<R>, hasher: Arc<H>) -> Self {
+        Self { repository, hasher }
+    }
+
+    /// ログイン
+    ///
+    /// アカウントが1件も登録されていない場合は、入力された資格情報で
+    /// 最初のアカウントを作成してログインする。
+    pub async fn login(&self, request: LoginRequest) -> ApplicationResult<LoginResponse> {
+        UserAccount::validate_user_id(&request.user_id)?;
+
+        if self.repository.count().await? == 0 {
+            return self.create_first_account(request).await;
+        }
+
+        let mut account =
+            self.repository.find_by_id(&request.user_id).await?.ok_or_else(|| {
+                ApplicationError::ValidationError(INVALID_CREDENTIALS.to_string())
+            })?;
+
+        let hash = account.password_hash().clone();
+        if !self.blocking(move |hasher| hasher.verify(&request.password, &hash)).await? {
+            return Err(ApplicationError::ValidationError(INVALID_CREDENTIALS.to_string()));
+        }
+        account.record_login(chrono::Utc::now());
+        self.repository.save(&account).await?;
+
+        Ok(Self::to_response(&account, false))
+    }
+
+    async fn create_first_account(
+        &self,
+        request: LoginRequest,
+    ) -> ApplicationResult<LoginResponse> {
+        UserAccount::validate_password(&request.password)?;
+
+        let hash = self.blocking(move |hasher| hasher.hash(&request.password)).await?;
+        let mut account = UserAccount::new(request.user_id, "", hash)?;
+        account.record_login(chrono::Utc::now());
+        self.repository.save(&account).await?;
+
+        Ok(Self::to_response(&account, true))
+    }
+
+    /// ハッシュ計算は意図的に低速なため、ブロッキングスレッドで実行する
+    async fn blocking<T, F>(&self, f: F) -> ApplicationResult<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(&H) -> DomainResult<T> + Send + 'static,
+    {
+        let hasher = Arc::clone(&self.hasher);
+        let result = tokio::task::spawn_blocking(move || f(&hasher))
+            .await
+            .map_err(|e| ApplicationError::UseCaseExecutionFailed(e.to_string()))?;
+        Ok(result?)
+    }
+
+    fn to_response(account: &UserAccount, account_created: bool) -> LoginResponse {
+        LoginResponse {
+            user_id: account.user_id().to_string(),
+            display_name: account.display_name().to_string(),
+            account_created,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use javelin_domain::masters::PasswordHash;
+
+    use super::*;
+
+    #[derive(Default)]
+    struct MockRepository {
+        accounts: Mutex<Vec<UserAccount>>,
+    }
+
+    impl UserAccountRepository for MockRepository {
+        async fn find_by_id(&self, user_id: &str) -> DomainResult<Option<UserAccount>> {
+            Ok(self.accounts.lock().unwrap().iter().find(|a| a.user_id() == user_id).cloned())
+        }
+
+        async fn save(&self, account: &UserAccount) -> DomainResult<()> {
+            let mut accounts = self.accounts.lock().unwrap();
+            accounts.retain(|a| a.user_id() != account.user_id());
+            accounts.push(account.clone());
+            Ok(())
+        }
+
+        async fn count(&self) -> DomainResult<usize> {
+            Ok(self.accounts.lock().unwrap().len())
+        }
+    }
+
+    struct PlainHasher;
+
+    impl PasswordHasher for PlainHasher {
+        fn hash(&self, password: &str) -> DomainResult<PasswordHash> {
+            PasswordHash::new(format!("plain${}", password))
+        }
+
+        fn verify(&self, password: &str, hash: &PasswordHash) -> DomainResult<bool> {
+            Ok(hash.as_str() == format!("plain${}", password))
+        }
+    }
+
+    fn request(user_id: &str, password: &str) -> LoginRequest {
+        LoginRequest { user_id: user_id.to_string(), password: password.to_string() }
+    }
+
+    #[tokio::test]
+    async fn test_first_login_creates_account() {
+        let repository = Arc::new(MockRepository::default());
+        let interactor =
+            AuthenticationInteractor::new(Arc::clone(&repository), Arc::new(PlainHasher));
+
+        let response = interactor.login(request("tanaka", "passw0rd")).await.unwrap();
+        assert!(response.account_created);
+        assert_eq!(response.user_id, "tanaka");
+
+        let stored = repository.find_by_id("tanaka").await.unwrap().unwrap();
+        assert_eq!(stored.password_hash().as_str(), "plain$passw0rd");
+        assert!(stored.last_login_at().is_some());
+
+        let response = interactor.login(request("tanaka", "passw0rd")).await.unwrap();
+        assert!(!response.account_created);
+    }
+
+    #[tokio::test]
+    async fn test_first_login_requires_strong_password() {
+        let repository = Arc::new(MockRepository::default());
+        let interactor =
+            AuthenticationInteractor::new(Arc::clone(&repository), Arc::new(PlainHasher));
+
+        assert!(interactor.login(request("tanaka", "short")).await.is_err());
+        assert_eq!(repository.count().await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_invalid_credentials_are_rejected() {
+        let interactor = AuthenticationInteractor::new(
+            Arc::new(MockRepository::default()),
+            Arc::new(PlainHasher),
+        );
+        interactor.login(request("tanaka", "passw0rd")).await.unwrap();
+
+        let wrong_password = interactor.login(request("tanaka", "wrong-password")).await;
+        let unknown_user = interactor.login(request("suzuki", "passw0rd")).await;
+
+        for result in [wrong_password, unknown_user] {
+            match result {
+                Err(ApplicationError::ValidationError(message)) => {
+                    assert_eq!(message, INVALID_CREDENTIALS)
+                }
+                other => panic!("unexpected result: {:?}", other),
+            }
+        }
+    }
+}
@@ -1,6 +1,13 @@
 // Closing Interactors - 月次決算処理
 
+use chrono::NaiveDate;
+
+use crate::error::{ApplicationError, ApplicationResult};
+
+mod account_reconciliation_interactor;
 mod adjust_accounts_interactor;
+mod analyze_account_activity_interactor;
+mod analyze_balances_interactor;
 mod apply_ifrs_valuation_interactor;
 mod consolidate_ledger_interactor;
 mod generate_financial_statements_interactor;
@@ -8,8 +15,14 @@ mod generate_note_draft_interactor;
 mod generate_trial_balance_interactor;
 mod lock_closing_period_interactor;
 mod prepare_closing_interactor;
+mod reopen_closing_period_interactor;
+mod roll_over_period_interactor;
+mod variance_commentary_interactor;
 
+pub use account_reconciliation_interactor::AccountReconciliationInteractor;
 pub use adjust_accounts_interactor::AdjustAccountsInteractor;
+pub use analyze_account_activity_interactor::AnalyzeAccountActivityInteractor;
+pub use analyze_balances_interactor::AnalyzeBalancesInteractor;
 pub use apply_ifrs_valuation_interactor::ApplyIfrsValuationInteractor;
 pub use consolidate_ledger_interactor::ConsolidateLedgerInteractor;
 pub use generate_financial_statements_interactor::GenerateFinancialStatementsInteractor;
@@ -17,3 +30,23 @@ pub use generate_note_draft_interactor::GenerateNoteDraftInteractor;
 pub use generate_trial_balance_interactor::GenerateTrialBalanceInteractor;
 pub use lock_closing_period_interactor::LockClosingPeriodInteractor;
 pub use prepare_closing_interactor::PrepareClosingInteractor;
+pub use reopen_closing_period_interactor::ReopenClosingPeriodInteractor;
+pub use roll_over_period_interactor::RollOverPeriodInteractor;
+pub use variance_commentary_interactor::VarianceCommentaryInteractor;
+
+/// 会計期間の末日
+fn period_end_date(fiscal_year: i32, period: u8) -> ApplicationResult<NaiveDate> {
+    let (next_year, next_month) = if period == 12 {
+        (fiscal_year + 1, 1)
+    } else {
+        (fiscal_year, period as u32 + 1)
+    };
+    NaiveDate::from_ymd_opt(next_year, next_month, 1)
+        .and_then(|date| date.pred_opt())
+        .ok_or_else(|| {
+            ApplicationError::ValidationError(format!(
+                "会計期間が不正です: {}-{:02}",
+                fiscal_year, period
+            ))
+        })
+}
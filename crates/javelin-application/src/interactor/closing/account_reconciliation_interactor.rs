@@ -0,0 +1,506 @@
+// AccountReconciliationInteractor - 勘定照合（残高の裏付け）
+// 責務: 貸借対照表科目の照合ワークスペースの管理と、締日固定前の照合状況の確認
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    sync::Arc,
+};
+
+use javelin_domain::{
+    financial_close::account_reconciliation::{AccountReconciliation, ReconciliationItem},
+    repositories::AccountReconciliationRepository,
+};
+
+use crate::{
+    dtos::{
+        request::{
+            AccountReconciliationTarget, AddReconciliationItemRequest,
+            MarkAccountReconciledRequest, RemoveReconciliationItemRequest,
+        },
+        response::AccountReconciliationResponse,
+    },
+    error::{ApplicationError, ApplicationResult},
+    query_service::ledger_query_service::{
+        EntryStatusScope, GetLedgerQuery, GetTrialBalanceQuery, LedgerQueryService,
+    },
+};
+
+/// 勘定照合のInteractor
+///
+/// 照合対象は当期に動きのあった貸借対照表科目（1xxx〜3xxx）、照合必須の科目、
+/// 作成済みのワークスペースの和集合。元帳残高は記帳済の仕訳のみで集計する。
+pub struct AccountReconciliationInteractor<R, Q>
+where
+    R: AccountReconciliationRepository,
+    Q: LedgerQueryService,
+{
+    reconciliation_repository: Arc<R>,
+    ledger_query_service: Arc<Q>,
+}
+
+impl<R, Q> AccountReconciliationInteractor<R, Q>
+where
+    R: AccountReconciliationRepository,
+    Q: LedgerQueryService,
+{
+    pub fn new(reconciliation_repository: Arc<R>, ledger_query_service: Arc<Q>) -> Self {
+        Self { reconciliation_repository, ledger_query_service }
+    }
+
+    /// 会計期間の照合ワークスペース一覧（科目コード順）
+    pub async fn list(
+        &self,
+        fiscal_year: i32,
+        period: u8,
+    ) -> ApplicationResult<Vec<AccountReconciliationResponse>> {
+        validate_period(period)?;
+
+        let mut balances = period_balances(self.ledger_query_service.as_ref(), fiscal_year, period)
+            .await?
+            .into_iter()
+            .filter(|(account_code, _)| is_balance_sheet_account(account_code))
+            .collect::<BTreeMap<_, _>>();
+        let required: BTreeSet<String> =
+            self.reconciliation_repository.required_accounts().await?.into_iter().collect();
+        let reconciliations: BTreeMap<String, AccountReconciliation> = self
+            .reconciliation_repository
+            .find_by_period(fiscal_year, period)
+            .await?
+            .into_iter()
+            .map(|reconciliation| (reconciliation.account_code().to_string(), reconciliation))
+            .collect();
+
+        let account_codes: BTreeSet<String> = balances
+            .keys()
+            .chain(required.iter())
+            .chain(reconciliations.keys())
+            .cloned()
+            .collect();
+
+        let mut responses = Vec::with_capacity(account_codes.len());
+        for account_code in account_codes {
+            let (account_name, gl_balance) = match balances.remove(&account_code) {
+                Some(balance) => balance,
+                None => {
+                    carried_balance(
+                        self.ledger_query_service.as_ref(),
+                        &account_code,
+                        fiscal_year,
+                        period,
+                    )
+                    .await?
+                }
+            };
+            responses.push(AccountReconciliationResponse::new(
+                &account_code,
+                account_name,
+                fiscal_year,
+                period,
+                gl_balance,
+                reconciliations.get(&account_code),
+                required.contains(&account_code),
+            ));
+        }
+
+        Ok(responses)
+    }
+
+    /// 裏付け明細を追加（ワークスペースがなければ作成する）
+    pub async fn add_item(
+        &self,
+        request: AddReconciliationItemRequest,
+    ) -> ApplicationResult<AccountReconciliationResponse> {
+        let mut reconciliation = self.find_or_new(&request.target).await?;
+        reconciliation.add_item(ReconciliationItem::new(
+            request.description,
+            request.amount,
+            request.reference,
+        )?)?;
+        self.reconciliation_repository.save(&reconciliation).await?;
+        self.respond(&reconciliation).await
+    }
+
+    /// 裏付け明細を削除
+    pub async fn remove_item(
+        &self,
+        request: RemoveReconciliationItemRequest,
+    ) -> ApplicationResult<AccountReconciliationResponse> {
+        let mut reconciliation = self.find_existing(&request.target).await?;
+        reconciliation.remove_item(request.item_index)?;
+        self.reconciliation_repository.save(&reconciliation).await?;
+        self.respond(&reconciliation).await
+    }
+
+    /// 現在の元帳残高で照合済にする
+    pub async fn mark_reconciled(
+        &self,
+        request: MarkAccountReconciledRequest,
+    ) -> ApplicationResult<AccountReconciliationResponse> {
+        let mut reconciliation = self.find_existing(&request.target).await?;
+        let (_, gl_balance) = carried_balance(
+            self.ledger_query_service.as_ref(),
+            reconciliation.account_code(),
+            reconciliation.fiscal_year(),
+            reconciliation.period(),
+        )
+        .await?;
+        reconciliation.mark_reconciled(
+            gl_balance,
+            request.reconciled_by,
+            chrono::Utc::now().to_rfc3339(),
+        )?;
+        self.reconciliation_repository.save(&reconciliation).await?;
+        self.respond(&reconciliation).await
+    }
+
+    /// 照合を取り消して作業中に戻す
+    pub async fn reopen(
+        &self,
+        target: AccountReconciliationTarget,
+    ) -> ApplicationResult<AccountReconciliationResponse> {
+        let mut reconciliation = self.find_existing(&target).await?;
+        reconciliation.reopen()?;
+        self.reconciliation_repository.save(&reconciliation).await?;
+        self.respond(&reconciliation).await
+    }
+
+    /// 締日固定前に照合が必要な科目かを設定
+    pub async fn set_required(&self, account_code: &str, required: bool) -> ApplicationResult<()> {
+        let account_code = account_code.trim();
+        if account_code.is_empty() {
+            return Err(ApplicationError::ValidationError("勘定科目コードは必須です".to_string()));
+        }
+
+        let mut accounts = self.reconciliation_repository.required_accounts().await?;
+        accounts.retain(|code| code != account_code);
+        if required {
+            accounts.push(account_code.to_string());
+        }
+        self.reconciliation_repository.save_required_accounts(&accounts).await?;
+        Ok(())
+    }
+
+    async fn find_or_new(
+        &self,
+        target: &AccountReconciliationTarget,
+    ) -> ApplicationResult<AccountReconciliation> {
+        match self
+            .reconciliation_repository
+            .find(&target.account_code, target.fiscal_year, target.period)
+            .await?
+        {
+            Some(reconciliation) => Ok(reconciliation),
+            None => Ok(AccountReconciliation::new(
+                target.account_code.trim(),
+                target.fiscal_year,
+                target.period,
+            )?),
+        }
+    }
+
+    async fn find_existing(
+        &self,
+        target: &AccountReconciliationTarget,
+    ) -> ApplicationResult<AccountReconciliation> {
+        self.reconciliation_repository
+            .find(&target.account_code, target.fiscal_year, target.period)
+            .await?
+            .ok_or_else(|| {
+                ApplicationError::ValidationError(format!(
+                    "照合ワークスペースがありません: {} ({}-{:02})",
+                    target.account_code, target.fiscal_year, target.period
+                ))
+            })
+    }
+
+    async fn respond(
+        &self,
+        reconciliation: &AccountReconciliation,
+    ) -> ApplicationResult<AccountReconciliationResponse> {
+        let (account_name, gl_balance) = carried_balance(
+            self.ledger_query_service.as_ref(),
+            reconciliation.account_code(),
+            reconciliation.fiscal_year(),
+            reconciliation.period(),
+        )
+        .await?;
+        let required = self
+            .reconciliation_repository
+            .required_accounts()
+            .await?
+            .iter()
+            .any(|code| code == reconciliation.account_code());
+
+        Ok(AccountReconciliationResponse::new(
+            reconciliation.account_code(),
+            account_name,
+            reconciliation.fiscal_year(),
+            reconciliation.period(),
+            gl_balance,
+            Some(reconciliation),
+            required,
+        ))
+    }
+}
+
+/// 照合が完了していない照合必須科目（科目コード順）
+///
+/// 照合後に記帳があり元帳残高が変わった科目も未完了とみなす。
+pub(crate) async fn outstanding_reconciliations<R, Q>(
+    reconciliation_repository: &R,
+    ledger_query_service: &Q,
+    fiscal_year: i32,
+    period: u8,
+) -> ApplicationResult<Vec<String>>
+where
+    R: AccountReconciliationRepository,
+    Q: LedgerQueryService,
+{
+    let mut required = reconciliation_repository.required_accounts().await?;
+    required.sort();
+
+    let mut outstanding = Vec::new();
+    for account_code in required {
+        let complete =
+            match reconciliation_repository.find(&account_code, fiscal_year, period).await? {
+                Some(reconciliation) => {
+                    let (_, gl_balance) =
+                        carried_balance(ledger_query_service, &account_code, fiscal_year, period)
+                            .await?;
+                    reconciliation.is_current(gl_balance)
+                }
+                None => false,
+            };
+        if !complete {
+            outstanding.push(account_code);
+        }
+    }
+
+    Ok(outstanding)
+}
+
+fn validate_period(period: u8) -> ApplicationResult<()> {
+    if !(1..=12).contains(&period) {
+        return Err(ApplicationError::ValidationError(format!("会計期間が不正です: {}", period)));
+    }
+    Ok(())
+}
+
+/// 貸借対照表科目か（1xxx資産 / 2xxx負債 / 3xxx純資産）
+fn is_balance_sheet_account(account_code: &str) -> bool {
+    matches!(account_code.chars().next(), Some('1'..='3'))
+}
+
+/// 期間中に動きのあった勘定科目の科目名と期末残高
+async fn period_balances<Q>(
+    ledger_query_service: &Q,
+    fiscal_year: i32,
+    period: u8,
+) -> ApplicationResult<BTreeMap<String, (String, f64)>>
+where
+    Q: LedgerQueryService,
+{
+    let trial_balance = ledger_query_service
+        .get_trial_balance(GetTrialBalanceQuery {
+            period_year: fiscal_year as u32,
+            period_month: period,
+            status_scope: EntryStatusScope::PostedOnly,
+        })
+        .await?;
+
+    Ok(trial_balance
+        .entries
+        .into_iter()
+        .map(|entry| (entry.account_code, (entry.account_name, entry.closing_balance)))
+        .collect())
+}
+
+/// 勘定科目の科目名と期末日までの元帳残高
+pub(crate) async fn carried_balance<Q>(
+    ledger_query_service: &Q,
+    account_code: &str,
+    fiscal_year: i32,
+    period: u8,
+) -> ApplicationResult<(String, f64)>
+where
+    Q: LedgerQueryService,
+{
+    let ledger = ledger_query_service
+        .get_ledger(GetLedgerQuery {
+            account_code: account_code.to_string(),
+            from_date: None,
+            // 日付は文字列比較のため、月末日に関わらず31日までを対象とする
+            to_date: Some(format!("{:04}-{:02}-31", fiscal_year, period)),
+            limit: Some(u32::MAX),
+            offset: None,
+            status_scope: EntryStatusScope::PostedOnly,
+            flagged_only: false,
+            dimensions: Default::default(),
+        })
+        .await?;
+
+    Ok((ledger.account_name, ledger.closing_balance))
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::sync::Mutex;
+
+    use javelin_domain::error::DomainResult;
+
+    use super::*;
+    use crate::testkit::{InMemoryLedgerQueryService, trial_balance_entry};
+
+    #[derive(Default)]
+    pub(crate) struct MockReconciliationRepository {
+        reconciliations: Mutex<BTreeMap<(String, i32, u8), AccountReconciliation>>,
+        required: Mutex<Vec<String>>,
+    }
+
+    impl AccountReconciliationRepository for MockReconciliationRepository {
+        async fn find(
+            &self,
+            account_code: &str,
+            fiscal_year: i32,
+            period: u8,
+        ) -> DomainResult<Option<AccountReconciliation>> {
+            Ok(self
+                .reconciliations
+                .lock()
+                .unwrap()
+                .get(&(account_code.to_string(), fiscal_year, period))
+                .cloned())
+        }
+
+        async fn find_by_period(
+            &self,
+            fiscal_year: i32,
+            period: u8,
+        ) -> DomainResult<Vec<AccountReconciliation>> {
+            Ok(self
+                .reconciliations
+                .lock()
+                .unwrap()
+                .values()
+                .filter(|r| r.fiscal_year() == fiscal_year && r.period() == period)
+                .cloned()
+                .collect())
+        }
+
+        async fn save(&self, reconciliation: &AccountReconciliation) -> DomainResult<()> {
+            self.reconciliations.lock().unwrap().insert(
+                (
+                    reconciliation.account_code().to_string(),
+                    reconciliation.fiscal_year(),
+                    reconciliation.period(),
+                ),
+                reconciliation.clone(),
+            );
+            Ok(())
+        }
+
+        async fn required_accounts(&self) -> DomainResult<Vec<String>> {
+            Ok(self.required.lock().unwrap().clone())
+        }
+
+        async fn save_required_accounts(&self, account_codes: &[String]) -> DomainResult<()> {
+            *self.required.lock().unwrap() = account_codes.to_vec();
+            Ok(())
+        }
+    }
+
+    fn target(account_code: &str) -> AccountReconciliationTarget {
+        AccountReconciliationTarget {
+            account_code: account_code.to_string(),
+            fiscal_year: 2024,
+            period: 3,
+        }
+    }
+
+    /// 1100・4100は期間内に動きがあり、1200は繰越残高のみ
+    fn ledger() -> InMemoryLedgerQueryService {
+        InMemoryLedgerQueryService::new()
+            .with_ledger_balance("1100", 1_000.0)
+            .with_ledger_balance("1200", 300.0)
+            .with_ledger_balance("4100", -500.0)
+            .with_trial_balance(
+                2024,
+                3,
+                vec![trial_balance_entry("1100", 1_000.0), trial_balance_entry("4100", -500.0)],
+            )
+    }
+
+    #[tokio::test]
+    async fn test_list_covers_balance_sheet_required_and_existing_accounts() {
+        let repository = Arc::new(MockReconciliationRepository::default());
+        let interactor =
+            AccountReconciliationInteractor::new(Arc::clone(&repository), Arc::new(ledger()));
+        interactor.set_required("1200", true).await.unwrap();
+
+        let listed = interactor.list(2024, 3).await.unwrap();
+        let codes: Vec<_> = listed.iter().map(|r| r.account_code.as_str()).collect();
+        assert_eq!(codes, vec!["1100", "1200"]);
+        assert_eq!(listed[1].gl_balance, 300.0);
+        assert!(listed[1].required);
+        assert_eq!(listed[0].difference, 1_000.0);
+
+        assert!(interactor.list(2024, 13).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_reconcile_and_outstanding_after_balance_change() {
+        let repository = Arc::new(MockReconciliationRepository::default());
+        let ledger = Arc::new(ledger());
+        let interactor =
+            AccountReconciliationInteractor::new(Arc::clone(&repository), Arc::clone(&ledger));
+        interactor.set_required("1100", true).await.unwrap();
+
+        let mark = || MarkAccountReconciledRequest {
+            target: target("1100"),
+            reconciled_by: "user1".to_string(),
+        };
+        assert!(interactor.mark_reconciled(mark()).await.is_err());
+
+        interactor
+            .add_item(AddReconciliationItemRequest {
+                target: target("1100"),
+                description: "A銀行 残高証明".to_string(),
+                amount: 1_000.0,
+                reference: None,
+            })
+            .await
+            .unwrap();
+        assert_eq!(
+            outstanding_reconciliations(repository.as_ref(), ledger.as_ref(), 2024, 3)
+                .await
+                .unwrap(),
+            vec!["1100"]
+        );
+
+        let reconciled = interactor.mark_reconciled(mark()).await.unwrap();
+        assert!(reconciled.reconciled && reconciled.current);
+        assert!(
+            outstanding_reconciliations(repository.as_ref(), ledger.as_ref(), 2024, 3)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+
+        // 照合後の記帳で残高が変わると照合をやり直す必要がある
+        ledger.set_balance("1100", 1_200.0);
+        assert_eq!(
+            outstanding_reconciliations(repository.as_ref(), ledger.as_ref(), 2024, 3)
+                .await
+                .unwrap(),
+            vec!["1100"]
+        );
+
+        interactor.reopen(target("1100")).await.unwrap();
+        let removed = interactor
+            .remove_item(RemoveReconciliationItemRequest { target: target("1100"), item_index: 0 })
+            .await
+            .unwrap();
+        assert!(removed.items.is_empty());
+        assert_eq!(removed.difference, 1_200.0);
+    }
+}
@@ -8,13 +8,29 @@ use javelin_domain::{
     financial_close::closing_events::ClosingEvent, repositories::EventRepository,
 };
 
+use super::period_end_date;
 use crate::{
-    dtos::{AdjustAccountsRequest, AdjustAccountsResponse},
+    dtos::{
+        AdjustAccountsRequest, AdjustAccountsResponse, JournalEntryLineDto,
+        response::{ClosingEntryDto, TrialBalanceImpactDto},
+    },
     error::ApplicationResult,
     input_ports::AdjustAccountsUseCase,
-    query_service::ledger_query_service::{GetTrialBalanceQuery, LedgerQueryService},
+    query_service::ledger_query_service::{
+        EntryStatusScope, GetTrialBalanceQuery, LedgerQueryService,
+    },
 };
 
+/// 補正の内容（勘定科目・貸借・理由）
+///
+/// 仮勘定の残高を本来の科目へ振り替える。
+const ADJUSTMENTS: [(&str, &str, &str); 2] =
+    [("9999", "Credit", "仮勘定整理"), ("1000", "Debit", "区分修正")];
+/// 補正金額
+const ADJUSTMENT_AMOUNT: f64 = 100000.0;
+/// 補正の通貨
+const ADJUSTMENT_CURRENCY: &str = "JPY";
+
 pub struct AdjustAccountsInteractor<R, Q>
 where
     R: EventRepository,
@@ -44,49 +60,123 @@ where
         request: AdjustAccountsRequest,
     ) -> ApplicationResult<AdjustAccountsResponse> {
         // 試算表を取得して補正対象を特定
-        let _trial_balance = self
+        let trial_balance = self
             .ledger_query_service
             .get_trial_balance(GetTrialBalanceQuery {
                 period_year: request.fiscal_year as u32,
                 period_month: request.period,
+                status_scope: EntryStatusScope::PostedOnly,
             })
             .await?;
 
-        // 勘定補正イベントを記録
         let adjustment_id = format!("ADJ-{}-{:02}", request.fiscal_year, request.period);
-        let events = vec![
-            ClosingEvent::AccountAdjusted {
-                adjustment_id: format!("{}-001", adjustment_id),
-                fiscal_year: request.fiscal_year,
-                period: request.period,
-                account_code: "9999".to_string(), // 仮勘定
-                adjustment_type: "Reclassification".to_string(),
-                amount: 100000.0,
-                currency: "JPY".to_string(),
-                reason: "仮勘定整理".to_string(),
-                adjusted_by: "system".to_string(),
-                adjusted_at: Utc::now(),
-            },
-            ClosingEvent::AccountAdjusted {
-                adjustment_id: format!("{}-002", adjustment_id),
-                fiscal_year: request.fiscal_year,
-                period: request.period,
-                account_code: "1000".to_string(),
-                adjustment_type: "Reclassification".to_string(),
-                amount: 100000.0,
-                currency: "JPY".to_string(),
-                reason: "区分修正".to_string(),
-                adjusted_by: "system".to_string(),
-                adjusted_at: Utc::now(),
-            },
-        ];
-
-        self.event_repository.append_events(&adjustment_id, events).await?;
+        let entry = ClosingEntryDto {
+            entry_id: (!request.dry_run).then(|| adjustment_id.clone()),
+            transaction_date: period_end_date(request.fiscal_year, request.period)?
+                .format("%Y-%m-%d")
+                .to_string(),
+            description: "仮勘定整理・区分修正".to_string(),
+            lines: ADJUSTMENTS
+                .iter()
+                .enumerate()
+                .map(|(index, (account_code, side, reason))| JournalEntryLineDto {
+                    line_number: index as u32 + 1,
+                    side: side.to_string(),
+                    account_code: account_code.to_string(),
+                    sub_account_code: None,
+                    department_code: None,
+                    dimensions: Default::default(),
+                    amount: ADJUSTMENT_AMOUNT,
+                    currency: ADJUSTMENT_CURRENCY.to_string(),
+                    tax_type: "NonTaxable".to_string(),
+                    tax_amount: 0.0,
+                    description: Some(reason.to_string()),
+                })
+                .collect(),
+        };
+        let entries = vec![entry];
+        let trial_balance_impact = TrialBalanceImpactDto::simulate(&trial_balance, &entries);
+
+        // 試算の場合はイベントを記録しない
+        if !request.dry_run {
+            let adjusted_at = Utc::now();
+            let events = ADJUSTMENTS
+                .iter()
+                .enumerate()
+                .map(|(index, (account_code, _, reason))| ClosingEvent::AccountAdjusted {
+                    adjustment_id: format!("{}-{:03}", adjustment_id, index + 1),
+                    fiscal_year: request.fiscal_year,
+                    period: request.period,
+                    account_code: account_code.to_string(),
+                    adjustment_type: "Reclassification".to_string(),
+                    amount: ADJUSTMENT_AMOUNT,
+                    currency: ADJUSTMENT_CURRENCY.to_string(),
+                    reason: reason.to_string(),
+                    adjusted_by: "system".to_string(),
+                    adjusted_at,
+                })
+                .collect();
+            self.event_repository.append_events(&adjustment_id, events).await?;
+        }
 
         Ok(AdjustAccountsResponse {
             adjustment_entries_created: 3,
             reclassified_accounts: vec![],
             tax_effect_adjustments: vec![],
+            dry_run: request.dry_run,
+            entries,
+            trial_balance_impact,
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::testkit::{
+        InMemoryEventRepository, InMemoryLedgerQueryService, trial_balance_entry,
+    };
+
+    /// 現金500,000と仮勘定100,000の試算表
+    fn ledger() -> InMemoryLedgerQueryService {
+        InMemoryLedgerQueryService::new().with_trial_balance(
+            2024,
+            3,
+            vec![trial_balance_entry("1000", 500_000.0), trial_balance_entry("9999", 100_000.0)],
+        )
+    }
+
+    #[tokio::test]
+    async fn test_dry_run_returns_entries_without_recording_events() {
+        let events = Arc::new(InMemoryEventRepository::default());
+        let interactor = AdjustAccountsInteractor::new(Arc::clone(&events), Arc::new(ledger()));
+
+        let simulated = interactor
+            .execute(AdjustAccountsRequest { fiscal_year: 2024, period: 3, dry_run: true })
+            .await
+            .unwrap();
+        assert!(simulated.dry_run);
+        assert!(events.is_empty());
+        assert_eq!(simulated.entries.len(), 1);
+        assert_eq!(simulated.entries[0].entry_id, None);
+        assert_eq!(simulated.entries[0].transaction_date, "2024-03-31");
+
+        // 試算表の残高に補正を反映した結果
+        let impact: Vec<(&str, f64, f64)> = simulated
+            .trial_balance_impact
+            .iter()
+            .map(|i| (i.account_code.as_str(), i.closing_balance, i.simulated_balance))
+            .collect();
+        assert_eq!(impact, vec![("1000", 500_000.0, 600_000.0), ("9999", 100_000.0, 0.0)]);
+
+        let committed = interactor
+            .execute(AdjustAccountsRequest { fiscal_year: 2024, period: 3, dry_run: false })
+            .await
+            .unwrap();
+        assert!(!committed.dry_run);
+        assert_eq!(committed.entries[0].entry_id.as_deref(), Some("ADJ-2024-03"));
+        assert_eq!(committed.trial_balance_impact, simulated.trial_balance_impact);
+        assert_eq!(events.len(), 2);
+    }
+}
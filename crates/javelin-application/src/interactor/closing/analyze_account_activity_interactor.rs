@@ -0,0 +1,233 @@
+// AnalyzeAccountActivityInteractor - 勘定科目別の記帳量分析
+// 責務: 月別の記帳件数・金額のヒートマップ化と急増月の検出（締レビュー用）
+
+use std::sync::Arc;
+
+use crate::{
+    dtos::{
+        request::AnalyzeAccountActivityRequest,
+        response::{
+            AccountActivityHeatCell, AccountActivityHeatRow, AnalyzeAccountActivityResponse,
+            MAX_HEAT_LEVEL,
+        },
+    },
+    error::{ApplicationError, ApplicationResult},
+    query_service::{
+        account_activity::{
+            AccountActivityCell, AccountActivityQueryService, GetAccountActivityQuery,
+        },
+        ledger_query_service::EntryStatusScope,
+    },
+};
+
+/// 集計月数の上限
+const MAX_ACTIVITY_MONTHS: u8 = 24;
+/// 他の月の平均に対して急増とみなす倍率
+const SPIKE_RATIO: f64 = 2.0;
+/// 件数で急増と判定する最低件数（少数件の揺らぎを除く）
+const MIN_SPIKE_POSTINGS: u32 = 3;
+
+/// 勘定科目別の記帳量分析のInteractor
+///
+/// 濃度は勘定科目ごとに期間中の最大値を基準とする（科目間で規模の異なる金額を
+/// 同じ表で比較するため）。件数または金額が他の月の平均のSPIKE_RATIO倍以上の月を急増とする。
+pub struct AnalyzeAccountActivityInteractor<Q>
+where
+    Q: AccountActivityQueryService,
+{
+    query_service: Arc<Q>,
+}
+
+impl<Q> AnalyzeAccountActivityInteractor<Q>
+where
+    Q: AccountActivityQueryService,
+{
+    pub fn new(query_service: Arc<Q>) -> Self {
+        Self { query_service }
+    }
+
+    pub async fn execute(
+        &self,
+        request: AnalyzeAccountActivityRequest,
+    ) -> ApplicationResult<AnalyzeAccountActivityResponse> {
+        if !(1..=12).contains(&request.period) {
+            return Err(ApplicationError::ValidationError(format!(
+                "会計期間が不正です: {}",
+                request.period
+            )));
+        }
+        if !(1..=MAX_ACTIVITY_MONTHS).contains(&request.months) {
+            return Err(ApplicationError::ValidationError(format!(
+                "集計月数は1〜{}で指定してください: {}",
+                MAX_ACTIVITY_MONTHS, request.months
+            )));
+        }
+
+        let activity = self
+            .query_service
+            .get_account_activity(GetAccountActivityQuery {
+                to_year: request.fiscal_year,
+                to_month: request.period,
+                months: request.months,
+                status_scope: EntryStatusScope::PostedOnly,
+            })
+            .await?;
+
+        let rows = activity
+            .rows
+            .into_iter()
+            .map(|row| AccountActivityHeatRow {
+                total_count: row.cells.iter().map(|cell| cell.posting_count).sum(),
+                total_amount: row.cells.iter().map(|cell| cell.amount).sum(),
+                cells: heat_cells(&row.cells),
+                account_code: row.account_code,
+                account_name: row.account_name,
+                amount_masked: false,
+            })
+            .collect();
+
+        Ok(AnalyzeAccountActivityResponse {
+            periods: activity
+                .periods
+                .iter()
+                .map(|(year, month)| format!("{:04}-{:02}", year, month))
+                .collect(),
+            rows,
+        })
+    }
+}
+
+/// 勘定科目1行分の濃度と急増の判定
+fn heat_cells(cells: &[AccountActivityCell]) -> Vec<AccountActivityHeatCell> {
+    let counts: Vec<f64> = cells.iter().map(|cell| f64::from(cell.posting_count)).collect();
+    let amounts: Vec<f64> = cells.iter().map(|cell| cell.amount).collect();
+    let max_count = counts.iter().copied().fold(0.0, f64::max);
+    let max_amount = amounts.iter().copied().fold(0.0, f64::max);
+
+    cells
+        .iter()
+        .enumerate()
+        .map(|(index, cell)| {
+            let count_spike = cell.posting_count >= MIN_SPIKE_POSTINGS && is_spike(&counts, index);
+            AccountActivityHeatCell {
+                posting_count: cell.posting_count,
+                amount: cell.amount,
+                count_level: heat_level(counts[index], max_count),
+                amount_level: heat_level(cell.amount, max_amount),
+                spike: count_spike || is_spike(&amounts, index),
+            }
+        })
+        .collect()
+}
+
+/// 最大値に対する濃度（値があれば最低1段階）
+fn heat_level(value: f64, max: f64) -> u8 {
+    if value <= 0.0 || max <= 0.0 {
+        return 0;
+    }
+    ((value / max * f64::from(MAX_HEAT_LEVEL)).ceil() as u8).clamp(1, MAX_HEAT_LEVEL)
+}
+
+/// 指定月の値が他の月の平均のSPIKE_RATIO倍以上か（他の月に記帳がなければ判定しない）
+fn is_spike(values: &[f64], index: usize) -> bool {
+    if values.len() < 2 {
+        return false;
+    }
+    let others: f64 = values
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| *i != index)
+        .map(|(_, value)| value)
+        .sum();
+    let average = others / (values.len() - 1) as f64;
+    average > 0.0 && values[index] >= average * SPIKE_RATIO
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::query_service::account_activity::{
+        AccountActivityResult, AccountActivityRow, activity_periods,
+    };
+
+    /// 勘定科目ごとの月別（件数, 金額）を返すモック
+    struct MockAccountActivityQueryService {
+        rows: Vec<(&'static str, Vec<(u32, f64)>)>,
+    }
+
+    impl AccountActivityQueryService for MockAccountActivityQueryService {
+        async fn get_account_activity(
+            &self,
+            query: GetAccountActivityQuery,
+        ) -> ApplicationResult<AccountActivityResult> {
+            Ok(AccountActivityResult {
+                periods: activity_periods(query.to_year, query.to_month, query.months),
+                rows: self
+                    .rows
+                    .iter()
+                    .map(|(account_code, cells)| AccountActivityRow {
+                        account_code: account_code.to_string(),
+                        account_name: format!("勘定科目{}", account_code),
+                        cells: cells
+                            .iter()
+                            .map(|(posting_count, amount)| AccountActivityCell {
+                                posting_count: *posting_count,
+                                amount: *amount,
+                            })
+                            .collect(),
+                    })
+                    .collect(),
+            })
+        }
+    }
+
+    fn request(period: u8, months: u8) -> AnalyzeAccountActivityRequest {
+        AnalyzeAccountActivityRequest { fiscal_year: 2024, period, months }
+    }
+
+    #[tokio::test]
+    async fn test_levels_are_relative_per_account_and_spikes_are_flagged() {
+        let service = MockAccountActivityQueryService {
+            rows: vec![
+                // 3月に件数・金額とも急増
+                ("1100", vec![(4, 100_000.0), (5, 120_000.0), (0, 0.0), (20, 900_000.0)]),
+                // 毎月同程度（金額規模が大きくても急増なし）
+                (
+                    "4000",
+                    vec![(10, 5_000_000.0), (10, 5_000_000.0), (9, 4_800_000.0), (10, 5_000_000.0)],
+                ),
+            ],
+        };
+        let interactor = AnalyzeAccountActivityInteractor::new(Arc::new(service));
+
+        let response = interactor.execute(request(3, 4)).await.unwrap();
+
+        assert_eq!(response.periods, vec!["2023-12", "2024-01", "2024-02", "2024-03"]);
+        let cash = &response.rows[0];
+        assert_eq!(cash.total_count, 29);
+        assert_eq!(
+            cash.cells.iter().map(|cell| cell.count_level).collect::<Vec<_>>(),
+            vec![1, 1, 0, 4]
+        );
+        assert_eq!(
+            cash.cells.iter().map(|cell| cell.spike).collect::<Vec<_>>(),
+            vec![false, false, false, true]
+        );
+        let sales = &response.rows[1];
+        assert!(sales.cells.iter().all(|cell| cell.amount_level >= 3));
+        assert_eq!(sales.spike_count(), 0);
+        assert_eq!(response.spike_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_invalid_period_and_months_are_rejected() {
+        let interactor =
+            AnalyzeAccountActivityInteractor::new(Arc::new(MockAccountActivityQueryService {
+                rows: vec![],
+            }));
+
+        assert!(interactor.execute(request(13, 12)).await.is_err());
+        assert!(interactor.execute(request(3, 0)).await.is_err());
+        assert!(interactor.execute(request(3, MAX_ACTIVITY_MONTHS + 1)).await.is_err());
+    }
+}
@@ -0,0 +1,355 @@
+// AnalyzeBalancesInteractor - 勘定残高の期間比較分析
+// 責務: 前月・前年同月との残高比較による異常値の抽出（締準備のレビュー用）
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    sync::Arc,
+};
+
+use crate::{
+    dtos::{
+        request::{AnalyzeBalancesRequest, BalanceAnomalyThresholds},
+        response::{AnalyzeBalancesResponse, BalanceAnomalyItem, BalanceAnomalyKind},
+    },
+    error::{ApplicationError, ApplicationResult},
+    query_service::ledger_query_service::{
+        EntryStatusScope, GetLedgerQuery, GetTrialBalanceQuery, LedgerQueryService,
+    },
+};
+
+/// 残高をゼロとみなす許容誤差
+const BALANCE_TOLERANCE: f64 = 0.005;
+
+/// 勘定科目の期末残高
+#[derive(Debug, Clone)]
+struct AccountBalance {
+    account_name: String,
+    balance: f64,
+}
+
+/// 勘定残高の期間比較分析のInteractor
+///
+/// 当月末残高を前月末・前年同月末と比較し、次のいずれかに該当する勘定科目を抽出する。
+/// - 増減額が下限以上、かつ増減率が閾値以上（比較対象がゼロの場合は増減額のみで判定）
+/// - 通常の残高側（資産・費用は借方、負債・純資産・収益は貸方）から逆側へ符号が反転した
+pub struct AnalyzeBalancesInteractor<Q>
+where
+    Q: LedgerQueryService,
+{
+    ledger_query_service: Arc<Q>,
+}
+
+impl<Q> AnalyzeBalancesInteractor<Q>
+where
+    Q: LedgerQueryService,
+{
+    pub fn new(ledger_query_service: Arc<Q>) -> Self {
+        Self { ledger_query_service }
+    }
+
+    pub async fn execute(
+        &self,
+        request: AnalyzeBalancesRequest,
+    ) -> ApplicationResult<AnalyzeBalancesResponse> {
+        if !(1..=12).contains(&request.period) {
+            return Err(ApplicationError::ValidationError(format!(
+                "会計期間が不正です: {}",
+                request.period
+            )));
+        }
+
+        let current_period = (request.fiscal_year, request.period);
+        let prior_period = if request.period == 1 {
+            (request.fiscal_year - 1, 12)
+        } else {
+            (request.fiscal_year, request.period - 1)
+        };
+        let prior_year = (request.fiscal_year - 1, request.period);
+
+        let mut current = self.period_balances(current_period).await?;
+        let mut prior = self.period_balances(prior_period).await?;
+        let mut last_year = self.period_balances(prior_year).await?;
+
+        // いずれかの期間で動きのあった勘定科目を対象とし、動きのない期間は元帳残高で補完する
+        let account_codes: BTreeSet<String> =
+            current.keys().chain(prior.keys()).chain(last_year.keys()).cloned().collect();
+        for account_code in &account_codes {
+            for (balances, period) in [
+                (&mut current, current_period),
+                (&mut prior, prior_period),
+                (&mut last_year, prior_year),
+            ] {
+                if !balances.contains_key(account_code) {
+                    let balance = self.carried_balance(account_code, period).await?;
+                    balances.insert(account_code.clone(), balance);
+                }
+            }
+        }
+
+        let mut anomalies = Vec::new();
+        for account_code in &account_codes {
+            let current = &current[account_code];
+            let prior = &prior[account_code];
+            let last_year = &last_year[account_code];
+
+            if let Some(anomaly) = detect_sign_change(account_code, current, prior) {
+                anomalies.push(anomaly);
+            }
+            for (kind, comparison) in [
+                (BalanceAnomalyKind::PriorPeriodChange, prior),
+                (BalanceAnomalyKind::PriorYearChange, last_year),
+            ] {
+                if let Some(anomaly) =
+                    detect_change(account_code, kind, current, comparison, &request.thresholds)
+                {
+                    anomalies.push(anomaly);
+                }
+            }
+        }
+
+        // 符号反転を優先し、それ以外は増減額の大きい順に並べる
+        anomalies.sort_by(|a, b| {
+            let a_sign_change = a.kind == BalanceAnomalyKind::SignChange;
+            let b_sign_change = b.kind == BalanceAnomalyKind::SignChange;
+            b_sign_change
+                .cmp(&a_sign_change)
+                .then_with(|| b.change_amount.abs().total_cmp(&a.change_amount.abs()))
+        });
+
+        Ok(AnalyzeBalancesResponse {
+            fiscal_year: request.fiscal_year,
+            period: request.period,
+            analyzed_accounts: account_codes.len(),
+            anomalies,
+        })
+    }
+
+    /// 期間中に動きのあった勘定科目の期末残高
+    async fn period_balances(
+        &self,
+        (year, month): (i32, u8),
+    ) -> ApplicationResult<BTreeMap<String, AccountBalance>> {
+        let trial_balance = self
+            .ledger_query_service
+            .get_trial_balance(GetTrialBalanceQuery {
+                period_year: year as u32,
+                period_month: month,
+                status_scope: EntryStatusScope::PostedOnly,
+            })
+            .await?;
+
+        Ok(trial_balance
+            .entries
+            .into_iter()
+            .map(|entry| {
+                let balance = AccountBalance {
+                    account_name: entry.account_name,
+                    balance: entry.closing_balance,
+                };
+                (entry.account_code, balance)
+            })
+            .collect())
+    }
+
+    /// 期間中に動きのない勘定科目の期末残高（期末日までの元帳残高）
+    async fn carried_balance(
+        &self,
+        account_code: &str,
+        (year, month): (i32, u8),
+    ) -> ApplicationResult<AccountBalance> {
+        let ledger = self
+            .ledger_query_service
+            .get_ledger(GetLedgerQuery {
+                account_code: account_code.to_string(),
+                from_date: None,
+                // 日付は文字列比較のため、月末日に関わらず31日までを対象とする
+                to_date: Some(format!("{:04}-{:02}-31", year, month)),
+                limit: Some(u32::MAX),
+                offset: None,
+                status_scope: EntryStatusScope::PostedOnly,
+                flagged_only: false,
+                dimensions: Default::default(),
+            })
+            .await?;
+
+        Ok(AccountBalance { account_name: ledger.account_name, balance: ledger.closing_balance })
+    }
+}
+
+/// 通常の残高側が借方の勘定科目か
+///
+/// 勘定科目コード体系（1xxx資産 / 2xxx負債 / 3xxx純資産 / 4xxx収益 / 5xxx以降費用）に基づく。
+fn is_debit_normal(account_code: &str) -> bool {
+    !matches!(account_code.chars().next(), Some('2'..='4'))
+}
+
+/// 通常と逆側の残高か
+fn is_abnormal_side(account_code: &str, balance: f64) -> bool {
+    if is_debit_normal(account_code) {
+        balance < -BALANCE_TOLERANCE
+    } else {
+        balance > BALANCE_TOLERANCE
+    }
+}
+
+fn detect_sign_change(
+    account_code: &str,
+    current: &AccountBalance,
+    prior: &AccountBalance,
+) -> Option<BalanceAnomalyItem> {
+    if !is_abnormal_side(account_code, current.balance)
+        || is_abnormal_side(account_code, prior.balance)
+    {
+        return None;
+    }
+
+    let (normal_side, abnormal_side) = if is_debit_normal(account_code) {
+        ("借方", "貸方")
+    } else {
+        ("貸方", "借方")
+    };
+    let change_amount = current.balance - prior.balance;
+    Some(BalanceAnomalyItem {
+        account_code: account_code.to_string(),
+        account_name: current.account_name.clone(),
+        kind: BalanceAnomalyKind::SignChange,
+        current_balance: current.balance,
+        comparison_balance: prior.balance,
+        change_amount,
+        change_rate: change_rate(change_amount, prior.balance),
+        detail: format!("{}残高の科目が{}残高になりました", normal_side, abnormal_side),
+    })
+}
+
+fn detect_change(
+    account_code: &str,
+    kind: BalanceAnomalyKind,
+    current: &AccountBalance,
+    comparison: &AccountBalance,
+    thresholds: &BalanceAnomalyThresholds,
+) -> Option<BalanceAnomalyItem> {
+    let change_amount = current.balance - comparison.balance;
+    if change_amount.abs() < thresholds.min_change_amount {
+        return None;
+    }
+
+    let rate = change_rate(change_amount, comparison.balance);
+    if rate.is_some_and(|rate| rate.abs() < thresholds.change_rate) {
+        return None;
+    }
+
+    let detail = match rate {
+        Some(rate) => format!("{}で{:+.1}%増減しました", kind.label(), rate * 100.0),
+        None => format!("{}の残高がゼロから増減しました", kind.label()),
+    };
+    Some(BalanceAnomalyItem {
+        account_code: account_code.to_string(),
+        account_name: current.account_name.clone(),
+        kind,
+        current_balance: current.balance,
+        comparison_balance: comparison.balance,
+        change_amount,
+        change_rate: rate,
+        detail,
+    })
+}
+
+/// 比較対象残高に対する増減率（比較対象がゼロの場合はNone）
+fn change_rate(change_amount: f64, comparison_balance: f64) -> Option<f64> {
+    if comparison_balance.abs() < BALANCE_TOLERANCE {
+        None
+    } else {
+        Some(change_amount / comparison_balance.abs())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        query_service::TrialBalanceEntry,
+        testkit::{InMemoryLedgerQueryService, trial_balance_entry},
+    };
+
+    /// 勘定科目別期末残高の試算表明細
+    fn entries(balances: &[(&str, f64)]) -> Vec<TrialBalanceEntry> {
+        balances
+            .iter()
+            .map(|&(code, balance)| trial_balance_entry(code, balance))
+            .collect()
+    }
+
+    fn request(period: u8) -> AnalyzeBalancesRequest {
+        AnalyzeBalancesRequest {
+            fiscal_year: 2024,
+            period,
+            thresholds: BalanceAnomalyThresholds::default(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_flags_changes_over_thresholds_ranked_by_amount() {
+        let service = InMemoryLedgerQueryService::new()
+            .with_trial_balance(
+                2024,
+                3,
+                entries(&[("1100", 2_000_000.0), ("5000", 1_100_000.0), ("1000", 50_000.0)]),
+            )
+            .with_trial_balance(
+                2024,
+                2,
+                entries(&[("1100", 1_000_000.0), ("5000", 1_000_000.0), ("1000", 10_000.0)]),
+            )
+            .with_trial_balance(2023, 3, entries(&[("1100", 1_900_000.0), ("5000", 500_000.0)]))
+            .with_ledger_balance("1000", 50_000.0);
+        let interactor = AnalyzeBalancesInteractor::new(Arc::new(service));
+
+        let response = interactor.execute(request(3)).await.unwrap();
+
+        assert_eq!(response.analyzed_accounts, 3);
+        let flagged: Vec<(&str, BalanceAnomalyKind)> = response
+            .anomalies
+            .iter()
+            .map(|anomaly| (anomaly.account_code.as_str(), anomaly.kind))
+            .collect();
+        // 1100: 前月比+100%、5000: 前年同月比+120%（前月比+10%は閾値未満）
+        // 1000: 増減額が下限未満のため対象外
+        assert_eq!(
+            flagged,
+            vec![
+                ("1100", BalanceAnomalyKind::PriorPeriodChange),
+                ("5000", BalanceAnomalyKind::PriorYearChange),
+            ]
+        );
+        assert_eq!(response.anomalies[0].change_rate, Some(1.0));
+    }
+
+    #[tokio::test]
+    async fn test_sign_change_is_ranked_first_and_prior_period_wraps_year() {
+        let service = InMemoryLedgerQueryService::new()
+            .with_trial_balance(2024, 1, entries(&[("2000", 50_000.0), ("1100", 5_000_000.0)]))
+            .with_trial_balance(2023, 12, entries(&[("2000", -300_000.0)]))
+            // 1100は前月・前年同月に動きがなく、元帳残高で補完される
+            .with_ledger_balance("1100", 1_000_000.0);
+        let interactor = AnalyzeBalancesInteractor::new(Arc::new(service));
+
+        let response = interactor.execute(request(1)).await.unwrap();
+
+        assert_eq!(response.anomalies[0].account_code, "2000");
+        assert_eq!(response.anomalies[0].kind, BalanceAnomalyKind::SignChange);
+        assert!(
+            response
+                .anomalies
+                .iter()
+                .any(|a| a.account_code == "1100" && a.comparison_balance == 1_000_000.0)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_invalid_period_is_rejected() {
+        let service = InMemoryLedgerQueryService::new();
+        let interactor = AnalyzeBalancesInteractor::new(Arc::new(service));
+
+        assert!(interactor.execute(request(13)).await.is_err());
+    }
+}
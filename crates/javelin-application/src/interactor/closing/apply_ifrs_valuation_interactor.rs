@@ -3,52 +3,296 @@
 
 use std::sync::Arc;
 
-use chrono::Utc;
+use chrono::{Datelike, NaiveDate, Utc};
 use javelin_domain::{
-    financial_close::closing_events::ClosingEvent, repositories::EventRepository,
+    entity::EntityId,
+    financial_close::{
+        closing_events::ClosingEvent,
+        financial_instrument::{FinancialInstrument, InstrumentPosition},
+        inventory_valuation::InventoryWriteDownWorksheet,
+        journal_entry::{
+            entities::{JournalEntry, JournalEntryId, JournalEntryLine},
+            services::{JournalEntryService, VoucherNumberGenerator},
+            values::{TransactionDate, UserId, VoucherNumber},
+        },
+    },
+    id_generator::AggregateIdGenerator,
+    repositories::{EventRepository, FinancialInstrumentRepository, InventoryWorksheetRepository},
 };
 
+use super::period_end_date;
 use crate::{
-    dtos::{ApplyIfrsValuationRequest, ApplyIfrsValuationResponse},
-    error::ApplicationResult,
+    dtos::{
+        ApplyIfrsValuationRequest, ApplyIfrsValuationResponse, JournalEntryLineDto,
+        response::{
+            ClosingEntryDto, InterestAccrualDto, InventoryWriteDownDto, TrialBalanceImpactDto,
+        },
+    },
+    error::{ApplicationError, ApplicationResult},
+    id_generator::TimeOrderedIdGenerator,
     input_ports::ApplyIfrsValuationUseCase,
-    query_service::ledger_query_service::{GetTrialBalanceQuery, LedgerQueryService},
+    query_service::ledger_query_service::{
+        EntryStatusScope, GetTrialBalanceQuery, LedgerQueryService,
+    },
 };
 
-pub struct ApplyIfrsValuationInteractor<R, Q>
+/// 棚卸資産（評価減の貸方）
+const INVENTORY_ACCOUNT_CODE: &str = "1300";
+/// 棚卸資産評価損（評価減の借方）
+const INVENTORY_WRITE_DOWN_ACCOUNT_CODE: &str = "5100";
+/// 未収収益（金融資産の表面利息）
+const ACCRUED_INTEREST_RECEIVABLE_ACCOUNT_CODE: &str = "1410";
+/// 受取利息（金融資産の実効金利による利息）
+const INTEREST_INCOME_ACCOUNT_CODE: &str = "8100";
+/// 未払費用（金融負債の表面利息）
+const ACCRUED_INTEREST_PAYABLE_ACCOUNT_CODE: &str = "2310";
+/// 支払利息（金融負債の実効金利による利息）
+const INTEREST_EXPENSE_ACCOUNT_CODE: &str = "8200";
+/// 評価処理の実行者
+const VALUATION_USER: &str = "system";
+
+pub struct ApplyIfrsValuationInteractor<R, Q, W, F, V>
 where
     R: EventRepository,
     Q: LedgerQueryService,
+    W: InventoryWorksheetRepository,
+    F: FinancialInstrumentRepository,
+    V: VoucherNumberGenerator,
 {
     event_repository: Arc<R>,
     ledger_query_service: Arc<Q>,
+    worksheet_repository: Arc<W>,
+    instrument_repository: Arc<F>,
+    voucher_generator: Arc<V>,
 }
 
-impl<R, Q> ApplyIfrsValuationInteractor<R, Q>
+impl<R, Q, W, F, V> ApplyIfrsValuationInteractor<R, Q, W, F, V>
 where
     R: EventRepository,
     Q: LedgerQueryService,
+    W: InventoryWorksheetRepository,
+    F: FinancialInstrumentRepository,
+    V: VoucherNumberGenerator,
 {
-    pub fn new(event_repository: Arc<R>, ledger_query_service: Arc<Q>) -> Self {
-        Self { event_repository, ledger_query_service }
+    pub fn new(
+        event_repository: Arc<R>,
+        ledger_query_service: Arc<Q>,
+        worksheet_repository: Arc<W>,
+        instrument_repository: Arc<F>,
+        voucher_generator: Arc<V>,
+    ) -> Self {
+        Self {
+            event_repository,
+            ledger_query_service,
+            worksheet_repository,
+            instrument_repository,
+            voucher_generator,
+        }
+    }
+
+    /// 評価処理の仕訳を起票し、仕訳IDを返す
+    async fn post_entry(
+        &self,
+        date: NaiveDate,
+        dtos: &[JournalEntryLineDto],
+    ) -> ApplicationResult<JournalEntryId> {
+        let lines: Vec<JournalEntryLine> =
+            dtos.iter().map(|dto| dto.try_into()).collect::<Result<_, _>>()?;
+        JournalEntryService::validate_balance(&lines).map_err(ApplicationError::DomainError)?;
+
+        let transaction_date = TransactionDate::new(date).map_err(ApplicationError::DomainError)?;
+
+        let voucher_number = self
+            .voucher_generator
+            .reserve(date.year() as u32)
+            .await
+            .map_err(ApplicationError::DomainError)?;
+
+        // 保存に失敗した場合は予約した伝票番号を取り消す
+        let entry_id = JournalEntryId::new(TimeOrderedIdGenerator.next_id());
+        let result: ApplicationResult<()> = async {
+            let journal_entry = JournalEntry::new(
+                entry_id.clone(),
+                transaction_date,
+                VoucherNumber::new(voucher_number.clone())
+                    .map_err(ApplicationError::DomainError)?,
+                lines,
+                UserId::new(VALUATION_USER.to_string()),
+            )
+            .map_err(ApplicationError::DomainError)?;
+
+            self.event_repository
+                .append_events(entry_id.value(), journal_entry.events().to_vec())
+                .await?;
+
+            Ok(())
+        }
+        .await;
+        self.voucher_generator.settle(&voucher_number, result).await?;
+
+        Ok(entry_id)
+    }
+
+    /// 棚卸資産評価ワークシートの評価減を仕訳として起票し、起票した仕訳を返す
+    ///
+    /// 起票済みのワークシートは再度起票しない。評価減がない場合は起票しない。
+    /// 試算の場合は起票予定の仕訳を返すのみで、仕訳・イベント・ワークシートを記録しない。
+    async fn post_inventory_write_down(
+        &self,
+        worksheet: &mut InventoryWriteDownWorksheet,
+        valuation_id: &str,
+        dry_run: bool,
+    ) -> ApplicationResult<Option<ClosingEntryDto>> {
+        let amount = worksheet.total_write_down();
+        if worksheet.is_posted() || amount <= 0.0 {
+            return Ok(None);
+        }
+
+        let date = period_end_date(worksheet.fiscal_year(), worksheet.period())?;
+        let description = format!("棚卸資産評価減 {}", worksheet.worksheet_id());
+        let line = |line_number: u32, side: &str, account_code: &str| JournalEntryLineDto {
+            line_number,
+            side: side.to_string(),
+            account_code: account_code.to_string(),
+            sub_account_code: None,
+            department_code: None,
+            dimensions: Default::default(),
+            amount,
+            currency: worksheet.currency().to_string(),
+            tax_type: "NonTaxable".to_string(),
+            tax_amount: 0.0,
+            description: Some(description.clone()),
+        };
+        let dtos = vec![
+            line(1, "Debit", INVENTORY_WRITE_DOWN_ACCOUNT_CODE),
+            line(2, "Credit", INVENTORY_ACCOUNT_CODE),
+        ];
+        if dry_run {
+            return Ok(Some(closing_entry(None, date, description, dtos)));
+        }
+        let entry_id = self.post_entry(date, &dtos).await?;
+
+        let inventory_valuation_id = format!("{}-INV", valuation_id);
+        self.event_repository
+            .append_events(
+                &inventory_valuation_id,
+                vec![ClosingEvent::IfrsValuationApplied {
+                    valuation_id: inventory_valuation_id.clone(),
+                    fiscal_year: worksheet.fiscal_year(),
+                    period: worksheet.period(),
+                    valuation_type: "InventoryWriteDown".to_string(),
+                    account_code: INVENTORY_ACCOUNT_CODE.to_string(),
+                    amount,
+                    currency: worksheet.currency().to_string(),
+                    applied_by: VALUATION_USER.to_string(),
+                    applied_at: Utc::now(),
+                }],
+            )
+            .await?;
+
+        // ワークシートを根拠資料として仕訳に紐付ける
+        worksheet.mark_posted(entry_id.value())?;
+        self.worksheet_repository.save(worksheet).await?;
+
+        Ok(Some(closing_entry(Some(&entry_id), date, description, dtos)))
+    }
+
+    /// 償却原価で測定する金融商品の当月の利息を見越計上し、見越計上と起票した仕訳を返す
+    ///
+    /// 償却期間外の金融商品と、当月を見越計上済みの金融商品は起票しない。
+    /// 試算の場合は起票予定の仕訳を返すのみで、仕訳・イベント・金融商品を記録しない。
+    async fn post_interest_accruals(
+        &self,
+        fiscal_year: i32,
+        period: u8,
+        valuation_id: &str,
+        dry_run: bool,
+    ) -> ApplicationResult<(Vec<InterestAccrualDto>, Vec<ClosingEntryDto>)> {
+        let date = period_end_date(fiscal_year, period)?;
+        let mut accruals = vec![];
+        let mut entries = vec![];
+
+        for mut instrument in self.instrument_repository.find_all().await? {
+            let Some(schedule) = instrument.schedule_for(fiscal_year, period) else {
+                continue;
+            };
+            if instrument.accrued_entry_id(fiscal_year, period).is_some()
+                || schedule.effective_interest == 0.0
+            {
+                continue;
+            }
+
+            let dtos = accrual_lines(
+                &instrument,
+                schedule.effective_interest,
+                schedule.coupon_interest,
+                schedule.amortization,
+            );
+            let accrual = InterestAccrualDto {
+                instrument_id: instrument.instrument_id().to_string(),
+                instrument_name: instrument.name().to_string(),
+                effective_interest: schedule.effective_interest,
+                coupon_interest: schedule.coupon_interest,
+                amortization: schedule.amortization,
+                currency: instrument.currency().to_string(),
+                entry_id: String::new(),
+            };
+            let description = format!("利息見越計上 {}", instrument.name());
+            if dry_run {
+                entries.push(closing_entry(None, date, description, dtos));
+                accruals.push(accrual);
+                continue;
+            }
+            let entry_id = self.post_entry(date, &dtos).await?;
+
+            let accrual_id = format!("{}-INT-{}", valuation_id, instrument.instrument_id());
+            self.event_repository
+                .append_events(
+                    &accrual_id,
+                    vec![ClosingEvent::IfrsValuationApplied {
+                        valuation_id: accrual_id.clone(),
+                        fiscal_year,
+                        period,
+                        valuation_type: "InterestAccrual".to_string(),
+                        account_code: instrument.account_code().to_string(),
+                        amount: schedule.effective_interest,
+                        currency: instrument.currency().to_string(),
+                        applied_by: VALUATION_USER.to_string(),
+                        applied_at: Utc::now(),
+                    }],
+                )
+                .await?;
+
+            instrument.mark_accrued(fiscal_year, period, entry_id.value())?;
+            self.instrument_repository.save(&instrument).await?;
+
+            accruals.push(InterestAccrualDto { entry_id: entry_id.value().to_string(), ..accrual });
+            entries.push(closing_entry(Some(&entry_id), date, description, dtos));
+        }
+
+        Ok((accruals, entries))
     }
 }
 
-impl<R, Q> ApplyIfrsValuationUseCase for ApplyIfrsValuationInteractor<R, Q>
+impl<R, Q, W, F, V> ApplyIfrsValuationUseCase for ApplyIfrsValuationInteractor<R, Q, W, F, V>
 where
     R: EventRepository,
     Q: LedgerQueryService,
+    W: InventoryWorksheetRepository,
+    F: FinancialInstrumentRepository,
+    V: VoucherNumberGenerator,
 {
     async fn execute(
         &self,
         request: ApplyIfrsValuationRequest,
     ) -> ApplicationResult<ApplyIfrsValuationResponse> {
         // 試算表を取得してIFRS評価対象を特定
-        let _trial_balance = self
+        let trial_balance = self
             .ledger_query_service
             .get_trial_balance(GetTrialBalanceQuery {
                 period_year: request.fiscal_year as u32,
                 period_month: request.period,
+                status_scope: EntryStatusScope::PostedOnly,
             })
             .await?;
 
@@ -62,20 +306,177 @@ where
             account_code: "1100".to_string(), // 売掛金
             amount: 50000.0,
             currency: "JPY".to_string(),
-            applied_by: "system".to_string(),
+            applied_by: VALUATION_USER.to_string(),
             applied_at: Utc::now(),
         }];
 
-        self.event_repository.append_events(&valuation_id, events).await?;
+        // 試算の場合はイベントを記録しない
+        if !request.dry_run {
+            self.event_repository.append_events(&valuation_id, events).await?;
+        }
+
+        // 棚卸資産の評価減（ワークシートが取り込まれている場合のみ）
+        let mut inventory_write_downs = vec![];
+        let mut entries = vec![];
+        if let Some(mut worksheet) =
+            self.worksheet_repository.find(request.fiscal_year, request.period).await?
+        {
+            entries.extend(
+                self.post_inventory_write_down(&mut worksheet, &valuation_id, request.dry_run)
+                    .await?,
+            );
+            inventory_write_downs = InventoryWriteDownDto::from_worksheet(&worksheet);
+        }
+
+        // 償却原価で測定する金融商品の利息の見越計上
+        let (interest_accruals, accrual_entries) = self
+            .post_interest_accruals(
+                request.fiscal_year,
+                request.period,
+                &valuation_id,
+                request.dry_run,
+            )
+            .await?;
+        entries.extend(accrual_entries);
+        let trial_balance_impact = TrialBalanceImpactDto::simulate(&trial_balance, &entries);
 
         Ok(ApplyIfrsValuationResponse {
             expected_credit_loss: 50000.0,
             expected_credit_loss_currency: "JPY".to_string(),
             contingent_liabilities: vec![],
-            inventory_write_downs: vec![],
+            inventory_write_downs,
             impairment_losses: vec![],
             fair_value_adjustments: vec![],
             lease_measurements: vec![],
+            interest_accruals,
+            dry_run: request.dry_run,
+            entries,
+            trial_balance_impact,
+        })
+    }
+}
+
+/// 起票した（試算の場合は起票予定の）仕訳
+fn closing_entry(
+    entry_id: Option<&JournalEntryId>,
+    date: NaiveDate,
+    description: String,
+    lines: Vec<JournalEntryLineDto>,
+) -> ClosingEntryDto {
+    ClosingEntryDto {
+        entry_id: entry_id.map(|id| id.value().to_string()),
+        transaction_date: date.format("%Y-%m-%d").to_string(),
+        description,
+        lines,
+    }
+}
+
+/// 利息の見越計上仕訳の明細
+///
+/// 金融資産は「未収収益 + 償却額 = 受取利息」、金融負債は「支払利息 = 未払費用 + 償却額」。
+/// 償却額が負（プレミアム）の場合は帳簿価額の勘定の貸借が逆になり、金額が0の明細は作らない。
+fn accrual_lines(
+    instrument: &FinancialInstrument,
+    effective_interest: f64,
+    coupon_interest: f64,
+    amortization: f64,
+) -> Vec<JournalEntryLineDto> {
+    // 借方を正とした金額
+    let signed_amounts = match instrument.position() {
+        InstrumentPosition::Asset => [
+            (ACCRUED_INTEREST_RECEIVABLE_ACCOUNT_CODE, coupon_interest),
+            (instrument.account_code(), amortization),
+            (INTEREST_INCOME_ACCOUNT_CODE, -effective_interest),
+        ],
+        InstrumentPosition::Liability => [
+            (INTEREST_EXPENSE_ACCOUNT_CODE, effective_interest),
+            (ACCRUED_INTEREST_PAYABLE_ACCOUNT_CODE, -coupon_interest),
+            (instrument.account_code(), -amortization),
+        ],
+    };
+    let description = format!("利息見越計上 {}", instrument.name());
+
+    signed_amounts
+        .into_iter()
+        .filter(|(_, amount)| *amount != 0.0)
+        .enumerate()
+        .map(|(index, (account_code, amount))| JournalEntryLineDto {
+            line_number: index as u32 + 1,
+            side: if amount > 0.0 { "Debit" } else { "Credit" }.to_string(),
+            account_code: account_code.to_string(),
+            sub_account_code: None,
+            department_code: None,
+            dimensions: Default::default(),
+            amount: amount.abs(),
+            currency: instrument.currency().to_string(),
+            tax_type: "NonTaxable".to_string(),
+            tax_amount: 0.0,
+            description: Some(description.clone()),
         })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use javelin_domain::financial_close::financial_instrument::InstrumentKind;
+
+    use super::*;
+
+    fn instrument(position: InstrumentPosition, account_code: &str) -> FinancialInstrument {
+        FinancialInstrument::new(
+            "FI-001",
+            "第1回社債",
+            InstrumentKind::Bond,
+            position,
+            account_code,
+            "JPY",
+            1_000_000.0,
+            980_000.0,
+            0.01,
+            0.0305,
+            NaiveDate::from_ymd_opt(2024, 11, 1).unwrap(),
+            NaiveDate::from_ymd_opt(2025, 11, 1).unwrap(),
+        )
+        .unwrap()
+    }
+
+    fn summary(lines: &[JournalEntryLineDto]) -> Vec<(&str, &str, f64)> {
+        lines
+            .iter()
+            .map(|line| (line.side.as_str(), line.account_code.as_str(), line.amount))
+            .collect()
+    }
+
+    #[test]
+    fn test_accrual_lines_for_discount_asset() {
+        let lines =
+            accrual_lines(&instrument(InstrumentPosition::Asset, "1500"), 2491.0, 833.0, 1658.0);
+        assert_eq!(
+            summary(&lines),
+            vec![
+                ("Debit", ACCRUED_INTEREST_RECEIVABLE_ACCOUNT_CODE, 833.0),
+                ("Debit", "1500", 1658.0),
+                ("Credit", INTEREST_INCOME_ACCOUNT_CODE, 2491.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_accrual_lines_for_premium_liability_and_zero_coupon() {
+        let liability = instrument(InstrumentPosition::Liability, "2500");
+
+        let premium = accrual_lines(&liability, 1683.0, 2500.0, -817.0);
+        assert_eq!(
+            summary(&premium),
+            vec![
+                ("Debit", INTEREST_EXPENSE_ACCOUNT_CODE, 1683.0),
+                ("Credit", ACCRUED_INTEREST_PAYABLE_ACCOUNT_CODE, 2500.0),
+                ("Debit", "2500", 817.0),
+            ]
+        );
+
+        let zero_coupon = accrual_lines(&liability, 1200.0, 0.0, 1200.0);
+        assert_eq!(zero_coupon.len(), 2);
+        assert_eq!(zero_coupon[1].line_number, 2);
     }
 }
@@ -7,7 +7,7 @@ use crate::{
     dtos::{ConsolidateLedgerRequest, ConsolidateLedgerResponse},
     error::ApplicationResult,
     input_ports::ConsolidateLedgerUseCase,
-    query_service::ledger_query_service::{GetLedgerQuery, LedgerQueryService},
+    query_service::ledger_query_service::{EntryStatusScope, GetLedgerQuery, LedgerQueryService},
 };
 
 pub struct ConsolidateLedgerInteractor<Q>
@@ -43,6 +43,9 @@ where
                 to_date: Some(request.to_date.clone()),
                 limit: None,
                 offset: None,
+                status_scope: EntryStatusScope::PostedOnly,
+                flagged_only: false,
+                dimensions: Default::default(),
             })
             .await?;
 
@@ -1,111 +1,703 @@
 // GenerateFinancialStatementsInteractor - 財務諸表生成処理
 // 責務: 制度開示資料作成
 
-use std::sync::Arc;
+use std::{
+    collections::{BTreeSet, HashMap},
+    sync::Arc,
+};
+
+use javelin_domain::{
+    financial_close::journal_entry::values::Currency,
+    masters::{NoteCrossReference, NoteReferenceTarget, StatementLine},
+    repositories::{
+        AccountingPolicyRepository, NoteCrossReferenceRepository, StatementLineMappingRepository,
+        VarianceCommentaryRepository,
+    },
+};
 
 use crate::{
     dtos::{
         FinancialIndicatorsDto, GenerateFinancialStatementsRequest,
-        GenerateFinancialStatementsResponse, StatementOfCashFlowsDto,
-        StatementOfChangesInEquityDto, StatementOfFinancialPositionDto, StatementOfProfitOrLossDto,
+        GenerateFinancialStatementsResponse, StatementOfChangesInEquityDto,
+        StatementOfFinancialPositionDto, StatementOfProfitOrLossDto,
+        response::{
+            FINANCIAL_STATEMENTS_REPORT_TYPE, ReportDocument, StatementCaptionDto,
+            StatementCommentaryDto,
+        },
     },
-    error::ApplicationResult,
+    error::{ApplicationError, ApplicationResult},
     input_ports::GenerateFinancialStatementsUseCase,
-    query_service::ledger_query_service::{GetTrialBalanceQuery, LedgerQueryService},
+    interactor::audit_export_interactor::csv_row,
+    output_port::ReportDeliveryOutputPort,
+    query_service::ledger_query_service::{
+        EntryStatusScope, GetTrialBalanceQuery, LedgerQueryService, TrialBalanceEntry,
+    },
 };
 
-pub struct GenerateFinancialStatementsInteractor<Q>
+/// 財務諸表の表示通貨
+const STATEMENT_CURRENCY: &str = "JPY";
+
+/// 貸借一致判定の許容誤差
+const BALANCE_TOLERANCE: f64 = 0.5;
+
+pub struct GenerateFinancialStatementsInteractor<Q, M, P, R, N, C>
 where
     Q: LedgerQueryService,
+    M: StatementLineMappingRepository,
+    P: AccountingPolicyRepository,
+    R: ReportDeliveryOutputPort,
+    N: NoteCrossReferenceRepository,
+    C: VarianceCommentaryRepository,
 {
     ledger_query_service: Arc<Q>,
+    mapping_repository: Arc<M>,
+    policy_repository: Arc<P>,
+    delivery: Arc<R>,
+    note_repository: Arc<N>,
+    commentary_repository: Arc<C>,
 }
 
-impl<Q> GenerateFinancialStatementsInteractor<Q>
+impl<Q, M, P, R, N, C> GenerateFinancialStatementsInteractor<Q, M, P, R, N, C>
 where
     Q: LedgerQueryService,
+    M: StatementLineMappingRepository,
+    P: AccountingPolicyRepository,
+    R: ReportDeliveryOutputPort,
+    N: NoteCrossReferenceRepository,
+    C: VarianceCommentaryRepository,
 {
-    pub fn new(ledger_query_service: Arc<Q>) -> Self {
-        Self { ledger_query_service }
+    pub fn new(
+        ledger_query_service: Arc<Q>,
+        mapping_repository: Arc<M>,
+        policy_repository: Arc<P>,
+        delivery: Arc<R>,
+        note_repository: Arc<N>,
+        commentary_repository: Arc<C>,
+    ) -> Self {
+        Self {
+            ledger_query_service,
+            mapping_repository,
+            policy_repository,
+            delivery,
+            note_repository,
+            commentary_repository,
+        }
+    }
+}
+
+/// 注記参照が表示される表示科目（勘定科目からの参照は割り当てた表示科目に集約する）
+fn referenced_line(
+    reference: &NoteCrossReference,
+    mappings: &HashMap<String, StatementLine>,
+) -> Option<StatementLine> {
+    match reference.target() {
+        NoteReferenceTarget::Caption(line) => Some(*line),
+        NoteReferenceTarget::Account(code) => mappings.get(code.value()).copied(),
+    }
+}
+
+/// 配信用の財務諸表（表示科目と金額、CSV）
+///
+/// 試算表の増減コメントがある場合は、空行に続けて別紙として添付する。
+fn delivery_document(
+    request: &GenerateFinancialStatementsRequest,
+    statements: &GenerateFinancialStatementsResponse,
+) -> ReportDocument {
+    let mut content = csv_row(&["表示科目".to_string(), "金額".to_string()]);
+    for line in statements.report_lines() {
+        content.push_str(&csv_row(&[line.label, format!("{:.0}", line.amount)]));
+    }
+    if !statements.variance_commentaries.is_empty() {
+        content.push_str("\r\n");
+        content.push_str(&csv_row(&["別紙 増減コメント".to_string()]));
+        content.push_str(&csv_row(&[
+            "勘定科目コード".to_string(),
+            "勘定科目名".to_string(),
+            "コメント".to_string(),
+            "記入者".to_string(),
+            "記入日時".to_string(),
+        ]));
+        for commentary in &statements.variance_commentaries {
+            content.push_str(&csv_row(&[
+                commentary.account_code.clone(),
+                commentary.account_name.clone(),
+                commentary.commentary.clone(),
+                commentary.written_by.clone(),
+                commentary.written_at.clone(),
+            ]));
+        }
+    }
+
+    ReportDocument {
+        report_type: FINANCIAL_STATEMENTS_REPORT_TYPE.to_string(),
+        fiscal_year: request.fiscal_year,
+        period: request.period,
+        file_name: format!(
+            "financial_statements_{:04}{:02}.csv",
+            request.fiscal_year, request.period
+        ),
+        content,
+    }
+}
+
+/// 表示科目別の集計額（表示符号）
+#[derive(Default)]
+struct StatementLineTotals {
+    closing: HashMap<StatementLine, f64>,
+    opening: HashMap<StatementLine, f64>,
+}
+
+impl StatementLineTotals {
+    fn add(&mut self, line: StatementLine, entry: &TrialBalanceEntry) {
+        *self.closing.entry(line).or_default() += line.presented_amount(entry.closing_balance);
+        *self.opening.entry(line).or_default() += line.presented_amount(entry.opening_balance);
+    }
+
+    fn closing(&self, line: StatementLine) -> f64 {
+        self.closing.get(&line).copied().unwrap_or_default()
+    }
+
+    fn opening(&self, line: StatementLine) -> f64 {
+        self.opening.get(&line).copied().unwrap_or_default()
     }
 }
 
-impl<Q> GenerateFinancialStatementsUseCase for GenerateFinancialStatementsInteractor<Q>
+fn ratio(numerator: f64, denominator: f64) -> f64 {
+    if denominator.abs() < f64::EPSILON {
+        0.0
+    } else {
+        numerator / denominator
+    }
+}
+
+impl<Q, M, P, R, N, C> GenerateFinancialStatementsUseCase
+    for GenerateFinancialStatementsInteractor<Q, M, P, R, N, C>
 where
     Q: LedgerQueryService,
+    M: StatementLineMappingRepository,
+    P: AccountingPolicyRepository,
+    R: ReportDeliveryOutputPort,
+    N: NoteCrossReferenceRepository,
+    C: VarianceCommentaryRepository,
 {
     async fn execute(
         &self,
         request: GenerateFinancialStatementsRequest,
     ) -> ApplicationResult<GenerateFinancialStatementsResponse> {
-        // 試算表を取得して財務諸表を生成
         let trial_balance = self
             .ledger_query_service
             .get_trial_balance(GetTrialBalanceQuery {
                 period_year: request.fiscal_year as u32,
                 period_month: request.period,
+                status_scope: EntryStatusScope::PostedOnly,
             })
             .await?;
 
-        // 実装: 試算表から財務諸表を生成
-        let total_assets = trial_balance.total_debit;
-        let total_liabilities = trial_balance.total_credit * 0.5;
-        let equity = total_assets - total_liabilities;
+        let mappings: HashMap<String, StatementLine> = self
+            .mapping_repository
+            .find_all()
+            .await?
+            .into_iter()
+            .map(|mapping| (mapping.account_code().value().to_string(), mapping.statement_line()))
+            .collect();
+
+        // 表示科目が未設定の勘定科目があれば生成しない
+        let unmapped: Vec<String> = trial_balance
+            .entries
+            .iter()
+            .filter(|entry| !mappings.contains_key(&entry.account_code))
+            .map(|entry| {
+                format!(
+                    "勘定科目 {} {} の財務諸表表示科目が未設定です",
+                    entry.account_code, entry.account_name
+                )
+            })
+            .collect();
+        if !unmapped.is_empty() {
+            return Err(ApplicationError::ValidationFailed(unmapped));
+        }
+
+        // 注記の作成が必須の参照先に、作成済みの注記がなければ生成しない
+        let sections = self.note_repository.find_sections().await?;
+        let references = self.note_repository.find_references().await?;
+        let missing_notes: Vec<String> = references
+            .iter()
+            .filter(|reference| !reference.is_satisfied_by(&sections))
+            .map(|reference| {
+                format!(
+                    "{} に必要な注記{}が作成されていません",
+                    reference.target().label(),
+                    reference.note_number()
+                )
+            })
+            .collect();
+        if !missing_notes.is_empty() {
+            return Err(ApplicationError::ValidationFailed(missing_notes));
+        }
+
+        let mut note_numbers: HashMap<StatementLine, BTreeSet<u32>> = HashMap::new();
+        for reference in &references {
+            let registered =
+                sections.iter().any(|section| section.number() == reference.note_number());
+            if let Some(line) = referenced_line(reference, &mappings).filter(|_| registered) {
+                note_numbers.entry(line).or_default().insert(reference.note_number());
+            }
+        }
+
+        let mut totals = StatementLineTotals::default();
+        for entry in &trial_balance.entries {
+            totals.add(mappings[&entry.account_code], entry);
+        }
+
+        // 表示科目ごとに会計方針の端数処理を行い、小計・合計は丸めた金額から算出する
+        let policy = self.policy_repository.load().await?;
+        let statement_currency = STATEMENT_CURRENCY
+            .parse::<Currency>()
+            .map_err(|e| ApplicationError::ValidationFailed(vec![e]))?;
+        let closing = |line| policy.round_amount(&statement_currency, totals.closing(line));
+        let opening = |line| policy.round_amount(&statement_currency, totals.opening(line));
+
+        // 損益計算書
+        let revenue = closing(StatementLine::Revenue);
+        let cost_of_sales = closing(StatementLine::CostOfSales);
+        let gross_profit = revenue - cost_of_sales;
+        let operating_expenses = closing(StatementLine::OperatingExpenses);
+        let operating_profit = gross_profit - operating_expenses;
+        let net_profit = operating_profit + closing(StatementLine::NonOperatingIncome)
+            - closing(StatementLine::NonOperatingExpenses);
+
+        // 財政状態計算書（当期純利益は未振替のため資本に含める）
+        let current_assets = closing(StatementLine::CurrentAssets);
+        let non_current_assets = closing(StatementLine::NonCurrentAssets);
+        let current_liabilities = closing(StatementLine::CurrentLiabilities);
+        let non_current_liabilities = closing(StatementLine::NonCurrentLiabilities);
+        let opening_equity = opening(StatementLine::Equity);
+        let equity = closing(StatementLine::Equity) + net_profit;
 
-        Ok(GenerateFinancialStatementsResponse {
+        let total_assets = current_assets + non_current_assets;
+        let total_liabilities = current_liabilities + non_current_liabilities;
+        let cross_check_passed =
+            (total_assets - (total_liabilities + equity)).abs() < BALANCE_TOLERANCE;
+
+        let currency = || STATEMENT_CURRENCY.to_string();
+
+        // 別紙に添付する増減コメント（科目名は試算表から補う）
+        let variance_commentaries = self
+            .commentary_repository
+            .find_by_period(request.fiscal_year, request.period)
+            .await?
+            .iter()
+            .map(|commentary| StatementCommentaryDto {
+                account_code: commentary.account_code().to_string(),
+                account_name: trial_balance
+                    .entries
+                    .iter()
+                    .find(|entry| entry.account_code == commentary.account_code())
+                    .map(|entry| entry.account_name.clone())
+                    .unwrap_or_default(),
+                commentary: commentary.commentary().to_string(),
+                written_by: commentary.written_by().to_string(),
+                written_at: commentary.written_at().to_string(),
+            })
+            .collect();
+
+        // 資本は当期純利益を含めた金額を表示する
+        let statement_captions = StatementLine::ALL
+            .into_iter()
+            .map(|line| StatementCaptionDto {
+                statement_line: line.code().to_string(),
+                caption: line.display_name().to_string(),
+                amount: if line == StatementLine::Equity {
+                    equity
+                } else {
+                    closing(line)
+                },
+                note_numbers: note_numbers
+                    .get(&line)
+                    .map(|numbers| numbers.iter().copied().collect())
+                    .unwrap_or_default(),
+            })
+            .collect();
+
+        let mut statements = GenerateFinancialStatementsResponse {
             statement_of_financial_position: StatementOfFinancialPositionDto {
-                current_assets: total_assets * 0.5,
-                current_assets_currency: "JPY".to_string(),
-                non_current_assets: total_assets * 0.5,
-                non_current_assets_currency: "JPY".to_string(),
-                current_liabilities: total_liabilities * 0.6,
-                current_liabilities_currency: "JPY".to_string(),
-                non_current_liabilities: total_liabilities * 0.4,
-                non_current_liabilities_currency: "JPY".to_string(),
+                current_assets,
+                current_assets_currency: currency(),
+                non_current_assets,
+                non_current_assets_currency: currency(),
+                current_liabilities,
+                current_liabilities_currency: currency(),
+                non_current_liabilities,
+                non_current_liabilities_currency: currency(),
                 equity,
-                equity_currency: "JPY".to_string(),
+                equity_currency: currency(),
             },
             statement_of_profit_or_loss: StatementOfProfitOrLossDto {
-                revenue: 20000000.0,
-                revenue_currency: "JPY".to_string(),
-                cost_of_sales: 12000000.0,
-                cost_of_sales_currency: "JPY".to_string(),
-                gross_profit: 8000000.0,
-                gross_profit_currency: "JPY".to_string(),
-                operating_expenses: 5000000.0,
-                operating_expenses_currency: "JPY".to_string(),
-                operating_profit: 3000000.0,
-                operating_profit_currency: "JPY".to_string(),
-                net_profit: 2000000.0,
-                net_profit_currency: "JPY".to_string(),
+                revenue,
+                revenue_currency: currency(),
+                cost_of_sales,
+                cost_of_sales_currency: currency(),
+                gross_profit,
+                gross_profit_currency: currency(),
+                operating_expenses,
+                operating_expenses_currency: currency(),
+                operating_profit,
+                operating_profit_currency: currency(),
+                net_profit,
+                net_profit_currency: currency(),
             },
             statement_of_changes_in_equity: StatementOfChangesInEquityDto {
-                opening_balance: equity * 0.9,
-                opening_balance_currency: "JPY".to_string(),
-                net_profit: 2000000.0,
-                net_profit_currency: "JPY".to_string(),
-                dividends: 1000000.0,
-                dividends_currency: "JPY".to_string(),
+                opening_balance: opening_equity,
+                opening_balance_currency: currency(),
+                net_profit,
+                net_profit_currency: currency(),
+                dividends: 0.0,
+                dividends_currency: currency(),
                 closing_balance: equity,
-                closing_balance_currency: "JPY".to_string(),
-            },
-            statement_of_cash_flows: StatementOfCashFlowsDto {
-                operating_activities: 3000000.0,
-                operating_activities_currency: "JPY".to_string(),
-                investing_activities: -1000000.0,
-                investing_activities_currency: "JPY".to_string(),
-                financing_activities: -500000.0,
-                financing_activities_currency: "JPY".to_string(),
-                net_change_in_cash: 1500000.0,
-                net_change_in_cash_currency: "JPY".to_string(),
+                closing_balance_currency: currency(),
             },
+            // 現金及び現金同等物は流動資産に含まれ、表示科目の残高からは増減を求められない
+            statement_of_cash_flows: None,
             financial_indicators: FinancialIndicatorsDto {
-                roe: 0.286,
-                roa: 0.133,
-                current_ratio: 1.67,
-                debt_to_equity_ratio: 1.14,
+                roe: ratio(net_profit, equity),
+                roa: ratio(net_profit, total_assets),
+                current_ratio: ratio(current_assets, current_liabilities),
+                debt_to_equity_ratio: ratio(total_liabilities, equity),
             },
-            cross_check_passed: true,
-        })
+            cross_check_passed,
+            statement_captions,
+            variance_commentaries,
+            deliveries: vec![],
+        };
+
+        statements.deliveries =
+            self.delivery.deliver(&delivery_document(&request, &statements)).await;
+        Ok(statements)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use javelin_domain::{
+        error::DomainResult,
+        financial_close::variance_commentary::VarianceCommentary,
+        masters::{
+            AccountCode, AccountingPolicy, AccountingPolicyChanged, NoteSection,
+            StatementLineMapping,
+        },
+    };
+
+    use super::*;
+    use crate::{
+        dtos::response::ReportDeliveryResultDto,
+        interactor::{
+            closing::variance_commentary_interactor::tests::InMemoryVarianceCommentaryRepository,
+            note_cross_reference_interactor::tests::InMemoryNoteCrossReferenceRepository,
+        },
+        testkit::InMemoryLedgerQueryService,
+    };
+
+    /// 2024年3月の試算表を返す元帳照会サービス
+    fn ledger(entries: Vec<TrialBalanceEntry>) -> InMemoryLedgerQueryService {
+        InMemoryLedgerQueryService::new().with_trial_balance(2024, 3, entries)
+    }
+
+    struct MockMappingRepository {
+        mappings: Mutex<Vec<StatementLineMapping>>,
+    }
+
+    impl MockMappingRepository {
+        fn new(mappings: &[(&str, StatementLine)]) -> Self {
+            let mappings = mappings
+                .iter()
+                .map(|(code, line)| {
+                    StatementLineMapping::new(AccountCode::new(*code).unwrap(), *line)
+                })
+                .collect();
+            Self { mappings: Mutex::new(mappings) }
+        }
+    }
+
+    impl StatementLineMappingRepository for MockMappingRepository {
+        async fn find_by_account(
+            &self,
+            account_code: &AccountCode,
+        ) -> DomainResult<Option<StatementLineMapping>> {
+            let mappings = self.mappings.lock().unwrap();
+            Ok(mappings.iter().find(|m| m.account_code() == account_code).cloned())
+        }
+
+        async fn find_all(&self) -> DomainResult<Vec<StatementLineMapping>> {
+            Ok(self.mappings.lock().unwrap().clone())
+        }
+
+        async fn save(&self, mapping: &StatementLineMapping) -> DomainResult<()> {
+            self.mappings.lock().unwrap().push(mapping.clone());
+            Ok(())
+        }
+
+        async fn delete(&self, account_code: &AccountCode) -> DomainResult<()> {
+            self.mappings.lock().unwrap().retain(|m| m.account_code() != account_code);
+            Ok(())
+        }
+    }
+
+    /// 既定の会計方針を返すモック
+    struct MockPolicyRepository;
+
+    impl AccountingPolicyRepository for MockPolicyRepository {
+        async fn load(&self) -> DomainResult<AccountingPolicy> {
+            Ok(AccountingPolicy::default())
+        }
+
+        async fn save(
+            &self,
+            _policy: &AccountingPolicy,
+            _change: &AccountingPolicyChanged,
+        ) -> DomainResult<()> {
+            Ok(())
+        }
+
+        async fn find_changes(&self) -> DomainResult<Vec<AccountingPolicyChanged>> {
+            Ok(vec![])
+        }
+    }
+
+    /// 配信先が未設定の配信（テスト用）
+    struct NoDelivery;
+
+    impl ReportDeliveryOutputPort for NoDelivery {
+        async fn deliver(&self, _document: &ReportDocument) -> Vec<ReportDeliveryResultDto> {
+            vec![]
+        }
+    }
+
+    fn entry(account_code: &str, opening: f64, debit: f64, credit: f64) -> TrialBalanceEntry {
+        TrialBalanceEntry {
+            account_code: account_code.to_string(),
+            account_name: format!("勘定科目{}", account_code),
+            opening_balance: opening,
+            debit_amount: debit,
+            credit_amount: credit,
+            closing_balance: opening + debit - credit,
+        }
+    }
+
+    fn request() -> GenerateFinancialStatementsRequest {
+        GenerateFinancialStatementsRequest { fiscal_year: 2024, period: 3 }
+    }
+
+    #[tokio::test]
+    async fn test_statements_follow_mapping() {
+        let service = ledger(vec![
+            entry("1100", 1000.0, 800.0, 300.0),
+            entry("2000", 0.0, 0.0, 200.0),
+            entry("3000", -1000.0, 0.0, 0.0),
+            entry("4000", 0.0, 0.0, 800.0),
+            entry("5000", 0.0, 500.0, 0.0),
+        ]);
+        let repository = MockMappingRepository::new(&[
+            ("1100", StatementLine::CurrentAssets),
+            ("2000", StatementLine::CurrentLiabilities),
+            ("3000", StatementLine::Equity),
+            ("4000", StatementLine::Revenue),
+            ("5000", StatementLine::CostOfSales),
+        ]);
+        let interactor = GenerateFinancialStatementsInteractor::new(
+            Arc::new(service),
+            Arc::new(repository),
+            Arc::new(MockPolicyRepository),
+            Arc::new(NoDelivery),
+            Arc::new(InMemoryNoteCrossReferenceRepository::default()),
+            Arc::new(InMemoryVarianceCommentaryRepository::default()),
+        );
+
+        let response = interactor.execute(request()).await.unwrap();
+
+        let pl = &response.statement_of_profit_or_loss;
+        assert_eq!(pl.revenue, 800.0);
+        assert_eq!(pl.cost_of_sales, 500.0);
+        assert_eq!(pl.net_profit, 300.0);
+        let bs = &response.statement_of_financial_position;
+        assert_eq!(bs.current_assets, 1500.0);
+        assert_eq!(bs.current_liabilities, 200.0);
+        assert_eq!(bs.equity, 1300.0);
+        assert_eq!(response.statement_of_changes_in_equity.opening_balance, 1000.0);
+        assert!(response.cross_check_passed);
+        assert!(response.statement_of_cash_flows.is_none());
+        assert!(
+            !response
+                .report_lines()
+                .iter()
+                .any(|line| line.label.starts_with("キャッシュ・フロー計算書"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_unmapped_accounts_block_generation() {
+        let service = ledger(vec![entry("1100", 0.0, 100.0, 0.0), entry("6100", 0.0, 0.0, 100.0)]);
+        let repository = MockMappingRepository::new(&[("1100", StatementLine::CurrentAssets)]);
+        let interactor = GenerateFinancialStatementsInteractor::new(
+            Arc::new(service),
+            Arc::new(repository),
+            Arc::new(MockPolicyRepository),
+            Arc::new(NoDelivery),
+            Arc::new(InMemoryNoteCrossReferenceRepository::default()),
+            Arc::new(InMemoryVarianceCommentaryRepository::default()),
+        );
+
+        match interactor.execute(request()).await {
+            Err(ApplicationError::ValidationFailed(errors)) => {
+                assert_eq!(errors.len(), 1);
+                assert!(errors[0].contains("6100"));
+            }
+            other => panic!("unexpected result: {:?}", other.map(|_| ())),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_statement_lines_are_rounded_by_accounting_policy() {
+        let service = ledger(vec![
+            entry("1100", 0.0, 1000.5, 0.0),
+            entry("4000", 0.0, 0.0, 800.4),
+            entry("5000", 0.0, 0.0, 200.1),
+        ]);
+        let repository = MockMappingRepository::new(&[
+            ("1100", StatementLine::CurrentAssets),
+            ("4000", StatementLine::Revenue),
+            ("5000", StatementLine::NonOperatingIncome),
+        ]);
+        let interactor = GenerateFinancialStatementsInteractor::new(
+            Arc::new(service),
+            Arc::new(repository),
+            Arc::new(MockPolicyRepository),
+            Arc::new(NoDelivery),
+            Arc::new(InMemoryNoteCrossReferenceRepository::default()),
+            Arc::new(InMemoryVarianceCommentaryRepository::default()),
+        );
+
+        let response = interactor.execute(request()).await.unwrap();
+
+        // 円は整数に四捨五入し、利益は丸めた表示科目から算出する
+        let pl = &response.statement_of_profit_or_loss;
+        assert_eq!(pl.revenue, 800.0);
+        assert_eq!(pl.net_profit, 1000.0);
+        assert_eq!(response.statement_of_financial_position.current_assets, 1001.0);
+    }
+
+    #[tokio::test]
+    async fn test_required_notes_block_generation_and_numbers_follow_captions() {
+        let entries = vec![entry("1100", 0.0, 1000.0, 0.0), entry("4000", 0.0, 0.0, 1000.0)];
+        let notes = Arc::new(InMemoryNoteCrossReferenceRepository::default());
+        notes.save_section(&NoteSection::new(3, "収益認識", "").unwrap()).await.unwrap();
+        notes
+            .save_section(&NoteSection::new(7, "金融資産", "償却原価で測定").unwrap())
+            .await
+            .unwrap();
+        let revenue = NoteReferenceTarget::Caption(StatementLine::Revenue);
+        let receivables = NoteReferenceTarget::parse("1100").unwrap();
+        notes
+            .save_reference(&NoteCrossReference::new(revenue, 3, true).unwrap())
+            .await
+            .unwrap();
+        notes
+            .save_reference(&NoteCrossReference::new(receivables, 7, false).unwrap())
+            .await
+            .unwrap();
+
+        let interactor = GenerateFinancialStatementsInteractor::new(
+            Arc::new(ledger(entries)),
+            Arc::new(MockMappingRepository::new(&[
+                ("1100", StatementLine::CurrentAssets),
+                ("4000", StatementLine::Revenue),
+            ])),
+            Arc::new(MockPolicyRepository),
+            Arc::new(NoDelivery),
+            Arc::clone(&notes),
+            Arc::new(InMemoryVarianceCommentaryRepository::default()),
+        );
+
+        // 必須の注記が未作成のため生成しない
+        match interactor.execute(request()).await {
+            Err(ApplicationError::ValidationFailed(errors)) => {
+                assert_eq!(errors, vec!["売上収益 に必要な注記3が作成されていません"]);
+            }
+            other => panic!("unexpected result: {:?}", other.map(|_| ())),
+        }
+
+        notes
+            .save_section(&NoteSection::new(3, "収益認識", "履行義務の充足時に認識").unwrap())
+            .await
+            .unwrap();
+        let response = interactor.execute(request()).await.unwrap();
+
+        let caption = |code: &str| {
+            response.statement_captions.iter().find(|c| c.statement_line == code).unwrap()
+        };
+        assert_eq!(caption("PL-REV").note_numbers, vec![3]);
+        assert_eq!(caption("PL-REV").amount, 1000.0);
+        // 勘定科目からの参照は割り当てた表示科目に表示する
+        assert_eq!(caption("BS-CA").note_numbers, vec![7]);
+        assert!(caption("BS-CL").note_numbers.is_empty());
+    }
+
+    /// 配信した帳票を記録する配信（テスト用）
+    #[derive(Default)]
+    struct CapturingDelivery {
+        documents: Mutex<Vec<ReportDocument>>,
+    }
+
+    impl ReportDeliveryOutputPort for CapturingDelivery {
+        async fn deliver(&self, document: &ReportDocument) -> Vec<ReportDeliveryResultDto> {
+            self.documents.lock().unwrap().push(document.clone());
+            vec![]
+        }
+    }
+
+    #[tokio::test]
+    async fn test_variance_commentaries_are_attached_as_annex() {
+        let commentaries = Arc::new(InMemoryVarianceCommentaryRepository::default());
+        commentaries
+            .save(
+                &VarianceCommentary::new(
+                    "1100",
+                    2024,
+                    3,
+                    "期末の大口入金, 翌月に振替予定",
+                    "alice",
+                    "2024-04-05T10:00:00Z",
+                )
+                .unwrap(),
+            )
+            .await
+            .unwrap();
+        let delivery = Arc::new(CapturingDelivery::default());
+        let interactor = GenerateFinancialStatementsInteractor::new(
+            Arc::new(ledger(vec![
+                entry("1100", 0.0, 1000.0, 0.0),
+                entry("4000", 0.0, 0.0, 1000.0),
+            ])),
+            Arc::new(MockMappingRepository::new(&[
+                ("1100", StatementLine::CurrentAssets),
+                ("4000", StatementLine::Revenue),
+            ])),
+            Arc::new(MockPolicyRepository),
+            Arc::clone(&delivery),
+            Arc::new(InMemoryNoteCrossReferenceRepository::default()),
+            commentaries,
+        );
+
+        let response = interactor.execute(request()).await.unwrap();
+
+        assert_eq!(response.variance_commentaries.len(), 1);
+        assert_eq!(response.variance_commentaries[0].account_name, "勘定科目1100");
+        let documents = delivery.documents.lock().unwrap();
+        let content = &documents[0].content;
+        assert!(content.contains("\r\n\r\n別紙 増減コメント\r\n"));
+        assert!(content.contains(
+            "1100,勘定科目1100,\"期末の大口入金, 翌月に振替予定\",alice,2024-04-05T10:00:00Z\r\n"
+        ));
     }
 }
@@ -3,32 +3,43 @@
 
 use std::sync::Arc;
 
+use javelin_domain::repositories::InventoryWorksheetRepository;
+
 use crate::{
-    dtos::{GenerateNoteDraftRequest, GenerateNoteDraftResponse},
+    dtos::{
+        AccountBreakdownDto, GenerateNoteDraftRequest, GenerateNoteDraftResponse,
+        SupportingDocumentDto,
+    },
     error::ApplicationResult,
     input_ports::GenerateNoteDraftUseCase,
-    query_service::ledger_query_service::{GetTrialBalanceQuery, LedgerQueryService},
+    query_service::ledger_query_service::{
+        EntryStatusScope, GetTrialBalanceQuery, LedgerQueryService,
+    },
 };
 
-pub struct GenerateNoteDraftInteractor<Q>
+pub struct GenerateNoteDraftInteractor<Q, W>
 where
     Q: LedgerQueryService,
+    W: InventoryWorksheetRepository,
 {
     ledger_query_service: Arc<Q>,
+    worksheet_repository: Arc<W>,
 }
 
-impl<Q> GenerateNoteDraftInteractor<Q>
+impl<Q, W> GenerateNoteDraftInteractor<Q, W>
 where
     Q: LedgerQueryService,
+    W: InventoryWorksheetRepository,
 {
-    pub fn new(ledger_query_service: Arc<Q>) -> Self {
-        Self { ledger_query_service }
+    pub fn new(ledger_query_service: Arc<Q>, worksheet_repository: Arc<W>) -> Self {
+        Self { ledger_query_service, worksheet_repository }
     }
 }
 
-impl<Q> GenerateNoteDraftUseCase for GenerateNoteDraftInteractor<Q>
+impl<Q, W> GenerateNoteDraftUseCase for GenerateNoteDraftInteractor<Q, W>
 where
     Q: LedgerQueryService,
+    W: InventoryWorksheetRepository,
 {
     async fn execute(
         &self,
@@ -40,14 +51,46 @@ where
             .get_trial_balance(GetTrialBalanceQuery {
                 period_year: request.fiscal_year as u32,
                 period_month: request.period,
+                status_scope: EntryStatusScope::PostedOnly,
             })
             .await?;
 
-        // 実装: 注記草案生成
+        let mut significant_estimates = vec!["減価償却".to_string()];
+        let mut account_breakdowns = vec![];
+        let mut supporting_documents = vec![];
+
+        // 棚卸資産の評価減（ワークシートを根拠資料として添付）
+        if let Some(worksheet) =
+            self.worksheet_repository.find(request.fiscal_year, request.period).await?
+        {
+            let lines = worksheet.calculate();
+            significant_estimates.push("棚卸資産の評価（正味実現可能価額）".to_string());
+            account_breakdowns.push(AccountBreakdownDto {
+                account_code: "棚卸資産評価減".to_string(),
+                components: lines
+                    .iter()
+                    .filter(|line| line.write_down > 0.0)
+                    .map(|line| format!("{} {:.0}", line.unit, line.write_down))
+                    .collect(),
+            });
+            supporting_documents.push(SupportingDocumentDto {
+                document_id: worksheet.worksheet_id(),
+                title: "棚卸資産評価ワークシート".to_string(),
+                summary: format!(
+                    "{}品目 評価減 {:.0} {}",
+                    worksheet.items().len(),
+                    worksheet.total_write_down(),
+                    worksheet.currency()
+                ),
+                journal_entry_id: worksheet.posted_entry_id().map(str::to_string),
+            });
+        }
+
         Ok(GenerateNoteDraftResponse {
             accounting_policies: vec!["継続企業の前提".to_string()],
-            significant_estimates: vec!["減価償却".to_string()],
-            account_breakdowns: vec![],
+            significant_estimates,
+            account_breakdowns,
+            supporting_documents,
             note_draft: "注記草案が生成されました".to_string(),
         })
     }
@@ -1,80 +1,861 @@
 // GenerateTrialBalanceInteractor - 試算表生成処理
-// 責務: 残高検証・異常値抽出
+// 責務: 残高検証・異常値抽出・通貨別試算表の表示通貨換算・縦計/横計の検証・区分別小計
 
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
+
+use javelin_domain::{
+    financial_close::report_archive::ReportDigester,
+    masters::{AccountType, ApplicationSettings, StatementLine},
+    repositories::{ApplicationSettingsRepository, StatementLineMappingRepository},
+};
 
 use crate::{
-    dtos::{AccountBalanceDto, GenerateTrialBalanceRequest, GenerateTrialBalanceResponse},
-    error::ApplicationResult,
+    dtos::{
+        AccountBalanceDto, CurrencyTrialBalanceDto, ForeignExchangeDifferenceDto,
+        GenerateTrialBalanceRequest, GenerateTrialBalanceResponse, TranslationRateDto,
+        TrialBalanceLineDto,
+        response::{
+            ReportDocument, TRIAL_BALANCE_REPORT_TYPE, TrialBalanceClassDto, TrialBalanceGroupDto,
+            TrialBalanceProofDto, TrialBalanceSubtotalDto, trial_balance_rows_payload,
+        },
+    },
+    error::{ApplicationError, ApplicationResult},
     input_ports::GenerateTrialBalanceUseCase,
-    query_service::ledger_query_service::{GetTrialBalanceQuery, LedgerQueryService},
+    interactor::audit_export_interactor::csv_row,
+    output_port::ReportDeliveryOutputPort,
+    query_service::ledger_query_service::{
+        CurrencyTrialBalanceResult, EntryStatusScope, GetTrialBalanceQuery, LedgerQueryService,
+    },
 };
 
-pub struct GenerateTrialBalanceInteractor<Q>
+/// 為替換算調整勘定の科目名
+const TRANSLATION_ADJUSTMENT_ACCOUNT_NAME: &str = "為替換算調整勘定";
+
+/// 縦計・横計の一致判定に用いる許容誤差
+const FOOTING_TOLERANCE: f64 = 0.005;
+
+/// 財務諸表表示科目が未割当の科目をまとめる科目グループ名
+const UNASSIGNED_GROUP_NAME: &str = "未割当";
+
+pub struct GenerateTrialBalanceInteractor<Q, M, G, D, R>
 where
     Q: LedgerQueryService,
+    M: StatementLineMappingRepository,
+    G: ApplicationSettingsRepository,
+    D: ReportDigester,
+    R: ReportDeliveryOutputPort,
 {
     ledger_query_service: Arc<Q>,
+    mapping_repository: Arc<M>,
+    settings_repository: Arc<G>,
+    digester: Arc<D>,
+    delivery: Arc<R>,
 }
 
-impl<Q> GenerateTrialBalanceInteractor<Q>
+impl<Q, M, G, D, R> GenerateTrialBalanceInteractor<Q, M, G, D, R>
 where
     Q: LedgerQueryService,
+    M: StatementLineMappingRepository,
+    G: ApplicationSettingsRepository,
+    D: ReportDigester,
+    R: ReportDeliveryOutputPort,
 {
-    pub fn new(ledger_query_service: Arc<Q>) -> Self {
-        Self { ledger_query_service }
+    pub fn new(
+        ledger_query_service: Arc<Q>,
+        mapping_repository: Arc<M>,
+        settings_repository: Arc<G>,
+        digester: Arc<D>,
+        delivery: Arc<R>,
+    ) -> Self {
+        Self {
+            ledger_query_service,
+            mapping_repository,
+            settings_repository,
+            digester,
+            delivery,
+        }
+    }
+
+    /// 換算差額を計上する勘定科目コード（アプリケーション設定、未設定なら既定の科目）
+    async fn translation_adjustment_account(&self) -> ApplicationResult<String> {
+        Ok(self
+            .settings_repository
+            .find()
+            .await?
+            .map(|settings| settings.translation_adjustment_account().value().to_string())
+            .unwrap_or_else(|| {
+                ApplicationSettings::DEFAULT_TRANSLATION_ADJUSTMENT_ACCOUNT.to_string()
+            }))
+    }
+}
+
+/// 配信用の試算表（表示通貨建て、CSV）
+fn delivery_document(
+    request: &GenerateTrialBalanceRequest,
+    trial_balance: &CurrencyTrialBalanceDto,
+) -> ReportDocument {
+    let header = ["勘定科目コード", "勘定科目名", "期首残高", "借方金額", "貸方金額", "期末残高"];
+    let mut content = csv_row(&header.map(String::from));
+    let line_row = |line: &TrialBalanceLineDto| {
+        csv_row(&[
+            line.account_code.clone(),
+            line.account_name.clone(),
+            format!("{:.2}", line.opening_balance),
+            format!("{:.2}", line.debit_amount),
+            format!("{:.2}", line.credit_amount),
+            format!("{:.2}", line.closing_balance),
+        ])
+    };
+    // 小計行は科目コードを空欄にし、グループ・区分の末尾に置く
+    let subtotal_row = |subtotal: &TrialBalanceSubtotalDto| {
+        csv_row(&[
+            String::new(),
+            subtotal.label.clone(),
+            format!("{:.2}", subtotal.opening_balance),
+            format!("{:.2}", subtotal.debit_amount),
+            format!("{:.2}", subtotal.credit_amount),
+            format!("{:.2}", subtotal.closing_balance),
+        ])
+    };
+    for class in &trial_balance.hierarchy {
+        for group in &class.groups {
+            for line in trial_balance
+                .lines
+                .iter()
+                .filter(|line| group.account_codes.contains(&line.account_code))
+            {
+                content.push_str(&line_row(line));
+            }
+            content.push_str(&subtotal_row(&group.subtotal));
+        }
+        content.push_str(&subtotal_row(&class.subtotal));
+    }
+
+    ReportDocument {
+        report_type: TRIAL_BALANCE_REPORT_TYPE.to_string(),
+        fiscal_year: request.fiscal_year,
+        period: request.period,
+        file_name: format!(
+            "trial_balance_{:04}{:02}_{}.csv",
+            request.fiscal_year, request.period, trial_balance.currency
+        ),
+        content,
+    }
+}
+
+/// 明細行から縦計・横計を再計算し、報告された合計と照合する
+fn prove(
+    currency: &str,
+    lines: &[TrialBalanceLineDto],
+    total_debit: f64,
+    total_credit: f64,
+    digester: &dyn ReportDigester,
+) -> TrialBalanceProofDto {
+    let debit_total: f64 = lines.iter().map(|l| l.debit_amount).sum();
+    let credit_total: f64 = lines.iter().map(|l| l.credit_amount).sum();
+    let opening_total: f64 = lines.iter().map(|l| l.opening_balance).sum();
+    let closing_total: f64 = lines.iter().map(|l| l.closing_balance).sum();
+    let cross_foot_difference = closing_total - (opening_total + debit_total - credit_total);
+
+    TrialBalanceProofDto {
+        debit_total,
+        credit_total,
+        difference: debit_total - credit_total,
+        cross_foot_difference,
+        account_count: lines.len(),
+        rows_hash: digester.digest(&trial_balance_rows_payload(currency, lines)),
+        footed: (debit_total - total_debit).abs() < FOOTING_TOLERANCE
+            && (credit_total - total_credit).abs() < FOOTING_TOLERANCE
+            && cross_foot_difference.abs() < FOOTING_TOLERANCE,
+    }
+}
+
+/// 貸借対照表科目かどうか
+///
+/// 勘定科目コード体系（1xxx資産 / 2xxx負債 / 3xxx純資産）に基づいて判定する。
+/// それ以外は損益計算書科目として扱う。
+fn is_balance_sheet_account(account_code: &str) -> bool {
+    matches!(account_code.chars().next(), Some('1'..='3'))
+}
+
+/// 勘定科目コード体系から勘定科目区分を判定（表示科目が未割当の科目に用いる）
+fn account_type_of_code(account_code: &str) -> AccountType {
+    match account_code.chars().next() {
+        Some('1') => AccountType::Asset,
+        Some('2') => AccountType::Liability,
+        Some('3') => AccountType::Equity,
+        Some('4') => AccountType::Revenue,
+        _ => AccountType::Expense,
+    }
+}
+
+/// 明細行を勘定科目区分 → 科目グループ（財務諸表表示科目）の階層にまとめ、小計を求める
+///
+/// 区分・グループは表示順に並べ、明細行のない区分・グループは省く。
+/// 表示科目が未割当の科目は、科目コード体系で判定した区分の「未割当」グループに入れる。
+fn build_hierarchy(
+    lines: &[TrialBalanceLineDto],
+    mappings: &HashMap<String, StatementLine>,
+) -> Vec<TrialBalanceClassDto> {
+    let group_of = |line: &TrialBalanceLineDto| match mappings.get(&line.account_code) {
+        Some(statement_line) => (statement_line.account_type(), Some(*statement_line)),
+        None => (account_type_of_code(&line.account_code), None),
+    };
+    let group_names = StatementLine::ALL.into_iter().map(Some).chain([None]);
+
+    AccountType::ALL
+        .into_iter()
+        .filter_map(|account_type| {
+            let groups: Vec<TrialBalanceGroupDto> = group_names
+                .clone()
+                .filter_map(|statement_line| {
+                    let members: Vec<&TrialBalanceLineDto> = lines
+                        .iter()
+                        .filter(|line| group_of(line) == (account_type, statement_line))
+                        .collect();
+                    if members.is_empty() {
+                        return None;
+                    }
+                    let name = statement_line
+                        .map_or(UNASSIGNED_GROUP_NAME, |line| line.display_name())
+                        .to_string();
+                    let mut account_codes: Vec<String> =
+                        members.iter().map(|line| line.account_code.clone()).collect();
+                    account_codes.sort();
+                    Some(TrialBalanceGroupDto {
+                        subtotal: TrialBalanceSubtotalDto::sum(format!("{}計", name), members),
+                        name,
+                        account_codes,
+                    })
+                })
+                .collect();
+            if groups.is_empty() {
+                return None;
+            }
+            let name = account_type.display_name().to_string();
+            let members = lines.iter().filter(|line| group_of(line).0 == account_type);
+            Some(TrialBalanceClassDto {
+                subtotal: TrialBalanceSubtotalDto::sum(format!("{}合計", name), members),
+                name,
+                groups,
+            })
+        })
+        .collect()
+}
+
+/// 取引通貨建て試算表をDTOに変換（換算なし）
+fn to_currency_dto(
+    result: &CurrencyTrialBalanceResult,
+    mappings: &HashMap<String, StatementLine>,
+    digester: &dyn ReportDigester,
+) -> CurrencyTrialBalanceDto {
+    let lines: Vec<TrialBalanceLineDto> = result
+        .entries
+        .iter()
+        .map(|entry| TrialBalanceLineDto {
+            account_code: entry.account_code.clone(),
+            account_name: entry.account_name.clone(),
+            opening_balance: entry.opening_balance,
+            debit_amount: entry.debit_amount,
+            credit_amount: entry.credit_amount,
+            closing_balance: entry.closing_balance,
+            exchange_rate: 1.0,
+        })
+        .collect();
+    let proof = prove(&result.currency, &lines, result.total_debit, result.total_credit, digester);
+    let hierarchy = build_hierarchy(&lines, mappings);
+
+    CurrencyTrialBalanceDto {
+        currency: result.currency.clone(),
+        lines,
+        total_debit: result.total_debit,
+        total_credit: result.total_credit,
+        proof,
+        hierarchy,
     }
 }
 
-impl<Q> GenerateTrialBalanceUseCase for GenerateTrialBalanceInteractor<Q>
+/// 表示通貨建て試算表の換算結果
+struct TranslatedTrialBalance {
+    trial_balance: CurrencyTrialBalanceDto,
+    translation_difference: f64,
+    foreign_exchange_differences: Vec<ForeignExchangeDifferenceDto>,
+}
+
+/// 通貨別試算表を表示通貨へ換算する
+///
+/// B/S科目は決算日レート、P/L科目は期中平均レートで換算し、
+/// 換算後の貸借差額を為替換算調整勘定（`adjustment_account`）として計上する。
+fn translate(
+    currency_trial_balances: &[CurrencyTrialBalanceResult],
+    presentation_currency: &str,
+    rates: &[TranslationRateDto],
+    adjustment_account: &str,
+    mappings: &HashMap<String, StatementLine>,
+    digester: &dyn ReportDigester,
+) -> ApplicationResult<TranslatedTrialBalance> {
+    // 換算レートの欠落を検証
+    let missing: Vec<String> = currency_trial_balances
+        .iter()
+        .filter(|tb| tb.currency != presentation_currency)
+        .filter(|tb| !rates.iter().any(|rate| rate.currency == tb.currency))
+        .map(|tb| format!("換算レートが指定されていません: {}", tb.currency))
+        .collect();
+    if !missing.is_empty() {
+        return Err(ApplicationError::ValidationFailed(missing));
+    }
+
+    let mut lines: Vec<TrialBalanceLineDto> = Vec::new();
+    let mut foreign_exchange_differences = Vec::new();
+
+    for tb in currency_trial_balances {
+        let rate = rates.iter().find(|rate| rate.currency == tb.currency);
+
+        for entry in &tb.entries {
+            let (applied_rate, closing_rate) = match rate {
+                Some(rate) if tb.currency != presentation_currency => {
+                    if is_balance_sheet_account(&entry.account_code) {
+                        (rate.closing_rate, rate.closing_rate)
+                    } else {
+                        (rate.average_rate, rate.closing_rate)
+                    }
+                }
+                _ => (1.0, 1.0),
+            };
+
+            let converted_closing = entry.closing_balance * applied_rate;
+
+            if tb.currency != presentation_currency {
+                foreign_exchange_differences.push(ForeignExchangeDifferenceDto {
+                    account_code: entry.account_code.clone(),
+                    original_amount: entry.closing_balance,
+                    original_currency: tb.currency.clone(),
+                    exchange_rate: applied_rate,
+                    converted_amount: converted_closing,
+                    converted_currency: presentation_currency.to_string(),
+                    difference: converted_closing - entry.closing_balance * closing_rate,
+                    difference_currency: presentation_currency.to_string(),
+                });
+            }
+
+            // 同一科目は表示通貨建てで合算
+            let line = match lines.iter_mut().find(|l| l.account_code == entry.account_code) {
+                Some(line) => line,
+                None => {
+                    lines.push(TrialBalanceLineDto {
+                        account_code: entry.account_code.clone(),
+                        account_name: entry.account_name.clone(),
+                        opening_balance: 0.0,
+                        debit_amount: 0.0,
+                        credit_amount: 0.0,
+                        closing_balance: 0.0,
+                        exchange_rate: applied_rate,
+                    });
+                    lines.last_mut().expect("line was just pushed")
+                }
+            };
+            line.opening_balance += entry.opening_balance * applied_rate;
+            line.debit_amount += entry.debit_amount * applied_rate;
+            line.credit_amount += entry.credit_amount * applied_rate;
+            line.closing_balance += converted_closing;
+        }
+    }
+
+    lines.sort_by(|a, b| a.account_code.cmp(&b.account_code));
+
+    // 換算差額（換算後の期首・期中それぞれの貸借不一致を調整）
+    let opening_difference: f64 = -lines.iter().map(|l| l.opening_balance).sum::<f64>();
+    let movement_difference: f64 =
+        -lines.iter().map(|l| l.debit_amount - l.credit_amount).sum::<f64>();
+    let translation_difference = opening_difference + movement_difference;
+
+    if opening_difference.abs() >= 0.005 || movement_difference.abs() >= 0.005 {
+        lines.push(TrialBalanceLineDto {
+            account_code: adjustment_account.to_string(),
+            account_name: TRANSLATION_ADJUSTMENT_ACCOUNT_NAME.to_string(),
+            opening_balance: opening_difference,
+            debit_amount: movement_difference.max(0.0),
+            credit_amount: (-movement_difference).max(0.0),
+            closing_balance: translation_difference,
+            exchange_rate: 1.0,
+        });
+    }
+
+    let total_debit = lines.iter().map(|l| l.debit_amount).sum();
+    let total_credit = lines.iter().map(|l| l.credit_amount).sum();
+    let proof = prove(presentation_currency, &lines, total_debit, total_credit, digester);
+    let hierarchy = build_hierarchy(&lines, mappings);
+
+    Ok(TranslatedTrialBalance {
+        trial_balance: CurrencyTrialBalanceDto {
+            currency: presentation_currency.to_string(),
+            lines,
+            total_debit,
+            total_credit,
+            proof,
+            hierarchy,
+        },
+        translation_difference,
+        foreign_exchange_differences,
+    })
+}
+
+impl<Q, M, G, D, R> GenerateTrialBalanceUseCase for GenerateTrialBalanceInteractor<Q, M, G, D, R>
 where
     Q: LedgerQueryService,
+    M: StatementLineMappingRepository,
+    G: ApplicationSettingsRepository,
+    D: ReportDigester,
+    R: ReportDeliveryOutputPort,
 {
     async fn execute(
         &self,
         request: GenerateTrialBalanceRequest,
     ) -> ApplicationResult<GenerateTrialBalanceResponse> {
-        // 試算表を取得
-        let trial_balance = self
+        if request.presentation_currency.is_empty() {
+            return Err(ApplicationError::ValidationError(
+                "表示通貨が指定されていません".to_string(),
+            ));
+        }
+
+        // 通貨別試算表を取得
+        let status_scope = if request.include_pending_approval {
+            EntryStatusScope::IncludePendingApproval
+        } else {
+            EntryStatusScope::PostedOnly
+        };
+        let currency_trial_balances = self
             .ledger_query_service
-            .get_trial_balance(GetTrialBalanceQuery {
+            .get_trial_balance_by_currency(GetTrialBalanceQuery {
                 period_year: request.fiscal_year as u32,
                 period_month: request.period,
+                status_scope,
             })
             .await?;
 
-        // 試算表エントリをDTOに変換
-        let account_balances: Vec<AccountBalanceDto> = trial_balance
-            .entries
+        // 小計の科目グループは財務諸表表示科目のマッピングに従う
+        let mappings: HashMap<String, StatementLine> = self
+            .mapping_repository
+            .find_all()
+            .await?
+            .into_iter()
+            .map(|mapping| (mapping.account_code().value().to_string(), mapping.statement_line()))
+            .collect();
+
+        // 表示通貨へ換算
+        let translated = translate(
+            &currency_trial_balances,
+            &request.presentation_currency,
+            &request.translation_rates,
+            &self.translation_adjustment_account().await?,
+            &mappings,
+            self.digester.as_ref(),
+        )?;
+
+        // 承認待ちを含む試算は正式な試算表ではないため配信しない
+        let deliveries = if request.include_pending_approval {
+            vec![]
+        } else {
+            self.delivery
+                .deliver(&delivery_document(&request, &translated.trial_balance))
+                .await
+        };
+        let currency = request.presentation_currency;
+
+        // 表示通貨建て試算表をDTOに変換
+        let account_balances: Vec<AccountBalanceDto> = translated
+            .trial_balance
+            .lines
             .iter()
-            .map(|entry| AccountBalanceDto {
-                account_code: entry.account_code.clone(),
-                debit_balance: if entry.closing_balance >= 0.0 {
-                    entry.closing_balance
+            .map(|line| AccountBalanceDto {
+                account_code: line.account_code.clone(),
+                debit_balance: if line.closing_balance >= 0.0 {
+                    line.closing_balance
                 } else {
                     0.0
                 },
-                debit_balance_currency: "JPY".to_string(),
-                credit_balance: if entry.closing_balance < 0.0 {
-                    -entry.closing_balance
+                debit_balance_currency: currency.clone(),
+                credit_balance: if line.closing_balance < 0.0 {
+                    -line.closing_balance
                 } else {
                     0.0
                 },
-                credit_balance_currency: "JPY".to_string(),
-                net_balance: entry.closing_balance,
-                net_balance_currency: "JPY".to_string(),
+                credit_balance_currency: currency.clone(),
+                net_balance: line.closing_balance,
+                net_balance_currency: currency.clone(),
             })
             .collect();
 
+        let total_debit = translated.trial_balance.total_debit;
+        let total_credit = translated.trial_balance.total_credit;
+
         Ok(GenerateTrialBalanceResponse {
-            total_debit: trial_balance.total_debit,
-            total_debit_currency: "JPY".to_string(),
-            total_credit: trial_balance.total_credit,
-            total_credit_currency: "JPY".to_string(),
-            is_balanced: (trial_balance.total_debit - trial_balance.total_credit).abs() < 0.01,
+            total_debit,
+            total_debit_currency: currency.clone(),
+            total_credit,
+            total_credit_currency: currency.clone(),
+            is_balanced: (total_debit - total_credit).abs() < 0.01,
             account_balances,
             temporary_account_balances: vec![],
-            foreign_exchange_differences: vec![],
+            foreign_exchange_differences: translated.foreign_exchange_differences,
+            presentation_trial_balance: translated.trial_balance,
+            currency_trial_balances: currency_trial_balances
+                .iter()
+                .map(|result| to_currency_dto(result, &mappings, self.digester.as_ref()))
+                .collect(),
+            translation_difference: translated.translation_difference,
+            translation_difference_currency: currency,
+            include_pending_approval: request.include_pending_approval,
+            deliveries,
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use javelin_domain::{
+        error::DomainResult,
+        masters::{AccountCode, StatementLineMapping},
+    };
+
+    use super::*;
+    use crate::{
+        dtos::response::ReportDeliveryResultDto,
+        interactor::closing::lock_closing_period_interactor::tests::MockSettingsRepository,
+        query_service::ledger_query_service::TrialBalanceEntry,
+        testkit::InMemoryLedgerQueryService,
+    };
+
+    /// 固定のマッピングを返すリポジトリ（1000: 流動資産、1500: 非流動資産、4000: 売上収益）
+    struct MockMappingRepository;
+
+    impl StatementLineMappingRepository for MockMappingRepository {
+        async fn find_by_account(
+            &self,
+            account_code: &AccountCode,
+        ) -> DomainResult<Option<StatementLineMapping>> {
+            Ok(self.find_all().await?.into_iter().find(|m| m.account_code() == account_code))
+        }
+
+        async fn find_all(&self) -> DomainResult<Vec<StatementLineMapping>> {
+            Ok([
+                ("1000", StatementLine::CurrentAssets),
+                ("1500", StatementLine::NonCurrentAssets),
+                ("4000", StatementLine::Revenue),
+            ]
+            .into_iter()
+            .map(|(code, line)| StatementLineMapping::new(AccountCode::new(code).unwrap(), line))
+            .collect())
+        }
+
+        async fn save(&self, _mapping: &StatementLineMapping) -> DomainResult<()> {
+            Ok(())
+        }
+
+        async fn delete(&self, _account_code: &AccountCode) -> DomainResult<()> {
+            Ok(())
+        }
+    }
+
+    /// ハッシュ対象の長さを返すダイジェスト（テスト用）
+    struct StubDigester;
+
+    impl ReportDigester for StubDigester {
+        fn digest(&self, payload: &str) -> String {
+            format!("len:{}", payload.len())
+        }
+    }
+
+    /// 配信された帳票を記録する配信先（テスト用）
+    #[derive(Default)]
+    struct RecordingDelivery {
+        documents: Mutex<Vec<ReportDocument>>,
+    }
+
+    impl ReportDeliveryOutputPort for RecordingDelivery {
+        async fn deliver(&self, document: &ReportDocument) -> Vec<ReportDeliveryResultDto> {
+            self.documents.lock().unwrap().push(document.clone());
+            vec![ReportDeliveryResultDto::delivered("file:reports", &document.file_name)]
+        }
+    }
+
+    type TestInteractor = GenerateTrialBalanceInteractor<
+        InMemoryLedgerQueryService,
+        MockMappingRepository,
+        MockSettingsRepository,
+        StubDigester,
+        RecordingDelivery,
+    >;
+
+    fn interactor(service: InMemoryLedgerQueryService) -> TestInteractor {
+        interactor_with_settings(service, MockSettingsRepository::default())
+    }
+
+    fn interactor_with_settings(
+        service: InMemoryLedgerQueryService,
+        settings: MockSettingsRepository,
+    ) -> TestInteractor {
+        GenerateTrialBalanceInteractor::new(
+            Arc::new(service),
+            Arc::new(MockMappingRepository),
+            Arc::new(settings),
+            Arc::new(StubDigester),
+            Arc::new(RecordingDelivery::default()),
+        )
+    }
+
+    fn entry(account_code: &str, opening: f64, debit: f64, credit: f64) -> TrialBalanceEntry {
+        TrialBalanceEntry {
+            account_code: account_code.to_string(),
+            account_name: format!("勘定科目{}", account_code),
+            opening_balance: opening,
+            debit_amount: debit,
+            credit_amount: credit,
+            closing_balance: opening + debit - credit,
+        }
+    }
+
+    fn currency_tb(currency: &str, entries: Vec<TrialBalanceEntry>) -> CurrencyTrialBalanceResult {
+        let total_debit = entries.iter().map(|e| e.debit_amount).sum();
+        let total_credit = entries.iter().map(|e| e.credit_amount).sum();
+        CurrencyTrialBalanceResult {
+            period_year: 2024,
+            period_month: 3,
+            currency: currency.to_string(),
+            entries,
+            total_debit,
+            total_credit,
+        }
+    }
+
+    fn request(rates: Vec<TranslationRateDto>) -> GenerateTrialBalanceRequest {
+        GenerateTrialBalanceRequest {
+            fiscal_year: 2024,
+            period: 3,
+            presentation_currency: "JPY".to_string(),
+            translation_rates: rates,
+            include_pending_approval: false,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_presentation_currency_only() {
+        let service =
+            InMemoryLedgerQueryService::new().with_currency_trial_balances(vec![currency_tb(
+                "JPY",
+                vec![entry("1000", 0.0, 1000.0, 0.0), entry("4000", 0.0, 0.0, 1000.0)],
+            )]);
+        let interactor = interactor(service);
+
+        let response = interactor.execute(request(vec![])).await.unwrap();
+
+        assert!(response.is_balanced);
+        assert_eq!(response.translation_difference, 0.0);
+        assert_eq!(response.presentation_trial_balance.lines.len(), 2);
+        assert_eq!(response.currency_trial_balances.len(), 1);
+        assert!(response.foreign_exchange_differences.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_translation_uses_closing_and_average_rates() {
+        // USD建て: 現金100 / 売上100
+        let service =
+            InMemoryLedgerQueryService::new().with_currency_trial_balances(vec![currency_tb(
+                "USD",
+                vec![entry("1000", 0.0, 100.0, 0.0), entry("4000", 0.0, 0.0, 100.0)],
+            )]);
+        let interactor = interactor(service);
+
+        let response = interactor
+            .execute(request(vec![TranslationRateDto {
+                currency: "USD".to_string(),
+                closing_rate: 150.0,
+                average_rate: 145.0,
+            }]))
+            .await
+            .unwrap();
+
+        let lines = &response.presentation_trial_balance.lines;
+        let cash = lines.iter().find(|l| l.account_code == "1000").unwrap();
+        let sales = lines.iter().find(|l| l.account_code == "4000").unwrap();
+        assert_eq!(cash.closing_balance, 15000.0);
+        assert_eq!(sales.closing_balance, -14500.0);
+
+        // 換算差額 = -(15000 - 14500)
+        assert_eq!(response.translation_difference, -500.0);
+        let adjustment = lines
+            .iter()
+            .find(|l| l.account_code == ApplicationSettings::DEFAULT_TRANSLATION_ADJUSTMENT_ACCOUNT)
+            .unwrap();
+        assert_eq!(adjustment.closing_balance, -500.0);
+        assert!(response.is_balanced);
+        assert_eq!(response.foreign_exchange_differences.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_translation_difference_uses_configured_account() {
+        let service =
+            InMemoryLedgerQueryService::new().with_currency_trial_balances(vec![currency_tb(
+                "USD",
+                vec![entry("1000", 0.0, 100.0, 0.0), entry("4000", 0.0, 0.0, 100.0)],
+            )]);
+        let settings = MockSettingsRepository::default();
+        let mut configured = settings.find().await.unwrap().unwrap();
+        configured.update_translation_adjustment_account(AccountCode::new("3950").unwrap());
+        settings.save(&configured).await.unwrap();
+        let interactor = interactor_with_settings(service, settings);
+
+        let response = interactor
+            .execute(request(vec![TranslationRateDto {
+                currency: "USD".to_string(),
+                closing_rate: 150.0,
+                average_rate: 145.0,
+            }]))
+            .await
+            .unwrap();
+
+        let lines = &response.presentation_trial_balance.lines;
+        let adjustment = lines.iter().find(|l| l.account_code == "3950").unwrap();
+        assert_eq!(adjustment.closing_balance, -500.0);
+        assert!(
+            !lines
+                .iter()
+                .any(|l| l.account_code
+                    == ApplicationSettings::DEFAULT_TRANSLATION_ADJUSTMENT_ACCOUNT)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_missing_rate_is_validation_error() {
+        let service =
+            InMemoryLedgerQueryService::new().with_currency_trial_balances(vec![currency_tb(
+                "USD",
+                vec![entry("1000", 0.0, 1.0, 0.0)],
+            )]);
+        let interactor = interactor(service);
+
+        let result = interactor.execute(request(vec![])).await;
+
+        assert!(matches!(result, Err(ApplicationError::ValidationFailed(_))));
+    }
+
+    #[tokio::test]
+    async fn test_proof_detects_truncated_totals() {
+        let mut truncated = currency_tb(
+            "JPY",
+            vec![entry("1000", 500.0, 1000.0, 0.0), entry("4000", -500.0, 0.0, 1000.0)],
+        );
+        // 報告された合計と明細が一致しない（明細の欠落を想定）
+        truncated.total_debit = 1500.0;
+        let service =
+            InMemoryLedgerQueryService::new().with_currency_trial_balances(vec![truncated]);
+        let interactor = interactor(service);
+
+        let response = interactor.execute(request(vec![])).await.unwrap();
+
+        let presentation = &response.presentation_trial_balance.proof;
+        assert!(presentation.footed);
+        assert_eq!(presentation.account_count, 2);
+        assert_eq!(presentation.difference, 0.0);
+        assert_eq!(presentation.cross_foot_difference, 0.0);
+        assert_eq!(
+            presentation.rows_hash,
+            StubDigester.digest(&trial_balance_rows_payload(
+                "JPY",
+                &response.presentation_trial_balance.lines
+            ))
+        );
+
+        let source = &response.currency_trial_balances[0].proof;
+        assert_eq!(source.debit_total, 1000.0);
+        assert!(!source.footed);
+    }
+
+    #[tokio::test]
+    async fn test_trial_balance_is_delivered_as_csv() {
+        let service =
+            InMemoryLedgerQueryService::new().with_currency_trial_balances(vec![currency_tb(
+                "JPY",
+                vec![entry("1000", 0.0, 1000.0, 0.0), entry("4000", 0.0, 0.0, 1000.0)],
+            )]);
+        let interactor = interactor(service);
+
+        let response = interactor.execute(request(vec![])).await.unwrap();
+
+        assert_eq!(
+            response.deliveries,
+            vec![ReportDeliveryResultDto::delivered(
+                "file:reports",
+                "trial_balance_202403_JPY.csv"
+            )]
+        );
+        let documents = interactor.delivery.documents.lock().unwrap();
+        assert_eq!(documents[0].report_type, TRIAL_BALANCE_REPORT_TYPE);
+        let rows: Vec<&str> = documents[0].content.lines().collect();
+        assert_eq!(rows.len(), 7);
+        assert_eq!(rows[1], "1000,勘定科目1000,0.00,1000.00,0.00,1000.00");
+        assert_eq!(rows[2], ",流動資産計,0.00,1000.00,0.00,1000.00");
+        assert_eq!(rows[3], ",資産合計,0.00,1000.00,0.00,1000.00");
+        assert_eq!(rows[6], ",収益合計,0.00,0.00,1000.00,-1000.00");
+    }
+
+    #[tokio::test]
+    async fn test_hierarchy_subtotals_by_class_and_group() {
+        let service =
+            InMemoryLedgerQueryService::new().with_currency_trial_balances(vec![currency_tb(
+                "JPY",
+                vec![
+                    entry("1000", 500.0, 1000.0, 0.0),
+                    entry("1500", 2000.0, 0.0, 0.0),
+                    entry("1900", 0.0, 300.0, 0.0),
+                    entry("2000", -2500.0, 0.0, 300.0),
+                    entry("4000", 0.0, 0.0, 1000.0),
+                ],
+            )]);
+        let interactor = interactor(service);
+
+        let response = interactor.execute(request(vec![])).await.unwrap();
+        let hierarchy = &response.presentation_trial_balance.hierarchy;
+
+        let names: Vec<&str> = hierarchy.iter().map(|class| class.name.as_str()).collect();
+        assert_eq!(names, vec!["資産", "負債", "収益"]);
+
+        let assets = &hierarchy[0];
+        let groups: Vec<&str> = assets.groups.iter().map(|group| group.name.as_str()).collect();
+        assert_eq!(groups, vec!["流動資産", "非流動資産", "未割当"]);
+        assert_eq!(assets.groups[0].subtotal.label, "流動資産計");
+        assert_eq!(assets.groups[0].subtotal.closing_balance, 1500.0);
+        assert_eq!(assets.groups[2].account_codes, vec!["1900"]);
+        assert_eq!(assets.subtotal.label, "資産合計");
+        assert_eq!(assets.subtotal.opening_balance, 2500.0);
+        assert_eq!(assets.subtotal.closing_balance, 3800.0);
+
+        // 未割当の科目は科目コード体系で区分を判定する
+        assert_eq!(hierarchy[1].groups[0].name, "未割当");
+        assert_eq!(hierarchy[1].subtotal.closing_balance, -2800.0);
+        assert_eq!(response.currency_trial_balances[0].hierarchy.len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_trial_balance_with_pending_entries_is_not_delivered() {
+        let service =
+            InMemoryLedgerQueryService::new().with_currency_trial_balances(vec![currency_tb(
+                "JPY",
+                vec![entry("1000", 0.0, 1.0, 1.0)],
+            )]);
+        let interactor = interactor(service);
+
+        let response = interactor
+            .execute(GenerateTrialBalanceRequest {
+                include_pending_approval: true,
+                ..request(vec![])
+            })
+            .await
+            .unwrap();
+
+        assert!(response.deliveries.is_empty());
+        assert!(interactor.delivery.documents.lock().unwrap().is_empty());
+    }
+}
@@ -1,40 +1,257 @@
 // LockClosingPeriodInteractor - 締日固定処理
-// 責務: 取引データのロック処理
+// 責務: 取引データのロック処理（設定で有効にした事前検証を通過していることを確認し、
+// 失敗した場合は管理者が理由を付けて上書きしたときのみ固定する）
 
 use std::sync::Arc;
 
-use javelin_domain::repositories::EventRepository;
+use javelin_domain::{
+    entity::EntityId,
+    error::DomainError,
+    financial_close::accounting_period::{AccountingPeriodEvent, FiscalYear, Period, PeriodId},
+    masters::ClosingLockCheck,
+    repositories::{
+        AccountReconciliationRepository, AccountingPolicyRepository, ApplicationSettingsRepository,
+        EventRepository,
+    },
+};
 
+use super::account_reconciliation_interactor::{carried_balance, outstanding_reconciliations};
 use crate::{
-    dtos::{LockClosingPeriodRequest, LockClosingPeriodResponse},
-    error::ApplicationResult,
+    dtos::{
+        LockClosingPeriodRequest, LockClosingPeriodResponse,
+        request::{SearchCriteriaDto, ValidateClosingLockRequest},
+        response::{ClosingLockCheckResult, ClosingLockValidationReport},
+    },
+    error::{ApplicationError, ApplicationResult},
     input_ports::LockClosingPeriodUseCase,
+    query_service::{
+        JournalEntrySearchQueryService, SUSPENSE_ACCOUNT_CODES,
+        ledger_query_service::{EntryStatusScope, GetTrialBalanceQuery, LedgerQueryService},
+    },
 };
 
-pub struct LockClosingPeriodInteractor<R>
+/// 試算表の貸借差額の許容誤差
+const BALANCE_TOLERANCE: f64 = 0.005;
+
+/// 締日固定を止める未記帳の仕訳の状態
+const UNPOSTED_STATUSES: [&str; 2] = ["Draft", "PendingApproval"];
+
+pub struct LockClosingPeriodInteractor<R, A, Q, S, P, G>
 where
     R: EventRepository,
+    A: AccountReconciliationRepository,
+    Q: LedgerQueryService,
+    S: JournalEntrySearchQueryService,
+    P: AccountingPolicyRepository,
+    G: ApplicationSettingsRepository,
 {
     event_repository: Arc<R>,
+    reconciliation_repository: Arc<A>,
+    ledger_query_service: Arc<Q>,
+    search_query_service: Arc<S>,
+    policy_repository: Arc<P>,
+    settings_repository: Arc<G>,
 }
 
-impl<R> LockClosingPeriodInteractor<R>
+impl<R, A, Q, S, P, G> LockClosingPeriodInteractor<R, A, Q, S, P, G>
 where
     R: EventRepository,
+    A: AccountReconciliationRepository,
+    Q: LedgerQueryService,
+    S: JournalEntrySearchQueryService,
+    P: AccountingPolicyRepository,
+    G: ApplicationSettingsRepository,
 {
-    pub fn new(event_repository: Arc<R>) -> Self {
-        Self { event_repository }
+    pub fn new(
+        event_repository: Arc<R>,
+        reconciliation_repository: Arc<A>,
+        ledger_query_service: Arc<Q>,
+        search_query_service: Arc<S>,
+        policy_repository: Arc<P>,
+        settings_repository: Arc<G>,
+    ) -> Self {
+        Self {
+            event_repository,
+            reconciliation_repository,
+            ledger_query_service,
+            search_query_service,
+            policy_repository,
+            settings_repository,
+        }
+    }
+
+    /// 設定で有効な検証項目を順に実行
+    async fn run_checks(
+        &self,
+        fiscal_year: i32,
+        period: u8,
+    ) -> ApplicationResult<ClosingLockValidationReport> {
+        let period_id = period_id(fiscal_year, period)?;
+        let enabled_checks = self
+            .settings_repository
+            .find()
+            .await?
+            .map(|settings| settings.closing_lock_validation().enabled_checks())
+            .unwrap_or_else(|| ClosingLockCheck::ALL.to_vec());
+
+        let mut checks = Vec::with_capacity(enabled_checks.len());
+        for check in enabled_checks {
+            let findings = match check {
+                ClosingLockCheck::TrialBalanceBalanced => {
+                    self.unbalanced_trial_balance(fiscal_year, period).await?
+                }
+                ClosingLockCheck::NoUnpostedEntries => {
+                    self.unposted_entries(fiscal_year, period).await?
+                }
+                ClosingLockCheck::SuspenseCleared => {
+                    self.uncleared_suspense(fiscal_year, period).await?
+                }
+                ClosingLockCheck::ReconciliationsComplete => outstanding_reconciliations(
+                    self.reconciliation_repository.as_ref(),
+                    self.ledger_query_service.as_ref(),
+                    fiscal_year,
+                    period,
+                )
+                .await?
+                .into_iter()
+                .map(|account_code| format!("{} 未照合", account_code))
+                .collect(),
+            };
+            checks.push(ClosingLockCheckResult {
+                code: check.code().to_string(),
+                name: check.display_name().to_string(),
+                passed: findings.is_empty(),
+                findings,
+            });
+        }
+
+        Ok(ClosingLockValidationReport { period_id: period_id.value().to_string(), checks })
+    }
+
+    /// 記帳済の試算表の貸借差額
+    async fn unbalanced_trial_balance(
+        &self,
+        fiscal_year: i32,
+        period: u8,
+    ) -> ApplicationResult<Vec<String>> {
+        let trial_balance = self
+            .ledger_query_service
+            .get_trial_balance(GetTrialBalanceQuery {
+                period_year: fiscal_year as u32,
+                period_month: period,
+                status_scope: EntryStatusScope::PostedOnly,
+            })
+            .await?;
+        let difference = trial_balance.total_debit - trial_balance.total_credit;
+        if difference.abs() < BALANCE_TOLERANCE {
+            return Ok(Vec::new());
+        }
+        Ok(vec![format!(
+            "借方 {:.2} / 貸方 {:.2}（差額 {:.2}）",
+            trial_balance.total_debit, trial_balance.total_credit, difference
+        )])
+    }
+
+    /// 期間内の下書き・承認待ちの仕訳
+    async fn unposted_entries(
+        &self,
+        fiscal_year: i32,
+        period: u8,
+    ) -> ApplicationResult<Vec<String>> {
+        // 日付は文字列比較のため、月末日に関わらず31日までを対象とする
+        let criteria = SearchCriteriaDto::new()
+            .with_from_date(format!("{:04}-{:02}-01", fiscal_year, period))
+            .with_to_date(format!("{:04}-{:02}-31", fiscal_year, period))
+            .with_limit(u32::MAX);
+        let result = self.search_query_service.search(criteria).await?;
+
+        Ok(result
+            .entries
+            .into_iter()
+            .filter(|entry| UNPOSTED_STATUSES.contains(&entry.status.as_str()))
+            .map(|entry| {
+                format!(
+                    "{} {}（{}）",
+                    entry.transaction_date,
+                    entry.entry_number.unwrap_or(entry.entry_id),
+                    entry.status
+                )
+            })
+            .collect())
+    }
+
+    /// 期末日の残高がゼロでない仮勘定
+    async fn uncleared_suspense(
+        &self,
+        fiscal_year: i32,
+        period: u8,
+    ) -> ApplicationResult<Vec<String>> {
+        let mut findings = Vec::new();
+        for account_code in SUSPENSE_ACCOUNT_CODES {
+            let (account_name, balance) = carried_balance(
+                self.ledger_query_service.as_ref(),
+                account_code,
+                fiscal_year,
+                period,
+            )
+            .await?;
+            if balance.abs() >= BALANCE_TOLERANCE {
+                findings.push(format!("{} {} 残高 {:.2}", account_code, account_name, balance));
+            }
+        }
+        Ok(findings)
     }
 }
 
-impl<R> LockClosingPeriodUseCase for LockClosingPeriodInteractor<R>
+fn period_id(fiscal_year: i32, period: u8) -> ApplicationResult<PeriodId> {
+    let fiscal_year = u32::try_from(fiscal_year).map_err(|_| {
+        ApplicationError::ValidationError(format!("不正な会計年度です: {}", fiscal_year))
+    })?;
+    Ok(PeriodId::from_year_period(FiscalYear::new(fiscal_year)?, Period::new(period)?))
+}
+
+impl<R, A, Q, S, P, G> LockClosingPeriodUseCase for LockClosingPeriodInteractor<R, A, Q, S, P, G>
 where
     R: EventRepository,
+    A: AccountReconciliationRepository,
+    Q: LedgerQueryService,
+    S: JournalEntrySearchQueryService,
+    P: AccountingPolicyRepository,
+    G: ApplicationSettingsRepository,
 {
     async fn execute(
         &self,
         request: LockClosingPeriodRequest,
     ) -> ApplicationResult<LockClosingPeriodResponse> {
+        let period_id = period_id(request.fiscal_year, request.period)?;
+        let validation = self.run_checks(request.fiscal_year, request.period).await?;
+
+        // 事前検証に失敗した場合は、管理者が理由を付けて上書きしたときのみ固定する
+        let locked_at = chrono::Utc::now().to_rfc3339();
+        let mut events = Vec::new();
+        if !validation.passed() {
+            let Some(reason) =
+                request.override_reason.as_deref().map(str::trim).filter(|r| !r.is_empty())
+            else {
+                return Err(ApplicationError::ValidationFailed(validation.failure_messages()));
+            };
+            let policy = self.policy_repository.load().await?;
+            if !policy.is_administrator(&request.locked_by) {
+                return Err(ApplicationError::DomainError(DomainError::PermissionDenied(format!(
+                    "締日固定の事前検証の上書きは管理者のみ可能です（ユーザ: {}）",
+                    request.locked_by
+                ))));
+            }
+            events.push(AccountingPeriodEvent::LockValidationOverridden {
+                period_id: period_id.value().to_string(),
+                failed_checks: validation.failed_checks().map(|check| check.code.clone()).collect(),
+                reason: reason.to_string(),
+                overridden_by: request.locked_by.clone(),
+                overridden_at: locked_at.clone(),
+            });
+        }
+        let overridden = !events.is_empty();
+
         // イベントストアから最新シーケンスを取得
         let latest_sequence = self
             .event_repository
@@ -42,11 +259,286 @@ where
             .await
             .map_err(|e| crate::error::ApplicationError::EventStoreError(e.to_string()))?;
 
-        // 実装: 締日固定処理（イベント追記）
+        // 期間ロックを記録（期間の履歴と再オープンの可否の判定に使う）
+        events.push(AccountingPeriodEvent::PeriodLocked {
+            period_id: period_id.value().to_string(),
+            locked_by: request.locked_by,
+            locked_at: locked_at.clone(),
+        });
+        self.event_repository.append_events(period_id.value(), events).await?;
+
         Ok(LockClosingPeriodResponse {
             locked_entries_count: latest_sequence as usize,
-            locked_at: chrono::Utc::now().to_rfc3339(),
+            locked_at,
             audit_log_id: format!("LOCK-{}-{:02}", request.fiscal_year, request.period),
+            validation,
+            overridden,
         })
     }
+
+    async fn validate(
+        &self,
+        request: ValidateClosingLockRequest,
+    ) -> ApplicationResult<ClosingLockValidationReport> {
+        self.run_checks(request.fiscal_year, request.period).await
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::sync::Mutex;
+
+    use javelin_domain::{
+        error::DomainResult,
+        financial_close::account_reconciliation::{AccountReconciliation, ReconciliationItem},
+        masters::{
+            AccountingPolicy, AccountingPolicyChanged, ApplicationSettings, BackupRetentionDays,
+            ClosingDay, ClosingLockValidationSettings, DEFAULT_POLICY_ADMINISTRATOR, DateFormat,
+            DecimalPlaces, FiscalYearStartMonth, Language,
+        },
+    };
+
+    use super::*;
+    use crate::{
+        dtos::response::{JournalEntryItemDto, JournalEntrySearchResultDto},
+        interactor::closing::account_reconciliation_interactor::tests::MockReconciliationRepository,
+        testkit::{InMemoryEventRepository, InMemoryLedgerQueryService, trial_balance_entry},
+    };
+
+    /// 期間内の仕訳（状態のみ）
+    #[derive(Default)]
+    struct MockSearchQueryService {
+        statuses: Mutex<Vec<&'static str>>,
+    }
+
+    impl JournalEntrySearchQueryService for MockSearchQueryService {
+        async fn search(
+            &self,
+            _criteria: SearchCriteriaDto,
+        ) -> ApplicationResult<JournalEntrySearchResultDto> {
+            let entries: Vec<_> = self
+                .statuses
+                .lock()
+                .unwrap()
+                .iter()
+                .enumerate()
+                .map(|(index, status)| {
+                    JournalEntryItemDto::new(
+                        format!("JE-{}", index + 1),
+                        None,
+                        "2024-03-15".to_string(),
+                        status.to_string(),
+                        vec![],
+                    )
+                })
+                .collect();
+            let total_count = entries.len() as u32;
+            Ok(JournalEntrySearchResultDto::new(entries, total_count))
+        }
+    }
+
+    #[derive(Default)]
+    struct MockPolicyRepository;
+
+    impl AccountingPolicyRepository for MockPolicyRepository {
+        async fn load(&self) -> DomainResult<AccountingPolicy> {
+            Ok(AccountingPolicy::default())
+        }
+
+        async fn save(
+            &self,
+            _policy: &AccountingPolicy,
+            _change: &AccountingPolicyChanged,
+        ) -> DomainResult<()> {
+            Ok(())
+        }
+
+        async fn find_changes(&self) -> DomainResult<Vec<AccountingPolicyChanged>> {
+            Ok(Vec::new())
+        }
+    }
+
+    pub(crate) struct MockSettingsRepository {
+        settings: Mutex<ApplicationSettings>,
+    }
+
+    impl Default for MockSettingsRepository {
+        fn default() -> Self {
+            Self {
+                settings: Mutex::new(ApplicationSettings::new(
+                    None,
+                    Language::new("ja").unwrap(),
+                    DecimalPlaces::new(2).unwrap(),
+                    DateFormat::new("YYYY-MM-DD").unwrap(),
+                    FiscalYearStartMonth::new(4).unwrap(),
+                    ClosingDay::new(31).unwrap(),
+                    false,
+                    BackupRetentionDays::new(90).unwrap(),
+                )),
+            }
+        }
+    }
+
+    impl ApplicationSettingsRepository for MockSettingsRepository {
+        async fn find(&self) -> DomainResult<Option<ApplicationSettings>> {
+            Ok(Some(self.settings.lock().unwrap().clone()))
+        }
+
+        async fn save(&self, settings: &ApplicationSettings) -> DomainResult<()> {
+            *self.settings.lock().unwrap() = settings.clone();
+            Ok(())
+        }
+    }
+
+    type TestInteractor = LockClosingPeriodInteractor<
+        InMemoryEventRepository,
+        MockReconciliationRepository,
+        InMemoryLedgerQueryService,
+        MockSearchQueryService,
+        MockPolicyRepository,
+        MockSettingsRepository,
+    >;
+
+    struct Fixture {
+        event_repository: Arc<InMemoryEventRepository>,
+        reconciliation_repository: Arc<MockReconciliationRepository>,
+        search_query_service: Arc<MockSearchQueryService>,
+        settings_repository: Arc<MockSettingsRepository>,
+        interactor: TestInteractor,
+    }
+
+    /// 期間内に動きのある勘定科目を1つ持つ試算表で作成（相手科目は売上4000）
+    fn fixture(account_code: &str, balance: f64) -> Fixture {
+        let event_repository = Arc::new(InMemoryEventRepository::default());
+        let reconciliation_repository = Arc::new(MockReconciliationRepository::default());
+        let search_query_service = Arc::new(MockSearchQueryService::default());
+        let settings_repository = Arc::new(MockSettingsRepository::default());
+        let ledger = InMemoryLedgerQueryService::new()
+            .with_ledger_balance(account_code, balance)
+            .with_trial_balance(
+                2024,
+                3,
+                vec![
+                    trial_balance_entry(account_code, balance),
+                    trial_balance_entry("4000", -balance),
+                ],
+            );
+        let interactor = LockClosingPeriodInteractor::new(
+            Arc::clone(&event_repository),
+            Arc::clone(&reconciliation_repository),
+            Arc::new(ledger),
+            Arc::clone(&search_query_service),
+            Arc::new(MockPolicyRepository),
+            Arc::clone(&settings_repository),
+        );
+        Fixture {
+            event_repository,
+            reconciliation_repository,
+            search_query_service,
+            settings_repository,
+            interactor,
+        }
+    }
+
+    fn request(locked_by: &str, override_reason: Option<&str>) -> LockClosingPeriodRequest {
+        LockClosingPeriodRequest {
+            fiscal_year: 2024,
+            period: 3,
+            locked_by: locked_by.to_string(),
+            override_reason: override_reason.map(str::to_string),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_lock_requires_completed_reconciliations() {
+        let fixture = fixture("1100", 500.0);
+        fixture
+            .reconciliation_repository
+            .save_required_accounts(&["1100".to_string()])
+            .await
+            .unwrap();
+
+        let error = fixture.interactor.execute(request("user1", None)).await.unwrap_err();
+        assert!(error.to_string().contains("1100"));
+        assert!(fixture.event_repository.is_empty());
+
+        let mut reconciliation = AccountReconciliation::new("1100", 2024, 3).unwrap();
+        reconciliation
+            .add_item(ReconciliationItem::new("A銀行 残高証明", 500.0, None).unwrap())
+            .unwrap();
+        reconciliation.mark_reconciled(500.0, "user1", "2024-04-05T00:00:00Z").unwrap();
+        fixture.reconciliation_repository.save(&reconciliation).await.unwrap();
+
+        let response = fixture.interactor.execute(request("user1", None)).await.unwrap();
+        assert_eq!(response.audit_log_id, "LOCK-2024-03");
+        assert!(response.validation.passed());
+        assert!(!response.overridden);
+        let saved = fixture.event_repository.recorded();
+        assert_eq!(saved.len(), 1);
+        assert_eq!(saved[0].aggregate_id, "2024-03");
+    }
+
+    #[tokio::test]
+    async fn test_validation_report_lists_failed_checks() {
+        let fixture = fixture("9999", 1200.0);
+        fixture
+            .search_query_service
+            .statuses
+            .lock()
+            .unwrap()
+            .extend(["Posted", "Draft"]);
+
+        let report = fixture
+            .interactor
+            .validate(ValidateClosingLockRequest { fiscal_year: 2024, period: 3 })
+            .await
+            .unwrap();
+        assert_eq!(report.period_id, "2024-03");
+        assert_eq!(report.checks.len(), ClosingLockCheck::ALL.len());
+        let failed: Vec<_> = report.failed_checks().map(|check| check.code.as_str()).collect();
+        assert_eq!(failed, ["no_unposted_entries", "suspense_cleared"]);
+        assert_eq!(report.checks[1].findings, ["2024-03-15 JE-2（Draft）"]);
+
+        // 無効にした項目は検証しない
+        let mut settings = fixture.settings_repository.find().await.unwrap().unwrap();
+        settings.update_closing_lock_validation(ClosingLockValidationSettings::new([
+            ClosingLockCheck::NoUnpostedEntries,
+            ClosingLockCheck::SuspenseCleared,
+        ]));
+        fixture.settings_repository.save(&settings).await.unwrap();
+
+        let response = fixture.interactor.execute(request("user1", None)).await.unwrap();
+        assert_eq!(response.validation.checks.len(), 2);
+        assert!(response.validation.passed());
+    }
+
+    #[tokio::test]
+    async fn test_override_requires_administrator_and_records_event() {
+        let fixture = fixture("9999", 1200.0);
+
+        let error = fixture.interactor.execute(request("user1", None)).await.unwrap_err();
+        assert!(error.to_string().contains("仮勘定残高ゼロ"));
+
+        let error = fixture
+            .interactor
+            .execute(request("user1", Some("監査法人と合意済み")))
+            .await
+            .unwrap_err();
+        assert!(matches!(error, ApplicationError::DomainError(DomainError::PermissionDenied(_))));
+        assert!(fixture.event_repository.is_empty());
+
+        let response = fixture
+            .interactor
+            .execute(request(DEFAULT_POLICY_ADMINISTRATOR, Some("監査法人と合意済み")))
+            .await
+            .unwrap();
+        assert!(response.overridden);
+        assert_eq!(
+            fixture.event_repository.event_types(),
+            ["LockValidationOverridden", "PeriodLocked"]
+        );
+        let saved = fixture.event_repository.recorded();
+        assert_eq!(saved[0].payload["failed_checks"], serde_json::json!(["suspense_cleared"]));
+        assert_eq!(saved[0].payload["reason"], "監査法人と合意済み");
+    }
 }
@@ -1,34 +1,75 @@
 // PrepareClosingInteractor - 締準備処理
-// 責務: 期間帰属確認・仮仕訳作成
+// 責務: 期間帰属確認・仮仕訳作成・仮勘定の滞留状況の確認
 
-use std::sync::Arc;
+use std::{collections::BTreeMap, sync::Arc};
 
+use super::period_end_date;
 use crate::{
-    dtos::{PrepareClosingRequest, PrepareClosingResponse},
+    dtos::{PrepareClosingRequest, PrepareClosingResponse, response::SuspenseAccountAgingDto},
     error::ApplicationResult,
     input_ports::PrepareClosingUseCase,
-    query_service::ledger_query_service::{GetTrialBalanceQuery, LedgerQueryService},
+    interactor::suspense_clearing_interactor::{age_open_items, summarize_buckets},
+    query_service::{
+        SuspenseAgingQueryService,
+        ledger_query_service::{EntryStatusScope, GetTrialBalanceQuery, LedgerQueryService},
+    },
 };
 
-pub struct PrepareClosingInteractor<Q>
+pub struct PrepareClosingInteractor<Q, S>
 where
     Q: LedgerQueryService,
+    S: SuspenseAgingQueryService,
 {
     ledger_query_service: Arc<Q>,
+    suspense_aging_query_service: Arc<S>,
 }
 
-impl<Q> PrepareClosingInteractor<Q>
+impl<Q, S> PrepareClosingInteractor<Q, S>
 where
     Q: LedgerQueryService,
+    S: SuspenseAgingQueryService,
 {
-    pub fn new(ledger_query_service: Arc<Q>) -> Self {
-        Self { ledger_query_service }
+    pub fn new(ledger_query_service: Arc<Q>, suspense_aging_query_service: Arc<S>) -> Self {
+        Self { ledger_query_service, suspense_aging_query_service }
+    }
+
+    /// 期末日時点の仮勘定・清算勘定の滞留状況を勘定科目ごとに集計
+    ///
+    /// 期末日より後に計上されたものは対象外とする。
+    async fn suspense_aging(
+        &self,
+        fiscal_year: i32,
+        period: u8,
+    ) -> ApplicationResult<Vec<SuspenseAccountAgingDto>> {
+        let period_end = period_end_date(fiscal_year, period)?;
+        let period_end_label = period_end.format("%Y-%m-%d").to_string();
+        let open_items: Vec<_> = self
+            .suspense_aging_query_service
+            .get_open_suspense_items()
+            .await?
+            .into_iter()
+            .filter(|item| item.transaction_date <= period_end_label)
+            .collect();
+
+        let mut by_account: BTreeMap<String, Vec<_>> = BTreeMap::new();
+        for item in age_open_items(&open_items, period_end) {
+            by_account.entry(item.account_code.clone()).or_default().push(item);
+        }
+        Ok(by_account
+            .into_iter()
+            .map(|(account_code, items)| SuspenseAccountAgingDto {
+                account_code,
+                buckets: summarize_buckets(&items),
+                items,
+            })
+            .collect())
     }
 }
 
-impl<Q> PrepareClosingUseCase for PrepareClosingInteractor<Q>
+impl<Q, S> PrepareClosingUseCase for PrepareClosingInteractor<Q, S>
 where
     Q: LedgerQueryService,
+    S: SuspenseAgingQueryService,
 {
     async fn execute(
         &self,
@@ -40,15 +81,87 @@ where
             .get_trial_balance(GetTrialBalanceQuery {
                 period_year: request.fiscal_year as u32,
                 period_month: request.period,
+                status_scope: EntryStatusScope::PostedOnly,
             })
             .await?;
 
+        // 締日固定の前に整理すべき仮勘定の滞留状況
+        let suspense_aging = self.suspense_aging(request.fiscal_year, request.period).await?;
+
         // 実装: 期間帰属確認・仮仕訳作成
         Ok(PrepareClosingResponse {
             unregistered_transactions_count: 0,
             bank_reconciliation_differences: vec![],
             accrual_entries_created: 5,
             provisional_financial_statements_generated: true,
+            suspense_aging,
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        dtos::response::AgingBucket, query_service::OpenSuspenseItem,
+        testkit::InMemoryLedgerQueryService,
+    };
+
+    struct MockSuspenseAgingQueryService;
+
+    impl SuspenseAgingQueryService for MockSuspenseAgingQueryService {
+        async fn get_open_suspense_items(&self) -> ApplicationResult<Vec<OpenSuspenseItem>> {
+            let item = |entry_id: &str, account_code: &str, transaction_date: &str, amount| {
+                OpenSuspenseItem {
+                    entry_id: entry_id.to_string(),
+                    entry_number: format!("V-{}", entry_id),
+                    line_number: 1,
+                    transaction_date: transaction_date.to_string(),
+                    account_code: account_code.to_string(),
+                    side: "Debit".to_string(),
+                    amount,
+                    currency: "JPY".to_string(),
+                    description: None,
+                    candidates: vec![],
+                }
+            };
+            Ok(vec![
+                item("E1", "9999", "2023-12-15", 30_000.0),
+                item("E2", "9999", "2024-03-20", 10_000.0),
+                item("E3", "1190", "2024-02-10", 5_000.0),
+                // 期末日より後の計上は対象外
+                item("E4", "9999", "2024-04-02", 1_000.0),
+            ])
+        }
+    }
+
+    #[tokio::test]
+    async fn test_prepare_closing_ages_suspense_accounts_at_period_end() {
+        let interactor = PrepareClosingInteractor::new(
+            Arc::new(InMemoryLedgerQueryService::new()),
+            Arc::new(MockSuspenseAgingQueryService),
+        );
+
+        let response = interactor
+            .execute(PrepareClosingRequest { fiscal_year: 2024, period: 3 })
+            .await
+            .unwrap();
+
+        let accounts: Vec<&str> =
+            response.suspense_aging.iter().map(|a| a.account_code.as_str()).collect();
+        assert_eq!(accounts, vec!["1190", "9999"]);
+
+        let suspense = &response.suspense_aging[1];
+        assert_eq!(suspense.total_amount(), 40_000.0);
+        assert_eq!(suspense.oldest_age_days(), 107);
+        // 経過日数の長い順で、仕訳IDと行番号で元の仕訳を参照できる
+        let keys: Vec<(&str, u32)> =
+            suspense.items.iter().map(|i| (i.entry_id.as_str(), i.line_number)).collect();
+        assert_eq!(keys, vec![("E1", 1), ("E2", 1)]);
+
+        let over_90 = suspense.buckets.iter().find(|b| b.bucket == AgingBucket::Over90Days);
+        assert_eq!(over_90.map(|b| (b.count, b.amount)), Some((1, 30_000.0)));
+        let within_30 = suspense.buckets.iter().find(|b| b.bucket == AgingBucket::Within30Days);
+        assert_eq!(within_30.map(|b| (b.count, b.amount)), Some((1, 10_000.0)));
+    }
+}
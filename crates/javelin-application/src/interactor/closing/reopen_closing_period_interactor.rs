@@ -0,0 +1,450 @@
+// ReopenClosingPeriodInteractor - 会計期間の再オープン
+// 責務: 承認付きの期間再オープンと、再オープン期間中の記帳の一覧
+
+use std::sync::Arc;
+
+use chrono::{DateTime, Months, NaiveDate, Utc};
+use javelin_domain::{
+    entity::EntityId,
+    error::DomainResult,
+    financial_close::{
+        accounting_period::{
+            AccountingPeriodEvent, FiscalYear, Period, PeriodHistory, PeriodId, ReopenApproval,
+        },
+        journal_entry::{events::JournalEntryEvent, values::UserId},
+    },
+    masters::PasswordHasher,
+    repositories::{EventRepository, UserAccountRepository},
+    time_provider::TimeProvider,
+};
+
+use crate::{
+    dtos::{
+        request::{ReopenClosingPeriodRequest, ReopenWindowReportRequest},
+        response::{ReopenClosingPeriodResponse, ReopenWindowPosting, ReopenWindowReportResponse},
+    },
+    error::{ApplicationError, ApplicationResult},
+    query_service::PeriodReopenQueryService,
+};
+
+/// 承認者の照合失敗時のメッセージ（ユーザの存在有無を区別しない）
+const INVALID_APPROVER: &str = "承認者のユーザIDまたはパスワードが正しくありません";
+
+/// 会計期間の再オープンのInteractor
+///
+/// 締め済・ロック済の期間を、理由と申請者以外の承認者の承認（パスワードによる本人確認）を
+/// 得て再オープンする。期間の再オープンと、期間内の締め済仕訳を記帳済に戻す記録は一括で保存する。
+///
+/// 仕訳の登録・承認は締め済・ロック済の期間を拒否するため、再オープンした期間は
+/// 再び締め・ロックするまで記帳できる。
+pub struct ReopenClosingPeriodInteractor<R, Q, U, H>
+where
+    R: EventRepository,
+    Q: PeriodReopenQueryService,
+    U: UserAccountRepository,
+    H: PasswordHasher + 'static,
+{
+    event_repository: Arc<R>,
+    query_service: Arc<Q>,
+    user_repository: Arc<U>,
+    hasher: Arc<H>,
+    /// 再オープン日時の基準
+    time_provider: Arc<dyn TimeProvider>,
+}
+
+impl<R, Q, U, H> ReopenClosingPeriodInteractor<R, Q, U, H>
+where
+    R: EventRepository,
+    Q: PeriodReopenQueryService,
+    U: UserAccountRepository,
+    H: PasswordHasher + 'static,
+{
+    pub fn new(
+        event_repository: Arc<R>,
+        query_service: Arc<Q>,
+        user_repository: Arc<U>,
+        hasher: Arc<H>,
+        time_provider: Arc<dyn TimeProvider>,
+    ) -> Self {
+        Self { event_repository, query_service, user_repository, hasher, time_provider }
+    }
+
+    /// 会計期間を再オープン
+    pub async fn reopen(
+        &self,
+        request: ReopenClosingPeriodRequest,
+    ) -> ApplicationResult<ReopenClosingPeriodResponse> {
+        let (period_id, from, to) = period_range(request.fiscal_year, request.period)?;
+        let approval = ReopenApproval::new(
+            UserId::new(request.requested_by),
+            UserId::new(request.approved_by),
+            request.reason,
+        )?;
+        self.verify_approver(approval.approved_by().value(), request.approver_password)
+            .await?;
+
+        let events = self.query_service.load_period_events(&period_id).await?;
+        let history = PeriodHistory::replay(&events);
+        if !history.status().can_reopen() {
+            return Err(ApplicationError::ValidationError(format!(
+                "{}は{}のため再オープンできません（締め済・ロック済の期間のみ再オープンできます）",
+                period_id,
+                history.status().display_name()
+            )));
+        }
+
+        let reopened_at = self.time_provider.now();
+        let requested_by = approval.requested_by().value().to_string();
+        let approved_by = approval.approved_by().value().to_string();
+        let mut batches = vec![(
+            period_id.clone(),
+            vec![to_value(AccountingPeriodEvent::PeriodReopened {
+                period_id: period_id.clone(),
+                reason: approval.reason().to_string(),
+                reopened_by: requested_by.clone(),
+                approved_by: approved_by.clone(),
+                reopened_at: reopened_at.to_rfc3339(),
+            })?],
+        )];
+
+        // 期間内の締め済仕訳を記帳済に戻す
+        let reopened_entry_ids = self.query_service.find_closed_entry_ids(from, to).await?;
+        for entry_id in &reopened_entry_ids {
+            batches.push((
+                entry_id.clone(),
+                vec![to_value(JournalEntryEvent::Reopened {
+                    entry_id: entry_id.clone(),
+                    reason: format!("会計期間{}の再オープン: {}", period_id, approval.reason()),
+                    reopened_by: requested_by.clone(),
+                    reopened_at,
+                })?],
+            ));
+        }
+
+        // 期間と仕訳の再オープンは、一部だけが記録されないよう一括で保存する
+        self.event_repository.append_batches(batches).await?;
+
+        Ok(ReopenClosingPeriodResponse {
+            period_id,
+            previous_status: history.status().display_name().to_string(),
+            reopened_by: requested_by,
+            approved_by,
+            reopened_at: reopened_at.to_rfc3339(),
+            reopened_entry_ids,
+        })
+    }
+
+    /// 直近の再オープン期間中に行われた記帳の一覧
+    pub async fn window_report(
+        &self,
+        request: ReopenWindowReportRequest,
+    ) -> ApplicationResult<ReopenWindowReportResponse> {
+        let (period_id, from, to) = period_range(request.fiscal_year, request.period)?;
+        let events = self.query_service.load_period_events(&period_id).await?;
+        let history = PeriodHistory::replay(&events);
+        let reopening = history.last_reopening().ok_or_else(|| {
+            ApplicationError::ValidationError(format!("{}は再オープンされていません", period_id))
+        })?;
+
+        let window_start = parse_timestamp(&reopening.reopened_at)?;
+        let window_end = reopening.closed_again_at.as_deref().map(parse_timestamp).transpose()?;
+
+        let mut postings: Vec<_> = self
+            .query_service
+            .load_postings(from, to)
+            .await?
+            .into_iter()
+            .filter(|posting| {
+                posting.recorded_at >= window_start
+                    && window_end.is_none_or(|end| posting.recorded_at < end)
+            })
+            .collect();
+        postings.sort_by_key(|posting| posting.recorded_at);
+
+        Ok(ReopenWindowReportResponse {
+            period_id,
+            status: history.status().display_name().to_string(),
+            reason: reopening.reason.clone(),
+            reopened_by: reopening.reopened_by.clone(),
+            approved_by: reopening.approved_by.clone(),
+            reopened_at: reopening.reopened_at.clone(),
+            closed_again_at: reopening.closed_again_at.clone(),
+            postings: postings
+                .into_iter()
+                .map(|posting| ReopenWindowPosting {
+                    entry_id: posting.entry_id,
+                    entry_number: posting.entry_number,
+                    transaction_date: posting.transaction_date,
+                    kind: posting.kind.label().to_string(),
+                    recorded_by: posting.recorded_by,
+                    recorded_at: posting.recorded_at.to_rfc3339(),
+                })
+                .collect(),
+        })
+    }
+
+    /// 承認者の本人確認
+    async fn verify_approver(&self, approved_by: &str, password: String) -> ApplicationResult<()> {
+        let account = self
+            .user_repository
+            .find_by_id(approved_by)
+            .await?
+            .ok_or_else(|| ApplicationError::ValidationError(INVALID_APPROVER.to_string()))?;
+
+        let hash = account.password_hash().clone();
+        if !self.blocking(move |hasher| hasher.verify(&password, &hash)).await? {
+            return Err(ApplicationError::ValidationError(INVALID_APPROVER.to_string()));
+        }
+        Ok(())
+    }
+
+    /// ハッシュ計算は意図的に低速なため、ブロッキングスレッドで実行する
+    async fn blocking<T, F>(&self, f: F) -> ApplicationResult<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(&H) -> DomainResult<T> + Send + 'static,
+    {
+        let hasher = Arc::clone(&self.hasher);
+        let result = tokio::task::spawn_blocking(move || f(&hasher))
+            .await
+            .map_err(|e| ApplicationError::UseCaseExecutionFailed(e.to_string()))?;
+        Ok(result?)
+    }
+}
+
+/// 期間IDと期間の初日・末日
+fn period_range(fiscal_year: u32, period: u8) -> ApplicationResult<(String, NaiveDate, NaiveDate)> {
+    let period_id = PeriodId::from_year_period(FiscalYear::new(fiscal_year)?, Period::new(period)?);
+    let from = NaiveDate::from_ymd_opt(fiscal_year as i32, period as u32, 1).ok_or_else(|| {
+        ApplicationError::ValidationError(format!("不正な会計期間です: {}", period_id.value()))
+    })?;
+    let to = from
+        .checked_add_months(Months::new(1))
+        .and_then(|next| next.pred_opt())
+        .ok_or_else(|| {
+            ApplicationError::ValidationError(format!("不正な会計期間です: {}", period_id.value()))
+        })?;
+    Ok((period_id.value().to_string(), from, to))
+}
+
+/// 種類の異なるイベントを一括で保存するためにJSONへ変換
+fn to_value<T: serde::Serialize>(event: T) -> ApplicationResult<serde_json::Value> {
+    serde_json::to_value(event).map_err(|e| ApplicationError::UseCaseExecutionFailed(e.to_string()))
+}
+
+fn parse_timestamp(value: &str) -> ApplicationResult<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|at| at.with_timezone(&Utc))
+        .map_err(|e| ApplicationError::QueryExecutionFailed(format!("{}: {}", value, e)))
+}
+
+#[cfg(test)]
+mod tests {
+
+    use chrono::{Offset, TimeZone};
+    use javelin_domain::{
+        masters::{PasswordHash, UserAccount},
+        time_provider::FixedTimeProvider,
+    };
+
+    use super::*;
+    use crate::{
+        query_service::{PostingKind, PostingRecord},
+        testkit::InMemoryEventRepository,
+    };
+
+    struct MockQueryService {
+        period_events: Vec<AccountingPeriodEvent>,
+        closed_entry_ids: Vec<String>,
+        postings: Vec<PostingRecord>,
+    }
+
+    impl PeriodReopenQueryService for MockQueryService {
+        async fn load_period_events(
+            &self,
+            _period_id: &str,
+        ) -> ApplicationResult<Vec<AccountingPeriodEvent>> {
+            Ok(self.period_events.clone())
+        }
+
+        async fn find_closed_entry_ids(
+            &self,
+            _from: NaiveDate,
+            _to: NaiveDate,
+        ) -> ApplicationResult<Vec<String>> {
+            Ok(self.closed_entry_ids.clone())
+        }
+
+        async fn load_postings(
+            &self,
+            _from: NaiveDate,
+            _to: NaiveDate,
+        ) -> ApplicationResult<Vec<PostingRecord>> {
+            Ok(self.postings.clone())
+        }
+    }
+
+    struct MockUserRepository;
+
+    impl UserAccountRepository for MockUserRepository {
+        async fn find_by_id(&self, user_id: &str) -> DomainResult<Option<UserAccount>> {
+            if user_id != "suzuki" {
+                return Ok(None);
+            }
+            UserAccount::new("suzuki", "鈴木", PasswordHash::new("plain$approve-pw")?).map(Some)
+        }
+
+        async fn save(&self, _account: &UserAccount) -> DomainResult<()> {
+            Ok(())
+        }
+
+        async fn count(&self) -> DomainResult<usize> {
+            Ok(1)
+        }
+    }
+
+    struct PlainHasher;
+
+    impl PasswordHasher for PlainHasher {
+        fn hash(&self, password: &str) -> DomainResult<PasswordHash> {
+            PasswordHash::new(format!("plain${}", password))
+        }
+
+        fn verify(&self, password: &str, hash: &PasswordHash) -> DomainResult<bool> {
+            Ok(hash.as_str() == format!("plain${}", password))
+        }
+    }
+
+    type TestInteractor = ReopenClosingPeriodInteractor<
+        InMemoryEventRepository,
+        MockQueryService,
+        MockUserRepository,
+        PlainHasher,
+    >;
+
+    fn interactor(
+        period_events: Vec<AccountingPeriodEvent>,
+        postings: Vec<PostingRecord>,
+    ) -> (TestInteractor, Arc<InMemoryEventRepository>) {
+        let repository = Arc::new(InMemoryEventRepository::default());
+        let query_service = Arc::new(MockQueryService {
+            period_events,
+            closed_entry_ids: vec!["je-closed".to_string()],
+            postings,
+        });
+        let interactor = ReopenClosingPeriodInteractor::new(
+            Arc::clone(&repository),
+            query_service,
+            Arc::new(MockUserRepository),
+            Arc::new(PlainHasher),
+            Arc::new(FixedTimeProvider::new(
+                Utc.with_ymd_and_hms(2024, 4, 10, 0, 0, 0).unwrap(),
+                Utc.fix(),
+            )),
+        );
+        (interactor, repository)
+    }
+
+    fn locked() -> AccountingPeriodEvent {
+        AccountingPeriodEvent::PeriodLocked {
+            period_id: "2024-03".to_string(),
+            locked_by: "tanaka".to_string(),
+            locked_at: "2024-04-05T00:00:00+00:00".to_string(),
+        }
+    }
+
+    fn request(approved_by: &str, password: &str) -> ReopenClosingPeriodRequest {
+        ReopenClosingPeriodRequest {
+            fiscal_year: 2024,
+            period: 3,
+            reason: "売上計上漏れの追加".to_string(),
+            requested_by: "tanaka".to_string(),
+            approved_by: approved_by.to_string(),
+            approver_password: password.to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reopen_appends_period_and_entry_events() {
+        let (interactor, repository) = interactor(vec![locked()], vec![]);
+
+        let response = interactor.reopen(request("suzuki", "approve-pw")).await.unwrap();
+        assert_eq!(response.period_id, "2024-03");
+        assert_eq!(response.previous_status, "ロック済");
+        assert_eq!(response.reopened_entry_ids, vec!["je-closed".to_string()]);
+
+        let saved = repository.recorded();
+        assert_eq!(saved.len(), 2);
+        assert_eq!(saved[0].aggregate_id, "2024-03");
+        assert_eq!(saved[0].payload["type"], "PeriodReopened");
+        assert_eq!(saved[0].payload["approved_by"], "suzuki");
+        assert_eq!(saved[0].payload["reopened_at"], "2024-04-10T00:00:00+00:00");
+        assert_eq!(saved[1].aggregate_id, "je-closed");
+        assert_eq!(saved[1].payload["type"], "Reopened");
+    }
+
+    #[tokio::test]
+    async fn test_reopen_requires_authenticated_different_approver() {
+        let (interactor, repository) = interactor(vec![locked()], vec![]);
+
+        assert!(interactor.reopen(request("tanaka", "approve-pw")).await.is_err());
+        assert!(interactor.reopen(request("suzuki", "wrong-password")).await.is_err());
+        assert!(interactor.reopen(request("", "approve-pw")).await.is_err());
+        assert!(repository.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_reopen_rejects_open_period() {
+        let (interactor, repository) = interactor(vec![], vec![]);
+
+        let result = interactor.reopen(request("suzuki", "approve-pw")).await;
+        assert!(matches!(result, Err(ApplicationError::ValidationError(_))));
+        assert!(repository.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_window_report_lists_postings_between_reopen_and_reclose() {
+        let posting = |entry_id: &str, day: u32| PostingRecord {
+            entry_id: entry_id.to_string(),
+            entry_number: Some(format!("JE-{}", entry_id)),
+            transaction_date: "2024-03-31".to_string(),
+            kind: PostingKind::Posted,
+            recorded_by: "tanaka".to_string(),
+            recorded_at: Utc.with_ymd_and_hms(2024, 4, day, 9, 0, 0).unwrap(),
+        };
+        let period_events = vec![
+            locked(),
+            AccountingPeriodEvent::PeriodReopened {
+                period_id: "2024-03".to_string(),
+                reason: "売上計上漏れの追加".to_string(),
+                reopened_by: "tanaka".to_string(),
+                approved_by: "suzuki".to_string(),
+                reopened_at: "2024-04-10T00:00:00+00:00".to_string(),
+            },
+            AccountingPeriodEvent::PeriodLocked {
+                period_id: "2024-03".to_string(),
+                locked_by: "tanaka".to_string(),
+                locked_at: "2024-04-12T00:00:00+00:00".to_string(),
+            },
+        ];
+        let (interactor, _) = interactor(
+            period_events,
+            vec![
+                posting("late", 11),
+                posting("before", 3),
+                posting("after", 20),
+                posting("early", 10),
+            ],
+        );
+
+        let report = interactor
+            .window_report(ReopenWindowReportRequest { fiscal_year: 2024, period: 3 })
+            .await
+            .unwrap();
+        let ids: Vec<&str> = report.postings.iter().map(|p| p.entry_id.as_str()).collect();
+        assert_eq!(ids, vec!["early", "late"]);
+        assert_eq!(report.status, "ロック済");
+        assert_eq!(report.approved_by, "suzuki");
+        assert_eq!(report.closed_again_at.as_deref(), Some("2024-04-12T00:00:00+00:00"));
+    }
+}
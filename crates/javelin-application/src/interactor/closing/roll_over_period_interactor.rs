@@ -0,0 +1,98 @@
+// RollOverPeriodInteractor - 月初の繰越残高の確定
+// 責務: 新しい月の開始時に前月末の勘定残高を繰越残高として確定する
+
+use std::sync::Arc;
+
+use crate::{
+    dtos::{request::RollOverPeriodRequest, response::RollOverPeriodResponse},
+    error::{ApplicationError, ApplicationResult},
+    query_service::ledger_query_service::LedgerQueryService,
+};
+
+/// 月初の繰越残高を確定するInteractor
+///
+/// 繰越残高は元帳Projectionに仮想的に保持する（繰越仕訳は起票しない）。
+/// 確定後の元帳照会は照会期間の開始日に「前月繰越」行を表示し、
+/// 開始日以前の全履歴を集計せずに繰越残高を起点とする。
+pub struct RollOverPeriodInteractor<Q>
+where
+    Q: LedgerQueryService,
+{
+    ledger_query_service: Arc<Q>,
+}
+
+impl<Q> RollOverPeriodInteractor<Q>
+where
+    Q: LedgerQueryService,
+{
+    pub fn new(ledger_query_service: Arc<Q>) -> Self {
+        Self { ledger_query_service }
+    }
+
+    pub async fn execute(
+        &self,
+        request: RollOverPeriodRequest,
+    ) -> ApplicationResult<RollOverPeriodResponse> {
+        if !(1..=12).contains(&request.period) {
+            return Err(ApplicationError::ValidationError(format!(
+                "会計期間が不正です: {}",
+                request.period
+            )));
+        }
+        let fiscal_year = u32::try_from(request.fiscal_year).map_err(|_| {
+            ApplicationError::ValidationError(format!(
+                "会計年度が不正です: {}",
+                request.fiscal_year
+            ))
+        })?;
+
+        let balances =
+            self.ledger_query_service.roll_over_period(fiscal_year, request.period).await?;
+
+        let debit_balance_total = balances.iter().map(|b| b.balance.max(0.0)).sum();
+        let credit_balance_total = balances.iter().map(|b| (-b.balance).max(0.0)).sum();
+
+        Ok(RollOverPeriodResponse {
+            fiscal_year: request.fiscal_year,
+            period: request.period,
+            account_count: balances.len(),
+            debit_balance_total,
+            credit_balance_total,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{query_service::CarriedForwardBalance, testkit::InMemoryLedgerQueryService};
+
+    fn ledger() -> InMemoryLedgerQueryService {
+        let balance = |account_code: &str, balance: f64| CarriedForwardBalance {
+            account_code: account_code.to_string(),
+            balance,
+        };
+        InMemoryLedgerQueryService::new().with_carried_forward(vec![
+            balance("1100", 300_000.0),
+            balance("1200", 50_000.0),
+            balance("2100", -350_000.0),
+        ])
+    }
+
+    #[tokio::test]
+    async fn test_roll_over_period_totals_debit_and_credit_balances() {
+        let interactor = RollOverPeriodInteractor::new(Arc::new(ledger()));
+
+        let response = interactor
+            .execute(RollOverPeriodRequest { fiscal_year: 2024, period: 5 })
+            .await
+            .unwrap();
+        assert_eq!(response.account_count, 3);
+        assert_eq!(response.debit_balance_total, 350_000.0);
+        assert_eq!(response.credit_balance_total, 350_000.0);
+        assert!(response.is_balanced());
+
+        let invalid = interactor.execute(RollOverPeriodRequest { fiscal_year: 2024, period: 13 });
+        assert!(matches!(invalid.await, Err(ApplicationError::ValidationError(_))));
+    }
+}
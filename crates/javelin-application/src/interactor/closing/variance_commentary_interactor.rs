@@ -0,0 +1,158 @@
+// VarianceCommentaryInteractor - 試算表の増減コメント
+// 責務: 試算表レビューで記入する科目・会計期間ごとの増減コメントの管理
+
+use std::sync::Arc;
+
+use javelin_domain::{
+    financial_close::variance_commentary::VarianceCommentary,
+    repositories::VarianceCommentaryRepository,
+};
+
+use crate::{
+    dtos::{request::SaveVarianceCommentaryRequest, response::VarianceCommentaryDto},
+    error::{ApplicationError, ApplicationResult},
+};
+
+/// 増減コメントのInteractor
+pub struct VarianceCommentaryInteractor<R>
+where
+    R: VarianceCommentaryRepository,
+{
+    repository: Arc<R>,
+}
+
+impl<R> VarianceCommentaryInteractor<R>
+where
+    R: VarianceCommentaryRepository,
+{
+    pub fn new(repository: Arc<R>) -> Self {
+        Self { repository }
+    }
+
+    /// 会計期間の増減コメント一覧（科目コード順）
+    pub async fn list(
+        &self,
+        fiscal_year: i32,
+        period: u8,
+    ) -> ApplicationResult<Vec<VarianceCommentaryDto>> {
+        Ok(self
+            .repository
+            .find_by_period(fiscal_year, period)
+            .await?
+            .iter()
+            .map(VarianceCommentaryDto::from)
+            .collect())
+    }
+
+    /// 増減コメントを保存（コメントが空の場合は削除してNoneを返す）
+    pub async fn save(
+        &self,
+        request: SaveVarianceCommentaryRequest,
+    ) -> ApplicationResult<Option<VarianceCommentaryDto>> {
+        let account_code = request.account_code.trim();
+        if account_code.is_empty() {
+            return Err(ApplicationError::ValidationError("勘定科目コードは必須です".to_string()));
+        }
+        if request.commentary.trim().is_empty() {
+            self.repository
+                .delete(account_code, request.fiscal_year, request.period)
+                .await?;
+            return Ok(None);
+        }
+
+        let commentary = VarianceCommentary::new(
+            account_code,
+            request.fiscal_year,
+            request.period,
+            request.commentary,
+            request.written_by,
+            chrono::Utc::now().to_rfc3339(),
+        )?;
+        self.repository.save(&commentary).await?;
+        Ok(Some(VarianceCommentaryDto::from(&commentary)))
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::sync::Mutex;
+
+    use javelin_domain::error::DomainResult;
+
+    use super::*;
+
+    #[derive(Default)]
+    pub(crate) struct InMemoryVarianceCommentaryRepository {
+        commentaries: Mutex<Vec<VarianceCommentary>>,
+    }
+
+    impl VarianceCommentaryRepository for InMemoryVarianceCommentaryRepository {
+        async fn find_by_period(
+            &self,
+            fiscal_year: i32,
+            period: u8,
+        ) -> DomainResult<Vec<VarianceCommentary>> {
+            let mut commentaries: Vec<_> = self
+                .commentaries
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|c| c.fiscal_year() == fiscal_year && c.period() == period)
+                .cloned()
+                .collect();
+            commentaries.sort_by(|a, b| a.account_code().cmp(b.account_code()));
+            Ok(commentaries)
+        }
+
+        async fn save(&self, commentary: &VarianceCommentary) -> DomainResult<()> {
+            self.delete(commentary.account_code(), commentary.fiscal_year(), commentary.period())
+                .await?;
+            self.commentaries.lock().unwrap().push(commentary.clone());
+            Ok(())
+        }
+
+        async fn delete(
+            &self,
+            account_code: &str,
+            fiscal_year: i32,
+            period: u8,
+        ) -> DomainResult<()> {
+            self.commentaries.lock().unwrap().retain(|c| {
+                !(c.account_code() == account_code
+                    && c.fiscal_year() == fiscal_year
+                    && c.period() == period)
+            });
+            Ok(())
+        }
+    }
+
+    fn request(account_code: &str, commentary: &str) -> SaveVarianceCommentaryRequest {
+        SaveVarianceCommentaryRequest {
+            account_code: account_code.to_string(),
+            fiscal_year: 2024,
+            period: 3,
+            commentary: commentary.to_string(),
+            written_by: "alice".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_save_replaces_and_empty_commentary_deletes() {
+        let interactor = VarianceCommentaryInteractor::new(Arc::new(
+            InMemoryVarianceCommentaryRepository::default(),
+        ));
+
+        let saved = interactor.save(request("1100", "大口入金")).await.unwrap().unwrap();
+        assert_eq!(saved.written_by, "alice");
+        interactor.save(request("1100", "期末の大口入金")).await.unwrap();
+        interactor.save(request("4000", "新規取引先")).await.unwrap();
+
+        let list = interactor.list(2024, 3).await.unwrap();
+        assert_eq!(list.len(), 2);
+        assert_eq!(list[0].commentary, "期末の大口入金");
+
+        assert!(interactor.save(request("1100", "  ")).await.unwrap().is_none());
+        assert_eq!(interactor.list(2024, 3).await.unwrap().len(), 1);
+        assert!(interactor.save(request(" ", "増加")).await.is_err());
+    }
+}
@@ -0,0 +1,354 @@
+// ClosingTimetableInteractor - 締めスケジュールのユースケース
+// 責務: 締めタスク（担当者・期日）の登録・削除、期間ごとの進捗ボードの作成と進捗更新
+
+use std::{collections::HashMap, sync::Arc};
+
+use chrono::{Months, NaiveDate, Utc};
+use javelin_domain::{
+    masters::{
+        ClosingStep, ClosingTaskProgress, ClosingTaskStatus, ClosingTimetableItem, DeadlineState,
+    },
+    repositories::ClosingTimetableRepository,
+};
+
+use crate::{
+    dtos::{
+        request::{
+            DeleteClosingTimetableItemRequest, LoadClosingTimetableBoardRequest,
+            SaveClosingTimetableItemRequest, UpdateClosingTaskStatusRequest,
+        },
+        response::{ClosingTimetableBoardItem, ClosingTimetableBoardResponse},
+    },
+    error::{ApplicationError, ApplicationResult},
+};
+
+/// 締めスケジュールのInteractor
+pub struct ClosingTimetableInteractor<R>
+where
+    R: ClosingTimetableRepository,
+{
+    repository: Arc<R>,
+}
+
+impl<R> ClosingTimetableInteractor<R>
+where
+    R: ClosingTimetableRepository,
+{
+    pub fn new(repository: Arc<R>) -> Self {
+        Self { repository }
+    }
+
+    /// 締めタスクを保存（既存のタスクコードの場合は置き換える）
+    pub async fn save_item(
+        &self,
+        request: SaveClosingTimetableItemRequest,
+    ) -> ApplicationResult<()> {
+        let step = if request.step.trim().is_empty() {
+            ClosingStep::Manual
+        } else {
+            ClosingStep::parse(&request.step)?
+        };
+        let item = ClosingTimetableItem::new(
+            request.code,
+            request.task,
+            request.owner,
+            request.due_offset_days,
+            step,
+        )?;
+        Ok(self.repository.save_item(&item).await?)
+    }
+
+    /// 締めタスクを削除（記録済みの進捗は残る）
+    pub async fn delete_item(
+        &self,
+        request: DeleteClosingTimetableItemRequest,
+    ) -> ApplicationResult<()> {
+        Ok(self.repository.delete_item(request.code.trim()).await?)
+    }
+
+    /// 期間の進捗ボードを作成（期日・タスクコード順）
+    pub async fn load_board(
+        &self,
+        request: LoadClosingTimetableBoardRequest,
+    ) -> ApplicationResult<ClosingTimetableBoardResponse> {
+        let period_end = period_end_date(request.fiscal_year, request.period)?;
+        let progress: HashMap<String, ClosingTaskProgress> = self
+            .repository
+            .find_progress(request.fiscal_year, request.period)
+            .await?
+            .into_iter()
+            .map(|progress| (progress.code().to_string(), progress))
+            .collect();
+
+        let mut items: Vec<(NaiveDate, ClosingTimetableBoardItem)> = self
+            .repository
+            .find_items()
+            .await?
+            .into_iter()
+            .map(|item| {
+                let due_date = item.due_date(period_end);
+                let recorded = progress.get(item.code());
+                let status = recorded.map(ClosingTaskProgress::status).unwrap_or_default();
+                let state = DeadlineState::evaluate(due_date, request.today, status);
+                let days_remaining = match state {
+                    DeadlineState::Completed => None,
+                    DeadlineState::Remaining(days) => Some(days),
+                    DeadlineState::Overdue(days) => Some(-days),
+                };
+                let board_item = ClosingTimetableBoardItem {
+                    code: item.code().to_string(),
+                    task: item.task().to_string(),
+                    owner: item.owner().to_string(),
+                    due_offset_days: item.due_offset_days(),
+                    due_date: due_date.format("%Y-%m-%d").to_string(),
+                    step: item.step().as_str().to_string(),
+                    step_label: item.step().label().to_string(),
+                    status: status.as_str().to_string(),
+                    status_label: status.label().to_string(),
+                    days_remaining,
+                    is_overdue: state.is_overdue(),
+                    updated_by: recorded.map(|progress| progress.updated_by().to_string()),
+                };
+                (due_date, board_item)
+            })
+            .collect();
+        items.sort_by(|(a_due, a), (b_due, b)| (a_due, &a.code).cmp(&(b_due, &b.code)));
+
+        let items: Vec<ClosingTimetableBoardItem> =
+            items.into_iter().map(|(_, item)| item).collect();
+        let completed_count = items
+            .iter()
+            .filter(|item| item.status == ClosingTaskStatus::Completed.as_str())
+            .count();
+        let overdue_count = items.iter().filter(|item| item.is_overdue).count();
+
+        Ok(ClosingTimetableBoardResponse {
+            fiscal_year: request.fiscal_year,
+            period: request.period,
+            period_end: period_end.format("%Y-%m-%d").to_string(),
+            items,
+            completed_count,
+            overdue_count,
+        })
+    }
+
+    /// 締めタスクの進捗を更新
+    pub async fn update_status(
+        &self,
+        request: UpdateClosingTaskStatusRequest,
+    ) -> ApplicationResult<()> {
+        let code = request.code.trim();
+        if !self.repository.find_items().await?.iter().any(|item| item.code() == code) {
+            return Err(ApplicationError::ValidationError(format!(
+                "締めタスクが登録されていません: {}",
+                code
+            )));
+        }
+        let progress = ClosingTaskProgress::new(
+            request.fiscal_year,
+            request.period,
+            code,
+            ClosingTaskStatus::parse(&request.status)?,
+            request.updated_by,
+            Utc::now(),
+        )?;
+        Ok(self.repository.save_progress(&progress).await?)
+    }
+
+    /// 締め工程の正常終了を、その工程に対応するタスクの完了として記録
+    ///
+    /// 完了にしたタスクの数を返す。
+    pub async fn complete_step(
+        &self,
+        fiscal_year: i32,
+        period: u8,
+        step: ClosingStep,
+        completed_by: &str,
+    ) -> ApplicationResult<usize> {
+        let mut completed = 0;
+        for item in self.repository.find_items().await? {
+            if item.step() != step {
+                continue;
+            }
+            let progress = ClosingTaskProgress::new(
+                fiscal_year,
+                period,
+                item.code(),
+                ClosingTaskStatus::Completed,
+                completed_by,
+                Utc::now(),
+            )?;
+            self.repository.save_progress(&progress).await?;
+            completed += 1;
+        }
+        Ok(completed)
+    }
+}
+
+/// 会計期間の末日
+fn period_end_date(fiscal_year: i32, period: u8) -> ApplicationResult<NaiveDate> {
+    NaiveDate::from_ymd_opt(fiscal_year, period as u32, 1)
+        .and_then(|first| first.checked_add_months(Months::new(1)))
+        .and_then(|next| next.pred_opt())
+        .ok_or_else(|| {
+            ApplicationError::ValidationError(format!(
+                "会計期間が不正です: {}-{:02}",
+                fiscal_year, period
+            ))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use javelin_domain::error::DomainResult;
+
+    use super::*;
+
+    #[derive(Default)]
+    struct InMemoryClosingTimetableRepository {
+        items: Mutex<Vec<ClosingTimetableItem>>,
+        progress: Mutex<Vec<ClosingTaskProgress>>,
+    }
+
+    impl ClosingTimetableRepository for InMemoryClosingTimetableRepository {
+        async fn find_items(&self) -> DomainResult<Vec<ClosingTimetableItem>> {
+            Ok(self.items.lock().unwrap().clone())
+        }
+
+        async fn save_item(&self, item: &ClosingTimetableItem) -> DomainResult<()> {
+            let mut items = self.items.lock().unwrap();
+            items.retain(|existing| existing.code() != item.code());
+            items.push(item.clone());
+            Ok(())
+        }
+
+        async fn delete_item(&self, code: &str) -> DomainResult<()> {
+            self.items.lock().unwrap().retain(|item| item.code() != code);
+            Ok(())
+        }
+
+        async fn find_progress(
+            &self,
+            fiscal_year: i32,
+            period: u8,
+        ) -> DomainResult<Vec<ClosingTaskProgress>> {
+            Ok(self
+                .progress
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|p| p.fiscal_year() == fiscal_year && p.period() == period)
+                .cloned()
+                .collect())
+        }
+
+        async fn save_progress(&self, progress: &ClosingTaskProgress) -> DomainResult<()> {
+            let mut stored = self.progress.lock().unwrap();
+            stored.retain(|p| {
+                !(p.fiscal_year() == progress.fiscal_year()
+                    && p.period() == progress.period()
+                    && p.code() == progress.code())
+            });
+            stored.push(progress.clone());
+            Ok(())
+        }
+    }
+
+    fn save_request(
+        code: &str,
+        owner: &str,
+        offset: i32,
+        step: &str,
+    ) -> SaveClosingTimetableItemRequest {
+        SaveClosingTimetableItemRequest {
+            code: code.to_string(),
+            task: format!("タスク{}", code),
+            owner: owner.to_string(),
+            due_offset_days: offset,
+            step: step.to_string(),
+        }
+    }
+
+    fn board_request(today: NaiveDate) -> LoadClosingTimetableBoardRequest {
+        LoadClosingTimetableBoardRequest { fiscal_year: 2024, period: 4, today }
+    }
+
+    #[tokio::test]
+    async fn test_board_orders_by_due_date_and_flags_overdue() {
+        let interactor = ClosingTimetableInteractor::new(Arc::new(
+            InMemoryClosingTimetableRepository::default(),
+        ));
+        interactor
+            .save_item(save_request("T03", "鈴木", 5, "財務諸表生成"))
+            .await
+            .unwrap();
+        interactor.save_item(save_request("T01", "佐藤", -1, "")).await.unwrap();
+        interactor
+            .save_item(save_request("T02", "田中", 2, "LedgerConsolidation"))
+            .await
+            .unwrap();
+        assert!(interactor.save_item(save_request("T04", "鈴木", 1, "監査")).await.is_err());
+
+        let today = NaiveDate::from_ymd_opt(2024, 5, 3).unwrap();
+        interactor
+            .update_status(UpdateClosingTaskStatusRequest {
+                fiscal_year: 2024,
+                period: 4,
+                code: "T01".to_string(),
+                status: "Completed".to_string(),
+                updated_by: "sato".to_string(),
+            })
+            .await
+            .unwrap();
+
+        let board = interactor.load_board(board_request(today)).await.unwrap();
+        assert_eq!(board.period_end, "2024-04-30");
+        let codes: Vec<&str> = board.items.iter().map(|item| item.code.as_str()).collect();
+        assert_eq!(codes, vec!["T01", "T02", "T03"]);
+        assert_eq!(board.items[0].due_date, "2024-04-29");
+        assert_eq!(board.items[0].days_remaining, None);
+        assert_eq!(board.items[0].updated_by.as_deref(), Some("sato"));
+        // 翌月2日期限のタスクは1日超過
+        assert!(board.items[1].is_overdue);
+        assert_eq!(board.items[1].days_remaining, Some(-1));
+        assert_eq!(board.items[2].days_remaining, Some(2));
+        assert_eq!((board.completed_count, board.overdue_count), (1, 1));
+
+        // 未登録のタスクの進捗は更新できない
+        let unknown = UpdateClosingTaskStatusRequest {
+            fiscal_year: 2024,
+            period: 4,
+            code: "T09".to_string(),
+            status: "Completed".to_string(),
+            updated_by: "sato".to_string(),
+        };
+        assert!(interactor.update_status(unknown).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_complete_step_marks_linked_tasks_for_period() {
+        let interactor = ClosingTimetableInteractor::new(Arc::new(
+            InMemoryClosingTimetableRepository::default(),
+        ));
+        interactor.save_item(save_request("T01", "佐藤", 1, "締日固定")).await.unwrap();
+        interactor.save_item(save_request("T02", "田中", 1, "")).await.unwrap();
+
+        let completed = interactor
+            .complete_step(2024, 4, ClosingStep::PeriodLock, "system")
+            .await
+            .unwrap();
+        assert_eq!(completed, 1);
+
+        let today = NaiveDate::from_ymd_opt(2024, 5, 1).unwrap();
+        let board = interactor.load_board(board_request(today)).await.unwrap();
+        let statuses: Vec<&str> = board.items.iter().map(|item| item.status.as_str()).collect();
+        assert_eq!(statuses, vec!["Completed", "NotStarted"]);
+
+        // 他の期間の進捗には影響しない
+        let next = LoadClosingTimetableBoardRequest { fiscal_year: 2024, period: 5, today };
+        let board = interactor.load_board(next).await.unwrap();
+        assert_eq!(board.completed_count, 0);
+    }
+}
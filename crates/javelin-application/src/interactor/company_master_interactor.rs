@@ -1,13 +1,19 @@
 // CompanyMasterInteractor - 会社マスタ操作のユースケース
+//
+// 登録・更新・削除は変更イベントとして記録し、マスタには変更後のイメージを反映する。
+// 変更の取消しは逆向きの変更イベントを記録して行う。
 
 use std::sync::Arc;
 
 use javelin_domain::{
-    masters::{CompanyCode, CompanyMaster, CompanyName},
+    masters::{CompanyCode, CompanyMaster, CompanyName, MasterChangeHistory},
     repositories::CompanyMasterRepository,
 };
 
-use crate::error::ApplicationResult;
+use crate::{
+    dtos::{request::RevertMasterChangeRequest, response::MasterChangeDto},
+    error::ApplicationResult,
+};
 
 /// 会社マスタ取得クエリ
 #[derive(Debug, Clone)]
@@ -18,6 +24,7 @@ pub struct GetCompanyMastersQuery;
 pub struct RegisterCompanyMasterRequest {
     pub code: String,
     pub name: String,
+    pub changed_by: String,
 }
 
 /// 会社マスタ更新リクエスト
@@ -26,6 +33,7 @@ pub struct UpdateCompanyMasterRequest {
     pub code: String,
     pub name: String,
     pub is_active: bool,
+    pub changed_by: String,
 }
 
 /// 会社マスタInteractor
@@ -70,12 +78,9 @@ where
             )));
         }
 
-        let company_master = CompanyMaster::new(code, name, true);
+        let company_master = CompanyMaster::new(code.clone(), name, true);
 
-        self.repository
-            .save(&company_master)
-            .await
-            .map_err(|e| crate::error::ApplicationError::UseCaseExecutionFailed(e.to_string()))
+        self.apply_change(&code, None, Some(company_master), &request.changed_by).await
     }
 
     /// 会社マスタを更新
@@ -83,7 +88,7 @@ where
         let code = CompanyCode::new(request.code)
             .map_err(|e| crate::error::ApplicationError::ValidationError(e.to_string()))?;
 
-        let _company_master = self.repository.find_by_code(&code).await?.ok_or_else(|| {
+        let company_master = self.repository.find_by_code(&code).await?.ok_or_else(|| {
             crate::error::ApplicationError::ValidationError(format!(
                 "会社コード {} が見つかりません",
                 code.value()
@@ -93,21 +98,71 @@ where
         let name = CompanyName::new(request.name)
             .map_err(|e| crate::error::ApplicationError::ValidationError(e.to_string()))?;
 
-        let updated = CompanyMaster::new(code, name, request.is_active);
+        let updated = CompanyMaster::new(code.clone(), name, request.is_active);
 
-        self.repository
-            .save(&updated)
+        self.apply_change(&code, Some(company_master), Some(updated), &request.changed_by)
             .await
-            .map_err(|e| crate::error::ApplicationError::UseCaseExecutionFailed(e.to_string()))
     }
 
     /// 会社マスタを削除
-    pub async fn delete(&self, code: String) -> ApplicationResult<()> {
+    pub async fn delete(&self, code: String, changed_by: &str) -> ApplicationResult<()> {
+        let code = CompanyCode::new(code)
+            .map_err(|e| crate::error::ApplicationError::ValidationError(e.to_string()))?;
+
+        let Some(company_master) = self.repository.find_by_code(&code).await? else {
+            return Ok(());
+        };
+        self.apply_change(&code, Some(company_master), None, changed_by).await
+    }
+
+    /// 会社マスタの変更履歴を取得（新しい順）
+    pub async fn history(&self, code: String) -> ApplicationResult<Vec<MasterChangeDto>> {
         let code = CompanyCode::new(code)
             .map_err(|e| crate::error::ApplicationError::ValidationError(e.to_string()))?;
 
+        let current = self.repository.find_by_code(&code).await?;
+        let history = self.load_history(&code).await?;
+        Ok(MasterChangeDto::from_history(&history, current.as_ref()))
+    }
+
+    /// 会社マスタの変更を取り消す（逆向きの変更を記録する）
+    pub async fn revert(&self, request: RevertMasterChangeRequest) -> ApplicationResult<()> {
+        let code = CompanyCode::new(request.code)
+            .map_err(|e| crate::error::ApplicationError::ValidationError(e.to_string()))?;
+
+        let current = self.repository.find_by_code(&code).await?;
+        let history = self.load_history(&code).await?;
+        let change = history.revert(request.sequence, current.as_ref(), &request.changed_by)?;
+
+        self.repository
+            .apply_change(&change)
+            .await
+            .map_err(|e| crate::error::ApplicationError::UseCaseExecutionFailed(e.to_string()))
+    }
+
+    async fn load_history(
+        &self,
+        code: &CompanyCode,
+    ) -> ApplicationResult<MasterChangeHistory<CompanyMaster>> {
+        let changes = self.repository.find_changes(code).await?;
+        Ok(MasterChangeHistory::new(code.value(), changes))
+    }
+
+    /// 変更イベントを記録してマスタへ反映（変更がない場合は何もしない）
+    async fn apply_change(
+        &self,
+        code: &CompanyCode,
+        before: Option<CompanyMaster>,
+        after: Option<CompanyMaster>,
+        changed_by: &str,
+    ) -> ApplicationResult<()> {
+        let history = self.load_history(code).await?;
+        let Some(change) = history.record(before, after, changed_by)? else {
+            return Ok(());
+        };
+
         self.repository
-            .delete(&code)
+            .apply_change(&change)
             .await
             .map_err(|e| crate::error::ApplicationError::UseCaseExecutionFailed(e.to_string()))
     }
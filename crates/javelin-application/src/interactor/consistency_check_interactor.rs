@@ -0,0 +1,315 @@
+// ConsistencyCheckInteractor - Projection間整合性チェックのユースケース
+// 責務: 仕訳一覧Projectionと元帳Projectionの不変条件の検証と修復方法の提案
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    sync::Arc,
+};
+
+use crate::{
+    dtos::response::{ConsistencyCheckResponse, ConsistencyViolation, ConsistencyViolationKind},
+    error::ApplicationResult,
+    query_service::{ProjectionConsistencyQueryService, ProjectionConsistencySnapshot},
+};
+
+/// 金額比較の許容誤差
+const AMOUNT_TOLERANCE: f64 = 0.005;
+
+/// 元帳へ反映済みであるべき仕訳ステータス
+const LEDGER_STATUSES: [&str; 4] = ["Posted", "Corrected", "Closed", "Reversed"];
+
+/// 元帳の増減として集計する仕訳ステータス
+///
+/// 取消済・訂正済の仕訳は元帳上で元仕訳と逆仕訳が相殺されるため、
+/// 仕訳明細側の増減はゼロとして扱う。
+const MOVEMENT_STATUSES: [&str; 2] = ["Posted", "Closed"];
+
+const SUGGEST_REBUILD: &str = "Projectionを再構築してください";
+const SUGGEST_INSPECT: &str =
+    "イベントインスペクタで隔離イベントを確認し、修復後にProjectionを再構築してください";
+
+/// Projection間整合性チェックのInteractor
+///
+/// 次の不変条件を検証する。
+/// - 勘定科目別の仕訳明細合計（借方−貸方）と元帳の増減が一致する
+/// - 記帳済の仕訳はすべて元帳に存在する
+/// - 元帳の明細にはすべて対応する記帳（または取消）イベントがある
+pub struct ConsistencyCheckInteractor<Q>
+where
+    Q: ProjectionConsistencyQueryService,
+{
+    query_service: Arc<Q>,
+}
+
+impl<Q> ConsistencyCheckInteractor<Q>
+where
+    Q: ProjectionConsistencyQueryService,
+{
+    pub fn new(query_service: Arc<Q>) -> Self {
+        Self { query_service }
+    }
+
+    pub async fn execute(&self) -> ApplicationResult<ConsistencyCheckResponse> {
+        let snapshot = self.query_service.load_snapshot().await?;
+        Ok(check(&snapshot))
+    }
+}
+
+fn check(snapshot: &ProjectionConsistencySnapshot) -> ConsistencyCheckResponse {
+    let mut violations = Vec::new();
+
+    let ledger_numbers: BTreeSet<&str> =
+        snapshot.ledger_movements.iter().map(|m| m.entry_number.as_str()).collect();
+
+    // 記帳済の仕訳が元帳に存在すること
+    for entry in &snapshot.journal_entries {
+        if !LEDGER_STATUSES.contains(&entry.status.as_str()) {
+            continue;
+        }
+        let Some(entry_number) = entry.entry_number.as_deref() else {
+            continue;
+        };
+        if !ledger_numbers.contains(entry_number) {
+            violations.push(ConsistencyViolation {
+                kind: ConsistencyViolationKind::PostedEntryMissingFromLedger,
+                subject: entry_number.to_string(),
+                detail: format!("仕訳 {}（{}）が元帳に存在しません", entry_number, entry.status),
+                suggestion: SUGGEST_REBUILD.to_string(),
+            });
+        }
+    }
+
+    // 元帳の明細に記帳イベントがあること
+    let known_numbers: BTreeSet<&str> = snapshot
+        .posted_entry_numbers
+        .iter()
+        .chain(snapshot.reversal_entry_ids.iter())
+        .map(String::as_str)
+        .collect();
+    for entry_number in &ledger_numbers {
+        if !known_numbers.contains(entry_number) {
+            violations.push(ConsistencyViolation {
+                kind: ConsistencyViolationKind::LedgerEntryWithoutPostedEvent,
+                subject: entry_number.to_string(),
+                detail: format!("元帳の伝票 {} に対応する記帳イベントがありません", entry_number),
+                suggestion: SUGGEST_INSPECT.to_string(),
+            });
+        }
+    }
+
+    // 勘定科目別の増減が一致すること
+    let mut journal_net: BTreeMap<&str, f64> = BTreeMap::new();
+    for entry in &snapshot.journal_entries {
+        if !MOVEMENT_STATUSES.contains(&entry.status.as_str()) {
+            continue;
+        }
+        for line in &entry.lines {
+            let signed = if line.side == "Debit" {
+                line.amount
+            } else {
+                -line.amount
+            };
+            *journal_net.entry(line.account_code.as_str()).or_default() += signed;
+        }
+    }
+
+    // 取消仕訳の逆仕訳も含め、元帳の全明細を集計する
+    let mut ledger_net: BTreeMap<&str, f64> = BTreeMap::new();
+    for movement in &snapshot.ledger_movements {
+        *ledger_net.entry(movement.account_code.as_str()).or_default() +=
+            movement.debit_amount - movement.credit_amount;
+    }
+
+    let accounts: BTreeSet<&str> = journal_net.keys().chain(ledger_net.keys()).copied().collect();
+    for account in &accounts {
+        let journal = journal_net.get(account).copied().unwrap_or_default();
+        let ledger = ledger_net.get(account).copied().unwrap_or_default();
+        if (journal - ledger).abs() > AMOUNT_TOLERANCE {
+            violations.push(ConsistencyViolation {
+                kind: ConsistencyViolationKind::AccountMovementMismatch,
+                subject: account.to_string(),
+                detail: format!(
+                    "勘定科目 {} の仕訳明細合計 {:.2} と元帳増減 {:.2} が一致しません",
+                    account, journal, ledger
+                ),
+                suggestion: SUGGEST_REBUILD.to_string(),
+            });
+        }
+    }
+
+    ConsistencyCheckResponse {
+        checked_entries: snapshot.journal_entries.len(),
+        checked_accounts: accounts.len(),
+        violations,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::query_service::{
+        JournalProjectionEntry, JournalProjectionLine, LedgerProjectionMovement,
+    };
+
+    struct MockQueryService {
+        snapshot: ProjectionConsistencySnapshot,
+    }
+
+    impl ProjectionConsistencyQueryService for MockQueryService {
+        async fn load_snapshot(&self) -> ApplicationResult<ProjectionConsistencySnapshot> {
+            Ok(self.snapshot.clone())
+        }
+    }
+
+    fn entry(number: &str, status: &str, amount: f64) -> JournalProjectionEntry {
+        JournalProjectionEntry {
+            entry_id: format!("id-{}", number),
+            entry_number: Some(number.to_string()),
+            status: status.to_string(),
+            lines: vec![
+                JournalProjectionLine {
+                    account_code: "1100".to_string(),
+                    side: "Debit".to_string(),
+                    amount,
+                },
+                JournalProjectionLine {
+                    account_code: "4100".to_string(),
+                    side: "Credit".to_string(),
+                    amount,
+                },
+            ],
+        }
+    }
+
+    fn movements(number: &str, amount: f64) -> Vec<LedgerProjectionMovement> {
+        vec![
+            LedgerProjectionMovement {
+                account_code: "1100".to_string(),
+                entry_number: number.to_string(),
+                debit_amount: amount,
+                credit_amount: 0.0,
+            },
+            LedgerProjectionMovement {
+                account_code: "4100".to_string(),
+                entry_number: number.to_string(),
+                debit_amount: 0.0,
+                credit_amount: amount,
+            },
+        ]
+    }
+
+    fn reversal_movements(entry_id: &str, amount: f64) -> Vec<LedgerProjectionMovement> {
+        movements(entry_id, amount)
+            .into_iter()
+            .map(|m| LedgerProjectionMovement {
+                debit_amount: m.credit_amount,
+                credit_amount: m.debit_amount,
+                ..m
+            })
+            .collect()
+    }
+
+    async fn run(snapshot: ProjectionConsistencySnapshot) -> ConsistencyCheckResponse {
+        let interactor = ConsistencyCheckInteractor::new(Arc::new(MockQueryService { snapshot }));
+        interactor.execute().await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_consistent_projections() {
+        let response = run(ProjectionConsistencySnapshot {
+            journal_entries: vec![
+                entry("JE-001", "Posted", 1000.0),
+                entry("JE-002", "Draft", 50.0),
+            ],
+            ledger_movements: movements("JE-001", 1000.0),
+            posted_entry_numbers: vec!["JE-001".to_string()],
+            reversal_entry_ids: vec![],
+        })
+        .await;
+
+        assert!(response.is_consistent());
+        assert_eq!(response.checked_entries, 2);
+        assert_eq!(response.checked_accounts, 2);
+    }
+
+    #[tokio::test]
+    async fn test_reversed_entry_offsets_in_ledger() {
+        let mut ledger_movements = movements("JE-001", 1000.0);
+        ledger_movements.extend(reversal_movements("REV-001", 1000.0));
+
+        let response = run(ProjectionConsistencySnapshot {
+            journal_entries: vec![entry("JE-001", "Reversed", 1000.0)],
+            ledger_movements,
+            posted_entry_numbers: vec!["JE-001".to_string()],
+            reversal_entry_ids: vec!["REV-001".to_string()],
+        })
+        .await;
+
+        assert!(response.is_consistent());
+
+        // 逆仕訳が元帳に反映されていない場合は残高不一致となる
+        let response = run(ProjectionConsistencySnapshot {
+            journal_entries: vec![entry("JE-001", "Reversed", 1000.0)],
+            ledger_movements: movements("JE-001", 1000.0),
+            posted_entry_numbers: vec!["JE-001".to_string()],
+            reversal_entry_ids: vec!["REV-001".to_string()],
+        })
+        .await;
+
+        assert_eq!(response.violations.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_posted_entry_missing_from_ledger() {
+        let response = run(ProjectionConsistencySnapshot {
+            journal_entries: vec![entry("JE-001", "Posted", 1000.0)],
+            ledger_movements: vec![],
+            posted_entry_numbers: vec!["JE-001".to_string()],
+            reversal_entry_ids: vec![],
+        })
+        .await;
+
+        let kinds: Vec<_> = response.violations.iter().map(|v| v.kind).collect();
+        assert!(kinds.contains(&ConsistencyViolationKind::PostedEntryMissingFromLedger));
+        assert!(kinds.contains(&ConsistencyViolationKind::AccountMovementMismatch));
+    }
+
+    #[tokio::test]
+    async fn test_ledger_entry_without_posted_event() {
+        let response = run(ProjectionConsistencySnapshot {
+            journal_entries: vec![],
+            ledger_movements: movements("JE-009", 500.0),
+            posted_entry_numbers: vec![],
+            reversal_entry_ids: vec![],
+        })
+        .await;
+
+        let orphan: Vec<_> = response
+            .violations
+            .iter()
+            .filter(|v| v.kind == ConsistencyViolationKind::LedgerEntryWithoutPostedEvent)
+            .collect();
+        assert_eq!(orphan.len(), 1);
+        assert_eq!(orphan[0].subject, "JE-009");
+        assert!(!orphan[0].suggestion.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_account_movement_mismatch() {
+        let response = run(ProjectionConsistencySnapshot {
+            journal_entries: vec![entry("JE-001", "Posted", 1000.0)],
+            ledger_movements: movements("JE-001", 900.0),
+            posted_entry_numbers: vec!["JE-001".to_string()],
+            reversal_entry_ids: vec![],
+        })
+        .await;
+
+        assert_eq!(response.violations.len(), 2);
+        assert!(
+            response
+                .violations
+                .iter()
+                .all(|v| v.kind == ConsistencyViolationKind::AccountMovementMismatch)
+        );
+    }
+}
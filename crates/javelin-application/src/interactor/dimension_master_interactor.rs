@@ -0,0 +1,168 @@
+// DimensionMasterInteractor - 分析軸マスタ操作のユースケース
+// 責務: 仕訳明細に付ける分析軸（プロジェクト・セグメント・任意の分析タグ）の登録・更新・削除
+
+use std::sync::Arc;
+
+use javelin_domain::{masters::DimensionMaster, repositories::DimensionMasterRepository};
+
+use crate::{
+    dtos::{
+        request::{DeleteDimensionMasterRequest, SaveDimensionMasterRequest},
+        response::{DimensionMasterItem, LoadDimensionMastersResponse},
+    },
+    error::ApplicationResult,
+};
+
+/// 分析軸マスタ操作のInteractor
+pub struct DimensionMasterInteractor<R>
+where
+    R: DimensionMasterRepository,
+{
+    repository: Arc<R>,
+}
+
+impl<R> DimensionMasterInteractor<R>
+where
+    R: DimensionMasterRepository,
+{
+    pub fn new(repository: Arc<R>) -> Self {
+        Self { repository }
+    }
+
+    /// 仕訳明細の検証に用いる分析軸マスタ
+    pub async fn masters(&self) -> ApplicationResult<Vec<DimensionMaster>> {
+        Ok(self.repository.find_all().await?)
+    }
+
+    /// 分析軸の一覧を取得（種類・コード順）
+    pub async fn load(&self) -> ApplicationResult<LoadDimensionMastersResponse> {
+        let mut items: Vec<DimensionMasterItem> = self
+            .repository
+            .find_all()
+            .await?
+            .into_iter()
+            .map(|master| DimensionMasterItem {
+                kind: master.kind().to_string(),
+                code: master.code().to_string(),
+                name: master.name().to_string(),
+                is_active: master.is_active(),
+            })
+            .collect();
+
+        items.sort_by(|a, b| (&a.kind, &a.code).cmp(&(&b.kind, &b.code)));
+
+        Ok(LoadDimensionMastersResponse { items })
+    }
+
+    /// 分析軸を保存（既存の種類・コードの場合は名称と有効状態を置き換える）
+    pub async fn save(&self, request: SaveDimensionMasterRequest) -> ApplicationResult<()> {
+        let master =
+            DimensionMaster::new(request.kind, request.code, request.name, request.is_active)?;
+        Ok(self.repository.save(&master).await?)
+    }
+
+    /// 分析軸を削除
+    ///
+    /// 記帳済みの明細に付いた分析軸はそのまま残る。使用を止める場合は無効化する。
+    pub async fn delete(&self, request: DeleteDimensionMasterRequest) -> ApplicationResult<()> {
+        Ok(self.repository.delete(request.kind.trim(), request.code.trim()).await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use javelin_domain::error::DomainResult;
+
+    use super::*;
+
+    #[derive(Default)]
+    struct InMemoryDimensionMasterRepository {
+        masters: Mutex<Vec<DimensionMaster>>,
+    }
+
+    impl DimensionMasterRepository for InMemoryDimensionMasterRepository {
+        async fn find(&self, kind: &str, code: &str) -> DomainResult<Option<DimensionMaster>> {
+            let masters = self.masters.lock().unwrap();
+            Ok(masters.iter().find(|m| m.kind() == kind && m.code() == code).cloned())
+        }
+
+        async fn find_all(&self) -> DomainResult<Vec<DimensionMaster>> {
+            Ok(self.masters.lock().unwrap().clone())
+        }
+
+        async fn save(&self, master: &DimensionMaster) -> DomainResult<()> {
+            let mut masters = self.masters.lock().unwrap();
+            masters.retain(|m| !(m.kind() == master.kind() && m.code() == master.code()));
+            masters.push(master.clone());
+            Ok(())
+        }
+
+        async fn delete(&self, kind: &str, code: &str) -> DomainResult<()> {
+            self.masters.lock().unwrap().retain(|m| !(m.kind() == kind && m.code() == code));
+            Ok(())
+        }
+    }
+
+    fn save_request(
+        kind: &str,
+        code: &str,
+        name: &str,
+        is_active: bool,
+    ) -> SaveDimensionMasterRequest {
+        SaveDimensionMasterRequest {
+            kind: kind.to_string(),
+            code: code.to_string(),
+            name: name.to_string(),
+            is_active,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_save_load_and_delete_dimensions() {
+        let interactor =
+            DimensionMasterInteractor::new(Arc::new(InMemoryDimensionMasterRepository::default()));
+
+        interactor.save(save_request("segment", "S1", "国内", true)).await.unwrap();
+        interactor
+            .save(save_request("project", "P002", "旧システム", true))
+            .await
+            .unwrap();
+        interactor
+            .save(save_request("project", "P001", "新基幹システム", true))
+            .await
+            .unwrap();
+        // 同じ種類・コードは置き換える
+        interactor
+            .save(save_request("project", "P002", "旧システム", false))
+            .await
+            .unwrap();
+        assert!(
+            interactor
+                .save(save_request("department", "D01", "営業部", true))
+                .await
+                .is_err()
+        );
+
+        let loaded = interactor.load().await.unwrap();
+        let keys: Vec<(&str, &str, bool)> = loaded
+            .items
+            .iter()
+            .map(|item| (item.kind.as_str(), item.code.as_str(), item.is_active))
+            .collect();
+        assert_eq!(
+            keys,
+            vec![("project", "P001", true), ("project", "P002", false), ("segment", "S1", true)]
+        );
+
+        interactor
+            .delete(DeleteDimensionMasterRequest {
+                kind: "segment".to_string(),
+                code: "S1".to_string(),
+            })
+            .await
+            .unwrap();
+        assert_eq!(interactor.masters().await.unwrap().len(), 2);
+    }
+}
@@ -0,0 +1,425 @@
+// EntryLinkInteractor - 仕訳間の紐付けのユースケース
+// 責務: 計上↔決済・前払↔償却の紐付けと解除、期末時点の未決済の計上・前払の集計
+//
+// 紐付けのグラフから、期末までに決済・償却されていない残高を求め、
+// 決算整理（見越し・繰延べ）の検討材料とする。
+
+use std::{collections::HashMap, sync::Arc};
+
+use chrono::Utc;
+use javelin_domain::{
+    financial_close::journal_entry::{
+        entities::{EntryLink, EntryLinkKind, EntryLinkRole},
+        events::JournalEntryEvent,
+    },
+    repositories::EventRepository,
+};
+
+use crate::{
+    dtos::{
+        request::{LinkEntriesRequest, UnlinkEntriesRequest, UnsettledLinksRequest},
+        response::{EntryLinkDto, EntryLinksResponse, UnsettledLinkItem, UnsettledLinksResponse},
+    },
+    error::{ApplicationError, ApplicationResult},
+};
+
+/// 金額比較の許容誤差
+const AMOUNT_TOLERANCE: f64 = 0.005;
+
+/// 仕訳間の紐付けのInteractor
+///
+/// 紐付けは両方の仕訳のイベントストリームに追記する。記帳済の仕訳同士のみ
+/// 紐付けられ、同じ組み合わせを重ねて紐付けることはできない。
+pub struct EntryLinkInteractor<R: EventRepository> {
+    event_repository: Arc<R>,
+}
+
+impl<R: EventRepository> EntryLinkInteractor<R> {
+    pub fn new(event_repository: Arc<R>) -> Self {
+        Self { event_repository }
+    }
+
+    /// 計上・前払の仕訳と決済・償却の仕訳を紐付ける
+    pub async fn link(&self, request: LinkEntriesRequest) -> ApplicationResult<EntryLinksResponse> {
+        let kind = EntryLinkKind::from_code(&request.kind)?;
+        let source_events = self.load_events(&request.source_entry_id).await?;
+        let target_events = self.load_events(&request.target_entry_id).await?;
+
+        for (entry_id, events) in [
+            (&request.source_entry_id, &source_events),
+            (&request.target_entry_id, &target_events),
+        ] {
+            if !EntrySnapshot::from_events(events).posted {
+                return Err(ApplicationError::ValidationFailed(vec![format!(
+                    "記帳済の仕訳のみ紐付けできます: {}",
+                    entry_id
+                )]));
+            }
+        }
+
+        let links = EntryLink::replay(&source_events);
+        if links.iter().any(|link| link.counterpart_id() == request.target_entry_id) {
+            return Err(ApplicationError::ValidationFailed(vec![format!(
+                "既に紐付けられています: {} ↔ {}",
+                request.source_entry_id, request.target_entry_id
+            )]));
+        }
+
+        let linked_amount: f64 = links
+            .iter()
+            .filter(|link| link.role() == EntryLinkRole::Source)
+            .map(EntryLink::amount)
+            .sum();
+        let outstanding = EntrySnapshot::from_events(&source_events).debit_total - linked_amount;
+        let amount = request.amount.unwrap_or(outstanding);
+        if amount > outstanding + AMOUNT_TOLERANCE {
+            return Err(ApplicationError::ValidationFailed(vec![format!(
+                "紐付け金額が未決済残高（{:.0}）を超えています",
+                outstanding.max(0.0)
+            )]));
+        }
+
+        let link_id =
+            format!("L-{}", uuid::Uuid::new_v4().simple().to_string()[..8].to_uppercase());
+        let (source_event, target_event) = EntryLink::link(
+            link_id,
+            &request.source_entry_id,
+            &request.target_entry_id,
+            kind,
+            amount,
+            request.user_id,
+            Utc::now(),
+        )?;
+        self.event_repository
+            .append_events(&request.source_entry_id, vec![source_event])
+            .await?;
+        self.event_repository
+            .append_events(&request.target_entry_id, vec![target_event])
+            .await?;
+
+        self.links(&request.source_entry_id).await
+    }
+
+    /// 紐付けを解除する
+    pub async fn unlink(
+        &self,
+        request: UnlinkEntriesRequest,
+    ) -> ApplicationResult<EntryLinksResponse> {
+        let events = self.load_events(&request.entry_id).await?;
+        let link = EntryLink::replay(&events)
+            .into_iter()
+            .find(|link| link.link_id() == request.link_id)
+            .ok_or_else(|| {
+                ApplicationError::ValidationFailed(vec![format!(
+                    "紐付けが見つかりません: {}",
+                    request.link_id
+                )])
+            })?;
+
+        let (event, counterpart_event) = link.unlink(request.user_id, Utc::now());
+        self.event_repository.append_events(link.entry_id(), vec![event]).await?;
+        self.event_repository
+            .append_events(link.counterpart_id(), vec![counterpart_event])
+            .await?;
+
+        self.links(&request.entry_id).await
+    }
+
+    /// 仕訳の紐付け一覧
+    pub async fn links(&self, entry_id: &str) -> ApplicationResult<EntryLinksResponse> {
+        let events = self.load_events(entry_id).await?;
+        let links = EntryLink::replay(&events)
+            .into_iter()
+            .map(|link| EntryLinkDto {
+                link_id: link.link_id().to_string(),
+                counterpart_id: link.counterpart_id().to_string(),
+                kind: link.kind().code().to_string(),
+                kind_name: link.kind().display_name().to_string(),
+                role_name: link.role_name().to_string(),
+                counterpart_role_name: link.counterpart_role_name().to_string(),
+                amount: link.amount(),
+                linked_by: link.linked_by().to_string(),
+                linked_at: link.linked_at().to_rfc3339(),
+            })
+            .collect();
+
+        Ok(EntryLinksResponse { entry_id: entry_id.to_string(), links })
+    }
+
+    /// 期末時点で未決済の計上・前払
+    ///
+    /// 期末までに取引日を迎えた計上・前払の仕訳ごとに、期末までに取引日を迎えた
+    /// 決済・償却の仕訳への紐付け金額を差し引いた残高を求める。
+    pub async fn unsettled(
+        &self,
+        request: UnsettledLinksRequest,
+    ) -> ApplicationResult<UnsettledLinksResponse> {
+        let period_end = format!("{:04}-{:02}-31", request.fiscal_year, request.period);
+
+        let mut streams: HashMap<String, Vec<JournalEntryEvent>> = HashMap::new();
+        for value in self.event_repository.get_all_events(0).await? {
+            if let Ok(event) = serde_json::from_value::<JournalEntryEvent>(value) {
+                streams.entry(event.aggregate_id().to_string()).or_default().push(event);
+            }
+        }
+        let snapshots: HashMap<&str, EntrySnapshot> = streams
+            .iter()
+            .map(|(entry_id, events)| (entry_id.as_str(), EntrySnapshot::from_events(events)))
+            .collect();
+        let booked_by_period_end = |entry_id: &str| {
+            snapshots.get(entry_id).is_some_and(|snapshot| {
+                snapshot.posted && !snapshot.deleted && snapshot.transaction_date <= period_end
+            })
+        };
+
+        let mut items: Vec<UnsettledLinkItem> = Vec::new();
+        for (entry_id, events) in &streams {
+            if !booked_by_period_end(entry_id) {
+                continue;
+            }
+            let links: Vec<EntryLink> = EntryLink::replay(events)
+                .into_iter()
+                .filter(|link| link.role() == EntryLinkRole::Source)
+                .collect();
+            let Some(first) = links.first() else {
+                continue;
+            };
+
+            let snapshot = &snapshots[entry_id.as_str()];
+            let settled_amount: f64 = links
+                .iter()
+                .filter(|link| booked_by_period_end(link.counterpart_id()))
+                .map(EntryLink::amount)
+                .sum();
+            let outstanding_amount = snapshot.debit_total - settled_amount;
+            if outstanding_amount > AMOUNT_TOLERANCE {
+                items.push(UnsettledLinkItem {
+                    entry_id: entry_id.clone(),
+                    transaction_date: snapshot.transaction_date.clone(),
+                    kind_name: first.kind().display_name().to_string(),
+                    amount: snapshot.debit_total,
+                    settled_amount,
+                    outstanding_amount,
+                });
+            }
+        }
+        items.sort_by(|a, b| {
+            a.transaction_date
+                .cmp(&b.transaction_date)
+                .then_with(|| a.entry_id.cmp(&b.entry_id))
+        });
+
+        Ok(UnsettledLinksResponse { period_end, items })
+    }
+
+    /// 仕訳のイベントを読み込む（存在しない・削除済みの仕訳はエラー）
+    async fn load_events(&self, entry_id: &str) -> ApplicationResult<Vec<JournalEntryEvent>> {
+        let events: Vec<JournalEntryEvent> = self
+            .event_repository
+            .get_events(entry_id)
+            .await?
+            .into_iter()
+            .filter_map(|value| serde_json::from_value(value).ok())
+            .collect();
+
+        if events.is_empty() {
+            return Err(ApplicationError::ValidationFailed(vec![format!(
+                "仕訳が見つかりません: {}",
+                entry_id
+            )]));
+        }
+        if EntrySnapshot::from_events(&events).deleted {
+            return Err(ApplicationError::ValidationFailed(vec![format!(
+                "削除された仕訳は紐付けできません: {}",
+                entry_id
+            )]));
+        }
+        Ok(events)
+    }
+}
+
+/// 紐付けの判定に必要な仕訳の最新の状態
+#[derive(Debug, Default)]
+struct EntrySnapshot {
+    transaction_date: String,
+    debit_total: f64,
+    posted: bool,
+    deleted: bool,
+}
+
+impl EntrySnapshot {
+    fn from_events(events: &[JournalEntryEvent]) -> Self {
+        let mut snapshot = Self::default();
+        for event in events {
+            match event {
+                JournalEntryEvent::DraftCreated { transaction_date, lines, .. } => {
+                    snapshot.transaction_date = transaction_date.clone();
+                    snapshot.debit_total = debit_total(lines);
+                }
+                JournalEntryEvent::DraftUpdated { transaction_date, lines, .. } => {
+                    if let Some(transaction_date) = transaction_date {
+                        snapshot.transaction_date = transaction_date.clone();
+                    }
+                    if let Some(lines) = lines {
+                        snapshot.debit_total = debit_total(lines);
+                    }
+                }
+                JournalEntryEvent::Posted { .. } => snapshot.posted = true,
+                JournalEntryEvent::Deleted { .. } => snapshot.deleted = true,
+                _ => {}
+            }
+        }
+        snapshot
+    }
+}
+
+/// 借方合計
+fn debit_total(
+    lines: &[javelin_domain::financial_close::journal_entry::events::JournalEntryLineDto],
+) -> f64 {
+    lines.iter().filter(|line| line.side == "Debit").map(|line| line.amount).sum()
+}
+
+#[cfg(test)]
+mod tests {
+
+    use javelin_domain::financial_close::journal_entry::events::JournalEntryLineDto;
+
+    use super::*;
+    use crate::testkit::InMemoryEventRepository;
+
+    /// ストリームごとのイベントと、追記順の全イベントを保持するモック
+    fn line(line_number: u32, side: &str, amount: f64) -> JournalEntryLineDto {
+        JournalEntryLineDto {
+            line_number,
+            side: side.to_string(),
+            account_code: "1100".to_string(),
+            account_name: None,
+            sub_account_code: None,
+            department_code: None,
+            dimensions: Default::default(),
+            amount,
+            currency: "JPY".to_string(),
+            tax_type: "NonTaxable".to_string(),
+            tax_amount: 0.0,
+            description: None,
+        }
+    }
+
+    async fn post_entry(
+        repository: &InMemoryEventRepository,
+        entry_id: &str,
+        transaction_date: &str,
+        amount: f64,
+    ) {
+        let events = vec![
+            JournalEntryEvent::DraftCreated {
+                entry_id: entry_id.to_string(),
+                transaction_date: transaction_date.to_string(),
+                voucher_number: format!("V-{}", entry_id),
+                lines: vec![line(1, "Debit", amount), line(2, "Credit", amount)],
+                description: None,
+                created_by: "clerk".to_string(),
+                created_at: Utc::now(),
+            },
+            JournalEntryEvent::Posted {
+                entry_id: entry_id.to_string(),
+                entry_number: format!("JE-{}", entry_id),
+                posted_by: "clerk".to_string(),
+                posted_at: Utc::now(),
+            },
+        ];
+        repository.append_events(entry_id, events).await.unwrap();
+    }
+
+    fn link_request(source: &str, target: &str, amount: Option<f64>) -> LinkEntriesRequest {
+        LinkEntriesRequest {
+            source_entry_id: source.to_string(),
+            target_entry_id: target.to_string(),
+            kind: EntryLinkKind::AccrualSettlement.code().to_string(),
+            amount,
+            user_id: "clerk".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_link_shows_on_both_entries_and_unlinks() {
+        let repository = Arc::new(InMemoryEventRepository::default());
+        post_entry(&repository, "ACC", "2024-03-31", 1000.0).await;
+        post_entry(&repository, "SET", "2024-04-10", 1000.0).await;
+        let interactor = EntryLinkInteractor::new(Arc::clone(&repository));
+
+        let response = interactor.link(link_request("ACC", "SET", None)).await.unwrap();
+        assert_eq!(response.links.len(), 1);
+        assert_eq!(response.links[0].role_name, "計上");
+        assert_eq!(response.links[0].amount, 1000.0);
+
+        let counterpart = interactor.links("SET").await.unwrap();
+        assert_eq!(counterpart.links[0].counterpart_id, "ACC");
+        assert_eq!(counterpart.links[0].role_name, "決済");
+
+        // 同じ組み合わせの重複・残高超過・未記帳の仕訳は紐付けできない
+        assert!(interactor.link(link_request("ACC", "SET", None)).await.is_err());
+        post_entry(&repository, "SET2", "2024-04-20", 500.0).await;
+        assert!(interactor.link(link_request("ACC", "SET2", Some(1.0))).await.is_err());
+        let draft = JournalEntryEvent::DraftCreated {
+            entry_id: "DRAFT".to_string(),
+            transaction_date: "2024-04-01".to_string(),
+            voucher_number: "V-DRAFT".to_string(),
+            lines: vec![line(1, "Debit", 100.0)],
+            description: None,
+            created_by: "clerk".to_string(),
+            created_at: Utc::now(),
+        };
+        repository.append_events("DRAFT", vec![draft]).await.unwrap();
+        assert!(interactor.link(link_request("ACC", "DRAFT", None)).await.is_err());
+
+        let response = interactor
+            .unlink(UnlinkEntriesRequest {
+                entry_id: "SET".to_string(),
+                link_id: counterpart.links[0].link_id.clone(),
+                user_id: "clerk".to_string(),
+            })
+            .await
+            .unwrap();
+        assert!(response.links.is_empty());
+        assert!(interactor.links("ACC").await.unwrap().links.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_unsettled_at_period_end() {
+        let repository = Arc::new(InMemoryEventRepository::default());
+        post_entry(&repository, "ACC", "2024-03-31", 1000.0).await;
+        post_entry(&repository, "SET1", "2024-03-31", 400.0).await;
+        post_entry(&repository, "SET2", "2024-04-10", 600.0).await;
+        post_entry(&repository, "PRE", "2024-03-01", 1200.0).await;
+        post_entry(&repository, "AMO", "2024-03-31", 1200.0).await;
+        let interactor = EntryLinkInteractor::new(Arc::clone(&repository));
+
+        interactor.link(link_request("ACC", "SET1", Some(400.0))).await.unwrap();
+        interactor.link(link_request("ACC", "SET2", None)).await.unwrap();
+        interactor
+            .link(LinkEntriesRequest {
+                kind: EntryLinkKind::PrepaymentAmortization.code().to_string(),
+                ..link_request("PRE", "AMO", None)
+            })
+            .await
+            .unwrap();
+
+        // 3月末時点では4月の決済分が未決済として残る
+        let march = interactor
+            .unsettled(UnsettledLinksRequest { fiscal_year: 2024, period: 3 })
+            .await
+            .unwrap();
+        assert_eq!(march.items.len(), 1);
+        assert_eq!(march.items[0].entry_id, "ACC");
+        assert_eq!(march.items[0].settled_amount, 400.0);
+        assert_eq!(march.total_outstanding(), 600.0);
+
+        let april = interactor
+            .unsettled(UnsettledLinksRequest { fiscal_year: 2024, period: 4 })
+            .await
+            .unwrap();
+        assert!(april.items.is_empty());
+    }
+}
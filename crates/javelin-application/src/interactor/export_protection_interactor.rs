@@ -0,0 +1,229 @@
+// ExportProtectionInteractor - 出力ファイル保護のユースケース
+// 責務: 設定に従った出力ファイルの暗号化・分離署名と、分離署名の検証
+
+use std::sync::Arc;
+
+use javelin_domain::{
+    masters::ExportProtectionSettings, repositories::ApplicationSettingsRepository,
+};
+
+use crate::{
+    dtos::{
+        request::{ProtectExportRequest, VerifyExportSignatureRequest},
+        response::{ExportSignatureVerificationDto, ProtectExportResponse, ProtectedExportFileDto},
+    },
+    error::{ApplicationError, ApplicationResult},
+    output_port::ExportProtectionOutputPort,
+};
+
+/// 暗号化パスワードの最小文字数
+pub const MIN_EXPORT_PASSWORD_LENGTH: usize = 8;
+
+/// 暗号化した出力ファイルに付ける拡張子
+const ENCRYPTED_EXTENSION: &str = "zip";
+
+/// 分離署名ファイルに付ける拡張子
+const SIGNATURE_EXTENSION: &str = "sig";
+
+/// 出力ファイル保護のInteractor
+pub struct ExportProtectionInteractor<R, P>
+where
+    R: ApplicationSettingsRepository,
+    P: ExportProtectionOutputPort,
+{
+    settings_repository: Arc<R>,
+    protection: Arc<P>,
+}
+
+impl<R, P> ExportProtectionInteractor<R, P>
+where
+    R: ApplicationSettingsRepository,
+    P: ExportProtectionOutputPort,
+{
+    pub fn new(settings_repository: Arc<R>, protection: Arc<P>) -> Self {
+        Self { settings_repository, protection }
+    }
+
+    /// 現在の出力ファイル保護設定（設定が未作成の場合は保護なし）
+    pub async fn settings(&self) -> ApplicationResult<ExportProtectionSettings> {
+        Ok(self
+            .settings_repository
+            .find()
+            .await?
+            .map(|settings| settings.export_protection())
+            .unwrap_or_default())
+    }
+
+    /// 設定に従って出力内容を保護する
+    ///
+    /// 暗号化が有効な場合は `<ファイル名>.zip` に格納し、署名が有効な場合は
+    /// 出力するファイル（暗号化した場合はZIP）の署名を `<ファイル名>.sig` として続ける。
+    pub async fn protect(
+        &self,
+        request: ProtectExportRequest,
+    ) -> ApplicationResult<ProtectExportResponse> {
+        let settings = self.settings().await?;
+
+        let mut file =
+            ProtectedExportFileDto { file_name: request.file_name, content: request.content };
+        if settings.encryption_enabled {
+            let password = request.password.unwrap_or_default();
+            if password.chars().count() < MIN_EXPORT_PASSWORD_LENGTH {
+                return Err(ApplicationError::ValidationError(format!(
+                    "暗号化のパスワードは{}文字以上で入力してください",
+                    MIN_EXPORT_PASSWORD_LENGTH
+                )));
+            }
+            let content = self.protection.encrypt(&file.file_name, &file.content, &password)?;
+            file = ProtectedExportFileDto {
+                file_name: format!("{}.{}", file.file_name, ENCRYPTED_EXTENSION),
+                content,
+            };
+        }
+
+        let mut files = Vec::new();
+        if settings.signature_enabled {
+            let signature = self.protection.sign(&file.file_name, &file.content)?;
+            let signature_file = ProtectedExportFileDto {
+                file_name: format!("{}.{}", file.file_name, SIGNATURE_EXTENSION),
+                content: signature.into_bytes(),
+            };
+            files.push(file);
+            files.push(signature_file);
+        } else {
+            files.push(file);
+        }
+
+        Ok(ProtectExportResponse {
+            files,
+            encrypted: settings.encryption_enabled,
+            signed: settings.signature_enabled,
+        })
+    }
+
+    /// 出力ファイルを分離署名で検証する
+    pub fn verify(
+        &self,
+        request: VerifyExportSignatureRequest,
+    ) -> ApplicationResult<ExportSignatureVerificationDto> {
+        self.protection.verify(&request.content, &request.signature)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use javelin_domain::{
+        error::DomainResult,
+        masters::{
+            ApplicationSettings, BackupRetentionDays, ClosingDay, DateFormat, DecimalPlaces,
+            FiscalYearStartMonth, Language,
+        },
+    };
+
+    use super::*;
+
+    struct InMemorySettingsRepository {
+        settings: Mutex<ApplicationSettings>,
+    }
+
+    impl InMemorySettingsRepository {
+        fn with_protection(encryption_enabled: bool, signature_enabled: bool) -> Self {
+            let mut settings = ApplicationSettings::new(
+                None,
+                Language::new("ja").unwrap(),
+                DecimalPlaces::new(2).unwrap(),
+                DateFormat::new("YYYY-MM-DD").unwrap(),
+                FiscalYearStartMonth::new(4).unwrap(),
+                ClosingDay::new(31).unwrap(),
+                false,
+                BackupRetentionDays::new(90).unwrap(),
+            );
+            settings.update_export_protection(ExportProtectionSettings::new(
+                encryption_enabled,
+                signature_enabled,
+            ));
+            Self { settings: Mutex::new(settings) }
+        }
+    }
+
+    impl ApplicationSettingsRepository for InMemorySettingsRepository {
+        async fn find(&self) -> DomainResult<Option<ApplicationSettings>> {
+            Ok(Some(self.settings.lock().unwrap().clone()))
+        }
+
+        async fn save(&self, settings: &ApplicationSettings) -> DomainResult<()> {
+            *self.settings.lock().unwrap() = settings.clone();
+            Ok(())
+        }
+    }
+
+    /// 暗号化・署名の内容を見分けられる文字列で返すスタブ
+    struct StubProtection;
+
+    impl ExportProtectionOutputPort for StubProtection {
+        fn encrypt(
+            &self,
+            file_name: &str,
+            content: &[u8],
+            password: &str,
+        ) -> ApplicationResult<Vec<u8>> {
+            Ok(format!("zip({},{},{})", file_name, content.len(), password).into_bytes())
+        }
+
+        fn sign(&self, file_name: &str, content: &[u8]) -> ApplicationResult<String> {
+            Ok(format!("sig({},{})", file_name, content.len()))
+        }
+
+        fn verify(
+            &self,
+            _content: &[u8],
+            _signature: &str,
+        ) -> ApplicationResult<ExportSignatureVerificationDto> {
+            Err(ApplicationError::ValidationError("未使用".to_string()))
+        }
+    }
+
+    fn interactor(
+        encryption_enabled: bool,
+        signature_enabled: bool,
+    ) -> ExportProtectionInteractor<InMemorySettingsRepository, StubProtection> {
+        ExportProtectionInteractor::new(
+            Arc::new(InMemorySettingsRepository::with_protection(
+                encryption_enabled,
+                signature_enabled,
+            )),
+            Arc::new(StubProtection),
+        )
+    }
+
+    fn request(password: Option<&str>) -> ProtectExportRequest {
+        ProtectExportRequest {
+            file_name: "ledger.csv".to_string(),
+            content: b"a,b\n".to_vec(),
+            password: password.map(str::to_string),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_unprotected_export_is_passed_through() {
+        let response = interactor(false, false).protect(request(None)).await.unwrap();
+        assert_eq!(response.file_names(), vec!["ledger.csv"]);
+        assert_eq!(response.files[0].content, b"a,b\n");
+        assert!(!response.encrypted && !response.signed);
+    }
+
+    #[tokio::test]
+    async fn test_encrypted_export_is_signed_after_encryption() {
+        let interactor = interactor(true, true);
+        assert!(interactor.protect(request(None)).await.is_err());
+        assert!(interactor.protect(request(Some("short"))).await.is_err());
+
+        let response = interactor.protect(request(Some("passw0rd!"))).await.unwrap();
+        assert_eq!(response.file_names(), vec!["ledger.csv.zip", "ledger.csv.zip.sig"]);
+        assert_eq!(response.files[0].content, b"zip(ledger.csv,4,passw0rd!)");
+        // 署名は暗号化後のZIPに対して作成する
+        assert_eq!(response.files[1].content, b"sig(ledger.csv.zip,27)");
+    }
+}
@@ -0,0 +1,166 @@
+// FinancialInstrumentInteractor - 償却原価で測定する金融商品のユースケース
+// 責務: 債券・貸付金・借入金の登録と償却原価スケジュールの提供
+
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+use javelin_domain::{
+    financial_close::financial_instrument::{
+        FinancialInstrument, InstrumentKind, InstrumentPosition,
+    },
+    repositories::FinancialInstrumentRepository,
+};
+
+use crate::{
+    dtos::{request::RegisterFinancialInstrumentRequest, response::FinancialInstrumentResponse},
+    error::{ApplicationError, ApplicationResult},
+};
+
+/// 金融商品のInteractor
+///
+/// 利息の見越計上はIFRS評価処理で起票する。見越計上済みの金融商品は
+/// スケジュールの根拠となるため、再登録による置き換えを認めない。
+pub struct FinancialInstrumentInteractor<F>
+where
+    F: FinancialInstrumentRepository,
+{
+    instrument_repository: Arc<F>,
+}
+
+impl<F> FinancialInstrumentInteractor<F>
+where
+    F: FinancialInstrumentRepository,
+{
+    pub fn new(instrument_repository: Arc<F>) -> Self {
+        Self { instrument_repository }
+    }
+
+    /// 金融商品を登録（未計上の金融商品は置き換える）
+    pub async fn register(
+        &self,
+        request: RegisterFinancialInstrumentRequest,
+    ) -> ApplicationResult<FinancialInstrumentResponse> {
+        if let Some(existing) = self.instrument_repository.find(&request.instrument_id).await?
+            && !existing.accrued_entries().is_empty()
+        {
+            return Err(ApplicationError::ValidationFailed(vec![format!(
+                "利息を見越計上済みのため置き換えられません: {}",
+                existing.instrument_id()
+            )]));
+        }
+
+        let instrument = FinancialInstrument::new(
+            request.instrument_id,
+            request.name,
+            InstrumentKind::parse(&request.kind)?,
+            InstrumentPosition::parse(&request.position)?,
+            request.account_code,
+            request.currency,
+            request.face_value,
+            request.initial_carrying_amount,
+            request.coupon_rate,
+            request.effective_rate,
+            parse_date(&request.start_date, "取得日")?,
+            parse_date(&request.maturity_date, "満期日")?,
+        )?;
+
+        self.instrument_repository.save(&instrument).await?;
+
+        Ok(FinancialInstrumentResponse::from_instrument(&instrument))
+    }
+
+    /// 登録済みの金融商品を償却原価スケジュールとともに取得
+    pub async fn list(&self) -> ApplicationResult<Vec<FinancialInstrumentResponse>> {
+        let instruments = self.instrument_repository.find_all().await?;
+        Ok(instruments.iter().map(FinancialInstrumentResponse::from_instrument).collect())
+    }
+}
+
+fn parse_date(value: &str, label: &str) -> ApplicationResult<NaiveDate> {
+    NaiveDate::parse_from_str(value.trim(), "%Y-%m-%d").map_err(|_| {
+        ApplicationError::ValidationError(format!(
+            "{}はYYYY-MM-DD形式で指定してください: {}",
+            label, value
+        ))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::BTreeMap, sync::Mutex};
+
+    use javelin_domain::error::DomainResult;
+
+    use super::*;
+
+    #[derive(Default)]
+    struct MockInstrumentRepository {
+        instruments: Mutex<BTreeMap<String, FinancialInstrument>>,
+    }
+
+    impl FinancialInstrumentRepository for MockInstrumentRepository {
+        async fn find(&self, instrument_id: &str) -> DomainResult<Option<FinancialInstrument>> {
+            Ok(self.instruments.lock().unwrap().get(instrument_id).cloned())
+        }
+
+        async fn find_all(&self) -> DomainResult<Vec<FinancialInstrument>> {
+            Ok(self.instruments.lock().unwrap().values().cloned().collect())
+        }
+
+        async fn save(&self, instrument: &FinancialInstrument) -> DomainResult<()> {
+            self.instruments
+                .lock()
+                .unwrap()
+                .insert(instrument.instrument_id().to_string(), instrument.clone());
+            Ok(())
+        }
+    }
+
+    fn request(start_date: &str) -> RegisterFinancialInstrumentRequest {
+        RegisterFinancialInstrumentRequest {
+            instrument_id: "BOND-001".to_string(),
+            name: "第1回社債".to_string(),
+            kind: "Bond".to_string(),
+            position: "Asset".to_string(),
+            account_code: "1500".to_string(),
+            currency: "JPY".to_string(),
+            face_value: 1_000_000.0,
+            initial_carrying_amount: 980_000.0,
+            coupon_rate: 0.01,
+            effective_rate: 0.0305,
+            start_date: start_date.to_string(),
+            maturity_date: "2025-11-01".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_register_returns_schedule() {
+        let interactor =
+            FinancialInstrumentInteractor::new(Arc::new(MockInstrumentRepository::default()));
+
+        let registered = interactor.register(request("2024-11-01")).await.unwrap();
+        assert_eq!(registered.schedule.len(), 12);
+        assert_eq!(registered.schedule[11].closing_carrying_amount, 1_000_000.0);
+
+        let listed = interactor.list().await.unwrap();
+        assert_eq!(listed.len(), 1);
+        assert!(listed[0].schedule.iter().all(|line| line.accrued_entry_id.is_none()));
+
+        assert!(interactor.register(request("2024/11/01")).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_accrued_instrument_cannot_be_replaced() {
+        let repository = Arc::new(MockInstrumentRepository::default());
+        let interactor = FinancialInstrumentInteractor::new(Arc::clone(&repository));
+        interactor.register(request("2024-11-01")).await.unwrap();
+
+        let mut instrument = repository.find("BOND-001").await.unwrap().unwrap();
+        instrument.mark_accrued(2024, 11, "entry-1").unwrap();
+        repository.save(&instrument).await.unwrap();
+
+        assert!(interactor.register(request("2024-10-01")).await.is_err());
+        let listed = interactor.list().await.unwrap();
+        assert_eq!(listed[0].schedule[0].accrued_entry_id.as_deref(), Some("entry-1"));
+    }
+}
@@ -0,0 +1,495 @@
+// InitialSetupInteractor - 初期セットアップのユースケース
+// 責務: 空のデータディレクトリでの初回起動時に、運用開始に必要なマスタを一括で作成する
+
+use std::{str::FromStr, sync::Arc};
+
+use javelin_domain::{
+    financial_close::journal_entry::values::Currency,
+    masters::{
+        AccountMaster, ChartOfAccountsTemplate, CompanyCode, CompanyMaster, CompanyName,
+        DEFAULT_POLICY_ADMINISTRATOR, FiscalYearStartMonth, MasterChangeHistory, PasswordHasher,
+        UserAccount,
+    },
+    repositories::{
+        AccountMasterRepository, AccountingPolicyRepository, ApplicationSettingsRepository,
+        CompanyMasterRepository, UserAccountRepository,
+    },
+};
+
+use crate::{
+    dtos::{
+        request::InitialSetupRequest,
+        response::{InitialSetupOptions, InitialSetupResponse, PolicyOption},
+    },
+    error::{ApplicationError, ApplicationResult},
+};
+
+/// 初期セットアップで選択できる基準通貨
+const BASE_CURRENCIES: [Currency; 3] = [Currency::JPY, Currency::USD, Currency::EUR];
+
+/// 初期セットアップのInteractor
+///
+/// 管理者ユーザの登録を完了の目印とし、ユーザが1件も登録されていない間だけ実行できる。
+/// 途中で失敗した場合も再実行できるよう、管理者ユーザは最後に登録する。
+pub struct InitialSetupInteractor<U, H, C, A, S, P>
+where
+    U: UserAccountRepository,
+    H: PasswordHasher + 'static,
+    C: CompanyMasterRepository,
+    A: AccountMasterRepository,
+    S: ApplicationSettingsRepository,
+    P: AccountingPolicyRepository,
+{
+    users: Arc<U>,
+    hasher: Arc<H>,
+    companies: Arc<C>,
+    accounts: Arc<A>,
+    settings: Arc<S>,
+    policy: Arc<P>,
+}
+
+impl<U, H, C, A, S, P> InitialSetupInteractor<U, H, C, A, S, P>
+where
+    U: UserAccountRepository,
+    H: PasswordHasher + 'static,
+    C: CompanyMasterRepository,
+    A: AccountMasterRepository,
+    S: ApplicationSettingsRepository,
+    P: AccountingPolicyRepository,
+{
+    pub fn new(
+        users: Arc<U>,
+        hasher: Arc<H>,
+        companies: Arc<C>,
+        accounts: Arc<A>,
+        settings: Arc<S>,
+        policy: Arc<P>,
+    ) -> Self {
+        Self { users, hasher, companies, accounts, settings, policy }
+    }
+
+    /// 初期セットアップが必要か（ユーザが1件も登録されていない）
+    pub async fn is_required(&self) -> ApplicationResult<bool> {
+        Ok(self.users.count().await? == 0)
+    }
+
+    /// 基準通貨と勘定科目テンプレートの選択肢
+    pub fn options(&self) -> InitialSetupOptions {
+        InitialSetupOptions {
+            currencies: BASE_CURRENCIES
+                .iter()
+                .map(|currency| PolicyOption {
+                    code: currency.as_str().to_string(),
+                    name: format!("{}（{}）", currency.display_name(), currency.as_str()),
+                })
+                .collect(),
+            chart_templates: ChartOfAccountsTemplate::ALL
+                .iter()
+                .map(|template| PolicyOption {
+                    code: template.code().to_string(),
+                    name: format!(
+                        "{}（{}科目）",
+                        template.display_name(),
+                        template.accounts().len()
+                    ),
+                })
+                .collect(),
+        }
+    }
+
+    /// 初期セットアップを実行
+    ///
+    /// 入力をすべて検証してから、会社・勘定科目・アプリケーション設定・会計方針の管理者・
+    /// 管理者ユーザの順に登録する。
+    pub async fn execute(
+        &self,
+        request: InitialSetupRequest,
+    ) -> ApplicationResult<InitialSetupResponse> {
+        if !self.is_required().await? {
+            return Err(ApplicationError::ValidationError(
+                "初期セットアップは完了しています".to_string(),
+            ));
+        }
+
+        let company = CompanyMaster::new(
+            CompanyCode::new(request.company_code.trim())?,
+            CompanyName::new(request.company_name.trim())?,
+            true,
+        );
+        let fiscal_year_start_month = FiscalYearStartMonth::new(request.fiscal_year_start_month)?;
+        let base_currency = Currency::from_str(&request.base_currency)
+            .ok()
+            .filter(|currency| BASE_CURRENCIES.contains(currency))
+            .ok_or_else(|| {
+                ApplicationError::ValidationError(format!(
+                    "基準通貨を選択してください: {}",
+                    request.base_currency
+                ))
+            })?;
+        let template = ChartOfAccountsTemplate::from_code(&request.chart_template)?;
+        let admin_user_id = request.admin_user_id.trim().to_string();
+        UserAccount::validate_user_id(&admin_user_id)?;
+        UserAccount::validate_password(&request.admin_password)?;
+
+        let hasher = Arc::clone(&self.hasher);
+        let password = request.admin_password;
+        let hash = tokio::task::spawn_blocking(move || hasher.hash(&password))
+            .await
+            .map_err(|e| ApplicationError::UseCaseExecutionFailed(e.to_string()))??;
+        let admin = UserAccount::new(admin_user_id, request.admin_display_name.trim(), hash)?;
+
+        self.register_company(&company, admin.user_id()).await?;
+        let accounts = template.accounts();
+        for account in &accounts {
+            self.register_account(account, admin.user_id()).await?;
+        }
+
+        let mut settings = self.settings.find().await?.ok_or_else(|| {
+            ApplicationError::QueryExecutionFailed(
+                "アプリケーション設定が見つかりません".to_string(),
+            )
+        })?;
+        settings.update_default_company_code(Some(company.code().clone()));
+        settings.update_fiscal_year_start_month(fiscal_year_start_month);
+        settings.update_base_currency(base_currency);
+        self.settings.save(&settings).await?;
+
+        let mut policy = self.policy.load().await?;
+        if let Some(change) =
+            policy.grant_administrator(DEFAULT_POLICY_ADMINISTRATOR, admin.user_id())?
+        {
+            self.policy.save(&policy, &change).await?;
+        }
+
+        self.users.save(&admin).await?;
+
+        Ok(InitialSetupResponse {
+            company_code: company.code().value().to_string(),
+            accounts_registered: accounts.len(),
+            admin_user_id: admin.user_id().to_string(),
+            admin_display_name: admin.display_name().to_string(),
+        })
+    }
+
+    /// 会社を登録（変更履歴に初期登録として残す）
+    async fn register_company(
+        &self,
+        company: &CompanyMaster,
+        changed_by: &str,
+    ) -> ApplicationResult<()> {
+        let before = self.companies.find_by_code(company.code()).await?;
+        let history = MasterChangeHistory::new(
+            company.code().value(),
+            self.companies.find_changes(company.code()).await?,
+        );
+        if let Some(change) = history.record(before, Some(company.clone()), changed_by)? {
+            self.companies.apply_change(&change).await?;
+        }
+        Ok(())
+    }
+
+    /// 勘定科目を登録（既定で登録済みの科目はテンプレートの名称・区分で上書き）
+    async fn register_account(
+        &self,
+        account: &AccountMaster,
+        changed_by: &str,
+    ) -> ApplicationResult<()> {
+        let before = self.accounts.find_by_code(account.code()).await?;
+        let history = MasterChangeHistory::new(
+            account.code().value(),
+            self.accounts.find_changes(account.code()).await?,
+        );
+        if let Some(change) = history.record(before, Some(account.clone()), changed_by)? {
+            self.accounts.apply_change(&change).await?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use javelin_domain::{
+        error::DomainResult,
+        masters::{
+            AccountCode, AccountingPolicy, AccountingPolicyChanged, ApplicationSettings,
+            BackupRetentionDays, ClosingDay, DateFormat, DecimalPlaces, Language, MasterChange,
+            MasterRecord, PasswordHash,
+        },
+    };
+
+    use super::*;
+
+    /// 変更イベントと現在の内容を保持するマスタ
+    struct MasterStore<T> {
+        records: Mutex<Vec<T>>,
+        changes: Mutex<Vec<MasterChange<T>>>,
+    }
+
+    impl<T: MasterRecord> MasterStore<T> {
+        fn new() -> Self {
+            Self { records: Mutex::new(Vec::new()), changes: Mutex::new(Vec::new()) }
+        }
+
+        fn find(&self, code: &str) -> Option<T> {
+            self.records.lock().unwrap().iter().find(|r| r.record_code() == code).cloned()
+        }
+
+        fn put(&self, record: &T) {
+            let mut records = self.records.lock().unwrap();
+            records.retain(|r| r.record_code() != record.record_code());
+            records.push(record.clone());
+        }
+
+        fn apply(&self, change: &MasterChange<T>) {
+            let mut records = self.records.lock().unwrap();
+            records.retain(|r| r.record_code() != change.record_code());
+            records.extend(change.after().cloned());
+            self.changes.lock().unwrap().push(change.clone());
+        }
+
+        fn changes_of(&self, code: &str) -> Vec<MasterChange<T>> {
+            self.changes
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|c| c.record_code() == code)
+                .cloned()
+                .collect()
+        }
+    }
+
+    impl CompanyMasterRepository for MasterStore<CompanyMaster> {
+        async fn find_by_code(&self, code: &CompanyCode) -> DomainResult<Option<CompanyMaster>> {
+            Ok(self.find(code.value()))
+        }
+
+        async fn find_all(&self) -> DomainResult<Vec<CompanyMaster>> {
+            Ok(self.records.lock().unwrap().clone())
+        }
+
+        async fn save(&self, company_master: &CompanyMaster) -> DomainResult<()> {
+            self.put(company_master);
+            Ok(())
+        }
+
+        async fn delete(&self, _code: &CompanyCode) -> DomainResult<()> {
+            Ok(())
+        }
+
+        async fn apply_change(&self, change: &MasterChange<CompanyMaster>) -> DomainResult<()> {
+            self.apply(change);
+            Ok(())
+        }
+
+        async fn find_changes(
+            &self,
+            code: &CompanyCode,
+        ) -> DomainResult<Vec<MasterChange<CompanyMaster>>> {
+            Ok(self.changes_of(code.value()))
+        }
+    }
+
+    impl AccountMasterRepository for MasterStore<AccountMaster> {
+        async fn find_by_code(&self, code: &AccountCode) -> DomainResult<Option<AccountMaster>> {
+            Ok(self.find(code.value()))
+        }
+
+        async fn find_all(&self) -> DomainResult<Vec<AccountMaster>> {
+            Ok(self.records.lock().unwrap().clone())
+        }
+
+        async fn save(&self, account_master: &AccountMaster) -> DomainResult<()> {
+            self.put(account_master);
+            Ok(())
+        }
+
+        async fn delete(&self, _code: &AccountCode) -> DomainResult<()> {
+            Ok(())
+        }
+
+        async fn apply_change(&self, change: &MasterChange<AccountMaster>) -> DomainResult<()> {
+            self.apply(change);
+            Ok(())
+        }
+
+        async fn find_changes(
+            &self,
+            code: &AccountCode,
+        ) -> DomainResult<Vec<MasterChange<AccountMaster>>> {
+            Ok(self.changes_of(code.value()))
+        }
+    }
+
+    #[derive(Default)]
+    struct MockUserRepository {
+        accounts: Mutex<Vec<UserAccount>>,
+    }
+
+    impl UserAccountRepository for MockUserRepository {
+        async fn find_by_id(&self, user_id: &str) -> DomainResult<Option<UserAccount>> {
+            Ok(self.accounts.lock().unwrap().iter().find(|a| a.user_id() == user_id).cloned())
+        }
+
+        async fn save(&self, account: &UserAccount) -> DomainResult<()> {
+            self.accounts.lock().unwrap().push(account.clone());
+            Ok(())
+        }
+
+        async fn count(&self) -> DomainResult<usize> {
+            Ok(self.accounts.lock().unwrap().len())
+        }
+    }
+
+    struct MockSettingsRepository {
+        settings: Mutex<ApplicationSettings>,
+    }
+
+    impl ApplicationSettingsRepository for MockSettingsRepository {
+        async fn find(&self) -> DomainResult<Option<ApplicationSettings>> {
+            Ok(Some(self.settings.lock().unwrap().clone()))
+        }
+
+        async fn save(&self, settings: &ApplicationSettings) -> DomainResult<()> {
+            *self.settings.lock().unwrap() = settings.clone();
+            Ok(())
+        }
+    }
+
+    #[derive(Default)]
+    struct MockPolicyRepository {
+        policy: Mutex<AccountingPolicy>,
+    }
+
+    impl AccountingPolicyRepository for MockPolicyRepository {
+        async fn load(&self) -> DomainResult<AccountingPolicy> {
+            Ok(self.policy.lock().unwrap().clone())
+        }
+
+        async fn save(
+            &self,
+            policy: &AccountingPolicy,
+            _change: &AccountingPolicyChanged,
+        ) -> DomainResult<()> {
+            *self.policy.lock().unwrap() = policy.clone();
+            Ok(())
+        }
+
+        async fn find_changes(&self) -> DomainResult<Vec<AccountingPolicyChanged>> {
+            Ok(Vec::new())
+        }
+    }
+
+    struct PlainHasher;
+
+    impl PasswordHasher for PlainHasher {
+        fn hash(&self, password: &str) -> DomainResult<PasswordHash> {
+            PasswordHash::new(format!("plain${}", password))
+        }
+
+        fn verify(&self, password: &str, hash: &PasswordHash) -> DomainResult<bool> {
+            Ok(hash.as_str() == format!("plain${}", password))
+        }
+    }
+
+    type TestInteractor = InitialSetupInteractor<
+        MockUserRepository,
+        PlainHasher,
+        MasterStore<CompanyMaster>,
+        MasterStore<AccountMaster>,
+        MockSettingsRepository,
+        MockPolicyRepository,
+    >;
+
+    struct Fixture {
+        users: Arc<MockUserRepository>,
+        accounts: Arc<MasterStore<AccountMaster>>,
+        settings: Arc<MockSettingsRepository>,
+        policy: Arc<MockPolicyRepository>,
+        interactor: TestInteractor,
+    }
+
+    fn fixture() -> Fixture {
+        let users = Arc::new(MockUserRepository::default());
+        let accounts = Arc::new(MasterStore::new());
+        let settings = Arc::new(MockSettingsRepository {
+            settings: Mutex::new(ApplicationSettings::new(
+                Some(CompanyCode::new("0001").unwrap()),
+                Language::new("ja").unwrap(),
+                DecimalPlaces::new(2).unwrap(),
+                DateFormat::new("YYYY-MM-DD").unwrap(),
+                FiscalYearStartMonth::new(4).unwrap(),
+                ClosingDay::new(31).unwrap(),
+                true,
+                BackupRetentionDays::new(90).unwrap(),
+            )),
+        });
+        let policy = Arc::new(MockPolicyRepository::default());
+        let interactor = InitialSetupInteractor::new(
+            Arc::clone(&users),
+            Arc::new(PlainHasher),
+            Arc::new(MasterStore::new()),
+            Arc::clone(&accounts),
+            Arc::clone(&settings),
+            Arc::clone(&policy),
+        );
+        Fixture { users, accounts, settings, policy, interactor }
+    }
+
+    fn request() -> InitialSetupRequest {
+        InitialSetupRequest {
+            company_code: "1000".to_string(),
+            company_name: "株式会社ジャベリン".to_string(),
+            fiscal_year_start_month: 1,
+            base_currency: "USD".to_string(),
+            chart_template: "manufacturing".to_string(),
+            admin_user_id: "tanaka".to_string(),
+            admin_display_name: "田中".to_string(),
+            admin_password: "passw0rd".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_initial_setup_registers_masters_and_admin() {
+        let fixture = fixture();
+        assert!(fixture.interactor.is_required().await.unwrap());
+
+        let response = fixture.interactor.execute(request()).await.unwrap();
+        assert_eq!(response.company_code, "1000");
+        assert_eq!(
+            response.accounts_registered,
+            ChartOfAccountsTemplate::Manufacturing.accounts().len()
+        );
+        assert_eq!(fixture.accounts.find_all().await.unwrap().len(), response.accounts_registered);
+
+        let settings = fixture.settings.find().await.unwrap().unwrap();
+        assert_eq!(settings.default_company_code().unwrap().value(), "1000");
+        assert_eq!(settings.fiscal_year_start_month().value(), 1);
+        assert_eq!(settings.base_currency(), &Currency::USD);
+
+        assert!(fixture.policy.load().await.unwrap().is_administrator("tanaka"));
+        let admin = fixture.users.find_by_id("tanaka").await.unwrap().unwrap();
+        assert_eq!(admin.display_name(), "田中");
+
+        // 完了後は再実行できない
+        assert!(!fixture.interactor.is_required().await.unwrap());
+        assert!(fixture.interactor.execute(request()).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_invalid_input_registers_nothing() {
+        let fixture = fixture();
+
+        let weak_password =
+            InitialSetupRequest { admin_password: "short".to_string(), ..request() };
+        let unknown_template =
+            InitialSetupRequest { chart_template: "unknown".to_string(), ..request() };
+        for request in [weak_password, unknown_template] {
+            assert!(fixture.interactor.execute(request).await.is_err());
+        }
+
+        assert!(fixture.accounts.find_all().await.unwrap().is_empty());
+        assert!(fixture.interactor.is_required().await.unwrap());
+    }
+}
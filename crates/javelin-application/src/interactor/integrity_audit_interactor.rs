@@ -0,0 +1,348 @@
+// IntegrityAuditInteractor - 整合性監査のユースケース
+// 責務: チェックサム検証・Projection整合性チェック・番号連続性監査をまとめて実行し、
+//       結果を監査証跡として記録する
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use javelin_domain::{
+    integrity_audit::{
+        AuditTrigger, INTEGRITY_AUDIT_STREAM, IntegrityAuditCheck, IntegrityAuditEvent,
+        IntegrityAuditReport, is_audit_due,
+    },
+    repositories::EventRepository,
+};
+
+use crate::{
+    dtos::{
+        request::{RunIntegrityAuditRequest, SequenceAuditRequest},
+        response::{IntegrityAuditCheckDto, IntegrityAuditResponse, SequenceFindingKind},
+    },
+    error::ApplicationResult,
+    interactor::{ConsistencyCheckInteractor, SequenceAuditInteractor},
+    query_service::{
+        EventChecksumQueryService, ProjectionConsistencyQueryService, SequenceAuditQueryService,
+    },
+};
+
+/// 整合性監査のInteractor
+///
+/// 監査項目は個別に実行し、実行できなかった項目は検出事項として記録する。
+/// チェックサム検証で破損イベントを隔離してから、残りの項目を同じイベント列で検査する。
+pub struct IntegrityAuditInteractor<C, S, K, R>
+where
+    C: ProjectionConsistencyQueryService,
+    S: SequenceAuditQueryService,
+    K: EventChecksumQueryService,
+    R: EventRepository,
+{
+    consistency_check: ConsistencyCheckInteractor<C>,
+    sequence_audit: SequenceAuditInteractor<S>,
+    checksum_query_service: Arc<K>,
+    event_repository: Arc<R>,
+}
+
+impl<C, S, K, R> IntegrityAuditInteractor<C, S, K, R>
+where
+    C: ProjectionConsistencyQueryService,
+    S: SequenceAuditQueryService,
+    K: EventChecksumQueryService,
+    R: EventRepository,
+{
+    pub fn new(
+        consistency_query_service: Arc<C>,
+        sequence_query_service: Arc<S>,
+        checksum_query_service: Arc<K>,
+        event_repository: Arc<R>,
+    ) -> Self {
+        Self {
+            consistency_check: ConsistencyCheckInteractor::new(consistency_query_service),
+            sequence_audit: SequenceAuditInteractor::new(sequence_query_service),
+            checksum_query_service,
+            event_repository,
+        }
+    }
+
+    /// 監査を実行し、結果を記録する
+    pub async fn run(
+        &self,
+        request: RunIntegrityAuditRequest,
+    ) -> ApplicationResult<IntegrityAuditResponse> {
+        let trigger = AuditTrigger::parse(&request.trigger)?;
+        let started_at = Utc::now();
+
+        let checks = vec![
+            self.check_checksums().await,
+            self.check_consistency().await,
+            self.check_sequence().await,
+        ];
+
+        let report = IntegrityAuditReport {
+            audit_id: format!(
+                "IA-{}",
+                uuid::Uuid::new_v4().simple().to_string()[..12].to_uppercase()
+            ),
+            trigger,
+            requested_by: request.requested_by,
+            started_at,
+            completed_at: Utc::now(),
+            checks,
+        };
+        self.event_repository
+            .append_events(
+                INTEGRITY_AUDIT_STREAM,
+                vec![IntegrityAuditEvent::AuditCompleted { report: report.clone() }],
+            )
+            .await?;
+
+        Ok(to_response(&report))
+    }
+
+    /// 最新の監査結果（未実施の場合はNone）
+    pub async fn latest(&self) -> ApplicationResult<Option<IntegrityAuditResponse>> {
+        Ok(self.load_latest().await?.as_ref().map(to_response))
+    }
+
+    /// 定期監査が必要か
+    pub async fn is_due(&self) -> ApplicationResult<bool> {
+        Ok(is_audit_due(self.load_latest().await?.as_ref(), Utc::now()))
+    }
+
+    async fn load_latest(&self) -> ApplicationResult<Option<IntegrityAuditReport>> {
+        Ok(self
+            .event_repository
+            .get_events(INTEGRITY_AUDIT_STREAM)
+            .await?
+            .into_iter()
+            .filter_map(|value| serde_json::from_value(value).ok())
+            .map(|event| match event {
+                IntegrityAuditEvent::AuditCompleted { report } => report,
+            })
+            .max_by_key(|report| report.completed_at))
+    }
+
+    async fn check_checksums(&self) -> IntegrityAuditCheck {
+        const NAME: &str = "イベントのチェックサム";
+        match self.checksum_query_service.verify_checksums().await {
+            Ok(report) => IntegrityAuditCheck {
+                name: NAME.to_string(),
+                summary: format!("イベント{}件", report.verified_events),
+                findings: report
+                    .corrupted_events
+                    .iter()
+                    .map(|event| format!("破損 seq={}: {}", event.global_sequence, event.error))
+                    .collect(),
+            },
+            Err(e) => failed_check(NAME, e),
+        }
+    }
+
+    async fn check_consistency(&self) -> IntegrityAuditCheck {
+        const NAME: &str = "Projection整合性";
+        match self.consistency_check.execute().await {
+            Ok(response) => IntegrityAuditCheck {
+                name: NAME.to_string(),
+                summary: format!(
+                    "仕訳{}件・勘定{}科目",
+                    response.checked_entries, response.checked_accounts
+                ),
+                findings: response
+                    .violations
+                    .iter()
+                    .map(|v| format!("{} {}: {}", v.kind.label(), v.subject, v.detail))
+                    .collect(),
+            },
+            Err(e) => failed_check(NAME, e),
+        }
+    }
+
+    async fn check_sequence(&self) -> IntegrityAuditCheck {
+        const NAME: &str = "番号連続性";
+        match self.sequence_audit.execute(SequenceAuditRequest { fiscal_year: None }).await {
+            Ok(response) => IntegrityAuditCheck {
+                name: NAME.to_string(),
+                summary: format!("番号系列{}件", response.series.len()),
+                // 取消番号は説明済みの欠番のため検出事項としない
+                findings: response
+                    .findings
+                    .iter()
+                    .filter(|f| f.kind != SequenceFindingKind::Voided)
+                    .map(|f| format!("{} {}: {}", f.kind.label(), f.subject, f.detail))
+                    .collect(),
+            },
+            Err(e) => failed_check(NAME, e),
+        }
+    }
+}
+
+/// 実行できなかった監査項目
+fn failed_check(name: &str, error: impl std::fmt::Display) -> IntegrityAuditCheck {
+    IntegrityAuditCheck {
+        name: name.to_string(),
+        summary: String::new(),
+        findings: vec![format!("実行できませんでした: {}", error)],
+    }
+}
+
+fn to_response(report: &IntegrityAuditReport) -> IntegrityAuditResponse {
+    IntegrityAuditResponse {
+        audit_id: report.audit_id.clone(),
+        trigger: report.trigger.label().to_string(),
+        requested_by: report.requested_by.clone(),
+        started_at: report.started_at.to_rfc3339(),
+        completed_at: report.completed_at.to_rfc3339(),
+        passed: report.passed(),
+        finding_count: report.finding_count(),
+        checks: report
+            .checks
+            .iter()
+            .map(|check| IntegrityAuditCheckDto {
+                name: check.name.clone(),
+                summary: check.summary.clone(),
+                passed: check.passed(),
+                findings: check.findings.clone(),
+            })
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::{
+        error::ApplicationError,
+        query_service::{
+            CorruptedEvent, EventChecksumReport, NumberedEntry, ProjectionConsistencySnapshot,
+            VoidedNumber,
+        },
+        testkit::InMemoryEventRepository,
+    };
+
+    struct MockConsistency {
+        fail: bool,
+    }
+
+    impl ProjectionConsistencyQueryService for MockConsistency {
+        async fn load_snapshot(&self) -> ApplicationResult<ProjectionConsistencySnapshot> {
+            if self.fail {
+                return Err(ApplicationError::ProjectionDatabaseError("読み込み失敗".to_string()));
+            }
+            Ok(ProjectionConsistencySnapshot::default())
+        }
+    }
+
+    struct MockSequence {
+        entry_numbers: Vec<&'static str>,
+    }
+
+    impl SequenceAuditQueryService for MockSequence {
+        async fn load_numbered_entries(&self) -> ApplicationResult<Vec<NumberedEntry>> {
+            Ok(self
+                .entry_numbers
+                .iter()
+                .map(|number| NumberedEntry {
+                    entry_id: format!("id-{}", number),
+                    transaction_date: "2024-04-01".to_string(),
+                    voucher_number: None,
+                    entry_number: Some(number.to_string()),
+                    status: "Posted".to_string(),
+                })
+                .collect())
+        }
+
+        async fn load_voided_numbers(&self) -> ApplicationResult<Vec<VoidedNumber>> {
+            Ok(Vec::new())
+        }
+    }
+
+    struct MockChecksum {
+        corrupted: Vec<u64>,
+    }
+
+    impl EventChecksumQueryService for MockChecksum {
+        async fn verify_checksums(&self) -> ApplicationResult<EventChecksumReport> {
+            Ok(EventChecksumReport {
+                verified_events: 10,
+                corrupted_events: self
+                    .corrupted
+                    .iter()
+                    .map(|&global_sequence| CorruptedEvent {
+                        global_sequence,
+                        error: "checksum mismatch".to_string(),
+                    })
+                    .collect(),
+            })
+        }
+    }
+
+    type TestInteractor = IntegrityAuditInteractor<
+        MockConsistency,
+        MockSequence,
+        MockChecksum,
+        InMemoryEventRepository,
+    >;
+
+    fn interactor(
+        consistency_fails: bool,
+        entry_numbers: Vec<&'static str>,
+        corrupted: Vec<u64>,
+        events: Arc<InMemoryEventRepository>,
+    ) -> TestInteractor {
+        IntegrityAuditInteractor::new(
+            Arc::new(MockConsistency { fail: consistency_fails }),
+            Arc::new(MockSequence { entry_numbers }),
+            Arc::new(MockChecksum { corrupted }),
+            events,
+        )
+    }
+
+    fn request(trigger: &str) -> RunIntegrityAuditRequest {
+        RunIntegrityAuditRequest {
+            trigger: trigger.to_string(),
+            requested_by: "system".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_audit_passes_and_is_persisted() {
+        let events = Arc::new(InMemoryEventRepository::default());
+        let interactor =
+            interactor(false, vec!["JE-2024-00001", "JE-2024-00002"], vec![], Arc::clone(&events));
+        assert!(interactor.latest().await.unwrap().is_none());
+        assert!(interactor.is_due().await.unwrap());
+
+        let response = interactor.run(request("Scheduled")).await.unwrap();
+        assert!(response.passed);
+        assert_eq!(response.trigger, "定期");
+        assert_eq!(response.checks.len(), 3);
+
+        // 記録した結果は別のインスタンスからも参照できる
+        let reloaded = self::interactor(false, vec![], vec![], events);
+        let latest = reloaded.latest().await.unwrap().unwrap();
+        assert_eq!(latest.audit_id, response.audit_id);
+        assert!(!reloaded.is_due().await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_audit_collects_findings_from_each_check() {
+        let events = Arc::new(InMemoryEventRepository::default());
+        let interactor = interactor(true, vec!["JE-2024-00001", "JE-2024-00003"], vec![7], events);
+
+        let response = interactor.run(request("BeforeLock")).await.unwrap();
+        assert!(!response.passed);
+        assert_eq!(response.trigger, "締日固定前");
+        let failed: Vec<&str> = response
+            .checks
+            .iter()
+            .filter(|check| !check.passed)
+            .map(|check| check.name.as_str())
+            .collect();
+        assert_eq!(failed, vec!["イベントのチェックサム", "Projection整合性", "番号連続性"]);
+        assert!(response.checks[0].findings[0].contains("seq=7"));
+        assert!(response.checks[1].findings[0].starts_with("実行できませんでした"));
+        assert_eq!(response.finding_count, 3);
+
+        assert!(interactor.run(request("Weekly")).await.is_err());
+    }
+}
@@ -1,443 +1,703 @@
-/// Unit tests for Interactors
-///
-/// Task 9.10: Interactorのユニットテストを作成
-/// - 正常な仕訳登録フロー
-/// - バリデーションエラー
-/// - EventStore保存失敗
-///
-/// Requirements: 1.1, 1.9
-
-#[cfg(test)]
-mod interactor_unit_tests {
-    use std::sync::{Arc, Mutex};
-
-    use javelin_domain::{
-        error::DomainError, financial_close::journal_entry::events::JournalEntryEvent,
-        repositories::EventRepository,
-    };
-    use tokio::sync::mpsc;
-
-    use crate::{
-        dtos::{JournalEntryLineDto, RegisterJournalEntryRequest, RegisterJournalEntryResponse},
-        input_ports::RegisterJournalEntryUseCase,
-        interactor::RegisterJournalEntryInteractor,
-        output_port::{EventNotification, EventOutputPort, JournalEntryOutputPort},
-    };
+//! Unit tests for Interactors
+//!
+//! Task 9.10: Interactorのユニットテストを作成
+//! - 正常な仕訳登録フロー
+//! - バリデーションエラー
+//! - EventStore保存失敗
+//!
+//! Requirements: 1.1, 1.9
+
+use std::sync::{Arc, Mutex};
+
+use javelin_domain::{
+    error::DomainError,
+    financial_close::journal_entry::{
+        events::JournalEntryEvent,
+        services::{EntryNumberExistenceChecker, EntryNumberGenerator},
+        values::{EntryNumber, EntryNumberScope, TaxType},
+    },
+    masters::{
+        AccountingPolicy, ApplicationSettings, BackupRetentionDays, ClosingDay, CompanyCode,
+        DEFAULT_POLICY_ADMINISTRATOR, DateFormat, DecimalPlaces, DimensionMaster,
+        FiscalYearStartMonth, Language, RoundingMode, TaxRounding,
+    },
+    repositories::{ApplicationSettingsRepository, EventRepository},
+};
+use tokio::sync::mpsc;
+
+use crate::{
+    dtos::{
+        ApproveJournalEntryRequest, JournalEntryLineDto, RegisterJournalEntryRequest,
+        RegisterJournalEntryResponse,
+    },
+    input_ports::{ApproveJournalEntryUseCase, RegisterJournalEntryUseCase},
+    interactor::{ApproveJournalEntryInteractor, RegisterJournalEntryInteractor},
+    output_port::{EventNotification, EventOutputPort, JournalEntryOutputPort},
+    testkit::InMemoryEventRepository,
+};
+
+/// モックEventRepository - 常に失敗する
+struct FailingEventRepository;
+
+impl EventRepository for FailingEventRepository {
+    type Event = JournalEntryEvent;
+
+    async fn append(&self, _event: Self::Event) -> javelin_domain::error::DomainResult<()> {
+        Err(DomainError::RepositoryError("EventStore保存失敗".to_string()))
+    }
 
-    /// モックEventRepository - 成功する
-    struct MockEventRepository {
-        saved_events: Arc<Mutex<Vec<(String, Vec<serde_json::Value>)>>>,
+    async fn append_events<T>(
+        &self,
+        _aggregate_id: &str,
+        _events: Vec<T>,
+    ) -> javelin_domain::error::DomainResult<u64>
+    where
+        T: serde::Serialize + Send + 'static,
+    {
+        Err(DomainError::RepositoryError("EventStore保存失敗".to_string()))
     }
 
-    impl MockEventRepository {
-        fn new() -> Self {
-            Self { saved_events: Arc::new(Mutex::new(Vec::new())) }
-        }
+    async fn append_batches<T>(
+        &self,
+        _batches: Vec<(String, Vec<T>)>,
+    ) -> javelin_domain::error::DomainResult<u64>
+    where
+        T: serde::Serialize + Send + 'static,
+    {
+        Err(DomainError::RepositoryError("EventStore保存失敗".to_string()))
+    }
 
-        fn get_saved_events(&self) -> Vec<(String, Vec<serde_json::Value>)> {
-            self.saved_events.lock().unwrap().clone()
-        }
+    async fn get_events(
+        &self,
+        _aggregate_id: &str,
+    ) -> javelin_domain::error::DomainResult<Vec<serde_json::Value>> {
+        Ok(vec![])
     }
 
-    impl EventRepository for MockEventRepository {
-        type Event = JournalEntryEvent;
+    async fn get_all_events(
+        &self,
+        _from_sequence: u64,
+    ) -> javelin_domain::error::DomainResult<Vec<serde_json::Value>> {
+        Ok(vec![])
+    }
 
-        async fn append(&self, _event: Self::Event) -> javelin_domain::error::DomainResult<()> {
-            Ok(())
-        }
+    async fn get_latest_sequence(&self) -> javelin_domain::error::DomainResult<u64> {
+        Ok(0)
+    }
+}
 
-        async fn append_events<T>(
-            &self,
-            aggregate_id: &str,
-            events: Vec<T>,
-        ) -> javelin_domain::error::DomainResult<u64>
-        where
-            T: serde::Serialize + Send + 'static,
-        {
-            let json_events: Vec<serde_json::Value> =
-                events.into_iter().map(|e| serde_json::to_value(e).unwrap()).collect();
-
-            self.saved_events
-                .lock()
-                .unwrap()
-                .push((aggregate_id.to_string(), json_events.clone()));
-
-            Ok(json_events.len() as u64)
-        }
+/// モックEventOutputPort
+struct MockEventOutputPort;
 
-        async fn get_events(
-            &self,
-            _aggregate_id: &str,
-        ) -> javelin_domain::error::DomainResult<Vec<serde_json::Value>> {
-            Ok(vec![])
-        }
+impl EventOutputPort for MockEventOutputPort {
+    async fn notify_event(&self, _notification: EventNotification) {}
+}
 
-        async fn get_all_events(
-            &self,
-            _from_sequence: u64,
-        ) -> javelin_domain::error::DomainResult<Vec<serde_json::Value>> {
-            Ok(vec![])
-        }
+/// モックVoucherNumberGenerator
+struct MockVoucherNumberGenerator;
 
-        async fn get_latest_sequence(&self) -> javelin_domain::error::DomainResult<u64> {
-            Ok(0)
-        }
+#[allow(async_fn_in_trait)]
+impl javelin_domain::financial_close::journal_entry::services::VoucherNumberGenerator
+    for MockVoucherNumberGenerator
+{
+    async fn reserve(&self, fiscal_year: u32) -> javelin_domain::error::DomainResult<String> {
+        Ok(format!("V-{}-00001", fiscal_year))
     }
 
-    /// モックEventRepository - 常に失敗する
-    struct FailingEventRepository;
-
-    impl EventRepository for FailingEventRepository {
-        type Event = JournalEntryEvent;
-
-        async fn append(&self, _event: Self::Event) -> javelin_domain::error::DomainResult<()> {
-            Err(DomainError::RepositoryError("EventStore保存失敗".to_string()))
-        }
+    async fn confirm(&self, _voucher_number: &str) -> javelin_domain::error::DomainResult<()> {
+        Ok(())
+    }
 
-        async fn append_events<T>(
-            &self,
-            _aggregate_id: &str,
-            _events: Vec<T>,
-        ) -> javelin_domain::error::DomainResult<u64>
-        where
-            T: serde::Serialize + Send + 'static,
-        {
-            Err(DomainError::RepositoryError("EventStore保存失敗".to_string()))
-        }
+    async fn release(
+        &self,
+        _voucher_number: &str,
+        _reason: &str,
+    ) -> javelin_domain::error::DomainResult<
+        javelin_domain::financial_close::journal_entry::services::VoucherNumberRelease,
+    > {
+        Ok(javelin_domain::financial_close::journal_entry::services::VoucherNumberRelease::Returned)
+    }
 
-        async fn get_events(
-            &self,
-            _aggregate_id: &str,
-        ) -> javelin_domain::error::DomainResult<Vec<serde_json::Value>> {
-            Ok(vec![])
-        }
+    async fn voided_numbers(
+        &self,
+        _fiscal_year: Option<u32>,
+    ) -> javelin_domain::error::DomainResult<
+        Vec<javelin_domain::financial_close::journal_entry::services::VoidedVoucherNumber>,
+    > {
+        Ok(Vec::new())
+    }
+}
 
-        async fn get_all_events(
-            &self,
-            _from_sequence: u64,
-        ) -> javelin_domain::error::DomainResult<Vec<serde_json::Value>> {
-            Ok(vec![])
-        }
+/// モックJournalEntryOutputPort
+struct MockJournalEntryOutputPort {
+    sender: mpsc::UnboundedSender<RegisterJournalEntryResponse>,
+}
 
-        async fn get_latest_sequence(&self) -> javelin_domain::error::DomainResult<u64> {
-            Ok(0)
-        }
+impl JournalEntryOutputPort for MockJournalEntryOutputPort {
+    async fn present_register_result(&self, response: RegisterJournalEntryResponse) {
+        let _ = self.sender.send(response);
     }
 
-    /// モックEventOutputPort
-    struct MockEventOutputPort;
+    async fn notify_progress(&self, _message: String) {
+        // モックでは進捗通知を無視
+    }
 
-    impl EventOutputPort for MockEventOutputPort {
-        async fn notify_event(&self, _notification: EventNotification) {}
+    async fn notify_error(&self, _error_message: String) {
+        // モックではエラー通知を無視
     }
 
-    /// モックVoucherNumberGenerator
-    struct MockVoucherNumberGenerator;
+    async fn present_approve_result(&self, _response: crate::dtos::ApproveJournalEntryResponse) {}
 
-    #[allow(async_fn_in_trait)]
-    impl javelin_domain::financial_close::journal_entry::services::VoucherNumberGenerator
-        for MockVoucherNumberGenerator
-    {
-        async fn generate_next(
-            &self,
-            fiscal_year: u32,
-        ) -> javelin_domain::error::DomainResult<String> {
-            Ok(format!("V-{}-00001", fiscal_year))
-        }
+    async fn present_reject_result(&self, _response: crate::dtos::RejectJournalEntryResponse) {}
+
+    async fn present_update_draft_result(
+        &self,
+        _response: crate::dtos::UpdateDraftJournalEntryResponse,
+    ) {
     }
 
-    /// モックJournalEntryOutputPort
-    struct MockJournalEntryOutputPort {
-        sender: mpsc::UnboundedSender<RegisterJournalEntryResponse>,
+    async fn present_delete_draft_result(
+        &self,
+        _response: crate::dtos::DeleteDraftJournalEntryResponse,
+    ) {
     }
 
-    impl JournalEntryOutputPort for MockJournalEntryOutputPort {
-        async fn present_register_result(&self, response: RegisterJournalEntryResponse) {
-            let _ = self.sender.send(response);
-        }
+    async fn present_correct_result(&self, _response: crate::dtos::CorrectJournalEntryResponse) {}
 
-        async fn notify_progress(&self, _message: String) {
-            // モックでは進捗通知を無視
-        }
+    async fn present_reverse_result(&self, _response: crate::dtos::ReverseJournalEntryResponse) {}
 
-        async fn notify_error(&self, _error_message: String) {
-            // モックではエラー通知を無視
-        }
+    async fn present_submit_for_approval_result(
+        &self,
+        _response: crate::dtos::SubmitForApprovalResponse,
+    ) {
+    }
+}
 
-        async fn present_approve_result(
-            &self,
-            _response: crate::dtos::ApproveJournalEntryResponse,
-        ) {
-        }
+#[tokio::test]
+async fn test_successful_journal_entry_registration() {
+    // 正常な仕訳登録フロー
+    let repo = Arc::new(InMemoryEventRepository::new());
+    let event_output = Arc::new(MockEventOutputPort);
+    let (sender, mut receiver) = mpsc::unbounded_channel();
+    let output_port = Arc::new(MockJournalEntryOutputPort { sender });
+    let voucher_generator = Arc::new(MockVoucherNumberGenerator);
+
+    let interactor = RegisterJournalEntryInteractor::new(
+        Arc::clone(&repo),
+        event_output,
+        output_port,
+        voucher_generator,
+    );
+
+    let request = RegisterJournalEntryRequest {
+        transaction_date: "2024-01-15".parse().unwrap(),
+        voucher_number: "V-001".to_string(),
+        lines: vec![
+            JournalEntryLineDto {
+                line_number: 1,
+                side: "Debit".to_string(),
+                account_code: "1010".to_string(),
+                sub_account_code: None,
+                department_code: None,
+                dimensions: Default::default(),
+                amount: 100000.0,
+                currency: "JPY".to_string(),
+                tax_type: "NonTaxable".to_string(),
+                tax_amount: 0.0,
+                description: None,
+            },
+            JournalEntryLineDto {
+                line_number: 2,
+                side: "Credit".to_string(),
+                account_code: "4010".to_string(),
+                sub_account_code: None,
+                department_code: None,
+                dimensions: Default::default(),
+                amount: 100000.0,
+                currency: "JPY".to_string(),
+                tax_type: "NonTaxable".to_string(),
+                tax_amount: 0.0,
+                description: None,
+            },
+        ],
+        description: None,
+        user_id: "user1".to_string(),
+    };
 
-        async fn present_reject_result(&self, _response: crate::dtos::RejectJournalEntryResponse) {}
+    let result = interactor.execute(request).await;
+
+    // 成功することを確認
+    assert!(result.is_ok());
+
+    // イベントが保存されたことを確認（仕訳IDは生成順に並ぶUUIDv7）
+    let saved_events = repo.recorded();
+    assert_eq!(saved_events.len(), 1);
+    assert_eq!(
+        uuid::Uuid::parse_str(&saved_events[0].aggregate_id).unwrap().get_version_num(),
+        7
+    );
+
+    // レスポンスが送信されたことを確認
+    let response = receiver.recv().await;
+    assert!(response.is_some());
+    let response = response.unwrap();
+    assert_eq!(response.status, "Draft");
+    assert_eq!(response.entry_id, saved_events[0].aggregate_id);
+}
 
-        async fn present_update_draft_result(
-            &self,
-            _response: crate::dtos::UpdateDraftJournalEntryResponse,
-        ) {
-        }
+#[test]
+fn test_validation_error_invalid_date() {
+    // バリデーションエラー: 無効な日付形式
+    // 取引日付は画面・JSONの境界で解析するため、不正な日付ではリクエストを作成できない
+    let result = crate::dtos::request::parse_transaction_date("invalid-date");
+    assert_eq!(result.unwrap_err(), "YYYY-MM-DD形式で入力してください: invalid-date");
+    assert!(crate::dtos::request::parse_transaction_date("2024-02-30").is_err());
+}
 
-        async fn present_delete_draft_result(
-            &self,
-            _response: crate::dtos::DeleteDraftJournalEntryResponse,
-        ) {
-        }
+#[tokio::test]
+async fn test_validation_error_unbalanced_entry() {
+    // バリデーションエラー: 借貸不一致
+    let repo = Arc::new(InMemoryEventRepository::new());
+    let event_output = Arc::new(MockEventOutputPort);
+    let (sender, _receiver) = mpsc::unbounded_channel();
+    let output_port = Arc::new(MockJournalEntryOutputPort { sender });
+    let voucher_generator = Arc::new(MockVoucherNumberGenerator);
+
+    let interactor =
+        RegisterJournalEntryInteractor::new(repo, event_output, output_port, voucher_generator);
+
+    let request = RegisterJournalEntryRequest {
+        transaction_date: "2024-01-15".parse().unwrap(),
+        voucher_number: "V-001".to_string(),
+        lines: vec![
+            JournalEntryLineDto {
+                line_number: 1,
+                side: "Debit".to_string(),
+                account_code: "1010".to_string(),
+                sub_account_code: None,
+                department_code: None,
+                dimensions: Default::default(),
+                amount: 100000.0,
+                currency: "JPY".to_string(),
+                tax_type: "NonTaxable".to_string(),
+                tax_amount: 0.0,
+                description: None,
+            },
+            JournalEntryLineDto {
+                line_number: 2,
+                side: "Credit".to_string(),
+                account_code: "4010".to_string(),
+                sub_account_code: None,
+                department_code: None,
+                dimensions: Default::default(),
+                amount: 50000.0, // 借貸不一致
+                currency: "JPY".to_string(),
+                tax_type: "NonTaxable".to_string(),
+                tax_amount: 0.0,
+                description: None,
+            },
+        ],
+        description: None,
+        user_id: "user1".to_string(),
+    };
 
-        async fn present_correct_result(
-            &self,
-            _response: crate::dtos::CorrectJournalEntryResponse,
-        ) {
-        }
+    let result = interactor.execute(request).await;
 
-        async fn present_reverse_result(
-            &self,
-            _response: crate::dtos::ReverseJournalEntryResponse,
-        ) {
-        }
+    // エラーが返されることを確認
+    assert!(result.is_err());
 
-        async fn present_submit_for_approval_result(
-            &self,
-            _response: crate::dtos::SubmitForApprovalResponse,
-        ) {
+    // DomainErrorであることを確認
+    match result {
+        Err(crate::error::ApplicationError::DomainError(_)) => {
+            // 期待通り
         }
+        _ => panic!("Expected DomainError"),
     }
+}
 
-    #[tokio::test]
-    async fn test_successful_journal_entry_registration() {
-        // 正常な仕訳登録フロー
-        let repo = Arc::new(MockEventRepository::new());
-        let event_output = Arc::new(MockEventOutputPort);
-        let (sender, mut receiver) = mpsc::unbounded_channel();
-        let output_port = Arc::new(MockJournalEntryOutputPort { sender });
-        let voucher_generator = Arc::new(MockVoucherNumberGenerator);
+#[tokio::test]
+async fn test_event_store_save_failure() {
+    // EventStore保存失敗
+    let repo = Arc::new(FailingEventRepository);
+    let event_output = Arc::new(MockEventOutputPort);
+    let (sender, _receiver) = mpsc::unbounded_channel();
+    let output_port = Arc::new(MockJournalEntryOutputPort { sender });
+    let voucher_generator = Arc::new(MockVoucherNumberGenerator);
+
+    let interactor =
+        RegisterJournalEntryInteractor::new(repo, event_output, output_port, voucher_generator);
+
+    let request = RegisterJournalEntryRequest {
+        transaction_date: "2024-01-15".parse().unwrap(),
+        voucher_number: "V-001".to_string(),
+        lines: vec![
+            JournalEntryLineDto {
+                line_number: 1,
+                side: "Debit".to_string(),
+                account_code: "1010".to_string(),
+                sub_account_code: None,
+                department_code: None,
+                dimensions: Default::default(),
+                amount: 100000.0,
+                currency: "JPY".to_string(),
+                tax_type: "NonTaxable".to_string(),
+                tax_amount: 0.0,
+                description: None,
+            },
+            JournalEntryLineDto {
+                line_number: 2,
+                side: "Credit".to_string(),
+                account_code: "4010".to_string(),
+                sub_account_code: None,
+                department_code: None,
+                dimensions: Default::default(),
+                amount: 100000.0,
+                currency: "JPY".to_string(),
+                tax_type: "NonTaxable".to_string(),
+                tax_amount: 0.0,
+                description: None,
+            },
+        ],
+        description: None,
+        user_id: "user1".to_string(),
+    };
 
-        let interactor = RegisterJournalEntryInteractor::new(
-            Arc::clone(&repo),
-            event_output,
-            output_port,
-            voucher_generator,
-        );
+    let result = interactor.execute(request).await;
 
-        let request = RegisterJournalEntryRequest {
-            transaction_date: "2024-01-15".to_string(),
-            voucher_number: "V-001".to_string(),
-            lines: vec![
-                JournalEntryLineDto {
-                    line_number: 1,
-                    side: "Debit".to_string(),
-                    account_code: "1010".to_string(),
-                    sub_account_code: None,
-                    department_code: None,
-                    amount: 100000.0,
-                    currency: "JPY".to_string(),
-                    tax_type: "NonTaxable".to_string(),
-                    tax_amount: 0.0,
-                    description: None,
-                },
-                JournalEntryLineDto {
-                    line_number: 2,
-                    side: "Credit".to_string(),
-                    account_code: "4010".to_string(),
-                    sub_account_code: None,
-                    department_code: None,
-                    amount: 100000.0,
-                    currency: "JPY".to_string(),
-                    tax_type: "NonTaxable".to_string(),
-                    tax_amount: 0.0,
-                    description: None,
-                },
-            ],
-            user_id: "user1".to_string(),
-        };
+    // エラーが返されることを確認
+    assert!(result.is_err());
 
-        let result = interactor.execute(request).await;
+    // DomainErrorであることを確認
+    match result {
+        Err(crate::error::ApplicationError::DomainError(_)) => {
+            // 期待通り
+        }
+        _ => panic!("Expected DomainError"),
+    }
+}
 
-        // 成功することを確認
-        assert!(result.is_ok());
+#[tokio::test]
+async fn test_registration_rounds_amounts_by_accounting_policy() {
+    // 会計方針: 円は整数に四捨五入、課税の税額は切上げ
+    let mut policy = AccountingPolicy::default();
+    policy
+        .change_tax_rounding(
+            DEFAULT_POLICY_ADMINISTRATOR,
+            TaxRounding::new(TaxType::Taxable, RoundingMode::Ceiling),
+        )
+        .unwrap();
+
+    let repo = Arc::new(InMemoryEventRepository::new());
+    let (sender, _receiver) = mpsc::unbounded_channel();
+    let interactor = RegisterJournalEntryInteractor::new(
+        Arc::clone(&repo),
+        Arc::new(MockEventOutputPort),
+        Arc::new(MockJournalEntryOutputPort { sender }),
+        Arc::new(MockVoucherNumberGenerator),
+    )
+    .with_accounting_policy(policy);
+
+    let line = |line_number: u32, side: &str, account_code: &str| JournalEntryLineDto {
+        line_number,
+        side: side.to_string(),
+        account_code: account_code.to_string(),
+        sub_account_code: None,
+        department_code: None,
+        dimensions: Default::default(),
+        amount: 10000.4,
+        currency: "JPY".to_string(),
+        tax_type: "Taxable".to_string(),
+        tax_amount: 909.09,
+        description: None,
+    };
+    let request = RegisterJournalEntryRequest {
+        transaction_date: "2024-01-15".parse().unwrap(),
+        voucher_number: "V-001".to_string(),
+        lines: vec![line(1, "Debit", "5010"), line(2, "Credit", "1010")],
+        description: None,
+        user_id: "user1".to_string(),
+    };
 
-        // イベントが保存されたことを確認
-        let saved_events = repo.get_saved_events();
-        assert_eq!(saved_events.len(), 1);
+    interactor.execute(request).await.unwrap();
 
-        // レスポンスが送信されたことを確認
-        let response = receiver.recv().await;
-        assert!(response.is_some());
-        let response = response.unwrap();
-        assert_eq!(response.status, "Draft");
+    let saved_events = repo.recorded();
+    let lines = saved_events[0].payload["lines"].as_array().unwrap();
+    for line in lines {
+        assert_eq!(line["amount"].as_f64(), Some(10000.0));
+        assert_eq!(line["tax_amount"].as_f64(), Some(910.0));
     }
+}
 
-    #[tokio::test]
-    async fn test_validation_error_invalid_date() {
-        // バリデーションエラー: 無効な日付形式
-        let repo = Arc::new(MockEventRepository::new());
-        let event_output = Arc::new(MockEventOutputPort);
+#[tokio::test]
+async fn test_registration_validates_dimensions_against_masters() {
+    let masters = vec![DimensionMaster::new("project", "P001", "新基幹システム", true).unwrap()];
+    let line =
+        |line_number: u32, side: &str, account_code: &str, project: &str| JournalEntryLineDto {
+            line_number,
+            side: side.to_string(),
+            account_code: account_code.to_string(),
+            sub_account_code: None,
+            department_code: None,
+            dimensions: [("project".to_string(), project.to_string())].into(),
+            amount: 50000.0,
+            currency: "JPY".to_string(),
+            tax_type: "NonTaxable".to_string(),
+            tax_amount: 0.0,
+            description: None,
+        };
+    let register = |project: &'static str| {
+        let repo = Arc::new(InMemoryEventRepository::new());
         let (sender, _receiver) = mpsc::unbounded_channel();
-        let output_port = Arc::new(MockJournalEntryOutputPort { sender });
-
-        let voucher_generator = Arc::new(MockVoucherNumberGenerator);
-        let interactor =
-            RegisterJournalEntryInteractor::new(repo, event_output, output_port, voucher_generator);
-
+        let interactor = RegisterJournalEntryInteractor::new(
+            Arc::clone(&repo),
+            Arc::new(MockEventOutputPort),
+            Arc::new(MockJournalEntryOutputPort { sender }),
+            Arc::new(MockVoucherNumberGenerator),
+        )
+        .with_dimension_masters(masters.clone());
         let request = RegisterJournalEntryRequest {
-            transaction_date: "invalid-date".to_string(),
+            transaction_date: "2024-01-15".parse().unwrap(),
             voucher_number: "V-001".to_string(),
-            lines: vec![
-                JournalEntryLineDto {
-                    line_number: 1,
-                    side: "Debit".to_string(),
-                    account_code: "1010".to_string(),
-                    sub_account_code: None,
-                    department_code: None,
-                    amount: 100000.0,
-                    currency: "JPY".to_string(),
-                    tax_type: "NonTaxable".to_string(),
-                    tax_amount: 0.0,
-                    description: None,
-                },
-                JournalEntryLineDto {
-                    line_number: 2,
-                    side: "Credit".to_string(),
-                    account_code: "4010".to_string(),
-                    sub_account_code: None,
-                    department_code: None,
-                    amount: 100000.0,
-                    currency: "JPY".to_string(),
-                    tax_type: "NonTaxable".to_string(),
-                    tax_amount: 0.0,
-                    description: None,
-                },
-            ],
+            lines: vec![line(1, "Debit", "6100", project), line(2, "Credit", "2100", project)],
+            description: None,
             user_id: "user1".to_string(),
         };
+        async move { (interactor.execute(request).await, repo) }
+    };
 
-        let result = interactor.execute(request).await;
-
-        // エラーが返されることを確認
-        assert!(result.is_err());
+    let (result, repo) = register("P001").await;
+    result.unwrap();
+    let saved_events = repo.recorded();
+    let lines = saved_events[0].payload["lines"].as_array().unwrap();
+    assert_eq!(lines[0]["dimensions"]["project"].as_str(), Some("P001"));
 
-        // ValidationFailedエラーであることを確認
-        match result {
-            Err(crate::error::ApplicationError::ValidationFailed(_)) => {
-                // 期待通り
-            }
-            _ => panic!("Expected ValidationFailed error"),
-        }
-    }
+    let (result, repo) = register("P999").await;
+    assert!(matches!(result, Err(crate::error::ApplicationError::DomainError(_))));
+    assert!(repo.recorded().is_empty());
+}
+#[tokio::test]
+async fn test_registration_embeds_account_names() {
+    let repo = Arc::new(InMemoryEventRepository::new());
+    let (sender, _receiver) = mpsc::unbounded_channel();
+    let interactor = RegisterJournalEntryInteractor::new(
+        Arc::clone(&repo),
+        Arc::new(MockEventOutputPort),
+        Arc::new(MockJournalEntryOutputPort { sender }),
+        Arc::new(MockVoucherNumberGenerator),
+    )
+    .with_account_names([("1000".to_string(), "現金".to_string())].into());
+
+    let line = |line_number: u32, side: &str, account_code: &str| JournalEntryLineDto {
+        line_number,
+        side: side.to_string(),
+        account_code: account_code.to_string(),
+        sub_account_code: None,
+        department_code: None,
+        dimensions: Default::default(),
+        amount: 1000.0,
+        currency: "JPY".to_string(),
+        tax_type: "NonTaxable".to_string(),
+        tax_amount: 0.0,
+        description: None,
+    };
+    let request = RegisterJournalEntryRequest {
+        transaction_date: "2024-01-15".parse().unwrap(),
+        voucher_number: "V-001".to_string(),
+        lines: vec![line(1, "Debit", "1000"), line(2, "Credit", "4999")],
+        description: None,
+        user_id: "user1".to_string(),
+    };
 
-    #[tokio::test]
-    async fn test_validation_error_unbalanced_entry() {
-        // バリデーションエラー: 借貸不一致
-        let repo = Arc::new(MockEventRepository::new());
-        let event_output = Arc::new(MockEventOutputPort);
-        let (sender, _receiver) = mpsc::unbounded_channel();
-        let output_port = Arc::new(MockJournalEntryOutputPort { sender });
-        let voucher_generator = Arc::new(MockVoucherNumberGenerator);
+    interactor.execute(request).await.unwrap();
 
-        let interactor =
-            RegisterJournalEntryInteractor::new(repo, event_output, output_port, voucher_generator);
+    let saved_events = repo.recorded();
+    let lines = saved_events[0].payload["lines"].as_array().unwrap();
+    assert_eq!(lines[0]["account_name"].as_str(), Some("現金"));
+    // マスタに登録されていない科目は名称なしで保存する
+    assert!(lines[1].get("account_name").is_none());
+}
 
-        let request = RegisterJournalEntryRequest {
-            transaction_date: "2024-01-15".to_string(),
-            voucher_number: "V-001".to_string(),
-            lines: vec![
-                JournalEntryLineDto {
-                    line_number: 1,
-                    side: "Debit".to_string(),
-                    account_code: "1010".to_string(),
-                    sub_account_code: None,
-                    department_code: None,
-                    amount: 100000.0,
-                    currency: "JPY".to_string(),
-                    tax_type: "NonTaxable".to_string(),
-                    tax_amount: 0.0,
-                    description: None,
-                },
-                JournalEntryLineDto {
-                    line_number: 2,
-                    side: "Credit".to_string(),
-                    account_code: "4010".to_string(),
-                    sub_account_code: None,
-                    department_code: None,
-                    amount: 50000.0, // 借貸不一致
-                    currency: "JPY".to_string(),
-                    tax_type: "NonTaxable".to_string(),
-                    tax_amount: 0.0,
-                    description: None,
-                },
-            ],
-            user_id: "user1".to_string(),
-        };
+/// モックEntryNumberGenerator - 払い出し・戻した番号を記録する
+#[derive(Default)]
+struct MockEntryNumberGenerator {
+    issued: Mutex<u64>,
+    released: Mutex<Vec<String>>,
+    posted: Vec<String>,
+}
 
-        let result = interactor.execute(request).await;
+impl EntryNumberGenerator for MockEntryNumberGenerator {
+    async fn next(
+        &self,
+        scope: &EntryNumberScope,
+    ) -> javelin_domain::error::DomainResult<EntryNumber> {
+        let mut issued = self.issued.lock().unwrap();
+        *issued += 1;
+        EntryNumber::scoped(scope, *issued)
+    }
 
-        // エラーが返されることを確認
-        assert!(result.is_err());
+    async fn release(&self, entry_number: &EntryNumber) -> javelin_domain::error::DomainResult<()> {
+        self.released.lock().unwrap().push(entry_number.value().to_string());
+        Ok(())
+    }
+}
 
-        // DomainErrorであることを確認
-        match result {
-            Err(crate::error::ApplicationError::DomainError(_)) => {
-                // 期待通り
-            }
-            _ => panic!("Expected DomainError"),
-        }
+impl EntryNumberExistenceChecker for MockEntryNumberGenerator {
+    async fn exists(
+        &self,
+        entry_number: &EntryNumber,
+    ) -> javelin_domain::error::DomainResult<bool> {
+        Ok(self.posted.iter().any(|posted| posted == entry_number.value()))
     }
+}
 
-    #[tokio::test]
-    async fn test_event_store_save_failure() {
-        // EventStore保存失敗
-        let repo = Arc::new(FailingEventRepository);
-        let event_output = Arc::new(MockEventOutputPort);
-        let (sender, _receiver) = mpsc::unbounded_channel();
-        let output_port = Arc::new(MockJournalEntryOutputPort { sender });
-        let voucher_generator = Arc::new(MockVoucherNumberGenerator);
+/// 2024-03-15付の承認待ちの仕訳を登録し、仕訳IDを返す
+async fn pending_entry(repo: &Arc<InMemoryEventRepository>) -> String {
+    let (sender, _receiver) = mpsc::unbounded_channel();
+    let interactor = RegisterJournalEntryInteractor::new(
+        Arc::clone(repo),
+        Arc::new(MockEventOutputPort),
+        Arc::new(MockJournalEntryOutputPort { sender }),
+        Arc::new(MockVoucherNumberGenerator),
+    );
+    let line = |line_number: u32, side: &str, account_code: &str| JournalEntryLineDto {
+        line_number,
+        side: side.to_string(),
+        account_code: account_code.to_string(),
+        sub_account_code: None,
+        department_code: None,
+        dimensions: Default::default(),
+        amount: 1000.0,
+        currency: "JPY".to_string(),
+        tax_type: "NonTaxable".to_string(),
+        tax_amount: 0.0,
+        description: None,
+    };
+    let request = RegisterJournalEntryRequest {
+        transaction_date: "2024-03-15".parse().unwrap(),
+        voucher_number: "V-001".to_string(),
+        lines: vec![line(1, "Debit", "1000"), line(2, "Credit", "4000")],
+        description: None,
+        user_id: "user1".to_string(),
+    };
+    interactor.execute(request).await.unwrap();
+
+    let entry_id = repo.recorded()[0].aggregate_id.clone();
+    repo.append_events(
+        &entry_id,
+        vec![JournalEntryEvent::ApprovalRequested {
+            entry_id: entry_id.clone(),
+            requested_by: "user1".to_string(),
+            requested_at: chrono::Utc::now(),
+        }],
+    )
+    .await
+    .unwrap();
+    entry_id
+}
 
-        let interactor =
-            RegisterJournalEntryInteractor::new(repo, event_output, output_port, voucher_generator);
+/// モックApplicationSettingsRepository - 会計年度は4月開始
+struct MockSettingsRepository {
+    default_company_code: Option<&'static str>,
+}
 
-        let request = RegisterJournalEntryRequest {
-            transaction_date: "2024-01-15".to_string(),
-            voucher_number: "V-001".to_string(),
-            lines: vec![
-                JournalEntryLineDto {
-                    line_number: 1,
-                    side: "Debit".to_string(),
-                    account_code: "1010".to_string(),
-                    sub_account_code: None,
-                    department_code: None,
-                    amount: 100000.0,
-                    currency: "JPY".to_string(),
-                    tax_type: "NonTaxable".to_string(),
-                    tax_amount: 0.0,
-                    description: None,
-                },
-                JournalEntryLineDto {
-                    line_number: 2,
-                    side: "Credit".to_string(),
-                    account_code: "4010".to_string(),
-                    sub_account_code: None,
-                    department_code: None,
-                    amount: 100000.0,
-                    currency: "JPY".to_string(),
-                    tax_type: "NonTaxable".to_string(),
-                    tax_amount: 0.0,
-                    description: None,
-                },
-            ],
-            user_id: "user1".to_string(),
-        };
+impl ApplicationSettingsRepository for MockSettingsRepository {
+    async fn find(&self) -> javelin_domain::error::DomainResult<Option<ApplicationSettings>> {
+        Ok(Some(ApplicationSettings::new(
+            self.default_company_code.map(|code| CompanyCode::new(code).unwrap()),
+            Language::new("ja").unwrap(),
+            DecimalPlaces::new(2).unwrap(),
+            DateFormat::new("YYYY-MM-DD").unwrap(),
+            FiscalYearStartMonth::new(4).unwrap(),
+            ClosingDay::new(31).unwrap(),
+            false,
+            BackupRetentionDays::new(90).unwrap(),
+        )))
+    }
 
-        let result = interactor.execute(request).await;
+    async fn save(
+        &self,
+        _settings: &ApplicationSettings,
+    ) -> javelin_domain::error::DomainResult<()> {
+        Ok(())
+    }
+}
 
-        // エラーが返されることを確認
-        assert!(result.is_err());
+fn settings_with_company() -> Arc<MockSettingsRepository> {
+    Arc::new(MockSettingsRepository { default_company_code: Some("0001") })
+}
 
-        // DomainErrorであることを確認
-        match result {
-            Err(crate::error::ApplicationError::DomainError(_)) => {
-                // 期待通り
-            }
-            _ => panic!("Expected DomainError"),
-        }
-    }
+#[tokio::test]
+async fn test_approval_numbers_entry_in_fiscal_year_of_default_company() {
+    let repo = Arc::new(InMemoryEventRepository::new());
+    let entry_id = pending_entry(&repo).await;
+    let (sender, _receiver) = mpsc::unbounded_channel();
+    let interactor = ApproveJournalEntryInteractor::new(
+        Arc::clone(&repo),
+        Arc::new(MockEventOutputPort),
+        Arc::new(MockJournalEntryOutputPort { sender }),
+        Arc::new(MockEntryNumberGenerator::default()),
+        settings_with_company(),
+    );
+
+    interactor
+        .execute(ApproveJournalEntryRequest {
+            entry_id: entry_id.clone(),
+            approver_id: "approver".to_string(),
+        })
+        .await
+        .unwrap();
+
+    // 4月開始のため、2024-03-15の取引は2023年度として採番する
+    let posted = repo
+        .events_of(&entry_id)
+        .into_iter()
+        .find(|event| event["type"] == "Posted")
+        .unwrap();
+    assert_eq!(posted["entry_number"].as_str(), Some("EN-0001-2023-000001"));
+}
+
+#[tokio::test]
+async fn test_approval_releases_number_when_posting_fails() {
+    let repo = Arc::new(InMemoryEventRepository::new());
+    let entry_id = pending_entry(&repo).await;
+    let generator = Arc::new(MockEntryNumberGenerator {
+        posted: vec!["EN-0001-2023-000001".to_string()],
+        ..Default::default()
+    });
+    let (sender, _receiver) = mpsc::unbounded_channel();
+    let interactor = ApproveJournalEntryInteractor::new(
+        Arc::clone(&repo),
+        Arc::new(MockEventOutputPort),
+        Arc::new(MockJournalEntryOutputPort { sender }),
+        Arc::clone(&generator),
+        settings_with_company(),
+    );
+    let recorded = repo.len();
+
+    let result = interactor
+        .execute(ApproveJournalEntryRequest { entry_id, approver_id: "approver".to_string() })
+        .await;
+
+    assert!(result.is_err());
+    assert_eq!(repo.len(), recorded);
+    assert_eq!(*generator.released.lock().unwrap(), vec!["EN-0001-2023-000001".to_string()]);
+}
+
+#[tokio::test]
+async fn test_approval_requires_default_company() {
+    let repo = Arc::new(InMemoryEventRepository::new());
+    let entry_id = pending_entry(&repo).await;
+    let (sender, _receiver) = mpsc::unbounded_channel();
+    let interactor = ApproveJournalEntryInteractor::new(
+        Arc::clone(&repo),
+        Arc::new(MockEventOutputPort),
+        Arc::new(MockJournalEntryOutputPort { sender }),
+        Arc::new(MockEntryNumberGenerator::default()),
+        Arc::new(MockSettingsRepository { default_company_code: None }),
+    );
+
+    let result = interactor
+        .execute(ApproveJournalEntryRequest { entry_id, approver_id: "approver".to_string() })
+        .await;
+    assert!(matches!(result, Err(crate::error::ApplicationError::ValidationError(_))));
 }
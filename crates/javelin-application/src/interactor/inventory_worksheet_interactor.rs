@@ -0,0 +1,176 @@
+// InventoryWorksheetInteractor - 棚卸資産評価ワークシートのユースケース
+// 責務: 品目別の取得原価・正味実現可能価額の取込と評価減の算定
+
+use std::sync::Arc;
+
+use javelin_domain::{
+    financial_close::inventory_valuation::{
+        InventoryValuationItem, InventoryWriteDownWorksheet, WriteDownMethod,
+    },
+    repositories::InventoryWorksheetRepository,
+};
+
+use crate::{
+    dtos::{
+        request::{ImportInventoryWorksheetRequest, LoadInventoryWorksheetRequest},
+        response::InventoryWorksheetResponse,
+    },
+    error::{ApplicationError, ApplicationResult},
+};
+
+/// 棚卸資産評価ワークシートのInteractor
+///
+/// 評価減の仕訳はIFRS評価処理で起票する。起票後のワークシートは
+/// 注記の根拠資料となるため、再取込による置き換えを認めない。
+pub struct InventoryWorksheetInteractor<W>
+where
+    W: InventoryWorksheetRepository,
+{
+    worksheet_repository: Arc<W>,
+}
+
+impl<W> InventoryWorksheetInteractor<W>
+where
+    W: InventoryWorksheetRepository,
+{
+    pub fn new(worksheet_repository: Arc<W>) -> Self {
+        Self { worksheet_repository }
+    }
+
+    /// 品目別の評価データを取り込み、会計期間のワークシートを作成
+    pub async fn import(
+        &self,
+        request: ImportInventoryWorksheetRequest,
+    ) -> ApplicationResult<InventoryWorksheetResponse> {
+        if let Some(existing) =
+            self.worksheet_repository.find(request.fiscal_year, request.period).await?
+            && existing.is_posted()
+        {
+            return Err(ApplicationError::ValidationFailed(vec![format!(
+                "評価減の仕訳を起票済みのため取り込めません: {}",
+                existing.worksheet_id()
+            )]));
+        }
+
+        let items = request
+            .items
+            .into_iter()
+            .map(|item| {
+                InventoryValuationItem::new(
+                    item.item_code,
+                    item.item_name,
+                    item.product_group,
+                    item.quantity,
+                    item.unit_cost,
+                    item.unit_net_realizable_value,
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let worksheet = InventoryWriteDownWorksheet::new(
+            request.fiscal_year,
+            request.period,
+            WriteDownMethod::parse(&request.method)?,
+            request.currency,
+            items,
+        )?;
+
+        self.worksheet_repository.save(&worksheet).await?;
+
+        Ok(InventoryWorksheetResponse::from_worksheet(&worksheet))
+    }
+
+    /// 会計期間のワークシートを取得
+    pub async fn load(
+        &self,
+        request: LoadInventoryWorksheetRequest,
+    ) -> ApplicationResult<Option<InventoryWorksheetResponse>> {
+        let worksheet = self.worksheet_repository.find(request.fiscal_year, request.period).await?;
+        Ok(worksheet.as_ref().map(InventoryWorksheetResponse::from_worksheet))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, sync::Mutex};
+
+    use javelin_domain::error::DomainResult;
+
+    use super::*;
+    use crate::dtos::request::InventoryValuationItemDto;
+
+    #[derive(Default)]
+    struct MockWorksheetRepository {
+        worksheets: Mutex<HashMap<String, InventoryWriteDownWorksheet>>,
+    }
+
+    impl InventoryWorksheetRepository for MockWorksheetRepository {
+        async fn find(
+            &self,
+            fiscal_year: i32,
+            period: u8,
+        ) -> DomainResult<Option<InventoryWriteDownWorksheet>> {
+            let key = InventoryWriteDownWorksheet::id_for(fiscal_year, period);
+            Ok(self.worksheets.lock().unwrap().get(&key).cloned())
+        }
+
+        async fn save(&self, worksheet: &InventoryWriteDownWorksheet) -> DomainResult<()> {
+            self.worksheets
+                .lock()
+                .unwrap()
+                .insert(worksheet.worksheet_id(), worksheet.clone());
+            Ok(())
+        }
+    }
+
+    fn request(method: &str) -> ImportInventoryWorksheetRequest {
+        let item = |code: &str, unit_cost: f64, unit_nrv: f64| InventoryValuationItemDto {
+            item_code: code.to_string(),
+            item_name: code.to_string(),
+            product_group: "G1".to_string(),
+            quantity: 10.0,
+            unit_cost,
+            unit_net_realizable_value: unit_nrv,
+        };
+        ImportInventoryWorksheetRequest {
+            fiscal_year: 2024,
+            period: 3,
+            method: method.to_string(),
+            currency: "JPY".to_string(),
+            items: vec![item("A", 100.0, 80.0), item("B", 100.0, 130.0)],
+        }
+    }
+
+    #[tokio::test]
+    async fn test_import_calculates_write_down_by_method() {
+        let interactor =
+            InventoryWorksheetInteractor::new(Arc::new(MockWorksheetRepository::default()));
+
+        let by_item = interactor.import(request("ItemByItem")).await.unwrap();
+        assert_eq!(by_item.write_downs.len(), 2);
+        assert_eq!(by_item.total_write_down, 200.0);
+
+        let by_group = interactor.import(request("ProductGroup")).await.unwrap();
+        assert_eq!(by_group.write_downs.len(), 1);
+        assert_eq!(by_group.total_write_down, 0.0);
+
+        let loaded = interactor
+            .load(LoadInventoryWorksheetRequest { fiscal_year: 2024, period: 3 })
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(loaded.method, "ProductGroup");
+    }
+
+    #[tokio::test]
+    async fn test_posted_worksheet_cannot_be_replaced() {
+        let repository = Arc::new(MockWorksheetRepository::default());
+        let interactor = InventoryWorksheetInteractor::new(Arc::clone(&repository));
+        interactor.import(request("ItemByItem")).await.unwrap();
+
+        let mut worksheet = repository.find(2024, 3).await.unwrap().unwrap();
+        worksheet.mark_posted("entry-1").unwrap();
+        repository.save(&worksheet).await.unwrap();
+
+        assert!(interactor.import(request("ProductGroup")).await.is_err());
+    }
+}
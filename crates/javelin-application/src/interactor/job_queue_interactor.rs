@@ -0,0 +1,384 @@
+// JobQueueInteractor - 長時間処理ジョブキューのユースケース
+// 責務: ジョブの登録・一覧・取消・再実行、再起動時の復旧、ワーカーによる逐次実行
+
+use std::{sync::Arc, time::Duration};
+
+use chrono::Utc;
+use javelin_domain::{
+    error::DomainError,
+    job::{Job, JobKind, JobStatus},
+    repositories::JobRepository,
+};
+use tokio::{
+    sync::{Mutex, Notify, mpsc},
+    task::AbortHandle,
+};
+
+use crate::{
+    dtos::{
+        request::{CancelJobRequest, EnqueueJobRequest, RetryJobRequest},
+        response::{JobResponse, RecoverJobsResponse},
+    },
+    error::ApplicationResult,
+    job_runner::{JobProgress, JobRunner},
+};
+
+/// ジョブ一覧の読込に失敗した場合にワーカーが再試行するまでの間隔
+const WORKER_RETRY_INTERVAL: Duration = Duration::from_secs(5);
+
+/// 実行中のジョブ
+struct RunningJob {
+    job_id: String,
+    abort_handle: AbortHandle,
+    /// 取消を要求した利用者
+    cancelled_by: Option<String>,
+}
+
+/// 長時間処理ジョブキューのInteractor
+///
+/// ジョブは登録順に1件ずつ実行する。状態と進捗はリポジトリに保存するため、
+/// アプリケーションが終了してもジョブの記録は失われない。
+/// 実行中のジョブの状態はワーカーだけが更新し、取消は実行タスクの中断で行う。
+pub struct JobQueueInteractor<R, X>
+where
+    R: JobRepository + 'static,
+    X: JobRunner,
+{
+    job_repository: Arc<R>,
+    runner: Arc<X>,
+    /// 待機中のジョブが増えたことをワーカーへ知らせる
+    wake: Notify,
+    /// 実行中のジョブ（待機中ジョブの取り出しと取消を排他する）
+    running: Mutex<Option<RunningJob>>,
+}
+
+impl<R, X> JobQueueInteractor<R, X>
+where
+    R: JobRepository + 'static,
+    X: JobRunner,
+{
+    pub fn new(job_repository: Arc<R>, runner: Arc<X>) -> Self {
+        Self { job_repository, runner, wake: Notify::new(), running: Mutex::new(None) }
+    }
+
+    /// ジョブを登録
+    pub async fn enqueue(&self, request: EnqueueJobRequest) -> ApplicationResult<JobResponse> {
+        let now = Utc::now();
+        let job_id = format!(
+            "JOB-{}-{}",
+            now.format("%Y%m%d%H%M%S"),
+            &uuid::Uuid::new_v4().simple().to_string()[..8]
+        );
+        let job = Job::enqueue(
+            job_id,
+            JobKind::parse(&request.kind)?,
+            request.parameters,
+            request.requested_by,
+            now,
+        )?;
+
+        self.job_repository.save(&job).await?;
+        self.wake.notify_one();
+
+        Ok(JobResponse::from_job(&job))
+    }
+
+    /// ジョブの一覧（登録日時の新しい順）
+    pub async fn list(&self) -> ApplicationResult<Vec<JobResponse>> {
+        let mut jobs = self.job_repository.find_all().await?;
+        jobs.sort_by_key(|job| std::cmp::Reverse(job.created_at()));
+        Ok(jobs.iter().map(JobResponse::from_job).collect())
+    }
+
+    /// ジョブの状態を取得
+    pub async fn find(&self, job_id: &str) -> ApplicationResult<JobResponse> {
+        Ok(JobResponse::from_job(&self.load(job_id).await?))
+    }
+
+    /// ジョブを取消
+    ///
+    /// 待機中のジョブはその場で取消とする。実行中のジョブは実行タスクを中断し、
+    /// ワーカーが取消として記録する（戻り値は中断前の状態）。
+    pub async fn cancel(&self, request: CancelJobRequest) -> ApplicationResult<JobResponse> {
+        let mut running = self.running.lock().await;
+        let mut job = self.load(&request.job_id).await?;
+
+        if job.status() == JobStatus::Running
+            && let Some(current) = running.as_mut()
+            && current.job_id == request.job_id
+        {
+            current.cancelled_by = Some(request.cancelled_by);
+            current.abort_handle.abort();
+            return Ok(JobResponse::from_job(&job));
+        }
+
+        job.cancel(&request.cancelled_by, Utc::now())?;
+        self.job_repository.save(&job).await?;
+        Ok(JobResponse::from_job(&job))
+    }
+
+    /// 失敗・取消したジョブを再実行（待機に戻す）
+    pub async fn retry(&self, request: RetryJobRequest) -> ApplicationResult<JobResponse> {
+        let _running = self.running.lock().await;
+        let mut job = self.load(&request.job_id).await?;
+
+        job.retry(Utc::now())?;
+        self.job_repository.save(&job).await?;
+        self.wake.notify_one();
+
+        Ok(JobResponse::from_job(&job))
+    }
+
+    /// 再起動時の復旧
+    ///
+    /// 前回の終了時に実行中だったジョブを、種別に応じて待機へ戻すか失敗として記録する。
+    /// ワーカーを起動する前に呼び出すこと。
+    pub async fn recover(&self) -> ApplicationResult<RecoverJobsResponse> {
+        let now = Utc::now();
+        let mut response = RecoverJobsResponse::default();
+
+        for mut job in self.job_repository.find_all().await? {
+            let interrupted = job.recover_after_restart(now);
+            if interrupted {
+                self.job_repository.save(&job).await?;
+            }
+            match job.status() {
+                JobStatus::Queued => response.resumed += 1,
+                JobStatus::Failed if interrupted => response.failed += 1,
+                _ => {}
+            }
+        }
+
+        Ok(response)
+    }
+
+    /// 待機中のジョブを登録順に実行し続ける（アプリケーション終了まで戻らない）
+    pub async fn run_worker(self: Arc<Self>) {
+        loop {
+            match self.start_next().await {
+                Ok(Some((job, handle))) => self.wait_for(job, handle).await,
+                Ok(None) => self.wake.notified().await,
+                Err(_) => tokio::time::sleep(WORKER_RETRY_INTERVAL).await,
+            }
+        }
+    }
+
+    /// 最も古い待機中のジョブを実行中にし、実行タスクを起動
+    async fn start_next(&self) -> ApplicationResult<Option<(Job, RunTask)>> {
+        let mut running = self.running.lock().await;
+
+        let Some(mut job) = self
+            .job_repository
+            .find_all()
+            .await?
+            .into_iter()
+            .filter(|job| job.status() == JobStatus::Queued)
+            .min_by_key(|job| job.created_at())
+        else {
+            return Ok(None);
+        };
+
+        job.start(Utc::now())?;
+        self.job_repository.save(&job).await?;
+
+        let (progress_tx, progress_rx) = mpsc::unbounded_channel();
+        let runner = Arc::clone(&self.runner);
+        let (kind, parameters) = (job.kind(), job.parameters().to_vec());
+        let handle = tokio::spawn(async move {
+            runner.run(kind, parameters, JobProgress::new(progress_tx)).await
+        });
+
+        *running = Some(RunningJob {
+            job_id: job.job_id().to_string(),
+            abort_handle: handle.abort_handle(),
+            cancelled_by: None,
+        });
+
+        Ok(Some((job, RunTask { handle, progress_rx })))
+    }
+
+    /// 実行タスクの進捗を保存しながら終了を待ち、結果を記録
+    async fn wait_for(&self, mut job: Job, task: RunTask) {
+        let RunTask { mut handle, mut progress_rx } = task;
+
+        let outcome = loop {
+            tokio::select! {
+                Some((progress, message)) = progress_rx.recv() => {
+                    if job.report_progress(progress, message, Utc::now()).is_ok() {
+                        let _ = self.job_repository.save(&job).await;
+                    }
+                }
+                result = &mut handle => break result,
+            }
+        };
+
+        let cancelled_by = self.running.lock().await.take().and_then(|r| r.cancelled_by);
+        let now = Utc::now();
+        let _ = match outcome {
+            Ok(Ok(message)) => job.complete(message, now),
+            Ok(Err(e)) => job.fail(e.user_message(), now),
+            Err(e) if e.is_cancelled() => {
+                let cancelled_by = cancelled_by.unwrap_or_else(|| job.requested_by().to_string());
+                job.cancel(&cancelled_by, now)
+            }
+            Err(e) => job.fail(format!("ジョブが異常終了しました: {}", e), now),
+        };
+        let _ = self.job_repository.save(&job).await;
+    }
+
+    async fn load(&self, job_id: &str) -> ApplicationResult<Job> {
+        self.job_repository.find_by_id(job_id).await?.ok_or_else(|| {
+            DomainError::NotFound(format!("ジョブが見つかりません: {}", job_id)).into()
+        })
+    }
+}
+
+/// 起動した実行タスク
+struct RunTask {
+    handle: tokio::task::JoinHandle<ApplicationResult<String>>,
+    progress_rx: mpsc::UnboundedReceiver<(u8, String)>,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex as StdMutex;
+
+    use javelin_domain::error::DomainResult;
+
+    use super::*;
+    use crate::error::ApplicationError;
+
+    #[derive(Default)]
+    struct InMemoryJobRepository {
+        jobs: StdMutex<Vec<Job>>,
+    }
+
+    impl JobRepository for InMemoryJobRepository {
+        async fn save(&self, job: &Job) -> DomainResult<()> {
+            let mut jobs = self.jobs.lock().unwrap();
+            jobs.retain(|existing| existing.job_id() != job.job_id());
+            jobs.push(job.clone());
+            Ok(())
+        }
+
+        async fn find_by_id(&self, job_id: &str) -> DomainResult<Option<Job>> {
+            Ok(self.jobs.lock().unwrap().iter().find(|job| job.job_id() == job_id).cloned())
+        }
+
+        async fn find_all(&self) -> DomainResult<Vec<Job>> {
+            Ok(self.jobs.lock().unwrap().clone())
+        }
+    }
+
+    /// 元帳集約は成功、取込は失敗、Projection再構築は終わらない検証用の実行
+    struct StubRunner;
+
+    impl JobRunner for StubRunner {
+        async fn run(
+            &self,
+            kind: JobKind,
+            _parameters: Vec<(String, String)>,
+            progress: JobProgress,
+        ) -> ApplicationResult<String> {
+            match kind {
+                JobKind::LedgerConsolidation => {
+                    progress.report(50, "集約中");
+                    Ok("集約しました".to_string())
+                }
+                JobKind::InventoryImport => {
+                    Err(ApplicationError::ValidationError("取込ファイルがありません".to_string()))
+                }
+                JobKind::ProjectionRebuild
+                | JobKind::ProjectionCompaction
+                | JobKind::IntegrityAudit => {
+                    std::future::pending::<()>().await;
+                    unreachable!()
+                }
+            }
+        }
+    }
+
+    fn interactor() -> Arc<JobQueueInteractor<InMemoryJobRepository, StubRunner>> {
+        Arc::new(JobQueueInteractor::new(
+            Arc::new(InMemoryJobRepository::default()),
+            Arc::new(StubRunner),
+        ))
+    }
+
+    fn request(kind: JobKind) -> EnqueueJobRequest {
+        EnqueueJobRequest {
+            kind: kind.as_str().to_string(),
+            parameters: vec![],
+            requested_by: "accountant".to_string(),
+        }
+    }
+
+    async fn run_next(interactor: &JobQueueInteractor<InMemoryJobRepository, StubRunner>) {
+        let (job, task) = interactor.start_next().await.unwrap().unwrap();
+        interactor.wait_for(job, task).await;
+    }
+
+    #[tokio::test]
+    async fn test_worker_completes_and_records_failure() {
+        let interactor = interactor();
+        let consolidation =
+            interactor.enqueue(request(JobKind::LedgerConsolidation)).await.unwrap();
+        let import = interactor.enqueue(request(JobKind::InventoryImport)).await.unwrap();
+
+        run_next(&interactor).await;
+        run_next(&interactor).await;
+
+        let completed = interactor.find(&consolidation.job_id).await.unwrap();
+        assert!(completed.is_completed());
+        assert_eq!(completed.progress, 100);
+        let failed = interactor.find(&import.job_id).await.unwrap();
+        assert_eq!(failed.status, "Failed");
+        assert!(failed.message.unwrap().contains("取込ファイルがありません"));
+        assert!(interactor.start_next().await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_cancel_running_job_and_retry() {
+        let interactor = interactor();
+        let rebuild = interactor.enqueue(request(JobKind::ProjectionRebuild)).await.unwrap();
+        let (job, task) = interactor.start_next().await.unwrap().unwrap();
+
+        interactor
+            .cancel(CancelJobRequest {
+                job_id: rebuild.job_id.clone(),
+                cancelled_by: "admin".to_string(),
+            })
+            .await
+            .unwrap();
+        interactor.wait_for(job, task).await;
+
+        let cancelled = interactor.find(&rebuild.job_id).await.unwrap();
+        assert_eq!(cancelled.status, "Cancelled");
+        assert!(cancelled.message.unwrap().contains("admin"));
+
+        let retried = interactor
+            .retry(RetryJobRequest { job_id: rebuild.job_id.clone() })
+            .await
+            .unwrap();
+        assert_eq!(retried.status, "Queued");
+    }
+
+    #[tokio::test]
+    async fn test_recover_marks_interrupted_jobs() {
+        let repository = Arc::new(InMemoryJobRepository::default());
+        for (job_id, kind) in
+            [("JOB-1", JobKind::LedgerConsolidation), ("JOB-2", JobKind::InventoryImport)]
+        {
+            let mut job = Job::enqueue(job_id, kind, vec![], "accountant", Utc::now()).unwrap();
+            job.start(Utc::now()).unwrap();
+            repository.save(&job).await.unwrap();
+        }
+        let interactor = JobQueueInteractor::new(Arc::clone(&repository), Arc::new(StubRunner));
+
+        let response = interactor.recover().await.unwrap();
+
+        assert_eq!(response, RecoverJobsResponse { resumed: 1, failed: 1 });
+        assert_eq!(interactor.find("JOB-1").await.unwrap().status, "Queued");
+        assert_eq!(interactor.find("JOB-2").await.unwrap().status, "Failed");
+    }
+}
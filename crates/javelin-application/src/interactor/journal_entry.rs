@@ -8,6 +8,8 @@ mod create_reclassification_entry_interactor;
 mod create_replacement_entry_interactor;
 mod create_reversal_entry_interactor;
 mod delete_draft_journal_entry_interactor;
+mod journal_entry_chain_validator;
+mod posting_period_guard;
 mod register_journal_entry_interactor;
 mod reject_journal_entry_interactor;
 mod reverse_journal_entry_interactor;
@@ -23,6 +25,7 @@ pub use create_reclassification_entry_interactor::CreateReclassificationEntryInt
 pub use create_replacement_entry_interactor::CreateReplacementEntryInteractor;
 pub use create_reversal_entry_interactor::CreateReversalEntryInteractor;
 pub use delete_draft_journal_entry_interactor::DeleteDraftJournalEntryInteractor;
+pub use journal_entry_chain_validator::JournalEntryChainValidator;
 pub use register_journal_entry_interactor::RegisterJournalEntryInteractor;
 pub use reject_journal_entry_interactor::RejectJournalEntryInteractor;
 pub use reverse_journal_entry_interactor::ReverseJournalEntryInteractor;
@@ -3,40 +3,106 @@
 use std::sync::Arc;
 
 use javelin_domain::{
+    error::DomainError,
     financial_close::journal_entry::{
         events::JournalEntryEvent,
-        values::{EntryNumber, UserId},
+        services::{EntryNumberExistenceChecker, EntryNumberGenerator, JournalEntryService},
+        values::{EntryNumberScope, UserId},
     },
-    repositories::EventRepository,
+    repositories::{ApplicationSettingsRepository, EventRepository},
 };
 
+use super::posting_period_guard::ensure_period_open;
 use crate::{
+    business_metrics::{BusinessMetric, BusinessMetrics, approval_wait, record_metric},
     dtos::{ApproveJournalEntryRequest, ApproveJournalEntryResponse},
     error::{ApplicationError, ApplicationResult},
     input_ports::ApproveJournalEntryUseCase,
     output_port::{EventNotification, EventOutputPort, JournalEntryOutputPort},
+    parameter_validation::FiscalCalendar,
 };
 
+/// 仕訳承認のInteractor
+///
+/// 承認時に伝票番号を採番する。採番範囲はアプリケーション設定の既定の会社と、
+/// 会計年度の開始月から求めた取引日付の会計年度とする。
 pub struct ApproveJournalEntryInteractor<
     R: EventRepository,
     E: EventOutputPort,
     O: JournalEntryOutputPort,
+    N: EntryNumberGenerator + EntryNumberExistenceChecker,
+    G: ApplicationSettingsRepository,
 > {
     event_repository: Arc<R>,
     event_output: Arc<E>,
     output_port: Arc<O>,
+    entry_number_generator: Arc<N>,
+    settings_repository: Arc<G>,
+    /// 業務指標の記録先（未設定の場合は記録しない）
+    metrics: Option<Arc<dyn BusinessMetrics>>,
 }
 
-impl<R: EventRepository, E: EventOutputPort, O: JournalEntryOutputPort>
-    ApproveJournalEntryInteractor<R, E, O>
+impl<
+    R: EventRepository,
+    E: EventOutputPort,
+    O: JournalEntryOutputPort,
+    N: EntryNumberGenerator + EntryNumberExistenceChecker,
+    G: ApplicationSettingsRepository,
+> ApproveJournalEntryInteractor<R, E, O, N, G>
 {
-    pub fn new(event_repository: Arc<R>, event_output: Arc<E>, output_port: Arc<O>) -> Self {
-        Self { event_repository, event_output, output_port }
+    pub fn new(
+        event_repository: Arc<R>,
+        event_output: Arc<E>,
+        output_port: Arc<O>,
+        entry_number_generator: Arc<N>,
+        settings_repository: Arc<G>,
+    ) -> Self {
+        Self {
+            event_repository,
+            event_output,
+            output_port,
+            entry_number_generator,
+            settings_repository,
+            metrics: None,
+        }
+    }
+
+    /// 業務指標の記録先を設定
+    pub fn with_metrics(mut self, metrics: Arc<dyn BusinessMetrics>) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// 取引日付の伝票番号の採番範囲（既定の会社・会計年度）
+    async fn entry_number_scope(
+        &self,
+        transaction_date: chrono::NaiveDate,
+    ) -> ApplicationResult<EntryNumberScope> {
+        let settings = self.settings_repository.find().await?.ok_or_else(|| {
+            ApplicationError::QueryExecutionFailed(
+                "アプリケーション設定が見つかりません".to_string(),
+            )
+        })?;
+        let company_code = settings.default_company_code().ok_or_else(|| {
+            ApplicationError::ValidationError(
+                "伝票番号を採番する会社が設定されていません（アプリケーション設定で既定の会社を指定してください）"
+                    .to_string(),
+            )
+        })?;
+        let calendar =
+            FiscalCalendar::new(settings.fiscal_year_start_month().value(), transaction_date);
+        let fiscal_year = calendar.current_fiscal_year() as u32;
+        Ok(EntryNumberScope::new(company_code.value(), fiscal_year)?)
     }
 }
 
-impl<R: EventRepository, E: EventOutputPort, O: JournalEntryOutputPort> ApproveJournalEntryUseCase
-    for ApproveJournalEntryInteractor<R, E, O>
+impl<
+    R: EventRepository,
+    E: EventOutputPort,
+    O: JournalEntryOutputPort,
+    N: EntryNumberGenerator + EntryNumberExistenceChecker,
+    G: ApplicationSettingsRepository,
+> ApproveJournalEntryUseCase for ApproveJournalEntryInteractor<R, E, O, N, G>
 {
     async fn execute(&self, request: ApproveJournalEntryRequest) -> ApplicationResult<()> {
         self.event_output
@@ -151,27 +217,42 @@ impl<R: EventRepository, E: EventOutputPort, O: JournalEntryOutputPort> ApproveJ
             }
         }
 
-        // 5. 伝票番号を生成
-        let entry_number =
-            EntryNumber::new(format!("EN-{}", chrono::Utc::now().format("%Y%m%d-%H%M%S")))
-                .map_err(ApplicationError::DomainError)?;
-
-        // 6. 承認処理を実行
-        let user_id = UserId::new(request.approver_id.clone());
-        journal_entry
-            .approve(entry_number.clone(), user_id)
-            .map_err(ApplicationError::DomainError)?;
-
-        // 7. 新しいイベントを取得
-        let new_events = journal_entry.drain_events();
+        // 締め済・ロック済の期間の仕訳は承認しない（採番より前に確認する）
+        ensure_period_open(
+            self.event_repository.as_ref(),
+            journal_entry.transaction_date().value(),
+        )
+        .await?;
 
-        // 8. イベントストアへの保存
-        self.event_repository
-            .append_events(&request.entry_id, new_events)
+        // 5. 会社・会計年度の範囲で伝票番号を採番
+        let scope = self.entry_number_scope(journal_entry.transaction_date().value()).await?;
+        let entry_number = self
+            .entry_number_generator
+            .next(&scope)
             .await
             .map_err(ApplicationError::DomainError)?;
 
-        // 9. レスポンスを作成
+        // 6. 記帳済みの番号と重複しないことを確認して承認し、イベントストアへ保存
+        let posted = async {
+            JournalEntryService::validate_entry_number_uniqueness(
+                &entry_number,
+                self.entry_number_generator.as_ref(),
+            )
+            .await?;
+            journal_entry
+                .approve(entry_number.clone(), UserId::new(request.approver_id.clone()))?;
+            let new_events = journal_entry.drain_events();
+            self.event_repository.append_events(&request.entry_id, new_events).await?;
+            Ok::<_, DomainError>(())
+        }
+        .await;
+        if let Err(e) = posted {
+            // 記帳できなかった番号は戻し、次の承認で払い出す（欠番にしない）
+            let _ = self.entry_number_generator.release(&entry_number).await;
+            return Err(ApplicationError::DomainError(e));
+        }
+
+        // 7. レスポンスを作成
         let response = ApproveJournalEntryResponse {
             entry_id: request.entry_id,
             entry_number: entry_number.value().to_string(),
@@ -188,6 +269,9 @@ impl<R: EventRepository, E: EventOutputPort, O: JournalEntryOutputPort> ApproveJ
             ))
             .await;
 
+        let wait = approval_wait(&journal_events, chrono::Utc::now());
+        record_metric(self.metrics.as_ref(), BusinessMetric::EntryApproved, wait).await;
+
         Ok(())
     }
 }
@@ -3,23 +3,25 @@
 
 use std::sync::Arc;
 
-use chrono::NaiveDate;
 use javelin_domain::{
     entity::EntityId,
     financial_close::journal_entry::{
         entities::{JournalEntry, JournalEntryId},
         services::JournalEntryService,
-        values::{TransactionDate, UserId, VoucherNumber},
+        values::{UserId, VoucherNumber},
     },
+    id_generator::AggregateIdGenerator,
     repositories::EventRepository,
 };
 
 use crate::{
     dtos::{CancelJournalEntryRequest, RegisterJournalEntryResponse},
     error::{ApplicationError, ApplicationResult},
+    id_generator::TimeOrderedIdGenerator,
     input_ports::CancelJournalEntryUseCase,
+    interactor::JournalEntryChainValidator,
     output_port::{EventNotification, EventOutputPort, JournalEntryOutputPort},
-    query_service::JournalEntryFinderService,
+    query_service::{JournalEntryChainQueryService, JournalEntryFinderService},
 };
 
 pub struct CancelJournalEntryInteractor<
@@ -27,11 +29,13 @@ pub struct CancelJournalEntryInteractor<
     E: EventOutputPort,
     O: JournalEntryOutputPort,
     F: JournalEntryFinderService,
+    Q: JournalEntryChainQueryService,
 > {
     event_repository: Arc<R>,
     event_output: Arc<E>,
     output_port: Arc<O>,
     finder_service: Arc<F>,
+    chain_validator: JournalEntryChainValidator<Q>,
 }
 
 impl<
@@ -39,15 +43,23 @@ impl<
     E: EventOutputPort,
     O: JournalEntryOutputPort,
     F: JournalEntryFinderService,
-> CancelJournalEntryInteractor<R, E, O, F>
+    Q: JournalEntryChainQueryService,
+> CancelJournalEntryInteractor<R, E, O, F, Q>
 {
     pub fn new(
         event_repository: Arc<R>,
         event_output: Arc<E>,
         output_port: Arc<O>,
         finder_service: Arc<F>,
+        chain_query_service: Arc<Q>,
     ) -> Self {
-        Self { event_repository, event_output, output_port, finder_service }
+        Self {
+            event_repository,
+            event_output,
+            output_port,
+            finder_service,
+            chain_validator: JournalEntryChainValidator::new(chain_query_service),
+        }
     }
 }
 
@@ -56,7 +68,8 @@ impl<
     E: EventOutputPort,
     O: JournalEntryOutputPort,
     F: JournalEntryFinderService,
-> CancelJournalEntryUseCase for CancelJournalEntryInteractor<R, E, O, F>
+    Q: JournalEntryChainQueryService,
+> CancelJournalEntryUseCase for CancelJournalEntryInteractor<R, E, O, F, Q>
 {
     async fn execute(&self, request: CancelJournalEntryRequest) -> ApplicationResult<()> {
         // イベント通知: 処理開始
@@ -80,6 +93,9 @@ impl<
                 )])
             })?;
 
+        // 取消済の伝票・取消仕訳そのものは参照元にできない
+        self.chain_validator.validate_reversal(&reference_entry.entry_id).await?;
+
         // 2. 参照元伝票のイベントストリームから明細を取得
         let reference_events = self
             .event_repository
@@ -112,16 +128,8 @@ impl<
                 ])
             })?;
 
-        // 4. 取引日付のパース
-        let transaction_date = NaiveDate::parse_from_str(&request.transaction_date, "%Y-%m-%d")
-            .map_err(|_| {
-                ApplicationError::ValidationFailed(vec![format!(
-                    "Invalid date format: {}",
-                    request.transaction_date
-                )])
-            })?;
-        let transaction_date =
-            TransactionDate::new(transaction_date).map_err(ApplicationError::DomainError)?;
+        // 4. 取引日付（境界で解析済み）
+        let transaction_date = request.transaction_date;
 
         // 5. 証憑番号の作成
         let voucher_number = VoucherNumber::new(request.voucher_number.clone())
@@ -148,7 +156,7 @@ impl<
         JournalEntryService::validate_balance(&lines).map_err(ApplicationError::DomainError)?;
 
         // 9. 仕訳IDの生成
-        let entry_id = JournalEntryId::new(uuid::Uuid::new_v4().to_string());
+        let entry_id = JournalEntryId::new(TimeOrderedIdGenerator.next_id());
 
         // 10. 仕訳エンティティの作成（Draft状態）
         let journal_entry =
@@ -12,29 +12,51 @@ use crate::{
     dtos::{CorrectJournalEntryRequest, CorrectJournalEntryResponse},
     error::{ApplicationError, ApplicationResult},
     input_ports::CorrectJournalEntryUseCase,
+    interactor::JournalEntryChainValidator,
     output_port::{EventNotification, EventOutputPort, JournalEntryOutputPort},
+    query_service::JournalEntryChainQueryService,
 };
 
 pub struct CorrectJournalEntryInteractor<
     R: EventRepository,
     E: EventOutputPort,
     O: JournalEntryOutputPort,
+    Q: JournalEntryChainQueryService,
 > {
     event_repository: Arc<R>,
     event_output: Arc<E>,
     output_port: Arc<O>,
+    chain_validator: JournalEntryChainValidator<Q>,
 }
 
-impl<R: EventRepository, E: EventOutputPort, O: JournalEntryOutputPort>
-    CorrectJournalEntryInteractor<R, E, O>
+impl<
+    R: EventRepository,
+    E: EventOutputPort,
+    O: JournalEntryOutputPort,
+    Q: JournalEntryChainQueryService,
+> CorrectJournalEntryInteractor<R, E, O, Q>
 {
-    pub fn new(event_repository: Arc<R>, event_output: Arc<E>, output_port: Arc<O>) -> Self {
-        Self { event_repository, event_output, output_port }
+    pub fn new(
+        event_repository: Arc<R>,
+        event_output: Arc<E>,
+        output_port: Arc<O>,
+        chain_query_service: Arc<Q>,
+    ) -> Self {
+        Self {
+            event_repository,
+            event_output,
+            output_port,
+            chain_validator: JournalEntryChainValidator::new(chain_query_service),
+        }
     }
 }
 
-impl<R: EventRepository, E: EventOutputPort, O: JournalEntryOutputPort> CorrectJournalEntryUseCase
-    for CorrectJournalEntryInteractor<R, E, O>
+impl<
+    R: EventRepository,
+    E: EventOutputPort,
+    O: JournalEntryOutputPort,
+    Q: JournalEntryChainQueryService,
+> CorrectJournalEntryUseCase for CorrectJournalEntryInteractor<R, E, O, Q>
 {
     async fn execute(&self, request: CorrectJournalEntryRequest) -> ApplicationResult<()> {
         self.event_output
@@ -45,6 +67,9 @@ impl<R: EventRepository, E: EventOutputPort, O: JournalEntryOutputPort> CorrectJ
             ))
             .await;
 
+        // 取消されていない仕訳の修正・二重修正を防ぐ
+        self.chain_validator.validate_correction(&request.reversed_entry_id).await?;
+
         // 修正イベントを生成
         let user_id = UserId::new(request.user_id.clone());
         let correction_entry_id = format!("COR-{}", request.reversed_entry_id);
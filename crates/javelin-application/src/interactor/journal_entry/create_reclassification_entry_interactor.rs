@@ -3,20 +3,23 @@
 
 use std::sync::Arc;
 
-use chrono::NaiveDate;
 use javelin_domain::{
     entity::EntityId,
     financial_close::journal_entry::{
         entities::{JournalEntry, JournalEntryId},
         services::JournalEntryService,
-        values::{TransactionDate, UserId, VoucherNumber},
     },
+    id_generator::AggregateIdGenerator,
     repositories::EventRepository,
 };
 
 use crate::{
-    dtos::{CreateReclassificationEntryRequest, RegisterJournalEntryResponse},
+    dtos::{
+        CreateReclassificationEntryRequest, RegisterJournalEntryResponse,
+        request::ValidatedReferencedEntryRequest,
+    },
     error::{ApplicationError, ApplicationResult},
+    id_generator::TimeOrderedIdGenerator,
     input_ports::CreateReclassificationEntryUseCase,
     output_port::{EventNotification, EventOutputPort, JournalEntryOutputPort},
     query_service::JournalEntryFinderService,
@@ -67,6 +70,8 @@ impl<
             ))
             .await;
 
+        let request = ValidatedReferencedEntryRequest::try_from(&request)?;
+
         let reference_entry = self
             .finder_service
             .find_by_entry_number(&request.reference_entry_id)
@@ -78,29 +83,23 @@ impl<
                 )])
             })?;
 
-        let transaction_date = NaiveDate::parse_from_str(&request.transaction_date, "%Y-%m-%d")
-            .map_err(|_| {
-                ApplicationError::ValidationFailed(vec![format!(
-                    "Invalid date format: {}",
-                    request.transaction_date
-                )])
-            })?;
-        let transaction_date =
-            TransactionDate::new(transaction_date).map_err(ApplicationError::DomainError)?;
-
-        let voucher_number = VoucherNumber::new(request.voucher_number.clone())
-            .map_err(ApplicationError::DomainError)?;
-        let user_id = UserId::new(request.user_id.clone());
+        let transaction_date = request.transaction_date;
 
-        let lines: Result<Vec<_>, _> = request.lines.iter().map(|dto| dto.try_into()).collect();
+        let lines: Result<Vec<_>, _> =
+            request.lines.into_iter().map(|line| line.into_line()).collect();
         let lines = lines?;
 
         JournalEntryService::validate_balance(&lines).map_err(ApplicationError::DomainError)?;
 
-        let entry_id = JournalEntryId::new(uuid::Uuid::new_v4().to_string());
-        let journal_entry =
-            JournalEntry::new(entry_id.clone(), transaction_date, voucher_number, lines, user_id)
-                .map_err(ApplicationError::DomainError)?;
+        let entry_id = JournalEntryId::new(TimeOrderedIdGenerator.next_id());
+        let journal_entry = JournalEntry::new(
+            entry_id.clone(),
+            transaction_date,
+            request.voucher_number,
+            lines,
+            request.user_id,
+        )
+        .map_err(ApplicationError::DomainError)?;
 
         let events = journal_entry.events();
         self.event_repository
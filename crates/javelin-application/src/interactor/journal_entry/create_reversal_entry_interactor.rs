@@ -3,20 +3,21 @@
 
 use std::sync::Arc;
 
-use chrono::NaiveDate;
 use javelin_domain::{
     entity::EntityId,
     financial_close::journal_entry::{
         entities::{JournalEntry, JournalEntryId},
         services::JournalEntryService,
-        values::{TransactionDate, UserId, VoucherNumber},
+        values::{UserId, VoucherNumber},
     },
+    id_generator::AggregateIdGenerator,
     repositories::EventRepository,
 };
 
 use crate::{
     dtos::{CreateReversalEntryRequest, RegisterJournalEntryResponse},
     error::{ApplicationError, ApplicationResult},
+    id_generator::TimeOrderedIdGenerator,
     input_ports::CreateReversalEntryUseCase,
     output_port::{EventNotification, EventOutputPort, JournalEntryOutputPort},
     query_service::JournalEntryFinderService,
@@ -111,16 +112,8 @@ impl<
                 ])
             })?;
 
-        // 4. 取引日付のパース
-        let transaction_date = NaiveDate::parse_from_str(&request.transaction_date, "%Y-%m-%d")
-            .map_err(|_| {
-                ApplicationError::ValidationFailed(vec![format!(
-                    "Invalid date format: {}",
-                    request.transaction_date
-                )])
-            })?;
-        let transaction_date =
-            TransactionDate::new(transaction_date).map_err(ApplicationError::DomainError)?;
+        // 4. 取引日付（境界で解析済み）
+        let transaction_date = request.transaction_date;
 
         // 5. 証憑番号の作成
         let voucher_number = VoucherNumber::new(request.voucher_number.clone())
@@ -147,7 +140,7 @@ impl<
         JournalEntryService::validate_balance(&lines).map_err(ApplicationError::DomainError)?;
 
         // 9. 仕訳IDの生成
-        let entry_id = JournalEntryId::new(uuid::Uuid::new_v4().to_string());
+        let entry_id = JournalEntryId::new(TimeOrderedIdGenerator.next_id());
 
         // 10. 仕訳エンティティの作成（Draft状態）
         let journal_entry =
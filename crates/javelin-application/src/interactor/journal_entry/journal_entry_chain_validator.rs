@@ -0,0 +1,131 @@
+// JournalEntryChainValidator - 取消・修正チェーンの整合性検証
+// 責務: 単一の集約の状態では判定できない、仕訳間の取消・修正の重複と欠落の検出
+
+use std::sync::Arc;
+
+use crate::{
+    error::{ApplicationError, ApplicationResult},
+    query_service::JournalEntryChainQueryService,
+};
+
+/// 取消・修正チェーンの検証
+///
+/// 取消・取消仕訳登録・修正の各Interactorがイベントを保存する前に呼び出す。
+/// リンクはProjectionから参照するため、反映前の直前の操作は検出できない。
+pub struct JournalEntryChainValidator<Q: JournalEntryChainQueryService> {
+    query_service: Arc<Q>,
+}
+
+impl<Q: JournalEntryChainQueryService> JournalEntryChainValidator<Q> {
+    pub fn new(query_service: Arc<Q>) -> Self {
+        Self { query_service }
+    }
+
+    /// 仕訳を取り消せるか検証
+    ///
+    /// 取消済の仕訳と、取消仕訳そのものは取り消せない。
+    pub async fn validate_reversal(&self, entry_id: &str) -> ApplicationResult<()> {
+        let links = self.query_service.find_links(entry_id).await?;
+        if let Some(reversal_id) = links.reversal_id {
+            return Err(chain_error(format!(
+                "仕訳 {} は取消済です（取消仕訳: {}）",
+                entry_id, reversal_id
+            )));
+        }
+        if let Some(original_id) = links.original_id {
+            return Err(chain_error(format!(
+                "仕訳 {} は仕訳 {} の取消仕訳のため取り消せません",
+                entry_id, original_id
+            )));
+        }
+        Ok(())
+    }
+
+    /// 取消仕訳に対して修正できるか検証
+    ///
+    /// 修正は取消仕訳に対して1回だけ行える。
+    pub async fn validate_correction(&self, reversed_id: &str) -> ApplicationResult<()> {
+        let links = self.query_service.find_links(reversed_id).await?;
+        if links.original_id.is_none() {
+            return Err(chain_error(format!(
+                "仕訳 {} は取消仕訳ではないため修正できません（先に取消を行ってください）",
+                reversed_id
+            )));
+        }
+        if let Some(correction_id) = links.correction_id {
+            return Err(chain_error(format!(
+                "取消仕訳 {} は修正済です（修正仕訳: {}）",
+                reversed_id, correction_id
+            )));
+        }
+        Ok(())
+    }
+}
+
+fn chain_error(message: String) -> ApplicationError {
+    ApplicationError::ValidationFailed(vec![message])
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::query_service::JournalEntryChainLinks;
+
+    struct StubChainQueryService(HashMap<String, JournalEntryChainLinks>);
+
+    impl JournalEntryChainQueryService for StubChainQueryService {
+        async fn find_links(&self, entry_id: &str) -> ApplicationResult<JournalEntryChainLinks> {
+            Ok(self.0.get(entry_id).cloned().unwrap_or_default())
+        }
+    }
+
+    fn validator() -> JournalEntryChainValidator<StubChainQueryService> {
+        let links = HashMap::from([
+            (
+                "JE001".to_string(),
+                JournalEntryChainLinks {
+                    reversal_id: Some("REV-JE001".to_string()),
+                    ..Default::default()
+                },
+            ),
+            (
+                "REV-JE001".to_string(),
+                JournalEntryChainLinks {
+                    original_id: Some("JE001".to_string()),
+                    correction_id: Some("COR-REV-JE001".to_string()),
+                    ..Default::default()
+                },
+            ),
+            (
+                "REV-JE002".to_string(),
+                JournalEntryChainLinks {
+                    original_id: Some("JE002".to_string()),
+                    ..Default::default()
+                },
+            ),
+        ]);
+        JournalEntryChainValidator::new(Arc::new(StubChainQueryService(links)))
+    }
+
+    #[tokio::test]
+    async fn test_duplicate_reversal_is_rejected() {
+        let validator = validator();
+
+        assert!(validator.validate_reversal("JE003").await.is_ok());
+        let error = validator.validate_reversal("JE001").await.unwrap_err();
+        assert!(error.to_string().contains("REV-JE001"));
+        assert!(validator.validate_reversal("REV-JE002").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_dangling_and_duplicate_corrections_are_rejected() {
+        let validator = validator();
+
+        assert!(validator.validate_correction("REV-JE002").await.is_ok());
+        assert!(validator.validate_correction("JE003").await.is_err());
+        let error = validator.validate_correction("REV-JE001").await.unwrap_err();
+        assert!(error.to_string().contains("COR-REV-JE001"));
+    }
+}
@@ -0,0 +1,90 @@
+// PostingPeriodGuard - 記帳対象期間の状態確認
+// 責務: 締め済・ロック済の会計期間への仕訳の登録・承認を拒否
+
+use chrono::NaiveDate;
+use javelin_domain::{
+    entity::EntityId,
+    financial_close::accounting_period::{AccountingPeriodEvent, PeriodHistory, PeriodId},
+    repositories::EventRepository,
+};
+
+use crate::error::{ApplicationError, ApplicationResult};
+
+/// 取引日付を含む会計期間が記帳可能か確認
+///
+/// 期間のイベントを再生して状態を求める。締め済・ロック済の期間は、
+/// 承認付きの再オープンでオープンに戻すまで仕訳を登録・承認できない。
+pub(crate) async fn ensure_period_open<R: EventRepository>(
+    event_repository: &R,
+    transaction_date: NaiveDate,
+) -> ApplicationResult<()> {
+    let period_id = PeriodId::containing(transaction_date);
+    let events = event_repository
+        .get_events(period_id.value())
+        .await?
+        .into_iter()
+        .map(serde_json::from_value::<AccountingPeriodEvent>)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| {
+            ApplicationError::EventStoreError(format!(
+                "会計期間{}のイベントを読み込めません: {}",
+                period_id.value(),
+                e
+            ))
+        })?;
+
+    let history = PeriodHistory::replay(&events);
+    if !history.status().can_post_journal() {
+        return Err(ApplicationError::ValidationError(format!(
+            "会計期間{}は{}のため記帳できません（再オープンの承認を得てください）",
+            period_id.value(),
+            history.status().display_name()
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testkit::InMemoryEventRepository;
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 3, day).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_locked_period_rejects_until_reopened() {
+        let repository = InMemoryEventRepository::default();
+        assert!(ensure_period_open(&repository, date(31)).await.is_ok());
+
+        repository
+            .append_events(
+                "2024-03",
+                vec![AccountingPeriodEvent::PeriodLocked {
+                    period_id: "2024-03".to_string(),
+                    locked_by: "tanaka".to_string(),
+                    locked_at: "2024-04-05T00:00:00+00:00".to_string(),
+                }],
+            )
+            .await
+            .unwrap();
+        let result = ensure_period_open(&repository, date(31)).await;
+        assert!(matches!(result, Err(ApplicationError::ValidationError(_))));
+
+        repository
+            .append_events(
+                "2024-03",
+                vec![AccountingPeriodEvent::PeriodReopened {
+                    period_id: "2024-03".to_string(),
+                    reason: "売上計上漏れの追加".to_string(),
+                    reopened_by: "tanaka".to_string(),
+                    approved_by: "suzuki".to_string(),
+                    reopened_at: "2024-04-10T00:00:00+00:00".to_string(),
+                }],
+            )
+            .await
+            .unwrap();
+        assert!(ensure_period_open(&repository, date(1)).await.is_ok());
+    }
+}
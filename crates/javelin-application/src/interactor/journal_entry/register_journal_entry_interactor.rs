@@ -1,22 +1,30 @@
 // RegisterJournalEntryInteractor - 仕訳登録ユースケース実装
 // 責務: 仕訳登録のビジネスロジック実行
 
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc, time::Instant};
 
-use chrono::{Datelike, NaiveDate};
+use chrono::Datelike;
 use javelin_domain::{
-    entity::EntityId,
+    entity::{Entity, EntityId},
     financial_close::journal_entry::{
-        entities::{JournalEntry, JournalEntryId},
+        entities::{JournalEntry, JournalEntryId, JournalEntryLine},
         services::{JournalEntryService, VoucherNumberGenerator},
-        values::{TransactionDate, UserId, VoucherNumber},
+        values::{TransactionDate, VoucherNumber},
     },
+    id_generator::AggregateIdGenerator,
+    masters::{AccountingPolicy, DimensionMaster},
     repositories::EventRepository,
 };
 
+use super::posting_period_guard::ensure_period_open;
 use crate::{
-    dtos::{RegisterJournalEntryRequest, RegisterJournalEntryResponse},
+    business_metrics::{BusinessMetric, BusinessMetrics, record_metric},
+    dtos::{
+        RegisterJournalEntryRequest, RegisterJournalEntryResponse,
+        request::ValidatedRegisterJournalEntryRequest,
+    },
     error::{ApplicationError, ApplicationResult},
+    id_generator::TimeOrderedIdGenerator,
     input_ports::RegisterJournalEntryUseCase,
     output_port::{EventNotification, EventOutputPort, JournalEntryOutputPort},
 };
@@ -31,6 +39,16 @@ pub struct RegisterJournalEntryInteractor<
     event_output: Arc<E>,
     output_port: Arc<O>,
     voucher_generator: Arc<V>,
+    /// 仕訳IDの採番（伝票番号とは別に、生成順に並ぶIDを付与する）
+    id_generator: Arc<dyn AggregateIdGenerator>,
+    /// 金額・税額の端数処理に用いる会計方針
+    accounting_policy: AccountingPolicy,
+    /// 業務指標の記録先（未設定の場合は記録しない）
+    metrics: Option<Arc<dyn BusinessMetrics>>,
+    /// 明細の分析軸を検証する分析軸マスタ（未設定の場合は検証しない）
+    dimension_masters: Option<Vec<DimensionMaster>>,
+    /// イベントに埋め込む勘定科目名（勘定科目コード → 名称）
+    account_names: HashMap<String, String>,
 }
 
 impl<R: EventRepository, E: EventOutputPort, O: JournalEntryOutputPort, V: VoucherNumberGenerator>
@@ -42,82 +60,64 @@ impl<R: EventRepository, E: EventOutputPort, O: JournalEntryOutputPort, V: Vouch
         output_port: Arc<O>,
         voucher_generator: Arc<V>,
     ) -> Self {
-        Self { event_repository, event_output, output_port, voucher_generator }
+        Self {
+            event_repository,
+            event_output,
+            output_port,
+            voucher_generator,
+            id_generator: Arc::new(TimeOrderedIdGenerator),
+            accounting_policy: AccountingPolicy::default(),
+            metrics: None,
+            dimension_masters: None,
+            account_names: HashMap::new(),
+        }
     }
-}
-
-impl<R: EventRepository, E: EventOutputPort, O: JournalEntryOutputPort, V: VoucherNumberGenerator>
-    RegisterJournalEntryUseCase for RegisterJournalEntryInteractor<R, E, O, V>
-{
-    async fn execute(&self, request: RegisterJournalEntryRequest) -> ApplicationResult<()> {
-        // イベント通知: 処理開始
-        self.event_output
-            .notify_event(EventNotification::success(
-                "system",
-                "RegisterJournalEntry",
-                "仕訳登録を開始".to_string(),
-            ))
-            .await;
 
-        // 1. 入力バリデーション - 取引日付のパース
-        let transaction_date =
-            match NaiveDate::parse_from_str(&request.transaction_date, "%Y-%m-%d") {
-                Ok(date) => date,
-                Err(e) => {
-                    let error_msg =
-                        format!("日付形式が不正です: {} (エラー: {})", request.transaction_date, e);
-                    self.output_port.notify_error(error_msg.clone()).await;
-                    return Err(ApplicationError::ValidationFailed(vec![error_msg]));
-                }
-            };
-
-        let transaction_date = match TransactionDate::new(transaction_date) {
-            Ok(date) => date,
-            Err(e) => {
-                let error_msg = format!("取引日付が無効です: {}", e);
-                self.output_port.notify_error(error_msg.clone()).await;
-                return Err(ApplicationError::DomainError(e));
-            }
-        };
+    /// 端数処理に用いる会計方針を設定（未設定の場合は既定の方針）
+    pub fn with_accounting_policy(mut self, accounting_policy: AccountingPolicy) -> Self {
+        self.accounting_policy = accounting_policy;
+        self
+    }
 
-        // 進捗通知: 入力検証完了
-        self.output_port.notify_progress("入力データを検証しました".to_string()).await;
+    /// 仕訳IDの採番方式を設定（未設定の場合はUUIDv7）
+    pub fn with_id_generator(mut self, id_generator: Arc<dyn AggregateIdGenerator>) -> Self {
+        self.id_generator = id_generator;
+        self
+    }
 
-        // 2. 証憑番号の作成（空の場合は自動生成）
-        let voucher_number_str = if request.voucher_number.is_empty() {
-            // 取引日付から年度を取得（簡易的に年を使用）
-            let fiscal_year = transaction_date.value().year() as u32;
-
-            match self.voucher_generator.generate_next(fiscal_year).await {
-                Ok(vn) => {
-                    // 進捗通知: 伝票番号採番完了
-                    self.output_port.notify_progress("伝票番号を採番しました".to_string()).await;
-                    vn
-                }
-                Err(e) => {
-                    let error_msg = format!("伝票番号の採番に失敗しました: {}", e);
-                    self.output_port.notify_error(error_msg.clone()).await;
-                    return Err(ApplicationError::DomainError(e));
-                }
-            }
-        } else {
-            request.voucher_number.clone()
-        };
+    /// 業務指標の記録先を設定
+    pub fn with_metrics(mut self, metrics: Arc<dyn BusinessMetrics>) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
 
-        let voucher_number = match VoucherNumber::new(voucher_number_str) {
-            Ok(vn) => vn,
-            Err(e) => {
-                let error_msg = format!("伝票番号が無効です: {}", e);
-                self.output_port.notify_error(error_msg.clone()).await;
-                return Err(ApplicationError::DomainError(e));
-            }
-        };
+    /// 明細の分析軸を検証する分析軸マスタを設定
+    pub fn with_dimension_masters(mut self, dimension_masters: Vec<DimensionMaster>) -> Self {
+        self.dimension_masters = Some(dimension_masters);
+        self
+    }
 
-        // 3. ユーザーIDの作成
-        let user_id = UserId::new(request.user_id.clone());
+    /// イベントに埋め込む勘定科目名を設定（勘定科目コード → 名称）
+    ///
+    /// 登録時点の名称をイベントに残し、Projectionが名称を表示できるようにする。
+    pub fn with_account_names(mut self, account_names: HashMap<String, String>) -> Self {
+        self.account_names = account_names;
+        self
+    }
 
-        // 4. 仕訳明細の作成
-        let lines: Result<Vec<_>, _> = request.lines.iter().map(|dto| dto.try_into()).collect();
+    /// 仕訳を作成してイベントストアへ保存（手順3〜9）
+    async fn create_and_save(
+        &self,
+        request: ValidatedRegisterJournalEntryRequest,
+        transaction_date: TransactionDate,
+        voucher_number: VoucherNumber,
+    ) -> ApplicationResult<JournalEntry> {
+        // 4. 仕訳明細の作成（会計方針に従って端数処理）
+        let lines: Result<Vec<_>, _> = request
+            .lines
+            .into_iter()
+            .map(|line| line.rounded(&self.accounting_policy).into_line())
+            .collect();
         let lines = match lines {
             Ok(l) => l,
             Err(e) => {
@@ -127,6 +127,17 @@ impl<R: EventRepository, E: EventOutputPort, O: JournalEntryOutputPort, V: Vouch
             }
         };
 
+        // 分析軸がマスタに登録済みの有効なコードか検証
+        if let Some(dimension_masters) = &self.dimension_masters
+            && let Err(e) = lines.iter().try_for_each(|line: &JournalEntryLine| {
+                DimensionMaster::validate_line_dimensions(dimension_masters, line.dimensions())
+            })
+        {
+            let error_msg = format!("仕訳明細の分析軸が不正です: {}", e);
+            self.output_port.notify_error(error_msg.clone()).await;
+            return Err(ApplicationError::DomainError(e));
+        }
+
         // 進捗通知: 仕訳明細作成完了
         self.output_port.notify_progress("仕訳明細を作成しました".to_string()).await;
 
@@ -140,16 +151,17 @@ impl<R: EventRepository, E: EventOutputPort, O: JournalEntryOutputPort, V: Vouch
         // 進捗通知: 借貸バランス検証完了
         self.output_port.notify_progress("借貸バランスを検証しました".to_string()).await;
 
-        // 6. 仕訳IDの生成（UUIDを使用）
-        let entry_id = JournalEntryId::new(uuid::Uuid::new_v4().to_string());
+        // 6. 仕訳IDの生成（生成順に並ぶID。利用者向けの番号は伝票番号を用いる）
+        let entry_id = JournalEntryId::new(self.id_generator.next_id());
 
         // 7. 仕訳エンティティの作成（Draft状態）
-        let journal_entry = match JournalEntry::new(
+        let journal_entry = match JournalEntry::new_with_description(
             entry_id.clone(),
             transaction_date,
             voucher_number,
             lines,
-            user_id,
+            request.description,
+            request.user_id,
         ) {
             Ok(je) => je,
             Err(e) => {
@@ -165,11 +177,14 @@ impl<R: EventRepository, E: EventOutputPort, O: JournalEntryOutputPort, V: Vouch
             .await;
 
         // 8. イベントの取得（DraftCreatedイベントが含まれる）
-        let events = journal_entry.events();
+        //    明細には勘定科目マスタの名称を埋め込む
+        let mut events = journal_entry.events().to_vec();
+        for event in &mut events {
+            event.fill_account_names(|code| self.account_names.get(code).cloned());
+        }
 
         // 9. イベントストアへの保存
-        if let Err(e) = self.event_repository.append_events(entry_id.value(), events.to_vec()).await
-        {
+        if let Err(e) = self.event_repository.append_events(entry_id.value(), events).await {
             let error_msg = format!("イベントストアへの保存に失敗しました: {}", e);
             self.output_port.notify_error(error_msg.clone()).await;
             return Err(ApplicationError::DomainError(e));
@@ -180,9 +195,94 @@ impl<R: EventRepository, E: EventOutputPort, O: JournalEntryOutputPort, V: Vouch
             .notify_progress("イベントストアへ保存しました".to_string())
             .await;
 
+        Ok(journal_entry)
+    }
+}
+
+impl<R: EventRepository, E: EventOutputPort, O: JournalEntryOutputPort, V: VoucherNumberGenerator>
+    RegisterJournalEntryUseCase for RegisterJournalEntryInteractor<R, E, O, V>
+{
+    async fn execute(&self, request: RegisterJournalEntryRequest) -> ApplicationResult<()> {
+        let started = Instant::now();
+
+        // イベント通知: 処理開始
+        self.event_output
+            .notify_event(EventNotification::success(
+                "system",
+                "RegisterJournalEntry",
+                "仕訳登録を開始".to_string(),
+            ))
+            .await;
+
+        // 1. 入力バリデーション（日付・伝票番号・摘要・明細をまとめて検証）
+        let mut request = match ValidatedRegisterJournalEntryRequest::try_from(&request) {
+            Ok(request) => request,
+            Err(e) => {
+                let error_msg = format!("入力内容が不正です: {}", e.user_message());
+                self.output_port.notify_error(error_msg).await;
+                return Err(e);
+            }
+        };
+
+        let transaction_date = request.transaction_date.clone();
+
+        // 締め済・ロック済の期間には登録しない（採番より前に確認する）
+        if let Err(e) =
+            ensure_period_open(self.event_repository.as_ref(), transaction_date.value()).await
+        {
+            self.output_port.notify_error(e.user_message()).await;
+            return Err(e);
+        }
+
+        // 進捗通知: 入力検証完了
+        self.output_port.notify_progress("入力データを検証しました".to_string()).await;
+
+        // 2. 証憑番号の作成（空の場合は自動生成）
+        let (voucher_number, reserved_voucher_number) = match request.voucher_number.take() {
+            Some(voucher_number) => (voucher_number, None),
+            None => {
+                // 取引日付から年度を取得（簡易的に年を使用）
+                let fiscal_year = transaction_date.value().year() as u32;
+
+                let reserved = match self.voucher_generator.reserve(fiscal_year).await {
+                    Ok(vn) => {
+                        // 進捗通知: 伝票番号採番完了
+                        self.output_port
+                            .notify_progress("伝票番号を採番しました".to_string())
+                            .await;
+                        vn
+                    }
+                    Err(e) => {
+                        let error_msg = format!("伝票番号の採番に失敗しました: {}", e);
+                        self.output_port.notify_error(error_msg.clone()).await;
+                        return Err(ApplicationError::DomainError(e));
+                    }
+                };
+
+                match VoucherNumber::new(reserved.clone()) {
+                    Ok(voucher_number) => (voucher_number, Some(reserved)),
+                    Err(e) => {
+                        let error_msg = format!("伝票番号が無効です: {}", e);
+                        self.output_port.notify_error(error_msg).await;
+                        return self
+                            .voucher_generator
+                            .settle(&reserved, Err(ApplicationError::DomainError(e)))
+                            .await;
+                    }
+                }
+            }
+        };
+
+        // 3〜9. 仕訳の作成と保存（採番した番号は保存結果に応じて確定・取消）
+        let result = self.create_and_save(request, transaction_date, voucher_number).await;
+        let journal_entry = match &reserved_voucher_number {
+            Some(reserved) => self.voucher_generator.settle(reserved, result).await?,
+            None => result?,
+        };
+
         // 10. レスポンスDTOを作成してOutput Portへ送信
         let response = RegisterJournalEntryResponse {
-            entry_id: entry_id.value().to_string(),
+            entry_id: journal_entry.id().value().to_string(),
             status: journal_entry.status().as_str().to_string(),
         };
         self.output_port.present_register_result(response).await;
@@ -196,6 +296,9 @@ impl<R: EventRepository, E: EventOutputPort, O: JournalEntryOutputPort, V: Vouch
             ))
             .await;
 
+        record_metric(self.metrics.as_ref(), BusinessMetric::EntryRegistered, started.elapsed())
+            .await;
+
         Ok(())
     }
 }
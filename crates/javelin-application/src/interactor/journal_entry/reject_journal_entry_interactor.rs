@@ -9,6 +9,7 @@ use javelin_domain::{
 };
 
 use crate::{
+    business_metrics::{BusinessMetric, BusinessMetrics, approval_wait, record_metric},
     dtos::{RejectJournalEntryRequest, RejectJournalEntryResponse},
     error::{ApplicationError, ApplicationResult},
     input_ports::RejectJournalEntryUseCase,
@@ -23,13 +24,21 @@ pub struct RejectJournalEntryInteractor<
     event_repository: Arc<R>,
     event_output: Arc<E>,
     output_port: Arc<O>,
+    /// 業務指標の記録先（未設定の場合は記録しない）
+    metrics: Option<Arc<dyn BusinessMetrics>>,
 }
 
 impl<R: EventRepository, E: EventOutputPort, O: JournalEntryOutputPort>
     RejectJournalEntryInteractor<R, E, O>
 {
     pub fn new(event_repository: Arc<R>, event_output: Arc<E>, output_port: Arc<O>) -> Self {
-        Self { event_repository, event_output, output_port }
+        Self { event_repository, event_output, output_port, metrics: None }
+    }
+
+    /// 業務指標の記録先を設定
+    pub fn with_metrics(mut self, metrics: Arc<dyn BusinessMetrics>) -> Self {
+        self.metrics = Some(metrics);
+        self
     }
 }
 
@@ -45,6 +54,19 @@ impl<R: EventRepository, E: EventOutputPort, O: JournalEntryOutputPort> RejectJo
             ))
             .await;
 
+        // 承認待ち時間の計測用に、差戻し前のイベントを読み込む
+        let previous_events: Vec<JournalEntryEvent> = match &self.metrics {
+            Some(_) => self
+                .event_repository
+                .get_events(&request.entry_id)
+                .await
+                .map_err(ApplicationError::DomainError)?
+                .into_iter()
+                .filter_map(|value| serde_json::from_value(value).ok())
+                .collect(),
+            None => Vec::new(),
+        };
+
         // 差戻しイベントを生成
         let user_id = UserId::new(request.rejected_by.clone());
 
@@ -76,6 +98,9 @@ impl<R: EventRepository, E: EventOutputPort, O: JournalEntryOutputPort> RejectJo
             ))
             .await;
 
+        let wait = approval_wait(&previous_events, chrono::Utc::now());
+        record_metric(self.metrics.as_ref(), BusinessMetric::EntryRejected, wait).await;
+
         Ok(())
     }
 }
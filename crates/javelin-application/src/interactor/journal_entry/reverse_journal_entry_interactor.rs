@@ -12,29 +12,51 @@ use crate::{
     dtos::{ReverseJournalEntryRequest, ReverseJournalEntryResponse},
     error::{ApplicationError, ApplicationResult},
     input_ports::ReverseJournalEntryUseCase,
+    interactor::JournalEntryChainValidator,
     output_port::{EventNotification, EventOutputPort, JournalEntryOutputPort},
+    query_service::JournalEntryChainQueryService,
 };
 
 pub struct ReverseJournalEntryInteractor<
     R: EventRepository,
     E: EventOutputPort,
     O: JournalEntryOutputPort,
+    Q: JournalEntryChainQueryService,
 > {
     event_repository: Arc<R>,
     event_output: Arc<E>,
     output_port: Arc<O>,
+    chain_validator: JournalEntryChainValidator<Q>,
 }
 
-impl<R: EventRepository, E: EventOutputPort, O: JournalEntryOutputPort>
-    ReverseJournalEntryInteractor<R, E, O>
+impl<
+    R: EventRepository,
+    E: EventOutputPort,
+    O: JournalEntryOutputPort,
+    Q: JournalEntryChainQueryService,
+> ReverseJournalEntryInteractor<R, E, O, Q>
 {
-    pub fn new(event_repository: Arc<R>, event_output: Arc<E>, output_port: Arc<O>) -> Self {
-        Self { event_repository, event_output, output_port }
+    pub fn new(
+        event_repository: Arc<R>,
+        event_output: Arc<E>,
+        output_port: Arc<O>,
+        chain_query_service: Arc<Q>,
+    ) -> Self {
+        Self {
+            event_repository,
+            event_output,
+            output_port,
+            chain_validator: JournalEntryChainValidator::new(chain_query_service),
+        }
     }
 }
 
-impl<R: EventRepository, E: EventOutputPort, O: JournalEntryOutputPort> ReverseJournalEntryUseCase
-    for ReverseJournalEntryInteractor<R, E, O>
+impl<
+    R: EventRepository,
+    E: EventOutputPort,
+    O: JournalEntryOutputPort,
+    Q: JournalEntryChainQueryService,
+> ReverseJournalEntryUseCase for ReverseJournalEntryInteractor<R, E, O, Q>
 {
     async fn execute(&self, request: ReverseJournalEntryRequest) -> ApplicationResult<()> {
         self.event_output
@@ -45,6 +67,9 @@ impl<R: EventRepository, E: EventOutputPort, O: JournalEntryOutputPort> ReverseJ
             ))
             .await;
 
+        // 二重取消・取消仕訳の取消を防ぐ
+        self.chain_validator.validate_reversal(&request.entry_id).await?;
+
         // 取消イベントを生成
         let user_id = UserId::new(request.user_id.clone());
         let reversal_entry_id = format!("REV-{}", request.entry_id);
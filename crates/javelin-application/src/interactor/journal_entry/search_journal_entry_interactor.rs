@@ -3,11 +3,18 @@
 
 use std::sync::Arc;
 
+use chrono::NaiveDate;
+use javelin_domain::financial_close::journal_entry::values::LineDimensions;
+
 use crate::{
-    dtos::request::SearchCriteriaDto,
-    error::{ApplicationError, ApplicationResult},
+    dtos::request::{
+        SEARCH_FIELD_DIMENSIONS, SEARCH_FIELD_FROM_DATE, SEARCH_FIELD_MAX_AMOUNT,
+        SEARCH_FIELD_MIN_AMOUNT, SEARCH_FIELD_TO_DATE, SearchCriteriaDto,
+    },
+    error::ApplicationResult,
     input_ports::SearchJournalEntryUseCase,
     output_port::SearchOutputPort,
+    parameter_validation::{FieldError, check_date_range, into_result},
     query_service::JournalEntrySearchQueryService,
 };
 
@@ -35,58 +42,61 @@ where
     }
 
     /// 検索条件のバリデーション
+    ///
+    /// 誤りのある項目をすべて集め、項目ごとの検証エラーとして返す。
     fn validate_criteria(&self, criteria: &SearchCriteriaDto) -> ApplicationResult<()> {
-        // 日付範囲検証
-        if let (Some(from), Some(to)) = (&criteria.from_date, &criteria.to_date)
-            && from > to
-        {
-            return Err(ApplicationError::ValidationError(
-                "開始日付は終了日付以前である必要があります".to_string(),
-            ));
-        }
+        let mut errors = Vec::new();
 
         // 日付形式検証（YYYY-MM-DD形式）
-        if let Some(from_date) = &criteria.from_date
-            && !Self::is_valid_date_format(from_date)
-        {
-            return Err(ApplicationError::ValidationError(
-                "日付形式が不正です: YYYY-MM-DD形式で入力してください".to_string(),
-            ));
-        }
-        if let Some(to_date) = &criteria.to_date
-            && !Self::is_valid_date_format(to_date)
-        {
-            return Err(ApplicationError::ValidationError(
-                "日付形式が不正です: YYYY-MM-DD形式で入力してください".to_string(),
-            ));
+        let mut parse_date = |field: &str, value: &Option<String>| {
+            let value = value.as_deref()?;
+            let date = Self::is_valid_date_format(value)
+                .then(|| NaiveDate::parse_from_str(value, "%Y-%m-%d").ok())
+                .flatten();
+            if date.is_none() {
+                errors.push(FieldError::new(
+                    field,
+                    "日付形式が不正です: YYYY-MM-DD形式で入力してください",
+                ));
+            }
+            date
+        };
+        let from_date = parse_date(SEARCH_FIELD_FROM_DATE, &criteria.from_date);
+        let to_date = parse_date(SEARCH_FIELD_TO_DATE, &criteria.to_date);
+
+        // 日付範囲検証
+        if let (Some(from), Some(to)) = (from_date, to_date) {
+            errors.extend(check_date_range(from, SEARCH_FIELD_TO_DATE, to));
         }
 
         // 金額範囲検証
-        if let Some(min) = criteria.min_amount
-            && min < 0.0
-        {
-            return Err(ApplicationError::ValidationError(
-                "最小金額は0以上である必要があります".to_string(),
+        if criteria.min_amount.is_some_and(|min| min < 0.0) {
+            errors.push(FieldError::new(
+                SEARCH_FIELD_MIN_AMOUNT,
+                "最小金額は0以上である必要があります",
             ));
         }
-
-        if let Some(max) = criteria.max_amount
-            && max < 0.0
-        {
-            return Err(ApplicationError::ValidationError(
-                "最大金額は0以上である必要があります".to_string(),
+        if criteria.max_amount.is_some_and(|max| max < 0.0) {
+            errors.push(FieldError::new(
+                SEARCH_FIELD_MAX_AMOUNT,
+                "最大金額は0以上である必要があります",
             ));
         }
-
         if let (Some(min), Some(max)) = (criteria.min_amount, criteria.max_amount)
             && min > max
         {
-            return Err(ApplicationError::ValidationError(
-                "最小金額は最大金額以下である必要があります".to_string(),
+            errors.push(FieldError::new(
+                SEARCH_FIELD_MAX_AMOUNT,
+                "最小金額は最大金額以下である必要があります",
             ));
         }
 
-        Ok(())
+        // 分析軸の形式検証
+        if let Err(e) = LineDimensions::new(criteria.dimensions.clone()) {
+            errors.push(FieldError::new(SEARCH_FIELD_DIMENSIONS, e.user_message()));
+        }
+
+        into_result(errors)
     }
 
     /// 日付形式が正しいかチェック（YYYY-MM-DD形式）
@@ -122,7 +132,7 @@ where
 
         // バリデーション
         if let Err(e) = self.validate_criteria(&criteria) {
-            self.output_port.present_validation_error(e.to_string());
+            self.output_port.present_validation_error(e.user_message());
             return Err(e);
         }
 
@@ -229,11 +239,25 @@ mod tests {
             .with_to_date("2024-12-31".to_string());
         assert!(interactor.validate_criteria(&criteria).is_ok());
 
-        // 不正な日付範囲（開始 > 終了）
+        // 不正な日付範囲（開始 > 終了）は終了日の項目を示す
         let criteria = SearchCriteriaDto::new()
             .with_from_date("2024-12-31".to_string())
             .with_to_date("2024-01-01".to_string());
-        assert!(interactor.validate_criteria(&criteria).is_err());
+        assert!(matches!(
+            interactor.validate_criteria(&criteria),
+            Err(crate::error::ApplicationError::InvalidFields(errors))
+                if errors.len() == 1 && errors[0].field == SEARCH_FIELD_TO_DATE
+        ));
+
+        // 存在しない日付は範囲の比較より先に形式の誤りとする
+        let criteria = SearchCriteriaDto::new()
+            .with_from_date("2024-02-30".to_string())
+            .with_to_date("2024-01-01".to_string());
+        assert!(matches!(
+            interactor.validate_criteria(&criteria),
+            Err(crate::error::ApplicationError::InvalidFields(errors))
+                if errors.len() == 1 && errors[0].field == SEARCH_FIELD_FROM_DATE
+        ));
     }
 
     #[test]
@@ -276,4 +300,20 @@ mod tests {
         let criteria = SearchCriteriaDto::new().with_from_date("invalid".to_string());
         assert!(interactor.validate_criteria(&criteria).is_err());
     }
+
+    #[test]
+    fn test_validate_criteria_dimensions() {
+        let query_service = Arc::new(MockQueryService);
+        let output_port = Arc::new(MockOutputPort);
+        let interactor = SearchJournalEntryInteractor::new(query_service, output_port);
+
+        let criteria =
+            SearchCriteriaDto::new().with_dimension("project".to_string(), "P001".to_string());
+        assert!(interactor.validate_criteria(&criteria).is_ok());
+
+        // 不正な分析軸の種類
+        let criteria =
+            SearchCriteriaDto::new().with_dimension("Project".to_string(), "P001".to_string());
+        assert!(interactor.validate_criteria(&criteria).is_err());
+    }
 }
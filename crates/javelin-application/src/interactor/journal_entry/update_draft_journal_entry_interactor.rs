@@ -2,7 +2,6 @@
 
 use std::sync::Arc;
 
-use chrono::NaiveDate;
 use javelin_domain::{
     entity::EntityId,
     financial_close::journal_entry::{
@@ -14,7 +13,9 @@ use javelin_domain::{
 };
 
 use crate::{
-    dtos::{UpdateDraftJournalEntryRequest, UpdateDraftJournalEntryResponse},
+    dtos::{
+        UpdateDraftJournalEntryRequest, UpdateDraftJournalEntryResponse, request::validate_lines,
+    },
     error::{ApplicationError, ApplicationResult},
     input_ports::UpdateDraftJournalEntryUseCase,
     output_port::{EventNotification, EventOutputPort, JournalEntryOutputPort},
@@ -60,7 +61,8 @@ impl<R: EventRepository, E: EventOutputPort, O: JournalEntryOutputPort>
             use javelin_domain::financial_close::journal_entry::services::JournalEntryService;
 
             // DTOからエンティティに変換（TryFromを使用）
-            let lines: Result<Vec<_>, _> = lines_dto.iter().map(|dto| dto.try_into()).collect();
+            let lines: Result<Vec<_>, _> =
+                validate_lines(lines_dto)?.into_iter().map(|line| line.into_line()).collect();
             let lines = lines?;
 
             // 借貸バランスチェック
@@ -75,19 +77,7 @@ impl<R: EventRepository, E: EventOutputPort, O: JournalEntryOutputPort>
             None
         };
 
-        // 2. 取引日付のバリデーション（指定されている場合）
-        if let Some(ref date_str) = request.transaction_date {
-            use javelin_domain::financial_close::journal_entry::values::TransactionDate;
-
-            let transaction_date =
-                NaiveDate::parse_from_str(date_str, "%Y-%m-%d").map_err(|_| {
-                    ApplicationError::ValidationFailed(vec![format!(
-                        "Invalid date format: {}",
-                        date_str
-                    )])
-                })?;
-            TransactionDate::new(transaction_date).map_err(ApplicationError::DomainError)?;
-        }
+        // 2. 取引日付は境界で解析済みのため検証不要
 
         // 3. 証憑番号のバリデーション（指定されている場合）
         if let Some(ref voucher) = request.voucher_number {
@@ -95,19 +85,29 @@ impl<R: EventRepository, E: EventOutputPort, O: JournalEntryOutputPort>
             VoucherNumber::new(voucher.clone()).map_err(ApplicationError::DomainError)?;
         }
 
-        // 4. 更新イベントを生成
+        // 4. 摘要のバリデーション（指定されている場合）
+        if let Some(ref description) = request.description
+            && !description.trim().is_empty()
+        {
+            use javelin_domain::financial_close::journal_entry::values::Description;
+            Description::new(description.trim().to_string())
+                .map_err(ApplicationError::DomainError)?;
+        }
+
+        // 5. 更新イベントを生成
         let user_id = UserId::new(request.user_id.clone());
 
         let event = JournalEntryEvent::DraftUpdated {
             entry_id: request.entry_id.clone(),
-            transaction_date: request.transaction_date.clone(),
+            transaction_date: request.transaction_date.map(|date| date.to_string()),
             voucher_number: request.voucher_number.clone(),
             lines: event_lines,
+            description: request.description.as_deref().map(|d| d.trim().to_string()),
             updated_by: user_id.value().to_string(),
             updated_at: chrono::Utc::now(),
         };
 
-        // 5. イベントストアへの保存
+        // 6. イベントストアへの保存
         self.event_repository
             .append_events(&request.entry_id, vec![event])
             .await
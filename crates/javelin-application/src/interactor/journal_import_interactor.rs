@@ -0,0 +1,642 @@
+// JournalImportInteractor - 表計算ファイルからの仕訳取込のユースケース
+// 責務: 列の割当（テンプレート）の復元・記憶、取込内容の検証と下書き仕訳の起票
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::Arc,
+};
+
+use chrono::NaiveDate;
+use javelin_domain::{
+    entity::EntityId,
+    financial_close::journal_entry::{
+        entities::{JournalEntry, JournalEntryId, JournalEntryLine},
+        services::JournalEntryService,
+        values::{TransactionDate, UserId, VoucherNumber},
+    },
+    id_generator::AggregateIdGenerator,
+    masters::{ImportField, JournalImportTemplate},
+    repositories::{EventRepository, JournalImportTemplateRepository},
+};
+
+use crate::{
+    dtos::{
+        JournalEntryLineDto,
+        request::{JournalImportPreviewRequest, JournalImportRequest},
+        response::{
+            IMPORT_PREVIEW_ROWS, ImportRowError, ImportedEntryDto, ImportedEntrySummary,
+            JournalImportPreviewResponse, JournalImportResponse, JournalImportValidationReport,
+        },
+    },
+    error::{ApplicationError, ApplicationResult},
+    id_generator::TimeOrderedIdGenerator,
+    projection_builder::ProjectionBuilder,
+    spreadsheet_reader::{Spreadsheet, SpreadsheetReader},
+};
+
+/// 取込失敗時にメッセージへ含める行エラーの件数
+const MAX_REPORTED_ERRORS: usize = 5;
+
+/// Projectionへの反映を保留して一括で取り込む仕訳数の下限
+pub const DEFERRED_PROJECTION_MIN_ENTRIES: usize = 100;
+
+/// 進捗を通知する仕訳数の間隔
+const PROGRESS_INTERVAL: usize = 500;
+
+/// 表計算ファイルからの仕訳取込のInteractor
+///
+/// 1行を仕訳明細1行として読み、同じ伝票番号の行を1件の仕訳にまとめて下書きで起票する。
+/// 取込に成功した列の割当は見出し行ごとに記憶し、次回のプレビューで復元する。
+pub struct JournalImportInteractor<S, T, R>
+where
+    S: SpreadsheetReader,
+    T: JournalImportTemplateRepository,
+    R: EventRepository,
+{
+    reader: Arc<S>,
+    template_repository: Arc<T>,
+    event_repository: Arc<R>,
+    /// 一括取込中に反映を保留するProjection（未設定の場合は保留しない）
+    projections: Option<Arc<dyn ProjectionBuilder>>,
+}
+
+/// 伝票番号ごとにまとめた取込行
+struct ImportedVoucher {
+    voucher_number: String,
+    transaction_date: NaiveDate,
+    /// 最初の行のシート上の行番号
+    first_row: usize,
+    lines: Vec<(usize, JournalEntryLineDto)>,
+}
+
+impl<S, T, R> JournalImportInteractor<S, T, R>
+where
+    S: SpreadsheetReader,
+    T: JournalImportTemplateRepository,
+    R: EventRepository,
+{
+    pub fn new(reader: Arc<S>, template_repository: Arc<T>, event_repository: Arc<R>) -> Self {
+        Self { reader, template_repository, event_repository, projections: None }
+    }
+
+    /// 一括取込中に反映を保留するProjectionを設定
+    ///
+    /// 取り込む仕訳が `DEFERRED_PROJECTION_MIN_ENTRIES` 件以上の場合、追記のたびの
+    /// 反映を保留し、すべて追記した後に未反映のイベントをまとめて反映する。
+    pub fn with_deferred_projections(mut self, projections: Arc<dyn ProjectionBuilder>) -> Self {
+        self.projections = Some(projections);
+        self
+    }
+
+    /// ファイルを読み込み、先頭行と列の割当を返す
+    pub async fn preview(
+        &self,
+        request: JournalImportPreviewRequest,
+    ) -> ApplicationResult<JournalImportPreviewResponse> {
+        let sheet = self.reader.read_first_sheet(&request.path).await?;
+        let signature = JournalImportTemplate::signature_of(&sheet.headers);
+        let remembered = self.template_repository.find_by_signature(&signature).await?;
+
+        let columns = match &remembered {
+            Some(template) => template.columns().clone(),
+            None => JournalImportTemplate::guess_columns(&sheet.headers),
+        };
+
+        Ok(JournalImportPreviewResponse {
+            path: request.path,
+            sheet_name: sheet.sheet_name,
+            preview_rows: sheet.rows.iter().take(IMPORT_PREVIEW_ROWS).cloned().collect(),
+            total_rows: sheet.rows.len(),
+            headers: sheet.headers,
+            columns,
+            remembered: remembered.is_some(),
+        })
+    }
+
+    /// 列の割当に従って取込内容を検証する
+    pub async fn validate(
+        &self,
+        request: JournalImportRequest,
+    ) -> ApplicationResult<JournalImportValidationReport> {
+        let sheet = self.reader.read_first_sheet(&request.path).await?;
+        let template = template_for(&sheet, request.columns)?;
+        let (report, _) = parse_sheet(&sheet, &template);
+        Ok(report)
+    }
+
+    /// 検証に通った場合に仕訳を下書きとして起票し、列の割当を記憶する
+    pub async fn import(
+        &self,
+        request: JournalImportRequest,
+    ) -> ApplicationResult<JournalImportResponse> {
+        self.import_with_progress(request, &|_| {}).await
+    }
+
+    /// 仕訳を下書きとして取り込む（追記・反映の進捗を `progress` へ通知）
+    pub async fn import_with_progress(
+        &self,
+        request: JournalImportRequest,
+        progress: &(dyn Fn(String) + Send + Sync),
+    ) -> ApplicationResult<JournalImportResponse> {
+        let sheet = self.reader.read_first_sheet(&request.path).await?;
+        let template = template_for(&sheet, request.columns)?;
+        let (report, vouchers) = parse_sheet(&sheet, &template);
+
+        if report.entries.is_empty() && report.errors.is_empty() {
+            return Err(ApplicationError::ValidationFailed(vec![
+                "取込対象の行がありません".to_string(),
+            ]));
+        }
+        if !report.is_valid() {
+            let mut messages: Vec<String> = report
+                .errors
+                .iter()
+                .take(MAX_REPORTED_ERRORS)
+                .map(|error| format!("{}行目: {}", error.row, error.message))
+                .collect();
+            if report.errors.len() > MAX_REPORTED_ERRORS {
+                messages.push(format!("ほか{}件", report.errors.len() - MAX_REPORTED_ERRORS));
+            }
+            return Err(ApplicationError::ValidationFailed(messages));
+        }
+
+        self.template_repository.save(&template).await?;
+
+        let deferred = self
+            .projections
+            .as_ref()
+            .filter(|_| vouchers.len() >= DEFERRED_PROJECTION_MIN_ENTRIES);
+        if let Some(projections) = deferred {
+            projections.defer_projections();
+        }
+
+        // 追記に失敗しても保留は解除し、追記済みの仕訳は反映する
+        let appended = self.append_vouchers(vouchers, &request.user_id, progress).await;
+        let deferred_projection = match deferred {
+            Some(projections) => Some(
+                projections
+                    .resume_projections(&|applied, total| {
+                        progress(format!("Projectionへ反映中... {} / {}", applied, total))
+                    })
+                    .await?,
+            ),
+            None => None,
+        };
+        let imported = appended?;
+
+        Ok(JournalImportResponse { report, imported, deferred_projection })
+    }
+
+    /// 伝票ごとに下書き仕訳を起票してイベントを追記
+    async fn append_vouchers(
+        &self,
+        vouchers: Vec<ImportedVoucher>,
+        user_id: &str,
+        progress: &(dyn Fn(String) + Send + Sync),
+    ) -> ApplicationResult<Vec<ImportedEntryDto>> {
+        let total = vouchers.len();
+        let mut imported = Vec::with_capacity(total);
+        for voucher in vouchers {
+            let lines: Vec<JournalEntryLine> =
+                voucher.lines.iter().map(|(_, dto)| dto.try_into()).collect::<Result<_, _>>()?;
+            let entry_id = JournalEntryId::new(TimeOrderedIdGenerator.next_id());
+            let journal_entry = JournalEntry::new(
+                entry_id.clone(),
+                TransactionDate::new(voucher.transaction_date)?,
+                VoucherNumber::new(voucher.voucher_number.clone())?,
+                lines,
+                UserId::new(user_id.to_string()),
+            )?;
+
+            self.event_repository
+                .append_events(entry_id.value(), journal_entry.events().to_vec())
+                .await?;
+
+            imported.push(ImportedEntryDto {
+                voucher_number: voucher.voucher_number,
+                entry_id: entry_id.value().to_string(),
+            });
+            if imported.len() % PROGRESS_INTERVAL == 0 {
+                progress(format!("仕訳を取込中... {} / {}", imported.len(), total));
+            }
+        }
+
+        Ok(imported)
+    }
+}
+
+/// 列の割当をテンプレートとして検証（必須項目の割当、列の重複、列の範囲）
+fn template_for(
+    sheet: &Spreadsheet,
+    columns: BTreeMap<ImportField, usize>,
+) -> ApplicationResult<JournalImportTemplate> {
+    if let Some((field, column)) =
+        columns.iter().find(|(_, column)| **column >= sheet.headers.len())
+    {
+        return Err(ApplicationError::ValidationFailed(vec![format!(
+            "{}に割り当てた{}列目はファイルにありません",
+            field.label(),
+            column + 1
+        )]));
+    }
+    Ok(JournalImportTemplate::new(
+        JournalImportTemplate::signature_of(&sheet.headers),
+        columns,
+    )?)
+}
+
+/// シートを伝票番号ごとの仕訳に変換し、検証結果を作成
+fn parse_sheet(
+    sheet: &Spreadsheet,
+    template: &JournalImportTemplate,
+) -> (JournalImportValidationReport, Vec<ImportedVoucher>) {
+    let mut report = JournalImportValidationReport::default();
+    let mut vouchers: Vec<ImportedVoucher> = Vec::new();
+    let mut index_by_voucher: HashMap<String, usize> = HashMap::new();
+
+    for (index, cells) in sheet.rows.iter().enumerate() {
+        if cells.iter().all(|cell| cell.trim().is_empty()) {
+            continue;
+        }
+        report.total_rows += 1;
+        let row = sheet.sheet_row(index);
+        let mut error = |message: String| report.errors.push(ImportRowError { row, message });
+
+        let (voucher_number, transaction_date, line) = match parse_row(cells, template) {
+            Ok(parsed) => parsed,
+            Err(message) => {
+                error(message);
+                continue;
+            }
+        };
+
+        let voucher = match index_by_voucher.get(&voucher_number) {
+            Some(&position) => &mut vouchers[position],
+            None => {
+                index_by_voucher.insert(voucher_number.clone(), vouchers.len());
+                vouchers.push(ImportedVoucher {
+                    voucher_number: voucher_number.clone(),
+                    transaction_date,
+                    first_row: row,
+                    lines: Vec::new(),
+                });
+                vouchers.last_mut().expect("pushed voucher")
+            }
+        };
+        if voucher.transaction_date != transaction_date {
+            error(format!(
+                "伝票{}の取引日付が{}行目と異なります（{}）",
+                voucher_number, voucher.first_row, voucher.transaction_date
+            ));
+            continue;
+        }
+        let line_number = voucher.lines.len() as u32 + 1;
+        voucher.lines.push((row, JournalEntryLineDto { line_number, ..line }));
+    }
+
+    for voucher in &vouchers {
+        let header = VoucherNumber::new(voucher.voucher_number.clone())
+            .and_then(|_| TransactionDate::new(voucher.transaction_date));
+        if let Err(e) = header {
+            report.errors.push(ImportRowError {
+                row: voucher.first_row,
+                message: format!("伝票{}: {}", voucher.voucher_number, e.user_message()),
+            });
+            continue;
+        }
+        let mut lines = Vec::with_capacity(voucher.lines.len());
+        for (row, dto) in &voucher.lines {
+            match JournalEntryLine::try_from(dto) {
+                Ok(line) => lines.push(line),
+                Err(e) => {
+                    report.errors.push(ImportRowError { row: *row, message: e.user_message() })
+                }
+            }
+        }
+        if lines.len() != voucher.lines.len() {
+            continue;
+        }
+        if let Err(e) = JournalEntryService::validate_balance(&lines) {
+            report.errors.push(ImportRowError {
+                row: voucher.first_row,
+                message: format!("伝票{}: {}", voucher.voucher_number, e.user_message()),
+            });
+            continue;
+        }
+
+        let total = |side: &str| -> f64 {
+            voucher
+                .lines
+                .iter()
+                .filter(|(_, dto)| dto.side == side)
+                .map(|(_, dto)| dto.amount)
+                .sum()
+        };
+        report.entries.push(ImportedEntrySummary {
+            voucher_number: voucher.voucher_number.clone(),
+            transaction_date: voucher.transaction_date.format("%Y-%m-%d").to_string(),
+            line_count: voucher.lines.len(),
+            debit_total: total("Debit"),
+            credit_total: total("Credit"),
+        });
+    }
+
+    report.errors.sort_by_key(|error| error.row);
+    (report, vouchers)
+}
+
+/// 1行を伝票番号・取引日付・明細に変換（明細番号は呼び出し側で採番）
+fn parse_row(
+    cells: &[String],
+    template: &JournalImportTemplate,
+) -> Result<(String, NaiveDate, JournalEntryLineDto), String> {
+    let cell = |field: ImportField| -> Option<String> {
+        template
+            .column(field)
+            .and_then(|column| cells.get(column))
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+    };
+    let required = |field: ImportField| -> Result<String, String> {
+        cell(field).ok_or_else(|| format!("{}が空です", field.label()))
+    };
+
+    let voucher_number = required(ImportField::VoucherNumber)?;
+    let date = required(ImportField::TransactionDate)?;
+    let transaction_date =
+        parse_date(&date).ok_or_else(|| format!("取引日付を読み取れません: {}", date))?;
+    let side = required(ImportField::Side)?;
+    let side = parse_side(&side).ok_or_else(|| format!("貸借を読み取れません: {}", side))?;
+    let amount = required(ImportField::Amount)?;
+    let amount =
+        parse_amount(&amount).ok_or_else(|| format!("金額を読み取れません: {}", amount))?;
+    let tax_type = match cell(ImportField::TaxType) {
+        Some(value) => {
+            parse_tax_type(&value).ok_or_else(|| format!("税区分を読み取れません: {}", value))?
+        }
+        None => "NonTaxable",
+    };
+
+    let line = JournalEntryLineDto {
+        line_number: 0,
+        side: side.to_string(),
+        account_code: required(ImportField::AccountCode)?,
+        sub_account_code: cell(ImportField::SubAccountCode),
+        department_code: cell(ImportField::DepartmentCode),
+        dimensions: Default::default(),
+        amount,
+        currency: cell(ImportField::Currency)
+            .map(|value| value.to_uppercase())
+            .unwrap_or_else(|| "JPY".to_string()),
+        tax_type: tax_type.to_string(),
+        tax_amount: 0.0,
+        description: cell(ImportField::Description),
+    };
+    Ok((voucher_number, transaction_date, line))
+}
+
+/// `YYYY-MM-DD`・`YYYY/MM/DD`・`YYYYMMDD` 形式の日付
+fn parse_date(value: &str) -> Option<NaiveDate> {
+    ["%Y-%m-%d", "%Y/%m/%d", "%Y%m%d"]
+        .iter()
+        .find_map(|format| NaiveDate::parse_from_str(value, format).ok())
+}
+
+fn parse_side(value: &str) -> Option<&'static str> {
+    match value.to_lowercase().as_str() {
+        "借方" | "借" | "debit" | "d" | "dr" => Some("Debit"),
+        "貸方" | "貸" | "credit" | "c" | "cr" => Some("Credit"),
+        _ => None,
+    }
+}
+
+/// 桁区切りと通貨記号を除いた金額
+fn parse_amount(value: &str) -> Option<f64> {
+    let normalized: String =
+        value.chars().filter(|c| !matches!(c, ',' | '¥' | '￥' | '$' | ' ')).collect();
+    normalized.parse::<f64>().ok().filter(|amount| amount.is_finite())
+}
+
+fn parse_tax_type(value: &str) -> Option<&'static str> {
+    match value {
+        "課税" | "Taxable" => Some("Taxable"),
+        "非課税" | "NonTaxable" => Some("NonTaxable"),
+        "免税" | "TaxExempt" => Some("TaxExempt"),
+        "不課税" | "OutOfScope" => Some("OutOfScope"),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use javelin_domain::error::DomainResult;
+
+    use super::*;
+    use crate::testkit::InMemoryEventRepository;
+
+    struct MockReader {
+        sheet: Spreadsheet,
+    }
+
+    #[async_trait::async_trait]
+    impl SpreadsheetReader for MockReader {
+        async fn read_first_sheet(&self, _path: &str) -> ApplicationResult<Spreadsheet> {
+            Ok(self.sheet.clone())
+        }
+    }
+
+    #[derive(Default)]
+    struct MockTemplateRepository {
+        templates: Mutex<HashMap<String, JournalImportTemplate>>,
+    }
+
+    impl JournalImportTemplateRepository for MockTemplateRepository {
+        async fn find_by_signature(
+            &self,
+            signature: &str,
+        ) -> DomainResult<Option<JournalImportTemplate>> {
+            Ok(self.templates.lock().unwrap().get(signature).cloned())
+        }
+
+        async fn save(&self, template: &JournalImportTemplate) -> DomainResult<()> {
+            self.templates
+                .lock()
+                .unwrap()
+                .insert(template.signature().to_string(), template.clone());
+            Ok(())
+        }
+    }
+
+    fn sheet(rows: &[[&str; 6]]) -> Spreadsheet {
+        let strings = |cells: &[&str]| cells.iter().map(|cell| cell.to_string()).collect();
+        Spreadsheet {
+            sheet_name: "仕訳".to_string(),
+            headers: strings(&["日付", "伝票番号", "貸借", "科目コード", "金額", "備考"]),
+            rows: rows.iter().map(|row| strings(row)).collect(),
+            header_row: 1,
+        }
+    }
+
+    type TestInteractor =
+        JournalImportInteractor<MockReader, MockTemplateRepository, InMemoryEventRepository>;
+
+    fn interactor(sheet: Spreadsheet) -> (TestInteractor, Arc<InMemoryEventRepository>) {
+        let events = Arc::new(InMemoryEventRepository::default());
+        let interactor = JournalImportInteractor::new(
+            Arc::new(MockReader { sheet }),
+            Arc::new(MockTemplateRepository::default()),
+            Arc::clone(&events),
+        );
+        (interactor, events)
+    }
+
+    #[tokio::test]
+    async fn test_import_groups_rows_by_voucher_and_remembers_template() {
+        let (interactor, events) = interactor(sheet(&[
+            ["2024/04/01", "X-001", "借方", "1100", "1,000", ""],
+            ["2024/04/01", "X-001", "貸方", "4000", "1000", ""],
+            ["", "", "", "", "", ""],
+            ["20240402", "X-002", "D", "5100", "500", ""],
+            ["20240402", "X-002", "C", "1100", "500", ""],
+        ]));
+
+        let preview = interactor
+            .preview(JournalImportPreviewRequest { path: "a.xlsx".to_string() })
+            .await
+            .unwrap();
+        assert!(!preview.remembered);
+        assert_eq!(preview.total_rows, 5);
+        assert_eq!(preview.columns.get(&ImportField::Amount), Some(&4));
+        assert!(!preview.columns.contains_key(&ImportField::Description));
+
+        let request = JournalImportRequest {
+            path: "a.xlsx".to_string(),
+            columns: preview.columns.clone(),
+            user_id: "clerk".to_string(),
+        };
+        let response = interactor.import(request).await.unwrap();
+        assert_eq!(response.report.total_rows, 4);
+        assert_eq!(response.imported.len(), 2);
+        assert_eq!(response.report.entries[0].voucher_number, "X-001");
+        assert_eq!(response.report.entries[0].debit_total, 1000.0);
+        assert_eq!(response.report.entries[1].transaction_date, "2024-04-02");
+        assert_eq!(events.aggregate_count(), 2);
+
+        // 同じ見出し行のファイルは記憶した割当を復元する
+        let preview = interactor
+            .preview(JournalImportPreviewRequest { path: "b.xlsx".to_string() })
+            .await
+            .unwrap();
+        assert!(preview.remembered);
+    }
+
+    #[tokio::test]
+    async fn test_validation_report_lists_row_errors() {
+        let (interactor, events) = interactor(sheet(&[
+            ["2024-04-01", "X-001", "借方", "1100", "1000", ""],
+            ["2024-04-01", "X-001", "貸方", "4000", "900", ""],
+            ["2024-04-31", "X-002", "借方", "1100", "100", ""],
+            ["2024-04-01", "X-003", "左", "1100", "abc", ""],
+        ]));
+        let columns = JournalImportTemplate::guess_columns(&sheet(&[]).headers);
+        let request = JournalImportRequest {
+            path: "a.xlsx".to_string(),
+            columns,
+            user_id: "clerk".to_string(),
+        };
+
+        let report = interactor.validate(request.clone()).await.unwrap();
+        assert!(!report.is_valid());
+        let rows: Vec<usize> = report.errors.iter().map(|error| error.row).collect();
+        assert_eq!(rows, vec![2, 4, 5]);
+        assert!(report.errors[0].message.contains("X-001"));
+
+        assert!(interactor.import(request.clone()).await.is_err());
+        assert!(events.is_empty());
+
+        // 必須項目が未割当の場合は検証前に拒否する
+        let mut columns = request.columns.clone();
+        columns.remove(&ImportField::Side);
+        assert!(interactor.validate(JournalImportRequest { columns, ..request }).await.is_err());
+    }
+
+    /// 反映の保留・解除を記録するProjection
+    #[derive(Default)]
+    struct MockProjections {
+        calls: Mutex<Vec<&'static str>>,
+    }
+
+    #[async_trait::async_trait]
+    impl ProjectionBuilder for MockProjections {
+        async fn rebuild_all_projections(&self) -> ApplicationResult<()> {
+            Ok(())
+        }
+
+        async fn process_event(&self, _event_data: &[u8]) -> ApplicationResult<()> {
+            Ok(())
+        }
+
+        fn defer_projections(&self) {
+            self.calls.lock().unwrap().push("defer");
+        }
+
+        async fn resume_projections(
+            &self,
+            progress: &(dyn Fn(u64, u64) + Send + Sync),
+        ) -> ApplicationResult<u64> {
+            self.calls.lock().unwrap().push("resume");
+            progress(7, 7);
+            Ok(7)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_bulk_import_defers_projections_until_appended() {
+        let projections = Arc::new(MockProjections::default());
+        let mut rows = Vec::new();
+        for index in 0..DEFERRED_PROJECTION_MIN_ENTRIES {
+            let voucher = format!("X-{:03}", index);
+            rows.push(["2024-04-01".to_string(), voucher.clone(), "借方".to_string()]);
+            rows.push(["2024-04-01".to_string(), voucher, "貸方".to_string()]);
+        }
+        let mut bulk = sheet(&[]);
+        bulk.rows = rows
+            .into_iter()
+            .map(|[date, voucher, side]| {
+                vec![date, voucher, side, "1100".to_string(), "100".to_string(), String::new()]
+            })
+            .collect();
+        let (bulk_interactor, events) = interactor(bulk);
+        let bulk_interactor = bulk_interactor.with_deferred_projections(projections.clone());
+        let request = JournalImportRequest {
+            path: "a.xlsx".to_string(),
+            columns: JournalImportTemplate::guess_columns(&sheet(&[]).headers),
+            user_id: "clerk".to_string(),
+        };
+
+        let messages = Mutex::new(Vec::new());
+        let response = bulk_interactor
+            .import_with_progress(request.clone(), &|message| {
+                messages.lock().unwrap().push(message)
+            })
+            .await
+            .unwrap();
+        assert_eq!(response.imported.len(), DEFERRED_PROJECTION_MIN_ENTRIES);
+        assert_eq!(response.deferred_projection, Some(7));
+        assert_eq!(*projections.calls.lock().unwrap(), vec!["defer", "resume"]);
+        assert_eq!(events.aggregate_count(), DEFERRED_PROJECTION_MIN_ENTRIES);
+        assert_eq!(messages.lock().unwrap().last().unwrap(), "Projectionへ反映中... 7 / 7");
+
+        // 少量の取込では保留しない
+        let (interactor, _) = interactor(sheet(&[
+            ["2024-04-01", "X-001", "借方", "1100", "100", ""],
+            ["2024-04-01", "X-001", "貸方", "1100", "100", ""],
+        ]));
+        let interactor = interactor.with_deferred_projections(projections.clone());
+        let response = interactor.import(request).await.unwrap();
+        assert_eq!(response.deferred_projection, None);
+        assert_eq!(projections.calls.lock().unwrap().len(), 2);
+    }
+}
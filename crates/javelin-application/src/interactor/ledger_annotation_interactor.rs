@@ -0,0 +1,242 @@
+// LedgerAnnotationInteractor - 仕訳明細の注記（レビューフラグ）のユースケース
+// 責務: 明細への注記の追加・解決と、仕訳ごとの注記一覧の取得
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use javelin_domain::{
+    financial_close::journal_entry::{entities::LineAnnotation, events::JournalEntryEvent},
+    repositories::EventRepository,
+};
+
+use crate::{
+    dtos::{
+        request::{AnnotateEntryLineRequest, ResolveAnnotationRequest},
+        response::{EntryAnnotationsResponse, LineAnnotationDto},
+    },
+    error::{ApplicationError, ApplicationResult},
+};
+
+/// 仕訳明細の注記のInteractor
+///
+/// 注記は仕訳のイベントストリームに追記する。削除された仕訳と、
+/// 存在しない明細番号には注記を付けられない。
+pub struct LedgerAnnotationInteractor<R: EventRepository> {
+    event_repository: Arc<R>,
+}
+
+impl<R: EventRepository> LedgerAnnotationInteractor<R> {
+    pub fn new(event_repository: Arc<R>) -> Self {
+        Self { event_repository }
+    }
+
+    /// 明細に注記を付ける
+    pub async fn annotate(
+        &self,
+        request: AnnotateEntryLineRequest,
+    ) -> ApplicationResult<EntryAnnotationsResponse> {
+        let events = self.load_events(&request.entry_id).await?;
+        if !line_numbers(&events).contains(&request.line_number) {
+            return Err(ApplicationError::ValidationFailed(vec![format!(
+                "明細が見つかりません: {} 行{}",
+                request.entry_id, request.line_number
+            )]));
+        }
+
+        let annotation_id =
+            format!("N-{}", uuid::Uuid::new_v4().simple().to_string()[..8].to_uppercase());
+        let event = LineAnnotation::annotate(
+            annotation_id,
+            &request.entry_id,
+            request.line_number,
+            &request.note,
+            request.user_id,
+            Utc::now(),
+        )?;
+        self.event_repository.append_events(&request.entry_id, vec![event]).await?;
+
+        self.annotations(&request.entry_id).await
+    }
+
+    /// 注記を解決する
+    pub async fn resolve(
+        &self,
+        request: ResolveAnnotationRequest,
+    ) -> ApplicationResult<EntryAnnotationsResponse> {
+        let events = self.load_events(&request.entry_id).await?;
+        let annotation = LineAnnotation::replay(&events)
+            .into_iter()
+            .find(|a| a.annotation_id() == request.annotation_id)
+            .ok_or_else(|| {
+                ApplicationError::ValidationFailed(vec![format!(
+                    "注記が見つかりません: {}",
+                    request.annotation_id
+                )])
+            })?;
+
+        let event = annotation.resolve(&request.resolution, request.user_id, Utc::now())?;
+        self.event_repository.append_events(&request.entry_id, vec![event]).await?;
+
+        self.annotations(&request.entry_id).await
+    }
+
+    /// 仕訳の注記一覧
+    pub async fn annotations(&self, entry_id: &str) -> ApplicationResult<EntryAnnotationsResponse> {
+        let events = self.load_events(entry_id).await?;
+        let annotations = LineAnnotation::replay(&events)
+            .into_iter()
+            .map(|annotation| {
+                let resolution = annotation.resolution().cloned();
+                LineAnnotationDto {
+                    annotation_id: annotation.annotation_id().to_string(),
+                    line_number: annotation.line_number(),
+                    note: annotation.note().to_string(),
+                    annotated_by: annotation.annotated_by().to_string(),
+                    annotated_at: annotation.annotated_at().to_rfc3339(),
+                    resolved_by: resolution.as_ref().map(|r| r.resolved_by.clone()),
+                    resolved_at: resolution.as_ref().map(|r| r.resolved_at.to_rfc3339()),
+                    resolution: resolution.map(|r| r.resolution),
+                }
+            })
+            .collect();
+
+        Ok(EntryAnnotationsResponse { entry_id: entry_id.to_string(), annotations })
+    }
+
+    /// 仕訳のイベントを読み込む（存在しない・削除済みの仕訳はエラー）
+    async fn load_events(&self, entry_id: &str) -> ApplicationResult<Vec<JournalEntryEvent>> {
+        let events: Vec<JournalEntryEvent> = self
+            .event_repository
+            .get_events(entry_id)
+            .await?
+            .into_iter()
+            .filter_map(|value| serde_json::from_value(value).ok())
+            .collect();
+
+        if events.is_empty() {
+            return Err(ApplicationError::ValidationFailed(vec![format!(
+                "仕訳が見つかりません: {}",
+                entry_id
+            )]));
+        }
+        if events.iter().any(|event| matches!(event, JournalEntryEvent::Deleted { .. })) {
+            return Err(ApplicationError::ValidationFailed(vec![format!(
+                "削除された仕訳には注記できません: {}",
+                entry_id
+            )]));
+        }
+        Ok(events)
+    }
+}
+
+/// 最新の明細番号
+fn line_numbers(events: &[JournalEntryEvent]) -> Vec<u32> {
+    let mut numbers = Vec::new();
+    for event in events {
+        match event {
+            JournalEntryEvent::DraftCreated { lines, .. }
+            | JournalEntryEvent::DraftUpdated { lines: Some(lines), .. } => {
+                numbers = lines.iter().map(|line| line.line_number).collect();
+            }
+            _ => {}
+        }
+    }
+    numbers
+}
+
+#[cfg(test)]
+mod tests {
+
+    use javelin_domain::financial_close::journal_entry::events::JournalEntryLineDto;
+
+    use super::*;
+    use crate::testkit::InMemoryEventRepository;
+
+    fn line(line_number: u32) -> JournalEntryLineDto {
+        JournalEntryLineDto {
+            line_number,
+            side: if line_number == 1 { "Debit" } else { "Credit" }.to_string(),
+            account_code: "1100".to_string(),
+            account_name: None,
+            sub_account_code: None,
+            department_code: None,
+            dimensions: Default::default(),
+            amount: 1000.0,
+            currency: "JPY".to_string(),
+            tax_type: "NonTaxable".to_string(),
+            tax_amount: 0.0,
+            description: None,
+        }
+    }
+
+    async fn repository_with_entry(entry_id: &str) -> Arc<InMemoryEventRepository> {
+        let repository = Arc::new(InMemoryEventRepository::default());
+        let created = JournalEntryEvent::DraftCreated {
+            entry_id: entry_id.to_string(),
+            transaction_date: "2024-04-01".to_string(),
+            voucher_number: "V-001".to_string(),
+            lines: vec![line(1), line(2)],
+            description: None,
+            created_by: "clerk".to_string(),
+            created_at: Utc::now(),
+        };
+        repository.append_events(entry_id, vec![created]).await.unwrap();
+        repository
+    }
+
+    #[tokio::test]
+    async fn test_annotate_and_resolve_line() {
+        let repository = repository_with_entry("JE001").await;
+        let interactor = LedgerAnnotationInteractor::new(Arc::clone(&repository));
+
+        let response = interactor
+            .annotate(AnnotateEntryLineRequest {
+                entry_id: "JE001".to_string(),
+                line_number: 2,
+                note: "請求書を確認".to_string(),
+                user_id: "reviewer".to_string(),
+            })
+            .await
+            .unwrap();
+        assert_eq!(response.open_count(), 1);
+        let annotation = &response.annotations[0];
+        assert_eq!(annotation.line_number, 2);
+        assert!(annotation.annotation_id.starts_with("N-"));
+
+        let response = interactor
+            .resolve(ResolveAnnotationRequest {
+                entry_id: "JE001".to_string(),
+                annotation_id: annotation.annotation_id.clone(),
+                resolution: "請求書と一致".to_string(),
+                user_id: "clerk".to_string(),
+            })
+            .await
+            .unwrap();
+        assert_eq!(response.open_count(), 0);
+        assert_eq!(response.annotations[0].resolved_by.as_deref(), Some("clerk"));
+        assert!(response.annotations[0].resolved_at.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_rejects_unknown_line_and_deleted_entry() {
+        let repository = repository_with_entry("JE001").await;
+        let interactor = LedgerAnnotationInteractor::new(Arc::clone(&repository));
+        let request = |entry_id: &str, line_number| AnnotateEntryLineRequest {
+            entry_id: entry_id.to_string(),
+            line_number,
+            note: "確認".to_string(),
+            user_id: "reviewer".to_string(),
+        };
+
+        assert!(interactor.annotate(request("JE001", 3)).await.is_err());
+        assert!(interactor.annotate(request("JE999", 1)).await.is_err());
+
+        let deleted = JournalEntryEvent::Deleted {
+            entry_id: "JE001".to_string(),
+            deleted_by: "clerk".to_string(),
+            deleted_at: Utc::now(),
+        };
+        repository.append_events("JE001", vec![deleted]).await.unwrap();
+        assert!(interactor.annotate(request("JE001", 1)).await.is_err());
+    }
+}
@@ -0,0 +1,239 @@
+// ManagementAccountMappingInteractor - 管理会計科目マッピング操作のユースケース
+// 責務: 勘定科目と管理会計科目の割当と、試算表の管理会計科目体系への集計し直し
+
+use std::{collections::BTreeMap, sync::Arc};
+
+use javelin_domain::{
+    masters::{AccountCode, ManagementAccount, ManagementAccountMapping},
+    repositories::ManagementAccountMappingRepository,
+};
+
+use crate::{
+    dtos::{
+        CurrencyTrialBalanceDto,
+        request::{AssignManagementAccountRequest, RemoveManagementAccountMappingRequest},
+        response::{
+            LoadManagementAccountMappingResponse, ManagementAccountMappingItem,
+            MappedTrialBalanceLineDto, MappedTrialBalanceResponse,
+        },
+    },
+    error::ApplicationResult,
+    query_service::MasterDataLoaderService,
+};
+
+/// 管理会計科目マッピング操作のInteractor
+pub struct ManagementAccountMappingInteractor<R, L>
+where
+    R: ManagementAccountMappingRepository,
+    L: MasterDataLoaderService,
+{
+    repository: Arc<R>,
+    master_data_loader: Arc<L>,
+}
+
+impl<R, L> ManagementAccountMappingInteractor<R, L>
+where
+    R: ManagementAccountMappingRepository,
+    L: MasterDataLoaderService,
+{
+    pub fn new(repository: Arc<R>, master_data_loader: Arc<L>) -> Self {
+        Self { repository, master_data_loader }
+    }
+
+    /// 勘定科目マスタと突き合わせた割当状況を取得
+    ///
+    /// マッピングのみ存在する勘定科目（マスタから削除済み）は除外する。
+    pub async fn load(&self) -> ApplicationResult<LoadManagementAccountMappingResponse> {
+        let master_data = self.master_data_loader.load_master_data().await?;
+        let mappings = self.repository.find_all().await?;
+
+        let mut items: Vec<ManagementAccountMappingItem> = master_data
+            .accounts
+            .into_iter()
+            .map(|account| {
+                let management_account = mappings
+                    .iter()
+                    .find(|mapping| mapping.account_code().value() == account.code)
+                    .map(|mapping| mapping.management_account());
+                ManagementAccountMappingItem {
+                    account_code: account.code,
+                    account_name: account.name,
+                    management_account_code: management_account.map(|a| a.code().to_string()),
+                    management_account_name: management_account.map(|a| a.name().to_string()),
+                }
+            })
+            .collect();
+
+        items.sort_by(|a, b| a.account_code.cmp(&b.account_code));
+
+        Ok(LoadManagementAccountMappingResponse { items })
+    }
+
+    /// 勘定科目に管理会計科目を割り当てる（既存の割当は置き換える）
+    pub async fn assign(&self, request: AssignManagementAccountRequest) -> ApplicationResult<()> {
+        let account_code = AccountCode::new(&request.account_code)?;
+        let management_account = ManagementAccount::new(
+            &request.management_account_code,
+            &request.management_account_name,
+        )?;
+
+        let mapping = match self.repository.find_by_account(&account_code).await? {
+            Some(mut mapping) => {
+                mapping.reassign(management_account);
+                mapping
+            }
+            None => ManagementAccountMapping::new(account_code, management_account),
+        };
+
+        Ok(self.repository.save(&mapping).await?)
+    }
+
+    /// 勘定科目の管理会計科目割当を解除
+    pub async fn remove(
+        &self,
+        request: RemoveManagementAccountMappingRequest,
+    ) -> ApplicationResult<()> {
+        let account_code = AccountCode::new(&request.account_code)?;
+        Ok(self.repository.delete(&account_code).await?)
+    }
+
+    /// 試算表を管理会計科目体系で集計し直す
+    pub async fn map_trial_balance(
+        &self,
+        trial_balance: &CurrencyTrialBalanceDto,
+    ) -> ApplicationResult<MappedTrialBalanceResponse> {
+        let mappings = self.repository.find_all().await?;
+        Ok(map_trial_balance(trial_balance, &mappings))
+    }
+}
+
+/// 勘定科目ごとの明細行を管理会計科目ごとに合算する
+///
+/// 合算は明細行の単純合計のため、借方・貸方の合計は元の試算表と一致する。
+fn map_trial_balance(
+    trial_balance: &CurrencyTrialBalanceDto,
+    mappings: &[ManagementAccountMapping],
+) -> MappedTrialBalanceResponse {
+    let management_accounts: BTreeMap<&str, &ManagementAccount> = mappings
+        .iter()
+        .map(|mapping| (mapping.account_code().value(), mapping.management_account()))
+        .collect();
+
+    let mut mapped: BTreeMap<String, MappedTrialBalanceLineDto> = BTreeMap::new();
+    let mut unmapped = Vec::new();
+    for line in &trial_balance.lines {
+        let Some(management_account) = management_accounts.get(line.account_code.as_str()) else {
+            unmapped.push(MappedTrialBalanceLineDto {
+                account_code: line.account_code.clone(),
+                account_name: line.account_name.clone(),
+                source_account_codes: vec![line.account_code.clone()],
+                mapped: false,
+                opening_balance: line.opening_balance,
+                debit_amount: line.debit_amount,
+                credit_amount: line.credit_amount,
+                closing_balance: line.closing_balance,
+            });
+            continue;
+        };
+
+        let entry = mapped.entry(management_account.code().to_string()).or_insert_with(|| {
+            MappedTrialBalanceLineDto {
+                account_code: management_account.code().to_string(),
+                account_name: management_account.name().to_string(),
+                source_account_codes: Vec::new(),
+                mapped: true,
+                opening_balance: 0.0,
+                debit_amount: 0.0,
+                credit_amount: 0.0,
+                closing_balance: 0.0,
+            }
+        });
+        entry.source_account_codes.push(line.account_code.clone());
+        entry.opening_balance += line.opening_balance;
+        entry.debit_amount += line.debit_amount;
+        entry.credit_amount += line.credit_amount;
+        entry.closing_balance += line.closing_balance;
+    }
+
+    unmapped.sort_by(|a, b| a.account_code.cmp(&b.account_code));
+    let unmapped_account_count = unmapped.len();
+
+    MappedTrialBalanceResponse {
+        currency: trial_balance.currency.clone(),
+        lines: mapped.into_values().chain(unmapped).collect(),
+        total_debit: trial_balance.total_debit,
+        total_credit: trial_balance.total_credit,
+        unmapped_account_count,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dtos::{TrialBalanceLineDto, response::TrialBalanceProofDto};
+
+    fn line(account_code: &str, debit: f64, credit: f64) -> TrialBalanceLineDto {
+        TrialBalanceLineDto {
+            account_code: account_code.to_string(),
+            account_name: format!("科目{}", account_code),
+            opening_balance: 100.0,
+            debit_amount: debit,
+            credit_amount: credit,
+            closing_balance: 100.0 + debit - credit,
+            exchange_rate: 1.0,
+        }
+    }
+
+    fn mapping(account_code: &str, code: &str, name: &str) -> ManagementAccountMapping {
+        ManagementAccountMapping::new(
+            AccountCode::new(account_code).unwrap(),
+            ManagementAccount::new(code, name).unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_map_trial_balance_sums_lines_by_management_account() {
+        let trial_balance = CurrencyTrialBalanceDto {
+            currency: "JPY".to_string(),
+            lines: vec![
+                line("5100", 300.0, 0.0),
+                line("5110", 200.0, 50.0),
+                line("1000", 0.0, 450.0),
+            ],
+            total_debit: 500.0,
+            total_credit: 500.0,
+            proof: TrialBalanceProofDto {
+                debit_total: 500.0,
+                credit_total: 500.0,
+                difference: 0.0,
+                cross_foot_difference: 0.0,
+                account_count: 3,
+                rows_hash: String::new(),
+                footed: true,
+            },
+            hierarchy: vec![],
+        };
+        let mapped = map_trial_balance(
+            &trial_balance,
+            &[mapping("5100", "M510", "人件費"), mapping("5110", "M510", "人件費")],
+        );
+
+        assert_eq!(mapped.lines.len(), 2);
+        let personnel = &mapped.lines[0];
+        assert_eq!(personnel.account_code, "M510");
+        assert_eq!(personnel.source_account_codes, vec!["5100", "5110"]);
+        assert_eq!(personnel.opening_balance, 200.0);
+        assert_eq!(personnel.debit_amount, 500.0);
+        assert_eq!(personnel.credit_amount, 50.0);
+        assert_eq!(personnel.closing_balance, 650.0);
+
+        // 未割当の勘定科目は勘定科目のまま末尾に残す
+        assert!(!mapped.lines[1].mapped);
+        assert_eq!(mapped.lines[1].account_code, "1000");
+        assert_eq!(mapped.unmapped_account_count, 1);
+
+        let debit: f64 = mapped.lines.iter().map(|l| l.debit_amount).sum();
+        let credit: f64 = mapped.lines.iter().map(|l| l.credit_amount).sum();
+        assert_eq!((debit, credit), (500.0, 500.0));
+    }
+}
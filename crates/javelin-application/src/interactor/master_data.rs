@@ -2,12 +2,14 @@
 
 mod load_account_master_interactor;
 mod load_application_settings_interactor;
+mod load_calendar_master_interactor;
 mod load_company_master_interactor;
 mod load_subsidiary_account_master_interactor;
 mod record_user_action_interactor;
 
 pub use load_account_master_interactor::LoadAccountMasterInteractor;
 pub use load_application_settings_interactor::LoadApplicationSettingsInteractor;
+pub use load_calendar_master_interactor::LoadCalendarMasterInteractor;
 pub use load_company_master_interactor::LoadCompanyMasterInteractor;
 pub use load_subsidiary_account_master_interactor::LoadSubsidiaryAccountMasterInteractor;
 pub use record_user_action_interactor::RecordUserActionInteractor;
@@ -5,7 +5,9 @@ use crate::{
     error::ApplicationResult,
     input_ports::LoadAccountMasterInputPort,
     output_port::AccountMasterOutputPort,
-    query_service::master_data_loader::MasterDataLoaderService,
+    query_service::{
+        account_master_cache::AccountMasterCache, master_data_loader::MasterDataLoaderService,
+    },
 };
 
 /// 勘定科目マスタ取得Interactor
@@ -16,6 +18,8 @@ where
 {
     query_service: std::sync::Arc<Q>,
     output_port: O,
+    /// 勘定科目マスタのキャッシュ（未設定なら毎回ロード）
+    cache: Option<std::sync::Arc<AccountMasterCache>>,
 }
 
 impl<Q, O> LoadAccountMasterInteractor<Q, O>
@@ -24,7 +28,13 @@ where
     O: AccountMasterOutputPort,
 {
     pub fn new(query_service: std::sync::Arc<Q>, output_port: O) -> Self {
-        Self { query_service, output_port }
+        Self { query_service, output_port, cache: None }
+    }
+
+    /// 勘定科目マスタのキャッシュを設定
+    pub fn with_cache(mut self, cache: std::sync::Arc<AccountMasterCache>) -> Self {
+        self.cache = Some(cache);
+        self
     }
 }
 
@@ -38,13 +48,15 @@ where
         &self,
         request: LoadAccountMasterRequest,
     ) -> ApplicationResult<LoadAccountMasterResponse> {
-        // マスタデータを取得
-        let master_data = self.query_service.load_master_data().await?;
+        // 勘定科目マスタを取得（キャッシュがあれば再利用）
+        let master_accounts = match &self.cache {
+            Some(cache) => cache.get_or_load(self.query_service.as_ref()).await?,
+            None => std::sync::Arc::new(self.query_service.load_master_data().await?.accounts),
+        };
 
         // フィルタリング
-        let mut accounts: Vec<AccountMasterItem> = master_data
-            .accounts
-            .into_iter()
+        let mut accounts: Vec<AccountMasterItem> = master_accounts
+            .iter()
             .filter(|acc| {
                 // アクティブフィルタ
                 if request.active_only && !acc.is_active {
@@ -59,9 +71,10 @@ where
                 true
             })
             .map(|acc| AccountMasterItem {
-                code: acc.code,
-                name: acc.name,
+                code: acc.code.clone(),
+                name: acc.name.clone(),
                 account_type: format!("{:?}", acc.account_type),
+                is_active: acc.is_active,
             })
             .collect();
 
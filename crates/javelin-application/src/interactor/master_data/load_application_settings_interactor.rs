@@ -50,6 +50,8 @@ where
             language: master_data.user_options.language,
             decimal_places: master_data.user_options.decimal_places,
             date_format: master_data.user_options.date_format,
+            batch_notification_bell: master_data.user_options.batch_notification_bell,
+            batch_notification_desktop: master_data.user_options.batch_notification_desktop,
         };
 
         let system_settings = SystemSettingsDto {
@@ -57,6 +59,19 @@ where
             closing_day: master_data.system_settings.closing_day,
             auto_backup_enabled: master_data.system_settings.auto_backup_enabled,
             backup_retention_days: master_data.system_settings.backup_retention_days,
+            approval_sla_warning_hours: master_data.system_settings.approval_sla_warning_hours,
+            approval_sla_breach_hours: master_data.system_settings.approval_sla_breach_hours,
+            report_delivery: master_data.system_settings.report_delivery.clone(),
+            export_encryption_enabled: master_data.system_settings.export_encryption_enabled,
+            export_signature_enabled: master_data.system_settings.export_signature_enabled,
+            deleted_entry_retain_enabled: master_data.system_settings.deleted_entry_retain_enabled,
+            deleted_entry_purge_after_months: master_data
+                .system_settings
+                .deleted_entry_purge_after_months,
+            closing_lock_disabled_checks: master_data
+                .system_settings
+                .closing_lock_disabled_checks
+                .clone(),
         };
 
         let response = LoadApplicationSettingsResponse { user_options, system_settings };
@@ -0,0 +1,69 @@
+// LoadCalendarMasterInteractor - カレンダーマスタ取得Interactor
+
+use crate::{
+    dtos::{
+        request::LoadCalendarMasterRequest,
+        response::{HolidayItem, LoadCalendarMasterResponse},
+    },
+    error::ApplicationResult,
+    input_ports::LoadCalendarMasterInputPort,
+    output_port::CalendarMasterOutputPort,
+    query_service::master_data_loader::MasterDataLoaderService,
+};
+
+/// カレンダーマスタ取得Interactor
+pub struct LoadCalendarMasterInteractor<Q, O>
+where
+    Q: MasterDataLoaderService,
+    O: CalendarMasterOutputPort,
+{
+    query_service: std::sync::Arc<Q>,
+    output_port: O,
+}
+
+impl<Q, O> LoadCalendarMasterInteractor<Q, O>
+where
+    Q: MasterDataLoaderService,
+    O: CalendarMasterOutputPort,
+{
+    pub fn new(query_service: std::sync::Arc<Q>, output_port: O) -> Self {
+        Self { query_service, output_port }
+    }
+}
+
+#[allow(async_fn_in_trait)]
+impl<Q, O> LoadCalendarMasterInputPort for LoadCalendarMasterInteractor<Q, O>
+where
+    Q: MasterDataLoaderService,
+    O: CalendarMasterOutputPort,
+{
+    async fn execute(
+        &self,
+        request: LoadCalendarMasterRequest,
+    ) -> ApplicationResult<LoadCalendarMasterResponse> {
+        // マスタデータを取得
+        let master_data = self.query_service.load_master_data().await?;
+
+        // 年でフィルタリング
+        let year_prefix = request.year.map(|year| format!("{:04}-", year));
+        let holidays: Vec<HolidayItem> = master_data
+            .holidays
+            .into_iter()
+            .filter(|holiday| match &year_prefix {
+                Some(prefix) => holiday.date.starts_with(prefix),
+                None => true,
+            })
+            .map(|holiday| HolidayItem { date: holiday.date, name: holiday.name })
+            .collect();
+
+        let response = LoadCalendarMasterResponse {
+            holidays,
+            fiscal_year_start_month: master_data.system_settings.fiscal_year_start_month,
+        };
+
+        // Output Portに通知
+        self.output_port.present_calendar_master(&response).await;
+
+        Ok(response)
+    }
+}
@@ -1,26 +1,35 @@
 // RecordUserActionInteractor - ユーザ操作記録ユースケース実装
-// 責務: ユーザ操作をLMDBに記録
+// 責務: ユーザ操作をLMDBに記録し、利用状況の集計に反映
 
 use std::{future::Future, pin::Pin, sync::Arc};
 
 use chrono::Utc;
-use javelin_domain::repositories::UserActionRepository;
+use javelin_domain::{
+    masters::{UserAction, UserActionKind},
+    repositories::UserActionRepository,
+};
 
 use crate::{
-    dtos::{RecordUserActionRequest, RecordUserActionResponse},
+    dtos::{RecordUserActionRequest, RecordUserActionResponse, request::RecordedActionKind},
     error::{ApplicationError, ApplicationResult},
     input_ports::RecordUserActionUseCase,
     output_port::{EventNotification, EventOutputPort},
+    user_activity::UserActivityProjection,
 };
 
 pub struct RecordUserActionInteractor<R: UserActionRepository, E: EventOutputPort> {
     repository: Arc<R>,
+    activity: Arc<dyn UserActivityProjection>,
     event_output: Arc<E>,
 }
 
 impl<R: UserActionRepository, E: EventOutputPort> RecordUserActionInteractor<R, E> {
-    pub fn new(repository: Arc<R>, event_output: Arc<E>) -> Self {
-        Self { repository, event_output }
+    pub fn new(
+        repository: Arc<R>,
+        activity: Arc<dyn UserActivityProjection>,
+        event_output: Arc<E>,
+    ) -> Self {
+        Self { repository, activity, event_output }
     }
 }
 
@@ -33,16 +42,28 @@ impl<R: UserActionRepository, E: EventOutputPort> RecordUserActionUseCase
     ) -> Pin<Box<dyn Future<Output = ApplicationResult<RecordUserActionResponse>> + Send + '_>>
     {
         Box::pin(async move {
+            let kind = match request.kind {
+                RecordedActionKind::ScreenVisited => UserActionKind::ScreenVisited,
+                RecordedActionKind::ActionPerformed => UserActionKind::ActionPerformed,
+            };
+            let action = UserAction::new(
+                request.user.as_str(),
+                kind,
+                request.location.as_str(),
+                request.action.as_str(),
+                request.duration_ms,
+            )?;
+
             // リポジトリに保存
-            match self
-                .repository
-                .save_action(&request.user, &request.location, &request.action)
-                .await
-            {
+            match self.repository.save_action(&action).await {
                 Ok(action_id) => {
+                    let recorded_at = Utc::now();
+                    // 集計の失敗で操作記録を失敗させない
+                    let _ = self.activity.apply(&action, recorded_at).await;
+
                     let response = RecordUserActionResponse {
                         action_id: action_id.clone(),
-                        recorded_at: Utc::now().to_string(),
+                        recorded_at: recorded_at.to_string(),
                     };
 
                     // 成功をイベントビューアに通知
@@ -88,14 +109,36 @@ mod tests {
     impl UserActionRepository for MockRepository {
         async fn save_action(
             &self,
-            _user: &str,
-            _location: &str,
-            _action: &str,
+            _action: &UserAction,
         ) -> Result<String, Box<dyn std::error::Error + Send + Sync>> {
             Ok("test-action-id".to_string())
         }
     }
 
+    #[derive(Default)]
+    struct MockActivity {
+        applied: std::sync::Mutex<Vec<UserAction>>,
+    }
+
+    #[async_trait::async_trait]
+    impl UserActivityProjection for MockActivity {
+        async fn apply(
+            &self,
+            action: &UserAction,
+            _at: chrono::DateTime<Utc>,
+        ) -> ApplicationResult<()> {
+            self.applied.lock().unwrap().push(action.clone());
+            Ok(())
+        }
+
+        async fn daily_activity(
+            &self,
+            _from: chrono::NaiveDate,
+        ) -> ApplicationResult<Vec<crate::user_activity::DailyUserActivity>> {
+            Ok(vec![])
+        }
+    }
+
     struct MockEventOutput {
         events: std::sync::Mutex<Vec<EventNotification>>,
     }
@@ -119,18 +162,32 @@ mod tests {
     #[tokio::test]
     async fn test_record_user_action_success() {
         let repository = Arc::new(MockRepository);
+        let activity = Arc::new(MockActivity::default());
         let event_output = Arc::new(MockEventOutput::new());
-        let interactor = RecordUserActionInteractor::new(repository, Arc::clone(&event_output));
+        let interactor = RecordUserActionInteractor::new(
+            repository,
+            Arc::clone(&activity) as Arc<dyn UserActivityProjection>,
+            Arc::clone(&event_output),
+        );
 
         let request = RecordUserActionRequest {
             user: "test_user".to_string(),
+            kind: RecordedActionKind::ActionPerformed,
             location: "HomePage".to_string(),
-            action: "メニュー選択".to_string(),
+            action: "menu_select".to_string(),
+            duration_ms: 120,
         };
 
-        let result = interactor.execute(request).await;
+        let result = interactor.execute(request.clone()).await;
 
         assert!(result.is_ok());
+        assert_eq!(activity.applied.lock().unwrap()[0].duration_ms(), 120);
+
+        // 入力値などの自由記述は記録しない
+        let free_text =
+            RecordUserActionRequest { action: "メニュー選択".to_string(), ..request };
+        assert!(interactor.execute(free_text).await.is_err());
+        assert_eq!(activity.applied.lock().unwrap().len(), 1);
 
         // イベント通知を確認
         let events = event_output.get_events();
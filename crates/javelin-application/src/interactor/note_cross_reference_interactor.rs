@@ -0,0 +1,214 @@
+// NoteCrossReferenceInteractor - 注記参照のユースケース
+// 責務: 注記の登録・削除と、表示科目・勘定科目から注記への参照の登録・解除
+
+use std::sync::Arc;
+
+use javelin_domain::{
+    masters::{NoteCrossReference, NoteReferenceTarget, NoteSection},
+    repositories::NoteCrossReferenceRepository,
+};
+
+use crate::{
+    dtos::{
+        request::{
+            DeleteNoteCrossReferenceRequest, DeleteNoteSectionRequest,
+            SaveNoteCrossReferenceRequest, SaveNoteSectionRequest,
+        },
+        response::{LoadNoteCrossReferencesResponse, NoteReferenceItem, NoteSectionItem},
+    },
+    error::{ApplicationError, ApplicationResult},
+};
+
+/// 注記参照のInteractor
+pub struct NoteCrossReferenceInteractor<R>
+where
+    R: NoteCrossReferenceRepository,
+{
+    repository: Arc<R>,
+}
+
+impl<R> NoteCrossReferenceInteractor<R>
+where
+    R: NoteCrossReferenceRepository,
+{
+    pub fn new(repository: Arc<R>) -> Self {
+        Self { repository }
+    }
+
+    /// 注記と参照の一覧を取得
+    pub async fn load(&self) -> ApplicationResult<LoadNoteCrossReferencesResponse> {
+        let references = self.repository.find_references().await?;
+        let sections = self
+            .repository
+            .find_sections()
+            .await?
+            .into_iter()
+            .map(|section| NoteSectionItem {
+                number: section.number(),
+                title: section.title().to_string(),
+                body: section.body().to_string(),
+                is_drafted: section.is_drafted(),
+                references: references
+                    .iter()
+                    .filter(|reference| reference.note_number() == section.number())
+                    .map(|reference| NoteReferenceItem {
+                        target: reference.target().code().to_string(),
+                        target_label: reference.target().label(),
+                        required: reference.is_required(),
+                    })
+                    .collect(),
+            })
+            .collect();
+
+        Ok(LoadNoteCrossReferencesResponse { sections })
+    }
+
+    /// 注記を保存（既存の注記番号の場合は置き換える）
+    pub async fn save_section(&self, request: SaveNoteSectionRequest) -> ApplicationResult<()> {
+        let section = NoteSection::new(request.number, request.title, request.body)?;
+        Ok(self.repository.save_section(&section).await?)
+    }
+
+    /// 注記を削除（参照されている注記は削除できない）
+    pub async fn delete_section(&self, request: DeleteNoteSectionRequest) -> ApplicationResult<()> {
+        let references = self.repository.find_references().await?;
+        if references.iter().any(|reference| reference.note_number() == request.number) {
+            return Err(ApplicationError::ValidationError(format!(
+                "注記{}は参照されているため削除できません",
+                request.number
+            )));
+        }
+        Ok(self.repository.delete_section(request.number).await?)
+    }
+
+    /// 注記参照を保存（参照先の注記は登録済みであること）
+    pub async fn save_reference(
+        &self,
+        request: SaveNoteCrossReferenceRequest,
+    ) -> ApplicationResult<()> {
+        let target = NoteReferenceTarget::parse(&request.target)?;
+        let reference = NoteCrossReference::new(target, request.note_number, request.required)?;
+        let sections = self.repository.find_sections().await?;
+        if !sections.iter().any(|section| section.number() == reference.note_number()) {
+            return Err(ApplicationError::ValidationError(format!(
+                "注記{}が登録されていません",
+                reference.note_number()
+            )));
+        }
+        Ok(self.repository.save_reference(&reference).await?)
+    }
+
+    /// 注記参照を解除
+    pub async fn delete_reference(
+        &self,
+        request: DeleteNoteCrossReferenceRequest,
+    ) -> ApplicationResult<()> {
+        let target = NoteReferenceTarget::parse(&request.target)?;
+        Ok(self.repository.delete_reference(&target, request.note_number).await?)
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::sync::Mutex;
+
+    use javelin_domain::error::DomainResult;
+
+    use super::*;
+
+    #[derive(Default)]
+    pub(crate) struct InMemoryNoteCrossReferenceRepository {
+        sections: Mutex<Vec<NoteSection>>,
+        references: Mutex<Vec<NoteCrossReference>>,
+    }
+
+    impl NoteCrossReferenceRepository for InMemoryNoteCrossReferenceRepository {
+        async fn find_sections(&self) -> DomainResult<Vec<NoteSection>> {
+            Ok(self.sections.lock().unwrap().clone())
+        }
+
+        async fn save_section(&self, section: &NoteSection) -> DomainResult<()> {
+            let mut sections = self.sections.lock().unwrap();
+            sections.retain(|existing| existing.number() != section.number());
+            sections.push(section.clone());
+            sections.sort_by_key(NoteSection::number);
+            Ok(())
+        }
+
+        async fn delete_section(&self, number: u32) -> DomainResult<()> {
+            self.sections.lock().unwrap().retain(|section| section.number() != number);
+            Ok(())
+        }
+
+        async fn find_references(&self) -> DomainResult<Vec<NoteCrossReference>> {
+            Ok(self.references.lock().unwrap().clone())
+        }
+
+        async fn save_reference(&self, reference: &NoteCrossReference) -> DomainResult<()> {
+            let mut references = self.references.lock().unwrap();
+            references.retain(|existing| {
+                !(existing.target() == reference.target()
+                    && existing.note_number() == reference.note_number())
+            });
+            references.push(reference.clone());
+            Ok(())
+        }
+
+        async fn delete_reference(
+            &self,
+            target: &NoteReferenceTarget,
+            note_number: u32,
+        ) -> DomainResult<()> {
+            self.references.lock().unwrap().retain(|reference| {
+                !(reference.target() == target && reference.note_number() == note_number)
+            });
+            Ok(())
+        }
+    }
+
+    fn section(number: u32, title: &str, body: &str) -> SaveNoteSectionRequest {
+        SaveNoteSectionRequest { number, title: title.to_string(), body: body.to_string() }
+    }
+
+    fn reference(target: &str, note_number: u32, required: bool) -> SaveNoteCrossReferenceRequest {
+        SaveNoteCrossReferenceRequest { target: target.to_string(), note_number, required }
+    }
+
+    #[tokio::test]
+    async fn test_references_are_grouped_under_sections() {
+        let interactor = NoteCrossReferenceInteractor::new(Arc::new(
+            InMemoryNoteCrossReferenceRepository::default(),
+        ));
+        interactor.save_section(section(5, "収益認識", "")).await.unwrap();
+        interactor
+            .save_section(section(2, "棚卸資産", "総平均法による原価法"))
+            .await
+            .unwrap();
+
+        interactor.save_reference(reference("PL-REV", 5, true)).await.unwrap();
+        interactor.save_reference(reference("1300", 2, false)).await.unwrap();
+        // 未登録の注記は参照できない
+        assert!(interactor.save_reference(reference("BS-CA", 9, false)).await.is_err());
+
+        let response = interactor.load().await.unwrap();
+        let numbers: Vec<u32> = response.sections.iter().map(|s| s.number).collect();
+        assert_eq!(numbers, vec![2, 5]);
+        assert!(response.sections[0].is_drafted);
+        assert_eq!(response.sections[0].references[0].target_label, "勘定科目 1300");
+        assert!(!response.sections[1].is_drafted);
+        assert_eq!(response.sections[1].references[0].target_label, "売上収益");
+        assert!(response.sections[1].references[0].required);
+
+        // 参照されている注記は削除できない
+        assert!(interactor.delete_section(DeleteNoteSectionRequest { number: 5 }).await.is_err());
+        interactor
+            .delete_reference(DeleteNoteCrossReferenceRequest {
+                target: "PL-REV".to_string(),
+                note_number: 5,
+            })
+            .await
+            .unwrap();
+        interactor.delete_section(DeleteNoteSectionRequest { number: 5 }).await.unwrap();
+        assert_eq!(interactor.load().await.unwrap().sections.len(), 1);
+    }
+}
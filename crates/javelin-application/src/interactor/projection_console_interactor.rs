@@ -0,0 +1,198 @@
+// ProjectionConsoleInteractor - Projection照会コンソールのユースケース
+// 責務: 管理者による読み取りモデルの生データ参照（キー前方一致・ページング）
+
+use std::sync::Arc;
+
+use javelin_domain::{error::DomainError, repositories::AccountingPolicyRepository};
+
+use crate::{
+    dtos::{
+        request::QueryProjectionRequest,
+        response::{ProjectionRecordItem, QueryProjectionResponse},
+    },
+    error::{ApplicationError, ApplicationResult},
+    query_service::{ProjectionInspectionQueryService, ProjectionRecord},
+};
+
+/// 1ページの最大件数
+const MAX_PAGE_SIZE: usize = 500;
+
+/// Projection照会コンソールのInteractor
+///
+/// 読み取り専用。照会できるのは会計方針の管理者に限る。
+pub struct ProjectionConsoleInteractor<Q, R>
+where
+    Q: ProjectionInspectionQueryService,
+    R: AccountingPolicyRepository,
+{
+    query_service: Arc<Q>,
+    policy_repository: Arc<R>,
+}
+
+impl<Q, R> ProjectionConsoleInteractor<Q, R>
+where
+    Q: ProjectionInspectionQueryService,
+    R: AccountingPolicyRepository,
+{
+    pub fn new(query_service: Arc<Q>, policy_repository: Arc<R>) -> Self {
+        Self { query_service, policy_repository }
+    }
+
+    pub async fn execute(
+        &self,
+        request: QueryProjectionRequest,
+    ) -> ApplicationResult<QueryProjectionResponse> {
+        let policy = self.policy_repository.load().await?;
+        if !policy.is_administrator(&request.user_id) {
+            return Err(ApplicationError::DomainError(DomainError::PermissionDenied(format!(
+                "Projection照会は管理者のみ可能です（ユーザ: {}）",
+                request.user_id
+            ))));
+        }
+
+        let prefix = request.prefix.trim();
+        if prefix.is_empty() {
+            return Err(ApplicationError::ValidationError(
+                "キーの前方一致条件を入力してください".to_string(),
+            ));
+        }
+
+        // 次ページの有無を判定するため1件多く取得する
+        let page_size = request.page_size.clamp(1, MAX_PAGE_SIZE);
+        let mut records =
+            self.query_service.scan(prefix, request.after.as_deref(), page_size + 1).await?;
+        let has_more = records.len() > page_size;
+        records.truncate(page_size);
+
+        let next_after = if has_more {
+            records.last().map(|record| record.key.clone())
+        } else {
+            None
+        };
+
+        Ok(QueryProjectionResponse {
+            prefix: prefix.to_string(),
+            records: records.into_iter().map(decode).collect(),
+            next_after,
+        })
+    }
+}
+
+/// 値をJSONとして整形（JSONでない場合はテキスト、テキストでもない場合はバイト数）
+fn decode(record: ProjectionRecord) -> ProjectionRecordItem {
+    let size = record.value.len();
+    let value = match serde_json::from_slice::<serde_json::Value>(&record.value) {
+        Ok(json) => serde_json::to_string_pretty(&json).unwrap_or_default(),
+        Err(_) => match String::from_utf8(record.value) {
+            Ok(text) => text,
+            Err(_) => format!("<binary {} bytes>", size),
+        },
+    };
+    ProjectionRecordItem { key: record.key, value, size }
+}
+
+#[cfg(test)]
+mod tests {
+    use javelin_domain::{
+        error::DomainResult,
+        masters::{AccountingPolicy, AccountingPolicyChanged, DEFAULT_POLICY_ADMINISTRATOR},
+    };
+
+    use super::*;
+
+    struct MockQueryService {
+        records: Vec<ProjectionRecord>,
+    }
+
+    impl ProjectionInspectionQueryService for MockQueryService {
+        async fn scan(
+            &self,
+            prefix: &str,
+            after: Option<&str>,
+            limit: usize,
+        ) -> ApplicationResult<Vec<ProjectionRecord>> {
+            Ok(self
+                .records
+                .iter()
+                .filter(|record| record.key.starts_with(prefix))
+                .filter(|record| after.is_none_or(|after| record.key.as_str() > after))
+                .take(limit)
+                .cloned()
+                .collect())
+        }
+    }
+
+    struct MockPolicyRepository;
+
+    impl AccountingPolicyRepository for MockPolicyRepository {
+        async fn load(&self) -> DomainResult<AccountingPolicy> {
+            Ok(AccountingPolicy::default())
+        }
+
+        async fn save(
+            &self,
+            _policy: &AccountingPolicy,
+            _change: &AccountingPolicyChanged,
+        ) -> DomainResult<()> {
+            Ok(())
+        }
+
+        async fn find_changes(&self) -> DomainResult<Vec<AccountingPolicyChanged>> {
+            Ok(Vec::new())
+        }
+    }
+
+    fn interactor() -> ProjectionConsoleInteractor<MockQueryService, MockPolicyRepository> {
+        let record = |key: &str, value: &[u8]| ProjectionRecord {
+            key: key.to_string(),
+            value: value.to_vec(),
+        };
+        let records = vec![
+            record("ledger:1000:2024:01", br#"{"balance":100}"#),
+            record("ledger:1000:2024:02", b"plain text"),
+            record("ledger:1000:2024:03", &[0xff, 0xfe]),
+            record("ledger:2000:2024:01", b"{}"),
+        ];
+        ProjectionConsoleInteractor::new(
+            Arc::new(MockQueryService { records }),
+            Arc::new(MockPolicyRepository),
+        )
+    }
+
+    fn request(user_id: &str, after: Option<&str>) -> QueryProjectionRequest {
+        QueryProjectionRequest {
+            user_id: user_id.to_string(),
+            prefix: "ledger:1000:".to_string(),
+            after: after.map(str::to_string),
+            page_size: 2,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_query_pages_and_decodes_values() {
+        let interactor = interactor();
+
+        let first = interactor.execute(request(DEFAULT_POLICY_ADMINISTRATOR, None)).await.unwrap();
+        assert_eq!(first.records.len(), 2);
+        assert_eq!(first.records[0].value, "{\n  \"balance\": 100\n}");
+        assert_eq!(first.records[1].value, "plain text");
+        assert_eq!(first.next_after.as_deref(), Some("ledger:1000:2024:02"));
+
+        let second = interactor
+            .execute(request(DEFAULT_POLICY_ADMINISTRATOR, first.next_after.as_deref()))
+            .await
+            .unwrap();
+        assert_eq!(second.records.len(), 1);
+        assert_eq!(second.records[0].value, "<binary 2 bytes>");
+        assert!(second.next_after.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_non_administrator_is_rejected() {
+        let result = interactor().execute(request("clerk", None)).await;
+        assert!(matches!(
+            result,
+            Err(ApplicationError::DomainError(DomainError::PermissionDenied(_)))
+        ));
+    }
+}
@@ -0,0 +1,264 @@
+// ReportArchiveInteractor - 帳票アーカイブのユースケース
+// 責務: 確定した帳票の承認・保存、一覧、ハッシュ検証、再生成した数値との比較
+
+use std::sync::Arc;
+
+use javelin_domain::{
+    error::DomainError,
+    financial_close::report_archive::{ArchivedReport, ReportDigester, ReportLine},
+    repositories::ReportArchiveRepository,
+};
+
+use crate::{
+    dtos::{
+        request::{ArchiveReportRequest, CompareArchivedReportRequest, OpenArchivedReportRequest},
+        response::{
+            ArchivedReportResponse, ArchivedReportSummary, ReportComparisonResponse, ReportLineDto,
+        },
+    },
+    error::ApplicationResult,
+};
+
+/// 帳票アーカイブのInteractor
+///
+/// 承認時点の数値・出力条件・承認者からハッシュ値を算出して保存する。
+/// 保存後は変更できず、開くたびにハッシュ値を再計算して改ざんの有無を確認する。
+pub struct ReportArchiveInteractor<R, D>
+where
+    R: ReportArchiveRepository,
+    D: ReportDigester,
+{
+    archive_repository: Arc<R>,
+    digester: Arc<D>,
+}
+
+impl<R, D> ReportArchiveInteractor<R, D>
+where
+    R: ReportArchiveRepository,
+    D: ReportDigester,
+{
+    pub fn new(archive_repository: Arc<R>, digester: Arc<D>) -> Self {
+        Self { archive_repository, digester }
+    }
+
+    /// 帳票を承認してアーカイブ
+    pub async fn archive(
+        &self,
+        request: ArchiveReportRequest,
+    ) -> ApplicationResult<ArchivedReportSummary> {
+        let report = ArchivedReport::sign(
+            request.report_type,
+            request.fiscal_year,
+            request.period,
+            request.parameters,
+            request.lines.iter().map(ReportLineDto::to_domain).collect(),
+            request.signed_by,
+            chrono::Utc::now(),
+            self.digester.as_ref(),
+        )?;
+
+        self.archive_repository.append(&report).await?;
+
+        Ok(ArchivedReportSummary::from_report(&report))
+    }
+
+    /// アーカイブ済み帳票の一覧（承認日時の新しい順）
+    pub async fn list(&self) -> ApplicationResult<Vec<ArchivedReportSummary>> {
+        let mut reports = self.archive_repository.find_all().await?;
+        reports.sort_by_key(|report| std::cmp::Reverse(report.signed_at()));
+        Ok(reports.iter().map(ArchivedReportSummary::from_report).collect())
+    }
+
+    /// アーカイブ済み帳票を開き、ハッシュ値を検証
+    pub async fn open(
+        &self,
+        request: OpenArchivedReportRequest,
+    ) -> ApplicationResult<ArchivedReportResponse> {
+        let report = self.find(&request.archive_id).await?;
+
+        Ok(ArchivedReportResponse {
+            summary: ArchivedReportSummary::from_report(&report),
+            parameters: report.parameters().to_vec(),
+            lines: report
+                .lines()
+                .iter()
+                .map(|line| ReportLineDto::new(line.label.clone(), line.amount))
+                .collect(),
+            hash_verified: report.verify(self.digester.as_ref()),
+        })
+    }
+
+    /// 再生成した数値と承認時点の数値を比較
+    pub async fn compare(
+        &self,
+        request: CompareArchivedReportRequest,
+    ) -> ApplicationResult<ReportComparisonResponse> {
+        let report = self.find(&request.archive_id).await?;
+        let current: Vec<ReportLine> =
+            request.current_lines.iter().map(ReportLineDto::to_domain).collect();
+
+        Ok(ReportComparisonResponse {
+            archive_id: request.archive_id,
+            differences: report.differences(&current).into_iter().map(Into::into).collect(),
+        })
+    }
+
+    async fn find(&self, archive_id: &str) -> ApplicationResult<ArchivedReport> {
+        self.archive_repository.find_by_id(archive_id).await?.ok_or_else(|| {
+            DomainError::NotFound(format!("アーカイブ済みの帳票が見つかりません: {}", archive_id))
+                .into()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use javelin_domain::error::DomainResult;
+
+    use super::*;
+    use crate::dtos::response::FINANCIAL_STATEMENTS_REPORT_TYPE;
+
+    #[derive(Default)]
+    struct MockArchiveRepository {
+        reports: Mutex<Vec<ArchivedReport>>,
+    }
+
+    impl ReportArchiveRepository for MockArchiveRepository {
+        async fn append(&self, report: &ArchivedReport) -> DomainResult<()> {
+            let mut reports = self.reports.lock().unwrap();
+            if reports.iter().any(|existing| existing.archive_id() == report.archive_id()) {
+                return Err(DomainError::ValidationError("上書きできません".to_string()));
+            }
+            reports.push(report.clone());
+            Ok(())
+        }
+
+        async fn find_by_id(&self, archive_id: &str) -> DomainResult<Option<ArchivedReport>> {
+            Ok(self
+                .reports
+                .lock()
+                .unwrap()
+                .iter()
+                .find(|report| report.archive_id() == archive_id)
+                .cloned())
+        }
+
+        async fn find_all(&self) -> DomainResult<Vec<ArchivedReport>> {
+            Ok(self.reports.lock().unwrap().clone())
+        }
+    }
+
+    /// 署名対象の内容をそのまま返す検証用のハッシュ算出
+    struct MockDigester;
+
+    impl ReportDigester for MockDigester {
+        fn digest(&self, payload: &str) -> String {
+            payload.to_string()
+        }
+    }
+
+    fn interactor() -> (
+        ReportArchiveInteractor<MockArchiveRepository, MockDigester>,
+        Arc<MockArchiveRepository>,
+    ) {
+        let repository = Arc::new(MockArchiveRepository::default());
+        (
+            ReportArchiveInteractor::new(Arc::clone(&repository), Arc::new(MockDigester)),
+            repository,
+        )
+    }
+
+    fn archive_request() -> ArchiveReportRequest {
+        ArchiveReportRequest {
+            report_type: FINANCIAL_STATEMENTS_REPORT_TYPE.to_string(),
+            fiscal_year: 2024,
+            period: 3,
+            parameters: vec![("status_scope".to_string(), "PostedOnly".to_string())],
+            lines: vec![
+                ReportLineDto::new("損益計算書/売上収益", 1_000_000.0),
+                ReportLineDto::new("損益計算書/当期純利益", 120_000.0),
+            ],
+            signed_by: "accountant".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_archive_then_open_verifies_hash() {
+        let (interactor, _) = interactor();
+
+        let summary = interactor.archive(archive_request()).await.unwrap();
+        assert_eq!(summary.report_label, "財務諸表");
+        assert_eq!(summary.signed_by, "accountant");
+
+        let opened = interactor
+            .open(OpenArchivedReportRequest { archive_id: summary.archive_id.clone() })
+            .await
+            .unwrap();
+        assert!(opened.hash_verified);
+        assert_eq!(opened.lines.len(), 2);
+        assert_eq!(opened.summary.content_hash, summary.content_hash);
+        assert_eq!(interactor.list().await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_open_detects_tampered_archive() {
+        let (interactor, repository) = interactor();
+        let summary = interactor.archive(archive_request()).await.unwrap();
+
+        {
+            let mut reports = repository.reports.lock().unwrap();
+            let original = reports.remove(0);
+            reports.push(ArchivedReport::restore(
+                original.archive_id().to_string(),
+                original.report_type().to_string(),
+                original.fiscal_year(),
+                original.period(),
+                original.parameters().to_vec(),
+                vec![ReportLine::new("損益計算書/売上収益", 9_999_999.0)],
+                original.content_hash().to_string(),
+                original.signed_by().to_string(),
+                original.signed_at(),
+            ));
+        }
+
+        let opened = interactor
+            .open(OpenArchivedReportRequest { archive_id: summary.archive_id })
+            .await
+            .unwrap();
+        assert!(!opened.hash_verified);
+    }
+
+    #[tokio::test]
+    async fn test_compare_with_regenerated_lines() {
+        let (interactor, _) = interactor();
+        let summary = interactor.archive(archive_request()).await.unwrap();
+
+        let comparison = interactor
+            .compare(CompareArchivedReportRequest {
+                archive_id: summary.archive_id,
+                current_lines: vec![
+                    ReportLineDto::new("損益計算書/売上収益", 1_000_000.0),
+                    ReportLineDto::new("損益計算書/当期純利益", 150_000.0),
+                ],
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(comparison.differences.len(), 1);
+        assert_eq!(comparison.differences[0].label, "損益計算書/当期純利益");
+        assert_eq!(comparison.differences[0].difference, 30_000.0);
+    }
+
+    #[tokio::test]
+    async fn test_open_unknown_archive() {
+        let (interactor, _) = interactor();
+
+        let result = interactor
+            .open(OpenArchivedReportRequest { archive_id: "FS-unknown".to_string() })
+            .await;
+
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,128 @@
+// ReportParameterHistoryInteractor - 帳票の最近使った条件のユースケース
+// 責務: 帳票メニューで実行した条件の記録と、次回入力時の候補の提供
+
+use std::sync::Arc;
+
+use javelin_domain::{
+    masters::ReportParameterHistory, repositories::ReportParameterHistoryRepository,
+};
+
+use crate::{
+    dtos::{request::RememberReportParametersRequest, response::RecentReportParametersResponse},
+    error::ApplicationResult,
+};
+
+/// 帳票の最近使った条件のInteractor
+pub struct ReportParameterHistoryInteractor<R>
+where
+    R: ReportParameterHistoryRepository,
+{
+    repository: Arc<R>,
+}
+
+impl<R> ReportParameterHistoryInteractor<R>
+where
+    R: ReportParameterHistoryRepository,
+{
+    pub fn new(repository: Arc<R>) -> Self {
+        Self { repository }
+    }
+
+    /// 最近使った条件を新しい順に取得（未記録の場合は空）
+    pub async fn recent(
+        &self,
+        user_id: &str,
+        report_id: &str,
+    ) -> ApplicationResult<RecentReportParametersResponse> {
+        let recent = self
+            .repository
+            .find(user_id, report_id)
+            .await?
+            .map(|history| history.recent().to_vec())
+            .unwrap_or_default();
+        Ok(RecentReportParametersResponse { report_id: report_id.to_string(), recent })
+    }
+
+    /// 実行した条件を記録し、記録後の最近使った条件を返す
+    pub async fn remember(
+        &self,
+        request: RememberReportParametersRequest,
+    ) -> ApplicationResult<RecentReportParametersResponse> {
+        let mut history = match self.repository.find(&request.user_id, &request.report_id).await? {
+            Some(history) => history,
+            None => ReportParameterHistory::new(&request.user_id, &request.report_id, vec![])?,
+        };
+        history.remember(request.values);
+        self.repository.save(&history).await?;
+
+        Ok(RecentReportParametersResponse {
+            report_id: history.report_id().to_string(),
+            recent: history.recent().to_vec(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::BTreeMap, sync::Mutex};
+
+    use javelin_domain::error::DomainResult;
+
+    use super::*;
+
+    #[derive(Default)]
+    struct InMemoryReportParameterHistoryRepository {
+        histories: Mutex<Vec<ReportParameterHistory>>,
+    }
+
+    impl ReportParameterHistoryRepository for InMemoryReportParameterHistoryRepository {
+        async fn find(
+            &self,
+            user_id: &str,
+            report_id: &str,
+        ) -> DomainResult<Option<ReportParameterHistory>> {
+            Ok(self
+                .histories
+                .lock()
+                .unwrap()
+                .iter()
+                .find(|h| h.user_id() == user_id && h.report_id() == report_id)
+                .cloned())
+        }
+
+        async fn save(&self, history: &ReportParameterHistory) -> DomainResult<()> {
+            let mut histories = self.histories.lock().unwrap();
+            histories.retain(|h| {
+                !(h.user_id() == history.user_id() && h.report_id() == history.report_id())
+            });
+            histories.push(history.clone());
+            Ok(())
+        }
+    }
+
+    fn request(user_id: &str, period: &str) -> RememberReportParametersRequest {
+        RememberReportParametersRequest {
+            user_id: user_id.to_string(),
+            report_id: "trial_balance".to_string(),
+            values: BTreeMap::from([("period".to_string(), period.to_string())]),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_remember_keeps_recent_per_user() {
+        let interactor = ReportParameterHistoryInteractor::new(Arc::new(
+            InMemoryReportParameterHistoryRepository::default(),
+        ));
+        assert!(interactor.recent("alice", "trial_balance").await.unwrap().recent.is_empty());
+
+        interactor.remember(request("alice", "2024-02")).await.unwrap();
+        let recent = interactor.remember(request("alice", "2024-03")).await.unwrap();
+        assert_eq!(recent.recent[0]["period"], "2024-03");
+        interactor.remember(request("bob", "2023-12")).await.unwrap();
+
+        let alice = interactor.recent("alice", "trial_balance").await.unwrap();
+        assert_eq!(alice.recent.len(), 2);
+        assert_eq!(alice.recent[1]["period"], "2024-02");
+        assert!(interactor.remember(request("", "2024-03")).await.is_err());
+    }
+}
@@ -0,0 +1,515 @@
+// SequenceAuditInteractor - 番号連続性監査のユースケース
+// 責務: 伝票番号・証憑番号の欠番と重複の検出
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    sync::Arc,
+};
+
+use crate::{
+    dtos::{
+        request::SequenceAuditRequest,
+        response::{
+            NumberKind, SequenceAuditEntry, SequenceAuditResponse, SequenceFinding,
+            SequenceFindingKind, SequenceSeriesSummary,
+        },
+    },
+    error::ApplicationResult,
+    query_service::{NumberedEntry, SequenceAuditQueryService, VoidedNumber},
+};
+
+/// 番号の構成要素
+struct ParsedNumber {
+    series: String,
+    fiscal_year: Option<u32>,
+    /// 連番（連番形式でない場合はNone）
+    sequence: Option<u64>,
+    /// 連番部分の桁数
+    width: usize,
+}
+
+/// 番号を分解する
+///
+/// `{接頭辞}-{年度4桁}-{連番}` 形式（例: V-2024-00001）を年度単位の連番として扱う。
+/// それ以外の形式は取引日の暦年で分類し、連番としては扱わない。
+fn parse_number(number: &str, transaction_date: &str) -> ParsedNumber {
+    let segments: Vec<&str> = number.split('-').collect();
+    if segments.len() >= 3 {
+        let last = segments[segments.len() - 1];
+        let year = segments[segments.len() - 2];
+        if !last.is_empty()
+            && last.chars().all(|c| c.is_ascii_digit())
+            && year.len() == 4
+            && let (Ok(year), Ok(sequence)) = (year.parse::<u32>(), last.parse::<u64>())
+        {
+            return ParsedNumber {
+                series: segments[..segments.len() - 1].join("-"),
+                fiscal_year: Some(year),
+                sequence: Some(sequence),
+                width: last.len(),
+            };
+        }
+    }
+
+    ParsedNumber {
+        series: segments[0].to_string(),
+        fiscal_year: transaction_date.get(..4).and_then(|year| year.parse().ok()),
+        sequence: None,
+        width: 0,
+    }
+}
+
+/// 系列内の番号
+struct SeriesMember<'a> {
+    number: &'a str,
+    sequence: Option<u64>,
+    entry: &'a NumberedEntry,
+}
+
+/// 系列の識別子（番号の種類・年度・接頭辞）
+type SeriesKey = (NumberKind, Option<u32>, String);
+
+/// 番号連続性監査のInteractor
+///
+/// 年度ごとの伝票番号・証憑番号の系列について、欠番と重複を検出する。
+/// 削除済の仕訳も番号を消費しているため、欠番としては扱わない。
+/// 登録失敗により取り消された証憑番号は欠番とせず、取消として理由とともに報告する。
+pub struct SequenceAuditInteractor<Q>
+where
+    Q: SequenceAuditQueryService,
+{
+    query_service: Arc<Q>,
+}
+
+impl<Q> SequenceAuditInteractor<Q>
+where
+    Q: SequenceAuditQueryService,
+{
+    pub fn new(query_service: Arc<Q>) -> Self {
+        Self { query_service }
+    }
+
+    pub async fn execute(
+        &self,
+        request: SequenceAuditRequest,
+    ) -> ApplicationResult<SequenceAuditResponse> {
+        let entries = self.query_service.load_numbered_entries().await?;
+        let voided = self.query_service.load_voided_numbers().await?;
+        Ok(audit(&entries, &voided, request.fiscal_year))
+    }
+}
+
+fn audit(
+    entries: &[NumberedEntry],
+    voided: &[VoidedNumber],
+    fiscal_year: Option<u32>,
+) -> SequenceAuditResponse {
+    let voided: Vec<&VoidedNumber> = voided
+        .iter()
+        .filter(|v| fiscal_year.is_none_or(|fiscal_year| v.fiscal_year == fiscal_year))
+        .collect();
+
+    let mut groups: BTreeMap<SeriesKey, (usize, Vec<SeriesMember>)> = BTreeMap::new();
+
+    for entry in entries {
+        let numbers = [
+            (NumberKind::EntryNumber, entry.entry_number.as_deref()),
+            (NumberKind::VoucherNumber, entry.voucher_number.as_deref()),
+        ];
+        for (kind, number) in numbers {
+            let Some(number) = number.filter(|n| !n.is_empty()) else {
+                continue;
+            };
+            let parsed = parse_number(number, &entry.transaction_date);
+            if fiscal_year.is_some() && parsed.fiscal_year != fiscal_year {
+                continue;
+            }
+
+            let group = groups
+                .entry((kind, parsed.fiscal_year, parsed.series))
+                .or_insert((0, Vec::new()));
+            group.0 = group.0.max(parsed.width);
+            group.1.push(SeriesMember { number, sequence: parsed.sequence, entry });
+        }
+    }
+
+    let mut series = Vec::new();
+    let mut findings = Vec::new();
+
+    for ((kind, year, prefix), (width, mut members)) in groups {
+        let sequential = members.iter().all(|m| m.sequence.is_some());
+        if sequential {
+            members.sort_by_key(|m| m.sequence);
+        } else {
+            members.sort_by(|a, b| a.number.cmp(b.number));
+        }
+
+        find_duplicates(kind, year, &members, &mut findings);
+        if sequential {
+            // 取り消された番号は証憑番号の系列のみに存在する
+            let voided_sequences: BTreeSet<u64> = voided
+                .iter()
+                .filter(|_| kind == NumberKind::VoucherNumber)
+                .map(|v| parse_number(&v.number, ""))
+                .filter(|parsed| parsed.fiscal_year == year && parsed.series == prefix)
+                .filter_map(|parsed| parsed.sequence)
+                .collect();
+            find_gaps(kind, year, &prefix, width, &members, &voided_sequences, &mut findings);
+        }
+
+        series.push(SequenceSeriesSummary {
+            number_kind: kind,
+            fiscal_year: year,
+            series: prefix,
+            count: members.len(),
+            first: members.first().map(|m| m.number.to_string()).unwrap_or_default(),
+            last: members.last().map(|m| m.number.to_string()).unwrap_or_default(),
+            sequential,
+        });
+    }
+
+    for voided in voided {
+        findings.push(SequenceFinding {
+            kind: SequenceFindingKind::Voided,
+            number_kind: NumberKind::VoucherNumber,
+            fiscal_year: Some(voided.fiscal_year),
+            subject: voided.number.clone(),
+            detail: format!(
+                "{} {} は採番後に取り消されました（{}、理由: {}）",
+                NumberKind::VoucherNumber.label(),
+                voided.number,
+                voided.voided_at,
+                voided.reason
+            ),
+            surrounding: Vec::new(),
+        });
+    }
+
+    SequenceAuditResponse { fiscal_year, series, findings }
+}
+
+/// 同一番号が複数の仕訳で使用されている箇所を検出
+fn find_duplicates(
+    kind: NumberKind,
+    fiscal_year: Option<u32>,
+    members: &[SeriesMember],
+    findings: &mut Vec<SequenceFinding>,
+) {
+    let mut by_number: BTreeMap<&str, Vec<&SeriesMember>> = BTreeMap::new();
+    for member in members {
+        by_number.entry(member.number).or_default().push(member);
+    }
+
+    for (number, used_by) in by_number {
+        if used_by.len() < 2 {
+            continue;
+        }
+        findings.push(SequenceFinding {
+            kind: SequenceFindingKind::Duplicate,
+            number_kind: kind,
+            fiscal_year,
+            subject: number.to_string(),
+            detail: format!(
+                "{} {} が{}件の仕訳で使用されています",
+                kind.label(),
+                number,
+                used_by.len()
+            ),
+            surrounding: used_by.into_iter().map(audit_entry).collect(),
+        });
+    }
+}
+
+/// 連番の欠番を検出（連番は1から始まる、取り消された番号は除く）
+fn find_gaps(
+    kind: NumberKind,
+    fiscal_year: Option<u32>,
+    prefix: &str,
+    width: usize,
+    members: &[SeriesMember],
+    voided_sequences: &BTreeSet<u64>,
+    findings: &mut Vec<SequenceFinding>,
+) {
+    let format_number = |sequence: u64| format!("{}-{:0width$}", prefix, sequence, width = width);
+
+    let mut previous: Option<&SeriesMember> = None;
+    for member in members {
+        let Some(sequence) = member.sequence else {
+            continue;
+        };
+        let expected = previous.and_then(|p| p.sequence).map_or(1, |s| s + 1);
+
+        if sequence > expected {
+            // 取り消された番号で区切った欠番の範囲
+            let mut ranges = Vec::new();
+            let mut range_start = expected;
+            for &voided in voided_sequences.range(expected..sequence) {
+                if voided > range_start {
+                    ranges.push((range_start, voided - 1));
+                }
+                range_start = voided + 1;
+            }
+            if range_start < sequence {
+                ranges.push((range_start, sequence - 1));
+            }
+
+            for (first, last) in ranges {
+                let subject = if first == last {
+                    format_number(first)
+                } else {
+                    format!("{}〜{}", format_number(first), format_number(last))
+                };
+                let surrounding: Vec<SequenceAuditEntry> =
+                    previous.into_iter().chain(Some(member)).map(audit_entry).collect();
+
+                findings.push(SequenceFinding {
+                    kind: SequenceFindingKind::Gap,
+                    number_kind: kind,
+                    fiscal_year,
+                    detail: format!(
+                        "{} {} が欠番です（{}件）",
+                        kind.label(),
+                        subject,
+                        last - first + 1
+                    ),
+                    subject,
+                    surrounding,
+                });
+            }
+        }
+
+        if previous.and_then(|p| p.sequence) != Some(sequence) {
+            previous = Some(member);
+        }
+    }
+}
+
+fn audit_entry(member: &SeriesMember) -> SequenceAuditEntry {
+    SequenceAuditEntry {
+        entry_id: member.entry.entry_id.clone(),
+        number: member.number.to_string(),
+        transaction_date: member.entry.transaction_date.clone(),
+        status: member.entry.status.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct MockQueryService {
+        entries: Vec<NumberedEntry>,
+        voided: Vec<VoidedNumber>,
+    }
+
+    impl SequenceAuditQueryService for MockQueryService {
+        async fn load_numbered_entries(&self) -> ApplicationResult<Vec<NumberedEntry>> {
+            Ok(self.entries.clone())
+        }
+
+        async fn load_voided_numbers(&self) -> ApplicationResult<Vec<VoidedNumber>> {
+            Ok(self.voided.clone())
+        }
+    }
+
+    fn entry(id: &str, voucher: &str, entry_number: Option<&str>, status: &str) -> NumberedEntry {
+        NumberedEntry {
+            entry_id: id.to_string(),
+            transaction_date: "2024-04-01".to_string(),
+            voucher_number: Some(voucher.to_string()),
+            entry_number: entry_number.map(str::to_string),
+            status: status.to_string(),
+        }
+    }
+
+    async fn run(entries: Vec<NumberedEntry>, fiscal_year: Option<u32>) -> SequenceAuditResponse {
+        run_with_voided(entries, Vec::new(), fiscal_year).await
+    }
+
+    async fn run_with_voided(
+        entries: Vec<NumberedEntry>,
+        voided: Vec<VoidedNumber>,
+        fiscal_year: Option<u32>,
+    ) -> SequenceAuditResponse {
+        let interactor =
+            SequenceAuditInteractor::new(Arc::new(MockQueryService { entries, voided }));
+        interactor.execute(SequenceAuditRequest { fiscal_year }).await.unwrap()
+    }
+
+    fn voided(number: &str) -> VoidedNumber {
+        VoidedNumber {
+            number: number.to_string(),
+            fiscal_year: 2024,
+            reason: "イベントストアへの保存に失敗しました".to_string(),
+            voided_at: "2024-04-01T09:00:00+09:00".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_continuous_sequence_has_no_findings() {
+        let response = run(
+            vec![
+                entry("e1", "V-2024-00001", Some("EN-20240401-090000"), "Posted"),
+                entry("e2", "V-2024-00002", None, "Deleted"),
+                entry("e3", "V-2024-00003", None, "Draft"),
+            ],
+            None,
+        )
+        .await;
+
+        assert!(!response.has_findings());
+        let voucher = response
+            .series
+            .iter()
+            .find(|s| s.number_kind == NumberKind::VoucherNumber)
+            .unwrap();
+        assert!(voucher.sequential);
+        assert_eq!(voucher.count, 3);
+        assert_eq!(voucher.first, "V-2024-00001");
+        assert_eq!(voucher.last, "V-2024-00003");
+
+        // 日時形式の伝票番号は連番として扱わない
+        let entry_number = response
+            .series
+            .iter()
+            .find(|s| s.number_kind == NumberKind::EntryNumber)
+            .unwrap();
+        assert!(!entry_number.sequential);
+        assert_eq!(entry_number.fiscal_year, Some(2024));
+    }
+
+    #[tokio::test]
+    async fn test_scoped_entry_numbers_are_audited_per_company() {
+        let response = run(
+            vec![
+                entry("e1", "V-2024-00001", Some("EN-0001-2024-000001"), "Posted"),
+                entry("e2", "V-2024-00002", Some("EN-0001-2024-000003"), "Posted"),
+                entry("e3", "V-2024-00003", Some("EN-0003-2024-000001"), "Posted"),
+            ],
+            None,
+        )
+        .await;
+
+        let entry_series: Vec<_> = response
+            .series
+            .iter()
+            .filter(|s| s.number_kind == NumberKind::EntryNumber)
+            .collect();
+        assert_eq!(entry_series.len(), 2);
+        assert!(entry_series.iter().all(|s| s.sequential && s.fiscal_year == Some(2024)));
+
+        assert_eq!(response.gap_count(), 1);
+        assert_eq!(response.findings[0].subject, "EN-0001-2024-000002");
+    }
+
+    #[tokio::test]
+    async fn test_gaps_are_reported_with_surrounding_entries() {
+        let response = run(
+            vec![
+                entry("e2", "V-2024-00002", None, "Draft"),
+                entry("e5", "V-2024-00005", None, "Draft"),
+                entry("e6", "V-2024-00006", None, "Draft"),
+            ],
+            None,
+        )
+        .await;
+
+        assert_eq!(response.gap_count(), 2);
+        assert_eq!(response.duplicate_count(), 0);
+
+        let leading = &response.findings[0];
+        assert_eq!(leading.subject, "V-2024-00001");
+        assert_eq!(leading.surrounding.len(), 1);
+        assert_eq!(leading.surrounding[0].entry_id, "e2");
+
+        let middle = &response.findings[1];
+        assert_eq!(middle.subject, "V-2024-00003〜V-2024-00004");
+        let ids: Vec<&str> = middle.surrounding.iter().map(|e| e.entry_id.as_str()).collect();
+        assert_eq!(ids, vec!["e2", "e5"]);
+    }
+
+    #[tokio::test]
+    async fn test_duplicates_are_reported() {
+        let response = run(
+            vec![
+                entry("e1", "V-2024-00001", Some("EN-20240401-090000"), "Posted"),
+                entry("e2", "V-2024-00001", Some("EN-20240401-090000"), "Posted"),
+                entry("e3", "V-2024-00002", None, "Draft"),
+            ],
+            None,
+        )
+        .await;
+
+        assert_eq!(response.gap_count(), 0);
+        assert_eq!(response.duplicate_count(), 2);
+        for finding in &response.findings {
+            assert_eq!(finding.surrounding.len(), 2);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_fiscal_year_filter() {
+        let response = run(
+            vec![
+                entry("e1", "V-2023-00001", None, "Posted"),
+                entry("e2", "V-2023-00003", None, "Posted"),
+                entry("e3", "V-2024-00001", None, "Draft"),
+            ],
+            Some(2024),
+        )
+        .await;
+
+        assert!(!response.has_findings());
+        assert_eq!(response.series.len(), 1);
+        assert_eq!(response.series[0].series, "V-2024");
+    }
+
+    #[tokio::test]
+    async fn test_voided_numbers_are_reported_instead_of_gaps() {
+        let response = run_with_voided(
+            vec![
+                entry("e1", "V-2024-00001", None, "Draft"),
+                entry("e5", "V-2024-00005", None, "Draft"),
+            ],
+            vec![voided("V-2024-00003")],
+            None,
+        )
+        .await;
+
+        // 取消番号で区切られた残りの欠番だけを指摘する
+        assert_eq!(response.gap_count(), 2);
+        assert_eq!(response.voided_count(), 1);
+        let gaps: Vec<&str> = response
+            .findings
+            .iter()
+            .filter(|f| f.kind == SequenceFindingKind::Gap)
+            .map(|f| f.subject.as_str())
+            .collect();
+        assert_eq!(gaps, vec!["V-2024-00002", "V-2024-00004"]);
+
+        let voided = response
+            .findings
+            .iter()
+            .find(|f| f.kind == SequenceFindingKind::Voided)
+            .unwrap();
+        assert_eq!(voided.subject, "V-2024-00003");
+        assert!(voided.detail.contains("イベントストアへの保存に失敗しました"));
+    }
+
+    #[tokio::test]
+    async fn test_only_voided_numbers_are_not_findings() {
+        let response = run_with_voided(
+            vec![
+                entry("e1", "V-2024-00001", None, "Draft"),
+                entry("e3", "V-2024-00003", None, "Draft"),
+            ],
+            vec![voided("V-2024-00002")],
+            None,
+        )
+        .await;
+
+        assert_eq!(response.gap_count(), 0);
+        assert_eq!(response.voided_count(), 1);
+        assert!(!response.has_findings());
+    }
+}
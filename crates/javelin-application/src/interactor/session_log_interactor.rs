@@ -0,0 +1,253 @@
+// SessionLogInteractor - ログインセッションの記録と管理のユースケース
+// 責務: ログイン・ログイン失敗・ログアウトの記録、管理者によるセッション一覧の照会と強制ログアウト
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use javelin_domain::{
+    error::DomainError,
+    masters::{SESSION_LOG_STREAM, SessionClosure, SessionEvent, SessionLog, UserSession},
+    repositories::{AccountingPolicyRepository, EventRepository},
+};
+
+use crate::{
+    dtos::{
+        request::{ForceLogoutRequest, SessionOverviewRequest},
+        response::{FailedLoginDto, SessionDto, SessionOverviewResponse},
+    },
+    error::{ApplicationError, ApplicationResult},
+};
+
+/// 一覧に表示する最近のログイン・ログイン失敗の件数
+const RECENT_LIMIT: usize = 20;
+
+/// ログインセッションのInteractor
+///
+/// セッションのイベントはイベントストアの専用ストリームに追記する。
+/// 一覧の照会と強制ログアウトは会計方針の管理者に限る。
+pub struct SessionLogInteractor<R, P>
+where
+    R: EventRepository,
+    P: AccountingPolicyRepository,
+{
+    event_repository: Arc<R>,
+    policy_repository: Arc<P>,
+}
+
+impl<R, P> SessionLogInteractor<R, P>
+where
+    R: EventRepository,
+    P: AccountingPolicyRepository,
+{
+    pub fn new(event_repository: Arc<R>, policy_repository: Arc<P>) -> Self {
+        Self { event_repository, policy_repository }
+    }
+
+    /// ログインを記録し、セッションIDを返す
+    pub async fn record_login(&self, user_id: &str) -> ApplicationResult<String> {
+        let session_id =
+            format!("S-{}", uuid::Uuid::new_v4().simple().to_string()[..12].to_uppercase());
+        let event = UserSession::start(&session_id, user_id, Utc::now());
+        self.append(event).await?;
+        Ok(session_id)
+    }
+
+    /// ログインの失敗を記録
+    pub async fn record_failed_login(&self, user_id: &str, reason: &str) -> ApplicationResult<()> {
+        self.append(SessionEvent::LoginFailed {
+            user_id: user_id.to_string(),
+            reason: reason.to_string(),
+            failed_at: Utc::now(),
+        })
+        .await
+    }
+
+    /// ログアウトを記録（終了済みのセッションは何もしない）
+    pub async fn record_logout(&self, session_id: &str) -> ApplicationResult<()> {
+        let log = self.load().await?;
+        match log.find(session_id).and_then(|session| session.end(Utc::now())) {
+            Some(event) => self.append(event).await,
+            None => Ok(()),
+        }
+    }
+
+    /// セッションが終了していないか（強制ログアウトの検知用）
+    pub async fn is_active(&self, session_id: &str) -> ApplicationResult<bool> {
+        Ok(self.load().await?.find(session_id).is_some_and(UserSession::is_active))
+    }
+
+    /// ログイン中のセッション・最近のログイン・ログイン失敗の一覧
+    pub async fn overview(
+        &self,
+        request: SessionOverviewRequest,
+    ) -> ApplicationResult<SessionOverviewResponse> {
+        self.ensure_administrator(&request.user_id).await?;
+        let log = self.load().await?;
+
+        Ok(SessionOverviewResponse {
+            active_sessions: log.active_sessions().map(to_dto).collect(),
+            recent_logins: log.recent_sessions().take(RECENT_LIMIT).map(to_dto).collect(),
+            failed_logins: log
+                .recent_failed_logins()
+                .take(RECENT_LIMIT)
+                .map(|failed| FailedLoginDto {
+                    user_id: failed.user_id.clone(),
+                    reason: failed.reason.clone(),
+                    failed_at: failed.failed_at.to_rfc3339(),
+                })
+                .collect(),
+        })
+    }
+
+    /// セッションを強制ログアウトする
+    pub async fn force_logout(
+        &self,
+        request: ForceLogoutRequest,
+    ) -> ApplicationResult<SessionOverviewResponse> {
+        self.ensure_administrator(&request.user_id).await?;
+        let log = self.load().await?;
+        let session = log.find(&request.session_id).ok_or_else(|| {
+            ApplicationError::ValidationFailed(vec![format!(
+                "セッションが見つかりません: {}",
+                request.session_id
+            )])
+        })?;
+
+        let event = session.force_out(&request.user_id, Utc::now())?;
+        self.append(event).await?;
+
+        self.overview(SessionOverviewRequest { user_id: request.user_id }).await
+    }
+
+    async fn ensure_administrator(&self, user_id: &str) -> ApplicationResult<()> {
+        let policy = self.policy_repository.load().await?;
+        if !policy.is_administrator(user_id) {
+            return Err(ApplicationError::DomainError(DomainError::PermissionDenied(format!(
+                "セッションの管理は管理者のみ可能です（ユーザ: {}）",
+                user_id
+            ))));
+        }
+        Ok(())
+    }
+
+    async fn load(&self) -> ApplicationResult<SessionLog> {
+        let events: Vec<SessionEvent> = self
+            .event_repository
+            .get_events(SESSION_LOG_STREAM)
+            .await?
+            .into_iter()
+            .filter_map(|value| serde_json::from_value(value).ok())
+            .collect();
+        Ok(SessionLog::replay(&events))
+    }
+
+    async fn append(&self, event: SessionEvent) -> ApplicationResult<()> {
+        self.event_repository.append_events(SESSION_LOG_STREAM, vec![event]).await?;
+        Ok(())
+    }
+}
+
+fn to_dto(session: &UserSession) -> SessionDto {
+    let (status, closed_at, forced_by) = match session.closure() {
+        None => ("ログイン中", None, None),
+        Some(SessionClosure::Ended { ended_at }) => ("ログアウト", Some(*ended_at), None),
+        Some(SessionClosure::ForcedOut { forced_by, forced_at }) => {
+            ("強制ログアウト", Some(*forced_at), Some(forced_by.clone()))
+        }
+    };
+    SessionDto {
+        session_id: session.session_id().to_string(),
+        user_id: session.user_id().to_string(),
+        started_at: session.started_at().to_rfc3339(),
+        status: status.to_string(),
+        closed_at: closed_at.map(|at| at.to_rfc3339()),
+        forced_by,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use javelin_domain::{
+        error::DomainResult,
+        masters::{AccountingPolicy, AccountingPolicyChanged, DEFAULT_POLICY_ADMINISTRATOR},
+    };
+
+    use super::*;
+    use crate::testkit::InMemoryEventRepository;
+
+    struct MockPolicyRepository;
+
+    impl AccountingPolicyRepository for MockPolicyRepository {
+        async fn load(&self) -> DomainResult<AccountingPolicy> {
+            Ok(AccountingPolicy::default())
+        }
+
+        async fn save(
+            &self,
+            _policy: &AccountingPolicy,
+            _change: &AccountingPolicyChanged,
+        ) -> DomainResult<()> {
+            Ok(())
+        }
+
+        async fn find_changes(&self) -> DomainResult<Vec<AccountingPolicyChanged>> {
+            Ok(Vec::new())
+        }
+    }
+
+    fn interactor() -> SessionLogInteractor<InMemoryEventRepository, MockPolicyRepository> {
+        SessionLogInteractor::new(
+            Arc::new(InMemoryEventRepository::default()),
+            Arc::new(MockPolicyRepository),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_records_sessions_and_forces_logout() {
+        let interactor = interactor();
+        let admin_session = interactor.record_login(DEFAULT_POLICY_ADMINISTRATOR).await.unwrap();
+        let clerk_session = interactor.record_login("clerk").await.unwrap();
+        interactor.record_failed_login("clerk", "パスワード不一致").await.unwrap();
+
+        let overview = interactor
+            .overview(SessionOverviewRequest { user_id: DEFAULT_POLICY_ADMINISTRATOR.to_string() })
+            .await
+            .unwrap();
+        assert_eq!(overview.active_sessions.len(), 2);
+        assert_eq!(overview.recent_logins[0].user_id, "clerk");
+        assert_eq!(overview.failed_logins[0].reason, "パスワード不一致");
+
+        let overview = interactor
+            .force_logout(ForceLogoutRequest {
+                session_id: clerk_session.clone(),
+                user_id: DEFAULT_POLICY_ADMINISTRATOR.to_string(),
+            })
+            .await
+            .unwrap();
+        assert_eq!(overview.active_sessions.len(), 1);
+        assert_eq!(overview.recent_logins[0].status, "強制ログアウト");
+        assert!(!interactor.is_active(&clerk_session).await.unwrap());
+
+        interactor.record_logout(&admin_session).await.unwrap();
+        assert!(!interactor.is_active(&admin_session).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_non_administrator_is_rejected() {
+        let interactor = interactor();
+        let session_id = interactor.record_login(DEFAULT_POLICY_ADMINISTRATOR).await.unwrap();
+
+        let overview = interactor
+            .overview(SessionOverviewRequest { user_id: "clerk".to_string() })
+            .await;
+        assert!(matches!(
+            overview,
+            Err(ApplicationError::DomainError(DomainError::PermissionDenied(_)))
+        ));
+        let forced = interactor
+            .force_logout(ForceLogoutRequest { session_id, user_id: "clerk".to_string() })
+            .await;
+        assert!(forced.is_err());
+    }
+}
@@ -0,0 +1,111 @@
+// StatementLineMappingInteractor - 財務諸表表示科目マッピング操作のユースケース
+
+use std::sync::Arc;
+
+use javelin_domain::{
+    masters::{AccountCode, FinancialStatementKind, StatementLine, StatementLineMapping},
+    repositories::StatementLineMappingRepository,
+};
+
+use crate::{
+    dtos::{
+        request::{AssignStatementLineRequest, RemoveStatementLineMappingRequest},
+        response::{
+            LoadStatementLineMappingResponse, StatementLineMappingItem, StatementLineOption,
+        },
+    },
+    error::ApplicationResult,
+    query_service::MasterDataLoaderService,
+};
+
+/// 財務諸表の略称
+fn statement_label(line: StatementLine) -> &'static str {
+    match line.statement() {
+        FinancialStatementKind::FinancialPosition => "BS",
+        FinancialStatementKind::ProfitOrLoss => "PL",
+    }
+}
+
+/// 財務諸表表示科目マッピング操作のInteractor
+pub struct StatementLineMappingInteractor<R, L>
+where
+    R: StatementLineMappingRepository,
+    L: MasterDataLoaderService,
+{
+    repository: Arc<R>,
+    master_data_loader: Arc<L>,
+}
+
+impl<R, L> StatementLineMappingInteractor<R, L>
+where
+    R: StatementLineMappingRepository,
+    L: MasterDataLoaderService,
+{
+    pub fn new(repository: Arc<R>, master_data_loader: Arc<L>) -> Self {
+        Self { repository, master_data_loader }
+    }
+
+    /// 勘定科目マスタと突き合わせた割当状況を取得
+    ///
+    /// マッピングのみ存在する勘定科目（マスタから削除済み）は除外する。
+    pub async fn load(&self) -> ApplicationResult<LoadStatementLineMappingResponse> {
+        let master_data = self.master_data_loader.load_master_data().await?;
+        let mappings = self.repository.find_all().await?;
+
+        let mut items: Vec<StatementLineMappingItem> = master_data
+            .accounts
+            .into_iter()
+            .map(|account| {
+                let line = mappings
+                    .iter()
+                    .find(|mapping| mapping.account_code().value() == account.code)
+                    .map(|mapping| mapping.statement_line());
+                StatementLineMappingItem {
+                    account_code: account.code,
+                    account_name: account.name,
+                    statement_line_code: line.map(|line| line.code().to_string()),
+                    statement_line_name: line.map(|line| line.display_name().to_string()),
+                    statement: line.map(|line| statement_label(line).to_string()),
+                }
+            })
+            .collect();
+
+        items.sort_by(|a, b| a.account_code.cmp(&b.account_code));
+
+        let statement_lines = StatementLine::ALL
+            .into_iter()
+            .map(|line| StatementLineOption {
+                code: line.code().to_string(),
+                name: line.display_name().to_string(),
+                statement: statement_label(line).to_string(),
+            })
+            .collect();
+
+        Ok(LoadStatementLineMappingResponse { items, statement_lines })
+    }
+
+    /// 勘定科目に表示科目を割り当てる（既存の割当は置き換える）
+    pub async fn assign(&self, request: AssignStatementLineRequest) -> ApplicationResult<()> {
+        let account_code = AccountCode::new(&request.account_code)?;
+        let statement_line = StatementLine::from_code(&request.statement_line_code)?;
+
+        let mapping = match self.repository.find_by_account(&account_code).await? {
+            Some(mut mapping) => {
+                mapping.reassign(statement_line);
+                mapping
+            }
+            None => StatementLineMapping::new(account_code, statement_line),
+        };
+
+        Ok(self.repository.save(&mapping).await?)
+    }
+
+    /// 勘定科目の表示科目割当を解除
+    pub async fn remove(
+        &self,
+        request: RemoveStatementLineMappingRequest,
+    ) -> ApplicationResult<()> {
+        let account_code = AccountCode::new(&request.account_code)?;
+        Ok(self.repository.delete(&account_code).await?)
+    }
+}
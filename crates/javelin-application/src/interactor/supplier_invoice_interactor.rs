@@ -0,0 +1,362 @@
+// SupplierInvoiceInteractor - 仕入先請求書台帳のユースケース
+// 責務: 請求書の登録、買掛金計上仕訳（下書き）の起票、支払状況の記録
+
+use std::sync::Arc;
+
+use chrono::{Datelike, NaiveDate};
+use javelin_domain::{
+    entity::EntityId,
+    financial_close::{
+        journal_entry::{
+            entities::{JournalEntry, JournalEntryId, JournalEntryLine},
+            services::{JournalEntryService, VoucherNumberGenerator},
+            values::{TransactionDate, UserId, VoucherNumber},
+        },
+        supplier_invoice::{ACCOUNTS_PAYABLE_ACCOUNT_CODE, SupplierInvoice},
+    },
+    id_generator::AggregateIdGenerator,
+    repositories::{EventRepository, SupplierInvoiceRepository},
+};
+
+use crate::{
+    dtos::{
+        JournalEntryLineDto,
+        request::{
+            CreateInvoiceJournalDraftRequest, RecordInvoicePaymentRequest,
+            RegisterSupplierInvoiceRequest,
+        },
+        response::{InvoiceJournalDraftResponse, SupplierInvoiceResponse},
+    },
+    error::{ApplicationError, ApplicationResult},
+    id_generator::TimeOrderedIdGenerator,
+};
+
+/// 仕入先請求書台帳のInteractor
+///
+/// 買掛金計上仕訳は請求日を取引日とし、借方に費用（税込、消費税額を付記）、
+/// 貸方に買掛金を置いた下書きとして起票する。承認・記帳は通常の仕訳と同じ流れで行う。
+/// 起票済みの請求書は仕訳の根拠となるため、再登録による置き換えを認めない。
+pub struct SupplierInvoiceInteractor<I, R, V>
+where
+    I: SupplierInvoiceRepository,
+    R: EventRepository,
+    V: VoucherNumberGenerator,
+{
+    invoice_repository: Arc<I>,
+    event_repository: Arc<R>,
+    voucher_generator: Arc<V>,
+}
+
+impl<I, R, V> SupplierInvoiceInteractor<I, R, V>
+where
+    I: SupplierInvoiceRepository,
+    R: EventRepository,
+    V: VoucherNumberGenerator,
+{
+    pub fn new(
+        invoice_repository: Arc<I>,
+        event_repository: Arc<R>,
+        voucher_generator: Arc<V>,
+    ) -> Self {
+        Self { invoice_repository, event_repository, voucher_generator }
+    }
+
+    /// 請求書を登録（仕訳未起票の請求書は置き換える）
+    pub async fn register(
+        &self,
+        request: RegisterSupplierInvoiceRequest,
+    ) -> ApplicationResult<SupplierInvoiceResponse> {
+        if let Some(existing) = self
+            .invoice_repository
+            .find(&request.counterparty, &request.invoice_number)
+            .await?
+            && existing.journal_entry_id().is_some()
+        {
+            return Err(ApplicationError::ValidationFailed(vec![format!(
+                "買掛金計上仕訳を起票済みのため置き換えられません: {} {}",
+                existing.counterparty(),
+                existing.invoice_number()
+            )]));
+        }
+
+        let invoice_date = parse_date(&request.invoice_date, "請求日")?;
+        let invoice = SupplierInvoice::new(
+            request.invoice_number,
+            request.counterparty,
+            invoice_date,
+            parse_date(&request.due_date, "支払期日")?,
+            request.expense_account_code,
+            request.currency,
+            request.amount,
+            request.tax_amount,
+        )?;
+
+        self.invoice_repository.save(&invoice).await?;
+
+        Ok(SupplierInvoiceResponse::from_invoice(&invoice, invoice_date))
+    }
+
+    /// 登録済みの請求書を支払状況とともに取得（期日超過は基準日で判定）
+    pub async fn list(&self, as_of: NaiveDate) -> ApplicationResult<Vec<SupplierInvoiceResponse>> {
+        let invoices = self.invoice_repository.find_all().await?;
+        Ok(invoices
+            .iter()
+            .map(|invoice| SupplierInvoiceResponse::from_invoice(invoice, as_of))
+            .collect())
+    }
+
+    /// 請求書から買掛金計上仕訳を下書きとして起票
+    pub async fn create_journal_draft(
+        &self,
+        request: CreateInvoiceJournalDraftRequest,
+    ) -> ApplicationResult<InvoiceJournalDraftResponse> {
+        let mut invoice = self.load(&request.counterparty, &request.invoice_number).await?;
+        if invoice.journal_entry_id().is_some() {
+            return Err(ApplicationError::ValidationFailed(vec![format!(
+                "買掛金計上仕訳は起票済みです: {} {}",
+                invoice.counterparty(),
+                invoice.invoice_number()
+            )]));
+        }
+
+        let date = invoice.invoice_date();
+        let transaction_date = TransactionDate::new(date).map_err(ApplicationError::DomainError)?;
+        let description =
+            format!("仕入先請求書 {} {}", invoice.counterparty(), invoice.invoice_number());
+        let (tax_type, tax_amount) = if invoice.tax_amount() > 0.0 {
+            ("Taxable", invoice.tax_amount())
+        } else {
+            ("NonTaxable", 0.0)
+        };
+        let line =
+            |line_number: u32, side: &str, account_code: &str, tax_type: &str, tax_amount| {
+                JournalEntryLineDto {
+                    line_number,
+                    side: side.to_string(),
+                    account_code: account_code.to_string(),
+                    sub_account_code: None,
+                    department_code: None,
+                    dimensions: Default::default(),
+                    amount: invoice.total_amount(),
+                    currency: invoice.currency().to_string(),
+                    tax_type: tax_type.to_string(),
+                    tax_amount,
+                    description: Some(description.clone()),
+                }
+            };
+        let dtos = [
+            line(1, "Debit", invoice.expense_account_code(), tax_type, tax_amount),
+            line(2, "Credit", ACCOUNTS_PAYABLE_ACCOUNT_CODE, "NonTaxable", 0.0),
+        ];
+        let lines: Vec<JournalEntryLine> =
+            dtos.iter().map(|dto| dto.try_into()).collect::<Result<_, _>>()?;
+        JournalEntryService::validate_balance(&lines).map_err(ApplicationError::DomainError)?;
+
+        let voucher_number = self
+            .voucher_generator
+            .reserve(date.year() as u32)
+            .await
+            .map_err(ApplicationError::DomainError)?;
+
+        // 保存に失敗した場合は予約した伝票番号を取り消す
+        let entry_id = JournalEntryId::new(TimeOrderedIdGenerator.next_id());
+        let result: ApplicationResult<JournalEntry> = async {
+            let journal_entry = JournalEntry::new(
+                entry_id.clone(),
+                transaction_date,
+                VoucherNumber::new(voucher_number.clone())
+                    .map_err(ApplicationError::DomainError)?,
+                lines,
+                UserId::new(request.user_id),
+            )
+            .map_err(ApplicationError::DomainError)?;
+
+            self.event_repository
+                .append_events(entry_id.value(), journal_entry.events().to_vec())
+                .await
+                .map_err(ApplicationError::DomainError)?;
+
+            Ok(journal_entry)
+        }
+        .await;
+        let journal_entry = self.voucher_generator.settle(&voucher_number, result).await?;
+
+        invoice.mark_journalized(entry_id.value())?;
+        self.invoice_repository.save(&invoice).await?;
+
+        Ok(InvoiceJournalDraftResponse {
+            entry_id: entry_id.value().to_string(),
+            voucher_number,
+            status: journal_entry.status().as_str().to_string(),
+        })
+    }
+
+    /// 請求書の支払を記録
+    pub async fn record_payment(
+        &self,
+        request: RecordInvoicePaymentRequest,
+    ) -> ApplicationResult<SupplierInvoiceResponse> {
+        let mut invoice = self.load(&request.counterparty, &request.invoice_number).await?;
+        let paid_date = parse_date(&request.paid_date, "支払日")?;
+        invoice.record_payment(paid_date, request.amount)?;
+        self.invoice_repository.save(&invoice).await?;
+
+        Ok(SupplierInvoiceResponse::from_invoice(&invoice, paid_date))
+    }
+
+    async fn load(
+        &self,
+        counterparty: &str,
+        invoice_number: &str,
+    ) -> ApplicationResult<SupplierInvoice> {
+        self.invoice_repository
+            .find(counterparty, invoice_number)
+            .await?
+            .ok_or_else(|| {
+                ApplicationError::ValidationFailed(vec![format!(
+                    "仕入先請求書が見つかりません: {} {}",
+                    counterparty, invoice_number
+                )])
+            })
+    }
+}
+
+fn parse_date(value: &str, label: &str) -> ApplicationResult<NaiveDate> {
+    NaiveDate::parse_from_str(value.trim(), "%Y-%m-%d").map_err(|_| {
+        ApplicationError::ValidationError(format!(
+            "{}はYYYY-MM-DD形式で指定してください: {}",
+            label, value
+        ))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::BTreeMap, sync::Mutex};
+
+    use javelin_domain::{
+        error::DomainResult,
+        financial_close::journal_entry::{
+            events::JournalEntryEvent,
+            services::{VoidedVoucherNumber, VoucherNumberRelease},
+        },
+    };
+
+    use super::*;
+    use crate::testkit::InMemoryEventRepository;
+
+    #[derive(Default)]
+    struct MockInvoiceRepository {
+        invoices: Mutex<BTreeMap<(String, String), SupplierInvoice>>,
+    }
+
+    impl SupplierInvoiceRepository for MockInvoiceRepository {
+        async fn find(
+            &self,
+            counterparty: &str,
+            invoice_number: &str,
+        ) -> DomainResult<Option<SupplierInvoice>> {
+            let key = (counterparty.to_string(), invoice_number.to_string());
+            Ok(self.invoices.lock().unwrap().get(&key).cloned())
+        }
+
+        async fn find_all(&self) -> DomainResult<Vec<SupplierInvoice>> {
+            Ok(self.invoices.lock().unwrap().values().cloned().collect())
+        }
+
+        async fn save(&self, invoice: &SupplierInvoice) -> DomainResult<()> {
+            let key = (invoice.counterparty().to_string(), invoice.invoice_number().to_string());
+            self.invoices.lock().unwrap().insert(key, invoice.clone());
+            Ok(())
+        }
+    }
+
+    struct MockVoucherGenerator;
+
+    impl VoucherNumberGenerator for MockVoucherGenerator {
+        async fn reserve(&self, fiscal_year: u32) -> DomainResult<String> {
+            Ok(format!("V-{}-00001", fiscal_year))
+        }
+
+        async fn confirm(&self, _voucher_number: &str) -> DomainResult<()> {
+            Ok(())
+        }
+
+        async fn release(
+            &self,
+            _voucher_number: &str,
+            _reason: &str,
+        ) -> DomainResult<VoucherNumberRelease> {
+            Ok(VoucherNumberRelease::Returned)
+        }
+
+        async fn voided_numbers(
+            &self,
+            _fiscal_year: Option<u32>,
+        ) -> DomainResult<Vec<VoidedVoucherNumber>> {
+            Ok(Vec::new())
+        }
+    }
+
+    fn register_request(amount: f64) -> RegisterSupplierInvoiceRequest {
+        RegisterSupplierInvoiceRequest {
+            invoice_number: "INV-001".to_string(),
+            counterparty: "X001".to_string(),
+            invoice_date: "2024-04-05".to_string(),
+            due_date: "2024-05-31".to_string(),
+            expense_account_code: "5100".to_string(),
+            currency: "JPY".to_string(),
+            amount,
+            tax_amount: amount / 10.0,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_invoice_flows_from_register_to_draft_and_payment() {
+        let events = Arc::new(InMemoryEventRepository::default());
+        let interactor = SupplierInvoiceInteractor::new(
+            Arc::new(MockInvoiceRepository::default()),
+            Arc::clone(&events),
+            Arc::new(MockVoucherGenerator),
+        );
+        interactor.register(register_request(100_000.0)).await.unwrap();
+        let payment = |amount: f64| RecordInvoicePaymentRequest {
+            counterparty: "X001".to_string(),
+            invoice_number: "INV-001".to_string(),
+            paid_date: "2024-05-31".to_string(),
+            amount,
+        };
+        assert!(interactor.record_payment(payment(110_000.0)).await.is_err());
+
+        let draft_request = CreateInvoiceJournalDraftRequest {
+            user_id: "user1".to_string(),
+            counterparty: "X001".to_string(),
+            invoice_number: "INV-001".to_string(),
+        };
+        let draft = interactor.create_journal_draft(draft_request.clone()).await.unwrap();
+        assert_eq!(
+            (draft.voucher_number.as_str(), draft.status.as_str()),
+            ("V-2024-00001", "Draft")
+        );
+        assert!(interactor.create_journal_draft(draft_request).await.is_err());
+        assert!(interactor.register(register_request(90_000.0)).await.is_err());
+
+        let event: JournalEntryEvent =
+            serde_json::from_value(events.recorded()[0].payload.clone()).unwrap();
+        let JournalEntryEvent::DraftCreated { transaction_date, lines, .. } = event else {
+            panic!("DraftCreated expected");
+        };
+        assert_eq!(transaction_date, "2024-04-05");
+        assert_eq!((lines[0].side.as_str(), lines[0].account_code.as_str()), ("Debit", "5100"));
+        assert_eq!((lines[0].amount, lines[0].tax_amount), (110_000.0, 10_000.0));
+        assert_eq!(lines[1].account_code, ACCOUNTS_PAYABLE_ACCOUNT_CODE);
+
+        let paid = interactor.record_payment(payment(110_000.0)).await.unwrap();
+        assert_eq!(paid.payment_status, "Paid");
+        assert_eq!(paid.outstanding_amount, 0.0);
+
+        let listed = interactor.list(NaiveDate::from_ymd_opt(2024, 6, 30).unwrap()).await.unwrap();
+        assert_eq!(listed[0].journal_entry_id.as_deref(), Some(draft.entry_id.as_str()));
+        assert!(!listed[0].overdue);
+    }
+}
@@ -0,0 +1,429 @@
+// SuspenseClearingInteractor - 仮勘定の滞留分析・整理のユースケース
+// 責務: 仮勘定計上の経過日数分析と、整理仕訳（振替仕訳）の提案・起票
+
+use std::{collections::HashSet, sync::Arc};
+
+use chrono::{Datelike, NaiveDate};
+use javelin_domain::{
+    entity::EntityId,
+    financial_close::journal_entry::{
+        entities::{JournalEntry, JournalEntryId, JournalEntryLine},
+        services::{JournalEntryService, VoucherNumberGenerator},
+        values::{TransactionDate, UserId, VoucherNumber},
+    },
+    id_generator::AggregateIdGenerator,
+    repositories::EventRepository,
+};
+
+use crate::{
+    dtos::{
+        JournalEntryLineDto,
+        request::{AcceptClearingSuggestionRequest, SuspenseAgingRequest},
+        response::{
+            AcceptClearingSuggestionResponse, AgingBucket, AgingBucketSummary, ClearingSuggestion,
+            SuspenseAgingItem, SuspenseAgingResponse,
+        },
+    },
+    error::{ApplicationError, ApplicationResult},
+    id_generator::TimeOrderedIdGenerator,
+    query_service::{OpenSuspenseItem, SuspenseAgingQueryService},
+};
+
+/// 仮勘定の滞留分析・整理のInteractor
+///
+/// 整理先の候補は1件の明細につき1件の仮勘定計上にのみ割り当てる。
+/// 計上の古い順に、未割当の候補のうち最も早く記帳されたものを提案する。
+pub struct SuspenseClearingInteractor<Q, R, V>
+where
+    Q: SuspenseAgingQueryService,
+    R: EventRepository,
+    V: VoucherNumberGenerator,
+{
+    query_service: Arc<Q>,
+    event_repository: Arc<R>,
+    voucher_generator: Arc<V>,
+}
+
+impl<Q, R, V> SuspenseClearingInteractor<Q, R, V>
+where
+    Q: SuspenseAgingQueryService,
+    R: EventRepository,
+    V: VoucherNumberGenerator,
+{
+    pub fn new(query_service: Arc<Q>, event_repository: Arc<R>, voucher_generator: Arc<V>) -> Self {
+        Self { query_service, event_repository, voucher_generator }
+    }
+
+    /// 基準日時点の仮勘定の滞留状況を分析
+    pub async fn load(
+        &self,
+        request: SuspenseAgingRequest,
+    ) -> ApplicationResult<SuspenseAgingResponse> {
+        let as_of = parse_date(&request.as_of_date)?;
+        let open_items = self.query_service.get_open_suspense_items().await?;
+
+        let items = age_open_items(&open_items, as_of);
+        let buckets = summarize_buckets(&items);
+
+        Ok(SuspenseAgingResponse { as_of_date: request.as_of_date, items, buckets })
+    }
+
+    /// 提案された整理仕訳を下書きとして起票
+    ///
+    /// 仮勘定計上の反対側に仮勘定、計上側に整理先の勘定科目を置き、
+    /// 整理先の明細の取引日で振替仕訳を作成する。
+    pub async fn accept(
+        &self,
+        request: AcceptClearingSuggestionRequest,
+    ) -> ApplicationResult<AcceptClearingSuggestionResponse> {
+        let open_items = self.query_service.get_open_suspense_items().await?;
+        let (item, suggestion) = open_items
+            .iter()
+            .zip(suggest(&open_items))
+            .find(|(item, _)| {
+                item.entry_id == request.entry_id && item.line_number == request.line_number
+            })
+            .ok_or_else(|| {
+                ApplicationError::ValidationFailed(vec![format!(
+                    "未整理の仮勘定計上が見つかりません: {} 行{}",
+                    request.entry_id, request.line_number
+                )])
+            })?;
+        let suggestion = suggestion.ok_or_else(|| {
+            ApplicationError::ValidationFailed(vec![format!(
+                "整理仕訳の提案がありません: {}",
+                item.entry_number
+            )])
+        })?;
+
+        let date = parse_date(&suggestion.matched_transaction_date)?;
+        let transaction_date = TransactionDate::new(date).map_err(ApplicationError::DomainError)?;
+        let description = format!("仮勘定整理 {}", item.entry_number);
+        let suspense_side = opposite_side(&item.side);
+        let line = |line_number: u32, side: &str, account_code: &str| JournalEntryLineDto {
+            line_number,
+            side: side.to_string(),
+            account_code: account_code.to_string(),
+            sub_account_code: None,
+            department_code: None,
+            dimensions: Default::default(),
+            amount: item.amount,
+            currency: item.currency.clone(),
+            tax_type: "NonTaxable".to_string(),
+            tax_amount: 0.0,
+            description: Some(description.clone()),
+        };
+        let dtos = [
+            line(1, suspense_side, &item.account_code),
+            line(2, &item.side, &suggestion.account_code),
+        ];
+        let lines: Vec<JournalEntryLine> =
+            dtos.iter().map(|dto| dto.try_into()).collect::<Result<_, _>>()?;
+        JournalEntryService::validate_balance(&lines).map_err(ApplicationError::DomainError)?;
+
+        let voucher_number = self
+            .voucher_generator
+            .reserve(date.year() as u32)
+            .await
+            .map_err(ApplicationError::DomainError)?;
+
+        // 保存に失敗した場合は予約した伝票番号を取り消す
+        let entry_id = JournalEntryId::new(TimeOrderedIdGenerator.next_id());
+        let result: ApplicationResult<JournalEntry> = async {
+            let journal_entry = JournalEntry::new(
+                entry_id.clone(),
+                transaction_date,
+                VoucherNumber::new(voucher_number.clone())
+                    .map_err(ApplicationError::DomainError)?,
+                lines,
+                UserId::new(request.user_id),
+            )
+            .map_err(ApplicationError::DomainError)?;
+
+            self.event_repository
+                .append_events(entry_id.value(), journal_entry.events().to_vec())
+                .await
+                .map_err(ApplicationError::DomainError)?;
+
+            Ok(journal_entry)
+        }
+        .await;
+        let journal_entry = self.voucher_generator.settle(&voucher_number, result).await?;
+
+        Ok(AcceptClearingSuggestionResponse {
+            entry_id: entry_id.value().to_string(),
+            voucher_number,
+            status: journal_entry.status().as_str().to_string(),
+        })
+    }
+}
+
+/// 未整理の仮勘定計上の基準日時点の経過日数と整理先の提案（経過日数の長い順）
+pub(crate) fn age_open_items(
+    open_items: &[OpenSuspenseItem],
+    as_of: NaiveDate,
+) -> Vec<SuspenseAgingItem> {
+    let mut items: Vec<SuspenseAgingItem> = open_items
+        .iter()
+        .zip(suggest(open_items))
+        .map(|(item, suggestion)| {
+            let age_days = NaiveDate::parse_from_str(&item.transaction_date, "%Y-%m-%d")
+                .map(|date| (as_of - date).num_days().max(0))
+                .unwrap_or_default();
+            SuspenseAgingItem {
+                entry_id: item.entry_id.clone(),
+                entry_number: item.entry_number.clone(),
+                line_number: item.line_number,
+                transaction_date: item.transaction_date.clone(),
+                account_code: item.account_code.clone(),
+                side: item.side.clone(),
+                amount: item.amount,
+                currency: item.currency.clone(),
+                description: item.description.clone(),
+                age_days,
+                bucket: AgingBucket::from_age_days(age_days),
+                suggestion,
+            }
+        })
+        .collect();
+    items.sort_by_key(|item| std::cmp::Reverse(item.age_days));
+    items
+}
+
+/// 経過日数区分ごとの件数・金額（AgingBucket::ALLの順）
+pub(crate) fn summarize_buckets(items: &[SuspenseAgingItem]) -> Vec<AgingBucketSummary> {
+    AgingBucket::ALL
+        .into_iter()
+        .map(|bucket| {
+            let in_bucket = items.iter().filter(|item| item.bucket == bucket);
+            AgingBucketSummary {
+                bucket,
+                count: in_bucket.clone().count(),
+                amount: in_bucket.map(|item| item.amount).sum(),
+            }
+        })
+        .collect()
+}
+
+fn parse_date(value: &str) -> ApplicationResult<NaiveDate> {
+    NaiveDate::parse_from_str(value, "%Y-%m-%d").map_err(|_| {
+        ApplicationError::ValidationFailed(vec![format!("日付形式が不正です: {}", value)])
+    })
+}
+
+fn opposite_side(side: &str) -> &'static str {
+    if side == "Debit" { "Credit" } else { "Debit" }
+}
+
+/// 仮勘定計上ごとに整理先を割り当てる（計上順、候補の重複割当なし）
+fn suggest(items: &[OpenSuspenseItem]) -> Vec<Option<ClearingSuggestion>> {
+    let mut used: HashSet<(&str, u32)> = HashSet::new();
+    items
+        .iter()
+        .map(|item| {
+            let candidate = item
+                .candidates
+                .iter()
+                .find(|c| !used.contains(&(c.entry_id.as_str(), c.line_number)))?;
+            used.insert((candidate.entry_id.as_str(), candidate.line_number));
+            Some(ClearingSuggestion {
+                matched_entry_id: candidate.entry_id.clone(),
+                matched_entry_number: candidate.entry_number.clone(),
+                matched_line_number: candidate.line_number,
+                matched_transaction_date: candidate.transaction_date.clone(),
+                account_code: candidate.account_code.clone(),
+                description: candidate.description.clone(),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+
+    use javelin_domain::{
+        error::DomainResult,
+        financial_close::journal_entry::{
+            events::JournalEntryEvent,
+            services::{VoidedVoucherNumber, VoucherNumberRelease},
+        },
+    };
+
+    use super::*;
+    use crate::{query_service::ClearingCandidate, testkit::InMemoryEventRepository};
+
+    struct MockQueryService {
+        items: Vec<OpenSuspenseItem>,
+    }
+
+    impl SuspenseAgingQueryService for MockQueryService {
+        async fn get_open_suspense_items(&self) -> ApplicationResult<Vec<OpenSuspenseItem>> {
+            Ok(self.items.clone())
+        }
+    }
+
+    struct MockVoucherGenerator;
+
+    impl VoucherNumberGenerator for MockVoucherGenerator {
+        async fn reserve(&self, fiscal_year: u32) -> DomainResult<String> {
+            Ok(format!("V-{}-00001", fiscal_year))
+        }
+
+        async fn confirm(&self, _voucher_number: &str) -> DomainResult<()> {
+            Ok(())
+        }
+
+        async fn release(
+            &self,
+            _voucher_number: &str,
+            _reason: &str,
+        ) -> DomainResult<VoucherNumberRelease> {
+            Ok(VoucherNumberRelease::Returned)
+        }
+
+        async fn voided_numbers(
+            &self,
+            _fiscal_year: Option<u32>,
+        ) -> DomainResult<Vec<VoidedVoucherNumber>> {
+            Ok(Vec::new())
+        }
+    }
+
+    fn candidate(entry_id: &str, date: &str, account_code: &str) -> ClearingCandidate {
+        ClearingCandidate {
+            entry_id: entry_id.to_string(),
+            entry_number: format!("EN-{}", entry_id),
+            line_number: 1,
+            transaction_date: date.to_string(),
+            account_code: account_code.to_string(),
+            description: None,
+        }
+    }
+
+    fn open_item(
+        entry_id: &str,
+        date: &str,
+        candidates: Vec<ClearingCandidate>,
+    ) -> OpenSuspenseItem {
+        OpenSuspenseItem {
+            entry_id: entry_id.to_string(),
+            entry_number: format!("EN-{}", entry_id),
+            line_number: 1,
+            transaction_date: date.to_string(),
+            account_code: "9999".to_string(),
+            side: "Debit".to_string(),
+            amount: 10000.0,
+            currency: "JPY".to_string(),
+            description: None,
+            candidates,
+        }
+    }
+
+    fn interactor(
+        items: Vec<OpenSuspenseItem>,
+    ) -> (
+        SuspenseClearingInteractor<MockQueryService, InMemoryEventRepository, MockVoucherGenerator>,
+        Arc<InMemoryEventRepository>,
+    ) {
+        let repository = Arc::new(InMemoryEventRepository::default());
+        let interactor = SuspenseClearingInteractor::new(
+            Arc::new(MockQueryService { items }),
+            Arc::clone(&repository),
+            Arc::new(MockVoucherGenerator),
+        );
+        (interactor, repository)
+    }
+
+    #[tokio::test]
+    async fn test_items_are_aged_and_bucketed() {
+        let (interactor, _) = interactor(vec![
+            open_item("e1", "2024-01-01", vec![]),
+            open_item("e2", "2024-03-20", vec![]),
+        ]);
+
+        let response = interactor
+            .load(SuspenseAgingRequest { as_of_date: "2024-03-31".to_string() })
+            .await
+            .unwrap();
+
+        assert_eq!(response.items[0].entry_id, "e1");
+        assert_eq!(response.items[0].age_days, 90);
+        assert_eq!(response.items[0].bucket, AgingBucket::Within90Days);
+        assert_eq!(response.items[1].age_days, 11);
+        assert_eq!(response.items[1].bucket, AgingBucket::Within30Days);
+
+        let counts: Vec<usize> = response.buckets.iter().map(|b| b.count).collect();
+        assert_eq!(counts, vec![1, 0, 1, 0]);
+        assert_eq!(response.buckets[0].amount, 10000.0);
+    }
+
+    #[tokio::test]
+    async fn test_candidate_is_suggested_only_once() {
+        let shared = candidate("m1", "2024-02-01", "1100");
+        let (interactor, _) = interactor(vec![
+            open_item("e1", "2024-01-01", vec![shared.clone()]),
+            open_item("e2", "2024-01-02", vec![shared, candidate("m2", "2024-02-02", "1200")]),
+        ]);
+
+        let response = interactor
+            .load(SuspenseAgingRequest { as_of_date: "2024-03-31".to_string() })
+            .await
+            .unwrap();
+
+        let suggested: Vec<&str> = response
+            .items
+            .iter()
+            .map(|item| item.suggestion.as_ref().unwrap().matched_entry_id.as_str())
+            .collect();
+        assert_eq!(suggested, vec!["m1", "m2"]);
+    }
+
+    #[tokio::test]
+    async fn test_accept_creates_reclassification_draft() {
+        let (interactor, repository) = interactor(vec![open_item(
+            "e1",
+            "2024-01-01",
+            vec![candidate("m1", "2024-02-01", "1100")],
+        )]);
+
+        let response = interactor
+            .accept(AcceptClearingSuggestionRequest {
+                user_id: "user1".to_string(),
+                entry_id: "e1".to_string(),
+                line_number: 1,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(response.voucher_number, "V-2024-00001");
+        assert_eq!(response.status, "Draft");
+
+        let saved = repository.recorded();
+        assert_eq!(saved.len(), 1);
+        let event: JournalEntryEvent = serde_json::from_value(saved[0].payload.clone()).unwrap();
+        let JournalEntryEvent::DraftCreated { transaction_date, lines, .. } = event else {
+            panic!("DraftCreated expected");
+        };
+        assert_eq!(transaction_date, "2024-02-01");
+        assert_eq!(lines[0].side, "Credit");
+        assert_eq!(lines[0].account_code, "9999");
+        assert_eq!(lines[1].side, "Debit");
+        assert_eq!(lines[1].account_code, "1100");
+    }
+
+    #[tokio::test]
+    async fn test_accept_without_suggestion_fails() {
+        let (interactor, repository) = interactor(vec![open_item("e1", "2024-01-01", vec![])]);
+
+        let result = interactor
+            .accept(AcceptClearingSuggestionRequest {
+                user_id: "user1".to_string(),
+                entry_id: "e1".to_string(),
+                line_number: 1,
+            })
+            .await;
+
+        assert!(result.is_err());
+        assert!(repository.is_empty());
+    }
+}
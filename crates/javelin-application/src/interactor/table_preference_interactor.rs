@@ -0,0 +1,67 @@
+// TablePreferenceInteractor - 一覧テーブル表示設定操作のユースケース
+
+use std::sync::Arc;
+
+use javelin_domain::{
+    masters::{ColumnPreference, TablePreference, TableSort},
+    repositories::TablePreferenceRepository,
+};
+
+use crate::{
+    dtos::{
+        request::SaveTablePreferenceRequest,
+        response::{ColumnPreferenceDto, TablePreferenceResponse, TableSortDto},
+    },
+    error::ApplicationResult,
+};
+
+/// 一覧テーブル表示設定操作のInteractor
+pub struct TablePreferenceInteractor<R>
+where
+    R: TablePreferenceRepository,
+{
+    repository: Arc<R>,
+}
+
+impl<R> TablePreferenceInteractor<R>
+where
+    R: TablePreferenceRepository,
+{
+    pub fn new(repository: Arc<R>) -> Self {
+        Self { repository }
+    }
+
+    /// 表示設定を取得（未保存の場合はNone）
+    pub async fn load(&self, table_id: &str) -> ApplicationResult<Option<TablePreferenceResponse>> {
+        let preference = self.repository.find(table_id).await?;
+
+        Ok(preference.map(|preference| TablePreferenceResponse {
+            table_id: preference.table_id().to_string(),
+            columns: preference
+                .columns()
+                .iter()
+                .map(|c| ColumnPreferenceDto {
+                    column: c.column(),
+                    visible: c.visible(),
+                    width: c.width(),
+                })
+                .collect(),
+            sort: preference
+                .sort()
+                .map(|s| TableSortDto { column: s.column(), descending: s.descending() }),
+        }))
+    }
+
+    /// 表示設定を保存
+    pub async fn save(&self, request: SaveTablePreferenceRequest) -> ApplicationResult<()> {
+        let columns = request
+            .columns
+            .iter()
+            .map(|c| ColumnPreference::new(c.column, c.visible, c.width))
+            .collect::<Result<Vec<_>, _>>()?;
+        let sort = request.sort.map(|s| TableSort::new(s.column, s.descending));
+        let preference = TablePreference::new(request.table_id, columns, sort)?;
+
+        Ok(self.repository.save(&preference).await?)
+    }
+}
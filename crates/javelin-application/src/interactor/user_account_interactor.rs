@@ -0,0 +1,210 @@
+// UserAccountInteractor - 利用者管理のユースケース
+// 責務: 管理者による利用者アカウントの登録
+
+use std::sync::Arc;
+
+use javelin_domain::{
+    error::{DomainError, DomainResult},
+    masters::{PasswordHasher, UserAccount},
+    repositories::{AccountingPolicyRepository, UserAccountRepository},
+};
+
+use crate::{
+    dtos::{request::RegisterUserAccountRequest, response::LoginResponse},
+    error::{ApplicationError, ApplicationResult},
+};
+
+/// 利用者管理のInteractor
+///
+/// 初回起動時のアカウント以外の利用者は、会計方針の管理者が登録する。
+pub struct UserAccountInteractor<R, P, H>
+where
+    R: UserAccountRepository,
+    P: AccountingPolicyRepository,
+    H: PasswordHasher + 'static,
+{
+    repository: Arc<R>,
+    policy_repository: Arc<P>,
+    hasher: Arc<H>,
+}
+
+impl<R, P, H> UserAccountInteractor<R, P, H>
+where
+    R: UserAccountRepository,
+    P: AccountingPolicyRepository,
+    H: PasswordHasher + 'static,
+{
+    pub fn new(repository: Arc<R>, policy_repository: Arc<P>, hasher: Arc<H>) -> Self {
+        Self { repository, policy_repository, hasher }
+    }
+
+    /// 利用者を登録（登録済みのユーザIDは上書きしない）
+    pub async fn register(
+        &self,
+        request: RegisterUserAccountRequest,
+    ) -> ApplicationResult<LoginResponse> {
+        let policy = self.policy_repository.load().await?;
+        if !policy.is_administrator(&request.registered_by) {
+            return Err(ApplicationError::DomainError(DomainError::PermissionDenied(format!(
+                "利用者の登録は管理者のみ可能です（ユーザ: {}）",
+                request.registered_by
+            ))));
+        }
+
+        let user_id = request.user_id.trim().to_string();
+        UserAccount::validate_user_id(&user_id)?;
+        UserAccount::validate_password(&request.password)?;
+        if self.repository.find_by_id(&user_id).await?.is_some() {
+            return Err(ApplicationError::ValidationError(format!(
+                "ユーザID {} は登録済みです",
+                user_id
+            )));
+        }
+
+        let password = request.password;
+        let hash = self.blocking(move |hasher| hasher.hash(&password)).await?;
+        let account = UserAccount::new(user_id, request.display_name.trim(), hash)?;
+        self.repository.save(&account).await?;
+
+        Ok(LoginResponse {
+            user_id: account.user_id().to_string(),
+            display_name: account.display_name().to_string(),
+            account_created: true,
+        })
+    }
+
+    /// ハッシュ計算は意図的に低速なため、ブロッキングスレッドで実行する
+    async fn blocking<T, F>(&self, f: F) -> ApplicationResult<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(&H) -> DomainResult<T> + Send + 'static,
+    {
+        let hasher = Arc::clone(&self.hasher);
+        let result = tokio::task::spawn_blocking(move || f(&hasher))
+            .await
+            .map_err(|e| ApplicationError::UseCaseExecutionFailed(e.to_string()))?;
+        Ok(result?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use javelin_domain::masters::{
+        AccountingPolicy, AccountingPolicyChanged, DEFAULT_POLICY_ADMINISTRATOR, PasswordHash,
+    };
+
+    use super::*;
+
+    #[derive(Default)]
+    struct MockRepository {
+        accounts: Mutex<Vec<UserAccount>>,
+    }
+
+    impl UserAccountRepository for MockRepository {
+        async fn find_by_id(&self, user_id: &str) -> DomainResult<Option<UserAccount>> {
+            Ok(self.accounts.lock().unwrap().iter().find(|a| a.user_id() == user_id).cloned())
+        }
+
+        async fn save(&self, account: &UserAccount) -> DomainResult<()> {
+            let mut accounts = self.accounts.lock().unwrap();
+            accounts.retain(|a| a.user_id() != account.user_id());
+            accounts.push(account.clone());
+            Ok(())
+        }
+
+        async fn count(&self) -> DomainResult<usize> {
+            Ok(self.accounts.lock().unwrap().len())
+        }
+    }
+
+    struct MockPolicyRepository;
+
+    impl AccountingPolicyRepository for MockPolicyRepository {
+        async fn load(&self) -> DomainResult<AccountingPolicy> {
+            Ok(AccountingPolicy::default())
+        }
+
+        async fn save(
+            &self,
+            _policy: &AccountingPolicy,
+            _change: &AccountingPolicyChanged,
+        ) -> DomainResult<()> {
+            Ok(())
+        }
+
+        async fn find_changes(&self) -> DomainResult<Vec<AccountingPolicyChanged>> {
+            Ok(Vec::new())
+        }
+    }
+
+    struct PlainHasher;
+
+    impl PasswordHasher for PlainHasher {
+        fn hash(&self, password: &str) -> DomainResult<PasswordHash> {
+            PasswordHash::new(format!("plain${}", password))
+        }
+
+        fn verify(&self, password: &str, hash: &PasswordHash) -> DomainResult<bool> {
+            Ok(hash.as_str() == format!("plain${}", password))
+        }
+    }
+
+    fn request(registered_by: &str, user_id: &str, password: &str) -> RegisterUserAccountRequest {
+        RegisterUserAccountRequest {
+            registered_by: registered_by.to_string(),
+            user_id: user_id.to_string(),
+            display_name: "鈴木".to_string(),
+            password: password.to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_administrator_registers_account() {
+        let repository = Arc::new(MockRepository::default());
+        let interactor = UserAccountInteractor::new(
+            Arc::clone(&repository),
+            Arc::new(MockPolicyRepository),
+            Arc::new(PlainHasher),
+        );
+
+        let response = interactor
+            .register(request(DEFAULT_POLICY_ADMINISTRATOR, "suzuki", "approve-pw"))
+            .await
+            .unwrap();
+        assert_eq!(response.display_name, "鈴木");
+
+        let stored = repository.find_by_id("suzuki").await.unwrap().unwrap();
+        assert_eq!(stored.password_hash().as_str(), "plain$approve-pw");
+
+        // 登録済みのユーザIDは上書きしない
+        let duplicate = interactor
+            .register(request(DEFAULT_POLICY_ADMINISTRATOR, "suzuki", "other-pw"))
+            .await;
+        assert!(matches!(duplicate, Err(ApplicationError::ValidationError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_registration_requires_administrator_and_strong_password() {
+        let repository = Arc::new(MockRepository::default());
+        let interactor = UserAccountInteractor::new(
+            Arc::clone(&repository),
+            Arc::new(MockPolicyRepository),
+            Arc::new(PlainHasher),
+        );
+
+        let result = interactor.register(request("clerk", "suzuki", "approve-pw")).await;
+        assert!(matches!(
+            result,
+            Err(ApplicationError::DomainError(DomainError::PermissionDenied(_)))
+        ));
+        assert!(
+            interactor
+                .register(request(DEFAULT_POLICY_ADMINISTRATOR, "suzuki", "short"))
+                .await
+                .is_err()
+        );
+        assert_eq!(repository.count().await.unwrap(), 0);
+    }
+}
@@ -0,0 +1,227 @@
+// UserActivityReportInteractor - 利用状況レポートのユースケース
+// 責務: 管理者向けに、画面の利用状況・処理時間の長い操作・日ごとの利用者数を集計
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    sync::Arc,
+};
+
+use javelin_domain::{
+    error::DomainError, masters::UserActionKind, repositories::AccountingPolicyRepository,
+};
+
+use crate::{
+    dtos::{
+        request::UserActivityReportRequest,
+        response::{
+            DailyActivityItem, OperationLatencyItem, ScreenUsageItem, UserActivityReportResponse,
+        },
+    },
+    error::{ApplicationError, ApplicationResult},
+    user_activity::UserActivityProjection,
+};
+
+/// 処理時間の長い操作の表示件数
+const SLOWEST_OPERATION_LIMIT: usize = 10;
+
+/// 利用状況レポートのInteractor
+///
+/// 照会できるのは会計方針の管理者に限る。
+pub struct UserActivityReportInteractor<R>
+where
+    R: AccountingPolicyRepository,
+{
+    activity: Arc<dyn UserActivityProjection>,
+    policy_repository: Arc<R>,
+}
+
+impl<R> UserActivityReportInteractor<R>
+where
+    R: AccountingPolicyRepository,
+{
+    pub fn new(activity: Arc<dyn UserActivityProjection>, policy_repository: Arc<R>) -> Self {
+        Self { activity, policy_repository }
+    }
+
+    pub async fn execute(
+        &self,
+        request: UserActivityReportRequest,
+    ) -> ApplicationResult<UserActivityReportResponse> {
+        let policy = self.policy_repository.load().await?;
+        if !policy.is_administrator(&request.user_id) {
+            return Err(ApplicationError::DomainError(DomainError::PermissionDenied(format!(
+                "利用状況レポートは管理者のみ照会できます（ユーザ: {}）",
+                request.user_id
+            ))));
+        }
+
+        let activity = self.activity.daily_activity(request.from).await?;
+
+        // 画面ごとの表示回数・利用者・表示時間
+        let mut screens: BTreeMap<&str, (u64, BTreeSet<&str>, u64)> = BTreeMap::new();
+        // 操作ごとの件数・合計時間・最大時間
+        let mut operations: BTreeMap<(&str, &str), (u64, u64, u64)> = BTreeMap::new();
+        // 日ごとの件数・利用者
+        let mut daily: BTreeMap<_, (u64, BTreeSet<&str>)> = BTreeMap::new();
+
+        for item in &activity {
+            let day = daily.entry(item.date).or_default();
+            day.0 += item.count;
+            day.1.extend(item.users.iter().map(String::as_str));
+
+            if item.is_kind(UserActionKind::ScreenVisited) {
+                let screen = screens.entry(item.screen.as_str()).or_default();
+                screen.0 += item.count;
+                screen.1.extend(item.users.iter().map(String::as_str));
+                screen.2 = screen.2.saturating_add(item.total_duration_ms);
+            } else {
+                let operation =
+                    operations.entry((item.screen.as_str(), item.action.as_str())).or_default();
+                operation.0 += item.count;
+                operation.1 = operation.1.saturating_add(item.total_duration_ms);
+                operation.2 = operation.2.max(item.max_duration_ms);
+            }
+        }
+
+        let mut screens: Vec<_> = screens
+            .into_iter()
+            .map(|(screen, (visits, users, total_duration_ms))| ScreenUsageItem {
+                screen: screen.to_string(),
+                visits,
+                users: users.len(),
+                total_duration_ms,
+            })
+            .collect();
+        screens.sort_by(|a, b| b.visits.cmp(&a.visits).then_with(|| a.screen.cmp(&b.screen)));
+
+        let mut slowest_operations: Vec<_> = operations
+            .into_iter()
+            .map(|((screen, action), (count, total, max))| OperationLatencyItem {
+                screen: screen.to_string(),
+                action: action.to_string(),
+                count,
+                average_duration_ms: total / count.max(1),
+                max_duration_ms: max,
+            })
+            .collect();
+        slowest_operations.sort_by(|a, b| {
+            b.max_duration_ms
+                .cmp(&a.max_duration_ms)
+                .then_with(|| b.average_duration_ms.cmp(&a.average_duration_ms))
+        });
+        slowest_operations.truncate(SLOWEST_OPERATION_LIMIT);
+
+        Ok(UserActivityReportResponse {
+            from: request.from.format("%Y-%m-%d").to_string(),
+            screens,
+            slowest_operations,
+            daily: daily
+                .into_iter()
+                .map(|(date, (actions, users))| DailyActivityItem {
+                    date: date.format("%Y-%m-%d").to_string(),
+                    actions,
+                    active_users: users.len(),
+                })
+                .collect(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, NaiveDate, Utc};
+    use javelin_domain::{
+        error::DomainResult,
+        masters::{
+            AccountingPolicy, AccountingPolicyChanged, DEFAULT_POLICY_ADMINISTRATOR, UserAction,
+        },
+    };
+
+    use super::*;
+    use crate::user_activity::DailyUserActivity;
+
+    struct MockActivity {
+        days: Vec<DailyUserActivity>,
+    }
+
+    #[async_trait::async_trait]
+    impl UserActivityProjection for MockActivity {
+        async fn apply(&self, _action: &UserAction, _at: DateTime<Utc>) -> ApplicationResult<()> {
+            Ok(())
+        }
+
+        async fn daily_activity(
+            &self,
+            from: NaiveDate,
+        ) -> ApplicationResult<Vec<DailyUserActivity>> {
+            Ok(self.days.iter().filter(|day| day.date >= from).cloned().collect())
+        }
+    }
+
+    struct MockPolicyRepository;
+
+    impl AccountingPolicyRepository for MockPolicyRepository {
+        async fn load(&self) -> DomainResult<AccountingPolicy> {
+            Ok(AccountingPolicy::default())
+        }
+
+        async fn save(
+            &self,
+            _policy: &AccountingPolicy,
+            _change: &AccountingPolicyChanged,
+        ) -> DomainResult<()> {
+            Ok(())
+        }
+
+        async fn find_changes(&self) -> DomainResult<Vec<AccountingPolicyChanged>> {
+            Ok(Vec::new())
+        }
+    }
+
+    fn aggregate(day: u32, actions: &[UserAction]) -> DailyUserActivity {
+        let date = NaiveDate::from_ymd_opt(2024, 4, day).unwrap();
+        let mut daily = DailyUserActivity::new(date, &actions[0]);
+        actions.iter().for_each(|action| daily.add(action));
+        daily
+    }
+
+    #[tokio::test]
+    async fn test_report_ranks_screens_and_slowest_operations() {
+        let visit =
+            |user: &str, screen: &str, ms| UserAction::screen_visit(user, screen, ms).unwrap();
+        let run = |user: &str, action: &str, ms| {
+            UserAction::new(user, UserActionKind::ActionPerformed, "ReportHub", action, ms).unwrap()
+        };
+        let days = vec![
+            aggregate(1, &[visit("alice", "Ledger", 5_000)]),
+            aggregate(
+                2,
+                &[visit("alice", "TrialBalance", 1_000), visit("bob", "TrialBalance", 3_000)],
+            ),
+            aggregate(2, &[run("alice", "export", 200), run("bob", "export", 400)]),
+            aggregate(2, &[run("bob", "audit_export", 9_000)]),
+        ];
+        let interactor = UserActivityReportInteractor::new(
+            Arc::new(MockActivity { days }),
+            Arc::new(MockPolicyRepository),
+        );
+        let request = |user_id: &str| UserActivityReportRequest {
+            user_id: user_id.to_string(),
+            from: NaiveDate::from_ymd_opt(2024, 4, 1).unwrap(),
+        };
+
+        let report = interactor.execute(request(DEFAULT_POLICY_ADMINISTRATOR)).await.unwrap();
+        assert_eq!(report.screens[0].screen, "TrialBalance");
+        assert_eq!((report.screens[0].visits, report.screens[0].users), (2, 2));
+        assert_eq!(report.screens[0].total_duration_ms, 4_000);
+        assert_eq!(report.slowest_operations[0].action, "audit_export");
+        assert_eq!(report.slowest_operations[1].average_duration_ms, 300);
+        assert_eq!(report.daily.len(), 2);
+        assert_eq!((report.daily[1].actions, report.daily[1].active_users), (5, 2));
+
+        assert!(matches!(
+            interactor.execute(request("clerk")).await,
+            Err(ApplicationError::DomainError(DomainError::PermissionDenied(_)))
+        ));
+    }
+}
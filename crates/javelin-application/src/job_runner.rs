@@ -0,0 +1,45 @@
+// JobRunner - 長時間処理ジョブの実行インターフェース
+// 責務: ジョブ種別ごとの処理呼び出しの抽象化
+// 具象実装: 各ユースケースを呼び出せるAdapter層で提供
+
+use std::future::Future;
+
+use javelin_domain::job::JobKind;
+use tokio::sync::mpsc;
+
+use crate::error::ApplicationResult;
+
+/// ジョブの進捗通知
+///
+/// 実行中の処理から進捗（0〜100）とメッセージを送る。
+/// 受け取ったジョブキューが進捗を永続化する。
+#[derive(Debug, Clone)]
+pub struct JobProgress {
+    sender: mpsc::UnboundedSender<(u8, String)>,
+}
+
+impl JobProgress {
+    pub fn new(sender: mpsc::UnboundedSender<(u8, String)>) -> Self {
+        Self { sender }
+    }
+
+    /// 進捗を通知（ジョブキューが終了済みの場合は破棄される）
+    pub fn report(&self, progress: u8, message: impl Into<String>) {
+        let _ = self.sender.send((progress, message.into()));
+    }
+}
+
+/// ジョブ実行トレイト
+///
+/// ジョブキューのワーカーから別タスクで呼び出される。
+/// 取消時はタスクごと中断されるため、処理は途中で打ち切られても
+/// 不整合が残らない単位で永続化すること。
+pub trait JobRunner: Send + Sync + 'static {
+    /// ジョブを実行し、完了時のメッセージを返す
+    fn run(
+        &self,
+        kind: JobKind,
+        parameters: Vec<(String, String)>,
+        progress: JobProgress,
+    ) -> impl Future<Output = ApplicationResult<String>> + Send;
+}
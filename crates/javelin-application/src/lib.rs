@@ -1,11 +1,23 @@
 // Application Layer - ユースケース / Query / Projection制御
 // 依存方向: → Domain
 
+pub mod business_metrics;
 pub mod error;
+pub mod error_report;
+pub mod id_generator;
 pub mod interactor;
+pub mod job_runner;
 pub mod output_port;
+pub mod parameter_validation;
 pub mod projection_builder;
+pub mod projection_compactor;
+pub mod projection_events;
 pub mod query_service;
+pub mod spreadsheet_reader;
+pub mod storage_telemetry;
+#[cfg(any(test, feature = "testkit"))]
+pub mod testkit;
+pub mod user_activity;
 
 // DTOs - Request/Response data transfer objects
 pub mod dtos {
@@ -26,23 +38,26 @@ pub mod dtos {
         GetJournalEntryQuery, JournalEntryLineDto, ListJournalEntriesQuery,
         LoadAccountMasterRequest, LockClosingPeriodRequest, PrepareClosingRequest,
         RecordUserActionRequest, RegisterJournalEntryRequest, RejectJournalEntryRequest,
-        ReverseJournalEntryRequest, SubmitForApprovalRequest, UpdateDraftJournalEntryRequest,
+        ReverseJournalEntryRequest, SubmitForApprovalRequest, TranslationRateDto,
+        UpdateDraftJournalEntryRequest,
     };
     // Response types
     pub use response::{
         AccountBalanceDto, AccountBreakdownDto, AccountMasterItem, AccountReclassificationDto,
         AdjustAccountsResponse, ApplyIfrsValuationResponse, ApproveJournalEntryResponse,
         BankReconciliationDifferenceDto, ConsolidateLedgerResponse, ContingentLiabilityDto,
-        CorrectJournalEntryResponse, DeleteDraftJournalEntryResponse, FairValueAdjustmentDto,
-        FinancialIndicatorsDto, ForeignExchangeDifferenceDto, GenerateFinancialStatementsResponse,
-        GenerateNoteDraftResponse, GenerateTrialBalanceResponse, ImpairmentLossDto,
-        InventoryWriteDownDto, JournalEntryDetail, JournalEntryLineDetail, JournalEntryListItem,
+        CorrectJournalEntryResponse, CurrencyTrialBalanceDto, DeleteDraftJournalEntryResponse,
+        FairValueAdjustmentDto, FinancialIndicatorsDto, ForeignExchangeDifferenceDto,
+        GenerateFinancialStatementsResponse, GenerateNoteDraftResponse,
+        GenerateTrialBalanceResponse, ImpairmentLossDto, InterestAccrualDto, InventoryWriteDownDto,
+        JournalActionAffordance, JournalEntryDetail, JournalEntryLineDetail, JournalEntryListItem,
         JournalEntryListResult, LeaseMeasurementDto, LedgerDiscrepancyDto,
         LoadAccountMasterResponse, LockClosingPeriodResponse, PrepareClosingResponse,
         RecordUserActionResponse, RegisterJournalEntryResponse, RejectJournalEntryResponse,
         ReverseJournalEntryResponse, StatementOfCashFlowsDto, StatementOfChangesInEquityDto,
         StatementOfFinancialPositionDto, StatementOfProfitOrLossDto, SubmitForApprovalResponse,
-        TaxEffectAdjustmentDto, UpdateDraftJournalEntryResponse,
+        SupportingDocumentDto, TaxEffectAdjustmentDto, TrialBalanceLineDto,
+        UpdateDraftJournalEntryResponse,
     };
 }
 
@@ -64,6 +79,7 @@ pub mod input_ports {
     pub mod generate_trial_balance;
     pub mod load_account_master;
     pub mod load_application_settings;
+    pub mod load_calendar_master;
     pub mod load_company_master;
     pub mod load_subsidiary_account_master;
     pub mod lock_closing_period;
@@ -93,6 +109,7 @@ pub mod input_ports {
     pub use generate_trial_balance::*;
     pub use load_account_master::*;
     pub use load_application_settings::*;
+    pub use load_calendar_master::*;
     pub use load_company_master::*;
     pub use load_subsidiary_account_master::*;
     pub use lock_closing_period::*;
@@ -4,12 +4,14 @@
 use crate::{
     dtos::response::{
         ApproveJournalEntryResponse, CorrectJournalEntryResponse, DeleteDraftJournalEntryResponse,
-        JournalEntryDetail, JournalEntryListResult, JournalEntrySearchResultDto,
-        LoadAccountMasterResponse, LoadApplicationSettingsResponse, LoadCompanyMasterResponse,
-        LoadSubsidiaryAccountMasterResponse, RegisterJournalEntryResponse,
-        RejectJournalEntryResponse, ReverseJournalEntryResponse, SubmitForApprovalResponse,
+        ExportSignatureVerificationDto, JournalEntryDetail, JournalEntryListResult,
+        JournalEntrySearchResultDto, LoadAccountMasterResponse, LoadApplicationSettingsResponse,
+        LoadCalendarMasterResponse, LoadCompanyMasterResponse, LoadSubsidiaryAccountMasterResponse,
+        RegisterJournalEntryResponse, RejectJournalEntryResponse, ReportDeliveryResultDto,
+        ReportDocument, ReverseJournalEntryResponse, SubmitForApprovalResponse,
         UpdateDraftJournalEntryResponse,
     },
+    error::ApplicationResult,
     query_service::{LedgerResult, TrialBalanceResult},
 };
 
@@ -130,6 +132,13 @@ pub trait CompanyMasterOutputPort: Send + Sync {
     async fn present_company_master(&self, response: &LoadCompanyMasterResponse);
 }
 
+/// CalendarMasterOutputPort - カレンダーマスタ結果の出力
+#[allow(async_fn_in_trait)]
+pub trait CalendarMasterOutputPort: Send + Sync {
+    /// カレンダーマスタ結果を出力
+    async fn present_calendar_master(&self, response: &LoadCalendarMasterResponse);
+}
+
 /// ApplicationSettingsOutputPort - アプリケーション設定結果の出力
 #[allow(async_fn_in_trait)]
 pub trait ApplicationSettingsOutputPort: Send + Sync {
@@ -147,6 +156,41 @@ pub trait SubsidiaryAccountMasterOutputPort: Send + Sync {
     );
 }
 
+/// ReportDeliveryOutputPort - 生成した帳票の配信
+///
+/// 帳票の種類ごとに設定された配信先（ファイル・メール・Webhook）へ帳票を送る。
+#[allow(async_fn_in_trait)]
+pub trait ReportDeliveryOutputPort: Send + Sync {
+    /// 帳票を配信し、配信先ごとの結果を返す（配信先が未設定の場合は空）
+    ///
+    /// 配信の失敗は帳票生成の失敗とはせず、結果として返す。
+    async fn deliver(&self, document: &ReportDocument) -> Vec<ReportDeliveryResultDto>;
+}
+
+/// ExportProtectionOutputPort - 出力ファイルの暗号化と分離署名
+///
+/// 端末の外へ持ち出す出力ファイルを、パスワード付きZIP（AES-256）への格納と
+/// このインストールの署名鍵による分離署名で保護する。
+pub trait ExportProtectionOutputPort: Send + Sync {
+    /// 出力内容を1ファイルのパスワード付きZIPに格納する
+    fn encrypt(
+        &self,
+        file_name: &str,
+        content: &[u8],
+        password: &str,
+    ) -> ApplicationResult<Vec<u8>>;
+
+    /// 出力内容の分離署名（署名ファイルの内容）を作成する
+    fn sign(&self, file_name: &str, content: &[u8]) -> ApplicationResult<String>;
+
+    /// 出力内容を分離署名で検証する（署名ファイルの形式が不正な場合はエラー）
+    fn verify(
+        &self,
+        content: &[u8],
+        signature: &str,
+    ) -> ApplicationResult<ExportSignatureVerificationDto>;
+}
+
 /// SearchOutputPort - 仕訳検索結果の出力
 pub trait SearchOutputPort: Send + Sync {
     /// 検索結果を出力
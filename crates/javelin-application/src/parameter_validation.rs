@@ -0,0 +1,162 @@
+// ParameterValidation - 照会・帳票条件の項目間検証
+// 責務: 日付範囲・会計期間の前後関係と、会計カレンダーの範囲外の条件の検出
+//
+// 検索画面・帳票の条件入力フォームが入力中に同じ規則で検証できるよう、
+// 項目ごとの検証エラー（FieldError）を返す。
+
+use std::fmt;
+
+use chrono::{Datelike, NaiveDate};
+
+use crate::error::ApplicationError;
+
+/// 項目ごとの検証エラー
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldError {
+    /// 条件名（画面・DTOの項目名）
+    pub field: String,
+    pub message: String,
+}
+
+impl FieldError {
+    pub fn new(field: impl Into<String>, message: impl Into<String>) -> Self {
+        Self { field: field.into(), message: message.into() }
+    }
+}
+
+impl fmt::Display for FieldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.field, self.message)
+    }
+}
+
+/// 検証エラーがあればApplicationErrorに変換
+pub fn into_result(errors: Vec<FieldError>) -> Result<(), ApplicationError> {
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(ApplicationError::InvalidFields(errors))
+    }
+}
+
+/// 日付範囲の前後関係を検証（誤りは終了日の項目に付ける）
+pub fn check_date_range(from: NaiveDate, to_field: &str, to: NaiveDate) -> Option<FieldError> {
+    (to < from).then(|| {
+        FieldError::new(
+            to_field,
+            format!("終了日（{}）は開始日（{}）以降を指定してください", to, from),
+        )
+    })
+}
+
+/// 会計期間の範囲の前後関係を検証（誤りは終了月の項目に付ける）
+pub fn check_period_range(from: (i32, u32), to_field: &str, to: (i32, u32)) -> Option<FieldError> {
+    (to < from).then(|| {
+        FieldError::new(
+            to_field,
+            format!(
+                "終了月（{}-{:02}）は開始月（{}-{:02}）以降を指定してください",
+                to.0, to.1, from.0, from.1
+            ),
+        )
+    })
+}
+
+/// 会計カレンダー
+///
+/// 会計年度の開始月と業務日付から、条件に指定できる範囲（当会計年度の末日まで）を求める。
+/// 会計年度は開始月を含む暦年で表す（4月開始の場合、2024年度は2024-04〜2025-03）。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FiscalCalendar {
+    start_month: u32,
+    business_date: NaiveDate,
+}
+
+impl FiscalCalendar {
+    pub fn new(start_month: u8, business_date: NaiveDate) -> Self {
+        Self { start_month: u32::from(start_month).clamp(1, 12), business_date }
+    }
+
+    /// 日付が属する会計年度
+    pub fn fiscal_year_of(&self, date: NaiveDate) -> i32 {
+        if date.month() >= self.start_month {
+            date.year()
+        } else {
+            date.year() - 1
+        }
+    }
+
+    /// 業務日付の会計年度
+    pub fn current_fiscal_year(&self) -> i32 {
+        self.fiscal_year_of(self.business_date)
+    }
+
+    /// 業務日付の会計年度の末日
+    pub fn current_fiscal_year_end(&self) -> NaiveDate {
+        let next_start =
+            NaiveDate::from_ymd_opt(self.current_fiscal_year() + 1, self.start_month, 1)
+                .unwrap_or(NaiveDate::MAX);
+        next_start.pred_opt().unwrap_or(next_start)
+    }
+
+    /// 日付が当会計年度の末日までかを検証
+    pub fn check_date(&self, field: &str, date: NaiveDate) -> Option<FieldError> {
+        (date > self.current_fiscal_year_end()).then(|| self.out_of_calendar(field))
+    }
+
+    /// 会計期間（年・月）が当会計年度の末日までかを検証
+    pub fn check_period(&self, field: &str, year: i32, month: u32) -> Option<FieldError> {
+        let first_day = NaiveDate::from_ymd_opt(year, month, 1)?;
+        self.check_date(field, first_day)
+    }
+
+    /// 会計年度が当会計年度までかを検証
+    pub fn check_fiscal_year(&self, field: &str, fiscal_year: i32) -> Option<FieldError> {
+        (fiscal_year > self.current_fiscal_year()).then(|| self.out_of_calendar(field))
+    }
+
+    fn out_of_calendar(&self, field: &str) -> FieldError {
+        FieldError::new(
+            field,
+            format!(
+                "会計カレンダーの範囲外です（当会計年度 {}年度 の末日 {} まで指定できます）",
+                self.current_fiscal_year(),
+                self.current_fiscal_year_end()
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_ranges_and_fiscal_calendar_bounds() {
+        assert!(check_date_range(date(2024, 4, 1), "to_date", date(2024, 4, 1)).is_none());
+        let error = check_date_range(date(2024, 4, 2), "to_date", date(2024, 4, 1)).unwrap();
+        assert_eq!(error.field, "to_date");
+        assert!(check_period_range((2024, 12), "to", (2024, 11)).is_some());
+        assert!(check_period_range((2024, 12), "to", (2025, 1)).is_none());
+
+        // 4月開始、業務日付 2025-02-10 は 2024年度（2024-04〜2025-03）
+        let calendar = FiscalCalendar::new(4, date(2025, 2, 10));
+        assert_eq!(calendar.current_fiscal_year(), 2024);
+        assert_eq!(calendar.current_fiscal_year_end(), date(2025, 3, 31));
+        assert!(calendar.check_period("period", 2025, 3).is_none());
+        assert_eq!(calendar.check_period("period", 2025, 4).unwrap().field, "period");
+        assert!(calendar.check_date("as_of_date", date(2025, 4, 1)).is_some());
+        assert!(calendar.check_fiscal_year("fiscal_year", 2024).is_none());
+        assert!(calendar.check_fiscal_year("fiscal_year", 2025).is_some());
+
+        assert!(matches!(
+            into_result(vec![error]),
+            Err(ApplicationError::InvalidFields(errors)) if errors.len() == 1
+        ));
+        assert!(into_result(Vec::new()).is_ok());
+    }
+}
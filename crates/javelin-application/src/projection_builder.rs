@@ -35,4 +35,23 @@ pub trait ProjectionBuilder: Send + Sync {
     /// # Returns
     /// 成功時はOk(())、失敗時はエラー
     async fn process_event(&self, event_data: &[u8]) -> ApplicationResult<()>;
+
+    /// イベント保存時のProjection反映を保留
+    ///
+    /// 一括取込など大量のイベントを追記する間、1イベントごとの反映を止める。
+    /// 保留中に追記されたイベントは `resume_projections` でまとめて反映する。
+    /// 保留は入れ子にでき、最後の `resume_projections` で反映する。
+    fn defer_projections(&self);
+
+    /// 保留を解除し、未反映のイベントを反映位置から順にまとめて反映
+    ///
+    /// # Arguments
+    /// * `progress` - 反映済みのイベント数と反映するイベント数を受け取る
+    ///
+    /// # Returns
+    /// 反映したイベント数（保留が残っている場合は0）
+    async fn resume_projections(
+        &self,
+        progress: &(dyn Fn(u64, u64) + Send + Sync),
+    ) -> ApplicationResult<u64>;
 }
@@ -0,0 +1,36 @@
+// ProjectionCompactor - Projection保守インターフェース
+// 責務: 不要になったRead Modelキーの除去とProjection環境の圧縮
+// 具象実装: Infrastructure層で提供
+
+use crate::error::ApplicationResult;
+
+/// Projection圧縮の結果
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ProjectionCompactionReport {
+    /// 除去したキーの数
+    pub swept_keys: usize,
+    /// 保持期間内のため残した削除済み仕訳の数
+    pub retained_deleted: usize,
+    /// 圧縮前のデータファイルサイズ（バイト）
+    pub size_before: u64,
+    /// 圧縮後のデータファイルサイズ（バイト）
+    pub size_after: u64,
+}
+
+impl ProjectionCompactionReport {
+    /// 圧縮で削減できる容量（バイト）
+    pub fn reclaimed_bytes(&self) -> u64 {
+        self.size_before.saturating_sub(self.size_after)
+    }
+}
+
+/// ProjectionCompactorトレイト
+///
+/// 削除済みの集約に対応するキーを除去し、空き領域を詰めた環境を書き出す。
+/// 削除済み仕訳を保持する設定の場合、保持期間を過ぎたものだけを除去する。
+/// 使用中の環境は置き換えられないため、圧縮した環境は次回起動時に反映される。
+#[async_trait::async_trait]
+pub trait ProjectionCompactor: Send + Sync {
+    /// 不要キーを除去して圧縮し、結果を返す
+    async fn compact(&self) -> ApplicationResult<ProjectionCompactionReport>;
+}
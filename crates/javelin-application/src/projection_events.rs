@@ -0,0 +1,144 @@
+// ProjectionEvents - Projection更新通知
+// 責務: Projectionの反映完了をプロセス内の購読者（表示中の画面）へ通知
+//
+// 通知は「どのRead Modelが変わったか」だけを伝え、内容は購読側が照会し直す。
+// 購読側の受信が追いつかず通知を取りこぼした場合は、すべて変更されたものとして扱う。
+
+use std::collections::BTreeSet;
+
+use tokio::sync::broadcast::{self, error::TryRecvError};
+
+/// 購読者ごとに保持する未受信通知の上限
+const EVENT_BUS_CAPACITY: usize = 256;
+
+/// Projectionの変更通知
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ProjectionChange {
+    /// 仕訳一覧が変更された
+    JournalListChanged,
+    /// 元帳（勘定科目・年月）が変更された
+    LedgerChanged { account_code: String, year: u32, month: u8 },
+}
+
+/// Projection更新通知のイベントバス
+///
+/// Projectionの反映処理が発行し、表示中の画面が購読する。
+/// 購読者がいない場合、通知は破棄される。
+pub struct ProjectionEventBus {
+    sender: broadcast::Sender<ProjectionChange>,
+}
+
+impl ProjectionEventBus {
+    pub fn new() -> Self {
+        let (sender, _) = broadcast::channel(EVENT_BUS_CAPACITY);
+        Self { sender }
+    }
+
+    /// 変更を通知
+    pub fn publish(&self, change: ProjectionChange) {
+        let _ = self.sender.send(change);
+    }
+
+    /// 購読を開始（以降に発行された通知を受け取る）
+    pub fn subscribe(&self) -> ProjectionSubscription {
+        ProjectionSubscription { receiver: self.sender.subscribe() }
+    }
+}
+
+impl Default for ProjectionEventBus {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Projection更新通知の購読
+pub struct ProjectionSubscription {
+    receiver: broadcast::Receiver<ProjectionChange>,
+}
+
+impl ProjectionSubscription {
+    /// 未受信の通知をまとめて取り出す（待機しない）
+    pub fn poll(&mut self) -> PendingProjectionChanges {
+        let mut pending = PendingProjectionChanges::default();
+        loop {
+            match self.receiver.try_recv() {
+                Ok(change) => {
+                    pending.changes.insert(change);
+                }
+                Err(TryRecvError::Lagged(_)) => pending.lagged = true,
+                Err(TryRecvError::Empty) | Err(TryRecvError::Closed) => return pending,
+            }
+        }
+    }
+}
+
+/// 未受信の変更通知（同じ変更は1件にまとめる）
+#[derive(Debug, Default)]
+pub struct PendingProjectionChanges {
+    changes: BTreeSet<ProjectionChange>,
+    /// 通知を取りこぼしたか
+    lagged: bool,
+}
+
+impl PendingProjectionChanges {
+    /// 変更がないか
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty() && !self.lagged
+    }
+
+    /// 仕訳一覧が変更されたか
+    pub fn journal_list_changed(&self) -> bool {
+        self.lagged || self.changes.contains(&ProjectionChange::JournalListChanged)
+    }
+
+    /// 指定勘定科目の元帳（いずれかの年月）が変更されたか
+    pub fn ledger_changed(&self, account_code: &str) -> bool {
+        self.lagged
+            || self.changes.iter().any(|change| {
+                matches!(change, ProjectionChange::LedgerChanged { account_code: code, .. }
+                    if code == account_code)
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ledger(account_code: &str, month: u8) -> ProjectionChange {
+        ProjectionChange::LedgerChanged {
+            account_code: account_code.to_string(),
+            year: 2024,
+            month,
+        }
+    }
+
+    #[test]
+    fn test_subscription_receives_changes_published_after_subscribe() {
+        let bus = ProjectionEventBus::new();
+        bus.publish(ProjectionChange::JournalListChanged);
+        let mut subscription = bus.subscribe();
+        assert!(subscription.poll().is_empty());
+
+        bus.publish(ledger("1100", 4));
+        bus.publish(ledger("1100", 4));
+        let pending = subscription.poll();
+        assert!(pending.ledger_changed("1100"));
+        assert!(!pending.ledger_changed("4000"));
+        assert!(!pending.journal_list_changed());
+        assert!(subscription.poll().is_empty());
+    }
+
+    #[test]
+    fn test_lagged_subscription_treats_everything_as_changed() {
+        let bus = ProjectionEventBus::new();
+        let mut subscription = bus.subscribe();
+        for month in 0..=EVENT_BUS_CAPACITY {
+            bus.publish(ledger("1100", (month % 12) as u8 + 1));
+        }
+
+        let pending = subscription.poll();
+        assert!(pending.journal_list_changed());
+        assert!(pending.ledger_changed("4000"));
+    }
+}
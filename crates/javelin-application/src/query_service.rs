@@ -2,11 +2,25 @@
 // 責務: Projection検索
 // 禁止: Repository利用
 
+pub mod account_activity;
+pub mod account_master_cache;
+pub mod audit_export;
 pub mod batch_history_query_service;
+pub mod entry_history;
+pub mod event_checksum;
+pub mod inbox_query_service;
+pub mod journal_entry_chain;
 pub mod journal_entry_finder;
 pub mod journal_entry_search_query_service;
 pub mod ledger_query_service;
 pub mod master_data_loader;
+pub mod period_reopen;
+pub mod projection_consistency;
+pub mod projection_inspection;
+pub mod projection_positions;
+pub mod projection_warm_up;
+pub mod sequence_audit;
+pub mod suspense_aging;
 
 use crate::error::ApplicationResult;
 
@@ -20,8 +34,22 @@ pub trait QueryService: Send + Sync {
 }
 
 // Re-export for convenience
+pub use account_activity::*;
+pub use account_master_cache::*;
+pub use audit_export::*;
 pub use batch_history_query_service::*;
+pub use entry_history::*;
+pub use event_checksum::*;
+pub use inbox_query_service::*;
+pub use journal_entry_chain::*;
 pub use journal_entry_finder::*;
 pub use journal_entry_search_query_service::*;
 pub use ledger_query_service::*;
 pub use master_data_loader::*;
+pub use period_reopen::*;
+pub use projection_consistency::*;
+pub use projection_inspection::*;
+pub use projection_positions::*;
+pub use projection_warm_up::*;
+pub use sequence_audit::*;
+pub use suspense_aging::*;
@@ -0,0 +1,78 @@
+// AccountActivityQueryService - 勘定科目別・月別の記帳件数と金額の照会サービス
+
+use crate::{error::ApplicationResult, query_service::ledger_query_service::EntryStatusScope};
+
+/// 勘定科目別・月別の記帳状況の照会条件
+#[derive(Debug, Clone)]
+pub struct GetAccountActivityQuery {
+    /// 集計期間の最終月（年）
+    pub to_year: i32,
+    /// 集計期間の最終月（月）
+    pub to_month: u8,
+    /// 最終月を含めて遡る月数
+    pub months: u8,
+    pub status_scope: EntryStatusScope,
+}
+
+/// 1勘定科目・1か月の記帳状況
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct AccountActivityCell {
+    /// 記帳された明細の件数
+    pub posting_count: u32,
+    /// 借方・貸方金額の合計
+    pub amount: f64,
+}
+
+/// 勘定科目の月別の記帳状況
+#[derive(Debug, Clone, PartialEq)]
+pub struct AccountActivityRow {
+    pub account_code: String,
+    pub account_name: String,
+    /// 集計期間の月順（periodsと同じ並び）
+    pub cells: Vec<AccountActivityCell>,
+}
+
+/// 勘定科目別・月別の記帳状況
+#[derive(Debug, Clone, PartialEq)]
+pub struct AccountActivityResult {
+    /// 集計期間の月（古い順）
+    pub periods: Vec<(i32, u8)>,
+    /// 集計期間中に記帳のあった勘定科目（科目コード順）
+    pub rows: Vec<AccountActivityRow>,
+}
+
+/// 勘定科目別・月別の記帳状況の照会サービス（Application層トレイト）
+#[allow(async_fn_in_trait)]
+pub trait AccountActivityQueryService: Send + Sync {
+    /// 集計期間の月ごとに勘定科目別の記帳件数・金額を集計
+    async fn get_account_activity(
+        &self,
+        query: GetAccountActivityQuery,
+    ) -> ApplicationResult<AccountActivityResult>;
+}
+
+/// 最終月から遡ったmonthsか月分の月（古い順）
+pub fn activity_periods(to_year: i32, to_month: u8, months: u8) -> Vec<(i32, u8)> {
+    let last = to_year * 12 + i32::from(to_month) - 1;
+    (0..i32::from(months))
+        .rev()
+        .map(|offset| {
+            let index = last - offset;
+            (index.div_euclid(12), (index.rem_euclid(12) + 1) as u8)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_activity_periods_wrap_year() {
+        assert_eq!(
+            activity_periods(2024, 2, 4),
+            vec![(2023, 11), (2023, 12), (2024, 1), (2024, 2)]
+        );
+        assert!(activity_periods(2024, 2, 0).is_empty());
+    }
+}
@@ -0,0 +1,131 @@
+// AccountMasterCache - 勘定科目マスタのメモリキャッシュ
+// 責務: 初回ロード後の勘定科目マスタを保持し、再読込・編集時にバージョンで無効化する
+
+use std::sync::{
+    Arc,
+    atomic::{AtomicU64, Ordering},
+};
+
+use tokio::sync::RwLock;
+
+use crate::{
+    error::ApplicationResult,
+    query_service::master_data_loader::{AccountMaster, MasterDataLoaderService},
+};
+
+/// バージョン付きのキャッシュ内容
+struct CachedAccounts {
+    /// ロード開始時点のバージョン
+    version: u64,
+    accounts: Arc<Vec<AccountMaster>>,
+}
+
+/// 勘定科目マスタのキャッシュ
+///
+/// 無効化のたびにバージョンを進め、現在のバージョンと異なる内容は使わない。
+/// ロード中に無効化された場合も、ロード開始時点のバージョンで保存するため
+/// 次回の取得で再ロードされる。
+#[derive(Default)]
+pub struct AccountMasterCache {
+    version: AtomicU64,
+    cached: RwLock<Option<CachedAccounts>>,
+}
+
+impl AccountMasterCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 現在のバージョン
+    pub fn version(&self) -> u64 {
+        self.version.load(Ordering::Acquire)
+    }
+
+    /// キャッシュを無効化し、新しいバージョンを返す
+    pub fn invalidate(&self) -> u64 {
+        self.version.fetch_add(1, Ordering::AcqRel) + 1
+    }
+
+    /// 現在のバージョンのキャッシュがあれば返す
+    pub async fn get(&self) -> Option<Arc<Vec<AccountMaster>>> {
+        let version = self.version();
+        self.cached
+            .read()
+            .await
+            .as_ref()
+            .filter(|cached| cached.version == version)
+            .map(|cached| Arc::clone(&cached.accounts))
+    }
+
+    /// キャッシュがあれば返し、なければマスタデータからロードして保持
+    pub async fn get_or_load<Q>(&self, loader: &Q) -> ApplicationResult<Arc<Vec<AccountMaster>>>
+    where
+        Q: MasterDataLoaderService,
+    {
+        if let Some(accounts) = self.get().await {
+            return Ok(accounts);
+        }
+
+        let version = self.version();
+        let accounts = Arc::new(loader.load_master_data().await?.accounts);
+
+        let mut cached = self.cached.write().await;
+        // より新しいバージョンでロード済みの内容は上書きしない
+        if cached.as_ref().is_none_or(|current| current.version <= version) {
+            *cached = Some(CachedAccounts { version, accounts: Arc::clone(&accounts) });
+        }
+        Ok(accounts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicUsize;
+
+    use super::*;
+    use crate::query_service::master_data_loader::{
+        AccountType, MasterData, SystemSettings, UserOptions,
+    };
+
+    /// ロード回数を数えるローダー
+    #[derive(Default)]
+    struct CountingLoader {
+        loads: AtomicUsize,
+    }
+
+    impl MasterDataLoaderService for CountingLoader {
+        async fn load_master_data(&self) -> ApplicationResult<MasterData> {
+            let count = self.loads.fetch_add(1, Ordering::SeqCst) + 1;
+            Ok(MasterData {
+                accounts: vec![AccountMaster {
+                    code: format!("{:04}", count),
+                    name: "現金".to_string(),
+                    account_type: AccountType::Asset,
+                    is_active: true,
+                }],
+                companies: vec![],
+                holidays: vec![],
+                user_options: UserOptions::default(),
+                system_settings: SystemSettings::default(),
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_loads_once_until_invalidated() {
+        let cache = AccountMasterCache::new();
+        let loader = CountingLoader::default();
+
+        let first = cache.get_or_load(&loader).await.unwrap();
+        let second = cache.get_or_load(&loader).await.unwrap();
+        assert_eq!(loader.loads.load(Ordering::SeqCst), 1);
+        assert!(Arc::ptr_eq(&first, &second));
+
+        assert_eq!(cache.invalidate(), 1);
+        assert!(cache.get().await.is_none());
+
+        let reloaded = cache.get_or_load(&loader).await.unwrap();
+        assert_eq!(loader.loads.load(Ordering::SeqCst), 2);
+        assert_eq!(reloaded[0].code, "0002");
+    }
+}
@@ -0,0 +1,58 @@
+// AuditExportQueryService - 監査用帳簿出力の照会サービス
+
+use chrono::{DateTime, Utc};
+
+use crate::{error::ApplicationResult, query_service::AccountMaster};
+
+/// 監査用の仕訳明細
+#[derive(Debug, Clone)]
+pub struct AuditJournalLine {
+    pub line_number: u32,
+    /// "Debit" または "Credit"
+    pub side: String,
+    pub account_code: String,
+    pub sub_account_code: Option<String>,
+    pub department_code: Option<String>,
+    pub amount: f64,
+    pub currency: String,
+    pub tax_type: String,
+    pub tax_amount: f64,
+    pub description: Option<String>,
+}
+
+impl AuditJournalLine {
+    pub fn is_debit(&self) -> bool {
+        self.side == "Debit"
+    }
+}
+
+/// 監査用の仕訳（記帳済みの仕訳と起票から記帳までの履歴）
+#[derive(Debug, Clone)]
+pub struct AuditJournalEntry {
+    pub entry_id: String,
+    /// YYYY-MM-DD形式
+    pub transaction_date: String,
+    pub entry_number: String,
+    pub voucher_number: Option<String>,
+    /// 現在の状態（Posted / Reversed / Corrected / Closed）
+    pub status: String,
+    pub created_by: String,
+    pub created_at: DateTime<Utc>,
+    /// 最後に下書きを更新した利用者と日時（更新していない場合はNone）
+    pub updated: Option<(String, DateTime<Utc>)>,
+    /// 最後に承認申請した利用者と日時
+    pub approval_requested: Option<(String, DateTime<Utc>)>,
+    pub posted_by: String,
+    pub posted_at: DateTime<Utc>,
+    pub lines: Vec<AuditJournalLine>,
+}
+
+/// 監査用帳簿出力の照会サービス（Application層トレイト）
+#[allow(async_fn_in_trait)]
+pub trait AuditExportQueryService: Send + Sync {
+    /// 記帳済みの仕訳をすべて取得（取消・修正・締め済みを含む）
+    async fn load_posted_entries(&self) -> ApplicationResult<Vec<AuditJournalEntry>>;
+
+    /// 勘定科目マスタを取得（科目名と前期繰越の要否の判定に使う）
+    async fn load_accounts(&self) -> ApplicationResult<Vec<AccountMaster>>;
+}
@@ -0,0 +1,155 @@
+// EntryHistory - 仕訳修正履歴
+// 責務: 修正チェーン（元仕訳 → 取消 → 修正）の明細差分
+
+use serde::{Deserialize, Serialize};
+
+/// 仕訳修正履歴照会クエリ
+#[derive(Debug, Clone)]
+pub struct GetEntryHistoryQuery {
+    /// 伝票ID または 伝票番号
+    pub entry_id: String,
+}
+
+/// 履歴明細（比較対象の明細1行）
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EntryHistoryLine {
+    pub line_number: u32,
+    pub side: String,
+    pub account_code: String,
+    pub amount: f64,
+    pub description: Option<String>,
+}
+
+/// 明細の変更区分
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LineChangeKind {
+    /// 変更なし
+    Unchanged,
+    /// 修正仕訳で追加
+    Added,
+    /// 修正仕訳で削除
+    Removed,
+    /// 科目・貸借・金額・摘要のいずれかを変更
+    Modified,
+}
+
+impl LineChangeKind {
+    /// 表示名
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            LineChangeKind::Unchanged => "変更なし",
+            LineChangeKind::Added => "追加",
+            LineChangeKind::Removed => "削除",
+            LineChangeKind::Modified => "変更",
+        }
+    }
+}
+
+/// 明細差分
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EntryLineDiff {
+    pub line_number: u32,
+    pub change: LineChangeKind,
+    pub original: Option<EntryHistoryLine>,
+    pub corrected: Option<EntryHistoryLine>,
+}
+
+/// 仕訳修正履歴結果
+#[derive(Debug, Clone)]
+pub struct EntryHistoryResult {
+    /// 元仕訳ID
+    pub original_entry_id: String,
+    /// 取消仕訳ID（取消されていない場合はNone）
+    pub reversal_entry_id: Option<String>,
+    /// 修正仕訳ID（修正されていない場合はNone）
+    pub correcting_entry_id: Option<String>,
+    /// 修正理由
+    pub reason: Option<String>,
+    /// 明細差分（行番号順）
+    pub diffs: Vec<EntryLineDiff>,
+}
+
+impl EntryHistoryResult {
+    /// 修正チェーンが存在するか
+    pub fn has_correction(&self) -> bool {
+        self.correcting_entry_id.is_some()
+    }
+}
+
+/// 元仕訳と修正仕訳の明細を行番号で突き合わせて差分を作成
+pub fn diff_entry_lines(
+    original: &[EntryHistoryLine],
+    corrected: &[EntryHistoryLine],
+) -> Vec<EntryLineDiff> {
+    let mut line_numbers: Vec<u32> =
+        original.iter().chain(corrected.iter()).map(|line| line.line_number).collect();
+    line_numbers.sort_unstable();
+    line_numbers.dedup();
+
+    line_numbers
+        .into_iter()
+        .map(|line_number| {
+            let before = original.iter().find(|line| line.line_number == line_number).cloned();
+            let after = corrected.iter().find(|line| line.line_number == line_number).cloned();
+
+            let change = match (&before, &after) {
+                (Some(before), Some(after)) if before == after => LineChangeKind::Unchanged,
+                (Some(_), Some(_)) => LineChangeKind::Modified,
+                (Some(_), None) => LineChangeKind::Removed,
+                (None, _) => LineChangeKind::Added,
+            };
+
+            EntryLineDiff { line_number, change, original: before, corrected: after }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line(line_number: u32, side: &str, account_code: &str, amount: f64) -> EntryHistoryLine {
+        EntryHistoryLine {
+            line_number,
+            side: side.to_string(),
+            account_code: account_code.to_string(),
+            amount,
+            description: None,
+        }
+    }
+
+    #[test]
+    fn test_diff_entry_lines() {
+        let original = vec![
+            line(1, "Debit", "1100", 10000.0),
+            line(2, "Credit", "4000", 10000.0),
+            line(3, "Debit", "5000", 500.0),
+        ];
+        let corrected = vec![
+            line(1, "Debit", "1100", 12000.0),
+            line(2, "Credit", "4000", 10000.0),
+            line(4, "Credit", "2100", 2000.0),
+        ];
+
+        let diffs = diff_entry_lines(&original, &corrected);
+
+        assert_eq!(diffs.len(), 4);
+        assert_eq!(diffs[0].change, LineChangeKind::Modified);
+        assert_eq!(diffs[1].change, LineChangeKind::Unchanged);
+        assert_eq!(diffs[2].change, LineChangeKind::Removed);
+        assert!(diffs[2].corrected.is_none());
+        assert_eq!(diffs[3].change, LineChangeKind::Added);
+        assert!(diffs[3].original.is_none());
+    }
+
+    #[test]
+    fn test_diff_entry_lines_description_change() {
+        let original = vec![line(1, "Debit", "1100", 10000.0)];
+        let mut changed = line(1, "Debit", "1100", 10000.0);
+        changed.description = Some("摘要修正".to_string());
+
+        let diffs = diff_entry_lines(&original, &[changed]);
+
+        assert_eq!(diffs[0].change, LineChangeKind::Modified);
+    }
+}
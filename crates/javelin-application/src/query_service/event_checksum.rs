@@ -0,0 +1,26 @@
+// EventChecksumQueryService - イベントのチェックサム検証用の照会サービス
+
+use crate::error::ApplicationResult;
+
+/// チェックサムが一致しない・読み取れないイベント
+#[derive(Debug, Clone)]
+pub struct CorruptedEvent {
+    pub global_sequence: u64,
+    pub error: String,
+}
+
+/// チェックサム検証の結果
+#[derive(Debug, Clone, Default)]
+pub struct EventChecksumReport {
+    /// 検証して読み取れたイベントの件数
+    pub verified_events: usize,
+    /// 破損していたイベント（検証前から隔離されていたものを含む）
+    pub corrupted_events: Vec<CorruptedEvent>,
+}
+
+/// イベントのチェックサム検証用の照会サービス（Application層トレイト）
+#[allow(async_fn_in_trait)]
+pub trait EventChecksumQueryService: Send + Sync {
+    /// 全イベントのチェックサムを検証する
+    async fn verify_checksums(&self) -> ApplicationResult<EventChecksumReport>;
+}
@@ -0,0 +1,155 @@
+// InboxQueryService - 受信箱照会サービス
+// 利用者ごとに対応が必要な項目を照会する
+
+use chrono::{DateTime, Duration, Utc};
+use javelin_domain::masters::{ApprovalAging, ApprovalSlaSettings};
+
+use crate::{dtos::response::JournalActionAffordance, error::ApplicationResult};
+
+/// 受信箱項目の種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InboxItemKind {
+    /// 承認待ちの仕訳
+    AwaitingApproval,
+    /// 差し戻された自分の下書き
+    Rejected,
+}
+
+impl InboxItemKind {
+    /// 項目の仕訳のステータス名（JournalStatus::as_str）
+    pub fn status(&self) -> &'static str {
+        match self {
+            Self::AwaitingApproval => "PendingApproval",
+            Self::Rejected => "Draft",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::AwaitingApproval => "承認待ち",
+            Self::Rejected => "差戻し",
+        }
+    }
+}
+
+/// 受信箱項目の明細
+#[derive(Debug, Clone)]
+pub struct InboxItemLine {
+    pub account_code: String,
+    /// "Debit" または "Credit"
+    pub side: String,
+    pub amount: f64,
+}
+
+/// 受信箱項目
+#[derive(Debug, Clone)]
+pub struct InboxItem {
+    pub kind: InboxItemKind,
+    pub entry_id: String,
+    pub voucher_number: String,
+    pub transaction_date: String,
+    /// 申請者または差戻し者
+    pub from_user: String,
+    /// 差戻し理由
+    pub reason: Option<String>,
+    /// 申請・差戻し日時（RFC3339）
+    pub occurred_at: String,
+    pub lines: Vec<InboxItemLine>,
+    /// 現在のステータスで可能な操作
+    pub actions: Vec<JournalActionAffordance>,
+}
+
+impl InboxItem {
+    /// 承認待ちの滞留時間（承認待ち以外、または申請日時が読めない場合はNone）
+    pub fn pending_duration(&self, now: DateTime<Utc>) -> Option<Duration> {
+        if self.kind != InboxItemKind::AwaitingApproval {
+            return None;
+        }
+        let requested_at = DateTime::parse_from_rfc3339(&self.occurred_at).ok()?;
+        Some((now - requested_at.with_timezone(&Utc)).max(Duration::zero()))
+    }
+
+    /// 承認待ちのSLA上の状態（承認待ち以外はNone）
+    pub fn approval_aging(
+        &self,
+        sla: ApprovalSlaSettings,
+        now: DateTime<Utc>,
+    ) -> Option<ApprovalAging> {
+        self.pending_duration(now).map(|elapsed| sla.classify(elapsed))
+    }
+}
+
+/// 承認待ちのSLA集計
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ApprovalSlaSummary {
+    /// 承認待ちの件数
+    pub pending: usize,
+    /// 注意閾値を超えた件数（SLA超過を除く）
+    pub warning: usize,
+    /// SLAを超過した件数
+    pub breached: usize,
+}
+
+impl ApprovalSlaSummary {
+    pub fn from_items(items: &[InboxItem], sla: ApprovalSlaSettings, now: DateTime<Utc>) -> Self {
+        items.iter().filter_map(|item| item.approval_aging(sla, now)).fold(
+            Self::default(),
+            |mut summary, aging| {
+                summary.pending += 1;
+                match aging {
+                    ApprovalAging::WithinSla => {}
+                    ApprovalAging::Warning => summary.warning += 1,
+                    ApprovalAging::Breached => summary.breached += 1,
+                }
+                summary
+            },
+        )
+    }
+}
+
+/// 受信箱照会サービス（Application層トレイト）
+#[allow(async_fn_in_trait)]
+pub trait InboxQueryService: Send + Sync {
+    /// 利用者の受信箱を取得（新しい順）
+    ///
+    /// 承認待ちの仕訳はすべての利用者に、差し戻された下書きは作成者にのみ表示する。
+    async fn get_inbox(&self, user_id: &str) -> ApplicationResult<Vec<InboxItem>>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(kind: InboxItemKind, occurred_at: &str) -> InboxItem {
+        InboxItem {
+            kind,
+            entry_id: "entry-1".to_string(),
+            voucher_number: "V-0001".to_string(),
+            transaction_date: "2024-04-01".to_string(),
+            from_user: "user1".to_string(),
+            reason: None,
+            occurred_at: occurred_at.to_string(),
+            lines: vec![],
+            actions: JournalActionAffordance::for_status(kind.status()),
+        }
+    }
+
+    #[test]
+    fn test_approval_sla_summary() {
+        let now = DateTime::parse_from_rfc3339("2024-04-03T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let items = vec![
+            item(InboxItemKind::AwaitingApproval, "2024-04-03T09:00:00Z"),
+            item(InboxItemKind::AwaitingApproval, "2024-04-02T09:00:00+00:00"),
+            item(InboxItemKind::AwaitingApproval, "2024-03-31T12:00:00Z"),
+            item(InboxItemKind::Rejected, "2024-03-01T00:00:00Z"),
+        ];
+
+        let summary = ApprovalSlaSummary::from_items(&items, ApprovalSlaSettings::default(), now);
+        assert_eq!(summary, ApprovalSlaSummary { pending: 3, warning: 1, breached: 1 });
+
+        assert_eq!(items[0].pending_duration(now), Some(Duration::hours(3)));
+        assert_eq!(items[3].pending_duration(now), None);
+    }
+}
@@ -0,0 +1,24 @@
+// JournalEntryChain - 取消・修正チェーン照会
+// 責務: 仕訳間の取消・修正のリンクの参照（チェーンの整合性検証用）
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::ApplicationResult;
+
+/// 仕訳に付いた取消・修正のリンク
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JournalEntryChainLinks {
+    /// この仕訳を取り消した取消仕訳のID
+    pub reversal_id: Option<String>,
+    /// この仕訳が取消仕訳の場合、取り消された元仕訳のID
+    pub original_id: Option<String>,
+    /// この取消仕訳に対する修正仕訳のID
+    pub correction_id: Option<String>,
+}
+
+/// 取消・修正チェーン照会サービス（Application層トレイト）
+#[allow(async_fn_in_trait)]
+pub trait JournalEntryChainQueryService: Send + Sync {
+    /// 仕訳のリンクを取得（取消・修正されていない仕訳は既定値）
+    async fn find_links(&self, entry_id: &str) -> ApplicationResult<JournalEntryChainLinks>;
+}
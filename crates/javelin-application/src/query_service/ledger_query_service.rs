@@ -1,8 +1,29 @@
 // LedgerQueryService - 元帳照会サービス
 
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
 
-use crate::error::ApplicationResult;
+use crate::{
+    error::ApplicationResult,
+    query_service::entry_history::{EntryHistoryResult, GetEntryHistoryQuery},
+};
+
+/// 集計対象とする仕訳の状態
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EntryStatusScope {
+    /// 記帳済の仕訳のみ
+    #[default]
+    PostedOnly,
+    /// 承認待ちの仕訳を含める（試算）
+    IncludePendingApproval,
+}
+
+impl EntryStatusScope {
+    pub fn includes_pending_approval(&self) -> bool {
+        matches!(self, EntryStatusScope::IncludePendingApproval)
+    }
+}
 
 /// 元帳照会クエリ
 #[derive(Debug, Clone)]
@@ -12,6 +33,11 @@ pub struct GetLedgerQuery {
     pub to_date: Option<String>,
     pub limit: Option<u32>,
     pub offset: Option<u32>,
+    pub status_scope: EntryStatusScope,
+    /// 未解決の注記が付いた明細のみ
+    pub flagged_only: bool,
+    /// 分析軸（種類 → コード、指定した分析軸をすべて持つ明細のみ）
+    pub dimensions: BTreeMap<String, String>,
 }
 
 /// 試算表照会クエリ
@@ -19,18 +45,28 @@ pub struct GetLedgerQuery {
 pub struct GetTrialBalanceQuery {
     pub period_year: u32,
     pub period_month: u8,
+    pub status_scope: EntryStatusScope,
 }
 
+/// 繰越行の摘要
+pub const CARRIED_FORWARD_DESCRIPTION: &str = "前月繰越";
+
 /// 元帳明細
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LedgerEntry {
     pub transaction_date: String,
     pub entry_number: String,
     pub entry_id: String,
+    /// 仕訳の明細行番号
+    #[serde(default)]
+    pub line_number: u32,
     pub description: String,
     pub debit_amount: f64,
     pub credit_amount: f64,
     pub balance: f64,
+    /// 未解決の注記数
+    #[serde(default)]
+    pub open_annotations: u32,
 }
 
 /// 元帳結果
@@ -39,12 +75,21 @@ pub struct LedgerResult {
     pub account_code: String,
     pub account_name: String,
     pub opening_balance: f64,
+    /// 期首残高が照会開始日時点の繰越残高の場合、その日付（前月繰越行として表示する）
+    pub carried_forward_date: Option<String>,
     pub entries: Vec<LedgerEntry>,
     pub closing_balance: f64,
     pub total_debit: f64,
     pub total_credit: f64,
 }
 
+/// 月初の繰越残高（前月末までの記帳済残高、借方を正とする）
+#[derive(Debug, Clone, PartialEq)]
+pub struct CarriedForwardBalance {
+    pub account_code: String,
+    pub balance: f64,
+}
+
 /// 試算表明細
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TrialBalanceEntry {
@@ -66,6 +111,19 @@ pub struct TrialBalanceResult {
     pub total_credit: f64,
 }
 
+/// 通貨別試算表結果
+///
+/// 取引通貨ごとに集計した試算表。金額はすべて`currency`建て。
+#[derive(Debug, Clone)]
+pub struct CurrencyTrialBalanceResult {
+    pub period_year: u32,
+    pub period_month: u8,
+    pub currency: String,
+    pub entries: Vec<TrialBalanceEntry>,
+    pub total_debit: f64,
+    pub total_credit: f64,
+}
+
 /// 元帳照会サービス（Application層トレイト）
 #[allow(async_fn_in_trait)]
 pub trait LedgerQueryService: Send + Sync {
@@ -77,4 +135,30 @@ pub trait LedgerQueryService: Send + Sync {
         &self,
         query: GetTrialBalanceQuery,
     ) -> ApplicationResult<TrialBalanceResult>;
+
+    /// 通貨別試算表を取得
+    ///
+    /// 取引通貨ごとに試算表を分けて返す（通貨コード順）。
+    async fn get_trial_balance_by_currency(
+        &self,
+        query: GetTrialBalanceQuery,
+    ) -> ApplicationResult<Vec<CurrencyTrialBalanceResult>>;
+
+    /// 月初の繰越残高を確定
+    ///
+    /// 前月末までの記帳済残高を勘定科目別に保持し（勘定科目コード順、残高0の科目は除く）、
+    /// 以降の期間指定の元帳照会は全履歴を集計せず繰越残高を起点とする。
+    async fn roll_over_period(
+        &self,
+        period_year: u32,
+        period_month: u8,
+    ) -> ApplicationResult<Vec<CarriedForwardBalance>>;
+
+    /// 仕訳修正履歴を取得
+    ///
+    /// イベントストリームの修正チェーンから元仕訳と修正仕訳を特定し、明細差分を返す。
+    async fn get_entry_history(
+        &self,
+        query: GetEntryHistoryQuery,
+    ) -> ApplicationResult<EntryHistoryResult>;
 }
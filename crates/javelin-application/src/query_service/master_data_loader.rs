@@ -2,11 +2,20 @@
 // 責務: アプリケーション起動時の初期データロード
 // 禁止: Repository利用（Projectionのみ）
 
-use javelin_domain::masters::{
-    AccountCode, AccountMaster as DomainAccountMaster, AccountName,
-    AccountType as DomainAccountType, ApplicationSettings as DomainApplicationSettings,
-    BackupRetentionDays, ClosingDay, CompanyCode, CompanyMaster as DomainCompanyMaster,
-    CompanyName, DateFormat, DecimalPlaces, FiscalYearStartMonth, Language,
+use std::{collections::BTreeMap, str::FromStr};
+
+use javelin_domain::{
+    financial_close::journal_entry::values::Currency,
+    masters::{
+        AccountCode, AccountMaster as DomainAccountMaster, AccountName,
+        AccountType as DomainAccountType, ApplicationSettings as DomainApplicationSettings,
+        ApprovalSlaSettings, BackupRetentionDays, BatchNotificationSettings,
+        CalendarMaster as DomainCalendarMaster, ClosingDay, ClosingLockCheck,
+        ClosingLockValidationSettings, CompanyCode, CompanyMaster as DomainCompanyMaster,
+        CompanyName, DateFormat, DecimalPlaces, DeletedEntryRetentionSettings,
+        ExportProtectionSettings, FiscalYearStartMonth, Language, ReportDeliverySettings,
+        ReportDestination,
+    },
 };
 use serde::{Deserialize, Serialize};
 
@@ -19,6 +28,8 @@ pub struct MasterData {
     pub accounts: Vec<AccountMaster>,
     /// 会社マスタ
     pub companies: Vec<CompanyMaster>,
+    /// カレンダーマスタ（休日）
+    pub holidays: Vec<Holiday>,
     /// ユーザ設定
     pub user_options: UserOptions,
     /// システム設定
@@ -52,6 +63,13 @@ pub struct CompanyMaster {
     pub is_active: bool,
 }
 
+/// 休日
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Holiday {
+    pub date: String, // YYYY-MM-DD format
+    pub name: String,
+}
+
 /// ユーザ設定
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UserOptions {
@@ -63,6 +81,12 @@ pub struct UserOptions {
     pub decimal_places: u8,
     /// 日付フォーマット
     pub date_format: String,
+    /// バッチ完了時に端末ベルを鳴らす
+    #[serde(default)]
+    pub batch_notification_bell: bool,
+    /// バッチ完了時にデスクトップ通知を送る
+    #[serde(default)]
+    pub batch_notification_desktop: bool,
 }
 
 /// システム設定
@@ -70,12 +94,62 @@ pub struct UserOptions {
 pub struct SystemSettings {
     /// 会計年度開始月
     pub fiscal_year_start_month: u8,
+    /// 基準通貨
+    #[serde(default = "default_base_currency")]
+    pub base_currency: String,
     /// 締日
     pub closing_day: u8,
     /// 自動バックアップ有効
     pub auto_backup_enabled: bool,
     /// バックアップ保持日数
     pub backup_retention_days: u32,
+    /// 承認SLAの注意閾値（時間）
+    #[serde(default = "default_approval_sla_warning_hours")]
+    pub approval_sla_warning_hours: u32,
+    /// 承認SLAの超過閾値（時間）
+    #[serde(default = "default_approval_sla_breach_hours")]
+    pub approval_sla_breach_hours: u32,
+    /// 帳票の種類ごとの配信先（URI形式）
+    #[serde(default)]
+    pub report_delivery: BTreeMap<String, Vec<String>>,
+    /// 出力ファイルをパスワード付きZIPで暗号化する
+    #[serde(default)]
+    pub export_encryption_enabled: bool,
+    /// 出力ファイルの分離署名を出力する
+    #[serde(default)]
+    pub export_signature_enabled: bool,
+    /// 削除済み仕訳を保持する
+    #[serde(default)]
+    pub deleted_entry_retain_enabled: bool,
+    /// 削除済み仕訳を物理削除するまでの月数
+    #[serde(default = "default_deleted_entry_purge_after_months")]
+    pub deleted_entry_purge_after_months: u32,
+    /// 締日固定前に検証しない項目（検証項目コード）
+    #[serde(default)]
+    pub closing_lock_disabled_checks: Vec<String>,
+    /// 為替換算調整勘定の勘定科目コード
+    #[serde(default = "default_translation_adjustment_account")]
+    pub translation_adjustment_account: String,
+}
+
+fn default_base_currency() -> String {
+    Currency::JPY.as_str().to_string()
+}
+
+fn default_deleted_entry_purge_after_months() -> u32 {
+    DeletedEntryRetentionSettings::DEFAULT_PURGE_AFTER_MONTHS
+}
+
+fn default_translation_adjustment_account() -> String {
+    DomainApplicationSettings::DEFAULT_TRANSLATION_ADJUSTMENT_ACCOUNT.to_string()
+}
+
+fn default_approval_sla_warning_hours() -> u32 {
+    ApprovalSlaSettings::DEFAULT_WARNING_HOURS
+}
+
+fn default_approval_sla_breach_hours() -> u32 {
+    ApprovalSlaSettings::DEFAULT_BREACH_HOURS
 }
 
 impl Default for UserOptions {
@@ -85,6 +159,8 @@ impl Default for UserOptions {
             language: "ja".to_string(),
             decimal_places: 2,
             date_format: "YYYY-MM-DD".to_string(),
+            batch_notification_bell: false,
+            batch_notification_desktop: false,
         }
     }
 }
@@ -93,9 +169,20 @@ impl Default for SystemSettings {
     fn default() -> Self {
         Self {
             fiscal_year_start_month: 4, // 4月開始
-            closing_day: 31,            // 月末締め
+            base_currency: default_base_currency(),
+            closing_day: 31, // 月末締め
             auto_backup_enabled: true,
             backup_retention_days: 90,
+            approval_sla_warning_hours: ApprovalSlaSettings::DEFAULT_WARNING_HOURS,
+            approval_sla_breach_hours: ApprovalSlaSettings::DEFAULT_BREACH_HOURS,
+            report_delivery: BTreeMap::new(),
+            export_encryption_enabled: false,
+            export_signature_enabled: false,
+            deleted_entry_retain_enabled: false,
+            deleted_entry_purge_after_months:
+                DeletedEntryRetentionSettings::DEFAULT_PURGE_AFTER_MONTHS,
+            closing_lock_disabled_checks: Vec::new(),
+            translation_adjustment_account: default_translation_adjustment_account(),
         }
     }
 }
@@ -139,6 +226,15 @@ impl From<&DomainCompanyMaster> for CompanyMaster {
     }
 }
 
+impl From<&DomainCalendarMaster> for Holiday {
+    fn from(domain: &DomainCalendarMaster) -> Self {
+        Self {
+            date: domain.date().format("%Y-%m-%d").to_string(),
+            name: domain.name().value().to_string(),
+        }
+    }
+}
+
 impl From<&DomainApplicationSettings> for UserOptions {
     fn from(domain: &DomainApplicationSettings) -> Self {
         Self {
@@ -146,6 +242,8 @@ impl From<&DomainApplicationSettings> for UserOptions {
             language: domain.language().value().to_string(),
             decimal_places: domain.decimal_places().value(),
             date_format: domain.date_format().value().to_string(),
+            batch_notification_bell: domain.batch_notification().bell_enabled,
+            batch_notification_desktop: domain.batch_notification().desktop_enabled,
         }
     }
 }
@@ -154,9 +252,35 @@ impl From<&DomainApplicationSettings> for SystemSettings {
     fn from(domain: &DomainApplicationSettings) -> Self {
         Self {
             fiscal_year_start_month: domain.fiscal_year_start_month().value(),
+            base_currency: domain.base_currency().as_str().to_string(),
             closing_day: domain.closing_day().value(),
             auto_backup_enabled: domain.auto_backup_enabled(),
             backup_retention_days: domain.backup_retention_days().value(),
+            approval_sla_warning_hours: domain.approval_sla().warning_hours(),
+            approval_sla_breach_hours: domain.approval_sla().breach_hours(),
+            report_delivery: domain
+                .report_delivery()
+                .routes()
+                .map(|(report_type, destinations)| {
+                    (
+                        report_type.to_string(),
+                        destinations.iter().map(ReportDestination::to_uri).collect(),
+                    )
+                })
+                .collect(),
+            export_encryption_enabled: domain.export_protection().encryption_enabled,
+            export_signature_enabled: domain.export_protection().signature_enabled,
+            deleted_entry_retain_enabled: domain.deleted_entry_retention().retain_enabled(),
+            deleted_entry_purge_after_months: domain.deleted_entry_retention().purge_after_months(),
+            closing_lock_disabled_checks: domain
+                .closing_lock_validation()
+                .disabled_checks()
+                .map(|check| check.code().to_string())
+                .collect(),
+            translation_adjustment_account: domain
+                .translation_adjustment_account()
+                .value()
+                .to_string(),
         }
     }
 }
@@ -220,12 +344,44 @@ pub fn to_application_settings(
     let fiscal_year_start_month =
         FiscalYearStartMonth::new(sys_settings.fiscal_year_start_month)
             .map_err(|e| crate::error::ApplicationError::ValidationError(e.to_string()))?;
+    let base_currency = Currency::from_str(&sys_settings.base_currency)
+        .map_err(crate::error::ApplicationError::ValidationError)?;
     let closing_day = ClosingDay::new(sys_settings.closing_day)
         .map_err(|e| crate::error::ApplicationError::ValidationError(e.to_string()))?;
     let backup_retention_days = BackupRetentionDays::new(sys_settings.backup_retention_days)
         .map_err(|e| crate::error::ApplicationError::ValidationError(e.to_string()))?;
+    let approval_sla = ApprovalSlaSettings::new(
+        sys_settings.approval_sla_warning_hours,
+        sys_settings.approval_sla_breach_hours,
+    )
+    .map_err(|e| crate::error::ApplicationError::ValidationError(e.to_string()))?;
+    let deleted_entry_retention = DeletedEntryRetentionSettings::new(
+        sys_settings.deleted_entry_retain_enabled,
+        sys_settings.deleted_entry_purge_after_months,
+    )
+    .map_err(|e| crate::error::ApplicationError::ValidationError(e.to_string()))?;
+    let closing_lock_validation = ClosingLockValidationSettings::new(
+        sys_settings
+            .closing_lock_disabled_checks
+            .iter()
+            .map(|code| ClosingLockCheck::from_code(code))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| crate::error::ApplicationError::ValidationError(e.to_string()))?,
+    );
+    let translation_adjustment_account =
+        AccountCode::new(&sys_settings.translation_adjustment_account)
+            .map_err(|e| crate::error::ApplicationError::ValidationError(e.to_string()))?;
+    let mut report_delivery = ReportDeliverySettings::new();
+    for (report_type, uris) in &sys_settings.report_delivery {
+        let destinations = uris
+            .iter()
+            .map(|uri| ReportDestination::parse(uri))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| crate::error::ApplicationError::ValidationError(e.to_string()))?;
+        report_delivery.set_destinations(report_type.clone(), destinations);
+    }
 
-    Ok(DomainApplicationSettings::new(
+    let mut settings = DomainApplicationSettings::new(
         default_company_code,
         language,
         decimal_places,
@@ -234,5 +390,20 @@ pub fn to_application_settings(
         closing_day,
         sys_settings.auto_backup_enabled,
         backup_retention_days,
-    ))
+    );
+    settings.update_batch_notification(BatchNotificationSettings::new(
+        user_opts.batch_notification_bell,
+        user_opts.batch_notification_desktop,
+    ));
+    settings.update_base_currency(base_currency);
+    settings.update_approval_sla(approval_sla);
+    settings.update_report_delivery(report_delivery);
+    settings.update_export_protection(ExportProtectionSettings::new(
+        sys_settings.export_encryption_enabled,
+        sys_settings.export_signature_enabled,
+    ));
+    settings.update_deleted_entry_retention(deleted_entry_retention);
+    settings.update_closing_lock_validation(closing_lock_validation);
+    settings.update_translation_adjustment_account(translation_adjustment_account);
+    Ok(settings)
 }
@@ -0,0 +1,63 @@
+// PeriodReopenQueryService - 会計期間の再オープン用の照会サービス
+
+use chrono::{DateTime, NaiveDate, Utc};
+use javelin_domain::financial_close::accounting_period::AccountingPeriodEvent;
+
+use crate::error::ApplicationResult;
+
+/// 記帳の種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PostingKind {
+    /// 承認による記帳
+    Posted,
+    /// 取消仕訳の記帳
+    Reversed,
+    /// 修正仕訳の記帳
+    Corrected,
+}
+
+impl PostingKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Posted => "記帳",
+            Self::Reversed => "取消",
+            Self::Corrected => "修正",
+        }
+    }
+}
+
+/// 仕訳の記帳記録
+#[derive(Debug, Clone)]
+pub struct PostingRecord {
+    pub entry_id: String,
+    pub entry_number: Option<String>,
+    /// YYYY-MM-DD形式（取消・修正仕訳は元の仕訳の取引日付）
+    pub transaction_date: String,
+    pub kind: PostingKind,
+    pub recorded_by: String,
+    pub recorded_at: DateTime<Utc>,
+}
+
+/// 会計期間の再オープン用の照会サービス（Application層トレイト）
+#[allow(async_fn_in_trait)]
+pub trait PeriodReopenQueryService: Send + Sync {
+    /// 会計期間のイベントを記録順に取得
+    async fn load_period_events(
+        &self,
+        period_id: &str,
+    ) -> ApplicationResult<Vec<AccountingPeriodEvent>>;
+
+    /// 取引日付が指定範囲内の締め済仕訳のIDを取得
+    async fn find_closed_entry_ids(
+        &self,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> ApplicationResult<Vec<String>>;
+
+    /// 取引日付が指定範囲内の仕訳の記帳記録を取得
+    async fn load_postings(
+        &self,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> ApplicationResult<Vec<PostingRecord>>;
+}
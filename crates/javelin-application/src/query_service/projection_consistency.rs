@@ -0,0 +1,51 @@
+// ProjectionConsistencyQueryService - Projection間整合性検証用の照会サービス
+
+use crate::error::ApplicationResult;
+
+/// 仕訳一覧Projectionの明細
+#[derive(Debug, Clone)]
+pub struct JournalProjectionLine {
+    pub account_code: String,
+    /// "Debit" または "Credit"
+    pub side: String,
+    pub amount: f64,
+}
+
+/// 仕訳一覧Projectionの仕訳
+#[derive(Debug, Clone)]
+pub struct JournalProjectionEntry {
+    pub entry_id: String,
+    pub entry_number: Option<String>,
+    pub status: String,
+    pub lines: Vec<JournalProjectionLine>,
+}
+
+/// 元帳Projectionの明細
+#[derive(Debug, Clone)]
+pub struct LedgerProjectionMovement {
+    pub account_code: String,
+    pub entry_number: String,
+    pub debit_amount: f64,
+    pub credit_amount: f64,
+}
+
+/// 整合性検証用のProjectionスナップショット
+///
+/// 仕訳一覧・元帳の両Projectionと、イベントストア上の記帳・取消イベントを
+/// 同一時点のイベント列から取得したもの。
+#[derive(Debug, Clone, Default)]
+pub struct ProjectionConsistencySnapshot {
+    pub journal_entries: Vec<JournalProjectionEntry>,
+    pub ledger_movements: Vec<LedgerProjectionMovement>,
+    /// Postedイベントの伝票番号
+    pub posted_entry_numbers: Vec<String>,
+    /// Reversedイベントの仕訳ID（元帳上の逆仕訳の番号）
+    pub reversal_entry_ids: Vec<String>,
+}
+
+/// Projection間整合性検証用の照会サービス（Application層トレイト）
+#[allow(async_fn_in_trait)]
+pub trait ProjectionConsistencyQueryService: Send + Sync {
+    /// 整合性検証用のスナップショットを取得
+    async fn load_snapshot(&self) -> ApplicationResult<ProjectionConsistencySnapshot>;
+}
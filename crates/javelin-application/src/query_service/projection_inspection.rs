@@ -0,0 +1,25 @@
+// ProjectionInspectionQueryService - Projectionの生データ照会サービス
+// 用途: 管理者による読み取りモデルのデバッグ（キー前方一致で参照）
+
+use crate::error::ApplicationResult;
+
+/// Projectionの1レコード（キーと保存されている値そのもの）
+#[derive(Debug, Clone)]
+pub struct ProjectionRecord {
+    pub key: String,
+    pub value: Vec<u8>,
+}
+
+/// Projectionの生データ照会サービス（Application層トレイト）
+#[allow(async_fn_in_trait)]
+pub trait ProjectionInspectionQueryService: Send + Sync {
+    /// キーが前方一致するレコードをキー順に取得
+    ///
+    /// `after` を指定した場合はそのキーより後のレコードから取得する。
+    async fn scan(
+        &self,
+        prefix: &str,
+        after: Option<&str>,
+        limit: usize,
+    ) -> ApplicationResult<Vec<ProjectionRecord>>;
+}
@@ -0,0 +1,120 @@
+// ProjectionPositionQueryService - Projectionごとの反映位置の照会サービス
+// 用途: イベントストアの最新イベント番号との突合（起動時の検査・保守コマンド）
+
+use crate::error::ApplicationResult;
+
+/// 反映位置の状態
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProjectionPositionStatus {
+    /// 最新イベントまで反映済み
+    UpToDate,
+    /// 未反映のイベントがある（再構築・追いつきで解消する）
+    Lagging,
+    /// 反映位置がイベントストアの最新イベント番号より先行している
+    AheadOfEventStore,
+    /// 反映位置が過去に確認した位置より後退している
+    Regressed,
+}
+
+impl ProjectionPositionStatus {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::UpToDate => "最新",
+            Self::Lagging => "遅延",
+            Self::AheadOfEventStore => "先行",
+            Self::Regressed => "後退",
+        }
+    }
+
+    /// Projectionを初期化して再構築する必要があるか
+    ///
+    /// 先行・後退したProjectionには、イベントストアと対応しない内容が含まれうる。
+    pub fn needs_repair(&self) -> bool {
+        matches!(self, Self::AheadOfEventStore | Self::Regressed)
+    }
+}
+
+/// Projection1件の反映位置
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProjectionPositionDiagnostics {
+    pub projection_name: String,
+    pub projection_version: u32,
+    /// 反映済みの最終イベント番号
+    pub position: u64,
+    /// イベントストアの最新イベント番号
+    pub latest_sequence: u64,
+    /// 過去に確認した反映位置の最高到達点（未記録は0）
+    pub high_water_mark: u64,
+    /// 反映位置の最終更新日時（RFC 3339。未反映の場合はNone）
+    pub updated_at: Option<String>,
+}
+
+impl ProjectionPositionDiagnostics {
+    /// 表示用の識別子（名前:vバージョン）
+    pub fn key(&self) -> String {
+        format!("{}:v{}", self.projection_name, self.projection_version)
+    }
+
+    /// 未反映のイベント数
+    pub fn lag(&self) -> u64 {
+        self.latest_sequence.saturating_sub(self.position)
+    }
+
+    pub fn status(&self) -> ProjectionPositionStatus {
+        if self.position > self.latest_sequence {
+            ProjectionPositionStatus::AheadOfEventStore
+        } else if self.position < self.high_water_mark {
+            ProjectionPositionStatus::Regressed
+        } else if self.position < self.latest_sequence {
+            ProjectionPositionStatus::Lagging
+        } else {
+            ProjectionPositionStatus::UpToDate
+        }
+    }
+}
+
+/// Projectionごとの反映位置の照会サービス（Application層トレイト）
+#[allow(async_fn_in_trait)]
+pub trait ProjectionPositionQueryService: Send + Sync {
+    /// 登録済みのProjectionごとの反映位置（名前・バージョン順）
+    async fn get_projection_positions(
+        &self,
+    ) -> ApplicationResult<Vec<ProjectionPositionDiagnostics>>;
+
+    /// 現在の反映位置を最高到達点として記録（後退の検出に使用）
+    ///
+    /// 記録済みの値より小さい反映位置では更新しない。
+    async fn record_high_water_marks(&self) -> ApplicationResult<()>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn diagnostics(
+        position: u64,
+        latest_sequence: u64,
+        high_water_mark: u64,
+    ) -> ProjectionPositionDiagnostics {
+        ProjectionPositionDiagnostics {
+            projection_name: "main".to_string(),
+            projection_version: 1,
+            position,
+            latest_sequence,
+            high_water_mark,
+            updated_at: None,
+        }
+    }
+
+    #[test]
+    fn test_status_prefers_ahead_then_regressed() {
+        assert_eq!(diagnostics(10, 10, 10).status(), ProjectionPositionStatus::UpToDate);
+        assert_eq!(diagnostics(7, 10, 5).status(), ProjectionPositionStatus::Lagging);
+        assert_eq!(diagnostics(7, 10, 5).lag(), 3);
+        assert_eq!(diagnostics(12, 10, 0).status(), ProjectionPositionStatus::AheadOfEventStore);
+        assert_eq!(diagnostics(12, 10, 0).lag(), 0);
+        assert_eq!(diagnostics(4, 10, 8).status(), ProjectionPositionStatus::Regressed);
+        assert!(ProjectionPositionStatus::Regressed.needs_repair());
+        assert!(!ProjectionPositionStatus::Lagging.needs_repair());
+    }
+}
@@ -0,0 +1,51 @@
+// ProjectionWarmUp - 照会用Projectionの事前構築
+// 重い画面（元帳・仕訳検索）の初回表示前にProjectionを構築しておく
+
+use crate::error::ApplicationResult;
+
+/// Projection構築の進捗
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct WarmUpProgress {
+    /// 今回の構築で適用済みのイベント数
+    pub processed: u64,
+    /// 今回の構築で適用するイベント数
+    pub total: u64,
+    /// 構築中かどうか
+    pub in_progress: bool,
+}
+
+impl WarmUpProgress {
+    /// 進捗率（0〜100）
+    pub fn percent(&self) -> u8 {
+        (self.processed.min(self.total) * 100)
+            .checked_div(self.total)
+            .map_or(100, |p| p as u8)
+    }
+}
+
+/// Projectionを事前構築できる照会サービス
+///
+/// 構築済みのProjectionはキャッシュされ、以降の照会では未適用のイベントのみを適用する。
+#[allow(async_fn_in_trait)]
+pub trait ProjectionWarmUp: Send + Sync {
+    /// Projectionを最新のイベントまで構築してキャッシュする
+    async fn warm_up(&self) -> ApplicationResult<()>;
+
+    /// 現在の構築進捗（構築中の照会から参照できる）
+    fn warm_up_progress(&self) -> WarmUpProgress;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_percent() {
+        let progress = |processed, total| WarmUpProgress { processed, total, in_progress: true };
+
+        assert_eq!(progress(0, 0).percent(), 100);
+        assert_eq!(progress(0, 200).percent(), 0);
+        assert_eq!(progress(50, 200).percent(), 25);
+        assert_eq!(progress(300, 200).percent(), 100);
+    }
+}
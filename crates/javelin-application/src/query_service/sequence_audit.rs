@@ -0,0 +1,35 @@
+// SequenceAuditQueryService - 番号連続性監査用の照会サービス
+
+use crate::error::ApplicationResult;
+
+/// 採番済みの仕訳
+#[derive(Debug, Clone)]
+pub struct NumberedEntry {
+    pub entry_id: String,
+    /// YYYY-MM-DD形式
+    pub transaction_date: String,
+    pub voucher_number: Option<String>,
+    /// 記帳時に採番される伝票番号（未記帳の場合はNone）
+    pub entry_number: Option<String>,
+    pub status: String,
+}
+
+/// 採番後に取り消された証憑番号
+#[derive(Debug, Clone)]
+pub struct VoidedNumber {
+    pub number: String,
+    pub fiscal_year: u32,
+    pub reason: String,
+    /// RFC 3339形式
+    pub voided_at: String,
+}
+
+/// 番号連続性監査用の照会サービス（Application層トレイト）
+#[allow(async_fn_in_trait)]
+pub trait SequenceAuditQueryService: Send + Sync {
+    /// 採番済みの仕訳をすべて取得（削除済の仕訳を含む）
+    async fn load_numbered_entries(&self) -> ApplicationResult<Vec<NumberedEntry>>;
+
+    /// 採番後に取り消された証憑番号を取得（欠番の説明に使う）
+    async fn load_voided_numbers(&self) -> ApplicationResult<Vec<VoidedNumber>>;
+}
@@ -0,0 +1,44 @@
+// SuspenseAgingQueryService - 仮勘定の滞留状況の照会サービス
+
+use crate::error::ApplicationResult;
+
+/// 仮勘定として扱う勘定科目コード
+pub const SUSPENSE_ACCOUNT_CODES: &[&str] = &["9999"];
+
+/// 未整理の仮勘定計上
+#[derive(Debug, Clone, PartialEq)]
+pub struct OpenSuspenseItem {
+    pub entry_id: String,
+    pub entry_number: String,
+    pub line_number: u32,
+    /// YYYY-MM-DD形式
+    pub transaction_date: String,
+    pub account_code: String,
+    /// "Debit" または "Credit"
+    pub side: String,
+    /// 未整理残高（一部整理済みの場合は残額）
+    pub amount: f64,
+    pub currency: String,
+    pub description: Option<String>,
+    /// 計上後に記帳された、同額・反対側の明細（記帳順）
+    pub candidates: Vec<ClearingCandidate>,
+}
+
+/// 仮勘定の整理先候補となる明細
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClearingCandidate {
+    pub entry_id: String,
+    pub entry_number: String,
+    pub line_number: u32,
+    /// YYYY-MM-DD形式
+    pub transaction_date: String,
+    pub account_code: String,
+    pub description: Option<String>,
+}
+
+/// 仮勘定の滞留状況の照会サービス（Application層トレイト）
+#[allow(async_fn_in_trait)]
+pub trait SuspenseAgingQueryService: Send + Sync {
+    /// 未整理の仮勘定計上を計上順に取得
+    async fn get_open_suspense_items(&self) -> ApplicationResult<Vec<OpenSuspenseItem>>;
+}
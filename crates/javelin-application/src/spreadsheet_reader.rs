@@ -0,0 +1,33 @@
+// SpreadsheetReader - 表計算ファイルの読込インターフェース
+// 責務: 表計算ファイル（XLSX等）の先頭シートを見出し行と文字列のセルに変換
+// 具象実装: Infrastructure層で提供
+
+use crate::error::ApplicationResult;
+
+/// 読み込んだシート
+///
+/// 最初の空でない行を見出し行とし、それ以降の行をデータ行とする。
+/// セルは表示用の文字列に変換済み（日付は `YYYY-MM-DD`、整数値の数値は小数点なし）。
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Spreadsheet {
+    pub sheet_name: String,
+    pub headers: Vec<String>,
+    /// データ行（シート上の行番号は `header_row + 1 + インデックス`）
+    pub rows: Vec<Vec<String>>,
+    /// 見出し行のシート上の行番号（1始まり）
+    pub header_row: usize,
+}
+
+impl Spreadsheet {
+    /// データ行のシート上の行番号（1始まり）
+    pub fn sheet_row(&self, index: usize) -> usize {
+        self.header_row + 1 + index
+    }
+}
+
+/// SpreadsheetReaderトレイト
+#[async_trait::async_trait]
+pub trait SpreadsheetReader: Send + Sync {
+    /// ファイルの先頭シートを読み込む
+    async fn read_first_sheet(&self, path: &str) -> ApplicationResult<Spreadsheet>;
+}
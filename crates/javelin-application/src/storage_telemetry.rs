@@ -0,0 +1,36 @@
+// StorageTelemetry - ストレージ使用状況の時系列記録インターフェース
+// 責務: 使用状況の定期的な標本の記録と、傾向表示用の標本の取得
+// 具象実装: Infrastructure層で提供
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::error::ApplicationResult;
+
+/// ストレージ使用状況の標本
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StorageSample {
+    pub sampled_at: DateTime<Utc>,
+    /// イベントストアのmap_sizeに対する使用率（%）
+    pub usage_percent: f64,
+    /// イベントストアの使用量（バイト）
+    pub used_size: u64,
+    /// イベントストアのmap_size（バイト）
+    pub map_size: u64,
+    /// イベント件数
+    pub entries: u64,
+    /// Projectionへの未反映イベント数
+    pub projection_lag: u64,
+}
+
+/// StorageTelemetryトレイト
+///
+/// 標本は上限件数まで保持し、上限を超えた場合は古い標本から破棄する。
+#[async_trait::async_trait]
+pub trait StorageTelemetry: Send + Sync {
+    /// 現在の使用状況を標本として記録し、記録した標本を返す
+    async fn record_sample(&self) -> ApplicationResult<StorageSample>;
+
+    /// 記録済みの標本（古い順）
+    async fn samples(&self) -> ApplicationResult<Vec<StorageSample>>;
+}
@@ -0,0 +1,323 @@
+// Testkit - 単体テスト用のインメモリ実装
+// 責務: Interactorのテストで永続化層を用意せずに使えるEventRepositoryと
+//       元帳照会サービスを提供する
+
+use std::{collections::HashMap, sync::Mutex};
+
+use javelin_domain::{
+    error::{DomainError, DomainResult},
+    financial_close::journal_entry::events::JournalEntryEvent,
+    repositories::EventRepository,
+};
+
+use crate::{
+    error::{ApplicationError, ApplicationResult},
+    query_service::{
+        CarriedForwardBalance, CurrencyTrialBalanceResult, EntryHistoryResult,
+        GetEntryHistoryQuery, GetLedgerQuery, GetTrialBalanceQuery, LedgerQueryService,
+        LedgerResult, TrialBalanceEntry, TrialBalanceResult,
+    },
+};
+
+/// 保存されたイベント
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecordedEvent {
+    /// グローバルシーケンス番号（1始まり）
+    pub sequence: u64,
+    pub aggregate_id: String,
+    pub payload: serde_json::Value,
+}
+
+/// メモリ上のEventRepository
+///
+/// EventStoreと同じく、イベントは保存順にグローバルシーケンスを採番し、
+/// ペイロードはJSONとして保持する。
+#[derive(Debug, Default)]
+pub struct InMemoryEventRepository {
+    events: Mutex<Vec<RecordedEvent>>,
+}
+
+impl InMemoryEventRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 保存されたすべてのイベント（シーケンス順）
+    pub fn recorded(&self) -> Vec<RecordedEvent> {
+        self.events.lock().unwrap().clone()
+    }
+
+    /// 集約のイベントのペイロード（シーケンス順）
+    pub fn events_of(&self, aggregate_id: &str) -> Vec<serde_json::Value> {
+        self.events
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|event| event.aggregate_id == aggregate_id)
+            .map(|event| event.payload.clone())
+            .collect()
+    }
+
+    /// イベントを保存した集約ID（シーケンス順、重複あり）
+    pub fn aggregate_ids(&self) -> Vec<String> {
+        self.events
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|event| event.aggregate_id.clone())
+            .collect()
+    }
+
+    /// 保存されたイベントの種別（`type` フィールド、シーケンス順）
+    pub fn event_types(&self) -> Vec<String> {
+        self.events
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|event| event.payload["type"].as_str().unwrap_or_default().to_string())
+            .collect()
+    }
+
+    /// イベントを保存した集約の数
+    pub fn aggregate_count(&self) -> usize {
+        let mut ids = self.aggregate_ids();
+        ids.sort();
+        ids.dedup();
+        ids.len()
+    }
+
+    /// 保存されたイベント数
+    pub fn len(&self) -> usize {
+        self.events.lock().unwrap().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl EventRepository for InMemoryEventRepository {
+    type Event = JournalEntryEvent;
+
+    async fn append(&self, event: Self::Event) -> DomainResult<()> {
+        let aggregate_id = event.aggregate_id().to_string();
+        self.append_events(&aggregate_id, vec![event]).await.map(|_| ())
+    }
+
+    async fn append_events<T>(&self, aggregate_id: &str, events: Vec<T>) -> DomainResult<u64>
+    where
+        T: serde::Serialize + Send + 'static,
+    {
+        self.append_batches(vec![(aggregate_id.to_string(), events)]).await
+    }
+
+    async fn append_batches<T>(&self, batches: Vec<(String, Vec<T>)>) -> DomainResult<u64>
+    where
+        T: serde::Serialize + Send + 'static,
+    {
+        // すべてシリアライズできてから保存する（一部だけ保存しない）
+        let mut payloads = Vec::new();
+        for (aggregate_id, events) in &batches {
+            for event in events {
+                let payload = serde_json::to_value(event)
+                    .map_err(|e| DomainError::RepositoryError(e.to_string()))?;
+                payloads.push((aggregate_id.clone(), payload));
+            }
+        }
+
+        let mut stored = self.events.lock().unwrap();
+        for (aggregate_id, payload) in payloads {
+            let sequence = stored.len() as u64 + 1;
+            stored.push(RecordedEvent { sequence, aggregate_id, payload });
+        }
+        Ok(stored.len() as u64)
+    }
+
+    async fn get_events(&self, aggregate_id: &str) -> DomainResult<Vec<serde_json::Value>> {
+        Ok(self.events_of(aggregate_id))
+    }
+
+    async fn get_all_events(&self, from_sequence: u64) -> DomainResult<Vec<serde_json::Value>> {
+        Ok(self
+            .events
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|event| event.sequence >= from_sequence)
+            .map(|event| event.payload.clone())
+            .collect())
+    }
+
+    async fn get_latest_sequence(&self) -> DomainResult<u64> {
+        Ok(self.len() as u64)
+    }
+}
+
+/// 残高から試算表の明細を作成（勘定科目名は「勘定科目<コード>」）
+///
+/// 借方残高は借方金額、貸方残高（負の値）は貸方金額とする。
+pub fn trial_balance_entry(account_code: &str, closing_balance: f64) -> TrialBalanceEntry {
+    TrialBalanceEntry {
+        account_code: account_code.to_string(),
+        account_name: format!("勘定科目{}", account_code),
+        opening_balance: 0.0,
+        debit_amount: closing_balance.max(0.0),
+        credit_amount: (-closing_balance).max(0.0),
+        closing_balance,
+    }
+}
+
+/// メモリ上のLedgerQueryService
+///
+/// 元帳の残高・期間別の試算表・通貨別試算表・繰越残高をテストごとに設定する。
+/// 設定のない元帳は残高0、試算表は明細なしとして返す。仕訳修正履歴は扱わない。
+#[derive(Debug, Default)]
+pub struct InMemoryLedgerQueryService {
+    ledger_balances: Mutex<HashMap<String, f64>>,
+    trial_balances: Mutex<HashMap<(u32, u8), Vec<TrialBalanceEntry>>>,
+    currency_trial_balances: Vec<CurrencyTrialBalanceResult>,
+    carried_forward: Vec<CarriedForwardBalance>,
+}
+
+impl InMemoryLedgerQueryService {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 元帳の残高を設定
+    pub fn with_ledger_balance(self, account_code: &str, balance: f64) -> Self {
+        self.ledger_balances.lock().unwrap().insert(account_code.to_string(), balance);
+        self
+    }
+
+    /// 期間の試算表の明細を設定
+    pub fn with_trial_balance(
+        self,
+        period_year: u32,
+        period_month: u8,
+        entries: Vec<TrialBalanceEntry>,
+    ) -> Self {
+        self.trial_balances.lock().unwrap().insert((period_year, period_month), entries);
+        self
+    }
+
+    /// 通貨別試算表を設定（期間によらず同じ結果を返す）
+    pub fn with_currency_trial_balances(
+        mut self,
+        results: Vec<CurrencyTrialBalanceResult>,
+    ) -> Self {
+        self.currency_trial_balances = results;
+        self
+    }
+
+    /// 繰越残高の確定結果を設定
+    pub fn with_carried_forward(mut self, balances: Vec<CarriedForwardBalance>) -> Self {
+        self.carried_forward = balances;
+        self
+    }
+
+    /// 勘定科目の残高を変更（元帳と、科目を含むすべての期間の試算表に反映する）
+    pub fn set_balance(&self, account_code: &str, balance: f64) {
+        self.ledger_balances.lock().unwrap().insert(account_code.to_string(), balance);
+        for entries in self.trial_balances.lock().unwrap().values_mut() {
+            for entry in entries.iter_mut().filter(|entry| entry.account_code == account_code) {
+                *entry = TrialBalanceEntry {
+                    account_name: entry.account_name.clone(),
+                    ..trial_balance_entry(account_code, balance)
+                };
+            }
+        }
+    }
+}
+
+impl LedgerQueryService for InMemoryLedgerQueryService {
+    async fn get_ledger(&self, query: GetLedgerQuery) -> ApplicationResult<LedgerResult> {
+        let balance = self
+            .ledger_balances
+            .lock()
+            .unwrap()
+            .get(&query.account_code)
+            .copied()
+            .unwrap_or(0.0);
+        Ok(LedgerResult {
+            account_name: format!("勘定科目{}", query.account_code),
+            account_code: query.account_code,
+            opening_balance: balance,
+            carried_forward_date: None,
+            entries: vec![],
+            closing_balance: balance,
+            total_debit: 0.0,
+            total_credit: 0.0,
+        })
+    }
+
+    async fn get_trial_balance(
+        &self,
+        query: GetTrialBalanceQuery,
+    ) -> ApplicationResult<TrialBalanceResult> {
+        let entries = self
+            .trial_balances
+            .lock()
+            .unwrap()
+            .get(&(query.period_year, query.period_month))
+            .cloned()
+            .unwrap_or_default();
+        Ok(TrialBalanceResult {
+            period_year: query.period_year,
+            period_month: query.period_month,
+            total_debit: entries.iter().map(|entry| entry.debit_amount).sum(),
+            total_credit: entries.iter().map(|entry| entry.credit_amount).sum(),
+            entries,
+        })
+    }
+
+    async fn get_trial_balance_by_currency(
+        &self,
+        _query: GetTrialBalanceQuery,
+    ) -> ApplicationResult<Vec<CurrencyTrialBalanceResult>> {
+        Ok(self.currency_trial_balances.clone())
+    }
+
+    async fn roll_over_period(
+        &self,
+        _period_year: u32,
+        _period_month: u8,
+    ) -> ApplicationResult<Vec<CarriedForwardBalance>> {
+        Ok(self.carried_forward.clone())
+    }
+
+    async fn get_entry_history(
+        &self,
+        query: GetEntryHistoryQuery,
+    ) -> ApplicationResult<EntryHistoryResult> {
+        Err(ApplicationError::QueryExecutionFailed(format!(
+            "仕訳修正履歴は設定されていません: {}",
+            query.entry_id
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_sequences_are_global_and_streams_are_per_aggregate() {
+        let repository = InMemoryEventRepository::new();
+
+        let last = repository
+            .append_events("agg-1", vec![serde_json::json!({"n": 1}), serde_json::json!({"n": 2})])
+            .await
+            .unwrap();
+        assert_eq!(last, 2);
+        repository
+            .append_events("agg-2", vec![serde_json::json!({"n": 3})])
+            .await
+            .unwrap();
+
+        assert_eq!(repository.get_events("agg-1").await.unwrap().len(), 2);
+        assert_eq!(repository.get_all_events(3).await.unwrap(), vec![serde_json::json!({"n": 3})]);
+        assert_eq!(repository.get_latest_sequence().await.unwrap(), 3);
+        assert_eq!(repository.aggregate_ids(), vec!["agg-1", "agg-1", "agg-2"]);
+    }
+}
@@ -0,0 +1,80 @@
+// UserActivity - 利用状況の集計インターフェース
+// 責務: 操作記録の日次集計（画面・操作ごとの件数・利用者数・所要時間）の更新と取得
+// 具象実装: Infrastructure層で提供
+
+use std::collections::BTreeSet;
+
+use chrono::{DateTime, NaiveDate, Utc};
+use javelin_domain::masters::{UserAction, UserActionKind};
+use serde::{Deserialize, Serialize};
+
+use crate::error::ApplicationResult;
+
+/// 画面・操作ごとの日次集計
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DailyUserActivity {
+    pub date: NaiveDate,
+    /// 操作の種類（UserActionKind::as_str）
+    pub kind: String,
+    pub screen: String,
+    pub action: String,
+    pub count: u64,
+    /// 操作した利用者（重複なし）
+    pub users: BTreeSet<String>,
+    /// 所要時間の合計（ミリ秒）
+    pub total_duration_ms: u64,
+    /// 所要時間の最大値（ミリ秒）
+    pub max_duration_ms: u64,
+}
+
+impl DailyUserActivity {
+    pub fn new(date: NaiveDate, action: &UserAction) -> Self {
+        Self {
+            date,
+            kind: action.kind().as_str().to_string(),
+            screen: action.screen().to_string(),
+            action: action.action().to_string(),
+            count: 0,
+            users: BTreeSet::new(),
+            total_duration_ms: 0,
+            max_duration_ms: 0,
+        }
+    }
+
+    /// 同じ日・画面・操作の記録か
+    pub fn matches(&self, date: NaiveDate, action: &UserAction) -> bool {
+        self.date == date
+            && self.kind == action.kind().as_str()
+            && self.screen == action.screen()
+            && self.action == action.action()
+    }
+
+    /// 操作記録を集計に加える
+    pub fn add(&mut self, action: &UserAction) {
+        self.count += 1;
+        self.users.insert(action.user_id().to_string());
+        self.total_duration_ms = self.total_duration_ms.saturating_add(action.duration_ms());
+        self.max_duration_ms = self.max_duration_ms.max(action.duration_ms());
+    }
+
+    pub fn is_kind(&self, kind: UserActionKind) -> bool {
+        self.kind == kind.as_str()
+    }
+
+    /// 平均所要時間（ミリ秒）。件数がない場合はNone
+    pub fn average_duration_ms(&self) -> Option<u64> {
+        (self.count > 0).then(|| self.total_duration_ms / self.count)
+    }
+}
+
+/// 利用状況の集計（Projection）
+///
+/// 記録日（業務日付）・画面・操作ごとに集計し、保持期間を過ぎた日は破棄する。
+#[async_trait::async_trait]
+pub trait UserActivityProjection: Send + Sync {
+    /// 操作記録を集計に反映
+    async fn apply(&self, action: &UserAction, at: DateTime<Utc>) -> ApplicationResult<()>;
+
+    /// 指定日以降の日次集計（日付・種類・画面・操作順）
+    async fn daily_activity(&self, from: NaiveDate) -> ApplicationResult<Vec<DailyUserActivity>>;
+}
@@ -41,8 +41,53 @@ pub enum DomainError {
     #[error("[D-4002] Repository error: {0}")]
     RepositoryError(String),
 
+    #[error("[D-5001] Permission denied: {0}")]
+    PermissionDenied(String),
+
     #[error("[D-9999] Unknown domain error: {0}")]
     Unknown(String),
 }
 
 pub type DomainResult<T> = Result<T, DomainError>;
+
+impl DomainError {
+    /// エラーコード（D-xxxx）
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::InvalidAccountingPeriod => "D-1001",
+            Self::InvalidAccountCode => "D-1002",
+            Self::InvalidAmount(_) => "D-1003",
+            Self::ValidationError(_) => "D-1004",
+            Self::JournalEntryValidationFailed => "D-2001",
+            Self::EvidenceRequired => "D-2002",
+            Self::InvalidStatusTransition => "D-2003",
+            Self::EntityNotFound(_) => "D-3001",
+            Self::NotFound(_) => "D-3002",
+            Self::VersionConflict => "D-3003",
+            Self::SerializationFailed(_) => "D-4001",
+            Self::RepositoryError(_) => "D-4002",
+            Self::PermissionDenied(_) => "D-5001",
+            Self::Unknown(_) => "D-9999",
+        }
+    }
+
+    /// 利用者向けのメッセージ（エラーコードを含まない）
+    pub fn user_message(&self) -> String {
+        match self {
+            Self::InvalidAccountingPeriod => "会計期間の月は1〜12で指定してください".to_string(),
+            Self::InvalidAccountCode => "勘定科目コードを入力してください".to_string(),
+            Self::JournalEntryValidationFailed => "借方と貸方の合計が一致していません".to_string(),
+            Self::EvidenceRequired => "証憑の参照が必要です".to_string(),
+            Self::InvalidStatusTransition => "現在の状態ではこの操作を行えません".to_string(),
+            Self::VersionConflict => "他の操作で更新されています".to_string(),
+            Self::InvalidAmount(message)
+            | Self::ValidationError(message)
+            | Self::EntityNotFound(message)
+            | Self::NotFound(message)
+            | Self::SerializationFailed(message)
+            | Self::RepositoryError(message)
+            | Self::PermissionDenied(message)
+            | Self::Unknown(message) => message.clone(),
+        }
+    }
+}
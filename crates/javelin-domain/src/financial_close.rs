@@ -1,12 +1,18 @@
 // 月次決算確報ドメインモデル
 // financialCloseFinalReport.md 第2章 財務情報基盤に基づく
 
+pub mod account_reconciliation;
 pub mod accounting_period;
 pub mod closing_events;
 pub mod company;
+pub mod financial_instrument;
+pub mod inventory_valuation;
 pub mod journal_entry;
 pub mod ledger;
+pub mod report_archive;
+pub mod supplier_invoice;
 pub mod values;
+pub mod variance_commentary;
 
 use crate::{
     error::{DomainError, DomainResult},
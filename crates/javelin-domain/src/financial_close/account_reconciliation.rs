@@ -0,0 +1,269 @@
+// 勘定照合（残高の裏付け）
+// 主要な貸借対照表科目について、総勘定元帳の残高を裏付ける明細を集め、
+// 期間ごとに照合済みとして記録する
+
+use crate::error::{DomainError, DomainResult};
+
+/// 照合差額の許容範囲（端数の丸め誤差）
+pub const RECONCILIATION_TOLERANCE: f64 = 0.005;
+
+/// 残高の裏付け明細
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReconciliationItem {
+    /// 内容（銀行残高証明、売掛金年齢表など）
+    pub description: String,
+    /// 金額（元帳残高と同じ符号）
+    pub amount: f64,
+    /// 証憑の参照（ファイル名・文書番号など）
+    pub reference: Option<String>,
+}
+
+impl ReconciliationItem {
+    pub fn new(
+        description: impl Into<String>,
+        amount: f64,
+        reference: Option<String>,
+    ) -> DomainResult<Self> {
+        let description = description.into().trim().to_string();
+        if description.is_empty() {
+            return Err(DomainError::ValidationError("裏付け明細の内容は必須です".to_string()));
+        }
+        if !amount.is_finite() {
+            return Err(DomainError::ValidationError(format!(
+                "裏付け明細の金額が不正です: {}",
+                amount
+            )));
+        }
+        let reference = reference.map(|r| r.trim().to_string()).filter(|r| !r.is_empty());
+        Ok(Self { description, amount, reference })
+    }
+}
+
+/// 照合状況
+#[derive(Debug, Clone, PartialEq)]
+pub enum ReconciliationStatus {
+    /// 作業中
+    Open,
+    /// 照合済（照合時点の元帳残高を保持する）
+    Reconciled { reconciled_by: String, reconciled_at: String, gl_balance: f64 },
+}
+
+/// 勘定照合ワークスペース
+///
+/// 科目・会計期間ごとに1つ作成する。照合済の間は明細を変更できず、
+/// 変更するには照合を取り消して作業中に戻す。
+#[derive(Debug, Clone, PartialEq)]
+pub struct AccountReconciliation {
+    account_code: String,
+    fiscal_year: i32,
+    period: u8,
+    items: Vec<ReconciliationItem>,
+    status: ReconciliationStatus,
+}
+
+impl AccountReconciliation {
+    pub fn new(
+        account_code: impl Into<String>,
+        fiscal_year: i32,
+        period: u8,
+    ) -> DomainResult<Self> {
+        let account_code = account_code.into();
+        if account_code.trim().is_empty() {
+            return Err(DomainError::InvalidAccountCode);
+        }
+        if !(1..=12).contains(&period) {
+            return Err(DomainError::InvalidAccountingPeriod);
+        }
+        Ok(Self {
+            account_code,
+            fiscal_year,
+            period,
+            items: Vec::new(),
+            status: ReconciliationStatus::Open,
+        })
+    }
+
+    /// 保存済みの状態から復元
+    pub fn restore(
+        account_code: impl Into<String>,
+        fiscal_year: i32,
+        period: u8,
+        items: Vec<ReconciliationItem>,
+        status: ReconciliationStatus,
+    ) -> DomainResult<Self> {
+        let mut reconciliation = Self::new(account_code, fiscal_year, period)?;
+        reconciliation.items = items;
+        reconciliation.status = status;
+        Ok(reconciliation)
+    }
+
+    pub fn account_code(&self) -> &str {
+        &self.account_code
+    }
+
+    pub fn fiscal_year(&self) -> i32 {
+        self.fiscal_year
+    }
+
+    pub fn period(&self) -> u8 {
+        self.period
+    }
+
+    pub fn items(&self) -> &[ReconciliationItem] {
+        &self.items
+    }
+
+    pub fn status(&self) -> &ReconciliationStatus {
+        &self.status
+    }
+
+    pub fn is_reconciled(&self) -> bool {
+        matches!(self.status, ReconciliationStatus::Reconciled { .. })
+    }
+
+    /// 裏付け明細の合計
+    pub fn supported_total(&self) -> f64 {
+        self.items.iter().map(|item| item.amount).sum()
+    }
+
+    /// 元帳残高と裏付け明細合計の差額（元帳残高 − 明細合計）
+    pub fn difference(&self, gl_balance: f64) -> f64 {
+        gl_balance - self.supported_total()
+    }
+
+    /// 照合済で、照合時点から元帳残高が変わっていないか
+    ///
+    /// 照合後に記帳があり残高が変わった場合は照合をやり直す必要がある。
+    pub fn is_current(&self, gl_balance: f64) -> bool {
+        match &self.status {
+            ReconciliationStatus::Reconciled { gl_balance: reconciled, .. } => {
+                (reconciled - gl_balance).abs() < RECONCILIATION_TOLERANCE
+            }
+            ReconciliationStatus::Open => false,
+        }
+    }
+
+    /// 裏付け明細を追加
+    pub fn add_item(&mut self, item: ReconciliationItem) -> DomainResult<()> {
+        self.ensure_open()?;
+        self.items.push(item);
+        Ok(())
+    }
+
+    /// 裏付け明細を削除
+    pub fn remove_item(&mut self, index: usize) -> DomainResult<ReconciliationItem> {
+        self.ensure_open()?;
+        if index >= self.items.len() {
+            return Err(DomainError::ValidationError(format!(
+                "裏付け明細が見つかりません: {}",
+                index + 1
+            )));
+        }
+        Ok(self.items.remove(index))
+    }
+
+    /// 照合済にする（明細合計が元帳残高と一致する場合のみ）
+    pub fn mark_reconciled(
+        &mut self,
+        gl_balance: f64,
+        reconciled_by: impl Into<String>,
+        reconciled_at: impl Into<String>,
+    ) -> DomainResult<()> {
+        self.ensure_open()?;
+        let difference = self.difference(gl_balance);
+        if difference.abs() >= RECONCILIATION_TOLERANCE {
+            return Err(DomainError::ValidationError(format!(
+                "裏付け明細の合計が元帳残高と一致しません（差額 {:.2}）",
+                difference
+            )));
+        }
+        self.status = ReconciliationStatus::Reconciled {
+            reconciled_by: reconciled_by.into(),
+            reconciled_at: reconciled_at.into(),
+            gl_balance,
+        };
+        Ok(())
+    }
+
+    /// 照合を取り消して作業中に戻す
+    pub fn reopen(&mut self) -> DomainResult<()> {
+        if !self.is_reconciled() {
+            return Err(DomainError::ValidationError(format!(
+                "{} は照合済ではありません",
+                self.account_code
+            )));
+        }
+        self.status = ReconciliationStatus::Open;
+        Ok(())
+    }
+
+    fn ensure_open(&self) -> DomainResult<()> {
+        if self.is_reconciled() {
+            return Err(DomainError::ValidationError(format!(
+                "{} は照合済のため変更できません（照合を取り消してください）",
+                self.account_code
+            )));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(description: &str, amount: f64) -> ReconciliationItem {
+        ReconciliationItem::new(description, amount, None).unwrap()
+    }
+
+    #[test]
+    fn test_mark_reconciled_requires_matching_total() {
+        let mut reconciliation = AccountReconciliation::new("1100", 2024, 3).unwrap();
+        reconciliation.add_item(item("A銀行 残高証明", 700_000.0)).unwrap();
+        reconciliation.add_item(item("B銀行 残高証明", 250_000.0)).unwrap();
+
+        assert_eq!(reconciliation.difference(1_000_000.0), 50_000.0);
+        assert!(
+            reconciliation
+                .mark_reconciled(1_000_000.0, "user1", "2024-04-05T00:00:00Z")
+                .is_err()
+        );
+
+        reconciliation.add_item(item("未達小切手", 50_000.0)).unwrap();
+        reconciliation
+            .mark_reconciled(1_000_000.0, "user1", "2024-04-05T00:00:00Z")
+            .unwrap();
+        assert!(reconciliation.is_reconciled());
+        assert!(reconciliation.is_current(1_000_000.0));
+        assert!(!reconciliation.is_current(1_200_000.0));
+    }
+
+    #[test]
+    fn test_reconciled_workspace_is_frozen_until_reopened() {
+        let mut reconciliation = AccountReconciliation::new("1100", 2024, 3).unwrap();
+        reconciliation.add_item(item("A銀行 残高証明", 100.0)).unwrap();
+        reconciliation.mark_reconciled(100.0, "user1", "2024-04-05T00:00:00Z").unwrap();
+
+        assert!(reconciliation.add_item(item("追加", 1.0)).is_err());
+        assert!(reconciliation.remove_item(0).is_err());
+
+        reconciliation.reopen().unwrap();
+        assert_eq!(reconciliation.remove_item(0).unwrap().amount, 100.0);
+        assert!(reconciliation.remove_item(0).is_err());
+        assert!(reconciliation.reopen().is_err());
+    }
+
+    #[test]
+    fn test_invalid_inputs() {
+        assert!(AccountReconciliation::new("", 2024, 3).is_err());
+        assert!(AccountReconciliation::new("1100", 2024, 13).is_err());
+        assert!(ReconciliationItem::new("  ", 1.0, None).is_err());
+        assert!(ReconciliationItem::new("残高証明", f64::NAN, None).is_err());
+        assert_eq!(
+            ReconciliationItem::new("残高証明", 1.0, Some(" ".to_string()))
+                .unwrap()
+                .reference,
+            None
+        );
+    }
+}
@@ -1,7 +1,9 @@
 // 会計期間エンティティ
 
 pub mod entities;
+pub mod events;
 pub mod values;
 
 pub use entities::*;
+pub use events::*;
 pub use values::*;
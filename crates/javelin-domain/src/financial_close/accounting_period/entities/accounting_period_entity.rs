@@ -4,7 +4,7 @@ use crate::{
     entity::Entity,
     error::{DomainError, DomainResult},
     financial_close::{
-        accounting_period::values::{Date, DateTime, FiscalYear, Period, PeriodId},
+        accounting_period::values::{Date, DateTime, FiscalYear, Period, PeriodId, ReopenApproval},
         journal_entry::values::{identifiers::UserId, status::PeriodStatus},
     },
 };
@@ -32,6 +32,8 @@ pub struct AccountingPeriod {
     reopened_at: Option<DateTime>,
     /// 再オープン実行者
     reopened_by: Option<UserId>,
+    /// 再オープン承認者
+    reopen_approved_by: Option<UserId>,
     /// 再オープン理由
     reopen_reason: Option<String>,
 }
@@ -72,6 +74,7 @@ impl AccountingPeriod {
             closed_by: None,
             reopened_at: None,
             reopened_by: None,
+            reopen_approved_by: None,
             reopen_reason: None,
         })
     }
@@ -103,25 +106,18 @@ impl AccountingPeriod {
     }
 
     /// 期間を再オープンする
-    pub fn reopen(
-        &mut self,
-        user_id: UserId,
-        reason: String,
-        reopened_at: DateTime,
-    ) -> DomainResult<()> {
-        // Closed状態のみ再オープンできる
-        if !matches!(self.status, PeriodStatus::Closed) {
+    ///
+    /// 締め済・ロック済の期間のみ、申請者と異なる承認者の承認を得て再オープンできる。
+    pub fn reopen(&mut self, approval: ReopenApproval, reopened_at: DateTime) -> DomainResult<()> {
+        if !self.status.can_reopen() {
             return Err(DomainError::InvalidStatusTransition);
         }
 
-        if reason.is_empty() {
-            return Err(DomainError::InvalidAmount("Reopen reason is required".to_string()));
-        }
-
         self.status = PeriodStatus::Open;
         self.reopened_at = Some(reopened_at);
-        self.reopened_by = Some(user_id);
-        self.reopen_reason = Some(reason);
+        self.reopened_by = Some(approval.requested_by().clone());
+        self.reopen_approved_by = Some(approval.approved_by().clone());
+        self.reopen_reason = Some(approval.reason().to_string());
 
         Ok(())
     }
@@ -173,6 +169,10 @@ impl AccountingPeriod {
         self.reopened_by.as_ref()
     }
 
+    pub fn reopen_approved_by(&self) -> Option<&UserId> {
+        self.reopen_approved_by.as_ref()
+    }
+
     pub fn reopen_reason(&self) -> Option<&str> {
         self.reopen_reason.as_deref()
     }
@@ -272,12 +272,46 @@ mod tests {
         period.close(user_id.clone(), closed_at).unwrap();
 
         let reopened_at = chrono::Utc::now().naive_utc();
-        let result = period.reopen(user_id, "Need to add missing entries".to_string(), reopened_at);
+        let approval = ReopenApproval::new(
+            user_id,
+            UserId::new("manager".to_string()),
+            "Need to add missing entries".to_string(),
+        )
+        .unwrap();
+        let result = period.reopen(approval, reopened_at);
 
         assert!(result.is_ok());
         assert_eq!(period.status(), &PeriodStatus::Open);
         assert!(period.can_post_journal());
         assert_eq!(period.reopen_reason().unwrap(), "Need to add missing entries");
+        assert_eq!(period.reopen_approved_by().unwrap().value(), "manager");
+    }
+
+    #[test]
+    fn test_reopen_locked_period() {
+        let mut period = create_test_period();
+        let user_id = UserId::new("user1".to_string());
+        let closed_at = chrono::Utc::now().naive_utc();
+
+        period.close(user_id.clone(), closed_at).unwrap();
+        period.lock().unwrap();
+
+        let approval = ReopenApproval::new(
+            user_id,
+            UserId::new("manager".to_string()),
+            "監査指摘".to_string(),
+        )
+        .unwrap();
+        assert!(period.reopen(approval, closed_at).is_ok());
+        assert!(period.can_post_journal());
+    }
+
+    #[test]
+    fn test_reopen_requires_different_approver() {
+        let user_id = UserId::new("user1".to_string());
+
+        let result = ReopenApproval::new(user_id.clone(), user_id, "Test reason".to_string());
+        assert!(result.is_err());
     }
 
     #[test]
@@ -288,10 +322,11 @@ mod tests {
 
         period.close(user_id.clone(), closed_at).unwrap();
 
-        let reopened_at = chrono::Utc::now().naive_utc();
-        let result = period.reopen(user_id, "".to_string(), reopened_at);
+        let result =
+            ReopenApproval::new(user_id, UserId::new("manager".to_string()), "  ".to_string());
 
         assert!(result.is_err());
+        assert_eq!(period.status(), &PeriodStatus::Closed);
     }
 
     #[test]
@@ -299,8 +334,14 @@ mod tests {
         let mut period = create_test_period();
         let user_id = UserId::new("user1".to_string());
         let reopened_at = chrono::Utc::now().naive_utc();
-
-        let result = period.reopen(user_id, "Test reason".to_string(), reopened_at);
+        let approval = ReopenApproval::new(
+            user_id,
+            UserId::new("manager".to_string()),
+            "Test reason".to_string(),
+        )
+        .unwrap();
+
+        let result = period.reopen(approval, reopened_at);
         assert!(result.is_err());
     }
 
@@ -0,0 +1,220 @@
+// 会計期間ドメインイベント
+
+use serde::{Deserialize, Serialize};
+
+use crate::{event::DomainEvent, financial_close::journal_entry::values::status::PeriodStatus};
+
+/// 会計期間ドメインイベント
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "type")]
+pub enum AccountingPeriodEvent {
+    /// 期間作成
+    PeriodCreated {
+        period_id: String,
+        fiscal_year: u32,
+        period: u32,
+        start_date: String,
+        end_date: String,
+        created_by: String,
+        created_at: String,
+    },
+
+    /// 期間締め
+    PeriodClosed { period_id: String, closed_by: String, closed_at: String },
+
+    /// 期間ロック
+    PeriodLocked { period_id: String, locked_by: String, locked_at: String },
+
+    /// 締日固定の事前検証の上書き
+    ///
+    /// 検証に失敗した項目（failed_checks）を管理者が理由を付けて承認した記録。
+    /// 直後に期間ロックが記録される。
+    LockValidationOverridden {
+        period_id: String,
+        failed_checks: Vec<String>,
+        reason: String,
+        overridden_by: String,
+        overridden_at: String,
+    },
+
+    /// 期間再オープン
+    ///
+    /// 申請者（reopened_by）と異なる承認者（approved_by）の承認が必要。
+    PeriodReopened {
+        period_id: String,
+        reason: String,
+        reopened_by: String,
+        #[serde(default)]
+        approved_by: String,
+        reopened_at: String,
+    },
+}
+
+impl AccountingPeriodEvent {
+    /// イベントタイプを取得
+    pub fn event_type(&self) -> &str {
+        match self {
+            AccountingPeriodEvent::PeriodCreated { .. } => "PeriodCreated",
+            AccountingPeriodEvent::PeriodClosed { .. } => "PeriodClosed",
+            AccountingPeriodEvent::PeriodLocked { .. } => "PeriodLocked",
+            AccountingPeriodEvent::LockValidationOverridden { .. } => "LockValidationOverridden",
+            AccountingPeriodEvent::PeriodReopened { .. } => "PeriodReopened",
+        }
+    }
+
+    /// 集約IDを取得
+    pub fn aggregate_id(&self) -> &str {
+        match self {
+            AccountingPeriodEvent::PeriodCreated { period_id, .. }
+            | AccountingPeriodEvent::PeriodClosed { period_id, .. }
+            | AccountingPeriodEvent::PeriodLocked { period_id, .. }
+            | AccountingPeriodEvent::LockValidationOverridden { period_id, .. }
+            | AccountingPeriodEvent::PeriodReopened { period_id, .. } => period_id,
+        }
+    }
+}
+
+impl DomainEvent for AccountingPeriodEvent {
+    fn event_type(&self) -> &str {
+        self.event_type()
+    }
+
+    fn aggregate_id(&self) -> &str {
+        self.aggregate_id()
+    }
+
+    fn version(&self) -> u64 {
+        0
+    }
+}
+
+/// 直近の再オープン
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PeriodReopening {
+    pub reason: String,
+    pub reopened_by: String,
+    pub approved_by: String,
+    /// RFC 3339形式
+    pub reopened_at: String,
+    /// 再オープン後に締め直した日時（RFC 3339形式、再オープン中はNone）
+    pub closed_again_at: Option<String>,
+}
+
+/// イベント履歴から復元した会計期間の状態
+///
+/// 締め・ロックと再オープンの履歴から、期間の状態と直近の再オープンを求める。
+/// イベントのない期間はオープンとみなす。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PeriodHistory {
+    status: PeriodStatus,
+    last_reopening: Option<PeriodReopening>,
+}
+
+impl PeriodHistory {
+    /// イベントを順に適用して状態を復元
+    pub fn replay<'a>(events: impl IntoIterator<Item = &'a AccountingPeriodEvent>) -> Self {
+        let mut history = Self { status: PeriodStatus::Open, last_reopening: None };
+        for event in events {
+            match event {
+                AccountingPeriodEvent::PeriodCreated { .. }
+                | AccountingPeriodEvent::LockValidationOverridden { .. } => {}
+                AccountingPeriodEvent::PeriodClosed { closed_at, .. } => {
+                    history.close(PeriodStatus::Closed, closed_at)
+                }
+                AccountingPeriodEvent::PeriodLocked { locked_at, .. } => {
+                    history.close(PeriodStatus::Locked, locked_at)
+                }
+                AccountingPeriodEvent::PeriodReopened {
+                    reason,
+                    reopened_by,
+                    approved_by,
+                    reopened_at,
+                    ..
+                } => {
+                    history.status = PeriodStatus::Open;
+                    history.last_reopening = Some(PeriodReopening {
+                        reason: reason.clone(),
+                        reopened_by: reopened_by.clone(),
+                        approved_by: approved_by.clone(),
+                        reopened_at: reopened_at.clone(),
+                        closed_again_at: None,
+                    });
+                }
+            }
+        }
+        history
+    }
+
+    /// 締め・ロック（再オープン中であれば再オープン期間を終える）
+    fn close(&mut self, status: PeriodStatus, at: &str) {
+        self.status = status;
+        if let Some(reopening) = &mut self.last_reopening
+            && reopening.closed_again_at.is_none()
+        {
+            reopening.closed_again_at = Some(at.to_string());
+        }
+    }
+
+    pub fn status(&self) -> &PeriodStatus {
+        &self.status
+    }
+
+    pub fn last_reopening(&self) -> Option<&PeriodReopening> {
+        self.last_reopening.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn locked(at: &str) -> AccountingPeriodEvent {
+        AccountingPeriodEvent::PeriodLocked {
+            period_id: "2024-03".to_string(),
+            locked_by: "admin".to_string(),
+            locked_at: at.to_string(),
+        }
+    }
+
+    fn reopened(at: &str) -> AccountingPeriodEvent {
+        AccountingPeriodEvent::PeriodReopened {
+            period_id: "2024-03".to_string(),
+            reason: "売上計上漏れ".to_string(),
+            reopened_by: "tanaka".to_string(),
+            approved_by: "suzuki".to_string(),
+            reopened_at: at.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_replay_tracks_reopen_window() {
+        let events = vec![
+            locked("2024-04-05T00:00:00Z"),
+            reopened("2024-04-10T00:00:00Z"),
+            locked("2024-04-12T00:00:00Z"),
+        ];
+
+        let history = PeriodHistory::replay(&events[..2]);
+        assert_eq!(history.status(), &PeriodStatus::Open);
+        assert!(history.status().can_post_journal());
+        assert_eq!(history.last_reopening().unwrap().closed_again_at, None);
+
+        let history = PeriodHistory::replay(&events);
+        assert_eq!(history.status(), &PeriodStatus::Locked);
+        let reopening = history.last_reopening().unwrap();
+        assert_eq!(reopening.reopened_at, "2024-04-10T00:00:00Z");
+        assert_eq!(reopening.closed_again_at.as_deref(), Some("2024-04-12T00:00:00Z"));
+    }
+
+    #[test]
+    fn test_reopened_event_without_approver_deserializes() {
+        let json = r#"{"type":"PeriodReopened","period_id":"2024-03","reason":"r",
+            "reopened_by":"tanaka","reopened_at":"2024-04-10T00:00:00Z"}"#;
+        let event: AccountingPeriodEvent = serde_json::from_str(json).unwrap();
+
+        assert!(matches!(
+            event,
+            AccountingPeriodEvent::PeriodReopened { approved_by, .. } if approved_by.is_empty()
+        ));
+    }
+}
@@ -5,6 +5,7 @@ use chrono::{NaiveDate, NaiveDateTime};
 use crate::{
     entity::EntityId,
     error::{DomainError, DomainResult},
+    financial_close::journal_entry::values::identifiers::UserId,
     value_object::ValueObject,
 };
 
@@ -79,6 +80,53 @@ impl PeriodId {
     pub fn from_year_period(year: FiscalYear, period: Period) -> Self {
         Self(format!("{}-{:02}", year.value(), period.value()))
     }
+
+    /// 取引日付を含む期間の期間ID
+    pub fn containing(date: NaiveDate) -> Self {
+        Self(date.format("%Y-%m").to_string())
+    }
+}
+
+/// 期間再オープンの承認
+///
+/// 再オープンには理由と、申請者とは別の承認者が必要。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReopenApproval {
+    requested_by: UserId,
+    approved_by: UserId,
+    reason: String,
+}
+
+impl ReopenApproval {
+    pub fn new(requested_by: UserId, approved_by: UserId, reason: String) -> DomainResult<Self> {
+        let reason = reason.trim().to_string();
+        if reason.is_empty() {
+            return Err(DomainError::ValidationError(
+                "再オープンの理由を入力してください".to_string(),
+            ));
+        }
+        if approved_by.value().trim().is_empty() {
+            return Err(DomainError::ValidationError("承認者を指定してください".to_string()));
+        }
+        if approved_by == requested_by {
+            return Err(DomainError::ValidationError(
+                "承認者は申請者と異なる利用者を指定してください".to_string(),
+            ));
+        }
+        Ok(Self { requested_by, approved_by, reason })
+    }
+
+    pub fn requested_by(&self) -> &UserId {
+        &self.requested_by
+    }
+
+    pub fn approved_by(&self) -> &UserId {
+        &self.approved_by
+    }
+
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
 }
 
 /// 日付
@@ -129,6 +177,12 @@ mod tests {
         assert_eq!(id.value(), "2024-03");
     }
 
+    #[test]
+    fn test_period_id_containing_date() {
+        let date = NaiveDate::from_ymd_opt(2024, 3, 31).unwrap();
+        assert_eq!(PeriodId::containing(date).value(), "2024-03");
+    }
+
     // Property-based tests
     mod property_tests {
         use proptest::prelude::*;
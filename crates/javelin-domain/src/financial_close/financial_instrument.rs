@@ -0,0 +1,386 @@
+// 金融商品の償却原価測定（IFRS第9号）
+// 実効金利法で償却原価スケジュールを作成し、月次の利息を見越計上する
+
+use std::collections::BTreeMap;
+
+use chrono::{Datelike, NaiveDate};
+
+use crate::error::{DomainError, DomainResult};
+
+/// 金融商品の種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InstrumentKind {
+    /// 債券
+    Bond,
+    /// 貸付金・借入金
+    Loan,
+}
+
+impl InstrumentKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            InstrumentKind::Bond => "Bond",
+            InstrumentKind::Loan => "Loan",
+        }
+    }
+
+    pub fn parse(value: &str) -> DomainResult<Self> {
+        match value {
+            "Bond" => Ok(InstrumentKind::Bond),
+            "Loan" => Ok(InstrumentKind::Loan),
+            _ => Err(DomainError::ValidationError(format!("金融商品の種類が不正です: {}", value))),
+        }
+    }
+}
+
+/// 保有区分
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InstrumentPosition {
+    /// 金融資産（保有債券・貸付金）：受取利息を計上
+    Asset,
+    /// 金融負債（発行債券・借入金）：支払利息を計上
+    Liability,
+}
+
+impl InstrumentPosition {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            InstrumentPosition::Asset => "Asset",
+            InstrumentPosition::Liability => "Liability",
+        }
+    }
+
+    pub fn parse(value: &str) -> DomainResult<Self> {
+        match value {
+            "Asset" => Ok(InstrumentPosition::Asset),
+            "Liability" => Ok(InstrumentPosition::Liability),
+            _ => Err(DomainError::ValidationError(format!("保有区分が不正です: {}", value))),
+        }
+    }
+}
+
+/// 償却原価スケジュールの1か月分
+#[derive(Debug, Clone, PartialEq)]
+pub struct AmortizationLine {
+    pub fiscal_year: i32,
+    pub period: u8,
+    /// 期首の償却原価
+    pub opening_carrying_amount: f64,
+    /// 実効金利による利息
+    pub effective_interest: f64,
+    /// 表面利率による利息
+    pub coupon_interest: f64,
+    /// 償却額（実効金利による利息 - 表面利率による利息）
+    pub amortization: f64,
+    /// 期末の償却原価
+    pub closing_carrying_amount: f64,
+}
+
+/// 償却原価で測定する金融商品
+///
+/// 取得時の帳簿価額から満期の額面まで、実効金利法で月次に償却する。
+/// 利息を見越計上した会計期間は仕訳IDとともに記録し、重複して起票しない。
+#[derive(Debug, Clone, PartialEq)]
+pub struct FinancialInstrument {
+    instrument_id: String,
+    name: String,
+    kind: InstrumentKind,
+    position: InstrumentPosition,
+    /// 帳簿価額を計上する勘定科目
+    account_code: String,
+    currency: String,
+    face_value: f64,
+    /// 取得時（発行時）の帳簿価額
+    initial_carrying_amount: f64,
+    /// 表面利率（年率）
+    coupon_rate: f64,
+    /// 実効金利（年率）
+    effective_rate: f64,
+    start_date: NaiveDate,
+    maturity_date: NaiveDate,
+    /// 利息を見越計上した会計期間と仕訳ID
+    accrued_entries: BTreeMap<(i32, u8), String>,
+}
+
+impl FinancialInstrument {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        instrument_id: impl Into<String>,
+        name: impl Into<String>,
+        kind: InstrumentKind,
+        position: InstrumentPosition,
+        account_code: impl Into<String>,
+        currency: impl Into<String>,
+        face_value: f64,
+        initial_carrying_amount: f64,
+        coupon_rate: f64,
+        effective_rate: f64,
+        start_date: NaiveDate,
+        maturity_date: NaiveDate,
+    ) -> DomainResult<Self> {
+        let instrument_id = instrument_id.into();
+        if instrument_id.trim().is_empty() {
+            return Err(DomainError::ValidationError("金融商品IDは必須です".to_string()));
+        }
+        let account_code = account_code.into();
+        if account_code.trim().is_empty() {
+            return Err(DomainError::InvalidAccountCode);
+        }
+        if face_value <= 0.0 || initial_carrying_amount <= 0.0 {
+            return Err(DomainError::ValidationError(format!(
+                "額面・帳簿価額は正の値を指定してください: {}",
+                instrument_id
+            )));
+        }
+        if coupon_rate < 0.0 || effective_rate < 0.0 {
+            return Err(DomainError::ValidationError(format!(
+                "利率に負の値は指定できません: {}",
+                instrument_id
+            )));
+        }
+        if month_index(maturity_date) <= month_index(start_date) {
+            return Err(DomainError::ValidationError(format!(
+                "満期日は取得日の翌月以降を指定してください: {}",
+                instrument_id
+            )));
+        }
+
+        Ok(Self {
+            instrument_id,
+            name: name.into(),
+            kind,
+            position,
+            account_code,
+            currency: currency.into(),
+            face_value,
+            initial_carrying_amount,
+            coupon_rate,
+            effective_rate,
+            start_date,
+            maturity_date,
+            accrued_entries: BTreeMap::new(),
+        })
+    }
+
+    pub fn instrument_id(&self) -> &str {
+        &self.instrument_id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn kind(&self) -> InstrumentKind {
+        self.kind
+    }
+
+    pub fn position(&self) -> InstrumentPosition {
+        self.position
+    }
+
+    pub fn account_code(&self) -> &str {
+        &self.account_code
+    }
+
+    pub fn currency(&self) -> &str {
+        &self.currency
+    }
+
+    pub fn face_value(&self) -> f64 {
+        self.face_value
+    }
+
+    pub fn initial_carrying_amount(&self) -> f64 {
+        self.initial_carrying_amount
+    }
+
+    pub fn coupon_rate(&self) -> f64 {
+        self.coupon_rate
+    }
+
+    pub fn effective_rate(&self) -> f64 {
+        self.effective_rate
+    }
+
+    pub fn start_date(&self) -> NaiveDate {
+        self.start_date
+    }
+
+    pub fn maturity_date(&self) -> NaiveDate {
+        self.maturity_date
+    }
+
+    /// 利息を見越計上した会計期間と仕訳ID
+    pub fn accrued_entries(&self) -> &BTreeMap<(i32, u8), String> {
+        &self.accrued_entries
+    }
+
+    pub fn accrued_entry_id(&self, fiscal_year: i32, period: u8) -> Option<&str> {
+        self.accrued_entries.get(&(fiscal_year, period)).map(String::as_str)
+    }
+
+    /// 利息の見越計上を記録
+    pub fn mark_accrued(
+        &mut self,
+        fiscal_year: i32,
+        period: u8,
+        entry_id: impl Into<String>,
+    ) -> DomainResult<()> {
+        if self.schedule_for(fiscal_year, period).is_none() {
+            return Err(DomainError::ValidationError(format!(
+                "償却期間外の会計期間です: {} {}-{:02}",
+                self.instrument_id, fiscal_year, period
+            )));
+        }
+        if self.accrued_entries.contains_key(&(fiscal_year, period)) {
+            return Err(DomainError::ValidationError(format!(
+                "利息は見越計上済みです: {} {}-{:02}",
+                self.instrument_id, fiscal_year, period
+            )));
+        }
+        self.accrued_entries.insert((fiscal_year, period), entry_id.into());
+        Ok(())
+    }
+
+    /// 償却原価スケジュールを作成
+    ///
+    /// 取得月から満期月の前月までを1か月ずつ償却する。各月の利息は通貨単位で
+    /// 端数処理し、端数の累積は最終月で調整して満期の償却原価を額面に一致させる。
+    pub fn amortization_schedule(&self) -> Vec<AmortizationLine> {
+        let months = month_index(self.maturity_date) - month_index(self.start_date);
+        let coupon_interest = self.round(self.face_value * self.coupon_rate / 12.0);
+        let mut carrying_amount = self.initial_carrying_amount;
+
+        (0..months)
+            .map(|offset| {
+                let index = month_index(self.start_date) + offset;
+                let opening = carrying_amount;
+                let (effective_interest, amortization) = if offset == months - 1 {
+                    let amortization = self.round(self.face_value - opening);
+                    (coupon_interest + amortization, amortization)
+                } else {
+                    let effective_interest = self.round(opening * self.effective_rate / 12.0);
+                    (effective_interest, effective_interest - coupon_interest)
+                };
+                carrying_amount = self.round(opening + amortization);
+
+                AmortizationLine {
+                    fiscal_year: index.div_euclid(12),
+                    period: (index.rem_euclid(12) + 1) as u8,
+                    opening_carrying_amount: opening,
+                    effective_interest,
+                    coupon_interest,
+                    amortization,
+                    closing_carrying_amount: carrying_amount,
+                }
+            })
+            .collect()
+    }
+
+    /// 会計期間の償却額（償却期間外はNone）
+    pub fn schedule_for(&self, fiscal_year: i32, period: u8) -> Option<AmortizationLine> {
+        self.amortization_schedule()
+            .into_iter()
+            .find(|line| line.fiscal_year == fiscal_year && line.period == period)
+    }
+
+    /// 通貨単位で端数処理（円は1円未満、その他の通貨は0.01未満を四捨五入）
+    fn round(&self, amount: f64) -> f64 {
+        let scale = if self.currency == "JPY" { 1.0 } else { 100.0 };
+        (amount * scale).round() / scale
+    }
+}
+
+/// 年月を通算の月数に変換
+fn month_index(date: NaiveDate) -> i32 {
+    date.year() * 12 + date.month0() as i32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bond(
+        initial_carrying_amount: f64,
+        coupon_rate: f64,
+        effective_rate: f64,
+    ) -> FinancialInstrument {
+        FinancialInstrument::new(
+            "BOND-001",
+            "第1回社債",
+            InstrumentKind::Bond,
+            InstrumentPosition::Asset,
+            "1500",
+            "JPY",
+            1_000_000.0,
+            initial_carrying_amount,
+            coupon_rate,
+            effective_rate,
+            NaiveDate::from_ymd_opt(2024, 11, 1).unwrap(),
+            NaiveDate::from_ymd_opt(2025, 11, 1).unwrap(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_discount_bond_amortizes_to_face_value() {
+        let instrument = bond(980_000.0, 0.01, 0.0305);
+        let schedule = instrument.amortization_schedule();
+
+        assert_eq!(schedule.len(), 12);
+        assert_eq!((schedule[0].fiscal_year, schedule[0].period), (2024, 11));
+        assert_eq!((schedule[11].fiscal_year, schedule[11].period), (2025, 10));
+
+        assert_eq!(schedule[0].coupon_interest, 833.0);
+        assert_eq!(schedule[0].effective_interest, 2491.0);
+        assert_eq!(schedule[0].amortization, 1658.0);
+        assert_eq!(schedule[1].opening_carrying_amount, 981_658.0);
+        assert_eq!(schedule[11].closing_carrying_amount, 1_000_000.0);
+
+        let total_amortization: f64 = schedule.iter().map(|line| line.amortization).sum();
+        assert_eq!(total_amortization, 20_000.0);
+    }
+
+    #[test]
+    fn test_premium_bond_amortizes_downward() {
+        let schedule = bond(1_010_000.0, 0.03, 0.02).amortization_schedule();
+        assert!(schedule.iter().all(|line| line.amortization < 0.0));
+        assert_eq!(schedule.last().unwrap().closing_carrying_amount, 1_000_000.0);
+    }
+
+    #[test]
+    fn test_mark_accrued_only_once_within_schedule() {
+        let mut instrument = bond(980_000.0, 0.01, 0.0305);
+
+        instrument.mark_accrued(2024, 12, "entry-1").unwrap();
+        assert_eq!(instrument.accrued_entry_id(2024, 12), Some("entry-1"));
+        assert!(instrument.mark_accrued(2024, 12, "entry-2").is_err());
+        assert!(instrument.mark_accrued(2025, 11, "entry-3").is_err());
+    }
+
+    #[test]
+    fn test_invalid_instrument() {
+        let date = |y, m| NaiveDate::from_ymd_opt(y, m, 1).unwrap();
+        let new = |face: f64, rate: f64, maturity: NaiveDate| {
+            FinancialInstrument::new(
+                "LOAN-001",
+                "借入金",
+                InstrumentKind::Loan,
+                InstrumentPosition::Liability,
+                "2500",
+                "JPY",
+                face,
+                face,
+                rate,
+                rate,
+                date(2024, 4),
+                maturity,
+            )
+        };
+
+        assert!(new(1_000_000.0, 0.02, date(2025, 4)).is_ok());
+        assert!(new(0.0, 0.02, date(2025, 4)).is_err());
+        assert!(new(1_000_000.0, -0.01, date(2025, 4)).is_err());
+        assert!(new(1_000_000.0, 0.02, date(2024, 4)).is_err());
+    }
+}
@@ -0,0 +1,335 @@
+// 棚卸資産の評価（IAS第2号）
+// 取得原価と正味実現可能価額のいずれか低い方で測定し、差額を評価減として計上する
+
+use std::collections::{BTreeMap, HashSet};
+
+use crate::error::{DomainError, DomainResult};
+
+/// 評価減の算定単位
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WriteDownMethod {
+    /// 品目ごとに比較（原則）
+    #[default]
+    ItemByItem,
+    /// 類似品目のグループごとに比較
+    ProductGroup,
+}
+
+impl WriteDownMethod {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WriteDownMethod::ItemByItem => "ItemByItem",
+            WriteDownMethod::ProductGroup => "ProductGroup",
+        }
+    }
+
+    pub fn parse(value: &str) -> DomainResult<Self> {
+        match value {
+            "ItemByItem" => Ok(WriteDownMethod::ItemByItem),
+            "ProductGroup" => Ok(WriteDownMethod::ProductGroup),
+            _ => {
+                Err(DomainError::ValidationError(format!("評価減の算定単位が不正です: {}", value)))
+            }
+        }
+    }
+}
+
+/// 品目別の評価データ
+#[derive(Debug, Clone, PartialEq)]
+pub struct InventoryValuationItem {
+    item_code: String,
+    item_name: String,
+    product_group: String,
+    quantity: f64,
+    unit_cost: f64,
+    unit_net_realizable_value: f64,
+}
+
+impl InventoryValuationItem {
+    pub fn new(
+        item_code: impl Into<String>,
+        item_name: impl Into<String>,
+        product_group: impl Into<String>,
+        quantity: f64,
+        unit_cost: f64,
+        unit_net_realizable_value: f64,
+    ) -> DomainResult<Self> {
+        let item_code = item_code.into();
+        if item_code.trim().is_empty() {
+            return Err(DomainError::ValidationError("品目コードは必須です".to_string()));
+        }
+        if quantity < 0.0 || unit_cost < 0.0 || unit_net_realizable_value < 0.0 {
+            return Err(DomainError::ValidationError(format!(
+                "数量・単価に負の値は指定できません: {}",
+                item_code
+            )));
+        }
+
+        Ok(Self {
+            item_code,
+            item_name: item_name.into(),
+            product_group: product_group.into(),
+            quantity,
+            unit_cost,
+            unit_net_realizable_value,
+        })
+    }
+
+    pub fn item_code(&self) -> &str {
+        &self.item_code
+    }
+
+    pub fn item_name(&self) -> &str {
+        &self.item_name
+    }
+
+    pub fn product_group(&self) -> &str {
+        &self.product_group
+    }
+
+    pub fn quantity(&self) -> f64 {
+        self.quantity
+    }
+
+    pub fn unit_cost(&self) -> f64 {
+        self.unit_cost
+    }
+
+    pub fn unit_net_realizable_value(&self) -> f64 {
+        self.unit_net_realizable_value
+    }
+
+    /// 取得原価
+    pub fn cost(&self) -> f64 {
+        self.quantity * self.unit_cost
+    }
+
+    /// 正味実現可能価額
+    pub fn net_realizable_value(&self) -> f64 {
+        self.quantity * self.unit_net_realizable_value
+    }
+}
+
+/// 評価減の算定結果（算定単位ごと）
+#[derive(Debug, Clone, PartialEq)]
+pub struct WriteDownLine {
+    /// 品目コードまたは品目グループ
+    pub unit: String,
+    pub cost: f64,
+    pub net_realizable_value: f64,
+    pub write_down: f64,
+}
+
+/// 棚卸資産評価ワークシート
+///
+/// 会計期間ごとに1件。評価減の仕訳を起票した後は根拠資料として保持し、
+/// 起票した仕訳IDを記録する。
+#[derive(Debug, Clone, PartialEq)]
+pub struct InventoryWriteDownWorksheet {
+    fiscal_year: i32,
+    period: u8,
+    method: WriteDownMethod,
+    currency: String,
+    items: Vec<InventoryValuationItem>,
+    posted_entry_id: Option<String>,
+}
+
+impl InventoryWriteDownWorksheet {
+    pub fn new(
+        fiscal_year: i32,
+        period: u8,
+        method: WriteDownMethod,
+        currency: impl Into<String>,
+        items: Vec<InventoryValuationItem>,
+    ) -> DomainResult<Self> {
+        if !(1..=12).contains(&period) {
+            return Err(DomainError::InvalidAccountingPeriod);
+        }
+        if items.is_empty() {
+            return Err(DomainError::ValidationError("評価対象の品目がありません".to_string()));
+        }
+        let mut codes = HashSet::new();
+        for item in &items {
+            if !codes.insert(item.item_code()) {
+                return Err(DomainError::ValidationError(format!(
+                    "品目コードが重複しています: {}",
+                    item.item_code()
+                )));
+            }
+        }
+
+        Ok(Self {
+            fiscal_year,
+            period,
+            method,
+            currency: currency.into(),
+            items,
+            posted_entry_id: None,
+        })
+    }
+
+    /// ワークシートID（会計期間で一意）
+    pub fn worksheet_id(&self) -> String {
+        Self::id_for(self.fiscal_year, self.period)
+    }
+
+    pub fn id_for(fiscal_year: i32, period: u8) -> String {
+        format!("INV-WD-{}-{:02}", fiscal_year, period)
+    }
+
+    pub fn fiscal_year(&self) -> i32 {
+        self.fiscal_year
+    }
+
+    pub fn period(&self) -> u8 {
+        self.period
+    }
+
+    pub fn method(&self) -> WriteDownMethod {
+        self.method
+    }
+
+    pub fn currency(&self) -> &str {
+        &self.currency
+    }
+
+    pub fn items(&self) -> &[InventoryValuationItem] {
+        &self.items
+    }
+
+    pub fn posted_entry_id(&self) -> Option<&str> {
+        self.posted_entry_id.as_deref()
+    }
+
+    pub fn is_posted(&self) -> bool {
+        self.posted_entry_id.is_some()
+    }
+
+    /// 評価減の仕訳を起票済みとして記録
+    pub fn mark_posted(&mut self, entry_id: impl Into<String>) -> DomainResult<()> {
+        if self.is_posted() {
+            return Err(DomainError::ValidationError(format!(
+                "評価減の仕訳は起票済みです: {}",
+                self.worksheet_id()
+            )));
+        }
+        self.posted_entry_id = Some(entry_id.into());
+        Ok(())
+    }
+
+    /// 算定単位ごとの評価減を計算
+    ///
+    /// 算定単位ごとに取得原価と正味実現可能価額を比較するため、
+    /// グループ単位では同一グループ内の評価益と評価損が相殺される。
+    pub fn calculate(&self) -> Vec<WriteDownLine> {
+        let mut units: BTreeMap<&str, (f64, f64)> = BTreeMap::new();
+        for item in &self.items {
+            let unit = match self.method {
+                WriteDownMethod::ItemByItem => item.item_code(),
+                WriteDownMethod::ProductGroup => item.product_group(),
+            };
+            let entry = units.entry(unit).or_default();
+            entry.0 += item.cost();
+            entry.1 += item.net_realizable_value();
+        }
+
+        units
+            .into_iter()
+            .map(|(unit, (cost, net_realizable_value))| WriteDownLine {
+                unit: unit.to_string(),
+                cost,
+                net_realizable_value,
+                write_down: (cost - net_realizable_value).max(0.0),
+            })
+            .collect()
+    }
+
+    /// 評価減の合計
+    pub fn total_write_down(&self) -> f64 {
+        self.calculate().iter().map(|line| line.write_down).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(code: &str, group: &str, unit_cost: f64, unit_nrv: f64) -> InventoryValuationItem {
+        InventoryValuationItem::new(code, code, group, 10.0, unit_cost, unit_nrv).unwrap()
+    }
+
+    #[test]
+    fn test_item_by_item_write_down() {
+        let worksheet = InventoryWriteDownWorksheet::new(
+            2024,
+            3,
+            WriteDownMethod::ItemByItem,
+            "JPY",
+            vec![item("A", "G1", 100.0, 80.0), item("B", "G1", 100.0, 130.0)],
+        )
+        .unwrap();
+
+        let lines = worksheet.calculate();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0].write_down, 200.0);
+        assert_eq!(lines[1].write_down, 0.0);
+        assert_eq!(worksheet.total_write_down(), 200.0);
+    }
+
+    #[test]
+    fn test_product_group_offsets_within_group() {
+        let worksheet = InventoryWriteDownWorksheet::new(
+            2024,
+            3,
+            WriteDownMethod::ProductGroup,
+            "JPY",
+            vec![
+                item("A", "G1", 100.0, 80.0),
+                item("B", "G1", 100.0, 130.0),
+                item("C", "G2", 50.0, 40.0),
+            ],
+        )
+        .unwrap();
+
+        let lines = worksheet.calculate();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0].unit, "G1");
+        assert_eq!(lines[0].write_down, 0.0);
+        assert_eq!(lines[1].write_down, 100.0);
+    }
+
+    #[test]
+    fn test_invalid_worksheet() {
+        assert!(InventoryValuationItem::new("A", "A", "G1", -1.0, 100.0, 80.0).is_err());
+        assert!(
+            InventoryWriteDownWorksheet::new(2024, 3, WriteDownMethod::ItemByItem, "JPY", vec![])
+                .is_err()
+        );
+        assert!(
+            InventoryWriteDownWorksheet::new(
+                2024,
+                3,
+                WriteDownMethod::ItemByItem,
+                "JPY",
+                vec![item("A", "G1", 100.0, 80.0), item("A", "G2", 100.0, 80.0)],
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_mark_posted_only_once() {
+        let mut worksheet = InventoryWriteDownWorksheet::new(
+            2024,
+            3,
+            WriteDownMethod::ItemByItem,
+            "JPY",
+            vec![item("A", "G1", 100.0, 80.0)],
+        )
+        .unwrap();
+
+        worksheet.mark_posted("entry-1").unwrap();
+        assert_eq!(worksheet.posted_entry_id(), Some("entry-1"));
+        assert!(worksheet.mark_posted("entry-2").is_err());
+    }
+}
@@ -1,10 +1,14 @@
 // エンティティのエントリーポイント
 
+pub mod entry_link;
 pub mod journal_entry_entity;
 pub mod journal_entry_id;
 pub mod journal_entry_line;
+pub mod line_annotation;
 
 // Re-export entities
+pub use entry_link::{EntryLink, EntryLinkKind, EntryLinkRole};
 pub use journal_entry_entity::*;
 pub use journal_entry_id::JournalEntryId;
 pub use journal_entry_line::JournalEntryLine;
+pub use line_annotation::{AnnotationResolution, LineAnnotation, MAX_ANNOTATION_LENGTH};
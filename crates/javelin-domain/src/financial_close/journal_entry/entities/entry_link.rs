@@ -0,0 +1,280 @@
+// EntryLink - 仕訳間の紐付け
+// 責務: 計上仕訳と決済仕訳、前払仕訳と償却仕訳の対応関係の記録
+//
+// 紐付けは仕訳の状態を変えないため、両方の仕訳のイベントストリームに追記し、
+// 記帳済・締め済の仕訳にも付けられる。
+
+use chrono::{DateTime, Utc};
+
+use crate::{
+    error::{DomainError, DomainResult},
+    financial_close::journal_entry::events::JournalEntryEvent,
+};
+
+/// 紐付けの種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EntryLinkKind {
+    /// 未払・未収の計上と、その決済
+    AccrualSettlement,
+    /// 前払の支出と、その償却
+    PrepaymentAmortization,
+}
+
+impl EntryLinkKind {
+    /// 選択肢の表示順
+    pub const ALL: [EntryLinkKind; 2] =
+        [EntryLinkKind::AccrualSettlement, EntryLinkKind::PrepaymentAmortization];
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            EntryLinkKind::AccrualSettlement => "accrual_settlement",
+            EntryLinkKind::PrepaymentAmortization => "prepayment_amortization",
+        }
+    }
+
+    pub fn from_code(code: &str) -> DomainResult<Self> {
+        Self::ALL.into_iter().find(|kind| kind.code() == code).ok_or_else(|| {
+            DomainError::ValidationError(format!("不明な紐付けの種類です: {}", code))
+        })
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            EntryLinkKind::AccrualSettlement => "計上↔決済",
+            EntryLinkKind::PrepaymentAmortization => "前払↔償却",
+        }
+    }
+
+    /// 紐付けにおける立場の表示名
+    pub fn role_name(&self, role: EntryLinkRole) -> &'static str {
+        match (self, role) {
+            (EntryLinkKind::AccrualSettlement, EntryLinkRole::Source) => "計上",
+            (EntryLinkKind::AccrualSettlement, EntryLinkRole::Target) => "決済",
+            (EntryLinkKind::PrepaymentAmortization, EntryLinkRole::Source) => "前払",
+            (EntryLinkKind::PrepaymentAmortization, EntryLinkRole::Target) => "償却",
+        }
+    }
+}
+
+/// 紐付けにおける仕訳の立場
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EntryLinkRole {
+    /// 計上・前払の仕訳（未決済残高の発生元）
+    Source,
+    /// 決済・償却の仕訳（未決済残高を消し込む側）
+    Target,
+}
+
+impl EntryLinkRole {
+    pub fn code(&self) -> &'static str {
+        match self {
+            EntryLinkRole::Source => "source",
+            EntryLinkRole::Target => "target",
+        }
+    }
+
+    pub fn from_code(code: &str) -> DomainResult<Self> {
+        match code {
+            "source" => Ok(EntryLinkRole::Source),
+            "target" => Ok(EntryLinkRole::Target),
+            _ => Err(DomainError::ValidationError(format!("不明な紐付けの立場です: {}", code))),
+        }
+    }
+
+    /// 相手側の立場
+    pub fn opposite(&self) -> Self {
+        match self {
+            EntryLinkRole::Source => EntryLinkRole::Target,
+            EntryLinkRole::Target => EntryLinkRole::Source,
+        }
+    }
+}
+
+/// 仕訳間の紐付け（ある仕訳から見た有効な紐付け）
+#[derive(Debug, Clone, PartialEq)]
+pub struct EntryLink {
+    link_id: String,
+    entry_id: String,
+    counterpart_id: String,
+    kind: EntryLinkKind,
+    role: EntryLinkRole,
+    amount: f64,
+    linked_by: String,
+    linked_at: DateTime<Utc>,
+}
+
+impl EntryLink {
+    /// 計上・前払の仕訳と決済・償却の仕訳を紐付ける
+    ///
+    /// 返すイベントは (元仕訳に追記するイベント, 相手仕訳に追記するイベント)。
+    pub fn link(
+        link_id: impl Into<String>,
+        source_id: &str,
+        target_id: &str,
+        kind: EntryLinkKind,
+        amount: f64,
+        linked_by: impl Into<String>,
+        linked_at: DateTime<Utc>,
+    ) -> DomainResult<(JournalEntryEvent, JournalEntryEvent)> {
+        if source_id == target_id {
+            return Err(DomainError::ValidationError("同じ仕訳同士は紐付けできません".to_string()));
+        }
+        if !amount.is_finite() || amount <= 0.0 {
+            return Err(DomainError::ValidationError(
+                "紐付け金額は0より大きい値を指定してください".to_string(),
+            ));
+        }
+
+        let link_id = link_id.into();
+        let linked_by = linked_by.into();
+        let event = |entry_id: &str, counterpart_id: &str, role: EntryLinkRole| {
+            JournalEntryEvent::EntryLinked {
+                entry_id: entry_id.to_string(),
+                link_id: link_id.clone(),
+                counterpart_id: counterpart_id.to_string(),
+                kind: kind.code().to_string(),
+                role: role.code().to_string(),
+                amount,
+                linked_by: linked_by.clone(),
+                linked_at,
+            }
+        };
+        Ok((
+            event(source_id, target_id, EntryLinkRole::Source),
+            event(target_id, source_id, EntryLinkRole::Target),
+        ))
+    }
+
+    /// 紐付けを解除する
+    ///
+    /// 返すイベントは (この仕訳に追記するイベント, 相手仕訳に追記するイベント)。
+    pub fn unlink(
+        &self,
+        unlinked_by: impl Into<String>,
+        unlinked_at: DateTime<Utc>,
+    ) -> (JournalEntryEvent, JournalEntryEvent) {
+        let unlinked_by = unlinked_by.into();
+        let event = |entry_id: &str| JournalEntryEvent::EntryUnlinked {
+            entry_id: entry_id.to_string(),
+            link_id: self.link_id.clone(),
+            unlinked_by: unlinked_by.clone(),
+            unlinked_at,
+        };
+        (event(&self.entry_id), event(&self.counterpart_id))
+    }
+
+    /// 仕訳のイベントから有効な紐付けを復元（紐付け順、解除済みは除く）
+    pub fn replay<'a>(events: impl IntoIterator<Item = &'a JournalEntryEvent>) -> Vec<Self> {
+        let mut links: Vec<Self> = Vec::new();
+        for event in events {
+            match event {
+                JournalEntryEvent::EntryLinked {
+                    entry_id,
+                    link_id,
+                    counterpart_id,
+                    kind,
+                    role,
+                    amount,
+                    linked_by,
+                    linked_at,
+                } => {
+                    // 未知の種類・立場の紐付けは読み飛ばす
+                    let (Ok(kind), Ok(role)) =
+                        (EntryLinkKind::from_code(kind), EntryLinkRole::from_code(role))
+                    else {
+                        continue;
+                    };
+                    links.push(Self {
+                        link_id: link_id.clone(),
+                        entry_id: entry_id.clone(),
+                        counterpart_id: counterpart_id.clone(),
+                        kind,
+                        role,
+                        amount: *amount,
+                        linked_by: linked_by.clone(),
+                        linked_at: *linked_at,
+                    });
+                }
+                JournalEntryEvent::EntryUnlinked { link_id, .. } => {
+                    links.retain(|link| link.link_id != *link_id);
+                }
+                _ => {}
+            }
+        }
+        links
+    }
+
+    pub fn link_id(&self) -> &str {
+        &self.link_id
+    }
+
+    pub fn entry_id(&self) -> &str {
+        &self.entry_id
+    }
+
+    pub fn counterpart_id(&self) -> &str {
+        &self.counterpart_id
+    }
+
+    pub fn kind(&self) -> EntryLinkKind {
+        self.kind
+    }
+
+    pub fn role(&self) -> EntryLinkRole {
+        self.role
+    }
+
+    pub fn amount(&self) -> f64 {
+        self.amount
+    }
+
+    pub fn linked_by(&self) -> &str {
+        &self.linked_by
+    }
+
+    pub fn linked_at(&self) -> DateTime<Utc> {
+        self.linked_at
+    }
+
+    /// この仕訳の立場の表示名
+    pub fn role_name(&self) -> &'static str {
+        self.kind.role_name(self.role)
+    }
+
+    /// 相手仕訳の立場の表示名
+    pub fn counterpart_role_name(&self) -> &'static str {
+        self.kind.role_name(self.role.opposite())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_link_and_unlink() {
+        let at = Utc::now();
+        let kind = EntryLinkKind::AccrualSettlement;
+        assert!(EntryLink::link("L-1", "JE001", "JE001", kind, 100.0, "clerk", at).is_err());
+        assert!(EntryLink::link("L-1", "JE001", "JE002", kind, 0.0, "clerk", at).is_err());
+
+        let (source, target) =
+            EntryLink::link("L-1", "JE001", "JE002", kind, 100.0, "clerk", at).unwrap();
+        assert_eq!(source.aggregate_id(), "JE001");
+        assert_eq!(target.aggregate_id(), "JE002");
+
+        let links = EntryLink::replay([&source]);
+        assert_eq!(links.len(), 1);
+        assert_eq!(links[0].counterpart_id(), "JE002");
+        assert_eq!(links[0].role(), EntryLinkRole::Source);
+        assert_eq!(links[0].role_name(), "計上");
+        assert_eq!(links[0].counterpart_role_name(), "決済");
+
+        let target_links = EntryLink::replay([&target]);
+        assert_eq!(target_links[0].role(), EntryLinkRole::Target);
+
+        let (unlinked, counterpart_unlinked) = links[0].unlink("clerk", at);
+        assert_eq!(counterpart_unlinked.aggregate_id(), "JE002");
+        assert!(EntryLink::replay([&source, &unlinked]).is_empty());
+    }
+}
@@ -10,7 +10,7 @@ use crate::{
     financial_close::journal_entry::{
         event_publisher::EventCollector,
         events::{JournalEntryEvent, JournalEntryLineDto},
-        values::{EntryNumber, JournalStatus, TransactionDate, UserId, VoucherNumber},
+        values::{Description, EntryNumber, JournalStatus, TransactionDate, UserId, VoucherNumber},
     },
 };
 
@@ -94,6 +94,8 @@ pub struct JournalEntry {
     transaction_date: TransactionDate,
     voucher_number: VoucherNumber,
     lines: Vec<JournalEntryLine>,
+    /// 伝票の摘要（摘要のない明細に引き継がれる）
+    description: Option<Description>,
     metadata: JournalMetadata,
     audit_trail: AuditTrail,
     event_collector: EventCollector,
@@ -115,6 +117,18 @@ impl JournalEntry {
         voucher_number: VoucherNumber,
         lines: Vec<JournalEntryLine>,
         created_by: UserId,
+    ) -> DomainResult<Self> {
+        Self::new_with_description(id, transaction_date, voucher_number, lines, None, created_by)
+    }
+
+    /// 伝票の摘要を指定して新しい仕訳伝票を作成（Draft状態）
+    pub fn new_with_description(
+        id: JournalEntryId,
+        transaction_date: TransactionDate,
+        voucher_number: VoucherNumber,
+        lines: Vec<JournalEntryLine>,
+        description: Option<Description>,
+        created_by: UserId,
     ) -> DomainResult<Self> {
         let mut entry = Self {
             id: id.clone(),
@@ -123,6 +137,7 @@ impl JournalEntry {
             transaction_date: transaction_date.clone(),
             voucher_number: voucher_number.clone(),
             lines: lines.clone(),
+            description: description.clone(),
             metadata: JournalMetadata::new(created_by.clone()),
             audit_trail: AuditTrail::new(),
             event_collector: EventCollector::new(),
@@ -140,6 +155,7 @@ impl JournalEntry {
             transaction_date: format!("{}", transaction_date.value()),
             voucher_number: voucher_number.value().to_string(),
             lines: lines.iter().map(JournalEntryLineDto::from_entity).collect(),
+            description: description.map(|d| d.value().to_string()),
             created_by: created_by.value().to_string(),
             created_at: Utc::now(),
         };
@@ -178,6 +194,16 @@ impl JournalEntry {
         &self.lines
     }
 
+    /// 伝票の摘要を取得
+    pub fn description(&self) -> Option<&Description> {
+        self.description.as_ref()
+    }
+
+    /// 明細の摘要を取得（明細に摘要がなければ伝票の摘要を引き継ぐ）
+    pub fn line_description<'a>(&'a self, line: &'a JournalEntryLine) -> Option<&'a Description> {
+        line.description().or(self.description.as_ref())
+    }
+
     /// メタデータを取得
     pub fn metadata(&self) -> &JournalMetadata {
         &self.metadata
@@ -518,6 +544,61 @@ mod tests {
         assert!(entry.validate_balance().is_ok());
     }
 
+    #[test]
+    fn test_journal_entry_header_description_inherited_by_lines() {
+        let id = JournalEntryId::new("JE005".to_string());
+        let transaction_date =
+            TransactionDate::new(chrono::NaiveDate::from_ymd_opt(2024, 1, 1).unwrap()).unwrap();
+        let voucher_number = VoucherNumber::new("V005".to_string()).unwrap();
+        let user_id = UserId::new("user1".to_string());
+
+        let overridden = JournalEntryLine::new(
+            LineNumber::new(2).unwrap(),
+            DebitCredit::Credit,
+            AccountCode::new("4000".to_string()).unwrap(),
+            None,
+            None,
+            Amount::new(100000.0, Currency::JPY).unwrap(),
+            TaxType::NonTaxable,
+            Amount::zero(Currency::JPY),
+            Some(Description::new("売上計上".to_string()).unwrap()),
+        )
+        .unwrap();
+        let lines = vec![create_test_line(1, DebitCredit::Debit, "1000", 100000.0), overridden];
+
+        let entry = JournalEntry::new_with_description(
+            id,
+            transaction_date,
+            voucher_number,
+            lines,
+            Some(Description::new("4月分売上".to_string()).unwrap()),
+            user_id,
+        )
+        .unwrap();
+
+        let descriptions: Vec<_> = entry
+            .lines()
+            .iter()
+            .map(|line| entry.line_description(line).map(|d| d.value().to_string()))
+            .collect();
+        assert_eq!(descriptions, vec![Some("4月分売上".to_string()), Some("売上計上".to_string())]);
+
+        match &entry.events()[0] {
+            JournalEntryEvent::DraftCreated { description, lines, .. } => {
+                assert_eq!(description.as_deref(), Some("4月分売上"));
+                assert_eq!(
+                    lines[0].effective_description(description.as_deref()),
+                    Some("4月分売上")
+                );
+                assert_eq!(
+                    lines[1].effective_description(description.as_deref()),
+                    Some("売上計上")
+                );
+            }
+            other => panic!("unexpected event: {:?}", other),
+        }
+    }
+
     #[test]
     fn test_journal_entry_empty_lines() {
         let id = JournalEntryId::new("JE004".to_string());
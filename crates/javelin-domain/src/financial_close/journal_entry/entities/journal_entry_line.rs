@@ -1,7 +1,8 @@
 // 仕訳明細エンティティ
 
 use super::super::values::{
-    Amount, DebitCredit, DepartmentCode, Description, LineNumber, SubAccountCode, TaxType,
+    Amount, DebitCredit, DepartmentCode, Description, LineDimensions, LineNumber, SubAccountCode,
+    TaxType,
 };
 use crate::{error::DomainResult, financial_close::AccountCode, value_object::ValueObject};
 
@@ -21,6 +22,8 @@ pub struct JournalEntryLine {
     sub_account_code: Option<SubAccountCode>,
     /// 部門コード（オプション）
     department_code: Option<DepartmentCode>,
+    /// 分析軸（プロジェクト・セグメント等）
+    dimensions: LineDimensions,
     /// 金額
     amount: Amount,
     /// 税区分
@@ -38,6 +41,7 @@ pub struct JournalEntryLineBuilder {
     account_code: AccountCode,
     sub_account_code: Option<SubAccountCode>,
     department_code: Option<DepartmentCode>,
+    dimensions: LineDimensions,
     amount: Amount,
     tax_type: TaxType,
     tax_amount: Amount,
@@ -58,6 +62,7 @@ impl JournalEntryLineBuilder {
             account_code,
             sub_account_code: None,
             department_code: None,
+            dimensions: LineDimensions::default(),
             amount,
             tax_type: TaxType::NonTaxable,
             tax_amount: Amount::zero(currency),
@@ -75,6 +80,11 @@ impl JournalEntryLineBuilder {
         self
     }
 
+    pub fn dimensions(mut self, dimensions: LineDimensions) -> Self {
+        self.dimensions = dimensions;
+        self
+    }
+
     pub fn tax(mut self, tax_type: TaxType, tax_amount: Amount) -> Self {
         self.tax_type = tax_type;
         self.tax_amount = tax_amount;
@@ -98,6 +108,7 @@ impl JournalEntryLineBuilder {
             self.tax_amount,
             self.description,
         )
+        .map(|line| line.with_dimensions(self.dimensions))
     }
 }
 
@@ -121,6 +132,7 @@ impl JournalEntryLine {
             account_code,
             sub_account_code,
             department_code,
+            dimensions: LineDimensions::default(),
             amount,
             tax_type,
             tax_amount,
@@ -130,6 +142,12 @@ impl JournalEntryLine {
         Ok(line)
     }
 
+    /// 分析軸を設定
+    pub fn with_dimensions(mut self, dimensions: LineDimensions) -> Self {
+        self.dimensions = dimensions;
+        self
+    }
+
     /// ビルダーを作成
     pub fn builder(
         line_number: LineNumber,
@@ -165,6 +183,11 @@ impl JournalEntryLine {
         self.department_code.as_ref()
     }
 
+    /// 分析軸を取得
+    pub fn dimensions(&self) -> &LineDimensions {
+        &self.dimensions
+    }
+
     /// 金額を取得
     pub fn amount(&self) -> &Amount {
         &self.amount
@@ -299,6 +322,22 @@ mod tests {
         assert!(line.department_code().is_some());
     }
 
+    #[test]
+    fn test_journal_entry_line_builder_with_dimensions() {
+        let line = JournalEntryLine::builder(
+            LineNumber::new(1).unwrap(),
+            DebitCredit::Debit,
+            AccountCode::new("6100".to_string()).unwrap(),
+            Amount::new(30000.0, Currency::JPY).unwrap(),
+        )
+        .dimensions(LineDimensions::parse("project=P001, segment=S1").unwrap())
+        .build()
+        .unwrap();
+
+        assert_eq!(line.dimensions().get("project"), Some("P001"));
+        assert_eq!(line.dimensions().get("segment"), Some("S1"));
+    }
+
     #[test]
     fn test_journal_entry_line_currency_mismatch() {
         let line_number = LineNumber::new(1).unwrap();
@@ -378,7 +417,7 @@ mod tests {
 
         // 有効な金額生成戦略（正の値のみ: 0.01以上）
         fn positive_amount_strategy() -> impl Strategy<Value = f64> {
-            (1i64..=1_000_000_00i64).prop_map(|cents| cents as f64 / 100.0)
+            (1i64..=100_000_000_i64).prop_map(|cents| cents as f64 / 100.0)
         }
 
         proptest! {
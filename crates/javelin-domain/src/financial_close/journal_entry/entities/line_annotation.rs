@@ -0,0 +1,197 @@
+// LineAnnotation - 仕訳明細の注記（レビューフラグ）
+// 責務: レビュー担当者が明細に付けた確認事項と、その解決の記録
+//
+// 注記は仕訳の状態を変えないため、仕訳のイベントストリームに追記し、
+// 記帳済・締め済の仕訳にも付けられる。
+
+use chrono::{DateTime, Utc};
+
+use crate::{
+    error::{DomainError, DomainResult},
+    financial_close::journal_entry::events::JournalEntryEvent,
+};
+
+/// 注記・解決コメントの最大文字数
+pub const MAX_ANNOTATION_LENGTH: usize = 200;
+
+/// 注記の解決
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AnnotationResolution {
+    pub resolution: String,
+    pub resolved_by: String,
+    pub resolved_at: DateTime<Utc>,
+}
+
+/// 仕訳明細の注記
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LineAnnotation {
+    annotation_id: String,
+    entry_id: String,
+    line_number: u32,
+    note: String,
+    annotated_by: String,
+    annotated_at: DateTime<Utc>,
+    resolution: Option<AnnotationResolution>,
+}
+
+impl LineAnnotation {
+    /// 明細に注記を付ける（追記するイベントを返す）
+    pub fn annotate(
+        annotation_id: impl Into<String>,
+        entry_id: impl Into<String>,
+        line_number: u32,
+        note: &str,
+        annotated_by: impl Into<String>,
+        annotated_at: DateTime<Utc>,
+    ) -> DomainResult<JournalEntryEvent> {
+        Ok(JournalEntryEvent::LineAnnotated {
+            entry_id: entry_id.into(),
+            annotation_id: annotation_id.into(),
+            line_number,
+            note: validate_text(note, "注記")?,
+            annotated_by: annotated_by.into(),
+            annotated_at,
+        })
+    }
+
+    /// 注記を解決する（追記するイベントを返す）
+    pub fn resolve(
+        &self,
+        resolution: &str,
+        resolved_by: impl Into<String>,
+        resolved_at: DateTime<Utc>,
+    ) -> DomainResult<JournalEntryEvent> {
+        if !self.is_open() {
+            return Err(DomainError::ValidationError(format!(
+                "注記は解決済みです: {}",
+                self.annotation_id
+            )));
+        }
+        Ok(JournalEntryEvent::AnnotationResolved {
+            entry_id: self.entry_id.clone(),
+            annotation_id: self.annotation_id.clone(),
+            resolution: validate_text(resolution, "解決内容")?,
+            resolved_by: resolved_by.into(),
+            resolved_at,
+        })
+    }
+
+    /// 仕訳のイベントから注記の状態を復元（注記順）
+    pub fn replay<'a>(events: impl IntoIterator<Item = &'a JournalEntryEvent>) -> Vec<Self> {
+        let mut annotations: Vec<Self> = Vec::new();
+        for event in events {
+            match event {
+                JournalEntryEvent::LineAnnotated {
+                    entry_id,
+                    annotation_id,
+                    line_number,
+                    note,
+                    annotated_by,
+                    annotated_at,
+                } => annotations.push(Self {
+                    annotation_id: annotation_id.clone(),
+                    entry_id: entry_id.clone(),
+                    line_number: *line_number,
+                    note: note.clone(),
+                    annotated_by: annotated_by.clone(),
+                    annotated_at: *annotated_at,
+                    resolution: None,
+                }),
+                JournalEntryEvent::AnnotationResolved {
+                    annotation_id,
+                    resolution,
+                    resolved_by,
+                    resolved_at,
+                    ..
+                } => {
+                    if let Some(annotation) =
+                        annotations.iter_mut().find(|a| a.annotation_id == *annotation_id)
+                    {
+                        annotation.resolution = Some(AnnotationResolution {
+                            resolution: resolution.clone(),
+                            resolved_by: resolved_by.clone(),
+                            resolved_at: *resolved_at,
+                        });
+                    }
+                }
+                _ => {}
+            }
+        }
+        annotations
+    }
+
+    pub fn annotation_id(&self) -> &str {
+        &self.annotation_id
+    }
+
+    pub fn entry_id(&self) -> &str {
+        &self.entry_id
+    }
+
+    pub fn line_number(&self) -> u32 {
+        self.line_number
+    }
+
+    pub fn note(&self) -> &str {
+        &self.note
+    }
+
+    pub fn annotated_by(&self) -> &str {
+        &self.annotated_by
+    }
+
+    pub fn annotated_at(&self) -> DateTime<Utc> {
+        self.annotated_at
+    }
+
+    pub fn resolution(&self) -> Option<&AnnotationResolution> {
+        self.resolution.as_ref()
+    }
+
+    /// 未解決か
+    pub fn is_open(&self) -> bool {
+        self.resolution.is_none()
+    }
+}
+
+/// 前後の空白を除き、空でなく上限以内であることを確認
+fn validate_text(text: &str, label: &str) -> DomainResult<String> {
+    let text = text.trim();
+    if text.is_empty() {
+        return Err(DomainError::ValidationError(format!("{}を入力してください", label)));
+    }
+    if text.chars().count() > MAX_ANNOTATION_LENGTH {
+        return Err(DomainError::ValidationError(format!(
+            "{}は{}文字以内で入力してください",
+            label, MAX_ANNOTATION_LENGTH
+        )));
+    }
+    Ok(text.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_annotate_and_resolve() {
+        let at = Utc::now();
+        let annotated =
+            LineAnnotation::annotate("N-1", "JE001", 2, " 請求書を確認 ", "reviewer", at).unwrap();
+        assert!(LineAnnotation::annotate("N-2", "JE001", 1, "  ", "reviewer", at).is_err());
+
+        let annotations = LineAnnotation::replay([&annotated]);
+        assert_eq!(annotations.len(), 1);
+        assert_eq!(annotations[0].note(), "請求書を確認");
+        assert_eq!(annotations[0].line_number(), 2);
+        assert!(annotations[0].is_open());
+
+        let resolved = annotations[0].resolve("請求書と一致", "clerk", at).unwrap();
+        let annotations = LineAnnotation::replay([&annotated, &resolved]);
+        assert!(!annotations[0].is_open());
+        assert_eq!(annotations[0].resolution().unwrap().resolved_by, "clerk");
+
+        // 解決済みの注記は再度解決できない
+        assert!(annotations[0].resolve("再確認", "clerk", at).is_err());
+    }
+}
@@ -101,6 +101,7 @@ mod tests {
             transaction_date: "2024-01-01".to_string(),
             voucher_number: "V001".to_string(),
             lines: vec![],
+            description: None,
             created_by: "user1".to_string(),
             created_at: Utc::now(),
         };
@@ -120,6 +121,7 @@ mod tests {
             transaction_date: "2024-01-01".to_string(),
             voucher_number: "V001".to_string(),
             lines: vec![],
+            description: None,
             created_by: "user1".to_string(),
             created_at: Utc::now(),
         };
@@ -149,6 +151,7 @@ mod tests {
             transaction_date: "2024-01-01".to_string(),
             voucher_number: "V001".to_string(),
             lines: vec![],
+            description: None,
             created_by: "user1".to_string(),
             created_at: Utc::now(),
         };
@@ -1,6 +1,8 @@
 // 仕訳伝票ドメインイベント
 // すべての状態変更をイベントとして記録
 
+use std::collections::BTreeMap;
+
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
@@ -22,6 +24,9 @@ pub enum JournalEntryEvent {
         transaction_date: String,
         voucher_number: String,
         lines: Vec<JournalEntryLineDto>,
+        /// 伝票の摘要（摘要のない明細に引き継がれる）
+        #[serde(default)]
+        description: Option<String>,
         created_by: String,
         created_at: DateTime<Utc>,
     },
@@ -34,6 +39,9 @@ pub enum JournalEntryEvent {
         transaction_date: Option<String>,
         voucher_number: Option<String>,
         lines: Option<Vec<JournalEntryLineDto>>,
+        /// 伝票の摘要（Noneの場合は変更なし、空文字の場合は摘要を消去）
+        #[serde(default)]
+        description: Option<String>,
         updated_by: String,
         updated_at: DateTime<Utc>,
     },
@@ -115,6 +123,56 @@ pub enum JournalEntryEvent {
     /// 下書き状態の仕訳伝票が削除された。
     /// Draft状態のみ削除可能。
     Deleted { entry_id: String, deleted_by: String, deleted_at: DateTime<Utc> },
+
+    /// 明細への注記
+    ///
+    /// レビュー担当者が仕訳明細に確認事項の注記（フラグ）を付けた。
+    /// 仕訳の状態は変化しない。
+    LineAnnotated {
+        entry_id: String,
+        annotation_id: String,
+        line_number: u32,
+        note: String,
+        annotated_by: String,
+        annotated_at: DateTime<Utc>,
+    },
+
+    /// 注記の解決
+    ///
+    /// 明細の注記が確認済みとして解決された。
+    AnnotationResolved {
+        entry_id: String,
+        annotation_id: String,
+        resolution: String,
+        resolved_by: String,
+        resolved_at: DateTime<Utc>,
+    },
+
+    /// 仕訳間の紐付け
+    ///
+    /// 計上仕訳と決済仕訳（前払仕訳と償却仕訳）が紐付けられた。
+    /// 両方の仕訳のイベントストリームに、それぞれの立場（role）で追記される。
+    /// 仕訳の状態は変化しない。
+    EntryLinked {
+        entry_id: String,
+        link_id: String,
+        counterpart_id: String,
+        kind: String,
+        role: String,
+        amount: f64,
+        linked_by: String,
+        linked_at: DateTime<Utc>,
+    },
+
+    /// 仕訳間の紐付け解除
+    ///
+    /// 仕訳間の紐付けが解除された。両方の仕訳のイベントストリームに追記される。
+    EntryUnlinked {
+        entry_id: String,
+        link_id: String,
+        unlinked_by: String,
+        unlinked_at: DateTime<Utc>,
+    },
 }
 
 /// 仕訳明細DTO
@@ -125,8 +183,14 @@ pub struct JournalEntryLineDto {
     pub line_number: u32,
     pub side: String, // "Debit" or "Credit"
     pub account_code: String,
+    /// 登録時点の勘定科目名（勘定科目マスタから補完。名称導入前のイベントはNone）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub account_name: Option<String>,
     pub sub_account_code: Option<String>,
     pub department_code: Option<String>,
+    /// 分析軸（種類 → コード）
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub dimensions: BTreeMap<String, String>,
     pub amount: f64,
     pub currency: String,
     pub tax_type: String,
@@ -135,6 +199,29 @@ pub struct JournalEntryLineDto {
 }
 
 impl JournalEntryEvent {
+    /// 仕訳伝票のイベントタイプ（`event_type` の戻り値の一覧）
+    pub const EVENT_TYPES: [&'static str; 14] = [
+        "DraftCreated",
+        "DraftUpdated",
+        "ApprovalRequested",
+        "Rejected",
+        "Posted",
+        "Reversed",
+        "Corrected",
+        "Closed",
+        "Reopened",
+        "Deleted",
+        "LineAnnotated",
+        "AnnotationResolved",
+        "EntryLinked",
+        "EntryUnlinked",
+    ];
+
+    /// 仕訳伝票のイベントタイプか（ペイロードを復元できない場合の判定に使用）
+    pub fn is_event_type(event_type: &str) -> bool {
+        Self::EVENT_TYPES.contains(&event_type)
+    }
+
     /// イベントタイプを取得
     pub fn event_type(&self) -> &str {
         match self {
@@ -148,6 +235,10 @@ impl JournalEntryEvent {
             JournalEntryEvent::Closed { .. } => "Closed",
             JournalEntryEvent::Reopened { .. } => "Reopened",
             JournalEntryEvent::Deleted { .. } => "Deleted",
+            JournalEntryEvent::LineAnnotated { .. } => "LineAnnotated",
+            JournalEntryEvent::AnnotationResolved { .. } => "AnnotationResolved",
+            JournalEntryEvent::EntryLinked { .. } => "EntryLinked",
+            JournalEntryEvent::EntryUnlinked { .. } => "EntryUnlinked",
         }
     }
 
@@ -163,7 +254,11 @@ impl JournalEntryEvent {
             | JournalEntryEvent::Corrected { entry_id, .. }
             | JournalEntryEvent::Closed { entry_id, .. }
             | JournalEntryEvent::Reopened { entry_id, .. }
-            | JournalEntryEvent::Deleted { entry_id, .. } => entry_id,
+            | JournalEntryEvent::Deleted { entry_id, .. }
+            | JournalEntryEvent::LineAnnotated { entry_id, .. }
+            | JournalEntryEvent::AnnotationResolved { entry_id, .. }
+            | JournalEntryEvent::EntryLinked { entry_id, .. }
+            | JournalEntryEvent::EntryUnlinked { entry_id, .. } => entry_id,
         }
     }
 
@@ -180,6 +275,10 @@ impl JournalEntryEvent {
             JournalEntryEvent::Closed { closed_at, .. } => *closed_at,
             JournalEntryEvent::Reopened { reopened_at, .. } => *reopened_at,
             JournalEntryEvent::Deleted { deleted_at, .. } => *deleted_at,
+            JournalEntryEvent::LineAnnotated { annotated_at, .. } => *annotated_at,
+            JournalEntryEvent::AnnotationResolved { resolved_at, .. } => *resolved_at,
+            JournalEntryEvent::EntryLinked { linked_at, .. } => *linked_at,
+            JournalEntryEvent::EntryUnlinked { unlinked_at, .. } => *unlinked_at,
         }
     }
 
@@ -196,6 +295,26 @@ impl JournalEntryEvent {
             JournalEntryEvent::Closed { closed_by, .. } => closed_by,
             JournalEntryEvent::Reopened { reopened_by, .. } => reopened_by,
             JournalEntryEvent::Deleted { deleted_by, .. } => deleted_by,
+            JournalEntryEvent::LineAnnotated { annotated_by, .. } => annotated_by,
+            JournalEntryEvent::AnnotationResolved { resolved_by, .. } => resolved_by,
+            JournalEntryEvent::EntryLinked { linked_by, .. } => linked_by,
+            JournalEntryEvent::EntryUnlinked { unlinked_by, .. } => unlinked_by,
+        }
+    }
+
+    /// 勘定科目名のない明細に名称を補完
+    ///
+    /// `resolve` は勘定科目コードから名称を返す。名称を持つ明細は変更しない。
+    pub fn fill_account_names(&mut self, resolve: impl Fn(&str) -> Option<String>) {
+        let lines = match self {
+            JournalEntryEvent::DraftCreated { lines, .. } => Some(lines),
+            JournalEntryEvent::DraftUpdated { lines, .. } => lines.as_mut(),
+            _ => None,
+        };
+        for line in lines.into_iter().flatten() {
+            if line.account_name.as_deref().is_none_or(str::is_empty) {
+                line.account_name = resolve(&line.account_code);
+            }
         }
     }
 }
@@ -207,8 +326,10 @@ impl JournalEntryLineDto {
             line_number: line.line_number().value(),
             side: line.side().as_str().to_string(),
             account_code: line.account_code().code().to_string(),
+            account_name: None,
             sub_account_code: line.sub_account_code().map(|c| c.value().to_string()),
             department_code: line.department_code().map(|c| c.value().to_string()),
+            dimensions: line.dimensions().as_map().clone(),
             amount: line.amount().value(),
             currency: line.amount().currency().as_str().to_string(),
             tax_type: line.tax_type().as_str().to_string(),
@@ -216,6 +337,13 @@ impl JournalEntryLineDto {
             description: line.description().map(|d| d.value().to_string()),
         }
     }
+
+    /// 伝票の摘要を引き継いだ明細の摘要
+    ///
+    /// 明細に摘要があればそれを優先し、なければ伝票の摘要を使う。
+    pub fn effective_description<'a>(&'a self, header: Option<&'a str>) -> Option<&'a str> {
+        self.description.as_deref().filter(|d| !d.is_empty()).or(header)
+    }
 }
 
 // DomainEventトレイト実装
@@ -246,6 +374,7 @@ mod tests {
             transaction_date: "2024-01-01".to_string(),
             voucher_number: "V001".to_string(),
             lines: vec![],
+            description: None,
             created_by: "user1".to_string(),
             created_at: Utc::now(),
         };
@@ -253,6 +382,48 @@ mod tests {
         assert_eq!(event.event_type(), "DraftCreated");
         assert_eq!(event.aggregate_id(), "JE001");
         assert_eq!(event.actor(), "user1");
+        assert!(JournalEntryEvent::is_event_type(event.event_type()));
+        assert!(!JournalEntryEvent::is_event_type("PeriodLocked"));
+    }
+
+    #[test]
+    fn test_fill_account_names_keeps_existing_names() {
+        let line = |account_code: &str, account_name: Option<&str>| JournalEntryLineDto {
+            line_number: 1,
+            side: "Debit".to_string(),
+            account_code: account_code.to_string(),
+            account_name: account_name.map(str::to_string),
+            sub_account_code: None,
+            department_code: None,
+            dimensions: BTreeMap::new(),
+            amount: 1000.0,
+            currency: "JPY".to_string(),
+            tax_type: "NonTaxable".to_string(),
+            tax_amount: 0.0,
+            description: None,
+        };
+        let mut event = JournalEntryEvent::DraftCreated {
+            entry_id: "JE001".to_string(),
+            transaction_date: "2024-01-01".to_string(),
+            voucher_number: "V001".to_string(),
+            lines: vec![line("1000", None), line("4000", Some("売上")), line("9999", None)],
+            description: None,
+            created_by: "user1".to_string(),
+            created_at: Utc::now(),
+        };
+
+        event.fill_account_names(|code| match code {
+            "1000" => Some("現金".to_string()),
+            "4000" => Some("売上高".to_string()),
+            _ => None,
+        });
+
+        let JournalEntryEvent::DraftCreated { lines, .. } = event else {
+            panic!("DraftCreated expected");
+        };
+        let names: Vec<Option<&str>> =
+            lines.iter().map(|line| line.account_name.as_deref()).collect();
+        assert_eq!(names, vec![Some("現金"), Some("売上"), None]);
     }
 
     #[test]
@@ -304,6 +475,7 @@ mod tests {
             transaction_date: "2024-01-01".to_string(),
             voucher_number: "V005".to_string(),
             lines: vec![],
+            description: None,
             created_by: "user1".to_string(),
             created_at: Utc::now(),
         };
@@ -320,8 +492,10 @@ mod tests {
             line_number: 1,
             side: "Debit".to_string(),
             account_code: "1000".to_string(),
+            account_name: Some("現金".to_string()),
             sub_account_code: None,
             department_code: Some("D001".to_string()),
+            dimensions: BTreeMap::from([("project".to_string(), "P001".to_string())]),
             amount: 100000.0,
             currency: "JPY".to_string(),
             tax_type: "NonTaxable".to_string(),
@@ -333,5 +507,13 @@ mod tests {
         let deserialized: JournalEntryLineDto = serde_json::from_str(&json).unwrap();
 
         assert_eq!(dto, deserialized);
+
+        // 分析軸導入前のイベントは分析軸なしとして読み込む
+        let mut legacy: serde_json::Value = serde_json::from_str(&json).unwrap();
+        legacy.as_object_mut().unwrap().remove("dimensions");
+        legacy.as_object_mut().unwrap().remove("account_name");
+        let legacy: JournalEntryLineDto = serde_json::from_value(legacy).unwrap();
+        assert!(legacy.dimensions.is_empty());
+        assert_eq!(legacy.account_name, None);
     }
 }
@@ -1,8 +1,13 @@
 // 仕訳関連のドメインサービス
 
+use chrono::{DateTime, Utc};
+
 use crate::{
     error::{DomainError, DomainResult},
-    financial_close::journal_entry::{entities::JournalEntryLine, values::EntryNumber},
+    financial_close::journal_entry::{
+        entities::JournalEntryLine,
+        values::{EntryNumber, EntryNumberScope},
+    },
 };
 
 /// 伝票番号存在確認サービス
@@ -21,20 +26,96 @@ pub trait EntryNumberExistenceChecker: Send + Sync {
     async fn exists(&self, entry_number: &EntryNumber) -> DomainResult<bool>;
 }
 
+/// 記帳時の伝票番号生成サービス
+///
+/// 伝票番号（EntryNumber）は会社・会計年度ごとに連番を付与する。
+/// 再起動後も採番済みの番号を再び払い出さないよう、記帳済みの番号を基に続きから採番する。
+///
+/// 払い出した番号は記帳されるまで他の承認に払い出さない。記帳に失敗した場合は
+/// `release` で戻し、欠番を作らない。
+#[allow(async_fn_in_trait)]
+pub trait EntryNumberGenerator: Send + Sync {
+    /// 指定された採番範囲の次の伝票番号を払い出す
+    ///
+    /// # Returns
+    /// 払い出された伝票番号（例: "EN-1000-2024-000001"）
+    async fn next(&self, scope: &EntryNumberScope) -> DomainResult<EntryNumber>;
+
+    /// 払い出した伝票番号を戻す（記帳に失敗した場合に呼ぶ）
+    async fn release(&self, entry_number: &EntryNumber) -> DomainResult<()>;
+}
+
+/// 採番を取り消した伝票番号（監査用）
+#[derive(Debug, Clone, PartialEq)]
+pub struct VoidedVoucherNumber {
+    pub voucher_number: String,
+    pub fiscal_year: u32,
+    /// 取消理由（登録失敗時のエラー内容）
+    pub reason: String,
+    pub voided_at: DateTime<Utc>,
+}
+
+/// 予約した伝票番号の取消結果
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VoucherNumberRelease {
+    /// 最後に予約された番号のため採番前に戻した（欠番にならない）
+    Returned,
+    /// 後続の番号が予約済みのため取消番号として記録した
+    Voided,
+}
+
 /// 伝票番号生成サービス
 ///
 /// 伝票番号（VoucherNumber）は、エンドユーザが理解しやすいように
 /// 年度単位で連番を付与する。これは証憑番号ではなく、伝票コードである。
+///
+/// 採番は二段階で行う。`reserve` で番号を予約し、仕訳の保存に成功したら `confirm`、
+/// 失敗したら `release` する。取り消した番号は欠番の説明として監査で参照する。
 #[allow(async_fn_in_trait)]
 pub trait VoucherNumberGenerator: Send + Sync {
-    /// 指定された会計年度の次の伝票番号を生成する
+    /// 指定された会計年度の次の伝票番号を予約する
     ///
     /// # Arguments
     /// * `fiscal_year` - 会計年度（例: 2024）
     ///
     /// # Returns
-    /// 生成された伝票番号（例: "V-2024-00001"）
-    async fn generate_next(&self, fiscal_year: u32) -> DomainResult<String>;
+    /// 予約された伝票番号（例: "V-2024-00001"）
+    async fn reserve(&self, fiscal_year: u32) -> DomainResult<String>;
+
+    /// 予約した伝票番号を確定する（仕訳の保存後に呼ぶ）
+    async fn confirm(&self, voucher_number: &str) -> DomainResult<()>;
+
+    /// 予約した伝票番号を取り消す（仕訳の保存に失敗した場合に呼ぶ）
+    async fn release(
+        &self,
+        voucher_number: &str,
+        reason: &str,
+    ) -> DomainResult<VoucherNumberRelease>;
+
+    /// 取り消した伝票番号を取得（年度を省略した場合は全年度）
+    async fn voided_numbers(
+        &self,
+        fiscal_year: Option<u32>,
+    ) -> DomainResult<Vec<VoidedVoucherNumber>>;
+
+    /// 処理結果に応じて予約した伝票番号を確定または取消する
+    ///
+    /// 保存に成功した仕訳は取り消せないため、確定自体の失敗は結果に反映しない。
+    async fn settle<T, E>(&self, voucher_number: &str, result: Result<T, E>) -> Result<T, E>
+    where
+        E: std::fmt::Display,
+    {
+        match result {
+            Ok(value) => {
+                let _ = self.confirm(voucher_number).await;
+                Ok(value)
+            }
+            Err(error) => {
+                let _ = self.release(voucher_number, &error.to_string()).await;
+                Err(error)
+            }
+        }
+    }
 }
 
 /// 仕訳ドメインサービス
@@ -117,7 +198,8 @@ impl JournalEntryService {
                 line.tax_type().clone(),
                 line.tax_amount().clone(),
                 line.description().cloned(),
-            )?;
+            )?
+            .with_dimensions(line.dimensions().clone());
 
             reversed_lines.push(reversed_line);
         }
@@ -233,7 +315,7 @@ mod tests {
 
         // 有効な金額生成戦略（小数点以下2桁まで）
         fn valid_amount_strategy() -> impl Strategy<Value = f64> {
-            (0i64..=1_000_000_00i64).prop_map(|cents| cents as f64 / 100.0)
+            (0i64..=100_000_000_i64).prop_map(|cents| cents as f64 / 100.0)
         }
 
         proptest! {
@@ -4,6 +4,7 @@ pub mod accounting;
 pub mod amount;
 pub mod codes;
 pub mod descriptive;
+pub mod dimensions;
 pub mod identifiers;
 pub mod status;
 
@@ -12,5 +13,6 @@ pub use accounting::*;
 pub use amount::*;
 pub use codes::*;
 pub use descriptive::*;
+pub use dimensions::*;
 pub use identifiers::*;
 pub use status::*;
@@ -207,7 +207,7 @@ mod tests {
                 if dc.is_debit() {
                     prop_assert!(!dc.is_credit());
                 } else {
-                    prop_assert!(dc.is_debit() == false);
+                    prop_assert!(!dc.is_debit());
                     prop_assert!(dc.is_credit());
                 }
             }
@@ -295,7 +295,7 @@ mod tests {
 
         // 有効な金額生成戦略（0以上、小数点以下2桁まで）
         fn valid_amount_strategy() -> impl Strategy<Value = f64> {
-            (0i64..=1_000_000_00i64).prop_map(|cents| cents as f64 / 100.0)
+            (0i64..=100_000_000_i64).prop_map(|cents| cents as f64 / 100.0)
         }
 
         proptest! {
@@ -437,7 +437,7 @@ mod tests {
             // プロパティ11: 正の金額は仕訳明細行として常に有効
             #[test]
             fn prop_positive_amount_valid_for_journal_entry(
-                value_cents in 1i64..=1_000_000_00i64,
+                value_cents in 1i64..=100_000_000_i64,
                 currency in currency_strategy()
             ) {
                 let value = value_cents as f64 / 100.0;
@@ -0,0 +1,154 @@
+// 分析軸関連の値オブジェクト
+
+use std::{collections::BTreeMap, fmt};
+
+use crate::{
+    error::{DomainError, DomainResult},
+    value_object::ValueObject,
+};
+
+/// 分析軸: プロジェクト
+pub const DIMENSION_PROJECT: &str = "project";
+
+/// 分析軸: セグメント
+pub const DIMENSION_SEGMENT: &str = "segment";
+
+/// 部門は専用の項目（部門コード）で保持するため、分析軸としては使用できない
+const RESERVED_DIMENSION_KINDS: [&str; 1] = ["department"];
+
+/// 分析軸の種類を検証
+///
+/// 種類は英小文字・数字・`_`・`-` で表す（例: `project`, `segment`, `campaign`）。
+pub fn validate_dimension_kind(kind: &str) -> DomainResult<()> {
+    if kind.is_empty() {
+        return Err(DomainError::ValidationError("分析軸の種類は空にできません".to_string()));
+    }
+    if !kind
+        .chars()
+        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_' || c == '-')
+    {
+        return Err(DomainError::ValidationError(format!(
+            "分析軸の種類は英小文字・数字・_・- で指定してください: {}",
+            kind
+        )));
+    }
+    if RESERVED_DIMENSION_KINDS.contains(&kind) {
+        return Err(DomainError::ValidationError(format!(
+            "{} は専用の項目で指定してください",
+            kind
+        )));
+    }
+    Ok(())
+}
+
+/// 分析軸のコードを検証
+pub fn validate_dimension_code(code: &str) -> DomainResult<()> {
+    if code.is_empty() {
+        return Err(DomainError::ValidationError("分析軸のコードは空にできません".to_string()));
+    }
+    if code.contains(|c: char| c.is_whitespace() || c == ',' || c == '=') {
+        return Err(DomainError::ValidationError(format!(
+            "分析軸のコードに空白・カンマ・= は使用できません: {}",
+            code
+        )));
+    }
+    Ok(())
+}
+
+/// 仕訳明細の分析軸
+///
+/// 部門以外の集計軸（プロジェクト・セグメント・任意の分析タグ）を
+/// 種類ごとに1つのコードで保持する。
+/// 入力・表示では `<種類>=<コード>` をカンマ区切りで表す（例: `project=P001, segment=S1`）。
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LineDimensions(BTreeMap<String, String>);
+
+impl LineDimensions {
+    pub fn new(dimensions: BTreeMap<String, String>) -> DomainResult<Self> {
+        let dimensions = Self(dimensions);
+        dimensions.validate()?;
+        Ok(dimensions)
+    }
+
+    /// `<種類>=<コード>` のカンマ区切り形式を解析
+    pub fn parse(spec: &str) -> DomainResult<Self> {
+        let mut dimensions = BTreeMap::new();
+        for item in spec.split(',').map(str::trim).filter(|item| !item.is_empty()) {
+            let (kind, code) = item.split_once('=').ok_or_else(|| {
+                DomainError::ValidationError(format!(
+                    "分析軸の形式が不正です（<種類>=<コード> で指定してください）: {}",
+                    item
+                ))
+            })?;
+            let kind = kind.trim().to_string();
+            if dimensions.insert(kind.clone(), code.trim().to_string()).is_some() {
+                return Err(DomainError::ValidationError(format!(
+                    "分析軸の種類が重複しています: {}",
+                    kind
+                )));
+            }
+        }
+        Self::new(dimensions)
+    }
+
+    /// 種類のコードを取得
+    pub fn get(&self, kind: &str) -> Option<&str> {
+        self.0.get(kind).map(String::as_str)
+    }
+
+    /// 種類・コードの組（種類の昇順）
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.0.iter().map(|(kind, code)| (kind.as_str(), code.as_str()))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn as_map(&self) -> &BTreeMap<String, String> {
+        &self.0
+    }
+}
+
+impl fmt::Display for LineDimensions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, (kind, code)) in self.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}={}", kind, code)?;
+        }
+        Ok(())
+    }
+}
+
+impl ValueObject for LineDimensions {
+    fn validate(&self) -> DomainResult<()> {
+        for (kind, code) in self.iter() {
+            validate_dimension_kind(kind)?;
+            validate_dimension_code(code)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_line_dimensions() {
+        let dimensions = LineDimensions::parse(" segment=S1 , project=P001 ").unwrap();
+        assert_eq!(dimensions.get(DIMENSION_PROJECT), Some("P001"));
+        assert_eq!(dimensions.get(DIMENSION_SEGMENT), Some("S1"));
+        assert_eq!(dimensions.to_string(), "project=P001, segment=S1");
+        assert_eq!(LineDimensions::parse(&dimensions.to_string()).unwrap(), dimensions);
+
+        assert!(LineDimensions::parse("").unwrap().is_empty());
+        assert!(LineDimensions::parse("project").is_err());
+        assert!(LineDimensions::parse("project=").is_err());
+        assert!(LineDimensions::parse("Project=P001").is_err());
+        assert!(LineDimensions::parse("project=P001,project=P002").is_err());
+        assert!(LineDimensions::parse("department=D01").is_err());
+    }
+}
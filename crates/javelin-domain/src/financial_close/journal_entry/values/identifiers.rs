@@ -26,9 +26,80 @@ impl EntryNumber {
         Ok(entry_number)
     }
 
+    /// 会社・会計年度の範囲内の連番から伝票番号を作成
+    ///
+    /// フォーマット: "EN-{会社コード}-{会計年度}-{6桁連番}"（例: EN-1000-2024-000001）
+    pub fn scoped(scope: &EntryNumberScope, sequence: u64) -> DomainResult<Self> {
+        if sequence == 0 {
+            return Err(DomainError::ValidationError(
+                "伝票番号の連番は1以上で指定してください".to_string(),
+            ));
+        }
+        Self::new(format!(
+            "{}-{}-{}-{:06}",
+            ENTRY_NUMBER_PREFIX, scope.company_code, scope.fiscal_year, sequence
+        ))
+    }
+
     pub fn value(&self) -> &str {
         &self.0
     }
+
+    /// 採番範囲と連番（範囲付きの形式でない場合はNone）
+    pub fn scope(&self) -> Option<(EntryNumberScope, u64)> {
+        let rest = self.0.strip_prefix(ENTRY_NUMBER_PREFIX)?.strip_prefix('-')?;
+        let (rest, sequence) = rest.rsplit_once('-')?;
+        let (company_code, fiscal_year) = rest.rsplit_once('-')?;
+        if fiscal_year.len() != 4 || !sequence.chars().all(|c| c.is_ascii_digit()) {
+            return None;
+        }
+        let scope = EntryNumberScope::new(company_code, fiscal_year.parse().ok()?).ok()?;
+        Some((scope, sequence.parse().ok()?))
+    }
+}
+
+/// 範囲付き伝票番号の接頭辞
+const ENTRY_NUMBER_PREFIX: &str = "EN";
+
+/// 伝票番号の採番範囲（会社・会計年度）
+///
+/// 伝票番号は会社と会計年度の組み合わせごとに一意な連番とする。
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct EntryNumberScope {
+    company_code: String,
+    fiscal_year: u32,
+}
+
+impl EntryNumberScope {
+    pub fn new(company_code: impl Into<String>, fiscal_year: u32) -> DomainResult<Self> {
+        let company_code = company_code.into();
+        if company_code.trim().is_empty() {
+            return Err(DomainError::ValidationError(
+                "伝票番号の採番には会社コードが必要です".to_string(),
+            ));
+        }
+        if company_code.contains(char::is_whitespace) {
+            return Err(DomainError::ValidationError(format!(
+                "会社コードに空白は使用できません: {}",
+                company_code
+            )));
+        }
+        if !(2000..=2100).contains(&fiscal_year) {
+            return Err(DomainError::ValidationError(format!(
+                "会計年度が不正です: {}",
+                fiscal_year
+            )));
+        }
+        Ok(Self { company_code, fiscal_year })
+    }
+
+    pub fn company_code(&self) -> &str {
+        &self.company_code
+    }
+
+    pub fn fiscal_year(&self) -> u32 {
+        self.fiscal_year
+    }
 }
 
 /// 証憑番号
@@ -113,6 +184,23 @@ mod tests {
         assert!(empty.is_err());
     }
 
+    #[test]
+    fn test_scoped_entry_number_round_trip() {
+        let scope = EntryNumberScope::new("1000", 2024).unwrap();
+        let number = EntryNumber::scoped(&scope, 12).unwrap();
+        assert_eq!(number.value(), "EN-1000-2024-000012");
+        assert_eq!(number.scope(), Some((scope, 12)));
+
+        // 会社コードにハイフンを含んでも年度と連番は末尾から分解する
+        let hyphenated = EntryNumberScope::new("JP-01", 2025).unwrap();
+        let number = EntryNumber::scoped(&hyphenated, 1).unwrap();
+        assert_eq!(number.scope().unwrap().0.company_code(), "JP-01");
+
+        assert!(EntryNumber::new("EN-20240401-090000".to_string()).unwrap().scope().is_none());
+        assert!(EntryNumber::scoped(&EntryNumberScope::new("1000", 2024).unwrap(), 0).is_err());
+        assert!(EntryNumberScope::new(" ", 2024).is_err());
+    }
+
     #[test]
     fn test_line_number() {
         let line = LineNumber::new(1);
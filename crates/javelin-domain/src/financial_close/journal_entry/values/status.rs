@@ -86,6 +86,95 @@ impl JournalStatus {
             JournalStatus::Closed => "締め済",
         }
     }
+
+    /// 操作が可能かチェック（不可の場合は理由を返す）
+    pub fn check_action(&self, action: JournalAction) -> Result<(), String> {
+        let required = action.required_status();
+        if *self == required {
+            return Ok(());
+        }
+        Err(format!(
+            "{}の仕訳は{}できません（{}のみ）",
+            self.display_name(),
+            action.display_name(),
+            required.display_name()
+        ))
+    }
+}
+
+/// 仕訳に対する操作
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JournalAction {
+    /// 編集
+    Edit,
+    /// 削除
+    Delete,
+    /// 承認申請
+    SubmitForApproval,
+    /// 承認
+    Approve,
+    /// 差戻し
+    Reject,
+    /// 取消
+    Reverse,
+    /// 締め
+    Close,
+    /// 再オープン
+    Reopen,
+}
+
+impl JournalAction {
+    /// すべての操作（表示順）
+    pub const ALL: [JournalAction; 8] = [
+        JournalAction::Edit,
+        JournalAction::Delete,
+        JournalAction::SubmitForApproval,
+        JournalAction::Approve,
+        JournalAction::Reject,
+        JournalAction::Reverse,
+        JournalAction::Close,
+        JournalAction::Reopen,
+    ];
+
+    /// 操作が可能なステータス
+    fn required_status(&self) -> JournalStatus {
+        match self {
+            JournalAction::Edit | JournalAction::Delete | JournalAction::SubmitForApproval => {
+                JournalStatus::Draft
+            }
+            JournalAction::Approve | JournalAction::Reject => JournalStatus::PendingApproval,
+            JournalAction::Reverse | JournalAction::Close => JournalStatus::Posted,
+            JournalAction::Reopen => JournalStatus::Closed,
+        }
+    }
+
+    /// 文字列に変換
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            JournalAction::Edit => "Edit",
+            JournalAction::Delete => "Delete",
+            JournalAction::SubmitForApproval => "SubmitForApproval",
+            JournalAction::Approve => "Approve",
+            JournalAction::Reject => "Reject",
+            JournalAction::Reverse => "Reverse",
+            JournalAction::Close => "Close",
+            JournalAction::Reopen => "Reopen",
+        }
+    }
+
+    /// 表示名を取得
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            JournalAction::Edit => "編集",
+            JournalAction::Delete => "削除",
+            JournalAction::SubmitForApproval => "承認申請",
+            JournalAction::Approve => "承認",
+            JournalAction::Reject => "差戻し",
+            JournalAction::Reverse => "取消",
+            JournalAction::Close => "締め",
+            JournalAction::Reopen => "再オープン",
+        }
+    }
 }
 
 /// 期間ステータス
@@ -104,6 +193,20 @@ impl PeriodStatus {
     pub fn can_post_journal(&self) -> bool {
         matches!(self, PeriodStatus::Open)
     }
+
+    /// 再オープン可能かチェック（締め済・ロック済のみ）
+    pub fn can_reopen(&self) -> bool {
+        matches!(self, PeriodStatus::Closed | PeriodStatus::Locked)
+    }
+
+    /// 表示名を取得
+    pub fn display_name(&self) -> &str {
+        match self {
+            PeriodStatus::Open => "オープン",
+            PeriodStatus::Closed => "締め済",
+            PeriodStatus::Locked => "ロック済",
+        }
+    }
 }
 
 #[cfg(test)]
@@ -144,6 +247,26 @@ mod tests {
         assert!(!JournalStatus::Closed.is_editable());
     }
 
+    #[test]
+    fn test_check_action() {
+        assert!(JournalStatus::PendingApproval.check_action(JournalAction::Approve).is_ok());
+        assert_eq!(
+            JournalStatus::Draft.check_action(JournalAction::Approve),
+            Err("下書きの仕訳は承認できません（承認待ちのみ）".to_string())
+        );
+        // 操作の可否は状態遷移の規則と一致する
+        assert!(JournalStatus::Draft.check_action(JournalAction::SubmitForApproval).is_ok());
+        assert!(JournalStatus::Draft.can_transition_to(&JournalStatus::PendingApproval));
+        assert!(JournalStatus::Closed.check_action(JournalAction::Reopen).is_ok());
+        assert!(JournalStatus::Closed.check_action(JournalAction::Approve).is_err());
+
+        let reversed = JournalStatus::Reversed {
+            reason: "誤り".to_string(),
+            original_id: "entry-1".to_string(),
+        };
+        assert!(JournalAction::ALL.iter().all(|action| reversed.check_action(*action).is_err()));
+    }
+
     #[test]
     fn test_period_status_can_post() {
         assert!(PeriodStatus::Open.can_post_journal());
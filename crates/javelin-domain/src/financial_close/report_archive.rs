@@ -0,0 +1,350 @@
+// 帳票アーカイブ
+// 確定した帳票を数値・出力条件・ハッシュ値・承認者とともに保存し、
+// 再生成した数値と承認時点の数値を比較できるようにする
+
+use chrono::{DateTime, Utc};
+
+use crate::error::{DomainError, DomainResult};
+
+/// 金額の一致判定の許容誤差
+const AMOUNT_TOLERANCE: f64 = 0.005;
+
+/// 帳票ハッシュ算出サービス
+///
+/// ハッシュ方式はInfrastructure層が決定する。
+pub trait ReportDigester: Send + Sync {
+    /// 署名対象の内容からハッシュ値（16進文字列）を算出
+    fn digest(&self, payload: &str) -> String;
+}
+
+/// 帳票の表示行（表示科目と金額）
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReportLine {
+    pub label: String,
+    pub amount: f64,
+}
+
+impl ReportLine {
+    pub fn new(label: impl Into<String>, amount: f64) -> Self {
+        Self { label: label.into(), amount }
+    }
+}
+
+/// 承認時点と再生成時点の差異（表示科目ごと）
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReportLineDifference {
+    pub label: String,
+    /// 承認時点の金額（承認後に追加された表示科目はNone）
+    pub archived: Option<f64>,
+    /// 再生成した金額（再生成で表示されなくなった表示科目はNone）
+    pub current: Option<f64>,
+}
+
+impl ReportLineDifference {
+    /// 差額（再生成 - 承認時点）
+    pub fn difference(&self) -> f64 {
+        self.current.unwrap_or_default() - self.archived.unwrap_or_default()
+    }
+}
+
+/// アーカイブ済み帳票
+///
+/// 承認時点の内容から算出したハッシュ値を保持する。保存後は変更できず、
+/// 開くたびにハッシュ値を再計算して内容が承認時点から変わっていないことを確認する。
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArchivedReport {
+    archive_id: String,
+    report_type: String,
+    fiscal_year: i32,
+    period: u8,
+    parameters: Vec<(String, String)>,
+    lines: Vec<ReportLine>,
+    content_hash: String,
+    signed_by: String,
+    signed_at: DateTime<Utc>,
+}
+
+impl ArchivedReport {
+    /// 帳票を承認し、ハッシュ値を算出してアーカイブ
+    #[allow(clippy::too_many_arguments)]
+    pub fn sign(
+        report_type: impl Into<String>,
+        fiscal_year: i32,
+        period: u8,
+        parameters: Vec<(String, String)>,
+        lines: Vec<ReportLine>,
+        signed_by: impl Into<String>,
+        signed_at: DateTime<Utc>,
+        digester: &impl ReportDigester,
+    ) -> DomainResult<Self> {
+        let report_type = report_type.into();
+        let signed_by = signed_by.into();
+        if !(1..=12).contains(&period) {
+            return Err(DomainError::InvalidAccountingPeriod);
+        }
+        if report_type.trim().is_empty() {
+            return Err(DomainError::ValidationError("帳票の種類は必須です".to_string()));
+        }
+        if signed_by.trim().is_empty() {
+            return Err(DomainError::ValidationError("承認者は必須です".to_string()));
+        }
+        if lines.is_empty() {
+            return Err(DomainError::ValidationError("帳票に表示行がありません".to_string()));
+        }
+
+        let mut report = Self {
+            archive_id: format!(
+                "{}-{}-{:02}-{}",
+                report_type,
+                fiscal_year,
+                period,
+                signed_at.format("%Y%m%d%H%M%S")
+            ),
+            report_type,
+            fiscal_year,
+            period,
+            parameters,
+            lines,
+            content_hash: String::new(),
+            signed_by,
+            signed_at,
+        };
+        report.content_hash = digester.digest(&report.signing_payload());
+        Ok(report)
+    }
+
+    /// 保存済みの内容から復元（ハッシュ値は再計算しない）
+    #[allow(clippy::too_many_arguments)]
+    pub fn restore(
+        archive_id: String,
+        report_type: String,
+        fiscal_year: i32,
+        period: u8,
+        parameters: Vec<(String, String)>,
+        lines: Vec<ReportLine>,
+        content_hash: String,
+        signed_by: String,
+        signed_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            archive_id,
+            report_type,
+            fiscal_year,
+            period,
+            parameters,
+            lines,
+            content_hash,
+            signed_by,
+            signed_at,
+        }
+    }
+
+    pub fn archive_id(&self) -> &str {
+        &self.archive_id
+    }
+
+    pub fn report_type(&self) -> &str {
+        &self.report_type
+    }
+
+    pub fn fiscal_year(&self) -> i32 {
+        self.fiscal_year
+    }
+
+    pub fn period(&self) -> u8 {
+        self.period
+    }
+
+    pub fn parameters(&self) -> &[(String, String)] {
+        &self.parameters
+    }
+
+    pub fn lines(&self) -> &[ReportLine] {
+        &self.lines
+    }
+
+    pub fn content_hash(&self) -> &str {
+        &self.content_hash
+    }
+
+    pub fn signed_by(&self) -> &str {
+        &self.signed_by
+    }
+
+    pub fn signed_at(&self) -> DateTime<Utc> {
+        self.signed_at
+    }
+
+    /// 署名対象の内容（出力条件・表示行・承認者・承認日時を正規化した文字列）
+    ///
+    /// 金額は小数点以下2桁に揃え、浮動小数点の表現差でハッシュ値が変わらないようにする。
+    pub fn signing_payload(&self) -> String {
+        let mut payload = format!(
+            "report_type={}\nfiscal_year={}\nperiod={}\n",
+            self.report_type, self.fiscal_year, self.period
+        );
+        for (key, value) in &self.parameters {
+            payload.push_str(&format!("param:{}={}\n", key, value));
+        }
+        for line in &self.lines {
+            payload.push_str(&format!("line:{}={:.2}\n", line.label, line.amount));
+        }
+        payload.push_str(&format!(
+            "signed_by={}\nsigned_at={}\n",
+            self.signed_by,
+            self.signed_at.to_rfc3339()
+        ));
+        payload
+    }
+
+    /// 内容が承認時点から変わっていないか
+    pub fn verify(&self, digester: &impl ReportDigester) -> bool {
+        digester.digest(&self.signing_payload()) == self.content_hash
+    }
+
+    /// 再生成した表示行と比較し、金額が異なる表示科目を返す
+    pub fn differences(&self, current: &[ReportLine]) -> Vec<ReportLineDifference> {
+        let mut differences: Vec<ReportLineDifference> = self
+            .lines
+            .iter()
+            .filter_map(|line| {
+                let current_amount = current
+                    .iter()
+                    .find(|candidate| candidate.label == line.label)
+                    .map(|candidate| candidate.amount);
+                match current_amount {
+                    Some(amount) if (amount - line.amount).abs() < AMOUNT_TOLERANCE => None,
+                    _ => Some(ReportLineDifference {
+                        label: line.label.clone(),
+                        archived: Some(line.amount),
+                        current: current_amount,
+                    }),
+                }
+            })
+            .collect();
+
+        differences.extend(
+            current
+                .iter()
+                .filter(|line| !self.lines.iter().any(|archived| archived.label == line.label))
+                .map(|line| ReportLineDifference {
+                    label: line.label.clone(),
+                    archived: None,
+                    current: Some(line.amount),
+                }),
+        );
+        differences
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    /// 内容をそのまま返す検証用のハッシュ算出
+    struct PlainDigester;
+
+    impl ReportDigester for PlainDigester {
+        fn digest(&self, payload: &str) -> String {
+            payload.to_string()
+        }
+    }
+
+    fn signed_report() -> ArchivedReport {
+        ArchivedReport::sign(
+            "FS",
+            2024,
+            3,
+            vec![("status_scope".to_string(), "PostedOnly".to_string())],
+            vec![
+                ReportLine::new("売上収益", 1_000_000.0),
+                ReportLine::new("当期純利益", 120_000.0),
+            ],
+            "accountant",
+            Utc.with_ymd_and_hms(2024, 4, 10, 9, 0, 0).unwrap(),
+            &PlainDigester,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_sign_and_verify() {
+        let report = signed_report();
+
+        assert_eq!(report.archive_id(), "FS-2024-03-20240410090000");
+        assert!(report.content_hash().contains("line:売上収益=1000000.00"));
+        assert!(report.verify(&PlainDigester));
+    }
+
+    #[test]
+    fn test_verify_detects_tampered_lines() {
+        let report = signed_report();
+        let mut lines = report.lines().to_vec();
+        lines[0].amount = 1_100_000.0;
+
+        let tampered = ArchivedReport::restore(
+            report.archive_id().to_string(),
+            report.report_type().to_string(),
+            report.fiscal_year(),
+            report.period(),
+            report.parameters().to_vec(),
+            lines,
+            report.content_hash().to_string(),
+            report.signed_by().to_string(),
+            report.signed_at(),
+        );
+
+        assert!(!tampered.verify(&PlainDigester));
+    }
+
+    #[test]
+    fn test_differences_against_regenerated_lines() {
+        let report = signed_report();
+        let current = vec![
+            ReportLine::new("売上収益", 1_000_000.001),
+            ReportLine::new("当期純利益", 90_000.0),
+            ReportLine::new("その他の包括利益", 5_000.0),
+        ];
+
+        let differences = report.differences(&current);
+
+        assert_eq!(differences.len(), 2);
+        assert_eq!(differences[0].label, "当期純利益");
+        assert_eq!(differences[0].difference(), -30_000.0);
+        assert_eq!(differences[1].archived, None);
+        assert_eq!(differences[1].current, Some(5_000.0));
+    }
+
+    #[test]
+    fn test_sign_requires_signer_and_lines() {
+        let signed_at = Utc::now();
+        assert!(
+            ArchivedReport::sign(
+                "FS",
+                2024,
+                3,
+                vec![],
+                vec![],
+                "accountant",
+                signed_at,
+                &PlainDigester
+            )
+            .is_err()
+        );
+        assert!(
+            ArchivedReport::sign(
+                "FS",
+                2024,
+                3,
+                vec![],
+                vec![ReportLine::new("売上収益", 1.0)],
+                " ",
+                signed_at,
+                &PlainDigester
+            )
+            .is_err()
+        );
+    }
+}
@@ -0,0 +1,296 @@
+// 仕入先請求書台帳
+// 受け取った請求書を登録し、買掛金計上仕訳（下書き）の起票と支払状況を管理する
+
+use chrono::NaiveDate;
+
+use crate::error::{DomainError, DomainResult};
+
+/// 買掛金計上仕訳の貸方に置く勘定科目（買掛金）
+pub const ACCOUNTS_PAYABLE_ACCOUNT_CODE: &str = "2000";
+
+/// 支払状況
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InvoicePaymentStatus {
+    /// 未払
+    Unpaid,
+    /// 一部支払済み
+    PartiallyPaid,
+    /// 支払済み
+    Paid,
+}
+
+impl InvoicePaymentStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            InvoicePaymentStatus::Unpaid => "Unpaid",
+            InvoicePaymentStatus::PartiallyPaid => "PartiallyPaid",
+            InvoicePaymentStatus::Paid => "Paid",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            InvoicePaymentStatus::Unpaid => "未払",
+            InvoicePaymentStatus::PartiallyPaid => "一部支払",
+            InvoicePaymentStatus::Paid => "支払済",
+        }
+    }
+}
+
+/// 支払の記録
+#[derive(Debug, Clone, PartialEq)]
+pub struct InvoicePayment {
+    pub paid_date: NaiveDate,
+    pub amount: f64,
+}
+
+/// 仕入先請求書
+///
+/// 請求書は仕入先と請求書番号で識別する。買掛金計上仕訳は1通につき1件のみ起票し、
+/// 支払は計上後に記録する（支払額の合計は請求額（税込）を超えられない）。
+#[derive(Debug, Clone, PartialEq)]
+pub struct SupplierInvoice {
+    invoice_number: String,
+    /// 仕入先（取引先コード）
+    counterparty: String,
+    invoice_date: NaiveDate,
+    due_date: NaiveDate,
+    /// 借方に置く費用・資産の勘定科目
+    expense_account_code: String,
+    currency: String,
+    /// 税抜金額
+    amount: f64,
+    /// 消費税額
+    tax_amount: f64,
+    /// 買掛金計上仕訳（起票済みの場合）
+    journal_entry_id: Option<String>,
+    payments: Vec<InvoicePayment>,
+}
+
+impl SupplierInvoice {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        invoice_number: impl Into<String>,
+        counterparty: impl Into<String>,
+        invoice_date: NaiveDate,
+        due_date: NaiveDate,
+        expense_account_code: impl Into<String>,
+        currency: impl Into<String>,
+        amount: f64,
+        tax_amount: f64,
+    ) -> DomainResult<Self> {
+        let invoice_number = invoice_number.into();
+        if invoice_number.trim().is_empty() {
+            return Err(DomainError::ValidationError("請求書番号は必須です".to_string()));
+        }
+        let counterparty = counterparty.into();
+        if counterparty.trim().is_empty() {
+            return Err(DomainError::ValidationError(format!(
+                "仕入先は必須です: {}",
+                invoice_number
+            )));
+        }
+        let expense_account_code = expense_account_code.into();
+        if expense_account_code.trim().is_empty() {
+            return Err(DomainError::InvalidAccountCode);
+        }
+        if amount <= 0.0 || tax_amount < 0.0 {
+            return Err(DomainError::ValidationError(format!(
+                "金額は正の値、消費税額は0以上を指定してください: {}",
+                invoice_number
+            )));
+        }
+        if due_date < invoice_date {
+            return Err(DomainError::ValidationError(format!(
+                "支払期日は請求日以降を指定してください: {}",
+                invoice_number
+            )));
+        }
+
+        Ok(Self {
+            invoice_number,
+            counterparty,
+            invoice_date,
+            due_date,
+            expense_account_code,
+            currency: currency.into(),
+            amount,
+            tax_amount,
+            journal_entry_id: None,
+            payments: Vec::new(),
+        })
+    }
+
+    pub fn invoice_number(&self) -> &str {
+        &self.invoice_number
+    }
+
+    pub fn counterparty(&self) -> &str {
+        &self.counterparty
+    }
+
+    pub fn invoice_date(&self) -> NaiveDate {
+        self.invoice_date
+    }
+
+    pub fn due_date(&self) -> NaiveDate {
+        self.due_date
+    }
+
+    pub fn expense_account_code(&self) -> &str {
+        &self.expense_account_code
+    }
+
+    pub fn currency(&self) -> &str {
+        &self.currency
+    }
+
+    pub fn amount(&self) -> f64 {
+        self.amount
+    }
+
+    pub fn tax_amount(&self) -> f64 {
+        self.tax_amount
+    }
+
+    /// 請求額（税込）
+    pub fn total_amount(&self) -> f64 {
+        self.amount + self.tax_amount
+    }
+
+    pub fn journal_entry_id(&self) -> Option<&str> {
+        self.journal_entry_id.as_deref()
+    }
+
+    pub fn payments(&self) -> &[InvoicePayment] {
+        &self.payments
+    }
+
+    pub fn paid_amount(&self) -> f64 {
+        self.payments.iter().map(|payment| payment.amount).sum()
+    }
+
+    /// 未払残高
+    pub fn outstanding_amount(&self) -> f64 {
+        (self.total_amount() - self.paid_amount()).max(0.0)
+    }
+
+    pub fn payment_status(&self) -> InvoicePaymentStatus {
+        if self.payments.is_empty() {
+            InvoicePaymentStatus::Unpaid
+        } else if self.outstanding_amount() > 0.0 {
+            InvoicePaymentStatus::PartiallyPaid
+        } else {
+            InvoicePaymentStatus::Paid
+        }
+    }
+
+    /// 基準日時点で支払期日を過ぎた未払があるか
+    pub fn is_overdue(&self, as_of: NaiveDate) -> bool {
+        self.payment_status() != InvoicePaymentStatus::Paid && as_of > self.due_date
+    }
+
+    /// 買掛金計上仕訳の起票を記録
+    pub fn mark_journalized(&mut self, entry_id: impl Into<String>) -> DomainResult<()> {
+        if self.journal_entry_id.is_some() {
+            return Err(DomainError::ValidationError(format!(
+                "買掛金計上仕訳は起票済みです: {}",
+                self.invoice_number
+            )));
+        }
+        self.journal_entry_id = Some(entry_id.into());
+        Ok(())
+    }
+
+    /// 支払を記録
+    pub fn record_payment(&mut self, paid_date: NaiveDate, amount: f64) -> DomainResult<()> {
+        if self.journal_entry_id.is_none() {
+            return Err(DomainError::ValidationError(format!(
+                "買掛金計上仕訳を起票してから支払を記録してください: {}",
+                self.invoice_number
+            )));
+        }
+        if amount <= 0.0 {
+            return Err(DomainError::ValidationError(format!(
+                "支払額は正の値を指定してください: {}",
+                self.invoice_number
+            )));
+        }
+        if amount > self.outstanding_amount() {
+            return Err(DomainError::ValidationError(format!(
+                "支払額が未払残高（{}）を超えています: {}",
+                self.outstanding_amount(),
+                self.invoice_number
+            )));
+        }
+        if paid_date < self.invoice_date {
+            return Err(DomainError::ValidationError(format!(
+                "支払日は請求日以降を指定してください: {}",
+                self.invoice_number
+            )));
+        }
+        self.payments.push(InvoicePayment { paid_date, amount });
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn invoice() -> SupplierInvoice {
+        SupplierInvoice::new(
+            "INV-001",
+            "X001",
+            date(2024, 4, 5),
+            date(2024, 5, 31),
+            "5100",
+            "JPY",
+            100_000.0,
+            10_000.0,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_payment_status_follows_recorded_payments() {
+        let mut invoice = invoice();
+        assert_eq!(invoice.total_amount(), 110_000.0);
+        assert!(invoice.record_payment(date(2024, 5, 31), 10_000.0).is_err());
+
+        invoice.mark_journalized("entry-1").unwrap();
+        assert!(invoice.mark_journalized("entry-2").is_err());
+        assert_eq!(invoice.payment_status(), InvoicePaymentStatus::Unpaid);
+        assert!(invoice.is_overdue(date(2024, 6, 1)));
+
+        invoice.record_payment(date(2024, 5, 31), 60_000.0).unwrap();
+        assert_eq!(invoice.payment_status(), InvoicePaymentStatus::PartiallyPaid);
+        assert!(invoice.record_payment(date(2024, 6, 10), 60_000.0).is_err());
+        invoice.record_payment(date(2024, 6, 10), 50_000.0).unwrap();
+        assert_eq!(invoice.payment_status(), InvoicePaymentStatus::Paid);
+        assert!(!invoice.is_overdue(date(2024, 6, 30)));
+    }
+
+    #[test]
+    fn test_invalid_invoice() {
+        let new = |amount: f64, due_date: NaiveDate| {
+            SupplierInvoice::new(
+                "INV-002",
+                "X001",
+                date(2024, 4, 5),
+                due_date,
+                "5100",
+                "JPY",
+                amount,
+                0.0,
+            )
+        };
+        assert!(new(1_000.0, date(2024, 4, 5)).is_ok());
+        assert!(new(0.0, date(2024, 4, 30)).is_err());
+        assert!(new(1_000.0, date(2024, 4, 4)).is_err());
+    }
+}
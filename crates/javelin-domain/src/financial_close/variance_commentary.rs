@@ -0,0 +1,108 @@
+// 試算表の増減コメント
+// 試算表のレビューで大きな増減があった科目について、担当者が記入する説明。
+// 科目・会計期間ごとに1つ保持し、財務諸表の別紙に添付する
+
+use crate::error::{DomainError, DomainResult};
+
+/// コメントの最大文字数
+pub const MAX_COMMENTARY_LENGTH: usize = 500;
+
+/// 増減コメント
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VarianceCommentary {
+    account_code: String,
+    fiscal_year: i32,
+    period: u8,
+    commentary: String,
+    written_by: String,
+    /// 記入日時（RFC3339）
+    written_at: String,
+}
+
+impl VarianceCommentary {
+    pub fn new(
+        account_code: impl Into<String>,
+        fiscal_year: i32,
+        period: u8,
+        commentary: impl Into<String>,
+        written_by: impl Into<String>,
+        written_at: impl Into<String>,
+    ) -> DomainResult<Self> {
+        let account_code = account_code.into().trim().to_string();
+        if account_code.is_empty() {
+            return Err(DomainError::InvalidAccountCode);
+        }
+        if !(1..=12).contains(&period) {
+            return Err(DomainError::InvalidAccountingPeriod);
+        }
+        let commentary = commentary.into().trim().to_string();
+        if commentary.is_empty() {
+            return Err(DomainError::ValidationError("増減コメントは必須です".to_string()));
+        }
+        if commentary.chars().count() > MAX_COMMENTARY_LENGTH {
+            return Err(DomainError::ValidationError(format!(
+                "増減コメントは{}文字以内で入力してください",
+                MAX_COMMENTARY_LENGTH
+            )));
+        }
+
+        Ok(Self {
+            account_code,
+            fiscal_year,
+            period,
+            commentary,
+            written_by: written_by.into(),
+            written_at: written_at.into(),
+        })
+    }
+
+    pub fn account_code(&self) -> &str {
+        &self.account_code
+    }
+
+    pub fn fiscal_year(&self) -> i32 {
+        self.fiscal_year
+    }
+
+    pub fn period(&self) -> u8 {
+        self.period
+    }
+
+    pub fn commentary(&self) -> &str {
+        &self.commentary
+    }
+
+    pub fn written_by(&self) -> &str {
+        &self.written_by
+    }
+
+    pub fn written_at(&self) -> &str {
+        &self.written_at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_commentary_is_trimmed_and_validated() {
+        let commentary = VarianceCommentary::new(
+            " 1100 ",
+            2024,
+            3,
+            "  期末の大口入金による増加  ",
+            "alice",
+            "2024-04-05T10:00:00Z",
+        )
+        .unwrap();
+        assert_eq!(commentary.account_code(), "1100");
+        assert_eq!(commentary.commentary(), "期末の大口入金による増加");
+
+        assert!(VarianceCommentary::new("1100", 2024, 3, "   ", "alice", "").is_err());
+        assert!(VarianceCommentary::new("1100", 2024, 13, "増加", "alice", "").is_err());
+        assert!(VarianceCommentary::new("", 2024, 3, "増加", "alice", "").is_err());
+        let too_long = "あ".repeat(MAX_COMMENTARY_LENGTH + 1);
+        assert!(VarianceCommentary::new("1100", 2024, 3, too_long, "alice", "").is_err());
+    }
+}
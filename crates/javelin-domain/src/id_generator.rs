@@ -0,0 +1,48 @@
+// AggregateIdGenerator - 集約IDの採番
+// 責務: 集約（仕訳など）の内部IDの生成方式を一箇所に集約
+//
+// 集約IDはシステム内部の識別子であり、利用者に見せる伝票番号とは別に採番する。
+// 生成順に並ぶIDを用いると、IDをキーとするProjectionで新しい集約が末尾に追記され、
+// 集約単位の走査でも近いキーがまとまって読まれる。
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// 集約IDの生成元
+///
+/// 生成するIDは一意であり、同一プロセス内では生成順に文字列として昇順に並ぶこと。
+pub trait AggregateIdGenerator: Send + Sync {
+    fn next_id(&self) -> String;
+}
+
+/// 接頭辞と連番でIDを生成するAggregateIdGenerator（テスト用）
+#[derive(Debug)]
+pub struct SequentialIdGenerator {
+    prefix: String,
+    next: AtomicU64,
+}
+
+impl SequentialIdGenerator {
+    pub fn new(prefix: impl Into<String>) -> Self {
+        Self { prefix: prefix.into(), next: AtomicU64::new(1) }
+    }
+}
+
+impl AggregateIdGenerator for SequentialIdGenerator {
+    fn next_id(&self) -> String {
+        // 桁数を揃えて文字列の並びを生成順と一致させる
+        format!("{}{:012}", self.prefix, self.next.fetch_add(1, Ordering::Relaxed))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sequential_ids_sort_in_generation_order() {
+        let generator = SequentialIdGenerator::new("JE-");
+        let ids: Vec<String> = (0..12).map(|_| generator.next_id()).collect();
+        assert_eq!(ids[0], "JE-000000000001");
+        assert!(ids.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+}
@@ -0,0 +1,166 @@
+// IntegrityAudit - 整合性監査
+// 責務: Projection整合性・番号連続性・イベントのチェックサムをまとめた監査の結果と実行要否
+//
+// 監査の結果は監査証跡としてイベントストアの専用ストリームに追記する。
+// 定期実行（週次）と締日固定の直前に実行し、最新の結果をホーム画面に表示する。
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::error::{DomainError, DomainResult};
+
+/// 監査の結果を追記するストリーム
+pub const INTEGRITY_AUDIT_STREAM: &str = "integrity-audits";
+
+/// 定期監査の間隔（日）
+pub const INTEGRITY_AUDIT_INTERVAL_DAYS: i64 = 7;
+
+/// 監査の実行契機
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AuditTrigger {
+    /// 定期実行
+    Scheduled,
+    /// 締日固定の直前
+    BeforeLock,
+    /// 利用者による実行
+    Manual,
+}
+
+impl AuditTrigger {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Scheduled => "Scheduled",
+            Self::BeforeLock => "BeforeLock",
+            Self::Manual => "Manual",
+        }
+    }
+
+    pub fn parse(value: &str) -> DomainResult<Self> {
+        match value {
+            "Scheduled" => Ok(Self::Scheduled),
+            "BeforeLock" => Ok(Self::BeforeLock),
+            "Manual" => Ok(Self::Manual),
+            _ => Err(DomainError::ValidationError(format!("不明な監査の実行契機です: {}", value))),
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Scheduled => "定期",
+            Self::BeforeLock => "締日固定前",
+            Self::Manual => "手動",
+        }
+    }
+}
+
+/// 監査項目ごとの結果
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IntegrityAuditCheck {
+    /// 監査項目名（例: Projection整合性）
+    pub name: String,
+    /// 検査した件数の要約（例: 仕訳120件・勘定35科目）
+    pub summary: String,
+    /// 検出事項（空の場合は合格）
+    pub findings: Vec<String>,
+}
+
+impl IntegrityAuditCheck {
+    pub fn passed(&self) -> bool {
+        self.findings.is_empty()
+    }
+}
+
+/// 整合性監査の結果
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IntegrityAuditReport {
+    pub audit_id: String,
+    pub trigger: AuditTrigger,
+    pub requested_by: String,
+    pub started_at: DateTime<Utc>,
+    pub completed_at: DateTime<Utc>,
+    pub checks: Vec<IntegrityAuditCheck>,
+}
+
+impl IntegrityAuditReport {
+    /// すべての監査項目に検出事項がないか
+    pub fn passed(&self) -> bool {
+        self.checks.iter().all(IntegrityAuditCheck::passed)
+    }
+
+    /// 検出事項の件数
+    pub fn finding_count(&self) -> usize {
+        self.checks.iter().map(|check| check.findings.len()).sum()
+    }
+}
+
+/// 監査のイベント
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum IntegrityAuditEvent {
+    /// 監査を完了した
+    AuditCompleted { report: IntegrityAuditReport },
+}
+
+/// 定期監査が必要か
+///
+/// 一度も監査していない場合と、最新の監査から間隔以上経過した場合に必要とする。
+/// 締日固定前・手動の監査も最新の監査として扱う。
+pub fn is_audit_due(latest: Option<&IntegrityAuditReport>, now: DateTime<Utc>) -> bool {
+    latest.is_none_or(|report| {
+        now - report.completed_at >= Duration::days(INTEGRITY_AUDIT_INTERVAL_DAYS)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report(completed_at: DateTime<Utc>, findings: Vec<String>) -> IntegrityAuditReport {
+        IntegrityAuditReport {
+            audit_id: "IA-1".to_string(),
+            trigger: AuditTrigger::Scheduled,
+            requested_by: "system".to_string(),
+            started_at: completed_at,
+            completed_at,
+            checks: vec![
+                IntegrityAuditCheck {
+                    name: "Projection整合性".to_string(),
+                    summary: String::new(),
+                    findings: Vec::new(),
+                },
+                IntegrityAuditCheck {
+                    name: "番号連続性".to_string(),
+                    summary: String::new(),
+                    findings,
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_report_passes_only_without_findings() {
+        let now = Utc::now();
+        assert!(report(now, vec![]).passed());
+
+        let failed = report(now, vec!["欠番 V-2024-00002".to_string()]);
+        assert!(!failed.passed());
+        assert_eq!(failed.finding_count(), 1);
+        assert!(failed.checks[0].passed());
+    }
+
+    #[test]
+    fn test_audit_is_due_weekly() {
+        let now = Utc::now();
+        assert!(is_audit_due(None, now));
+        assert!(!is_audit_due(Some(&report(now - Duration::days(6), vec![])), now));
+        assert!(is_audit_due(Some(&report(now - Duration::days(7), vec![])), now));
+    }
+
+    #[test]
+    fn test_trigger_round_trip() {
+        for trigger in [AuditTrigger::Scheduled, AuditTrigger::BeforeLock, AuditTrigger::Manual] {
+            assert_eq!(AuditTrigger::parse(trigger.as_str()).unwrap(), trigger);
+        }
+        assert!(AuditTrigger::parse("Weekly").is_err());
+    }
+}
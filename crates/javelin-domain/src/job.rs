@@ -0,0 +1,417 @@
+// Job - 長時間処理ジョブ
+// 責務: 元帳集約・Projection再構築・取込などの長時間処理の状態遷移
+//
+// ジョブは状態と進捗を永続化し、アプリケーションが終了しても記録が残る。
+// 再起動時は待機中のジョブを再開し、実行中だったジョブは種別に応じて
+// 待機に戻すか失敗として記録する。
+
+use chrono::{DateTime, Utc};
+
+use crate::error::{DomainError, DomainResult};
+
+/// 再起動時に中断されたジョブへ記録するメッセージ
+pub const INTERRUPTED_MESSAGE: &str = "アプリケーションの終了により中断されました";
+
+/// ジョブの種別
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JobKind {
+    /// 元帳集約
+    LedgerConsolidation,
+    /// Projection再構築
+    ProjectionRebuild,
+    /// 棚卸資産評価データの取込
+    InventoryImport,
+    /// Projectionの不要キー除去と圧縮
+    ProjectionCompaction,
+    /// 整合性監査
+    IntegrityAudit,
+}
+
+impl JobKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::LedgerConsolidation => "LedgerConsolidation",
+            Self::ProjectionRebuild => "ProjectionRebuild",
+            Self::InventoryImport => "InventoryImport",
+            Self::ProjectionCompaction => "ProjectionCompaction",
+            Self::IntegrityAudit => "IntegrityAudit",
+        }
+    }
+
+    pub fn parse(value: &str) -> DomainResult<Self> {
+        match value {
+            "LedgerConsolidation" => Ok(Self::LedgerConsolidation),
+            "ProjectionRebuild" => Ok(Self::ProjectionRebuild),
+            "InventoryImport" => Ok(Self::InventoryImport),
+            "ProjectionCompaction" => Ok(Self::ProjectionCompaction),
+            "IntegrityAudit" => Ok(Self::IntegrityAudit),
+            _ => Err(DomainError::ValidationError(format!("不明なジョブ種別です: {}", value))),
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::LedgerConsolidation => "元帳集約",
+            Self::ProjectionRebuild => "Projection再構築",
+            Self::InventoryImport => "棚卸評価取込",
+            Self::ProjectionCompaction => "Projection圧縮",
+            Self::IntegrityAudit => "整合性監査",
+        }
+    }
+
+    /// 中断後に最初からやり直しても結果が変わらないか
+    ///
+    /// 元帳集約・Projection再構築・Projection圧縮・整合性監査は冪等のため再起動時に待機へ戻す。
+    /// 取込は途中まで反映された可能性があるため失敗として記録し、利用者の再実行を待つ。
+    pub fn is_resumable(&self) -> bool {
+        matches!(
+            self,
+            Self::LedgerConsolidation
+                | Self::ProjectionRebuild
+                | Self::ProjectionCompaction
+                | Self::IntegrityAudit
+        )
+    }
+}
+
+/// ジョブの状態
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JobStatus {
+    Queued,
+    Running,
+    Completed,
+    Failed,
+    Cancelled,
+}
+
+impl JobStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Queued => "Queued",
+            Self::Running => "Running",
+            Self::Completed => "Completed",
+            Self::Failed => "Failed",
+            Self::Cancelled => "Cancelled",
+        }
+    }
+
+    pub fn parse(value: &str) -> DomainResult<Self> {
+        match value {
+            "Queued" => Ok(Self::Queued),
+            "Running" => Ok(Self::Running),
+            "Completed" => Ok(Self::Completed),
+            "Failed" => Ok(Self::Failed),
+            "Cancelled" => Ok(Self::Cancelled),
+            _ => Err(DomainError::ValidationError(format!("不明なジョブ状態です: {}", value))),
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Queued => "待機中",
+            Self::Running => "実行中",
+            Self::Completed => "完了",
+            Self::Failed => "失敗",
+            Self::Cancelled => "取消",
+        }
+    }
+
+    /// 終了済み（完了・失敗・取消）か
+    pub fn is_finished(&self) -> bool {
+        matches!(self, Self::Completed | Self::Failed | Self::Cancelled)
+    }
+}
+
+/// 長時間処理ジョブ
+#[derive(Debug, Clone, PartialEq)]
+pub struct Job {
+    job_id: String,
+    kind: JobKind,
+    parameters: Vec<(String, String)>,
+    status: JobStatus,
+    /// 進捗（0〜100）
+    progress: u8,
+    /// 直近の進捗・結果・失敗理由
+    message: Option<String>,
+    /// 実行回数（再実行のたびに増える）
+    attempts: u32,
+    requested_by: String,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl Job {
+    /// ジョブを登録（待機中）
+    pub fn enqueue(
+        job_id: impl Into<String>,
+        kind: JobKind,
+        parameters: Vec<(String, String)>,
+        requested_by: impl Into<String>,
+        now: DateTime<Utc>,
+    ) -> DomainResult<Self> {
+        let job_id = job_id.into();
+        let requested_by = requested_by.into();
+        if job_id.trim().is_empty() {
+            return Err(DomainError::ValidationError("ジョブIDは必須です".to_string()));
+        }
+        if requested_by.trim().is_empty() {
+            return Err(DomainError::ValidationError("ジョブの依頼者は必須です".to_string()));
+        }
+
+        Ok(Self {
+            job_id,
+            kind,
+            parameters,
+            status: JobStatus::Queued,
+            progress: 0,
+            message: None,
+            attempts: 0,
+            requested_by,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    /// 保存済みの内容から復元
+    #[allow(clippy::too_many_arguments)]
+    pub fn restore(
+        job_id: String,
+        kind: JobKind,
+        parameters: Vec<(String, String)>,
+        status: JobStatus,
+        progress: u8,
+        message: Option<String>,
+        attempts: u32,
+        requested_by: String,
+        created_at: DateTime<Utc>,
+        updated_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            job_id,
+            kind,
+            parameters,
+            status,
+            progress,
+            message,
+            attempts,
+            requested_by,
+            created_at,
+            updated_at,
+        }
+    }
+
+    pub fn job_id(&self) -> &str {
+        &self.job_id
+    }
+
+    pub fn kind(&self) -> JobKind {
+        self.kind
+    }
+
+    pub fn parameters(&self) -> &[(String, String)] {
+        &self.parameters
+    }
+
+    /// パラメータの値を取得
+    pub fn parameter(&self, key: &str) -> Option<&str> {
+        self.parameters.iter().find(|(k, _)| k == key).map(|(_, value)| value.as_str())
+    }
+
+    pub fn status(&self) -> JobStatus {
+        self.status
+    }
+
+    pub fn progress(&self) -> u8 {
+        self.progress
+    }
+
+    pub fn message(&self) -> Option<&str> {
+        self.message.as_deref()
+    }
+
+    pub fn attempts(&self) -> u32 {
+        self.attempts
+    }
+
+    pub fn requested_by(&self) -> &str {
+        &self.requested_by
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    pub fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+
+    /// 実行を開始
+    pub fn start(&mut self, now: DateTime<Utc>) -> DomainResult<()> {
+        if self.status != JobStatus::Queued {
+            return Err(DomainError::InvalidStatusTransition);
+        }
+        self.status = JobStatus::Running;
+        self.progress = 0;
+        self.message = None;
+        self.attempts += 1;
+        self.updated_at = now;
+        Ok(())
+    }
+
+    /// 進捗を記録（100を超える値は100に丸める）
+    pub fn report_progress(
+        &mut self,
+        progress: u8,
+        message: impl Into<String>,
+        now: DateTime<Utc>,
+    ) -> DomainResult<()> {
+        if self.status != JobStatus::Running {
+            return Err(DomainError::InvalidStatusTransition);
+        }
+        self.progress = progress.min(100);
+        self.message = Some(message.into());
+        self.updated_at = now;
+        Ok(())
+    }
+
+    /// 完了
+    pub fn complete(&mut self, message: impl Into<String>, now: DateTime<Utc>) -> DomainResult<()> {
+        if self.status != JobStatus::Running {
+            return Err(DomainError::InvalidStatusTransition);
+        }
+        self.status = JobStatus::Completed;
+        self.progress = 100;
+        self.message = Some(message.into());
+        self.updated_at = now;
+        Ok(())
+    }
+
+    /// 失敗
+    pub fn fail(&mut self, reason: impl Into<String>, now: DateTime<Utc>) -> DomainResult<()> {
+        if self.status != JobStatus::Running {
+            return Err(DomainError::InvalidStatusTransition);
+        }
+        self.status = JobStatus::Failed;
+        self.message = Some(reason.into());
+        self.updated_at = now;
+        Ok(())
+    }
+
+    /// 取消（待機中・実行中のみ）
+    pub fn cancel(&mut self, cancelled_by: &str, now: DateTime<Utc>) -> DomainResult<()> {
+        if self.status.is_finished() {
+            return Err(DomainError::InvalidStatusTransition);
+        }
+        self.status = JobStatus::Cancelled;
+        self.message = Some(format!("{} が取り消しました", cancelled_by));
+        self.updated_at = now;
+        Ok(())
+    }
+
+    /// 再実行（失敗・取消のみ、待機に戻す）
+    pub fn retry(&mut self, now: DateTime<Utc>) -> DomainResult<()> {
+        if !matches!(self.status, JobStatus::Failed | JobStatus::Cancelled) {
+            return Err(DomainError::InvalidStatusTransition);
+        }
+        self.status = JobStatus::Queued;
+        self.progress = 0;
+        self.message = None;
+        self.updated_at = now;
+        Ok(())
+    }
+
+    /// 再起動時の復旧
+    ///
+    /// 実行中のまま残っていたジョブは、再開できる種別なら待機へ戻し、
+    /// そうでなければ失敗として記録する。状態を変更した場合はtrueを返す。
+    pub fn recover_after_restart(&mut self, now: DateTime<Utc>) -> bool {
+        if self.status != JobStatus::Running {
+            return false;
+        }
+        if self.kind.is_resumable() {
+            self.status = JobStatus::Queued;
+            self.progress = 0;
+            self.message = Some(format!("{}。再実行します", INTERRUPTED_MESSAGE));
+        } else {
+            self.status = JobStatus::Failed;
+            self.message = Some(INTERRUPTED_MESSAGE.to_string());
+        }
+        self.updated_at = now;
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn queued(kind: JobKind) -> Job {
+        Job::enqueue(
+            "JOB-1",
+            kind,
+            vec![("fiscal_year".to_string(), "2024".to_string())],
+            "accountant",
+            Utc::now(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_lifecycle_to_completed() {
+        let mut job = queued(JobKind::LedgerConsolidation);
+
+        job.start(Utc::now()).unwrap();
+        job.report_progress(150, "集約中", Utc::now()).unwrap();
+        assert_eq!(job.progress(), 100);
+        job.complete("完了しました", Utc::now()).unwrap();
+
+        assert_eq!(job.status(), JobStatus::Completed);
+        assert_eq!(job.attempts(), 1);
+        assert_eq!(job.parameter("fiscal_year"), Some("2024"));
+        assert!(job.cancel("accountant", Utc::now()).is_err());
+    }
+
+    #[test]
+    fn test_cancel_and_retry() {
+        let mut job = queued(JobKind::InventoryImport);
+
+        job.cancel("accountant", Utc::now()).unwrap();
+        assert_eq!(job.status(), JobStatus::Cancelled);
+        job.retry(Utc::now()).unwrap();
+        assert_eq!(job.status(), JobStatus::Queued);
+        assert!(job.retry(Utc::now()).is_err());
+    }
+
+    #[test]
+    fn test_recover_after_restart_depends_on_kind() {
+        let mut resumable = queued(JobKind::ProjectionRebuild);
+        resumable.start(Utc::now()).unwrap();
+        assert!(resumable.recover_after_restart(Utc::now()));
+        assert_eq!(resumable.status(), JobStatus::Queued);
+
+        let mut import = queued(JobKind::InventoryImport);
+        import.start(Utc::now()).unwrap();
+        assert!(import.recover_after_restart(Utc::now()));
+        assert_eq!(import.status(), JobStatus::Failed);
+        assert_eq!(import.message(), Some(INTERRUPTED_MESSAGE));
+
+        let mut waiting = queued(JobKind::InventoryImport);
+        assert!(!waiting.recover_after_restart(Utc::now()));
+        assert_eq!(waiting.status(), JobStatus::Queued);
+    }
+
+    #[test]
+    fn test_kind_and_status_round_trip() {
+        for kind in [
+            JobKind::LedgerConsolidation,
+            JobKind::ProjectionRebuild,
+            JobKind::InventoryImport,
+            JobKind::ProjectionCompaction,
+            JobKind::IntegrityAudit,
+        ] {
+            assert_eq!(JobKind::parse(kind.as_str()).unwrap(), kind);
+        }
+        assert_eq!(JobStatus::parse("Running").unwrap(), JobStatus::Running);
+        assert!(JobStatus::parse("Unknown").is_err());
+    }
+}
@@ -5,7 +5,11 @@ pub mod entity;
 pub mod error;
 pub mod event;
 pub mod financial_close;
+pub mod id_generator;
+pub mod integrity_audit;
+pub mod job;
 pub mod masters;
 pub mod repositories;
 pub mod service;
+pub mod time_provider;
 pub mod value_object;
@@ -2,17 +2,64 @@
 // 責務: 各種マスタデータの定義
 
 pub mod account_master;
+pub mod accounting_policy;
+pub mod amount_masking;
 pub mod application_settings;
+pub mod calendar_master;
+pub mod chart_of_accounts_template;
+pub mod closing_timetable;
 pub mod company_master;
+pub mod dimension_master;
+pub mod journal_import_template;
+pub mod management_account_mapping;
+pub mod master_change;
+pub mod note_cross_reference;
+pub mod report_delivery;
+pub mod report_parameter_history;
+pub mod statement_line_mapping;
 pub mod subsidiary_account_master;
+pub mod table_preference;
+pub mod user_account;
+pub mod user_action;
+pub mod user_session;
 
 // 公開インターフェース
 pub use account_master::{AccountCode, AccountMaster, AccountName, AccountType};
+pub use accounting_policy::{
+    AccountingPolicy, AccountingPolicyChanged, CurrencyRounding, DEFAULT_POLICY_ADMINISTRATOR,
+    NegativeNumberPresentation, RoundingMode, TaxRounding,
+};
+pub use amount_masking::{AmountMaskingPolicy, AmountMaskingRule, MASKED_AMOUNT};
 pub use application_settings::{
-    ApplicationSettings, BackupRetentionDays, ClosingDay, DateFormat, DecimalPlaces,
+    ApplicationSettings, ApprovalAging, ApprovalSlaSettings, BackupRetentionDays,
+    BatchNotificationSettings, ClosingDay, ClosingLockCheck, ClosingLockValidationSettings,
+    DateFormat, DecimalPlaces, DeletedEntryRetentionSettings, ExportProtectionSettings,
     FiscalYearStartMonth, Language,
 };
+pub use calendar_master::{CalendarMaster, HolidayName};
+pub use chart_of_accounts_template::ChartOfAccountsTemplate;
+pub use closing_timetable::{
+    ClosingStep, ClosingTaskProgress, ClosingTaskStatus, ClosingTimetableItem, DeadlineState,
+};
 pub use company_master::{CompanyCode, CompanyMaster, CompanyName};
+pub use dimension_master::DimensionMaster;
+pub use journal_import_template::{ImportField, JournalImportTemplate};
+pub use management_account_mapping::{ManagementAccount, ManagementAccountMapping};
+pub use master_change::{MasterChange, MasterChangeHistory, MasterChangeKind, MasterRecord};
+pub use note_cross_reference::{NoteCrossReference, NoteReferenceTarget, NoteSection};
+pub use report_delivery::{DEFAULT_SMTP_PORT, ReportDeliverySettings, ReportDestination};
+pub use report_parameter_history::{
+    MAX_RECENT_PARAMETER_SETS, ReportParameterHistory, ReportParameterValues,
+};
+pub use statement_line_mapping::{FinancialStatementKind, StatementLine, StatementLineMapping};
 pub use subsidiary_account_master::{
     SubsidiaryAccountCode, SubsidiaryAccountMaster, SubsidiaryAccountName,
 };
+pub use table_preference::{ColumnPreference, TablePreference, TableSort};
+pub use user_account::{MIN_PASSWORD_LENGTH, PasswordHash, PasswordHasher, UserAccount};
+pub use user_action::{
+    MAX_ACTION_IDENTIFIER_LENGTH, SCREEN_VISIT_ACTION, UserAction, UserActionKind,
+};
+pub use user_session::{
+    FailedLogin, SESSION_LOG_STREAM, SessionClosure, SessionEvent, SessionLog, UserSession,
+};
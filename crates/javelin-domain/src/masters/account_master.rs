@@ -118,6 +118,28 @@ pub enum AccountType {
     Expense,
 }
 
+impl AccountType {
+    /// 全区分（試算表・財務諸表の表示順）
+    pub const ALL: [AccountType; 5] = [
+        AccountType::Asset,
+        AccountType::Liability,
+        AccountType::Equity,
+        AccountType::Revenue,
+        AccountType::Expense,
+    ];
+
+    /// 表示名
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            AccountType::Asset => "資産",
+            AccountType::Liability => "負債",
+            AccountType::Equity => "純資産",
+            AccountType::Revenue => "収益",
+            AccountType::Expense => "費用",
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,673 @@
+// AccountingPolicy - 会計方針設定ドメイン
+// 責務: 端数処理（通貨別・税区分別）・負数表示・金額マスキングの方針、および変更権限の管理
+
+use std::collections::BTreeSet;
+
+use chrono::{DateTime, Utc};
+
+use super::{
+    amount_masking::{AmountMaskingPolicy, AmountMaskingRule},
+    application_settings::DecimalPlaces,
+};
+use crate::{
+    error::{DomainError, DomainResult},
+    financial_close::journal_entry::values::{Currency, TaxType},
+};
+
+/// 金額として保持できる小数点以下桁数の上限（Amountは小数点以下2桁まで）
+const MAX_AMOUNT_DECIMAL_PLACES: u8 = 2;
+
+/// 初期状態の管理者
+pub const DEFAULT_POLICY_ADMINISTRATOR: &str = "system_user";
+
+/// 端数処理方法
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RoundingMode {
+    /// 四捨五入
+    #[default]
+    HalfUp,
+    /// 銀行丸め（偶数丸め）
+    HalfEven,
+    /// 切捨て
+    Truncate,
+    /// 切上げ
+    Ceiling,
+}
+
+impl RoundingMode {
+    /// 全端数処理方法（表示順）
+    pub const ALL: [RoundingMode; 4] = [
+        RoundingMode::HalfUp,
+        RoundingMode::HalfEven,
+        RoundingMode::Truncate,
+        RoundingMode::Ceiling,
+    ];
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            RoundingMode::HalfUp => "HalfUp",
+            RoundingMode::HalfEven => "HalfEven",
+            RoundingMode::Truncate => "Truncate",
+            RoundingMode::Ceiling => "Ceiling",
+        }
+    }
+
+    pub fn from_code(code: &str) -> DomainResult<Self> {
+        Self::ALL.into_iter().find(|mode| mode.code() == code).ok_or_else(|| {
+            DomainError::ValidationError(format!("不明な端数処理方法です: {}", code))
+        })
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            RoundingMode::HalfUp => "四捨五入",
+            RoundingMode::HalfEven => "銀行丸め",
+            RoundingMode::Truncate => "切捨て",
+            RoundingMode::Ceiling => "切上げ",
+        }
+    }
+
+    /// 指定桁数に端数処理する（切捨て・切上げは絶対値に対して行う）
+    pub fn round(&self, value: f64, decimal_places: u8) -> f64 {
+        let factor = 10f64.powi(i32::from(decimal_places));
+        // 2進小数の誤差（例: 1.005 * 100 = 100.49999...）を吸収してから丸める
+        let scaled = (value * factor * 1e6).round() / 1e6;
+        let rounded = match self {
+            RoundingMode::HalfUp => scaled.round(),
+            RoundingMode::HalfEven => scaled.round_ties_even(),
+            RoundingMode::Truncate => scaled.trunc(),
+            RoundingMode::Ceiling => scaled.abs().ceil().copysign(scaled),
+        };
+        rounded / factor
+    }
+}
+
+/// 負数の表示方法
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NegativeNumberPresentation {
+    /// マイナス記号（-1,000）
+    #[default]
+    MinusSign,
+    /// 括弧（(1,000)）
+    Parentheses,
+    /// 三角（△1,000）
+    Triangle,
+}
+
+impl NegativeNumberPresentation {
+    /// 全表示方法（表示順）
+    pub const ALL: [NegativeNumberPresentation; 3] = [
+        NegativeNumberPresentation::MinusSign,
+        NegativeNumberPresentation::Parentheses,
+        NegativeNumberPresentation::Triangle,
+    ];
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            NegativeNumberPresentation::MinusSign => "MinusSign",
+            NegativeNumberPresentation::Parentheses => "Parentheses",
+            NegativeNumberPresentation::Triangle => "Triangle",
+        }
+    }
+
+    pub fn from_code(code: &str) -> DomainResult<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|presentation| presentation.code() == code)
+            .ok_or_else(|| {
+                DomainError::ValidationError(format!("不明な負数表示方法です: {}", code))
+            })
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            NegativeNumberPresentation::MinusSign => "マイナス記号 (-1,000)",
+            NegativeNumberPresentation::Parentheses => "括弧 ((1,000))",
+            NegativeNumberPresentation::Triangle => "三角 (△1,000)",
+        }
+    }
+
+    /// 金額を桁区切り付きで表示用に整形する
+    pub fn format(&self, value: f64, decimal_places: u8) -> String {
+        let formatted = format!("{:.*}", usize::from(decimal_places), value.abs());
+        let (integer, fraction) = match formatted.split_once('.') {
+            Some((integer, fraction)) => (integer, Some(fraction)),
+            None => (formatted.as_str(), None),
+        };
+
+        let mut digits = String::new();
+        for (i, ch) in integer.chars().enumerate() {
+            if i > 0 && (integer.len() - i).is_multiple_of(3) {
+                digits.push(',');
+            }
+            digits.push(ch);
+        }
+        if let Some(fraction) = fraction {
+            digits.push('.');
+            digits.push_str(fraction);
+        }
+
+        // 丸めた結果が0になる場合は符号を付けない
+        let is_negative = value < 0.0 && formatted.chars().any(|c| c.is_ascii_digit() && c != '0');
+        if !is_negative {
+            return digits;
+        }
+        match self {
+            NegativeNumberPresentation::MinusSign => format!("-{}", digits),
+            NegativeNumberPresentation::Parentheses => format!("({})", digits),
+            NegativeNumberPresentation::Triangle => format!("△{}", digits),
+        }
+    }
+}
+
+/// 通貨別の端数処理
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CurrencyRounding {
+    currency: Currency,
+    decimal_places: DecimalPlaces,
+    mode: RoundingMode,
+}
+
+impl CurrencyRounding {
+    pub fn new(
+        currency: Currency,
+        decimal_places: DecimalPlaces,
+        mode: RoundingMode,
+    ) -> DomainResult<Self> {
+        if decimal_places.value() > MAX_AMOUNT_DECIMAL_PLACES {
+            return Err(DomainError::ValidationError(format!(
+                "{}の小数点以下桁数は0〜{}の範囲で指定してください",
+                currency.as_str(),
+                MAX_AMOUNT_DECIMAL_PLACES
+            )));
+        }
+        Ok(Self { currency, decimal_places, mode })
+    }
+
+    pub fn currency(&self) -> &Currency {
+        &self.currency
+    }
+
+    pub fn decimal_places(&self) -> DecimalPlaces {
+        self.decimal_places
+    }
+
+    pub fn mode(&self) -> RoundingMode {
+        self.mode
+    }
+
+    fn describe(&self) -> String {
+        format!("小数点以下{}桁 {}", self.decimal_places.value(), self.mode.display_name())
+    }
+}
+
+/// 税区分別の税額端数処理
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaxRounding {
+    tax_type: TaxType,
+    mode: RoundingMode,
+}
+
+impl TaxRounding {
+    pub fn new(tax_type: TaxType, mode: RoundingMode) -> Self {
+        Self { tax_type, mode }
+    }
+
+    pub fn tax_type(&self) -> &TaxType {
+        &self.tax_type
+    }
+
+    pub fn mode(&self) -> RoundingMode {
+        self.mode
+    }
+}
+
+/// 会計方針の変更イベント（監査証跡）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccountingPolicyChanged {
+    /// 変更した設定項目
+    pub setting: String,
+    /// 変更前の値
+    pub before: String,
+    /// 変更後の値
+    pub after: String,
+    pub changed_by: String,
+    pub changed_at: DateTime<Utc>,
+}
+
+/// 会計方針設定
+///
+/// 通貨別の金額端数処理、税区分別の税額端数処理、負数の表示方法および
+/// 機密科目の金額マスキング方針を保持する。
+/// 変更は管理者のみが行え、変更ごとに監査イベント（AccountingPolicyChanged）を返す。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccountingPolicy {
+    currency_roundings: Vec<CurrencyRounding>,
+    tax_roundings: Vec<TaxRounding>,
+    negative_presentation: NegativeNumberPresentation,
+    administrators: Vec<String>,
+    amount_masking: AmountMaskingPolicy,
+}
+
+impl AccountingPolicy {
+    pub fn new(
+        currency_roundings: Vec<CurrencyRounding>,
+        tax_roundings: Vec<TaxRounding>,
+        negative_presentation: NegativeNumberPresentation,
+        administrators: Vec<String>,
+    ) -> DomainResult<Self> {
+        if administrators.is_empty() {
+            return Err(DomainError::ValidationError(
+                "会計方針の管理者を1名以上設定してください".to_string(),
+            ));
+        }
+        Ok(Self {
+            currency_roundings,
+            tax_roundings,
+            negative_presentation,
+            administrators,
+            amount_masking: AmountMaskingPolicy::default(),
+        })
+    }
+
+    /// 金額マスキング方針を設定（保存済みの方針の復元用）
+    pub fn with_amount_masking(mut self, amount_masking: AmountMaskingPolicy) -> Self {
+        self.amount_masking = amount_masking;
+        self
+    }
+
+    pub fn currency_roundings(&self) -> &[CurrencyRounding] {
+        &self.currency_roundings
+    }
+
+    pub fn tax_roundings(&self) -> &[TaxRounding] {
+        &self.tax_roundings
+    }
+
+    pub fn negative_presentation(&self) -> NegativeNumberPresentation {
+        self.negative_presentation
+    }
+
+    pub fn administrators(&self) -> &[String] {
+        &self.administrators
+    }
+
+    pub fn amount_masking(&self) -> &AmountMaskingPolicy {
+        &self.amount_masking
+    }
+
+    pub fn is_administrator(&self, user_id: &str) -> bool {
+        self.administrators.iter().any(|admin| admin == user_id)
+    }
+
+    /// 通貨の端数処理（未設定の通貨は小数点以下2桁・四捨五入）
+    pub fn currency_rounding(&self, currency: &Currency) -> CurrencyRounding {
+        self.currency_roundings
+            .iter()
+            .find(|r| &r.currency == currency)
+            .cloned()
+            .unwrap_or(CurrencyRounding {
+                currency: currency.clone(),
+                decimal_places: DecimalPlaces::new(MAX_AMOUNT_DECIMAL_PLACES)
+                    .expect("within range"),
+                mode: RoundingMode::HalfUp,
+            })
+    }
+
+    /// 税区分の税額端数処理（未設定の税区分は切捨て）
+    pub fn tax_rounding_mode(&self, tax_type: &TaxType) -> RoundingMode {
+        self.tax_roundings
+            .iter()
+            .find(|r| &r.tax_type == tax_type)
+            .map_or(RoundingMode::Truncate, |r| r.mode)
+    }
+
+    /// 金額を通貨の端数処理に従って丸める
+    pub fn round_amount(&self, currency: &Currency, value: f64) -> f64 {
+        let rounding = self.currency_rounding(currency);
+        rounding.mode.round(value, rounding.decimal_places.value())
+    }
+
+    /// 税額を税区分の端数処理に従って通貨の桁数へ丸める
+    pub fn round_tax(&self, tax_type: &TaxType, currency: &Currency, value: f64) -> f64 {
+        let decimal_places = self.currency_rounding(currency).decimal_places.value();
+        self.tax_rounding_mode(tax_type).round(value, decimal_places)
+    }
+
+    /// 金額を通貨の桁数と負数表示方法に従って整形する
+    pub fn format_amount(&self, currency: &Currency, value: f64) -> String {
+        let rounding = self.currency_rounding(currency);
+        let decimal_places = rounding.decimal_places.value();
+        self.negative_presentation
+            .format(rounding.mode.round(value, decimal_places), decimal_places)
+    }
+
+    /// 通貨の端数処理を変更
+    pub fn change_currency_rounding(
+        &mut self,
+        changed_by: &str,
+        rounding: CurrencyRounding,
+    ) -> DomainResult<Option<AccountingPolicyChanged>> {
+        self.ensure_administrator(changed_by)?;
+
+        let before = self.currency_rounding(&rounding.currency);
+        if before == rounding {
+            return Ok(None);
+        }
+        let change = self.record_change(
+            changed_by,
+            format!("通貨端数処理 {}", rounding.currency.as_str()),
+            before.describe(),
+            rounding.describe(),
+        );
+
+        match self.currency_roundings.iter_mut().find(|r| r.currency == rounding.currency) {
+            Some(existing) => *existing = rounding,
+            None => self.currency_roundings.push(rounding),
+        }
+        Ok(Some(change))
+    }
+
+    /// 税区分の税額端数処理を変更
+    pub fn change_tax_rounding(
+        &mut self,
+        changed_by: &str,
+        rounding: TaxRounding,
+    ) -> DomainResult<Option<AccountingPolicyChanged>> {
+        self.ensure_administrator(changed_by)?;
+
+        let before = self.tax_rounding_mode(&rounding.tax_type);
+        if before == rounding.mode {
+            return Ok(None);
+        }
+        let change = self.record_change(
+            changed_by,
+            format!("税額端数処理 {}", rounding.tax_type.display_name()),
+            before.display_name().to_string(),
+            rounding.mode.display_name().to_string(),
+        );
+
+        match self.tax_roundings.iter_mut().find(|r| r.tax_type == rounding.tax_type) {
+            Some(existing) => *existing = rounding,
+            None => self.tax_roundings.push(rounding),
+        }
+        Ok(Some(change))
+    }
+
+    /// 負数の表示方法を変更
+    pub fn change_negative_presentation(
+        &mut self,
+        changed_by: &str,
+        presentation: NegativeNumberPresentation,
+    ) -> DomainResult<Option<AccountingPolicyChanged>> {
+        self.ensure_administrator(changed_by)?;
+
+        if self.negative_presentation == presentation {
+            return Ok(None);
+        }
+        let change = self.record_change(
+            changed_by,
+            "負数表示".to_string(),
+            self.negative_presentation.display_name().to_string(),
+            presentation.display_name().to_string(),
+        );
+        self.negative_presentation = presentation;
+        Ok(Some(change))
+    }
+
+    /// 金額マスキング規則を置き換える
+    pub fn change_amount_masking_rules(
+        &mut self,
+        changed_by: &str,
+        rules: Vec<AmountMaskingRule>,
+    ) -> DomainResult<Option<AccountingPolicyChanged>> {
+        self.ensure_administrator(changed_by)?;
+
+        if self.amount_masking.rules() == rules.as_slice() {
+            return Ok(None);
+        }
+        let before = self.amount_masking.describe_rules();
+        self.amount_masking.set_rules(rules);
+        Ok(Some(self.record_change(
+            changed_by,
+            "金額マスキング規則".to_string(),
+            before,
+            self.amount_masking.describe_rules(),
+        )))
+    }
+
+    /// ユーザのロールを置き換える（空の場合は割当を削除）
+    pub fn change_user_roles(
+        &mut self,
+        changed_by: &str,
+        user_id: &str,
+        roles: BTreeSet<String>,
+    ) -> DomainResult<Option<AccountingPolicyChanged>> {
+        self.ensure_administrator(changed_by)?;
+
+        let before = self.amount_masking.roles_of(user_id);
+        if before == roles {
+            return Ok(None);
+        }
+        let describe =
+            |roles: &BTreeSet<String>| roles.iter().cloned().collect::<Vec<_>>().join(",");
+        let change = self.record_change(
+            changed_by,
+            format!("ロール {}", user_id),
+            describe(&before),
+            describe(&roles),
+        );
+        self.amount_masking.assign_roles(user_id, roles);
+        Ok(Some(change))
+    }
+
+    /// 管理者を追加（初期セットアップで作成した管理者ユーザの登録など）
+    pub fn grant_administrator(
+        &mut self,
+        changed_by: &str,
+        user_id: &str,
+    ) -> DomainResult<Option<AccountingPolicyChanged>> {
+        self.ensure_administrator(changed_by)?;
+
+        if user_id.trim().is_empty() {
+            return Err(DomainError::ValidationError(
+                "管理者に追加するユーザを指定してください".to_string(),
+            ));
+        }
+        if self.is_administrator(user_id) {
+            return Ok(None);
+        }
+        let before = self.administrators.join(",");
+        self.administrators.push(user_id.to_string());
+        Ok(Some(self.record_change(
+            changed_by,
+            "管理者".to_string(),
+            before,
+            self.administrators.join(","),
+        )))
+    }
+
+    fn ensure_administrator(&self, user_id: &str) -> DomainResult<()> {
+        if self.is_administrator(user_id) {
+            Ok(())
+        } else {
+            Err(DomainError::PermissionDenied(format!(
+                "会計方針の変更は管理者のみ可能です（ユーザ: {}）",
+                user_id
+            )))
+        }
+    }
+
+    fn record_change(
+        &self,
+        changed_by: &str,
+        setting: String,
+        before: String,
+        after: String,
+    ) -> AccountingPolicyChanged {
+        AccountingPolicyChanged {
+            setting,
+            before,
+            after,
+            changed_by: changed_by.to_string(),
+            changed_at: Utc::now(),
+        }
+    }
+}
+
+impl Default for AccountingPolicy {
+    /// 円は整数・四捨五入、外貨は小数点以下2桁・四捨五入、税額は切捨て、負数はマイナス記号
+    fn default() -> Self {
+        let currency_rounding = |currency: Currency, decimal_places: u8| CurrencyRounding {
+            currency,
+            decimal_places: DecimalPlaces::new(decimal_places).expect("within range"),
+            mode: RoundingMode::HalfUp,
+        };
+
+        Self {
+            currency_roundings: vec![
+                currency_rounding(Currency::JPY, 0),
+                currency_rounding(Currency::USD, 2),
+                currency_rounding(Currency::EUR, 2),
+            ],
+            tax_roundings: [
+                TaxType::Taxable,
+                TaxType::NonTaxable,
+                TaxType::TaxExempt,
+                TaxType::OutOfScope,
+            ]
+            .into_iter()
+            .map(|tax_type| TaxRounding::new(tax_type, RoundingMode::Truncate))
+            .collect(),
+            negative_presentation: NegativeNumberPresentation::MinusSign,
+            administrators: vec![DEFAULT_POLICY_ADMINISTRATOR.to_string()],
+            amount_masking: AmountMaskingPolicy::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rounding_modes() {
+        assert_eq!(RoundingMode::HalfUp.round(2.5, 0), 3.0);
+        assert_eq!(RoundingMode::HalfUp.round(-2.5, 0), -3.0);
+        assert_eq!(RoundingMode::HalfUp.round(1.005, 2), 1.01);
+        assert_eq!(RoundingMode::HalfEven.round(2.5, 0), 2.0);
+        assert_eq!(RoundingMode::HalfEven.round(3.5, 0), 4.0);
+        assert_eq!(RoundingMode::Truncate.round(99.99, 0), 99.0);
+        assert_eq!(RoundingMode::Truncate.round(-99.99, 0), -99.0);
+        assert_eq!(RoundingMode::Ceiling.round(10.01, 0), 11.0);
+        assert_eq!(RoundingMode::Ceiling.round(-10.01, 0), -11.0);
+        for mode in RoundingMode::ALL {
+            assert_eq!(RoundingMode::from_code(mode.code()).unwrap(), mode);
+        }
+    }
+
+    #[test]
+    fn test_negative_presentation_format() {
+        assert_eq!(NegativeNumberPresentation::MinusSign.format(-1234567.0, 0), "-1,234,567");
+        assert_eq!(NegativeNumberPresentation::Parentheses.format(-1234.5, 2), "(1,234.50)");
+        assert_eq!(NegativeNumberPresentation::Triangle.format(-1000.0, 0), "△1,000");
+        assert_eq!(NegativeNumberPresentation::Triangle.format(1000.0, 0), "1,000");
+        assert_eq!(NegativeNumberPresentation::Parentheses.format(-0.001, 0), "0");
+    }
+
+    #[test]
+    fn test_policy_applies_currency_and_tax_rounding() {
+        let policy = AccountingPolicy::default();
+        assert_eq!(policy.round_amount(&Currency::JPY, 1000.5), 1001.0);
+        assert_eq!(policy.round_amount(&Currency::USD, 10.005), 10.01);
+        assert_eq!(policy.round_tax(&TaxType::Taxable, &Currency::JPY, 909.9), 909.0);
+        assert_eq!(policy.format_amount(&Currency::JPY, -1500.4), "-1,500");
+    }
+
+    #[test]
+    fn test_only_administrators_can_change_policy() {
+        let mut policy = AccountingPolicy::default();
+
+        let denied =
+            policy.change_negative_presentation("clerk", NegativeNumberPresentation::Triangle);
+        assert!(matches!(denied, Err(DomainError::PermissionDenied(_))));
+        assert_eq!(policy.negative_presentation(), NegativeNumberPresentation::MinusSign);
+
+        let change = policy
+            .change_tax_rounding(
+                DEFAULT_POLICY_ADMINISTRATOR,
+                TaxRounding::new(TaxType::Taxable, RoundingMode::HalfUp),
+            )
+            .unwrap()
+            .unwrap();
+        assert_eq!(change.setting, "税額端数処理 課税");
+        assert_eq!(change.before, "切捨て");
+        assert_eq!(change.after, "四捨五入");
+        assert_eq!(policy.tax_rounding_mode(&TaxType::Taxable), RoundingMode::HalfUp);
+
+        // 変更がない場合はイベントを記録しない
+        let unchanged = policy
+            .change_tax_rounding(
+                DEFAULT_POLICY_ADMINISTRATOR,
+                TaxRounding::new(TaxType::Taxable, RoundingMode::HalfUp),
+            )
+            .unwrap();
+        assert!(unchanged.is_none());
+    }
+
+    #[test]
+    fn test_grant_administrator() {
+        let mut policy = AccountingPolicy::default();
+
+        assert!(matches!(
+            policy.grant_administrator("clerk", "clerk"),
+            Err(DomainError::PermissionDenied(_))
+        ));
+
+        let change = policy
+            .grant_administrator(DEFAULT_POLICY_ADMINISTRATOR, "tanaka")
+            .unwrap()
+            .unwrap();
+        assert_eq!(change.after, format!("{},tanaka", DEFAULT_POLICY_ADMINISTRATOR));
+        assert!(policy.is_administrator("tanaka"));
+        assert!(policy.grant_administrator("tanaka", "tanaka").unwrap().is_none());
+    }
+
+    #[test]
+    fn test_amount_masking_changes_require_administrator() {
+        let mut policy = AccountingPolicy::default();
+        let rules = AmountMaskingPolicy::parse_rules("5100-5199:payroll").unwrap();
+
+        let denied = policy.change_amount_masking_rules("clerk", rules.clone());
+        assert!(matches!(denied, Err(DomainError::PermissionDenied(_))));
+
+        let change = policy
+            .change_amount_masking_rules(DEFAULT_POLICY_ADMINISTRATOR, rules)
+            .unwrap()
+            .unwrap();
+        assert_eq!(change.before, "");
+        assert_eq!(change.after, "5100-5199:payroll");
+        assert!(policy.amount_masking().is_masked("5100", "clerk"));
+
+        let change = policy
+            .change_user_roles(
+                DEFAULT_POLICY_ADMINISTRATOR,
+                "clerk",
+                BTreeSet::from(["payroll".to_string()]),
+            )
+            .unwrap()
+            .unwrap();
+        assert_eq!(change.setting, "ロール clerk");
+        assert!(!policy.amount_masking().is_masked("5100", "clerk"));
+    }
+
+    #[test]
+    fn test_currency_rounding_rejects_excess_decimal_places() {
+        let result = CurrencyRounding::new(
+            Currency::JPY,
+            DecimalPlaces::new(3).unwrap(),
+            RoundingMode::HalfUp,
+        );
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,240 @@
+// AmountMasking - 機密科目の金額マスキング方針
+// 責務: 科目コード範囲ごとの金額参照ロールと、ユーザへのロール割当の管理
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt,
+};
+
+use crate::{
+    error::{DomainError, DomainResult},
+    value_object::ValueObject,
+};
+
+/// マスキングされた金額の表示
+pub const MASKED_AMOUNT: &str = "***";
+
+/// 金額マスキング規則
+///
+/// 科目コード範囲（両端を含む）の金額を、参照を許可したロールを持たないユーザから隠す。
+/// 設定画面では `<開始科目>[-<終了科目>]:<ロール>[,<ロール>...]` の形式で表す
+/// （例: `5100-5199:payroll,hr`）。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AmountMaskingRule {
+    account_from: String,
+    account_to: String,
+    authorized_roles: BTreeSet<String>,
+}
+
+impl AmountMaskingRule {
+    pub fn new(
+        account_from: impl Into<String>,
+        account_to: impl Into<String>,
+        authorized_roles: impl IntoIterator<Item = impl Into<String>>,
+    ) -> DomainResult<Self> {
+        let rule = Self {
+            account_from: account_from.into(),
+            account_to: account_to.into(),
+            authorized_roles: authorized_roles.into_iter().map(Into::into).collect(),
+        };
+        rule.validate()?;
+        Ok(rule)
+    }
+
+    /// `<開始科目>[-<終了科目>]:<ロール>[,<ロール>...]` 形式の規則を解析
+    pub fn parse(spec: &str) -> DomainResult<Self> {
+        let (range, roles) = spec.trim().split_once(':').ok_or_else(|| {
+            invalid(format!(
+                "マスキング規則の形式が不正です（<科目範囲>:<ロール> で指定してください）: {}",
+                spec
+            ))
+        })?;
+        let (from, to) = range.split_once('-').unwrap_or((range, range));
+        Self::new(
+            from.trim(),
+            to.trim(),
+            roles.split(',').map(str::trim).filter(|role| !role.is_empty()),
+        )
+    }
+
+    pub fn account_from(&self) -> &str {
+        &self.account_from
+    }
+
+    pub fn account_to(&self) -> &str {
+        &self.account_to
+    }
+
+    pub fn authorized_roles(&self) -> &BTreeSet<String> {
+        &self.authorized_roles
+    }
+
+    /// 科目コードが範囲に含まれるか
+    pub fn covers(&self, account_code: &str) -> bool {
+        self.account_from.as_str() <= account_code && account_code <= self.account_to.as_str()
+    }
+
+    /// いずれかのロールが金額の参照を許可されているか
+    pub fn authorizes(&self, roles: &BTreeSet<String>) -> bool {
+        !self.authorized_roles.is_disjoint(roles)
+    }
+
+    /// 設定画面での表記
+    pub fn to_spec(&self) -> String {
+        let roles = self.authorized_roles.iter().cloned().collect::<Vec<_>>().join(",");
+        if self.account_from == self.account_to {
+            format!("{}:{}", self.account_from, roles)
+        } else {
+            format!("{}-{}:{}", self.account_from, self.account_to, roles)
+        }
+    }
+}
+
+impl fmt::Display for AmountMaskingRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_spec())
+    }
+}
+
+impl ValueObject for AmountMaskingRule {
+    fn validate(&self) -> DomainResult<()> {
+        if self.account_from.is_empty() || self.account_to.is_empty() {
+            return Err(invalid("マスキング対象の科目コードを指定してください"));
+        }
+        if self.account_from > self.account_to {
+            return Err(invalid(format!(
+                "マスキング対象の科目範囲が不正です: {}-{}",
+                self.account_from, self.account_to
+            )));
+        }
+        if self.authorized_roles.is_empty() {
+            return Err(invalid(format!(
+                "金額の参照を許可するロールを指定してください: {}-{}",
+                self.account_from, self.account_to
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// 金額マスキング方針
+///
+/// マスキング規則とユーザへのロール割当を保持する。
+/// 規則の範囲に含まれる科目の金額は、許可されたロールを持たないユーザにはマスキングして表示する。
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AmountMaskingPolicy {
+    rules: Vec<AmountMaskingRule>,
+    user_roles: BTreeMap<String, BTreeSet<String>>,
+}
+
+impl AmountMaskingPolicy {
+    pub fn new(
+        rules: Vec<AmountMaskingRule>,
+        user_roles: BTreeMap<String, BTreeSet<String>>,
+    ) -> Self {
+        let mut policy = Self { rules, user_roles: BTreeMap::new() };
+        for (user_id, roles) in user_roles {
+            policy.assign_roles(user_id, roles);
+        }
+        policy
+    }
+
+    /// 空白区切りの規則一覧を解析
+    pub fn parse_rules(specs: &str) -> DomainResult<Vec<AmountMaskingRule>> {
+        specs.split_whitespace().map(AmountMaskingRule::parse).collect()
+    }
+
+    pub fn rules(&self) -> &[AmountMaskingRule] {
+        &self.rules
+    }
+
+    pub fn user_roles(&self) -> &BTreeMap<String, BTreeSet<String>> {
+        &self.user_roles
+    }
+
+    /// ユーザに割り当てられたロール
+    pub fn roles_of(&self, user_id: &str) -> BTreeSet<String> {
+        self.user_roles.get(user_id).cloned().unwrap_or_default()
+    }
+
+    pub fn set_rules(&mut self, rules: Vec<AmountMaskingRule>) {
+        self.rules = rules;
+    }
+
+    /// ユーザのロールを置き換える（空の場合は割当を削除）
+    pub fn assign_roles(&mut self, user_id: impl Into<String>, roles: BTreeSet<String>) {
+        let user_id = user_id.into();
+        if roles.is_empty() {
+            self.user_roles.remove(&user_id);
+        } else {
+            self.user_roles.insert(user_id, roles);
+        }
+    }
+
+    /// ユーザに対して科目の金額をマスキングするか
+    pub fn is_masked(&self, account_code: &str, user_id: &str) -> bool {
+        let roles = self.roles_of(user_id);
+        self.rules
+            .iter()
+            .any(|rule| rule.covers(account_code) && !rule.authorizes(&roles))
+    }
+
+    /// ユーザに対してマスキングする規則
+    pub fn masked_rules_for(&self, user_id: &str) -> Vec<&AmountMaskingRule> {
+        let roles = self.roles_of(user_id);
+        self.rules.iter().filter(|rule| !rule.authorizes(&roles)).collect()
+    }
+
+    /// 規則一覧の表記（空白区切り）
+    pub fn describe_rules(&self) -> String {
+        self.rules.iter().map(AmountMaskingRule::to_spec).collect::<Vec<_>>().join(" ")
+    }
+}
+
+fn invalid(message: impl Into<String>) -> DomainError {
+    DomainError::ValidationError(message.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn roles(names: &[&str]) -> BTreeSet<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_rules() {
+        let rule = AmountMaskingRule::parse("5100-5199:payroll, hr").unwrap();
+        assert_eq!(rule.account_from(), "5100");
+        assert_eq!(rule.account_to(), "5199");
+        assert_eq!(rule.to_spec(), "5100-5199:hr,payroll");
+        assert_eq!(AmountMaskingRule::parse(&rule.to_spec()).unwrap(), rule);
+        assert_eq!(AmountMaskingRule::parse("2150:payroll").unwrap().to_spec(), "2150:payroll");
+
+        assert!(AmountMaskingRule::parse("5100-5199").is_err());
+        assert!(AmountMaskingRule::parse("5199-5100:payroll").is_err());
+        assert!(AmountMaskingRule::parse("5100-5199:").is_err());
+        assert!(AmountMaskingPolicy::parse_rules("5100-5199:payroll 2150:payroll").is_ok());
+    }
+
+    #[test]
+    fn test_masks_accounts_for_users_without_role() {
+        let mut policy = AmountMaskingPolicy::new(
+            AmountMaskingPolicy::parse_rules("5100-5199:payroll").unwrap(),
+            BTreeMap::new(),
+        );
+        policy.assign_roles("hr_manager", roles(&["payroll"]));
+
+        assert!(policy.is_masked("5110", "clerk"));
+        assert!(!policy.is_masked("5200", "clerk"));
+        assert!(!policy.is_masked("5110", "hr_manager"));
+        assert_eq!(policy.masked_rules_for("clerk").len(), 1);
+        assert!(policy.masked_rules_for("hr_manager").is_empty());
+
+        // ロールを外すとマスキング対象になる
+        policy.assign_roles("hr_manager", BTreeSet::new());
+        assert!(policy.is_masked("5110", "hr_manager"));
+        assert!(policy.user_roles().is_empty());
+    }
+}
@@ -1,7 +1,17 @@
 // ApplicationSettings - アプリケーション設定マスタ
 
-use super::company_master::CompanyCode;
-use crate::{error::DomainResult, value_object::ValueObject};
+use std::collections::BTreeSet;
+
+use chrono::{DateTime, Months, Utc};
+
+use super::{
+    account_master::AccountCode, company_master::CompanyCode,
+    report_delivery::ReportDeliverySettings,
+};
+use crate::{
+    error::DomainResult, financial_close::journal_entry::values::Currency,
+    value_object::ValueObject,
+};
 
 /// アプリケーション設定マスタ
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -11,15 +21,26 @@ pub struct ApplicationSettings {
     language: Language,
     decimal_places: DecimalPlaces,
     date_format: DateFormat,
+    batch_notification: BatchNotificationSettings,
 
     // システム設定
     fiscal_year_start_month: FiscalYearStartMonth,
+    base_currency: Currency,
     closing_day: ClosingDay,
     auto_backup_enabled: bool,
     backup_retention_days: BackupRetentionDays,
+    approval_sla: ApprovalSlaSettings,
+    report_delivery: ReportDeliverySettings,
+    export_protection: ExportProtectionSettings,
+    deleted_entry_retention: DeletedEntryRetentionSettings,
+    closing_lock_validation: ClosingLockValidationSettings,
+    translation_adjustment_account: AccountCode,
 }
 
 impl ApplicationSettings {
+    /// 為替換算調整勘定の既定の勘定科目コード
+    pub const DEFAULT_TRANSLATION_ADJUSTMENT_ACCOUNT: &'static str = "3900";
+
     #[allow(clippy::too_many_arguments)]
     pub fn new(
         default_company_code: Option<CompanyCode>,
@@ -36,10 +57,21 @@ impl ApplicationSettings {
             language,
             decimal_places,
             date_format,
+            batch_notification: BatchNotificationSettings::default(),
             fiscal_year_start_month,
+            base_currency: Currency::JPY,
             closing_day,
             auto_backup_enabled,
             backup_retention_days,
+            approval_sla: ApprovalSlaSettings::default(),
+            report_delivery: ReportDeliverySettings::default(),
+            export_protection: ExportProtectionSettings::default(),
+            deleted_entry_retention: DeletedEntryRetentionSettings::default(),
+            closing_lock_validation: ClosingLockValidationSettings::default(),
+            translation_adjustment_account: AccountCode::new(
+                Self::DEFAULT_TRANSLATION_ADJUSTMENT_ACCOUNT,
+            )
+            .expect("default code is not empty"),
         }
     }
 
@@ -60,11 +92,20 @@ impl ApplicationSettings {
         &self.date_format
     }
 
+    pub fn batch_notification(&self) -> BatchNotificationSettings {
+        self.batch_notification
+    }
+
     // システム設定のゲッター
     pub fn fiscal_year_start_month(&self) -> &FiscalYearStartMonth {
         &self.fiscal_year_start_month
     }
 
+    /// 基準通貨（帳簿の記帳通貨）
+    pub fn base_currency(&self) -> &Currency {
+        &self.base_currency
+    }
+
     pub fn closing_day(&self) -> &ClosingDay {
         &self.closing_day
     }
@@ -77,6 +118,31 @@ impl ApplicationSettings {
         &self.backup_retention_days
     }
 
+    pub fn approval_sla(&self) -> ApprovalSlaSettings {
+        self.approval_sla
+    }
+
+    pub fn report_delivery(&self) -> &ReportDeliverySettings {
+        &self.report_delivery
+    }
+
+    pub fn export_protection(&self) -> ExportProtectionSettings {
+        self.export_protection
+    }
+
+    pub fn deleted_entry_retention(&self) -> DeletedEntryRetentionSettings {
+        self.deleted_entry_retention
+    }
+
+    pub fn closing_lock_validation(&self) -> &ClosingLockValidationSettings {
+        &self.closing_lock_validation
+    }
+
+    /// 外貨建試算表の換算差額を計上する勘定科目
+    pub fn translation_adjustment_account(&self) -> &AccountCode {
+        &self.translation_adjustment_account
+    }
+
     // セッター
     pub fn update_default_company_code(&mut self, company_code: Option<CompanyCode>) {
         self.default_company_code = company_code;
@@ -94,10 +160,18 @@ impl ApplicationSettings {
         self.date_format = date_format;
     }
 
+    pub fn update_batch_notification(&mut self, batch_notification: BatchNotificationSettings) {
+        self.batch_notification = batch_notification;
+    }
+
     pub fn update_fiscal_year_start_month(&mut self, month: FiscalYearStartMonth) {
         self.fiscal_year_start_month = month;
     }
 
+    pub fn update_base_currency(&mut self, currency: Currency) {
+        self.base_currency = currency;
+    }
+
     pub fn update_closing_day(&mut self, day: ClosingDay) {
         self.closing_day = day;
     }
@@ -110,6 +184,36 @@ impl ApplicationSettings {
         self.backup_retention_days = days;
     }
 
+    pub fn update_approval_sla(&mut self, approval_sla: ApprovalSlaSettings) {
+        self.approval_sla = approval_sla;
+    }
+
+    pub fn update_report_delivery(&mut self, report_delivery: ReportDeliverySettings) {
+        self.report_delivery = report_delivery;
+    }
+
+    pub fn update_export_protection(&mut self, export_protection: ExportProtectionSettings) {
+        self.export_protection = export_protection;
+    }
+
+    pub fn update_deleted_entry_retention(
+        &mut self,
+        deleted_entry_retention: DeletedEntryRetentionSettings,
+    ) {
+        self.deleted_entry_retention = deleted_entry_retention;
+    }
+
+    pub fn update_closing_lock_validation(
+        &mut self,
+        closing_lock_validation: ClosingLockValidationSettings,
+    ) {
+        self.closing_lock_validation = closing_lock_validation;
+    }
+
+    pub fn update_translation_adjustment_account(&mut self, account_code: AccountCode) {
+        self.translation_adjustment_account = account_code;
+    }
+
     pub fn validate(&self) -> DomainResult<()> {
         if let Some(company_code) = &self.default_company_code {
             company_code.validate()?;
@@ -120,10 +224,288 @@ impl ApplicationSettings {
         self.fiscal_year_start_month.validate()?;
         self.closing_day.validate()?;
         self.backup_retention_days.validate()?;
+        self.approval_sla.validate()?;
+        self.report_delivery.validate()?;
+        self.translation_adjustment_account.validate()?;
         Ok(())
     }
 }
 
+/// バッチ完了通知設定
+///
+/// 長時間のバッチ処理（決算処理等）の完了・失敗を利用者に知らせる方法。
+/// 既定ではいずれも無効。
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BatchNotificationSettings {
+    /// 端末ベルを鳴らす
+    pub bell_enabled: bool,
+    /// OSのデスクトップ通知を送る
+    pub desktop_enabled: bool,
+}
+
+impl BatchNotificationSettings {
+    pub fn new(bell_enabled: bool, desktop_enabled: bool) -> Self {
+        Self { bell_enabled, desktop_enabled }
+    }
+
+    /// いずれかの通知方法が有効か
+    pub fn is_enabled(&self) -> bool {
+        self.bell_enabled || self.desktop_enabled
+    }
+}
+
+/// 出力ファイルの保護設定
+///
+/// 画面から出力するCSV等のファイルを、出力時に入力したパスワードで暗号化するか、
+/// 改ざん検知用の分離署名を併せて出力するか。既定ではいずれも無効。
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ExportProtectionSettings {
+    /// パスワード付きZIP（AES-256）で出力する
+    pub encryption_enabled: bool,
+    /// 分離署名ファイル（.sig）を併せて出力する
+    pub signature_enabled: bool,
+}
+
+impl ExportProtectionSettings {
+    pub fn new(encryption_enabled: bool, signature_enabled: bool) -> Self {
+        Self { encryption_enabled, signature_enabled }
+    }
+
+    /// いずれかの保護が有効か
+    pub fn is_enabled(&self) -> bool {
+        self.encryption_enabled || self.signature_enabled
+    }
+}
+
+/// 削除済み仕訳の保持設定
+///
+/// 保持する場合、削除した仕訳は状態「削除済み」として一覧・検索から照会でき
+/// （既定の絞り込みでは表示しない）、削除から指定月数を過ぎたものを
+/// Projection圧縮ジョブで物理削除する。保持しない場合は圧縮ジョブですべて除去する。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeletedEntryRetentionSettings {
+    retain_enabled: bool,
+    purge_after_months: u32,
+}
+
+impl DeletedEntryRetentionSettings {
+    /// 既定の保持月数
+    pub const DEFAULT_PURGE_AFTER_MONTHS: u32 = 12;
+    /// 保持月数の上限（10年）
+    pub const MAX_PURGE_AFTER_MONTHS: u32 = 120;
+
+    pub fn new(retain_enabled: bool, purge_after_months: u32) -> DomainResult<Self> {
+        let settings = Self { retain_enabled, purge_after_months };
+        settings.validate()?;
+        Ok(settings)
+    }
+
+    pub fn retain_enabled(&self) -> bool {
+        self.retain_enabled
+    }
+
+    pub fn purge_after_months(&self) -> u32 {
+        self.purge_after_months
+    }
+
+    /// この日時より前に削除された仕訳を物理削除する
+    ///
+    /// 保持しない場合は `now` を返し、削除済みの仕訳をすべて対象とする。
+    pub fn purge_cutoff(&self, now: DateTime<Utc>) -> DateTime<Utc> {
+        if !self.retain_enabled {
+            return now;
+        }
+        now.checked_sub_months(Months::new(self.purge_after_months))
+            .unwrap_or(DateTime::<Utc>::MIN_UTC)
+    }
+}
+
+impl Default for DeletedEntryRetentionSettings {
+    fn default() -> Self {
+        Self { retain_enabled: false, purge_after_months: Self::DEFAULT_PURGE_AFTER_MONTHS }
+    }
+}
+
+impl ValueObject for DeletedEntryRetentionSettings {
+    fn validate(&self) -> DomainResult<()> {
+        if self.purge_after_months == 0 || self.purge_after_months > Self::MAX_PURGE_AFTER_MONTHS {
+            return Err(crate::error::DomainError::ValidationError(format!(
+                "削除済み仕訳の保持月数は1〜{}か月で指定してください",
+                Self::MAX_PURGE_AFTER_MONTHS
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// 締日固定前の事前検証項目
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ClosingLockCheck {
+    /// 試算表の貸借が一致している
+    TrialBalanceBalanced,
+    /// 期間内に下書き・承認待ちの仕訳がない
+    NoUnpostedEntries,
+    /// 仮勘定の残高がゼロ
+    SuspenseCleared,
+    /// 照合必須科目の勘定照合が完了している
+    ReconciliationsComplete,
+}
+
+impl ClosingLockCheck {
+    /// 検証の実行順
+    pub const ALL: [ClosingLockCheck; 4] = [
+        ClosingLockCheck::TrialBalanceBalanced,
+        ClosingLockCheck::NoUnpostedEntries,
+        ClosingLockCheck::SuspenseCleared,
+        ClosingLockCheck::ReconciliationsComplete,
+    ];
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            ClosingLockCheck::TrialBalanceBalanced => "trial_balance_balanced",
+            ClosingLockCheck::NoUnpostedEntries => "no_unposted_entries",
+            ClosingLockCheck::SuspenseCleared => "suspense_cleared",
+            ClosingLockCheck::ReconciliationsComplete => "reconciliations_complete",
+        }
+    }
+
+    pub fn from_code(code: &str) -> DomainResult<Self> {
+        Self::ALL.into_iter().find(|check| check.code() == code).ok_or_else(|| {
+            crate::error::DomainError::ValidationError(format!(
+                "不明な締日固定の検証項目です: {}",
+                code
+            ))
+        })
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            ClosingLockCheck::TrialBalanceBalanced => "試算表の貸借一致",
+            ClosingLockCheck::NoUnpostedEntries => "未記帳仕訳なし",
+            ClosingLockCheck::SuspenseCleared => "仮勘定残高ゼロ",
+            ClosingLockCheck::ReconciliationsComplete => "勘定照合の完了",
+        }
+    }
+}
+
+/// 締日固定前の事前検証設定
+///
+/// 無効にした検証項目を保持する（既定ではすべての項目を検証する）。
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ClosingLockValidationSettings {
+    disabled: BTreeSet<ClosingLockCheck>,
+}
+
+impl ClosingLockValidationSettings {
+    pub fn new(disabled: impl IntoIterator<Item = ClosingLockCheck>) -> Self {
+        Self { disabled: disabled.into_iter().collect() }
+    }
+
+    pub fn is_enabled(&self, check: ClosingLockCheck) -> bool {
+        !self.disabled.contains(&check)
+    }
+
+    /// 有効な検証項目（実行順）
+    pub fn enabled_checks(&self) -> Vec<ClosingLockCheck> {
+        ClosingLockCheck::ALL
+            .into_iter()
+            .filter(|check| self.is_enabled(*check))
+            .collect()
+    }
+
+    pub fn disabled_checks(&self) -> impl Iterator<Item = ClosingLockCheck> + '_ {
+        self.disabled.iter().copied()
+    }
+
+    /// 検証項目の有効・無効を切り替えた設定
+    pub fn toggled(&self, check: ClosingLockCheck) -> Self {
+        let mut disabled = self.disabled.clone();
+        if !disabled.remove(&check) {
+            disabled.insert(check);
+        }
+        Self { disabled }
+    }
+}
+
+/// 承認SLA設定
+///
+/// 承認待ちの仕訳が滞留している時間の閾値（時間単位）。
+/// 注意閾値を超えると注意、超過閾値を超えるとSLA超過として扱う。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ApprovalSlaSettings {
+    warning_hours: u32,
+    breach_hours: u32,
+}
+
+impl ApprovalSlaSettings {
+    /// 既定の注意閾値（時間）
+    pub const DEFAULT_WARNING_HOURS: u32 = 24;
+    /// 既定の超過閾値（時間）
+    pub const DEFAULT_BREACH_HOURS: u32 = 48;
+
+    pub fn new(warning_hours: u32, breach_hours: u32) -> DomainResult<Self> {
+        let settings = Self { warning_hours, breach_hours };
+        settings.validate()?;
+        Ok(settings)
+    }
+
+    pub fn warning_hours(&self) -> u32 {
+        self.warning_hours
+    }
+
+    pub fn breach_hours(&self) -> u32 {
+        self.breach_hours
+    }
+
+    /// 滞留時間からSLA上の状態を判定
+    pub fn classify(&self, elapsed: chrono::Duration) -> ApprovalAging {
+        let hours = elapsed.num_hours();
+        if hours >= i64::from(self.breach_hours) {
+            ApprovalAging::Breached
+        } else if hours >= i64::from(self.warning_hours) {
+            ApprovalAging::Warning
+        } else {
+            ApprovalAging::WithinSla
+        }
+    }
+}
+
+impl Default for ApprovalSlaSettings {
+    fn default() -> Self {
+        Self {
+            warning_hours: Self::DEFAULT_WARNING_HOURS,
+            breach_hours: Self::DEFAULT_BREACH_HOURS,
+        }
+    }
+}
+
+impl ValueObject for ApprovalSlaSettings {
+    fn validate(&self) -> DomainResult<()> {
+        if self.warning_hours == 0 {
+            return Err(crate::error::DomainError::ValidationError(
+                "承認SLAの注意閾値は1時間以上を指定してください".to_string(),
+            ));
+        }
+        if self.breach_hours <= self.warning_hours {
+            return Err(crate::error::DomainError::ValidationError(
+                "承認SLAの超過閾値は注意閾値より長く指定してください".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// 承認待ちの滞留状況
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ApprovalAging {
+    /// SLA内
+    WithinSla,
+    /// 注意閾値を超過
+    Warning,
+    /// SLA超過
+    Breached,
+}
+
 /// 言語設定
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Language(String);
@@ -315,3 +697,60 @@ impl ValueObject for BackupRetentionDays {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_approval_sla_classify() {
+        let sla = ApprovalSlaSettings::new(24, 48).unwrap();
+
+        assert_eq!(sla.classify(chrono::Duration::hours(3)), ApprovalAging::WithinSla);
+        assert_eq!(sla.classify(chrono::Duration::hours(24)), ApprovalAging::Warning);
+        assert_eq!(sla.classify(chrono::Duration::hours(47)), ApprovalAging::Warning);
+        assert_eq!(sla.classify(chrono::Duration::hours(48)), ApprovalAging::Breached);
+    }
+
+    #[test]
+    fn test_approval_sla_validation() {
+        assert!(ApprovalSlaSettings::new(0, 48).is_err());
+        assert!(ApprovalSlaSettings::new(48, 48).is_err());
+        assert!(ApprovalSlaSettings::new(8, 24).is_ok());
+        assert!(ApprovalSlaSettings::default().validate().is_ok());
+    }
+
+    #[test]
+    fn test_deleted_entry_retention_purge_cutoff() {
+        let now = DateTime::parse_from_rfc3339("2024-06-15T00:00:00Z").unwrap().to_utc();
+
+        assert_eq!(DeletedEntryRetentionSettings::default().purge_cutoff(now), now);
+        let retained = DeletedEntryRetentionSettings::new(true, 12).unwrap();
+        assert_eq!(
+            retained.purge_cutoff(now),
+            DateTime::parse_from_rfc3339("2023-06-15T00:00:00Z").unwrap().to_utc()
+        );
+
+        assert!(DeletedEntryRetentionSettings::new(true, 0).is_err());
+        assert!(DeletedEntryRetentionSettings::new(true, 121).is_err());
+    }
+
+    #[test]
+    fn test_closing_lock_validation_toggle() {
+        let settings = ClosingLockValidationSettings::default();
+        assert_eq!(settings.enabled_checks(), ClosingLockCheck::ALL.to_vec());
+
+        let settings = settings.toggled(ClosingLockCheck::SuspenseCleared);
+        assert!(!settings.is_enabled(ClosingLockCheck::SuspenseCleared));
+        assert_eq!(settings.enabled_checks().len(), 3);
+        assert_eq!(
+            settings.toggled(ClosingLockCheck::SuspenseCleared),
+            ClosingLockValidationSettings::default()
+        );
+
+        for check in ClosingLockCheck::ALL {
+            assert_eq!(ClosingLockCheck::from_code(check.code()).unwrap(), check);
+        }
+        assert!(ClosingLockCheck::from_code("unknown").is_err());
+    }
+}
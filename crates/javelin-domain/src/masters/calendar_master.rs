@@ -0,0 +1,76 @@
+// CalendarMaster - カレンダーマスタドメイン
+// 責務: 休日（祝日・会社休日）の定義
+
+use chrono::NaiveDate;
+
+use crate::{error::DomainResult, value_object::ValueObject};
+
+/// カレンダーマスタ（休日1件）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CalendarMaster {
+    date: NaiveDate,
+    name: HolidayName,
+}
+
+impl CalendarMaster {
+    pub fn new(date: NaiveDate, name: HolidayName) -> Self {
+        Self { date, name }
+    }
+
+    pub fn date(&self) -> NaiveDate {
+        self.date
+    }
+
+    pub fn name(&self) -> &HolidayName {
+        &self.name
+    }
+}
+
+/// 休日名
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HolidayName(String);
+
+impl HolidayName {
+    pub fn new(name: impl Into<String>) -> DomainResult<Self> {
+        let name = Self(name.into());
+        name.validate()?;
+        Ok(name)
+    }
+
+    pub fn value(&self) -> &str {
+        &self.0
+    }
+}
+
+impl ValueObject for HolidayName {
+    fn validate(&self) -> DomainResult<()> {
+        if self.0.is_empty() {
+            return Err(crate::error::DomainError::ValidationError(
+                "休日名は空にできません".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_holiday_name() {
+        let name = HolidayName::new("元日");
+        assert!(name.is_ok());
+        assert_eq!(name.unwrap().value(), "元日");
+
+        assert!(HolidayName::new("").is_err());
+    }
+
+    #[test]
+    fn test_calendar_master() {
+        let date = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        let master = CalendarMaster::new(date, HolidayName::new("元日").unwrap());
+        assert_eq!(master.date(), date);
+        assert_eq!(master.name().value(), "元日");
+    }
+}
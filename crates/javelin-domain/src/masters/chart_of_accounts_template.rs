@@ -0,0 +1,156 @@
+// ChartOfAccountsTemplate - 勘定科目体系のテンプレート
+// 責務: 初期セットアップで登録する標準的な勘定科目一覧の定義
+
+use super::account_master::{AccountCode, AccountMaster, AccountName, AccountType};
+use crate::error::{DomainError, DomainResult};
+
+/// 全テンプレートに共通する科目（コード, 科目名, 区分）
+const COMMON_ACCOUNTS: &[(&str, &str, AccountType)] = &[
+    ("1000", "現金", AccountType::Asset),
+    ("1100", "普通預金", AccountType::Asset),
+    ("1200", "売掛金", AccountType::Asset),
+    ("1500", "建物", AccountType::Asset),
+    ("1510", "工具器具備品", AccountType::Asset),
+    ("1600", "減価償却累計額", AccountType::Asset),
+    ("2000", "買掛金", AccountType::Liability),
+    ("2100", "未払金", AccountType::Liability),
+    ("2200", "預り金", AccountType::Liability),
+    ("2300", "未払法人税等", AccountType::Liability),
+    ("2500", "長期借入金", AccountType::Liability),
+    ("3000", "資本金", AccountType::Equity),
+    ("3100", "繰越利益剰余金", AccountType::Equity),
+    ("4000", "売上高", AccountType::Revenue),
+    ("4900", "受取利息", AccountType::Revenue),
+    ("5000", "売上原価", AccountType::Expense),
+    ("6100", "給料手当", AccountType::Expense),
+    ("6110", "法定福利費", AccountType::Expense),
+    ("6200", "地代家賃", AccountType::Expense),
+    ("6300", "水道光熱費", AccountType::Expense),
+    ("6400", "通信費", AccountType::Expense),
+    ("6500", "旅費交通費", AccountType::Expense),
+    ("6600", "消耗品費", AccountType::Expense),
+    ("6700", "減価償却費", AccountType::Expense),
+    ("6800", "租税公課", AccountType::Expense),
+    ("6900", "支払利息", AccountType::Expense),
+];
+
+/// 一般（サービス業）向けの追加科目
+const GENERAL_ACCOUNTS: &[(&str, &str, AccountType)] = &[
+    ("1250", "未収入金", AccountType::Asset),
+    ("1400", "前払費用", AccountType::Asset),
+    ("2400", "前受金", AccountType::Liability),
+    ("4100", "役務収益", AccountType::Revenue),
+    ("5100", "外注費", AccountType::Expense),
+    ("6950", "支払手数料", AccountType::Expense),
+];
+
+/// 製造業向けの追加科目（製造原価の内訳を含む）
+const MANUFACTURING_ACCOUNTS: &[(&str, &str, AccountType)] = &[
+    ("1310", "製品", AccountType::Asset),
+    ("1320", "仕掛品", AccountType::Asset),
+    ("1330", "原材料", AccountType::Asset),
+    ("1520", "機械装置", AccountType::Asset),
+    ("5200", "材料費", AccountType::Expense),
+    ("5300", "労務費", AccountType::Expense),
+    ("5400", "製造経費", AccountType::Expense),
+    ("5410", "外注加工費", AccountType::Expense),
+];
+
+/// 卸売・小売業向けの追加科目
+const TRADING_ACCOUNTS: &[(&str, &str, AccountType)] = &[
+    ("1210", "受取手形", AccountType::Asset),
+    ("1300", "商品", AccountType::Asset),
+    ("2010", "支払手形", AccountType::Liability),
+    ("4010", "売上値引・返品", AccountType::Revenue),
+    ("5010", "仕入高", AccountType::Expense),
+    ("5020", "仕入値引・返品", AccountType::Expense),
+    ("6550", "荷造運賃", AccountType::Expense),
+    ("6560", "広告宣伝費", AccountType::Expense),
+];
+
+/// 勘定科目体系のテンプレート（日本の中小企業向けの標準的な科目構成）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChartOfAccountsTemplate {
+    /// 一般（サービス業）
+    General,
+    /// 製造業
+    Manufacturing,
+    /// 卸売・小売業
+    Trading,
+}
+
+impl ChartOfAccountsTemplate {
+    /// 選択肢の表示順
+    pub const ALL: [ChartOfAccountsTemplate; 3] = [
+        ChartOfAccountsTemplate::General,
+        ChartOfAccountsTemplate::Manufacturing,
+        ChartOfAccountsTemplate::Trading,
+    ];
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            ChartOfAccountsTemplate::General => "general",
+            ChartOfAccountsTemplate::Manufacturing => "manufacturing",
+            ChartOfAccountsTemplate::Trading => "trading",
+        }
+    }
+
+    pub fn from_code(code: &str) -> DomainResult<Self> {
+        Self::ALL.into_iter().find(|template| template.code() == code).ok_or_else(|| {
+            DomainError::ValidationError(format!("不明な勘定科目テンプレートです: {}", code))
+        })
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            ChartOfAccountsTemplate::General => "一般（サービス業）",
+            ChartOfAccountsTemplate::Manufacturing => "製造業",
+            ChartOfAccountsTemplate::Trading => "卸売・小売業",
+        }
+    }
+
+    /// テンプレートの勘定科目（共通科目＋業種別科目、コード順）
+    pub fn accounts(&self) -> Vec<AccountMaster> {
+        let specific = match self {
+            ChartOfAccountsTemplate::General => GENERAL_ACCOUNTS,
+            ChartOfAccountsTemplate::Manufacturing => MANUFACTURING_ACCOUNTS,
+            ChartOfAccountsTemplate::Trading => TRADING_ACCOUNTS,
+        };
+        let mut accounts: Vec<AccountMaster> = COMMON_ACCOUNTS
+            .iter()
+            .chain(specific)
+            .map(|(code, name, account_type)| {
+                AccountMaster::new(
+                    AccountCode::new(*code).expect("template code is not empty"),
+                    AccountName::new(*name).expect("template name is not empty"),
+                    *account_type,
+                    true,
+                )
+            })
+            .collect();
+        accounts.sort_by(|a, b| a.code().value().cmp(b.code().value()));
+        accounts
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    #[test]
+    fn test_templates_have_unique_codes() {
+        for template in ChartOfAccountsTemplate::ALL {
+            let accounts = template.accounts();
+            let codes: HashSet<_> = accounts.iter().map(|a| a.code().value()).collect();
+            assert_eq!(codes.len(), accounts.len(), "{}", template.display_name());
+            assert!(codes.contains("1000"));
+            assert_eq!(ChartOfAccountsTemplate::from_code(template.code()).unwrap(), template);
+        }
+
+        let manufacturing = ChartOfAccountsTemplate::Manufacturing.accounts();
+        assert!(manufacturing.iter().any(|a| a.name().value() == "仕掛品"));
+        assert!(ChartOfAccountsTemplate::from_code("unknown").is_err());
+    }
+}
@@ -0,0 +1,339 @@
+// ClosingTimetable - 締めスケジュールドメイン
+// 責務: 月次締めタスクの担当者・期日（期末日からの日数）と、期間ごとの進捗の管理
+
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+
+use crate::error::{DomainError, DomainResult};
+
+/// タスクコードの最大文字数
+const MAX_TASK_CODE_LENGTH: usize = 16;
+
+/// 期日として指定できる期末日からの日数の範囲
+const DUE_OFFSET_RANGE: std::ops::RangeInclusive<i32> = -31..=60;
+
+/// 締めタスクに対応する締め処理の工程
+///
+/// 工程を指定したタスクは、その締め処理が正常終了した時点で完了になる。
+/// 画面から進捗を更新するタスクは `Manual` とする。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClosingStep {
+    Manual,
+    ClosingPreparation,
+    LedgerConsolidation,
+    TrialBalance,
+    NoteDraft,
+    AccountAdjustment,
+    IfrsValuation,
+    FinancialStatements,
+    PeriodLock,
+}
+
+impl ClosingStep {
+    pub const ALL: [ClosingStep; 9] = [
+        Self::Manual,
+        Self::ClosingPreparation,
+        Self::LedgerConsolidation,
+        Self::TrialBalance,
+        Self::NoteDraft,
+        Self::AccountAdjustment,
+        Self::IfrsValuation,
+        Self::FinancialStatements,
+        Self::PeriodLock,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Manual => "Manual",
+            Self::ClosingPreparation => "ClosingPreparation",
+            Self::LedgerConsolidation => "LedgerConsolidation",
+            Self::TrialBalance => "TrialBalance",
+            Self::NoteDraft => "NoteDraft",
+            Self::AccountAdjustment => "AccountAdjustment",
+            Self::IfrsValuation => "IfrsValuation",
+            Self::FinancialStatements => "FinancialStatements",
+            Self::PeriodLock => "PeriodLock",
+        }
+    }
+
+    /// 英語名または表示名から解析
+    pub fn parse(value: &str) -> DomainResult<Self> {
+        let value = value.trim();
+        Self::ALL
+            .into_iter()
+            .find(|step| step.as_str() == value || step.label() == value)
+            .ok_or_else(|| DomainError::ValidationError(format!("不明な締め工程です: {}", value)))
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Manual => "手動",
+            Self::ClosingPreparation => "締準備",
+            Self::LedgerConsolidation => "元帳集約",
+            Self::TrialBalance => "試算表生成",
+            Self::NoteDraft => "注記草案生成",
+            Self::AccountAdjustment => "勘定補正",
+            Self::IfrsValuation => "IFRS評価",
+            Self::FinancialStatements => "財務諸表生成",
+            Self::PeriodLock => "締日固定",
+        }
+    }
+}
+
+/// 締めタスクの進捗状態
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ClosingTaskStatus {
+    #[default]
+    NotStarted,
+    InProgress,
+    Completed,
+}
+
+impl ClosingTaskStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::NotStarted => "NotStarted",
+            Self::InProgress => "InProgress",
+            Self::Completed => "Completed",
+        }
+    }
+
+    pub fn parse(value: &str) -> DomainResult<Self> {
+        match value {
+            "NotStarted" => Ok(Self::NotStarted),
+            "InProgress" => Ok(Self::InProgress),
+            "Completed" => Ok(Self::Completed),
+            _ => Err(DomainError::ValidationError(format!("不明な進捗状態です: {}", value))),
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::NotStarted => "未着手",
+            Self::InProgress => "進行中",
+            Self::Completed => "完了",
+        }
+    }
+
+    /// 画面で切り替える次の状態（未着手 → 進行中 → 完了 → 未着手）
+    pub fn next(&self) -> Self {
+        match self {
+            Self::NotStarted => Self::InProgress,
+            Self::InProgress => Self::Completed,
+            Self::Completed => Self::NotStarted,
+        }
+    }
+}
+
+/// 期日に対する状況
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeadlineState {
+    /// 完了済み
+    Completed,
+    /// 期日まで残り日数（0は当日）
+    Remaining(i64),
+    /// 期日超過の日数
+    Overdue(i64),
+}
+
+impl DeadlineState {
+    /// 期日・基準日・進捗から状況を判定
+    pub fn evaluate(due_date: NaiveDate, today: NaiveDate, status: ClosingTaskStatus) -> Self {
+        if status == ClosingTaskStatus::Completed {
+            return Self::Completed;
+        }
+        let remaining = (due_date - today).num_days();
+        if remaining < 0 {
+            Self::Overdue(-remaining)
+        } else {
+            Self::Remaining(remaining)
+        }
+    }
+
+    pub fn is_overdue(&self) -> bool {
+        matches!(self, Self::Overdue(_))
+    }
+}
+
+/// 締めスケジュールの項目
+///
+/// 期日は期末日からの日数で指定し、期間ごとの期日は期末日から求める
+/// （例: 3 は翌月3日、-2 は期末日の2日前）。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClosingTimetableItem {
+    code: String,
+    task: String,
+    owner: String,
+    due_offset_days: i32,
+    step: ClosingStep,
+}
+
+impl ClosingTimetableItem {
+    pub fn new(
+        code: impl Into<String>,
+        task: impl Into<String>,
+        owner: impl Into<String>,
+        due_offset_days: i32,
+        step: ClosingStep,
+    ) -> DomainResult<Self> {
+        let code = code.into().trim().to_string();
+        let task = task.into().trim().to_string();
+        let owner = owner.into().trim().to_string();
+        if code.is_empty()
+            || code.chars().count() > MAX_TASK_CODE_LENGTH
+            || code.contains(char::is_whitespace)
+            || code.contains(':')
+        {
+            return Err(DomainError::ValidationError(format!(
+                "タスクコードは空白・コロンを含まない{}文字以内で指定してください: {}",
+                MAX_TASK_CODE_LENGTH, code
+            )));
+        }
+        if task.is_empty() {
+            return Err(DomainError::ValidationError("タスク名は空にできません".to_string()));
+        }
+        if owner.is_empty() {
+            return Err(DomainError::ValidationError("担当者は空にできません".to_string()));
+        }
+        if !DUE_OFFSET_RANGE.contains(&due_offset_days) {
+            return Err(DomainError::ValidationError(format!(
+                "期日は期末日から{}〜{}日の範囲で指定してください: {}",
+                DUE_OFFSET_RANGE.start(),
+                DUE_OFFSET_RANGE.end(),
+                due_offset_days
+            )));
+        }
+        Ok(Self { code, task, owner, due_offset_days, step })
+    }
+
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    pub fn task(&self) -> &str {
+        &self.task
+    }
+
+    pub fn owner(&self) -> &str {
+        &self.owner
+    }
+
+    pub fn due_offset_days(&self) -> i32 {
+        self.due_offset_days
+    }
+
+    pub fn step(&self) -> ClosingStep {
+        self.step
+    }
+
+    /// 期末日に対する期日
+    pub fn due_date(&self, period_end: NaiveDate) -> NaiveDate {
+        period_end + Duration::days(self.due_offset_days as i64)
+    }
+}
+
+/// 期間ごとの締めタスクの進捗
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClosingTaskProgress {
+    fiscal_year: i32,
+    period: u8,
+    code: String,
+    status: ClosingTaskStatus,
+    updated_by: String,
+    updated_at: DateTime<Utc>,
+}
+
+impl ClosingTaskProgress {
+    pub fn new(
+        fiscal_year: i32,
+        period: u8,
+        code: impl Into<String>,
+        status: ClosingTaskStatus,
+        updated_by: impl Into<String>,
+        updated_at: DateTime<Utc>,
+    ) -> DomainResult<Self> {
+        if !(1..=12).contains(&period) {
+            return Err(DomainError::ValidationError(format!(
+                "会計期間は1〜12で指定してください: {}",
+                period
+            )));
+        }
+        Ok(Self {
+            fiscal_year,
+            period,
+            code: code.into(),
+            status,
+            updated_by: updated_by.into(),
+            updated_at,
+        })
+    }
+
+    pub fn fiscal_year(&self) -> i32 {
+        self.fiscal_year
+    }
+
+    pub fn period(&self) -> u8 {
+        self.period
+    }
+
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    pub fn status(&self) -> ClosingTaskStatus {
+        self.status
+    }
+
+    pub fn updated_by(&self) -> &str {
+        &self.updated_by
+    }
+
+    pub fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_due_date_and_deadline_state() {
+        let item =
+            ClosingTimetableItem::new(" T01 ", "売上計上確認", "佐藤", 3, ClosingStep::Manual)
+                .unwrap();
+        assert_eq!(item.code(), "T01");
+
+        let period_end = NaiveDate::from_ymd_opt(2024, 4, 30).unwrap();
+        let due = item.due_date(period_end);
+        assert_eq!(due, NaiveDate::from_ymd_opt(2024, 5, 3).unwrap());
+
+        let today = NaiveDate::from_ymd_opt(2024, 5, 1).unwrap();
+        assert_eq!(
+            DeadlineState::evaluate(due, today, ClosingTaskStatus::InProgress),
+            DeadlineState::Remaining(2)
+        );
+        let late = NaiveDate::from_ymd_opt(2024, 5, 5).unwrap();
+        assert!(DeadlineState::evaluate(due, late, ClosingTaskStatus::NotStarted).is_overdue());
+        assert_eq!(
+            DeadlineState::evaluate(due, late, ClosingTaskStatus::Completed),
+            DeadlineState::Completed
+        );
+    }
+
+    #[test]
+    fn test_item_validation_and_step_parsing() {
+        assert!(
+            ClosingTimetableItem::new("T 1", "タスク", "佐藤", 0, ClosingStep::Manual).is_err()
+        );
+        assert!(ClosingTimetableItem::new("T1", " ", "佐藤", 0, ClosingStep::Manual).is_err());
+        assert!(ClosingTimetableItem::new("T1", "タスク", "", 0, ClosingStep::Manual).is_err());
+        assert!(
+            ClosingTimetableItem::new("T1", "タスク", "佐藤", 61, ClosingStep::Manual).is_err()
+        );
+
+        assert_eq!(ClosingStep::parse("締日固定").unwrap(), ClosingStep::PeriodLock);
+        assert_eq!(ClosingStep::parse("IfrsValuation").unwrap(), ClosingStep::IfrsValuation);
+        assert!(ClosingStep::parse("監査").is_err());
+        assert_eq!(ClosingTaskStatus::Completed.next(), ClosingTaskStatus::NotStarted);
+    }
+}
@@ -0,0 +1,121 @@
+// DimensionMaster - 分析軸マスタドメイン
+// 責務: 仕訳明細に付ける分析軸（プロジェクト・セグメント・任意の分析タグ）のコード体系
+
+use crate::{
+    error::{DomainError, DomainResult},
+    financial_close::journal_entry::values::{
+        LineDimensions, validate_dimension_code, validate_dimension_kind,
+    },
+};
+
+/// 分析軸マスタ
+///
+/// 分析軸の種類（例: `project`）ごとに使用できるコードと名称を管理する。
+/// 無効化したコードは新しい仕訳明細に使用できない（記帳済みの明細はそのまま残る）。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DimensionMaster {
+    kind: String,
+    code: String,
+    name: String,
+    is_active: bool,
+}
+
+impl DimensionMaster {
+    pub fn new(
+        kind: impl Into<String>,
+        code: impl Into<String>,
+        name: impl Into<String>,
+        is_active: bool,
+    ) -> DomainResult<Self> {
+        let kind = kind.into().trim().to_string();
+        let code = code.into().trim().to_string();
+        let name = name.into().trim().to_string();
+        validate_dimension_kind(&kind)?;
+        validate_dimension_code(&code)?;
+        if name.is_empty() {
+            return Err(DomainError::ValidationError("分析軸の名称は空にできません".to_string()));
+        }
+        Ok(Self { kind, code, name, is_active })
+    }
+
+    pub fn kind(&self) -> &str {
+        &self.kind
+    }
+
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.is_active
+    }
+
+    pub fn activate(&mut self) {
+        self.is_active = true;
+    }
+
+    pub fn deactivate(&mut self) {
+        self.is_active = false;
+    }
+
+    /// 仕訳明細の分析軸がマスタに登録済みの有効なコードか検証
+    pub fn validate_line_dimensions(
+        masters: &[DimensionMaster],
+        dimensions: &LineDimensions,
+    ) -> DomainResult<()> {
+        for (kind, code) in dimensions.iter() {
+            match masters.iter().find(|m| m.kind == kind && m.code == code) {
+                Some(master) if master.is_active => {}
+                Some(_) => {
+                    return Err(DomainError::ValidationError(format!(
+                        "無効化された分析軸は使用できません: {}={}",
+                        kind, code
+                    )));
+                }
+                None => {
+                    return Err(DomainError::ValidationError(format!(
+                        "分析軸マスタに登録されていません: {}={}",
+                        kind, code
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_line_dimensions_against_masters() {
+        let mut closed = DimensionMaster::new("project", "P002", "旧プロジェクト", true).unwrap();
+        closed.deactivate();
+        let masters = vec![
+            DimensionMaster::new(" project ", " P001 ", "新基幹システム", true).unwrap(),
+            DimensionMaster::new("segment", "S1", "国内", true).unwrap(),
+            closed,
+        ];
+        assert_eq!(masters[0].kind(), "project");
+        assert_eq!(masters[0].code(), "P001");
+
+        let valid = LineDimensions::parse("project=P001, segment=S1").unwrap();
+        assert!(DimensionMaster::validate_line_dimensions(&masters, &valid).is_ok());
+        assert!(
+            DimensionMaster::validate_line_dimensions(&masters, &LineDimensions::default()).is_ok()
+        );
+
+        let inactive = LineDimensions::parse("project=P002").unwrap();
+        assert!(DimensionMaster::validate_line_dimensions(&masters, &inactive).is_err());
+        let unknown = LineDimensions::parse("campaign=C1").unwrap();
+        assert!(DimensionMaster::validate_line_dimensions(&masters, &unknown).is_err());
+
+        assert!(DimensionMaster::new("project", "P003", " ", true).is_err());
+        assert!(DimensionMaster::new("department", "D01", "営業部", true).is_err());
+    }
+}
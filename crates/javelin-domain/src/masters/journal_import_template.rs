@@ -0,0 +1,250 @@
+// JournalImportTemplate - 仕訳取込テンプレート
+// 責務: 表計算ファイルの列と仕訳明細の項目の対応付け（見出し行ごとに記憶）
+
+use std::{collections::BTreeMap, fmt};
+
+use crate::{
+    error::{DomainError, DomainResult},
+    value_object::ValueObject,
+};
+
+/// 仕訳取込の項目
+///
+/// 表計算ファイルの1行を仕訳明細1行として取り込む。
+/// 同じ伝票番号の行を1件の仕訳にまとめる。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ImportField {
+    TransactionDate,
+    VoucherNumber,
+    Side,
+    AccountCode,
+    Amount,
+    Description,
+    SubAccountCode,
+    DepartmentCode,
+    TaxType,
+    Currency,
+}
+
+impl ImportField {
+    pub const ALL: [ImportField; 10] = [
+        Self::TransactionDate,
+        Self::VoucherNumber,
+        Self::Side,
+        Self::AccountCode,
+        Self::Amount,
+        Self::Description,
+        Self::SubAccountCode,
+        Self::DepartmentCode,
+        Self::TaxType,
+        Self::Currency,
+    ];
+
+    /// 保存時の識別子
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::TransactionDate => "transaction_date",
+            Self::VoucherNumber => "voucher_number",
+            Self::Side => "side",
+            Self::AccountCode => "account_code",
+            Self::Amount => "amount",
+            Self::Description => "description",
+            Self::SubAccountCode => "sub_account_code",
+            Self::DepartmentCode => "department_code",
+            Self::TaxType => "tax_type",
+            Self::Currency => "currency",
+        }
+    }
+
+    pub fn parse(value: &str) -> DomainResult<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|field| field.as_str() == value)
+            .ok_or_else(|| invalid(format!("取込項目が不正です: {}", value)))
+    }
+
+    /// 画面表示名
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::TransactionDate => "取引日付",
+            Self::VoucherNumber => "伝票番号",
+            Self::Side => "貸借",
+            Self::AccountCode => "勘定科目",
+            Self::Amount => "金額",
+            Self::Description => "摘要",
+            Self::SubAccountCode => "補助科目",
+            Self::DepartmentCode => "部門",
+            Self::TaxType => "税区分",
+            Self::Currency => "通貨",
+        }
+    }
+
+    /// 列の割当が必須か
+    pub fn is_required(&self) -> bool {
+        matches!(
+            self,
+            Self::TransactionDate
+                | Self::VoucherNumber
+                | Self::Side
+                | Self::AccountCode
+                | Self::Amount
+        )
+    }
+
+    /// 見出しから項目を推測
+    pub fn guess(header: &str) -> Option<Self> {
+        let header = header.trim().to_lowercase();
+        let matches = |aliases: &[&str]| aliases.iter().any(|alias| header == *alias);
+        Self::ALL.into_iter().find(|field| match field {
+            Self::TransactionDate => matches(&["取引日付", "取引日", "日付", "date"]),
+            Self::VoucherNumber => {
+                matches(&["伝票番号", "伝票no", "伝票no.", "伝票", "voucher", "voucher_number"])
+            }
+            Self::Side => matches(&["貸借", "借方/貸方", "貸借区分", "side"]),
+            Self::AccountCode => {
+                matches(&["勘定科目", "科目コード", "勘定科目コード", "科目", "account"])
+            }
+            Self::Amount => matches(&["金額", "amount"]),
+            Self::Description => matches(&["摘要", "description"]),
+            Self::SubAccountCode => matches(&["補助科目", "補助科目コード"]),
+            Self::DepartmentCode => matches(&["部門", "部門コード", "department"]),
+            Self::TaxType => matches(&["税区分", "tax_type"]),
+            Self::Currency => matches(&["通貨", "currency"]),
+        })
+    }
+}
+
+impl fmt::Display for ImportField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.label())
+    }
+}
+
+/// 仕訳取込テンプレート
+///
+/// 見出し行（シグネチャ）ごとに項目と列番号（0始まり）の対応を記憶し、
+/// 同じ様式のファイルを次回取り込むときに割当を復元する。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JournalImportTemplate {
+    signature: String,
+    columns: BTreeMap<ImportField, usize>,
+}
+
+impl JournalImportTemplate {
+    pub fn new(
+        signature: impl Into<String>,
+        columns: BTreeMap<ImportField, usize>,
+    ) -> DomainResult<Self> {
+        let template = Self { signature: signature.into(), columns };
+        template.validate()?;
+        Ok(template)
+    }
+
+    /// 見出し行からシグネチャを作成（前後の空白は無視）
+    pub fn signature_of(headers: &[String]) -> String {
+        headers.iter().map(|header| header.trim()).collect::<Vec<_>>().join("\t")
+    }
+
+    /// 見出しから列の割当を推測（同じ項目は最初の列に割り当てる）
+    pub fn guess_columns(headers: &[String]) -> BTreeMap<ImportField, usize> {
+        let mut columns = BTreeMap::new();
+        for (index, header) in headers.iter().enumerate() {
+            if let Some(field) = ImportField::guess(header) {
+                columns.entry(field).or_insert(index);
+            }
+        }
+        columns
+    }
+
+    pub fn signature(&self) -> &str {
+        &self.signature
+    }
+
+    pub fn columns(&self) -> &BTreeMap<ImportField, usize> {
+        &self.columns
+    }
+
+    /// 項目に割り当てた列番号
+    pub fn column(&self, field: ImportField) -> Option<usize> {
+        self.columns.get(&field).copied()
+    }
+}
+
+impl ValueObject for JournalImportTemplate {
+    fn validate(&self) -> DomainResult<()> {
+        if self.signature.is_empty() {
+            return Err(invalid("見出し行がありません"));
+        }
+        let missing: Vec<&str> = ImportField::ALL
+            .iter()
+            .filter(|field| field.is_required() && !self.columns.contains_key(field))
+            .map(|field| field.label())
+            .collect();
+        if !missing.is_empty() {
+            return Err(invalid(format!(
+                "列が割り当てられていない項目があります: {}",
+                missing.join("・")
+            )));
+        }
+        let mut used: BTreeMap<usize, ImportField> = BTreeMap::new();
+        for (field, column) in &self.columns {
+            if let Some(other) = used.insert(*column, *field) {
+                return Err(invalid(format!(
+                    "{}列目が{}と{}に重複して割り当てられています",
+                    column + 1,
+                    other.label(),
+                    field.label()
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+fn invalid(message: impl Into<String>) -> DomainError {
+    DomainError::ValidationError(message.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn headers(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    #[test]
+    fn test_guess_columns_from_headers() {
+        let headers = headers(&["日付", "伝票No", "貸借", "科目コード", "金額", "摘要", "備考"]);
+        let columns = JournalImportTemplate::guess_columns(&headers);
+
+        assert_eq!(columns.get(&ImportField::TransactionDate), Some(&0));
+        assert_eq!(columns.get(&ImportField::VoucherNumber), Some(&1));
+        assert_eq!(columns.get(&ImportField::Amount), Some(&4));
+        assert_eq!(columns.get(&ImportField::Description), Some(&5));
+        assert_eq!(columns.len(), 6);
+
+        let template =
+            JournalImportTemplate::new(JournalImportTemplate::signature_of(&headers), columns)
+                .unwrap();
+        assert_eq!(template.column(ImportField::Side), Some(2));
+        assert_eq!(ImportField::parse("tax_type").unwrap(), ImportField::TaxType);
+    }
+
+    #[test]
+    fn test_rejects_missing_and_duplicate_columns() {
+        let mut columns = JournalImportTemplate::guess_columns(&headers(&[
+            "取引日付",
+            "伝票番号",
+            "貸借",
+            "勘定科目",
+        ]));
+        assert!(JournalImportTemplate::new("sig", columns.clone()).is_err());
+
+        columns.insert(ImportField::Amount, 3);
+        assert!(JournalImportTemplate::new("sig", columns.clone()).is_err());
+
+        columns.insert(ImportField::Amount, 4);
+        assert!(JournalImportTemplate::new("sig", columns).is_ok());
+    }
+}
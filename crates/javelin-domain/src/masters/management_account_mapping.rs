@@ -0,0 +1,120 @@
+// ManagementAccountMapping - 管理会計科目マッピングドメイン
+// 責務: 勘定科目（制度会計）と管理会計科目の対応付け
+
+use std::fmt;
+
+use super::account_master::AccountCode;
+use crate::{
+    error::{DomainError, DomainResult},
+    value_object::ValueObject,
+};
+
+/// 管理会計科目
+///
+/// 設定画面では `<科目コード>:<科目名>` の形式で表す（例: `M510:人件費`）。
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ManagementAccount {
+    code: String,
+    name: String,
+}
+
+impl ManagementAccount {
+    pub fn new(code: impl Into<String>, name: impl Into<String>) -> DomainResult<Self> {
+        let account =
+            Self { code: code.into().trim().to_string(), name: name.into().trim().to_string() };
+        account.validate()?;
+        Ok(account)
+    }
+
+    /// `<科目コード>:<科目名>` 形式の管理会計科目を解析
+    pub fn parse(spec: &str) -> DomainResult<Self> {
+        let (code, name) = spec.split_once(':').ok_or_else(|| {
+            DomainError::ValidationError(format!(
+                "管理会計科目の形式が不正です（<科目コード>:<科目名> で指定してください）: {}",
+                spec
+            ))
+        })?;
+        Self::new(code, name)
+    }
+
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl fmt::Display for ManagementAccount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.code, self.name)
+    }
+}
+
+impl ValueObject for ManagementAccount {
+    fn validate(&self) -> DomainResult<()> {
+        if self.code.is_empty() {
+            return Err(DomainError::ValidationError(
+                "管理会計科目コードは空にできません".to_string(),
+            ));
+        }
+        if self.code.contains(char::is_whitespace) {
+            return Err(DomainError::ValidationError(format!(
+                "管理会計科目コードに空白は使用できません: {}",
+                self.code
+            )));
+        }
+        if self.name.is_empty() {
+            return Err(DomainError::ValidationError("管理会計科目名は空にできません".to_string()));
+        }
+        Ok(())
+    }
+}
+
+/// 勘定科目 → 管理会計科目マッピング
+///
+/// 複数の勘定科目を同じ管理会計科目に割り当てることで、制度会計の仕訳を
+/// 管理会計の科目体系で集計し直す。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ManagementAccountMapping {
+    account_code: AccountCode,
+    management_account: ManagementAccount,
+}
+
+impl ManagementAccountMapping {
+    pub fn new(account_code: AccountCode, management_account: ManagementAccount) -> Self {
+        Self { account_code, management_account }
+    }
+
+    pub fn account_code(&self) -> &AccountCode {
+        &self.account_code
+    }
+
+    pub fn management_account(&self) -> &ManagementAccount {
+        &self.management_account
+    }
+
+    /// 管理会計科目を変更
+    pub fn reassign(&mut self, management_account: ManagementAccount) {
+        self.management_account = management_account;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_management_account() {
+        let account = ManagementAccount::parse(" M510 : 人件費 ").unwrap();
+        assert_eq!(account.code(), "M510");
+        assert_eq!(account.name(), "人件費");
+        assert_eq!(ManagementAccount::parse(&account.to_string()).unwrap(), account);
+
+        assert!(ManagementAccount::parse("M510").is_err());
+        assert!(ManagementAccount::parse(":人件費").is_err());
+        assert!(ManagementAccount::parse("M510:").is_err());
+        assert!(ManagementAccount::parse("M 510:人件費").is_err());
+    }
+}
@@ -0,0 +1,324 @@
+// MasterChange - マスタ変更イベントドメイン
+// 責務: マスタレコードの変更前後のイメージの記録と、変更を打ち消す逆変更の生成
+//
+// マスタの登録・変更・削除は変更イベントとして記録し、マスタには変更後のイメージを反映する。
+// 取消しも逆向きの変更イベントとして記録するため、取消しを含めた経緯が履歴に残る。
+
+use chrono::{DateTime, Utc};
+
+use super::{account_master::AccountMaster, company_master::CompanyMaster};
+use crate::error::{DomainError, DomainResult};
+
+/// 変更履歴を記録するマスタレコード
+pub trait MasterRecord: Clone + PartialEq {
+    /// レコードのコード（変更履歴のキー）
+    fn record_code(&self) -> &str;
+
+    /// 変更履歴に表示する内容
+    fn describe(&self) -> String;
+}
+
+fn active_label(is_active: bool) -> &'static str {
+    if is_active { "有効" } else { "無効" }
+}
+
+impl MasterRecord for AccountMaster {
+    fn record_code(&self) -> &str {
+        self.code().value()
+    }
+
+    fn describe(&self) -> String {
+        format!("{}（{}）", self.name().value(), active_label(self.is_active()))
+    }
+}
+
+impl MasterRecord for CompanyMaster {
+    fn record_code(&self) -> &str {
+        self.code().value()
+    }
+
+    fn describe(&self) -> String {
+        format!("{}（{}）", self.name().value(), active_label(self.is_active()))
+    }
+}
+
+/// 変更の種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MasterChangeKind {
+    Registered,
+    Updated,
+    Deleted,
+}
+
+impl MasterChangeKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Registered => "登録",
+            Self::Updated => "変更",
+            Self::Deleted => "削除",
+        }
+    }
+}
+
+/// マスタ変更イベント（監査証跡）
+///
+/// 変更前・変更後のイメージを保持する。登録は変更前が、削除は変更後がない。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MasterChange<T> {
+    /// レコード内の連番（1始まり）
+    sequence: u64,
+    record_code: String,
+    before: Option<T>,
+    after: Option<T>,
+    changed_by: String,
+    changed_at: DateTime<Utc>,
+    /// 取り消した変更の連番（取消しによる変更の場合）
+    reverts: Option<u64>,
+}
+
+impl<T: MasterRecord> MasterChange<T> {
+    /// 保存済みの変更イベントを復元
+    pub fn restore(
+        sequence: u64,
+        record_code: impl Into<String>,
+        before: Option<T>,
+        after: Option<T>,
+        changed_by: impl Into<String>,
+        changed_at: DateTime<Utc>,
+        reverts: Option<u64>,
+    ) -> Self {
+        Self {
+            sequence,
+            record_code: record_code.into(),
+            before,
+            after,
+            changed_by: changed_by.into(),
+            changed_at,
+            reverts,
+        }
+    }
+
+    pub fn sequence(&self) -> u64 {
+        self.sequence
+    }
+
+    pub fn record_code(&self) -> &str {
+        &self.record_code
+    }
+
+    pub fn before(&self) -> Option<&T> {
+        self.before.as_ref()
+    }
+
+    pub fn after(&self) -> Option<&T> {
+        self.after.as_ref()
+    }
+
+    pub fn changed_by(&self) -> &str {
+        &self.changed_by
+    }
+
+    pub fn changed_at(&self) -> DateTime<Utc> {
+        self.changed_at
+    }
+
+    pub fn reverts(&self) -> Option<u64> {
+        self.reverts
+    }
+
+    pub fn kind(&self) -> MasterChangeKind {
+        match (&self.before, &self.after) {
+            (None, _) => MasterChangeKind::Registered,
+            (Some(_), None) => MasterChangeKind::Deleted,
+            (Some(_), Some(_)) => MasterChangeKind::Updated,
+        }
+    }
+}
+
+/// マスタレコード単位の変更履歴
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MasterChangeHistory<T> {
+    record_code: String,
+    /// 記録順の変更イベント
+    changes: Vec<MasterChange<T>>,
+}
+
+impl<T: MasterRecord> MasterChangeHistory<T> {
+    pub fn new(record_code: impl Into<String>, changes: Vec<MasterChange<T>>) -> Self {
+        Self { record_code: record_code.into(), changes }
+    }
+
+    pub fn record_code(&self) -> &str {
+        &self.record_code
+    }
+
+    pub fn changes(&self) -> &[MasterChange<T>] {
+        &self.changes
+    }
+
+    /// 変更を記録（変更前後が同じ場合はNone）
+    pub fn record(
+        &self,
+        before: Option<T>,
+        after: Option<T>,
+        changed_by: &str,
+    ) -> DomainResult<Option<MasterChange<T>>> {
+        if before == after {
+            return Ok(None);
+        }
+        let codes_match = [&before, &after]
+            .into_iter()
+            .flatten()
+            .all(|record| record.record_code() == self.record_code);
+        if !codes_match {
+            return Err(DomainError::ValidationError(format!(
+                "変更前後のコードが変更履歴のコードと一致しません: {}",
+                self.record_code
+            )));
+        }
+        Ok(Some(self.next_change(before, after, changed_by, None)))
+    }
+
+    /// 変更を取り消せるか
+    ///
+    /// 現在の内容が変更後のイメージと一致する場合のみ取り消せる。
+    /// 以降の変更が残っている場合は、新しい変更から順に取り消す。
+    pub fn is_revertible(&self, sequence: u64, current: Option<&T>) -> bool {
+        self.find(sequence).is_some_and(|change| change.after.as_ref() == current)
+    }
+
+    /// 変更を打ち消す逆変更を生成
+    pub fn revert(
+        &self,
+        sequence: u64,
+        current: Option<&T>,
+        changed_by: &str,
+    ) -> DomainResult<MasterChange<T>> {
+        let change = self.find(sequence).ok_or_else(|| {
+            DomainError::NotFound(format!(
+                "{} の変更 #{} が見つかりません",
+                self.record_code, sequence
+            ))
+        })?;
+        if change.after.as_ref() != current {
+            return Err(DomainError::ValidationError(format!(
+                "{} の変更 #{} より後の変更が残っているため取り消せません（新しい変更から順に取り消してください）",
+                self.record_code, sequence
+            )));
+        }
+        Ok(self.next_change(
+            change.after.clone(),
+            change.before.clone(),
+            changed_by,
+            Some(sequence),
+        ))
+    }
+
+    fn find(&self, sequence: u64) -> Option<&MasterChange<T>> {
+        self.changes.iter().find(|change| change.sequence == sequence)
+    }
+
+    fn next_change(
+        &self,
+        before: Option<T>,
+        after: Option<T>,
+        changed_by: &str,
+        reverts: Option<u64>,
+    ) -> MasterChange<T> {
+        MasterChange {
+            sequence: self.changes.last().map_or(1, |change| change.sequence + 1),
+            record_code: self.record_code.clone(),
+            before,
+            after,
+            changed_by: changed_by.to_string(),
+            changed_at: Utc::now(),
+            reverts,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::masters::{AccountCode, AccountName, AccountType};
+
+    fn account(name: &str, is_active: bool) -> AccountMaster {
+        AccountMaster::new(
+            AccountCode::new("1000").unwrap(),
+            AccountName::new(name).unwrap(),
+            AccountType::Asset,
+            is_active,
+        )
+    }
+
+    /// 変更を記録して履歴に追加し、変更後のイメージを返す
+    fn apply(
+        history: &mut MasterChangeHistory<AccountMaster>,
+        change: MasterChange<AccountMaster>,
+    ) -> Option<AccountMaster> {
+        let after = change.after().cloned();
+        history.changes.push(change);
+        after
+    }
+
+    #[test]
+    fn test_record_skips_unchanged_and_foreign_records() {
+        let history = MasterChangeHistory::new("1000", Vec::new());
+        let unchanged =
+            history.record(Some(account("現金", true)), Some(account("現金", true)), "u");
+        assert!(unchanged.unwrap().is_none());
+
+        let other = AccountMaster::new(
+            AccountCode::new("2000").unwrap(),
+            AccountName::new("買掛金").unwrap(),
+            AccountType::Liability,
+            true,
+        );
+        assert!(history.record(None, Some(other), "u").is_err());
+
+        let change = history.record(None, Some(account("現金", true)), "u").unwrap().unwrap();
+        assert_eq!(change.sequence(), 1);
+        assert_eq!(change.kind(), MasterChangeKind::Registered);
+    }
+
+    #[test]
+    fn test_multi_step_revert_in_reverse_order() {
+        let mut history = MasterChangeHistory::new("1000", Vec::new());
+        let registered = history.record(None, Some(account("現金", true)), "alice").unwrap();
+        let current = apply(&mut history, registered.unwrap());
+        let renamed = history
+            .record(current.clone(), Some(account("小口現金", true)), "alice")
+            .unwrap();
+        let current = apply(&mut history, renamed.unwrap());
+        let deactivated = history
+            .record(current.clone(), Some(account("小口現金", false)), "bob")
+            .unwrap();
+        let current = apply(&mut history, deactivated.unwrap());
+
+        // 後の変更が残っている変更は取り消せない
+        assert!(!history.is_revertible(2, current.as_ref()));
+        assert!(history.revert(2, current.as_ref(), "carol").is_err());
+        assert!(history.revert(9, current.as_ref(), "carol").is_err());
+
+        let undo = history.revert(3, current.as_ref(), "carol").unwrap();
+        assert_eq!(undo.sequence(), 4);
+        assert_eq!(undo.reverts(), Some(3));
+        assert_eq!(undo.changed_by(), "carol");
+        let current = apply(&mut history, undo);
+        assert_eq!(current, Some(account("小口現金", true)));
+
+        let undo = history.revert(2, current.as_ref(), "carol").unwrap();
+        let current = apply(&mut history, undo);
+        assert_eq!(current, Some(account("現金", true)));
+
+        // 登録の取消しは削除になる
+        let undo = history.revert(1, current.as_ref(), "carol").unwrap();
+        assert_eq!(undo.kind(), MasterChangeKind::Deleted);
+        let current = apply(&mut history, undo);
+        assert_eq!(current, None);
+
+        // 取消しも取り消せる（やり直し）
+        assert!(history.is_revertible(6, None));
+        assert_eq!(history.changes().len(), 6);
+    }
+}
@@ -0,0 +1,183 @@
+// NoteCrossReference - 注記参照ドメイン
+// 責務: 注記（番号・表題・本文）と、財務諸表表示科目・試算表の勘定科目から注記への参照の管理
+
+use super::{account_master::AccountCode, statement_line_mapping::StatementLine};
+use crate::error::{DomainError, DomainResult};
+
+/// 注記番号の範囲
+const NOTE_NUMBER_RANGE: std::ops::RangeInclusive<u32> = 1..=99;
+
+fn validate_note_number(number: u32) -> DomainResult<()> {
+    if NOTE_NUMBER_RANGE.contains(&number) {
+        Ok(())
+    } else {
+        Err(DomainError::ValidationError(format!(
+            "注記番号は{}〜{}で指定してください: {}",
+            NOTE_NUMBER_RANGE.start(),
+            NOTE_NUMBER_RANGE.end(),
+            number
+        )))
+    }
+}
+
+/// 注記
+///
+/// 本文が空の注記は番号と表題だけを先に用意したもので、作成済みとは扱わない。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoteSection {
+    number: u32,
+    title: String,
+    body: String,
+}
+
+impl NoteSection {
+    pub fn new(
+        number: u32,
+        title: impl Into<String>,
+        body: impl Into<String>,
+    ) -> DomainResult<Self> {
+        validate_note_number(number)?;
+        let title = title.into().trim().to_string();
+        if title.is_empty() {
+            return Err(DomainError::ValidationError("注記の表題は空にできません".to_string()));
+        }
+        Ok(Self { number, title, body: body.into().trim().to_string() })
+    }
+
+    pub fn number(&self) -> u32 {
+        self.number
+    }
+
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    pub fn body(&self) -> &str {
+        &self.body
+    }
+
+    /// 本文が作成済みか
+    pub fn is_drafted(&self) -> bool {
+        !self.body.is_empty()
+    }
+}
+
+/// 注記を参照する対象
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NoteReferenceTarget {
+    /// 財務諸表の表示科目
+    Caption(StatementLine),
+    /// 試算表の勘定科目（財務諸表では割り当てた表示科目に表示する）
+    Account(AccountCode),
+}
+
+impl NoteReferenceTarget {
+    /// 表示科目コード（BS-CA など）または勘定科目コードから解析
+    pub fn parse(value: &str) -> DomainResult<Self> {
+        let value = value.trim();
+        if let Ok(line) = StatementLine::from_code(value) {
+            return Ok(Self::Caption(line));
+        }
+        let invalid = || {
+            DomainError::ValidationError(format!(
+                "参照先は表示科目コードまたは勘定科目コードで指定してください: {}",
+                value
+            ))
+        };
+        if value.contains(char::is_whitespace) || value.contains(':') {
+            return Err(invalid());
+        }
+        AccountCode::new(value).map(Self::Account).map_err(|_| invalid())
+    }
+
+    /// 表示科目コードまたは勘定科目コード
+    pub fn code(&self) -> &str {
+        match self {
+            Self::Caption(line) => line.code(),
+            Self::Account(code) => code.value(),
+        }
+    }
+
+    pub fn label(&self) -> String {
+        match self {
+            Self::Caption(line) => line.display_name().to_string(),
+            Self::Account(code) => format!("勘定科目 {}", code.value()),
+        }
+    }
+}
+
+/// 注記参照
+///
+/// 必須の参照は、財務諸表の生成時に参照先の注記が作成済みであることを求める。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoteCrossReference {
+    target: NoteReferenceTarget,
+    note_number: u32,
+    required: bool,
+}
+
+impl NoteCrossReference {
+    pub fn new(
+        target: NoteReferenceTarget,
+        note_number: u32,
+        required: bool,
+    ) -> DomainResult<Self> {
+        validate_note_number(note_number)?;
+        Ok(Self { target, note_number, required })
+    }
+
+    pub fn target(&self) -> &NoteReferenceTarget {
+        &self.target
+    }
+
+    pub fn note_number(&self) -> u32 {
+        self.note_number
+    }
+
+    pub fn is_required(&self) -> bool {
+        self.required
+    }
+
+    /// 参照の要件を満たすか（必須の参照は参照先の注記が作成済みであること）
+    pub fn is_satisfied_by(&self, sections: &[NoteSection]) -> bool {
+        !self.required
+            || sections
+                .iter()
+                .any(|section| section.number() == self.note_number && section.is_drafted())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_target_parsing_prefers_statement_line_codes() {
+        let caption = NoteReferenceTarget::parse(" BS-CA ").unwrap();
+        assert_eq!(caption, NoteReferenceTarget::Caption(StatementLine::CurrentAssets));
+        assert_eq!(caption.label(), "流動資産");
+
+        let account = NoteReferenceTarget::parse("1100").unwrap();
+        assert_eq!(account.code(), "1100");
+        assert_eq!(account.label(), "勘定科目 1100");
+        assert!(NoteReferenceTarget::parse("").is_err());
+        assert!(NoteReferenceTarget::parse("11:00").is_err());
+    }
+
+    #[test]
+    fn test_required_reference_needs_drafted_section() {
+        assert!(NoteSection::new(0, "重要な会計方針", "").is_err());
+        assert!(NoteSection::new(1, " ", "本文").is_err());
+
+        let target = NoteReferenceTarget::Caption(StatementLine::Revenue);
+        let required = NoteCrossReference::new(target.clone(), 2, true).unwrap();
+        let optional = NoteCrossReference::new(target, 2, false).unwrap();
+
+        let placeholder = vec![NoteSection::new(2, "収益認識", " ").unwrap()];
+        assert!(!required.is_satisfied_by(&placeholder));
+        assert!(optional.is_satisfied_by(&placeholder));
+
+        let drafted = vec![NoteSection::new(2, "収益認識", "履行義務の充足時に認識").unwrap()];
+        assert!(required.is_satisfied_by(&drafted));
+    }
+}
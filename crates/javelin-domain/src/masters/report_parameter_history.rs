@@ -0,0 +1,98 @@
+// ReportParameterHistory - 帳票の最近使った条件
+// 責務: 利用者・帳票ごとに、直近に実行した条件（パラメータの組）を新しい順に保持
+
+use std::collections::BTreeMap;
+
+use crate::error::{DomainError, DomainResult};
+
+/// 保持する条件の件数
+pub const MAX_RECENT_PARAMETER_SETS: usize = 5;
+
+/// 帳票の実行条件（パラメータ名と入力値）
+pub type ReportParameterValues = BTreeMap<String, String>;
+
+/// 帳票の最近使った条件
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReportParameterHistory {
+    user_id: String,
+    report_id: String,
+    /// 新しい順の実行条件
+    recent: Vec<ReportParameterValues>,
+}
+
+impl ReportParameterHistory {
+    pub fn new(
+        user_id: impl Into<String>,
+        report_id: impl Into<String>,
+        recent: Vec<ReportParameterValues>,
+    ) -> DomainResult<Self> {
+        let user_id = user_id.into();
+        let report_id = report_id.into();
+        if user_id.trim().is_empty() {
+            return Err(DomainError::ValidationError("利用者IDが空です".to_string()));
+        }
+        if report_id.trim().is_empty() {
+            return Err(DomainError::ValidationError("帳票IDが空です".to_string()));
+        }
+
+        let mut history = Self { user_id, report_id, recent: Vec::new() };
+        // 古い順に記録し直して重複と件数を整える
+        for values in recent.into_iter().rev() {
+            history.remember(values);
+        }
+        Ok(history)
+    }
+
+    pub fn user_id(&self) -> &str {
+        &self.user_id
+    }
+
+    pub fn report_id(&self) -> &str {
+        &self.report_id
+    }
+
+    pub fn recent(&self) -> &[ReportParameterValues] {
+        &self.recent
+    }
+
+    /// 実行した条件を記録
+    ///
+    /// 同じ条件は先頭へ移動し、保持件数を超えた古い条件は破棄する。
+    /// 条件が空の場合は記録しない。
+    pub fn remember(&mut self, values: ReportParameterValues) {
+        if values.is_empty() {
+            return;
+        }
+        self.recent.retain(|recent| *recent != values);
+        self.recent.insert(0, values);
+        self.recent.truncate(MAX_RECENT_PARAMETER_SETS);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn period(value: &str) -> ReportParameterValues {
+        BTreeMap::from([("period".to_string(), value.to_string())])
+    }
+
+    #[test]
+    fn test_remember_moves_duplicates_to_front_and_keeps_limit() {
+        let mut history = ReportParameterHistory::new("alice", "trial_balance", vec![]).unwrap();
+        history.remember(period("2024-03"));
+        history.remember(period("2024-02"));
+        history.remember(period("2024-03"));
+        history.remember(BTreeMap::new());
+        assert_eq!(history.recent(), &[period("2024-03"), period("2024-02")]);
+
+        for month in 4..=9 {
+            history.remember(period(&format!("2024-{:02}", month)));
+        }
+        assert_eq!(history.recent().len(), MAX_RECENT_PARAMETER_SETS);
+        assert_eq!(history.recent()[0], period("2024-09"));
+
+        assert!(ReportParameterHistory::new("", "trial_balance", vec![]).is_err());
+        assert!(ReportParameterHistory::new("alice", " ", vec![]).is_err());
+    }
+}
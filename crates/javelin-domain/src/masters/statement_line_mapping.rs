@@ -0,0 +1,181 @@
+// StatementLineMapping - 財務諸表表示科目マッピングドメイン
+// 責務: 勘定科目と財務諸表表示科目（BS/PL区分）の対応付け
+
+use super::account_master::{AccountCode, AccountType};
+use crate::error::{DomainError, DomainResult};
+
+/// 財務諸表の種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FinancialStatementKind {
+    /// 財政状態計算書（BS）
+    FinancialPosition,
+    /// 損益計算書（PL）
+    ProfitOrLoss,
+}
+
+/// 財務諸表表示科目
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum StatementLine {
+    CurrentAssets,
+    NonCurrentAssets,
+    CurrentLiabilities,
+    NonCurrentLiabilities,
+    Equity,
+    Revenue,
+    CostOfSales,
+    OperatingExpenses,
+    NonOperatingIncome,
+    NonOperatingExpenses,
+}
+
+impl StatementLine {
+    /// 全表示科目（表示順）
+    pub const ALL: [StatementLine; 10] = [
+        StatementLine::CurrentAssets,
+        StatementLine::NonCurrentAssets,
+        StatementLine::CurrentLiabilities,
+        StatementLine::NonCurrentLiabilities,
+        StatementLine::Equity,
+        StatementLine::Revenue,
+        StatementLine::CostOfSales,
+        StatementLine::OperatingExpenses,
+        StatementLine::NonOperatingIncome,
+        StatementLine::NonOperatingExpenses,
+    ];
+
+    /// 表示科目コード
+    pub fn code(&self) -> &'static str {
+        match self {
+            StatementLine::CurrentAssets => "BS-CA",
+            StatementLine::NonCurrentAssets => "BS-NCA",
+            StatementLine::CurrentLiabilities => "BS-CL",
+            StatementLine::NonCurrentLiabilities => "BS-NCL",
+            StatementLine::Equity => "BS-EQ",
+            StatementLine::Revenue => "PL-REV",
+            StatementLine::CostOfSales => "PL-COS",
+            StatementLine::OperatingExpenses => "PL-OPEX",
+            StatementLine::NonOperatingIncome => "PL-NOI",
+            StatementLine::NonOperatingExpenses => "PL-NOE",
+        }
+    }
+
+    /// 表示科目コードから生成
+    pub fn from_code(code: &str) -> DomainResult<Self> {
+        Self::ALL.into_iter().find(|line| line.code() == code).ok_or_else(|| {
+            DomainError::ValidationError(format!("不明な財務諸表表示科目です: {}", code))
+        })
+    }
+
+    /// 表示名
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            StatementLine::CurrentAssets => "流動資産",
+            StatementLine::NonCurrentAssets => "非流動資産",
+            StatementLine::CurrentLiabilities => "流動負債",
+            StatementLine::NonCurrentLiabilities => "非流動負債",
+            StatementLine::Equity => "資本",
+            StatementLine::Revenue => "売上収益",
+            StatementLine::CostOfSales => "売上原価",
+            StatementLine::OperatingExpenses => "販売費及び一般管理費",
+            StatementLine::NonOperatingIncome => "その他の収益",
+            StatementLine::NonOperatingExpenses => "その他の費用",
+        }
+    }
+
+    /// 所属する財務諸表
+    pub fn statement(&self) -> FinancialStatementKind {
+        match self {
+            StatementLine::CurrentAssets
+            | StatementLine::NonCurrentAssets
+            | StatementLine::CurrentLiabilities
+            | StatementLine::NonCurrentLiabilities
+            | StatementLine::Equity => FinancialStatementKind::FinancialPosition,
+            _ => FinancialStatementKind::ProfitOrLoss,
+        }
+    }
+
+    /// 所属する勘定科目区分（試算表の小計の上位区分）
+    pub fn account_type(&self) -> AccountType {
+        match self {
+            StatementLine::CurrentAssets | StatementLine::NonCurrentAssets => AccountType::Asset,
+            StatementLine::CurrentLiabilities | StatementLine::NonCurrentLiabilities => {
+                AccountType::Liability
+            }
+            StatementLine::Equity => AccountType::Equity,
+            StatementLine::Revenue | StatementLine::NonOperatingIncome => AccountType::Revenue,
+            StatementLine::CostOfSales
+            | StatementLine::OperatingExpenses
+            | StatementLine::NonOperatingExpenses => AccountType::Expense,
+        }
+    }
+
+    /// 借方残高を正とする表示科目か（資産・費用）
+    pub fn is_debit_normal(&self) -> bool {
+        matches!(
+            self,
+            StatementLine::CurrentAssets
+                | StatementLine::NonCurrentAssets
+                | StatementLine::CostOfSales
+                | StatementLine::OperatingExpenses
+                | StatementLine::NonOperatingExpenses
+        )
+    }
+
+    /// 試算表残高（借方プラス）を表示科目の符号に変換
+    pub fn presented_amount(&self, debit_balance: f64) -> f64 {
+        if self.is_debit_normal() {
+            debit_balance
+        } else {
+            -debit_balance
+        }
+    }
+}
+
+/// 勘定科目 → 財務諸表表示科目マッピング
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatementLineMapping {
+    account_code: AccountCode,
+    statement_line: StatementLine,
+}
+
+impl StatementLineMapping {
+    pub fn new(account_code: AccountCode, statement_line: StatementLine) -> Self {
+        Self { account_code, statement_line }
+    }
+
+    pub fn account_code(&self) -> &AccountCode {
+        &self.account_code
+    }
+
+    pub fn statement_line(&self) -> StatementLine {
+        self.statement_line
+    }
+
+    /// 表示科目を変更
+    pub fn reassign(&mut self, statement_line: StatementLine) {
+        self.statement_line = statement_line;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_statement_line_code_round_trip() {
+        for line in StatementLine::ALL {
+            assert_eq!(StatementLine::from_code(line.code()).unwrap(), line);
+        }
+        assert!(StatementLine::from_code("BS-XX").is_err());
+    }
+
+    #[test]
+    fn test_statement_line_sign_and_statement() {
+        assert_eq!(StatementLine::CurrentAssets.presented_amount(1000.0), 1000.0);
+        assert_eq!(StatementLine::Revenue.presented_amount(-1000.0), 1000.0);
+        assert_eq!(StatementLine::Equity.statement(), FinancialStatementKind::FinancialPosition);
+        assert_eq!(StatementLine::CostOfSales.statement(), FinancialStatementKind::ProfitOrLoss);
+        assert_eq!(StatementLine::NonCurrentAssets.account_type(), AccountType::Asset);
+        assert_eq!(StatementLine::NonOperatingIncome.account_type(), AccountType::Revenue);
+    }
+}
@@ -0,0 +1,161 @@
+// TablePreference - 一覧テーブルの表示設定
+// 責務: テーブルごとの列の表示・順序・幅と並び替えキーの保持
+
+use crate::error::{DomainError, DomainResult};
+
+/// 列幅の最小値
+pub const MIN_COLUMN_WIDTH: u16 = 4;
+/// 列幅の最大値
+pub const MAX_COLUMN_WIDTH: u16 = 80;
+
+/// 列の表示設定
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ColumnPreference {
+    /// テーブル定義上の列番号
+    column: usize,
+    visible: bool,
+    width: u16,
+}
+
+impl ColumnPreference {
+    pub fn new(column: usize, visible: bool, width: u16) -> DomainResult<Self> {
+        if !(MIN_COLUMN_WIDTH..=MAX_COLUMN_WIDTH).contains(&width) {
+            return Err(DomainError::ValidationError(format!(
+                "列幅は{}〜{}の範囲で指定してください: {}",
+                MIN_COLUMN_WIDTH, MAX_COLUMN_WIDTH, width
+            )));
+        }
+        Ok(Self { column, visible, width })
+    }
+
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    pub fn visible(&self) -> bool {
+        self.visible
+    }
+
+    pub fn width(&self) -> u16 {
+        self.width
+    }
+}
+
+/// 並び替えキー
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TableSort {
+    column: usize,
+    descending: bool,
+}
+
+impl TableSort {
+    pub fn new(column: usize, descending: bool) -> Self {
+        Self { column, descending }
+    }
+
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    pub fn descending(&self) -> bool {
+        self.descending
+    }
+}
+
+/// 一覧テーブルの表示設定
+///
+/// 列の並びは`columns`の順序で表す。各列番号は一度だけ現れ、
+/// 少なくとも1列は表示されていなければならない。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TablePreference {
+    table_id: String,
+    columns: Vec<ColumnPreference>,
+    sort: Option<TableSort>,
+}
+
+impl TablePreference {
+    pub fn new(
+        table_id: impl Into<String>,
+        columns: Vec<ColumnPreference>,
+        sort: Option<TableSort>,
+    ) -> DomainResult<Self> {
+        let table_id = table_id.into();
+        if table_id.is_empty() {
+            return Err(DomainError::ValidationError("テーブルIDが空です".to_string()));
+        }
+
+        let mut seen: Vec<usize> = columns.iter().map(|c| c.column()).collect();
+        seen.sort_unstable();
+        seen.dedup();
+        if seen.len() != columns.len() {
+            return Err(DomainError::ValidationError(format!(
+                "テーブル {} の列設定に重複があります",
+                table_id
+            )));
+        }
+
+        if !columns.iter().any(|c| c.visible()) {
+            return Err(DomainError::ValidationError(format!(
+                "テーブル {} には表示する列が必要です",
+                table_id
+            )));
+        }
+
+        if let Some(sort) = sort
+            && !columns.iter().any(|c| c.column() == sort.column())
+        {
+            return Err(DomainError::ValidationError(format!(
+                "テーブル {} の並び替え列 {} が存在しません",
+                table_id,
+                sort.column()
+            )));
+        }
+
+        Ok(Self { table_id, columns, sort })
+    }
+
+    pub fn table_id(&self) -> &str {
+        &self.table_id
+    }
+
+    pub fn columns(&self) -> &[ColumnPreference] {
+        &self.columns
+    }
+
+    pub fn sort(&self) -> Option<TableSort> {
+        self.sort
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn column(index: usize, visible: bool) -> ColumnPreference {
+        ColumnPreference::new(index, visible, 10).unwrap()
+    }
+
+    #[test]
+    fn test_column_width_range() {
+        assert!(ColumnPreference::new(0, true, MIN_COLUMN_WIDTH).is_ok());
+        assert!(ColumnPreference::new(0, true, MAX_COLUMN_WIDTH).is_ok());
+        assert!(ColumnPreference::new(0, true, MIN_COLUMN_WIDTH - 1).is_err());
+        assert!(ColumnPreference::new(0, true, MAX_COLUMN_WIDTH + 1).is_err());
+    }
+
+    #[test]
+    fn test_table_preference_validation() {
+        let columns = vec![column(1, true), column(0, false)];
+        let preference =
+            TablePreference::new("ledger", columns.clone(), Some(TableSort::new(0, true))).unwrap();
+        assert_eq!(preference.columns()[0].column(), 1);
+        assert_eq!(preference.sort(), Some(TableSort::new(0, true)));
+
+        assert!(TablePreference::new("", columns.clone(), None).is_err());
+        assert!(
+            TablePreference::new("ledger", vec![column(0, true), column(0, true)], None).is_err()
+        );
+        assert!(TablePreference::new("ledger", vec![column(0, false)], None).is_err());
+        assert!(TablePreference::new("ledger", columns, Some(TableSort::new(5, false))).is_err());
+    }
+}
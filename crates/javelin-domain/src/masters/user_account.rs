@@ -0,0 +1,190 @@
+// UserAccount - 利用者アカウントドメイン
+// 責務: ログイン資格情報（パスワードハッシュ）の管理と照合
+
+use chrono::{DateTime, Utc};
+
+use crate::error::{DomainError, DomainResult};
+
+/// パスワードの最小文字数
+pub const MIN_PASSWORD_LENGTH: usize = 8;
+
+/// ユーザIDの最大文字数
+const MAX_USER_ID_LENGTH: usize = 32;
+
+/// パスワードハッシュ（アルゴリズム・パラメータ・ソルトを含むエンコード済み文字列）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PasswordHash(String);
+
+impl PasswordHash {
+    pub fn new(encoded: impl Into<String>) -> DomainResult<Self> {
+        let encoded = encoded.into();
+        if encoded.is_empty() {
+            return Err(DomainError::ValidationError("パスワードハッシュが空です".to_string()));
+        }
+        Ok(Self(encoded))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// パスワードハッシュ化サービス
+///
+/// ハッシュ方式はInfrastructure層が決定する。
+/// 平文パスワードは保存・ログ出力しないこと。
+pub trait PasswordHasher: Send + Sync {
+    /// パスワードをソルト付きでハッシュ化
+    fn hash(&self, password: &str) -> DomainResult<PasswordHash>;
+
+    /// パスワードがハッシュと一致するか
+    fn verify(&self, password: &str, hash: &PasswordHash) -> DomainResult<bool>;
+}
+
+/// 利用者アカウント
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UserAccount {
+    user_id: String,
+    display_name: String,
+    password_hash: PasswordHash,
+    created_at: DateTime<Utc>,
+    last_login_at: Option<DateTime<Utc>>,
+}
+
+impl UserAccount {
+    /// 新規アカウントを作成
+    pub fn new(
+        user_id: impl Into<String>,
+        display_name: impl Into<String>,
+        password_hash: PasswordHash,
+    ) -> DomainResult<Self> {
+        let user_id = user_id.into();
+        Self::validate_user_id(&user_id)?;
+
+        let display_name = display_name.into();
+        let display_name = if display_name.trim().is_empty() {
+            user_id.clone()
+        } else {
+            display_name
+        };
+
+        Ok(Self {
+            user_id,
+            display_name,
+            password_hash,
+            created_at: Utc::now(),
+            last_login_at: None,
+        })
+    }
+
+    /// 保存済みの状態から復元
+    pub fn reconstruct(
+        user_id: String,
+        display_name: String,
+        password_hash: PasswordHash,
+        created_at: DateTime<Utc>,
+        last_login_at: Option<DateTime<Utc>>,
+    ) -> Self {
+        Self { user_id, display_name, password_hash, created_at, last_login_at }
+    }
+
+    /// ユーザIDの形式を検証（空白を含まない1〜32文字）
+    pub fn validate_user_id(user_id: &str) -> DomainResult<()> {
+        if user_id.is_empty() || user_id.chars().count() > MAX_USER_ID_LENGTH {
+            return Err(DomainError::ValidationError(format!(
+                "ユーザIDは1〜{}文字で入力してください",
+                MAX_USER_ID_LENGTH
+            )));
+        }
+        if user_id.chars().any(char::is_whitespace) {
+            return Err(DomainError::ValidationError("ユーザIDに空白は使用できません".to_string()));
+        }
+        Ok(())
+    }
+
+    /// パスワードの強度を検証（ハッシュ化前に呼び出す）
+    pub fn validate_password(password: &str) -> DomainResult<()> {
+        if password.chars().count() < MIN_PASSWORD_LENGTH {
+            return Err(DomainError::ValidationError(format!(
+                "パスワードは{}文字以上で入力してください",
+                MIN_PASSWORD_LENGTH
+            )));
+        }
+        Ok(())
+    }
+
+    pub fn user_id(&self) -> &str {
+        &self.user_id
+    }
+
+    pub fn display_name(&self) -> &str {
+        &self.display_name
+    }
+
+    pub fn password_hash(&self) -> &PasswordHash {
+        &self.password_hash
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    pub fn last_login_at(&self) -> Option<DateTime<Utc>> {
+        self.last_login_at
+    }
+
+    /// ログイン日時を記録（パスワード照合後に呼び出す）
+    pub fn record_login(&mut self, now: DateTime<Utc>) {
+        self.last_login_at = Some(now);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 平文に接頭辞を付けるだけのテスト用ハッシュ
+    struct PlainHasher;
+
+    impl PasswordHasher for PlainHasher {
+        fn hash(&self, password: &str) -> DomainResult<PasswordHash> {
+            PasswordHash::new(format!("plain${}", password))
+        }
+
+        fn verify(&self, password: &str, hash: &PasswordHash) -> DomainResult<bool> {
+            Ok(hash.as_str() == format!("plain${}", password))
+        }
+    }
+
+    #[test]
+    fn test_user_id_validation() {
+        assert!(UserAccount::validate_user_id("tanaka").is_ok());
+        assert!(UserAccount::validate_user_id("").is_err());
+        assert!(UserAccount::validate_user_id("tanaka taro").is_err());
+        assert!(UserAccount::validate_user_id(&"a".repeat(33)).is_err());
+    }
+
+    #[test]
+    fn test_password_validation() {
+        assert!(UserAccount::validate_password("passw0rd").is_ok());
+        assert!(UserAccount::validate_password("short").is_err());
+    }
+
+    #[test]
+    fn test_display_name_defaults_to_user_id() {
+        let hash = PlainHasher.hash("passw0rd").unwrap();
+        let account = UserAccount::new("tanaka", " ", hash).unwrap();
+        assert_eq!(account.display_name(), "tanaka");
+    }
+
+    #[test]
+    fn test_record_login() {
+        let hash = PlainHasher.hash("passw0rd").unwrap();
+        let mut account = UserAccount::new("tanaka", "田中", hash).unwrap();
+        assert_eq!(account.last_login_at(), None);
+
+        let now = Utc::now();
+        account.record_login(now);
+        assert_eq!(account.last_login_at(), Some(now));
+    }
+}
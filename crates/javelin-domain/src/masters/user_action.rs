@@ -0,0 +1,149 @@
+// UserAction - 利用者の操作記録
+// 責務: 画面の表示・操作の実行と所要時間の記録項目の定義と検証
+//
+// 操作記録は利用状況の分析に使う。入力値などの自由記述（パスワードや金額を
+// 含み得る）が記録に紛れ込まないよう、画面・操作は英数字の識別子に限る。
+
+use crate::error::{DomainError, DomainResult};
+
+/// 画面・操作の識別子の最大長
+pub const MAX_ACTION_IDENTIFIER_LENGTH: usize = 64;
+
+/// 画面を表示したことを表す操作名
+pub const SCREEN_VISIT_ACTION: &str = "visit";
+
+/// 操作の種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum UserActionKind {
+    /// 画面の表示（所要時間は表示していた時間）
+    ScreenVisited,
+    /// 操作の実行（所要時間は処理時間）
+    ActionPerformed,
+}
+
+impl UserActionKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UserActionKind::ScreenVisited => "screen_visited",
+            UserActionKind::ActionPerformed => "action_performed",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "screen_visited" => Some(UserActionKind::ScreenVisited),
+            "action_performed" => Some(UserActionKind::ActionPerformed),
+            _ => None,
+        }
+    }
+}
+
+/// 利用者の操作記録
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UserAction {
+    user_id: String,
+    kind: UserActionKind,
+    /// 画面の識別子（例: TrialBalance）
+    screen: String,
+    /// 操作の識別子（例: export, run.trial_balance）
+    action: String,
+    duration_ms: u64,
+}
+
+impl UserAction {
+    pub fn new(
+        user_id: impl Into<String>,
+        kind: UserActionKind,
+        screen: impl Into<String>,
+        action: impl Into<String>,
+        duration_ms: u64,
+    ) -> DomainResult<Self> {
+        let user_id = user_id.into();
+        if user_id.trim().is_empty() {
+            return Err(DomainError::ValidationError("利用者IDが空です".to_string()));
+        }
+        let screen = screen.into();
+        validate_identifier("画面", &screen)?;
+        let action = action.into();
+        validate_identifier("操作", &action)?;
+
+        Ok(Self { user_id, kind, screen, action, duration_ms })
+    }
+
+    /// 画面の表示
+    pub fn screen_visit(
+        user_id: impl Into<String>,
+        screen: impl Into<String>,
+        duration_ms: u64,
+    ) -> DomainResult<Self> {
+        Self::new(user_id, UserActionKind::ScreenVisited, screen, SCREEN_VISIT_ACTION, duration_ms)
+    }
+
+    pub fn user_id(&self) -> &str {
+        &self.user_id
+    }
+
+    pub fn kind(&self) -> UserActionKind {
+        self.kind
+    }
+
+    pub fn screen(&self) -> &str {
+        &self.screen
+    }
+
+    pub fn action(&self) -> &str {
+        &self.action
+    }
+
+    pub fn duration_ms(&self) -> u64 {
+        self.duration_ms
+    }
+}
+
+/// 識別子の検証（英数字と「_ . : -」のみ）
+fn validate_identifier(label: &str, value: &str) -> DomainResult<()> {
+    if value.is_empty() || value.len() > MAX_ACTION_IDENTIFIER_LENGTH {
+        return Err(DomainError::ValidationError(format!(
+            "{}の識別子は1〜{}文字で指定してください",
+            label, MAX_ACTION_IDENTIFIER_LENGTH
+        )));
+    }
+    if !value
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | ':' | '-'))
+    {
+        return Err(DomainError::ValidationError(format!(
+            "{}の識別子に使用できない文字が含まれています",
+            label
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_identifiers_reject_free_text() {
+        let visit = UserAction::screen_visit("alice", "TrialBalance", 1200).unwrap();
+        assert_eq!(visit.kind(), UserActionKind::ScreenVisited);
+        assert_eq!(visit.action(), SCREEN_VISIT_ACTION);
+        assert!(
+            UserAction::new("alice", UserActionKind::ActionPerformed, "Ledger", "export", 30)
+                .is_ok()
+        );
+
+        assert!(UserAction::screen_visit("", "TrialBalance", 0).is_err());
+        assert!(
+            UserAction::new("alice", UserActionKind::ActionPerformed, "Login", "password=abc d", 0)
+                .is_err()
+        );
+        assert!(UserAction::screen_visit("alice", "画面", 0).is_err());
+        assert!(UserAction::screen_visit("alice", "a".repeat(65), 0).is_err());
+
+        for kind in [UserActionKind::ScreenVisited, UserActionKind::ActionPerformed] {
+            assert_eq!(UserActionKind::parse(kind.as_str()), Some(kind));
+        }
+    }
+}
@@ -0,0 +1,246 @@
+// UserSession - ログインセッションの記録
+// 責務: ログイン・ログイン失敗・ログアウト・強制ログアウトのイベントと、
+//       イベントから復元したセッションの状態
+//
+// セッションのイベントは監査証跡としてイベントストアの専用ストリームに追記する。
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::error::{DomainError, DomainResult};
+
+/// セッションのイベントを追記するストリーム
+pub const SESSION_LOG_STREAM: &str = "user-sessions";
+
+/// セッションのイベント
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "type")]
+pub enum SessionEvent {
+    /// ログインに成功し、セッションを開始した
+    SessionStarted { session_id: String, user_id: String, started_at: DateTime<Utc> },
+
+    /// ログインに失敗した（存在しないユーザIDも記録する）
+    LoginFailed { user_id: String, reason: String, failed_at: DateTime<Utc> },
+
+    /// 利用者がセッションを終了した
+    SessionEnded { session_id: String, user_id: String, ended_at: DateTime<Utc> },
+
+    /// 管理者がセッションを強制終了した
+    SessionForcedOut {
+        session_id: String,
+        user_id: String,
+        forced_by: String,
+        forced_at: DateTime<Utc>,
+    },
+}
+
+/// セッションの終了理由
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SessionClosure {
+    /// 利用者による終了
+    Ended { ended_at: DateTime<Utc> },
+    /// 管理者による強制終了
+    ForcedOut { forced_by: String, forced_at: DateTime<Utc> },
+}
+
+/// ログインセッション
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UserSession {
+    session_id: String,
+    user_id: String,
+    started_at: DateTime<Utc>,
+    closure: Option<SessionClosure>,
+}
+
+impl UserSession {
+    /// ログインしたセッションを開始する（追記するイベントを返す）
+    pub fn start(
+        session_id: impl Into<String>,
+        user_id: impl Into<String>,
+        started_at: DateTime<Utc>,
+    ) -> SessionEvent {
+        SessionEvent::SessionStarted {
+            session_id: session_id.into(),
+            user_id: user_id.into(),
+            started_at,
+        }
+    }
+
+    /// 利用者がセッションを終了する（終了済みの場合はNone）
+    pub fn end(&self, ended_at: DateTime<Utc>) -> Option<SessionEvent> {
+        self.is_active().then(|| SessionEvent::SessionEnded {
+            session_id: self.session_id.clone(),
+            user_id: self.user_id.clone(),
+            ended_at,
+        })
+    }
+
+    /// 管理者がセッションを強制終了する
+    ///
+    /// 終了済みのセッションと、操作している管理者自身のセッションは対象外。
+    pub fn force_out(
+        &self,
+        forced_by: impl Into<String>,
+        forced_at: DateTime<Utc>,
+    ) -> DomainResult<SessionEvent> {
+        let forced_by = forced_by.into();
+        if !self.is_active() {
+            return Err(DomainError::ValidationError(format!(
+                "セッションは終了しています: {}",
+                self.session_id
+            )));
+        }
+        if self.user_id == forced_by {
+            return Err(DomainError::ValidationError(
+                "自分のセッションは強制ログアウトできません".to_string(),
+            ));
+        }
+        Ok(SessionEvent::SessionForcedOut {
+            session_id: self.session_id.clone(),
+            user_id: self.user_id.clone(),
+            forced_by,
+            forced_at,
+        })
+    }
+
+    pub fn session_id(&self) -> &str {
+        &self.session_id
+    }
+
+    pub fn user_id(&self) -> &str {
+        &self.user_id
+    }
+
+    pub fn started_at(&self) -> DateTime<Utc> {
+        self.started_at
+    }
+
+    pub fn closure(&self) -> Option<&SessionClosure> {
+        self.closure.as_ref()
+    }
+
+    /// 終了していないか
+    pub fn is_active(&self) -> bool {
+        self.closure.is_none()
+    }
+}
+
+/// ログインに失敗した試行
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FailedLogin {
+    pub user_id: String,
+    pub reason: String,
+    pub failed_at: DateTime<Utc>,
+}
+
+/// セッションの記録（イベントから復元）
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SessionLog {
+    /// 開始順
+    sessions: Vec<UserSession>,
+    /// 発生順
+    failed_logins: Vec<FailedLogin>,
+}
+
+impl SessionLog {
+    pub fn replay<'a>(events: impl IntoIterator<Item = &'a SessionEvent>) -> Self {
+        let mut log = Self::default();
+        for event in events {
+            match event {
+                SessionEvent::SessionStarted { session_id, user_id, started_at } => {
+                    log.sessions.push(UserSession {
+                        session_id: session_id.clone(),
+                        user_id: user_id.clone(),
+                        started_at: *started_at,
+                        closure: None,
+                    });
+                }
+                SessionEvent::LoginFailed { user_id, reason, failed_at } => {
+                    log.failed_logins.push(FailedLogin {
+                        user_id: user_id.clone(),
+                        reason: reason.clone(),
+                        failed_at: *failed_at,
+                    });
+                }
+                SessionEvent::SessionEnded { session_id, ended_at, .. } => {
+                    log.close(session_id, SessionClosure::Ended { ended_at: *ended_at });
+                }
+                SessionEvent::SessionForcedOut { session_id, forced_by, forced_at, .. } => {
+                    log.close(
+                        session_id,
+                        SessionClosure::ForcedOut {
+                            forced_by: forced_by.clone(),
+                            forced_at: *forced_at,
+                        },
+                    );
+                }
+            }
+        }
+        log
+    }
+
+    /// 最初の終了のみを記録する
+    fn close(&mut self, session_id: &str, closure: SessionClosure) {
+        if let Some(session) =
+            self.sessions.iter_mut().find(|s| s.session_id == session_id && s.is_active())
+        {
+            session.closure = Some(closure);
+        }
+    }
+
+    pub fn find(&self, session_id: &str) -> Option<&UserSession> {
+        self.sessions.iter().find(|s| s.session_id == session_id)
+    }
+
+    /// 終了していないセッション（開始順）
+    pub fn active_sessions(&self) -> impl Iterator<Item = &UserSession> {
+        self.sessions.iter().filter(|s| s.is_active())
+    }
+
+    /// 新しい順のセッション
+    pub fn recent_sessions(&self) -> impl Iterator<Item = &UserSession> {
+        self.sessions.iter().rev()
+    }
+
+    /// 新しい順のログイン失敗
+    pub fn recent_failed_logins(&self) -> impl Iterator<Item = &FailedLogin> {
+        self.failed_logins.iter().rev()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_session_lifecycle() {
+        let at = Utc::now();
+        let started = UserSession::start("S-1", "tanaka", at);
+        let admin = UserSession::start("S-2", "admin", at);
+        let failed = SessionEvent::LoginFailed {
+            user_id: "suzuki".to_string(),
+            reason: "パスワード不一致".to_string(),
+            failed_at: at,
+        };
+        let log = SessionLog::replay([&started, &admin, &failed]);
+        assert_eq!(log.active_sessions().count(), 2);
+        assert_eq!(log.recent_failed_logins().next().unwrap().user_id, "suzuki");
+
+        // 自分のセッションは強制終了できない
+        let session = log.find("S-2").unwrap();
+        assert!(session.force_out("admin", at).is_err());
+
+        let forced = log.find("S-1").unwrap().force_out("admin", at).unwrap();
+        let log = SessionLog::replay([&started, &admin, &failed, &forced]);
+        let session = log.find("S-1").unwrap();
+        assert!(!session.is_active());
+        assert!(matches!(
+            session.closure(),
+            Some(SessionClosure::ForcedOut { forced_by, .. }) if forced_by == "admin"
+        ));
+        assert!(session.force_out("admin", at).is_err());
+        assert!(session.end(at).is_none());
+        assert_eq!(log.active_sessions().count(), 1);
+        assert_eq!(log.recent_sessions().next().unwrap().session_id(), "S-2");
+    }
+}
@@ -2,15 +2,51 @@
 // 必須操作: append / loadStream
 // 禁止: 詳細なQuery機能
 pub mod account_master_repository;
+pub mod account_reconciliation_repository;
+pub mod accounting_policy_repository;
 pub mod application_settings_repository;
+pub mod calendar_master_repository;
+pub mod closing_timetable_repository;
 pub mod company_master_repository;
+pub mod dimension_master_repository;
 pub mod event_repository;
+pub mod financial_instrument_repository;
+pub mod inventory_worksheet_repository;
+pub mod job_repository;
+pub mod journal_import_template_repository;
+pub mod management_account_mapping_repository;
+pub mod note_cross_reference_repository;
+pub mod report_archive_repository;
+pub mod report_parameter_history_repository;
+pub mod statement_line_mapping_repository;
 pub mod subsidiary_account_master_repository;
+pub mod supplier_invoice_repository;
+pub mod table_preference_repository;
+pub mod user_account_repository;
 pub mod user_action_repository;
+pub mod variance_commentary_repository;
 
 pub use account_master_repository::*;
+pub use account_reconciliation_repository::*;
+pub use accounting_policy_repository::*;
 pub use application_settings_repository::*;
+pub use calendar_master_repository::*;
+pub use closing_timetable_repository::*;
 pub use company_master_repository::*;
+pub use dimension_master_repository::*;
 pub use event_repository::*;
+pub use financial_instrument_repository::*;
+pub use inventory_worksheet_repository::*;
+pub use job_repository::*;
+pub use journal_import_template_repository::*;
+pub use management_account_mapping_repository::*;
+pub use note_cross_reference_repository::*;
+pub use report_archive_repository::*;
+pub use report_parameter_history_repository::*;
+pub use statement_line_mapping_repository::*;
 pub use subsidiary_account_master_repository::*;
+pub use supplier_invoice_repository::*;
+pub use table_preference_repository::*;
+pub use user_account_repository::*;
 pub use user_action_repository::*;
+pub use variance_commentary_repository::*;
@@ -2,7 +2,7 @@
 
 use crate::{
     error::DomainResult,
-    masters::{AccountCode, AccountMaster},
+    masters::{AccountCode, AccountMaster, MasterChange},
 };
 
 /// 勘定科目マスタリポジトリトレイト
@@ -19,4 +19,15 @@ pub trait AccountMasterRepository: Send + Sync {
 
     /// 勘定科目マスタを削除
     async fn delete(&self, code: &AccountCode) -> DomainResult<()>;
+
+    /// 変更イベントを記録し、変更後のイメージを勘定科目マスタへ反映（同一トランザクション）
+    ///
+    /// 同じ連番の変更イベントが記録済みの場合（同時に変更された場合）はエラー。
+    async fn apply_change(&self, change: &MasterChange<AccountMaster>) -> DomainResult<()>;
+
+    /// 勘定科目マスタの変更イベントを記録順に取得
+    async fn find_changes(
+        &self,
+        code: &AccountCode,
+    ) -> DomainResult<Vec<MasterChange<AccountMaster>>>;
 }
@@ -0,0 +1,31 @@
+// AccountReconciliationRepository - 勘定照合リポジトリトレイト
+
+use crate::{error::DomainResult, financial_close::account_reconciliation::AccountReconciliation};
+
+/// 勘定照合リポジトリトレイト
+#[allow(async_fn_in_trait)]
+pub trait AccountReconciliationRepository: Send + Sync {
+    /// 科目・会計期間の照合ワークスペースを取得
+    async fn find(
+        &self,
+        account_code: &str,
+        fiscal_year: i32,
+        period: u8,
+    ) -> DomainResult<Option<AccountReconciliation>>;
+
+    /// 会計期間の照合ワークスペースを科目コード順に取得
+    async fn find_by_period(
+        &self,
+        fiscal_year: i32,
+        period: u8,
+    ) -> DomainResult<Vec<AccountReconciliation>>;
+
+    /// 照合ワークスペースを保存（同じ科目・会計期間は置き換える）
+    async fn save(&self, reconciliation: &AccountReconciliation) -> DomainResult<()>;
+
+    /// 締日固定の前に照合が必要な科目コードを取得（科目コード順）
+    async fn required_accounts(&self) -> DomainResult<Vec<String>>;
+
+    /// 締日固定の前に照合が必要な科目コードを保存
+    async fn save_required_accounts(&self, account_codes: &[String]) -> DomainResult<()>;
+}
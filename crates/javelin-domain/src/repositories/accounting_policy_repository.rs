@@ -0,0 +1,23 @@
+// AccountingPolicyRepository - 会計方針設定リポジトリトレイト
+
+use crate::{
+    error::DomainResult,
+    masters::{AccountingPolicy, AccountingPolicyChanged},
+};
+
+/// 会計方針設定リポジトリトレイト
+#[allow(async_fn_in_trait)]
+pub trait AccountingPolicyRepository: Send + Sync {
+    /// 会計方針を取得（未保存の場合は既定の方針）
+    async fn load(&self) -> DomainResult<AccountingPolicy>;
+
+    /// 会計方針と変更イベントを同一トランザクションで保存
+    async fn save(
+        &self,
+        policy: &AccountingPolicy,
+        change: &AccountingPolicyChanged,
+    ) -> DomainResult<()>;
+
+    /// 変更イベントを記録順に取得
+    async fn find_changes(&self) -> DomainResult<Vec<AccountingPolicyChanged>>;
+}
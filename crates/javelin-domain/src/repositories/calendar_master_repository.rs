@@ -0,0 +1,21 @@
+// CalendarMasterRepository - カレンダーマスタリポジトリトレイト
+
+use chrono::NaiveDate;
+
+use crate::{error::DomainResult, masters::CalendarMaster};
+
+/// カレンダーマスタリポジトリトレイト
+#[allow(async_fn_in_trait)]
+pub trait CalendarMasterRepository: Send + Sync {
+    /// 指定日の休日を取得
+    async fn find_by_date(&self, date: NaiveDate) -> DomainResult<Option<CalendarMaster>>;
+
+    /// すべての休日を取得（日付順）
+    async fn find_all(&self) -> DomainResult<Vec<CalendarMaster>>;
+
+    /// 休日を保存
+    async fn save(&self, calendar_master: &CalendarMaster) -> DomainResult<()>;
+
+    /// 休日を削除
+    async fn delete(&self, date: NaiveDate) -> DomainResult<()>;
+}
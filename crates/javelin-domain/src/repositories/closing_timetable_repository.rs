@@ -0,0 +1,29 @@
+// ClosingTimetableRepository - 締めスケジュールリポジトリトレイト
+
+use crate::{
+    error::DomainResult,
+    masters::{ClosingTaskProgress, ClosingTimetableItem},
+};
+
+/// 締めスケジュール（タスク定義と期間ごとの進捗）のリポジトリトレイト
+#[allow(async_fn_in_trait)]
+pub trait ClosingTimetableRepository: Send + Sync {
+    /// すべてのタスクを取得（タスクコードの昇順）
+    async fn find_items(&self) -> DomainResult<Vec<ClosingTimetableItem>>;
+
+    /// タスクを保存（同じタスクコードは置き換える）
+    async fn save_item(&self, item: &ClosingTimetableItem) -> DomainResult<()>;
+
+    /// タスクを削除
+    async fn delete_item(&self, code: &str) -> DomainResult<()>;
+
+    /// 期間の進捗を取得
+    async fn find_progress(
+        &self,
+        fiscal_year: i32,
+        period: u8,
+    ) -> DomainResult<Vec<ClosingTaskProgress>>;
+
+    /// 進捗を保存（同じ期間・タスクコードは置き換える）
+    async fn save_progress(&self, progress: &ClosingTaskProgress) -> DomainResult<()>;
+}
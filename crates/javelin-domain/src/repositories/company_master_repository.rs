@@ -2,7 +2,7 @@
 
 use crate::{
     error::DomainResult,
-    masters::{CompanyCode, CompanyMaster},
+    masters::{CompanyCode, CompanyMaster, MasterChange},
 };
 
 /// 会社マスタリポジトリトレイト
@@ -19,4 +19,15 @@ pub trait CompanyMasterRepository: Send + Sync {
 
     /// 会社マスタを削除
     async fn delete(&self, code: &CompanyCode) -> DomainResult<()>;
+
+    /// 変更イベントを記録し、変更後のイメージを会社マスタへ反映（同一トランザクション）
+    ///
+    /// 同じ連番の変更イベントが記録済みの場合（同時に変更された場合）はエラー。
+    async fn apply_change(&self, change: &MasterChange<CompanyMaster>) -> DomainResult<()>;
+
+    /// 会社マスタの変更イベントを記録順に取得
+    async fn find_changes(
+        &self,
+        code: &CompanyCode,
+    ) -> DomainResult<Vec<MasterChange<CompanyMaster>>>;
 }
@@ -0,0 +1,19 @@
+// DimensionMasterRepository - 分析軸マスタリポジトリトレイト
+
+use crate::{error::DomainResult, masters::DimensionMaster};
+
+/// 分析軸マスタリポジトリトレイト
+#[allow(async_fn_in_trait)]
+pub trait DimensionMasterRepository: Send + Sync {
+    /// 種類とコードで分析軸を取得
+    async fn find(&self, kind: &str, code: &str) -> DomainResult<Option<DimensionMaster>>;
+
+    /// すべての分析軸を取得（種類・コードの昇順）
+    async fn find_all(&self) -> DomainResult<Vec<DimensionMaster>>;
+
+    /// 分析軸を保存
+    async fn save(&self, master: &DimensionMaster) -> DomainResult<()>;
+
+    /// 分析軸を削除
+    async fn delete(&self, kind: &str, code: &str) -> DomainResult<()>;
+}
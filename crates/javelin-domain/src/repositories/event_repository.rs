@@ -27,6 +27,19 @@ pub trait EventRepository: Send + Sync {
     where
         T: serde::Serialize + Send + 'static;
 
+    /// 複数集約のイベントを一括追記
+    ///
+    /// すべての集約のイベントを同一トランザクションで保存する（一部だけが保存されることはない）。
+    ///
+    /// # Arguments
+    /// * `batches` - 集約IDと保存するドメインイベントのリストの組（保存順）
+    ///
+    /// # Returns
+    /// 最後に保存されたイベントのシーケンス番号
+    async fn append_batches<T>(&self, batches: Vec<(String, Vec<T>)>) -> DomainResult<u64>
+    where
+        T: serde::Serialize + Send + 'static;
+
     /// 指定された集約IDのイベントストリームを取得
     ///
     /// # Arguments
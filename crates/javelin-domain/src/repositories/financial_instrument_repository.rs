@@ -0,0 +1,16 @@
+// FinancialInstrumentRepository - 金融商品リポジトリトレイト
+
+use crate::{error::DomainResult, financial_close::financial_instrument::FinancialInstrument};
+
+/// 金融商品リポジトリトレイト
+#[allow(async_fn_in_trait)]
+pub trait FinancialInstrumentRepository: Send + Sync {
+    /// 金融商品を取得
+    async fn find(&self, instrument_id: &str) -> DomainResult<Option<FinancialInstrument>>;
+
+    /// すべての金融商品を金融商品ID順に取得
+    async fn find_all(&self) -> DomainResult<Vec<FinancialInstrument>>;
+
+    /// 金融商品を保存（同じ金融商品IDは置き換える）
+    async fn save(&self, instrument: &FinancialInstrument) -> DomainResult<()>;
+}
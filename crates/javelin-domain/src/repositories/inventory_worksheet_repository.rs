@@ -0,0 +1,19 @@
+// InventoryWorksheetRepository - 棚卸資産評価ワークシートリポジトリトレイト
+
+use crate::{
+    error::DomainResult, financial_close::inventory_valuation::InventoryWriteDownWorksheet,
+};
+
+/// 棚卸資産評価ワークシートリポジトリトレイト
+#[allow(async_fn_in_trait)]
+pub trait InventoryWorksheetRepository: Send + Sync {
+    /// 会計期間のワークシートを取得
+    async fn find(
+        &self,
+        fiscal_year: i32,
+        period: u8,
+    ) -> DomainResult<Option<InventoryWriteDownWorksheet>>;
+
+    /// ワークシートを保存（同じ会計期間のワークシートは置き換える）
+    async fn save(&self, worksheet: &InventoryWriteDownWorksheet) -> DomainResult<()>;
+}
@@ -0,0 +1,16 @@
+// JobRepository - 長時間処理ジョブリポジトリトレイト
+
+use crate::{error::DomainResult, job::Job};
+
+/// 長時間処理ジョブリポジトリトレイト
+#[allow(async_fn_in_trait)]
+pub trait JobRepository: Send + Sync {
+    /// ジョブを保存（同じジョブIDは上書き）
+    async fn save(&self, job: &Job) -> DomainResult<()>;
+
+    /// ジョブIDで取得
+    async fn find_by_id(&self, job_id: &str) -> DomainResult<Option<Job>>;
+
+    /// すべてのジョブを取得
+    async fn find_all(&self) -> DomainResult<Vec<Job>>;
+}
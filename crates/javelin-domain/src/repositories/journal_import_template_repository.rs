@@ -0,0 +1,16 @@
+// JournalImportTemplateRepository - 仕訳取込テンプレートリポジトリトレイト
+
+use crate::{error::DomainResult, masters::JournalImportTemplate};
+
+/// 仕訳取込テンプレートリポジトリトレイト
+#[allow(async_fn_in_trait)]
+pub trait JournalImportTemplateRepository: Send + Sync {
+    /// 見出し行のシグネチャでテンプレートを取得
+    async fn find_by_signature(
+        &self,
+        signature: &str,
+    ) -> DomainResult<Option<JournalImportTemplate>>;
+
+    /// テンプレートを保存（同じシグネチャは置き換える）
+    async fn save(&self, template: &JournalImportTemplate) -> DomainResult<()>;
+}
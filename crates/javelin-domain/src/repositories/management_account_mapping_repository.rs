@@ -0,0 +1,25 @@
+// ManagementAccountMappingRepository - 管理会計科目マッピングリポジトリトレイト
+
+use crate::{
+    error::DomainResult,
+    masters::{AccountCode, ManagementAccountMapping},
+};
+
+/// 管理会計科目マッピングリポジトリトレイト
+#[allow(async_fn_in_trait)]
+pub trait ManagementAccountMappingRepository: Send + Sync {
+    /// 勘定科目のマッピングを取得
+    async fn find_by_account(
+        &self,
+        account_code: &AccountCode,
+    ) -> DomainResult<Option<ManagementAccountMapping>>;
+
+    /// すべてのマッピングを取得
+    async fn find_all(&self) -> DomainResult<Vec<ManagementAccountMapping>>;
+
+    /// マッピングを保存
+    async fn save(&self, mapping: &ManagementAccountMapping) -> DomainResult<()>;
+
+    /// マッピングを削除
+    async fn delete(&self, account_code: &AccountCode) -> DomainResult<()>;
+}
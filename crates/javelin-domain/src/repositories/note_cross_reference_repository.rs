@@ -0,0 +1,32 @@
+// NoteCrossReferenceRepository - 注記参照リポジトリトレイト
+
+use crate::{
+    error::DomainResult,
+    masters::{NoteCrossReference, NoteReferenceTarget, NoteSection},
+};
+
+/// 注記と注記参照のリポジトリトレイト
+#[allow(async_fn_in_trait)]
+pub trait NoteCrossReferenceRepository: Send + Sync {
+    /// すべての注記を取得（注記番号の昇順）
+    async fn find_sections(&self) -> DomainResult<Vec<NoteSection>>;
+
+    /// 注記を保存（同じ注記番号は置き換える）
+    async fn save_section(&self, section: &NoteSection) -> DomainResult<()>;
+
+    /// 注記を削除
+    async fn delete_section(&self, number: u32) -> DomainResult<()>;
+
+    /// すべての注記参照を取得
+    async fn find_references(&self) -> DomainResult<Vec<NoteCrossReference>>;
+
+    /// 注記参照を保存（同じ参照先・注記番号は置き換える）
+    async fn save_reference(&self, reference: &NoteCrossReference) -> DomainResult<()>;
+
+    /// 注記参照を削除
+    async fn delete_reference(
+        &self,
+        target: &NoteReferenceTarget,
+        note_number: u32,
+    ) -> DomainResult<()>;
+}
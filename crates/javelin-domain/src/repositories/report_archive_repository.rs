@@ -0,0 +1,18 @@
+// ReportArchiveRepository - 帳票アーカイブリポジトリトレイト
+
+use crate::{error::DomainResult, financial_close::report_archive::ArchivedReport};
+
+/// 帳票アーカイブリポジトリトレイト
+///
+/// アーカイブ済みの帳票は追記のみとし、更新・削除の操作は提供しない。
+#[allow(async_fn_in_trait)]
+pub trait ReportArchiveRepository: Send + Sync {
+    /// 帳票を追加（同じアーカイブIDが存在する場合はエラー）
+    async fn append(&self, report: &ArchivedReport) -> DomainResult<()>;
+
+    /// アーカイブIDで取得
+    async fn find_by_id(&self, archive_id: &str) -> DomainResult<Option<ArchivedReport>>;
+
+    /// すべてのアーカイブ済み帳票を取得
+    async fn find_all(&self) -> DomainResult<Vec<ArchivedReport>>;
+}
@@ -0,0 +1,17 @@
+// ReportParameterHistoryRepository - 帳票の最近使った条件リポジトリトレイト
+
+use crate::{error::DomainResult, masters::ReportParameterHistory};
+
+/// 帳票の最近使った条件リポジトリトレイト
+#[allow(async_fn_in_trait)]
+pub trait ReportParameterHistoryRepository: Send + Sync {
+    /// 利用者・帳票の最近使った条件を取得
+    async fn find(
+        &self,
+        user_id: &str,
+        report_id: &str,
+    ) -> DomainResult<Option<ReportParameterHistory>>;
+
+    /// 最近使った条件を保存
+    async fn save(&self, history: &ReportParameterHistory) -> DomainResult<()>;
+}
@@ -0,0 +1,25 @@
+// StatementLineMappingRepository - 財務諸表表示科目マッピングリポジトリトレイト
+
+use crate::{
+    error::DomainResult,
+    masters::{AccountCode, StatementLineMapping},
+};
+
+/// 財務諸表表示科目マッピングリポジトリトレイト
+#[allow(async_fn_in_trait)]
+pub trait StatementLineMappingRepository: Send + Sync {
+    /// 勘定科目のマッピングを取得
+    async fn find_by_account(
+        &self,
+        account_code: &AccountCode,
+    ) -> DomainResult<Option<StatementLineMapping>>;
+
+    /// すべてのマッピングを取得
+    async fn find_all(&self) -> DomainResult<Vec<StatementLineMapping>>;
+
+    /// マッピングを保存
+    async fn save(&self, mapping: &StatementLineMapping) -> DomainResult<()>;
+
+    /// マッピングを削除
+    async fn delete(&self, account_code: &AccountCode) -> DomainResult<()>;
+}
@@ -0,0 +1,20 @@
+// SupplierInvoiceRepository - 仕入先請求書リポジトリトレイト
+
+use crate::{error::DomainResult, financial_close::supplier_invoice::SupplierInvoice};
+
+/// 仕入先請求書リポジトリトレイト
+#[allow(async_fn_in_trait)]
+pub trait SupplierInvoiceRepository: Send + Sync {
+    /// 仕入先と請求書番号で請求書を取得
+    async fn find(
+        &self,
+        counterparty: &str,
+        invoice_number: &str,
+    ) -> DomainResult<Option<SupplierInvoice>>;
+
+    /// すべての請求書を仕入先・請求書番号順に取得
+    async fn find_all(&self) -> DomainResult<Vec<SupplierInvoice>>;
+
+    /// 請求書を保存（同じ仕入先・請求書番号は置き換える）
+    async fn save(&self, invoice: &SupplierInvoice) -> DomainResult<()>;
+}
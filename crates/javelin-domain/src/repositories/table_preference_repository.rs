@@ -0,0 +1,13 @@
+// TablePreferenceRepository - 一覧テーブル表示設定リポジトリトレイト
+
+use crate::{error::DomainResult, masters::TablePreference};
+
+/// 一覧テーブル表示設定リポジトリトレイト
+#[allow(async_fn_in_trait)]
+pub trait TablePreferenceRepository: Send + Sync {
+    /// テーブルIDで表示設定を取得
+    async fn find(&self, table_id: &str) -> DomainResult<Option<TablePreference>>;
+
+    /// 表示設定を保存
+    async fn save(&self, preference: &TablePreference) -> DomainResult<()>;
+}
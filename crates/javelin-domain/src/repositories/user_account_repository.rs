@@ -0,0 +1,16 @@
+// UserAccountRepository - 利用者アカウントリポジトリトレイト
+
+use crate::{error::DomainResult, masters::UserAccount};
+
+/// 利用者アカウントリポジトリトレイト
+#[allow(async_fn_in_trait)]
+pub trait UserAccountRepository: Send + Sync {
+    /// ユーザIDでアカウントを取得
+    async fn find_by_id(&self, user_id: &str) -> DomainResult<Option<UserAccount>>;
+
+    /// アカウントを保存（既存の場合は上書き）
+    async fn save(&self, account: &UserAccount) -> DomainResult<()>;
+
+    /// 登録済みアカウント数
+    async fn count(&self) -> DomainResult<usize>;
+}
@@ -0,0 +1,20 @@
+// VarianceCommentaryRepository - 増減コメントリポジトリトレイト
+
+use crate::{error::DomainResult, financial_close::variance_commentary::VarianceCommentary};
+
+/// 増減コメントリポジトリトレイト
+#[allow(async_fn_in_trait)]
+pub trait VarianceCommentaryRepository: Send + Sync {
+    /// 会計期間の増減コメントを科目コード順に取得
+    async fn find_by_period(
+        &self,
+        fiscal_year: i32,
+        period: u8,
+    ) -> DomainResult<Vec<VarianceCommentary>>;
+
+    /// 増減コメントを保存（同じ科目・会計期間は置き換える）
+    async fn save(&self, commentary: &VarianceCommentary) -> DomainResult<()>;
+
+    /// 科目・会計期間の増減コメントを削除
+    async fn delete(&self, account_code: &str, fiscal_year: i32, period: u8) -> DomainResult<()>;
+}
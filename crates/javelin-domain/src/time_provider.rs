@@ -0,0 +1,140 @@
+// TimeProvider - 現在時刻と業務日付の提供
+// 責務: イベント時刻（UTC）と業務日付（設定タイムゾーン）の基準を一箇所に集約
+//
+// イベント時刻はUTCで記録し、業務日付（取引日付の既定値・会計期間の判定）は
+// 設定したタイムゾーンの暦日で求める。テストでは固定時刻の実装を注入する。
+
+use chrono::{DateTime, FixedOffset, Local, NaiveDate, Offset, Utc};
+
+use crate::error::{DomainError, DomainResult};
+
+/// 現在時刻と業務日付の提供元
+pub trait TimeProvider: Send + Sync {
+    /// 現在時刻（UTC）
+    fn now(&self) -> DateTime<Utc>;
+
+    /// 業務日付・表示に用いるタイムゾーン
+    fn time_zone(&self) -> FixedOffset;
+
+    /// 指定時刻の業務日付（設定タイムゾーンでの暦日）
+    fn business_date_of(&self, at: DateTime<Utc>) -> NaiveDate {
+        at.with_timezone(&self.time_zone()).date_naive()
+    }
+
+    /// 現在の業務日付
+    fn business_date(&self) -> NaiveDate {
+        self.business_date_of(self.now())
+    }
+}
+
+/// システム時計を用いるTimeProvider
+#[derive(Debug, Clone, Copy)]
+pub struct SystemTimeProvider {
+    time_zone: FixedOffset,
+}
+
+impl SystemTimeProvider {
+    /// 指定タイムゾーンで作成
+    pub fn new(time_zone: FixedOffset) -> Self {
+        Self { time_zone }
+    }
+
+    /// 実行環境のローカルタイムゾーン（起動時点のオフセット）で作成
+    pub fn local() -> Self {
+        Self::new(Local::now().offset().fix())
+    }
+}
+
+impl Default for SystemTimeProvider {
+    fn default() -> Self {
+        Self::local()
+    }
+}
+
+impl TimeProvider for SystemTimeProvider {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+
+    fn time_zone(&self) -> FixedOffset {
+        self.time_zone
+    }
+}
+
+/// 常に同じ時刻を返すTimeProvider（テスト用）
+#[derive(Debug, Clone, Copy)]
+pub struct FixedTimeProvider {
+    now: DateTime<Utc>,
+    time_zone: FixedOffset,
+}
+
+impl FixedTimeProvider {
+    pub fn new(now: DateTime<Utc>, time_zone: FixedOffset) -> Self {
+        Self { now, time_zone }
+    }
+}
+
+impl TimeProvider for FixedTimeProvider {
+    fn now(&self) -> DateTime<Utc> {
+        self.now
+    }
+
+    fn time_zone(&self) -> FixedOffset {
+        self.time_zone
+    }
+}
+
+/// UTCオフセット表記（`+09:00` / `-05:00` / `Z`）を解析
+pub fn parse_utc_offset(value: &str) -> DomainResult<FixedOffset> {
+    let invalid = || {
+        DomainError::ValidationError(format!(
+            "タイムゾーンは +HH:MM 形式で指定してください: {}",
+            value
+        ))
+    };
+
+    let value = value.trim();
+    if value.eq_ignore_ascii_case("z") || value.eq_ignore_ascii_case("utc") {
+        return Ok(Utc.fix());
+    }
+
+    let (sign, rest) = match value.split_at_checked(1) {
+        Some(("+", rest)) => (1, rest),
+        Some(("-", rest)) => (-1, rest),
+        _ => return Err(invalid()),
+    };
+    let (hours, minutes) = rest.split_once(':').ok_or_else(invalid)?;
+    let hours: i32 = hours.parse().map_err(|_| invalid())?;
+    let minutes: i32 = minutes.parse().map_err(|_| invalid())?;
+    if hours > 14 || minutes >= 60 {
+        return Err(invalid());
+    }
+
+    FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60)).ok_or_else(invalid)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    #[test]
+    fn test_business_date_follows_configured_time_zone() {
+        // UTC 2024-03-31 16:30 は日本時間 2024-04-01 01:30
+        let now = Utc.with_ymd_and_hms(2024, 3, 31, 16, 30, 0).unwrap();
+        let tokyo = FixedTimeProvider::new(now, parse_utc_offset("+09:00").unwrap());
+        let utc = FixedTimeProvider::new(now, parse_utc_offset("Z").unwrap());
+
+        assert_eq!(tokyo.business_date(), NaiveDate::from_ymd_opt(2024, 4, 1).unwrap());
+        assert_eq!(utc.business_date(), NaiveDate::from_ymd_opt(2024, 3, 31).unwrap());
+    }
+
+    #[test]
+    fn test_parse_utc_offset() {
+        assert_eq!(parse_utc_offset("-05:30").unwrap().local_minus_utc(), -(5 * 3600 + 30 * 60));
+        assert!(parse_utc_offset("09:00").is_err());
+        assert!(parse_utc_offset("+15:00").is_err());
+        assert!(parse_utc_offset("+09:60").is_err());
+    }
+}
@@ -0,0 +1,214 @@
+// BusinessMetrics具象実装 - Infrastructure層
+// 業務指標を記録日ごとに集計し、Projection DBに保存する
+
+use std::{sync::Arc, time::Duration};
+
+use chrono::{DateTime, NaiveDate, TimeDelta, Utc};
+use javelin_application::{
+    business_metrics::{BusinessMetric, BusinessMetrics, DailyMetric},
+    error::{ApplicationError, ApplicationResult},
+};
+use javelin_domain::time_provider::{SystemTimeProvider, TimeProvider};
+use serde::{Deserialize, Serialize};
+use tokio::sync::Mutex;
+
+use crate::projection_db::ProjectionDb;
+
+/// Projection DBに保存する集計の名前
+const METRICS_NAME: &str = "business_metrics";
+
+/// 既定の保持日数
+pub const DEFAULT_METRICS_RETENTION_DAYS: u32 = 90;
+
+/// 日次集計（日付・指標順）
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+struct DailyMetrics {
+    days: Vec<DailyMetric>,
+}
+
+impl DailyMetrics {
+    fn record(&mut self, date: NaiveDate, metric: BusinessMetric, latency: Duration) {
+        let latency_ms = latency.as_millis().min(u64::MAX as u128) as u64;
+        let position = self
+            .days
+            .binary_search_by(|daily| (daily.date, daily.metric).cmp(&(date, metric)));
+        let daily = match position {
+            Ok(index) => &mut self.days[index],
+            Err(index) => {
+                self.days.insert(
+                    index,
+                    DailyMetric { date, metric, count: 0, total_latency_ms: 0, max_latency_ms: 0 },
+                );
+                &mut self.days[index]
+            }
+        };
+        daily.count += 1;
+        daily.total_latency_ms = daily.total_latency_ms.saturating_add(latency_ms);
+        daily.max_latency_ms = daily.max_latency_ms.max(latency_ms);
+    }
+
+    /// 指定日より前の集計を破棄
+    fn prune_before(&mut self, date: NaiveDate) {
+        self.days.retain(|daily| daily.date >= date);
+    }
+}
+
+/// BusinessMetrics具象実装
+///
+/// 集計はProjection DBのメタ情報として保存するため、Projectionの
+/// 再構築・圧縮による置き換えでも失われない。記録日は設定タイムゾーンの業務日付とする。
+pub struct BusinessMetricsImpl {
+    projection_db: Arc<ProjectionDb>,
+    retention_days: u32,
+    time_provider: Arc<dyn TimeProvider>,
+    /// 読み込み・追加・保存を直列化する
+    lock: Mutex<()>,
+}
+
+impl BusinessMetricsImpl {
+    /// 新しいBusinessMetricsImplを作成
+    pub fn new(projection_db: Arc<ProjectionDb>) -> Self {
+        Self::with_retention_days(projection_db, DEFAULT_METRICS_RETENTION_DAYS)
+    }
+
+    /// 保持日数を指定して作成
+    pub fn with_retention_days(projection_db: Arc<ProjectionDb>, retention_days: u32) -> Self {
+        Self {
+            projection_db,
+            retention_days,
+            time_provider: Arc::new(SystemTimeProvider::local()),
+            lock: Mutex::new(()),
+        }
+    }
+
+    /// 記録時刻・業務日付の基準を設定（既定はシステム時計とローカルタイムゾーン）
+    pub fn with_time_provider(mut self, time_provider: Arc<dyn TimeProvider>) -> Self {
+        self.time_provider = time_provider;
+        self
+    }
+
+    async fn load(&self) -> ApplicationResult<DailyMetrics> {
+        let stored =
+            self.projection_db.get_telemetry(METRICS_NAME).await.map_err(projection_error)?;
+        match stored {
+            Some(bytes) => serde_json::from_slice(&bytes)
+                .map_err(|e| ApplicationError::ProjectionDatabaseError(e.to_string())),
+            None => Ok(DailyMetrics::default()),
+        }
+    }
+
+    /// 指定時刻の指標として記録
+    async fn record_at(
+        &self,
+        metric: BusinessMetric,
+        latency: Duration,
+        at: DateTime<Utc>,
+    ) -> ApplicationResult<()> {
+        let _guard = self.lock.lock().await;
+        let mut metrics = self.load().await?;
+        let date = self.time_provider.business_date_of(at);
+        metrics.record(date, metric, latency);
+        metrics.prune_before(date - TimeDelta::days(i64::from(self.retention_days)));
+
+        let bytes = serde_json::to_vec(&metrics)
+            .map_err(|e| ApplicationError::ProjectionDatabaseError(e.to_string()))?;
+        self.projection_db
+            .put_telemetry(METRICS_NAME, bytes)
+            .await
+            .map_err(projection_error)
+    }
+}
+
+#[async_trait::async_trait]
+impl BusinessMetrics for BusinessMetricsImpl {
+    async fn record(&self, metric: BusinessMetric, latency: Duration) -> ApplicationResult<()> {
+        self.record_at(metric, latency, self.time_provider.now()).await
+    }
+
+    async fn daily_metrics(&self, from: NaiveDate) -> ApplicationResult<Vec<DailyMetric>> {
+        let metrics = self.load().await?;
+        Ok(metrics.days.into_iter().filter(|daily| daily.date >= from).collect())
+    }
+}
+
+fn projection_error(e: crate::error::InfrastructureError) -> ApplicationError {
+    ApplicationError::ProjectionDatabaseError(e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::FixedOffset;
+    use javelin_domain::time_provider::FixedTimeProvider;
+    use tempfile::TempDir;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_records_daily_aggregates_and_prunes_old_days() {
+        let temp_dir = TempDir::new().unwrap();
+        let projection_db = Arc::new(ProjectionDb::new(temp_dir.path()).await.unwrap());
+        let metrics =
+            BusinessMetricsImpl::with_retention_days(projection_db, 7).with_time_provider(
+                Arc::new(FixedTimeProvider::new(Utc::now(), FixedOffset::east_opt(0).unwrap())),
+            );
+
+        let day = |d: u32| {
+            NaiveDate::from_ymd_opt(2024, 4, d)
+                .unwrap()
+                .and_hms_opt(9, 0, 0)
+                .unwrap()
+                .and_utc()
+        };
+        let ms = Duration::from_millis;
+        metrics.record_at(BusinessMetric::EntryApproved, ms(300), day(1)).await.unwrap();
+        metrics
+            .record_at(BusinessMetric::EntryRegistered, ms(40), day(2))
+            .await
+            .unwrap();
+        metrics
+            .record_at(BusinessMetric::EntryRegistered, ms(60), day(2))
+            .await
+            .unwrap();
+        metrics.record_at(BusinessMetric::EntryApproved, ms(900), day(2)).await.unwrap();
+
+        let daily = metrics.daily_metrics(day(1).date_naive()).await.unwrap();
+        assert_eq!(daily.len(), 3);
+        let registered = &daily[1];
+        assert_eq!(registered.metric, BusinessMetric::EntryRegistered);
+        assert_eq!(registered.count, 2);
+        assert_eq!(registered.average_latency_ms(), Some(50));
+        assert_eq!(registered.max_latency_ms, 60);
+        assert_eq!(metrics.daily_metrics(day(2).date_naive()).await.unwrap().len(), 2);
+
+        // 保持日数を過ぎた日の集計は破棄される
+        metrics.record_at(BusinessMetric::ClosingStep, ms(1), day(9)).await.unwrap();
+        let daily = metrics.daily_metrics(day(1).date_naive()).await.unwrap();
+        assert!(daily.iter().all(|d| d.date >= day(2).date_naive()));
+        assert_eq!(daily.len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_records_on_business_date_of_configured_time_zone() {
+        let temp_dir = TempDir::new().unwrap();
+        let projection_db = Arc::new(ProjectionDb::new(temp_dir.path()).await.unwrap());
+        // 2024-04-01 16:30 UTC は日本時間で 2024-04-02 01:30
+        let now = NaiveDate::from_ymd_opt(2024, 4, 1)
+            .unwrap()
+            .and_hms_opt(16, 30, 0)
+            .unwrap()
+            .and_utc();
+        let metrics = BusinessMetricsImpl::new(projection_db).with_time_provider(Arc::new(
+            FixedTimeProvider::new(now, FixedOffset::east_opt(9 * 3600).unwrap()),
+        ));
+
+        metrics
+            .record(BusinessMetric::EntryRegistered, Duration::from_millis(10))
+            .await
+            .unwrap();
+
+        let daily = metrics.daily_metrics(NaiveDate::from_ymd_opt(2024, 4, 1).unwrap()).await;
+        let daily = daily.unwrap();
+        assert_eq!(daily.len(), 1);
+        assert_eq!(daily[0].date, NaiveDate::from_ymd_opt(2024, 4, 2).unwrap());
+    }
+}
@@ -3,8 +3,8 @@
 
 use std::sync::Arc;
 
+pub use javelin_domain::financial_close::accounting_period::AccountingPeriodEvent;
 use javelin_domain::{error::DomainResult, repositories::EventRepository};
-use serde::{Deserialize, Serialize};
 
 use crate::{
     error::{InfrastructureError, InfrastructureResult},
@@ -12,53 +12,6 @@ use crate::{
     types::{ExpectedVersion, Sequence},
 };
 
-/// 会計期間ドメインイベント
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-#[serde(tag = "type")]
-pub enum AccountingPeriodEvent {
-    /// 期間作成
-    PeriodCreated {
-        period_id: String,
-        fiscal_year: u32,
-        period: u32,
-        start_date: String,
-        end_date: String,
-        created_by: String,
-        created_at: String,
-    },
-
-    /// 期間締め
-    PeriodClosed { period_id: String, closed_by: String, closed_at: String },
-
-    /// 期間ロック
-    PeriodLocked { period_id: String, locked_by: String, locked_at: String },
-
-    /// 期間再オープン
-    PeriodReopened { period_id: String, reason: String, reopened_by: String, reopened_at: String },
-}
-
-impl AccountingPeriodEvent {
-    /// イベントタイプを取得
-    pub fn event_type(&self) -> &str {
-        match self {
-            AccountingPeriodEvent::PeriodCreated { .. } => "PeriodCreated",
-            AccountingPeriodEvent::PeriodClosed { .. } => "PeriodClosed",
-            AccountingPeriodEvent::PeriodLocked { .. } => "PeriodLocked",
-            AccountingPeriodEvent::PeriodReopened { .. } => "PeriodReopened",
-        }
-    }
-
-    /// 集約IDを取得
-    pub fn aggregate_id(&self) -> &str {
-        match self {
-            AccountingPeriodEvent::PeriodCreated { period_id, .. }
-            | AccountingPeriodEvent::PeriodClosed { period_id, .. }
-            | AccountingPeriodEvent::PeriodLocked { period_id, .. }
-            | AccountingPeriodEvent::PeriodReopened { period_id, .. } => period_id,
-        }
-    }
-}
-
 /// AccountingPeriodRepository実装
 ///
 /// Event Sourcingパターンに基づき、会計期間のイベントを
@@ -139,6 +92,16 @@ impl EventRepository for AccountingPeriodRepositoryImpl {
             .map_err(|e| javelin_domain::error::DomainError::RepositoryError(e.to_string()))
     }
 
+    async fn append_batches<T>(&self, batches: Vec<(String, Vec<T>)>) -> DomainResult<u64>
+    where
+        T: serde::Serialize + Send + 'static,
+    {
+        self.event_store
+            .append_batches(batches)
+            .await
+            .map_err(|e| javelin_domain::error::DomainError::RepositoryError(e.to_string()))
+    }
+
     async fn get_events(&self, aggregate_id: &str) -> DomainResult<Vec<serde_json::Value>> {
         let events = self
             .event_store
@@ -262,18 +225,3 @@ mod tests {
         assert_eq!(version, 3);
     }
 }
-
-// DomainEventトレイト実装
-impl javelin_domain::event::DomainEvent for AccountingPeriodEvent {
-    fn event_type(&self) -> &str {
-        self.event_type()
-    }
-
-    fn aggregate_id(&self) -> &str {
-        self.aggregate_id()
-    }
-
-    fn version(&self) -> u64 {
-        0
-    }
-}
@@ -100,6 +100,16 @@ impl EventRepository for JournalEntryRepositoryImpl {
             .map_err(|e| javelin_domain::error::DomainError::RepositoryError(e.to_string()))
     }
 
+    async fn append_batches<T>(&self, batches: Vec<(String, Vec<T>)>) -> DomainResult<u64>
+    where
+        T: serde::Serialize + Send + 'static,
+    {
+        self.event_store
+            .append_batches(batches)
+            .await
+            .map_err(|e| javelin_domain::error::DomainError::RepositoryError(e.to_string()))
+    }
+
     async fn get_events(&self, aggregate_id: &str) -> DomainResult<Vec<serde_json::Value>> {
         let events = self
             .event_store
@@ -172,6 +182,7 @@ mod tests {
             transaction_date: "2024-01-01".to_string(),
             voucher_number: "V001".to_string(),
             lines: vec![],
+            description: None,
             created_by: "user1".to_string(),
             created_at: Utc::now(),
         };
@@ -198,6 +209,7 @@ mod tests {
             transaction_date: "2024-01-01".to_string(),
             voucher_number: "V002".to_string(),
             lines: vec![],
+            description: None,
             created_by: "user1".to_string(),
             created_at: Utc::now(),
         };
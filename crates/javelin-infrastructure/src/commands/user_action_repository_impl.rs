@@ -3,18 +3,20 @@
 
 use std::{path::Path, sync::Arc};
 
-use javelin_domain::repositories::UserActionRepository;
+use javelin_domain::{masters::UserAction, repositories::UserActionRepository};
 use lmdb::{Database, DatabaseFlags, Environment, Transaction, WriteFlags};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 #[derive(Debug, Serialize, Deserialize)]
-struct UserAction {
+struct StoredUserAction {
     id: String,
     timestamp: String,
     user: String,
+    kind: String,
     location: String,
     action: String,
+    duration_ms: u64,
 }
 
 pub struct UserActionRepositoryImpl {
@@ -45,18 +47,19 @@ impl UserActionRepositoryImpl {
 impl UserActionRepository for UserActionRepositoryImpl {
     fn save_action(
         &self,
-        user: &str,
-        location: &str,
-        action: &str,
+        action: &UserAction,
     ) -> impl std::future::Future<Output = Result<String, Box<dyn std::error::Error + Send + Sync>>> + Send
     {
         let action_id = Uuid::new_v4().to_string();
-        let timestamp = chrono::Utc::now().to_rfc3339();
-        let user = user.to_string();
-        let location = location.to_string();
-        let action = action.to_string();
-
-        let user_action = UserAction { id: action_id.clone(), timestamp, user, location, action };
+        let user_action = StoredUserAction {
+            id: action_id.clone(),
+            timestamp: chrono::Utc::now().to_rfc3339(),
+            user: action.user_id().to_string(),
+            kind: action.kind().as_str().to_string(),
+            location: action.screen().to_string(),
+            action: action.action().to_string(),
+            duration_ms: action.duration_ms(),
+        };
 
         let env = Arc::clone(&self.env);
         let db = self.db;
@@ -82,7 +85,7 @@ impl UserActionRepository for UserActionRepositoryImpl {
 
 #[cfg(test)]
 mod tests {
-    use javelin_domain::repositories::UserActionRepository;
+    use javelin_domain::{masters::UserActionKind, repositories::UserActionRepository};
     use tempfile::TempDir;
 
     use super::*;
@@ -92,7 +95,8 @@ mod tests {
         let temp_dir = TempDir::new().expect("Failed to create temp directory");
         let repo = UserActionRepositoryImpl::new(temp_dir.path()).await.unwrap();
 
-        let result = repo.save_action("test_user", "HomePage", "test action").await;
+        let action = UserAction::screen_visit("test_user", "HomePage", 1500).unwrap();
+        let result = repo.save_action(&action).await;
 
         assert!(result.is_ok());
         let action_id = result.unwrap();
@@ -110,8 +114,11 @@ mod tests {
         let repo = UserActionRepositoryImpl::new(temp_dir.path()).await.unwrap();
 
         // 複数のアクションを保存
-        let id1 = repo.save_action("user1", "HomePage", "action1").await.unwrap();
-        let id2 = repo.save_action("user2", "SettingsPage", "action2").await.unwrap();
+        let action = |user: &str, location: &str, name: &str| {
+            UserAction::new(user, UserActionKind::ActionPerformed, location, name, 10).unwrap()
+        };
+        let id1 = repo.save_action(&action("user1", "HomePage", "action1")).await.unwrap();
+        let id2 = repo.save_action(&action("user2", "SettingsPage", "action2")).await.unwrap();
 
         assert_ne!(id1, id2, "Action IDs should be unique");
     }
@@ -26,6 +26,9 @@ pub enum InfrastructureError {
         source: std::io::Error,
     },
 
+    #[error("[I-1004] Replica source not found (start the writer instance first): {path}")]
+    ReplicaSourceNotFound { path: String },
+
     #[error("[I-2001] Event append failed")]
     EventAppendFailed,
 
@@ -41,12 +44,18 @@ pub enum InfrastructureError {
     #[error("[I-4001] LMDB error: {0}")]
     LmdbError(String),
 
+    #[error("[I-4002] Storage table not found: {0}")]
+    StorageTableNotFound(String),
+
     #[error("[I-5001] Serialization failed: {0}")]
     SerializationFailed(String),
 
     #[error("[I-5002] Deserialization failed: {0}")]
     DeserializationFailed(String),
 
+    #[error("[I-5003] Event checksum mismatch at seq={global_sequence}: {detail}")]
+    ChecksumMismatch { global_sequence: u64, detail: String },
+
     #[error(
         "[I-6001] Concurrency conflict for aggregate {aggregate_id}: expected version {expected}, but found {actual}"
     )]
@@ -58,6 +67,9 @@ pub enum InfrastructureError {
     #[error("[I-8001] Transaction failed: {0}")]
     TransactionFailed(String),
 
+    #[error("[I-8002] Write rejected: {0} is opened read-only (replica mode)")]
+    ReadOnlyReplica(String),
+
     #[error("[I-9999] Unknown infrastructure error: {0}")]
     Unknown(String),
 }
@@ -198,6 +198,7 @@ mod tests {
             transaction_date: "2024-01-01".to_string(),
             voucher_number: "V001".to_string(),
             lines: vec![],
+            description: None,
             created_by: "user1".to_string(),
             created_at: Utc::now(),
         };
@@ -240,6 +241,7 @@ mod tests {
                 transaction_date: "2024-01-01".to_string(),
                 voucher_number: "V001".to_string(),
                 lines: vec![],
+                description: None,
                 created_by: "user1".to_string(),
                 created_at: Utc::now(),
             },
@@ -248,6 +250,7 @@ mod tests {
                 transaction_date: "2024-01-02".to_string(),
                 voucher_number: "V002".to_string(),
                 lines: vec![],
+                description: None,
                 created_by: "user1".to_string(),
                 created_at: Utc::now(),
             },
@@ -0,0 +1,203 @@
+// AggregateCache - 集約イベントの読み取りキャッシュ
+// 責務: 直近に再構築した集約のイベント列を保持し、同じ集約の全件走査の繰り返しを省く
+//
+// 承認・差戻し等のワークフロー操作は同じ集約を短時間に何度も再構築するため、
+// 集約IDごとのイベント列をLRUで保持する。集約への追記・修復時に該当集約を無効化する。
+// 他プロセスによる追記は検知できないため、参照専用（レプリカ）では容量0（無効）で使用する。
+
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{
+        Mutex,
+        atomic::{AtomicU64, Ordering},
+    },
+};
+
+use crate::event_stream::StoredEvent;
+
+/// 既定で保持する集約数
+pub const DEFAULT_AGGREGATE_CACHE_CAPACITY: usize = 256;
+
+/// キャッシュの利用状況
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AggregateCacheStats {
+    pub hits: u64,
+    pub misses: u64,
+    pub entries: usize,
+}
+
+#[derive(Default)]
+struct CacheState {
+    entries: HashMap<String, Vec<StoredEvent>>,
+    /// 参照順（先頭が最も古い）
+    order: VecDeque<String>,
+    /// 無効化の世代（読み取り中に無効化された結果を格納しないため）
+    generation: u64,
+}
+
+impl CacheState {
+    fn touch(&mut self, aggregate_id: &str) {
+        if let Some(position) = self.order.iter().position(|id| id == aggregate_id) {
+            let id = self.order.remove(position).unwrap_or_default();
+            self.order.push_back(id);
+        }
+    }
+
+    fn remove(&mut self, aggregate_id: &str) {
+        if self.entries.remove(aggregate_id).is_some() {
+            self.order.retain(|id| id != aggregate_id);
+        }
+    }
+}
+
+/// 集約IDごとのイベント列のLRUキャッシュ
+pub struct AggregateCache {
+    capacity: usize,
+    state: Mutex<CacheState>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl AggregateCache {
+    /// 指定した集約数まで保持するキャッシュを作成（0の場合はキャッシュしない）
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            state: Mutex::new(CacheState::default()),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    /// キャッシュ済みのイベント列を取得（ヒット・ミスを記録）
+    pub fn get(&self, aggregate_id: &str) -> Option<Vec<StoredEvent>> {
+        let mut state = self.state.lock().unwrap();
+        let events = state.entries.get(aggregate_id).cloned();
+        match events {
+            Some(events) => {
+                state.touch(aggregate_id);
+                self.hits.fetch_add(1, Ordering::Relaxed);
+                Some(events)
+            }
+            None => {
+                self.misses.fetch_add(1, Ordering::Relaxed);
+                None
+            }
+        }
+    }
+
+    /// 現在の無効化の世代（ストレージを読む前に取得し、`insert`に渡す）
+    pub fn generation(&self) -> u64 {
+        self.state.lock().unwrap().generation
+    }
+
+    /// ストレージから読んだイベント列を格納
+    ///
+    /// 読み取り開始（`generation`の取得）以降に無効化があった場合は、
+    /// 追記前の内容の可能性があるため格納しない。
+    pub fn insert(&self, aggregate_id: &str, events: Vec<StoredEvent>, generation: u64) {
+        if self.capacity == 0 {
+            return;
+        }
+        let mut state = self.state.lock().unwrap();
+        if state.generation != generation {
+            return;
+        }
+
+        if state.entries.insert(aggregate_id.to_string(), events).is_some() {
+            state.touch(aggregate_id);
+        } else {
+            state.order.push_back(aggregate_id.to_string());
+        }
+        while state.entries.len() > self.capacity {
+            let Some(oldest) = state.order.pop_front() else {
+                break;
+            };
+            state.entries.remove(&oldest);
+        }
+    }
+
+    /// 集約への追記・修復時に該当集約を無効化
+    pub fn invalidate(&self, aggregate_id: &str) {
+        let mut state = self.state.lock().unwrap();
+        state.generation += 1;
+        state.remove(aggregate_id);
+    }
+
+    /// すべての集約を無効化（対象の集約が特定できない変更時）
+    pub fn clear(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.generation += 1;
+        state.entries.clear();
+        state.order.clear();
+    }
+
+    /// ヒット・ミス件数と保持している集約数
+    pub fn stats(&self) -> AggregateCacheStats {
+        AggregateCacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            entries: self.state.lock().unwrap().entries.len(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(aggregate_id: &str, global_sequence: u64) -> StoredEvent {
+        StoredEvent {
+            global_sequence,
+            event_type: "Test".to_string(),
+            aggregate_id: aggregate_id.to_string(),
+            version: global_sequence,
+            timestamp: "2024-01-01T00:00:00Z".to_string(),
+            business_date: None,
+            payload: b"{}".to_vec(),
+            checksum: None,
+        }
+    }
+
+    #[test]
+    fn test_evicts_least_recently_used() {
+        let cache = AggregateCache::new(2);
+        let generation = cache.generation();
+        cache.insert("A", vec![event("A", 1)], generation);
+        cache.insert("B", vec![event("B", 2)], generation);
+
+        // Aを参照してからCを格納すると、最も古いBが追い出される
+        assert!(cache.get("A").is_some());
+        cache.insert("C", vec![event("C", 3)], generation);
+
+        assert!(cache.get("B").is_none());
+        assert!(cache.get("A").is_some());
+        assert!(cache.get("C").is_some());
+        assert_eq!(cache.stats(), AggregateCacheStats { hits: 3, misses: 1, entries: 2 });
+    }
+
+    #[test]
+    fn test_invalidation_discards_stale_reads() {
+        let cache = AggregateCache::new(8);
+        let generation = cache.generation();
+        cache.insert("A", vec![event("A", 1)], generation);
+
+        cache.invalidate("A");
+        assert!(cache.get("A").is_none());
+
+        // 無効化前に読み始めた結果は格納しない
+        cache.insert("A", vec![event("A", 1)], generation);
+        assert!(cache.get("A").is_none());
+
+        cache.insert("A", vec![event("A", 1), event("A", 2)], cache.generation());
+        assert_eq!(cache.get("A").unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_zero_capacity_disables_cache() {
+        let cache = AggregateCache::new(0);
+        cache.insert("A", vec![event("A", 1)], cache.generation());
+        assert!(cache.get("A").is_none());
+        assert_eq!(cache.stats().entries, 0);
+    }
+}
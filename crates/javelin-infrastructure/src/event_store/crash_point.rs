@@ -0,0 +1,64 @@
+// CrashPoint - クラッシュ注入ポイント
+// 責務: 書き込み経路上の特定位置でプロセスを強制終了させ、クラッシュ復旧を検証可能にする
+//
+// テストビルドでのみ有効。子プロセスとして起動したテストバイナリに
+// 環境変数でクラッシュ位置を指定し、該当位置で abort する。
+// 通常ビルドでは何もしない。
+
+/// クラッシュ位置を指定する環境変数
+pub const CRASH_POINT_ENV: &str = "JAVELIN_CRASH_POINT";
+/// クラッシュまでに通過させる回数を指定する環境変数（省略時は0）
+pub const CRASH_AFTER_ENV: &str = "JAVELIN_CRASH_AFTER";
+
+/// クラッシュ注入ポイント
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CrashPoint {
+    /// イベント追記トランザクションのコミット直前
+    AppendBeforeCommit,
+    /// イベント追記トランザクションのコミット直後（呼び出し元への応答前）
+    AppendAfterCommit,
+    /// Projection更新トランザクションのコミット直前
+    ProjectionBeforeCommit,
+    /// Projection更新トランザクションのコミット直後
+    ProjectionAfterCommit,
+}
+
+impl CrashPoint {
+    pub const ALL: [CrashPoint; 4] = [
+        CrashPoint::AppendBeforeCommit,
+        CrashPoint::AppendAfterCommit,
+        CrashPoint::ProjectionBeforeCommit,
+        CrashPoint::ProjectionAfterCommit,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::AppendBeforeCommit => "append_before_commit",
+            Self::AppendAfterCommit => "append_after_commit",
+            Self::ProjectionBeforeCommit => "projection_before_commit",
+            Self::ProjectionAfterCommit => "projection_after_commit",
+        }
+    }
+}
+
+/// 指定位置に到達したことを通知（指定されたクラッシュ位置であればabort）
+#[cfg(test)]
+pub(crate) fn reached(point: CrashPoint) {
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    static HITS: AtomicU64 = AtomicU64::new(0);
+
+    if std::env::var(CRASH_POINT_ENV).ok().as_deref() != Some(point.as_str()) {
+        return;
+    }
+    let crash_after =
+        std::env::var(CRASH_AFTER_ENV).ok().and_then(|v| v.parse().ok()).unwrap_or(0u64);
+    if HITS.fetch_add(1, Ordering::SeqCst) >= crash_after {
+        std::process::abort();
+    }
+}
+
+/// 指定位置に到達したことを通知（通常ビルドでは何もしない）
+#[cfg(not(test))]
+#[inline(always)]
+pub(crate) fn reached(_point: CrashPoint) {}
@@ -0,0 +1,114 @@
+// EventEnvelope - 型付きイベント
+// 責務: StoredEventのペイロードをドメインイベントに復元し、種別を列挙型で扱う
+//
+// Projectionは `event_type` の文字列ではなくこの列挙型で分岐する。
+// ドメインイベントの追加・改名は、網羅的なmatchのコンパイルエラーとして検出される。
+
+use javelin_domain::financial_close::journal_entry::events::JournalEntryEvent;
+
+use crate::event_stream::StoredEvent;
+
+/// 型付きイベント
+#[derive(Debug, Clone, PartialEq)]
+pub enum EventEnvelope {
+    /// 仕訳伝票のイベント
+    JournalEntry(JournalEntryEvent),
+    /// 仕訳伝票の種別だがペイロードを復元できないイベント
+    ///
+    /// 読み飛ばすと仕訳の状態や残高が欠けるため、Projectionはエラーとして扱う。
+    Undecodable { event_type: String, error: String },
+    /// 仕訳伝票の種別ではないイベント（他の集約のイベント・未知の種別）
+    Unknown { event_type: String },
+}
+
+impl EventEnvelope {
+    /// StoredEventのペイロードを復元
+    pub fn from_stored(event: &StoredEvent) -> Self {
+        match serde_json::from_slice::<JournalEntryEvent>(&event.payload) {
+            Ok(event) => EventEnvelope::JournalEntry(event),
+            Err(e) if JournalEntryEvent::is_event_type(&event.event_type) => {
+                EventEnvelope::Undecodable {
+                    event_type: event.event_type.clone(),
+                    error: e.to_string(),
+                }
+            }
+            Err(_) => EventEnvelope::Unknown { event_type: event.event_type.clone() },
+        }
+    }
+
+    /// イベント種別（ログ・表示用）
+    pub fn event_type(&self) -> &str {
+        match self {
+            EventEnvelope::JournalEntry(event) => event.event_type(),
+            EventEnvelope::Undecodable { event_type, .. }
+            | EventEnvelope::Unknown { event_type } => event_type,
+        }
+    }
+
+    /// 仕訳伝票のイベント（それ以外はNone）
+    pub fn into_journal_entry(self) -> Option<JournalEntryEvent> {
+        match self {
+            EventEnvelope::JournalEntry(event) => Some(event),
+            EventEnvelope::Undecodable { .. } | EventEnvelope::Unknown { .. } => None,
+        }
+    }
+}
+
+impl StoredEvent {
+    /// ペイロードを型付きイベントとして復元
+    pub fn envelope(&self) -> EventEnvelope {
+        EventEnvelope::from_stored(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+
+    use super::*;
+
+    fn stored(event_type: &str, payload: Vec<u8>) -> StoredEvent {
+        StoredEvent {
+            global_sequence: 1,
+            event_type: event_type.to_string(),
+            aggregate_id: "JE001".to_string(),
+            version: 1,
+            timestamp: "2024-01-01T00:00:00Z".to_string(),
+            business_date: None,
+            payload,
+            checksum: None,
+        }
+    }
+
+    #[test]
+    fn test_envelope_decodes_journal_entry_events() {
+        let posted = JournalEntryEvent::Posted {
+            entry_id: "JE001".to_string(),
+            entry_number: "EN-2024-001".to_string(),
+            posted_by: "approver".to_string(),
+            posted_at: Utc::now(),
+        };
+        let envelope = stored("Posted", serde_json::to_vec(&posted).unwrap()).envelope();
+        assert_eq!(envelope.event_type(), "Posted");
+        assert_eq!(envelope.into_journal_entry(), Some(posted));
+
+        // 仕訳伝票以外の種別は種別名のみ保持する
+        let unknown = stored("Approved", br#"{"type":"Approved"}"#.to_vec()).envelope();
+        assert_eq!(unknown, EventEnvelope::Unknown { event_type: "Approved".to_string() });
+    }
+
+    #[test]
+    fn test_envelope_keeps_decode_failure_of_journal_entry_types() {
+        // 仕訳伝票の種別で復元できないペイロードはUnknownにしない
+        let garbage = stored("DraftCreated", b"{not json".to_vec()).envelope();
+        assert!(matches!(
+            &garbage,
+            EventEnvelope::Undecodable { event_type, .. } if event_type == "DraftCreated"
+        ));
+        assert_eq!(garbage.into_journal_entry(), None);
+
+        let missing_field =
+            stored("Posted", br#"{"type":"Posted","entry_id":"JE001"}"#.to_vec()).envelope();
+        assert!(matches!(missing_field, EventEnvelope::Undecodable { .. }));
+    }
+}
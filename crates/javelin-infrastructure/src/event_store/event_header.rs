@@ -0,0 +1,223 @@
+// イベントヘッダー - ペイロードを読まずに走査するための索引
+// 責務: シーケンス・集約ID・イベントタイプ・バージョンのコンパクトな保存と復元
+//
+// イベント本体（JSON）とは別テーブルに、グローバルシーケンスをキーとして保存する。
+// 集約ID・イベントタイプによる絞り込みはヘッダーだけで行い、
+// 一致したイベントのみ本体をデシリアライズする。
+//
+// 値の形式: version(u64 BE) | 集約IDの長さ(u16 BE) | 集約ID | イベントタイプ
+
+use crate::{
+    error::{InfrastructureError, InfrastructureResult},
+    event_store::EVENTS_TABLE,
+    event_stream::StoredEvent,
+    storage::{KvRead, KvWrite},
+};
+
+/// イベントヘッダーのテーブル（キー: グローバルシーケンス）
+pub(crate) const HEADERS_TABLE: &str = "event_headers";
+
+/// 集約IDの長さの前までのバイト数（version）
+const VERSION_LEN: usize = 8;
+/// 固定長部分のバイト数（version + 集約IDの長さ）
+const FIXED_LEN: usize = VERSION_LEN + 2;
+
+/// イベントヘッダー
+///
+/// 文字列は読み取りトランザクションのバッファを借用する（コピーしない）。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EventHeader<'a> {
+    pub global_sequence: u64,
+    pub version: u64,
+    pub aggregate_id: &'a str,
+    pub event_type: &'a str,
+}
+
+impl<'a> EventHeader<'a> {
+    /// イベントのヘッダー
+    pub fn of(event: &'a StoredEvent) -> Self {
+        Self {
+            global_sequence: event.global_sequence,
+            version: event.version,
+            aggregate_id: &event.aggregate_id,
+            event_type: &event.event_type,
+        }
+    }
+
+    /// 保存形式へエンコード
+    pub fn encode(&self) -> InfrastructureResult<Vec<u8>> {
+        let aggregate_len = u16::try_from(self.aggregate_id.len()).map_err(|_| {
+            InfrastructureError::SerializationFailed(format!(
+                "aggregate id is too long for event header: {} bytes",
+                self.aggregate_id.len()
+            ))
+        })?;
+
+        let mut value =
+            Vec::with_capacity(FIXED_LEN + self.aggregate_id.len() + self.event_type.len());
+        value.extend_from_slice(&self.version.to_be_bytes());
+        value.extend_from_slice(&aggregate_len.to_be_bytes());
+        value.extend_from_slice(self.aggregate_id.as_bytes());
+        value.extend_from_slice(self.event_type.as_bytes());
+        Ok(value)
+    }
+
+    /// 保存形式からデコード（キーはグローバルシーケンス）
+    pub fn decode(key: &[u8], value: &'a [u8]) -> InfrastructureResult<Self> {
+        let invalid = |detail: &str| {
+            InfrastructureError::DeserializationFailed(format!("invalid event header: {}", detail))
+        };
+
+        let global_sequence = key
+            .as_array::<8>()
+            .map(|arr| u64::from_be_bytes(*arr))
+            .ok_or_else(|| invalid("key"))?;
+        let (fixed, rest) = value.split_at_checked(FIXED_LEN).ok_or_else(|| invalid("length"))?;
+        let (version, aggregate_len) = fixed.split_at(VERSION_LEN);
+        let version =
+            u64::from_be_bytes(*version.as_array::<8>().ok_or_else(|| invalid("version"))?);
+        let aggregate_len = u16::from_be_bytes(
+            *aggregate_len.as_array::<2>().ok_or_else(|| invalid("aggregate length"))?,
+        ) as usize;
+        let (aggregate_id, event_type) =
+            rest.split_at_checked(aggregate_len).ok_or_else(|| invalid("aggregate id"))?;
+
+        Ok(Self {
+            global_sequence,
+            version,
+            aggregate_id: std::str::from_utf8(aggregate_id).map_err(|_| invalid("aggregate id"))?,
+            event_type: std::str::from_utf8(event_type).map_err(|_| invalid("event type"))?,
+        })
+    }
+}
+
+/// イベントのヘッダーを書き込む
+pub(crate) fn put_header(txn: &mut impl KvWrite, event: &StoredEvent) -> InfrastructureResult<()> {
+    let header = EventHeader::of(event);
+    txn.put(HEADERS_TABLE, &header.global_sequence.to_be_bytes(), &header.encode()?)
+}
+
+/// ヘッダーによる走査が使えるか
+///
+/// すべてのイベントにヘッダーがある場合のみ使う。ヘッダー導入前のストアを
+/// 参照専用で開いた場合や、デシリアライズできないイベントが残っている場合は
+/// イベント本体を走査する。
+pub(crate) fn headers_available(txn: &impl KvRead) -> InfrastructureResult<bool> {
+    let headers = match txn.entry_count(HEADERS_TABLE) {
+        Ok(count) => count,
+        Err(InfrastructureError::StorageTableNotFound(_)) => return Ok(false),
+        Err(e) => return Err(e),
+    };
+    Ok(headers == txn.entry_count(EVENTS_TABLE)?)
+}
+
+/// ヘッダーのないイベントにヘッダーを作成する
+///
+/// ヘッダー導入前に保存されたイベントを書き込み可能でオープンした時点で補う。
+/// デシリアライズできないイベントは隔離・修復の対象となるため作成しない。
+///
+/// # Returns
+/// 書き込んだヘッダーの件数
+pub(crate) fn backfill_headers(txn: &mut impl KvWrite) -> InfrastructureResult<usize> {
+    if headers_available(txn)? {
+        return Ok(0);
+    }
+
+    let mut headers = Vec::new();
+    txn.scan(EVENTS_TABLE, None, &mut |key, value| {
+        if let Ok(event) = serde_json::from_slice::<StoredEvent>(value) {
+            headers.push((key.to_vec(), EventHeader::of(&event).encode()?));
+        }
+        Ok(true)
+    })?;
+
+    for (key, header) in &headers {
+        txn.put(HEADERS_TABLE, key, header)?;
+    }
+    Ok(headers.len())
+}
+
+/// ヘッダーの走査結果
+pub(crate) struct HeaderScan {
+    /// 条件に一致したシーケンス番号（昇順）
+    pub sequences: Vec<u64>,
+    /// 走査した最後のシーケンス番号
+    pub last_scanned: Option<u64>,
+    /// 走査した件数
+    pub scanned: usize,
+}
+
+/// ヘッダーが条件に一致するイベントのシーケンス番号を取得
+///
+/// `from_sequence` 以降のヘッダーを、`scan_limit` 件走査するか
+/// `match_limit` 件一致するまで走査する。
+pub(crate) fn matching_sequences(
+    txn: &impl KvRead,
+    from_sequence: u64,
+    scan_limit: usize,
+    match_limit: usize,
+    predicate: &mut dyn FnMut(&EventHeader<'_>) -> bool,
+) -> InfrastructureResult<HeaderScan> {
+    let mut result = HeaderScan { sequences: Vec::new(), last_scanned: None, scanned: 0 };
+
+    txn.scan(HEADERS_TABLE, Some(&from_sequence.to_be_bytes()), &mut |key, value| {
+        let header = EventHeader::decode(key, value)?;
+        if predicate(&header) {
+            result.sequences.push(header.global_sequence);
+        }
+        result.last_scanned = Some(header.global_sequence);
+        result.scanned += 1;
+        Ok(result.scanned < scan_limit && result.sequences.len() < match_limit)
+    })?;
+
+    Ok(result)
+}
+
+/// イベント本体を読み込み、チェックサムを検証する
+pub(crate) fn load_event(
+    txn: &impl KvRead,
+    global_sequence: u64,
+) -> InfrastructureResult<StoredEvent> {
+    let value = txn.get(EVENTS_TABLE, &global_sequence.to_be_bytes())?.ok_or_else(|| {
+        InfrastructureError::EventStreamLoadFailed(format!(
+            "event seq={} has a header but no body",
+            global_sequence
+        ))
+    })?;
+    let event: StoredEvent = serde_json::from_slice(&value)
+        .map_err(|e| InfrastructureError::DeserializationFailed(e.to_string()))?;
+    event
+        .verify_checksum()
+        .map_err(|detail| InfrastructureError::ChecksumMismatch { global_sequence, detail })?;
+    Ok(event)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_decode_round_trip_borrows_value() {
+        let header = EventHeader {
+            global_sequence: 42,
+            version: 7,
+            aggregate_id: "仕訳-001",
+            event_type: "JournalEntryApproved",
+        };
+        let value = header.encode().unwrap();
+        assert_eq!(value.len(), FIXED_LEN + "仕訳-001".len() + "JournalEntryApproved".len());
+
+        let decoded = EventHeader::decode(&42u64.to_be_bytes(), &value).unwrap();
+        assert_eq!(decoded, header);
+        assert!(std::ptr::eq(
+            decoded.event_type.as_bytes().as_ptr(),
+            value[value.len() - 20..].as_ptr()
+        ));
+
+        assert!(EventHeader::decode(&42u64.to_be_bytes(), &value[..5]).is_err());
+        assert!(EventHeader::decode(&[0; 4], &value).is_err());
+        let mut truncated = value.clone();
+        truncated[9] = 0xff;
+        assert!(EventHeader::decode(&42u64.to_be_bytes(), &truncated).is_err());
+    }
+}
@@ -0,0 +1,145 @@
+// EventQuarantine - 不正イベントの隔離
+// 責務: デシリアライズできないイベントを隔離し、調査・修復の手段を提供する
+//
+// 破損したペイロードが1件あるだけでProjection再構築全体が中断しないよう、
+// 読み取れないイベントは隔離テーブルへエラー内容とともに退避してスキップする。
+// チェックサムが一致しないイベント（ディスク上の破損）も同様に隔離する。
+
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::{InfrastructureError, InfrastructureResult},
+    event_store::EventStore,
+    event_stream::StoredEvent,
+};
+
+/// 隔離されたイベント
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuarantinedEvent {
+    /// グローバルシーケンス番号（events DBのキー）
+    pub global_sequence: u64,
+    /// events DBに保存されていた生データ
+    pub raw: Vec<u8>,
+    /// デシリアライズ時のエラー内容
+    pub error: String,
+    /// 隔離日時（RFC3339）
+    pub quarantined_at: String,
+}
+
+impl QuarantinedEvent {
+    pub fn new(global_sequence: u64, raw: Vec<u8>, error: impl Into<String>) -> Self {
+        Self {
+            global_sequence,
+            raw,
+            error: error.into(),
+            quarantined_at: chrono::Utc::now().to_rfc3339(),
+        }
+    }
+
+    /// 生データをStoredEventとして解釈（レコード自体が壊れている場合はNone）
+    pub fn stored_event(&self) -> Option<StoredEvent> {
+        serde_json::from_slice(&self.raw).ok()
+    }
+
+    /// 生データを表示用に文字列化（不正なUTF-8は置換文字で表示）
+    pub fn raw_text(&self) -> String {
+        String::from_utf8_lossy(&self.raw).into_owned()
+    }
+}
+
+/// ペイロードがチェックサムと一致し、JSONとして読めることを確認
+pub fn validate_payload(event: &StoredEvent) -> Result<(), String> {
+    event.verify_checksum()?;
+    serde_json::from_slice::<serde_json::Value>(&event.payload)
+        .map(|_| ())
+        .map_err(|e| format!("payload: {}", e))
+}
+
+/// イベントインスペクタ
+///
+/// 隔離されたイベントの一覧・詳細表示と、修復・破棄を行う。
+pub struct EventInspector {
+    event_store: Arc<EventStore>,
+}
+
+impl EventInspector {
+    pub fn new(event_store: Arc<EventStore>) -> Self {
+        Self { event_store }
+    }
+
+    /// 隔離中のイベント一覧（シーケンス順）
+    pub async fn list(&self) -> InfrastructureResult<Vec<QuarantinedEvent>> {
+        self.event_store.get_quarantined_events().await
+    }
+
+    /// 隔離中のイベントの詳細を表示用に整形
+    pub async fn describe(&self, global_sequence: u64) -> InfrastructureResult<String> {
+        let quarantined = self.find(global_sequence).await?;
+
+        let mut lines = vec![
+            format!("seq: {}", quarantined.global_sequence),
+            format!("隔離日時: {}", quarantined.quarantined_at),
+            format!("エラー: {}", quarantined.error),
+        ];
+        match quarantined.stored_event() {
+            Some(event) => {
+                lines.push(format!("イベント種別: {}", event.event_type));
+                lines.push(format!("集約ID: {}", event.aggregate_id));
+                lines.push(format!("バージョン: {}", event.version));
+                lines.push(format!("記録日時: {}", event.timestamp));
+                lines.push(format!("ペイロード: {}", String::from_utf8_lossy(&event.payload)));
+            }
+            None => lines.push(format!("生データ: {}", quarantined.raw_text())),
+        }
+
+        Ok(lines.join("\n"))
+    }
+
+    /// ペイロードを差し替えて修復
+    ///
+    /// レコード自体は読めるがペイロードが壊れているイベントに使用する。
+    /// チェックサムは差し替え後のペイロードから再計算される。
+    pub async fn repair_payload(
+        &self,
+        global_sequence: u64,
+        payload: Vec<u8>,
+    ) -> InfrastructureResult<StoredEvent> {
+        let quarantined = self.find(global_sequence).await?;
+        let mut event = quarantined.stored_event().ok_or_else(|| {
+            InfrastructureError::ValidationFailed(format!(
+                "seq={} はレコード自体が破損しているため、レコード全体を置き換えてください",
+                global_sequence
+            ))
+        })?;
+        event.payload = payload;
+        let event = event.with_checksum();
+
+        self.event_store.repair_event(event.clone()).await?;
+        Ok(event)
+    }
+
+    /// レコード全体を置き換えて修復
+    pub async fn repair_record(&self, event: StoredEvent) -> InfrastructureResult<()> {
+        self.find(event.global_sequence).await?;
+        self.event_store.repair_event(event).await
+    }
+
+    /// 修復不能なイベントを破棄
+    ///
+    /// events DBからも削除されるため、Projectionには反映されなくなる。
+    pub async fn discard(&self, global_sequence: u64) -> InfrastructureResult<()> {
+        self.find(global_sequence).await?;
+        self.event_store.discard_quarantined_event(global_sequence).await
+    }
+
+    async fn find(&self, global_sequence: u64) -> InfrastructureResult<QuarantinedEvent> {
+        self.event_store.get_quarantined_event(global_sequence).await?.ok_or_else(|| {
+            InfrastructureError::ValidationFailed(format!(
+                "seq={} は隔離されていません",
+                global_sequence
+            ))
+        })
+    }
+}
@@ -5,22 +5,42 @@
 // - std::fmt::from_fn によるログ出力
 
 use std::{
-    path::{Path, PathBuf},
+    collections::BTreeSet,
+    path::Path,
     sync::{Arc, Mutex},
+    time::{Duration, Instant},
 };
 
-use lmdb::{
-    Cursor, Database, DatabaseFlags, Environment, EnvironmentFlags, Transaction, WriteFlags,
-};
-use lmdb_sys as ffi;
+use javelin_domain::time_provider::{SystemTimeProvider, TimeProvider};
 
 use crate::{
+    aggregate_cache::{AggregateCache, AggregateCacheStats, DEFAULT_AGGREGATE_CACHE_CAPACITY},
+    crash_point::{self, CrashPoint},
     error::{InfrastructureError, InfrastructureResult},
+    event_header::{
+        HEADERS_TABLE, backfill_headers, headers_available, load_event, matching_sequences,
+        put_header,
+    },
+    event_quarantine::{QuarantinedEvent, validate_payload},
     event_stream::{EventStream, EventStreamBuilder, StoredEvent},
-    storage_metrics::{DurabilityPolicy, StorageMetrics},
+    event_subscription::{EventSubscription, SubscriptionFilter},
+    group_commit::{GroupCommitter, WriteTicket},
+    storage::{KvRead, KvWrite, LmdbBackend, StorageBackend},
+    storage_metrics::{AppendMetrics, DurabilityPolicy, StorageMetrics},
     types::{AggregateId, ExpectedVersion, Sequence},
 };
 
+/// イベント本体のテーブル（キー: グローバルシーケンス）
+pub(crate) const EVENTS_TABLE: &str = "events";
+/// 採番状態のテーブル
+const META_TABLE: &str = "meta";
+/// デシリアライズできないイベントの隔離先
+const QUARANTINE_TABLE: &str = "quarantine";
+const TABLES: &[&str] = &[EVENTS_TABLE, META_TABLE, QUARANTINE_TABLE, HEADERS_TABLE];
+
+/// 次に採番するシーケンス番号を保持するキー
+const SEQUENCE_KEY: &[u8] = b"next_sequence";
+
 /// イベント通知コールバック型
 pub type EventNotificationCallback = Arc<
     dyn Fn(StoredEvent) -> std::pin::Pin<Box<dyn std::future::Future<Output = ()> + Send>>
@@ -28,20 +48,50 @@ pub type EventNotificationCallback = Arc<
         + Sync,
 >;
 
-pub struct EventStore {
-    env: Arc<Environment>,
-    events_db: Database,
-    meta_db: Database,
-    #[allow(dead_code)]
-    path: PathBuf,
-    current_map_size: Arc<Mutex<usize>>,
-    #[allow(dead_code)]
+pub struct EventStore<B: StorageBackend = LmdbBackend> {
+    backend: Arc<B>,
     durability_policy: DurabilityPolicy,
     /// イベント保存後の通知コールバック
     notification_callback: Arc<Mutex<Option<EventNotificationCallback>>>,
+    /// イベント追記の通知（購読タスクの起床用）
+    appended: Arc<tokio::sync::Notify>,
+    /// 記録時刻・業務日付の基準
+    time_provider: Arc<dyn TimeProvider>,
+    /// 集約単位のイベント読み取りキャッシュ
+    aggregate_cache: AggregateCache,
+    /// fsyncのまとめ役（グループコミットの場合のみ）
+    group_commit: Option<Arc<GroupCommitter>>,
+    /// 追記の計測値
+    append_recorder: Mutex<AppendRecorder>,
+    /// 参照専用のため隔離できなかった破損イベントのシーケンス番号
+    skipped_corrupted: Arc<Mutex<BTreeSet<u64>>>,
 }
 
-impl EventStore {
+/// 追記の待ち時間・件数の集計
+#[derive(Default)]
+struct AppendRecorder {
+    appends: u64,
+    events: u64,
+    total_latency: Duration,
+    max_latency: Duration,
+    first_started: Option<Instant>,
+    last_finished: Option<Instant>,
+}
+
+impl AppendRecorder {
+    fn record(&mut self, started: Instant, events: u64) {
+        let finished = Instant::now();
+        let latency = finished - started;
+        self.appends += 1;
+        self.events += events;
+        self.total_latency += latency;
+        self.max_latency = self.max_latency.max(latency);
+        self.first_started.get_or_insert(started);
+        self.last_finished = Some(finished);
+    }
+}
+
+impl EventStore<LmdbBackend> {
     pub async fn new(path: &Path) -> InfrastructureResult<Self> {
         Self::new_with_config(path, 100 * 1024 * 1024, DurabilityPolicy::default()).await
     }
@@ -60,54 +110,174 @@ impl EventStore {
             })?;
         }
 
-        let data_file = path.join("data.mdb");
-        let existing_size = if data_file.exists() {
-            tokio::fs::metadata(&data_file).await.map(|m| m.len() as usize).unwrap_or(0)
-        } else {
-            0
-        };
-
-        // 既存ファイルサイズに余裕を持たせるが、指数関数的な増加を防ぐ
-        // existing_size + 50%の余裕、または initial_map_size のいずれか大きい方
-        // ただし、最大10GBまでに制限（異常な増加を防ぐ）
-        const MAX_MAP_SIZE: usize = 10 * 1024 * 1024 * 1024; // 10GB
-        let calculated_size = std::cmp::max(initial_map_size, existing_size + (existing_size / 2));
-        let map_size = std::cmp::min(calculated_size, MAX_MAP_SIZE);
+        let backend = LmdbBackend::open(path, TABLES, initial_map_size, durability_policy)?;
 
-        let mut env_builder = Environment::new();
-        env_builder.set_max_dbs(2).set_map_size(map_size);
-
-        match durability_policy {
-            DurabilityPolicy::MaxDurability => {}
-            DurabilityPolicy::Balanced => {
-                env_builder.set_flags(EnvironmentFlags::NO_META_SYNC);
-            }
-            DurabilityPolicy::MaxPerformance => {
-                env_builder.set_flags(EnvironmentFlags::NO_SYNC | EnvironmentFlags::NO_META_SYNC);
+        // ヘッダー導入前に保存されたイベントのヘッダーを補う
+        {
+            let mut txn = backend.begin_write()?;
+            if backfill_headers(&mut txn)? > 0 {
+                txn.commit()?;
             }
         }
 
-        let env = env_builder
-            .open(path)
-            .map_err(|e| InfrastructureError::LmdbError(e.to_string()))?;
+        Ok(Self::with_backend(Arc::new(backend), durability_policy))
+    }
 
-        let events_db = env
-            .create_db(Some("events"), DatabaseFlags::empty())
-            .map_err(|e| InfrastructureError::LmdbError(e.to_string()))?;
+    /// 参照専用（レプリカ）としてオープン
+    ///
+    /// 書き込みプロセスが使用中のディレクトリを別プロセスから共有する。
+    /// LMDBの読み取りトランザクションは開始時点の最新コミットを参照するため、
+    /// 書き込みプロセスが追記したイベントは次回の読み取りから見える。
+    /// 追記は`ReadOnlyReplica`エラーとなる。
+    /// 書き込みプロセスの追記を検知できないため、集約キャッシュは使用しない。
+    /// 書き込みプロセスがヘッダーを作成するまではイベント本体を走査する。
+    /// 破損したレコードは隔離テーブルへ退避できないため、スキップして件数のみ記録する。
+    pub async fn open_read_only(path: &Path) -> InfrastructureResult<Self> {
+        let backend = LmdbBackend::open_read_only(path, TABLES)?;
+        Ok(Self::with_backend(Arc::new(backend), DurabilityPolicy::default())
+            .with_aggregate_cache_capacity(0))
+    }
+
+    /// LMDB環境に実際に設定されているフラグ
+    ///
+    /// 耐久性ポリシーどおりにfsyncが構成されているかの検証に使用する。
+    pub fn environment_flags(&self) -> InfrastructureResult<lmdb::EnvironmentFlags> {
+        self.backend.environment_flags()
+    }
+}
 
-        let meta_db = env
-            .create_db(Some("meta"), DatabaseFlags::empty())
-            .map_err(|e| InfrastructureError::LmdbError(e.to_string()))?;
+#[cfg(any(test, feature = "testkit"))]
+impl EventStore<crate::storage::MemoryBackend> {
+    /// メモリ上のEventStoreを作成（単体テスト用）
+    ///
+    /// 内容はプロセス終了とともに破棄される。
+    pub fn in_memory() -> Self {
+        let backend = crate::storage::MemoryBackend::new(TABLES);
+        Self::with_backend(Arc::new(backend), DurabilityPolicy::default())
+    }
+}
+
+impl<B: StorageBackend> EventStore<B> {
+    /// 任意のバックエンドでEventStoreを構築
+    ///
+    /// バックエンドは events / meta / quarantine / event_headers テーブルを持つこと。
+    /// グループコミットの場合は、並行する追記のfsyncをまとめて行う。
+    pub fn with_backend(backend: Arc<B>, durability_policy: DurabilityPolicy) -> Self {
+        let group_commit = match durability_policy {
+            DurabilityPolicy::GroupCommit { window } => {
+                let sync_backend = Arc::clone(&backend);
+                Some(GroupCommitter::new(window, Arc::new(move || sync_backend.sync())))
+            }
+            _ => None,
+        };
 
-        Ok(Self {
-            env: Arc::new(env),
-            events_db,
-            meta_db,
-            path: path.to_path_buf(),
-            current_map_size: Arc::new(Mutex::new(map_size)),
+        Self {
+            backend,
             durability_policy,
             notification_callback: Arc::new(Mutex::new(None)),
-        })
+            appended: Arc::new(tokio::sync::Notify::new()),
+            time_provider: Arc::new(SystemTimeProvider::local()),
+            aggregate_cache: AggregateCache::new(DEFAULT_AGGREGATE_CACHE_CAPACITY),
+            group_commit,
+            append_recorder: Mutex::new(AppendRecorder::default()),
+            skipped_corrupted: Arc::new(Mutex::new(BTreeSet::new())),
+        }
+    }
+
+    /// 記録時刻・業務日付の基準を設定（既定はシステム時計とローカルタイムゾーン）
+    pub fn with_time_provider(mut self, time_provider: Arc<dyn TimeProvider>) -> Self {
+        self.time_provider = time_provider;
+        self
+    }
+
+    /// 集約キャッシュに保持する集約数を設定（0でキャッシュしない）
+    pub fn with_aggregate_cache_capacity(mut self, capacity: usize) -> Self {
+        self.aggregate_cache = AggregateCache::new(capacity);
+        self
+    }
+
+    /// 記録時刻（RFC3339）と業務日付（YYYY-MM-DD）
+    fn recorded_at(&self) -> (String, String) {
+        let now = self.time_provider.now();
+        let business_date = self.time_provider.business_date_of(now);
+        (now.to_rfc3339(), business_date.format("%Y-%m-%d").to_string())
+    }
+
+    /// 参照専用（レプリカ）としてオープンしたか
+    pub fn is_read_only(&self) -> bool {
+        self.backend.is_read_only()
+    }
+
+    /// 書き込み可能であることを確認
+    fn ensure_writable(&self) -> InfrastructureResult<()> {
+        if self.is_read_only() {
+            return Err(InfrastructureError::ReadOnlyReplica("event store".to_string()));
+        }
+        Ok(())
+    }
+
+    /// 書き込み時の耐久性ポリシー
+    pub fn durability_policy(&self) -> DurabilityPolicy {
+        self.durability_policy
+    }
+
+    /// 追記の計測値（起動以降）
+    pub fn append_metrics(&self) -> AppendMetrics {
+        let recorder = self.append_recorder.lock().unwrap();
+        let elapsed = match (recorder.first_started, recorder.last_finished) {
+            (Some(first), Some(last)) => last - first,
+            _ => Duration::ZERO,
+        };
+        AppendMetrics {
+            policy: self.durability_policy.label(),
+            appends: recorder.appends,
+            events: recorder.events,
+            syncs: self.group_commit.as_ref().map_or(0, |group| group.sync_count()),
+            total_latency: recorder.total_latency,
+            max_latency: recorder.max_latency,
+            elapsed,
+        }
+    }
+
+    /// 書き込みトランザクションの開始を登録（グループコミットの場合のみ）
+    fn begin_write(&self) -> Option<WriteTicket> {
+        self.group_commit.as_ref().map(|group| group.begin_write())
+    }
+
+    /// コミット後の完了処理
+    ///
+    /// グループコミットではfsyncを待ってから追記を完了とし、計測値を記録する。
+    async fn finish_append(
+        &self,
+        ticket: Option<WriteTicket>,
+        started: Instant,
+        events: u64,
+    ) -> InfrastructureResult<()> {
+        if let (Some(group), Some(ticket)) = (&self.group_commit, ticket) {
+            group.wait_synced(ticket).await?;
+        }
+        self.append_recorder.lock().unwrap().record(started, events);
+        Ok(())
+    }
+
+    /// バックエンドのブロッキング操作をワーカースレッドで実行
+    async fn blocking<R, F>(&self, f: F) -> InfrastructureResult<R>
+    where
+        R: Send + 'static,
+        F: FnOnce(&B) -> InfrastructureResult<R> + Send + 'static,
+    {
+        let backend = Arc::clone(&self.backend);
+        tokio::task::spawn_blocking(move || f(&backend))
+            .await
+            .map_err(|e| InfrastructureError::TransactionFailed(e.to_string()))?
+    }
+
+    /// 未同期の書き込みをディスクへ強制的にfsync
+    ///
+    /// Balanced / MaxPerformance ではコミット時にfsyncされないため、
+    /// 終了処理など耐久性を確定させたい時点で呼び出す。
+    pub async fn sync(&self) -> InfrastructureResult<()> {
+        self.blocking(|backend| backend.sync()).await
     }
 
     /// 複数イベントを一括追記
@@ -125,97 +295,129 @@ impl EventStore {
     ///
     /// # Errors
     /// - イベントのシリアライズに失敗した場合
-    /// - LMDBへの書き込みに失敗した場合
+    /// - ストレージへの書き込みに失敗した場合
     /// - トランザクションのコミットに失敗した場合
     pub async fn append<T>(&self, aggregate_id: &str, events: Vec<T>) -> InfrastructureResult<u64>
     where
         T: serde::Serialize + Send + 'static,
     {
-        if events.is_empty() {
+        self.append_batches(vec![(aggregate_id.to_string(), events)]).await
+    }
+
+    /// 複数集約のイベントを一括追記
+    ///
+    /// 集約ごとのイベントをすべて同一トランザクションで保存する。
+    /// 途中で失敗した場合は、いずれの集約のイベントも保存されない。
+    ///
+    /// # Arguments
+    /// * `batches` - 集約IDと保存するドメインイベントのリストの組（保存順）
+    ///
+    /// # Returns
+    /// 最後に保存されたイベントのシーケンス番号
+    pub async fn append_batches<T>(
+        &self,
+        batches: Vec<(String, Vec<T>)>,
+    ) -> InfrastructureResult<u64>
+    where
+        T: serde::Serialize + Send + 'static,
+    {
+        self.ensure_writable()?;
+        if batches.is_empty() || batches.iter().any(|(_, events)| events.is_empty()) {
             return Err(InfrastructureError::ValidationFailed(
                 "Cannot append empty event list".to_string(),
             ));
         }
 
-        let aggregate_id = aggregate_id.to_string();
-        let env = Arc::clone(&self.env);
-        let events_db = self.events_db;
-        let meta_db = self.meta_db;
+        let started = Instant::now();
+        let event_count = batches.iter().map(|(_, events)| events.len() as u64).sum();
+        let (timestamp, business_date) = self.recorded_at();
+        let cache_keys: Vec<String> =
+            batches.iter().map(|(aggregate_id, _)| aggregate_id.clone()).collect();
+        // 追記中に読み取った集約をキャッシュに残さないよう、書き込み前にも無効化する
+        for aggregate_id in &cache_keys {
+            self.aggregate_cache.invalidate(aggregate_id);
+        }
 
         // イベントを事前にシリアライズ
-        let serialized_events: Vec<Vec<u8>> = events
+        let serialized_events: Vec<(String, Vec<u8>)> = batches
             .into_iter()
-            .map(|event| {
+            .flat_map(|(aggregate_id, events)| {
+                events.into_iter().map(move |event| (aggregate_id.clone(), event))
+            })
+            .map(|(aggregate_id, event)| {
                 serde_json::to_vec(&event)
+                    .map(|data| (aggregate_id, data))
                     .map_err(|e| InfrastructureError::SerializationFailed(e.to_string()))
             })
             .collect::<Result<Vec<_>, _>>()?;
 
-        let (last_sequence, stored_events) = tokio::task::spawn_blocking(move || {
-            let mut txn =
-                env.begin_rw_txn().map_err(|e| InfrastructureError::LmdbError(e.to_string()))?;
-
-            // グローバルシーケンス取得
-            let seq_key = b"next_sequence";
-            let mut current_sequence = match txn.get(meta_db, &seq_key) {
-                Ok(bytes) => {
-                    let arr = bytes.as_array::<8>().ok_or_else(|| {
-                        InfrastructureError::DeserializationFailed("Invalid sequence".to_string())
-                    })?;
-                    u64::from_be_bytes(*arr)
-                }
-                Err(lmdb::Error::NotFound) => 0,
-                Err(e) => return Err(InfrastructureError::LmdbError(e.to_string())),
-            };
-
-            let timestamp = chrono::Utc::now().to_rfc3339();
-            let mut last_seq = 0u64;
-            let mut stored_events = Vec::new();
-
-            // 各イベントを保存
-            for event_data in serialized_events {
-                current_sequence += 1;
-                last_seq = current_sequence;
-
-                // payloadからイベントタイプを抽出
-                let event_type = if let Ok(json_value) =
-                    serde_json::from_slice::<serde_json::Value>(&event_data)
-                {
-                    json_value.get("type").and_then(|v| v.as_str()).unwrap_or("Unknown").to_string()
-                } else {
-                    "Unknown".to_string()
-                };
+        let ticket = self.begin_write();
+        let result = self
+            .blocking(move |backend| {
+                let mut txn = backend.begin_write()?;
+
+                // グローバルシーケンス取得
+                let mut current_sequence = read_sequence(&txn)?;
+
+                let mut last_seq = 0u64;
+                let mut stored_events = Vec::new();
+
+                // 各イベントを保存
+                for (aggregate_id, event_data) in serialized_events {
+                    current_sequence += 1;
+                    last_seq = current_sequence;
+
+                    // payloadからイベントタイプを抽出
+                    let event_type = if let Ok(json_value) =
+                        serde_json::from_slice::<serde_json::Value>(&event_data)
+                    {
+                        json_value
+                            .get("type")
+                            .and_then(|v| v.as_str())
+                            .unwrap_or("Unknown")
+                            .to_string()
+                    } else {
+                        "Unknown".to_string()
+                    };
+
+                    // StoredEvent構造を構築
+                    let stored_event = StoredEvent {
+                        global_sequence: current_sequence,
+                        event_type,
+                        aggregate_id,
+                        version: current_sequence, // バージョンはシーケンスと同じ
+                        timestamp: timestamp.clone(),
+                        business_date: Some(business_date.clone()),
+                        payload: event_data,
+                        checksum: None,
+                    }
+                    .with_checksum();
 
-                // StoredEvent構造を構築
-                let stored_event = StoredEvent {
-                    global_sequence: current_sequence,
-                    event_type,
-                    aggregate_id: aggregate_id.clone(),
-                    version: current_sequence, // バージョンはシーケンスと同じ
-                    timestamp: timestamp.clone(),
-                    payload: event_data,
-                };
+                    let event_value = serde_json::to_vec(&stored_event)
+                        .map_err(|e| InfrastructureError::SerializationFailed(e.to_string()))?;
+                    txn.put(EVENTS_TABLE, &current_sequence.to_be_bytes(), &event_value)?;
+                    put_header(&mut txn, &stored_event)?;
 
-                let event_key = current_sequence.to_be_bytes();
-                let event_value = serde_json::to_vec(&stored_event)
-                    .map_err(|e| InfrastructureError::SerializationFailed(e.to_string()))?;
+                    stored_events.push(stored_event);
+                }
 
-                txn.put(events_db, &event_key, &event_value, WriteFlags::empty())
-                    .map_err(|e| InfrastructureError::LmdbError(e.to_string()))?;
+                // 最新シーケンス番号を更新
+                txn.put(META_TABLE, SEQUENCE_KEY, &current_sequence.to_be_bytes())?;
 
-                stored_events.push(stored_event);
-            }
+                crash_point::reached(CrashPoint::AppendBeforeCommit);
+                txn.commit()?;
+                crash_point::reached(CrashPoint::AppendAfterCommit);
 
-            // 最新シーケンス番号を更新
-            txn.put(meta_db, &seq_key, &current_sequence.to_be_bytes(), WriteFlags::empty())
-                .map_err(|e| InfrastructureError::LmdbError(e.to_string()))?;
+                Ok((last_seq, stored_events))
+            })
+            .await;
+        for aggregate_id in &cache_keys {
+            self.aggregate_cache.invalidate(aggregate_id);
+        }
+        let (last_sequence, stored_events) = result?;
+        self.finish_append(ticket, started, event_count).await?;
 
-            txn.commit().map_err(|e| InfrastructureError::LmdbError(e.to_string()))?;
-
-            Ok::<(u64, Vec<StoredEvent>), InfrastructureError>((last_seq, stored_events))
-        })
-        .await
-        .map_err(|e| InfrastructureError::LmdbError(e.to_string()))??;
+        self.appended.notify_waiters();
 
         // イベント通知を送信
         if let Some(callback) = self.notification_callback.lock().unwrap().as_ref() {
@@ -239,75 +441,70 @@ impl EventStore {
         expected_version: ExpectedVersion,
         payload: &[u8],
     ) -> InfrastructureResult<Sequence> {
+        self.ensure_writable()?;
+        let started = Instant::now();
         let event_type = event_type.to_string();
         let aggregate_id = aggregate_id.to_string();
         let payload = payload.to_vec();
+        let (timestamp, business_date) = self.recorded_at();
+        self.aggregate_cache.invalidate(&aggregate_id);
+        let cache_key = aggregate_id.clone();
+
+        let ticket = self.begin_write();
+        let result = self
+            .blocking(move |backend| {
+                let mut txn = backend.begin_write()?;
+
+                // 楽観的ロックチェック（必要に応じて）
+                if !expected_version.matches(version.saturating_sub(1)) {
+                    return Err(InfrastructureError::ConcurrencyConflict {
+                        aggregate_id: aggregate_id.clone(),
+                        expected: expected_version.0,
+                        actual: version.saturating_sub(1),
+                    });
+                }
 
-        let env = Arc::clone(&self.env);
-        let events_db = self.events_db;
-        let meta_db = self.meta_db;
-
-        let sequence = tokio::task::spawn_blocking(move || {
-            let mut txn =
-                env.begin_rw_txn().map_err(|e| InfrastructureError::LmdbError(e.to_string()))?;
-
-            // 楽観的ロックチェック（必要に応じて）
-            if !expected_version.matches(version.saturating_sub(1)) {
-                return Err(InfrastructureError::ConcurrencyConflict {
-                    aggregate_id: aggregate_id.clone(),
-                    expected: expected_version.0,
-                    actual: version.saturating_sub(1),
-                });
-            }
+                // グローバルシーケンス発番
+                let global_sequence = Sequence::new(read_sequence(&txn)? + 1);
+                txn.put(META_TABLE, SEQUENCE_KEY, &global_sequence.to_be_bytes())?;
 
-            // グローバルシーケンス発番
-            let seq_key = b"next_sequence";
-            let current = match txn.get(meta_db, &seq_key) {
-                Ok(bytes) => {
-                    // 現代Rust: as_array による型安全な変換
-                    let arr = bytes.as_array::<8>().ok_or_else(|| {
-                        InfrastructureError::DeserializationFailed("Invalid sequence".to_string())
-                    })?;
-                    u64::from_be_bytes(*arr)
+                // イベント構築
+                let stored_event = StoredEvent {
+                    global_sequence: global_sequence.as_u64(),
+                    event_type,
+                    aggregate_id,
+                    version,
+                    timestamp,
+                    business_date: Some(business_date),
+                    payload,
+                    checksum: None,
                 }
-                Err(lmdb::Error::NotFound) => 0,
-                Err(e) => return Err(InfrastructureError::LmdbError(e.to_string())),
-            };
-
-            let global_sequence = Sequence::new(current + 1);
-            txn.put(meta_db, &seq_key, &global_sequence.to_be_bytes(), WriteFlags::empty())
-                .map_err(|e| InfrastructureError::LmdbError(e.to_string()))?;
-
-            // イベント構築
-            let stored_event = StoredEvent {
-                global_sequence: global_sequence.as_u64(),
-                event_type,
-                aggregate_id,
-                version,
-                timestamp: chrono::Utc::now().to_rfc3339(),
-                payload,
-            };
-
-            let event_key = global_sequence.to_be_bytes();
-            let event_value = serde_json::to_vec(&stored_event)
-                .map_err(|e| InfrastructureError::SerializationFailed(e.to_string()))?;
+                .with_checksum();
 
-            txn.put(events_db, &event_key, &event_value, WriteFlags::empty())
-                .map_err(|e| InfrastructureError::LmdbError(e.to_string()))?;
+                let event_value = serde_json::to_vec(&stored_event)
+                    .map_err(|e| InfrastructureError::SerializationFailed(e.to_string()))?;
+                txn.put(EVENTS_TABLE, &global_sequence.to_be_bytes(), &event_value)?;
+                put_header(&mut txn, &stored_event)?;
 
-            txn.commit().map_err(|e| InfrastructureError::LmdbError(e.to_string()))?;
+                crash_point::reached(CrashPoint::AppendBeforeCommit);
+                txn.commit()?;
+                crash_point::reached(CrashPoint::AppendAfterCommit);
 
-            Ok::<Sequence, InfrastructureError>(global_sequence)
-        })
-        .await
-        .map_err(|e| InfrastructureError::LmdbError(e.to_string()))??;
+                Ok(global_sequence)
+            })
+            .await;
+        self.aggregate_cache.invalidate(&cache_key);
+        let sequence = result?;
+        self.finish_append(ticket, started, 1).await?;
+
+        self.appended.notify_waiters();
 
         Ok(sequence)
     }
 
     /// イベントストリームを取得（Iterator指向）
-    pub fn stream_events(&self, from_sequence: Sequence) -> EventStream {
-        EventStreamBuilder::new(Arc::clone(&self.env), self.events_db)
+    pub fn stream_events(&self, from_sequence: Sequence) -> EventStream<B> {
+        EventStreamBuilder::new(Arc::clone(&self.backend))
             .from_sequence(from_sequence)
             .build()
     }
@@ -317,8 +514,8 @@ impl EventStore {
         &self,
         aggregate_id: AggregateId,
         from_sequence: Sequence,
-    ) -> EventStream {
-        EventStreamBuilder::new(Arc::clone(&self.env), self.events_db)
+    ) -> EventStream<B> {
+        EventStreamBuilder::new(Arc::clone(&self.backend))
             .from_sequence(from_sequence)
             .for_aggregate(aggregate_id)
             .build()
@@ -336,67 +533,87 @@ impl EventStore {
     /// イベントのベクタ（シーケンス順）
     ///
     /// # Errors
-    /// - LMDBからの読み取りに失敗した場合
+    /// - ストレージからの読み取りに失敗した場合
     /// - イベントのデシリアライズに失敗した場合
     pub async fn get_events(&self, aggregate_id: &str) -> InfrastructureResult<Vec<StoredEvent>> {
+        if let Some(events) = self.aggregate_cache.get(aggregate_id) {
+            return Ok(events);
+        }
+        let generation = self.aggregate_cache.generation();
+        let cache_key = aggregate_id.to_string();
         let aggregate_id = aggregate_id.to_string();
-        let env = Arc::clone(&self.env);
-        let events_db = self.events_db;
-
-        let events = tokio::task::spawn_blocking(move || {
-            let txn =
-                env.begin_ro_txn().map_err(|e| InfrastructureError::LmdbError(e.to_string()))?;
-
-            let cursor = txn
-                .open_ro_cursor(events_db)
-                .map_err(|e| InfrastructureError::LmdbError(e.to_string()))?;
-
-            let mut events = Vec::new();
-
-            // データベースが空かどうかを確認するため、最初のキーを取得を試みる
-            // 空の場合はNotFoundエラーが返される
-            match cursor.get(None, None, ffi::MDB_FIRST) {
-                Ok((_, value)) => {
-                    // 最初のイベントを処理
-                    let event: StoredEvent = serde_json::from_slice(value)
-                        .map_err(|e| InfrastructureError::DeserializationFailed(e.to_string()))?;
-
-                    if event.aggregate_id == aggregate_id {
-                        events.push(event);
-                    }
 
-                    // 残りのイベントを処理
-                    loop {
-                        match cursor.get(None, None, ffi::MDB_NEXT) {
-                            Ok((_, value)) => {
-                                let event: StoredEvent =
-                                    serde_json::from_slice(value).map_err(|e| {
-                                        InfrastructureError::DeserializationFailed(e.to_string())
-                                    })?;
-
-                                if event.aggregate_id == aggregate_id {
-                                    events.push(event);
-                                }
+        let skipped_corrupted = Arc::clone(&self.skipped_corrupted);
+
+        let events = self
+            .blocking(move |backend| {
+                let mut events = Vec::new();
+                let mut corrupted = None;
+                let txn = backend.begin_read()?;
+
+                if headers_available(&txn)? {
+                    // ヘッダーで集約を絞り込み、一致したイベントのみデシリアライズする
+                    let scan =
+                        matching_sequences(&txn, 0, usize::MAX, usize::MAX, &mut |header| {
+                            header.aggregate_id == aggregate_id
+                        })?;
+                    for global_sequence in scan.sequences {
+                        match load_event(&txn, global_sequence) {
+                            Ok(event) => events.push(event),
+                            Err(InfrastructureError::ChecksumMismatch { detail, .. }) => {
+                                let value = txn
+                                    .get(EVENTS_TABLE, &global_sequence.to_be_bytes())?
+                                    .unwrap_or_default();
+                                corrupted =
+                                    Some(QuarantinedEvent::new(global_sequence, value, detail));
+                                break;
                             }
-                            Err(lmdb::Error::NotFound) => break,
-                            Err(e) => return Err(InfrastructureError::LmdbError(e.to_string())),
+                            Err(e) => return Err(e),
                         }
                     }
+                } else {
+                    txn.scan(EVENTS_TABLE, None, &mut |key, value| {
+                        let event: StoredEvent = serde_json::from_slice(value).map_err(|e| {
+                            InfrastructureError::DeserializationFailed(e.to_string())
+                        })?;
+                        if event.aggregate_id == aggregate_id {
+                            if let Err(e) = event.verify_checksum() {
+                                let global_sequence = key
+                                    .as_array::<8>()
+                                    .map(|arr| u64::from_be_bytes(*arr))
+                                    .unwrap_or(event.global_sequence);
+                                corrupted =
+                                    Some(QuarantinedEvent::new(global_sequence, value.to_vec(), e));
+                                return Ok(false);
+                            }
+                            events.push(event);
+                        }
+                        Ok(true)
+                    })?;
                 }
-                Err(lmdb::Error::NotFound) => {
-                    // データベースが空の場合は空のベクタを返す
+                drop(txn);
+
+                // 破損したイベントを含む集約は再現できないため、隔離したうえでエラーとする
+                if let Some(quarantined) = corrupted {
+                    quarantine_records(
+                        backend,
+                        &skipped_corrupted,
+                        std::slice::from_ref(&quarantined),
+                    )?;
+                    return Err(InfrastructureError::ChecksumMismatch {
+                        global_sequence: quarantined.global_sequence,
+                        detail: quarantined.error,
+                    });
                 }
-                Err(e) => return Err(InfrastructureError::LmdbError(e.to_string())),
-            }
 
-            // シーケンス順にソート（念のため）
-            events.sort_by_key(|e| e.global_sequence);
+                // シーケンス順にソート（念のため）
+                events.sort_by_key(|e| e.global_sequence);
 
-            Ok::<Vec<StoredEvent>, InfrastructureError>(events)
-        })
-        .await
-        .map_err(|e| InfrastructureError::LmdbError(e.to_string()))??;
+                Ok(events)
+            })
+            .await?;
 
+        self.aggregate_cache.insert(&cache_key, events.clone(), generation);
         Ok(events)
     }
 
@@ -404,6 +621,8 @@ impl EventStore {
     ///
     /// Projection再構築用のメソッド。指定されたシーケンス番号以降の
     /// すべてのイベントをシーケンス順に取得する。
+    /// デシリアライズできないレコードやチェックサムが一致しないレコードは
+    /// 隔離テーブルへ退避してスキップする（参照専用の場合は退避せずにスキップする）。
     ///
     /// # Arguments
     /// * `from_sequence` - 開始シーケンス番号（この番号を含む）
@@ -412,165 +631,208 @@ impl EventStore {
     /// イベントのベクタ（シーケンス順）
     ///
     /// # Errors
-    /// - LMDBからの読み取りに失敗した場合
-    /// - イベントのデシリアライズに失敗した場合
+    /// - ストレージの読み書きに失敗した場合
     pub async fn get_all_events(
         &self,
         from_sequence: u64,
     ) -> InfrastructureResult<Vec<StoredEvent>> {
-        let env = Arc::clone(&self.env);
-        let events_db = self.events_db;
-
-        let events = tokio::task::spawn_blocking(move || {
-            let txn =
-                env.begin_ro_txn().map_err(|e| InfrastructureError::LmdbError(e.to_string()))?;
-
-            let cursor = txn
-                .open_ro_cursor(events_db)
-                .map_err(|e| InfrastructureError::LmdbError(e.to_string()))?;
-
+        let skipped_corrupted = Arc::clone(&self.skipped_corrupted);
+        self.blocking(move |backend| {
             let mut events = Vec::new();
+            let mut poisoned = Vec::new();
 
             // 指定されたシーケンス番号から開始
-            let start_key = from_sequence.to_be_bytes();
-
-            // カーソルを指定されたキー以降に移動
-            match cursor.get(Some(&start_key), None, ffi::MDB_SET_RANGE) {
-                Ok((_, value)) => {
-                    // 最初のイベントを処理
-                    let event: StoredEvent = serde_json::from_slice(value)
-                        .map_err(|e| InfrastructureError::DeserializationFailed(e.to_string()))?;
-
-                    if event.global_sequence >= from_sequence {
-                        events.push(event);
-                    }
-
-                    // 残りのイベントを処理
-                    loop {
-                        match cursor.get(None, None, ffi::MDB_NEXT) {
-                            Ok((_, value)) => {
-                                let event: StoredEvent =
-                                    serde_json::from_slice(value).map_err(|e| {
-                                        InfrastructureError::DeserializationFailed(e.to_string())
-                                    })?;
-
-                                if event.global_sequence >= from_sequence {
-                                    events.push(event);
-                                }
+            backend.begin_read()?.scan(
+                EVENTS_TABLE,
+                Some(&from_sequence.to_be_bytes()),
+                &mut |key, value| {
+                    let global_sequence = || {
+                        key.as_array::<8>().map(|arr| u64::from_be_bytes(*arr)).unwrap_or_default()
+                    };
+                    match serde_json::from_slice::<StoredEvent>(value) {
+                        Ok(event) => {
+                            if event.global_sequence < from_sequence {
+                                return Ok(true);
+                            }
+                            match event.verify_checksum() {
+                                Ok(()) => events.push(event),
+                                Err(e) => poisoned.push(QuarantinedEvent::new(
+                                    global_sequence(),
+                                    value.to_vec(),
+                                    e,
+                                )),
                             }
-                            Err(lmdb::Error::NotFound) => break,
-                            Err(e) => return Err(InfrastructureError::LmdbError(e.to_string())),
+                        }
+                        Err(e) => {
+                            poisoned.push(QuarantinedEvent::new(
+                                global_sequence(),
+                                value.to_vec(),
+                                e.to_string(),
+                            ));
                         }
                     }
-                }
-                Err(lmdb::Error::NotFound) => {
-                    // 指定されたシーケンス以降のイベントがない場合は空のベクタを返す
-                }
-                Err(e) => return Err(InfrastructureError::LmdbError(e.to_string())),
-            }
+                    Ok(true)
+                },
+            )?;
+
+            quarantine_records(backend, &skipped_corrupted, &poisoned)?;
 
             // シーケンス順にソート（念のため）
             events.sort_by_key(|e| e.global_sequence);
 
-            Ok::<Vec<StoredEvent>, InfrastructureError>(events)
+            Ok(events)
         })
         .await
-        .map_err(|e| InfrastructureError::LmdbError(e.to_string()))??;
-
-        Ok(events)
     }
 
-    /// 最新シーケンス取得
-    pub async fn get_latest_sequence(&self) -> InfrastructureResult<Sequence> {
-        let env = Arc::clone(&self.env);
-        let meta_db = self.meta_db;
-
-        let result = tokio::task::spawn_blocking(move || {
-            let txn =
-                env.begin_ro_txn().map_err(|e| InfrastructureError::LmdbError(e.to_string()))?;
-
-            let key = b"next_sequence";
-            match txn.get(meta_db, &key) {
-                Ok(bytes) => {
-                    let arr = bytes.as_array::<8>().ok_or_else(|| {
-                        InfrastructureError::DeserializationFailed("Invalid sequence".to_string())
-                    })?;
-                    Ok(Sequence::from_be_bytes(*arr))
-                }
-                Err(lmdb::Error::NotFound) => Ok(Sequence::new(0)),
-                Err(e) => Err(InfrastructureError::LmdbError(e.to_string())),
-            }
+    /// イベントを隔離テーブルへ退避
+    ///
+    /// 既に隔離済みの場合は最初の記録を保持する。
+    pub async fn quarantine_event(
+        &self,
+        quarantined: QuarantinedEvent,
+    ) -> InfrastructureResult<()> {
+        self.blocking(move |backend| {
+            let mut txn = backend.begin_write()?;
+            put_quarantined(&mut txn, &quarantined)?;
+            txn.commit()
         })
         .await
-        .map_err(|e| InfrastructureError::LmdbError(e.to_string()))??;
-
-        Ok(result)
     }
 
-    /// ストレージメトリクス取得
-    pub async fn get_storage_metrics(&self) -> InfrastructureResult<StorageMetrics> {
-        let env = Arc::clone(&self.env);
-        let events_db = self.events_db;
-        let current_map_size = *self.current_map_size.lock().unwrap();
+    /// 隔離中のイベント一覧を取得（シーケンス順）
+    pub async fn get_quarantined_events(&self) -> InfrastructureResult<Vec<QuarantinedEvent>> {
+        self.blocking(|backend| {
+            let mut quarantined = Vec::new();
+            backend.begin_read()?.scan(QUARANTINE_TABLE, None, &mut |_, value| {
+                quarantined.push(
+                    serde_json::from_slice(value)
+                        .map_err(|e| InfrastructureError::DeserializationFailed(e.to_string()))?,
+                );
+                Ok(true)
+            })?;
+            Ok(quarantined)
+        })
+        .await
+    }
 
-        let metrics = tokio::task::spawn_blocking(move || {
-            let txn =
-                env.begin_ro_txn().map_err(|e| InfrastructureError::LmdbError(e.to_string()))?;
+    /// 隔離中のイベントを取得
+    pub async fn get_quarantined_event(
+        &self,
+        global_sequence: u64,
+    ) -> InfrastructureResult<Option<QuarantinedEvent>> {
+        self.blocking(move |backend| {
+            backend
+                .begin_read()?
+                .get(QUARANTINE_TABLE, &global_sequence.to_be_bytes())?
+                .map(|value| {
+                    serde_json::from_slice(&value)
+                        .map_err(|e| InfrastructureError::DeserializationFailed(e.to_string()))
+                })
+                .transpose()
+        })
+        .await
+    }
 
-            use lmdb_sys as ffi;
+    /// 隔離中のイベントを修復
+    ///
+    /// 修復後のイベントでevents テーブルのレコードを上書きし、隔離を解除する。
+    /// チェックサムは修復後のペイロードから再計算する。
+    /// ペイロードがJSONとして読めない場合は修復しない。
+    pub async fn repair_event(&self, event: StoredEvent) -> InfrastructureResult<()> {
+        let event = event.with_checksum();
+        validate_payload(&event).map_err(InfrastructureError::ValidationFailed)?;
+        self.aggregate_cache.invalidate(&event.aggregate_id);
+
+        self.blocking(move |backend| {
+            let key = event.global_sequence.to_be_bytes();
+            let value = serde_json::to_vec(&event)
+                .map_err(|e| InfrastructureError::SerializationFailed(e.to_string()))?;
 
-            let mut env_stat: ffi::MDB_stat = unsafe { std::mem::zeroed() };
-            let mut env_info: ffi::MDB_envinfo = unsafe { std::mem::zeroed() };
+            let mut txn = backend.begin_write()?;
+            txn.put(EVENTS_TABLE, &key, &value)?;
+            put_header(&mut txn, &event)?;
+            txn.delete(QUARANTINE_TABLE, &key)?;
+            txn.commit()
+        })
+        .await
+    }
 
-            let env_ptr = env.as_ref() as *const lmdb::Environment as *mut ffi::MDB_env;
+    /// 隔離中のイベントを破棄
+    ///
+    /// events テーブルからもレコードを削除する。修復不能な場合のみ使用すること。
+    pub async fn discard_quarantined_event(
+        &self,
+        global_sequence: u64,
+    ) -> InfrastructureResult<()> {
+        // 破棄するイベントの集約は特定できないため、キャッシュ全体を無効化する
+        self.aggregate_cache.clear();
+        self.blocking(move |backend| {
+            let key = global_sequence.to_be_bytes();
+            let mut txn = backend.begin_write()?;
+            txn.delete(EVENTS_TABLE, &key)?;
+            txn.delete(HEADERS_TABLE, &key)?;
+            txn.delete(QUARANTINE_TABLE, &key)?;
+            txn.commit()
+        })
+        .await
+    }
 
-            unsafe {
-                let ret = ffi::mdb_env_stat(env_ptr, &mut env_stat);
-                if ret != 0 {
-                    return Err(InfrastructureError::LmdbError(format!(
-                        "mdb_env_stat failed: {}",
-                        ret
-                    )));
-                }
+    /// テスト用：events テーブルへ生データを直接書き込む（破損レコードの再現用）
+    #[cfg(test)]
+    pub(crate) async fn put_raw_event(
+        &self,
+        global_sequence: u64,
+        raw: Vec<u8>,
+    ) -> InfrastructureResult<()> {
+        self.aggregate_cache.clear();
+        let mut txn = self.backend.begin_write()?;
+        txn.put(EVENTS_TABLE, &global_sequence.to_be_bytes(), &raw)?;
+        txn.commit()
+    }
 
-                let ret = ffi::mdb_env_info(env_ptr, &mut env_info);
-                if ret != 0 {
-                    return Err(InfrastructureError::LmdbError(format!(
-                        "mdb_env_info failed: {}",
-                        ret
-                    )));
-                }
-            }
+    /// 最新シーケンス取得
+    pub async fn get_latest_sequence(&self) -> InfrastructureResult<Sequence> {
+        self.blocking(|backend| read_sequence(&backend.begin_read()?).map(Sequence::new))
+            .await
+    }
 
-            let mut db_stat: ffi::MDB_stat = unsafe { std::mem::zeroed() };
-            unsafe {
-                let ret = ffi::mdb_stat(txn.txn(), events_db.dbi(), &mut db_stat);
-                if ret != 0 {
-                    return Err(InfrastructureError::LmdbError(format!(
-                        "mdb_stat failed: {}",
-                        ret
-                    )));
+    /// ストレージメトリクス取得
+    pub async fn get_storage_metrics(&self) -> InfrastructureResult<StorageMetrics> {
+        let cache = self.aggregate_cache.stats();
+        let skipped_corrupted: Vec<u64> =
+            self.skipped_corrupted.lock().unwrap().iter().copied().collect();
+        self.blocking(move |backend| {
+            let stats = backend.stats()?;
+            let txn = backend.begin_read()?;
+            let usage_percent = (stats.used_size as f64 * 100.0) / stats.map_size as f64;
+            // 隔離できなかったものは、書き込みプロセスが隔離済みでなければ件数に含める
+            let mut quarantined_events = txn.entry_count(QUARANTINE_TABLE)?;
+            for global_sequence in skipped_corrupted {
+                if txn.get(QUARANTINE_TABLE, &global_sequence.to_be_bytes())?.is_none() {
+                    quarantined_events += 1;
                 }
             }
 
-            let page_size = env_stat.ms_psize as usize;
-            let last_page_no = env_info.me_last_pgno;
-            let used_size = page_size * last_page_no;
-            let usage_percent = (used_size as f64 * 100.0) / current_map_size as f64;
-
-            Ok::<StorageMetrics, InfrastructureError>(StorageMetrics {
-                map_size: current_map_size,
-                used_size,
+            Ok(StorageMetrics {
+                map_size: stats.map_size,
+                used_size: stats.used_size,
                 usage_percent,
-                page_size,
-                last_page_no,
-                entries: db_stat.ms_entries,
+                page_size: stats.page_size,
+                last_page_no: stats.last_page_no,
+                entries: txn.entry_count(EVENTS_TABLE)?,
+                quarantined_events,
+                aggregate_cache_hits: cache.hits,
+                aggregate_cache_misses: cache.misses,
+                aggregate_cache_entries: cache.entries,
             })
         })
         .await
-        .map_err(|e| InfrastructureError::LmdbError(e.to_string()))??;
+    }
 
-        Ok(metrics)
+    /// 集約キャッシュの利用状況
+    pub fn aggregate_cache_stats(&self) -> AggregateCacheStats {
+        self.aggregate_cache.stats()
     }
 
     /// デバッグ用：std::fmt::from_fn によるイベントダンプ
@@ -608,4 +870,79 @@ impl EventStore {
     pub fn clear_notification_callback(&self) {
         *self.notification_callback.lock().unwrap() = None;
     }
+
+    /// イベントストリームを購読
+    ///
+    /// 外部連携（データウェアハウス同期、通知ボット等）向けに、
+    /// 指定シーケンス以降のイベントをフィルタ条件に一致するものだけ配信する。
+    /// 既存イベントの配信後は新規追記を待ち受ける。
+    ///
+    /// # Arguments
+    /// * `from_sequence` - 開始シーケンス番号（この番号を含む）
+    /// * `filter` - イベントタイプ・集約IDプレフィックスによるフィルタ
+    /// * `buffer` - 未受信イベントの上限件数（バックプレッシャー）
+    pub fn subscribe(
+        &self,
+        from_sequence: u64,
+        filter: SubscriptionFilter,
+        buffer: usize,
+    ) -> EventSubscription {
+        EventSubscription::spawn(
+            Arc::clone(&self.backend),
+            Arc::clone(&self.appended),
+            from_sequence,
+            filter,
+            buffer,
+        )
+    }
+}
+
+/// 採番済みの最新シーケンス番号を読み取る（未採番の場合は0）
+fn read_sequence(txn: &impl KvRead) -> InfrastructureResult<u64> {
+    match txn.get(META_TABLE, SEQUENCE_KEY)? {
+        Some(bytes) => {
+            let arr = bytes.as_array::<8>().ok_or_else(|| {
+                InfrastructureError::DeserializationFailed("Invalid sequence".to_string())
+            })?;
+            Ok(u64::from_be_bytes(*arr))
+        }
+        None => Ok(0),
+    }
+}
+
+/// 隔離レコードを書き込む（既存の記録は保持）
+/// 破損したイベントを隔離テーブルへ退避
+///
+/// 参照専用の場合は書き込めないため、退避せずにメモリ上で記録する。
+/// 隔離は書き込みプロセスが同じレコードを読んだ時点で行われる。
+fn quarantine_records<B: StorageBackend>(
+    backend: &B,
+    skipped_corrupted: &Mutex<BTreeSet<u64>>,
+    records: &[QuarantinedEvent],
+) -> InfrastructureResult<()> {
+    if records.is_empty() {
+        return Ok(());
+    }
+    if backend.is_read_only() {
+        skipped_corrupted
+            .lock()
+            .unwrap()
+            .extend(records.iter().map(|quarantined| quarantined.global_sequence));
+        return Ok(());
+    }
+    let mut txn = backend.begin_write()?;
+    for quarantined in records {
+        put_quarantined(&mut txn, quarantined)?;
+    }
+    txn.commit()
+}
+
+fn put_quarantined(
+    txn: &mut impl KvWrite,
+    quarantined: &QuarantinedEvent,
+) -> InfrastructureResult<()> {
+    let value = serde_json::to_vec(quarantined)
+        .map_err(|e| InfrastructureError::SerializationFailed(e.to_string()))?;
+    txn.put_if_absent(QUARANTINE_TABLE, &quarantined.global_sequence.to_be_bytes(), &value)
+        .map(|_| ())
 }
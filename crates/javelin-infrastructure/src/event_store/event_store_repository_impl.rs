@@ -3,14 +3,14 @@
 
 use javelin_domain::{error::DomainResult, repositories::EventRepository};
 
-use crate::event_store::EventStore;
+use crate::{event_store::EventStore, storage::StorageBackend};
 
 /// EventStoreのEventRepositoryトレイト実装
 ///
 /// Domain層が定義するEventRepositoryトレイトを、
 /// Infrastructure層のEventStoreが実装する。
 /// これによりクリーンアーキテクチャの依存関係原則を実現。
-impl EventRepository for EventStore {
+impl<B: StorageBackend> EventRepository for EventStore<B> {
     type Event = javelin_domain::financial_close::journal_entry::events::JournalEntryEvent;
 
     async fn append(&self, event: Self::Event) -> DomainResult<()> {
@@ -28,24 +28,31 @@ impl EventRepository for EventStore {
             .map_err(|e| javelin_domain::error::DomainError::RepositoryError(e.to_string()))
     }
 
+    async fn append_batches<T>(&self, batches: Vec<(String, Vec<T>)>) -> DomainResult<u64>
+    where
+        T: serde::Serialize + Send + 'static,
+    {
+        self.append_batches(batches)
+            .await
+            .map_err(|e| javelin_domain::error::DomainError::RepositoryError(e.to_string()))
+    }
+
     async fn get_events(&self, aggregate_id: &str) -> DomainResult<Vec<serde_json::Value>> {
         let events = self
             .get_events(aggregate_id)
             .await
             .map_err(|e| javelin_domain::error::DomainError::RepositoryError(e.to_string()))?;
 
-        // StoredEventをserde_json::Valueに変換
+        // StoredEventのペイロード（JSON）をserde_json::Valueに変換
         events
             .into_iter()
             .map(|stored_event| {
-                serde_json::to_value(&stored_event.payload)
-                    .or_else(|_| serde_json::from_slice(&stored_event.payload))
-                    .map_err(|e| {
-                        javelin_domain::error::DomainError::RepositoryError(format!(
-                            "Failed to convert event: {}",
-                            e
-                        ))
-                    })
+                serde_json::from_slice(&stored_event.payload).map_err(|e| {
+                    javelin_domain::error::DomainError::RepositoryError(format!(
+                        "Failed to convert event: {}",
+                        e
+                    ))
+                })
             })
             .collect()
     }
@@ -56,18 +63,16 @@ impl EventRepository for EventStore {
             .await
             .map_err(|e| javelin_domain::error::DomainError::RepositoryError(e.to_string()))?;
 
-        // StoredEventをserde_json::Valueに変換
+        // StoredEventのペイロード（JSON）をserde_json::Valueに変換
         events
             .into_iter()
             .map(|stored_event| {
-                serde_json::to_value(&stored_event.payload)
-                    .or_else(|_| serde_json::from_slice(&stored_event.payload))
-                    .map_err(|e| {
-                        javelin_domain::error::DomainError::RepositoryError(format!(
-                            "Failed to convert event: {}",
-                            e
-                        ))
-                    })
+                serde_json::from_slice(&stored_event.payload).map_err(|e| {
+                    javelin_domain::error::DomainError::RepositoryError(format!(
+                        "Failed to convert event: {}",
+                        e
+                    ))
+                })
             })
             .collect()
     }
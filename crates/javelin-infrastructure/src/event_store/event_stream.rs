@@ -3,11 +3,14 @@
 
 use std::sync::Arc;
 
-use lmdb::{Cursor, Database, Environment, Transaction};
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 
 use crate::{
     error::{InfrastructureError, InfrastructureResult},
+    event_header::{headers_available, load_event, matching_sequences},
+    event_store::EVENTS_TABLE,
+    storage::{KvRead, LmdbBackend, StorageBackend},
     types::{AggregateId, Sequence},
 };
 
@@ -17,156 +20,133 @@ pub struct StoredEvent {
     pub event_type: String,
     pub aggregate_id: String,
     pub version: u64,
+    /// 記録時刻（UTC、RFC3339）
     pub timestamp: String,
+    /// 記録時点の業務日付（YYYY-MM-DD、設定タイムゾーンでの暦日）
+    ///
+    /// 業務日付の記録前に保存されたイベントでは None。
+    #[serde(default)]
+    pub business_date: Option<String>,
     pub payload: Vec<u8>,
+    /// ペイロードのチェックサム（SHA-256、16進）
+    ///
+    /// ディスク上の破損を検出するため保存時に算出する。
+    /// チェックサム導入前に保存されたイベントでは None（検証しない）。
+    #[serde(default)]
+    pub checksum: Option<String>,
+}
+
+impl StoredEvent {
+    /// ペイロードのチェックサムを算出
+    pub fn checksum_of(payload: &[u8]) -> String {
+        Sha256::digest(payload).iter().map(|b| format!("{:02x}", b)).collect()
+    }
+
+    /// 現在のペイロードからチェックサムを設定
+    pub fn with_checksum(mut self) -> Self {
+        self.checksum = Some(Self::checksum_of(&self.payload));
+        self
+    }
+
+    /// 保存時のチェックサムとペイロードが一致することを確認
+    pub fn verify_checksum(&self) -> Result<(), String> {
+        match &self.checksum {
+            Some(expected) => {
+                let actual = Self::checksum_of(&self.payload);
+                if *expected == actual {
+                    Ok(())
+                } else {
+                    Err(format!("checksum: expected {}, actual {}", expected, actual))
+                }
+            }
+            None => Ok(()),
+        }
+    }
 }
 
 /// イベントストリームIterator - Lazy evaluation
-pub struct EventStream {
-    env: Arc<Environment>,
-    db: Database,
+pub struct EventStream<B: StorageBackend = LmdbBackend> {
+    backend: Arc<B>,
     from_sequence: Sequence,
     aggregate_filter: Option<AggregateId>,
 }
 
-impl EventStream {
+impl<B: StorageBackend> EventStream<B> {
     pub fn new(
-        env: Arc<Environment>,
-        db: Database,
+        backend: Arc<B>,
         from_sequence: Sequence,
         aggregate_filter: Option<AggregateId>,
     ) -> Self {
-        Self { env, db, from_sequence, aggregate_filter }
+        Self { backend, from_sequence, aggregate_filter }
     }
 
     /// Iteratorとして消費
-    pub fn iter(self) -> EventStreamIterator {
+    pub fn iter(self) -> EventStreamIterator<B> {
         EventStreamIterator { stream: self, buffer: Vec::new(), exhausted: false }
     }
 
     /// バッチ読み込み（内部用）
     fn load_batch(&self, limit: usize) -> InfrastructureResult<Vec<StoredEvent>> {
-        let env = Arc::clone(&self.env);
-        let db = self.db;
         let from_seq = self.from_sequence.as_u64();
-        let aggregate_filter = self.aggregate_filter;
-
-        let txn = env.begin_ro_txn().map_err(|e| InfrastructureError::LmdbError(e.to_string()))?;
-
-        let cursor = txn
-            .open_ro_cursor(db)
-            .map_err(|e| InfrastructureError::LmdbError(e.to_string()))?;
+        let aggregate_filter = self.aggregate_filter.map(|id| id.to_string());
+
+        let txn = self.backend.begin_read()?;
+
+        if headers_available(&txn)? {
+            // ヘッダーで絞り込み、一致したイベントのみデシリアライズする
+            let scan = matching_sequences(&txn, from_seq, usize::MAX, limit, &mut |header| {
+                aggregate_filter
+                    .as_deref()
+                    .is_none_or(|filter_id| header.aggregate_id == filter_id)
+            })?;
+            return scan.sequences.into_iter().map(|seq| load_event(&txn, seq)).collect();
+        }
 
         let mut events = Vec::new();
-        let start_key = from_seq.to_be_bytes();
-
-        use lmdb_sys as ffi;
-
-        // Try to position cursor at or after the starting sequence
-        // If the database is empty or no matching key exists, this will return NotFound
-        match cursor.get(Some(&start_key), None, ffi::MDB_SET_RANGE) {
-            Ok((Some(key), value)) => {
-                // Process the first event
-                if key.len() != 8 {
-                    return Err(InfrastructureError::DeserializationFailed(
-                        "Invalid key length".to_string(),
-                    ));
+        let mut scanned = 0;
+
+        // 開始シーケンス以降のキーから順に走査する
+        txn.scan(EVENTS_TABLE, Some(&from_seq.to_be_bytes()), &mut |key, value| {
+            let key_bytes = key.as_array::<8>().ok_or_else(|| {
+                InfrastructureError::DeserializationFailed("Invalid key length".to_string())
+            })?;
+            let seq = u64::from_be_bytes(*key_bytes);
+
+            if seq >= from_seq {
+                let event: StoredEvent = serde_json::from_slice(value)
+                    .map_err(|e| InfrastructureError::DeserializationFailed(e.to_string()))?;
+                event.verify_checksum().map_err(|e| InfrastructureError::ChecksumMismatch {
+                    global_sequence: seq,
+                    detail: e,
+                })?;
+
+                // Aggregate filterが指定されている場合はフィルタリング
+                let matches_filter = aggregate_filter
+                    .as_ref()
+                    .is_none_or(|filter_id| event.aggregate_id == *filter_id);
+
+                if matches_filter {
+                    events.push(event);
                 }
-                let mut key_bytes = [0u8; 8];
-                key_bytes.copy_from_slice(key);
-                let seq = u64::from_be_bytes(key_bytes);
-
-                if seq >= from_seq {
-                    let event: StoredEvent = serde_json::from_slice(value)
-                        .map_err(|e| InfrastructureError::DeserializationFailed(e.to_string()))?;
-
-                    // Aggregate filterが指定されている場合はフィルタリング
-                    let matches_filter = if let Some(filter_id) = aggregate_filter {
-                        event.aggregate_id == filter_id.to_string()
-                    } else {
-                        true
-                    };
-
-                    if matches_filter {
-                        events.push(event);
-                    }
-                }
-
-                // Process remaining events
-                let mut count = 0;
-                loop {
-                    if events.len() >= limit {
-                        break;
-                    }
-
-                    count += 1;
-                    if count >= limit * 2 {
-                        // Safety limit to avoid infinite loops
-                        break;
-                    }
-
-                    match cursor.get(None, None, ffi::MDB_NEXT) {
-                        Ok((Some(key), value)) => {
-                            if key.len() != 8 {
-                                return Err(InfrastructureError::DeserializationFailed(
-                                    "Invalid key length".to_string(),
-                                ));
-                            }
-                            let mut key_bytes = [0u8; 8];
-                            key_bytes.copy_from_slice(key);
-                            let seq = u64::from_be_bytes(key_bytes);
-
-                            if seq < from_seq {
-                                continue;
-                            }
-
-                            let event: StoredEvent =
-                                serde_json::from_slice(value).map_err(|e| {
-                                    InfrastructureError::DeserializationFailed(e.to_string())
-                                })?;
-
-                            // Aggregate filterが指定されている場合はフィルタリング
-                            let matches_filter = if let Some(filter_id) = aggregate_filter {
-                                event.aggregate_id == filter_id.to_string()
-                            } else {
-                                true
-                            };
-
-                            if matches_filter {
-                                events.push(event);
-                            }
-                        }
-                        Ok((None, _)) => {
-                            // Unexpected None key
-                            break;
-                        }
-                        Err(lmdb::Error::NotFound) => break,
-                        Err(e) => return Err(InfrastructureError::LmdbError(e.to_string())),
-                    }
-                }
-            }
-            Ok((None, _)) => {
-                // Unexpected None key from MDB_SET_RANGE
             }
-            Err(lmdb::Error::NotFound) => {
-                // Database is empty or no events at or after from_seq
-                // Return empty vector
-            }
-            Err(e) => return Err(InfrastructureError::LmdbError(e.to_string())),
-        }
+
+            // Safety limit to avoid infinite loops
+            scanned += 1;
+            Ok(events.len() < limit && scanned <= limit * 2)
+        })?;
 
         Ok(events)
     }
 }
 
 /// EventStream Iterator実装
-pub struct EventStreamIterator {
-    stream: EventStream,
+pub struct EventStreamIterator<B: StorageBackend = LmdbBackend> {
+    stream: EventStream<B>,
     buffer: Vec<StoredEvent>,
     exhausted: bool,
 }
 
-impl Iterator for EventStreamIterator {
+impl<B: StorageBackend> Iterator for EventStreamIterator<B> {
     type Item = InfrastructureResult<StoredEvent>;
 
     fn next(&mut self) -> Option<Self::Item> {
@@ -202,16 +182,15 @@ impl Iterator for EventStreamIterator {
 }
 
 /// イベントストリームビルダー
-pub struct EventStreamBuilder {
-    env: Arc<Environment>,
-    db: Database,
+pub struct EventStreamBuilder<B: StorageBackend = LmdbBackend> {
+    backend: Arc<B>,
     from_sequence: Sequence,
     aggregate_filter: Option<AggregateId>,
 }
 
-impl EventStreamBuilder {
-    pub fn new(env: Arc<Environment>, db: Database) -> Self {
-        Self { env, db, from_sequence: Sequence::new(0), aggregate_filter: None }
+impl<B: StorageBackend> EventStreamBuilder<B> {
+    pub fn new(backend: Arc<B>) -> Self {
+        Self { backend, from_sequence: Sequence::new(0), aggregate_filter: None }
     }
 
     pub fn from_sequence(mut self, seq: Sequence) -> Self {
@@ -224,8 +203,8 @@ impl EventStreamBuilder {
         self
     }
 
-    pub fn build(self) -> EventStream {
-        EventStream::new(self.env, self.db, self.from_sequence, self.aggregate_filter)
+    pub fn build(self) -> EventStream<B> {
+        EventStream::new(self.backend, self.from_sequence, self.aggregate_filter)
     }
 }
 
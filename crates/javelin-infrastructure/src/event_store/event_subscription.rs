@@ -0,0 +1,254 @@
+// イベントストリーム購読 - 外部連携向け
+// 責務: シーケンス位置から再開可能なイベント配信（at-least-once + バックプレッシャー）
+//
+// データウェアハウス同期や通知ボットなどの外部コンシューマーが、
+// 取り込み済み位置（ack済みシーケンス）から購読を再開できるようにする。
+// 配信は有界チャネル経由で行い、コンシューマーの処理が遅い場合は
+// 読み出し側が待機する。
+
+use std::{
+    sync::{
+        Arc,
+        atomic::{AtomicU64, Ordering},
+    },
+    time::Duration,
+};
+
+use tokio::{
+    sync::{Notify, mpsc},
+    task::JoinHandle,
+};
+
+use crate::{
+    error::{InfrastructureError, InfrastructureResult},
+    event_header::{EventHeader, headers_available, load_event, matching_sequences},
+    event_store::EVENTS_TABLE,
+    event_stream::StoredEvent,
+    storage::{KvRead, StorageBackend},
+};
+
+/// 1回の読み出しで取得する最大イベント数
+const SUBSCRIPTION_BATCH_SIZE: usize = 256;
+
+/// 追記通知を取りこぼした場合の再読み出し間隔
+const SUBSCRIPTION_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// 購読フィルタ
+///
+/// イベントタイプが空の場合はすべてのタイプを対象とする。
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SubscriptionFilter {
+    event_types: Vec<String>,
+    aggregate_prefix: Option<String>,
+}
+
+impl SubscriptionFilter {
+    /// すべてのイベントを対象とするフィルタ
+    pub fn all() -> Self {
+        Self::default()
+    }
+
+    /// 対象イベントタイプを追加
+    pub fn with_event_type(mut self, event_type: impl Into<String>) -> Self {
+        self.event_types.push(event_type.into());
+        self
+    }
+
+    /// 集約IDのプレフィックスを指定
+    pub fn with_aggregate_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.aggregate_prefix = Some(prefix.into());
+        self
+    }
+
+    /// イベントがフィルタ条件に一致するか
+    pub fn matches(&self, event: &StoredEvent) -> bool {
+        self.matches_header(&EventHeader::of(event))
+    }
+
+    /// イベントヘッダーがフィルタ条件に一致するか（ペイロードを読まずに判定）
+    pub fn matches_header(&self, header: &EventHeader<'_>) -> bool {
+        let type_matches =
+            self.event_types.is_empty() || self.event_types.iter().any(|t| t == header.event_type);
+        let aggregate_matches = self
+            .aggregate_prefix
+            .as_deref()
+            .is_none_or(|prefix| header.aggregate_id.starts_with(prefix));
+
+        type_matches && aggregate_matches
+    }
+}
+
+/// イベント購読
+///
+/// `next()` で受け取ったイベントは、処理完了後に `ack()` する。
+/// 切断後は `resume_from()` の位置から `EventStore::subscribe` し直すことで、
+/// 未ackのイベントが再配信される（at-least-once）。
+pub struct EventSubscription {
+    receiver: mpsc::Receiver<InfrastructureResult<StoredEvent>>,
+    acked_sequence: Arc<AtomicU64>,
+    handle: JoinHandle<()>,
+}
+
+impl EventSubscription {
+    /// 購読タスクを起動
+    ///
+    /// `from_sequence` はこの番号を含む。`buffer` は未受信イベントの上限件数で、
+    /// これを超えるとイベントの読み出しを停止する。
+    pub(crate) fn spawn<B: StorageBackend>(
+        backend: Arc<B>,
+        appended: Arc<Notify>,
+        from_sequence: u64,
+        filter: SubscriptionFilter,
+        buffer: usize,
+    ) -> Self {
+        let (sender, receiver) = mpsc::channel(buffer.max(1));
+        let acked_sequence = Arc::new(AtomicU64::new(from_sequence.saturating_sub(1)));
+
+        let handle = tokio::spawn(async move {
+            let mut next_sequence = from_sequence.max(1);
+
+            loop {
+                // 読み出し前に通知待ちを登録し、読み出し中の追記を取りこぼさない
+                let notified = appended.notified();
+                tokio::pin!(notified);
+                notified.as_mut().enable();
+
+                let batch = {
+                    let backend = Arc::clone(&backend);
+                    let filter = filter.clone();
+                    tokio::task::spawn_blocking(move || {
+                        read_events_batch(
+                            &*backend,
+                            next_sequence,
+                            SUBSCRIPTION_BATCH_SIZE,
+                            &filter,
+                        )
+                    })
+                    .await
+                    .map_err(|e| InfrastructureError::EventStreamLoadFailed(e.to_string()))
+                    .and_then(|result| result)
+                };
+
+                let batch = match batch {
+                    Ok(batch) => batch,
+                    Err(e) => {
+                        let _ = sender.send(Err(e)).await;
+                        return;
+                    }
+                };
+
+                for event in batch.events {
+                    next_sequence = event.global_sequence + 1;
+                    // チャネルが満杯の間はここで待機（バックプレッシャー）
+                    if sender.send(Ok(event)).await.is_err() {
+                        return;
+                    }
+                }
+                // 一致しなかったイベントも読み出し済みとして進める
+                next_sequence = next_sequence.max(batch.next_sequence);
+
+                if batch.exhausted {
+                    tokio::select! {
+                        _ = &mut notified => {}
+                        _ = tokio::time::sleep(SUBSCRIPTION_POLL_INTERVAL) => {}
+                        _ = sender.closed() => return,
+                    }
+                }
+            }
+        });
+
+        Self { receiver, acked_sequence, handle }
+    }
+
+    /// 次のイベントを受信
+    ///
+    /// 購読が終了した場合は `None` を返す。
+    pub async fn next(&mut self) -> Option<InfrastructureResult<StoredEvent>> {
+        self.receiver.recv().await
+    }
+
+    /// 処理済みイベントを確認応答
+    ///
+    /// 配信順に処理されることを前提とし、既にackされた位置より
+    /// 前のシーケンスは無視する。
+    pub fn ack(&self, sequence: u64) {
+        self.acked_sequence.fetch_max(sequence, Ordering::AcqRel);
+    }
+
+    /// 最後にackされたシーケンス番号
+    pub fn acked_sequence(&self) -> u64 {
+        self.acked_sequence.load(Ordering::Acquire)
+    }
+
+    /// 再購読時の開始シーケンス番号
+    pub fn resume_from(&self) -> u64 {
+        self.acked_sequence() + 1
+    }
+}
+
+impl Drop for EventSubscription {
+    fn drop(&mut self) {
+        self.handle.abort();
+    }
+}
+
+/// 1回の読み出し結果
+struct EventBatch {
+    /// フィルタ条件に一致したイベント（シーケンス順）
+    events: Vec<StoredEvent>,
+    /// 次回の読み出し開始シーケンス
+    next_sequence: u64,
+    /// 最新のイベントまで読み出したか
+    exhausted: bool,
+}
+
+/// 指定シーケンス以降のイベントを最大 `limit` 件走査し、フィルタ条件に一致するものを読み出す
+///
+/// ヘッダーが揃っている場合はヘッダーで絞り込み、一致したイベントのみデシリアライズする。
+fn read_events_batch(
+    backend: &impl StorageBackend,
+    from_sequence: u64,
+    limit: usize,
+    filter: &SubscriptionFilter,
+) -> InfrastructureResult<EventBatch> {
+    let txn = backend.begin_read()?;
+
+    if headers_available(&txn)? {
+        let scan = matching_sequences(&txn, from_sequence, limit, usize::MAX, &mut |header| {
+            filter.matches_header(header)
+        })?;
+        let events = scan
+            .sequences
+            .into_iter()
+            .map(|seq| load_event(&txn, seq))
+            .collect::<InfrastructureResult<Vec<_>>>()?;
+        return Ok(EventBatch {
+            events,
+            next_sequence: scan.last_scanned.map_or(from_sequence, |seq| seq + 1),
+            exhausted: scan.scanned < limit,
+        });
+    }
+
+    let mut events = Vec::new();
+    let mut next_sequence = from_sequence;
+    let mut scanned = 0;
+
+    txn.scan(EVENTS_TABLE, Some(&from_sequence.to_be_bytes()), &mut |_, value| {
+        let event: StoredEvent = serde_json::from_slice(value)
+            .map_err(|e| InfrastructureError::DeserializationFailed(e.to_string()))?;
+        event.verify_checksum().map_err(|e| InfrastructureError::ChecksumMismatch {
+            global_sequence: event.global_sequence,
+            detail: e,
+        })?;
+        if event.global_sequence >= from_sequence {
+            scanned += 1;
+            next_sequence = event.global_sequence + 1;
+            if filter.matches(&event) {
+                events.push(event);
+            }
+        }
+        Ok(scanned < limit)
+    })?;
+
+    Ok(EventBatch { events, next_sequence, exhausted: scanned < limit })
+}
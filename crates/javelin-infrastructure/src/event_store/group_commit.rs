@@ -0,0 +1,175 @@
+// GroupCommitter - イベント追記のfsyncをまとめる
+// 並行する追記のコミット後のfsyncを1回にまとめ、各追記にはfsync後に完了を返す
+//
+// 追記は書き込みトランザクションの前に `begin_write` で書き込み中として登録し、
+// コミット後に `wait_synced` でfsyncを待つ。最初に待ちに入った追記がfsyncを
+// 担当するタスクを起動し、他に書き込み中の追記があればそれらのコミットを
+// 最大 `window` だけ待ってから1回だけfsyncする。書き込み中の追記がなければ
+// 待たずに同期するため、逐次の追記に待ち時間は加わらない。
+
+use std::{
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicU64, Ordering},
+    },
+    time::Duration,
+};
+
+use tokio::sync::{Notify, oneshot};
+
+use crate::error::{InfrastructureError, InfrastructureResult};
+
+/// fsyncの実行（バックエンドの同期）
+pub type SyncFn = Arc<dyn Fn() -> InfrastructureResult<()> + Send + Sync>;
+
+#[derive(Default)]
+struct GroupState {
+    /// 書き込みトランザクションを実行中の追記数
+    writing: usize,
+    /// fsyncを待つ追記
+    waiters: Vec<oneshot::Sender<Result<(), String>>>,
+    /// fsyncを担当するタスクを起動済みか
+    flush_scheduled: bool,
+}
+
+pub struct GroupCommitter {
+    window: Duration,
+    sync: SyncFn,
+    state: Mutex<GroupState>,
+    /// 書き込み中の追記がなくなったことの通知
+    idle: Notify,
+    /// 実行したfsyncの回数
+    syncs: AtomicU64,
+}
+
+/// 書き込み中の登録（破棄すると書き込み中から外れる）
+pub struct WriteTicket {
+    committer: Arc<GroupCommitter>,
+}
+
+impl Drop for WriteTicket {
+    fn drop(&mut self) {
+        let idle = {
+            let mut state = self.committer.state.lock().unwrap();
+            state.writing -= 1;
+            state.writing == 0
+        };
+        if idle {
+            self.committer.idle.notify_waiters();
+        }
+    }
+}
+
+impl GroupCommitter {
+    pub fn new(window: Duration, sync: SyncFn) -> Arc<Self> {
+        Arc::new(Self {
+            window,
+            sync,
+            state: Mutex::new(GroupState::default()),
+            idle: Notify::new(),
+            syncs: AtomicU64::new(0),
+        })
+    }
+
+    /// 書き込みトランザクションの開始を登録
+    pub fn begin_write(self: &Arc<Self>) -> WriteTicket {
+        self.state.lock().unwrap().writing += 1;
+        WriteTicket { committer: Arc::clone(self) }
+    }
+
+    /// コミット済みの書き込みがfsyncされるまで待つ
+    pub async fn wait_synced(self: &Arc<Self>, ticket: WriteTicket) -> InfrastructureResult<()> {
+        let (tx, rx) = oneshot::channel();
+        let schedule = {
+            let mut state = self.state.lock().unwrap();
+            state.waiters.push(tx);
+            !std::mem::replace(&mut state.flush_scheduled, true)
+        };
+        // 待ちに登録してから書き込み中を外す（担当タスクが取りこぼさないように）
+        drop(ticket);
+        if schedule {
+            tokio::spawn(Arc::clone(self).flush());
+        }
+
+        rx.await
+            .map_err(|e| InfrastructureError::TransactionFailed(e.to_string()))?
+            .map_err(InfrastructureError::LmdbError)
+    }
+
+    /// 実行したfsyncの回数
+    pub fn sync_count(&self) -> u64 {
+        self.syncs.load(Ordering::Relaxed)
+    }
+
+    /// 書き込み中の追記を待ってからfsyncし、待っている追記に結果を返す
+    async fn flush(self: Arc<Self>) {
+        let _ = tokio::time::timeout(self.window, self.wait_idle()).await;
+
+        let waiters = {
+            let mut state = self.state.lock().unwrap();
+            state.flush_scheduled = false;
+            std::mem::take(&mut state.waiters)
+        };
+
+        let sync = Arc::clone(&self.sync);
+        let result = match tokio::task::spawn_blocking(move || sync()).await {
+            Ok(result) => result.map_err(|e| e.to_string()),
+            Err(e) => Err(e.to_string()),
+        };
+        self.syncs.fetch_add(1, Ordering::Relaxed);
+
+        for waiter in waiters {
+            let _ = waiter.send(result.clone());
+        }
+    }
+
+    async fn wait_idle(&self) {
+        loop {
+            let notified = self.idle.notified();
+            tokio::pin!(notified);
+            notified.as_mut().enable();
+            if self.state.lock().unwrap().writing == 0 {
+                return;
+            }
+            notified.await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_concurrent_writes_share_one_sync() {
+        let synced = Arc::new(AtomicU64::new(0));
+        let counter = Arc::clone(&synced);
+        let committer = GroupCommitter::new(
+            Duration::from_millis(200),
+            Arc::new(move || {
+                counter.fetch_add(1, Ordering::SeqCst);
+                Ok(())
+            }),
+        );
+
+        // 2件目の書き込みが終わるまで1件目のfsyncは待たされる
+        let first = committer.begin_write();
+        let second = committer.begin_write();
+        let waiting = tokio::spawn({
+            let committer = Arc::clone(&committer);
+            async move { committer.wait_synced(first).await }
+        });
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert_eq!(synced.load(Ordering::SeqCst), 0);
+
+        committer.wait_synced(second).await.unwrap();
+        waiting.await.unwrap().unwrap();
+        assert_eq!((synced.load(Ordering::SeqCst), committer.sync_count()), (1, 1));
+
+        // 書き込み中の追記がなければ待たずに同期する
+        let started = std::time::Instant::now();
+        committer.wait_synced(committer.begin_write()).await.unwrap();
+        assert!(started.elapsed() < Duration::from_millis(200));
+        assert_eq!(committer.sync_count(), 2);
+    }
+}
@@ -5,8 +5,9 @@ use std::sync::Arc;
 
 use javelin_application::{
     dtos::{
-        GetJournalEntryQuery, JournalEntryDetail, JournalEntryLineDetail, JournalEntryListItem,
-        JournalEntryListResult, ListJournalEntriesQuery,
+        GetJournalEntryQuery, JournalActionAffordance, JournalEntryDetail, JournalEntryLineDetail,
+        JournalEntryListItem, JournalEntryListResult, ListJournalEntriesQuery,
+        request::DELETED_JOURNAL_ENTRY_STATUS,
     },
     error::{ApplicationError, ApplicationResult},
     output_port::QueryOutputPort,
@@ -28,6 +29,42 @@ impl<O: QueryOutputPort> JournalEntryFinderImpl<O> {
     pub fn new(projection_db: Arc<ProjectionDb>, output_port: Arc<O>) -> Self {
         Self { projection_db, output_port }
     }
+
+    /// 削除済みを除いた仕訳を読み込む（既存伝票の検索用）
+    async fn load_active_entries(&self) -> ApplicationResult<Vec<StoredJournalEntry>> {
+        let mut entries = self.load_stored_entries().await?;
+        entries.retain(|entry| entry.status != DELETED_JOURNAL_ENTRY_STATUS);
+        Ok(entries)
+    }
+
+    /// 保存済みの仕訳を単一のスナップショットから読み込む
+    ///
+    /// キーごとに読み取りトランザクションを分けると、ワーカーの反映途中で
+    /// 一部の仕訳だけが新しい状態になった一覧を返しうるため、まとめて読む。
+    async fn load_stored_entries(&self) -> ApplicationResult<Vec<StoredJournalEntry>> {
+        let records = self
+            .projection_db
+            .read_snapshot(|snapshot| {
+                let mut records = Vec::new();
+                for i in 0..1000 {
+                    let key = format!("journal_entry:entry-{}", i);
+                    if let Some(data) = snapshot.get(&key)? {
+                        records.push(data);
+                    }
+                }
+                Ok(records)
+            })
+            .await
+            .map_err(|e| ApplicationError::ProjectionDatabaseError(e.to_string()))?;
+
+        records
+            .iter()
+            .map(|data| {
+                serde_json::from_slice(data)
+                    .map_err(|e| ApplicationError::ProjectionDatabaseError(e.to_string()))
+            })
+            .collect()
+    }
 }
 
 impl<O: QueryOutputPort> JournalEntryFinderService for JournalEntryFinderImpl<O> {
@@ -38,31 +75,18 @@ impl<O: QueryOutputPort> JournalEntryFinderService for JournalEntryFinderImpl<O>
         entry_number: &str,
     ) -> ApplicationResult<Option<JournalEntrySearchResult>> {
         // ProjectionDBから伝票番号で検索
-        for i in 0..1000 {
-            let entry_id = format!("entry-{}", i);
-            let key = format!("journal_entry:{}", entry_id);
-
-            if let Some(data) = self
-                .projection_db
-                .get_projection(&key)
-                .await
-                .map_err(|e| ApplicationError::ProjectionDatabaseError(e.to_string()))?
+        for stored_entry in self.load_active_entries().await? {
+            if let Some(ref num) = stored_entry.entry_number
+                && num == entry_number
             {
-                let stored_entry: StoredJournalEntry = serde_json::from_slice(&data)
-                    .map_err(|e| ApplicationError::ProjectionDatabaseError(e.to_string()))?;
-
-                if let Some(ref num) = stored_entry.entry_number
-                    && num == entry_number
-                {
-                    return Ok(Some(JournalEntrySearchResult {
-                        entry_id: stored_entry.entry_id,
-                        entry_number: stored_entry.entry_number,
-                        transaction_date: stored_entry.transaction_date,
-                        total_debit: stored_entry.total_debit as i64,
-                        total_credit: stored_entry.total_credit as i64,
-                        status: stored_entry.status,
-                    }));
-                }
+                return Ok(Some(JournalEntrySearchResult {
+                    entry_id: stored_entry.entry_id,
+                    entry_number: stored_entry.entry_number,
+                    transaction_date: stored_entry.transaction_date,
+                    total_debit: stored_entry.total_debit as i64,
+                    total_credit: stored_entry.total_credit as i64,
+                    status: stored_entry.status,
+                }));
             }
         }
         Ok(None)
@@ -74,29 +98,16 @@ impl<O: QueryOutputPort> JournalEntryFinderService for JournalEntryFinderImpl<O>
     ) -> ApplicationResult<Vec<JournalEntrySearchResult>> {
         let mut results = Vec::new();
 
-        for i in 0..1000 {
-            let entry_id = format!("entry-{}", i);
-            let key = format!("journal_entry:{}", entry_id);
-
-            if let Some(data) = self
-                .projection_db
-                .get_projection(&key)
-                .await
-                .map_err(|e| ApplicationError::ProjectionDatabaseError(e.to_string()))?
-            {
-                let stored_entry: StoredJournalEntry = serde_json::from_slice(&data)
-                    .map_err(|e| ApplicationError::ProjectionDatabaseError(e.to_string()))?;
-
-                if stored_entry.voucher_number == voucher_number {
-                    results.push(JournalEntrySearchResult {
-                        entry_id: stored_entry.entry_id,
-                        entry_number: stored_entry.entry_number,
-                        transaction_date: stored_entry.transaction_date,
-                        total_debit: stored_entry.total_debit as i64,
-                        total_credit: stored_entry.total_credit as i64,
-                        status: stored_entry.status,
-                    });
-                }
+        for stored_entry in self.load_active_entries().await? {
+            if stored_entry.voucher_number == voucher_number {
+                results.push(JournalEntrySearchResult {
+                    entry_id: stored_entry.entry_id,
+                    entry_number: stored_entry.entry_number,
+                    transaction_date: stored_entry.transaction_date,
+                    total_debit: stored_entry.total_debit as i64,
+                    total_credit: stored_entry.total_credit as i64,
+                    status: stored_entry.status,
+                });
             }
         }
 
@@ -110,31 +121,18 @@ impl<O: QueryOutputPort> JournalEntryFinderService for JournalEntryFinderImpl<O>
     ) -> ApplicationResult<Vec<JournalEntrySearchResult>> {
         let mut results = Vec::new();
 
-        for i in 0..1000 {
-            let entry_id = format!("entry-{}", i);
-            let key = format!("journal_entry:{}", entry_id);
-
-            if let Some(data) = self
-                .projection_db
-                .get_projection(&key)
-                .await
-                .map_err(|e| ApplicationError::ProjectionDatabaseError(e.to_string()))?
+        for stored_entry in self.load_active_entries().await? {
+            if stored_entry.transaction_date.as_str() >= from_date
+                && stored_entry.transaction_date.as_str() <= to_date
             {
-                let stored_entry: StoredJournalEntry = serde_json::from_slice(&data)
-                    .map_err(|e| ApplicationError::ProjectionDatabaseError(e.to_string()))?;
-
-                if stored_entry.transaction_date.as_str() >= from_date
-                    && stored_entry.transaction_date.as_str() <= to_date
-                {
-                    results.push(JournalEntrySearchResult {
-                        entry_id: stored_entry.entry_id,
-                        entry_number: stored_entry.entry_number,
-                        transaction_date: stored_entry.transaction_date,
-                        total_debit: stored_entry.total_debit as i64,
-                        total_credit: stored_entry.total_credit as i64,
-                        status: stored_entry.status,
-                    });
-                }
+                results.push(JournalEntrySearchResult {
+                    entry_id: stored_entry.entry_id,
+                    entry_number: stored_entry.entry_number,
+                    transaction_date: stored_entry.transaction_date,
+                    total_debit: stored_entry.total_debit as i64,
+                    total_credit: stored_entry.total_credit as i64,
+                    status: stored_entry.status,
+                });
             }
         }
 
@@ -146,51 +144,36 @@ impl<O: QueryOutputPort> JournalEntryFinderService for JournalEntryFinderImpl<O>
     async fn list_journal_entries(&self, query: ListJournalEntriesQuery) -> ApplicationResult<()> {
         let mut all_entries: Vec<JournalEntryListItem> = Vec::new();
 
-        for i in 0..1000 {
-            let entry_id = format!("entry-{}", i);
-            let key = format!("journal_entry:{}", entry_id);
+        for stored_entry in self.load_stored_entries().await? {
+            // フィルタリング: ステータス（削除済みは指定した場合のみ）
+            if !query.matches_status(&stored_entry.status) {
+                continue;
+            }
 
-            if let Some(data) = self
-                .projection_db
-                .get_projection(&key)
-                .await
-                .map_err(|e| ApplicationError::ProjectionDatabaseError(e.to_string()))?
+            // フィルタリング: 日付範囲
+            if let Some(ref from_date) = query.from_date
+                && stored_entry.transaction_date < *from_date
             {
-                let stored_entry: StoredJournalEntry = serde_json::from_slice(&data)
-                    .map_err(|e| ApplicationError::ProjectionDatabaseError(e.to_string()))?;
-
-                // フィルタリング: ステータス
-                if let Some(ref status_filter) = query.status
-                    && &stored_entry.status != status_filter
-                {
-                    continue;
-                }
-
-                // フィルタリング: 日付範囲
-                if let Some(ref from_date) = query.from_date
-                    && stored_entry.transaction_date < *from_date
-                {
-                    continue;
-                }
-
-                if let Some(ref to_date) = query.to_date
-                    && stored_entry.transaction_date > *to_date
-                {
-                    continue;
-                }
+                continue;
+            }
 
-                all_entries.push(JournalEntryListItem {
-                    entry_id: stored_entry.entry_id,
-                    entry_number: stored_entry.entry_number,
-                    status: stored_entry.status,
-                    transaction_date: stored_entry.transaction_date,
-                    voucher_number: stored_entry.voucher_number,
-                    total_debit: stored_entry.total_debit,
-                    total_credit: stored_entry.total_credit,
-                    created_by: stored_entry.created_by,
-                    created_at: stored_entry.created_at,
-                });
+            if let Some(ref to_date) = query.to_date
+                && stored_entry.transaction_date > *to_date
+            {
+                continue;
             }
+
+            all_entries.push(JournalEntryListItem {
+                entry_id: stored_entry.entry_id,
+                entry_number: stored_entry.entry_number,
+                status: stored_entry.status,
+                transaction_date: stored_entry.transaction_date,
+                voucher_number: stored_entry.voucher_number,
+                total_debit: stored_entry.total_debit,
+                total_credit: stored_entry.total_credit,
+                created_by: stored_entry.created_by,
+                created_at: stored_entry.created_at,
+            });
         }
 
         // ソート（日付順）
@@ -234,6 +217,7 @@ impl<O: QueryOutputPort> JournalEntryFinderService for JournalEntryFinderImpl<O>
                     account_name: line.account_name,
                     sub_account_code: line.sub_account_code,
                     department_code: line.department_code,
+                    dimensions: line.dimensions,
                     amount: line.amount,
                     currency: line.currency,
                     tax_type: line.tax_type,
@@ -244,6 +228,7 @@ impl<O: QueryOutputPort> JournalEntryFinderService for JournalEntryFinderImpl<O>
             let result = JournalEntryDetail {
                 entry_id: stored_entry.entry_id,
                 entry_number: stored_entry.entry_number,
+                actions: JournalActionAffordance::for_status(&stored_entry.status),
                 status: stored_entry.status,
                 transaction_date: stored_entry.transaction_date,
                 voucher_number: stored_entry.voucher_number,
@@ -271,6 +256,7 @@ impl<O: QueryOutputPort> JournalEntryFinderService for JournalEntryFinderImpl<O>
                 updated_at: None,
                 approved_by: None,
                 approved_at: None,
+                actions: JournalActionAffordance::for_status("NotFound"),
             };
 
             self.output_port.present_journal_entry_detail(result).await;
@@ -308,6 +294,8 @@ struct StoredJournalEntryLine {
     account_name: String,
     sub_account_code: Option<String>,
     department_code: Option<String>,
+    #[serde(default)]
+    dimensions: std::collections::BTreeMap<String, String>,
     amount: f64,
     currency: String,
     tax_type: String,
@@ -1,60 +1,66 @@
 // LedgerQueryServiceImpl - 元帳照会サービス実装（Infrastructure層）
 // LedgerProjectionから元帳データを取得
 
-use std::sync::Arc;
+use std::{borrow::Cow, collections::BTreeMap, sync::Arc};
 
 use javelin_application::{
     error::{ApplicationError, ApplicationResult},
-    query_service::ledger_query_service::{
-        GetLedgerQuery, GetTrialBalanceQuery, LedgerEntry, LedgerQueryService, LedgerResult,
-        TrialBalanceResult,
+    query_service::{
+        account_activity::{
+            AccountActivityCell, AccountActivityQueryService, AccountActivityResult,
+            AccountActivityRow, GetAccountActivityQuery, activity_periods,
+        },
+        entry_history::{
+            EntryHistoryLine, EntryHistoryResult, GetEntryHistoryQuery, diff_entry_lines,
+        },
+        ledger_query_service::{
+            CarriedForwardBalance, CurrencyTrialBalanceResult, EntryStatusScope, GetLedgerQuery,
+            GetTrialBalanceQuery, LedgerEntry, LedgerQueryService, LedgerResult,
+            TrialBalanceResult,
+        },
+        projection_warm_up::{ProjectionWarmUp, WarmUpProgress},
     },
 };
 
 use crate::{
     EventStore,
-    projection_trait::Apply,
-    queries::ledger_projection::{LedgerEntryReadModel, LedgerProjection},
+    queries::{
+        ProjectionCache,
+        ledger_projection::{LedgerEntryReadModel, LedgerProjection},
+    },
 };
 
+/// 繰越残高を0とみなす許容誤差
+const BALANCE_TOLERANCE: f64 = 0.005;
+
 /// LedgerQueryService実装
 ///
 /// EventStoreからイベントを取得してLedgerProjectionを構築し、
-/// 元帳データを返す。
+/// 元帳データを返す。構築したProjectionはキャッシュし、以降は増分のみ適用する。
 pub struct LedgerQueryServiceImpl {
     event_store: Arc<EventStore>,
+    projection_cache: ProjectionCache<LedgerProjection>,
 }
 
 impl LedgerQueryServiceImpl {
     /// 新しいインスタンスを作成
     pub fn new(event_store: Arc<EventStore>) -> Self {
-        Self { event_store }
+        Self { event_store, projection_cache: ProjectionCache::new(LedgerProjection::new()) }
     }
 
-    /// イベントストリームからLedgerProjectionを構築
+    /// イベントストリームからLedgerProjectionを構築（未適用のイベントのみ適用）
     async fn build_ledger_projection(&self) -> ApplicationResult<LedgerProjection> {
-        use javelin_domain::financial_close::journal_entry::events::JournalEntryEvent;
-
-        let mut projection = LedgerProjection::new();
-
-        // 全イベントを取得（EventStoreから直接）
-        let events = self
-            .event_store
-            .get_all_events(0)
-            .await
-            .map_err(|e| ApplicationError::ProjectionDatabaseError(e.to_string()))?;
+        self.projection_cache.refresh(&self.event_store).await
+    }
+}
 
-        // イベントを適用
-        for stored_event in events.iter() {
-            // JournalEntryEventにデシリアライズ
-            if let Ok(event) = serde_json::from_slice::<JournalEntryEvent>(&stored_event.payload) {
-                projection
-                    .apply(event)
-                    .map_err(|e| ApplicationError::ProjectionDatabaseError(e.to_string()))?;
-            }
-        }
+impl ProjectionWarmUp for LedgerQueryServiceImpl {
+    async fn warm_up(&self) -> ApplicationResult<()> {
+        self.build_ledger_projection().await.map(|_| ())
+    }
 
-        Ok(projection)
+    fn warm_up_progress(&self) -> WarmUpProgress {
+        self.projection_cache.progress()
     }
 }
 
@@ -64,37 +70,80 @@ impl LedgerQueryService for LedgerQueryServiceImpl {
         let projection = self.build_ledger_projection().await?;
 
         // 元帳エントリを取得
-        let all_entries = projection.entries();
+        let all_entries = scoped_entries(&projection, query.status_scope);
 
-        // 勘定科目でフィルタリング
-        let mut filtered_entries: Vec<&LedgerEntryReadModel> = all_entries
+        // 勘定科目でフィルタリングし、取引日付でソート
+        // （分析軸の指定がある場合は、指定した分析軸をすべて持つ明細のみ）
+        let mut account_entries: Vec<&LedgerEntryReadModel> = all_entries
             .iter()
             .filter(|entry| entry.account_code == query.account_code)
+            .filter(|entry| entry.has_dimensions(&query.dimensions))
             .collect();
+        account_entries.sort_by(|a, b| a.transaction_date.cmp(&b.transaction_date));
+
+        // 照会期間の開始日時点の繰越残高
+        // （確定済みの繰越残高は勘定科目単位のため、分析軸の指定がある場合は明細から集計する）
+        let carried_forward = query.from_date.as_deref().map(|from_date| {
+            if query.dimensions.is_empty() {
+                carried_forward_balance(
+                    &projection,
+                    &query.account_code,
+                    &account_entries,
+                    query.status_scope,
+                    from_date,
+                )
+            } else {
+                balance_before(&account_entries, from_date)
+            }
+        });
+
+        // 残高の起点（分析軸の指定がある場合、明細の残高は勘定科目全体の残高のため積み上げ直す）
+        let starting_balance =
+            carried_forward.or_else(|| (!query.dimensions.is_empty()).then_some(0.0));
 
         // 日付範囲でフィルタリング
-        if let Some(ref from_date) = query.from_date {
-            filtered_entries.retain(|entry| entry.transaction_date >= *from_date);
-        }
-        if let Some(ref to_date) = query.to_date {
-            filtered_entries.retain(|entry| entry.transaction_date <= *to_date);
+        let mut filtered_entries: Vec<Cow<'_, LedgerEntryReadModel>> = account_entries
+            .into_iter()
+            .filter(|entry| {
+                query.from_date.as_ref().is_none_or(|from| entry.transaction_date >= *from)
+            })
+            .filter(|entry| query.to_date.as_ref().is_none_or(|to| entry.transaction_date <= *to))
+            .map(Cow::Borrowed)
+            .collect();
+
+        // 開始日または分析軸の指定がある場合は、残高の起点から取引日付順に残高を積み上げる
+        if let Some(starting_balance) = starting_balance {
+            let mut balance = starting_balance;
+            for entry in filtered_entries.iter_mut() {
+                balance += entry.debit_amount - entry.credit_amount;
+                entry.to_mut().balance = balance;
+            }
         }
 
-        // 取引日付でソート
-        filtered_entries.sort_by(|a, b| a.transaction_date.cmp(&b.transaction_date));
+        // 未解決の注記が付いた明細のみ
+        if query.flagged_only {
+            filtered_entries.retain(|entry| {
+                projection.open_annotation_count(&entry.entry_id, entry.line_number) > 0
+            });
+        }
 
         // ページネーション適用
         let offset = query.offset.unwrap_or(0) as usize;
         let limit = query.limit.unwrap_or(100) as usize;
-        let paginated_entries: Vec<&LedgerEntryReadModel> =
-            filtered_entries.iter().skip(offset).take(limit).copied().collect();
+        let paginated_entries: Vec<&LedgerEntryReadModel> = filtered_entries
+            .iter()
+            .skip(offset)
+            .take(limit)
+            .map(|entry| entry.as_ref())
+            .collect();
 
-        // 期首残高を計算（フィルタ前の最初のエントリの残高 - その借方貸方差額）
-        let opening_balance = if let Some(first_entry) = filtered_entries.first() {
-            first_entry.balance - (first_entry.debit_amount - first_entry.credit_amount)
-        } else {
-            0.0
-        };
+        // 期首残高（開始日の指定がない場合はフィルタ後の最初のエントリの残高 - その借方貸方差額）
+        let opening_balance = starting_balance.unwrap_or_else(|| {
+            filtered_entries
+                .first()
+                .map(|first| first.balance - (first.debit_amount - first.credit_amount))
+                .unwrap_or(0.0)
+        });
 
         // 借方合計、貸方合計を計算
         let mut total_debit = 0.0;
@@ -114,18 +163,22 @@ impl LedgerQueryService for LedgerQueryServiceImpl {
             .map(|entry| LedgerEntry {
                 transaction_date: entry.transaction_date.clone(),
                 entry_number: entry.entry_number.clone(),
-                entry_id: entry.entry_number.clone(), // entry_idがないのでentry_numberを使用
+                entry_id: entry.entry_id.clone(),
+                line_number: entry.line_number,
                 description: entry.description.clone(),
                 debit_amount: entry.debit_amount,
                 credit_amount: entry.credit_amount,
                 balance: entry.balance,
+                open_annotations: projection
+                    .open_annotation_count(&entry.entry_id, entry.line_number),
             })
             .collect();
 
         Ok(LedgerResult {
             account_code: query.account_code.clone(),
-            account_name: format!("勘定科目{}", query.account_code), // TODO: マスタデータから取得
+            account_name: ledger_account_name(&projection, &query.account_code),
             opening_balance,
+            carried_forward_date: carried_forward.and(query.from_date),
             entries,
             closing_balance,
             total_debit,
@@ -145,7 +198,7 @@ impl LedgerQueryService for LedgerQueryServiceImpl {
         let projection = self.build_ledger_projection().await?;
 
         // 元帳エントリを取得
-        let all_entries = projection.entries();
+        let all_entries = scoped_entries(&projection, query.status_scope);
 
         // 期間でフィルタリング（YYYY-MM形式）
         let period_str = format!("{:04}-{:02}", query.period_year, query.period_month);
@@ -165,23 +218,28 @@ impl LedgerQueryService for LedgerQueryServiceImpl {
         }
 
         // 期首残高を計算（期末残高 - 借方 + 貸方）
-        for (_account_code, (opening, debit, credit, closing)) in account_map.iter_mut() {
+        for (opening, debit, credit, closing) in account_map.values_mut() {
             *opening = *closing - *debit + *credit;
         }
 
         // TrialBalanceEntryに変換
         let mut entries: Vec<TrialBalanceEntry> = account_map
             .into_iter()
-            .map(|(account_code, (opening_balance, debit_amount, credit_amount, closing_balance))| {
-                TrialBalanceEntry {
-                    account_code: account_code.clone(),
-                    account_name: format!("勘定科目{}", account_code), // TODO: マスタデータから取得
-                    opening_balance,
-                    debit_amount,
-                    credit_amount,
-                    closing_balance,
-                }
-            })
+            .map(
+                |(
+                    account_code,
+                    (opening_balance, debit_amount, credit_amount, closing_balance),
+                )| {
+                    TrialBalanceEntry {
+                        account_code: account_code.clone(),
+                        account_name: ledger_account_name(&projection, &account_code),
+                        opening_balance,
+                        debit_amount,
+                        credit_amount,
+                        closing_balance,
+                    }
+                },
+            )
             .collect();
 
         // 勘定科目コードでソート
@@ -203,6 +261,317 @@ impl LedgerQueryService for LedgerQueryServiceImpl {
             total_credit,
         })
     }
+
+    async fn get_trial_balance_by_currency(
+        &self,
+        query: GetTrialBalanceQuery,
+    ) -> ApplicationResult<Vec<CurrencyTrialBalanceResult>> {
+        use std::collections::BTreeMap;
+
+        use javelin_application::query_service::TrialBalanceEntry;
+
+        // LedgerProjectionを構築
+        let projection = self.build_ledger_projection().await?;
+
+        // 期間（YYYY-MM形式）
+        let period_str = format!("{:04}-{:02}", query.period_year, query.period_month);
+
+        // 通貨・勘定科目ごとに集計（期首残高, 借方, 貸方）
+        // LedgerEntryReadModel::balanceは全通貨合算の残高のため、明細から積み上げる
+        let mut currency_map: BTreeMap<String, BTreeMap<String, (f64, f64, f64)>> = BTreeMap::new();
+        for entry in scoped_entries(&projection, query.status_scope).iter() {
+            let in_period = entry.transaction_date.starts_with(&period_str);
+            let before_period = !in_period && entry.transaction_date < period_str;
+            if !in_period && !before_period {
+                continue;
+            }
+
+            let (opening, debit, credit) = currency_map
+                .entry(entry.currency.clone())
+                .or_default()
+                .entry(entry.account_code.clone())
+                .or_insert((0.0, 0.0, 0.0));
+            if in_period {
+                *debit += entry.debit_amount;
+                *credit += entry.credit_amount;
+            } else {
+                *opening += entry.debit_amount - entry.credit_amount;
+            }
+        }
+
+        // 通貨ごとにTrialBalanceEntryへ変換（BTreeMapのため通貨・科目コード順）
+        let results = currency_map
+            .into_iter()
+            .map(|(currency, accounts)| {
+                let entries: Vec<TrialBalanceEntry> = accounts
+                    .into_iter()
+                    .map(|(account_code, (opening_balance, debit_amount, credit_amount))| {
+                        TrialBalanceEntry {
+                            account_name: ledger_account_name(&projection, &account_code),
+                            account_code,
+                            opening_balance,
+                            debit_amount,
+                            credit_amount,
+                            closing_balance: opening_balance + debit_amount - credit_amount,
+                        }
+                    })
+                    .collect();
+
+                let total_debit = entries.iter().map(|e| e.debit_amount).sum();
+                let total_credit = entries.iter().map(|e| e.credit_amount).sum();
+
+                CurrencyTrialBalanceResult {
+                    period_year: query.period_year,
+                    period_month: query.period_month,
+                    currency,
+                    entries,
+                    total_debit,
+                    total_credit,
+                }
+            })
+            .collect();
+
+        Ok(results)
+    }
+
+    async fn roll_over_period(
+        &self,
+        period_year: u32,
+        period_month: u8,
+    ) -> ApplicationResult<Vec<CarriedForwardBalance>> {
+        let period = format!("{:04}-{:02}", period_year, period_month);
+        let mut balances: Vec<CarriedForwardBalance> = self
+            .projection_cache
+            .update(&self.event_store, |projection| {
+                projection
+                    .roll_over(&period)
+                    .iter()
+                    .filter(|(_, balance)| balance.abs() >= BALANCE_TOLERANCE)
+                    .map(|(account_code, balance)| CarriedForwardBalance {
+                        account_code: account_code.clone(),
+                        balance: *balance,
+                    })
+                    .collect()
+            })
+            .await?;
+        balances.sort_by(|a, b| a.account_code.cmp(&b.account_code));
+        Ok(balances)
+    }
+
+    async fn get_entry_history(
+        &self,
+        query: GetEntryHistoryQuery,
+    ) -> ApplicationResult<EntryHistoryResult> {
+        use std::collections::HashMap;
+
+        use javelin_domain::financial_close::journal_entry::events::JournalEntryEvent;
+
+        let events = self
+            .event_store
+            .get_all_events(0)
+            .await
+            .map_err(|e| ApplicationError::ProjectionDatabaseError(e.to_string()))?;
+
+        // 修正チェーンの構築に必要な情報を収集
+        let mut lines_by_entry: HashMap<String, Vec<EntryHistoryLine>> = HashMap::new();
+        let mut entry_id_by_number: HashMap<String, String> = HashMap::new();
+        // 取消仕訳ID -> 元仕訳ID
+        let mut original_by_reversal: HashMap<String, String> = HashMap::new();
+        // 取消仕訳ID -> (修正仕訳ID, 修正理由)
+        let mut correction_by_reversal: HashMap<String, (String, String)> = HashMap::new();
+
+        let to_history_lines = |lines: Vec<
+            javelin_domain::financial_close::journal_entry::events::JournalEntryLineDto,
+        >| {
+            lines
+                .into_iter()
+                .map(|line| EntryHistoryLine {
+                    line_number: line.line_number,
+                    side: line.side,
+                    account_code: line.account_code,
+                    amount: line.amount,
+                    description: line.description,
+                })
+                .collect::<Vec<_>>()
+        };
+
+        for stored_event in events.iter() {
+            let Ok(event) = serde_json::from_slice::<JournalEntryEvent>(&stored_event.payload)
+            else {
+                continue;
+            };
+            match event {
+                JournalEntryEvent::DraftCreated { entry_id, lines, .. }
+                | JournalEntryEvent::DraftUpdated { entry_id, lines: Some(lines), .. } => {
+                    lines_by_entry.insert(entry_id, to_history_lines(lines));
+                }
+                JournalEntryEvent::Posted { entry_id, entry_number, .. } => {
+                    entry_id_by_number.insert(entry_number, entry_id);
+                }
+                JournalEntryEvent::Reversed { entry_id, original_id, .. } => {
+                    original_by_reversal.insert(entry_id, original_id);
+                }
+                JournalEntryEvent::Corrected { entry_id, reversed_id, reason, .. } => {
+                    correction_by_reversal.insert(reversed_id, (entry_id, reason));
+                }
+                _ => {}
+            }
+        }
+
+        // 伝票番号で指定された場合は伝票IDへ解決
+        let target_id = entry_id_by_number
+            .get(&query.entry_id)
+            .cloned()
+            .unwrap_or(query.entry_id.clone());
+
+        // 指定伝票がチェーン上のどこにあるかを判定し、取消仕訳IDを特定
+        let reversal_entry_id = if let Some((reversed_id, _)) = correction_by_reversal
+            .iter()
+            .find(|(_, (correcting_id, _))| *correcting_id == target_id)
+        {
+            // 修正仕訳が指定された
+            Some(reversed_id.clone())
+        } else if original_by_reversal.contains_key(&target_id)
+            || correction_by_reversal.contains_key(&target_id)
+        {
+            // 取消仕訳（または自身を取り消した元仕訳）が指定された
+            Some(target_id.clone())
+        } else {
+            // 元仕訳が指定された
+            original_by_reversal
+                .iter()
+                .find(|(_, original_id)| **original_id == target_id)
+                .map(|(reversal_id, _)| reversal_id.clone())
+        };
+
+        let original_entry_id = reversal_entry_id
+            .as_ref()
+            .and_then(|reversal_id| original_by_reversal.get(reversal_id))
+            .cloned()
+            .or_else(|| reversal_entry_id.clone())
+            .unwrap_or(target_id);
+
+        let correction = reversal_entry_id
+            .as_ref()
+            .and_then(|reversal_id| correction_by_reversal.get(reversal_id))
+            .cloned();
+
+        let original_lines = lines_by_entry.get(&original_entry_id).cloned().unwrap_or_default();
+        let diffs = match &correction {
+            Some((correcting_id, _)) => {
+                let corrected_lines =
+                    lines_by_entry.get(correcting_id).cloned().unwrap_or_default();
+                diff_entry_lines(&original_lines, &corrected_lines)
+            }
+            // 修正されていない場合は元仕訳のみ（すべて変更なし）
+            None => diff_entry_lines(&original_lines, &original_lines),
+        };
+
+        Ok(EntryHistoryResult {
+            original_entry_id,
+            reversal_entry_id,
+            correcting_entry_id: correction.as_ref().map(|(id, _)| id.clone()),
+            reason: correction.map(|(_, reason)| reason),
+            diffs,
+        })
+    }
+}
+
+impl AccountActivityQueryService for LedgerQueryServiceImpl {
+    async fn get_account_activity(
+        &self,
+        query: GetAccountActivityQuery,
+    ) -> ApplicationResult<AccountActivityResult> {
+        let projection = self.build_ledger_projection().await?;
+        let periods = activity_periods(query.to_year, query.to_month, query.months);
+        let period_index: BTreeMap<String, usize> = periods
+            .iter()
+            .enumerate()
+            .map(|(index, (year, month))| (format!("{:04}-{:02}", year, month), index))
+            .collect();
+
+        // 取引日付の年月（YYYY-MM）で集計期間の月に振り分ける
+        let mut activity: BTreeMap<&str, Vec<AccountActivityCell>> = BTreeMap::new();
+        let entries = scoped_entries(&projection, query.status_scope);
+        for entry in entries.iter() {
+            let Some(&index) = entry.transaction_date.get(..7).and_then(|ym| period_index.get(ym))
+            else {
+                continue;
+            };
+            let cells = activity
+                .entry(entry.account_code.as_str())
+                .or_insert_with(|| vec![AccountActivityCell::default(); periods.len()]);
+            cells[index].posting_count += 1;
+            cells[index].amount += entry.debit_amount + entry.credit_amount;
+        }
+
+        let rows = activity
+            .into_iter()
+            .map(|(account_code, cells)| AccountActivityRow {
+                account_code: account_code.to_string(),
+                account_name: ledger_account_name(&projection, account_code),
+                cells,
+            })
+            .collect();
+
+        Ok(AccountActivityResult { periods, rows })
+    }
+}
+
+/// 照会期間の開始日時点の繰越残高（取引日付順の勘定科目のエントリから算出）
+///
+/// 記帳済のみの照会では、開始日以前で最も新しい確定済みの繰越残高を起点とし、
+/// その月初から開始日の前日までの明細のみを積み上げる。
+fn carried_forward_balance(
+    projection: &LedgerProjection,
+    account_code: &str,
+    account_entries: &[&LedgerEntryReadModel],
+    status_scope: EntryStatusScope,
+    from_date: &str,
+) -> f64 {
+    let before_from = |entry: &&&LedgerEntryReadModel| entry.transaction_date.as_str() < from_date;
+    match projection.carried_forward_before(account_code, from_date) {
+        Some((period, balance)) if status_scope == EntryStatusScope::PostedOnly => {
+            balance
+                + account_entries
+                    .iter()
+                    .filter(|entry| entry.transaction_date.as_str() >= period)
+                    .take_while(before_from)
+                    .map(|entry| entry.debit_amount - entry.credit_amount)
+                    .sum::<f64>()
+        }
+        _ => balance_before(account_entries, from_date),
+    }
+}
+
+/// 取引日付順の元帳エントリのうち、指定日より前のエントリの借方貸方差額の合計
+fn balance_before(account_entries: &[&LedgerEntryReadModel], from_date: &str) -> f64 {
+    account_entries
+        .iter()
+        .take_while(|entry| entry.transaction_date.as_str() < from_date)
+        .map(|entry| entry.debit_amount - entry.credit_amount)
+        .sum()
+}
+
+/// 勘定科目名（イベントに埋め込まれた名称がなければ科目コードから仮の名称を付ける）
+fn ledger_account_name(projection: &LedgerProjection, account_code: &str) -> String {
+    projection
+        .account_name(account_code)
+        .map(str::to_string)
+        .unwrap_or_else(|| format!("勘定科目{}", account_code))
+}
+
+/// 集計対象の状態に応じた元帳エントリ
+fn scoped_entries(
+    projection: &LedgerProjection,
+    status_scope: EntryStatusScope,
+) -> Cow<'_, [LedgerEntryReadModel]> {
+    match status_scope {
+        EntryStatusScope::PostedOnly => Cow::Borrowed(projection.entries()),
+        EntryStatusScope::IncludePendingApproval => {
+            Cow::Owned(projection.entries_including_pending())
+        }
+    }
 }
 
 #[cfg(test)]
@@ -223,6 +592,9 @@ mod tests {
             to_date: None,
             limit: None,
             offset: None,
+            status_scope: EntryStatusScope::PostedOnly,
+            flagged_only: false,
+            dimensions: Default::default(),
         };
 
         let result = service.get_ledger(query).await.unwrap();
@@ -236,11 +608,309 @@ mod tests {
         let event_store = Arc::new(EventStore::new(temp_dir.path()).await.unwrap());
         let service = LedgerQueryServiceImpl::new(event_store);
 
-        let query = GetTrialBalanceQuery { period_year: 2024, period_month: 1 };
+        let query = GetTrialBalanceQuery {
+            period_year: 2024,
+            period_month: 1,
+            status_scope: EntryStatusScope::PostedOnly,
+        };
 
         let result = service.get_trial_balance(query).await.unwrap();
         assert_eq!(result.period_year, 2024);
         assert_eq!(result.period_month, 1);
         assert_eq!(result.entries.len(), 0);
     }
+
+    #[tokio::test]
+    async fn test_get_trial_balance_by_currency_empty() {
+        let temp_dir = TempDir::new().unwrap();
+        let event_store = Arc::new(EventStore::new(temp_dir.path()).await.unwrap());
+        let service = LedgerQueryServiceImpl::new(event_store);
+
+        let query = GetTrialBalanceQuery {
+            period_year: 2024,
+            period_month: 1,
+            status_scope: EntryStatusScope::PostedOnly,
+        };
+
+        let result = service.get_trial_balance_by_currency(query).await.unwrap();
+        assert!(result.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_get_entry_history_with_correction() {
+        use javelin_application::query_service::LineChangeKind;
+        use javelin_domain::{
+            financial_close::journal_entry::events::{JournalEntryEvent, JournalEntryLineDto},
+            repositories::EventRepository,
+        };
+
+        let temp_dir = TempDir::new().unwrap();
+        let event_store = Arc::new(EventStore::new(temp_dir.path()).await.unwrap());
+
+        let line =
+            |line_number: u32, side: &str, account_code: &str, amount: f64| JournalEntryLineDto {
+                line_number,
+                side: side.to_string(),
+                account_code: account_code.to_string(),
+                account_name: None,
+                sub_account_code: None,
+                department_code: None,
+                dimensions: Default::default(),
+                amount,
+                currency: "JPY".to_string(),
+                tax_type: "NonTaxable".to_string(),
+                tax_amount: 0.0,
+                description: None,
+            };
+        let draft =
+            |entry_id: &str, lines: Vec<JournalEntryLineDto>| JournalEntryEvent::DraftCreated {
+                entry_id: entry_id.to_string(),
+                transaction_date: "2024-01-10".to_string(),
+                voucher_number: "V-001".to_string(),
+                lines,
+                description: None,
+                created_by: "user1".to_string(),
+                created_at: chrono::Utc::now(),
+            };
+
+        let original_events = vec![
+            draft(
+                "JE001",
+                vec![line(1, "Debit", "1100", 10000.0), line(2, "Credit", "4000", 10000.0)],
+            ),
+            JournalEntryEvent::Posted {
+                entry_id: "JE001".to_string(),
+                entry_number: "EN-001".to_string(),
+                posted_by: "user1".to_string(),
+                posted_at: chrono::Utc::now(),
+            },
+        ];
+        EventRepository::append_events(&*event_store, "JE001", original_events)
+            .await
+            .unwrap();
+
+        let reversal = JournalEntryEvent::Reversed {
+            entry_id: "REV001".to_string(),
+            original_id: "JE001".to_string(),
+            reason: "金額誤り".to_string(),
+            reversed_by: "user1".to_string(),
+            reversed_at: chrono::Utc::now(),
+        };
+        EventRepository::append_events(&*event_store, "REV001", vec![reversal])
+            .await
+            .unwrap();
+
+        let correction_events = vec![
+            draft(
+                "JE002",
+                vec![line(1, "Debit", "1100", 12000.0), line(2, "Credit", "4000", 12000.0)],
+            ),
+            JournalEntryEvent::Corrected {
+                entry_id: "JE002".to_string(),
+                reversed_id: "REV001".to_string(),
+                reason: "金額誤り".to_string(),
+                corrected_by: "user1".to_string(),
+                corrected_at: chrono::Utc::now(),
+            },
+        ];
+        EventRepository::append_events(&*event_store, "JE002", correction_events)
+            .await
+            .unwrap();
+
+        let service = LedgerQueryServiceImpl::new(event_store);
+
+        // 伝票番号・修正仕訳IDのどちらからでも同じチェーンを辿れる
+        for entry_id in ["EN-001", "JE002"] {
+            let query = GetEntryHistoryQuery { entry_id: entry_id.to_string() };
+            let result = service.get_entry_history(query).await.unwrap();
+
+            assert_eq!(result.original_entry_id, "JE001");
+            assert_eq!(result.reversal_entry_id.as_deref(), Some("REV001"));
+            assert_eq!(result.correcting_entry_id.as_deref(), Some("JE002"));
+            assert_eq!(result.reason.as_deref(), Some("金額誤り"));
+            assert_eq!(result.diffs.len(), 2);
+            assert!(result.diffs.iter().all(|diff| diff.change == LineChangeKind::Modified));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_account_activity_groups_postings_by_month() {
+        use javelin_domain::{
+            financial_close::journal_entry::events::{JournalEntryEvent, JournalEntryLineDto},
+            repositories::EventRepository,
+        };
+
+        let temp_dir = TempDir::new().unwrap();
+        let event_store = Arc::new(EventStore::new(temp_dir.path()).await.unwrap());
+
+        let line =
+            |line_number: u32, side: &str, account_code: &str, amount: f64| JournalEntryLineDto {
+                line_number,
+                side: side.to_string(),
+                account_code: account_code.to_string(),
+                account_name: None,
+                sub_account_code: None,
+                department_code: None,
+                dimensions: Default::default(),
+                amount,
+                currency: "JPY".to_string(),
+                tax_type: "NonTaxable".to_string(),
+                tax_amount: 0.0,
+                description: None,
+            };
+        let post = |entry_id: &str, transaction_date: &str, amount: f64| {
+            vec![
+                JournalEntryEvent::DraftCreated {
+                    entry_id: entry_id.to_string(),
+                    transaction_date: transaction_date.to_string(),
+                    voucher_number: format!("V-{}", entry_id),
+                    lines: vec![
+                        line(1, "Debit", "1100", amount),
+                        line(2, "Credit", "4000", amount),
+                    ],
+                    description: None,
+                    created_by: "user1".to_string(),
+                    created_at: chrono::Utc::now(),
+                },
+                JournalEntryEvent::Posted {
+                    entry_id: entry_id.to_string(),
+                    entry_number: format!("EN-{}", entry_id),
+                    posted_by: "user1".to_string(),
+                    posted_at: chrono::Utc::now(),
+                },
+            ]
+        };
+
+        let store = &*event_store;
+        for (entry_id, date, amount) in [
+            ("JE001", "2023-12-28", 500.0),
+            ("JE002", "2024-01-10", 1000.0),
+            ("JE003", "2024-02-05", 300.0),
+            ("JE004", "2024-02-20", 200.0),
+        ] {
+            EventRepository::append_events(store, entry_id, post(entry_id, date, amount))
+                .await
+                .unwrap();
+        }
+
+        let service = LedgerQueryServiceImpl::new(event_store);
+        let result = service
+            .get_account_activity(GetAccountActivityQuery {
+                to_year: 2024,
+                to_month: 2,
+                months: 2,
+                status_scope: EntryStatusScope::PostedOnly,
+            })
+            .await
+            .unwrap();
+
+        // 集計期間外（2023-12）の記帳は含まない
+        assert_eq!(result.periods, vec![(2024, 1), (2024, 2)]);
+        assert_eq!(result.rows.len(), 2);
+        assert_eq!(result.rows[0].account_code, "1100");
+        assert_eq!(
+            result.rows[0].cells,
+            vec![
+                AccountActivityCell { posting_count: 1, amount: 1000.0 },
+                AccountActivityCell { posting_count: 2, amount: 500.0 },
+            ]
+        );
+        assert_eq!(result.rows[1].account_code, "4000");
+    }
+
+    #[tokio::test]
+    async fn test_roll_over_period_carries_forward_balances_into_ledger() {
+        use javelin_domain::{
+            financial_close::journal_entry::events::{JournalEntryEvent, JournalEntryLineDto},
+            repositories::EventRepository,
+        };
+
+        let temp_dir = TempDir::new().unwrap();
+        let event_store = Arc::new(EventStore::new(temp_dir.path()).await.unwrap());
+
+        let line =
+            |line_number: u32, side: &str, account_code: &str, amount: f64| JournalEntryLineDto {
+                line_number,
+                side: side.to_string(),
+                account_code: account_code.to_string(),
+                account_name: None,
+                sub_account_code: None,
+                department_code: None,
+                dimensions: Default::default(),
+                amount,
+                currency: "JPY".to_string(),
+                tax_type: "NonTaxable".to_string(),
+                tax_amount: 0.0,
+                description: None,
+            };
+        let post = |entry_id: &str, transaction_date: &str, amount: f64| {
+            vec![
+                JournalEntryEvent::DraftCreated {
+                    entry_id: entry_id.to_string(),
+                    transaction_date: transaction_date.to_string(),
+                    voucher_number: format!("V-{}", entry_id),
+                    lines: vec![
+                        line(1, "Debit", "1100", amount),
+                        line(2, "Credit", "4000", amount),
+                    ],
+                    description: None,
+                    created_by: "user1".to_string(),
+                    created_at: chrono::Utc::now(),
+                },
+                JournalEntryEvent::Posted {
+                    entry_id: entry_id.to_string(),
+                    entry_number: format!("EN-{}", entry_id),
+                    posted_by: "user1".to_string(),
+                    posted_at: chrono::Utc::now(),
+                },
+            ]
+        };
+
+        let store = &*event_store;
+        EventRepository::append_events(store, "JE001", post("JE001", "2024-03-10", 1000.0))
+            .await
+            .unwrap();
+        EventRepository::append_events(store, "JE002", post("JE002", "2024-04-05", 300.0))
+            .await
+            .unwrap();
+
+        let service = LedgerQueryServiceImpl::new(Arc::clone(&event_store));
+        let balances = service.roll_over_period(2024, 4).await.unwrap();
+        assert_eq!(
+            balances,
+            vec![
+                CarriedForwardBalance { account_code: "1100".to_string(), balance: 1000.0 },
+                CarriedForwardBalance { account_code: "4000".to_string(), balance: -1000.0 },
+            ]
+        );
+
+        // 確定後の遡及記帳は繰越残高に反映される
+        EventRepository::append_events(store, "JE003", post("JE003", "2024-03-20", 200.0))
+            .await
+            .unwrap();
+        EventRepository::append_events(store, "JE004", post("JE004", "2024-04-20", 50.0))
+            .await
+            .unwrap();
+
+        let query = GetLedgerQuery {
+            account_code: "1100".to_string(),
+            from_date: Some("2024-04-10".to_string()),
+            to_date: None,
+            limit: None,
+            offset: None,
+            status_scope: EntryStatusScope::PostedOnly,
+            flagged_only: false,
+            dimensions: Default::default(),
+        };
+        let result = service.get_ledger(query).await.unwrap();
+
+        assert_eq!(result.opening_balance, 1500.0);
+        assert_eq!(result.carried_forward_date.as_deref(), Some("2024-04-10"));
+        assert_eq!(result.entries.len(), 1);
+        assert_eq!(result.entries[0].entry_number, "EN-JE004");
+        assert_eq!(result.entries[0].balance, 1550.0);
+        assert_eq!(result.total_debit, 50.0);
+        assert_eq!(result.closing_balance, 1550.0);
+        assert_eq!(service.roll_over_period(2024, 4).await.unwrap()[0].balance, 1200.0);
+    }
 }
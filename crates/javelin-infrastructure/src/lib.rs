@@ -2,6 +2,7 @@
 // 依存方向: → Domain
 // 現代Rust設計: LMDB + CQRS + Event Sourcing 最適化
 
+pub mod business_metrics_impl;
 pub mod commands;
 pub mod error;
 pub mod event_handlers;
@@ -10,60 +11,104 @@ pub mod ledger_query_service_impl;
 pub mod queries;
 pub mod repositories;
 pub mod services;
+pub mod storage;
 pub mod storage_metrics;
+pub mod storage_telemetry_impl;
 pub mod types;
+pub mod user_activity_projection_impl;
 
 // Event Store modules
+#[path = "event_store/aggregate_cache.rs"]
+pub mod aggregate_cache;
+#[path = "event_store/crash_point.rs"]
+pub mod crash_point;
+#[path = "event_store/event_envelope.rs"]
+pub mod event_envelope;
+#[path = "event_store/event_header.rs"]
+pub mod event_header;
+#[path = "event_store/event_quarantine.rs"]
+pub mod event_quarantine;
 #[path = "event_store/event_store.rs"]
 pub mod event_store;
 #[path = "event_store/event_store_repository_impl.rs"]
 pub mod event_store_repository_impl;
 #[path = "event_store/event_stream.rs"]
 pub mod event_stream;
+#[path = "event_store/event_subscription.rs"]
+pub mod event_subscription;
+#[path = "event_store/group_commit.rs"]
+pub mod group_commit;
 #[path = "event_store/snapshot_db.rs"]
 pub mod snapshot_db;
 
 // Projection modules
 #[path = "projections/projection_builder_impl.rs"]
 pub mod projection_builder_impl;
+#[path = "projections/projection_compactor_impl.rs"]
+pub mod projection_compactor_impl;
 #[path = "projections/projection_db.rs"]
 pub mod projection_db;
 #[path = "projections/projection_trait.rs"]
 pub mod projection_trait;
 #[path = "projections/projection_worker.rs"]
 pub mod projection_worker;
+#[path = "projections/replica_monitor.rs"]
+pub mod replica_monitor;
 
 // Test modules
 #[cfg(test)]
+#[path = "tests/crash_recovery_tests.rs"]
+mod crash_recovery_tests;
+#[cfg(test)]
+#[path = "tests/event_quarantine_tests.rs"]
+mod event_quarantine_tests;
+#[cfg(test)]
 #[path = "tests/event_store_property_tests.rs"]
 mod event_store_property_tests;
 #[cfg(test)]
 #[path = "tests/event_store_unit_tests.rs"]
 mod event_store_unit_tests;
 #[cfg(test)]
+#[path = "tests/event_subscription_tests.rs"]
+mod event_subscription_tests;
+#[cfg(test)]
 #[path = "tests/ledger_query_service_property_tests.rs"]
 mod ledger_query_service_property_tests;
 #[cfg(test)]
 #[path = "tests/projection_builder_property_tests.rs"]
 mod projection_builder_property_tests;
+#[cfg(test)]
+#[path = "tests/replica_tests.rs"]
+mod replica_tests;
+#[cfg(test)]
+#[path = "tests/storage_contract_tests.rs"]
+mod storage_contract_tests;
 
 // Re-export for convenience
+pub use business_metrics_impl::BusinessMetricsImpl;
 pub use commands::{
     AccountingPeriodRepositoryImpl, JournalEntryRepositoryImpl, UserActionRepositoryImpl,
 };
+pub use event_envelope::EventEnvelope;
 pub use event_handlers::journal_entry_event_handler;
+pub use event_quarantine::{EventInspector, QuarantinedEvent};
 pub use event_store::EventStore;
 pub use event_stream::{EventStream, EventStreamBuilder, EventStreamIterator, StoredEvent};
+pub use event_subscription::{EventSubscription, SubscriptionFilter};
 pub use journal_entry_finder_impl::JournalEntryFinderImpl;
 pub use ledger_query_service_impl::LedgerQueryServiceImpl;
 pub use projection_builder_impl::ProjectionBuilderImpl;
-pub use projection_db::{ProjectionDb, ProjectionPosition};
+pub use projection_compactor_impl::ProjectionCompactorImpl;
+pub use projection_db::{
+    ProjectionDb, ProjectionPosition, ProjectionSnapshot, ProjectionWriteBatch,
+};
 pub use projection_trait::{Apply, ProjectEvent, ProjectionStrategy, ToReadModel};
 pub use projection_worker::ProjectionWorker;
 pub use queries::{
     journal_entry_projection, journal_entry_projection_worker, ledger_projection,
     master_data_loader_impl,
 };
+pub use replica_monitor::{ReplicaMonitor, ReplicaStatus, wait_for_position};
 pub use repositories::{
     AccountMasterRepositoryImpl, ApplicationSettingsRepositoryImpl, CompanyMasterRepositoryImpl,
 };
@@ -72,5 +117,10 @@ pub use snapshot_db::{
     EveryNEvents, EveryNMinutes, Snapshot, SnapshotDb, SnapshotEvery60Min, SnapshotEvery100,
     SnapshotEvery1000, SnapshotPolicyTrait,
 };
-pub use storage_metrics::{DurabilityPolicy, ProjectionLagMetrics, StorageMetrics};
+pub use storage_metrics::{
+    AppendMetrics, DEFAULT_GROUP_COMMIT_WINDOW, DEFAULT_STORAGE_HISTORY_CAPACITY, DurabilityPolicy,
+    ProjectionLagMetrics, StorageMetrics, StorageMetricsHistory,
+};
+pub use storage_telemetry_impl::StorageTelemetryImpl;
 pub use types::{AggregateId, EventKey, ExpectedVersion, Sequence};
+pub use user_activity_projection_impl::UserActivityProjectionImpl;
@@ -2,18 +2,43 @@
 // Application層のProjectionBuilderトレイトを実装
 
 use std::{
-    collections::VecDeque,
-    sync::{Arc, Mutex},
+    collections::{BTreeSet, HashMap, VecDeque},
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicU64, AtomicUsize, Ordering},
+    },
 };
 
 use javelin_application::{
     error::{ApplicationError, ApplicationResult},
     projection_builder::ProjectionBuilder as ProjectionBuilderTrait,
+    projection_events::{ProjectionChange, ProjectionEventBus},
+    query_service::{JournalEntryChainLinks, MasterDataLoaderService},
+};
+use javelin_domain::financial_close::journal_entry::{
+    events::{JournalEntryEvent, JournalEntryLineDto},
+    values::DebitCredit,
 };
 use serde::{Deserialize, Serialize};
-use tokio::sync::mpsc;
+use tokio::sync::{RwLock, mpsc};
+
+use crate::{
+    event_envelope::EventEnvelope,
+    event_quarantine::{QuarantinedEvent, validate_payload},
+    event_store::EventStore,
+    event_stream::StoredEvent,
+    projection_db::{ProjectionDb, ProjectionWriteBatch},
+    queries::{
+        journal_entry_chain_query_service_impl::journal_entry_chain_key,
+        master_data_loader_impl::MasterDataLoaderImpl,
+    },
+};
 
-use crate::{event_store::EventStore, event_stream::StoredEvent, projection_db::ProjectionDb};
+/// 仕訳一覧・元帳・試算表Projectionのチェックポイント名
+const PROJECTION_NAME: &str = "main";
+const PROJECTION_VERSION: u32 = 1;
+/// 再構築時に1トランザクションで反映するイベント数
+const REBUILD_BATCH_SIZE: usize = 500;
 
 /// 再試行キューエントリ
 #[derive(Debug, Clone)]
@@ -36,6 +61,18 @@ pub struct ProjectionBuilderImpl {
     retry_queue: Arc<Mutex<VecDeque<RetryQueueEntry>>>,
     /// インフラエラー通知チャネル
     error_sender: Arc<Mutex<Option<mpsc::UnboundedSender<String>>>>,
+    /// Projection更新通知の発行先
+    event_bus: Option<Arc<ProjectionEventBus>>,
+    /// 勘定科目名の取得元（名称を持たないイベントの補完に使用）
+    master_data_loader: Option<Arc<MasterDataLoaderImpl>>,
+    /// 勘定科目コード → 名称（勘定科目マスタから読み込む）
+    account_names: Mutex<HashMap<String, String>>,
+    /// 反映の保留数（0より大きい間はイベント保存時に反映しない）
+    deferred: AtomicUsize,
+    /// イベント保存時の反映（共有）と保留分の反映（排他）の調停
+    apply_gate: RwLock<()>,
+    /// 保留分の反映で反映済みとなった最後のイベント番号
+    caught_up_to: AtomicU64,
 }
 
 impl ProjectionBuilderImpl {
@@ -50,346 +87,326 @@ impl ProjectionBuilderImpl {
             event_store,
             retry_queue: Arc::new(Mutex::new(VecDeque::new())),
             error_sender: Arc::new(Mutex::new(None)),
+            event_bus: None,
+            master_data_loader: None,
+            account_names: Mutex::new(HashMap::new()),
+            deferred: AtomicUsize::new(0),
+            apply_gate: RwLock::new(()),
+            caught_up_to: AtomicU64::new(0),
         }
     }
 
+    /// Projection更新通知の発行先を設定
+    ///
+    /// コミットしたバッチごとに、変更された仕訳一覧・元帳を通知する。
+    pub fn with_event_bus(mut self, event_bus: Arc<ProjectionEventBus>) -> Self {
+        self.event_bus = Some(event_bus);
+        self
+    }
+
+    /// 勘定科目名の取得元を設定
+    ///
+    /// 勘定科目名を持たないイベント（名称の埋め込み導入前のイベントなど）は、
+    /// 勘定科目マスタの名称で補完してProjectionへ反映する。
+    pub fn with_master_data_loader(
+        mut self,
+        master_data_loader: Arc<MasterDataLoaderImpl>,
+    ) -> Self {
+        self.master_data_loader = Some(master_data_loader);
+        self
+    }
+
+    /// 補完に使う勘定科目名を勘定科目マスタから読み込み直す
+    pub async fn refresh_account_names(&self) -> ApplicationResult<()> {
+        let Some(master_data_loader) = &self.master_data_loader else {
+            return Ok(());
+        };
+        let accounts = master_data_loader.load_master_data().await?.accounts;
+        *self.account_names.lock().unwrap() =
+            accounts.into_iter().map(|account| (account.code, account.name)).collect();
+        Ok(())
+    }
+
     /// 単一イベントからProjectionを更新（内部実装）
     ///
-    /// イベント種別に応じて適切なProjection更新メソッドを呼び出す。
+    /// 1イベント分の更新（仕訳一覧・元帳・試算表）を1トランザクションで反映する。
     ///
     /// # Arguments
     /// * `event` - 処理するイベント
     async fn process_event_internal(&self, event: &StoredEvent) -> ApplicationResult<()> {
-        // イベント種別に応じて適切なProjection更新メソッドを呼び出す
-        match event.event_type.as_str() {
-            "DraftCreated"
-            | "SubmittedForApproval"
-            | "Approved"
-            | "Rejected"
-            | "Updated"
-            | "Deleted"
-            | "Corrected"
-            | "Reversed" => {
-                // 仕訳一覧Projectionを更新（Task 4.1で実装）
-                self.update_journal_entry_list_projection(event).await?;
+        let mut batch = ProjectionWriteBatch::new();
+        self.stage_event(event, &mut batch).await?;
+        self.commit_batch(batch, event.global_sequence).await
+    }
+
+    /// イベントによるProjectionの更新をバッチに積む
+    ///
+    /// 仕訳伝票のイベントのみを反映し、それ以外のイベントは無視する。
+    /// 仕訳伝票の種別で復元できないイベントは、反映位置を進めずにエラーとする。
+    async fn stage_event(
+        &self,
+        event: &StoredEvent,
+        batch: &mut ProjectionWriteBatch,
+    ) -> ApplicationResult<()> {
+        match event.envelope() {
+            EventEnvelope::JournalEntry(journal_event) => {
+                self.update_journal_entry_list_projection(event, journal_event, batch).await
             }
-            _ => {
-                // 未知のイベント種別はログに記録して無視
-                // 本番環境ではログ出力を追加すべき
+            EventEnvelope::Undecodable { event_type, error } => {
+                Err(ApplicationError::ProjectionBuildFailed(format!(
+                    "イベント（seq: {}, 種別: {}）を復元できません: {}",
+                    event.global_sequence, event_type, error
+                )))
             }
+            EventEnvelope::Unknown { .. } => Ok(()),
         }
-
-        Ok(())
     }
 
     /// 仕訳一覧Projectionを更新
     ///
-    /// Task 4.1で実装
-    ///
     /// イベント種別に応じて仕訳一覧Projectionを更新：
     /// - DraftCreated: 新規エントリを追加
-    /// - Approved: ステータスを更新
-    /// - Deleted: エントリを削除
+    /// - Posted: ステータスを更新し、元帳・試算表に転記
+    /// - Deleted: ステータスを削除済みに更新（物理削除はProjection圧縮ジョブで行う）
     ///
     /// 要件: 2.3, 2.4, 2.5
     async fn update_journal_entry_list_projection(
         &self,
         event: &StoredEvent,
+        journal_event: JournalEntryEvent,
+        batch: &mut ProjectionWriteBatch,
     ) -> ApplicationResult<()> {
-        use serde_json::Value;
-
-        // イベントペイロードをデシリアライズ
-        let event_data: Value = serde_json::from_slice(&event.payload)
-            .map_err(|e| ApplicationError::ValidationFailed(vec![e.to_string()]))?;
-
         let entry_id = event.aggregate_id.clone();
         let key = format!("journal_entry:{}", entry_id);
 
-        match event.event_type.as_str() {
-            "DraftCreated" => {
+        match journal_event {
+            JournalEntryEvent::DraftCreated {
+                transaction_date,
+                voucher_number,
+                lines,
+                description,
+                created_by,
+                ..
+            } => {
                 // 新規エントリを追加
+                let lines = self.stored_lines(&lines);
+                let (total_debit, total_credit) = totals_of(&lines);
                 let stored_entry = StoredJournalEntry {
                     entry_id: entry_id.clone(),
                     entry_number: None,
                     status: "Draft".to_string(),
-                    transaction_date: event_data["transaction_date"]
-                        .as_str()
-                        .unwrap_or("2024-01-01")
-                        .to_string(),
-                    voucher_number: event_data["voucher_number"].as_str().unwrap_or("").to_string(),
-                    description: event_data["description"].as_str().unwrap_or("").to_string(),
-                    total_debit: event_data["total_debit"].as_f64().unwrap_or(0.0),
-                    total_credit: event_data["total_credit"].as_f64().unwrap_or(0.0),
-                    created_by: event_data["created_by"].as_str().unwrap_or("").to_string(),
+                    transaction_date,
+                    voucher_number,
+                    description: description.unwrap_or_default(),
+                    total_debit,
+                    total_credit,
+                    created_by,
                     created_at: event.timestamp.clone(),
                     updated_by: None,
                     updated_at: None,
                     approved_by: None,
                     approved_at: None,
-                    lines: event_data["lines"]
-                        .as_array()
-                        .map(|arr| {
-                            arr.iter()
-                                .map(|line| StoredJournalEntryLine {
-                                    line_number: line["line_number"].as_u64().unwrap_or(0) as u32,
-                                    side: line["side"].as_str().unwrap_or("").to_string(),
-                                    account_code: line["account_code"]
-                                        .as_str()
-                                        .unwrap_or("")
-                                        .to_string(),
-                                    account_name: line["account_name"]
-                                        .as_str()
-                                        .unwrap_or("")
-                                        .to_string(),
-                                    sub_account_code: line["sub_account_code"]
-                                        .as_str()
-                                        .map(|s| s.to_string()),
-                                    department_code: line["department_code"]
-                                        .as_str()
-                                        .map(|s| s.to_string()),
-                                    amount: line["amount"].as_f64().unwrap_or(0.0),
-                                    currency: line["currency"]
-                                        .as_str()
-                                        .unwrap_or("JPY")
-                                        .to_string(),
-                                    tax_type: line["tax_type"].as_str().unwrap_or("").to_string(),
-                                    tax_amount: line["tax_amount"].as_f64().unwrap_or(0.0),
-                                })
-                                .collect()
-                        })
-                        .unwrap_or_default(),
+                    deleted_by: None,
+                    deleted_at: None,
+                    lines,
                 };
-
-                let data = serde_json::to_vec(&stored_entry)
-                    .map_err(|e| ApplicationError::ProjectionDatabaseError(e.to_string()))?;
-
-                self.projection_db
-                    .update_projection(&key, &data, event.global_sequence)
-                    .await
-                    .map_err(|e| ApplicationError::ProjectionDatabaseError(e.to_string()))?;
+                stage_entry(batch, &key, &stored_entry)?;
             }
-            "SubmittedForApproval" => {
-                // ステータスを更新
-                if let Some(existing_data) = self
-                    .projection_db
-                    .get_projection(&key)
-                    .await
-                    .map_err(|e| ApplicationError::ProjectionDatabaseError(e.to_string()))?
-                {
-                    let mut stored_entry: StoredJournalEntry =
-                        serde_json::from_slice(&existing_data).map_err(|e| {
-                            ApplicationError::ProjectionDatabaseError(e.to_string())
-                        })?;
-
-                    stored_entry.status = "PendingApproval".to_string();
-
-                    let data = serde_json::to_vec(&stored_entry)
-                        .map_err(|e| ApplicationError::ProjectionDatabaseError(e.to_string()))?;
-
-                    self.projection_db
-                        .update_projection(&key, &data, event.global_sequence)
-                        .await
-                        .map_err(|e| ApplicationError::ProjectionDatabaseError(e.to_string()))?;
+            JournalEntryEvent::DraftUpdated {
+                transaction_date,
+                voucher_number,
+                lines,
+                description,
+                updated_by,
+                ..
+            } => {
+                // 指定された項目のみ更新
+                if let Some(mut stored_entry) = self.read_entry(batch, &key).await? {
+                    if let Some(transaction_date) = transaction_date {
+                        stored_entry.transaction_date = transaction_date;
+                    }
+                    if let Some(voucher_number) = voucher_number {
+                        stored_entry.voucher_number = voucher_number;
+                    }
+                    if let Some(description) = description {
+                        stored_entry.description = description;
+                    }
+                    if let Some(lines) = lines {
+                        stored_entry.lines = self.stored_lines(&lines);
+                        (stored_entry.total_debit, stored_entry.total_credit) =
+                            totals_of(&stored_entry.lines);
+                    }
+                    stored_entry.updated_by = Some(updated_by);
+                    stored_entry.updated_at = Some(event.timestamp.clone());
+                    stage_entry(batch, &key, &stored_entry)?;
                 }
             }
-            "Approved" => {
-                // ステータスを更新
-                if let Some(existing_data) = self
-                    .projection_db
-                    .get_projection(&key)
-                    .await
-                    .map_err(|e| ApplicationError::ProjectionDatabaseError(e.to_string()))?
-                {
-                    let mut stored_entry: StoredJournalEntry =
-                        serde_json::from_slice(&existing_data).map_err(|e| {
-                            ApplicationError::ProjectionDatabaseError(e.to_string())
-                        })?;
-
-                    stored_entry.status = "Approved".to_string();
-                    stored_entry.approved_by =
-                        event_data["approved_by"].as_str().map(|s| s.to_string());
+            JournalEntryEvent::ApprovalRequested { .. } => {
+                self.stage_status(batch, &key, "PendingApproval").await?;
+            }
+            JournalEntryEvent::Rejected { .. } => {
+                self.stage_status(batch, &key, "Rejected").await?;
+            }
+            JournalEntryEvent::Posted { entry_number, posted_by, .. } => {
+                if let Some(mut stored_entry) = self.read_entry(batch, &key).await? {
+                    stored_entry.status = "Posted".to_string();
+                    stored_entry.approved_by = Some(posted_by);
                     stored_entry.approved_at = Some(event.timestamp.clone());
-                    stored_entry.entry_number =
-                        event_data["entry_number"].as_str().map(|s| s.to_string());
-
-                    let data = serde_json::to_vec(&stored_entry)
-                        .map_err(|e| ApplicationError::ProjectionDatabaseError(e.to_string()))?;
+                    stored_entry.entry_number = Some(entry_number);
+                    stage_entry(batch, &key, &stored_entry)?;
 
-                    self.projection_db
-                        .update_projection(&key, &data, event.global_sequence)
-                        .await
-                        .map_err(|e| ApplicationError::ProjectionDatabaseError(e.to_string()))?;
-
-                    // 元帳Projectionも更新
-                    self.update_ledger_projection(event).await?;
+                    // 元帳・試算表Projectionも更新
+                    self.update_ledger_projection(&stored_entry, batch).await?;
+                    self.update_trial_balance_projection(&stored_entry, batch).await?;
                 }
             }
-            "Rejected" => {
-                // ステータスを更新
-                if let Some(existing_data) = self
-                    .projection_db
-                    .get_projection(&key)
-                    .await
-                    .map_err(|e| ApplicationError::ProjectionDatabaseError(e.to_string()))?
-                {
-                    let mut stored_entry: StoredJournalEntry =
-                        serde_json::from_slice(&existing_data).map_err(|e| {
-                            ApplicationError::ProjectionDatabaseError(e.to_string())
-                        })?;
-
-                    stored_entry.status = "Rejected".to_string();
-
-                    let data = serde_json::to_vec(&stored_entry)
-                        .map_err(|e| ApplicationError::ProjectionDatabaseError(e.to_string()))?;
-
-                    self.projection_db
-                        .update_projection(&key, &data, event.global_sequence)
-                        .await
-                        .map_err(|e| ApplicationError::ProjectionDatabaseError(e.to_string()))?;
-                }
+            JournalEntryEvent::Closed { .. } => {
+                self.stage_status(batch, &key, "Closed").await?;
             }
-            "Updated" => {
-                // エントリを更新
-                if let Some(existing_data) = self
-                    .projection_db
-                    .get_projection(&key)
-                    .await
-                    .map_err(|e| ApplicationError::ProjectionDatabaseError(e.to_string()))?
-                {
-                    let mut stored_entry: StoredJournalEntry =
-                        serde_json::from_slice(&existing_data).map_err(|e| {
-                            ApplicationError::ProjectionDatabaseError(e.to_string())
-                        })?;
-
-                    stored_entry.transaction_date = event_data["transaction_date"]
-                        .as_str()
-                        .unwrap_or(&stored_entry.transaction_date)
-                        .to_string();
-                    stored_entry.voucher_number = event_data["voucher_number"]
-                        .as_str()
-                        .unwrap_or(&stored_entry.voucher_number)
-                        .to_string();
-                    stored_entry.description = event_data["description"]
-                        .as_str()
-                        .unwrap_or(&stored_entry.description)
-                        .to_string();
-                    stored_entry.updated_by =
-                        event_data["updated_by"].as_str().map(|s| s.to_string());
-                    stored_entry.updated_at = Some(event.timestamp.clone());
-
-                    let data = serde_json::to_vec(&stored_entry)
-                        .map_err(|e| ApplicationError::ProjectionDatabaseError(e.to_string()))?;
-
-                    self.projection_db
-                        .update_projection(&key, &data, event.global_sequence)
-                        .await
-                        .map_err(|e| ApplicationError::ProjectionDatabaseError(e.to_string()))?;
-                }
+            JournalEntryEvent::Reopened { .. } => {
+                self.stage_status(batch, &key, "Posted").await?;
             }
-            "Deleted" => {
-                // エントリを削除
-                self.projection_db
-                    .delete_projection(&key)
-                    .await
-                    .map_err(|e| ApplicationError::ProjectionDatabaseError(e.to_string()))?;
+            JournalEntryEvent::Deleted { deleted_by, .. } => {
+                // 削除済みとして残す（保持設定に応じてProjection圧縮ジョブで物理削除する）
+                if let Some(mut stored_entry) = self.read_entry(batch, &key).await? {
+                    stored_entry.status = "Deleted".to_string();
+                    stored_entry.deleted_by = Some(deleted_by);
+                    stored_entry.deleted_at = Some(event.timestamp.clone());
+                    stage_entry(batch, &key, &stored_entry)?;
+                }
             }
-            "Corrected" | "Reversed" => {
-                // 訂正・取消の場合は新しいエントリとして扱う（元のエントリは残す）
-                // 実装は必要に応じて追加
+            JournalEntryEvent::Reversed { original_id, .. } => {
+                // 元のエントリは残し、取消・修正チェーンのリンクだけを記録する
+                self.stage_chain_link(batch, &original_id, |links| {
+                    links.reversal_id = Some(entry_id.clone())
+                })
+                .await?;
+                self.stage_chain_link(batch, &entry_id, |links| {
+                    links.original_id = Some(original_id.clone())
+                })
+                .await?;
             }
-            _ => {
-                // 未知のイベント種別は無視
+            JournalEntryEvent::Corrected { reversed_id, .. } => {
+                self.stage_chain_link(batch, &reversed_id, |links| {
+                    links.correction_id = Some(entry_id.clone())
+                })
+                .await?;
             }
+            // 注記・仕訳間の紐付けは仕訳一覧に影響しない
+            JournalEntryEvent::LineAnnotated { .. }
+            | JournalEntryEvent::AnnotationResolved { .. }
+            | JournalEntryEvent::EntryLinked { .. }
+            | JournalEntryEvent::EntryUnlinked { .. } => {}
         }
 
         Ok(())
     }
 
+    /// イベントの明細を保存形式に変換（勘定科目名を補完）
+    fn stored_lines(&self, lines: &[JournalEntryLineDto]) -> Vec<StoredJournalEntryLine> {
+        let account_names = self.account_names.lock().unwrap();
+        lines
+            .iter()
+            .map(|line| StoredJournalEntryLine {
+                line_number: line.line_number,
+                side: line.side.clone(),
+                account_code: line.account_code.clone(),
+                account_name: resolve_account_name(
+                    &line.account_code,
+                    line.account_name.as_deref(),
+                    &account_names,
+                ),
+                sub_account_code: line.sub_account_code.clone(),
+                department_code: line.department_code.clone(),
+                dimensions: line.dimensions.clone(),
+                amount: line.amount,
+                currency: line.currency.clone(),
+                tax_type: line.tax_type.clone(),
+                tax_amount: line.tax_amount,
+            })
+            .collect()
+    }
+
+    /// 保存済みの仕訳を取得
+    async fn read_entry(
+        &self,
+        batch: &ProjectionWriteBatch,
+        key: &str,
+    ) -> ApplicationResult<Option<StoredJournalEntry>> {
+        self.read_projection(batch, key)
+            .await?
+            .map(|data| {
+                serde_json::from_slice(&data)
+                    .map_err(|e| ApplicationError::ProjectionDatabaseError(e.to_string()))
+            })
+            .transpose()
+    }
+
+    /// 保存済みの仕訳のステータスを更新
+    async fn stage_status(
+        &self,
+        batch: &mut ProjectionWriteBatch,
+        key: &str,
+        status: &str,
+    ) -> ApplicationResult<()> {
+        if let Some(mut stored_entry) = self.read_entry(batch, key).await? {
+            stored_entry.status = status.to_string();
+            stage_entry(batch, key, &stored_entry)?;
+        }
+        Ok(())
+    }
+
     /// 元帳Projectionを更新
     ///
-    /// Task 4.2で実装
-    ///
-    /// Approvedイベント時に元帳に転記し、勘定科目別の残高を更新する。
+    /// 記帳時に元帳に転記する。
     ///
     /// 要件: 2.6
-    async fn update_ledger_projection(&self, event: &StoredEvent) -> ApplicationResult<()> {
-        use serde_json::Value;
-
-        // イベントペイロードをデシリアライズ
-        let event_data: Value = serde_json::from_slice(&event.payload)
-            .map_err(|e| ApplicationError::ValidationFailed(vec![e.to_string()]))?;
+    async fn update_ledger_projection(
+        &self,
+        entry: &StoredJournalEntry,
+        batch: &mut ProjectionWriteBatch,
+    ) -> ApplicationResult<()> {
+        let (year, month) = year_month_of(&entry.transaction_date);
 
         // 仕訳明細から勘定科目ごとに元帳を更新
-        if let Some(lines) = event_data["lines"].as_array() {
-            for line in lines {
-                let account_code = line["account_code"].as_str().unwrap_or("");
-                let account_name = line["account_name"].as_str().unwrap_or("");
-                let side = line["side"].as_str().unwrap_or("");
-                let amount = line["amount"].as_f64().unwrap_or(0.0);
-
-                // 取引日から年月を抽出
-                let transaction_date =
-                    event_data["transaction_date"].as_str().unwrap_or("2024-01-01");
-                let parts: Vec<&str> = transaction_date.split('-').collect();
-                let year = parts.first().and_then(|s| s.parse::<u32>().ok()).unwrap_or(2024);
-                let month = parts.get(1).and_then(|s| s.parse::<u8>().ok()).unwrap_or(1);
-
-                let ledger_key = format!("ledger:{}:{}:{}", account_code, year, month);
-
-                // 既存の元帳データを取得
-                let mut ledger_data = if let Some(data) = self
-                    .projection_db
-                    .get_projection(&ledger_key)
-                    .await
-                    .map_err(|e| ApplicationError::ProjectionDatabaseError(e.to_string()))?
-                {
+        for line in &entry.lines {
+            let ledger_key = format!("ledger:{}:{}:{}", line.account_code, year, month);
+
+            // 既存の元帳データを取得
+            let mut ledger_data =
+                if let Some(data) = self.read_projection(batch, &ledger_key).await? {
                     serde_json::from_slice::<StoredLedgerData>(&data)
                         .map_err(|e| ApplicationError::ProjectionDatabaseError(e.to_string()))?
                 } else {
                     StoredLedgerData {
-                        account_name: account_name.to_string(),
+                        account_name: String::new(),
                         opening_balance: 0.0,
                         entries: vec![],
                     }
                 };
-
-                // 新しいエントリを追加
-                use javelin_domain::financial_close::journal_entry::values::DebitCredit;
-                let side_enum = side.parse::<DebitCredit>().ok();
-                let debit_amount = if matches!(side_enum, Some(DebitCredit::Debit)) {
-                    amount
-                } else {
-                    0.0
-                };
-                let credit_amount = if matches!(side_enum, Some(DebitCredit::Credit)) {
-                    amount
-                } else {
-                    0.0
-                };
-
-                ledger_data.entries.push(StoredLedgerEntry {
-                    transaction_date: transaction_date.to_string(),
-                    entry_number: event_data["entry_number"].as_str().unwrap_or("").to_string(),
-                    entry_id: event.aggregate_id.clone(),
-                    voucher_number: event_data["voucher_number"].as_str().unwrap_or("").to_string(),
-                    description: event_data["description"].as_str().unwrap_or("").to_string(),
-                    debit_amount,
-                    credit_amount,
-                    balance: 0.0, // 残高は照会時に計算
-                });
-
-                // 元帳データを保存
-                let data = serde_json::to_vec(&ledger_data)
-                    .map_err(|e| ApplicationError::ProjectionDatabaseError(e.to_string()))?;
-
-                self.projection_db
-                    .update_projection(&ledger_key, &data, event.global_sequence)
-                    .await
-                    .map_err(|e| ApplicationError::ProjectionDatabaseError(e.to_string()))?;
+            // 名称が空の元帳は名称を補完
+            if ledger_data.account_name.is_empty() {
+                ledger_data.account_name = line.account_name.clone();
             }
 
-            // 試算表Projectionも更新
-            self.update_trial_balance_projection(event).await?;
+            // 新しいエントリを追加
+            let (debit_amount, credit_amount) = amounts_of(line);
+            ledger_data.entries.push(StoredLedgerEntry {
+                transaction_date: entry.transaction_date.clone(),
+                entry_number: entry.entry_number.clone().unwrap_or_default(),
+                entry_id: entry.entry_id.clone(),
+                voucher_number: entry.voucher_number.clone(),
+                description: entry.description.clone(),
+                debit_amount,
+                credit_amount,
+                balance: 0.0, // 残高は照会時に計算
+            });
+
+            // 元帳データを保存
+            let data = serde_json::to_vec(&ledger_data)
+                .map_err(|e| ApplicationError::ProjectionDatabaseError(e.to_string()))?;
+
+            batch.put(&ledger_key, data);
         }
 
         Ok(())
@@ -397,76 +414,50 @@ impl ProjectionBuilderImpl {
 
     /// 試算表Projectionを更新
     ///
-    /// Task 4.3で実装
-    ///
-    /// 元帳Projectionから試算表を生成し、借貸合計を計算する。
+    /// 記帳した仕訳の明細を勘定科目ごとに集計し、借貸合計を更新する。
     ///
     /// 要件: 2.7
-    async fn update_trial_balance_projection(&self, event: &StoredEvent) -> ApplicationResult<()> {
-        use serde_json::Value;
-
-        // イベントペイロードをデシリアライズ
-        let event_data: Value = serde_json::from_slice(&event.payload)
-            .map_err(|e| ApplicationError::ValidationFailed(vec![e.to_string()]))?;
-
-        // 取引日から年月を抽出
-        let transaction_date = event_data["transaction_date"].as_str().unwrap_or("2024-01-01");
-        let parts: Vec<&str> = transaction_date.split('-').collect();
-        let year = parts.first().and_then(|s| s.parse::<u32>().ok()).unwrap_or(2024);
-        let month = parts.get(1).and_then(|s| s.parse::<u8>().ok()).unwrap_or(1);
-
+    async fn update_trial_balance_projection(
+        &self,
+        entry: &StoredJournalEntry,
+        batch: &mut ProjectionWriteBatch,
+    ) -> ApplicationResult<()> {
+        let (year, month) = year_month_of(&entry.transaction_date);
         let trial_balance_key = format!("trial_balance:{}:{}", year, month);
 
         // 既存の試算表データを取得
-        let mut trial_balance_data = if let Some(data) = self
-            .projection_db
-            .get_projection(&trial_balance_key)
-            .await
-            .map_err(|e| ApplicationError::ProjectionDatabaseError(e.to_string()))?
-        {
-            serde_json::from_slice::<StoredTrialBalanceData>(&data)
-                .map_err(|e| ApplicationError::ProjectionDatabaseError(e.to_string()))?
-        } else {
-            StoredTrialBalanceData { entries: vec![] }
-        };
+        let mut trial_balance_data =
+            if let Some(data) = self.read_projection(batch, &trial_balance_key).await? {
+                serde_json::from_slice::<StoredTrialBalanceData>(&data)
+                    .map_err(|e| ApplicationError::ProjectionDatabaseError(e.to_string()))?
+            } else {
+                StoredTrialBalanceData { entries: vec![] }
+            };
 
         // 仕訳明細から勘定科目ごとに集計
-        if let Some(lines) = event_data["lines"].as_array() {
-            for line in lines {
-                let account_code = line["account_code"].as_str().unwrap_or("");
-                let account_name = line["account_name"].as_str().unwrap_or("");
-                let side = line["side"].as_str().unwrap_or("");
-                let amount = line["amount"].as_f64().unwrap_or(0.0);
-
-                use javelin_domain::financial_close::journal_entry::values::DebitCredit;
-                let side_enum = side.parse::<DebitCredit>().ok();
-                let debit_amount = if matches!(side_enum, Some(DebitCredit::Debit)) {
-                    amount
-                } else {
-                    0.0
-                };
-                let credit_amount = if matches!(side_enum, Some(DebitCredit::Credit)) {
-                    amount
-                } else {
-                    0.0
-                };
-
-                // 既存のエントリを検索
-                if let Some(entry) =
-                    trial_balance_data.entries.iter_mut().find(|e| e.account_code == account_code)
-                {
-                    // 既存エントリを更新
-                    entry.debit_amount += debit_amount;
-                    entry.credit_amount += credit_amount;
-                } else {
-                    // 新規エントリを追加
-                    trial_balance_data.entries.push(StoredTrialBalanceEntry {
-                        account_code: account_code.to_string(),
-                        account_name: account_name.to_string(),
-                        debit_amount,
-                        credit_amount,
-                    });
+        for line in &entry.lines {
+            let (debit_amount, credit_amount) = amounts_of(line);
+
+            // 既存のエントリを検索
+            if let Some(existing) = trial_balance_data
+                .entries
+                .iter_mut()
+                .find(|e| e.account_code == line.account_code)
+            {
+                // 既存エントリを更新（名称が空の場合は補完）
+                existing.debit_amount += debit_amount;
+                existing.credit_amount += credit_amount;
+                if existing.account_name.is_empty() {
+                    existing.account_name = line.account_name.clone();
                 }
+            } else {
+                // 新規エントリを追加
+                trial_balance_data.entries.push(StoredTrialBalanceEntry {
+                    account_code: line.account_code.clone(),
+                    account_name: line.account_name.clone(),
+                    debit_amount,
+                    credit_amount,
+                });
             }
         }
 
@@ -474,14 +465,100 @@ impl ProjectionBuilderImpl {
         let data = serde_json::to_vec(&trial_balance_data)
             .map_err(|e| ApplicationError::ProjectionDatabaseError(e.to_string()))?;
 
+        batch.put(&trial_balance_key, data);
+
+        Ok(())
+    }
+
+    /// Projectionを取得（バッチ内の未コミットの更新を優先）
+    async fn read_projection(
+        &self,
+        batch: &ProjectionWriteBatch,
+        key: &str,
+    ) -> ApplicationResult<Option<Vec<u8>>> {
+        if let Some(staged) = batch.get(key) {
+            return Ok(staged.map(<[u8]>::to_vec));
+        }
+        self.projection_db
+            .get_projection(key)
+            .await
+            .map_err(|e| ApplicationError::ProjectionDatabaseError(e.to_string()))
+    }
+
+    /// 取消・修正チェーンのリンクを更新してバッチに積む
+    async fn stage_chain_link(
+        &self,
+        batch: &mut ProjectionWriteBatch,
+        entry_id: &str,
+        update: impl FnOnce(&mut JournalEntryChainLinks),
+    ) -> ApplicationResult<()> {
+        let key = journal_entry_chain_key(entry_id);
+        let mut links: JournalEntryChainLinks = match self.read_projection(batch, &key).await? {
+            Some(data) => serde_json::from_slice(&data)
+                .map_err(|e| ApplicationError::ProjectionDatabaseError(e.to_string()))?,
+            None => JournalEntryChainLinks::default(),
+        };
+        update(&mut links);
+
+        let data = serde_json::to_vec(&links)
+            .map_err(|e| ApplicationError::ProjectionDatabaseError(e.to_string()))?;
+        batch.put(key, data);
+        Ok(())
+    }
+
+    /// バッチとチェックポイントを1トランザクションでコミット
+    async fn commit_batch(
+        &self,
+        batch: ProjectionWriteBatch,
+        event_sequence: u64,
+    ) -> ApplicationResult<()> {
+        let changes = changes_of(&batch);
         self.projection_db
-            .update_projection(&trial_balance_key, &data, event.global_sequence)
+            .update_projection_batch(PROJECTION_NAME, PROJECTION_VERSION, batch, event_sequence)
             .await
             .map_err(|e| ApplicationError::ProjectionDatabaseError(e.to_string()))?;
 
+        // コミット後に通知し、購読側が照会した時点で反映済みであるようにする
+        if let Some(event_bus) = &self.event_bus {
+            for change in changes {
+                event_bus.publish(change);
+            }
+        }
         Ok(())
     }
 
+    /// ペイロードが読めないイベントを隔離
+    ///
+    /// ペイロードが正常な場合はfalseを返す。隔離した場合はtrueを返し、
+    /// 呼び出し側はそのイベントをスキップする。
+    async fn quarantine_if_poisoned(&self, event: &StoredEvent) -> ApplicationResult<bool> {
+        let Err(error) = validate_payload(event) else {
+            return Ok(false);
+        };
+
+        let raw = serde_json::to_vec(event)
+            .map_err(|e| ApplicationError::EventStoreError(e.to_string()))?;
+        self.event_store
+            .quarantine_event(QuarantinedEvent::new(event.global_sequence, raw, error.clone()))
+            .await
+            .map_err(|e| {
+                ApplicationError::EventStoreError(format!("Failed to quarantine event: {}", e))
+            })?;
+
+        self.notify(format!(
+            "イベントを隔離しました [seq={}, type={}]: {}",
+            event.global_sequence, event.event_type, error
+        ));
+        Ok(true)
+    }
+
+    /// インフラエラー通知チャネルへ送信（未登録の場合は何もしない）
+    fn notify(&self, message: String) {
+        if let Some(sender) = self.error_sender.lock().unwrap().as_ref() {
+            let _ = sender.send(message);
+        }
+    }
+
     /// イベント通知ハンドラを作成
     ///
     /// EventStoreに登録するコールバックを作成する。
@@ -507,6 +584,27 @@ impl ProjectionBuilderImpl {
             let builder = Arc::clone(&self);
             let error_sender = error_sender.clone();
             Box::pin(async move {
+                // 保留中のイベントと、保留分の反映で反映済みのイベントは反映しない
+                let _gate = builder.apply_gate.read().await;
+                if builder.deferred.load(Ordering::SeqCst) > 0
+                    || event.global_sequence <= builder.caught_up_to.load(Ordering::SeqCst)
+                {
+                    return;
+                }
+
+                // 読めないイベントは再試行せず隔離する
+                match builder.quarantine_if_poisoned(&event).await {
+                    Ok(true) => return,
+                    Ok(false) => {}
+                    Err(e) => {
+                        let _ = error_sender.send(format!(
+                            "イベント隔離エラー [seq={}]: {:?}",
+                            event.global_sequence, e
+                        ));
+                        return;
+                    }
+                }
+
                 if let Err(e) = builder.process_event_internal(&event).await {
                     // エラーメッセージを作成
                     let error_message = format!(
@@ -600,40 +698,104 @@ impl ProjectionBuilderImpl {
     pub fn retry_queue_size(&self) -> usize {
         self.retry_queue.lock().unwrap().len()
     }
+
+    /// イベントを一定件数ごとに1トランザクションで反映
+    ///
+    /// 読めないイベントは隔離してスキップし、隔離した件数を返す。
+    /// `progress` には反映済みのイベント数と反映するイベント数を渡す。
+    async fn apply_events(
+        &self,
+        events: &[StoredEvent],
+        progress: &(dyn Fn(u64, u64) + Send + Sync),
+    ) -> ApplicationResult<usize> {
+        let total = events.len() as u64;
+        let mut applied = 0u64;
+        let mut quarantined = 0usize;
+        progress(applied, total);
+
+        for chunk in events.chunks(REBUILD_BATCH_SIZE) {
+            let mut batch = ProjectionWriteBatch::new();
+            for event in chunk {
+                if self.quarantine_if_poisoned(event).await? {
+                    quarantined += 1;
+                    continue;
+                }
+                self.stage_event(event, &mut batch).await?;
+            }
+            // チェックポイントはチャンク末尾（隔離したイベントを含む）まで進める
+            if let Some(last_event) = chunk.last() {
+                self.commit_batch(batch, last_event.global_sequence).await?;
+            }
+            applied += chunk.len() as u64;
+            progress(applied, total);
+        }
+
+        Ok(quarantined)
+    }
 }
 
 #[async_trait::async_trait]
 impl ProjectionBuilderTrait for ProjectionBuilderImpl {
     async fn rebuild_all_projections(&self) -> ApplicationResult<()> {
+        // 名称を持たないイベントを補完できるよう、勘定科目名を読み込み直す
+        self.refresh_account_names().await?;
+
         // EventStoreから全イベントを取得（シーケンス0から）
         let events = self.event_store.get_all_events(0).await.map_err(|e| {
             ApplicationError::EventStoreError(format!("Failed to get events: {}", e))
         })?;
 
-        // 各イベントを順次処理
-        for event in events.iter() {
-            self.process_event_internal(event).await?;
+        let quarantined = self.apply_events(&events, &|_, _| {}).await?;
+        if quarantined > 0 {
+            self.notify(format!(
+                "Projection再構築: {}件のイベントを隔離してスキップしました",
+                quarantined
+            ));
         }
 
-        // チェックポイントを更新
+        Ok(())
+    }
+
+    fn defer_projections(&self) {
+        self.deferred.fetch_add(1, Ordering::SeqCst);
+    }
+
+    async fn resume_projections(
+        &self,
+        progress: &(dyn Fn(u64, u64) + Send + Sync),
+    ) -> ApplicationResult<u64> {
+        // 反映中に保存されたイベントは、保留分の反映が終わるまで待たせる
+        let _gate = self.apply_gate.write().await;
+        // 保留を減らすのは反映の排他を取った後だけなので、読んだ値から減らしてよい
+        let remaining = match self.deferred.load(Ordering::SeqCst) {
+            0 => 0,
+            _ => self.deferred.fetch_sub(1, Ordering::SeqCst) - 1,
+        };
+        if remaining > 0 {
+            return Ok(0);
+        }
+
+        let position = self
+            .projection_db
+            .get_position(PROJECTION_NAME, PROJECTION_VERSION)
+            .await
+            .map_err(|e| ApplicationError::ProjectionDatabaseError(e.to_string()))?;
+        let events = self.event_store.get_all_events(position + 1).await.map_err(|e| {
+            ApplicationError::EventStoreError(format!("Failed to get events: {}", e))
+        })?;
+
+        let quarantined = self.apply_events(&events, progress).await?;
         if let Some(last_event) = events.last() {
-            self.projection_db
-                .update_projection_batch(
-                    "main",
-                    1,
-                    vec![], // 空の更新（チェックポイントのみ更新）
-                    last_event.global_sequence,
-                )
-                .await
-                .map_err(|e| {
-                    ApplicationError::ProjectionDatabaseError(format!(
-                        "Failed to update checkpoint: {}",
-                        e
-                    ))
-                })?;
+            self.caught_up_to.fetch_max(last_event.global_sequence, Ordering::SeqCst);
+        }
+        if quarantined > 0 {
+            self.notify(format!(
+                "Projection反映: {}件のイベントを隔離してスキップしました",
+                quarantined
+            ));
         }
 
-        Ok(())
+        Ok(events.len() as u64)
     }
 
     async fn process_event(&self, event_data: &[u8]) -> ApplicationResult<()> {
@@ -645,6 +807,76 @@ impl ProjectionBuilderTrait for ProjectionBuilderImpl {
     }
 }
 
+/// バッチで書き込むキーから、通知する変更を求める
+///
+/// 仕訳一覧は `journal_entry:{id}`、元帳は `ledger:{科目}:{年}:{月}` のキーで保存される。
+fn changes_of(batch: &ProjectionWriteBatch) -> BTreeSet<ProjectionChange> {
+    batch
+        .keys()
+        .filter_map(|key| {
+            if key.starts_with("journal_entry:") {
+                return Some(ProjectionChange::JournalListChanged);
+            }
+            let mut parts = key.strip_prefix("ledger:")?.rsplitn(3, ':');
+            let month = parts.next()?.parse().ok()?;
+            let year = parts.next()?.parse().ok()?;
+            let account_code = parts.next()?.to_string();
+            Some(ProjectionChange::LedgerChanged { account_code, year, month })
+        })
+        .collect()
+}
+
+/// 明細の勘定科目名を求める
+///
+/// イベントに埋め込まれた名称を優先し、なければ勘定科目マスタの名称で補完する。
+fn resolve_account_name(
+    account_code: &str,
+    embedded: Option<&str>,
+    account_names: &HashMap<String, String>,
+) -> String {
+    match embedded.filter(|name| !name.is_empty()) {
+        Some(name) => name.to_string(),
+        None => account_names.get(account_code).cloned().unwrap_or_default(),
+    }
+}
+
+/// 取引日（YYYY-MM-DD）の年・月
+fn year_month_of(transaction_date: &str) -> (u32, u8) {
+    let mut parts = transaction_date.split('-');
+    let year = parts.next().and_then(|s| s.parse().ok()).unwrap_or(2024);
+    let month = parts.next().and_then(|s| s.parse().ok()).unwrap_or(1);
+    (year, month)
+}
+
+/// 明細の借方・貸方金額
+fn amounts_of(line: &StoredJournalEntryLine) -> (f64, f64) {
+    match line.side.parse::<DebitCredit>() {
+        Ok(DebitCredit::Debit) => (line.amount, 0.0),
+        Ok(DebitCredit::Credit) => (0.0, line.amount),
+        Err(_) => (0.0, 0.0),
+    }
+}
+
+/// 明細の借方・貸方合計
+fn totals_of(lines: &[StoredJournalEntryLine]) -> (f64, f64) {
+    lines
+        .iter()
+        .map(amounts_of)
+        .fold((0.0, 0.0), |(debit, credit), (d, c)| (debit + d, credit + c))
+}
+
+/// 仕訳をバッチに積む
+fn stage_entry(
+    batch: &mut ProjectionWriteBatch,
+    key: &str,
+    stored_entry: &StoredJournalEntry,
+) -> ApplicationResult<()> {
+    let data = serde_json::to_vec(stored_entry)
+        .map_err(|e| ApplicationError::ProjectionDatabaseError(e.to_string()))?;
+    batch.put(key, data);
+    Ok(())
+}
+
 /// ProjectionDBに保存される仕訳エントリデータ構造
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct StoredJournalEntry {
@@ -662,6 +894,10 @@ struct StoredJournalEntry {
     updated_at: Option<String>,
     approved_by: Option<String>,
     approved_at: Option<String>,
+    #[serde(default)]
+    deleted_by: Option<String>,
+    #[serde(default)]
+    deleted_at: Option<String>,
     lines: Vec<StoredJournalEntryLine>,
 }
 
@@ -674,6 +910,8 @@ struct StoredJournalEntryLine {
     account_name: String,
     sub_account_code: Option<String>,
     department_code: Option<String>,
+    #[serde(default)]
+    dimensions: std::collections::BTreeMap<String, String>,
     amount: f64,
     currency: String,
     tax_type: String,
@@ -715,3 +953,185 @@ struct StoredTrialBalanceEntry {
     debit_amount: f64,
     credit_amount: f64,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_account_name_prefers_embedded_name() {
+        let names = HashMap::from([("1000".to_string(), "現金".to_string())]);
+        assert_eq!(resolve_account_name("1000", Some("小口現金"), &names), "小口現金");
+        assert_eq!(resolve_account_name("1000", None, &names), "現金");
+        assert_eq!(resolve_account_name("9999", Some(""), &names), "");
+    }
+
+    #[test]
+    fn test_changes_of_batch_keys() {
+        let mut batch = ProjectionWriteBatch::new();
+        batch.put("journal_entry:je-1", b"{}".to_vec());
+        batch.delete("journal_entry:je-2");
+        batch.put("journal_entry_chain:je-1", b"{}".to_vec());
+        batch.put("ledger:1100:2024:4", b"{}".to_vec());
+        batch.put("trial_balance:2024:4", b"{}".to_vec());
+
+        let changes: Vec<ProjectionChange> = changes_of(&batch).into_iter().collect();
+        assert_eq!(
+            changes,
+            vec![
+                ProjectionChange::JournalListChanged,
+                ProjectionChange::LedgerChanged {
+                    account_code: "1100".to_string(),
+                    year: 2024,
+                    month: 4,
+                },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_deferred_events_are_applied_on_resume() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let event_store = Arc::new(EventStore::new(&temp_dir.path().join("events")).await.unwrap());
+        let projection_db =
+            Arc::new(ProjectionDb::new(&temp_dir.path().join("projections")).await.unwrap());
+        let builder = Arc::new(ProjectionBuilderImpl::new(
+            Arc::clone(&projection_db),
+            Arc::clone(&event_store),
+        ));
+        let (error_sender, _error_receiver) = mpsc::unbounded_channel();
+        event_store.set_notification_callback(
+            Arc::clone(&builder).create_event_notification_handler(error_sender),
+        );
+        let event = || serde_json::json!({"id": "1", "data": "x"});
+
+        // 保留中は保存時に反映されない
+        builder.defer_projections();
+        let latest = event_store.append("entry-1", vec![event(), event()]).await.unwrap();
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+        assert_eq!(
+            projection_db.get_position(PROJECTION_NAME, PROJECTION_VERSION).await.unwrap(),
+            0
+        );
+
+        let reported = Mutex::new(Vec::new());
+        let applied = builder
+            .resume_projections(&|done, total| reported.lock().unwrap().push((done, total)))
+            .await
+            .unwrap();
+        assert_eq!(applied, 2);
+        assert_eq!(*reported.lock().unwrap(), vec![(0, 2), (2, 2)]);
+        assert_eq!(
+            projection_db.get_position(PROJECTION_NAME, PROJECTION_VERSION).await.unwrap(),
+            latest
+        );
+
+        // 保留を解除した後は保存時に反映される
+        let latest = event_store.append("entry-2", vec![event()]).await.unwrap();
+        for _ in 0..100 {
+            if projection_db.get_position(PROJECTION_NAME, PROJECTION_VERSION).await.unwrap()
+                == latest
+            {
+                return;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+        panic!("event appended after resume was not projected");
+    }
+
+    #[tokio::test]
+    async fn test_undecodable_journal_entry_event_is_an_error() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let event_store = Arc::new(EventStore::new(&temp_dir.path().join("events")).await.unwrap());
+        let projection_db =
+            Arc::new(ProjectionDb::new(&temp_dir.path().join("projections")).await.unwrap());
+        let builder =
+            ProjectionBuilderImpl::new(Arc::clone(&projection_db), Arc::clone(&event_store));
+
+        // 仕訳伝票の種別で必須項目が欠けたイベントは読み飛ばさない
+        let broken = serde_json::json!({"type": "Posted", "entry_id": "JE001"});
+        event_store.append("JE001", vec![broken]).await.unwrap();
+        let stored = event_store.get_events("JE001").await.unwrap();
+
+        let result = builder.process_event(&serde_json::to_vec(&stored[0]).unwrap()).await;
+        assert!(matches!(result, Err(ApplicationError::ProjectionBuildFailed(_))));
+        assert_eq!(
+            projection_db.get_position(PROJECTION_NAME, PROJECTION_VERSION).await.unwrap(),
+            0
+        );
+    }
+
+    #[tokio::test]
+    async fn test_posted_entry_updates_list_ledger_and_trial_balance() {
+        use chrono::Utc;
+
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let event_store = Arc::new(EventStore::new(&temp_dir.path().join("events")).await.unwrap());
+        let projection_db =
+            Arc::new(ProjectionDb::new(&temp_dir.path().join("projections")).await.unwrap());
+        let builder =
+            ProjectionBuilderImpl::new(Arc::clone(&projection_db), Arc::clone(&event_store));
+
+        let line = |line_number, side: &str, account_code: &str| JournalEntryLineDto {
+            line_number,
+            side: side.to_string(),
+            account_code: account_code.to_string(),
+            account_name: Some(format!("科目{}", account_code)),
+            sub_account_code: None,
+            department_code: None,
+            dimensions: Default::default(),
+            amount: 1000.0,
+            currency: "JPY".to_string(),
+            tax_type: "NonTaxable".to_string(),
+            tax_amount: 0.0,
+            description: None,
+        };
+        let events = vec![
+            JournalEntryEvent::DraftCreated {
+                entry_id: "JE001".to_string(),
+                transaction_date: "2024-04-10".to_string(),
+                voucher_number: "V001".to_string(),
+                lines: vec![line(1, "Debit", "1100"), line(2, "Credit", "4000")],
+                description: Some("売上計上".to_string()),
+                created_by: "clerk".to_string(),
+                created_at: Utc::now(),
+            },
+            JournalEntryEvent::ApprovalRequested {
+                entry_id: "JE001".to_string(),
+                requested_by: "clerk".to_string(),
+                requested_at: Utc::now(),
+            },
+            JournalEntryEvent::Posted {
+                entry_id: "JE001".to_string(),
+                entry_number: "EN-2024-001".to_string(),
+                posted_by: "approver".to_string(),
+                posted_at: Utc::now(),
+            },
+        ];
+        event_store.append("JE001", events).await.unwrap();
+        builder.rebuild_all_projections().await.unwrap();
+
+        let entry: StoredJournalEntry = serde_json::from_slice(
+            &projection_db.get_projection("journal_entry:JE001").await.unwrap().unwrap(),
+        )
+        .unwrap();
+        assert_eq!(entry.status, "Posted");
+        assert_eq!(entry.entry_number.as_deref(), Some("EN-2024-001"));
+        assert_eq!(entry.approved_by.as_deref(), Some("approver"));
+        assert_eq!((entry.total_debit, entry.total_credit), (1000.0, 1000.0));
+
+        let ledger: StoredLedgerData = serde_json::from_slice(
+            &projection_db.get_projection("ledger:1100:2024:4").await.unwrap().unwrap(),
+        )
+        .unwrap();
+        assert_eq!(ledger.account_name, "科目1100");
+        assert_eq!(ledger.entries[0].debit_amount, 1000.0);
+        assert_eq!(ledger.entries[0].entry_number, "EN-2024-001");
+
+        let trial_balance: StoredTrialBalanceData = serde_json::from_slice(
+            &projection_db.get_projection("trial_balance:2024:4").await.unwrap().unwrap(),
+        )
+        .unwrap();
+        assert_eq!(trial_balance.entries.len(), 2);
+    }
+}
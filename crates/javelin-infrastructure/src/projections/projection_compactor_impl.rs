@@ -0,0 +1,314 @@
+// ProjectionCompactor具象実装 - Infrastructure層
+// 保持期間を過ぎた削除済み仕訳のキー除去と、空き領域を詰めたProjection環境の書き出し
+
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use chrono::{DateTime, Utc};
+use javelin_application::{
+    error::{ApplicationError, ApplicationResult},
+    projection_compactor::{ProjectionCompactionReport, ProjectionCompactor},
+};
+use javelin_domain::{
+    financial_close::journal_entry::events::JournalEntryEvent,
+    masters::DeletedEntryRetentionSettings, repositories::ApplicationSettingsRepository,
+};
+
+use crate::{
+    event_envelope::EventEnvelope,
+    event_store::EventStore,
+    projection_db::{ProjectionDb, pending_compaction_dir},
+    repositories::ApplicationSettingsRepositoryImpl,
+};
+
+/// 仕訳一覧Projectionのキー接頭辞
+const JOURNAL_ENTRY_PREFIX: &str = "journal_entry:";
+
+/// ProjectionCompactor具象実装
+///
+/// 仕訳一覧Projectionのうち、最後のイベントが削除の仕訳と、
+/// イベントストアに存在しない仕訳のキーを除去する。削除済み仕訳を保持する設定の場合、
+/// 削除から保持月数を過ぎていない仕訳のキーは残す。
+/// 圧縮した環境は `{projection_dir}.compact/` に書き出し、次回起動時に置き換える。
+pub struct ProjectionCompactorImpl {
+    projection_db: Arc<ProjectionDb>,
+    event_store: Arc<EventStore>,
+    projection_dir: PathBuf,
+    settings_repository: Arc<ApplicationSettingsRepositoryImpl>,
+}
+
+/// 除去対象の収集結果
+struct StaleKeys {
+    keys: Vec<String>,
+    /// 保持期間内のため残した削除済み仕訳の数
+    retained_deleted: usize,
+}
+
+impl ProjectionCompactorImpl {
+    /// 新しいProjectionCompactorImplを作成
+    ///
+    /// `projection_dir` は `projection_db` をオープンしたディレクトリ。
+    pub fn new(
+        projection_db: Arc<ProjectionDb>,
+        event_store: Arc<EventStore>,
+        projection_dir: PathBuf,
+        settings_repository: Arc<ApplicationSettingsRepositoryImpl>,
+    ) -> Self {
+        Self { projection_db, event_store, projection_dir, settings_repository }
+    }
+
+    /// 削除済み仕訳の保持設定（未設定の場合は保持しない）
+    async fn deleted_entry_retention(&self) -> ApplicationResult<DeletedEntryRetentionSettings> {
+        Ok(self
+            .settings_repository
+            .find()
+            .await
+            .map_err(|e| ApplicationError::QueryExecutionFailed(e.to_string()))?
+            .map(|settings| settings.deleted_entry_retention())
+            .unwrap_or_default())
+    }
+
+    /// 除去対象のキーを収集
+    ///
+    /// Projectionはイベントの追記後に更新されるため、先にキーを走査してから
+    /// イベントを読み込む。走査したキーの集約は必ずイベントストアに存在し、
+    /// 作成直後の仕訳を取りこぼしとして除去することはない。
+    /// 削除済みの仕訳は、削除日時が `purge_before` 以前のものだけを対象とする。
+    async fn collect_stale_keys(
+        &self,
+        purge_before: DateTime<Utc>,
+    ) -> ApplicationResult<StaleKeys> {
+        let records = self
+            .projection_db
+            .scan_projections(JOURNAL_ENTRY_PREFIX, None, usize::MAX)
+            .await
+            .map_err(|e| ApplicationError::ProjectionDatabaseError(e.to_string()))?;
+
+        let events = self
+            .event_store
+            .get_all_events(0)
+            .await
+            .map_err(|e| ApplicationError::EventStoreError(e.to_string()))?;
+        // 集約ごとの最後のイベント（削除か、記録時刻）
+        let mut last_events: HashMap<&str, (bool, &str)> = HashMap::new();
+        for event in &events {
+            let deleted = matches!(
+                event.envelope(),
+                EventEnvelope::JournalEntry(JournalEntryEvent::Deleted { .. })
+            );
+            last_events.insert(&event.aggregate_id, (deleted, &event.timestamp));
+        }
+
+        let mut stale = StaleKeys { keys: Vec::new(), retained_deleted: 0 };
+        for (key, _) in records {
+            let entry_id = &key[JOURNAL_ENTRY_PREFIX.len()..];
+            match last_events.get(entry_id) {
+                None => stale.keys.push(key),
+                Some((true, timestamp)) => {
+                    // 削除日時を読めない仕訳は、保持期間内とみなして残す
+                    let deleted_at = DateTime::parse_from_rfc3339(timestamp).ok();
+                    if deleted_at.is_some_and(|at| at.to_utc() <= purge_before) {
+                        stale.keys.push(key);
+                    } else {
+                        stale.retained_deleted += 1;
+                    }
+                }
+                Some(_) => {}
+            }
+        }
+        Ok(stale)
+    }
+}
+
+#[async_trait::async_trait]
+impl ProjectionCompactor for ProjectionCompactorImpl {
+    async fn compact(&self) -> ApplicationResult<ProjectionCompactionReport> {
+        let purge_before = self.deleted_entry_retention().await?.purge_cutoff(Utc::now());
+        let stale = self.collect_stale_keys(purge_before).await?;
+        let swept_keys = self
+            .projection_db
+            .delete_projections(stale.keys)
+            .await
+            .map_err(|e| ApplicationError::ProjectionDatabaseError(e.to_string()))?;
+
+        let size_before = data_file_size(&self.projection_dir).await?;
+
+        // 書き出し完了後に改名し、不完全な環境で置き換えられないようにする
+        let staged = pending_compaction_dir(&self.projection_dir);
+        let staging = self.projection_dir.with_extension("compact.tmp");
+        for dir in [&staging, &staged] {
+            if tokio::fs::try_exists(dir).await.unwrap_or(false) {
+                tokio::fs::remove_dir_all(dir).await.map_err(io_error)?;
+            }
+        }
+        tokio::fs::create_dir_all(&staging).await.map_err(io_error)?;
+        self.projection_db
+            .copy_compacted(staging.clone())
+            .await
+            .map_err(|e| ApplicationError::ProjectionDatabaseError(e.to_string()))?;
+        tokio::fs::rename(&staging, &staged).await.map_err(io_error)?;
+
+        let size_after = data_file_size(&staged).await?;
+
+        Ok(ProjectionCompactionReport {
+            swept_keys,
+            retained_deleted: stale.retained_deleted,
+            size_before,
+            size_after,
+        })
+    }
+}
+
+async fn data_file_size(dir: &Path) -> ApplicationResult<u64> {
+    Ok(tokio::fs::metadata(dir.join("data.mdb")).await.map_err(io_error)?.len())
+}
+
+fn io_error(e: std::io::Error) -> ApplicationError {
+    ApplicationError::ProjectionDatabaseError(e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use javelin_domain::financial_close::journal_entry::events::JournalEntryEvent;
+    use tempfile::TempDir;
+
+    use super::*;
+
+    fn deleted(entry_id: &str) -> JournalEntryEvent {
+        JournalEntryEvent::Deleted {
+            entry_id: entry_id.to_string(),
+            deleted_by: "clerk".to_string(),
+            deleted_at: Utc::now(),
+        }
+    }
+
+    fn draft(entry_id: &str) -> JournalEntryEvent {
+        JournalEntryEvent::DraftCreated {
+            entry_id: entry_id.to_string(),
+            transaction_date: "2024-04-01".to_string(),
+            voucher_number: format!("V-{}", entry_id),
+            lines: vec![],
+            description: None,
+            created_by: "clerk".to_string(),
+            created_at: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_compact_sweeps_deleted_and_orphan_entries() {
+        let temp_dir = TempDir::new().unwrap();
+        let projection_dir = temp_dir.path().join("projections");
+        let event_store = Arc::new(EventStore::new(&temp_dir.path().join("events")).await.unwrap());
+        let projection_db = Arc::new(ProjectionDb::new(&projection_dir).await.unwrap());
+
+        event_store.append("je-live", vec![draft("je-live")]).await.unwrap();
+        event_store
+            .append("je-deleted", vec![draft("je-deleted"), deleted("je-deleted")])
+            .await
+            .unwrap();
+        projection_db
+            .update_projection_batch(
+                "main",
+                1,
+                vec![
+                    ("journal_entry:je-live".to_string(), b"{}".to_vec()),
+                    ("journal_entry:je-deleted".to_string(), b"{}".to_vec()),
+                    ("journal_entry:je-orphan".to_string(), b"{}".to_vec()),
+                    ("ledger:1000:2024:4".to_string(), b"{}".to_vec()),
+                ],
+                3,
+            )
+            .await
+            .unwrap();
+
+        let settings_repository = Arc::new(
+            ApplicationSettingsRepositoryImpl::new(&temp_dir.path().join("settings"))
+                .await
+                .unwrap(),
+        );
+        let compactor = ProjectionCompactorImpl::new(
+            Arc::clone(&projection_db),
+            event_store,
+            projection_dir.clone(),
+            settings_repository,
+        );
+        let report = compactor.compact().await.unwrap();
+
+        assert_eq!(report.swept_keys, 2);
+        assert_eq!(report.retained_deleted, 0);
+        assert!(report.size_before > 0);
+        assert!(report.size_after > 0);
+        assert!(pending_compaction_dir(&projection_dir).join("data.mdb").exists());
+        assert!(!projection_dir.with_extension("compact.tmp").exists());
+
+        let remaining = projection_db
+            .scan_projections(JOURNAL_ENTRY_PREFIX, None, usize::MAX)
+            .await
+            .unwrap();
+        let keys: Vec<&str> = remaining.iter().map(|(key, _)| key.as_str()).collect();
+        assert_eq!(keys, vec!["journal_entry:je-live"]);
+        assert!(projection_db.get_projection("ledger:1000:2024:4").await.unwrap().is_some());
+
+        // 再実行しても除去対象はなく、書き出し先は作り直される
+        let again = compactor.compact().await.unwrap();
+        assert_eq!(again.swept_keys, 0);
+    }
+
+    #[tokio::test]
+    async fn test_compact_keeps_deleted_entries_within_retention() {
+        let temp_dir = TempDir::new().unwrap();
+        let projection_dir = temp_dir.path().join("projections");
+        let event_store = Arc::new(EventStore::new(&temp_dir.path().join("events")).await.unwrap());
+        let projection_db = Arc::new(ProjectionDb::new(&projection_dir).await.unwrap());
+        let settings_repository = Arc::new(
+            ApplicationSettingsRepositoryImpl::new(&temp_dir.path().join("settings"))
+                .await
+                .unwrap(),
+        );
+        let mut settings = settings_repository.find().await.unwrap().unwrap();
+        settings
+            .update_deleted_entry_retention(DeletedEntryRetentionSettings::new(true, 12).unwrap());
+        settings_repository.save(&settings).await.unwrap();
+
+        event_store
+            .append("je-deleted", vec![draft("je-deleted"), deleted("je-deleted")])
+            .await
+            .unwrap();
+        projection_db
+            .update_projection_batch(
+                "main",
+                1,
+                vec![
+                    ("journal_entry:je-deleted".to_string(), b"{}".to_vec()),
+                    ("journal_entry:je-orphan".to_string(), b"{}".to_vec()),
+                ],
+                2,
+            )
+            .await
+            .unwrap();
+
+        let compactor = ProjectionCompactorImpl::new(
+            Arc::clone(&projection_db),
+            Arc::clone(&event_store),
+            projection_dir.clone(),
+            Arc::clone(&settings_repository),
+        );
+        let report = compactor.compact().await.unwrap();
+        assert_eq!((report.swept_keys, report.retained_deleted), (1, 1));
+        assert!(
+            projection_db
+                .get_projection("journal_entry:je-deleted")
+                .await
+                .unwrap()
+                .is_some()
+        );
+
+        // 保持月数を過ぎた削除済み仕訳は除去する
+        let stale = compactor.collect_stale_keys(Utc::now()).await.unwrap();
+        assert_eq!(stale.keys, vec!["journal_entry:je-deleted".to_string()]);
+    }
+}
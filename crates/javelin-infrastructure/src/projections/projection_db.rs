@@ -4,12 +4,20 @@
 // 独立性: Projection単位で管理
 // 冪等性: event_sequence追跡
 
-use std::{path::Path, sync::Arc};
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
 
-use lmdb::{Database, DatabaseFlags, Environment, Transaction, WriteFlags};
 use serde::{Deserialize, Serialize};
 
-use crate::error::{InfrastructureError, InfrastructureResult};
+use crate::{
+    crash_point::{self, CrashPoint},
+    error::{InfrastructureError, InfrastructureResult},
+    storage::{KvRead, KvWrite, LmdbBackend, StorageBackend},
+    storage_metrics::DurabilityPolicy,
+};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProjectionPosition {
@@ -19,13 +27,199 @@ pub struct ProjectionPosition {
     pub updated_at: String,
 }
 
-pub struct ProjectionDb {
-    env: Arc<Environment>,
-    state_db: Database, // Read Model本体
-    meta_db: Database,  // チェックポイント・バージョン管理
+/// 1トランザクションで反映するProjectionの更新
+///
+/// 同一キーへの書き込みは最後の値だけを残す。処理中の値は`get`で参照でき、
+/// コミット前の更新を前提に次の更新を組み立てられる。
+#[derive(Debug, Clone, Default)]
+pub struct ProjectionWriteBatch {
+    /// キーごとの書き込み内容（Noneは削除）
+    writes: BTreeMap<String, Option<Vec<u8>>>,
 }
 
-impl ProjectionDb {
+impl ProjectionWriteBatch {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// キーの値を書き込む
+    pub fn put(&mut self, key: impl Into<String>, value: Vec<u8>) {
+        self.writes.insert(key.into(), Some(value));
+    }
+
+    /// キーを削除する
+    pub fn delete(&mut self, key: impl Into<String>) {
+        self.writes.insert(key.into(), None);
+    }
+
+    /// バッチ内の値を取得
+    ///
+    /// バッチに含まれないキーはNone、削除済みのキーはSome(None)を返す。
+    pub fn get(&self, key: &str) -> Option<Option<&[u8]>> {
+        self.writes.get(key).map(|value| value.as_deref())
+    }
+
+    /// 書き込む（削除する）キー
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.writes.keys().map(String::as_str)
+    }
+
+    /// 書き込むキーの数
+    pub fn len(&self) -> usize {
+        self.writes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.writes.is_empty()
+    }
+}
+
+impl From<Vec<(String, Vec<u8>)>> for ProjectionWriteBatch {
+    fn from(updates: Vec<(String, Vec<u8>)>) -> Self {
+        let mut batch = Self::new();
+        for (key, value) in updates {
+            batch.put(key, value);
+        }
+        batch
+    }
+}
+
+/// Read Model本体のテーブル
+const STATE_TABLE: &str = "state";
+/// チェックポイント・バージョン管理のテーブル
+const META_TABLE: &str = "meta";
+const TABLES: &[&str] = &[STATE_TABLE, META_TABLE];
+/// LMDBのデータファイル名
+const DATA_FILE: &str = "data.mdb";
+/// 運用メトリクスのキー接頭辞（metaテーブル、Projectionの再構築・圧縮で失わない）
+const TELEMETRY_PREFIX: &str = "telemetry:";
+/// 反映位置の最高到達点のキー接頭辞（反映位置とともに初期化で削除する）
+const HIGH_WATER_MARK_PREFIX: &str = "high_water_mark:";
+/// Projection環境のmap_size
+const MAP_SIZE: usize = 100 * 1024 * 1024; // 100MB
+
+/// 圧縮済み環境の配置先（`{path}.compact/`、次回オープン時に置き換える）
+pub fn pending_compaction_dir(path: &Path) -> PathBuf {
+    path.with_extension("compact")
+}
+
+fn open_backend(path: &Path) -> InfrastructureResult<LmdbBackend> {
+    LmdbBackend::open(path, TABLES, MAP_SIZE, DurabilityPolicy::MaxDurability)
+}
+
+/// 運用メトリクスを読み出す
+fn read_telemetry(backend: &impl StorageBackend) -> InfrastructureResult<Vec<(Vec<u8>, Vec<u8>)>> {
+    let mut records = Vec::new();
+    backend.begin_read()?.scan(
+        META_TABLE,
+        Some(TELEMETRY_PREFIX.as_bytes()),
+        &mut |key, value| {
+            if !key.starts_with(TELEMETRY_PREFIX.as_bytes()) {
+                return Ok(false);
+            }
+            records.push((key.to_vec(), value.to_vec()));
+            Ok(true)
+        },
+    )?;
+    Ok(records)
+}
+
+/// 予約された圧縮済み環境で置き換え、置き換えたかを返す
+///
+/// 環境をオープンする前に呼び出すこと。圧縮済み環境の書き出し後に記録した
+/// 運用メトリクスは圧縮済み環境に含まれないため、置き換え前の値を引き継ぐ。
+fn apply_pending_compaction(path: &Path) -> InfrastructureResult<bool> {
+    let staged = pending_compaction_dir(path);
+    let staged_file = staged.join(DATA_FILE);
+    if !staged_file.exists() {
+        return Ok(false);
+    }
+
+    let telemetry = if path.join(DATA_FILE).exists() {
+        read_telemetry(&open_backend(path)?)?
+    } else {
+        Vec::new()
+    };
+
+    std::fs::rename(&staged_file, path.join(DATA_FILE)).map_err(|e| {
+        InfrastructureError::ProjectionDbInitFailed { path: path.display().to_string(), source: e }
+    })?;
+    // 置き換え後の残りは次回の圧縮で作り直すため、削除に失敗しても続行する
+    let _ = std::fs::remove_dir_all(&staged);
+
+    if !telemetry.is_empty() {
+        let backend = open_backend(path)?;
+        let mut txn = backend.begin_write()?;
+        for (key, value) in &telemetry {
+            txn.put(META_TABLE, key, value)?;
+        }
+        txn.commit()?;
+    }
+    Ok(true)
+}
+
+/// 単一の読み取りトランザクションに固定したProjectionの参照
+///
+/// スナップショット内の読み取りは、ワーカーが並行して書き込んでも
+/// すべて開始時点の同じ反映位置の内容を返す。試算表と元帳のように
+/// 複数キーをまたいで照会する場合に、更新途中の状態を読まないために使用する。
+pub struct ProjectionSnapshot<'t> {
+    txn: &'t dyn KvRead,
+}
+
+impl ProjectionSnapshot<'_> {
+    /// Projectionを取得
+    pub fn get(&self, key: &str) -> InfrastructureResult<Option<Vec<u8>>> {
+        self.txn.get(STATE_TABLE, key.as_bytes())
+    }
+
+    /// 前方一致するProjectionをキー順に取得
+    ///
+    /// `after` を指定した場合はそのキーより後から取得する（ページング用）。
+    pub fn scan(
+        &self,
+        prefix: &str,
+        after: Option<&str>,
+        limit: usize,
+    ) -> InfrastructureResult<Vec<(String, Vec<u8>)>> {
+        let prefix = prefix.as_bytes();
+        let after = after.map(str::as_bytes);
+        let mut records = Vec::new();
+        self.txn.scan(STATE_TABLE, Some(after.unwrap_or(prefix)), &mut |key, value| {
+            if !key.starts_with(prefix) || records.len() >= limit {
+                return Ok(false);
+            }
+            if after != Some(key) {
+                records.push((String::from_utf8_lossy(key).into_owned(), value.to_vec()));
+            }
+            Ok(true)
+        })?;
+        Ok(records)
+    }
+
+    /// スナップショット時点のプロジェクション位置
+    pub fn position(
+        &self,
+        projection_name: &str,
+        projection_version: u32,
+    ) -> InfrastructureResult<u64> {
+        let key = format!("{}:v{}", projection_name, projection_version);
+        match self.txn.get(META_TABLE, key.as_bytes())? {
+            Some(bytes) => {
+                let position: ProjectionPosition = serde_json::from_slice(&bytes)
+                    .map_err(|e| InfrastructureError::DeserializationFailed(e.to_string()))?;
+                Ok(position.last_processed_sequence)
+            }
+            None => Ok(0),
+        }
+    }
+}
+
+pub struct ProjectionDb<B: StorageBackend = LmdbBackend> {
+    backend: Arc<B>,
+}
+
+impl ProjectionDb<LmdbBackend> {
     pub async fn new(path: &Path) -> InfrastructureResult<Self> {
         // ディレクトリが存在しない場合は作成
         if !path.exists() {
@@ -37,22 +231,79 @@ impl ProjectionDb {
             })?;
         }
 
-        // LMDB環境の初期化
-        let env = Environment::new()
-            .set_max_dbs(2) // state + meta
-            .set_map_size(100 * 1024 * 1024) // 100MB
-            .open(path)
-            .map_err(|e| InfrastructureError::LmdbError(e.to_string()))?;
+        // 前回の圧縮結果があれば置き換える（Projectionは反映位置から追い付く）
+        apply_pending_compaction(path)?;
 
-        let state_db = env
-            .create_db(Some("state"), DatabaseFlags::empty())
-            .map_err(|e| InfrastructureError::LmdbError(e.to_string()))?;
+        Ok(Self::with_backend(Arc::new(open_backend(path)?)))
+    }
 
-        let meta_db = env
-            .create_db(Some("meta"), DatabaseFlags::empty())
-            .map_err(|e| InfrastructureError::LmdbError(e.to_string()))?;
+    /// 参照専用（レプリカ）としてオープン
+    ///
+    /// 書き込みプロセスが更新するProjectionを別プロセスから参照する。
+    /// チェックポイントはstateと同一トランザクションで更新されるため、
+    /// 読み取りトランザクション内のstateは常に`get_position`の位置と一致する。
+    pub async fn open_read_only(path: &Path) -> InfrastructureResult<Self> {
+        let backend = LmdbBackend::open_read_only(path, TABLES)?;
+        Ok(Self::with_backend(Arc::new(backend)))
+    }
+}
 
-        Ok(Self { env: Arc::new(env), state_db, meta_db })
+#[cfg(any(test, feature = "testkit"))]
+impl ProjectionDb<crate::storage::MemoryBackend> {
+    /// メモリ上のProjectionDbを作成（単体テスト用）
+    pub fn in_memory() -> Self {
+        Self::with_backend(Arc::new(crate::storage::MemoryBackend::new(TABLES)))
+    }
+}
+
+impl<B: StorageBackend> ProjectionDb<B> {
+    /// 任意のバックエンドでProjectionDbを構築
+    ///
+    /// バックエンドは state / meta テーブルを持つこと。
+    pub fn with_backend(backend: Arc<B>) -> Self {
+        Self { backend }
+    }
+
+    /// 参照専用（レプリカ）としてオープンしたか
+    pub fn is_read_only(&self) -> bool {
+        self.backend.is_read_only()
+    }
+
+    /// 書き込み可能であることを確認
+    fn ensure_writable(&self) -> InfrastructureResult<()> {
+        if self.is_read_only() {
+            return Err(InfrastructureError::ReadOnlyReplica("projection database".to_string()));
+        }
+        Ok(())
+    }
+
+    /// バックエンドのブロッキング操作をワーカースレッドで実行
+    async fn blocking<R, F>(&self, f: F) -> InfrastructureResult<R>
+    where
+        R: Send + 'static,
+        F: FnOnce(&B) -> InfrastructureResult<R> + Send + 'static,
+    {
+        let backend = Arc::clone(&self.backend);
+        tokio::task::spawn_blocking(move || f(&backend))
+            .await
+            .map_err(|e| InfrastructureError::LmdbError(e.to_string()))?
+    }
+
+    /// 単一の読み取りトランザクション内で照会を実行
+    ///
+    /// `read` 内のすべての読み取りは同じスナップショットを参照する。
+    /// トランザクションは `read` の終了まで保持されるため、長時間の処理は避けること。
+    /// 読み取り専用のため、参照専用（レプリカ）でも利用できる。
+    pub async fn read_snapshot<R, F>(&self, read: F) -> InfrastructureResult<R>
+    where
+        R: Send + 'static,
+        F: FnOnce(&ProjectionSnapshot<'_>) -> InfrastructureResult<R> + Send + 'static,
+    {
+        self.blocking(move |backend| {
+            let txn = backend.begin_read()?;
+            read(&ProjectionSnapshot { txn: &txn })
+        })
+        .await
     }
 
     /// プロジェクション位置を取得
@@ -61,28 +312,9 @@ impl ProjectionDb {
         projection_name: &str,
         projection_version: u32,
     ) -> InfrastructureResult<u64> {
-        let env = Arc::clone(&self.env);
-        let meta_db = self.meta_db;
-        let key = format!("{}:v{}", projection_name, projection_version);
-
-        let result = tokio::task::spawn_blocking(move || {
-            let txn =
-                env.begin_ro_txn().map_err(|e| InfrastructureError::LmdbError(e.to_string()))?;
-
-            match txn.get(meta_db, &key.as_bytes()) {
-                Ok(bytes) => {
-                    let position: ProjectionPosition = serde_json::from_slice(bytes)
-                        .map_err(|e| InfrastructureError::DeserializationFailed(e.to_string()))?;
-                    Ok(position.last_processed_sequence)
-                }
-                Err(lmdb::Error::NotFound) => Ok(0),
-                Err(e) => Err(InfrastructureError::LmdbError(e.to_string())),
-            }
-        })
-        .await
-        .map_err(|e| InfrastructureError::LmdbError(e.to_string()))??;
-
-        Ok(result)
+        let projection_name = projection_name.to_string();
+        self.read_snapshot(move |snapshot| snapshot.position(&projection_name, projection_version))
+            .await
     }
 
     /// プロジェクション更新（複数キー + チェックポイント、同一トランザクション）
@@ -91,25 +323,27 @@ impl ProjectionDb {
         &self,
         projection_name: &str,
         projection_version: u32,
-        updates: Vec<(String, Vec<u8>)>,
+        updates: impl Into<ProjectionWriteBatch>,
         event_sequence: u64,
     ) -> InfrastructureResult<()> {
-        let env = Arc::clone(&self.env);
-        let state_db = self.state_db;
-        let meta_db = self.meta_db;
+        self.ensure_writable()?;
+        let updates = updates.into();
         let projection_name = projection_name.to_string(); // 所有権を取得
         let checkpoint_key = format!("{}:v{}", projection_name, projection_version);
 
-        tokio::task::spawn_blocking(move || {
+        self.blocking(move |backend| {
             // 単一RWトランザクション内で全更新を実行
-            let mut txn =
-                env.begin_rw_txn().map_err(|e| InfrastructureError::LmdbError(e.to_string()))?;
+            let mut txn = backend.begin_write()?;
 
             // 1. 全state更新
-            for (key, value) in updates {
-                // データを直接保存（メタデータなし）
-                txn.put(state_db, &key.as_bytes(), &value, WriteFlags::empty())
-                    .map_err(|e| InfrastructureError::LmdbError(e.to_string()))?;
+            for (key, value) in updates.writes {
+                match value {
+                    // データを直接保存（メタデータなし）
+                    Some(value) => txn.put(STATE_TABLE, key.as_bytes(), &value)?,
+                    None => {
+                        txn.delete(STATE_TABLE, key.as_bytes())?;
+                    }
+                }
             }
 
             // 2. チェックポイント更新
@@ -122,18 +356,16 @@ impl ProjectionDb {
             let position_bytes = serde_json::to_vec(&position)
                 .map_err(|e| InfrastructureError::SerializationFailed(e.to_string()))?;
 
-            txn.put(meta_db, &checkpoint_key.as_bytes(), &position_bytes, WriteFlags::empty())
-                .map_err(|e| InfrastructureError::LmdbError(e.to_string()))?;
+            txn.put(META_TABLE, checkpoint_key.as_bytes(), &position_bytes)?;
 
             // 3. 単一コミット（アトミック性保証）
-            txn.commit().map_err(|e| InfrastructureError::LmdbError(e.to_string()))?;
+            crash_point::reached(CrashPoint::ProjectionBeforeCommit);
+            txn.commit()?;
+            crash_point::reached(CrashPoint::ProjectionAfterCommit);
 
-            Ok::<_, InfrastructureError>(())
+            Ok(())
         })
         .await
-        .map_err(|e| InfrastructureError::LmdbError(e.to_string()))??;
-
-        Ok(())
     }
 
     /// Projectionを更新（単一キー、後方互換用）
@@ -154,50 +386,190 @@ impl ProjectionDb {
 
     /// Projectionを取得
     pub async fn get_projection(&self, key: &str) -> InfrastructureResult<Option<Vec<u8>>> {
-        let env = Arc::clone(&self.env);
-        let state_db = self.state_db;
         let key = key.to_string();
 
-        let result = tokio::task::spawn_blocking(move || {
-            let txn =
-                env.begin_ro_txn().map_err(|e| InfrastructureError::LmdbError(e.to_string()))?;
+        // データを直接返す（メタデータなし）
+        self.read_snapshot(move |snapshot| snapshot.get(&key)).await
+    }
 
-            match txn.get(state_db, &key.as_bytes()) {
-                Ok(bytes) => {
-                    // データを直接返す（メタデータなし）
-                    Ok(Some(bytes.to_vec()))
+    /// 前方一致するProjectionをキー順に取得
+    ///
+    /// `after` を指定した場合はそのキーより後から取得する（ページング用）。
+    /// 読み取り専用のため、参照専用（レプリカ）でも利用できる。
+    pub async fn scan_projections(
+        &self,
+        prefix: &str,
+        after: Option<&str>,
+        limit: usize,
+    ) -> InfrastructureResult<Vec<(String, Vec<u8>)>> {
+        let prefix = prefix.to_string();
+        let after = after.map(str::to_string);
+
+        self.read_snapshot(move |snapshot| snapshot.scan(&prefix, after.as_deref(), limit))
+            .await
+    }
+
+    /// 複数のProjectionを1トランザクションで削除し、削除した件数を返す
+    ///
+    /// チェックポイントは更新しない（保守用）。存在しないキーは件数に含めない。
+    pub async fn delete_projections(&self, keys: Vec<String>) -> InfrastructureResult<usize> {
+        self.ensure_writable()?;
+
+        self.blocking(move |backend| {
+            let mut txn = backend.begin_write()?;
+            let mut deleted = 0;
+            for key in keys {
+                if txn.delete(STATE_TABLE, key.as_bytes())? {
+                    deleted += 1;
                 }
-                Err(lmdb::Error::NotFound) => Ok(None),
-                Err(e) => Err(InfrastructureError::LmdbError(e.to_string())),
             }
+            txn.commit()?;
+            Ok(deleted)
         })
         .await
-        .map_err(|e| InfrastructureError::LmdbError(e.to_string()))??;
+    }
 
-        Ok(result)
+    /// 空き領域を詰めた環境を `destination` に書き出す
+    ///
+    /// `destination` は存在する空のディレクトリであること。
+    pub async fn copy_compacted(&self, destination: PathBuf) -> InfrastructureResult<()> {
+        self.blocking(move |backend| backend.copy_compacted(&destination)).await
     }
 
-    /// Projectionを削除
-    pub async fn delete_projection(&self, key: &str) -> InfrastructureResult<()> {
-        let env = Arc::clone(&self.env);
-        let state_db = self.state_db;
-        let key = key.to_string();
+    /// 運用メトリクスを取得
+    ///
+    /// 読み取り専用のため、参照専用（レプリカ）でも利用できる。
+    pub async fn get_telemetry(&self, name: &str) -> InfrastructureResult<Option<Vec<u8>>> {
+        let key = format!("{}{}", TELEMETRY_PREFIX, name);
+        self.blocking(move |backend| backend.begin_read()?.get(META_TABLE, key.as_bytes()))
+            .await
+    }
 
-        tokio::task::spawn_blocking(move || {
-            let mut txn =
-                env.begin_rw_txn().map_err(|e| InfrastructureError::LmdbError(e.to_string()))?;
+    /// 運用メトリクスを保存
+    ///
+    /// Projectionのstateとは別に保持し、再構築・圧縮の対象としない。
+    pub async fn put_telemetry(&self, name: &str, value: Vec<u8>) -> InfrastructureResult<()> {
+        self.ensure_writable()?;
+        let key = format!("{}{}", TELEMETRY_PREFIX, name);
+
+        self.blocking(move |backend| {
+            let mut txn = backend.begin_write()?;
+            txn.put(META_TABLE, key.as_bytes(), &value)?;
+            txn.commit()
+        })
+        .await
+    }
 
-            txn.del(state_db, &key.as_bytes(), None)
-                .map_err(|e| InfrastructureError::LmdbError(e.to_string()))?;
+    /// すべてのProjectionと反映位置を削除し、削除したProjectionの件数を返す
+    ///
+    /// 反映位置がイベントストアより先行した場合など、Projectionを作り直す前に使用する。
+    /// 運用メトリクスは再構築の対象外のため残す。
+    pub async fn reset(&self) -> InfrastructureResult<usize> {
+        self.ensure_writable()?;
+
+        self.blocking(|backend| {
+            let mut txn = backend.begin_write()?;
+            let mut state_keys = Vec::new();
+            txn.scan(STATE_TABLE, None, &mut |key, _| {
+                state_keys.push(key.to_vec());
+                Ok(true)
+            })?;
+            let mut checkpoint_keys = Vec::new();
+            txn.scan(META_TABLE, None, &mut |key, _| {
+                if !key.starts_with(TELEMETRY_PREFIX.as_bytes()) {
+                    checkpoint_keys.push(key.to_vec());
+                }
+                Ok(true)
+            })?;
 
-            txn.commit().map_err(|e| InfrastructureError::LmdbError(e.to_string()))?;
+            for key in &state_keys {
+                txn.delete(STATE_TABLE, key)?;
+            }
+            for key in &checkpoint_keys {
+                txn.delete(META_TABLE, key)?;
+            }
+            txn.commit()?;
+            Ok(state_keys.len())
+        })
+        .await
+    }
 
-            Ok::<_, InfrastructureError>(())
+    /// 保存されているすべての反映位置（キー順）
+    ///
+    /// 読み取り専用のため、参照専用（レプリカ）でも利用できる。
+    pub async fn list_positions(&self) -> InfrastructureResult<Vec<ProjectionPosition>> {
+        self.blocking(|backend| {
+            let mut positions = Vec::new();
+            backend.begin_read()?.scan(META_TABLE, None, &mut |key, value| {
+                if key.starts_with(TELEMETRY_PREFIX.as_bytes())
+                    || key.starts_with(HIGH_WATER_MARK_PREFIX.as_bytes())
+                {
+                    return Ok(true);
+                }
+                let position: ProjectionPosition = serde_json::from_slice(value)
+                    .map_err(|e| InfrastructureError::DeserializationFailed(e.to_string()))?;
+                positions.push(position);
+                Ok(true)
+            })?;
+            Ok(positions)
         })
         .await
-        .map_err(|e| InfrastructureError::LmdbError(e.to_string()))??;
+    }
 
-        Ok(())
+    /// 反映位置の最高到達点（未記録は0）
+    pub async fn get_high_water_mark(
+        &self,
+        projection_name: &str,
+        projection_version: u32,
+    ) -> InfrastructureResult<u64> {
+        let key = format!("{}{}:v{}", HIGH_WATER_MARK_PREFIX, projection_name, projection_version);
+        let bytes = self
+            .blocking(move |backend| backend.begin_read()?.get(META_TABLE, key.as_bytes()))
+            .await?;
+        Ok(bytes
+            .and_then(|bytes| bytes.try_into().ok())
+            .map(u64::from_be_bytes)
+            .unwrap_or(0))
+    }
+
+    /// 反映位置の最高到達点を記録（記録済みの値より小さい場合は更新しない）
+    pub async fn record_high_water_mark(
+        &self,
+        projection_name: &str,
+        projection_version: u32,
+        position: u64,
+    ) -> InfrastructureResult<()> {
+        self.ensure_writable()?;
+        let key = format!("{}{}:v{}", HIGH_WATER_MARK_PREFIX, projection_name, projection_version);
+
+        self.blocking(move |backend| {
+            let mut txn = backend.begin_write()?;
+            let recorded = txn
+                .get(META_TABLE, key.as_bytes())?
+                .and_then(|bytes| bytes.try_into().ok())
+                .map(u64::from_be_bytes)
+                .unwrap_or(0);
+            if position > recorded {
+                txn.put(META_TABLE, key.as_bytes(), &position.to_be_bytes())?;
+            }
+            txn.commit()
+        })
+        .await
+    }
+
+    /// Projectionを削除
+    ///
+    /// 存在しないキーの削除はエラーとしない。
+    pub async fn delete_projection(&self, key: &str) -> InfrastructureResult<()> {
+        self.ensure_writable()?;
+        let key = key.to_string();
+
+        self.blocking(move |backend| {
+            let mut txn = backend.begin_write()?;
+            txn.delete(STATE_TABLE, key.as_bytes())?;
+            txn.commit()
+        })
+        .await
     }
 }
 
@@ -237,4 +609,211 @@ mod tests {
 
         assert!(result.is_ok(), "Projection get should succeed");
     }
+
+    #[tokio::test]
+    async fn test_in_memory_projection_db_keeps_state_and_position() {
+        let db = ProjectionDb::in_memory();
+        db.update_projection_batch("main", 1, vec![("a".to_string(), b"1".to_vec())], 7)
+            .await
+            .unwrap();
+
+        assert_eq!(db.get_projection("a").await.unwrap(), Some(b"1".to_vec()));
+        assert_eq!(db.get_position("main", 1).await.unwrap(), 7);
+        assert!(!db.is_read_only());
+    }
+
+    #[tokio::test]
+    async fn test_projection_db_batch_with_delete() {
+        let temp_dir = TempDir::new().expect("Failed to create temp directory");
+        let db = ProjectionDb::new(&temp_dir.path().join("projections")).await.unwrap();
+        db.update_projection("old", b"value", 1).await.unwrap();
+
+        let mut batch = ProjectionWriteBatch::new();
+        batch.put("key1", b"first".to_vec());
+        batch.put("key1", b"second".to_vec());
+        batch.delete("old");
+        assert_eq!(batch.len(), 2);
+        assert_eq!(batch.get("key1"), Some(Some(&b"second"[..])));
+        assert_eq!(batch.get("old"), Some(None));
+
+        db.update_projection_batch("main", 1, batch, 2).await.unwrap();
+
+        assert_eq!(db.get_projection("key1").await.unwrap(), Some(b"second".to_vec()));
+        assert_eq!(db.get_projection("old").await.unwrap(), None);
+        assert_eq!(db.get_position("main", 1).await.unwrap(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_reset_removes_projections_and_positions_but_keeps_telemetry() {
+        let temp_dir = TempDir::new().expect("Failed to create temp directory");
+        let db = ProjectionDb::new(&temp_dir.path().join("projections")).await.unwrap();
+        db.update_projection_batch(
+            "main",
+            1,
+            vec![("a".to_string(), b"1".to_vec()), ("b".to_string(), b"2".to_vec())],
+            7,
+        )
+        .await
+        .unwrap();
+        db.put_telemetry("storage", b"sample".to_vec()).await.unwrap();
+
+        assert_eq!(db.reset().await.unwrap(), 2);
+
+        assert_eq!(db.get_projection("a").await.unwrap(), None);
+        assert_eq!(db.get_position("main", 1).await.unwrap(), 0);
+        assert_eq!(db.get_telemetry("storage").await.unwrap(), Some(b"sample".to_vec()));
+    }
+
+    #[tokio::test]
+    async fn test_list_positions_and_high_water_marks_are_cleared_by_reset() {
+        let temp_dir = TempDir::new().expect("Failed to create temp directory");
+        let db = ProjectionDb::new(&temp_dir.path().join("projections")).await.unwrap();
+        db.update_projection_batch("main", 1, vec![("a".to_string(), b"1".to_vec())], 7)
+            .await
+            .unwrap();
+        db.update_projection_batch("journal_entries", 1, Vec::<(String, Vec<u8>)>::new(), 3)
+            .await
+            .unwrap();
+        db.put_telemetry("storage", b"sample".to_vec()).await.unwrap();
+        db.record_high_water_mark("main", 1, 7).await.unwrap();
+        db.record_high_water_mark("main", 1, 5).await.unwrap();
+
+        let positions: Vec<(String, u64)> = db
+            .list_positions()
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|p| (p.projection_name, p.last_processed_sequence))
+            .collect();
+        assert_eq!(positions, vec![("journal_entries".to_string(), 3), ("main".to_string(), 7)]);
+        assert_eq!(db.get_high_water_mark("main", 1).await.unwrap(), 7);
+
+        db.reset().await.unwrap();
+        assert!(db.list_positions().await.unwrap().is_empty());
+        assert_eq!(db.get_high_water_mark("main", 1).await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_projection_db_scan_with_prefix_and_paging() {
+        let temp_dir = TempDir::new().expect("Failed to create temp directory");
+        let db = ProjectionDb::new(&temp_dir.path().join("projections")).await.unwrap();
+        db.update_projection_batch(
+            "main",
+            1,
+            vec![
+                ("ledger:1000:a".to_string(), b"1".to_vec()),
+                ("ledger:1000:b".to_string(), b"2".to_vec()),
+                ("ledger:1000:c".to_string(), b"3".to_vec()),
+                ("ledger:2000:a".to_string(), b"4".to_vec()),
+                ("journal:a".to_string(), b"5".to_vec()),
+            ],
+            1,
+        )
+        .await
+        .unwrap();
+
+        let first = db.scan_projections("ledger:1000:", None, 2).await.unwrap();
+        let keys: Vec<&str> = first.iter().map(|(key, _)| key.as_str()).collect();
+        assert_eq!(keys, vec!["ledger:1000:a", "ledger:1000:b"]);
+
+        let second = db.scan_projections("ledger:1000:", Some("ledger:1000:b"), 2).await.unwrap();
+        assert_eq!(second, vec![("ledger:1000:c".to_string(), b"3".to_vec())]);
+
+        assert!(db.scan_projections("missing:", None, 10).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_does_not_observe_concurrent_commit() {
+        let temp_dir = TempDir::new().expect("Failed to create temp directory");
+        let path = temp_dir.path().join("projections");
+        std::fs::create_dir_all(&path).unwrap();
+        let backend = Arc::new(open_backend(&path).unwrap());
+        let db = ProjectionDb::with_backend(Arc::clone(&backend));
+        db.update_projection_batch(
+            "main",
+            1,
+            vec![
+                ("trial_balance:2024:4".to_string(), b"v1".to_vec()),
+                ("ledger:1000:2024:4".to_string(), b"v1".to_vec()),
+            ],
+            1,
+        )
+        .await
+        .unwrap();
+
+        let writer = Arc::clone(&backend);
+        let (trial_balance, ledger, position) = db
+            .read_snapshot(move |snapshot| {
+                let trial_balance = snapshot.get("trial_balance:2024:4")?;
+                // 照会の途中でワーカーが両方のキーを更新する
+                std::thread::spawn(move || {
+                    let mut txn = writer.begin_write()?;
+                    txn.put(STATE_TABLE, b"trial_balance:2024:4", b"v2")?;
+                    txn.put(STATE_TABLE, b"ledger:1000:2024:4", b"v2")?;
+                    txn.commit()
+                })
+                .join()
+                .unwrap()?;
+                let ledger = snapshot.get("ledger:1000:2024:4")?;
+                Ok((trial_balance, ledger, snapshot.position("main", 1)?))
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(trial_balance, Some(b"v1".to_vec()));
+        assert_eq!(ledger, Some(b"v1".to_vec()));
+        assert_eq!(position, 1);
+        // 新しいスナップショットではコミット後の内容を参照する
+        assert_eq!(db.get_projection("ledger:1000:2024:4").await.unwrap(), Some(b"v2".to_vec()));
+    }
+
+    #[tokio::test]
+    async fn test_delete_projections_counts_existing_keys() {
+        let temp_dir = TempDir::new().expect("Failed to create temp directory");
+        let db = ProjectionDb::new(&temp_dir.path().join("projections")).await.unwrap();
+        db.update_projection_batch(
+            "main",
+            1,
+            vec![("a".to_string(), b"1".to_vec()), ("b".to_string(), b"2".to_vec())],
+            5,
+        )
+        .await
+        .unwrap();
+
+        let deleted = db
+            .delete_projections(vec!["a".to_string(), "missing".to_string()])
+            .await
+            .unwrap();
+
+        assert_eq!(deleted, 1);
+        assert_eq!(db.get_projection("a").await.unwrap(), None);
+        assert_eq!(db.get_projection("b").await.unwrap(), Some(b"2".to_vec()));
+        // チェックポイントは変わらない
+        assert_eq!(db.get_position("main", 1).await.unwrap(), 5);
+    }
+
+    #[tokio::test]
+    async fn test_pending_compaction_is_applied_on_open() {
+        let temp_dir = TempDir::new().expect("Failed to create temp directory");
+        let path = temp_dir.path().join("projections");
+        let staged = pending_compaction_dir(&path);
+        {
+            let db = ProjectionDb::new(&path).await.unwrap();
+            db.update_projection("kept", b"value", 1).await.unwrap();
+            std::fs::create_dir_all(&staged).unwrap();
+            db.copy_compacted(staged.clone()).await.unwrap();
+            // 書き出し後の更新は置き換えで失われる
+            db.update_projection("after", b"value", 2).await.unwrap();
+            // 運用メトリクスは置き換え後も引き継ぐ
+            db.put_telemetry("storage", b"samples".to_vec()).await.unwrap();
+        }
+
+        let db = ProjectionDb::new(&path).await.unwrap();
+        assert_eq!(db.get_telemetry("storage").await.unwrap(), Some(b"samples".to_vec()));
+
+        assert!(!staged.exists());
+        assert_eq!(db.get_projection("kept").await.unwrap(), Some(b"value".to_vec()));
+        assert_eq!(db.get_projection("after").await.unwrap(), None);
+        assert_eq!(db.get_position("default", 1).await.unwrap(), 1);
+    }
 }
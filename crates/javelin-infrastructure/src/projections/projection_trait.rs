@@ -81,7 +81,9 @@ mod tests {
             aggregate_id: "user-1".to_string(),
             version: 1,
             timestamp: "2024-01-01T00:00:00Z".to_string(),
+            business_date: None,
             payload: vec![],
+            checksum: None,
         };
 
         let event2 = StoredEvent {
@@ -90,7 +92,9 @@ mod tests {
             aggregate_id: "order-1".to_string(),
             version: 1,
             timestamp: "2024-01-01T00:00:00Z".to_string(),
+            business_date: None,
             payload: vec![],
+            checksum: None,
         };
 
         assert!(strategy.should_update(&event1));
@@ -0,0 +1,120 @@
+// ReplicaMonitor - 参照専用レプリカの反映位置監視
+// 書き込みプロセスが更新するProjectionのチェックポイントを定期的に確認し、
+// 反映位置が進んだことを購読者へ通知する
+
+use std::sync::Arc;
+
+use tokio::{
+    sync::watch,
+    time::{Duration, Instant, interval},
+};
+
+use crate::{error::InfrastructureResult, event_store::EventStore, projection_db::ProjectionDb};
+
+/// レプリカの反映状況
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ReplicaStatus {
+    /// Projectionに反映済みのイベント位置
+    pub projection_position: u64,
+    /// イベントストア上の最新位置
+    pub latest_sequence: u64,
+}
+
+impl ReplicaStatus {
+    /// Projectionへの未反映イベント数
+    pub fn lag(&self) -> u64 {
+        self.latest_sequence.saturating_sub(self.projection_position)
+    }
+}
+
+/// 参照専用レプリカの反映位置監視
+///
+/// LMDBの読み取りは常に最新のコミットを参照するため、レプリカ側で
+/// データを再読込する必要はない。本監視は反映位置の変化を通知し、
+/// 表示中の集計を再取得する契機として利用する。
+pub struct ReplicaMonitor {
+    event_store: Arc<EventStore>,
+    projection_db: Arc<ProjectionDb>,
+    projection_name: String,
+    projection_version: u32,
+    poll_interval: Duration,
+}
+
+impl ReplicaMonitor {
+    pub fn new(
+        event_store: Arc<EventStore>,
+        projection_db: Arc<ProjectionDb>,
+        projection_name: impl Into<String>,
+        projection_version: u32,
+    ) -> Self {
+        Self {
+            event_store,
+            projection_db,
+            projection_name: projection_name.into(),
+            projection_version,
+            poll_interval: Duration::from_secs(1),
+        }
+    }
+
+    pub fn with_poll_interval(mut self, interval: Duration) -> Self {
+        self.poll_interval = interval;
+        self
+    }
+
+    /// 現在の反映状況を取得
+    pub async fn status(&self) -> InfrastructureResult<ReplicaStatus> {
+        let projection_position = self
+            .projection_db
+            .get_position(&self.projection_name, self.projection_version)
+            .await?;
+        let latest_sequence = self.event_store.get_latest_sequence().await?.as_u64();
+        Ok(ReplicaStatus { projection_position, latest_sequence })
+    }
+
+    /// 監視タスクを開始し、反映状況の購読チャネルを返す
+    ///
+    /// 反映状況が変化した時のみ通知する。購読者がいなくなると終了する。
+    pub async fn spawn(self) -> InfrastructureResult<watch::Receiver<ReplicaStatus>> {
+        let (status_tx, status_rx) = watch::channel(self.status().await?);
+
+        tokio::spawn(async move {
+            let mut ticker = interval(self.poll_interval);
+            loop {
+                ticker.tick().await;
+                if status_tx.is_closed() {
+                    break;
+                }
+                // 一時的な読み取り失敗は次回の確認で回復する
+                if let Ok(status) = self.status().await {
+                    status_tx.send_if_modified(|current| {
+                        let changed = *current != status;
+                        *current = status;
+                        changed
+                    });
+                }
+            }
+        });
+
+        Ok(status_rx)
+    }
+}
+
+/// 反映位置が指定位置に到達するまで待機（到達した場合はtrue）
+///
+/// 書き込みプロセスで記帳した直後に、レプリカ側で集計を取得する場合に使用する。
+pub async fn wait_for_position(
+    status_rx: &mut watch::Receiver<ReplicaStatus>,
+    position: u64,
+    timeout: Duration,
+) -> bool {
+    let deadline = Instant::now() + timeout;
+    loop {
+        if status_rx.borrow_and_update().projection_position >= position {
+            return true;
+        }
+        match tokio::time::timeout_at(deadline, status_rx.changed()).await {
+            Ok(Ok(())) => continue,
+            _ => return false,
+        }
+    }
+}
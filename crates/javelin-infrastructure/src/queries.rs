@@ -1,4 +1,9 @@
+pub mod audit_export_query_service_impl;
 pub mod batch_history_query_service_impl;
+pub mod event_checksum_query_service_impl;
+pub mod inbox_projection;
+pub mod inbox_query_service_impl;
+pub mod journal_entry_chain_query_service_impl;
 pub mod journal_entry_projection;
 pub mod journal_entry_projection_worker;
 pub mod journal_entry_search_projection;
@@ -6,8 +11,27 @@ pub mod journal_entry_search_query_service_impl;
 pub mod journal_entry_search_read_model;
 pub mod ledger_projection;
 pub mod master_data_loader_impl;
+pub mod period_reopen_query_service_impl;
+pub mod projection_cache;
+pub mod projection_consistency_query_service_impl;
+pub mod projection_inspection_query_service_impl;
+pub mod projection_position_query_service_impl;
+pub mod sequence_audit_query_service_impl;
+pub mod suspense_aging_projection;
+pub mod suspense_aging_query_service_impl;
 
 // Re-export for convenience
+pub use audit_export_query_service_impl::AuditExportQueryServiceImpl;
 pub use batch_history_query_service_impl::BatchHistoryQueryServiceImpl;
+pub use event_checksum_query_service_impl::EventChecksumQueryServiceImpl;
+pub use inbox_query_service_impl::InboxQueryServiceImpl;
+pub use journal_entry_chain_query_service_impl::JournalEntryChainQueryServiceImpl;
 pub use journal_entry_search_query_service_impl::JournalEntrySearchQueryServiceImpl;
 pub use master_data_loader_impl::MasterDataLoaderImpl;
+pub use period_reopen_query_service_impl::PeriodReopenQueryServiceImpl;
+pub use projection_cache::ProjectionCache;
+pub use projection_consistency_query_service_impl::ProjectionConsistencyQueryServiceImpl;
+pub use projection_inspection_query_service_impl::ProjectionInspectionQueryServiceImpl;
+pub use projection_position_query_service_impl::ProjectionPositionQueryServiceImpl;
+pub use sequence_audit_query_service_impl::SequenceAuditQueryServiceImpl;
+pub use suspense_aging_query_service_impl::SuspenseAgingQueryServiceImpl;
@@ -0,0 +1,285 @@
+// AuditExportQueryServiceImpl - 監査用帳簿出力の照会サービス実装
+// イベントストアから仕訳ごとの起票・更新・承認申請・記帳の履歴を復元する
+
+use std::{collections::HashMap, sync::Arc};
+
+use javelin_application::{
+    error::{ApplicationError, ApplicationResult},
+    query_service::{
+        AccountMaster, AuditExportQueryService, AuditJournalEntry, AuditJournalLine,
+        MasterDataLoaderService,
+    },
+};
+use javelin_domain::financial_close::journal_entry::events::{
+    JournalEntryEvent, JournalEntryLineDto,
+};
+
+use crate::{EventStore, queries::MasterDataLoaderImpl};
+
+/// 記帳前の状態も含めた仕訳の履歴
+struct EntryHistory {
+    entry: AuditJournalEntry,
+    /// 最新の明細（出力時に伝票の摘要を引き継いで変換する）
+    lines: Vec<JournalEntryLineDto>,
+    /// 伝票の摘要
+    description: Option<String>,
+    posted: bool,
+}
+
+impl EntryHistory {
+    /// 明細に伝票の摘要を引き継いで出力用の仕訳を作成
+    fn into_entry(self) -> AuditJournalEntry {
+        let description = self.description.as_deref();
+        AuditJournalEntry {
+            lines: self.lines.iter().map(|line| to_audit_line(line, description)).collect(),
+            ..self.entry
+        }
+    }
+}
+
+/// AuditExportQueryService実装
+///
+/// 仕訳一覧Projectionは利用者・日時の履歴を保持しないため、イベントストアを直接走査する。
+/// 一度も記帳されていない仕訳（下書き・承認待ち・削除済）は出力しない。
+pub struct AuditExportQueryServiceImpl {
+    event_store: Arc<EventStore>,
+    master_data_loader: Arc<MasterDataLoaderImpl>,
+}
+
+impl AuditExportQueryServiceImpl {
+    /// 新しいインスタンスを作成
+    pub fn new(
+        event_store: Arc<EventStore>,
+        master_data_loader: Arc<MasterDataLoaderImpl>,
+    ) -> Self {
+        Self { event_store, master_data_loader }
+    }
+}
+
+impl AuditExportQueryService for AuditExportQueryServiceImpl {
+    async fn load_posted_entries(&self) -> ApplicationResult<Vec<AuditJournalEntry>> {
+        let events = self
+            .event_store
+            .get_all_events(0)
+            .await
+            .map_err(|e| ApplicationError::QueryExecutionFailed(e.to_string()))?;
+
+        let mut histories: HashMap<String, EntryHistory> = HashMap::new();
+        let mut order: Vec<String> = Vec::new();
+
+        for stored_event in events.iter() {
+            let Ok(event) = serde_json::from_slice::<JournalEntryEvent>(&stored_event.payload)
+            else {
+                continue;
+            };
+
+            if let JournalEntryEvent::DraftCreated {
+                entry_id,
+                transaction_date,
+                voucher_number,
+                lines,
+                description,
+                created_by,
+                created_at,
+            } = &event
+            {
+                order.push(entry_id.clone());
+                histories.insert(
+                    entry_id.clone(),
+                    EntryHistory {
+                        entry: AuditJournalEntry {
+                            entry_id: entry_id.clone(),
+                            transaction_date: transaction_date.clone(),
+                            entry_number: String::new(),
+                            voucher_number: Some(voucher_number.clone()),
+                            status: "Draft".to_string(),
+                            created_by: created_by.clone(),
+                            created_at: *created_at,
+                            updated: None,
+                            approval_requested: None,
+                            posted_by: String::new(),
+                            posted_at: *created_at,
+                            lines: Vec::new(),
+                        },
+                        lines: lines.clone(),
+                        description: description.clone(),
+                        posted: false,
+                    },
+                );
+                continue;
+            }
+
+            let Some(history) = histories.get_mut(event.aggregate_id()) else {
+                continue;
+            };
+            let entry = &mut history.entry;
+            match event {
+                JournalEntryEvent::DraftUpdated {
+                    transaction_date,
+                    voucher_number,
+                    lines,
+                    description,
+                    updated_by,
+                    updated_at,
+                    ..
+                } => {
+                    if let Some(transaction_date) = transaction_date {
+                        entry.transaction_date = transaction_date;
+                    }
+                    if let Some(voucher_number) = voucher_number {
+                        entry.voucher_number = Some(voucher_number);
+                    }
+                    if let Some(lines) = lines {
+                        history.lines = lines;
+                    }
+                    if let Some(description) = description {
+                        history.description = Some(description).filter(|d| !d.is_empty());
+                    }
+                    entry.updated = Some((updated_by, updated_at));
+                }
+                JournalEntryEvent::ApprovalRequested { requested_by, requested_at, .. } => {
+                    entry.approval_requested = Some((requested_by, requested_at));
+                }
+                JournalEntryEvent::Posted { entry_number, posted_by, posted_at, .. } => {
+                    entry.entry_number = entry_number;
+                    entry.posted_by = posted_by;
+                    entry.posted_at = posted_at;
+                    entry.status = "Posted".to_string();
+                    history.posted = true;
+                }
+                JournalEntryEvent::Reversed { .. } => entry.status = "Reversed".to_string(),
+                JournalEntryEvent::Corrected { .. } => entry.status = "Corrected".to_string(),
+                JournalEntryEvent::Closed { .. } => entry.status = "Closed".to_string(),
+                JournalEntryEvent::Reopened { .. } => entry.status = "Posted".to_string(),
+                JournalEntryEvent::DraftCreated { .. }
+                | JournalEntryEvent::Rejected { .. }
+                | JournalEntryEvent::Deleted { .. }
+                | JournalEntryEvent::LineAnnotated { .. }
+                | JournalEntryEvent::AnnotationResolved { .. }
+                | JournalEntryEvent::EntryLinked { .. }
+                | JournalEntryEvent::EntryUnlinked { .. } => {}
+            }
+        }
+
+        Ok(order
+            .into_iter()
+            .filter_map(|entry_id| histories.remove(&entry_id))
+            .filter(|history| history.posted)
+            .map(EntryHistory::into_entry)
+            .collect())
+    }
+
+    async fn load_accounts(&self) -> ApplicationResult<Vec<AccountMaster>> {
+        Ok(self.master_data_loader.load_master_data().await?.accounts)
+    }
+}
+
+fn to_audit_line(line: &JournalEntryLineDto, description: Option<&str>) -> AuditJournalLine {
+    AuditJournalLine {
+        line_number: line.line_number,
+        side: line.side.clone(),
+        account_code: line.account_code.clone(),
+        sub_account_code: line.sub_account_code.clone(),
+        department_code: line.department_code.clone(),
+        amount: line.amount,
+        currency: line.currency.clone(),
+        tax_type: line.tax_type.clone(),
+        tax_amount: line.tax_amount,
+        description: line.effective_description(description).map(str::to_string),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use tempfile::TempDir;
+
+    use super::*;
+
+    fn line(line_number: u32, side: &str, account_code: &str) -> JournalEntryLineDto {
+        JournalEntryLineDto {
+            line_number,
+            side: side.to_string(),
+            account_code: account_code.to_string(),
+            account_name: None,
+            sub_account_code: None,
+            department_code: None,
+            dimensions: Default::default(),
+            amount: 1000.0,
+            currency: "JPY".to_string(),
+            tax_type: "NonTaxable".to_string(),
+            tax_amount: 0.0,
+            description: None,
+        }
+    }
+
+    fn draft(entry_id: &str) -> JournalEntryEvent {
+        JournalEntryEvent::DraftCreated {
+            entry_id: entry_id.to_string(),
+            transaction_date: "2024-04-01".to_string(),
+            voucher_number: format!("V-{}", entry_id),
+            lines: vec![line(1, "Debit", "1100"), line(2, "Credit", "4000")],
+            description: None,
+            created_by: "clerk".to_string(),
+            created_at: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_only_posted_entries_are_loaded_with_their_history() {
+        let temp_dir = TempDir::new().unwrap();
+        let event_store = Arc::new(EventStore::new(&temp_dir.path().join("events")).await.unwrap());
+        let master_data_loader = Arc::new(
+            MasterDataLoaderImpl::new(&temp_dir.path().join("master_data")).await.unwrap(),
+        );
+
+        event_store
+            .append(
+                "je-1",
+                vec![
+                    draft("je-1"),
+                    JournalEntryEvent::DraftUpdated {
+                        entry_id: "je-1".to_string(),
+                        transaction_date: Some("2024-04-02".to_string()),
+                        voucher_number: None,
+                        lines: None,
+                        description: None,
+                        updated_by: "editor".to_string(),
+                        updated_at: Utc::now(),
+                    },
+                    JournalEntryEvent::ApprovalRequested {
+                        entry_id: "je-1".to_string(),
+                        requested_by: "clerk".to_string(),
+                        requested_at: Utc::now(),
+                    },
+                    JournalEntryEvent::Posted {
+                        entry_id: "je-1".to_string(),
+                        entry_number: "EN-0001-2024-000001".to_string(),
+                        posted_by: "manager".to_string(),
+                        posted_at: Utc::now(),
+                    },
+                    JournalEntryEvent::Closed {
+                        entry_id: "je-1".to_string(),
+                        closed_by: "manager".to_string(),
+                        closed_at: Utc::now(),
+                    },
+                ],
+            )
+            .await
+            .unwrap();
+        event_store.append("je-2", vec![draft("je-2")]).await.unwrap();
+
+        let service = AuditExportQueryServiceImpl::new(event_store, master_data_loader);
+        let entries = service.load_posted_entries().await.unwrap();
+
+        assert_eq!(entries.len(), 1);
+        let entry = &entries[0];
+        assert_eq!(entry.transaction_date, "2024-04-02");
+        assert_eq!(entry.entry_number, "EN-0001-2024-000001");
+        assert_eq!(entry.status, "Closed");
+        assert_eq!(entry.updated.as_ref().unwrap().0, "editor");
+        assert_eq!(entry.approval_requested.as_ref().unwrap().0, "clerk");
+        assert_eq!(entry.posted_by, "manager");
+        assert_eq!(entry.lines.len(), 2);
+    }
+}
@@ -0,0 +1,48 @@
+// EventChecksumQueryServiceImpl - イベントのチェックサム検証用照会サービス実装
+// 全イベントを読み込んで破損イベントを隔離し、隔離中のイベントを破損として報告する
+
+use std::sync::Arc;
+
+use javelin_application::{
+    error::{ApplicationError, ApplicationResult},
+    query_service::{CorruptedEvent, EventChecksumQueryService, EventChecksumReport},
+};
+
+use crate::EventStore;
+
+/// EventChecksumQueryService実装
+///
+/// イベントストアは読み込み時にチェックサムを検証し、一致しないイベントを隔離する。
+/// 以前の読み込みで隔離され、まだ修復・破棄されていないイベントも破損として扱う。
+pub struct EventChecksumQueryServiceImpl {
+    event_store: Arc<EventStore>,
+}
+
+impl EventChecksumQueryServiceImpl {
+    /// 新しいインスタンスを作成
+    pub fn new(event_store: Arc<EventStore>) -> Self {
+        Self { event_store }
+    }
+}
+
+impl EventChecksumQueryService for EventChecksumQueryServiceImpl {
+    async fn verify_checksums(&self) -> ApplicationResult<EventChecksumReport> {
+        let to_error = |e: crate::error::InfrastructureError| {
+            ApplicationError::ProjectionDatabaseError(e.to_string())
+        };
+        let verified_events = self.event_store.get_all_events(0).await.map_err(to_error)?.len();
+        let corrupted_events = self
+            .event_store
+            .get_quarantined_events()
+            .await
+            .map_err(to_error)?
+            .into_iter()
+            .map(|quarantined| CorruptedEvent {
+                global_sequence: quarantined.global_sequence,
+                error: quarantined.error,
+            })
+            .collect();
+
+        Ok(EventChecksumReport { verified_events, corrupted_events })
+    }
+}
@@ -0,0 +1,272 @@
+// 受信箱Projection
+// 承認申請・差戻しイベントから利用者ごとの対応待ち項目を構築
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use javelin_domain::financial_close::journal_entry::events::{
+    JournalEntryEvent, JournalEntryLineDto,
+};
+
+use crate::{error::InfrastructureResult, projection_trait::Apply};
+
+/// 受信箱項目の状態
+#[derive(Debug, Clone, PartialEq)]
+pub enum InboxState {
+    /// 承認待ち
+    AwaitingApproval { requested_by: String, requested_at: DateTime<Utc> },
+    /// 差戻し
+    Rejected { rejected_by: String, reason: String, rejected_at: DateTime<Utc> },
+}
+
+/// 受信箱ReadModel
+#[derive(Debug, Clone, PartialEq)]
+pub struct InboxItemReadModel {
+    pub entry_id: String,
+    pub voucher_number: String,
+    pub transaction_date: String,
+    pub created_by: String,
+    pub lines: Vec<JournalEntryLineDto>,
+    pub state: InboxState,
+}
+
+impl InboxItemReadModel {
+    /// 状態が変化した日時
+    pub fn occurred_at(&self) -> DateTime<Utc> {
+        match &self.state {
+            InboxState::AwaitingApproval { requested_at, .. } => *requested_at,
+            InboxState::Rejected { rejected_at, .. } => *rejected_at,
+        }
+    }
+
+    /// 指定利用者の受信箱に表示するか
+    ///
+    /// 承認待ちはすべての利用者に、差戻しは下書きの作成者にのみ表示する。
+    pub fn is_visible_to(&self, user_id: &str) -> bool {
+        match &self.state {
+            InboxState::AwaitingApproval { .. } => true,
+            InboxState::Rejected { .. } => self.created_by == user_id,
+        }
+    }
+}
+
+/// 下書きの内容（受信箱項目の表示用）
+#[derive(Debug, Clone)]
+struct DraftSummary {
+    voucher_number: String,
+    transaction_date: String,
+    created_by: String,
+    lines: Vec<JournalEntryLineDto>,
+}
+
+/// 受信箱Projection
+///
+/// ApprovalRequested / Rejected イベントで項目を追加・更新し、
+/// 記帳・削除された仕訳は対応不要として取り除く。
+#[derive(Debug, Clone, Default)]
+pub struct InboxProjection {
+    drafts: HashMap<String, DraftSummary>,
+    items: HashMap<String, InboxItemReadModel>,
+}
+
+impl InboxProjection {
+    /// 新しいProjectionインスタンスを作成
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 利用者の受信箱項目を新しい順に取得
+    pub fn items_for(&self, user_id: &str) -> Vec<InboxItemReadModel> {
+        let mut items: Vec<_> = self
+            .items
+            .values()
+            .filter(|item| item.is_visible_to(user_id))
+            .cloned()
+            .collect();
+        items.sort_by(|a, b| {
+            b.occurred_at().cmp(&a.occurred_at()).then_with(|| a.entry_id.cmp(&b.entry_id))
+        });
+        items
+    }
+
+    fn set_state(&mut self, entry_id: &str, state: InboxState) {
+        let Some(draft) = self.drafts.get(entry_id) else {
+            return;
+        };
+        self.items.insert(
+            entry_id.to_string(),
+            InboxItemReadModel {
+                entry_id: entry_id.to_string(),
+                voucher_number: draft.voucher_number.clone(),
+                transaction_date: draft.transaction_date.clone(),
+                created_by: draft.created_by.clone(),
+                lines: draft.lines.clone(),
+                state,
+            },
+        );
+    }
+}
+
+impl Apply<JournalEntryEvent> for InboxProjection {
+    fn apply(&mut self, event: JournalEntryEvent) -> InfrastructureResult<()> {
+        match event {
+            JournalEntryEvent::DraftCreated {
+                entry_id,
+                transaction_date,
+                voucher_number,
+                lines,
+                created_by,
+                ..
+            } => {
+                self.drafts.insert(
+                    entry_id,
+                    DraftSummary { voucher_number, transaction_date, created_by, lines },
+                );
+            }
+            JournalEntryEvent::DraftUpdated {
+                entry_id,
+                transaction_date,
+                voucher_number,
+                lines,
+                ..
+            } => {
+                if let Some(draft) = self.drafts.get_mut(&entry_id) {
+                    if let Some(transaction_date) = transaction_date {
+                        draft.transaction_date = transaction_date;
+                    }
+                    if let Some(voucher_number) = voucher_number {
+                        draft.voucher_number = voucher_number;
+                    }
+                    if let Some(lines) = lines {
+                        draft.lines = lines;
+                    }
+                }
+                // 差戻し後の修正中は差戻し項目を残す
+                if let Some(item) = self.items.get_mut(&entry_id)
+                    && let Some(draft) = self.drafts.get(&entry_id)
+                {
+                    item.voucher_number = draft.voucher_number.clone();
+                    item.transaction_date = draft.transaction_date.clone();
+                    item.lines = draft.lines.clone();
+                }
+            }
+            JournalEntryEvent::ApprovalRequested { entry_id, requested_by, requested_at } => {
+                self.set_state(
+                    &entry_id,
+                    InboxState::AwaitingApproval { requested_by, requested_at },
+                );
+            }
+            JournalEntryEvent::Rejected { entry_id, reason, rejected_by, rejected_at } => {
+                self.set_state(
+                    &entry_id,
+                    InboxState::Rejected { rejected_by, reason, rejected_at },
+                );
+            }
+            // 記帳・削除された仕訳は対応不要
+            JournalEntryEvent::Posted { entry_id, .. }
+            | JournalEntryEvent::Deleted { entry_id, .. } => {
+                self.items.remove(&entry_id);
+                self.drafts.remove(&entry_id);
+            }
+            _ => {
+                // その他のイベントは受信箱に影響しない
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn draft_created(entry_id: &str, created_by: &str) -> JournalEntryEvent {
+        JournalEntryEvent::DraftCreated {
+            entry_id: entry_id.to_string(),
+            transaction_date: "2024-01-01".to_string(),
+            voucher_number: format!("V-{}", entry_id),
+            lines: vec![],
+            description: None,
+            created_by: created_by.to_string(),
+            created_at: Utc::now(),
+        }
+    }
+
+    fn approval_requested(entry_id: &str, requested_by: &str) -> JournalEntryEvent {
+        JournalEntryEvent::ApprovalRequested {
+            entry_id: entry_id.to_string(),
+            requested_by: requested_by.to_string(),
+            requested_at: Utc::now(),
+        }
+    }
+
+    fn rejected(entry_id: &str) -> JournalEntryEvent {
+        JournalEntryEvent::Rejected {
+            entry_id: entry_id.to_string(),
+            reason: "金額誤り".to_string(),
+            rejected_by: "approver".to_string(),
+            rejected_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_approval_request_appears_for_all_users() {
+        let mut projection = InboxProjection::new();
+        projection.apply(draft_created("JE001", "alice")).unwrap();
+        assert!(projection.items_for("alice").is_empty());
+
+        projection.apply(approval_requested("JE001", "alice")).unwrap();
+        let items = projection.items_for("bob");
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].voucher_number, "V-JE001");
+        assert!(matches!(items[0].state, InboxState::AwaitingApproval { .. }));
+    }
+
+    #[test]
+    fn test_rejected_draft_appears_only_for_creator() {
+        let mut projection = InboxProjection::new();
+        projection.apply(draft_created("JE001", "alice")).unwrap();
+        projection.apply(approval_requested("JE001", "alice")).unwrap();
+        projection.apply(rejected("JE001")).unwrap();
+
+        assert!(projection.items_for("bob").is_empty());
+        let items = projection.items_for("alice");
+        assert_eq!(items.len(), 1);
+        assert!(
+            matches!(&items[0].state, InboxState::Rejected { reason, .. } if reason == "金額誤り")
+        );
+
+        // 再申請で承認待ちに戻る
+        projection.apply(approval_requested("JE001", "alice")).unwrap();
+        assert_eq!(projection.items_for("bob").len(), 1);
+    }
+
+    #[test]
+    fn test_posted_and_deleted_entries_are_removed() {
+        let mut projection = InboxProjection::new();
+        projection.apply(draft_created("JE001", "alice")).unwrap();
+        projection.apply(draft_created("JE002", "alice")).unwrap();
+        projection.apply(approval_requested("JE001", "alice")).unwrap();
+        projection.apply(approval_requested("JE002", "alice")).unwrap();
+        projection.apply(rejected("JE002")).unwrap();
+
+        projection
+            .apply(JournalEntryEvent::Posted {
+                entry_id: "JE001".to_string(),
+                entry_number: "EN-001".to_string(),
+                posted_by: "approver".to_string(),
+                posted_at: Utc::now(),
+            })
+            .unwrap();
+        projection
+            .apply(JournalEntryEvent::Deleted {
+                entry_id: "JE002".to_string(),
+                deleted_by: "alice".to_string(),
+                deleted_at: Utc::now(),
+            })
+            .unwrap();
+
+        assert!(projection.items_for("alice").is_empty());
+    }
+}
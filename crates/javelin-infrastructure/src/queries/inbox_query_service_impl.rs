@@ -0,0 +1,92 @@
+// InboxQueryServiceImpl - 受信箱照会サービス実装（Infrastructure層）
+// InboxProjectionから利用者ごとの対応待ち項目を取得
+
+use std::sync::Arc;
+
+use javelin_application::{
+    dtos::response::JournalActionAffordance,
+    error::{ApplicationError, ApplicationResult},
+    query_service::{InboxItem, InboxItemKind, InboxItemLine, InboxQueryService},
+};
+use javelin_domain::financial_close::journal_entry::events::JournalEntryEvent;
+
+use crate::{
+    EventStore,
+    projection_trait::Apply,
+    queries::inbox_projection::{InboxItemReadModel, InboxProjection, InboxState},
+};
+
+/// InboxQueryService実装
+///
+/// EventStoreからイベントを取得してInboxProjectionを構築する。
+pub struct InboxQueryServiceImpl {
+    event_store: Arc<EventStore>,
+}
+
+impl InboxQueryServiceImpl {
+    /// 新しいインスタンスを作成
+    pub fn new(event_store: Arc<EventStore>) -> Self {
+        Self { event_store }
+    }
+
+    /// イベントストリームからInboxProjectionを構築
+    async fn build_inbox_projection(&self) -> ApplicationResult<InboxProjection> {
+        let mut projection = InboxProjection::new();
+
+        let events = self
+            .event_store
+            .get_all_events(0)
+            .await
+            .map_err(|e| ApplicationError::ProjectionDatabaseError(e.to_string()))?;
+
+        for stored_event in events.iter() {
+            if let Ok(event) = serde_json::from_slice::<JournalEntryEvent>(&stored_event.payload) {
+                projection
+                    .apply(event)
+                    .map_err(|e| ApplicationError::ProjectionDatabaseError(e.to_string()))?;
+            }
+        }
+
+        Ok(projection)
+    }
+
+    fn to_inbox_item(item: InboxItemReadModel) -> InboxItem {
+        let occurred_at = item.occurred_at().to_rfc3339();
+        let (kind, from_user, reason) = match item.state {
+            InboxState::AwaitingApproval { requested_by, .. } => {
+                (InboxItemKind::AwaitingApproval, requested_by, None)
+            }
+            InboxState::Rejected { rejected_by, reason, .. } => {
+                (InboxItemKind::Rejected, rejected_by, Some(reason))
+            }
+        };
+
+        InboxItem {
+            kind,
+            entry_id: item.entry_id,
+            voucher_number: item.voucher_number,
+            transaction_date: item.transaction_date,
+            from_user,
+            reason,
+            occurred_at,
+            lines: item
+                .lines
+                .into_iter()
+                .map(|line| InboxItemLine {
+                    account_code: line.account_code,
+                    side: line.side,
+                    amount: line.amount,
+                })
+                .collect(),
+            actions: JournalActionAffordance::for_status(kind.status()),
+        }
+    }
+}
+
+impl InboxQueryService for InboxQueryServiceImpl {
+    async fn get_inbox(&self, user_id: &str) -> ApplicationResult<Vec<InboxItem>> {
+        let projection = self.build_inbox_projection().await?;
+
+        Ok(projection.items_for(user_id).into_iter().map(Self::to_inbox_item).collect())
+    }
+}
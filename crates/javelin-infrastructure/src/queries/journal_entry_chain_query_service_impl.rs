@@ -0,0 +1,104 @@
+// JournalEntryChainQueryServiceImpl - 取消・修正チェーン照会サービス実装
+// ProjectionBuilderが取消・修正イベントから記録したリンクを参照する
+
+use std::sync::Arc;
+
+use javelin_application::{
+    error::{ApplicationError, ApplicationResult},
+    query_service::{JournalEntryChainLinks, JournalEntryChainQueryService},
+};
+
+use crate::ProjectionDb;
+
+/// 取消・修正チェーンのリンクのキー
+pub fn journal_entry_chain_key(entry_id: &str) -> String {
+    format!("journal_entry_chain:{}", entry_id)
+}
+
+/// JournalEntryChainQueryService実装
+pub struct JournalEntryChainQueryServiceImpl {
+    projection_db: Arc<ProjectionDb>,
+}
+
+impl JournalEntryChainQueryServiceImpl {
+    /// 新しいインスタンスを作成
+    pub fn new(projection_db: Arc<ProjectionDb>) -> Self {
+        Self { projection_db }
+    }
+}
+
+impl JournalEntryChainQueryService for JournalEntryChainQueryServiceImpl {
+    async fn find_links(&self, entry_id: &str) -> ApplicationResult<JournalEntryChainLinks> {
+        let Some(data) = self
+            .projection_db
+            .get_projection(&journal_entry_chain_key(entry_id))
+            .await
+            .map_err(|e| ApplicationError::ProjectionDatabaseError(e.to_string()))?
+        else {
+            return Ok(JournalEntryChainLinks::default());
+        };
+
+        serde_json::from_slice(&data)
+            .map_err(|e| ApplicationError::ProjectionDatabaseError(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use javelin_application::projection_builder::ProjectionBuilder;
+    use javelin_domain::financial_close::journal_entry::events::JournalEntryEvent;
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::{EventStore, projection_builder_impl::ProjectionBuilderImpl};
+
+    #[tokio::test]
+    async fn test_links_are_built_from_reversal_and_correction_events() {
+        let temp_dir = TempDir::new().unwrap();
+        let event_store = Arc::new(EventStore::new(&temp_dir.path().join("events")).await.unwrap());
+        let projection_db =
+            Arc::new(ProjectionDb::new(&temp_dir.path().join("projections")).await.unwrap());
+
+        event_store
+            .append(
+                "REV-JE001",
+                vec![JournalEntryEvent::Reversed {
+                    entry_id: "REV-JE001".to_string(),
+                    original_id: "JE001".to_string(),
+                    reason: "金額誤り".to_string(),
+                    reversed_by: "manager".to_string(),
+                    reversed_at: Utc::now(),
+                }],
+            )
+            .await
+            .unwrap();
+        event_store
+            .append(
+                "COR-REV-JE001",
+                vec![JournalEntryEvent::Corrected {
+                    entry_id: "COR-REV-JE001".to_string(),
+                    reversed_id: "REV-JE001".to_string(),
+                    reason: "金額誤り".to_string(),
+                    corrected_by: "manager".to_string(),
+                    corrected_at: Utc::now(),
+                }],
+            )
+            .await
+            .unwrap();
+        ProjectionBuilderImpl::new(Arc::clone(&projection_db), event_store)
+            .rebuild_all_projections()
+            .await
+            .unwrap();
+
+        let service = JournalEntryChainQueryServiceImpl::new(projection_db);
+        assert_eq!(
+            service.find_links("JE001").await.unwrap().reversal_id.as_deref(),
+            Some("REV-JE001")
+        );
+        let reversal = service.find_links("REV-JE001").await.unwrap();
+        assert_eq!(reversal.original_id.as_deref(), Some("JE001"));
+        assert_eq!(reversal.correction_id.as_deref(), Some("COR-REV-JE001"));
+        assert_eq!(service.find_links("JE002").await.unwrap(), JournalEntryChainLinks::default());
+    }
+}
@@ -6,6 +6,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     error::InfrastructureResult,
+    event_envelope::EventEnvelope,
     event_stream::StoredEvent,
     projection_trait::{Apply, ProjectionStrategy, ToReadModel},
 };
@@ -18,6 +19,9 @@ pub struct JournalEntryReadModel {
     pub status: String,
     pub transaction_date: String,
     pub voucher_number: String,
+    /// 伝票の摘要
+    #[serde(default)]
+    pub description: Option<String>,
     pub total_debit: f64,
     pub total_credit: f64,
     pub created_by: String,
@@ -37,6 +41,7 @@ pub struct JournalEntryProjection {
     status: String,
     transaction_date: String,
     voucher_number: String,
+    description: Option<String>,
     total_debit: f64,
     total_credit: f64,
     created_by: String,
@@ -54,6 +59,7 @@ impl JournalEntryProjection {
             status: "Draft".to_string(),
             transaction_date: String::new(),
             voucher_number: String::new(),
+            description: None,
             total_debit: 0.0,
             total_credit: 0.0,
             created_by: String::new(),
@@ -90,6 +96,7 @@ impl Apply<JournalEntryEvent> for JournalEntryProjection {
                 transaction_date,
                 voucher_number,
                 lines,
+                description,
                 created_by,
                 created_at,
             } => {
@@ -97,6 +104,7 @@ impl Apply<JournalEntryEvent> for JournalEntryProjection {
                 self.status = "Draft".to_string();
                 self.transaction_date = transaction_date;
                 self.voucher_number = voucher_number;
+                self.description = description;
                 let (debit, credit) = Self::calculate_totals(&lines);
                 self.total_debit = debit;
                 self.total_credit = credit;
@@ -107,10 +115,14 @@ impl Apply<JournalEntryEvent> for JournalEntryProjection {
                 transaction_date,
                 voucher_number,
                 lines,
+                description,
                 updated_by,
                 updated_at,
                 ..
             } => {
+                if let Some(description) = description {
+                    self.description = Some(description).filter(|d| !d.is_empty());
+                }
                 if let Some(date) = transaction_date {
                     self.transaction_date = date;
                 }
@@ -150,6 +162,11 @@ impl Apply<JournalEntryEvent> for JournalEntryProjection {
             JournalEntryEvent::Deleted { .. } => {
                 self.status = "Deleted".to_string();
             }
+            // 注記・仕訳間の紐付けは仕訳の状態を変えない
+            JournalEntryEvent::LineAnnotated { .. }
+            | JournalEntryEvent::AnnotationResolved { .. }
+            | JournalEntryEvent::EntryLinked { .. }
+            | JournalEntryEvent::EntryUnlinked { .. } => {}
         }
 
         Ok(())
@@ -166,6 +183,7 @@ impl ToReadModel for JournalEntryProjection {
             status: self.status.clone(),
             transaction_date: self.transaction_date.clone(),
             voucher_number: self.voucher_number.clone(),
+            description: self.description.clone(),
             total_debit: self.total_debit,
             total_credit: self.total_credit,
             created_by: self.created_by.clone(),
@@ -181,15 +199,28 @@ pub struct JournalEntryProjectionStrategy;
 
 impl ProjectionStrategy for JournalEntryProjectionStrategy {
     fn should_update(&self, event: &StoredEvent) -> bool {
-        event.event_type.starts_with("Draft")
-            || event.event_type.starts_with("Approval")
-            || event.event_type == "Rejected"
-            || event.event_type == "Posted"
-            || event.event_type == "Reversed"
-            || event.event_type == "Corrected"
-            || event.event_type == "Closed"
-            || event.event_type == "Reopened"
-            || event.event_type == "Deleted"
+        match event.envelope() {
+            EventEnvelope::JournalEntry(event) => match event {
+                JournalEntryEvent::DraftCreated { .. }
+                | JournalEntryEvent::DraftUpdated { .. }
+                | JournalEntryEvent::ApprovalRequested { .. }
+                | JournalEntryEvent::Rejected { .. }
+                | JournalEntryEvent::Posted { .. }
+                | JournalEntryEvent::Reversed { .. }
+                | JournalEntryEvent::Corrected { .. }
+                | JournalEntryEvent::Closed { .. }
+                | JournalEntryEvent::Reopened { .. }
+                | JournalEntryEvent::Deleted { .. } => true,
+                // 注記・仕訳間の紐付けは仕訳の状態を変えない
+                JournalEntryEvent::LineAnnotated { .. }
+                | JournalEntryEvent::AnnotationResolved { .. }
+                | JournalEntryEvent::EntryLinked { .. }
+                | JournalEntryEvent::EntryUnlinked { .. } => false,
+            },
+            // 復元できない仕訳伝票のイベントは処理側でエラーにする
+            EventEnvelope::Undecodable { .. } => true,
+            EventEnvelope::Unknown { .. } => false,
+        }
     }
 
     fn batch_size(&self) -> usize {
@@ -212,6 +243,7 @@ mod tests {
             transaction_date: "2024-01-01".to_string(),
             voucher_number: "V001".to_string(),
             lines: vec![],
+            description: None,
             created_by: "user1".to_string(),
             created_at: Utc::now(),
         };
@@ -234,6 +266,7 @@ mod tests {
             transaction_date: "2024-01-01".to_string(),
             voucher_number: "V002".to_string(),
             lines: vec![],
+            description: None,
             created_by: "user1".to_string(),
             created_at: Utc::now(),
         };
@@ -270,8 +303,10 @@ mod tests {
                 line_number: 1,
                 side: "Debit".to_string(),
                 account_code: "1000".to_string(),
+                account_name: None,
                 sub_account_code: None,
                 department_code: None,
+                dimensions: Default::default(),
                 amount: 100000.0,
                 currency: "JPY".to_string(),
                 tax_type: "NonTaxable".to_string(),
@@ -282,8 +317,10 @@ mod tests {
                 line_number: 2,
                 side: "Credit".to_string(),
                 account_code: "2000".to_string(),
+                account_name: None,
                 sub_account_code: None,
                 department_code: None,
+                dimensions: Default::default(),
                 amount: 100000.0,
                 currency: "JPY".to_string(),
                 tax_type: "NonTaxable".to_string(),
@@ -7,7 +7,8 @@ use javelin_domain::financial_close::journal_entry::events::JournalEntryEvent;
 use tokio::time::{Duration, interval};
 
 use crate::{
-    error::InfrastructureResult,
+    error::{InfrastructureError, InfrastructureResult},
+    event_envelope::EventEnvelope,
     event_store::EventStore,
     projection_db::ProjectionDb,
     projection_trait::ProjectionStrategy,
@@ -71,11 +72,17 @@ impl JournalEntryProjectionWorker {
                 continue;
             }
 
-            // イベントをデシリアライズ
-            let journal_event: JournalEntryEvent =
-                serde_json::from_slice(&event.payload).map_err(|e| {
-                    crate::error::InfrastructureError::DeserializationFailed(e.to_string())
-                })?;
+            // 仕訳伝票のイベントとして復元（復元できない場合は反映を中断する）
+            let journal_event = match event.envelope() {
+                EventEnvelope::JournalEntry(journal_event) => journal_event,
+                EventEnvelope::Undecodable { event_type, error } => {
+                    return Err(InfrastructureError::DeserializationFailed(format!(
+                        "イベント（seq: {}, 種別: {}）を復元できません: {}",
+                        event.global_sequence, event_type, error
+                    )));
+                }
+                EventEnvelope::Unknown { .. } => continue,
+            };
 
             let entry_id = journal_event.aggregate_id().to_string();
 
@@ -195,6 +202,7 @@ mod tests {
             transaction_date: "2024-01-01".to_string(),
             voucher_number: "V001".to_string(),
             lines: vec![],
+            description: None,
             created_by: "user1".to_string(),
             created_at: Utc::now(),
         };
@@ -235,12 +243,13 @@ mod tests {
         let entry_id = "JE002";
 
         // 複数イベント追加
-        let events = vec![
+        let events = [
             JournalEntryEvent::DraftCreated {
                 entry_id: entry_id.to_string(),
                 transaction_date: "2024-01-01".to_string(),
                 voucher_number: "V002".to_string(),
                 lines: vec![],
+                description: None,
                 created_by: "user1".to_string(),
                 created_at: Utc::now(),
             },
@@ -1,7 +1,11 @@
 // 仕訳検索用Projection
 // JournalEntryEventから検索用ReadModelを構築
 
-use javelin_domain::financial_close::journal_entry::events::JournalEntryEvent;
+use std::collections::HashMap;
+
+use javelin_domain::financial_close::journal_entry::events::{
+    JournalEntryEvent, JournalEntryLineDto,
+};
 
 use crate::{
     error::InfrastructureResult,
@@ -15,15 +19,45 @@ use crate::{
 ///
 /// JournalEntryEventを受け取り、検索最適化されたReadModelを構築する。
 /// 取引日付、勘定科目、摘要などでインデックス化される。
+/// 伝票番号・記帳番号はエントリー位置への索引を持ち、番号指定の検索を全件走査せずに行う。
 #[derive(Debug, Clone)]
 pub struct JournalEntrySearchProjection {
     entries: Vec<JournalEntrySearchReadModel>,
+    /// 伝票番号 → エントリー位置（下書きの更新で同じ番号が付くことがあるため複数保持）
+    voucher_index: HashMap<String, Vec<usize>>,
+    /// 記帳番号 → エントリー位置
+    entry_number_index: HashMap<String, usize>,
+    /// 未解決の注記 → (仕訳ID, 明細行番号)
+    open_annotations: HashMap<String, (String, u32)>,
 }
 
 impl JournalEntrySearchProjection {
     /// 新しいProjectionインスタンスを作成
     pub fn new() -> Self {
-        Self { entries: Vec::new() }
+        Self {
+            entries: Vec::new(),
+            voucher_index: HashMap::new(),
+            entry_number_index: HashMap::new(),
+            open_annotations: HashMap::new(),
+        }
+    }
+
+    /// 伝票番号でエントリーを検索
+    pub fn find_by_voucher_number(
+        &self,
+        voucher_number: &str,
+    ) -> Vec<&JournalEntrySearchReadModel> {
+        self.voucher_index
+            .get(voucher_number.trim())
+            .map(|positions| positions.iter().map(|&position| &self.entries[position]).collect())
+            .unwrap_or_default()
+    }
+
+    /// 記帳番号でエントリーを検索
+    pub fn find_by_entry_number(&self, entry_number: &str) -> Option<&JournalEntrySearchReadModel> {
+        self.entry_number_index
+            .get(entry_number.trim())
+            .map(|&position| &self.entries[position])
     }
 
     /// エントリーリストを取得
@@ -56,10 +90,47 @@ impl JournalEntrySearchProjection {
         }
     }
 
+    /// 明細の勘定科目名（イベントに埋め込まれた名称を優先）
+    fn line_account_name(&self, line: &JournalEntryLineDto) -> String {
+        line.account_name
+            .clone()
+            .filter(|name| !name.is_empty())
+            .unwrap_or_else(|| self.get_account_name(&line.account_code))
+    }
+
     /// エントリーを検索
     fn find_entry_mut(&mut self, entry_id: &str) -> Option<&mut JournalEntrySearchReadModel> {
         self.entries.iter_mut().find(|e| e.entry_id == entry_id)
     }
+
+    /// 明細の未解決の注記数を増減
+    fn adjust_open_annotations(&mut self, entry_id: &str, line_number: u32, delta: i32) {
+        if let Some(line) = self
+            .find_entry_mut(entry_id)
+            .and_then(|entry| entry.lines.iter_mut().find(|l| l.line_number == line_number))
+        {
+            line.open_annotations = line.open_annotations.saturating_add_signed(delta);
+        }
+    }
+
+    /// 伝票番号の索引を張り替え
+    fn reindex_voucher_number(&mut self, entry_id: &str, voucher_number: String) {
+        let Some(position) = self.entries.iter().position(|e| e.entry_id == entry_id) else {
+            return;
+        };
+
+        if let Some(previous) = self.entries[position].voucher_number.take()
+            && let Some(positions) = self.voucher_index.get_mut(&previous)
+        {
+            positions.retain(|&p| p != position);
+            if positions.is_empty() {
+                self.voucher_index.remove(&previous);
+            }
+        }
+
+        self.voucher_index.entry(voucher_number.clone()).or_default().push(position);
+        self.entries[position].voucher_number = Some(voucher_number);
+    }
 }
 
 impl Default for JournalEntrySearchProjection {
@@ -71,12 +142,19 @@ impl Default for JournalEntrySearchProjection {
 impl Apply<JournalEntryEvent> for JournalEntrySearchProjection {
     fn apply(&mut self, event: JournalEntryEvent) -> InfrastructureResult<()> {
         match event {
-            JournalEntryEvent::DraftCreated { entry_id, transaction_date, lines, .. } => {
+            JournalEntryEvent::DraftCreated {
+                entry_id,
+                transaction_date,
+                voucher_number,
+                lines,
+                description,
+                ..
+            } => {
                 // 明細をReadModelに変換（アカウント名を先に収集）
                 let line_models: Vec<JournalEntryLineReadModel> = lines
                     .iter()
                     .map(|line| {
-                        let account_name = self.get_account_name(&line.account_code);
+                        let account_name = self.line_account_name(line);
                         JournalEntryLineReadModel::new(
                             line.line_number,
                             line.side.clone(),
@@ -85,28 +163,44 @@ impl Apply<JournalEntryEvent> for JournalEntrySearchProjection {
                             line.amount,
                             line.description.clone(),
                         )
+                        .with_dimensions(line.dimensions.clone())
                     })
                     .collect();
 
                 // 新しいエントリーを追加
-                let read_model = JournalEntrySearchReadModel::new(
+                let mut read_model = JournalEntrySearchReadModel::new(
                     entry_id,
-                    None, // 下書き時点では伝票番号なし
+                    None, // 下書き時点では記帳番号なし
                     transaction_date,
                     "Draft".to_string(),
                     line_models,
-                );
+                )
+                .with_description(description);
+                if !voucher_number.is_empty() {
+                    self.voucher_index
+                        .entry(voucher_number.clone())
+                        .or_default()
+                        .push(self.entries.len());
+                    read_model = read_model.with_voucher_number(voucher_number);
+                }
 
                 self.entries.push(read_model);
             }
 
-            JournalEntryEvent::DraftUpdated { entry_id, transaction_date, lines, .. } => {
+            JournalEntryEvent::DraftUpdated {
+                entry_id,
+                transaction_date,
+                voucher_number,
+                lines,
+                description,
+                ..
+            } => {
                 // 先にアカウント名を収集（不変借用）
                 let line_models_opt = lines.as_ref().map(|lines| {
                     lines
                         .iter()
                         .map(|line| {
-                            let account_name = self.get_account_name(&line.account_code);
+                            let account_name = self.line_account_name(line);
                             JournalEntryLineReadModel::new(
                                 line.line_number,
                                 line.side.clone(),
@@ -115,6 +209,7 @@ impl Apply<JournalEntryEvent> for JournalEntrySearchProjection {
                                 line.amount,
                                 line.description.clone(),
                             )
+                            .with_dimensions(line.dimensions.clone())
                         })
                         .collect::<Vec<_>>()
                 });
@@ -124,9 +219,24 @@ impl Apply<JournalEntryEvent> for JournalEntrySearchProjection {
                     if let Some(date) = transaction_date {
                         entry.transaction_date = date;
                     }
-                    if let Some(line_models) = line_models_opt {
+                    if let Some(mut line_models) = line_models_opt {
+                        // 注記は行番号に紐づくため、明細の更新後も引き継ぐ
+                        for line in &mut line_models {
+                            line.open_annotations = entry
+                                .lines
+                                .iter()
+                                .find(|l| l.line_number == line.line_number)
+                                .map_or(0, |l| l.open_annotations);
+                        }
                         entry.lines = line_models;
                     }
+                    if let Some(description) = description {
+                        entry.description = Some(description).filter(|d| !d.is_empty());
+                    }
+                }
+
+                if let Some(voucher_number) = voucher_number.filter(|v| !v.is_empty()) {
+                    self.reindex_voucher_number(&entry_id, voucher_number);
                 }
             }
 
@@ -143,9 +253,11 @@ impl Apply<JournalEntryEvent> for JournalEntrySearchProjection {
             }
 
             JournalEntryEvent::Posted { entry_id, entry_number, .. } => {
-                if let Some(entry) = self.find_entry_mut(&entry_id) {
+                if let Some(position) = self.entries.iter().position(|e| e.entry_id == entry_id) {
+                    let entry = &mut self.entries[position];
                     entry.status = "Posted".to_string();
-                    entry.entry_number = Some(entry_number);
+                    entry.entry_number = Some(entry_number.clone());
+                    self.entry_number_index.insert(entry_number, position);
                 }
             }
 
@@ -178,6 +290,21 @@ impl Apply<JournalEntryEvent> for JournalEntrySearchProjection {
                     entry.status = "Deleted".to_string();
                 }
             }
+
+            JournalEntryEvent::LineAnnotated { entry_id, annotation_id, line_number, .. } => {
+                self.adjust_open_annotations(&entry_id, line_number, 1);
+                self.open_annotations.insert(annotation_id, (entry_id, line_number));
+            }
+
+            JournalEntryEvent::AnnotationResolved { annotation_id, .. } => {
+                if let Some((entry_id, line_number)) = self.open_annotations.remove(&annotation_id)
+                {
+                    self.adjust_open_annotations(&entry_id, line_number, -1);
+                }
+            }
+
+            // 仕訳間の紐付けは検索結果に影響しない
+            JournalEntryEvent::EntryLinked { .. } | JournalEntryEvent::EntryUnlinked { .. } => {}
         }
 
         Ok(())
@@ -200,8 +327,10 @@ mod tests {
                 line_number: 1,
                 side: "Debit".to_string(),
                 account_code: "1000".to_string(),
+                account_name: None,
                 sub_account_code: None,
                 department_code: None,
+                dimensions: Default::default(),
                 amount: 100000.0,
                 currency: "JPY".to_string(),
                 tax_type: "NonTaxable".to_string(),
@@ -212,8 +341,10 @@ mod tests {
                 line_number: 2,
                 side: "Credit".to_string(),
                 account_code: "4000".to_string(),
+                account_name: None,
                 sub_account_code: None,
                 department_code: None,
+                dimensions: Default::default(),
                 amount: 100000.0,
                 currency: "JPY".to_string(),
                 tax_type: "NonTaxable".to_string(),
@@ -227,6 +358,7 @@ mod tests {
             transaction_date: "2024-01-01".to_string(),
             voucher_number: "V001".to_string(),
             lines,
+            description: None,
             created_by: "user1".to_string(),
             created_at: Utc::now(),
         };
@@ -255,6 +387,7 @@ mod tests {
             transaction_date: "2024-01-01".to_string(),
             voucher_number: "V002".to_string(),
             lines: vec![],
+            description: None,
             created_by: "user1".to_string(),
             created_at: Utc::now(),
         };
@@ -282,6 +415,50 @@ mod tests {
         assert_eq!(projection.entries()[0].entry_number, Some("EN-2024-001".to_string()));
     }
 
+    #[test]
+    fn test_voucher_and_entry_number_index() {
+        let mut projection = JournalEntrySearchProjection::new();
+
+        projection
+            .apply(JournalEntryEvent::DraftCreated {
+                entry_id: "JE006".to_string(),
+                transaction_date: "2024-01-01".to_string(),
+                voucher_number: "V006".to_string(),
+                lines: vec![],
+                description: None,
+                created_by: "user1".to_string(),
+                created_at: Utc::now(),
+            })
+            .unwrap();
+        assert_eq!(projection.find_by_voucher_number("V006")[0].entry_id, "JE006");
+
+        // 伝票番号の変更で索引を張り替える
+        projection
+            .apply(JournalEntryEvent::DraftUpdated {
+                entry_id: "JE006".to_string(),
+                transaction_date: None,
+                voucher_number: Some("V007".to_string()),
+                lines: None,
+                description: None,
+                updated_by: "user1".to_string(),
+                updated_at: Utc::now(),
+            })
+            .unwrap();
+        assert!(projection.find_by_voucher_number("V006").is_empty());
+        assert_eq!(projection.find_by_voucher_number(" V007 ")[0].entry_id, "JE006");
+
+        projection
+            .apply(JournalEntryEvent::Posted {
+                entry_id: "JE006".to_string(),
+                entry_number: "EN-2024-006".to_string(),
+                posted_by: "approver1".to_string(),
+                posted_at: Utc::now(),
+            })
+            .unwrap();
+        assert_eq!(projection.find_by_entry_number("EN-2024-006").unwrap().entry_id, "JE006");
+        assert!(projection.find_by_entry_number("EN-2024-999").is_none());
+    }
+
     #[test]
     fn test_draft_updated_projection() {
         let mut projection = JournalEntrySearchProjection::new();
@@ -292,6 +469,7 @@ mod tests {
             transaction_date: "2024-01-01".to_string(),
             voucher_number: "V003".to_string(),
             lines: vec![],
+            description: None,
             created_by: "user1".to_string(),
             created_at: Utc::now(),
         };
@@ -302,8 +480,10 @@ mod tests {
             line_number: 1,
             side: "Debit".to_string(),
             account_code: "1000".to_string(),
+            account_name: None,
             sub_account_code: None,
             department_code: None,
+            dimensions: Default::default(),
             amount: 50000.0,
             currency: "JPY".to_string(),
             tax_type: "NonTaxable".to_string(),
@@ -316,6 +496,7 @@ mod tests {
             transaction_date: Some("2024-01-02".to_string()),
             voucher_number: None,
             lines: Some(new_lines),
+            description: None,
             updated_by: "user1".to_string(),
             updated_at: Utc::now(),
         };
@@ -337,6 +518,7 @@ mod tests {
             transaction_date: "2024-01-01".to_string(),
             voucher_number: "V004".to_string(),
             lines: vec![],
+            description: None,
             created_by: "user1".to_string(),
             created_at: Utc::now(),
         };
@@ -383,6 +565,7 @@ mod tests {
             transaction_date: "2024-01-01".to_string(),
             voucher_number: "V005".to_string(),
             lines: vec![],
+            description: None,
             created_by: "user1".to_string(),
             created_at: Utc::now(),
         };
@@ -426,6 +609,7 @@ mod tests {
             transaction_date: "2024-01-01".to_string(),
             voucher_number: "V006".to_string(),
             lines: vec![],
+            description: None,
             created_by: "user1".to_string(),
             created_at: Utc::now(),
         };
@@ -440,4 +624,60 @@ mod tests {
         projection.apply(event2).unwrap();
         assert_eq!(projection.entries()[0].status, "Deleted");
     }
+
+    #[test]
+    fn test_line_annotations_flag_entry() {
+        let mut projection = JournalEntrySearchProjection::new();
+
+        let line = JournalEntryLineDto {
+            line_number: 1,
+            side: "Debit".to_string(),
+            account_code: "1000".to_string(),
+            account_name: None,
+            sub_account_code: None,
+            department_code: None,
+            dimensions: Default::default(),
+            amount: 5000.0,
+            currency: "JPY".to_string(),
+            tax_type: "NonTaxable".to_string(),
+            tax_amount: 0.0,
+            description: None,
+        };
+        projection
+            .apply(JournalEntryEvent::DraftCreated {
+                entry_id: "JE007".to_string(),
+                transaction_date: "2024-01-01".to_string(),
+                voucher_number: "V007".to_string(),
+                lines: vec![line],
+                description: None,
+                created_by: "user1".to_string(),
+                created_at: Utc::now(),
+            })
+            .unwrap();
+        assert!(!projection.entries()[0].is_flagged());
+
+        projection
+            .apply(JournalEntryEvent::LineAnnotated {
+                entry_id: "JE007".to_string(),
+                annotation_id: "N-1".to_string(),
+                line_number: 1,
+                note: "請求書を確認".to_string(),
+                annotated_by: "reviewer".to_string(),
+                annotated_at: Utc::now(),
+            })
+            .unwrap();
+        assert_eq!(projection.entries()[0].lines[0].open_annotations, 1);
+        assert!(projection.entries()[0].is_flagged());
+
+        projection
+            .apply(JournalEntryEvent::AnnotationResolved {
+                entry_id: "JE007".to_string(),
+                annotation_id: "N-1".to_string(),
+                resolution: "確認済".to_string(),
+                resolved_by: "user1".to_string(),
+                resolved_at: Utc::now(),
+            })
+            .unwrap();
+        assert!(!projection.entries()[0].is_flagged());
+    }
 }
@@ -1,22 +1,27 @@
 // JournalEntrySearchQueryServiceImpl - 仕訳検索サービス実装（Infrastructure層）
 // JournalEntrySearchProjectionから仕訳データを検索
 
-use std::sync::Arc;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    sync::Arc,
+};
 
 use javelin_application::{
     dtos::{
-        request::SearchCriteriaDto,
-        response::{JournalEntryItemDto, JournalEntryLineItemDto, JournalEntrySearchResultDto},
+        request::{DELETED_JOURNAL_ENTRY_STATUS, SearchCriteriaDto, SearchGrouping},
+        response::{
+            JournalEntryItemDto, JournalEntryLineItemDto, JournalEntrySearchGroupDto,
+            JournalEntrySearchResultDto,
+        },
     },
-    error::{ApplicationError, ApplicationResult},
-    query_service::JournalEntrySearchQueryService,
+    error::ApplicationResult,
+    query_service::{JournalEntrySearchQueryService, ProjectionWarmUp, WarmUpProgress},
 };
 
 use crate::{
     EventStore,
-    projection_trait::Apply,
     queries::{
-        journal_entry_search_projection::JournalEntrySearchProjection,
+        ProjectionCache, journal_entry_search_projection::JournalEntrySearchProjection,
         journal_entry_search_read_model::JournalEntrySearchReadModel,
     },
 };
@@ -24,41 +29,52 @@ use crate::{
 /// JournalEntrySearchQueryService実装
 ///
 /// EventStoreからイベントを取得してJournalEntrySearchProjectionを構築し、
-/// 検索条件に基づいて仕訳データを返す。
+/// 検索条件に基づいて仕訳データを返す。構築したProjectionはキャッシュし、以降は増分のみ適用する。
 pub struct JournalEntrySearchQueryServiceImpl {
     event_store: Arc<EventStore>,
+    projection_cache: ProjectionCache<JournalEntrySearchProjection>,
 }
 
 impl JournalEntrySearchQueryServiceImpl {
     /// 新しいインスタンスを作成
     pub fn new(event_store: Arc<EventStore>) -> Self {
-        Self { event_store }
+        Self {
+            event_store,
+            projection_cache: ProjectionCache::new(JournalEntrySearchProjection::new()),
+        }
     }
 
-    /// イベントストリームからJournalEntrySearchProjectionを構築
+    /// イベントストリームからJournalEntrySearchProjectionを構築（未適用のイベントのみ適用）
     async fn build_search_projection(&self) -> ApplicationResult<JournalEntrySearchProjection> {
-        use javelin_domain::financial_close::journal_entry::events::JournalEntryEvent;
-
-        let mut projection = JournalEntrySearchProjection::new();
+        self.projection_cache.refresh(&self.event_store).await
+    }
 
-        // 全イベントを取得（EventStoreから直接）
-        let events = self
-            .event_store
-            .get_all_events(0)
-            .await
-            .map_err(|e| ApplicationError::ProjectionDatabaseError(e.to_string()))?;
-
-        // イベントを適用
-        for stored_event in events.iter() {
-            // JournalEntryEventにデシリアライズ
-            if let Ok(event) = serde_json::from_slice::<JournalEntryEvent>(&stored_event.payload) {
-                projection
-                    .apply(event)
-                    .map_err(|e| ApplicationError::ProjectionDatabaseError(e.to_string()))?;
+    /// 検索対象の候補を取得
+    ///
+    /// 記帳番号・伝票番号が指定された場合はProjectionの索引から候補を絞り込み、
+    /// 未指定の場合は全エントリーを候補とする。
+    fn candidates(
+        &self,
+        projection: &JournalEntrySearchProjection,
+        voucher_number: Option<&str>,
+        entry_number: Option<&str>,
+    ) -> Vec<JournalEntrySearchReadModel> {
+        match (entry_number, voucher_number) {
+            (Some(entry_number), voucher_number) => projection
+                .find_by_entry_number(entry_number)
+                .filter(|entry| {
+                    voucher_number
+                        .map(|v| entry.voucher_number.as_deref() == Some(v.trim()))
+                        .unwrap_or(true)
+                })
+                .into_iter()
+                .cloned()
+                .collect(),
+            (None, Some(voucher_number)) => {
+                projection.find_by_voucher_number(voucher_number).into_iter().cloned().collect()
             }
+            (None, None) => projection.entries().to_vec(),
         }
-
-        Ok(projection)
     }
 
     /// 日付範囲でフィルタリング
@@ -91,6 +107,18 @@ impl JournalEntrySearchQueryServiceImpl {
             .collect()
     }
 
+    /// フリーワードでフィルタリング（摘要・勘定科目・伝票番号・記帳番号のいずれかに部分一致）
+    fn filter_by_free_text(
+        &self,
+        entries: Vec<JournalEntrySearchReadModel>,
+        free_text: String,
+    ) -> Vec<JournalEntrySearchReadModel> {
+        entries
+            .into_iter()
+            .filter(|entry| entry.matches_free_text(&free_text))
+            .collect()
+    }
+
     /// 勘定科目でフィルタリング
     fn filter_by_account(
         &self,
@@ -124,6 +152,74 @@ impl JournalEntrySearchQueryServiceImpl {
             .filter(|entry| entry.contains_amount_in_range(min_amount, max_amount))
             .collect()
     }
+
+    /// グループごとの小計を集計（ページネーション前の全件が対象、グループキー順）
+    ///
+    /// 勘定科目別は明細単位で集計し、仕訳件数はその科目の明細を含む仕訳の数とする。
+    /// 取引月別・ステータス別は仕訳のすべての明細を集計する。
+    fn subtotals(
+        &self,
+        entries: &[JournalEntrySearchReadModel],
+        grouping: SearchGrouping,
+    ) -> Vec<JournalEntrySearchGroupDto> {
+        #[derive(Default)]
+        struct Subtotal {
+            name: String,
+            entries: BTreeSet<String>,
+            debit_total: f64,
+            credit_total: f64,
+            account_codes: BTreeSet<String>,
+        }
+
+        let mut groups: BTreeMap<String, Subtotal> = BTreeMap::new();
+        for entry in entries {
+            for line in &entry.lines {
+                let key = match grouping {
+                    SearchGrouping::None => return Vec::new(),
+                    SearchGrouping::Account => line.account_code.clone(),
+                    SearchGrouping::Month => entry
+                        .transaction_date
+                        .get(..7)
+                        .unwrap_or(&entry.transaction_date)
+                        .to_string(),
+                    SearchGrouping::Status => entry.status.clone(),
+                };
+                let subtotal = groups.entry(key).or_default();
+                if grouping == SearchGrouping::Account {
+                    subtotal.name = line.account_name.clone();
+                }
+                subtotal.entries.insert(entry.entry_id.clone());
+                subtotal.account_codes.insert(line.account_code.clone());
+                match line.side.as_str() {
+                    "Debit" => subtotal.debit_total += line.amount,
+                    "Credit" => subtotal.credit_total += line.amount,
+                    _ => {}
+                }
+            }
+        }
+
+        groups
+            .into_iter()
+            .map(|(key, subtotal)| JournalEntrySearchGroupDto {
+                key,
+                name: subtotal.name,
+                entry_count: subtotal.entries.len() as u32,
+                debit_total: subtotal.debit_total,
+                credit_total: subtotal.credit_total,
+                account_codes: subtotal.account_codes.into_iter().collect(),
+            })
+            .collect()
+    }
+}
+
+impl ProjectionWarmUp for JournalEntrySearchQueryServiceImpl {
+    async fn warm_up(&self) -> ApplicationResult<()> {
+        self.build_search_projection().await.map(|_| ())
+    }
+
+    fn warm_up_progress(&self) -> WarmUpProgress {
+        self.projection_cache.progress()
+    }
 }
 
 impl JournalEntrySearchQueryService for JournalEntrySearchQueryServiceImpl {
@@ -134,8 +230,17 @@ impl JournalEntrySearchQueryService for JournalEntrySearchQueryServiceImpl {
         // JournalEntrySearchProjectionを構築
         let projection = self.build_search_projection().await?;
 
-        // 全エントリーを取得
-        let mut entries: Vec<JournalEntrySearchReadModel> = projection.entries().to_vec();
+        // 検索対象の候補を取得（伝票番号・記帳番号は索引を使用）
+        let mut entries = self.candidates(
+            &projection,
+            criteria.voucher_number.as_deref(),
+            criteria.entry_number.as_deref(),
+        );
+
+        // 削除済みの仕訳は指定した場合のみ
+        if !criteria.include_deleted {
+            entries.retain(|entry| entry.status != DELETED_JOURNAL_ENTRY_STATUS);
+        }
 
         // 日付範囲でフィルタリング
         if criteria.from_date.is_some() || criteria.to_date.is_some() {
@@ -151,6 +256,11 @@ impl JournalEntrySearchQueryService for JournalEntrySearchQueryServiceImpl {
             entries = self.filter_by_description(entries, description);
         }
 
+        // フリーワードでフィルタリング
+        if let Some(free_text) = criteria.free_text.clone() {
+            entries = self.filter_by_free_text(entries, free_text);
+        }
+
         // 勘定科目でフィルタリング
         if let Some(account_code) = criteria.account_code.clone() {
             entries = self.filter_by_account(entries, account_code);
@@ -167,11 +277,22 @@ impl JournalEntrySearchQueryService for JournalEntrySearchQueryServiceImpl {
                 self.filter_by_amount_range(entries, criteria.min_amount, criteria.max_amount);
         }
 
+        // 未解決の注記が付いた仕訳のみ
+        if criteria.flagged_only {
+            entries.retain(JournalEntrySearchReadModel::is_flagged);
+        }
+
+        // 分析軸でフィルタリング
+        if !criteria.dimensions.is_empty() {
+            entries.retain(|entry| entry.contains_dimensions(&criteria.dimensions));
+        }
+
         // 取引日付降順でソート
         entries.sort_by(|a, b| b.transaction_date.cmp(&a.transaction_date));
 
-        // 総件数を保存
+        // 総件数とグループごとの小計を保存
         let total_count = entries.len() as u32;
+        let groups = self.subtotals(&entries, criteria.group_by);
 
         // ページネーション適用
         let offset = criteria.offset.unwrap_or(0) as usize;
@@ -185,14 +306,15 @@ impl JournalEntrySearchQueryService for JournalEntrySearchQueryServiceImpl {
             .map(|entry| {
                 let line_dtos: Vec<JournalEntryLineItemDto> = entry
                     .lines
-                    .into_iter()
+                    .iter()
                     .map(|line| JournalEntryLineItemDto {
                         line_number: line.line_number,
-                        side: line.side,
-                        account_code: line.account_code,
-                        account_name: line.account_name,
+                        side: line.side.clone(),
+                        account_code: line.account_code.clone(),
+                        account_name: line.account_name.clone(),
                         amount: line.amount,
-                        description: line.description,
+                        description: entry.line_description(line).map(str::to_string),
+                        open_annotations: line.open_annotations,
                     })
                     .collect();
 
@@ -201,12 +323,14 @@ impl JournalEntrySearchQueryService for JournalEntrySearchQueryServiceImpl {
                     entry_number: entry.entry_number,
                     transaction_date: entry.transaction_date,
                     status: entry.status,
+                    description: entry.description,
                     lines: line_dtos,
                 }
             })
             .collect();
 
-        Ok(JournalEntrySearchResultDto { entries: entry_dtos, total_count })
+        Ok(JournalEntrySearchResultDto::new(entry_dtos, total_count)
+            .with_groups(criteria.group_by, groups))
     }
 }
 
@@ -215,6 +339,7 @@ mod tests {
     use tempfile::TempDir;
 
     use super::*;
+    use crate::queries::journal_entry_search_read_model::JournalEntryLineReadModel;
 
     #[tokio::test]
     async fn test_search_empty_criteria() {
@@ -314,4 +439,134 @@ mod tests {
         assert_eq!(result.entries.len(), 0);
         assert_eq!(result.total_count, 0);
     }
+
+    #[tokio::test]
+    async fn test_search_with_numbers_and_free_text() {
+        let temp_dir = TempDir::new().unwrap();
+        let event_store = Arc::new(EventStore::new(temp_dir.path()).await.unwrap());
+        let service = JournalEntrySearchQueryServiceImpl::new(event_store);
+
+        let criteria = SearchCriteriaDto::new()
+            .with_voucher_number("V001".to_string())
+            .with_entry_number("EN-2024-001".to_string())
+            .with_free_text("現金".to_string());
+
+        let result = service.search(criteria).await.unwrap();
+
+        assert_eq!(result.entries.len(), 0);
+        assert_eq!(result.total_count, 0);
+    }
+
+    #[tokio::test]
+    async fn test_subtotals_by_account_month_and_status() {
+        let temp_dir = TempDir::new().unwrap();
+        let event_store = Arc::new(EventStore::new(temp_dir.path()).await.unwrap());
+        let service = JournalEntrySearchQueryServiceImpl::new(event_store);
+
+        let line = |number, side: &str, code: &str, name: &str, amount| {
+            JournalEntryLineReadModel::new(
+                number,
+                side.to_string(),
+                code.to_string(),
+                name.to_string(),
+                amount,
+                None,
+            )
+        };
+        let entry = |id: &str, date: &str, status: &str, lines| {
+            JournalEntrySearchReadModel::new(
+                id.to_string(),
+                None,
+                date.to_string(),
+                status.to_string(),
+                lines,
+            )
+        };
+        let entries = vec![
+            entry(
+                "JE-1",
+                "2024-04-10",
+                "Posted",
+                vec![
+                    line(1, "Debit", "1110", "現金", 1000.0),
+                    line(2, "Credit", "4110", "売上高", 1000.0),
+                ],
+            ),
+            entry(
+                "JE-2",
+                "2024-05-02",
+                "Draft",
+                vec![
+                    line(1, "Debit", "1110", "現金", 300.0),
+                    line(2, "Debit", "1110", "現金", 200.0),
+                    line(3, "Credit", "4110", "売上高", 500.0),
+                ],
+            ),
+        ];
+
+        assert!(service.subtotals(&entries, SearchGrouping::None).is_empty());
+
+        let by_account = service.subtotals(&entries, SearchGrouping::Account);
+        assert_eq!(by_account.len(), 2);
+        assert_eq!((by_account[0].key.as_str(), by_account[0].name.as_str()), ("1110", "現金"));
+        assert_eq!(by_account[0].entry_count, 2);
+        assert_eq!((by_account[0].debit_total, by_account[0].credit_total), (1500.0, 0.0));
+        assert_eq!(by_account[1].credit_total, 1500.0);
+
+        let by_month = service.subtotals(&entries, SearchGrouping::Month);
+        let months: Vec<&str> = by_month.iter().map(|group| group.key.as_str()).collect();
+        assert_eq!(months, vec!["2024-04", "2024-05"]);
+        assert_eq!((by_month[1].debit_total, by_month[1].credit_total), (500.0, 500.0));
+        assert_eq!(by_month[1].account_codes, vec!["1110".to_string(), "4110".to_string()]);
+
+        let by_status = service.subtotals(&entries, SearchGrouping::Status);
+        let statuses: Vec<&str> = by_status.iter().map(|group| group.key.as_str()).collect();
+        assert_eq!(statuses, vec!["Draft", "Posted"]);
+        assert_eq!(by_status[1].entry_count, 1);
+    }
+
+    #[tokio::test]
+    async fn test_search_excludes_deleted_entries_by_default() {
+        use chrono::Utc;
+        use javelin_domain::financial_close::journal_entry::events::JournalEntryEvent;
+
+        let temp_dir = TempDir::new().unwrap();
+        let event_store = Arc::new(EventStore::new(temp_dir.path()).await.unwrap());
+        let draft = |entry_id: &str| JournalEntryEvent::DraftCreated {
+            entry_id: entry_id.to_string(),
+            transaction_date: "2024-04-01".to_string(),
+            voucher_number: format!("V-{}", entry_id),
+            lines: vec![],
+            description: None,
+            created_by: "clerk".to_string(),
+            created_at: Utc::now(),
+        };
+        event_store.append("je-live", vec![draft("je-live")]).await.unwrap();
+        event_store
+            .append(
+                "je-deleted",
+                vec![
+                    draft("je-deleted"),
+                    JournalEntryEvent::Deleted {
+                        entry_id: "je-deleted".to_string(),
+                        deleted_by: "clerk".to_string(),
+                        deleted_at: Utc::now(),
+                    },
+                ],
+            )
+            .await
+            .unwrap();
+        let service = JournalEntrySearchQueryServiceImpl::new(event_store);
+
+        let result = service.search(SearchCriteriaDto::new()).await.unwrap();
+        assert_eq!(result.total_count, 1);
+        assert_eq!(result.entries[0].entry_id, "je-live");
+
+        let result = service
+            .search(SearchCriteriaDto::new().with_include_deleted(true))
+            .await
+            .unwrap();
+        assert_eq!(result.total_count, 2);
+        assert!(result.entries.iter().any(|entry| entry.status == DELETED_JOURNAL_ENTRY_STATUS));
+    }
 }
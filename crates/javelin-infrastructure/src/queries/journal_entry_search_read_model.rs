@@ -1,6 +1,8 @@
 // 仕訳検索用ReadModel
 // 検索最適化されたデータ構造
 
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
 
 /// 仕訳検索用ReadModel
@@ -11,8 +13,13 @@ use serde::{Deserialize, Serialize};
 pub struct JournalEntrySearchReadModel {
     pub entry_id: String,
     pub entry_number: Option<String>,
+    #[serde(default)]
+    pub voucher_number: Option<String>,
     pub transaction_date: String, // YYYY-MM-DD形式
     pub status: String,
+    /// 伝票の摘要（摘要のない明細に引き継がれる）
+    #[serde(default)]
+    pub description: Option<String>,
     pub lines: Vec<JournalEntryLineReadModel>,
 }
 
@@ -27,6 +34,12 @@ pub struct JournalEntryLineReadModel {
     pub account_name: String, // マスタデータから取得
     pub amount: f64,
     pub description: Option<String>,
+    /// 未解決の注記数
+    #[serde(default)]
+    pub open_annotations: u32,
+    /// 分析軸（種類 → コード）
+    #[serde(default)]
+    pub dimensions: BTreeMap<String, String>,
 }
 
 impl JournalEntrySearchReadModel {
@@ -38,7 +51,37 @@ impl JournalEntrySearchReadModel {
         status: String,
         lines: Vec<JournalEntryLineReadModel>,
     ) -> Self {
-        Self { entry_id, entry_number, transaction_date, status, lines }
+        Self {
+            entry_id,
+            entry_number,
+            voucher_number: None,
+            transaction_date,
+            status,
+            description: None,
+            lines,
+        }
+    }
+
+    /// 伝票の摘要を設定（空文字は摘要なし）
+    pub fn with_description(mut self, description: Option<String>) -> Self {
+        self.description = description.filter(|d| !d.is_empty());
+        self
+    }
+
+    /// 伝票の摘要を取得
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    /// 明細の摘要を取得（明細に摘要がなければ伝票の摘要を引き継ぐ）
+    pub fn line_description<'a>(&'a self, line: &'a JournalEntryLineReadModel) -> Option<&'a str> {
+        line.description().or(self.description())
+    }
+
+    /// 伝票番号を設定
+    pub fn with_voucher_number(mut self, voucher_number: String) -> Self {
+        self.voucher_number = Some(voucher_number);
+        self
     }
 
     /// 取引日付を取得
@@ -61,15 +104,39 @@ impl JournalEntrySearchReadModel {
         self.lines.iter().any(|line| line.account_code == account_code)
     }
 
-    /// 指定された摘要を含むかチェック（大文字小文字非区別）
+    /// 指定された摘要を含むかチェック（伝票の摘要を含む、大文字小文字非区別）
     pub fn contains_description(&self, search_text: &str) -> bool {
         let search_lower = search_text.to_lowercase();
-        self.lines.iter().any(|line| {
-            line.description
-                .as_ref()
-                .map(|desc| desc.to_lowercase().contains(&search_lower))
-                .unwrap_or(false)
-        })
+        let contains = |desc: &str| desc.to_lowercase().contains(&search_lower);
+
+        self.description().is_some_and(contains)
+            || self.lines.iter().any(|line| line.description().is_some_and(contains))
+    }
+
+    /// フリーワードが摘要・勘定科目・伝票番号・記帳番号のいずれかに含まれるかチェック
+    /// （大文字小文字非区別）
+    pub fn matches_free_text(&self, search_text: &str) -> bool {
+        let search_lower = search_text.to_lowercase();
+        let contains = |value: &str| value.to_lowercase().contains(&search_lower);
+
+        self.voucher_number.as_deref().is_some_and(contains)
+            || self.entry_number.as_deref().is_some_and(contains)
+            || self.description().is_some_and(contains)
+            || self.lines.iter().any(|line| {
+                contains(&line.account_code)
+                    || contains(&line.account_name)
+                    || line.description.as_deref().is_some_and(contains)
+            })
+    }
+
+    /// 未解決の注記が付いた明細を含むかチェック
+    pub fn is_flagged(&self) -> bool {
+        self.lines.iter().any(|line| line.open_annotations > 0)
+    }
+
+    /// 指定された分析軸をすべて持つ明細を含むかチェック
+    pub fn contains_dimensions(&self, filters: &BTreeMap<String, String>) -> bool {
+        self.lines.iter().any(|line| line.has_dimensions(filters))
     }
 
     /// 指定された借方貸方区分の明細を含むかチェック
@@ -98,7 +165,27 @@ impl JournalEntryLineReadModel {
         amount: f64,
         description: Option<String>,
     ) -> Self {
-        Self { line_number, side, account_code, account_name, amount, description }
+        Self {
+            line_number,
+            side,
+            account_code,
+            account_name,
+            amount,
+            description,
+            open_annotations: 0,
+            dimensions: BTreeMap::new(),
+        }
+    }
+
+    /// 分析軸を設定
+    pub fn with_dimensions(mut self, dimensions: BTreeMap<String, String>) -> Self {
+        self.dimensions = dimensions;
+        self
+    }
+
+    /// 指定された分析軸（種類 → コード）をすべて持つかチェック
+    pub fn has_dimensions(&self, filters: &BTreeMap<String, String>) -> bool {
+        filters.iter().all(|(kind, code)| self.dimensions.get(kind) == Some(code))
     }
 
     /// 借方貸方区分を取得
@@ -196,6 +283,45 @@ mod tests {
         assert!(!model.contains_account("2000"));
     }
 
+    #[test]
+    fn test_contains_dimensions() {
+        let dimensions = |pairs: &[(&str, &str)]| -> BTreeMap<String, String> {
+            pairs.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect()
+        };
+        let lines = vec![
+            JournalEntryLineReadModel::new(
+                1,
+                "Debit".to_string(),
+                "6100".to_string(),
+                "外注費".to_string(),
+                50000.0,
+                None,
+            )
+            .with_dimensions(dimensions(&[("project", "P001"), ("segment", "S1")])),
+            JournalEntryLineReadModel::new(
+                2,
+                "Credit".to_string(),
+                "2100".to_string(),
+                "買掛金".to_string(),
+                50000.0,
+                None,
+            ),
+        ];
+        let model = JournalEntrySearchReadModel::new(
+            "JE001".to_string(),
+            None,
+            "2024-01-01".to_string(),
+            "Posted".to_string(),
+            lines,
+        );
+
+        assert!(model.contains_dimensions(&dimensions(&[])));
+        assert!(model.contains_dimensions(&dimensions(&[("project", "P001")])));
+        assert!(model.contains_dimensions(&dimensions(&[("project", "P001"), ("segment", "S1")])));
+        assert!(!model.contains_dimensions(&dimensions(&[("project", "P002")])));
+        assert!(!model.contains_dimensions(&dimensions(&[("project", "P001"), ("segment", "S2")])));
+    }
+
     #[test]
     fn test_contains_description_case_insensitive() {
         let lines = vec![JournalEntryLineReadModel::new(
@@ -221,6 +347,64 @@ mod tests {
         assert!(!model.contains_description("仕入"));
     }
 
+    #[test]
+    fn test_header_description_searchable_and_inherited() {
+        let line = |line_number, description: Option<&str>| {
+            JournalEntryLineReadModel::new(
+                line_number,
+                "Debit".to_string(),
+                "1000".to_string(),
+                "現金".to_string(),
+                100000.0,
+                description.map(str::to_string),
+            )
+        };
+
+        let model = JournalEntrySearchReadModel::new(
+            "JE001".to_string(),
+            None,
+            "2024-01-01".to_string(),
+            "Draft".to_string(),
+            vec![line(1, None), line(2, Some("振込手数料"))],
+        )
+        .with_description(Some("4月分売上".to_string()));
+
+        assert!(model.contains_description("4月分"));
+        assert!(model.contains_description("手数料"));
+        assert!(model.matches_free_text("売上"));
+
+        let descriptions: Vec<_> =
+            model.lines().iter().map(|line| model.line_description(line)).collect();
+        assert_eq!(descriptions, vec![Some("4月分売上"), Some("振込手数料")]);
+    }
+
+    #[test]
+    fn test_matches_free_text_across_fields() {
+        let lines = vec![JournalEntryLineReadModel::new(
+            1,
+            "Debit".to_string(),
+            "1000".to_string(),
+            "現金".to_string(),
+            100000.0,
+            Some("売上入金".to_string()),
+        )];
+
+        let model = JournalEntrySearchReadModel::new(
+            "JE001".to_string(),
+            Some("EN-2024-001".to_string()),
+            "2024-01-01".to_string(),
+            "Posted".to_string(),
+            lines,
+        )
+        .with_voucher_number("V-2024-00001".to_string());
+
+        assert!(model.matches_free_text("入金"));
+        assert!(model.matches_free_text("現金"));
+        assert!(model.matches_free_text("v-2024"));
+        assert!(model.matches_free_text("en-2024-001"));
+        assert!(!model.matches_free_text("買掛金"));
+    }
+
     #[test]
     fn test_contains_side() {
         let lines = vec![
@@ -1,11 +1,14 @@
 // LedgerProjection実装
 // 元帳表示用のReadModel
 
+use std::collections::BTreeMap;
+
 use javelin_domain::financial_close::journal_entry::events::JournalEntryEvent;
 use serde::{Deserialize, Serialize};
 
 use crate::{
     error::InfrastructureResult,
+    event_envelope::EventEnvelope,
     event_stream::StoredEvent,
     projection_trait::{Apply, ProjectionStrategy, ToReadModel},
 };
@@ -14,12 +17,30 @@ use crate::{
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct LedgerEntryReadModel {
     pub account_code: String,
+    /// 取引通貨（ISO 4217）
+    pub currency: String,
     pub transaction_date: String,
     pub entry_number: String,
+    /// 仕訳ID（注記の紐付けに使用）
+    #[serde(default)]
+    pub entry_id: String,
+    /// 仕訳の明細行番号
+    #[serde(default)]
+    pub line_number: u32,
     pub description: String,
     pub debit_amount: f64,
     pub credit_amount: f64,
     pub balance: f64,
+    /// 明細の分析軸（種類 → コード）
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub dimensions: BTreeMap<String, String>,
+}
+
+impl LedgerEntryReadModel {
+    /// 指定された分析軸（種類 → コード）をすべて持つかチェック
+    pub fn has_dimensions(&self, filters: &BTreeMap<String, String>) -> bool {
+        filters.iter().all(|(kind, code)| self.dimensions.get(kind) == Some(code))
+    }
 }
 
 /// 元帳Projection
@@ -39,6 +60,14 @@ pub struct LedgerProjection {
     entry_transaction_date_cache: std::collections::HashMap<String, String>,
     // 仕訳の摘要をキャッシュ（entry_id -> description）
     entry_description_cache: std::collections::HashMap<String, String>,
+    // 承認待ちの仕訳（申請順）
+    pending_entry_ids: Vec<String>,
+    // 未解決の注記（annotation_id -> (entry_id, line_number)）
+    open_annotations: std::collections::HashMap<String, (String, u32)>,
+    // 確定した月初の繰越残高（YYYY-MM -> 勘定科目コード -> 前月末残高）
+    carried_forward: std::collections::BTreeMap<String, std::collections::HashMap<String, f64>>,
+    // イベントに埋め込まれた勘定科目名（勘定科目コード -> 最新の名称）
+    account_names: std::collections::HashMap<String, String>,
 }
 
 impl LedgerProjection {
@@ -50,6 +79,10 @@ impl LedgerProjection {
             entry_lines_cache: std::collections::HashMap::new(),
             entry_transaction_date_cache: std::collections::HashMap::new(),
             entry_description_cache: std::collections::HashMap::new(),
+            pending_entry_ids: Vec::new(),
+            open_annotations: std::collections::HashMap::new(),
+            carried_forward: std::collections::BTreeMap::new(),
+            account_names: std::collections::HashMap::new(),
         }
     }
 
@@ -60,12 +93,28 @@ impl LedgerProjection {
         *balance
     }
 
+    /// 確定済みの繰越残高のうち、取引日より後の月の繰越残高に反映（遡及記帳）
+    fn carry_forward(&mut self, account_code: &str, transaction_date: &str, amount: f64) {
+        // 日付は文字列比較のため、"YYYY-MM-DD" < "YYYY-MM" は前月以前の取引を表す
+        for (_, balances) in self
+            .carried_forward
+            .iter_mut()
+            .filter(|(period, _)| transaction_date < period.as_str())
+        {
+            *balances.entry(account_code.to_string()).or_insert(0.0) += amount;
+        }
+    }
+
     /// 記帳済イベントから元帳エントリを作成
+    ///
+    /// 摘要のない明細には伝票の摘要（なければdefault_description）を引き継ぐ。
     fn create_ledger_entries(
         &mut self,
+        entry_id: &str,
         entry_number: &str,
         transaction_date: &str,
-        description: &str,
+        header_description: Option<&str>,
+        default_description: &str,
         lines: &[javelin_domain::financial_close::journal_entry::events::JournalEntryLineDto],
     ) {
         use javelin_domain::financial_close::journal_entry::values::DebitCredit;
@@ -77,17 +126,28 @@ impl LedgerProjection {
                 Some(DebitCredit::Credit) => (0.0, line.amount),
                 None => (0.0, 0.0),
             };
+            if let Some(name) = line.account_name.as_ref().filter(|name| !name.is_empty()) {
+                self.account_names.insert(line.account_code.clone(), name.clone());
+            }
 
             let balance = self.update_balance(&line.account_code, debit, credit);
+            self.carry_forward(&line.account_code, transaction_date, debit - credit);
 
             self.entries.push(LedgerEntryReadModel {
                 account_code: line.account_code.clone(),
+                currency: line.currency.clone(),
                 transaction_date: transaction_date.to_string(),
                 entry_number: entry_number.to_string(),
-                description: description.to_string(),
+                entry_id: entry_id.to_string(),
+                line_number: line.line_number,
+                description: line
+                    .effective_description(header_description)
+                    .unwrap_or(default_description)
+                    .to_string(),
                 debit_amount: debit,
                 credit_amount: credit,
                 balance,
+                dimensions: line.dimensions.clone(),
             });
         }
     }
@@ -95,6 +155,7 @@ impl LedgerProjection {
     /// 取消仕訳から元帳エントリを作成（逆仕訳）
     fn create_reversal_entries(
         &mut self,
+        entry_id: &str,
         entry_number: &str,
         transaction_date: &str,
         description: &str,
@@ -112,15 +173,20 @@ impl LedgerProjection {
             };
 
             let balance = self.update_balance(&line.account_code, debit, credit);
+            self.carry_forward(&line.account_code, transaction_date, debit - credit);
 
             self.entries.push(LedgerEntryReadModel {
                 account_code: line.account_code.clone(),
+                currency: line.currency.clone(),
                 transaction_date: transaction_date.to_string(),
                 entry_number: entry_number.to_string(),
+                entry_id: entry_id.to_string(),
+                line_number: line.line_number,
                 description: format!("取消: {}", description),
                 debit_amount: debit,
                 credit_amount: credit,
                 balance,
+                dimensions: line.dimensions.clone(),
             });
         }
     }
@@ -130,10 +196,107 @@ impl LedgerProjection {
         &self.entries
     }
 
+    /// 記帳済イベントに埋め込まれた勘定科目名（名称を持つイベントがなければNone）
+    pub fn account_name(&self, account_code: &str) -> Option<&str> {
+        self.account_names.get(account_code).map(String::as_str)
+    }
+
     /// 勘定科目別の残高を取得
     pub fn balance(&self, account_code: &str) -> f64 {
         *self.balances.get(account_code).unwrap_or(&0.0)
     }
+
+    /// 月初の繰越残高を確定し、勘定科目別の前月末残高を返す（period: YYYY-MM）
+    ///
+    /// 確定済みの月は再集計せず、確定後の遡及記帳は記帳時に反映済みの残高を返す。
+    pub fn roll_over(&mut self, period: &str) -> &std::collections::HashMap<String, f64> {
+        if !self.carried_forward.contains_key(period) {
+            let mut balances = std::collections::HashMap::new();
+            for entry in self.entries.iter().filter(|e| e.transaction_date.as_str() < period) {
+                *balances.entry(entry.account_code.clone()).or_insert(0.0) +=
+                    entry.debit_amount - entry.credit_amount;
+            }
+            self.carried_forward.insert(period.to_string(), balances);
+        }
+        &self.carried_forward[period]
+    }
+
+    /// 確定済みの繰越残高のうち、指定日以前で最も新しい月の繰越残高（月, 前月末残高）
+    pub fn carried_forward_before(&self, account_code: &str, date: &str) -> Option<(&str, f64)> {
+        self.carried_forward
+            .iter()
+            .rev()
+            .find(|(period, _)| period.as_str() <= date)
+            .map(|(period, balances)| {
+                (period.as_str(), balances.get(account_code).copied().unwrap_or(0.0))
+            })
+    }
+
+    /// 承認待ちの仕訳を含めたエントリ一覧を取得（試算用）
+    ///
+    /// 承認待ちの仕訳は記帳済エントリの後に申請順で積み上げ、残高は記帳済の残高から継続する。
+    /// 伝票番号は未採番のため仕訳IDを表示する。
+    pub fn entries_including_pending(&self) -> Vec<LedgerEntryReadModel> {
+        use javelin_domain::financial_close::journal_entry::values::DebitCredit;
+
+        let mut entries = self.entries.clone();
+        let mut balances = self.balances.clone();
+
+        for entry_id in &self.pending_entry_ids {
+            let Some(lines) = self.entry_lines_cache.get(entry_id) else {
+                continue;
+            };
+            let transaction_date = self
+                .entry_transaction_date_cache
+                .get(entry_id)
+                .cloned()
+                .unwrap_or_else(|| "1900-01-01".to_string());
+            let header_description = self.entry_description_cache.get(entry_id);
+
+            for line in lines {
+                let (debit, credit) = match line.side.parse::<DebitCredit>().ok() {
+                    Some(DebitCredit::Debit) => (line.amount, 0.0),
+                    Some(DebitCredit::Credit) => (0.0, line.amount),
+                    None => (0.0, 0.0),
+                };
+                let balance = balances.entry(line.account_code.clone()).or_insert(0.0);
+                *balance += debit - credit;
+
+                entries.push(LedgerEntryReadModel {
+                    account_code: line.account_code.clone(),
+                    currency: line.currency.clone(),
+                    transaction_date: transaction_date.clone(),
+                    entry_number: entry_id.clone(),
+                    entry_id: entry_id.clone(),
+                    line_number: line.line_number,
+                    description: format!(
+                        "[承認待ち] {}",
+                        line.effective_description(header_description.map(String::as_str))
+                            .unwrap_or("承認待ち")
+                    ),
+                    debit_amount: debit,
+                    credit_amount: credit,
+                    balance: *balance,
+                    dimensions: line.dimensions.clone(),
+                });
+            }
+        }
+
+        entries
+    }
+
+    /// 明細の未解決の注記数
+    pub fn open_annotation_count(&self, entry_id: &str, line_number: u32) -> u32 {
+        self.open_annotations
+            .values()
+            .filter(|(id, line)| id == entry_id && *line == line_number)
+            .count() as u32
+    }
+
+    /// 承認待ちの一覧から除外
+    fn remove_pending(&mut self, entry_id: &str) {
+        self.pending_entry_ids.retain(|id| id != entry_id);
+    }
 }
 
 impl Default for LedgerProjection {
@@ -146,32 +309,61 @@ impl Apply<JournalEntryEvent> for LedgerProjection {
     fn apply(&mut self, event: JournalEntryEvent) -> InfrastructureResult<()> {
         match event {
             // DraftCreatedで明細、取引日、摘要をキャッシュ
-            JournalEntryEvent::DraftCreated { entry_id, transaction_date, lines, .. } => {
+            JournalEntryEvent::DraftCreated {
+                entry_id,
+                transaction_date,
+                lines,
+                description,
+                ..
+            } => {
                 self.entry_lines_cache.insert(entry_id.clone(), lines);
                 self.entry_transaction_date_cache.insert(entry_id.clone(), transaction_date);
+                if let Some(description) = description.filter(|d| !d.is_empty()) {
+                    self.entry_description_cache.insert(entry_id, description);
+                }
+            }
+            // DraftUpdatedで明細・摘要を更新
+            JournalEntryEvent::DraftUpdated { entry_id, lines, description, .. } => {
+                if let Some(lines) = lines {
+                    self.entry_lines_cache.insert(entry_id.clone(), lines);
+                }
+                match description {
+                    Some(description) if description.is_empty() => {
+                        self.entry_description_cache.remove(&entry_id);
+                    }
+                    Some(description) => {
+                        self.entry_description_cache.insert(entry_id, description);
+                    }
+                    None => {}
+                }
             }
-            // DraftUpdatedで明細を更新
-            JournalEntryEvent::DraftUpdated { entry_id, lines: Some(lines), .. } => {
-                self.entry_lines_cache.insert(entry_id, lines);
+            // 承認申請で承認待ちに追加
+            JournalEntryEvent::ApprovalRequested { entry_id, .. } => {
+                if !self.pending_entry_ids.contains(&entry_id) {
+                    self.pending_entry_ids.push(entry_id);
+                }
+            }
+            // 差戻しで承認待ちから除外
+            JournalEntryEvent::Rejected { entry_id, .. } => {
+                self.remove_pending(&entry_id);
             }
             // 記帳時に元帳に反映
             JournalEntryEvent::Posted { entry_id, entry_number, .. } => {
+                self.remove_pending(&entry_id);
                 if let Some(lines) = self.entry_lines_cache.get(&entry_id).cloned() {
                     let transaction_date = self
                         .entry_transaction_date_cache
                         .get(&entry_id)
                         .cloned()
                         .unwrap_or_else(|| "1900-01-01".to_string());
-                    let description = self
-                        .entry_description_cache
-                        .get(&entry_id)
-                        .cloned()
-                        .unwrap_or_else(|| "記帳済".to_string());
+                    let header_description = self.entry_description_cache.get(&entry_id).cloned();
 
                     self.create_ledger_entries(
+                        &entry_id,
                         &entry_number,
                         &transaction_date,
-                        &description,
+                        header_description.as_deref(),
+                        "記帳済",
                         &lines,
                     );
                 }
@@ -181,6 +373,7 @@ impl Apply<JournalEntryEvent> for LedgerProjection {
                 if let Some(lines) = self.entry_lines_cache.get(&original_id).cloned() {
                     // 元の仕訳の明細を使って逆仕訳を作成
                     self.create_reversal_entries(
+                        &entry_id,
                         &entry_id,
                         &reversed_at.format("%Y-%m-%d").to_string(),
                         &reason,
@@ -190,10 +383,18 @@ impl Apply<JournalEntryEvent> for LedgerProjection {
             }
             // Deletedでキャッシュをクリア
             JournalEntryEvent::Deleted { entry_id, .. } => {
+                self.remove_pending(&entry_id);
                 self.entry_lines_cache.remove(&entry_id);
                 self.entry_transaction_date_cache.remove(&entry_id);
                 self.entry_description_cache.remove(&entry_id);
             }
+            // 注記は明細ごとに未解決のものを保持
+            JournalEntryEvent::LineAnnotated { entry_id, annotation_id, line_number, .. } => {
+                self.open_annotations.insert(annotation_id, (entry_id, line_number));
+            }
+            JournalEntryEvent::AnnotationResolved { annotation_id, .. } => {
+                self.open_annotations.remove(&annotation_id);
+            }
             _ => {
                 // その他のイベントは元帳に影響しない
             }
@@ -216,8 +417,28 @@ pub struct LedgerProjectionStrategy;
 
 impl ProjectionStrategy for LedgerProjectionStrategy {
     fn should_update(&self, event: &StoredEvent) -> bool {
-        // 記帳済と取消のみ元帳に反映
-        event.event_type == "Posted" || event.event_type == "Reversed"
+        match event.envelope() {
+            // 記帳・取消で元帳に転記し、下書きの明細・承認待ち・注記は転記に備えて保持する
+            EventEnvelope::JournalEntry(event) => match event {
+                JournalEntryEvent::DraftCreated { .. }
+                | JournalEntryEvent::DraftUpdated { .. }
+                | JournalEntryEvent::ApprovalRequested { .. }
+                | JournalEntryEvent::Rejected { .. }
+                | JournalEntryEvent::Posted { .. }
+                | JournalEntryEvent::Reversed { .. }
+                | JournalEntryEvent::Deleted { .. }
+                | JournalEntryEvent::LineAnnotated { .. }
+                | JournalEntryEvent::AnnotationResolved { .. } => true,
+                JournalEntryEvent::Corrected { .. }
+                | JournalEntryEvent::Closed { .. }
+                | JournalEntryEvent::Reopened { .. }
+                | JournalEntryEvent::EntryLinked { .. }
+                | JournalEntryEvent::EntryUnlinked { .. } => false,
+            },
+            // 復元できない仕訳伝票のイベントは処理側でエラーにする
+            EventEnvelope::Undecodable { .. } => true,
+            EventEnvelope::Unknown { .. } => false,
+        }
     }
 
     fn batch_size(&self) -> usize {
@@ -264,8 +485,10 @@ mod tests {
                 line_number: 1,
                 side: "Debit".to_string(),
                 account_code: "1000".to_string(),
+                account_name: Some("現金".to_string()),
                 sub_account_code: None,
                 department_code: None,
+                dimensions: Default::default(),
                 amount: 100000.0,
                 currency: "JPY".to_string(),
                 tax_type: "NonTaxable".to_string(),
@@ -276,8 +499,10 @@ mod tests {
                 line_number: 2,
                 side: "Credit".to_string(),
                 account_code: "2000".to_string(),
+                account_name: None,
                 sub_account_code: None,
                 department_code: None,
+                dimensions: Default::default(),
                 amount: 100000.0,
                 currency: "JPY".to_string(),
                 tax_type: "NonTaxable".to_string(),
@@ -286,11 +511,20 @@ mod tests {
             },
         ];
 
-        projection.create_ledger_entries("EN-2024-001", "2024-01-01", "Test entry", &lines);
+        projection.create_ledger_entries(
+            "JE001",
+            "EN-2024-001",
+            "2024-01-01",
+            Some("Test entry"),
+            "記帳済",
+            &lines,
+        );
 
         assert_eq!(projection.entries().len(), 2);
         assert_eq!(projection.balance("1000"), 100000.0);
         assert_eq!(projection.balance("2000"), -100000.0);
+        assert_eq!(projection.account_name("1000"), Some("現金"));
+        assert_eq!(projection.account_name("2000"), None);
     }
 
     #[test]
@@ -303,8 +537,10 @@ mod tests {
             line_number: 1,
             side: "Debit".to_string(),
             account_code: "1000".to_string(),
+            account_name: None,
             sub_account_code: None,
             department_code: None,
+            dimensions: Default::default(),
             amount: 100000.0,
             currency: "JPY".to_string(),
             tax_type: "NonTaxable".to_string(),
@@ -313,11 +549,24 @@ mod tests {
         }];
 
         // 元仕訳
-        projection.create_ledger_entries("EN-2024-001", "2024-01-01", "Original", &lines);
+        projection.create_ledger_entries(
+            "JE001",
+            "EN-2024-001",
+            "2024-01-01",
+            Some("Original"),
+            "記帳済",
+            &lines,
+        );
         assert_eq!(projection.balance("1000"), 100000.0);
 
         // 取消仕訳
-        projection.create_reversal_entries("EN-2024-002", "2024-01-02", "Original", &lines);
+        projection.create_reversal_entries(
+            "JE002",
+            "EN-2024-002",
+            "2024-01-02",
+            "Original",
+            &lines,
+        );
         assert_eq!(projection.balance("1000"), 0.0);
     }
 
@@ -331,8 +580,10 @@ mod tests {
             line_number: 1,
             side: "Debit".to_string(),
             account_code: "1000".to_string(),
+            account_name: None,
             sub_account_code: None,
             department_code: None,
+            dimensions: Default::default(),
             amount: 100000.0,
             currency: "JPY".to_string(),
             tax_type: "NonTaxable".to_string(),
@@ -345,6 +596,7 @@ mod tests {
             transaction_date: "2024-01-01".to_string(),
             voucher_number: "V001".to_string(),
             lines: lines.clone(),
+            description: None,
             created_by: "user1".to_string(),
             created_at: Utc::now(),
         };
@@ -367,8 +619,10 @@ mod tests {
                 line_number: 1,
                 side: "Debit".to_string(),
                 account_code: "1000".to_string(),
+                account_name: None,
                 sub_account_code: None,
                 department_code: None,
+                dimensions: Default::default(),
                 amount: 100000.0,
                 currency: "JPY".to_string(),
                 tax_type: "NonTaxable".to_string(),
@@ -379,8 +633,10 @@ mod tests {
                 line_number: 2,
                 side: "Credit".to_string(),
                 account_code: "2000".to_string(),
+                account_name: None,
                 sub_account_code: None,
                 department_code: None,
+                dimensions: Default::default(),
                 amount: 100000.0,
                 currency: "JPY".to_string(),
                 tax_type: "NonTaxable".to_string(),
@@ -395,6 +651,7 @@ mod tests {
             transaction_date: "2024-01-01".to_string(),
             voucher_number: "V001".to_string(),
             lines,
+            description: None,
             created_by: "user1".to_string(),
             created_at: Utc::now(),
         };
@@ -424,8 +681,10 @@ mod tests {
             line_number: 1,
             side: "Debit".to_string(),
             account_code: "1000".to_string(),
+            account_name: None,
             sub_account_code: None,
             department_code: None,
+            dimensions: Default::default(),
             amount: 100000.0,
             currency: "JPY".to_string(),
             tax_type: "NonTaxable".to_string(),
@@ -439,6 +698,7 @@ mod tests {
             transaction_date: "2024-01-01".to_string(),
             voucher_number: "V001".to_string(),
             lines,
+            description: None,
             created_by: "user1".to_string(),
             created_at: Utc::now(),
         };
@@ -479,8 +739,10 @@ mod tests {
             line_number: 1,
             side: "Debit".to_string(),
             account_code: "1000".to_string(),
+            account_name: None,
             sub_account_code: None,
             department_code: None,
+            dimensions: Default::default(),
             amount: 100000.0,
             currency: "JPY".to_string(),
             tax_type: "NonTaxable".to_string(),
@@ -494,6 +756,7 @@ mod tests {
             transaction_date: "2024-01-01".to_string(),
             voucher_number: "V001".to_string(),
             lines,
+            description: None,
             created_by: "user1".to_string(),
             created_at: Utc::now(),
         };
@@ -509,4 +772,121 @@ mod tests {
         projection.apply(deleted_event).unwrap();
         assert!(!projection.entry_lines_cache.contains_key("JE001"));
     }
+
+    #[test]
+    fn test_header_description_inherited_by_ledger_entries() {
+        use javelin_domain::financial_close::journal_entry::events::JournalEntryLineDto;
+
+        let mut projection = LedgerProjection::new();
+
+        let line =
+            |side: &str, account_code: &str, description: Option<&str>| JournalEntryLineDto {
+                line_number: 1,
+                side: side.to_string(),
+                account_code: account_code.to_string(),
+                account_name: None,
+                sub_account_code: None,
+                department_code: None,
+                dimensions: Default::default(),
+                amount: 1000.0,
+                currency: "JPY".to_string(),
+                tax_type: "NonTaxable".to_string(),
+                tax_amount: 0.0,
+                description: description.map(str::to_string),
+            };
+        projection
+            .apply(JournalEntryEvent::DraftCreated {
+                entry_id: "JE001".to_string(),
+                transaction_date: "2024-04-30".to_string(),
+                voucher_number: "V001".to_string(),
+                lines: vec![
+                    line("Debit", "5000", None),
+                    line("Credit", "1000", Some("普通預金振込")),
+                ],
+                description: Some("4月分家賃".to_string()),
+                created_by: "user1".to_string(),
+                created_at: Utc::now(),
+            })
+            .unwrap();
+        projection
+            .apply(JournalEntryEvent::Posted {
+                entry_id: "JE001".to_string(),
+                entry_number: "EN-2024-001".to_string(),
+                posted_by: "manager".to_string(),
+                posted_at: Utc::now(),
+            })
+            .unwrap();
+
+        let descriptions: Vec<&str> =
+            projection.entries().iter().map(|entry| entry.description.as_str()).collect();
+        assert_eq!(descriptions, vec!["4月分家賃", "普通預金振込"]);
+    }
+
+    #[test]
+    fn test_entries_including_pending() {
+        use javelin_domain::financial_close::journal_entry::events::JournalEntryLineDto;
+
+        let mut projection = LedgerProjection::new();
+
+        let line = |side: &str, account_code: &str| JournalEntryLineDto {
+            line_number: 1,
+            side: side.to_string(),
+            account_code: account_code.to_string(),
+            account_name: None,
+            sub_account_code: None,
+            department_code: None,
+            dimensions: Default::default(),
+            amount: 1000.0,
+            currency: "JPY".to_string(),
+            tax_type: "NonTaxable".to_string(),
+            tax_amount: 0.0,
+            description: None,
+        };
+        projection.create_ledger_entries(
+            "JE001",
+            "EN-2024-001",
+            "2024-01-01",
+            Some("Posted"),
+            "記帳済",
+            &[line("Debit", "1000")],
+        );
+
+        let draft_event = JournalEntryEvent::DraftCreated {
+            entry_id: "JE002".to_string(),
+            transaction_date: "2024-01-05".to_string(),
+            voucher_number: "V002".to_string(),
+            lines: vec![line("Debit", "1000"), line("Credit", "2000")],
+            description: None,
+            created_by: "user1".to_string(),
+            created_at: Utc::now(),
+        };
+        projection.apply(draft_event).unwrap();
+        let requested_event = JournalEntryEvent::ApprovalRequested {
+            entry_id: "JE002".to_string(),
+            requested_by: "user1".to_string(),
+            requested_at: Utc::now(),
+        };
+        projection.apply(requested_event).unwrap();
+
+        // 記帳済のみの元帳には反映されない
+        assert_eq!(projection.entries().len(), 1);
+        assert_eq!(projection.balance("1000"), 1000.0);
+
+        // 承認待ちを含めると記帳済の残高から積み上がる
+        let entries = projection.entries_including_pending();
+        assert_eq!(entries.len(), 3);
+        assert_eq!(entries[1].entry_number, "JE002");
+        assert_eq!(entries[1].balance, 2000.0);
+        assert_eq!(entries[2].balance, -1000.0);
+
+        // 差戻し後は承認待ちに含めない
+        let rejected_event = JournalEntryEvent::Rejected {
+            entry_id: "JE002".to_string(),
+            reason: "差戻し".to_string(),
+            rejected_by: "approver1".to_string(),
+            rejected_at: Utc::now(),
+        };
+        projection.apply(rejected_event).unwrap();
+        assert_eq!(projection.entries_including_pending().len(), 1);
+    }
 }
@@ -6,19 +6,21 @@ use std::{path::Path, sync::Arc};
 use javelin_application::{
     error::ApplicationResult,
     query_service::{
-        AccountMaster, CompanyMaster, MasterData, MasterDataLoaderService, SystemSettings,
+        AccountMaster, CompanyMaster, Holiday, MasterData, MasterDataLoaderService, SystemSettings,
         UserOptions,
     },
 };
 
 use crate::repositories::{
-    AccountMasterRepositoryImpl, ApplicationSettingsRepositoryImpl, CompanyMasterRepositoryImpl,
+    AccountMasterRepositoryImpl, ApplicationSettingsRepositoryImpl, CalendarMasterRepositoryImpl,
+    CompanyMasterRepositoryImpl,
 };
 
 /// マスタデータローダーの実装
 pub struct MasterDataLoaderImpl {
     account_repository: Arc<AccountMasterRepositoryImpl>,
     company_repository: Arc<CompanyMasterRepositoryImpl>,
+    calendar_repository: Arc<CalendarMasterRepositoryImpl>,
     settings_repository: Arc<ApplicationSettingsRepositoryImpl>,
 }
 
@@ -26,23 +28,44 @@ impl MasterDataLoaderImpl {
     pub async fn new(path: &Path) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
         let account_path = path.join("accounts");
         let company_path = path.join("companies");
+        let calendar_path = path.join("calendar");
         let settings_path = path.join("settings");
 
         let account_repository = AccountMasterRepositoryImpl::new(&account_path).await?;
         let company_repository = CompanyMasterRepositoryImpl::new(&company_path).await?;
+        let calendar_repository = CalendarMasterRepositoryImpl::new(&calendar_path).await?;
         let settings_repository = ApplicationSettingsRepositoryImpl::new(&settings_path).await?;
 
         Ok(Self {
             account_repository: Arc::new(account_repository),
             company_repository: Arc::new(company_repository),
+            calendar_repository: Arc::new(calendar_repository),
             settings_repository: Arc::new(settings_repository),
         })
     }
 
+    /// 勘定科目マスタリポジトリ（マスタ変更用。ロード用のリポジトリを共有する）
+    pub fn account_repository(&self) -> Arc<AccountMasterRepositoryImpl> {
+        Arc::clone(&self.account_repository)
+    }
+
+    /// 会社マスタリポジトリ（マスタ変更用。ロード用のリポジトリを共有する）
+    pub fn company_repository(&self) -> Arc<CompanyMasterRepositoryImpl> {
+        Arc::clone(&self.company_repository)
+    }
+
+    /// アプリケーション設定リポジトリ（設定変更用）
+    ///
+    /// 同一プロセスで同じLMDB環境を二重に開けないため、ロード用のリポジトリを共有する。
+    pub fn settings_repository(&self) -> Arc<ApplicationSettingsRepositoryImpl> {
+        Arc::clone(&self.settings_repository)
+    }
+
     /// 各リポジトリからマスタデータをロード
     async fn load_from_repositories(&self) -> ApplicationResult<MasterData> {
         use javelin_domain::repositories::{
-            AccountMasterRepository, ApplicationSettingsRepository, CompanyMasterRepository,
+            AccountMasterRepository, ApplicationSettingsRepository, CalendarMasterRepository,
+            CompanyMasterRepository,
         };
 
         let account_masters = self.account_repository.find_all().await.map_err(|e| {
@@ -53,6 +76,10 @@ impl MasterDataLoaderImpl {
             javelin_application::error::ApplicationError::QueryExecutionFailed(e.to_string())
         })?;
 
+        let calendar_masters = self.calendar_repository.find_all().await.map_err(|e| {
+            javelin_application::error::ApplicationError::QueryExecutionFailed(e.to_string())
+        })?;
+
         let settings = self
             .settings_repository
             .find()
@@ -68,10 +95,11 @@ impl MasterDataLoaderImpl {
 
         let accounts = account_masters.iter().map(AccountMaster::from).collect();
         let companies = company_masters.iter().map(CompanyMaster::from).collect();
+        let holidays = calendar_masters.iter().map(Holiday::from).collect();
         let user_options = UserOptions::from(&settings);
         let system_settings = SystemSettings::from(&settings);
 
-        Ok(MasterData { accounts, companies, user_options, system_settings })
+        Ok(MasterData { accounts, companies, holidays, user_options, system_settings })
     }
 }
 
@@ -123,6 +151,14 @@ mod tests {
         assert_eq!(master_data.companies.len(), 2);
         assert!(master_data.companies.iter().any(|c| c.code == "0001" && c.name == "本社"));
 
+        // カレンダーマスタの検証
+        assert!(
+            master_data
+                .holidays
+                .iter()
+                .any(|h| h.date.ends_with("-01-01") && h.name == "元日")
+        );
+
         // ユーザ設定の検証
         assert_eq!(master_data.user_options.decimal_places, 2);
         assert_eq!(master_data.user_options.date_format, "YYYY-MM-DD");
@@ -130,7 +166,7 @@ mod tests {
         // システム設定の検証
         assert_eq!(master_data.system_settings.fiscal_year_start_month, 4);
         assert_eq!(master_data.system_settings.closing_day, 31);
-        assert_eq!(master_data.system_settings.auto_backup_enabled, true);
+        assert!(master_data.system_settings.auto_backup_enabled);
         assert_eq!(master_data.system_settings.backup_retention_days, 90);
     }
 
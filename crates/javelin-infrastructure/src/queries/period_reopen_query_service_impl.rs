@@ -0,0 +1,279 @@
+// PeriodReopenQueryServiceImpl - 会計期間の再オープン用照会サービス実装
+// 会計期間イベントと仕訳イベントをイベントストアから直接読み込む
+
+use std::{collections::HashMap, sync::Arc};
+
+use chrono::NaiveDate;
+use javelin_application::{
+    error::{ApplicationError, ApplicationResult},
+    query_service::{PeriodReopenQueryService, PostingKind, PostingRecord},
+};
+use javelin_domain::financial_close::{
+    accounting_period::AccountingPeriodEvent, journal_entry::events::JournalEntryEvent,
+};
+
+use crate::EventStore;
+
+/// 仕訳ごとの集計状態
+#[derive(Default)]
+struct EntryState {
+    transaction_date: Option<String>,
+    entry_number: Option<String>,
+    closed: bool,
+}
+
+/// PeriodReopenQueryService実装
+///
+/// 仕訳一覧Projectionは記帳日時と締め状態を保持しないため、
+/// 仕訳イベントを再生して記帳記録と締め済の仕訳を求める。
+pub struct PeriodReopenQueryServiceImpl {
+    event_store: Arc<EventStore>,
+}
+
+impl PeriodReopenQueryServiceImpl {
+    /// 新しいインスタンスを作成
+    pub fn new(event_store: Arc<EventStore>) -> Self {
+        Self { event_store }
+    }
+
+    /// 仕訳イベントを再生し、仕訳ごとの状態と記帳記録を求める
+    async fn replay_journal_entries(
+        &self,
+    ) -> ApplicationResult<(HashMap<String, EntryState>, Vec<PostingRecord>)> {
+        let events = self
+            .event_store
+            .get_all_events(0)
+            .await
+            .map_err(|e| ApplicationError::EventStoreError(e.to_string()))?;
+
+        let mut entries: HashMap<String, EntryState> = HashMap::new();
+        let mut postings = Vec::new();
+
+        for stored_event in events.iter() {
+            let Ok(event) = serde_json::from_slice::<JournalEntryEvent>(&stored_event.payload)
+            else {
+                continue;
+            };
+
+            match event {
+                JournalEntryEvent::DraftCreated { entry_id, transaction_date, .. }
+                | JournalEntryEvent::DraftUpdated {
+                    entry_id,
+                    transaction_date: Some(transaction_date),
+                    ..
+                } => {
+                    entries.entry(entry_id).or_default().transaction_date = Some(transaction_date);
+                }
+                JournalEntryEvent::Posted { entry_id, entry_number, posted_by, posted_at } => {
+                    entries.entry(entry_id.clone()).or_default().entry_number =
+                        Some(entry_number.clone());
+                    postings.push(PostingRecord {
+                        entry_id,
+                        entry_number: Some(entry_number),
+                        transaction_date: String::new(),
+                        kind: PostingKind::Posted,
+                        recorded_by: posted_by,
+                        recorded_at: posted_at,
+                    });
+                }
+                JournalEntryEvent::Reversed {
+                    entry_id,
+                    original_id,
+                    reversed_by,
+                    reversed_at,
+                    ..
+                } => {
+                    let transaction_date =
+                        entries.get(&original_id).and_then(|state| state.transaction_date.clone());
+                    entries.entry(entry_id.clone()).or_default().transaction_date =
+                        transaction_date;
+                    postings.push(PostingRecord {
+                        entry_id,
+                        entry_number: None,
+                        transaction_date: String::new(),
+                        kind: PostingKind::Reversed,
+                        recorded_by: reversed_by,
+                        recorded_at: reversed_at,
+                    });
+                }
+                JournalEntryEvent::Corrected {
+                    entry_id,
+                    reversed_id,
+                    corrected_by,
+                    corrected_at,
+                    ..
+                } => {
+                    let transaction_date =
+                        entries.get(&reversed_id).and_then(|state| state.transaction_date.clone());
+                    let state = entries.entry(entry_id.clone()).or_default();
+                    if state.transaction_date.is_none() {
+                        state.transaction_date = transaction_date;
+                    }
+                    postings.push(PostingRecord {
+                        entry_id,
+                        entry_number: None,
+                        transaction_date: String::new(),
+                        kind: PostingKind::Corrected,
+                        recorded_by: corrected_by,
+                        recorded_at: corrected_at,
+                    });
+                }
+                JournalEntryEvent::Closed { entry_id, .. } => {
+                    entries.entry(entry_id).or_default().closed = true;
+                }
+                JournalEntryEvent::Reopened { entry_id, .. } => {
+                    entries.entry(entry_id).or_default().closed = false;
+                }
+                _ => {}
+            }
+        }
+
+        // 取引日付・伝票番号は再生後の仕訳の状態で埋める
+        for posting in &mut postings {
+            if let Some(state) = entries.get(&posting.entry_id) {
+                posting.transaction_date = state.transaction_date.clone().unwrap_or_default();
+                if posting.entry_number.is_none() {
+                    posting.entry_number = state.entry_number.clone();
+                }
+            }
+        }
+
+        Ok((entries, postings))
+    }
+}
+
+/// 取引日付（YYYY-MM-DD）が範囲内か
+fn within(transaction_date: &str, from: NaiveDate, to: NaiveDate) -> bool {
+    NaiveDate::parse_from_str(transaction_date, "%Y-%m-%d")
+        .is_ok_and(|date| date >= from && date <= to)
+}
+
+impl PeriodReopenQueryService for PeriodReopenQueryServiceImpl {
+    async fn load_period_events(
+        &self,
+        period_id: &str,
+    ) -> ApplicationResult<Vec<AccountingPeriodEvent>> {
+        let events = self
+            .event_store
+            .get_events(period_id)
+            .await
+            .map_err(|e| ApplicationError::EventStoreError(e.to_string()))?;
+
+        Ok(events
+            .iter()
+            .filter_map(|stored_event| serde_json::from_slice(&stored_event.payload).ok())
+            .collect())
+    }
+
+    async fn find_closed_entry_ids(
+        &self,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> ApplicationResult<Vec<String>> {
+        let (entries, _) = self.replay_journal_entries().await?;
+        let mut entry_ids: Vec<String> = entries
+            .into_iter()
+            .filter(|(_, state)| {
+                state.closed
+                    && state.transaction_date.as_deref().is_some_and(|date| within(date, from, to))
+            })
+            .map(|(entry_id, _)| entry_id)
+            .collect();
+        entry_ids.sort();
+        Ok(entry_ids)
+    }
+
+    async fn load_postings(
+        &self,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> ApplicationResult<Vec<PostingRecord>> {
+        let (_, postings) = self.replay_journal_entries().await?;
+        Ok(postings
+            .into_iter()
+            .filter(|posting| within(&posting.transaction_date, from, to))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use tempfile::TempDir;
+
+    use super::*;
+
+    fn draft(entry_id: &str, transaction_date: &str) -> JournalEntryEvent {
+        JournalEntryEvent::DraftCreated {
+            entry_id: entry_id.to_string(),
+            transaction_date: transaction_date.to_string(),
+            voucher_number: format!("V-{}", entry_id),
+            lines: vec![],
+            description: None,
+            created_by: "clerk".to_string(),
+            created_at: Utc::now(),
+        }
+    }
+
+    fn posted(entry_id: &str) -> JournalEntryEvent {
+        JournalEntryEvent::Posted {
+            entry_id: entry_id.to_string(),
+            entry_number: format!("JE-{}", entry_id),
+            posted_by: "manager".to_string(),
+            posted_at: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_postings_and_closed_entries_within_period() {
+        let temp_dir = TempDir::new().unwrap();
+        let event_store = Arc::new(EventStore::new(temp_dir.path()).await.unwrap());
+
+        event_store
+            .append(
+                "je-march",
+                vec![
+                    draft("je-march", "2024-03-31"),
+                    posted("je-march"),
+                    JournalEntryEvent::Closed {
+                        entry_id: "je-march".to_string(),
+                        closed_by: "manager".to_string(),
+                        closed_at: Utc::now(),
+                    },
+                ],
+            )
+            .await
+            .unwrap();
+        event_store
+            .append("je-april", vec![draft("je-april", "2024-04-01"), posted("je-april")])
+            .await
+            .unwrap();
+        event_store
+            .append(
+                "2024-03",
+                vec![AccountingPeriodEvent::PeriodLocked {
+                    period_id: "2024-03".to_string(),
+                    locked_by: "manager".to_string(),
+                    locked_at: Utc::now().to_rfc3339(),
+                }],
+            )
+            .await
+            .unwrap();
+
+        let service = PeriodReopenQueryServiceImpl::new(event_store);
+        let from = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+        let to = NaiveDate::from_ymd_opt(2024, 3, 31).unwrap();
+
+        let postings = service.load_postings(from, to).await.unwrap();
+        assert_eq!(postings.len(), 1);
+        assert_eq!(postings[0].entry_id, "je-march");
+        assert_eq!(postings[0].transaction_date, "2024-03-31");
+        assert_eq!(postings[0].entry_number.as_deref(), Some("JE-je-march"));
+
+        let closed = service.find_closed_entry_ids(from, to).await.unwrap();
+        assert_eq!(closed, vec!["je-march".to_string()]);
+
+        let period_events = service.load_period_events("2024-03").await.unwrap();
+        assert!(matches!(period_events[..], [AccountingPeriodEvent::PeriodLocked { .. }]));
+    }
+}
@@ -0,0 +1,198 @@
+// ProjectionCache - イベントストリームから増分構築するインメモリProjection
+// 照会のたびに全イベントを再生せず、前回以降のイベントのみを適用する
+
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+
+use javelin_application::{
+    error::{ApplicationError, ApplicationResult},
+    query_service::WarmUpProgress,
+};
+use javelin_domain::financial_close::journal_entry::events::JournalEntryEvent;
+
+use crate::{EventStore, projection_trait::Apply};
+
+/// 構築済みProjectionと次に適用するシーケンス番号
+struct CachedProjection<P> {
+    projection: P,
+    next_sequence: u64,
+}
+
+/// 増分構築するProjectionキャッシュ
+///
+/// 構築は排他的に行い、構築中に届いた照会は構築の完了を待って結果を共有する。
+pub struct ProjectionCache<P> {
+    state: tokio::sync::Mutex<CachedProjection<P>>,
+    processed: AtomicU64,
+    total: AtomicU64,
+    in_progress: AtomicBool,
+}
+
+impl<P> ProjectionCache<P>
+where
+    P: Apply<JournalEntryEvent> + Clone,
+{
+    /// 空のProjectionからキャッシュを作成
+    pub fn new(projection: P) -> Self {
+        Self {
+            state: tokio::sync::Mutex::new(CachedProjection { projection, next_sequence: 0 }),
+            processed: AtomicU64::new(0),
+            total: AtomicU64::new(0),
+            in_progress: AtomicBool::new(false),
+        }
+    }
+
+    /// 未適用のイベントを適用して最新のProjectionを返す
+    pub async fn refresh(&self, event_store: &EventStore) -> ApplicationResult<P> {
+        let state = self.latest(event_store).await?;
+        Ok(state.projection.clone())
+    }
+
+    /// 未適用のイベントを適用した最新のProjectionを変更する
+    ///
+    /// イベントに由来しない導出データ（月初の繰越残高など）の追加に使用する。
+    pub async fn update<R>(
+        &self,
+        event_store: &EventStore,
+        f: impl FnOnce(&mut P) -> R,
+    ) -> ApplicationResult<R> {
+        let mut state = self.latest(event_store).await?;
+        Ok(f(&mut state.projection))
+    }
+
+    /// 未適用のイベントを適用し、構築済みの状態をロックしたまま返す
+    async fn latest(
+        &self,
+        event_store: &EventStore,
+    ) -> ApplicationResult<tokio::sync::MutexGuard<'_, CachedProjection<P>>> {
+        let mut state = self.state.lock().await;
+
+        let latest_sequence = event_store
+            .get_latest_sequence()
+            .await
+            .map_err(|e| ApplicationError::ProjectionDatabaseError(e.to_string()))?
+            .as_u64();
+        if latest_sequence < state.next_sequence {
+            return Ok(state);
+        }
+
+        let start = state.next_sequence.max(1);
+        self.processed.store(0, Ordering::Relaxed);
+        self.total.store(latest_sequence + 1 - start, Ordering::Relaxed);
+        self.in_progress.store(true, Ordering::Relaxed);
+
+        let result = self.apply_from(&mut state, event_store).await;
+        self.in_progress.store(false, Ordering::Relaxed);
+        result?;
+
+        Ok(state)
+    }
+
+    /// 現在の構築進捗
+    pub fn progress(&self) -> WarmUpProgress {
+        WarmUpProgress {
+            processed: self.processed.load(Ordering::Relaxed),
+            total: self.total.load(Ordering::Relaxed),
+            in_progress: self.in_progress.load(Ordering::Relaxed),
+        }
+    }
+
+    async fn apply_from(
+        &self,
+        state: &mut CachedProjection<P>,
+        event_store: &EventStore,
+    ) -> ApplicationResult<()> {
+        let start = state.next_sequence.max(1);
+        let events = event_store
+            .get_all_events(state.next_sequence)
+            .await
+            .map_err(|e| ApplicationError::ProjectionDatabaseError(e.to_string()))?;
+
+        for stored_event in events.iter() {
+            if let Ok(event) = serde_json::from_slice::<JournalEntryEvent>(&stored_event.payload) {
+                state
+                    .projection
+                    .apply(event)
+                    .map_err(|e| ApplicationError::ProjectionDatabaseError(e.to_string()))?;
+            }
+            // 適用に成功したイベントまでを構築済みとする
+            state.next_sequence = stored_event.global_sequence + 1;
+            self.processed
+                .store((stored_event.global_sequence + 1).saturating_sub(start), Ordering::Relaxed);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use javelin_domain::{
+        financial_close::journal_entry::events::JournalEntryLineDto, repositories::EventRepository,
+    };
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::queries::ledger_projection::LedgerProjection;
+
+    fn line(line_number: u32, side: &str, account_code: &str) -> JournalEntryLineDto {
+        JournalEntryLineDto {
+            line_number,
+            side: side.to_string(),
+            account_code: account_code.to_string(),
+            account_name: None,
+            sub_account_code: None,
+            department_code: None,
+            dimensions: Default::default(),
+            amount: 1000.0,
+            currency: "JPY".to_string(),
+            tax_type: "NonTaxable".to_string(),
+            tax_amount: 0.0,
+            description: None,
+        }
+    }
+
+    async fn append_posted_entry(event_store: &EventStore, index: u32) {
+        let entry_id = format!("JE{:03}", index);
+        let events = vec![
+            JournalEntryEvent::DraftCreated {
+                entry_id: entry_id.clone(),
+                transaction_date: "2024-01-15".to_string(),
+                voucher_number: format!("V-{:03}", index),
+                lines: vec![line(1, "Debit", "1100"), line(2, "Credit", "4000")],
+                description: None,
+                created_by: "test_user".to_string(),
+                created_at: chrono::Utc::now(),
+            },
+            JournalEntryEvent::Posted {
+                entry_id: entry_id.clone(),
+                entry_number: format!("EN-2024-{:03}", index),
+                posted_by: "approver".to_string(),
+                posted_at: chrono::Utc::now(),
+            },
+        ];
+        EventRepository::append_events(event_store, &entry_id, events).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_refresh_applies_only_new_events() {
+        let temp_dir = TempDir::new().unwrap();
+        let event_store = Arc::new(EventStore::new(temp_dir.path()).await.unwrap());
+        let cache = ProjectionCache::new(LedgerProjection::new());
+
+        append_posted_entry(&event_store, 1).await;
+        let projection = cache.refresh(&event_store).await.unwrap();
+        assert_eq!(projection.entries().len(), 2);
+        assert_eq!(cache.progress(), WarmUpProgress { processed: 2, total: 2, in_progress: false });
+
+        // 変更がなければ再適用しない
+        let projection = cache.refresh(&event_store).await.unwrap();
+        assert_eq!(projection.entries().len(), 2);
+
+        append_posted_entry(&event_store, 2).await;
+        let projection = cache.refresh(&event_store).await.unwrap();
+        assert_eq!(projection.entries().len(), 4);
+        assert_eq!(cache.progress(), WarmUpProgress { processed: 2, total: 2, in_progress: false });
+    }
+}
@@ -0,0 +1,113 @@
+// ProjectionConsistencyQueryServiceImpl - Projection間整合性検証用照会サービス実装
+// 同一のイベント列から仕訳一覧Projectionと元帳Projectionを構築する
+
+use std::sync::Arc;
+
+use javelin_application::{
+    error::{ApplicationError, ApplicationResult},
+    query_service::{
+        JournalProjectionEntry, JournalProjectionLine, LedgerProjectionMovement,
+        ProjectionConsistencyQueryService, ProjectionConsistencySnapshot,
+    },
+};
+use javelin_domain::financial_close::journal_entry::events::JournalEntryEvent;
+
+use crate::{
+    EventStore,
+    projection_trait::Apply,
+    queries::{
+        journal_entry_search_projection::JournalEntrySearchProjection,
+        ledger_projection::LedgerProjection,
+    },
+};
+
+/// ProjectionConsistencyQueryService実装
+///
+/// EventStoreの全イベントを一度だけ取得し、両Projectionへ適用する。
+/// 同時に記帳・取消イベントの番号を収集する。
+pub struct ProjectionConsistencyQueryServiceImpl {
+    event_store: Arc<EventStore>,
+}
+
+impl ProjectionConsistencyQueryServiceImpl {
+    /// 新しいインスタンスを作成
+    pub fn new(event_store: Arc<EventStore>) -> Self {
+        Self { event_store }
+    }
+}
+
+impl ProjectionConsistencyQueryService for ProjectionConsistencyQueryServiceImpl {
+    async fn load_snapshot(&self) -> ApplicationResult<ProjectionConsistencySnapshot> {
+        let events = self
+            .event_store
+            .get_all_events(0)
+            .await
+            .map_err(|e| ApplicationError::ProjectionDatabaseError(e.to_string()))?;
+
+        let mut ledger = LedgerProjection::new();
+        let mut search = JournalEntrySearchProjection::new();
+        let mut posted_entry_numbers = Vec::new();
+        let mut reversal_entry_ids = Vec::new();
+
+        for stored_event in events.iter() {
+            let Ok(event) = serde_json::from_slice::<JournalEntryEvent>(&stored_event.payload)
+            else {
+                continue;
+            };
+
+            match &event {
+                JournalEntryEvent::Posted { entry_number, .. } => {
+                    posted_entry_numbers.push(entry_number.clone());
+                }
+                JournalEntryEvent::Reversed { entry_id, .. } => {
+                    reversal_entry_ids.push(entry_id.clone());
+                }
+                _ => {}
+            }
+
+            ledger
+                .apply(event.clone())
+                .map_err(|e| ApplicationError::ProjectionDatabaseError(e.to_string()))?;
+            search
+                .apply(event)
+                .map_err(|e| ApplicationError::ProjectionDatabaseError(e.to_string()))?;
+        }
+
+        let journal_entries = search
+            .entries()
+            .iter()
+            .map(|entry| JournalProjectionEntry {
+                entry_id: entry.entry_id.clone(),
+                entry_number: entry.entry_number.clone(),
+                status: entry.status.clone(),
+                lines: entry
+                    .lines
+                    .iter()
+                    .map(|line| JournalProjectionLine {
+                        account_code: line.account_code.clone(),
+                        side: line.side.clone(),
+                        amount: line.amount,
+                    })
+                    .collect(),
+            })
+            .collect();
+
+        let ledger_movements = ledger
+            .entries()
+            .iter()
+            .map(|entry| LedgerProjectionMovement {
+                account_code: entry.account_code.clone(),
+                entry_number: entry.entry_number.clone(),
+                debit_amount: entry.debit_amount,
+                credit_amount: entry.credit_amount,
+            })
+            .collect();
+
+        Ok(ProjectionConsistencySnapshot {
+            journal_entries,
+            ledger_movements,
+            posted_entry_numbers,
+            reversal_entry_ids,
+        })
+    }
+}
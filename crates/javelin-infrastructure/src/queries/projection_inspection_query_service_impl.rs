@@ -0,0 +1,45 @@
+// ProjectionInspectionQueryServiceImpl - Projectionの生データ照会サービス実装
+// ProjectionDbのstateテーブルをキー前方一致で走査する
+
+use std::sync::Arc;
+
+use javelin_application::{
+    error::{ApplicationError, ApplicationResult},
+    query_service::{ProjectionInspectionQueryService, ProjectionRecord},
+};
+
+use crate::ProjectionDb;
+
+/// ProjectionInspectionQueryService実装
+///
+/// 読み取りトランザクションのみを使用するため、起票中のプロセスを止めずに参照できる。
+pub struct ProjectionInspectionQueryServiceImpl {
+    projection_db: Arc<ProjectionDb>,
+}
+
+impl ProjectionInspectionQueryServiceImpl {
+    /// 新しいインスタンスを作成
+    pub fn new(projection_db: Arc<ProjectionDb>) -> Self {
+        Self { projection_db }
+    }
+}
+
+impl ProjectionInspectionQueryService for ProjectionInspectionQueryServiceImpl {
+    async fn scan(
+        &self,
+        prefix: &str,
+        after: Option<&str>,
+        limit: usize,
+    ) -> ApplicationResult<Vec<ProjectionRecord>> {
+        let records = self
+            .projection_db
+            .scan_projections(prefix, after, limit)
+            .await
+            .map_err(|e| ApplicationError::ProjectionDatabaseError(e.to_string()))?;
+
+        Ok(records
+            .into_iter()
+            .map(|(key, value)| ProjectionRecord { key, value })
+            .collect())
+    }
+}
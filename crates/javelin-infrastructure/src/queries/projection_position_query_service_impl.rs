@@ -0,0 +1,173 @@
+// ProjectionPositionQueryServiceImpl - Projectionごとの反映位置の照会サービス実装
+// ProjectionDbのチェックポイントとEventStoreの最新イベント番号を突き合わせる
+
+use std::sync::Arc;
+
+use javelin_application::{
+    error::{ApplicationError, ApplicationResult},
+    query_service::{ProjectionPositionDiagnostics, ProjectionPositionQueryService},
+};
+
+use crate::{EventStore, ProjectionDb, error::InfrastructureError};
+
+/// 常に照会対象とするProjection（未反映でチェックポイントがない場合も含める）
+const REGISTERED_PROJECTIONS: &[(&str, u32)] = &[("main", 1)];
+
+/// ProjectionPositionQueryService実装
+///
+/// チェックポイントが保存されているProjectionと、登録済みのProjectionを対象とする。
+pub struct ProjectionPositionQueryServiceImpl {
+    event_store: Arc<EventStore>,
+    projection_db: Arc<ProjectionDb>,
+}
+
+impl ProjectionPositionQueryServiceImpl {
+    /// 新しいインスタンスを作成
+    pub fn new(event_store: Arc<EventStore>, projection_db: Arc<ProjectionDb>) -> Self {
+        Self { event_store, projection_db }
+    }
+}
+
+impl ProjectionPositionQueryService for ProjectionPositionQueryServiceImpl {
+    async fn get_projection_positions(
+        &self,
+    ) -> ApplicationResult<Vec<ProjectionPositionDiagnostics>> {
+        let latest_sequence = self
+            .event_store
+            .get_latest_sequence()
+            .await
+            .map_err(|e| ApplicationError::EventStoreError(e.to_string()))?
+            .as_u64();
+
+        let mut diagnostics: Vec<ProjectionPositionDiagnostics> = self
+            .projection_db
+            .list_positions()
+            .await
+            .map_err(projection_error)?
+            .into_iter()
+            .map(|position| ProjectionPositionDiagnostics {
+                projection_name: position.projection_name,
+                projection_version: position.projection_version,
+                position: position.last_processed_sequence,
+                latest_sequence,
+                high_water_mark: 0,
+                updated_at: Some(position.updated_at),
+            })
+            .collect();
+        for (name, version) in REGISTERED_PROJECTIONS {
+            if !diagnostics
+                .iter()
+                .any(|d| d.projection_name == *name && d.projection_version == *version)
+            {
+                diagnostics.push(ProjectionPositionDiagnostics {
+                    projection_name: name.to_string(),
+                    projection_version: *version,
+                    position: 0,
+                    latest_sequence,
+                    high_water_mark: 0,
+                    updated_at: None,
+                });
+            }
+        }
+
+        for item in diagnostics.iter_mut() {
+            item.high_water_mark = self
+                .projection_db
+                .get_high_water_mark(&item.projection_name, item.projection_version)
+                .await
+                .map_err(projection_error)?;
+        }
+        diagnostics.sort_by(|a, b| {
+            (&a.projection_name, a.projection_version)
+                .cmp(&(&b.projection_name, b.projection_version))
+        });
+        Ok(diagnostics)
+    }
+
+    async fn record_high_water_marks(&self) -> ApplicationResult<()> {
+        let positions = self.projection_db.list_positions().await.map_err(projection_error)?;
+        for position in positions {
+            self.projection_db
+                .record_high_water_mark(
+                    &position.projection_name,
+                    position.projection_version,
+                    position.last_processed_sequence,
+                )
+                .await
+                .map_err(projection_error)?;
+        }
+        Ok(())
+    }
+}
+
+fn projection_error(e: InfrastructureError) -> ApplicationError {
+    ApplicationError::ProjectionDatabaseError(e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use javelin_application::query_service::ProjectionPositionStatus;
+    use javelin_domain::{
+        financial_close::journal_entry::events::JournalEntryEvent, repositories::EventRepository,
+    };
+    use tempfile::TempDir;
+
+    use super::*;
+
+    fn draft(entry_id: &str) -> JournalEntryEvent {
+        JournalEntryEvent::DraftCreated {
+            entry_id: entry_id.to_string(),
+            transaction_date: "2024-04-01".to_string(),
+            voucher_number: format!("V-{}", entry_id),
+            lines: vec![],
+            description: None,
+            created_by: "clerk".to_string(),
+            created_at: chrono::Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reports_lag_ahead_and_regression_per_projection() {
+        let temp_dir = TempDir::new().unwrap();
+        let event_store = Arc::new(EventStore::new(&temp_dir.path().join("events")).await.unwrap());
+        let projection_db =
+            Arc::new(ProjectionDb::new(&temp_dir.path().join("projections")).await.unwrap());
+        for entry_id in ["JE001", "JE002", "JE003"] {
+            EventRepository::append_events(&*event_store, entry_id, vec![draft(entry_id)])
+                .await
+                .unwrap();
+        }
+        let service =
+            ProjectionPositionQueryServiceImpl::new(event_store, Arc::clone(&projection_db));
+
+        // 未反映のmainもチェックポイントなしで一覧に含まれる
+        let diagnostics = service.get_projection_positions().await.unwrap();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].key(), "main:v1");
+        assert_eq!(diagnostics[0].lag(), 3);
+        assert_eq!(diagnostics[0].updated_at, None);
+
+        let empty = Vec::<(String, Vec<u8>)>::new;
+        projection_db.update_projection_batch("main", 1, empty(), 3).await.unwrap();
+        projection_db
+            .update_projection_batch("journal_entries", 1, empty(), 5)
+            .await
+            .unwrap();
+        service.record_high_water_marks().await.unwrap();
+        // mainの反映位置が記録済みの最高到達点より後退
+        projection_db.update_projection_batch("main", 1, empty(), 2).await.unwrap();
+
+        let diagnostics = service.get_projection_positions().await.unwrap();
+        let statuses: Vec<(String, ProjectionPositionStatus)> =
+            diagnostics.iter().map(|d| (d.key(), d.status())).collect();
+        assert_eq!(
+            statuses,
+            vec![
+                ("journal_entries:v1".to_string(), ProjectionPositionStatus::AheadOfEventStore),
+                ("main:v1".to_string(), ProjectionPositionStatus::Regressed),
+            ]
+        );
+        assert_eq!(diagnostics[1].high_water_mark, 3);
+        assert!(diagnostics[1].updated_at.is_some());
+    }
+}
@@ -0,0 +1,104 @@
+// SequenceAuditQueryServiceImpl - 番号連続性監査用照会サービス実装
+// 仕訳一覧Projectionに証憑番号を付加して採番済みの仕訳を取得する
+
+use std::{collections::HashMap, sync::Arc};
+
+use javelin_application::{
+    error::{ApplicationError, ApplicationResult},
+    query_service::{NumberedEntry, SequenceAuditQueryService, VoidedNumber},
+};
+use javelin_domain::financial_close::journal_entry::{
+    events::JournalEntryEvent, services::VoucherNumberGenerator,
+};
+
+use crate::{
+    EventStore, projection_trait::Apply,
+    queries::journal_entry_search_projection::JournalEntrySearchProjection,
+    services::VoucherNumberGeneratorImpl,
+};
+
+/// SequenceAuditQueryService実装
+///
+/// 仕訳一覧Projectionは証憑番号を保持しないため、
+/// 起票・下書き更新イベントから仕訳ごとの最新の証憑番号を収集する。
+/// 取り消された証憑番号は採番サービスから取得する。
+pub struct SequenceAuditQueryServiceImpl {
+    event_store: Arc<EventStore>,
+    voucher_generator: Arc<VoucherNumberGeneratorImpl>,
+}
+
+impl SequenceAuditQueryServiceImpl {
+    /// 新しいインスタンスを作成
+    pub fn new(
+        event_store: Arc<EventStore>,
+        voucher_generator: Arc<VoucherNumberGeneratorImpl>,
+    ) -> Self {
+        Self { event_store, voucher_generator }
+    }
+}
+
+impl SequenceAuditQueryService for SequenceAuditQueryServiceImpl {
+    async fn load_numbered_entries(&self) -> ApplicationResult<Vec<NumberedEntry>> {
+        let events = self
+            .event_store
+            .get_all_events(0)
+            .await
+            .map_err(|e| ApplicationError::ProjectionDatabaseError(e.to_string()))?;
+
+        let mut search = JournalEntrySearchProjection::new();
+        let mut voucher_numbers: HashMap<String, String> = HashMap::new();
+
+        for stored_event in events.iter() {
+            let Ok(event) = serde_json::from_slice::<JournalEntryEvent>(&stored_event.payload)
+            else {
+                continue;
+            };
+
+            match &event {
+                JournalEntryEvent::DraftCreated { entry_id, voucher_number, .. }
+                | JournalEntryEvent::DraftUpdated {
+                    entry_id,
+                    voucher_number: Some(voucher_number),
+                    ..
+                } => {
+                    voucher_numbers.insert(entry_id.clone(), voucher_number.clone());
+                }
+                _ => {}
+            }
+
+            search
+                .apply(event)
+                .map_err(|e| ApplicationError::ProjectionDatabaseError(e.to_string()))?;
+        }
+
+        Ok(search
+            .entries()
+            .iter()
+            .map(|entry| NumberedEntry {
+                entry_id: entry.entry_id.clone(),
+                transaction_date: entry.transaction_date.clone(),
+                voucher_number: voucher_numbers.get(&entry.entry_id).cloned(),
+                entry_number: entry.entry_number.clone(),
+                status: entry.status.clone(),
+            })
+            .collect())
+    }
+
+    async fn load_voided_numbers(&self) -> ApplicationResult<Vec<VoidedNumber>> {
+        let voided = self
+            .voucher_generator
+            .voided_numbers(None)
+            .await
+            .map_err(|e| ApplicationError::QueryExecutionFailed(e.to_string()))?;
+
+        Ok(voided
+            .into_iter()
+            .map(|v| VoidedNumber {
+                number: v.voucher_number,
+                fiscal_year: v.fiscal_year,
+                reason: v.reason,
+                voided_at: v.voided_at.to_rfc3339(),
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,358 @@
+// SuspenseAgingProjection - 仮勘定の滞留状況Projection
+// 記帳済の仮勘定計上を未整理残高として追跡し、整理先の候補となる明細を保持する
+
+use std::collections::HashMap;
+
+use javelin_application::query_service::{ClearingCandidate, OpenSuspenseItem};
+use javelin_domain::financial_close::journal_entry::events::{
+    JournalEntryEvent, JournalEntryLineDto,
+};
+
+use crate::{error::InfrastructureResult, projection_trait::Apply};
+
+/// 金額一致の許容誤差
+const AMOUNT_TOLERANCE: f64 = 0.005;
+
+/// 記帳済の仮勘定以外の明細（整理先の候補）
+#[derive(Debug, Clone)]
+struct PostedLine {
+    candidate: ClearingCandidate,
+    side: String,
+    amount: f64,
+    currency: String,
+}
+
+/// 仮勘定の滞留状況Projection
+///
+/// 仮勘定の計上は、同一勘定科目・同一通貨の反対側の計上で古い順に消し込む。
+/// 同額の未整理計上があればそれを優先する。
+#[derive(Debug, Clone)]
+pub struct SuspenseAgingProjection {
+    suspense_codes: Vec<String>,
+    /// 未整理の仮勘定計上（計上順）
+    open_items: Vec<OpenSuspenseItem>,
+    posted_lines: Vec<PostedLine>,
+    // 仕訳明細をキャッシュ（entry_id -> (transaction_date, lines)）
+    entry_cache: HashMap<String, (String, Vec<JournalEntryLineDto>)>,
+}
+
+impl SuspenseAgingProjection {
+    /// 仮勘定として扱う勘定科目コードを指定して作成
+    pub fn new(suspense_codes: &[&str]) -> Self {
+        Self {
+            suspense_codes: suspense_codes.iter().map(|code| code.to_string()).collect(),
+            open_items: Vec::new(),
+            posted_lines: Vec::new(),
+            entry_cache: HashMap::new(),
+        }
+    }
+
+    /// 未整理の仮勘定計上と整理先の候補を取得
+    ///
+    /// 整理先の候補は、計上日以降に記帳された同額・同一通貨で、
+    /// 仮勘定計上と反対側の明細とする（例: 仮払の後に計上された買掛金）。
+    pub fn open_items(&self) -> Vec<OpenSuspenseItem> {
+        self.open_items
+            .iter()
+            .map(|item| {
+                let mut item = item.clone();
+                item.candidates = self
+                    .posted_lines
+                    .iter()
+                    .filter(|line| {
+                        line.candidate.transaction_date >= item.transaction_date
+                            && line.currency == item.currency
+                            && line.side != item.side
+                            && (line.amount - item.amount).abs() < AMOUNT_TOLERANCE
+                    })
+                    .map(|line| line.candidate.clone())
+                    .collect();
+                item
+            })
+            .collect()
+    }
+
+    fn is_suspense(&self, account_code: &str) -> bool {
+        self.suspense_codes.iter().any(|code| code == account_code)
+    }
+
+    /// 記帳済の明細を反映
+    ///
+    /// `reverse_of` を指定した場合は取消として貸借を逆転し、
+    /// 取消元の仕訳の未整理計上から優先して消し込む。
+    fn post(
+        &mut self,
+        entry_id: &str,
+        entry_number: &str,
+        transaction_date: &str,
+        lines: &[JournalEntryLineDto],
+        reverse_of: Option<&str>,
+    ) {
+        let has_suspense = lines.iter().any(|line| self.is_suspense(&line.account_code));
+
+        if let Some(original_id) = reverse_of {
+            self.posted_lines.retain(|line| line.candidate.entry_id != original_id);
+        }
+
+        for line in lines {
+            let side = match (reverse_of, line.side.as_str()) {
+                (Some(_), "Debit") => "Credit".to_string(),
+                (Some(_), "Credit") => "Debit".to_string(),
+                (_, side) => side.to_string(),
+            };
+
+            if self.is_suspense(&line.account_code) {
+                let remaining = self.clear(line, &side, reverse_of);
+                if remaining >= AMOUNT_TOLERANCE {
+                    self.open_items.push(OpenSuspenseItem {
+                        entry_id: entry_id.to_string(),
+                        entry_number: entry_number.to_string(),
+                        line_number: line.line_number,
+                        transaction_date: transaction_date.to_string(),
+                        account_code: line.account_code.clone(),
+                        side,
+                        amount: remaining,
+                        currency: line.currency.clone(),
+                        description: line.description.clone(),
+                        candidates: Vec::new(),
+                    });
+                }
+            } else if !has_suspense && reverse_of.is_none() {
+                self.posted_lines.push(PostedLine {
+                    candidate: ClearingCandidate {
+                        entry_id: entry_id.to_string(),
+                        entry_number: entry_number.to_string(),
+                        line_number: line.line_number,
+                        transaction_date: transaction_date.to_string(),
+                        account_code: line.account_code.clone(),
+                        description: line.description.clone(),
+                    },
+                    side,
+                    amount: line.amount,
+                    currency: line.currency.clone(),
+                });
+            }
+        }
+    }
+
+    /// 反対側の未整理計上を消し込み、消し込めなかった残額を返す
+    fn clear(&mut self, line: &JournalEntryLineDto, side: &str, prefer_entry: Option<&str>) -> f64 {
+        let mut remaining = line.amount;
+
+        loop {
+            let opposite = |item: &OpenSuspenseItem| {
+                item.account_code == line.account_code
+                    && item.currency == line.currency
+                    && item.side != side
+            };
+            let target = prefer_entry
+                .and_then(|entry_id| {
+                    self.open_items.iter().position(|i| opposite(i) && i.entry_id == entry_id)
+                })
+                .or_else(|| {
+                    self.open_items.iter().position(|i| {
+                        opposite(i) && (i.amount - remaining).abs() < AMOUNT_TOLERANCE
+                    })
+                })
+                .or_else(|| self.open_items.iter().position(opposite));
+            let Some(index) = target else {
+                return remaining;
+            };
+
+            let item = &mut self.open_items[index];
+            if item.amount - remaining >= AMOUNT_TOLERANCE {
+                item.amount -= remaining;
+                return 0.0;
+            }
+            remaining -= item.amount;
+            self.open_items.remove(index);
+            if remaining < AMOUNT_TOLERANCE {
+                return 0.0;
+            }
+        }
+    }
+}
+
+impl Apply<JournalEntryEvent> for SuspenseAgingProjection {
+    fn apply(&mut self, event: JournalEntryEvent) -> InfrastructureResult<()> {
+        match event {
+            JournalEntryEvent::DraftCreated { entry_id, transaction_date, lines, .. } => {
+                self.entry_cache.insert(entry_id, (transaction_date, lines));
+            }
+            JournalEntryEvent::DraftUpdated { entry_id, transaction_date, lines, .. } => {
+                if let Some(cached) = self.entry_cache.get_mut(&entry_id) {
+                    if let Some(transaction_date) = transaction_date {
+                        cached.0 = transaction_date;
+                    }
+                    if let Some(lines) = lines {
+                        cached.1 = lines;
+                    }
+                }
+            }
+            JournalEntryEvent::Posted { entry_id, entry_number, .. } => {
+                if let Some((transaction_date, lines)) = self.entry_cache.get(&entry_id).cloned() {
+                    self.post(&entry_id, &entry_number, &transaction_date, &lines, None);
+                }
+            }
+            // 取消時は元の仕訳を貸借逆転で反映
+            JournalEntryEvent::Reversed { entry_id, original_id, reversed_at, .. } => {
+                if let Some((_, lines)) = self.entry_cache.get(&original_id).cloned() {
+                    self.post(
+                        &entry_id,
+                        &entry_id,
+                        &reversed_at.format("%Y-%m-%d").to_string(),
+                        &lines,
+                        Some(&original_id),
+                    );
+                }
+            }
+            JournalEntryEvent::Deleted { entry_id, .. } => {
+                self.entry_cache.remove(&entry_id);
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+
+    use super::*;
+
+    fn line(line_number: u32, side: &str, account_code: &str, amount: f64) -> JournalEntryLineDto {
+        JournalEntryLineDto {
+            line_number,
+            side: side.to_string(),
+            account_code: account_code.to_string(),
+            account_name: None,
+            sub_account_code: None,
+            department_code: None,
+            dimensions: Default::default(),
+            amount,
+            currency: "JPY".to_string(),
+            tax_type: "NonTaxable".to_string(),
+            tax_amount: 0.0,
+            description: None,
+        }
+    }
+
+    fn post(
+        projection: &mut SuspenseAgingProjection,
+        entry_id: &str,
+        date: &str,
+        lines: Vec<JournalEntryLineDto>,
+    ) {
+        projection
+            .apply(JournalEntryEvent::DraftCreated {
+                entry_id: entry_id.to_string(),
+                transaction_date: date.to_string(),
+                voucher_number: format!("V-{}", entry_id),
+                lines,
+                description: None,
+                created_by: "user1".to_string(),
+                created_at: Utc::now(),
+            })
+            .unwrap();
+        projection
+            .apply(JournalEntryEvent::Posted {
+                entry_id: entry_id.to_string(),
+                entry_number: format!("EN-{}", entry_id),
+                posted_by: "approver".to_string(),
+                posted_at: Utc::now(),
+            })
+            .unwrap();
+    }
+
+    #[test]
+    fn test_suspense_posting_is_tracked_with_candidates() {
+        let mut projection = SuspenseAgingProjection::new(&["9999"]);
+        post(
+            &mut projection,
+            "JE001",
+            "2024-01-10",
+            vec![line(1, "Debit", "9999", 5000.0), line(2, "Credit", "1000", 5000.0)],
+        );
+        // 計上前の明細は候補にならない
+        post(
+            &mut projection,
+            "JE000",
+            "2024-01-05",
+            vec![line(1, "Debit", "6000", 5000.0), line(2, "Credit", "2000", 5000.0)],
+        );
+        post(
+            &mut projection,
+            "JE002",
+            "2024-01-20",
+            vec![line(1, "Debit", "6000", 5000.0), line(2, "Credit", "2000", 5000.0)],
+        );
+
+        let items = projection.open_items();
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].entry_number, "EN-JE001");
+        assert_eq!(items[0].side, "Debit");
+        assert_eq!(items[0].candidates.len(), 1);
+        assert_eq!(items[0].candidates[0].entry_id, "JE002");
+        assert_eq!(items[0].candidates[0].account_code, "2000");
+    }
+
+    #[test]
+    fn test_clearing_entry_removes_open_item() {
+        let mut projection = SuspenseAgingProjection::new(&["9999"]);
+        post(
+            &mut projection,
+            "JE001",
+            "2024-01-10",
+            vec![line(1, "Debit", "9999", 5000.0), line(2, "Credit", "1000", 5000.0)],
+        );
+        post(
+            &mut projection,
+            "JE002",
+            "2024-01-11",
+            vec![line(1, "Debit", "9999", 3000.0), line(2, "Credit", "1000", 3000.0)],
+        );
+        // 同額の計上を優先して消し込む
+        post(
+            &mut projection,
+            "JE003",
+            "2024-01-20",
+            vec![line(1, "Debit", "2000", 3000.0), line(2, "Credit", "9999", 3000.0)],
+        );
+        // 残りは古い順に一部消し込む
+        post(
+            &mut projection,
+            "JE004",
+            "2024-01-21",
+            vec![line(1, "Debit", "2000", 2000.0), line(2, "Credit", "9999", 2000.0)],
+        );
+
+        let items = projection.open_items();
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].entry_id, "JE001");
+        assert_eq!(items[0].amount, 3000.0);
+    }
+
+    #[test]
+    fn test_reversal_removes_open_item() {
+        let mut projection = SuspenseAgingProjection::new(&["9999"]);
+        post(
+            &mut projection,
+            "JE001",
+            "2024-01-10",
+            vec![line(1, "Debit", "9999", 5000.0), line(2, "Credit", "1000", 5000.0)],
+        );
+        projection
+            .apply(JournalEntryEvent::Reversed {
+                entry_id: "JE001-R".to_string(),
+                original_id: "JE001".to_string(),
+                reason: "誤り".to_string(),
+                reversed_by: "user1".to_string(),
+                reversed_at: Utc::now(),
+            })
+            .unwrap();
+
+        assert!(projection.open_items().is_empty());
+    }
+}
@@ -0,0 +1,38 @@
+// SuspenseAgingQueryServiceImpl - 仮勘定の滞留状況照会サービス実装
+
+use std::sync::Arc;
+
+use javelin_application::{
+    error::ApplicationResult,
+    query_service::{OpenSuspenseItem, SuspenseAgingQueryService},
+};
+
+use crate::{
+    EventStore,
+    queries::{ProjectionCache, suspense_aging_projection::SuspenseAgingProjection},
+};
+
+/// SuspenseAgingQueryService実装
+///
+/// 仮勘定の滞留状況Projectionを増分構築して未整理の計上を取得する。
+pub struct SuspenseAgingQueryServiceImpl {
+    event_store: Arc<EventStore>,
+    projection_cache: ProjectionCache<SuspenseAgingProjection>,
+}
+
+impl SuspenseAgingQueryServiceImpl {
+    /// 仮勘定として扱う勘定科目コードを指定して作成
+    pub fn new(event_store: Arc<EventStore>, suspense_codes: &[&str]) -> Self {
+        Self {
+            event_store,
+            projection_cache: ProjectionCache::new(SuspenseAgingProjection::new(suspense_codes)),
+        }
+    }
+}
+
+impl SuspenseAgingQueryService for SuspenseAgingQueryServiceImpl {
+    async fn get_open_suspense_items(&self) -> ApplicationResult<Vec<OpenSuspenseItem>> {
+        let projection = self.projection_cache.refresh(&self.event_store).await?;
+        Ok(projection.open_items())
+    }
+}
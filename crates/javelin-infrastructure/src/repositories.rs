@@ -1,11 +1,48 @@
 // Repository implementations
 
 pub mod account_master_repository_impl;
+pub mod account_reconciliation_repository_impl;
+pub mod accounting_policy_repository_impl;
 pub mod application_settings_repository_impl;
+pub mod calendar_master_repository_impl;
+pub mod closing_timetable_repository_impl;
 pub mod company_master_repository_impl;
+pub mod dimension_master_repository_impl;
+pub mod financial_instrument_repository_impl;
+pub mod inventory_worksheet_repository_impl;
+pub mod job_repository_impl;
+pub mod journal_import_template_repository_impl;
+pub mod management_account_mapping_repository_impl;
+mod master_change_store;
+pub mod note_cross_reference_repository_impl;
+pub mod report_archive_repository_impl;
+pub mod report_parameter_history_repository_impl;
+pub mod statement_line_mapping_repository_impl;
 pub mod subsidiary_account_master_repository_impl;
+pub mod supplier_invoice_repository_impl;
+pub mod table_preference_repository_impl;
+pub mod user_account_repository_impl;
+pub mod variance_commentary_repository_impl;
 
 pub use account_master_repository_impl::AccountMasterRepositoryImpl;
+pub use account_reconciliation_repository_impl::AccountReconciliationRepositoryImpl;
+pub use accounting_policy_repository_impl::AccountingPolicyRepositoryImpl;
 pub use application_settings_repository_impl::ApplicationSettingsRepositoryImpl;
+pub use calendar_master_repository_impl::CalendarMasterRepositoryImpl;
+pub use closing_timetable_repository_impl::ClosingTimetableRepositoryImpl;
 pub use company_master_repository_impl::CompanyMasterRepositoryImpl;
+pub use dimension_master_repository_impl::DimensionMasterRepositoryImpl;
+pub use financial_instrument_repository_impl::FinancialInstrumentRepositoryImpl;
+pub use inventory_worksheet_repository_impl::InventoryWorksheetRepositoryImpl;
+pub use job_repository_impl::JobRepositoryImpl;
+pub use journal_import_template_repository_impl::JournalImportTemplateRepositoryImpl;
+pub use management_account_mapping_repository_impl::ManagementAccountMappingRepositoryImpl;
+pub use note_cross_reference_repository_impl::NoteCrossReferenceRepositoryImpl;
+pub use report_archive_repository_impl::ReportArchiveRepositoryImpl;
+pub use report_parameter_history_repository_impl::ReportParameterHistoryRepositoryImpl;
+pub use statement_line_mapping_repository_impl::StatementLineMappingRepositoryImpl;
 pub use subsidiary_account_master_repository_impl::SubsidiaryAccountMasterRepositoryImpl;
+pub use supplier_invoice_repository_impl::SupplierInvoiceRepositoryImpl;
+pub use table_preference_repository_impl::TablePreferenceRepositoryImpl;
+pub use user_account_repository_impl::UserAccountRepositoryImpl;
+pub use variance_commentary_repository_impl::VarianceCommentaryRepositoryImpl;
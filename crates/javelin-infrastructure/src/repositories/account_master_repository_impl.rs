@@ -4,12 +4,14 @@ use std::{path::Path, sync::Arc};
 
 use javelin_domain::{
     error::DomainResult,
-    masters::{AccountCode, AccountMaster, AccountName, AccountType},
+    masters::{AccountCode, AccountMaster, AccountName, AccountType, MasterChange},
     repositories::AccountMasterRepository,
 };
 use lmdb::{Cursor, Database, DatabaseFlags, Environment, Transaction};
 use serde::{Deserialize, Serialize};
 
+use super::master_change_store::{self, StoredMasterChange};
+
 #[derive(Debug, Serialize, Deserialize)]
 struct StoredAccountMaster {
     code: String,
@@ -21,6 +23,8 @@ struct StoredAccountMaster {
 pub struct AccountMasterRepositoryImpl {
     env: Arc<Environment>,
     db: Database,
+    /// 変更イベント
+    change_db: Database,
 }
 
 impl AccountMasterRepositoryImpl {
@@ -29,11 +33,12 @@ impl AccountMasterRepositoryImpl {
             tokio::fs::create_dir_all(path).await?;
         }
 
-        let env = Environment::new().set_max_dbs(1).set_map_size(50 * 1024 * 1024).open(path)?;
+        let env = Environment::new().set_max_dbs(2).set_map_size(50 * 1024 * 1024).open(path)?;
 
         let db = env.create_db(Some("account_masters"), DatabaseFlags::empty())?;
+        let change_db = env.create_db(Some("account_master_changes"), DatabaseFlags::empty())?;
 
-        let repository = Self { env: Arc::new(env), db };
+        let repository = Self { env: Arc::new(env), db, change_db };
         repository.initialize_defaults().await?;
 
         Ok(repository)
@@ -202,4 +207,89 @@ impl AccountMasterRepository for AccountMasterRepositoryImpl {
 
         Ok(())
     }
+
+    async fn apply_change(&self, change: &MasterChange<AccountMaster>) -> DomainResult<()> {
+        let stored = StoredMasterChange::to_stored(change, Self::to_stored);
+        let record_code = change.record_code().to_string();
+        let env = Arc::clone(&self.env);
+        let db = self.db;
+        let change_db = self.change_db;
+
+        tokio::task::spawn_blocking(move || {
+            master_change_store::apply_change(&env, db, change_db, &record_code, &stored)
+        })
+        .await
+        .map_err(|e| javelin_domain::error::DomainError::RepositoryError(e.to_string()))?
+    }
+
+    async fn find_changes(
+        &self,
+        code: &AccountCode,
+    ) -> DomainResult<Vec<MasterChange<AccountMaster>>> {
+        let env = Arc::clone(&self.env);
+        let change_db = self.change_db;
+        let record_code = code.value().to_string();
+
+        let stored = tokio::task::spawn_blocking(move || {
+            master_change_store::load_changes::<StoredAccountMaster>(&env, change_db, &record_code)
+        })
+        .await
+        .map_err(|e| javelin_domain::error::DomainError::RepositoryError(e.to_string()))?
+        .map_err(|e| javelin_domain::error::DomainError::RepositoryError(e.to_string()))?;
+
+        stored
+            .into_iter()
+            .map(|change| change.into_change(code.value(), Self::from_stored))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use javelin_domain::masters::MasterChangeHistory;
+    use tempfile::TempDir;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_changes_are_applied_and_reverted_through_events() {
+        let temp_dir = TempDir::new().unwrap();
+        let repository = AccountMasterRepositoryImpl::new(temp_dir.path()).await.unwrap();
+        let code = AccountCode::new("1000").unwrap();
+        let current = repository.find_by_code(&code).await.unwrap();
+
+        let history = MasterChangeHistory::new("1000", Vec::new());
+        let renamed = AccountMaster::new(
+            code.clone(),
+            AccountName::new("小口現金").unwrap(),
+            AccountType::Asset,
+            true,
+        );
+        let change = history
+            .record(current.clone(), Some(renamed.clone()), "alice")
+            .unwrap()
+            .unwrap();
+        repository.apply_change(&change).await.unwrap();
+        assert_eq!(repository.find_by_code(&code).await.unwrap(), Some(renamed.clone()));
+        // 同じ連番の変更は記録できない
+        assert!(repository.apply_change(&change).await.is_err());
+
+        let history =
+            MasterChangeHistory::new("1000", repository.find_changes(&code).await.unwrap());
+        assert_eq!(history.changes(), std::slice::from_ref(&change));
+        let undo = history.revert(1, Some(&renamed), "bob").unwrap();
+        repository.apply_change(&undo).await.unwrap();
+        assert_eq!(repository.find_by_code(&code).await.unwrap(), current);
+
+        let changes = repository.find_changes(&code).await.unwrap();
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[1].reverts(), Some(1));
+        assert!(
+            repository
+                .find_changes(&AccountCode::new("100").unwrap())
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
 }
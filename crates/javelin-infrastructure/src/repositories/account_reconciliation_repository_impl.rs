@@ -0,0 +1,307 @@
+// AccountReconciliationRepositoryImpl - 勘定照合リポジトリ実装
+
+use std::{path::Path, sync::Arc};
+
+use javelin_domain::{
+    error::{DomainError, DomainResult},
+    financial_close::account_reconciliation::{
+        AccountReconciliation, ReconciliationItem, ReconciliationStatus,
+    },
+    repositories::AccountReconciliationRepository,
+};
+use lmdb::{Cursor, Database, DatabaseFlags, Environment, Transaction};
+use serde::{Deserialize, Serialize};
+
+/// 照合が必要な科目一覧の保存キー
+const REQUIRED_ACCOUNTS_KEY: &str = "required_accounts";
+
+#[derive(Debug, Serialize, Deserialize)]
+struct StoredReconciliationItem {
+    description: String,
+    amount: f64,
+    reference: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct StoredReconciled {
+    reconciled_by: String,
+    reconciled_at: String,
+    gl_balance: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct StoredAccountReconciliation {
+    account_code: String,
+    fiscal_year: i32,
+    period: u8,
+    items: Vec<StoredReconciliationItem>,
+    /// 作業中の場合はNone
+    reconciled: Option<StoredReconciled>,
+}
+
+pub struct AccountReconciliationRepositoryImpl {
+    env: Arc<Environment>,
+    reconciliations_db: Database,
+    settings_db: Database,
+}
+
+impl AccountReconciliationRepositoryImpl {
+    pub async fn new(path: &Path) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        if !path.exists() {
+            tokio::fs::create_dir_all(path).await?;
+        }
+
+        let env = Environment::new().set_max_dbs(2).set_map_size(10 * 1024 * 1024).open(path)?;
+
+        let reconciliations_db =
+            env.create_db(Some("account_reconciliations"), DatabaseFlags::empty())?;
+        let settings_db =
+            env.create_db(Some("account_reconciliation_settings"), DatabaseFlags::empty())?;
+
+        Ok(Self { env: Arc::new(env), reconciliations_db, settings_db })
+    }
+
+    /// 保存キー（会計期間順・科目コード順に並ぶ）
+    fn key(account_code: &str, fiscal_year: i32, period: u8) -> String {
+        format!("{}:{}", Self::period_prefix(fiscal_year, period), account_code)
+    }
+
+    fn period_prefix(fiscal_year: i32, period: u8) -> String {
+        format!("{:04}-{:02}", fiscal_year, period)
+    }
+
+    fn to_stored(reconciliation: &AccountReconciliation) -> StoredAccountReconciliation {
+        StoredAccountReconciliation {
+            account_code: reconciliation.account_code().to_string(),
+            fiscal_year: reconciliation.fiscal_year(),
+            period: reconciliation.period(),
+            items: reconciliation
+                .items()
+                .iter()
+                .map(|item| StoredReconciliationItem {
+                    description: item.description.clone(),
+                    amount: item.amount,
+                    reference: item.reference.clone(),
+                })
+                .collect(),
+            reconciled: match reconciliation.status() {
+                ReconciliationStatus::Open => None,
+                ReconciliationStatus::Reconciled { reconciled_by, reconciled_at, gl_balance } => {
+                    Some(StoredReconciled {
+                        reconciled_by: reconciled_by.clone(),
+                        reconciled_at: reconciled_at.clone(),
+                        gl_balance: *gl_balance,
+                    })
+                }
+            },
+        }
+    }
+
+    fn from_stored(stored: StoredAccountReconciliation) -> DomainResult<AccountReconciliation> {
+        let items = stored
+            .items
+            .into_iter()
+            .map(|item| ReconciliationItem::new(item.description, item.amount, item.reference))
+            .collect::<DomainResult<Vec<_>>>()?;
+        let status = match stored.reconciled {
+            Some(reconciled) => ReconciliationStatus::Reconciled {
+                reconciled_by: reconciled.reconciled_by,
+                reconciled_at: reconciled.reconciled_at,
+                gl_balance: reconciled.gl_balance,
+            },
+            None => ReconciliationStatus::Open,
+        };
+
+        AccountReconciliation::restore(
+            stored.account_code,
+            stored.fiscal_year,
+            stored.period,
+            items,
+            status,
+        )
+    }
+}
+
+impl AccountReconciliationRepository for AccountReconciliationRepositoryImpl {
+    async fn find(
+        &self,
+        account_code: &str,
+        fiscal_year: i32,
+        period: u8,
+    ) -> DomainResult<Option<AccountReconciliation>> {
+        let env = Arc::clone(&self.env);
+        let db = self.reconciliations_db;
+        let key = Self::key(account_code, fiscal_year, period);
+
+        let result = tokio::task::spawn_blocking(move || {
+            let txn = env.begin_ro_txn()?;
+            match txn.get(db, &key) {
+                Ok(value) => {
+                    let stored: StoredAccountReconciliation = serde_json::from_slice(value)?;
+                    let reconciliation = Self::from_stored(stored)?;
+                    Ok::<_, Box<dyn std::error::Error + Send + Sync>>(Some(reconciliation))
+                }
+                Err(lmdb::Error::NotFound) => Ok(None),
+                Err(e) => Err(e.into()),
+            }
+        })
+        .await
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?;
+
+        Ok(result)
+    }
+
+    async fn find_by_period(
+        &self,
+        fiscal_year: i32,
+        period: u8,
+    ) -> DomainResult<Vec<AccountReconciliation>> {
+        let env = Arc::clone(&self.env);
+        let db = self.reconciliations_db;
+        let prefix = format!("{}:", Self::period_prefix(fiscal_year, period));
+
+        let result = tokio::task::spawn_blocking(move || {
+            let txn = env.begin_ro_txn()?;
+            let mut cursor = txn.open_ro_cursor(db)?;
+            let mut reconciliations = Vec::new();
+
+            for (key, value) in cursor.iter_from(prefix.as_bytes()) {
+                if !key.starts_with(prefix.as_bytes()) {
+                    break;
+                }
+                let stored: StoredAccountReconciliation = serde_json::from_slice(value)?;
+                reconciliations.push(Self::from_stored(stored)?);
+            }
+
+            Ok::<_, Box<dyn std::error::Error + Send + Sync>>(reconciliations)
+        })
+        .await
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?;
+
+        Ok(result)
+    }
+
+    async fn save(&self, reconciliation: &AccountReconciliation) -> DomainResult<()> {
+        let stored = Self::to_stored(reconciliation);
+        let value =
+            serde_json::to_vec(&stored).map_err(|e| DomainError::RepositoryError(e.to_string()))?;
+
+        let env = Arc::clone(&self.env);
+        let db = self.reconciliations_db;
+        let key = Self::key(
+            reconciliation.account_code(),
+            reconciliation.fiscal_year(),
+            reconciliation.period(),
+        );
+
+        tokio::task::spawn_blocking(move || {
+            let mut txn = env.begin_rw_txn()?;
+            txn.put(db, &key, &value, lmdb::WriteFlags::empty())?;
+            txn.commit()?;
+            Ok::<_, Box<dyn std::error::Error + Send + Sync>>(())
+        })
+        .await
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn required_accounts(&self) -> DomainResult<Vec<String>> {
+        let env = Arc::clone(&self.env);
+        let db = self.settings_db;
+
+        let result = tokio::task::spawn_blocking(move || {
+            let txn = env.begin_ro_txn()?;
+            match txn.get(db, &REQUIRED_ACCOUNTS_KEY) {
+                Ok(value) => {
+                    Ok::<_, Box<dyn std::error::Error + Send + Sync>>(serde_json::from_slice::<
+                        Vec<String>,
+                    >(value)?)
+                }
+                Err(lmdb::Error::NotFound) => Ok(Vec::new()),
+                Err(e) => Err(e.into()),
+            }
+        })
+        .await
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?;
+
+        Ok(result)
+    }
+
+    async fn save_required_accounts(&self, account_codes: &[String]) -> DomainResult<()> {
+        let mut account_codes = account_codes.to_vec();
+        account_codes.sort();
+        account_codes.dedup();
+        let value = serde_json::to_vec(&account_codes)
+            .map_err(|e| DomainError::RepositoryError(e.to_string()))?;
+
+        let env = Arc::clone(&self.env);
+        let db = self.settings_db;
+
+        tokio::task::spawn_blocking(move || {
+            let mut txn = env.begin_rw_txn()?;
+            txn.put(db, &REQUIRED_ACCOUNTS_KEY, &value, lmdb::WriteFlags::empty())?;
+            txn.commit()?;
+            Ok::<_, Box<dyn std::error::Error + Send + Sync>>(())
+        })
+        .await
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_save_and_find_by_period() {
+        let temp_dir = TempDir::new().unwrap();
+        let repository = AccountReconciliationRepositoryImpl::new(temp_dir.path()).await.unwrap();
+        assert!(repository.find("1100", 2024, 3).await.unwrap().is_none());
+
+        let mut cash = AccountReconciliation::new("1100", 2024, 3).unwrap();
+        cash.add_item(
+            ReconciliationItem::new("A銀行 残高証明", 500.0, Some("BS-001.pdf".to_string()))
+                .unwrap(),
+        )
+        .unwrap();
+        cash.mark_reconciled(500.0, "user1", "2024-04-05T00:00:00Z").unwrap();
+        repository.save(&cash).await.unwrap();
+        repository
+            .save(&AccountReconciliation::new("1000", 2024, 3).unwrap())
+            .await
+            .unwrap();
+        repository
+            .save(&AccountReconciliation::new("1100", 2024, 4).unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(repository.find("1100", 2024, 3).await.unwrap().unwrap(), cash);
+
+        let march = repository.find_by_period(2024, 3).await.unwrap();
+        let codes: Vec<_> = march.iter().map(|r| r.account_code()).collect();
+        assert_eq!(codes, vec!["1000", "1100"]);
+    }
+
+    #[tokio::test]
+    async fn test_required_accounts_round_trip() {
+        let temp_dir = TempDir::new().unwrap();
+        let repository = AccountReconciliationRepositoryImpl::new(temp_dir.path()).await.unwrap();
+        assert!(repository.required_accounts().await.unwrap().is_empty());
+
+        repository
+            .save_required_accounts(&["2100".to_string(), "1100".to_string(), "1100".to_string()])
+            .await
+            .unwrap();
+        assert_eq!(repository.required_accounts().await.unwrap(), vec!["1100", "2100"]);
+    }
+}
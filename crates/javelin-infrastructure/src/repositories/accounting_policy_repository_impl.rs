@@ -0,0 +1,319 @@
+// AccountingPolicyRepositoryImpl - 会計方針設定リポジトリ実装
+
+use std::{collections::BTreeMap, path::Path, sync::Arc};
+
+use chrono::{DateTime, Utc};
+use javelin_domain::{
+    error::{DomainError, DomainResult},
+    financial_close::journal_entry::values::{Currency, TaxType},
+    masters::{
+        AccountingPolicy, AccountingPolicyChanged, AmountMaskingPolicy, AmountMaskingRule,
+        CurrencyRounding, DecimalPlaces, NegativeNumberPresentation, RoundingMode, TaxRounding,
+    },
+    repositories::AccountingPolicyRepository,
+};
+use lmdb::{Cursor, Database, DatabaseFlags, Environment, Transaction};
+use serde::{Deserialize, Serialize};
+
+/// 会計方針を保存するキー（単一レコード）
+const POLICY_KEY: &str = "policy";
+
+#[derive(Debug, Serialize, Deserialize)]
+struct StoredCurrencyRounding {
+    currency: String,
+    decimal_places: u8,
+    mode: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct StoredTaxRounding {
+    tax_type: String,
+    mode: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct StoredAccountingPolicy {
+    currency_roundings: Vec<StoredCurrencyRounding>,
+    tax_roundings: Vec<StoredTaxRounding>,
+    negative_presentation: String,
+    administrators: Vec<String>,
+    /// 金額マスキング規則（`<科目範囲>:<ロール>`形式）
+    #[serde(default)]
+    amount_masking_rules: Vec<String>,
+    /// ユーザごとのロール
+    #[serde(default)]
+    user_roles: BTreeMap<String, Vec<String>>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct StoredPolicyChange {
+    setting: String,
+    before: String,
+    after: String,
+    changed_by: String,
+    changed_at: DateTime<Utc>,
+}
+
+pub struct AccountingPolicyRepositoryImpl {
+    env: Arc<Environment>,
+    policy_db: Database,
+    change_db: Database,
+}
+
+impl AccountingPolicyRepositoryImpl {
+    pub async fn new(path: &Path) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        if !path.exists() {
+            tokio::fs::create_dir_all(path).await?;
+        }
+
+        let env = Environment::new().set_max_dbs(2).set_map_size(10 * 1024 * 1024).open(path)?;
+
+        let policy_db = env.create_db(Some("accounting_policy"), DatabaseFlags::empty())?;
+        let change_db = env.create_db(Some("accounting_policy_changes"), DatabaseFlags::empty())?;
+
+        Ok(Self { env: Arc::new(env), policy_db, change_db })
+    }
+
+    fn to_stored(policy: &AccountingPolicy) -> StoredAccountingPolicy {
+        StoredAccountingPolicy {
+            currency_roundings: policy
+                .currency_roundings()
+                .iter()
+                .map(|r| StoredCurrencyRounding {
+                    currency: r.currency().as_str().to_string(),
+                    decimal_places: r.decimal_places().value(),
+                    mode: r.mode().code().to_string(),
+                })
+                .collect(),
+            tax_roundings: policy
+                .tax_roundings()
+                .iter()
+                .map(|r| StoredTaxRounding {
+                    tax_type: r.tax_type().as_str().to_string(),
+                    mode: r.mode().code().to_string(),
+                })
+                .collect(),
+            negative_presentation: policy.negative_presentation().code().to_string(),
+            administrators: policy.administrators().to_vec(),
+            amount_masking_rules: policy
+                .amount_masking()
+                .rules()
+                .iter()
+                .map(AmountMaskingRule::to_spec)
+                .collect(),
+            user_roles: policy
+                .amount_masking()
+                .user_roles()
+                .iter()
+                .map(|(user_id, roles)| (user_id.clone(), roles.iter().cloned().collect()))
+                .collect(),
+        }
+    }
+
+    fn from_stored(stored: StoredAccountingPolicy) -> DomainResult<AccountingPolicy> {
+        let currency_roundings = stored
+            .currency_roundings
+            .iter()
+            .map(|r| {
+                let currency =
+                    r.currency.parse::<Currency>().map_err(DomainError::ValidationError)?;
+                CurrencyRounding::new(
+                    currency,
+                    DecimalPlaces::new(r.decimal_places)?,
+                    RoundingMode::from_code(&r.mode)?,
+                )
+            })
+            .collect::<DomainResult<Vec<_>>>()?;
+        let tax_roundings = stored
+            .tax_roundings
+            .iter()
+            .map(|r| {
+                let tax_type =
+                    r.tax_type.parse::<TaxType>().map_err(DomainError::ValidationError)?;
+                Ok(TaxRounding::new(tax_type, RoundingMode::from_code(&r.mode)?))
+            })
+            .collect::<DomainResult<Vec<_>>>()?;
+        let masking_rules = stored
+            .amount_masking_rules
+            .iter()
+            .map(|spec| AmountMaskingRule::parse(spec))
+            .collect::<DomainResult<Vec<_>>>()?;
+        let user_roles = stored
+            .user_roles
+            .into_iter()
+            .map(|(user_id, roles)| (user_id, roles.into_iter().collect()))
+            .collect();
+
+        Ok(AccountingPolicy::new(
+            currency_roundings,
+            tax_roundings,
+            NegativeNumberPresentation::from_code(&stored.negative_presentation)?,
+            stored.administrators,
+        )?
+        .with_amount_masking(AmountMaskingPolicy::new(masking_rules, user_roles)))
+    }
+}
+
+impl AccountingPolicyRepository for AccountingPolicyRepositoryImpl {
+    async fn load(&self) -> DomainResult<AccountingPolicy> {
+        let env = Arc::clone(&self.env);
+        let db = self.policy_db;
+
+        let stored = tokio::task::spawn_blocking(move || {
+            let txn = env.begin_ro_txn()?;
+            match txn.get(db, &POLICY_KEY) {
+                Ok(value) => {
+                    let stored: StoredAccountingPolicy = serde_json::from_slice(value)?;
+                    Ok::<_, Box<dyn std::error::Error + Send + Sync>>(Some(stored))
+                }
+                Err(lmdb::Error::NotFound) => Ok(None),
+                Err(e) => Err(e.into()),
+            }
+        })
+        .await
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?;
+
+        match stored {
+            Some(stored) => Self::from_stored(stored),
+            None => Ok(AccountingPolicy::default()),
+        }
+    }
+
+    async fn save(
+        &self,
+        policy: &AccountingPolicy,
+        change: &AccountingPolicyChanged,
+    ) -> DomainResult<()> {
+        let policy_value = serde_json::to_vec(&Self::to_stored(policy))
+            .map_err(|e| DomainError::RepositoryError(e.to_string()))?;
+        let change_value = serde_json::to_vec(&StoredPolicyChange {
+            setting: change.setting.clone(),
+            before: change.before.clone(),
+            after: change.after.clone(),
+            changed_by: change.changed_by.clone(),
+            changed_at: change.changed_at,
+        })
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?;
+
+        let env = Arc::clone(&self.env);
+        let policy_db = self.policy_db;
+        let change_db = self.change_db;
+
+        tokio::task::spawn_blocking(move || {
+            let mut txn = env.begin_rw_txn()?;
+            // 変更イベントは記録順の連番をキーとする
+            let sequence = {
+                let mut cursor = txn.open_ro_cursor(change_db)?;
+                cursor.iter().count() as u64 + 1
+            };
+            txn.put(policy_db, &POLICY_KEY, &policy_value, lmdb::WriteFlags::empty())?;
+            txn.put(
+                change_db,
+                &format!("{:020}", sequence),
+                &change_value,
+                lmdb::WriteFlags::empty(),
+            )?;
+            txn.commit()?;
+            Ok::<_, Box<dyn std::error::Error + Send + Sync>>(())
+        })
+        .await
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn find_changes(&self) -> DomainResult<Vec<AccountingPolicyChanged>> {
+        let env = Arc::clone(&self.env);
+        let db = self.change_db;
+
+        let result = tokio::task::spawn_blocking(move || {
+            let txn = env.begin_ro_txn()?;
+            let mut cursor = txn.open_ro_cursor(db)?;
+            let mut changes = Vec::new();
+
+            for (_key, value) in cursor.iter() {
+                let stored: StoredPolicyChange = serde_json::from_slice(value)?;
+                changes.push(AccountingPolicyChanged {
+                    setting: stored.setting,
+                    before: stored.before,
+                    after: stored.after,
+                    changed_by: stored.changed_by,
+                    changed_at: stored.changed_at,
+                });
+            }
+
+            Ok::<_, Box<dyn std::error::Error + Send + Sync>>(changes)
+        })
+        .await
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?;
+
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use javelin_domain::masters::DEFAULT_POLICY_ADMINISTRATOR;
+    use tempfile::TempDir;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_policy_and_changes_round_trip() {
+        let temp_dir = TempDir::new().unwrap();
+        let repository = AccountingPolicyRepositoryImpl::new(temp_dir.path()).await.unwrap();
+
+        let mut policy = repository.load().await.unwrap();
+        assert_eq!(policy, AccountingPolicy::default());
+        assert!(repository.find_changes().await.unwrap().is_empty());
+
+        let first = policy
+            .change_negative_presentation(
+                DEFAULT_POLICY_ADMINISTRATOR,
+                NegativeNumberPresentation::Triangle,
+            )
+            .unwrap()
+            .unwrap();
+        repository.save(&policy, &first).await.unwrap();
+
+        let rounding = CurrencyRounding::new(
+            Currency::USD,
+            DecimalPlaces::new(0).unwrap(),
+            RoundingMode::Truncate,
+        )
+        .unwrap();
+        let second = policy
+            .change_currency_rounding(DEFAULT_POLICY_ADMINISTRATOR, rounding)
+            .unwrap()
+            .unwrap();
+        repository.save(&policy, &second).await.unwrap();
+
+        let rules = AmountMaskingPolicy::parse_rules("5100-5199:payroll").unwrap();
+        let third = policy
+            .change_amount_masking_rules(DEFAULT_POLICY_ADMINISTRATOR, rules)
+            .unwrap()
+            .unwrap();
+        repository.save(&policy, &third).await.unwrap();
+        let fourth = policy
+            .change_user_roles(
+                DEFAULT_POLICY_ADMINISTRATOR,
+                "hr_manager",
+                ["payroll".to_string()].into_iter().collect(),
+            )
+            .unwrap()
+            .unwrap();
+        repository.save(&policy, &fourth).await.unwrap();
+
+        let reloaded = repository.load().await.unwrap();
+        assert_eq!(reloaded, policy);
+        assert_eq!(reloaded.round_amount(&Currency::USD, 10.99), 10.0);
+        assert!(reloaded.amount_masking().is_masked("5150", "clerk"));
+        assert!(!reloaded.amount_masking().is_masked("5150", "hr_manager"));
+
+        let changes = repository.find_changes().await.unwrap();
+        assert_eq!(changes, vec![first, second, third, fourth]);
+    }
+}
@@ -1,12 +1,16 @@
 // ApplicationSettingsRepositoryImpl - アプリケーション設定リポジトリの実装
 
-use std::{path::Path, sync::Arc};
+use std::{collections::BTreeMap, path::Path, str::FromStr, sync::Arc};
 
 use javelin_domain::{
-    error::DomainResult,
+    error::{DomainError, DomainResult},
+    financial_close::journal_entry::values::Currency,
     masters::{
-        ApplicationSettings, BackupRetentionDays, ClosingDay, CompanyCode, DateFormat,
-        DecimalPlaces, FiscalYearStartMonth, Language,
+        AccountCode, ApplicationSettings, ApprovalSlaSettings, BackupRetentionDays,
+        BatchNotificationSettings, ClosingDay, ClosingLockCheck, ClosingLockValidationSettings,
+        CompanyCode, DateFormat, DecimalPlaces, DeletedEntryRetentionSettings,
+        ExportProtectionSettings, FiscalYearStartMonth, Language, ReportDeliverySettings,
+        ReportDestination,
     },
     repositories::ApplicationSettingsRepository,
 };
@@ -19,10 +23,62 @@ struct StoredApplicationSettings {
     language: String,
     decimal_places: u8,
     date_format: String,
+    // 通知設定の追加前に保存された設定は無効として読み込む
+    #[serde(default)]
+    batch_notification_bell: bool,
+    #[serde(default)]
+    batch_notification_desktop: bool,
     fiscal_year_start_month: u8,
+    // 基準通貨の追加前に保存された設定は円として読み込む
+    #[serde(default = "default_base_currency")]
+    base_currency: String,
     closing_day: u8,
     auto_backup_enabled: bool,
     backup_retention_days: u32,
+    // 承認SLAの追加前に保存された設定は既定の閾値として読み込む
+    #[serde(default = "default_approval_sla_warning_hours")]
+    approval_sla_warning_hours: u32,
+    #[serde(default = "default_approval_sla_breach_hours")]
+    approval_sla_breach_hours: u32,
+    // 帳票の種類ごとの配信先（URI形式）。配信の追加前に保存された設定は配信先なし
+    #[serde(default)]
+    report_delivery: BTreeMap<String, Vec<String>>,
+    // 出力ファイル保護の追加前に保存された設定は保護なし
+    #[serde(default)]
+    export_encryption_enabled: bool,
+    #[serde(default)]
+    export_signature_enabled: bool,
+    // 削除済み仕訳の保持の追加前に保存された設定は保持しない
+    #[serde(default)]
+    deleted_entry_retain_enabled: bool,
+    #[serde(default = "default_deleted_entry_purge_after_months")]
+    deleted_entry_purge_after_months: u32,
+    // 締日固定の事前検証の追加前に保存された設定はすべての項目を検証する
+    #[serde(default)]
+    closing_lock_disabled_checks: Vec<String>,
+    // 為替換算調整勘定の追加前に保存された設定は既定の勘定科目として読み込む
+    #[serde(default = "default_translation_adjustment_account")]
+    translation_adjustment_account: String,
+}
+
+fn default_base_currency() -> String {
+    Currency::JPY.as_str().to_string()
+}
+
+fn default_approval_sla_warning_hours() -> u32 {
+    ApprovalSlaSettings::DEFAULT_WARNING_HOURS
+}
+
+fn default_approval_sla_breach_hours() -> u32 {
+    ApprovalSlaSettings::DEFAULT_BREACH_HOURS
+}
+
+fn default_deleted_entry_purge_after_months() -> u32 {
+    DeletedEntryRetentionSettings::DEFAULT_PURGE_AFTER_MONTHS
+}
+
+fn default_translation_adjustment_account() -> String {
+    ApplicationSettings::DEFAULT_TRANSLATION_ADJUSTMENT_ACCOUNT.to_string()
 }
 
 pub struct ApplicationSettingsRepositoryImpl {
@@ -69,10 +125,40 @@ impl ApplicationSettingsRepositoryImpl {
             language: settings.language().value().to_string(),
             decimal_places: settings.decimal_places().value(),
             date_format: settings.date_format().value().to_string(),
+            batch_notification_bell: settings.batch_notification().bell_enabled,
+            batch_notification_desktop: settings.batch_notification().desktop_enabled,
             fiscal_year_start_month: settings.fiscal_year_start_month().value(),
+            base_currency: settings.base_currency().as_str().to_string(),
             closing_day: settings.closing_day().value(),
             auto_backup_enabled: settings.auto_backup_enabled(),
             backup_retention_days: settings.backup_retention_days().value(),
+            approval_sla_warning_hours: settings.approval_sla().warning_hours(),
+            approval_sla_breach_hours: settings.approval_sla().breach_hours(),
+            report_delivery: settings
+                .report_delivery()
+                .routes()
+                .map(|(report_type, destinations)| {
+                    (
+                        report_type.to_string(),
+                        destinations.iter().map(ReportDestination::to_uri).collect(),
+                    )
+                })
+                .collect(),
+            export_encryption_enabled: settings.export_protection().encryption_enabled,
+            export_signature_enabled: settings.export_protection().signature_enabled,
+            deleted_entry_retain_enabled: settings.deleted_entry_retention().retain_enabled(),
+            deleted_entry_purge_after_months: settings
+                .deleted_entry_retention()
+                .purge_after_months(),
+            closing_lock_disabled_checks: settings
+                .closing_lock_validation()
+                .disabled_checks()
+                .map(|check| check.code().to_string())
+                .collect(),
+            translation_adjustment_account: settings
+                .translation_adjustment_account()
+                .value()
+                .to_string(),
         }
     }
 
@@ -84,10 +170,37 @@ impl ApplicationSettingsRepositoryImpl {
         let decimal_places = DecimalPlaces::new(stored.decimal_places)?;
         let date_format = DateFormat::new(&stored.date_format)?;
         let fiscal_year_start_month = FiscalYearStartMonth::new(stored.fiscal_year_start_month)?;
+        let base_currency =
+            Currency::from_str(&stored.base_currency).map_err(DomainError::ValidationError)?;
         let closing_day = ClosingDay::new(stored.closing_day)?;
         let backup_retention_days = BackupRetentionDays::new(stored.backup_retention_days)?;
+        let approval_sla = ApprovalSlaSettings::new(
+            stored.approval_sla_warning_hours,
+            stored.approval_sla_breach_hours,
+        )?;
+        let deleted_entry_retention = DeletedEntryRetentionSettings::new(
+            stored.deleted_entry_retain_enabled,
+            stored.deleted_entry_purge_after_months,
+        )?;
+        let closing_lock_validation = ClosingLockValidationSettings::new(
+            stored
+                .closing_lock_disabled_checks
+                .iter()
+                .map(|code| ClosingLockCheck::from_code(code))
+                .collect::<DomainResult<Vec<_>>>()?,
+        );
+        let translation_adjustment_account =
+            AccountCode::new(&stored.translation_adjustment_account)?;
+        let mut report_delivery = ReportDeliverySettings::new();
+        for (report_type, uris) in &stored.report_delivery {
+            let destinations = uris
+                .iter()
+                .map(|uri| ReportDestination::parse(uri))
+                .collect::<DomainResult<Vec<_>>>()?;
+            report_delivery.set_destinations(report_type.clone(), destinations);
+        }
 
-        Ok(ApplicationSettings::new(
+        let mut settings = ApplicationSettings::new(
             default_company_code,
             language,
             decimal_places,
@@ -96,7 +209,22 @@ impl ApplicationSettingsRepositoryImpl {
             closing_day,
             stored.auto_backup_enabled,
             backup_retention_days,
-        ))
+        );
+        settings.update_base_currency(base_currency);
+        settings.update_batch_notification(BatchNotificationSettings::new(
+            stored.batch_notification_bell,
+            stored.batch_notification_desktop,
+        ));
+        settings.update_approval_sla(approval_sla);
+        settings.update_report_delivery(report_delivery);
+        settings.update_export_protection(ExportProtectionSettings::new(
+            stored.export_encryption_enabled,
+            stored.export_signature_enabled,
+        ));
+        settings.update_deleted_entry_retention(deleted_entry_retention);
+        settings.update_closing_lock_validation(closing_lock_validation);
+        settings.update_translation_adjustment_account(translation_adjustment_account);
+        Ok(settings)
     }
 }
 
@@ -147,3 +275,160 @@ impl ApplicationSettingsRepository for ApplicationSettingsRepositoryImpl {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_batch_notification_round_trip() {
+        let temp_dir = TempDir::new().unwrap();
+        let repository = ApplicationSettingsRepositoryImpl::new(temp_dir.path()).await.unwrap();
+
+        let mut settings = repository.find().await.unwrap().unwrap();
+        assert!(!settings.batch_notification().is_enabled());
+
+        settings.update_batch_notification(BatchNotificationSettings::new(true, false));
+        repository.save(&settings).await.unwrap();
+
+        let reloaded = repository.find().await.unwrap().unwrap();
+        assert_eq!(reloaded.batch_notification(), BatchNotificationSettings::new(true, false));
+    }
+
+    #[tokio::test]
+    async fn test_approval_sla_round_trip() {
+        let temp_dir = TempDir::new().unwrap();
+        let repository = ApplicationSettingsRepositoryImpl::new(temp_dir.path()).await.unwrap();
+
+        let mut settings = repository.find().await.unwrap().unwrap();
+        assert_eq!(settings.approval_sla(), ApprovalSlaSettings::default());
+
+        settings.update_approval_sla(ApprovalSlaSettings::new(8, 24).unwrap());
+        repository.save(&settings).await.unwrap();
+
+        let reloaded = repository.find().await.unwrap().unwrap();
+        assert_eq!(reloaded.approval_sla(), ApprovalSlaSettings::new(8, 24).unwrap());
+    }
+
+    #[test]
+    fn test_settings_saved_before_notification_support() {
+        let json = r#"{
+            "default_company_code": "0001",
+            "language": "ja",
+            "decimal_places": 2,
+            "date_format": "YYYY-MM-DD",
+            "fiscal_year_start_month": 4,
+            "closing_day": 31,
+            "auto_backup_enabled": true,
+            "backup_retention_days": 90
+        }"#;
+        let stored: StoredApplicationSettings = serde_json::from_str(json).unwrap();
+        let settings = ApplicationSettingsRepositoryImpl::from_stored(&stored).unwrap();
+
+        assert_eq!(settings.batch_notification(), BatchNotificationSettings::default());
+        assert_eq!(settings.approval_sla(), ApprovalSlaSettings::default());
+        assert_eq!(settings.report_delivery(), &ReportDeliverySettings::default());
+        assert_eq!(settings.export_protection(), ExportProtectionSettings::default());
+        assert_eq!(settings.deleted_entry_retention(), DeletedEntryRetentionSettings::default());
+        assert_eq!(settings.base_currency(), &Currency::JPY);
+        assert_eq!(
+            settings.translation_adjustment_account().value(),
+            ApplicationSettings::DEFAULT_TRANSLATION_ADJUSTMENT_ACCOUNT
+        );
+    }
+
+    #[tokio::test]
+    async fn test_base_currency_round_trip() {
+        let temp_dir = TempDir::new().unwrap();
+        let repository = ApplicationSettingsRepositoryImpl::new(temp_dir.path()).await.unwrap();
+
+        let mut settings = repository.find().await.unwrap().unwrap();
+        settings.update_base_currency(Currency::USD);
+        repository.save(&settings).await.unwrap();
+
+        let reloaded = repository.find().await.unwrap().unwrap();
+        assert_eq!(reloaded.base_currency(), &Currency::USD);
+    }
+
+    #[tokio::test]
+    async fn test_report_delivery_round_trip() {
+        let temp_dir = TempDir::new().unwrap();
+        let repository = ApplicationSettingsRepositoryImpl::new(temp_dir.path()).await.unwrap();
+
+        let mut settings = repository.find().await.unwrap().unwrap();
+        let mut report_delivery = ReportDeliverySettings::new();
+        report_delivery.set_destinations(
+            "TB",
+            vec![
+                ReportDestination::parse("file:/srv/reports").unwrap(),
+                ReportDestination::parse("smtp://mail?from=gl@example.com&to=cfo@example.com")
+                    .unwrap(),
+            ],
+        );
+        settings.update_report_delivery(report_delivery.clone());
+        repository.save(&settings).await.unwrap();
+
+        let reloaded = repository.find().await.unwrap().unwrap();
+        assert_eq!(reloaded.report_delivery(), &report_delivery);
+    }
+
+    #[tokio::test]
+    async fn test_export_protection_round_trip() {
+        let temp_dir = TempDir::new().unwrap();
+        let repository = ApplicationSettingsRepositoryImpl::new(temp_dir.path()).await.unwrap();
+
+        let mut settings = repository.find().await.unwrap().unwrap();
+        assert!(!settings.export_protection().is_enabled());
+
+        settings.update_export_protection(ExportProtectionSettings::new(false, true));
+        repository.save(&settings).await.unwrap();
+
+        let reloaded = repository.find().await.unwrap().unwrap();
+        assert_eq!(reloaded.export_protection(), ExportProtectionSettings::new(false, true));
+    }
+
+    #[tokio::test]
+    async fn test_deleted_entry_retention_round_trip() {
+        let temp_dir = TempDir::new().unwrap();
+        let repository = ApplicationSettingsRepositoryImpl::new(temp_dir.path()).await.unwrap();
+
+        let mut settings = repository.find().await.unwrap().unwrap();
+        let retention = DeletedEntryRetentionSettings::new(true, 24).unwrap();
+        settings.update_deleted_entry_retention(retention);
+        repository.save(&settings).await.unwrap();
+
+        let reloaded = repository.find().await.unwrap().unwrap();
+        assert_eq!(reloaded.deleted_entry_retention(), retention);
+    }
+
+    #[tokio::test]
+    async fn test_translation_adjustment_account_round_trip() {
+        let temp_dir = TempDir::new().unwrap();
+        let repository = ApplicationSettingsRepositoryImpl::new(temp_dir.path()).await.unwrap();
+
+        let mut settings = repository.find().await.unwrap().unwrap();
+        settings.update_translation_adjustment_account(AccountCode::new("3950").unwrap());
+        repository.save(&settings).await.unwrap();
+
+        let reloaded = repository.find().await.unwrap().unwrap();
+        assert_eq!(reloaded.translation_adjustment_account().value(), "3950");
+    }
+
+    #[tokio::test]
+    async fn test_closing_lock_validation_round_trip() {
+        let temp_dir = TempDir::new().unwrap();
+        let repository = ApplicationSettingsRepositoryImpl::new(temp_dir.path()).await.unwrap();
+
+        let mut settings = repository.find().await.unwrap().unwrap();
+        assert_eq!(settings.closing_lock_validation(), &ClosingLockValidationSettings::default());
+
+        let validation = ClosingLockValidationSettings::new([ClosingLockCheck::SuspenseCleared]);
+        settings.update_closing_lock_validation(validation.clone());
+        repository.save(&settings).await.unwrap();
+
+        let reloaded = repository.find().await.unwrap().unwrap();
+        assert_eq!(reloaded.closing_lock_validation(), &validation);
+    }
+}
@@ -0,0 +1,229 @@
+// CalendarMasterRepositoryImpl - カレンダーマスタリポジトリの実装
+
+use std::{path::Path, sync::Arc};
+
+use chrono::{Datelike, NaiveDate};
+use javelin_domain::{
+    error::DomainResult,
+    masters::{CalendarMaster, HolidayName},
+    repositories::CalendarMasterRepository,
+};
+use lmdb::{Cursor, Database, DatabaseFlags, Environment, Transaction};
+use serde::{Deserialize, Serialize};
+
+/// 日付固定の国民の祝日（月, 日, 名称）
+///
+/// ハッピーマンデー・春分日・秋分日など年により変動する休日は
+/// マスタへ個別に登録する。
+const FIXED_HOLIDAYS: [(u32, u32, &str); 10] = [
+    (1, 1, "元日"),
+    (2, 11, "建国記念の日"),
+    (2, 23, "天皇誕生日"),
+    (4, 29, "昭和の日"),
+    (5, 3, "憲法記念日"),
+    (5, 4, "みどりの日"),
+    (5, 5, "こどもの日"),
+    (8, 11, "山の日"),
+    (11, 3, "文化の日"),
+    (11, 23, "勤労感謝の日"),
+];
+
+#[derive(Debug, Serialize, Deserialize)]
+struct StoredCalendarMaster {
+    date: String, // YYYY-MM-DD format
+    name: String,
+}
+
+pub struct CalendarMasterRepositoryImpl {
+    env: Arc<Environment>,
+    db: Database,
+}
+
+impl CalendarMasterRepositoryImpl {
+    pub async fn new(path: &Path) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        if !path.exists() {
+            tokio::fs::create_dir_all(path).await?;
+        }
+
+        let env = Environment::new().set_max_dbs(1).set_map_size(50 * 1024 * 1024).open(path)?;
+
+        let db = env.create_db(Some("calendar_masters"), DatabaseFlags::empty())?;
+
+        let repository = Self { env: Arc::new(env), db };
+        repository.initialize_defaults().await?;
+
+        Ok(repository)
+    }
+
+    async fn initialize_defaults(&self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let env = Arc::clone(&self.env);
+        let db = self.db;
+
+        let is_empty = tokio::task::spawn_blocking(move || {
+            let txn = env.begin_ro_txn()?;
+            let mut cursor = txn.open_ro_cursor(db)?;
+            Ok::<_, Box<dyn std::error::Error + Send + Sync>>(cursor.iter().next().is_none())
+        })
+        .await??;
+
+        if is_empty {
+            // 前年〜翌年の固定祝日を登録
+            let this_year = chrono::Local::now().year();
+            for year in (this_year - 1)..=(this_year + 1) {
+                for (month, day, name) in FIXED_HOLIDAYS {
+                    if let Some(date) = NaiveDate::from_ymd_opt(year, month, day) {
+                        self.save(&CalendarMaster::new(date, HolidayName::new(name)?)).await?;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn to_stored(calendar_master: &CalendarMaster) -> StoredCalendarMaster {
+        StoredCalendarMaster {
+            date: calendar_master.date().format("%Y-%m-%d").to_string(),
+            name: calendar_master.name().value().to_string(),
+        }
+    }
+
+    fn from_stored(stored: &StoredCalendarMaster) -> DomainResult<CalendarMaster> {
+        let date = NaiveDate::parse_from_str(&stored.date, "%Y-%m-%d").map_err(|e| {
+            javelin_domain::error::DomainError::ValidationError(format!(
+                "休日の日付が不正です: {} ({})",
+                stored.date, e
+            ))
+        })?;
+        let name = HolidayName::new(&stored.name)?;
+        Ok(CalendarMaster::new(date, name))
+    }
+
+    fn key(date: NaiveDate) -> String {
+        date.format("%Y-%m-%d").to_string()
+    }
+}
+
+impl CalendarMasterRepository for CalendarMasterRepositoryImpl {
+    async fn find_by_date(&self, date: NaiveDate) -> DomainResult<Option<CalendarMaster>> {
+        let env = Arc::clone(&self.env);
+        let db = self.db;
+        let key = Self::key(date);
+
+        let result = tokio::task::spawn_blocking(move || {
+            let txn = env.begin_ro_txn()?;
+            match txn.get(db, &key) {
+                Ok(value) => {
+                    let stored: StoredCalendarMaster = serde_json::from_slice(value)?;
+                    let holiday = Self::from_stored(&stored)?;
+                    Ok::<_, Box<dyn std::error::Error + Send + Sync>>(Some(holiday))
+                }
+                Err(lmdb::Error::NotFound) => Ok(None),
+                Err(e) => Err(e.into()),
+            }
+        })
+        .await
+        .map_err(|e| javelin_domain::error::DomainError::RepositoryError(e.to_string()))?
+        .map_err(|e| javelin_domain::error::DomainError::RepositoryError(e.to_string()))?;
+
+        Ok(result)
+    }
+
+    async fn find_all(&self) -> DomainResult<Vec<CalendarMaster>> {
+        let env = Arc::clone(&self.env);
+        let db = self.db;
+
+        let result = tokio::task::spawn_blocking(move || {
+            let txn = env.begin_ro_txn()?;
+            let mut cursor = txn.open_ro_cursor(db)?;
+            let mut holidays = Vec::new();
+
+            // キーがYYYY-MM-DD形式のため日付順に走査される
+            for (_key, value) in cursor.iter() {
+                let stored: StoredCalendarMaster = serde_json::from_slice(value)?;
+                let holiday = Self::from_stored(&stored)?;
+                holidays.push(holiday);
+            }
+
+            Ok::<_, Box<dyn std::error::Error + Send + Sync>>(holidays)
+        })
+        .await
+        .map_err(|e| javelin_domain::error::DomainError::RepositoryError(e.to_string()))?
+        .map_err(|e| javelin_domain::error::DomainError::RepositoryError(e.to_string()))?;
+
+        Ok(result)
+    }
+
+    async fn save(&self, calendar_master: &CalendarMaster) -> DomainResult<()> {
+        let stored = Self::to_stored(calendar_master);
+        let value = serde_json::to_vec(&stored)
+            .map_err(|e| javelin_domain::error::DomainError::RepositoryError(e.to_string()))?;
+
+        let env = Arc::clone(&self.env);
+        let db = self.db;
+        let key = Self::key(calendar_master.date());
+
+        tokio::task::spawn_blocking(move || {
+            let mut txn = env.begin_rw_txn()?;
+            txn.put(db, &key, &value, lmdb::WriteFlags::empty())?;
+            txn.commit()?;
+            Ok::<_, Box<dyn std::error::Error + Send + Sync>>(())
+        })
+        .await
+        .map_err(|e| javelin_domain::error::DomainError::RepositoryError(e.to_string()))?
+        .map_err(|e| javelin_domain::error::DomainError::RepositoryError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, date: NaiveDate) -> DomainResult<()> {
+        let env = Arc::clone(&self.env);
+        let db = self.db;
+        let key = Self::key(date);
+
+        tokio::task::spawn_blocking(move || {
+            let mut txn = env.begin_rw_txn()?;
+            txn.del(db, &key, None)?;
+            txn.commit()?;
+            Ok::<_, Box<dyn std::error::Error + Send + Sync>>(())
+        })
+        .await
+        .map_err(|e| javelin_domain::error::DomainError::RepositoryError(e.to_string()))?
+        .map_err(|e| javelin_domain::error::DomainError::RepositoryError(e.to_string()))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_defaults_are_sorted_by_date() {
+        let temp_dir = TempDir::new().unwrap();
+        let repository = CalendarMasterRepositoryImpl::new(temp_dir.path()).await.unwrap();
+
+        let holidays = repository.find_all().await.unwrap();
+        assert_eq!(holidays.len(), FIXED_HOLIDAYS.len() * 3);
+        assert!(holidays.windows(2).all(|w| w[0].date() < w[1].date()));
+    }
+
+    #[tokio::test]
+    async fn test_save_and_delete() {
+        let temp_dir = TempDir::new().unwrap();
+        let repository = CalendarMasterRepositoryImpl::new(temp_dir.path()).await.unwrap();
+
+        let date = NaiveDate::from_ymd_opt(2024, 12, 30).unwrap();
+        let holiday = CalendarMaster::new(date, HolidayName::new("年末休暇").unwrap());
+        repository.save(&holiday).await.unwrap();
+
+        let found = repository.find_by_date(date).await.unwrap();
+        assert_eq!(found, Some(holiday));
+
+        repository.delete(date).await.unwrap();
+        assert!(repository.find_by_date(date).await.unwrap().is_none());
+    }
+}
@@ -0,0 +1,246 @@
+// ClosingTimetableRepositoryImpl - 締めスケジュールリポジトリ実装
+// タスク定義と期間ごとの進捗を別のデータベースに保存する
+
+use std::{path::Path, sync::Arc};
+
+use chrono::{DateTime, Utc};
+use javelin_domain::{
+    error::{DomainError, DomainResult},
+    masters::{ClosingStep, ClosingTaskProgress, ClosingTaskStatus, ClosingTimetableItem},
+    repositories::ClosingTimetableRepository,
+};
+use lmdb::{Cursor, Database, DatabaseFlags, Environment, Transaction};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+struct StoredClosingTimetableItem {
+    code: String,
+    task: String,
+    owner: String,
+    due_offset_days: i32,
+    step: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct StoredClosingTaskProgress {
+    fiscal_year: i32,
+    period: u8,
+    code: String,
+    status: String,
+    updated_by: String,
+    updated_at: DateTime<Utc>,
+}
+
+pub struct ClosingTimetableRepositoryImpl {
+    env: Arc<Environment>,
+    items_db: Database,
+    progress_db: Database,
+}
+
+impl ClosingTimetableRepositoryImpl {
+    pub async fn new(path: &Path) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        if !path.exists() {
+            tokio::fs::create_dir_all(path).await?;
+        }
+
+        let env = Environment::new().set_max_dbs(2).set_map_size(10 * 1024 * 1024).open(path)?;
+
+        let items_db = env.create_db(Some("closing_timetable_items"), DatabaseFlags::empty())?;
+        let progress_db = env.create_db(Some("closing_task_progress"), DatabaseFlags::empty())?;
+
+        Ok(Self { env: Arc::new(env), items_db, progress_db })
+    }
+
+    /// 期間ごとにまとまって並ぶ進捗のキー（`{年}-{月}:` で前方一致検索する）
+    fn period_prefix(fiscal_year: i32, period: u8) -> String {
+        format!("{:04}-{:02}:", fiscal_year, period)
+    }
+
+    fn item_from_stored(stored: StoredClosingTimetableItem) -> DomainResult<ClosingTimetableItem> {
+        ClosingTimetableItem::new(
+            stored.code,
+            stored.task,
+            stored.owner,
+            stored.due_offset_days,
+            ClosingStep::parse(&stored.step)?,
+        )
+    }
+
+    fn progress_from_stored(
+        stored: StoredClosingTaskProgress,
+    ) -> DomainResult<ClosingTaskProgress> {
+        ClosingTaskProgress::new(
+            stored.fiscal_year,
+            stored.period,
+            stored.code,
+            ClosingTaskStatus::parse(&stored.status)?,
+            stored.updated_by,
+            stored.updated_at,
+        )
+    }
+
+    async fn put(&self, db: Database, key: String, value: Vec<u8>) -> DomainResult<()> {
+        let env = Arc::clone(&self.env);
+
+        tokio::task::spawn_blocking(move || {
+            let mut txn = env.begin_rw_txn()?;
+            txn.put(db, &key, &value, lmdb::WriteFlags::empty())?;
+            txn.commit()?;
+            Ok::<_, Box<dyn std::error::Error + Send + Sync>>(())
+        })
+        .await
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))
+    }
+}
+
+impl ClosingTimetableRepository for ClosingTimetableRepositoryImpl {
+    async fn find_items(&self) -> DomainResult<Vec<ClosingTimetableItem>> {
+        let env = Arc::clone(&self.env);
+        let db = self.items_db;
+
+        tokio::task::spawn_blocking(move || {
+            let txn = env.begin_ro_txn()?;
+            let mut cursor = txn.open_ro_cursor(db)?;
+            let mut items = Vec::new();
+
+            for (_key, value) in cursor.iter() {
+                let stored: StoredClosingTimetableItem = serde_json::from_slice(value)?;
+                items.push(Self::item_from_stored(stored)?);
+            }
+
+            Ok::<_, Box<dyn std::error::Error + Send + Sync>>(items)
+        })
+        .await
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))
+    }
+
+    async fn save_item(&self, item: &ClosingTimetableItem) -> DomainResult<()> {
+        let stored = StoredClosingTimetableItem {
+            code: item.code().to_string(),
+            task: item.task().to_string(),
+            owner: item.owner().to_string(),
+            due_offset_days: item.due_offset_days(),
+            step: item.step().as_str().to_string(),
+        };
+        let value =
+            serde_json::to_vec(&stored).map_err(|e| DomainError::RepositoryError(e.to_string()))?;
+
+        self.put(self.items_db, item.code().to_string(), value).await
+    }
+
+    async fn delete_item(&self, code: &str) -> DomainResult<()> {
+        let env = Arc::clone(&self.env);
+        let db = self.items_db;
+        let key = code.to_string();
+
+        tokio::task::spawn_blocking(move || {
+            let mut txn = env.begin_rw_txn()?;
+            match txn.del(db, &key, None) {
+                Ok(()) | Err(lmdb::Error::NotFound) => {}
+                Err(e) => return Err(e.into()),
+            }
+            txn.commit()?;
+            Ok::<_, Box<dyn std::error::Error + Send + Sync>>(())
+        })
+        .await
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))
+    }
+
+    async fn find_progress(
+        &self,
+        fiscal_year: i32,
+        period: u8,
+    ) -> DomainResult<Vec<ClosingTaskProgress>> {
+        let env = Arc::clone(&self.env);
+        let db = self.progress_db;
+        let prefix = Self::period_prefix(fiscal_year, period);
+
+        tokio::task::spawn_blocking(move || {
+            let txn = env.begin_ro_txn()?;
+            let mut cursor = txn.open_ro_cursor(db)?;
+            let mut progress = Vec::new();
+
+            // キーが期間順に並ぶため、対象期間の範囲を抜けた時点で終了
+            for (key, value) in cursor.iter() {
+                if key < prefix.as_bytes() {
+                    continue;
+                }
+                if !key.starts_with(prefix.as_bytes()) {
+                    break;
+                }
+                let stored: StoredClosingTaskProgress = serde_json::from_slice(value)?;
+                progress.push(Self::progress_from_stored(stored)?);
+            }
+
+            Ok::<_, Box<dyn std::error::Error + Send + Sync>>(progress)
+        })
+        .await
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))
+    }
+
+    async fn save_progress(&self, progress: &ClosingTaskProgress) -> DomainResult<()> {
+        let stored = StoredClosingTaskProgress {
+            fiscal_year: progress.fiscal_year(),
+            period: progress.period(),
+            code: progress.code().to_string(),
+            status: progress.status().as_str().to_string(),
+            updated_by: progress.updated_by().to_string(),
+            updated_at: progress.updated_at(),
+        };
+        let value =
+            serde_json::to_vec(&stored).map_err(|e| DomainError::RepositoryError(e.to_string()))?;
+        let key = format!(
+            "{}{}",
+            Self::period_prefix(progress.fiscal_year(), progress.period()),
+            progress.code()
+        );
+
+        self.put(self.progress_db, key, value).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_items_and_progress_round_trip_per_period() {
+        let temp_dir = TempDir::new().unwrap();
+        let repository = ClosingTimetableRepositoryImpl::new(temp_dir.path()).await.unwrap();
+        assert!(repository.find_items().await.unwrap().is_empty());
+
+        let item = ClosingTimetableItem::new("T01", "締日固定", "佐藤", 3, ClosingStep::PeriodLock)
+            .unwrap();
+        repository.save_item(&item).await.unwrap();
+        repository
+            .save_item(
+                &ClosingTimetableItem::new("T02", "売上確認", "田中", -1, ClosingStep::Manual)
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(repository.find_items().await.unwrap()[0], item);
+
+        for (period, status) in
+            [(4, ClosingTaskStatus::Completed), (5, ClosingTaskStatus::InProgress)]
+        {
+            let progress =
+                ClosingTaskProgress::new(2024, period, "T01", status, "sato", Utc::now()).unwrap();
+            repository.save_progress(&progress).await.unwrap();
+        }
+
+        let april = repository.find_progress(2024, 4).await.unwrap();
+        assert_eq!(april.len(), 1);
+        assert_eq!(april[0].status(), ClosingTaskStatus::Completed);
+        assert!(repository.find_progress(2024, 6).await.unwrap().is_empty());
+
+        repository.delete_item("T02").await.unwrap();
+        assert_eq!(repository.find_items().await.unwrap().len(), 1);
+    }
+}
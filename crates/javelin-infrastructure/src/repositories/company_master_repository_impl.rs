@@ -4,12 +4,14 @@ use std::{path::Path, sync::Arc};
 
 use javelin_domain::{
     error::DomainResult,
-    masters::{CompanyCode, CompanyMaster, CompanyName},
+    masters::{CompanyCode, CompanyMaster, CompanyName, MasterChange},
     repositories::CompanyMasterRepository,
 };
 use lmdb::{Cursor, Database, DatabaseFlags, Environment, Transaction};
 use serde::{Deserialize, Serialize};
 
+use super::master_change_store::{self, StoredMasterChange};
+
 #[derive(Debug, Serialize, Deserialize)]
 struct StoredCompanyMaster {
     code: String,
@@ -20,6 +22,8 @@ struct StoredCompanyMaster {
 pub struct CompanyMasterRepositoryImpl {
     env: Arc<Environment>,
     db: Database,
+    /// 変更イベント
+    change_db: Database,
 }
 
 impl CompanyMasterRepositoryImpl {
@@ -28,11 +32,12 @@ impl CompanyMasterRepositoryImpl {
             tokio::fs::create_dir_all(path).await?;
         }
 
-        let env = Environment::new().set_max_dbs(1).set_map_size(50 * 1024 * 1024).open(path)?;
+        let env = Environment::new().set_max_dbs(2).set_map_size(50 * 1024 * 1024).open(path)?;
 
         let db = env.create_db(Some("company_masters"), DatabaseFlags::empty())?;
+        let change_db = env.create_db(Some("company_master_changes"), DatabaseFlags::empty())?;
 
-        let repository = Self { env: Arc::new(env), db };
+        let repository = Self { env: Arc::new(env), db, change_db };
         repository.initialize_defaults().await?;
 
         Ok(repository)
@@ -174,4 +179,39 @@ impl CompanyMasterRepository for CompanyMasterRepositoryImpl {
 
         Ok(())
     }
+
+    async fn apply_change(&self, change: &MasterChange<CompanyMaster>) -> DomainResult<()> {
+        let stored = StoredMasterChange::to_stored(change, Self::to_stored);
+        let record_code = change.record_code().to_string();
+        let env = Arc::clone(&self.env);
+        let db = self.db;
+        let change_db = self.change_db;
+
+        tokio::task::spawn_blocking(move || {
+            master_change_store::apply_change(&env, db, change_db, &record_code, &stored)
+        })
+        .await
+        .map_err(|e| javelin_domain::error::DomainError::RepositoryError(e.to_string()))?
+    }
+
+    async fn find_changes(
+        &self,
+        code: &CompanyCode,
+    ) -> DomainResult<Vec<MasterChange<CompanyMaster>>> {
+        let env = Arc::clone(&self.env);
+        let change_db = self.change_db;
+        let record_code = code.value().to_string();
+
+        let stored = tokio::task::spawn_blocking(move || {
+            master_change_store::load_changes::<StoredCompanyMaster>(&env, change_db, &record_code)
+        })
+        .await
+        .map_err(|e| javelin_domain::error::DomainError::RepositoryError(e.to_string()))?
+        .map_err(|e| javelin_domain::error::DomainError::RepositoryError(e.to_string()))?;
+
+        stored
+            .into_iter()
+            .map(|change| change.into_change(code.value(), Self::from_stored))
+            .collect()
+    }
 }
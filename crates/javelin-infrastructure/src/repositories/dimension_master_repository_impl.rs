@@ -0,0 +1,182 @@
+// DimensionMasterRepositoryImpl - 分析軸マスタリポジトリ実装
+
+use std::{path::Path, sync::Arc};
+
+use javelin_domain::{
+    error::{DomainError, DomainResult},
+    masters::DimensionMaster,
+    repositories::DimensionMasterRepository,
+};
+use lmdb::{Cursor, Database, DatabaseFlags, Environment, Transaction};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+struct StoredDimensionMaster {
+    kind: String,
+    code: String,
+    name: String,
+    is_active: bool,
+}
+
+pub struct DimensionMasterRepositoryImpl {
+    env: Arc<Environment>,
+    db: Database,
+}
+
+impl DimensionMasterRepositoryImpl {
+    pub async fn new(path: &Path) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        if !path.exists() {
+            tokio::fs::create_dir_all(path).await?;
+        }
+
+        let env = Environment::new().set_max_dbs(1).set_map_size(10 * 1024 * 1024).open(path)?;
+
+        let db = env.create_db(Some("dimension_masters"), DatabaseFlags::empty())?;
+
+        Ok(Self { env: Arc::new(env), db })
+    }
+
+    /// 種類・コードの昇順で並ぶキー
+    fn key(kind: &str, code: &str) -> String {
+        format!("{}:{}", kind, code)
+    }
+
+    fn to_stored(master: &DimensionMaster) -> StoredDimensionMaster {
+        StoredDimensionMaster {
+            kind: master.kind().to_string(),
+            code: master.code().to_string(),
+            name: master.name().to_string(),
+            is_active: master.is_active(),
+        }
+    }
+
+    fn from_stored(stored: StoredDimensionMaster) -> DomainResult<DimensionMaster> {
+        DimensionMaster::new(stored.kind, stored.code, stored.name, stored.is_active)
+    }
+}
+
+impl DimensionMasterRepository for DimensionMasterRepositoryImpl {
+    async fn find(&self, kind: &str, code: &str) -> DomainResult<Option<DimensionMaster>> {
+        let env = Arc::clone(&self.env);
+        let db = self.db;
+        let key = Self::key(kind, code);
+
+        let result = tokio::task::spawn_blocking(move || {
+            let txn = env.begin_ro_txn()?;
+            match txn.get(db, &key) {
+                Ok(value) => {
+                    let stored: StoredDimensionMaster = serde_json::from_slice(value)?;
+                    let master = Self::from_stored(stored)?;
+                    Ok::<_, Box<dyn std::error::Error + Send + Sync>>(Some(master))
+                }
+                Err(lmdb::Error::NotFound) => Ok(None),
+                Err(e) => Err(e.into()),
+            }
+        })
+        .await
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?;
+
+        Ok(result)
+    }
+
+    async fn find_all(&self) -> DomainResult<Vec<DimensionMaster>> {
+        let env = Arc::clone(&self.env);
+        let db = self.db;
+
+        let result = tokio::task::spawn_blocking(move || {
+            let txn = env.begin_ro_txn()?;
+            let mut cursor = txn.open_ro_cursor(db)?;
+            let mut masters = Vec::new();
+
+            for (_key, value) in cursor.iter() {
+                let stored: StoredDimensionMaster = serde_json::from_slice(value)?;
+                masters.push(Self::from_stored(stored)?);
+            }
+
+            Ok::<_, Box<dyn std::error::Error + Send + Sync>>(masters)
+        })
+        .await
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?;
+
+        Ok(result)
+    }
+
+    async fn save(&self, master: &DimensionMaster) -> DomainResult<()> {
+        let stored = Self::to_stored(master);
+        let value =
+            serde_json::to_vec(&stored).map_err(|e| DomainError::RepositoryError(e.to_string()))?;
+
+        let env = Arc::clone(&self.env);
+        let db = self.db;
+        let key = Self::key(master.kind(), master.code());
+
+        tokio::task::spawn_blocking(move || {
+            let mut txn = env.begin_rw_txn()?;
+            txn.put(db, &key, &value, lmdb::WriteFlags::empty())?;
+            txn.commit()?;
+            Ok::<_, Box<dyn std::error::Error + Send + Sync>>(())
+        })
+        .await
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, kind: &str, code: &str) -> DomainResult<()> {
+        let env = Arc::clone(&self.env);
+        let db = self.db;
+        let key = Self::key(kind, code);
+
+        tokio::task::spawn_blocking(move || {
+            let mut txn = env.begin_rw_txn()?;
+            match txn.del(db, &key, None) {
+                Ok(()) | Err(lmdb::Error::NotFound) => {}
+                Err(e) => return Err(e.into()),
+            }
+            txn.commit()?;
+            Ok::<_, Box<dyn std::error::Error + Send + Sync>>(())
+        })
+        .await
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_save_deactivate_and_delete() {
+        let temp_dir = TempDir::new().unwrap();
+        let repository = DimensionMasterRepositoryImpl::new(temp_dir.path()).await.unwrap();
+        assert!(repository.find_all().await.unwrap().is_empty());
+
+        let mut master = DimensionMaster::new("project", "P001", "新基幹システム", true).unwrap();
+        repository.save(&master).await.unwrap();
+        repository
+            .save(&DimensionMaster::new("segment", "S1", "国内", true).unwrap())
+            .await
+            .unwrap();
+
+        master.deactivate();
+        repository.save(&master).await.unwrap();
+        let reloaded = repository.find("project", "P001").await.unwrap().unwrap();
+        assert!(!reloaded.is_active());
+        assert_eq!(reloaded.name(), "新基幹システム");
+
+        let all = repository.find_all().await.unwrap();
+        assert_eq!(all.len(), 2);
+        assert_eq!(all[0].kind(), "project");
+
+        repository.delete("project", "P001").await.unwrap();
+        assert!(repository.find("project", "P001").await.unwrap().is_none());
+    }
+}
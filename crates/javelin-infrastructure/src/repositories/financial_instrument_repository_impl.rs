@@ -0,0 +1,226 @@
+// FinancialInstrumentRepositoryImpl - 金融商品リポジトリ実装
+
+use std::{path::Path, sync::Arc};
+
+use chrono::NaiveDate;
+use javelin_domain::{
+    error::{DomainError, DomainResult},
+    financial_close::financial_instrument::{
+        FinancialInstrument, InstrumentKind, InstrumentPosition,
+    },
+    repositories::FinancialInstrumentRepository,
+};
+use lmdb::{Cursor, Database, DatabaseFlags, Environment, Transaction};
+use serde::{Deserialize, Serialize};
+
+/// 日付の保存形式
+const DATE_FORMAT: &str = "%Y-%m-%d";
+
+#[derive(Debug, Serialize, Deserialize)]
+struct StoredAccrual {
+    fiscal_year: i32,
+    period: u8,
+    entry_id: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct StoredFinancialInstrument {
+    instrument_id: String,
+    name: String,
+    kind: String,
+    position: String,
+    account_code: String,
+    currency: String,
+    face_value: f64,
+    initial_carrying_amount: f64,
+    coupon_rate: f64,
+    effective_rate: f64,
+    start_date: String,
+    maturity_date: String,
+    accruals: Vec<StoredAccrual>,
+}
+
+pub struct FinancialInstrumentRepositoryImpl {
+    env: Arc<Environment>,
+    db: Database,
+}
+
+impl FinancialInstrumentRepositoryImpl {
+    pub async fn new(path: &Path) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        if !path.exists() {
+            tokio::fs::create_dir_all(path).await?;
+        }
+
+        let env = Environment::new().set_max_dbs(1).set_map_size(10 * 1024 * 1024).open(path)?;
+
+        let db = env.create_db(Some("financial_instruments"), DatabaseFlags::empty())?;
+
+        Ok(Self { env: Arc::new(env), db })
+    }
+
+    fn to_stored(instrument: &FinancialInstrument) -> StoredFinancialInstrument {
+        StoredFinancialInstrument {
+            instrument_id: instrument.instrument_id().to_string(),
+            name: instrument.name().to_string(),
+            kind: instrument.kind().as_str().to_string(),
+            position: instrument.position().as_str().to_string(),
+            account_code: instrument.account_code().to_string(),
+            currency: instrument.currency().to_string(),
+            face_value: instrument.face_value(),
+            initial_carrying_amount: instrument.initial_carrying_amount(),
+            coupon_rate: instrument.coupon_rate(),
+            effective_rate: instrument.effective_rate(),
+            start_date: instrument.start_date().format(DATE_FORMAT).to_string(),
+            maturity_date: instrument.maturity_date().format(DATE_FORMAT).to_string(),
+            accruals: instrument
+                .accrued_entries()
+                .iter()
+                .map(|(&(fiscal_year, period), entry_id)| StoredAccrual {
+                    fiscal_year,
+                    period,
+                    entry_id: entry_id.clone(),
+                })
+                .collect(),
+        }
+    }
+
+    fn from_stored(stored: StoredFinancialInstrument) -> DomainResult<FinancialInstrument> {
+        let date = |value: &str| {
+            NaiveDate::parse_from_str(value, DATE_FORMAT)
+                .map_err(|e| DomainError::RepositoryError(format!("日付が不正です: {}", e)))
+        };
+
+        let mut instrument = FinancialInstrument::new(
+            stored.instrument_id,
+            stored.name,
+            InstrumentKind::parse(&stored.kind)?,
+            InstrumentPosition::parse(&stored.position)?,
+            stored.account_code,
+            stored.currency,
+            stored.face_value,
+            stored.initial_carrying_amount,
+            stored.coupon_rate,
+            stored.effective_rate,
+            date(&stored.start_date)?,
+            date(&stored.maturity_date)?,
+        )?;
+        for accrual in stored.accruals {
+            instrument.mark_accrued(accrual.fiscal_year, accrual.period, accrual.entry_id)?;
+        }
+        Ok(instrument)
+    }
+}
+
+impl FinancialInstrumentRepository for FinancialInstrumentRepositoryImpl {
+    async fn find(&self, instrument_id: &str) -> DomainResult<Option<FinancialInstrument>> {
+        let env = Arc::clone(&self.env);
+        let db = self.db;
+        let key = instrument_id.to_string();
+
+        let result = tokio::task::spawn_blocking(move || {
+            let txn = env.begin_ro_txn()?;
+            match txn.get(db, &key) {
+                Ok(value) => {
+                    let stored: StoredFinancialInstrument = serde_json::from_slice(value)?;
+                    let instrument = Self::from_stored(stored)?;
+                    Ok::<_, Box<dyn std::error::Error + Send + Sync>>(Some(instrument))
+                }
+                Err(lmdb::Error::NotFound) => Ok(None),
+                Err(e) => Err(e.into()),
+            }
+        })
+        .await
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?;
+
+        Ok(result)
+    }
+
+    async fn find_all(&self) -> DomainResult<Vec<FinancialInstrument>> {
+        let env = Arc::clone(&self.env);
+        let db = self.db;
+
+        let result = tokio::task::spawn_blocking(move || {
+            let txn = env.begin_ro_txn()?;
+            let mut cursor = txn.open_ro_cursor(db)?;
+            let mut instruments = Vec::new();
+
+            for (_key, value) in cursor.iter() {
+                let stored: StoredFinancialInstrument = serde_json::from_slice(value)?;
+                instruments.push(Self::from_stored(stored)?);
+            }
+
+            Ok::<_, Box<dyn std::error::Error + Send + Sync>>(instruments)
+        })
+        .await
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?;
+
+        Ok(result)
+    }
+
+    async fn save(&self, instrument: &FinancialInstrument) -> DomainResult<()> {
+        let stored = Self::to_stored(instrument);
+        let value =
+            serde_json::to_vec(&stored).map_err(|e| DomainError::RepositoryError(e.to_string()))?;
+
+        let env = Arc::clone(&self.env);
+        let db = self.db;
+        let key = instrument.instrument_id().to_string();
+
+        tokio::task::spawn_blocking(move || {
+            let mut txn = env.begin_rw_txn()?;
+            txn.put(db, &key, &value, lmdb::WriteFlags::empty())?;
+            txn.commit()?;
+            Ok::<_, Box<dyn std::error::Error + Send + Sync>>(())
+        })
+        .await
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+
+    fn instrument(instrument_id: &str) -> FinancialInstrument {
+        FinancialInstrument::new(
+            instrument_id,
+            "第1回社債",
+            InstrumentKind::Bond,
+            InstrumentPosition::Asset,
+            "1500",
+            "JPY",
+            1_000_000.0,
+            980_000.0,
+            0.01,
+            0.0305,
+            NaiveDate::from_ymd_opt(2024, 11, 1).unwrap(),
+            NaiveDate::from_ymd_opt(2025, 11, 1).unwrap(),
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_save_and_find() {
+        let temp_dir = TempDir::new().unwrap();
+        let repository = FinancialInstrumentRepositoryImpl::new(temp_dir.path()).await.unwrap();
+        assert!(repository.find("BOND-001").await.unwrap().is_none());
+
+        let mut bond = instrument("BOND-001");
+        bond.mark_accrued(2024, 11, "entry-1").unwrap();
+        repository.save(&bond).await.unwrap();
+        repository.save(&instrument("BOND-000")).await.unwrap();
+
+        assert_eq!(repository.find("BOND-001").await.unwrap().unwrap(), bond);
+
+        let all = repository.find_all().await.unwrap();
+        let ids: Vec<_> = all.iter().map(|instrument| instrument.instrument_id()).collect();
+        assert_eq!(ids, vec!["BOND-000", "BOND-001"]);
+    }
+}
@@ -0,0 +1,184 @@
+// InventoryWorksheetRepositoryImpl - 棚卸資産評価ワークシートリポジトリ実装
+
+use std::{path::Path, sync::Arc};
+
+use javelin_domain::{
+    error::{DomainError, DomainResult},
+    financial_close::inventory_valuation::{
+        InventoryValuationItem, InventoryWriteDownWorksheet, WriteDownMethod,
+    },
+    repositories::InventoryWorksheetRepository,
+};
+use lmdb::{Database, DatabaseFlags, Environment, Transaction};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+struct StoredInventoryValuationItem {
+    item_code: String,
+    item_name: String,
+    product_group: String,
+    quantity: f64,
+    unit_cost: f64,
+    unit_net_realizable_value: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct StoredInventoryWorksheet {
+    fiscal_year: i32,
+    period: u8,
+    method: String,
+    currency: String,
+    items: Vec<StoredInventoryValuationItem>,
+    posted_entry_id: Option<String>,
+}
+
+pub struct InventoryWorksheetRepositoryImpl {
+    env: Arc<Environment>,
+    db: Database,
+}
+
+impl InventoryWorksheetRepositoryImpl {
+    pub async fn new(path: &Path) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        if !path.exists() {
+            tokio::fs::create_dir_all(path).await?;
+        }
+
+        let env = Environment::new().set_max_dbs(1).set_map_size(10 * 1024 * 1024).open(path)?;
+
+        let db = env.create_db(Some("inventory_worksheets"), DatabaseFlags::empty())?;
+
+        Ok(Self { env: Arc::new(env), db })
+    }
+
+    fn to_stored(worksheet: &InventoryWriteDownWorksheet) -> StoredInventoryWorksheet {
+        StoredInventoryWorksheet {
+            fiscal_year: worksheet.fiscal_year(),
+            period: worksheet.period(),
+            method: worksheet.method().as_str().to_string(),
+            currency: worksheet.currency().to_string(),
+            items: worksheet
+                .items()
+                .iter()
+                .map(|item| StoredInventoryValuationItem {
+                    item_code: item.item_code().to_string(),
+                    item_name: item.item_name().to_string(),
+                    product_group: item.product_group().to_string(),
+                    quantity: item.quantity(),
+                    unit_cost: item.unit_cost(),
+                    unit_net_realizable_value: item.unit_net_realizable_value(),
+                })
+                .collect(),
+            posted_entry_id: worksheet.posted_entry_id().map(str::to_string),
+        }
+    }
+
+    fn from_stored(stored: StoredInventoryWorksheet) -> DomainResult<InventoryWriteDownWorksheet> {
+        let items = stored
+            .items
+            .into_iter()
+            .map(|item| {
+                InventoryValuationItem::new(
+                    item.item_code,
+                    item.item_name,
+                    item.product_group,
+                    item.quantity,
+                    item.unit_cost,
+                    item.unit_net_realizable_value,
+                )
+            })
+            .collect::<DomainResult<Vec<_>>>()?;
+
+        let mut worksheet = InventoryWriteDownWorksheet::new(
+            stored.fiscal_year,
+            stored.period,
+            WriteDownMethod::parse(&stored.method)?,
+            stored.currency,
+            items,
+        )?;
+        if let Some(entry_id) = stored.posted_entry_id {
+            worksheet.mark_posted(entry_id)?;
+        }
+        Ok(worksheet)
+    }
+}
+
+impl InventoryWorksheetRepository for InventoryWorksheetRepositoryImpl {
+    async fn find(
+        &self,
+        fiscal_year: i32,
+        period: u8,
+    ) -> DomainResult<Option<InventoryWriteDownWorksheet>> {
+        let env = Arc::clone(&self.env);
+        let db = self.db;
+        let key = InventoryWriteDownWorksheet::id_for(fiscal_year, period);
+
+        let result = tokio::task::spawn_blocking(move || {
+            let txn = env.begin_ro_txn()?;
+            match txn.get(db, &key) {
+                Ok(value) => {
+                    let stored: StoredInventoryWorksheet = serde_json::from_slice(value)?;
+                    let worksheet = Self::from_stored(stored)?;
+                    Ok::<_, Box<dyn std::error::Error + Send + Sync>>(Some(worksheet))
+                }
+                Err(lmdb::Error::NotFound) => Ok(None),
+                Err(e) => Err(e.into()),
+            }
+        })
+        .await
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?;
+
+        Ok(result)
+    }
+
+    async fn save(&self, worksheet: &InventoryWriteDownWorksheet) -> DomainResult<()> {
+        let stored = Self::to_stored(worksheet);
+        let value =
+            serde_json::to_vec(&stored).map_err(|e| DomainError::RepositoryError(e.to_string()))?;
+
+        let env = Arc::clone(&self.env);
+        let db = self.db;
+        let key = worksheet.worksheet_id();
+
+        tokio::task::spawn_blocking(move || {
+            let mut txn = env.begin_rw_txn()?;
+            txn.put(db, &key, &value, lmdb::WriteFlags::empty())?;
+            txn.commit()?;
+            Ok::<_, Box<dyn std::error::Error + Send + Sync>>(())
+        })
+        .await
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_save_and_find() {
+        let temp_dir = TempDir::new().unwrap();
+        let repository = InventoryWorksheetRepositoryImpl::new(temp_dir.path()).await.unwrap();
+        assert!(repository.find(2024, 3).await.unwrap().is_none());
+
+        let mut worksheet = InventoryWriteDownWorksheet::new(
+            2024,
+            3,
+            WriteDownMethod::ProductGroup,
+            "JPY",
+            vec![InventoryValuationItem::new("A-001", "商品A", "G1", 10.0, 100.0, 80.0).unwrap()],
+        )
+        .unwrap();
+        worksheet.mark_posted("entry-1").unwrap();
+        repository.save(&worksheet).await.unwrap();
+
+        let reloaded = repository.find(2024, 3).await.unwrap().unwrap();
+        assert_eq!(reloaded, worksheet);
+        assert!(repository.find(2024, 4).await.unwrap().is_none());
+    }
+}
@@ -0,0 +1,178 @@
+// JobRepositoryImpl - 長時間処理ジョブリポジトリ実装
+
+use std::{path::Path, sync::Arc};
+
+use chrono::{DateTime, Utc};
+use javelin_domain::{
+    error::{DomainError, DomainResult},
+    job::{Job, JobKind, JobStatus},
+    repositories::JobRepository,
+};
+use lmdb::{Cursor, Database, DatabaseFlags, Environment, Transaction, WriteFlags};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+struct StoredJob {
+    job_id: String,
+    kind: String,
+    parameters: Vec<(String, String)>,
+    status: String,
+    progress: u8,
+    message: Option<String>,
+    attempts: u32,
+    requested_by: String,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+pub struct JobRepositoryImpl {
+    env: Arc<Environment>,
+    db: Database,
+}
+
+impl JobRepositoryImpl {
+    pub async fn new(path: &Path) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        if !path.exists() {
+            tokio::fs::create_dir_all(path).await?;
+        }
+
+        let env = Environment::new().set_max_dbs(1).set_map_size(10 * 1024 * 1024).open(path)?;
+
+        let db = env.create_db(Some("jobs"), DatabaseFlags::empty())?;
+
+        Ok(Self { env: Arc::new(env), db })
+    }
+
+    fn to_stored(job: &Job) -> StoredJob {
+        StoredJob {
+            job_id: job.job_id().to_string(),
+            kind: job.kind().as_str().to_string(),
+            parameters: job.parameters().to_vec(),
+            status: job.status().as_str().to_string(),
+            progress: job.progress(),
+            message: job.message().map(str::to_string),
+            attempts: job.attempts(),
+            requested_by: job.requested_by().to_string(),
+            created_at: job.created_at(),
+            updated_at: job.updated_at(),
+        }
+    }
+
+    fn from_stored(stored: StoredJob) -> DomainResult<Job> {
+        Ok(Job::restore(
+            stored.job_id,
+            JobKind::parse(&stored.kind)?,
+            stored.parameters,
+            JobStatus::parse(&stored.status)?,
+            stored.progress,
+            stored.message,
+            stored.attempts,
+            stored.requested_by,
+            stored.created_at,
+            stored.updated_at,
+        ))
+    }
+}
+
+impl JobRepository for JobRepositoryImpl {
+    async fn save(&self, job: &Job) -> DomainResult<()> {
+        let stored = Self::to_stored(job);
+        let value =
+            serde_json::to_vec(&stored).map_err(|e| DomainError::RepositoryError(e.to_string()))?;
+
+        let env = Arc::clone(&self.env);
+        let db = self.db;
+        let key = job.job_id().to_string();
+
+        tokio::task::spawn_blocking(move || {
+            let mut txn = env.begin_rw_txn()?;
+            txn.put(db, &key, &value, WriteFlags::empty())?;
+            txn.commit()?;
+            Ok::<_, lmdb::Error>(())
+        })
+        .await
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn find_by_id(&self, job_id: &str) -> DomainResult<Option<Job>> {
+        let env = Arc::clone(&self.env);
+        let db = self.db;
+        let key = job_id.to_string();
+
+        let stored = tokio::task::spawn_blocking(move || {
+            let txn = env.begin_ro_txn()?;
+            match txn.get(db, &key) {
+                Ok(value) => Ok::<_, Box<dyn std::error::Error + Send + Sync>>(Some(
+                    serde_json::from_slice::<StoredJob>(value)?,
+                )),
+                Err(lmdb::Error::NotFound) => Ok(None),
+                Err(e) => Err(e.into()),
+            }
+        })
+        .await
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?;
+
+        stored.map(Self::from_stored).transpose()
+    }
+
+    async fn find_all(&self) -> DomainResult<Vec<Job>> {
+        let env = Arc::clone(&self.env);
+        let db = self.db;
+
+        let stored = tokio::task::spawn_blocking(move || {
+            let txn = env.begin_ro_txn()?;
+            let mut cursor = txn.open_ro_cursor(db)?;
+            let mut jobs = Vec::new();
+
+            for (_key, value) in cursor.iter() {
+                jobs.push(serde_json::from_slice::<StoredJob>(value)?);
+            }
+
+            Ok::<_, Box<dyn std::error::Error + Send + Sync>>(jobs)
+        })
+        .await
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?;
+
+        stored.into_iter().map(Self::from_stored).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_save_overwrites_and_survives_reopen() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut job = Job::enqueue(
+            "JOB-1",
+            JobKind::LedgerConsolidation,
+            vec![("period".to_string(), "3".to_string())],
+            "accountant",
+            Utc::now(),
+        )
+        .unwrap();
+
+        {
+            let repository = JobRepositoryImpl::new(temp_dir.path()).await.unwrap();
+            repository.save(&job).await.unwrap();
+            job.start(Utc::now()).unwrap();
+            job.report_progress(40, "集約中", Utc::now()).unwrap();
+            repository.save(&job).await.unwrap();
+        }
+
+        let repository = JobRepositoryImpl::new(temp_dir.path()).await.unwrap();
+        let reloaded = repository.find_by_id("JOB-1").await.unwrap().unwrap();
+        assert_eq!(reloaded, job);
+        assert_eq!(reloaded.status(), JobStatus::Running);
+        assert_eq!(repository.find_all().await.unwrap().len(), 1);
+        assert!(repository.find_by_id("JOB-2").await.unwrap().is_none());
+    }
+}
@@ -0,0 +1,139 @@
+// JournalImportTemplateRepositoryImpl - 仕訳取込テンプレートリポジトリ実装
+
+use std::{collections::BTreeMap, path::Path, sync::Arc};
+
+use javelin_domain::{
+    error::{DomainError, DomainResult},
+    masters::{ImportField, JournalImportTemplate},
+    repositories::JournalImportTemplateRepository,
+};
+use lmdb::{Database, DatabaseFlags, Environment, Transaction};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+struct StoredJournalImportTemplate {
+    signature: String,
+    /// 項目の識別子と列番号
+    columns: Vec<(String, usize)>,
+}
+
+pub struct JournalImportTemplateRepositoryImpl {
+    env: Arc<Environment>,
+    db: Database,
+}
+
+impl JournalImportTemplateRepositoryImpl {
+    pub async fn new(path: &Path) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        if !path.exists() {
+            tokio::fs::create_dir_all(path).await?;
+        }
+
+        let env = Environment::new().set_max_dbs(1).set_map_size(10 * 1024 * 1024).open(path)?;
+
+        let db = env.create_db(Some("journal_import_templates"), DatabaseFlags::empty())?;
+
+        Ok(Self { env: Arc::new(env), db })
+    }
+
+    fn to_stored(template: &JournalImportTemplate) -> StoredJournalImportTemplate {
+        StoredJournalImportTemplate {
+            signature: template.signature().to_string(),
+            columns: template
+                .columns()
+                .iter()
+                .map(|(field, column)| (field.as_str().to_string(), *column))
+                .collect(),
+        }
+    }
+
+    fn from_stored(stored: &StoredJournalImportTemplate) -> DomainResult<JournalImportTemplate> {
+        let columns = stored
+            .columns
+            .iter()
+            .map(|(field, column)| Ok((ImportField::parse(field)?, *column)))
+            .collect::<DomainResult<BTreeMap<_, _>>>()?;
+        JournalImportTemplate::new(&stored.signature, columns)
+    }
+}
+
+impl JournalImportTemplateRepository for JournalImportTemplateRepositoryImpl {
+    async fn find_by_signature(
+        &self,
+        signature: &str,
+    ) -> DomainResult<Option<JournalImportTemplate>> {
+        let env = Arc::clone(&self.env);
+        let db = self.db;
+        let key = signature.to_string();
+
+        let result = tokio::task::spawn_blocking(move || {
+            let txn = env.begin_ro_txn()?;
+            match txn.get(db, &key) {
+                Ok(value) => {
+                    let stored: StoredJournalImportTemplate = serde_json::from_slice(value)?;
+                    let template = Self::from_stored(&stored)?;
+                    Ok::<_, Box<dyn std::error::Error + Send + Sync>>(Some(template))
+                }
+                Err(lmdb::Error::NotFound) => Ok(None),
+                Err(e) => Err(e.into()),
+            }
+        })
+        .await
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?;
+
+        Ok(result)
+    }
+
+    async fn save(&self, template: &JournalImportTemplate) -> DomainResult<()> {
+        let stored = Self::to_stored(template);
+        let value =
+            serde_json::to_vec(&stored).map_err(|e| DomainError::RepositoryError(e.to_string()))?;
+
+        let env = Arc::clone(&self.env);
+        let db = self.db;
+        let key = template.signature().to_string();
+
+        tokio::task::spawn_blocking(move || {
+            let mut txn = env.begin_rw_txn()?;
+            txn.put(db, &key, &value, lmdb::WriteFlags::empty())?;
+            txn.commit()?;
+            Ok::<_, Box<dyn std::error::Error + Send + Sync>>(())
+        })
+        .await
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_save_and_find_by_signature() {
+        let temp_dir = TempDir::new().unwrap();
+        let repository = JournalImportTemplateRepositoryImpl::new(temp_dir.path()).await.unwrap();
+
+        let headers: Vec<String> =
+            ["日付", "伝票", "区分", "科目", "金額"].iter().map(|h| h.to_string()).collect();
+        let signature = JournalImportTemplate::signature_of(&headers);
+        assert!(repository.find_by_signature(&signature).await.unwrap().is_none());
+
+        let columns = BTreeMap::from([
+            (ImportField::TransactionDate, 0),
+            (ImportField::VoucherNumber, 1),
+            (ImportField::Side, 2),
+            (ImportField::AccountCode, 3),
+            (ImportField::Amount, 4),
+        ]);
+        let template = JournalImportTemplate::new(&signature, columns).unwrap();
+        repository.save(&template).await.unwrap();
+
+        let reloaded = repository.find_by_signature(&signature).await.unwrap().unwrap();
+        assert_eq!(reloaded, template);
+    }
+}
@@ -0,0 +1,183 @@
+// ManagementAccountMappingRepositoryImpl - 管理会計科目マッピングリポジトリ実装
+
+use std::{path::Path, sync::Arc};
+
+use javelin_domain::{
+    error::{DomainError, DomainResult},
+    masters::{AccountCode, ManagementAccount, ManagementAccountMapping},
+    repositories::ManagementAccountMappingRepository,
+};
+use lmdb::{Cursor, Database, DatabaseFlags, Environment, Transaction};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+struct StoredManagementAccountMapping {
+    account_code: String,
+    management_account_code: String,
+    management_account_name: String,
+}
+
+pub struct ManagementAccountMappingRepositoryImpl {
+    env: Arc<Environment>,
+    db: Database,
+}
+
+impl ManagementAccountMappingRepositoryImpl {
+    pub async fn new(path: &Path) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        if !path.exists() {
+            tokio::fs::create_dir_all(path).await?;
+        }
+
+        let env = Environment::new().set_max_dbs(1).set_map_size(10 * 1024 * 1024).open(path)?;
+
+        let db = env.create_db(Some("management_account_mappings"), DatabaseFlags::empty())?;
+
+        Ok(Self { env: Arc::new(env), db })
+    }
+
+    fn to_stored(mapping: &ManagementAccountMapping) -> StoredManagementAccountMapping {
+        StoredManagementAccountMapping {
+            account_code: mapping.account_code().value().to_string(),
+            management_account_code: mapping.management_account().code().to_string(),
+            management_account_name: mapping.management_account().name().to_string(),
+        }
+    }
+
+    fn from_stored(
+        stored: &StoredManagementAccountMapping,
+    ) -> DomainResult<ManagementAccountMapping> {
+        let account_code = AccountCode::new(&stored.account_code)?;
+        let management_account = ManagementAccount::new(
+            &stored.management_account_code,
+            &stored.management_account_name,
+        )?;
+        Ok(ManagementAccountMapping::new(account_code, management_account))
+    }
+}
+
+impl ManagementAccountMappingRepository for ManagementAccountMappingRepositoryImpl {
+    async fn find_by_account(
+        &self,
+        account_code: &AccountCode,
+    ) -> DomainResult<Option<ManagementAccountMapping>> {
+        let env = Arc::clone(&self.env);
+        let db = self.db;
+        let key = account_code.value().to_string();
+
+        let result = tokio::task::spawn_blocking(move || {
+            let txn = env.begin_ro_txn()?;
+            match txn.get(db, &key) {
+                Ok(value) => {
+                    let stored: StoredManagementAccountMapping = serde_json::from_slice(value)?;
+                    let mapping = Self::from_stored(&stored)?;
+                    Ok::<_, Box<dyn std::error::Error + Send + Sync>>(Some(mapping))
+                }
+                Err(lmdb::Error::NotFound) => Ok(None),
+                Err(e) => Err(e.into()),
+            }
+        })
+        .await
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?;
+
+        Ok(result)
+    }
+
+    async fn find_all(&self) -> DomainResult<Vec<ManagementAccountMapping>> {
+        let env = Arc::clone(&self.env);
+        let db = self.db;
+
+        let result = tokio::task::spawn_blocking(move || {
+            let txn = env.begin_ro_txn()?;
+            let mut cursor = txn.open_ro_cursor(db)?;
+            let mut mappings = Vec::new();
+
+            for (_key, value) in cursor.iter() {
+                let stored: StoredManagementAccountMapping = serde_json::from_slice(value)?;
+                mappings.push(Self::from_stored(&stored)?);
+            }
+
+            Ok::<_, Box<dyn std::error::Error + Send + Sync>>(mappings)
+        })
+        .await
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?;
+
+        Ok(result)
+    }
+
+    async fn save(&self, mapping: &ManagementAccountMapping) -> DomainResult<()> {
+        let stored = Self::to_stored(mapping);
+        let value =
+            serde_json::to_vec(&stored).map_err(|e| DomainError::RepositoryError(e.to_string()))?;
+
+        let env = Arc::clone(&self.env);
+        let db = self.db;
+        let key = mapping.account_code().value().to_string();
+
+        tokio::task::spawn_blocking(move || {
+            let mut txn = env.begin_rw_txn()?;
+            txn.put(db, &key, &value, lmdb::WriteFlags::empty())?;
+            txn.commit()?;
+            Ok::<_, Box<dyn std::error::Error + Send + Sync>>(())
+        })
+        .await
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, account_code: &AccountCode) -> DomainResult<()> {
+        let env = Arc::clone(&self.env);
+        let db = self.db;
+        let key = account_code.value().to_string();
+
+        tokio::task::spawn_blocking(move || {
+            let mut txn = env.begin_rw_txn()?;
+            match txn.del(db, &key, None) {
+                Ok(()) | Err(lmdb::Error::NotFound) => {}
+                Err(e) => return Err(e.into()),
+            }
+            txn.commit()?;
+            Ok::<_, Box<dyn std::error::Error + Send + Sync>>(())
+        })
+        .await
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_save_reassign_and_delete() {
+        let temp_dir = TempDir::new().unwrap();
+        let repository =
+            ManagementAccountMappingRepositoryImpl::new(temp_dir.path()).await.unwrap();
+        assert!(repository.find_all().await.unwrap().is_empty());
+
+        let code = AccountCode::new("5100").unwrap();
+        let mut mapping = ManagementAccountMapping::new(
+            code.clone(),
+            ManagementAccount::new("M510", "人件費").unwrap(),
+        );
+        repository.save(&mapping).await.unwrap();
+
+        mapping.reassign(ManagementAccount::new("M520", "本社費").unwrap());
+        repository.save(&mapping).await.unwrap();
+        let reloaded = repository.find_by_account(&code).await.unwrap().unwrap();
+        assert_eq!(reloaded.management_account().code(), "M520");
+        assert_eq!(reloaded.management_account().name(), "本社費");
+        assert_eq!(repository.find_all().await.unwrap().len(), 1);
+
+        repository.delete(&code).await.unwrap();
+        assert!(repository.find_by_account(&code).await.unwrap().is_none());
+    }
+}
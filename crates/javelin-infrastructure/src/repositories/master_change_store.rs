@@ -0,0 +1,138 @@
+// MasterChangeStore - マスタ変更イベントの保存
+// 責務: 勘定科目・会社マスタの変更イベントの記録と、変更後のイメージのマスタへの反映
+//
+// 変更イベントはマスタと同じLMDB環境の別DBに `<コード>:<連番>` をキーとして保存し、
+// マスタへの反映と同一トランザクションで書き込む。
+
+use chrono::{DateTime, Utc};
+use javelin_domain::{
+    error::{DomainError, DomainResult},
+    masters::{MasterChange, MasterRecord},
+};
+use lmdb::{Cursor, Database, Environment, Transaction, WriteFlags};
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+
+/// 保存形式の変更イベント（`S` はマスタの保存形式）
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct StoredMasterChange<S> {
+    sequence: u64,
+    before: Option<S>,
+    after: Option<S>,
+    changed_by: String,
+    changed_at: DateTime<Utc>,
+    #[serde(default)]
+    reverts: Option<u64>,
+}
+
+impl<S> StoredMasterChange<S> {
+    pub(crate) fn to_stored<T: MasterRecord>(
+        change: &MasterChange<T>,
+        to_stored: impl Fn(&T) -> S,
+    ) -> Self {
+        Self {
+            sequence: change.sequence(),
+            before: change.before().map(&to_stored),
+            after: change.after().map(&to_stored),
+            changed_by: change.changed_by().to_string(),
+            changed_at: change.changed_at(),
+            reverts: change.reverts(),
+        }
+    }
+
+    pub(crate) fn into_change<T: MasterRecord>(
+        self,
+        record_code: &str,
+        from_stored: impl Fn(&S) -> DomainResult<T>,
+    ) -> DomainResult<MasterChange<T>> {
+        Ok(MasterChange::restore(
+            self.sequence,
+            record_code,
+            self.before.as_ref().map(&from_stored).transpose()?,
+            self.after.as_ref().map(&from_stored).transpose()?,
+            self.changed_by,
+            self.changed_at,
+            self.reverts,
+        ))
+    }
+}
+
+fn change_key(record_code: &str, sequence: u64) -> String {
+    format!("{}:{:010}", record_code, sequence)
+}
+
+/// 変更イベントを記録し、変更後のイメージをマスタへ反映（同一トランザクション）
+pub(crate) fn apply_change<S: Serialize>(
+    env: &Environment,
+    master_db: Database,
+    change_db: Database,
+    record_code: &str,
+    change: &StoredMasterChange<S>,
+) -> DomainResult<()> {
+    let repository_error = |e: &dyn std::fmt::Display| DomainError::RepositoryError(e.to_string());
+    let change_value = serde_json::to_vec(change).map_err(|e| repository_error(&e))?;
+    let master_value = change
+        .after
+        .as_ref()
+        .map(serde_json::to_vec)
+        .transpose()
+        .map_err(|e| repository_error(&e))?;
+
+    let mut txn = env.begin_rw_txn().map_err(|e| repository_error(&e))?;
+    match txn.put(
+        change_db,
+        &change_key(record_code, change.sequence),
+        &change_value,
+        WriteFlags::NO_OVERWRITE,
+    ) {
+        Ok(()) => {}
+        Err(lmdb::Error::KeyExist) => {
+            return Err(DomainError::ValidationError(format!(
+                "{} は他の操作で変更されました。履歴を読み込み直してください",
+                record_code
+            )));
+        }
+        Err(e) => return Err(repository_error(&e)),
+    }
+    match master_value {
+        Some(value) => {
+            txn.put(master_db, &record_code, &value, WriteFlags::empty())
+                .map_err(|e| repository_error(&e))?;
+        }
+        None => match txn.del(master_db, &record_code, None) {
+            Ok(()) | Err(lmdb::Error::NotFound) => {}
+            Err(e) => return Err(repository_error(&e)),
+        },
+    }
+    txn.commit().map_err(|e| repository_error(&e))
+}
+
+/// レコードの変更イベントを記録順に取得
+pub(crate) fn load_changes<S: DeserializeOwned>(
+    env: &Environment,
+    change_db: Database,
+    record_code: &str,
+) -> Result<Vec<StoredMasterChange<S>>, Box<dyn std::error::Error + Send + Sync>> {
+    let prefix = format!("{}:", record_code);
+    let txn = env.begin_ro_txn()?;
+    let cursor = txn.open_ro_cursor(change_db)?;
+
+    let mut changes = Vec::new();
+    // iter_from は範囲外のキーで panic するため MDB_SET_RANGE で走査する
+    let mut entry = cursor.get(Some(prefix.as_bytes()), None, lmdb_sys::MDB_SET_RANGE);
+    loop {
+        let (key, value) = match entry {
+            Ok((Some(key), value)) => (key, value),
+            Ok((None, _)) | Err(lmdb::Error::NotFound) => break,
+            Err(e) => return Err(e.into()),
+        };
+        let Some(sequence) = key.strip_prefix(prefix.as_bytes()) else {
+            break;
+        };
+        // コードに ':' を含む別レコードのキーを除く
+        if sequence.len() == 10 && sequence.iter().all(u8::is_ascii_digit) {
+            changes.push(serde_json::from_slice(value)?);
+        }
+        entry = cursor.get(None, None, lmdb_sys::MDB_NEXT);
+    }
+    Ok(changes)
+}
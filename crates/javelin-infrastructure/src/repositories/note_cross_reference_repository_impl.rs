@@ -0,0 +1,229 @@
+// NoteCrossReferenceRepositoryImpl - 注記参照リポジトリ実装
+// 注記と注記参照を別のデータベースに保存する
+
+use std::{path::Path, sync::Arc};
+
+use javelin_domain::{
+    error::{DomainError, DomainResult},
+    masters::{NoteCrossReference, NoteReferenceTarget, NoteSection},
+    repositories::NoteCrossReferenceRepository,
+};
+use lmdb::{Cursor, Database, DatabaseFlags, Environment, Transaction};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+struct StoredNoteSection {
+    number: u32,
+    title: String,
+    body: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct StoredNoteCrossReference {
+    target: String,
+    note_number: u32,
+    required: bool,
+}
+
+pub struct NoteCrossReferenceRepositoryImpl {
+    env: Arc<Environment>,
+    sections_db: Database,
+    references_db: Database,
+}
+
+impl NoteCrossReferenceRepositoryImpl {
+    pub async fn new(path: &Path) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        if !path.exists() {
+            tokio::fs::create_dir_all(path).await?;
+        }
+
+        let env = Environment::new().set_max_dbs(2).set_map_size(10 * 1024 * 1024).open(path)?;
+
+        let sections_db = env.create_db(Some("note_sections"), DatabaseFlags::empty())?;
+        let references_db = env.create_db(Some("note_cross_references"), DatabaseFlags::empty())?;
+
+        Ok(Self { env: Arc::new(env), sections_db, references_db })
+    }
+
+    /// 注記番号順に並ぶキー
+    fn section_key(number: u32) -> String {
+        format!("{:03}", number)
+    }
+
+    /// 参照先ごとにまとまって並ぶキー
+    fn reference_key(target: &NoteReferenceTarget, note_number: u32) -> String {
+        format!("{}:{:03}", target.code(), note_number)
+    }
+
+    async fn put(&self, db: Database, key: String, value: Vec<u8>) -> DomainResult<()> {
+        let env = Arc::clone(&self.env);
+
+        tokio::task::spawn_blocking(move || {
+            let mut txn = env.begin_rw_txn()?;
+            txn.put(db, &key, &value, lmdb::WriteFlags::empty())?;
+            txn.commit()?;
+            Ok::<_, Box<dyn std::error::Error + Send + Sync>>(())
+        })
+        .await
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))
+    }
+
+    async fn delete(&self, db: Database, key: String) -> DomainResult<()> {
+        let env = Arc::clone(&self.env);
+
+        tokio::task::spawn_blocking(move || {
+            let mut txn = env.begin_rw_txn()?;
+            match txn.del(db, &key, None) {
+                Ok(()) | Err(lmdb::Error::NotFound) => {}
+                Err(e) => return Err(e.into()),
+            }
+            txn.commit()?;
+            Ok::<_, Box<dyn std::error::Error + Send + Sync>>(())
+        })
+        .await
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))
+    }
+}
+
+impl NoteCrossReferenceRepository for NoteCrossReferenceRepositoryImpl {
+    async fn find_sections(&self) -> DomainResult<Vec<NoteSection>> {
+        let env = Arc::clone(&self.env);
+        let db = self.sections_db;
+
+        tokio::task::spawn_blocking(move || {
+            let txn = env.begin_ro_txn()?;
+            let mut cursor = txn.open_ro_cursor(db)?;
+            let mut sections = Vec::new();
+
+            for (_key, value) in cursor.iter() {
+                let stored: StoredNoteSection = serde_json::from_slice(value)?;
+                sections.push(NoteSection::new(stored.number, stored.title, stored.body)?);
+            }
+
+            Ok::<_, Box<dyn std::error::Error + Send + Sync>>(sections)
+        })
+        .await
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))
+    }
+
+    async fn save_section(&self, section: &NoteSection) -> DomainResult<()> {
+        let stored = StoredNoteSection {
+            number: section.number(),
+            title: section.title().to_string(),
+            body: section.body().to_string(),
+        };
+        let value =
+            serde_json::to_vec(&stored).map_err(|e| DomainError::RepositoryError(e.to_string()))?;
+
+        self.put(self.sections_db, Self::section_key(section.number()), value).await
+    }
+
+    async fn delete_section(&self, number: u32) -> DomainResult<()> {
+        self.delete(self.sections_db, Self::section_key(number)).await
+    }
+
+    async fn find_references(&self) -> DomainResult<Vec<NoteCrossReference>> {
+        let env = Arc::clone(&self.env);
+        let db = self.references_db;
+
+        tokio::task::spawn_blocking(move || {
+            let txn = env.begin_ro_txn()?;
+            let mut cursor = txn.open_ro_cursor(db)?;
+            let mut references = Vec::new();
+
+            for (_key, value) in cursor.iter() {
+                let stored: StoredNoteCrossReference = serde_json::from_slice(value)?;
+                references.push(NoteCrossReference::new(
+                    NoteReferenceTarget::parse(&stored.target)?,
+                    stored.note_number,
+                    stored.required,
+                )?);
+            }
+
+            Ok::<_, Box<dyn std::error::Error + Send + Sync>>(references)
+        })
+        .await
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))
+    }
+
+    async fn save_reference(&self, reference: &NoteCrossReference) -> DomainResult<()> {
+        let stored = StoredNoteCrossReference {
+            target: reference.target().code().to_string(),
+            note_number: reference.note_number(),
+            required: reference.is_required(),
+        };
+        let value =
+            serde_json::to_vec(&stored).map_err(|e| DomainError::RepositoryError(e.to_string()))?;
+        let key = Self::reference_key(reference.target(), reference.note_number());
+
+        self.put(self.references_db, key, value).await
+    }
+
+    async fn delete_reference(
+        &self,
+        target: &NoteReferenceTarget,
+        note_number: u32,
+    ) -> DomainResult<()> {
+        self.delete(self.references_db, Self::reference_key(target, note_number)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use javelin_domain::masters::StatementLine;
+    use tempfile::TempDir;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_sections_and_references_round_trip() {
+        let temp_dir = TempDir::new().unwrap();
+        let repository = NoteCrossReferenceRepositoryImpl::new(temp_dir.path()).await.unwrap();
+        assert!(repository.find_sections().await.unwrap().is_empty());
+
+        for (number, title) in [(12, "有形固定資産"), (3, "収益認識")] {
+            repository
+                .save_section(&NoteSection::new(number, title, "").unwrap())
+                .await
+                .unwrap();
+        }
+        let numbers: Vec<u32> = repository
+            .find_sections()
+            .await
+            .unwrap()
+            .iter()
+            .map(NoteSection::number)
+            .collect();
+        assert_eq!(numbers, vec![3, 12]);
+
+        let caption = NoteReferenceTarget::Caption(StatementLine::Revenue);
+        let account = NoteReferenceTarget::parse("1500").unwrap();
+        repository
+            .save_reference(&NoteCrossReference::new(caption.clone(), 3, true).unwrap())
+            .await
+            .unwrap();
+        repository
+            .save_reference(&NoteCrossReference::new(account.clone(), 12, false).unwrap())
+            .await
+            .unwrap();
+        // 同じ参照先・注記番号は置き換える
+        repository
+            .save_reference(&NoteCrossReference::new(caption.clone(), 3, false).unwrap())
+            .await
+            .unwrap();
+
+        let references = repository.find_references().await.unwrap();
+        assert_eq!(references.len(), 2);
+        assert!(references.iter().all(|reference| !reference.is_required()));
+        assert!(references.iter().any(|reference| reference.target() == &account));
+
+        repository.delete_reference(&caption, 3).await.unwrap();
+        repository.delete_section(12).await.unwrap();
+        assert_eq!(repository.find_references().await.unwrap().len(), 1);
+        assert_eq!(repository.find_sections().await.unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,221 @@
+// ReportArchiveRepositoryImpl - 帳票アーカイブリポジトリ実装
+
+use std::{path::Path, sync::Arc};
+
+use chrono::{DateTime, Utc};
+use javelin_domain::{
+    error::{DomainError, DomainResult},
+    financial_close::report_archive::{ArchivedReport, ReportLine},
+    repositories::ReportArchiveRepository,
+};
+use lmdb::{Cursor, Database, DatabaseFlags, Environment, Transaction, WriteFlags};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+struct StoredReportLine {
+    label: String,
+    amount: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct StoredArchivedReport {
+    archive_id: String,
+    report_type: String,
+    fiscal_year: i32,
+    period: u8,
+    parameters: Vec<(String, String)>,
+    lines: Vec<StoredReportLine>,
+    content_hash: String,
+    signed_by: String,
+    signed_at: DateTime<Utc>,
+}
+
+pub struct ReportArchiveRepositoryImpl {
+    env: Arc<Environment>,
+    db: Database,
+}
+
+impl ReportArchiveRepositoryImpl {
+    pub async fn new(path: &Path) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        if !path.exists() {
+            tokio::fs::create_dir_all(path).await?;
+        }
+
+        let env = Environment::new().set_max_dbs(1).set_map_size(10 * 1024 * 1024).open(path)?;
+
+        let db = env.create_db(Some("report_archives"), DatabaseFlags::empty())?;
+
+        Ok(Self { env: Arc::new(env), db })
+    }
+
+    fn to_stored(report: &ArchivedReport) -> StoredArchivedReport {
+        StoredArchivedReport {
+            archive_id: report.archive_id().to_string(),
+            report_type: report.report_type().to_string(),
+            fiscal_year: report.fiscal_year(),
+            period: report.period(),
+            parameters: report.parameters().to_vec(),
+            lines: report
+                .lines()
+                .iter()
+                .map(|line| StoredReportLine { label: line.label.clone(), amount: line.amount })
+                .collect(),
+            content_hash: report.content_hash().to_string(),
+            signed_by: report.signed_by().to_string(),
+            signed_at: report.signed_at(),
+        }
+    }
+
+    fn from_stored(stored: StoredArchivedReport) -> ArchivedReport {
+        ArchivedReport::restore(
+            stored.archive_id,
+            stored.report_type,
+            stored.fiscal_year,
+            stored.period,
+            stored.parameters,
+            stored
+                .lines
+                .into_iter()
+                .map(|line| ReportLine::new(line.label, line.amount))
+                .collect(),
+            stored.content_hash,
+            stored.signed_by,
+            stored.signed_at,
+        )
+    }
+}
+
+impl ReportArchiveRepository for ReportArchiveRepositoryImpl {
+    async fn append(&self, report: &ArchivedReport) -> DomainResult<()> {
+        let stored = Self::to_stored(report);
+        let value =
+            serde_json::to_vec(&stored).map_err(|e| DomainError::RepositoryError(e.to_string()))?;
+
+        let env = Arc::clone(&self.env);
+        let db = self.db;
+        let key = report.archive_id().to_string();
+
+        // 既存の帳票は上書きしない（NO_OVERWRITE）
+        let appended = tokio::task::spawn_blocking(move || {
+            let mut txn = env.begin_rw_txn()?;
+            match txn.put(db, &key, &value, WriteFlags::NO_OVERWRITE) {
+                Ok(()) => {
+                    txn.commit()?;
+                    Ok::<_, Box<dyn std::error::Error + Send + Sync>>(true)
+                }
+                Err(lmdb::Error::KeyExist) => Ok(false),
+                Err(e) => Err(e.into()),
+            }
+        })
+        .await
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?;
+
+        if !appended {
+            return Err(DomainError::ValidationError(format!(
+                "アーカイブ済みの帳票は上書きできません: {}",
+                report.archive_id()
+            )));
+        }
+        Ok(())
+    }
+
+    async fn find_by_id(&self, archive_id: &str) -> DomainResult<Option<ArchivedReport>> {
+        let env = Arc::clone(&self.env);
+        let db = self.db;
+        let key = archive_id.to_string();
+
+        let result = tokio::task::spawn_blocking(move || {
+            let txn = env.begin_ro_txn()?;
+            match txn.get(db, &key) {
+                Ok(value) => {
+                    let stored: StoredArchivedReport = serde_json::from_slice(value)?;
+                    Ok::<_, Box<dyn std::error::Error + Send + Sync>>(Some(Self::from_stored(
+                        stored,
+                    )))
+                }
+                Err(lmdb::Error::NotFound) => Ok(None),
+                Err(e) => Err(e.into()),
+            }
+        })
+        .await
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?;
+
+        Ok(result)
+    }
+
+    async fn find_all(&self) -> DomainResult<Vec<ArchivedReport>> {
+        let env = Arc::clone(&self.env);
+        let db = self.db;
+
+        let result = tokio::task::spawn_blocking(move || {
+            let txn = env.begin_ro_txn()?;
+            let mut cursor = txn.open_ro_cursor(db)?;
+            let mut reports = Vec::new();
+
+            for (_key, value) in cursor.iter() {
+                let stored: StoredArchivedReport = serde_json::from_slice(value)?;
+                reports.push(Self::from_stored(stored));
+            }
+
+            Ok::<_, Box<dyn std::error::Error + Send + Sync>>(reports)
+        })
+        .await
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?;
+
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use javelin_domain::financial_close::report_archive::ReportDigester;
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::services::ReportDigesterImpl;
+
+    fn report(signed_at: DateTime<Utc>) -> ArchivedReport {
+        ArchivedReport::sign(
+            "FS",
+            2024,
+            3,
+            vec![("status_scope".to_string(), "PostedOnly".to_string())],
+            vec![ReportLine::new("売上収益", 1_000_000.0)],
+            "accountant",
+            signed_at,
+            &ReportDigesterImpl,
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_append_and_find_keeps_hash_valid() {
+        let temp_dir = TempDir::new().unwrap();
+        let repository = ReportArchiveRepositoryImpl::new(temp_dir.path()).await.unwrap();
+        let archived = report(Utc::now());
+
+        repository.append(&archived).await.unwrap();
+
+        let reloaded = repository.find_by_id(archived.archive_id()).await.unwrap().unwrap();
+        assert_eq!(reloaded, archived);
+        assert!(reloaded.verify(&ReportDigesterImpl));
+        assert_eq!(repository.find_all().await.unwrap().len(), 1);
+        assert!(repository.find_by_id("FS-2024-04-00000000000000").await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_append_rejects_overwrite() {
+        let temp_dir = TempDir::new().unwrap();
+        let repository = ReportArchiveRepositoryImpl::new(temp_dir.path()).await.unwrap();
+        let archived = report(Utc::now());
+        repository.append(&archived).await.unwrap();
+
+        let result = repository.append(&archived).await;
+
+        assert!(matches!(result, Err(DomainError::ValidationError(_))));
+        assert_eq!(ReportDigesterImpl.digest(&archived.signing_payload()), archived.content_hash());
+    }
+}
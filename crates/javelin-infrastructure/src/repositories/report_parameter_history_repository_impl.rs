@@ -0,0 +1,128 @@
+// ReportParameterHistoryRepositoryImpl - 帳票の最近使った条件リポジトリ実装
+
+use std::{path::Path, sync::Arc};
+
+use javelin_domain::{
+    error::{DomainError, DomainResult},
+    masters::{ReportParameterHistory, ReportParameterValues},
+    repositories::ReportParameterHistoryRepository,
+};
+use lmdb::{Database, DatabaseFlags, Environment, Transaction};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+struct StoredReportParameterHistory {
+    user_id: String,
+    report_id: String,
+    recent: Vec<ReportParameterValues>,
+}
+
+pub struct ReportParameterHistoryRepositoryImpl {
+    env: Arc<Environment>,
+    db: Database,
+}
+
+impl ReportParameterHistoryRepositoryImpl {
+    pub async fn new(path: &Path) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        if !path.exists() {
+            tokio::fs::create_dir_all(path).await?;
+        }
+
+        let env = Environment::new().set_max_dbs(1).set_map_size(10 * 1024 * 1024).open(path)?;
+
+        let db = env.create_db(Some("report_parameter_history"), DatabaseFlags::empty())?;
+
+        Ok(Self { env: Arc::new(env), db })
+    }
+
+    /// 保存キー（利用者ごとに帳票ID順に並ぶ）
+    fn key(user_id: &str, report_id: &str) -> String {
+        format!("{}:{}", user_id, report_id)
+    }
+}
+
+impl ReportParameterHistoryRepository for ReportParameterHistoryRepositoryImpl {
+    async fn find(
+        &self,
+        user_id: &str,
+        report_id: &str,
+    ) -> DomainResult<Option<ReportParameterHistory>> {
+        let env = Arc::clone(&self.env);
+        let db = self.db;
+        let key = Self::key(user_id, report_id);
+
+        let result = tokio::task::spawn_blocking(move || {
+            let txn = env.begin_ro_txn()?;
+            match txn.get(db, &key) {
+                Ok(value) => {
+                    let stored: StoredReportParameterHistory = serde_json::from_slice(value)?;
+                    let history = ReportParameterHistory::new(
+                        stored.user_id,
+                        stored.report_id,
+                        stored.recent,
+                    )?;
+                    Ok::<_, Box<dyn std::error::Error + Send + Sync>>(Some(history))
+                }
+                Err(lmdb::Error::NotFound) => Ok(None),
+                Err(e) => Err(e.into()),
+            }
+        })
+        .await
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?;
+
+        Ok(result)
+    }
+
+    async fn save(&self, history: &ReportParameterHistory) -> DomainResult<()> {
+        let stored = StoredReportParameterHistory {
+            user_id: history.user_id().to_string(),
+            report_id: history.report_id().to_string(),
+            recent: history.recent().to_vec(),
+        };
+        let value =
+            serde_json::to_vec(&stored).map_err(|e| DomainError::RepositoryError(e.to_string()))?;
+
+        let env = Arc::clone(&self.env);
+        let db = self.db;
+        let key = Self::key(history.user_id(), history.report_id());
+
+        tokio::task::spawn_blocking(move || {
+            let mut txn = env.begin_rw_txn()?;
+            txn.put(db, &key, &value, lmdb::WriteFlags::empty())?;
+            txn.commit()?;
+            Ok::<_, Box<dyn std::error::Error + Send + Sync>>(())
+        })
+        .await
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use tempfile::TempDir;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_save_and_find_per_user_and_report() {
+        let temp_dir = TempDir::new().unwrap();
+        let repository = ReportParameterHistoryRepositoryImpl::new(temp_dir.path()).await.unwrap();
+        assert!(repository.find("alice", "trial_balance").await.unwrap().is_none());
+
+        let values = BTreeMap::from([("period".to_string(), "2024-03".to_string())]);
+        let history =
+            ReportParameterHistory::new("alice", "trial_balance", vec![values.clone()]).unwrap();
+        repository.save(&history).await.unwrap();
+
+        let found = repository.find("alice", "trial_balance").await.unwrap().unwrap();
+        assert_eq!(found.recent(), &[values]);
+        assert!(repository.find("bob", "trial_balance").await.unwrap().is_none());
+        assert!(repository.find("alice", "audit_export").await.unwrap().is_none());
+    }
+}
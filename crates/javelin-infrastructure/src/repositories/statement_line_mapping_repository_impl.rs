@@ -0,0 +1,203 @@
+// StatementLineMappingRepositoryImpl - 財務諸表表示科目マッピングリポジトリ実装
+
+use std::{path::Path, sync::Arc};
+
+use javelin_domain::{
+    error::{DomainError, DomainResult},
+    masters::{AccountCode, StatementLine, StatementLineMapping},
+    repositories::StatementLineMappingRepository,
+};
+use lmdb::{Cursor, Database, DatabaseFlags, Environment, Transaction};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+struct StoredStatementLineMapping {
+    account_code: String,
+    statement_line: String,
+}
+
+pub struct StatementLineMappingRepositoryImpl {
+    env: Arc<Environment>,
+    db: Database,
+}
+
+impl StatementLineMappingRepositoryImpl {
+    pub async fn new(path: &Path) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        if !path.exists() {
+            tokio::fs::create_dir_all(path).await?;
+        }
+
+        let env = Environment::new().set_max_dbs(1).set_map_size(10 * 1024 * 1024).open(path)?;
+
+        let db = env.create_db(Some("statement_line_mappings"), DatabaseFlags::empty())?;
+
+        let repository = Self { env: Arc::new(env), db };
+        repository.initialize_defaults().await?;
+
+        Ok(repository)
+    }
+
+    /// 初期勘定科目マスタに対応するマッピングを登録
+    async fn initialize_defaults(&self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let env = Arc::clone(&self.env);
+        let db = self.db;
+
+        let is_empty = tokio::task::spawn_blocking(move || {
+            let txn = env.begin_ro_txn()?;
+            let mut cursor = txn.open_ro_cursor(db)?;
+            Ok::<_, Box<dyn std::error::Error + Send + Sync>>(cursor.iter().next().is_none())
+        })
+        .await??;
+
+        if is_empty {
+            let defaults = [
+                ("1000", StatementLine::CurrentAssets),
+                ("1100", StatementLine::CurrentAssets),
+                ("2000", StatementLine::CurrentLiabilities),
+                ("3000", StatementLine::Equity),
+                ("4000", StatementLine::Revenue),
+                ("5000", StatementLine::CostOfSales),
+            ];
+
+            for (code, line) in defaults {
+                self.save(&StatementLineMapping::new(AccountCode::new(code)?, line)).await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn to_stored(mapping: &StatementLineMapping) -> StoredStatementLineMapping {
+        StoredStatementLineMapping {
+            account_code: mapping.account_code().value().to_string(),
+            statement_line: mapping.statement_line().code().to_string(),
+        }
+    }
+
+    fn from_stored(stored: &StoredStatementLineMapping) -> DomainResult<StatementLineMapping> {
+        let account_code = AccountCode::new(&stored.account_code)?;
+        let statement_line = StatementLine::from_code(&stored.statement_line)?;
+        Ok(StatementLineMapping::new(account_code, statement_line))
+    }
+}
+
+impl StatementLineMappingRepository for StatementLineMappingRepositoryImpl {
+    async fn find_by_account(
+        &self,
+        account_code: &AccountCode,
+    ) -> DomainResult<Option<StatementLineMapping>> {
+        let env = Arc::clone(&self.env);
+        let db = self.db;
+        let key = account_code.value().to_string();
+
+        let result = tokio::task::spawn_blocking(move || {
+            let txn = env.begin_ro_txn()?;
+            match txn.get(db, &key) {
+                Ok(value) => {
+                    let stored: StoredStatementLineMapping = serde_json::from_slice(value)?;
+                    let mapping = Self::from_stored(&stored)?;
+                    Ok::<_, Box<dyn std::error::Error + Send + Sync>>(Some(mapping))
+                }
+                Err(lmdb::Error::NotFound) => Ok(None),
+                Err(e) => Err(e.into()),
+            }
+        })
+        .await
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?;
+
+        Ok(result)
+    }
+
+    async fn find_all(&self) -> DomainResult<Vec<StatementLineMapping>> {
+        let env = Arc::clone(&self.env);
+        let db = self.db;
+
+        let result = tokio::task::spawn_blocking(move || {
+            let txn = env.begin_ro_txn()?;
+            let mut cursor = txn.open_ro_cursor(db)?;
+            let mut mappings = Vec::new();
+
+            for (_key, value) in cursor.iter() {
+                let stored: StoredStatementLineMapping = serde_json::from_slice(value)?;
+                mappings.push(Self::from_stored(&stored)?);
+            }
+
+            Ok::<_, Box<dyn std::error::Error + Send + Sync>>(mappings)
+        })
+        .await
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?;
+
+        Ok(result)
+    }
+
+    async fn save(&self, mapping: &StatementLineMapping) -> DomainResult<()> {
+        let stored = Self::to_stored(mapping);
+        let value =
+            serde_json::to_vec(&stored).map_err(|e| DomainError::RepositoryError(e.to_string()))?;
+
+        let env = Arc::clone(&self.env);
+        let db = self.db;
+        let key = mapping.account_code().value().to_string();
+
+        tokio::task::spawn_blocking(move || {
+            let mut txn = env.begin_rw_txn()?;
+            txn.put(db, &key, &value, lmdb::WriteFlags::empty())?;
+            txn.commit()?;
+            Ok::<_, Box<dyn std::error::Error + Send + Sync>>(())
+        })
+        .await
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, account_code: &AccountCode) -> DomainResult<()> {
+        let env = Arc::clone(&self.env);
+        let db = self.db;
+        let key = account_code.value().to_string();
+
+        tokio::task::spawn_blocking(move || {
+            let mut txn = env.begin_rw_txn()?;
+            match txn.del(db, &key, None) {
+                Ok(()) | Err(lmdb::Error::NotFound) => {}
+                Err(e) => return Err(e.into()),
+            }
+            txn.commit()?;
+            Ok::<_, Box<dyn std::error::Error + Send + Sync>>(())
+        })
+        .await
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_defaults_and_reassign() {
+        let temp_dir = TempDir::new().unwrap();
+        let repository = StatementLineMappingRepositoryImpl::new(temp_dir.path()).await.unwrap();
+
+        let code = AccountCode::new("1100").unwrap();
+        let mut mapping = repository.find_by_account(&code).await.unwrap().unwrap();
+        assert_eq!(mapping.statement_line(), StatementLine::CurrentAssets);
+
+        mapping.reassign(StatementLine::NonCurrentAssets);
+        repository.save(&mapping).await.unwrap();
+        let reloaded = repository.find_by_account(&code).await.unwrap().unwrap();
+        assert_eq!(reloaded.statement_line(), StatementLine::NonCurrentAssets);
+
+        repository.delete(&code).await.unwrap();
+        assert!(repository.find_by_account(&code).await.unwrap().is_none());
+        assert_eq!(repository.find_all().await.unwrap().len(), 5);
+    }
+}
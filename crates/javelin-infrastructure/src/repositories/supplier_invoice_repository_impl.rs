@@ -0,0 +1,228 @@
+// SupplierInvoiceRepositoryImpl - 仕入先請求書リポジトリ実装
+
+use std::{path::Path, sync::Arc};
+
+use chrono::NaiveDate;
+use javelin_domain::{
+    error::{DomainError, DomainResult},
+    financial_close::supplier_invoice::SupplierInvoice,
+    repositories::SupplierInvoiceRepository,
+};
+use lmdb::{Cursor, Database, DatabaseFlags, Environment, Transaction};
+use serde::{Deserialize, Serialize};
+
+/// 日付の保存形式
+const DATE_FORMAT: &str = "%Y-%m-%d";
+
+/// キーの仕入先と請求書番号の区切り（仕入先・請求書番号順に並ぶよう制御文字を使う）
+const KEY_SEPARATOR: char = '\u{1f}';
+
+#[derive(Debug, Serialize, Deserialize)]
+struct StoredPayment {
+    paid_date: String,
+    amount: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct StoredSupplierInvoice {
+    invoice_number: String,
+    counterparty: String,
+    invoice_date: String,
+    due_date: String,
+    expense_account_code: String,
+    currency: String,
+    amount: f64,
+    tax_amount: f64,
+    journal_entry_id: Option<String>,
+    payments: Vec<StoredPayment>,
+}
+
+pub struct SupplierInvoiceRepositoryImpl {
+    env: Arc<Environment>,
+    db: Database,
+}
+
+impl SupplierInvoiceRepositoryImpl {
+    pub async fn new(path: &Path) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        if !path.exists() {
+            tokio::fs::create_dir_all(path).await?;
+        }
+
+        let env = Environment::new().set_max_dbs(1).set_map_size(10 * 1024 * 1024).open(path)?;
+
+        let db = env.create_db(Some("supplier_invoices"), DatabaseFlags::empty())?;
+
+        Ok(Self { env: Arc::new(env), db })
+    }
+
+    fn key(counterparty: &str, invoice_number: &str) -> String {
+        format!("{}{}{}", counterparty, KEY_SEPARATOR, invoice_number)
+    }
+
+    fn to_stored(invoice: &SupplierInvoice) -> StoredSupplierInvoice {
+        StoredSupplierInvoice {
+            invoice_number: invoice.invoice_number().to_string(),
+            counterparty: invoice.counterparty().to_string(),
+            invoice_date: invoice.invoice_date().format(DATE_FORMAT).to_string(),
+            due_date: invoice.due_date().format(DATE_FORMAT).to_string(),
+            expense_account_code: invoice.expense_account_code().to_string(),
+            currency: invoice.currency().to_string(),
+            amount: invoice.amount(),
+            tax_amount: invoice.tax_amount(),
+            journal_entry_id: invoice.journal_entry_id().map(str::to_string),
+            payments: invoice
+                .payments()
+                .iter()
+                .map(|payment| StoredPayment {
+                    paid_date: payment.paid_date.format(DATE_FORMAT).to_string(),
+                    amount: payment.amount,
+                })
+                .collect(),
+        }
+    }
+
+    fn from_stored(stored: StoredSupplierInvoice) -> DomainResult<SupplierInvoice> {
+        let date = |value: &str| {
+            NaiveDate::parse_from_str(value, DATE_FORMAT)
+                .map_err(|e| DomainError::RepositoryError(format!("日付が不正です: {}", e)))
+        };
+
+        let mut invoice = SupplierInvoice::new(
+            stored.invoice_number,
+            stored.counterparty,
+            date(&stored.invoice_date)?,
+            date(&stored.due_date)?,
+            stored.expense_account_code,
+            stored.currency,
+            stored.amount,
+            stored.tax_amount,
+        )?;
+        if let Some(entry_id) = stored.journal_entry_id {
+            invoice.mark_journalized(entry_id)?;
+        }
+        for payment in stored.payments {
+            invoice.record_payment(date(&payment.paid_date)?, payment.amount)?;
+        }
+        Ok(invoice)
+    }
+}
+
+impl SupplierInvoiceRepository for SupplierInvoiceRepositoryImpl {
+    async fn find(
+        &self,
+        counterparty: &str,
+        invoice_number: &str,
+    ) -> DomainResult<Option<SupplierInvoice>> {
+        let env = Arc::clone(&self.env);
+        let db = self.db;
+        let key = Self::key(counterparty, invoice_number);
+
+        let result = tokio::task::spawn_blocking(move || {
+            let txn = env.begin_ro_txn()?;
+            match txn.get(db, &key) {
+                Ok(value) => {
+                    let stored: StoredSupplierInvoice = serde_json::from_slice(value)?;
+                    let invoice = Self::from_stored(stored)?;
+                    Ok::<_, Box<dyn std::error::Error + Send + Sync>>(Some(invoice))
+                }
+                Err(lmdb::Error::NotFound) => Ok(None),
+                Err(e) => Err(e.into()),
+            }
+        })
+        .await
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?;
+
+        Ok(result)
+    }
+
+    async fn find_all(&self) -> DomainResult<Vec<SupplierInvoice>> {
+        let env = Arc::clone(&self.env);
+        let db = self.db;
+
+        let result = tokio::task::spawn_blocking(move || {
+            let txn = env.begin_ro_txn()?;
+            let mut cursor = txn.open_ro_cursor(db)?;
+            let mut invoices = Vec::new();
+
+            for (_key, value) in cursor.iter() {
+                let stored: StoredSupplierInvoice = serde_json::from_slice(value)?;
+                invoices.push(Self::from_stored(stored)?);
+            }
+
+            Ok::<_, Box<dyn std::error::Error + Send + Sync>>(invoices)
+        })
+        .await
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?;
+
+        Ok(result)
+    }
+
+    async fn save(&self, invoice: &SupplierInvoice) -> DomainResult<()> {
+        let stored = Self::to_stored(invoice);
+        let value =
+            serde_json::to_vec(&stored).map_err(|e| DomainError::RepositoryError(e.to_string()))?;
+
+        let env = Arc::clone(&self.env);
+        let db = self.db;
+        let key = Self::key(invoice.counterparty(), invoice.invoice_number());
+
+        tokio::task::spawn_blocking(move || {
+            let mut txn = env.begin_rw_txn()?;
+            txn.put(db, &key, &value, lmdb::WriteFlags::empty())?;
+            txn.commit()?;
+            Ok::<_, Box<dyn std::error::Error + Send + Sync>>(())
+        })
+        .await
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+
+    fn invoice(counterparty: &str, invoice_number: &str) -> SupplierInvoice {
+        SupplierInvoice::new(
+            invoice_number,
+            counterparty,
+            NaiveDate::from_ymd_opt(2024, 4, 5).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 5, 31).unwrap(),
+            "5100",
+            "JPY",
+            100_000.0,
+            10_000.0,
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_save_and_find() {
+        let temp_dir = TempDir::new().unwrap();
+        let repository = SupplierInvoiceRepositoryImpl::new(temp_dir.path()).await.unwrap();
+        assert!(repository.find("X001", "INV-001").await.unwrap().is_none());
+
+        let mut paid = invoice("X001", "INV-001");
+        paid.mark_journalized("entry-1").unwrap();
+        paid.record_payment(NaiveDate::from_ymd_opt(2024, 5, 31).unwrap(), 50_000.0)
+            .unwrap();
+        repository.save(&paid).await.unwrap();
+        repository.save(&invoice("X002", "INV-001")).await.unwrap();
+        repository.save(&invoice("X001", "INV-000")).await.unwrap();
+
+        assert_eq!(repository.find("X001", "INV-001").await.unwrap().unwrap(), paid);
+
+        let all = repository.find_all().await.unwrap();
+        let keys: Vec<_> = all
+            .iter()
+            .map(|invoice| (invoice.counterparty(), invoice.invoice_number()))
+            .collect();
+        assert_eq!(keys, vec![("X001", "INV-000"), ("X001", "INV-001"), ("X002", "INV-001")]);
+    }
+}
@@ -0,0 +1,154 @@
+// TablePreferenceRepositoryImpl - 一覧テーブル表示設定リポジトリ実装
+
+use std::{path::Path, sync::Arc};
+
+use javelin_domain::{
+    error::{DomainError, DomainResult},
+    masters::{ColumnPreference, TablePreference, TableSort},
+    repositories::TablePreferenceRepository,
+};
+use lmdb::{Database, DatabaseFlags, Environment, Transaction};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+struct StoredColumnPreference {
+    column: usize,
+    visible: bool,
+    width: u16,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct StoredTableSort {
+    column: usize,
+    descending: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct StoredTablePreference {
+    table_id: String,
+    columns: Vec<StoredColumnPreference>,
+    sort: Option<StoredTableSort>,
+}
+
+pub struct TablePreferenceRepositoryImpl {
+    env: Arc<Environment>,
+    db: Database,
+}
+
+impl TablePreferenceRepositoryImpl {
+    pub async fn new(path: &Path) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        if !path.exists() {
+            tokio::fs::create_dir_all(path).await?;
+        }
+
+        let env = Environment::new().set_max_dbs(1).set_map_size(10 * 1024 * 1024).open(path)?;
+
+        let db = env.create_db(Some("table_preferences"), DatabaseFlags::empty())?;
+
+        Ok(Self { env: Arc::new(env), db })
+    }
+
+    fn to_stored(preference: &TablePreference) -> StoredTablePreference {
+        StoredTablePreference {
+            table_id: preference.table_id().to_string(),
+            columns: preference
+                .columns()
+                .iter()
+                .map(|c| StoredColumnPreference {
+                    column: c.column(),
+                    visible: c.visible(),
+                    width: c.width(),
+                })
+                .collect(),
+            sort: preference
+                .sort()
+                .map(|s| StoredTableSort { column: s.column(), descending: s.descending() }),
+        }
+    }
+
+    fn from_stored(stored: StoredTablePreference) -> DomainResult<TablePreference> {
+        let columns = stored
+            .columns
+            .into_iter()
+            .map(|c| ColumnPreference::new(c.column, c.visible, c.width))
+            .collect::<DomainResult<Vec<_>>>()?;
+        let sort = stored.sort.map(|s| TableSort::new(s.column, s.descending));
+        TablePreference::new(stored.table_id, columns, sort)
+    }
+}
+
+impl TablePreferenceRepository for TablePreferenceRepositoryImpl {
+    async fn find(&self, table_id: &str) -> DomainResult<Option<TablePreference>> {
+        let env = Arc::clone(&self.env);
+        let db = self.db;
+        let key = table_id.to_string();
+
+        let result = tokio::task::spawn_blocking(move || {
+            let txn = env.begin_ro_txn()?;
+            match txn.get(db, &key) {
+                Ok(value) => {
+                    let stored: StoredTablePreference = serde_json::from_slice(value)?;
+                    let preference = Self::from_stored(stored)?;
+                    Ok::<_, Box<dyn std::error::Error + Send + Sync>>(Some(preference))
+                }
+                Err(lmdb::Error::NotFound) => Ok(None),
+                Err(e) => Err(e.into()),
+            }
+        })
+        .await
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?;
+
+        Ok(result)
+    }
+
+    async fn save(&self, preference: &TablePreference) -> DomainResult<()> {
+        let stored = Self::to_stored(preference);
+        let value =
+            serde_json::to_vec(&stored).map_err(|e| DomainError::RepositoryError(e.to_string()))?;
+
+        let env = Arc::clone(&self.env);
+        let db = self.db;
+        let key = preference.table_id().to_string();
+
+        tokio::task::spawn_blocking(move || {
+            let mut txn = env.begin_rw_txn()?;
+            txn.put(db, &key, &value, lmdb::WriteFlags::empty())?;
+            txn.commit()?;
+            Ok::<_, Box<dyn std::error::Error + Send + Sync>>(())
+        })
+        .await
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_save_and_find() {
+        let temp_dir = TempDir::new().unwrap();
+        let repository = TablePreferenceRepositoryImpl::new(temp_dir.path()).await.unwrap();
+        assert!(repository.find("ledger").await.unwrap().is_none());
+
+        let preference = TablePreference::new(
+            "ledger",
+            vec![
+                ColumnPreference::new(2, true, 40).unwrap(),
+                ColumnPreference::new(0, false, 12).unwrap(),
+            ],
+            Some(TableSort::new(2, true)),
+        )
+        .unwrap();
+        repository.save(&preference).await.unwrap();
+
+        let reloaded = repository.find("ledger").await.unwrap().unwrap();
+        assert_eq!(reloaded, preference);
+    }
+}
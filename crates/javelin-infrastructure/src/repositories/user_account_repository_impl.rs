@@ -0,0 +1,150 @@
+// UserAccountRepositoryImpl - 利用者アカウントリポジトリ実装
+
+use std::{path::Path, sync::Arc};
+
+use chrono::{DateTime, Utc};
+use javelin_domain::{
+    error::{DomainError, DomainResult},
+    masters::{PasswordHash, UserAccount},
+    repositories::UserAccountRepository,
+};
+use lmdb::{Cursor, Database, DatabaseFlags, Environment, Transaction};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+struct StoredUserAccount {
+    user_id: String,
+    display_name: String,
+    password_hash: String,
+    created_at: DateTime<Utc>,
+    last_login_at: Option<DateTime<Utc>>,
+}
+
+pub struct UserAccountRepositoryImpl {
+    env: Arc<Environment>,
+    db: Database,
+}
+
+impl UserAccountRepositoryImpl {
+    pub async fn new(path: &Path) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        if !path.exists() {
+            tokio::fs::create_dir_all(path).await?;
+        }
+
+        let env = Environment::new().set_max_dbs(1).set_map_size(10 * 1024 * 1024).open(path)?;
+
+        let db = env.create_db(Some("user_accounts"), DatabaseFlags::empty())?;
+
+        Ok(Self { env: Arc::new(env), db })
+    }
+}
+
+impl UserAccountRepository for UserAccountRepositoryImpl {
+    async fn find_by_id(&self, user_id: &str) -> DomainResult<Option<UserAccount>> {
+        let env = Arc::clone(&self.env);
+        let db = self.db;
+        let key = user_id.to_string();
+
+        let stored = tokio::task::spawn_blocking(move || {
+            let txn = env.begin_ro_txn()?;
+            match txn.get(db, &key) {
+                Ok(value) => {
+                    let stored: StoredUserAccount = serde_json::from_slice(value)?;
+                    Ok::<_, Box<dyn std::error::Error + Send + Sync>>(Some(stored))
+                }
+                Err(lmdb::Error::NotFound) => Ok(None),
+                Err(e) => Err(e.into()),
+            }
+        })
+        .await
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?;
+
+        stored
+            .map(|stored| {
+                Ok(UserAccount::reconstruct(
+                    stored.user_id,
+                    stored.display_name,
+                    PasswordHash::new(stored.password_hash)?,
+                    stored.created_at,
+                    stored.last_login_at,
+                ))
+            })
+            .transpose()
+    }
+
+    async fn save(&self, account: &UserAccount) -> DomainResult<()> {
+        let key = account.user_id().to_string();
+        let value = serde_json::to_vec(&StoredUserAccount {
+            user_id: account.user_id().to_string(),
+            display_name: account.display_name().to_string(),
+            password_hash: account.password_hash().as_str().to_string(),
+            created_at: account.created_at(),
+            last_login_at: account.last_login_at(),
+        })
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?;
+
+        let env = Arc::clone(&self.env);
+        let db = self.db;
+
+        tokio::task::spawn_blocking(move || {
+            let mut txn = env.begin_rw_txn()?;
+            txn.put(db, &key, &value, lmdb::WriteFlags::empty())?;
+            txn.commit()?;
+            Ok::<_, Box<dyn std::error::Error + Send + Sync>>(())
+        })
+        .await
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn count(&self) -> DomainResult<usize> {
+        let env = Arc::clone(&self.env);
+        let db = self.db;
+
+        let result = tokio::task::spawn_blocking(move || {
+            let txn = env.begin_ro_txn()?;
+            let mut cursor = txn.open_ro_cursor(db)?;
+            Ok::<_, Box<dyn std::error::Error + Send + Sync>>(cursor.iter().count())
+        })
+        .await
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?;
+
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use javelin_domain::masters::PasswordHasher;
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::services::PasswordHasherImpl;
+
+    #[tokio::test]
+    async fn test_account_round_trip() {
+        let temp_dir = TempDir::new().unwrap();
+        let repository = UserAccountRepositoryImpl::new(temp_dir.path()).await.unwrap();
+        let hasher = PasswordHasherImpl::with_params(
+            argon2::Params::new(argon2::Params::MIN_M_COST, 1, 1, None).unwrap(),
+        );
+
+        assert_eq!(repository.count().await.unwrap(), 0);
+        assert!(repository.find_by_id("tanaka").await.unwrap().is_none());
+
+        let mut account =
+            UserAccount::new("tanaka", "田中", hasher.hash("passw0rd").unwrap()).unwrap();
+        repository.save(&account).await.unwrap();
+
+        account.record_login(Utc::now());
+        repository.save(&account).await.unwrap();
+
+        let reloaded = repository.find_by_id("tanaka").await.unwrap().unwrap();
+        assert_eq!(reloaded, account);
+        assert_eq!(repository.count().await.unwrap(), 1);
+    }
+}
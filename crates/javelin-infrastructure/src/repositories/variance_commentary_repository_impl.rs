@@ -0,0 +1,191 @@
+// VarianceCommentaryRepositoryImpl - 増減コメントリポジトリ実装
+
+use std::{path::Path, sync::Arc};
+
+use javelin_domain::{
+    error::{DomainError, DomainResult},
+    financial_close::variance_commentary::VarianceCommentary,
+    repositories::VarianceCommentaryRepository,
+};
+use lmdb::{Cursor, Database, DatabaseFlags, Environment, Transaction};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+struct StoredVarianceCommentary {
+    account_code: String,
+    fiscal_year: i32,
+    period: u8,
+    commentary: String,
+    written_by: String,
+    written_at: String,
+}
+
+pub struct VarianceCommentaryRepositoryImpl {
+    env: Arc<Environment>,
+    commentaries_db: Database,
+}
+
+impl VarianceCommentaryRepositoryImpl {
+    pub async fn new(path: &Path) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        if !path.exists() {
+            tokio::fs::create_dir_all(path).await?;
+        }
+
+        let env = Environment::new().set_max_dbs(1).set_map_size(10 * 1024 * 1024).open(path)?;
+
+        let commentaries_db =
+            env.create_db(Some("variance_commentaries"), DatabaseFlags::empty())?;
+
+        Ok(Self { env: Arc::new(env), commentaries_db })
+    }
+
+    /// 保存キー（会計期間順・科目コード順に並ぶ）
+    fn key(account_code: &str, fiscal_year: i32, period: u8) -> String {
+        format!("{}:{}", Self::period_prefix(fiscal_year, period), account_code)
+    }
+
+    fn period_prefix(fiscal_year: i32, period: u8) -> String {
+        format!("{:04}-{:02}", fiscal_year, period)
+    }
+
+    fn to_stored(commentary: &VarianceCommentary) -> StoredVarianceCommentary {
+        StoredVarianceCommentary {
+            account_code: commentary.account_code().to_string(),
+            fiscal_year: commentary.fiscal_year(),
+            period: commentary.period(),
+            commentary: commentary.commentary().to_string(),
+            written_by: commentary.written_by().to_string(),
+            written_at: commentary.written_at().to_string(),
+        }
+    }
+
+    fn from_stored(stored: StoredVarianceCommentary) -> DomainResult<VarianceCommentary> {
+        VarianceCommentary::new(
+            stored.account_code,
+            stored.fiscal_year,
+            stored.period,
+            stored.commentary,
+            stored.written_by,
+            stored.written_at,
+        )
+    }
+}
+
+impl VarianceCommentaryRepository for VarianceCommentaryRepositoryImpl {
+    async fn find_by_period(
+        &self,
+        fiscal_year: i32,
+        period: u8,
+    ) -> DomainResult<Vec<VarianceCommentary>> {
+        let env = Arc::clone(&self.env);
+        let db = self.commentaries_db;
+        let prefix = format!("{}:", Self::period_prefix(fiscal_year, period));
+
+        let result = tokio::task::spawn_blocking(move || {
+            let txn = env.begin_ro_txn()?;
+            let cursor = txn.open_ro_cursor(db)?;
+            let mut commentaries = Vec::new();
+
+            // iter_from は範囲外のキーで panic するため MDB_SET_RANGE で走査する
+            let mut entry = cursor.get(Some(prefix.as_bytes()), None, lmdb_sys::MDB_SET_RANGE);
+            loop {
+                let (key, value) = match entry {
+                    Ok((Some(key), value)) => (key, value),
+                    Ok((None, _)) | Err(lmdb::Error::NotFound) => break,
+                    Err(e) => return Err(e.into()),
+                };
+                if !key.starts_with(prefix.as_bytes()) {
+                    break;
+                }
+                let stored: StoredVarianceCommentary = serde_json::from_slice(value)?;
+                commentaries.push(Self::from_stored(stored)?);
+                entry = cursor.get(None, None, lmdb_sys::MDB_NEXT);
+            }
+
+            Ok::<_, Box<dyn std::error::Error + Send + Sync>>(commentaries)
+        })
+        .await
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?;
+
+        Ok(result)
+    }
+
+    async fn save(&self, commentary: &VarianceCommentary) -> DomainResult<()> {
+        let stored = Self::to_stored(commentary);
+        let value =
+            serde_json::to_vec(&stored).map_err(|e| DomainError::RepositoryError(e.to_string()))?;
+
+        let env = Arc::clone(&self.env);
+        let db = self.commentaries_db;
+        let key =
+            Self::key(commentary.account_code(), commentary.fiscal_year(), commentary.period());
+
+        tokio::task::spawn_blocking(move || {
+            let mut txn = env.begin_rw_txn()?;
+            txn.put(db, &key, &value, lmdb::WriteFlags::empty())?;
+            txn.commit()?;
+            Ok::<_, Box<dyn std::error::Error + Send + Sync>>(())
+        })
+        .await
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, account_code: &str, fiscal_year: i32, period: u8) -> DomainResult<()> {
+        let env = Arc::clone(&self.env);
+        let db = self.commentaries_db;
+        let key = Self::key(account_code, fiscal_year, period);
+
+        tokio::task::spawn_blocking(move || {
+            let mut txn = env.begin_rw_txn()?;
+            match txn.del(db, &key, None) {
+                Ok(()) | Err(lmdb::Error::NotFound) => {}
+                Err(e) => return Err(e.into()),
+            }
+            txn.commit()?;
+            Ok::<_, Box<dyn std::error::Error + Send + Sync>>(())
+        })
+        .await
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?
+        .map_err(|e| DomainError::RepositoryError(e.to_string()))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+
+    fn commentary(account_code: &str, period: u8, text: &str) -> VarianceCommentary {
+        VarianceCommentary::new(account_code, 2024, period, text, "alice", "2024-04-05T00:00:00Z")
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_save_find_and_delete_by_period() {
+        let temp_dir = TempDir::new().unwrap();
+        let repository = VarianceCommentaryRepositoryImpl::new(temp_dir.path()).await.unwrap();
+        assert!(repository.find_by_period(2024, 3).await.unwrap().is_empty());
+
+        repository.save(&commentary("1100", 3, "大口入金")).await.unwrap();
+        repository.save(&commentary("1000", 3, "小口現金の補充")).await.unwrap();
+        repository.save(&commentary("1100", 4, "翌月分")).await.unwrap();
+        // 同じ科目・会計期間は置き換える
+        repository.save(&commentary("1100", 3, "期末の大口入金")).await.unwrap();
+
+        let march = repository.find_by_period(2024, 3).await.unwrap();
+        let texts: Vec<_> = march.iter().map(|c| (c.account_code(), c.commentary())).collect();
+        assert_eq!(texts, vec![("1000", "小口現金の補充"), ("1100", "期末の大口入金")]);
+
+        repository.delete("1100", 2024, 3).await.unwrap();
+        repository.delete("9999", 2024, 3).await.unwrap();
+        assert_eq!(repository.find_by_period(2024, 3).await.unwrap().len(), 1);
+        assert_eq!(repository.find_by_period(2024, 4).await.unwrap().len(), 1);
+    }
+}
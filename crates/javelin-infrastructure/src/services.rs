@@ -1,5 +1,17 @@
 // Services module
 
+pub mod entry_number_generator_impl;
+pub mod export_protection_impl;
+pub mod password_hasher_impl;
+pub mod report_delivery_impl;
+pub mod report_digester_impl;
+pub mod spreadsheet_reader_impl;
 pub mod voucher_number_generator_impl;
 
+pub use entry_number_generator_impl::EntryNumberGeneratorImpl;
+pub use export_protection_impl::{EXPORT_SIGNING_KEY_FILE, ExportProtectionImpl};
+pub use password_hasher_impl::PasswordHasherImpl;
+pub use report_delivery_impl::ReportDeliveryImpl;
+pub use report_digester_impl::ReportDigesterImpl;
+pub use spreadsheet_reader_impl::SpreadsheetReaderImpl;
 pub use voucher_number_generator_impl::VoucherNumberGeneratorImpl;
@@ -0,0 +1,193 @@
+// 記帳時の伝票番号生成サービスの実装
+
+use std::{
+    collections::{BTreeSet, HashMap, HashSet},
+    sync::Arc,
+};
+
+use javelin_domain::{
+    error::{DomainError, DomainResult},
+    financial_close::journal_entry::{
+        events::JournalEntryEvent,
+        services::{EntryNumberExistenceChecker, EntryNumberGenerator},
+        values::{EntryNumber, EntryNumberScope},
+    },
+};
+use tokio::sync::Mutex;
+
+use crate::EventStore;
+
+/// 記帳時の伝票番号生成サービスの実装
+///
+/// 会社・会計年度ごとに連番を管理する。
+/// フォーマット: "EN-{会社コード}-{会計年度}-{6桁連番}"
+/// 例: EN-1000-2024-000001, EN-1000-2024-000002, ...
+///
+/// 記帳済みの番号はイベントストアの記帳イベントから索引を作り、前回以降に追加された
+/// イベントだけを取り込んで更新する。再起動後も続きから採番し、他の経路で記帳された
+/// 番号も重複の確認の対象とする。
+///
+/// 払い出した番号は記帳イベントを取り込むか `release` されるまで予約中とし、
+/// 記帳に失敗して戻された番号は次の採番で再び払い出す（欠番にしない）。
+pub struct EntryNumberGeneratorImpl {
+    event_store: Arc<EventStore>,
+    index: Mutex<PostedEntryNumbers>,
+}
+
+/// 記帳済みの伝票番号の索引
+#[derive(Debug, Default)]
+struct PostedEntryNumbers {
+    /// 取り込み済みの最後のイベントのシーケンス
+    last_sequence: u64,
+    posted: HashSet<String>,
+    /// 採番範囲ごとの記帳済みの最大連番
+    last_posted: HashMap<EntryNumberScope, u64>,
+    /// 採番範囲ごとの払い出し済み・記帳前の連番
+    reserved: HashMap<EntryNumberScope, BTreeSet<u64>>,
+}
+
+impl PostedEntryNumbers {
+    fn record_posted(&mut self, entry_number: EntryNumber) {
+        if let Some((scope, sequence)) = entry_number.scope() {
+            let last = self.last_posted.entry(scope.clone()).or_default();
+            *last = (*last).max(sequence);
+            if let Some(reserved) = self.reserved.get_mut(&scope) {
+                reserved.remove(&sequence);
+            }
+        }
+        self.posted.insert(entry_number.value().to_string());
+    }
+
+    /// 記帳済みの最大連番より後で、予約中でない最小の連番
+    fn next_sequence(&self, scope: &EntryNumberScope) -> u64 {
+        let mut sequence = self.last_posted.get(scope).copied().unwrap_or_default() + 1;
+        if let Some(reserved) = self.reserved.get(scope) {
+            while reserved.contains(&sequence) {
+                sequence += 1;
+            }
+        }
+        sequence
+    }
+}
+
+impl EntryNumberGeneratorImpl {
+    pub fn new(event_store: Arc<EventStore>) -> Self {
+        Self { event_store, index: Mutex::new(PostedEntryNumbers::default()) }
+    }
+
+    /// 前回の取り込み以降に追加された記帳イベントを索引へ取り込む
+    async fn catch_up(&self, index: &mut PostedEntryNumbers) -> DomainResult<()> {
+        let events = self
+            .event_store
+            .get_all_events(index.last_sequence + 1)
+            .await
+            .map_err(|e| DomainError::RepositoryError(e.to_string()))?;
+
+        for stored in events {
+            index.last_sequence = index.last_sequence.max(stored.global_sequence);
+            if stored.event_type != "Posted" {
+                continue;
+            }
+            if let Ok(JournalEntryEvent::Posted { entry_number, .. }) =
+                serde_json::from_slice::<JournalEntryEvent>(&stored.payload)
+                && let Ok(entry_number) = EntryNumber::new(entry_number)
+            {
+                index.record_posted(entry_number);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl EntryNumberGenerator for EntryNumberGeneratorImpl {
+    async fn next(&self, scope: &EntryNumberScope) -> DomainResult<EntryNumber> {
+        let mut index = self.index.lock().await;
+        self.catch_up(&mut index).await?;
+
+        let sequence = index.next_sequence(scope);
+        let entry_number = EntryNumber::scoped(scope, sequence)?;
+        index.reserved.entry(scope.clone()).or_default().insert(sequence);
+        Ok(entry_number)
+    }
+
+    async fn release(&self, entry_number: &EntryNumber) -> DomainResult<()> {
+        if let Some((scope, sequence)) = entry_number.scope() {
+            let mut index = self.index.lock().await;
+            if let Some(reserved) = index.reserved.get_mut(&scope) {
+                reserved.remove(&sequence);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl EntryNumberExistenceChecker for EntryNumberGeneratorImpl {
+    async fn exists(&self, entry_number: &EntryNumber) -> DomainResult<bool> {
+        let mut index = self.index.lock().await;
+        self.catch_up(&mut index).await?;
+        Ok(index.posted.contains(entry_number.value()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use tempfile::TempDir;
+
+    use super::*;
+
+    async fn post(event_store: &EventStore, entry_id: &str, entry_number: &str) {
+        let event = JournalEntryEvent::Posted {
+            entry_id: entry_id.to_string(),
+            entry_number: entry_number.to_string(),
+            posted_by: "approver".to_string(),
+            posted_at: Utc::now(),
+        };
+        event_store.append(entry_id, vec![event]).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_next_continues_from_posted_numbers_per_scope() {
+        let temp_dir = TempDir::new().unwrap();
+        let event_store = Arc::new(EventStore::new(temp_dir.path()).await.unwrap());
+        post(&event_store, "je-1", "EN-1000-2024-000001").await;
+        post(&event_store, "je-2", "EN-1000-2024-000002").await;
+        post(&event_store, "je-3", "EN-20240401-090000").await;
+
+        let generator = EntryNumberGeneratorImpl::new(Arc::clone(&event_store));
+        let scope_2024 = EntryNumberScope::new("1000", 2024).unwrap();
+        let scope_2025 = EntryNumberScope::new("1000", 2025).unwrap();
+        let other_company = EntryNumberScope::new("2000", 2024).unwrap();
+
+        assert_eq!(generator.next(&scope_2024).await.unwrap().value(), "EN-1000-2024-000003");
+        assert_eq!(generator.next(&scope_2024).await.unwrap().value(), "EN-1000-2024-000004");
+        assert_eq!(generator.next(&scope_2025).await.unwrap().value(), "EN-1000-2025-000001");
+        assert_eq!(generator.next(&other_company).await.unwrap().value(), "EN-2000-2024-000001");
+
+        let posted = EntryNumber::new("EN-1000-2024-000002".to_string()).unwrap();
+        let unused = EntryNumber::new("EN-2000-2024-000002".to_string()).unwrap();
+        assert!(generator.exists(&posted).await.unwrap());
+        assert!(!generator.exists(&unused).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_released_number_is_reissued_and_posted_numbers_are_indexed() {
+        let temp_dir = TempDir::new().unwrap();
+        let event_store = Arc::new(EventStore::new(temp_dir.path()).await.unwrap());
+        let generator = EntryNumberGeneratorImpl::new(Arc::clone(&event_store));
+        let scope = EntryNumberScope::new("1000", 2024).unwrap();
+
+        // 記帳に失敗して戻した番号は欠番にせず、次の採番で払い出す
+        let first = generator.next(&scope).await.unwrap();
+        let second = generator.next(&scope).await.unwrap();
+        assert_eq!(second.value(), "EN-1000-2024-000002");
+        generator.release(&first).await.unwrap();
+        assert_eq!(generator.next(&scope).await.unwrap(), first);
+
+        // 払い出し後に記帳された番号は、次の確認・採番の前に索引へ取り込む
+        post(&event_store, "je-1", first.value()).await;
+        post(&event_store, "je-2", second.value()).await;
+        assert!(generator.exists(&second).await.unwrap());
+        assert_eq!(generator.next(&scope).await.unwrap().value(), "EN-1000-2024-000003");
+    }
+}
@@ -0,0 +1,265 @@
+// 出力ファイル保護サービスの実装
+// 責務: 出力ファイルのパスワード付きZIP（AES-256）への格納と、Ed25519による分離署名の作成・検証
+//
+// 署名鍵はインストールごとにデータディレクトリへ作成し、外へは出さない。
+// 署名ファイルには公開鍵を記録するため、鍵を持たない受け取り側でも改ざんの有無を確認できる。
+// 署名者の確認は、公開鍵をこのインストールの鍵（`javelin verify-export` で照合）と比べて行う。
+
+use std::{
+    io::{Cursor, Write},
+    path::Path,
+};
+
+use chrono::{SecondsFormat, Utc};
+use ed25519_dalek::{Signature, Signer, SigningKey, VerifyingKey};
+use javelin_application::{
+    dtos::response::ExportSignatureVerificationDto,
+    error::{ApplicationError, ApplicationResult},
+    output_port::ExportProtectionOutputPort,
+};
+use sha2::{Digest, Sha256};
+use zip::{AesMode, CompressionMethod, ZipWriter, write::SimpleFileOptions};
+
+/// データディレクトリに作成する署名鍵ファイルの名前
+pub const EXPORT_SIGNING_KEY_FILE: &str = "export_signing.key";
+
+/// 署名ファイルの1行目
+const SIGNATURE_HEADER: &str = "# Javelin export signature v1";
+
+/// 出力ファイル保護サービスの実装
+pub struct ExportProtectionImpl {
+    /// 署名鍵（検証専用で開いた場合、鍵が未作成ならNone）
+    signing_key: Option<SigningKey>,
+}
+
+impl ExportProtectionImpl {
+    /// 署名鍵ファイルを読み込む（ない場合は作成する）
+    pub fn open(key_path: &Path) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        if !key_path.exists() {
+            create_key_file(key_path)?;
+        }
+        Ok(Self { signing_key: Some(read_key_file(key_path)?) })
+    }
+
+    /// 検証専用に署名鍵ファイルを読み込む（ない場合も作成しない）
+    pub fn open_for_verification(
+        key_path: &Path,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        let signing_key = if key_path.exists() {
+            Some(read_key_file(key_path)?)
+        } else {
+            None
+        };
+        Ok(Self { signing_key })
+    }
+
+    /// このインストールの公開鍵（16進）
+    pub fn public_key(&self) -> Option<String> {
+        self.signing_key.as_ref().map(|key| to_hex(key.verifying_key().as_bytes()))
+    }
+}
+
+impl ExportProtectionOutputPort for ExportProtectionImpl {
+    fn encrypt(
+        &self,
+        file_name: &str,
+        content: &[u8],
+        password: &str,
+    ) -> ApplicationResult<Vec<u8>> {
+        let failed = |e: &dyn std::fmt::Display| {
+            ApplicationError::UseCaseExecutionFailed(format!("暗号化に失敗しました: {}", e))
+        };
+        let options = SimpleFileOptions::default()
+            .compression_method(CompressionMethod::Deflated)
+            .with_aes_encryption(AesMode::Aes256, password);
+
+        let mut writer = ZipWriter::new(Cursor::new(Vec::new()));
+        writer.start_file(file_name, options).map_err(|e| failed(&e))?;
+        writer.write_all(content).map_err(|e| failed(&e))?;
+        Ok(writer.finish().map_err(|e| failed(&e))?.into_inner())
+    }
+
+    fn sign(&self, file_name: &str, content: &[u8]) -> ApplicationResult<String> {
+        let signing_key = self.signing_key.as_ref().ok_or_else(|| {
+            ApplicationError::UseCaseExecutionFailed("署名鍵が作成されていません".to_string())
+        })?;
+        let fields = SignedFields {
+            file_name: file_name.to_string(),
+            digest: to_hex(&Sha256::digest(content)),
+            signed_at: Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true),
+            public_key: to_hex(signing_key.verifying_key().as_bytes()),
+        };
+        let signature = signing_key.sign(fields.payload().as_bytes());
+
+        Ok(format!(
+            "{}\n{}signature: {}\n",
+            SIGNATURE_HEADER,
+            fields.payload(),
+            to_hex(&signature.to_bytes())
+        ))
+    }
+
+    fn verify(
+        &self,
+        content: &[u8],
+        signature: &str,
+    ) -> ApplicationResult<ExportSignatureVerificationDto> {
+        let invalid =
+            || ApplicationError::ValidationError("署名ファイルの形式が不正です".to_string());
+        let field = |name: &str| {
+            signature
+                .lines()
+                .find_map(|line| line.strip_prefix(name)?.strip_prefix(": "))
+                .map(|value| value.trim().to_string())
+                .ok_or_else(invalid)
+        };
+        if signature.lines().next().map(str::trim) != Some(SIGNATURE_HEADER) {
+            return Err(invalid());
+        }
+
+        let fields = SignedFields {
+            file_name: field("file")?,
+            digest: field("sha256")?,
+            signed_at: field("signed-at")?,
+            public_key: field("public-key")?,
+        };
+        let public_key: [u8; 32] = from_hex(&fields.public_key)
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or_else(invalid)?;
+        let verifying_key = VerifyingKey::from_bytes(&public_key).map_err(|_| invalid())?;
+        let signature_bytes: [u8; 64] = from_hex(&field("signature")?)
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or_else(invalid)?;
+
+        let digest_matches = to_hex(&Sha256::digest(content)) == fields.digest;
+        let signature_matches = verifying_key
+            .verify_strict(fields.payload().as_bytes(), &Signature::from_bytes(&signature_bytes))
+            .is_ok();
+        let trusted = self
+            .signing_key
+            .as_ref()
+            .is_some_and(|key| key.verifying_key().as_bytes() == &public_key);
+
+        Ok(ExportSignatureVerificationDto {
+            file_name: fields.file_name,
+            digest: fields.digest,
+            public_key: fields.public_key,
+            signed_at: fields.signed_at,
+            valid: digest_matches && signature_matches,
+            trusted,
+        })
+    }
+}
+
+/// 署名の対象とする項目
+struct SignedFields {
+    file_name: String,
+    digest: String,
+    signed_at: String,
+    public_key: String,
+}
+
+impl SignedFields {
+    /// 署名するテキスト（署名ファイルの署名行より前の部分と同じ）
+    fn payload(&self) -> String {
+        format!(
+            "file: {}\nsha256: {}\nsigned-at: {}\npublic-key: {}\n",
+            self.file_name, self.digest, self.signed_at, self.public_key
+        )
+    }
+}
+
+/// 署名鍵を作成して保存（所有者のみ読み書きできる権限で作成する）
+fn create_key_file(key_path: &Path) -> std::io::Result<()> {
+    if let Some(parent) = key_path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    // 乱数源はパスワードのソルトと同じくUUID v4を用いる
+    let mut hasher = Sha256::new();
+    hasher.update(uuid::Uuid::new_v4().as_bytes());
+    hasher.update(uuid::Uuid::new_v4().as_bytes());
+    let seed: [u8; 32] = hasher.finalize().into();
+
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+    let mut file = options.open(key_path)?;
+    writeln!(file, "{}", to_hex(&seed))
+}
+
+fn read_key_file(key_path: &Path) -> Result<SigningKey, Box<dyn std::error::Error + Send + Sync>> {
+    let hex = std::fs::read_to_string(key_path)?;
+    let seed: [u8; 32] = from_hex(hex.trim())
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or_else(|| format!("署名鍵ファイルの形式が不正です: {}", key_path.display()))?;
+    Ok(SigningKey::from_bytes(&seed))
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn from_hex(hex: &str) -> Option<Vec<u8>> {
+    if !hex.len().is_multiple_of(2) {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Read;
+
+    use tempfile::TempDir;
+    use zip::ZipArchive;
+
+    use super::*;
+
+    #[test]
+    fn test_encrypted_archive_requires_password() {
+        let temp_dir = TempDir::new().unwrap();
+        let protection = ExportProtectionImpl::open(&temp_dir.path().join("export.key")).unwrap();
+
+        let archive = protection
+            .encrypt("ledger.csv", b"code,amount\n1100,500\n", "secret-pass")
+            .unwrap();
+        let mut archive = ZipArchive::new(Cursor::new(archive)).unwrap();
+        assert!(archive.by_index_decrypt(0, b"wrong-pass").is_err());
+
+        let mut content = String::new();
+        let mut file = archive.by_index_decrypt(0, b"secret-pass").unwrap();
+        assert_eq!(file.name(), "ledger.csv");
+        file.read_to_string(&mut content).unwrap();
+        assert_eq!(content, "code,amount\n1100,500\n");
+    }
+
+    #[test]
+    fn test_signature_detects_tampering_and_foreign_keys() {
+        let temp_dir = TempDir::new().unwrap();
+        let key_path = temp_dir.path().join("export.key");
+        let protection = ExportProtectionImpl::open(&key_path).unwrap();
+        let signature = protection.sign("tb.csv", b"1100,500\n").unwrap();
+
+        // 鍵は再起動後も同じものを使う
+        let reopened = ExportProtectionImpl::open_for_verification(&key_path).unwrap();
+        assert_eq!(reopened.public_key(), protection.public_key());
+        let verification = reopened.verify(b"1100,500\n", &signature).unwrap();
+        assert_eq!(verification.file_name, "tb.csv");
+        assert!(verification.valid && verification.trusted);
+
+        assert!(!reopened.verify(b"1100,900\n", &signature).unwrap().valid);
+        let forged = signature.replace("file: tb.csv", "file: other.csv");
+        assert!(!reopened.verify(b"1100,500\n", &forged).unwrap().valid);
+        assert!(reopened.verify(b"1100,500\n", "signature: 00").is_err());
+
+        // 他のインストールの鍵で署名されたファイルは改ざんがなくても信頼しない
+        let other_dir = TempDir::new().unwrap();
+        let other = ExportProtectionImpl::open(&other_dir.path().join("export.key")).unwrap();
+        let verification = other.verify(b"1100,500\n", &signature).unwrap();
+        assert!(verification.valid && !verification.trusted);
+    }
+}
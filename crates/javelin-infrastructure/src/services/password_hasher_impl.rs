@@ -0,0 +1,115 @@
+// パスワードハッシュ化サービスの実装
+
+use argon2::{
+    Argon2, Params,
+    password_hash::{
+        self, PasswordHash as PhcString, PasswordHasher as _, PasswordVerifier as _, SaltString,
+        rand_core::OsRng,
+    },
+};
+use javelin_domain::{
+    error::{DomainError, DomainResult},
+    masters::{PasswordHash, PasswordHasher},
+};
+
+/// パスワードハッシュ化サービスの実装
+///
+/// Argon2idでソルト付きハッシュを生成し、PHC文字列形式で保存する。
+/// 保存形式: "$argon2id$v=19$m=...,t=...,p=...${ソルト}${ハッシュ}"
+/// パラメータをハッシュと一緒に保存するため、
+/// 計算量を変更しても既存のハッシュは照合できる。
+pub struct PasswordHasherImpl {
+    params: Params,
+}
+
+impl PasswordHasherImpl {
+    pub fn new() -> Self {
+        Self::with_params(Params::default())
+    }
+
+    /// パラメータを指定して構築（テスト等で計算量を抑える場合に使用）
+    pub fn with_params(params: Params) -> Self {
+        Self { params }
+    }
+
+    fn argon2(&self) -> Argon2<'static> {
+        Argon2::new(argon2::Algorithm::Argon2id, argon2::Version::V0x13, self.params.clone())
+    }
+}
+
+impl Default for PasswordHasherImpl {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PasswordHasher for PasswordHasherImpl {
+    fn hash(&self, password: &str) -> DomainResult<PasswordHash> {
+        let salt = SaltString::generate(&mut OsRng);
+        let hash = self.argon2().hash_password(password.as_bytes(), &salt).map_err(|e| {
+            DomainError::ValidationError(format!("パスワードのハッシュ化に失敗しました: {}", e))
+        })?;
+        PasswordHash::new(hash.to_string())
+    }
+
+    fn verify(&self, password: &str, hash: &PasswordHash) -> DomainResult<bool> {
+        let parsed = PhcString::new(hash.as_str()).map_err(|_| {
+            DomainError::ValidationError("パスワードハッシュの形式が不正です".to_string())
+        })?;
+        if parsed.algorithm != argon2::ARGON2ID_IDENT {
+            return Err(DomainError::ValidationError(format!(
+                "未対応のハッシュ方式です: {}",
+                parsed.algorithm
+            )));
+        }
+
+        // 照合にはハッシュに保存されたパラメータを使う
+        match Argon2::default().verify_password(password.as_bytes(), &parsed) {
+            Ok(()) => Ok(true),
+            Err(password_hash::Error::Password) => Ok(false),
+            Err(e) => Err(DomainError::ValidationError(format!(
+                "パスワードハッシュの照合に失敗しました: {}",
+                e
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// テスト用に計算量を最小にしたハッシュ化サービス
+    fn hasher() -> PasswordHasherImpl {
+        PasswordHasherImpl::with_params(Params::new(Params::MIN_M_COST, 1, 1, None).unwrap())
+    }
+
+    #[test]
+    fn test_hash_and_verify() {
+        let hasher = hasher();
+        let hash = hasher.hash("correct horse").unwrap();
+
+        assert!(hash.as_str().starts_with("$argon2id$v=19$m=8,t=1,p=1$"));
+        assert!(hasher.verify("correct horse", &hash).unwrap());
+        assert!(!hasher.verify("wrong horse", &hash).unwrap());
+
+        // 同じパスワードでもソルトが異なるためハッシュは一致しない
+        assert_ne!(hasher.hash("correct horse").unwrap(), hash);
+    }
+
+    #[test]
+    fn test_verify_uses_parameters_stored_in_hash() {
+        let hash = hasher().hash("correct horse").unwrap();
+        assert!(PasswordHasherImpl::new().verify("correct horse", &hash).unwrap());
+    }
+
+    #[test]
+    fn test_verify_rejects_malformed_hash() {
+        let hasher = hasher();
+        let malformed = PasswordHash::new("$argon2id$v=19$zz").unwrap();
+        assert!(hasher.verify("password", &malformed).is_err());
+
+        let unknown = PasswordHash::new("pbkdf2-sha256$10$73616c74$00").unwrap();
+        assert!(hasher.verify("password", &unknown).is_err());
+    }
+}
@@ -0,0 +1,382 @@
+// 帳票配信の実装
+// 責務: アプリケーション設定の配信先（ファイル・メール・Webhook）への帳票の送信
+//
+// 外部ライブラリに依存しないよう、メールはSMTP、WebhookはHTTP/1.1を直接話す。
+// TLS（SMTPS/STARTTLS・HTTPS）と認証には対応しないため、社内の中継サーバを配信先に指定する。
+
+use std::{path::Path, sync::Arc, time::Duration};
+
+use javelin_application::{
+    dtos::response::{ReportDeliveryResultDto, ReportDocument},
+    output_port::ReportDeliveryOutputPort,
+};
+use javelin_domain::{masters::ReportDestination, repositories::ApplicationSettingsRepository};
+use tokio::{
+    io::{AsyncBufReadExt, AsyncRead, AsyncWrite, AsyncWriteExt, BufReader},
+    net::TcpStream,
+};
+
+use crate::repositories::ApplicationSettingsRepositoryImpl;
+
+/// 配信先1件あたりのタイムアウト
+const DELIVERY_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// SMTPのEHLOで名乗るホスト名
+const SMTP_CLIENT_NAME: &str = "javelin";
+
+/// 帳票配信の実装
+///
+/// 配信のたびに設定を読み込むため、設定画面での変更は次の帳票生成から反映される。
+pub struct ReportDeliveryImpl {
+    settings_repository: Arc<ApplicationSettingsRepositoryImpl>,
+}
+
+impl ReportDeliveryImpl {
+    pub fn new(settings_repository: Arc<ApplicationSettingsRepositoryImpl>) -> Self {
+        Self { settings_repository }
+    }
+}
+
+impl ReportDeliveryOutputPort for ReportDeliveryImpl {
+    async fn deliver(&self, document: &ReportDocument) -> Vec<ReportDeliveryResultDto> {
+        let settings = match self.settings_repository.find().await {
+            Ok(Some(settings)) => settings,
+            Ok(None) => return vec![],
+            Err(e) => {
+                return vec![ReportDeliveryResultDto::failed(
+                    "配信設定",
+                    format!("配信設定を読み込めません: {}", e),
+                )];
+            }
+        };
+
+        let mut results = Vec::new();
+        for destination in settings.report_delivery().destinations(&document.report_type) {
+            let uri = destination.to_uri();
+            let result =
+                match tokio::time::timeout(DELIVERY_TIMEOUT, deliver_to(destination, document))
+                    .await
+                {
+                    Ok(Ok(message)) => ReportDeliveryResultDto::delivered(uri, message),
+                    Ok(Err(message)) => ReportDeliveryResultDto::failed(uri, message),
+                    Err(_) => ReportDeliveryResultDto::failed(uri, "タイムアウトしました"),
+                };
+            results.push(result);
+        }
+        results
+    }
+}
+
+/// 配信先1件へ帳票を送る（成功時は結果の詳細を返す）
+async fn deliver_to(
+    destination: &ReportDestination,
+    document: &ReportDocument,
+) -> Result<String, String> {
+    match destination {
+        ReportDestination::File { directory } => write_file(Path::new(directory), document).await,
+        ReportDestination::Email { smtp_host, smtp_port, from, to } => {
+            let stream = TcpStream::connect((smtp_host.as_str(), *smtp_port))
+                .await
+                .map_err(|e| format!("SMTPサーバに接続できません: {}", e))?;
+            send_mail(stream, from, to, document).await
+        }
+        ReportDestination::Webhook { url } => post_webhook(url, document).await,
+    }
+}
+
+/// ディレクトリへ帳票ファイルを保存
+async fn write_file(directory: &Path, document: &ReportDocument) -> Result<String, String> {
+    tokio::fs::create_dir_all(directory)
+        .await
+        .map_err(|e| format!("ディレクトリを作成できません: {}", e))?;
+    let path = directory.join(&document.file_name);
+    tokio::fs::write(&path, document.content.as_bytes())
+        .await
+        .map_err(|e| format!("ファイルを保存できません: {}", e))?;
+    Ok(path.display().to_string())
+}
+
+/// 帳票をCSV添付のメールとして送信（SMTP）
+async fn send_mail<S>(
+    stream: S,
+    from: &str,
+    to: &[String],
+    document: &ReportDocument,
+) -> Result<String, String>
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    let mut stream = BufReader::new(stream);
+    expect_reply(&mut stream, &[220]).await?;
+    smtp_command(&mut stream, &format!("EHLO {}", SMTP_CLIENT_NAME), &[250]).await?;
+    smtp_command(&mut stream, &format!("MAIL FROM:<{}>", from), &[250]).await?;
+    for address in to {
+        smtp_command(&mut stream, &format!("RCPT TO:<{}>", address), &[250, 251]).await?;
+    }
+    smtp_command(&mut stream, "DATA", &[354]).await?;
+
+    // 本文中の "." で始まる行はドットを重ねる（RFC 5321 4.5.2）
+    let body: String = document
+        .content
+        .lines()
+        .map(|line| {
+            if line.starts_with('.') {
+                format!(".{}\r\n", line)
+            } else {
+                format!("{}\r\n", line)
+            }
+        })
+        .collect();
+    let message = format!(
+        "From: <{from}>\r\n\
+         To: {to}\r\n\
+         Date: {date}\r\n\
+         Subject: [Javelin] {file_name}\r\n\
+         MIME-Version: 1.0\r\n\
+         Content-Type: text/csv; charset=UTF-8; name=\"{file_name}\"\r\n\
+         Content-Disposition: attachment; filename=\"{file_name}\"\r\n\
+         Content-Transfer-Encoding: 8bit\r\n\
+         \r\n\
+         {body}.",
+        to = to.iter().map(|address| format!("<{}>", address)).collect::<Vec<_>>().join(", "),
+        date = chrono::Utc::now().to_rfc2822(),
+        file_name = document.file_name,
+    );
+    smtp_command(&mut stream, &message, &[250]).await?;
+
+    // 受付後の切断の失敗は配信結果に影響しない
+    let _ = smtp_command(&mut stream, "QUIT", &[221]).await;
+    Ok(format!("{} 宛に送信しました", to.join(", ")))
+}
+
+/// SMTPコマンドを送り、応答コードを確認
+async fn smtp_command<S>(
+    stream: &mut BufReader<S>,
+    command: &str,
+    expected: &[u16],
+) -> Result<(), String>
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    let io_error = |e: std::io::Error| format!("SMTPサーバとの通信に失敗しました: {}", e);
+    let stream_ref = stream.get_mut();
+    stream_ref.write_all(command.as_bytes()).await.map_err(io_error)?;
+    stream_ref.write_all(b"\r\n").await.map_err(io_error)?;
+    stream_ref.flush().await.map_err(io_error)?;
+    expect_reply(stream, expected).await
+}
+
+/// SMTPの応答（複数行の場合は最終行まで）を読み、応答コードを確認
+async fn expect_reply<S>(stream: &mut BufReader<S>, expected: &[u16]) -> Result<(), String>
+where
+    S: AsyncRead + Unpin,
+{
+    loop {
+        let mut line = String::new();
+        let read = stream
+            .read_line(&mut line)
+            .await
+            .map_err(|e| format!("SMTPサーバとの通信に失敗しました: {}", e))?;
+        if read == 0 {
+            return Err("SMTPサーバが切断しました".to_string());
+        }
+        // "250-..." は継続行
+        if line.as_bytes().get(3) == Some(&b'-') {
+            continue;
+        }
+        let code = line.get(..3).and_then(|code| code.parse::<u16>().ok());
+        return match code {
+            Some(code) if expected.contains(&code) => Ok(()),
+            _ => Err(format!("SMTPサーバが拒否しました: {}", line.trim_end())),
+        };
+    }
+}
+
+/// WebhookへCSVをPOST（HTTP/1.1）
+async fn post_webhook(url: &str, document: &ReportDocument) -> Result<String, String> {
+    let Some(rest) = url.strip_prefix("http://") else {
+        return Err(
+            "HTTPSには対応していません。社内のHTTP中継（http://）を指定してください".to_string()
+        );
+    };
+    let (authority, path) = match rest.find('/') {
+        Some(index) => rest.split_at(index),
+        None => (rest, "/"),
+    };
+    let address = if authority.contains(':') {
+        authority.to_string()
+    } else {
+        format!("{}:80", authority)
+    };
+
+    let stream = TcpStream::connect(&address)
+        .await
+        .map_err(|e| format!("Webhookに接続できません: {}", e))?;
+    let mut stream = BufReader::new(stream);
+    let io_error = |e: std::io::Error| format!("Webhookとの通信に失敗しました: {}", e);
+
+    let request = format!(
+        "POST {path} HTTP/1.1\r\n\
+         Host: {authority}\r\n\
+         Content-Type: text/csv; charset=utf-8\r\n\
+         Content-Length: {length}\r\n\
+         X-Javelin-Report-Type: {report_type}\r\n\
+         X-Javelin-File-Name: {file_name}\r\n\
+         Connection: close\r\n\
+         \r\n",
+        length = document.content.len(),
+        report_type = document.report_type,
+        file_name = document.file_name,
+    );
+    let writer = stream.get_mut();
+    writer.write_all(request.as_bytes()).await.map_err(io_error)?;
+    writer.write_all(document.content.as_bytes()).await.map_err(io_error)?;
+    writer.flush().await.map_err(io_error)?;
+
+    let mut status_line = String::new();
+    stream.read_line(&mut status_line).await.map_err(io_error)?;
+    match status_line.split_whitespace().nth(1).and_then(|code| code.parse::<u16>().ok()) {
+        Some(status) if (200..300).contains(&status) => Ok(format!("HTTP {}", status)),
+        Some(_) => Err(format!("Webhookが拒否しました: {}", status_line.trim_end())),
+        None => Err("Webhookの応答を解釈できません".to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+    use tokio::{
+        io::{AsyncReadExt, BufReader},
+        net::TcpListener,
+    };
+
+    use super::*;
+
+    fn document() -> ReportDocument {
+        ReportDocument {
+            report_type: "TB".to_string(),
+            fiscal_year: 2024,
+            period: 3,
+            file_name: "trial_balance_202403_JPY.csv".to_string(),
+            content: "勘定科目コード,期末残高\r\n1000,1000.00\r\n.hidden,0.00\r\n".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_file_delivery_creates_directory() {
+        let temp_dir = TempDir::new().unwrap();
+        let directory = temp_dir.path().join("reports").to_string_lossy().to_string();
+
+        let message =
+            deliver_to(&ReportDestination::File { directory }, &document()).await.unwrap();
+
+        let saved = std::fs::read_to_string(&message).unwrap();
+        assert_eq!(saved, document().content);
+    }
+
+    #[tokio::test]
+    async fn test_webhook_posts_csv() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/hooks/reports", listener.local_addr().unwrap());
+        let server = tokio::spawn(async move {
+            let (socket, _) = listener.accept().await.unwrap();
+            let mut socket = BufReader::new(socket);
+            let mut head = String::new();
+            loop {
+                let mut line = String::new();
+                socket.read_line(&mut line).await.unwrap();
+                head.push_str(&line);
+                if line == "\r\n" {
+                    break;
+                }
+            }
+            let length: usize = head
+                .lines()
+                .find_map(|line| line.strip_prefix("Content-Length: "))
+                .unwrap()
+                .parse()
+                .unwrap();
+            let mut body = vec![0; length];
+            socket.read_exact(&mut body).await.unwrap();
+            socket.get_mut().write_all(b"HTTP/1.1 204 No Content\r\n\r\n").await.unwrap();
+            (head, String::from_utf8(body).unwrap())
+        });
+
+        let message = deliver_to(&ReportDestination::Webhook { url }, &document()).await.unwrap();
+
+        let (head, body) = server.await.unwrap();
+        assert_eq!(message, "HTTP 204");
+        assert!(head.starts_with("POST /hooks/reports HTTP/1.1\r\n"));
+        assert!(head.contains("X-Javelin-Report-Type: TB\r\n"));
+        assert_eq!(body, document().content);
+    }
+
+    #[tokio::test]
+    async fn test_https_webhook_is_rejected() {
+        let destination = ReportDestination::Webhook { url: "https://example.com/x".to_string() };
+        let error = deliver_to(&destination, &document()).await.unwrap_err();
+        assert!(error.contains("HTTPS"));
+    }
+
+    #[tokio::test]
+    async fn test_mail_is_sent_over_smtp() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = tokio::spawn(async move {
+            let (socket, _) = listener.accept().await.unwrap();
+            let mut socket = BufReader::new(socket);
+            let mut commands = Vec::new();
+            let mut data = String::new();
+            socket.get_mut().write_all(b"220 test ESMTP\r\n").await.unwrap();
+            loop {
+                let mut line = String::new();
+                if socket.read_line(&mut line).await.unwrap() == 0 {
+                    break;
+                }
+                let reply: &[u8] = match line.trim_end() {
+                    "DATA" => b"354 go ahead\r\n",
+                    "QUIT" => b"221 bye\r\n",
+                    command if command.starts_with("EHLO") => b"250-test\r\n250 8BITMIME\r\n",
+                    _ => b"250 OK\r\n",
+                };
+                commands.push(line.trim_end().to_string());
+                socket.get_mut().write_all(reply).await.unwrap();
+                if line.trim_end() == "DATA" {
+                    loop {
+                        let mut line = String::new();
+                        socket.read_line(&mut line).await.unwrap();
+                        if line == ".\r\n" {
+                            break;
+                        }
+                        data.push_str(&line);
+                    }
+                    socket.get_mut().write_all(b"250 queued\r\n").await.unwrap();
+                }
+            }
+            (commands, data)
+        });
+
+        let destination = ReportDestination::Email {
+            smtp_host: "127.0.0.1".to_string(),
+            smtp_port: port,
+            from: "gl@example.com".to_string(),
+            to: vec!["cfo@example.com".to_string()],
+        };
+        let message = deliver_to(&destination, &document()).await.unwrap();
+
+        let (commands, data) = server.await.unwrap();
+        assert_eq!(message, "cfo@example.com 宛に送信しました");
+        assert_eq!(
+            commands,
+            vec![
+                "EHLO javelin",
+                "MAIL FROM:<gl@example.com>",
+                "RCPT TO:<cfo@example.com>",
+                "DATA",
+                "QUIT"
+            ]
+        );
+        assert!(data.contains("Subject: [Javelin] trial_balance_202403_JPY.csv\r\n"));
+        assert!(data.ends_with("\r\n1000,1000.00\r\n..hidden,0.00\r\n"));
+    }
+}
@@ -0,0 +1,30 @@
+// 帳票ハッシュ算出サービスの実装
+
+use javelin_domain::financial_close::report_archive::ReportDigester;
+use sha2::{Digest, Sha256};
+
+/// 帳票ハッシュ算出サービスの実装（SHA-256）
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ReportDigesterImpl;
+
+impl ReportDigester for ReportDigesterImpl {
+    fn digest(&self, payload: &str) -> String {
+        Sha256::digest(payload.as_bytes())
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_digest_is_sha256_hex() {
+        assert_eq!(
+            ReportDigesterImpl.digest("abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+}
@@ -0,0 +1,116 @@
+// SpreadsheetReaderImpl - 表計算ファイル読込の具象実装
+// calamineでXLSX/XLS/ODSの先頭シートを読み込み、セルを文字列に変換する
+
+use calamine::{Data, Reader, open_workbook_auto};
+use javelin_application::{
+    error::{ApplicationError, ApplicationResult},
+    spreadsheet_reader::{Spreadsheet, SpreadsheetReader},
+};
+
+/// SpreadsheetReader具象実装
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SpreadsheetReaderImpl;
+
+impl SpreadsheetReaderImpl {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn read(path: &str) -> ApplicationResult<Spreadsheet> {
+        let mut workbook = open_workbook_auto(path).map_err(|e| {
+            ApplicationError::ValidationError(format!("ファイルを開けません: {} ({})", path, e))
+        })?;
+        let sheet_name = workbook.sheet_names().first().cloned().ok_or_else(|| {
+            ApplicationError::ValidationError(format!("シートがありません: {}", path))
+        })?;
+        let range = workbook.worksheet_range(&sheet_name).map_err(|e| {
+            ApplicationError::ValidationError(format!(
+                "シートを読み込めません: {} ({})",
+                sheet_name, e
+            ))
+        })?;
+
+        // 範囲の開始行（0始まり）。先頭の空行は範囲に含まれない
+        let start_row = range.start().map(|(row, _)| row as usize).unwrap_or(0);
+        let mut rows =
+            range.rows().map(|cells| cells.iter().map(cell_to_string).collect::<Vec<_>>());
+        let mut header_row = start_row;
+        let headers: Vec<String> = loop {
+            match rows.next() {
+                Some(cells) if is_blank(&cells) => header_row += 1,
+                Some(cells) => break cells,
+                None => {
+                    return Err(ApplicationError::ValidationError(format!(
+                        "見出し行がありません: {}",
+                        sheet_name
+                    )));
+                }
+            }
+        };
+
+        Ok(Spreadsheet { sheet_name, headers, rows: rows.collect(), header_row: header_row + 1 })
+    }
+}
+
+#[async_trait::async_trait]
+impl SpreadsheetReader for SpreadsheetReaderImpl {
+    async fn read_first_sheet(&self, path: &str) -> ApplicationResult<Spreadsheet> {
+        let path = path.to_string();
+        tokio::task::spawn_blocking(move || Self::read(&path))
+            .await
+            .map_err(|e| ApplicationError::Unknown(e.to_string()))?
+    }
+}
+
+fn is_blank(cells: &[String]) -> bool {
+    cells.iter().all(|cell| cell.trim().is_empty())
+}
+
+/// セルを文字列に変換（日付は `YYYY-MM-DD`、整数値の数値は小数点なし）
+fn cell_to_string(cell: &Data) -> String {
+    match cell {
+        Data::Empty => String::new(),
+        Data::String(value) | Data::DateTimeIso(value) | Data::DurationIso(value) => {
+            value.trim().to_string()
+        }
+        Data::Int(value) => value.to_string(),
+        Data::Float(value) if value.fract() == 0.0 && value.abs() < 1e15 => {
+            format!("{}", *value as i64)
+        }
+        Data::Float(value) => value.to_string(),
+        Data::Bool(value) => value.to_string(),
+        Data::DateTime(value) => match value.as_datetime() {
+            Some(datetime) if datetime.time() == chrono::NaiveTime::MIN => {
+                datetime.format("%Y-%m-%d").to_string()
+            }
+            Some(datetime) => datetime.format("%Y-%m-%d %H:%M:%S").to_string(),
+            None => value.as_f64().to_string(),
+        },
+        Data::Error(error) => format!("#{:?}", error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use calamine::{ExcelDateTime, ExcelDateTimeType};
+
+    use super::*;
+
+    #[test]
+    fn test_cell_to_string() {
+        assert_eq!(cell_to_string(&Data::Float(1000.0)), "1000");
+        assert_eq!(cell_to_string(&Data::Float(12.5)), "12.5");
+        assert_eq!(cell_to_string(&Data::String(" 借方 ".to_string())), "借方");
+        assert_eq!(cell_to_string(&Data::Empty), "");
+
+        // 45383 = 2024-04-01（1900年日付系）
+        let date = ExcelDateTime::new(45383.0, ExcelDateTimeType::DateTime, false);
+        assert_eq!(cell_to_string(&Data::DateTime(date)), "2024-04-01");
+    }
+
+    #[tokio::test]
+    async fn test_missing_file_is_reported() {
+        let reader = SpreadsheetReaderImpl::new();
+        assert!(reader.read_first_sheet("/nonexistent/journal.xlsx").await.is_err());
+    }
+}
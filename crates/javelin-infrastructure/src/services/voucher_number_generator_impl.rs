@@ -1,38 +1,150 @@
 // 伝票番号生成サービスの実装
 
-use std::sync::Arc;
+use std::{
+    collections::{BTreeSet, HashMap},
+    sync::Arc,
+};
 
+use chrono::{DateTime, Utc};
 use javelin_domain::{
     error::{DomainError, DomainResult},
-    financial_close::journal_entry::services::VoucherNumberGenerator,
+    financial_close::journal_entry::{
+        events::JournalEntryEvent,
+        services::{VoidedVoucherNumber, VoucherNumberGenerator, VoucherNumberRelease},
+    },
 };
+use serde::{Deserialize, Serialize};
 use tokio::sync::Mutex;
 
+use crate::EventStore;
+
+/// 年度ごとの採番状況
+#[derive(Debug, Default)]
+struct FiscalYearSequence {
+    /// 最後に予約した連番
+    last: u32,
+    /// 予約中（未確定）の連番
+    reserved: BTreeSet<u32>,
+    /// 取り消した番号
+    voided: Vec<VoidedVoucherNumber>,
+}
+
+/// 伝票番号の採番に関するイベント（イベントストアに記録する）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type")]
+enum VoucherNumberEvent {
+    /// 予約した伝票番号を取消番号とした
+    VoucherNumberVoided {
+        voucher_number: String,
+        fiscal_year: u32,
+        reason: String,
+        voided_at: DateTime<Utc>,
+    },
+}
+
 /// 伝票番号生成サービスの実装
 ///
 /// 年度単位で連番を管理し、エンドユーザが理解しやすい形式で伝票番号を生成する。
 /// フォーマット: "V-{年度}-{5桁連番}"
 /// 例: V-2024-00001, V-2024-00002, ...
+///
+/// 予約から確定・取消までを1つのロックで直列化するため、並行して採番しても
+/// 同じ番号を二度予約することはない。取り消した番号が最後の予約であれば
+/// 採番前に戻し、後続の予約がある場合は取消番号として記録する。
+///
+/// 取消番号はイベントストアに記録し、採番状況は初回の採番時に下書き作成イベントと
+/// 取消番号のイベントから復元する。保存前に終了した予約は復元しないため、
+/// 再起動後に同じ番号を予約し直す（欠番にならない）。
 pub struct VoucherNumberGeneratorImpl {
-    /// 年度ごとの最新番号を保持（実運用ではDBに永続化）
-    counters: Arc<Mutex<std::collections::HashMap<u32, u32>>>,
+    event_store: Arc<EventStore>,
+    /// 年度ごとの採番状況（初回の利用時にイベントストアから復元）
+    sequences: Mutex<Option<HashMap<u32, FiscalYearSequence>>>,
 }
 
 impl VoucherNumberGeneratorImpl {
-    pub fn new() -> Self {
-        Self { counters: Arc::new(Mutex::new(std::collections::HashMap::new())) }
+    pub fn new(event_store: Arc<EventStore>) -> Self {
+        Self { event_store, sequences: Mutex::new(None) }
+    }
+
+    /// 採番状況をイベントストアから復元
+    async fn restore(&self) -> DomainResult<HashMap<u32, FiscalYearSequence>> {
+        let events = self
+            .event_store
+            .get_all_events(0)
+            .await
+            .map_err(|e| DomainError::RepositoryError(e.to_string()))?;
+
+        let mut sequences: HashMap<u32, FiscalYearSequence> = HashMap::new();
+        for stored in events {
+            let voucher_number = match stored.event_type.as_str() {
+                "DraftCreated" => match serde_json::from_slice(&stored.payload) {
+                    Ok(JournalEntryEvent::DraftCreated { voucher_number, .. }) => voucher_number,
+                    _ => continue,
+                },
+                "VoucherNumberVoided" => match serde_json::from_slice(&stored.payload) {
+                    Ok(VoucherNumberEvent::VoucherNumberVoided {
+                        voucher_number,
+                        fiscal_year,
+                        reason,
+                        voided_at,
+                    }) => {
+                        sequences.entry(fiscal_year).or_default().voided.push(
+                            VoidedVoucherNumber {
+                                voucher_number: voucher_number.clone(),
+                                fiscal_year,
+                                reason,
+                                voided_at,
+                            },
+                        );
+                        voucher_number
+                    }
+                    Err(_) => continue,
+                },
+                _ => continue,
+            };
+            // 利用者が入力した形式の異なる番号は採番の対象外
+            if let Ok((fiscal_year, number)) = parse_voucher_number(&voucher_number) {
+                let sequence = sequences.entry(fiscal_year).or_default();
+                sequence.last = sequence.last.max(number);
+            }
+        }
+        Ok(sequences)
+    }
+
+    /// 採番状況をロックして取得（未復元の場合は復元する）
+    async fn sequences(
+        &self,
+    ) -> DomainResult<tokio::sync::MappedMutexGuard<'_, HashMap<u32, FiscalYearSequence>>> {
+        let mut sequences = self.sequences.lock().await;
+        if sequences.is_none() {
+            *sequences = Some(self.restore().await?);
+        }
+        Ok(tokio::sync::MutexGuard::map(sequences, |sequences| {
+            sequences.get_or_insert_with(HashMap::new)
+        }))
     }
 }
 
-impl Default for VoucherNumberGeneratorImpl {
-    fn default() -> Self {
-        Self::new()
+/// 伝票番号を年度と連番に分解
+fn parse_voucher_number(voucher_number: &str) -> DomainResult<(u32, u32)> {
+    let invalid =
+        || DomainError::ValidationError(format!("伝票番号が不正です: {}", voucher_number));
+    let mut segments = voucher_number.splitn(3, '-');
+    match (segments.next(), segments.next(), segments.next()) {
+        (Some("V"), Some(year), Some(sequence)) => {
+            Ok((year.parse().map_err(|_| invalid())?, sequence.parse().map_err(|_| invalid())?))
+        }
+        _ => Err(invalid()),
     }
 }
 
+fn not_reserved(voucher_number: &str) -> DomainError {
+    DomainError::NotFound(format!("予約されていない伝票番号です: {}", voucher_number))
+}
+
 #[allow(async_fn_in_trait)]
 impl VoucherNumberGenerator for VoucherNumberGeneratorImpl {
-    async fn generate_next(&self, fiscal_year: u32) -> DomainResult<String> {
+    async fn reserve(&self, fiscal_year: u32) -> DomainResult<String> {
         if !(2000..=2100).contains(&fiscal_year) {
             return Err(DomainError::InvalidAmount(format!(
                 "Invalid fiscal year: {}",
@@ -40,54 +152,235 @@ impl VoucherNumberGenerator for VoucherNumberGeneratorImpl {
             )));
         }
 
-        let mut counters = self.counters.lock().await;
-        let counter = counters.entry(fiscal_year).or_insert(0);
-        *counter += 1;
+        let mut sequences = self.sequences().await?;
+        let sequence = sequences.entry(fiscal_year).or_default();
+        sequence.last += 1;
+        sequence.reserved.insert(sequence.last);
+
+        Ok(format!("V-{}-{:05}", fiscal_year, sequence.last))
+    }
+
+    async fn confirm(&self, voucher_number: &str) -> DomainResult<()> {
+        let (fiscal_year, number) = parse_voucher_number(voucher_number)?;
 
-        Ok(format!("V-{}-{:05}", fiscal_year, *counter))
+        let mut sequences = self.sequences().await?;
+        let removed = sequences
+            .get_mut(&fiscal_year)
+            .is_some_and(|sequence| sequence.reserved.remove(&number));
+        if removed {
+            Ok(())
+        } else {
+            Err(not_reserved(voucher_number))
+        }
+    }
+
+    async fn release(
+        &self,
+        voucher_number: &str,
+        reason: &str,
+    ) -> DomainResult<VoucherNumberRelease> {
+        let (fiscal_year, number) = parse_voucher_number(voucher_number)?;
+
+        let mut sequences = self.sequences().await?;
+        let sequence = sequences
+            .get_mut(&fiscal_year)
+            .filter(|sequence| sequence.reserved.contains(&number))
+            .ok_or_else(|| not_reserved(voucher_number))?;
+
+        if number == sequence.last {
+            sequence.reserved.remove(&number);
+            sequence.last -= 1;
+            return Ok(VoucherNumberRelease::Returned);
+        }
+
+        // 取消番号は再起動後も監査で参照できるよう記録してから確定する
+        let voided = VoidedVoucherNumber {
+            voucher_number: voucher_number.to_string(),
+            fiscal_year,
+            reason: reason.to_string(),
+            voided_at: Utc::now(),
+        };
+        self.event_store
+            .append(
+                &format!("voucher-numbers-{}", fiscal_year),
+                vec![VoucherNumberEvent::VoucherNumberVoided {
+                    voucher_number: voided.voucher_number.clone(),
+                    fiscal_year,
+                    reason: voided.reason.clone(),
+                    voided_at: voided.voided_at,
+                }],
+            )
+            .await
+            .map_err(|e| DomainError::RepositoryError(e.to_string()))?;
+        sequence.reserved.remove(&number);
+        sequence.voided.push(voided);
+        Ok(VoucherNumberRelease::Voided)
+    }
+
+    async fn voided_numbers(
+        &self,
+        fiscal_year: Option<u32>,
+    ) -> DomainResult<Vec<VoidedVoucherNumber>> {
+        let sequences = self.sequences().await?;
+        let mut voided: Vec<VoidedVoucherNumber> = sequences
+            .iter()
+            .filter(|(year, _)| fiscal_year.is_none_or(|fiscal_year| **year == fiscal_year))
+            .flat_map(|(_, sequence)| sequence.voided.iter().cloned())
+            .collect();
+        voided.sort_by(|a, b| a.voucher_number.cmp(&b.voucher_number));
+        Ok(voided)
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use tempfile::TempDir;
+
     use super::*;
 
+    async fn generator() -> (TempDir, VoucherNumberGeneratorImpl) {
+        let temp_dir = TempDir::new().unwrap();
+        let event_store = Arc::new(EventStore::new(temp_dir.path()).await.unwrap());
+        (temp_dir, VoucherNumberGeneratorImpl::new(event_store))
+    }
+
     #[tokio::test]
     async fn test_generate_sequential_numbers() {
-        let generator = VoucherNumberGeneratorImpl::new();
+        let (_temp_dir, generator) = generator().await;
 
-        let num1 = generator.generate_next(2024).await.unwrap();
+        let num1 = generator.reserve(2024).await.unwrap();
         assert_eq!(num1, "V-2024-00001");
 
-        let num2 = generator.generate_next(2024).await.unwrap();
+        let num2 = generator.reserve(2024).await.unwrap();
         assert_eq!(num2, "V-2024-00002");
 
-        let num3 = generator.generate_next(2024).await.unwrap();
+        let num3 = generator.reserve(2024).await.unwrap();
         assert_eq!(num3, "V-2024-00003");
     }
 
     #[tokio::test]
     async fn test_generate_different_fiscal_years() {
-        let generator = VoucherNumberGeneratorImpl::new();
+        let (_temp_dir, generator) = generator().await;
 
-        let num1 = generator.generate_next(2024).await.unwrap();
+        let num1 = generator.reserve(2024).await.unwrap();
         assert_eq!(num1, "V-2024-00001");
 
-        let num2 = generator.generate_next(2025).await.unwrap();
+        let num2 = generator.reserve(2025).await.unwrap();
         assert_eq!(num2, "V-2025-00001");
 
-        let num3 = generator.generate_next(2024).await.unwrap();
+        let num3 = generator.reserve(2024).await.unwrap();
         assert_eq!(num3, "V-2024-00002");
     }
 
+    #[tokio::test]
+    async fn test_release_of_latest_reservation_returns_number() {
+        let (_temp_dir, generator) = generator().await;
+
+        let num1 = generator.reserve(2024).await.unwrap();
+        generator.confirm(&num1).await.unwrap();
+        let num2 = generator.reserve(2024).await.unwrap();
+
+        let release = generator.release(&num2, "保存失敗").await.unwrap();
+        assert_eq!(release, VoucherNumberRelease::Returned);
+
+        // 欠番にならないよう同じ番号を再度予約する
+        assert_eq!(generator.reserve(2024).await.unwrap(), "V-2024-00002");
+        assert!(generator.voided_numbers(Some(2024)).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_release_with_later_reservation_voids_number() {
+        let (_temp_dir, generator) = generator().await;
+
+        let num1 = generator.reserve(2024).await.unwrap();
+        let num2 = generator.reserve(2024).await.unwrap();
+
+        let release = generator.release(&num1, "保存失敗").await.unwrap();
+        assert_eq!(release, VoucherNumberRelease::Voided);
+        generator.confirm(&num2).await.unwrap();
+
+        let voided = generator.voided_numbers(None).await.unwrap();
+        assert_eq!(voided.len(), 1);
+        assert_eq!(voided[0].voucher_number, "V-2024-00001");
+        assert_eq!(voided[0].fiscal_year, 2024);
+        assert_eq!(voided[0].reason, "保存失敗");
+        assert_eq!(generator.reserve(2024).await.unwrap(), "V-2024-00003");
+    }
+
+    #[tokio::test]
+    async fn test_settled_number_cannot_be_released_again() {
+        let (_temp_dir, generator) = generator().await;
+
+        let num1 = generator.reserve(2024).await.unwrap();
+        generator.confirm(&num1).await.unwrap();
+
+        assert!(generator.release(&num1, "保存失敗").await.is_err());
+        assert!(generator.confirm(&num1).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_reservations_are_unique() {
+        let (_temp_dir, generator) = generator().await;
+        let generator = Arc::new(generator);
+
+        let handles: Vec<_> = (0..20)
+            .map(|_| {
+                let generator = Arc::clone(&generator);
+                tokio::spawn(async move { generator.reserve(2024).await.unwrap() })
+            })
+            .collect();
+        let mut numbers = BTreeSet::new();
+        for handle in handles {
+            numbers.insert(handle.await.unwrap());
+        }
+
+        assert_eq!(numbers.len(), 20);
+        assert_eq!(numbers.last().unwrap(), "V-2024-00020");
+    }
+
     #[tokio::test]
     async fn test_invalid_fiscal_year() {
-        let generator = VoucherNumberGeneratorImpl::new();
+        let (_temp_dir, generator) = generator().await;
 
-        let result = generator.generate_next(1999).await;
+        let result = generator.reserve(1999).await;
         assert!(result.is_err());
 
-        let result = generator.generate_next(2101).await;
+        let result = generator.reserve(2101).await;
         assert!(result.is_err());
     }
+
+    #[tokio::test]
+    async fn test_sequences_and_voided_numbers_survive_restart() {
+        let temp_dir = TempDir::new().unwrap();
+        let event_store = Arc::new(EventStore::new(temp_dir.path()).await.unwrap());
+        let generator = VoucherNumberGeneratorImpl::new(Arc::clone(&event_store));
+
+        let num1 = generator.reserve(2024).await.unwrap();
+        let num2 = generator.reserve(2024).await.unwrap();
+        generator.release(&num1, "保存失敗").await.unwrap();
+        event_store
+            .append(
+                "je-2",
+                vec![JournalEntryEvent::DraftCreated {
+                    entry_id: "je-2".to_string(),
+                    transaction_date: "2024-04-01".to_string(),
+                    voucher_number: num2.clone(),
+                    lines: vec![],
+                    description: None,
+                    created_by: "user1".to_string(),
+                    created_at: Utc::now(),
+                }],
+            )
+            .await
+            .unwrap();
+        generator.confirm(&num2).await.unwrap();
+
+        // 再起動後も保存済みの番号の続きから採番し、取消番号を参照できる
+        let restarted = VoucherNumberGeneratorImpl::new(Arc::clone(&event_store));
+        assert_eq!(restarted.reserve(2024).await.unwrap(), "V-2024-00003");
+        let voided = restarted.voided_numbers(Some(2024)).await.unwrap();
+        assert_eq!(voided.len(), 1);
+        assert_eq!(voided[0].voucher_number, num1);
+        assert_eq!(voided[0].reason, "保存失敗");
+    }
 }
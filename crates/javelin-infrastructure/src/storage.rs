@@ -0,0 +1,114 @@
+// Storage - 永続化バックエンドの抽象
+// 責務: 名前付きテーブルに対するKVトランザクション・範囲走査・統計情報
+//
+// EventStore / ProjectionDb はこの抽象のみに依存する。
+// 新しいバックエンドを追加する場合は StorageBackend を実装し、
+// tests/storage_contract_tests.rs の契約テストをすべて通すこと。
+
+pub mod lmdb_backend;
+#[cfg(any(test, feature = "testkit"))]
+pub mod memory_backend;
+
+use std::path::Path;
+
+pub use lmdb_backend::LmdbBackend;
+#[cfg(any(test, feature = "testkit"))]
+pub use memory_backend::MemoryBackend;
+
+use crate::error::InfrastructureResult;
+
+/// バックエンド全体の容量統計
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StorageStats {
+    /// 確保済みの容量（バイト）
+    pub map_size: usize,
+    /// 使用中の容量（バイト）
+    pub used_size: usize,
+    pub page_size: usize,
+    pub last_page_no: usize,
+}
+
+/// 走査時に各エントリ（キー・値）を受け取るコールバック
+///
+/// 走査を続ける場合は `true` を返す。
+pub type ScanVisitor<'v> = dyn FnMut(&[u8], &[u8]) -> InfrastructureResult<bool> + 'v;
+
+/// 読み取り操作
+///
+/// キーはバイト列の辞書順で並ぶ。シーケンス番号などの数値キーは
+/// ビッグエンディアンで格納すること。
+pub trait KvRead {
+    /// キーに対応する値を取得
+    fn get(&self, table: &str, key: &[u8]) -> InfrastructureResult<Option<Vec<u8>>>;
+
+    /// `from` 以上のキーを昇順に走査（Noneの場合は先頭から）
+    ///
+    /// `visit` が `false` を返した時点で走査を打ち切る。
+    fn scan(
+        &self,
+        table: &str,
+        from: Option<&[u8]>,
+        visit: &mut ScanVisitor<'_>,
+    ) -> InfrastructureResult<()>;
+
+    /// テーブルの件数
+    fn entry_count(&self, table: &str) -> InfrastructureResult<usize>;
+}
+
+/// 書き込み操作
+///
+/// `commit` するまで他のトランザクションからは見えない。
+/// コミットせずに破棄した場合はすべての変更が取り消される。
+pub trait KvWrite: KvRead {
+    /// 値を書き込む（既存の値は上書き）
+    fn put(&mut self, table: &str, key: &[u8], value: &[u8]) -> InfrastructureResult<()>;
+
+    /// キーが存在しない場合のみ書き込み、書き込んだかを返す
+    fn put_if_absent(
+        &mut self,
+        table: &str,
+        key: &[u8],
+        value: &[u8],
+    ) -> InfrastructureResult<bool>;
+
+    /// キーを削除し、削除したかを返す
+    fn delete(&mut self, table: &str, key: &[u8]) -> InfrastructureResult<bool>;
+
+    /// 変更を確定
+    fn commit(self) -> InfrastructureResult<()>;
+}
+
+/// 永続化バックエンド
+///
+/// テーブルはオープン時に作成され、以降は名前で参照する。
+/// 操作はブロッキングで行うため、非同期コンテキストからは
+/// `spawn_blocking` 内で呼び出すこと。
+pub trait StorageBackend: Send + Sync + 'static {
+    type ReadTxn<'a>: KvRead
+    where
+        Self: 'a;
+    type WriteTxn<'a>: KvWrite
+    where
+        Self: 'a;
+
+    /// 読み取りトランザクションを開始（開始時点のスナップショットを参照）
+    fn begin_read(&self) -> InfrastructureResult<Self::ReadTxn<'_>>;
+
+    /// 書き込みトランザクションを開始（書き込みは直列化される）
+    fn begin_write(&self) -> InfrastructureResult<Self::WriteTxn<'_>>;
+
+    /// 未同期の書き込みをディスクへ強制的に同期
+    fn sync(&self) -> InfrastructureResult<()>;
+
+    /// 容量統計
+    fn stats(&self) -> InfrastructureResult<StorageStats>;
+
+    /// コミット済みの内容を空き領域を詰めて書き出す
+    ///
+    /// `destination` は存在する空のディレクトリであること。書き出し中も
+    /// 読み書きは継続でき、書き出しは開始時点のスナップショットを対象とする。
+    fn copy_compacted(&self, destination: &Path) -> InfrastructureResult<()>;
+
+    /// 参照専用としてオープンしたか
+    fn is_read_only(&self) -> bool;
+}
@@ -0,0 +1,327 @@
+// LmdbBackend - LMDBによるStorageBackend実装
+// テーブルはLMDBの名前付きデータベースとして作成する
+
+use std::{collections::HashMap, path::Path};
+
+use lmdb::{
+    Cursor, Database, DatabaseFlags, Environment, EnvironmentFlags, RoTransaction, RwTransaction,
+    Transaction, WriteFlags,
+};
+use lmdb_sys as ffi;
+
+use crate::{
+    error::{InfrastructureError, InfrastructureResult},
+    storage::{KvRead, KvWrite, ScanVisitor, StorageBackend, StorageStats},
+    storage_metrics::DurabilityPolicy,
+};
+
+/// 拡張時のマップサイズ上限（異常な増加を防ぐ）
+const MAX_MAP_SIZE: usize = 10 * 1024 * 1024 * 1024; // 10GB
+
+fn lmdb_error(e: lmdb::Error) -> InfrastructureError {
+    InfrastructureError::LmdbError(e.to_string())
+}
+
+/// LMDBバックエンド
+pub struct LmdbBackend {
+    env: Environment,
+    tables: HashMap<String, Database>,
+    map_size: usize,
+    read_only: bool,
+}
+
+impl LmdbBackend {
+    /// 書き込み可能としてオープン（テーブルが存在しない場合は作成）
+    ///
+    /// マップサイズは既存データファイルの1.5倍と `initial_map_size` の大きい方とする。
+    pub fn open(
+        path: &Path,
+        tables: &[&str],
+        initial_map_size: usize,
+        durability_policy: DurabilityPolicy,
+    ) -> InfrastructureResult<Self> {
+        let existing_size =
+            std::fs::metadata(path.join("data.mdb")).map(|m| m.len() as usize).unwrap_or(0);
+        let map_size =
+            std::cmp::max(initial_map_size, existing_size + (existing_size / 2)).min(MAX_MAP_SIZE);
+
+        let mut env_builder = Environment::new();
+        env_builder.set_max_dbs(tables.len() as u32).set_map_size(map_size);
+
+        match durability_policy {
+            DurabilityPolicy::MaxDurability => {}
+            DurabilityPolicy::Balanced => {
+                env_builder.set_flags(EnvironmentFlags::NO_META_SYNC);
+            }
+            DurabilityPolicy::MaxPerformance | DurabilityPolicy::GroupCommit { .. } => {
+                env_builder.set_flags(EnvironmentFlags::NO_SYNC | EnvironmentFlags::NO_META_SYNC);
+            }
+        }
+
+        let env = env_builder.open(path).map_err(lmdb_error)?;
+        let tables = tables
+            .iter()
+            .map(|name| {
+                env.create_db(Some(name), DatabaseFlags::empty())
+                    .map(|db| (name.to_string(), db))
+                    .map_err(lmdb_error)
+            })
+            .collect::<InfrastructureResult<_>>()?;
+
+        Ok(Self { env, tables, map_size, read_only: false })
+    }
+
+    /// 参照専用としてオープン
+    ///
+    /// 書き込みプロセスが使用中のディレクトリを別プロセスから共有する。
+    /// 読み取りトランザクションは開始時点の最新コミットを参照する。
+    /// 書き込みプロセスがまだ作成していないテーブルは開かず、
+    /// 参照時に `StorageTableNotFound` となる。
+    pub fn open_read_only(path: &Path, tables: &[&str]) -> InfrastructureResult<Self> {
+        let data_file = path.join("data.mdb");
+        if !data_file.exists() {
+            return Err(InfrastructureError::ReplicaSourceNotFound {
+                path: path.display().to_string(),
+            });
+        }
+        let map_size = std::fs::metadata(&data_file).map(|m| m.len() as usize).unwrap_or(0);
+
+        let env = Environment::new()
+            .set_max_dbs(tables.len() as u32)
+            .set_flags(EnvironmentFlags::READ_ONLY)
+            .open(path)
+            .map_err(lmdb_error)?;
+        let mut opened = HashMap::new();
+        for name in tables {
+            match env.open_db(Some(name)) {
+                Ok(db) => {
+                    opened.insert(name.to_string(), db);
+                }
+                Err(lmdb::Error::NotFound) => {}
+                Err(e) => return Err(lmdb_error(e)),
+            }
+        }
+
+        Ok(Self { env, tables: opened, map_size, read_only: true })
+    }
+
+    /// LMDB環境に実際に設定されているフラグ
+    ///
+    /// 耐久性ポリシーどおりにfsyncが構成されているかの検証に使用する。
+    pub fn environment_flags(&self) -> InfrastructureResult<EnvironmentFlags> {
+        let mut flags: std::ffi::c_uint = 0;
+        // SAFETY: envはselfが所有する有効なMDB_envであり、flagsは書き込み可能な領域
+        let rc = unsafe { ffi::mdb_env_get_flags(self.env.env(), &mut flags) };
+        if rc != 0 {
+            return Err(lmdb_error(lmdb::Error::from_err_code(rc)));
+        }
+        Ok(EnvironmentFlags::from_bits_truncate(flags))
+    }
+
+    fn table(&self, name: &str) -> InfrastructureResult<Database> {
+        self.tables
+            .get(name)
+            .copied()
+            .ok_or_else(|| InfrastructureError::StorageTableNotFound(name.to_string()))
+    }
+}
+
+impl StorageBackend for LmdbBackend {
+    type ReadTxn<'a> = LmdbReadTxn<'a>;
+    type WriteTxn<'a> = LmdbWriteTxn<'a>;
+
+    fn begin_read(&self) -> InfrastructureResult<LmdbReadTxn<'_>> {
+        let txn = self.env.begin_ro_txn().map_err(lmdb_error)?;
+        Ok(LmdbReadTxn { backend: self, txn })
+    }
+
+    fn begin_write(&self) -> InfrastructureResult<LmdbWriteTxn<'_>> {
+        if self.read_only {
+            return Err(InfrastructureError::ReadOnlyReplica("storage".to_string()));
+        }
+        let txn = self.env.begin_rw_txn().map_err(lmdb_error)?;
+        Ok(LmdbWriteTxn { backend: self, txn })
+    }
+
+    fn sync(&self) -> InfrastructureResult<()> {
+        self.env.sync(true).map_err(lmdb_error)
+    }
+
+    fn stats(&self) -> InfrastructureResult<StorageStats> {
+        let mut env_stat: ffi::MDB_stat = unsafe { std::mem::zeroed() };
+        let mut env_info: ffi::MDB_envinfo = unsafe { std::mem::zeroed() };
+
+        // SAFETY: envはselfが所有する有効なMDB_envであり、統計領域は書き込み可能
+        unsafe {
+            let ret = ffi::mdb_env_stat(self.env.env(), &mut env_stat);
+            if ret != 0 {
+                return Err(InfrastructureError::LmdbError(format!(
+                    "mdb_env_stat failed: {}",
+                    ret
+                )));
+            }
+
+            let ret = ffi::mdb_env_info(self.env.env(), &mut env_info);
+            if ret != 0 {
+                return Err(InfrastructureError::LmdbError(format!(
+                    "mdb_env_info failed: {}",
+                    ret
+                )));
+            }
+        }
+
+        let page_size = env_stat.ms_psize as usize;
+        let last_page_no = env_info.me_last_pgno;
+        Ok(StorageStats {
+            map_size: self.map_size,
+            used_size: page_size * last_page_no,
+            page_size,
+            last_page_no,
+        })
+    }
+
+    fn copy_compacted(&self, destination: &Path) -> InfrastructureResult<()> {
+        let destination = std::ffi::CString::new(destination.as_os_str().as_encoded_bytes())
+            .map_err(|e| InfrastructureError::LmdbError(e.to_string()))?;
+        // SAFETY: envはselfが所有する有効なMDB_envであり、destinationはNUL終端された文字列
+        let rc = unsafe {
+            ffi::mdb_env_copy2(self.env.env(), destination.as_ptr(), ffi::MDB_CP_COMPACT)
+        };
+        if rc != 0 {
+            return Err(lmdb_error(lmdb::Error::from_err_code(rc)));
+        }
+        Ok(())
+    }
+
+    fn is_read_only(&self) -> bool {
+        self.read_only
+    }
+}
+
+/// LMDBの読み取りトランザクション
+pub struct LmdbReadTxn<'a> {
+    backend: &'a LmdbBackend,
+    txn: RoTransaction<'a>,
+}
+
+/// LMDBの書き込みトランザクション（破棄時にabort）
+pub struct LmdbWriteTxn<'a> {
+    backend: &'a LmdbBackend,
+    txn: RwTransaction<'a>,
+}
+
+fn get<T: Transaction>(txn: &T, db: Database, key: &[u8]) -> InfrastructureResult<Option<Vec<u8>>> {
+    match txn.get(db, &key) {
+        Ok(value) => Ok(Some(value.to_vec())),
+        Err(lmdb::Error::NotFound) => Ok(None),
+        Err(e) => Err(lmdb_error(e)),
+    }
+}
+
+fn scan<T: Transaction>(
+    txn: &T,
+    db: Database,
+    from: Option<&[u8]>,
+    visit: &mut ScanVisitor<'_>,
+) -> InfrastructureResult<()> {
+    let cursor = txn.open_ro_cursor(db).map_err(lmdb_error)?;
+    let mut entry = match from {
+        Some(key) => cursor.get(Some(key), None, ffi::MDB_SET_RANGE),
+        None => cursor.get(None, None, ffi::MDB_FIRST),
+    };
+
+    loop {
+        match entry {
+            Ok((Some(key), value)) => {
+                if !visit(key, value)? {
+                    return Ok(());
+                }
+            }
+            Ok((None, _)) | Err(lmdb::Error::NotFound) => return Ok(()),
+            Err(e) => return Err(lmdb_error(e)),
+        }
+        entry = cursor.get(None, None, ffi::MDB_NEXT);
+    }
+}
+
+fn entry_count<T: Transaction>(txn: &T, db: Database) -> InfrastructureResult<usize> {
+    let mut stat: ffi::MDB_stat = unsafe { std::mem::zeroed() };
+    // SAFETY: txnとdbは有効であり、statは書き込み可能な領域
+    let ret = unsafe { ffi::mdb_stat(txn.txn(), db.dbi(), &mut stat) };
+    if ret != 0 {
+        return Err(InfrastructureError::LmdbError(format!("mdb_stat failed: {}", ret)));
+    }
+    Ok(stat.ms_entries)
+}
+
+impl KvRead for LmdbReadTxn<'_> {
+    fn get(&self, table: &str, key: &[u8]) -> InfrastructureResult<Option<Vec<u8>>> {
+        get(&self.txn, self.backend.table(table)?, key)
+    }
+
+    fn scan(
+        &self,
+        table: &str,
+        from: Option<&[u8]>,
+        visit: &mut ScanVisitor<'_>,
+    ) -> InfrastructureResult<()> {
+        scan(&self.txn, self.backend.table(table)?, from, visit)
+    }
+
+    fn entry_count(&self, table: &str) -> InfrastructureResult<usize> {
+        entry_count(&self.txn, self.backend.table(table)?)
+    }
+}
+
+impl KvRead for LmdbWriteTxn<'_> {
+    fn get(&self, table: &str, key: &[u8]) -> InfrastructureResult<Option<Vec<u8>>> {
+        get(&self.txn, self.backend.table(table)?, key)
+    }
+
+    fn scan(
+        &self,
+        table: &str,
+        from: Option<&[u8]>,
+        visit: &mut ScanVisitor<'_>,
+    ) -> InfrastructureResult<()> {
+        scan(&self.txn, self.backend.table(table)?, from, visit)
+    }
+
+    fn entry_count(&self, table: &str) -> InfrastructureResult<usize> {
+        entry_count(&self.txn, self.backend.table(table)?)
+    }
+}
+
+impl KvWrite for LmdbWriteTxn<'_> {
+    fn put(&mut self, table: &str, key: &[u8], value: &[u8]) -> InfrastructureResult<()> {
+        let db = self.backend.table(table)?;
+        self.txn.put(db, &key, &value, WriteFlags::empty()).map_err(lmdb_error)
+    }
+
+    fn put_if_absent(
+        &mut self,
+        table: &str,
+        key: &[u8],
+        value: &[u8],
+    ) -> InfrastructureResult<bool> {
+        let db = self.backend.table(table)?;
+        match self.txn.put(db, &key, &value, WriteFlags::NO_OVERWRITE) {
+            Ok(()) => Ok(true),
+            Err(lmdb::Error::KeyExist) => Ok(false),
+            Err(e) => Err(lmdb_error(e)),
+        }
+    }
+
+    fn delete(&mut self, table: &str, key: &[u8]) -> InfrastructureResult<bool> {
+        let db = self.backend.table(table)?;
+        match self.txn.del(db, &key, None) {
+            Ok(()) => Ok(true),
+            Err(lmdb::Error::NotFound) => Ok(false),
+            Err(e) => Err(lmdb_error(e)),
+        }
+    }
+
+    fn commit(self) -> InfrastructureResult<()> {
+        self.txn.commit().map_err(lmdb_error)
+    }
+}
@@ -0,0 +1,219 @@
+// MemoryBackend - メモリ上のStorageBackend実装
+// テーブルはBTreeMapとして保持し、プロセス終了とともに破棄される
+//
+// 単体テストでLMDBの一時ディレクトリを用意せずにEventStore / ProjectionDbを使うためのもの。
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    path::Path,
+    sync::{Arc, Mutex, MutexGuard, RwLock},
+};
+
+use crate::{
+    error::{InfrastructureError, InfrastructureResult},
+    storage::{KvRead, KvWrite, ScanVisitor, StorageBackend, StorageStats},
+};
+
+/// 統計情報で報告するページサイズ（LMDBの既定値に合わせる）
+const PAGE_SIZE: usize = 4096;
+/// `copy_compacted` の書き出し先ファイル名
+const DATA_FILE: &str = "data.json";
+
+type Table = BTreeMap<Vec<u8>, Vec<u8>>;
+type Tables = HashMap<String, Table>;
+
+fn lock_error<T>(_: T) -> InfrastructureError {
+    InfrastructureError::TransactionFailed("memory storage lock poisoned".to_string())
+}
+
+fn table<'t>(tables: &'t Tables, name: &str) -> InfrastructureResult<&'t Table> {
+    tables
+        .get(name)
+        .ok_or_else(|| InfrastructureError::StorageTableNotFound(name.to_string()))
+}
+
+/// メモリ上のバックエンド
+///
+/// コミット済みの内容を不変のスナップショットとして共有する。
+/// 読み取りトランザクションは開始時点のスナップショットを参照し、
+/// 書き込みトランザクションは複製に対して変更してコミット時に差し替える。
+pub struct MemoryBackend {
+    committed: RwLock<Arc<Tables>>,
+    /// 書き込みトランザクションの直列化
+    writer: Mutex<()>,
+}
+
+impl MemoryBackend {
+    /// 空のテーブルを持つバックエンドを作成
+    pub fn new(tables: &[&str]) -> Self {
+        let tables = tables.iter().map(|name| (name.to_string(), Table::new())).collect();
+        Self { committed: RwLock::new(Arc::new(tables)), writer: Mutex::new(()) }
+    }
+
+    fn snapshot(&self) -> InfrastructureResult<Arc<Tables>> {
+        self.committed.read().map(|tables| Arc::clone(&tables)).map_err(lock_error)
+    }
+}
+
+impl StorageBackend for MemoryBackend {
+    type ReadTxn<'a> = MemoryReadTxn;
+    type WriteTxn<'a> = MemoryWriteTxn<'a>;
+
+    fn begin_read(&self) -> InfrastructureResult<MemoryReadTxn> {
+        Ok(MemoryReadTxn { tables: self.snapshot()? })
+    }
+
+    fn begin_write(&self) -> InfrastructureResult<MemoryWriteTxn<'_>> {
+        let guard = self.writer.lock().map_err(lock_error)?;
+        let tables = Tables::clone(&*self.snapshot()?);
+        Ok(MemoryWriteTxn { backend: self, _guard: guard, tables })
+    }
+
+    fn sync(&self) -> InfrastructureResult<()> {
+        Ok(())
+    }
+
+    fn stats(&self) -> InfrastructureResult<StorageStats> {
+        let bytes: usize = self
+            .snapshot()?
+            .values()
+            .flat_map(|table| table.iter())
+            .map(|(key, value)| key.len() + value.len())
+            .sum();
+        let last_page_no = bytes.div_ceil(PAGE_SIZE).max(1);
+        let used_size = last_page_no * PAGE_SIZE;
+        // 必要に応じて確保するため、確保済みの容量は使用中の容量と等しい
+        Ok(StorageStats { map_size: used_size, used_size, page_size: PAGE_SIZE, last_page_no })
+    }
+
+    fn copy_compacted(&self, destination: &Path) -> InfrastructureResult<()> {
+        let snapshot = self.snapshot()?;
+        let dump: BTreeMap<&str, Vec<_>> = snapshot
+            .iter()
+            .map(|(name, table)| {
+                (
+                    name.as_str(),
+                    table.iter().map(|(key, value)| (key.as_slice(), value.as_slice())).collect(),
+                )
+            })
+            .collect();
+        let bytes = serde_json::to_vec(&dump)
+            .map_err(|e| InfrastructureError::SerializationFailed(e.to_string()))?;
+        std::fs::write(destination.join(DATA_FILE), bytes).map_err(|e| {
+            InfrastructureError::DirectoryCreationFailed {
+                path: destination.display().to_string(),
+                source: e,
+            }
+        })
+    }
+
+    fn is_read_only(&self) -> bool {
+        false
+    }
+}
+
+/// メモリ上の読み取りトランザクション
+pub struct MemoryReadTxn {
+    tables: Arc<Tables>,
+}
+
+/// メモリ上の書き込みトランザクション（破棄時は変更を捨てる）
+pub struct MemoryWriteTxn<'a> {
+    backend: &'a MemoryBackend,
+    _guard: MutexGuard<'a, ()>,
+    tables: Tables,
+}
+
+impl MemoryWriteTxn<'_> {
+    fn table_mut(&mut self, name: &str) -> InfrastructureResult<&mut Table> {
+        self.tables
+            .get_mut(name)
+            .ok_or_else(|| InfrastructureError::StorageTableNotFound(name.to_string()))
+    }
+}
+
+fn scan(
+    table: &Table,
+    from: Option<&[u8]>,
+    visit: &mut ScanVisitor<'_>,
+) -> InfrastructureResult<()> {
+    let entries: Box<dyn Iterator<Item = (&Vec<u8>, &Vec<u8>)>> = match from {
+        Some(key) => Box::new(table.range(key.to_vec()..)),
+        None => Box::new(table.iter()),
+    };
+    for (key, value) in entries {
+        if !visit(key, value)? {
+            break;
+        }
+    }
+    Ok(())
+}
+
+impl KvRead for MemoryReadTxn {
+    fn get(&self, name: &str, key: &[u8]) -> InfrastructureResult<Option<Vec<u8>>> {
+        Ok(table(&self.tables, name)?.get(key).cloned())
+    }
+
+    fn scan(
+        &self,
+        name: &str,
+        from: Option<&[u8]>,
+        visit: &mut ScanVisitor<'_>,
+    ) -> InfrastructureResult<()> {
+        scan(table(&self.tables, name)?, from, visit)
+    }
+
+    fn entry_count(&self, name: &str) -> InfrastructureResult<usize> {
+        Ok(table(&self.tables, name)?.len())
+    }
+}
+
+impl KvRead for MemoryWriteTxn<'_> {
+    fn get(&self, name: &str, key: &[u8]) -> InfrastructureResult<Option<Vec<u8>>> {
+        Ok(table(&self.tables, name)?.get(key).cloned())
+    }
+
+    fn scan(
+        &self,
+        name: &str,
+        from: Option<&[u8]>,
+        visit: &mut ScanVisitor<'_>,
+    ) -> InfrastructureResult<()> {
+        scan(table(&self.tables, name)?, from, visit)
+    }
+
+    fn entry_count(&self, name: &str) -> InfrastructureResult<usize> {
+        Ok(table(&self.tables, name)?.len())
+    }
+}
+
+impl KvWrite for MemoryWriteTxn<'_> {
+    fn put(&mut self, name: &str, key: &[u8], value: &[u8]) -> InfrastructureResult<()> {
+        self.table_mut(name)?.insert(key.to_vec(), value.to_vec());
+        Ok(())
+    }
+
+    fn put_if_absent(
+        &mut self,
+        name: &str,
+        key: &[u8],
+        value: &[u8],
+    ) -> InfrastructureResult<bool> {
+        let table = self.table_mut(name)?;
+        if table.contains_key(key) {
+            return Ok(false);
+        }
+        table.insert(key.to_vec(), value.to_vec());
+        Ok(true)
+    }
+
+    fn delete(&mut self, name: &str, key: &[u8]) -> InfrastructureResult<bool> {
+        Ok(self.table_mut(name)?.remove(key).is_some())
+    }
+
+    fn commit(self) -> InfrastructureResult<()> {
+        let mut committed = self.backend.committed.write().map_err(lock_error)?;
+        *committed = Arc::new(self.tables);
+        Ok(())
+    }
+}
@@ -1,9 +1,15 @@
 // Storage Metrics - LMDB容量監視
 // 目的: map_size枯渇の早期検知
-// 用途: アラート、自動拡張判定
+// 用途: アラート、自動拡張判定、使用状況の傾向表示
 
+use std::{collections::VecDeque, time::Duration};
+
+use javelin_application::storage_telemetry::StorageSample;
 use serde::{Deserialize, Serialize};
 
+/// 既定で保持する標本数（10分間隔で2日分）
+pub const DEFAULT_STORAGE_HISTORY_CAPACITY: usize = 288;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StorageMetrics {
     pub map_size: usize,
@@ -12,6 +18,14 @@ pub struct StorageMetrics {
     pub page_size: usize,
     pub last_page_no: usize,
     pub entries: usize,
+    /// 隔離中のイベント件数（Projection再構築でスキップされる）
+    pub quarantined_events: usize,
+    /// 集約イベントキャッシュのヒット件数（起動以降）
+    pub aggregate_cache_hits: u64,
+    /// 集約イベントキャッシュのミス件数（LMDBを走査した件数）
+    pub aggregate_cache_misses: u64,
+    /// 集約イベントキャッシュが保持している集約数
+    pub aggregate_cache_entries: usize,
 }
 
 impl StorageMetrics {
@@ -26,6 +40,62 @@ impl StorageMetrics {
     pub fn should_expand(&self) -> bool {
         self.usage_percent >= 75.0
     }
+
+    pub fn has_quarantined_events(&self) -> bool {
+        self.quarantined_events > 0
+    }
+
+    /// 集約イベントキャッシュのヒット率（%）。照会がない場合はNone
+    pub fn aggregate_cache_hit_rate(&self) -> Option<f64> {
+        let lookups = self.aggregate_cache_hits + self.aggregate_cache_misses;
+        (lookups > 0).then(|| self.aggregate_cache_hits as f64 * 100.0 / lookups as f64)
+    }
+}
+
+/// ストレージ使用状況の時系列（リングバッファ）
+///
+/// 上限件数を超えた場合は古い標本から破棄する。
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StorageMetricsHistory {
+    capacity: usize,
+    samples: VecDeque<StorageSample>,
+}
+
+impl StorageMetricsHistory {
+    pub fn new(capacity: usize) -> Self {
+        Self { capacity, samples: VecDeque::with_capacity(capacity) }
+    }
+
+    /// 標本を追加（上限を超えた分は古い順に破棄）
+    pub fn push(&mut self, sample: StorageSample) {
+        self.samples.push_back(sample);
+        self.truncate();
+    }
+
+    /// 上限件数を変更（減らした場合は古い標本から破棄）
+    pub fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity;
+        self.truncate();
+    }
+
+    fn truncate(&mut self) {
+        while self.samples.len() > self.capacity {
+            self.samples.pop_front();
+        }
+    }
+
+    /// 標本（古い順）
+    pub fn samples(&self) -> impl Iterator<Item = &StorageSample> {
+        self.samples.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -48,6 +118,9 @@ impl ProjectionLagMetrics {
     }
 }
 
+/// グループコミットで後続の追記を待つ既定の時間
+pub const DEFAULT_GROUP_COMMIT_WINDOW: Duration = Duration::from_millis(5);
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum DurabilityPolicy {
     /// 最大耐久性（デフォルト）
@@ -65,4 +138,102 @@ pub enum DurabilityPolicy {
     /// - fsyncなし
     /// - 性能: 高、リスク: クラッシュ時に最新データ喪失可能
     MaxPerformance,
+
+    /// グループコミット
+    /// - コミット時はfsyncせず、並行する追記のfsyncを1回にまとめる
+    /// - 追記の完了はfsync後に返すため、耐久性は MaxDurability と同じ
+    /// - 他に書き込み中の追記がある場合だけ、最大 `window` だけ待って同期する
+    GroupCommit { window: Duration },
+}
+
+impl DurabilityPolicy {
+    /// 既定の待ち時間のグループコミット
+    pub fn group_commit() -> Self {
+        DurabilityPolicy::GroupCommit { window: DEFAULT_GROUP_COMMIT_WINDOW }
+    }
+
+    /// 名前から解析（`max-durability` / `balanced` / `max-performance` /
+    /// `group-commit` / `group-commit:<待ち時間ミリ秒>`）
+    pub fn parse(name: &str) -> Option<Self> {
+        match name {
+            "max-durability" => Some(DurabilityPolicy::MaxDurability),
+            "balanced" => Some(DurabilityPolicy::Balanced),
+            "max-performance" => Some(DurabilityPolicy::MaxPerformance),
+            "group-commit" => Some(DurabilityPolicy::group_commit()),
+            _ => {
+                let millis = name.strip_prefix("group-commit:")?.parse().ok()?;
+                Some(DurabilityPolicy::GroupCommit { window: Duration::from_millis(millis) })
+            }
+        }
+    }
+
+    /// 表示名
+    pub fn label(&self) -> String {
+        match self {
+            DurabilityPolicy::MaxDurability => "max-durability".to_string(),
+            DurabilityPolicy::Balanced => "balanced".to_string(),
+            DurabilityPolicy::MaxPerformance => "max-performance".to_string(),
+            DurabilityPolicy::GroupCommit { window } => {
+                format!("group-commit({}ms)", window.as_millis())
+            }
+        }
+    }
+}
+
+/// イベント追記の計測値（起動以降）
+///
+/// 耐久性ポリシーごとの追記の待ち時間とスループットの比較に使用する。
+/// 待ち時間は追記の呼び出しから完了（グループコミットではfsync後）までを計る。
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AppendMetrics {
+    /// 耐久性ポリシーの表示名
+    pub policy: String,
+    /// 追記の回数
+    pub appends: u64,
+    /// 追記したイベント数
+    pub events: u64,
+    /// 明示的なfsyncの回数（グループコミットのみ）
+    pub syncs: u64,
+    /// 待ち時間の合計
+    pub total_latency: Duration,
+    /// 最大の待ち時間
+    pub max_latency: Duration,
+    /// 最初の追記開始から最後の追記完了まで
+    pub elapsed: Duration,
+}
+
+impl AppendMetrics {
+    /// 平均の待ち時間（ミリ秒）
+    pub fn average_latency_ms(&self) -> f64 {
+        if self.appends == 0 {
+            return 0.0;
+        }
+        self.total_latency.as_secs_f64() * 1000.0 / self.appends as f64
+    }
+
+    /// 1秒あたりの追記イベント数
+    pub fn events_per_second(&self) -> f64 {
+        self.events as f64 / self.elapsed.as_secs_f64().max(f64::EPSILON)
+    }
+
+    /// 1回のfsyncでまとめた追記数（グループコミット以外はNone）
+    pub fn appends_per_sync(&self) -> Option<f64> {
+        (self.syncs > 0).then(|| self.appends as f64 / self.syncs as f64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_durability_policy() {
+        assert_eq!(DurabilityPolicy::parse("balanced"), Some(DurabilityPolicy::Balanced));
+        assert_eq!(DurabilityPolicy::parse("group-commit"), Some(DurabilityPolicy::group_commit()));
+        let policy = DurabilityPolicy::parse("group-commit:20").unwrap();
+        assert_eq!(policy, DurabilityPolicy::GroupCommit { window: Duration::from_millis(20) });
+        assert_eq!(policy.label(), "group-commit(20ms)");
+        assert_eq!(DurabilityPolicy::parse("group-commit:x"), None);
+        assert_eq!(DurabilityPolicy::parse("fast"), None);
+    }
 }
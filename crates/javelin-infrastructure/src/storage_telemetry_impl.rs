@@ -0,0 +1,167 @@
+// StorageTelemetry具象実装 - Infrastructure層
+// イベントストアの容量とProjectionの遅延を標本化し、Projection DBに時系列として保存する
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use javelin_application::{
+    error::{ApplicationError, ApplicationResult},
+    storage_telemetry::{StorageSample, StorageTelemetry},
+};
+use tokio::sync::Mutex;
+
+use crate::{
+    event_store::EventStore,
+    projection_db::ProjectionDb,
+    storage_metrics::{DEFAULT_STORAGE_HISTORY_CAPACITY, StorageMetricsHistory},
+};
+
+/// Projection DBに保存する時系列の名前
+const HISTORY_NAME: &str = "storage_metrics";
+/// 遅延を計測するProjection
+const PROJECTION_NAME: &str = "main";
+const PROJECTION_VERSION: u32 = 1;
+
+/// StorageTelemetry具象実装
+///
+/// 時系列はProjection DBのメタ情報として保存するため、Projectionの
+/// 再構築・圧縮による置き換えでも失われない。
+pub struct StorageTelemetryImpl {
+    event_store: Arc<EventStore>,
+    projection_db: Arc<ProjectionDb>,
+    capacity: usize,
+    /// 読み込み・追加・保存を直列化する
+    lock: Mutex<()>,
+}
+
+impl StorageTelemetryImpl {
+    /// 新しいStorageTelemetryImplを作成
+    pub fn new(event_store: Arc<EventStore>, projection_db: Arc<ProjectionDb>) -> Self {
+        Self::with_capacity(event_store, projection_db, DEFAULT_STORAGE_HISTORY_CAPACITY)
+    }
+
+    /// 保持する標本数を指定して作成
+    pub fn with_capacity(
+        event_store: Arc<EventStore>,
+        projection_db: Arc<ProjectionDb>,
+        capacity: usize,
+    ) -> Self {
+        Self { event_store, projection_db, capacity, lock: Mutex::new(()) }
+    }
+
+    async fn load_history(&self) -> ApplicationResult<StorageMetricsHistory> {
+        let stored =
+            self.projection_db.get_telemetry(HISTORY_NAME).await.map_err(projection_error)?;
+        let mut history = match stored {
+            Some(bytes) => serde_json::from_slice::<StorageMetricsHistory>(&bytes)
+                .map_err(|e| ApplicationError::ProjectionDatabaseError(e.to_string()))?,
+            None => StorageMetricsHistory::new(self.capacity),
+        };
+        history.set_capacity(self.capacity);
+        Ok(history)
+    }
+
+    async fn current_sample(&self) -> ApplicationResult<StorageSample> {
+        let metrics = self
+            .event_store
+            .get_storage_metrics()
+            .await
+            .map_err(|e| ApplicationError::EventStoreError(e.to_string()))?;
+        let latest = self
+            .event_store
+            .get_latest_sequence()
+            .await
+            .map_err(|e| ApplicationError::EventStoreError(e.to_string()))?
+            .as_u64();
+        let processed = self
+            .projection_db
+            .get_position(PROJECTION_NAME, PROJECTION_VERSION)
+            .await
+            .map_err(projection_error)?;
+
+        Ok(StorageSample {
+            sampled_at: Utc::now(),
+            usage_percent: metrics.usage_percent,
+            used_size: metrics.used_size as u64,
+            map_size: metrics.map_size as u64,
+            entries: metrics.entries as u64,
+            projection_lag: latest.saturating_sub(processed),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl StorageTelemetry for StorageTelemetryImpl {
+    async fn record_sample(&self) -> ApplicationResult<StorageSample> {
+        let sample = self.current_sample().await?;
+
+        let _guard = self.lock.lock().await;
+        let mut history = self.load_history().await?;
+        history.push(sample.clone());
+        let bytes = serde_json::to_vec(&history)
+            .map_err(|e| ApplicationError::ProjectionDatabaseError(e.to_string()))?;
+        self.projection_db
+            .put_telemetry(HISTORY_NAME, bytes)
+            .await
+            .map_err(projection_error)?;
+
+        Ok(sample)
+    }
+
+    async fn samples(&self) -> ApplicationResult<Vec<StorageSample>> {
+        let history = self.load_history().await?;
+        Ok(history.samples().cloned().collect())
+    }
+}
+
+fn projection_error(e: crate::error::InfrastructureError) -> ApplicationError {
+    ApplicationError::ProjectionDatabaseError(e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use javelin_domain::financial_close::journal_entry::events::JournalEntryEvent;
+    use tempfile::TempDir;
+
+    use super::*;
+
+    fn draft(entry_id: &str) -> JournalEntryEvent {
+        JournalEntryEvent::DraftCreated {
+            entry_id: entry_id.to_string(),
+            transaction_date: "2024-04-01".to_string(),
+            voucher_number: format!("V-{}", entry_id),
+            lines: vec![],
+            description: None,
+            created_by: "clerk".to_string(),
+            created_at: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_record_sample_keeps_bounded_history() {
+        let temp_dir = TempDir::new().unwrap();
+        let event_store = Arc::new(EventStore::new(&temp_dir.path().join("events")).await.unwrap());
+        let projection_db =
+            Arc::new(ProjectionDb::new(&temp_dir.path().join("projections")).await.unwrap());
+        let telemetry =
+            StorageTelemetryImpl::with_capacity(Arc::clone(&event_store), projection_db, 2);
+
+        assert!(telemetry.samples().await.unwrap().is_empty());
+
+        event_store.append("je-1", vec![draft("je-1")]).await.unwrap();
+        let first = telemetry.record_sample().await.unwrap();
+        assert_eq!(first.projection_lag, 1);
+        assert!(first.map_size > 0);
+
+        event_store.append("je-2", vec![draft("je-2")]).await.unwrap();
+        telemetry.record_sample().await.unwrap();
+        let third = telemetry.record_sample().await.unwrap();
+
+        // 上限を超えた最も古い標本は破棄される
+        let samples = telemetry.samples().await.unwrap();
+        assert_eq!(samples.len(), 2);
+        assert_eq!(samples.last(), Some(&third));
+        assert_eq!(third.projection_lag, 2);
+        assert!(!samples.contains(&first));
+    }
+}
@@ -0,0 +1,280 @@
+//! Crash recovery tests
+//!
+//! テストバイナリ自身を子プロセスとして起動し、書き込み経路上の
+//! クラッシュ注入ポイントでabortさせた後、再オープンして次を検証する。
+//! - 応答済み（ACK）のイベントが失われないこと
+//! - Projectionの状態とチェックポイントが一致し、追従処理で最新位置まで復旧すること
+//! - DurabilityPolicyどおりにfsyncが構成されていること
+//!
+//! プロセスのクラッシュを対象とし、OSクラッシュや電源断は対象外。
+
+use std::{io::Write, path::Path, process::Command};
+
+use lmdb::EnvironmentFlags;
+use serde::{Deserialize, Serialize};
+use tempfile::TempDir;
+
+use crate::{
+    crash_point::{CRASH_AFTER_ENV, CRASH_POINT_ENV, CrashPoint},
+    event_store::EventStore,
+    projection_db::ProjectionDb,
+    storage_metrics::{DEFAULT_GROUP_COMMIT_WINDOW, DurabilityPolicy},
+};
+
+/// 子プロセスの作業ディレクトリを指定する環境変数
+const CHILD_DIR_ENV: &str = "JAVELIN_CRASH_CHILD_DIR";
+/// 子プロセスの耐久性ポリシーを指定する環境変数
+const CHILD_POLICY_ENV: &str = "JAVELIN_CRASH_CHILD_POLICY";
+
+const PROJECTION_NAME: &str = "crash_recovery";
+const PROJECTION_VERSION: u32 = 1;
+/// 子プロセスが追記するイベント数
+const EVENT_COUNT: u64 = 10;
+/// クラッシュまでに注入ポイントを通過させる回数
+const CRASH_AFTER: u64 = 4;
+
+const POLICIES: [DurabilityPolicy; 4] = [
+    DurabilityPolicy::MaxDurability,
+    DurabilityPolicy::Balanced,
+    DurabilityPolicy::MaxPerformance,
+    DurabilityPolicy::GroupCommit { window: DEFAULT_GROUP_COMMIT_WINDOW },
+];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct TestEvent {
+    #[serde(rename = "type")]
+    event_type: String,
+    index: u64,
+}
+
+fn policy_name(policy: DurabilityPolicy) -> &'static str {
+    match policy {
+        DurabilityPolicy::MaxDurability => "max_durability",
+        DurabilityPolicy::Balanced => "balanced",
+        DurabilityPolicy::MaxPerformance => "max_performance",
+        DurabilityPolicy::GroupCommit { .. } => "group_commit",
+    }
+}
+
+fn parse_policy(name: &str) -> DurabilityPolicy {
+    POLICIES.into_iter().find(|p| policy_name(*p) == name).expect("unknown policy")
+}
+
+async fn open_store(dir: &Path, policy: DurabilityPolicy) -> EventStore {
+    EventStore::new_with_config(&dir.join("events"), 10 * 1024 * 1024, policy)
+        .await
+        .unwrap()
+}
+
+async fn open_projection_db(dir: &Path) -> ProjectionDb {
+    ProjectionDb::new(&dir.join("projections")).await.unwrap()
+}
+
+fn projection_key(sequence: u64) -> String {
+    format!("event:{:020}", sequence)
+}
+
+/// 1イベント分のProjectionを更新（stateとチェックポイントを同一トランザクションで更新）
+async fn project(projection_db: &ProjectionDb, sequence: u64) {
+    projection_db
+        .update_projection_batch(
+            PROJECTION_NAME,
+            PROJECTION_VERSION,
+            vec![(projection_key(sequence), sequence.to_be_bytes().to_vec())],
+            sequence,
+        )
+        .await
+        .unwrap();
+}
+
+/// 子プロセス側の処理
+///
+/// イベント追記とProjection更新を1件ずつ繰り返し、応答を受けるたびに
+/// 標準出力へ報告する。通常のテスト実行では環境変数がないため何もしない。
+#[tokio::test]
+async fn crash_child() {
+    let Ok(dir) = std::env::var(CHILD_DIR_ENV) else {
+        return;
+    };
+    let dir = Path::new(&dir);
+    let policy = parse_policy(&std::env::var(CHILD_POLICY_ENV).unwrap());
+    let store = open_store(dir, policy).await;
+    let projection_db = open_projection_db(dir).await;
+    let mut stdout = std::io::stdout();
+
+    for index in 0..EVENT_COUNT {
+        let event = TestEvent { event_type: "DraftCreated".to_string(), index };
+        let sequence = store.append(&format!("entry-{:03}", index), vec![event]).await.unwrap();
+        writeln!(stdout, "\nACK {}", sequence).unwrap();
+        stdout.flush().unwrap();
+
+        project(&projection_db, sequence).await;
+        writeln!(stdout, "\nPROJECTED {}", sequence).unwrap();
+        stdout.flush().unwrap();
+    }
+}
+
+/// 子プロセスの実行結果
+struct ChildReport {
+    crashed: bool,
+    acked: Vec<u64>,
+    projected: Vec<u64>,
+}
+
+/// テストバイナリを子プロセスとして起動し、指定位置でクラッシュさせる
+fn run_child(dir: &Path, policy: DurabilityPolicy, point: CrashPoint) -> ChildReport {
+    let test_path = concat!(module_path!(), "::crash_child");
+    let (_, test_name) = test_path.split_once("::").unwrap();
+
+    let output = Command::new(std::env::current_exe().unwrap())
+        .args([test_name, "--exact", "--nocapture", "--test-threads=1"])
+        .env(CHILD_DIR_ENV, dir)
+        .env(CHILD_POLICY_ENV, policy_name(policy))
+        .env(CRASH_POINT_ENV, point.as_str())
+        .env(CRASH_AFTER_ENV, CRASH_AFTER.to_string())
+        .output()
+        .unwrap();
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let reported = |tag: &str| -> Vec<u64> {
+        stdout
+            .lines()
+            .filter_map(|line| line.strip_prefix(tag))
+            .filter_map(|rest| rest.trim().parse().ok())
+            .collect()
+    };
+
+    ChildReport {
+        crashed: !output.status.success(),
+        acked: reported("ACK "),
+        projected: reported("PROJECTED "),
+    }
+}
+
+/// 再オープン後の状態を検証し、Projectionを最新位置まで追従させる
+async fn verify_recovery(
+    dir: &Path,
+    policy: DurabilityPolicy,
+    point: CrashPoint,
+    report: &ChildReport,
+) {
+    let case = format!("{} / {}", policy_name(policy), point.as_str());
+    assert!(report.crashed, "{}: 子プロセスがクラッシュしていません", case);
+
+    // イベントストア：欠番がなく、ACK済みのイベントがすべて残っていること
+    let store = open_store(dir, policy).await;
+    let sequences: Vec<u64> = store
+        .get_all_events(0)
+        .await
+        .unwrap()
+        .iter()
+        .map(|e| e.global_sequence)
+        .collect();
+    let latest = sequences.len() as u64;
+    assert_eq!(sequences, (1..=latest).collect::<Vec<_>>(), "{}: 欠番があります", case);
+    for sequence in &report.acked {
+        assert!(
+            sequences.contains(sequence),
+            "{}: ACK済みのseq={}が失われました",
+            case,
+            sequence
+        );
+    }
+
+    let acked = report.acked.len() as u64;
+    match point {
+        // コミット前のイベントは残らない
+        CrashPoint::AppendBeforeCommit => assert_eq!(latest, acked, "{}", case),
+        // コミット済みだが応答前のイベントは残る
+        CrashPoint::AppendAfterCommit => assert_eq!(latest, acked + 1, "{}", case),
+        CrashPoint::ProjectionBeforeCommit | CrashPoint::ProjectionAfterCommit => {
+            assert_eq!(latest, acked, "{}", case)
+        }
+    }
+    assert_eq!(store.get_latest_sequence().await.unwrap().as_u64(), latest, "{}", case);
+
+    // Projection：チェックポイントとstateが一致していること
+    let projection_db = open_projection_db(dir).await;
+    let position = projection_db.get_position(PROJECTION_NAME, PROJECTION_VERSION).await.unwrap();
+    let last_projected = report.projected.last().copied().unwrap_or(0);
+    assert!(position >= last_projected, "{}: 応答済みのProjection更新が失われました", case);
+    assert!(position <= latest, "{}: チェックポイントがイベントを追い越しています", case);
+
+    let expected_position = match point {
+        CrashPoint::ProjectionAfterCommit => last_projected + 1,
+        _ => last_projected,
+    };
+    assert_eq!(position, expected_position, "{}", case);
+
+    for sequence in 1..=latest {
+        let state = projection_db.get_projection(&projection_key(sequence)).await.unwrap();
+        assert_eq!(
+            state.is_some(),
+            sequence <= position,
+            "{}: seq={}のstateとチェックポイントが一致しません",
+            case,
+            sequence
+        );
+    }
+
+    // 再起動後の追従処理で最新位置まで復旧すること
+    for sequence in (position + 1)..=latest {
+        project(&projection_db, sequence).await;
+    }
+    assert_eq!(
+        projection_db.get_position(PROJECTION_NAME, PROJECTION_VERSION).await.unwrap(),
+        latest,
+        "{}",
+        case
+    );
+    for sequence in 1..=latest {
+        assert!(
+            projection_db.get_projection(&projection_key(sequence)).await.unwrap().is_some(),
+            "{}: seq={}のProjectionが復旧していません",
+            case,
+            sequence
+        );
+    }
+
+    // 復旧後も追記を継続できること
+    let event = TestEvent { event_type: "DraftCreated".to_string(), index: EVENT_COUNT };
+    let next = store.append("entry-recovered", vec![event]).await.unwrap();
+    assert_eq!(next, latest + 1, "{}", case);
+}
+
+/// すべての耐久性ポリシー・注入ポイントでクラッシュから復旧できること
+#[tokio::test]
+async fn test_recovers_from_crash_at_each_point_under_each_policy() {
+    for policy in POLICIES {
+        for point in CrashPoint::ALL {
+            let temp_dir = TempDir::new().unwrap();
+            let report = run_child(temp_dir.path(), policy, point);
+            verify_recovery(temp_dir.path(), policy, point, &report).await;
+        }
+    }
+}
+
+/// 耐久性ポリシーどおりにfsyncが構成されていること
+#[tokio::test]
+async fn test_durability_policy_configures_fsync() {
+    for policy in POLICIES {
+        let temp_dir = TempDir::new().unwrap();
+        let store = open_store(temp_dir.path(), policy).await;
+        assert_eq!(store.durability_policy(), policy);
+
+        let flags = store.environment_flags().unwrap();
+        let (no_sync, no_meta_sync) = match policy {
+            DurabilityPolicy::MaxDurability => (false, false),
+            DurabilityPolicy::Balanced => (false, true),
+            // グループコミットはコミット時ではなく追記の完了前に明示的にfsyncする
+            DurabilityPolicy::MaxPerformance | DurabilityPolicy::GroupCommit { .. } => (true, true),
+        };
+        assert_eq!(flags.contains(EnvironmentFlags::NO_SYNC), no_sync, "{:?}", policy);
+        assert_eq!(flags.contains(EnvironmentFlags::NO_META_SYNC), no_meta_sync, "{:?}", policy);
+
+        // 明示的なfsyncはどのポリシーでも成功すること
+        let event = TestEvent { event_type: "DraftCreated".to_string(), index: 0 };
+        store.append("entry-001", vec![event]).await.unwrap();
+        store.sync().await.unwrap();
+    }
+}
@@ -0,0 +1,85 @@
+//! Unit tests for EventSubscription
+//!
+//! - シーケンス位置からの再開
+//! - イベントタイプ・集約IDプレフィックスによるフィルタ
+//! - 購読開始後の追記イベントの配信
+
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use tempfile::TempDir;
+
+use crate::{event_store::EventStore, event_subscription::SubscriptionFilter};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct TestEvent {
+    #[serde(rename = "type")]
+    event_type: String,
+}
+
+fn event(event_type: &str) -> TestEvent {
+    TestEvent { event_type: event_type.to_string() }
+}
+
+/// ack済み位置から再購読すると未ackのイベントが再配信されること
+#[tokio::test]
+async fn test_subscription_resumes_from_acked_sequence() {
+    let temp_dir = TempDir::new().unwrap();
+    let store = EventStore::new(temp_dir.path()).await.unwrap();
+    store
+        .append("entry-001", vec![event("DraftCreated"), event("Posted"), event("Reversed")])
+        .await
+        .unwrap();
+
+    let mut subscription = store.subscribe(1, SubscriptionFilter::all(), 8);
+    let first = subscription.next().await.unwrap().unwrap();
+    assert_eq!(first.global_sequence, 1);
+    subscription.ack(first.global_sequence);
+    let _unacked = subscription.next().await.unwrap().unwrap();
+    let resume_from = subscription.resume_from();
+    drop(subscription);
+
+    let mut resumed = store.subscribe(resume_from, SubscriptionFilter::all(), 8);
+    let redelivered = resumed.next().await.unwrap().unwrap();
+    assert_eq!(redelivered.global_sequence, 2);
+}
+
+/// フィルタ条件に一致するイベントのみ配信されること
+#[tokio::test]
+async fn test_subscription_filters_by_type_and_aggregate_prefix() {
+    let temp_dir = TempDir::new().unwrap();
+    let store = EventStore::new(temp_dir.path()).await.unwrap();
+    store
+        .append("journal-001", vec![event("DraftCreated"), event("Posted")])
+        .await
+        .unwrap();
+    store.append("account-001", vec![event("Posted")]).await.unwrap();
+    store.append("journal-002", vec![event("Posted")]).await.unwrap();
+
+    let filter = SubscriptionFilter::all()
+        .with_event_type("Posted")
+        .with_aggregate_prefix("journal-");
+    let mut subscription = store.subscribe(1, filter, 8);
+
+    let first = subscription.next().await.unwrap().unwrap();
+    let second = subscription.next().await.unwrap().unwrap();
+    assert_eq!((first.aggregate_id.as_str(), first.global_sequence), ("journal-001", 2));
+    assert_eq!((second.aggregate_id.as_str(), second.global_sequence), ("journal-002", 4));
+}
+
+/// 購読開始後に追記されたイベントが配信されること
+#[tokio::test]
+async fn test_subscription_receives_appended_events() {
+    let temp_dir = TempDir::new().unwrap();
+    let store = EventStore::new(temp_dir.path()).await.unwrap();
+
+    let mut subscription = store.subscribe(1, SubscriptionFilter::all(), 1);
+    store.append("entry-001", vec![event("DraftCreated")]).await.unwrap();
+
+    let received = tokio::time::timeout(Duration::from_secs(5), subscription.next())
+        .await
+        .unwrap()
+        .unwrap()
+        .unwrap();
+    assert_eq!(received.event_type, "DraftCreated");
+}
@@ -9,7 +9,7 @@ mod tests {
     use std::sync::Arc;
 
     use javelin_application::query_service::ledger_query_service::{
-        GetLedgerQuery, GetTrialBalanceQuery, LedgerQueryService,
+        EntryStatusScope, GetLedgerQuery, GetTrialBalanceQuery, LedgerQueryService,
     };
     use javelin_domain::{
         financial_close::journal_entry::events::{JournalEntryEvent, JournalEntryLineDto},
@@ -34,6 +34,7 @@ mod tests {
             transaction_date,
             voucher_number: format!("V-{}", entry_number),
             lines,
+            description: None,
             created_by: "test_user".to_string(),
             created_at: chrono::Utc::now(),
         };
@@ -55,7 +56,8 @@ mod tests {
         /// EventStoreに保存されたイベントから元帳データを正確に取得できることを検証
         #[test]
         fn prop_ledger_query_accuracy(
-            account_code in "[0-9]{4}",
+            // 相手科目（9999）と同じ科目では貸方も集計されるため除外
+            account_code in "[0-9]{4}".prop_filter("相手科目以外", |code| code != "9999"),
             num_entries in 1usize..5usize,
         ) {
             tokio::runtime::Runtime::new().unwrap().block_on(async {
@@ -78,8 +80,10 @@ mod tests {
                             line_number: 1,
                             side: "Debit".to_string(),
                             account_code: account_code.clone(),
+                            account_name: None,
                             sub_account_code: None,
                             department_code: None,
+                            dimensions: Default::default(),
                             amount,
                             currency: "JPY".to_string(),
                             tax_type: "NonTaxable".to_string(),
@@ -90,8 +94,10 @@ mod tests {
                             line_number: 2,
                             side: "Credit".to_string(),
                             account_code: "9999".to_string(),
+                            account_name: None,
                             sub_account_code: None,
                             department_code: None,
+                            dimensions: Default::default(),
                             amount,
                             currency: "JPY".to_string(),
                             tax_type: "NonTaxable".to_string(),
@@ -121,6 +127,9 @@ mod tests {
                     to_date: Some("2024-01-31".to_string()),
                     limit: None,
                     offset: None,
+                    status_scope: EntryStatusScope::PostedOnly,
+                    flagged_only: false,
+                    dimensions: Default::default(),
                 };
 
                 let result = service.get_ledger(query).await.unwrap();
@@ -167,8 +176,10 @@ mod tests {
                             line_number: 1,
                             side: "Debit".to_string(),
                             account_code: account_code.clone(),
+                            account_name: None,
                             sub_account_code: None,
                             department_code: None,
+                            dimensions: Default::default(),
                             amount,
                             currency: "JPY".to_string(),
                             tax_type: "NonTaxable".to_string(),
@@ -179,8 +190,10 @@ mod tests {
                             line_number: 2,
                             side: "Credit".to_string(),
                             account_code: "9999".to_string(),
+                            account_name: None,
                             sub_account_code: None,
                             department_code: None,
+                            dimensions: Default::default(),
                             amount,
                             currency: "JPY".to_string(),
                             tax_type: "NonTaxable".to_string(),
@@ -204,7 +217,11 @@ mod tests {
 
                 // LedgerQueryServiceで取得
                 let service = LedgerQueryServiceImpl::new(event_store);
-                let query = GetTrialBalanceQuery { period_year, period_month };
+                let query = GetTrialBalanceQuery {
+                    period_year,
+                    period_month,
+                    status_scope: EntryStatusScope::PostedOnly,
+                };
 
                 let result = service.get_trial_balance(query).await.unwrap();
 
@@ -241,8 +258,10 @@ mod tests {
                         line_number: 1,
                         side: "Debit".to_string(),
                         account_code: account_code.clone(),
+                        account_name: None,
                         sub_account_code: None,
                         department_code: None,
+                        dimensions: Default::default(),
                         amount: 10000.0,
                         currency: "JPY".to_string(),
                         tax_type: "NonTaxable".to_string(),
@@ -253,8 +272,10 @@ mod tests {
                         line_number: 2,
                         side: "Credit".to_string(),
                         account_code: "9999".to_string(),
+                        account_name: None,
                         sub_account_code: None,
                         department_code: None,
+                        dimensions: Default::default(),
                         amount: 10000.0,
                         currency: "JPY".to_string(),
                         tax_type: "NonTaxable".to_string(),
@@ -280,6 +301,9 @@ mod tests {
                     to_date: None,
                     limit: None,
                     offset: None,
+                    status_scope: EntryStatusScope::PostedOnly,
+                    flagged_only: false,
+                    dimensions: Default::default(),
                 };
 
                 let result = service.get_ledger(query).await.unwrap();
@@ -325,8 +349,10 @@ mod tests {
                             line_number: 1,
                             side: "Debit".to_string(),
                             account_code: account_code.clone(),
+                            account_name: None,
                             sub_account_code: None,
                             department_code: None,
+                            dimensions: Default::default(),
                             amount: 10000.0,
                             currency: "JPY".to_string(),
                             tax_type: "NonTaxable".to_string(),
@@ -337,8 +363,10 @@ mod tests {
                             line_number: 2,
                             side: "Credit".to_string(),
                             account_code: "9999".to_string(),
+                            account_name: None,
                             sub_account_code: None,
                             department_code: None,
+                            dimensions: Default::default(),
                             amount: 10000.0,
                             currency: "JPY".to_string(),
                             tax_type: "NonTaxable".to_string(),
@@ -365,6 +393,9 @@ mod tests {
                     to_date: None,
                     limit: Some(limit as u32),
                     offset: Some(offset as u32),
+                    status_scope: EntryStatusScope::PostedOnly,
+                    flagged_only: false,
+                    dimensions: Default::default(),
                 };
 
                 let result = service.get_ledger(query).await.unwrap();
@@ -393,8 +424,10 @@ mod tests {
                 line_number: 1,
                 side: "Debit".to_string(),
                 account_code: account_code.clone(),
+                account_name: None,
                 sub_account_code: None,
                 department_code: None,
+                dimensions: Default::default(),
                 amount: 10000.0,
                 currency: "JPY".to_string(),
                 tax_type: "NonTaxable".to_string(),
@@ -405,8 +438,10 @@ mod tests {
                 line_number: 2,
                 side: "Credit".to_string(),
                 account_code: "9999".to_string(),
+                account_name: None,
                 sub_account_code: None,
                 department_code: None,
+                dimensions: Default::default(),
                 amount: 10000.0,
                 currency: "JPY".to_string(),
                 tax_type: "NonTaxable".to_string(),
@@ -431,6 +466,9 @@ mod tests {
             to_date: Some("2024-01-31".to_string()),
             limit: None,
             offset: None,
+            status_scope: EntryStatusScope::PostedOnly,
+            flagged_only: false,
+            dimensions: Default::default(),
         };
 
         let result = service.get_ledger(query).await.unwrap();
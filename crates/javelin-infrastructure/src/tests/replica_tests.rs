@@ -0,0 +1,193 @@
+//! Read-only replica tests
+//!
+//! 書き込みプロセス（テスト本体）がEventStore・ProjectionDbを開いたまま、
+//! テストバイナリ自身を子プロセス（レプリカ）として起動し、次を検証する。
+//! - レプリカから既存のイベントとProjectionを参照できること
+//! - レプリカからの書き込みが拒否されること
+//! - 書き込みプロセスの追記が反映位置の監視を通じてレプリカに見えること
+
+use std::{
+    io::{BufRead, BufReader, Write},
+    path::Path,
+    process::{Command, Stdio},
+    sync::Arc,
+};
+
+use serde::{Deserialize, Serialize};
+use tempfile::TempDir;
+use tokio::time::Duration;
+
+use crate::{
+    error::InfrastructureError,
+    event_store::EventStore,
+    projection_db::ProjectionDb,
+    replica_monitor::{ReplicaMonitor, wait_for_position},
+};
+
+/// 子プロセスの参照先ディレクトリを指定する環境変数
+const REPLICA_DIR_ENV: &str = "JAVELIN_REPLICA_TEST_DIR";
+
+const PROJECTION_NAME: &str = "main";
+const PROJECTION_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct TestEvent {
+    #[serde(rename = "type")]
+    event_type: String,
+    index: u64,
+}
+
+fn event(index: u64) -> TestEvent {
+    TestEvent { event_type: "DraftCreated".to_string(), index }
+}
+
+/// イベントを1件追記してProjectionへ反映
+async fn append_and_project(store: &EventStore, projection_db: &ProjectionDb, index: u64) {
+    let sequence = store.append(&format!("entry-{:03}", index), vec![event(index)]).await.unwrap();
+    projection_db
+        .update_projection_batch(
+            PROJECTION_NAME,
+            PROJECTION_VERSION,
+            vec![(format!("event:{:020}", sequence), sequence.to_be_bytes().to_vec())],
+            sequence,
+        )
+        .await
+        .unwrap();
+}
+
+/// 子プロセス（レプリカ）側の処理
+///
+/// 通常のテスト実行では環境変数がないため何もしない。
+#[tokio::test]
+async fn replica_child() {
+    let Ok(dir) = std::env::var(REPLICA_DIR_ENV) else {
+        return;
+    };
+    let dir = Path::new(&dir);
+    let store = Arc::new(EventStore::open_read_only(&dir.join("events")).await.unwrap());
+    let projection_db =
+        Arc::new(ProjectionDb::open_read_only(&dir.join("projections")).await.unwrap());
+    let mut stdout = std::io::stdout();
+
+    assert!(store.is_read_only());
+    assert!(projection_db.is_read_only());
+    let events = store.get_all_events(0).await.unwrap();
+    writeln!(stdout, "\nEVENTS {}", events.len()).unwrap();
+
+    let rejected = matches!(
+        store.append("entry-replica", vec![event(99)]).await,
+        Err(InfrastructureError::ReadOnlyReplica(_))
+    ) && matches!(
+        projection_db.update_projection("replica", b"x", 99).await,
+        Err(InfrastructureError::ReadOnlyReplica(_))
+    );
+    writeln!(stdout, "\nREJECTED {}", rejected).unwrap();
+
+    let mut status_rx = ReplicaMonitor::new(
+        Arc::clone(&store),
+        Arc::clone(&projection_db),
+        PROJECTION_NAME,
+        PROJECTION_VERSION,
+    )
+    .with_poll_interval(Duration::from_millis(20))
+    .spawn()
+    .await
+    .unwrap();
+    let position = status_rx.borrow().projection_position;
+    writeln!(stdout, "\nREADY {}", position).unwrap();
+    stdout.flush().unwrap();
+
+    let caught_up = wait_for_position(&mut status_rx, position + 1, Duration::from_secs(10)).await;
+    let events = store.get_all_events(0).await.unwrap();
+    writeln!(stdout, "\nCAUGHT_UP {} {}", caught_up, events.len()).unwrap();
+    stdout.flush().unwrap();
+}
+
+#[tokio::test]
+async fn test_replica_reads_writer_data_and_follows_projection_position() {
+    let temp_dir = TempDir::new().unwrap();
+    let store = EventStore::new(&temp_dir.path().join("events")).await.unwrap();
+    let projection_db = ProjectionDb::new(&temp_dir.path().join("projections")).await.unwrap();
+    for index in 0..3 {
+        append_and_project(&store, &projection_db, index).await;
+    }
+
+    let test_path = concat!(module_path!(), "::replica_child");
+    let (_, test_name) = test_path.split_once("::").unwrap();
+    let mut child = Command::new(std::env::current_exe().unwrap())
+        .args([test_name, "--exact", "--nocapture", "--test-threads=1"])
+        .env(REPLICA_DIR_ENV, temp_dir.path())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+
+    // レプリカの準備完了を待ってから追記する
+    let mut lines = BufReader::new(child.stdout.take().unwrap()).lines();
+    let mut reported = Vec::new();
+    for line in lines.by_ref() {
+        let line = line.unwrap();
+        let ready = line.starts_with("READY ");
+        reported.push(line);
+        if ready {
+            break;
+        }
+    }
+    append_and_project(&store, &projection_db, 3).await;
+
+    reported.extend(lines.map(|line| line.unwrap()));
+    assert!(child.wait().unwrap().success(), "{:?}", reported);
+
+    let has = |expected: &str| reported.iter().any(|line| line == expected);
+    assert!(has("EVENTS 3"), "{:?}", reported);
+    assert!(has("REJECTED true"), "{:?}", reported);
+    assert!(has("READY 3"), "{:?}", reported);
+    assert!(has("CAUGHT_UP true 4"), "{:?}", reported);
+}
+
+/// レプリカは破損レコードを隔離できないため、スキップしてメモリ上で件数を数えること
+#[tokio::test]
+async fn test_replica_skips_corrupted_records_without_writing() {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("events");
+    {
+        let store = EventStore::new(&path).await.unwrap();
+        store.append("entry-001", vec![event(0), event(1)]).await.unwrap();
+        store.append("entry-002", vec![event(2)]).await.unwrap();
+        store.put_raw_event(2, b"{not json".to_vec()).await.unwrap();
+
+        // チェックサムが一致しないレコード
+        let mut tampered = store.get_events("entry-002").await.unwrap().remove(0);
+        tampered.payload = br#"{"type":"Rejected","index":9}"#.to_vec();
+        store.put_raw_event(3, serde_json::to_vec(&tampered).unwrap()).await.unwrap();
+        // 同一プロセスで同じ環境を二重にオープンしない
+    }
+
+    let replica = EventStore::open_read_only(&path).await.unwrap();
+
+    let events = replica.get_all_events(0).await.unwrap();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].global_sequence, 1);
+    assert!(matches!(
+        replica.get_events("entry-002").await,
+        Err(InfrastructureError::ChecksumMismatch { global_sequence: 3, .. })
+    ));
+
+    // 再取得しても重複して数えない
+    replica.get_all_events(0).await.unwrap();
+    assert_eq!(replica.get_storage_metrics().await.unwrap().quarantined_events, 2);
+    assert!(replica.get_quarantined_events().await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_open_read_only_requires_existing_environment() {
+    let temp_dir = TempDir::new().unwrap();
+
+    assert!(matches!(
+        EventStore::open_read_only(&temp_dir.path().join("events")).await,
+        Err(InfrastructureError::ReplicaSourceNotFound { .. })
+    ));
+    assert!(matches!(
+        ProjectionDb::open_read_only(&temp_dir.path().join("projections")).await,
+        Err(InfrastructureError::ReplicaSourceNotFound { .. })
+    ));
+}
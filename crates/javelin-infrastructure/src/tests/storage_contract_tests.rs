@@ -0,0 +1,359 @@
+//! Storage backend contract tests
+//!
+//! StorageBackendの実装が満たすべき振る舞いを検証する。
+//! `check_*` はバックエンドに依存しない汎用関数として定義し、
+//! 新しいバックエンドを追加した場合は同じ関数を呼び出すテストを追加すること。
+//! バックエンドは `t1` / `t2` テーブルを持つ空の状態で渡す。
+
+use tempfile::TempDir;
+
+use crate::{
+    error::InfrastructureError,
+    storage::{KvRead, KvWrite, LmdbBackend, MemoryBackend, StorageBackend},
+    storage_metrics::DurabilityPolicy,
+};
+
+const TABLES: &[&str] = &["t1", "t2"];
+
+fn put_committed<B: StorageBackend>(backend: &B, table: &str, key: &[u8], value: &[u8]) {
+    let mut txn = backend.begin_write().unwrap();
+    txn.put(table, key, value).unwrap();
+    txn.commit().unwrap();
+}
+
+fn scan_keys<B: StorageBackend>(backend: &B, table: &str, from: Option<&[u8]>) -> Vec<Vec<u8>> {
+    let mut keys = Vec::new();
+    backend
+        .begin_read()
+        .unwrap()
+        .scan(table, from, &mut |key, _| {
+            keys.push(key.to_vec());
+            Ok(true)
+        })
+        .unwrap();
+    keys
+}
+
+// ========== 契約 ==========
+
+fn check_get_missing_key<B: StorageBackend>(backend: &B) {
+    let txn = backend.begin_read().unwrap();
+    assert_eq!(txn.get("t1", b"missing").unwrap(), None);
+}
+
+fn check_put_get_round_trip<B: StorageBackend>(backend: &B) {
+    put_committed(backend, "t1", b"key", b"value");
+    put_committed(backend, "t1", b"key", b"updated");
+
+    let txn = backend.begin_read().unwrap();
+    assert_eq!(txn.get("t1", b"key").unwrap(), Some(b"updated".to_vec()));
+}
+
+fn check_uncommitted_write_is_discarded<B: StorageBackend>(backend: &B) {
+    {
+        let mut txn = backend.begin_write().unwrap();
+        txn.put("t1", b"key", b"value").unwrap();
+        // 書き込み中のトランザクション自身からは見える
+        assert_eq!(txn.get("t1", b"key").unwrap(), Some(b"value".to_vec()));
+    }
+
+    let txn = backend.begin_read().unwrap();
+    assert_eq!(txn.get("t1", b"key").unwrap(), None);
+}
+
+fn check_scan_is_ordered_from_start_key<B: StorageBackend>(backend: &B) {
+    let mut txn = backend.begin_write().unwrap();
+    for seq in [3u64, 1, 256, 2] {
+        txn.put("t1", &seq.to_be_bytes(), b"event").unwrap();
+    }
+    txn.commit().unwrap();
+
+    let all: Vec<u64> = scan_keys(backend, "t1", None)
+        .iter()
+        .map(|key| u64::from_be_bytes(key.as_slice().try_into().unwrap()))
+        .collect();
+    assert_eq!(all, vec![1, 2, 3, 256]);
+
+    let from_three: Vec<u64> = scan_keys(backend, "t1", Some(&3u64.to_be_bytes()))
+        .iter()
+        .map(|key| u64::from_be_bytes(key.as_slice().try_into().unwrap()))
+        .collect();
+    assert_eq!(from_three, vec![3, 256]);
+
+    // 存在しないキーからの走査は次のキーから始まる
+    let from_four = scan_keys(backend, "t1", Some(&4u64.to_be_bytes()));
+    assert_eq!(from_four, vec![256u64.to_be_bytes().to_vec()]);
+
+    let past_end = scan_keys(backend, "t1", Some(&1000u64.to_be_bytes()));
+    assert!(past_end.is_empty());
+}
+
+fn check_scan_stops_when_visitor_returns_false<B: StorageBackend>(backend: &B) {
+    for key in [b"a", b"b", b"c"] {
+        put_committed(backend, "t1", key, b"value");
+    }
+
+    let mut visited = Vec::new();
+    backend
+        .begin_read()
+        .unwrap()
+        .scan("t1", None, &mut |key, _| {
+            visited.push(key.to_vec());
+            Ok(visited.len() < 2)
+        })
+        .unwrap();
+    assert_eq!(visited, vec![b"a".to_vec(), b"b".to_vec()]);
+}
+
+fn check_scan_propagates_visitor_error<B: StorageBackend>(backend: &B) {
+    put_committed(backend, "t1", b"a", b"value");
+
+    let result = backend.begin_read().unwrap().scan("t1", None, &mut |_, _| {
+        Err(InfrastructureError::DeserializationFailed("broken".to_string()))
+    });
+    assert!(matches!(result, Err(InfrastructureError::DeserializationFailed(_))));
+}
+
+fn check_scan_empty_table<B: StorageBackend>(backend: &B) {
+    assert!(scan_keys(backend, "t1", None).is_empty());
+    assert!(scan_keys(backend, "t1", Some(b"a")).is_empty());
+}
+
+fn check_put_if_absent_keeps_existing<B: StorageBackend>(backend: &B) {
+    let mut txn = backend.begin_write().unwrap();
+    assert!(txn.put_if_absent("t1", b"key", b"first").unwrap());
+    assert!(!txn.put_if_absent("t1", b"key", b"second").unwrap());
+    txn.commit().unwrap();
+
+    let txn = backend.begin_read().unwrap();
+    assert_eq!(txn.get("t1", b"key").unwrap(), Some(b"first".to_vec()));
+}
+
+fn check_delete_reports_existence<B: StorageBackend>(backend: &B) {
+    put_committed(backend, "t1", b"key", b"value");
+
+    let mut txn = backend.begin_write().unwrap();
+    assert!(txn.delete("t1", b"key").unwrap());
+    assert!(!txn.delete("t1", b"key").unwrap());
+    assert!(!txn.delete("t1", b"missing").unwrap());
+    txn.commit().unwrap();
+
+    let txn = backend.begin_read().unwrap();
+    assert_eq!(txn.get("t1", b"key").unwrap(), None);
+}
+
+fn check_entry_count<B: StorageBackend>(backend: &B) {
+    assert_eq!(backend.begin_read().unwrap().entry_count("t1").unwrap(), 0);
+
+    let mut txn = backend.begin_write().unwrap();
+    txn.put("t1", b"a", b"1").unwrap();
+    txn.put("t1", b"b", b"2").unwrap();
+    txn.put("t1", b"a", b"3").unwrap();
+    txn.commit().unwrap();
+
+    assert_eq!(backend.begin_read().unwrap().entry_count("t1").unwrap(), 2);
+}
+
+fn check_unknown_table_is_rejected<B: StorageBackend>(backend: &B) {
+    let txn = backend.begin_read().unwrap();
+    assert!(matches!(
+        txn.get("unknown", b"key"),
+        Err(InfrastructureError::StorageTableNotFound(name)) if name == "unknown"
+    ));
+    assert!(matches!(
+        txn.entry_count("unknown"),
+        Err(InfrastructureError::StorageTableNotFound(_))
+    ));
+    drop(txn);
+
+    let mut txn = backend.begin_write().unwrap();
+    assert!(matches!(
+        txn.put("unknown", b"key", b"value"),
+        Err(InfrastructureError::StorageTableNotFound(_))
+    ));
+}
+
+fn check_tables_are_isolated<B: StorageBackend>(backend: &B) {
+    put_committed(backend, "t1", b"key", b"one");
+    put_committed(backend, "t2", b"key", b"two");
+
+    let txn = backend.begin_read().unwrap();
+    assert_eq!(txn.get("t1", b"key").unwrap(), Some(b"one".to_vec()));
+    assert_eq!(txn.get("t2", b"key").unwrap(), Some(b"two".to_vec()));
+    assert_eq!(txn.entry_count("t2").unwrap(), 1);
+}
+
+fn check_read_sees_snapshot<B: StorageBackend>(backend: &B) {
+    put_committed(backend, "t1", b"key", b"before");
+
+    let snapshot = backend.begin_read().unwrap();
+    let mut writer = backend.begin_write().unwrap();
+    writer.put("t1", b"key", b"after").unwrap();
+    writer.put("t1", b"new", b"value").unwrap();
+    writer.commit().unwrap();
+
+    assert_eq!(snapshot.get("t1", b"key").unwrap(), Some(b"before".to_vec()));
+    assert_eq!(snapshot.get("t1", b"new").unwrap(), None);
+    drop(snapshot);
+
+    let latest = backend.begin_read().unwrap();
+    assert_eq!(latest.get("t1", b"key").unwrap(), Some(b"after".to_vec()));
+}
+
+fn check_stats_and_sync<B: StorageBackend>(backend: &B) {
+    put_committed(backend, "t1", b"key", b"value");
+    backend.sync().unwrap();
+
+    let stats = backend.stats().unwrap();
+    assert!(stats.map_size > 0);
+    assert!(stats.page_size > 0);
+    assert!(stats.used_size <= stats.map_size);
+    assert!(!backend.is_read_only());
+}
+
+fn check_copy_compacted_writes_destination<B: StorageBackend>(backend: &B) {
+    put_committed(backend, "t1", b"key", b"value");
+    let destination = TempDir::new().expect("Failed to create temp directory");
+
+    backend.copy_compacted(destination.path()).unwrap();
+
+    assert!(std::fs::read_dir(destination.path()).unwrap().next().is_some());
+    // 書き出し後も元のバックエンドは使用できる
+    put_committed(backend, "t1", b"after", b"value");
+    assert_eq!(backend.begin_read().unwrap().entry_count("t1").unwrap(), 2);
+}
+
+// ========== LMDB ==========
+
+fn lmdb_backend() -> (TempDir, LmdbBackend) {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let backend =
+        LmdbBackend::open(temp_dir.path(), TABLES, 10 * 1024 * 1024, DurabilityPolicy::default())
+            .unwrap();
+    (temp_dir, backend)
+}
+
+macro_rules! lmdb_contract {
+    ($($name:ident),* $(,)?) => {
+        $(
+            #[test]
+            fn $name() {
+                let (_temp_dir, backend) = lmdb_backend();
+                super::$name(&backend);
+            }
+        )*
+    };
+}
+
+mod lmdb_backend_tests {
+    use super::lmdb_backend;
+
+    lmdb_contract!(
+        check_get_missing_key,
+        check_put_get_round_trip,
+        check_uncommitted_write_is_discarded,
+        check_scan_is_ordered_from_start_key,
+        check_scan_stops_when_visitor_returns_false,
+        check_scan_propagates_visitor_error,
+        check_scan_empty_table,
+        check_put_if_absent_keeps_existing,
+        check_delete_reports_existence,
+        check_entry_count,
+        check_unknown_table_is_rejected,
+        check_tables_are_isolated,
+        check_read_sees_snapshot,
+        check_stats_and_sync,
+        check_copy_compacted_writes_destination,
+    );
+}
+
+// ========== メモリ ==========
+
+macro_rules! memory_contract {
+    ($($name:ident),* $(,)?) => {
+        $(
+            #[test]
+            fn $name() {
+                super::$name(&MemoryBackend::new(TABLES));
+            }
+        )*
+    };
+}
+
+mod memory_backend_tests {
+    use super::{MemoryBackend, TABLES};
+
+    memory_contract!(
+        check_get_missing_key,
+        check_put_get_round_trip,
+        check_uncommitted_write_is_discarded,
+        check_scan_is_ordered_from_start_key,
+        check_scan_stops_when_visitor_returns_false,
+        check_scan_propagates_visitor_error,
+        check_scan_empty_table,
+        check_put_if_absent_keeps_existing,
+        check_delete_reports_existence,
+        check_entry_count,
+        check_unknown_table_is_rejected,
+        check_tables_are_isolated,
+        check_read_sees_snapshot,
+        check_stats_and_sync,
+        check_copy_compacted_writes_destination,
+    );
+}
+
+#[test]
+fn test_lmdb_read_only_rejects_write() {
+    let (temp_dir, backend) = lmdb_backend();
+    put_committed(&backend, "t1", b"key", b"value");
+    // 同一プロセスで同じ環境を二重にオープンしない
+    drop(backend);
+
+    let replica = LmdbBackend::open_read_only(temp_dir.path(), TABLES).unwrap();
+    assert!(replica.is_read_only());
+    assert_eq!(
+        replica.begin_read().unwrap().get("t1", b"key").unwrap(),
+        Some(b"value".to_vec())
+    );
+    assert!(matches!(replica.begin_write(), Err(InfrastructureError::ReadOnlyReplica(_))));
+}
+
+#[test]
+fn test_lmdb_compacted_copy_drops_free_pages() {
+    let (temp_dir, backend) = lmdb_backend();
+    let value = vec![0u8; 1024];
+    for seq in 0u64..2000 {
+        put_committed(&backend, "t1", &seq.to_be_bytes(), &value);
+    }
+    let mut txn = backend.begin_write().unwrap();
+    for seq in 0u64..1990 {
+        txn.delete("t1", &seq.to_be_bytes()).unwrap();
+    }
+    txn.commit().unwrap();
+    backend.sync().unwrap();
+
+    let destination = temp_dir.path().join("compact");
+    std::fs::create_dir_all(&destination).unwrap();
+    backend.copy_compacted(&destination).unwrap();
+
+    let original = std::fs::metadata(temp_dir.path().join("data.mdb")).unwrap().len();
+    let compacted = std::fs::metadata(destination.join("data.mdb")).unwrap().len();
+    assert!(
+        compacted < original / 10,
+        "{} should be much smaller than {}",
+        compacted,
+        original
+    );
+
+    let copy = LmdbBackend::open_read_only(&destination, TABLES).unwrap();
+    assert_eq!(copy.begin_read().unwrap().entry_count("t1").unwrap(), 10);
+}
+
+#[test]
+fn test_lmdb_read_only_requires_existing_data() {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    assert!(matches!(
+        LmdbBackend::open_read_only(temp_dir.path(), TABLES),
+        Err(InfrastructureError::ReplicaSourceNotFound { .. })
+    ));
+}
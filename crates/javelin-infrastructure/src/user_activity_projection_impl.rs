@@ -0,0 +1,183 @@
+// UserActivityProjection具象実装 - Infrastructure層
+// 操作記録を記録日・画面・操作ごとに集計し、Projection DBに保存する
+
+use std::sync::Arc;
+
+use chrono::{DateTime, NaiveDate, TimeDelta, Utc};
+use javelin_application::{
+    error::{ApplicationError, ApplicationResult},
+    user_activity::{DailyUserActivity, UserActivityProjection},
+};
+use javelin_domain::{
+    masters::UserAction,
+    time_provider::{SystemTimeProvider, TimeProvider},
+};
+use serde::{Deserialize, Serialize};
+use tokio::sync::Mutex;
+
+use crate::projection_db::ProjectionDb;
+
+/// Projection DBに保存する集計の名前
+const ACTIVITY_NAME: &str = "user_activity";
+
+/// 既定の保持日数
+pub const DEFAULT_ACTIVITY_RETENTION_DAYS: u32 = 90;
+
+/// 日次集計（日付・種類・画面・操作順）
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+struct DailyActivities {
+    days: Vec<DailyUserActivity>,
+}
+
+impl DailyActivities {
+    fn record(&mut self, date: NaiveDate, action: &UserAction) {
+        let key = (date, action.kind().as_str(), action.screen(), action.action());
+        let position = self.days.binary_search_by(|daily| {
+            (daily.date, daily.kind.as_str(), daily.screen.as_str(), daily.action.as_str())
+                .cmp(&key)
+        });
+        let daily = match position {
+            Ok(index) => &mut self.days[index],
+            Err(index) => {
+                self.days.insert(index, DailyUserActivity::new(date, action));
+                &mut self.days[index]
+            }
+        };
+        daily.add(action);
+    }
+
+    /// 指定日より前の集計を破棄
+    fn prune_before(&mut self, date: NaiveDate) {
+        self.days.retain(|daily| daily.date >= date);
+    }
+}
+
+/// UserActivityProjection具象実装
+///
+/// 業務指標と同様にProjection DBのメタ情報として保存するため、Projectionの
+/// 再構築・圧縮による置き換えでも失われない。
+pub struct UserActivityProjectionImpl {
+    projection_db: Arc<ProjectionDb>,
+    retention_days: u32,
+    /// 記録日時を業務日付に変換する基準
+    time_provider: Arc<dyn TimeProvider>,
+    /// 読み込み・追加・保存を直列化する
+    lock: Mutex<()>,
+}
+
+impl UserActivityProjectionImpl {
+    /// 新しいUserActivityProjectionImplを作成
+    pub fn new(projection_db: Arc<ProjectionDb>) -> Self {
+        Self::with_retention_days(projection_db, DEFAULT_ACTIVITY_RETENTION_DAYS)
+    }
+
+    /// 保持日数を指定して作成
+    pub fn with_retention_days(projection_db: Arc<ProjectionDb>, retention_days: u32) -> Self {
+        Self {
+            projection_db,
+            retention_days,
+            time_provider: Arc::new(SystemTimeProvider::local()),
+            lock: Mutex::new(()),
+        }
+    }
+
+    /// 業務日付の基準を設定（既定はシステム時計とローカルタイムゾーン）
+    pub fn with_time_provider(mut self, time_provider: Arc<dyn TimeProvider>) -> Self {
+        self.time_provider = time_provider;
+        self
+    }
+
+    async fn load(&self) -> ApplicationResult<DailyActivities> {
+        let stored = self
+            .projection_db
+            .get_telemetry(ACTIVITY_NAME)
+            .await
+            .map_err(projection_error)?;
+        match stored {
+            Some(bytes) => serde_json::from_slice(&bytes)
+                .map_err(|e| ApplicationError::ProjectionDatabaseError(e.to_string())),
+            None => Ok(DailyActivities::default()),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl UserActivityProjection for UserActivityProjectionImpl {
+    async fn apply(&self, action: &UserAction, at: DateTime<Utc>) -> ApplicationResult<()> {
+        let _guard = self.lock.lock().await;
+        let mut activities = self.load().await?;
+        let date = self.time_provider.business_date_of(at);
+        activities.record(date, action);
+        activities.prune_before(date - TimeDelta::days(i64::from(self.retention_days)));
+
+        let bytes = serde_json::to_vec(&activities)
+            .map_err(|e| ApplicationError::ProjectionDatabaseError(e.to_string()))?;
+        self.projection_db
+            .put_telemetry(ACTIVITY_NAME, bytes)
+            .await
+            .map_err(projection_error)
+    }
+
+    async fn daily_activity(&self, from: NaiveDate) -> ApplicationResult<Vec<DailyUserActivity>> {
+        let activities = self.load().await?;
+        Ok(activities.days.into_iter().filter(|daily| daily.date >= from).collect())
+    }
+}
+
+fn projection_error(e: crate::error::InfrastructureError) -> ApplicationError {
+    ApplicationError::ProjectionDatabaseError(e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::FixedOffset;
+    use javelin_domain::{masters::UserActionKind, time_provider::FixedTimeProvider};
+    use tempfile::TempDir;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_aggregates_per_screen_and_action_and_prunes_old_days() {
+        let temp_dir = TempDir::new().unwrap();
+        let projection_db = Arc::new(ProjectionDb::new(temp_dir.path()).await.unwrap());
+        let activity =
+            UserActivityProjectionImpl::with_retention_days(projection_db, 7).with_time_provider(
+                Arc::new(FixedTimeProvider::new(Utc::now(), FixedOffset::east_opt(0).unwrap())),
+            );
+
+        let day = |d: u32| {
+            NaiveDate::from_ymd_opt(2024, 4, d)
+                .unwrap()
+                .and_hms_opt(9, 0, 0)
+                .unwrap()
+                .and_utc()
+        };
+        let visit = |user: &str, ms| UserAction::screen_visit(user, "TrialBalance", ms).unwrap();
+        let export = UserAction::new(
+            "alice",
+            UserActionKind::ActionPerformed,
+            "TrialBalance",
+            "export",
+            700,
+        )
+        .unwrap();
+
+        activity.apply(&visit("alice", 1_000), day(1)).await.unwrap();
+        activity.apply(&visit("alice", 2_000), day(2)).await.unwrap();
+        activity.apply(&visit("bob", 4_000), day(2)).await.unwrap();
+        activity.apply(&export, day(2)).await.unwrap();
+
+        let daily = activity.daily_activity(day(2).date_naive()).await.unwrap();
+        assert_eq!(daily.len(), 2);
+        let visits = daily.iter().find(|d| d.is_kind(UserActionKind::ScreenVisited)).unwrap();
+        assert_eq!((visits.count, visits.users.len()), (2, 2));
+        assert_eq!(visits.average_duration_ms(), Some(3_000));
+        assert_eq!(visits.max_duration_ms, 4_000);
+
+        // 保持日数を過ぎた日の集計は破棄される
+        activity.apply(&visit("alice", 1), day(9)).await.unwrap();
+        let daily = activity.daily_activity(day(1).date_naive()).await.unwrap();
+        assert!(daily.iter().all(|d| d.date >= day(2).date_naive()));
+        assert_eq!(daily.len(), 3);
+    }
+}
@@ -1,17 +1,17 @@
 // Application - アプリケーション本体
 // 責務: ナビゲーションループの実行
 
-use std::sync::Arc;
+use std::{sync::Arc, time::Instant};
 
 use javelin_adapter::{
-    HomePageState, NavigationStack, PresenterRegistry, navigation::Controllers,
-    views::terminal_manager::TerminalManager,
+    HomePageState, LoginPageState, NavigationStack, PresenterRegistry, SetupWizardPageState,
+    error_log::record_error, navigation::Controllers, views::terminal_manager::TerminalManager,
 };
 use javelin_infrastructure::{
     event_store::EventStore, projection_builder_impl::ProjectionBuilderImpl,
-    projection_db::ProjectionDb, queries::MasterDataLoaderImpl,
+    projection_db::ProjectionDb, queries::MasterDataLoaderImpl, replica_monitor::ReplicaStatus,
 };
-use tokio::sync::mpsc;
+use tokio::sync::{mpsc, watch};
 
 use crate::{app_error::AppResult, app_resolver::PageStateResolver};
 
@@ -33,6 +33,10 @@ pub struct Application {
     _event_receiver: mpsc::UnboundedReceiver<javelin_application::output_port::EventNotification>,
     // インフラエラー通知用
     infra_error_receiver: mpsc::UnboundedReceiver<String>,
+    // レプリカの反映状況（参照専用モードのみ）
+    replica_status: Option<watch::Receiver<ReplicaStatus>>,
+    // 初回起動時はログインの代わりにセットアップウィザードを表示する
+    setup_required: bool,
 }
 
 impl Application {
@@ -52,6 +56,8 @@ impl Application {
             javelin_application::output_port::EventNotification,
         >,
         infra_error_receiver: mpsc::UnboundedReceiver<String>,
+        replica_status: Option<watch::Receiver<ReplicaStatus>>,
+        setup_required: bool,
     ) -> Self {
         let controllers_arc = Arc::new(controllers);
         let resolver =
@@ -71,20 +77,21 @@ impl Application {
             _event_sender: event_sender,
             _event_receiver: event_receiver,
             infra_error_receiver,
+            replica_status,
+            setup_required,
         }
     }
 
     /// アプリケーションを実行
     pub fn run(mut self) -> AppResult<()> {
-        println!("\n◆ アプリケーション起動 ◆");
-        println!("  Navigation: Stack-based architecture");
-        println!("  Controllers: 準備完了");
-        println!("  PresenterRegistry: 準備完了");
-        println!("\n✓ すべてのコンポーネントが正常に初期化されました");
-        println!("  メインメニューを起動します...\n");
-
-        // Push home page as initial screen
+        // Push home page as initial screen, with the login screen (or the setup wizard
+        // on first run) on top
         self.nav_stack.push(Box::new(HomePageState::new()));
+        if self.setup_required {
+            self.nav_stack.push(Box::new(SetupWizardPageState::new()));
+        } else {
+            self.nav_stack.push(Box::new(LoginPageState::new()));
+        }
 
         // Main navigation loop
         loop {
@@ -95,40 +102,92 @@ impl Application {
                 }
             }
 
+            // レプリカの反映位置が進んだことを通知（各画面は表示時に最新データを読む）
+            if let Some(status_rx) = self.replica_status.as_mut()
+                && status_rx.has_changed().unwrap_or(false)
+            {
+                let status = *status_rx.borrow_and_update();
+                if let Some(page) = self.nav_stack.current() {
+                    page.on_notice(&format!(
+                        "レプリカ更新: 反映位置 {} / 最新イベント {}",
+                        status.projection_position, status.latest_sequence
+                    ));
+                }
+            }
+
             // Get current page
             let current_page = match self.nav_stack.current() {
                 Some(page) => page,
                 None => break, // Exit when stack is empty
             };
 
+            // 画面の表示時間を利用状況として記録する（ログイン画面・未ログイン時は除く）
+            let route = current_page.route();
+            let visitor = (route != javelin_adapter::Route::Login
+                && route != javelin_adapter::Route::SetupWizard
+                && self.controllers.session.is_active())
+            .then(|| self.controllers.session.user_id());
+            let started = Instant::now();
+
             // Run page event loop
             let nav_action =
                 match current_page.run(self.terminal_manager.terminal_mut(), &self.controllers) {
                     Ok(action) => action,
                     Err(e) => {
-                        let error_message = format!("Page error: {}", e);
-                        current_page.on_navigation_error(&error_message);
+                        let report = e.report();
+                        record_error(&report);
+                        // 描画・入力の障害で表示できない場合も、ログには問い合わせID付きで残る
+                        let _ = self.terminal_manager.show_error_report(report.clone());
+                        current_page.on_navigation_error(&report.summary());
                         javelin_adapter::NavAction::Back
                     }
                 };
 
+            if let Some(user_id) = visitor {
+                let controller = Arc::clone(&self.controllers.record_user_action);
+                let elapsed = started.elapsed();
+                tokio::spawn(async move {
+                    let _ = controller
+                        .record_screen_visit(user_id, format!("{:?}", route), elapsed)
+                        .await;
+                });
+            }
+
             // Handle navigation action
             match nav_action {
                 javelin_adapter::NavAction::Go(route) => {
+                    // ログイン・ロック時は前の利用者の画面状態を残さない
+                    if route == javelin_adapter::Route::Login {
+                        self.nav_stack.clear_preserved();
+                    }
+                    // 戻る操作で保持した画面があれば、条件・選択・スクロール位置ごと復元する
+                    if let Some(page) = self.nav_stack.take_preserved(&route) {
+                        self.nav_stack.push(page);
+                        continue;
+                    }
                     match self.resolver.resolve(route.clone()) {
                         Ok(new_page) => {
                             self.nav_stack.push(new_page);
                         }
                         Err(e) => {
-                            let error_message = format!("Navigation error: {:?} - {}", route, e);
+                            let report = e.report();
+                            record_error(&report);
                             if let Some(page) = self.nav_stack.current() {
-                                page.on_navigation_error(&error_message);
+                                page.on_navigation_error(&format!(
+                                    "{:?}: {}",
+                                    route,
+                                    report.summary()
+                                ));
                             }
                         }
                     }
                 }
                 javelin_adapter::NavAction::Back => {
-                    self.nav_stack.pop();
+                    self.nav_stack.back();
+                    // ログイン画面・ロック画面を認証せずに閉じた場合は終了する
+                    if !self.controllers.session.is_active() {
+                        break;
+                    }
                 }
                 javelin_adapter::NavAction::None => {
                     // Continue on current page
@@ -136,6 +195,16 @@ impl Application {
             }
         }
 
+        // ログイン中のセッションはログアウトとして記録する
+        if let Some(session_id) = self.controllers.session.session_id() {
+            let controller = Arc::clone(&self.controllers.session_log);
+            let _ = tokio::task::block_in_place(|| {
+                tokio::runtime::Handle::current()
+                    .block_on(async move { controller.record_logout(session_id).await })
+            });
+            self.controllers.session.end();
+        }
+
         println!("\n◆ アプリケーション終了 ◆");
         println!("  すべてのコンポーネントを正常にシャットダウンしました");
 
@@ -0,0 +1,69 @@
+// Application Audit Export - 監査用帳簿の出力
+// 責務: `javelin audit-export` サブコマンドの実行
+//
+// 指定年度の仕訳帳・総勘定元帳と索引ファイルを出力する（電子帳簿保存法向け）。
+// イベントストアは読み取り専用で開くため、アプリケーションの実行中でも出力できる。
+
+use std::{path::PathBuf, sync::Arc};
+
+use javelin_adapter::controller::AuditExportController;
+use javelin_infrastructure::{
+    EventStore,
+    queries::{AuditExportQueryServiceImpl, MasterDataLoaderImpl},
+};
+
+use crate::app_error::{AppError, AppResult};
+
+/// 索引ファイルに記録する既定の出力者
+pub const DEFAULT_EXPORTED_BY: &str = "system";
+
+/// 監査用帳簿出力コマンド
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuditExportCommand {
+    pub data_dir: PathBuf,
+    /// 対象年度（取引日付の暦年）
+    pub fiscal_year: u32,
+    /// 出力先（この配下に audit_export_{年度}/ を作成する）
+    pub output_dir: PathBuf,
+    /// 出力者のユーザID
+    pub exported_by: String,
+}
+
+impl AuditExportCommand {
+    pub async fn execute(self) -> AppResult<()> {
+        let events_dir = self.data_dir.join("events");
+        if !events_dir.is_dir() {
+            return Err(AppError::ExportFailed(format!(
+                "{} にイベントストアがありません",
+                self.data_dir.display()
+            )));
+        }
+
+        let event_store = Arc::new(EventStore::open_read_only(&events_dir).await?);
+        let master_data_loader = Arc::new(
+            MasterDataLoaderImpl::new(&self.data_dir.join("master_data"))
+                .await
+                .map_err(AppError::InitializationFailed)?,
+        );
+        let controller = AuditExportController::new(Arc::new(AuditExportQueryServiceImpl::new(
+            event_store,
+            master_data_loader,
+        )));
+
+        let (directory, response) = controller
+            .export(self.fiscal_year, self.exported_by, &self.output_dir)
+            .await
+            .map_err(AppError::ExportFailed)?;
+
+        println!("✓ Audit export completed: {}", directory.display());
+        println!("  - Fiscal year: {}", response.fiscal_year);
+        println!("  - Journal entries: {}", response.entry_count);
+        for file in response.files() {
+            println!(
+                "  - {} ({} records, sha256 {})",
+                file.file_name, file.record_count, file.digest
+            );
+        }
+        Ok(())
+    }
+}
@@ -1,23 +1,63 @@
 // ApplicationBuilder - アプリケーションのビルド
 // 責務: 各セットアップモジュールを呼び出してApplicationを構築
 
-use std::path::PathBuf;
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use chrono::FixedOffset;
+use javelin_adapter::{
+    controller::DEFAULT_REQUEST_TIMEOUT,
+    startup_report::{StartupReport, init_startup_report},
+};
+use javelin_domain::{
+    repositories::ApplicationSettingsRepository,
+    time_provider::{SystemTimeProvider, TimeProvider},
+};
 
 use crate::{
     app::Application,
     app_error::AppResult,
-    app_setup::{setup_controllers, setup_infrastructure},
+    app_setup::{
+        LaunchMode, ProjectionRepair, measure_store_sizes, schedule_integrity_audit,
+        schedule_period_rollover, schedule_projection_compaction, schedule_storage_sampling,
+        setup_controllers, setup_infrastructure, start_job_queue,
+    },
 };
 
+/// ストレージ使用状況の既定の記録間隔
+pub const DEFAULT_STORAGE_SAMPLE_INTERVAL: Duration = Duration::from_secs(10 * 60);
+
 /// アプリケーションビルダー
 pub struct ApplicationBuilder {
     data_dir: Option<PathBuf>,
+    launch_mode: LaunchMode,
+    /// Projection圧縮の定期実行間隔（None の場合は定期実行しない）
+    compaction_interval: Option<Duration>,
+    /// ストレージ使用状況の記録間隔
+    storage_sample_interval: Duration,
+    /// 業務日付・表示のタイムゾーン（None の場合は実行環境のローカルタイムゾーン）
+    time_zone: Option<FixedOffset>,
+    /// 反映位置がイベントストアより先行していた場合の扱い
+    projection_repair: ProjectionRepair,
+    /// 画面からの照会のタイムアウト
+    request_timeout: Duration,
 }
 
 impl ApplicationBuilder {
     /// 新規ビルダーを作成
     pub fn new() -> Self {
-        Self { data_dir: None }
+        Self {
+            data_dir: None,
+            launch_mode: LaunchMode::default(),
+            compaction_interval: None,
+            storage_sample_interval: DEFAULT_STORAGE_SAMPLE_INTERVAL,
+            time_zone: None,
+            projection_repair: ProjectionRepair::default(),
+            request_timeout: DEFAULT_REQUEST_TIMEOUT,
+        }
     }
 
     /// データディレクトリを設定
@@ -26,27 +66,128 @@ impl ApplicationBuilder {
         self
     }
 
+    /// 起動モードを設定
+    pub fn with_launch_mode(mut self, mode: LaunchMode) -> Self {
+        self.launch_mode = mode;
+        self
+    }
+
+    /// Projection圧縮の定期実行間隔を設定
+    pub fn with_compaction_interval(mut self, interval: Duration) -> Self {
+        self.compaction_interval = Some(interval);
+        self
+    }
+
+    /// ストレージ使用状況の記録間隔を設定
+    pub fn with_storage_sample_interval(mut self, interval: Duration) -> Self {
+        self.storage_sample_interval = interval;
+        self
+    }
+
+    /// 業務日付・表示のタイムゾーンを設定
+    pub fn with_time_zone(mut self, time_zone: FixedOffset) -> Self {
+        self.time_zone = Some(time_zone);
+        self
+    }
+
+    /// 反映位置がイベントストアより先行していた場合の扱いを設定
+    pub fn with_projection_repair(mut self, repair: ProjectionRepair) -> Self {
+        self.projection_repair = repair;
+        self
+    }
+
+    /// 画面からの照会のタイムアウトを設定
+    pub fn with_request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = timeout;
+        self
+    }
+
     /// アプリケーションをビルド
+    ///
+    /// 起動時の情報は標準出力ではなく起動診断レポートに記録し、
+    /// システム情報画面とログディレクトリの `startup_report.txt` で確認する。
     pub async fn build(self) -> AppResult<Application> {
+        let build_started = Instant::now();
+
         // データディレクトリの決定
-        let data_dir = self.data_dir.unwrap_or_else(|| {
-            let mut path = std::env::current_dir().expect("Failed to get current directory");
-            path.push("data");
-            path
-        });
+        let data_dir = self.data_dir.unwrap_or_else(default_data_dir);
+        let mut report =
+            StartupReport::new(env!("CARGO_PKG_VERSION"), &data_dir, self.launch_mode.label());
 
-        println!("✓ Data directory: {}", data_dir.display());
+        // エラーログ（問い合わせIDで画面表示と突き合わせる）
+        javelin_adapter::error_log::init_error_log(data_dir.join("logs").join("error.log"));
+
+        // 時刻の基準（イベントの記録と画面表示で共有）
+        let time_provider: Arc<dyn TimeProvider> = Arc::new(
+            self.time_zone
+                .map(SystemTimeProvider::new)
+                .unwrap_or_else(SystemTimeProvider::local),
+        );
+        javelin_adapter::clock::init_clock(time_provider.clone());
+        report.time_zone = format!("UTC{}", time_provider.time_zone());
+        report.started_at = javelin_adapter::clock::now().format("%Y-%m-%d %H:%M:%S").to_string();
 
         // インフラ層のセットアップ
-        let infra = setup_infrastructure(&data_dir).await?;
+        let infra = setup_infrastructure(
+            &data_dir,
+            self.launch_mode,
+            Arc::clone(&time_provider),
+            self.projection_repair,
+            &mut report,
+        )
+        .await?;
+
+        // 会計カレンダー（条件入力の範囲チェックに使用）
+        if let Ok(Some(settings)) = infra.master_data_loader.settings_repository().find().await {
+            javelin_adapter::clock::set_fiscal_year_start_month(
+                settings.fiscal_year_start_month().value(),
+            );
+        }
 
         // コントローラのセットアップ
+        let started = Instant::now();
         let controller_components = setup_controllers(
             &data_dir,
+            Arc::clone(&time_provider),
+            self.request_timeout,
             infra.event_store.clone(),
+            infra.projection_db.clone(),
+            infra.projection_builder.clone(),
             infra.master_data_loader.clone(),
+            infra.projection_events.clone(),
         )
         .await?;
+        report.record_step("コントローラ・画面", started, Vec::new());
+
+        // ジョブキュー（参照専用モードでは書き込み側のプロセスに任せる）
+        if infra.replica_status.is_none() {
+            start_job_queue(&controller_components.controllers, &mut report).await?;
+            if let Some(interval) = self.compaction_interval {
+                schedule_projection_compaction(
+                    &controller_components.controllers,
+                    interval,
+                    &mut report,
+                );
+            }
+            schedule_storage_sampling(
+                &controller_components.controllers,
+                self.storage_sample_interval,
+                &mut report,
+            );
+            schedule_integrity_audit(&controller_components.controllers, &mut report);
+        }
+
+        // 月初の繰越残高（元帳Projectionに保持するため参照専用モードでも確定する）
+        schedule_period_rollover(&controller_components.controllers, time_provider, &mut report);
+
+        // 利用者が未登録の場合は初期セットアップ（参照専用モードでは書き込み側に任せる）
+        let setup_required = infra.replica_status.is_none()
+            && controller_components.controllers.initial_setup.is_required().await;
+
+        report.store_sizes = measure_store_sizes(&data_dir);
+        report.total_duration = build_started.elapsed();
+        save_startup_report(&data_dir, &report);
+        init_startup_report(report);
 
         // TerminalManagerの作成
         let terminal_manager = javelin_adapter::views::terminal_manager::TerminalManager::new()
@@ -65,6 +206,8 @@ impl ApplicationBuilder {
             controller_components.event_sender,
             controller_components.event_receiver,
             infra.infra_error_receiver,
+            infra.replica_status,
+            setup_required,
         ))
     }
 }
@@ -74,3 +217,18 @@ impl Default for ApplicationBuilder {
         Self::new()
     }
 }
+
+/// 起動診断レポートをログディレクトリへ保存（保存の失敗は起動を妨げない）
+fn save_startup_report(data_dir: &Path, report: &StartupReport) {
+    let logs_dir = data_dir.join("logs");
+    if std::fs::create_dir_all(&logs_dir).is_ok() {
+        let _ = std::fs::write(logs_dir.join("startup_report.txt"), report.to_lines().join("\n"));
+    }
+}
+
+/// 既定のデータディレクトリ（カレントディレクトリ直下の data）
+pub fn default_data_dir() -> PathBuf {
+    let mut path = std::env::current_dir().expect("Failed to get current directory");
+    path.push("data");
+    path
+}
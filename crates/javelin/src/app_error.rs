@@ -1,6 +1,7 @@
 // Application-wide Error
 // Top-level error type for the entire application
 
+use javelin_application::{error::ErrorCategory, error_report::ErrorReport};
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -18,6 +19,25 @@ pub enum AppError {
     #[error("[APP-1003] Feature not implemented: {0}")]
     NotImplemented(String),
 
+    #[error("[APP-1004] Invalid command line argument: {0}")]
+    InvalidArgument(String),
+
+    #[error("[APP-1005] Data maintenance failed: {0}")]
+    MaintenanceFailed(String),
+
+    #[error("[APP-1006] Audit export failed: {0}")]
+    ExportFailed(String),
+
+    #[error(
+        "[APP-1007] Projection position {position} is ahead of the event store (latest {latest_sequence})"
+    )]
+    ProjectionDiverged { position: u64, latest_sequence: u64 },
+
+    #[error(
+        "[APP-1008] Projection {projection} position {position} regressed below {high_water_mark}"
+    )]
+    ProjectionRegressed { projection: String, position: u64, high_water_mark: u64 },
+
     #[error("[APP-2001] Adapter error: {0}")]
     AdapterError(#[from] javelin_adapter::error::AdapterError),
 
@@ -32,3 +52,54 @@ pub enum AppError {
 }
 
 pub type AppResult<T> = Result<T, AppError>;
+
+impl AppError {
+    /// 構造化エラーに変換（下位層のエラーは下位層の分類・コードを引き継ぐ）
+    pub fn report(&self) -> ErrorReport {
+        let (category, code, message) = match self {
+            Self::AdapterError(error) => return error.report(),
+            Self::ApplicationError(error) => return ErrorReport::from(error),
+            Self::NotImplemented(feature) => (
+                ErrorCategory::NotFound,
+                "APP-1003",
+                format!("この機能は未実装です: {}", feature),
+            ),
+            Self::InvalidArgument(message) => {
+                (ErrorCategory::Validation, "APP-1004", message.clone())
+            }
+            Self::InitializationFailed(_) => {
+                (ErrorCategory::System, "APP-1001", "初期化に失敗しました".to_string())
+            }
+            Self::DataDirectoryCreationFailed { path, .. } => (
+                ErrorCategory::Storage,
+                "APP-1002",
+                format!("データディレクトリを作成できません: {}", path),
+            ),
+            Self::MaintenanceFailed(message) => {
+                (ErrorCategory::Storage, "APP-1005", message.clone())
+            }
+            Self::ExportFailed(message) => (ErrorCategory::Storage, "APP-1006", message.clone()),
+            Self::ProjectionDiverged { position, latest_sequence } => (
+                ErrorCategory::Storage,
+                "APP-1007",
+                format!(
+                    "Projection反映位置（{}）がイベントストア（{}）より先行しているため起動を中止しました。--repair-projections を指定して再構築してください",
+                    position, latest_sequence
+                ),
+            ),
+            Self::ProjectionRegressed { projection, position, high_water_mark } => (
+                ErrorCategory::Storage,
+                "APP-1008",
+                format!(
+                    "Projection {} の反映位置（{}）が過去の反映位置（{}）より後退しているため起動を中止しました。--repair-projections を指定して再構築してください",
+                    projection, position, high_water_mark
+                ),
+            ),
+            Self::InfrastructureError(_) => {
+                (ErrorCategory::Storage, "APP-2003", "保存先の処理に失敗しました".to_string())
+            }
+            Self::Unknown(message) => (ErrorCategory::System, "APP-9999", message.clone()),
+        };
+        ErrorReport::from_error(category, code, message, self)
+    }
+}
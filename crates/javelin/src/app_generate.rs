@@ -0,0 +1,454 @@
+// Application Generate - 負荷試験用の大量仕訳生成
+// 責務: `javelin generate` サブコマンドの実行
+//
+// 勘定科目マスタの有効な科目を使い、貸借一致した仕訳を部門・会計期間に散らして
+// イベントストアへ直接投入する（画面・Interactorを経由しない）。
+// Projection・照会の性能確認に十分な件数を用意するための開発者向けコマンド。
+// 乱数の種を指定すると同じ内容を再現できる。Projectionは次回起動時に再構築される。
+// 耐久性ポリシーと並行して追記する数を指定すると、ポリシーごとの追記の待ち時間と
+// スループットを比較できる（グループコミットの待ち時間の調整に使う）。
+
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::Instant,
+};
+
+use chrono::{Datelike, Days, Months, NaiveDate};
+use javelin_domain::{
+    financial_close::{
+        AccountCode,
+        journal_entry::{
+            entities::{JournalEntry, JournalEntryId, journal_entry_line::JournalEntryLineBuilder},
+            events::JournalEntryEvent,
+            services::EntryNumberGenerator,
+            values::{
+                Amount, Currency, DebitCredit, DepartmentCode, Description, EntryNumberScope,
+                LineNumber, TransactionDate, UserId, VoucherNumber,
+            },
+        },
+    },
+    masters::{AccountMaster, AccountType},
+    repositories::AccountMasterRepository,
+};
+use javelin_infrastructure::{
+    AccountMasterRepositoryImpl, EventStore, services::EntryNumberGeneratorImpl,
+    storage_metrics::DurabilityPolicy,
+};
+
+use crate::app_error::{AppError, AppResult};
+
+/// 生成仕訳の起票者・承認者
+const GENERATOR_USER: &str = "generator";
+
+/// 生成仕訳を記帳する会社（既定の会社）
+const GENERATOR_COMPANY_CODE: &str = "0001";
+
+/// 既定の生成件数
+pub const DEFAULT_GENERATE_ENTRIES: u64 = 10_000;
+
+/// 既定の会計期間数（当月を含む過去の月数）
+pub const DEFAULT_GENERATE_PERIODS: u32 = 12;
+
+/// 既定の乱数の種
+pub const DEFAULT_GENERATE_SEED: u64 = 20_240_401;
+
+/// 既定の耐久性ポリシー（コミット毎のfsyncを省略し、最後にまとめて同期する）
+pub const DEFAULT_GENERATE_DURABILITY: DurabilityPolicy = DurabilityPolicy::MaxPerformance;
+
+/// 仕訳を割り振る部門
+const DEPARTMENTS: &[&str] = &["D100", "D200", "D300", "D400", "D500"];
+
+/// 承認待ちのまま残す割合（1/N）
+const PENDING_RATIO: u64 = 20;
+
+/// 進捗を表示する間隔（件数）
+const PROGRESS_INTERVAL: u64 = 10_000;
+
+/// 生成直後のイベントストアのマップサイズ（以降の起動時は既存サイズから拡張される）
+const INITIAL_MAP_SIZE: usize = 1024 * 1024 * 1024;
+
+/// 仕訳の雛形（借方科目タイプ, 貸方科目タイプ, 摘要, 金額の下限, 上限）
+const TEMPLATES: &[(AccountType, AccountType, &str, i64, i64)] = &[
+    (AccountType::Asset, AccountType::Revenue, "売上計上", 50_000, 5_000_000),
+    (AccountType::Expense, AccountType::Asset, "経費支払", 1_000, 500_000),
+    (AccountType::Expense, AccountType::Liability, "費用計上", 10_000, 1_000_000),
+    (AccountType::Asset, AccountType::Liability, "仕入計上", 50_000, 3_000_000),
+    (AccountType::Liability, AccountType::Asset, "債務支払", 50_000, 3_000_000),
+    (AccountType::Asset, AccountType::Asset, "資金移動", 100_000, 10_000_000),
+];
+
+/// 大量仕訳生成コマンド
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GenerateCommand {
+    pub data_dir: PathBuf,
+    /// 生成する仕訳数
+    pub entries: u64,
+    /// 仕訳を散らす会計期間数（当月を含む過去の月数）
+    pub periods: u32,
+    /// 乱数の種
+    pub seed: u64,
+    /// イベントストアの耐久性ポリシー
+    pub durability: DurabilityPolicy,
+    /// 並行して追記する数
+    pub writers: usize,
+}
+
+impl GenerateCommand {
+    pub async fn execute(self) -> AppResult<()> {
+        if !self.data_dir.exists() {
+            tokio::fs::create_dir_all(&self.data_dir).await.map_err(|e| {
+                AppError::DataDirectoryCreationFailed {
+                    path: self.data_dir.display().to_string(),
+                    source: e,
+                }
+            })?;
+        }
+
+        let accounts = AccountPool::load(&self.data_dir.join("master_data")).await?;
+        let months = target_months(chrono::Local::now().date_naive(), self.periods)?;
+
+        // fsyncを省略するポリシーでは最後にまとめて同期する
+        let event_store = Arc::new(
+            EventStore::new_with_config(
+                &self.data_dir.join("events"),
+                INITIAL_MAP_SIZE,
+                self.durability,
+            )
+            .await?,
+        );
+        // 既存の仕訳と集約IDが重ならないよう、実行ごとに接頭辞を変える
+        let run_id = event_store.get_latest_sequence().await?.as_u64() + 1;
+        let entry_numbers = EntryNumberGeneratorImpl::new(Arc::clone(&event_store));
+        let mut rng = GeneratorRng::new(self.seed);
+
+        println!(
+            "Generating {} journal entries into {} ...",
+            self.entries,
+            self.data_dir.display()
+        );
+        let started = Instant::now();
+        let mut pending = 0;
+        let mut index = 0;
+        while index < self.entries {
+            // 仕訳の内容と採番は順に決め、追記だけを並行させる
+            let mut appends = tokio::task::JoinSet::new();
+            for _ in 0..(self.entries - index).min(self.writers as u64) {
+                index += 1;
+                let entry = generate_entry(&mut rng, &accounts, &months);
+                if !entry.approve {
+                    pending += 1;
+                }
+                let voucher_number = format!("GEN-{}-{:07}", run_id, index);
+                let (entry_id, events) =
+                    build_entry(&entry_numbers, &voucher_number, entry).await?;
+                let event_store = Arc::clone(&event_store);
+                appends.spawn(async move { event_store.append(&entry_id, events).await });
+
+                if index % PROGRESS_INTERVAL == 0 {
+                    println!("  - {} / {} entries", index, self.entries);
+                }
+            }
+            while let Some(appended) = appends.join_next().await {
+                appended.map_err(generate_error)??;
+            }
+        }
+        event_store.sync().await?;
+
+        let elapsed = started.elapsed();
+        println!("✓ Journal entries generated");
+        println!(
+            "  - Periods: {:04}-{:02} .. {:04}-{:02}",
+            months[0].year(),
+            months[0].month(),
+            months[months.len() - 1].year(),
+            months[months.len() - 1].month()
+        );
+        println!("  - Posted: {}", self.entries - pending);
+        println!("  - Pending approval: {}", pending);
+        println!(
+            "  - Elapsed: {:.1}s ({:.0} entries/s)",
+            elapsed.as_secs_f64(),
+            self.entries as f64 / elapsed.as_secs_f64().max(f64::EPSILON)
+        );
+        let metrics = event_store.append_metrics();
+        println!("  - Durability: {} ({} writers)", metrics.policy, self.writers);
+        println!(
+            "  - Append latency: avg {:.2}ms / max {:.2}ms",
+            metrics.average_latency_ms(),
+            metrics.max_latency.as_secs_f64() * 1000.0
+        );
+        if let Some(appends_per_sync) = metrics.appends_per_sync() {
+            println!("  - fsync: {} ({:.1} appends/fsync)", metrics.syncs, appends_per_sync);
+        }
+        println!("✓ Projections will be rebuilt on next launch");
+
+        Ok(())
+    }
+}
+
+/// 勘定科目タイプ別の有効な勘定科目コード
+struct AccountPool {
+    accounts: Vec<(AccountType, String)>,
+}
+
+impl AccountPool {
+    /// 勘定科目マスタを読込（空の場合は既定の勘定科目が登録される）
+    async fn load(master_db_path: &Path) -> AppResult<Self> {
+        let repository = AccountMasterRepositoryImpl::new(&master_db_path.join("accounts"))
+            .await
+            .map_err(AppError::InitializationFailed)?;
+        let accounts = repository.find_all().await.map_err(generate_error)?;
+        Self::from_masters(&accounts)
+    }
+
+    fn from_masters(masters: &[AccountMaster]) -> AppResult<Self> {
+        let accounts: Vec<(AccountType, String)> = masters
+            .iter()
+            .filter(|account| account.is_active())
+            .map(|account| (account.account_type(), account.code().value().to_string()))
+            .collect();
+
+        for (debit, credit, description, _, _) in TEMPLATES {
+            if !accounts.iter().any(|(t, _)| t == debit)
+                || !accounts.iter().any(|(t, _)| t == credit)
+            {
+                return Err(AppError::MaintenanceFailed(format!(
+                    "{} に使用する勘定科目（{:?} / {:?}）が勘定科目マスタにありません",
+                    description, debit, credit
+                )));
+            }
+        }
+        Ok(Self { accounts })
+    }
+
+    /// 指定タイプの勘定科目を1つ選ぶ（exceptと異なる科目があればそれを優先）
+    fn pick(&self, rng: &mut GeneratorRng, account_type: AccountType, except: &str) -> String {
+        let candidates: Vec<&String> = self
+            .accounts
+            .iter()
+            .filter(|(t, code)| *t == account_type && code != except)
+            .map(|(_, code)| code)
+            .collect();
+        if candidates.is_empty() {
+            return except.to_string();
+        }
+        candidates[rng.below(candidates.len() as u64) as usize].clone()
+    }
+}
+
+/// 生成した仕訳の内容
+struct GeneratedEntry {
+    date: NaiveDate,
+    description: &'static str,
+    /// (貸借, 勘定科目, 部門, 金額)
+    lines: Vec<(DebitCredit, String, &'static str, i64)>,
+    /// 記帳まで行うか（falseなら承認待ちのまま残す）
+    approve: bool,
+}
+
+/// 仕訳を1件生成
+///
+/// 借方を1〜3行に部門別で按分し、貸方1行と金額を一致させる。
+fn generate_entry(
+    rng: &mut GeneratorRng,
+    accounts: &AccountPool,
+    months: &[NaiveDate],
+) -> GeneratedEntry {
+    let (debit_type, credit_type, description, min, max) =
+        TEMPLATES[rng.below(TEMPLATES.len() as u64) as usize];
+
+    let month = months[rng.below(months.len() as u64) as usize];
+    let date = month + Days::new(rng.below(days_in_month(month) as u64));
+
+    // 金額は100円単位
+    let total = (min + rng.below((max - min) as u64 + 1) as i64) / 100 * 100;
+    let debit_lines = (1 + rng.below(3) as usize).min((total / 100) as usize).max(1);
+
+    let credit_account = accounts.pick(rng, credit_type, "");
+    let debit_account = accounts.pick(rng, debit_type, &credit_account);
+
+    let mut lines = Vec::with_capacity(debit_lines + 1);
+    let mut remaining = total;
+    for index in 0..debit_lines {
+        let amount = if index + 1 == debit_lines {
+            remaining
+        } else {
+            let share = (remaining / 100 / (debit_lines - index) as i64).max(1) * 100;
+            share.min(remaining - 100 * (debit_lines - index - 1) as i64)
+        };
+        remaining -= amount;
+        let department = DEPARTMENTS[rng.below(DEPARTMENTS.len() as u64) as usize];
+        lines.push((DebitCredit::Debit, debit_account.clone(), department, amount));
+    }
+    let department = DEPARTMENTS[rng.below(DEPARTMENTS.len() as u64) as usize];
+    lines.push((DebitCredit::Credit, credit_account, department, total));
+
+    GeneratedEntry { date, description, lines, approve: rng.below(PENDING_RATIO) != 0 }
+}
+
+/// 仕訳を起票・承認申請し、必要なら記帳まで行って追記するイベントを返す
+async fn build_entry(
+    entry_numbers: &EntryNumberGeneratorImpl,
+    voucher_number: &str,
+    entry: GeneratedEntry,
+) -> AppResult<(String, Vec<JournalEntryEvent>)> {
+    let lines = entry
+        .lines
+        .iter()
+        .enumerate()
+        .map(|(index, (side, account, department, amount))| {
+            JournalEntryLineBuilder::new(
+                LineNumber::new(index as u32 + 1)?,
+                side.clone(),
+                AccountCode::new(account.clone())?,
+                Amount::new(*amount as f64, Currency::JPY)?,
+            )
+            .department_code(DepartmentCode::new(department.to_string())?)
+            .description(Description::new(entry.description.to_string())?)
+            .build()
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(generate_error)?;
+
+    let user_id = UserId::new(GENERATOR_USER.to_string());
+    let entry_id = voucher_number.to_lowercase();
+    let mut journal_entry = JournalEntry::new(
+        JournalEntryId::new(entry_id.clone()),
+        TransactionDate::new(entry.date).map_err(generate_error)?,
+        VoucherNumber::new(voucher_number.to_string()).map_err(generate_error)?,
+        lines,
+        user_id.clone(),
+    )
+    .map_err(generate_error)?;
+
+    journal_entry.submit_for_approval(user_id.clone()).map_err(generate_error)?;
+    if entry.approve {
+        let scope = EntryNumberScope::new(GENERATOR_COMPANY_CODE, entry.date.year() as u32)
+            .map_err(generate_error)?;
+        let entry_number = entry_numbers.next(&scope).await.map_err(generate_error)?;
+        journal_entry.approve(entry_number, user_id).map_err(generate_error)?;
+    }
+
+    Ok((entry_id, journal_entry.drain_events()))
+}
+
+/// 当月を含む過去periodsヶ月の月初日（古い順）
+fn target_months(today: NaiveDate, periods: u32) -> AppResult<Vec<NaiveDate>> {
+    let current = today.with_day(1).unwrap_or(today);
+    (0..periods)
+        .rev()
+        .map(|offset| {
+            current.checked_sub_months(Months::new(offset)).ok_or_else(|| {
+                AppError::MaintenanceFailed(format!("invalid period offset: {}", offset))
+            })
+        })
+        .collect()
+}
+
+fn days_in_month(first_day: NaiveDate) -> u32 {
+    first_day
+        .checked_add_months(Months::new(1))
+        .and_then(|next| next.pred_opt())
+        .map(|last| last.day())
+        .unwrap_or(28)
+}
+
+/// 再現可能な擬似乱数（xorshift64*）
+///
+/// 負荷試験データの再現にのみ使用する（暗号用途には使わない）。
+struct GeneratorRng(u64);
+
+impl GeneratorRng {
+    fn new(seed: u64) -> Self {
+        // 状態が0だと以降も0のままになるため避ける
+        Self(seed ^ 0x9E37_79B9_7F4A_7C15)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    /// 0以上bound未満の値
+    fn below(&mut self, bound: u64) -> u64 {
+        self.next_u64() % bound.max(1)
+    }
+}
+
+fn generate_error(error: impl std::fmt::Display) -> AppError {
+    AppError::MaintenanceFailed(error.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use javelin_domain::masters::{AccountCode as MasterAccountCode, AccountName};
+
+    use super::*;
+
+    fn pool() -> AccountPool {
+        let accounts = [
+            ("1000", AccountType::Asset),
+            ("1100", AccountType::Asset),
+            ("2000", AccountType::Liability),
+            ("4000", AccountType::Revenue),
+            ("5000", AccountType::Expense),
+        ]
+        .map(|(code, account_type)| {
+            AccountMaster::new(
+                MasterAccountCode::new(code).unwrap(),
+                AccountName::new(code).unwrap(),
+                account_type,
+                true,
+            )
+        });
+        AccountPool::from_masters(&accounts).unwrap()
+    }
+
+    #[test]
+    fn test_generated_entries_balance_within_target_months() {
+        let accounts = pool();
+        let months = target_months(NaiveDate::from_ymd_opt(2024, 3, 15).unwrap(), 3).unwrap();
+        assert_eq!(months[0], NaiveDate::from_ymd_opt(2024, 1, 1).unwrap());
+        let mut rng = GeneratorRng::new(DEFAULT_GENERATE_SEED);
+
+        for _ in 0..1_000 {
+            let entry = generate_entry(&mut rng, &accounts, &months);
+            let total = |side: DebitCredit| -> i64 {
+                entry.lines.iter().filter(|line| line.0 == side).map(|line| line.3).sum()
+            };
+            assert_eq!(total(DebitCredit::Debit), total(DebitCredit::Credit));
+            assert!(entry.lines.iter().all(|line| line.3 > 0));
+            assert!(entry.date >= months[0]);
+            assert!(entry.date <= NaiveDate::from_ymd_opt(2024, 3, 31).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_same_seed_generates_same_entries() {
+        let accounts = pool();
+        let months = target_months(NaiveDate::from_ymd_opt(2024, 3, 15).unwrap(), 12).unwrap();
+        let mut first = GeneratorRng::new(7);
+        let mut second = GeneratorRng::new(7);
+
+        for _ in 0..100 {
+            let a = generate_entry(&mut first, &accounts, &months);
+            let b = generate_entry(&mut second, &accounts, &months);
+            assert_eq!(a.date, b.date);
+            assert_eq!(a.lines, b.lines);
+        }
+    }
+
+    #[test]
+    fn test_missing_account_type_is_rejected() {
+        let accounts = [AccountMaster::new(
+            MasterAccountCode::new("1000").unwrap(),
+            AccountName::new("現金").unwrap(),
+            AccountType::Asset,
+            true,
+        )];
+        assert!(AccountPool::from_masters(&accounts).is_err());
+    }
+}
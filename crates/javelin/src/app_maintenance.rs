@@ -0,0 +1,367 @@
+// Application Maintenance - デモ・研修環境向けのデータ投入と初期化
+// 責務: `javelin seed` / `javelin reset` サブコマンドの実行
+//
+// seed  : 空のデータディレクトリへ勘定科目・会社と複数期間の仕訳を投入する
+// reset : イベントストア・Projection等のトランザクションデータを削除する
+//
+// いずれもアプリケーション本体を起動せずに実行する。Projectionは次回起動時に
+// イベントストアから再構築される。
+
+use std::{
+    io::{BufRead, Write},
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use chrono::{Datelike, Months, NaiveDate};
+use javelin_domain::{
+    financial_close::{
+        AccountCode,
+        journal_entry::{
+            entities::{JournalEntry, JournalEntryId, journal_entry_line::JournalEntryLineBuilder},
+            services::EntryNumberGenerator,
+            values::{
+                Amount, Currency, DebitCredit, Description, EntryNumberScope, LineNumber,
+                TransactionDate, UserId, VoucherNumber,
+            },
+        },
+    },
+    masters::{
+        AccountCode as MasterAccountCode, AccountMaster, AccountName, AccountType, CompanyCode,
+        CompanyMaster, CompanyName,
+    },
+    repositories::{AccountMasterRepository, CompanyMasterRepository},
+};
+use javelin_infrastructure::{
+    AccountMasterRepositoryImpl, CompanyMasterRepositoryImpl, EventStore,
+    services::EntryNumberGeneratorImpl,
+};
+
+use crate::app_error::{AppError, AppResult};
+
+/// デモデータの起票者・承認者
+const DEMO_USER: &str = "demo";
+
+/// デモデータを投入する月数（当月を含む）
+const DEMO_PERIODS: u32 = 3;
+
+/// `reset` で常に削除するトランザクションデータ（データディレクトリからの相対パス）
+const TRANSACTIONAL_STORES: &[&str] =
+    &["events", "projections", "master_data/inventory_worksheets"];
+
+/// `reset --keep-masters` を指定しない場合に追加で削除するマスタデータ
+const MASTER_STORE: &str = "master_data";
+
+/// デモ用に追加する勘定科目（既定の勘定科目に加えて投入）
+const DEMO_ACCOUNTS: &[(&str, &str, AccountType)] = &[
+    ("1200", "売掛金", AccountType::Asset),
+    ("1300", "商品", AccountType::Asset),
+    ("2100", "未払金", AccountType::Liability),
+    ("6100", "給料手当", AccountType::Expense),
+    ("6200", "地代家賃", AccountType::Expense),
+];
+
+/// デモ仕訳を記帳する会社（既定の会社）
+const DEMO_COMPANY_CODE: &str = "0001";
+
+/// デモ用に追加する会社
+const DEMO_COMPANIES: &[(&str, &str)] = &[("0003", "支社B")];
+
+/// デモ仕訳の雛形（日, 借方科目, 貸方科目, 金額, 摘要）
+const MONTHLY_ENTRIES: &[(u32, &str, &str, f64, &str)] = &[
+    (5, "1300", "2000", 1_800_000.0, "商品仕入"),
+    (10, "1200", "4000", 3_000_000.0, "商品売上"),
+    (10, "5000", "1300", 1_650_000.0, "売上原価振替"),
+    (20, "1100", "1200", 2_500_000.0, "売掛金回収"),
+    (25, "6100", "1100", 600_000.0, "給与支払"),
+    (27, "2000", "1100", 1_500_000.0, "買掛金支払"),
+    (28, "6200", "2100", 200_000.0, "事務所家賃"),
+];
+
+/// 保守サブコマンド
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MaintenanceCommand {
+    /// デモデータ投入
+    Seed { data_dir: PathBuf },
+    /// トランザクションデータ削除
+    Reset {
+        data_dir: PathBuf,
+        /// マスタデータを残す
+        keep_masters: bool,
+        /// 確認プロンプトを省略する
+        assume_yes: bool,
+    },
+}
+
+impl MaintenanceCommand {
+    pub async fn execute(self) -> AppResult<()> {
+        match self {
+            MaintenanceCommand::Seed { data_dir } => seed(&data_dir).await,
+            MaintenanceCommand::Reset { data_dir, keep_masters, assume_yes } => {
+                reset(&data_dir, keep_masters, assume_yes)
+            }
+        }
+    }
+}
+
+/// デモデータを投入
+///
+/// 既存の仕訳との混在を避けるため、イベントストアが空の場合のみ実行する。
+async fn seed(data_dir: &Path) -> AppResult<()> {
+    if !data_dir.exists() {
+        tokio::fs::create_dir_all(data_dir).await.map_err(|e| {
+            AppError::DataDirectoryCreationFailed {
+                path: data_dir.display().to_string(),
+                source: e,
+            }
+        })?;
+    }
+
+    let event_store = Arc::new(EventStore::new(&data_dir.join("events")).await?);
+    if event_store.get_latest_sequence().await?.as_u64() > 0 {
+        return Err(AppError::MaintenanceFailed(format!(
+            "{} には既に仕訳が存在します。先に javelin reset を実行してください",
+            data_dir.display()
+        )));
+    }
+
+    let master_db_path = data_dir.join(MASTER_STORE);
+    seed_masters(&master_db_path).await?;
+    println!("✓ Master data seeded");
+    println!("  - Accounts added: {}", DEMO_ACCOUNTS.len());
+    println!("  - Companies added: {}", DEMO_COMPANIES.len());
+
+    let today = chrono::Local::now().date_naive();
+    let first_month =
+        first_day_of_month(today)
+            .checked_sub_months(Months::new(DEMO_PERIODS - 1))
+            .ok_or_else(|| AppError::MaintenanceFailed("invalid demo period".to_string()))?;
+
+    // 期首の資本金払込
+    let entry_numbers = EntryNumberGeneratorImpl::new(Arc::clone(&event_store));
+    let mut posted = 0;
+    let mut pending = 0;
+    post_entry(
+        &event_store,
+        &entry_numbers,
+        "DEMO-OPEN",
+        first_month,
+        "1100",
+        "3000",
+        10_000_000.0,
+        "資本金払込",
+        true,
+    )
+    .await?;
+    posted += 1;
+
+    for offset in 0..DEMO_PERIODS {
+        let month = first_month
+            .checked_add_months(Months::new(offset))
+            .ok_or_else(|| AppError::MaintenanceFailed("invalid demo period".to_string()))?;
+        let is_current = offset == DEMO_PERIODS - 1;
+
+        for (index, (day, debit, credit, amount, description)) in MONTHLY_ENTRIES.iter().enumerate()
+        {
+            let date = month.with_day(*day).unwrap_or(month);
+            let voucher = format!("DEMO-{:04}{:02}-{:02}", month.year(), month.month(), index + 1);
+            // 当月の月末仕訳は承認待ちのまま残す
+            let approve = !(is_current && index + 1 == MONTHLY_ENTRIES.len());
+
+            post_entry(
+                &event_store,
+                &entry_numbers,
+                &voucher,
+                date,
+                debit,
+                credit,
+                *amount,
+                description,
+                approve,
+            )
+            .await?;
+            if approve {
+                posted += 1;
+            } else {
+                pending += 1;
+            }
+        }
+    }
+
+    println!("✓ Journal entries seeded");
+    println!(
+        "  - Periods: {:04}-{:02} .. {:04}-{:02}",
+        first_month.year(),
+        first_month.month(),
+        today.year(),
+        today.month()
+    );
+    println!("  - Posted: {}", posted);
+    println!("  - Pending approval: {}", pending);
+    println!("✓ Projections will be rebuilt on next launch");
+
+    Ok(())
+}
+
+/// デモ用の勘定科目・会社を登録（既定のマスタはリポジトリ初期化時に登録される）
+async fn seed_masters(master_db_path: &Path) -> AppResult<()> {
+    let account_repository = AccountMasterRepositoryImpl::new(&master_db_path.join("accounts"))
+        .await
+        .map_err(AppError::InitializationFailed)?;
+    for (code, name, account_type) in DEMO_ACCOUNTS {
+        let account = AccountMaster::new(
+            MasterAccountCode::new(*code).map_err(maintenance_error)?,
+            AccountName::new(*name).map_err(maintenance_error)?,
+            *account_type,
+            true,
+        );
+        account_repository.save(&account).await.map_err(maintenance_error)?;
+    }
+
+    let company_repository = CompanyMasterRepositoryImpl::new(&master_db_path.join("companies"))
+        .await
+        .map_err(AppError::InitializationFailed)?;
+    for (code, name) in DEMO_COMPANIES {
+        let company = CompanyMaster::new(
+            CompanyCode::new(*code).map_err(maintenance_error)?,
+            CompanyName::new(*name).map_err(maintenance_error)?,
+            true,
+        );
+        company_repository.save(&company).await.map_err(maintenance_error)?;
+    }
+
+    Ok(())
+}
+
+/// 2行仕訳を起票し、承認申請（approveがtrueなら記帳まで）を行う
+#[allow(clippy::too_many_arguments)]
+async fn post_entry(
+    event_store: &EventStore,
+    entry_numbers: &EntryNumberGeneratorImpl,
+    voucher_number: &str,
+    date: NaiveDate,
+    debit_account: &str,
+    credit_account: &str,
+    amount: f64,
+    description: &str,
+    approve: bool,
+) -> AppResult<()> {
+    let line = |line_number: u32, side: DebitCredit, account: &str| {
+        JournalEntryLineBuilder::new(
+            LineNumber::new(line_number)?,
+            side,
+            AccountCode::new(account.to_string())?,
+            Amount::new(amount, Currency::JPY)?,
+        )
+        .description(Description::new(description.to_string())?)
+        .build()
+    };
+    let lines = vec![
+        line(1, DebitCredit::Debit, debit_account).map_err(maintenance_error)?,
+        line(2, DebitCredit::Credit, credit_account).map_err(maintenance_error)?,
+    ];
+
+    let user_id = UserId::new(DEMO_USER.to_string());
+    let entry_id = voucher_number.to_lowercase();
+    let mut journal_entry = JournalEntry::new(
+        JournalEntryId::new(entry_id.clone()),
+        TransactionDate::new(date).map_err(maintenance_error)?,
+        VoucherNumber::new(voucher_number.to_string()).map_err(maintenance_error)?,
+        lines,
+        user_id.clone(),
+    )
+    .map_err(maintenance_error)?;
+
+    journal_entry.submit_for_approval(user_id.clone()).map_err(maintenance_error)?;
+    if approve {
+        let scope = EntryNumberScope::new(DEMO_COMPANY_CODE, date.year() as u32)
+            .map_err(maintenance_error)?;
+        let entry_number = entry_numbers.next(&scope).await.map_err(maintenance_error)?;
+        journal_entry.approve(entry_number, user_id).map_err(maintenance_error)?;
+    }
+
+    event_store.append(&entry_id, journal_entry.drain_events()).await?;
+    Ok(())
+}
+
+/// トランザクションデータを削除
+///
+/// 削除対象は既知のサブディレクトリに限定し、それ以外のファイルには触れない。
+/// 実行中のアプリケーションがある場合は先に終了しておくこと。
+fn reset(data_dir: &Path, keep_masters: bool, assume_yes: bool) -> AppResult<()> {
+    let looks_like_data_dir =
+        data_dir.join("events").is_dir() || data_dir.join(MASTER_STORE).is_dir();
+    if !looks_like_data_dir {
+        return Err(AppError::MaintenanceFailed(format!(
+            "{} はJavelinのデータディレクトリではありません",
+            data_dir.display()
+        )));
+    }
+
+    let targets = reset_targets(data_dir, keep_masters);
+    if targets.is_empty() {
+        println!("✓ Nothing to reset: {}", data_dir.display());
+        return Ok(());
+    }
+
+    println!("The following stores will be deleted:");
+    for target in &targets {
+        println!("  - {}", target.display());
+    }
+    if keep_masters {
+        println!("Master data is kept: {}", data_dir.join(MASTER_STORE).display());
+    }
+
+    if !assume_yes && !confirm("Type 'yes' to continue: ")? {
+        println!("✓ Reset cancelled");
+        return Ok(());
+    }
+
+    for target in &targets {
+        std::fs::remove_dir_all(target).map_err(|e| {
+            AppError::MaintenanceFailed(format!("failed to remove {}: {}", target.display(), e))
+        })?;
+    }
+
+    println!("✓ Reset completed ({} stores removed)", targets.len());
+    Ok(())
+}
+
+/// 削除対象のうち実在するディレクトリ
+fn reset_targets(data_dir: &Path, keep_masters: bool) -> Vec<PathBuf> {
+    let mut stores: Vec<&str> = TRANSACTIONAL_STORES.to_vec();
+    if !keep_masters {
+        // master_data配下のトランザクションデータもまとめて削除される
+        stores.retain(|store| !store.starts_with(MASTER_STORE));
+        stores.push(MASTER_STORE);
+    }
+
+    stores
+        .into_iter()
+        .map(|store| data_dir.join(store))
+        .filter(|path| path.is_dir())
+        .collect()
+}
+
+/// 標準入力で確認を取る
+pub(crate) fn confirm(prompt: &str) -> AppResult<bool> {
+    print!("{}", prompt);
+    std::io::stdout()
+        .flush()
+        .map_err(|e| AppError::MaintenanceFailed(e.to_string()))?;
+
+    let mut answer = String::new();
+    std::io::stdin()
+        .lock()
+        .read_line(&mut answer)
+        .map_err(|e| AppError::MaintenanceFailed(e.to_string()))?;
+    Ok(answer.trim() == "yes")
+}
+
+fn first_day_of_month(date: NaiveDate) -> NaiveDate {
+    date.with_day(1).unwrap_or(date)
+}
+
+fn maintenance_error(error: impl std::fmt::Display) -> AppError {
+    AppError::MaintenanceFailed(error.to_string())
+}
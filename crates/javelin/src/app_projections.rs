@@ -0,0 +1,102 @@
+// Application Projections - Projectionの反映位置の確認と修復
+// 責務: `javelin projections` サブコマンドの実行
+//
+// Projectionごとに反映位置・イベントストアの最新イベント番号・未反映件数・最終更新日時を
+// 表示する。--repair を指定すると、反映位置が先行または後退したProjectionがある場合に
+// Projectionを初期化してイベントストアから再構築する。
+// アプリケーションを停止した状態で実行すること。
+
+use std::{path::PathBuf, sync::Arc};
+
+use javelin_application::{
+    projection_builder::ProjectionBuilder,
+    query_service::{ProjectionPositionDiagnostics, ProjectionPositionQueryService},
+};
+use javelin_infrastructure::{
+    EventStore,
+    projection_builder_impl::ProjectionBuilderImpl,
+    projection_db::ProjectionDb,
+    queries::{MasterDataLoaderImpl, ProjectionPositionQueryServiceImpl},
+};
+
+use crate::{
+    app_error::{AppError, AppResult},
+    app_setup::divergence_warning,
+};
+
+/// 反映位置の確認コマンド
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProjectionsCommand {
+    pub data_dir: PathBuf,
+    /// 先行・後退したProjectionを初期化して再構築する
+    pub repair: bool,
+}
+
+impl ProjectionsCommand {
+    pub async fn execute(self) -> AppResult<()> {
+        if !self.data_dir.join("events").exists() {
+            return Err(AppError::MaintenanceFailed(format!(
+                "{} にイベントストアがありません",
+                self.data_dir.display()
+            )));
+        }
+
+        let event_store = Arc::new(EventStore::new(&self.data_dir.join("events")).await?);
+        let projection_db = Arc::new(ProjectionDb::new(&self.data_dir.join("projections")).await?);
+        let service = ProjectionPositionQueryServiceImpl::new(
+            Arc::clone(&event_store),
+            Arc::clone(&projection_db),
+        );
+
+        let diagnostics = service.get_projection_positions().await?;
+        print_diagnostics(&diagnostics);
+
+        let diverged: Vec<&ProjectionPositionDiagnostics> =
+            diagnostics.iter().filter(|item| item.status().needs_repair()).collect();
+        if diverged.is_empty() {
+            println!("✓ No diverged projections");
+            return Ok(());
+        }
+        for item in &diverged {
+            println!("⚠ {}", divergence_warning(item));
+        }
+        if !self.repair {
+            println!("  Run with --repair to reset and rebuild projections");
+            return Err(crate::app_setup::divergence_error(diverged[0]));
+        }
+
+        let master_data_loader = Arc::new(
+            MasterDataLoaderImpl::new(&self.data_dir.join("master_data"))
+                .await
+                .map_err(AppError::InitializationFailed)?,
+        );
+        let projection_builder =
+            ProjectionBuilderImpl::new(Arc::clone(&projection_db), Arc::clone(&event_store))
+                .with_master_data_loader(master_data_loader);
+
+        let removed = projection_db.reset().await?;
+        projection_builder.rebuild_all_projections().await?;
+        service.record_high_water_marks().await?;
+        println!("✓ Projections reset and rebuilt");
+        println!("  - Projection records removed: {}", removed);
+
+        print_diagnostics(&service.get_projection_positions().await?);
+        Ok(())
+    }
+}
+
+/// 反映位置の一覧を表示
+fn print_diagnostics(diagnostics: &[ProjectionPositionDiagnostics]) {
+    println!("Projection                 Position     Latest      Lag Status Updated at");
+    for item in diagnostics {
+        println!(
+            "{:<24} {:>10} {:>10} {:>8} {:<6} {}",
+            item.key(),
+            item.position,
+            item.latest_sequence,
+            item.lag(),
+            item.status().label(),
+            item.updated_at.as_deref().unwrap_or("-")
+        );
+    }
+}
@@ -27,11 +27,15 @@ impl PageStateResolver {
             Route::Search => {
                 Ok(Box::new(SearchPageState::new(Arc::clone(&self.presenter_registry))))
             }
+            Route::SupplierInvoice => {
+                Ok(Box::new(javelin_adapter::SupplierInvoicePageState::new()))
+            }
             Route::JournalEntry => Ok(Box::new(javelin_adapter::JournalEntryPageState::new(
                 Arc::clone(&self.presenter_registry),
             ))),
             Route::Ledger => Ok(Box::new(javelin_adapter::LedgerPageState::new())),
             Route::LedgerDetail => Ok(Box::new(javelin_adapter::LedgerDetailPageState::new())),
+            Route::ReportHub => Ok(Box::new(javelin_adapter::ReportHubPageState::new())),
             Route::LedgerConsolidation => {
                 Ok(Box::new(javelin_adapter::LedgerConsolidationPageState::new(&self.controllers)))
             }
@@ -44,7 +48,16 @@ impl PageStateResolver {
             Route::ClosingPreparationExecution => {
                 Ok(Box::new(javelin_adapter::ClosingPreparationExecutionPageState::new()))
             }
+            Route::BalanceAnalysis => {
+                Ok(Box::new(javelin_adapter::BalanceAnalysisPageState::new()))
+            }
+            Route::AccountActivity => {
+                Ok(Box::new(javelin_adapter::AccountActivityPageState::new()))
+            }
             Route::ClosingLock => Ok(Box::new(javelin_adapter::ClosingLockPageState::new())),
+            Route::AccountReconciliation => {
+                Ok(Box::new(javelin_adapter::AccountReconciliationPageState::new()))
+            }
             Route::TrialBalance => Ok(Box::new(javelin_adapter::TrialBalancePageState::new())),
             Route::AccountAdjustment => {
                 Ok(Box::new(javelin_adapter::AccountAdjustmentPageState::new(&self.controllers)))
@@ -59,12 +72,22 @@ impl PageStateResolver {
             Route::IfrsValuationExecution => {
                 Ok(Box::new(javelin_adapter::IfrsValuationExecutionPageState::new()))
             }
+            Route::InventoryWorksheet => {
+                Ok(Box::new(javelin_adapter::InventoryWorksheetPageState::new()))
+            }
             Route::FinancialStatement => {
                 Ok(Box::new(javelin_adapter::FinancialStatementPageState::new(&self.controllers)))
             }
             Route::FinancialStatementExecution => {
                 Ok(Box::new(javelin_adapter::FinancialStatementExecutionPageState::new()))
             }
+            Route::ReportArchive => Ok(Box::new(javelin_adapter::ReportArchivePageState::new())),
+            Route::NoteCrossReference => {
+                Ok(Box::new(javelin_adapter::NoteCrossReferencePageState::new()))
+            }
+            Route::ClosingTimetable => {
+                Ok(Box::new(javelin_adapter::ClosingTimetablePageState::new()))
+            }
             Route::AccountMaster => Ok(Box::new(javelin_adapter::AccountMasterPageState::new(
                 Arc::clone(&self.presenter_registry),
             ))),
@@ -78,6 +101,32 @@ impl PageStateResolver {
                     &self.presenter_registry,
                 ))))
             }
+            Route::StatementLineMapping => {
+                Ok(Box::new(javelin_adapter::StatementLineMappingPageState::new()))
+            }
+            Route::DataImport => Ok(Box::new(javelin_adapter::JournalImportPageState::new())),
+            Route::ManagementAccountMapping => {
+                Ok(Box::new(javelin_adapter::ManagementAccountMappingPageState::new()))
+            }
+            Route::Maintenance => Ok(Box::new(javelin_adapter::MaintenancePageState::new())),
+            Route::ProjectionConsole => {
+                Ok(Box::new(javelin_adapter::ProjectionConsolePageState::new()))
+            }
+            Route::JobQueue => Ok(Box::new(javelin_adapter::JobQueuePageState::new())),
+            Route::UserActivity => Ok(Box::new(javelin_adapter::UserActivityPageState::new())),
+            Route::SessionLog => Ok(Box::new(javelin_adapter::SessionLogPageState::new())),
+            Route::AccountingPolicy => {
+                Ok(Box::new(javelin_adapter::AccountingPolicyPageState::new()))
+            }
+            Route::DimensionMaster => {
+                Ok(Box::new(javelin_adapter::DimensionMasterPageState::new()))
+            }
+            Route::SystemInfo => Ok(Box::new(javelin_adapter::SystemInfoPageState::new())),
+            Route::Inbox => Ok(Box::new(javelin_adapter::InboxPageState::new())),
+            Route::InboxDetail => Ok(Box::new(javelin_adapter::InboxDetailPageState::new())),
+            Route::KpiDashboard => Ok(Box::new(javelin_adapter::KpiDashboardPageState::new())),
+            Route::Login => Ok(Box::new(javelin_adapter::LoginPageState::new())),
+            Route::SetupWizard => Ok(Box::new(javelin_adapter::SetupWizardPageState::new())),
             _ => Err(AppError::NotImplemented(format!("Route {:?} not yet implemented", route))),
         }
     }
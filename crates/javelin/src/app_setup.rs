@@ -1,40 +1,95 @@
 // AppSetup - インフラ層のセットアップ
 // 責務: リポジトリ、Interactor、コントローラの初期化
 
-use std::{path::Path, sync::Arc};
+use std::{
+    path::Path,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
+use chrono::Datelike;
 use javelin_adapter::{
-    PresenterRegistry,
+    BatchNotifier, PresenterRegistry,
     controller::{
-        AccountMasterController, ApplicationSettingsController, BatchHistoryController,
-        ClosingController, CompanyMasterController, JournalEntryController, LedgerController,
-        SearchController, SubsidiaryAccountMasterController,
+        AccountActivityController, AccountMasterController, AccountReconciliationController,
+        AccountingPolicyController, ApplicationSettingsController, AuditExportController,
+        AuthenticationController, BalanceAnalysisController, BatchHistoryController,
+        BusinessMetricsController, CalendarMasterController, ClosingController,
+        ClosingTimetableController, ClosingUseCases, CompanyMasterController,
+        ConsistencyCheckController, ControllerJobRunner, DimensionMasterController,
+        EntryLinkController, ExportProtectionController, FinancialInstrumentController,
+        InboxController, InitialSetupController, IntegrityAuditController,
+        InventoryWorksheetController, JobQueueController, JournalEntryController,
+        JournalImportController, LedgerAnnotationController, LedgerController,
+        ManagementAccountMappingController, NoteCrossReferenceController, PeriodReopenController,
+        ProjectionConsoleController, RecordUserActionController, ReportArchiveController,
+        ReportParameterHistoryController, SearchController, SequenceAuditController,
+        SessionLogController, StatementLineMappingController, StorageTelemetryController,
+        SubsidiaryAccountMasterController, SupplierInvoiceController, SuspenseClearingController,
+        TablePreferenceController, UserActivityController, VarianceCommentaryController,
     },
-    navigation::Controllers,
-    presenter::LedgerPresenter,
+    navigation::{Controllers, Session},
+    presenter::{LedgerPresenter, Presenter},
+    startup_report::{StartupReport, StoreSize},
+    use_case,
     views::pages::ClosingPage,
 };
 use javelin_application::{
+    business_metrics::BusinessMetrics,
+    input_ports::{
+        AdjustAccountsUseCase, ApplyIfrsValuationUseCase, ConsolidateLedgerUseCase,
+        GenerateFinancialStatementsUseCase, GenerateNoteDraftUseCase, GenerateTrialBalanceUseCase,
+        LockClosingPeriodUseCase, PrepareClosingUseCase,
+    },
     interactor::{
         AdjustAccountsInteractor, ApplyIfrsValuationInteractor, ConsolidateLedgerInteractor,
         GenerateFinancialStatementsInteractor, GenerateNoteDraftInteractor,
         GenerateTrialBalanceInteractor, LockClosingPeriodInteractor, PrepareClosingInteractor,
+        RecordUserActionInteractor,
     },
     projection_builder::ProjectionBuilder,
-    query_service::MasterDataLoaderService,
+    projection_events::ProjectionEventBus,
+    query_service::{
+        AccountMasterCache, MasterDataLoaderService, ProjectionPositionDiagnostics,
+        ProjectionPositionQueryService, ProjectionPositionStatus, SUSPENSE_ACCOUNT_CODES,
+    },
+    user_activity::UserActivityProjection,
 };
+use javelin_domain::{integrity_audit::INTEGRITY_AUDIT_INTERVAL_DAYS, time_provider::TimeProvider};
 use javelin_infrastructure::{
+    business_metrics_impl::BusinessMetricsImpl,
+    commands::UserActionRepositoryImpl,
     event_store::EventStore,
     ledger_query_service_impl::LedgerQueryServiceImpl,
     projection_builder_impl::ProjectionBuilderImpl,
+    projection_compactor_impl::ProjectionCompactorImpl,
     projection_db::ProjectionDb,
     queries::{
-        BatchHistoryQueryServiceImpl, JournalEntrySearchQueryServiceImpl, MasterDataLoaderImpl,
+        AuditExportQueryServiceImpl, BatchHistoryQueryServiceImpl, InboxQueryServiceImpl,
+        JournalEntrySearchQueryServiceImpl, MasterDataLoaderImpl, PeriodReopenQueryServiceImpl,
+        ProjectionConsistencyQueryServiceImpl, ProjectionInspectionQueryServiceImpl,
+        ProjectionPositionQueryServiceImpl, SequenceAuditQueryServiceImpl,
+        SuspenseAgingQueryServiceImpl,
+    },
+    replica_monitor::{ReplicaMonitor, ReplicaStatus},
+    repositories::{
+        AccountReconciliationRepositoryImpl, AccountingPolicyRepositoryImpl,
+        ClosingTimetableRepositoryImpl, DimensionMasterRepositoryImpl,
+        FinancialInstrumentRepositoryImpl, InventoryWorksheetRepositoryImpl, JobRepositoryImpl,
+        JournalImportTemplateRepositoryImpl, ManagementAccountMappingRepositoryImpl,
+        NoteCrossReferenceRepositoryImpl, ReportArchiveRepositoryImpl,
+        ReportParameterHistoryRepositoryImpl, StatementLineMappingRepositoryImpl,
+        SubsidiaryAccountMasterRepositoryImpl, SupplierInvoiceRepositoryImpl,
+        TablePreferenceRepositoryImpl, UserAccountRepositoryImpl, VarianceCommentaryRepositoryImpl,
+    },
+    services::{
+        EXPORT_SIGNING_KEY_FILE, EntryNumberGeneratorImpl, ExportProtectionImpl,
+        PasswordHasherImpl, ReportDeliveryImpl, ReportDigesterImpl, VoucherNumberGeneratorImpl,
     },
-    repositories::SubsidiaryAccountMasterRepositoryImpl,
-    services::VoucherNumberGeneratorImpl,
+    storage_telemetry_impl::StorageTelemetryImpl,
+    user_activity_projection_impl::UserActivityProjectionImpl,
 };
-use tokio::sync::mpsc;
+use tokio::sync::{mpsc, watch};
 
 use crate::app_error::{AppError, AppResult};
 
@@ -44,7 +99,11 @@ pub struct InfrastructureComponents {
     pub projection_db: Arc<ProjectionDb>,
     pub projection_builder: Arc<ProjectionBuilderImpl>,
     pub master_data_loader: Arc<MasterDataLoaderImpl>,
+    /// Projection更新通知（参照専用モードでは発行元がない）
+    pub projection_events: Arc<ProjectionEventBus>,
     pub infra_error_receiver: mpsc::UnboundedReceiver<String>,
+    /// レプリカの反映状況（参照専用モードのみ）
+    pub replica_status: Option<watch::Receiver<ReplicaStatus>>,
 }
 
 /// コントローラのセットアップ結果
@@ -57,8 +116,82 @@ pub struct ControllerComponents {
         mpsc::UnboundedReceiver<javelin_application::output_port::EventNotification>,
 }
 
+/// ディスク使用量を計測する保存先（データディレクトリ配下のディレクトリ名）
+const MEASURED_STORES: [&str; 4] = ["events", "projections", "master_data", "logs"];
+
+/// 保存先ごとのディスク使用量を計測（存在しない保存先は0）
+pub fn measure_store_sizes(data_dir: &Path) -> Vec<StoreSize> {
+    MEASURED_STORES
+        .iter()
+        .map(|name| StoreSize {
+            name: name.to_string(),
+            bytes: directory_size(&data_dir.join(name)),
+        })
+        .collect()
+}
+
+fn directory_size(path: &Path) -> u64 {
+    let Ok(entries) = std::fs::read_dir(path) else {
+        return 0;
+    };
+    entries
+        .flatten()
+        .map(|entry| match entry.metadata() {
+            Ok(metadata) if metadata.is_dir() => directory_size(&entry.path()),
+            Ok(metadata) => metadata.len(),
+            Err(_) => 0,
+        })
+        .sum()
+}
+
+/// 起動モード
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LaunchMode {
+    /// 通常モード（イベントの追記とProjectionの更新を行う）
+    #[default]
+    Writer,
+    /// 参照専用モード（書き込みプロセスのデータを読み取り専用で共有する）
+    Replica,
+}
+
+/// Projectionの反映位置がイベントストアより先行していた場合の扱い
+///
+/// 新しいProjectionのまま古いイベントのバックアップを復元した場合などに起こる。
+/// そのまま起動すると存在しない仕訳や残高を表示するため、再構築するまで起動しない。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ProjectionRepair {
+    /// 警告を表示し、再構築するか確認する（拒否した場合は起動を中止）
+    #[default]
+    Prompt,
+    /// 確認せずにProjectionを初期化して再構築する
+    Automatic,
+}
+
+impl LaunchMode {
+    /// 表示名
+    pub fn label(&self) -> &'static str {
+        match self {
+            LaunchMode::Writer => "通常",
+            LaunchMode::Replica => "参照専用",
+        }
+    }
+}
+
 /// インフラ層をセットアップ
-pub async fn setup_infrastructure(data_dir: &Path) -> AppResult<InfrastructureComponents> {
+///
+/// `time_provider` はイベントの記録時刻・業務日付の基準。
+/// `repair` は反映位置がイベントストアより先行していた場合の扱い。
+pub async fn setup_infrastructure(
+    data_dir: &Path,
+    mode: LaunchMode,
+    time_provider: Arc<dyn TimeProvider>,
+    repair: ProjectionRepair,
+    report: &mut StartupReport,
+) -> AppResult<InfrastructureComponents> {
+    if mode == LaunchMode::Replica {
+        return setup_replica_infrastructure(data_dir, report).await;
+    }
+
     // データディレクトリの作成
     if !data_dir.exists() {
         tokio::fs::create_dir_all(&data_dir).await.map_err(|e| {
@@ -70,81 +203,252 @@ pub async fn setup_infrastructure(data_dir: &Path) -> AppResult<InfrastructureCo
     }
 
     // Infrastructure層の構築
-    let event_store = Arc::new(EventStore::new(&data_dir.join("events")).await?);
+    let started = Instant::now();
+    let event_store = Arc::new(
+        EventStore::new(&data_dir.join("events"))
+            .await?
+            .with_time_provider(time_provider),
+    );
     let projection_db = Arc::new(ProjectionDb::new(&data_dir.join("projections")).await?);
 
     // インフラエラー通知チャネル
     let (infra_error_sender, infra_error_receiver) = mpsc::unbounded_channel();
 
-    // ProjectionBuilderの構築
-    let projection_builder =
-        Arc::new(ProjectionBuilderImpl::new(Arc::clone(&projection_db), Arc::clone(&event_store)));
+    // マスタデータローダー
+    let master_db_path = data_dir.join("master_data");
+    let master_data_loader = Arc::new(
+        MasterDataLoaderImpl::new(&master_db_path)
+            .await
+            .map_err(AppError::InitializationFailed)?,
+    );
+
+    // ProjectionBuilderの構築（反映完了を表示中の画面へ通知する）
+    // 勘定科目名を持たないイベントは勘定科目マスタの名称で補完する
+    let projection_events = Arc::new(ProjectionEventBus::new());
+    let projection_builder = Arc::new(
+        ProjectionBuilderImpl::new(Arc::clone(&projection_db), Arc::clone(&event_store))
+            .with_event_bus(Arc::clone(&projection_events))
+            .with_master_data_loader(Arc::clone(&master_data_loader)),
+    );
+    projection_builder.refresh_account_names().await?;
 
     // イベント通知ハンドラを登録
     let notification_handler =
         projection_builder.clone().create_event_notification_handler(infra_error_sender);
     event_store.set_notification_callback(notification_handler);
+    report.record_step("イベントストア・Projection DB", started, Vec::new());
 
     // Projection再構築チェック
-    check_and_rebuild_projections(&event_store, &projection_db, &projection_builder).await?;
+    check_and_rebuild_projections(
+        &event_store,
+        &projection_db,
+        &projection_builder,
+        repair,
+        report,
+    )
+    .await?;
+
+    // 初期データロード確認
+    report_master_data(&master_data_loader, report).await?;
+
+    Ok(InfrastructureComponents {
+        event_store,
+        projection_db,
+        projection_builder,
+        master_data_loader,
+        projection_events,
+        infra_error_receiver,
+        replica_status: None,
+    })
+}
+
+/// 参照専用モードのインフラ層をセットアップ
+///
+/// イベントストアとProjectionを読み取り専用で開き、Projectionの再構築や
+/// イベント通知は書き込みプロセスに任せる。反映位置の監視を開始する。
+async fn setup_replica_infrastructure(
+    data_dir: &Path,
+    report: &mut StartupReport,
+) -> AppResult<InfrastructureComponents> {
+    let started = Instant::now();
+    let event_store = Arc::new(EventStore::open_read_only(&data_dir.join("events")).await?);
+    let projection_db =
+        Arc::new(ProjectionDb::open_read_only(&data_dir.join("projections")).await?);
+
+    // 参照専用モードではインフラエラーの送信元がないため送信側は破棄する
+    let (_, infra_error_receiver) = mpsc::unbounded_channel();
+
+    let projection_builder =
+        Arc::new(ProjectionBuilderImpl::new(Arc::clone(&projection_db), Arc::clone(&event_store)));
+
+    let replica_status =
+        ReplicaMonitor::new(Arc::clone(&event_store), Arc::clone(&projection_db), "main", 1)
+            .spawn()
+            .await?;
+    let status = *replica_status.borrow();
+    report.record_step(
+        "参照専用モード（読み取り専用で接続）",
+        started,
+        vec![
+            format!("最新イベント番号: {}", status.latest_sequence),
+            format!("Projection反映位置: {}", status.projection_position),
+        ],
+    );
 
-    // マスタデータローダー
-    let master_db_path = data_dir.join("master_data");
     let master_data_loader = Arc::new(
-        MasterDataLoaderImpl::new(&master_db_path)
+        MasterDataLoaderImpl::new(&data_dir.join("master_data"))
             .await
             .map_err(AppError::InitializationFailed)?,
     );
-
-    // 初期データロード確認
-    let master_data = master_data_loader.load_master_data().await?;
-    println!("✓ Master data loaded successfully");
-    println!("  - Accounts: {}", master_data.accounts.len());
-    println!("  - Companies: {}", master_data.companies.len());
-    println!("  - Language: {}", master_data.user_options.language);
+    report_master_data(&master_data_loader, report).await?;
 
     Ok(InfrastructureComponents {
         event_store,
         projection_db,
         projection_builder,
         master_data_loader,
+        projection_events: Arc::new(ProjectionEventBus::new()),
         infra_error_receiver,
+        replica_status: Some(replica_status),
     })
 }
 
+/// マスタデータを読み込み、概要を記録
+async fn report_master_data(
+    master_data_loader: &MasterDataLoaderImpl,
+    report: &mut StartupReport,
+) -> AppResult<()> {
+    let started = Instant::now();
+    let master_data = master_data_loader.load_master_data().await?;
+    report.record_step(
+        "マスタデータ",
+        started,
+        vec![
+            format!("勘定科目: {}件", master_data.accounts.len()),
+            format!("会社: {}件", master_data.companies.len()),
+            format!("言語: {}", master_data.user_options.language),
+        ],
+    );
+    Ok(())
+}
+
 /// Projection再構築チェック
+///
+/// Projectionごとの反映位置をイベントストアの最新イベント番号・過去の最高到達点と
+/// 突き合わせる。先行または後退したProjectionがある場合は、Projectionを初期化してから
+/// 再構築する（既存のProjectionにはイベントストアと対応しない内容が含まれうるため）。
 async fn check_and_rebuild_projections(
     event_store: &Arc<EventStore>,
     projection_db: &Arc<ProjectionDb>,
     projection_builder: &Arc<ProjectionBuilderImpl>,
+    repair: ProjectionRepair,
+    report: &mut StartupReport,
 ) -> AppResult<()> {
+    let started = Instant::now();
+    let position_service =
+        ProjectionPositionQueryServiceImpl::new(Arc::clone(event_store), Arc::clone(projection_db));
+    let diagnostics = position_service.get_projection_positions().await?;
     let latest_sequence =
         event_store.get_latest_sequence().await.map(|seq| seq.as_u64()).unwrap_or(0);
-    let projection_position = projection_db.get_position("main", 1).await.unwrap_or(0);
 
-    if projection_position < latest_sequence {
-        println!("✓ Projection rebuild required");
-        println!("  - Latest event sequence: {}", latest_sequence);
-        println!("  - Projection position: {}", projection_position);
-        println!("  - Rebuilding projections...");
+    let mut details = vec![format!("最新イベント番号: {}", latest_sequence)];
+    details.extend(diagnostics.iter().map(|item| {
+        format!("{} 反映位置: {}（{}）", item.key(), item.position, item.status().label())
+    }));
+
+    let diverged: Vec<&ProjectionPositionDiagnostics> =
+        diagnostics.iter().filter(|item| item.status().needs_repair()).collect();
+    let lagging = diagnostics.iter().any(|item| item.lag() > 0);
+
+    if let Some(first) = diverged.first() {
+        let warnings: Vec<String> = diverged.iter().map(|item| divergence_warning(item)).collect();
+        if repair == ProjectionRepair::Prompt {
+            for warning in &warnings {
+                eprintln!("⚠ {}", warning);
+            }
+            eprintln!("  バックアップを組み合わせて復元した場合などに起こります。");
+            eprintln!("  Projectionを初期化し、イベントストアから再構築します。");
+            if !crate::app_maintenance::confirm("Type 'yes' to reset and rebuild projections: ")? {
+                return Err(divergence_error(first));
+            }
+        }
 
+        let removed = projection_db.reset().await?;
         projection_builder.rebuild_all_projections().await?;
 
-        println!("✓ Projection rebuild completed");
+        details.extend(warnings);
+        details.push(format!("Projection {}件を初期化して再構築しました", removed));
+        report.record_action(format!(
+            "Projectionを初期化して再構築しました（{}件の反映位置の不整合、最新イベント番号 {}）",
+            diverged.len(),
+            latest_sequence
+        ));
+    } else if lagging {
+        projection_builder.rebuild_all_projections().await?;
+
+        details.push("未反映のイベントがあったため再構築しました".to_string());
+        report.record_action(format!(
+            "Projectionを再構築しました（最新イベント番号 {}）",
+            latest_sequence
+        ));
     } else {
-        println!("✓ Projections are up to date");
-        println!("  - Latest event sequence: {}", latest_sequence);
-        println!("  - Projection position: {}", projection_position);
+        details.push("最新の状態です".to_string());
     }
 
+    // 確認・再構築後の反映位置を次回起動時の後退検出に使用する
+    position_service.record_high_water_marks().await?;
+    report.record_step("Projection整合性確認", started, details);
+
     Ok(())
 }
 
+/// 反映位置の不整合の警告文
+pub(crate) fn divergence_warning(item: &ProjectionPositionDiagnostics) -> String {
+    match item.status() {
+        ProjectionPositionStatus::Regressed => format!(
+            "Projection {} の反映位置（{}）が過去の反映位置（{}）より後退しています",
+            item.key(),
+            item.position,
+            item.high_water_mark
+        ),
+        _ => format!(
+            "Projection {} の反映位置（{}）がイベントストアの最新イベント番号（{}）より先行しています",
+            item.key(),
+            item.position,
+            item.latest_sequence
+        ),
+    }
+}
+
+/// 反映位置の不整合により起動を中止する場合のエラー
+pub(crate) fn divergence_error(item: &ProjectionPositionDiagnostics) -> AppError {
+    match item.status() {
+        ProjectionPositionStatus::Regressed => AppError::ProjectionRegressed {
+            projection: item.key(),
+            position: item.position,
+            high_water_mark: item.high_water_mark,
+        },
+        _ => AppError::ProjectionDiverged {
+            position: item.position,
+            latest_sequence: item.latest_sequence,
+        },
+    }
+}
+
 /// コントローラをセットアップ
+///
+/// `time_provider` は再オープン日時など、ユースケースが記録する時刻の基準。
+/// `request_timeout` は画面からの照会を打ち切るまでの時間。
+#[allow(clippy::too_many_arguments)]
 pub async fn setup_controllers(
     data_dir: &Path,
+    time_provider: Arc<dyn TimeProvider>,
+    request_timeout: Duration,
     event_store: Arc<EventStore>,
+    projection_db: Arc<ProjectionDb>,
+    projection_builder: Arc<ProjectionBuilderImpl>,
     master_data_loader: Arc<MasterDataLoaderImpl>,
+    projection_events: Arc<ProjectionEventBus>,
 ) -> AppResult<ControllerComponents> {
     // イベント通知チャネル
     let (event_sender, event_receiver) = mpsc::unbounded_channel();
@@ -162,60 +466,243 @@ pub async fn setup_controllers(
     let ledger_query_service = Arc::new(LedgerQueryServiceImpl::new(Arc::clone(&event_store)));
     let search_query_service =
         Arc::new(JournalEntrySearchQueryServiceImpl::new(Arc::clone(&event_store)));
+
+    // VoucherNumberGenerator（番号連続性監査で取消番号を参照するため照会サービスより先に作成）
+    let voucher_generator = Arc::new(VoucherNumberGeneratorImpl::new(Arc::clone(&event_store)));
+
     let batch_history_query_service = Arc::new(BatchHistoryQueryServiceImpl::new());
+    let projection_consistency_query_service =
+        Arc::new(ProjectionConsistencyQueryServiceImpl::new(Arc::clone(&event_store)));
+    let projection_inspection_query_service =
+        Arc::new(ProjectionInspectionQueryServiceImpl::new(Arc::clone(&projection_db)));
+    let inbox_query_service = Arc::new(InboxQueryServiceImpl::new(Arc::clone(&event_store)));
+    let sequence_audit_query_service = Arc::new(SequenceAuditQueryServiceImpl::new(
+        Arc::clone(&event_store),
+        Arc::clone(&voucher_generator),
+    ));
+    let suspense_aging_query_service = Arc::new(SuspenseAgingQueryServiceImpl::new(
+        Arc::clone(&event_store),
+        SUSPENSE_ACCOUNT_CODES,
+    ));
+    let audit_export_query_service = Arc::new(AuditExportQueryServiceImpl::new(
+        Arc::clone(&event_store),
+        Arc::clone(&master_data_loader),
+    ));
 
     // PresenterRegistry
     let presenter_registry = Arc::new(PresenterRegistry::new());
 
-    // VoucherNumberGenerator
-    let voucher_generator = Arc::new(VoucherNumberGeneratorImpl::new());
-
-    // マスタリポジトリの作成（補助科目のみ個別に必要）
+    // マスタリポジトリの作成（補助科目・表示科目マッピング・テーブル表示設定・
+    // 会計方針は個別に必要）
     let master_db_path = data_dir.join("master_data");
     let subsidiary_account_master_repository = Arc::new(
         SubsidiaryAccountMasterRepositoryImpl::new(&master_db_path.join("subsidiary_accounts"))
             .await
             .map_err(AppError::InitializationFailed)?,
     );
+    let statement_line_mapping_repository = Arc::new(
+        StatementLineMappingRepositoryImpl::new(&master_db_path.join("statement_line_mappings"))
+            .await
+            .map_err(AppError::InitializationFailed)?,
+    );
+    let management_account_mapping_repository = Arc::new(
+        ManagementAccountMappingRepositoryImpl::new(
+            &master_db_path.join("management_account_mappings"),
+        )
+        .await
+        .map_err(AppError::InitializationFailed)?,
+    );
+    let dimension_master_repository = Arc::new(
+        DimensionMasterRepositoryImpl::new(&master_db_path.join("dimension_masters"))
+            .await
+            .map_err(AppError::InitializationFailed)?,
+    );
+    let closing_timetable_repository = Arc::new(
+        ClosingTimetableRepositoryImpl::new(&master_db_path.join("closing_timetable"))
+            .await
+            .map_err(AppError::InitializationFailed)?,
+    );
+    let note_cross_reference_repository = Arc::new(
+        NoteCrossReferenceRepositoryImpl::new(&master_db_path.join("note_cross_references"))
+            .await
+            .map_err(AppError::InitializationFailed)?,
+    );
+    let journal_import_template_repository = Arc::new(
+        JournalImportTemplateRepositoryImpl::new(&master_db_path.join("journal_import_templates"))
+            .await
+            .map_err(AppError::InitializationFailed)?,
+    );
+    let accounting_policy_repository = Arc::new(
+        AccountingPolicyRepositoryImpl::new(&master_db_path.join("accounting_policy"))
+            .await
+            .map_err(AppError::InitializationFailed)?,
+    );
+    let table_preference_repository = Arc::new(
+        TablePreferenceRepositoryImpl::new(&master_db_path.join("table_preferences"))
+            .await
+            .map_err(AppError::InitializationFailed)?,
+    );
+    let inventory_worksheet_repository = Arc::new(
+        InventoryWorksheetRepositoryImpl::new(&master_db_path.join("inventory_worksheets"))
+            .await
+            .map_err(AppError::InitializationFailed)?,
+    );
+    let financial_instrument_repository = Arc::new(
+        FinancialInstrumentRepositoryImpl::new(&master_db_path.join("financial_instruments"))
+            .await
+            .map_err(AppError::InitializationFailed)?,
+    );
+    let supplier_invoice_repository = Arc::new(
+        SupplierInvoiceRepositoryImpl::new(&master_db_path.join("supplier_invoices"))
+            .await
+            .map_err(AppError::InitializationFailed)?,
+    );
+    let account_reconciliation_repository = Arc::new(
+        AccountReconciliationRepositoryImpl::new(&master_db_path.join("account_reconciliations"))
+            .await
+            .map_err(AppError::InitializationFailed)?,
+    );
+    let variance_commentary_repository = Arc::new(
+        VarianceCommentaryRepositoryImpl::new(&master_db_path.join("variance_commentaries"))
+            .await
+            .map_err(AppError::InitializationFailed)?,
+    );
+    let report_parameter_history_repository = Arc::new(
+        ReportParameterHistoryRepositoryImpl::new(&master_db_path.join("report_parameter_history"))
+            .await
+            .map_err(AppError::InitializationFailed)?,
+    );
+    // 操作記録（利用状況の分析用。マスタとは別に保存し、追記のみとする）
+    let user_action_repository = Arc::new(
+        UserActionRepositoryImpl::new(&data_dir.join("user_actions"))
+            .await
+            .map_err(AppError::InitializationFailed)?,
+    );
+    // 帳票アーカイブ（マスタとは別に保存し、追記のみとする）
+    let report_archive_repository = Arc::new(
+        ReportArchiveRepositoryImpl::new(&data_dir.join("report_archive"))
+            .await
+            .map_err(AppError::InitializationFailed)?,
+    );
+    // 長時間処理ジョブ（状態と進捗を保存し、再起動後も参照できるようにする）
+    let job_repository = Arc::new(
+        JobRepositoryImpl::new(&data_dir.join("jobs"))
+            .await
+            .map_err(AppError::InitializationFailed)?,
+    );
+    let user_account_repository = Arc::new(
+        UserAccountRepositoryImpl::new(&master_db_path.join("user_accounts"))
+            .await
+            .map_err(AppError::InitializationFailed)?,
+    );
+
+    // ログインセッション（全画面で共有）
+    let session = Arc::new(Session::default());
+
+    // バッチ完了通知（設定画面で変更された内容は実行中にも反映される）
+    let batch_notifier = Arc::new(BatchNotifier::new());
+    let user_options = master_data_loader.load_master_data().await?.user_options;
+    batch_notifier
+        .configure(user_options.batch_notification_bell, user_options.batch_notification_desktop);
+
+    // 勘定科目マスタのキャッシュ（科目選択と仕訳登録時の科目名の埋め込みで共有）
+    let account_master_cache = Arc::new(AccountMasterCache::new());
 
     // マスタコントローラ構築（master_data_loaderとpresenter_registryを使用）
-    let account_master_controller = Arc::new(AccountMasterController::new(
-        Arc::clone(&master_data_loader),
-        Arc::clone(&presenter_registry),
-    ));
+    let account_master_controller = Arc::new(
+        AccountMasterController::new(
+            Arc::clone(&master_data_loader),
+            Arc::clone(&account_master_cache),
+            Arc::clone(&presenter_registry),
+        )
+        .with_request_timeout(request_timeout),
+    );
     let application_settings_controller = Arc::new(ApplicationSettingsController::new(
         Arc::clone(&master_data_loader),
         Arc::clone(&presenter_registry),
+        Arc::clone(&batch_notifier),
     ));
     let company_master_controller = Arc::new(CompanyMasterController::new(
         Arc::clone(&master_data_loader),
         Arc::clone(&presenter_registry),
     ));
+    let calendar_master_controller = Arc::new(
+        CalendarMasterController::new(
+            Arc::clone(&master_data_loader),
+            Arc::clone(&presenter_registry),
+        )
+        .with_request_timeout(request_timeout),
+    );
     let subsidiary_account_master_controller = Arc::new(SubsidiaryAccountMasterController::new(
         Arc::clone(&subsidiary_account_master_repository),
         Arc::clone(&presenter_registry),
     ));
+    let statement_line_mapping_controller = Arc::new(StatementLineMappingController::new(
+        Arc::clone(&statement_line_mapping_repository),
+        Arc::clone(&master_data_loader),
+    ));
+    let management_account_mapping_controller = Arc::new(ManagementAccountMappingController::new(
+        management_account_mapping_repository,
+        Arc::clone(&master_data_loader),
+    ));
+    let table_preference_controller =
+        Arc::new(TablePreferenceController::new(Arc::clone(&table_preference_repository)));
+    let accounting_policy_controller =
+        Arc::new(AccountingPolicyController::new(Arc::clone(&accounting_policy_repository)));
+
+    // 業務指標（日次集計はProjection DBに保存する）
+    let business_metrics: Arc<dyn BusinessMetrics> = Arc::new(
+        BusinessMetricsImpl::new(Arc::clone(&projection_db))
+            .with_time_provider(Arc::clone(&time_provider)),
+    );
 
     // 業務コントローラ構築
-    let journal_entry_controller = Arc::new(JournalEntryController::new(
-        Arc::clone(&event_store),
-        Arc::clone(&voucher_generator),
-        Arc::clone(&presenter_registry),
-    ));
+    let journal_entry_controller = Arc::new(
+        JournalEntryController::new(
+            Arc::clone(&event_store),
+            Arc::clone(&voucher_generator),
+            Arc::new(EntryNumberGeneratorImpl::new(Arc::clone(&event_store))),
+            master_data_loader.settings_repository(),
+            Arc::clone(&presenter_registry),
+            Arc::clone(&accounting_policy_repository),
+        )
+        .with_dimension_masters(Arc::clone(&dimension_master_repository))
+        .with_account_master(Arc::clone(&master_data_loader), account_master_cache)
+        .with_metrics(Arc::clone(&business_metrics))
+        .with_request_timeout(request_timeout),
+    );
 
-    let _ledger_controller = Arc::new(LedgerController::new(Arc::clone(&ledger_query_service)));
+    let ledger_controller = Arc::new(LedgerController::new(Arc::clone(&ledger_query_service)));
 
     // 月次決算Interactor構築
     let consolidate_ledger_interactor =
         Arc::new(ConsolidateLedgerInteractor::new(Arc::clone(&ledger_query_service)));
-    let prepare_closing_interactor =
-        Arc::new(PrepareClosingInteractor::new(Arc::clone(&ledger_query_service)));
-    let lock_closing_period_interactor =
-        Arc::new(LockClosingPeriodInteractor::new(Arc::clone(&event_store)));
-    let generate_trial_balance_interactor =
-        Arc::new(GenerateTrialBalanceInteractor::new(Arc::clone(&ledger_query_service)));
-    let generate_note_draft_interactor =
-        Arc::new(GenerateNoteDraftInteractor::new(Arc::clone(&ledger_query_service)));
+    let prepare_closing_interactor = Arc::new(PrepareClosingInteractor::new(
+        Arc::clone(&ledger_query_service),
+        Arc::clone(&suspense_aging_query_service),
+    ));
+    let lock_closing_period_interactor = Arc::new(LockClosingPeriodInteractor::new(
+        Arc::clone(&event_store),
+        Arc::clone(&account_reconciliation_repository),
+        Arc::clone(&ledger_query_service),
+        Arc::clone(&search_query_service),
+        Arc::clone(&accounting_policy_repository),
+        master_data_loader.settings_repository(),
+    ));
+    // 帳票配信（配信先はアプリケーション設定から読み込む）
+    let report_delivery =
+        Arc::new(ReportDeliveryImpl::new(master_data_loader.settings_repository()));
+    let generate_trial_balance_interactor = Arc::new(GenerateTrialBalanceInteractor::new(
+        Arc::clone(&ledger_query_service),
+        Arc::clone(&statement_line_mapping_repository),
+        master_data_loader.settings_repository(),
+        Arc::new(ReportDigesterImpl),
+        Arc::clone(&report_delivery),
+    ));
+    let generate_note_draft_interactor = Arc::new(GenerateNoteDraftInteractor::new(
+        Arc::clone(&ledger_query_service),
+        Arc::clone(&inventory_worksheet_repository),
+    ));
     let adjust_accounts_interactor = Arc::new(AdjustAccountsInteractor::new(
         Arc::clone(&event_store),
         Arc::clone(&ledger_query_service),
@@ -223,27 +710,54 @@ pub async fn setup_controllers(
     let apply_ifrs_valuation_interactor = Arc::new(ApplyIfrsValuationInteractor::new(
         Arc::clone(&event_store),
         Arc::clone(&ledger_query_service),
+        Arc::clone(&inventory_worksheet_repository),
+        Arc::clone(&financial_instrument_repository),
+        Arc::clone(&voucher_generator),
     ));
     let generate_financial_statements_interactor =
-        Arc::new(GenerateFinancialStatementsInteractor::new(Arc::clone(&ledger_query_service)));
-
-    // ClosingController構築
-    let closing_controller = Arc::new(ClosingController::new(
-        consolidate_ledger_interactor,
-        prepare_closing_interactor,
-        lock_closing_period_interactor,
-        generate_trial_balance_interactor,
-        generate_note_draft_interactor,
-        adjust_accounts_interactor,
-        apply_ifrs_valuation_interactor,
-        generate_financial_statements_interactor,
-    ));
+        Arc::new(GenerateFinancialStatementsInteractor::new(
+            Arc::clone(&ledger_query_service),
+            Arc::clone(&statement_line_mapping_repository),
+            Arc::clone(&accounting_policy_repository),
+            Arc::clone(&report_delivery),
+            Arc::clone(&note_cross_reference_repository),
+            Arc::clone(&variance_commentary_repository),
+        ));
+
+    // ClosingController構築（Interactorを型を消去したユースケースとして登録）
+    let closing_controller = Arc::new(
+        ClosingController::new(ClosingUseCases {
+            consolidate_ledger: use_case!(consolidate_ledger_interactor),
+            prepare_closing: use_case!(prepare_closing_interactor),
+            lock_closing_period: use_case!(Arc::clone(&lock_closing_period_interactor)),
+            validate_closing_lock: use_case!(lock_closing_period_interactor, validate),
+            generate_trial_balance: use_case!(generate_trial_balance_interactor),
+            generate_note_draft: use_case!(generate_note_draft_interactor),
+            adjust_accounts: use_case!(adjust_accounts_interactor),
+            apply_ifrs_valuation: use_case!(apply_ifrs_valuation_interactor),
+            generate_financial_statements: use_case!(generate_financial_statements_interactor),
+        })
+        .with_notifier(Arc::clone(&batch_notifier))
+        .with_metrics(Arc::clone(&business_metrics))
+        .with_timetable(Arc::clone(&closing_timetable_repository))
+        .with_request_timeout(request_timeout),
+    );
 
     // SearchController構築
-    let search_controller = Arc::new(SearchController::new(
-        Arc::clone(&search_query_service),
-        Arc::clone(&presenter_registry),
-    ));
+    let search_controller = Arc::new(
+        SearchController::new(Arc::clone(&search_query_service), Arc::clone(&presenter_registry))
+            .with_request_timeout(request_timeout),
+    );
+
+    // よく使うProjection（元帳・仕訳検索）を起動直後にバックグラウンドで事前構築
+    // （失敗した場合は初回照会時に再構築されるため結果は待たない）
+    {
+        let ledger_controller = Arc::clone(&ledger_controller);
+        let search_controller = Arc::clone(&search_controller);
+        tokio::spawn(async move {
+            let _ = tokio::join!(ledger_controller.warm_up(), search_controller.warm_up());
+        });
+    }
 
     // BatchHistoryController構築
     let batch_history_controller = Arc::new(BatchHistoryController::new(
@@ -251,26 +765,252 @@ pub async fn setup_controllers(
         Arc::clone(&presenter_registry),
     ));
 
-    // Controllers container
-    let controllers = Controllers::new(
-        account_master_controller,
-        application_settings_controller,
-        company_master_controller,
-        subsidiary_account_master_controller,
-        journal_entry_controller,
-        closing_controller,
-        search_controller,
-        batch_history_controller,
+    // ConsistencyCheckController構築
+    let consistency_check_controller = Arc::new(ConsistencyCheckController::new(Arc::clone(
+        &projection_consistency_query_service,
+    )));
+
+    // InboxController構築
+    let inbox_controller = Arc::new(InboxController::new(
+        Arc::clone(&inbox_query_service),
+        Arc::clone(&session),
+        master_data_loader.settings_repository(),
+    ));
+
+    // SequenceAuditController構築
+    let sequence_audit_controller =
+        Arc::new(SequenceAuditController::new(Arc::clone(&sequence_audit_query_service)));
+
+    // SuspenseClearingController構築
+    let suspense_clearing_controller = Arc::new(SuspenseClearingController::new(
+        Arc::clone(&suspense_aging_query_service),
+        Arc::clone(&event_store),
+        Arc::clone(&voucher_generator),
+    ));
+
+    // SupplierInvoiceController構築
+    let supplier_invoice_controller = Arc::new(SupplierInvoiceController::new(
+        supplier_invoice_repository,
+        Arc::clone(&event_store),
+        Arc::clone(&voucher_generator),
+    ));
+
+    // AuthenticationController構築（利用者の登録は会計方針の管理者のみ）
+    let authentication_controller = Arc::new(AuthenticationController::new(
+        Arc::clone(&user_account_repository),
+        Arc::clone(&accounting_policy_repository),
+        Arc::new(PasswordHasherImpl::new()),
+    ));
+
+    // InitialSetupController構築（初回起動時のセットアップウィザード）
+    let initial_setup_controller = Arc::new(InitialSetupController::new(
+        Arc::clone(&user_account_repository),
+        Arc::new(PasswordHasherImpl::new()),
+        master_data_loader.company_repository(),
+        master_data_loader.account_repository(),
+        master_data_loader.settings_repository(),
+        Arc::clone(&accounting_policy_repository),
+    ));
+
+    // InventoryWorksheetController構築
+    let inventory_worksheet_controller =
+        Arc::new(InventoryWorksheetController::new(Arc::clone(&inventory_worksheet_repository)));
+
+    // FinancialInstrumentController構築
+    let financial_instrument_controller =
+        Arc::new(FinancialInstrumentController::new(Arc::clone(&financial_instrument_repository)));
+
+    // AccountReconciliationController構築
+    let account_reconciliation_controller = Arc::new(AccountReconciliationController::new(
+        Arc::clone(&account_reconciliation_repository),
+        Arc::clone(&ledger_query_service),
+    ));
+
+    // ProjectionConsoleController構築
+    let projection_console_controller = Arc::new(ProjectionConsoleController::new(
+        Arc::clone(&projection_inspection_query_service),
+        Arc::clone(&accounting_policy_repository),
+    ));
+
+    // BalanceAnalysisController構築
+    let balance_analysis_controller =
+        Arc::new(BalanceAnalysisController::new(Arc::clone(&ledger_query_service)));
+
+    // AccountActivityController構築
+    let account_activity_controller = Arc::new(AccountActivityController::new(
+        Arc::clone(&ledger_query_service),
+        Arc::clone(&accounting_policy_repository),
+    ));
+
+    // ReportArchiveController構築
+    let report_archive_controller =
+        Arc::new(ReportArchiveController::new(Arc::clone(&report_archive_repository)));
+
+    // IntegrityAuditController構築（監査結果は監査用のストリームに追記する）
+    let integrity_audit_controller = Arc::new(IntegrityAuditController::new(
+        Arc::clone(&projection_consistency_query_service),
+        Arc::clone(&sequence_audit_query_service),
+        Arc::clone(&event_store),
+    ));
+
+    // JobQueueController構築（ワーカーは start_job_queue で起動する）
+    let job_queue_controller = Arc::new(JobQueueController::new(
+        job_repository,
+        Arc::new(ControllerJobRunner::new(
+            Arc::clone(&closing_controller),
+            Arc::clone(&inventory_worksheet_controller),
+            projection_builder.clone(),
+            Arc::new(ProjectionCompactorImpl::new(
+                Arc::clone(&projection_db),
+                Arc::clone(&event_store),
+                data_dir.join("projections"),
+                master_data_loader.settings_repository(),
+            )),
+            Arc::clone(&integrity_audit_controller),
+        )),
+    ));
+
+    // AuditExportController構築
+    let audit_export_controller = Arc::new(AuditExportController::new(audit_export_query_service));
+
+    // PeriodReopenController構築
+    let period_reopen_controller = Arc::new(PeriodReopenController::new(
+        Arc::clone(&event_store),
+        Arc::new(PeriodReopenQueryServiceImpl::new(Arc::clone(&event_store))),
+        Arc::clone(&user_account_repository),
+        Arc::new(PasswordHasherImpl::new()),
+        Arc::clone(&time_provider),
+    ));
+
+    // StorageTelemetryController構築（標本はProjection DBに保存する）
+    let storage_telemetry_controller = Arc::new(StorageTelemetryController::new(Arc::new(
+        StorageTelemetryImpl::new(Arc::clone(&event_store), Arc::clone(&projection_db)),
+    )));
+
+    // LedgerAnnotationController構築（注記は仕訳のイベントストリームに追記する）
+    let ledger_annotation_controller =
+        Arc::new(LedgerAnnotationController::new(Arc::clone(&event_store)));
+
+    // EntryLinkController構築（紐付けは両方の仕訳のイベントストリームに追記する）
+    let entry_link_controller = Arc::new(EntryLinkController::new(Arc::clone(&event_store)));
+
+    // JournalImportController構築（取り込んだ仕訳は下書きとしてイベントストアに追記する）
+    // 大量の取込では追記中のProjection反映を保留し、取込後にまとめて反映する
+    let journal_import_controller = Arc::new(
+        JournalImportController::new(journal_import_template_repository, Arc::clone(&event_store))
+            .with_deferred_projections(projection_builder),
+    );
+
+    // BusinessMetricsController構築
+    let business_metrics_controller = Arc::new(BusinessMetricsController::new(business_metrics));
+
+    // DimensionMasterController構築
+    let dimension_master_controller =
+        Arc::new(DimensionMasterController::new(dimension_master_repository));
+
+    // ClosingTimetableController構築
+    let closing_timetable_controller =
+        Arc::new(ClosingTimetableController::new(closing_timetable_repository));
+
+    // NoteCrossReferenceController構築
+    let note_cross_reference_controller =
+        Arc::new(NoteCrossReferenceController::new(note_cross_reference_repository));
+
+    // VarianceCommentaryController構築
+    let variance_commentary_controller =
+        Arc::new(VarianceCommentaryController::new(variance_commentary_repository));
+
+    // ReportParameterHistoryController構築
+    let report_parameter_history_controller =
+        Arc::new(ReportParameterHistoryController::new(report_parameter_history_repository));
+
+    // 操作記録・利用状況レポート（日次集計はProjection DBに保存する）
+    let user_activity: Arc<dyn UserActivityProjection> = Arc::new(
+        UserActivityProjectionImpl::new(Arc::clone(&projection_db))
+            .with_time_provider(Arc::clone(&time_provider)),
+    );
+    let record_user_action_controller =
+        Arc::new(RecordUserActionController::new(Arc::new(RecordUserActionInteractor::new(
+            user_action_repository,
+            Arc::clone(&user_activity),
+            Arc::new(Presenter::default()),
+        ))));
+    let user_activity_controller = Arc::new(UserActivityController::new(
+        user_activity,
+        Arc::clone(&accounting_policy_repository),
+    ));
+
+    // SessionLogController構築（ログイン・ログアウトは監査用のセッションストリームに追記する）
+    let session_log_controller = Arc::new(SessionLogController::new(
+        Arc::clone(&event_store),
+        Arc::clone(&accounting_policy_repository),
+    ));
+
+    // ExportProtectionController構築（署名鍵はデータディレクトリに作成する）
+    let export_protection = Arc::new(
+        ExportProtectionImpl::open(&data_dir.join(EXPORT_SIGNING_KEY_FILE))
+            .map_err(AppError::InitializationFailed)?,
     );
+    let export_protection_controller = Arc::new(ExportProtectionController::new(
+        master_data_loader.settings_repository(),
+        export_protection,
+    ));
+
+    // Controllers container
+    let controllers = Controllers {
+        account_master: account_master_controller,
+        application_settings: application_settings_controller,
+        company_master: company_master_controller,
+        subsidiary_account_master: subsidiary_account_master_controller,
+        journal_entry: journal_entry_controller,
+        closing: closing_controller,
+        search: search_controller,
+        batch_history: batch_history_controller,
+        calendar_master: calendar_master_controller,
+        ledger: ledger_controller,
+        statement_line_mapping: statement_line_mapping_controller,
+        management_account_mapping: management_account_mapping_controller,
+        table_preference: table_preference_controller,
+        consistency_check: consistency_check_controller,
+        inbox: inbox_controller,
+        sequence_audit: sequence_audit_controller,
+        accounting_policy: accounting_policy_controller,
+        suspense_clearing: suspense_clearing_controller,
+        supplier_invoice: supplier_invoice_controller,
+        authentication: authentication_controller,
+        initial_setup: initial_setup_controller,
+        inventory_worksheet: inventory_worksheet_controller,
+        projection_console: projection_console_controller,
+        balance_analysis: balance_analysis_controller,
+        account_activity: account_activity_controller,
+        report_archive: report_archive_controller,
+        job_queue: job_queue_controller,
+        audit_export: audit_export_controller,
+        period_reopen: period_reopen_controller,
+        financial_instrument: financial_instrument_controller,
+        account_reconciliation: account_reconciliation_controller,
+        storage_telemetry: storage_telemetry_controller,
+        ledger_annotation: ledger_annotation_controller,
+        entry_link: entry_link_controller,
+        journal_import: journal_import_controller,
+        business_metrics: business_metrics_controller,
+        dimension_master: dimension_master_controller,
+        closing_timetable: closing_timetable_controller,
+        note_cross_reference: note_cross_reference_controller,
+        export_protection: export_protection_controller,
+        variance_commentary: variance_commentary_controller,
+        report_parameter_history: report_parameter_history_controller,
+        record_user_action: record_user_action_controller,
+        user_activity: user_activity_controller,
+        session_log: session_log_controller,
+        integrity_audit: integrity_audit_controller,
+        session,
+        projection_events,
+    };
 
     // View層の構築
     let closing_page = ClosingPage::new(trial_balance_rx);
 
-    println!("✓ Application components initialized");
-    println!("  - Controllers: AccountMaster, JournalEntry, Closing, Search");
-    println!("  - Navigation: Stack-based architecture");
-    println!("  - PresenterRegistry: Ready");
-
     Ok(ControllerComponents {
         controllers,
         presenter_registry,
@@ -279,3 +1019,148 @@ pub async fn setup_controllers(
         event_receiver,
     })
 }
+
+/// ジョブキューを起動
+///
+/// 前回の終了時に実行中だったジョブを復旧してからワーカーを起動する。
+pub async fn start_job_queue(
+    controllers: &Controllers,
+    report: &mut StartupReport,
+) -> AppResult<()> {
+    let started = Instant::now();
+    let recovered = controllers
+        .job_queue
+        .recover()
+        .await
+        .map_err(|e| AppError::InitializationFailed(e.into()))?;
+    if recovered.failed > 0 {
+        report.record_action(format!(
+            "前回の終了時に実行中だったジョブ {}件を失敗として記録しました",
+            recovered.failed
+        ));
+    }
+
+    controllers.job_queue.spawn_worker();
+    report.record_step(
+        "ジョブキュー",
+        started,
+        vec![format!("待機中のジョブ: {}件", recovered.resumed)],
+    );
+    Ok(())
+}
+
+/// Projection圧縮の定期実行を開始
+///
+/// 間隔ごとにジョブキューへ登録する。前回の圧縮が未終了の間は登録しない。
+pub fn schedule_projection_compaction(
+    controllers: &Controllers,
+    interval: Duration,
+    report: &mut StartupReport,
+) {
+    let job_queue = Arc::clone(&controllers.job_queue);
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        // 起動直後の即時実行は行わない
+        ticker.tick().await;
+        loop {
+            ticker.tick().await;
+            // 失敗はコントローラがエラーログに記録済みのため、次回の実行に任せる
+            let _ = job_queue.schedule_projection_compaction("system".to_string()).await;
+        }
+    });
+    report.record_step(
+        "Projection圧縮の定期実行",
+        Instant::now(),
+        vec![format!("間隔: {}時間", interval.as_secs() / 3600)],
+    );
+}
+
+/// 月初の繰越残高の確定要否を確認する間隔
+const PERIOD_ROLLOVER_CHECK_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// 月初の繰越残高の確定を開始
+///
+/// 起動直後に当月の繰越残高を確定し、以降は業務日付の月が変わるたびに確定する。
+pub fn schedule_period_rollover(
+    controllers: &Controllers,
+    time_provider: Arc<dyn TimeProvider>,
+    report: &mut StartupReport,
+) {
+    let ledger = Arc::clone(&controllers.ledger);
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(PERIOD_ROLLOVER_CHECK_INTERVAL);
+        let mut rolled_over = None;
+        loop {
+            ticker.tick().await;
+            let today = time_provider.business_date();
+            let period = (today.year(), today.month() as u8);
+            if rolled_over == Some(period) {
+                continue;
+            }
+            // 失敗はコントローラがエラーログに記録済みのため、次回の確認で再試行する
+            if ledger.roll_over_period(period.0, period.1).await.is_ok() {
+                rolled_over = Some(period);
+            }
+        }
+    });
+    report.record_step(
+        "月初の繰越残高の確定",
+        Instant::now(),
+        vec![format!("確認間隔: {}分", PERIOD_ROLLOVER_CHECK_INTERVAL.as_secs() / 60)],
+    );
+}
+
+/// ストレージ使用状況の定期記録を開始
+///
+/// 起動直後に1件記録し、以降は間隔ごとに記録する。
+pub fn schedule_storage_sampling(
+    controllers: &Controllers,
+    interval: Duration,
+    report: &mut StartupReport,
+) {
+    let storage_telemetry = Arc::clone(&controllers.storage_telemetry);
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        loop {
+            ticker.tick().await;
+            // 失敗はコントローラがエラーログに記録済みのため、次回の記録に任せる
+            let _ = storage_telemetry.record_sample().await;
+        }
+    });
+    report.record_step(
+        "ストレージ使用状況の記録",
+        Instant::now(),
+        vec![format!("間隔: {}分", interval.as_secs() / 60)],
+    );
+}
+
+/// 定期の整合性監査の要否を確認する間隔
+const INTEGRITY_AUDIT_CHECK_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// 整合性監査の定期実行を開始
+///
+/// 起動直後と以降の確認ごとに、最新の監査から1週間以上経過していればジョブキューへ登録する。
+/// 前回の監査が未終了の間は登録しない。
+pub fn schedule_integrity_audit(controllers: &Controllers, report: &mut StartupReport) {
+    let integrity_audit = Arc::clone(&controllers.integrity_audit);
+    let job_queue = Arc::clone(&controllers.job_queue);
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(INTEGRITY_AUDIT_CHECK_INTERVAL);
+        loop {
+            ticker.tick().await;
+            // 失敗はコントローラがエラーログに記録済みのため、次回の確認で再試行する
+            if let Ok(true) = integrity_audit.is_due().await {
+                let _ = job_queue.schedule_integrity_audit("system".to_string()).await;
+            }
+        }
+    });
+    report.record_step(
+        "整合性監査の定期実行",
+        Instant::now(),
+        vec![format!(
+            "間隔: {}日（確認間隔: {}分）",
+            INTEGRITY_AUDIT_INTERVAL_DAYS,
+            INTEGRITY_AUDIT_CHECK_INTERVAL.as_secs() / 60
+        )],
+    );
+}
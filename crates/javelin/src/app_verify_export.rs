@@ -0,0 +1,76 @@
+// Application Verify Export - 出力ファイルの署名検証
+// 責務: `javelin verify-export` サブコマンドの実行
+//
+// 出力ファイルを分離署名ファイル（<ファイル名>.sig）で検証し、改ざんの有無と
+// 署名がこのインストールの鍵によるものかを表示する。
+// 署名鍵ファイルがない場合も検証はでき、その場合は署名者を確認できない旨を表示する。
+
+use std::{path::PathBuf, sync::Arc};
+
+use javelin_adapter::controller::ExportProtectionController;
+use javelin_infrastructure::{
+    repositories::ApplicationSettingsRepositoryImpl,
+    services::{EXPORT_SIGNING_KEY_FILE, ExportProtectionImpl},
+};
+
+use crate::app_error::{AppError, AppResult};
+
+/// 署名検証コマンド
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VerifyExportCommand {
+    pub data_dir: PathBuf,
+    /// 検証する出力ファイル
+    pub file: PathBuf,
+    /// 分離署名ファイル（省略時は `<ファイル名>.sig`）
+    pub signature: Option<PathBuf>,
+}
+
+impl VerifyExportCommand {
+    pub async fn execute(self) -> AppResult<()> {
+        let signature = self.signature.clone().unwrap_or_else(|| {
+            let mut path = self.file.clone().into_os_string();
+            path.push(".sig");
+            PathBuf::from(path)
+        });
+
+        let settings_repository = Arc::new(
+            ApplicationSettingsRepositoryImpl::new(
+                &self.data_dir.join("master_data").join("settings"),
+            )
+            .await
+            .map_err(AppError::InitializationFailed)?,
+        );
+        let protection = Arc::new(
+            ExportProtectionImpl::open_for_verification(
+                &self.data_dir.join(EXPORT_SIGNING_KEY_FILE),
+            )
+            .map_err(AppError::InitializationFailed)?,
+        );
+        let controller = ExportProtectionController::new(settings_repository, protection);
+
+        let verification = controller
+            .verify(&self.file, &signature)
+            .await
+            .map_err(AppError::ExportFailed)?;
+
+        println!("  - File: {}", verification.file_name);
+        println!("  - sha256: {}", verification.digest);
+        println!("  - Signed at: {}", verification.signed_at);
+        println!("  - Public key: {}", verification.public_key);
+        if !verification.valid {
+            return Err(AppError::ExportFailed(format!(
+                "署名が一致しません（ファイルが改ざんされた可能性があります）: {}",
+                self.file.display()
+            )));
+        }
+        if verification.trusted {
+            println!("✓ Signature verified: {}", self.file.display());
+        } else {
+            println!(
+                "✓ Signature verified, but the signing key is not this installation's key: {}",
+                self.file.display()
+            );
+        }
+        Ok(())
+    }
+}
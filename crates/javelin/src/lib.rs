@@ -2,10 +2,15 @@
 // Orchestrates all layers
 
 pub mod app;
+pub mod app_audit_export;
 pub mod app_builder;
 pub mod app_error;
+pub mod app_generate;
+pub mod app_maintenance;
+pub mod app_projections;
 pub mod app_resolver;
 pub mod app_setup;
+pub mod app_verify_export;
 
 // Re-export all layers for convenience
 pub use javelin_adapter as adapter;
@@ -1,7 +1,363 @@
 // Javelin - 主計部業務バッチシステム
 // Clean Architecture + Event Sourcing + CQRS
+//
+// 使い方:
+//   javelin [--data-dir <PATH>] [--replica] [--compact-every <HOURS>] [--sample-every <MINUTES>]
+//           [--timezone <+HH:MM>] [--request-timeout <SECONDS>] [--repair-projections]
+//   javelin seed [--data-dir <PATH>]
+//   javelin reset [--data-dir <PATH>] [--keep-masters] [--yes]
+//   javelin audit-export --fiscal-year <YYYY> [--data-dir <PATH>] [--output <DIR>] [--user <ID>]
+//   javelin generate [--entries <N>] [--periods <N>] [--seed <N>] [--data-dir <PATH>]
+//                    [--durability <POLICY>] [--writers <N>]
+//   javelin verify-export --file <PATH> [--signature <PATH>] [--data-dir <PATH>]
+//   javelin projections [--data-dir <PATH>] [--repair]
+//
+//   --data-dir <PATH>  データディレクトリ（省略時は ./data）
+//   --replica          参照専用モード（集計・照会用）
+//   --repair-projections
+// Projection反映位置がイベントストアより先行していた場合に確認せず初期化・再構築する
+//   --compact-every    Projection圧縮をジョブキューに定期登録する間隔（時間）
+//   --sample-every     ストレージ使用状況を記録する間隔（分。省略時は 10）
+//   --request-timeout  画面からの照会を打ち切るまでの時間（秒。省略時は 30）
+//   --timezone
+// 業務日付と日時表示のタイムゾーン（UTCオフセット。省略時は実行環境のローカル）
+// イベントの記録時刻はUTCのまま保存し、記録時点の業務日付を併せて保存する   seed
+// デモ用の勘定科目・会社と複数期間の仕訳を投入（空のデータディレクトリのみ）   reset
+// イベントストア・Projection等のトランザクションデータを削除   --keep-masters
+// reset時にマスタデータ（勘定科目・会社・設定等）を残す   --yes
+// reset時の確認プロンプトを省略   audit-export
+// 指定年度の仕訳帳・総勘定元帳と索引ファイルを出力（電子帳簿保存法向け）   --fiscal-year
+// 出力する年度（取引日付の暦年）   --output <DIR>     出力先（省略時はカレントディレクトリ。配下に
+// audit_export_<年度> を作成）   --user <ID>        索引ファイルに記録する出力者（省略時は system）
+//   generate           負荷試験用に貸借一致した仕訳を大量にイベントストアへ直接投入（開発者向け）
+//   --entries <N>      生成する仕訳数（省略時は 10000）
+//   --periods <N>      仕訳を散らす月数（当月を含む過去の月数。省略時は 12）
+//   --seed <N>         乱数の種（同じ種で同じ仕訳を再現する）
+//   --durability       耐久性ポリシー（max-durability / balanced / max-performance /
+//                      group-commit / group-commit:<ミリ秒>。省略時は max-performance）
+//   --writers <N>      並行して追記する数（省略時は 1）
+//   verify-export      出力ファイルを分離署名で検証（改ざんの有無と署名鍵の照合）
+//   --file <PATH>      検証する出力ファイル
+//   --signature <PATH> 分離署名ファイル（省略時は <ファイル名>.sig）
+//   projections        Projectionごとの反映位置・最新イベント番号・未反映件数を表示
+//   --repair           反映位置が先行・後退したProjectionがあれば初期化して再構築する
+//
+// 参照専用モードは、起票を行う書き込みプロセスと同じデータディレクトリを
+// 別プロセスから読み取り専用で開く。試算表などの重い集計を書き込みプロセスから
+// 切り離すために使用する。イベントストアとProjectionへの書き込みは拒否され、
+// 書き込みプロセスが反映した内容は画面の表示・再読込時に参照される。
+// 書き込みプロセスを先に起動してデータディレクトリを初期化しておくこと。
 
-use javelin::{app_builder::ApplicationBuilder, app_error::AppResult};
+use std::{path::PathBuf, time::Duration};
+
+use javelin::{
+    app_audit_export::{AuditExportCommand, DEFAULT_EXPORTED_BY},
+    app_builder::{ApplicationBuilder, default_data_dir},
+    app_error::{AppError, AppResult},
+    app_generate::{
+        DEFAULT_GENERATE_DURABILITY, DEFAULT_GENERATE_ENTRIES, DEFAULT_GENERATE_PERIODS,
+        DEFAULT_GENERATE_SEED, GenerateCommand,
+    },
+    app_maintenance::MaintenanceCommand,
+    app_projections::ProjectionsCommand,
+    app_setup::{LaunchMode, ProjectionRepair},
+    app_verify_export::VerifyExportCommand,
+};
+use javelin_domain::time_provider::parse_utc_offset;
+use javelin_infrastructure::storage_metrics::DurabilityPolicy;
+
+/// 起動コマンド
+enum Command {
+    /// アプリケーション起動
+    Run(ApplicationBuilder),
+    /// データ保守（seed / reset）
+    Maintenance(MaintenanceCommand),
+    /// 監査用帳簿出力
+    AuditExport(AuditExportCommand),
+    /// 負荷試験用の仕訳生成
+    Generate(GenerateCommand),
+    /// 出力ファイルの署名検証
+    VerifyExport(VerifyExportCommand),
+    /// Projectionの反映位置の確認と修復
+    Projections(ProjectionsCommand),
+}
+
+/// コマンドライン引数からコマンドを構成
+fn parse_args(args: impl Iterator<Item = String>) -> AppResult<Command> {
+    let mut args = args.peekable();
+    match args.peek().map(String::as_str) {
+        Some("seed") | Some("reset") => {
+            let subcommand = args.next().unwrap_or_default();
+            parse_maintenance_args(&subcommand, args).map(Command::Maintenance)
+        }
+        Some("audit-export") => {
+            args.next();
+            parse_audit_export_args(args).map(Command::AuditExport)
+        }
+        Some("generate") => {
+            args.next();
+            parse_generate_args(args).map(Command::Generate)
+        }
+        Some("verify-export") => {
+            args.next();
+            parse_verify_export_args(args).map(Command::VerifyExport)
+        }
+        Some("projections") => {
+            args.next();
+            parse_projections_args(args).map(Command::Projections)
+        }
+        _ => parse_run_args(args).map(Command::Run),
+    }
+}
+
+/// アプリケーション起動時の引数からビルダーを構成
+fn parse_run_args(mut args: impl Iterator<Item = String>) -> AppResult<ApplicationBuilder> {
+    let mut builder = ApplicationBuilder::new();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--replica" => builder = builder.with_launch_mode(LaunchMode::Replica),
+            "--repair-projections" => {
+                builder = builder.with_projection_repair(ProjectionRepair::Automatic)
+            }
+            "--data-dir" => builder = builder.with_data_dir(parse_data_dir(&mut args)?),
+            "--compact-every" => {
+                let hours: u64 = args
+                    .next()
+                    .and_then(|hours| hours.parse().ok())
+                    .filter(|h| *h > 0)
+                    .ok_or_else(|| {
+                        AppError::InvalidArgument(
+                            "--compact-every requires a positive number of hours".to_string(),
+                        )
+                    })?;
+                builder = builder.with_compaction_interval(Duration::from_secs(hours * 3600));
+            }
+            "--sample-every" => {
+                let minutes: u64 = args
+                    .next()
+                    .and_then(|minutes| minutes.parse().ok())
+                    .filter(|m| *m > 0)
+                    .ok_or_else(|| {
+                        AppError::InvalidArgument(
+                            "--sample-every requires a positive number of minutes".to_string(),
+                        )
+                    })?;
+                builder = builder.with_storage_sample_interval(Duration::from_secs(minutes * 60));
+            }
+            "--timezone" => {
+                let time_zone = args
+                    .next()
+                    .and_then(|offset| parse_utc_offset(&offset).ok())
+                    .ok_or_else(|| {
+                        AppError::InvalidArgument(
+                            "--timezone requires a UTC offset such as +09:00".to_string(),
+                        )
+                    })?;
+                builder = builder.with_time_zone(time_zone);
+            }
+            "--request-timeout" => {
+                let seconds: u64 = args
+                    .next()
+                    .and_then(|seconds| seconds.parse().ok())
+                    .filter(|s| *s > 0)
+                    .ok_or_else(|| {
+                        AppError::InvalidArgument(
+                            "--request-timeout requires a positive number of seconds".to_string(),
+                        )
+                    })?;
+                builder = builder.with_request_timeout(Duration::from_secs(seconds));
+            }
+            other => return Err(AppError::InvalidArgument(other.to_string())),
+        }
+    }
+    Ok(builder)
+}
+
+/// seed / reset の引数を解析
+fn parse_maintenance_args(
+    subcommand: &str,
+    mut args: impl Iterator<Item = String>,
+) -> AppResult<MaintenanceCommand> {
+    let mut data_dir = None;
+    let mut keep_masters = false;
+    let mut assume_yes = false;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--data-dir" => data_dir = Some(parse_data_dir(&mut args)?),
+            "--keep-masters" if subcommand == "reset" => keep_masters = true,
+            "--yes" if subcommand == "reset" => assume_yes = true,
+            other => return Err(AppError::InvalidArgument(other.to_string())),
+        }
+    }
+
+    let data_dir = data_dir.unwrap_or_else(default_data_dir);
+    Ok(match subcommand {
+        "seed" => MaintenanceCommand::Seed { data_dir },
+        _ => MaintenanceCommand::Reset { data_dir, keep_masters, assume_yes },
+    })
+}
+
+/// audit-export の引数を解析
+fn parse_audit_export_args(
+    mut args: impl Iterator<Item = String>,
+) -> AppResult<AuditExportCommand> {
+    let mut data_dir = None;
+    let mut fiscal_year = None;
+    let mut output_dir = PathBuf::from(".");
+    let mut exported_by = DEFAULT_EXPORTED_BY.to_string();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--data-dir" => data_dir = Some(parse_data_dir(&mut args)?),
+            "--fiscal-year" => {
+                fiscal_year =
+                    Some(args.next().and_then(|year| year.parse().ok()).ok_or_else(|| {
+                        AppError::InvalidArgument("--fiscal-year requires a year".to_string())
+                    })?)
+            }
+            "--output" => {
+                output_dir = args.next().map(PathBuf::from).ok_or_else(|| {
+                    AppError::InvalidArgument("--output requires a path".to_string())
+                })?
+            }
+            "--user" => {
+                exported_by = args.next().ok_or_else(|| {
+                    AppError::InvalidArgument("--user requires a user id".to_string())
+                })?
+            }
+            other => return Err(AppError::InvalidArgument(other.to_string())),
+        }
+    }
+
+    Ok(AuditExportCommand {
+        data_dir: data_dir.unwrap_or_else(default_data_dir),
+        fiscal_year: fiscal_year.ok_or_else(|| {
+            AppError::InvalidArgument("audit-export requires --fiscal-year".to_string())
+        })?,
+        output_dir,
+        exported_by,
+    })
+}
+
+/// generate の引数を解析
+fn parse_generate_args(mut args: impl Iterator<Item = String>) -> AppResult<GenerateCommand> {
+    let mut command = GenerateCommand {
+        data_dir: default_data_dir(),
+        entries: DEFAULT_GENERATE_ENTRIES,
+        periods: DEFAULT_GENERATE_PERIODS,
+        seed: DEFAULT_GENERATE_SEED,
+        durability: DEFAULT_GENERATE_DURABILITY,
+        writers: 1,
+    };
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--data-dir" => command.data_dir = parse_data_dir(&mut args)?,
+            "--entries" => {
+                command.entries = args
+                    .next()
+                    .and_then(|entries| entries.parse().ok())
+                    .filter(|entries| *entries > 0)
+                    .ok_or_else(|| {
+                        AppError::InvalidArgument(
+                            "--entries requires a positive number".to_string(),
+                        )
+                    })?
+            }
+            "--periods" => {
+                command.periods = args
+                    .next()
+                    .and_then(|periods| periods.parse().ok())
+                    .filter(|periods| *periods > 0)
+                    .ok_or_else(|| {
+                        AppError::InvalidArgument(
+                            "--periods requires a positive number of months".to_string(),
+                        )
+                    })?
+            }
+            "--seed" => {
+                command.seed = args.next().and_then(|seed| seed.parse().ok()).ok_or_else(|| {
+                    AppError::InvalidArgument("--seed requires a number".to_string())
+                })?
+            }
+            "--durability" => {
+                command.durability = args
+                    .next()
+                    .and_then(|name| DurabilityPolicy::parse(&name))
+                    .ok_or_else(|| {
+                        AppError::InvalidArgument(
+                            "--durability requires max-durability, balanced, \
+                                 max-performance or group-commit[:<ms>]"
+                                .to_string(),
+                        )
+                    })?
+            }
+            "--writers" => {
+                command.writers = args
+                    .next()
+                    .and_then(|writers| writers.parse().ok())
+                    .filter(|writers| *writers > 0)
+                    .ok_or_else(|| {
+                        AppError::InvalidArgument(
+                            "--writers requires a positive number".to_string(),
+                        )
+                    })?
+            }
+            other => return Err(AppError::InvalidArgument(other.to_string())),
+        }
+    }
+    Ok(command)
+}
+
+/// verify-export の引数を解析
+fn parse_verify_export_args(
+    mut args: impl Iterator<Item = String>,
+) -> AppResult<VerifyExportCommand> {
+    let mut data_dir = None;
+    let mut file = None;
+    let mut signature = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--data-dir" => data_dir = Some(parse_data_dir(&mut args)?),
+            "--file" => {
+                file = Some(args.next().map(PathBuf::from).ok_or_else(|| {
+                    AppError::InvalidArgument("--file requires a path".to_string())
+                })?)
+            }
+            "--signature" => {
+                signature = Some(args.next().map(PathBuf::from).ok_or_else(|| {
+                    AppError::InvalidArgument("--signature requires a path".to_string())
+                })?)
+            }
+            other => return Err(AppError::InvalidArgument(other.to_string())),
+        }
+    }
+
+    Ok(VerifyExportCommand {
+        data_dir: data_dir.unwrap_or_else(default_data_dir),
+        file: file.ok_or_else(|| {
+            AppError::InvalidArgument("verify-export requires --file".to_string())
+        })?,
+        signature,
+    })
+}
+
+/// projections の引数を解析
+fn parse_projections_args(mut args: impl Iterator<Item = String>) -> AppResult<ProjectionsCommand> {
+    let mut command = ProjectionsCommand { data_dir: default_data_dir(), repair: false };
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--data-dir" => command.data_dir = parse_data_dir(&mut args)?,
+            "--repair" => command.repair = true,
+            other => return Err(AppError::InvalidArgument(other.to_string())),
+        }
+    }
+    Ok(command)
+}
+
+fn parse_data_dir(args: &mut impl Iterator<Item = String>) -> AppResult<PathBuf> {
+    args.next()
+        .map(PathBuf::from)
+        .ok_or_else(|| AppError::InvalidArgument("--data-dir requires a path".to_string()))
+}
 
 #[tokio::main]
 async fn main() -> AppResult<()> {
@@ -10,8 +366,17 @@ async fn main() -> AppResult<()> {
         javelin::app_error::AppError::Unknown(format!("color-eyre initialization failed: {}", e))
     })?;
 
+    let builder = match parse_args(std::env::args().skip(1))? {
+        Command::Run(builder) => builder,
+        Command::Maintenance(command) => return command.execute().await,
+        Command::AuditExport(command) => return command.execute().await,
+        Command::Generate(command) => return command.execute().await,
+        Command::VerifyExport(command) => return command.execute().await,
+        Command::Projections(command) => return command.execute().await,
+    };
+
     // アプリケーション構築
-    let app = ApplicationBuilder::new().build().await?;
+    let app = builder.build().await?;
 
     // アプリケーション実行
     app.run()?;